@@ -23,6 +23,8 @@ fi
 # Ensure SSH directory has proper permissions
 chown torrust:torrust /home/torrust/.ssh
 chmod 700 /home/torrust/.ssh
+# The Docker provider uploads authorized_keys as root before the first start
+chown torrust:torrust /home/torrust/.ssh/authorized_keys
 chmod 600 /home/torrust/.ssh/authorized_keys
 
 # Signal that container is ready
@@ -98,15 +98,17 @@ resource "lxd_instance" "torrust_vm" {
 
 # Output information about the container
 # IMPORTANT: This output is parsed by src/opentofu/json_parser.rs
-# The output name "instance_info" and all fields (name, image, status, ip_address)
-# are required by the parser and must remain present with these exact names.
+# The output name "instance_info" and all fields (name, image, status, ipv4_address,
+# ipv6_address) are required by the parser and must remain present with these exact
+# names. An address the instance does not have is an empty string.
 output "instance_info" {
   description = "Information about the created container"
   value = {
-    name       = lxd_instance.torrust_vm.name
-    image      = lxd_instance.torrust_vm.image
-    status     = lxd_instance.torrust_vm.status
-    ip_address = lxd_instance.torrust_vm.ipv4_address
+    name         = lxd_instance.torrust_vm.name
+    image        = lxd_instance.torrust_vm.image
+    status       = lxd_instance.torrust_vm.status
+    ipv4_address = lxd_instance.torrust_vm.ipv4_address
+    ipv6_address = lxd_instance.torrust_vm.ipv6_address
   }
   depends_on = [lxd_instance.torrust_vm]
 }
@@ -139,15 +139,17 @@ resource "hcloud_server" "torrust" {
 # ============================================================================
 
 # IMPORTANT: This output is parsed by src/adapters/tofu/json_parser.rs
-# The output name "instance_info" and all fields (name, image, status, ip_address)
-# are required by the parser and must remain present with these exact names.
+# The output name "instance_info" and all fields (name, image, status, ipv4_address,
+# ipv6_address) are required by the parser and must remain present with these exact
+# names. An address the instance does not have is an empty string.
 output "instance_info" {
   description = "Information about the created server"
   value = {
-    name       = hcloud_server.torrust.name
-    image      = hcloud_server.torrust.image
-    status     = hcloud_server.torrust.status
-    ip_address = hcloud_server.torrust.ipv4_address
+    name         = hcloud_server.torrust.name
+    image        = hcloud_server.torrust.image
+    status       = hcloud_server.torrust.status
+    ipv4_address = hcloud_server.torrust.ipv4_address
+    ipv6_address = hcloud_server.torrust.ipv6_address
   }
   depends_on = [hcloud_server.torrust]
 }
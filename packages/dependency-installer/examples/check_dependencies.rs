@@ -5,7 +5,9 @@
 //!
 //! Run with: `cargo run --example check_dependencies`
 
-use torrust_tracker_deployer_dependency_installer::{init_tracing, DependencyManager};
+use torrust_tracker_deployer_dependency_installer::{
+    init_tracing, DependencyManager, DependencyStatus,
+};
 
 fn main() {
     // Initialize tracing for structured logging with INFO level
@@ -25,11 +27,18 @@ fn main() {
             for result in &results {
                 let detector = manager.get_detector(result.dependency);
                 let name = detector.name();
-                let status = if result.installed { "✓" } else { "✗" };
-                let status_text = if result.installed {
-                    "Installed"
+                let status = if result.status.is_satisfied() {
+                    "✓"
                 } else {
-                    "Not Installed"
+                    "✗"
+                };
+                let status_text = match result.status {
+                    DependencyStatus::Installed { version: Some(v) } => format!("Installed ({v})"),
+                    DependencyStatus::Installed { version: None } => "Installed".to_string(),
+                    DependencyStatus::Outdated { found, required } => {
+                        format!("Outdated ({found} < {required})")
+                    }
+                    DependencyStatus::NotInstalled => "Not Installed".to_string(),
                 };
 
                 println!("{status} {name:20} {status_text}");
@@ -22,7 +22,7 @@ use crate::DependencyManager;
 pub enum ExitCode {
     /// Success - all checks passed
     Success = 0,
-    /// Missing dependencies (tool not installed or missing dependencies)
+    /// Missing dependencies (tool not installed, outdated, or missing dependencies)
     MissingDependencies = 1,
     /// Invalid arguments (unknown tool name)
     InvalidArguments = 2,
@@ -82,11 +82,19 @@ pub async fn run() -> ExitCode {
 /// Returns an error if the command execution fails
 async fn execute_command(command: &Commands, manager: &DependencyManager) -> Result<(), AppError> {
     match command {
-        Commands::Check { dependency } => {
-            crate::handlers::check::handle_check(manager, *dependency)?;
+        Commands::Check {
+            dependency,
+            allow_outdated,
+        } => {
+            crate::handlers::check::handle_check(manager, *dependency, *allow_outdated)?;
         }
-        Commands::Install { dependency } => {
-            crate::handlers::install::handle_install(manager, *dependency).await?;
+        Commands::Install {
+            dependency,
+            upgrade,
+            docker_rootless,
+        } => {
+            let manager = manager.with_docker_rootless(*docker_rootless);
+            crate::handlers::install::handle_install(&manager, *dependency, *upgrade).await?;
         }
         Commands::List => {
             crate::handlers::list::handle_list(manager)?;
@@ -151,7 +159,8 @@ impl AppError {
         match self {
             Self::CheckFailed { source } => match source {
                 CheckError::CheckAllFailed { source } => match source {
-                    CheckAllDependenciesError::MissingDependencies { .. } => {
+                    CheckAllDependenciesError::MissingDependencies { .. }
+                    | CheckAllDependenciesError::OutdatedDependencies { .. } => {
                         ExitCode::MissingDependencies
                     }
                     CheckAllDependenciesError::DependencyCheckFailed { .. } => {
@@ -159,7 +168,8 @@ impl AppError {
                     }
                 },
                 CheckError::CheckSpecificFailed { source } => match source {
-                    CheckSpecificDependencyError::DependencyNotInstalled { .. } => {
+                    CheckSpecificDependencyError::DependencyNotInstalled { .. }
+                    | CheckSpecificDependencyError::DependencyOutdated { .. } => {
                         ExitCode::MissingDependencies
                     }
                     CheckSpecificDependencyError::DetectionFailed { .. } => ExitCode::InternalError,
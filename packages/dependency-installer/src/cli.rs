@@ -66,6 +66,10 @@ pub enum Commands {
         /// Specific dependency to check (if omitted, checks all)
         #[arg(short = 'd', long)]
         dependency: Option<Dependency>,
+
+        /// Accept installed tools older than the minimum supported version
+        #[arg(long)]
+        allow_outdated: bool,
     },
 
     /// Install dependencies
@@ -73,6 +77,14 @@ pub enum Commands {
         /// Specific dependency to install (if omitted, installs all)
         #[arg(short = 'd', long)]
         dependency: Option<Dependency>,
+
+        /// Upgrade outdated dependencies and skip the ones already up to date
+        #[arg(long)]
+        upgrade: bool,
+
+        /// Set up Docker in rootless mode, for accounts without the docker group
+        #[arg(long)]
+        docker_rootless: bool,
     },
 
     /// List all available tools and their status
@@ -6,7 +6,8 @@
 use tracing::info;
 
 // Internal crate
-use crate::command::command_exists;
+use crate::command::{command_exists, execute_command};
+use crate::version::Version;
 use crate::Dependency;
 
 use super::{DependencyDetector, DetectionError};
@@ -27,6 +28,10 @@ impl DependencyDetector for AnsibleDetector {
         "Ansible"
     }
 
+    fn dependency(&self) -> Dependency {
+        Dependency::Ansible
+    }
+
     fn is_installed(&self) -> Result<bool, DetectionError> {
         info!(dependency = "ansible", "Checking if Ansible is installed");
 
@@ -51,4 +56,30 @@ impl DependencyDetector for AnsibleDetector {
 
         Ok(installed)
     }
+
+    fn installed_version(&self) -> Result<Option<Version>, DetectionError> {
+        let output = execute_command("ansible", &["--version"]).map_err(|e| {
+            DetectionError::DetectionFailed {
+                dependency: Dependency::Ansible,
+                source: e.into(),
+            }
+        })?;
+
+        Ok(parse_version(&output))
+    }
+}
+
+// ============================================================================
+// PUBLIC API - Functions
+// ============================================================================
+
+/// Parse the version from `ansible --version` output
+///
+/// Modern releases report the `ansible-core` version on the first line
+/// (`ansible [core 2.16.3]`), while older releases use `ansible 2.9.6`. The
+/// remaining lines list configuration paths and the Python version, which
+/// must not be mistaken for the Ansible version.
+#[must_use]
+pub fn parse_version(output: &str) -> Option<Version> {
+    output.lines().next().and_then(Version::find_in)
 }
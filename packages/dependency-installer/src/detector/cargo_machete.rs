@@ -27,6 +27,10 @@ impl DependencyDetector for CargoMacheteDetector {
         "cargo-machete"
     }
 
+    fn dependency(&self) -> Dependency {
+        Dependency::CargoMachete
+    }
+
     fn is_installed(&self) -> Result<bool, DetectionError> {
         info!(
             dependency = "cargo-machete",
@@ -0,0 +1,215 @@
+//! `Docker` dependency detector
+//!
+//! This module provides detection logic for the `Docker` dependency.
+//!
+//! Both the standard (rootful) installation and rootless mode are detected.
+//! A rootless installation made by `dockerd-rootless-setuptool.sh` keeps the
+//! CLI in `~/bin`, which is often missing from `PATH`, and serves the daemon
+//! on `$XDG_RUNTIME_DIR/docker.sock` instead of `/var/run/docker.sock`.
+
+// Standard library
+use std::env;
+use std::fmt;
+use std::path::{Path, PathBuf};
+
+// External crates
+use tracing::{info, warn};
+
+// Internal crate
+use crate::command::{command_exists, execute_command};
+use crate::version::Version;
+use crate::Dependency;
+
+use super::{DependencyDetector, DetectionError};
+
+// ============================================================================
+// PUBLIC API - Main Types
+// ============================================================================
+
+/// Detector for `Docker` dependency
+pub struct DockerDetector;
+
+/// How the Docker daemon used by the current user runs
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DockerMode {
+    /// System daemon owned by root, reached through `/var/run/docker.sock`
+    Rootful,
+    /// Per-user daemon, reached through `$XDG_RUNTIME_DIR/docker.sock`
+    Rootless,
+}
+
+// ============================================================================
+// PUBLIC API - Implementations
+// ============================================================================
+
+impl DependencyDetector for DockerDetector {
+    fn name(&self) -> &'static str {
+        "Docker"
+    }
+
+    fn dependency(&self) -> Dependency {
+        Dependency::Docker
+    }
+
+    fn is_installed(&self) -> Result<bool, DetectionError> {
+        info!(dependency = "docker", "Checking if Docker is installed");
+
+        let cli = docker_cli()?;
+        let installed = cli.is_some();
+
+        if let Some(cli) = cli.filter(|cli| cli != "docker") {
+            warn!(
+                dependency = "docker",
+                path = %cli,
+                "Rootless Docker CLI found outside PATH, add its directory to PATH"
+            );
+        }
+
+        if installed {
+            info!(
+                dependency = "docker",
+                status = "installed",
+                mode = %detect_mode(),
+                "Docker is installed"
+            );
+        } else {
+            info!(
+                dependency = "docker",
+                status = "not installed",
+                "Docker is not installed"
+            );
+        }
+
+        Ok(installed)
+    }
+
+    fn installed_version(&self) -> Result<Option<Version>, DetectionError> {
+        let Some(docker) = docker_cli()? else {
+            return Ok(None);
+        };
+
+        let output = execute_command(&docker, &["--version"]).map_err(|e| {
+            DetectionError::DetectionFailed {
+                dependency: Dependency::Docker,
+                source: e.into(),
+            }
+        })?;
+
+        Ok(parse_version(&output))
+    }
+}
+
+impl fmt::Display for DockerMode {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Rootful => f.write_str("rootful"),
+            Self::Rootless => f.write_str("rootless"),
+        }
+    }
+}
+
+// ============================================================================
+// PUBLIC API - Functions
+// ============================================================================
+
+/// Parse the version from `docker --version` output
+///
+/// The output has the form `Docker version 27.3.1, build ce12230`. Distro
+/// packages may append a suffix to the version (e.g. `20.10.24+dfsg1`).
+#[must_use]
+pub fn parse_version(output: &str) -> Option<Version> {
+    output.lines().next().and_then(Version::find_in)
+}
+
+/// Socket of the rootless daemon for the given `XDG_RUNTIME_DIR`
+///
+/// Returns `None` when the runtime directory is unknown.
+#[must_use]
+pub fn rootless_socket_path(xdg_runtime_dir: Option<&Path>) -> Option<PathBuf> {
+    xdg_runtime_dir
+        .filter(|dir| !dir.as_os_str().is_empty())
+        .map(|dir| dir.join("docker.sock"))
+}
+
+/// Whether a Docker endpoint (`DOCKER_HOST` or a `docker context` endpoint)
+/// targets a rootless daemon
+///
+/// Rootless daemons listen on `docker.sock` in the user runtime directory:
+/// `$XDG_RUNTIME_DIR` when known, `/run/user/<uid>` otherwise.
+#[must_use]
+pub fn is_rootless_endpoint(endpoint: &str, xdg_runtime_dir: Option<&Path>) -> bool {
+    let Some(socket) = endpoint.trim().strip_prefix("unix://") else {
+        return false;
+    };
+    let socket = Path::new(socket);
+
+    match rootless_socket_path(xdg_runtime_dir) {
+        Some(rootless_socket) if socket == rootless_socket => true,
+        _ => socket.starts_with("/run/user/") && socket.ends_with("docker.sock"),
+    }
+}
+
+/// Detect whether the current user talks to a rootful or a rootless daemon
+///
+/// `DOCKER_HOST` wins over the endpoint of the current `docker context`.
+/// When neither can be read, an existing rootless socket means rootless mode.
+#[must_use]
+pub fn detect_mode() -> DockerMode {
+    let xdg_runtime_dir = env::var_os("XDG_RUNTIME_DIR").map(PathBuf::from);
+    let xdg_runtime_dir = xdg_runtime_dir.as_deref();
+
+    let endpoint = env::var("DOCKER_HOST").ok().or_else(|| {
+        let docker = docker_cli().ok().flatten()?;
+        execute_command(
+            &docker,
+            &[
+                "context",
+                "inspect",
+                "--format",
+                "{{.Endpoints.docker.Host}}",
+            ],
+        )
+        .ok()
+    });
+
+    let rootless = match endpoint {
+        Some(endpoint) => is_rootless_endpoint(&endpoint, xdg_runtime_dir),
+        None => rootless_socket_path(xdg_runtime_dir).is_some_and(|socket| socket.exists()),
+    };
+
+    if rootless {
+        DockerMode::Rootless
+    } else {
+        DockerMode::Rootful
+    }
+}
+
+// ============================================================================
+// PRIVATE - Helper Functions
+// ============================================================================
+
+/// Resolve the Docker CLI to run
+///
+/// Prefers `docker` from `PATH`. Falls back to `~/bin/docker`, where rootless
+/// installations put the CLI, when the rootless socket exists.
+fn docker_cli() -> Result<Option<String>, DetectionError> {
+    let on_path = command_exists("docker").map_err(|e| DetectionError::DetectionFailed {
+        dependency: Dependency::Docker,
+        source: e.into(),
+    })?;
+
+    if on_path {
+        return Ok(Some("docker".to_string()));
+    }
+
+    let xdg_runtime_dir = env::var_os("XDG_RUNTIME_DIR").map(PathBuf::from);
+    let has_rootless_socket =
+        rootless_socket_path(xdg_runtime_dir.as_deref()).is_some_and(|socket| socket.exists());
+    let user_cli = env::var_os("HOME")
+        .map(|home| PathBuf::from(home).join("bin").join("docker"))
+        .filter(|cli| cli.is_file());
+
+    Ok(user_cli
+        .filter(|_| has_rootless_socket)
+        .map(|cli| cli.to_string_lossy().into_owned()))
+}
@@ -6,7 +6,8 @@
 use tracing::info;
 
 // Internal crate
-use crate::command::command_exists;
+use crate::command::{command_exists, execute_command};
+use crate::version::Version;
 use crate::Dependency;
 
 use super::{DependencyDetector, DetectionError};
@@ -27,6 +28,10 @@ impl DependencyDetector for LxdDetector {
         "LXD"
     }
 
+    fn dependency(&self) -> Dependency {
+        Dependency::Lxd
+    }
+
     fn is_installed(&self) -> Result<bool, DetectionError> {
         info!(dependency = "lxd", "Checking if LXD is installed");
 
@@ -48,4 +53,28 @@ impl DependencyDetector for LxdDetector {
 
         Ok(installed)
     }
+
+    fn installed_version(&self) -> Result<Option<Version>, DetectionError> {
+        let output = execute_command("lxc", &["--version"]).map_err(|e| {
+            DetectionError::DetectionFailed {
+                dependency: Dependency::Lxd,
+                source: e.into(),
+            }
+        })?;
+
+        Ok(parse_version(&output))
+    }
+}
+
+// ============================================================================
+// PUBLIC API - Functions
+// ============================================================================
+
+/// Parse the version from `lxc --version` output
+///
+/// The output is the bare version number, optionally followed by the
+/// release channel (e.g. `5.21.3 LTS` or `5.0.2`).
+#[must_use]
+pub fn parse_version(output: &str) -> Option<Version> {
+    output.lines().next().and_then(Version::find_in)
 }
@@ -5,17 +5,24 @@
 
 pub mod ansible;
 pub mod cargo_machete;
+pub mod docker;
 pub mod lxd;
 pub mod opentofu;
 
+// Standard library
+use std::fmt;
+
 // External crates
 use thiserror::Error;
 
 // Internal crate
+use crate::manifest::minimum_version;
+use crate::version::Version;
 use crate::Dependency;
 
 pub use ansible::AnsibleDetector;
 pub use cargo_machete::CargoMacheteDetector;
+pub use docker::{DockerDetector, DockerMode};
 pub use lxd::LxdDetector;
 pub use opentofu::OpenTofuDetector;
 
@@ -28,6 +35,9 @@ pub trait DependencyDetector {
     /// Get the dependency name for display purposes
     fn name(&self) -> &'static str;
 
+    /// Get the dependency enum value
+    fn dependency(&self) -> Dependency;
+
     /// Check if the dependency is already installed
     ///
     /// # Errors
@@ -35,9 +45,99 @@ pub trait DependencyDetector {
     /// Returns an error if the detection process fails
     fn is_installed(&self) -> Result<bool, DetectionError>;
 
-    /// Get the required version (if applicable)
-    fn required_version(&self) -> Option<&str> {
-        None // Default implementation
+    /// Get the version of the installed tool (if it can be determined)
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the version command cannot be executed
+    fn installed_version(&self) -> Result<Option<Version>, DetectionError> {
+        Ok(None) // Default implementation
+    }
+
+    /// Get the required minimum version (if applicable)
+    ///
+    /// Defaults to the version declared in the [`manifest`](crate::manifest).
+    fn required_version(&self) -> Option<Version> {
+        minimum_version(self.dependency())
+    }
+
+    /// Detect the installation status, including version constraints
+    ///
+    /// A tool whose version cannot be determined is reported as installed,
+    /// since refusing to run on an unparseable version string would be more
+    /// disruptive than a late failure.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the detection process fails
+    fn status(&self) -> Result<DependencyStatus, DetectionError> {
+        if !self.is_installed()? {
+            return Ok(DependencyStatus::NotInstalled);
+        }
+
+        let found = self.installed_version()?;
+
+        match (found, self.required_version()) {
+            (Some(found), Some(required)) if found < required => {
+                Ok(DependencyStatus::Outdated { found, required })
+            }
+            (version, _) => Ok(DependencyStatus::Installed { version }),
+        }
+    }
+}
+
+// ============================================================================
+// PUBLIC API - Main Types
+// ============================================================================
+
+/// Installation status of a dependency
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DependencyStatus {
+    /// The tool is not available in the system PATH
+    NotInstalled,
+
+    /// The tool is installed and satisfies the minimum version (if any)
+    Installed {
+        /// The detected version, if it could be determined
+        version: Option<Version>,
+    },
+
+    /// The tool is installed but older than the minimum supported version
+    Outdated {
+        /// The detected version
+        found: Version,
+        /// The minimum version declared in the manifest
+        required: Version,
+    },
+}
+
+impl DependencyStatus {
+    /// Whether the tool is present in the system, regardless of its version
+    #[must_use]
+    pub const fn is_installed(&self) -> bool {
+        !matches!(self, Self::NotInstalled)
+    }
+
+    /// Whether the tool is present but older than the required version
+    #[must_use]
+    pub const fn is_outdated(&self) -> bool {
+        matches!(self, Self::Outdated { .. })
+    }
+
+    /// Whether the tool is present and satisfies the version constraint
+    #[must_use]
+    pub const fn is_satisfied(&self) -> bool {
+        matches!(self, Self::Installed { .. })
+    }
+}
+
+impl fmt::Display for DependencyStatus {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::NotInstalled => f.write_str("not installed"),
+            Self::Installed { .. } => f.write_str("installed"),
+            Self::Outdated { .. } => f.write_str("outdated"),
+        }
     }
 }
 
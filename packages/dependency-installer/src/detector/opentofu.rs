@@ -6,7 +6,8 @@
 use tracing::info;
 
 // Internal crate
-use crate::command::command_exists;
+use crate::command::{command_exists, execute_command};
+use crate::version::Version;
 use crate::Dependency;
 
 use super::{DependencyDetector, DetectionError};
@@ -27,6 +28,10 @@ impl DependencyDetector for OpenTofuDetector {
         "OpenTofu"
     }
 
+    fn dependency(&self) -> Dependency {
+        Dependency::OpenTofu
+    }
+
     fn is_installed(&self) -> Result<bool, DetectionError> {
         info!(dependency = "opentofu", "Checking if OpenTofu is installed");
 
@@ -51,4 +56,28 @@ impl DependencyDetector for OpenTofuDetector {
 
         Ok(installed)
     }
+
+    fn installed_version(&self) -> Result<Option<Version>, DetectionError> {
+        let output = execute_command("tofu", &["--version"]).map_err(|e| {
+            DetectionError::DetectionFailed {
+                dependency: Dependency::OpenTofu,
+                source: e.into(),
+            }
+        })?;
+
+        Ok(parse_version(&output))
+    }
+}
+
+// ============================================================================
+// PUBLIC API - Functions
+// ============================================================================
+
+/// Parse the version from `tofu --version` output
+///
+/// The first line has the form `OpenTofu v1.8.5`, optionally followed by the
+/// platform and provider versions on subsequent lines.
+#[must_use]
+pub fn parse_version(output: &str) -> Option<Version> {
+    output.lines().next().and_then(Version::find_in)
 }
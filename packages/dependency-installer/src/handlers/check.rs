@@ -4,10 +4,11 @@
 
 // External crates
 use thiserror::Error;
-use tracing::info;
+use tracing::{info, warn};
 
 // Internal crate
-use crate::detector::DetectionError;
+use crate::detector::{DependencyStatus, DetectionError};
+use crate::version::Version;
 use crate::{Dependency, DependencyManager};
 
 // ============================================================================
@@ -16,18 +17,23 @@ use crate::{Dependency, DependencyManager};
 
 /// Handle the check command
 ///
+/// Outdated dependencies are reported as failures unless `allow_outdated`
+/// is set, in which case they are only logged as warnings.
+///
 /// # Errors
 ///
 /// Returns an error if:
 /// - Dependencies are missing
+/// - Dependencies are outdated and `allow_outdated` is not set
 /// - Internal error occurs during dependency checking
 pub fn handle_check(
     manager: &DependencyManager,
     dependency: Option<Dependency>,
+    allow_outdated: bool,
 ) -> Result<(), CheckError> {
     match dependency {
-        Some(dep) => check_specific_dependency(manager, dep)?,
-        None => check_all_dependencies(manager)?,
+        Some(dep) => check_specific_dependency(manager, dep, allow_outdated)?,
+        None => check_all_dependencies(manager, allow_outdated)?,
     }
 
     Ok(())
@@ -37,29 +43,47 @@ pub fn handle_check(
 // PRIVATE - Helper Functions
 // ============================================================================
 
-fn check_all_dependencies(manager: &DependencyManager) -> Result<(), CheckAllDependenciesError> {
+fn check_all_dependencies(
+    manager: &DependencyManager,
+    allow_outdated: bool,
+) -> Result<(), CheckAllDependenciesError> {
     info!("Checking all dependencies");
 
     let results = manager.check_all()?;
 
     let mut missing_count = 0;
+    let mut outdated_count = 0;
 
     for result in &results {
         let detector = manager.get_detector(result.dependency);
         let name = detector.name();
-        if result.installed {
-            info!(
-                dependency = name,
-                status = "installed",
-                "Dependency check result"
-            );
-        } else {
-            info!(
-                dependency = name,
-                status = "not installed",
-                "Dependency check result"
-            );
-            missing_count += 1;
+        match result.status {
+            DependencyStatus::Installed { version } => {
+                info!(
+                    dependency = name,
+                    status = "installed",
+                    version = version.map(|v| v.to_string()),
+                    "Dependency check result"
+                );
+            }
+            DependencyStatus::Outdated { found, required } => {
+                warn!(
+                    dependency = name,
+                    status = "outdated",
+                    found = %found,
+                    required = %required,
+                    "Dependency check result"
+                );
+                outdated_count += 1;
+            }
+            DependencyStatus::NotInstalled => {
+                info!(
+                    dependency = name,
+                    status = "not installed",
+                    "Dependency check result"
+                );
+                missing_count += 1;
+            }
         }
     }
 
@@ -73,6 +97,16 @@ fn check_all_dependencies(manager: &DependencyManager) -> Result<(), CheckAllDep
             missing_count,
             total_count: results.len(),
         })
+    } else if outdated_count > 0 && !allow_outdated {
+        info!(
+            outdated_count,
+            total_count = results.len(),
+            "Outdated dependencies"
+        );
+        Err(CheckAllDependenciesError::OutdatedDependencies {
+            outdated_count,
+            total_count: results.len(),
+        })
     } else {
         info!("All dependencies are installed");
         Ok(())
@@ -82,27 +116,48 @@ fn check_all_dependencies(manager: &DependencyManager) -> Result<(), CheckAllDep
 fn check_specific_dependency(
     manager: &DependencyManager,
     dependency: Dependency,
+    allow_outdated: bool,
 ) -> Result<(), CheckSpecificDependencyError> {
     info!(dependency = %dependency, "Checking specific dependency");
 
     let detector = manager.get_detector(dependency);
 
-    let installed = detector.is_installed()?;
-
-    if installed {
-        info!(
-            dependency = detector.name(),
-            status = "installed",
-            "Dependency is installed"
-        );
-        Ok(())
-    } else {
-        info!(
-            dependency = detector.name(),
-            status = "not installed",
-            "Dependency is not installed"
-        );
-        Err(CheckSpecificDependencyError::DependencyNotInstalled { dependency })
+    match detector.status()? {
+        DependencyStatus::Installed { version } => {
+            info!(
+                dependency = detector.name(),
+                status = "installed",
+                version = version.map(|v| v.to_string()),
+                "Dependency is installed"
+            );
+            Ok(())
+        }
+        DependencyStatus::Outdated { found, required } => {
+            warn!(
+                dependency = detector.name(),
+                status = "outdated",
+                found = %found,
+                required = %required,
+                "Dependency is outdated"
+            );
+            if allow_outdated {
+                Ok(())
+            } else {
+                Err(CheckSpecificDependencyError::DependencyOutdated {
+                    dependency,
+                    found,
+                    required,
+                })
+            }
+        }
+        DependencyStatus::NotInstalled => {
+            info!(
+                dependency = detector.name(),
+                status = "not installed",
+                "Dependency is not installed"
+            );
+            Err(CheckSpecificDependencyError::DependencyNotInstalled { dependency })
+        }
     }
 }
 
@@ -167,6 +222,18 @@ pub enum CheckAllDependenciesError {
         /// Total number of dependencies checked
         total_count: usize,
     },
+
+    /// One or more dependencies are older than the minimum supported version
+    ///
+    /// This occurs when required tools are installed but outdated, and
+    /// `--allow-outdated` was not passed.
+    #[error("{outdated_count} out of {total_count} dependencies are outdated (use `install --upgrade` or pass `--allow-outdated`)")]
+    OutdatedDependencies {
+        /// Number of outdated dependencies
+        outdated_count: usize,
+        /// Total number of dependencies checked
+        total_count: usize,
+    },
 }
 
 impl From<DetectionError> for CheckAllDependenciesError {
@@ -196,6 +263,20 @@ pub enum CheckSpecificDependencyError {
         /// The dependency that is not installed
         dependency: Dependency,
     },
+
+    /// Dependency is older than the minimum supported version
+    ///
+    /// This occurs when the specified dependency is installed but outdated,
+    /// and `--allow-outdated` was not passed.
+    #[error("{dependency}: version {found} is older than the required {required} (use `install --upgrade` or pass `--allow-outdated`)")]
+    DependencyOutdated {
+        /// The outdated dependency
+        dependency: Dependency,
+        /// The installed version
+        found: Version,
+        /// The minimum supported version
+        required: Version,
+    },
 }
 
 impl From<DetectionError> for CheckSpecificDependencyError {
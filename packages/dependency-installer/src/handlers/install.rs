@@ -16,6 +16,10 @@ use crate::{Dependency, DependencyManager};
 
 /// Handle the install command
 ///
+/// In upgrade mode, outdated dependencies are upgraded, missing ones are
+/// installed, and dependencies that already satisfy the minimum version
+/// manifest are skipped.
+///
 /// # Errors
 ///
 /// Returns an error if:
@@ -24,10 +28,11 @@ use crate::{Dependency, DependencyManager};
 pub async fn handle_install(
     manager: &DependencyManager,
     dependency: Option<Dependency>,
+    upgrade: bool,
 ) -> Result<(), InstallError> {
     match dependency {
-        Some(dep) => install_specific_dependency(manager, dep).await?,
-        None => install_all_dependencies(manager).await?,
+        Some(dep) => install_specific_dependency(manager, dep, upgrade).await?,
+        None => install_all_dependencies(manager, upgrade).await?,
     }
 
     Ok(())
@@ -39,10 +44,15 @@ pub async fn handle_install(
 
 async fn install_all_dependencies(
     manager: &DependencyManager,
+    upgrade: bool,
 ) -> Result<(), InstallAllDependenciesError> {
-    info!("Installing all dependencies");
+    info!(upgrade, "Installing all dependencies");
 
-    let results = manager.install_all().await;
+    let results = if upgrade {
+        manager.upgrade_all().await
+    } else {
+        manager.install_all().await
+    };
 
     let mut failed_count = 0;
 
@@ -86,12 +96,17 @@ async fn install_all_dependencies(
 async fn install_specific_dependency(
     manager: &DependencyManager,
     dependency: Dependency,
+    upgrade: bool,
 ) -> Result<(), InstallSpecificDependencyError> {
-    info!(dependency = %dependency, "Installing specific dependency");
+    info!(dependency = %dependency, upgrade, "Installing specific dependency");
 
     let installer = manager.get_installer(dependency);
 
-    installer.install().await?;
+    if upgrade {
+        manager.upgrade(dependency).await?;
+    } else {
+        installer.install().await?;
+    }
 
     info!(
         dependency = installer.name(),
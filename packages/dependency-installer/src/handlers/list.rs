@@ -7,7 +7,7 @@ use thiserror::Error;
 use tracing::info;
 
 // Internal crate
-use crate::detector::DetectionError;
+use crate::detector::{DependencyStatus, DetectionError};
 use crate::DependencyManager;
 
 // ============================================================================
@@ -27,12 +27,19 @@ pub fn handle_list(manager: &DependencyManager) -> Result<(), ListError> {
     for result in results {
         let detector = manager.get_detector(result.dependency);
         let name = detector.name();
-        let status = if result.installed {
-            "installed"
-        } else {
-            "not installed"
+        let status = result.status.to_string();
+        let version = match result.status {
+            DependencyStatus::Installed { version } => version,
+            DependencyStatus::Outdated { found, .. } => Some(found),
+            DependencyStatus::NotInstalled => None,
         };
-        info!(dependency = name, status, "Available dependency");
+        info!(
+            dependency = name,
+            status,
+            version = version.map(|v| v.to_string()),
+            required = detector.required_version().map(|v| v.to_string()),
+            "Available dependency"
+        );
     }
 
     Ok(())
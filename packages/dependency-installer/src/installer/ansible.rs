@@ -63,6 +63,32 @@ impl DependencyInstaller for AnsibleInstaller {
         Ok(())
     }
 
+    async fn upgrade(&self) -> Result<(), InstallationError> {
+        info!(dependency = "ansible", "Upgrading Ansible");
+
+        debug!("Upgrading Ansible via apt-get");
+        let output = Command::new("sudo")
+            .args(["apt-get", "install", "-y", "--only-upgrade", "ansible"])
+            .output()
+            .map_err(|e| InstallationError::command_failed(Dependency::Ansible, e))?;
+
+        if !output.status.success() {
+            let stderr = String::from_utf8_lossy(&output.stderr);
+            return Err(InstallationError::InstallationFailed {
+                dependency: Dependency::Ansible,
+                message: format!("apt-get upgrade failed: {stderr}"),
+            });
+        }
+
+        info!(
+            dependency = "ansible",
+            status = "upgraded",
+            "Ansible upgrade completed"
+        );
+
+        Ok(())
+    }
+
     fn requires_sudo(&self) -> bool {
         true
     }
@@ -0,0 +1,237 @@
+//! `Docker` dependency installer
+//!
+//! This module provides installation logic for the `Docker` dependency.
+
+// Standard library
+#[cfg(unix)]
+use std::fs;
+#[cfg(unix)]
+use std::process::Command;
+
+// External crates
+use async_trait::async_trait;
+#[cfg(unix)]
+use tracing::{debug, info};
+
+// Internal crate
+#[cfg(unix)]
+use crate::command::command_exists;
+use crate::Dependency;
+
+use super::{DependencyInstaller, InstallationError};
+
+// ============================================================================
+// PUBLIC API - Main Types
+// ============================================================================
+
+/// Installer for `Docker` dependency
+///
+/// Uses the official convenience script from `get.docker.com`, which installs
+/// the latest Docker Engine release. Re-running the script upgrades an
+/// existing installation, so the default upgrade behavior is sufficient.
+pub struct DockerInstaller;
+
+/// Installer for `Docker` in rootless mode
+///
+/// Sets up a per-user daemon for accounts that cannot join the `docker`
+/// group. Uses `dockerd-rootless-setuptool.sh` when the system already has
+/// it (package `docker-ce-rootless-extras`), otherwise the official rootless
+/// script from `get.docker.com/rootless`, which installs the binaries in
+/// `~/bin` without root privileges. Both need `newuidmap`/`newgidmap`
+/// (package `uidmap`) and subordinate ID ranges for the user.
+pub struct DockerRootlessInstaller;
+
+// ============================================================================
+// PUBLIC API - Implementations
+// ============================================================================
+
+#[async_trait]
+impl DependencyInstaller for DockerInstaller {
+    fn name(&self) -> &'static str {
+        "Docker"
+    }
+
+    fn dependency(&self) -> Dependency {
+        Dependency::Docker
+    }
+
+    async fn install(&self) -> Result<(), InstallationError> {
+        #[cfg(not(unix))]
+        {
+            return Err(InstallationError::InstallationFailed {
+                dependency: Dependency::Docker,
+                message: "Docker installation is only supported on Unix-like systems".to_string(),
+            });
+        }
+
+        #[cfg(unix)]
+        {
+            info!(dependency = "docker", "Installing Docker");
+
+            let script_path = "/tmp/install-docker.sh";
+
+            // Download installer script
+            debug!("Downloading Docker installer script");
+            let output = Command::new("curl")
+                .args([
+                    "--proto",
+                    "=https",
+                    "--tlsv1.2",
+                    "-fsSL",
+                    "https://get.docker.com",
+                    "-o",
+                    script_path,
+                ])
+                .output()
+                .map_err(|e| InstallationError::command_failed(Dependency::Docker, e))?;
+
+            if !output.status.success() {
+                let stderr = String::from_utf8_lossy(&output.stderr);
+                return Err(InstallationError::InstallationFailed {
+                    dependency: Dependency::Docker,
+                    message: format!("Failed to download installer: {stderr}"),
+                });
+            }
+
+            // Run installer with sudo
+            debug!("Running Docker installer with sudo");
+            let output = Command::new("sudo")
+                .args(["sh", script_path])
+                .output()
+                .map_err(|e| InstallationError::command_failed(Dependency::Docker, e))?;
+
+            if !output.status.success() {
+                let stderr = String::from_utf8_lossy(&output.stderr);
+                // Clean up script before returning error (ignore cleanup errors)
+                fs::remove_file(script_path).ok();
+                return Err(InstallationError::InstallationFailed {
+                    dependency: Dependency::Docker,
+                    message: format!("Installer script failed: {stderr}"),
+                });
+            }
+
+            // Clean up installer script
+            debug!("Cleaning up installer script");
+            fs::remove_file(script_path)
+                .map_err(|e| InstallationError::command_failed(Dependency::Docker, e))?;
+
+            info!(
+                dependency = "docker",
+                status = "installed",
+                "Docker installation completed"
+            );
+
+            Ok(())
+        }
+    }
+
+    fn requires_sudo(&self) -> bool {
+        true
+    }
+}
+
+#[async_trait]
+impl DependencyInstaller for DockerRootlessInstaller {
+    fn name(&self) -> &'static str {
+        "Docker"
+    }
+
+    fn dependency(&self) -> Dependency {
+        Dependency::Docker
+    }
+
+    async fn install(&self) -> Result<(), InstallationError> {
+        #[cfg(not(unix))]
+        {
+            return Err(InstallationError::InstallationFailed {
+                dependency: Dependency::Docker,
+                message: "Docker installation is only supported on Unix-like systems".to_string(),
+            });
+        }
+
+        #[cfg(unix)]
+        {
+            info!(
+                dependency = "docker",
+                mode = "rootless",
+                "Installing Docker"
+            );
+
+            let has_setup_tool = command_exists("dockerd-rootless-setuptool.sh")
+                .map_err(|e| InstallationError::command_failed(Dependency::Docker, e.into()))?;
+
+            if has_setup_tool {
+                debug!("Running dockerd-rootless-setuptool.sh");
+                let output = Command::new("dockerd-rootless-setuptool.sh")
+                    .arg("install")
+                    .output()
+                    .map_err(|e| InstallationError::command_failed(Dependency::Docker, e))?;
+
+                if !output.status.success() {
+                    let stderr = String::from_utf8_lossy(&output.stderr);
+                    return Err(InstallationError::InstallationFailed {
+                        dependency: Dependency::Docker,
+                        message: format!("Rootless setup tool failed: {stderr}"),
+                    });
+                }
+            } else {
+                let script_path = "/tmp/install-docker-rootless.sh";
+
+                // Download installer script
+                debug!("Downloading Docker rootless installer script");
+                let output = Command::new("curl")
+                    .args([
+                        "--proto",
+                        "=https",
+                        "--tlsv1.2",
+                        "-fsSL",
+                        "https://get.docker.com/rootless",
+                        "-o",
+                        script_path,
+                    ])
+                    .output()
+                    .map_err(|e| InstallationError::command_failed(Dependency::Docker, e))?;
+
+                if !output.status.success() {
+                    let stderr = String::from_utf8_lossy(&output.stderr);
+                    return Err(InstallationError::InstallationFailed {
+                        dependency: Dependency::Docker,
+                        message: format!("Failed to download installer: {stderr}"),
+                    });
+                }
+
+                // Run installer as the current user: rootless mode refuses root
+                debug!("Running Docker rootless installer");
+                let output = Command::new("sh")
+                    .arg(script_path)
+                    .output()
+                    .map_err(|e| InstallationError::command_failed(Dependency::Docker, e))?;
+
+                if !output.status.success() {
+                    let stderr = String::from_utf8_lossy(&output.stderr);
+                    // Clean up script before returning error (ignore cleanup errors)
+                    fs::remove_file(script_path).ok();
+                    return Err(InstallationError::InstallationFailed {
+                        dependency: Dependency::Docker,
+                        message: format!("Installer script failed: {stderr}"),
+                    });
+                }
+
+                // Clean up installer script
+                debug!("Cleaning up installer script");
+                fs::remove_file(script_path)
+                    .map_err(|e| InstallationError::command_failed(Dependency::Docker, e))?;
+            }
+
+            info!(
+                dependency = "docker",
+                mode = "rootless",
+                status = "installed",
+                "Docker installation completed, make sure ~/bin is in PATH and DOCKER_HOST \
+                 is unix://$XDG_RUNTIME_DIR/docker.sock (or run 'docker context use rootless')"
+            );
+
+            Ok(())
+        }
+    }
+}
@@ -128,6 +128,34 @@ impl DependencyInstaller for LxdInstaller {
         Ok(())
     }
 
+    async fn upgrade(&self) -> Result<(), InstallationError> {
+        info!(dependency = "lxd", "Upgrading LXD");
+
+        // Re-running the full installation would re-initialize LXD, which
+        // is not safe on a machine that already has instances.
+        debug!("Refreshing LXD via snap");
+        let output = Command::new("sudo")
+            .args(["snap", "refresh", "lxd"])
+            .output()
+            .map_err(|e| InstallationError::command_failed(Dependency::Lxd, e))?;
+
+        if !output.status.success() {
+            let stderr = String::from_utf8_lossy(&output.stderr);
+            return Err(InstallationError::InstallationFailed {
+                dependency: Dependency::Lxd,
+                message: format!("snap refresh failed: {stderr}"),
+            });
+        }
+
+        info!(
+            dependency = "lxd",
+            status = "upgraded",
+            "LXD upgrade completed"
+        );
+
+        Ok(())
+    }
+
     fn requires_sudo(&self) -> bool {
         true
     }
@@ -5,6 +5,7 @@
 
 pub mod ansible;
 pub mod cargo_machete;
+pub mod docker;
 pub mod lxd;
 pub mod opentofu;
 
@@ -13,10 +14,12 @@ use async_trait::async_trait;
 use thiserror::Error;
 
 // Internal crate
+use crate::detector::DetectionError;
 use crate::Dependency;
 
 pub use ansible::AnsibleInstaller;
 pub use cargo_machete::CargoMacheteInstaller;
+pub use docker::{DockerInstaller, DockerRootlessInstaller};
 pub use lxd::LxdInstaller;
 pub use opentofu::OpenTofuInstaller;
 
@@ -40,6 +43,18 @@ pub trait DependencyInstaller: Send + Sync {
     /// Returns an error if the installation process fails
     async fn install(&self) -> Result<(), InstallationError>;
 
+    /// Upgrade an already installed but outdated dependency
+    ///
+    /// Defaults to re-running the installation, which is enough for
+    /// installers that always fetch the latest release.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the upgrade process fails
+    async fn upgrade(&self) -> Result<(), InstallationError> {
+        self.install().await
+    }
+
     /// Check if the installer requires sudo privileges
     fn requires_sudo(&self) -> bool {
         false // Default implementation
@@ -68,6 +83,13 @@ pub enum InstallationError {
 
     #[error("Installation requires sudo privileges but sudo is not available")]
     SudoNotAvailable,
+
+    #[error("Failed to detect the installed version of '{dependency}': {source}")]
+    DetectionFailed {
+        dependency: Dependency,
+        #[source]
+        source: DetectionError,
+    },
 }
 
 impl InstallationError {
@@ -6,10 +6,13 @@ pub mod handlers;
 pub mod installer;
 pub mod logging;
 pub mod manager;
+pub mod manifest;
 pub mod verification;
+pub mod version;
 
-pub use detector::{DependencyDetector, DetectionError};
+pub use detector::{DependencyDetector, DependencyStatus, DetectionError};
 pub use installer::{DependencyInstaller, InstallationError};
 pub use logging::*;
 pub use manager::*;
-pub use verification::{verify_dependencies, DependencyVerificationError};
+pub use verification::{verify_dependencies, DependencyVerificationError, OutdatedDependency};
+pub use version::Version;
@@ -9,12 +9,12 @@ use std::str::FromStr;
 
 // Internal crate
 use crate::detector::{
-    AnsibleDetector, CargoMacheteDetector, DependencyDetector, DetectionError, LxdDetector,
-    OpenTofuDetector,
+    AnsibleDetector, CargoMacheteDetector, DependencyDetector, DependencyStatus, DetectionError,
+    DockerDetector, LxdDetector, OpenTofuDetector,
 };
 use crate::installer::{
-    AnsibleInstaller, CargoMacheteInstaller, DependencyInstaller, InstallationError, LxdInstaller,
-    OpenTofuInstaller,
+    AnsibleInstaller, CargoMacheteInstaller, DependencyInstaller, DockerInstaller,
+    DockerRootlessInstaller, InstallationError, LxdInstaller, OpenTofuInstaller,
 };
 
 // ============================================================================
@@ -28,6 +28,7 @@ pub enum Dependency {
     OpenTofu,
     Ansible,
     Lxd,
+    Docker,
 }
 
 /// Result of checking a single dependency
@@ -35,8 +36,8 @@ pub enum Dependency {
 pub struct CheckResult {
     /// The dependency that was checked
     pub dependency: Dependency,
-    /// Whether the dependency is installed
-    pub installed: bool,
+    /// The detected installation status, including version constraints
+    pub status: DependencyStatus,
 }
 
 /// Result of installing a single dependency
@@ -51,7 +52,11 @@ pub struct InstallResult {
 }
 
 /// Main dependency manager for detection operations
-pub struct DependencyManager;
+#[derive(Debug, Clone, Copy)]
+pub struct DependencyManager {
+    /// Whether Docker is installed in rootless mode
+    docker_rootless: bool,
+}
 
 // ============================================================================
 // PUBLIC API - Implementations
@@ -61,7 +66,13 @@ impl Dependency {
     /// Returns all available dependencies
     #[must_use]
     pub const fn all() -> &'static [Self] {
-        &[Self::CargoMachete, Self::OpenTofu, Self::Ansible, Self::Lxd]
+        &[
+            Self::CargoMachete,
+            Self::OpenTofu,
+            Self::Ansible,
+            Self::Lxd,
+            Self::Docker,
+        ]
     }
 
     /// Returns the canonical name for this dependency
@@ -72,6 +83,7 @@ impl Dependency {
             Self::OpenTofu => "opentofu",
             Self::Ansible => "ansible",
             Self::Lxd => "lxd",
+            Self::Docker => "docker",
         }
     }
 }
@@ -91,6 +103,7 @@ impl FromStr for Dependency {
             "opentofu" | "tofu" => Ok(Self::OpenTofu),
             "ansible" => Ok(Self::Ansible),
             "lxd" => Ok(Self::Lxd),
+            "docker" => Ok(Self::Docker),
             _ => Err(DependencyParseError::UnknownDependency {
                 name: s.to_string(),
             }),
@@ -102,7 +115,18 @@ impl DependencyManager {
     /// Create a new dependency manager
     #[must_use]
     pub const fn new() -> Self {
-        Self
+        Self {
+            docker_rootless: false,
+        }
+    }
+
+    /// Install Docker in rootless mode instead of the standard system daemon
+    ///
+    /// Only changes installation: detection accepts both modes.
+    #[must_use]
+    pub const fn with_docker_rootless(mut self, docker_rootless: bool) -> Self {
+        self.docker_rootless = docker_rootless;
+        self
     }
 
     /// Check all dependencies and return results
@@ -115,11 +139,8 @@ impl DependencyManager {
             .iter()
             .map(|&dependency| {
                 let detector = self.get_detector(dependency);
-                let installed = detector.is_installed()?;
-                Ok(CheckResult {
-                    dependency,
-                    installed,
-                })
+                let status = detector.status()?;
+                Ok(CheckResult { dependency, status })
             })
             .collect()
     }
@@ -135,6 +156,7 @@ impl DependencyManager {
             Dependency::OpenTofu => Box::new(OpenTofuDetector),
             Dependency::Ansible => Box::new(AnsibleDetector),
             Dependency::Lxd => Box::new(LxdDetector),
+            Dependency::Docker => Box::new(DockerDetector),
         }
     }
 
@@ -149,6 +171,8 @@ impl DependencyManager {
             Dependency::OpenTofu => Box::new(OpenTofuInstaller),
             Dependency::Ansible => Box::new(AnsibleInstaller),
             Dependency::Lxd => Box::new(LxdInstaller),
+            Dependency::Docker if self.docker_rootless => Box::new(DockerRootlessInstaller),
+            Dependency::Docker => Box::new(DockerInstaller),
         }
     }
 
@@ -162,16 +186,59 @@ impl DependencyManager {
         installer.install().await
     }
 
+    /// Upgrade a specific dependency to satisfy the minimum version manifest
+    ///
+    /// Missing dependencies are installed, outdated ones are upgraded, and
+    /// dependencies that already satisfy the manifest are left untouched.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if version detection or the installation process fails
+    pub async fn upgrade(&self, dep: Dependency) -> Result<(), InstallationError> {
+        let status = self.get_detector(dep).status().map_err(|source| {
+            InstallationError::DetectionFailed {
+                dependency: dep,
+                source,
+            }
+        })?;
+
+        let installer = self.get_installer(dep);
+
+        match status {
+            DependencyStatus::NotInstalled => installer.install().await,
+            DependencyStatus::Outdated { .. } => installer.upgrade().await,
+            DependencyStatus::Installed { .. } => Ok(()),
+        }
+    }
+
     /// Install all dependencies and return results
     ///
     /// This method attempts to install all dependencies, collecting results
     /// for each one. Even if some installations fail, all dependencies will
     /// be attempted.
     pub async fn install_all(&self) -> Vec<InstallResult> {
+        self.install_all_with(false).await
+    }
+
+    /// Install or upgrade all dependencies and return results
+    ///
+    /// Like [`install_all`](Self::install_all), but outdated dependencies are
+    /// upgraded and up-to-date ones are skipped (see [`upgrade`](Self::upgrade)).
+    pub async fn upgrade_all(&self) -> Vec<InstallResult> {
+        self.install_all_with(true).await
+    }
+
+    async fn install_all_with(&self, upgrade: bool) -> Vec<InstallResult> {
         let mut results = Vec::new();
 
         for &dependency in Dependency::all() {
-            let result = match self.install(dependency).await {
+            let outcome = if upgrade {
+                self.upgrade(dependency).await
+            } else {
+                self.install(dependency).await
+            };
+
+            let result = match outcome {
                 Ok(()) => InstallResult {
                     dependency,
                     success: true,
@@ -0,0 +1,28 @@
+//! Minimum version manifest
+//!
+//! Central declaration of the minimum tool versions the deployer supports.
+//! Detectors compare the installed version against this manifest and report
+//! outdated tools instead of letting them fail later (e.g. `tofu apply`
+//! rejecting syntax only supported by newer `OpenTofu` releases).
+
+// Internal crate
+use crate::version::Version;
+use crate::Dependency;
+
+// ============================================================================
+// PUBLIC API - Functions
+// ============================================================================
+
+/// Get the minimum supported version for a dependency
+///
+/// Returns `None` for dependencies without a version constraint.
+#[must_use]
+pub const fn minimum_version(dependency: Dependency) -> Option<Version> {
+    match dependency {
+        Dependency::CargoMachete => None,
+        Dependency::OpenTofu => Some(Version::new(1, 6, 0)),
+        Dependency::Ansible => Some(Version::new(2, 15, 0)),
+        Dependency::Lxd => Some(Version::new(5, 0, 0)),
+        Dependency::Docker => Some(Version::new(24, 0, 0)),
+    }
+}
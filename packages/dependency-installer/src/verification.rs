@@ -5,9 +5,9 @@
 //! It checks dependencies and provides clear error messages with installation guidance.
 
 use thiserror::Error;
-use tracing::{error, info};
+use tracing::{error, info, warn};
 
-use crate::{Dependency, DependencyManager, DetectionError};
+use crate::{Dependency, DependencyManager, DependencyStatus, DetectionError, Version};
 
 // ============================================================================
 // PUBLIC API - Main Functions
@@ -19,10 +19,15 @@ use crate::{Dependency, DependencyManager, DetectionError};
 /// clear errors if any are missing. It does NOT attempt automatic installation,
 /// allowing the user to control when and how dependencies are installed.
 ///
+/// Dependencies older than the minimum version declared in the
+/// [`manifest`](crate::manifest) are also reported, unless `allow_outdated`
+/// is set, in which case they are only logged as warnings.
+///
 /// # Errors
 ///
 /// Returns an error if:
 /// - One or more dependencies are not installed
+/// - One or more dependencies are outdated and `allow_outdated` is not set
 /// - Detection system fails to check a dependency
 ///
 /// # Example
@@ -32,31 +37,51 @@ use crate::{Dependency, DependencyManager, DetectionError};
 ///
 /// // Verify all dependencies for a full workflow
 /// let deps = &[Dependency::OpenTofu, Dependency::Ansible, Dependency::Lxd];
-/// verify_dependencies(deps)?;
+/// verify_dependencies(deps, false)?;
 ///
-/// // Verify only specific dependencies
+/// // Verify only specific dependencies, accepting outdated versions
 /// let deps = &[Dependency::Ansible];
-/// verify_dependencies(deps)?;
+/// verify_dependencies(deps, true)?;
 /// # Ok::<(), Box<dyn std::error::Error>>(())
 /// ```
-pub fn verify_dependencies(dependencies: &[Dependency]) -> Result<(), DependencyVerificationError> {
+pub fn verify_dependencies(
+    dependencies: &[Dependency],
+    allow_outdated: bool,
+) -> Result<(), DependencyVerificationError> {
     let manager = DependencyManager::new();
     let mut missing = Vec::new();
+    let mut outdated = Vec::new();
 
     info!("Verifying dependencies");
 
     for &dep in dependencies {
         let detector = manager.get_detector(dep);
 
-        match detector.is_installed() {
-            Ok(true) => {
+        match detector.status() {
+            Ok(DependencyStatus::Installed { .. }) => {
                 info!(
                     dependency = detector.name(),
                     status = "installed",
                     "Dependency check passed"
                 );
             }
-            Ok(false) => {
+            Ok(DependencyStatus::Outdated { found, required }) => {
+                warn!(
+                    dependency = detector.name(),
+                    status = "outdated",
+                    found = %found,
+                    required = %required,
+                    "Dependency is older than the minimum supported version"
+                );
+                if !allow_outdated {
+                    outdated.push(OutdatedDependency {
+                        dependency: dep,
+                        found,
+                        required,
+                    });
+                }
+            }
+            Ok(DependencyStatus::NotInstalled) => {
                 error!(
                     dependency = detector.name(),
                     status = "not installed",
@@ -78,16 +103,35 @@ pub fn verify_dependencies(dependencies: &[Dependency]) -> Result<(), Dependency
         }
     }
 
-    if missing.is_empty() {
-        info!("All required dependencies are available");
-        Ok(())
-    } else {
+    if !missing.is_empty() {
         Err(DependencyVerificationError::MissingDependencies {
             dependencies: missing,
         })
+    } else if !outdated.is_empty() {
+        Err(DependencyVerificationError::OutdatedDependencies {
+            dependencies: outdated,
+        })
+    } else {
+        info!("All required dependencies are available");
+        Ok(())
     }
 }
 
+// ============================================================================
+// PUBLIC API - Main Types
+// ============================================================================
+
+/// A dependency whose installed version is below the required minimum
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct OutdatedDependency {
+    /// The outdated dependency
+    pub dependency: Dependency,
+    /// The installed version
+    pub found: Version,
+    /// The minimum supported version
+    pub required: Version,
+}
+
 // ============================================================================
 // ERROR TYPES - Secondary Concerns
 // ============================================================================
@@ -102,6 +146,13 @@ pub enum DependencyVerificationError {
         dependencies: Vec<Dependency>,
     },
 
+    /// One or more required dependencies are older than the minimum version
+    #[error("Outdated dependencies: {}", format_outdated_list(.dependencies))]
+    OutdatedDependencies {
+        /// List of outdated dependencies with found and required versions
+        dependencies: Vec<OutdatedDependency>,
+    },
+
     /// Failed to detect if a dependency is installed
     #[error("Failed to detect dependency '{dependency}': {source}")]
     DetectionFailed {
@@ -130,6 +181,18 @@ impl DependencyVerificationError {
                     https://github.com/torrust/torrust-tracker-deployer/blob/main/packages/dependency-installer/README.md"
                 )
             }
+            Self::OutdatedDependencies { dependencies } => {
+                let dep_list = format_outdated_list(dependencies);
+                format!(
+                    "Outdated dependencies: {dep_list}\n\n\
+                    To upgrade outdated dependencies automatically, run:\n  \
+                    cargo run --bin dependency-installer install --upgrade\n\n\
+                    Or upgrade specific dependencies:\n  \
+                    cargo run --bin dependency-installer install --upgrade --dependency <dependency>\n\n\
+                    To proceed anyway with the installed versions, pass:\n  \
+                    --allow-outdated"
+                )
+            }
             Self::DetectionFailed { dependency, source } => {
                 format!(
                     "Failed to detect dependency '{dependency}': {source}\n\n\
@@ -153,6 +216,19 @@ fn format_dependency_list(dependencies: &[Dependency]) -> String {
         .join(", ")
 }
 
+fn format_outdated_list(dependencies: &[OutdatedDependency]) -> String {
+    dependencies
+        .iter()
+        .map(|d| {
+            format!(
+                "{} (found {}, required {})",
+                d.dependency, d.found, d.required
+            )
+        })
+        .collect::<Vec<_>>()
+        .join(", ")
+}
+
 // NOTE: No unit tests here - verification logic is tested via Docker-based
 // integration tests in packages/dependency-installer/tests/ which provide
 // reliable, controlled environments. Unit tests would be environment-dependent
@@ -0,0 +1,79 @@
+//! Tool version parsing and comparison
+//!
+//! This module provides a minimal semantic version type used to compare the
+//! versions reported by installed tools against the minimum versions declared
+//! in the [`manifest`](crate::manifest).
+
+// Standard library
+use std::fmt;
+
+// ============================================================================
+// PUBLIC API - Main Types
+// ============================================================================
+
+/// A `major.minor.patch` version number reported by an installed tool
+///
+/// Pre-release and build metadata suffixes (e.g. `-alpha1`, `+dfsg1`) are
+/// ignored because tools report them inconsistently and they are not relevant
+/// for minimum version checks.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Version {
+    pub major: u64,
+    pub minor: u64,
+    pub patch: u64,
+}
+
+// ============================================================================
+// PUBLIC API - Implementations
+// ============================================================================
+
+impl Version {
+    /// Create a new version from its components
+    #[must_use]
+    pub const fn new(major: u64, minor: u64, patch: u64) -> Self {
+        Self {
+            major,
+            minor,
+            patch,
+        }
+    }
+
+    /// Find the first dotted version number (`X.Y` or `X.Y.Z`) in a text
+    ///
+    /// This is the building block for the per-tool `--version` output parsers.
+    /// Leading `v` prefixes and trailing suffixes are skipped.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use torrust_tracker_deployer_dependency_installer::version::Version;
+    ///
+    /// assert_eq!(Version::find_in("OpenTofu v1.8.5"), Some(Version::new(1, 8, 5)));
+    /// assert_eq!(Version::find_in("5.21 LTS"), Some(Version::new(5, 21, 0)));
+    /// assert_eq!(Version::find_in("no version here"), None);
+    /// ```
+    #[must_use]
+    pub fn find_in(text: &str) -> Option<Self> {
+        text.split(|c: char| !(c.is_ascii_digit() || c == '.'))
+            .find_map(Self::parse_dotted)
+    }
+
+    fn parse_dotted(candidate: &str) -> Option<Self> {
+        let mut parts = candidate.trim_matches('.').split('.');
+
+        let major = parts.next()?.parse().ok()?;
+        let minor = parts.next()?.parse().ok()?;
+        let patch = match parts.next() {
+            Some(patch) => patch.parse().ok()?,
+            None => 0,
+        };
+
+        Some(Self::new(major, minor, patch))
+    }
+}
+
+impl fmt::Display for Version {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}.{}.{}", self.major, self.minor, self.patch)
+    }
+}
@@ -76,6 +76,10 @@ async fn it_should_list_all_dependencies_with_their_installation_status() {
         output.contains("LXD"),
         "Expected LXD to be listed, got: {output}"
     );
+    assert!(
+        output.contains("Docker"),
+        "Expected Docker to be listed, got: {output}"
+    );
 
     // Verify status is shown
     assert!(
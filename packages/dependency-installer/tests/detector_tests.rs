@@ -6,9 +6,13 @@
 //! - Error handling
 
 use torrust_tracker_deployer_dependency_installer::detector::{
-    AnsibleDetector, CargoMacheteDetector, DependencyDetector, LxdDetector, OpenTofuDetector,
+    AnsibleDetector, CargoMacheteDetector, DependencyDetector, DockerDetector, LxdDetector,
+    OpenTofuDetector,
+};
+use torrust_tracker_deployer_dependency_installer::manifest::minimum_version;
+use torrust_tracker_deployer_dependency_installer::{
+    CheckResult, Dependency, DependencyManager, DependencyStatus, Version,
 };
-use torrust_tracker_deployer_dependency_installer::{CheckResult, Dependency, DependencyManager};
 
 // =============================================================================
 // DETECTOR TRAIT TESTS
@@ -38,6 +42,12 @@ fn it_should_return_lxd_detector_name() {
     assert_eq!(detector.name(), "LXD");
 }
 
+#[test]
+fn it_should_return_docker_detector_name() {
+    let detector = DockerDetector;
+    assert_eq!(detector.name(), "Docker");
+}
+
 // =============================================================================
 // DETECTOR INSTALLATION CHECK TESTS
 // =============================================================================
@@ -78,21 +88,58 @@ fn it_should_run_lxd_detector_without_error() {
     assert!(result.is_ok(), "Detection should not error");
 }
 
+#[test]
+fn it_should_run_docker_detector_without_error() {
+    let detector = DockerDetector;
+    // Should not panic - result depends on system state
+    let result = detector.is_installed();
+    assert!(result.is_ok(), "Detection should not error");
+}
+
 // =============================================================================
 // DETECTOR REQUIRED VERSION TESTS
 // =============================================================================
 
 #[test]
-fn it_should_return_no_required_version_by_default_for_all_detectors() {
+fn it_should_return_no_required_version_for_cargo_machete() {
     let cargo_machete = CargoMacheteDetector;
-    let opentofu = OpenTofuDetector;
-    let ansible = AnsibleDetector;
-    let lxd = LxdDetector;
 
     assert_eq!(cargo_machete.required_version(), None);
-    assert_eq!(opentofu.required_version(), None);
-    assert_eq!(ansible.required_version(), None);
-    assert_eq!(lxd.required_version(), None);
+}
+
+#[test]
+fn it_should_return_the_manifest_required_version_for_versioned_detectors() {
+    assert_eq!(
+        OpenTofuDetector.required_version(),
+        minimum_version(Dependency::OpenTofu)
+    );
+    assert_eq!(
+        AnsibleDetector.required_version(),
+        minimum_version(Dependency::Ansible)
+    );
+    assert_eq!(
+        LxdDetector.required_version(),
+        minimum_version(Dependency::Lxd)
+    );
+    assert_eq!(
+        DockerDetector.required_version(),
+        minimum_version(Dependency::Docker)
+    );
+}
+
+#[test]
+fn it_should_declare_a_minimum_version_for_every_tool_except_cargo_machete() {
+    for &dependency in Dependency::all() {
+        let required = minimum_version(dependency);
+        if dependency == Dependency::CargoMachete {
+            assert!(required.is_none());
+        } else {
+            assert!(
+                required.is_some(),
+                "{dependency} should have a minimum version"
+            );
+        }
+    }
 }
 
 // =============================================================================
@@ -119,9 +166,9 @@ fn it_should_check_all_dependencies_without_error() {
     // Should not panic - result depends on system state
     assert!(results.is_ok(), "check_all should not error");
 
-    // Verify we get results for all 4 dependencies
+    // Verify we get results for all 5 dependencies
     let check_results = results.unwrap();
-    assert_eq!(check_results.len(), 4, "Should check 4 dependencies");
+    assert_eq!(check_results.len(), 5, "Should check 5 dependencies");
 
     // Verify all expected dependencies are in results
     let dependencies: Vec<Dependency> = check_results.iter().map(|r| r.dependency).collect();
@@ -129,6 +176,7 @@ fn it_should_check_all_dependencies_without_error() {
     assert!(dependencies.contains(&Dependency::OpenTofu));
     assert!(dependencies.contains(&Dependency::Ansible));
     assert!(dependencies.contains(&Dependency::Lxd));
+    assert!(dependencies.contains(&Dependency::Docker));
 }
 
 #[test]
@@ -159,6 +207,28 @@ fn it_should_get_lxd_detector_from_manager() {
     assert_eq!(detector.name(), "LXD");
 }
 
+#[test]
+fn it_should_get_docker_detector_from_manager() {
+    let manager = DependencyManager::new();
+    let detector = manager.get_detector(Dependency::Docker);
+    assert_eq!(detector.name(), "Docker");
+}
+
+#[test]
+fn it_should_install_docker_with_sudo_by_default() {
+    let manager = DependencyManager::new();
+    assert!(manager.get_installer(Dependency::Docker).requires_sudo());
+}
+
+#[test]
+fn it_should_install_docker_without_sudo_in_rootless_mode() {
+    let manager = DependencyManager::new().with_docker_rootless(true);
+    let installer = manager.get_installer(Dependency::Docker);
+
+    assert_eq!(installer.name(), "Docker");
+    assert!(!installer.requires_sudo());
+}
+
 // =============================================================================
 // CHECK RESULT TESTS
 // =============================================================================
@@ -168,11 +238,11 @@ fn it_should_create_check_result() {
     use torrust_tracker_deployer_dependency_installer::Dependency;
     let result = CheckResult {
         dependency: Dependency::CargoMachete,
-        installed: true,
+        status: DependencyStatus::Installed { version: None },
     };
 
     assert_eq!(result.dependency, Dependency::CargoMachete);
-    assert!(result.installed);
+    assert!(result.status.is_installed());
 }
 
 #[test]
@@ -180,12 +250,40 @@ fn it_should_clone_check_result() {
     use torrust_tracker_deployer_dependency_installer::Dependency;
     let result = CheckResult {
         dependency: Dependency::OpenTofu,
-        installed: false,
+        status: DependencyStatus::NotInstalled,
     };
 
     let cloned = result.clone();
     assert_eq!(cloned.dependency, Dependency::OpenTofu);
-    assert!(!cloned.installed);
+    assert!(!cloned.status.is_installed());
+}
+
+// =============================================================================
+// DEPENDENCY STATUS TESTS
+// =============================================================================
+
+#[test]
+fn it_should_consider_an_outdated_dependency_installed_but_not_satisfied() {
+    let status = DependencyStatus::Outdated {
+        found: Version::new(1, 4, 0),
+        required: Version::new(1, 6, 0),
+    };
+
+    assert!(status.is_installed());
+    assert!(status.is_outdated());
+    assert!(!status.is_satisfied());
+    assert_eq!(status.to_string(), "outdated");
+}
+
+#[test]
+fn it_should_consider_an_installed_dependency_satisfied() {
+    let status = DependencyStatus::Installed {
+        version: Some(Version::new(1, 8, 5)),
+    };
+
+    assert!(status.is_installed());
+    assert!(!status.is_outdated());
+    assert!(status.is_satisfied());
 }
 
 // =============================================================================
@@ -232,3 +330,51 @@ fn it_should_fail_to_execute_nonexistent_command() {
     let result = execute_command("this-command-definitely-does-not-exist-12345", &["test"]);
     assert!(result.is_err());
 }
+
+// =============================================================================
+// DOCKER ROOTLESS MODE TESTS
+// =============================================================================
+
+#[test]
+fn it_should_place_the_rootless_docker_socket_in_the_runtime_directory() {
+    use std::path::{Path, PathBuf};
+    use torrust_tracker_deployer_dependency_installer::detector::docker::rootless_socket_path;
+
+    assert_eq!(
+        rootless_socket_path(Some(Path::new("/run/user/1000"))),
+        Some(PathBuf::from("/run/user/1000/docker.sock"))
+    );
+    assert_eq!(rootless_socket_path(Some(Path::new(""))), None);
+    assert_eq!(rootless_socket_path(None), None);
+}
+
+#[test]
+fn it_should_recognize_rootless_docker_endpoints() {
+    use std::path::Path;
+    use torrust_tracker_deployer_dependency_installer::detector::docker::is_rootless_endpoint;
+
+    let runtime_dir = Some(Path::new("/tmp/runtime-ci"));
+
+    assert!(is_rootless_endpoint(
+        "unix:///tmp/runtime-ci/docker.sock",
+        runtime_dir
+    ));
+    assert!(is_rootless_endpoint(
+        "unix:///run/user/1000/docker.sock",
+        None
+    ));
+}
+
+#[test]
+fn it_should_not_consider_the_system_docker_socket_rootless() {
+    use std::path::Path;
+    use torrust_tracker_deployer_dependency_installer::detector::docker::is_rootless_endpoint;
+
+    let runtime_dir = Some(Path::new("/run/user/1000"));
+
+    assert!(!is_rootless_endpoint(
+        "unix:///var/run/docker.sock",
+        runtime_dir
+    ));
+    assert!(!is_rootless_endpoint("tcp://127.0.0.1:2375", runtime_dir));
+}
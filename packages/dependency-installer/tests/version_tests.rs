@@ -0,0 +1,134 @@
+//! Unit tests for tool version parsing
+//!
+//! Tests for the `Version` type and the per-tool `--version` output parsers,
+//! using the real output formats reported by each tool.
+
+use torrust_tracker_deployer_dependency_installer::detector::{ansible, docker, lxd, opentofu};
+use torrust_tracker_deployer_dependency_installer::Version;
+
+// =============================================================================
+// VERSION TYPE TESTS
+// =============================================================================
+
+#[test]
+fn it_should_find_a_full_version_in_text() {
+    assert_eq!(Version::find_in("v1.8.5"), Some(Version::new(1, 8, 5)));
+}
+
+#[test]
+fn it_should_default_the_patch_number_to_zero_when_missing() {
+    assert_eq!(Version::find_in("5.21"), Some(Version::new(5, 21, 0)));
+}
+
+#[test]
+fn it_should_ignore_pre_release_and_build_suffixes() {
+    assert_eq!(
+        Version::find_in("1.9.0-alpha1"),
+        Some(Version::new(1, 9, 0))
+    );
+    assert_eq!(
+        Version::find_in("20.10.24+dfsg1"),
+        Some(Version::new(20, 10, 24))
+    );
+}
+
+#[test]
+fn it_should_return_none_when_text_contains_no_version() {
+    assert_eq!(Version::find_in("command not found"), None);
+    assert_eq!(Version::find_in("linux_amd64"), None);
+}
+
+#[test]
+fn it_should_order_versions_numerically() {
+    assert!(Version::new(1, 10, 0) > Version::new(1, 9, 9));
+    assert!(Version::new(2, 0, 0) > Version::new(1, 99, 99));
+    assert!(Version::new(1, 6, 1) > Version::new(1, 6, 0));
+}
+
+#[test]
+fn it_should_display_version_as_dotted_triple() {
+    assert_eq!(Version::new(1, 6, 0).to_string(), "1.6.0");
+}
+
+// =============================================================================
+// OPENTOFU VERSION PARSING TESTS
+// =============================================================================
+
+#[test]
+fn it_should_parse_opentofu_version_output() {
+    let output = "OpenTofu v1.8.5\non linux_amd64";
+
+    assert_eq!(opentofu::parse_version(output), Some(Version::new(1, 8, 5)));
+}
+
+#[test]
+fn it_should_parse_opentofu_version_output_with_providers() {
+    let output = "OpenTofu v1.6.2\non linux_amd64\n+ provider registry.opentofu.org/terraform-lxd/lxd v2.0.0";
+
+    assert_eq!(opentofu::parse_version(output), Some(Version::new(1, 6, 2)));
+}
+
+// =============================================================================
+// ANSIBLE VERSION PARSING TESTS
+// =============================================================================
+
+#[test]
+fn it_should_parse_ansible_core_version_output() {
+    let output = "ansible [core 2.16.3]\n  \
+        config file = None\n  \
+        configured module search path = ['/home/user/.ansible/plugins/modules']\n  \
+        ansible python module location = /usr/lib/python3/dist-packages/ansible\n  \
+        executable location = /usr/bin/ansible\n  \
+        python version = 3.12.3 (main, Nov  6 2024, 18:32:19) [GCC 13.2.0] (/usr/bin/python3)\n  \
+        jinja version = 3.1.2\n  \
+        libyaml = True";
+
+    assert_eq!(ansible::parse_version(output), Some(Version::new(2, 16, 3)));
+}
+
+#[test]
+fn it_should_parse_legacy_ansible_version_output() {
+    let output = "ansible 2.9.6\n  \
+        config file = /etc/ansible/ansible.cfg\n  \
+        python version = 3.8.10 (default, Nov 22 2023, 10:22:35) [GCC 9.4.0]";
+
+    assert_eq!(ansible::parse_version(output), Some(Version::new(2, 9, 6)));
+}
+
+// =============================================================================
+// LXD VERSION PARSING TESTS
+// =============================================================================
+
+#[test]
+fn it_should_parse_lxd_lts_version_output() {
+    assert_eq!(
+        lxd::parse_version("5.21.3 LTS"),
+        Some(Version::new(5, 21, 3))
+    );
+}
+
+#[test]
+fn it_should_parse_lxd_bare_version_output() {
+    assert_eq!(lxd::parse_version("5.0.2"), Some(Version::new(5, 0, 2)));
+}
+
+// =============================================================================
+// DOCKER VERSION PARSING TESTS
+// =============================================================================
+
+#[test]
+fn it_should_parse_docker_version_output() {
+    let output = "Docker version 27.3.1, build ce12230";
+
+    assert_eq!(docker::parse_version(output), Some(Version::new(27, 3, 1)));
+}
+
+#[test]
+fn it_should_parse_distro_packaged_docker_version_output() {
+    let output = "Docker version 20.10.24+dfsg1, build 297e128";
+
+    assert_eq!(
+        docker::parse_version(output),
+        Some(Version::new(20, 10, 24))
+    );
+}
@@ -12,6 +12,11 @@
 //! - Be non-empty strings
 //! - Not start or end with separators
 //! - Not start with numbers (for consistency with `InstanceName`)
+//! - Not be one of the [`EnvironmentName::RESERVED_NAMES`]
+//!
+//! Names derived from the environment name (such as instance names) are
+//! limited by the provider, so callers that know the provider also check the
+//! length with [`EnvironmentName::check_max_length`].
 //!
 //! ## Valid Examples
 //!
@@ -56,6 +61,9 @@ use thiserror::Error;
 pub struct EnvironmentName(String);
 
 impl EnvironmentName {
+    /// Names that would collide with directories used by the deployer itself
+    pub const RESERVED_NAMES: &'static [&'static str] = &["templates", "traces"];
+
     /// Creates a new `EnvironmentName` from a string with validation.
     ///
     /// # Arguments
@@ -71,6 +79,7 @@ impl EnvironmentName {
     /// 5. **No leading/trailing separators**: Cannot start or end with dashes
     /// 6. **No consecutive separators**: Cannot contain multiple consecutive dashes
     /// 7. **No leading numbers**: Cannot start with a digit
+    /// 8. **Not reserved**: Cannot be one of [`Self::RESERVED_NAMES`]
     ///
     /// # Examples
     ///
@@ -86,6 +95,7 @@ impl EnvironmentName {
     /// assert!(EnvironmentName::new("Dev").is_err());           // Uppercase
     /// assert!(EnvironmentName::new("dev_test").is_err());      // Underscore
     /// assert!(EnvironmentName::new("123prod").is_err());       // Starts with number
+    /// assert!(EnvironmentName::new("traces").is_err());        // Reserved
     /// ```
     ///
     /// # Errors
@@ -115,6 +125,53 @@ impl EnvironmentName {
         &self.0
     }
 
+    /// Checks that the name fits the length allowed by a provider
+    ///
+    /// The allowed length depends on the provider, since the names derived
+    /// from the environment name must follow the provider's naming rules.
+    ///
+    /// # Arguments
+    ///
+    /// * `max_length` - Maximum number of characters allowed
+    /// * `provider` - Provider imposing the limit, for the error message
+    /// * `reason` - How the limit is derived, for the error message
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use torrust_tracker_deployer_types::EnvironmentName;
+    /// let env_name = EnvironmentName::new("staging")?;
+    ///
+    /// assert!(env_name.check_max_length(7, "lxd", "instance name limit").is_ok());
+    /// assert!(env_name.check_max_length(6, "lxd", "instance name limit").is_err());
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Returns `EnvironmentNameError::TooLong` if the name has more than
+    /// `max_length` characters.
+    pub fn check_max_length(
+        &self,
+        max_length: usize,
+        provider: &str,
+        reason: &str,
+    ) -> Result<(), EnvironmentNameError> {
+        let length = self.0.len();
+
+        if length > max_length {
+            return Err(EnvironmentNameError::TooLong {
+                attempted_name: self.0.clone(),
+                length,
+                max_length,
+                provider: provider.to_string(),
+                reason: reason.to_string(),
+            });
+        }
+
+        Ok(())
+    }
+
     /// Validates an environment name according to the format rules
     ///
     /// This is used internally by `new()` but is also public for testing purposes.
@@ -211,6 +268,13 @@ impl EnvironmentName {
             });
         }
 
+        // Check for names used by the deployer's own directories
+        if Self::RESERVED_NAMES.contains(&name) {
+            return Err(EnvironmentNameError::Reserved {
+                attempted_name: name.to_string(),
+            });
+        }
+
         Ok(())
     }
 
@@ -263,6 +327,29 @@ pub enum EnvironmentNameError {
         /// List of valid example names
         valid_examples: Vec<String>,
     },
+
+    /// Environment name collides with a directory used by the deployer
+    #[error("Environment name '{attempted_name}' is reserved for a directory used by the deployer.\n\nReserved names: {}",
+        EnvironmentName::RESERVED_NAMES.join(", "))]
+    Reserved {
+        /// The name that was attempted to be created
+        attempted_name: String,
+    },
+
+    /// Environment name is too long for the names the provider derives from it
+    #[error("Environment name '{attempted_name}' is {length} characters long, but the {provider} provider allows at most {max_length}: {reason}.\n\nUse a shorter environment name")]
+    TooLong {
+        /// The name that was checked
+        attempted_name: String,
+        /// Number of characters of the name
+        length: usize,
+        /// Maximum number of characters allowed by the provider
+        max_length: usize,
+        /// Provider imposing the limit
+        provider: String,
+        /// How the limit is derived from the provider's naming rules
+        reason: String,
+    },
 }
 
 #[cfg(test)]
@@ -303,7 +390,7 @@ mod tests {
             EnvironmentNameError::Empty => {
                 // Expected error type
             }
-            other @ EnvironmentNameError::InvalidFormat { .. } => {
+            other => {
                 panic!("Expected Empty error, got: {other:?}")
             }
         }
@@ -326,7 +413,7 @@ mod tests {
                     assert_eq!(attempted_name, name);
                     assert!(reason.contains("uppercase"));
                 }
-                other @ EnvironmentNameError::Empty => {
+                other => {
                     panic!("Expected InvalidFormat error, got: {other:?}")
                 }
             }
@@ -357,7 +444,7 @@ mod tests {
                     assert_eq!(attempted_name, name);
                     assert!(reason.contains("invalid characters"));
                 }
-                other @ EnvironmentNameError::Empty => {
+                other => {
                     panic!("Expected InvalidFormat error, got: {other:?}")
                 }
             }
@@ -381,7 +468,7 @@ mod tests {
                     assert_eq!(attempted_name, name);
                     assert!(reason.contains("starts with dash"));
                 }
-                other @ EnvironmentNameError::Empty => {
+                other => {
                     panic!("Expected InvalidFormat error, got: {other:?}")
                 }
             }
@@ -405,7 +492,7 @@ mod tests {
                     assert_eq!(attempted_name, name);
                     assert!(reason.contains("ends with dash"));
                 }
-                other @ EnvironmentNameError::Empty => {
+                other => {
                     panic!("Expected InvalidFormat error, got: {other:?}")
                 }
             }
@@ -429,7 +516,7 @@ mod tests {
                     assert_eq!(attempted_name, name);
                     assert!(reason.contains("consecutive dashes"));
                 }
-                other @ EnvironmentNameError::Empty => {
+                other => {
                     panic!("Expected InvalidFormat error, got: {other:?}")
                 }
             }
@@ -475,6 +562,67 @@ mod tests {
         assert!(error_message.contains("dev")); // Should contain examples
     }
 
+    #[test]
+    fn it_should_reject_reserved_names() {
+        for name in EnvironmentName::RESERVED_NAMES {
+            let result = EnvironmentName::new(*name);
+
+            assert!(
+                matches!(
+                    result,
+                    Err(EnvironmentNameError::Reserved { ref attempted_name }) if attempted_name == name
+                ),
+                "Expected '{name}' to be reserved, got: {result:?}"
+            );
+        }
+    }
+
+    #[test]
+    fn it_should_accept_names_containing_a_reserved_name() {
+        assert!(EnvironmentName::new("traces-dev").is_ok());
+        assert!(EnvironmentName::new("my-templates").is_ok());
+    }
+
+    #[test]
+    fn it_should_accept_a_name_of_exactly_the_max_length() {
+        let env_name = EnvironmentName::new("a".repeat(44)).unwrap();
+
+        assert!(env_name.check_max_length(44, "lxd", "limit").is_ok());
+    }
+
+    #[test]
+    fn it_should_reject_a_name_one_character_over_the_max_length() {
+        let env_name = EnvironmentName::new("a".repeat(45)).unwrap();
+
+        match env_name.check_max_length(44, "lxd", "limit").unwrap_err() {
+            EnvironmentNameError::TooLong {
+                length,
+                max_length,
+                provider,
+                ..
+            } => {
+                assert_eq!(length, 45);
+                assert_eq!(max_length, 44);
+                assert_eq!(provider, "lxd");
+            }
+            other => panic!("Expected TooLong error, got: {other:?}"),
+        }
+    }
+
+    #[test]
+    fn it_should_state_the_max_length_in_the_too_long_error() {
+        let env_name = EnvironmentName::new("a".repeat(60)).unwrap();
+
+        let message = env_name
+            .check_max_length(44, "lxd", "instance names are limited to 63 characters")
+            .unwrap_err()
+            .to_string();
+
+        assert!(message.contains("is 60 characters long"));
+        assert!(message.contains("the lxd provider allows at most 44"));
+        assert!(message.contains("instance names are limited to 63 characters"));
+    }
+
     #[test]
     fn it_should_reject_names_starting_with_numbers() {
         let invalid_names = vec!["1dev", "2test", "3env"];
@@ -492,7 +640,7 @@ mod tests {
                     assert_eq!(attempted_name, name);
                     assert!(reason.contains("starts with a number"));
                 }
-                other @ EnvironmentNameError::Empty => {
+                other => {
                     panic!("Expected InvalidFormat error, got: {other:?}")
                 }
             }
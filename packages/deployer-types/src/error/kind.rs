@@ -18,7 +18,8 @@ use serde::{Deserialize, Serialize};
 ///
 /// - Displayed to users without technical details
 /// - Used for filtering/grouping errors
-/// - Foundation for future retry/recovery strategies based on error category
+/// - Used to decide whether a failed command is worth retrying
+///   (see [`ErrorKind::is_retryable`])
 ///
 /// # Examples
 ///
@@ -77,6 +78,60 @@ pub enum ErrorKind {
     /// Examples: Failed to save environment state, repository errors,
     /// serialization/deserialization failures, storage access issues
     StatePersistence,
+
+    /// The command was interrupted before it could finish
+    ///
+    /// Examples: User pressed Ctrl-C during a long-running command,
+    /// external tool terminated by `SIGINT`
+    Interrupted,
+}
+
+impl ErrorKind {
+    /// Whether a command failing with this kind of error may succeed if retried
+    ///
+    /// Transient failures come from the environment the command runs in and
+    /// may clear without any change on the caller side: network timeouts, an
+    /// LXD daemon busy with another operation, an apt lock held by unattended
+    /// upgrades. Permanent failures need a change before retrying: an invalid
+    /// configuration or environment state, a missing file, a broken template.
+    ///
+    /// | Kind                      | Retryable |
+    /// |---------------------------|-----------|
+    /// | `NetworkConnectivity`     | yes       |
+    /// | `Timeout`                 | yes       |
+    /// | `InfrastructureOperation` | yes       |
+    /// | `CommandExecution`        | yes       |
+    /// | `TemplateRendering`       | no        |
+    /// | `FileSystem`              | no        |
+    /// | `Configuration`           | no        |
+    /// | `StatePersistence`        | no        |
+    /// | `Interrupted`             | no        |
+    ///
+    /// The classification is per kind, so a retryable kind may still wrap a
+    /// failure that repeats; callers should bound the number of retries.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use torrust_tracker_deployer_types::ErrorKind;
+    ///
+    /// assert!(ErrorKind::NetworkConnectivity.is_retryable());
+    /// assert!(!ErrorKind::Configuration.is_retryable());
+    /// ```
+    #[must_use]
+    pub const fn is_retryable(self) -> bool {
+        match self {
+            Self::NetworkConnectivity
+            | Self::Timeout
+            | Self::InfrastructureOperation
+            | Self::CommandExecution => true,
+            Self::TemplateRendering
+            | Self::FileSystem
+            | Self::Configuration
+            | Self::StatePersistence
+            | Self::Interrupted => false,
+        }
+    }
 }
 
 #[cfg(test)]
@@ -113,6 +168,23 @@ mod tests {
         assert_ne!(ErrorKind::Timeout, ErrorKind::FileSystem);
     }
 
+    #[test]
+    fn it_should_retry_transient_failures() {
+        assert!(ErrorKind::NetworkConnectivity.is_retryable());
+        assert!(ErrorKind::Timeout.is_retryable());
+        assert!(ErrorKind::InfrastructureOperation.is_retryable());
+        assert!(ErrorKind::CommandExecution.is_retryable());
+    }
+
+    #[test]
+    fn it_should_not_retry_permanent_failures() {
+        assert!(!ErrorKind::TemplateRendering.is_retryable());
+        assert!(!ErrorKind::FileSystem.is_retryable());
+        assert!(!ErrorKind::Configuration.is_retryable());
+        assert!(!ErrorKind::StatePersistence.is_retryable());
+        assert!(!ErrorKind::Interrupted.is_retryable());
+    }
+
     #[test]
     fn it_should_have_descriptive_debug_output() {
         let kind = ErrorKind::InfrastructureOperation;
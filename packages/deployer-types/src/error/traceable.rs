@@ -105,6 +105,25 @@ pub trait Traceable: std::error::Error {
     /// }
     /// ```
     fn error_kind(&self) -> ErrorKind;
+
+    /// Whether the failed operation may succeed if retried
+    ///
+    /// Classifies the [error kind](Self::error_kind) with
+    /// [`ErrorKind::is_retryable`], so callers can decide to retry without
+    /// inspecting error messages.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use torrust_tracker_deployer_types::Traceable;
+    ///
+    /// fn should_retry<E: Traceable>(error: &E, attempt: u32) -> bool {
+    ///     attempt < 3 && error.is_retryable()
+    /// }
+    /// ```
+    fn is_retryable(&self) -> bool {
+        self.error_kind().is_retryable()
+    }
 }
 
 #[cfg(test)]
@@ -187,4 +206,12 @@ mod tests {
         };
         assert!(error.trace_source().is_none());
     }
+
+    #[test]
+    fn it_should_derive_retryability_from_error_kind() {
+        let error = TestError::Root {
+            message: "test".to_string(),
+        };
+        assert!(error.is_retryable());
+    }
 }
@@ -75,7 +75,7 @@ impl ServiceEndpoint {
         path: impl Into<String>,
     ) -> Result<Self, InvalidServiceEndpointUrl> {
         let path = path.into();
-        let url_string = format!("http://{}:{}{}", socket_addr.ip(), socket_addr.port(), path); // DevSkim: ignore DS137138
+        let url_string = format!("http://{socket_addr}{path}"); // DevSkim: ignore DS137138
 
         let url = Url::parse(&url_string).map_err(|e| InvalidServiceEndpointUrl {
             url_string,
@@ -216,6 +216,18 @@ mod tests {
         );
     }
 
+    #[test]
+    fn it_should_bracket_an_ipv6_address_in_http_url() {
+        let socket_addr = SocketAddr::new("2001:db8::10".parse().unwrap(), 1212);
+
+        let endpoint = ServiceEndpoint::http(socket_addr, "/api/health_check").unwrap();
+
+        assert_eq!(
+            endpoint.url().as_str(),
+            "http://[2001:db8::10]:1212/api/health_check" // DevSkim: ignore DS137138
+        );
+    }
+
     #[test]
     fn it_should_build_https_url() {
         let domain = DomainName::new("api.tracker.local").unwrap();
@@ -29,7 +29,11 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
     // 1. Initialize the deployer SDK
     println!("=== Torrust Tracker Deployer SDK — Basic Example ===\n");
 
-    let deployer = Deployer::builder().working_dir(&workspace).build()?;
+    let deployer = Deployer::builder()
+        .working_dir(&workspace)
+        .allow_uninitialized()
+        .build()?;
+    deployer.init_workspace()?;
     println!(
         "[OK] Deployer initialized (workspace: {})\n",
         workspace.display()
@@ -30,7 +30,11 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
 
     println!("=== Torrust Tracker Deployer SDK — Create from JSON File ===\n");
 
-    let deployer = Deployer::builder().working_dir(&workspace).build()?;
+    let deployer = Deployer::builder()
+        .working_dir(&workspace)
+        .allow_uninitialized()
+        .build()?;
+    deployer.init_workspace()?;
     println!(
         "[OK] Deployer initialized (workspace: {})\n",
         workspace.display()
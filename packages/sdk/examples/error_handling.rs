@@ -6,6 +6,8 @@
 //! 1. **Idempotent create** — skip creation if the environment already exists
 //! 2. **Inspect individual error types** — extract context from typed variants
 //! 3. **Unified `SdkError`** — wrapping multiple operations under one error type
+//! 4. **Retry decisions** — `is_retryable()` tells transient failures from
+//!    permanent ones without matching on error messages
 //!
 //! This example only uses operations that work locally (no infrastructure
 //! required). No VMs are created.
@@ -28,7 +30,11 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
 
     println!("=== Torrust Tracker Deployer SDK — Error Handling Example ===\n");
 
-    let deployer = Deployer::builder().working_dir(&workspace).build()?;
+    let deployer = Deployer::builder()
+        .working_dir(&workspace)
+        .allow_uninitialized()
+        .build()?;
+    deployer.init_workspace()?;
 
     let private_key = workspace.join("fixtures/testing_rsa");
     let public_key = workspace.join("fixtures/testing_rsa.pub");
@@ -96,6 +102,19 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
     }
     println!();
 
+    // -----------------------------------------------------------------------
+    // Demo 4: Retry decisions — classify an error without string matching.
+    // -----------------------------------------------------------------------
+    println!("--- Demo 4: Retry decisions ---");
+
+    let missing = EnvironmentName::new("sdk-error-demo-missing")?;
+    if let Err(e) = run_workflow(&deployer, &missing) {
+        println!("  Error kind: {:?}", e.kind());
+        println!("  Retryable: {}", e.is_retryable());
+        println!("  (A missing environment needs a fix, not a retry.)");
+    }
+    println!();
+
     // -----------------------------------------------------------------------
     // Cleanup
     // -----------------------------------------------------------------------
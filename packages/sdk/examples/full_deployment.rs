@@ -45,7 +45,9 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
     let deployer = Deployer::builder()
         .working_dir(&workspace)
         .progress_listener(Arc::new(PrintProgressListener))
+        .allow_uninitialized()
         .build()?;
+    deployer.init_workspace()?;
     let workspace_display = workspace.display();
     println!("[OK] Deployer initialized (workspace: {workspace_display})\n");
 
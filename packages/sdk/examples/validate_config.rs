@@ -26,7 +26,11 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
 
     println!("=== Torrust Tracker Deployer SDK — Validate Config Example ===\n");
 
-    let deployer = Deployer::builder().working_dir(&workspace).build()?;
+    // Validation does not use the workspace: no need to initialize it
+    let deployer = Deployer::builder()
+        .working_dir(&workspace)
+        .allow_uninitialized()
+        .build()?;
 
     let private_key = workspace.join("fixtures/testing_rsa");
     let public_key = workspace.join("fixtures/testing_rsa.pub");
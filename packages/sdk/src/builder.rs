@@ -1,7 +1,14 @@
 //! Builder for constructing a [`Deployer`] with sensible defaults.
 //!
 //! The builder pattern hides dependency wiring (repository, clock, etc.)
-//! so SDK consumers only need to provide the workspace path.
+//! so SDK consumers only need to provide the workspace path. The clock,
+//! the data and build directories, and the progress listener can be
+//! replaced when the defaults do not fit, and custom steps can be added to
+//! the provision and configure workflows.
+//!
+//! The workspace must have been initialized (`torrust-tracker-deployer init`
+//! or [`Deployer::init_workspace`]), otherwise [`DeployerBuilder::build`]
+//! fails instead of letting later operations fail on missing files.
 //!
 //! # Example
 //!
@@ -14,18 +21,24 @@
 //!     .unwrap();
 //! ```
 
-use std::path::{Path, PathBuf};
+use std::path::PathBuf;
 use std::sync::Arc;
 
 use thiserror::Error;
 
 use super::deployer::Deployer;
+use torrust_tracker_deployer_lib::application::command_handlers::init::{
+    read_marker, verify_workspace, WorkspaceError,
+};
+use torrust_tracker_deployer_lib::application::steps::{CustomSteps, HookPoint, Step};
 use torrust_tracker_deployer_lib::application::traits::{
     CommandProgressListener, NullProgressListener,
 };
 use torrust_tracker_deployer_lib::bootstrap::sdk::{
-    default_clock, default_repository_provider, DEFAULT_SDK_LOCK_TIMEOUT,
+    backend_repository_provider, default_clock, DEFAULT_SDK_LOCK_TIMEOUT,
 };
+use torrust_tracker_deployer_lib::domain::environment::WorkspaceLayout;
+use torrust_tracker_deployer_lib::shared::{Clock, ErrorKind, Traceable};
 
 /// Builder for constructing a [`Deployer`] instance.
 ///
@@ -34,6 +47,24 @@ use torrust_tracker_deployer_lib::bootstrap::sdk::{
 /// - [`working_dir`](DeployerBuilder::working_dir) — the workspace root
 ///   where `data/` and `build/` directories live
 ///
+/// # Optional
+///
+/// - [`data_dir`](DeployerBuilder::data_dir) / [`build_dir`](DeployerBuilder::build_dir)
+///   — move the environment state or the build artifacts out of the workspace
+/// - [`clock`](DeployerBuilder::clock) — time source for recorded timestamps
+/// - [`progress_listener`](DeployerBuilder::progress_listener) — progress
+///   events for every long-running operation
+/// - [`templates_override_dir`](DeployerBuilder::templates_override_dir) —
+///   default template overrides for new environments
+/// - [`trace_dir`](DeployerBuilder::trace_dir) — directory of the trace
+///   files of the failed operations
+/// - [`allow_uninitialized`](DeployerBuilder::allow_uninitialized) — skip
+///   the workspace check, e.g. to initialize the workspace
+/// - [`after_provision`](DeployerBuilder::after_provision),
+///   [`before_configure`](DeployerBuilder::before_configure) and
+///   [`after_configure`](DeployerBuilder::after_configure) — custom steps
+///   run by [`Deployer::provision`] and [`Deployer::configure`]
+///
 /// # Example
 ///
 /// ```rust,no_run
@@ -46,7 +77,14 @@ use torrust_tracker_deployer_lib::bootstrap::sdk::{
 /// ```
 pub struct DeployerBuilder {
     working_dir: Option<PathBuf>,
+    data_dir: Option<PathBuf>,
+    build_dir: Option<PathBuf>,
+    clock: Option<Arc<dyn Clock>>,
     progress_listener: Option<Arc<dyn CommandProgressListener + Send + Sync>>,
+    templates_override_dir: Option<PathBuf>,
+    trace_dir: Option<PathBuf>,
+    allow_uninitialized: bool,
+    custom_steps: CustomSteps,
 }
 
 impl DeployerBuilder {
@@ -55,7 +93,14 @@ impl DeployerBuilder {
     pub fn new() -> Self {
         Self {
             working_dir: None,
+            data_dir: None,
+            build_dir: None,
+            clock: None,
             progress_listener: None,
+            templates_override_dir: None,
+            trace_dir: None,
+            allow_uninitialized: false,
+            custom_steps: CustomSteps::new(),
         }
     }
 
@@ -69,11 +114,81 @@ impl DeployerBuilder {
         self
     }
 
+    /// Override the directory holding the environment state.
+    ///
+    /// Each environment is stored in `{data_dir}/{env-name}/`. Defaults to
+    /// `{working_dir}/data`.
+    ///
+    /// # Example
+    ///
+    /// ```rust,no_run
+    /// use torrust_tracker_deployer_sdk::Deployer;
+    ///
+    /// let deployer = Deployer::builder()
+    ///     .working_dir("/path/to/workspace")
+    ///     .data_dir("/var/lib/deployer")
+    ///     .build()
+    ///     .unwrap();
+    /// ```
+    #[must_use]
+    pub fn data_dir(mut self, path: impl Into<PathBuf>) -> Self {
+        self.data_dir = Some(path.into());
+        self
+    }
+
+    /// Override the directory holding the generated build artifacts.
+    ///
+    /// Each environment renders into `{build_dir}/{env-name}/`. Defaults to
+    /// `{working_dir}/build`. Only environments created by this deployer
+    /// use it: existing environments keep the build directory stored in
+    /// their state.
+    ///
+    /// # Example
+    ///
+    /// ```rust,no_run
+    /// use torrust_tracker_deployer_sdk::Deployer;
+    ///
+    /// let deployer = Deployer::builder()
+    ///     .working_dir("/path/to/workspace")
+    ///     .build_dir("/tmp/deployer-build")
+    ///     .build()
+    ///     .unwrap();
+    /// ```
+    #[must_use]
+    pub fn build_dir(mut self, path: impl Into<PathBuf>) -> Self {
+        self.build_dir = Some(path.into());
+        self
+    }
+
+    /// Set the clock used for every timestamp the deployer records.
+    ///
+    /// Creation times, state transitions and failure reports all read this
+    /// clock. Defaults to the system clock. Inject a fixed clock to make
+    /// persisted state reproducible in tests.
+    ///
+    /// # Example
+    ///
+    /// ```rust,no_run
+    /// use std::sync::Arc;
+    /// use torrust_tracker_deployer_sdk::{Deployer, SystemClock};
+    ///
+    /// let deployer = Deployer::builder()
+    ///     .working_dir("/path/to/workspace")
+    ///     .clock(Arc::new(SystemClock))
+    ///     .build()
+    ///     .unwrap();
+    /// ```
+    #[must_use]
+    pub fn clock(mut self, clock: Arc<dyn Clock>) -> Self {
+        self.clock = Some(clock);
+        self
+    }
+
     /// Set a default progress listener for all operations.
     ///
-    /// The listener receives step-by-step progress events from long-running
-    /// operations (provision, configure, release). If not set, a
-    /// [`NullProgressListener`] is used (silent).
+    /// The listener is set once and receives step-by-step progress events
+    /// from every long-running operation (provision, configure, release,
+    /// rollback). If not set, a [`NullProgressListener`] is used (silent).
     ///
     /// # Example
     ///
@@ -96,34 +211,190 @@ impl DeployerBuilder {
         self
     }
 
+    /// Set a default template override directory for new environments.
+    ///
+    /// Templates found at `{dir}/{relative_path}` (e.g.
+    /// `tofu/common/cloud-init.yml.tera`) replace the embedded templates.
+    /// Applied by [`Deployer::create_environment`] when the configuration
+    /// does not set its own `templates_override_dir`.
+    ///
+    /// # Example
+    ///
+    /// ```rust,no_run
+    /// use torrust_tracker_deployer_sdk::Deployer;
+    ///
+    /// let deployer = Deployer::builder()
+    ///     .working_dir("/path/to/workspace")
+    ///     .templates_override_dir("/path/to/workspace/template-overrides")
+    ///     .build()
+    ///     .unwrap();
+    /// ```
+    #[must_use]
+    pub fn templates_override_dir(mut self, path: impl Into<PathBuf>) -> Self {
+        self.templates_override_dir = Some(path.into());
+        self
+    }
+
+    /// Write the trace files of the failed operations to this directory.
+    ///
+    /// Overrides the traces directory of the environment configuration
+    /// (`data/{env-name}/traces/` by default), e.g. to collect the traces
+    /// as CI artifacts.
+    ///
+    /// # Example
+    ///
+    /// ```rust,no_run
+    /// use torrust_tracker_deployer_sdk::Deployer;
+    ///
+    /// let deployer = Deployer::builder()
+    ///     .working_dir("/path/to/workspace")
+    ///     .trace_dir("/tmp/artifacts/traces")
+    ///     .build()
+    ///     .unwrap();
+    /// ```
+    #[must_use]
+    pub fn trace_dir(mut self, path: impl Into<PathBuf>) -> Self {
+        self.trace_dir = Some(path.into());
+        self
+    }
+
+    /// Accept a working directory that is not an initialized workspace.
+    ///
+    /// By default [`build`](DeployerBuilder::build) refuses a directory
+    /// without workspace marker. Use this to build a deployer that
+    /// initializes the workspace.
+    ///
+    /// # Example
+    ///
+    /// ```rust,no_run
+    /// use torrust_tracker_deployer_sdk::Deployer;
+    ///
+    /// let deployer = Deployer::builder()
+    ///     .working_dir("/path/to/new-workspace")
+    ///     .allow_uninitialized()
+    ///     .build()
+    ///     .unwrap();
+    /// deployer.init_workspace().unwrap();
+    /// ```
+    #[must_use]
+    pub fn allow_uninitialized(mut self) -> Self {
+        self.allow_uninitialized = true;
+        self
+    }
+
+    /// Run a custom step once the instance of a provisioned environment is
+    /// reachable.
+    ///
+    /// The step runs before the environment is saved as `Provisioned`. If it
+    /// fails, the environment is saved as `ProvisionFailed` with
+    /// `custom:{step name}` as failed step. Steps run in registration
+    /// order.
+    ///
+    /// # Example
+    ///
+    /// ```rust,no_run
+    /// use torrust_tracker_deployer_sdk::{
+    ///     Deployer, Step, StepContext, StepError, StepFuture, StepOutcome,
+    /// };
+    ///
+    /// struct RegisterDns;
+    ///
+    /// impl Step for RegisterDns {
+    ///     fn name(&self) -> &str {
+    ///         "register-dns"
+    ///     }
+    ///
+    ///     fn execute<'a>(&'a self, context: &'a StepContext) -> StepFuture<'a> {
+    ///         Box::pin(async move {
+    ///             let ip = context
+    ///                 .instance_ip()
+    ///                 .ok_or_else(|| StepError::new("no instance IP"))?;
+    ///             println!("register {} -> {ip}", context.environment_name());
+    ///             Ok(StepOutcome::Completed)
+    ///         })
+    ///     }
+    /// }
+    ///
+    /// let deployer = Deployer::builder()
+    ///     .working_dir("/path/to/workspace")
+    ///     .after_provision(RegisterDns)
+    ///     .build()
+    ///     .unwrap();
+    /// ```
+    #[must_use]
+    pub fn after_provision(mut self, step: impl Step + 'static) -> Self {
+        self.custom_steps.register(HookPoint::AfterProvision, step);
+        self
+    }
+
+    /// Run a custom step before the configuration playbooks.
+    ///
+    /// If the step fails, no playbook runs and the environment is saved as
+    /// `ConfigureFailed` with `custom:{step name}` as failed step.
+    #[must_use]
+    pub fn before_configure(mut self, step: impl Step + 'static) -> Self {
+        self.custom_steps.register(HookPoint::BeforeConfigure, step);
+        self
+    }
+
+    /// Run a custom step after the configuration playbooks.
+    ///
+    /// The step runs before the environment is saved as `Configured`. If it
+    /// fails, the environment is saved as `ConfigureFailed` with
+    /// `custom:{step name}` as failed step.
+    #[must_use]
+    pub fn after_configure(mut self, step: impl Step + 'static) -> Self {
+        self.custom_steps.register(HookPoint::AfterConfigure, step);
+        self
+    }
+
     /// Build the [`Deployer`] instance.
     ///
     /// # Errors
     ///
     /// Returns [`DeployerBuildError::MissingWorkingDir`] if `working_dir`
-    /// was not set.
+    /// was not set, and [`DeployerBuildError::Workspace`] if it is not an
+    /// initialized workspace (unless
+    /// [`allow_uninitialized`](DeployerBuilder::allow_uninitialized) is set).
     pub fn build(self) -> Result<Deployer, DeployerBuildError> {
         let working_dir = self
             .working_dir
             .ok_or(DeployerBuildError::MissingWorkingDir)?;
 
-        let file_repository_factory = default_repository_provider(DEFAULT_SDK_LOCK_TIMEOUT);
-        let data_dir = working_dir.join("data");
-        let data_directory: Arc<Path> = Arc::from(data_dir.as_path());
-        let repository = file_repository_factory.create(data_dir.clone());
-        let clock = default_clock();
+        let mut layout = WorkspaceLayout::in_working_dir(&working_dir);
+        if let Some(data_dir) = self.data_dir {
+            layout = layout.with_data_dir(data_dir);
+        }
+        if let Some(build_dir) = self.build_dir {
+            layout = layout.with_build_dir(build_dir);
+        }
+
+        let marker = if self.allow_uninitialized {
+            read_marker(&working_dir).ok().flatten()
+        } else {
+            Some(verify_workspace(&working_dir, &layout)?)
+        };
+        let backend = marker.map(|marker| marker.repository).unwrap_or_default();
+
+        let file_repository_factory =
+            backend_repository_provider(backend, DEFAULT_SDK_LOCK_TIMEOUT);
+        let repository = file_repository_factory.create(layout.data_dir().to_path_buf());
+        let clock = self.clock.unwrap_or_else(default_clock);
         let listener = self
             .progress_listener
             .unwrap_or_else(|| Arc::new(NullProgressListener));
 
         Ok(Deployer::new(
             working_dir,
+            layout,
             repository,
             file_repository_factory,
             clock,
-            data_directory,
             listener,
-        ))
+            self.templates_override_dir,
+        )
+        .with_custom_steps(self.custom_steps)
+        .with_trace_dir(self.trace_dir))
     }
 }
 
@@ -139,4 +410,27 @@ pub enum DeployerBuildError {
     /// The required `working_dir` was not provided.
     #[error("working_dir is required but was not set")]
     MissingWorkingDir,
+
+    /// The `working_dir` is not an initialized workspace.
+    #[error(transparent)]
+    Workspace(#[from] WorkspaceError),
+}
+
+impl Traceable for DeployerBuildError {
+    fn trace_format(&self) -> String {
+        match self {
+            Self::MissingWorkingDir => "DeployerBuildError: Missing working directory".to_string(),
+            Self::Workspace(e) => format!("DeployerBuildError: Invalid workspace - {e}"),
+        }
+    }
+
+    fn trace_source(&self) -> Option<&dyn Traceable> {
+        None
+    }
+
+    fn error_kind(&self) -> ErrorKind {
+        match self {
+            Self::MissingWorkingDir | Self::Workspace(_) => ErrorKind::Configuration,
+        }
+    }
 }
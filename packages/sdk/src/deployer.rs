@@ -19,20 +19,37 @@
 
 use std::path::{Path, PathBuf};
 use std::sync::Arc;
+use std::time::Duration;
 
+use torrust_tracker_deployer_lib::application::command_handlers::common::DryRunReport;
 use torrust_tracker_deployer_lib::application::command_handlers::configure::{
     ConfigureCommandHandler, ConfigureCommandHandlerError,
 };
 use torrust_tracker_deployer_lib::application::command_handlers::create::config::EnvironmentCreationConfig;
+use torrust_tracker_deployer_lib::application::command_handlers::create::schema::{
+    CreateSchemaCommandHandler, CreateSchemaCommandHandlerError,
+};
 use torrust_tracker_deployer_lib::application::command_handlers::create::CreateCommandHandlerError;
 use torrust_tracker_deployer_lib::application::command_handlers::destroy::{
     DestroyCommandHandler, DestroyCommandHandlerError,
 };
+use torrust_tracker_deployer_lib::application::command_handlers::diff::{
+    DiffCommandHandler, DiffCommandHandlerError,
+};
 use torrust_tracker_deployer_lib::application::command_handlers::exists::{
     ExistsCommandHandler, ExistsCommandHandlerError,
 };
+use torrust_tracker_deployer_lib::application::command_handlers::init::{
+    InitCommandHandler, InitCommandHandlerError, WorkspaceInitialization,
+};
+use torrust_tracker_deployer_lib::application::command_handlers::keys::{
+    KeysCommandHandler, KeysCommandHandlerError, ListedAuthKey,
+};
+use torrust_tracker_deployer_lib::application::command_handlers::last_failure::{
+    LastFailureCommandHandler, LastFailureCommandHandlerError,
+};
 use torrust_tracker_deployer_lib::application::command_handlers::list::{
-    EnvironmentList, ListCommandHandler, ListCommandHandlerError,
+    EnvironmentList, ListCommandHandler, ListCommandHandlerError, ListFilter,
 };
 use torrust_tracker_deployer_lib::application::command_handlers::provision::{
     ProvisionCommandHandler, ProvisionCommandHandlerError,
@@ -42,27 +59,45 @@ use torrust_tracker_deployer_lib::application::command_handlers::purge::handler:
 use torrust_tracker_deployer_lib::application::command_handlers::release::{
     ReleaseCommandHandler, ReleaseCommandHandlerError,
 };
+use torrust_tracker_deployer_lib::application::command_handlers::repair::{
+    RepairCommandHandler, RepairCommandHandlerError, RepairReport,
+};
+use torrust_tracker_deployer_lib::application::command_handlers::rollback::{
+    RollbackCommandHandler, RollbackCommandHandlerError,
+};
 use torrust_tracker_deployer_lib::application::command_handlers::run::{
     RunCommandHandler, RunCommandHandlerError,
 };
 use torrust_tracker_deployer_lib::application::command_handlers::show::{
     EnvironmentInfo, ShowCommandHandler, ShowCommandHandlerError,
 };
+use torrust_tracker_deployer_lib::application::command_handlers::start::{
+    StartCommandHandler, StartCommandHandlerError,
+};
+use torrust_tracker_deployer_lib::application::command_handlers::stop::{
+    StopCommandHandler, StopCommandHandlerError,
+};
 use torrust_tracker_deployer_lib::application::command_handlers::test::{
-    TestCommandHandler, TestCommandHandlerError, TestResult,
+    SmokeTestOptions, TestCommandHandler, TestCommandHandlerError, TestResult,
 };
 use torrust_tracker_deployer_lib::application::command_handlers::validate::{
     ValidateCommandHandler, ValidateCommandHandlerError, ValidationResult,
 };
+use torrust_tracker_deployer_lib::application::steps::CustomSteps;
 use torrust_tracker_deployer_lib::application::traits::CommandProgressListener;
 use torrust_tracker_deployer_lib::application::traits::RepositoryProvider;
 use torrust_tracker_deployer_lib::application::CreateCommandHandler;
 use torrust_tracker_deployer_lib::domain::environment::repository::EnvironmentRepository;
+use torrust_tracker_deployer_lib::domain::environment::{
+    ConfigDiff, FailureReport, WorkspaceLayout,
+};
+use torrust_tracker_deployer_lib::domain::tracker::{AuthKey, IssuedAuthKey};
 use torrust_tracker_deployer_lib::domain::EnvironmentName;
 use torrust_tracker_deployer_lib::shared::Clock;
 
 use super::builder::DeployerBuilder;
 use super::error::CreateEnvironmentFromFileError;
+use super::watch::{EnvironmentEvent, EnvironmentWatcher, DEFAULT_DEBOUNCE};
 
 /// The main entry point for SDK consumers.
 ///
@@ -93,11 +128,15 @@ use super::error::CreateEnvironmentFromFileError;
 #[derive(Clone)]
 pub struct Deployer {
     working_dir: PathBuf,
+    layout: WorkspaceLayout,
     repository: Arc<dyn EnvironmentRepository + Send + Sync>,
     file_repository_factory: Arc<dyn RepositoryProvider>,
     clock: Arc<dyn Clock>,
     data_directory: Arc<Path>,
     listener: Arc<dyn CommandProgressListener + Send + Sync>,
+    templates_override_dir: Option<PathBuf>,
+    custom_steps: CustomSteps,
+    trace_dir: Option<PathBuf>,
 }
 
 impl Deployer {
@@ -110,48 +149,108 @@ impl Deployer {
     /// Internal constructor used by [`DeployerBuilder`].
     pub(crate) fn new(
         working_dir: PathBuf,
+        layout: WorkspaceLayout,
         repository: Arc<dyn EnvironmentRepository + Send + Sync>,
         file_repository_factory: Arc<dyn RepositoryProvider>,
         clock: Arc<dyn Clock>,
-        data_directory: Arc<Path>,
         listener: Arc<dyn CommandProgressListener + Send + Sync>,
+        templates_override_dir: Option<PathBuf>,
     ) -> Self {
+        let data_directory: Arc<Path> = Arc::from(layout.data_dir());
+
         Self {
             working_dir,
+            layout,
             repository,
             file_repository_factory,
             clock,
             data_directory,
             listener,
+            templates_override_dir,
+            custom_steps: CustomSteps::new(),
+            trace_dir: None,
         }
     }
 
+    /// Run `custom_steps` in the provision and configure workflows.
+    pub(crate) fn with_custom_steps(mut self, custom_steps: CustomSteps) -> Self {
+        self.custom_steps = custom_steps;
+        self
+    }
+
+    /// Write the trace files of the failed operations to `trace_dir`.
+    pub(crate) fn with_trace_dir(mut self, trace_dir: Option<PathBuf>) -> Self {
+        self.trace_dir = trace_dir;
+        self
+    }
+
+    /// Initialize the workspace: create `data/`, `build/` and the workspace
+    /// marker.
+    ///
+    /// Safe to call on an initialized workspace, which is left as it is. A
+    /// workspace holding environments but no marker (created by an older
+    /// version) is only marked when all its environments load.
+    ///
+    /// Equivalent to `torrust-tracker-deployer init`. Build the deployer with
+    /// [`DeployerBuilder::allow_uninitialized`] to initialize a new workspace.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`InitCommandHandlerError`] if an environment fails to load,
+    /// the marker is unreadable or from a newer version, or the layout cannot
+    /// be written.
+    pub fn init_workspace(&self) -> Result<WorkspaceInitialization, InitCommandHandlerError> {
+        InitCommandHandler::with_layout(
+            self.repository.clone(),
+            self.working_dir.clone(),
+            self.layout.clone(),
+        )
+        .execute()
+    }
+
     /// Create a new deployment environment from a configuration.
     ///
     /// Equivalent to `torrust-tracker-deployer create environment --env-file <path>`.
     ///
+    /// Creating an environment that already exists with the same configuration
+    /// succeeds and leaves it untouched, so the call can be repeated.
+    ///
+    /// If the configuration does not set `templates_override_dir`, the
+    /// directory set with [`DeployerBuilder::templates_override_dir`] is used.
+    ///
     /// # Errors
     ///
     /// Returns [`CreateCommandHandlerError`] if the configuration is invalid,
-    /// the environment already exists, or a repository error occurs.
+    /// the environment already exists with a different configuration, or a
+    /// repository error occurs.
     pub fn create_environment(
         &self,
-        config: EnvironmentCreationConfig,
+        mut config: EnvironmentCreationConfig,
     ) -> Result<EnvironmentName, CreateCommandHandlerError> {
+        if config.templates_override_dir.is_none() {
+            config.templates_override_dir = self
+                .templates_override_dir
+                .as_ref()
+                .map(|dir| dir.to_string_lossy().into_owned());
+        }
+
         let handler = CreateCommandHandler::new(
             self.repository.clone() as Arc<dyn EnvironmentRepository>,
             Arc::clone(&self.clock),
         );
         handler
-            .execute(config, &self.working_dir)
-            .map(|env| env.name().clone())
+            .execute_or_reuse_in_layout(config, &self.layout)
+            .map(|result| result.name().clone())
     }
 
-    /// Create a new deployment environment from a JSON configuration file.
+    /// Create a new deployment environment from a JSON, YAML or TOML
+    /// configuration file.
     ///
-    /// This is a convenience wrapper that reads the file, parses the JSON, and
+    /// This is a convenience wrapper that reads the file, parses it, and
     /// creates the environment in one step — mirroring the CLI's
-    /// `--env-file <path>` flag.
+    /// `--env-file <path>` flag. The format comes from the extension
+    /// (`.json`, `.yaml`/`.yml`, `.toml`) or, for other extensions, from the
+    /// content (see [`ConfigFormat`](crate::ConfigFormat)).
     ///
     /// # Errors
     ///
@@ -171,7 +270,7 @@ impl Deployer {
     ///     .unwrap();
     ///
     /// let env_name = deployer
-    ///     .create_environment_from_file(Path::new("envs/my-env.json"))
+    ///     .create_environment_from_file(Path::new("envs/my-env.yaml"))
     ///     .unwrap();
     /// println!("Created: {env_name}");
     /// ```
@@ -200,6 +299,100 @@ impl Deployer {
         handler.execute(env_name)
     }
 
+    /// Show information about every environment in the workspace, sorted by name.
+    ///
+    /// Loads all environments in a single pass over the data directory —
+    /// cheaper than calling [`Deployer::show`] for each name returned by
+    /// [`Deployer::list`]. Environments that fail to load are skipped; use
+    /// [`Deployer::list`] to see their load errors.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ListCommandHandlerError`] if the data directory cannot be
+    /// read.
+    ///
+    /// # Examples
+    ///
+    /// ```rust,no_run
+    /// use torrust_tracker_deployer_sdk::Deployer;
+    ///
+    /// let deployer = Deployer::builder()
+    ///     .working_dir("/path/to/workspace")
+    ///     .build()
+    ///     .unwrap();
+    ///
+    /// for info in deployer.show_all().unwrap() {
+    ///     println!("{}: {}", info.name, info.state);
+    /// }
+    /// ```
+    pub fn show_all(&self) -> Result<Vec<EnvironmentInfo>, ListCommandHandlerError> {
+        let handler = ListCommandHandler::new(
+            Arc::clone(&self.file_repository_factory),
+            Arc::clone(&self.data_directory),
+            Arc::clone(&self.clock),
+        );
+        handler.execute_details()
+    }
+
+    /// Watch an environment and receive its state whenever it changes.
+    ///
+    /// `callback` runs on a background thread each time the environment is
+    /// saved, after writes have been quiet for [`DEFAULT_DEBOUNCE`] — a
+    /// multi-step command produces one event with its final state rather than
+    /// one per step. It receives [`EnvironmentEvent::Removed`] when the
+    /// environment's local data is purged.
+    ///
+    /// The watch runs until the returned [`EnvironmentWatcher`] is stopped or
+    /// dropped. The environment does not need to exist yet.
+    ///
+    /// # Examples
+    ///
+    /// ```rust,no_run
+    /// use torrust_tracker_deployer_sdk::{Deployer, EnvironmentEvent, EnvironmentName};
+    ///
+    /// let deployer = Deployer::builder()
+    ///     .working_dir("/path/to/workspace")
+    ///     .build()
+    ///     .unwrap();
+    ///
+    /// let name = EnvironmentName::new("my-env").unwrap();
+    /// let _watcher = deployer.watch(&name, |event| {
+    ///     if let EnvironmentEvent::Changed(info) = event {
+    ///         println!("{} is now {}", info.name, info.state);
+    ///     }
+    /// });
+    /// ```
+    #[must_use = "the watch stops when the watcher is dropped"]
+    pub fn watch<F>(&self, env_name: &EnvironmentName, callback: F) -> EnvironmentWatcher
+    where
+        F: FnMut(EnvironmentEvent) + Send + 'static,
+    {
+        self.watch_with_debounce(env_name, DEFAULT_DEBOUNCE, callback)
+    }
+
+    /// Watch an environment with a custom debounce window.
+    ///
+    /// Same as [`Deployer::watch`], but changes are delivered once the
+    /// environment has not been saved for `debounce`.
+    #[must_use = "the watch stops when the watcher is dropped"]
+    pub fn watch_with_debounce<F>(
+        &self,
+        env_name: &EnvironmentName,
+        debounce: Duration,
+        callback: F,
+    ) -> EnvironmentWatcher
+    where
+        F: FnMut(EnvironmentEvent) + Send + 'static,
+    {
+        EnvironmentWatcher::start(
+            env_name.clone(),
+            self.layout.data_dir(),
+            Arc::clone(&self.repository),
+            debounce,
+            callback,
+        )
+    }
+
     /// Check whether a named environment exists in the workspace.
     ///
     /// Returns `Ok(true)` if the environment is found, `Ok(false)` if it does
@@ -233,7 +426,45 @@ impl Deployer {
         Ok(handler.execute(env_name)?.exists)
     }
 
-    /// List all environments in the workspace.
+    /// Get a structured report of the last failure of an environment.
+    ///
+    /// Returns `Ok(None)` when the environment is not in a failed state. For
+    /// failed environments the report contains the failed step, error kind,
+    /// timestamps, trace id and the error chain parsed from the trace file.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`LastFailureCommandHandlerError`] if the environment is not
+    /// found, its state cannot be loaded, or its structured trace is corrupted.
+    ///
+    /// # Examples
+    ///
+    /// ```rust,no_run
+    /// use torrust_tracker_deployer_sdk::{Deployer, EnvironmentName, ProvisionStep};
+    ///
+    /// let deployer = Deployer::builder()
+    ///     .working_dir("/path/to/workspace")
+    ///     .build()
+    ///     .unwrap();
+    ///
+    /// let name = EnvironmentName::new("my-env").unwrap();
+    /// if let Some(failure) = deployer.last_failure(&name).unwrap() {
+    ///     if failure.failed_step == ProvisionStep::OpenTofuApply {
+    ///         println!("apply failed: {}", failure.base.error_summary);
+    ///     }
+    /// }
+    /// ```
+    pub fn last_failure(
+        &self,
+        env_name: &EnvironmentName,
+    ) -> Result<Option<FailureReport>, LastFailureCommandHandlerError> {
+        let handler = LastFailureCommandHandler::new(
+            self.repository.clone() as Arc<dyn EnvironmentRepository>
+        );
+        handler.execute(env_name)
+    }
+
+    /// List all environments in the workspace, sorted by name.
     ///
     /// Equivalent to `torrust-tracker-deployer list`.
     ///
@@ -241,11 +472,47 @@ impl Deployer {
     ///
     /// Returns [`ListCommandHandlerError`] if a repository error occurs.
     pub fn list(&self) -> Result<EnvironmentList, ListCommandHandlerError> {
+        self.list_filtered(&ListFilter::default())
+    }
+
+    /// List the environments matching a filter, in the filter's sort order.
+    ///
+    /// Equivalent to `torrust-tracker-deployer list` with `--state`,
+    /// `--name`, `--older-than` and `--sort`.
+    ///
+    /// # Example
+    ///
+    /// ```rust,no_run
+    /// use std::time::Duration;
+    /// use torrust_tracker_deployer_sdk::{Deployer, ListFilter, ListSort};
+    ///
+    /// let deployer = Deployer::builder()
+    ///     .working_dir("/path/to/workspace")
+    ///     .build()
+    ///     .unwrap();
+    ///
+    /// let stale_failures = ListFilter::new()
+    ///     .with_states(["provision_failed"])
+    ///     .older_than(Duration::from_secs(3 * 24 * 60 * 60))
+    ///     .sorted_by(ListSort::Created);
+    ///
+    /// let env_list = deployer.list_filtered(&stale_failures).unwrap();
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ListCommandHandlerError`] if the filter contains an unknown
+    /// state or a repository error occurs.
+    pub fn list_filtered(
+        &self,
+        filter: &ListFilter,
+    ) -> Result<EnvironmentList, ListCommandHandlerError> {
         let handler = ListCommandHandler::new(
             Arc::clone(&self.file_repository_factory),
             Arc::clone(&self.data_directory),
+            Arc::clone(&self.clock),
         );
-        handler.execute()
+        handler.execute(filter)
     }
 
     /// Validate an environment configuration file.
@@ -264,6 +531,42 @@ impl Deployer {
         handler.validate(config_path)
     }
 
+    /// Compare an environment with an updated configuration file.
+    ///
+    /// Each changed setting is categorized by what has to be redeployed for
+    /// it to take effect. Nothing is modified.
+    ///
+    /// Equivalent to `torrust-tracker-deployer diff <name> <path>`.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`DiffCommandHandlerError`] if the environment is not found,
+    /// or the file cannot be read, is for another environment or is invalid.
+    pub fn diff(
+        &self,
+        env_name: &EnvironmentName,
+        config_path: &Path,
+    ) -> Result<ConfigDiff, DiffCommandHandlerError> {
+        let handler = DiffCommandHandler::new(Arc::clone(&self.repository), self.layout.clone());
+        handler.execute(env_name, config_path)
+    }
+
+    /// JSON Schema of environment configuration files.
+    ///
+    /// Does not need a workspace. Write the schema next to your
+    /// configurations and reference it with a `"$schema"` key to get editor
+    /// validation and completion.
+    ///
+    /// Equivalent to `torrust-tracker-deployer config schema`.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`CreateSchemaCommandHandlerError`] if the schema cannot be
+    /// generated.
+    pub fn environment_config_schema() -> Result<String, CreateSchemaCommandHandlerError> {
+        CreateSchemaCommandHandler::execute(None)
+    }
+
     /// Destroy the infrastructure for an environment.
     ///
     /// Equivalent to `torrust-tracker-deployer destroy <name>`.
@@ -277,26 +580,67 @@ impl Deployer {
             self.repository.clone() as Arc<dyn EnvironmentRepository>,
             Arc::clone(&self.clock),
         );
-        handler.execute(env_name).map(|_| ())
+        let listener: &dyn CommandProgressListener = &*self.listener;
+        handler.execute(env_name, Some(listener)).map(|_| ())
+    }
+
+    /// Simulate [`destroy`](Self::destroy) without destroying anything.
+    ///
+    /// Runs `tofu plan -destroy` and lists the resources and local
+    /// directories that would be deleted. The environment state is not changed.
+    ///
+    /// Equivalent to `torrust-tracker-deployer destroy <name> --dry-run`.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`DestroyCommandHandlerError`] if the environment is not found
+    /// or the destroy plan fails.
+    pub fn destroy_dry_run(
+        &self,
+        env_name: &EnvironmentName,
+    ) -> Result<DryRunReport, DestroyCommandHandlerError> {
+        let handler = DestroyCommandHandler::new(
+            self.repository.clone() as Arc<dyn EnvironmentRepository>,
+            Arc::clone(&self.clock),
+        );
+        handler.dry_run(env_name)
     }
 
     /// Purge all local data for an environment.
     ///
     /// This removes both the `data/{env-name}/` and `build/{env-name}/`
-    /// directories. It does NOT destroy infrastructure.
+    /// directories. It does NOT destroy infrastructure: only environments in
+    /// the `Created` or `Destroyed` state are purged, call
+    /// [`destroy`](Self::destroy) first for the others.
     ///
-    /// Equivalent to `torrust-tracker-deployer purge <name>`.
+    /// Equivalent to `torrust-tracker-deployer purge <name> --yes`.
     ///
     /// # Errors
     ///
-    /// Returns [`PurgeCommandHandlerError`] if the environment is not found
-    /// or the purge operation fails.
+    /// Returns [`PurgeCommandHandlerError`] if the environment is not found,
+    /// may still have infrastructure, or the purge operation fails.
     pub fn purge(&self, env_name: &EnvironmentName) -> Result<(), PurgeCommandHandlerError> {
         let handler =
-            PurgeCommandHandler::new(Arc::clone(&self.repository), self.working_dir.clone());
+            PurgeCommandHandler::with_layout(Arc::clone(&self.repository), self.layout.clone());
         handler.execute(env_name)
     }
 
+    /// Simulate [`purge`](Self::purge) without removing anything.
+    ///
+    /// Equivalent to `torrust-tracker-deployer purge <name> --dry-run`.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`PurgeCommandHandlerError`] if the environment is not found.
+    pub fn purge_dry_run(
+        &self,
+        env_name: &EnvironmentName,
+    ) -> Result<DryRunReport, PurgeCommandHandlerError> {
+        let handler =
+            PurgeCommandHandler::with_layout(Arc::clone(&self.repository), self.layout.clone());
+        handler.dry_run(env_name)
+    }
+
     // ===================================================================
     // Async operations — require infrastructure (LXD / SSH / cloud)
     // ===================================================================
@@ -305,6 +649,8 @@ impl Deployer {
     ///
     /// Runs `OpenTofu` to create the VM instance, waits for SSH connectivity,
     /// and transitions the environment to the `Provisioned` state.
+    /// Steps registered with [`DeployerBuilder::after_provision`] run once the
+    /// instance is reachable.
     ///
     /// Equivalent to `torrust-tracker-deployer provision <name>`.
     ///
@@ -319,15 +665,42 @@ impl Deployer {
         let handler = ProvisionCommandHandler::new(
             Arc::clone(&self.clock),
             self.repository.clone() as Arc<dyn EnvironmentRepository>,
-        );
+        )
+        .with_custom_steps(self.custom_steps.clone())
+        .with_trace_dir(self.trace_dir.clone());
         let listener: &dyn CommandProgressListener = &*self.listener;
         handler.execute(env_name, Some(listener)).await.map(|_| ())
     }
 
+    /// Simulate [`provision`](Self::provision) without creating anything.
+    ///
+    /// Renders the `OpenTofu` templates and runs `tofu plan` to list the
+    /// resources that would be created. The environment stays `Created`.
+    ///
+    /// Equivalent to `torrust-tracker-deployer provision <name> --dry-run`.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ProvisionCommandHandlerError`] if the environment is not found,
+    /// is in the wrong state, or planning fails.
+    pub async fn provision_dry_run(
+        &self,
+        env_name: &EnvironmentName,
+    ) -> Result<DryRunReport, ProvisionCommandHandlerError> {
+        let handler = ProvisionCommandHandler::new(
+            Arc::clone(&self.clock),
+            self.repository.clone() as Arc<dyn EnvironmentRepository>,
+        );
+        let listener: &dyn CommandProgressListener = &*self.listener;
+        handler.dry_run(env_name, Some(listener)).await
+    }
+
     /// Configure a provisioned environment.
     ///
     /// Runs Ansible playbooks to install required software and configure the
     /// VM, transitioning the environment to the `Configured` state.
+    /// Steps registered with [`DeployerBuilder::before_configure`] and
+    /// [`DeployerBuilder::after_configure`] run around the playbooks.
     ///
     /// Equivalent to `torrust-tracker-deployer configure <name>`.
     ///
@@ -342,7 +715,9 @@ impl Deployer {
         let handler = ConfigureCommandHandler::new(
             Arc::clone(&self.clock),
             self.repository.clone() as Arc<dyn EnvironmentRepository>,
-        );
+        )
+        .with_custom_steps(self.custom_steps.clone())
+        .with_trace_dir(self.trace_dir.clone());
         let listener: &dyn CommandProgressListener = &*self.listener;
         handler.execute(env_name, Some(listener)).map(|_| ())
     }
@@ -366,11 +741,74 @@ impl Deployer {
         let handler = ReleaseCommandHandler::new(
             self.repository.clone() as Arc<dyn EnvironmentRepository>,
             Arc::clone(&self.clock),
-        );
+        )
+        .with_trace_dir(self.trace_dir.clone());
         let listener: &dyn CommandProgressListener = &*self.listener;
         handler.execute(env_name, Some(listener)).await.map(|_| ())
     }
 
+    /// Redeploy the tracker version of the previous release.
+    ///
+    /// Runs the release workflow with the tracker image pinned to the version
+    /// released before the current one, leaving the environment in the
+    /// `Released` state. Call [`Self::run_services`] afterwards.
+    ///
+    /// Equivalent to `torrust-tracker-deployer rollback <name>`.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`RollbackCommandHandlerError`] if the environment is not
+    /// found, is not released or running, has no previous release, or the
+    /// release operation fails.
+    pub async fn rollback(
+        &self,
+        env_name: &EnvironmentName,
+    ) -> Result<(), RollbackCommandHandlerError> {
+        let handler = RollbackCommandHandler::new(
+            self.repository.clone() as Arc<dyn EnvironmentRepository>,
+            Arc::clone(&self.clock),
+        )
+        .with_trace_dir(self.trace_dir.clone());
+        let listener: &dyn CommandProgressListener = &*self.listener;
+        handler.execute(env_name, Some(listener)).await.map(|_| ())
+    }
+
+    /// Report whether the persisted state of an environment loads, and list
+    /// its state backups (newest first).
+    ///
+    /// Equivalent to `torrust-tracker-deployer repair <name> --list`.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`RepairCommandHandlerError`] if neither the state nor a
+    /// backup exists, or the state cannot be read.
+    pub fn repair_status(
+        &self,
+        env_name: &EnvironmentName,
+    ) -> Result<RepairReport, RepairCommandHandlerError> {
+        RepairCommandHandler::new(self.repository.clone()).inspect(env_name)
+    }
+
+    /// Restore the persisted state of an environment from a backup.
+    ///
+    /// With `backup_id` set to `None`, the latest backup is restored, and only
+    /// when the current state cannot be loaded.
+    ///
+    /// Equivalent to `torrust-tracker-deployer repair <name> [--backup <id>]`.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`RepairCommandHandlerError`] if the environment is not found,
+    /// the state loads and no backup was selected, the backup does not exist,
+    /// or restoring it fails.
+    pub fn repair(
+        &self,
+        env_name: &EnvironmentName,
+        backup_id: Option<&str>,
+    ) -> Result<RepairReport, RepairCommandHandlerError> {
+        RepairCommandHandler::new(self.repository.clone()).execute(env_name, backup_id)
+    }
+
     /// Start services on a released environment.
     ///
     /// Runs `docker compose up` on the remote instance, transitioning the
@@ -387,27 +825,173 @@ impl Deployer {
         let handler = RunCommandHandler::new(
             self.repository.clone() as Arc<dyn EnvironmentRepository>,
             Arc::clone(&self.clock),
-        );
+        )
+        .with_trace_dir(self.trace_dir.clone());
+        handler.execute(env_name).map(|_| ())
+    }
+
+    /// Stop the tracker services of a running environment.
+    ///
+    /// Runs `docker compose stop` on the remote instance, keeping the
+    /// containers and the deployed configuration, and transitions the
+    /// environment to the `Stopped` state.
+    ///
+    /// Equivalent to `torrust-tracker-deployer stop <name>`.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`StopCommandHandlerError`] if the environment is not found,
+    /// is not running, or stopping services fails.
+    #[allow(clippy::result_large_err)]
+    pub fn stop_services(&self, env_name: &EnvironmentName) -> Result<(), StopCommandHandlerError> {
+        let handler =
+            StopCommandHandler::new(self.repository.clone() as Arc<dyn EnvironmentRepository>);
+        handler.execute(env_name).map(|_| ())
+    }
+
+    /// Start the stopped tracker services of an environment again.
+    ///
+    /// Runs `docker compose start` on the remote instance and waits for the
+    /// tracker to report healthy, transitioning the environment back to the
+    /// `Running` state.
+    ///
+    /// Equivalent to `torrust-tracker-deployer start <name>`.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`StartCommandHandlerError`] if the environment is not found,
+    /// is not stopped, starting services fails, or the tracker never reports
+    /// healthy.
+    #[allow(clippy::result_large_err)]
+    pub fn start_services(
+        &self,
+        env_name: &EnvironmentName,
+    ) -> Result<(), StartCommandHandlerError> {
+        let handler =
+            StartCommandHandler::new(self.repository.clone() as Arc<dyn EnvironmentRepository>);
         handler.execute(env_name).map(|_| ())
     }
 
     /// Test a deployed environment.
     ///
-    /// Verifies connectivity and DNS resolution for the running instance.
+    /// Runs every smoke check against the running tracker and checks DNS
+    /// resolution of its domains.
     ///
     /// Equivalent to `torrust-tracker-deployer test <name>`.
     ///
     /// # Errors
     ///
-    /// Returns [`TestCommandHandlerError`] if the environment is not found
-    /// or the test fails.
+    /// Returns [`TestCommandHandlerError`] if the environment is not found,
+    /// is not running, or a check fails.
     pub async fn test(
         &self,
         env_name: &EnvironmentName,
+    ) -> Result<TestResult, TestCommandHandlerError> {
+        self.test_with_options(env_name, &SmokeTestOptions::default())
+            .await
+    }
+
+    /// Test a deployed environment with a selection of smoke checks.
+    ///
+    /// Equivalent to `torrust-tracker-deployer test <name>` with `--check`
+    /// and `--timeout`.
+    ///
+    /// # Example
+    ///
+    /// ```rust,no_run
+    /// use std::time::Duration;
+    /// use torrust_tracker_deployer_sdk::{Deployer, EnvironmentName, SmokeCheck, SmokeTestOptions};
+    ///
+    /// # async fn example() -> Result<(), Box<dyn std::error::Error>> {
+    /// let deployer = Deployer::builder()
+    ///     .working_dir("/path/to/workspace")
+    ///     .build()?;
+    ///
+    /// let options = SmokeTestOptions::new()
+    ///     .with_checks([SmokeCheck::Udp, SmokeCheck::Api])
+    ///     .with_timeout(Duration::from_secs(30));
+    ///
+    /// let result = deployer
+    ///     .test_with_options(&EnvironmentName::new("my-env")?, &options)
+    ///     .await?;
+    /// for check in &result.checks {
+    ///     println!("{check}");
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Returns [`TestCommandHandlerError`] if the environment is not found,
+    /// is not running, or a check fails.
+    pub async fn test_with_options(
+        &self,
+        env_name: &EnvironmentName,
+        options: &SmokeTestOptions,
     ) -> Result<TestResult, TestCommandHandlerError> {
         let handler =
             TestCommandHandler::new(self.repository.clone() as Arc<dyn EnvironmentRepository>);
-        handler.execute(env_name).await
+        handler.execute_with_options(env_name, options).await
+    }
+
+    /// Issue an authentication key on the private tracker of an environment.
+    ///
+    /// The key is permanent when `duration` is `None`. It is recorded in the
+    /// environment secrets so that [`Self::list_tracker_keys`] can list it.
+    ///
+    /// Equivalent to `torrust-tracker-deployer keys add <name> [--duration <d>]`.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`KeysCommandHandlerError`] if the environment is not found,
+    /// is not running a private tracker, or the tracker API call fails.
+    pub async fn add_tracker_key(
+        &self,
+        env_name: &EnvironmentName,
+        duration: Option<Duration>,
+    ) -> Result<IssuedAuthKey, KeysCommandHandlerError> {
+        KeysCommandHandler::new(self.repository.clone(), Arc::clone(&self.clock))
+            .add(env_name, duration)
+            .await
+    }
+
+    /// List the authentication keys issued for an environment.
+    ///
+    /// Reads the keys recorded in the environment secrets; the tracker is not
+    /// contacted.
+    ///
+    /// Equivalent to `torrust-tracker-deployer keys list <name>`.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`KeysCommandHandlerError`] if the environment is not found or
+    /// does not use a private tracker.
+    pub fn list_tracker_keys(
+        &self,
+        env_name: &EnvironmentName,
+    ) -> Result<Vec<ListedAuthKey>, KeysCommandHandlerError> {
+        KeysCommandHandler::new(self.repository.clone(), Arc::clone(&self.clock)).list(env_name)
+    }
+
+    /// Revoke an authentication key on the private tracker of an environment.
+    ///
+    /// Returns whether the key was recorded for the environment.
+    ///
+    /// Equivalent to `torrust-tracker-deployer keys remove <name> <key>`.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`KeysCommandHandlerError`] if the environment is not found,
+    /// is not running a private tracker, or the tracker API call fails.
+    pub async fn remove_tracker_key(
+        &self,
+        env_name: &EnvironmentName,
+        key: &AuthKey,
+    ) -> Result<bool, KeysCommandHandlerError> {
+        KeysCommandHandler::new(self.repository.clone(), Arc::clone(&self.clock))
+            .remove(env_name, key)
+            .await
     }
 }
 
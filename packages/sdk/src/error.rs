@@ -2,22 +2,35 @@
 //!
 //! - [`CreateEnvironmentFromFileError`] — for [`super::deployer::Deployer::create_environment_from_file`]
 //! - [`SdkError`] — unified error enum covering all Deployer operations
+//!
+//! Every error implements [`Traceable`], so its [`ErrorKind`] tells transient
+//! failures, worth retrying, from permanent ones without inspecting messages.
 
 use thiserror::Error;
 
 use torrust_tracker_deployer_lib::application::command_handlers::configure::ConfigureCommandHandlerError;
 use torrust_tracker_deployer_lib::application::command_handlers::create::config::ConfigLoadError;
+use torrust_tracker_deployer_lib::application::command_handlers::create::schema::CreateSchemaCommandHandlerError;
 use torrust_tracker_deployer_lib::application::command_handlers::create::CreateCommandHandlerError;
 use torrust_tracker_deployer_lib::application::command_handlers::destroy::DestroyCommandHandlerError;
+use torrust_tracker_deployer_lib::application::command_handlers::diff::DiffCommandHandlerError;
 use torrust_tracker_deployer_lib::application::command_handlers::exists::ExistsCommandHandlerError;
+use torrust_tracker_deployer_lib::application::command_handlers::init::InitCommandHandlerError;
+use torrust_tracker_deployer_lib::application::command_handlers::keys::KeysCommandHandlerError;
+use torrust_tracker_deployer_lib::application::command_handlers::last_failure::LastFailureCommandHandlerError;
 use torrust_tracker_deployer_lib::application::command_handlers::list::ListCommandHandlerError;
 use torrust_tracker_deployer_lib::application::command_handlers::provision::ProvisionCommandHandlerError;
 use torrust_tracker_deployer_lib::application::command_handlers::purge::errors::PurgeCommandHandlerError;
 use torrust_tracker_deployer_lib::application::command_handlers::release::ReleaseCommandHandlerError;
+use torrust_tracker_deployer_lib::application::command_handlers::repair::RepairCommandHandlerError;
+use torrust_tracker_deployer_lib::application::command_handlers::rollback::RollbackCommandHandlerError;
 use torrust_tracker_deployer_lib::application::command_handlers::run::RunCommandHandlerError;
 use torrust_tracker_deployer_lib::application::command_handlers::show::ShowCommandHandlerError;
+use torrust_tracker_deployer_lib::application::command_handlers::start::StartCommandHandlerError;
+use torrust_tracker_deployer_lib::application::command_handlers::stop::StopCommandHandlerError;
 use torrust_tracker_deployer_lib::application::command_handlers::test::TestCommandHandlerError;
 use torrust_tracker_deployer_lib::application::command_handlers::validate::ValidateCommandHandlerError;
+use torrust_tracker_deployer_lib::shared::{ErrorKind, Traceable};
 
 use super::builder::DeployerBuildError;
 
@@ -35,6 +48,29 @@ pub enum CreateEnvironmentFromFileError {
     Create(#[from] CreateCommandHandlerError),
 }
 
+impl Traceable for CreateEnvironmentFromFileError {
+    fn trace_format(&self) -> String {
+        match self {
+            Self::Load(e) => format!("CreateEnvironmentFromFileError: Load failed - {e}"),
+            Self::Create(e) => format!("CreateEnvironmentFromFileError: Create failed - {e}"),
+        }
+    }
+
+    fn trace_source(&self) -> Option<&dyn Traceable> {
+        match self {
+            Self::Load(e) => Some(e),
+            Self::Create(e) => Some(e),
+        }
+    }
+
+    fn error_kind(&self) -> ErrorKind {
+        match self {
+            Self::Load(e) => e.error_kind(),
+            Self::Create(e) => e.error_kind(),
+        }
+    }
+}
+
 /// Unified error type covering every [`super::deployer::Deployer`] operation.
 ///
 /// Each variant corresponds to one operation (or the builder). Prefer the
@@ -62,6 +98,10 @@ pub enum SdkError {
     #[error(transparent)]
     Build(#[from] DeployerBuildError),
 
+    /// [`super::deployer::Deployer::init_workspace`] failed.
+    #[error(transparent)]
+    Init(#[from] InitCommandHandlerError),
+
     /// [`super::deployer::Deployer::create_environment`] failed.
     #[error(transparent)]
     Create(#[from] CreateCommandHandlerError),
@@ -78,6 +118,10 @@ pub enum SdkError {
     #[error(transparent)]
     Show(#[from] ShowCommandHandlerError),
 
+    /// [`super::deployer::Deployer::last_failure`] failed.
+    #[error(transparent)]
+    LastFailure(#[from] LastFailureCommandHandlerError),
+
     /// [`super::deployer::Deployer::list`] failed.
     #[error(transparent)]
     List(#[from] ListCommandHandlerError),
@@ -86,6 +130,14 @@ pub enum SdkError {
     #[error(transparent)]
     Validate(#[from] ValidateCommandHandlerError),
 
+    /// [`super::deployer::Deployer::diff`] failed.
+    #[error(transparent)]
+    Diff(#[from] DiffCommandHandlerError),
+
+    /// [`super::deployer::Deployer::environment_config_schema`] failed.
+    #[error(transparent)]
+    Schema(#[from] CreateSchemaCommandHandlerError),
+
     /// [`super::deployer::Deployer::destroy`] failed.
     #[error(transparent)]
     Destroy(#[from] DestroyCommandHandlerError),
@@ -106,11 +158,207 @@ pub enum SdkError {
     #[error(transparent)]
     Release(#[from] ReleaseCommandHandlerError),
 
+    /// [`super::deployer::Deployer::rollback`] failed.
+    #[error(transparent)]
+    Rollback(#[from] RollbackCommandHandlerError),
+
+    /// [`super::deployer::Deployer::repair`] or
+    /// [`super::deployer::Deployer::repair_status`] failed.
+    #[error(transparent)]
+    Repair(#[from] RepairCommandHandlerError),
+
     /// [`super::deployer::Deployer::run_services`] failed.
     #[error(transparent)]
     Run(#[from] RunCommandHandlerError),
 
+    /// [`super::deployer::Deployer::stop_services`] failed.
+    #[error(transparent)]
+    Stop(#[from] StopCommandHandlerError),
+
+    /// [`super::deployer::Deployer::start_services`] failed.
+    #[error(transparent)]
+    Start(#[from] StartCommandHandlerError),
+
     /// [`super::deployer::Deployer::test`] failed.
     #[error(transparent)]
     Test(#[from] TestCommandHandlerError),
+
+    /// [`super::deployer::Deployer::add_tracker_key`],
+    /// [`super::deployer::Deployer::list_tracker_keys`] or
+    /// [`super::deployer::Deployer::remove_tracker_key`] failed.
+    #[error(transparent)]
+    Keys(#[from] KeysCommandHandlerError),
+}
+
+impl SdkError {
+    /// Returns the high-level category of the failure
+    ///
+    /// Delegates to the error of the failed operation, see [`Traceable::error_kind`].
+    #[must_use]
+    pub fn kind(&self) -> ErrorKind {
+        self.operation_error().error_kind()
+    }
+
+    /// Whether the failed operation may succeed if retried
+    ///
+    /// Transient infrastructure failures (network timeouts, LXD busy, apt lock
+    /// held) are retryable; permanent ones (invalid state, validation errors,
+    /// missing files) are not. See [`ErrorKind::is_retryable`] for the mapping.
+    ///
+    /// # Example
+    ///
+    /// ```rust,no_run
+    /// use torrust_tracker_deployer_sdk::{Deployer, SdkError};
+    /// use torrust_tracker_deployer_lib::domain::EnvironmentName;
+    ///
+    /// async fn provision_with_retry(
+    ///     deployer: &Deployer,
+    ///     name: &EnvironmentName,
+    /// ) -> Result<(), SdkError> {
+    ///     let mut attempt = 0;
+    ///     loop {
+    ///         match deployer.provision(name).await.map_err(SdkError::from) {
+    ///             Err(err) if err.is_retryable() && attempt < 3 => attempt += 1,
+    ///             result => return result,
+    ///         }
+    ///     }
+    /// }
+    /// ```
+    #[must_use]
+    pub fn is_retryable(&self) -> bool {
+        self.kind().is_retryable()
+    }
+
+    /// The error of the failed operation
+    fn operation_error(&self) -> &dyn Traceable {
+        match self {
+            Self::Build(e) => e,
+            Self::Init(e) => e,
+            Self::Create(e) => e,
+            Self::CreateFromFile(e) => e,
+            Self::Exists(e) => e,
+            Self::Show(e) => e,
+            Self::LastFailure(e) => e,
+            Self::List(e) => e,
+            Self::Validate(e) => e,
+            Self::Diff(e) => e,
+            Self::Schema(e) => e,
+            Self::Destroy(e) => e,
+            Self::Purge(e) => e,
+            Self::Provision(e) => e,
+            Self::Configure(e) => e,
+            Self::Release(e) => e,
+            Self::Rollback(e) => e,
+            Self::Repair(e) => e,
+            Self::Run(e) => e,
+            Self::Stop(e) => e,
+            Self::Start(e) => e,
+            Self::Test(e) => e,
+            Self::Keys(e) => e,
+        }
+    }
+}
+
+impl Traceable for SdkError {
+    fn trace_format(&self) -> String {
+        self.operation_error().trace_format()
+    }
+
+    fn trace_source(&self) -> Option<&dyn Traceable> {
+        self.operation_error().trace_source()
+    }
+
+    fn error_kind(&self) -> ErrorKind {
+        self.kind()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::path::PathBuf;
+    use std::time::Duration;
+
+    use torrust_tracker_deployer_lib::application::command_handlers::init::WorkspaceError;
+
+    use super::*;
+
+    #[test]
+    fn it_should_retry_an_operation_that_timed_out() {
+        let error = SdkError::from(ProvisionCommandHandlerError::DeadlineExceeded {
+            deadline: Duration::from_secs(600),
+        });
+
+        assert_eq!(error.kind(), ErrorKind::Timeout);
+        assert!(error.is_retryable());
+    }
+
+    #[test]
+    fn it_should_retry_a_failed_infrastructure_operation() {
+        let error = SdkError::from(ReleaseCommandHandlerError::ReleaseOperationFailed {
+            name: "production".to_string(),
+            message: "connection reset".to_string(),
+        });
+
+        assert_eq!(error.kind(), ErrorKind::InfrastructureOperation);
+        assert!(error.is_retryable());
+    }
+
+    #[test]
+    fn it_should_not_retry_a_deployer_that_cannot_be_built() {
+        let missing = SdkError::from(DeployerBuildError::MissingWorkingDir);
+        let uninitialized = SdkError::from(DeployerBuildError::Workspace(
+            WorkspaceError::NotInitialized {
+                path: PathBuf::from("/tmp/empty"),
+            },
+        ));
+
+        assert_eq!(missing.kind(), ErrorKind::Configuration);
+        assert!(!missing.is_retryable());
+        assert!(!uninitialized.is_retryable());
+    }
+
+    #[test]
+    fn it_should_not_retry_an_invalid_state() {
+        let error = SdkError::from(RollbackCommandHandlerError::NoPreviousRelease {
+            name: "production".to_string(),
+        });
+
+        assert_eq!(error.kind(), ErrorKind::Configuration);
+        assert!(!error.is_retryable());
+    }
+
+    #[test]
+    fn it_should_not_retry_a_missing_configuration_file() {
+        let error = SdkError::from(CreateEnvironmentFromFileError::Load(
+            ConfigLoadError::FileNotFound {
+                path: PathBuf::from("envs/missing.json"),
+            },
+        ));
+
+        assert_eq!(error.kind(), ErrorKind::FileSystem);
+        assert!(!error.is_retryable());
+    }
+
+    #[test]
+    fn it_should_not_retry_a_validation_error() {
+        let error = SdkError::from(ValidateCommandHandlerError::FileReadFailed {
+            path: PathBuf::from("envs/missing.json"),
+            source: std::io::Error::new(std::io::ErrorKind::NotFound, "not found"),
+        });
+
+        assert_eq!(error.kind(), ErrorKind::FileSystem);
+        assert!(!error.is_retryable());
+    }
+
+    #[test]
+    fn it_should_trace_the_error_of_the_failed_operation() {
+        let error = SdkError::from(RepairCommandHandlerError::NoBackups {
+            name: "production".to_string(),
+        });
+
+        assert_eq!(
+            error.trace_format(),
+            "RepairCommandHandlerError: No state backups - 'production'"
+        );
+    }
 }
@@ -34,45 +34,90 @@
 mod builder;
 mod deployer;
 mod error;
+mod watch;
 
 // === Core facade ===
 pub use builder::{DeployerBuildError, DeployerBuilder};
 pub use deployer::Deployer;
+pub use watch::{EnvironmentEvent, EnvironmentWatcher, DEFAULT_DEBOUNCE};
 
 // === Domain types (inputs only) ===
-pub use torrust_tracker_deployer_types::{EnvironmentName, EnvironmentNameError};
+pub use torrust_tracker_deployer_types::{
+    Clock, EnvironmentName, EnvironmentNameError, SystemClock,
+};
 
 // === Configuration types (for create_environment) ===
 pub use torrust_tracker_deployer_lib::application::command_handlers::create::config::{
-    EnvironmentCreationConfig, EnvironmentCreationConfigBuildError,
-    EnvironmentCreationConfigBuilder,
+    ConfigFormat, ConfigValidationIssue, CreateConfigError, EnvironmentCreationConfig,
+    EnvironmentCreationConfigBuildError, EnvironmentCreationConfigBuilder, IssueSeverity,
+};
+
+// === Query types ===
+pub use torrust_tracker_deployer_lib::application::command_handlers::list::{ListFilter, ListSort};
+pub use torrust_tracker_deployer_lib::application::command_handlers::test::{
+    SmokeCheck, SmokeTestOptions,
 };
 
 // === Result types ===
+pub use torrust_tracker_deployer_lib::adapters::tofu::{PlannedAction, PlannedChange};
+pub use torrust_tracker_deployer_lib::application::command_handlers::common::DryRunReport;
+pub use torrust_tracker_deployer_lib::application::command_handlers::init::WorkspaceInitialization;
+pub use torrust_tracker_deployer_lib::application::command_handlers::keys::ListedAuthKey;
 pub use torrust_tracker_deployer_lib::application::command_handlers::list::EnvironmentList;
+pub use torrust_tracker_deployer_lib::application::command_handlers::repair::{
+    RepairReport, StateFileStatus,
+};
 pub use torrust_tracker_deployer_lib::application::command_handlers::show::EnvironmentInfo;
-pub use torrust_tracker_deployer_lib::application::command_handlers::test::TestResult;
+pub use torrust_tracker_deployer_lib::application::command_handlers::test::{
+    CheckResult, CheckStatus, TestResult,
+};
 pub use torrust_tracker_deployer_lib::application::command_handlers::validate::ValidationResult;
+pub use torrust_tracker_deployer_lib::domain::environment::repository::StateBackup;
+pub use torrust_tracker_deployer_lib::domain::environment::state::{
+    ConfigureStep, DestroyStep, ProvisionStep, ReleaseStep, RunStep,
+};
+pub use torrust_tracker_deployer_lib::domain::environment::{
+    ChangeImpact, ConfigChange, ConfigDiff, FailedStep, FailureReport, TraceEntry, TraceId,
+};
+pub use torrust_tracker_deployer_lib::domain::tracker::{AuthKey, AuthKeyError, IssuedAuthKey};
+pub use torrust_tracker_deployer_lib::shared::{ErrorKind, Traceable};
 
 // === Error types ===
 pub use error::{CreateEnvironmentFromFileError, SdkError};
 pub use torrust_tracker_deployer_lib::application::command_handlers::configure::ConfigureCommandHandlerError;
 pub use torrust_tracker_deployer_lib::application::command_handlers::create::config::ConfigLoadError;
+pub use torrust_tracker_deployer_lib::application::command_handlers::create::schema::CreateSchemaCommandHandlerError;
 pub use torrust_tracker_deployer_lib::application::command_handlers::create::CreateCommandHandlerError;
 pub use torrust_tracker_deployer_lib::application::command_handlers::destroy::DestroyCommandHandlerError;
-pub use torrust_tracker_deployer_lib::application::command_handlers::list::ListCommandHandlerError;
+pub use torrust_tracker_deployer_lib::application::command_handlers::diff::DiffCommandHandlerError;
+pub use torrust_tracker_deployer_lib::application::command_handlers::init::{
+    InitCommandHandlerError, WorkspaceError,
+};
+pub use torrust_tracker_deployer_lib::application::command_handlers::keys::KeysCommandHandlerError;
+pub use torrust_tracker_deployer_lib::application::command_handlers::last_failure::LastFailureCommandHandlerError;
+pub use torrust_tracker_deployer_lib::application::command_handlers::list::{
+    ListCommandHandlerError, ListFilterError,
+};
 pub use torrust_tracker_deployer_lib::application::command_handlers::provision::ProvisionCommandHandlerError;
 pub use torrust_tracker_deployer_lib::application::command_handlers::purge::errors::PurgeCommandHandlerError;
 pub use torrust_tracker_deployer_lib::application::command_handlers::release::ReleaseCommandHandlerError;
+pub use torrust_tracker_deployer_lib::application::command_handlers::repair::RepairCommandHandlerError;
+pub use torrust_tracker_deployer_lib::application::command_handlers::rollback::RollbackCommandHandlerError;
 pub use torrust_tracker_deployer_lib::application::command_handlers::run::RunCommandHandlerError;
 pub use torrust_tracker_deployer_lib::application::command_handlers::show::ShowCommandHandlerError;
+pub use torrust_tracker_deployer_lib::application::command_handlers::start::StartCommandHandlerError;
+pub use torrust_tracker_deployer_lib::application::command_handlers::stop::StopCommandHandlerError;
 pub use torrust_tracker_deployer_lib::application::command_handlers::test::TestCommandHandlerError;
 pub use torrust_tracker_deployer_lib::application::command_handlers::validate::ValidateCommandHandlerError;
 pub use torrust_tracker_deployer_lib::application::errors::{
-    InvalidStateError, PersistenceError, ReleaseWorkflowStep,
+    AllowedCommands, InvalidStateError, PersistenceError, ReleaseWorkflowStep,
 };
 
 // === Extension points ===
+pub use torrust_tracker_deployer_lib::application::steps::{
+    CustomStepFailure, HookPoint, Step, StepContext, StepError, StepFuture, StepOutcome,
+};
 pub use torrust_tracker_deployer_lib::application::traits::{
     CommandProgressListener, NullProgressListener,
 };
+pub use torrust_tracker_deployer_lib::domain::environment::state::AnyEnvironmentState;
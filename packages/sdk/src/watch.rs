@@ -0,0 +1,262 @@
+//! Environment state watcher.
+//!
+//! [`EnvironmentWatcher`] pushes the state of an environment to a callback
+//! whenever it is saved, so consumers such as dashboards do not have to poll
+//! [`super::deployer::Deployer::show`].
+//!
+//! # Change Detection
+//!
+//! A background thread compares the change stamp of the environment in the
+//! repository, whatever the storage backend (JSON files or `SQLite`). Reading
+//! the stamp is a `stat` call or an indexed query; the state is only loaded
+//! and deserialized once a change has been seen.
+//!
+//! The stamp is read when the operating system reports a change in the data
+//! directory (inotify, `FSEvents`, ...), and every [`RESCAN_INTERVAL`] in
+//! case a notification was lost. When the data directory cannot be watched
+//! — it does not exist yet, or the platform has no notification API — the
+//! watcher falls back to reading the stamp every [`POLL_INTERVAL`].
+//!
+//! # Debouncing
+//!
+//! Multi-step commands (provision, configure, ...) persist the state several
+//! times in quick succession. After a change the watcher waits until the stamp
+//! has stayed the same for the debounce window and then delivers only the
+//! final state. A state that cannot be loaded yet stays pending and is loaded
+//! again after another debounce window, so the last change of a command is
+//! never dropped.
+
+use std::path::Path;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::mpsc::{self, Receiver, Sender};
+use std::sync::Arc;
+use std::thread::{self, JoinHandle};
+use std::time::{Duration, Instant};
+
+use notify::{RecommendedWatcher, RecursiveMode, Watcher};
+
+use torrust_tracker_deployer_lib::application::command_handlers::show::{
+    EnvironmentInfo, ShowCommandHandler,
+};
+use torrust_tracker_deployer_lib::domain::environment::repository::{
+    EnvironmentRepository, RepositoryError,
+};
+use torrust_tracker_deployer_lib::domain::EnvironmentName;
+
+/// How often the change stamp is checked when the data directory cannot be watched
+const POLL_INTERVAL: Duration = Duration::from_millis(50);
+
+/// How often the change stamp is checked without a change notification
+const RESCAN_INTERVAL: Duration = Duration::from_secs(1);
+
+/// Default quiet period after the last write before a change is delivered
+pub const DEFAULT_DEBOUNCE: Duration = Duration::from_millis(250);
+
+/// A change in the persisted state of a watched environment
+#[derive(Debug, Clone)]
+pub enum EnvironmentEvent {
+    /// The environment was saved; carries its state after the last write
+    Changed(Box<EnvironmentInfo>),
+
+    /// The environment is no longer stored (e.g. after purge)
+    Removed,
+}
+
+/// Handle of a running environment watch
+///
+/// The watch stops when [`EnvironmentWatcher::stop`] is called or the handle
+/// is dropped.
+#[derive(Debug)]
+pub struct EnvironmentWatcher {
+    stopped: Arc<AtomicBool>,
+    wake: Sender<()>,
+    thread: Option<JoinHandle<()>>,
+}
+
+impl EnvironmentWatcher {
+    /// Start watching `env_name`, stored under `data_dir`, and deliver its
+    /// changes to `callback`
+    ///
+    /// The callback runs on the watcher thread.
+    pub(crate) fn start<F>(
+        env_name: EnvironmentName,
+        data_dir: &Path,
+        repository: Arc<dyn EnvironmentRepository + Send + Sync>,
+        debounce: Duration,
+        callback: F,
+    ) -> Self
+    where
+        F: FnMut(EnvironmentEvent) + Send + 'static,
+    {
+        let stopped = Arc::new(AtomicBool::new(false));
+        let (wake, woken) = mpsc::channel();
+
+        // Both taken before returning so changes right after `start` are not missed
+        let notifier = watch_data_dir(data_dir, wake.clone());
+        let initial = stamp(repository.as_ref(), &env_name).unwrap_or(None);
+
+        let thread = {
+            let stopped = Arc::clone(&stopped);
+            thread::spawn(move || {
+                let idle_interval = if notifier.is_some() {
+                    RESCAN_INTERVAL
+                } else {
+                    POLL_INTERVAL
+                };
+                watch_loop(
+                    &env_name,
+                    initial,
+                    repository,
+                    debounce,
+                    &Wakeups {
+                        woken,
+                        idle_interval,
+                        stopped: &stopped,
+                    },
+                    callback,
+                );
+                drop(notifier);
+            })
+        };
+
+        Self {
+            stopped,
+            wake,
+            thread: Some(thread),
+        }
+    }
+
+    /// Stop watching and wait for the watcher thread to finish
+    pub fn stop(mut self) {
+        self.shutdown();
+    }
+
+    fn shutdown(&mut self) {
+        self.stopped.store(true, Ordering::Relaxed);
+        // The thread may already have ended, e.g. after a panicking callback
+        self.wake.send(()).ok();
+
+        if let Some(thread) = self.thread.take() {
+            // A panicking callback only ends its own watch
+            drop(thread.join());
+        }
+    }
+}
+
+impl Drop for EnvironmentWatcher {
+    fn drop(&mut self) {
+        self.shutdown();
+    }
+}
+
+/// Change stamp of the stored environment, `None` when it is not stored
+///
+/// Fails when the repository cannot be read, e.g. while another process holds
+/// its lock.
+fn stamp(
+    repository: &(dyn EnvironmentRepository + Send + Sync),
+    env_name: &EnvironmentName,
+) -> Result<Option<String>, RepositoryError> {
+    repository.change_stamp(env_name)
+}
+
+/// Send a wake-up on every change the operating system reports under `data_dir`
+///
+/// Returns `None` when the directory cannot be watched; the caller then polls.
+fn watch_data_dir(data_dir: &Path, wake: Sender<()>) -> Option<RecommendedWatcher> {
+    let mut notifier = notify::recommended_watcher(move |_event| {
+        wake.send(()).ok();
+    })
+    .ok()?;
+    notifier.watch(data_dir, RecursiveMode::Recursive).ok()?;
+    Some(notifier)
+}
+
+/// What the watcher thread waits on between two reads of the change stamp
+struct Wakeups<'a> {
+    /// Change notifications, and the wake-up sent when the watch is stopped
+    woken: Receiver<()>,
+
+    /// Longest wait without a wake-up
+    idle_interval: Duration,
+
+    stopped: &'a AtomicBool,
+}
+
+impl Wakeups<'_> {
+    /// Wait for a wake-up or for `timeout`, whichever comes first
+    ///
+    /// Returns `false` once the watch is stopped.
+    fn wait(&self, timeout: Duration) -> bool {
+        if self.woken.recv_timeout(timeout).is_ok() {
+            // Notifications arrive in bursts; one stamp read covers them all
+            while self.woken.try_recv().is_ok() {}
+        }
+        !self.stopped.load(Ordering::Relaxed)
+    }
+}
+
+fn watch_loop<F>(
+    env_name: &EnvironmentName,
+    initial: Option<String>,
+    repository: Arc<dyn EnvironmentRepository + Send + Sync>,
+    debounce: Duration,
+    wakeups: &Wakeups<'_>,
+    mut callback: F,
+) where
+    F: FnMut(EnvironmentEvent),
+{
+    let handler =
+        ShowCommandHandler::new(Arc::clone(&repository) as Arc<dyn EnvironmentRepository>);
+
+    let mut delivered = initial;
+    // Latest unseen stamp and when it was first observed unchanged
+    let mut pending: Option<(Option<String>, Instant)> = None;
+
+    loop {
+        // A pending change is checked again once its debounce window is over
+        let timeout = pending
+            .as_ref()
+            .map_or(wakeups.idle_interval, |(_, since)| {
+                debounce
+                    .saturating_sub(since.elapsed())
+                    .min(wakeups.idle_interval)
+            });
+        if !wakeups.wait(timeout) {
+            break;
+        }
+
+        // An unreadable repository is checked again on the next poll
+        let Ok(current) = stamp(repository.as_ref(), env_name) else {
+            continue;
+        };
+
+        match pending {
+            Some((ref seen, since)) if *seen == current => {
+                if since.elapsed() < debounce {
+                    continue;
+                }
+
+                let event = match current {
+                    None => EnvironmentEvent::Removed,
+                    Some(_) => match handler.execute(env_name) {
+                        Ok(info) => EnvironmentEvent::Changed(Box::new(info)),
+                        Err(_) => {
+                            // The last write may not be followed by another one
+                            // (e.g. destroy): the load is retried after another
+                            // debounce window until it succeeds
+                            pending = Some((current, Instant::now()));
+                            continue;
+                        }
+                    },
+                };
+
+                pending = None;
+                delivered = current;
+                callback(event);
+            }
+            _ if current != delivered => pending = Some((current, Instant::now())),
+            _ => pending = None,
+        }
+    }
+}
@@ -1,4 +1,10 @@
-use torrust_tracker_deployer_sdk::Deployer;
+use std::sync::Arc;
+
+use chrono::{TimeZone, Utc};
+use tempfile::TempDir;
+use torrust_tracker_deployer_sdk::{Deployer, DeployerBuildError, WorkspaceError};
+
+use super::{create_environment, minimal_config, FixedClock};
 
 #[test]
 fn it_should_return_error_when_building_deployer_without_working_dir() {
@@ -6,3 +12,93 @@ fn it_should_return_error_when_building_deployer_without_working_dir() {
 
     assert!(result.is_err(), "expected MissingWorkingDir error");
 }
+
+#[test]
+fn it_should_refuse_to_build_a_deployer_for_an_uninitialized_directory() {
+    let workspace = TempDir::new().expect("Failed to create temp directory");
+
+    let result = Deployer::builder().working_dir(workspace.path()).build();
+
+    assert!(
+        matches!(
+            result,
+            Err(DeployerBuildError::Workspace(
+                WorkspaceError::NotInitialized { .. }
+            ))
+        ),
+        "expected NotInitialized error"
+    );
+}
+
+#[test]
+fn it_should_build_a_deployer_once_the_workspace_is_initialized() {
+    let workspace = TempDir::new().expect("Failed to create temp directory");
+    let deployer = Deployer::builder()
+        .working_dir(workspace.path())
+        .allow_uninitialized()
+        .build()
+        .expect("Failed to build deployer");
+
+    let first = deployer.init_workspace().expect("first init failed");
+    let second = deployer.init_workspace().expect("second init failed");
+
+    assert!(!first.already_initialized);
+    assert!(second.already_initialized);
+    assert!(workspace.path().join("build").is_dir());
+    assert!(Deployer::builder()
+        .working_dir(workspace.path())
+        .build()
+        .is_ok());
+}
+
+#[test]
+fn it_should_record_the_creation_time_from_the_injected_clock() {
+    let workspace = TempDir::new().expect("Failed to create temp directory");
+    let fixed_time = Utc.with_ymd_and_hms(2025, 6, 1, 12, 30, 0).unwrap();
+    let deployer = Deployer::builder()
+        .working_dir(workspace.path())
+        .clock(Arc::new(FixedClock(fixed_time)))
+        .allow_uninitialized()
+        .build()
+        .expect("Failed to build deployer");
+    deployer
+        .init_workspace()
+        .expect("Failed to initialize workspace");
+
+    let env_name = create_environment(&deployer, "sdk-test-fixed-clock");
+
+    let info = deployer.show(&env_name).expect("show failed");
+    assert_eq!(info.created_at, fixed_time);
+}
+
+#[test]
+fn it_should_store_environments_in_the_overridden_data_and_build_dirs() {
+    let workspace = TempDir::new().expect("Failed to create temp directory");
+    let data_dir = workspace.path().join("state");
+    let build_dir = workspace.path().join("artifacts");
+    let deployer = Deployer::builder()
+        .working_dir(workspace.path())
+        .data_dir(&data_dir)
+        .build_dir(&build_dir)
+        .allow_uninitialized()
+        .build()
+        .expect("Failed to build deployer");
+    deployer
+        .init_workspace()
+        .expect("Failed to initialize workspace");
+
+    let env_name = deployer
+        .create_environment(minimal_config("sdk-test-layout"))
+        .expect("create_environment failed");
+
+    let state_file = data_dir.join("sdk-test-layout").join("environment.json");
+    let state = std::fs::read_to_string(&state_file).expect("state file not in data_dir");
+    assert!(state.contains(
+        &build_dir
+            .join("sdk-test-layout")
+            .to_string_lossy()
+            .into_owned()
+    ));
+    assert!(!workspace.path().join("data").exists());
+    assert!(deployer.exists(&env_name).expect("exists() failed"));
+}
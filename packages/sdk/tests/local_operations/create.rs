@@ -1,6 +1,18 @@
-use torrust_tracker_deployer_sdk::CreateCommandHandlerError;
+use std::sync::Arc;
 
-use super::{create_environment, deployer_in_temp_dir, minimal_config, write_config_json};
+use torrust_tracker_deployer_lib::domain::environment::EnvironmentParams;
+use torrust_tracker_deployer_lib::domain::template::TemplateManager;
+use torrust_tracker_deployer_lib::infrastructure::templating::tracker::TrackerProjectGenerator;
+use torrust_tracker_deployer_lib::shared::SystemClock;
+use torrust_tracker_deployer_sdk::{
+    CreateCommandHandlerError, EnvironmentCreationConfig, EnvironmentCreationConfigBuildError,
+    IssueSeverity,
+};
+
+use super::{
+    create_environment, deployer_in_temp_dir, fixture_ssh_keys, minimal_config, write_config_json,
+    write_fixture_config_yaml,
+};
 
 #[test]
 fn it_should_create_and_show_an_environment() {
@@ -16,20 +28,35 @@ fn it_should_create_and_show_an_environment() {
 }
 
 #[test]
-fn it_should_return_error_when_creating_duplicate_environment() {
+fn it_should_succeed_when_creating_the_same_environment_again() {
     let (deployer, _workspace) = deployer_in_temp_dir();
 
     create_environment(&deployer, "sdk-test-dup");
 
-    let result = deployer.create_environment(minimal_config("sdk-test-dup"));
+    let env_name = deployer
+        .create_environment(minimal_config("sdk-test-dup"))
+        .expect("re-creating with the same configuration should succeed");
 
-    assert!(
-        matches!(
-            result,
-            Err(CreateCommandHandlerError::EnvironmentAlreadyExists { .. })
-        ),
-        "expected EnvironmentAlreadyExists, got: {result:?}"
-    );
+    assert_eq!(env_name.as_str(), "sdk-test-dup");
+}
+
+#[test]
+fn it_should_return_error_when_creating_an_existing_environment_with_another_configuration() {
+    let (deployer, _workspace) = deployer_in_temp_dir();
+
+    create_environment(&deployer, "sdk-test-dup");
+
+    let mut config = minimal_config("sdk-test-dup");
+    config.environment.instance_name = Some("other-instance".to_string());
+    let result = deployer.create_environment(config);
+
+    match result {
+        Err(CreateCommandHandlerError::EnvironmentConfigConflict { diff, .. }) => {
+            let paths: Vec<&str> = diff.changes().iter().map(|c| c.path.as_str()).collect();
+            assert_eq!(paths, vec!["environment.instance_name"]);
+        }
+        other => panic!("expected EnvironmentConfigConflict, got: {other:?}"),
+    }
 }
 
 #[test]
@@ -47,3 +74,139 @@ fn it_should_create_environment_from_json_file() {
     let info = deployer.show(&env_name).expect("show failed");
     assert_eq!(info.name, "sdk-test-from-file");
 }
+
+#[test]
+fn it_should_create_environment_from_yaml_file() {
+    let (deployer, workspace) = deployer_in_temp_dir();
+
+    let config_path = write_fixture_config_yaml(workspace.path(), "test-config.yaml");
+
+    let env_name = deployer
+        .create_environment_from_file(&config_path)
+        .expect("create_environment_from_file failed");
+
+    assert_eq!(env_name.as_str(), "sdk-test-from-yaml");
+
+    let info = deployer.show(&env_name).expect("show failed");
+    assert_eq!(info.provider, "LXD");
+}
+
+#[test]
+fn it_should_create_an_environment_with_every_tracker_added_with_the_builder() {
+    let (deployer, workspace) = deployer_in_temp_dir();
+    let (private_key, public_key) = fixture_ssh_keys();
+
+    let config = EnvironmentCreationConfig::builder()
+        .name("sdk-test-trackers")
+        .ssh_keys(private_key.to_string_lossy(), public_key.to_string_lossy())
+        .provider_lxd("torrust-sdk-test")
+        .sqlite("tracker.db")
+        .add_udp_tracker("0.0.0.0:6969")
+        .add_udp_tracker("0.0.0.0:6868")
+        .add_http_tracker("0.0.0.0:7070", None, false)
+        .api("0.0.0.0:1212", "MyAccessToken")
+        .build()
+        .expect("Failed to build config");
+
+    let udp_addresses: Vec<&str> = config
+        .tracker
+        .udp_trackers
+        .iter()
+        .map(|udp| udp.bind_address.as_str())
+        .collect();
+    assert_eq!(udp_addresses, ["0.0.0.0:6969", "0.0.0.0:6868"]);
+    assert_eq!(config.tracker.http_trackers.len(), 1);
+
+    // The SDK has no render operation: render the tracker configuration
+    // of the built config with the deployer library itself
+    let params = EnvironmentParams::try_from(config.clone()).expect("invalid config");
+    let build_dir = workspace.path().join("rendered");
+    let template_manager = Arc::new(TemplateManager::new(workspace.path().join("templates")));
+    TrackerProjectGenerator::new(&build_dir, template_manager, Arc::new(SystemClock))
+        .render(Some(&params.tracker_config))
+        .expect("Failed to render tracker.toml");
+    let tracker_toml = std::fs::read_to_string(build_dir.join("tracker/tracker.toml"))
+        .expect("Failed to read rendered tracker.toml");
+    assert!(
+        tracker_toml.contains(r#"bind_address = "0.0.0.0:6969""#),
+        "tracker.toml should contain the first UDP tracker:\n{tracker_toml}"
+    );
+    assert!(
+        tracker_toml.contains(r#"bind_address = "0.0.0.0:6868""#),
+        "tracker.toml should contain the second UDP tracker:\n{tracker_toml}"
+    );
+
+    let env_name = deployer
+        .create_environment(config)
+        .expect("create_environment failed");
+
+    assert_eq!(env_name.as_str(), "sdk-test-trackers");
+}
+
+#[test]
+fn it_should_reject_two_udp_trackers_on_the_same_socket_address() {
+    let (private_key, public_key) = fixture_ssh_keys();
+
+    let result = EnvironmentCreationConfig::builder()
+        .name("sdk-test-conflict")
+        .ssh_keys(private_key.to_string_lossy(), public_key.to_string_lossy())
+        .provider_lxd("torrust-sdk-test")
+        .sqlite("tracker.db")
+        .add_udp_tracker("0.0.0.0:6969")
+        .add_udp_tracker("0.0.0.0:6969")
+        .api("0.0.0.0:1212", "MyAccessToken")
+        .build();
+
+    assert!(
+        matches!(
+            result,
+            Err(EnvironmentCreationConfigBuildError::InvalidTracker(_))
+        ),
+        "expected InvalidTracker, got: {result:?}"
+    );
+}
+
+#[test]
+fn it_should_reject_a_tracker_bind_address_that_is_not_a_socket_address() {
+    let (private_key, public_key) = fixture_ssh_keys();
+
+    let result = EnvironmentCreationConfig::builder()
+        .name("sdk-test-bad-address")
+        .ssh_keys(private_key.to_string_lossy(), public_key.to_string_lossy())
+        .provider_lxd("torrust-sdk-test")
+        .sqlite("tracker.db")
+        .add_udp_tracker("not-an-address")
+        .api("0.0.0.0:1212", "MyAccessToken")
+        .build();
+
+    assert!(
+        matches!(
+            result,
+            Err(EnvironmentCreationConfigBuildError::InvalidSocketAddress { ref address, .. })
+                if address == "not-an-address"
+        ),
+        "expected InvalidSocketAddress, got: {result:?}"
+    );
+}
+
+#[test]
+fn it_should_report_every_missing_or_invalid_field_of_the_builder_at_once() {
+    let (private_key, public_key) = fixture_ssh_keys();
+
+    let result = EnvironmentCreationConfig::builder()
+        .name("sdk-test-problems")
+        .ssh_keys(private_key.to_string_lossy(), public_key.to_string_lossy())
+        .sqlite("tracker.db")
+        .add_udp_tracker("not-an-address")
+        .api("0.0.0.0:1212", "MyAccessToken")
+        .build();
+
+    let Err(EnvironmentCreationConfigBuildError::InvalidConfiguration { issues }) = result else {
+        panic!("expected InvalidConfiguration, got: {result:?}");
+    };
+    let paths: Vec<&str> = issues.iter().map(|issue| issue.path.as_str()).collect();
+    assert_eq!(paths, ["provider", "tracker.udp_trackers[0].bind_address"]);
+    assert!(issues
+        .iter()
+        .all(|issue| issue.severity == IssueSeverity::Error));
+}
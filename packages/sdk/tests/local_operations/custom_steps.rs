@@ -0,0 +1,168 @@
+use std::path::PathBuf;
+
+use tempfile::TempDir;
+use torrust_tracker_deployer_sdk::{
+    ConfigureCommandHandlerError, Deployer, DeployerBuilder, ProvisionStep, Step, StepContext,
+    StepError, StepFuture, StepOutcome,
+};
+
+use super::create_environment;
+#[cfg(unix)]
+use super::install_fake_infrastructure_tools;
+
+/// Writes the hook point it ran at to a marker file.
+struct WriteMarkerStep {
+    path: PathBuf,
+}
+
+impl Step for WriteMarkerStep {
+    fn name(&self) -> &str {
+        "write-marker"
+    }
+
+    fn execute<'a>(&'a self, context: &'a StepContext) -> StepFuture<'a> {
+        Box::pin(async move {
+            std::fs::write(&self.path, context.hook().to_string())
+                .map_err(|e| StepError::new("cannot write the marker").with_source(e))?;
+            Ok(StepOutcome::Completed)
+        })
+    }
+}
+
+/// Fails like a step registering the instance in an unreachable DNS API.
+struct FailingDnsStep;
+
+impl Step for FailingDnsStep {
+    fn name(&self) -> &str {
+        "register-dns"
+    }
+
+    fn execute<'a>(&'a self, _context: &'a StepContext) -> StepFuture<'a> {
+        Box::pin(async { Err(StepError::new("DNS API unreachable")) })
+    }
+}
+
+/// Build an initialized deployer with `configure` applied to its builder.
+fn deployer_with_steps(
+    configure: impl FnOnce(DeployerBuilder) -> DeployerBuilder,
+) -> (Deployer, TempDir) {
+    let workspace = TempDir::new().expect("Failed to create temp directory");
+    let deployer = configure(
+        Deployer::builder()
+            .working_dir(workspace.path())
+            .allow_uninitialized(),
+    )
+    .build()
+    .expect("Failed to build deployer");
+    deployer
+        .init_workspace()
+        .expect("Failed to initialize workspace");
+    (deployer, workspace)
+}
+
+#[cfg(unix)]
+#[tokio::test]
+async fn it_should_run_after_provision_steps_once_the_instance_is_provisioned() {
+    install_fake_infrastructure_tools();
+    let markers = TempDir::new().expect("Failed to create temp directory");
+    let marker = markers.path().join("after-provision");
+    let (deployer, _workspace) = deployer_with_steps(|builder| {
+        builder.after_provision(WriteMarkerStep {
+            path: marker.clone(),
+        })
+    });
+    let name = create_environment(&deployer, "sdk-custom-marker");
+
+    deployer.provision(&name).await.expect("provision failed");
+
+    assert_eq!(
+        std::fs::read_to_string(&marker).expect("the custom step should have run"),
+        "after_provision"
+    );
+    let info = deployer.show(&name).expect("show() failed");
+    assert_eq!(info.state, "Provisioned");
+}
+
+#[cfg(unix)]
+#[tokio::test]
+async fn it_should_fail_provisioning_at_the_failing_custom_step() {
+    install_fake_infrastructure_tools();
+    let (deployer, _workspace) =
+        deployer_with_steps(|builder| builder.after_provision(FailingDnsStep));
+    let name = create_environment(&deployer, "sdk-custom-failure");
+
+    deployer
+        .provision(&name)
+        .await
+        .expect_err("provision should fail");
+
+    let info = deployer.show(&name).expect("show() failed");
+    assert_eq!(info.state, "Provision Failed");
+    let failure = info.failure.expect("expected the failure details");
+    assert_eq!(failure.command, "provision");
+    assert_eq!(failure.failed_step, "custom:register-dns");
+
+    let report = deployer
+        .last_failure(&name)
+        .expect("last_failure() failed")
+        .expect("expected a failure report");
+    assert_eq!(
+        report.failed_step,
+        ProvisionStep::CustomStep("register-dns".to_string())
+    );
+    assert!(
+        report.base.error_summary.contains("DNS API unreachable"),
+        "expected the error of the step in the summary, got: {}",
+        report.base.error_summary
+    );
+}
+
+#[tokio::test]
+async fn it_should_not_run_after_provision_steps_when_provisioning_fails() {
+    let markers = TempDir::new().expect("Failed to create temp directory");
+    let marker = markers.path().join("after-provision");
+    let (deployer, workspace) = deployer_with_steps(|builder| {
+        builder.after_provision(WriteMarkerStep {
+            path: marker.clone(),
+        })
+    });
+    let name = create_environment(&deployer, "sdk-custom-steps");
+
+    // Occupy the build directory with a regular file so rendering the
+    // OpenTofu templates fails before the custom steps are reached.
+    std::fs::create_dir_all(workspace.path().join("build")).expect("create build dir");
+    std::fs::write(workspace.path().join("build").join(name.as_str()), "")
+        .expect("write blocking file");
+
+    deployer
+        .provision(&name)
+        .await
+        .expect_err("provision should fail");
+
+    let failure = deployer
+        .last_failure(&name)
+        .expect("last_failure() failed")
+        .expect("expected a failure report");
+    assert_eq!(failure.failed_step, ProvisionStep::RenderOpenTofuTemplates);
+    assert!(!marker.exists(), "the custom step should not have run");
+}
+
+#[test]
+fn it_should_not_run_configure_steps_for_an_environment_in_the_wrong_state() {
+    let markers = TempDir::new().expect("Failed to create temp directory");
+    let marker = markers.path().join("before-configure");
+    let (deployer, _workspace) = deployer_with_steps(|builder| {
+        builder.before_configure(WriteMarkerStep {
+            path: marker.clone(),
+        })
+    });
+    let name = create_environment(&deployer, "sdk-custom-configure");
+
+    let result = deployer.configure(&name);
+
+    assert!(
+        matches!(result, Err(ConfigureCommandHandlerError::InvalidState(_))),
+        "expected InvalidState, got: {result:?}"
+    );
+    assert!(!marker.exists(), "the custom step should not have run");
+}
@@ -0,0 +1,59 @@
+use torrust_tracker_deployer_sdk::{EnvironmentName, LastFailureCommandHandlerError};
+#[cfg(unix)]
+use torrust_tracker_deployer_sdk::{FailedStep, ProvisionStep};
+
+use super::{create_environment, deployer_in_temp_dir};
+#[cfg(unix)]
+use super::{install_fake_infrastructure_tools, APPLY_FAILS_SUFFIX};
+
+#[test]
+fn it_should_return_error_when_inspecting_non_existent_environment() {
+    let (deployer, _workspace) = deployer_in_temp_dir();
+
+    let name = EnvironmentName::new("does-not-exist").expect("invalid name");
+    let result = deployer.last_failure(&name);
+
+    assert!(
+        matches!(
+            result,
+            Err(LastFailureCommandHandlerError::EnvironmentNotFound { .. })
+        ),
+        "expected EnvironmentNotFound, got: {result:?}"
+    );
+}
+
+#[test]
+fn it_should_return_none_for_environment_without_failures() {
+    let (deployer, _workspace) = deployer_in_temp_dir();
+    let name = create_environment(&deployer, "sdk-test-no-failure");
+
+    let failure = deployer.last_failure(&name).expect("last_failure() failed");
+
+    assert!(failure.is_none(), "expected no failure, got: {failure:?}");
+}
+
+#[cfg(unix)]
+#[tokio::test]
+async fn it_should_report_the_failed_step_after_a_provision_failure() {
+    install_fake_infrastructure_tools();
+    let (deployer, _workspace) = deployer_in_temp_dir();
+    let name = create_environment(&deployer, &format!("sdk-test{APPLY_FAILS_SUFFIX}"));
+
+    deployer
+        .provision(&name)
+        .await
+        .expect_err("provision should fail");
+
+    let failure = deployer
+        .last_failure(&name)
+        .expect("last_failure() failed")
+        .expect("expected a failure report");
+
+    assert_eq!(failure.failed_step, ProvisionStep::OpenTofuApply);
+    assert!(matches!(failure.failed_step, FailedStep::Provision(_)));
+    assert!(
+        !failure.error_chain.is_empty(),
+        "expected the error chain to be read from the trace file"
+    );
+    assert_eq!(failure.error_chain[0].kind, failure.error_kind);
+}
@@ -1,4 +1,17 @@
+#[cfg(unix)]
+use std::sync::Arc;
+#[cfg(unix)]
+use std::time::Duration;
+
+#[cfg(unix)]
+use chrono::{TimeDelta, Utc};
+#[cfg(unix)]
+use torrust_tracker_deployer_sdk::{Deployer, ListSort};
+use torrust_tracker_deployer_sdk::{ListCommandHandlerError, ListFilter, ListFilterError};
+
 use super::{create_environment, deployer_in_temp_dir};
+#[cfg(unix)]
+use super::{install_fake_infrastructure_tools, FixedClock, APPLY_FAILS_SUFFIX};
 
 #[test]
 fn it_should_list_environments_in_workspace() {
@@ -33,3 +46,82 @@ fn it_should_return_empty_list_when_no_environments_exist() {
     assert_eq!(env_list.total_count, 0);
     assert!(env_list.is_empty());
 }
+
+#[test]
+fn it_should_list_only_environments_matching_the_filter() {
+    let (deployer, _workspace) = deployer_in_temp_dir();
+
+    create_environment(&deployer, "sdk-e2e-b");
+    create_environment(&deployer, "sdk-e2e-a");
+    create_environment(&deployer, "sdk-dev");
+
+    let filter = ListFilter::new()
+        .with_states(["created"])
+        .with_name_pattern("sdk-e2e-*");
+    let env_list = deployer.list_filtered(&filter).expect("list failed");
+
+    let names: Vec<&str> = env_list
+        .environments
+        .iter()
+        .map(|s| s.name.as_str())
+        .collect();
+    assert_eq!(names, ["sdk-e2e-a", "sdk-e2e-b"]);
+}
+
+#[cfg(unix)]
+#[tokio::test]
+async fn it_should_list_only_provision_failures_older_than_the_given_age() {
+    install_fake_infrastructure_tools();
+    let (deployer, workspace) = deployer_in_temp_dir();
+    let deployer_ten_days_ago = Deployer::builder()
+        .working_dir(workspace.path())
+        .clock(Arc::new(FixedClock(Utc::now() - TimeDelta::days(10))))
+        .build()
+        .expect("Failed to build deployer");
+
+    let stale_failure = create_environment(
+        &deployer_ten_days_ago,
+        &format!("sdk-stale{APPLY_FAILS_SUFFIX}"),
+    );
+    create_environment(&deployer_ten_days_ago, "sdk-stale-created");
+    let recent_failure = create_environment(&deployer, &format!("sdk-recent{APPLY_FAILS_SUFFIX}"));
+    for (deployer, name) in [
+        (&deployer_ten_days_ago, &stale_failure),
+        (&deployer, &recent_failure),
+    ] {
+        deployer
+            .provision(name)
+            .await
+            .expect_err("provision should fail");
+    }
+
+    let stale_failures = ListFilter::new()
+        .with_states(["provision_failed"])
+        .older_than(Duration::from_secs(3 * 24 * 60 * 60))
+        .sorted_by(ListSort::Created);
+    let env_list = deployer
+        .list_filtered(&stale_failures)
+        .expect("list failed");
+
+    let names: Vec<&str> = env_list
+        .environments
+        .iter()
+        .map(|s| s.name.as_str())
+        .collect();
+    assert_eq!(names, [stale_failure.as_str()]);
+}
+
+#[test]
+fn it_should_reject_filters_with_unknown_states() {
+    let (deployer, _workspace) = deployer_in_temp_dir();
+    create_environment(&deployer, "sdk-test-list-state");
+
+    let result = deployer.list_filtered(&ListFilter::new().with_states(["paused"]));
+
+    assert!(matches!(
+        result,
+        Err(ListCommandHandlerError::InvalidFilter(
+            ListFilterError::UnknownState { .. }
+        ))
+    ));
+}
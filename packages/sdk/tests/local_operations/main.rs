@@ -1,43 +1,63 @@
 //! SDK integration tests for local operations.
 //!
 //! These tests exercise the SDK public API exactly as an external consumer
-//! would — importing only from `torrust_tracker_deployer_sdk`. They cover
+//! would — importing from `torrust_tracker_deployer_sdk`, and from the
+//! deployer library only to check rendered templates, which the SDK does
+//! not expose. They cover
 //! local-only operations (create, show, list, exists, validate, destroy,
 //! purge) against a temporary workspace directory.
 //!
-//! No infrastructure (LXD, Docker, SSH) is required.
+//! No infrastructure (LXD, Docker, SSH) is required: tests that provision
+//! run against fake `tofu`, `ssh` and `ssh-keyscan` executables (see
+//! [`install_fake_infrastructure_tools`]).
 //!
 //! ## Structure
 //!
 //! One module per command, mirroring the CLI E2E tests in `tests/e2e/`:
 //!
-//! - `create` — create environment (typed builder + JSON file)
-//! - `show` — show environment details + not-found error
-//! - `list` — list environments (populated + empty workspace)
+//! - `create` — create environment (typed builder + JSON and YAML files) and builder validation
+//! - `show` — show environment details + not-found error + show all
+//! - `list` — list environments (populated + empty workspace + filtered by name, state and age)
 //! - `exists` — exists before/after create
+//! - `last_failure` — structured failure report (no failure + failure at `tofu apply`)
 //! - `validate` — validate config files (valid + invalid)
 //! - `destroy` — destroy a created environment
 //! - `purge` — purge environment completely
-//! - `builder` — `DeployerBuilder` error cases
+//! - `watch` — state change notifications (destroy + purge + `SQLite` repository + polling fallback + retried load)
+//! - `builder` — `DeployerBuilder` error cases, workspace initialization, injected clock and directories
+//! - `custom_steps` — custom steps registered on the builder (marker file, failing step, skipped steps)
 //! - `workflow` — chained operations (create → list → show → destroy → purge)
 
 mod builder;
 mod create;
+mod custom_steps;
 mod destroy;
 mod exists;
+mod last_failure;
 mod list;
 mod purge;
 mod show;
 mod validate;
+mod watch;
 mod workflow;
 
 use std::path::{Path, PathBuf};
 
+use chrono::{DateTime, Utc};
 use tempfile::TempDir;
-use torrust_tracker_deployer_sdk::{Deployer, EnvironmentCreationConfig, EnvironmentName};
+use torrust_tracker_deployer_sdk::{Clock, Deployer, EnvironmentCreationConfig, EnvironmentName};
 
 // ── Helpers ─────────────────────────────────────────────────────────
 
+/// A clock that always returns the same instant.
+struct FixedClock(DateTime<Utc>);
+
+impl Clock for FixedClock {
+    fn now(&self) -> DateTime<Utc> {
+        self.0
+    }
+}
+
 /// Absolute path to the repository root (two levels up from `packages/sdk/`).
 fn repo_root() -> PathBuf {
     PathBuf::from(env!("CARGO_MANIFEST_DIR"))
@@ -55,7 +75,7 @@ fn fixture_ssh_keys() -> (PathBuf, PathBuf) {
     )
 }
 
-/// Build a `Deployer` rooted in a fresh temp directory.
+/// Build a `Deployer` rooted in a fresh, initialized temp directory.
 ///
 /// Returns `(deployer, temp_dir)`. The `TempDir` must be kept alive for
 /// the duration of the test so the workspace is not deleted.
@@ -63,11 +83,77 @@ fn deployer_in_temp_dir() -> (Deployer, TempDir) {
     let workspace = TempDir::new().expect("Failed to create temp directory");
     let deployer = Deployer::builder()
         .working_dir(workspace.path())
+        .allow_uninitialized()
         .build()
         .expect("Failed to build deployer");
+    deployer
+        .init_workspace()
+        .expect("Failed to initialize workspace");
     (deployer, workspace)
 }
 
+/// Suffix of the environment names whose `tofu apply` fails with the fake tools
+#[cfg(unix)]
+const APPLY_FAILS_SUFFIX: &str = "-apply-fails";
+
+/// Put fake `tofu`, `ssh` and `ssh-keyscan` executables first on the `PATH`.
+///
+/// Provisioning then runs every step without touching any infrastructure:
+/// `tofu` reports an instance at `127.0.0.1` whose SSH port is a local
+/// listener, `ssh` answers every command as an instance that finished
+/// cloud-init, and `ssh-keyscan` reports one host key. `tofu apply` fails
+/// for environments whose name ends with [`APPLY_FAILS_SUFFIX`].
+///
+/// The `PATH` is shared by every test of this binary, so the tools are
+/// installed once and kept for the whole test process.
+#[cfg(unix)]
+fn install_fake_infrastructure_tools() {
+    use std::net::TcpListener;
+    use std::os::unix::fs::PermissionsExt;
+    use std::sync::OnceLock;
+
+    static TOOLS: OnceLock<(TempDir, TcpListener)> = OnceLock::new();
+
+    TOOLS.get_or_init(|| {
+        let bin = TempDir::new().expect("Failed to create temp directory");
+        let ssh_listener = TcpListener::bind("127.0.0.1:0").expect("bind fake SSH port");
+        let ssh_port = ssh_listener.local_addr().expect("fake SSH address").port();
+
+        let tools = [
+            (
+                "tofu",
+                format!(
+                    "#!/bin/sh\n\
+                     case \"$1\" in\n\
+                     apply) case \"$(pwd)\" in *{APPLY_FAILS_SUFFIX}/*) echo 'Error: apply failed' >&2; exit 1;; esac;;\n\
+                     output) echo '{{\"instance_info\":{{\"value\":{{\"image\":\"ubuntu:24.04\",\"ipv4_address\":\"127.0.0.1\",\"ipv6_address\":\"\",\"name\":\"fake\",\"status\":\"Running\",\"ssh_port\":{ssh_port}}}}}}}';;\n\
+                     esac\n"
+                ),
+            ),
+            (
+                "ssh",
+                "#!/bin/sh\necho 'boot-finished: yes'\necho 'status: done'\n".to_string(),
+            ),
+            (
+                "ssh-keyscan",
+                format!("#!/bin/sh\necho '[127.0.0.1]:{ssh_port} ssh-ed25519 AAAAC3NzaC1lZDI1NTE5AAAAIFakeHostKey'\n"),
+            ),
+        ];
+        for (name, script) in tools {
+            let path = bin.path().join(name);
+            std::fs::write(&path, script).expect("write fake tool");
+            std::fs::set_permissions(&path, std::fs::Permissions::from_mode(0o755))
+                .expect("make fake tool executable");
+        }
+
+        let path = std::env::var_os("PATH").unwrap_or_default();
+        let paths = std::iter::once(bin.path().to_path_buf()).chain(std::env::split_paths(&path));
+        std::env::set_var("PATH", std::env::join_paths(paths).expect("join PATH"));
+
+        (bin, ssh_listener)
+    });
+}
+
 /// Build a minimal `EnvironmentCreationConfig` with the given name.
 ///
 /// Uses the repository-root `fixtures/` SSH keys (absolute paths).
@@ -129,6 +215,21 @@ fn write_config_json(dir: &Path, filename: &str, env_name: &str) -> PathBuf {
     path
 }
 
+/// Copy the `fixtures/environment-config.yaml` config to `dir/{filename}`.
+///
+/// The fixture's `FIXTURES_DIR` placeholder is replaced with the absolute
+/// path of `fixtures/`, so the SSH key paths are absolute.
+fn write_fixture_config_yaml(dir: &Path, filename: &str) -> PathBuf {
+    let fixtures_dir = repo_root().join("fixtures");
+    let yaml = std::fs::read_to_string(fixtures_dir.join("environment-config.yaml"))
+        .expect("Failed to read YAML config fixture")
+        .replace("FIXTURES_DIR", &fixtures_dir.to_string_lossy());
+
+    let path = dir.join(filename);
+    std::fs::write(&path, yaml).expect("Failed to write config file");
+    path
+}
+
 // ── Custom asserts ──────────────────────────────────────────────────
 
 /// Assert that the named environment exists in the deployer workspace.
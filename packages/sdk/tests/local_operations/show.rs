@@ -1,6 +1,6 @@
 use torrust_tracker_deployer_sdk::{EnvironmentName, ShowCommandHandlerError};
 
-use super::deployer_in_temp_dir;
+use super::{create_environment, deployer_in_temp_dir};
 
 #[test]
 fn it_should_return_error_when_showing_non_existent_environment() {
@@ -17,3 +17,17 @@ fn it_should_return_error_when_showing_non_existent_environment() {
         "expected EnvironmentNotFound, got: {result:?}"
     );
 }
+
+#[test]
+fn it_should_show_all_environments_sorted_by_name() {
+    let (deployer, _workspace) = deployer_in_temp_dir();
+
+    create_environment(&deployer, "sdk-test-show-all-b");
+    create_environment(&deployer, "sdk-test-show-all-a");
+
+    let infos = deployer.show_all().expect("show_all failed");
+
+    let names: Vec<&str> = infos.iter().map(|info| info.name.as_str()).collect();
+    assert_eq!(names, ["sdk-test-show-all-a", "sdk-test-show-all-b"]);
+    assert!(infos.iter().all(|info| info.state_name == "created"));
+}
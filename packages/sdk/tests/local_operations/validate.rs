@@ -1,3 +1,5 @@
+use torrust_tracker_deployer_sdk::Deployer;
+
 use super::{deployer_in_temp_dir, write_config_json};
 
 #[test]
@@ -31,3 +33,11 @@ fn it_should_return_error_when_validating_invalid_json() {
         "expected validation error for invalid JSON"
     );
 }
+
+#[test]
+fn it_should_emit_the_environment_config_schema_without_a_workspace() {
+    let schema = Deployer::environment_config_schema().expect("schema generation failed");
+
+    assert!(schema.contains(r#""title": "EnvironmentCreationConfig""#));
+    assert!(schema.contains(r#""provider""#));
+}
@@ -0,0 +1,178 @@
+use std::sync::mpsc;
+use std::thread;
+use std::time::Duration;
+
+use tempfile::TempDir;
+use torrust_tracker_deployer_sdk::{Deployer, EnvironmentEvent, EnvironmentName};
+
+use super::{create_environment, deployer_in_temp_dir};
+
+/// Upper bound for an event to arrive, well above the debounce window
+const EVENT_TIMEOUT: Duration = Duration::from_secs(10);
+
+#[test]
+fn it_should_notify_the_destroyed_state_when_another_thread_destroys_the_environment() {
+    let (deployer, _workspace) = deployer_in_temp_dir();
+    let env_name = create_environment(&deployer, "sdk-test-watch");
+
+    let (sender, receiver) = mpsc::channel();
+    let watcher = deployer.watch(&env_name, move |event| {
+        sender.send(event).ok();
+    });
+
+    let destroyer = {
+        let deployer = deployer.clone();
+        let env_name = env_name.clone();
+        thread::spawn(move || deployer.destroy(&env_name))
+    };
+    destroyer
+        .join()
+        .expect("destroy thread panicked")
+        .expect("destroy failed");
+
+    let event = receiver
+        .recv_timeout(EVENT_TIMEOUT)
+        .expect("no state change received");
+    watcher.stop();
+
+    match event {
+        EnvironmentEvent::Changed(info) => assert_eq!(info.state_name, "destroyed"),
+        EnvironmentEvent::Removed => panic!("expected the destroyed state, got Removed"),
+    }
+}
+
+/// Build a `Deployer` rooted in a fresh workspace using the `SQLite` repository.
+fn sqlite_deployer_in_temp_dir() -> (Deployer, TempDir) {
+    let workspace = TempDir::new().expect("Failed to create temp directory");
+    std::fs::write(
+        workspace.path().join("deployer-workspace.json"),
+        r#"{ "format_version": 1, "repository": "sqlite" }"#,
+    )
+    .expect("Failed to write workspace marker");
+    let deployer = Deployer::builder()
+        .working_dir(workspace.path())
+        .build()
+        .expect("Failed to build deployer");
+    (deployer, workspace)
+}
+
+#[test]
+fn it_should_notify_the_destroyed_state_of_an_environment_stored_in_sqlite() {
+    let (deployer, workspace) = sqlite_deployer_in_temp_dir();
+    let env_name = create_environment(&deployer, "sdk-test-watch-sqlite");
+    assert!(workspace.path().join("data/workspace.db").exists());
+
+    let (sender, receiver) = mpsc::channel();
+    let watcher = deployer.watch(&env_name, move |event| {
+        sender.send(event).ok();
+    });
+
+    deployer.destroy(&env_name).expect("destroy failed");
+
+    let event = receiver
+        .recv_timeout(EVENT_TIMEOUT)
+        .expect("no state change received");
+    watcher.stop();
+
+    match event {
+        EnvironmentEvent::Changed(info) => assert_eq!(info.state_name, "destroyed"),
+        EnvironmentEvent::Removed => panic!("expected the destroyed state, got Removed"),
+    }
+}
+
+#[test]
+fn it_should_notify_an_environment_created_in_a_data_directory_that_did_not_exist_when_the_watch_started(
+) {
+    let workspace = TempDir::new().expect("Failed to create temp directory");
+    let data_dir = workspace.path().join("late-data");
+    let deployer = Deployer::builder()
+        .working_dir(workspace.path())
+        .data_dir(&data_dir)
+        .allow_uninitialized()
+        .build()
+        .expect("Failed to build deployer");
+    assert!(!data_dir.exists());
+
+    let env_name = EnvironmentName::new("sdk-test-watch-late-data").expect("valid name");
+    let (sender, receiver) = mpsc::channel();
+    let watcher = deployer.watch(&env_name, move |event| {
+        sender.send(event).ok();
+    });
+
+    create_environment(&deployer, env_name.as_str());
+
+    let event = receiver
+        .recv_timeout(EVENT_TIMEOUT)
+        .expect("no state change received");
+    watcher.stop();
+
+    match event {
+        EnvironmentEvent::Changed(info) => assert_eq!(info.state_name, "created"),
+        EnvironmentEvent::Removed => panic!("expected the created state, got Removed"),
+    }
+}
+
+#[test]
+fn it_should_notify_removal_when_the_environment_is_purged() {
+    let (deployer, _workspace) = deployer_in_temp_dir();
+    let env_name = create_environment(&deployer, "sdk-test-watch-purge");
+    deployer.destroy(&env_name).expect("destroy failed");
+
+    let (sender, receiver) = mpsc::channel();
+    let _watcher = deployer.watch(&env_name, move |event| {
+        sender.send(event).ok();
+    });
+
+    deployer.purge(&env_name).expect("purge failed");
+
+    let event = receiver
+        .recv_timeout(EVENT_TIMEOUT)
+        .expect("no removal received");
+    assert!(matches!(event, EnvironmentEvent::Removed));
+}
+
+#[test]
+fn it_should_deliver_a_change_whose_state_could_not_be_loaded_at_first_once_it_loads() {
+    let (deployer, workspace) = deployer_in_temp_dir();
+    let env_name = create_environment(&deployer, "sdk-test-watch-retry");
+    let state_file = workspace
+        .path()
+        .join("data")
+        .join(env_name.as_str())
+        .join("environment.json");
+    let state = std::fs::read(&state_file).expect("Failed to read state");
+
+    let debounce = Duration::from_millis(50);
+    let (sender, receiver) = mpsc::channel();
+    let watcher = deployer.watch_with_debounce(&env_name, debounce, move |event| {
+        sender.send(event).ok();
+    });
+
+    // A save the watcher cannot load: same length, but not JSON
+    let mut unreadable = state.clone();
+    unreadable[0] = b'x';
+    std::fs::write(&state_file, unreadable).expect("Failed to write state");
+    thread::sleep(debounce * 10);
+
+    // Fixing it without changing its length and modification time keeps the
+    // change stamp the watcher already saw
+    let modified = std::fs::metadata(&state_file)
+        .and_then(|metadata| metadata.modified())
+        .expect("Failed to read modification time");
+    std::fs::write(&state_file, state).expect("Failed to restore state");
+    std::fs::File::options()
+        .write(true)
+        .open(&state_file)
+        .and_then(|file| file.set_modified(modified))
+        .expect("Failed to restore modification time");
+
+    let event = receiver
+        .recv_timeout(EVENT_TIMEOUT)
+        .expect("the change was dropped after the failed load");
+    watcher.stop();
+
+    match event {
+        EnvironmentEvent::Changed(info) => assert_eq!(info.state_name, "created"),
+        EnvironmentEvent::Removed => panic!("expected the created state, got Removed"),
+    }
+}
@@ -0,0 +1,446 @@
+//! Parsing of the output of the `json` stdout callback of `ansible-playbook`
+//!
+//! The deployer runs every playbook with `ANSIBLE_STDOUT_CALLBACK=json`, so
+//! the standard output ends with one JSON document describing every task run
+//! on every host:
+//!
+//! ```text
+//! {
+//!     "plays": [
+//!         {
+//!             "play": { "name": "Install Docker" },
+//!             "tasks": [
+//!                 {
+//!                     "task": { "name": "Install docker-ce" },
+//!                     "hosts": {
+//!                         "torrust-tracker-vm": {
+//!                             "action": "ansible.builtin.apt",
+//!                             "failed": true,
+//!                             "msg": "No package matching 'docker-ce' is available"
+//!                         }
+//!                     }
+//!                 }
+//!             ]
+//!         }
+//!     ],
+//!     "stats": {
+//!         "torrust-tracker-vm": { "ok": 2, "changed": 0, "failures": 1, ... }
+//!     }
+//! }
+//! ```
+//!
+//! Aggregate callbacks enabled in `ansible.cfg` (e.g. `profile_tasks`) may
+//! print lines before and after the document; they are ignored.
+
+use std::collections::BTreeMap;
+use std::fmt;
+
+use serde::{Deserialize, Serialize};
+
+use super::recap::PlaybookRecap;
+
+/// Outcome of a task on one host
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TaskStatus {
+    /// The task ran without changing the host
+    Ok,
+    /// The task changed (or, in check mode, would change) the host
+    Changed,
+    /// The task was skipped by a condition
+    Skipped,
+    /// The task failed
+    Failed,
+    /// The host could not be reached
+    Unreachable,
+}
+
+impl fmt::Display for TaskStatus {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let status = match self {
+            Self::Ok => "ok",
+            Self::Changed => "changed",
+            Self::Skipped => "skipped",
+            Self::Failed => "failed",
+            Self::Unreachable => "unreachable",
+        };
+        write!(f, "{status}")
+    }
+}
+
+/// Result of one task on one host
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TaskResult {
+    /// Name of the task, as written in the playbook
+    pub name: String,
+    /// Module run by the task (e.g. `ansible.builtin.apt`)
+    pub module: String,
+    /// Inventory name of the host
+    pub host: String,
+    /// Outcome of the task on the host
+    pub status: TaskStatus,
+}
+
+impl fmt::Display for TaskResult {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}: {} [{}]", self.status, self.name, self.host)
+    }
+}
+
+/// The task that stopped a playbook run
+///
+/// Displayed as `task 'Install docker-ce' failed: <message>`.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct TaskFailure {
+    /// Name of the playbook (without `.yml`)
+    pub playbook: String,
+    /// Name of the failed task
+    pub task: String,
+    /// Module run by the failed task (e.g. `ansible.builtin.apt`)
+    pub module: String,
+    /// Inventory name of the host the task failed on
+    pub host: String,
+    /// Message reported by the module
+    pub message: String,
+    /// Standard error of the command run by the module, if any
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub stderr: Option<String>,
+}
+
+impl fmt::Display for TaskFailure {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "task '{}' failed: {}", self.task, self.message)
+    }
+}
+
+/// Tasks, failure and recap of one playbook run
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PlaybookOutput {
+    /// Result of every task on every host, in run order
+    pub tasks: Vec<TaskResult>,
+    /// The task that stopped the run, if it failed
+    pub failure: Option<TaskFailure>,
+    /// Task counters summed over all hosts
+    pub recap: PlaybookRecap,
+}
+
+impl PlaybookOutput {
+    /// Parse the JSON document printed by the `json` callback
+    ///
+    /// Returns `None` when the output has no such document, e.g. when the
+    /// playbook could not be started or another callback was configured.
+    ///
+    /// Failures of tasks with `ignore_errors` are reported by the callback
+    /// like real ones. They are listed in [`PlaybookOutput::tasks`], but only
+    /// a failure counted in the stats is reported as
+    /// [`PlaybookOutput::failure`]: since it stops the play, it is the last
+    /// failed task.
+    #[must_use]
+    pub fn parse(playbook: &str, stdout: &str) -> Option<Self> {
+        let start = if stdout.starts_with('{') {
+            0
+        } else {
+            stdout.find("\n{")? + 1
+        };
+
+        let document = serde_json::Deserializer::from_str(&stdout[start..])
+            .into_iter::<CallbackDocument>()
+            .next()?
+            .ok()?;
+
+        Some(Self::from_document(playbook, document))
+    }
+
+    /// One line per task, for error messages and logs
+    #[must_use]
+    pub fn to_text(&self) -> String {
+        let mut lines: Vec<String> = self.tasks.iter().map(ToString::to_string).collect();
+
+        if let Some(failure) = &self.failure {
+            lines.push(failure.to_string());
+            if let Some(stderr) = &failure.stderr {
+                lines.push(stderr.clone());
+            }
+        }
+
+        lines.join("\n")
+    }
+
+    fn from_document(playbook: &str, document: CallbackDocument) -> Self {
+        let mut tasks = Vec::new();
+        let mut failure = None;
+
+        for task in document.plays.into_iter().flat_map(|play| play.tasks) {
+            for (host, result) in task.hosts {
+                let status = result.status();
+
+                if matches!(status, TaskStatus::Failed | TaskStatus::Unreachable) {
+                    failure = Some(TaskFailure {
+                        playbook: playbook.to_string(),
+                        task: task.task.name.clone(),
+                        module: result.action.clone(),
+                        host: host.clone(),
+                        message: result.message(),
+                        stderr: result.stderr(),
+                    });
+                }
+
+                tasks.push(TaskResult {
+                    name: task.task.name.clone(),
+                    module: result.action,
+                    host,
+                    status,
+                });
+            }
+        }
+
+        let mut recap = PlaybookRecap {
+            playbook: playbook.to_string(),
+            ..PlaybookRecap::default()
+        };
+        for stats in document.stats.values() {
+            recap.ok += stats.ok;
+            recap.changed += stats.changed;
+            recap.failed += stats.failures;
+            recap.unreachable += stats.unreachable;
+            recap.skipped += stats.skipped;
+        }
+
+        if recap.failed == 0 && recap.unreachable == 0 {
+            failure = None;
+        }
+
+        Self {
+            tasks,
+            failure,
+            recap,
+        }
+    }
+}
+
+/// The JSON document printed by the `json` callback
+#[derive(Debug, Deserialize)]
+struct CallbackDocument {
+    #[serde(default)]
+    plays: Vec<CallbackPlay>,
+    #[serde(default)]
+    stats: BTreeMap<String, CallbackStats>,
+}
+
+#[derive(Debug, Deserialize)]
+struct CallbackPlay {
+    #[serde(default)]
+    tasks: Vec<CallbackTask>,
+}
+
+#[derive(Debug, Deserialize)]
+struct CallbackTask {
+    task: CallbackTaskInfo,
+    #[serde(default)]
+    hosts: BTreeMap<String, CallbackHostResult>,
+}
+
+#[derive(Debug, Deserialize)]
+struct CallbackTaskInfo {
+    name: String,
+}
+
+#[derive(Debug, Default, Deserialize)]
+#[serde(default)]
+struct CallbackHostResult {
+    action: String,
+    changed: bool,
+    failed: bool,
+    skipped: bool,
+    unreachable: bool,
+    msg: Option<serde_json::Value>,
+    stderr: Option<String>,
+    /// Per-item results of a task with a loop
+    results: Vec<CallbackHostResult>,
+}
+
+impl CallbackHostResult {
+    fn status(&self) -> TaskStatus {
+        if self.unreachable {
+            TaskStatus::Unreachable
+        } else if self.failed {
+            TaskStatus::Failed
+        } else if self.skipped {
+            TaskStatus::Skipped
+        } else if self.changed {
+            TaskStatus::Changed
+        } else {
+            TaskStatus::Ok
+        }
+    }
+
+    /// The message of the result, or of its first failed item
+    fn message(&self) -> String {
+        let item_message = || self.failed_item().and_then(|item| item.msg.as_ref());
+
+        match self.msg.as_ref().or_else(item_message) {
+            Some(serde_json::Value::String(message)) => message.clone(),
+            Some(message) => message.to_string(),
+            None => "no message reported by the module".to_string(),
+        }
+    }
+
+    /// The standard error of the result, or of its first failed item
+    fn stderr(&self) -> Option<String> {
+        self.stderr
+            .clone()
+            .or_else(|| self.failed_item().and_then(|item| item.stderr.clone()))
+            .filter(|stderr| !stderr.trim().is_empty())
+    }
+
+    fn failed_item(&self) -> Option<&Self> {
+        self.results.iter().find(|item| item.failed)
+    }
+}
+
+#[derive(Debug, Default, Deserialize)]
+#[serde(default)]
+struct CallbackStats {
+    ok: u32,
+    changed: u32,
+    failures: u32,
+    unreachable: u32,
+    skipped: u32,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const FAILED_RUN: &str = r#"Using /build/tmp/ansible/ansible.cfg as config file
+{
+    "custom_stats": {},
+    "global_custom_stats": {},
+    "plays": [
+        {
+            "play": { "id": "1", "name": "Install Docker" },
+            "tasks": [
+                {
+                    "task": { "id": "2", "name": "Gathering Facts" },
+                    "hosts": {
+                        "torrust-tracker-vm": { "action": "gather_facts", "changed": false }
+                    }
+                },
+                {
+                    "task": { "id": "3", "name": "Add Docker repository" },
+                    "hosts": {
+                        "torrust-tracker-vm": { "action": "ansible.builtin.apt_repository", "changed": true }
+                    }
+                },
+                {
+                    "task": { "id": "4", "name": "Install docker-ce" },
+                    "hosts": {
+                        "torrust-tracker-vm": {
+                            "action": "ansible.builtin.apt",
+                            "changed": false,
+                            "failed": true,
+                            "msg": "No package matching 'docker-ce' is available",
+                            "stderr": ""
+                        }
+                    }
+                }
+            ]
+        }
+    ],
+    "stats": {
+        "torrust-tracker-vm": {
+            "changed": 1, "failures": 1, "ignored": 0, "ok": 2,
+            "rescued": 0, "skipped": 0, "unreachable": 0
+        }
+    }
+}
+Playbook run took 0 days, 0 hours, 0 minutes, 12 seconds
+"#;
+
+    #[test]
+    fn it_should_attribute_the_failure_to_the_failed_task() {
+        let output = PlaybookOutput::parse("install-docker", FAILED_RUN).unwrap();
+
+        let failure = output.failure.unwrap();
+        assert_eq!(failure.task, "Install docker-ce");
+        assert_eq!(failure.module, "ansible.builtin.apt");
+        assert_eq!(failure.host, "torrust-tracker-vm");
+        assert_eq!(failure.stderr, None);
+        assert_eq!(
+            failure.to_string(),
+            "task 'Install docker-ce' failed: No package matching 'docker-ce' is available"
+        );
+    }
+
+    #[test]
+    fn it_should_list_every_task_with_its_status() {
+        let output = PlaybookOutput::parse("install-docker", FAILED_RUN).unwrap();
+
+        let statuses: Vec<(&str, TaskStatus)> = output
+            .tasks
+            .iter()
+            .map(|task| (task.name.as_str(), task.status))
+            .collect();
+        assert_eq!(
+            statuses,
+            vec![
+                ("Gathering Facts", TaskStatus::Ok),
+                ("Add Docker repository", TaskStatus::Changed),
+                ("Install docker-ce", TaskStatus::Failed),
+            ]
+        );
+    }
+
+    #[test]
+    fn it_should_sum_the_stats_into_the_recap() {
+        let output = PlaybookOutput::parse("install-docker", FAILED_RUN).unwrap();
+
+        assert_eq!(
+            output.recap,
+            PlaybookRecap {
+                playbook: "install-docker".to_string(),
+                ok: 2,
+                changed: 1,
+                failed: 1,
+                unreachable: 0,
+                skipped: 0,
+            }
+        );
+    }
+
+    #[test]
+    fn it_should_report_the_first_failed_item_of_a_loop() {
+        let stdout = r#"{
+    "plays": [{ "tasks": [{
+        "task": { "name": "Install packages" },
+        "hosts": { "vm": {
+            "action": "ansible.builtin.command",
+            "failed": true,
+            "results": [
+                { "failed": false, "msg": "" },
+                { "failed": true, "msg": "non-zero return code", "stderr": "E: Unable to locate package" }
+            ]
+        } }
+    }] }],
+    "stats": { "vm": { "ok": 0, "failures": 1 } }
+}"#;
+
+        let failure = PlaybookOutput::parse("install-docker", stdout)
+            .unwrap()
+            .failure
+            .unwrap();
+
+        assert_eq!(failure.message, "non-zero return code");
+        assert_eq!(
+            failure.stderr.as_deref(),
+            Some("E: Unable to locate package")
+        );
+    }
+
+    #[test]
+    fn it_should_return_none_when_the_output_is_not_json() {
+        assert!(
+            PlaybookOutput::parse("install-docker", "ERROR! the playbook could not be found")
+                .is_none()
+        );
+    }
+}
@@ -8,16 +8,47 @@
 //! - Playbook execution with inventory management
 //! - Ad-hoc command execution on remote hosts
 //! - Working directory management for Ansible projects
+//! - Tag selection and check (dry-run) mode via [`AnsiblePlaybookOptions`]
+//! - Playbook runs bounded by [`CommandTimeouts::ansible_playbook`]
+//! - Per-playbook task counters parsed from the `PLAY RECAP` ([`PlaybookRecap`])
+//! - Per-task results and failure attribution parsed from the `json` stdout
+//!   callback ([`PlaybookOutput`], [`TaskFailure`])
+//! - Warnings and errors forwarded line by line while a playbook runs
+//!   ([`AnsibleClient::run_playbook_with_output`])
+//! - Complete output of each playbook kept in the run log
+//!   ([`AnsibleClient::with_run_log`])
+//! - Extra environment variables for the playbooks, e.g. an outbound proxy
+//!   ([`AnsibleClient::with_env_vars`])
+//! - Playbooks run again when the host became unreachable because of a
+//!   network failure ([`AnsibleClient::with_retry_policy`])
 //! - Comprehensive error handling and logging
 //!
 //! The client handles the complexity of Ansible command construction and provides
 //! a clean API for common configuration management tasks.
 
+pub mod json_output;
+pub mod options;
+pub mod recap;
+
 use std::path::{Path, PathBuf};
+use std::sync::{Mutex, PoisonError};
+
+use tracing::{info, warn};
 
-use tracing::info;
+pub use json_output::{PlaybookOutput, TaskFailure, TaskResult, TaskStatus};
+pub use options::AnsiblePlaybookOptions;
+pub use recap::PlaybookRecap;
 
-use crate::shared::command::{CommandError, CommandExecutor};
+use crate::adapters::ssh::{transient_reason, SshRetryHandler, SshRetryPolicy};
+use crate::shared::command::{
+    CommandError, CommandExecutor, CommandTimeouts, OutputLineHandler, OutputStream, RunLog,
+};
+
+/// Environment variable selecting the stdout callback of `ansible-playbook`
+///
+/// It takes precedence over the `stdout_callback` of the rendered
+/// `ansible.cfg`, which stays human-readable for manual runs.
+const STDOUT_CALLBACK_VAR: &str = "ANSIBLE_STDOUT_CALLBACK";
 
 /// A specialized `Ansible` client for configuration management.
 /// This client provides a consistent interface for `Ansible` operations:
@@ -29,6 +60,14 @@ use crate::shared::command::{CommandError, CommandExecutor};
 pub struct AnsibleClient {
     working_dir: PathBuf,
     command_executor: CommandExecutor,
+    options: AnsiblePlaybookOptions,
+    timeouts: CommandTimeouts,
+    retry_policy: SshRetryPolicy,
+    run_log: Option<RunLog>,
+    attempts: Mutex<u32>,
+    recaps: Mutex<Vec<PlaybookRecap>>,
+    task_results: Mutex<Vec<TaskResult>>,
+    failed_task: Mutex<Option<TaskFailure>>,
 }
 
 impl AnsibleClient {
@@ -41,10 +80,61 @@ impl AnsibleClient {
     pub fn new<P: Into<PathBuf>>(working_dir: P) -> Self {
         Self {
             working_dir: working_dir.into(),
-            command_executor: CommandExecutor::new(),
+            command_executor: CommandExecutor::new().with_env(STDOUT_CALLBACK_VAR, "json"),
+            options: AnsiblePlaybookOptions::default(),
+            timeouts: CommandTimeouts::default(),
+            retry_policy: SshRetryPolicy::default(),
+            run_log: None,
+            attempts: Mutex::new(0),
+            recaps: Mutex::new(Vec::new()),
+            task_results: Mutex::new(Vec::new()),
+            failed_task: Mutex::new(None),
         }
     }
 
+    /// Bound each playbook run with the given timeouts
+    #[must_use]
+    pub fn with_timeouts(mut self, timeouts: CommandTimeouts) -> Self {
+        self.timeouts = timeouts;
+        self
+    }
+
+    /// Run a playbook again when a network failure made the host unreachable
+    ///
+    /// Playbooks are idempotent, so the tasks that already ran are only
+    /// checked again. Failed tasks and authentication failures are not
+    /// retried.
+    #[must_use]
+    pub fn with_retry_policy(mut self, retry_policy: SshRetryPolicy) -> Self {
+        self.retry_policy = retry_policy;
+        self
+    }
+
+    /// Write the output of each playbook to the log file named after it in the given run log
+    #[must_use]
+    pub fn with_run_log(mut self, run_log: RunLog) -> Self {
+        self.run_log = Some(run_log);
+        self
+    }
+
+    /// Set environment variables of every playbook run by this client (e.g. a proxy)
+    ///
+    /// They are added to the environment inherited from the deployer.
+    #[must_use]
+    pub fn with_env_vars(mut self, vars: impl IntoIterator<Item = (String, String)>) -> Self {
+        for (key, value) in vars {
+            self.command_executor = self.command_executor.with_env(key, value);
+        }
+        self
+    }
+
+    /// Pass tag selection and check mode to every playbook run by this client
+    #[must_use]
+    pub fn with_options(mut self, options: AnsiblePlaybookOptions) -> Self {
+        self.options = options;
+        self
+    }
+
     /// Run an Ansible playbook with optional extra arguments
     ///
     /// # Arguments
@@ -52,10 +142,17 @@ impl AnsibleClient {
     /// * `playbook` - Name of the playbook file (without .yml extension)
     /// * `extra_args` - Optional extra arguments to pass to ansible-playbook
     ///
+    /// The client's [`AnsiblePlaybookOptions`] are appended to the arguments.
+    /// The playbook runs with the `json` stdout callback; the results of its
+    /// tasks are recorded (see [`AnsibleClient::drain_task_results`]), as are
+    /// the recap of a successful run (see [`AnsibleClient::recaps`]) and the
+    /// task that stopped a failed one (see [`AnsibleClient::failed_task`]).
+    ///
     /// # Returns
     ///
-    /// * `Ok(String)` - The stdout output if the command succeeds
-    /// * `Err(CommandError)` - Error describing what went wrong
+    /// * `Ok(String)` - The stdout output (the JSON document) if the command succeeds
+    /// * `Err(CommandError)` - Error describing what went wrong; its stdout
+    ///   lists one task per line instead of the JSON document
     ///
     /// # Errors
     ///
@@ -63,6 +160,7 @@ impl AnsibleClient {
     /// * The Ansible playbook execution fails
     /// * The playbook file does not exist in the working directory
     /// * There are issues with the inventory or configuration
+    /// * The playbook runs longer than its timeout (`CommandError::TimedOut`)
     ///
     /// # Examples
     ///
@@ -80,6 +178,62 @@ impl AnsibleClient {
         playbook: &str,
         extra_args: &[&str],
     ) -> Result<String, CommandError> {
+        self.run_playbook_with_output(playbook, extra_args, &|_, _| {}, &|_| {})
+    }
+
+    /// Run an Ansible playbook like [`AnsibleClient::run_playbook`], forwarding its stderr lines
+    ///
+    /// Every output line is logged as `ansible-playbook` writes it. Only the
+    /// stderr lines (warnings, connection errors) are passed to `on_output`:
+    /// the `json` stdout callback writes the whole JSON document once the
+    /// playbook is over, and its tasks are reported from the parsed results.
+    ///
+    /// When a network failure makes the host unreachable the playbook is run
+    /// again, following the retry policy of the client; `on_retry` is told
+    /// about each retry. See [`AnsibleClient::attempts`].
+    ///
+    /// # Errors
+    ///
+    /// Same as [`AnsibleClient::run_playbook`].
+    pub fn run_playbook_with_output(
+        &self,
+        playbook: &str,
+        extra_args: &[&str],
+        on_output: OutputLineHandler<'_>,
+        on_retry: SshRetryHandler<'_>,
+    ) -> Result<String, CommandError> {
+        self.retry_policy
+            .run(
+                |attempt| {
+                    *self.attempts.lock().unwrap_or_else(PoisonError::into_inner) = attempt;
+                    self.run_playbook_once(playbook, extra_args, on_output)
+                },
+                |(_, unreachable)| unreachable.clone(),
+                &|retry| {
+                    warn!(
+                        playbook = playbook,
+                        failed_attempt = retry.failed_attempt,
+                        max_attempts = retry.max_attempts,
+                        delay_secs = retry.delay.as_secs(),
+                        reason = %retry.reason,
+                        "Host unreachable, running the playbook again"
+                    );
+                    on_retry(retry);
+                },
+            )
+            .map_err(|(error, _)| error)
+    }
+
+    /// Run an Ansible playbook once
+    ///
+    /// A failed run is returned with the network error that made the host
+    /// unreachable, if retrying may fix it.
+    fn run_playbook_once(
+        &self,
+        playbook: &str,
+        extra_args: &[&str],
+        on_output: OutputLineHandler<'_>,
+    ) -> Result<String, (CommandError, Option<String>)> {
         info!(
             "Running Ansible playbook '{}' in directory: {}",
             playbook,
@@ -88,15 +242,196 @@ impl AnsibleClient {
 
         let playbook_file = format!("{playbook}.yml");
 
-        // Build command arguments: -v flag + playbook + extra args
+        let option_args = self.options.to_args();
+
+        // Build command arguments: -v flag + playbook + extra args + options
         let mut args = vec!["-v", &playbook_file];
         args.extend_from_slice(extra_args);
+        args.extend(option_args.iter().map(String::as_str));
+
+        *self
+            .failed_task
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner) = None;
+
+        let logged;
+        let executor = match &self.run_log {
+            Some(run_log) => {
+                logged = self
+                    .command_executor
+                    .clone()
+                    .with_run_log(run_log.clone(), playbook);
+                &logged
+            }
+            None => &self.command_executor,
+        };
+
+        let result = executor.run_command_streaming(
+            "ansible-playbook",
+            &args,
+            Some(&self.working_dir),
+            self.timeouts.budget(self.timeouts.ansible_playbook),
+            &|stream, line| {
+                if stream == OutputStream::Stderr {
+                    on_output(stream, line);
+                }
+            },
+        );
+
+        let stdout = match result {
+            Ok(result) => result.stdout,
+            Err(error) => return Err(self.record_failed_run(playbook, error)),
+        };
 
-        // Use -v flag for verbose output showing task progress
-        // This helps track progress during long-running operations like Docker installation
-        self.command_executor
-            .run_command("ansible-playbook", &args, Some(&self.working_dir))
-            .map(|result| result.stdout)
+        let output = PlaybookOutput::parse(playbook, &stdout);
+        let recap = output.as_ref().map_or_else(
+            || PlaybookRecap::parse(playbook, &stdout),
+            |o| o.recap.clone(),
+        );
+        info!(
+            playbook = playbook,
+            changed = recap.changed,
+            check = self.options.is_check(),
+            "Ansible playbook finished"
+        );
+        if let Some(output) = output {
+            self.record_task_results(output.tasks);
+        }
+        self.recaps
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner)
+            .push(recap);
+
+        Ok(stdout)
+    }
+
+    /// Record the tasks and the failed task of a failed run
+    ///
+    /// The JSON document in the stdout of the error is replaced with one line
+    /// per task, so error messages and traces stay readable. The error is
+    /// returned with the network error that made the host unreachable, if
+    /// retrying may fix it.
+    fn record_failed_run(
+        &self,
+        playbook: &str,
+        error: CommandError,
+    ) -> (CommandError, Option<String>) {
+        let (CommandError::ExecutionFailed { stdout, .. } | CommandError::TimedOut { stdout, .. }) =
+            &error
+        else {
+            return (error, None);
+        };
+
+        let Some(output) = PlaybookOutput::parse(playbook, stdout) else {
+            return (error, None);
+        };
+
+        let text = output.to_text();
+        let unreachable = output
+            .failure
+            .as_ref()
+            .filter(|_| output.recap.unreachable > 0)
+            .and_then(|failure| transient_reason(&failure.message));
+
+        if let Some(failure) = &output.failure {
+            warn!(
+                playbook = playbook,
+                task = %failure.task,
+                module = %failure.module,
+                host = %failure.host,
+                "Ansible task failed: {}",
+                failure.message
+            );
+        }
+        *self
+            .failed_task
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner) = output.failure;
+        self.record_task_results(output.tasks);
+
+        let error = match error {
+            CommandError::ExecutionFailed {
+                command,
+                exit_code,
+                stderr,
+                ..
+            } => CommandError::ExecutionFailed {
+                command,
+                exit_code,
+                stdout: text,
+                stderr,
+            },
+            CommandError::TimedOut {
+                command,
+                timeout,
+                stderr,
+                ..
+            } => CommandError::TimedOut {
+                command,
+                timeout,
+                stdout: text,
+                stderr,
+            },
+            other => other,
+        };
+
+        (error, unreachable)
+    }
+
+    fn record_task_results(&self, tasks: Vec<TaskResult>) {
+        self.task_results
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner)
+            .extend(tasks);
+    }
+
+    /// Results of the tasks run since the previous call, in run order
+    ///
+    /// Lets callers report the tasks of each playbook as it finishes.
+    #[must_use]
+    pub fn drain_task_results(&self) -> Vec<TaskResult> {
+        std::mem::take(
+            &mut *self
+                .task_results
+                .lock()
+                .unwrap_or_else(PoisonError::into_inner),
+        )
+    }
+
+    /// The task that stopped the last playbook run, if it failed in a task
+    ///
+    /// `None` after a successful run, or when the run failed before any
+    /// task (e.g. `ansible-playbook` could not be started).
+    #[must_use]
+    pub fn failed_task(&self) -> Option<TaskFailure> {
+        self.failed_task
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner)
+            .clone()
+    }
+
+    /// Number of times the last playbook was run
+    ///
+    /// Greater than one when the host became unreachable and the playbook
+    /// was run again; `0` before the first playbook.
+    #[must_use]
+    pub fn attempts(&self) -> u32 {
+        *self.attempts.lock().unwrap_or_else(PoisonError::into_inner)
+    }
+
+    /// Recaps of the playbooks run successfully by this client, in run order
+    #[must_use]
+    pub fn recaps(&self) -> Vec<PlaybookRecap> {
+        self.recaps
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner)
+            .clone()
+    }
+
+    /// The tag selection and check mode applied to every playbook run
+    #[must_use]
+    pub fn options(&self) -> &AnsiblePlaybookOptions {
+        &self.options
     }
 
     /// Get the working directory path
@@ -163,4 +498,44 @@ mod tests {
         // But this confirms the method signature and basic functionality works
         assert!(result.is_err());
     }
+
+    fn unreachable_run(message: &str) -> CommandError {
+        let stdout = format!(
+            r#"{{
+    "plays": [{{ "tasks": [{{
+        "task": {{ "name": "Gathering Facts" }},
+        "hosts": {{ "torrust-tracker-vm": {{ "unreachable": true, "msg": "{message}" }} }}
+    }}] }}],
+    "stats": {{ "torrust-tracker-vm": {{ "ok": 0, "changed": 0, "failures": 0, "unreachable": 1 }} }}
+}}"#
+        );
+
+        CommandError::ExecutionFailed {
+            command: "ansible-playbook -v install-docker.yml".to_string(),
+            exit_code: "4".to_string(),
+            stdout,
+            stderr: String::new(),
+        }
+    }
+
+    #[test]
+    fn it_should_retry_a_run_whose_host_was_unreachable_because_of_the_network() {
+        let client = AnsibleClient::new("/test/path");
+        let message =
+            "Failed to connect to the host via ssh: Connection reset by 10.140.190.14 port 22";
+
+        let (_, unreachable) = client.record_failed_run("install-docker", unreachable_run(message));
+
+        assert_eq!(unreachable.as_deref(), Some(message));
+    }
+
+    #[test]
+    fn it_should_not_retry_a_run_whose_host_refused_the_key() {
+        let client = AnsibleClient::new("/test/path");
+        let message = "Failed to connect to the host via ssh: torrust@10.140.190.14: Permission denied (publickey).";
+
+        let (_, unreachable) = client.record_failed_run("install-docker", unreachable_run(message));
+
+        assert_eq!(unreachable, None);
+    }
 }
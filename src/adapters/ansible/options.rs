@@ -0,0 +1,137 @@
+//! Execution controls passed through to `ansible-playbook`
+
+/// Options controlling which tasks `ansible-playbook` runs and whether it
+/// applies changes
+///
+/// Every playbook shipped by the deployer tags its play with the component it
+/// configures (`docker`, `docker-compose`, `security-updates`, `firewall`,
+/// `tracker`, `prometheus`, `grafana`, `mysql`, `backup`, `caddy`, `compose`),
+/// so `--tags docker` restricts a run to the Docker installation.
+///
+/// # Examples
+///
+/// ```rust
+/// use torrust_tracker_deployer_lib::adapters::ansible::AnsiblePlaybookOptions;
+///
+/// let options = AnsiblePlaybookOptions::default()
+///     .with_tags(vec!["docker".to_string()])
+///     .with_check(true);
+///
+/// assert_eq!(options.to_args(), vec!["--tags", "docker", "--check", "--diff"]);
+/// ```
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct AnsiblePlaybookOptions {
+    tags: Vec<String>,
+    skip_tags: Vec<String>,
+    check: bool,
+}
+
+impl AnsiblePlaybookOptions {
+    /// Only run tasks tagged with one of the given tags (`--tags`)
+    #[must_use]
+    pub fn with_tags(mut self, tags: Vec<String>) -> Self {
+        self.tags = tags;
+        self
+    }
+
+    /// Skip tasks tagged with one of the given tags (`--skip-tags`)
+    #[must_use]
+    pub fn with_skip_tags(mut self, skip_tags: Vec<String>) -> Self {
+        self.skip_tags = skip_tags;
+        self
+    }
+
+    /// Report what would change without applying it (`--check --diff`)
+    #[must_use]
+    pub fn with_check(mut self, check: bool) -> Self {
+        self.check = check;
+        self
+    }
+
+    /// Tags selected with `--tags`
+    #[must_use]
+    pub fn tags(&self) -> &[String] {
+        &self.tags
+    }
+
+    /// Tags excluded with `--skip-tags`
+    #[must_use]
+    pub fn skip_tags(&self) -> &[String] {
+        &self.skip_tags
+    }
+
+    /// Whether `--tags` or `--skip-tags` restrict the run to some tasks
+    #[must_use]
+    pub fn is_tag_filtered(&self) -> bool {
+        !self.tags.is_empty() || !self.skip_tags.is_empty()
+    }
+
+    /// Whether the playbooks run in check (dry-run) mode
+    #[must_use]
+    pub fn is_check(&self) -> bool {
+        self.check
+    }
+
+    /// Build the `ansible-playbook` arguments for these options
+    #[must_use]
+    pub fn to_args(&self) -> Vec<String> {
+        let mut args = Vec::new();
+
+        if !self.tags.is_empty() {
+            args.push("--tags".to_string());
+            args.push(self.tags.join(","));
+        }
+
+        if !self.skip_tags.is_empty() {
+            args.push("--skip-tags".to_string());
+            args.push(self.skip_tags.join(","));
+        }
+
+        if self.check {
+            args.push("--check".to_string());
+            args.push("--diff".to_string());
+        }
+
+        args
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn it_should_produce_no_arguments_by_default() {
+        assert!(AnsiblePlaybookOptions::default().to_args().is_empty());
+    }
+
+    #[test]
+    fn it_should_be_tag_filtered_when_tags_are_selected_or_skipped() {
+        assert!(!AnsiblePlaybookOptions::default()
+            .with_check(true)
+            .is_tag_filtered());
+        assert!(AnsiblePlaybookOptions::default()
+            .with_tags(vec!["docker".to_string()])
+            .is_tag_filtered());
+        assert!(AnsiblePlaybookOptions::default()
+            .with_skip_tags(vec!["firewall".to_string()])
+            .is_tag_filtered());
+    }
+
+    #[test]
+    fn it_should_join_multiple_tags_with_commas() {
+        let options = AnsiblePlaybookOptions::default()
+            .with_tags(vec!["docker".to_string(), "firewall".to_string()])
+            .with_skip_tags(vec!["security-updates".to_string()]);
+
+        assert_eq!(
+            options.to_args(),
+            vec![
+                "--tags",
+                "docker,firewall",
+                "--skip-tags",
+                "security-updates"
+            ]
+        );
+    }
+}
@@ -0,0 +1,118 @@
+//! Parsing of the `PLAY RECAP` summary printed by `ansible-playbook`
+
+/// Task counters of one playbook run, summed over all hosts
+///
+/// Parsed from the `PLAY RECAP` section at the end of the `ansible-playbook`
+/// output:
+///
+/// ```text
+/// PLAY RECAP *********************************************************
+/// torrust-tracker-vm : ok=5 changed=2 unreachable=0 failed=0 skipped=1 rescued=0 ignored=0
+/// ```
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct PlaybookRecap {
+    /// Name of the playbook (without `.yml`)
+    pub playbook: String,
+    /// Tasks that ran without changes
+    pub ok: u32,
+    /// Tasks that changed (or, in check mode, would change) the host
+    pub changed: u32,
+    /// Tasks that failed
+    pub failed: u32,
+    /// Hosts that could not be reached
+    pub unreachable: u32,
+    /// Tasks skipped by conditions or tag selection
+    pub skipped: u32,
+}
+
+impl PlaybookRecap {
+    /// Parse the recap of a playbook run from its standard output
+    ///
+    /// Counters are zero when the output has no `PLAY RECAP` section.
+    #[must_use]
+    pub fn parse(playbook: &str, stdout: &str) -> Self {
+        let mut recap = Self {
+            playbook: playbook.to_string(),
+            ..Self::default()
+        };
+
+        let host_lines = stdout
+            .lines()
+            .skip_while(|line| !line.starts_with("PLAY RECAP"))
+            .skip(1)
+            .take_while(|line| !line.trim().is_empty());
+
+        for line in host_lines {
+            let Some((_, counters)) = line.split_once(':') else {
+                continue;
+            };
+
+            for counter in counters.split_whitespace() {
+                let Some((name, value)) = counter.split_once('=') else {
+                    continue;
+                };
+                let value: u32 = value.parse().unwrap_or(0);
+
+                match name {
+                    "ok" => recap.ok += value,
+                    "changed" => recap.changed += value,
+                    "failed" => recap.failed += value,
+                    "unreachable" => recap.unreachable += value,
+                    "skipped" => recap.skipped += value,
+                    _ => {}
+                }
+            }
+        }
+
+        recap
+    }
+
+    /// Whether the run changed (or, in check mode, would change) anything
+    #[must_use]
+    pub fn has_changes(&self) -> bool {
+        self.changed > 0
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn it_should_parse_the_play_recap_counters() {
+        let stdout = "\
+PLAY [Install Docker] **********************************************************
+
+TASK [Gathering Facts] *********************************************************
+ok: [torrust-tracker-vm]
+
+PLAY RECAP *********************************************************************
+torrust-tracker-vm         : ok=5    changed=2    unreachable=0    failed=0    skipped=1    rescued=0    ignored=0
+
+";
+
+        let recap = PlaybookRecap::parse("install-docker", stdout);
+
+        assert_eq!(
+            recap,
+            PlaybookRecap {
+                playbook: "install-docker".to_string(),
+                ok: 5,
+                changed: 2,
+                failed: 0,
+                unreachable: 0,
+                skipped: 1,
+            }
+        );
+        assert!(recap.has_changes());
+    }
+
+    #[test]
+    fn it_should_return_zero_counters_when_there_is_no_recap() {
+        let recap =
+            PlaybookRecap::parse("install-docker", "ERROR! the playbook could not be found");
+
+        assert_eq!(recap.changed, 0);
+        assert!(!recap.has_changes());
+    }
+}
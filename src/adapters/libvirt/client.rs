@@ -0,0 +1,203 @@
+//! `virsh` client for libvirt domain queries
+//!
+//! This module provides the `VirshClient` which wraps `virsh` to read the
+//! addresses of a libvirt domain and to check that the libvirt daemon answers.
+//!
+//! ## Instance Addresses
+//!
+//! The `OpenTofu` libvirt provider reports the addresses of a domain only once
+//! its DHCP lease is in the state, which is not always the case right after
+//! the domain is created. `virsh domifaddr --source lease` reads the lease
+//! from the libvirt network instead.
+
+use std::net::Ipv4Addr;
+use std::path::Path;
+use std::time::Duration;
+
+use anyhow::{Context, Result};
+use tracing::info;
+
+use crate::shared::command::CommandExecutor;
+
+/// Unix sockets of the system libvirt daemon
+///
+/// The monolithic `libvirtd` listens on the first one and the modular
+/// `virtqemud` (the default on Fedora) on the second one.
+pub const LIBVIRT_SOCKETS: [&str; 2] = [
+    "/var/run/libvirt/libvirt-sock",
+    "/var/run/libvirt/virtqemud-sock",
+];
+
+/// A specialized `virsh` client for libvirt domain queries.
+///
+/// Every command is run against the daemon given by the connection URI
+/// (e.g. `qemu:///system`).
+///
+/// Uses `CommandExecutor` as a collaborator for actual command execution.
+pub struct VirshClient {
+    command_executor: CommandExecutor,
+    uri: String,
+}
+
+impl VirshClient {
+    /// Creates a new `VirshClient` connected to the daemon at `uri`
+    #[must_use]
+    pub fn new(uri: impl Into<String>) -> Self {
+        Self {
+            command_executor: CommandExecutor::new(),
+            uri: uri.into(),
+        }
+    }
+
+    /// Connection URI of the libvirt daemon
+    #[must_use]
+    pub fn uri(&self) -> &str {
+        &self.uri
+    }
+
+    /// Whether a socket of the system libvirt daemon exists on this host
+    #[must_use]
+    pub fn system_socket_exists() -> bool {
+        LIBVIRT_SOCKETS
+            .iter()
+            .any(|socket| Path::new(socket).exists())
+    }
+
+    /// Get the version of the libvirt daemon
+    ///
+    /// Unlike `virsh --version`, which only needs the client, this connects
+    /// to the daemon, so it fails when the daemon is not running or the user
+    /// is not allowed to use it.
+    ///
+    /// # Arguments
+    ///
+    /// * `timeout` - Maximum time to wait for the daemon to answer
+    ///
+    /// # Errors
+    ///
+    /// This function will return an error if:
+    /// * The daemon cannot be reached within `timeout`
+    /// * `virsh` is not installed
+    pub fn server_version(&self, timeout: Duration) -> Result<String> {
+        info!(uri = %self.uri, "Querying libvirt daemon version");
+
+        let output = self
+            .command_executor
+            .run_command_with_timeout("virsh", &["--connect", &self.uri, "version"], None, timeout)
+            .map_err(anyhow::Error::from)
+            .context("Failed to execute virsh version command")?;
+
+        Self::parse_server_version(&output.stdout)
+            .with_context(|| format!("Unexpected output of virsh version: {}", output.stdout))
+    }
+
+    /// Get the IPv4 address leased to a domain by its libvirt network
+    ///
+    /// # Arguments
+    ///
+    /// * `domain` - Name of the libvirt domain
+    ///
+    /// # Returns
+    /// * `Ok(Some(Ipv4Addr))` - The leased IPv4 address
+    /// * `Ok(None)` - The domain has no IPv4 lease yet
+    /// * `Err(anyhow::Error)` - Error describing what went wrong
+    ///
+    /// # Errors
+    ///
+    /// This function will return an error if:
+    /// * The `virsh` command fails (e.g. the domain does not exist)
+    /// * `virsh` is not installed or the daemon is not accessible
+    pub fn domain_ipv4_address(&self, domain: &str) -> Result<Option<Ipv4Addr>> {
+        info!(domain = %domain, "Reading the DHCP lease of the libvirt domain");
+
+        let output = self
+            .command_executor
+            .run_command(
+                "virsh",
+                &[
+                    "--connect",
+                    &self.uri,
+                    "domifaddr",
+                    domain,
+                    "--source",
+                    "lease",
+                ],
+                None,
+            )
+            .map_err(anyhow::Error::from)
+            .context("Failed to execute virsh domifaddr command")?;
+
+        Ok(Self::parse_domifaddr_ipv4(&output.stdout))
+    }
+
+    /// Parse the first IPv4 address of a `virsh domifaddr` table
+    ///
+    /// ```text
+    ///  Name       MAC address          Protocol     Address
+    /// -------------------------------------------------------------------------------
+    ///  vnet0      52:54:00:6b:3c:58    ipv4         192.168.122.45/24
+    /// ```
+    #[must_use]
+    pub fn parse_domifaddr_ipv4(output: &str) -> Option<Ipv4Addr> {
+        output.lines().find_map(|line| {
+            let fields: Vec<&str> = line.split_whitespace().collect();
+            let protocol_index = fields.iter().position(|field| *field == "ipv4")?;
+            let address = fields.get(protocol_index + 1)?;
+            address.split('/').next()?.parse().ok()
+        })
+    }
+
+    /// Parse the daemon version of a `virsh version` output
+    ///
+    /// ```text
+    /// Compiled against library: libvirt 10.1.0
+    /// Using library: libvirt 10.1.0
+    /// Using API: QEMU 10.1.0
+    /// Running hypervisor: QEMU 8.2.2
+    /// ```
+    fn parse_server_version(output: &str) -> Option<String> {
+        output
+            .lines()
+            .find_map(|line| line.trim().strip_prefix("Using library: libvirt "))
+            .map(|version| version.trim().to_string())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn it_should_parse_the_ipv4_address_of_a_domifaddr_table() {
+        let output = " Name       MAC address          Protocol     Address\n\
+-------------------------------------------------------------------------------\n \
+vnet0      52:54:00:6b:3c:58    ipv6         fe80::5054:ff:fe6b:3c58/64\n \
+-          -                    ipv4         192.168.122.45/24\n";
+
+        assert_eq!(
+            VirshClient::parse_domifaddr_ipv4(output),
+            Some(Ipv4Addr::new(192, 168, 122, 45))
+        );
+    }
+
+    #[test]
+    fn it_should_return_none_when_the_domain_has_no_lease_yet() {
+        let output = " Name       MAC address          Protocol     Address\n\
+-------------------------------------------------------------------------------\n\n";
+
+        assert_eq!(VirshClient::parse_domifaddr_ipv4(output), None);
+    }
+
+    #[test]
+    fn it_should_parse_the_version_of_the_libvirt_daemon() {
+        let output = "Compiled against library: libvirt 10.1.0\n\
+Using library: libvirt 10.1.0\n\
+Using API: QEMU 10.1.0\n\
+Running hypervisor: QEMU 8.2.2\n";
+
+        assert_eq!(
+            VirshClient::parse_server_version(output).as_deref(),
+            Some("10.1.0")
+        );
+    }
+}
@@ -0,0 +1,17 @@
+//! Libvirt virtual machine management wrapper
+//!
+//! This module provides a small interface to the system libvirt daemon
+//! through `virsh`, for the queries the `OpenTofu` libvirt provider does not
+//! answer reliably.
+//!
+//! ## Module Structure
+//!
+//! - `client` - `VirshClient` for executing `virsh` commands and parsing their output
+//!
+//! The deployer creates and destroys libvirt domains with `OpenTofu`; `virsh`
+//! is only used to read the DHCP lease of a domain and to check that the
+//! daemon is reachable.
+
+pub mod client;
+
+pub use client::{VirshClient, LIBVIRT_SOCKETS};
@@ -13,8 +13,17 @@
 //!
 //! The client abstracts the complexity of LXD command-line interaction and provides
 //! type-safe APIs for common instance management tasks.
+//!
+//! ## Remote LXD Servers
+//!
+//! By default the client talks to the local LXD daemon. A client created with
+//! [`LxdClient::with_remote`] prefixes every instance and profile with the
+//! remote name (`bigbox:torrust-tracker-vm-prod`), so the same queries run
+//! against another LXD server configured in the LXD client.
 
 use std::net::IpAddr;
+use std::path::Path;
+use std::time::Duration;
 
 use anyhow::{Context, Result};
 use tracing::info;
@@ -35,6 +44,7 @@ use super::json_parser::LxdJsonParser;
 /// Uses `CommandExecutor` as a collaborator for actual command execution.
 pub struct LxdClient {
     command_executor: CommandExecutor,
+    remote: Option<String>,
 }
 
 impl Default for LxdClient {
@@ -49,6 +59,37 @@ impl LxdClient {
     pub fn new() -> Self {
         Self {
             command_executor: CommandExecutor::new(),
+            remote: None,
+        }
+    }
+
+    /// Run every query against the LXD server known to the client as `remote`
+    #[must_use]
+    pub fn with_remote(mut self, remote: impl Into<String>) -> Self {
+        self.remote = Some(remote.into());
+        self
+    }
+
+    /// Read the LXD client configuration (remotes and client certificate) from `config_dir`
+    #[must_use]
+    pub fn with_config_dir(mut self, config_dir: &Path) -> Self {
+        self.command_executor = self
+            .command_executor
+            .with_env("LXD_CONF", config_dir.display().to_string());
+        self
+    }
+
+    /// Name of the remote the client queries, if not the local daemon
+    #[must_use]
+    pub fn remote(&self) -> Option<&str> {
+        self.remote.as_deref()
+    }
+
+    /// Prefix `name` with the remote, as expected by `lxc` (`remote:name`)
+    fn target(&self, name: &str) -> String {
+        match &self.remote {
+            Some(remote) => format!("{remote}:{name}"),
+            None => name.to_string(),
         }
     }
 
@@ -113,7 +154,7 @@ impl LxdClient {
         timeout_seconds: u64,
         poll_interval_seconds: u64,
     ) -> Result<IpAddr> {
-        use std::time::{Duration, Instant};
+        use std::time::Instant;
 
         info!(
             "Waiting for instance '{}' to get IP address (timeout: {}s, poll interval: {}s)",
@@ -174,6 +215,44 @@ impl LxdClient {
             .find(|inst| inst.name.as_str() == instance_name.as_str()))
     }
 
+    /// Get a configuration value of an instance, including the ones set by its profiles
+    ///
+    /// # Arguments
+    ///
+    /// * `instance_name` - Name of the instance
+    /// * `key` - Configuration key (e.g. `limits.memory`)
+    ///
+    /// # Returns
+    /// * `Ok(Some(String))` - The configured value
+    /// * `Ok(None)` - The key is not set
+    /// * `Err(anyhow::Error)` - Error describing what went wrong
+    ///
+    /// # Errors
+    ///
+    /// This function will return an error if:
+    /// * The LXD command fails (e.g. the instance does not exist)
+    /// * LXD is not installed or accessible
+    pub fn get_instance_config_value(
+        &self,
+        instance_name: &InstanceName,
+        key: &str,
+    ) -> Result<Option<String>> {
+        info!(
+            "Getting configuration '{}' of instance: {}",
+            key, instance_name
+        );
+
+        let target = self.target(instance_name.as_str());
+        let output = self
+            .command_executor
+            .run_command("lxc", &["config", "get", "--expanded", &target, key], None)
+            .map_err(anyhow::Error::from)
+            .context("Failed to execute lxc config get command")?;
+
+        let value = output.stdout.trim();
+        Ok((!value.is_empty()).then(|| value.to_string()))
+    }
+
     /// List instances in JSON format
     ///
     /// # Arguments
@@ -193,11 +272,17 @@ impl LxdClient {
     fn list(&self, instance_name: Option<&InstanceName>) -> Result<Vec<InstanceInfo>> {
         info!("Listing LXD instances");
 
-        let mut args = vec!["list", "--format=json"];
+        let filter = match instance_name {
+            Some(name) => {
+                info!("Filtering by instance name: {}", name);
+                Some(self.target(name.as_str()))
+            }
+            None => self.remote.as_ref().map(|_| self.target("")),
+        };
 
-        if let Some(name) = instance_name {
-            args.push(name.as_str());
-            info!("Filtering by instance name: {}", name);
+        let mut args = vec!["list", "--format=json"];
+        if let Some(filter) = &filter {
+            args.push(filter);
         }
 
         let output = self
@@ -209,6 +294,165 @@ impl LxdClient {
         LxdJsonParser::parse_instances_json(&output.stdout)
     }
 
+    /// List the names of all LXD instances
+    ///
+    /// Unlike the instance lookups, names are not validated as [`InstanceName`],
+    /// so hosts with instances created by other tools can still be listed.
+    ///
+    /// # Errors
+    ///
+    /// This function will return an error if:
+    /// * The LXD command fails
+    /// * LXD is not installed or accessible
+    /// * JSON parsing fails
+    pub fn list_instance_names(&self) -> Result<Vec<String>> {
+        info!("Listing LXD instance names");
+
+        let remote = self.target("");
+        let output = self
+            .command_executor
+            .run_command("lxc", &["list", &remote, "--format=json"], None)
+            .map_err(anyhow::Error::from)
+            .context("Failed to execute lxc list command")?;
+
+        LxdJsonParser::parse_names_json(&output.stdout)
+    }
+
+    /// Get the version of the LXD daemon
+    ///
+    /// Unlike `lxc --version`, which only needs the client, this queries the
+    /// daemon, so it fails when the daemon is not running or the user is not
+    /// allowed to use it. A remote server that does not trust the client
+    /// certificate is reported as an error too.
+    ///
+    /// # Arguments
+    ///
+    /// * `timeout` - Maximum time to wait for the daemon to answer
+    ///
+    /// # Errors
+    ///
+    /// This function will return an error if:
+    /// * The daemon cannot be reached within `timeout`
+    /// * LXD is not installed or accessible
+    /// * JSON parsing fails
+    pub fn server_version(&self, timeout: Duration) -> Result<String> {
+        info!("Querying LXD server version");
+
+        let output = self
+            .command_executor
+            .run_command_with_timeout("lxc", &["query", &self.target("/1.0")], None, timeout)
+            .map_err(anyhow::Error::from)
+            .context("Failed to execute lxc query command")?;
+
+        LxdJsonParser::parse_server_version(&output.stdout)
+    }
+
+    /// Get the free space, in bytes, of an LXD storage pool
+    ///
+    /// The space is the `total - used` reported by the pool resources, so it
+    /// is what new instance disks can still take.
+    ///
+    /// # Arguments
+    ///
+    /// * `pool` - Name of the storage pool (e.g. `default`)
+    /// * `timeout` - Maximum time to wait for the daemon to answer
+    ///
+    /// # Errors
+    ///
+    /// This function will return an error if:
+    /// * The daemon cannot be reached within `timeout`
+    /// * The storage pool does not exist
+    /// * JSON parsing fails
+    pub fn storage_pool_free_space(&self, pool: &str, timeout: Duration) -> Result<u64> {
+        info!(pool = %pool, "Querying LXD storage pool resources");
+
+        let path = self.target(&format!("/1.0/storage-pools/{pool}/resources"));
+        let output = self
+            .command_executor
+            .run_command_with_timeout("lxc", &["query", &path], None, timeout)
+            .map_err(anyhow::Error::from)
+            .context("Failed to execute lxc query command")?;
+
+        LxdJsonParser::parse_storage_pool_free_space(&output.stdout)
+    }
+
+    /// List the names of all LXD profiles
+    ///
+    /// # Errors
+    ///
+    /// This function will return an error if:
+    /// * The LXD command fails
+    /// * LXD is not installed or accessible
+    /// * JSON parsing fails
+    pub fn list_profile_names(&self) -> Result<Vec<String>> {
+        info!("Listing LXD profile names");
+
+        let remote = self.target("");
+        let output = self
+            .command_executor
+            .run_command("lxc", &["profile", "list", &remote, "--format=json"], None)
+            .map_err(anyhow::Error::from)
+            .context("Failed to execute lxc profile list command")?;
+
+        LxdJsonParser::parse_names_json(&output.stdout)
+    }
+
+    /// List the names of the remotes configured in the LXD client
+    ///
+    /// # Errors
+    ///
+    /// This function will return an error if:
+    /// * The LXD command fails
+    /// * LXD is not installed or accessible
+    /// * JSON parsing fails
+    pub fn list_remote_names(&self) -> Result<Vec<String>> {
+        info!("Listing LXD remotes");
+
+        let output = self
+            .command_executor
+            .run_command("lxc", &["remote", "list", "--format=json"], None)
+            .map_err(anyhow::Error::from)
+            .context("Failed to execute lxc remote list command")?;
+
+        LxdJsonParser::parse_remote_names_json(&output.stdout)
+    }
+
+    /// Add the remote of this client to the LXD client configuration
+    ///
+    /// The trust token issued on the server (`lxc config trust add`) holds
+    /// the addresses and certificate fingerprint of the server; adding the
+    /// remote with it makes the server trust the client certificate. The
+    /// token never appears in logs.
+    ///
+    /// # Errors
+    ///
+    /// This function will return an error if:
+    /// * The client has no remote
+    /// * The server cannot be reached or rejects the token
+    /// * LXD is not installed or accessible
+    pub fn add_remote(&self, trust_token: &str, timeout: Duration) -> Result<()> {
+        let remote = self
+            .remote
+            .as_deref()
+            .ok_or_else(|| anyhow::anyhow!("No LXD remote to add"))?;
+
+        info!("Adding LXD remote: {}", remote);
+
+        self.command_executor
+            .clone()
+            .with_redacted(trust_token)
+            .run_command_with_timeout(
+                "lxc",
+                &["remote", "add", remote, trust_token],
+                None,
+                timeout,
+            )
+            .map_err(anyhow::Error::from)
+            .context(format!("Failed to add LXD remote '{remote}'"))?;
+
+        Ok(())
+    }
+
     /// Delete an LXD instance
     ///
     /// # Arguments
@@ -228,7 +472,8 @@ impl LxdClient {
     pub fn delete_instance(&self, instance_name: &InstanceName, force: bool) -> Result<()> {
         info!("Deleting LXD instance: {}", instance_name);
 
-        let mut args = vec!["delete", instance_name.as_str()];
+        let target = self.target(instance_name.as_str());
+        let mut args = vec!["delete", &target];
         if force {
             args.push("--force");
         }
@@ -276,7 +521,8 @@ impl LxdClient {
     pub fn delete_profile(&self, profile_name: &str) -> Result<()> {
         info!("Deleting LXD profile: {}", profile_name);
 
-        let args = vec!["profile", "delete", profile_name];
+        let target = self.target(profile_name);
+        let args = vec!["profile", "delete", &target];
 
         let result = self.command_executor.run_command("lxc", &args, None);
 
@@ -320,6 +566,23 @@ mod tests {
         // Client should be created successfully using Default trait
     }
 
+    #[test]
+    fn it_should_prefix_names_with_the_remote() {
+        let local = LxdClient::new();
+        let remote = LxdClient::new().with_remote("bigbox");
+
+        assert_eq!(
+            local.target("torrust-tracker-vm-e2e"),
+            "torrust-tracker-vm-e2e"
+        );
+        assert_eq!(
+            remote.target("torrust-tracker-vm-e2e"),
+            "bigbox:torrust-tracker-vm-e2e"
+        );
+        assert_eq!(remote.target("/1.0"), "bigbox:/1.0");
+        assert_eq!(remote.target(""), "bigbox:");
+    }
+
     #[test]
     fn it_should_return_none_when_instance_not_found() {
         let _client = LxdClient::new();
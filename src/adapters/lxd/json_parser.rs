@@ -64,6 +64,112 @@ impl LxdJsonParser {
         Ok(result)
     }
 
+    /// Parse the `name` fields from JSON output of `lxc list` or `lxc profile list`
+    ///
+    /// Names are returned as plain strings without validation, so resources
+    /// not created by the deployer (which may not be valid instance names)
+    /// can still be listed.
+    ///
+    /// # Arguments
+    ///
+    /// * `json_output` - JSON array returned by the lxc command
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(Vec<String>)` - Names of all listed resources
+    /// * `Err(anyhow::Error)` - JSON parsing error
+    pub fn parse_names_json(json_output: &str) -> Result<Vec<String>> {
+        let resources: Value =
+            serde_json::from_str(json_output).context("Failed to parse lxc output as JSON")?;
+
+        resources
+            .as_array()
+            .ok_or_else(|| anyhow!("Expected JSON array from lxc"))?
+            .iter()
+            .map(|resource| {
+                resource["name"]
+                    .as_str()
+                    .map(ToString::to_string)
+                    .ok_or_else(|| anyhow!("Resource missing name field"))
+            })
+            .collect()
+    }
+
+    /// Parse the remote names from JSON output of `lxc remote list`
+    ///
+    /// # Arguments
+    ///
+    /// * `json_output` - JSON object keyed by remote name
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(Vec<String>)` - Names of the configured remotes, sorted
+    /// * `Err(anyhow::Error)` - JSON parsing error
+    pub fn parse_remote_names_json(json_output: &str) -> Result<Vec<String>> {
+        let remotes: Value =
+            serde_json::from_str(json_output).context("Failed to parse lxc output as JSON")?;
+
+        let mut names: Vec<String> = remotes
+            .as_object()
+            .ok_or_else(|| anyhow!("Expected JSON object from lxc remote list"))?
+            .keys()
+            .cloned()
+            .collect();
+        names.sort();
+
+        Ok(names)
+    }
+
+    /// Parse the server version from JSON output of `lxc query /1.0`
+    ///
+    /// # Arguments
+    ///
+    /// * `json_output` - JSON object describing the LXD server
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(String)` - The `environment.server_version` of the server
+    /// * `Err(anyhow::Error)` - JSON parsing error, missing version or a
+    ///   server that does not trust the client
+    pub fn parse_server_version(json_output: &str) -> Result<String> {
+        let server: Value =
+            serde_json::from_str(json_output).context("Failed to parse lxc output as JSON")?;
+
+        // An untrusted client only sees the public part of the server info
+        if let Some(auth) = server["auth"].as_str().filter(|auth| *auth != "trusted") {
+            return Err(anyhow!(
+                "the LXD server does not trust the client certificate (auth: {auth})"
+            ));
+        }
+
+        server["environment"]["server_version"]
+            .as_str()
+            .map(ToString::to_string)
+            .ok_or_else(|| anyhow!("Server info missing environment.server_version field"))
+    }
+
+    /// Parse the free space from JSON output of `lxc query /1.0/storage-pools/<pool>/resources`
+    ///
+    /// # Arguments
+    ///
+    /// * `json_output` - JSON object describing the resources of the pool
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(u64)` - The free space of the pool in bytes (`space.total - space.used`)
+    /// * `Err(anyhow::Error)` - JSON parsing error or missing space fields
+    pub fn parse_storage_pool_free_space(json_output: &str) -> Result<u64> {
+        let resources: Value =
+            serde_json::from_str(json_output).context("Failed to parse lxc output as JSON")?;
+
+        let total = resources["space"]["total"]
+            .as_u64()
+            .ok_or_else(|| anyhow!("Storage pool resources missing space.total field"))?;
+        let used = resources["space"]["used"].as_u64().unwrap_or(0);
+
+        Ok(total.saturating_sub(used))
+    }
+
     /// Extract IPv4 address from instance JSON data
     ///
     /// # Arguments
@@ -178,6 +284,64 @@ mod tests {
         assert!(instances[0].ip_address.is_none());
     }
 
+    #[test]
+    fn it_should_parse_resource_names_including_non_deployer_names() {
+        let mock_json = r#"[
+            {"name": "torrust-tracker-vm-e2e-provision", "status": "Running"},
+            {"name": "Other_Container", "status": "Stopped"}
+        ]"#;
+
+        let names = LxdJsonParser::parse_names_json(mock_json).unwrap();
+
+        assert_eq!(
+            names,
+            ["torrust-tracker-vm-e2e-provision", "Other_Container"]
+        );
+    }
+
+    #[test]
+    fn it_should_parse_the_server_version() {
+        let json = r#"{"api_version": "1.0", "environment": {"server_version": "5.21.3", "driver": "lxc | qemu"}}"#;
+
+        let version = LxdJsonParser::parse_server_version(json).unwrap();
+
+        assert_eq!(version, "5.21.3");
+    }
+
+    #[test]
+    fn it_should_fail_when_the_server_version_is_missing() {
+        let result = LxdJsonParser::parse_server_version(r#"{"api_version": "1.0"}"#);
+
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn it_should_fail_when_the_server_does_not_trust_the_client() {
+        let json = r#"{"api_version": "1.0", "auth": "untrusted", "public": false}"#;
+
+        let error = LxdJsonParser::parse_server_version(json).unwrap_err();
+
+        assert!(error.to_string().contains("does not trust"));
+    }
+
+    #[test]
+    fn it_should_parse_the_free_space_of_a_storage_pool() {
+        let json = r#"{"inodes": {"used": 12, "total": 0}, "space": {"used": 3221225472, "total": 32212254720}}"#;
+
+        let free = LxdJsonParser::parse_storage_pool_free_space(json).unwrap();
+
+        assert_eq!(free, 27 * 1024 * 1024 * 1024);
+    }
+
+    #[test]
+    fn it_should_parse_the_remote_names() {
+        let json = r#"{"local": {"addr": "unix://", "protocol": "lxd"}, "bigbox": {"addr": "https://bigbox.lan:8443", "protocol": "lxd"}}"#;
+
+        let names = LxdJsonParser::parse_remote_names_json(json).unwrap();
+
+        assert_eq!(names, ["bigbox", "local"]);
+    }
+
     #[test]
     fn it_should_handle_malformed_json() {
         let malformed_json = r"{ invalid json }";
@@ -17,7 +17,7 @@
 //!
 //! Each adapter:
 //!
-//! 1. Wraps an external CLI tool (SSH, Docker, Ansible, LXD, `OpenTofu`)
+//! 1. Wraps an external CLI tool (SSH, Docker, Ansible, LXD, libvirt, `OpenTofu`)
 //! 2. Uses `CommandExecutor` as a collaborator for actual command execution
 //! 3. Provides domain-specific methods that return typed results
 //! 4. Handles tool-specific error cases with structured error types
@@ -26,6 +26,7 @@
 //!
 //! - **`ansible`** - Ansible configuration management tool wrapper
 //! - **`docker`** - Docker container platform wrapper
+//! - **`libvirt`** - Libvirt domain queries (`virsh`) wrapper
 //! - **`lxd`** - LXD container and VM management wrapper
 //! - **`network`** - Network diagnostic tools (netstat, ss) wrappers
 //! - **`ssh`** - SSH secure shell client wrapper
@@ -60,6 +61,7 @@
 
 pub mod ansible;
 pub mod docker;
+pub mod libvirt;
 pub mod lxd;
 pub mod network;
 pub mod ssh;
@@ -68,6 +70,7 @@ pub mod tofu;
 // Re-exports for commonly used types
 pub use ansible::AnsibleClient;
 pub use docker::DockerClient;
+pub use libvirt::VirshClient;
 pub use lxd::LxdClient;
 pub use network::{NetstatClient, SsClient};
 pub use ssh::{SshClient, SshConfig, SshConnectionConfig, SshCredentials, SshPublicKey};
@@ -6,34 +6,54 @@
 //! ## Key Features
 //!
 //! - Private key authentication with configurable credentials
-//! - Automated host key management (disabled strict checking for automation)
+//! - Strict host key checking against a per-environment `known_hosts` file
+//! - Host key capture with `ssh-keyscan` after provisioning
 //! - Connection timeout and retry mechanisms
+//! - Commands interrupted by a network failure are run again, following the
+//!   [`super::SshRetryPolicy`] of the configuration
+//! - Bounded remote commands: a hung command is killed after its timeout
 //! - Comprehensive error handling for network and authentication issues
+//! - Optional connection multiplexing through `OpenSSH` control sockets
 //! - Integration with the command execution framework
 //!
-//! The client is designed for automated deployment scenarios where security
-//! is important but strict host key checking would interfere with automation.
+//! Host keys are only accepted unchecked when no `known_hosts` file is
+//! configured, which is the case for the first connection to a freshly
+//! provisioned instance, before its keys are recorded.
 
 use std::time::Duration;
 
-use tracing::{info, warn};
+use tracing::{error, info, warn};
 
-use crate::shared::command::{CommandError, CommandExecutor};
+use crate::shared::command::{CommandError, CommandExecutor, CommandResult, CommandTimeouts};
 
-use super::{SshConfig, SshError};
+use super::known_hosts::normalize;
+use super::retry::transient_failure_reason;
+use super::{HostKeyRecord, KnownHostsFile, SshConfig, SshError};
+
+/// How long an idle multiplexed connection stays open after its last command
+const CONTROL_PERSIST: &str = "60s";
+
+/// Seconds between keep-alive probes on a multiplexed connection
+///
+/// A dropped connection is detected after three unanswered probes, so the
+/// next command opens a fresh one instead of hanging on the dead master.
+const SERVER_ALIVE_INTERVAL_SECS: u32 = 15;
 
 /// A specialized SSH client with predefined security settings
 ///
 /// This client provides a secure SSH interface for connecting to remote hosts with:
 /// - Private key authentication
-/// - Disabled strict host key checking (for automation)
-/// - No known hosts file usage
+/// - Strict host key checking when a `known_hosts` file is configured
 /// - Consistent connection settings
 ///
 /// Uses `CommandExecutor` as a collaborator for actual command execution.
+/// Each remote command is bounded by [`CommandTimeouts::ssh_command`], and
+/// run again when a network failure interrupts it (see
+/// [`super::SshRetryPolicy`]).
 pub struct SshClient {
     ssh_config: SshConfig,
     command_executor: CommandExecutor,
+    timeouts: CommandTimeouts,
 }
 
 impl SshClient {
@@ -51,9 +71,17 @@ impl SshClient {
         Self {
             ssh_config,
             command_executor: CommandExecutor::new(),
+            timeouts: CommandTimeouts::default(),
         }
     }
 
+    /// Bound each remote command with the given timeouts
+    #[must_use]
+    pub fn with_timeouts(mut self, timeouts: CommandTimeouts) -> Self {
+        self.timeouts = timeouts;
+        self
+    }
+
     // ============================================================================
     // PUBLIC API - Accessors
     // ============================================================================
@@ -90,6 +118,30 @@ impl SshClient {
         self.execute_with_options(remote_command, &[])
     }
 
+    /// Execute a command on a remote host via SSH, writing `input` to its stdin
+    ///
+    /// Used to stream file contents to the remote host without going through
+    /// the command line. The command sees the end of its input once `input`
+    /// is written.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(String)` - The stdout output if the command succeeds
+    /// * `Err(CommandError)` - Error describing what went wrong
+    ///
+    /// # Errors
+    ///
+    /// This function will return an error if:
+    /// * The SSH connection cannot be established
+    /// * The remote command execution fails with a non-zero exit code
+    pub fn execute_with_input(
+        &self,
+        remote_command: &str,
+        input: &[u8],
+    ) -> Result<String, CommandError> {
+        self.execute_ssh(remote_command, &[], Some(input))
+    }
+
     /// Check if a command succeeds on a remote host (returns only status)
     ///
     /// # Arguments
@@ -158,7 +210,8 @@ impl SshClient {
         let mut attempt = 0;
 
         while attempt < max_attempts {
-            match self.execute_with_options("echo 'SSH connected'", &[]) {
+            // This loop is the retry policy of the connectivity check
+            match self.execute_ssh_once("echo 'SSH connected'", &[], None) {
                 Ok(_) => {
                     info!(
                         operation = "ssh_connectivity",
@@ -198,6 +251,149 @@ impl SshClient {
         })
     }
 
+    // ============================================================================
+    // PUBLIC API - Host Keys
+    // ============================================================================
+
+    /// Scan the host keys of the target host with `ssh-keyscan`
+    ///
+    /// # Returns
+    ///
+    /// The `known_hosts` lines of every key the host offers, sorted
+    ///
+    /// # Errors
+    ///
+    /// Returns `SshError::HostKeyScanFailed` if `ssh-keyscan` fails or the
+    /// host does not report any key.
+    pub fn scan_host_keys(&self) -> Result<Vec<String>, SshError> {
+        let host = self.ssh_config.host_ip().to_string();
+        let port = self.ssh_config.ssh_port().to_string();
+        let connect_timeout = self.ssh_config.connection_timeout_secs().to_string();
+        let timeout = self.timeouts.budget(self.timeouts.ssh_command);
+
+        let result = self
+            .command_executor
+            .run_command_with_timeout(
+                "ssh-keyscan",
+                &["-p", &port, "-T", &connect_timeout, &host],
+                None,
+                timeout,
+            )
+            .map_err(|e| SshError::HostKeyScanFailed {
+                host: host.clone(),
+                reason: e.to_string(),
+            })?;
+
+        let keys = normalize(result.stdout.lines());
+        if keys.is_empty() {
+            return Err(SshError::HostKeyScanFailed {
+                host,
+                reason: "no host key reported".to_string(),
+            });
+        }
+
+        Ok(keys)
+    }
+
+    /// Compare the host keys of the target host with the ones in `known_hosts`
+    ///
+    /// Keys are stored when the file does not exist yet. When they differ,
+    /// they are replaced only if `allow_rotation` is set, which callers do
+    /// right after (re)provisioning the instance.
+    ///
+    /// # Errors
+    ///
+    /// Returns `SshError::HostKeyChanged` if the keys differ and rotation is
+    /// not allowed, or an error if the keys cannot be scanned or the file
+    /// cannot be accessed.
+    pub fn record_host_keys(
+        &self,
+        known_hosts: &KnownHostsFile,
+        allow_rotation: bool,
+    ) -> Result<HostKeyRecord, SshError> {
+        let scanned = self.scan_host_keys()?;
+        let known_hosts_error = |source| SshError::KnownHostsFailed {
+            path: known_hosts.path().to_path_buf(),
+            source,
+        };
+
+        let record = match known_hosts.read().map_err(known_hosts_error)? {
+            None => HostKeyRecord::Recorded,
+            Some(stored) if scanned.iter().all(|key| stored.contains(key)) => {
+                return Ok(HostKeyRecord::Unchanged);
+            }
+            Some(_) if allow_rotation => HostKeyRecord::Rotated,
+            Some(_) => {
+                return Err(SshError::HostKeyChanged {
+                    host: self.ssh_config.host_ip().to_string(),
+                    known_hosts: known_hosts.path().to_path_buf(),
+                });
+            }
+        };
+
+        known_hosts.write(&scanned).map_err(known_hosts_error)?;
+
+        info!(
+            operation = "ssh_host_keys",
+            host_ip = %self.ssh_config.host_ip(),
+            known_hosts = %known_hosts.path().display(),
+            record = ?record,
+            "Recorded SSH host keys"
+        );
+
+        Ok(record)
+    }
+
+    // ============================================================================
+    // PUBLIC API - Interactive Sessions
+    // ============================================================================
+
+    /// Build the arguments for an interactive `ssh` session
+    ///
+    /// Uses the same credentials, port and default options as the automated
+    /// command execution, so interactive sessions behave consistently with
+    /// the connections made by the deployer itself. Default options are
+    /// emitted in a stable (alphabetical) order.
+    ///
+    /// # Arguments
+    ///
+    /// * `remote_command` - Optional command to run instead of a login shell.
+    ///   Each element is passed as a separate argument to `ssh`.
+    ///
+    /// # Returns
+    ///
+    /// The arguments to pass to the system `ssh` binary
+    #[must_use]
+    pub fn build_interactive_args(&self, remote_command: &[String]) -> Vec<String> {
+        let mut args = vec![
+            "-i".to_string(),
+            self.ssh_config
+                .ssh_priv_key_path()
+                .to_string_lossy()
+                .to_string(),
+            "-p".to_string(),
+            self.ssh_config.ssh_port().to_string(),
+        ];
+
+        let defaults: std::collections::BTreeMap<String, String> =
+            self.build_default_ssh_options().into_iter().collect();
+
+        for (key, value) in defaults {
+            args.push("-o".to_string());
+            args.push(format!("{key}={value}"));
+        }
+
+        args.push(format!(
+            "{}@{}",
+            self.ssh_config.ssh_username(),
+            self.ssh_config.host_ip()
+        ));
+
+        args.extend(remote_command.iter().cloned());
+
+        args
+    }
+
     // ============================================================================
     // PRIVATE - Helper Methods
     // ============================================================================
@@ -205,17 +401,34 @@ impl SshClient {
     /// Build default SSH options for automation
     ///
     /// Returns a map of default SSH option keys to their values:
-    /// - `StrictHostKeyChecking`: `no` (disable host key verification)
-    /// - `UserKnownHostsFile`: `/dev/null` (ignore known hosts file)
+    /// - `StrictHostKeyChecking`: `yes` when a `known_hosts` file is
+    ///   configured, `no` otherwise
+    /// - `UserKnownHostsFile`: the configured `known_hosts` file, or
+    ///   `/dev/null` (keys are neither checked nor saved)
     /// - `ConnectTimeout`: configured timeout (prevents hanging)
     /// - `IdentitiesOnly`: `yes` (only use the configured key, ignore SSH agent)
     ///
+    /// When a control directory is configured, the multiplexing options
+    /// (`ControlMaster`, `ControlPath`, `ControlPersist`, `ServerAliveInterval`)
+    /// are added as well.
+    ///
     /// These defaults ensure reliable automation but can be overridden by
     /// user-provided options in `additional_options`.
     fn build_default_ssh_options(&self) -> std::collections::HashMap<String, String> {
         let mut defaults = std::collections::HashMap::new();
-        defaults.insert("StrictHostKeyChecking".to_string(), "no".to_string());
-        defaults.insert("UserKnownHostsFile".to_string(), "/dev/null".to_string());
+        match self.ssh_config.known_hosts() {
+            Some(known_hosts) => {
+                defaults.insert("StrictHostKeyChecking".to_string(), "yes".to_string());
+                defaults.insert(
+                    "UserKnownHostsFile".to_string(),
+                    known_hosts.to_string_lossy().to_string(),
+                );
+            }
+            None => {
+                defaults.insert("StrictHostKeyChecking".to_string(), "no".to_string());
+                defaults.insert("UserKnownHostsFile".to_string(), "/dev/null".to_string());
+            }
+        }
         defaults.insert(
             "ConnectTimeout".to_string(),
             self.ssh_config
@@ -228,9 +441,67 @@ impl SshClient {
         // MaxAuthTries limit by trying agent keys before the configured key,
         // causing "Too many authentication failures" on every attempt.
         defaults.insert("IdentitiesOnly".to_string(), "yes".to_string());
+
+        if let Some(control_path) = self.control_path() {
+            defaults.insert("ControlMaster".to_string(), "auto".to_string());
+            defaults.insert(
+                "ControlPath".to_string(),
+                control_path.to_string_lossy().to_string(),
+            );
+            defaults.insert("ControlPersist".to_string(), CONTROL_PERSIST.to_string());
+            defaults.insert(
+                "ServerAliveInterval".to_string(),
+                SERVER_ALIVE_INTERVAL_SECS.to_string(),
+            );
+        }
+
         defaults
     }
 
+    /// Run `ssh` with the given arguments, killing it after the SSH command timeout
+    fn run_ssh(&self, args: &[&str], input: Option<&[u8]>) -> Result<CommandResult, CommandError> {
+        let timeout = self.timeouts.budget(self.timeouts.ssh_command);
+
+        match input {
+            Some(input) => self
+                .command_executor
+                .run_command_with_input("ssh", args, None, timeout, input),
+            None => self
+                .command_executor
+                .run_command_with_timeout("ssh", args, None, timeout),
+        }
+    }
+
+    /// Whether an SSH failure was caused by a broken multiplexed connection
+    ///
+    /// The master process reports these through `mux_client_*` messages when
+    /// the shared connection dropped while a command was using it. Retrying
+    /// opens a new master connection.
+    fn is_broken_master_connection(stderr: &str) -> bool {
+        stderr.contains("mux_client")
+    }
+
+    /// Whether `ssh` refused the host because its key is not the recorded one
+    fn is_host_key_mismatch(error: &CommandError) -> bool {
+        matches!(error, CommandError::ExecutionFailed { stderr, .. }
+            if stderr.contains("REMOTE HOST IDENTIFICATION HAS CHANGED")
+                || stderr.contains("Host key verification failed"))
+    }
+
+    /// Path of the control socket of the master connection to this host
+    ///
+    /// Returns `None` when connection multiplexing is disabled.
+    fn control_path(&self) -> Option<std::path::PathBuf> {
+        self.ssh_config.control_dir().map(|control_dir| {
+            control_dir.join(format!(
+                "{}@{}:{}",
+                self.ssh_config.ssh_username(),
+                self.ssh_config.host_ip(),
+                self.ssh_config.ssh_port()
+            ))
+        })
+    }
+
     /// Extract SSH option key from an option string
     ///
     /// Parses option strings in formats like:
@@ -255,6 +526,7 @@ impl SshClient {
     ///
     /// User-provided options in `additional_options` take precedence over defaults:
     /// - If a user provides `StrictHostKeyChecking=yes`, it will override the default `no`
+    ///   used when no `known_hosts` file is configured
     /// - If a user provides `ConnectTimeout=30`, it will override the configured default
     /// - Default options are only added if the user hasn't provided them
     ///
@@ -360,11 +632,79 @@ impl SshClient {
         &self,
         remote_command: &str,
         additional_options: &[&str],
+    ) -> Result<String, CommandError> {
+        self.execute_ssh(remote_command, additional_options, None)
+    }
+
+    /// Run a remote command, running it again after each network failure
+    ///
+    /// Failures of the remote command itself, authentication failures and
+    /// host key mismatches are returned at once.
+    fn execute_ssh(
+        &self,
+        remote_command: &str,
+        additional_options: &[&str],
+        input: Option<&[u8]>,
+    ) -> Result<String, CommandError> {
+        self.ssh_config.retry_policy().run(
+            |_| self.execute_ssh_once(remote_command, additional_options, input),
+            transient_failure_reason,
+            &|retry| {
+                warn!(
+                    operation = "ssh_retry",
+                    host_ip = %self.ssh_config.host_ip(),
+                    failed_attempt = retry.failed_attempt,
+                    max_attempts = retry.max_attempts,
+                    delay_secs = retry.delay.as_secs(),
+                    reason = %retry.reason,
+                    "SSH connection failed, retrying the remote command"
+                );
+            },
+        )
+    }
+
+    /// Run a remote command, reconnecting once if the multiplexed connection dropped
+    fn execute_ssh_once(
+        &self,
+        remote_command: &str,
+        additional_options: &[&str],
+        input: Option<&[u8]>,
     ) -> Result<String, CommandError> {
         let args = self.build_ssh_args(remote_command, additional_options);
         let args_str: Vec<&str> = args.iter().map(std::string::String::as_str).collect();
 
-        let result = self.command_executor.run_command("ssh", &args_str, None)?;
+        if let Some(control_dir) = self.ssh_config.control_dir() {
+            std::fs::create_dir_all(control_dir).map_err(|source| CommandError::StartupFailed {
+                command: "ssh".to_string(),
+                source,
+            })?;
+        }
+
+        let result = match self.run_ssh(&args_str, input) {
+            Err(CommandError::ExecutionFailed { ref stderr, .. })
+                if Self::is_broken_master_connection(stderr) =>
+            {
+                warn!(
+                    operation = "ssh_multiplexing",
+                    host_ip = %self.ssh_config.host_ip(),
+                    "Multiplexed SSH connection dropped, reconnecting"
+                );
+                if let Some(control_path) = self.control_path() {
+                    super::multiplexer::stop_master(&control_path);
+                }
+                self.run_ssh(&args_str, input)?
+            }
+            Err(e @ CommandError::ExecutionFailed { .. }) if Self::is_host_key_mismatch(&e) => {
+                error!(
+                    operation = "ssh_host_keys",
+                    host_ip = %self.ssh_config.host_ip(),
+                    known_hosts = ?self.ssh_config.known_hosts(),
+                    "SSH host key does not match the recorded one, refusing to connect"
+                );
+                return Err(e);
+            }
+            other => other?,
+        };
 
         // Process stderr for SSH warnings and log them
         self.process_ssh_warnings(&result.stderr);
@@ -605,6 +945,58 @@ mod tests {
         );
     }
 
+    #[test]
+    fn it_should_build_interactive_args_without_remote_command() {
+        // Arrange
+        let (_temp_dir, credentials) = create_test_ssh_credentials();
+        let key_path = credentials.ssh_priv_key_path.to_string_lossy().to_string();
+        let host_ip = IpAddr::V4(Ipv4Addr::new(192, 168, 1, 1));
+        let ssh_config = SshConfig::with_default_port(credentials, host_ip);
+        let timeout = ssh_config.connection_config.connect_timeout_secs;
+        let ssh_client = SshClient::new(ssh_config);
+
+        // Act
+        let args = ssh_client.build_interactive_args(&[]);
+
+        // Assert
+        assert_eq!(
+            args,
+            vec![
+                "-i".to_string(),
+                key_path,
+                "-p".to_string(),
+                "22".to_string(),
+                "-o".to_string(),
+                format!("ConnectTimeout={timeout}"),
+                "-o".to_string(),
+                "IdentitiesOnly=yes".to_string(),
+                "-o".to_string(),
+                "StrictHostKeyChecking=no".to_string(),
+                "-o".to_string(),
+                "UserKnownHostsFile=/dev/null".to_string(),
+                "testuser@192.168.1.1".to_string(),
+            ]
+        );
+    }
+
+    #[test]
+    fn it_should_append_remote_command_to_interactive_args() {
+        // Arrange
+        let (_temp_dir, credentials) = create_test_ssh_credentials();
+        let host_ip = IpAddr::V4(Ipv4Addr::new(192, 168, 1, 1));
+        let ssh_config = SshConfig::with_default_port(credentials, host_ip);
+        let ssh_client = SshClient::new(ssh_config);
+
+        // Act
+        let args = ssh_client.build_interactive_args(&["docker".to_string(), "ps".to_string()]);
+
+        // Assert
+        assert_eq!(
+            &args[args.len() - 3..],
+            ["testuser@192.168.1.1", "docker", "ps"]
+        );
+    }
+
     #[test]
     fn it_should_build_ssh_args_with_user_options_before_defaults() {
         // Arrange
@@ -675,4 +1067,91 @@ mod tests {
             "Default StrictHostKeyChecking=no should be excluded when user provides override"
         );
     }
+
+    #[test]
+    fn it_should_add_multiplexing_options_when_a_control_dir_is_configured() {
+        // Arrange
+        let (temp_dir, credentials) = create_test_ssh_credentials();
+        let host_ip = IpAddr::V4(Ipv4Addr::new(192, 168, 1, 1));
+        let control_dir = temp_dir.path().join("ssh");
+        let ssh_config = SshConfig::with_default_port(credentials, host_ip)
+            .with_control_dir(control_dir.clone());
+        let ssh_client = SshClient::new(ssh_config);
+
+        // Act
+        let default_options = ssh_client.build_default_ssh_options();
+
+        // Assert
+        assert_eq!(
+            default_options.get("ControlMaster"),
+            Some(&"auto".to_string())
+        );
+        assert_eq!(
+            default_options.get("ControlPath"),
+            Some(
+                &control_dir
+                    .join("testuser@192.168.1.1:22")
+                    .to_string_lossy()
+                    .to_string()
+            )
+        );
+        assert_eq!(
+            default_options.get("ControlPersist"),
+            Some(&CONTROL_PERSIST.to_string())
+        );
+    }
+
+    #[test]
+    fn it_should_detect_a_broken_multiplexed_connection() {
+        assert!(SshClient::is_broken_master_connection(
+            "mux_client_request_session: read from master failed: Broken pipe"
+        ));
+        assert!(!SshClient::is_broken_master_connection(
+            "ssh: connect to host 192.168.1.1 port 22: Connection refused"
+        ));
+    }
+
+    #[test]
+    fn it_should_check_host_keys_strictly_when_a_known_hosts_file_is_configured() {
+        // Arrange
+        let (temp_dir, credentials) = create_test_ssh_credentials();
+        let host_ip = IpAddr::V4(Ipv4Addr::new(192, 168, 1, 1));
+        let known_hosts = temp_dir.path().join("ssh").join("known_hosts");
+        let ssh_config = SshConfig::with_default_port(credentials, host_ip)
+            .with_known_hosts(known_hosts.clone());
+        let ssh_client = SshClient::new(ssh_config);
+
+        // Act
+        let default_options = ssh_client.build_default_ssh_options();
+
+        // Assert
+        assert_eq!(
+            default_options.get("StrictHostKeyChecking"),
+            Some(&"yes".to_string())
+        );
+        assert_eq!(
+            default_options.get("UserKnownHostsFile"),
+            Some(&known_hosts.to_string_lossy().to_string())
+        );
+    }
+
+    #[test]
+    fn it_should_detect_a_changed_host_key() {
+        let error = |stderr: &str| CommandError::ExecutionFailed {
+            command: "ssh".to_string(),
+            exit_code: "255".to_string(),
+            stdout: String::new(),
+            stderr: stderr.to_string(),
+        };
+
+        assert!(SshClient::is_host_key_mismatch(&error(
+            "@    WARNING: REMOTE HOST IDENTIFICATION HAS CHANGED!     @"
+        )));
+        assert!(SshClient::is_host_key_mismatch(&error(
+            "Host key verification failed."
+        )));
+        assert!(!SshClient::is_host_key_mismatch(&error(
+            "Permission denied (publickey)."
+        )));
+    }
 }
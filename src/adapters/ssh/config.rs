@@ -16,7 +16,7 @@
 use std::net::{IpAddr, SocketAddr};
 use std::path::PathBuf;
 
-use super::SshCredentials;
+use super::{SshCredentials, SshRetryPolicy};
 
 /// Default SSH port number.
 pub const DEFAULT_SSH_PORT: u16 = 22;
@@ -162,6 +162,23 @@ pub struct SshConfig {
 
     /// SSH connection behavior configuration (timeouts, retries, logging).
     pub connection_config: SshConnectionConfig,
+
+    /// Directory holding `OpenSSH` control sockets for connection multiplexing.
+    ///
+    /// When set, consecutive commands share one persistent connection instead
+    /// of performing a new handshake each time. See [`super::SshMultiplexer`].
+    pub control_dir: Option<PathBuf>,
+
+    /// `known_hosts` file holding the recorded host key of the target host.
+    ///
+    /// When set, connections verify the host key against this file and fail
+    /// if it does not match. See [`super::KnownHostsFile`].
+    pub known_hosts: Option<PathBuf>,
+
+    /// How remote commands interrupted by a network failure are retried.
+    ///
+    /// See [`super::SshRetryPolicy`]; connectivity checks are not retried.
+    pub retry_policy: SshRetryPolicy,
 }
 
 impl SshConfig {
@@ -191,6 +208,9 @@ impl SshConfig {
             credentials,
             socket_addr: ssh_socket_addr,
             connection_config: SshConnectionConfig::default(),
+            control_dir: None,
+            known_hosts: None,
+            retry_policy: SshRetryPolicy::default(),
         }
     }
 
@@ -229,6 +249,9 @@ impl SshConfig {
             credentials,
             socket_addr: ssh_socket_addr,
             connection_config,
+            control_dir: None,
+            known_hosts: None,
+            retry_policy: SshRetryPolicy::default(),
         }
     }
 
@@ -257,6 +280,35 @@ impl SshConfig {
         Self::new(credentials, socket_addr)
     }
 
+    /// Reuse one persistent connection per host through control sockets in `control_dir`.
+    ///
+    /// The sockets are left running between commands; use an
+    /// [`super::SshMultiplexer`] on the same directory to close them.
+    #[must_use]
+    pub fn with_control_dir(mut self, control_dir: PathBuf) -> Self {
+        self.control_dir = Some(control_dir);
+        self
+    }
+
+    /// Verify the host key of the target host against the `known_hosts` file at `path`.
+    ///
+    /// Without it, host keys are accepted without being checked, which is only
+    /// meant for the first connection to a freshly provisioned instance.
+    #[must_use]
+    pub fn with_known_hosts(mut self, path: PathBuf) -> Self {
+        self.known_hosts = Some(path);
+        self
+    }
+
+    /// Retry remote commands interrupted by a network failure with `retry_policy`.
+    ///
+    /// Use [`SshRetryPolicy::no_retries`] to fail on the first network error.
+    #[must_use]
+    pub fn with_retry_policy(mut self, retry_policy: SshRetryPolicy) -> Self {
+        self.retry_policy = retry_policy;
+        self
+    }
+
     /// Access the SSH private key path.
     #[must_use]
     pub fn ssh_priv_key_path(&self) -> &PathBuf {
@@ -298,4 +350,22 @@ impl SshConfig {
     pub fn connection_timeout_secs(&self) -> u32 {
         self.connection_config.connect_timeout_secs
     }
+
+    /// Access the control socket directory, if connection multiplexing is enabled.
+    #[must_use]
+    pub fn control_dir(&self) -> Option<&PathBuf> {
+        self.control_dir.as_ref()
+    }
+
+    /// Access the `known_hosts` file, if host key checking is enabled.
+    #[must_use]
+    pub fn known_hosts(&self) -> Option<&PathBuf> {
+        self.known_hosts.as_ref()
+    }
+
+    /// Access the retry policy of remote commands.
+    #[must_use]
+    pub fn retry_policy(&self) -> &SshRetryPolicy {
+        &self.retry_policy
+    }
 }
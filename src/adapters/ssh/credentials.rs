@@ -29,6 +29,7 @@ pub struct SshCredentials {
     /// This key will be used by the SSH client to authenticate with remote
     /// instances created during deployment. The corresponding public key
     /// should be authorized on the target instances.
+    #[serde(with = "crate::shared::portable_path")]
     pub ssh_priv_key_path: PathBuf,
 
     /// Path to the SSH public key file for remote connections.
@@ -36,6 +37,7 @@ pub struct SshCredentials {
     /// This public key will be used for authorization on target instances
     /// during the deployment process, typically injected into cloud-init
     /// configurations or `authorized_keys` files.
+    #[serde(with = "crate::shared::portable_path")]
     pub ssh_pub_key_path: PathBuf,
 
     /// Username for SSH connections to remote instances.
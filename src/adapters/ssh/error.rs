@@ -3,6 +3,8 @@
 //! This module defines the error types that can occur during SSH operations,
 //! including connectivity timeouts and command execution failures.
 
+use std::path::PathBuf;
+
 use thiserror::Error;
 
 use crate::shared::command::CommandError;
@@ -34,6 +36,32 @@ Tip: Check command syntax and remote host permissions"
         #[source]
         source: CommandError,
     },
+
+    /// The host presented a key that differs from the recorded one
+    ///
+    /// Either the instance was rebuilt outside the deployer or the connection
+    /// is being intercepted. Use `.help()` for detailed troubleshooting.
+    #[error(
+        "Host key of {host} does not match the one recorded in '{}'
+Tip: Do not connect until you know why the key changed",
+        known_hosts.display()
+    )]
+    HostKeyChanged { host: String, known_hosts: PathBuf },
+
+    /// `ssh-keyscan` did not report any host key
+    #[error(
+        "Failed to scan the host keys of {host}: {reason}
+Tip: Check that the SSH service of the instance is reachable"
+    )]
+    HostKeyScanFailed { host: String, reason: String },
+
+    /// The `known_hosts` file could not be read or written
+    #[error("Failed to access known hosts file '{}': {source}", path.display())]
+    KnownHostsFailed {
+        path: PathBuf,
+        #[source]
+        source: std::io::Error,
+    },
 }
 
 impl SshError {
@@ -120,6 +148,48 @@ For more information, see the SSH troubleshooting documentation."
 
 For more information, see the command execution documentation."
             }
+
+            Self::HostKeyChanged { .. } => {
+                "SSH Host Key Changed - Detailed Troubleshooting:
+
+1. Do not connect to the instance until the change is explained:
+   - A changed key can mean the connection is being intercepted
+
+2. Check whether the instance was rebuilt outside the deployer:
+   - Recreating the VM or reinstalling SSH generates new host keys
+   - Compare the key fingerprint with the one in the provider console:
+     ssh-keyscan <host_ip> | ssh-keygen -lf -
+
+3. Trust the new key only once the change is explained:
+   - Provisioning the environment again records the new key automatically
+   - Or remove data/<env>/ssh/known_hosts; the next command records the key again
+
+For more information, see the SSH troubleshooting documentation."
+            }
+
+            Self::HostKeyScanFailed { .. } => {
+                "SSH Host Key Scan Failed - Detailed Troubleshooting:
+
+1. Verify the SSH service is reachable:
+   - Run: ssh-keyscan -p <port> <host_ip>
+   - Check firewall rules allow the SSH port
+
+2. Verify ssh-keyscan is installed:
+   - It is part of the OpenSSH client package
+
+For more information, see the SSH troubleshooting documentation."
+            }
+
+            Self::KnownHostsFailed { .. } => {
+                "Known Hosts File Access Failed - Detailed Troubleshooting:
+
+1. Check the permissions of data/<env>/ssh:
+   - The deployer must be able to create and write the directory
+
+2. Check the disk has free space
+
+For more information, see the SSH troubleshooting documentation."
+            }
         }
     }
 }
@@ -137,18 +207,39 @@ impl crate::shared::Traceable for SshError {
             Self::CommandFailed { source } => {
                 format!("SshError: SSH command failed - {source}")
             }
+            Self::HostKeyChanged { host, known_hosts } => {
+                format!(
+                    "SshError: Host key of '{host}' does not match '{}'",
+                    known_hosts.display()
+                )
+            }
+            Self::HostKeyScanFailed { host, reason } => {
+                format!("SshError: Host key scan of '{host}' failed - {reason}")
+            }
+            Self::KnownHostsFailed { path, source } => {
+                format!(
+                    "SshError: Known hosts file '{}' not accessible - {source}",
+                    path.display()
+                )
+            }
         }
     }
 
     fn trace_source(&self) -> Option<&dyn crate::shared::Traceable> {
         match self {
-            Self::ConnectivityTimeout { .. } => None,
             Self::CommandFailed { source } => Some(source),
+            Self::ConnectivityTimeout { .. }
+            | Self::HostKeyChanged { .. }
+            | Self::HostKeyScanFailed { .. }
+            | Self::KnownHostsFailed { .. } => None,
         }
     }
 
     fn error_kind(&self) -> crate::shared::ErrorKind {
-        crate::shared::ErrorKind::NetworkConnectivity
+        match self {
+            Self::KnownHostsFailed { .. } => crate::shared::ErrorKind::FileSystem,
+            _ => crate::shared::ErrorKind::NetworkConnectivity,
+        }
     }
 }
 
@@ -186,6 +277,19 @@ mod tests {
             assert!(message.contains("instance is fully booted"));
         }
 
+        #[test]
+        fn it_should_include_the_known_hosts_file_in_host_key_changed_error() {
+            let error = SshError::HostKeyChanged {
+                host: "10.0.0.1".to_string(),
+                known_hosts: PathBuf::from("data/dev/ssh/known_hosts"),
+            };
+
+            let message = error.to_string();
+            assert!(message.contains("10.0.0.1"));
+            assert!(message.contains("data/dev/ssh/known_hosts"));
+            assert!(message.contains("Tip:"));
+        }
+
         #[test]
         fn it_should_include_brief_tip_in_command_failed_error() {
             let cmd_error = CommandError::ExecutionFailed {
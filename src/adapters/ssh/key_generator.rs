@@ -0,0 +1,279 @@
+//! SSH key pair generation
+//!
+//! This module provides the [`SshKeyGenerator`] which creates a new Ed25519
+//! key pair with `ssh-keygen`. It is used by the interactive `create` wizard
+//! to give a new environment its own deployment key in `data/{env}/ssh/`.
+//!
+//! The private key has no passphrase: the deployer runs SSH and Ansible
+//! non-interactively and cannot answer a passphrase prompt. It is therefore
+//! only readable by its owner (`0600`), and [`securely_remove_key`] overwrites
+//! it before deleting it.
+
+use std::io::Write;
+use std::path::{Path, PathBuf};
+
+use thiserror::Error;
+
+use crate::shared::command::{CommandError, CommandExecutor};
+
+/// File name of the private key generated for an environment
+pub const GENERATED_PRIVATE_KEY_FILE_NAME: &str = "id_ed25519";
+
+/// Errors that can occur while generating an SSH key pair
+#[derive(Debug, Error)]
+pub enum SshKeyGenerationError {
+    /// A key already exists at the target path
+    #[error("SSH key already exists at '{path}'")]
+    KeyAlreadyExists { path: PathBuf },
+
+    /// The directory of the key could not be created
+    #[error("Failed to create SSH key directory '{path}': {source}")]
+    DirectoryCreationFailed {
+        path: PathBuf,
+        #[source]
+        source: std::io::Error,
+    },
+
+    /// The permissions of the private key could not be restricted
+    #[error("Failed to set the permissions of SSH key '{path}': {source}")]
+    PermissionsFailed {
+        path: PathBuf,
+        #[source]
+        source: std::io::Error,
+    },
+
+    /// `ssh-keygen` could not be run or failed
+    #[error("ssh-keygen failed: {source}")]
+    CommandFailed {
+        #[source]
+        source: CommandError,
+    },
+}
+
+impl SshKeyGenerationError {
+    /// Get detailed troubleshooting guidance for this error
+    #[must_use]
+    pub fn help(&self) -> &'static str {
+        match self {
+            Self::KeyAlreadyExists { .. } => {
+                "An SSH key with the same name already exists and is never overwritten.\n\
+                 \n\
+                 Use the existing key pair instead of generating a new one, or move it\n\
+                 away if it is no longer needed."
+            }
+            Self::DirectoryCreationFailed { .. } => {
+                "The directory for the new SSH key could not be created.\n\
+                 \n\
+                 Check the permissions of the data directory and the available disk space."
+            }
+            Self::PermissionsFailed { .. } => {
+                "The new private key could not be made readable by its owner only.\n\
+                 \n\
+                 Check that the data directory is on a filesystem that supports Unix\n\
+                 permissions, then remove the generated key pair and retry."
+            }
+            Self::CommandFailed { .. } => {
+                "ssh-keygen could not generate the key pair.\n\
+                 \n\
+                 Check that the OpenSSH client is installed (ssh-keygen -h), or generate\n\
+                 the key yourself and pass its path:\n\
+                 \n\
+                 ssh-keygen -t ed25519 -N \"\" -f ~/.ssh/torrust_deployer"
+            }
+        }
+    }
+}
+
+/// Generates passphrase-free Ed25519 key pairs with `ssh-keygen`
+#[derive(Default)]
+pub struct SshKeyGenerator {
+    command_executor: CommandExecutor,
+}
+
+impl SshKeyGenerator {
+    /// Create a new `SshKeyGenerator`
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Generate a key pair, writing the private key to `private_key_path`
+    ///
+    /// The public key is written next to it with the `.pub` extension, as
+    /// `ssh-keygen` does. Missing parent directories are created.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if a file already exists at `private_key_path`, the
+    /// directory cannot be created, or `ssh-keygen` fails.
+    pub fn generate(
+        &self,
+        private_key_path: &Path,
+        comment: &str,
+    ) -> Result<PathBuf, SshKeyGenerationError> {
+        if private_key_path.exists() {
+            return Err(SshKeyGenerationError::KeyAlreadyExists {
+                path: private_key_path.to_path_buf(),
+            });
+        }
+
+        if let Some(parent) = private_key_path.parent() {
+            std::fs::create_dir_all(parent).map_err(|source| {
+                SshKeyGenerationError::DirectoryCreationFailed {
+                    path: parent.to_path_buf(),
+                    source,
+                }
+            })?;
+        }
+
+        let private_key = private_key_path.to_string_lossy();
+        self.command_executor
+            .run_command(
+                "ssh-keygen",
+                &[
+                    "-q",
+                    "-t",
+                    "ed25519",
+                    "-N",
+                    "",
+                    "-C",
+                    comment,
+                    "-f",
+                    &private_key,
+                ],
+                None,
+            )
+            .map_err(|source| SshKeyGenerationError::CommandFailed { source })?;
+
+        // ssh-keygen writes the private key with 0600; enforce it independently of its version
+        #[cfg(unix)]
+        {
+            use std::os::unix::fs::PermissionsExt;
+
+            std::fs::set_permissions(private_key_path, std::fs::Permissions::from_mode(0o600))
+                .map_err(|source| SshKeyGenerationError::PermissionsFailed {
+                    path: private_key_path.to_path_buf(),
+                    source,
+                })?;
+        }
+
+        Ok(public_key_path_of(private_key_path))
+    }
+}
+
+/// Overwrite a key file with zeros, flush it to disk and delete it
+///
+/// The content of a plain `remove_file` stays on disk until the blocks are
+/// reused. Overwriting first keeps a private key from being recovered from
+/// the data directory (on filesystems that write in place).
+///
+/// # Errors
+///
+/// Returns an error if the file cannot be opened, overwritten or removed.
+pub fn securely_remove_key(path: &Path) -> std::io::Result<()> {
+    let length = std::fs::metadata(path)?.len();
+
+    let mut file = std::fs::OpenOptions::new().write(true).open(path)?;
+    let zeros = [0u8; 4096];
+    let mut remaining = length;
+    while remaining > 0 {
+        let chunk = usize::try_from(remaining).map_or(zeros.len(), |r| r.min(zeros.len()));
+        file.write_all(&zeros[..chunk])?;
+        remaining -= chunk as u64;
+    }
+    file.sync_all()?;
+    drop(file);
+
+    std::fs::remove_file(path)
+}
+
+/// Path of the public key `ssh-keygen` writes for `private_key_path`
+#[must_use]
+pub fn public_key_path_of(private_key_path: &Path) -> PathBuf {
+    let mut public_key_path = private_key_path.as_os_str().to_owned();
+    public_key_path.push(".pub");
+    PathBuf::from(public_key_path)
+}
+
+#[cfg(test)]
+mod tests {
+    use tempfile::TempDir;
+
+    use super::*;
+    use crate::adapters::ssh::is_passphrase_protected;
+
+    #[test]
+    fn it_should_generate_a_key_pair_without_passphrase() {
+        let temp_dir = TempDir::new().unwrap();
+        let private_key_path = temp_dir
+            .path()
+            .join("ssh")
+            .join(GENERATED_PRIVATE_KEY_FILE_NAME);
+
+        let public_key_path = SshKeyGenerator::new()
+            .generate(&private_key_path, "torrust-deployer-test")
+            .unwrap();
+
+        assert!(private_key_path.exists());
+        assert!(!is_passphrase_protected(&private_key_path));
+        let public_key = std::fs::read_to_string(public_key_path).unwrap();
+        assert!(public_key.starts_with("ssh-ed25519 "));
+        assert!(public_key.trim_end().ends_with("torrust-deployer-test"));
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn it_should_make_the_private_key_readable_by_its_owner_only() {
+        use std::os::unix::fs::PermissionsExt;
+
+        let temp_dir = TempDir::new().unwrap();
+        let private_key_path = temp_dir.path().join(GENERATED_PRIVATE_KEY_FILE_NAME);
+
+        SshKeyGenerator::new()
+            .generate(&private_key_path, "comment")
+            .unwrap();
+
+        let mode = std::fs::metadata(&private_key_path)
+            .unwrap()
+            .permissions()
+            .mode();
+        assert_eq!(mode & 0o777, 0o600);
+    }
+
+    #[test]
+    fn it_should_securely_remove_a_key() {
+        let temp_dir = TempDir::new().unwrap();
+        let key_path = temp_dir.path().join(GENERATED_PRIVATE_KEY_FILE_NAME);
+        std::fs::write(&key_path, "secret key material").unwrap();
+
+        securely_remove_key(&key_path).unwrap();
+
+        assert!(!key_path.exists());
+    }
+
+    #[test]
+    fn it_should_not_overwrite_an_existing_key() {
+        let temp_dir = TempDir::new().unwrap();
+        let private_key_path = temp_dir.path().join(GENERATED_PRIVATE_KEY_FILE_NAME);
+        std::fs::write(&private_key_path, "existing").unwrap();
+
+        let result = SshKeyGenerator::new().generate(&private_key_path, "comment");
+
+        assert!(matches!(
+            result,
+            Err(SshKeyGenerationError::KeyAlreadyExists { .. })
+        ));
+        assert_eq!(
+            std::fs::read_to_string(&private_key_path).unwrap(),
+            "existing"
+        );
+    }
+
+    #[test]
+    fn it_should_derive_the_public_key_path() {
+        assert_eq!(
+            public_key_path_of(Path::new("/data/dev/ssh/id_ed25519")),
+            PathBuf::from("/data/dev/ssh/id_ed25519.pub")
+        );
+    }
+}
@@ -4,6 +4,9 @@
 //! files without requiring external tools. The primary entry point is
 //! [`is_passphrase_protected`], which is used during `create environment` to
 //! emit an early warning when a passphrase-protected key is detected.
+//! [`check_key_permissions`] backs the warning for private keys with too
+//! open permissions. [`check_key_pair`] derives the public key of a private
+//! key with `ssh-keygen -y` to detect a public key from another pair.
 //!
 //! See ADR: `docs/decisions/ssh-key-passphrase-detection.md`
 
@@ -12,6 +15,21 @@ use std::path::Path;
 use base64::engine::general_purpose::STANDARD;
 use base64::Engine as _;
 
+use crate::shared::command::CommandExecutor;
+
+/// Result of comparing a private key with a public key file
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum KeyPairCheck {
+    /// The public key file holds the public key of the private key
+    Matching,
+
+    /// The public key file holds the public key of another key pair
+    Mismatching,
+
+    /// The public key could not be derived or read, so the pair was not compared
+    Undetermined { reason: String },
+}
+
 /// Returns `true` if the private key at `path` appears to be passphrase-protected.
 ///
 /// This is a best-effort heuristic used to emit an early warning during
@@ -44,6 +62,105 @@ pub fn is_passphrase_protected(path: &Path) -> bool {
     false
 }
 
+/// Result of checking who can access a private key file
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum KeyPermissionsCheck {
+    /// Only the owner can access the key
+    OwnerOnly,
+
+    /// The group or other users can access the key
+    ReadableByOthers,
+
+    /// The permissions could not be read, or the platform has no Unix permissions
+    Undetermined { reason: String },
+}
+
+/// Checks whether users other than the owner can access the file at `path`.
+///
+/// OpenSSH refuses private keys that are accessible by the group or others
+/// ("UNPROTECTED PRIVATE KEY FILE"), so such a key fails at `provision`.
+///
+/// I/O errors and platforms without Unix permissions (Windows, where access
+/// is controlled by ACLs) give [`KeyPermissionsCheck::Undetermined`] rather
+/// than an error.
+#[must_use]
+pub fn check_key_permissions(path: &Path) -> KeyPermissionsCheck {
+    #[cfg(unix)]
+    {
+        use std::os::unix::fs::PermissionsExt;
+
+        match std::fs::metadata(path) {
+            Ok(metadata) if metadata.permissions().mode() & 0o077 != 0 => {
+                KeyPermissionsCheck::ReadableByOthers
+            }
+            Ok(_) => KeyPermissionsCheck::OwnerOnly,
+            Err(e) => KeyPermissionsCheck::Undetermined {
+                reason: format!("cannot read the metadata of {}: {e}", path.display()),
+            },
+        }
+    }
+
+    #[cfg(not(unix))]
+    {
+        let _ = path;
+        KeyPermissionsCheck::Undetermined {
+            reason: "file permissions cannot be checked on this platform".to_string(),
+        }
+    }
+}
+
+/// Compares the public key derived from `private_key_path` with the key in `public_key_path`
+///
+/// The public key is derived with `ssh-keygen -y`. Only the key type and the
+/// key data are compared, so a different comment does not count as a mismatch.
+///
+/// Passphrase-protected keys cannot be derived without prompting, and neither
+/// can unreadable files: those, like a missing `ssh-keygen`, result in
+/// [`KeyPairCheck::Undetermined`] rather than an error.
+#[must_use]
+pub fn check_key_pair(private_key_path: &Path, public_key_path: &Path) -> KeyPairCheck {
+    if is_passphrase_protected(private_key_path) {
+        return KeyPairCheck::Undetermined {
+            reason: "the private key is passphrase-protected".to_string(),
+        };
+    }
+
+    let public_key = match std::fs::read_to_string(public_key_path) {
+        Ok(content) => content,
+        Err(e) => {
+            return KeyPairCheck::Undetermined {
+                reason: format!("the public key cannot be read: {e}"),
+            }
+        }
+    };
+
+    // `-P ""` makes ssh-keygen fail instead of prompting for a passphrase
+    let private_key = private_key_path.to_string_lossy();
+    let derived = match CommandExecutor::new().run_command(
+        "ssh-keygen",
+        &["-y", "-P", "", "-f", &private_key],
+        None,
+    ) {
+        Ok(result) => result.stdout,
+        Err(e) => {
+            return KeyPairCheck::Undetermined {
+                reason: format!("the public key cannot be derived: {e}"),
+            }
+        }
+    };
+
+    if key_material(&derived) == key_material(&public_key) {
+        KeyPairCheck::Matching
+    } else {
+        KeyPairCheck::Mismatching
+    }
+}
+
+/// Key type and base64 data of an OpenSSH public key line, without the comment
+fn key_material(public_key: &str) -> Vec<&str> {
+    public_key.split_whitespace().take(2).collect()
+}
+
 /// Checks whether an OpenSSH-format PEM body uses the bcrypt KDF.
 ///
 /// OpenSSH private key binary layout (after base64-decoding the body):
@@ -119,6 +236,103 @@ mod tests {
         );
     }
 
+    #[cfg(unix)]
+    #[test]
+    fn it_should_detect_a_key_readable_by_other_users() {
+        use std::os::unix::fs::PermissionsExt;
+
+        let dir = tempfile::TempDir::new().unwrap();
+        let key_path = dir.path().join("id_ed25519");
+        std::fs::write(&key_path, "key").unwrap();
+
+        std::fs::set_permissions(&key_path, std::fs::Permissions::from_mode(0o644)).unwrap();
+        assert_eq!(
+            check_key_permissions(&key_path),
+            KeyPermissionsCheck::ReadableByOthers
+        );
+
+        std::fs::set_permissions(&key_path, std::fs::Permissions::from_mode(0o600)).unwrap();
+        assert_eq!(
+            check_key_permissions(&key_path),
+            KeyPermissionsCheck::OwnerOnly
+        );
+    }
+
+    #[test]
+    fn it_should_not_determine_the_permissions_of_a_missing_key() {
+        let dir = tempfile::TempDir::new().unwrap();
+
+        let result = check_key_permissions(&dir.path().join("missing_key"));
+
+        assert!(matches!(result, KeyPermissionsCheck::Undetermined { .. }));
+    }
+
+    /// Copy of the RSA test key, readable by its owner only so `ssh-keygen` accepts it
+    fn private_key_copy(dir: &tempfile::TempDir) -> PathBuf {
+        let key_path = dir.path().join("id_rsa");
+        std::fs::copy(project_root().join("fixtures/testing_rsa"), &key_path).unwrap();
+        #[cfg(unix)]
+        {
+            use std::os::unix::fs::PermissionsExt;
+
+            std::fs::set_permissions(&key_path, std::fs::Permissions::from_mode(0o600)).unwrap();
+        }
+        key_path
+    }
+
+    #[test]
+    fn it_should_accept_the_public_key_of_the_same_pair() {
+        let dir = tempfile::TempDir::new().unwrap();
+        let private_key = private_key_copy(&dir);
+
+        let result = check_key_pair(
+            &private_key,
+            &project_root().join("fixtures/testing_rsa.pub"),
+        );
+
+        assert_eq!(result, KeyPairCheck::Matching);
+    }
+
+    #[test]
+    fn it_should_detect_a_public_key_from_another_pair() {
+        let dir = tempfile::TempDir::new().unwrap();
+        let private_key = private_key_copy(&dir);
+
+        let result = check_key_pair(
+            &private_key,
+            &project_root().join("fixtures/testing_ed25519_encrypted.pub"),
+        );
+
+        assert_eq!(result, KeyPairCheck::Mismatching);
+    }
+
+    #[test]
+    fn it_should_not_compare_keys_that_cannot_be_read() {
+        let dir = tempfile::TempDir::new().unwrap();
+        let private_key = dir.path().join("id_rsa");
+        std::fs::write(&private_key, "not a key").unwrap();
+
+        let unparsable = check_key_pair(
+            &private_key,
+            &project_root().join("fixtures/testing_rsa.pub"),
+        );
+        let missing_public_key = check_key_pair(
+            &private_key_copy(&dir),
+            &PathBuf::from("/nonexistent/path/to/key.pub"),
+        );
+        let encrypted = check_key_pair(
+            &project_root().join("fixtures/testing_ed25519_encrypted"),
+            &project_root().join("fixtures/testing_ed25519_encrypted.pub"),
+        );
+
+        assert!(matches!(unparsable, KeyPairCheck::Undetermined { .. }));
+        assert!(matches!(
+            missing_public_key,
+            KeyPairCheck::Undetermined { .. }
+        ));
+        assert!(matches!(encrypted, KeyPairCheck::Undetermined { .. }));
+    }
+
     #[test]
     fn it_should_return_true_when_legacy_pem_header_contains_encrypted() {
         // Arrange: write a minimal legacy-format PKCS#8 encrypted PEM to a temp file
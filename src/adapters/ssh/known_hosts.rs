@@ -0,0 +1,152 @@
+//! Per-environment `known_hosts` files
+//!
+//! This module provides the `KnownHostsFile` which stores the host keys of one
+//! instance, as reported by `ssh-keyscan`. Clients whose `SshConfig` points at
+//! the file connect with strict host key checking, so a host presenting a
+//! different key is refused instead of being silently trusted.
+//!
+//! ## Key Features
+//!
+//! - One file per environment (`data/{env}/ssh/known_hosts`)
+//! - Keys are compared as sets, ignoring their order and blank lines
+//! - The file is only readable by its owner
+
+use std::io;
+use std::path::{Path, PathBuf};
+
+/// Outcome of recording the host keys of an instance
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum HostKeyRecord {
+    /// No host key was known yet; the scanned keys were stored
+    Recorded,
+
+    /// The scanned keys match the stored ones
+    Unchanged,
+
+    /// The stored keys were replaced by the scanned ones
+    Rotated,
+}
+
+/// `known_hosts` file holding the host keys of one instance
+#[derive(Debug, Clone)]
+pub struct KnownHostsFile {
+    path: PathBuf,
+}
+
+impl KnownHostsFile {
+    /// Create a handle for the `known_hosts` file at `path`
+    ///
+    /// The file is created by the first [`Self::write`].
+    #[must_use]
+    pub fn new(path: PathBuf) -> Self {
+        Self { path }
+    }
+
+    /// Path of the file
+    #[must_use]
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+
+    /// Stored host keys, sorted, or `None` when the file does not exist
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the file exists but cannot be read.
+    pub fn read(&self) -> io::Result<Option<Vec<String>>> {
+        match std::fs::read_to_string(&self.path) {
+            Ok(content) => Ok(Some(normalize(content.lines()))),
+            Err(e) if e.kind() == io::ErrorKind::NotFound => Ok(None),
+            Err(e) => Err(e),
+        }
+    }
+
+    /// Replace the stored host keys
+    ///
+    /// Creates the parent directory if needed.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the directory or the file cannot be written.
+    pub fn write(&self, keys: &[String]) -> io::Result<()> {
+        if let Some(parent) = self.path.parent() {
+            std::fs::create_dir_all(parent)?;
+        }
+
+        let mut content = normalize(keys.iter().map(String::as_str)).join("\n");
+        content.push('\n');
+        std::fs::write(&self.path, content)?;
+
+        #[cfg(unix)]
+        {
+            use std::os::unix::fs::PermissionsExt;
+            std::fs::set_permissions(&self.path, std::fs::Permissions::from_mode(0o600))?;
+        }
+
+        Ok(())
+    }
+}
+
+/// Host key lines without comments or blank lines, sorted and deduplicated
+pub(crate) fn normalize<'a>(lines: impl Iterator<Item = &'a str>) -> Vec<String> {
+    let mut keys: Vec<String> = lines
+        .map(str::trim)
+        .filter(|line| !line.is_empty() && !line.starts_with('#'))
+        .map(ToString::to_string)
+        .collect();
+    keys.sort();
+    keys.dedup();
+    keys
+}
+
+#[cfg(test)]
+mod tests {
+    use tempfile::TempDir;
+
+    use super::*;
+
+    const ED25519: &str = "10.0.0.1 ssh-ed25519 AAAAC3NzaC1lZDI1NTE5AAAAIExample";
+    const RSA: &str = "10.0.0.1 ssh-rsa AAAAB3NzaC1yc2EAAAADAQABAAABAQExample";
+
+    #[test]
+    fn it_should_return_none_when_the_file_does_not_exist() {
+        let temp_dir = TempDir::new().unwrap();
+        let file = KnownHostsFile::new(temp_dir.path().join("ssh").join("known_hosts"));
+
+        assert_eq!(file.read().unwrap(), None);
+    }
+
+    #[test]
+    fn it_should_read_back_the_written_keys_sorted() {
+        let temp_dir = TempDir::new().unwrap();
+        let file = KnownHostsFile::new(temp_dir.path().join("ssh").join("known_hosts"));
+
+        file.write(&[RSA.to_string(), ED25519.to_string()]).unwrap();
+
+        assert_eq!(
+            file.read().unwrap(),
+            Some(vec![ED25519.to_string(), RSA.to_string()])
+        );
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn it_should_make_the_file_readable_by_its_owner_only() {
+        use std::os::unix::fs::PermissionsExt;
+
+        let temp_dir = TempDir::new().unwrap();
+        let file = KnownHostsFile::new(temp_dir.path().join("known_hosts"));
+
+        file.write(&[ED25519.to_string()]).unwrap();
+
+        let mode = std::fs::metadata(file.path()).unwrap().permissions().mode();
+        assert_eq!(mode & 0o777, 0o600);
+    }
+
+    #[test]
+    fn it_should_ignore_comments_and_blank_lines() {
+        let keys = normalize(["# 10.0.0.1:22 SSH-2.0-OpenSSH", "", ED25519].into_iter());
+
+        assert_eq!(keys, vec![ED25519.to_string()]);
+    }
+}
@@ -10,14 +10,19 @@
 //! - `config` - SSH configuration and management
 //! - `credentials` - SSH authentication credentials and key management
 //! - `error` - SSH error types and implementations
-//! - `key_inspector` - Best-effort detection of passphrase-protected private keys
+//! - `key_generator` - Generation of passphrase-free deployment key pairs
+//! - `key_inspector` - Best-effort inspection of private keys (passphrase, permissions, key pair match)
+//! - `known_hosts` - Per-environment `known_hosts` files for strict host key checking
+//! - `multiplexer` - Lifecycle of persistent connections shared through control sockets
 //! - `public_key` - SSH public key representation and validation
+//! - `retry` - Retry policy for remote commands interrupted by network failures
 //! - `service_checker` - SSH service availability testing without authentication
 //!
 //! ## Key Features
 //!
 //! - Private key authentication with configurable credentials
 //! - Connection timeout and retry mechanisms
+//! - Automatic retries, with backoff, of commands interrupted by network failures
 //! - Secure remote command execution with error handling
 //! - SSH service availability checking for connectivity testing
 //! - Integration with deployment automation workflows
@@ -29,8 +34,12 @@ pub mod client;
 pub mod config;
 pub mod credentials;
 pub mod error;
+pub mod key_generator;
 pub mod key_inspector;
+pub mod known_hosts;
+pub mod multiplexer;
 pub mod public_key;
+pub mod retry;
 pub mod service_checker;
 
 pub use client::SshClient;
@@ -40,6 +49,16 @@ pub use config::{
 };
 pub use credentials::SshCredentials;
 pub use error::SshError;
-pub use key_inspector::is_passphrase_protected;
+pub use key_generator::{securely_remove_key, SshKeyGenerationError, SshKeyGenerator};
+pub use key_inspector::{
+    check_key_pair, check_key_permissions, is_passphrase_protected, KeyPairCheck,
+    KeyPermissionsCheck,
+};
+pub use known_hosts::{HostKeyRecord, KnownHostsFile};
+pub use multiplexer::SshMultiplexer;
 pub use public_key::SshPublicKey;
+pub use retry::{
+    transient_failure_reason, transient_reason, SshRetry, SshRetryHandler, SshRetryPolicy,
+    DEFAULT_SSH_RETRY_ATTEMPTS,
+};
 pub use service_checker::SshServiceChecker;
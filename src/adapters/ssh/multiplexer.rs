@@ -0,0 +1,146 @@
+//! SSH connection multiplexing lifecycle management
+//!
+//! This module provides the `SshMultiplexer` which owns a directory of `OpenSSH`
+//! control sockets. Clients whose `SshConfig` points at that directory share
+//! one persistent connection per host, so running many short remote commands
+//! costs a single SSH handshake instead of one per command.
+//!
+//! ## Key Features
+//!
+//! - One control socket directory per command handler invocation
+//! - Master connections are stopped (`ssh -O exit`) when the multiplexer is dropped
+//! - The control socket directory is removed afterwards, leaving no stale sockets
+//!
+//! ## Usage
+//!
+//! ```rust,no_run
+//! # use std::net::{IpAddr, Ipv4Addr};
+//! # use std::path::PathBuf;
+//! # use torrust_tracker_deployer_lib::shared::Username;
+//! use torrust_tracker_deployer_lib::adapters::ssh::{
+//!     SshClient, SshConfig, SshCredentials, SshMultiplexer,
+//! };
+//!
+//! # let credentials = SshCredentials::new(
+//! #     PathBuf::from("/path/to/key"),
+//! #     PathBuf::from("/path/to/key.pub"),
+//! #     Username::new("torrust").unwrap(),
+//! # );
+//! let multiplexer = SshMultiplexer::new(PathBuf::from("build/dev/ssh"));
+//! let config = SshConfig::with_default_port(credentials, IpAddr::V4(Ipv4Addr::LOCALHOST))
+//!     .with_control_dir(multiplexer.control_dir().to_path_buf());
+//!
+//! let client = SshClient::new(config);
+//! client.execute("uptime").ok();
+//! client.execute("df -h").ok(); // reuses the connection opened by `uptime`
+//!
+//! drop(multiplexer); // closes the connection and removes `build/dev/ssh`
+//! ```
+
+use std::path::{Path, PathBuf};
+
+use tracing::{debug, warn};
+
+use crate::shared::command::CommandExecutor;
+
+/// Owner of a directory of SSH control sockets
+///
+/// Stops every master connection in the directory and removes it on drop.
+#[derive(Debug)]
+pub struct SshMultiplexer {
+    control_dir: PathBuf,
+}
+
+impl SshMultiplexer {
+    /// Create a multiplexer for the given control socket directory
+    ///
+    /// The directory is created lazily by the first SSH command that uses it.
+    #[must_use]
+    pub fn new(control_dir: PathBuf) -> Self {
+        Self { control_dir }
+    }
+
+    /// Directory holding the control sockets
+    #[must_use]
+    pub fn control_dir(&self) -> &Path {
+        &self.control_dir
+    }
+
+    /// Stop all master connections and remove the control socket directory
+    ///
+    /// Errors are logged but not returned: a master may already have exited
+    /// and cleanup must not mask the result of the command that used it.
+    pub fn close(&self) {
+        let Ok(entries) = std::fs::read_dir(&self.control_dir) else {
+            return;
+        };
+
+        for entry in entries.flatten() {
+            stop_master(&entry.path());
+        }
+
+        if let Err(e) = std::fs::remove_dir_all(&self.control_dir) {
+            warn!(
+                operation = "ssh_multiplexing",
+                control_dir = %self.control_dir.display(),
+                error = %e,
+                "Failed to remove SSH control socket directory"
+            );
+        }
+    }
+}
+
+impl Drop for SshMultiplexer {
+    fn drop(&mut self) {
+        self.close();
+    }
+}
+
+/// Stop the master connection listening on `control_path`, if any
+///
+/// The destination is required by `ssh` but ignored when the control path is
+/// given explicitly.
+pub(crate) fn stop_master(control_path: &Path) {
+    let control_path = control_path.to_string_lossy();
+    let result = CommandExecutor::new().run_command(
+        "ssh",
+        &["-S", &control_path, "-O", "exit", "multiplexed-host"],
+        None,
+    );
+
+    debug!(
+        operation = "ssh_multiplexing",
+        control_path = %control_path,
+        stopped = result.is_ok(),
+        "Stopped SSH master connection"
+    );
+}
+
+#[cfg(test)]
+mod tests {
+    use tempfile::TempDir;
+
+    use super::*;
+
+    #[test]
+    fn it_should_remove_the_control_directory_when_dropped() {
+        let temp_dir = TempDir::new().unwrap();
+        let control_dir = temp_dir.path().join("ssh");
+        std::fs::create_dir_all(&control_dir).unwrap();
+        std::fs::write(control_dir.join("stale-socket"), "").unwrap();
+
+        drop(SshMultiplexer::new(control_dir.clone()));
+
+        assert!(!control_dir.exists());
+    }
+
+    #[test]
+    fn it_should_ignore_a_control_directory_that_was_never_created() {
+        let temp_dir = TempDir::new().unwrap();
+        let multiplexer = SshMultiplexer::new(temp_dir.path().join("ssh"));
+
+        multiplexer.close();
+
+        assert!(!multiplexer.control_dir().exists());
+    }
+}
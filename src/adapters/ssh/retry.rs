@@ -0,0 +1,353 @@
+//! Retry policy for remote commands interrupted by network failures
+//!
+//! A remote command run over a flaky connection can fail because the
+//! connection was refused, reset or timed out, even though running it again
+//! a few seconds later succeeds. [`SshRetryPolicy`] decides how many times
+//! such a command is run and how long to wait between attempts, with an
+//! exponential backoff.
+//!
+//! ## Classification
+//!
+//! Only network failures are retried ([`transient_failure_reason`]):
+//!
+//! - `ssh` exits with code 255 when it fails itself; any other exit code is
+//!   the one of the remote command, which fails at once
+//! - Among the `ssh` failures, authentication failures and host key
+//!   mismatches fail at once as well: retrying cannot fix them
+//!
+//! Ansible reports the same failures as unreachable hosts, whose message
+//! contains the `ssh` error; it is classified with [`transient_reason`].
+
+use std::fmt;
+use std::time::Duration;
+
+use crate::shared::command::CommandError;
+
+/// Default number of times a command interrupted by a network failure is run
+pub const DEFAULT_SSH_RETRY_ATTEMPTS: u32 = 4;
+
+/// Default wait before the first retry, doubled before each following one
+pub const DEFAULT_SSH_RETRY_INITIAL_BACKOFF: Duration = Duration::from_secs(1);
+
+/// Default upper bound of the wait between two attempts
+pub const DEFAULT_SSH_RETRY_MAX_BACKOFF: Duration = Duration::from_secs(8);
+
+/// Exit code of `ssh` when the failure is its own, not the remote command's
+const SSH_ERROR_EXIT_CODE: &str = "255";
+
+/// Errors (lowercase) of connections that may succeed when retried
+const TRANSIENT_ERRORS: &[&str] = &[
+    "connection refused",
+    "connection reset",
+    "connection closed",
+    "closed by remote host",
+    "timed out",
+    "broken pipe",
+    "no route to host",
+    "network is unreachable",
+    "kex_exchange_identification",
+    "mux_client",
+    "data could not be sent to remote host",
+];
+
+/// Errors (lowercase) that retrying cannot fix, even on a dropped connection
+const PERMANENT_ERRORS: &[&str] = &[
+    "permission denied",
+    "too many authentication failures",
+    "host key verification failed",
+    "remote host identification has changed",
+];
+
+/// How many times a command interrupted by a network failure is run
+///
+/// The wait between two attempts starts at `initial_backoff` and doubles
+/// after each failed attempt, up to `max_backoff`. With the defaults a
+/// command is run up to 4 times over about 7 seconds of waits (1s, 2s, 4s).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct SshRetryPolicy {
+    max_attempts: u32,
+    initial_backoff: Duration,
+    max_backoff: Duration,
+}
+
+impl SshRetryPolicy {
+    /// Create a policy running a command up to `max_attempts` times
+    ///
+    /// A `max_attempts` of `0` is treated as `1` (no retries).
+    #[must_use]
+    pub fn new(max_attempts: u32, initial_backoff: Duration, max_backoff: Duration) -> Self {
+        Self {
+            max_attempts: max_attempts.max(1),
+            initial_backoff,
+            max_backoff,
+        }
+    }
+
+    /// A policy running each command once
+    #[must_use]
+    pub fn no_retries() -> Self {
+        Self::new(1, Duration::ZERO, Duration::ZERO)
+    }
+
+    /// Maximum number of times a command is run
+    #[must_use]
+    pub fn max_attempts(&self) -> u32 {
+        self.max_attempts
+    }
+
+    /// Wait after the failed attempt number `attempt` (starting at 1)
+    #[must_use]
+    pub fn backoff(&self, attempt: u32) -> Duration {
+        let factor = 2u32.saturating_pow(attempt.saturating_sub(1));
+        self.initial_backoff
+            .saturating_mul(factor)
+            .min(self.max_backoff)
+    }
+
+    /// Run `operation` until it succeeds, fails for good or runs out of attempts
+    ///
+    /// `operation` receives the number of the attempt (starting at 1).
+    /// `transient` returns the reason of a failure worth retrying, `None`
+    /// for the others, which are returned at once. `on_retry` is called
+    /// before waiting for each retry.
+    ///
+    /// # Errors
+    ///
+    /// Returns the error of the last attempt.
+    pub fn run<T, E>(
+        &self,
+        mut operation: impl FnMut(u32) -> Result<T, E>,
+        transient: impl Fn(&E) -> Option<String>,
+        on_retry: SshRetryHandler<'_>,
+    ) -> Result<T, E> {
+        let mut attempt = 1;
+
+        loop {
+            let error = match operation(attempt) {
+                Ok(value) => return Ok(value),
+                Err(error) => error,
+            };
+
+            if attempt >= self.max_attempts {
+                return Err(error);
+            }
+
+            let Some(reason) = transient(&error) else {
+                return Err(error);
+            };
+
+            let delay = self.backoff(attempt);
+            on_retry(&SshRetry {
+                failed_attempt: attempt,
+                max_attempts: self.max_attempts,
+                delay,
+                reason,
+            });
+            std::thread::sleep(delay);
+
+            attempt += 1;
+        }
+    }
+}
+
+impl Default for SshRetryPolicy {
+    fn default() -> Self {
+        Self::new(
+            DEFAULT_SSH_RETRY_ATTEMPTS,
+            DEFAULT_SSH_RETRY_INITIAL_BACKOFF,
+            DEFAULT_SSH_RETRY_MAX_BACKOFF,
+        )
+    }
+}
+
+/// A retry about to be made after a network failure
+///
+/// Displayed as `Connection lost (Connection reset by peer), retrying in 2s
+/// (attempt 3/4)`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SshRetry {
+    /// Number of the attempt that failed (starting at 1)
+    pub failed_attempt: u32,
+    /// Maximum number of attempts of the policy
+    pub max_attempts: u32,
+    /// Wait before the next attempt
+    pub delay: Duration,
+    /// The network error that made the attempt fail
+    pub reason: String,
+}
+
+impl fmt::Display for SshRetry {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "Connection lost ({}), retrying in {}s (attempt {}/{})",
+            self.reason,
+            self.delay.as_secs(),
+            self.failed_attempt + 1,
+            self.max_attempts
+        )
+    }
+}
+
+/// Callback told about each retry, e.g. to report it as progress
+pub type SshRetryHandler<'a> = &'a dyn Fn(&SshRetry);
+
+/// The network error in the output of a failed connection, if retrying may fix it
+///
+/// Returns the first line naming a transient error, or `None` when the
+/// output names none, or names an authentication or host key failure.
+#[must_use]
+pub fn transient_reason(output: &str) -> Option<String> {
+    let lowercase = output.to_lowercase();
+    if PERMANENT_ERRORS
+        .iter()
+        .any(|error| lowercase.contains(error))
+    {
+        return None;
+    }
+
+    output
+        .lines()
+        .find(|line| {
+            let line = line.to_lowercase();
+            TRANSIENT_ERRORS.iter().any(|error| line.contains(error))
+        })
+        .map(|line| line.trim().to_string())
+}
+
+/// The network error that made an `ssh` command fail, if retrying may fix it
+///
+/// Only failures of `ssh` itself (exit code 255) are considered; a remote
+/// command exiting with another code is never retried.
+#[must_use]
+pub fn transient_failure_reason(error: &CommandError) -> Option<String> {
+    match error {
+        CommandError::ExecutionFailed {
+            exit_code, stderr, ..
+        } if exit_code == SSH_ERROR_EXIT_CODE => transient_reason(stderr),
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::cell::RefCell;
+
+    use super::*;
+
+    fn ssh_failure(exit_code: &str, stderr: &str) -> CommandError {
+        CommandError::ExecutionFailed {
+            command: "ssh torrust@10.140.190.14 uptime".to_string(),
+            exit_code: exit_code.to_string(),
+            stdout: String::new(),
+            stderr: stderr.to_string(),
+        }
+    }
+
+    fn fast_policy(max_attempts: u32) -> SshRetryPolicy {
+        SshRetryPolicy::new(max_attempts, Duration::ZERO, Duration::ZERO)
+    }
+
+    #[test]
+    fn it_should_retry_connection_refused_reset_and_timed_out_failures() {
+        for stderr in [
+            "ssh: connect to host 10.140.190.14 port 22: Connection refused",
+            "Connection reset by 10.140.190.14 port 22",
+            "ssh: connect to host 10.140.190.14 port 22: Connection timed out",
+            "client_loop: send disconnect: Broken pipe",
+        ] {
+            assert_eq!(
+                transient_failure_reason(&ssh_failure("255", stderr)).as_deref(),
+                Some(stderr),
+                "{stderr}"
+            );
+        }
+    }
+
+    #[test]
+    fn it_should_not_retry_authentication_and_host_key_failures() {
+        for stderr in [
+            "torrust@10.140.190.14: Permission denied (publickey).",
+            "Host key verification failed.\r\nConnection closed by 10.140.190.14 port 22",
+        ] {
+            assert_eq!(transient_failure_reason(&ssh_failure("255", stderr)), None);
+        }
+    }
+
+    #[test]
+    fn it_should_not_retry_a_failing_remote_command() {
+        let error = ssh_failure("1", "docker: connection refused by the daemon");
+
+        assert_eq!(transient_failure_reason(&error), None);
+    }
+
+    #[test]
+    fn it_should_double_the_backoff_up_to_the_maximum() {
+        let policy = SshRetryPolicy::default();
+
+        assert_eq!(policy.backoff(1), Duration::from_secs(1));
+        assert_eq!(policy.backoff(2), Duration::from_secs(2));
+        assert_eq!(policy.backoff(3), Duration::from_secs(4));
+        assert_eq!(policy.backoff(5), Duration::from_secs(8));
+    }
+
+    #[test]
+    fn it_should_report_each_retry_until_the_operation_succeeds() {
+        let retries = RefCell::new(Vec::new());
+
+        let result = fast_policy(4).run(
+            |attempt| {
+                if attempt < 3 {
+                    Err("Connection reset")
+                } else {
+                    Ok(attempt)
+                }
+            },
+            |error| Some((*error).to_string()),
+            &|retry| retries.borrow_mut().push(retry.to_string()),
+        );
+
+        assert_eq!(result, Ok(3));
+        assert_eq!(
+            retries.into_inner(),
+            vec![
+                "Connection lost (Connection reset), retrying in 0s (attempt 2/4)",
+                "Connection lost (Connection reset), retrying in 0s (attempt 3/4)",
+            ]
+        );
+    }
+
+    #[test]
+    fn it_should_fail_at_once_on_a_permanent_failure() {
+        let attempts = RefCell::new(0);
+
+        let result: Result<(), _> = fast_policy(4).run(
+            |attempt| {
+                *attempts.borrow_mut() = attempt;
+                Err("exit code 1")
+            },
+            |_| None,
+            &|_| {},
+        );
+
+        assert_eq!(result, Err("exit code 1"));
+        assert_eq!(attempts.into_inner(), 1);
+    }
+
+    #[test]
+    fn it_should_stop_after_the_maximum_number_of_attempts() {
+        let attempts = RefCell::new(0);
+
+        let result: Result<(), _> = fast_policy(3).run(
+            |attempt| {
+                *attempts.borrow_mut() = attempt;
+                Err("Connection refused")
+            },
+            |error| Some((*error).to_string()),
+            &|_| {},
+        );
+
+        assert_eq!(result, Err("Connection refused"));
+        assert_eq!(attempts.into_inner(), 3);
+    }
+}
@@ -9,6 +9,8 @@
 //! - Instance information extraction from Terraform state
 //! - JSON output parsing for structured data access
 //! - Working directory management for Terraform projects
+//! - Per-operation timeouts ([`CommandTimeouts`]) that kill hung commands
+//! - Output forwarded line by line while long operations run ([`OutputLineHandler`])
 //! - Comprehensive error handling for all operations
 //!
 //! ## Supported Operations
@@ -19,24 +21,46 @@
 //! - `destroy` - Destroy managed infrastructure
 //! - `output` - Extract output values from state
 
-use std::net::IpAddr;
+use std::net::{IpAddr, Ipv4Addr, Ipv6Addr};
 use std::path::{Path, PathBuf};
+use std::time::Duration;
 
 use serde::{Deserialize, Serialize};
 use thiserror::Error;
 use tracing::info;
 
-use crate::shared::command::{CommandError, CommandExecutor};
+use crate::shared::command::{
+    CommandError, CommandExecutor, CommandResult, CommandTimeouts, OutputLineHandler, RunLog,
+};
 
 use super::json_parser::{OpenTofuJsonParser, ParseError};
 
 /// Container information extracted from `OpenTofu` outputs
+///
+/// At least one of `ipv4_address` and `ipv6_address` is set. `ssh_port` is
+/// only reported by providers that publish SSH on a different host port
+/// (e.g. the Docker provider).
 #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 pub struct InstanceInfo {
     pub image: String,
-    pub ip_address: IpAddr,
+    pub ipv4_address: Option<Ipv4Addr>,
+    pub ipv6_address: Option<Ipv6Addr>,
     pub name: String,
     pub status: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub ssh_port: Option<u16>,
+}
+
+impl InstanceInfo {
+    /// Returns every address of the instance, IPv4 first
+    #[must_use]
+    pub fn ip_addresses(&self) -> Vec<IpAddr> {
+        self.ipv4_address
+            .map(IpAddr::V4)
+            .into_iter()
+            .chain(self.ipv6_address.map(IpAddr::V6))
+            .collect()
+    }
 }
 
 /// Errors that can occur during `OpenTofu` operations
@@ -67,7 +91,12 @@ impl crate::shared::Traceable for OpenTofuError {
     }
 
     fn error_kind(&self) -> crate::shared::ErrorKind {
-        crate::shared::ErrorKind::InfrastructureOperation
+        match self {
+            Self::CommandError(CommandError::TimedOut { .. }) => crate::shared::ErrorKind::Timeout,
+            Self::CommandError(_) | Self::ParseError(_) => {
+                crate::shared::ErrorKind::InfrastructureOperation
+            }
+        }
     }
 }
 
@@ -79,9 +108,16 @@ impl crate::shared::Traceable for OpenTofuError {
 /// - Destroy infrastructure
 ///
 /// Uses `CommandExecutor` as a collaborator for actual command execution.
+/// Every operation is bounded by its timeout in [`CommandTimeouts`]
+/// (the defaults unless [`OpenTofuClient::with_timeouts`] is used).
+/// With [`OpenTofuClient::with_run_log`], the output of each operation is
+/// kept in the `tofu-{operation}` log file of the run.
 pub struct OpenTofuClient {
     working_dir: PathBuf,
+    executable: String,
     command_executor: CommandExecutor,
+    timeouts: CommandTimeouts,
+    run_log: Option<RunLog>,
 }
 
 impl OpenTofuClient {
@@ -93,12 +129,51 @@ impl OpenTofuClient {
     pub fn new<P: Into<PathBuf>>(working_dir: P) -> Self {
         Self {
             working_dir: working_dir.into(),
+            executable: "tofu".to_string(),
             command_executor: CommandExecutor::new(),
+            timeouts: CommandTimeouts::default(),
+            run_log: None,
         }
     }
 
+    /// Bound each operation with the given timeouts
+    #[must_use]
+    pub fn with_timeouts(mut self, timeouts: CommandTimeouts) -> Self {
+        self.timeouts = timeouts;
+        self
+    }
+
+    /// Write the output of each operation to the given run log
+    #[must_use]
+    pub fn with_run_log(mut self, run_log: RunLog) -> Self {
+        self.run_log = Some(run_log);
+        self
+    }
+
+    /// Set environment variables of every operation (e.g. a proxy for provider downloads)
+    ///
+    /// They are added to the environment inherited from the deployer.
+    #[must_use]
+    pub fn with_env_vars(mut self, vars: impl IntoIterator<Item = (String, String)>) -> Self {
+        for (key, value) in vars {
+            self.command_executor = self.command_executor.with_env(key, value);
+        }
+        self
+    }
+
+    /// Run the given executable instead of `tofu` from the `PATH`
+    #[must_use]
+    pub fn with_executable(mut self, executable: impl Into<String>) -> Self {
+        self.executable = executable.into();
+        self
+    }
+
     /// Initialize `OpenTofu` configuration
     ///
+    /// # Arguments
+    ///
+    /// * `on_output` - Receives each output line as `tofu` writes it
+    ///
     /// # Returns
     ///
     /// * `Ok(String)` - The stdout output if the command succeeds
@@ -109,14 +184,13 @@ impl OpenTofuClient {
     /// This function will return an error if:
     /// * The `OpenTofu` initialization fails
     /// * The working directory does not exist or is not accessible
-    pub fn init(&self) -> Result<String, CommandError> {
+    pub fn init(&self, on_output: OutputLineHandler<'_>) -> Result<String, CommandError> {
         info!(
             "Initializing OpenTofu in directory: {}",
             self.working_dir.display()
         );
 
-        self.command_executor
-            .run_command("tofu", &["init"], Some(&self.working_dir))
+        self.run_tofu(&["init"], self.timeouts.opentofu_init, on_output)
             .map(|result| result.stdout)
     }
 
@@ -139,8 +213,7 @@ impl OpenTofuClient {
             self.working_dir.display()
         );
 
-        self.command_executor
-            .run_command("tofu", &["validate"], Some(&self.working_dir))
+        self.run_tofu(&["validate"], self.timeouts.opentofu_validate, &|_, _| {})
             .map(|result| result.stdout)
     }
 
@@ -149,6 +222,7 @@ impl OpenTofuClient {
     /// # Arguments
     ///
     /// * `extra_args` - Additional arguments to pass to the tofu plan command (e.g., "-var-file=variables.tfvars")
+    /// * `on_output` - Receives each output line as `tofu` writes it
     ///
     /// # Returns
     ///
@@ -160,7 +234,11 @@ impl OpenTofuClient {
     /// This function will return an error if:
     /// * The `OpenTofu` plan fails
     /// * The configuration is not initialized
-    pub fn plan(&self, extra_args: &[&str]) -> Result<String, CommandError> {
+    pub fn plan(
+        &self,
+        extra_args: &[&str],
+        on_output: OutputLineHandler<'_>,
+    ) -> Result<String, CommandError> {
         info!(
             "Planning infrastructure changes in directory: {}",
             self.working_dir.display()
@@ -169,8 +247,44 @@ impl OpenTofuClient {
         let mut args = vec!["plan"];
         args.extend_from_slice(extra_args);
 
-        self.command_executor
-            .run_command("tofu", &args, Some(&self.working_dir))
+        self.run_tofu(&args, self.timeouts.opentofu_plan, on_output)
+            .map(|result| result.stdout)
+    }
+
+    /// Reconcile the state with the real infrastructure
+    ///
+    /// Resources deleted outside `OpenTofu` are dropped from the state. The
+    /// refresh is bounded by the plan timeout, as it reads the same resources.
+    ///
+    /// # Arguments
+    ///
+    /// * `extra_args` - Additional arguments to pass to the tofu refresh command (e.g., "-var-file=variables.tfvars")
+    /// * `on_output` - Receives each output line as `tofu` writes it
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(String)` - The stdout output if the command succeeds
+    /// * `Err(CommandError)` - Error describing what went wrong
+    ///
+    /// # Errors
+    ///
+    /// This function will return an error if:
+    /// * The `OpenTofu` refresh fails
+    /// * The configuration is not initialized
+    pub fn refresh(
+        &self,
+        extra_args: &[&str],
+        on_output: OutputLineHandler<'_>,
+    ) -> Result<String, CommandError> {
+        info!(
+            "Refreshing infrastructure state in directory: {}",
+            self.working_dir.display()
+        );
+
+        let mut args = vec!["refresh"];
+        args.extend_from_slice(extra_args);
+
+        self.run_tofu(&args, self.timeouts.opentofu_plan, on_output)
             .map(|result| result.stdout)
     }
 
@@ -180,6 +294,7 @@ impl OpenTofuClient {
     ///
     /// * `auto_approve` - Whether to automatically approve the changes without interactive confirmation
     /// * `extra_args` - Additional arguments to pass to the tofu apply command (e.g., "-var-file=variables.tfvars")
+    /// * `on_output` - Receives each output line as `tofu` writes it
     ///
     /// # Returns
     ///
@@ -191,7 +306,12 @@ impl OpenTofuClient {
     /// This function will return an error if:
     /// * The `OpenTofu` apply fails
     /// * The configuration is not initialized
-    pub fn apply(&self, auto_approve: bool, extra_args: &[&str]) -> Result<String, CommandError> {
+    pub fn apply(
+        &self,
+        auto_approve: bool,
+        extra_args: &[&str],
+        on_output: OutputLineHandler<'_>,
+    ) -> Result<String, CommandError> {
         info!(
             "Applying infrastructure changes in directory: {}",
             self.working_dir.display()
@@ -203,8 +323,7 @@ impl OpenTofuClient {
             args.push("-auto-approve");
         }
 
-        self.command_executor
-            .run_command("tofu", &args, Some(&self.working_dir))
+        self.run_tofu(&args, self.timeouts.opentofu_apply, on_output)
             .map(|result| result.stdout)
     }
 
@@ -214,6 +333,7 @@ impl OpenTofuClient {
     ///
     /// * `auto_approve` - Whether to automatically approve the destruction without interactive confirmation
     /// * `extra_args` - Additional arguments to pass to the tofu destroy command (e.g., "-var-file=variables.tfvars")
+    /// * `on_output` - Receives each output line as `tofu` writes it
     ///
     /// # Returns
     ///
@@ -225,7 +345,12 @@ impl OpenTofuClient {
     /// This function will return an error if:
     /// * The `OpenTofu` destroy fails
     /// * The configuration is not initialized
-    pub fn destroy(&self, auto_approve: bool, extra_args: &[&str]) -> Result<String, CommandError> {
+    pub fn destroy(
+        &self,
+        auto_approve: bool,
+        extra_args: &[&str],
+        on_output: OutputLineHandler<'_>,
+    ) -> Result<String, CommandError> {
         info!(
             "Destroying infrastructure in directory: {}",
             self.working_dir.display()
@@ -237,8 +362,7 @@ impl OpenTofuClient {
             args.push("-auto-approve");
         }
 
-        self.command_executor
-            .run_command("tofu", &args, Some(&self.working_dir))
+        self.run_tofu(&args, self.timeouts.opentofu_destroy, on_output)
             .map(|result| result.stdout)
     }
 
@@ -261,10 +385,10 @@ impl OpenTofuClient {
             self.working_dir.display()
         );
 
-        let output = self.command_executor.run_command(
-            "tofu",
+        let output = self.run_tofu(
             &["output", "-json"],
-            Some(&self.working_dir),
+            self.timeouts.opentofu_output,
+            &|_, _| {},
         )?;
 
         let instance_info = OpenTofuJsonParser::parse_instance_info(&output.stdout)?;
@@ -276,6 +400,38 @@ impl OpenTofuClient {
     pub fn working_dir(&self) -> &Path {
         &self.working_dir
     }
+
+    /// Run `tofu` in the working directory, killing it once the step's budget is spent
+    ///
+    /// Output lines are logged and passed to `on_output` as they are written,
+    /// and the whole output is written to the run log, if any.
+    fn run_tofu(
+        &self,
+        args: &[&str],
+        step_timeout: Duration,
+        on_output: OutputLineHandler<'_>,
+    ) -> Result<CommandResult, CommandError> {
+        let logged;
+        let executor = match &self.run_log {
+            Some(run_log) => {
+                let step = format!("tofu-{}", args.first().copied().unwrap_or_default());
+                logged = self
+                    .command_executor
+                    .clone()
+                    .with_run_log(run_log.clone(), step);
+                &logged
+            }
+            None => &self.command_executor,
+        };
+
+        executor.run_command_streaming(
+            &self.executable,
+            args,
+            Some(&self.working_dir),
+            self.timeouts.budget(step_timeout),
+            on_output,
+        )
+    }
 }
 
 #[cfg(test)]
@@ -6,7 +6,7 @@
 //! ## Key Features
 //!
 //! - Parsing `OpenTofu` output command JSON into instance information
-//! - IP address extraction from Terraform state outputs
+//! - IPv4 and IPv6 address extraction from Terraform state outputs
 //! - Error handling for malformed or unexpected JSON structures
 //! - Type-safe conversion from JSON to Rust structs
 //! - Support for complex nested JSON structures from Terraform state
@@ -14,7 +14,7 @@
 //! The parser encapsulates all JSON handling logic and provides a clean interface
 //! for converting `OpenTofu` command output into usable data structures.
 
-use std::net::IpAddr;
+use std::net::{IpAddr, Ipv4Addr, Ipv6Addr};
 use std::str::FromStr;
 
 use serde_json::Value;
@@ -32,6 +32,17 @@ pub enum ParseError {
     /// Required field is missing or has wrong type
     #[error("Field error: {message}")]
     FieldError { message: String },
+
+    /// The instance has neither an IPv4 nor an IPv6 address yet
+    ///
+    /// Carries the rest of the instance information, so that providers able
+    /// to look the address up elsewhere (e.g. the DHCP leases of a libvirt
+    /// network) can complete it.
+    #[error(
+        "instance '{}' has no IP address: ipv4_address and ipv6_address are both empty",
+        instance.name
+    )]
+    NoIpAddress { instance: Box<InstanceInfo> },
 }
 
 /// A JSON parser for `OpenTofu` command outputs.
@@ -59,6 +70,7 @@ impl OpenTofuJsonParser {
     /// * The JSON cannot be parsed
     /// * The `instance_info` section is missing
     /// * Required fields are missing or have wrong types
+    /// * The instance has no IP address yet (`ParseError::NoIpAddress`)
     pub fn parse_instance_info(json_output: &str) -> Result<InstanceInfo, ParseError> {
         let outputs: Value =
             serde_json::from_str(json_output).map_err(|e| ParseError::JsonError {
@@ -80,16 +92,7 @@ impl OpenTofuJsonParser {
             })?
             .to_string();
 
-        let ip_address_str = instance_info_value
-            .get("ip_address")
-            .and_then(|v| v.as_str())
-            .ok_or_else(|| ParseError::FieldError {
-                message: "ip_address field missing or not a string".to_string(),
-            })?;
-
-        let ip_address = IpAddr::from_str(ip_address_str).map_err(|e| ParseError::FieldError {
-            message: format!("ip_address field is not a valid IP address: {e}"),
-        })?;
+        let (ipv4_address, ipv6_address) = Self::parse_ip_addresses(instance_info_value)?;
 
         let name = instance_info_value
             .get("name")
@@ -107,12 +110,110 @@ impl OpenTofuJsonParser {
             })?
             .to_string();
 
-        Ok(InstanceInfo {
+        let ssh_port = Self::parse_optional_ssh_port(instance_info_value)?;
+
+        let instance = InstanceInfo {
             image,
-            ip_address,
+            ipv4_address,
+            ipv6_address,
             name,
             status,
-        })
+            ssh_port,
+        };
+
+        if instance.ip_addresses().is_empty() {
+            return Err(ParseError::NoIpAddress {
+                instance: Box::new(instance),
+            });
+        }
+
+        Ok(instance)
+    }
+
+    /// Parse the optional host port SSH is published on
+    ///
+    /// Only providers that do not expose SSH on the instance port report it
+    /// (e.g. the Docker provider). A missing or null field means the SSH port
+    /// of the environment configuration is used.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the field is present but not a valid port number.
+    fn parse_optional_ssh_port(instance_info_value: &Value) -> Result<Option<u16>, ParseError> {
+        match instance_info_value.get("ssh_port") {
+            None | Some(Value::Null) => Ok(None),
+            Some(value) => value
+                .as_u64()
+                .and_then(|port| u16::try_from(port).ok())
+                .filter(|port| *port != 0)
+                .map(Some)
+                .ok_or_else(|| ParseError::FieldError {
+                    message: format!("ssh_port field is not a valid port number: {value}"),
+                }),
+        }
+    }
+
+    /// Parse the IPv4 and IPv6 addresses of the instance
+    ///
+    /// Providers report a missing address family as an empty string. The
+    /// single `ip_address` field of templates written before dual-stack
+    /// support is still accepted (e.g. in template overrides).
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if an address is not valid for its field.
+    fn parse_ip_addresses(
+        instance_info_value: &Value,
+    ) -> Result<(Option<Ipv4Addr>, Option<Ipv6Addr>), ParseError> {
+        let mut ipv4_address = Self::parse_optional_ip(instance_info_value, "ipv4_address")?
+            .map(|ip| match ip {
+                IpAddr::V4(ipv4) => Ok(ipv4),
+                IpAddr::V6(_) => Err(ParseError::FieldError {
+                    message: format!("ipv4_address field is not an IPv4 address: {ip}"),
+                }),
+            })
+            .transpose()?;
+
+        let mut ipv6_address = Self::parse_optional_ip(instance_info_value, "ipv6_address")?
+            .map(|ip| match ip {
+                IpAddr::V6(ipv6) => Ok(ipv6),
+                IpAddr::V4(_) => Err(ParseError::FieldError {
+                    message: format!("ipv6_address field is not an IPv6 address: {ip}"),
+                }),
+            })
+            .transpose()?;
+
+        match Self::parse_optional_ip(instance_info_value, "ip_address")? {
+            Some(IpAddr::V4(ipv4)) => ipv4_address = ipv4_address.or(Some(ipv4)),
+            Some(IpAddr::V6(ipv6)) => ipv6_address = ipv6_address.or(Some(ipv6)),
+            None => {}
+        }
+
+        Ok((ipv4_address, ipv6_address))
+    }
+
+    /// Parse an optional IP address field, treating an empty string as absent
+    fn parse_optional_ip(
+        instance_info_value: &Value,
+        field: &str,
+    ) -> Result<Option<IpAddr>, ParseError> {
+        let Some(value) = instance_info_value.get(field) else {
+            return Ok(None);
+        };
+
+        let ip_str = value.as_str().ok_or_else(|| ParseError::FieldError {
+            message: format!("{field} field is not a string"),
+        })?;
+
+        if ip_str.is_empty() {
+            return Ok(None);
+        }
+
+        IpAddr::from_str(ip_str)
+            .map(Some)
+            .map_err(|e| ParseError::FieldError {
+                message: format!("{field} field is not a valid IP address: {e}"),
+            })
     }
 }
 
@@ -126,7 +227,8 @@ mod tests {
             "instance_info": {
                 "value": {
                     "image": "ubuntu:24.04",
-                    "ip_address": "10.140.190.68",
+                    "ipv4_address": "10.140.190.68",
+                    "ipv6_address": "",
                     "name": "torrust-tracker-vm",
                     "status": "Running"
                 }
@@ -137,11 +239,55 @@ mod tests {
 
         assert_eq!(result.image, "ubuntu:24.04");
         assert_eq!(
-            result.ip_address,
-            IpAddr::from_str("10.140.190.68").unwrap()
+            result.ipv4_address,
+            Some(Ipv4Addr::from_str("10.140.190.68").unwrap())
         );
+        assert_eq!(result.ipv6_address, None);
         assert_eq!(result.name, "torrust-tracker-vm");
         assert_eq!(result.status, "Running");
+        assert_eq!(result.ssh_port, None);
+    }
+
+    #[test]
+    fn it_should_parse_the_published_ssh_port_of_a_docker_instance() {
+        let json_output = r#"{
+            "instance_info": {
+                "value": {
+                    "image": "torrust-provisioned-instance:latest",
+                    "ipv4_address": "127.0.0.1",
+                    "ipv6_address": "",
+                    "name": "torrust-tracker-vm",
+                    "status": "running",
+                    "ssh_port": 49153
+                }
+            }
+        }"#;
+
+        let result = OpenTofuJsonParser::parse_instance_info(json_output).unwrap();
+
+        assert_eq!(result.ssh_port, Some(49153));
+    }
+
+    #[test]
+    fn it_should_fail_when_the_published_ssh_port_is_not_a_port_number() {
+        let json_output = r#"{
+            "instance_info": {
+                "value": {
+                    "image": "torrust-provisioned-instance:latest",
+                    "ipv4_address": "127.0.0.1",
+                    "name": "torrust-tracker-vm",
+                    "status": "running",
+                    "ssh_port": 70000
+                }
+            }
+        }"#;
+
+        let result = OpenTofuJsonParser::parse_instance_info(json_output);
+
+        assert!(result
+            .unwrap_err()
+            .to_string()
+            .contains("ssh_port field is not a valid port number"));
     }
 
     #[test]
@@ -215,6 +361,74 @@ mod tests {
             .contains("image field missing or not a string"));
     }
 
+    #[test]
+    fn it_should_parse_an_ipv6_only_instance() {
+        let json_output = r#"{
+            "instance_info": {
+                "value": {
+                    "image": "ubuntu:24.04",
+                    "ipv4_address": "",
+                    "ipv6_address": "fd42:1a2b::10",
+                    "name": "torrust-tracker-vm",
+                    "status": "Running"
+                }
+            }
+        }"#;
+
+        let result = OpenTofuJsonParser::parse_instance_info(json_output).unwrap();
+
+        assert_eq!(result.ipv4_address, None);
+        assert_eq!(
+            result.ipv6_address,
+            Some(Ipv6Addr::from_str("fd42:1a2b::10").unwrap())
+        );
+    }
+
+    #[test]
+    fn it_should_accept_the_single_ip_address_of_older_templates() {
+        let json_output = r#"{
+            "instance_info": {
+                "value": {
+                    "image": "ubuntu:24.04",
+                    "ip_address": "10.140.190.68",
+                    "name": "torrust-tracker-vm",
+                    "status": "Running"
+                }
+            }
+        }"#;
+
+        let result = OpenTofuJsonParser::parse_instance_info(json_output).unwrap();
+
+        assert_eq!(
+            result.ipv4_address,
+            Some(Ipv4Addr::from_str("10.140.190.68").unwrap())
+        );
+    }
+
+    #[test]
+    fn it_should_fail_when_the_instance_has_no_ip_address() {
+        let json_output = r#"{
+            "instance_info": {
+                "value": {
+                    "image": "ubuntu:24.04",
+                    "ipv4_address": "",
+                    "ipv6_address": "",
+                    "name": "torrust-tracker-vm",
+                    "status": "Running"
+                }
+            }
+        }"#;
+
+        let result = OpenTofuJsonParser::parse_instance_info(json_output);
+
+        let error = result.unwrap_err();
+        assert!(error.to_string().contains("has no IP address"));
+        assert!(matches!(
+            error,
+            ParseError::NoIpAddress { instance } if instance.name == "torrust-tracker-vm"
+        ));
+    }
+
     #[test]
     fn it_should_fail_when_ip_address_is_invalid() {
         let json_output = r#"{
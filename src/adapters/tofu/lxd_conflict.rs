@@ -0,0 +1,176 @@
+//! LXD conflicts reported by a failed `tofu apply`
+//!
+//! The LXD provider reports conflicts with existing resources as plain
+//! `Error:` diagnostics on stderr:
+//!
+//! ```text
+//! │ Error: Failed to create profile "torrust-profile-e2e": The profile already exists
+//! │ Error: Failed creating instance record: Instance "torrust-tracker-vm-e2e" already exists
+//! │ Error: Failed loading storage pool "default": Storage pool not found
+//! │ Error: Failed getting remote image info: The requested image couldn't be found
+//! ```
+//!
+//! Provisioning recognizes these to tell the user what went wrong and how to
+//! fix it, instead of reporting a generic apply failure.
+
+use std::fmt;
+
+use crate::shared::command::CommandError;
+
+/// A conflict between the LXD resources of an environment and the LXD host
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum LxdConflict {
+    /// A profile with the environment's profile name already exists
+    ProfileExists,
+    /// An instance with the environment's instance name already exists
+    InstanceExists,
+    /// The storage pool used by the instance does not exist
+    StoragePoolMissing,
+    /// The instance image could not be downloaded
+    ImageDownloadFailed,
+}
+
+impl LxdConflict {
+    /// Recognize the conflict in the output of `tofu apply`
+    ///
+    /// Returns the conflict of the first line that matches a known pattern,
+    /// or `None` when the output shows no LXD conflict.
+    #[must_use]
+    pub fn detect(output: &str) -> Option<Self> {
+        output.lines().find_map(Self::detect_line)
+    }
+
+    /// Recognize the conflict in the stderr of a failed `tofu apply`
+    ///
+    /// Only failed executions are considered: a timed out or unstarted
+    /// command never reached the LXD provider.
+    #[must_use]
+    pub fn from_command_error(error: &CommandError) -> Option<Self> {
+        match error {
+            CommandError::ExecutionFailed { stderr, .. } => Self::detect(stderr),
+            _ => None,
+        }
+    }
+
+    fn detect_line(line: &str) -> Option<Self> {
+        let line = line.to_lowercase();
+
+        if line.contains("already exists") {
+            if line.contains("profile") {
+                return Some(Self::ProfileExists);
+            }
+            if line.contains("instance") {
+                return Some(Self::InstanceExists);
+            }
+        }
+
+        if line.contains("storage pool")
+            && (line.contains("not found") || line.contains("doesn't exist"))
+        {
+            return Some(Self::StoragePoolMissing);
+        }
+
+        if line.contains("image")
+            && (line.contains("couldn't be found")
+                || line.contains("failed getting remote image")
+                || line.contains("failed to download"))
+        {
+            return Some(Self::ImageDownloadFailed);
+        }
+
+        None
+    }
+}
+
+impl fmt::Display for LxdConflict {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let description = match self {
+            Self::ProfileExists => "LXD profile already exists",
+            Self::InstanceExists => "LXD instance already exists",
+            Self::StoragePoolMissing => "LXD storage pool not found",
+            Self::ImageDownloadFailed => "LXD image download failed",
+        };
+        write!(f, "{description}")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn it_should_detect_an_existing_profile() {
+        let output = "╷\n│ Error: Failed to create profile \"torrust-profile-e2e\": Error inserting \"torrust-profile-e2e\" into database: The profile already exists\n╵";
+
+        assert_eq!(
+            LxdConflict::detect(output),
+            Some(LxdConflict::ProfileExists)
+        );
+    }
+
+    #[test]
+    fn it_should_detect_an_existing_instance() {
+        let output = "│ Error: Failed creating instance record: Add instance info to the database: Instance \"torrust-tracker-vm-e2e\" already exists";
+
+        assert_eq!(
+            LxdConflict::detect(output),
+            Some(LxdConflict::InstanceExists)
+        );
+    }
+
+    #[test]
+    fn it_should_detect_a_missing_storage_pool() {
+        let output = "│ Error: Failed loading storage pool \"default\": Storage pool not found";
+
+        assert_eq!(
+            LxdConflict::detect(output),
+            Some(LxdConflict::StoragePoolMissing)
+        );
+    }
+
+    #[test]
+    fn it_should_detect_a_failed_image_download() {
+        let output =
+            "│ Error: Failed getting remote image info: The requested image couldn't be found";
+
+        assert_eq!(
+            LxdConflict::detect(output),
+            Some(LxdConflict::ImageDownloadFailed)
+        );
+    }
+
+    #[test]
+    fn it_should_not_detect_a_conflict_in_unrelated_errors() {
+        let output =
+            "│ Error: Unsupported argument\n│ An argument named \"foo\" is not expected here.";
+
+        assert_eq!(LxdConflict::detect(output), None);
+    }
+
+    #[test]
+    fn it_should_detect_the_conflict_in_the_stderr_of_a_failed_command() {
+        let error = CommandError::ExecutionFailed {
+            command: "tofu apply".to_string(),
+            exit_code: "1".to_string(),
+            stdout: "lxd_profile.torrust_profile: Creating...".to_string(),
+            stderr: "│ Error: The profile already exists".to_string(),
+        };
+
+        assert_eq!(
+            LxdConflict::from_command_error(&error),
+            Some(LxdConflict::ProfileExists)
+        );
+    }
+
+    #[test]
+    fn it_should_ignore_timed_out_commands() {
+        let error = CommandError::TimedOut {
+            command: "tofu apply".to_string(),
+            timeout: std::time::Duration::from_secs(1),
+            stdout: String::new(),
+            stderr: "The profile already exists".to_string(),
+        };
+
+        assert_eq!(LxdConflict::from_command_error(&error), None);
+    }
+}
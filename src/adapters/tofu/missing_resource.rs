@@ -0,0 +1,131 @@
+//! Resources reported as already gone by a failed `tofu destroy`
+//!
+//! When an instance was deleted outside the deployer (e.g. with
+//! `lxc delete`), the provider fails to destroy what is still recorded in the
+//! `OpenTofu` state and reports it as plain `Error:` diagnostics on stderr:
+//!
+//! ```text
+//! │ Error: Failed to retrieve instance "torrust-tracker-vm-e2e": Instance not found
+//! │ Error: Failed to delete profile "torrust-profile-e2e": Profile not found
+//! │ Error: server not found (not_found)
+//! ```
+//!
+//! Destroying recognizes these as the desired end state already being
+//! reached, instead of reporting a failure.
+
+use crate::shared::command::CommandError;
+
+/// Fragments of a diagnostic saying that a resource no longer exists
+const MISSING_RESOURCE_PATTERNS: [&str; 5] = [
+    "not found",
+    "not_found",
+    "already deleted",
+    "already been deleted",
+    "no such object",
+];
+
+/// Fragments of a diagnostic about a missing local dependency, not a resource
+const LOCAL_FAILURE_PATTERNS: [&str; 3] = ["provider", "module", "executable"];
+
+/// Whether every error in the output of `tofu destroy` is a missing resource
+///
+/// Returns `false` when the output has no `Error:` diagnostic, or when any of
+/// them reports something else, so genuine failures are never hidden.
+#[must_use]
+pub fn reports_only_missing_resources(output: &str) -> bool {
+    let mut errors = output
+        .lines()
+        .filter(|line| line.contains("Error:"))
+        .peekable();
+
+    errors.peek().is_some() && errors.all(is_missing_resource_line)
+}
+
+/// Whether a failed `tofu destroy` only failed on resources already gone
+///
+/// Only failed executions are considered: a timed out or unstarted command
+/// never reached the provider.
+#[must_use]
+pub fn is_missing_resource_error(error: &CommandError) -> bool {
+    match error {
+        CommandError::ExecutionFailed { stderr, .. } => reports_only_missing_resources(stderr),
+        _ => false,
+    }
+}
+
+fn is_missing_resource_line(line: &str) -> bool {
+    let line = line.to_lowercase();
+
+    MISSING_RESOURCE_PATTERNS
+        .iter()
+        .any(|pattern| line.contains(pattern))
+        && !LOCAL_FAILURE_PATTERNS
+            .iter()
+            .any(|pattern| line.contains(pattern))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn failed_destroy(stderr: &str) -> CommandError {
+        CommandError::ExecutionFailed {
+            command: "tofu destroy".to_string(),
+            exit_code: "1".to_string(),
+            stdout: "lxd_instance.torrust_vm: Destroying...".to_string(),
+            stderr: stderr.to_string(),
+        }
+    }
+
+    #[test]
+    fn it_should_recognize_an_instance_deleted_outside_the_deployer() {
+        let output = "╷\n│ Error: Failed to retrieve instance \"torrust-tracker-vm-e2e\": Instance not found\n╵";
+
+        assert!(reports_only_missing_resources(output));
+    }
+
+    #[test]
+    fn it_should_recognize_several_missing_resources() {
+        let output = "│ Error: Instance not found\n│ Error: Failed to delete profile \"torrust-profile-e2e\": Profile not found\n│ Error: server not found (not_found)";
+
+        assert!(reports_only_missing_resources(output));
+    }
+
+    #[test]
+    fn it_should_not_hide_a_genuine_failure_next_to_a_missing_resource() {
+        let output = "│ Error: Instance not found\n│ Error: Failed to delete storage volume: device or resource busy";
+
+        assert!(!reports_only_missing_resources(output));
+    }
+
+    #[test]
+    fn it_should_not_treat_a_missing_provider_as_a_missing_resource() {
+        let output = "│ Error: Failed to query available provider packages: provider registry.opentofu.org/terraform-lxd/lxd not found";
+
+        assert!(!reports_only_missing_resources(output));
+    }
+
+    #[test]
+    fn it_should_not_recognize_output_without_errors() {
+        assert!(!reports_only_missing_resources("Instance not found"));
+    }
+
+    #[test]
+    fn it_should_recognize_the_missing_resource_in_the_stderr_of_a_failed_command() {
+        assert!(is_missing_resource_error(&failed_destroy(
+            "│ Error: Instance not found"
+        )));
+    }
+
+    #[test]
+    fn it_should_ignore_timed_out_commands() {
+        let error = CommandError::TimedOut {
+            command: "tofu destroy".to_string(),
+            timeout: std::time::Duration::from_secs(1),
+            stdout: String::new(),
+            stderr: "│ Error: Instance not found".to_string(),
+        };
+
+        assert!(!is_missing_resource_error(&error));
+    }
+}
@@ -8,6 +8,9 @@
 //!
 //! - `client` - Main `OpenTofuClient` for executing `OpenTofu` commands
 //! - `json_parser` - JSON output parsing for `OpenTofu` state and plan information
+//! - `lxd_conflict` - LXD conflicts recognized in the output of a failed apply
+//! - `missing_resource` - Resources already gone, recognized in the output of a failed destroy
+//! - `plan` - Resource changes listed by `tofu plan`
 //!
 //! ## Key Features
 //!
@@ -20,10 +23,15 @@ use std::path::Path;
 
 pub mod client;
 pub mod json_parser;
+pub mod lxd_conflict;
+pub mod missing_resource;
+pub mod plan;
 
 // Re-export the main types for easier access
 pub use client::{InstanceInfo, OpenTofuClient, OpenTofuError};
 pub use json_parser::ParseError;
+pub use lxd_conflict::LxdConflict;
+pub use plan::{PlannedAction, PlannedChange};
 
 /// Errors that can occur during emergency destroy operations
 #[derive(Debug)]
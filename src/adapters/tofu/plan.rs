@@ -0,0 +1,155 @@
+//! Resource changes listed by `tofu plan`
+//!
+//! `tofu plan` prints one heading per resource it would change:
+//!
+//! ```text
+//!   # lxd_instance.torrust_vm will be created
+//!   # hcloud_server.torrust_server will be destroyed
+//!   # hcloud_firewall.torrust_firewall will be updated in-place
+//! ```
+//!
+//! The dry-run of `provision` and `destroy` parses these headings to list the
+//! affected resources without applying the plan.
+
+use std::fmt;
+
+use serde::Serialize;
+
+/// What `tofu apply` would do to a resource
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum PlannedAction {
+    /// The resource would be created
+    Create,
+    /// The resource would be updated in place
+    Update,
+    /// The resource would be destroyed and created again
+    Replace,
+    /// The resource would be destroyed
+    Destroy,
+    /// The data source would be read
+    Read,
+}
+
+impl fmt::Display for PlannedAction {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let action = match self {
+            Self::Create => "create",
+            Self::Update => "update",
+            Self::Replace => "replace",
+            Self::Destroy => "destroy",
+            Self::Read => "read",
+        };
+        write!(f, "{action}")
+    }
+}
+
+/// A resource `tofu apply` would change
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct PlannedChange {
+    /// Resource address (e.g. `lxd_instance.torrust_vm`)
+    pub address: String,
+    /// What would happen to the resource
+    pub action: PlannedAction,
+}
+
+impl PlannedChange {
+    /// Parse the resource changes from the output of `tofu plan -no-color`
+    ///
+    /// Headings of unknown actions are ignored. Returns an empty list when the
+    /// plan has no changes.
+    #[must_use]
+    pub fn parse_plan(stdout: &str) -> Vec<Self> {
+        stdout.lines().filter_map(Self::parse_heading).collect()
+    }
+
+    fn parse_heading(line: &str) -> Option<Self> {
+        let heading = line.trim().strip_prefix("# ")?;
+        let (address, description) = heading.split_once(" will be ").or_else(|| {
+            heading
+                .split_once(" must be ")
+                .filter(|(_, description)| description.starts_with("replaced"))
+        })?;
+
+        let action = match description.trim() {
+            "created" => PlannedAction::Create,
+            "updated in-place" => PlannedAction::Update,
+            "destroyed" => PlannedAction::Destroy,
+            "read during apply" => PlannedAction::Read,
+            description if description.starts_with("replaced") => PlannedAction::Replace,
+            _ => return None,
+        };
+
+        Some(Self {
+            address: address.to_string(),
+            action,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const DESTROY_PLAN: &str = r"
+OpenTofu will perform the following actions:
+
+  # lxd_instance.torrust_vm will be destroyed
+  - resource lxd_instance.torrust_vm {
+      - name = torrust-tracker-vm-my-env -> null
+    }
+
+  # lxd_profile.torrust_profile will be destroyed
+  - resource lxd_profile.torrust_profile {
+      - name = torrust-profile-my-env -> null
+    }
+
+Plan: 0 to add, 0 to change, 2 to destroy.
+";
+
+    #[test]
+    fn it_should_list_the_resources_of_a_destroy_plan() {
+        let changes = PlannedChange::parse_plan(DESTROY_PLAN);
+
+        assert_eq!(
+            changes,
+            vec![
+                PlannedChange {
+                    address: "lxd_instance.torrust_vm".to_string(),
+                    action: PlannedAction::Destroy,
+                },
+                PlannedChange {
+                    address: "lxd_profile.torrust_profile".to_string(),
+                    action: PlannedAction::Destroy,
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn it_should_recognize_every_planned_action() {
+        let output = "  # a.one will be created\n  # a.two will be updated in-place\n  # a.three must be replaced\n  # data.a.four will be read during apply\n";
+
+        let actions: Vec<PlannedAction> = PlannedChange::parse_plan(output)
+            .into_iter()
+            .map(|change| change.action)
+            .collect();
+
+        assert_eq!(
+            actions,
+            [
+                PlannedAction::Create,
+                PlannedAction::Update,
+                PlannedAction::Replace,
+                PlannedAction::Read
+            ]
+        );
+    }
+
+    #[test]
+    fn it_should_return_no_changes_for_an_up_to_date_plan() {
+        let output = "No changes. Your infrastructure matches the configuration.";
+
+        assert!(PlannedChange::parse_plan(output).is_empty());
+    }
+}
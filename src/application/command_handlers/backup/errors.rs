@@ -0,0 +1,243 @@
+//! Error types for backup command handler
+
+use std::path::PathBuf;
+
+use crate::application::errors::{AllowedCommands, PersistenceError};
+use crate::infrastructure::remote_actions::{RemoteTransferError, TrackerDatabaseError};
+use crate::shared::error::kind::ErrorKind;
+use crate::shared::error::traceable::Traceable;
+
+/// Comprehensive error type for the `BackupCommandHandler`
+#[derive(Debug, thiserror::Error)]
+pub enum BackupCommandHandlerError {
+    #[error("Environment not found: '{name}'")]
+    EnvironmentNotFound { name: String },
+
+    #[error("Environment '{name}' is in state '{state}', but the tracker must be released or running to back up its database; {allowed_commands}")]
+    InvalidState {
+        name: String,
+        state: String,
+        allowed_commands: AllowedCommands,
+    },
+
+    #[error("Environment '{name}' does not have an instance IP set")]
+    MissingInstanceIp { name: String },
+
+    #[error("Failed to dump the tracker database: {0}")]
+    Dump(#[source] TrackerDatabaseError),
+
+    #[error("Failed to download the database dump: {0}")]
+    Download(#[source] RemoteTransferError),
+
+    #[error("Failed to write the backup to '{path}': {source}")]
+    WriteBackup {
+        path: PathBuf,
+        #[source]
+        source: std::io::Error,
+    },
+
+    #[error("State persistence error: {0}")]
+    StatePersistence(#[from] PersistenceError),
+}
+
+impl From<crate::domain::environment::repository::RepositoryError> for BackupCommandHandlerError {
+    fn from(e: crate::domain::environment::repository::RepositoryError) -> Self {
+        Self::StatePersistence(e.into())
+    }
+}
+
+impl Traceable for BackupCommandHandlerError {
+    fn trace_format(&self) -> String {
+        match self {
+            Self::EnvironmentNotFound { name } => {
+                format!("BackupCommandHandlerError: Environment not found - '{name}'")
+            }
+            Self::InvalidState { name, state, .. } => {
+                format!("BackupCommandHandlerError: Environment '{name}' is not released or running (state: {state})")
+            }
+            Self::MissingInstanceIp { name } => {
+                format!("BackupCommandHandlerError: Missing instance IP for environment '{name}'")
+            }
+            Self::Dump(e) => {
+                format!("BackupCommandHandlerError: Failed to dump the tracker database - {e}")
+            }
+            Self::Download(e) => {
+                format!("BackupCommandHandlerError: Failed to download the database dump - {e}")
+            }
+            Self::WriteBackup { path, source } => {
+                format!(
+                    "BackupCommandHandlerError: Failed to write the backup to '{}' - {source}",
+                    path.display()
+                )
+            }
+            Self::StatePersistence(e) => {
+                format!("BackupCommandHandlerError: State persistence error - {e}")
+            }
+        }
+    }
+
+    fn trace_source(&self) -> Option<&dyn Traceable> {
+        match self {
+            Self::Dump(e) => Some(e),
+            Self::Download(e) => Some(e),
+            Self::EnvironmentNotFound { .. }
+            | Self::InvalidState { .. }
+            | Self::MissingInstanceIp { .. }
+            | Self::WriteBackup { .. }
+            | Self::StatePersistence(_) => None,
+        }
+    }
+
+    fn error_kind(&self) -> ErrorKind {
+        match self {
+            Self::EnvironmentNotFound { .. }
+            | Self::InvalidState { .. }
+            | Self::MissingInstanceIp { .. } => ErrorKind::Configuration,
+            Self::Dump(e) => e.error_kind(),
+            Self::Download(e) => e.error_kind(),
+            Self::WriteBackup { .. } => ErrorKind::FileSystem,
+            Self::StatePersistence(_) => ErrorKind::StatePersistence,
+        }
+    }
+}
+
+impl BackupCommandHandlerError {
+    /// Provides detailed troubleshooting guidance for this error
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use torrust_tracker_deployer_lib::application::command_handlers::backup::BackupCommandHandlerError;
+    ///
+    /// let error = BackupCommandHandlerError::MissingInstanceIp {
+    ///     name: "my-env".to_string(),
+    /// };
+    ///
+    /// assert!(error.help().contains("show"));
+    /// ```
+    #[must_use]
+    pub fn help(&self) -> &'static str {
+        match self {
+            Self::EnvironmentNotFound { .. } => {
+                "Environment Not Found - Troubleshooting:
+
+1. Verify the environment name is correct
+2. List the existing environments:
+   torrust-tracker-deployer list
+
+For more information, see docs/user-guide/commands/backup.md"
+            }
+            Self::InvalidState { .. } => {
+                "Invalid Environment State - Troubleshooting:
+
+The tracker database only exists once the tracker has been released, so
+the environment must be in the 'released' or 'running' state.
+
+1. Check the environment state:
+   torrust-tracker-deployer show <env-name>
+
+2. Complete the deployment workflow up to the release:
+   torrust-tracker-deployer release <env-name>
+
+For more information, see docs/user-guide/commands/backup.md"
+            }
+            Self::MissingInstanceIp { .. } => {
+                "Missing Instance IP - Troubleshooting:
+
+The environment state has no instance IP, so the instance cannot be reached.
+
+1. Check the environment details:
+   torrust-tracker-deployer show <env-name>
+
+2. If the state file was edited by hand, restore it from a backup
+
+For more information, see docs/user-guide/commands/backup.md"
+            }
+            Self::Dump(_) => {
+                "Database Dump Failed - Troubleshooting:
+
+1. Check SSH connectivity to the instance:
+   ssh -i <ssh-key> <user>@<instance-ip>
+
+2. Check that the containers are up:
+   ssh <user>@<instance-ip> 'cd /opt/torrust && docker compose ps'
+
+3. Check the available disk space on the instance: df -h
+
+A SQLite tracker is started again even when the copy fails. If it is not
+running, start it with:
+   torrust-tracker-deployer run <env-name>
+
+For more information, see docs/user-guide/commands/backup.md"
+            }
+            Self::Download(RemoteTransferError::ChecksumMismatch { .. }) => {
+                "Backup Download Failed - Checksum Mismatch:
+
+The downloaded dump differs from the dump on the instance. The partial file
+was removed, so resuming downloads the whole dump again:
+   torrust-tracker-deployer backup <env-name> --resume
+
+If the mismatch persists, check the available disk space and the health of
+the disks of both hosts.
+
+For more information, see docs/user-guide/commands/backup.md"
+            }
+            Self::Download(_) => {
+                "Backup Download Failed - Troubleshooting:
+
+The dump is kept on the instance and the downloaded bytes in the .part file
+of the backup, so the download can continue where it stopped:
+   torrust-tracker-deployer backup <env-name> --resume
+
+1. Check SSH connectivity to the instance:
+   ssh -i <ssh-key> <user>@<instance-ip>
+
+2. Check the available disk space on both hosts: df -h
+
+For more information, see docs/user-guide/commands/backup.md"
+            }
+            Self::WriteBackup { .. } => {
+                "Writing the Backup Failed - Troubleshooting:
+
+1. Check that the data directory of the environment is writable
+2. Check the available disk space: df -h
+
+For more information, see docs/user-guide/commands/backup.md"
+            }
+            Self::StatePersistence(_) => {
+                "State Persistence Failed - Troubleshooting:
+
+The backup was downloaded, but it could not be recorded in the environment
+state. The backup itself is complete and can be restored.
+
+1. Check that the data directory is writable
+2. Check that no other command is running on the environment
+
+For more information, see docs/user-guide/commands/backup.md"
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn it_should_list_the_allowed_commands_when_the_state_is_invalid() {
+        let error = BackupCommandHandlerError::InvalidState {
+            name: "my-env".to_string(),
+            state: "provisioned".to_string(),
+            allowed_commands: AllowedCommands {
+                valid: vec!["configure".to_string(), "destroy".to_string()],
+                suggested_next: Some("configure".to_string()),
+            },
+        };
+
+        assert_eq!(
+            error.to_string(),
+            "Environment 'my-env' is in state 'provisioned', but the tracker must be released or running to back up its database; valid commands: configure, destroy; suggested next: configure"
+        );
+        assert_eq!(error.error_kind(), ErrorKind::Configuration);
+    }
+}
@@ -0,0 +1,298 @@
+//! Backup command handler implementation
+//!
+//! **Purpose**: Back up the tracker database of a deployed environment
+//!
+//! This handler dumps the tracker database on the instance, downloads the
+//! dump to `data/{env}/backups/{id}/` with a `manifest.json` describing it,
+//! and records the backup as the latest one in the environment state.
+//!
+//! An interrupted download keeps the dump on the instance and the partial
+//! file locally, so `backup --resume` continues it instead of starting over.
+
+use std::net::{IpAddr, SocketAddr};
+use std::path::{Path, PathBuf};
+use std::sync::Arc;
+
+use chrono::{DateTime, Utc};
+use tracing::{info, instrument};
+
+use super::errors::BackupCommandHandlerError;
+use crate::adapters::ssh::SshConfig;
+use crate::application::errors::AllowedCommands;
+use crate::domain::environment::repository::EnvironmentRepository;
+use crate::domain::environment::state::AnyEnvironmentState;
+use crate::domain::environment::{DatabaseBackup, BACKUP_MANIFEST_FILE_NAME};
+use crate::domain::EnvironmentName;
+use crate::infrastructure::remote_actions::{RemoteFileTransfer, TrackerDatabase};
+use crate::shared::Clock;
+
+/// Directory the database is dumped to on the instance, before the download
+pub const REMOTE_BACKUP_DIR: &str = "/tmp/torrust-tracker-deployer/backups";
+
+/// `BackupCommandHandler` backs up the tracker database of an environment
+///
+/// The environment must be `Released` or `Running`: before the release
+/// there is no tracker database to back up.
+///
+/// ## Consistency
+///
+/// A `SQLite` tracker is stopped while its database file is copied, and
+/// started again right after. A `MySQL` database is dumped in a single
+/// transaction while the tracker keeps running.
+///
+/// ## Resuming
+///
+/// A backup whose directory has no manifest was interrupted. When resuming,
+/// the most recent one is completed under its original id: its dump is
+/// downloaded again from where the partial file left off, and the database
+/// is only dumped again if the dump is gone from the instance.
+pub struct BackupCommandHandler {
+    repository: Arc<dyn EnvironmentRepository + Send + Sync>,
+    clock: Arc<dyn Clock>,
+}
+
+impl BackupCommandHandler {
+    /// Create a new `BackupCommandHandler`
+    #[must_use]
+    pub fn new(
+        repository: Arc<dyn EnvironmentRepository + Send + Sync>,
+        clock: Arc<dyn Clock>,
+    ) -> Self {
+        Self { repository, clock }
+    }
+
+    /// Back up the tracker database of an environment
+    ///
+    /// With `resume`, the most recent interrupted backup is completed
+    /// instead of taking a new one, if there is any.
+    ///
+    /// Returns the backup, as written to its manifest, and the path of the
+    /// backup file.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if:
+    /// * Environment not found
+    /// * Environment is not `Released` or `Running`
+    /// * The database cannot be dumped or the dump downloaded
+    /// * The backup or its manifest cannot be written
+    /// * The environment state cannot be saved
+    #[instrument(
+        name = "command.backup",
+        skip_all,
+        fields(
+            command_type = "backup",
+            environment_name = %env_name
+        )
+    )]
+    pub fn execute(
+        &self,
+        env_name: &EnvironmentName,
+        resume: bool,
+    ) -> Result<(DatabaseBackup, PathBuf), BackupCommandHandlerError> {
+        let _lock = self.repository.lock(env_name)?;
+        let mut environment = self.repository.load(env_name)?.ok_or_else(|| {
+            BackupCommandHandlerError::EnvironmentNotFound {
+                name: env_name.to_string(),
+            }
+        })?;
+
+        let instance_ip = Self::check_environment(&environment)?;
+
+        let database = environment.tracker_config().core().database().clone();
+        let interrupted = if resume {
+            find_interrupted_backup(&environment.backups_dir())
+        } else {
+            None
+        };
+        let resuming = interrupted.is_some();
+        let (id, created_at) = interrupted.unwrap_or_else(|| {
+            let created_at = self.clock.now();
+            (DatabaseBackup::id_for(created_at), created_at)
+        });
+        let file = DatabaseBackup::file_name_for(&database);
+
+        let backup_dir = environment.backups_dir().join(&id);
+        std::fs::create_dir_all(&backup_dir).map_err(|source| {
+            BackupCommandHandlerError::WriteBackup {
+                path: backup_dir.clone(),
+                source,
+            }
+        })?;
+
+        let ssh_config = ssh_config(&environment, instance_ip);
+        let tracker_database = TrackerDatabase::new(
+            ssh_config.clone(),
+            database.clone(),
+            environment.tracker_config().replica_names(),
+        );
+        let transfer = RemoteFileTransfer::new(ssh_config);
+        let remote_path = format!("{REMOTE_BACKUP_DIR}/{id}/{file}");
+
+        let dump_kept = resuming
+            && transfer
+                .exists(&remote_path)
+                .map_err(BackupCommandHandlerError::Download)?;
+        if !dump_kept {
+            tracker_database
+                .dump(&remote_path)
+                .map_err(BackupCommandHandlerError::Dump)?;
+        }
+
+        // On failure the dump stays on the instance for `backup --resume`
+        let backup_file = backup_dir.join(&file);
+        let report = transfer
+            .download_file(&remote_path, &backup_file, &|_| {})
+            .map_err(BackupCommandHandlerError::Download)?;
+        tracker_database.remove(&remote_path);
+
+        let backup = DatabaseBackup {
+            id,
+            created_at,
+            driver: database.driver_name().to_string(),
+            file,
+            size: report.total_bytes,
+            sha256: report.sha256,
+        };
+        write_manifest(&backup_dir, &backup)?;
+
+        environment.record_backup(backup.clone());
+        self.repository.save(&environment)?;
+
+        info!(
+            command = "backup",
+            environment = %env_name,
+            backup_id = %backup.id,
+            size = backup.size,
+            resumed_from = report.resumed_from,
+            "Tracker database backed up"
+        );
+
+        Ok((backup, backup_file))
+    }
+
+    /// Check the environment can be backed up, returning its instance IP
+    fn check_environment(
+        environment: &AnyEnvironmentState,
+    ) -> Result<IpAddr, BackupCommandHandlerError> {
+        if !matches!(
+            environment,
+            AnyEnvironmentState::Released(_) | AnyEnvironmentState::Running(_)
+        ) {
+            return Err(BackupCommandHandlerError::InvalidState {
+                name: environment.name().to_string(),
+                state: environment.state_name().to_string(),
+                allowed_commands: AllowedCommands::from(environment),
+            });
+        }
+
+        environment
+            .instance_ip()
+            .ok_or_else(|| BackupCommandHandlerError::MissingInstanceIp {
+                name: environment.name().to_string(),
+            })
+    }
+}
+
+/// SSH configuration of the instance of an environment
+pub(crate) fn ssh_config(environment: &AnyEnvironmentState, instance_ip: IpAddr) -> SshConfig {
+    SshConfig::new(
+        environment.ssh_credentials().clone(),
+        SocketAddr::new(instance_ip, environment.ssh_port()),
+    )
+    .with_known_hosts(environment.ssh_known_hosts_path())
+}
+
+/// Find the most recent interrupted backup, returning its id and creation time
+///
+/// A backup directory without a manifest belongs to a backup that did not
+/// complete. Backup ids sort chronologically.
+fn find_interrupted_backup(backups_dir: &Path) -> Option<(String, DateTime<Utc>)> {
+    let entries = std::fs::read_dir(backups_dir).ok()?;
+
+    entries
+        .filter_map(Result::ok)
+        .filter(|entry| !entry.path().join(BACKUP_MANIFEST_FILE_NAME).exists())
+        .filter_map(|entry| {
+            let id = entry.file_name().to_str()?.to_string();
+            let created_at = DatabaseBackup::created_at_of(&id)?;
+            Some((id, created_at))
+        })
+        .max_by(|a, b| a.0.cmp(&b.0))
+}
+
+/// Write the manifest of a backup next to the backup file
+fn write_manifest(
+    backup_dir: &Path,
+    backup: &DatabaseBackup,
+) -> Result<(), BackupCommandHandlerError> {
+    let path = backup_dir.join(BACKUP_MANIFEST_FILE_NAME);
+    let json = serde_json::to_string_pretty(backup).map_err(|e| {
+        BackupCommandHandlerError::WriteBackup {
+            path: path.clone(),
+            source: e.into(),
+        }
+    })?;
+
+    std::fs::write(&path, json)
+        .map_err(|source| BackupCommandHandlerError::WriteBackup { path, source })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::environment::testing::EnvironmentTestBuilder;
+    use crate::infrastructure::persistence::file_repository_factory::FileRepositoryFactory;
+    use crate::shared::SystemClock;
+
+    #[test]
+    fn it_should_refuse_an_environment_that_is_not_released_or_running() {
+        let (env, _data_dir, _build_dir, temp_dir) = EnvironmentTestBuilder::new()
+            .with_name("my-env")
+            .build_with_custom_paths();
+        let repository = FileRepositoryFactory::new(std::time::Duration::from_secs(30))
+            .create(temp_dir.path().join("data"));
+        repository.save(&env.into_any()).unwrap();
+        let handler = BackupCommandHandler::new(repository, Arc::new(SystemClock));
+
+        let error = handler
+            .execute(&EnvironmentName::new("my-env".to_string()).unwrap(), false)
+            .unwrap_err();
+
+        assert!(matches!(
+            error,
+            BackupCommandHandlerError::InvalidState { ref state, .. } if state == "created"
+        ));
+        assert!(error.to_string().contains("suggested next: provision"));
+    }
+
+    #[test]
+    fn it_should_find_the_most_recent_backup_without_a_manifest() {
+        let backups_dir = tempfile::tempdir().unwrap();
+        for id in ["20261015T100000Z", "20261016T100000Z", "20261017T100000Z"] {
+            std::fs::create_dir(backups_dir.path().join(id)).unwrap();
+        }
+        std::fs::write(
+            backups_dir
+                .path()
+                .join("20261017T100000Z")
+                .join(BACKUP_MANIFEST_FILE_NAME),
+            "{}",
+        )
+        .unwrap();
+
+        let (id, _) = find_interrupted_backup(backups_dir.path()).unwrap();
+
+        assert_eq!(id, "20261016T100000Z");
+    }
+
+    #[test]
+    fn it_should_find_no_interrupted_backup_when_every_backup_completed() {
+        let backups_dir = tempfile::tempdir().unwrap();
+        let completed = backups_dir.path().join("20261016T100000Z");
+        std::fs::create_dir(&completed).unwrap();
+        std::fs::write(completed.join(BACKUP_MANIFEST_FILE_NAME), "{}").unwrap();
+
+        assert!(find_interrupted_backup(backups_dir.path()).is_none());
+    }
+}
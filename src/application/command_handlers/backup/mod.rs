@@ -0,0 +1,26 @@
+//! Backup Command Module
+//!
+//! This module implements the delivery-agnostic `BackupCommandHandler`
+//! for backing up the tracker database of a `Released` or `Running`
+//! environment.
+//!
+//! ## Backups
+//!
+//! Each backup is downloaded to `data/{env}/backups/{id}/`, where `id` is
+//! the UTC time it was taken (e.g. `20261016T143045Z`), next to a
+//! `manifest.json` with its size, SHA-256 checksum and database driver.
+//! The latest backup is also recorded in the environment state.
+//!
+//! ## Design Principles
+//!
+//! - **Short Write Pause**: A `SQLite` tracker is only stopped while its
+//!   database file is copied; `MySQL` is dumped in a single transaction
+//! - **Verified Download**: The dump is downloaded with checksum verification
+//! - **No Leftovers**: The dump is removed from the instance once downloaded
+
+pub mod errors;
+pub mod handler;
+
+// Re-export main types for convenience
+pub use errors::BackupCommandHandlerError;
+pub use handler::BackupCommandHandler;
@@ -0,0 +1,255 @@
+//! Error types for the clone command handler
+
+use std::path::PathBuf;
+
+use crate::application::errors::PersistenceError;
+use crate::domain::environment::UserInputsError;
+use crate::domain::tracker::TrackerConfigError;
+use crate::domain::{EnvironmentNameError, InstanceNameError, ProfileNameError};
+use crate::shared::error::kind::ErrorKind;
+use crate::shared::error::traceable::Traceable;
+
+/// Comprehensive error type for the `CloneCommandHandler`
+#[derive(Debug, thiserror::Error)]
+pub enum CloneCommandHandlerError {
+    /// The environment to clone does not exist
+    #[error("Environment not found: '{name}'")]
+    SourceNotFound { name: String },
+
+    /// An environment with the target name already exists
+    #[error("Environment '{name}' already exists")]
+    TargetAlreadyExists { name: String },
+
+    /// The target name does not fit the provider's instance name limit
+    #[error("Invalid target environment name: {0}")]
+    InvalidTargetName(#[source] Box<EnvironmentNameError>),
+
+    /// Another environment already uses the instance name of the clone
+    #[error("Instance name '{instance_name}' is already used by environment '{environment}'")]
+    InstanceNameInUse {
+        instance_name: String,
+        environment: String,
+    },
+
+    /// Shifting the tracker ports by the requested offset failed
+    #[error("Invalid port offset: {0}")]
+    InvalidPortOffset(#[source] TrackerConfigError),
+
+    /// The generated instance name is not valid for the target environment
+    #[error("Cannot derive an instance name for the cloned environment: {0}")]
+    InvalidInstanceName(#[source] InstanceNameError),
+
+    /// The derived LXD profile name is not valid for the target environment
+    #[error("Cannot derive an LXD profile name for the cloned environment: {0}")]
+    InvalidProfileName(#[source] ProfileNameError),
+
+    /// The cloned configuration violates an environment invariant
+    #[error("Cloned configuration is invalid: {0}")]
+    InvalidConfiguration(#[source] UserInputsError),
+
+    /// A generated SSH key of the source could not be copied for the clone
+    #[error("Failed to copy the generated SSH key to '{path}': {source}")]
+    SshKeyCopyFailed {
+        path: PathBuf,
+        #[source]
+        source: std::io::Error,
+    },
+
+    /// Repository operation failed
+    #[error("Repository operation failed: {0}")]
+    RepositoryError(#[from] PersistenceError),
+}
+
+impl From<crate::domain::environment::repository::RepositoryError> for CloneCommandHandlerError {
+    fn from(e: crate::domain::environment::repository::RepositoryError) -> Self {
+        Self::RepositoryError(e.into())
+    }
+}
+
+impl Traceable for CloneCommandHandlerError {
+    fn trace_format(&self) -> String {
+        match self {
+            Self::SourceNotFound { name } => {
+                format!("CloneCommandHandlerError: Environment not found - '{name}'")
+            }
+            Self::TargetAlreadyExists { name } => {
+                format!("CloneCommandHandlerError: Environment already exists - '{name}'")
+            }
+            Self::InvalidTargetName(e) => {
+                format!("CloneCommandHandlerError: Invalid target environment name - {e}")
+            }
+            Self::InstanceNameInUse {
+                instance_name,
+                environment,
+            } => {
+                format!("CloneCommandHandlerError: Instance name '{instance_name}' already used by '{environment}'")
+            }
+            Self::InvalidPortOffset(e) => {
+                format!("CloneCommandHandlerError: Invalid port offset - {e}")
+            }
+            Self::InvalidInstanceName(e) => {
+                format!("CloneCommandHandlerError: Invalid instance name - {e}")
+            }
+            Self::InvalidProfileName(e) => {
+                format!("CloneCommandHandlerError: Invalid profile name - {e}")
+            }
+            Self::InvalidConfiguration(e) => {
+                format!("CloneCommandHandlerError: Invalid configuration - {e}")
+            }
+            Self::SshKeyCopyFailed { path, source } => {
+                format!(
+                    "CloneCommandHandlerError: Failed to copy SSH key to '{}' - {source}",
+                    path.display()
+                )
+            }
+            Self::RepositoryError(e) => {
+                format!("CloneCommandHandlerError: Repository operation failed - {e}")
+            }
+        }
+    }
+
+    fn trace_source(&self) -> Option<&dyn Traceable> {
+        None
+    }
+
+    fn error_kind(&self) -> ErrorKind {
+        match self {
+            Self::SourceNotFound { .. }
+            | Self::TargetAlreadyExists { .. }
+            | Self::InvalidTargetName(_)
+            | Self::InstanceNameInUse { .. }
+            | Self::InvalidPortOffset(_)
+            | Self::InvalidInstanceName(_)
+            | Self::InvalidProfileName(_)
+            | Self::InvalidConfiguration(_) => ErrorKind::Configuration,
+            Self::SshKeyCopyFailed { .. } => ErrorKind::FileSystem,
+            Self::RepositoryError(_) => ErrorKind::StatePersistence,
+        }
+    }
+}
+
+impl CloneCommandHandlerError {
+    /// Provides detailed troubleshooting guidance for this error
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use torrust_tracker_deployer_lib::application::command_handlers::clone::CloneCommandHandlerError;
+    ///
+    /// let error = CloneCommandHandlerError::TargetAlreadyExists {
+    ///     name: "staging".to_string(),
+    /// };
+    ///
+    /// assert!(error.help().contains("Choose a different"));
+    /// ```
+    #[must_use]
+    pub fn help(&self) -> &'static str {
+        match self {
+            Self::SourceNotFound { .. } => {
+                "Environment Not Found - Troubleshooting:
+
+1. Verify the source environment name is correct
+2. List the existing environments:
+   torrust-tracker-deployer list
+
+3. Create the source environment first if it does not exist:
+   torrust-tracker-deployer create environment --env-file <config.json>
+
+For more information, see docs/user-guide/commands/clone.md"
+            }
+            Self::TargetAlreadyExists { .. } => {
+                "Environment Already Exists - Troubleshooting:
+
+1. Choose a different name for the cloned environment
+2. Or remove the existing environment first:
+   torrust-tracker-deployer destroy <name>
+   torrust-tracker-deployer purge <name>
+
+Cloning never overwrites an existing environment.
+
+For more information, see docs/user-guide/commands/clone.md"
+            }
+            Self::InstanceNameInUse { .. } => {
+                "Instance Name Already In Use - Troubleshooting:
+
+The instance name of the clone is derived from the target environment name
+('torrust-tracker-vm-<name>'), and another environment already uses it.
+Two environments sharing an instance would provision, configure and destroy
+the same machine.
+
+1. Find the environment using the name:
+   torrust-tracker-deployer show <environment>
+
+2. Choose a different name for the cloned environment
+
+3. Or purge the other environment if it is no longer needed:
+   torrust-tracker-deployer destroy <environment>
+   torrust-tracker-deployer purge <environment>
+
+For more information, see docs/user-guide/commands/clone.md"
+            }
+            Self::InvalidPortOffset(_) => {
+                "Invalid Port Offset - Troubleshooting:
+
+1. Every shifted tracker port must stay within 1-65535
+2. Check the highest port used by the source environment:
+   torrust-tracker-deployer show <source>
+
+3. Use a smaller --offset value
+
+For more information, see docs/user-guide/commands/clone.md"
+            }
+            Self::InvalidTargetName(_)
+            | Self::InvalidInstanceName(_)
+            | Self::InvalidProfileName(_) => {
+                "Invalid Derived Name - Troubleshooting:
+
+The instance and profile names of the clone are derived from the target
+environment name, e.g. 'torrust-tracker-vm-<name>'.
+
+1. Use a shorter target environment name
+2. Use only lowercase letters, digits and dashes
+
+For more information, see docs/user-guide/commands/clone.md"
+            }
+            Self::InvalidConfiguration(_) => {
+                "Invalid Cloned Configuration - Troubleshooting:
+
+The source environment configuration no longer satisfies the environment
+invariants (e.g. Grafana requires Prometheus).
+
+1. Inspect the source environment:
+   torrust-tracker-deployer show <source>
+
+2. Create the new environment from a fixed configuration file instead:
+   torrust-tracker-deployer create environment --env-file <config.json>
+
+For more information, see docs/user-guide/commands/clone.md"
+            }
+            Self::SshKeyCopyFailed { .. } => {
+                "SSH Key Copy Failed - Troubleshooting:
+
+The SSH keys the deployer generated for the source environment are copied
+to data/<target>/ssh, so that purging the source does not delete the keys
+of the clone.
+
+1. Check that the source keys still exist:
+   ls data/<source>/ssh
+
+2. Check file system permissions for the data/ directory
+3. Verify sufficient disk space is available
+
+For more information, see docs/user-guide/commands/clone.md"
+            }
+            Self::RepositoryError(_) => {
+                "Repository Operation Failed - Troubleshooting:
+
+1. Check file system permissions for the data/ directory
+2. Verify sufficient disk space is available
+3. Check whether another process holds the environment lock
+
+For more information, see docs/user-guide/commands/clone.md"
+            }
+        }
+    }
+}
@@ -0,0 +1,643 @@
+//! Clone command handler implementation
+//!
+//! **Purpose**: Create a new environment from the configuration of an existing
+//! one, e.g. a staging environment mirroring production
+//!
+//! ## Design Strategy
+//!
+//! 1. **Uniqueness**: Refuse a target name that is already in use
+//! 2. **Source**: Load the source environment in any state
+//! 3. **Identity**: Regenerate the names derived from the environment name
+//!    (instance name, LXD profile name, data and build directories), refusing
+//!    an instance name another environment already uses
+//! 4. **Ports**: Optionally shift the tracker ports by a fixed offset
+//! 5. **SSH keys**: Copy the keys generated for the source into the data
+//!    directory of the clone, so that purging the source leaves them intact.
+//!    Keys supplied by the user are shared.
+//! 6. **Persistence**: Save the clone in the `Created` state
+//!
+//! Only the user inputs are copied. Runtime outputs such as the instance IP
+//! belong to the source deployment and are never carried over.
+
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::sync::Arc;
+
+use tracing::{info, instrument, warn};
+
+use super::errors::CloneCommandHandlerError;
+use crate::adapters::ssh::SshCredentials;
+use crate::application::command_handlers::create::config::validated_params::generate_checked_instance_name;
+use crate::domain::environment::repository::EnvironmentRepository;
+use crate::domain::environment::{
+    AnyEnvironmentState, Created, Environment, EnvironmentName, EnvironmentParams, UserInputs,
+    WorkspaceLayout,
+};
+use crate::domain::provider::{DigitalOceanConfig, LxdConfig, ProviderConfig};
+use crate::domain::{InstanceName, ProfileName};
+use crate::shared::Clock;
+
+/// Prefix of the LXD profile names generated when the source profile name
+/// does not end with the source environment name
+const PROFILE_PREFIX: &str = "torrust-profile-";
+
+/// `CloneCommandHandler` duplicates an environment under a new name
+pub struct CloneCommandHandler {
+    repository: Arc<dyn EnvironmentRepository + Send + Sync>,
+    clock: Arc<dyn Clock>,
+}
+
+impl CloneCommandHandler {
+    /// Create a new `CloneCommandHandler`
+    ///
+    /// # Arguments
+    ///
+    /// * `repository` - Repository for loading the source and saving the clone
+    /// * `clock` - Clock for the creation timestamp of the clone
+    #[must_use]
+    pub fn new(
+        repository: Arc<dyn EnvironmentRepository + Send + Sync>,
+        clock: Arc<dyn Clock>,
+    ) -> Self {
+        Self { repository, clock }
+    }
+
+    /// Clone the `source` environment as `target`
+    ///
+    /// # Arguments
+    ///
+    /// * `source` - Name of the environment to copy
+    /// * `target` - Name of the new environment
+    /// * `port_offset` - Offset added to every tracker port (`0` keeps them)
+    /// * `working_dir` - Root directory containing `data/` and `build/`
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if:
+    /// * The source environment does not exist
+    /// * The target environment already exists
+    /// * The target name is too long for the instance names of the provider
+    /// * A shifted port is out of range
+    /// * The derived instance or profile name is invalid
+    /// * A generated SSH key of the source cannot be copied
+    /// * Another environment already uses the derived instance name
+    /// * The environment cannot be persisted
+    #[instrument(
+        name = "command.clone",
+        skip_all,
+        fields(
+            command_type = "clone",
+            environment_name = %source,
+            target = %target
+        )
+    )]
+    pub fn execute(
+        &self,
+        source: &EnvironmentName,
+        target: &EnvironmentName,
+        port_offset: u16,
+        working_dir: &Path,
+    ) -> Result<Environment<Created>, CloneCommandHandlerError> {
+        let _lock = self.repository.lock(target)?;
+        if self.repository.exists(target)? {
+            return Err(CloneCommandHandlerError::TargetAlreadyExists {
+                name: target.to_string(),
+            });
+        }
+
+        let layout = WorkspaceLayout::in_working_dir(working_dir);
+        let source_env = self.load_environment(source)?;
+        let ssh_credentials =
+            Self::clone_ssh_credentials(source_env.ssh_credentials(), &layout, source, target);
+        let params = Self::clone_params(
+            source_env.user_inputs(),
+            target,
+            ssh_credentials.clone(),
+            port_offset,
+        )?;
+        self.check_instance_name_available(&params.instance_name)?;
+
+        let environment = Environment::create_in_layout(params, &layout, self.clock.now())
+            .map_err(CloneCommandHandlerError::InvalidConfiguration)?;
+
+        Self::copy_generated_ssh_keys(source_env.ssh_credentials(), &ssh_credentials)?;
+
+        if let Err(e) = self.repository.save(&environment.clone().into_any()) {
+            Self::remove_copied_ssh_keys(&layout, target);
+            return Err(e.into());
+        }
+
+        info!(
+            command = "clone",
+            environment = %source,
+            target = %target,
+            port_offset,
+            "Environment cloned successfully"
+        );
+
+        Ok(environment)
+    }
+
+    /// Load the source environment from the repository
+    fn load_environment(
+        &self,
+        name: &EnvironmentName,
+    ) -> Result<AnyEnvironmentState, CloneCommandHandlerError> {
+        self.repository
+            .load(name)?
+            .ok_or_else(|| CloneCommandHandlerError::SourceNotFound {
+                name: name.to_string(),
+            })
+    }
+
+    /// Check that no stored environment uses the instance name of the clone
+    fn check_instance_name_available(
+        &self,
+        instance_name: &InstanceName,
+    ) -> Result<(), CloneCommandHandlerError> {
+        match self.repository.find_by_instance_name(instance_name)? {
+            Some(environment) => Err(CloneCommandHandlerError::InstanceNameInUse {
+                instance_name: instance_name.to_string(),
+                environment: environment.to_string(),
+            }),
+            None => Ok(()),
+        }
+    }
+
+    /// Build the creation parameters of the clone from the source user inputs
+    fn clone_params(
+        source: &UserInputs,
+        target: &EnvironmentName,
+        ssh_credentials: SshCredentials,
+        port_offset: u16,
+    ) -> Result<EnvironmentParams, CloneCommandHandlerError> {
+        let instance_name = Self::clone_instance_name(source, target)?;
+
+        let provider_config = match source.provider_config() {
+            ProviderConfig::Lxd(lxd) => ProviderConfig::Lxd(LxdConfig {
+                profile_name: Self::clone_profile_name(&lxd.profile_name, source.name(), target)?,
+                remote: lxd.remote.clone(),
+            }),
+            ProviderConfig::Hetzner(hetzner) => ProviderConfig::Hetzner(hetzner.clone()),
+            // A reserved IP is assigned to one droplet only: the clone uses its own public IP
+            ProviderConfig::DigitalOcean(digitalocean) => {
+                ProviderConfig::DigitalOcean(DigitalOceanConfig {
+                    reserved_ip: None,
+                    ..digitalocean.clone()
+                })
+            }
+            ProviderConfig::Docker(docker) => ProviderConfig::Docker(docker.clone()),
+            ProviderConfig::Libvirt(libvirt) => ProviderConfig::Libvirt(libvirt.clone()),
+        };
+
+        let tracker_config = source
+            .tracker()
+            .with_port_offset(port_offset)
+            .map_err(CloneCommandHandlerError::InvalidPortOffset)?;
+
+        // Every other setting is copied, including those added to the user inputs later
+        let mut params = source.to_params();
+        params.environment_name = target.clone();
+        params.instance_name = instance_name;
+        params.provider_config = provider_config;
+        params.ssh_credentials = ssh_credentials;
+        params.tracker_config = tracker_config;
+
+        Ok(params)
+    }
+
+    /// Derive the SSH credentials of the clone
+    ///
+    /// Keys generated by the deployer live in `data/{source}/ssh`, which
+    /// `purge` securely deletes: the clone gets its own copy at the same place
+    /// under `data/{target}/ssh`. Keys supplied by the user are shared.
+    fn clone_ssh_credentials(
+        credentials: &SshCredentials,
+        layout: &WorkspaceLayout,
+        source: &EnvironmentName,
+        target: &EnvironmentName,
+    ) -> SshCredentials {
+        let absolute = |dir: PathBuf| std::path::absolute(&dir).unwrap_or(dir);
+        let source_dir = absolute(layout.environment_ssh_dir(source));
+        let target_dir = absolute(layout.environment_ssh_dir(target));
+        let relocate = |path: &Path| match absolute(path.to_path_buf()).strip_prefix(&source_dir) {
+            Ok(relative) => target_dir.join(relative),
+            Err(_) => path.to_path_buf(),
+        };
+
+        let mut cloned = credentials.clone();
+        cloned.ssh_priv_key_path = relocate(&credentials.ssh_priv_key_path);
+        cloned.ssh_pub_key_path = relocate(&credentials.ssh_pub_key_path);
+        cloned
+    }
+
+    /// Copy the generated keys of the source to the paths of the clone
+    ///
+    /// `fs::copy` keeps the permissions, so the private key stays readable
+    /// by its owner only.
+    fn copy_generated_ssh_keys(
+        source: &SshCredentials,
+        clone: &SshCredentials,
+    ) -> Result<(), CloneCommandHandlerError> {
+        let pairs = [
+            (&source.ssh_priv_key_path, &clone.ssh_priv_key_path),
+            (&source.ssh_pub_key_path, &clone.ssh_pub_key_path),
+        ];
+
+        for (from, to) in pairs.into_iter().filter(|(from, to)| from != to) {
+            let copy_failed = |source| CloneCommandHandlerError::SshKeyCopyFailed {
+                path: to.clone(),
+                source,
+            };
+            if let Some(parent) = to.parent() {
+                fs::create_dir_all(parent).map_err(copy_failed)?;
+            }
+            fs::copy(from, to).map_err(copy_failed)?;
+        }
+
+        Ok(())
+    }
+
+    /// Remove the keys copied for a clone that could not be saved
+    fn remove_copied_ssh_keys(layout: &WorkspaceLayout, target: &EnvironmentName) {
+        let ssh_dir = layout.environment_ssh_dir(target);
+
+        if let Err(e) = fs::remove_dir_all(&ssh_dir) {
+            if e.kind() != std::io::ErrorKind::NotFound {
+                warn!(
+                    command = "clone",
+                    environment = %target,
+                    path = %ssh_dir.display(),
+                    error = %e,
+                    "Failed to remove the SSH keys copied for the clone"
+                );
+            }
+        }
+    }
+
+    /// Derive the instance name of the clone
+    ///
+    /// A generated instance name (`torrust-tracker-vm-prod`) is generated
+    /// again for the target, with the same provider length check as `create`.
+    /// A custom instance name ending with the source name (e.g.
+    /// `tracker-prod`) is renamed after the target (`tracker-staging`). Any
+    /// other custom name falls back to the generated one, so the clone never
+    /// shares an instance with its source.
+    fn clone_instance_name(
+        source: &UserInputs,
+        target: &EnvironmentName,
+    ) -> Result<InstanceName, CloneCommandHandlerError> {
+        let custom_prefix = source
+            .instance_name()
+            .as_str()
+            .strip_suffix(source.name().as_str())
+            .filter(|prefix| *prefix != InstanceName::GENERATED_PREFIX);
+
+        match custom_prefix {
+            Some(prefix) => InstanceName::new(format!("{prefix}{}", target.as_str()))
+                .map_err(CloneCommandHandlerError::InvalidInstanceName),
+            None => generate_checked_instance_name(source.provider_config().provider(), target)
+                .map_err(|e| CloneCommandHandlerError::InvalidTargetName(Box::new(e))),
+        }
+    }
+
+    /// Derive the LXD profile name of the clone
+    ///
+    /// A profile named after the source environment (e.g. `lxd-prod`) is
+    /// renamed after the target (`lxd-staging`). Any other profile name falls
+    /// back to `torrust-profile-{target}`, so the clone never shares a profile
+    /// with its source.
+    fn clone_profile_name(
+        profile_name: &ProfileName,
+        source: &EnvironmentName,
+        target: &EnvironmentName,
+    ) -> Result<ProfileName, CloneCommandHandlerError> {
+        let name = match profile_name.as_str().strip_suffix(source.as_str()) {
+            Some(prefix) => format!("{prefix}{}", target.as_str()),
+            None => format!("{PROFILE_PREFIX}{}", target.as_str()),
+        };
+
+        ProfileName::new(name).map_err(CloneCommandHandlerError::InvalidProfileName)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::net::SocketAddr;
+
+    use chrono::Utc;
+    use tempfile::TempDir;
+
+    use super::*;
+    use crate::application::command_handlers::purge::handler::PurgeCommandHandler;
+    use crate::domain::environment::testing::EnvironmentTestBuilder;
+    use crate::domain::environment::{Label, TracesConfig};
+    use crate::domain::EnvironmentNameError;
+    use crate::infrastructure::persistence::file_repository_factory::FileRepositoryFactory;
+    use crate::testing::MockClock;
+
+    /// Handler backed by a repository that already holds the `prod` environment
+    fn handler_with_source(temp_dir: &TempDir) -> CloneCommandHandler {
+        let repository = FileRepositoryFactory::new(std::time::Duration::from_secs(30))
+            .create(temp_dir.path().join("data"));
+
+        let (source, _data_dir, _build_dir, _source_temp_dir) = EnvironmentTestBuilder::new()
+            .with_name("prod")
+            .build_with_custom_paths();
+        repository.save(&source.into_any()).unwrap();
+
+        CloneCommandHandler::new(repository, Arc::new(MockClock::new(Utc::now())))
+    }
+
+    /// Handler whose `prod` environment uses keys generated in `data/prod/ssh`
+    fn handler_with_generated_keys(temp_dir: &TempDir) -> CloneCommandHandler {
+        let handler = handler_with_source(temp_dir);
+        let layout = WorkspaceLayout::in_working_dir(temp_dir.path());
+        let ssh_dir = layout.environment_ssh_dir(&env_name("prod"));
+        fs::create_dir_all(&ssh_dir).unwrap();
+        fs::write(ssh_dir.join("id_ed25519"), "private key").unwrap();
+        fs::write(ssh_dir.join("id_ed25519.pub"), "public key").unwrap();
+
+        let mut source = handler.repository.load(&env_name("prod")).unwrap().unwrap();
+        source.relocate(
+            &layout,
+            ssh_dir.join("id_ed25519"),
+            ssh_dir.join("id_ed25519.pub"),
+        );
+        handler.repository.save(&source).unwrap();
+
+        handler
+    }
+
+    fn env_name(name: &str) -> EnvironmentName {
+        EnvironmentName::new(name.to_string()).unwrap()
+    }
+
+    #[test]
+    fn it_should_clone_the_environment_with_shifted_tracker_ports() {
+        let temp_dir = TempDir::new().unwrap();
+        let handler = handler_with_source(&temp_dir);
+
+        let clone = handler
+            .execute(
+                &env_name("prod"),
+                &env_name("staging"),
+                1000,
+                temp_dir.path(),
+            )
+            .unwrap();
+
+        let udp_address: SocketAddr = "0.0.0.0:7969".parse().unwrap();
+        assert_eq!(
+            clone.tracker_config().udp_trackers()[0].bind_address(),
+            udp_address
+        );
+        assert_eq!(clone.instance_name().as_str(), "torrust-tracker-vm-staging");
+        assert_eq!(
+            clone.clone().into_any().profile_name().as_str(),
+            "lxd-staging"
+        );
+        assert_eq!(clone.instance_ip(), None);
+        assert!(handler.repository.exists(&env_name("staging")).unwrap());
+    }
+
+    #[test]
+    fn it_should_copy_every_setting_not_derived_from_the_environment_name() {
+        let temp_dir = TempDir::new().unwrap();
+        let repository = FileRepositoryFactory::new(std::time::Duration::from_secs(30))
+            .create(temp_dir.path().join("data"));
+        let (source, _data_dir, _build_dir, _source_temp_dir) = EnvironmentTestBuilder::new()
+            .with_name("prod")
+            .build_with_custom_paths();
+        let params = source
+            .context()
+            .user_inputs
+            .to_params()
+            .with_traces(
+                TracesConfig::new(3, Some(7))
+                    .unwrap()
+                    .with_dir("/tmp/ci-traces"),
+            )
+            .with_labels(
+                [Label::new("tier", "production").unwrap()]
+                    .into_iter()
+                    .collect(),
+            );
+        let source = Environment::create_in_layout(
+            params,
+            &WorkspaceLayout::in_working_dir(temp_dir.path()),
+            Utc::now(),
+        )
+        .unwrap();
+        repository.save(&source.clone().into_any()).unwrap();
+        let handler = CloneCommandHandler::new(repository, Arc::new(MockClock::new(Utc::now())));
+
+        let clone = handler
+            .execute(&env_name("prod"), &env_name("staging"), 0, temp_dir.path())
+            .unwrap();
+
+        let diff = source
+            .context()
+            .user_inputs
+            .diff(&clone.context().user_inputs);
+        let changed: Vec<&str> = diff
+            .changes()
+            .iter()
+            .map(|change| change.path.as_str())
+            .collect();
+        assert_eq!(
+            changed,
+            vec!["provider.profile_name", "environment.instance_name"]
+        );
+        assert_eq!(
+            clone.context().user_inputs.traces(),
+            source.context().user_inputs.traces()
+        );
+        assert_eq!(
+            clone.context().user_inputs.labels().get("tier"),
+            Some("production")
+        );
+    }
+
+    #[test]
+    fn it_should_give_the_clone_its_own_copy_of_the_generated_ssh_keys() {
+        let temp_dir = TempDir::new().unwrap();
+        let handler = handler_with_generated_keys(&temp_dir);
+
+        let clone = handler
+            .execute(&env_name("prod"), &env_name("staging"), 0, temp_dir.path())
+            .unwrap();
+        PurgeCommandHandler::new(handler.repository.clone(), temp_dir.path().to_path_buf())
+            .execute(&env_name("prod"))
+            .unwrap();
+
+        let credentials = clone.ssh_credentials();
+        let clone_ssh_dir = WorkspaceLayout::in_working_dir(temp_dir.path())
+            .environment_ssh_dir(&env_name("staging"));
+        assert!(credentials.ssh_priv_key_path.starts_with(&clone_ssh_dir));
+        assert!(credentials.ssh_pub_key_path.starts_with(&clone_ssh_dir));
+        assert_eq!(
+            fs::read_to_string(&credentials.ssh_priv_key_path).unwrap(),
+            "private key"
+        );
+        assert_eq!(
+            fs::read_to_string(&credentials.ssh_pub_key_path).unwrap(),
+            "public key"
+        );
+    }
+
+    #[test]
+    fn it_should_share_the_ssh_keys_supplied_by_the_user() {
+        let temp_dir = TempDir::new().unwrap();
+        let handler = handler_with_source(&temp_dir);
+        let source = handler.repository.load(&env_name("prod")).unwrap().unwrap();
+
+        let clone = handler
+            .execute(&env_name("prod"), &env_name("staging"), 0, temp_dir.path())
+            .unwrap();
+
+        assert_eq!(
+            clone.ssh_credentials().ssh_priv_key_path,
+            source.ssh_credentials().ssh_priv_key_path
+        );
+        assert_eq!(
+            clone.ssh_credentials().ssh_pub_key_path,
+            source.ssh_credentials().ssh_pub_key_path
+        );
+        assert!(!WorkspaceLayout::in_working_dir(temp_dir.path())
+            .environment_ssh_dir(&env_name("staging"))
+            .exists());
+    }
+
+    #[test]
+    fn it_should_refuse_a_target_name_that_already_exists() {
+        let temp_dir = TempDir::new().unwrap();
+        let handler = handler_with_source(&temp_dir);
+
+        let result = handler.execute(&env_name("prod"), &env_name("prod"), 0, temp_dir.path());
+
+        assert!(matches!(
+            result,
+            Err(CloneCommandHandlerError::TargetAlreadyExists { .. })
+        ));
+    }
+
+    #[test]
+    fn it_should_refuse_a_target_name_too_long_for_the_provider_instance_names() {
+        let temp_dir = TempDir::new().unwrap();
+        let handler = handler_with_source(&temp_dir);
+        let target = env_name(&format!("env-{}", "a".repeat(41)));
+
+        let result = handler.execute(&env_name("prod"), &target, 0, temp_dir.path());
+
+        match result {
+            Err(CloneCommandHandlerError::InvalidTargetName(error)) => assert!(matches!(
+                *error,
+                EnvironmentNameError::TooLong {
+                    length: 45,
+                    max_length: 44,
+                    ..
+                }
+            )),
+            other => panic!("Expected TooLong target name error, got: {other:?}"),
+        }
+        assert!(!handler.repository.exists(&target).unwrap());
+    }
+
+    #[test]
+    fn it_should_accept_a_target_name_filling_the_provider_instance_names() {
+        let temp_dir = TempDir::new().unwrap();
+        let handler = handler_with_source(&temp_dir);
+        let target = env_name(&format!("env-{}", "a".repeat(40)));
+
+        let clone = handler
+            .execute(&env_name("prod"), &target, 0, temp_dir.path())
+            .unwrap();
+
+        assert_eq!(
+            clone.instance_name().as_str().len(),
+            InstanceName::MAX_LENGTH
+        );
+    }
+
+    /// Give the `prod` environment of `handler` a custom instance name
+    fn use_custom_instance_name(handler: &CloneCommandHandler, instance_name: &str) {
+        let mut source = handler.repository.load(&env_name("prod")).unwrap().unwrap();
+        source.rename_infrastructure(InstanceName::new(instance_name.to_string()).unwrap(), None);
+        handler.repository.save(&source).unwrap();
+    }
+
+    #[test]
+    fn it_should_rename_a_custom_instance_name_ending_with_the_source_name() {
+        let temp_dir = TempDir::new().unwrap();
+        let handler = handler_with_source(&temp_dir);
+        use_custom_instance_name(&handler, "tracker-prod");
+
+        let clone = handler
+            .execute(&env_name("prod"), &env_name("staging"), 0, temp_dir.path())
+            .unwrap();
+
+        assert_eq!(clone.instance_name().as_str(), "tracker-staging");
+    }
+
+    #[test]
+    fn it_should_generate_the_instance_name_when_the_custom_one_is_not_derived_from_the_source_name(
+    ) {
+        let temp_dir = TempDir::new().unwrap();
+        let handler = handler_with_source(&temp_dir);
+        use_custom_instance_name(&handler, "tracker-vm");
+
+        let clone = handler
+            .execute(&env_name("prod"), &env_name("staging"), 0, temp_dir.path())
+            .unwrap();
+
+        assert_eq!(clone.instance_name().as_str(), "torrust-tracker-vm-staging");
+    }
+
+    #[test]
+    fn it_should_refuse_an_instance_name_used_by_another_environment() {
+        let temp_dir = TempDir::new().unwrap();
+        let handler = handler_with_source(&temp_dir);
+        let (other, _data_dir, _build_dir, _other_temp_dir) = EnvironmentTestBuilder::new()
+            .with_name("legacy")
+            .build_with_custom_paths();
+        let mut other = other.into_any();
+        other.rename_infrastructure(
+            InstanceName::new("torrust-tracker-vm-staging".to_string()).unwrap(),
+            None,
+        );
+        handler.repository.save(&other).unwrap();
+
+        let result = handler.execute(&env_name("prod"), &env_name("staging"), 0, temp_dir.path());
+
+        match result {
+            Err(CloneCommandHandlerError::InstanceNameInUse {
+                instance_name,
+                environment,
+            }) => {
+                assert_eq!(instance_name, "torrust-tracker-vm-staging");
+                assert_eq!(environment, "legacy");
+            }
+            other => panic!("Expected InstanceNameInUse error, got: {other:?}"),
+        }
+        assert!(!handler.repository.exists(&env_name("staging")).unwrap());
+    }
+
+    #[test]
+    fn it_should_fail_when_the_source_environment_does_not_exist() {
+        let temp_dir = TempDir::new().unwrap();
+        let handler = handler_with_source(&temp_dir);
+
+        let result = handler.execute(
+            &env_name("missing"),
+            &env_name("staging"),
+            0,
+            temp_dir.path(),
+        );
+
+        assert!(matches!(
+            result,
+            Err(CloneCommandHandlerError::SourceNotFound { .. })
+        ));
+    }
+}
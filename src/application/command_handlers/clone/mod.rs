@@ -0,0 +1,26 @@
+//! Clone Command Module
+//!
+//! This module implements the delivery-agnostic `CloneCommandHandler`
+//! for creating a new environment from the configuration of an existing one.
+//!
+//! ## What Is Copied
+//!
+//! - Provider, SSH, tracker, Prometheus, Grafana, HTTPS and backup configuration
+//! - Secrets (API tokens, passwords) held by the source configuration
+//!
+//! ## What Is Regenerated
+//!
+//! - Instance name (`torrust-tracker-vm-{target}`)
+//! - LXD profile name (renamed after the target environment)
+//! - `data/{target}` and `build/{target}` directories
+//! - Tracker ports, when a port offset is given
+//!
+//! Runtime outputs of the source (instance IP, provision method, service
+//! endpoints) are never copied: the clone starts in the `Created` state.
+
+pub mod errors;
+pub mod handler;
+
+// Re-export main types for convenience
+pub use errors::CloneCommandHandlerError;
+pub use handler::CloneCommandHandler;
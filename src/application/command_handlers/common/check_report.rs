@@ -0,0 +1,72 @@
+//! Result of an Ansible check (dry-run) mode run
+//!
+//! The configure and release handlers can run their playbooks with
+//! `ansible-playbook --check`. Such a run never transitions the environment
+//! state; it returns a [`CheckReport`] instead. Tasks that would change the
+//! host are reported here, while real playbook failures are still returned as
+//! the handler's error type.
+
+use crate::adapters::ansible::PlaybookRecap;
+
+/// Playbook recaps collected during a check mode run
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct CheckReport {
+    recaps: Vec<PlaybookRecap>,
+}
+
+impl CheckReport {
+    /// Create a report from the recaps of the playbooks that ran
+    #[must_use]
+    pub fn new(recaps: Vec<PlaybookRecap>) -> Self {
+        Self { recaps }
+    }
+
+    /// Recaps of the playbooks that ran, in run order
+    #[must_use]
+    pub fn recaps(&self) -> &[PlaybookRecap] {
+        &self.recaps
+    }
+
+    /// Total number of tasks that would change the host
+    #[must_use]
+    pub fn changed_tasks(&self) -> u32 {
+        self.recaps.iter().map(|recap| recap.changed).sum()
+    }
+
+    /// Whether applying the playbooks would change the host
+    #[must_use]
+    pub fn has_changes(&self) -> bool {
+        self.recaps.iter().any(PlaybookRecap::has_changes)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn recap(playbook: &str, changed: u32) -> PlaybookRecap {
+        PlaybookRecap {
+            playbook: playbook.to_string(),
+            changed,
+            ..PlaybookRecap::default()
+        }
+    }
+
+    #[test]
+    fn it_should_sum_changed_tasks_over_all_playbooks() {
+        let report = CheckReport::new(vec![
+            recap("install-docker", 2),
+            recap("configure-firewall", 3),
+        ]);
+
+        assert_eq!(report.changed_tasks(), 5);
+        assert!(report.has_changes());
+    }
+
+    #[test]
+    fn it_should_report_no_changes_when_every_playbook_is_up_to_date() {
+        let report = CheckReport::new(vec![recap("install-docker", 0)]);
+
+        assert!(!report.has_changes());
+    }
+}
@@ -0,0 +1,234 @@
+//! Configuration override shared by the `configure` and `release` commands
+//!
+//! An override file (`--override <file>`) changes some settings of an
+//! existing environment. It is validated and merged onto the persisted user
+//! inputs before the command renders anything, and the merged inputs are
+//! persisted with the next state of the environment.
+//!
+//! A command only accepts the changes it deploys: `configure` accepts changes
+//! that require configure or less, `release` changes that require a release
+//! or less. Changes that require a new instance are always rejected.
+
+use std::path::Path;
+
+use tracing::info;
+
+use crate::application::command_handlers::create::config::{
+    ConfigLoadError, CreateConfigError, EnvironmentOverrideConfig,
+};
+use crate::domain::environment::{ChangeImpact, Environment};
+use crate::shared::error::kind::ErrorKind;
+use crate::shared::error::traceable::Traceable;
+
+/// Errors of a configuration override
+#[derive(Debug, thiserror::Error)]
+pub enum ConfigOverrideError {
+    #[error("Failed to load configuration override: {0}")]
+    Load(#[from] ConfigLoadError),
+
+    #[error("Invalid configuration override: {0}")]
+    Invalid(#[source] CreateConfigError),
+
+    #[error(
+        "The '{command}' command cannot apply these overridden settings ({impact}): {}",
+        settings.join(", ")
+    )]
+    NotApplicable {
+        command: String,
+        impact: ChangeImpact,
+        settings: Vec<String>,
+    },
+}
+
+impl Traceable for ConfigOverrideError {
+    fn trace_format(&self) -> String {
+        format!("ConfigOverrideError: {self}")
+    }
+
+    fn trace_source(&self) -> Option<&dyn Traceable> {
+        None
+    }
+
+    fn error_kind(&self) -> ErrorKind {
+        match self {
+            Self::Load(ConfigLoadError::FileReadFailed { .. }) => ErrorKind::FileSystem,
+            Self::Load(_) | Self::Invalid(_) | Self::NotApplicable { .. } => {
+                ErrorKind::Configuration
+            }
+        }
+    }
+}
+
+impl ConfigOverrideError {
+    /// Provides detailed troubleshooting guidance for this error
+    #[must_use]
+    pub fn help(&self) -> &'static str {
+        match self {
+            Self::Load(_) => {
+                "Failed to Load Configuration Override - Troubleshooting:
+
+1. Check that the override file exists and is readable
+2. Check that the file is valid JSON, with the sections of an environment
+   configuration file (all optional) and no unknown field
+
+For more information, see docs/user-guide/commands/release.md"
+            }
+            Self::Invalid(e) => e.help(),
+            Self::NotApplicable { .. } => {
+                "Override Not Applicable - Troubleshooting:
+
+The override changes settings that the command does not deploy:
+- release deploys tracker and service settings
+- configure also deploys the firewall, Docker and system settings
+- provider, instance, cloud-init and SSH settings need a new instance
+
+1. Preview the changes and what they require:
+   torrust-tracker-deployer diff <name> <full-config-file>
+2. Pass the override to the command that deploys them, or remove the
+   settings from the override file
+3. For settings that need a new instance, destroy and recreate the
+   environment
+
+For more information, see docs/user-guide/commands/release.md"
+            }
+        }
+    }
+}
+
+/// Merge the override file at `path` onto the user inputs of `environment`
+///
+/// The environment is returned unchanged when the override does not change
+/// any setting; otherwise its configuration revision is incremented. Nothing
+/// is persisted.
+///
+/// # Errors
+///
+/// Returns `ConfigOverrideError` if the file cannot be loaded, the merged
+/// configuration is invalid, or the override changes a setting whose impact
+/// is more disruptive than `allowed`.
+pub fn apply_config_override<S>(
+    environment: Environment<S>,
+    path: &Path,
+    command: &str,
+    allowed: ChangeImpact,
+) -> Result<Environment<S>, ConfigOverrideError> {
+    let current = &environment.context().user_inputs;
+    let overridden = EnvironmentOverrideConfig::from_file(path)?
+        .apply_to(current)
+        .map_err(ConfigOverrideError::Invalid)?;
+
+    let diff = current.diff(&overridden);
+
+    if let Some(impact) = diff.impact().filter(|impact| *impact > allowed) {
+        return Err(ConfigOverrideError::NotApplicable {
+            command: command.to_string(),
+            impact,
+            settings: diff
+                .changes_with(impact)
+                .map(|change| change.path.clone())
+                .collect(),
+        });
+    }
+
+    if diff.is_empty() {
+        info!(
+            command = command,
+            environment = %environment.name(),
+            "Configuration override does not change any setting"
+        );
+        return Ok(environment);
+    }
+
+    let environment = environment.with_overridden_user_inputs(overridden);
+
+    info!(
+        command = command,
+        environment = %environment.name(),
+        changes = diff.changes().len(),
+        config_revision = environment.config_revision(),
+        "Configuration override applied"
+    );
+
+    Ok(environment)
+}
+
+#[cfg(test)]
+mod tests {
+    use std::io::Write;
+
+    use super::*;
+    use crate::domain::environment::testing::EnvironmentTestBuilder;
+    use crate::domain::environment::INITIAL_CONFIG_REVISION;
+
+    fn override_file(content: &str) -> tempfile::NamedTempFile {
+        let mut file = tempfile::Builder::new().suffix(".json").tempfile().unwrap();
+        file.write_all(content.as_bytes()).unwrap();
+        file
+    }
+
+    #[test]
+    fn it_should_apply_a_new_admin_token_and_increment_the_config_revision() {
+        let (environment, _, _, _temp_dir) =
+            EnvironmentTestBuilder::new().build_with_custom_paths();
+        let file = override_file(r#"{ "tracker": { "http_api": { "admin_token": "NewToken" } } }"#);
+
+        let environment = apply_config_override(
+            environment,
+            file.path(),
+            "release",
+            ChangeImpact::RequiresRelease,
+        )
+        .unwrap();
+
+        let user_inputs = &environment.context().user_inputs;
+        assert_eq!(
+            user_inputs
+                .tracker()
+                .http_api()
+                .admin_token()
+                .expose_secret(),
+            "NewToken"
+        );
+        assert_eq!(environment.config_revision(), INITIAL_CONFIG_REVISION + 1);
+    }
+
+    #[test]
+    fn it_should_keep_the_config_revision_when_nothing_changes() {
+        let (environment, _, _, _temp_dir) =
+            EnvironmentTestBuilder::new().build_with_custom_paths();
+        let file = override_file("{}");
+
+        let environment = apply_config_override(
+            environment,
+            file.path(),
+            "release",
+            ChangeImpact::RequiresRelease,
+        )
+        .unwrap();
+
+        assert_eq!(environment.config_revision(), INITIAL_CONFIG_REVISION);
+    }
+
+    #[test]
+    fn it_should_reject_changes_that_require_a_new_instance() {
+        let (environment, _, _, _temp_dir) =
+            EnvironmentTestBuilder::new().build_with_custom_paths();
+        let file = override_file(r#"{ "instance": { "cpus": 16 } }"#);
+
+        let error = apply_config_override(
+            environment,
+            file.path(),
+            "configure",
+            ChangeImpact::RequiresConfigure,
+        )
+        .unwrap_err();
+
+        assert!(matches!(
+            error,
+            ConfigOverrideError::NotApplicable {
+                impact: ChangeImpact::RequiresProvision,
+                ..
+            }
+        ));
+    }
+}
@@ -0,0 +1,70 @@
+//! Result of a dry run of a destructive command
+//!
+//! The destroy, purge and provision handlers can simulate their workflow.
+//! A simulation never transitions the environment state nor deletes files; it
+//! returns a [`DryRunReport`] listing what the real run would change.
+
+use std::path::PathBuf;
+
+use crate::adapters::tofu::PlannedChange;
+
+/// What a destructive command would change, collected by a dry run
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct DryRunReport {
+    infrastructure_changes: Vec<PlannedChange>,
+    removed_paths: Vec<PathBuf>,
+    notes: Vec<String>,
+}
+
+impl DryRunReport {
+    /// Create an empty report
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Set the resource changes planned by `OpenTofu`
+    #[must_use]
+    pub fn with_infrastructure_changes(mut self, changes: Vec<PlannedChange>) -> Self {
+        self.infrastructure_changes = changes;
+        self
+    }
+
+    /// Add a local file or directory the command would delete
+    #[must_use]
+    pub fn with_removed_path(mut self, path: PathBuf) -> Self {
+        self.removed_paths.push(path);
+        self
+    }
+
+    /// Add a remark about the simulated run (e.g. a skipped step)
+    #[must_use]
+    pub fn with_note(mut self, note: impl Into<String>) -> Self {
+        self.notes.push(note.into());
+        self
+    }
+
+    /// Resource changes planned by `OpenTofu`
+    #[must_use]
+    pub fn infrastructure_changes(&self) -> &[PlannedChange] {
+        &self.infrastructure_changes
+    }
+
+    /// Local files and directories the command would delete
+    #[must_use]
+    pub fn removed_paths(&self) -> &[PathBuf] {
+        &self.removed_paths
+    }
+
+    /// Remarks about the simulated run
+    #[must_use]
+    pub fn notes(&self) -> &[String] {
+        &self.notes
+    }
+
+    /// Whether the real run would change anything
+    #[must_use]
+    pub fn has_changes(&self) -> bool {
+        !self.infrastructure_changes.is_empty() || !self.removed_paths.is_empty()
+    }
+}
@@ -17,7 +17,7 @@
 use std::net::{IpAddr, SocketAddr};
 
 use crate::domain::tracker::config::{HttpApiConfig, HttpTrackerConfig, TrackerConfig};
-use crate::shared::ServiceEndpoint;
+use crate::shared::{DomainName, ServiceEndpoint};
 
 /// Build a `ServiceEndpoint` for the HTTP API from configuration and instance IP
 ///
@@ -40,17 +40,31 @@ use crate::shared::ServiceEndpoint;
 /// with valid configuration types from the domain layer).
 #[must_use]
 pub fn build_api_endpoint(instance_ip: IpAddr, config: &HttpApiConfig) -> ServiceEndpoint {
-    let port = config.bind_address().port();
-    let path = "/api/health_check";
-    let socket_addr = SocketAddr::new(instance_ip, port);
+    build_endpoint(
+        instance_ip,
+        config.bind_address().port(),
+        config.tls_domain(),
+        "/api/health_check",
+    )
+}
 
-    if let Some(domain) = config.tls_domain() {
-        ServiceEndpoint::https(domain, path, instance_ip)
-            .expect("Valid TLS domain should produce valid HTTPS URL")
-    } else {
-        ServiceEndpoint::http(socket_addr, path)
-            .expect("Valid socket address should produce valid HTTP URL")
-    }
+/// Build a `ServiceEndpoint` for the root of the version 1 HTTP API
+///
+/// Same as [`build_api_endpoint`], but targeting `/api/v1/`, so clients can
+/// join the paths of the resources they manage (e.g. `keys`).
+///
+/// # Panics
+///
+/// Panics if the configuration produces an invalid URL (this should never happen
+/// with valid configuration types from the domain layer).
+#[must_use]
+pub fn build_api_v1_endpoint(instance_ip: IpAddr, config: &HttpApiConfig) -> ServiceEndpoint {
+    build_endpoint(
+        instance_ip,
+        config.bind_address().port(),
+        config.tls_domain(),
+        "/api/v1/",
+    )
 }
 
 /// Build a `ServiceEndpoint` for an HTTP Tracker from configuration and instance IP
@@ -77,17 +91,34 @@ pub fn build_http_tracker_endpoint(
     instance_ip: IpAddr,
     config: &HttpTrackerConfig,
 ) -> ServiceEndpoint {
-    let port = config.bind_address().port();
-    let path = "/health_check";
-    let socket_addr = SocketAddr::new(instance_ip, port);
+    build_endpoint(
+        instance_ip,
+        config.bind_address().port(),
+        config.tls_domain(),
+        "/health_check",
+    )
+}
 
-    if let Some(domain) = config.tls_domain() {
-        ServiceEndpoint::https(domain, path, instance_ip)
-            .expect("Valid TLS domain should produce valid HTTPS URL")
-    } else {
-        ServiceEndpoint::http(socket_addr, path)
-            .expect("Valid socket address should produce valid HTTP URL")
-    }
+/// Build a `ServiceEndpoint` for the announce path of an HTTP Tracker
+///
+/// Same as [`build_http_tracker_endpoint`], but targeting `/announce`. The
+/// announce parameters are added to the request by the caller.
+///
+/// # Panics
+///
+/// Panics if the configuration produces an invalid URL (this should never happen
+/// with valid configuration types from the domain layer).
+#[must_use]
+pub fn build_http_announce_endpoint(
+    instance_ip: IpAddr,
+    config: &HttpTrackerConfig,
+) -> ServiceEndpoint {
+    build_endpoint(
+        instance_ip,
+        config.bind_address().port(),
+        config.tls_domain(),
+        "/announce",
+    )
 }
 
 /// Build all tracker service endpoints from configuration and instance IP
@@ -127,6 +158,22 @@ pub fn build_all_tracker_endpoints(
     (api_endpoint, http_tracker_endpoints)
 }
 
+/// Build the endpoint of `path` on a service, over HTTPS when it has a TLS domain
+fn build_endpoint(
+    instance_ip: IpAddr,
+    port: u16,
+    tls_domain: Option<&DomainName>,
+    path: &str,
+) -> ServiceEndpoint {
+    if let Some(domain) = tls_domain {
+        ServiceEndpoint::https(domain, path, instance_ip)
+            .expect("Valid TLS domain should produce valid HTTPS URL")
+    } else {
+        ServiceEndpoint::http(SocketAddr::new(instance_ip, port), path)
+            .expect("Valid socket address should produce valid HTTP URL")
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use std::net::{IpAddr, Ipv4Addr};
@@ -280,6 +327,18 @@ mod tests {
         assert_eq!(endpoint.server_ip(), different_ip);
     }
 
+    #[test]
+    fn it_should_build_api_v1_endpoint_on_the_tls_domain() {
+        let config = http_api_config_with_tls();
+
+        let endpoint = build_api_v1_endpoint(test_ip(), &config);
+
+        assert_eq!(
+            endpoint.url().join("keys").unwrap().as_str(),
+            "https://api.tracker.local/api/v1/keys"
+        );
+    }
+
     // Tests for build_http_tracker_endpoint
 
     #[test]
@@ -339,6 +398,16 @@ mod tests {
         assert_eq!(endpoint.server_ip(), different_ip);
     }
 
+    #[test]
+    fn it_should_build_https_announce_endpoint_when_tls_is_enabled() {
+        let config = http_tracker_config_with_tls();
+
+        let endpoint = build_http_announce_endpoint(test_ip(), &config);
+
+        assert!(endpoint.uses_tls());
+        assert_eq!(endpoint.url().path(), "/announce");
+    }
+
     // Tests for build_all_tracker_endpoints
 
     #[test]
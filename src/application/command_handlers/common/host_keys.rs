@@ -0,0 +1,35 @@
+//! SSH host key check shared by the commands that act on an existing instance
+
+use std::net::{IpAddr, SocketAddr};
+
+use crate::adapters::ssh::{KnownHostsFile, SshConfig, SshError};
+use crate::application::steps::RecordHostKeysStep;
+use crate::domain::environment::Environment;
+
+/// Check the host keys of the instance before running anything on it
+///
+/// Records the keys on first use for environments provisioned before their
+/// `known_hosts` file existed.
+///
+/// # Errors
+///
+/// Returns `SshError::HostKeyChanged` if the instance presents other keys
+/// than the recorded ones, or an error if the keys cannot be scanned or
+/// recorded.
+pub fn verify_host_keys<S>(
+    environment: &Environment<S>,
+    instance_ip: IpAddr,
+) -> Result<(), SshError> {
+    let ssh_config = SshConfig::new(
+        environment.ssh_credentials().clone(),
+        SocketAddr::new(instance_ip, environment.ssh_port()),
+    );
+
+    RecordHostKeysStep::new(
+        ssh_config,
+        KnownHostsFile::new(environment.ssh_known_hosts_path()),
+    )
+    .execute()?;
+
+    Ok(())
+}
@@ -3,8 +3,25 @@
 //! This module provides shared functionality used across multiple command handlers
 //! to reduce code duplication and improve maintainability.
 
+pub mod check_report;
+pub mod config_override;
+pub mod dry_run_report;
 pub mod endpoint_builder;
 pub mod failure_context;
+pub mod host_keys;
+pub mod multi_environment;
+pub mod run_logs;
+pub mod traces;
+
+pub use check_report::CheckReport;
+pub use config_override::{apply_config_override, ConfigOverrideError};
+pub use dry_run_report::DryRunReport;
+pub use host_keys::verify_host_keys;
+pub use multi_environment::{
+    EnvironmentOutcome, MultiEnvironmentListener, MultiEnvironmentReport, MultiEnvironmentRunner,
+};
+pub use run_logs::prune_run_logs;
+pub use traces::{prune_traces, traces_dir};
 
 /// Result type for step execution in command handlers
 ///
@@ -0,0 +1,378 @@
+//! Concurrent execution of a command on several environments
+//!
+//! `provision`, `configure` and `destroy` can select several environments at
+//! once (e.g. with `--label`). [`MultiEnvironmentRunner`] runs the command on
+//! each of them with a bounded pool of worker threads:
+//!
+//! - At most `parallelism` environments are processed at the same time
+//! - A failing environment does not stop the others: the outcome of every
+//!   environment is collected in a [`MultiEnvironmentReport`]
+//! - An environment is processed once even if it is selected twice, so two
+//!   workers never race on the same environment. Each worker's command holds
+//!   the repository lock of its environment until it finishes, so an
+//!   environment another deployer process is working on fails with a
+//!   conflict instead of being changed under its feet
+//!
+//! Environments do not share resources: each one has its own build
+//! directory, `OpenTofu` state and provider resource names.
+
+use std::collections::HashSet;
+use std::fmt::Display;
+use std::future::Future;
+use std::num::NonZeroUsize;
+use std::panic::{catch_unwind, AssertUnwindSafe};
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::Mutex;
+use std::time::{Duration, Instant};
+
+use crate::domain::environment::name::EnvironmentName;
+
+/// Receives the progress of a multi-environment run
+///
+/// Called from the worker threads, as soon as an environment starts or
+/// finishes, so the calls of different environments interleave.
+pub trait MultiEnvironmentListener: Send + Sync {
+    /// Called when the command starts on an environment
+    fn on_environment_started(&self, name: &EnvironmentName);
+
+    /// Called when the command finished on an environment, successfully or not
+    fn on_environment_finished(&self, outcome: &EnvironmentOutcome);
+}
+
+/// Outcome of the command on one environment
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct EnvironmentOutcome {
+    /// Name of the environment
+    pub name: EnvironmentName,
+    /// Why the command failed, `None` when it succeeded
+    pub error: Option<String>,
+    /// How long the command ran on the environment
+    pub duration: Duration,
+}
+
+impl EnvironmentOutcome {
+    /// Whether the command succeeded on the environment
+    #[must_use]
+    pub fn succeeded(&self) -> bool {
+        self.error.is_none()
+    }
+}
+
+/// Outcomes of a multi-environment run, in selection order
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct MultiEnvironmentReport {
+    /// One outcome per environment
+    pub outcomes: Vec<EnvironmentOutcome>,
+}
+
+impl MultiEnvironmentReport {
+    /// Number of environments the command succeeded on
+    #[must_use]
+    pub fn succeeded_count(&self) -> usize {
+        self.outcomes.iter().filter(|o| o.succeeded()).count()
+    }
+
+    /// Number of environments the command failed on
+    #[must_use]
+    pub fn failed_count(&self) -> usize {
+        self.outcomes.len() - self.succeeded_count()
+    }
+}
+
+/// Runs a command on several environments with bounded parallelism
+///
+/// # Examples
+///
+/// ```rust
+/// use std::num::NonZeroUsize;
+/// use torrust_tracker_deployer_lib::application::command_handlers::common::MultiEnvironmentRunner;
+/// use torrust_tracker_deployer_lib::domain::EnvironmentName;
+///
+/// let names: Vec<EnvironmentName> = ["e2e-1", "e2e-2", "e2e-3"]
+///     .iter()
+///     .map(|name| EnvironmentName::new((*name).to_string()).unwrap())
+///     .collect();
+///
+/// let runner = MultiEnvironmentRunner::new(NonZeroUsize::new(2).unwrap());
+/// let report = runner.run(&names, None, |name| {
+///     if name.as_str() == "e2e-2" {
+///         Err("instance did not boot")
+///     } else {
+///         Ok(())
+///     }
+/// });
+///
+/// assert_eq!(report.succeeded_count(), 2);
+/// assert_eq!(report.outcomes[1].error.as_deref(), Some("instance did not boot"));
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct MultiEnvironmentRunner {
+    parallelism: NonZeroUsize,
+}
+
+impl Default for MultiEnvironmentRunner {
+    /// One environment at a time
+    fn default() -> Self {
+        Self::new(NonZeroUsize::MIN)
+    }
+}
+
+impl MultiEnvironmentRunner {
+    /// Create a runner processing at most `parallelism` environments at once
+    #[must_use]
+    pub fn new(parallelism: NonZeroUsize) -> Self {
+        Self { parallelism }
+    }
+
+    /// Maximum number of environments processed at the same time
+    #[must_use]
+    pub fn parallelism(&self) -> NonZeroUsize {
+        self.parallelism
+    }
+
+    /// Run `operation` on every environment of `names`
+    ///
+    /// Returns once every environment was processed. An operation that
+    /// panics is reported as failed on its environment.
+    pub fn run<F, T, E>(
+        &self,
+        names: &[EnvironmentName],
+        listener: Option<&dyn MultiEnvironmentListener>,
+        operation: F,
+    ) -> MultiEnvironmentReport
+    where
+        F: Fn(&EnvironmentName) -> Result<T, E> + Sync,
+        E: Display,
+    {
+        let mut seen = HashSet::new();
+        let names: Vec<&EnvironmentName> = names.iter().filter(|name| seen.insert(*name)).collect();
+
+        let next = AtomicUsize::new(0);
+        let outcomes: Vec<Mutex<Option<EnvironmentOutcome>>> =
+            names.iter().map(|_| Mutex::new(None)).collect();
+        let workers = self.parallelism.get().min(names.len());
+
+        std::thread::scope(|scope| {
+            for _ in 0..workers {
+                scope.spawn(|| loop {
+                    let index = next.fetch_add(1, Ordering::Relaxed);
+                    let Some(name) = names.get(index) else {
+                        break;
+                    };
+
+                    if let Some(l) = listener {
+                        l.on_environment_started(name);
+                    }
+
+                    let started_at = Instant::now();
+                    let error = match catch_unwind(AssertUnwindSafe(|| operation(name))) {
+                        Ok(Ok(_)) => None,
+                        Ok(Err(e)) => Some(e.to_string()),
+                        Err(_) => Some("the command panicked".to_string()),
+                    };
+                    let outcome = EnvironmentOutcome {
+                        name: (*name).clone(),
+                        error,
+                        duration: started_at.elapsed(),
+                    };
+
+                    if let Some(l) = listener {
+                        l.on_environment_finished(&outcome);
+                    }
+
+                    *outcomes[index]
+                        .lock()
+                        .unwrap_or_else(std::sync::PoisonError::into_inner) = Some(outcome);
+                });
+            }
+        });
+
+        MultiEnvironmentReport {
+            outcomes: outcomes
+                .into_iter()
+                .filter_map(|slot| {
+                    slot.into_inner()
+                        .unwrap_or_else(std::sync::PoisonError::into_inner)
+                })
+                .collect(),
+        }
+    }
+
+    /// Run the asynchronous `operation` on every environment of `names`
+    ///
+    /// Each operation runs to completion on its worker thread, in a runtime
+    /// of its own, so this can be called both outside and inside a Tokio
+    /// runtime.
+    pub fn run_async<F, Fut, T, E>(
+        &self,
+        names: &[EnvironmentName],
+        listener: Option<&dyn MultiEnvironmentListener>,
+        operation: F,
+    ) -> MultiEnvironmentReport
+    where
+        F: Fn(EnvironmentName) -> Fut + Sync,
+        Fut: Future<Output = Result<T, E>>,
+        E: Display,
+    {
+        self.run(names, listener, |name| {
+            let runtime = tokio::runtime::Builder::new_current_thread()
+                .enable_all()
+                .build()
+                .map_err(|e| format!("cannot start the runtime of the command: {e}"))?;
+
+            runtime
+                .block_on(operation(name.clone()))
+                .map_err(|e| e.to_string())
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::thread::sleep;
+
+    use super::*;
+
+    fn names(names: &[&str]) -> Vec<EnvironmentName> {
+        names
+            .iter()
+            .map(|name| EnvironmentName::new((*name).to_string()).unwrap())
+            .collect()
+    }
+
+    fn runner(parallelism: usize) -> MultiEnvironmentRunner {
+        MultiEnvironmentRunner::new(NonZeroUsize::new(parallelism).unwrap())
+    }
+
+    /// Records the calls of the runner
+    #[derive(Default)]
+    struct RecordingListener {
+        events: Mutex<Vec<String>>,
+    }
+
+    impl MultiEnvironmentListener for RecordingListener {
+        fn on_environment_started(&self, name: &EnvironmentName) {
+            self.events.lock().unwrap().push(format!("started {name}"));
+        }
+
+        fn on_environment_finished(&self, outcome: &EnvironmentOutcome) {
+            self.events
+                .lock()
+                .unwrap()
+                .push(format!("finished {}", outcome.name));
+        }
+    }
+
+    #[test]
+    fn it_should_report_the_outcomes_in_selection_order() {
+        let report = runner(3).run(&names(&["e2e-1", "e2e-2", "e2e-3"]), None, |name| {
+            // The first environment finishes last
+            if name.as_str() == "e2e-1" {
+                sleep(Duration::from_millis(50));
+            }
+            Ok::<_, String>(())
+        });
+
+        let order: Vec<&str> = report.outcomes.iter().map(|o| o.name.as_str()).collect();
+        assert_eq!(order, vec!["e2e-1", "e2e-2", "e2e-3"]);
+    }
+
+    #[test]
+    fn it_should_keep_running_the_other_environments_when_one_fails() {
+        let report = runner(1).run(&names(&["e2e-1", "e2e-2", "e2e-3"]), None, |name| {
+            if name.as_str() == "e2e-1" {
+                Err("tofu apply failed")
+            } else {
+                Ok(())
+            }
+        });
+
+        assert_eq!(report.succeeded_count(), 2);
+        assert_eq!(report.failed_count(), 1);
+        assert_eq!(
+            report.outcomes[0].error.as_deref(),
+            Some("tofu apply failed")
+        );
+    }
+
+    #[test]
+    fn it_should_not_process_more_environments_at_once_than_the_parallelism() {
+        let running = AtomicUsize::new(0);
+        let max_running = AtomicUsize::new(0);
+
+        let report = runner(2).run(&names(&["a", "b", "c", "d", "e"]), None, |_| {
+            let now = running.fetch_add(1, Ordering::SeqCst) + 1;
+            max_running.fetch_max(now, Ordering::SeqCst);
+            sleep(Duration::from_millis(20));
+            running.fetch_sub(1, Ordering::SeqCst);
+            Ok::<_, String>(())
+        });
+
+        assert_eq!(report.succeeded_count(), 5);
+        assert_eq!(max_running.load(Ordering::SeqCst), 2);
+    }
+
+    #[test]
+    fn it_should_process_an_environment_selected_twice_once() {
+        let calls = AtomicUsize::new(0);
+
+        let report = runner(4).run(&names(&["e2e-1", "e2e-1"]), None, |_| {
+            calls.fetch_add(1, Ordering::SeqCst);
+            Ok::<_, String>(())
+        });
+
+        assert_eq!(calls.load(Ordering::SeqCst), 1);
+        assert_eq!(report.outcomes.len(), 1);
+    }
+
+    #[test]
+    fn it_should_report_a_panicking_command_as_failed() {
+        let report = runner(2).run(&names(&["e2e-1", "e2e-2"]), None, |name| {
+            assert_ne!(name.as_str(), "e2e-1", "unexpected environment");
+            Ok::<_, String>(())
+        });
+
+        assert_eq!(
+            report.outcomes[0].error.as_deref(),
+            Some("the command panicked")
+        );
+        assert!(report.outcomes[1].succeeded());
+    }
+
+    #[test]
+    fn it_should_notify_the_listener_when_each_environment_starts_and_finishes() {
+        let listener = RecordingListener::default();
+
+        runner(1).run(&names(&["e2e-1", "e2e-2"]), Some(&listener), |_| {
+            Ok::<_, String>(())
+        });
+
+        assert_eq!(
+            *listener.events.lock().unwrap(),
+            vec![
+                "started e2e-1",
+                "finished e2e-1",
+                "started e2e-2",
+                "finished e2e-2"
+            ]
+        );
+    }
+
+    #[tokio::test]
+    async fn it_should_run_asynchronous_commands_inside_a_runtime() {
+        let report = runner(2).run_async(&names(&["e2e-1", "e2e-2"]), None, |name| async move {
+            tokio::time::sleep(Duration::from_millis(10)).await;
+            if name.as_str() == "e2e-2" {
+                Err("deadline exceeded")
+            } else {
+                Ok(())
+            }
+        });
+
+        assert!(report.outcomes[0].succeeded());
+        assert_eq!(
+            report.outcomes[1].error.as_deref(),
+            Some("deadline exceeded")
+        );
+    }
+}
@@ -0,0 +1,29 @@
+//! Output logs of the external tools run by a command
+//!
+//! The commands running `OpenTofu` or Ansible keep the complete output of
+//! each invocation under `build/{env}/logs/{command}/`, successful runs
+//! included. Only the most recent runs of each command are kept.
+
+use tracing::{info, warn};
+
+use crate::shared::command::RunLog;
+
+/// Remove the logs of the runs older than the ones `run_log` keeps
+///
+/// Called once the command is over, whatever its outcome. A failure to prune
+/// is only logged: it must not fail a command whose work is done.
+pub fn prune_run_logs(run_log: &RunLog) {
+    match run_log.prune() {
+        Ok(0) => {}
+        Ok(removed) => info!(
+            logs_dir = %run_log.dir().display(),
+            removed_files = removed,
+            "Removed the logs of older runs"
+        ),
+        Err(e) => warn!(
+            logs_dir = %run_log.dir().display(),
+            error = %e,
+            "Failed to remove the logs of older runs"
+        ),
+    }
+}
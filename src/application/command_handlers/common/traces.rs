@@ -0,0 +1,46 @@
+//! Trace files of the failed commands
+//!
+//! The commands running the deployment workflows write a trace file for every
+//! failure. The traces go to the directory set with `--trace-dir` for this
+//! invocation, else to the one of the environment configuration, else to
+//! `data/{env}/traces/`. Only the traces allowed by the retention policy of
+//! the environment are kept.
+
+use std::path::{Path, PathBuf};
+
+use chrono::{DateTime, Utc};
+use tracing::{info, warn};
+
+use crate::domain::environment::{Environment, TracesConfig};
+use crate::infrastructure::trace;
+
+/// Directory the traces of a command on `environment` are written to
+///
+/// # Arguments
+///
+/// * `environment` - The environment the command runs against
+/// * `trace_dir` - The directory set for this invocation, if any
+#[must_use]
+pub fn traces_dir<S>(environment: &Environment<S>, trace_dir: Option<&Path>) -> PathBuf {
+    trace_dir.map_or_else(|| environment.traces_dir(), Path::to_path_buf)
+}
+
+/// Remove the traces beyond the retention policy of the environment
+///
+/// Called once the command is over, whatever its outcome. A failure to prune
+/// is only logged: it must not fail a command whose work is done.
+pub fn prune_traces(traces_dir: &Path, traces: &TracesConfig, now: DateTime<Utc>) {
+    match trace::prune_traces(traces_dir, traces.keep_last(), traces.max_age(), now) {
+        Ok(0) => {}
+        Ok(removed) => info!(
+            traces_dir = %traces_dir.display(),
+            removed_traces = removed,
+            "Removed older trace files"
+        ),
+        Err(e) => warn!(
+            traces_dir = %traces_dir.display(),
+            error = %e,
+            "Failed to remove older trace files"
+        ),
+    }
+}
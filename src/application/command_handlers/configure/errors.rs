@@ -1,6 +1,10 @@
 //! Error types for the Configure command handler
 
+use crate::adapters::ssh::SshError;
+use crate::application::command_handlers::common::ConfigOverrideError;
 use crate::application::errors::{InvalidStateError, PersistenceError};
+use crate::application::services::rendering::AnsibleTemplateRenderingServiceError;
+use crate::application::steps::CustomStepFailure;
 use crate::shared::command::CommandError;
 
 /// Comprehensive error type for the `ConfigureCommandHandler`
@@ -21,6 +25,26 @@ pub enum ConfigureCommandHandlerError {
 
     #[error("Environment is in an invalid state for configuration: {0}")]
     InvalidState(#[from] InvalidStateError),
+
+    /// The host keys of the instance could not be checked or have changed
+    #[error("SSH host key check failed: {0}")]
+    HostKeyCheck(#[from] SshError),
+
+    /// The configuration override could not be applied
+    #[error("{0}")]
+    ConfigOverride(#[from] ConfigOverrideError),
+
+    /// The Ansible templates could not be rendered again with overridden settings
+    #[error("Template rendering failed: {0}")]
+    TemplateRendering(#[from] AnsibleTemplateRenderingServiceError),
+
+    /// The user interrupted the command (Ctrl-C)
+    #[error("interrupted by user")]
+    Interrupted,
+
+    /// A custom step registered through the SDK failed
+    #[error("{0}")]
+    CustomStepFailed(#[from] CustomStepFailure),
 }
 
 impl From<crate::domain::environment::repository::RepositoryError>
@@ -52,15 +76,35 @@ impl crate::shared::Traceable for ConfigureCommandHandlerError {
             Self::InvalidState(e) => {
                 format!("ConfigureCommandHandlerError: Environment is in an invalid state for configuration - {e}")
             }
+            Self::HostKeyCheck(e) => {
+                format!("ConfigureCommandHandlerError: SSH host key check failed - {e}")
+            }
+            Self::ConfigOverride(e) => {
+                format!("ConfigureCommandHandlerError: Configuration override failed - {e}")
+            }
+            Self::TemplateRendering(e) => {
+                format!("ConfigureCommandHandlerError: Template rendering failed - {e}")
+            }
+            Self::Interrupted => {
+                "ConfigureCommandHandlerError: Interrupted by user (Ctrl-C)".to_string()
+            }
+            Self::CustomStepFailed(e) => {
+                format!("ConfigureCommandHandlerError: Custom step failed - {e}")
+            }
         }
     }
 
     fn trace_source(&self) -> Option<&dyn crate::shared::Traceable> {
         match self {
             Self::Command(e) => Some(e),
+            Self::HostKeyCheck(e) => Some(e),
+            Self::ConfigOverride(e) => Some(e),
             Self::EnvironmentNotFound { .. }
             | Self::StatePersistence(_)
-            | Self::InvalidState(_) => None,
+            | Self::InvalidState(_)
+            | Self::TemplateRendering(_)
+            | Self::Interrupted
+            | Self::CustomStepFailed(_) => None,
         }
     }
 
@@ -69,8 +113,13 @@ impl crate::shared::Traceable for ConfigureCommandHandlerError {
             Self::EnvironmentNotFound { .. } | Self::InvalidState(_) => {
                 crate::shared::ErrorKind::Configuration
             }
-            Self::Command(_) => crate::shared::ErrorKind::CommandExecution,
+            Self::Command(e) => crate::shared::Traceable::error_kind(e),
+            Self::HostKeyCheck(e) => crate::shared::Traceable::error_kind(e),
+            Self::ConfigOverride(e) => crate::shared::Traceable::error_kind(e),
+            Self::TemplateRendering(_) => crate::shared::ErrorKind::TemplateRendering,
             Self::StatePersistence(_) => crate::shared::ErrorKind::StatePersistence,
+            Self::Interrupted => crate::shared::ErrorKind::Interrupted,
+            Self::CustomStepFailed(_) => crate::shared::ErrorKind::CommandExecution,
         }
     }
 }
@@ -185,6 +234,56 @@ If the problem persists, report it with full system details."
 For more information about environment states and transitions,
 see the documentation on environment lifecycle management."
             }
+            Self::HostKeyCheck(e) => e.help(),
+            Self::ConfigOverride(e) => e.help(),
+            Self::TemplateRendering(_) => {
+                "Template Rendering Failed - Troubleshooting:
+
+The Ansible templates are rendered again when a configuration override
+changes settings of the environment.
+
+1. Check that the Ansible template files exist in the templates directory
+2. If the environment uses a templates override directory, check the
+   syntax of the templates in it (Tera template syntax)
+3. Check file permissions on the build/<env-name>/ directory
+
+For more information, see docs/user-guide/commands/configure.md"
+            }
+            Self::Interrupted => {
+                "Configuration Interrupted - Troubleshooting:
+
+Configuration was stopped with Ctrl-C before it finished. The environment
+was saved in 'ConfigureFailed' state and the instance may be partially
+configured.
+
+1. Check the current environment state:
+   <tool> show <env-name>
+
+2. Review trace files in data/<env-name>/traces/ for the last step run
+
+3. Destroy and recreate the environment to start from a clean instance:
+   <tool> destroy <env-name>
+
+For more information about environment states and transitions,
+see the documentation on environment lifecycle management."
+            }
+            Self::CustomStepFailed(_) => {
+                "Custom Step Failed - Troubleshooting:
+
+A step registered through the SDK failed before or after the configuration
+playbooks. The environment was saved in 'ConfigureFailed' state with the
+failed step recorded as 'custom:<step-name>' and the error of the step as
+summary.
+
+1. Check the error returned by the step and fix its cause
+
+2. Review trace files in data/<env-name>/traces/ for the full error chain
+
+3. Destroy and recreate the environment to start from a clean instance:
+   <tool> destroy <env-name>
+
+For SDK details, see packages/sdk/README.md"
+            }
         }
     }
 }
@@ -192,6 +291,7 @@ see the documentation on environment lifecycle management."
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::application::errors::AllowedCommands;
     use crate::shared::command::CommandError;
 
     #[test]
@@ -235,6 +335,7 @@ mod tests {
         let error = ConfigureCommandHandlerError::InvalidState(InvalidStateError {
             expected: "provisioned".to_string(),
             actual: "created".to_string(),
+            allowed_commands: AllowedCommands::default(),
         });
 
         let help = error.help();
@@ -259,6 +360,13 @@ mod tests {
             ConfigureCommandHandlerError::InvalidState(InvalidStateError {
                 expected: "provisioned".to_string(),
                 actual: "created".to_string(),
+                allowed_commands: AllowedCommands::default(),
+            }),
+            ConfigureCommandHandlerError::Interrupted,
+            ConfigureCommandHandlerError::CustomStepFailed(CustomStepFailure {
+                step: "seed-users".to_string(),
+                hook: crate::application::steps::HookPoint::AfterConfigure,
+                source: crate::application::steps::StepError::new("database unreachable"),
             }),
         ];
 
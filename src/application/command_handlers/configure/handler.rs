@@ -1,29 +1,48 @@
 //! Configure command handler implementation
 
+use std::net::IpAddr;
+use std::path::PathBuf;
 use std::sync::Arc;
+use std::time::Duration;
 
-use tracing::{error, info, instrument};
+use tokio_util::sync::CancellationToken;
+use tracing::{error, info, instrument, warn};
 
 use super::errors::ConfigureCommandHandlerError;
-use crate::adapters::ansible::AnsibleClient;
-use crate::application::command_handlers::common::StepResult;
+use crate::adapters::ansible::{AnsibleClient, AnsiblePlaybookOptions, TaskFailure};
+use crate::adapters::ssh::{SshMultiplexer, SshRetryPolicy};
+use crate::application::command_handlers::common::{
+    apply_config_override, prune_run_logs, prune_traces, traces_dir, verify_host_keys, CheckReport,
+    StepResult,
+};
+use crate::application::services::rendering::{
+    AnsibleTemplateRenderingService, AnsibleTemplateRenderingServiceError,
+};
 use crate::application::steps::{
-    ConfigureFirewallStep, ConfigureSecurityUpdatesStep, InstallDockerComposeStep,
-    InstallDockerStep,
+    ConfigureFirewallStep, ConfigureSecurityUpdatesStep, ConfigureSystemTuningStep, CustomSteps,
+    HookPoint, InstallDockerComposeStep, InstallDockerStep, StepContext,
 };
 use crate::application::traits::CommandProgressListener;
+use crate::domain::environment::deployment_report::elapsed_ms;
 use crate::domain::environment::repository::{EnvironmentRepository, TypedEnvironmentRepository};
-use crate::domain::environment::state::{ConfigureFailureContext, ConfigureStep};
-use crate::domain::environment::{Configured, Configuring, Environment};
+use crate::domain::environment::state::{
+    AnyEnvironmentState, ConfigureFailureContext, ConfigureStep,
+};
+use crate::domain::environment::{
+    ChangeImpact, Configured, Configuring, DeploymentPhase, Environment, Provisioned, UserInputs,
+};
+use crate::domain::provider::Provider;
 use crate::domain::EnvironmentName;
 use crate::infrastructure::trace::ConfigureTraceWriter;
+use crate::shared::command::run_log::DEFAULT_KEPT_RUNS;
+use crate::shared::command::{CommandTimeouts, RunLog};
 use crate::shared::error::Traceable;
 
 /// Total number of steps in the configuration workflow.
 ///
 /// This constant is used for progress reporting via `CommandProgressListener`
-/// to display step progress like "[Step 1/4] Installing Docker...".
-const TOTAL_CONFIGURE_STEPS: usize = 4;
+/// to display step progress like "[Step 1/5] Installing Docker...".
+const TOTAL_CONFIGURE_STEPS: usize = 5;
 
 /// `ConfigureCommandHandler` orchestrates the complete infrastructure configuration workflow
 ///
@@ -34,6 +53,7 @@ const TOTAL_CONFIGURE_STEPS: usize = 4;
 /// 2. Install Docker Compose
 /// 3. Configure automatic security updates
 /// 4. Configure UFW firewall
+/// 5. Apply the system tuning (sysctls, ulimits, swap file)
 ///
 /// # State Management
 ///
@@ -45,9 +65,60 @@ const TOTAL_CONFIGURE_STEPS: usize = 4;
 ///
 /// State is persisted after each transition using the injected repository.
 /// Persistence failures are logged but don't fail the command (state remains valid in memory).
+///
+/// # Cancellation
+///
+/// The workflow checks its `CancellationToken` between steps. When the token
+/// is cancelled (e.g. the user pressed Ctrl-C) the environment is persisted as
+/// `ConfigureFailed` with `failed_step = ConfigureStep::Interrupted`.
+///
+/// # Ansible Execution Controls
+///
+/// [`AnsiblePlaybookOptions`] set with `with_ansible_options` are used by
+/// [`Self::execute_tagged`], where `--tags`/`--skip-tags` restrict the run to
+/// some components (e.g. only `docker`), and by [`Self::check`]. Neither
+/// changes the environment state: only a full run by [`Self::execute`], which
+/// ignores the options, makes the environment `Configured`.
+///
+/// # Timeouts
+///
+/// Every playbook run is bounded by [`CommandTimeouts::ansible_playbook`] and
+/// killed, with its whole process group, when it runs longer. An optional
+/// overall deadline (see [`Self::with_deadline`]) caps the whole workflow.
+///
+/// # Network Failures
+///
+/// A playbook whose host became unreachable because of a network failure
+/// (connection refused, reset or timed out) is run again, with the
+/// [`SshRetryPolicy`] set with [`Self::with_ssh_retry_policy`]. Each retry is
+/// reported as a detail of the current step, and the failure context of a
+/// failed run records how many times its playbook was run.
+///
+/// # Custom Steps
+///
+/// Steps registered with [`Self::with_custom_steps`] run before the first
+/// playbook ([`HookPoint::BeforeConfigure`]) and after the last one
+/// ([`HookPoint::AfterConfigure`]). They are not run by [`Self::check`].
+///
+/// # Configuration Overrides
+///
+/// An override file set with [`Self::with_config_override`] is merged onto
+/// the persisted configuration before the playbooks run, and the Ansible
+/// templates are rendered again when it changes a setting. Only changes that
+/// take effect with configure or a release are accepted; the merged
+/// configuration is persisted with the `Configuring` state.
 pub struct ConfigureCommandHandler {
     pub(crate) clock: Arc<dyn crate::shared::Clock>,
     pub(crate) repository: TypedEnvironmentRepository,
+    pub(crate) cancellation: CancellationToken,
+    pub(crate) ansible_options: AnsiblePlaybookOptions,
+    pub(crate) command_timeouts: CommandTimeouts,
+    pub(crate) ssh_retry_policy: SshRetryPolicy,
+    pub(crate) deadline: Option<Duration>,
+    pub(crate) custom_steps: CustomSteps,
+    pub(crate) kept_run_logs: usize,
+    trace_dir: Option<PathBuf>,
+    pub(crate) config_override: Option<PathBuf>,
 }
 
 impl ConfigureCommandHandler {
@@ -60,16 +131,98 @@ impl ConfigureCommandHandler {
         Self {
             clock,
             repository: TypedEnvironmentRepository::new(repository),
+            cancellation: CancellationToken::new(),
+            ansible_options: AnsiblePlaybookOptions::default(),
+            command_timeouts: CommandTimeouts::default(),
+            ssh_retry_policy: SshRetryPolicy::default(),
+            deadline: None,
+            custom_steps: CustomSteps::new(),
+            kept_run_logs: DEFAULT_KEPT_RUNS,
+            trace_dir: None,
+            config_override: None,
         }
     }
 
+    /// Stop the workflow when the given token is cancelled
+    ///
+    /// Without a token the handler runs to completion or failure.
+    #[must_use]
+    pub fn with_cancellation(mut self, cancellation: CancellationToken) -> Self {
+        self.cancellation = cancellation;
+        self
+    }
+
+    /// Pass tag selection to `ansible-playbook`
+    ///
+    /// The options are used by [`Self::execute_tagged`] and [`Self::check`];
+    /// [`Self::execute`] always runs every playbook in full.
+    #[must_use]
+    pub fn with_ansible_options(mut self, options: AnsiblePlaybookOptions) -> Self {
+        self.ansible_options = options;
+        self
+    }
+
+    /// Bound each playbook run with the given timeouts
+    #[must_use]
+    pub fn with_command_timeouts(mut self, command_timeouts: CommandTimeouts) -> Self {
+        self.command_timeouts = command_timeouts;
+        self
+    }
+
+    /// Run playbooks again with `policy` when a network failure makes the host unreachable
+    #[must_use]
+    pub fn with_ssh_retry_policy(mut self, policy: SshRetryPolicy) -> Self {
+        self.ssh_retry_policy = policy;
+        self
+    }
+
+    /// Stop running playbooks once `deadline` has passed since the workflow started
+    #[must_use]
+    pub fn with_deadline(mut self, deadline: Duration) -> Self {
+        self.deadline = Some(deadline);
+        self
+    }
+
+    /// Keep the Ansible output logs of the last `runs` configure runs
+    #[must_use]
+    pub fn with_kept_run_logs(mut self, runs: usize) -> Self {
+        self.kept_run_logs = runs;
+        self
+    }
+
+    /// Write the trace files of a failure to `trace_dir`
+    ///
+    /// `None` keeps the directory of the environment configuration, or
+    /// `data/{env}/traces/` when it sets none.
+    #[must_use]
+    pub fn with_trace_dir(mut self, trace_dir: Option<PathBuf>) -> Self {
+        self.trace_dir = trace_dir;
+        self
+    }
+
+    /// Merge the override file at `path` onto the persisted configuration before configuring
+    #[must_use]
+    pub fn with_config_override(mut self, path: PathBuf) -> Self {
+        self.config_override = Some(path);
+        self
+    }
+
+    /// Run the configure hooks of `custom_steps` around the playbooks
+    ///
+    /// A failing step fails the workflow with `ConfigureStep::CustomStep` naming the step.
+    #[must_use]
+    pub fn with_custom_steps(mut self, custom_steps: CustomSteps) -> Self {
+        self.custom_steps = custom_steps;
+        self
+    }
+
     /// Execute the complete configuration workflow
     ///
     /// # Arguments
     ///
     /// * `env_name` - The name of the environment to configure
     /// * `listener` - Optional progress listener for reporting step-level progress.
-    ///   When provided, the handler reports progress at each of the 4 configuration steps.
+    ///   When provided, the handler reports progress at each of the 5 configuration steps.
     ///   When `None`, the handler executes silently (backward compatible).
     ///
     /// # Returns
@@ -87,27 +240,65 @@ impl ConfigureCommandHandler {
     ///
     /// On error, the environment transitions to `ConfigureFailed` state and is persisted.
     #[instrument(
-        name = "configure_command",
+        name = "command.configure",
         skip_all,
         fields(
             command_type = "configure",
-            environment = %env_name
+            environment_name = %env_name
         )
     )]
+    #[allow(clippy::result_large_err)]
     pub fn execute(
         &self,
         env_name: &EnvironmentName,
         listener: Option<&dyn CommandProgressListener>,
     ) -> Result<Environment<Configured>, ConfigureCommandHandlerError> {
+        let _lock = self.repository.inner().lock(env_name)?;
         let environment = self.load_provisioned_environment(env_name)?;
 
+        // Refuse to configure a host presenting other keys than the recorded ones
+        if let Some(instance_ip) = environment.instance_ip() {
+            verify_host_keys(&environment, instance_ip)?;
+        }
+
+        let environment = self.apply_config_override(environment)?;
+
         let started_at = self.clock.now();
 
         let environment = environment.start_configuring();
 
         self.repository.save_configuring(&environment)?;
 
-        match Self::execute_configuration_with_tracking(&environment, listener) {
+        let run_log = self.run_log(&environment, started_at);
+        // A full run: tag filters only apply to `execute_tagged`
+        let ansible_client = Arc::new(
+            AnsibleClient::new(environment.ansible_build_dir())
+                .with_options(AnsiblePlaybookOptions::default())
+                .with_timeouts(self.run_timeouts())
+                .with_retry_policy(self.ssh_retry_policy)
+                .with_run_log(run_log.clone())
+                .with_env_vars(environment.context().user_inputs.proxy_env_vars()),
+        );
+
+        // Playbooks share SSH connections through control sockets in
+        // `build/{env}/ssh`; they are closed when the workflow returns
+        let _multiplexer = SshMultiplexer::new(environment.ssh_control_dir());
+
+        let result = self
+            .run_custom_steps(HookPoint::BeforeConfigure, environment.clone().into_any())
+            .and_then(|()| {
+                self.execute_configuration_with_tracking(&environment, &ansible_client, listener)
+            })
+            .and_then(|configured| {
+                self.run_custom_steps(HookPoint::AfterConfigure, configured.clone().into_any())?;
+                Ok(configured)
+            });
+        prune_run_logs(&run_log);
+
+        let traces_dir = traces_dir(&environment, self.trace_dir.as_deref());
+        let traces = environment.traces_config().clone();
+
+        let outcome = match result {
             Ok(configured_env) => {
                 info!(
                     command = "configure",
@@ -115,6 +306,10 @@ impl ConfigureCommandHandler {
                     "Infrastructure configuration completed successfully"
                 );
 
+                let configured_env = configured_env.with_phase_duration(
+                    DeploymentPhase::Configure,
+                    elapsed_ms(started_at, self.clock.now()),
+                );
                 self.repository.save_configured(&configured_env)?;
 
                 Ok(configured_env)
@@ -128,8 +323,40 @@ impl ConfigureCommandHandler {
                     "Infrastructure configuration failed"
                 );
 
-                let context =
-                    self.build_failure_context(&environment, &e, current_step, started_at);
+                // An Ansible run stopped by Ctrl-C fails its step with its own
+                // error; report the whole run as interrupted instead.
+                let (e, current_step, failed_task) = if self.cancellation.is_cancelled() {
+                    warn!(
+                        command = "configure",
+                        environment = %environment.name(),
+                        "Infrastructure configuration interrupted by user"
+                    );
+                    (
+                        ConfigureCommandHandlerError::Interrupted,
+                        ConfigureStep::Interrupted,
+                        None,
+                    )
+                } else {
+                    (e, current_step, ansible_client.failed_task())
+                };
+
+                // Only the playbook steps run playbooks; custom steps do not
+                let attempts = Some(ansible_client.attempts()).filter(|attempts| {
+                    *attempts > 0
+                        && !matches!(
+                            current_step,
+                            ConfigureStep::CustomStep(_) | ConfigureStep::Interrupted
+                        )
+                });
+
+                let context = self.build_failure_context(
+                    &environment,
+                    &e,
+                    current_step,
+                    failed_task,
+                    attempts,
+                    started_at,
+                );
 
                 let failed = environment.configure_failed(context);
 
@@ -137,7 +364,191 @@ impl ConfigureCommandHandler {
 
                 Err(e)
             }
+        };
+        prune_traces(&traces_dir, &traces, self.clock.now());
+
+        outcome
+    }
+
+    /// Report what the configuration workflow would change, without changing anything
+    ///
+    /// Runs the same playbooks as [`Self::execute`] with `ansible-playbook
+    /// --check`. The environment must be in `Provisioned` state, but its state
+    /// is neither transitioned nor persisted, whatever the outcome.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the environment cannot be loaded, is not in
+    /// `Provisioned` state, or a playbook fails in check mode. Tasks that
+    /// would change the host are not errors; they are counted in the
+    /// returned [`CheckReport`].
+    #[instrument(
+        name = "command.configure_check",
+        skip_all,
+        fields(
+            command_type = "configure",
+            environment_name = %env_name,
+            check = true
+        )
+    )]
+    pub fn check(
+        &self,
+        env_name: &EnvironmentName,
+        listener: Option<&dyn CommandProgressListener>,
+    ) -> Result<CheckReport, ConfigureCommandHandlerError> {
+        let ansible_client = self.run_in_place(
+            env_name,
+            self.ansible_options.clone().with_check(true),
+            listener,
+        )?;
+
+        let report = CheckReport::new(ansible_client.recaps());
+
+        info!(
+            command = "configure",
+            environment = %env_name,
+            changed_tasks = report.changed_tasks(),
+            "Configuration check completed"
+        );
+
+        Ok(report)
+    }
+
+    /// Run the playbooks restricted by the tag filters, without changing the environment state
+    ///
+    /// A run limited with `--tags`/`--skip-tags` does not apply the whole
+    /// configuration, so it cannot make the environment `Configured`. Like
+    /// [`Self::check`], the environment must be in `Provisioned` state and its
+    /// state is neither transitioned nor persisted, whatever the outcome.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the environment cannot be loaded, is not in
+    /// `Provisioned` state, or a playbook fails.
+    #[instrument(
+        name = "command.configure_tagged",
+        skip_all,
+        fields(
+            command_type = "configure",
+            environment_name = %env_name
+        )
+    )]
+    pub fn execute_tagged(
+        &self,
+        env_name: &EnvironmentName,
+        listener: Option<&dyn CommandProgressListener>,
+    ) -> Result<(), ConfigureCommandHandlerError> {
+        let _lock = self.repository.inner().lock(env_name)?;
+        self.run_in_place(
+            env_name,
+            self.ansible_options.clone().with_check(false),
+            listener,
+        )?;
+
+        info!(
+            command = "configure",
+            environment = %env_name,
+            "Tag-filtered configuration completed; the environment state was not changed"
+        );
+
+        Ok(())
+    }
+
+    /// Run the configuration playbooks with `options` on an environment kept in memory
+    ///
+    /// Shared by [`Self::check`] and [`Self::execute_tagged`]. Custom steps
+    /// are not run and nothing is persisted.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the environment cannot be loaded, is not in
+    /// `Provisioned` state, or a playbook fails.
+    fn run_in_place(
+        &self,
+        env_name: &EnvironmentName,
+        options: AnsiblePlaybookOptions,
+        listener: Option<&dyn CommandProgressListener>,
+    ) -> Result<Arc<AnsibleClient>, ConfigureCommandHandlerError> {
+        let environment = self.load_provisioned_environment(env_name)?;
+
+        if let Some(instance_ip) = environment.instance_ip() {
+            verify_host_keys(&environment, instance_ip)?;
+        }
+
+        let environment = self.apply_config_override(environment)?;
+
+        // Only used in memory to run the steps; never persisted
+        let environment = environment.start_configuring();
+
+        let run_log = self.run_log(&environment, self.clock.now());
+        let ansible_client = Arc::new(
+            AnsibleClient::new(environment.ansible_build_dir())
+                .with_options(options)
+                .with_timeouts(self.run_timeouts())
+                .with_retry_policy(self.ssh_retry_policy)
+                .with_run_log(run_log.clone())
+                .with_env_vars(environment.context().user_inputs.proxy_env_vars()),
+        );
+
+        // Playbooks share SSH connections through control sockets in
+        // `build/{env}/ssh`; they are closed when the workflow returns
+        let _multiplexer = SshMultiplexer::new(environment.ssh_control_dir());
+
+        let result =
+            self.execute_configuration_with_tracking(&environment, &ansible_client, listener);
+        prune_run_logs(&run_log);
+        result.map_err(|(e, _)| e)?;
+
+        Ok(ansible_client)
+    }
+
+    /// Merge the configuration override, if any, onto the environment
+    ///
+    /// When the override changes a setting, the Ansible templates are
+    /// rendered again so the playbooks use the merged configuration. Nothing
+    /// is persisted.
+    ///
+    /// # Errors
+    ///
+    /// Returns `ConfigOverride` if the override cannot be applied, or
+    /// `TemplateRendering` if the templates cannot be rendered again.
+    fn apply_config_override(
+        &self,
+        environment: Environment<Provisioned>,
+    ) -> Result<Environment<Provisioned>, ConfigureCommandHandlerError> {
+        let Some(path) = &self.config_override else {
+            return Ok(environment);
+        };
+
+        let revision = environment.config_revision();
+        let environment = apply_config_override(
+            environment,
+            path,
+            "configure",
+            ChangeImpact::RequiresConfigure,
+        )?;
+
+        if let Some(instance_ip) = environment
+            .instance_ip()
+            .filter(|_| environment.config_revision() != revision)
+        {
+            let service = AnsibleTemplateRenderingService::from_paths(
+                environment.templates_dir(),
+                environment.templates_override_dir(),
+                environment.extra_template_variables().clone(),
+                environment.build_dir().clone(),
+                self.clock.clone(),
+            )
+            .with_known_hosts_file(environment.ssh_known_hosts_path());
+            render_ansible_templates(
+                &service,
+                &environment.context().user_inputs,
+                instance_ip,
+                environment.context().runtime_outputs.published_ssh_port(),
+            )?;
         }
+
+        Ok(environment)
     }
 
     /// Execute the configuration steps with step tracking
@@ -149,23 +560,31 @@ impl ConfigureCommandHandler {
     /// # Arguments
     ///
     /// * `environment` - The environment in Configuring state
+    /// * `ansible_client` - Client running the playbooks, with the Ansible options applied
     /// * `listener` - Optional progress listener for step-level reporting
     ///
     /// # Errors
     ///
     /// Returns a tuple of (error, `current_step`) if any configuration step fails
-    fn execute_configuration_with_tracking(
+    #[allow(clippy::result_large_err)]
+    pub(super) fn execute_configuration_with_tracking(
+        &self,
         environment: &Environment<Configuring>,
+        ansible_client: &Arc<AnsibleClient>,
         listener: Option<&dyn CommandProgressListener>,
     ) -> StepResult<Environment<Configured>, ConfigureCommandHandlerError, ConfigureStep> {
-        let ansible_client = Arc::new(AnsibleClient::new(environment.ansible_build_dir()));
+        // The Docker provider runs the instance in a container whose image ships
+        // Docker and whose published ports replace the firewall
+        let in_container = environment.provider_config().provider() == Provider::Docker;
 
         // Allow tests or CI to skip Docker installation
         // (useful for container-based tests where Docker is already installed via Dockerfile)
-        let skip_docker =
-            std::env::var("TORRUST_TD_SKIP_DOCKER_INSTALL_IN_CONTAINER").is_ok_and(|v| v == "true");
+        let skip_docker = in_container
+            || std::env::var("TORRUST_TD_SKIP_DOCKER_INSTALL_IN_CONTAINER")
+                .is_ok_and(|v| v == "true");
 
-        // Step 1/4: Install Docker
+        // Step 1/5: Install Docker
+        self.ensure_not_interrupted()?;
         let current_step = ConfigureStep::InstallDocker;
         Self::notify_step_started(listener, 1, "Installing Docker");
         if skip_docker {
@@ -173,15 +592,18 @@ impl ConfigureCommandHandler {
                 command = "configure",
                 step = "install_docker",
                 status = "skipped",
-                "Skipping Docker installation due to TORRUST_TD_SKIP_DOCKER_INSTALL_IN_CONTAINER (Docker pre-installed)"
+                in_container,
+                "Skipping Docker installation due to the Docker provider or TORRUST_TD_SKIP_DOCKER_INSTALL_IN_CONTAINER (Docker pre-installed)"
             );
         } else {
-            InstallDockerStep::new(Arc::clone(&ansible_client))
-                .execute(listener)
-                .map_err(|e| (e.into(), current_step))?;
+            let result = InstallDockerStep::new(Arc::clone(ansible_client)).execute(listener);
+            Self::notify_playbook_tasks(listener, ansible_client);
+            result.map_err(|e| (e.into(), current_step))?;
         }
+        Self::notify_step_completed(listener, 1, "Installing Docker");
 
-        // Step 2/4: Install Docker Compose
+        // Step 2/5: Install Docker Compose
+        self.ensure_not_interrupted()?;
         let current_step = ConfigureStep::InstallDockerCompose;
         Self::notify_step_started(listener, 2, "Installing Docker Compose");
         if skip_docker {
@@ -189,42 +611,75 @@ impl ConfigureCommandHandler {
                 command = "configure",
                 step = "install_docker_compose",
                 status = "skipped",
-                "Skipping Docker Compose installation due to TORRUST_TD_SKIP_DOCKER_INSTALL_IN_CONTAINER (Docker Compose pre-installed)"
+                in_container,
+                "Skipping Docker Compose installation due to the Docker provider or TORRUST_TD_SKIP_DOCKER_INSTALL_IN_CONTAINER (Docker Compose pre-installed)"
             );
         } else {
-            InstallDockerComposeStep::new(Arc::clone(&ansible_client))
-                .execute(listener)
-                .map_err(|e| (e.into(), current_step))?;
+            let result =
+                InstallDockerComposeStep::new(Arc::clone(ansible_client)).execute(listener);
+            Self::notify_playbook_tasks(listener, ansible_client);
+            result.map_err(|e| (e.into(), current_step))?;
         }
+        Self::notify_step_completed(listener, 2, "Installing Docker Compose");
 
-        // Step 3/4: Configure automatic security updates
+        // Step 3/5: Configure automatic security updates
+        self.ensure_not_interrupted()?;
         let current_step = ConfigureStep::ConfigureSecurityUpdates;
         Self::notify_step_started(listener, 3, "Configuring automatic security updates");
-        ConfigureSecurityUpdatesStep::new(Arc::clone(&ansible_client))
-            .execute(listener)
-            .map_err(|e| (e.into(), current_step))?;
-
-        // Step 4/4: Configure firewall (UFW)
+        let result =
+            ConfigureSecurityUpdatesStep::new(Arc::clone(ansible_client)).execute(listener);
+        Self::notify_playbook_tasks(listener, ansible_client);
+        result.map_err(|e| (e.into(), current_step))?;
+        Self::notify_step_completed(listener, 3, "Configuring automatic security updates");
+
+        // Step 4/5: Configure firewall (UFW)
+        self.ensure_not_interrupted()?;
         let current_step = ConfigureStep::ConfigureFirewall;
         Self::notify_step_started(listener, 4, "Configuring firewall (UFW)");
         // Allow tests or CI to explicitly skip the firewall configuration step
         // (useful for container-based test runs where iptables/ufw require
         // elevated kernel capabilities not available in unprivileged containers).
-        let skip_firewall =
-            std::env::var("TORRUST_TD_SKIP_FIREWALL_IN_CONTAINER").is_ok_and(|v| v == "true");
+        let skip_firewall = in_container
+            || std::env::var("TORRUST_TD_SKIP_FIREWALL_IN_CONTAINER").is_ok_and(|v| v == "true");
 
         if skip_firewall {
             info!(
                 command = "configure",
                 step = "configure_firewall",
                 status = "skipped",
-                "Skipping UFW firewall configuration due to TORRUST_TD_SKIP_FIREWALL_IN_CONTAINER"
+                in_container,
+                "Skipping UFW firewall configuration due to the Docker provider or TORRUST_TD_SKIP_FIREWALL_IN_CONTAINER"
             );
         } else {
-            ConfigureFirewallStep::new(Arc::clone(&ansible_client))
-                .execute(listener)
-                .map_err(|e| (e.into(), current_step))?;
+            let result = ConfigureFirewallStep::new(Arc::clone(ansible_client)).execute(listener);
+            Self::notify_playbook_tasks(listener, ansible_client);
+            result.map_err(|e| (e.into(), current_step))?;
         }
+        Self::notify_step_completed(listener, 4, "Configuring firewall (UFW)");
+
+        // Step 5/5: Apply system tuning (sysctls, ulimits, swap file)
+        self.ensure_not_interrupted()?;
+        let current_step = ConfigureStep::ConfigureSystemTuning;
+        Self::notify_step_started(listener, 5, "Applying system tuning");
+        let system_tuning = environment.context().user_inputs.system_tuning();
+        // Containers share the host kernel; the `system_tuning` section is
+        // rejected for the Docker provider, so only an empty one reaches here
+        if in_container || system_tuning.is_noop() {
+            info!(
+                command = "configure",
+                step = "configure_system_tuning",
+                status = "skipped",
+                in_container,
+                "Skipping system tuning: nothing to apply or the Docker provider"
+            );
+        } else {
+            let result =
+                ConfigureSystemTuningStep::new(Arc::clone(ansible_client), system_tuning.clone())
+                    .execute(listener);
+            Self::notify_playbook_tasks(listener, ansible_client);
+            result.map_err(|e| (e.into(), current_step))?;
+        }
+        Self::notify_step_completed(listener, 5, "Applying system tuning");
 
         // Transition to Configured state
         let configured = environment.clone().configured();
@@ -232,6 +687,46 @@ impl ConfigureCommandHandler {
         Ok(configured)
     }
 
+    /// Run the custom steps registered for `hook`
+    ///
+    /// # Errors
+    ///
+    /// Returns `(CustomStepFailed, ConfigureStep::CustomStep(name))` for the first
+    /// failing step
+    #[allow(clippy::result_large_err)]
+    pub(crate) fn run_custom_steps(
+        &self,
+        hook: HookPoint,
+        environment: AnyEnvironmentState,
+    ) -> StepResult<(), ConfigureCommandHandlerError, ConfigureStep> {
+        let context = StepContext::new(environment, hook);
+
+        self.custom_steps.run_blocking(&context).map_err(|e| {
+            let step = ConfigureStep::CustomStep(e.step.clone());
+            (e.into(), step)
+        })
+    }
+
+    /// Fail with `Interrupted` if the user cancelled the command
+    ///
+    /// # Errors
+    ///
+    /// Returns `(Interrupted, ConfigureStep::Interrupted)` if the cancellation
+    /// token has been cancelled
+    #[allow(clippy::result_large_err)]
+    fn ensure_not_interrupted(
+        &self,
+    ) -> StepResult<(), ConfigureCommandHandlerError, ConfigureStep> {
+        if self.cancellation.is_cancelled() {
+            return Err((
+                ConfigureCommandHandlerError::Interrupted,
+                ConfigureStep::Interrupted,
+            ));
+        }
+
+        Ok(())
+    }
+
     /// Build failure context for a configuration error and generate trace file
     ///
     /// This helper method builds structured error context including the failed step,
@@ -246,6 +741,7 @@ impl ConfigureCommandHandler {
     /// * `environment` - The environment being configured (for trace directory path)
     /// * `error` - The configuration error that occurred
     /// * `current_step` - The step that was executing when the error occurred
+    /// * `failed_task` - The Ansible task that failed, if the step failed in a playbook task
     /// * `started_at` - The timestamp when configuration execution started
     ///
     /// # Returns
@@ -256,6 +752,8 @@ impl ConfigureCommandHandler {
         environment: &Environment<Configuring>,
         error: &ConfigureCommandHandlerError,
         current_step: ConfigureStep,
+        failed_task: Option<TaskFailure>,
+        attempts: Option<u32>,
         started_at: chrono::DateTime<chrono::Utc>,
     ) -> ConfigureFailureContext {
         use crate::application::command_handlers::common::failure_context::build_base_failure_context;
@@ -267,17 +765,21 @@ impl ConfigureCommandHandler {
         let error_kind = error.error_kind();
 
         // Build base failure context using common helper
-        let base = build_base_failure_context(&self.clock, started_at, error.to_string());
+        let error_summary = error.to_string();
+
+        let base = build_base_failure_context(&self.clock, started_at, error_summary);
 
         // Build handler-specific context
         let mut context = ConfigureFailureContext {
             failed_step,
             error_kind,
             base,
+            failed_task,
+            attempts,
         };
 
         // Generate trace file (logging handled by trace writer)
-        let traces_dir = environment.traces_dir();
+        let traces_dir = traces_dir(environment, self.trace_dir.as_deref());
         let trace_writer = ConfigureTraceWriter::new(traces_dir, Arc::clone(&self.clock));
 
         if let Ok(trace_file_path) = trace_writer.write_trace(&context, error) {
@@ -334,4 +836,96 @@ impl ConfigureCommandHandler {
             l.on_step_started(step_number, TOTAL_CONFIGURE_STEPS, description);
         }
     }
+
+    /// Notify progress listener that a step has completed
+    ///
+    /// Helper method to notify the listener when a configuration step ends,
+    /// so listeners like the deployment report can time it. If no listener
+    /// is provided, this is a no-op.
+    ///
+    /// # Arguments
+    ///
+    /// * `listener` - Optional progress listener
+    /// * `step_number` - The current step number (1-based)
+    /// * `description` - User-facing description of the step
+    fn notify_step_completed(
+        listener: Option<&dyn CommandProgressListener>,
+        step_number: usize,
+        description: &str,
+    ) {
+        if let Some(l) = listener {
+            l.on_step_completed(step_number, description);
+        }
+    }
+
+    /// Report the Ansible tasks run by the step that just finished
+    ///
+    /// Each task is reported as a detail of the current step, e.g.
+    /// `changed: Install docker-ce [torrust-tracker-vm]`. The recorded task
+    /// results are drained even without a listener.
+    fn notify_playbook_tasks(
+        listener: Option<&dyn CommandProgressListener>,
+        ansible_client: &AnsibleClient,
+    ) {
+        let tasks = ansible_client.drain_task_results();
+
+        if let Some(l) = listener {
+            for task in &tasks {
+                l.on_detail(&task.to_string());
+            }
+        }
+    }
+
+    /// Output logs of the configure run started at `started_at`
+    pub(super) fn run_log(
+        &self,
+        environment: &Environment<Configuring>,
+        started_at: chrono::DateTime<chrono::Utc>,
+    ) -> RunLog {
+        RunLog::new(&environment.run_logs_dir(), "configure", started_at)
+            .keeping(self.kept_run_logs)
+    }
+
+    /// Command timeouts for a run starting now, capped by the overall deadline
+    pub(super) fn run_timeouts(&self) -> CommandTimeouts {
+        self.command_timeouts
+            .clone()
+            .with_deadline_after(self.deadline)
+    }
+}
+
+/// Render the Ansible templates with `service`, from synchronous code
+///
+/// The configure workflow is synchronous; the templates are rendered on a
+/// runtime of their own, on another thread, so it also works when called
+/// from async code.
+///
+/// # Errors
+///
+/// Returns `AnsibleTemplateRenderingServiceError` if the templates cannot be
+/// rendered or the runtime cannot be started.
+pub(super) fn render_ansible_templates(
+    service: &AnsibleTemplateRenderingService,
+    user_inputs: &UserInputs,
+    instance_ip: IpAddr,
+    ssh_port: Option<u16>,
+) -> Result<(), AnsibleTemplateRenderingServiceError> {
+    std::thread::scope(|scope| {
+        scope
+            .spawn(|| {
+                tokio::runtime::Builder::new_current_thread()
+                    .enable_all()
+                    .build()
+                    .map_err(|e| AnsibleTemplateRenderingServiceError::RenderingFailed {
+                        reason: format!("cannot start the rendering runtime: {e}"),
+                    })?
+                    .block_on(service.render_templates(user_inputs, instance_ip, ssh_port))
+            })
+            .join()
+            .unwrap_or_else(|_| {
+                Err(AnsibleTemplateRenderingServiceError::RenderingFailed {
+                    reason: "template rendering panicked".to_string(),
+                })
+            })
+    })
 }
@@ -36,9 +36,16 @@
 //! - Transitions to `Environment<ConfigureFailed>` on error
 //!
 //! State is persisted after each transition using the injected repository.
+//!
+//! ## Out-of-Band Runs
+//!
+//! `execute_out_of_band` runs the playbooks against another host than the
+//! instance (`configure --target-host`), for developing Ansible roles. It
+//! skips the state checks and never persists anything.
 
 pub mod errors;
 pub mod handler;
+mod out_of_band;
 
 #[cfg(test)]
 mod tests;
@@ -0,0 +1,114 @@
+//! Out-of-band configure runs (`configure --target-host`)
+//!
+//! A developer mode running the configuration playbooks of an environment
+//! against another host than its instance, e.g. a throwaway local container
+//! with an SSH server, to develop Ansible roles without provisioning
+//! anything. The environment may be in any state; its recorded state is
+//! neither checked nor changed.
+//!
+//! The inventory and variables are rendered to `build/{env}/out-of-band/`,
+//! so the files of the instance in `build/{env}/ansible/` are left as they
+//! are. The target host key is not checked, as the target is expected to be
+//! recreated often.
+
+use std::net::SocketAddr;
+use std::sync::Arc;
+
+use tracing::{info, instrument};
+
+use super::errors::ConfigureCommandHandlerError;
+use super::handler::{render_ansible_templates, ConfigureCommandHandler};
+use crate::adapters::ansible::AnsibleClient;
+use crate::adapters::ssh::SshMultiplexer;
+use crate::application::command_handlers::common::prune_run_logs;
+use crate::application::services::rendering::AnsibleTemplateRenderingService;
+use crate::application::traits::CommandProgressListener;
+use crate::domain::environment::{ANSIBLE_DIR_NAME, OUT_OF_BAND_DIR_NAME, SSH_DIR_NAME};
+use crate::domain::EnvironmentName;
+
+impl ConfigureCommandHandler {
+    /// Run the configuration playbooks of an environment against `target`
+    ///
+    /// The playbooks connect to `target` with the SSH credentials of the
+    /// environment. Custom steps are not run, the configuration override is
+    /// not applied, and nothing is persisted, whatever the outcome.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the environment cannot be loaded, the templates
+    /// cannot be rendered, or a playbook fails.
+    #[instrument(
+        name = "command.configure_out_of_band",
+        skip_all,
+        fields(
+            command_type = "configure",
+            environment_name = %env_name,
+            target = %target
+        )
+    )]
+    pub fn execute_out_of_band(
+        &self,
+        env_name: &EnvironmentName,
+        target: SocketAddr,
+        listener: Option<&dyn CommandProgressListener>,
+    ) -> Result<(), ConfigureCommandHandlerError> {
+        let any_env = self
+            .repository
+            .inner()
+            .load(env_name)
+            .map_err(|e| ConfigureCommandHandlerError::StatePersistence(e.into()))?
+            .ok_or_else(|| ConfigureCommandHandlerError::EnvironmentNotFound {
+                name: env_name.to_string(),
+            })?;
+
+        // Only used in memory to run the steps; never persisted
+        let environment = any_env.start_configuring_out_of_band();
+        let build_dir = environment.build_dir().join(OUT_OF_BAND_DIR_NAME);
+
+        let service = AnsibleTemplateRenderingService::from_paths(
+            environment.templates_dir(),
+            environment.templates_override_dir(),
+            environment.extra_template_variables().clone(),
+            build_dir.clone(),
+            self.clock.clone(),
+        );
+        render_ansible_templates(
+            &service,
+            &environment.context().user_inputs,
+            target.ip(),
+            Some(target.port()),
+        )?;
+
+        let run_log = self.run_log(&environment, self.clock.now());
+        let ansible_client = Arc::new(
+            AnsibleClient::new(build_dir.join(ANSIBLE_DIR_NAME))
+                .with_options(self.ansible_options.clone().with_check(false))
+                .with_timeouts(self.run_timeouts())
+                .with_retry_policy(self.ssh_retry_policy)
+                .with_run_log(run_log.clone())
+                .with_env_vars(environment.context().user_inputs.proxy_env_vars()),
+        );
+
+        // The rendered `ansible.cfg` keeps its control sockets next to the
+        // inventory; they are closed when the workflow returns
+        let _multiplexer = SshMultiplexer::new(build_dir.join(SSH_DIR_NAME));
+
+        let result =
+            self.execute_configuration_with_tracking(&environment, &ansible_client, listener);
+        prune_run_logs(&run_log);
+
+        if self.cancellation.is_cancelled() {
+            return Err(ConfigureCommandHandlerError::Interrupted);
+        }
+        result.map_err(|(e, _)| e)?;
+
+        info!(
+            command = "configure",
+            environment = %env_name,
+            target = %target,
+            "Out-of-band configuration completed; the environment state was not changed"
+        );
+
+        Ok(())
+    }
+}
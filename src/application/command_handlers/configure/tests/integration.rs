@@ -5,6 +5,7 @@
 use std::sync::Arc;
 
 use super::builders::ConfigureCommandHandlerTestBuilder;
+use crate::adapters::ansible::AnsiblePlaybookOptions;
 use crate::application::command_handlers::configure::ConfigureCommandHandlerError;
 use crate::shared::command::CommandError;
 
@@ -27,3 +28,46 @@ fn it_should_have_correct_error_type_conversions() {
     let configure_error: ConfigureCommandHandlerError = command_error.into();
     drop(configure_error);
 }
+
+#[test]
+fn it_should_fail_the_check_when_the_environment_does_not_exist() {
+    let (command_handler, _temp_dir) = ConfigureCommandHandlerTestBuilder::new().build();
+    let env_name = crate::domain::EnvironmentName::new("missing-env".to_string()).unwrap();
+
+    let result = command_handler.check(&env_name, None);
+
+    assert!(matches!(
+        result,
+        Err(ConfigureCommandHandlerError::EnvironmentNotFound { name }) if name == "missing-env"
+    ));
+}
+
+#[test]
+fn it_should_fail_an_out_of_band_run_when_the_environment_does_not_exist() {
+    let (command_handler, _temp_dir) = ConfigureCommandHandlerTestBuilder::new().build();
+    let env_name = crate::domain::EnvironmentName::new("missing-env".to_string()).unwrap();
+    let target = "127.0.0.1:2222".parse().unwrap();
+
+    let result = command_handler.execute_out_of_band(&env_name, target, None);
+
+    assert!(matches!(
+        result,
+        Err(ConfigureCommandHandlerError::EnvironmentNotFound { name }) if name == "missing-env"
+    ));
+}
+
+#[test]
+fn it_should_fail_a_tag_filtered_run_when_the_environment_does_not_exist() {
+    let (command_handler, _temp_dir) = ConfigureCommandHandlerTestBuilder::new().build();
+    let command_handler = command_handler.with_ansible_options(
+        AnsiblePlaybookOptions::default().with_tags(vec!["docker".to_string()]),
+    );
+    let env_name = crate::domain::EnvironmentName::new("missing-env".to_string()).unwrap();
+
+    let result = command_handler.execute_tagged(&env_name, None);
+
+    assert!(matches!(
+        result,
+        Err(ConfigureCommandHandlerError::EnvironmentNotFound { name }) if name == "missing-env"
+    ));
+}
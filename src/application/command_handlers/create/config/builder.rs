@@ -3,15 +3,26 @@
 //! Provides a type-safe, ergonomic way to construct deployment configurations
 //! without hand-crafting JSON strings.
 
+use std::collections::BTreeMap;
+use std::net::{AddrParseError, SocketAddr};
+
 use thiserror::Error;
 
 use super::environment_config::{EnvironmentCreationConfig, EnvironmentSection};
-use super::provider::{HetznerProviderSection, LxdProviderSection, ProviderSection};
+use super::errors::CreateConfigError;
+use super::provider::{
+    DigitalOceanProviderSection, DockerProviderSection, HetznerProviderSection,
+    LibvirtProviderSection, LxdProviderSection, ProviderSection,
+};
+use super::provision::ProvisionSection;
+use super::secret::SecretSource;
 use super::ssh_credentials_config::SshCredentialsConfig;
 use super::tracker::{
     DatabaseSection, HealthCheckApiSection, HttpApiSection, HttpTrackerSection, TrackerCoreSection,
     TrackerSection, UdpTrackerSection,
 };
+use super::validation::{list_issues, ConfigValidationIssue};
+use crate::domain::tracker::TrackerConfig;
 
 /// Default health-check API bind address used when none is set.
 const DEFAULT_HEALTH_CHECK_BIND: &str = "127.0.0.1:1313";
@@ -25,19 +36,19 @@ pub enum EnvironmentCreationConfigBuildError {
 
     /// No SSH private key path was set.
     #[error(
-        "missing required field: SSH private key path — call .ssh_keys(private_path, public_path)"
+        "missing required field: SSH private key path — call .ssh_keys(private_path, public_path) or .generate_ssh_keys()"
     )]
     MissingPrivateKey,
 
     /// No SSH public key path was set.
     #[error(
-        "missing required field: SSH public key path — call .ssh_keys(private_path, public_path)"
+        "missing required field: SSH public key path — call .ssh_keys(private_path, public_path) or .generate_ssh_keys()"
     )]
     MissingPublicKey,
 
     /// No provider was set.
     #[error(
-        "missing required field: provider — call .provider_lxd(profile) or .provider_hetzner(…)"
+        "missing required field: provider — call .provider_lxd(profile), .provider_hetzner(…), .provider_digitalocean(…) or .provider_docker(image)"
     )]
     MissingProvider,
 
@@ -48,6 +59,30 @@ pub enum EnvironmentCreationConfigBuildError {
     /// No HTTP API was set.
     #[error("missing required field: HTTP API — call .api(bind_address, admin_token)")]
     MissingApi,
+
+    /// A bind address is not a valid `IP:PORT` socket address.
+    #[error("invalid socket address '{address}' — expected IP:PORT, e.g. \"0.0.0.0:6969\"")]
+    InvalidSocketAddress {
+        /// The rejected bind address.
+        address: String,
+
+        /// The underlying parse error.
+        #[source]
+        source: AddrParseError,
+    },
+
+    /// The tracker section failed domain validation (e.g. two listeners on
+    /// the same socket address, or a TLS proxy without a domain).
+    #[error("invalid tracker configuration: {0}")]
+    InvalidTracker(#[source] CreateConfigError),
+
+    /// Several fields are missing or invalid; each one is listed with the
+    /// JSON path it has in the built configuration.
+    #[error("{} problems in the configuration:\n{}", .issues.len(), list_issues(.issues))]
+    InvalidConfiguration {
+        /// Every missing or invalid field.
+        issues: Vec<ConfigValidationIssue>,
+    },
 }
 
 /// Fluent builder for [`EnvironmentCreationConfig`].
@@ -61,7 +96,7 @@ pub enum EnvironmentCreationConfigBuildError {
 /// |--------|------|
 /// | [`name`](Self::name) | environment name |
 /// | [`ssh_keys`](Self::ssh_keys) | private & public key paths |
-/// | [`provider_lxd`](Self::provider_lxd) / [`provider_hetzner`](Self::provider_hetzner) | VM provider |
+/// | [`provider_lxd`](Self::provider_lxd) / [`provider_hetzner`](Self::provider_hetzner) / [`provider_digitalocean`](Self::provider_digitalocean) / [`provider_docker`](Self::provider_docker) | VM provider |
 /// | [`sqlite`](Self::sqlite) / [`mysql`](Self::mysql) | tracker database |
 /// | [`api`](Self::api) | HTTP management API |
 ///
@@ -69,12 +104,16 @@ pub enum EnvironmentCreationConfigBuildError {
 ///
 /// | Method | Default |
 /// |--------|---------|
-/// | [`udp`](Self::udp) | none (call once per listener) |
-/// | [`http`](Self::http) | none (call once per listener) |
+/// | [`add_udp_tracker`](Self::add_udp_tracker) / [`udp`](Self::udp) | none (call once per listener) |
+/// | [`add_http_tracker`](Self::add_http_tracker) / [`http`](Self::http) | none (call once per listener) |
 /// | [`ssh_username`](Self::ssh_username) | `"torrust"` |
 /// | [`ssh_port`](Self::ssh_port) | `22` |
 /// | [`private`](Self::private) | `false` (public tracker) |
 /// | [`health_check`](Self::health_check) | `"127.0.0.1:1313"` |
+/// | [`templates_override_dir`](Self::templates_override_dir) | none (embedded templates only) |
+/// | [`extra_template_var`](Self::extra_template_var) | none (call once per variable) |
+/// | [`cloud_init_timeout_secs`](Self::cloud_init_timeout_secs) | `300` |
+/// | [`label`](Self::label) | none (call once per label) |
 ///
 /// # Example
 ///
@@ -86,17 +125,19 @@ pub enum EnvironmentCreationConfigBuildError {
 ///     .ssh_keys("/path/to/key", "/path/to/key.pub")
 ///     .provider_lxd("torrust-profile")
 ///     .sqlite("tracker.db")
-///     .udp("0.0.0.0:6969")
-///     .http("0.0.0.0:7070")
+///     .add_udp_tracker("0.0.0.0:6969")
+///     .add_udp_tracker("0.0.0.0:6868")
+///     .add_http_tracker("0.0.0.0:7070", None, false)
 ///     .api("0.0.0.0:1212", "MyToken")
 ///     .build()
 ///     .expect("Failed to build configuration");
 /// ```
-#[derive(Debug, Default)]
+#[derive(Debug, Default, Clone)]
 pub struct EnvironmentCreationConfigBuilder {
     name: Option<String>,
     ssh_private_key: Option<String>,
     ssh_public_key: Option<String>,
+    generate_ssh_keys: bool,
     ssh_username: Option<String>,
     ssh_port: Option<u16>,
     provider: Option<ProviderSection>,
@@ -105,8 +146,13 @@ pub struct EnvironmentCreationConfigBuilder {
     udp_trackers: Vec<UdpTrackerSection>,
     http_trackers: Vec<HttpTrackerSection>,
     api_bind_address: Option<String>,
-    api_admin_token: Option<String>,
+    api_admin_token: Option<SecretSource>,
     health_check_bind_address: Option<String>,
+    tracker_version: Option<String>,
+    templates_override_dir: Option<String>,
+    extra_template_vars: BTreeMap<String, serde_json::Value>,
+    cloud_init_timeout_secs: Option<u64>,
+    labels: BTreeMap<String, String>,
 }
 
 impl EnvironmentCreationConfigBuilder {
@@ -125,7 +171,7 @@ impl EnvironmentCreationConfigBuilder {
         self
     }
 
-    /// Set the SSH key pair paths (required).
+    /// Set the SSH key pair paths (required unless `generate_ssh_keys` is called).
     #[must_use]
     pub fn ssh_keys(
         mut self,
@@ -137,6 +183,13 @@ impl EnvironmentCreationConfigBuilder {
         self
     }
 
+    /// Generate a new SSH key pair in `data/{env}/ssh/` when the environment is created.
+    #[must_use]
+    pub fn generate_ssh_keys(mut self) -> Self {
+        self.generate_ssh_keys = true;
+        self
+    }
+
     /// Override the SSH username (optional, default: `"torrust"`).
     #[must_use]
     pub fn ssh_username(mut self, username: impl Into<String>) -> Self {
@@ -151,20 +204,21 @@ impl EnvironmentCreationConfigBuilder {
         self
     }
 
-    /// Use the LXD provider (required unless `provider_hetzner` is called).
+    /// Use the LXD provider (required unless another provider is chosen).
     #[must_use]
     pub fn provider_lxd(mut self, profile_name: impl Into<String>) -> Self {
         self.provider = Some(ProviderSection::Lxd(LxdProviderSection {
-            profile_name: profile_name.into(),
+            profile_name: Some(profile_name.into()),
+            remote: None,
         }));
         self
     }
 
-    /// Use the Hetzner provider (required unless `provider_lxd` is called).
+    /// Use the Hetzner provider (required unless another provider is chosen).
     #[must_use]
     pub fn provider_hetzner(
         mut self,
-        api_token: impl Into<String>,
+        api_token: impl Into<SecretSource>,
         server_type: impl Into<String>,
         location: impl Into<String>,
         image: impl Into<String>,
@@ -178,6 +232,54 @@ impl EnvironmentCreationConfigBuilder {
         self
     }
 
+    /// Use the `DigitalOcean` provider (required unless another provider is chosen).
+    ///
+    /// `reserved_ip` is an existing reserved IPv4 address to assign to the droplet.
+    #[must_use]
+    pub fn provider_digitalocean(
+        mut self,
+        api_token: impl Into<SecretSource>,
+        droplet_size: impl Into<String>,
+        region: impl Into<String>,
+        image: impl Into<String>,
+        reserved_ip: Option<String>,
+    ) -> Self {
+        self.provider = Some(ProviderSection::DigitalOcean(DigitalOceanProviderSection {
+            api_token: api_token.into(),
+            droplet_size: droplet_size.into(),
+            region: region.into(),
+            image: image.into(),
+            reserved_ip,
+        }));
+        self
+    }
+
+    /// Use the Docker provider (testing only) with a local instance image.
+    #[must_use]
+    pub fn provider_docker(mut self, image: impl Into<String>) -> Self {
+        self.provider = Some(ProviderSection::Docker(DockerProviderSection {
+            image: image.into(),
+        }));
+        self
+    }
+
+    /// Use the libvirt provider with a storage pool, a network and a cloud
+    /// image (absolute path or HTTP(S) URL).
+    #[must_use]
+    pub fn provider_libvirt(
+        mut self,
+        pool: impl Into<String>,
+        network_name: impl Into<String>,
+        image: impl Into<String>,
+    ) -> Self {
+        self.provider = Some(ProviderSection::Libvirt(LibvirtProviderSection {
+            pool: Some(pool.into()),
+            network_name: Some(network_name.into()),
+            image: Some(image.into()),
+        }));
+        self
+    }
+
     /// Use `SQLite` as the tracker database (required unless `mysql` is called).
     #[must_use]
     pub fn sqlite(mut self, database_name: impl Into<String>) -> Self {
@@ -195,7 +297,7 @@ impl EnvironmentCreationConfigBuilder {
         port: u16,
         database_name: impl Into<String>,
         username: impl Into<String>,
-        password: impl Into<String>,
+        password: impl Into<SecretSource>,
     ) -> Self {
         self.database = Some(DatabaseSection::Mysql {
             host: host.into(),
@@ -204,6 +306,8 @@ impl EnvironmentCreationConfigBuilder {
             username: username.into(),
             password: password.into(),
             root_password: None,
+            min_password_length: None,
+            expose_publicly: false,
         });
         self
     }
@@ -215,9 +319,13 @@ impl EnvironmentCreationConfigBuilder {
         self
     }
 
-    /// Add a UDP tracker listener (optional, repeatable).
+    /// Add a UDP tracker instance (optional, repeatable).
+    ///
+    /// Each call adds one more entry to `udp_trackers`. Bind addresses are
+    /// validated by [`build`](Self::build), which rejects two listeners on the
+    /// same socket address.
     #[must_use]
-    pub fn udp(mut self, bind_address: impl Into<String>) -> Self {
+    pub fn add_udp_tracker(mut self, bind_address: impl Into<String>) -> Self {
         self.udp_trackers.push(UdpTrackerSection {
             bind_address: bind_address.into(),
             domain: None,
@@ -225,20 +333,50 @@ impl EnvironmentCreationConfigBuilder {
         self
     }
 
-    /// Add an HTTP tracker listener (optional, repeatable).
+    /// Add an HTTP tracker instance (optional, repeatable).
+    ///
+    /// Each call adds one more entry to `http_trackers`. When `use_tls_proxy`
+    /// is `true` the tracker is served through the Caddy TLS proxy and
+    /// `domain` is required.
     #[must_use]
-    pub fn http(mut self, bind_address: impl Into<String>) -> Self {
+    pub fn add_http_tracker(
+        mut self,
+        bind_address: impl Into<String>,
+        domain: Option<&str>,
+        use_tls_proxy: bool,
+    ) -> Self {
         self.http_trackers.push(HttpTrackerSection {
             bind_address: bind_address.into(),
-            domain: None,
-            use_tls_proxy: None,
+            domain: domain.map(str::to_string),
+            use_tls_proxy: use_tls_proxy.then_some(true),
         });
         self
     }
 
+    /// Add a UDP tracker listener (optional, repeatable).
+    ///
+    /// Shorthand for [`add_udp_tracker`](Self::add_udp_tracker).
+    #[must_use]
+    pub fn udp(self, bind_address: impl Into<String>) -> Self {
+        self.add_udp_tracker(bind_address)
+    }
+
+    /// Add an HTTP tracker listener (optional, repeatable).
+    ///
+    /// Shorthand for [`add_http_tracker`](Self::add_http_tracker) without
+    /// domain or TLS proxy.
+    #[must_use]
+    pub fn http(self, bind_address: impl Into<String>) -> Self {
+        self.add_http_tracker(bind_address, None, false)
+    }
+
     /// Set the HTTP management API bind address and admin token (required).
     #[must_use]
-    pub fn api(mut self, bind_address: impl Into<String>, admin_token: impl Into<String>) -> Self {
+    pub fn api(
+        mut self,
+        bind_address: impl Into<String>,
+        admin_token: impl Into<SecretSource>,
+    ) -> Self {
         self.api_bind_address = Some(bind_address.into());
         self.api_admin_token = Some(admin_token.into());
         self
@@ -251,21 +389,82 @@ impl EnvironmentCreationConfigBuilder {
         self
     }
 
+    /// Set the tracker image tag or `sha256:` digest to deploy (optional, default: pinned tag).
+    #[must_use]
+    pub fn tracker_version(mut self, version: impl Into<String>) -> Self {
+        self.tracker_version = Some(version.into());
+        self
+    }
+
+    /// Set a directory with template overrides (optional).
+    ///
+    /// Files at `{dir}/{relative_path}` (e.g. `tofu/common/cloud-init.yml.tera`)
+    /// replace the embedded templates with the same relative path.
+    #[must_use]
+    pub fn templates_override_dir(mut self, dir: impl Into<String>) -> Self {
+        self.templates_override_dir = Some(dir.into());
+        self
+    }
+
+    /// Add a variable for the templates (optional, e.g. `"motd"`, `"hello"`).
+    ///
+    /// The variable is added to the context of every rendered template.
+    /// Setting the same name twice keeps the last value. Names are validated
+    /// when the configuration is converted to domain types.
+    #[must_use]
+    pub fn extra_template_var(
+        mut self,
+        name: impl Into<String>,
+        value: impl Into<serde_json::Value>,
+    ) -> Self {
+        self.extra_template_vars.insert(name.into(), value.into());
+        self
+    }
+
+    /// Set how long provisioning waits for cloud-init, in seconds (optional, default: `300`).
+    #[must_use]
+    pub fn cloud_init_timeout_secs(mut self, secs: u64) -> Self {
+        self.cloud_init_timeout_secs = Some(secs);
+        self
+    }
+
+    /// Add a label for organizing environments (optional, e.g. `"team"`, `"infra"`).
+    ///
+    /// Setting the same key twice keeps the last value. Keys and values are
+    /// validated when the configuration is converted to domain types.
+    #[must_use]
+    pub fn label(mut self, key: impl Into<String>, value: impl Into<String>) -> Self {
+        self.labels.insert(key.into(), value.into());
+        self
+    }
+
     /// Build the [`EnvironmentCreationConfig`].
     ///
     /// # Errors
     ///
-    /// Returns [`EnvironmentCreationConfigBuildError`] when any required field is missing.
+    /// Returns [`EnvironmentCreationConfigBuildError`] when any required field is
+    /// missing, a bind address is not a valid socket address, or the tracker
+    /// listeners fail domain validation (e.g. socket address conflicts).
     pub fn build(self) -> Result<EnvironmentCreationConfig, EnvironmentCreationConfigBuildError> {
+        self.check_fields()?;
+
         let name = self
             .name
             .ok_or(EnvironmentCreationConfigBuildError::MissingName)?;
-        let private_key_path = self
-            .ssh_private_key
-            .ok_or(EnvironmentCreationConfigBuildError::MissingPrivateKey)?;
-        let public_key_path = self
-            .ssh_public_key
-            .ok_or(EnvironmentCreationConfigBuildError::MissingPublicKey)?;
+        // Generated keys get their paths when the environment is created
+        let (private_key_path, public_key_path) = if self.generate_ssh_keys {
+            (
+                self.ssh_private_key.unwrap_or_default(),
+                self.ssh_public_key.unwrap_or_default(),
+            )
+        } else {
+            (
+                self.ssh_private_key
+                    .ok_or(EnvironmentCreationConfigBuildError::MissingPrivateKey)?,
+                self.ssh_public_key
+                    .ok_or(EnvironmentCreationConfigBuildError::MissingPublicKey)?,
+            )
+        };
         let provider = self
             .provider
             .ok_or(EnvironmentCreationConfigBuildError::MissingProvider)?;
@@ -305,9 +504,15 @@ impl EnvironmentCreationConfigBuilder {
                     .unwrap_or_else(|| DEFAULT_HEALTH_CHECK_BIND.to_string()),
                 domain: None,
                 use_tls_proxy: None,
+                wait: None,
             },
+            version: self.tracker_version,
+            overrides: None,
+            replicas: None,
         };
 
+        validate_tracker(&tracker)?;
+
         Ok(EnvironmentCreationConfig {
             environment: EnvironmentSection {
                 name,
@@ -315,12 +520,132 @@ impl EnvironmentCreationConfigBuilder {
                 instance_name: None,
             },
             ssh_credentials,
+            generate_ssh_keys: self.generate_ssh_keys,
             provider,
             tracker,
             prometheus: None,
             grafana: None,
             https: None,
             backup: None,
+            templates_override_dir: self.templates_override_dir,
+            extra_template_vars: self.extra_template_vars,
+            provision: self.cloud_init_timeout_secs.map(|cloud_init_timeout_secs| {
+                ProvisionSection {
+                    cloud_init_timeout_secs,
+                    ip_preference: None,
+                }
+            }),
+            firewall: None,
+            system_tuning: None,
+            cloud_init: None,
+            instance: None,
+            offline: None,
+            proxy: None,
+            release: None,
+            labels: self.labels,
+            traces: None,
         })
     }
 }
+
+impl EnvironmentCreationConfigBuilder {
+    /// Check that every required field is set and every bind address parses.
+    ///
+    /// All problems are reported at once: a single one as its own error,
+    /// several as [`EnvironmentCreationConfigBuildError::InvalidConfiguration`].
+    fn check_fields(&self) -> Result<(), EnvironmentCreationConfigBuildError> {
+        let mut problems: Vec<(String, EnvironmentCreationConfigBuildError)> = Vec::new();
+
+        if self.name.is_none() {
+            problems.push((
+                "environment.name".to_string(),
+                EnvironmentCreationConfigBuildError::MissingName,
+            ));
+        }
+        if !self.generate_ssh_keys && self.ssh_private_key.is_none() {
+            problems.push((
+                "ssh_credentials.private_key_path".to_string(),
+                EnvironmentCreationConfigBuildError::MissingPrivateKey,
+            ));
+        }
+        if !self.generate_ssh_keys && self.ssh_public_key.is_none() {
+            problems.push((
+                "ssh_credentials.public_key_path".to_string(),
+                EnvironmentCreationConfigBuildError::MissingPublicKey,
+            ));
+        }
+        if self.provider.is_none() {
+            problems.push((
+                "provider".to_string(),
+                EnvironmentCreationConfigBuildError::MissingProvider,
+            ));
+        }
+        if self.database.is_none() {
+            problems.push((
+                "tracker.core.database".to_string(),
+                EnvironmentCreationConfigBuildError::MissingDatabase,
+            ));
+        }
+        if self.api_bind_address.is_none() || self.api_admin_token.is_none() {
+            problems.push((
+                "tracker.http_api".to_string(),
+                EnvironmentCreationConfigBuildError::MissingApi,
+            ));
+        }
+
+        // Malformed bind addresses get a dedicated error
+        let bind_addresses = self
+            .udp_trackers
+            .iter()
+            .enumerate()
+            .map(|(index, udp)| (format!("tracker.udp_trackers[{index}]"), &udp.bind_address))
+            .chain(self.http_trackers.iter().enumerate().map(|(index, http)| {
+                (
+                    format!("tracker.http_trackers[{index}]"),
+                    &http.bind_address,
+                )
+            }))
+            .chain(
+                self.api_bind_address
+                    .iter()
+                    .map(|address| ("tracker.http_api".to_string(), address)),
+            )
+            .chain(
+                self.health_check_bind_address
+                    .iter()
+                    .map(|address| ("tracker.health_check_api".to_string(), address)),
+            );
+        for (path, address) in bind_addresses {
+            if let Err(source) = address.parse::<SocketAddr>() {
+                problems.push((
+                    format!("{path}.bind_address"),
+                    EnvironmentCreationConfigBuildError::InvalidSocketAddress {
+                        address: address.clone(),
+                        source,
+                    },
+                ));
+            }
+        }
+
+        if problems.len() > 1 {
+            return Err(EnvironmentCreationConfigBuildError::InvalidConfiguration {
+                issues: problems
+                    .into_iter()
+                    .map(|(path, error)| ConfigValidationIssue::error(path, error.to_string()))
+                    .collect(),
+            });
+        }
+        problems.pop().map_or(Ok(()), |(_, error)| Err(error))
+    }
+}
+
+/// Validate the tracker section the same way the JSON path does.
+///
+/// Bind addresses were parsed by `check_fields`; the section is converted
+/// with [`TrackerConfig::try_from`], which runs the socket-conflict checks of
+/// `TrackerConfig::new`.
+fn validate_tracker(tracker: &TrackerSection) -> Result<(), EnvironmentCreationConfigBuildError> {
+    TrackerConfig::try_from(tracker.clone())
+        .map(|_| ())
+        .map_err(EnvironmentCreationConfigBuildError::InvalidTracker)
+}
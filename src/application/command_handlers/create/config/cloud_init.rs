@@ -0,0 +1,98 @@
+//! Cloud-init Configuration DTO (Application Layer)
+//!
+//! This module contains the DTO type for the user additions to the cloud-init
+//! configuration of new instances. It uses raw primitives (String) for JSON
+//! deserialization and converts to the domain type (`CloudInitCustomization`).
+//!
+//! # Conversion Pattern
+//!
+//! Uses `TryFrom` for idiomatic Rust conversion from DTO to domain type.
+//! See ADR: `docs/decisions/tryfrom-for-dto-to-domain-conversion.md`
+
+use std::convert::TryFrom;
+
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+use crate::application::command_handlers::create::config::errors::CreateConfigError;
+use crate::domain::provision::CloudInitCustomization;
+
+/// Cloud-init configuration section (DTO)
+///
+/// Additions merged into the cloud-init configuration the deployer generates
+/// for new instances. They apply on the first boot only.
+///
+/// # Examples
+///
+/// ```json
+/// {
+///     "extra_packages": ["htop"],
+///     "extra_runcmd": ["echo 'Managed by Torrust' > /etc/motd"],
+///     "raw_append": "timezone: Europe/Madrid"
+/// }
+/// ```
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize, JsonSchema)]
+pub struct CloudInitSection {
+    /// Packages installed on the first boot, e.g. `htop` or `htop=3.3.0-4`
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub extra_packages: Vec<String>,
+
+    /// Shell commands run on the first boot, before the deployer connects
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub extra_runcmd: Vec<String>,
+
+    /// YAML mapping of further cloud-config keys (e.g. `timezone: UTC`)
+    ///
+    /// Lists such as `packages`, `runcmd` and `write_files` are appended to
+    /// the generated ones. Keys creating the SSH user (`users`,
+    /// `ssh_authorized_keys`, ...) are managed by the deployer and rejected.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub raw_append: Option<String>,
+}
+
+impl TryFrom<CloudInitSection> for CloudInitCustomization {
+    type Error = CreateConfigError;
+
+    fn try_from(section: CloudInitSection) -> Result<Self, Self::Error> {
+        CloudInitCustomization::new(
+            section.extra_packages,
+            section.extra_runcmd,
+            section.raw_append,
+        )
+        .map_err(|e| CreateConfigError::InvalidCloudInitConfig(e.to_string()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn it_should_convert_the_additions_to_the_domain_type() {
+        let section: CloudInitSection = serde_json::from_str(
+            r#"{ "extra_packages": ["htop"], "extra_runcmd": ["touch /root/ready"] }"#,
+        )
+        .expect("Valid JSON");
+
+        let customization = CloudInitCustomization::try_from(section).unwrap();
+
+        assert_eq!(customization.extra_packages(), ["htop"]);
+        assert_eq!(customization.extra_runcmd(), ["touch /root/ready"]);
+        assert_eq!(customization.raw_append(), None);
+    }
+
+    #[test]
+    fn it_should_reject_a_raw_append_setting_the_ssh_user() {
+        let section = CloudInitSection {
+            raw_append: Some("users:\n  - name: admin".to_string()),
+            ..CloudInitSection::default()
+        };
+
+        let result = CloudInitCustomization::try_from(section);
+
+        assert!(matches!(
+            result,
+            Err(CreateConfigError::InvalidCloudInitConfig(_))
+        ));
+    }
+}
@@ -4,20 +4,40 @@
 //! all configuration needed to create a deployment environment. It handles
 //! deserialization from configuration sources and conversion to domain types.
 
+use std::collections::BTreeMap;
+
 use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
 
-use crate::domain::provider::Provider;
+use crate::adapters::ssh::key_generator::{public_key_path_of, GENERATED_PRIVATE_KEY_FILE_NAME};
+use crate::domain::environment::WorkspaceLayout;
+use crate::domain::provider::{
+    Provider, DEFAULT_LIBVIRT_IMAGE, DEFAULT_LIBVIRT_NETWORK, DEFAULT_LIBVIRT_POOL,
+};
+use crate::domain::EnvironmentName;
 
 use super::backup::BackupSection;
 use super::builder::EnvironmentCreationConfigBuilder;
+use super::cloud_init::CloudInitSection;
 use super::errors::load_error;
 use super::errors::CreateConfigError;
+use super::firewall::FirewallSection;
+use super::format::ConfigFormat;
 use super::grafana::GrafanaSection;
 use super::https::HttpsSection;
+use super::instance::InstanceSection;
+use super::offline::OfflineSection;
 use super::prometheus::PrometheusSection;
-use super::provider::{HetznerProviderSection, LxdProviderSection, ProviderSection};
+use super::provider::{
+    DigitalOceanProviderSection, DockerProviderSection, HetznerProviderSection,
+    LibvirtProviderSection, LxdProviderSection, ProviderSection,
+};
+use super::provision::ProvisionSection;
+use super::proxy::ProxySection;
+use super::release::ReleaseSection;
 use super::ssh_credentials_config::SshCredentialsConfig;
+use super::system_tuning::SystemTuningSection;
+use super::traces::TracesSection;
 use super::tracker::TrackerSection;
 
 /// Configuration for creating a deployment environment
@@ -95,6 +115,14 @@ pub struct EnvironmentCreationConfig {
     /// SSH credentials configuration
     pub ssh_credentials: SshCredentialsConfig,
 
+    /// Generate a new SSH key pair for the environment (optional, default: `false`)
+    ///
+    /// The passphrase-free Ed25519 key pair is created in `data/{env}/ssh/`
+    /// when the environment is created, so it never exists outside the
+    /// workspace. The key paths in `ssh_credentials` must then be left out.
+    #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+    pub generate_ssh_keys: bool,
+
     /// Provider-specific configuration (LXD, Hetzner, etc.)
     ///
     /// Uses `ProviderSection` for JSON parsing with raw primitives.
@@ -150,6 +178,106 @@ pub struct EnvironmentCreationConfig {
     /// Default retention: 7 days
     #[serde(default)]
     pub backup: Option<BackupSection>,
+
+    /// Directory with template overrides (optional)
+    ///
+    /// Templates found at `{templates_override_dir}/{relative_path}` replace
+    /// the embedded templates with the same relative path, e.g.
+    /// `tofu/common/cloud-init.yml.tera` or `tofu/lxd/main.tf`. Missing files
+    /// fall back to the embedded templates. Overridden Tera templates must
+    /// still use every variable of the embedded template.
+    ///
+    /// The path must be absolute and the directory must exist when the
+    /// environment is created.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub templates_override_dir: Option<String>,
+
+    /// Extra variables for the templates (optional)
+    ///
+    /// Added to the context of every rendered template, so overridden
+    /// templates (see `templates_override_dir`) can reference them, e.g.
+    /// `{{ motd }}` for `"extra_template_vars": { "motd": "hello" }`. Values
+    /// may be any JSON value. Names must be valid Tera identifiers. Variables
+    /// provided by the deployer take precedence over extra variables with the
+    /// same name.
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub extra_template_vars: BTreeMap<String, serde_json::Value>,
+
+    /// Provision workflow settings (optional)
+    ///
+    /// Tunes the provision command, e.g. how long to wait for cloud-init
+    /// on slow hosts. Defaults apply when the section is absent.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub provision: Option<ProvisionSection>,
+
+    /// Host firewall settings (optional)
+    ///
+    /// Adds allow rules on top of SSH and the ports of the enabled services,
+    /// which are always allowed. No extra rules when the section is absent.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub firewall: Option<FirewallSection>,
+
+    /// Host system tuning (optional)
+    ///
+    /// Kernel parameters, resource limits and swap file applied by the
+    /// configure command: a preset name (`default` or `high-udp`) or a preset
+    /// with explicit settings. Nothing is changed when the section is absent.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub system_tuning: Option<SystemTuningSection>,
+
+    /// Cloud-init additions (optional, not supported by Docker)
+    ///
+    /// Extra packages, first-boot commands and cloud-config keys merged into
+    /// the generated cloud-init configuration. None when the section is absent.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub cloud_init: Option<CloudInitSection>,
+
+    /// Instance sizing (optional, LXD only)
+    ///
+    /// Sets the CPU, memory and disk of the instance. Defaults apply when
+    /// the section is absent. Cloud providers size the instance through
+    /// their server type instead and reject this section.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub instance: Option<InstanceSection>,
+
+    /// Offline mode (optional, LXD only)
+    ///
+    /// Pre-seeded image, packages and registry mirror replacing the downloads
+    /// of the deployment, for hosts without Internet access. Everything is
+    /// downloaded from the Internet when the section is absent.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub offline: Option<OfflineSection>,
+
+    /// Outbound HTTP proxy (optional)
+    ///
+    /// Proxy used by `OpenTofu` and Ansible on the deployer machine, and by
+    /// apt and Docker on the instance, for networks that only reach the
+    /// Internet through it. Downloads go direct when the section is absent.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub proxy: Option<ProxySection>,
+
+    /// Container images and private registry (optional)
+    ///
+    /// Replaces the images of the services, e.g. with copies in an internal
+    /// registry pinned by digest, and logs the instance in to that registry.
+    /// The deployer's pinned images are pulled from Docker Hub when absent.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub release: Option<ReleaseSection>,
+
+    /// Labels for organizing environments (optional)
+    ///
+    /// Free-form `key: value` pairs (e.g. `"team": "infra"`) used to filter
+    /// environments in `list --label` and to select the environments of bulk
+    /// commands such as `destroy --label`. They do not affect the deployment.
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub labels: BTreeMap<String, String>,
+
+    /// Trace files of failed commands (optional)
+    ///
+    /// Where the trace files go and how many of them are kept. The last 20
+    /// traces are kept in `data/<env>/traces/` when the section is absent.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub traces: Option<TracesSection>,
 }
 
 /// Environment-specific configuration section
@@ -245,7 +373,8 @@ impl EnvironmentCreationConfig {
     ///         22,
     ///     ),
     ///     ProviderSection::Lxd(LxdProviderSection {
-    ///         profile_name: "torrust-profile-dev".to_string(),
+    ///         profile_name: Some("torrust-profile-dev".to_string()),
+    ///         remote: None,
     ///     }),
     ///     TrackerSection::default(),
     ///     None,
@@ -269,15 +398,71 @@ impl EnvironmentCreationConfig {
         Self {
             environment,
             ssh_credentials,
+            generate_ssh_keys: false,
             provider,
             tracker,
             prometheus,
             grafana,
             https,
             backup,
+            templates_override_dir: None,
+            extra_template_vars: BTreeMap::new(),
+            provision: None,
+            firewall: None,
+            system_tuning: None,
+            cloud_init: None,
+            instance: None,
+            offline: None,
+            proxy: None,
+            release: None,
+            labels: BTreeMap::new(),
+            traces: None,
         }
     }
 
+    /// Sets the directory with template overrides
+    #[must_use]
+    pub fn with_templates_override_dir(mut self, templates_override_dir: Option<String>) -> Self {
+        self.templates_override_dir = templates_override_dir;
+        self
+    }
+
+    /// Fills in the SSH key paths of a generated key pair
+    ///
+    /// With `generate_ssh_keys` the key paths are
+    /// `{data_dir}/{env}/ssh/id_ed25519[.pub]`, made absolute. Without it the
+    /// configuration is returned unchanged. The key pair itself is generated
+    /// by the create command.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the environment name is invalid or the
+    /// configuration also names key files.
+    pub fn with_generated_ssh_key_paths(
+        mut self,
+        layout: &WorkspaceLayout,
+    ) -> Result<Self, CreateConfigError> {
+        if !self.generate_ssh_keys {
+            return Ok(self);
+        }
+
+        if self.ssh_credentials.has_key_paths() {
+            return Err(CreateConfigError::SshKeyPathsWithGeneratedKeys);
+        }
+
+        let name = EnvironmentName::new(self.environment.name.as_str())?;
+        let key_dir = layout.environment_ssh_dir(&name);
+        let key_dir = std::path::absolute(&key_dir).unwrap_or(key_dir);
+        let private_key_path = key_dir.join(GENERATED_PRIVATE_KEY_FILE_NAME);
+
+        self.ssh_credentials.public_key_path = public_key_path_of(&private_key_path)
+            .to_string_lossy()
+            .to_string();
+        self.ssh_credentials.private_key_path = private_key_path.to_string_lossy().to_string();
+
+        Ok(self)
+    }
+
     /// Checks if any service has TLS configured
     ///
     /// Returns `true` if at least one of the following services has TLS:
@@ -343,14 +528,30 @@ impl EnvironmentCreationConfig {
     pub fn template(provider: Provider) -> Self {
         let provider_section = match provider {
             Provider::Lxd => ProviderSection::Lxd(LxdProviderSection {
-                profile_name: "REPLACE_WITH_LXD_PROFILE_NAME".to_string(),
+                profile_name: Some("REPLACE_WITH_LXD_PROFILE_NAME".to_string()),
+                remote: None,
             }),
             Provider::Hetzner => ProviderSection::Hetzner(HetznerProviderSection {
-                api_token: "REPLACE_WITH_HETZNER_API_TOKEN".to_string(),
+                api_token: "REPLACE_WITH_HETZNER_API_TOKEN".into(),
                 server_type: "cx22".to_string(), // default value - small instance
                 location: "nbg1".to_string(),    // default value - Nuremberg
                 image: "ubuntu-24.04".to_string(), // default value - Ubuntu 24.04 LTS
             }),
+            Provider::DigitalOcean => ProviderSection::DigitalOcean(DigitalOceanProviderSection {
+                api_token: "REPLACE_WITH_DIGITALOCEAN_API_TOKEN".into(),
+                droplet_size: "s-1vcpu-2gb".to_string(), // default value - small droplet
+                region: "fra1".to_string(),              // default value - Frankfurt
+                image: "ubuntu-24-04-x64".to_string(),   // default value - Ubuntu 24.04 LTS
+                reserved_ip: None,
+            }),
+            Provider::Docker => ProviderSection::Docker(DockerProviderSection {
+                image: "torrust-provisioned-instance:latest".to_string(), // default value - e2e image
+            }),
+            Provider::Libvirt => ProviderSection::Libvirt(LibvirtProviderSection {
+                pool: Some(DEFAULT_LIBVIRT_POOL.to_string()),
+                network_name: Some(DEFAULT_LIBVIRT_NETWORK.to_string()),
+                image: Some(DEFAULT_LIBVIRT_IMAGE.to_string()),
+            }),
         };
 
         Self {
@@ -365,6 +566,7 @@ impl EnvironmentCreationConfig {
                 username: "torrust".to_string(), // default value
                 port: 22,                        // default value
             },
+            generate_ssh_keys: false,
             provider: provider_section,
             tracker: TrackerSection {
                 core: super::tracker::TrackerCoreSection {
@@ -384,16 +586,31 @@ impl EnvironmentCreationConfig {
                 }],
                 http_api: super::tracker::HttpApiSection {
                     bind_address: "0.0.0.0:1212".to_string(),
-                    admin_token: "MyAccessToken".to_string(),
+                    admin_token: "MyAccessToken".into(),
                     domain: None,
                     use_tls_proxy: None,
                 },
                 health_check_api: super::tracker::HealthCheckApiSection::default(),
+                version: None, // Pinned default tracker image tag,
+                overrides: None,
+                replicas: None,
             },
             prometheus: Some(PrometheusSection::default()),
             grafana: Some(GrafanaSection::default()),
             https: None, // Set to HttpsSection with admin_email for HTTPS deployments
             backup: Some(super::backup::BackupSection::default()), // Backups enabled by default with daily 3 AM schedule and 7-day retention
+            templates_override_dir: None,                          // Embedded templates only
+            extra_template_vars: BTreeMap::new(),                  // No extra template variables
+            provision: None,         // Default cloud-init timeout (300 seconds)
+            firewall: None,          // Only SSH and service ports allowed
+            system_tuning: None,     // Image's own kernel parameters and limits
+            cloud_init: None,        // Generated cloud-init configuration only
+            instance: None,          // Default sizing (2 CPUs, 2048 MiB, 10 GiB)
+            offline: None,           // Downloads from the Internet
+            proxy: None,             // Direct Internet access
+            release: None,           // Deployer's pinned images from Docker Hub
+            labels: BTreeMap::new(), // No labels
+            traces: None,            // Last 20 traces in data/<env>/traces/
         }
     }
 
@@ -510,17 +727,63 @@ impl EnvironmentCreationConfig {
             .map_err(|source| load_error::ConfigLoadError::JsonParseFailed { source })
     }
 
-    /// Load an [`EnvironmentCreationConfig`] from a JSON file.
+    /// Deserialize an [`EnvironmentCreationConfig`] from a YAML string.
+    ///
+    /// The YAML document has the same structure as the JSON configuration.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`load_error::ConfigLoadError::YamlParseFailed`] if the YAML is
+    /// malformed or does not match the expected structure.
+    pub fn from_yaml(yaml: &str) -> Result<Self, load_error::ConfigLoadError> {
+        serde_yaml::from_str(yaml)
+            .map_err(|source| load_error::ConfigLoadError::YamlParseFailed { source })
+    }
+
+    /// Deserialize an [`EnvironmentCreationConfig`] from a TOML string.
+    ///
+    /// Each top-level section of the JSON configuration is a TOML table
+    /// (e.g. `[environment]`, `[tracker.core]`).
+    ///
+    /// # Errors
+    ///
+    /// Returns [`load_error::ConfigLoadError::TomlParseFailed`] if the TOML is
+    /// malformed or does not match the expected structure.
+    pub fn from_toml(toml: &str) -> Result<Self, load_error::ConfigLoadError> {
+        toml::from_str(toml).map_err(|source| load_error::ConfigLoadError::TomlParseFailed {
+            source: Box::new(source),
+        })
+    }
+
+    /// Deserialize an [`EnvironmentCreationConfig`] from a string in `format`.
+    ///
+    /// # Errors
+    ///
+    /// Returns the [`load_error::ConfigLoadError`] parse error of `format` if
+    /// the content is malformed or does not match the expected structure.
+    pub fn parse(content: &str, format: ConfigFormat) -> Result<Self, load_error::ConfigLoadError> {
+        match format {
+            ConfigFormat::Json => Self::from_json(content),
+            ConfigFormat::Yaml => Self::from_yaml(content),
+            ConfigFormat::Toml => Self::from_toml(content),
+        }
+    }
+
+    /// Load an [`EnvironmentCreationConfig`] from a JSON, YAML or TOML file.
     ///
     /// This is a convenience constructor that reads and parses a configuration
     /// file in one step — equivalent to the CLI's `--env-file <path>` flag.
+    /// The format is detected with [`ConfigFormat::detect`]: from the
+    /// extension (`.json`, `.yaml`/`.yml`, `.toml`), or from the content for
+    /// any other extension.
     ///
     /// # Errors
     ///
     /// Returns [`load_error::ConfigLoadError`] if:
     /// - The file does not exist (`FileNotFound`)
     /// - The file cannot be read (`FileReadFailed`)
-    /// - The JSON content is malformed (`JsonParseFailed`)
+    /// - The content is malformed (`JsonParseFailed`, `YamlParseFailed` or
+    ///   `TomlParseFailed`)
     ///
     /// # Examples
     ///
@@ -545,13 +808,14 @@ impl EnvironmentCreationConfig {
             }
         })?;
 
-        Self::from_json(&content)
+        Self::parse(&content, ConfigFormat::detect(path, &content))
     }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::application::command_handlers::create::config::errors::SecretResolutionError;
     use crate::application::command_handlers::create::config::provider::LxdProviderSection;
     use crate::application::command_handlers::create::config::tracker::TrackerSection;
     use crate::domain::environment::EnvironmentParams;
@@ -560,7 +824,8 @@ mod tests {
     /// Helper to create a default LXD provider section for tests
     fn default_lxd_provider(profile_name: &str) -> ProviderSection {
         ProviderSection::Lxd(LxdProviderSection {
-            profile_name: profile_name.to_string(),
+            profile_name: Some(profile_name.to_string()),
+            remote: None,
         })
     }
 
@@ -654,6 +919,94 @@ mod tests {
         assert_eq!(config.provider.provider(), Provider::Lxd);
     }
 
+    #[test]
+    fn it_should_deserialize_from_yaml_into_the_same_structure_as_json() {
+        let yaml = r"
+environment:
+  name: e2e-config
+ssh_credentials:
+  private_key_path: fixtures/testing_rsa
+  public_key_path: fixtures/testing_rsa.pub
+provider:
+  provider: lxd
+  profile_name: torrust-profile-e2e-config
+tracker:
+  core:
+    database:
+      driver: sqlite3
+      database_name: tracker.db
+    private: false
+  udp_trackers:
+    - bind_address: 0.0.0.0:6969
+  http_trackers:
+    - bind_address: 0.0.0.0:7070
+  http_api:
+    bind_address: 0.0.0.0:1212
+    admin_token: MyAccessToken
+  health_check_api:
+    bind_address: 127.0.0.1:1313
+";
+
+        let config = EnvironmentCreationConfig::parse(yaml, ConfigFormat::Yaml).unwrap();
+
+        assert_eq!(config.environment.name, "e2e-config");
+        assert_eq!(config.ssh_credentials.port, 22); // default
+        assert_eq!(config.provider.provider(), Provider::Lxd);
+    }
+
+    #[test]
+    fn it_should_deserialize_from_toml_into_the_same_structure_as_json() {
+        let toml = r#"
+[environment]
+name = "e2e-config"
+
+[ssh_credentials]
+private_key_path = "fixtures/testing_rsa"
+public_key_path = "fixtures/testing_rsa.pub"
+
+[provider]
+provider = "lxd"
+profile_name = "torrust-profile-e2e-config"
+
+[tracker.core]
+database = { driver = "sqlite3", database_name = "tracker.db" }
+private = false
+
+[[tracker.udp_trackers]]
+bind_address = "0.0.0.0:6969"
+
+[[tracker.http_trackers]]
+bind_address = "0.0.0.0:7070"
+
+[tracker.http_api]
+bind_address = "0.0.0.0:1212"
+admin_token = "MyAccessToken"
+
+[tracker.health_check_api]
+bind_address = "127.0.0.1:1313"
+"#;
+
+        let config = EnvironmentCreationConfig::parse(toml, ConfigFormat::Toml).unwrap();
+
+        assert_eq!(config.environment.name, "e2e-config");
+        assert_eq!(config.ssh_credentials.port, 22); // default
+        assert_eq!(config.provider.provider(), Provider::Lxd);
+    }
+
+    #[test]
+    fn it_should_report_the_line_and_column_of_yaml_and_toml_syntax_errors() {
+        let yaml_error =
+            EnvironmentCreationConfig::from_yaml("environment:\n  name: [e2e\n").unwrap_err();
+        let toml_error =
+            EnvironmentCreationConfig::from_toml("[environment]\nname = \"e2e\n").unwrap_err();
+
+        assert!(
+            yaml_error.to_string().contains("line 2 column"),
+            "{yaml_error}"
+        );
+        assert!(toml_error.to_string().contains("line 2"), "{toml_error}");
+    }
+
     #[test]
     fn it_should_deserialize_from_json_when_using_hetzner_provider() {
         let json = r#"{
@@ -896,7 +1249,8 @@ mod tests {
             },
             SshCredentialsConfig::new(private_key_path, public_key_path, "torrust".to_string(), 22),
             ProviderSection::Lxd(LxdProviderSection {
-                profile_name: "invalid-".to_string(), // ends with dash - invalid
+                profile_name: Some("invalid-".to_string()), // ends with dash - invalid,
+                remote: None,
             }),
             TrackerSection::default(),
             None,
@@ -1140,7 +1494,7 @@ mod tests {
 
         // Verify Hetzner-specific fields
         if let ProviderSection::Hetzner(hetzner) = template.provider {
-            assert_eq!(hetzner.api_token, "REPLACE_WITH_HETZNER_API_TOKEN");
+            assert_eq!(hetzner.api_token, "REPLACE_WITH_HETZNER_API_TOKEN".into());
             assert_eq!(hetzner.server_type, "cx22");
             assert_eq!(hetzner.location, "nbg1");
         } else {
@@ -1370,11 +1724,14 @@ mod tests {
             }],
             http_api: HttpApiSection {
                 bind_address: "0.0.0.0:1212".to_string(),
-                admin_token: "MyAccessToken".to_string(),
+                admin_token: "MyAccessToken".into(),
                 domain: Some("api.tracker.example.com".to_string()),
                 use_tls_proxy: Some(true),
             },
             health_check_api: HealthCheckApiSection::default(),
+            version: None,
+            overrides: None,
+            replicas: None,
         };
 
         let config = EnvironmentCreationConfig::new(
@@ -1444,6 +1801,10 @@ mod tests {
 
         // Email validation now happens in domain layer (HttpsConfig::new())
         // This test verifies that valid emails pass through TryInto<EnvironmentParams>
+        let mut tracker = TrackerSection::default();
+        tracker.http_api.domain = Some("api.example.com".to_string());
+        tracker.http_api.use_tls_proxy = Some(true);
+
         let config = EnvironmentCreationConfig::new(
             EnvironmentSection {
                 name: "dev".to_string(),
@@ -1452,7 +1813,7 @@ mod tests {
             },
             SshCredentialsConfig::new(private_key_path, public_key_path, "torrust".to_string(), 22),
             default_lxd_provider("torrust-profile-dev"),
-            TrackerSection::default(),
+            tracker,
             None,
             None,
             Some(HttpsSection {
@@ -1462,8 +1823,7 @@ mod tests {
             None,
         );
 
-        // HTTPS section with valid email should convert successfully
-        // (actual cross-service TLS validation happens in domain layer)
+        // HTTPS section with valid email and a TLS service should convert successfully
         let result: Result<EnvironmentParams, _> = config.try_into();
         assert!(result.is_ok(), "Expected Ok but got: {:?}", result.err());
     }
@@ -1535,11 +1895,14 @@ mod tests {
             }],
             http_api: HttpApiSection {
                 bind_address: "0.0.0.0:1212".to_string(),
-                admin_token: "MyAccessToken".to_string(),
+                admin_token: "MyAccessToken".into(),
                 domain: None,
                 use_tls_proxy: None,
             },
             health_check_api: HealthCheckApiSection::default(),
+            version: None,
+            overrides: None,
+            replicas: None,
         };
 
         let config = EnvironmentCreationConfig::new(
@@ -1566,4 +1929,147 @@ mod tests {
         let result: Result<EnvironmentParams, _> = config.try_into();
         assert!(result.is_ok(), "Expected Ok but got: {:?}", result.err());
     }
+
+    fn config_json_with_admin_token_from_env(variable: &str) -> String {
+        let project_root = std::env::var("CARGO_MANIFEST_DIR").expect("CARGO_MANIFEST_DIR not set");
+
+        format!(
+            r#"{{
+                "environment": {{ "name": "dev" }},
+                "ssh_credentials": {{
+                    "private_key_path": "{project_root}/fixtures/testing_rsa",
+                    "public_key_path": "{project_root}/fixtures/testing_rsa.pub"
+                }},
+                "provider": {{ "provider": "lxd", "profile_name": "torrust-profile-dev" }},
+                "tracker": {{
+                    "core": {{
+                        "database": {{ "driver": "sqlite3", "database_name": "tracker.db" }},
+                        "private": false
+                    }},
+                    "udp_trackers": [{{ "bind_address": "0.0.0.0:6969" }}],
+                    "http_trackers": [{{ "bind_address": "0.0.0.0:7070" }}],
+                    "http_api": {{
+                        "bind_address": "0.0.0.0:1212",
+                        "admin_token": {{ "env": "{variable}" }}
+                    }},
+                    "health_check_api": {{ "bind_address": "127.0.0.1:1313" }}
+                }}
+            }}"#
+        )
+    }
+
+    #[test]
+    fn it_should_resolve_admin_token_from_environment_variable_when_converting() {
+        let variable = "TORRUST_TD_TEST_CONFIG_ADMIN_TOKEN_SET";
+        std::env::set_var(variable, "TokenFromEnv");
+        let config =
+            EnvironmentCreationConfig::from_json(&config_json_with_admin_token_from_env(variable))
+                .unwrap();
+
+        let params: EnvironmentParams = config.try_into().unwrap();
+
+        assert_eq!(
+            params
+                .tracker_config
+                .http_api()
+                .admin_token()
+                .expose_secret(),
+            "TokenFromEnv"
+        );
+    }
+
+    #[test]
+    fn it_should_return_error_naming_the_variable_when_admin_token_variable_is_not_set() {
+        let variable = "TORRUST_TD_TEST_CONFIG_ADMIN_TOKEN_UNSET";
+        let config =
+            EnvironmentCreationConfig::from_json(&config_json_with_admin_token_from_env(variable))
+                .unwrap();
+
+        let result: Result<EnvironmentParams, _> = config.try_into();
+
+        match result.unwrap_err() {
+            CreateConfigError::SecretResolution(SecretResolutionError::EnvVarNotSet {
+                variable: name,
+            }) => assert_eq!(name, variable),
+            other => panic!("Expected EnvVarNotSet error, got: {other:?}"),
+        }
+    }
+
+    #[test]
+    fn it_should_fill_in_the_generated_ssh_key_paths() {
+        let config = EnvironmentCreationConfig::builder()
+            .name("dev")
+            .generate_ssh_keys()
+            .provider_lxd("torrust-profile-dev")
+            .sqlite("tracker.db")
+            .api("0.0.0.0:1212", "MyAccessToken")
+            .build()
+            .unwrap();
+
+        let config = config
+            .with_generated_ssh_key_paths(&WorkspaceLayout::in_working_dir("/workspace"))
+            .unwrap();
+
+        assert_eq!(
+            config.ssh_credentials.private_key_path,
+            "/workspace/data/dev/ssh/id_ed25519"
+        );
+        assert_eq!(
+            config.ssh_credentials.public_key_path,
+            "/workspace/data/dev/ssh/id_ed25519.pub"
+        );
+        assert!(EnvironmentParams::try_from(config).is_ok());
+    }
+
+    #[test]
+    fn it_should_reject_key_paths_together_with_generated_keys() {
+        let mut config = EnvironmentCreationConfig::new(
+            EnvironmentSection {
+                name: "dev".to_string(),
+                description: None,
+                instance_name: None,
+            },
+            SshCredentialsConfig::new(
+                "/keys/id_ed25519".to_string(),
+                "/keys/id_ed25519.pub".to_string(),
+                "torrust".to_string(),
+                22,
+            ),
+            default_lxd_provider("torrust-profile-dev"),
+            TrackerSection::default(),
+            None,
+            None,
+            None,
+            None,
+        );
+        config.generate_ssh_keys = true;
+
+        let result = config.with_generated_ssh_key_paths(&WorkspaceLayout::in_working_dir("/w"));
+
+        assert!(matches!(
+            result,
+            Err(CreateConfigError::SshKeyPathsWithGeneratedKeys)
+        ));
+    }
+
+    #[test]
+    fn it_should_require_key_paths_when_keys_are_not_generated() {
+        let json = r#"{
+            "environment": { "name": "dev" },
+            "ssh_credentials": {},
+            "provider": { "provider": "lxd", "profile_name": "torrust-profile-dev" },
+            "tracker": {
+                "core": { "database": { "driver": "sqlite3", "database_name": "tracker.db" }, "private": false },
+                "udp_trackers": [],
+                "http_trackers": [],
+                "http_api": { "bind_address": "0.0.0.0:1212", "admin_token": "MyAccessToken" },
+                "health_check_api": { "bind_address": "127.0.0.1:1313" }
+            }
+        }"#;
+        let config: EnvironmentCreationConfig = serde_json::from_str(json).unwrap();
+
+        let result: Result<EnvironmentParams, _> = config.try_into();
+
+        assert!(matches!(result, Err(CreateConfigError::MissingSshKeyPaths)));
+    }
 }
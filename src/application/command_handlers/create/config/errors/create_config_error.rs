@@ -7,9 +7,17 @@
 use std::path::PathBuf;
 use thiserror::Error;
 
+use super::SecretResolutionError;
+use crate::application::command_handlers::create::config::validation::{
+    list_issues, ConfigValidationIssue,
+};
+use crate::domain::environment::LabelError;
+use crate::domain::release::ImageReferenceError;
+use crate::domain::template::ExtraTemplateVariableError;
 use crate::domain::tracker::{
-    HealthCheckApiConfigError, HttpApiConfigError, HttpTrackerConfigError, MysqlConfigError,
-    SqliteConfigError, TrackerConfigError, UdpTrackerConfigError,
+    HealthCheckApiConfigError, HealthCheckWaitError, HttpApiConfigError, HttpTrackerConfigError,
+    MysqlConfigError, SqliteConfigError, TrackerConfigError, TrackerOverridesError,
+    TrackerVersionError, UdpTrackerConfigError,
 };
 use crate::domain::EnvironmentNameError;
 use crate::domain::ProfileNameError;
@@ -50,6 +58,14 @@ pub enum CreateConfigError {
     #[error("SSH public key file not found: {path}")]
     PublicKeyNotFound { path: PathBuf },
 
+    /// Templates override directory not found
+    #[error("Templates override directory not found: {path}")]
+    TemplatesOverrideDirNotFound { path: PathBuf },
+
+    /// Templates override directory must be absolute
+    #[error("Templates override directory must be absolute: {path:?}")]
+    RelativeTemplatesOverrideDir { path: PathBuf },
+
     /// SSH private key path must be absolute
     #[error("SSH private key path must be absolute: {path:?}")]
     RelativePrivateKeyPath { path: PathBuf },
@@ -58,6 +74,14 @@ pub enum CreateConfigError {
     #[error("SSH public key path must be absolute: {path:?}")]
     RelativePublicKeyPath { path: PathBuf },
 
+    /// SSH key paths are missing and no key pair is generated
+    #[error("SSH key paths are missing: set ssh_credentials.private_key_path and public_key_path, or generate_ssh_keys")]
+    MissingSshKeyPaths,
+
+    /// SSH key paths are given together with `generate_ssh_keys`
+    #[error("SSH key paths cannot be set when generate_ssh_keys is enabled")]
+    SshKeyPathsWithGeneratedKeys,
+
     /// Invalid SSH port (must be 1-65535)
     #[error("Invalid SSH port: {port} (must be between 1 and 65535)")]
     InvalidPort { port: u16 },
@@ -72,6 +96,24 @@ pub enum CreateConfigError {
         source: std::net::AddrParseError,
     },
 
+    /// Invalid `DigitalOcean` reserved IP
+    #[error("Invalid reserved IP '{address}': failed to parse as an IPv4 address")]
+    InvalidReservedIp {
+        /// The invalid reserved IP that was provided
+        address: String,
+        /// The underlying parse error
+        #[source]
+        source: std::net::AddrParseError,
+    },
+
+    /// Invalid LXD remote
+    #[error("Invalid LXD remote: {0}")]
+    InvalidLxdRemote(String),
+
+    /// Invalid libvirt provider settings
+    #[error("Invalid libvirt provider configuration: {0}")]
+    InvalidLibvirtConfig(String),
+
     /// Dynamic port assignment (port 0) is not supported
     #[error("Dynamic port assignment (port 0) is not supported in bind address '{bind_address}'")]
     DynamicPortNotSupported {
@@ -114,6 +156,91 @@ pub enum CreateConfigError {
     #[error("Invalid Backup configuration: {0}")]
     InvalidBackupConfig(String),
 
+    /// Invalid Provision configuration
+    #[error("Invalid Provision configuration: {0}")]
+    InvalidProvisionConfig(String),
+
+    /// Invalid Firewall configuration
+    #[error("Invalid Firewall configuration: {0}")]
+    InvalidFirewallConfig(String),
+
+    /// Invalid Traces configuration
+    #[error("Invalid Traces configuration: {0}")]
+    InvalidTracesConfig(String),
+
+    /// Invalid System tuning configuration
+    #[error("Invalid System tuning configuration: {0}")]
+    InvalidSystemTuningConfig(String),
+
+    /// System tuning is set for a provider whose instances share the host kernel
+    #[error("The 'system_tuning' section is not supported by the {provider} provider")]
+    SystemTuningNotSupported { provider: String },
+
+    /// Invalid Cloud-init configuration
+    #[error("Invalid Cloud-init configuration: {0}")]
+    InvalidCloudInitConfig(String),
+
+    /// Cloud-init additions are set for a provider that does not run cloud-init
+    #[error("The 'cloud_init' section is not supported by the {provider} provider")]
+    CloudInitNotSupported { provider: String },
+
+    /// Invalid Instance configuration
+    #[error("Invalid Instance configuration: {0}")]
+    InvalidInstanceConfig(String),
+
+    /// Instance sizing is set for a provider that sizes instances by server type
+    #[error("The 'instance' section is not supported by the {provider} provider")]
+    InstanceSizingNotSupported { provider: String },
+
+    /// Invalid Offline configuration
+    #[error("Invalid Offline configuration: {0}")]
+    InvalidOfflineConfig(String),
+
+    /// Offline mode is set for a provider that does not create instances from LXD images
+    #[error("The 'offline' section is not supported by the {provider} provider")]
+    OfflineModeNotSupported { provider: String },
+
+    /// Offline packages directory not found
+    #[error("Offline packages directory not found: {path}")]
+    OfflinePackagesDirNotFound { path: PathBuf },
+
+    /// Offline mode leaves downloads from the Internet
+    #[error("Offline mode still needs these downloads from the Internet: {resources}")]
+    OfflineResourcesRequired {
+        /// The downloads left, separated by semicolons
+        resources: String,
+    },
+
+    /// Invalid Proxy configuration
+    #[error("Invalid Proxy configuration: {0}")]
+    InvalidProxyConfig(String),
+
+    /// An image of the release section is not a valid image reference
+    #[error("Invalid image for service '{service}': {source}")]
+    InvalidImageReference {
+        /// Name of the service the image is set for
+        service: String,
+        /// The image reference error
+        #[source]
+        source: ImageReferenceError,
+    },
+
+    /// Invalid Release configuration
+    #[error("Invalid Release configuration: {0}")]
+    InvalidReleaseConfig(String),
+
+    /// The tracker image is set by both the tracker version and the release section
+    #[error("The tracker image is set by both 'tracker.version' and 'release.images.tracker'")]
+    TrackerImageConflict,
+
+    /// A label key or value breaks the label rules
+    #[error("Invalid labels: {0}")]
+    InvalidLabel(#[from] LabelError),
+
+    /// An extra template variable cannot be referenced from a template
+    #[error("Invalid extra template variables: {0}")]
+    InvalidExtraTemplateVariable(#[from] ExtraTemplateVariableError),
+
     /// Tracker configuration validation failed
     #[error("Tracker configuration validation failed: {0}")]
     TrackerConfigValidation(#[from] TrackerConfigError),
@@ -125,6 +252,24 @@ pub enum CreateConfigError {
     #[error("HTTP API configuration invalid: {0}")]
     HttpApiConfigInvalid(#[from] HttpApiConfigError),
 
+    /// Tracker version is not a valid image tag or digest
+    ///
+    /// This error wraps domain-level validation errors from `TrackerVersion::new()`.
+    #[error("Tracker version invalid: {0}")]
+    TrackerVersionInvalid(#[from] TrackerVersionError),
+
+    /// Health check wait strategy is invalid
+    ///
+    /// This error wraps domain-level validation errors from `HealthCheckWait::new()`.
+    #[error("Health check wait configuration invalid: {0}")]
+    HealthCheckWaitInvalid(#[from] HealthCheckWaitError),
+
+    /// Tracker overrides are invalid or conflict with managed settings
+    ///
+    /// This error wraps domain-level validation errors from `TrackerOverrides`.
+    #[error("Tracker overrides invalid: {0}")]
+    TrackerOverridesInvalid(#[from] TrackerOverridesError),
+
     /// UDP tracker configuration validation failed (domain invariant violation)
     ///
     /// This error wraps domain-level validation errors from `UdpTrackerConfig::new()`,
@@ -195,6 +340,20 @@ pub enum CreateConfigError {
     /// such as Grafana requiring Prometheus or HTTPS/TLS configuration mismatches.
     #[error("Cross-service configuration validation failed: {0}")]
     CrossServiceValidation(#[from] crate::domain::environment::UserInputsError),
+
+    /// A secret referenced by environment variable or file could not be resolved
+    #[error("Failed to resolve secret: {0}")]
+    SecretResolution(#[from] SecretResolutionError),
+
+    /// Several fields of the configuration are invalid
+    ///
+    /// Returned instead of the first error when a configuration has more than
+    /// one problem, so that all of them can be fixed at once.
+    #[error("The configuration has {} problems:\n{}", .issues.len(), list_issues(.issues))]
+    InvalidConfiguration {
+        /// Every problem found, with the JSON path of its field
+        issues: Vec<ConfigValidationIssue>,
+    },
 }
 
 impl CreateConfigError {
@@ -230,6 +389,10 @@ impl CreateConfigError {
                  - Use dashes (-) as word separators\n\
                  - Not start or end with separators\n\
                  - Not start with numbers\n\
+                 - Not be a reserved name ('templates', 'traces')\n\
+                 - Leave the generated instance name 'torrust-tracker-vm-{env_name}' within\n\
+                 \x20 the provider's limit of 63 characters, i.e. at most 44 characters,\n\
+                 \x20 unless instance_name is set\n\
                  \n\
                  Examples: 'dev', 'staging', 'e2e-config', 'production'\n\
                  \n\
@@ -307,6 +470,28 @@ impl CreateConfigError {
                  3. Ensure you have read permissions on the file\n\
                  4. Generate public key from private key if needed: ssh-keygen -y -f <private_key> > <public_key>"
             }
+            Self::TemplatesOverrideDirNotFound { .. } => {
+                "Templates override directory not found.\n\
+                 \n\
+                 The directory set in 'templates_override_dir' does not exist or is not a directory.\n\
+                 \n\
+                 Fix:\n\
+                 1. Check that the path is correct in your configuration\n\
+                 2. Create the directory and mirror the embedded layout, e.g.:\n\
+                    mkdir -p <dir>/tofu/common\n\
+                 3. Or remove 'templates_override_dir' to use the embedded templates only"
+            }
+            Self::RelativeTemplatesOverrideDir { .. } => {
+                "Templates override directory must be absolute.\n\
+                 \n\
+                 The path is stored with the environment and used by every command that\n\
+                 renders templates, which may run from a different working directory.\n\
+                 \n\
+                 Fix:\n\
+                 1. Get the absolute path of the directory:\n\
+                    realpath <your-relative-path>\n\
+                 2. Update 'templates_override_dir' in your configuration with the result"
+            }
             Self::RelativePrivateKeyPath { .. } => {
                 // Note: Can't use format! in const context, so we use a static message
                 // The actual path will be shown in the error message itself
@@ -338,6 +523,29 @@ impl CreateConfigError {
                  - Environment state persists paths that must remain valid\n\
                  - Multi-command workflows (create → provision → configure)"
             }
+            Self::MissingSshKeyPaths => {
+                "SSH key paths are missing.\n\
+                 \n\
+                 Either point the configuration to an existing key pair:\n\
+                 \n\
+                 \"ssh_credentials\": {\n\
+                   \"private_key_path\": \"/home/user/.ssh/torrust_deployer\",\n\
+                   \"public_key_path\": \"/home/user/.ssh/torrust_deployer.pub\"\n\
+                 }\n\
+                 \n\
+                 Or let the deployer generate a key pair for the environment in\n\
+                 data/{env}/ssh/ by adding:\n\
+                 \n\
+                 \"generate_ssh_keys\": true"
+            }
+            Self::SshKeyPathsWithGeneratedKeys => {
+                "The configuration asks for a generated key pair and also names key files.\n\
+                 \n\
+                 Fix:\n\
+                 - Remove ssh_credentials.private_key_path and public_key_path to use the\n\
+                   key pair generated in data/{env}/ssh/\n\
+                 - Or remove generate_ssh_keys to use the existing key pair"
+            }
             Self::RelativePublicKeyPath { .. } => {
                 "SSH public key path must be absolute.\n\
                  \n\
@@ -395,6 +603,55 @@ impl CreateConfigError {
                  \n\
                  Fix: Update the bind_address in your configuration to use valid IP:PORT format."
             }
+            Self::InvalidLxdRemote(_) => {
+                "Invalid LXD remote.\n\
+                 \n\
+                 The remote is either the name of a remote configured in the LXD client\n\
+                 (see 'lxc remote list'), or an object with its settings:\n\
+                 \n\
+                 \"remote\": {\n\
+                   \"name\": \"bigbox\",\n\
+                   \"address\": \"https://bigbox.lan:8443\",\n\
+                   \"trust_token\": { \"env\": \"LXD_TRUST_TOKEN\" }\n\
+                 }\n\
+                 \n\
+                 The name may only contain letters, digits, '-', '_' and '.', and cannot\n\
+                 be 'local' (leave the remote out to use the local LXD daemon).\n\
+                 The address is the HTTPS URL of the LXD server API.\n\
+                 The trust token is issued on the server with: lxc config trust add\n\
+                 \n\
+                 Fix: Correct the 'remote' setting of the LXD provider."
+            }
+            Self::InvalidLibvirtConfig(_) => {
+                "Invalid libvirt provider configuration.\n\
+                 \n\
+                 The libvirt provider settings are all optional:\n\
+                 - pool: storage pool of the instance disk (default: 'default')\n\
+                 - network_name: network with DHCP the instance joins (default: 'default')\n\
+                 - image: absolute path on the host or HTTP(S) URL of a cloud image\n\
+                 (default: the Ubuntu 24.04 cloud image)\n\
+                 \n\
+                 List the pools and networks of the system daemon with:\n\
+                 \n\
+                 virsh --connect qemu:///system pool-list --all\n\
+                 virsh --connect qemu:///system net-list --all\n\
+                 \n\
+                 Fix: Correct the setting of the libvirt provider, or remove it to use the default."
+            }
+            Self::InvalidReservedIp { .. } => {
+                "Invalid DigitalOcean reserved IP.\n\
+                 \n\
+                 The reserved_ip must be an IPv4 address (e.g., '203.0.113.10').\n\
+                 DigitalOcean reserved IPs are IPv4 only.\n\
+                 \n\
+                 The reserved IP must already exist in your DigitalOcean account, in the\n\
+                 same region as the droplet. List them with:\n\
+                 \n\
+                 doctl compute reserved-ip list\n\
+                 \n\
+                 Fix: Update provider.reserved_ip in your configuration, or remove it to\n\
+                 use the droplet's own public IP."
+            }
             Self::DynamicPortNotSupported { .. } => {
                 "Dynamic port assignment (port 0) is not supported.\n\
                  \n\
@@ -550,6 +807,263 @@ impl CreateConfigError {
                  \n\
                  Note: All fields have sensible defaults (3:00 AM daily, 7 days retention)."
             }
+            Self::InvalidProvisionConfig(_) => {
+                "Invalid Provision configuration.\n\
+                 \n\
+                 The 'cloud_init_timeout_secs' value must be greater than 0, and\n\
+                 'ip_preference' must be \"ipv4\" or \"ipv6\".\n\
+                 \n\
+                 Fix:\n\
+                 Update your provision configuration:\n\
+                 \n\
+                 \"provision\": {\n\
+                   \"cloud_init_timeout_secs\": 300,\n\
+                   \"ip_preference\": \"ipv4\"\n\
+                 }\n\
+                 \n\
+                 Or remove the 'provision' section to use the defaults (300 seconds, IPv4)."
+            }
+            Self::InvalidTracesConfig(_) => {
+                "Invalid Traces configuration.\n\
+                 \n\
+                 The 'keep_last' value must be greater than 0, and 'max_age_days'\n\
+                 must be greater than 0 when set.\n\
+                 \n\
+                 Fix:\n\
+                 Update your traces configuration:\n\
+                 \n\
+                 \"traces\": {\n\
+                   \"dir\": \"/path/to/ci-artifacts/traces\",\n\
+                   \"keep_last\": 20,\n\
+                   \"max_age_days\": 30\n\
+                 }\n\
+                 \n\
+                 Or remove the 'traces' section to keep the last 20 traces in data/<env>/traces/."
+            }
+            Self::InvalidFirewallConfig(_) => {
+                "Invalid Firewall configuration.\n\
+                 \n\
+                 Each extra allow rule needs a port greater than 0, a protocol\n\
+                 ('tcp' or 'udp') and optionally a source network in CIDR notation.\n\
+                 \n\
+                 Fix:\n\
+                 Update your firewall configuration:\n\
+                 \n\
+                 \"firewall\": {\n\
+                   \"extra_allow\": [\n\
+                     { \"port\": 9100, \"protocol\": \"tcp\", \"cidr\": \"10.0.0.0/8\" }\n\
+                   ]\n\
+                 }\n\
+                 \n\
+                 Or remove the 'firewall' section to allow only SSH and the service ports."
+            }
+            Self::InvalidSystemTuningConfig(_) => {
+                "Invalid System tuning configuration.\n\
+                 \n\
+                 The preset must be 'default' or 'high-udp'. Only network and file\n\
+                 system sysctls can be set (net.core.*, net.ipv4.*, net.ipv6.*,\n\
+                 net.netfilter.*, net.unix.*, fs.aio-max-nr, fs.file-max, fs.inotify.*,\n\
+                 fs.nr_open), ulimits must be 'nofile' or 'nproc' and greater than 0,\n\
+                 and the swap size must be greater than 0.\n\
+                 \n\
+                 Fix:\n\
+                 Update your system tuning configuration:\n\
+                 \n\
+                 \"system_tuning\": {\n\
+                   \"preset\": \"high-udp\",\n\
+                   \"sysctls\": { \"net.core.rmem_max\": 67108864 },\n\
+                   \"ulimits\": { \"nofile\": 1048576 }\n\
+                 }\n\
+                 \n\
+                 Or remove the 'system_tuning' section to keep the image's settings."
+            }
+            Self::SystemTuningNotSupported { .. } => {
+                "System tuning is not supported by this provider.\n\
+                 \n\
+                 Docker containers share the kernel of the host running them, so their\n\
+                 kernel parameters and limits cannot be tuned by the deployer.\n\
+                 \n\
+                 Fix:\n\
+                 Remove the 'system_tuning' section and tune the Docker host instead,\n\
+                 or use the LXD, Hetzner or DigitalOcean provider."
+            }
+            Self::InvalidCloudInitConfig(_) => {
+                "Invalid Cloud-init configuration.\n\
+                 \n\
+                 Package names and commands must not be empty, package names must not\n\
+                 contain spaces, and 'raw_append' must be a YAML mapping that does not\n\
+                 set the keys creating the SSH user ('users', 'ssh_authorized_keys', ...).\n\
+                 \n\
+                 Fix:\n\
+                 Update your cloud-init configuration:\n\
+                 \n\
+                 \"cloud_init\": {\n\
+                   \"extra_packages\": [\"htop\"],\n\
+                   \"extra_runcmd\": [\"echo 'Managed by Torrust' > /etc/motd\"],\n\
+                   \"raw_append\": \"timezone: UTC\"\n\
+                 }\n\
+                 \n\
+                 Or remove the 'cloud_init' section to use the generated configuration only."
+            }
+            Self::CloudInitNotSupported { .. } => {
+                "Cloud-init additions are not supported by this provider.\n\
+                 \n\
+                 Docker containers are started from a prepared image and do not run\n\
+                 cloud-init, so the 'cloud_init' section would be ignored.\n\
+                 \n\
+                 Fix:\n\
+                 Remove the 'cloud_init' section, or use the LXD, Hetzner or\n\
+                 DigitalOcean provider."
+            }
+            Self::InvalidOfflineConfig(_) => {
+                "Invalid Offline configuration.\n\
+                 \n\
+                 'image.source' must be the alias of an image imported into LXD,\n\
+                 optionally prefixed with 'local:'. Mirrors must be http:// or https://\n\
+                 URLs and 'packages_dir' an absolute path.\n\
+                 \n\
+                 Fix:\n\
+                 Update your offline configuration:\n\
+                 \n\
+                 \"offline\": {\n\
+                   \"image\": { \"source\": \"local:torrust-ubuntu-24.04\" },\n\
+                   \"apt_mirror\": \"http://mirror.lab.internal/ubuntu\",\n\
+                   \"registry_mirror\": \"https://registry.lab.internal\"\n\
+                 }"
+            }
+            Self::InvalidProxyConfig(_) => {
+                "Invalid Proxy configuration.\n\
+                 \n\
+                 At least one of 'http' and 'https' must be set, each to an http:// or\n\
+                 https:// URL. Each 'no_proxy' entry is one host, domain or network.\n\
+                 \n\
+                 Fix:\n\
+                 Update your proxy configuration:\n\
+                 \n\
+                 \"proxy\": {\n\
+                   \"http\": \"http://proxy.corp.internal:3128\",\n\
+                   \"https\": \"http://proxy.corp.internal:3128\",\n\
+                   \"no_proxy\": [\"localhost\", \".corp.internal\"]\n\
+                 }"
+            }
+            Self::InvalidImageReference { source, .. } => source.help(),
+            Self::InvalidReleaseConfig(_) => {
+                "Invalid Release configuration.\n\
+                 \n\
+                 The registry 'server' is a host name with an optional port, without\n\
+                 scheme or path. 'username' and 'password' must not be empty.\n\
+                 \n\
+                 Fix:\n\
+                 Update your release configuration:\n\
+                 \n\
+                 \"release\": {\n\
+                   \"registry\": {\n\
+                     \"server\": \"registry.corp.internal:5000\",\n\
+                     \"username\": \"deployer\",\n\
+                     \"password\": { \"env\": \"REGISTRY_PASSWORD\" }\n\
+                   }\n\
+                 }"
+            }
+            Self::TrackerImageConflict => {
+                "The tracker image is selected twice.\n\
+                 \n\
+                 'tracker.version' selects a tag or digest of torrust/tracker, while\n\
+                 'release.images.tracker' replaces the whole image reference.\n\
+                 \n\
+                 Fix:\n\
+                 Remove 'tracker.version' to deploy the image of 'release.images.tracker',\n\
+                 or remove 'release.images.tracker' to deploy torrust/tracker."
+            }
+            Self::OfflineModeNotSupported { .. } => {
+                "Offline mode is not supported by this provider.\n\
+                 \n\
+                 Offline mode creates the instance from an image imported into LXD.\n\
+                 Cloud providers and Docker use their own images.\n\
+                 \n\
+                 Fix:\n\
+                 Remove the 'offline' section, or use the LXD provider."
+            }
+            Self::OfflinePackagesDirNotFound { .. } => {
+                "Offline packages directory not found.\n\
+                 \n\
+                 The directory set in 'offline.packages_dir' does not exist or is not a\n\
+                 directory. Its .deb files are copied to the instance and installed.\n\
+                 \n\
+                 Fix:\n\
+                 1. Check that the path is correct in your configuration\n\
+                 2. Download the packages on a machine with Internet access, e.g.:\n\
+                    apt-get download docker.io docker-compose-v2 <dependencies>\n\
+                 3. Or set 'offline.apt_mirror' instead"
+            }
+            Self::OfflineResourcesRequired { .. } => {
+                "Offline mode does not replace every download.\n\
+                 \n\
+                 Each download needs a pre-seeded replacement in the 'offline' section:\n\
+                 - Ubuntu packages and the Docker Compose plugin: 'apt_mirror' or\n\
+                   'packages_dir'\n\
+                 - First boot packages of the 'cloud_init' section: 'apt_mirror'\n\
+                 - Container images: 'registry_mirror'\n\
+                 \n\
+                 Fix:\n\
+                 Set the missing fields, or remove the 'offline' section to download\n\
+                 everything from the Internet."
+            }
+            Self::InvalidInstanceConfig(_) => {
+                "Invalid Instance configuration.\n\
+                 \n\
+                 'cpus' must be between 1 and 64, 'memory_mb' between 512 and 262144,\n\
+                 and 'disk_gb' between 5 and 2048.\n\
+                 \n\
+                 Fix:\n\
+                 Update your instance configuration:\n\
+                 \n\
+                 \"instance\": {\n\
+                   \"cpus\": 2,\n\
+                   \"memory_mb\": 2048,\n\
+                   \"disk_gb\": 10\n\
+                 }\n\
+                 \n\
+                 Or remove the 'instance' section to use the defaults (2 CPUs, 2048 MiB, 10 GiB)."
+            }
+            Self::InstanceSizingNotSupported { .. } => {
+                "Instance sizing is not supported by this provider.\n\
+                 \n\
+                 The 'instance' section sizes LXD instances. Cloud providers size the\n\
+                 instance through their own setting instead.\n\
+                 \n\
+                 Fix:\n\
+                 Remove the 'instance' section and choose the size in the provider section:\n\
+                 - Hetzner: \"server_type\", e.g. \"cx32\"\n\
+                 - DigitalOcean: \"droplet_size\", e.g. \"s-2vcpu-4gb\""
+            }
+            Self::InvalidLabel(_) => {
+                "Invalid labels.\n\
+                 \n\
+                 Label keys have 1 to 63 lowercase letters, digits, '-', '_' or '.',\n\
+                 starting and ending with a letter or a digit. Values have 1 to 63\n\
+                 letters, digits, '-', '_' or '.'.\n\
+                 \n\
+                 Fix:\n\
+                 Update the labels in your configuration:\n\
+                 \n\
+                 \"labels\": {\n\
+                   \"team\": \"infra\",\n\
+                   \"purpose\": \"e2e\"\n\
+                 }"
+            }
+            Self::InvalidExtraTemplateVariable(_) => {
+                "Invalid extra template variables.\n\
+                 \n\
+                 Variable names are referenced from Tera templates, so they have ASCII\n\
+                 letters, digits or '_', and do not start with a digit.\n\
+                 \n\
+                 Fix:\n\
+                 Rename the variables in your configuration, e.g. 'my-motd' to 'my_motd':\n\
+                 \n\
+                 \"extra_template_vars\": {\n\
+                   \"my_motd\": \"hello\"\n\
+                 }"
+            }
             Self::TrackerConfigValidation(_) => {
                 "Tracker configuration validation failed.\n\
                  \n\
@@ -565,6 +1079,7 @@ impl CreateConfigError {
                  1. Multiple services on same TCP port (HTTP tracker + API)\n\
                  2. Duplicate UDP tracker ports\n\
                  3. Duplicate HTTP tracker ports\n\
+                 4. Too many replicas for the highest tracker port\n\
                  \n\
                  Note: UDP and TCP can share the same port (different protocols),\n\
                  but this is not recommended for clarity.\n\
@@ -575,6 +1090,18 @@ impl CreateConfigError {
                 // Delegate to domain error's help method for detailed guidance
                 inner.help()
             }
+            Self::TrackerVersionInvalid(inner) => {
+                // Delegate to domain error's help method for detailed guidance
+                inner.help()
+            }
+            Self::HealthCheckWaitInvalid(inner) => {
+                // Delegate to domain error's help method for detailed guidance
+                inner.help()
+            }
+            Self::TrackerOverridesInvalid(inner) => {
+                // Delegate to domain error's help method for detailed guidance
+                inner.help()
+            }
             Self::UdpTrackerConfigInvalid(inner) => {
                 // Delegate to domain error's help method for detailed guidance
                 inner.help()
@@ -599,6 +1126,7 @@ impl CreateConfigError {
                 // Delegate to domain error's help method for detailed guidance
                 inner.help()
             }
+            Self::SecretResolution(inner) => inner.help(),
             Self::InvalidDomain { .. } => {
                 "Invalid domain name format for TLS configuration.\n\
                  \n\
@@ -658,6 +1186,59 @@ impl CreateConfigError {
                  remove or set use_tls_proxy to false."
             }
             Self::CrossServiceValidation(e) => e.help(),
+            Self::InvalidConfiguration { .. } => {
+                "The configuration has several problems.\n\
+                 \n\
+                 Each problem is listed with the JSON path of the field it is about,\n\
+                 e.g. 'tracker.http_api.bind_address' is the 'bind_address' field of\n\
+                 the 'http_api' object in the 'tracker' section.\n\
+                 \n\
+                 Fix: Correct every listed field, then check the file again with:\n\
+                 \x20 torrust-tracker-deployer validate --env-file <file>"
+            }
+        }
+    }
+
+    /// The field of its section this error is about, if it is about one field
+    ///
+    /// Used to locate the error by JSON path, e.g. an invalid bind address of
+    /// the `tracker.http_api` section is at `tracker.http_api.bind_address`.
+    #[must_use]
+    pub fn field(&self) -> Option<&'static str> {
+        match self {
+            Self::InvalidBindAddress { .. }
+            | Self::DynamicPortNotSupported { .. }
+            | Self::UdpTrackerConfigInvalid(UdpTrackerConfigError::DynamicPortNotSupported(_))
+            | Self::HttpTrackerConfigInvalid(HttpTrackerConfigError::DynamicPortNotSupported(_))
+            | Self::HttpApiConfigInvalid(HttpApiConfigError::DynamicPortNotSupported(_))
+            | Self::HealthCheckApiConfigInvalid(
+                HealthCheckApiConfigError::DynamicPortNotSupported(_),
+            ) => Some("bind_address"),
+            Self::InvalidDomain { .. }
+            | Self::TlsProxyWithoutDomain { .. }
+            | Self::HttpTrackerConfigInvalid(HttpTrackerConfigError::TlsProxyRequiresDomain(_))
+            | Self::HttpApiConfigInvalid(HttpApiConfigError::TlsProxyRequiresDomain(_))
+            | Self::HealthCheckApiConfigInvalid(
+                HealthCheckApiConfigError::TlsProxyRequiresDomain(_),
+            ) => Some("domain"),
+            Self::HttpTrackerConfigInvalid(HttpTrackerConfigError::LocalhostWithTls(_))
+            | Self::HttpApiConfigInvalid(HttpApiConfigError::LocalhostWithTls(_))
+            | Self::HealthCheckApiConfigInvalid(HealthCheckApiConfigError::LocalhostWithTls(_)) => {
+                Some("use_tls_proxy")
+            }
+            Self::PrivateKeyNotFound { .. } | Self::RelativePrivateKeyPath { .. } => {
+                Some("private_key_path")
+            }
+            Self::PublicKeyNotFound { .. } | Self::RelativePublicKeyPath { .. } => {
+                Some("public_key_path")
+            }
+            Self::InvalidUsername(_) => Some("username"),
+            Self::InvalidPort { .. } => Some("port"),
+            Self::InvalidProfileName(_) => Some("profile_name"),
+            Self::InvalidLxdRemote(_) => Some("remote"),
+            Self::SqliteConfigInvalid(_) | Self::MysqlConfigInvalid(_) => Some("database"),
+            Self::OfflinePackagesDirNotFound { .. } => Some("packages_dir"),
+            _ => None,
         }
     }
 }
@@ -712,6 +1293,28 @@ mod tests {
         assert!(error.help().contains("ssh-keygen -y"));
     }
 
+    #[test]
+    fn it_should_return_error_when_templates_override_dir_not_found() {
+        let error = CreateConfigError::TemplatesOverrideDirNotFound {
+            path: PathBuf::from("/nonexistent/overrides"),
+        };
+        assert!(error
+            .to_string()
+            .contains("Templates override directory not found"));
+        assert!(error.to_string().contains("/nonexistent/overrides"));
+        assert!(error.help().contains("templates_override_dir"));
+    }
+
+    #[test]
+    fn it_should_return_error_when_templates_override_dir_is_relative() {
+        let error = CreateConfigError::RelativeTemplatesOverrideDir {
+            path: PathBuf::from("template-overrides"),
+        };
+        assert!(error.to_string().contains("must be absolute"));
+        assert!(error.to_string().contains("template-overrides"));
+        assert!(error.help().contains("realpath"));
+    }
+
     #[test]
     fn it_should_return_error_when_port_is_invalid() {
         let error = CreateConfigError::InvalidPort { port: 0 };
@@ -731,7 +1334,64 @@ mod tests {
             CreateConfigError::PublicKeyNotFound {
                 path: PathBuf::from("/test"),
             },
+            CreateConfigError::TemplatesOverrideDirNotFound {
+                path: PathBuf::from("/test"),
+            },
+            CreateConfigError::RelativeTemplatesOverrideDir {
+                path: PathBuf::from("test"),
+            },
             CreateConfigError::InvalidPort { port: 0 },
+            CreateConfigError::InvalidSystemTuningConfig(
+                "Sysctl 'kernel.kptr_restrict' is not allowed".to_string(),
+            ),
+            CreateConfigError::SystemTuningNotSupported {
+                provider: "docker".to_string(),
+            },
+            CreateConfigError::InvalidCloudInitConfig(
+                "'raw_append' must not set 'users'".to_string(),
+            ),
+            CreateConfigError::CloudInitNotSupported {
+                provider: "docker".to_string(),
+            },
+            CreateConfigError::InvalidOfflineConfig(
+                "'packages_dir' must be an absolute path".to_string(),
+            ),
+            CreateConfigError::OfflineModeNotSupported {
+                provider: "hetzner".to_string(),
+            },
+            CreateConfigError::InvalidProxyConfig(
+                "The proxy section must set 'http', 'https' or both".to_string(),
+            ),
+            CreateConfigError::InvalidImageReference {
+                service: "mysql".to_string(),
+                source: ImageReferenceError::Empty,
+            },
+            CreateConfigError::InvalidReleaseConfig(
+                "the registry password cannot be empty".to_string(),
+            ),
+            CreateConfigError::TrackerImageConflict,
+            CreateConfigError::InvalidLxdRemote(
+                "the LXD remote trust token cannot be empty".to_string(),
+            ),
+            CreateConfigError::InvalidLibvirtConfig("the network name cannot be empty".to_string()),
+            CreateConfigError::OfflinePackagesDirNotFound {
+                path: PathBuf::from("/test"),
+            },
+            CreateConfigError::OfflineResourcesRequired {
+                resources: "container image 'mysql:8.4' from Docker Hub".to_string(),
+            },
+            CreateConfigError::InvalidInstanceConfig("cpus must be between 1 and 64".to_string()),
+            CreateConfigError::InstanceSizingNotSupported {
+                provider: "hetzner".to_string(),
+            },
+            CreateConfigError::InvalidLabel(LabelError::InvalidKey {
+                key: "Team".to_string(),
+            }),
+            CreateConfigError::InvalidExtraTemplateVariable(
+                ExtraTemplateVariableError::InvalidName {
+                    name: "my-motd".to_string(),
+                },
+            ),
             CreateConfigError::InvalidInstanceName {
                 name: "invalid-".to_string(),
                 reason: "ends with dash".to_string(),
@@ -1,11 +1,15 @@
-//! Error type for loading `EnvironmentCreationConfig` from files or JSON strings.
+//! Error type for loading `EnvironmentCreationConfig` from files or JSON, YAML
+//! and TOML strings.
 
 use std::path::PathBuf;
 
 use thiserror::Error;
 
+use crate::shared::error::kind::ErrorKind;
+use crate::shared::error::traceable::Traceable;
+
 /// Errors that can occur when loading an [`EnvironmentCreationConfig`]
-/// from a JSON string or a file.
+/// from a JSON, YAML or TOML string or a file.
 ///
 /// This is distinct from [`super::CreateConfigError`] which covers
 /// domain validation failures *after* parsing.
@@ -35,4 +39,57 @@ pub enum ConfigLoadError {
         /// Underlying `serde_json` error.
         source: serde_json::Error,
     },
+
+    /// The YAML content could not be parsed into `EnvironmentCreationConfig`.
+    #[error("Failed to parse YAML configuration: {source}")]
+    YamlParseFailed {
+        /// Underlying `serde_yaml` error, with the line and column.
+        source: serde_yaml::Error,
+    },
+
+    /// The TOML content could not be parsed into `EnvironmentCreationConfig`.
+    #[error("Failed to parse TOML configuration: {source}")]
+    TomlParseFailed {
+        /// Underlying `toml` error, with the line and column (boxed, it is
+        /// large).
+        source: Box<toml::de::Error>,
+    },
+}
+
+impl Traceable for ConfigLoadError {
+    fn trace_format(&self) -> String {
+        match self {
+            Self::FileNotFound { path } => {
+                format!("ConfigLoadError: File not found - '{}'", path.display())
+            }
+            Self::FileReadFailed { path, source } => {
+                format!(
+                    "ConfigLoadError: File read failed - '{}': {source}",
+                    path.display()
+                )
+            }
+            Self::JsonParseFailed { source } => {
+                format!("ConfigLoadError: JSON parsing failed - {source}")
+            }
+            Self::YamlParseFailed { source } => {
+                format!("ConfigLoadError: YAML parsing failed - {source}")
+            }
+            Self::TomlParseFailed { source } => {
+                format!("ConfigLoadError: TOML parsing failed - {source}")
+            }
+        }
+    }
+
+    fn trace_source(&self) -> Option<&dyn Traceable> {
+        None
+    }
+
+    fn error_kind(&self) -> ErrorKind {
+        match self {
+            Self::FileNotFound { .. } | Self::FileReadFailed { .. } => ErrorKind::FileSystem,
+            Self::JsonParseFailed { .. }
+            | Self::YamlParseFailed { .. }
+            | Self::TomlParseFailed { .. } => ErrorKind::Configuration,
+        }
+    }
 }
@@ -6,9 +6,13 @@
 //!   domain validation (reading a file, deserializing JSON).
 //! - [`CreateConfigError`] — domain validation failures that occur **after** the
 //!   configuration has been successfully loaded.
+//! - [`SecretResolutionError`] — failures resolving secret references
+//!   (environment variables, files) during that conversion.
 
 pub mod create_config_error;
 pub mod load_error;
+pub mod secret_resolution_error;
 
 pub use create_config_error::CreateConfigError;
 pub use load_error::ConfigLoadError;
+pub use secret_resolution_error::SecretResolutionError;
@@ -0,0 +1,107 @@
+//! Error type for resolving secret references in the environment configuration.
+
+use std::path::PathBuf;
+
+use thiserror::Error;
+
+/// Errors that can occur when resolving a [`SecretSource`]
+///
+/// Messages name the environment variable or file that was referenced, but
+/// never the secret value itself.
+///
+/// [`SecretSource`]: crate::application::command_handlers::create::config::SecretSource
+#[derive(Debug, Error)]
+pub enum SecretResolutionError {
+    /// The referenced environment variable is not set
+    #[error("Environment variable '{variable}' referenced by a secret is not set")]
+    EnvVarNotSet {
+        /// Name of the environment variable
+        variable: String,
+    },
+
+    /// The referenced environment variable does not contain valid Unicode
+    #[error("Environment variable '{variable}' referenced by a secret is not valid Unicode")]
+    EnvVarNotUnicode {
+        /// Name of the environment variable
+        variable: String,
+    },
+
+    /// The referenced secret file could not be read
+    #[error("Failed to read secret file '{path}': {source}")]
+    FileReadFailed {
+        /// Path of the secret file
+        path: PathBuf,
+        /// Underlying I/O error
+        source: std::io::Error,
+    },
+
+    /// The referenced environment variable or file is empty
+    #[error("Secret from {reference} is empty")]
+    EmptyValue {
+        /// Description of the reference (variable or file)
+        reference: String,
+    },
+}
+
+impl SecretResolutionError {
+    /// Provides troubleshooting guidance for secret resolution errors
+    #[must_use]
+    pub fn help(&self) -> &'static str {
+        match self {
+            Self::EnvVarNotSet { .. } => {
+                "A secret in the configuration references an environment variable that is not set.\n\
+                 \n\
+                 Fix:\n\
+                 1. Export the variable before running the command:\n\
+                    export TRACKER_ADMIN_TOKEN='...'\n\
+                 2. Or replace the reference with an inline value or a file reference:\n\
+                    \"admin_token\": { \"file\": \"/run/secrets/tracker_admin_token\" }"
+            }
+            Self::EnvVarNotUnicode { .. } => {
+                "A secret in the configuration references an environment variable that\n\
+                 contains invalid Unicode.\n\
+                 \n\
+                 Fix: Set the variable to a valid UTF-8 value."
+            }
+            Self::FileReadFailed { .. } => {
+                "A secret in the configuration references a file that cannot be read.\n\
+                 \n\
+                 Fix:\n\
+                 1. Check that the file path is correct and absolute\n\
+                 2. Check that the file exists\n\
+                 3. Check that the current user can read it: ls -l <path>"
+            }
+            Self::EmptyValue { .. } => {
+                "A secret in the configuration resolved to an empty value.\n\
+                 \n\
+                 Fix: Make sure the referenced environment variable or file contains the secret."
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn it_should_name_the_missing_environment_variable() {
+        let error = SecretResolutionError::EnvVarNotSet {
+            variable: "TRACKER_ADMIN_TOKEN".to_string(),
+        };
+
+        assert!(error.to_string().contains("TRACKER_ADMIN_TOKEN"));
+        assert!(error.help().contains("export"));
+    }
+
+    #[test]
+    fn it_should_name_the_unreadable_secret_file() {
+        let error = SecretResolutionError::FileReadFailed {
+            path: PathBuf::from("/run/secrets/token"),
+            source: std::io::Error::new(std::io::ErrorKind::NotFound, "not found"),
+        };
+
+        assert!(error.to_string().contains("/run/secrets/token"));
+        assert!(error.help().contains("file path"));
+    }
+}
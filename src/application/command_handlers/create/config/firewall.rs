@@ -0,0 +1,123 @@
+//! Firewall Configuration DTO (Application Layer)
+//!
+//! This module contains the DTO types for the host firewall settings used in
+//! environment creation. These types use raw primitives (u16, String) for JSON
+//! deserialization and convert to the domain type (`FirewallConfig`).
+//!
+//! # Conversion Pattern
+//!
+//! Uses `TryFrom` for idiomatic Rust conversion from DTO to domain type.
+//! See ADR: `docs/decisions/tryfrom-for-dto-to-domain-conversion.md`
+
+use std::convert::TryFrom;
+
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+use crate::application::command_handlers::create::config::errors::CreateConfigError;
+use crate::domain::firewall::{FirewallConfig, FirewallRule};
+use crate::domain::tracker::Protocol;
+
+/// Firewall configuration section (DTO)
+///
+/// The firewall denies all incoming traffic except SSH and the ports of the
+/// enabled services. This section adds further allow rules.
+///
+/// # Examples
+///
+/// ```json
+/// {
+///     "extra_allow": [
+///         { "port": 9100, "protocol": "tcp", "cidr": "10.0.0.0/8" }
+///     ]
+/// }
+/// ```
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize, JsonSchema)]
+pub struct FirewallSection {
+    /// Additional rules allowing incoming traffic
+    #[serde(default)]
+    pub extra_allow: Vec<FirewallRuleSection>,
+}
+
+/// A single allow rule (DTO)
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, JsonSchema)]
+pub struct FirewallRuleSection {
+    /// Port to open (1-65535)
+    pub port: u16,
+
+    /// Protocol of the port: `tcp` or `udp`
+    pub protocol: String,
+
+    /// Source network allowed to connect, e.g. `10.0.0.0/8`
+    ///
+    /// Any source is allowed when omitted.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub cidr: Option<String>,
+}
+
+impl TryFrom<FirewallRuleSection> for FirewallRule {
+    type Error = CreateConfigError;
+
+    fn try_from(section: FirewallRuleSection) -> Result<Self, Self::Error> {
+        let protocol: Protocol = section.protocol.parse().map_err(|_| {
+            CreateConfigError::InvalidFirewallConfig(format!(
+                "Unknown protocol '{}' for port {}",
+                section.protocol, section.port
+            ))
+        })?;
+
+        FirewallRule::new(section.port, protocol, section.cidr)
+            .map_err(|e| CreateConfigError::InvalidFirewallConfig(e.to_string()))
+    }
+}
+
+impl TryFrom<FirewallSection> for FirewallConfig {
+    type Error = CreateConfigError;
+
+    fn try_from(section: FirewallSection) -> Result<Self, Self::Error> {
+        let extra_allow = section
+            .extra_allow
+            .into_iter()
+            .map(FirewallRule::try_from)
+            .collect::<Result<Vec<_>, _>>()?;
+
+        Ok(FirewallConfig::new(extra_allow))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn it_should_convert_extra_allow_rules_to_the_domain_type() {
+        let section: FirewallSection = serde_json::from_str(
+            r#"{ "extra_allow": [{ "port": 9100, "protocol": "tcp", "cidr": "10.0.0.0/8" }] }"#,
+        )
+        .expect("Valid JSON");
+
+        let config = FirewallConfig::try_from(section).unwrap();
+
+        assert_eq!(config.extra_allow().len(), 1);
+        assert_eq!(config.extra_allow()[0].protocol(), Protocol::Tcp);
+        assert_eq!(config.extra_allow()[0].cidr(), Some("10.0.0.0/8"));
+    }
+
+    #[test]
+    fn it_should_reject_an_unknown_protocol() {
+        let section = FirewallSection {
+            extra_allow: vec![FirewallRuleSection {
+                port: 9100,
+                protocol: "icmp".to_string(),
+                cidr: None,
+            }],
+        };
+
+        let result = FirewallConfig::try_from(section);
+
+        assert!(matches!(
+            result,
+            Err(CreateConfigError::InvalidFirewallConfig(_))
+        ));
+    }
+}
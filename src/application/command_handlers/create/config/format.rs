@@ -0,0 +1,135 @@
+//! Configuration File Format
+//!
+//! Environment configuration files can be written in JSON, YAML or TOML.
+//! The format is taken from the file extension (`.json`, `.yaml`/`.yml`,
+//! `.toml`) and, for any other extension, guessed from the content.
+
+use std::path::Path;
+
+/// Format of an environment configuration file
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ConfigFormat {
+    /// JSON format
+    Json,
+    /// YAML format
+    Yaml,
+    /// TOML format
+    Toml,
+}
+
+impl ConfigFormat {
+    /// Returns the format matching the extension of `path`, if any
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use std::path::Path;
+    /// use torrust_tracker_deployer_lib::application::command_handlers::create::config::ConfigFormat;
+    ///
+    /// assert_eq!(ConfigFormat::from_extension(Path::new("env.yml")), Some(ConfigFormat::Yaml));
+    /// assert_eq!(ConfigFormat::from_extension(Path::new("env.conf")), None);
+    /// ```
+    #[must_use]
+    pub fn from_extension(path: &Path) -> Option<Self> {
+        let extension = path.extension()?.to_str()?.to_ascii_lowercase();
+
+        match extension.as_str() {
+            "json" => Some(Self::Json),
+            "yaml" | "yml" => Some(Self::Yaml),
+            "toml" => Some(Self::Toml),
+            _ => None,
+        }
+    }
+
+    /// Guesses the format of a configuration from its content
+    ///
+    /// A JSON configuration is an object, so it starts with `{`. A TOML one
+    /// is made of `key = value` lines and `[section]` headers. Anything else
+    /// is read as YAML.
+    #[must_use]
+    pub fn sniff(content: &str) -> Self {
+        if content.trim_start().starts_with('{') {
+            return Self::Json;
+        }
+
+        let first_line = content
+            .lines()
+            .map(str::trim)
+            .find(|line| !line.is_empty() && !line.starts_with('#'));
+
+        match first_line {
+            Some(line) if line.starts_with('[') || Self::is_toml_assignment(line) => Self::Toml,
+            _ => Self::Yaml,
+        }
+    }
+
+    /// Returns the format of the configuration file at `path` with `content`
+    ///
+    /// The extension wins; the content is only looked at for other
+    /// extensions.
+    #[must_use]
+    pub fn detect(path: &Path, content: &str) -> Self {
+        Self::from_extension(path).unwrap_or_else(|| Self::sniff(content))
+    }
+
+    /// Whether `line` is a TOML `key = value` line rather than YAML
+    fn is_toml_assignment(line: &str) -> bool {
+        line.split_once('=').is_some_and(|(key, _)| {
+            let key = key.trim();
+            !key.is_empty()
+                && !key.contains(':')
+                && key
+                    .chars()
+                    .all(|c| c.is_ascii_alphanumeric() || matches!(c, '_' | '-' | '.' | '"'))
+        })
+    }
+}
+
+impl std::fmt::Display for ConfigFormat {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Json => write!(f, "JSON"),
+            Self::Yaml => write!(f, "YAML"),
+            Self::Toml => write!(f, "TOML"),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn it_should_take_the_format_from_the_extension() {
+        for (path, format) in [
+            ("env.json", ConfigFormat::Json),
+            ("env.yaml", ConfigFormat::Yaml),
+            ("env.YML", ConfigFormat::Yaml),
+            ("env.toml", ConfigFormat::Toml),
+        ] {
+            assert_eq!(ConfigFormat::detect(Path::new(path), "{}"), format);
+        }
+    }
+
+    #[test]
+    fn it_should_sniff_the_format_of_files_with_another_extension() {
+        let path = Path::new("env.conf");
+
+        assert_eq!(
+            ConfigFormat::detect(path, "\n  {\"environment\": {}}"),
+            ConfigFormat::Json
+        );
+        assert_eq!(
+            ConfigFormat::detect(path, "# Staging\n[environment]\nname = \"staging\"\n"),
+            ConfigFormat::Toml
+        );
+        assert_eq!(
+            ConfigFormat::detect(path, "environment:\n  name: staging\n"),
+            ConfigFormat::Yaml
+        );
+        assert_eq!(
+            ConfigFormat::detect(path, "---\nenvironment: { name: staging }\n"),
+            ConfigFormat::Yaml
+        );
+    }
+}
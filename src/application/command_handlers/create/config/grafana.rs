@@ -15,9 +15,9 @@ use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
 
 use crate::application::command_handlers::create::config::errors::CreateConfigError;
+use crate::application::command_handlers::create::config::SecretSource;
 use crate::domain::grafana::GrafanaConfig;
-use crate::shared::secrets::PlainPassword;
-
+use crate::shared::secrets::Password;
 use crate::shared::DomainName;
 
 /// Grafana configuration section (DTO)
@@ -27,9 +27,9 @@ use crate::shared::DomainName;
 ///
 /// # Security
 ///
-/// The `admin_password` field uses `PlainPassword` type alias for string at
-/// DTO boundaries. It will be converted to `Password` (secrecy-wrapped) in
-/// the domain layer.
+/// The `admin_password` field is a [`SecretSource`], so it can be given inline
+/// or as an environment variable or file reference. It is resolved and
+/// converted to `Password` (secrecy-wrapped) in the domain layer.
 ///
 /// # Examples
 ///
@@ -54,11 +54,11 @@ pub struct GrafanaSection {
     /// Grafana admin username
     pub admin_user: String,
 
-    /// Grafana admin password (plain string at DTO boundary)
+    /// Grafana admin password, inline or as an environment variable or file reference
     ///
-    /// This will be converted to `Password` type in the domain layer
-    /// to prevent accidental exposure in logs or debug output.
-    pub admin_password: PlainPassword,
+    /// This will be resolved and converted to `Password` type in the domain
+    /// layer to prevent accidental exposure in logs or debug output.
+    pub admin_password: SecretSource,
 
     /// Domain name for external HTTPS access (optional)
     ///
@@ -82,7 +82,11 @@ impl Default for GrafanaSection {
         let default_config = GrafanaConfig::default();
         Self {
             admin_user: default_config.admin_user().to_string(),
-            admin_password: default_config.admin_password().expose_secret().to_string(),
+            admin_password: default_config
+                .admin_password()
+                .map(Password::expose_secret)
+                .unwrap_or_default()
+                .into(),
             domain: None,
             use_tls_proxy: None,
         }
@@ -123,7 +127,7 @@ impl TryFrom<GrafanaSection> for GrafanaConfig {
 
         Ok(GrafanaConfig::new(
             section.admin_user,
-            section.admin_password,
+            section.admin_password.resolve()?,
             domain,
             use_tls_proxy,
         ))
@@ -138,7 +142,7 @@ mod tests {
     fn it_should_have_default_values() {
         let section = GrafanaSection::default();
         assert_eq!(section.admin_user, "admin");
-        assert_eq!(section.admin_password, "admin");
+        assert_eq!(section.admin_password, "admin".into());
         assert!(section.domain.is_none());
         assert!(section.use_tls_proxy.is_none());
     }
@@ -147,7 +151,7 @@ mod tests {
     fn it_should_convert_to_grafana_config() {
         let section = GrafanaSection {
             admin_user: "custom_admin".to_string(),
-            admin_password: "secure_password".to_string(),
+            admin_password: "secure_password".into(),
             domain: None,
             use_tls_proxy: None,
         };
@@ -157,7 +161,10 @@ mod tests {
 
         let config = result.unwrap();
         assert_eq!(config.admin_user(), "custom_admin");
-        assert_eq!(config.admin_password().expose_secret(), "secure_password");
+        assert_eq!(
+            config.admin_password().unwrap().expose_secret(),
+            "secure_password"
+        );
     }
 
     #[test]
@@ -174,7 +181,7 @@ mod tests {
     fn it_should_not_expose_password_in_debug_output() {
         let section = GrafanaSection {
             admin_user: "admin".to_string(),
-            admin_password: "secret_password".to_string(),
+            admin_password: "secret_password".into(),
             domain: None,
             use_tls_proxy: None,
         };
@@ -191,7 +198,7 @@ mod tests {
     fn it_should_convert_with_domain_and_tls_proxy() {
         let section = GrafanaSection {
             admin_user: "admin".to_string(),
-            admin_password: "password".to_string(),
+            admin_password: "password".into(),
             domain: Some("grafana.example.com".to_string()),
             use_tls_proxy: Some(true),
         };
@@ -208,7 +215,7 @@ mod tests {
     fn it_should_convert_with_domain_without_tls_proxy() {
         let section = GrafanaSection {
             admin_user: "admin".to_string(),
-            admin_password: "password".to_string(),
+            admin_password: "password".into(),
             domain: Some("grafana.example.com".to_string()),
             use_tls_proxy: Some(false),
         };
@@ -228,7 +235,7 @@ mod tests {
     fn it_should_return_error_when_tls_proxy_enabled_without_domain() {
         let section = GrafanaSection {
             admin_user: "admin".to_string(),
-            admin_password: "password".to_string(),
+            admin_password: "password".into(),
             domain: None,
             use_tls_proxy: Some(true),
         };
@@ -247,7 +254,7 @@ mod tests {
     fn it_should_return_error_for_invalid_domain() {
         let section = GrafanaSection {
             admin_user: "admin".to_string(),
-            admin_password: "password".to_string(),
+            admin_password: "password".into(),
             domain: Some(String::new()),
             use_tls_proxy: Some(true),
         };
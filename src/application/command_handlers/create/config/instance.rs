@@ -0,0 +1,130 @@
+//! Instance Configuration DTO (Application Layer)
+//!
+//! This module contains the DTO type for the sizing of the provisioned
+//! instance used in environment creation. This type uses raw primitives (u32)
+//! for JSON deserialization and converts to the domain type
+//! (`InstanceResources`).
+//!
+//! # Conversion Pattern
+//!
+//! Uses `TryFrom` for idiomatic Rust conversion from DTO to domain type.
+//! See ADR: `docs/decisions/tryfrom-for-dto-to-domain-conversion.md`
+
+use std::convert::TryFrom;
+
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+use crate::application::command_handlers::create::config::errors::CreateConfigError;
+use crate::domain::provision::instance_resources::{
+    DEFAULT_CPUS, DEFAULT_DISK_GB, DEFAULT_MEMORY_MB,
+};
+use crate::domain::provision::InstanceResources;
+
+/// Instance configuration section (DTO)
+///
+/// Optional CPU, memory and disk of the instance. All fields have defaults.
+/// Only the LXD provider supports it: cloud providers are sized through
+/// their server type or droplet size.
+///
+/// # Examples
+///
+/// ```json
+/// {
+///     "cpus": 4,
+///     "memory_mb": 4096,
+///     "disk_gb": 40
+/// }
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, JsonSchema)]
+pub struct InstanceSection {
+    /// Number of virtual CPUs
+    ///
+    /// Default: 2. Must be between 1 and 64.
+    #[serde(default = "default_cpus")]
+    pub cpus: u32,
+
+    /// Memory limit, in MiB
+    ///
+    /// Default: 2048. Must be between 512 and 262144 (256 GiB).
+    #[serde(default = "default_memory_mb")]
+    pub memory_mb: u32,
+
+    /// Root disk size, in GiB
+    ///
+    /// Default: 10. Must be between 5 and 2048.
+    #[serde(default = "default_disk_gb")]
+    pub disk_gb: u32,
+}
+
+fn default_cpus() -> u32 {
+    DEFAULT_CPUS
+}
+
+fn default_memory_mb() -> u32 {
+    DEFAULT_MEMORY_MB
+}
+
+fn default_disk_gb() -> u32 {
+    DEFAULT_DISK_GB
+}
+
+impl Default for InstanceSection {
+    fn default() -> Self {
+        Self {
+            cpus: default_cpus(),
+            memory_mb: default_memory_mb(),
+            disk_gb: default_disk_gb(),
+        }
+    }
+}
+
+impl TryFrom<InstanceSection> for InstanceResources {
+    type Error = CreateConfigError;
+
+    fn try_from(section: InstanceSection) -> Result<Self, Self::Error> {
+        InstanceResources::new(section.cpus, section.memory_mb, section.disk_gb)
+            .map_err(|e| CreateConfigError::InvalidInstanceConfig(e.to_string()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn it_should_use_the_defaults_when_the_section_is_empty() {
+        let section: InstanceSection = serde_json::from_str("{}").expect("Valid JSON");
+
+        let resources = InstanceResources::try_from(section).unwrap();
+
+        assert_eq!(resources, InstanceResources::default());
+    }
+
+    #[test]
+    fn it_should_keep_the_defaults_of_the_fields_left_out() {
+        let section: InstanceSection =
+            serde_json::from_str(r#"{ "memory_mb": 4096 }"#).expect("Valid JSON");
+
+        let resources = InstanceResources::try_from(section).unwrap();
+
+        assert_eq!(resources.cpus(), 2);
+        assert_eq!(resources.memory_mb(), 4096);
+        assert_eq!(resources.disk_gb(), 10);
+    }
+
+    #[test]
+    fn it_should_reject_resources_outside_the_accepted_ranges() {
+        let section = InstanceSection {
+            cpus: 0,
+            ..InstanceSection::default()
+        };
+
+        let result = InstanceResources::try_from(section);
+
+        assert!(matches!(
+            result,
+            Err(CreateConfigError::InvalidInstanceConfig(_))
+        ));
+    }
+}
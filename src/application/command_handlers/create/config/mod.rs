@@ -29,6 +29,9 @@
 //! - `ProviderSection` - Tagged enum for provider-specific settings
 //! - `LxdProviderSection` - LXD provider configuration
 //! - `HetznerProviderSection` - Hetzner provider configuration
+//! - `DigitalOceanProviderSection` - `DigitalOcean` provider configuration
+//! - `DockerProviderSection` - Docker provider configuration (testing only)
+//! - `LibvirtProviderSection` - Libvirt provider configuration
 //!
 //! Note: `SshCredentialsConfig` (config layer) is distinct from
 //! `adapters::ssh::SshCredentials` (adapter layer). The config version uses
@@ -134,27 +137,58 @@
 
 pub mod backup;
 pub mod builder;
+pub mod cloud_init;
 pub mod environment_config;
 pub mod errors;
+pub mod firewall;
+pub mod format;
 pub mod grafana;
 pub mod https;
+pub mod instance;
+pub mod offline;
+pub mod override_config;
 pub mod prometheus;
 pub mod provider;
+pub mod provision;
+pub mod proxy;
+pub mod release;
+pub mod secret;
 pub mod ssh_credentials_config;
+pub mod system_tuning;
+pub mod traces;
 pub mod tracker;
 pub mod validated_params;
+pub mod validation;
 
 // Re-export commonly used types for convenience
 pub use backup::BackupSection;
 pub use builder::{EnvironmentCreationConfigBuildError, EnvironmentCreationConfigBuilder};
+pub use cloud_init::CloudInitSection;
 pub use environment_config::{EnvironmentCreationConfig, EnvironmentSection};
 pub use errors::ConfigLoadError;
 pub use errors::CreateConfigError;
+pub use errors::SecretResolutionError;
+pub use firewall::{FirewallRuleSection, FirewallSection};
+pub use format::ConfigFormat;
 pub use grafana::GrafanaSection;
 pub use https::HttpsSection;
+pub use instance::InstanceSection;
+pub use offline::{OfflineImageSection, OfflineSection};
+pub use override_config::EnvironmentOverrideConfig;
 pub use prometheus::PrometheusSection;
-pub use provider::{HetznerProviderSection, LxdProviderSection, ProviderSection};
+pub use provider::{
+    CustomLxdRemoteSection, DigitalOceanProviderSection, DockerProviderSection,
+    HetznerProviderSection, LibvirtProviderSection, LxdProviderSection, LxdRemoteSection,
+    ProviderSection,
+};
+pub use provision::ProvisionSection;
+pub use proxy::ProxySection;
+pub use release::{RegistrySection, ReleaseImagesSection, ReleaseSection};
+pub use secret::SecretSource;
 pub use ssh_credentials_config::SshCredentialsConfig;
+pub use system_tuning::{CustomSystemTuningSection, SysctlValueSection, SystemTuningSection};
+pub use traces::TracesSection;
+pub use validation::{ConfigValidationIssue, IssueSeverity};
 
 // Note: EnvironmentParams is now in domain layer (crate::domain::environment::EnvironmentParams)
 // The validated_params module provides TryFrom<EnvironmentCreationConfig> for EnvironmentParams
@@ -0,0 +1,130 @@
+//! Offline Configuration DTO (Application Layer)
+//!
+//! This module contains the DTO type for deploying on a network without
+//! Internet access. It uses raw primitives (String) for JSON deserialization
+//! and converts to the domain type (`OfflineMode`).
+//!
+//! # Conversion Pattern
+//!
+//! Uses `TryFrom` for idiomatic Rust conversion from DTO to domain type.
+//! See ADR: `docs/decisions/tryfrom-for-dto-to-domain-conversion.md`
+
+use std::convert::TryFrom;
+use std::path::PathBuf;
+
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+use crate::application::command_handlers::create::config::errors::CreateConfigError;
+use crate::domain::provision::OfflineMode;
+
+/// Offline configuration section (DTO)
+///
+/// Pre-seeded artifacts replacing the downloads of a deployment, for LXD
+/// hosts without Internet access.
+///
+/// # Examples
+///
+/// ```json
+/// {
+///     "image": { "source": "local:torrust-ubuntu-24.04" },
+///     "apt_mirror": "http://mirror.lab.internal/ubuntu",
+///     "registry_mirror": "https://registry.lab.internal"
+/// }
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, JsonSchema)]
+pub struct OfflineSection {
+    /// Image the instance is created from
+    pub image: OfflineImageSection,
+
+    /// Local apt mirror used instead of the Ubuntu archive
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub apt_mirror: Option<String>,
+
+    /// Absolute path of a directory of `.deb` files on the deployer machine
+    ///
+    /// The files are copied to the instance and installed instead of
+    /// downloading Docker and Docker Compose.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub packages_dir: Option<String>,
+
+    /// Registry mirror used instead of Docker Hub to pull container images
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub registry_mirror: Option<String>,
+}
+
+/// Image of the offline section (DTO)
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, JsonSchema)]
+pub struct OfflineImageSection {
+    /// Alias of an image imported into LXD beforehand, e.g. `local:torrust-ubuntu-24.04`
+    pub source: String,
+}
+
+impl TryFrom<OfflineSection> for OfflineMode {
+    type Error = CreateConfigError;
+
+    fn try_from(section: OfflineSection) -> Result<Self, Self::Error> {
+        let offline = OfflineMode::new(
+            &section.image.source,
+            section.apt_mirror.as_deref(),
+            section.packages_dir.map(PathBuf::from),
+            section.registry_mirror.as_deref(),
+        )
+        .map_err(|e| CreateConfigError::InvalidOfflineConfig(e.to_string()))?;
+
+        // The packages are copied from the deployer machine, so they must be there now
+        match offline.packages_dir() {
+            Some(path) if !path.is_dir() => Err(CreateConfigError::OfflinePackagesDirNotFound {
+                path: path.to_path_buf(),
+            }),
+            _ => Ok(offline),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn section(packages_dir: Option<String>) -> OfflineSection {
+        OfflineSection {
+            image: OfflineImageSection {
+                source: "local:torrust-ubuntu-24.04".to_string(),
+            },
+            apt_mirror: None,
+            packages_dir,
+            registry_mirror: Some("https://registry.lab.internal".to_string()),
+        }
+    }
+
+    #[test]
+    fn it_should_convert_the_section_to_the_domain_type() {
+        let packages_dir = tempfile::TempDir::new().unwrap();
+
+        let offline =
+            OfflineMode::try_from(section(Some(packages_dir.path().display().to_string())))
+                .unwrap();
+
+        assert_eq!(offline.image_alias(), "torrust-ubuntu-24.04");
+        assert_eq!(offline.packages_dir(), Some(packages_dir.path()));
+    }
+
+    #[test]
+    fn it_should_reject_a_remote_image_source() {
+        let mut section = section(None);
+        section.image.source = "ubuntu:24.04".to_string();
+
+        assert!(matches!(
+            OfflineMode::try_from(section),
+            Err(CreateConfigError::InvalidOfflineConfig(_))
+        ));
+    }
+
+    #[test]
+    fn it_should_reject_a_packages_dir_that_does_not_exist() {
+        assert!(matches!(
+            OfflineMode::try_from(section(Some("/nonexistent/debs".to_string()))),
+            Err(CreateConfigError::OfflinePackagesDirNotFound { .. })
+        ));
+    }
+}
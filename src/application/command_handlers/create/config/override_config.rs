@@ -0,0 +1,462 @@
+//! Configuration override value object
+//!
+//! This module provides the `EnvironmentOverrideConfig` type: a partial
+//! environment configuration read by the `configure` and `release` commands
+//! (`--override <file>`) to change the configuration of an existing
+//! environment without recreating it, e.g. to rotate the API admin token or
+//! upgrade the tracker.
+//!
+//! It has the sections of [`EnvironmentCreationConfig`], all optional. A
+//! section that is present replaces the persisted one; the tracker section is
+//! merged field by field (see [`PartialTrackerSection`]). The identity of the
+//! environment (`environment`, `ssh_credentials`) cannot be overridden.
+//!
+//! Sections cannot be removed by an override: an absent section and a `null`
+//! one both keep the persisted value.
+//!
+//! [`EnvironmentCreationConfig`]: super::EnvironmentCreationConfig
+
+use std::collections::BTreeMap;
+use std::path::{Path, PathBuf};
+
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+use super::backup::BackupSection;
+use super::cloud_init::CloudInitSection;
+use super::errors::{ConfigLoadError, CreateConfigError};
+use super::firewall::FirewallSection;
+use super::format::ConfigFormat;
+use super::grafana::GrafanaSection;
+use super::https::HttpsSection;
+use super::instance::InstanceSection;
+use super::offline::OfflineSection;
+use super::prometheus::PrometheusSection;
+use super::provider::ProviderSection;
+use super::provision::ProvisionSection;
+use super::proxy::ProxySection;
+use super::release::ReleaseSection;
+use super::system_tuning::SystemTuningSection;
+use super::traces::TracesSection;
+use super::tracker::PartialTrackerSection;
+use super::validated_params::validate_templates_override_dir;
+use super::validation::ConfigIssues;
+use crate::domain::environment::{Labels, UserInputs};
+use crate::domain::https::HttpsConfig;
+use crate::domain::provider::Provider;
+use crate::domain::template::ExtraTemplateVariables;
+
+/// Partial configuration overriding the persisted configuration of an environment
+///
+/// # Examples
+///
+/// ```rust
+/// use torrust_tracker_deployer_lib::application::command_handlers::create::config::{
+///     ConfigFormat, EnvironmentOverrideConfig,
+/// };
+///
+/// let json = r#"{
+///     "tracker": {
+///         "version": "v4.0.0",
+///         "http_api": { "admin_token": "MyNewAccessToken" }
+///     },
+///     "backup": { "schedule": "0 4 * * *", "retention_days": 14 }
+/// }"#;
+///
+/// let config = EnvironmentOverrideConfig::parse(json, ConfigFormat::Json)?;
+/// assert!(config.tracker.is_some());
+/// # Ok::<(), Box<dyn std::error::Error>>(())
+/// ```
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize, JsonSchema)]
+#[serde(deny_unknown_fields)]
+pub struct EnvironmentOverrideConfig {
+    /// Infrastructure provider (changes require a new instance)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub provider: Option<ProviderSection>,
+
+    /// Tracker fields to change
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub tracker: Option<PartialTrackerSection>,
+
+    /// Prometheus monitoring
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub prometheus: Option<PrometheusSection>,
+
+    /// Grafana dashboards
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub grafana: Option<GrafanaSection>,
+
+    /// HTTPS with Caddy
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub https: Option<HttpsSection>,
+
+    /// Database backups
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub backup: Option<BackupSection>,
+
+    /// Directory with template overrides (changes require a new instance)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub templates_override_dir: Option<String>,
+
+    /// Extra variables for the templates (changes require a new instance)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub extra_template_vars: Option<BTreeMap<String, serde_json::Value>>,
+
+    /// Provision workflow settings
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub provision: Option<ProvisionSection>,
+
+    /// Host firewall settings
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub firewall: Option<FirewallSection>,
+
+    /// Host system tuning
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub system_tuning: Option<SystemTuningSection>,
+
+    /// Cloud-init additions (changes require a new instance)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub cloud_init: Option<CloudInitSection>,
+
+    /// Instance sizing (changes require a new instance)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub instance: Option<InstanceSection>,
+
+    /// Offline mode (changes require a new instance)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub offline: Option<OfflineSection>,
+
+    /// Outbound HTTP proxy
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub proxy: Option<ProxySection>,
+
+    /// Container images and private registry
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub release: Option<ReleaseSection>,
+
+    /// Labels, replacing the persisted ones
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub labels: Option<BTreeMap<String, String>>,
+
+    /// Trace files location and retention
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub traces: Option<TracesSection>,
+}
+
+impl EnvironmentOverrideConfig {
+    /// Deserialize an [`EnvironmentOverrideConfig`] from a string in `format`
+    ///
+    /// # Errors
+    ///
+    /// Returns the [`ConfigLoadError`] parse error of `format` if the content
+    /// is malformed, has an unknown field or does not match the expected
+    /// structure.
+    pub fn parse(content: &str, format: ConfigFormat) -> Result<Self, ConfigLoadError> {
+        match format {
+            ConfigFormat::Json => serde_json::from_str(content)
+                .map_err(|source| ConfigLoadError::JsonParseFailed { source }),
+            ConfigFormat::Yaml => serde_yaml::from_str(content)
+                .map_err(|source| ConfigLoadError::YamlParseFailed { source }),
+            ConfigFormat::Toml => {
+                toml::from_str(content).map_err(|source| ConfigLoadError::TomlParseFailed {
+                    source: Box::new(source),
+                })
+            }
+        }
+    }
+
+    /// Load an [`EnvironmentOverrideConfig`] from a JSON, YAML or TOML file
+    ///
+    /// The format is detected as for the creation configuration (see
+    /// [`ConfigFormat::detect`]).
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ConfigLoadError`] if the file does not exist, cannot be read
+    /// or cannot be parsed.
+    pub fn from_file(path: &Path) -> Result<Self, ConfigLoadError> {
+        if !path.exists() {
+            return Err(ConfigLoadError::FileNotFound {
+                path: path.to_path_buf(),
+            });
+        }
+
+        let content =
+            std::fs::read_to_string(path).map_err(|source| ConfigLoadError::FileReadFailed {
+                path: path.to_path_buf(),
+                source,
+            })?;
+
+        Self::parse(&content, ConfigFormat::detect(path, &content))
+    }
+
+    /// Merge the override onto the user inputs of an environment
+    ///
+    /// Every present section is converted and validated as in the creation
+    /// configuration, then the merged user inputs are validated as a whole
+    /// (cross-service invariants, port conflicts, tracker image). The keys
+    /// minted on the tracker are kept.
+    ///
+    /// Which settings changed, and whether the environment can apply them
+    /// without a new instance, is left to the caller (see [`UserInputs::diff`]).
+    ///
+    /// # Errors
+    ///
+    /// Returns `CreateConfigError` if a section is invalid or the merged user
+    /// inputs violate an invariant. When several fields are invalid,
+    /// `CreateConfigError::InvalidConfiguration` lists them all with their
+    /// JSON paths.
+    #[allow(clippy::too_many_lines)] // One step per configuration section
+    pub fn apply_to(self, current: &UserInputs) -> Result<UserInputs, CreateConfigError> {
+        let mut issues = ConfigIssues::default();
+        let mut params = current.to_params();
+        let provider = current.provider();
+
+        if let Some(section) = self.provider {
+            if let Some(provider_config) = issues.check(
+                "provider",
+                section
+                    .with_generated_profile_name(current.name())
+                    .try_into(),
+            ) {
+                params.provider_config = provider_config;
+            }
+        }
+
+        if let Some(section) = self.tracker {
+            if let Some(tracker) = section.apply(current.tracker(), "tracker", &mut issues) {
+                params.tracker_config = tracker;
+            }
+        }
+
+        if let Some(section) = self.prometheus {
+            if let Some(prometheus) = issues.check("prometheus", section.try_into()) {
+                params.prometheus_config = Some(prometheus);
+            }
+        }
+
+        if let Some(section) = self.grafana {
+            if let Some(grafana) = issues.check("grafana", section.try_into()) {
+                params.grafana_config = Some(grafana);
+            }
+        }
+
+        if let Some(section) = self.https {
+            if let Some(https) = issues.check(
+                "https.admin_email",
+                HttpsConfig::new(section.admin_email, section.use_staging)
+                    .map_err(CreateConfigError::from),
+            ) {
+                params.https_config = Some(https);
+            }
+        }
+
+        if let Some(section) = self.backup {
+            if let Some(backup) = issues.check("backup", section.try_into()) {
+                params.backup_config = Some(backup);
+            }
+        }
+
+        if let Some(dir) = self.templates_override_dir.map(PathBuf::from) {
+            if let Some(dir) = issues.check(
+                "templates_override_dir",
+                validate_templates_override_dir(dir),
+            ) {
+                params.templates_override_dir = Some(dir);
+            }
+        }
+
+        if let Some(variables) = self.extra_template_vars {
+            if let Some(variables) = issues.check(
+                "extra_template_vars",
+                ExtraTemplateVariables::try_from(variables).map_err(CreateConfigError::from),
+            ) {
+                params.extra_template_variables = variables;
+            }
+        }
+
+        if let Some(section) = self.provision {
+            if let Some(provision) = issues.check("provision", section.try_into()) {
+                params.provision_config = provision;
+            }
+        }
+
+        if let Some(section) = self.firewall {
+            if let Some(firewall) = issues.check("firewall", section.try_into()) {
+                params.firewall_config = firewall;
+            }
+        }
+
+        // Docker containers share the host kernel
+        if let Some(section) = self.system_tuning {
+            if provider == Provider::Docker {
+                issues.push(
+                    "system_tuning",
+                    CreateConfigError::SystemTuningNotSupported {
+                        provider: provider.to_string(),
+                    },
+                );
+            } else if let Some(system_tuning) = issues.check("system_tuning", section.try_into()) {
+                params.system_tuning = system_tuning;
+            }
+        }
+
+        if let Some(section) = self.cloud_init {
+            if let Some(cloud_init) = issues.check("cloud_init", section.try_into()) {
+                params.cloud_init = cloud_init;
+            }
+        }
+
+        if let Some(section) = self.instance {
+            if let Some(instance_resources) = issues.check("instance", section.try_into()) {
+                params.instance_resources = instance_resources;
+            }
+        }
+
+        if let Some(section) = self.offline {
+            if let Some(offline) = issues.check("offline", section.try_into()) {
+                params.offline = Some(offline);
+            }
+        }
+
+        if let Some(section) = self.proxy {
+            if let Some(proxy) = issues.check("proxy", section.try_into()) {
+                params.proxy = Some(proxy);
+            }
+        }
+
+        if let Some(section) = self.release {
+            if let Some(release) = issues.check("release", section.try_into()) {
+                params.release = Some(release);
+            }
+        }
+
+        if let Some(labels) = self.labels {
+            if let Some(labels) = issues.check(
+                "labels",
+                Labels::try_from(labels).map_err(CreateConfigError::from),
+            ) {
+                params.labels = labels;
+            }
+        }
+
+        if let Some(section) = self.traces {
+            if let Some(traces) = issues.check("traces", section.try_into()) {
+                params.traces = traces;
+            }
+        }
+
+        // Only one setting may select the tracker image
+        if let Some(release) = &params.release {
+            if params.tracker_config.version().is_some() && release.images().tracker.is_some() {
+                issues.push(
+                    "release.images.tracker",
+                    CreateConfigError::TrackerImageConflict,
+                );
+            }
+        }
+
+        // An offline deployment fails on the first download it cannot replace
+        if let Some(offline) = &params.offline {
+            let uncovered = offline.uncovered(params.external_resources());
+            if !uncovered.is_empty() {
+                issues.push(
+                    "offline",
+                    CreateConfigError::OfflineResourcesRequired {
+                        resources: uncovered
+                            .iter()
+                            .map(ToString::to_string)
+                            .collect::<Vec<_>>()
+                            .join("; "),
+                    },
+                );
+            }
+        }
+
+        let params = issues.finish(Some(params))?;
+        let mut user_inputs = UserInputs::from_params(params).map_err(CreateConfigError::from)?;
+        user_inputs.set_tracker_auth_keys(current.tracker_auth_keys().to_vec());
+
+        Ok(user_inputs)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::environment::testing::EnvironmentTestBuilder;
+    use crate::domain::environment::ChangeImpact;
+
+    fn current_inputs() -> UserInputs {
+        let (environment, _, _, _temp_dir) =
+            EnvironmentTestBuilder::new().build_with_custom_paths();
+        environment.context().user_inputs.clone()
+    }
+
+    fn apply(json: &str, current: &UserInputs) -> Result<UserInputs, CreateConfigError> {
+        EnvironmentOverrideConfig::parse(json, ConfigFormat::Json)
+            .unwrap()
+            .apply_to(current)
+    }
+
+    #[test]
+    fn it_should_keep_the_user_inputs_when_the_override_is_empty() {
+        let current = current_inputs();
+
+        let merged = EnvironmentOverrideConfig::default()
+            .apply_to(&current)
+            .unwrap();
+
+        assert!(current.diff(&merged).is_empty());
+    }
+
+    #[test]
+    fn it_should_change_only_the_admin_token_when_only_the_token_is_overridden() {
+        let current = current_inputs();
+
+        let merged = apply(
+            r#"{ "tracker": { "http_api": { "admin_token": "NewToken" } } }"#,
+            &current,
+        )
+        .unwrap();
+
+        let diff = current.diff(&merged);
+        assert_eq!(diff.changes().len(), 1);
+        assert_eq!(diff.changes()[0].path, "tracker.http_api.admin_token");
+        assert_eq!(diff.impact(), Some(ChangeImpact::RequiresRelease));
+    }
+
+    #[test]
+    fn it_should_report_a_changed_instance_size_as_requiring_a_new_instance() {
+        let current = current_inputs();
+
+        let merged = apply(r#"{ "instance": { "cpus": 8 } }"#, &current).unwrap();
+
+        let diff = current.diff(&merged);
+        assert_eq!(diff.impact(), Some(ChangeImpact::RequiresProvision));
+    }
+
+    #[test]
+    fn it_should_reject_the_sections_identifying_the_environment() {
+        let result = EnvironmentOverrideConfig::parse(
+            r#"{ "environment": { "name": "other-env" } }"#,
+            ConfigFormat::Json,
+        );
+
+        assert!(matches!(
+            result,
+            Err(ConfigLoadError::JsonParseFailed { .. })
+        ));
+    }
+
+    #[test]
+    fn it_should_validate_the_merged_user_inputs_as_a_whole() {
+        // No service of the persisted configuration uses the TLS proxy
+        let result = apply(
+            r#"{ "https": { "admin_email": "admin@example.com" } }"#,
+            &current_inputs(),
+        );
+
+        assert!(result.is_err());
+    }
+}
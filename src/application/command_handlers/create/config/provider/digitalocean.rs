@@ -0,0 +1,95 @@
+//! `DigitalOcean` Provider Configuration Section (Application Layer)
+//!
+//! This module contains the configuration section for the `DigitalOcean` provider.
+//! Uses raw `String` fields for JSON deserialization, which are then validated
+//! when converting to domain types.
+
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+use crate::application::command_handlers::create::config::SecretSource;
+
+/// `DigitalOcean`-specific configuration section
+///
+/// Uses raw `String` fields for JSON deserialization. Convert to domain
+/// `DigitalOceanConfig` via `ProviderSection::to_provider_config()`.
+///
+/// # Examples
+///
+/// ```rust
+/// use torrust_tracker_deployer_lib::application::command_handlers::create::config::DigitalOceanProviderSection;
+///
+/// let section = DigitalOceanProviderSection {
+///     api_token: "your-api-token".into(),
+///     droplet_size: "s-1vcpu-2gb".to_string(),
+///     region: "fra1".to_string(),
+///     image: "ubuntu-24-04-x64".to_string(),
+///     reserved_ip: Some("203.0.113.10".to_string()),
+/// };
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, JsonSchema)]
+pub struct DigitalOceanProviderSection {
+    /// `DigitalOcean` API token, inline or as an environment variable or file reference.
+    ///
+    /// Resolved and converted to domain `ApiToken` at the DTO-to-domain boundary.
+    pub api_token: SecretSource,
+
+    /// `DigitalOcean` droplet size slug (e.g., "s-1vcpu-2gb", "s-2vcpu-4gb").
+    pub droplet_size: String,
+
+    /// `DigitalOcean` region slug (e.g., "fra1", "ams3", "nyc3").
+    pub region: String,
+
+    /// `DigitalOcean` image slug (e.g., "ubuntu-24-04-x64").
+    pub image: String,
+
+    /// Existing reserved IPv4 address to assign to the droplet (optional).
+    ///
+    /// Validated as an IPv4 address at the DTO-to-domain boundary.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub reserved_ip: Option<String>,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn create_digitalocean_section() -> DigitalOceanProviderSection {
+        DigitalOceanProviderSection {
+            api_token: "token".into(),
+            droplet_size: "s-1vcpu-2gb".to_string(),
+            region: "fra1".to_string(),
+            image: "ubuntu-24-04-x64".to_string(),
+            reserved_ip: None,
+        }
+    }
+
+    #[test]
+    fn it_should_serialize_to_json() {
+        let section = create_digitalocean_section();
+        let json = serde_json::to_string(&section).unwrap();
+        assert!(json.contains("\"api_token\":\"token\""));
+        assert!(json.contains("\"droplet_size\":\"s-1vcpu-2gb\""));
+        assert!(json.contains("\"region\":\"fra1\""));
+        assert!(json.contains("\"image\":\"ubuntu-24-04-x64\""));
+        assert!(!json.contains("reserved_ip"));
+    }
+
+    #[test]
+    fn it_should_deserialize_from_json() {
+        let json = r#"{"api_token":"token","droplet_size":"s-1vcpu-2gb","region":"fra1","image":"ubuntu-24-04-x64","reserved_ip":"203.0.113.10"}"#;
+        let section: DigitalOceanProviderSection = serde_json::from_str(json).unwrap();
+        assert_eq!(section.api_token, SecretSource::from("token"));
+        assert_eq!(section.droplet_size, "s-1vcpu-2gb");
+        assert_eq!(section.region, "fra1");
+        assert_eq!(section.image, "ubuntu-24-04-x64");
+        assert_eq!(section.reserved_ip.as_deref(), Some("203.0.113.10"));
+    }
+
+    #[test]
+    fn it_should_deserialize_from_json_without_reserved_ip() {
+        let json = r#"{"api_token":"token","droplet_size":"s-1vcpu-2gb","region":"fra1","image":"ubuntu-24-04-x64"}"#;
+        let section: DigitalOceanProviderSection = serde_json::from_str(json).unwrap();
+        assert_eq!(section.reserved_ip, None);
+    }
+}
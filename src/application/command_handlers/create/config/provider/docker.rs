@@ -0,0 +1,50 @@
+//! Docker Provider Configuration Section (Application Layer)
+//!
+//! This module contains the configuration section for the Docker provider.
+//! The Docker provider deploys into a local container instead of a VM and is
+//! intended for fast smoke tests only.
+
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+/// Docker-specific configuration section
+///
+/// Uses raw `String` for JSON deserialization. Convert to domain `DockerConfig`
+/// via `ProviderSection::to_provider_config()`.
+///
+/// # Examples
+///
+/// ```rust
+/// use torrust_tracker_deployer_lib::application::command_handlers::create::config::DockerProviderSection;
+///
+/// let section = DockerProviderSection {
+///     image: "torrust-provisioned-instance:latest".to_string(),
+/// };
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, JsonSchema)]
+pub struct DockerProviderSection {
+    /// Local Docker image used for the instance container
+    /// (e.g., "torrust-provisioned-instance:latest").
+    pub image: String,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn it_should_serialize_to_json() {
+        let section = DockerProviderSection {
+            image: "torrust-provisioned-instance:latest".to_string(),
+        };
+        let json = serde_json::to_string(&section).unwrap();
+        assert!(json.contains("\"image\":\"torrust-provisioned-instance:latest\""));
+    }
+
+    #[test]
+    fn it_should_deserialize_from_json() {
+        let json = r#"{"image":"torrust-provisioned-instance:latest"}"#;
+        let section: DockerProviderSection = serde_json::from_str(json).unwrap();
+        assert_eq!(section.image, "torrust-provisioned-instance:latest");
+    }
+}
@@ -7,7 +7,7 @@
 use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
 
-use crate::shared::PlainApiToken;
+use crate::application::command_handlers::create::config::SecretSource;
 
 /// Hetzner-specific configuration section
 ///
@@ -20,7 +20,7 @@ use crate::shared::PlainApiToken;
 /// use torrust_tracker_deployer_lib::application::command_handlers::create::config::HetznerProviderSection;
 ///
 /// let section = HetznerProviderSection {
-///     api_token: "your-api-token".to_string(),
+///     api_token: "your-api-token".into(),
 ///     server_type: "cx22".to_string(),
 ///     location: "nbg1".to_string(),
 ///     image: "ubuntu-24.04".to_string(),
@@ -28,11 +28,10 @@ use crate::shared::PlainApiToken;
 /// ```
 #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, JsonSchema)]
 pub struct HetznerProviderSection {
-    /// Hetzner API token in plain text format (DTO layer).
+    /// Hetzner API token, inline or as an environment variable or file reference.
     ///
-    /// This uses [`PlainApiToken`] to mark it as a transparent secret during
-    /// deserialization. Convert to domain `ApiToken` at the DTO-to-domain boundary.
-    pub api_token: PlainApiToken,
+    /// Resolved and converted to domain `ApiToken` at the DTO-to-domain boundary.
+    pub api_token: SecretSource,
 
     /// Hetzner server type (e.g., "cx22", "cx32", "cpx11").
     pub server_type: String,
@@ -50,7 +49,7 @@ mod tests {
 
     fn create_hetzner_section() -> HetznerProviderSection {
         HetznerProviderSection {
-            api_token: "token".to_string(),
+            api_token: "token".into(),
             server_type: "cx22".to_string(),
             location: "nbg1".to_string(),
             image: "ubuntu-24.04".to_string(),
@@ -71,7 +70,7 @@ mod tests {
     fn it_should_deserialize_from_json() {
         let json = r#"{"api_token":"token","server_type":"cx22","location":"nbg1","image":"ubuntu-24.04"}"#;
         let section: HetznerProviderSection = serde_json::from_str(json).unwrap();
-        assert_eq!(section.api_token, "token");
+        assert_eq!(section.api_token, SecretSource::from("token"));
         assert_eq!(section.server_type, "cx22");
         assert_eq!(section.location, "nbg1");
         assert_eq!(section.image, "ubuntu-24.04");
@@ -0,0 +1,141 @@
+//! Libvirt Provider Configuration Section (Application Layer)
+//!
+//! This module contains the configuration section for the libvirt provider.
+//! The libvirt provider deploys into a local KVM virtual machine, for hosts
+//! where LXD is not available.
+
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+use crate::application::command_handlers::create::config::CreateConfigError;
+use crate::domain::provider::{
+    LibvirtConfig, DEFAULT_LIBVIRT_IMAGE, DEFAULT_LIBVIRT_NETWORK, DEFAULT_LIBVIRT_POOL,
+};
+
+/// Libvirt-specific configuration section
+///
+/// Uses raw `String` for JSON deserialization. Every field is optional and
+/// defaults to the `default` storage pool and network of the system libvirt
+/// daemon and to the Ubuntu 24.04 cloud image. Convert to domain
+/// `LibvirtConfig` via `ProviderConfig::try_from()`.
+///
+/// # Examples
+///
+/// ```rust
+/// use torrust_tracker_deployer_lib::application::command_handlers::create::config::LibvirtProviderSection;
+///
+/// let section = LibvirtProviderSection {
+///     pool: None,
+///     network_name: Some("torrust".to_string()),
+///     image: Some("/var/lib/libvirt/images/noble-server-cloudimg-amd64.img".to_string()),
+/// };
+/// ```
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize, JsonSchema)]
+pub struct LibvirtProviderSection {
+    /// Storage pool the instance disk and cloud-init ISO are created in
+    /// (default: "default").
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub pool: Option<String>,
+
+    /// Libvirt network the instance is attached to, with DHCP enabled
+    /// (default: "default").
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub network_name: Option<String>,
+
+    /// Cloud image the instance disk is created from: an absolute path on the
+    /// host or an HTTP(S) URL (default: the Ubuntu 24.04 cloud image).
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub image: Option<String>,
+}
+
+impl TryFrom<LibvirtProviderSection> for LibvirtConfig {
+    type Error = CreateConfigError;
+
+    fn try_from(section: LibvirtProviderSection) -> Result<Self, Self::Error> {
+        let pool = section
+            .pool
+            .unwrap_or_else(|| DEFAULT_LIBVIRT_POOL.to_string());
+        let network_name = section
+            .network_name
+            .unwrap_or_else(|| DEFAULT_LIBVIRT_NETWORK.to_string());
+        let image = section
+            .image
+            .unwrap_or_else(|| DEFAULT_LIBVIRT_IMAGE.to_string());
+
+        if pool.trim().is_empty() {
+            return Err(CreateConfigError::InvalidLibvirtConfig(
+                "the storage pool name cannot be empty".to_string(),
+            ));
+        }
+
+        if network_name.trim().is_empty() {
+            return Err(CreateConfigError::InvalidLibvirtConfig(
+                "the network name cannot be empty".to_string(),
+            ));
+        }
+
+        let is_url = image.starts_with("http://") || image.starts_with("https://");
+        if !is_url && !image.starts_with('/') {
+            return Err(CreateConfigError::InvalidLibvirtConfig(format!(
+                "the image '{image}' must be an absolute path or an HTTP(S) URL"
+            )));
+        }
+
+        Ok(Self {
+            pool,
+            network_name,
+            image,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn it_should_default_every_field_when_omitted() {
+        let section: LibvirtProviderSection = serde_json::from_str("{}").unwrap();
+
+        let config = LibvirtConfig::try_from(section).unwrap();
+
+        assert_eq!(config, LibvirtConfig::default());
+    }
+
+    #[test]
+    fn it_should_keep_the_configured_fields() {
+        let json = r#"{"pool":"images","network_name":"torrust","image":"/srv/noble.img"}"#;
+        let section: LibvirtProviderSection = serde_json::from_str(json).unwrap();
+
+        let config = LibvirtConfig::try_from(section).unwrap();
+
+        assert_eq!(config.pool, "images");
+        assert_eq!(config.network_name, "torrust");
+        assert_eq!(config.image, "/srv/noble.img");
+    }
+
+    #[test]
+    fn it_should_reject_a_relative_image_path() {
+        let section = LibvirtProviderSection {
+            image: Some("images/noble.img".to_string()),
+            ..LibvirtProviderSection::default()
+        };
+
+        let result = LibvirtConfig::try_from(section);
+
+        assert!(matches!(
+            result,
+            Err(CreateConfigError::InvalidLibvirtConfig(reason)) if reason.contains("absolute path")
+        ));
+    }
+
+    #[test]
+    fn it_should_reject_an_empty_network_name() {
+        let section = LibvirtProviderSection {
+            network_name: Some(String::new()),
+            ..LibvirtProviderSection::default()
+        };
+
+        assert!(LibvirtConfig::try_from(section).is_err());
+    }
+}
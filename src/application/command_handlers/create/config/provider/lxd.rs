@@ -4,9 +4,17 @@
 //! Uses raw `String` for JSON deserialization, which is then validated
 //! when converting to domain types.
 
+use std::convert::TryFrom;
+use std::path::PathBuf;
+
 use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
 
+use crate::application::command_handlers::create::config::errors::CreateConfigError;
+use crate::application::command_handlers::create::config::SecretSource;
+use crate::domain::provider::LxdRemote;
+use crate::shared::secrets::Password;
+
 /// LXD-specific configuration section
 ///
 /// Uses raw `String` for JSON deserialization. Convert to domain `LxdConfig`
@@ -18,13 +26,100 @@ use serde::{Deserialize, Serialize};
 /// use torrust_tracker_deployer_lib::application::command_handlers::create::config::LxdProviderSection;
 ///
 /// let section = LxdProviderSection {
-///     profile_name: "torrust-profile-dev".to_string(),
+///     profile_name: Some("torrust-profile-dev".to_string()),
+///     remote: None,
 /// };
 /// ```
 #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, JsonSchema)]
 pub struct LxdProviderSection {
     /// LXD profile name (raw string - validated on conversion).
-    pub profile_name: String,
+    ///
+    /// If not provided, generated as `lxd-{env_name}`.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub profile_name: Option<String>,
+
+    /// LXD server to create the instance on, instead of the local daemon.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub remote: Option<LxdRemoteSection>,
+}
+
+/// LXD remote section (DTO)
+///
+/// Either the name of a remote already configured in the LXD client, or a
+/// remote with the settings needed to add it.
+///
+/// # Examples
+///
+/// ```json
+/// "bigbox"
+/// ```
+///
+/// ```json
+/// {
+///     "name": "bigbox",
+///     "address": "https://bigbox.lan:8443",
+///     "trust_token": { "env": "LXD_TRUST_TOKEN" }
+/// }
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, JsonSchema)]
+#[serde(untagged)]
+pub enum LxdRemoteSection {
+    /// Name of a remote listed by `lxc remote list`
+    Name(String),
+
+    /// Remote with explicit settings
+    Custom(CustomLxdRemoteSection),
+}
+
+/// LXD remote with explicit settings (DTO)
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, JsonSchema)]
+#[serde(deny_unknown_fields)]
+pub struct CustomLxdRemoteSection {
+    /// Name of the remote in the LXD client configuration
+    pub name: String,
+
+    /// HTTPS address of the LXD server API (e.g. `https://bigbox.lan:8443`)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub address: Option<String>,
+
+    /// Token issued on the server with `lxc config trust add`, used to add
+    /// the remote when it is not configured in the LXD client yet.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub trust_token: Option<SecretSource>,
+
+    /// Directory of the LXD client configuration, holding the remotes and
+    /// the client certificate (`client.crt` and `client.key`).
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub config_dir: Option<PathBuf>,
+}
+
+impl TryFrom<LxdRemoteSection> for LxdRemote {
+    type Error = CreateConfigError;
+
+    fn try_from(section: LxdRemoteSection) -> Result<Self, Self::Error> {
+        let section = match section {
+            LxdRemoteSection::Name(name) => CustomLxdRemoteSection {
+                name,
+                address: None,
+                trust_token: None,
+                config_dir: None,
+            },
+            LxdRemoteSection::Custom(section) => section,
+        };
+
+        let trust_token = section
+            .trust_token
+            .map(|token| token.resolve().map(Password::new))
+            .transpose()?;
+
+        LxdRemote::new(
+            section.name,
+            section.address,
+            section.config_dir,
+            trust_token,
+        )
+        .map_err(|e| CreateConfigError::InvalidLxdRemote(e.to_string()))
+    }
 }
 
 #[cfg(test)]
@@ -34,23 +129,33 @@ mod tests {
     #[test]
     fn it_should_serialize_to_json() {
         let section = LxdProviderSection {
-            profile_name: "test".to_string(),
+            profile_name: Some("test".to_string()),
+            remote: None,
         };
         let json = serde_json::to_string(&section).unwrap();
         assert!(json.contains("\"profile_name\":\"test\""));
     }
 
+    #[test]
+    fn it_should_leave_the_profile_name_unset_when_omitted() {
+        let section: LxdProviderSection = serde_json::from_str("{}").unwrap();
+
+        assert_eq!(section.profile_name, None);
+        assert_eq!(serde_json::to_string(&section).unwrap(), "{}");
+    }
+
     #[test]
     fn it_should_deserialize_from_json() {
         let json = r#"{"profile_name":"torrust-profile"}"#;
         let section: LxdProviderSection = serde_json::from_str(json).unwrap();
-        assert_eq!(section.profile_name, "torrust-profile");
+        assert_eq!(section.profile_name.as_deref(), Some("torrust-profile"));
     }
 
     #[test]
     fn it_should_be_cloneable() {
         let section = LxdProviderSection {
-            profile_name: "test".to_string(),
+            profile_name: Some("test".to_string()),
+            remote: None,
         };
         let cloned = section.clone();
         assert_eq!(section, cloned);
@@ -59,7 +164,8 @@ mod tests {
     #[test]
     fn it_should_implement_debug_trait() {
         let section = LxdProviderSection {
-            profile_name: "test".to_string(),
+            profile_name: Some("test".to_string()),
+            remote: None,
         };
         let debug = format!("{section:?}");
         assert!(debug.contains("LxdProviderSection"));
@@ -12,6 +12,9 @@
 //! Each provider has its own submodule for extensibility:
 //! - `lxd` - LXD provider configuration section
 //! - `hetzner` - Hetzner provider configuration section
+//! - `digitalocean` - `DigitalOcean` provider configuration section
+//! - `docker` - Docker provider configuration section (testing only)
+//! - `libvirt` - Libvirt provider configuration section
 //!
 //! # Layer Separation
 //!
@@ -41,11 +44,17 @@
 //! assert_eq!(config.provider_name(), "lxd");
 //! ```
 
+mod digitalocean;
+mod docker;
 mod hetzner;
+mod libvirt;
 mod lxd;
 
+pub use digitalocean::DigitalOceanProviderSection;
+pub use docker::DockerProviderSection;
 pub use hetzner::HetznerProviderSection;
-pub use lxd::LxdProviderSection;
+pub use libvirt::LibvirtProviderSection;
+pub use lxd::{CustomLxdRemoteSection, LxdProviderSection, LxdRemoteSection};
 
 use std::convert::TryFrom;
 
@@ -53,8 +62,11 @@ use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
 
 use crate::application::command_handlers::create::config::CreateConfigError;
-use crate::domain::provider::{HetznerConfig, LxdConfig, Provider, ProviderConfig};
-use crate::domain::ProfileName;
+use crate::domain::provider::{
+    DigitalOceanConfig, DockerConfig, HetznerConfig, LibvirtConfig, LxdConfig, LxdRemote, Provider,
+    ProviderConfig,
+};
+use crate::domain::{EnvironmentName, ProfileName};
 use crate::shared::ApiToken;
 
 /// Provider-specific configuration section
@@ -78,7 +90,8 @@ use crate::shared::ApiToken;
 /// use std::convert::TryInto;
 ///
 /// let section = ProviderSection::Lxd(LxdProviderSection {
-///     profile_name: "torrust-profile-dev".to_string(),
+///     profile_name: Some("torrust-profile-dev".to_string()),
+///     remote: None,
 /// });
 ///
 /// let config: ProviderConfig = section.try_into().unwrap();
@@ -94,6 +107,18 @@ pub enum ProviderSection {
     /// Hetzner provider configuration
     #[serde(rename = "hetzner")]
     Hetzner(HetznerProviderSection),
+
+    /// `DigitalOcean` provider configuration
+    #[serde(rename = "digitalocean")]
+    DigitalOcean(DigitalOceanProviderSection),
+
+    /// Docker provider configuration (testing only)
+    #[serde(rename = "docker")]
+    Docker(DockerProviderSection),
+
+    /// Libvirt provider configuration
+    #[serde(rename = "libvirt")]
+    Libvirt(LibvirtProviderSection),
 }
 
 impl ProviderSection {
@@ -108,7 +133,8 @@ impl ProviderSection {
     /// use torrust_tracker_deployer_lib::domain::provider::Provider;
     ///
     /// let section = ProviderSection::Lxd(LxdProviderSection {
-    ///     profile_name: "test".to_string(),
+    ///     profile_name: Some("test".to_string()),
+    ///     remote: None,
     /// });
     /// assert_eq!(section.provider(), Provider::Lxd);
     /// ```
@@ -117,6 +143,31 @@ impl ProviderSection {
         match self {
             Self::Lxd(_) => Provider::Lxd,
             Self::Hetzner(_) => Provider::Hetzner,
+            Self::DigitalOcean(_) => Provider::DigitalOcean,
+            Self::Docker(_) => Provider::Docker,
+            Self::Libvirt(_) => Provider::Libvirt,
+        }
+    }
+
+    /// Fill in the LXD profile name generated from the environment name
+    ///
+    /// A profile name given in the configuration is kept. Other providers
+    /// have no profile and are returned unchanged.
+    #[must_use]
+    pub fn with_generated_profile_name(self, environment_name: &EnvironmentName) -> Self {
+        match self {
+            Self::Lxd(LxdProviderSection {
+                profile_name: None,
+                remote,
+            }) => Self::Lxd(LxdProviderSection {
+                profile_name: Some(format!(
+                    "{}{}",
+                    ProfileName::GENERATED_PREFIX,
+                    environment_name.as_str()
+                )),
+                remote,
+            }),
+            section => section,
         }
     }
 }
@@ -127,18 +178,50 @@ impl TryFrom<ProviderSection> for ProviderConfig {
     fn try_from(section: ProviderSection) -> Result<Self, Self::Error> {
         match section {
             ProviderSection::Lxd(lxd) => {
-                let profile_name = ProfileName::new(lxd.profile_name)?;
-                Ok(Self::Lxd(LxdConfig { profile_name }))
+                // Unset only if `with_generated_profile_name` was not applied
+                let profile_name = ProfileName::new(lxd.profile_name.unwrap_or_default())?;
+                let remote = lxd.remote.map(LxdRemote::try_from).transpose()?;
+                Ok(Self::Lxd(LxdConfig {
+                    profile_name,
+                    remote,
+                }))
             }
             ProviderSection::Hetzner(hetzner) => {
                 // Note: Future improvement could add validation for these fields
                 Ok(Self::Hetzner(HetznerConfig {
-                    api_token: ApiToken::from(hetzner.api_token),
+                    api_token: ApiToken::from(hetzner.api_token.resolve()?),
                     server_type: hetzner.server_type,
                     location: hetzner.location,
                     image: hetzner.image,
                 }))
             }
+            ProviderSection::DigitalOcean(digitalocean) => {
+                let reserved_ip = digitalocean
+                    .reserved_ip
+                    .map(|address| {
+                        address
+                            .parse()
+                            .map_err(|source| CreateConfigError::InvalidReservedIp {
+                                address,
+                                source,
+                            })
+                    })
+                    .transpose()?;
+
+                Ok(Self::DigitalOcean(DigitalOceanConfig {
+                    api_token: ApiToken::from(digitalocean.api_token.resolve()?),
+                    droplet_size: digitalocean.droplet_size,
+                    region: digitalocean.region,
+                    image: digitalocean.image,
+                    reserved_ip,
+                }))
+            }
+            ProviderSection::Docker(docker) => Ok(Self::Docker(DockerConfig {
+                image: docker.image,
+            })),
+            ProviderSection::Libvirt(libvirt) => {
+                Ok(Self::Libvirt(LibvirtConfig::try_from(libvirt)?))
+            }
         }
     }
 }
@@ -149,13 +232,14 @@ mod tests {
 
     fn create_lxd_section() -> ProviderSection {
         ProviderSection::Lxd(LxdProviderSection {
-            profile_name: "torrust-profile".to_string(),
+            profile_name: Some("torrust-profile".to_string()),
+            remote: None,
         })
     }
 
     fn create_hetzner_section() -> ProviderSection {
         ProviderSection::Hetzner(HetznerProviderSection {
-            api_token: "test-token".to_string(),
+            api_token: "test-token".into(),
             server_type: "cx22".to_string(),
             location: "nbg1".to_string(),
             image: "ubuntu-24.04".to_string(),
@@ -181,7 +265,7 @@ mod tests {
 
         assert_eq!(section.provider(), Provider::Lxd);
         if let ProviderSection::Lxd(lxd) = section {
-            assert_eq!(lxd.profile_name, "torrust-profile");
+            assert_eq!(lxd.profile_name.as_deref(), Some("torrust-profile"));
         } else {
             panic!("Expected LXD section");
         }
@@ -200,7 +284,7 @@ mod tests {
 
         assert_eq!(section.provider(), Provider::Hetzner);
         if let ProviderSection::Hetzner(hetzner) = section {
-            assert_eq!(hetzner.api_token, "token123");
+            assert_eq!(hetzner.api_token, "token123".into());
             assert_eq!(hetzner.server_type, "cx32");
             assert_eq!(hetzner.location, "fsn1");
             assert_eq!(hetzner.image, "ubuntu-24.04");
@@ -258,10 +342,113 @@ mod tests {
         assert_eq!(hetzner.image, "ubuntu-24.04");
     }
 
+    #[test]
+    fn it_should_convert_digitalocean_section_to_domain_config() {
+        let section = ProviderSection::DigitalOcean(DigitalOceanProviderSection {
+            api_token: "test-token".into(),
+            droplet_size: "s-1vcpu-2gb".to_string(),
+            region: "fra1".to_string(),
+            image: "ubuntu-24-04-x64".to_string(),
+            reserved_ip: Some("203.0.113.10".to_string()),
+        });
+        let config: ProviderConfig = section.try_into().unwrap();
+
+        assert_eq!(config.provider(), Provider::DigitalOcean);
+        assert_eq!(config.provider_name(), "digitalocean");
+
+        let digitalocean = config.as_digitalocean().unwrap();
+        assert_eq!(digitalocean.api_token.expose_secret(), "test-token");
+        assert_eq!(digitalocean.droplet_size, "s-1vcpu-2gb");
+        assert_eq!(digitalocean.region, "fra1");
+        assert_eq!(
+            digitalocean.reserved_ip,
+            Some("203.0.113.10".parse().unwrap())
+        );
+    }
+
+    #[test]
+    fn it_should_fail_conversion_when_digitalocean_reserved_ip_is_not_ipv4() {
+        let section = ProviderSection::DigitalOcean(DigitalOceanProviderSection {
+            api_token: "test-token".into(),
+            droplet_size: "s-1vcpu-2gb".to_string(),
+            region: "fra1".to_string(),
+            image: "ubuntu-24-04-x64".to_string(),
+            reserved_ip: Some("2001:db8::1".to_string()),
+        });
+        let result: Result<ProviderConfig, _> = section.try_into();
+
+        assert!(matches!(
+            result,
+            Err(CreateConfigError::InvalidReservedIp { .. })
+        ));
+    }
+
+    #[test]
+    fn it_should_convert_docker_section_to_domain_config() {
+        let json = r#"{"provider": "docker", "image": "torrust-provisioned-instance:latest"}"#;
+        let section: ProviderSection = serde_json::from_str(json).unwrap();
+        assert_eq!(section.provider(), Provider::Docker);
+
+        let config: ProviderConfig = section.try_into().unwrap();
+
+        assert_eq!(config.provider_name(), "docker");
+        assert_eq!(
+            config.as_docker().unwrap().image,
+            "torrust-provisioned-instance:latest"
+        );
+    }
+
+    #[test]
+    fn it_should_convert_libvirt_section_to_domain_config() {
+        let json = r#"{"provider": "libvirt", "network_name": "torrust"}"#;
+        let section: ProviderSection = serde_json::from_str(json).unwrap();
+        assert_eq!(section.provider(), Provider::Libvirt);
+
+        let config: ProviderConfig = section.try_into().unwrap();
+
+        assert_eq!(config.provider_name(), "libvirt");
+        let libvirt = config.as_libvirt().unwrap();
+        assert_eq!(libvirt.network_name, "torrust");
+        assert_eq!(libvirt.pool, "default");
+    }
+
+    #[test]
+    fn it_should_generate_the_lxd_profile_name_when_omitted() {
+        let section = ProviderSection::Lxd(LxdProviderSection {
+            profile_name: None,
+            remote: None,
+        });
+        let environment_name = EnvironmentName::new("staging").unwrap();
+
+        let config: ProviderConfig = section
+            .with_generated_profile_name(&environment_name)
+            .try_into()
+            .unwrap();
+
+        assert_eq!(
+            config.as_lxd().unwrap().profile_name.as_str(),
+            "lxd-staging"
+        );
+    }
+
+    #[test]
+    fn it_should_keep_a_configured_lxd_profile_name() {
+        let section = create_lxd_section();
+        let environment_name = EnvironmentName::new("staging").unwrap();
+
+        assert_eq!(
+            section
+                .clone()
+                .with_generated_profile_name(&environment_name),
+            section
+        );
+    }
+
     #[test]
     fn it_should_fail_conversion_when_lxd_profile_name_is_empty() {
         let section = ProviderSection::Lxd(LxdProviderSection {
-            profile_name: String::new(), // Empty is invalid
+            profile_name: Some(String::new()), // Empty is invalid,
+            remote: None,
         });
         let result: Result<ProviderConfig, _> = section.try_into();
         assert!(result.is_err());
@@ -270,7 +457,8 @@ mod tests {
     #[test]
     fn it_should_fail_conversion_when_lxd_profile_name_starts_with_dash() {
         let section = ProviderSection::Lxd(LxdProviderSection {
-            profile_name: "-invalid".to_string(),
+            profile_name: Some("-invalid".to_string()),
+            remote: None,
         });
         let result: Result<ProviderConfig, _> = section.try_into();
         assert!(result.is_err());
@@ -279,7 +467,8 @@ mod tests {
     #[test]
     fn it_should_fail_conversion_when_lxd_profile_name_ends_with_dash() {
         let section = ProviderSection::Lxd(LxdProviderSection {
-            profile_name: "invalid-".to_string(),
+            profile_name: Some("invalid-".to_string()),
+            remote: None,
         });
         let result: Result<ProviderConfig, _> = section.try_into();
         assert!(result.is_err());
@@ -0,0 +1,137 @@
+//! Provision Configuration DTO (Application Layer)
+//!
+//! This module contains the DTO type for the provision workflow settings used
+//! in environment creation. This type uses raw primitives (u64) for JSON
+//! deserialization and converts to the domain type (`ProvisionConfig`).
+//!
+//! # Conversion Pattern
+//!
+//! Uses `TryFrom` for idiomatic Rust conversion from DTO to domain type.
+//! See ADR: `docs/decisions/tryfrom-for-dto-to-domain-conversion.md`
+
+use std::convert::TryFrom;
+
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+use crate::application::command_handlers::create::config::errors::CreateConfigError;
+use crate::domain::provision::config::DEFAULT_CLOUD_INIT_TIMEOUT_SECS;
+use crate::domain::provision::{IpPreference, IpPreferenceParseError, ProvisionConfig};
+
+/// Provision configuration section (DTO)
+///
+/// Optional settings for the provision command. All fields have defaults.
+///
+/// # Examples
+///
+/// ```json
+/// {
+///     "cloud_init_timeout_secs": 600,
+///     "ip_preference": "ipv6"
+/// }
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, JsonSchema)]
+pub struct ProvisionSection {
+    /// Maximum time to wait for cloud-init to finish on a new instance, in seconds
+    ///
+    /// Default: 300 (5 minutes)
+    ///
+    /// Increase it on slow or heavily loaded hosts. Must be greater than 0.
+    #[serde(default = "default_cloud_init_timeout_secs")]
+    pub cloud_init_timeout_secs: u64,
+
+    /// Address family used to reach the instance when it has both: `ipv4`
+    /// or `ipv6`
+    ///
+    /// Default: `ipv4`. The other family is used when the preferred one is
+    /// missing, so IPv6-only instances work without setting this.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub ip_preference: Option<String>,
+}
+
+fn default_cloud_init_timeout_secs() -> u64 {
+    DEFAULT_CLOUD_INIT_TIMEOUT_SECS
+}
+
+impl Default for ProvisionSection {
+    fn default() -> Self {
+        Self {
+            cloud_init_timeout_secs: default_cloud_init_timeout_secs(),
+            ip_preference: None,
+        }
+    }
+}
+
+impl TryFrom<ProvisionSection> for ProvisionConfig {
+    type Error = CreateConfigError;
+
+    fn try_from(section: ProvisionSection) -> Result<Self, Self::Error> {
+        let ip_preference: IpPreference = section
+            .ip_preference
+            .as_deref()
+            .map(str::parse)
+            .transpose()
+            .map_err(|e: IpPreferenceParseError| {
+                CreateConfigError::InvalidProvisionConfig(e.to_string())
+            })?
+            .unwrap_or_default();
+
+        ProvisionConfig::new(section.cloud_init_timeout_secs)
+            .map(|config| config.with_ip_preference(ip_preference))
+            .map_err(|e| CreateConfigError::InvalidProvisionConfig(e.to_string()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use super::*;
+
+    #[test]
+    fn it_should_use_the_default_timeout_when_the_section_is_empty() {
+        let section: ProvisionSection = serde_json::from_str("{}").expect("Valid JSON");
+
+        let config = ProvisionConfig::try_from(section).unwrap();
+
+        assert_eq!(config.cloud_init_timeout(), Duration::from_secs(300));
+    }
+
+    #[test]
+    fn it_should_reject_a_zero_cloud_init_timeout() {
+        let section = ProvisionSection {
+            cloud_init_timeout_secs: 0,
+            ip_preference: None,
+        };
+
+        let result = ProvisionConfig::try_from(section);
+
+        assert!(matches!(
+            result,
+            Err(CreateConfigError::InvalidProvisionConfig(_))
+        ));
+    }
+
+    #[test]
+    fn it_should_read_the_ip_preference() {
+        let section: ProvisionSection =
+            serde_json::from_str(r#"{ "ip_preference": "ipv6" }"#).expect("Valid JSON");
+
+        let config = ProvisionConfig::try_from(section).unwrap();
+
+        assert_eq!(config.ip_preference(), IpPreference::Ipv6);
+    }
+
+    #[test]
+    fn it_should_reject_an_unknown_ip_preference() {
+        let section: ProvisionSection =
+            serde_json::from_str(r#"{ "ip_preference": "ipx" }"#).expect("Valid JSON");
+
+        let result = ProvisionConfig::try_from(section);
+
+        assert!(matches!(
+            result,
+            Err(CreateConfigError::InvalidProvisionConfig(_))
+        ));
+    }
+}
@@ -0,0 +1,96 @@
+//! Proxy Configuration DTO (Application Layer)
+//!
+//! This module contains the DTO type for deploying behind an outbound HTTP
+//! proxy. It uses raw primitives (String) for JSON deserialization and
+//! converts to the domain type (`ProxyConfig`).
+//!
+//! # Conversion Pattern
+//!
+//! Uses `TryFrom` for idiomatic Rust conversion from DTO to domain type.
+//! See ADR: `docs/decisions/tryfrom-for-dto-to-domain-conversion.md`
+
+use std::convert::TryFrom;
+
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+use crate::application::command_handlers::create::config::errors::CreateConfigError;
+use crate::domain::provision::ProxyConfig;
+
+/// Proxy configuration section (DTO)
+///
+/// Outbound HTTP proxy used by the deployer and the instance, for networks
+/// that only reach the Internet through it.
+///
+/// # Examples
+///
+/// ```json
+/// {
+///     "http": "http://proxy.corp.internal:3128",
+///     "https": "http://proxy.corp.internal:3128",
+///     "no_proxy": ["localhost", "127.0.0.1", ".corp.internal"]
+/// }
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, JsonSchema)]
+pub struct ProxySection {
+    /// Proxy for plain HTTP requests (Ubuntu packages)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub http: Option<String>,
+
+    /// Proxy for HTTPS requests (provider plugins, Docker Compose, container images)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub https: Option<String>,
+
+    /// Hosts, domains (`.corp.internal`) and networks (`10.0.0.0/8`) reached without the proxy
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub no_proxy: Vec<String>,
+}
+
+impl TryFrom<ProxySection> for ProxyConfig {
+    type Error = CreateConfigError;
+
+    fn try_from(section: ProxySection) -> Result<Self, Self::Error> {
+        ProxyConfig::new(
+            section.http.as_deref(),
+            section.https.as_deref(),
+            section.no_proxy,
+        )
+        .map_err(|e| CreateConfigError::InvalidProxyConfig(e.to_string()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn it_should_convert_the_section_to_the_domain_type() {
+        let section: ProxySection = serde_json::from_str(
+            r#"{ "http": "http://proxy.corp.internal:3128", "no_proxy": [".corp.internal"] }"#,
+        )
+        .unwrap();
+
+        let proxy = ProxyConfig::try_from(section).unwrap();
+
+        assert_eq!(
+            proxy.http().map(url::Url::as_str),
+            Some("http://proxy.corp.internal:3128/")
+        );
+        assert_eq!(proxy.https(), None);
+        assert_eq!(proxy.no_proxy(), [".corp.internal"]);
+    }
+
+    #[test]
+    fn it_should_reject_a_section_without_proxy_url() {
+        let section = ProxySection {
+            http: None,
+            https: None,
+            no_proxy: vec!["localhost".to_string()],
+        };
+
+        assert!(matches!(
+            ProxyConfig::try_from(section),
+            Err(CreateConfigError::InvalidProxyConfig(_))
+        ));
+    }
+}
@@ -0,0 +1,208 @@
+//! Release Configuration DTO (Application Layer)
+//!
+//! This module contains the DTO types for replacing the container images of
+//! the services and pulling them from a private registry. It uses raw
+//! primitives (String) for JSON deserialization and converts to the domain
+//! type (`ReleaseConfig`).
+//!
+//! # Conversion Pattern
+//!
+//! Uses `TryFrom` for idiomatic Rust conversion from DTO to domain type.
+//! See ADR: `docs/decisions/tryfrom-for-dto-to-domain-conversion.md`
+
+use std::convert::TryFrom;
+
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+use crate::application::command_handlers::create::config::errors::CreateConfigError;
+use crate::application::command_handlers::create::config::SecretSource;
+use crate::domain::release::{ImageReference, RegistryCredentials, ReleaseConfig, ReleaseImages};
+use crate::shared::secrets::Password;
+
+/// Release configuration section (DTO)
+///
+/// Container images of the services, e.g. copies in an internal registry
+/// pinned by digest, and the credentials of that registry.
+///
+/// # Examples
+///
+/// ```json
+/// {
+///     "images": {
+///         "tracker": "registry.corp.internal/torrust/tracker@sha256:<digest>",
+///         "mysql": "registry.corp.internal/mysql:8.4@sha256:<digest>"
+///     },
+///     "registry": {
+///         "server": "registry.corp.internal",
+///         "username": "deployer",
+///         "password": { "env": "REGISTRY_PASSWORD" }
+///     }
+/// }
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, JsonSchema)]
+pub struct ReleaseSection {
+    /// Image of each service, overriding the image pinned by the deployer
+    ///
+    /// Images are `[registry[:port]/]repository[:tag][@sha256:<digest>]`.
+    /// The release fails when an image pinned by digest is pulled with
+    /// another digest.
+    #[serde(default)]
+    pub images: ReleaseImagesSection,
+
+    /// Private registry the instance logs in to before pulling the images
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub registry: Option<RegistrySection>,
+}
+
+/// Image overrides of the services (DTO)
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize, JsonSchema)]
+pub struct ReleaseImagesSection {
+    /// Image of the tracker; conflicts with `tracker.version`
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub tracker: Option<String>,
+
+    /// Image of the `MySQL` service
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub mysql: Option<String>,
+
+    /// Image of the Prometheus service
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub prometheus: Option<String>,
+
+    /// Image of the Grafana service
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub grafana: Option<String>,
+
+    /// Image of the Caddy TLS proxy
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub caddy: Option<String>,
+
+    /// Image of the backup service
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub backup: Option<String>,
+}
+
+/// Private container registry (DTO)
+///
+/// The `password` field is a [`SecretSource`], so it can be given inline or
+/// as an environment variable or file reference. It is stored with the
+/// environment secrets, never in the environment state file.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, JsonSchema)]
+pub struct RegistrySection {
+    /// Registry host, with an optional port (e.g. `registry.corp.internal:5000`)
+    pub server: String,
+
+    /// Registry user
+    pub username: String,
+
+    /// Registry password or access token
+    pub password: SecretSource,
+}
+
+impl TryFrom<ReleaseImagesSection> for ReleaseImages {
+    type Error = CreateConfigError;
+
+    fn try_from(section: ReleaseImagesSection) -> Result<Self, Self::Error> {
+        let parse = |service: &str, image: Option<String>| {
+            image
+                .map(|image| {
+                    ImageReference::new(image).map_err(|source| {
+                        CreateConfigError::InvalidImageReference {
+                            service: service.to_string(),
+                            source,
+                        }
+                    })
+                })
+                .transpose()
+        };
+
+        Ok(Self {
+            tracker: parse("tracker", section.tracker)?,
+            mysql: parse("mysql", section.mysql)?,
+            prometheus: parse("prometheus", section.prometheus)?,
+            grafana: parse("grafana", section.grafana)?,
+            caddy: parse("caddy", section.caddy)?,
+            backup: parse("backup", section.backup)?,
+        })
+    }
+}
+
+impl TryFrom<RegistrySection> for RegistryCredentials {
+    type Error = CreateConfigError;
+
+    fn try_from(section: RegistrySection) -> Result<Self, Self::Error> {
+        let password = Password::new(section.password.resolve()?);
+
+        RegistryCredentials::new(section.server, section.username, password)
+            .map_err(|e| CreateConfigError::InvalidReleaseConfig(e.to_string()))
+    }
+}
+
+impl TryFrom<ReleaseSection> for ReleaseConfig {
+    type Error = CreateConfigError;
+
+    fn try_from(section: ReleaseSection) -> Result<Self, Self::Error> {
+        let images = section.images.try_into()?;
+        let registry = section.registry.map(TryInto::try_into).transpose()?;
+
+        Ok(ReleaseConfig::new(images, registry))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn digest() -> String {
+        format!("sha256:{}", "0123456789abcdef".repeat(4))
+    }
+
+    #[test]
+    fn it_should_convert_the_section_to_the_domain_type() {
+        let section: ReleaseSection = serde_json::from_str(&format!(
+            r#"{{
+                "images": {{ "mysql": "registry.corp.internal/mysql:8.4@{}" }},
+                "registry": {{ "server": "registry.corp.internal", "username": "deployer", "password": "s3cret" }}
+            }}"#,
+            digest()
+        ))
+        .unwrap();
+
+        let release = ReleaseConfig::try_from(section).unwrap();
+
+        let mysql = release.images().mysql.as_ref().unwrap();
+        assert_eq!(mysql.digest(), Some(digest().as_str()));
+        assert_eq!(release.images().tracker, None);
+        let registry = release.registry().unwrap();
+        assert_eq!(registry.server(), "registry.corp.internal");
+        assert_eq!(registry.password().expose_secret(), "s3cret");
+    }
+
+    #[test]
+    fn it_should_name_the_service_of_an_invalid_image() {
+        let section = ReleaseImagesSection {
+            grafana: Some("registry.corp.internal/grafana@sha256:123".to_string()),
+            ..ReleaseImagesSection::default()
+        };
+
+        assert!(matches!(
+            ReleaseImages::try_from(section),
+            Err(CreateConfigError::InvalidImageReference { service, .. }) if service == "grafana"
+        ));
+    }
+
+    #[test]
+    fn it_should_reject_a_registry_server_with_scheme() {
+        let section = RegistrySection {
+            server: "https://registry.corp.internal".to_string(),
+            username: "deployer".to_string(),
+            password: "s3cret".into(),
+        };
+
+        assert!(matches!(
+            RegistryCredentials::try_from(section),
+            Err(CreateConfigError::InvalidReleaseConfig(_))
+        ));
+    }
+}
@@ -0,0 +1,246 @@
+//! Secret value DTO (Application Layer)
+//!
+//! Secret fields of the environment configuration (API tokens, passwords)
+//! accept three forms so that credentials do not have to be written inline
+//! in the JSON file:
+//!
+//! ```json
+//! "admin_token": "MyAccessToken"
+//! "admin_token": { "env": "TRACKER_ADMIN_TOKEN" }
+//! "admin_token": { "file": "/run/secrets/tracker_admin_token" }
+//! ```
+//!
+//! References are resolved when the configuration is converted to domain
+//! types. Only the resolved value reaches the domain, where it is wrapped in
+//! `ApiToken` or `Password`, so the reference itself is never persisted.
+
+use std::fmt;
+use std::path::PathBuf;
+
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+use crate::application::command_handlers::create::config::errors::SecretResolutionError;
+
+/// A secret given inline or as a reference to an environment variable or file
+///
+/// # Examples
+///
+/// ```rust
+/// use torrust_tracker_deployer_lib::application::command_handlers::create::config::SecretSource;
+///
+/// let inline = SecretSource::from("MyAccessToken");
+/// assert_eq!(inline.resolve().unwrap(), "MyAccessToken");
+///
+/// let reference: SecretSource = serde_json::from_str(r#"{"env": "TRACKER_ADMIN_TOKEN"}"#).unwrap();
+/// assert_eq!(reference, SecretSource::Env { env: "TRACKER_ADMIN_TOKEN".to_string() });
+/// ```
+#[derive(Clone, PartialEq, Eq, Serialize, Deserialize, JsonSchema)]
+#[serde(untagged)]
+pub enum SecretSource {
+    /// Secret value written inline in the configuration
+    Plain(String),
+
+    /// Name of an environment variable holding the secret
+    Env {
+        /// Environment variable name
+        env: String,
+    },
+
+    /// Path of a file holding the secret (e.g. a Docker or Kubernetes secret)
+    ///
+    /// Trailing line breaks are removed from the file content.
+    File {
+        /// Path to the secret file
+        file: PathBuf,
+    },
+}
+
+impl SecretSource {
+    /// Resolve the secret value
+    ///
+    /// # Errors
+    ///
+    /// Returns [`SecretResolutionError`] if the referenced environment
+    /// variable is not set, the referenced file cannot be read, or the
+    /// referenced value is empty. The error names the variable or file but
+    /// never contains the secret value.
+    pub fn resolve(&self) -> Result<String, SecretResolutionError> {
+        let value = match self {
+            Self::Plain(value) => return Ok(value.clone()),
+            Self::Env { env } => match std::env::var(env) {
+                Ok(value) => value,
+                Err(std::env::VarError::NotPresent) => {
+                    return Err(SecretResolutionError::EnvVarNotSet {
+                        variable: env.clone(),
+                    })
+                }
+                Err(std::env::VarError::NotUnicode(_)) => {
+                    return Err(SecretResolutionError::EnvVarNotUnicode {
+                        variable: env.clone(),
+                    })
+                }
+            },
+            Self::File { file } => std::fs::read_to_string(file)
+                .map_err(|source| SecretResolutionError::FileReadFailed {
+                    path: file.clone(),
+                    source,
+                })?
+                .trim_end_matches(['\n', '\r'])
+                .to_string(),
+        };
+
+        if value.is_empty() {
+            return Err(SecretResolutionError::EmptyValue {
+                reference: self.describe(),
+            });
+        }
+
+        Ok(value)
+    }
+
+    /// Human-readable description of where the secret comes from
+    ///
+    /// Never includes the secret value.
+    #[must_use]
+    pub fn describe(&self) -> String {
+        match self {
+            Self::Plain(_) => "inline value".to_string(),
+            Self::Env { env } => format!("environment variable '{env}'"),
+            Self::File { file } => format!("file '{}'", file.display()),
+        }
+    }
+}
+
+impl fmt::Debug for SecretSource {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Plain(_) => f.write_str("Plain([REDACTED])"),
+            Self::Env { env } => f.debug_struct("Env").field("env", env).finish(),
+            Self::File { file } => f.debug_struct("File").field("file", file).finish(),
+        }
+    }
+}
+
+impl From<String> for SecretSource {
+    fn from(value: String) -> Self {
+        Self::Plain(value)
+    }
+}
+
+impl From<&str> for SecretSource {
+    fn from(value: &str) -> Self {
+        Self::Plain(value.to_string())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::io::Write;
+
+    use super::*;
+
+    #[test]
+    fn it_should_deserialize_an_inline_secret() {
+        let secret: SecretSource = serde_json::from_str(r#""MyAccessToken""#).unwrap();
+
+        assert_eq!(secret, SecretSource::from("MyAccessToken"));
+    }
+
+    #[test]
+    fn it_should_deserialize_secret_references() {
+        let env: SecretSource = serde_json::from_str(r#"{"env": "MY_TOKEN"}"#).unwrap();
+        let file: SecretSource = serde_json::from_str(r#"{"file": "/run/secrets/token"}"#).unwrap();
+
+        assert_eq!(
+            env,
+            SecretSource::Env {
+                env: "MY_TOKEN".to_string()
+            }
+        );
+        assert_eq!(
+            file,
+            SecretSource::File {
+                file: PathBuf::from("/run/secrets/token")
+            }
+        );
+    }
+
+    #[test]
+    fn it_should_serialize_an_inline_secret_as_a_plain_string() {
+        let json = serde_json::to_string(&SecretSource::from("token")).unwrap();
+
+        assert_eq!(json, r#""token""#);
+    }
+
+    #[test]
+    fn it_should_resolve_a_secret_from_an_environment_variable() {
+        std::env::set_var("TORRUST_TD_TEST_SECRET_SOURCE_SET", "env-token");
+        let secret = SecretSource::Env {
+            env: "TORRUST_TD_TEST_SECRET_SOURCE_SET".to_string(),
+        };
+
+        assert_eq!(secret.resolve().unwrap(), "env-token");
+    }
+
+    #[test]
+    fn it_should_fail_when_the_environment_variable_is_not_set() {
+        let secret = SecretSource::Env {
+            env: "TORRUST_TD_TEST_SECRET_SOURCE_UNSET".to_string(),
+        };
+
+        let error = secret.resolve().unwrap_err();
+
+        assert!(matches!(
+            &error,
+            SecretResolutionError::EnvVarNotSet { variable } if variable == "TORRUST_TD_TEST_SECRET_SOURCE_UNSET"
+        ));
+        assert!(error
+            .to_string()
+            .contains("TORRUST_TD_TEST_SECRET_SOURCE_UNSET"));
+    }
+
+    #[test]
+    fn it_should_resolve_a_secret_from_a_file_without_trailing_line_breaks() {
+        let mut file = tempfile::NamedTempFile::new().unwrap();
+        writeln!(file, "file-token").unwrap();
+        let secret = SecretSource::File {
+            file: file.path().to_path_buf(),
+        };
+
+        assert_eq!(secret.resolve().unwrap(), "file-token");
+    }
+
+    #[test]
+    fn it_should_fail_when_the_secret_file_does_not_exist() {
+        let secret = SecretSource::File {
+            file: PathBuf::from("/nonexistent/secret"),
+        };
+
+        assert!(matches!(
+            secret.resolve(),
+            Err(SecretResolutionError::FileReadFailed { .. })
+        ));
+    }
+
+    #[test]
+    fn it_should_fail_when_the_referenced_secret_is_empty() {
+        let file = tempfile::NamedTempFile::new().unwrap();
+        let secret = SecretSource::File {
+            file: file.path().to_path_buf(),
+        };
+
+        assert!(matches!(
+            secret.resolve(),
+            Err(SecretResolutionError::EmptyValue { .. })
+        ));
+    }
+
+    #[test]
+    fn it_should_not_expose_inline_secrets_in_debug_output() {
+        let debug = format!("{:?}", SecretSource::from("super-secret"));
+
+        assert!(!debug.contains("super-secret"));
+        assert!(debug.contains("[REDACTED]"));
+    }
+}
@@ -47,9 +47,15 @@ const DEFAULT_SSH_PORT: u16 = 22;
 #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, JsonSchema)]
 pub struct SshCredentialsConfig {
     /// Path to the SSH private key file (as string in config)
+    ///
+    /// Left out when the key pair is generated (`generate_ssh_keys`).
+    #[serde(default)]
     pub private_key_path: String,
 
     /// Path to the SSH public key file (as string in config)
+    ///
+    /// Left out when the key pair is generated (`generate_ssh_keys`).
+    #[serde(default)]
     pub public_key_path: String,
 
     /// SSH username (as string in config)
@@ -66,6 +72,12 @@ pub struct SshCredentialsConfig {
 }
 
 impl SshCredentialsConfig {
+    /// Returns `true` if the configuration names key files
+    #[must_use]
+    pub fn has_key_paths(&self) -> bool {
+        !self.private_key_path.is_empty() || !self.public_key_path.is_empty()
+    }
+
     /// Creates a new SSH credentials configuration with explicit values
     ///
     /// # Examples
@@ -103,6 +115,10 @@ impl TryFrom<SshCredentialsConfig> for SshCredentials {
         // Convert string username to domain Username type
         let username = Username::new(&config.username)?;
 
+        if config.private_key_path.is_empty() || config.public_key_path.is_empty() {
+            return Err(CreateConfigError::MissingSshKeyPaths);
+        }
+
         // Convert string paths to PathBuf
         let private_key_path = PathBuf::from(&config.private_key_path);
         let public_key_path = PathBuf::from(&config.public_key_path);
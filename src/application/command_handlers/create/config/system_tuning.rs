@@ -0,0 +1,177 @@
+//! System Tuning Configuration DTO (Application Layer)
+//!
+//! This module contains the DTO types for the host system tuning settings used
+//! in environment creation. These types use raw primitives (String, u64) for
+//! JSON deserialization and convert to the domain type (`SystemTuningConfig`).
+//!
+//! # Conversion Pattern
+//!
+//! Uses `TryFrom` for idiomatic Rust conversion from DTO to domain type.
+//! See ADR: `docs/decisions/tryfrom-for-dto-to-domain-conversion.md`
+
+use std::collections::BTreeMap;
+use std::convert::TryFrom;
+
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+use crate::application::command_handlers::create::config::errors::CreateConfigError;
+use crate::domain::system_tuning::{SystemTuningConfig, TuningPreset};
+
+/// System tuning configuration section (DTO)
+///
+/// Either the name of a preset, or a preset with explicit settings on top.
+///
+/// # Examples
+///
+/// ```json
+/// "high-udp"
+/// ```
+///
+/// ```json
+/// {
+///     "preset": "high-udp",
+///     "sysctls": { "net.core.rmem_max": 67108864 },
+///     "ulimits": { "nofile": 524288 },
+///     "swap_size_mb": 2048
+/// }
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, JsonSchema)]
+#[serde(untagged)]
+pub enum SystemTuningSection {
+    /// Preset name: `default` or `high-udp`
+    Preset(String),
+
+    /// Preset with explicit settings
+    Custom(CustomSystemTuningSection),
+}
+
+/// Preset with explicit settings (DTO)
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize, JsonSchema)]
+#[serde(deny_unknown_fields)]
+pub struct CustomSystemTuningSection {
+    /// Preset the explicit settings are merged into: `default` (nothing
+    /// changed) or `high-udp`
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub preset: Option<String>,
+
+    /// Kernel parameters, by key, overriding the preset
+    ///
+    /// Only network and file system keys are accepted: `net.core.*`,
+    /// `net.ipv4.*`, `net.ipv6.*`, `net.netfilter.*`, `net.unix.*`,
+    /// `fs.aio-max-nr`, `fs.file-max`, `fs.inotify.*` and `fs.nr_open`.
+    #[serde(default)]
+    pub sysctls: BTreeMap<String, SysctlValueSection>,
+
+    /// Soft and hard resource limits, by name (`nofile` or `nproc`),
+    /// overriding the preset
+    #[serde(default)]
+    pub ulimits: BTreeMap<String, u64>,
+
+    /// Size of a swap file to create, in MB
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub swap_size_mb: Option<u32>,
+}
+
+/// A sysctl value (DTO): a number, or a string such as `"4096 87380 16777216"`
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, JsonSchema)]
+#[serde(untagged)]
+pub enum SysctlValueSection {
+    /// Numeric value
+    Number(u64),
+
+    /// Value as written to `/proc/sys`
+    Text(String),
+}
+
+impl From<SysctlValueSection> for String {
+    fn from(value: SysctlValueSection) -> Self {
+        match value {
+            SysctlValueSection::Number(number) => number.to_string(),
+            SysctlValueSection::Text(text) => text,
+        }
+    }
+}
+
+impl TryFrom<SystemTuningSection> for SystemTuningConfig {
+    type Error = CreateConfigError;
+
+    fn try_from(section: SystemTuningSection) -> Result<Self, Self::Error> {
+        let section = match section {
+            SystemTuningSection::Preset(preset) => CustomSystemTuningSection {
+                preset: Some(preset),
+                ..CustomSystemTuningSection::default()
+            },
+            SystemTuningSection::Custom(section) => section,
+        };
+
+        let preset = section
+            .preset
+            .as_deref()
+            .map(str::parse::<TuningPreset>)
+            .transpose()
+            .map_err(|e| CreateConfigError::InvalidSystemTuningConfig(e.to_string()))?
+            .unwrap_or_default();
+
+        let sysctls = section
+            .sysctls
+            .into_iter()
+            .map(|(key, value)| (key, value.into()))
+            .collect();
+
+        SystemTuningConfig::new(preset, sysctls, section.ulimits, section.swap_size_mb)
+            .map_err(|e| CreateConfigError::InvalidSystemTuningConfig(e.to_string()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn it_should_accept_a_preset_name() {
+        let section: SystemTuningSection = serde_json::from_str(r#""high-udp""#).unwrap();
+
+        let config = SystemTuningConfig::try_from(section).unwrap();
+
+        assert_eq!(
+            config,
+            SystemTuningConfig::from_preset(TuningPreset::HighUdp)
+        );
+    }
+
+    #[test]
+    fn it_should_convert_numeric_and_text_sysctl_values() {
+        let section: SystemTuningSection = serde_json::from_str(
+            r#"{
+                "sysctls": { "net.core.rmem_max": 67108864, "net.ipv4.tcp_rmem": "4096 87380 16777216" },
+                "ulimits": { "nofile": 524288 }
+            }"#,
+        )
+        .unwrap();
+
+        let config = SystemTuningConfig::try_from(section).unwrap();
+
+        assert_eq!(config.preset(), TuningPreset::Default);
+        assert_eq!(config.effective_sysctls()["net.core.rmem_max"], "67108864");
+        assert_eq!(
+            config.effective_sysctls()["net.ipv4.tcp_rmem"],
+            "4096 87380 16777216"
+        );
+        assert_eq!(config.effective_ulimits()["nofile"], 524_288);
+    }
+
+    #[test]
+    fn it_should_reject_an_unknown_preset_or_a_dangerous_key() {
+        let unknown_preset = SystemTuningSection::Preset("turbo".to_string());
+        let dangerous_key: SystemTuningSection =
+            serde_json::from_str(r#"{ "sysctls": { "kernel.kptr_restrict": 0 } }"#).unwrap();
+
+        for section in [unknown_preset, dangerous_key] {
+            assert!(matches!(
+                SystemTuningConfig::try_from(section),
+                Err(CreateConfigError::InvalidSystemTuningConfig(_))
+            ));
+        }
+    }
+}
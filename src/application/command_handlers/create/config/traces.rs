@@ -0,0 +1,127 @@
+//! Traces Configuration DTO (Application Layer)
+//!
+//! This module contains the DTO type for the trace file settings used in
+//! environment creation. This type uses raw primitives (String, usize, u32)
+//! for JSON deserialization and converts to the domain type (`TracesConfig`).
+//!
+//! # Conversion Pattern
+//!
+//! Uses `TryFrom` for idiomatic Rust conversion from DTO to domain type.
+//! See ADR: `docs/decisions/tryfrom-for-dto-to-domain-conversion.md`
+
+use std::convert::TryFrom;
+
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+use crate::application::command_handlers::create::config::errors::CreateConfigError;
+use crate::domain::environment::{TracesConfig, DEFAULT_KEPT_TRACES};
+
+/// Traces configuration section (DTO)
+///
+/// Optional settings for the trace files written on command failures. All
+/// fields have defaults.
+///
+/// # Examples
+///
+/// ```json
+/// {
+///     "dir": "/var/ci/artifacts/traces",
+///     "keep_last": 50,
+///     "max_age_days": 14
+/// }
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, JsonSchema)]
+pub struct TracesSection {
+    /// Directory the trace files are written to
+    ///
+    /// Default: `data/<env>/traces/`. Relative paths are relative to the
+    /// directory the deployer runs in. The `--trace-dir` option overrides it
+    /// for a single command.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub dir: Option<String>,
+
+    /// Number of most recent traces kept, older ones are removed after each command
+    ///
+    /// Default: 20. Must be greater than 0.
+    #[serde(default = "default_keep_last")]
+    pub keep_last: usize,
+
+    /// Traces older than this number of days are removed after each command
+    ///
+    /// Default: no age limit. Must be greater than 0 when set.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub max_age_days: Option<u32>,
+}
+
+fn default_keep_last() -> usize {
+    DEFAULT_KEPT_TRACES
+}
+
+impl Default for TracesSection {
+    fn default() -> Self {
+        Self {
+            dir: None,
+            keep_last: default_keep_last(),
+            max_age_days: None,
+        }
+    }
+}
+
+impl TryFrom<TracesSection> for TracesConfig {
+    type Error = CreateConfigError;
+
+    fn try_from(section: TracesSection) -> Result<Self, Self::Error> {
+        let config = TracesConfig::new(section.keep_last, section.max_age_days)
+            .map_err(|e| CreateConfigError::InvalidTracesConfig(e.to_string()))?;
+
+        Ok(match section.dir {
+            Some(dir) => config.with_dir(dir),
+            None => config,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::path::Path;
+
+    use super::*;
+
+    #[test]
+    fn it_should_use_the_defaults_when_the_section_is_empty() {
+        let section: TracesSection = serde_json::from_str("{}").expect("Valid JSON");
+
+        let config = TracesConfig::try_from(section).unwrap();
+
+        assert_eq!(config, TracesConfig::default());
+    }
+
+    #[test]
+    fn it_should_read_the_directory_and_the_retention() {
+        let section: TracesSection =
+            serde_json::from_str(r#"{ "dir": "/ci/traces", "keep_last": 5, "max_age_days": 7 }"#)
+                .expect("Valid JSON");
+
+        let config = TracesConfig::try_from(section).unwrap();
+
+        assert_eq!(config.dir(), Some(Path::new("/ci/traces")));
+        assert_eq!(config.keep_last(), 5);
+        assert_eq!(config.max_age().map(|age| age.num_days()), Some(7));
+    }
+
+    #[test]
+    fn it_should_reject_keeping_no_trace() {
+        let section = TracesSection {
+            keep_last: 0,
+            ..TracesSection::default()
+        };
+
+        let result = TracesConfig::try_from(section);
+
+        assert!(matches!(
+            result,
+            Err(CreateConfigError::InvalidTracesConfig(_))
+        ));
+    }
+}
@@ -19,8 +19,9 @@ use std::net::SocketAddr;
 use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
 
+use super::HealthCheckWaitSection;
 use crate::application::command_handlers::create::config::errors::CreateConfigError;
-use crate::domain::tracker::HealthCheckApiConfig;
+use crate::domain::tracker::{HealthCheckApiConfig, HealthCheckWait};
 use crate::shared::DomainName;
 
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq, JsonSchema)]
@@ -42,6 +43,13 @@ pub struct HealthCheckApiSection {
     /// This is useful for exposing health checks to external monitoring systems.
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub use_tls_proxy: Option<bool>,
+
+    /// How the `run` command waits for this API to report healthy
+    ///
+    /// Defaults to polling every 2 seconds for up to 60 seconds until the
+    /// API answers HTTP 200.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub wait: Option<HealthCheckWaitSection>,
 }
 
 /// Converts from application DTO to domain type using `TryFrom` trait
@@ -56,6 +64,7 @@ pub struct HealthCheckApiSection {
 ///     bind_address: "127.0.0.1:1313".to_string(),
 ///     domain: None,
 ///     use_tls_proxy: None,
+///     wait: None,
 /// };
 /// let config: HealthCheckApiConfig = section.try_into()?;
 /// ```
@@ -84,9 +93,13 @@ impl TryFrom<HealthCheckApiSection> for HealthCheckApiConfig {
 
         let use_tls_proxy = section.use_tls_proxy.unwrap_or(false);
 
+        let wait: HealthCheckWait = section.wait.unwrap_or_default().try_into()?;
+
         // Delegate all business validation to domain layer
-        HealthCheckApiConfig::new(bind_address, domain, use_tls_proxy)
-            .map_err(CreateConfigError::from)
+        let config = HealthCheckApiConfig::new(bind_address, domain, use_tls_proxy)
+            .map_err(CreateConfigError::from)?;
+
+        Ok(config.with_wait(wait))
     }
 }
 
@@ -96,6 +109,7 @@ impl Default for HealthCheckApiSection {
             bind_address: "127.0.0.1:1313".to_string(),
             domain: None,
             use_tls_proxy: None,
+            wait: None,
         }
     }
 }
@@ -114,6 +128,7 @@ mod tests {
             bind_address: "127.0.0.1:1313".to_string(),
             domain: None,
             use_tls_proxy: None,
+            wait: None,
         };
 
         let config: HealthCheckApiConfig = section.try_into().unwrap();
@@ -132,6 +147,7 @@ mod tests {
             bind_address: "0.0.0.0:1313".to_string(),
             domain: Some("health.tracker.local".to_string()),
             use_tls_proxy: Some(true),
+            wait: None,
         };
 
         let config: HealthCheckApiConfig = section.try_into().unwrap();
@@ -150,6 +166,7 @@ mod tests {
             bind_address: "invalid".to_string(),
             domain: None,
             use_tls_proxy: None,
+            wait: None,
         };
 
         let result: Result<HealthCheckApiConfig, _> = section.try_into();
@@ -167,6 +184,7 @@ mod tests {
             bind_address: "0.0.0.0:0".to_string(),
             domain: None,
             use_tls_proxy: None,
+            wait: None,
         };
 
         let result: Result<HealthCheckApiConfig, _> = section.try_into();
@@ -185,6 +203,7 @@ mod tests {
             bind_address: "[::1]:1313".to_string(),
             domain: None,
             use_tls_proxy: None,
+            wait: None,
         };
 
         let result: Result<HealthCheckApiConfig, _> = section.try_into();
@@ -198,6 +217,7 @@ mod tests {
             bind_address: "127.0.0.1:8080".to_string(),
             domain: None,
             use_tls_proxy: None,
+            wait: None,
         };
 
         let result: Result<HealthCheckApiConfig, _> = section.try_into();
@@ -220,6 +240,7 @@ mod tests {
             bind_address: "0.0.0.0:1313".to_string(),
             domain: Some("invalid domain with spaces".to_string()),
             use_tls_proxy: Some(true),
+            wait: None,
         };
 
         let result: Result<HealthCheckApiConfig, _> = section.try_into();
@@ -237,6 +258,7 @@ mod tests {
             bind_address: "0.0.0.0:1313".to_string(),
             domain: None,
             use_tls_proxy: Some(true),
+            wait: None,
         };
 
         let result: Result<HealthCheckApiConfig, _> = section.try_into();
@@ -255,6 +277,7 @@ mod tests {
             bind_address: "127.0.0.1:1313".to_string(),
             domain: Some("health.tracker.local".to_string()),
             use_tls_proxy: Some(true),
+            wait: None,
         };
 
         let result: Result<HealthCheckApiConfig, _> = section.try_into();
@@ -273,6 +296,7 @@ mod tests {
             bind_address: "0.0.0.0:1313".to_string(),
             domain: Some("health.tracker.local".to_string()),
             use_tls_proxy: None,
+            wait: None,
         };
 
         let config: HealthCheckApiConfig = section.try_into().unwrap();
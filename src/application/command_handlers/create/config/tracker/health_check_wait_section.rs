@@ -0,0 +1,103 @@
+//! Health check wait section DTO
+//!
+//! This module contains the application layer DTO for the strategy the `run`
+//! command uses to wait for the tracker Health Check API to report healthy.
+//! It follows the **`TryFrom` pattern** for DTO to domain conversion,
+//! delegating all business validation to the domain layer.
+//!
+//! See `docs/decisions/tryfrom-for-dto-to-domain-conversion.md` for rationale.
+
+use std::convert::TryFrom;
+use std::time::Duration;
+
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+use crate::application::command_handlers::create::config::errors::CreateConfigError;
+use crate::domain::tracker::HealthCheckWait;
+
+/// How the `run` command waits for the Health Check API to report healthy
+///
+/// Omitted fields keep their defaults.
+///
+/// # Examples
+///
+/// ```json
+/// {
+///   "interval_secs": 2,
+///   "timeout_secs": 60,
+///   "expected_status": 200
+/// }
+/// ```
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq, JsonSchema)]
+pub struct HealthCheckWaitSection {
+    /// Seconds between two health check requests (default 2)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub interval_secs: Option<u64>,
+
+    /// Seconds the services get to become healthy before the run fails
+    /// (default 60)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub timeout_secs: Option<u64>,
+
+    /// HTTP status of a healthy tracker (default 200)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub expected_status: Option<u16>,
+}
+
+impl TryFrom<HealthCheckWaitSection> for HealthCheckWait {
+    type Error = CreateConfigError;
+
+    fn try_from(section: HealthCheckWaitSection) -> Result<Self, Self::Error> {
+        let defaults = HealthCheckWait::default();
+
+        let interval = section
+            .interval_secs
+            .map_or(defaults.interval(), Duration::from_secs);
+        let timeout = section
+            .timeout_secs
+            .map_or(defaults.timeout(), Duration::from_secs);
+        let expected_status = section
+            .expected_status
+            .unwrap_or(defaults.expected_status());
+
+        Ok(HealthCheckWait::new(interval, timeout, expected_status)?)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::tracker::HealthCheckWaitError;
+
+    #[test]
+    fn it_should_keep_the_defaults_of_omitted_fields() {
+        let section = HealthCheckWaitSection {
+            timeout_secs: Some(120),
+            ..HealthCheckWaitSection::default()
+        };
+
+        let wait = HealthCheckWait::try_from(section).unwrap();
+
+        assert_eq!(wait.interval(), Duration::from_secs(2));
+        assert_eq!(wait.timeout(), Duration::from_secs(120));
+        assert_eq!(wait.expected_status(), 200);
+    }
+
+    #[test]
+    fn it_should_reject_an_interval_longer_than_the_timeout() {
+        let section = HealthCheckWaitSection {
+            interval_secs: Some(90),
+            ..HealthCheckWaitSection::default()
+        };
+
+        let result = HealthCheckWait::try_from(section);
+
+        assert!(matches!(
+            result,
+            Err(CreateConfigError::HealthCheckWaitInvalid(
+                HealthCheckWaitError::IntervalLongerThanTimeout { .. }
+            ))
+        ));
+    }
+}
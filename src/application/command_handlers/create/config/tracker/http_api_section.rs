@@ -32,8 +32,8 @@ use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
 
 use crate::application::command_handlers::create::config::errors::CreateConfigError;
+use crate::application::command_handlers::create::config::SecretSource;
 use crate::domain::tracker::HttpApiConfig;
-use crate::shared::secrets::PlainApiToken;
 use crate::shared::DomainName;
 
 /// HTTP API configuration section (Application DTO)
@@ -55,7 +55,7 @@ use crate::shared::DomainName;
 ///
 /// let section = HttpApiSection {
 ///     bind_address: "0.0.0.0:1212".to_string(),
-///     admin_token: "MyToken".to_string(),
+///     admin_token: "MyToken".into(),
 ///     domain: None,
 ///     use_tls_proxy: None,
 /// };
@@ -81,10 +81,10 @@ pub struct HttpApiSection {
     /// Parsed to `SocketAddr` during conversion.
     pub bind_address: String,
 
-    /// Admin token as plain string (at DTO boundary)
+    /// Admin token, inline or as an environment variable or file reference
     ///
-    /// Converted to `ApiToken` (secrecy-wrapped) in domain layer.
-    pub admin_token: PlainApiToken,
+    /// Resolved and converted to `ApiToken` (secrecy-wrapped) in domain layer.
+    pub admin_token: SecretSource,
 
     /// Domain name for HTTPS certificate acquisition
     ///
@@ -130,7 +130,7 @@ pub struct HttpApiSection {
 ///
 /// let section = HttpApiSection {
 ///     bind_address: "0.0.0.0:1212".to_string(),
-///     admin_token: "MyToken".to_string(),
+///     admin_token: "MyToken".into(),
 ///     domain: None,
 ///     use_tls_proxy: None,
 /// };
@@ -168,7 +168,7 @@ impl TryFrom<HttpApiSection> for HttpApiConfig {
         // The domain layer enforces: port != 0, TLS requires domain, no localhost with TLS
         let config = HttpApiConfig::new(
             bind_address,
-            section.admin_token.clone().into(),
+            section.admin_token.resolve()?.into(),
             domain,
             section.use_tls_proxy.unwrap_or(false),
         )?; // Uses From<HttpApiConfigError> for CreateConfigError
@@ -189,7 +189,7 @@ mod tests {
     fn it_should_convert_valid_config_to_http_api_config() {
         let section = HttpApiSection {
             bind_address: "0.0.0.0:1212".to_string(),
-            admin_token: "MyAccessToken".to_string(),
+            admin_token: "MyAccessToken".into(),
             domain: None,
             use_tls_proxy: None,
         };
@@ -210,7 +210,7 @@ mod tests {
     fn it_should_allow_non_localhost_with_tls_proxy() {
         let section = HttpApiSection {
             bind_address: "0.0.0.0:1212".to_string(),
-            admin_token: "token".to_string(),
+            admin_token: "token".into(),
             domain: Some("api.tracker.local".to_string()),
             use_tls_proxy: Some(true),
         };
@@ -228,7 +228,7 @@ mod tests {
         // Domain provided but use_tls_proxy is false - domain is stored but TLS disabled
         let section = HttpApiSection {
             bind_address: "0.0.0.0:1212".to_string(),
-            admin_token: "token".to_string(),
+            admin_token: "token".into(),
             domain: Some("api.tracker.local".to_string()),
             use_tls_proxy: Some(false),
         };
@@ -252,7 +252,7 @@ mod tests {
     fn it_should_fail_for_invalid_bind_address() {
         let section = HttpApiSection {
             bind_address: "invalid-address".to_string(),
-            admin_token: "token".to_string(),
+            admin_token: "token".into(),
             domain: None,
             use_tls_proxy: None,
         };
@@ -271,7 +271,7 @@ mod tests {
     fn it_should_fail_for_invalid_domain() {
         let section = HttpApiSection {
             bind_address: "0.0.0.0:1212".to_string(),
-            admin_token: "token".to_string(),
+            admin_token: "token".into(),
             domain: Some(String::new()), // Empty domain is invalid
             use_tls_proxy: Some(true),
         };
@@ -293,7 +293,7 @@ mod tests {
     fn it_should_reject_port_zero_via_domain_validation() {
         let section = HttpApiSection {
             bind_address: "0.0.0.0:0".to_string(),
-            admin_token: "token".to_string(),
+            admin_token: "token".into(),
             domain: None,
             use_tls_proxy: None,
         };
@@ -312,7 +312,7 @@ mod tests {
     fn it_should_reject_tls_proxy_without_domain_via_domain_validation() {
         let section = HttpApiSection {
             bind_address: "0.0.0.0:1212".to_string(),
-            admin_token: "token".to_string(),
+            admin_token: "token".into(),
             domain: None,              // No domain
             use_tls_proxy: Some(true), // But TLS enabled
         };
@@ -330,7 +330,7 @@ mod tests {
     fn it_should_reject_localhost_with_tls_via_domain_validation() {
         let section = HttpApiSection {
             bind_address: "127.0.0.1:1212".to_string(),
-            admin_token: "token".to_string(),
+            admin_token: "token".into(),
             domain: Some("api.example.com".to_string()),
             use_tls_proxy: Some(true),
         };
@@ -352,7 +352,7 @@ mod tests {
     fn it_should_be_serializable() {
         let section = HttpApiSection {
             bind_address: "0.0.0.0:1212".to_string(),
-            admin_token: "MyAccessToken".to_string(),
+            admin_token: "MyAccessToken".into(),
             domain: None,
             use_tls_proxy: None,
         };
@@ -369,7 +369,7 @@ mod tests {
         let json = r#"{"bind_address":"0.0.0.0:1212","admin_token":"MyAccessToken"}"#;
         let section: HttpApiSection = serde_json::from_str(json).unwrap();
         assert_eq!(section.bind_address, "0.0.0.0:1212");
-        assert_eq!(section.admin_token, "MyAccessToken");
+        assert_eq!(section.admin_token, SecretSource::from("MyAccessToken"));
         assert!(section.domain.is_none());
         assert!(section.use_tls_proxy.is_none());
     }
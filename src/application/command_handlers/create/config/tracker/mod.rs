@@ -5,15 +5,21 @@
 //! JSON deserialization and convert to rich domain types (`SocketAddr`).
 
 mod health_check_api_section;
+mod health_check_wait_section;
 mod http_api_section;
 mod http_tracker_section;
+mod partial_tracker_section;
 mod tracker_core_section;
+mod tracker_overrides_section;
 mod tracker_section;
 mod udp_tracker_section;
 
 pub use health_check_api_section::HealthCheckApiSection;
+pub use health_check_wait_section::HealthCheckWaitSection;
 pub use http_api_section::HttpApiSection;
 pub use http_tracker_section::HttpTrackerSection;
+pub use partial_tracker_section::{PartialHttpApiSection, PartialTrackerSection};
 pub use tracker_core_section::{DatabaseSection, TrackerCoreSection};
+pub use tracker_overrides_section::TrackerOverridesSection;
 pub use tracker_section::TrackerSection;
 pub use udp_tracker_section::UdpTrackerSection;
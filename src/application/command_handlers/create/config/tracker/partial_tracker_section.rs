@@ -0,0 +1,320 @@
+//! Partial tracker configuration sections (application DTOs)
+//!
+//! This module provides the tracker section of a configuration override file,
+//! where every field is optional. The fields that are present replace the
+//! ones of the persisted tracker configuration; the others are kept.
+//!
+//! # Merge Rules
+//!
+//! - `core`, `health_check_api` and `overrides` are replaced as a whole
+//! - `udp_trackers` and `http_trackers` are lists, replaced as a whole
+//! - `http_api` is merged field by field, so the admin token can be rotated
+//!   without repeating the bind address
+//! - `version` and `replicas` replace the persisted values
+
+use std::net::SocketAddr;
+use std::num::NonZeroU8;
+
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+use super::{
+    HealthCheckApiSection, HttpTrackerSection, TrackerCoreSection, TrackerOverridesSection,
+    UdpTrackerSection,
+};
+use crate::application::command_handlers::create::config::errors::CreateConfigError;
+use crate::application::command_handlers::create::config::validation::ConfigIssues;
+use crate::application::command_handlers::create::config::SecretSource;
+use crate::domain::tracker::{HttpApiConfig, TrackerConfig, TrackerVersion};
+use crate::shared::DomainName;
+
+/// Tracker section of a configuration override (application DTO)
+///
+/// Same fields as [`TrackerSection`](super::TrackerSection), all optional.
+///
+/// # Examples
+///
+/// ```json
+/// {
+///   "version": "v4.0.0",
+///   "http_api": {
+///     "admin_token": "MyNewAccessToken"
+///   }
+/// }
+/// ```
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq, JsonSchema)]
+#[serde(deny_unknown_fields)]
+pub struct PartialTrackerSection {
+    /// Core tracker configuration, replacing the persisted one
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub core: Option<TrackerCoreSection>,
+
+    /// UDP tracker instances, replacing the persisted ones
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub udp_trackers: Option<Vec<UdpTrackerSection>>,
+
+    /// HTTP tracker instances, replacing the persisted ones
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub http_trackers: Option<Vec<HttpTrackerSection>>,
+
+    /// HTTP API fields to change
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub http_api: Option<PartialHttpApiSection>,
+
+    /// Health check API configuration, replacing the persisted one
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub health_check_api: Option<HealthCheckApiSection>,
+
+    /// Tracker version (image tag) to deploy
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub version: Option<String>,
+
+    /// Settings of `tracker.toml`, replacing the persisted ones
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub overrides: Option<TrackerOverridesSection>,
+
+    /// Number of tracker containers to run
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub replicas: Option<NonZeroU8>,
+}
+
+/// HTTP API section of a configuration override (application DTO)
+///
+/// Same fields as [`HttpApiSection`](super::HttpApiSection), all optional.
+/// Absent fields keep their persisted value.
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq, JsonSchema)]
+#[serde(deny_unknown_fields)]
+pub struct PartialHttpApiSection {
+    /// Bind address as string (e.g., "0.0.0.0:1212")
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub bind_address: Option<String>,
+
+    /// Admin token, inline or as an environment variable or file reference
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub admin_token: Option<SecretSource>,
+
+    /// Domain name for HTTPS certificate acquisition
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub domain: Option<String>,
+
+    /// Whether to proxy this service through Caddy with TLS termination
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub use_tls_proxy: Option<bool>,
+}
+
+impl PartialTrackerSection {
+    /// Apply the fields that are present to `current`, recording the error
+    /// of every invalid field at its JSON path under `path`
+    ///
+    /// Returns `None` when an error was recorded.
+    pub(crate) fn apply(
+        self,
+        current: &TrackerConfig,
+        path: &str,
+        issues: &mut ConfigIssues,
+    ) -> Option<TrackerConfig> {
+        let at = |field: &str| format!("{path}.{field}");
+
+        let core = match self.core {
+            Some(section) => issues.check(&at("core"), section.try_into()),
+            None => Some(current.core().clone()),
+        };
+
+        let udp_trackers = match self.udp_trackers {
+            Some(sections) => sections
+                .into_iter()
+                .enumerate()
+                .map(|(index, udp)| {
+                    issues.check(&at(&format!("udp_trackers[{index}]")), udp.try_into())
+                })
+                .collect::<Vec<_>>()
+                .into_iter()
+                .collect(),
+            None => Some(current.udp_trackers().to_vec()),
+        };
+
+        let http_trackers = match self.http_trackers {
+            Some(sections) => sections
+                .into_iter()
+                .enumerate()
+                .map(|(index, http)| {
+                    issues.check(&at(&format!("http_trackers[{index}]")), http.try_into())
+                })
+                .collect::<Vec<_>>()
+                .into_iter()
+                .collect(),
+            None => Some(current.http_trackers().to_vec()),
+        };
+
+        let http_api = match self.http_api {
+            Some(section) => issues.check(&at("http_api"), section.apply(current.http_api())),
+            None => Some(current.http_api().clone()),
+        };
+
+        let health_check_api = match self.health_check_api {
+            Some(section) => issues.check(&at("health_check_api"), section.try_into()),
+            None => Some(current.health_check_api().clone()),
+        };
+
+        let version = match self.version {
+            Some(version) => issues.check(
+                &at("version"),
+                TrackerVersion::new(version)
+                    .map(Some)
+                    .map_err(CreateConfigError::from),
+            ),
+            None => Some(current.version().cloned()),
+        };
+
+        let overrides = match self.overrides {
+            Some(section) => issues.check(&at("overrides"), section.try_into()),
+            None => Some(current.overrides().clone()),
+        };
+
+        // The aggregate invariants are checked again on the merged configuration
+        let config = issues
+            .check(
+                path,
+                TrackerConfig::new(
+                    core?,
+                    udp_trackers?,
+                    http_trackers?,
+                    http_api?,
+                    health_check_api?,
+                )
+                .map_err(CreateConfigError::from),
+            )?
+            .with_overrides(overrides?);
+
+        let config = issues.check(
+            &at("replicas"),
+            config
+                .with_replicas(self.replicas.unwrap_or(current.replicas()))
+                .map_err(CreateConfigError::from),
+        )?;
+
+        Some(match version? {
+            Some(version) => config.with_version(version),
+            None => config,
+        })
+    }
+}
+
+impl PartialHttpApiSection {
+    /// Apply the fields that are present to `current`
+    ///
+    /// # Errors
+    ///
+    /// Returns `CreateConfigError` if a present field is invalid (see
+    /// [`HttpApiSection`](super::HttpApiSection)) or the merged configuration
+    /// violates an invariant of `HttpApiConfig`.
+    fn apply(self, current: &HttpApiConfig) -> Result<HttpApiConfig, CreateConfigError> {
+        let bind_address = match self.bind_address {
+            Some(address) => address
+                .parse::<SocketAddr>()
+                .map_err(|e| CreateConfigError::InvalidBindAddress { address, source: e })?,
+            None => current.bind_address(),
+        };
+
+        let admin_token = match self.admin_token {
+            Some(token) => token.resolve()?.into(),
+            None => current.admin_token().clone(),
+        };
+
+        let domain =
+            match self.domain {
+                Some(domain) => Some(DomainName::new(&domain).map_err(|e| {
+                    CreateConfigError::InvalidDomain {
+                        domain: domain.clone(),
+                        reason: e.to_string(),
+                    }
+                })?),
+                None => current.domain().cloned(),
+            };
+
+        Ok(HttpApiConfig::new(
+            bind_address,
+            admin_token,
+            domain,
+            self.use_tls_proxy.unwrap_or(current.use_tls_proxy()),
+        )?)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn apply(section: PartialTrackerSection) -> Result<TrackerConfig, CreateConfigError> {
+        let mut issues = ConfigIssues::default();
+        let config = section.apply(&TrackerConfig::default(), "tracker", &mut issues);
+        issues.finish(config)
+    }
+
+    #[test]
+    fn it_should_keep_the_persisted_configuration_when_no_field_is_present() {
+        let config = apply(PartialTrackerSection::default()).unwrap();
+
+        assert_eq!(config, TrackerConfig::default());
+    }
+
+    #[test]
+    fn it_should_only_change_the_admin_token_when_only_the_token_is_present() {
+        let config = apply(PartialTrackerSection {
+            http_api: Some(PartialHttpApiSection {
+                admin_token: Some("NewToken".into()),
+                ..PartialHttpApiSection::default()
+            }),
+            ..PartialTrackerSection::default()
+        })
+        .unwrap();
+
+        let current = TrackerConfig::default();
+        assert_eq!(config.http_api().admin_token().expose_secret(), "NewToken");
+        assert_eq!(
+            config.http_api().bind_address(),
+            current.http_api().bind_address()
+        );
+        assert_eq!(config.udp_trackers(), current.udp_trackers());
+    }
+
+    #[test]
+    fn it_should_set_the_tracker_version() {
+        let config = apply(PartialTrackerSection {
+            version: Some("v4.0.0".to_string()),
+            ..PartialTrackerSection::default()
+        })
+        .unwrap();
+
+        assert_eq!(
+            config.version().map(ToString::to_string).as_deref(),
+            Some("v4.0.0")
+        );
+    }
+
+    #[test]
+    fn it_should_report_an_invalid_field_at_its_path() {
+        let error = apply(PartialTrackerSection {
+            http_api: Some(PartialHttpApiSection {
+                bind_address: Some("not-an-address".to_string()),
+                ..PartialHttpApiSection::default()
+            }),
+            ..PartialTrackerSection::default()
+        })
+        .unwrap_err();
+
+        assert!(matches!(
+            error,
+            CreateConfigError::InvalidBindAddress { .. }
+        ));
+    }
+
+    #[test]
+    fn it_should_reject_unknown_fields() {
+        let result = serde_json::from_str::<PartialTrackerSection>(
+            r#"{ "http_api": { "admin_tokn": "typo" } }"#,
+        );
+
+        assert!(result.is_err());
+    }
+}
@@ -15,8 +15,11 @@ use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
 
 use crate::application::command_handlers::create::config::errors::CreateConfigError;
-use crate::domain::tracker::{DatabaseConfig, MysqlConfig, SqliteConfig, TrackerCoreConfig};
-use crate::shared::{generate_random_password, Password, PlainPassword};
+use crate::application::command_handlers::create::config::SecretSource;
+use crate::domain::tracker::{
+    DatabaseConfig, MysqlConfig, MysqlConfigOptions, SqliteConfig, TrackerCoreConfig,
+};
+use crate::shared::{generate_random_password, Password};
 
 /// Database configuration section (application DTO)
 ///
@@ -62,17 +65,27 @@ pub enum DatabaseSection {
         database_name: String,
         /// Database username
         username: String,
-        /// Database password (plain text during DTO serialization/deserialization)
+        /// Database password, inline or as an environment variable or file reference
         ///
-        /// Uses `PlainPassword` type alias to explicitly mark this as a temporarily visible secret.
-        /// Converted to secure `Password` type in `to_database_config()` at the DTO-to-domain boundary.
-        password: PlainPassword,
+        /// Resolved and converted to secure `Password` type at the DTO-to-domain boundary.
+        password: SecretSource,
         /// Optional `MySQL` root password
         ///
         /// When provided, used as `MYSQL_ROOT_PASSWORD` in the rendered `.env` file.
         /// When absent, a cryptographically random password is generated at environment creation time.
         #[serde(default)]
-        root_password: Option<PlainPassword>,
+        root_password: Option<SecretSource>,
+        /// Minimum length of the password and the root password
+        ///
+        /// Defaults to 12 characters.
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        min_password_length: Option<usize>,
+        /// Accept a public or unspecified IP address (e.g. `0.0.0.0`) as host
+        ///
+        /// Database traffic to a public address can be reached from the
+        /// Internet, so it must be confirmed explicitly.
+        #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+        expose_publicly: bool,
     },
 }
 
@@ -92,16 +105,27 @@ impl TryFrom<DatabaseSection> for DatabaseConfig {
                 username,
                 password,
                 root_password,
+                min_password_length,
+                expose_publicly,
             } => {
-                let root_password = root_password
-                    .map_or_else(generate_random_password, |p| Password::from(p.as_str()));
-                let config = MysqlConfig::new(
+                let root_password = match root_password {
+                    Some(secret) => Password::from(secret.resolve()?),
+                    None => generate_random_password(),
+                };
+                let defaults = MysqlConfigOptions::default();
+                let options = MysqlConfigOptions {
+                    min_password_length: min_password_length
+                        .unwrap_or(defaults.min_password_length),
+                    expose_publicly,
+                };
+                let config = MysqlConfig::with_options(
                     host,
                     port,
                     database_name,
                     username,
-                    Password::from(password.as_str()),
+                    Password::from(password.resolve()?),
                     root_password,
+                    options,
                 )?;
                 Ok(Self::Mysql(config))
             }
@@ -144,6 +168,7 @@ impl TryFrom<TrackerCoreSection> for TrackerCoreConfig {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::domain::tracker::MysqlConfigError;
 
     #[test]
     fn it_should_convert_to_domain_config_when_transforming_tracker_core_section() {
@@ -221,8 +246,10 @@ mod tests {
                 port: 3306,
                 database_name: "tracker".to_string(),
                 username: "tracker_user".to_string(),
-                password: "secure_password".to_string(),
+                password: "secure_password".into(),
                 root_password: None,
+                min_password_length: None,
+                expose_publicly: false,
             },
             private: false,
         };
@@ -250,8 +277,10 @@ mod tests {
                 port: 3306,
                 database_name: "tracker".to_string(),
                 username: "tracker_user".to_string(),
-                password: "pass123".to_string(),
+                password: "tracker_password".into(),
                 root_password: None,
+                min_password_length: None,
+                expose_publicly: false,
             },
             private: false,
         };
@@ -262,7 +291,7 @@ mod tests {
         assert!(json.contains("\"port\":3306"));
         assert!(json.contains("\"database_name\":\"tracker\""));
         assert!(json.contains("\"username\":\"tracker_user\""));
-        assert!(json.contains("\"password\":\"pass123\""));
+        assert!(json.contains("\"password\":\"tracker_password\""));
     }
 
     #[test]
@@ -288,10 +317,76 @@ mod tests {
                 port: 3306,
                 database_name: "tracker".to_string(),
                 username: "tracker_user".to_string(),
-                password: "secure_password".to_string(),
+                password: "secure_password".into(),
                 root_password: None,
+                min_password_length: None,
+                expose_publicly: false,
             }
         );
         assert!(!section.private);
     }
+
+    fn mysql_section(
+        host: &str,
+        database_name: &str,
+        min_password_length: Option<usize>,
+        expose_publicly: bool,
+    ) -> TrackerCoreSection {
+        TrackerCoreSection {
+            database: DatabaseSection::Mysql {
+                host: host.to_string(),
+                port: 3306,
+                database_name: database_name.to_string(),
+                username: "tracker_user".to_string(),
+                password: "short_pw".into(),
+                root_password: Some("root_short".into()),
+                min_password_length,
+                expose_publicly,
+            },
+            private: false,
+        }
+    }
+
+    #[test]
+    fn it_should_reject_invalid_mysql_settings_when_transforming_core_section() {
+        let result: Result<TrackerCoreConfig, _> =
+            mysql_section("mysql", "my tracker", Some(8), false).try_into();
+        assert!(matches!(
+            result,
+            Err(CreateConfigError::MysqlConfigInvalid(
+                MysqlConfigError::InvalidDatabaseName { .. }
+            ))
+        ));
+
+        let result: Result<TrackerCoreConfig, _> =
+            mysql_section("mysql", "tracker", None, false).try_into();
+        assert!(matches!(
+            result,
+            Err(CreateConfigError::MysqlConfigInvalid(
+                MysqlConfigError::PasswordTooShort { min_length: 12 }
+            ))
+        ));
+
+        let result: Result<TrackerCoreConfig, _> =
+            mysql_section("0.0.0.0", "tracker", Some(8), false).try_into();
+        assert!(matches!(
+            result,
+            Err(CreateConfigError::MysqlConfigInvalid(
+                MysqlConfigError::PubliclyExposed { .. }
+            ))
+        ));
+    }
+
+    #[test]
+    fn it_should_apply_mysql_validation_options_when_transforming_core_section() {
+        let config: TrackerCoreConfig = mysql_section("0.0.0.0", "tracker", Some(8), true)
+            .try_into()
+            .unwrap();
+
+        let DatabaseConfig::Mysql(mysql) = config.database() else {
+            panic!("expected MySQL config");
+        };
+        assert!(mysql.expose_publicly());
+        assert_eq!(mysql.password().expose_secret(), "short_pw");
+    }
 }
@@ -0,0 +1,149 @@
+//! Tracker overrides section DTO
+//!
+//! This module contains the application layer DTO for the tracker tuning
+//! overrides. It follows the **`TryFrom` pattern** for DTO to domain
+//! conversion, delegating all business validation to the domain layer.
+//!
+//! Keys other than the supported overrides are collected instead of being
+//! ignored, so that an attempt to override a setting managed by the deployer
+//! (e.g. `http_api.bind_address`) is reported as a conflict at validation
+//! time rather than silently dropped.
+//!
+//! See `docs/decisions/tryfrom-for-dto-to-domain-conversion.md` for rationale.
+
+use std::collections::BTreeMap;
+use std::convert::TryFrom;
+
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+use crate::application::command_handlers::create::config::errors::CreateConfigError;
+use crate::domain::tracker::{TrackerOverrides, TrackerOverridesError};
+
+/// Tracker tuning settings merged into the generated `tracker.toml`
+///
+/// # Examples
+///
+/// ```json
+/// {
+///   "announce_interval": 300,
+///   "min_announce_interval": 120,
+///   "max_peer_timeout": 900,
+///   "tracker_usage_statistics": true
+/// }
+/// ```
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq, JsonSchema)]
+pub struct TrackerOverridesSection {
+    /// Interval in seconds that clients should wait between announces
+    /// (`core.announce_policy.interval`, default 300)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub announce_interval: Option<u32>,
+
+    /// Minimum interval in seconds between announces
+    /// (`core.announce_policy.interval_min`)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub min_announce_interval: Option<u32>,
+
+    /// Seconds after which a peer that stopped announcing is removed
+    /// (`core.tracker_policy.max_peer_timeout`)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub max_peer_timeout: Option<u32>,
+
+    /// Whether the tracker collects usage statistics
+    /// (`core.tracker_usage_statistics`)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub tracker_usage_statistics: Option<bool>,
+
+    /// Any other key; rejected during validation
+    #[serde(flatten)]
+    pub other: BTreeMap<String, serde_json::Value>,
+}
+
+impl TryFrom<TrackerOverridesSection> for TrackerOverrides {
+    type Error = CreateConfigError;
+
+    fn try_from(section: TrackerOverridesSection) -> Result<Self, Self::Error> {
+        if let Some(key) = section.other.iter().flat_map(leaf_keys).next() {
+            return Err(TrackerOverridesError::for_unknown_key(&key).into());
+        }
+
+        let mut overrides = TrackerOverrides::default();
+        if let Some(seconds) = section.announce_interval {
+            overrides = overrides.with_announce_interval(seconds)?;
+        }
+        if let Some(seconds) = section.min_announce_interval {
+            overrides = overrides.with_min_announce_interval(seconds)?;
+        }
+        if let Some(seconds) = section.max_peer_timeout {
+            overrides = overrides.with_max_peer_timeout(seconds)?;
+        }
+        if let Some(enabled) = section.tracker_usage_statistics {
+            overrides = overrides.with_tracker_usage_statistics(enabled);
+        }
+
+        Ok(overrides)
+    }
+}
+
+/// Dotted paths of the leaf values below `key`
+///
+/// Both `{"http_api": {"bind_address": ...}}` and
+/// `{"http_api.bind_address": ...}` give `http_api.bind_address`.
+fn leaf_keys((key, value): (&String, &serde_json::Value)) -> Vec<String> {
+    match value {
+        serde_json::Value::Object(table) if !table.is_empty() => table
+            .iter()
+            .flat_map(leaf_keys)
+            .map(|child| format!("{key}.{child}"))
+            .collect(),
+        _ => vec![key.clone()],
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn section_from(json: &str) -> TrackerOverridesSection {
+        serde_json::from_str(json).expect("valid overrides JSON")
+    }
+
+    #[test]
+    fn it_should_convert_the_supported_overrides() {
+        let section = section_from(r#"{"announce_interval": 600, "max_peer_timeout": 900}"#);
+
+        let overrides = TrackerOverrides::try_from(section).unwrap();
+
+        assert_eq!(overrides.announce_interval(), 600);
+        assert_eq!(overrides.max_peer_timeout(), Some(900));
+    }
+
+    #[test]
+    fn it_should_reject_an_override_of_a_bind_address() {
+        for json in [
+            r#"{"http_api": {"bind_address": "0.0.0.0:1313"}}"#,
+            r#"{"http_api.bind_address": "0.0.0.0:1313"}"#,
+        ] {
+            let result = TrackerOverrides::try_from(section_from(json));
+
+            assert!(matches!(
+                result,
+                Err(CreateConfigError::TrackerOverridesInvalid(
+                    TrackerOverridesError::ManagedKey { ref key }
+                )) if key == "http_api.bind_address"
+            ));
+        }
+    }
+
+    #[test]
+    fn it_should_reject_unknown_overrides() {
+        let result = TrackerOverrides::try_from(section_from(r#"{"announce_intervall": 60}"#));
+
+        assert!(matches!(
+            result,
+            Err(CreateConfigError::TrackerOverridesInvalid(
+                TrackerOverridesError::UnsupportedKey { .. }
+            ))
+        ));
+    }
+}
@@ -10,17 +10,20 @@
 
 use std::convert::TryFrom;
 use std::convert::TryInto;
+use std::num::NonZeroU8;
 
 use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
 
 use super::{
     HealthCheckApiSection, HttpApiSection, HttpTrackerSection, TrackerCoreSection,
-    UdpTrackerSection,
+    TrackerOverridesSection, UdpTrackerSection,
 };
 use crate::application::command_handlers::create::config::errors::CreateConfigError;
+use crate::application::command_handlers::create::config::validation::ConfigIssues;
 use crate::domain::tracker::{
-    HealthCheckApiConfig, HttpApiConfig, HttpTrackerConfig, TrackerConfig, UdpTrackerConfig,
+    HealthCheckApiConfig, HttpApiConfig, HttpTrackerConfig, TrackerConfig, TrackerOverrides,
+    TrackerVersion, UdpTrackerConfig,
 };
 
 /// Tracker configuration section (application DTO)
@@ -66,41 +69,125 @@ pub struct TrackerSection {
     pub http_api: HttpApiSection,
     /// Health Check API configuration
     pub health_check_api: HealthCheckApiSection,
+    /// Tracker image version to deploy: a tag (e.g. `3.0.0`) or a
+    /// `sha256:` digest. The pinned default tag is used when omitted.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub version: Option<String>,
+    /// Tuning settings merged into the generated `tracker.toml` (announce
+    /// intervals, peer timeout, usage statistics). Settings derived from the
+    /// sections above, such as bind addresses, cannot be overridden.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub overrides: Option<TrackerOverridesSection>,
+    /// Number of tracker containers to run on the instance (default: 1).
+    /// Replica N publishes every tracker port shifted by N - 1, e.g. with
+    /// 2 replicas the UDP tracker is announced on 6969 and 6970. All
+    /// replicas share the same database. More than one replica requires a
+    /// `mysql` database and services without `use_tls_proxy`.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub replicas: Option<NonZeroU8>,
 }
 
 impl TryFrom<TrackerSection> for TrackerConfig {
     type Error = CreateConfigError;
 
     fn try_from(section: TrackerSection) -> Result<Self, Self::Error> {
-        let core = section.core.try_into()?;
+        let mut issues = ConfigIssues::default();
+        let config = section.convert("", &mut issues);
+        issues.finish(config)
+    }
+}
+
+impl TrackerSection {
+    /// Convert to the domain type, recording the error of every invalid
+    /// field at its JSON path under `path`
+    ///
+    /// Returns `None` when an error was recorded.
+    pub(crate) fn convert(self, path: &str, issues: &mut ConfigIssues) -> Option<TrackerConfig> {
+        let at = |field: &str| {
+            if path.is_empty() {
+                field.to_string()
+            } else {
+                format!("{path}.{field}")
+            }
+        };
+
+        let core = issues.check(&at("core"), self.core.try_into());
 
         // Use TryFrom for DTO to domain conversions
-        let udp_trackers: Result<Vec<UdpTrackerConfig>, CreateConfigError> = section
+        let udp_trackers: Option<Vec<UdpTrackerConfig>> = self
             .udp_trackers
             .into_iter()
-            .map(TryInto::try_into)
+            .enumerate()
+            .map(|(index, udp)| {
+                issues.check(&at(&format!("udp_trackers[{index}]")), udp.try_into())
+            })
+            .collect::<Vec<_>>()
+            .into_iter()
             .collect();
 
-        let http_trackers: Result<Vec<HttpTrackerConfig>, CreateConfigError> = section
+        let http_trackers: Option<Vec<HttpTrackerConfig>> = self
             .http_trackers
             .into_iter()
-            .map(TryInto::try_into)
+            .enumerate()
+            .map(|(index, http)| {
+                issues.check(&at(&format!("http_trackers[{index}]")), http.try_into())
+            })
+            .collect::<Vec<_>>()
+            .into_iter()
             .collect();
 
-        let http_api: HttpApiConfig = section.http_api.try_into()?;
+        let http_api: Option<HttpApiConfig> =
+            issues.check(&at("http_api"), self.http_api.try_into());
+
+        let health_check_api: Option<HealthCheckApiConfig> =
+            issues.check(&at("health_check_api"), self.health_check_api.try_into());
+
+        let version = issues.check(
+            &at("version"),
+            self.version
+                .map(TrackerVersion::new)
+                .transpose()
+                .map_err(CreateConfigError::from),
+        );
 
-        let health_check_api: HealthCheckApiConfig = section.health_check_api.try_into()?;
+        let overrides: Option<TrackerOverrides> = issues.check(
+            &at("overrides"),
+            self.overrides
+                .map(TryInto::try_into)
+                .transpose()
+                .map(Option::unwrap_or_default),
+        );
 
         // Create TrackerConfig with validated constructor
         // This validates socket address uniqueness at construction time
-        TrackerConfig::new(
-            core,
-            udp_trackers?,
-            http_trackers?,
-            http_api,
-            health_check_api,
-        )
-        .map_err(CreateConfigError::from)
+        let config = issues
+            .check(
+                path,
+                TrackerConfig::new(
+                    core?,
+                    udp_trackers?,
+                    http_trackers?,
+                    http_api?,
+                    health_check_api?,
+                )
+                .map_err(CreateConfigError::from),
+            )?
+            .with_overrides(overrides?);
+
+        let config = match self.replicas {
+            Some(replicas) => issues.check(
+                &at("replicas"),
+                config
+                    .with_replicas(replicas)
+                    .map_err(CreateConfigError::from),
+            )?,
+            None => config,
+        };
+
+        Some(match version? {
+            Some(version) => config.with_version(version),
+            None => config,
+        })
     }
 }
 
@@ -134,11 +221,14 @@ impl Default for TrackerSection {
             }],
             http_api: HttpApiSection {
                 bind_address: "0.0.0.0:1212".to_string(),
-                admin_token: "MyAccessToken".to_string(),
+                admin_token: "MyAccessToken".into(),
                 domain: None,
                 use_tls_proxy: None,
             },
             health_check_api: HealthCheckApiSection::default(),
+            version: None,
+            overrides: None,
+            replicas: None,
         }
     }
 }
@@ -149,7 +239,7 @@ mod tests {
 
     use super::*;
     use crate::application::command_handlers::create::config::tracker::tracker_core_section::DatabaseSection;
-    use crate::domain::tracker::{DatabaseConfig, SqliteConfig};
+    use crate::domain::tracker::{DatabaseConfig, SqliteConfig, TrackerConfigError};
 
     #[test]
     fn it_should_convert_to_domain_config_when_transforming_tracker_section() {
@@ -171,11 +261,14 @@ mod tests {
             }],
             http_api: HttpApiSection {
                 bind_address: "0.0.0.0:1212".to_string(),
-                admin_token: "MyAccessToken".to_string(),
+                admin_token: "MyAccessToken".into(),
                 domain: None,
                 use_tls_proxy: None,
             },
             health_check_api: HealthCheckApiSection::default(),
+            version: None,
+            overrides: None,
+            replicas: None,
         };
 
         let config: TrackerConfig = section.try_into().unwrap();
@@ -226,11 +319,14 @@ mod tests {
             ],
             http_api: HttpApiSection {
                 bind_address: "0.0.0.0:1212".to_string(),
-                admin_token: "MyAccessToken".to_string(),
+                admin_token: "MyAccessToken".into(),
                 domain: None,
                 use_tls_proxy: None,
             },
             health_check_api: HealthCheckApiSection::default(),
+            version: None,
+            overrides: None,
+            replicas: None,
         };
 
         let config: TrackerConfig = section.try_into().unwrap();
@@ -255,11 +351,14 @@ mod tests {
             http_trackers: vec![],
             http_api: HttpApiSection {
                 bind_address: "0.0.0.0:1212".to_string(),
-                admin_token: "MyAccessToken".to_string(),
+                admin_token: "MyAccessToken".into(),
                 domain: None,
                 use_tls_proxy: None,
             },
             health_check_api: HealthCheckApiSection::default(),
+            version: None,
+            overrides: None,
+            replicas: None,
         };
 
         let result: Result<TrackerConfig, CreateConfigError> = section.try_into();
@@ -291,11 +390,14 @@ mod tests {
             }],
             http_api: HttpApiSection {
                 bind_address: "0.0.0.0:1212".to_string(),
-                admin_token: "MyAccessToken".to_string(),
+                admin_token: "MyAccessToken".into(),
                 domain: None,
                 use_tls_proxy: None,
             },
             health_check_api: HealthCheckApiSection::default(),
+            version: None,
+            overrides: None,
+            replicas: None,
         };
 
         let json = serde_json::to_string(&section).unwrap();
@@ -355,11 +457,14 @@ mod tests {
             }],
             http_api: HttpApiSection {
                 bind_address: "0.0.0.0:7070".to_string(),
-                admin_token: "token".to_string(),
+                admin_token: "token".into(),
                 domain: None,
                 use_tls_proxy: None,
             },
             health_check_api: HealthCheckApiSection::default(),
+            version: None,
+            overrides: None,
+            replicas: None,
         };
 
         let result: Result<TrackerConfig, CreateConfigError> = section.try_into();
@@ -391,14 +496,101 @@ mod tests {
             }],
             http_api: HttpApiSection {
                 bind_address: "0.0.0.0:1212".to_string(),
-                admin_token: "token".to_string(),
+                admin_token: "token".into(),
                 domain: None,
                 use_tls_proxy: None,
             },
             health_check_api: HealthCheckApiSection::default(),
+            version: None,
+            overrides: None,
+            replicas: None,
         };
 
         let result: Result<TrackerConfig, CreateConfigError> = section.try_into();
         assert!(result.is_ok());
     }
+
+    #[test]
+    fn it_should_deploy_the_configured_tracker_version() {
+        let section = TrackerSection {
+            version: Some("3.0.0".to_string()),
+            ..TrackerSection::default()
+        };
+
+        let config: TrackerConfig = section.try_into().unwrap();
+
+        assert_eq!(config.image().full_reference(), "torrust/tracker:3.0.0");
+    }
+
+    #[test]
+    fn it_should_reject_a_malformed_tracker_version() {
+        let section = TrackerSection {
+            version: Some("3.0 beta".to_string()),
+            ..TrackerSection::default()
+        };
+
+        let result: Result<TrackerConfig, CreateConfigError> = section.try_into();
+
+        assert!(matches!(
+            result,
+            Err(CreateConfigError::TrackerVersionInvalid(_))
+        ));
+    }
+
+    #[test]
+    fn it_should_run_the_configured_number_of_replicas() {
+        let section = TrackerSection {
+            core: TrackerCoreSection {
+                database: DatabaseSection::Mysql {
+                    host: "mysql".to_string(),
+                    port: 3306,
+                    database_name: "torrust_tracker".to_string(),
+                    username: "tracker_user".to_string(),
+                    password: "tracker_password".into(),
+                    root_password: None,
+                    min_password_length: None,
+                    expose_publicly: false,
+                },
+                private: false,
+            },
+            replicas: NonZeroU8::new(2),
+            ..TrackerSection::default()
+        };
+
+        let config: TrackerConfig = section.try_into().unwrap();
+
+        assert_eq!(config.replicas().get(), 2);
+    }
+
+    #[test]
+    fn it_should_reject_several_replicas_with_a_sqlite_database() {
+        let section = TrackerSection {
+            replicas: NonZeroU8::new(2),
+            ..TrackerSection::default()
+        };
+
+        let result: Result<TrackerConfig, CreateConfigError> = section.try_into();
+
+        assert!(matches!(
+            result,
+            Err(CreateConfigError::TrackerConfigValidation(
+                TrackerConfigError::ReplicasWithSqlite { .. }
+            ))
+        ));
+    }
+
+    #[test]
+    fn it_should_reject_zero_replicas_when_parsing_section() {
+        let json = r#"{
+            "core": { "database": { "driver": "sqlite3", "database_name": "tracker.db" }, "private": false },
+            "udp_trackers": [],
+            "http_trackers": [],
+            "http_api": { "bind_address": "0.0.0.0:1212", "admin_token": "MyAccessToken" },
+            "replicas": 0
+        }"#;
+
+        let result: Result<TrackerSection, _> = serde_json::from_str(json);
+
+        assert!(result.is_err());
+    }
 }
@@ -40,12 +40,19 @@
 
 use std::convert::TryFrom;
 use std::convert::TryInto;
+use std::path::PathBuf;
 
-use crate::domain::environment::EnvironmentParams;
+use crate::domain::environment::{EnvironmentParams, Labels, UserInputs, UserInputsError};
 use crate::domain::https::HttpsConfig;
-use crate::domain::{EnvironmentName, InstanceName};
+use crate::domain::provision::{CloudInitCustomization, InstanceResources};
+use crate::domain::release::ReleaseConfig;
+use crate::domain::template::ExtraTemplateVariables;
+use crate::domain::{
+    EnvironmentName, EnvironmentNameError, InstanceName, Provider, ProviderConfig,
+};
 
 use super::errors::CreateConfigError;
+use super::validation::ConfigIssues;
 use super::EnvironmentCreationConfig;
 
 impl TryFrom<EnvironmentCreationConfig> for EnvironmentParams {
@@ -58,12 +65,25 @@ impl TryFrom<EnvironmentCreationConfig> for EnvironmentParams {
     ///
     /// # Validation
     ///
-    /// - Environment name must follow naming rules
+    /// - Environment name must follow naming rules and not be reserved
+    /// - Environment name must leave the generated instance name within the
+    ///   provider's limit (unless `instance_name` is provided)
     /// - Instance name (if provided) must follow instance naming rules
     /// - Provider config must be valid (e.g., valid profile name for LXD)
     /// - SSH username must follow Linux username requirements
     /// - SSH key files must exist and be accessible
     /// - SSH key paths must be absolute
+    /// - Templates override directory (if provided) must exist
+    /// - Instance sizing (if provided) must be in range and the provider must be LXD
+    /// - Cloud-init additions (if provided) must be valid and the provider must not be Docker
+    /// - System tuning (if provided) must only set allowlisted network and file system
+    ///   keys, and the provider must not be Docker
+    /// - Offline mode (if provided) must be valid, the provider must be LXD and it must
+    ///   replace every download of the deployment
+    /// - Proxy (if provided) must set an http:// or https:// URL for 'http', 'https' or both
+    /// - Release images (if provided) must be valid image references, and the tracker
+    ///   image must not be set by both 'tracker.version' and 'release.images.tracker'
+    /// - No two enabled services may publish the same host port and protocol
     ///
     /// # Instance Name Auto-Generation
     ///
@@ -73,60 +93,355 @@ impl TryFrom<EnvironmentCreationConfig> for EnvironmentParams {
     /// # Errors
     ///
     /// Returns `CreateConfigError` if any validation fails. All error variants
-    /// implement `.help()` with detailed troubleshooting guidance.
+    /// implement `.help()` with detailed troubleshooting guidance. When several
+    /// fields are invalid, `CreateConfigError::InvalidConfiguration` lists them
+    /// all with their JSON paths.
     fn try_from(config: EnvironmentCreationConfig) -> Result<Self, Self::Error> {
-        // Convert environment name string to domain type
-        let environment_name = EnvironmentName::new(&config.environment.name)?;
+        // Every section is converted, so that all invalid fields are reported at once
+        let mut issues = ConfigIssues::default();
+        let params = convert(config, &mut issues);
+        let params = issues.finish(params)?;
 
-        // Instance name: use provided or auto-generate from environment name
-        let instance_name = match &config.environment.instance_name {
-            Some(name_str) => InstanceName::new(name_str.clone()).map_err(|e| {
+        // An offline deployment fails on the first download it cannot replace
+        if let Some(offline) = &params.offline {
+            let uncovered = offline.uncovered(params.external_resources());
+            if !uncovered.is_empty() {
+                return Err(CreateConfigError::OfflineResourcesRequired {
+                    resources: uncovered
+                        .iter()
+                        .map(ToString::to_string)
+                        .collect::<Vec<_>>()
+                        .join("; "),
+                });
+            }
+        }
+
+        Ok(params)
+    }
+}
+
+/// Converts every section, recording the error of each invalid field
+///
+/// Returns `None` when an error was recorded. Checks that depend on other
+/// sections only run when those sections are valid.
+#[allow(clippy::too_many_lines)] // One step per configuration section
+fn convert(
+    config: EnvironmentCreationConfig,
+    issues: &mut ConfigIssues,
+) -> Option<EnvironmentParams> {
+    // Convert environment name string to domain type
+    let environment_name = issues.check(
+        "environment.name",
+        EnvironmentName::new(&config.environment.name).map_err(CreateConfigError::from),
+    );
+
+    // Convert ProviderSection (DTO) to domain ProviderConfig, generating
+    // the LXD profile name from the environment name when not provided
+    let provider_config: Option<ProviderConfig> = environment_name.as_ref().and_then(|name| {
+        issues.check(
+            "provider",
+            config.provider.with_generated_profile_name(name).try_into(),
+        )
+    });
+    let provider = provider_config.as_ref().map(ProviderConfig::provider);
+
+    // Instance name: use provided or auto-generate from environment name,
+    // which must leave the generated name within the provider's limit
+    let instance_name = match &config.environment.instance_name {
+        Some(name_str) => issues.check(
+            "environment.instance_name",
+            InstanceName::new(name_str.clone()).map_err(|e| {
                 CreateConfigError::InvalidInstanceName {
                     name: name_str.clone(),
                     reason: e.to_string(),
                 }
-            })?,
-            None => generate_instance_name(&environment_name),
-        };
+            }),
+        ),
+        None => match (&environment_name, provider) {
+            (Some(environment_name), Some(provider)) => issues.check(
+                "environment.name",
+                generate_checked_instance_name(provider, environment_name)
+                    .map_err(CreateConfigError::from),
+            ),
+            _ => None,
+        },
+    };
 
-        // Convert ProviderSection (DTO) to domain ProviderConfig
-        let provider_config = config.provider.try_into()?;
+    // Convert Instance section to domain type; only LXD sizes the instance itself
+    let instance_resources = match config.instance {
+        Some(_) if provider.is_some_and(|provider| provider != Provider::Lxd) => {
+            issues.push(
+                "instance",
+                CreateConfigError::InstanceSizingNotSupported {
+                    provider: provider.map(|p| p.to_string()).unwrap_or_default(),
+                },
+            );
+            None
+        }
+        Some(section) => issues.check("instance", section.try_into()),
+        None => Some(InstanceResources::default()),
+    };
 
-        // Get SSH port before consuming ssh_credentials
-        let ssh_port = config.ssh_credentials.port;
+    // Get SSH port before consuming ssh_credentials
+    let ssh_port = config.ssh_credentials.port;
 
-        // Convert SSH credentials config to domain type
-        let ssh_credentials = config.ssh_credentials.try_into()?;
+    // Convert SSH credentials config to domain type
+    let ssh_credentials = issues.check("ssh_credentials", config.ssh_credentials.try_into());
 
-        // Convert TrackerSection (DTO) to domain TrackerConfig
-        let tracker_config = config.tracker.try_into()?;
+    // Convert TrackerSection (DTO) to domain TrackerConfig
+    let tracker_config = config.tracker.convert("tracker", issues);
 
-        // Convert Prometheus and Grafana sections to domain types
-        let prometheus_config = config.prometheus.map(TryInto::try_into).transpose()?;
-        let grafana_config = config.grafana.map(TryInto::try_into).transpose()?;
+    // Convert Prometheus and Grafana sections to domain types
+    let prometheus_config = issues.check(
+        "prometheus",
+        config.prometheus.map(TryInto::try_into).transpose(),
+    );
+    let grafana_config = issues.check("grafana", config.grafana.map(TryInto::try_into).transpose());
 
-        // Convert HTTPS section to domain type with email validation
-        let https_config = config
+    // Convert HTTPS section to domain type with email validation
+    let https_config = issues.check(
+        "https.admin_email",
+        config
             .https
             .map(|section| HttpsConfig::new(section.admin_email, section.use_staging))
-            .transpose()?;
+            .transpose()
+            .map_err(CreateConfigError::from),
+    );
+
+    // Cross-service invariants are checked here too, as `validate` never
+    // builds the user inputs that enforce them at creation time
+    if let (
+        Some(tracker_config),
+        Some(prometheus_config),
+        Some(grafana_config),
+        Some(https_config),
+    ) = (
+        &tracker_config,
+        &prometheus_config,
+        &grafana_config,
+        &https_config,
+    ) {
+        if let Err(error) = UserInputs::check_cross_service_invariants(
+            tracker_config,
+            prometheus_config.as_ref(),
+            grafana_config.as_ref(),
+            https_config.as_ref(),
+        ) {
+            issues.push(cross_service_path(&error), error.into());
+        }
+        if let Some(provider) = provider {
+            if let Err(error) = UserInputs::check_ssh_port(
+                ssh_port,
+                provider,
+                tracker_config,
+                prometheus_config.as_ref(),
+                grafana_config.as_ref(),
+                https_config.as_ref(),
+            ) {
+                issues.push("ssh_credentials.port", error.into());
+            }
+        }
+    }
+
+    // Convert Backup section to domain type
+    let backup_config = issues.check("backup", config.backup.map(TryInto::try_into).transpose());
+
+    // Convert Provision section to domain type, defaulting when absent
+    let provision_config = issues.check(
+        "provision",
+        config
+            .provision
+            .map(TryInto::try_into)
+            .transpose()
+            .map(Option::unwrap_or_default),
+    );
+
+    // Convert Firewall section to domain type, defaulting when absent
+    let firewall_config = issues.check(
+        "firewall",
+        config
+            .firewall
+            .map(TryInto::try_into)
+            .transpose()
+            .map(Option::unwrap_or_default),
+    );
+
+    // Convert System tuning section to domain type; Docker containers share the host kernel
+    let system_tuning = match config.system_tuning {
+        Some(_) if provider == Some(Provider::Docker) => {
+            issues.push(
+                "system_tuning",
+                CreateConfigError::SystemTuningNotSupported {
+                    provider: Provider::Docker.to_string(),
+                },
+            );
+            None
+        }
+        section => issues.check(
+            "system_tuning",
+            section
+                .map(TryInto::try_into)
+                .transpose()
+                .map(Option::unwrap_or_default),
+        ),
+    };
+
+    // Convert Cloud-init section to domain type; Docker containers do not run cloud-init
+    let cloud_init = match config.cloud_init {
+        Some(_) if provider == Some(Provider::Docker) => {
+            issues.push(
+                "cloud_init",
+                CreateConfigError::CloudInitNotSupported {
+                    provider: Provider::Docker.to_string(),
+                },
+            );
+            None
+        }
+        Some(section) => issues.check("cloud_init", section.try_into()),
+        None => Some(CloudInitCustomization::default()),
+    };
+
+    // Convert Offline section to domain type; only LXD creates instances from local images
+    let offline = match config.offline {
+        Some(_) if provider.is_some_and(|provider| provider != Provider::Lxd) => {
+            issues.push(
+                "offline",
+                CreateConfigError::OfflineModeNotSupported {
+                    provider: provider.map(|p| p.to_string()).unwrap_or_default(),
+                },
+            );
+            None
+        }
+        section => issues.check("offline", section.map(TryInto::try_into).transpose()),
+    };
 
-        // Convert Backup section to domain type
-        let backup_config = config.backup.map(TryInto::try_into).transpose()?;
+    // Convert Proxy section to domain type
+    let proxy = issues.check("proxy", config.proxy.map(TryInto::try_into).transpose());
 
-        Ok(EnvironmentParams::new(
-            environment_name,
-            instance_name,
-            provider_config,
-            ssh_credentials,
+    // Convert Release section to domain type; only one setting may select the tracker image
+    let release: Option<Option<ReleaseConfig>> =
+        issues.check("release", config.release.map(TryInto::try_into).transpose());
+    if let (Some(tracker_config), Some(Some(release))) = (&tracker_config, &release) {
+        if tracker_config.version().is_some() && release.images().tracker.is_some() {
+            issues.push(
+                "release.images.tracker",
+                CreateConfigError::TrackerImageConflict,
+            );
+        }
+    }
+
+    // Labels are validated here so invalid keys are reported before anything is created
+    let labels = issues.check(
+        "labels",
+        Labels::try_from(config.labels).map_err(CreateConfigError::from),
+    );
+
+    // Convert Traces section to domain type, defaulting when absent
+    let traces = issues.check(
+        "traces",
+        config
+            .traces
+            .map(TryInto::try_into)
+            .transpose()
+            .map(Option::unwrap_or_default),
+    );
+
+    // Variable names are checked here so a typo fails before any template is rendered
+    let extra_template_variables = issues.check(
+        "extra_template_vars",
+        ExtraTemplateVariables::try_from(config.extra_template_vars)
+            .map_err(CreateConfigError::from),
+    );
+
+    // Template override directory must exist so overrides are not silently ignored
+    let templates_override_dir = issues.check(
+        "templates_override_dir",
+        config
+            .templates_override_dir
+            .map(PathBuf::from)
+            .map(validate_templates_override_dir)
+            .transpose(),
+    );
+
+    if !issues.is_empty() {
+        return None;
+    }
+
+    Some(
+        EnvironmentParams::new(
+            environment_name?,
+            instance_name?,
+            provider_config?,
+            ssh_credentials?,
             ssh_port,
-            tracker_config,
-            prometheus_config,
-            grafana_config,
-            https_config,
-            backup_config,
-        ))
+            tracker_config?,
+            prometheus_config?,
+            grafana_config?,
+            https_config?,
+            backup_config?,
+        )
+        .with_templates_override_dir(templates_override_dir?)
+        .with_extra_template_variables(extra_template_variables?)
+        .with_provision_config(provision_config?)
+        .with_firewall_config(firewall_config?)
+        .with_system_tuning(system_tuning?)
+        .with_cloud_init(cloud_init?)
+        .with_instance_resources(instance_resources?)
+        .with_offline(offline?)
+        .with_proxy(proxy?)
+        .with_release(release?)
+        .with_labels(labels?)
+        .with_traces(traces?),
+    )
+}
+
+/// Check that a templates override directory is absolute and exists
+///
+/// The path is stored with the environment and read by later commands, which
+/// may run from another directory, so relative paths are rejected like SSH
+/// key paths are.
+pub(super) fn validate_templates_override_dir(path: PathBuf) -> Result<PathBuf, CreateConfigError> {
+    if !path.is_absolute() {
+        return Err(CreateConfigError::RelativeTemplatesOverrideDir { path });
+    }
+
+    if !path.is_dir() {
+        return Err(CreateConfigError::TemplatesOverrideDirNotFound { path });
     }
+
+    Ok(path)
+}
+
+/// JSON path of the section to fix for a violated cross-service invariant
+///
+/// Empty for port conflicts, which involve two services.
+fn cross_service_path(error: &UserInputsError) -> &'static str {
+    match error {
+        UserInputsError::GrafanaRequiresPrometheus => "prometheus",
+        UserInputsError::HttpsSectionWithoutTlsServices
+        | UserInputsError::TlsServicesWithoutHttpsSection => "https",
+        UserInputsError::SshPortConflict { .. } | UserInputsError::SshPortReserved { .. } => {
+            "ssh_credentials.port"
+        }
+        _ => "",
+    }
+}
+
+/// Generates the instance name of `env_name` once it fits the provider's limit
+///
+/// Shared by `create` and `clone`, so both name instances the same way.
+///
+/// # Errors
+///
+/// Returns `EnvironmentNameError::TooLong` if the generated instance name
+/// would be too long for the provider.
+pub(crate) fn generate_checked_instance_name(
+    provider: Provider,
+    env_name: &EnvironmentName,
+) -> Result<InstanceName, EnvironmentNameError> {
+    provider.check_environment_name_length(env_name)?;
+
+    Ok(generate_instance_name(env_name))
 }
 
 /// Generates an instance name from the environment name
@@ -135,10 +450,11 @@ impl TryFrom<EnvironmentCreationConfig> for EnvironmentParams {
 ///
 /// # Panics
 ///
-/// This function does not panic. The generated instance name is guaranteed
-/// to be valid for any valid environment name.
+/// This function does not panic as long as the environment name passed
+/// `Provider::check_environment_name_length`: the generated instance name is
+/// then guaranteed to be valid.
 fn generate_instance_name(env_name: &EnvironmentName) -> InstanceName {
-    let instance_name_str = format!("torrust-tracker-vm-{}", env_name.as_str());
+    let instance_name_str = format!("{}{}", InstanceName::GENERATED_PREFIX, env_name.as_str());
     InstanceName::new(instance_name_str)
         .expect("Generated instance name should always be valid for valid environment names")
 }
@@ -149,8 +465,10 @@ mod tests {
     use crate::application::command_handlers::create::config::provider::LxdProviderSection;
     use crate::application::command_handlers::create::config::tracker::TrackerSection;
     use crate::application::command_handlers::create::config::{
-        EnvironmentSection, ProviderSection, SshCredentialsConfig,
+        CloudInitSection, EnvironmentSection, InstanceSection, OfflineImageSection, OfflineSection,
+        ProviderSection, SshCredentialsConfig,
     };
+    use crate::domain::EnvironmentNameError;
 
     /// Helper to create a valid configuration for testing
     fn valid_config() -> EnvironmentCreationConfig {
@@ -166,7 +484,8 @@ mod tests {
             },
             SshCredentialsConfig::new(private_key_path, public_key_path, "torrust".to_string(), 22),
             ProviderSection::Lxd(LxdProviderSection {
-                profile_name: "lxd-test-env".to_string(),
+                profile_name: Some("lxd-test-env".to_string()),
+                remote: None,
             }),
             TrackerSection::default(),
             None,
@@ -188,6 +507,310 @@ mod tests {
         assert_eq!(params.ssh_port, 22);
     }
 
+    #[test]
+    fn it_should_reject_an_environment_name_too_long_for_the_lxd_instance_name() {
+        let mut config = valid_config();
+        config.environment.name = format!("env-{}", "a".repeat(56));
+
+        let result: Result<EnvironmentParams, CreateConfigError> = config.try_into();
+
+        match result.unwrap_err() {
+            error @ CreateConfigError::InvalidEnvironmentName(EnvironmentNameError::TooLong {
+                length: 60,
+                max_length: 44,
+                ..
+            }) => {
+                assert!(error
+                    .to_string()
+                    .contains("is 60 characters long, but the lxd provider allows at most 44"));
+            }
+            other => panic!("Expected TooLong environment name error, got: {other:?}"),
+        }
+    }
+
+    #[test]
+    fn it_should_accept_an_environment_name_filling_the_lxd_instance_name() {
+        let mut config = valid_config();
+        config.environment.name = format!("env-{}", "a".repeat(40));
+
+        let params: EnvironmentParams = config.try_into().unwrap();
+
+        assert_eq!(
+            params.instance_name.as_str().len(),
+            InstanceName::MAX_LENGTH
+        );
+    }
+
+    #[test]
+    fn it_should_not_limit_the_environment_name_when_the_instance_name_is_given() {
+        let mut config = valid_config();
+        config.environment.name = format!("env-{}", "a".repeat(56));
+        config.environment.instance_name = Some("tracker-vm".to_string());
+
+        let params: EnvironmentParams = config.try_into().unwrap();
+
+        assert_eq!(params.instance_name.as_str(), "tracker-vm");
+    }
+
+    #[test]
+    fn it_should_keep_existing_templates_override_dir() {
+        let override_dir = tempfile::TempDir::new().unwrap();
+        let config = valid_config()
+            .with_templates_override_dir(Some(override_dir.path().to_string_lossy().to_string()));
+
+        let params: EnvironmentParams = config.try_into().unwrap();
+
+        assert_eq!(
+            params.templates_override_dir.as_deref(),
+            Some(override_dir.path())
+        );
+    }
+
+    #[test]
+    fn it_should_reject_missing_templates_override_dir() {
+        let config = valid_config()
+            .with_templates_override_dir(Some("/nonexistent/template-overrides".to_string()));
+
+        let result: Result<EnvironmentParams, CreateConfigError> = config.try_into();
+
+        assert!(matches!(
+            result,
+            Err(CreateConfigError::TemplatesOverrideDirNotFound { .. })
+        ));
+    }
+
+    #[test]
+    fn it_should_reject_relative_templates_override_dir() {
+        let config =
+            valid_config().with_templates_override_dir(Some("./template-overrides".to_string()));
+
+        let result: Result<EnvironmentParams, CreateConfigError> = config.try_into();
+
+        assert!(matches!(
+            result,
+            Err(CreateConfigError::RelativeTemplatesOverrideDir { .. })
+        ));
+    }
+
+    #[test]
+    fn it_should_read_the_instance_sizing() {
+        let mut config = valid_config();
+        config.instance = Some(InstanceSection {
+            memory_mb: 4096,
+            ..InstanceSection::default()
+        });
+
+        let params: EnvironmentParams = config.try_into().unwrap();
+
+        assert_eq!(params.instance_resources.memory_mb(), 4096);
+        assert_eq!(params.instance_resources.cpus(), 2);
+    }
+
+    #[test]
+    fn it_should_reject_instance_sizing_for_cloud_providers() {
+        use crate::application::command_handlers::create::config::provider::HetznerProviderSection;
+        use crate::application::command_handlers::create::config::SecretSource;
+
+        let mut config = valid_config();
+        config.provider = ProviderSection::Hetzner(HetznerProviderSection {
+            api_token: SecretSource::Plain("token".to_string()),
+            server_type: "cx22".to_string(),
+            location: "nbg1".to_string(),
+            image: "ubuntu-24.04".to_string(),
+        });
+        config.instance = Some(InstanceSection::default());
+
+        let result: Result<EnvironmentParams, CreateConfigError> = config.try_into();
+
+        assert!(matches!(
+            result,
+            Err(CreateConfigError::InstanceSizingNotSupported { .. })
+        ));
+    }
+
+    #[test]
+    fn it_should_read_the_cloud_init_additions() {
+        let mut config = valid_config();
+        config.cloud_init = Some(CloudInitSection {
+            extra_packages: vec!["htop".to_string()],
+            ..CloudInitSection::default()
+        });
+
+        let params: EnvironmentParams = config.try_into().unwrap();
+
+        assert_eq!(params.cloud_init.extra_packages(), ["htop"]);
+    }
+
+    fn offline_section(registry_mirror: Option<&str>) -> OfflineSection {
+        OfflineSection {
+            image: OfflineImageSection {
+                source: "torrust-ubuntu-24.04".to_string(),
+            },
+            apt_mirror: Some("http://mirror.lab.internal/ubuntu".to_string()),
+            packages_dir: None,
+            registry_mirror: registry_mirror.map(ToString::to_string),
+        }
+    }
+
+    #[test]
+    fn it_should_read_an_offline_mode_replacing_every_download() {
+        let mut config = valid_config();
+        config.offline = Some(offline_section(Some("https://registry.lab.internal")));
+
+        let params: EnvironmentParams = config.try_into().unwrap();
+
+        assert_eq!(
+            params
+                .offline
+                .map(|offline| offline.image_alias().to_string()),
+            Some("torrust-ubuntu-24.04".to_string())
+        );
+    }
+
+    #[test]
+    fn it_should_list_the_downloads_an_offline_mode_does_not_replace() {
+        let mut config = valid_config();
+        config.offline = Some(offline_section(None));
+
+        let result: Result<EnvironmentParams, CreateConfigError> = config.try_into();
+
+        let Err(CreateConfigError::OfflineResourcesRequired { resources }) = result else {
+            panic!("expected OfflineResourcesRequired, got {result:?}");
+        };
+        assert!(resources.contains("container image 'torrust/tracker:"));
+        assert!(!resources.contains("Ubuntu packages"));
+    }
+
+    #[test]
+    fn it_should_reject_offline_mode_for_cloud_providers() {
+        use crate::application::command_handlers::create::config::provider::HetznerProviderSection;
+        use crate::application::command_handlers::create::config::SecretSource;
+
+        let mut config = valid_config();
+        config.provider = ProviderSection::Hetzner(HetznerProviderSection {
+            api_token: SecretSource::Plain("token".to_string()),
+            server_type: "cx22".to_string(),
+            location: "nbg1".to_string(),
+            image: "ubuntu-24.04".to_string(),
+        });
+        config.offline = Some(offline_section(Some("https://registry.lab.internal")));
+
+        let result: Result<EnvironmentParams, CreateConfigError> = config.try_into();
+
+        assert!(matches!(
+            result,
+            Err(CreateConfigError::OfflineModeNotSupported { .. })
+        ));
+    }
+
+    #[test]
+    fn it_should_reject_cloud_init_additions_for_docker() {
+        use crate::application::command_handlers::create::config::provider::DockerProviderSection;
+
+        let mut config = valid_config();
+        config.provider = ProviderSection::Docker(DockerProviderSection {
+            image: "torrust-provisioned-instance:latest".to_string(),
+        });
+        config.cloud_init = Some(CloudInitSection::default());
+
+        let result: Result<EnvironmentParams, CreateConfigError> = config.try_into();
+
+        assert!(matches!(
+            result,
+            Err(CreateConfigError::CloudInitNotSupported { .. })
+        ));
+    }
+
+    #[test]
+    fn it_should_reject_system_tuning_for_docker() {
+        use crate::application::command_handlers::create::config::provider::DockerProviderSection;
+        use crate::application::command_handlers::create::config::SystemTuningSection;
+
+        let mut config = valid_config();
+        config.provider = ProviderSection::Docker(DockerProviderSection {
+            image: "torrust-provisioned-instance:latest".to_string(),
+        });
+        config.system_tuning = Some(SystemTuningSection::Preset("high-udp".to_string()));
+
+        let result: Result<EnvironmentParams, CreateConfigError> = config.try_into();
+
+        assert!(matches!(
+            result,
+            Err(CreateConfigError::SystemTuningNotSupported { .. })
+        ));
+    }
+
+    #[test]
+    fn it_should_reject_an_ssh_port_reserved_by_the_provider() {
+        use crate::domain::environment::UserInputsError;
+
+        let mut config = valid_config();
+        config.ssh_credentials.port = 8443;
+
+        let result: Result<EnvironmentParams, CreateConfigError> = config.try_into();
+
+        assert!(matches!(
+            result,
+            Err(CreateConfigError::CrossServiceValidation(
+                UserInputsError::SshPortReserved { ssh_port: 8443, .. }
+            ))
+        ));
+    }
+
+    #[test]
+    fn it_should_read_the_labels() {
+        let mut config = valid_config();
+        config
+            .labels
+            .insert("purpose".to_string(), "e2e".to_string());
+
+        let params: EnvironmentParams = config.try_into().unwrap();
+
+        assert_eq!(params.labels.get("purpose"), Some("e2e"));
+    }
+
+    #[test]
+    fn it_should_reject_invalid_label_keys() {
+        let mut config = valid_config();
+        config
+            .labels
+            .insert("Team".to_string(), "infra".to_string());
+
+        let result: Result<EnvironmentParams, CreateConfigError> = config.try_into();
+
+        assert!(matches!(result, Err(CreateConfigError::InvalidLabel(_))));
+    }
+
+    #[test]
+    fn it_should_read_the_extra_template_variables() {
+        let mut config = valid_config();
+        config
+            .extra_template_vars
+            .insert("motd".to_string(), serde_json::json!("hello"));
+
+        let params: EnvironmentParams = config.try_into().unwrap();
+
+        assert_eq!(
+            params.extra_template_variables.get("motd"),
+            Some(&serde_json::json!("hello"))
+        );
+    }
+
+    #[test]
+    fn it_should_reject_extra_template_variables_that_are_not_tera_identifiers() {
+        let mut config = valid_config();
+        config
+            .extra_template_vars
+            .insert("my-motd".to_string(), serde_json::json!("hello"));
+
+        let result: Result<EnvironmentParams, CreateConfigError> = config.try_into();
+
+        assert!(matches!(
+            result,
+            Err(CreateConfigError::InvalidExtraTemplateVariable(_))
+        ));
+    }
+
     #[test]
     fn it_should_use_custom_instance_name_when_provided() {
         let project_root = env!("CARGO_MANIFEST_DIR");
@@ -202,7 +825,8 @@ mod tests {
             },
             SshCredentialsConfig::new(private_key_path, public_key_path, "torrust".to_string(), 22),
             ProviderSection::Lxd(LxdProviderSection {
-                profile_name: "lxd-my-env".to_string(),
+                profile_name: Some("lxd-my-env".to_string()),
+                remote: None,
             }),
             TrackerSection::default(),
             None,
@@ -229,7 +853,8 @@ mod tests {
             },
             SshCredentialsConfig::new(private_key_path, public_key_path, "torrust".to_string(), 22),
             ProviderSection::Lxd(LxdProviderSection {
-                profile_name: "lxd-test".to_string(),
+                profile_name: Some("lxd-test".to_string()),
+                remote: None,
             }),
             TrackerSection::default(),
             None,
@@ -242,6 +867,30 @@ mod tests {
         assert!(result.is_err());
     }
 
+    #[test]
+    fn it_should_reject_a_tracker_image_set_by_both_the_version_and_the_release_section() {
+        use crate::application::command_handlers::create::config::{
+            ReleaseImagesSection, ReleaseSection,
+        };
+
+        let mut config = valid_config();
+        config.tracker.version = Some("3.0.0".to_string());
+        config.release = Some(ReleaseSection {
+            images: ReleaseImagesSection {
+                tracker: Some("registry.corp.internal/torrust/tracker:3.0.0".to_string()),
+                ..ReleaseImagesSection::default()
+            },
+            registry: None,
+        });
+
+        let result: Result<EnvironmentParams, CreateConfigError> = config.try_into();
+
+        assert!(matches!(
+            result,
+            Err(CreateConfigError::TrackerImageConflict)
+        ));
+    }
+
     #[test]
     fn it_should_provide_named_field_access() {
         use crate::adapters::ssh::SshCredentials;
@@ -0,0 +1,284 @@
+//! Validation issues of an environment configuration
+//!
+//! Converting a configuration to domain types reports every invalid field at
+//! once instead of stopping at the first one, so a hand-written file can be
+//! fixed in a single pass. Each [`ConfigValidationIssue`] carries the JSON
+//! path of the field it is about, e.g. `tracker.http_api.bind_address`.
+
+use std::fmt;
+
+use serde::Serialize;
+
+use super::errors::CreateConfigError;
+
+/// How serious a validation issue is
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum IssueSeverity {
+    /// The configuration cannot be used
+    Error,
+
+    /// The configuration is valid but likely to fail the deployment
+    Warning,
+}
+
+impl fmt::Display for IssueSeverity {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Error => write!(f, "error"),
+            Self::Warning => write!(f, "warning"),
+        }
+    }
+}
+
+/// A problem found in a configuration, located by its JSON path
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct ConfigValidationIssue {
+    /// JSON path of the field, e.g. `tracker.udp_trackers[0].bind_address`
+    ///
+    /// Empty when the issue involves several sections, such as two services
+    /// publishing the same port.
+    pub path: String,
+
+    /// Whether the issue fails the validation
+    pub severity: IssueSeverity,
+
+    /// What is wrong
+    pub message: String,
+}
+
+impl ConfigValidationIssue {
+    /// An issue that makes the configuration unusable
+    #[must_use]
+    pub fn error(path: impl Into<String>, message: impl Into<String>) -> Self {
+        Self {
+            path: path.into(),
+            severity: IssueSeverity::Error,
+            message: message.into(),
+        }
+    }
+
+    /// A setting that is valid but likely to fail the deployment
+    #[must_use]
+    pub fn warning(path: impl Into<String>, message: impl Into<String>) -> Self {
+        Self {
+            path: path.into(),
+            severity: IssueSeverity::Warning,
+            message: message.into(),
+        }
+    }
+}
+
+impl fmt::Display for ConfigValidationIssue {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.path.is_empty() {
+            write!(f, "{}: {}", self.severity, self.message)
+        } else {
+            write!(f, "{} at {}: {}", self.severity, self.path, self.message)
+        }
+    }
+}
+
+/// An error waiting to be reported, with the JSON path it was found at
+#[derive(Debug)]
+enum PendingIssue {
+    /// An error of one field, kept whole while it may be the only one
+    Error {
+        path: String,
+        error: CreateConfigError,
+    },
+
+    /// An issue already flattened from a nested conversion
+    Issue(ConfigValidationIssue),
+}
+
+/// Collects the errors of independent conversions
+///
+/// Each conversion is checked with [`Self::check`], which records its error
+/// and lets the next conversion run. [`Self::finish`] then reports a single
+/// error unchanged, or every error in
+/// [`CreateConfigError::InvalidConfiguration`].
+#[derive(Debug, Default)]
+pub(crate) struct ConfigIssues {
+    pending: Vec<PendingIssue>,
+}
+
+impl ConfigIssues {
+    /// Record the error of a conversion of the section or field at `path`
+    ///
+    /// The field of errors tied to one field (e.g. `bind_address` for an
+    /// invalid bind address) is appended to `path`.
+    pub(crate) fn check<T>(
+        &mut self,
+        path: &str,
+        result: Result<T, CreateConfigError>,
+    ) -> Option<T> {
+        match result {
+            Ok(value) => Some(value),
+            Err(error) => {
+                self.push(path, error);
+                None
+            }
+        }
+    }
+
+    /// Record an error found at `path`
+    pub(crate) fn push(&mut self, path: &str, error: CreateConfigError) {
+        match error {
+            CreateConfigError::InvalidConfiguration { issues } => {
+                self.pending.extend(issues.into_iter().map(|issue| {
+                    PendingIssue::Issue(ConfigValidationIssue {
+                        path: join(path, &issue.path),
+                        ..issue
+                    })
+                }));
+            }
+            error => {
+                let path = match error.field() {
+                    Some(field) => join(path, field),
+                    None => path.to_string(),
+                };
+                self.pending.push(PendingIssue::Error { path, error });
+            }
+        }
+    }
+
+    /// Whether no error was recorded
+    pub(crate) fn is_empty(&self) -> bool {
+        self.pending.is_empty()
+    }
+
+    /// The converted value, or the recorded errors
+    ///
+    /// A single error is returned unchanged so that its `.help()` stays
+    /// available.
+    ///
+    /// # Errors
+    ///
+    /// Returns the recorded error, or [`CreateConfigError::InvalidConfiguration`]
+    /// listing every recorded error when there are several.
+    ///
+    /// # Panics
+    ///
+    /// Panics if no error was recorded and `value` is `None`: a conversion
+    /// only skips its value after recording why.
+    pub(crate) fn finish<T>(mut self, value: Option<T>) -> Result<T, CreateConfigError> {
+        match self.pending.len() {
+            0 => Ok(value.expect("a conversion without errors produces its value")),
+            1 => Err(match self.pending.remove(0) {
+                PendingIssue::Error { error, .. } => error,
+                PendingIssue::Issue(issue) => CreateConfigError::InvalidConfiguration {
+                    issues: vec![issue],
+                },
+            }),
+            _ => Err(CreateConfigError::InvalidConfiguration {
+                issues: self
+                    .pending
+                    .into_iter()
+                    .map(|pending| match pending {
+                        PendingIssue::Error { path, error } => {
+                            ConfigValidationIssue::error(path, error.to_string())
+                        }
+                        PendingIssue::Issue(issue) => issue,
+                    })
+                    .collect(),
+            }),
+        }
+    }
+}
+
+/// One issue per line, e.g. `  - error at tracker.http_api.bind_address: ...`
+pub(crate) fn list_issues(issues: &[ConfigValidationIssue]) -> String {
+    issues
+        .iter()
+        .map(|issue| format!("  - {issue}").replace('\n', "\n    "))
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+/// Join two JSON paths, either of which may be empty
+fn join(parent: &str, child: &str) -> String {
+    match (parent.is_empty(), child.is_empty()) {
+        (true, _) => child.to_string(),
+        (false, true) => parent.to_string(),
+        (false, false) => format!("{parent}.{child}"),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn invalid_bind_address() -> CreateConfigError {
+        CreateConfigError::InvalidBindAddress {
+            address: "0.0.0.0:port".to_string(),
+            source: "0.0.0.0:port".parse::<std::net::SocketAddr>().unwrap_err(),
+        }
+    }
+
+    #[test]
+    fn it_should_return_a_single_error_unchanged() {
+        let mut issues = ConfigIssues::default();
+
+        issues.push("tracker.http_api", invalid_bind_address());
+
+        assert!(matches!(
+            issues.finish::<()>(None),
+            Err(CreateConfigError::InvalidBindAddress { .. })
+        ));
+    }
+
+    #[test]
+    fn it_should_list_every_error_with_the_path_of_its_field() {
+        let mut issues = ConfigIssues::default();
+
+        issues.push("tracker.http_api", invalid_bind_address());
+        issues.push("tracker.udp_trackers[1]", invalid_bind_address());
+        issues.push("", CreateConfigError::TrackerImageConflict);
+
+        let Err(CreateConfigError::InvalidConfiguration { issues }) = issues.finish::<()>(None)
+        else {
+            panic!("expected every error to be listed");
+        };
+        let paths: Vec<&str> = issues.iter().map(|issue| issue.path.as_str()).collect();
+        assert_eq!(
+            paths,
+            [
+                "tracker.http_api.bind_address",
+                "tracker.udp_trackers[1].bind_address",
+                ""
+            ]
+        );
+        assert!(issues
+            .iter()
+            .all(|issue| issue.severity == IssueSeverity::Error));
+    }
+
+    #[test]
+    fn it_should_prefix_the_paths_of_errors_collected_by_a_nested_conversion() {
+        let mut nested = ConfigIssues::default();
+        nested.push("http_api", invalid_bind_address());
+        nested.push("udp_trackers[0]", invalid_bind_address());
+        let nested_error = nested.finish::<()>(None).unwrap_err();
+
+        let mut issues = ConfigIssues::default();
+        issues.push("tracker", nested_error);
+
+        let Err(CreateConfigError::InvalidConfiguration { issues }) = issues.finish::<()>(None)
+        else {
+            panic!("expected every error to be listed");
+        };
+        assert_eq!(issues[0].path, "tracker.http_api.bind_address");
+        assert_eq!(issues[1].path, "tracker.udp_trackers[0].bind_address");
+    }
+
+    #[test]
+    fn it_should_display_the_severity_and_the_path_of_an_issue() {
+        let issue = ConfigValidationIssue::warning("proxy", "Only the 'http' proxy is set");
+
+        assert_eq!(
+            issue.to_string(),
+            "warning at proxy: Only the 'http' proxy is set"
+        );
+    }
+}
@@ -4,10 +4,16 @@
 //! command execution. All errors follow the project's error handling principles
 //! by providing clear, contextual, and actionable error messages with `.help()` methods.
 
+use std::path::PathBuf;
+
 use thiserror::Error;
 
+use crate::adapters::ssh::SshKeyGenerationError;
 use crate::application::command_handlers::create::config::CreateConfigError;
 use crate::application::errors::PersistenceError;
+use crate::domain::environment::ConfigDiff;
+use crate::shared::error::kind::ErrorKind;
+use crate::shared::error::traceable::Traceable;
 
 /// Errors that can occur during environment creation command execution
 ///
@@ -23,11 +29,120 @@ pub enum CreateCommandHandlerError {
     #[error("Environment '{name}' already exists")]
     EnvironmentAlreadyExists { name: String },
 
+    /// The environment already exists with another configuration
+    #[error(
+        "Environment '{name}' already exists with a different configuration: {}",
+        describe_changes(diff)
+    )]
+    EnvironmentConfigConflict { name: String, diff: ConfigDiff },
+
+    /// Another environment already uses the instance name
+    #[error("Instance name '{instance_name}' is already used by environment '{environment}'")]
+    InstanceNameInUse {
+        instance_name: String,
+        environment: String,
+    },
+
+    /// The public key does not belong to the private key
+    #[error("SSH public key '{public_key_path}' does not match private key '{private_key_path}'")]
+    SshKeyMismatch {
+        private_key_path: PathBuf,
+        public_key_path: PathBuf,
+    },
+
+    /// Generating the SSH key pair of the environment failed
+    #[error("SSH key pair generation failed: {0}")]
+    SshKeyGenerationFailed(#[source] SshKeyGenerationError),
+
     /// Repository operation failed
     #[error("Repository operation failed")]
     RepositoryError(#[source] PersistenceError),
 }
 
+impl Traceable for CreateCommandHandlerError {
+    fn trace_format(&self) -> String {
+        match self {
+            Self::InvalidConfiguration(e) => {
+                format!("CreateCommandHandlerError: Invalid configuration - {e}")
+            }
+            Self::EnvironmentAlreadyExists { name } => {
+                format!("CreateCommandHandlerError: Environment already exists - '{name}'")
+            }
+            Self::EnvironmentConfigConflict { name, diff } => {
+                format!(
+                    "CreateCommandHandlerError: Environment exists with a different configuration - '{name}': {}",
+                    describe_changes(diff)
+                )
+            }
+            Self::InstanceNameInUse {
+                instance_name,
+                environment,
+            } => {
+                format!(
+                    "CreateCommandHandlerError: Instance name in use - '{instance_name}' by environment '{environment}'"
+                )
+            }
+            Self::SshKeyMismatch {
+                private_key_path,
+                public_key_path,
+            } => {
+                format!(
+                    "CreateCommandHandlerError: SSH key mismatch - public key '{}' does not match private key '{}'",
+                    public_key_path.display(),
+                    private_key_path.display()
+                )
+            }
+            Self::SshKeyGenerationFailed(e) => {
+                format!("CreateCommandHandlerError: SSH key pair generation failed - {e}")
+            }
+            Self::RepositoryError(e) => {
+                format!("CreateCommandHandlerError: Repository operation failed - {e}")
+            }
+        }
+    }
+
+    fn trace_source(&self) -> Option<&dyn Traceable> {
+        None
+    }
+
+    fn error_kind(&self) -> ErrorKind {
+        match self {
+            Self::InvalidConfiguration(_)
+            | Self::EnvironmentAlreadyExists { .. }
+            | Self::EnvironmentConfigConflict { .. }
+            | Self::InstanceNameInUse { .. }
+            | Self::SshKeyMismatch { .. }
+            | Self::SshKeyGenerationFailed(SshKeyGenerationError::KeyAlreadyExists { .. }) => {
+                ErrorKind::Configuration
+            }
+            Self::SshKeyGenerationFailed(
+                SshKeyGenerationError::DirectoryCreationFailed { .. }
+                | SshKeyGenerationError::PermissionsFailed { .. },
+            ) => ErrorKind::FileSystem,
+            Self::SshKeyGenerationFailed(SshKeyGenerationError::CommandFailed { .. }) => {
+                ErrorKind::CommandExecution
+            }
+            Self::RepositoryError(_) => ErrorKind::StatePersistence,
+        }
+    }
+}
+
+/// The changed settings of a diff, secrets redacted
+fn describe_changes(diff: &ConfigDiff) -> String {
+    diff.changes()
+        .iter()
+        .map(|change| {
+            format!(
+                "{} ({} -> {})",
+                change.path,
+                change.before.as_deref().unwrap_or("not set"),
+                change.after.as_deref().unwrap_or("not set")
+            )
+        })
+        .collect::<Vec<_>>()
+        .join(", ")
+}
+
 impl CreateCommandHandlerError {
     /// Provides detailed troubleshooting guidance for this error
     ///
@@ -80,6 +195,75 @@ For more details, see the configuration documentation."
 Note: Environment names must be unique across the system.
 
 For more information, see the environment management documentation."
+            }
+            Self::EnvironmentConfigConflict { .. } => {
+                "Environment Exists With A Different Configuration - Troubleshooting:
+
+Creating an environment again with the same configuration succeeds and
+returns the existing environment. This configuration differs in the
+settings listed in the error.
+
+1. Review the differences:
+   torrust-tracker-deployer diff <env-name> <config-file>
+
+2. Restore the settings in your configuration file to create it again,
+   or choose a different environment name
+
+3. Or destroy and purge the existing environment to recreate it:
+   torrust-tracker-deployer destroy <env-name>
+   torrust-tracker-deployer purge <env-name>
+
+For more information, see docs/user-guide/commands/create.md"
+            }
+            Self::InstanceNameInUse { .. } => {
+                "Instance Name Already In Use - Troubleshooting:
+
+Two environments sharing an instance would provision, configure and destroy
+the same machine.
+
+1. Find the environment using the name:
+   torrust-tracker-deployer show <environment>
+
+2. Set another environment.instance_name in your configuration,
+   or remove it to use the generated name (torrust-tracker-vm-<env-name>)
+
+3. Or purge the other environment if it is no longer needed:
+   torrust-tracker-deployer destroy <environment>
+   torrust-tracker-deployer purge <environment>
+
+For more information, see docs/user-guide/commands/create.md"
+            }
+            Self::SshKeyMismatch { .. } => {
+                "SSH Key Mismatch - Troubleshooting:
+
+The public key file was not generated from the private key. The instance
+would accept the public key only, so SSH authentication would fail during
+configure.
+
+1. Compare the key of each file:
+   ssh-keygen -y -f <private-key>
+   cat <public-key>
+
+2. Point ssh_credentials.public_key_path at the public key of the pair,
+   or write it from the private key:
+   ssh-keygen -y -f <private-key> > <private-key>.pub
+
+For more information, see the configuration documentation."
+            }
+            Self::SshKeyGenerationFailed(_) => {
+                "SSH Key Pair Generation Failed - Troubleshooting:
+
+1. Check that ssh-keygen is installed:
+   ssh-keygen -h
+
+2. If a key pair from an earlier attempt exists in data/<env-name>/ssh/,
+   it is never overwritten. Remove it if no environment uses it:
+   rm -r data/<env-name>/ssh/
+
+3. Or use an existing key pair: remove generate_ssh_keys and set
+   ssh_credentials.private_key_path and public_key_path
+
+For more information, see the configuration documentation."
             }
             Self::RepositoryError(_) => {
                 "Repository Operation Failed - Troubleshooting:
@@ -144,6 +328,19 @@ mod tests {
         assert!(help.contains("SSH key"));
     }
 
+    #[test]
+    fn it_should_name_both_key_paths_in_a_key_mismatch_error() {
+        let error = CreateCommandHandlerError::SshKeyMismatch {
+            private_key_path: "/keys/id_rsa".into(),
+            public_key_path: "/keys/other.pub".into(),
+        };
+
+        let message = error.to_string();
+        assert!(message.contains("/keys/id_rsa"));
+        assert!(message.contains("/keys/other.pub"));
+        assert!(error.help().contains("ssh-keygen -y"));
+    }
+
     #[test]
     fn it_should_provide_help_for_repository_error() {
         let repo_error = PersistenceError::NotFound;
@@ -170,6 +367,19 @@ mod tests {
             CreateCommandHandlerError::EnvironmentAlreadyExists {
                 name: "test".to_string(),
             },
+            CreateCommandHandlerError::EnvironmentConfigConflict {
+                name: "test".to_string(),
+                diff: ConfigDiff::default(),
+            },
+            CreateCommandHandlerError::SshKeyMismatch {
+                private_key_path: "/keys/id_rsa".into(),
+                public_key_path: "/keys/other.pub".into(),
+            },
+            CreateCommandHandlerError::SshKeyGenerationFailed(
+                SshKeyGenerationError::KeyAlreadyExists {
+                    path: "data/test/ssh/id_ed25519".into(),
+                },
+            ),
             CreateCommandHandlerError::RepositoryError(PersistenceError::NotFound),
         ];
 
@@ -182,4 +392,37 @@ mod tests {
             );
         }
     }
+
+    #[test]
+    fn it_should_classify_ssh_key_generation_failures_by_cause() {
+        let key_exists = CreateCommandHandlerError::SshKeyGenerationFailed(
+            SshKeyGenerationError::KeyAlreadyExists {
+                path: "data/test/ssh/id_ed25519".into(),
+            },
+        );
+        let keygen_failed = CreateCommandHandlerError::SshKeyGenerationFailed(
+            SshKeyGenerationError::CommandFailed {
+                source: crate::shared::CommandError::ExecutionFailed {
+                    command: "ssh-keygen".to_string(),
+                    exit_code: "1".to_string(),
+                    stdout: String::new(),
+                    stderr: String::new(),
+                },
+            },
+        );
+
+        assert_eq!(key_exists.error_kind(), ErrorKind::Configuration);
+        assert!(!key_exists.is_retryable());
+        assert_eq!(keygen_failed.error_kind(), ErrorKind::CommandExecution);
+    }
+
+    #[test]
+    fn it_should_not_retry_an_environment_that_already_exists() {
+        let error = CreateCommandHandlerError::EnvironmentAlreadyExists {
+            name: "test".to_string(),
+        };
+
+        assert_eq!(error.error_kind(), ErrorKind::Configuration);
+        assert!(!error.is_retryable());
+    }
 }
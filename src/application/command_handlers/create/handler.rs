@@ -6,14 +6,19 @@
 
 use std::convert::TryInto;
 use std::sync::Arc;
-use tracing::{info, instrument};
+use tracing::{info, instrument, warn};
 
+use crate::adapters::ssh::{check_key_pair, securely_remove_key, KeyPairCheck, SshKeyGenerator};
 use crate::application::command_handlers::create::config::EnvironmentCreationConfig;
 use crate::domain::environment::repository::EnvironmentRepository;
-use crate::domain::environment::{Created, Environment, EnvironmentParams};
+use crate::domain::environment::{
+    Created, Environment, EnvironmentParams, UserInputs, WorkspaceLayout,
+};
+use crate::domain::InstanceName;
 use crate::shared::Clock;
 
 use super::errors::CreateCommandHandlerError;
+use super::result::CreateResult;
 
 /// Command to create a new deployment environment
 ///
@@ -35,8 +40,11 @@ use super::errors::CreateCommandHandlerError;
 ///
 /// 1. Convert configuration to domain objects
 /// 2. Check if environment already exists (prevent duplicates)
-/// 3. Create environment entity using `Environment::new()`
-/// 4. Persist via repository (repository handles directory creation)
+/// 3. Check that no other environment uses the same instance name
+/// 4. Create environment entity using `Environment::new()`
+/// 5. Check that the public key belongs to the private key, or generate
+///    the SSH key pair if the configuration asks for it
+/// 6. Persist via repository (repository handles directory creation)
 ///
 /// # Examples
 ///
@@ -73,7 +81,8 @@ use super::errors::CreateCommandHandlerError;
 ///         22,
 ///     ),
 ///     ProviderSection::Lxd(LxdProviderSection {
-///         profile_name: "lxd-dev".to_string(),
+///         profile_name: Some("lxd-dev".to_string()),
+///         remote: None,
 ///     }),
 ///     TrackerSection::default(),
 ///     None, // prometheus
@@ -150,14 +159,16 @@ impl CreateCommandHandler {
     ///
     /// 1. Configuration must convert to valid domain objects
     /// 2. Environment name must be unique (no duplicates)
-    /// 3. Repository handles directory creation atomically during save
-    /// 4. Environment state must be persisted successfully
+    /// 3. Instance name must not be used by another environment
+    /// 4. Repository handles directory creation atomically during save
+    /// 5. Environment state must be persisted successfully
     ///
     /// # Errors
     ///
     /// Returns an error if:
     /// - Configuration validation fails
     /// - Environment with the same name already exists
+    /// - The SSH public key does not belong to the private key
     /// - Repository persistence fails
     ///
     /// All errors implement `.help()` with detailed troubleshooting guidance.
@@ -192,7 +203,8 @@ impl CreateCommandHandler {
     ///         22,
     ///     ),
     ///     ProviderSection::Lxd(LxdProviderSection {
-    ///         profile_name: "lxd-staging".to_string(),
+    ///         profile_name: Some("lxd-staging".to_string()),
+    ///         remote: None,
     ///     }),
     ///     TrackerSection::default(),
     ///     None, // prometheus
@@ -207,24 +219,52 @@ impl CreateCommandHandler {
     /// # Ok(())
     /// # }
     /// ```
+    pub fn execute(
+        &self,
+        config: EnvironmentCreationConfig,
+        working_dir: &std::path::Path,
+    ) -> Result<Environment<Created>, CreateCommandHandlerError> {
+        self.execute_in_layout(config, &WorkspaceLayout::in_working_dir(working_dir))
+    }
+
+    /// Execute the create command with explicit data and build roots
+    ///
+    /// Same as [`CreateCommandHandler::execute`], except the environment
+    /// directories are `{layout.data_dir}/{name}` and `{layout.build_dir}/{name}`
+    /// instead of being derived from a working directory. The repository must
+    /// be rooted at the same data directory.
+    ///
+    /// # Errors
+    ///
+    /// Same as [`CreateCommandHandler::execute`].
     #[instrument(
-        name = "create_command",
+        name = "command.create",
         skip_all,
         fields(
             command_type = "create",
-            environment = %config.environment.name
+            environment_name = %config.environment.name
         )
     )]
-    pub fn execute(
+    pub fn execute_in_layout(
         &self,
         config: EnvironmentCreationConfig,
-        working_dir: &std::path::Path,
+        layout: &WorkspaceLayout,
     ) -> Result<Environment<Created>, CreateCommandHandlerError> {
+        let generate_ssh_keys = config.generate_ssh_keys;
+
         // Convert DTO to validated domain parameters
         let params: EnvironmentParams = config
-            .try_into()
+            .with_generated_ssh_key_paths(layout)
+            .and_then(TryInto::try_into)
             .map_err(CreateCommandHandlerError::InvalidConfiguration)?;
 
+        // Held until the environment is saved, so that a concurrent create
+        // of the same name cannot pass the duplicate check too
+        let _lock = self
+            .environment_repository
+            .lock(&params.environment_name)
+            .map_err(|e| CreateCommandHandlerError::RepositoryError(e.into()))?;
+
         // Check for duplicate environment
         if self
             .environment_repository
@@ -236,13 +276,28 @@ impl CreateCommandHandler {
             });
         }
 
+        self.check_instance_name_available(&params.instance_name)?;
+
         // Create environment aggregate from validated params
-        let environment = Environment::create(params, working_dir, self.clock.now())
+        let environment = Environment::create_in_layout(params, layout, self.clock.now())
             .map_err(|e| CreateCommandHandlerError::InvalidConfiguration(e.into()))?;
 
-        self.environment_repository
+        // Generated last, so that a rejected configuration leaves no key behind
+        if generate_ssh_keys {
+            Self::generate_ssh_keys(&environment)?;
+        } else {
+            Self::check_ssh_key_pair(&environment)?;
+        }
+
+        if let Err(e) = self
+            .environment_repository
             .save(&environment.clone().into_any())
-            .map_err(|e| CreateCommandHandlerError::RepositoryError(e.into()))?;
+        {
+            if generate_ssh_keys {
+                Self::remove_generated_ssh_keys(&environment);
+            }
+            return Err(CreateCommandHandlerError::RepositoryError(e.into()));
+        }
 
         info!(
             command = "create",
@@ -252,6 +307,183 @@ impl CreateCommandHandler {
 
         Ok(environment)
     }
+
+    /// Execute the create command, returning the environment if it already exists
+    ///
+    /// Same as [`CreateCommandHandler::execute`], except that an environment
+    /// that already exists is not an error when the configuration is the one
+    /// it was created with: it is returned unchanged, whatever its state, with
+    /// [`CreateResult::already_existed`] set. Settings are compared the way
+    /// the `diff` command does, so only the settings themselves matter, not
+    /// the layout of the configuration file.
+    ///
+    /// # Errors
+    ///
+    /// Same as [`CreateCommandHandler::execute`], except that an existing
+    /// environment with another configuration returns
+    /// [`CreateCommandHandlerError::EnvironmentConfigConflict`] listing the
+    /// settings that differ.
+    pub fn execute_or_reuse(
+        &self,
+        config: EnvironmentCreationConfig,
+        working_dir: &std::path::Path,
+    ) -> Result<CreateResult, CreateCommandHandlerError> {
+        self.execute_or_reuse_in_layout(config, &WorkspaceLayout::in_working_dir(working_dir))
+    }
+
+    /// Execute the create command with explicit data and build roots, returning
+    /// the environment if it already exists
+    ///
+    /// Same as [`CreateCommandHandler::execute_or_reuse`], with the layout of
+    /// [`CreateCommandHandler::execute_in_layout`].
+    ///
+    /// # Errors
+    ///
+    /// Same as [`CreateCommandHandler::execute_or_reuse`].
+    pub fn execute_or_reuse_in_layout(
+        &self,
+        config: EnvironmentCreationConfig,
+        layout: &WorkspaceLayout,
+    ) -> Result<CreateResult, CreateCommandHandlerError> {
+        match self.execute_in_layout(config.clone(), layout) {
+            Ok(environment) => Ok(CreateResult::created(environment)),
+            Err(CreateCommandHandlerError::EnvironmentAlreadyExists { name }) => {
+                self.reuse_existing(config, layout, name)
+            }
+            Err(e) => Err(e),
+        }
+    }
+
+    /// Return the existing environment `name` if `config` is the one it was created with
+    fn reuse_existing(
+        &self,
+        config: EnvironmentCreationConfig,
+        layout: &WorkspaceLayout,
+        name: String,
+    ) -> Result<CreateResult, CreateCommandHandlerError> {
+        let params: EnvironmentParams = config
+            .with_generated_ssh_key_paths(layout)
+            .and_then(TryInto::try_into)
+            .map_err(CreateCommandHandlerError::InvalidConfiguration)?;
+        let environment_name = params.environment_name.clone();
+        let new_inputs = UserInputs::from_params(params)
+            .map_err(|e| CreateCommandHandlerError::InvalidConfiguration(e.into()))?;
+
+        // Removed by another process since the existence check: keep the original error
+        let existing = self
+            .environment_repository
+            .load(&environment_name)
+            .map_err(|e| CreateCommandHandlerError::RepositoryError(e.into()))?
+            .ok_or_else(|| CreateCommandHandlerError::EnvironmentAlreadyExists {
+                name: name.clone(),
+            })?;
+
+        let diff = existing.user_inputs().diff(&new_inputs);
+        if !diff.is_empty() {
+            return Err(CreateCommandHandlerError::EnvironmentConfigConflict { name, diff });
+        }
+
+        info!(
+            command = "create",
+            environment = %environment_name,
+            state = existing.state_name(),
+            "Environment already exists with the same configuration"
+        );
+
+        Ok(CreateResult::existing(existing))
+    }
+
+    /// Check that no stored environment uses the instance name
+    fn check_instance_name_available(
+        &self,
+        instance_name: &InstanceName,
+    ) -> Result<(), CreateCommandHandlerError> {
+        let in_use_by = self
+            .environment_repository
+            .find_by_instance_name(instance_name)
+            .map_err(|e| CreateCommandHandlerError::RepositoryError(e.into()))?;
+
+        match in_use_by {
+            Some(environment) => Err(CreateCommandHandlerError::InstanceNameInUse {
+                instance_name: instance_name.to_string(),
+                environment: environment.to_string(),
+            }),
+            None => Ok(()),
+        }
+    }
+
+    /// Generate the key pair at the paths stored in the environment credentials
+    fn generate_ssh_keys(
+        environment: &Environment<Created>,
+    ) -> Result<(), CreateCommandHandlerError> {
+        let credentials = environment.ssh_credentials();
+        let comment = format!("torrust-tracker-deployer-{}", environment.name());
+
+        SshKeyGenerator::new()
+            .generate(&credentials.ssh_priv_key_path, &comment)
+            .map_err(CreateCommandHandlerError::SshKeyGenerationFailed)?;
+
+        info!(
+            command = "create",
+            environment = %environment.name(),
+            private_key = %credentials.ssh_priv_key_path.display(),
+            "Generated SSH key pair"
+        );
+
+        Ok(())
+    }
+
+    /// Reject a public key that does not belong to the private key
+    ///
+    /// cloud-init would install the wrong key and SSH authentication would
+    /// only fail at `configure`. When the public key cannot be derived (for
+    /// example from a passphrase-protected key) a warning is logged instead.
+    fn check_ssh_key_pair(
+        environment: &Environment<Created>,
+    ) -> Result<(), CreateCommandHandlerError> {
+        let credentials = environment.ssh_credentials();
+
+        match check_key_pair(
+            &credentials.ssh_priv_key_path,
+            &credentials.ssh_pub_key_path,
+        ) {
+            KeyPairCheck::Matching => Ok(()),
+            KeyPairCheck::Mismatching => Err(CreateCommandHandlerError::SshKeyMismatch {
+                private_key_path: credentials.ssh_priv_key_path.clone(),
+                public_key_path: credentials.ssh_pub_key_path.clone(),
+            }),
+            KeyPairCheck::Undetermined { reason } => {
+                warn!(
+                    command = "create",
+                    environment = %environment.name(),
+                    private_key = %credentials.ssh_priv_key_path.display(),
+                    public_key = %credentials.ssh_pub_key_path.display(),
+                    reason = %reason,
+                    "Could not check that the SSH public key matches the private key"
+                );
+                Ok(())
+            }
+        }
+    }
+
+    /// Best-effort removal of the key pair of an environment that was not saved
+    fn remove_generated_ssh_keys(environment: &Environment<Created>) {
+        let credentials = environment.ssh_credentials();
+
+        for path in [
+            &credentials.ssh_priv_key_path,
+            &credentials.ssh_pub_key_path,
+        ] {
+            if let Err(e) = securely_remove_key(path) {
+                warn!(
+                    command = "create",
+                    path = %path.display(),
+                    error = %e,
+                    "Failed to remove generated SSH key"
+                );
+            }
+        }
+    }
 }
 
 #[cfg(test)]
@@ -55,7 +55,8 @@
 //!         22,
 //!     ),
 //!     ProviderSection::Lxd(LxdProviderSection {
-//!         profile_name: "lxd-production".to_string(),
+//!         profile_name: Some("lxd-production".to_string()),
+//!         remote: None,
 //!     }),
 //!     TrackerSection::default(),
 //!     None, // prometheus
@@ -80,6 +81,7 @@
 pub mod config;
 pub mod errors;
 pub mod handler;
+pub mod result;
 pub mod schema;
 
 #[cfg(test)]
@@ -88,3 +90,4 @@ mod tests;
 // Re-export main types for convenience
 pub use errors::CreateCommandHandlerError;
 pub use handler::CreateCommandHandler;
+pub use result::CreateResult;
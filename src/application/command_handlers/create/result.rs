@@ -0,0 +1,46 @@
+//! Result type for the create command handler
+//!
+//! Creating an environment that already exists with the same configuration
+//! is not an error: the existing environment is returned instead, whatever
+//! its state, so automation can run `create` unconditionally.
+
+use crate::domain::environment::state::AnyEnvironmentState;
+use crate::domain::environment::{Created, Environment};
+use crate::domain::EnvironmentName;
+
+/// Result of [`CreateCommandHandler::execute_or_reuse`]
+///
+/// [`CreateCommandHandler::execute_or_reuse`]: super::CreateCommandHandler::execute_or_reuse
+#[derive(Debug, Clone)]
+pub struct CreateResult {
+    /// The created environment, or the existing one
+    pub environment: AnyEnvironmentState,
+    /// Whether the environment already existed with the same configuration
+    pub already_existed: bool,
+}
+
+impl CreateResult {
+    /// Result of the creation of a new environment
+    #[must_use]
+    pub fn created(environment: Environment<Created>) -> Self {
+        Self {
+            environment: environment.into_any(),
+            already_existed: false,
+        }
+    }
+
+    /// Result of a creation that found the environment with the same configuration
+    #[must_use]
+    pub fn existing(environment: AnyEnvironmentState) -> Self {
+        Self {
+            environment,
+            already_existed: true,
+        }
+    }
+
+    /// Name of the environment
+    #[must_use]
+    pub fn name(&self) -> &EnvironmentName {
+        self.environment.name()
+    }
+}
@@ -5,6 +5,8 @@ use std::path::PathBuf;
 use thiserror::Error;
 
 use crate::infrastructure::schema::SchemaGenerationError;
+use crate::shared::error::kind::ErrorKind;
+use crate::shared::error::traceable::Traceable;
 
 /// Errors that can occur during schema creation command handling
 #[derive(Debug, Error)]
@@ -38,6 +40,41 @@ pub enum CreateSchemaCommandHandlerError {
     },
 }
 
+impl Traceable for CreateSchemaCommandHandlerError {
+    fn trace_format(&self) -> String {
+        match self {
+            Self::SchemaGenerationFailed { source } => {
+                format!("CreateSchemaCommandHandlerError: Schema generation failed - {source}")
+            }
+            Self::FileWriteFailed { path, source } => {
+                format!(
+                    "CreateSchemaCommandHandlerError: File write failed - '{}': {source}",
+                    path.display()
+                )
+            }
+            Self::DirectoryCreationFailed { path, source } => {
+                format!(
+                    "CreateSchemaCommandHandlerError: Directory creation failed - '{}': {source}",
+                    path.display()
+                )
+            }
+        }
+    }
+
+    fn trace_source(&self) -> Option<&dyn Traceable> {
+        None
+    }
+
+    fn error_kind(&self) -> ErrorKind {
+        match self {
+            Self::SchemaGenerationFailed { .. } => ErrorKind::TemplateRendering,
+            Self::FileWriteFailed { .. } | Self::DirectoryCreationFailed { .. } => {
+                ErrorKind::FileSystem
+            }
+        }
+    }
+}
+
 impl CreateSchemaCommandHandlerError {
     /// Returns actionable help text for resolving this error
     ///
@@ -128,4 +165,15 @@ mod tests {
         assert!(help.contains("permissions"));
         assert!(help.contains("/test/nested/dir"));
     }
+
+    #[test]
+    fn it_should_classify_write_failures_as_file_system_errors() {
+        let error = CreateSchemaCommandHandlerError::FileWriteFailed {
+            path: PathBuf::from("/test/schema.json"),
+            source: std::io::Error::other("test"),
+        };
+
+        assert_eq!(error.error_kind(), ErrorKind::FileSystem);
+        assert!(!error.is_retryable());
+    }
 }
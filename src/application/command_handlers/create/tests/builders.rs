@@ -211,6 +211,7 @@ impl CreateCommandHandlerTestBuilder {
         let ssh_credentials = SshCredentials::new(private_key, public_key, username);
         let provider_config = ProviderConfig::Lxd(LxdConfig {
             profile_name: ProfileName::new(format!("lxd-{}", env_name.as_str())).unwrap(),
+            remote: None,
         });
 
         let environment = Environment::new(
@@ -276,7 +277,8 @@ pub fn create_valid_test_config(temp_dir: &TempDir, env_name: &str) -> Environme
             22,
         ),
         ProviderSection::Lxd(LxdProviderSection {
-            profile_name: format!("lxd-{env_name}"),
+            profile_name: Some(format!("lxd-{env_name}")),
+            remote: None,
         }),
         TrackerSection::default(),
         None,
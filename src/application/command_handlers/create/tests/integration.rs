@@ -5,6 +5,9 @@
 
 use tempfile::TempDir;
 
+use crate::application::command_handlers::create::config::{
+    CreateConfigError, LxdProviderSection, ProviderSection,
+};
 use crate::application::command_handlers::create::tests::{
     create_valid_test_config, CreateCommandHandlerTestBuilder,
 };
@@ -139,7 +142,8 @@ fn it_should_fail_with_invalid_environment_name() {
             22,
         ),
         ProviderSection::Lxd(LxdProviderSection {
-            profile_name: "test-profile".to_string(),
+            profile_name: Some("test-profile".to_string()),
+            remote: None,
         }),
         TrackerSection::default(),
         None,
@@ -195,7 +199,8 @@ fn it_should_fail_when_ssh_private_key_not_found() {
             22,
         ),
         ProviderSection::Lxd(LxdProviderSection {
-            profile_name: "test-profile".to_string(),
+            profile_name: Some("test-profile".to_string()),
+            remote: None,
         }),
         TrackerSection::default(),
         None,
@@ -268,6 +273,90 @@ fn it_should_create_multiple_different_environments() {
     assert!(command.environment_repository.exists(&env2_name).unwrap());
 }
 
+#[test]
+fn it_should_create_the_environment_with_the_configured_instance_and_profile_names() {
+    // Arrange
+    let (command, temp_dir) = CreateCommandHandlerTestBuilder::new().build();
+    let mut config = create_valid_test_config(&temp_dir, "tracker");
+    config.environment.instance_name = Some("infra-tracker-01".to_string());
+    config.provider = ProviderSection::Lxd(LxdProviderSection {
+        profile_name: Some("infra-tracker-profile".to_string()),
+        remote: None,
+    });
+
+    // Act
+    let environment = command.execute(config, temp_dir.path()).unwrap();
+
+    // Assert
+    assert_eq!(environment.instance_name().as_str(), "infra-tracker-01");
+    assert_eq!(
+        environment
+            .provider_config()
+            .as_lxd()
+            .unwrap()
+            .profile_name
+            .as_str(),
+        "infra-tracker-profile"
+    );
+}
+
+#[test]
+fn it_should_generate_the_profile_name_when_not_configured() {
+    // Arrange
+    let (command, temp_dir) = CreateCommandHandlerTestBuilder::new().build();
+    let mut config = create_valid_test_config(&temp_dir, "tracker");
+    config.provider = ProviderSection::Lxd(LxdProviderSection {
+        profile_name: None,
+        remote: None,
+    });
+
+    // Act
+    let environment = command.execute(config, temp_dir.path()).unwrap();
+
+    // Assert
+    assert_eq!(
+        environment.instance_name().as_str(),
+        "torrust-tracker-vm-tracker"
+    );
+    assert_eq!(
+        environment
+            .provider_config()
+            .as_lxd()
+            .unwrap()
+            .profile_name
+            .as_str(),
+        "lxd-tracker"
+    );
+}
+
+#[test]
+fn it_should_fail_when_another_environment_uses_the_instance_name() {
+    // Arrange
+    let (command, temp_dir) = CreateCommandHandlerTestBuilder::new().build();
+    let mut first = create_valid_test_config(&temp_dir, "tracker-a");
+    first.environment.instance_name = Some("infra-tracker-01".to_string());
+    command.execute(first, temp_dir.path()).unwrap();
+    let mut second = create_valid_test_config(&temp_dir, "tracker-b");
+    second.environment.instance_name = Some("infra-tracker-01".to_string());
+
+    // Act
+    let result = command.execute(second, temp_dir.path());
+
+    // Assert
+    match result.unwrap_err() {
+        CreateCommandHandlerError::InstanceNameInUse {
+            instance_name,
+            environment,
+        } => {
+            assert_eq!(instance_name, "infra-tracker-01");
+            assert_eq!(environment, "tracker-a");
+        }
+        other => panic!("Expected InstanceNameInUse error, got: {other:?}"),
+    }
+    let name = EnvironmentName::new("tracker-b").unwrap();
+    assert!(!command.environment_repository.exists(&name).unwrap());
+}
+
 #[test]
 fn it_should_use_deterministic_timestamps_with_mock_clock() {
     use chrono::TimeZone;
@@ -286,3 +375,219 @@ fn it_should_use_deterministic_timestamps_with_mock_clock() {
     // Assert: Clock maintains fixed time
     assert_eq!(command.clock.now(), fixed_time);
 }
+
+#[test]
+fn it_should_generate_the_ssh_key_pair_in_the_environment_data_directory() {
+    // Arrange
+    let (command, temp_dir) = CreateCommandHandlerTestBuilder::new().build();
+    let mut config = create_valid_test_config(&temp_dir, "test-env");
+    config.ssh_credentials.private_key_path = String::new();
+    config.ssh_credentials.public_key_path = String::new();
+    config.generate_ssh_keys = true;
+
+    // Act
+    let environment = command.execute(config, temp_dir.path()).unwrap();
+
+    // Assert
+    let ssh_dir = temp_dir.path().join("data/test-env/ssh");
+    let credentials = environment.ssh_credentials();
+    assert_eq!(credentials.ssh_priv_key_path, ssh_dir.join("id_ed25519"));
+    assert_eq!(credentials.ssh_pub_key_path, ssh_dir.join("id_ed25519.pub"));
+    assert!(credentials.ssh_priv_key_path.exists());
+    assert!(credentials.ssh_pub_key_path.exists());
+}
+
+#[test]
+fn it_should_not_overwrite_an_existing_generated_key() {
+    // Arrange
+    let (command, temp_dir) = CreateCommandHandlerTestBuilder::new().build();
+    let mut config = create_valid_test_config(&temp_dir, "test-env");
+    config.ssh_credentials.private_key_path = String::new();
+    config.ssh_credentials.public_key_path = String::new();
+    config.generate_ssh_keys = true;
+
+    let existing_key = temp_dir.path().join("data/test-env/ssh/id_ed25519");
+    std::fs::create_dir_all(existing_key.parent().unwrap()).unwrap();
+    std::fs::write(&existing_key, "existing").unwrap();
+
+    // Act
+    let result = command.execute(config, temp_dir.path());
+
+    // Assert
+    assert!(matches!(
+        result,
+        Err(CreateCommandHandlerError::SshKeyGenerationFailed(_))
+    ));
+    assert_eq!(std::fs::read_to_string(&existing_key).unwrap(), "existing");
+    let env_name = EnvironmentName::new("test-env").unwrap();
+    assert!(!command.environment_repository.exists(&env_name).unwrap());
+}
+
+#[test]
+fn it_should_reject_key_paths_together_with_generated_keys() {
+    // Arrange
+    let (command, temp_dir) = CreateCommandHandlerTestBuilder::new().build();
+    let mut config = create_valid_test_config(&temp_dir, "test-env");
+    config.generate_ssh_keys = true;
+
+    // Act
+    let result = command.execute(config, temp_dir.path());
+
+    // Assert
+    assert!(matches!(
+        result,
+        Err(CreateCommandHandlerError::InvalidConfiguration(
+            CreateConfigError::SshKeyPathsWithGeneratedKeys
+        ))
+    ));
+    assert!(!temp_dir.path().join("data/test-env/ssh").exists());
+}
+
+/// Point the configuration at a copy of the RSA test key and the given public key fixture
+fn use_test_key_pair(
+    config: &mut crate::application::command_handlers::create::config::EnvironmentCreationConfig,
+    temp_dir: &TempDir,
+    public_key_fixture: &str,
+) {
+    let fixtures = std::path::Path::new(env!("CARGO_MANIFEST_DIR")).join("fixtures");
+    let private_key = temp_dir.path().join("testing_rsa");
+    std::fs::copy(fixtures.join("testing_rsa"), &private_key).unwrap();
+    #[cfg(unix)]
+    {
+        use std::os::unix::fs::PermissionsExt;
+
+        std::fs::set_permissions(&private_key, std::fs::Permissions::from_mode(0o600)).unwrap();
+    }
+
+    config.ssh_credentials.private_key_path = private_key.to_string_lossy().to_string();
+    config.ssh_credentials.public_key_path = fixtures
+        .join(public_key_fixture)
+        .to_string_lossy()
+        .to_string();
+}
+
+#[test]
+fn it_should_create_environment_when_the_ssh_keys_form_a_pair() {
+    // Arrange
+    let (command, temp_dir) = CreateCommandHandlerTestBuilder::new().build();
+    let mut config = create_valid_test_config(&temp_dir, "test-env");
+    use_test_key_pair(&mut config, &temp_dir, "testing_rsa.pub");
+
+    // Act
+    let result = command.execute(config, temp_dir.path());
+
+    // Assert
+    assert!(result.is_ok(), "Expected matching key pair to be accepted");
+}
+
+#[test]
+fn it_should_fail_when_the_public_key_belongs_to_another_pair() {
+    // Arrange
+    let (command, temp_dir) = CreateCommandHandlerTestBuilder::new().build();
+    let mut config = create_valid_test_config(&temp_dir, "test-env");
+    use_test_key_pair(&mut config, &temp_dir, "testing_ed25519_encrypted.pub");
+
+    // Act
+    let result = command.execute(config, temp_dir.path());
+
+    // Assert
+    assert!(matches!(
+        result,
+        Err(CreateCommandHandlerError::SshKeyMismatch { ref public_key_path, .. })
+            if public_key_path.ends_with("testing_ed25519_encrypted.pub")
+    ));
+    let env_name = EnvironmentName::new("test-env").unwrap();
+    assert!(!command.environment_repository.exists(&env_name).unwrap());
+}
+
+#[test]
+fn it_should_return_the_existing_environment_when_the_configuration_is_the_same() {
+    // Arrange
+    let (command, temp_dir) = CreateCommandHandlerTestBuilder::new().build();
+    let config = create_valid_test_config(&temp_dir, "test-environment");
+    let first = command
+        .execute_or_reuse(config.clone(), temp_dir.path())
+        .unwrap();
+
+    // Act
+    let second = command.execute_or_reuse(config, temp_dir.path()).unwrap();
+
+    // Assert
+    assert!(!first.already_existed);
+    assert!(second.already_existed);
+    assert_eq!(second.name().as_str(), "test-environment");
+    assert_eq!(
+        second.environment.created_at(),
+        first.environment.created_at()
+    );
+}
+
+#[test]
+fn it_should_list_the_differing_settings_when_the_configuration_changed() {
+    // Arrange
+    let (command, temp_dir) = CreateCommandHandlerTestBuilder::new().build();
+    let config = create_valid_test_config(&temp_dir, "test-environment");
+    command
+        .execute_or_reuse(config.clone(), temp_dir.path())
+        .unwrap();
+
+    let mut changed = config;
+    changed.environment.instance_name = Some("other-instance".to_string());
+
+    // Act
+    let result = command.execute_or_reuse(changed, temp_dir.path());
+
+    // Assert
+    match result.unwrap_err() {
+        CreateCommandHandlerError::EnvironmentConfigConflict { name, diff } => {
+            assert_eq!(name, "test-environment");
+            let paths: Vec<&str> = diff.changes().iter().map(|c| c.path.as_str()).collect();
+            assert_eq!(paths, vec!["environment.instance_name"]);
+        }
+        other => panic!("Expected EnvironmentConfigConflict error, got: {other:?}"),
+    }
+}
+
+#[test]
+fn it_should_not_create_an_environment_whose_name_another_command_holds() {
+    use std::sync::Arc;
+    use std::time::Duration;
+
+    use crate::application::command_handlers::create::CreateCommandHandler;
+    use crate::domain::environment::repository::EnvironmentRepository;
+    use crate::infrastructure::persistence::filesystem::file_environment_repository::FileEnvironmentRepository;
+    use crate::shared::SystemClock;
+
+    // Arrange: Another create of the same name holds the environment lock
+    let temp_dir = TempDir::new().unwrap();
+    let repository = Arc::new(
+        FileEnvironmentRepository::new(temp_dir.path().to_path_buf())
+            .with_lock_timeout(Duration::from_millis(100)),
+    );
+    let command = CreateCommandHandler::new(repository.clone(), Arc::new(SystemClock));
+    let env_name = EnvironmentName::new("test-environment").unwrap();
+    let other_command = repository.lock(&env_name).unwrap();
+
+    // Act
+    let blocked = command.execute(
+        create_valid_test_config(&temp_dir, "test-environment"),
+        temp_dir.path(),
+    );
+    let blocked_saved = repository.exists(&env_name).unwrap();
+    drop(other_command);
+    let unblocked = command.execute(
+        create_valid_test_config(&temp_dir, "test-environment"),
+        temp_dir.path(),
+    );
+
+    // Assert
+    assert!(
+        matches!(blocked, Err(CreateCommandHandlerError::RepositoryError(_))),
+        "Expected a repository conflict, got: {blocked:?}"
+    );
+    assert!(!blocked_saved, "The blocked create must not save anything");
+    assert!(
+        unblocked.is_ok(),
+        "Expected creation once the lock is released"
+    );
+}
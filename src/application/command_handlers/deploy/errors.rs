@@ -0,0 +1,270 @@
+//! Error types for the Deploy command handler
+
+use crate::application::command_handlers::configure::ConfigureCommandHandlerError;
+use crate::application::command_handlers::provision::ProvisionCommandHandlerError;
+use crate::application::command_handlers::release::ReleaseCommandHandlerError;
+use crate::application::command_handlers::run::RunCommandHandlerError;
+use crate::application::errors::{AllowedCommands, PersistenceError};
+use crate::shared::error::kind::ErrorKind;
+use crate::shared::error::traceable::Traceable;
+
+/// Errors that can occur while deploying an environment
+///
+/// A failed phase keeps the error of its own command handler, which has
+/// already persisted the failed state of the environment.
+#[derive(Debug, thiserror::Error)]
+pub enum DeployCommandHandlerError {
+    /// Environment was not found in the repository
+    #[error("Environment not found: {name}")]
+    EnvironmentNotFound {
+        /// The name of the environment that was not found
+        name: String,
+    },
+
+    /// Environment is in a state the deployment cannot resume from
+    #[error("Environment '{name}' is in '{current_state}' state; deploy cannot resume from it; {allowed_commands}")]
+    InvalidState {
+        /// The name of the environment
+        name: String,
+        /// The actual state of the environment
+        current_state: String,
+        /// The commands allowed in the actual state
+        allowed_commands: AllowedCommands,
+    },
+
+    /// The provision phase failed
+    #[error("Provision phase failed: {0}")]
+    Provision(Box<ProvisionCommandHandlerError>),
+
+    /// The configure phase failed
+    #[error("Configure phase failed: {0}")]
+    Configure(Box<ConfigureCommandHandlerError>),
+
+    /// The release phase failed
+    #[error("Release phase failed: {0}")]
+    Release(Box<ReleaseCommandHandlerError>),
+
+    /// The run phase failed
+    #[error("Run phase failed: {0}")]
+    Run(Box<RunCommandHandlerError>),
+
+    /// The user cancelled the deployment before a phase started
+    #[error("interrupted by user with the environment in '{current_state}' state")]
+    Interrupted {
+        /// The state of the environment when the deployment stopped
+        current_state: String,
+    },
+
+    /// Failed to load the environment state
+    #[error("Failed to load environment state: {0}")]
+    StatePersistence(#[from] PersistenceError),
+}
+
+impl From<crate::domain::environment::repository::RepositoryError> for DeployCommandHandlerError {
+    fn from(e: crate::domain::environment::repository::RepositoryError) -> Self {
+        Self::StatePersistence(e.into())
+    }
+}
+
+impl From<ProvisionCommandHandlerError> for DeployCommandHandlerError {
+    fn from(e: ProvisionCommandHandlerError) -> Self {
+        Self::Provision(Box::new(e))
+    }
+}
+
+impl From<ConfigureCommandHandlerError> for DeployCommandHandlerError {
+    fn from(e: ConfigureCommandHandlerError) -> Self {
+        Self::Configure(Box::new(e))
+    }
+}
+
+impl From<ReleaseCommandHandlerError> for DeployCommandHandlerError {
+    fn from(e: ReleaseCommandHandlerError) -> Self {
+        Self::Release(Box::new(e))
+    }
+}
+
+impl From<RunCommandHandlerError> for DeployCommandHandlerError {
+    fn from(e: RunCommandHandlerError) -> Self {
+        Self::Run(Box::new(e))
+    }
+}
+
+impl DeployCommandHandlerError {
+    /// The error of the failed phase, if a phase failed
+    fn phase_error(&self) -> Option<&dyn Traceable> {
+        match self {
+            Self::Provision(e) => Some(e.as_ref()),
+            Self::Configure(e) => Some(e.as_ref()),
+            Self::Release(e) => Some(e.as_ref()),
+            Self::Run(e) => Some(e.as_ref()),
+            Self::EnvironmentNotFound { .. }
+            | Self::InvalidState { .. }
+            | Self::Interrupted { .. }
+            | Self::StatePersistence(_) => None,
+        }
+    }
+}
+
+impl Traceable for DeployCommandHandlerError {
+    fn trace_format(&self) -> String {
+        match self {
+            Self::EnvironmentNotFound { name } => {
+                format!("DeployCommandHandlerError: Environment not found - '{name}'")
+            }
+            Self::InvalidState {
+                name,
+                current_state,
+                ..
+            } => {
+                format!(
+                    "DeployCommandHandlerError: Invalid state - '{name}' is in '{current_state}'"
+                )
+            }
+            Self::Provision(e) => {
+                format!("DeployCommandHandlerError: Provision phase failed - {e}")
+            }
+            Self::Configure(e) => {
+                format!("DeployCommandHandlerError: Configure phase failed - {e}")
+            }
+            Self::Release(e) => format!("DeployCommandHandlerError: Release phase failed - {e}"),
+            Self::Run(e) => format!("DeployCommandHandlerError: Run phase failed - {e}"),
+            Self::Interrupted { current_state } => {
+                format!(
+                    "DeployCommandHandlerError: Interrupted by user (Ctrl-C) in '{current_state}'"
+                )
+            }
+            Self::StatePersistence(e) => {
+                format!("DeployCommandHandlerError: State persistence failed - {e}")
+            }
+        }
+    }
+
+    fn trace_source(&self) -> Option<&dyn Traceable> {
+        self.phase_error()
+    }
+
+    fn error_kind(&self) -> ErrorKind {
+        if let Some(e) = self.phase_error() {
+            return e.error_kind();
+        }
+
+        match self {
+            Self::Interrupted { .. } => ErrorKind::Interrupted,
+            Self::StatePersistence(_) => ErrorKind::StatePersistence,
+            _ => ErrorKind::Configuration,
+        }
+    }
+}
+
+impl DeployCommandHandlerError {
+    /// Provides detailed troubleshooting guidance for this error
+    ///
+    /// A failed phase returns the guidance of its own command.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use torrust_tracker_deployer_lib::application::command_handlers::deploy::DeployCommandHandlerError;
+    /// use torrust_tracker_deployer_lib::application::errors::AllowedCommands;
+    ///
+    /// let error = DeployCommandHandlerError::InvalidState {
+    ///     name: "my-env".to_string(),
+    ///     current_state: "provision_failed".to_string(),
+    ///     allowed_commands: AllowedCommands::default(),
+    /// };
+    ///
+    /// assert!(error.help().contains("Invalid Environment State"));
+    /// ```
+    #[must_use]
+    pub fn help(&self) -> &'static str {
+        match self {
+            Self::EnvironmentNotFound { .. } => {
+                "Environment Not Found - Troubleshooting:
+
+1. Verify the environment name is correct
+2. Check if the environment was created:
+   ls data/
+
+3. Deploy starts from an existing environment; create it first:
+   cargo run -- create environment --env-file <config.json>
+
+For more information, see docs/user-guide/commands/deploy.md"
+            }
+            Self::InvalidState { .. } => {
+                "Invalid Environment State - Troubleshooting:
+
+Deploy resumes from the Created, Provisioned, Configured, Released or
+Running states. It cannot resume from a failed or in-progress state.
+
+1. Check the current environment state:
+   cargo run -- show <env-name>
+
+2. For a failed state, review the failure and its trace file shown by
+   'show', then destroy and create the environment again
+
+3. For an in-progress state, wait for the running command to finish
+
+For more information, see docs/user-guide/commands/deploy.md"
+            }
+            Self::Provision(inner) => inner.help(),
+            Self::Configure(inner) => inner.help(),
+            Self::Release(inner) => inner.help(),
+            Self::Run(inner) => inner.help(),
+            Self::Interrupted { .. } => {
+                "Deployment Interrupted - Troubleshooting:
+
+The deployment was cancelled between two phases. Every completed phase
+was persisted, so the environment is in a consistent state.
+
+1. Check the current environment state:
+   cargo run -- show <env-name>
+
+2. Run deploy again to resume from that state:
+   cargo run -- deploy <env-name>
+
+For more information, see docs/user-guide/commands/deploy.md"
+            }
+            Self::StatePersistence(_) => {
+                "State Persistence Failed - Troubleshooting:
+
+1. Check read permissions on the data directory:
+   ls -la data/<env-name>/
+
+2. Make sure no other command is running for this environment
+
+For more information, see docs/user-guide/commands/deploy.md"
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn it_should_take_the_kind_and_help_of_the_failed_phase() {
+        let release_error = ReleaseCommandHandlerError::ReleaseOperationFailed {
+            name: "production".to_string(),
+            message: "connection reset".to_string(),
+        };
+        let help = release_error.help();
+
+        let error = DeployCommandHandlerError::from(release_error);
+
+        assert_eq!(error.error_kind(), ErrorKind::InfrastructureOperation);
+        assert!(error.trace_source().is_some());
+        assert_eq!(error.help(), help);
+    }
+
+    #[test]
+    fn it_should_classify_an_interruption_between_phases() {
+        let error = DeployCommandHandlerError::Interrupted {
+            current_state: "configured".to_string(),
+        };
+
+        assert_eq!(error.error_kind(), ErrorKind::Interrupted);
+        assert!(error.to_string().contains("'configured'"));
+    }
+}
@@ -0,0 +1,268 @@
+//! Deploy command handler implementation
+
+use std::path::PathBuf;
+use std::sync::Arc;
+
+use tokio_util::sync::CancellationToken;
+use tracing::{info, instrument};
+
+use super::errors::DeployCommandHandlerError;
+use super::target::DeployTarget;
+use crate::application::command_handlers::configure::ConfigureCommandHandler;
+use crate::application::command_handlers::provision::ProvisionCommandHandler;
+use crate::application::command_handlers::release::ReleaseCommandHandler;
+use crate::application::command_handlers::run::RunCommandHandler;
+use crate::application::errors::AllowedCommands;
+use crate::application::traits::CommandProgressListener;
+use crate::domain::environment::repository::EnvironmentRepository;
+use crate::domain::environment::state::AnyEnvironmentState;
+use crate::domain::environment::DiskSpaceThresholds;
+use crate::domain::EnvironmentName;
+
+/// Result of a deployment
+#[derive(Debug)]
+pub struct DeployOutcome {
+    /// State of the environment before the deployment
+    pub initial_state: &'static str,
+    /// Targets reached by the phases run, in order
+    pub performed: Vec<DeployTarget>,
+    /// The environment after the last phase
+    pub environment: AnyEnvironmentState,
+}
+
+/// `DeployCommandHandler` runs the deployment workflow up to a target state
+///
+/// This command handler:
+/// 1. Loads the environment and finds the last phase it completed
+/// 2. Runs the remaining phases (provision, configure, release, run) up to
+///    the target, each one through its own command handler
+/// 3. Stops at the first failing phase
+///
+/// Phases already completed are skipped, so running deploy again after a
+/// partial deployment resumes it.
+///
+/// # State Management
+///
+/// - Accepts environment in `Created`, `Provisioned`, `Configured`,
+///   `Released` or `Running` state
+/// - Each phase persists its own state transitions, including the failed
+///   state when the phase fails
+pub struct DeployCommandHandler {
+    repository: Arc<dyn EnvironmentRepository>,
+    cancellation: CancellationToken,
+    provision_handler: ProvisionCommandHandler,
+    configure_handler: ConfigureCommandHandler,
+    release_handler: ReleaseCommandHandler,
+    run_handler: RunCommandHandler,
+}
+
+impl DeployCommandHandler {
+    /// Create a new `DeployCommandHandler`
+    #[must_use]
+    pub fn new(
+        repository: Arc<dyn EnvironmentRepository>,
+        clock: Arc<dyn crate::shared::Clock>,
+    ) -> Self {
+        Self {
+            provision_handler: ProvisionCommandHandler::new(
+                Arc::clone(&clock),
+                Arc::clone(&repository),
+            ),
+            configure_handler: ConfigureCommandHandler::new(
+                Arc::clone(&clock),
+                Arc::clone(&repository),
+            ),
+            release_handler: ReleaseCommandHandler::new(
+                Arc::clone(&repository),
+                Arc::clone(&clock),
+            ),
+            run_handler: RunCommandHandler::new(Arc::clone(&repository), clock),
+            cancellation: CancellationToken::new(),
+            repository,
+        }
+    }
+
+    /// Cancel the workflow when the given token is cancelled (e.g. on Ctrl-C)
+    ///
+    /// The provision and configure phases stop at their next step boundary,
+    /// the run phase stops waiting for the tracker health; no new phase
+    /// starts once the token is cancelled.
+    #[must_use]
+    pub fn with_cancellation(mut self, cancellation: CancellationToken) -> Self {
+        self.provision_handler = self
+            .provision_handler
+            .with_cancellation(cancellation.clone());
+        self.configure_handler = self
+            .configure_handler
+            .with_cancellation(cancellation.clone());
+        self.run_handler = self.run_handler.with_cancellation(cancellation.clone());
+        self.cancellation = cancellation;
+        self
+    }
+
+    /// Keep the output logs of the last `runs` runs of each phase
+    #[must_use]
+    pub fn with_kept_run_logs(mut self, runs: usize) -> Self {
+        self.provision_handler = self.provision_handler.with_kept_run_logs(runs);
+        self.configure_handler = self.configure_handler.with_kept_run_logs(runs);
+        self.release_handler = self.release_handler.with_kept_run_logs(runs);
+        self.run_handler = self.run_handler.with_kept_run_logs(runs);
+        self
+    }
+
+    /// Write the trace files of a failure of any phase to `trace_dir`
+    #[must_use]
+    pub fn with_trace_dir(mut self, trace_dir: Option<PathBuf>) -> Self {
+        self.provision_handler = self.provision_handler.with_trace_dir(trace_dir.clone());
+        self.configure_handler = self.configure_handler.with_trace_dir(trace_dir.clone());
+        self.release_handler = self.release_handler.with_trace_dir(trace_dir.clone());
+        self.run_handler = self.run_handler.with_trace_dir(trace_dir);
+        self
+    }
+
+    /// Require the given free disk space before provisioning and releasing
+    #[must_use]
+    pub fn with_disk_space_thresholds(mut self, thresholds: DiskSpaceThresholds) -> Self {
+        self.provision_handler = self
+            .provision_handler
+            .with_disk_space_thresholds(thresholds);
+        self.release_handler = self.release_handler.with_disk_space_thresholds(thresholds);
+        self
+    }
+
+    /// Wait for the tracker to report healthy in the run phase (the default)
+    #[must_use]
+    pub fn with_wait_for_health(mut self, wait_for_health: bool) -> Self {
+        self.run_handler = self.run_handler.with_wait_for_health(wait_for_health);
+        self
+    }
+
+    /// Execute the deployment workflow
+    ///
+    /// Each phase is reported to the listener as a step, and the listener is
+    /// passed on to the command handler of the phase.
+    ///
+    /// # Arguments
+    ///
+    /// * `env_name` - The name of the environment to deploy
+    /// * `until` - The state at which the deployment stops
+    /// * `listener` - Optional progress listener
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if:
+    /// * Environment not found
+    /// * Environment is in a failed, in-progress or destroyed state
+    /// * A phase fails (its failed state is persisted by the phase)
+    /// * The user cancels the deployment
+    #[instrument(
+        name = "command.deploy",
+        skip_all,
+        fields(
+            command_type = "deploy",
+            environment_name = %env_name,
+            until = %until
+        )
+    )]
+    pub async fn execute(
+        &self,
+        env_name: &EnvironmentName,
+        until: DeployTarget,
+        listener: Option<&dyn CommandProgressListener>,
+    ) -> Result<DeployOutcome, DeployCommandHandlerError> {
+        let any_env = self.repository.load(env_name)?.ok_or_else(|| {
+            DeployCommandHandlerError::EnvironmentNotFound {
+                name: env_name.to_string(),
+            }
+        })?;
+
+        let initial_state = any_env.state_name();
+        let reached = reached_target(&any_env)?;
+        let pending = DeployTarget::pending(reached, until);
+
+        info!(
+            command = "deploy",
+            environment = %env_name,
+            current_state = initial_state,
+            phases = pending.len(),
+            "Deploying environment"
+        );
+
+        let mut environment = any_env;
+        for (index, target) in pending.iter().copied().enumerate() {
+            if self.cancellation.is_cancelled() {
+                return Err(DeployCommandHandlerError::Interrupted {
+                    current_state: environment.state_name().to_string(),
+                });
+            }
+
+            if let Some(l) = listener {
+                l.on_step_started(index + 1, pending.len(), target.description());
+            }
+
+            environment = self.run_phase(target, env_name, listener).await?;
+
+            if let Some(l) = listener {
+                l.on_step_completed(index + 1, target.description());
+            }
+        }
+
+        Ok(DeployOutcome {
+            initial_state,
+            performed: pending,
+            environment,
+        })
+    }
+
+    /// Run the phase reaching `target` through its command handler
+    async fn run_phase(
+        &self,
+        target: DeployTarget,
+        env_name: &EnvironmentName,
+        listener: Option<&dyn CommandProgressListener>,
+    ) -> Result<AnyEnvironmentState, DeployCommandHandlerError> {
+        let environment = match target {
+            DeployTarget::Provisioned => self
+                .provision_handler
+                .execute(env_name, listener)
+                .await?
+                .into_any(),
+            DeployTarget::Configured => self
+                .configure_handler
+                .execute(env_name, listener)?
+                .into_any(),
+            DeployTarget::Released => self
+                .release_handler
+                .execute(env_name, listener)
+                .await?
+                .into_any(),
+            DeployTarget::Running => self.run_handler.execute(env_name)?.into_any(),
+        };
+
+        Ok(environment)
+    }
+}
+
+/// Target of the last phase the environment completed
+///
+/// `None` for an environment that was never provisioned.
+///
+/// # Errors
+///
+/// Returns `InvalidState` for failed, in-progress and destroyed environments.
+fn reached_target(
+    environment: &AnyEnvironmentState,
+) -> Result<Option<DeployTarget>, DeployCommandHandlerError> {
+    match environment {
+        AnyEnvironmentState::Created(_) => Ok(None),
+        AnyEnvironmentState::Provisioned(_) => Ok(Some(DeployTarget::Provisioned)),
+        AnyEnvironmentState::Configured(_) => Ok(Some(DeployTarget::Configured)),
+        AnyEnvironmentState::Released(_) => Ok(Some(DeployTarget::Released)),
+        AnyEnvironmentState::Running(_) => Ok(Some(DeployTarget::Running)),
+        other => Err(DeployCommandHandlerError::InvalidState {
+            name: other.name().to_string(),
+            current_state: other.state_name().to_string(),
+            allowed_commands: AllowedCommands::from(other),
+        }),
+    }
+}
@@ -0,0 +1,36 @@
+//! Deploy Command Module
+//!
+//! This module implements the delivery-agnostic `DeployCommandHandler`
+//! for running the whole deployment workflow with a single command.
+//!
+//! ## Phases
+//!
+//! The deployment runs the provision, configure, release and run phases in
+//! order, each one through its own command handler. Phases the environment
+//! already completed are skipped, and `DeployTarget` selects the state at
+//! which the deployment stops.
+//!
+//! ## State Management
+//!
+//! - Accepts environment in `Created`, `Provisioned`, `Configured`,
+//!   `Released` or `Running` state
+//! - Every phase persists its own transitions; a failing phase leaves the
+//!   environment in its failed state and stops the deployment
+//!
+//! ## Module Organization
+//!
+//! - `handler.rs` - Core handler with `execute()`
+//! - `target.rs` - States at which the deployment can stop
+//! - `errors.rs` - Error types for deploy operations
+
+pub mod errors;
+pub mod handler;
+pub mod target;
+
+#[cfg(test)]
+mod tests;
+
+// Re-export main types for convenience
+pub use errors::DeployCommandHandlerError;
+pub use handler::{DeployCommandHandler, DeployOutcome};
+pub use target::DeployTarget;
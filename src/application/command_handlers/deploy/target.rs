@@ -0,0 +1,134 @@
+//! Deployment targets of the deploy command
+//!
+//! Each target is the state an environment reaches after one phase of the
+//! deployment workflow. The deploy command runs the phases between the
+//! current state of the environment and the requested target.
+
+use std::fmt;
+
+use serde::Serialize;
+
+/// State at which the deploy command stops
+///
+/// Targets are ordered along the deployment workflow, so comparing two
+/// targets tells which one comes first.
+#[derive(
+    Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Serialize, clap::ValueEnum,
+)]
+#[serde(rename_all = "snake_case")]
+pub enum DeployTarget {
+    /// Infrastructure created (`provision`)
+    Provisioned,
+
+    /// Instance configured with Docker and system packages (`configure`)
+    Configured,
+
+    /// Application files released to the instance (`release`)
+    Released,
+
+    /// Services started and the tracker healthy (`run`)
+    #[default]
+    Running,
+}
+
+impl DeployTarget {
+    /// All targets, in workflow order
+    pub const ALL: [Self; 4] = [
+        Self::Provisioned,
+        Self::Configured,
+        Self::Released,
+        Self::Running,
+    ];
+
+    /// Name used on the command line and in reports (the state name)
+    #[must_use]
+    pub fn name(self) -> &'static str {
+        match self {
+            Self::Provisioned => "provisioned",
+            Self::Configured => "configured",
+            Self::Released => "released",
+            Self::Running => "running",
+        }
+    }
+
+    /// Command whose phase reaches this target
+    #[must_use]
+    pub fn command(self) -> &'static str {
+        match self {
+            Self::Provisioned => "provision",
+            Self::Configured => "configure",
+            Self::Released => "release",
+            Self::Running => "run",
+        }
+    }
+
+    /// User-facing description of the phase reaching this target
+    #[must_use]
+    pub fn description(self) -> &'static str {
+        match self {
+            Self::Provisioned => "Provisioning infrastructure",
+            Self::Configured => "Configuring instance",
+            Self::Released => "Releasing application",
+            Self::Running => "Running application services",
+        }
+    }
+
+    /// Targets still to reach, in order, to go from `reached` to `until`
+    ///
+    /// `reached` is `None` for an environment that has not been provisioned.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use torrust_tracker_deployer_lib::application::command_handlers::deploy::DeployTarget;
+    ///
+    /// let pending = DeployTarget::pending(Some(DeployTarget::Configured), DeployTarget::Running);
+    ///
+    /// assert_eq!(pending, vec![DeployTarget::Released, DeployTarget::Running]);
+    /// ```
+    #[must_use]
+    pub fn pending(reached: Option<Self>, until: Self) -> Vec<Self> {
+        Self::ALL
+            .into_iter()
+            .filter(|target| Some(*target) > reached && *target <= until)
+            .collect()
+    }
+}
+
+impl fmt::Display for DeployTarget {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.name())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn it_should_run_every_phase_for_an_environment_that_was_never_provisioned() {
+        assert_eq!(
+            DeployTarget::pending(None, DeployTarget::Running),
+            DeployTarget::ALL.to_vec()
+        );
+    }
+
+    #[test]
+    fn it_should_stop_at_the_requested_target() {
+        assert_eq!(
+            DeployTarget::pending(None, DeployTarget::Configured),
+            vec![DeployTarget::Provisioned, DeployTarget::Configured]
+        );
+    }
+
+    #[test]
+    fn it_should_have_nothing_pending_when_the_target_was_already_reached() {
+        assert!(
+            DeployTarget::pending(Some(DeployTarget::Running), DeployTarget::Running).is_empty()
+        );
+        assert!(
+            DeployTarget::pending(Some(DeployTarget::Released), DeployTarget::Configured)
+                .is_empty()
+        );
+    }
+}
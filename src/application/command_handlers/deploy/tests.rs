@@ -0,0 +1,128 @@
+//! Test module for Deploy Command
+
+use std::net::{IpAddr, Ipv4Addr};
+use std::sync::Arc;
+
+use chrono::Utc;
+use tempfile::TempDir;
+use tokio_util::sync::CancellationToken;
+
+use super::errors::DeployCommandHandlerError;
+use super::handler::DeployCommandHandler;
+use super::target::DeployTarget;
+use crate::domain::environment::repository::EnvironmentRepository;
+use crate::domain::environment::runtime_outputs::ProvisionMethod;
+use crate::domain::environment::state::AnyEnvironmentState;
+use crate::domain::environment::testing::EnvironmentTestBuilder;
+use crate::domain::EnvironmentName;
+use crate::infrastructure::persistence::filesystem::file_environment_repository::FileEnvironmentRepository;
+use crate::testing::mock_clock::MockClock;
+
+fn create_test_handler() -> (
+    DeployCommandHandler,
+    Arc<FileEnvironmentRepository>,
+    TempDir,
+) {
+    let temp_dir = TempDir::new().expect("Failed to create temp directory");
+    let clock = Arc::new(MockClock::new(Utc::now()));
+    let repository = Arc::new(FileEnvironmentRepository::new(
+        temp_dir.path().to_path_buf(),
+    ));
+    let handler = DeployCommandHandler::new(repository.clone(), clock);
+    (handler, repository, temp_dir)
+}
+
+/// Save an environment that completed every phase up to `configured`
+fn save_configured_environment(repository: &FileEnvironmentRepository, name: &str) -> TempDir {
+    let (environment, _data_dir, _build_dir, env_temp_dir) = EnvironmentTestBuilder::new()
+        .with_name(name)
+        .build_with_custom_paths();
+    let configured = environment
+        .start_provisioning()
+        .provisioned(
+            IpAddr::V4(Ipv4Addr::new(10, 0, 0, 2)),
+            ProvisionMethod::Provisioned,
+        )
+        .start_configuring()
+        .configured();
+    repository
+        .save(&configured.into_any())
+        .expect("Failed to save environment");
+    env_temp_dir
+}
+
+#[tokio::test]
+async fn it_should_return_environment_not_found_error_when_environment_does_not_exist() {
+    let (handler, _repository, _temp_dir) = create_test_handler();
+    let env_name = EnvironmentName::new("nonexistent-env").unwrap();
+
+    let result = handler
+        .execute(&env_name, DeployTarget::Running, None)
+        .await;
+
+    assert!(matches!(
+        result,
+        Err(DeployCommandHandlerError::EnvironmentNotFound { .. })
+    ));
+}
+
+#[tokio::test]
+async fn it_should_refuse_to_resume_from_an_in_progress_state() {
+    let (handler, repository, _temp_dir) = create_test_handler();
+    let (environment, _data_dir, _build_dir, _env_temp_dir) = EnvironmentTestBuilder::new()
+        .with_name("provisioning")
+        .build_with_custom_paths();
+    repository
+        .save(&environment.start_provisioning().into_any())
+        .expect("Failed to save environment");
+    let env_name = EnvironmentName::new("provisioning").unwrap();
+
+    let result = handler
+        .execute(&env_name, DeployTarget::Running, None)
+        .await;
+
+    assert!(matches!(
+        result,
+        Err(DeployCommandHandlerError::InvalidState { current_state, .. }) if current_state == "provisioning"
+    ));
+}
+
+#[tokio::test]
+async fn it_should_skip_every_phase_when_the_target_was_already_reached() {
+    let (handler, repository, _temp_dir) = create_test_handler();
+    let _env_temp_dir = save_configured_environment(&repository, "configured");
+    let env_name = EnvironmentName::new("configured").unwrap();
+
+    let outcome = handler
+        .execute(&env_name, DeployTarget::Configured, None)
+        .await
+        .expect("Nothing to deploy should succeed");
+
+    assert_eq!(outcome.initial_state, "configured");
+    assert!(outcome.performed.is_empty());
+    assert!(matches!(
+        outcome.environment,
+        AnyEnvironmentState::Configured(_)
+    ));
+}
+
+#[tokio::test]
+async fn it_should_not_start_a_phase_once_cancelled() {
+    let (handler, repository, _temp_dir) = create_test_handler();
+    let _env_temp_dir = save_configured_environment(&repository, "cancelled");
+    let env_name = EnvironmentName::new("cancelled").unwrap();
+    let cancellation = CancellationToken::new();
+    cancellation.cancel();
+    let handler = handler.with_cancellation(cancellation);
+
+    let result = handler
+        .execute(&env_name, DeployTarget::Running, None)
+        .await;
+
+    assert!(matches!(
+        result,
+        Err(DeployCommandHandlerError::Interrupted { current_state }) if current_state == "configured"
+    ));
+    let reloaded = repository.load(&env_name).unwrap().unwrap();
+    assert_eq!(reloaded.state_name(), "configured");
+}
@@ -91,8 +91,8 @@ impl crate::shared::Traceable for DestroyCommandHandlerError {
             Self::EnvironmentNotFound { .. } | Self::StateTransition(_) => {
                 crate::shared::ErrorKind::Configuration
             }
-            Self::OpenTofu(_) => crate::shared::ErrorKind::InfrastructureOperation,
-            Self::Command(_) => crate::shared::ErrorKind::CommandExecution,
+            Self::OpenTofu(e) => crate::shared::Traceable::error_kind(e),
+            Self::Command(e) => crate::shared::Traceable::error_kind(e),
             Self::StatePersistence(_) | Self::StateCleanupFailed { .. } => {
                 crate::shared::ErrorKind::StatePersistence
             }
@@ -254,6 +254,7 @@ If the problem persists, report it with full system details."
 mod tests {
     use super::*;
     use crate::adapters::tofu::client::OpenTofuError;
+    use crate::application::errors::AllowedCommands;
     use crate::shared::command::CommandError;
     use std::path::PathBuf;
 
@@ -307,6 +308,7 @@ mod tests {
         let error = DestroyCommandHandlerError::StateTransition(InvalidStateError {
             expected: "Provisioned".to_string(),
             actual: "Created".to_string(),
+            allowed_commands: AllowedCommands::default(),
         });
 
         let help = error.help();
@@ -364,6 +366,7 @@ mod tests {
             DestroyCommandHandlerError::StateTransition(InvalidStateError {
                 expected: "Provisioned".to_string(),
                 actual: "Created".to_string(),
+                allowed_commands: AllowedCommands::default(),
             }),
             DestroyCommandHandlerError::StateCleanupFailed {
                 path: PathBuf::from("/test"),
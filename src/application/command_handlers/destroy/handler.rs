@@ -1,17 +1,29 @@
 //! Destroy command handler implementation
 
 use std::sync::Arc;
+use std::time::Duration;
 
-use tracing::{info, instrument};
+use tracing::{info, instrument, warn};
 
 use super::errors::DestroyCommandHandlerError;
-use crate::application::command_handlers::common::StepResult;
-use crate::application::steps::DestroyInfrastructureStep;
+use crate::adapters::tofu::client::OpenTofuClient;
+use crate::adapters::tofu::missing_resource::is_missing_resource_error;
+use crate::application::command_handlers::common::{prune_run_logs, DryRunReport, StepResult};
+use crate::application::steps::{DestroyInfrastructureStep, PlanInfrastructureStep};
+use crate::application::traits::CommandProgressListener;
 use crate::domain::environment::repository::{EnvironmentRepository, TypedEnvironmentRepository};
 use crate::domain::environment::{Destroyed, Destroying, Environment};
 use crate::domain::{AnyEnvironmentState, EnvironmentName};
+use crate::shared::command::run_log::DEFAULT_KEPT_RUNS;
+use crate::shared::command::{CommandTimeouts, RunLog};
 use crate::shared::error::Traceable;
 
+/// Total number of steps in the destroy workflow.
+///
+/// This constant is used for progress reporting via `CommandProgressListener`
+/// to display step progress like "[Step 1/2] Destroying infrastructure...".
+const TOTAL_DESTROY_STEPS: usize = 2;
+
 /// `DestroyCommandHandler` orchestrates the complete infrastructure destruction workflow
 ///
 /// The `DestroyCommandHandler` orchestrates the complete infrastructure teardown workflow.
@@ -38,9 +50,26 @@ use crate::shared::error::Traceable;
 /// - Succeed if the infrastructure is already destroyed
 /// - Report appropriate status to the user
 /// - Not fail due to missing resources
+///
+/// When `tofu destroy` only fails because resources were already deleted
+/// outside the deployer (e.g. `lxc delete`), the failure is logged as a
+/// warning and the environment still ends up `Destroyed`. Any other failure
+/// persists the environment as `DestroyFailed`. With [`Self::with_refresh`],
+/// `tofu refresh` reconciles the state before destroying.
+///
+/// # Timeouts
+///
+/// `tofu destroy` is bounded by [`CommandTimeouts::opentofu_destroy`], capped
+/// by the optional overall deadline (see [`Self::with_deadline`]). A hung
+/// command is killed and the environment is persisted as `DestroyFailed`.
 pub struct DestroyCommandHandler {
     pub(crate) repository: TypedEnvironmentRepository,
     pub(crate) clock: Arc<dyn crate::shared::Clock>,
+    pub(crate) command_timeouts: CommandTimeouts,
+    pub(crate) deadline: Option<Duration>,
+    pub(crate) refresh: bool,
+    pub(crate) opentofu_executable: Option<String>,
+    pub(crate) kept_run_logs: usize,
 }
 
 impl DestroyCommandHandler {
@@ -53,14 +82,61 @@ impl DestroyCommandHandler {
         Self {
             repository: TypedEnvironmentRepository::new(repository),
             clock,
+            command_timeouts: CommandTimeouts::default(),
+            deadline: None,
+            refresh: false,
+            opentofu_executable: None,
+            kept_run_logs: DEFAULT_KEPT_RUNS,
         }
     }
 
+    /// Bound each `OpenTofu` command with the given timeouts
+    #[must_use]
+    pub fn with_command_timeouts(mut self, command_timeouts: CommandTimeouts) -> Self {
+        self.command_timeouts = command_timeouts;
+        self
+    }
+
+    /// Kill `OpenTofu` once `deadline` has passed since the workflow started
+    #[must_use]
+    pub fn with_deadline(mut self, deadline: Duration) -> Self {
+        self.deadline = Some(deadline);
+        self
+    }
+
+    /// Run `tofu refresh` before destroying to reconcile the state with the
+    /// real infrastructure
+    #[must_use]
+    pub fn with_refresh(mut self, refresh: bool) -> Self {
+        self.refresh = refresh;
+        self
+    }
+
+    /// Run the given executable instead of `tofu` from the `PATH`
+    #[must_use]
+    pub fn with_opentofu_executable(mut self, executable: impl Into<String>) -> Self {
+        self.opentofu_executable = Some(executable.into());
+        self
+    }
+
+    /// Keep the `OpenTofu` output logs of the last `runs` destroy runs
+    ///
+    /// The logs are removed with the build directory once the environment
+    /// is destroyed; they are kept when the destruction fails.
+    #[must_use]
+    pub fn with_kept_run_logs(mut self, runs: usize) -> Self {
+        self.kept_run_logs = runs;
+        self
+    }
+
     /// Execute the complete destruction workflow
     ///
     /// # Arguments
     ///
     /// * `env_name` - The name of the environment to destroy
+    /// * `listener` - Optional progress listener for reporting step-level progress.
+    ///   When provided, the handler calls `on_step_started` and
+    ///   `on_step_completed` for each workflow step.
     ///
     /// # Returns
     ///
@@ -76,17 +152,19 @@ impl DestroyCommandHandler {
     ///
     /// On error, the environment transitions to `DestroyFailed` state and is persisted.
     #[instrument(
-        name = "destroy_command",
+        name = "command.destroy",
         skip_all,
         fields(
             command_type = "destroy",
-            environment = %env_name
+            environment_name = %env_name
         )
     )]
     pub fn execute(
         &self,
         env_name: &EnvironmentName,
+        listener: Option<&dyn CommandProgressListener>,
     ) -> Result<Environment<Destroyed>, DestroyCommandHandlerError> {
+        let _lock = self.repository.inner().lock(env_name)?;
         let any_env = self.load_environment(env_name)?;
 
         if let AnyEnvironmentState::Destroyed(env) = any_env {
@@ -100,37 +178,23 @@ impl DestroyCommandHandler {
 
         let started_at = self.clock.now();
 
-        let opentofu_build_dir = any_env.tofu_build_dir();
+        let run_log = self.run_log(&any_env, started_at);
 
-        let destroying_env = match any_env {
-            AnyEnvironmentState::Created(env) => env.start_destroying(),
-            AnyEnvironmentState::Provisioning(env) => env.start_destroying(),
-            AnyEnvironmentState::Provisioned(env) => env.start_destroying(),
-            AnyEnvironmentState::Configuring(env) => env.start_destroying(),
-            AnyEnvironmentState::Configured(env) => env.start_destroying(),
-            AnyEnvironmentState::Releasing(env) => env.start_destroying(),
-            AnyEnvironmentState::Released(env) => env.start_destroying(),
-            AnyEnvironmentState::Running(env) => env.start_destroying(),
-            AnyEnvironmentState::Destroying(env) => env, // Already destroying
-            AnyEnvironmentState::ProvisionFailed(env) => env.start_destroying(),
-            AnyEnvironmentState::ConfigureFailed(env) => env.start_destroying(),
-            AnyEnvironmentState::ReleaseFailed(env) => env.start_destroying(),
-            AnyEnvironmentState::RunFailed(env) => env.start_destroying(),
-            AnyEnvironmentState::DestroyFailed(env) => env.start_destroying(),
-            AnyEnvironmentState::Destroyed(_) => {
-                unreachable!("Already handled Destroyed state above")
-            }
-        };
+        let destroying_env = Self::start_destroying(any_env);
 
         self.repository.save_destroying(&destroying_env)?;
 
-        let opentofu_client = Arc::new(crate::adapters::tofu::client::OpenTofuClient::new(
-            opentofu_build_dir,
-        ));
+        let opentofu_client = Arc::new(self.opentofu_client(&destroying_env, &run_log));
 
-        match Self::execute_destruction_with_tracking(&destroying_env, &opentofu_client) {
+        let result =
+            self.execute_destruction_with_tracking(&destroying_env, &opentofu_client, listener);
+        prune_run_logs(&run_log);
+
+        match result {
             Ok(()) => {
-                let destroyed = destroying_env.destroyed();
+                let destroyed = destroying_env
+                    .destroyed()
+                    .with_destroyed_at(self.clock.now());
 
                 self.repository.save_destroyed(&destroyed)?;
 
@@ -154,6 +218,73 @@ impl DestroyCommandHandler {
         }
     }
 
+    /// Simulate the destruction workflow without changing anything
+    ///
+    /// Runs `tofu plan -destroy` to list the resources that would be removed
+    /// and collects the local directories that would be deleted. The
+    /// environment state is neither transitioned nor persisted and no file is
+    /// deleted, whatever the outcome.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the environment cannot be loaded or the `OpenTofu`
+    /// plan fails.
+    #[instrument(
+        name = "command.destroy_dry_run",
+        skip_all,
+        fields(
+            command_type = "destroy",
+            environment_name = %env_name,
+            dry_run = true
+        )
+    )]
+    pub fn dry_run(
+        &self,
+        env_name: &EnvironmentName,
+    ) -> Result<DryRunReport, DestroyCommandHandlerError> {
+        let any_env = self.load_environment(env_name)?;
+
+        if matches!(any_env, AnyEnvironmentState::Destroyed(_)) {
+            return Ok(DryRunReport::new().with_note("The environment is already destroyed"));
+        }
+
+        let run_log = self.run_log(&any_env, self.clock.now());
+
+        // Only used in memory to reuse the workflow decisions; never persisted
+        let destroying_env = Self::start_destroying(any_env);
+
+        let mut report = DryRunReport::new();
+
+        if Self::should_destroy_infrastructure(&destroying_env) {
+            let opentofu_client = Arc::new(self.opentofu_client(&destroying_env, &run_log));
+            let changes = PlanInfrastructureStep::new(opentofu_client)
+                .for_destroy()
+                .preview();
+            prune_run_logs(&run_log);
+            report = report.with_infrastructure_changes(changes?);
+        } else if Self::is_registered(&destroying_env) {
+            report = report
+                .with_note("The instance was registered or imported; it would not be destroyed");
+        } else {
+            report = report.with_note("The environment was never provisioned");
+        }
+
+        for dir in [destroying_env.data_dir(), destroying_env.build_dir()] {
+            if dir.exists() {
+                report = report.with_removed_path(dir.clone());
+            }
+        }
+
+        info!(
+            command = "destroy",
+            environment = %env_name,
+            resources = report.infrastructure_changes().len(),
+            "Destroy dry run completed"
+        );
+
+        Ok(report)
+    }
+
     // pub(crate) helper methods for testing business logic
 
     /// Check if infrastructure should be destroyed
@@ -247,6 +378,30 @@ impl DestroyCommandHandler {
 
     // Private helper methods
 
+    /// Transition an environment in any state but `Destroyed` to `Destroying`
+    fn start_destroying(any_env: AnyEnvironmentState) -> Environment<Destroying> {
+        match any_env {
+            AnyEnvironmentState::Created(env) => env.start_destroying(),
+            AnyEnvironmentState::Provisioning(env) => env.start_destroying(),
+            AnyEnvironmentState::Provisioned(env) => env.start_destroying(),
+            AnyEnvironmentState::Configuring(env) => env.start_destroying(),
+            AnyEnvironmentState::Configured(env) => env.start_destroying(),
+            AnyEnvironmentState::Releasing(env) => env.start_destroying(),
+            AnyEnvironmentState::Released(env) => env.start_destroying(),
+            AnyEnvironmentState::Running(env) => env.start_destroying(),
+            AnyEnvironmentState::Stopped(env) => env.start_destroying(),
+            AnyEnvironmentState::Destroying(env) => env, // Already destroying
+            AnyEnvironmentState::ProvisionFailed(env) => env.start_destroying(),
+            AnyEnvironmentState::ConfigureFailed(env) => env.start_destroying(),
+            AnyEnvironmentState::ReleaseFailed(env) => env.start_destroying(),
+            AnyEnvironmentState::RunFailed(env) => env.start_destroying(),
+            AnyEnvironmentState::DestroyFailed(env) => env.start_destroying(),
+            AnyEnvironmentState::Destroyed(_) => {
+                unreachable!("Callers handle the Destroyed state first")
+            }
+        }
+    }
+
     /// Execute the destruction steps with step tracking
     ///
     /// This method executes all destruction steps while tracking which step is currently
@@ -257,29 +412,32 @@ impl DestroyCommandHandler {
     ///
     /// Returns a tuple of (error, `current_step`) if any destruction step fails
     fn execute_destruction_with_tracking(
+        &self,
         environment: &crate::domain::environment::Environment<
             crate::domain::environment::Destroying,
         >,
-        opentofu_client: &Arc<crate::adapters::tofu::client::OpenTofuClient>,
+        opentofu_client: &Arc<OpenTofuClient>,
+        listener: Option<&dyn CommandProgressListener>,
     ) -> StepResult<(), DestroyCommandHandlerError, crate::domain::environment::state::DestroyStep>
     {
         use crate::domain::environment::state::DestroyStep;
 
-        // Step 1: Conditionally destroy infrastructure via OpenTofu
+        // Step 1/2: Conditionally destroy infrastructure via OpenTofu
+        Self::notify_step_started(listener, 1, "Destroying infrastructure");
         // Only attempt infrastructure destruction if infrastructure was provisioned (not registered)
         if Self::should_destroy_infrastructure(environment) {
             info!(
                 environment = %environment.name(),
                 "Destroying provisioned infrastructure"
             );
-            Self::destroy_infrastructure(opentofu_client)
+            self.destroy_infrastructure(environment, opentofu_client)
                 .map_err(|e| (e, DestroyStep::DestroyInfrastructure))?;
         } else if Self::is_registered(environment) {
             // Registered environments have external infrastructure that we don't manage
             tracing::warn!(
                 environment = %environment.name(),
                 instance_ip = ?environment.instance_ip(),
-                "This environment was registered from existing infrastructure. \
+                "This environment was registered or imported from existing infrastructure. \
                  The infrastructure will NOT be destroyed. \
                  You are responsible for destroying the actual instance (VM, container, or server) manually."
             );
@@ -294,12 +452,38 @@ impl DestroyCommandHandler {
             );
         }
 
-        // Step 2: Clean up state files
+        Self::notify_step_completed(listener, 1, "Destroying infrastructure");
+
+        // Step 2/2: Clean up state files
+        Self::notify_step_started(listener, 2, "Cleaning up state files");
         Self::cleanup_state_files(environment).map_err(|e| (e, DestroyStep::CleanupStateFiles))?;
+        Self::notify_step_completed(listener, 2, "Cleaning up state files");
 
         Ok(())
     }
 
+    /// Notify the progress listener that a step has started.
+    fn notify_step_started(
+        listener: Option<&dyn CommandProgressListener>,
+        step_number: usize,
+        description: &str,
+    ) {
+        if let Some(l) = listener {
+            l.on_step_started(step_number, TOTAL_DESTROY_STEPS, description);
+        }
+    }
+
+    /// Notify the progress listener that a step has completed.
+    fn notify_step_completed(
+        listener: Option<&dyn CommandProgressListener>,
+        step_number: usize,
+        description: &str,
+    ) {
+        if let Some(l) = listener {
+            l.on_step_completed(step_number, description);
+        }
+    }
+
     /// Build structured failure context for destroy command errors
     ///
     /// Creates a comprehensive `DestroyFailureContext` containing all relevant
@@ -349,19 +533,37 @@ impl DestroyCommandHandler {
     /// Destroy the infrastructure using `OpenTofu`
     ///
     /// Executes the `OpenTofu` destroy workflow to remove all managed infrastructure.
+    /// A failure that only reports resources already gone is logged as a
+    /// warning: nothing is left to destroy.
     ///
     /// # Arguments
     ///
+    /// * `environment` - The environment being destroyed
     /// * `opentofu_client` - The `OpenTofu` client configured with the correct build directory
     ///
     /// # Errors
     ///
-    /// Returns an error if `OpenTofu` destroy fails
+    /// Returns an error if `OpenTofu` refresh or destroy fails for any other reason
     fn destroy_infrastructure(
-        opentofu_client: &Arc<crate::adapters::tofu::client::OpenTofuClient>,
+        &self,
+        environment: &Environment<Destroying>,
+        opentofu_client: &Arc<OpenTofuClient>,
     ) -> Result<(), DestroyCommandHandlerError> {
-        DestroyInfrastructureStep::new(Arc::clone(opentofu_client)).execute()?;
-        Ok(())
+        match DestroyInfrastructureStep::new(Arc::clone(opentofu_client))
+            .with_refresh(self.refresh)
+            .execute()
+        {
+            Ok(()) => Ok(()),
+            Err(error) if is_missing_resource_error(&error) => {
+                warn!(
+                    environment = %environment.name(),
+                    error = %error,
+                    "Some resources were already deleted outside the deployer; treating them as destroyed"
+                );
+                Ok(())
+            }
+            Err(error) => Err(error.into()),
+        }
     }
 
     /// Load environment from storage
@@ -385,4 +587,36 @@ impl DestroyCommandHandler {
             name: env_name.to_string(),
         })
     }
+
+    /// `OpenTofu` client for the environment's build directory, writing its output to `run_log`
+    fn opentofu_client(
+        &self,
+        environment: &Environment<Destroying>,
+        run_log: &RunLog,
+    ) -> OpenTofuClient {
+        let opentofu_client = OpenTofuClient::new(environment.tofu_build_dir())
+            .with_timeouts(self.run_timeouts())
+            .with_run_log(run_log.clone())
+            .with_env_vars(environment.context().user_inputs.proxy_env_vars());
+        match &self.opentofu_executable {
+            Some(executable) => opentofu_client.with_executable(executable.clone()),
+            None => opentofu_client,
+        }
+    }
+
+    /// Output logs of the destroy run started at `started_at`
+    fn run_log(
+        &self,
+        environment: &AnyEnvironmentState,
+        started_at: chrono::DateTime<chrono::Utc>,
+    ) -> RunLog {
+        RunLog::new(&environment.run_logs_dir(), "destroy", started_at).keeping(self.kept_run_logs)
+    }
+
+    /// Command timeouts for a run starting now, capped by the overall deadline
+    fn run_timeouts(&self) -> CommandTimeouts {
+        self.command_timeouts
+            .clone()
+            .with_deadline_after(self.deadline)
+    }
 }
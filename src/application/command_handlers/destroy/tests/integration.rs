@@ -139,3 +139,131 @@ fn it_should_clean_up_state_files_regardless_of_infrastructure_state() {
         "Build directory should be removed after cleanup"
     );
 }
+
+#[test]
+fn it_should_list_the_directories_to_delete_without_changing_anything_in_a_dry_run() {
+    use crate::domain::environment::testing::EnvironmentTestBuilder;
+
+    // Arrange: A created environment with local data and build directories
+    let (command_handler, _handler_temp_dir) = DestroyCommandHandlerTestBuilder::new().build();
+    let (created_env, data_dir, build_dir, _temp_dir) =
+        EnvironmentTestBuilder::new().build_with_custom_paths();
+    std::fs::create_dir_all(&data_dir).expect("Failed to create data dir");
+    std::fs::create_dir_all(&build_dir).expect("Failed to create build dir");
+    command_handler
+        .repository
+        .save_created(&created_env)
+        .expect("Failed to save environment");
+
+    // Act
+    let report = command_handler
+        .dry_run(created_env.name())
+        .expect("Dry run should succeed");
+
+    // Assert: The directories are reported but kept, and the state is unchanged
+    assert!(report.infrastructure_changes().is_empty());
+    assert_eq!(
+        report.removed_paths(),
+        [data_dir.clone(), build_dir.clone()]
+    );
+    assert!(data_dir.exists() && build_dir.exists());
+
+    let reloaded = command_handler
+        .repository
+        .inner()
+        .load(created_env.name())
+        .expect("Failed to load environment")
+        .expect("Environment should still exist");
+    assert_eq!(reloaded.state_name(), "created");
+}
+
+#[test]
+fn it_should_record_the_timing_of_every_destroy_step_in_the_deployment_report() {
+    use std::collections::BTreeMap;
+
+    use crate::application::services::deployment_report::DeploymentReportListener;
+    use crate::domain::environment::repository::EnvironmentRepository;
+    use crate::domain::environment::testing::EnvironmentTestBuilder;
+    use crate::infrastructure::persistence::filesystem::file_environment_repository::FileEnvironmentRepository;
+    use crate::shared::SystemClock;
+
+    // Arrange: Save an environment that was never provisioned
+    let (created_env, _data_dir, _build_dir, temp_dir) = EnvironmentTestBuilder::new()
+        .with_name("report-env")
+        .build_with_custom_paths();
+    let env_name = created_env.name().clone();
+    let repository = Arc::new(FileEnvironmentRepository::new(temp_dir.path().join("data")));
+    repository.save(&created_env.into_any()).unwrap();
+    let clock = Arc::new(SystemClock);
+    let recorder = DeploymentReportListener::new("destroy", &env_name, clock.clone(), None);
+
+    // Act
+    DestroyCommandHandler::new(repository, clock)
+        .execute(&env_name, Some(&recorder))
+        .unwrap();
+
+    // Assert
+    let report = recorder.finish(None, None, BTreeMap::new());
+    let steps: Vec<&str> = report
+        .steps
+        .iter()
+        .map(|step| step.description.as_str())
+        .collect();
+    assert_eq!(
+        steps,
+        ["Destroying infrastructure", "Cleaning up state files"]
+    );
+    assert!(report.incomplete_steps().is_empty());
+}
+
+#[test]
+fn it_should_skip_an_environment_locked_by_another_command_in_a_multi_environment_run() {
+    use std::num::NonZeroUsize;
+    use std::time::Duration;
+
+    use crate::application::command_handlers::common::MultiEnvironmentRunner;
+    use crate::domain::environment::repository::EnvironmentRepository;
+    use crate::domain::environment::testing::EnvironmentTestBuilder;
+    use crate::infrastructure::persistence::filesystem::file_environment_repository::FileEnvironmentRepository;
+    use crate::shared::SystemClock;
+
+    // Arrange: Two environments that were never provisioned, one of them held
+    // by another command
+    let (first_env, _first_data_dir, _first_build_dir, temp_dir) = EnvironmentTestBuilder::new()
+        .with_name("multi-1")
+        .build_with_custom_paths();
+    let (second_env, _second_data_dir, _second_build_dir, _second_temp_dir) =
+        EnvironmentTestBuilder::new()
+            .with_name("multi-2")
+            .build_with_custom_paths();
+    let names = vec![first_env.name().clone(), second_env.name().clone()];
+    let repository = Arc::new(
+        FileEnvironmentRepository::new(temp_dir.path().join("data"))
+            .with_lock_timeout(Duration::from_millis(100)),
+    );
+    repository.save(&first_env.into_any()).unwrap();
+    repository.save(&second_env.into_any()).unwrap();
+    let _other_command = repository.lock(&names[1]).unwrap();
+
+    // Act
+    let report =
+        MultiEnvironmentRunner::new(NonZeroUsize::new(2).unwrap()).run(&names, None, |name| {
+            DestroyCommandHandler::new(repository.clone(), Arc::new(SystemClock))
+                .execute(name, None)
+        });
+
+    // Assert: The locked environment is left untouched, the other one destroyed
+    assert!(report.outcomes[0].succeeded());
+    assert!(report.outcomes[1]
+        .error
+        .as_deref()
+        .is_some_and(|error| error.contains("Conflict")));
+    assert_eq!(
+        repository.load(&names[0]).unwrap().unwrap().state_name(),
+        "destroyed"
+    );
+    assert_eq!(
+        repository.load(&names[1]).unwrap().unwrap().state_name(),
+        "created"
+    );
+}
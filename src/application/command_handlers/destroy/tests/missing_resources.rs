@@ -0,0 +1,125 @@
+//! Missing resource tests for Destroy Command
+//!
+//! These tests run the workflow against a fake `tofu` executable whose
+//! `destroy` fails, and verify how the failure is classified: resources
+//! already deleted outside the deployer still end in `Destroyed`, while
+//! genuine failures are persisted as `DestroyFailed`.
+
+use std::os::unix::fs::PermissionsExt;
+use std::path::{Path, PathBuf};
+use std::sync::Arc;
+
+use crate::application::command_handlers::destroy::{
+    DestroyCommandHandler, DestroyCommandHandlerError,
+};
+use crate::domain::environment::repository::EnvironmentRepository;
+use crate::domain::environment::state::{AnyEnvironmentState, DestroyStep};
+use crate::domain::environment::testing::EnvironmentTestBuilder;
+use crate::domain::EnvironmentName;
+use crate::infrastructure::persistence::filesystem::file_environment_repository::FileEnvironmentRepository;
+use crate::shared::SystemClock;
+
+/// Writes a fake `tofu` that logs its subcommands and fails `destroy` with `stderr`
+fn write_failing_tofu(dir: &Path, stderr: &str) -> (String, PathBuf) {
+    let script = dir.join("fake-tofu");
+    let calls_file = dir.join("tofu-calls.log");
+    std::fs::write(
+        &script,
+        format!(
+            "#!/bin/sh\necho \"$1\" >> {}\nif [ \"$1\" = destroy ]; then\n  printf '%s\\n' '{stderr}' >&2\n  exit 1\nfi\n",
+            calls_file.display()
+        ),
+    )
+    .unwrap();
+    std::fs::set_permissions(&script, std::fs::Permissions::from_mode(0o755)).unwrap();
+
+    (script.display().to_string(), calls_file)
+}
+
+/// Saves a provisioned-looking environment: its `OpenTofu` build directory exists
+fn save_environment(
+    name: &str,
+) -> (
+    EnvironmentName,
+    Arc<FileEnvironmentRepository>,
+    tempfile::TempDir,
+) {
+    let (environment, _data_dir, _build_dir, temp_dir) = EnvironmentTestBuilder::new()
+        .with_name(name)
+        .build_with_custom_paths();
+    std::fs::create_dir_all(environment.tofu_build_dir()).unwrap();
+    let env_name = environment.name().clone();
+    let repository = Arc::new(FileEnvironmentRepository::new(temp_dir.path().join("data")));
+    repository.save(&environment.into_any()).unwrap();
+
+    (env_name, repository, temp_dir)
+}
+
+#[test]
+fn it_should_end_destroyed_when_the_instance_was_already_deleted() {
+    let (env_name, repository, temp_dir) = save_environment("already-deleted-env");
+    let (fake_tofu, _calls_file) = write_failing_tofu(
+        temp_dir.path(),
+        "│ Error: Failed to retrieve instance \"torrust-tracker-vm\": Instance not found",
+    );
+
+    let handler = DestroyCommandHandler::new(repository.clone(), Arc::new(SystemClock))
+        .with_opentofu_executable(fake_tofu);
+
+    let result = handler.execute(&env_name, None);
+
+    assert!(result.is_ok(), "destroy should succeed: {:?}", result.err());
+    assert!(matches!(
+        repository.load(&env_name).unwrap().unwrap(),
+        AnyEnvironmentState::Destroyed(_)
+    ));
+}
+
+#[test]
+fn it_should_persist_destroy_failed_when_opentofu_fails_for_another_reason() {
+    let (env_name, repository, temp_dir) = save_environment("genuine-failure-env");
+    let (fake_tofu, _calls_file) = write_failing_tofu(
+        temp_dir.path(),
+        "│ Error: Failed to delete instance: Unable to connect to the LXD server",
+    );
+
+    let handler = DestroyCommandHandler::new(repository.clone(), Arc::new(SystemClock))
+        .with_opentofu_executable(fake_tofu);
+
+    let result = handler.execute(&env_name, None);
+
+    assert!(matches!(
+        result,
+        Err(DestroyCommandHandlerError::Command(_))
+    ));
+
+    let persisted = repository.load(&env_name).unwrap().unwrap();
+    let AnyEnvironmentState::DestroyFailed(failed) = &persisted else {
+        panic!(
+            "expected DestroyFailed state, got {}",
+            persisted.state_name()
+        );
+    };
+    assert_eq!(
+        failed.state().context.failed_step,
+        DestroyStep::DestroyInfrastructure
+    );
+}
+
+#[test]
+fn it_should_refresh_the_state_before_destroying_when_asked() {
+    let (env_name, repository, temp_dir) = save_environment("refresh-env");
+    let (fake_tofu, calls_file) =
+        write_failing_tofu(temp_dir.path(), "│ Error: Instance not found");
+
+    let handler = DestroyCommandHandler::new(repository.clone(), Arc::new(SystemClock))
+        .with_opentofu_executable(fake_tofu)
+        .with_refresh(true);
+
+    handler.execute(&env_name, None).unwrap();
+
+    assert_eq!(
+        std::fs::read_to_string(calls_file).unwrap(),
+        "refresh\ndestroy\n"
+    );
+}
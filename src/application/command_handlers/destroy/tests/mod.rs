@@ -4,3 +4,5 @@
 
 pub mod builders;
 pub mod integration;
+#[cfg(unix)] // The fake `tofu` is a shell script made executable with Unix permissions
+pub mod missing_resources;
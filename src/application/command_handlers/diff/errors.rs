@@ -0,0 +1,132 @@
+//! Error types for diff command handler
+
+use crate::application::command_handlers::create::config::{ConfigLoadError, CreateConfigError};
+use crate::application::errors::PersistenceError;
+use crate::shared::error::kind::ErrorKind;
+use crate::shared::error::traceable::Traceable;
+
+/// Comprehensive error type for the `DiffCommandHandler`
+#[derive(Debug, thiserror::Error)]
+pub enum DiffCommandHandlerError {
+    #[error("Environment not found: '{name}'")]
+    EnvironmentNotFound { name: String },
+
+    #[error("Configuration file is for environment '{config_name}', not '{name}'")]
+    EnvironmentNameMismatch { name: String, config_name: String },
+
+    #[error("Failed to load configuration file: {0}")]
+    ConfigLoad(#[from] ConfigLoadError),
+
+    #[error("Invalid configuration: {0}")]
+    InvalidConfiguration(#[source] CreateConfigError),
+
+    #[error("Failed to load environment: {0}")]
+    LoadError(#[from] PersistenceError),
+}
+
+impl From<crate::domain::environment::repository::RepositoryError> for DiffCommandHandlerError {
+    fn from(e: crate::domain::environment::repository::RepositoryError) -> Self {
+        Self::LoadError(e.into())
+    }
+}
+
+impl Traceable for DiffCommandHandlerError {
+    fn trace_format(&self) -> String {
+        match self {
+            Self::EnvironmentNotFound { name } => {
+                format!("DiffCommandHandlerError: Environment not found - '{name}'")
+            }
+            Self::EnvironmentNameMismatch { name, config_name } => {
+                format!(
+                    "DiffCommandHandlerError: Environment name mismatch - '{config_name}' in the file, '{name}' expected"
+                )
+            }
+            Self::ConfigLoad(e) => {
+                format!("DiffCommandHandlerError: Failed to load configuration file - {e}")
+            }
+            Self::InvalidConfiguration(e) => {
+                format!("DiffCommandHandlerError: Invalid configuration - {e}")
+            }
+            Self::LoadError(e) => {
+                format!("DiffCommandHandlerError: Failed to load environment - {e}")
+            }
+        }
+    }
+
+    fn trace_source(&self) -> Option<&dyn Traceable> {
+        None
+    }
+
+    fn error_kind(&self) -> ErrorKind {
+        match self {
+            Self::EnvironmentNotFound { .. }
+            | Self::EnvironmentNameMismatch { .. }
+            | Self::InvalidConfiguration(_) => ErrorKind::Configuration,
+            Self::ConfigLoad(ConfigLoadError::FileReadFailed { .. }) => ErrorKind::FileSystem,
+            Self::ConfigLoad(_) => ErrorKind::Configuration,
+            Self::LoadError(_) => ErrorKind::StatePersistence,
+        }
+    }
+}
+
+impl DiffCommandHandlerError {
+    /// Provides detailed troubleshooting guidance for this error
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use torrust_tracker_deployer_lib::application::command_handlers::diff::DiffCommandHandlerError;
+    ///
+    /// let error = DiffCommandHandlerError::EnvironmentNameMismatch {
+    ///     name: "my-env".to_string(),
+    ///     config_name: "other-env".to_string(),
+    /// };
+    ///
+    /// assert!(error.help().contains("environment.name"));
+    /// ```
+    #[must_use]
+    pub fn help(&self) -> &'static str {
+        match self {
+            Self::EnvironmentNotFound { .. } => {
+                "Environment Not Found - Troubleshooting:
+
+1. Verify the environment name is correct
+2. List the existing environments:
+   torrust-tracker-deployer list
+
+For more information, see docs/user-guide/commands/diff.md"
+            }
+            Self::EnvironmentNameMismatch { .. } => {
+                "Environment Name Mismatch - Troubleshooting:
+
+The file is compared with the environment named in its environment.name
+field, which must be the environment given on the command line.
+
+1. Check the environment.name field of the configuration file
+2. Pass the environment the file was written for, e.g.:
+   torrust-tracker-deployer diff <name> envs/<name>.json
+
+For more information, see docs/user-guide/commands/diff.md"
+            }
+            Self::ConfigLoad(_) => {
+                "Failed to Load Configuration File - Troubleshooting:
+
+1. Check that the file exists and is readable
+2. Check that the file is valid JSON:
+   torrust-tracker-deployer validate --env-file <file>
+
+For more information, see docs/user-guide/commands/diff.md"
+            }
+            Self::InvalidConfiguration(e) => e.help(),
+            Self::LoadError(_) => {
+                "Failed to Load Environment - Troubleshooting:
+
+1. Check file permissions on the data/ directory
+2. Verify the environment state file is not corrupted:
+   cat data/<name>/environment.json
+
+For more information, see docs/user-guide/commands/diff.md"
+            }
+        }
+    }
+}
@@ -0,0 +1,199 @@
+//! Diff command handler implementation
+//!
+//! **Purpose**: Compare the persisted configuration of an environment with a
+//! configuration file
+//!
+//! This handler loads the environment and the file, validates the file the
+//! way `create` does, and compares the resulting user inputs with the
+//! persisted ones.
+
+use std::convert::TryInto;
+use std::path::Path;
+use std::sync::Arc;
+
+use tracing::instrument;
+
+use super::errors::DiffCommandHandlerError;
+use crate::application::command_handlers::create::config::EnvironmentCreationConfig;
+use crate::domain::environment::repository::EnvironmentRepository;
+use crate::domain::environment::{ConfigDiff, EnvironmentParams, UserInputs, WorkspaceLayout};
+use crate::domain::EnvironmentName;
+
+/// `DiffCommandHandler` compares an environment with a configuration file
+///
+/// **Purpose**: Tell which settings a configuration file changes and what
+/// has to be redeployed for them to take effect
+///
+/// This handler never modifies state or makes network calls.
+pub struct DiffCommandHandler {
+    repository: Arc<dyn EnvironmentRepository + Send + Sync>,
+    layout: WorkspaceLayout,
+}
+
+impl DiffCommandHandler {
+    /// Create a new `DiffCommandHandler`
+    ///
+    /// The workspace layout locates the generated SSH keys of the
+    /// environment, for configuration files that generate them.
+    #[must_use]
+    pub fn new(
+        repository: Arc<dyn EnvironmentRepository + Send + Sync>,
+        layout: WorkspaceLayout,
+    ) -> Self {
+        Self { repository, layout }
+    }
+
+    /// Compare the persisted configuration of an environment with a file
+    ///
+    /// # Arguments
+    ///
+    /// * `env_name` - The name of the environment
+    /// * `config_path` - Path to the updated configuration file
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if:
+    /// * Environment not found
+    /// * Environment state file is corrupted or unreadable
+    /// * The configuration file cannot be read or parsed
+    /// * The configuration file is for another environment
+    /// * The configuration is invalid
+    #[instrument(
+        name = "command.diff",
+        skip_all,
+        fields(
+            command_type = "diff",
+            environment_name = %env_name,
+            config_path = %config_path.display()
+        )
+    )]
+    pub fn execute(
+        &self,
+        env_name: &EnvironmentName,
+        config_path: &Path,
+    ) -> Result<ConfigDiff, DiffCommandHandlerError> {
+        let any_env = self.repository.load(env_name)?.ok_or_else(|| {
+            DiffCommandHandlerError::EnvironmentNotFound {
+                name: env_name.to_string(),
+            }
+        })?;
+
+        let config = EnvironmentCreationConfig::from_file(config_path)?;
+
+        if config.environment.name != env_name.as_str() {
+            return Err(DiffCommandHandlerError::EnvironmentNameMismatch {
+                name: env_name.to_string(),
+                config_name: config.environment.name,
+            });
+        }
+
+        let params: EnvironmentParams = config
+            .with_generated_ssh_key_paths(&self.layout)
+            .and_then(TryInto::try_into)
+            .map_err(DiffCommandHandlerError::InvalidConfiguration)?;
+        let new_inputs = UserInputs::from_params(params)
+            .map_err(|e| DiffCommandHandlerError::InvalidConfiguration(e.into()))?;
+
+        Ok(any_env.user_inputs().diff(&new_inputs))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::path::PathBuf;
+
+    use super::*;
+    use crate::application::command_handlers::create::CreateCommandHandler;
+    use crate::domain::environment::ChangeImpact;
+    use crate::infrastructure::persistence::file_repository_factory::FileRepositoryFactory;
+    use crate::shared::SystemClock;
+
+    /// Configuration of `my-env` with the given API token, as written by a user
+    fn config_json(admin_token: &str) -> String {
+        let fixtures = PathBuf::from(env!("CARGO_MANIFEST_DIR")).join("fixtures");
+        serde_json::json!({
+            "environment": { "name": "my-env" },
+            "ssh_credentials": {
+                "private_key_path": fixtures.join("testing_rsa"),
+                "public_key_path": fixtures.join("testing_rsa.pub"),
+                "username": "torrust"
+            },
+            "provider": { "provider": "lxd", "profile_name": "torrust-profile-my-env" },
+            "tracker": {
+                "core": {
+                    "database": { "driver": "sqlite3", "database_name": "tracker.db" },
+                    "private": false
+                },
+                "udp_trackers": [{ "bind_address": "0.0.0.0:6969" }],
+                "http_trackers": [{ "bind_address": "0.0.0.0:7070" }],
+                "http_api": {
+                    "bind_address": "0.0.0.0:1212",
+                    "admin_token": admin_token
+                },
+                "health_check_api": { "bind_address": "127.0.0.1:1313" }
+            }
+        })
+        .to_string()
+    }
+
+    /// Handler in a workspace holding `my-env`, created with the token `old-token`
+    fn setup() -> (DiffCommandHandler, tempfile::TempDir) {
+        let temp_dir = tempfile::TempDir::new().unwrap();
+        let layout = WorkspaceLayout::in_working_dir(temp_dir.path());
+        let repository = FileRepositoryFactory::new(std::time::Duration::from_secs(30))
+            .create(layout.data_dir().to_path_buf());
+
+        let config = EnvironmentCreationConfig::from_json(&config_json("old-token")).unwrap();
+        CreateCommandHandler::new(repository.clone(), Arc::new(SystemClock))
+            .execute_in_layout(config, &layout)
+            .unwrap();
+
+        (DiffCommandHandler::new(repository, layout), temp_dir)
+    }
+
+    fn env_name() -> EnvironmentName {
+        EnvironmentName::new("my-env".to_string()).unwrap()
+    }
+
+    #[test]
+    fn it_should_only_require_a_release_when_the_api_token_changes() {
+        let (handler, temp_dir) = setup();
+        let config_path = temp_dir.path().join("new.json");
+        std::fs::write(&config_path, config_json("new-token")).unwrap();
+
+        let diff = handler.execute(&env_name(), &config_path).unwrap();
+
+        let paths: Vec<&str> = diff.changes().iter().map(|c| c.path.as_str()).collect();
+        assert_eq!(paths, vec!["tracker.http_api.admin_token"]);
+        assert_eq!(diff.impact(), Some(ChangeImpact::RequiresRelease));
+    }
+
+    #[test]
+    fn it_should_find_no_change_in_the_original_file() {
+        let (handler, temp_dir) = setup();
+        let config_path = temp_dir.path().join("same.json");
+        std::fs::write(&config_path, config_json("old-token")).unwrap();
+
+        let diff = handler.execute(&env_name(), &config_path).unwrap();
+
+        assert!(diff.is_empty());
+    }
+
+    #[test]
+    fn it_should_refuse_a_file_for_another_environment() {
+        let (handler, temp_dir) = setup();
+        let config_path = temp_dir.path().join("other.json");
+        std::fs::write(
+            &config_path,
+            config_json("old-token").replace("\"my-env\"", "\"other-env\""),
+        )
+        .unwrap();
+
+        let result = handler.execute(&env_name(), &config_path);
+
+        assert!(matches!(
+            result,
+            Err(DiffCommandHandlerError::EnvironmentNameMismatch { .. })
+        ));
+    }
+}
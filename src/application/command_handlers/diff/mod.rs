@@ -0,0 +1,25 @@
+//! Diff Command Module
+//!
+//! This module implements the delivery-agnostic `DiffCommandHandler`
+//! for comparing the persisted configuration of an environment with an
+//! updated configuration file.
+//!
+//! ## Impact of a Change
+//!
+//! The comparison itself lives in the domain (`UserInputs::diff`). Each
+//! changed setting is categorized by the earliest deployment command that has
+//! to run again for it to take effect: provision, configure or release. Some
+//! settings, like labels, need no redeploy at all.
+//!
+//! ## Design Principles
+//!
+//! - **Read-Only Operation**: Never modifies environment state
+//! - **No Network Calls**: Reads local data only
+//! - **Same Validation as Create**: The file must be a valid configuration for the environment
+
+pub mod errors;
+pub mod handler;
+
+// Re-export main types for convenience
+pub use errors::DiffCommandHandlerError;
+pub use handler::DiffCommandHandler;
@@ -0,0 +1,792 @@
+//! Doctor command handler implementation
+//!
+//! **Purpose**: Diagnose the local toolchain and workspace in one run
+//!
+//! The handler gathers everything needed to triage an environment-setup
+//! problem: the external tools and their versions, the LXD daemon and the
+//! permissions to use it, the LXD remotes environments are created on, the
+//! libvirt daemon when an environment uses it, the free disk space and directory permissions of
+//! the workspace, and the environments left in an error state.
+//!
+//! ## Disk Space
+//!
+//! The free space under the workspace and in the LXD storage pools is
+//! checked against the same thresholds as the `provision` preflight: below
+//! them provisioning refuses to start, so the check fails.
+//!
+//! ## Severity
+//!
+//! A check fails only when deployments are expected to fail until it is
+//! fixed. `OpenTofu` and Ansible are always required. LXD and Docker are only
+//! required when an environment of the workspace uses that provider;
+//! otherwise their problems are reported as warnings.
+//!
+//! The handler never fails: a check that cannot run is reported with the
+//! reason, so the report is complete even on a badly broken host.
+
+use std::path::{Path, PathBuf};
+use std::sync::Arc;
+use std::time::Duration;
+
+use torrust_tracker_deployer_dependency_installer::{
+    Dependency, DependencyManager, DependencyStatus, DetectionError,
+};
+use tracing::{info, instrument};
+
+use super::info::{CheckCategory, CheckStatus, DoctorCheck, DoctorReport, FailedEnvironment};
+use crate::adapters::libvirt::VirshClient;
+use crate::adapters::lxd::LxdClient;
+use crate::application::command_handlers::init::{verify_workspace, WorkspaceError};
+use crate::application::steps::validation::disk_space::{format_gib, LXD_STORAGE_POOL};
+use crate::application::steps::CheckLxdStoragePoolSpaceStep;
+use crate::domain::environment::repository::EnvironmentRepository;
+use crate::domain::environment::{
+    DiskSpaceThresholds, RepositoryBackend, WorkspaceLayout, WorkspaceMarker,
+};
+use crate::domain::provider::{LxdRemote, Provider, LIBVIRT_URI};
+use crate::infrastructure::host::{
+    check_writable, current_user, disk_space, DiskSpace, HostProbeError, UserAccount,
+};
+
+/// Free disk space below which a warning is reported, when above the threshold
+const LOW_FREE_DISK_SPACE: u64 = 5 * 1024 * 1024 * 1024;
+
+/// Maximum time to wait for the LXD daemon to answer
+const LXD_QUERY_TIMEOUT: Duration = Duration::from_secs(10);
+
+/// Maximum time to wait for the libvirt daemon to answer
+const LIBVIRT_QUERY_TIMEOUT: Duration = Duration::from_secs(10);
+
+/// Command installing the external tools
+const INSTALL_HINT: &str = "cargo run --bin dependency-installer install";
+
+/// `DoctorCommandHandler` diagnoses the local toolchain and workspace
+pub struct DoctorCommandHandler {
+    repository: Arc<dyn EnvironmentRepository + Send + Sync>,
+    working_dir: PathBuf,
+    disk_space_thresholds: DiskSpaceThresholds,
+}
+
+impl DoctorCommandHandler {
+    /// Create a new `DoctorCommandHandler`
+    #[must_use]
+    pub fn new(
+        repository: Arc<dyn EnvironmentRepository + Send + Sync>,
+        working_dir: PathBuf,
+    ) -> Self {
+        Self {
+            repository,
+            working_dir,
+            disk_space_thresholds: DiskSpaceThresholds::default(),
+        }
+    }
+
+    /// Check the free disk space against the given thresholds
+    #[must_use]
+    pub fn with_disk_space_thresholds(mut self, thresholds: DiskSpaceThresholds) -> Self {
+        self.disk_space_thresholds = thresholds;
+        self
+    }
+
+    /// Execute the doctor command workflow
+    ///
+    /// Runs every check and returns the report; see the module documentation
+    /// for which checks are critical.
+    #[instrument(
+        name = "command.doctor",
+        skip_all,
+        fields(command_type = "doctor", working_dir = %self.working_dir.display())
+    )]
+    pub fn execute(&self) -> DoctorReport {
+        let layout = WorkspaceLayout::in_working_dir(&self.working_dir);
+
+        // Environments first: their providers decide which tools are required
+        let EnvironmentsSummary {
+            check: environments_check,
+            failed: failed_environments,
+            providers,
+            lxd_remotes,
+        } = self.check_environments();
+        let lxd_required = providers.contains(&Provider::Lxd);
+        let docker_required = providers.contains(&Provider::Docker);
+        let libvirt_required = providers.contains(&Provider::Libvirt);
+
+        let manager = DependencyManager::new();
+        let detect = |dependency| manager.get_detector(dependency).status();
+        let lxd_status = detect(Dependency::Lxd);
+        let lxd_installed = lxd_status
+            .as_ref()
+            .is_ok_and(DependencyStatus::is_installed);
+
+        let mut checks = vec![
+            dependency_check(Dependency::OpenTofu, detect(Dependency::OpenTofu), None),
+            dependency_check(Dependency::Ansible, detect(Dependency::Ansible), None),
+            dependency_check(
+                Dependency::Lxd,
+                lxd_status,
+                (!lxd_required).then_some("the LXD provider"),
+            ),
+            dependency_check(
+                Dependency::Docker,
+                detect(Dependency::Docker),
+                (!docker_required).then_some("the Docker provider"),
+            ),
+        ];
+
+        if lxd_installed {
+            checks.push(lxd_daemon_check(
+                LxdClient::new().server_version(LXD_QUERY_TIMEOUT),
+                lxd_required,
+            ));
+            checks.push(lxd_group_check(current_user(), lxd_required));
+
+            let required = self.disk_space_thresholds.lxd_storage_pool();
+            let local_pool = CheckLxdStoragePoolSpaceStep::new(None, required);
+            checks.push(lxd_storage_pool_check(
+                &local_pool.location(),
+                local_pool
+                    .client()
+                    .storage_pool_free_space(LXD_STORAGE_POOL, LXD_QUERY_TIMEOUT),
+                required,
+                lxd_required,
+            ));
+
+            for remote in &lxd_remotes {
+                let pool = CheckLxdStoragePoolSpaceStep::new(Some(remote.clone()), required);
+                let client = pool.client();
+                checks.push(lxd_remote_check(
+                    remote.name(),
+                    client.server_version(LXD_QUERY_TIMEOUT),
+                ));
+                checks.push(lxd_storage_pool_check(
+                    &pool.location(),
+                    client.storage_pool_free_space(LXD_STORAGE_POOL, LXD_QUERY_TIMEOUT),
+                    required,
+                    true,
+                ));
+            }
+        }
+
+        // virsh is not one of the installed dependencies: only checked when needed
+        if libvirt_required {
+            checks.push(libvirt_daemon_check(
+                VirshClient::new(LIBVIRT_URI).server_version(LIBVIRT_QUERY_TIMEOUT),
+            ));
+        }
+
+        checks.push(workspace_marker_check(verify_workspace(
+            &self.working_dir,
+            &layout,
+        )));
+        let existing_dir = nearest_existing_dir(&self.working_dir);
+        checks.push(disk_space_check(
+            &existing_dir,
+            disk_space(&existing_dir),
+            self.disk_space_thresholds.workspace(),
+        ));
+        checks.push(directory_check("data directory", layout.data_dir()));
+        checks.push(directory_check("build directory", layout.build_dir()));
+        checks.push(environments_check);
+
+        let report = DoctorReport {
+            deployer_version: env!("CARGO_PKG_VERSION").to_string(),
+            platform: format!("{} {}", std::env::consts::OS, std::env::consts::ARCH),
+            working_dir: self.working_dir.display().to_string(),
+            checks,
+            failed_environments,
+        };
+
+        info!(
+            failed = report.count(CheckStatus::Failed),
+            warnings = report.count(CheckStatus::Warning),
+            "Doctor checks completed"
+        );
+
+        report
+    }
+
+    /// Summarize the environments of the workspace
+    ///
+    /// Returns the check, the environments needing attention, and the
+    /// providers and LXD remotes used by the readable environments.
+    fn check_environments(&self) -> EnvironmentsSummary {
+        let names = match self.repository.list() {
+            Ok(names) => names,
+            Err(e) => {
+                let check = DoctorCheck::new(
+                    CheckCategory::Environments,
+                    "environments",
+                    CheckStatus::Warning,
+                    format!("cannot list the environments: {e}"),
+                );
+                return EnvironmentsSummary {
+                    check,
+                    failed: vec![],
+                    providers: vec![],
+                    lxd_remotes: vec![],
+                };
+            }
+        };
+
+        let mut failed = Vec::new();
+        let mut providers = Vec::new();
+        let mut lxd_remotes: Vec<LxdRemote> = Vec::new();
+
+        for name in &names {
+            match self.repository.load(name) {
+                Ok(Some(environment)) => {
+                    providers.push(environment.user_inputs().provider());
+                    if let Some(remote) = environment
+                        .user_inputs()
+                        .provider_config()
+                        .as_lxd()
+                        .and_then(|lxd| lxd.remote.as_ref())
+                    {
+                        if !lxd_remotes
+                            .iter()
+                            .any(|known| known.name() == remote.name())
+                        {
+                            lxd_remotes.push(remote.clone());
+                        }
+                    }
+                    if environment.is_error_state() {
+                        failed.push(FailedEnvironment {
+                            name: name.to_string(),
+                            state: environment.state_name().to_string(),
+                            error: environment.error_details().unwrap_or_default().to_string(),
+                        });
+                    }
+                }
+                Ok(None) => {}
+                Err(e) => failed.push(FailedEnvironment {
+                    name: name.to_string(),
+                    state: "unreadable".to_string(),
+                    error: e.to_string(),
+                }),
+            }
+        }
+
+        let check = environments_summary(names.len(), &failed);
+        EnvironmentsSummary {
+            check,
+            failed,
+            providers,
+            lxd_remotes,
+        }
+    }
+}
+
+/// What the doctor learns from the environments of the workspace
+struct EnvironmentsSummary {
+    check: DoctorCheck,
+    failed: Vec<FailedEnvironment>,
+    providers: Vec<Provider>,
+    lxd_remotes: Vec<LxdRemote>,
+}
+
+/// Status of a problem, depending on whether the checked item is required
+fn severity(required: bool) -> CheckStatus {
+    if required {
+        CheckStatus::Failed
+    } else {
+        CheckStatus::Warning
+    }
+}
+
+/// Check an external tool
+///
+/// `optional_for` names what needs the tool when it is not required; a
+/// missing optional tool is fine.
+fn dependency_check(
+    dependency: Dependency,
+    status: Result<DependencyStatus, DetectionError>,
+    optional_for: Option<&str>,
+) -> DoctorCheck {
+    let required = optional_for.is_none();
+
+    let (status, detail) = match status {
+        Ok(DependencyStatus::Installed {
+            version: Some(version),
+        }) => (CheckStatus::Ok, version.to_string()),
+        Ok(DependencyStatus::Installed { version: None }) => (
+            CheckStatus::Ok,
+            "installed, version unknown".to_string(),
+        ),
+        Ok(DependencyStatus::Outdated { found, required: minimum }) => (
+            severity(required),
+            format!("{found} is older than the minimum supported {minimum}; upgrade with: {INSTALL_HINT}"),
+        ),
+        Ok(DependencyStatus::NotInstalled) => match optional_for {
+            Some(purpose) => (
+                CheckStatus::Ok,
+                format!("not installed (only needed for {purpose})"),
+            ),
+            None => (
+                CheckStatus::Failed,
+                format!("not installed; install with: {INSTALL_HINT}"),
+            ),
+        },
+        Err(e) => (severity(required), format!("detection failed: {e}")),
+    };
+
+    DoctorCheck::new(
+        CheckCategory::Dependencies,
+        dependency.canonical_name(),
+        status,
+        detail,
+    )
+}
+
+/// Check that the LXD daemon answers
+fn lxd_daemon_check(server_version: anyhow::Result<String>, required: bool) -> DoctorCheck {
+    let (status, detail) = match server_version {
+        Ok(version) => (
+            CheckStatus::Ok,
+            format!("reachable, server version {version}"),
+        ),
+        Err(e) => (
+            severity(required),
+            format!(
+                "not reachable: {e:#}; check that the daemon runs (sudo snap services lxd) and that it was initialized (sudo lxd init --auto)"
+            ),
+        ),
+    };
+
+    DoctorCheck::new(CheckCategory::Lxd, "daemon", status, detail)
+}
+
+/// Check that the system libvirt daemon an environment is created on answers
+fn libvirt_daemon_check(server_version: anyhow::Result<String>) -> DoctorCheck {
+    let (status, detail) = match server_version {
+        Ok(version) => (
+            CheckStatus::Ok,
+            format!("reachable at {LIBVIRT_URI}, library version {version}"),
+        ),
+        Err(e) => (
+            CheckStatus::Failed,
+            format!(
+                "not reachable at {LIBVIRT_URI}: {e:#}; check that the daemon runs (sudo systemctl enable --now libvirtd, or virtqemud on Fedora) and that the user is in the libvirt group"
+            ),
+        ),
+    };
+
+    DoctorCheck::new(CheckCategory::Libvirt, "daemon", status, detail)
+}
+
+/// Check that an LXD remote an environment is created on answers and trusts this machine
+fn lxd_remote_check(name: &str, server_version: anyhow::Result<String>) -> DoctorCheck {
+    let (status, detail) = match server_version {
+        Ok(version) => (
+            CheckStatus::Ok,
+            format!("reachable and trusted, server version {version}"),
+        ),
+        Err(e) => (
+            CheckStatus::Failed,
+            format!(
+                "not usable: {e:#}; check it is listed by 'lxc remote list' and that 'lxc query {name}:/1.0' succeeds"
+            ),
+        ),
+    };
+
+    DoctorCheck::new(
+        CheckCategory::Lxd,
+        format!("remote '{name}'"),
+        status,
+        detail,
+    )
+}
+
+/// Check that the current session may use LXD
+fn lxd_group_check(user: Result<UserAccount, HostProbeError>, required: bool) -> DoctorCheck {
+    let (status, detail) = match user {
+        Ok(user) if user.is_root() => (CheckStatus::Ok, "running as root".to_string()),
+        Ok(user) if user.in_group("lxd") => (
+            CheckStatus::Ok,
+            format!("user '{}' is in the lxd group", user.name),
+        ),
+        Ok(user) => (
+            severity(required),
+            format!(
+                "user '{name}' is not in the lxd group of this session; run: sudo usermod -aG lxd {name} && newgrp lxd",
+                name = user.name
+            ),
+        ),
+        Err(e) => (CheckStatus::Warning, format!("cannot read the user groups: {e}")),
+    };
+
+    DoctorCheck::new(CheckCategory::Lxd, "group membership", status, detail)
+}
+
+/// Check that the working directory is an initialized workspace
+fn workspace_marker_check(marker: Result<WorkspaceMarker, WorkspaceError>) -> DoctorCheck {
+    let (status, detail) = match marker {
+        Ok(marker) => {
+            let backend = match marker.repository {
+                RepositoryBackend::Json => "JSON files",
+                RepositoryBackend::Sqlite => "SQLite database",
+            };
+            (
+                CheckStatus::Ok,
+                format!(
+                    "initialized (format version {}, {backend} repository)",
+                    marker.format_version
+                ),
+            )
+        }
+        Err(WorkspaceError::NotInitialized { .. }) => (
+            CheckStatus::Warning,
+            "not initialized; run: torrust-tracker-deployer init".to_string(),
+        ),
+        Err(WorkspaceError::LegacyWorkspace {
+            environment_count, ..
+        }) => (
+            CheckStatus::Warning,
+            format!(
+                "{environment_count} environment(s) but no workspace marker; run: torrust-tracker-deployer init"
+            ),
+        ),
+        Err(e @ (WorkspaceError::InvalidMarker { .. } | WorkspaceError::UnsupportedFormat { .. })) => {
+            (CheckStatus::Failed, e.to_string())
+        }
+    };
+
+    DoctorCheck::new(CheckCategory::Workspace, "initialized", status, detail)
+}
+
+/// Status of a free space, failed below the threshold the preflights enforce
+fn free_space_status(available: u64, required: u64) -> CheckStatus {
+    if available < required {
+        CheckStatus::Failed
+    } else if available < LOW_FREE_DISK_SPACE {
+        CheckStatus::Warning
+    } else {
+        CheckStatus::Ok
+    }
+}
+
+/// Check the free disk space under the workspace
+fn disk_space_check(
+    path: &Path,
+    space: Result<DiskSpace, HostProbeError>,
+    required: u64,
+) -> DoctorCheck {
+    let (status, detail) = match space {
+        Ok(space) => (
+            free_space_status(space.available, required),
+            format!(
+                "{} free on partition '{}' holding {} ({} required)",
+                format_gib(space.available),
+                space.mount_point,
+                path.display(),
+                format_gib(required)
+            ),
+        ),
+        Err(e) => (CheckStatus::Warning, format!("cannot measure: {e}")),
+    };
+
+    DoctorCheck::new(CheckCategory::Workspace, "disk space", status, detail)
+}
+
+/// Check the free space of an LXD storage pool instances are created in
+fn lxd_storage_pool_check(
+    location: &str,
+    free: anyhow::Result<u64>,
+    required: u64,
+    lxd_required: bool,
+) -> DoctorCheck {
+    let (status, detail) = match free {
+        Ok(available) => (
+            free_space_status(available, required),
+            format!(
+                "{} free ({} required)",
+                format_gib(available),
+                format_gib(required)
+            ),
+        ),
+        Err(e) => (
+            severity(lxd_required),
+            format!("cannot measure: {e:#}; check it exists with 'lxc storage list'"),
+        ),
+    };
+
+    DoctorCheck::new(CheckCategory::Lxd, location, status, detail)
+}
+
+/// Check that files can be written in a workspace directory
+///
+/// A directory that does not exist yet is fine when it can be created.
+fn directory_check(name: &str, dir: &Path) -> DoctorCheck {
+    let existing_dir = nearest_existing_dir(dir);
+
+    let (status, detail) = match check_writable(&existing_dir) {
+        Ok(()) if existing_dir == dir => {
+            (CheckStatus::Ok, format!("{} is writable", dir.display()))
+        }
+        Ok(()) => (
+            CheckStatus::Ok,
+            format!("{} does not exist yet and can be created", dir.display()),
+        ),
+        Err(e) => (
+            CheckStatus::Failed,
+            format!(
+                "{e}; fix the ownership with: sudo chown -R $USER {}",
+                existing_dir.display()
+            ),
+        ),
+    };
+
+    DoctorCheck::new(CheckCategory::Workspace, name, status, detail)
+}
+
+/// Summarize the environments needing attention
+fn environments_summary(total: usize, failed: &[FailedEnvironment]) -> DoctorCheck {
+    let unreadable = failed
+        .iter()
+        .filter(|environment| environment.state == "unreadable")
+        .count();
+    let in_error = failed.len() - unreadable;
+
+    let mut detail = format!("{total} environment(s), {in_error} in an error state");
+    if unreadable > 0 {
+        detail.push_str(&format!(", {unreadable} unreadable"));
+    }
+
+    let status = if failed.is_empty() {
+        CheckStatus::Ok
+    } else {
+        CheckStatus::Warning
+    };
+
+    DoctorCheck::new(CheckCategory::Environments, "environments", status, detail)
+}
+
+/// The directory itself if it exists, or its closest existing ancestor
+fn nearest_existing_dir(dir: &Path) -> PathBuf {
+    dir.ancestors()
+        .find(|ancestor| ancestor.is_dir())
+        .unwrap_or_else(|| Path::new("."))
+        .to_path_buf()
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration as StdDuration;
+
+    use chrono::Utc;
+    use tempfile::TempDir;
+    use torrust_tracker_deployer_dependency_installer::Version;
+
+    use super::*;
+    use crate::domain::environment::state::{
+        AnyEnvironmentState, BaseFailureContext, ProvisionFailureContext, ProvisionStep,
+    };
+    use crate::domain::environment::testing::EnvironmentTestBuilder;
+    use crate::domain::environment::TraceId;
+    use crate::infrastructure::persistence::filesystem::file_environment_repository::FileEnvironmentRepository;
+    use crate::shared::ErrorKind;
+
+    fn version(text: &str) -> Version {
+        Version::find_in(text).unwrap()
+    }
+
+    #[test]
+    fn it_should_fail_when_a_required_tool_is_missing() {
+        let check = dependency_check(
+            Dependency::OpenTofu,
+            Ok(DependencyStatus::NotInstalled),
+            None,
+        );
+
+        assert_eq!(check.status, CheckStatus::Failed);
+        assert!(check.detail.contains(INSTALL_HINT));
+    }
+
+    #[test]
+    fn it_should_accept_a_missing_tool_no_environment_needs() {
+        let check = dependency_check(
+            Dependency::Lxd,
+            Ok(DependencyStatus::NotInstalled),
+            Some("the LXD provider"),
+        );
+
+        assert_eq!(check.status, CheckStatus::Ok);
+        assert_eq!(
+            check.detail,
+            "not installed (only needed for the LXD provider)"
+        );
+    }
+
+    #[test]
+    fn it_should_report_the_versions_of_an_outdated_tool() {
+        let check = dependency_check(
+            Dependency::Ansible,
+            Ok(DependencyStatus::Outdated {
+                found: version("2.9.0"),
+                required: version("2.15.0"),
+            }),
+            None,
+        );
+
+        assert_eq!(check.status, CheckStatus::Failed);
+        assert!(check
+            .detail
+            .starts_with("2.9.0 is older than the minimum supported 2.15.0"));
+    }
+
+    #[test]
+    fn it_should_only_warn_about_an_unreachable_daemon_no_environment_needs() {
+        let error = || Err(anyhow::anyhow!("connection refused"));
+
+        assert_eq!(
+            lxd_daemon_check(error(), false).status,
+            CheckStatus::Warning
+        );
+        assert_eq!(lxd_daemon_check(error(), true).status, CheckStatus::Failed);
+    }
+
+    #[test]
+    fn it_should_fail_when_the_libvirt_daemon_an_environment_needs_is_unreachable() {
+        let check = libvirt_daemon_check(Err(anyhow::anyhow!("failed to connect")));
+
+        assert_eq!(check.category, CheckCategory::Libvirt);
+        assert_eq!(check.status, CheckStatus::Failed);
+        assert!(check.detail.contains("qemu:///system"));
+    }
+
+    #[test]
+    fn it_should_explain_how_to_join_the_lxd_group() {
+        let user = UserAccount {
+            name: "torrust".to_string(),
+            groups: vec!["torrust".to_string()],
+        };
+
+        let check = lxd_group_check(Ok(user), true);
+
+        assert_eq!(check.status, CheckStatus::Failed);
+        assert!(check.detail.contains("sudo usermod -aG lxd torrust"));
+    }
+
+    #[test]
+    fn it_should_grade_the_free_disk_space() {
+        let required = DiskSpaceThresholds::default().workspace();
+        let status = |bytes| {
+            let space = DiskSpace {
+                mount_point: "/".to_string(),
+                available: bytes,
+            };
+            disk_space_check(Path::new("/"), Ok(space), required).status
+        };
+
+        assert_eq!(status(512 * 1024 * 1024), CheckStatus::Failed);
+        assert_eq!(status(required), CheckStatus::Warning);
+        assert_eq!(status(LOW_FREE_DISK_SPACE), CheckStatus::Ok);
+    }
+
+    #[test]
+    fn it_should_fail_when_an_lxd_storage_pool_is_below_the_provision_threshold() {
+        let required = DiskSpaceThresholds::default().lxd_storage_pool();
+
+        let check = lxd_storage_pool_check(
+            "LXD storage pool 'default'",
+            Ok(required / 2),
+            required,
+            true,
+        );
+
+        assert_eq!(check.name, "LXD storage pool 'default'");
+        assert_eq!(check.status, CheckStatus::Failed);
+        assert_eq!(check.detail, "2.5 GiB free (5.0 GiB required)");
+    }
+
+    #[test]
+    fn it_should_accept_a_directory_that_can_be_created() {
+        let temp_dir = TempDir::new().unwrap();
+
+        let check = directory_check("data directory", &temp_dir.path().join("data"));
+
+        assert_eq!(check.status, CheckStatus::Ok);
+        assert!(check
+            .detail
+            .ends_with("does not exist yet and can be created"));
+    }
+
+    #[test]
+    fn it_should_warn_about_an_uninitialized_workspace() {
+        let check = workspace_marker_check(Err(WorkspaceError::NotInitialized {
+            path: PathBuf::from("/workspace"),
+        }));
+
+        assert_eq!(check.status, CheckStatus::Warning);
+        assert!(check.detail.contains("torrust-tracker-deployer init"));
+    }
+
+    #[test]
+    fn it_should_report_the_environments_in_an_error_state() {
+        let temp_dir = TempDir::new().unwrap();
+        let repository = Arc::new(FileEnvironmentRepository::new(temp_dir.path().join("data")));
+        let (healthy, _data_dir, _build_dir, _healthy_temp) = EnvironmentTestBuilder::new()
+            .with_name("healthy-env")
+            .build_with_custom_paths();
+        let (failing, _data_dir, _build_dir, _failing_temp) = EnvironmentTestBuilder::new()
+            .with_name("failed-env")
+            .build_with_custom_paths();
+        let now = Utc::now();
+        let context = ProvisionFailureContext {
+            failed_step: ProvisionStep::OpenTofuApply,
+            error_kind: ErrorKind::InfrastructureOperation,
+            base: BaseFailureContext {
+                error_summary: "tofu apply failed".to_string(),
+                failed_at: now,
+                execution_started_at: now,
+                execution_duration: StdDuration::from_secs(42),
+                trace_id: TraceId::new(),
+                trace_file_path: None,
+            },
+            remote_log_excerpt: None,
+        };
+        repository
+            .save(&AnyEnvironmentState::Created(healthy))
+            .unwrap();
+        repository
+            .save(
+                &failing
+                    .start_provisioning()
+                    .provision_failed(context)
+                    .into_any(),
+            )
+            .unwrap();
+        let handler = DoctorCommandHandler::new(repository, temp_dir.path().to_path_buf());
+
+        let EnvironmentsSummary {
+            check,
+            failed,
+            providers,
+            lxd_remotes,
+        } = handler.check_environments();
+
+        assert_eq!(check.status, CheckStatus::Warning);
+        assert_eq!(check.detail, "2 environment(s), 1 in an error state");
+        assert_eq!(
+            failed,
+            [FailedEnvironment {
+                name: "failed-env".to_string(),
+                state: "provision_failed".to_string(),
+                error: "tofu apply failed".to_string(),
+            }]
+        );
+        assert_eq!(providers, [Provider::Lxd, Provider::Lxd]);
+        assert!(lxd_remotes.is_empty());
+    }
+
+    #[test]
+    fn it_should_fail_when_an_lxd_remote_is_not_trusted() {
+        let check = lxd_remote_check(
+            "bigbox",
+            Err(anyhow::anyhow!("the LXD server does not trust this client")),
+        );
+
+        assert_eq!(check.name, "remote 'bigbox'");
+        assert_eq!(check.status, CheckStatus::Failed);
+        assert!(check.detail.contains("lxc query bigbox:/1.0"));
+    }
+}
@@ -0,0 +1,186 @@
+//! Data Transfer Objects for the doctor command
+//!
+//! These DTOs describe the result of each check of the local toolchain and
+//! workspace, plus the environments left in an error state.
+
+use std::fmt;
+
+use serde::Serialize;
+
+/// Outcome of a single check
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum CheckStatus {
+    /// Nothing to fix
+    Ok,
+
+    /// Worth knowing, but the deployer can still be used
+    Warning,
+
+    /// A critical problem: deployments are expected to fail until it is fixed
+    Failed,
+}
+
+impl fmt::Display for CheckStatus {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Ok => write!(f, "ok"),
+            Self::Warning => write!(f, "warning"),
+            Self::Failed => write!(f, "failed"),
+        }
+    }
+}
+
+/// Area a check belongs to
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum CheckCategory {
+    /// External tools (`OpenTofu`, Ansible, LXD, Docker)
+    Dependencies,
+
+    /// LXD daemon and permissions to use it
+    Lxd,
+
+    /// Libvirt daemon the libvirt provider creates instances on
+    Libvirt,
+
+    /// Workspace marker, disk space and directory permissions
+    Workspace,
+
+    /// Environments stored in the workspace
+    Environments,
+}
+
+impl fmt::Display for CheckCategory {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Dependencies => write!(f, "dependencies"),
+            Self::Lxd => write!(f, "lxd"),
+            Self::Libvirt => write!(f, "libvirt"),
+            Self::Workspace => write!(f, "workspace"),
+            Self::Environments => write!(f, "environments"),
+        }
+    }
+}
+
+/// Result of a single check
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct DoctorCheck {
+    /// Area the check belongs to
+    pub category: CheckCategory,
+
+    /// What was checked (e.g. `opentofu`, `disk space`)
+    pub name: String,
+
+    /// Outcome of the check
+    pub status: CheckStatus,
+
+    /// What was found, and how to fix it when the check did not pass
+    pub detail: String,
+}
+
+impl DoctorCheck {
+    /// Create a new `DoctorCheck`
+    #[must_use]
+    pub fn new(
+        category: CheckCategory,
+        name: impl Into<String>,
+        status: CheckStatus,
+        detail: impl Into<String>,
+    ) -> Self {
+        Self {
+            category,
+            name: name.into(),
+            status,
+            detail: detail.into(),
+        }
+    }
+}
+
+/// An environment that needs attention
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct FailedEnvironment {
+    /// Name of the environment
+    pub name: String,
+
+    /// State of the environment, or `unreadable` if it could not be loaded
+    pub state: String,
+
+    /// Summary of the failure
+    pub error: String,
+}
+
+/// Result of a doctor run
+#[derive(Debug, Clone, Serialize)]
+pub struct DoctorReport {
+    /// Version of the deployer
+    pub deployer_version: String,
+
+    /// Operating system and architecture of the host (e.g. `linux x86_64`)
+    pub platform: String,
+
+    /// Working directory that was checked
+    pub working_dir: String,
+
+    /// Results of the checks, grouped by category
+    pub checks: Vec<DoctorCheck>,
+
+    /// Environments in an error state or that could not be loaded
+    pub failed_environments: Vec<FailedEnvironment>,
+}
+
+impl DoctorReport {
+    /// Number of checks with the given status
+    #[must_use]
+    pub fn count(&self, status: CheckStatus) -> usize {
+        self.checks
+            .iter()
+            .filter(|check| check.status == status)
+            .count()
+    }
+
+    /// Whether any critical check failed
+    #[must_use]
+    pub fn has_failures(&self) -> bool {
+        self.count(CheckStatus::Failed) > 0
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn report(statuses: &[CheckStatus]) -> DoctorReport {
+        DoctorReport {
+            deployer_version: "0.1.0".to_string(),
+            platform: "linux x86_64".to_string(),
+            working_dir: "/workspace".to_string(),
+            checks: statuses
+                .iter()
+                .map(|&status| DoctorCheck::new(CheckCategory::Workspace, "check", status, ""))
+                .collect(),
+            failed_environments: vec![],
+        }
+    }
+
+    #[test]
+    fn it_should_only_fail_on_failed_checks() {
+        assert!(!report(&[CheckStatus::Ok, CheckStatus::Warning]).has_failures());
+        assert!(report(&[CheckStatus::Ok, CheckStatus::Failed]).has_failures());
+    }
+
+    #[test]
+    fn it_should_serialize_statuses_and_categories_in_snake_case() {
+        let check = DoctorCheck::new(
+            CheckCategory::Dependencies,
+            "opentofu",
+            CheckStatus::Failed,
+            "not installed",
+        );
+
+        let json = serde_json::to_value(&check).unwrap();
+
+        assert_eq!(json["category"], "dependencies");
+        assert_eq!(json["status"], "failed");
+    }
+}
@@ -0,0 +1,22 @@
+//! Doctor Command Module
+//!
+//! This module implements the delivery-agnostic `DoctorCommandHandler`
+//! for diagnosing the local toolchain and workspace.
+//!
+//! ## Checks
+//!
+//! - External tools and their versions (`OpenTofu`, Ansible, LXD, Docker)
+//! - LXD daemon reachability and membership of the `lxd` group
+//! - Workspace marker, free disk space and write permissions on the
+//!   `data/` and `build/` directories
+//! - Environments in an error state or that cannot be loaded
+//!
+//! The report holds what maintainers need to triage an environment-setup
+//! problem, including the deployer version and the platform.
+
+pub mod handler;
+pub mod info;
+
+// Re-export main types for convenience
+pub use handler::DoctorCommandHandler;
+pub use info::{CheckCategory, CheckStatus, DoctorCheck, DoctorReport, FailedEnvironment};
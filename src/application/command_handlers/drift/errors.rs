@@ -0,0 +1,218 @@
+//! Error types for drift command handler
+
+use std::path::PathBuf;
+
+use crate::application::errors::{AllowedCommands, PersistenceError};
+use crate::infrastructure::remote_actions::{ContainerImagesError, RemoteTransferError};
+use crate::shared::error::kind::ErrorKind;
+use crate::shared::error::traceable::Traceable;
+
+/// Comprehensive error type for the `DriftCommandHandler`
+#[derive(Debug, thiserror::Error)]
+pub enum DriftCommandHandlerError {
+    #[error("Environment not found: '{name}'")]
+    EnvironmentNotFound { name: String },
+
+    #[error("Environment '{name}' is in state '{state}', but the tracker must be released or running to detect drift; {allowed_commands}")]
+    InvalidState {
+        name: String,
+        state: String,
+        allowed_commands: AllowedCommands,
+    },
+
+    #[error("Environment '{name}' does not have an instance IP set")]
+    MissingInstanceIp { name: String },
+
+    #[error("Failed to render the expected release artifacts: {reason}")]
+    TemplateRenderingFailed { reason: String },
+
+    #[error("The rendered docker-compose.yml is not valid YAML: {source}")]
+    InvalidComposeFile {
+        #[source]
+        source: serde_yaml::Error,
+    },
+
+    #[error("Failed to download a deployed release artifact: {0}")]
+    Download(#[source] RemoteTransferError),
+
+    #[error("Failed to read the running containers: {0}")]
+    Containers(#[source] ContainerImagesError),
+
+    #[error("Failed to access '{path}': {source}")]
+    LocalFile {
+        path: PathBuf,
+        #[source]
+        source: std::io::Error,
+    },
+
+    #[error("State persistence error: {0}")]
+    StatePersistence(#[from] PersistenceError),
+}
+
+impl From<crate::domain::environment::repository::RepositoryError> for DriftCommandHandlerError {
+    fn from(e: crate::domain::environment::repository::RepositoryError) -> Self {
+        Self::StatePersistence(e.into())
+    }
+}
+
+impl Traceable for DriftCommandHandlerError {
+    fn trace_format(&self) -> String {
+        match self {
+            Self::EnvironmentNotFound { name } => {
+                format!("DriftCommandHandlerError: Environment not found - '{name}'")
+            }
+            Self::InvalidState { name, state, .. } => {
+                format!("DriftCommandHandlerError: Environment '{name}' is not released or running (state: {state})")
+            }
+            Self::MissingInstanceIp { name } => {
+                format!("DriftCommandHandlerError: Missing instance IP for environment '{name}'")
+            }
+            Self::TemplateRenderingFailed { reason } => {
+                format!("DriftCommandHandlerError: Template rendering failed - {reason}")
+            }
+            Self::InvalidComposeFile { source } => {
+                format!("DriftCommandHandlerError: Invalid rendered docker-compose.yml - {source}")
+            }
+            Self::Download(e) => {
+                format!("DriftCommandHandlerError: Failed to download a deployed artifact - {e}")
+            }
+            Self::Containers(e) => {
+                format!("DriftCommandHandlerError: Failed to read the running containers - {e}")
+            }
+            Self::LocalFile { path, source } => {
+                format!(
+                    "DriftCommandHandlerError: Failed to access '{}' - {source}",
+                    path.display()
+                )
+            }
+            Self::StatePersistence(e) => {
+                format!("DriftCommandHandlerError: State persistence error - {e}")
+            }
+        }
+    }
+
+    fn trace_source(&self) -> Option<&dyn Traceable> {
+        match self {
+            Self::Download(e) => Some(e),
+            Self::Containers(e) => Some(e),
+            Self::EnvironmentNotFound { .. }
+            | Self::InvalidState { .. }
+            | Self::MissingInstanceIp { .. }
+            | Self::TemplateRenderingFailed { .. }
+            | Self::InvalidComposeFile { .. }
+            | Self::LocalFile { .. }
+            | Self::StatePersistence(_) => None,
+        }
+    }
+
+    fn error_kind(&self) -> ErrorKind {
+        match self {
+            Self::EnvironmentNotFound { .. }
+            | Self::InvalidState { .. }
+            | Self::MissingInstanceIp { .. } => ErrorKind::Configuration,
+            Self::TemplateRenderingFailed { .. } | Self::InvalidComposeFile { .. } => {
+                ErrorKind::TemplateRendering
+            }
+            Self::Download(e) => e.error_kind(),
+            Self::Containers(e) => e.error_kind(),
+            Self::LocalFile { .. } => ErrorKind::FileSystem,
+            Self::StatePersistence(_) => ErrorKind::StatePersistence,
+        }
+    }
+}
+
+impl DriftCommandHandlerError {
+    /// Provides detailed troubleshooting guidance for this error
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use torrust_tracker_deployer_lib::application::command_handlers::drift::DriftCommandHandlerError;
+    ///
+    /// let error = DriftCommandHandlerError::MissingInstanceIp {
+    ///     name: "my-env".to_string(),
+    /// };
+    ///
+    /// assert!(error.help().contains("show"));
+    /// ```
+    #[must_use]
+    pub fn help(&self) -> &'static str {
+        match self {
+            Self::EnvironmentNotFound { .. } => {
+                "Environment Not Found - Troubleshooting:
+
+1. Verify the environment name is correct
+2. List the existing environments:
+   torrust-tracker-deployer list
+
+For more information, see docs/user-guide/commands/drift.md"
+            }
+            Self::InvalidState { .. } => {
+                "Invalid Environment State - Troubleshooting:
+
+Nothing is deployed on the instance before the release, so the environment
+must be in the 'released' or 'running' state.
+
+1. Check the environment state:
+   torrust-tracker-deployer show <env-name>
+
+2. Complete the deployment workflow up to the release:
+   torrust-tracker-deployer release <env-name>
+
+For more information, see docs/user-guide/commands/drift.md"
+            }
+            Self::MissingInstanceIp { .. } => {
+                "Missing Instance IP - Troubleshooting:
+
+The environment state has no instance IP, so the instance cannot be reached.
+
+1. Check the environment details:
+   torrust-tracker-deployer show <env-name>
+
+2. If the state file was edited by hand, restore it from a backup
+
+For more information, see docs/user-guide/commands/drift.md"
+            }
+            Self::TemplateRenderingFailed { .. } | Self::InvalidComposeFile { .. } => {
+                "Template Rendering Failed - Troubleshooting:
+
+1. If the environment uses template overrides, check them:
+   torrust-tracker-deployer render --env-name <env-name> --instance-ip <ip> --output-dir /tmp/render
+
+2. Check that the templates directory is readable
+
+For more information, see docs/user-guide/commands/drift.md"
+            }
+            Self::Download(e) => e.help(),
+            Self::Containers(_) => {
+                "Reading the Running Containers Failed - Troubleshooting:
+
+1. Check SSH connectivity to the instance:
+   ssh -i <ssh-key> <user>@<instance-ip>
+
+2. Check that Docker is running on the instance:
+   ssh <user>@<instance-ip> 'cd /opt/torrust && sudo docker compose ps'
+
+For more information, see docs/user-guide/commands/drift.md"
+            }
+            Self::LocalFile { .. } => {
+                "Local File Access Failed - Troubleshooting:
+
+The artifacts are compared in the build directory of the environment.
+
+1. Check that the build directory is writable
+2. Check the available disk space: df -h
+
+For more information, see docs/user-guide/commands/drift.md"
+            }
+            Self::StatePersistence(_) => {
+                "State Persistence Failed - Troubleshooting:
+
+1. Check that the data directory is readable
+2. Check that no other command is running on the environment
+
+For more information, see docs/user-guide/commands/drift.md"
+            }
+        }
+    }
+}
@@ -0,0 +1,302 @@
+//! Drift command handler implementation
+//!
+//! **Purpose**: Detect changes made on the instance outside the deployer
+//!
+//! This handler renders the release artifacts of an environment again,
+//! downloads the copies deployed on the instance and compares both, then
+//! compares the images of the running containers with the images of the
+//! expected `docker-compose.yml`.
+
+use std::net::IpAddr;
+use std::path::Path;
+use std::sync::Arc;
+
+use tracing::{info, instrument};
+
+use super::errors::DriftCommandHandlerError;
+use super::report::{compare_images, compose_images, DriftReport, FileDrift};
+use crate::application::command_handlers::backup::handler::ssh_config;
+use crate::application::errors::AllowedCommands;
+use crate::application::services::rendering::{
+    CaddyTemplateRenderingService, DockerComposeTemplateRenderingService,
+    TrackerTemplateRenderingService,
+};
+use crate::domain::environment::repository::EnvironmentRepository;
+use crate::domain::environment::state::AnyEnvironmentState;
+use crate::domain::EnvironmentName;
+use crate::infrastructure::remote_actions::{ContainerImages, RemoteFileTransfer};
+use crate::shared::Clock;
+
+/// Directory of the build directory the artifacts are compared in
+const DRIFT_DIR: &str = "drift";
+
+/// A release artifact compared with its deployed copy
+struct Artifact {
+    /// Name shown in the report
+    name: &'static str,
+    /// Path of the rendered artifact, relative to the render directory
+    rendered: &'static str,
+    /// Path of the deployed copy on the instance
+    remote: &'static str,
+}
+
+/// The Docker Compose file, deployed by `DeployComposeFilesStep`
+const COMPOSE_FILE: Artifact = Artifact {
+    name: "docker-compose.yml",
+    rendered: "docker-compose/docker-compose.yml",
+    remote: "/opt/torrust/docker-compose.yml",
+};
+
+/// The tracker configuration, deployed by `DeployTrackerConfigStep`
+const TRACKER_CONFIG: Artifact = Artifact {
+    name: "tracker.toml",
+    rendered: "tracker/tracker.toml",
+    remote: "/opt/torrust/storage/tracker/etc/tracker.toml",
+};
+
+/// The Caddy configuration, deployed by `DeployCaddyConfigStep` when HTTPS
+/// is configured
+const CADDY_CONFIG: Artifact = Artifact {
+    name: "Caddyfile",
+    rendered: "caddy/Caddyfile",
+    remote: "/opt/torrust/storage/caddy/etc/Caddyfile",
+};
+
+/// `DriftCommandHandler` detects configuration drift on the instance
+///
+/// The environment must be `Released` or `Running`: before the release
+/// nothing is deployed on the instance.
+///
+/// The expected artifacts are rendered to `build/{env}/drift/expected/` and
+/// the deployed copies downloaded to `build/{env}/drift/deployed/`, where
+/// they can be inspected after the command. The `.env` file is not
+/// compared, as it holds the secrets of the environment.
+pub struct DriftCommandHandler {
+    repository: Arc<dyn EnvironmentRepository + Send + Sync>,
+    clock: Arc<dyn Clock>,
+}
+
+impl DriftCommandHandler {
+    /// Create a new `DriftCommandHandler`
+    #[must_use]
+    pub fn new(
+        repository: Arc<dyn EnvironmentRepository + Send + Sync>,
+        clock: Arc<dyn Clock>,
+    ) -> Self {
+        Self { repository, clock }
+    }
+
+    /// Detect configuration drift on the instance of an environment
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if:
+    /// * Environment not found
+    /// * Environment is not `Released` or `Running`
+    /// * The expected artifacts cannot be rendered
+    /// * The deployed artifacts or the running containers cannot be read
+    #[instrument(
+        name = "command.drift",
+        skip_all,
+        fields(
+            command_type = "drift",
+            environment_name = %env_name
+        )
+    )]
+    pub async fn execute(
+        &self,
+        env_name: &EnvironmentName,
+    ) -> Result<DriftReport, DriftCommandHandlerError> {
+        let environment = self.repository.load(env_name)?.ok_or_else(|| {
+            DriftCommandHandlerError::EnvironmentNotFound {
+                name: env_name.to_string(),
+            }
+        })?;
+
+        let instance_ip = Self::check_environment(&environment)?;
+
+        let drift_dir = environment.build_dir().join(DRIFT_DIR);
+        let expected_dir = drift_dir.join("expected");
+        let deployed_dir = drift_dir.join("deployed");
+        reset_dir(&drift_dir)?;
+
+        let artifacts = self.render(&environment, &expected_dir).await?;
+
+        let ssh_config = ssh_config(&environment, instance_ip);
+        let transfer = RemoteFileTransfer::new(ssh_config.clone());
+
+        let mut files = Vec::new();
+        for artifact in &artifacts {
+            let expected = read(&expected_dir.join(artifact.rendered))?;
+            let deployed = if transfer
+                .exists(artifact.remote)
+                .map_err(DriftCommandHandlerError::Download)?
+            {
+                let local_path = deployed_dir.join(artifact.rendered);
+                transfer
+                    .download_file(artifact.remote, &local_path, &|_| {})
+                    .map_err(DriftCommandHandlerError::Download)?;
+                Some(read(&local_path)?)
+            } else {
+                None
+            };
+
+            files.push(FileDrift::compare(
+                artifact.name,
+                artifact.remote,
+                deployed.as_deref(),
+                &expected,
+            ));
+        }
+
+        let compose = read(&expected_dir.join(COMPOSE_FILE.rendered))?;
+        let expected_images = compose_images(&compose)
+            .map_err(|source| DriftCommandHandlerError::InvalidComposeFile { source })?;
+        let running = ContainerImages::new(ssh_config)
+            .running()
+            .map_err(DriftCommandHandlerError::Containers)?;
+        let images = compare_images(&expected_images, &running);
+
+        let report = DriftReport { files, images };
+
+        info!(
+            command = "drift",
+            environment = %env_name,
+            drifted = report.is_drifted(),
+            differences = report.drifted_count(),
+            "Configuration drift detected"
+        );
+
+        Ok(report)
+    }
+
+    /// Check drift can be detected on the environment, returning its instance IP
+    fn check_environment(
+        environment: &AnyEnvironmentState,
+    ) -> Result<IpAddr, DriftCommandHandlerError> {
+        if !matches!(
+            environment,
+            AnyEnvironmentState::Released(_) | AnyEnvironmentState::Running(_)
+        ) {
+            return Err(DriftCommandHandlerError::InvalidState {
+                name: environment.name().to_string(),
+                state: environment.state_name().to_string(),
+                allowed_commands: AllowedCommands::from(environment),
+            });
+        }
+
+        environment
+            .instance_ip()
+            .ok_or_else(|| DriftCommandHandlerError::MissingInstanceIp {
+                name: environment.name().to_string(),
+            })
+    }
+
+    /// Render the release artifacts to `output_dir`, returning the rendered ones
+    async fn render(
+        &self,
+        environment: &AnyEnvironmentState,
+        output_dir: &Path,
+    ) -> Result<Vec<&'static Artifact>, DriftCommandHandlerError> {
+        let templates_dir = environment.templates_dir();
+        let templates_override_dir = environment.templates_override_dir();
+        let extra_template_variables = environment.extra_template_variables();
+        let build_dir = output_dir.to_path_buf();
+        let user_inputs = environment.user_inputs();
+
+        DockerComposeTemplateRenderingService::from_paths(
+            templates_dir.clone(),
+            templates_override_dir.clone(),
+            extra_template_variables.clone(),
+            build_dir.clone(),
+            self.clock.clone(),
+        )
+        .render(user_inputs, environment.admin_token())
+        .await
+        .map_err(|e| DriftCommandHandlerError::TemplateRenderingFailed {
+            reason: e.to_string(),
+        })?;
+
+        TrackerTemplateRenderingService::from_paths(
+            templates_dir.clone(),
+            templates_override_dir.clone(),
+            extra_template_variables.clone(),
+            build_dir.clone(),
+            self.clock.clone(),
+        )
+        .render(user_inputs.tracker())
+        .map_err(|e| DriftCommandHandlerError::TemplateRenderingFailed {
+            reason: e.to_string(),
+        })?;
+
+        let caddy = CaddyTemplateRenderingService::from_paths(
+            templates_dir,
+            templates_override_dir,
+            extra_template_variables.clone(),
+            build_dir,
+            self.clock.clone(),
+        )
+        .render(user_inputs)
+        .map_err(|e| DriftCommandHandlerError::TemplateRenderingFailed {
+            reason: e.to_string(),
+        })?;
+
+        let mut artifacts = vec![&COMPOSE_FILE, &TRACKER_CONFIG];
+        if caddy.is_some() {
+            artifacts.push(&CADDY_CONFIG);
+        }
+
+        Ok(artifacts)
+    }
+}
+
+/// Empty `dir`, creating it if needed
+fn reset_dir(dir: &Path) -> Result<(), DriftCommandHandlerError> {
+    let local_file_error = |source| DriftCommandHandlerError::LocalFile {
+        path: dir.to_path_buf(),
+        source,
+    };
+
+    if dir.exists() {
+        std::fs::remove_dir_all(dir).map_err(local_file_error)?;
+    }
+
+    std::fs::create_dir_all(dir).map_err(local_file_error)
+}
+
+/// Read a rendered or downloaded artifact
+fn read(path: &Path) -> Result<String, DriftCommandHandlerError> {
+    std::fs::read_to_string(path).map_err(|source| DriftCommandHandlerError::LocalFile {
+        path: path.to_path_buf(),
+        source,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::environment::testing::EnvironmentTestBuilder;
+    use crate::infrastructure::persistence::file_repository_factory::FileRepositoryFactory;
+    use crate::shared::SystemClock;
+
+    #[tokio::test]
+    async fn it_should_refuse_an_environment_that_is_not_released_or_running() {
+        let (env, _data_dir, _build_dir, temp_dir) = EnvironmentTestBuilder::new()
+            .with_name("my-env")
+            .build_with_custom_paths();
+        let repository = FileRepositoryFactory::new(std::time::Duration::from_secs(30))
+            .create(temp_dir.path().join("data"));
+        repository.save(&env.into_any()).unwrap();
+        let handler = DriftCommandHandler::new(repository, Arc::new(SystemClock));
+
+        let error = handler
+            .execute(&EnvironmentName::new("my-env".to_string()).unwrap())
+            .await
+            .unwrap_err();
+
+        assert!(matches!(
+            error,
+            DriftCommandHandlerError::InvalidState { ref state, .. } if state == "created"
+        ));
+    }
+}
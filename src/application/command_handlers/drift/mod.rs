@@ -0,0 +1,28 @@
+//! Drift Command Module
+//!
+//! This module implements the delivery-agnostic `DriftCommandHandler`
+//! for detecting configuration drift on the instance of a `Released` or
+//! `Running` environment.
+//!
+//! ## Drift
+//!
+//! The release artifacts (`docker-compose.yml`, `tracker.toml` and, with
+//! HTTPS, the `Caddyfile`) are rendered again from the environment state and
+//! compared with the copies deployed on the instance. The images of the
+//! running containers are compared with the images of the expected
+//! `docker-compose.yml`, including their pinned digests.
+//!
+//! ## Design Principles
+//!
+//! - **Read-Only Operation**: Never modifies the instance or the environment state
+//! - **Reviewable**: Every modified artifact comes with a unified diff
+//! - **Secrets Stay Put**: The `.env` file is not downloaded
+
+pub mod errors;
+pub mod handler;
+pub mod report;
+
+// Re-export main types for convenience
+pub use errors::DriftCommandHandlerError;
+pub use handler::DriftCommandHandler;
+pub use report::{DriftReport, FileDrift, FileDriftStatus, ImageDrift, ImageDriftStatus};
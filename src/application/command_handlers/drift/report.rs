@@ -0,0 +1,337 @@
+//! Drift report
+//!
+//! The outcome of comparing the deployed release artifacts and the running
+//! containers of an environment with what the deployer would deploy now.
+
+use similar::TextDiff;
+
+use crate::domain::release::ImageReference;
+use crate::infrastructure::remote_actions::RunningContainer;
+
+/// Prefix of the line with the rendering time at the top of every template
+///
+/// The line differs on every rendering, so it is left out of the comparison.
+const GENERATED_AT_PREFIX: &str = "# Generated:";
+
+/// Drift of one release artifact
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FileDrift {
+    /// Name of the artifact (e.g. `tracker.toml`)
+    pub name: String,
+    /// Path of the deployed copy on the instance
+    pub remote_path: String,
+    /// How the deployed copy differs from the expected one
+    pub status: FileDriftStatus,
+}
+
+/// How a deployed artifact differs from the expected one
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum FileDriftStatus {
+    /// The deployed copy matches
+    Clean,
+    /// The deployed copy was changed; the unified diff goes from the
+    /// deployed copy to the expected one
+    Modified { diff: String },
+    /// There is no deployed copy on the instance
+    Missing,
+}
+
+impl FileDrift {
+    /// Compare the `deployed` copy of an artifact with the `expected` one
+    #[must_use]
+    pub fn compare(name: &str, remote_path: &str, deployed: Option<&str>, expected: &str) -> Self {
+        let status = match deployed {
+            None => FileDriftStatus::Missing,
+            Some(deployed) => {
+                let deployed = without_generated_at(deployed);
+                let expected = without_generated_at(expected);
+                if deployed == expected {
+                    FileDriftStatus::Clean
+                } else {
+                    let diff = TextDiff::from_lines(&deployed, &expected)
+                        .unified_diff()
+                        .header(
+                            &format!("{remote_path} (deployed)"),
+                            &format!("{name} (expected)"),
+                        )
+                        .to_string();
+                    FileDriftStatus::Modified { diff }
+                }
+            }
+        };
+
+        Self {
+            name: name.to_string(),
+            remote_path: remote_path.to_string(),
+            status,
+        }
+    }
+
+    /// Whether the deployed copy differs from the expected one
+    #[must_use]
+    pub fn is_drifted(&self) -> bool {
+        self.status != FileDriftStatus::Clean
+    }
+}
+
+/// Drift of the container of one Docker Compose service
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ImageDrift {
+    /// Name of the Docker Compose service
+    pub service: String,
+    /// Image of the service in the expected `docker-compose.yml`
+    pub expected: Option<String>,
+    /// Image the running container was created from
+    pub running: Option<String>,
+    /// How the running container differs from the expected one
+    pub status: ImageDriftStatus,
+}
+
+/// How the container of a service differs from the expected one
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ImageDriftStatus {
+    /// The container runs the expected image
+    Clean,
+    /// The container runs another image
+    ImageChanged,
+    /// The container runs the expected image name, but not its pinned digest
+    DigestMismatch {
+        /// Pinned digest
+        expected: String,
+        /// Repository digests of the running image
+        actual: Vec<String>,
+    },
+    /// A container runs for a service that is not expected
+    Unexpected,
+    /// No container runs for the service (e.g. the stack is stopped)
+    NotRunning,
+}
+
+impl ImageDrift {
+    /// Whether the running container differs from the expected one
+    ///
+    /// A stopped service is not a drift of the configuration.
+    #[must_use]
+    pub fn is_drifted(&self) -> bool {
+        !matches!(
+            self.status,
+            ImageDriftStatus::Clean | ImageDriftStatus::NotRunning
+        )
+    }
+}
+
+/// Compare the running containers with the `expected` `(service, image)` pairs
+#[must_use]
+pub fn compare_images(
+    expected: &[(String, String)],
+    running: &[RunningContainer],
+) -> Vec<ImageDrift> {
+    let mut drifts: Vec<ImageDrift> = expected
+        .iter()
+        .map(|(service, image)| {
+            let container = running.iter().find(|c| &c.service == service);
+            let status = match container {
+                None => ImageDriftStatus::NotRunning,
+                Some(container) if &container.image != image => ImageDriftStatus::ImageChanged,
+                Some(container) => match pinned_digest(image) {
+                    Some(digest) if !container.digests.contains(&digest) => {
+                        ImageDriftStatus::DigestMismatch {
+                            expected: digest,
+                            actual: container.digests.clone(),
+                        }
+                    }
+                    _ => ImageDriftStatus::Clean,
+                },
+            };
+
+            ImageDrift {
+                service: service.clone(),
+                expected: Some(image.clone()),
+                running: container.map(|c| c.image.clone()),
+                status,
+            }
+        })
+        .collect();
+
+    drifts.extend(
+        running
+            .iter()
+            .filter(|c| !expected.iter().any(|(service, _)| service == &c.service))
+            .map(|c| ImageDrift {
+                service: c.service.clone(),
+                expected: None,
+                running: Some(c.image.clone()),
+                status: ImageDriftStatus::Unexpected,
+            }),
+    );
+
+    drifts
+}
+
+/// Outcome of a drift detection
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DriftReport {
+    /// Drift of each release artifact
+    pub files: Vec<FileDrift>,
+    /// Drift of the container of each service
+    pub images: Vec<ImageDrift>,
+}
+
+impl DriftReport {
+    /// Whether anything differs from what the deployer would deploy
+    #[must_use]
+    pub fn is_drifted(&self) -> bool {
+        self.files.iter().any(FileDrift::is_drifted)
+            || self.images.iter().any(ImageDrift::is_drifted)
+    }
+
+    /// Number of artifacts and containers that drifted
+    #[must_use]
+    pub fn drifted_count(&self) -> usize {
+        self.files.iter().filter(|f| f.is_drifted()).count()
+            + self.images.iter().filter(|i| i.is_drifted()).count()
+    }
+}
+
+/// The `(service, image)` pairs of the services a compose file starts
+///
+/// Services with a profile (e.g. `backup`) only run on demand and are left
+/// out.
+///
+/// # Errors
+///
+/// Returns an error if the compose file is not valid YAML.
+pub fn compose_images(compose_yaml: &str) -> Result<Vec<(String, String)>, serde_yaml::Error> {
+    let compose: serde_yaml::Value = serde_yaml::from_str(compose_yaml)?;
+
+    let Some(services) = compose
+        .get("services")
+        .and_then(serde_yaml::Value::as_mapping)
+    else {
+        return Ok(Vec::new());
+    };
+
+    Ok(services
+        .iter()
+        .filter(|(_, service)| service.get("profiles").is_none())
+        .filter_map(|(name, service)| {
+            Some((
+                name.as_str()?.to_string(),
+                service.get("image")?.as_str()?.to_string(),
+            ))
+        })
+        .collect())
+}
+
+/// The pinned digest of an image reference, if any
+fn pinned_digest(image: &str) -> Option<String> {
+    ImageReference::new(image)
+        .ok()
+        .and_then(|image| image.digest().map(ToString::to_string))
+}
+
+/// `content` without its rendering time line
+fn without_generated_at(content: &str) -> String {
+    content
+        .split_inclusive('\n')
+        .filter(|line| !line.starts_with(GENERATED_AT_PREFIX))
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const DIGEST: &str = "sha256:0123456789abcdef0123456789abcdef0123456789abcdef0123456789abcdef";
+
+    #[test]
+    fn it_should_ignore_the_rendering_time_when_comparing_files() {
+        let deployed = "# Generated: 2026-01-01T00:00:00Z\n[core]\nprivate = false\n";
+        let expected = "# Generated: 2026-10-16T10:00:00Z\n[core]\nprivate = false\n";
+
+        let drift = FileDrift::compare(
+            "tracker.toml",
+            "/etc/tracker.toml",
+            Some(deployed),
+            expected,
+        );
+
+        assert_eq!(drift.status, FileDriftStatus::Clean);
+    }
+
+    #[test]
+    fn it_should_show_a_unified_diff_of_a_modified_file() {
+        let deployed = "[core]\nprivate = true\n";
+        let expected = "[core]\nprivate = false\n";
+
+        let drift = FileDrift::compare(
+            "tracker.toml",
+            "/etc/tracker.toml",
+            Some(deployed),
+            expected,
+        );
+
+        let FileDriftStatus::Modified { diff } = drift.status else {
+            panic!("Expected a modified file, got {:?}", drift.status);
+        };
+        assert!(diff.contains("--- /etc/tracker.toml (deployed)"));
+        assert!(diff.contains("-private = true\n+private = false"));
+    }
+
+    #[test]
+    fn it_should_report_images_that_do_not_match_the_compose_file() {
+        let expected = vec![
+            ("tracker".to_string(), format!("torrust/tracker@{DIGEST}")),
+            ("mysql".to_string(), "mysql:8.4".to_string()),
+            ("caddy".to_string(), "caddy:2.10".to_string()),
+        ];
+        let running = vec![
+            RunningContainer {
+                service: "tracker".to_string(),
+                image: format!("torrust/tracker@{DIGEST}"),
+                digests: vec!["sha256:other".to_string()],
+            },
+            RunningContainer {
+                service: "mysql".to_string(),
+                image: "mysql:8.0".to_string(),
+                digests: Vec::new(),
+            },
+            RunningContainer {
+                service: "debug".to_string(),
+                image: "busybox".to_string(),
+                digests: Vec::new(),
+            },
+        ];
+
+        let statuses: Vec<_> = compare_images(&expected, &running)
+            .into_iter()
+            .map(|drift| (drift.service, drift.status))
+            .collect();
+
+        assert_eq!(
+            statuses,
+            vec![
+                (
+                    "tracker".to_string(),
+                    ImageDriftStatus::DigestMismatch {
+                        expected: DIGEST.to_string(),
+                        actual: vec!["sha256:other".to_string()],
+                    }
+                ),
+                ("mysql".to_string(), ImageDriftStatus::ImageChanged),
+                ("caddy".to_string(), ImageDriftStatus::NotRunning),
+                ("debug".to_string(), ImageDriftStatus::Unexpected),
+            ]
+        );
+    }
+
+    #[test]
+    fn it_should_read_the_images_of_the_services_without_profile() {
+        let compose = "services:\n  tracker:\n    image: torrust/tracker:develop\n  backup:\n    image: torrust/backup:latest\n    profiles: [backup]\n";
+
+        assert_eq!(
+            compose_images(compose).unwrap(),
+            vec![("tracker".to_string(), "torrust/tracker:develop".to_string())]
+        );
+    }
+}
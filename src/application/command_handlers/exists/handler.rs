@@ -64,11 +64,11 @@ impl ExistsCommandHandler {
     /// Returns an error if:
     /// * Repository access fails (file system error, permissions, etc.)
     #[instrument(
-        name = "exists_command",
+        name = "command.exists",
         skip_all,
         fields(
             command_type = "exists",
-            environment = %env_name
+            environment_name = %env_name
         )
     )]
     pub fn execute(
@@ -12,7 +12,9 @@ use tempfile::TempDir;
 
 use crate::application::command_handlers::exists::errors::ExistsCommandHandlerError;
 use crate::application::command_handlers::exists::handler::ExistsCommandHandler;
-use crate::domain::environment::repository::{EnvironmentRepository, RepositoryError};
+use crate::domain::environment::repository::{
+    EnvironmentLockGuard, EnvironmentRepository, RepositoryError, StateBackup, StoredStateVersion,
+};
 use crate::domain::environment::state::AnyEnvironmentState;
 use crate::domain::environment::testing::EnvironmentTestBuilder;
 use crate::domain::EnvironmentName;
@@ -83,6 +85,27 @@ fn it_should_propagate_repository_error() {
             )))
         }
 
+        fn load_version(
+            &self,
+            _name: &EnvironmentName,
+        ) -> Result<Option<StoredStateVersion>, RepositoryError> {
+            Err(RepositoryError::Internal(anyhow::anyhow!(
+                "simulated disk error"
+            )))
+        }
+
+        fn change_stamp(&self, _name: &EnvironmentName) -> Result<Option<String>, RepositoryError> {
+            Err(RepositoryError::Internal(anyhow::anyhow!(
+                "simulated disk error"
+            )))
+        }
+
+        fn list(&self) -> Result<Vec<EnvironmentName>, RepositoryError> {
+            Err(RepositoryError::Internal(anyhow::anyhow!(
+                "simulated disk error"
+            )))
+        }
+
         fn exists(&self, _name: &EnvironmentName) -> Result<bool, RepositoryError> {
             Err(RepositoryError::Internal(anyhow::anyhow!(
                 "simulated disk error"
@@ -94,6 +117,31 @@ fn it_should_propagate_repository_error() {
                 "simulated disk error"
             )))
         }
+
+        fn lock(&self, _name: &EnvironmentName) -> Result<EnvironmentLockGuard, RepositoryError> {
+            Err(RepositoryError::Internal(anyhow::anyhow!(
+                "simulated disk error"
+            )))
+        }
+
+        fn list_backups(
+            &self,
+            _name: &EnvironmentName,
+        ) -> Result<Vec<StateBackup>, RepositoryError> {
+            Err(RepositoryError::Internal(anyhow::anyhow!(
+                "simulated disk error"
+            )))
+        }
+
+        fn restore_backup(
+            &self,
+            _name: &EnvironmentName,
+            _backup_id: &str,
+        ) -> Result<(), RepositoryError> {
+            Err(RepositoryError::Internal(anyhow::anyhow!(
+                "simulated disk error"
+            )))
+        }
     }
 
     let handler = ExistsCommandHandler::new(Arc::new(FailingRepository));
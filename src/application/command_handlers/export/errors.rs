@@ -0,0 +1,209 @@
+//! Error types for the export command handler
+
+use std::path::PathBuf;
+
+use crate::application::errors::PersistenceError;
+use crate::infrastructure::bundle::BundleError;
+use crate::shared::error::kind::ErrorKind;
+use crate::shared::error::traceable::Traceable;
+
+/// Comprehensive error type for the `ExportCommandHandler`
+#[derive(Debug, thiserror::Error)]
+pub enum ExportCommandHandlerError {
+    /// The environment to export does not exist
+    #[error("Environment not found: '{name}'")]
+    EnvironmentNotFound { name: String },
+
+    /// Another command is changing the environment
+    #[error("Environment '{name}' is in state '{state}': an operation is in progress")]
+    OperationInProgress { name: String, state: String },
+
+    /// The output file is already present
+    #[error("Output file '{path}' already exists")]
+    OutputExists { path: PathBuf },
+
+    /// The SSH private key of the environment is missing
+    #[error("SSH private key '{path}' not found")]
+    PrivateKeyNotFound { path: PathBuf },
+
+    /// The environment state could not be serialized
+    #[error("Failed to serialize the state of environment '{name}': {reason}")]
+    StateSerializationFailed { name: String, reason: String },
+
+    /// Writing the bundle failed
+    ///
+    /// The partially written bundle is removed.
+    #[error("Failed to write bundle '{path}': {source}")]
+    BundleFailed {
+        path: PathBuf,
+        #[source]
+        source: BundleError,
+    },
+
+    /// Repository operation failed
+    #[error("Repository operation failed: {0}")]
+    RepositoryError(#[from] PersistenceError),
+}
+
+impl From<crate::domain::environment::repository::RepositoryError> for ExportCommandHandlerError {
+    fn from(e: crate::domain::environment::repository::RepositoryError) -> Self {
+        Self::RepositoryError(e.into())
+    }
+}
+
+impl Traceable for ExportCommandHandlerError {
+    fn trace_format(&self) -> String {
+        match self {
+            Self::EnvironmentNotFound { name } => {
+                format!("ExportCommandHandlerError: Environment not found - '{name}'")
+            }
+            Self::OperationInProgress { name, state } => {
+                format!("ExportCommandHandlerError: Operation in progress - '{name}' is {state}")
+            }
+            Self::OutputExists { path } => {
+                format!(
+                    "ExportCommandHandlerError: Output file exists - '{}'",
+                    path.display()
+                )
+            }
+            Self::PrivateKeyNotFound { path } => {
+                format!(
+                    "ExportCommandHandlerError: SSH private key not found - '{}'",
+                    path.display()
+                )
+            }
+            Self::StateSerializationFailed { name, reason } => {
+                format!(
+                    "ExportCommandHandlerError: State serialization failed - '{name}': {reason}"
+                )
+            }
+            Self::BundleFailed { path, source } => {
+                format!(
+                    "ExportCommandHandlerError: Bundle write failed - '{}': {source}",
+                    path.display()
+                )
+            }
+            Self::RepositoryError(e) => {
+                format!("ExportCommandHandlerError: Repository operation failed - {e}")
+            }
+        }
+    }
+
+    fn trace_source(&self) -> Option<&dyn Traceable> {
+        None
+    }
+
+    fn error_kind(&self) -> ErrorKind {
+        match self {
+            Self::EnvironmentNotFound { .. }
+            | Self::OperationInProgress { .. }
+            | Self::OutputExists { .. }
+            | Self::PrivateKeyNotFound { .. } => ErrorKind::Configuration,
+            Self::BundleFailed { .. } => ErrorKind::FileSystem,
+            Self::StateSerializationFailed { .. } | Self::RepositoryError(_) => {
+                ErrorKind::StatePersistence
+            }
+        }
+    }
+}
+
+impl ExportCommandHandlerError {
+    /// Provides detailed troubleshooting guidance for this error
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use std::path::PathBuf;
+    /// use torrust_tracker_deployer_lib::application::command_handlers::export::ExportCommandHandlerError;
+    ///
+    /// let error = ExportCommandHandlerError::OutputExists {
+    ///     path: PathBuf::from("prod-bundle.tar.gz"),
+    /// };
+    ///
+    /// assert!(error.help().contains("--output"));
+    /// ```
+    #[must_use]
+    pub fn help(&self) -> &'static str {
+        match self {
+            Self::EnvironmentNotFound { .. } => {
+                "Environment Not Found - Troubleshooting:
+
+1. Verify the environment name is correct
+2. List the existing environments:
+   torrust-tracker-deployer list
+
+For more information, see docs/user-guide/commands/export.md"
+            }
+            Self::OperationInProgress { .. } => {
+                "Operation In Progress - Troubleshooting:
+
+Another command is changing the environment. Exporting it meanwhile could
+capture a state and an OpenTofu state that do not match.
+
+1. Wait for the other command to finish, then export again
+2. If no command is running, the previous one was interrupted:
+   torrust-tracker-deployer repair <name>
+
+For more information, see docs/user-guide/commands/export.md"
+            }
+            Self::OutputExists { .. } => {
+                "Output File Exists - Troubleshooting:
+
+Export never overwrites an existing file.
+
+1. Write the bundle somewhere else:
+   torrust-tracker-deployer export <name> --output <file>
+
+2. Or remove the existing file if it is not needed
+
+For more information, see docs/user-guide/commands/export.md"
+            }
+            Self::PrivateKeyNotFound { .. } => {
+                "SSH Private Key Not Found - Troubleshooting:
+
+The private key is exported with the secrets of the environment.
+
+1. Check the key path shown by:
+   torrust-tracker-deployer show <name>
+
+2. Restore the key to that path, or export without secrets (such a
+   bundle cannot be imported):
+   torrust-tracker-deployer export <name> --no-secrets
+
+For more information, see docs/user-guide/commands/export.md"
+            }
+            Self::StateSerializationFailed { .. } => {
+                "State Serialization Failed - Troubleshooting:
+
+This is an internal error: the environment state could not be written to
+the bundle.
+
+1. Check the environment can be loaded:
+   torrust-tracker-deployer show <name>
+
+2. Report the issue with the full error message
+
+For more information, see docs/user-guide/commands/export.md"
+            }
+            Self::BundleFailed { .. } => {
+                "Bundle Write Failed - Troubleshooting:
+
+The partially written bundle was removed.
+
+1. Check the output directory exists and is writable
+2. Verify sufficient disk space is available
+3. Check the build/ directory of the environment is readable
+
+For more information, see docs/user-guide/commands/export.md"
+            }
+            Self::RepositoryError(_) => {
+                "Repository Operation Failed - Troubleshooting:
+
+1. Check file system permissions for the data/ directory
+2. Check whether another process holds the environment lock
+
+For more information, see docs/user-guide/commands/export.md"
+            }
+        }
+    }
+}
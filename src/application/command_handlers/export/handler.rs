@@ -0,0 +1,417 @@
+//! Export command handler implementation
+//!
+//! **Purpose**: Write everything needed to manage an environment to a single
+//! bundle file, e.g. to hand over an environment to another operator
+//!
+//! ## Design Strategy
+//!
+//! 1. **State**: Refuse environments with an operation in progress, whose
+//!    state and `OpenTofu` state may not match
+//! 2. **Safety**: Never overwrite an existing file
+//! 3. **Secrets**: Encrypt everything holding a secret (the state, the SSH
+//!    private key, the environment secrets and the build files holding
+//!    secrets) with the passphrase, or leave the secrets out when no
+//!    passphrase is given
+//! 4. **Cleanup**: Remove the partially written bundle if anything fails
+
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::sync::Arc;
+
+use tracing::{info, instrument, warn};
+
+use super::errors::ExportCommandHandlerError;
+use crate::domain::environment::repository::{migrations, EnvironmentRepository};
+use crate::domain::environment::{AnyEnvironmentState, EnvironmentName};
+use crate::infrastructure::bundle::manifest::{
+    BUILD_DIR, ENCRYPTED_EXTENSION, ENCRYPTED_STATE_FILE, SECRETS_FILE, SSH_DIR, STATE_FILE,
+};
+use crate::infrastructure::bundle::{
+    encrypt, BundleError, BundleManifest, BundleSecrets, BundleWriter, BUNDLE_FORMAT_VERSION,
+};
+use crate::infrastructure::persistence::stored_environment::StoredEnvironment;
+use crate::infrastructure::persistence::workspace_key::remove_secret_fields;
+use crate::shared::secrets::Password;
+use crate::shared::Clock;
+
+/// Names of the rendered build files that hold secrets
+///
+/// They are encrypted with the passphrase, or left out of bundles without
+/// secrets.
+const SECRET_BUILD_FILES: &[&str] = &[
+    ".env",
+    "tracker.toml",
+    "variables.tfvars",
+    "backup.conf",
+    "prometheus.yml",
+];
+
+/// Summary of a written bundle
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ExportedBundle {
+    /// Path of the bundle file
+    pub path: PathBuf,
+
+    /// The manifest written to the bundle
+    pub manifest: BundleManifest,
+}
+
+impl ExportedBundle {
+    /// Total size of the files in the bundle, before compression
+    #[must_use]
+    pub fn content_size(&self) -> u64 {
+        self.manifest.files.iter().map(|file| file.size).sum()
+    }
+}
+
+/// `ExportCommandHandler` writes an environment to a bundle file
+pub struct ExportCommandHandler {
+    repository: Arc<dyn EnvironmentRepository + Send + Sync>,
+    clock: Arc<dyn Clock>,
+}
+
+impl ExportCommandHandler {
+    /// Create a new `ExportCommandHandler`
+    #[must_use]
+    pub fn new(
+        repository: Arc<dyn EnvironmentRepository + Send + Sync>,
+        clock: Arc<dyn Clock>,
+    ) -> Self {
+        Self { repository, clock }
+    }
+
+    /// Export the environment `env_name` to the bundle file `output`
+    ///
+    /// With a `passphrase`, the state, the SSH private key, the environment
+    /// secrets and the build files holding secrets are encrypted with it.
+    /// Without one, the secrets are left out: the secret fields are removed
+    /// from the state, and the private key, the environment secrets and the
+    /// build files holding secrets are not written.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if:
+    /// * The environment does not exist or an operation is in progress on it
+    /// * `output` already exists
+    /// * Secrets are included and the SSH private key is missing
+    /// * The bundle cannot be written (the partial file is removed)
+    #[instrument(
+        name = "command.export",
+        skip_all,
+        fields(
+            command_type = "export",
+            environment_name = %env_name,
+            output = %output.display()
+        )
+    )]
+    pub fn execute(
+        &self,
+        env_name: &EnvironmentName,
+        output: &Path,
+        passphrase: Option<&Password>,
+    ) -> Result<ExportedBundle, ExportCommandHandlerError> {
+        let environment = self.load_environment(env_name)?;
+
+        if environment.is_transitional_state() {
+            return Err(ExportCommandHandlerError::OperationInProgress {
+                name: env_name.to_string(),
+                state: environment.state_display_name().to_string(),
+            });
+        }
+
+        if output.exists() {
+            return Err(ExportCommandHandlerError::OutputExists {
+                path: output.to_path_buf(),
+            });
+        }
+
+        let private_key = &environment.ssh_credentials().ssh_priv_key_path;
+        if passphrase.is_some() && !private_key.is_file() {
+            return Err(ExportCommandHandlerError::PrivateKeyNotFound {
+                path: private_key.clone(),
+            });
+        }
+
+        let state = Self::serialize_state(&environment, passphrase.is_some())?;
+
+        let manifest = self
+            .write_bundle(&environment, &state, output, passphrase)
+            .map_err(|source| {
+                if let Err(e) = fs::remove_file(output) {
+                    warn!(
+                        command = "export",
+                        path = %output.display(),
+                        error = %e,
+                        "Failed to remove the partially written bundle"
+                    );
+                }
+                ExportCommandHandlerError::BundleFailed {
+                    path: output.to_path_buf(),
+                    source,
+                }
+            })?;
+
+        info!(
+            command = "export",
+            environment = %env_name,
+            output = %output.display(),
+            files = manifest.files.len(),
+            secrets = ?manifest.secrets,
+            "Environment exported successfully"
+        );
+
+        Ok(ExportedBundle {
+            path: output.to_path_buf(),
+            manifest,
+        })
+    }
+
+    /// Load the environment from the repository
+    fn load_environment(
+        &self,
+        name: &EnvironmentName,
+    ) -> Result<AnyEnvironmentState, ExportCommandHandlerError> {
+        self.repository
+            .load(name)?
+            .ok_or_else(|| ExportCommandHandlerError::EnvironmentNotFound {
+                name: name.to_string(),
+            })
+    }
+
+    /// Serialize the state as the repository stores it, with its schema version
+    ///
+    /// Without `secrets`, the secret fields are removed.
+    fn serialize_state(
+        environment: &AnyEnvironmentState,
+        secrets: bool,
+    ) -> Result<Vec<u8>, ExportCommandHandlerError> {
+        let serialization_failed =
+            |e: serde_json::Error| ExportCommandHandlerError::StateSerializationFailed {
+                name: environment.name().to_string(),
+                reason: e.to_string(),
+            };
+
+        let mut document = serde_json::to_value(StoredEnvironment::current(environment))
+            .map_err(serialization_failed)?;
+        if !secrets {
+            remove_secret_fields(&mut document);
+        }
+        serde_json::to_vec_pretty(&document).map_err(serialization_failed)
+    }
+
+    fn write_bundle(
+        &self,
+        environment: &AnyEnvironmentState,
+        state: &[u8],
+        output: &Path,
+        passphrase: Option<&Password>,
+    ) -> Result<BundleManifest, BundleError> {
+        let mut writer = BundleWriter::create(output)?;
+        match passphrase {
+            Some(passphrase) => {
+                writer.add_bytes(ENCRYPTED_STATE_FILE, &encrypt(state, passphrase)?)?;
+            }
+            None => writer.add_bytes(STATE_FILE, state)?,
+        }
+
+        if environment.build_dir().is_dir() {
+            let secret_files =
+                writer.add_dir_except(environment.build_dir(), BUILD_DIR, &holds_secrets)?;
+            if let Some(passphrase) = passphrase {
+                for (path, name) in secret_files {
+                    let content =
+                        fs::read(&path).map_err(|source| BundleError::Io { path, source })?;
+                    writer.add_bytes(
+                        &format!("{name}.{ENCRYPTED_EXTENSION}"),
+                        &encrypt(&content, passphrase)?,
+                    )?;
+                }
+            }
+        }
+
+        let credentials = environment.ssh_credentials();
+        let ssh_public_key = if credentials.ssh_pub_key_path.is_file() {
+            let name = format!("{SSH_DIR}/{}", file_name(&credentials.ssh_pub_key_path));
+            writer.add_file(&credentials.ssh_pub_key_path, &name)?;
+            Some(name)
+        } else {
+            None
+        };
+
+        let mut ssh_private_key = None;
+        if let Some(passphrase) = passphrase {
+            let key =
+                fs::read(&credentials.ssh_priv_key_path).map_err(|source| BundleError::Io {
+                    path: credentials.ssh_priv_key_path.clone(),
+                    source,
+                })?;
+            let name = format!(
+                "{SSH_DIR}/{}.{ENCRYPTED_EXTENSION}",
+                file_name(&credentials.ssh_priv_key_path)
+            );
+            writer.add_bytes(&name, &encrypt(&key, passphrase)?)?;
+            ssh_private_key = Some(name);
+
+            let secrets = environment.secrets();
+            if !secrets.is_empty() {
+                let secrets =
+                    serde_json::to_vec(&secrets).map_err(|e| BundleError::Encryption {
+                        reason: e.to_string(),
+                    })?;
+                writer.add_bytes(SECRETS_FILE, &encrypt(&secrets, passphrase)?)?;
+            }
+        }
+
+        writer.finish(BundleManifest {
+            format_version: BUNDLE_FORMAT_VERSION,
+            environment: environment.name().to_string(),
+            state_schema_version: migrations::CURRENT_STATE_SCHEMA_VERSION,
+            deployer_version: migrations::DEPLOYER_VERSION.to_string(),
+            exported_at: self.clock.now(),
+            secrets: if passphrase.is_some() {
+                BundleSecrets::Encrypted
+            } else {
+                BundleSecrets::Excluded
+            },
+            ssh_private_key,
+            ssh_public_key,
+            files: Vec::new(),
+        })
+    }
+}
+
+/// Whether the build file at `path` holds secrets
+fn holds_secrets(path: &Path) -> bool {
+    path.file_name()
+        .and_then(|name| name.to_str())
+        .is_some_and(|name| SECRET_BUILD_FILES.contains(&name))
+}
+
+/// File name of a key path, used as its name inside the bundle
+fn file_name(path: &Path) -> String {
+    path.file_name().map_or_else(
+        || "id".to_string(),
+        |name| name.to_string_lossy().into_owned(),
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use std::io::Read;
+    use std::time::Duration;
+
+    use flate2::read::GzDecoder;
+    use tempfile::TempDir;
+
+    use super::*;
+    use crate::application::command_handlers::create::config::EnvironmentCreationConfig;
+    use crate::application::command_handlers::CreateCommandHandler;
+    use crate::infrastructure::persistence::file_repository_factory::FileRepositoryFactory;
+    use crate::shared::SystemClock;
+
+    const SECRETS: &[&str] = &["MyAccessToken", "HetznerApiToken", "MysqlPassword1234"];
+
+    fn repository(working_dir: &Path) -> Arc<dyn EnvironmentRepository + Send + Sync> {
+        FileRepositoryFactory::new(Duration::from_secs(30)).create(working_dir.join("data"))
+    }
+
+    /// Create the `prod` environment, with build files holding its secrets
+    fn create_environment(working_dir: &Path) {
+        let config = EnvironmentCreationConfig::builder()
+            .name("prod")
+            .generate_ssh_keys()
+            .provider_hetzner("HetznerApiToken", "cx22", "nbg1", "ubuntu-24.04")
+            .mysql(
+                "mysql",
+                3306,
+                "tracker",
+                "tracker_user",
+                "MysqlPassword1234",
+            )
+            .api("0.0.0.0:1212", "MyAccessToken")
+            .build()
+            .unwrap();
+        CreateCommandHandler::new(repository(working_dir), Arc::new(SystemClock))
+            .execute(config, working_dir)
+            .unwrap();
+
+        let build_dir = working_dir.join("build/prod");
+        fs::create_dir_all(build_dir.join("tofu/hetzner")).unwrap();
+        fs::create_dir_all(build_dir.join("docker-compose")).unwrap();
+        fs::write(
+            build_dir.join("tofu/hetzner/variables.tfvars"),
+            "hcloud_api_token = \"HetznerApiToken\"",
+        )
+        .unwrap();
+        fs::write(
+            build_dir.join("docker-compose/.env"),
+            "MYSQL_PASSWORD='MysqlPassword1234'\nTRACKER_ADMIN_TOKEN='MyAccessToken'",
+        )
+        .unwrap();
+        fs::write(
+            build_dir.join("tofu/hetzner/terraform.tfstate"),
+            "{\"version\": 4}",
+        )
+        .unwrap();
+    }
+
+    fn export(working_dir: &Path, passphrase: Option<&Password>) -> ExportedBundle {
+        ExportCommandHandler::new(repository(working_dir), Arc::new(SystemClock))
+            .execute(
+                &EnvironmentName::new("prod").unwrap(),
+                &working_dir.join("prod-bundle.tar.gz"),
+                passphrase,
+            )
+            .unwrap()
+    }
+
+    /// The uncompressed content of the bundle
+    fn bundle_content(bundle: &Path) -> String {
+        let mut content = Vec::new();
+        GzDecoder::new(fs::File::open(bundle).unwrap())
+            .read_to_end(&mut content)
+            .unwrap();
+        String::from_utf8_lossy(&content).into_owned()
+    }
+
+    #[test]
+    fn it_should_leave_every_secret_out_of_a_bundle_without_secrets() {
+        let working_dir = TempDir::new().unwrap();
+        create_environment(working_dir.path());
+
+        let exported = export(working_dir.path(), None);
+
+        let content = bundle_content(&exported.path);
+        for secret in SECRETS {
+            assert!(!content.contains(secret), "bundle holds '{secret}'");
+        }
+        assert!(exported.manifest.file(STATE_FILE).is_some());
+        assert!(exported
+            .manifest
+            .file("build/tofu/hetzner/terraform.tfstate")
+            .is_some());
+        assert!(exported
+            .manifest
+            .file("build/docker-compose/.env")
+            .is_none());
+    }
+
+    #[test]
+    fn it_should_encrypt_every_secret_of_a_bundle_with_secrets() {
+        let working_dir = TempDir::new().unwrap();
+        create_environment(working_dir.path());
+
+        let exported = export(working_dir.path(), Some(&Password::new("shared secret")));
+
+        let content = bundle_content(&exported.path);
+        for secret in SECRETS {
+            assert!(!content.contains(secret), "bundle holds '{secret}'");
+        }
+        assert!(exported.manifest.file(STATE_FILE).is_none());
+        assert!(exported.manifest.file(ENCRYPTED_STATE_FILE).is_some());
+        assert!(exported
+            .manifest
+            .file("build/tofu/hetzner/variables.tfvars.age")
+            .is_some());
+    }
+}
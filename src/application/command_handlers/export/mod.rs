@@ -0,0 +1,24 @@
+//! Export Command Module
+//!
+//! This module implements the delivery-agnostic `ExportCommandHandler`
+//! for writing an environment to a single bundle file, so it can be managed
+//! from another machine with `import-bundle`.
+//!
+//! ## What Is Exported
+//!
+//! - The environment state, stamped with its schema version
+//! - The build directory, including the `OpenTofu` state of the infrastructure
+//! - The SSH public key
+//! - The SSH private key and the environment secrets (e.g. the Grafana admin
+//!   password), encrypted with a passphrase, unless secrets are excluded
+//!
+//! The data directory itself (traces, reports, state backups) stays behind.
+//!
+//! See `infrastructure::bundle` for the layout of a bundle.
+
+pub mod errors;
+pub mod handler;
+
+// Re-export main types for convenience
+pub use errors::ExportCommandHandlerError;
+pub use handler::{ExportCommandHandler, ExportedBundle};
@@ -0,0 +1,196 @@
+//! Error types for the import command handler
+
+use std::net::IpAddr;
+
+use crate::application::errors::PersistenceError;
+use crate::domain::environment::UserInputsError;
+use crate::domain::{InstanceNameError, ProfileNameError};
+use crate::shared::error::kind::ErrorKind;
+use crate::shared::error::traceable::Traceable;
+
+/// Comprehensive error type for the `ImportCommandHandler`
+#[derive(Debug, thiserror::Error)]
+pub enum ImportCommandHandlerError {
+    /// An environment with the requested name already exists
+    #[error("Environment '{name}' already exists")]
+    AlreadyExists { name: String },
+
+    /// The generated instance name is not valid for the environment
+    #[error("Cannot derive an instance name for the imported environment: {0}")]
+    InvalidInstanceName(#[source] InstanceNameError),
+
+    /// The generated LXD profile name is not valid for the environment
+    #[error("Cannot derive an LXD profile name for the imported environment: {0}")]
+    InvalidProfileName(#[source] ProfileNameError),
+
+    /// The default configuration violates an environment invariant
+    #[error("Imported configuration is invalid: {0}")]
+    InvalidConfiguration(#[source] UserInputsError),
+
+    /// Failed to connect to the instance via SSH
+    #[error("Failed to connect to instance at {address}: {reason}")]
+    ConnectivityFailed { address: IpAddr, reason: String },
+
+    /// Failed to detect the existing installation
+    #[error("Failed to detect the existing installation on {address}: {reason}")]
+    DetectionFailed { address: IpAddr, reason: String },
+
+    /// Failed to render Ansible templates
+    #[error("Failed to render Ansible templates: {reason}")]
+    TemplateRenderingFailed { reason: String },
+
+    /// Repository operation failed
+    #[error("Repository operation failed: {0}")]
+    RepositoryError(#[from] PersistenceError),
+}
+
+impl From<crate::domain::environment::repository::RepositoryError> for ImportCommandHandlerError {
+    fn from(e: crate::domain::environment::repository::RepositoryError) -> Self {
+        Self::RepositoryError(e.into())
+    }
+}
+
+impl Traceable for ImportCommandHandlerError {
+    fn trace_format(&self) -> String {
+        match self {
+            Self::AlreadyExists { name } => {
+                format!("ImportCommandHandlerError: Environment already exists - '{name}'")
+            }
+            Self::InvalidInstanceName(e) => {
+                format!("ImportCommandHandlerError: Invalid instance name - {e}")
+            }
+            Self::InvalidProfileName(e) => {
+                format!("ImportCommandHandlerError: Invalid profile name - {e}")
+            }
+            Self::InvalidConfiguration(e) => {
+                format!("ImportCommandHandlerError: Invalid configuration - {e}")
+            }
+            Self::ConnectivityFailed { address, reason } => {
+                format!(
+                    "ImportCommandHandlerError: SSH connectivity to {address} failed - {reason}"
+                )
+            }
+            Self::DetectionFailed { address, reason } => {
+                format!("ImportCommandHandlerError: Installation detection on {address} failed - {reason}")
+            }
+            Self::TemplateRenderingFailed { reason } => {
+                format!("ImportCommandHandlerError: Template rendering failed - {reason}")
+            }
+            Self::RepositoryError(e) => {
+                format!("ImportCommandHandlerError: Repository operation failed - {e}")
+            }
+        }
+    }
+
+    fn trace_source(&self) -> Option<&dyn Traceable> {
+        None
+    }
+
+    fn error_kind(&self) -> ErrorKind {
+        match self {
+            Self::AlreadyExists { .. }
+            | Self::InvalidInstanceName(_)
+            | Self::InvalidProfileName(_)
+            | Self::InvalidConfiguration(_) => ErrorKind::Configuration,
+            Self::ConnectivityFailed { .. } | Self::DetectionFailed { .. } => {
+                ErrorKind::NetworkConnectivity
+            }
+            Self::TemplateRenderingFailed { .. } => ErrorKind::TemplateRendering,
+            Self::RepositoryError(_) => ErrorKind::StatePersistence,
+        }
+    }
+}
+
+impl ImportCommandHandlerError {
+    /// Provides detailed troubleshooting guidance for this error
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use torrust_tracker_deployer_lib::application::command_handlers::import::ImportCommandHandlerError;
+    ///
+    /// let error = ImportCommandHandlerError::AlreadyExists {
+    ///     name: "legacy".to_string(),
+    /// };
+    ///
+    /// assert!(error.help().contains("Choose a different"));
+    /// ```
+    #[must_use]
+    pub fn help(&self) -> &'static str {
+        match self {
+            Self::AlreadyExists { .. } => {
+                "Environment Already Exists - Troubleshooting:
+
+1. Choose a different name for the imported environment
+2. Or remove the existing environment first:
+   torrust-tracker-deployer destroy <name>
+   torrust-tracker-deployer purge <name>
+
+Importing never overwrites an existing environment.
+
+For more information, see docs/user-guide/commands/import.md"
+            }
+            Self::InvalidInstanceName(_) | Self::InvalidProfileName(_) => {
+                "Invalid Derived Name - Troubleshooting:
+
+The instance and profile names of the imported environment are derived from
+the environment name, e.g. 'torrust-tracker-vm-<name>'.
+
+1. Use a shorter environment name
+2. Use only lowercase letters, digits and dashes
+
+For more information, see docs/user-guide/commands/import.md"
+            }
+            Self::InvalidConfiguration(_) => {
+                "Invalid Imported Configuration - This is an internal error:
+
+The default configuration used for imported environments should always be
+valid. Please report this issue with the full command output and logs
+(use --log-output file-and-stderr).
+
+Report issues at: https://github.com/torrust/torrust-tracker-deployer/issues"
+            }
+            Self::ConnectivityFailed { .. } => {
+                "SSH Connectivity Failed - Troubleshooting:
+
+1. Verify the instance is running and reachable:
+   ping <ip>
+
+2. Check the SSH credentials manually:
+   ssh -i <private-key> -p <port> <user>@<ip>
+
+3. Make sure the public key is in ~/.ssh/authorized_keys of the SSH user
+4. Check firewall rules allow SSH on the given port
+
+For more information, see docs/user-guide/commands/import.md"
+            }
+            Self::DetectionFailed { .. } => {
+                "Installation Detection Failed - Troubleshooting:
+
+1. Check the SSH user can run commands on the instance
+2. Retry without --detect-existing to import the instance in the
+   Provisioned state, then run 'configure' to converge it
+
+For more information, see docs/user-guide/commands/import.md"
+            }
+            Self::TemplateRenderingFailed { .. } => {
+                "Template Rendering Failed - Troubleshooting:
+
+1. Check file system permissions for the build/ directory
+2. Verify sufficient disk space is available
+3. Re-run with --log-output file-and-stderr for details
+
+For more information, see docs/user-guide/commands/import.md"
+            }
+            Self::RepositoryError(_) => {
+                "Repository Operation Failed - Troubleshooting:
+
+1. Check file system permissions for the data/ directory
+2. Verify sufficient disk space is available
+3. Check whether another process holds the environment lock
+
+For more information, see docs/user-guide/commands/import.md"
+            }
+        }
+    }
+}
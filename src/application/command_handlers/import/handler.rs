@@ -0,0 +1,424 @@
+//! Import command handler implementation
+//!
+//! **Purpose**: Adopt an instance that was deployed before (or outside) this
+//! tool as a new environment
+//!
+//! ## Design Strategy
+//!
+//! 1. **Uniqueness**: Refuse a name that is already in use
+//! 2. **Configuration**: Build the environment from the default configuration
+//!    with the given SSH credentials
+//! 3. **Connectivity**: Verify the instance is reachable over SSH
+//! 4. **Preparation**: Render the Ansible templates, so `configure` can run next
+//! 5. **Detection** (optional): Start in `Configured` when Docker and Docker
+//!    Compose are installed, or in `Running` when a tracker container is running
+//! 6. **Persistence**: Save the environment, marked as externally provisioned
+//!
+//! The `OpenTofu` layer is skipped entirely. The provider settings derived
+//! from the name are placeholders that are never used.
+
+use std::net::{IpAddr, SocketAddr};
+use std::path::Path;
+use std::sync::Arc;
+
+use tracing::{info, instrument};
+
+use super::errors::ImportCommandHandlerError;
+use crate::adapters::ssh::{KnownHostsFile, SshClient, SshConfig, SshCredentials, SshMultiplexer};
+use crate::application::services::rendering::AnsibleTemplateRenderingService;
+use crate::application::steps::RecordHostKeysStep;
+use crate::domain::environment::repository::EnvironmentRepository;
+use crate::domain::environment::{
+    AnyEnvironmentState, Created, Environment, EnvironmentName, EnvironmentParams,
+};
+use crate::domain::provider::{LxdConfig, ProviderConfig};
+use crate::domain::tracker::TrackerConfig;
+use crate::domain::{InstanceName, ProfileName};
+use crate::infrastructure::remote_actions::{ExistingInstallation, InstallationProbe};
+use crate::shared::Clock;
+
+/// The existing instance to import
+#[derive(Debug, Clone)]
+pub struct ImportTarget {
+    /// IP address of the instance
+    pub instance_ip: IpAddr,
+
+    /// SSH credentials accepted by the instance
+    pub ssh_credentials: SshCredentials,
+
+    /// SSH port of the instance
+    pub ssh_port: u16,
+}
+
+/// Result of a successful import
+#[derive(Debug, Clone)]
+pub struct ImportOutcome {
+    /// The imported environment, in the state matching the detected installation
+    pub environment: AnyEnvironmentState,
+
+    /// What was found on the instance, `None` when detection was not requested
+    pub installation: Option<ExistingInstallation>,
+}
+
+/// `ImportCommandHandler` creates an environment from an existing instance
+pub struct ImportCommandHandler {
+    repository: Arc<dyn EnvironmentRepository + Send + Sync>,
+    clock: Arc<dyn Clock>,
+}
+
+impl ImportCommandHandler {
+    /// Create a new `ImportCommandHandler`
+    ///
+    /// # Arguments
+    ///
+    /// * `repository` - Repository for saving the imported environment
+    /// * `clock` - Clock for the creation timestamp and template rendering
+    #[must_use]
+    pub fn new(
+        repository: Arc<dyn EnvironmentRepository + Send + Sync>,
+        clock: Arc<dyn Clock>,
+    ) -> Self {
+        Self { repository, clock }
+    }
+
+    /// Import the `target` instance as the environment `name`
+    ///
+    /// # Arguments
+    ///
+    /// * `name` - Name of the new environment
+    /// * `target` - IP address and SSH access of the existing instance
+    /// * `detect_existing` - Probe the instance to pick a more advanced initial state
+    /// * `working_dir` - Root directory containing `data/` and `build/`
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if:
+    /// * An environment with the same name already exists
+    /// * The instance is not reachable over SSH
+    /// * The existing installation cannot be detected
+    /// * Ansible template rendering fails
+    /// * The environment cannot be persisted
+    #[instrument(
+        name = "command.import",
+        skip_all,
+        fields(
+            command_type = "import",
+            environment_name = %name,
+            instance_ip = %target.instance_ip,
+            detect_existing
+        )
+    )]
+    pub async fn execute(
+        &self,
+        name: &EnvironmentName,
+        target: ImportTarget,
+        detect_existing: bool,
+        working_dir: &Path,
+    ) -> Result<ImportOutcome, ImportCommandHandlerError> {
+        let _lock = self.repository.lock(name)?;
+        if self.repository.exists(name)? {
+            return Err(ImportCommandHandlerError::AlreadyExists {
+                name: name.to_string(),
+            });
+        }
+
+        let instance_ip = target.instance_ip;
+        let ssh_credentials = target.ssh_credentials.clone();
+        let ssh_socket_addr = SocketAddr::new(instance_ip, target.ssh_port);
+
+        let environment = Environment::create(
+            Self::import_params(name, target)?,
+            working_dir,
+            self.clock.now(),
+        )
+        .map_err(ImportCommandHandlerError::InvalidConfiguration)?;
+
+        // The connectivity check and the probe share one SSH connection
+        let multiplexer = SshMultiplexer::new(environment.ssh_control_dir());
+        let ssh_config = SshConfig::new(ssh_credentials, ssh_socket_addr)
+            .with_control_dir(multiplexer.control_dir().to_path_buf());
+
+        Self::validate_ssh_connectivity(&ssh_config)?;
+
+        // First connection to the instance: record its host keys, replacing
+        // any left by a previous environment of the same name
+        RecordHostKeysStep::new(
+            ssh_config.clone(),
+            KnownHostsFile::new(environment.ssh_known_hosts_path()),
+        )
+        .with_rotation()
+        .execute()
+        .map_err(|source| ImportCommandHandlerError::ConnectivityFailed {
+            address: instance_ip,
+            reason: source.to_string(),
+        })?;
+        let ssh_config = ssh_config.with_known_hosts(environment.ssh_known_hosts_path());
+
+        self.prepare_for_configuration(&environment, instance_ip)
+            .await?;
+
+        let installation = if detect_existing {
+            Some(Self::detect_installation(&ssh_config)?)
+        } else {
+            None
+        };
+
+        let environment = Self::initial_state(environment, instance_ip, installation);
+
+        self.repository.save(&environment)?;
+
+        info!(
+            command = "import",
+            environment = %name,
+            instance_ip = %instance_ip,
+            state = environment.state_name(),
+            "Instance imported successfully"
+        );
+
+        Ok(ImportOutcome {
+            environment,
+            installation,
+        })
+    }
+
+    /// Build the creation parameters from the default configuration
+    fn import_params(
+        name: &EnvironmentName,
+        target: ImportTarget,
+    ) -> Result<EnvironmentParams, ImportCommandHandlerError> {
+        let instance_name = InstanceName::new(format!(
+            "{}{}",
+            InstanceName::GENERATED_PREFIX,
+            name.as_str()
+        ))
+        .map_err(ImportCommandHandlerError::InvalidInstanceName)?;
+
+        let profile_name = ProfileName::new(format!(
+            "{}{}",
+            ProfileName::GENERATED_PREFIX,
+            name.as_str()
+        ))
+        .map_err(ImportCommandHandlerError::InvalidProfileName)?;
+
+        Ok(EnvironmentParams::new(
+            name.clone(),
+            instance_name,
+            ProviderConfig::Lxd(LxdConfig {
+                profile_name,
+                remote: None,
+            }),
+            target.ssh_credentials,
+            target.ssh_port,
+            TrackerConfig::default(),
+            None,
+            None,
+            None,
+            None,
+        ))
+    }
+
+    /// Verify the instance accepts the SSH credentials
+    fn validate_ssh_connectivity(ssh_config: &SshConfig) -> Result<(), ImportCommandHandlerError> {
+        let address = ssh_config.host_ip();
+        let ssh_client = SshClient::new(ssh_config.clone());
+
+        let connected = ssh_client.test_connectivity().map_err(|source| {
+            ImportCommandHandlerError::ConnectivityFailed {
+                address,
+                reason: source.to_string(),
+            }
+        })?;
+
+        if !connected {
+            return Err(ImportCommandHandlerError::ConnectivityFailed {
+                address,
+                reason: "SSH connection test returned false".to_string(),
+            });
+        }
+
+        Ok(())
+    }
+
+    /// Render the Ansible templates with the instance IP
+    async fn prepare_for_configuration(
+        &self,
+        environment: &Environment<Created>,
+        instance_ip: IpAddr,
+    ) -> Result<(), ImportCommandHandlerError> {
+        let ansible_template_service = AnsibleTemplateRenderingService::from_paths(
+            environment.templates_dir(),
+            environment.templates_override_dir(),
+            environment.extra_template_variables().clone(),
+            environment.build_dir().clone(),
+            self.clock.clone(),
+        )
+        .with_known_hosts_file(environment.ssh_known_hosts_path());
+
+        ansible_template_service
+            .render_templates(&environment.context().user_inputs, instance_ip, None)
+            .await
+            .map_err(|e| ImportCommandHandlerError::TemplateRenderingFailed {
+                reason: e.to_string(),
+            })
+    }
+
+    /// Probe the instance for an existing Docker and tracker installation
+    fn detect_installation(
+        ssh_config: &SshConfig,
+    ) -> Result<ExistingInstallation, ImportCommandHandlerError> {
+        InstallationProbe::new(ssh_config.clone())
+            .probe()
+            .map_err(|source| ImportCommandHandlerError::DetectionFailed {
+                address: ssh_config.host_ip(),
+                reason: source.to_string(),
+            })
+    }
+
+    /// Pick the initial state matching the detected installation
+    ///
+    /// Without detection the environment starts in `Provisioned`, so the next
+    /// step is always `configure`.
+    fn initial_state(
+        environment: Environment<Created>,
+        instance_ip: IpAddr,
+        installation: Option<ExistingInstallation>,
+    ) -> AnyEnvironmentState {
+        let provisioned = environment.import(instance_ip);
+
+        match installation {
+            Some(found) if found.is_configured() && found.tracker_running => provisioned
+                .start_configuring()
+                .configured()
+                .start_releasing()
+                .released()
+                .start_running()
+                .into_any(),
+            Some(found) if found.is_configured() => {
+                provisioned.start_configuring().configured().into_any()
+            }
+            _ => provisioned.into_any(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::net::Ipv4Addr;
+    use std::path::PathBuf;
+
+    use chrono::Utc;
+    use tempfile::TempDir;
+
+    use super::*;
+    use crate::domain::environment::runtime_outputs::ProvisionMethod;
+    use crate::domain::environment::testing::EnvironmentTestBuilder;
+    use crate::infrastructure::persistence::file_repository_factory::FileRepositoryFactory;
+    use crate::shared::Username;
+    use crate::testing::MockClock;
+
+    fn env_name(name: &str) -> EnvironmentName {
+        EnvironmentName::new(name.to_string()).unwrap()
+    }
+
+    fn created_environment(temp_dir: &TempDir) -> Environment<Created> {
+        let target = ImportTarget {
+            instance_ip: IpAddr::V4(Ipv4Addr::new(10, 0, 0, 5)),
+            ssh_credentials: SshCredentials::new(
+                PathBuf::from("/tmp/legacy_key"),
+                PathBuf::from("/tmp/legacy_key.pub"),
+                Username::new("torrust").unwrap(),
+            ),
+            ssh_port: 22,
+        };
+        let params = ImportCommandHandler::import_params(&env_name("legacy"), target).unwrap();
+
+        Environment::create(params, temp_dir.path(), Utc::now()).unwrap()
+    }
+
+    #[test]
+    fn it_should_start_in_provisioned_state_without_detection() {
+        let temp_dir = TempDir::new().unwrap();
+        let ip = IpAddr::V4(Ipv4Addr::new(10, 0, 0, 5));
+
+        let environment =
+            ImportCommandHandler::initial_state(created_environment(&temp_dir), ip, None);
+
+        assert_eq!(environment.state_name(), "provisioned");
+        assert_eq!(environment.instance_ip(), Some(ip));
+        assert_eq!(
+            environment.provision_method(),
+            Some(ProvisionMethod::Imported)
+        );
+        assert!(environment.is_externally_provisioned());
+    }
+
+    #[test]
+    fn it_should_start_in_configured_state_when_docker_is_installed() {
+        let temp_dir = TempDir::new().unwrap();
+        let installation = ExistingInstallation {
+            docker_installed: true,
+            compose_installed: true,
+            tracker_running: false,
+        };
+
+        let environment = ImportCommandHandler::initial_state(
+            created_environment(&temp_dir),
+            IpAddr::V4(Ipv4Addr::new(10, 0, 0, 5)),
+            Some(installation),
+        );
+
+        assert_eq!(environment.state_name(), "configured");
+    }
+
+    #[test]
+    fn it_should_start_in_running_state_when_the_tracker_is_running() {
+        let temp_dir = TempDir::new().unwrap();
+        let installation = ExistingInstallation {
+            docker_installed: true,
+            compose_installed: true,
+            tracker_running: true,
+        };
+
+        let environment = ImportCommandHandler::initial_state(
+            created_environment(&temp_dir),
+            IpAddr::V4(Ipv4Addr::new(10, 0, 0, 5)),
+            Some(installation),
+        );
+
+        assert_eq!(environment.state_name(), "running");
+    }
+
+    #[tokio::test]
+    async fn it_should_refuse_a_name_that_already_exists() {
+        let temp_dir = TempDir::new().unwrap();
+        let repository = FileRepositoryFactory::new(std::time::Duration::from_secs(30))
+            .create(temp_dir.path().join("data"));
+        let (existing, _data_dir, _build_dir, _existing_temp_dir) = EnvironmentTestBuilder::new()
+            .with_name("legacy")
+            .build_with_custom_paths();
+        repository.save(&existing.into_any()).unwrap();
+        let handler = ImportCommandHandler::new(repository, Arc::new(MockClock::new(Utc::now())));
+
+        let result = handler
+            .execute(
+                &env_name("legacy"),
+                ImportTarget {
+                    instance_ip: IpAddr::V4(Ipv4Addr::new(10, 0, 0, 5)),
+                    ssh_credentials: SshCredentials::new(
+                        PathBuf::from("/tmp/legacy_key"),
+                        PathBuf::from("/tmp/legacy_key.pub"),
+                        Username::new("torrust").unwrap(),
+                    ),
+                    ssh_port: 22,
+                },
+                false,
+                temp_dir.path(),
+            )
+            .await;
+
+        assert!(matches!(
+            result,
+            Err(ImportCommandHandlerError::AlreadyExists { .. })
+        ));
+    }
+}
@@ -0,0 +1,22 @@
+//! Import Command Module
+//!
+//! This module implements the delivery-agnostic `ImportCommandHandler`
+//! for adopting an instance that was deployed outside this tool.
+//!
+//! ## Differences from `register`
+//!
+//! - `register` attaches an instance to an environment created from a
+//!   configuration file; `import` creates the environment itself from a
+//!   name, SSH credentials and an IP address, using the default configuration
+//! - `import` can detect an existing Docker or tracker installation and start
+//!   the environment in the `Configured` or `Running` state
+//!
+//! Both skip `OpenTofu` entirely and mark the instance as externally
+//! provisioned, so `destroy` only cleans up local state.
+
+pub mod errors;
+pub mod handler;
+
+// Re-export main types for convenience
+pub use errors::ImportCommandHandlerError;
+pub use handler::{ImportCommandHandler, ImportOutcome, ImportTarget};
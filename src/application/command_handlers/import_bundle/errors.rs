@@ -0,0 +1,299 @@
+//! Error types for the import bundle command handler
+
+use std::path::PathBuf;
+
+use crate::application::errors::PersistenceError;
+use crate::infrastructure::bundle::BundleError;
+use crate::shared::error::kind::ErrorKind;
+use crate::shared::error::traceable::Traceable;
+
+/// Comprehensive error type for the `ImportBundleCommandHandler`
+#[derive(Debug, thiserror::Error)]
+pub enum ImportBundleCommandHandlerError {
+    /// The bundle file does not exist
+    #[error("Bundle file not found: '{path}'")]
+    BundleNotFound { path: PathBuf },
+
+    /// The bundle is corrupted, tampered with or written by a newer deployer
+    #[error("Invalid bundle '{path}': {source}")]
+    InvalidBundle {
+        path: PathBuf,
+        #[source]
+        source: BundleError,
+    },
+
+    /// The environment state in the bundle cannot be read
+    #[error("Invalid environment state in bundle '{path}': {reason}")]
+    InvalidState { path: PathBuf, reason: String },
+
+    /// The manifest and the state name different environments
+    #[error("Bundle manifest is for environment '{manifest}' but its state is for '{state}'")]
+    NameMismatch { manifest: String, state: String },
+
+    /// An environment with the same name already exists in this workspace
+    #[error("Environment '{name}' already exists")]
+    EnvironmentAlreadyExists { name: String },
+
+    /// A directory of the environment is already present on disk
+    #[error("Directory '{path}' already exists")]
+    TargetDirectoryExists { path: PathBuf },
+
+    /// The bundle was exported without its secrets
+    #[error("Bundle '{path}' was exported without secrets and cannot be imported")]
+    SecretsExcluded { path: PathBuf },
+
+    /// The bundle includes encrypted secrets and no passphrase was given
+    #[error("Bundle '{path}' includes encrypted secrets: a passphrase is required")]
+    PassphraseRequired { path: PathBuf },
+
+    /// The passphrase does not decrypt the secrets of the bundle
+    #[error("Failed to decrypt the secrets of bundle '{path}': wrong passphrase")]
+    WrongPassphrase { path: PathBuf },
+
+    /// A file or directory of the environment could not be written
+    ///
+    /// The directories created for the environment are removed.
+    #[error("Failed to write '{path}': {source}")]
+    FileSystem {
+        path: PathBuf,
+        #[source]
+        source: std::io::Error,
+    },
+
+    /// The imported environment could not be saved
+    ///
+    /// The directories created for the environment are removed.
+    #[error("Failed to save environment '{name}', its directories were removed: {source}")]
+    PersistenceFailed {
+        name: String,
+        #[source]
+        source: PersistenceError,
+    },
+
+    /// Repository operation failed
+    #[error("Repository operation failed: {0}")]
+    RepositoryError(#[from] PersistenceError),
+}
+
+impl From<crate::domain::environment::repository::RepositoryError>
+    for ImportBundleCommandHandlerError
+{
+    fn from(e: crate::domain::environment::repository::RepositoryError) -> Self {
+        Self::RepositoryError(e.into())
+    }
+}
+
+impl Traceable for ImportBundleCommandHandlerError {
+    fn trace_format(&self) -> String {
+        match self {
+            Self::BundleNotFound { path } => {
+                format!(
+                    "ImportBundleCommandHandlerError: Bundle not found - '{}'",
+                    path.display()
+                )
+            }
+            Self::InvalidBundle { path, source } => {
+                format!(
+                    "ImportBundleCommandHandlerError: Invalid bundle - '{}': {source}",
+                    path.display()
+                )
+            }
+            Self::InvalidState { path, reason } => {
+                format!(
+                    "ImportBundleCommandHandlerError: Invalid state - '{}': {reason}",
+                    path.display()
+                )
+            }
+            Self::NameMismatch { manifest, state } => {
+                format!(
+                    "ImportBundleCommandHandlerError: Name mismatch - manifest '{manifest}', state '{state}'"
+                )
+            }
+            Self::EnvironmentAlreadyExists { name } => {
+                format!("ImportBundleCommandHandlerError: Environment already exists - '{name}'")
+            }
+            Self::TargetDirectoryExists { path } => {
+                format!(
+                    "ImportBundleCommandHandlerError: Directory already exists - '{}'",
+                    path.display()
+                )
+            }
+            Self::SecretsExcluded { path } => {
+                format!(
+                    "ImportBundleCommandHandlerError: Secrets excluded - '{}'",
+                    path.display()
+                )
+            }
+            Self::PassphraseRequired { path } => {
+                format!(
+                    "ImportBundleCommandHandlerError: Passphrase required - '{}'",
+                    path.display()
+                )
+            }
+            Self::WrongPassphrase { path } => {
+                format!(
+                    "ImportBundleCommandHandlerError: Wrong passphrase - '{}'",
+                    path.display()
+                )
+            }
+            Self::FileSystem { path, source } => {
+                format!(
+                    "ImportBundleCommandHandlerError: File system error - '{}': {source}",
+                    path.display()
+                )
+            }
+            Self::PersistenceFailed { name, source } => {
+                format!("ImportBundleCommandHandlerError: Failed to save '{name}' - {source}")
+            }
+            Self::RepositoryError(e) => {
+                format!("ImportBundleCommandHandlerError: Repository operation failed - {e}")
+            }
+        }
+    }
+
+    fn trace_source(&self) -> Option<&dyn Traceable> {
+        None
+    }
+
+    fn error_kind(&self) -> ErrorKind {
+        match self {
+            Self::BundleNotFound { .. }
+            | Self::InvalidBundle { .. }
+            | Self::InvalidState { .. }
+            | Self::NameMismatch { .. }
+            | Self::EnvironmentAlreadyExists { .. }
+            | Self::TargetDirectoryExists { .. }
+            | Self::SecretsExcluded { .. }
+            | Self::PassphraseRequired { .. }
+            | Self::WrongPassphrase { .. } => ErrorKind::Configuration,
+            Self::FileSystem { .. } => ErrorKind::FileSystem,
+            Self::PersistenceFailed { .. } | Self::RepositoryError(_) => {
+                ErrorKind::StatePersistence
+            }
+        }
+    }
+}
+
+impl ImportBundleCommandHandlerError {
+    /// Provides detailed troubleshooting guidance for this error
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use std::path::PathBuf;
+    /// use torrust_tracker_deployer_lib::application::command_handlers::import_bundle::ImportBundleCommandHandlerError;
+    ///
+    /// let error = ImportBundleCommandHandlerError::PassphraseRequired {
+    ///     path: PathBuf::from("prod-bundle.tar.gz"),
+    /// };
+    ///
+    /// assert!(error.help().contains("--passphrase-env"));
+    /// ```
+    #[must_use]
+    pub fn help(&self) -> &'static str {
+        match self {
+            Self::BundleNotFound { .. } => {
+                "Bundle Not Found - Troubleshooting:
+
+1. Verify the path of the bundle file is correct
+2. Bundles are written by the export command on the source machine:
+   torrust-tracker-deployer export <name> --passphrase-env <VAR>
+
+For more information, see docs/user-guide/commands/import-bundle.md"
+            }
+            Self::InvalidBundle { .. } | Self::InvalidState { .. } | Self::NameMismatch { .. } => {
+                "Invalid Bundle - Troubleshooting:
+
+The bundle was refused before anything was written to the workspace.
+
+1. Check the bundle was copied completely (compare its size or checksum
+   with the original file)
+2. Check the bundle was not modified after it was exported
+3. If it was written by a newer deployer, upgrade this deployer
+4. Otherwise, export the environment again on the source machine
+
+For more information, see docs/user-guide/commands/import-bundle.md"
+            }
+            Self::EnvironmentAlreadyExists { .. } => {
+                "Environment Already Exists - Troubleshooting:
+
+Importing never overwrites an existing environment.
+
+1. Check whether the existing environment is the same one:
+   torrust-tracker-deployer show <name>
+
+2. Rename the existing environment to free the name:
+   torrust-tracker-deployer rename <name> <new-name>
+
+3. Or remove it if it is not needed:
+   torrust-tracker-deployer purge <name>
+
+For more information, see docs/user-guide/commands/import-bundle.md"
+            }
+            Self::TargetDirectoryExists { .. } => {
+                "Directory Already Exists - Troubleshooting:
+
+The data or build directory of the environment is present, but no
+environment uses it (e.g. left over by an interrupted purge).
+
+1. Inspect the directory and remove it if it is not needed
+2. Or list leftover build directories with:
+   torrust-tracker-deployer orphans
+
+For more information, see docs/user-guide/commands/import-bundle.md"
+            }
+            Self::SecretsExcluded { .. } => {
+                "Secrets Excluded - Troubleshooting:
+
+A bundle exported with --no-secrets has no tracker admin token, database
+passwords, provider API token, SSH private key nor environment secrets, so
+the environment cannot be managed from it.
+
+1. Ask for a bundle exported with a passphrase:
+   torrust-tracker-deployer export <name> --passphrase-env <VAR>
+
+For more information, see docs/user-guide/commands/import-bundle.md"
+            }
+            Self::PassphraseRequired { .. } => {
+                "Passphrase Required - Troubleshooting:
+
+The state, the SSH private key and the secrets of the bundle are
+encrypted with the passphrase given to export.
+
+1. Put the passphrase in an environment variable and name it:
+   torrust-tracker-deployer import-bundle <file> --passphrase-env <VAR>
+
+For more information, see docs/user-guide/commands/import-bundle.md"
+            }
+            Self::WrongPassphrase { .. } => {
+                "Wrong Passphrase - Troubleshooting:
+
+1. Check the variable named by --passphrase-env holds the passphrase given
+   to export (without a trailing newline)
+2. Ask the person who exported the environment for the passphrase
+
+For more information, see docs/user-guide/commands/import-bundle.md"
+            }
+            Self::FileSystem { .. } => {
+                "File System Error - Troubleshooting:
+
+The directories created for the environment were removed.
+
+1. Check file system permissions for the data/ and build/ directories
+2. Verify sufficient disk space is available
+3. data/ and build/ must be on file systems where a rename is possible
+
+For more information, see docs/user-guide/commands/import-bundle.md"
+            }
+            Self::PersistenceFailed { .. } | Self::RepositoryError(_) => {
+                "Repository Operation Failed - Troubleshooting:
+
+1. Check file system permissions for the data/ directory
+2. Verify sufficient disk space is available
+3. Check whether another process holds the environment lock
+
+For more information, see docs/user-guide/commands/import-bundle.md"
+            }
+        }
+    }
+}
@@ -0,0 +1,583 @@
+//! Import bundle command handler implementation
+//!
+//! **Purpose**: Add an environment exported on another machine to this
+//! workspace, so it can be managed from here
+//!
+//! ## Design Strategy
+//!
+//! 1. **Validation**: Extract the bundle to a staging directory, verify its
+//!    checksums and decrypt its state and secrets before writing to the
+//!    workspace. Bundles exported without secrets are refused
+//! 2. **Uniqueness**: Refuse a name that is already in use, as an
+//!    environment or as a leftover directory
+//! 3. **Relocation**: Move the build directory and the SSH keys to the
+//!    directories of the environment in this workspace, and point the
+//!    environment to them
+//! 4. **Persistence**: Save the environment, removing the directories
+//!    created for it if anything fails
+
+use std::fs;
+use std::io::Write;
+use std::path::{Path, PathBuf};
+use std::sync::Arc;
+
+use tracing::{error, info, instrument};
+
+use super::errors::ImportBundleCommandHandlerError;
+use crate::domain::environment::repository::EnvironmentRepository;
+use crate::domain::environment::{
+    AnyEnvironmentState, EnvironmentName, EnvironmentSecrets, WorkspaceLayout,
+};
+use crate::infrastructure::bundle::manifest::{
+    BUILD_DIR, ENCRYPTED_EXTENSION, ENCRYPTED_STATE_FILE, SECRETS_FILE,
+};
+use crate::infrastructure::bundle::{
+    decrypt, extract_bundle, BundleError, BundleManifest, BundleSecrets,
+};
+use crate::infrastructure::persistence::stored_environment::StoredEnvironment;
+use crate::shared::secrets::Password;
+
+/// Summary of an imported bundle
+#[derive(Debug, Clone)]
+pub struct ImportedBundle {
+    /// The imported environment, as saved in this workspace
+    pub environment: AnyEnvironmentState,
+
+    /// The manifest of the bundle
+    pub manifest: BundleManifest,
+}
+
+impl ImportedBundle {
+    /// Whether the SSH private key was imported with the bundle
+    #[must_use]
+    pub fn private_key_imported(&self) -> bool {
+        self.manifest.ssh_private_key.is_some()
+    }
+}
+
+/// Secrets decrypted from a bundle
+struct DecryptedSecrets {
+    state: Vec<u8>,
+    private_key: Option<Vec<u8>>,
+    environment: Option<EnvironmentSecrets>,
+}
+
+/// `ImportBundleCommandHandler` adds an exported environment to the workspace
+pub struct ImportBundleCommandHandler {
+    repository: Arc<dyn EnvironmentRepository + Send + Sync>,
+    layout: WorkspaceLayout,
+}
+
+impl ImportBundleCommandHandler {
+    /// Create a new `ImportBundleCommandHandler`
+    ///
+    /// # Arguments
+    ///
+    /// * `repository` - Repository for saving the imported environment
+    /// * `working_dir` - Root directory containing `data/` and `build/` subdirectories
+    #[must_use]
+    pub fn new(
+        repository: Arc<dyn EnvironmentRepository + Send + Sync>,
+        working_dir: PathBuf,
+    ) -> Self {
+        Self::with_layout(repository, WorkspaceLayout::in_working_dir(working_dir))
+    }
+
+    /// Create a new `ImportBundleCommandHandler` with explicit data and build roots
+    ///
+    /// # Arguments
+    ///
+    /// * `repository` - Repository for saving the imported environment
+    /// * `layout` - Roots of the per-environment data and build directories
+    #[must_use]
+    pub fn with_layout(
+        repository: Arc<dyn EnvironmentRepository + Send + Sync>,
+        layout: WorkspaceLayout,
+    ) -> Self {
+        Self { repository, layout }
+    }
+
+    /// Import the environment in the bundle file `bundle`
+    ///
+    /// The `passphrase` decrypts the state and the secrets of the bundle.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if:
+    /// * The bundle does not exist, is corrupted or fails checksum validation
+    /// * An environment or a directory with the same name already exists
+    /// * The bundle was exported without secrets
+    /// * The passphrase is missing or wrong
+    /// * A directory cannot be written, or the environment cannot be saved
+    ///   (the directories created for it are removed)
+    #[instrument(
+        name = "command.import_bundle",
+        skip_all,
+        fields(
+            command_type = "import-bundle",
+            bundle = %bundle.display()
+        )
+    )]
+    pub fn execute(
+        &self,
+        bundle: &Path,
+        passphrase: Option<&Password>,
+    ) -> Result<ImportedBundle, ImportBundleCommandHandlerError> {
+        if !bundle.is_file() {
+            return Err(ImportBundleCommandHandlerError::BundleNotFound {
+                path: bundle.to_path_buf(),
+            });
+        }
+
+        let staging = self.staging_dir()?;
+        let manifest = extract_bundle(bundle, staging.path()).map_err(|source| {
+            ImportBundleCommandHandlerError::InvalidBundle {
+                path: bundle.to_path_buf(),
+                source,
+            }
+        })?;
+
+        let secrets = Self::decrypt_secrets(bundle, staging.path(), &manifest, passphrase)?;
+
+        let mut environment = Self::read_state(bundle, &secrets.state)?;
+        if environment.name().as_str() != manifest.environment {
+            return Err(ImportBundleCommandHandlerError::NameMismatch {
+                manifest: manifest.environment.clone(),
+                state: environment.name().to_string(),
+            });
+        }
+
+        let name = environment.name().clone();
+        let _lock = self.repository.lock(&name)?;
+        self.verify_name_available(&name)?;
+
+        let mut created = CreatedDirectories::default();
+        let (private_key, public_key) = match self.install_files(
+            &mut created,
+            &environment,
+            staging.path(),
+            &manifest,
+            secrets.private_key.as_deref(),
+        ) {
+            Ok(paths) => paths,
+            Err(e) => {
+                created.remove();
+                return Err(e);
+            }
+        };
+
+        environment.relocate(&self.layout, private_key, public_key);
+        if let Some(environment_secrets) = secrets.environment {
+            environment.restore_secrets(environment_secrets);
+        }
+
+        if let Err(e) = self.repository.save(&environment) {
+            created.remove();
+            return Err(ImportBundleCommandHandlerError::PersistenceFailed {
+                name: name.to_string(),
+                source: e.into(),
+            });
+        }
+
+        info!(
+            command = "import-bundle",
+            environment = %name,
+            state = environment.state_name(),
+            secrets = ?manifest.secrets,
+            exported_at = %manifest.exported_at,
+            "Environment imported successfully"
+        );
+
+        Ok(ImportedBundle {
+            environment,
+            manifest,
+        })
+    }
+
+    /// Create the staging directory in the data root, cleaned up when dropped
+    fn staging_dir(&self) -> Result<tempfile::TempDir, ImportBundleCommandHandlerError> {
+        let data_dir = self.layout.data_dir();
+
+        fs::create_dir_all(data_dir).map_err(file_system_error(data_dir))?;
+        tempfile::Builder::new()
+            .prefix(".import-bundle-")
+            .tempdir_in(data_dir)
+            .map_err(file_system_error(data_dir))
+    }
+
+    /// Read and migrate the decrypted environment state of the bundle
+    fn read_state(
+        bundle: &Path,
+        content: &[u8],
+    ) -> Result<AnyEnvironmentState, ImportBundleCommandHandlerError> {
+        let invalid_state = |reason: String| ImportBundleCommandHandlerError::InvalidState {
+            path: bundle.to_path_buf(),
+            reason,
+        };
+
+        let value = serde_json::from_slice(content).map_err(|e| invalid_state(e.to_string()))?;
+
+        StoredEnvironment::from_value(value)
+            .map(StoredEnvironment::into_state)
+            .map_err(|e| invalid_state(e.to_string()))
+    }
+
+    /// Verify no environment or leftover directory uses the name
+    fn verify_name_available(
+        &self,
+        name: &EnvironmentName,
+    ) -> Result<(), ImportBundleCommandHandlerError> {
+        if self.repository.exists(name)? {
+            return Err(ImportBundleCommandHandlerError::EnvironmentAlreadyExists {
+                name: name.to_string(),
+            });
+        }
+
+        for path in [
+            self.layout.environment_data_dir(name),
+            self.layout.environment_build_dir(name),
+        ] {
+            if path.exists() {
+                return Err(ImportBundleCommandHandlerError::TargetDirectoryExists { path });
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Decrypt the state, the SSH private key and the environment secrets
+    ///
+    /// The build files holding secrets are decrypted in place, in the
+    /// staging directory.
+    fn decrypt_secrets(
+        bundle: &Path,
+        staging: &Path,
+        manifest: &BundleManifest,
+        passphrase: Option<&Password>,
+    ) -> Result<DecryptedSecrets, ImportBundleCommandHandlerError> {
+        if manifest.secrets == BundleSecrets::Excluded {
+            return Err(ImportBundleCommandHandlerError::SecretsExcluded {
+                path: bundle.to_path_buf(),
+            });
+        }
+
+        let passphrase =
+            passphrase.ok_or_else(|| ImportBundleCommandHandlerError::PassphraseRequired {
+                path: bundle.to_path_buf(),
+            })?;
+        let decrypt_file = |name: &str| {
+            let content = fs::read(staging.join(name)).map_err(|source| {
+                ImportBundleCommandHandlerError::InvalidBundle {
+                    path: bundle.to_path_buf(),
+                    source: BundleError::Io {
+                        path: PathBuf::from(name),
+                        source,
+                    },
+                }
+            })?;
+            decrypt(&content, passphrase).map_err(|_| {
+                ImportBundleCommandHandlerError::WrongPassphrase {
+                    path: bundle.to_path_buf(),
+                }
+            })
+        };
+
+        let state = decrypt_file(ENCRYPTED_STATE_FILE)?;
+
+        let encrypted_suffix = format!(".{ENCRYPTED_EXTENSION}");
+        for file in &manifest.files {
+            let Some(name) = file
+                .path
+                .strip_prefix(&format!("{BUILD_DIR}/"))
+                .and_then(|name| name.strip_suffix(&encrypted_suffix))
+            else {
+                continue;
+            };
+            let content = decrypt_file(&file.path)?;
+            let path = staging.join(BUILD_DIR).join(name);
+            write_private_file(&path, &content).map_err(file_system_error(&path))?;
+            fs::remove_file(staging.join(&file.path))
+                .map_err(file_system_error(&staging.join(&file.path)))?;
+        }
+
+        let private_key = manifest
+            .ssh_private_key
+            .as_deref()
+            .map(decrypt_file)
+            .transpose()?;
+
+        let environment = manifest
+            .file(SECRETS_FILE)
+            .map(|file| decrypt_file(&file.path))
+            .transpose()?
+            .map(|content| serde_json::from_slice(&content))
+            .transpose()
+            .map_err(|e| ImportBundleCommandHandlerError::InvalidState {
+                path: bundle.to_path_buf(),
+                reason: format!("{SECRETS_FILE}: {e}"),
+            })?;
+
+        Ok(DecryptedSecrets {
+            state,
+            private_key,
+            environment,
+        })
+    }
+
+    /// Move the build directory and write the SSH keys of the environment
+    ///
+    /// Returns the paths of the private and public keys in this workspace.
+    fn install_files(
+        &self,
+        created: &mut CreatedDirectories,
+        environment: &AnyEnvironmentState,
+        staging: &Path,
+        manifest: &BundleManifest,
+        private_key: Option<&[u8]>,
+    ) -> Result<(PathBuf, PathBuf), ImportBundleCommandHandlerError> {
+        let name = environment.name();
+        let data_dir = self.layout.environment_data_dir(name);
+        fs::create_dir_all(&data_dir).map_err(file_system_error(&data_dir))?;
+        created.push(data_dir);
+
+        let staged_build = staging.join(BUILD_DIR);
+        if staged_build.is_dir() {
+            let build_dir = self.layout.environment_build_dir(name);
+            fs::create_dir_all(self.layout.build_dir())
+                .map_err(file_system_error(self.layout.build_dir()))?;
+            fs::rename(&staged_build, &build_dir).map_err(file_system_error(&build_dir))?;
+            created.push(build_dir);
+        }
+
+        let ssh_dir = self.layout.environment_ssh_dir(name);
+        fs::create_dir_all(&ssh_dir).map_err(file_system_error(&ssh_dir))?;
+
+        let credentials = environment.ssh_credentials();
+        let private_key_path = ssh_dir.join(key_file_name(&credentials.ssh_priv_key_path));
+        let public_key_path = ssh_dir.join(key_file_name(&credentials.ssh_pub_key_path));
+
+        if let Some(staged) = &manifest.ssh_public_key {
+            fs::rename(staging.join(staged), &public_key_path)
+                .map_err(file_system_error(&public_key_path))?;
+        }
+
+        if let Some(key) = private_key {
+            write_private_file(&private_key_path, key)
+                .map_err(file_system_error(&private_key_path))?;
+        }
+
+        Ok((private_key_path, public_key_path))
+    }
+}
+
+/// File name of a key path, kept for the imported key
+fn key_file_name(path: &Path) -> std::ffi::OsString {
+    path.file_name()
+        .map_or_else(|| "id".into(), std::ffi::OsStr::to_os_string)
+}
+
+/// Map an I/O error on `path` to `ImportBundleCommandHandlerError::FileSystem`
+fn file_system_error(
+    path: &Path,
+) -> impl FnOnce(std::io::Error) -> ImportBundleCommandHandlerError + '_ {
+    move |source| ImportBundleCommandHandlerError::FileSystem {
+        path: path.to_path_buf(),
+        source,
+    }
+}
+
+/// Write a file readable only by its owner
+fn write_private_file(path: &Path, content: &[u8]) -> std::io::Result<()> {
+    let mut options = fs::OpenOptions::new();
+    options.write(true).create_new(true);
+
+    #[cfg(unix)]
+    {
+        use std::os::unix::fs::OpenOptionsExt;
+        options.mode(0o600);
+    }
+
+    options.open(path)?.write_all(content)
+}
+
+/// Directories created for the imported environment, removed on failure
+#[derive(Default)]
+struct CreatedDirectories {
+    paths: Vec<PathBuf>,
+}
+
+impl CreatedDirectories {
+    fn push(&mut self, path: PathBuf) {
+        self.paths.push(path);
+    }
+
+    /// Remove every directory created so far
+    ///
+    /// A directory that cannot be removed is logged and left in place.
+    fn remove(self) {
+        for path in self.paths.into_iter().rev() {
+            if let Err(e) = fs::remove_dir_all(&path) {
+                error!(
+                    command = "import-bundle",
+                    path = %path.display(),
+                    error = %e,
+                    "Failed to remove environment directory: remove it manually"
+                );
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use tempfile::TempDir;
+
+    use super::*;
+    use crate::application::command_handlers::create::config::EnvironmentCreationConfig;
+    use crate::application::command_handlers::{CreateCommandHandler, ExportCommandHandler};
+    use crate::infrastructure::persistence::file_repository_factory::FileRepositoryFactory;
+    use crate::shared::SystemClock;
+
+    fn repository(working_dir: &Path) -> Arc<dyn EnvironmentRepository + Send + Sync> {
+        FileRepositoryFactory::new(Duration::from_secs(30)).create(working_dir.join("data"))
+    }
+
+    /// Export the `prod` environment, with generated SSH keys and an `OpenTofu` state
+    fn export_environment(working_dir: &Path, passphrase: Option<&Password>) -> PathBuf {
+        let config = EnvironmentCreationConfig::builder()
+            .name("prod")
+            .generate_ssh_keys()
+            .provider_lxd("lxd-prod")
+            .sqlite("tracker.db")
+            .api("0.0.0.0:1212", "MyAccessToken")
+            .build()
+            .unwrap();
+        CreateCommandHandler::new(repository(working_dir), Arc::new(SystemClock))
+            .execute(config, working_dir)
+            .unwrap();
+        fs::create_dir_all(working_dir.join("build/prod/tofu/lxd")).unwrap();
+        fs::write(
+            working_dir.join("build/prod/tofu/lxd/terraform.tfstate"),
+            "{\"version\": 4}",
+        )
+        .unwrap();
+        fs::create_dir_all(working_dir.join("build/prod/docker-compose")).unwrap();
+        fs::write(
+            working_dir.join("build/prod/docker-compose/.env"),
+            "TRACKER_ADMIN_TOKEN='MyAccessToken'",
+        )
+        .unwrap();
+
+        let bundle = working_dir.join("prod-bundle.tar.gz");
+        ExportCommandHandler::new(repository(working_dir), Arc::new(SystemClock))
+            .execute(&EnvironmentName::new("prod").unwrap(), &bundle, passphrase)
+            .unwrap();
+        bundle
+    }
+
+    #[test]
+    fn it_should_import_an_exported_environment_into_another_workspace() {
+        let machine_a = TempDir::new().unwrap();
+        let machine_b = TempDir::new().unwrap();
+        let passphrase = Password::new("shared secret");
+        let bundle = export_environment(machine_a.path(), Some(&passphrase));
+        let handler =
+            ImportBundleCommandHandler::new(repository(machine_b.path()), machine_b.path().into());
+
+        let imported = handler.execute(&bundle, Some(&passphrase)).unwrap();
+
+        let environment = imported.environment;
+        let credentials = environment.ssh_credentials();
+        assert!(imported.manifest.ssh_private_key.is_some());
+        assert_eq!(environment.data_dir(), &machine_b.path().join("data/prod"));
+        assert!(credentials
+            .ssh_priv_key_path
+            .starts_with(machine_b.path().join("data/prod/ssh")));
+        assert_eq!(
+            fs::read(&credentials.ssh_priv_key_path).unwrap(),
+            fs::read(
+                machine_a
+                    .path()
+                    .join("data/prod/ssh")
+                    .join(credentials.ssh_priv_key_path.file_name().unwrap())
+            )
+            .unwrap()
+        );
+        assert!(credentials.ssh_pub_key_path.is_file());
+        assert_eq!(
+            fs::read_to_string(
+                machine_b
+                    .path()
+                    .join("build/prod/tofu/lxd/terraform.tfstate")
+            )
+            .unwrap(),
+            "{\"version\": 4}"
+        );
+        assert_eq!(
+            fs::read_to_string(machine_b.path().join("build/prod/docker-compose/.env")).unwrap(),
+            "TRACKER_ADMIN_TOKEN='MyAccessToken'"
+        );
+        assert!(!machine_b
+            .path()
+            .join("build/prod/docker-compose/.env.age")
+            .exists());
+        assert!(handler
+            .repository
+            .exists(&EnvironmentName::new("prod").unwrap())
+            .unwrap());
+    }
+
+    #[test]
+    fn it_should_refuse_a_bundle_for_an_environment_that_already_exists() {
+        let machine_a = TempDir::new().unwrap();
+        let passphrase = Password::new("shared secret");
+        let bundle = export_environment(machine_a.path(), Some(&passphrase));
+        let handler =
+            ImportBundleCommandHandler::new(repository(machine_a.path()), machine_a.path().into());
+
+        let result = handler.execute(&bundle, Some(&passphrase));
+
+        assert!(matches!(
+            result,
+            Err(ImportBundleCommandHandlerError::EnvironmentAlreadyExists { .. })
+        ));
+    }
+
+    #[test]
+    fn it_should_refuse_a_wrong_passphrase_without_writing_to_the_workspace() {
+        let machine_a = TempDir::new().unwrap();
+        let machine_b = TempDir::new().unwrap();
+        let bundle = export_environment(machine_a.path(), Some(&Password::new("right")));
+        let handler =
+            ImportBundleCommandHandler::new(repository(machine_b.path()), machine_b.path().into());
+
+        let result = handler.execute(&bundle, Some(&Password::new("wrong")));
+
+        assert!(matches!(
+            result,
+            Err(ImportBundleCommandHandlerError::WrongPassphrase { .. })
+        ));
+        assert!(!machine_b.path().join("data/prod").exists());
+        assert!(!machine_b.path().join("build/prod").exists());
+    }
+
+    #[test]
+    fn it_should_refuse_a_bundle_exported_without_secrets() {
+        let machine_a = TempDir::new().unwrap();
+        let machine_b = TempDir::new().unwrap();
+        let bundle = export_environment(machine_a.path(), None);
+        let handler =
+            ImportBundleCommandHandler::new(repository(machine_b.path()), machine_b.path().into());
+
+        let result = handler.execute(&bundle, None);
+
+        assert!(matches!(
+            result,
+            Err(ImportBundleCommandHandlerError::SecretsExcluded { .. })
+        ));
+        assert!(!machine_b.path().join("data/prod").exists());
+        assert!(!machine_b.path().join("build/prod").exists());
+    }
+}
@@ -0,0 +1,29 @@
+//! Import Bundle Command Module
+//!
+//! This module implements the delivery-agnostic `ImportBundleCommandHandler`
+//! for adding an environment written by `export` on another machine to this
+//! workspace.
+//!
+//! ## What Is Imported
+//!
+//! - The environment state, migrated to the current schema if needed
+//! - The build directory, including the `OpenTofu` state, to `build/{env}`
+//! - The SSH key pair, to `data/{env}/ssh`, the private key decrypted with
+//!   the passphrase when the bundle includes secrets
+//! - The environment secrets (e.g. the Grafana admin password), when included
+//!
+//! The imported environment manages the same infrastructure as the exported
+//! one: commands such as `configure` and `destroy` work from this machine.
+//!
+//! ## Atomicity
+//!
+//! The bundle is extracted and validated in a staging directory first. The
+//! directories of the environment are only created once it is valid, and
+//! are removed again if saving the environment fails.
+
+pub mod errors;
+pub mod handler;
+
+// Re-export main types for convenience
+pub use errors::ImportBundleCommandHandlerError;
+pub use handler::{ImportBundleCommandHandler, ImportedBundle};
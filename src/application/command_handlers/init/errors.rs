@@ -0,0 +1,269 @@
+//! Error types for the init command handler and workspace verification
+
+use std::path::PathBuf;
+
+use crate::infrastructure::persistence::workspace_key::WorkspaceKeyError;
+use crate::shared::error::kind::ErrorKind;
+use crate::shared::error::traceable::Traceable;
+
+/// Errors of a working directory that is not a usable workspace
+#[derive(Debug, thiserror::Error)]
+pub enum WorkspaceError {
+    /// The directory was not initialized
+    #[error(
+        "'{path}' is not an initialized workspace
+Tip: Run 'torrust-tracker-deployer init' first"
+    )]
+    NotInitialized { path: PathBuf },
+
+    /// The directory holds environments created before workspace markers
+    #[error(
+        "'{path}' holds {environment_count} environment(s) but no workspace marker
+Tip: Run 'torrust-tracker-deployer init' to migrate it"
+    )]
+    LegacyWorkspace {
+        path: PathBuf,
+        environment_count: usize,
+    },
+
+    /// The marker file cannot be read or parsed
+    #[error("Invalid workspace marker '{path}': {reason}")]
+    InvalidMarker { path: PathBuf, reason: String },
+
+    /// The workspace was written by a newer version of the deployer
+    #[error("Workspace marker '{path}' has unsupported format version {format_version}")]
+    UnsupportedFormat { path: PathBuf, format_version: u32 },
+}
+
+impl WorkspaceError {
+    /// Provides detailed troubleshooting guidance for this error
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use std::path::PathBuf;
+    /// use torrust_tracker_deployer_lib::application::command_handlers::init::WorkspaceError;
+    ///
+    /// let error = WorkspaceError::NotInitialized {
+    ///     path: PathBuf::from("/tmp/empty"),
+    /// };
+    ///
+    /// assert!(error.help().contains("torrust-tracker-deployer init"));
+    /// ```
+    #[must_use]
+    pub fn help(&self) -> &'static str {
+        match self {
+            Self::NotInitialized { .. } => {
+                "Workspace Not Initialized - Troubleshooting:
+
+The working directory has no workspace marker (deployer-workspace.json).
+
+1. Initialize the workspace, creating data/, build/ and the marker:
+   torrust-tracker-deployer init
+
+2. Or point the command at an existing workspace:
+   torrust-tracker-deployer --workspace <path> <command>
+
+3. Or run the command anyway (e.g. in scripts that manage the layout):
+   torrust-tracker-deployer --allow-uninitialized <command>
+
+For more information, see docs/user-guide/commands/init.md"
+            }
+            Self::LegacyWorkspace { .. } => {
+                "Workspace Without Marker - Troubleshooting:
+
+The working directory holds environments created by a version of the
+deployer that did not write a workspace marker.
+
+1. Migrate the workspace: init checks that every environment loads, then
+   adds the marker without changing the environments:
+   torrust-tracker-deployer init
+
+2. Or run the command anyway:
+   torrust-tracker-deployer --allow-uninitialized <command>
+
+For more information, see docs/user-guide/commands/init.md"
+            }
+            Self::InvalidMarker { .. } => {
+                "Invalid Workspace Marker - Troubleshooting:
+
+1. Check the content of deployer-workspace.json, it should look like:
+   {\"format_version\": 1}
+
+2. Check file system permissions for the working directory
+3. Remove the damaged marker and run init again:
+   torrust-tracker-deployer init
+
+For more information, see docs/user-guide/commands/init.md"
+            }
+            Self::UnsupportedFormat { .. } => {
+                "Unsupported Workspace Format - Troubleshooting:
+
+The workspace was initialized by a newer version of the deployer.
+
+1. Upgrade torrust-tracker-deployer to the version that manages this workspace
+2. Or use a different working directory:
+   torrust-tracker-deployer --workspace <path> <command>
+
+For more information, see docs/user-guide/commands/init.md"
+            }
+        }
+    }
+}
+
+/// Comprehensive error type for the `InitCommandHandler`
+#[derive(Debug, thiserror::Error)]
+pub enum InitCommandHandlerError {
+    /// The existing marker cannot be used
+    #[error("{0}")]
+    Workspace(#[from] WorkspaceError),
+
+    /// Environments of an existing workspace fail to load
+    #[error(
+        "Cannot migrate the workspace, {} environment(s) fail to load: {}",
+        failures.len(),
+        failures.iter().map(|(name, _)| name.as_str()).collect::<Vec<_>>().join(", ")
+    )]
+    InvalidEnvironments {
+        /// Name and load error of each environment
+        failures: Vec<(String, String)>,
+    },
+
+    /// A directory of the layout could not be created
+    #[error("Failed to create directory '{path}': {source}")]
+    DirectoryCreationFailed {
+        path: PathBuf,
+        #[source]
+        source: std::io::Error,
+    },
+
+    /// The marker file could not be written
+    #[error("Failed to write workspace marker '{path}': {source}")]
+    MarkerWriteFailed {
+        path: PathBuf,
+        #[source]
+        source: std::io::Error,
+    },
+
+    /// The workspace key could not be created or read
+    #[error("{0}")]
+    WorkspaceKey(#[source] WorkspaceKeyError),
+
+    /// The plaintext secrets of the environments could not be sealed
+    #[error("Failed to seal the secrets of the environments, {reason}")]
+    SealingFailed { reason: String },
+}
+
+impl Traceable for InitCommandHandlerError {
+    fn trace_format(&self) -> String {
+        match self {
+            Self::Workspace(e) => format!("InitCommandHandlerError: Invalid workspace - {e}"),
+            Self::InvalidEnvironments { failures } => {
+                format!(
+                    "InitCommandHandlerError: Invalid environments - {}",
+                    failures
+                        .iter()
+                        .map(|(name, error)| format!("'{name}': {error}"))
+                        .collect::<Vec<_>>()
+                        .join("; ")
+                )
+            }
+            Self::DirectoryCreationFailed { path, source } => {
+                format!(
+                    "InitCommandHandlerError: Directory creation failed - '{}': {source}",
+                    path.display()
+                )
+            }
+            Self::MarkerWriteFailed { path, source } => {
+                format!(
+                    "InitCommandHandlerError: Marker write failed - '{}': {source}",
+                    path.display()
+                )
+            }
+            Self::WorkspaceKey(e) => format!("InitCommandHandlerError: Workspace key - {e}"),
+            Self::SealingFailed { reason } => {
+                format!("InitCommandHandlerError: Sealing failed - {reason}")
+            }
+        }
+    }
+
+    fn trace_source(&self) -> Option<&dyn Traceable> {
+        None
+    }
+
+    fn error_kind(&self) -> ErrorKind {
+        match self {
+            Self::Workspace(_) | Self::InvalidEnvironments { .. } => ErrorKind::Configuration,
+            Self::DirectoryCreationFailed { .. }
+            | Self::MarkerWriteFailed { .. }
+            | Self::WorkspaceKey(_)
+            | Self::SealingFailed { .. } => ErrorKind::FileSystem,
+        }
+    }
+}
+
+impl InitCommandHandlerError {
+    /// Provides detailed troubleshooting guidance for this error
+    #[must_use]
+    pub fn help(&self) -> &'static str {
+        match self {
+            Self::Workspace(e) => e.help(),
+            Self::InvalidEnvironments { .. } => {
+                "Invalid Environments - Troubleshooting:
+
+The workspace is only marked as initialized when all its environments load,
+so that commands do not start failing on it afterwards.
+
+1. Inspect the state of each listed environment:
+   torrust-tracker-deployer --allow-uninitialized repair <name> --list
+
+2. Restore a readable state from a backup:
+   torrust-tracker-deployer --allow-uninitialized repair <name>
+
+3. Or move the environment directory out of data/ if it is not needed
+
+Then run init again.
+
+For more information, see docs/user-guide/commands/init.md"
+            }
+            Self::DirectoryCreationFailed { .. } | Self::MarkerWriteFailed { .. } => {
+                "Workspace Initialization Failed - Troubleshooting:
+
+1. Check file system permissions for the working directory
+2. Verify sufficient disk space is available
+3. Check that data/ and build/ are not existing files
+
+For more information, see docs/user-guide/commands/init.md"
+            }
+            Self::WorkspaceKey(_) => {
+                "Workspace Key Failed - Troubleshooting:
+
+The workspace key encrypts the secrets of the environments. It is kept
+outside the workspace, in ~/.config/torrust-deployer/workspace.key unless
+TORRUST_DEPLOYER_WORKSPACE_KEY gives another path.
+
+1. Check file system permissions for the key file and its directory
+2. If the key file is damaged, restore it from your backup: environments
+   sealed with a lost key cannot be loaded again
+3. Only remove the key file if no environment was sealed with it
+
+For more information, see docs/user-guide/commands/init.md"
+            }
+            Self::SealingFailed { .. } => {
+                "Sealing Secrets Failed - Troubleshooting:
+
+init encrypts the secrets still stored in plaintext in the environments.
+
+1. Check file system permissions for the data/ directory
+2. Check that the environment loads:
+   torrust-tracker-deployer show <name>
+3. If its secrets were sealed with another workspace key, restore that key
+   or point TORRUST_DEPLOYER_WORKSPACE_KEY at it
+
+Then run init again.
+
+For more information, see docs/user-guide/commands/init.md"
+            }
+        }
+    }
+}
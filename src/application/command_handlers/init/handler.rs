@@ -0,0 +1,378 @@
+//! Init command handler implementation
+//!
+//! **Purpose**: Turn a directory into a deployer workspace
+//!
+//! ## Design Strategy
+//!
+//! 1. **Idempotency**: A workspace with a supported marker is left as it is,
+//!    only its missing `data/` and `build/` directories are recreated
+//! 2. **Migration**: Environments created before workspace markers must all
+//!    load before the marker is added, so commands keep working afterwards
+//! 3. **Layout**: Create `data/` and `build/`, then write the marker last, so
+//!    an interrupted init leaves the directory uninitialized
+//! 4. **Secrets**: With a workspace key, create the key when missing and seal
+//!    the secrets still stored in plaintext, on every run
+
+use std::fs;
+use std::path::PathBuf;
+use std::sync::Arc;
+
+use tracing::{info, instrument};
+
+use super::errors::{InitCommandHandlerError, WorkspaceError};
+use super::info::{SecretSealing, WorkspaceInitialization};
+use super::workspace::{environment_names, marker_path, read_marker};
+use crate::domain::environment::repository::EnvironmentRepository;
+use crate::domain::environment::{EnvironmentName, WorkspaceLayout, WorkspaceMarker};
+use crate::infrastructure::persistence::workspace_key::WorkspaceKey;
+
+/// `InitCommandHandler` creates the canonical layout of a workspace
+pub struct InitCommandHandler {
+    repository: Arc<dyn EnvironmentRepository + Send + Sync>,
+    working_dir: PathBuf,
+    layout: WorkspaceLayout,
+    workspace_key: Option<Arc<WorkspaceKey>>,
+}
+
+impl InitCommandHandler {
+    /// Create a new `InitCommandHandler`
+    ///
+    /// # Arguments
+    ///
+    /// * `repository` - Repository for loading the existing environments
+    /// * `working_dir` - Root directory of the workspace
+    #[must_use]
+    pub fn new(
+        repository: Arc<dyn EnvironmentRepository + Send + Sync>,
+        working_dir: PathBuf,
+    ) -> Self {
+        let layout = WorkspaceLayout::in_working_dir(&working_dir);
+        Self::with_layout(repository, working_dir, layout)
+    }
+
+    /// Create a new `InitCommandHandler` with explicit data and build roots
+    ///
+    /// # Arguments
+    ///
+    /// * `repository` - Repository for loading the existing environments
+    /// * `working_dir` - Root directory of the workspace, holding the marker
+    /// * `layout` - Roots of the per-environment data and build directories
+    #[must_use]
+    pub fn with_layout(
+        repository: Arc<dyn EnvironmentRepository + Send + Sync>,
+        working_dir: PathBuf,
+        layout: WorkspaceLayout,
+    ) -> Self {
+        Self {
+            repository,
+            working_dir,
+            layout,
+            workspace_key: None,
+        }
+    }
+
+    /// Create `workspace_key` and seal the plaintext secrets of the
+    /// environments with it
+    ///
+    /// The repository must seal its secrets with the same key.
+    #[must_use]
+    pub fn with_workspace_key(mut self, workspace_key: Arc<WorkspaceKey>) -> Self {
+        self.workspace_key = Some(workspace_key);
+        self
+    }
+
+    /// Initialize the workspace
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if:
+    /// * An existing marker cannot be read or has an unsupported version
+    /// * An existing environment fails to load (nothing is written)
+    /// * The directories or the marker cannot be written
+    /// * The workspace key cannot be created, or the secrets of an environment
+    ///   cannot be sealed
+    #[instrument(
+        name = "command.init",
+        skip_all,
+        fields(
+            command_type = "init",
+            working_dir = %self.working_dir.display()
+        )
+    )]
+    pub fn execute(&self) -> Result<WorkspaceInitialization, InitCommandHandlerError> {
+        if let Some(marker) = read_marker(&self.working_dir)? {
+            if !marker.is_supported() {
+                return Err(WorkspaceError::UnsupportedFormat {
+                    path: marker_path(&self.working_dir),
+                    format_version: marker.format_version,
+                }
+                .into());
+            }
+
+            self.create_layout_directories()?;
+            let sealing = self.seal_secrets()?;
+
+            info!("Workspace already initialized");
+
+            return Ok(self.initialization(marker, true, Vec::new(), sealing));
+        }
+
+        let environments = environment_names(self.layout.data_dir());
+        self.verify_environments_load(&environments)?;
+
+        self.create_layout_directories()?;
+        let sealing = self.seal_secrets()?;
+        let marker = self.write_marker()?;
+
+        info!(
+            migrated_environments = environments.len(),
+            "Workspace initialized"
+        );
+
+        Ok(self.initialization(marker, false, environments, sealing))
+    }
+
+    /// Create the workspace key if missing, then seal the plaintext secrets
+    /// of every environment
+    fn seal_secrets(&self) -> Result<Option<SecretSealing>, InitCommandHandlerError> {
+        let Some(workspace_key) = &self.workspace_key else {
+            return Ok(None);
+        };
+
+        let key_created = workspace_key
+            .create_if_missing()
+            .map_err(InitCommandHandlerError::WorkspaceKey)?;
+
+        let sealing_failed = |reason: String| InitCommandHandlerError::SealingFailed { reason };
+        let names = self
+            .repository
+            .list()
+            .map_err(|e| sealing_failed(format!("cannot list the environments: {e}")))?;
+
+        let mut sealed_environments = Vec::new();
+        for name in names {
+            if self
+                .repository
+                .seal_secrets(&name)
+                .map_err(|e| sealing_failed(format!("environment '{name}': {e}")))?
+            {
+                sealed_environments.push(name.to_string());
+            }
+        }
+
+        if !sealed_environments.is_empty() {
+            info!(
+                sealed_environments = sealed_environments.len(),
+                "Plaintext secrets sealed with the workspace key"
+            );
+        }
+
+        Ok(Some(SecretSealing {
+            key_path: workspace_key.path().to_path_buf(),
+            key_created,
+            sealed_environments,
+        }))
+    }
+
+    /// Load every environment found, collecting those that fail
+    fn verify_environments_load(&self, names: &[String]) -> Result<(), InitCommandHandlerError> {
+        let failures: Vec<(String, String)> = names
+            .iter()
+            .filter_map(|name| {
+                self.load_environment(name)
+                    .err()
+                    .map(|error| (name.clone(), error))
+            })
+            .collect();
+
+        if failures.is_empty() {
+            Ok(())
+        } else {
+            Err(InitCommandHandlerError::InvalidEnvironments { failures })
+        }
+    }
+
+    /// Load a single environment by its directory name
+    fn load_environment(&self, name: &str) -> Result<(), String> {
+        let env_name = EnvironmentName::new(name.to_string())
+            .map_err(|e| format!("Invalid environment name: {e}"))?;
+
+        self.repository
+            .load(&env_name)
+            .map_err(|e| format!("Failed to load environment: {e}"))?
+            .map(|_| ())
+            .ok_or_else(|| format!("Environment '{env_name}' not found in repository"))
+    }
+
+    /// Create `data/` and `build/`, keeping their existing content
+    fn create_layout_directories(&self) -> Result<(), InitCommandHandlerError> {
+        for dir in [self.layout.data_dir(), self.layout.build_dir()] {
+            fs::create_dir_all(dir).map_err(|source| {
+                InitCommandHandlerError::DirectoryCreationFailed {
+                    path: dir.to_path_buf(),
+                    source,
+                }
+            })?;
+        }
+
+        Ok(())
+    }
+
+    /// Write the marker of the current format version
+    fn write_marker(&self) -> Result<WorkspaceMarker, InitCommandHandlerError> {
+        let marker = WorkspaceMarker::current();
+        let path = marker_path(&self.working_dir);
+        let content =
+            serde_json::to_string_pretty(&marker).expect("the workspace marker always serializes");
+
+        fs::write(&path, format!("{content}\n"))
+            .map_err(|source| InitCommandHandlerError::MarkerWriteFailed { path, source })?;
+
+        Ok(marker)
+    }
+
+    /// Describe the initialized workspace
+    fn initialization(
+        &self,
+        marker: WorkspaceMarker,
+        already_initialized: bool,
+        migrated_environments: Vec<String>,
+        secret_sealing: Option<SecretSealing>,
+    ) -> WorkspaceInitialization {
+        WorkspaceInitialization {
+            working_dir: self.working_dir.clone(),
+            data_dir: self.layout.data_dir().to_path_buf(),
+            build_dir: self.layout.build_dir().to_path_buf(),
+            format_version: marker.format_version,
+            already_initialized,
+            migrated_environments,
+            secret_sealing,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::path::Path;
+    use std::time::Duration;
+
+    use tempfile::TempDir;
+
+    use super::*;
+    use crate::application::command_handlers::create::config::EnvironmentCreationConfig;
+    use crate::application::command_handlers::init::verify_workspace;
+    use crate::application::command_handlers::CreateCommandHandler;
+    use crate::infrastructure::persistence::file_repository_factory::FileRepositoryFactory;
+    use crate::shared::SystemClock;
+
+    fn repository(working_dir: &Path) -> Arc<dyn EnvironmentRepository + Send + Sync> {
+        FileRepositoryFactory::new(Duration::from_secs(30)).create(working_dir.join("data"))
+    }
+
+    fn handler(working_dir: &Path) -> InitCommandHandler {
+        InitCommandHandler::new(repository(working_dir), working_dir.to_path_buf())
+    }
+
+    /// Create the `legacy` environment, as done before workspace markers
+    fn create_environment(working_dir: &Path) {
+        let config = EnvironmentCreationConfig::builder()
+            .name("legacy")
+            .generate_ssh_keys()
+            .provider_lxd("lxd-legacy")
+            .sqlite("tracker.db")
+            .api("0.0.0.0:1212", "MyAccessToken")
+            .build()
+            .unwrap();
+        CreateCommandHandler::new(repository(working_dir), Arc::new(SystemClock))
+            .execute(config, working_dir)
+            .unwrap();
+    }
+
+    #[test]
+    fn it_should_create_the_layout_and_the_marker_in_an_empty_directory() {
+        let temp_dir = TempDir::new().unwrap();
+        let working_dir = temp_dir.path().join("workspace");
+
+        let initialization = handler(&working_dir).execute().unwrap();
+
+        assert!(working_dir.join("data").is_dir());
+        assert!(working_dir.join("build").is_dir());
+        assert!(!initialization.already_initialized);
+        assert!(initialization.migrated_environments.is_empty());
+        let layout = WorkspaceLayout::in_working_dir(&working_dir);
+        assert_eq!(
+            verify_workspace(&working_dir, &layout).unwrap(),
+            WorkspaceMarker::current()
+        );
+    }
+
+    #[test]
+    fn it_should_leave_an_initialized_workspace_unchanged_when_run_again() {
+        let temp_dir = TempDir::new().unwrap();
+        handler(temp_dir.path()).execute().unwrap();
+        fs::remove_dir(temp_dir.path().join("build")).unwrap();
+
+        let initialization = handler(temp_dir.path()).execute().unwrap();
+
+        assert!(initialization.already_initialized);
+        assert!(temp_dir.path().join("build").is_dir());
+    }
+
+    #[test]
+    fn it_should_migrate_a_workspace_whose_environments_load() {
+        let temp_dir = TempDir::new().unwrap();
+        create_environment(temp_dir.path());
+
+        let initialization = handler(temp_dir.path()).execute().unwrap();
+
+        assert_eq!(initialization.migrated_environments, vec!["legacy"]);
+        assert!(marker_path(temp_dir.path()).is_file());
+    }
+
+    #[test]
+    fn it_should_not_mark_a_workspace_holding_an_environment_that_fails_to_load() {
+        let temp_dir = TempDir::new().unwrap();
+        create_environment(temp_dir.path());
+        fs::create_dir_all(temp_dir.path().join("data/broken")).unwrap();
+        fs::write(
+            temp_dir.path().join("data/broken/environment.json"),
+            "{ truncated",
+        )
+        .unwrap();
+
+        let result = handler(temp_dir.path()).execute();
+
+        match result {
+            Err(InitCommandHandlerError::InvalidEnvironments { failures }) => {
+                assert_eq!(failures.len(), 1);
+                assert_eq!(failures[0].0, "broken");
+            }
+            other => panic!("expected InvalidEnvironments, got {other:?}"),
+        }
+        assert!(!marker_path(temp_dir.path()).exists());
+    }
+
+    #[test]
+    fn it_should_create_the_workspace_key_and_seal_the_plaintext_secrets() {
+        let temp_dir = TempDir::new().unwrap();
+        create_environment(temp_dir.path());
+        let key_path = temp_dir.path().join("config/workspace.key");
+        let workspace_key = Arc::new(WorkspaceKey::new(&key_path));
+        let repository = FileRepositoryFactory::new(Duration::from_secs(30))
+            .with_workspace_key(Some(Arc::clone(&workspace_key)))
+            .create(temp_dir.path().join("data"));
+
+        let initialization = InitCommandHandler::new(repository, temp_dir.path().to_path_buf())
+            .with_workspace_key(workspace_key)
+            .execute()
+            .unwrap();
+
+        let sealing = initialization.secret_sealing.unwrap();
+        assert!(sealing.key_created);
+        assert!(key_path.is_file());
+        assert_eq!(sealing.sealed_environments, vec!["legacy"]);
+        let document =
+            fs::read_to_string(temp_dir.path().join("data/legacy/environment.json")).unwrap();
+        assert!(!document.contains("MyAccessToken"));
+    }
+}
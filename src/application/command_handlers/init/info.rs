@@ -0,0 +1,33 @@
+//! Result DTO of the init command
+
+use std::path::PathBuf;
+
+/// Outcome of initializing a workspace
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct WorkspaceInitialization {
+    /// Root of the workspace, holding the marker file
+    pub working_dir: PathBuf,
+    /// Directory holding one data directory per environment
+    pub data_dir: PathBuf,
+    /// Directory holding one build directory per environment
+    pub build_dir: PathBuf,
+    /// Format version recorded in the marker
+    pub format_version: u32,
+    /// Whether the marker was already present (nothing was written)
+    pub already_initialized: bool,
+    /// Environments found in a workspace created without marker, sorted by name
+    pub migrated_environments: Vec<String>,
+    /// Workspace key and sealed secrets, when the workspace has a key
+    pub secret_sealing: Option<SecretSealing>,
+}
+
+/// Secrets sealed with the workspace key by init
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SecretSealing {
+    /// Path of the workspace key file
+    pub key_path: PathBuf,
+    /// Whether the key file was created by this run
+    pub key_created: bool,
+    /// Environments whose plaintext secrets were sealed, sorted by name
+    pub sealed_environments: Vec<String>,
+}
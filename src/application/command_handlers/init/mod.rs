@@ -0,0 +1,41 @@
+//! Init Command Module
+//!
+//! This module implements the delivery-agnostic `InitCommandHandler` for
+//! turning a directory into a deployer workspace, and the verification the
+//! other commands run before operating on a working directory.
+//!
+//! ## Workspace Layout
+//!
+//! - `deployer-workspace.json` - Marker file recording the format version
+//! - `data/` - One state directory per environment
+//! - `build/` - One build directory per environment
+//!
+//! ## Migration
+//!
+//! Workspaces created before the marker existed hold environments but no
+//! marker. Running `init` on them checks that every environment loads and
+//! only then adds the marker, leaving the environments untouched.
+//!
+//! ## Secrets
+//!
+//! With a workspace key, `init` creates the key when it is missing and seals
+//! the secrets that environments still store in plaintext, including their
+//! state backups. This is the only change it makes to environments.
+//!
+//! ## Module Organization
+//!
+//! - `handler.rs` - Core handler with `execute()`
+//! - `workspace.rs` - Marker verification shared by the CLI and the SDK
+//! - `info.rs` - Result DTO
+//! - `errors.rs` - Error types for initialization and verification
+
+pub mod errors;
+pub mod handler;
+pub mod info;
+pub mod workspace;
+
+// Re-export main types for convenience
+pub use errors::{InitCommandHandlerError, WorkspaceError};
+pub use handler::InitCommandHandler;
+pub use info::{SecretSealing, WorkspaceInitialization};
+pub use workspace::{read_marker, verify_workspace};
@@ -0,0 +1,170 @@
+//! Workspace verification
+//!
+//! Checks that a working directory was initialized by the `init` command
+//! before a command operates on it. Shared by the CLI bootstrap and the SDK
+//! builder, so both refuse an unrecognized directory the same way.
+
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use super::errors::WorkspaceError;
+use crate::domain::environment::workspace_marker::WORKSPACE_MARKER_FILE_NAME;
+use crate::domain::environment::{WorkspaceLayout, WorkspaceMarker};
+
+/// Name of the state file inside an environment data directory
+const ENVIRONMENT_FILE_NAME: &str = "environment.json";
+
+/// Verify that `working_dir` is an initialized workspace this version supports
+///
+/// # Errors
+///
+/// Returns an error if:
+/// * The marker file is missing (`LegacyWorkspace` when the data directory
+///   already holds environments, so `init` can migrate it)
+/// * The marker file cannot be read or parsed
+/// * The workspace was written by a newer version of the deployer
+pub fn verify_workspace(
+    working_dir: &Path,
+    layout: &WorkspaceLayout,
+) -> Result<WorkspaceMarker, WorkspaceError> {
+    let Some(marker) = read_marker(working_dir)? else {
+        let environments = environment_names(layout.data_dir());
+
+        return Err(if environments.is_empty() {
+            WorkspaceError::NotInitialized {
+                path: working_dir.to_path_buf(),
+            }
+        } else {
+            WorkspaceError::LegacyWorkspace {
+                path: working_dir.to_path_buf(),
+                environment_count: environments.len(),
+            }
+        });
+    };
+
+    if !marker.is_supported() {
+        return Err(WorkspaceError::UnsupportedFormat {
+            path: marker_path(working_dir),
+            format_version: marker.format_version,
+        });
+    }
+
+    Ok(marker)
+}
+
+/// Returns the path of the marker file of `working_dir`
+#[must_use]
+pub fn marker_path(working_dir: &Path) -> PathBuf {
+    working_dir.join(WORKSPACE_MARKER_FILE_NAME)
+}
+
+/// Read the marker file of `working_dir`, if there is one
+///
+/// Unlike `verify_workspace`, a missing marker is not an error.
+///
+/// # Errors
+///
+/// Returns `WorkspaceError::InvalidMarker` if the marker file cannot be read
+/// or parsed.
+pub fn read_marker(working_dir: &Path) -> Result<Option<WorkspaceMarker>, WorkspaceError> {
+    let path = marker_path(working_dir);
+    if !path.exists() {
+        return Ok(None);
+    }
+
+    let content = fs::read_to_string(&path).map_err(|e| WorkspaceError::InvalidMarker {
+        path: path.clone(),
+        reason: e.to_string(),
+    })?;
+
+    serde_json::from_str(&content)
+        .map(Some)
+        .map_err(|e| WorkspaceError::InvalidMarker {
+            path,
+            reason: e.to_string(),
+        })
+}
+
+/// Names of the directories of `data_dir` holding an environment state file
+///
+/// Other entries, e.g. the `logs` directory, are ignored. A missing data
+/// directory has no environments.
+pub(crate) fn environment_names(data_dir: &Path) -> Vec<String> {
+    let Ok(entries) = fs::read_dir(data_dir) else {
+        return Vec::new();
+    };
+
+    let mut names: Vec<String> = entries
+        .filter_map(Result::ok)
+        .map(|entry| entry.path())
+        .filter(|path| path.join(ENVIRONMENT_FILE_NAME).is_file())
+        .filter_map(|path| path.file_name()?.to_str().map(ToString::to_string))
+        .collect();
+    names.sort();
+
+    names
+}
+
+#[cfg(test)]
+mod tests {
+    use tempfile::TempDir;
+
+    use super::*;
+
+    #[test]
+    fn it_should_refuse_an_empty_directory() {
+        let workspace = TempDir::new().unwrap();
+        let layout = WorkspaceLayout::in_working_dir(workspace.path());
+
+        let result = verify_workspace(workspace.path(), &layout);
+
+        assert!(matches!(result, Err(WorkspaceError::NotInitialized { .. })));
+    }
+
+    #[test]
+    fn it_should_ask_to_migrate_a_directory_holding_environments_without_marker() {
+        let workspace = TempDir::new().unwrap();
+        let layout = WorkspaceLayout::in_working_dir(workspace.path());
+        fs::create_dir_all(workspace.path().join("data/prod")).unwrap();
+        fs::write(workspace.path().join("data/prod/environment.json"), "{}").unwrap();
+        fs::create_dir_all(workspace.path().join("data/logs")).unwrap();
+
+        let result = verify_workspace(workspace.path(), &layout);
+
+        assert!(matches!(
+            result,
+            Err(WorkspaceError::LegacyWorkspace {
+                environment_count: 1,
+                ..
+            })
+        ));
+    }
+
+    #[test]
+    fn it_should_refuse_a_workspace_written_by_a_newer_version() {
+        let workspace = TempDir::new().unwrap();
+        let layout = WorkspaceLayout::in_working_dir(workspace.path());
+        fs::write(marker_path(workspace.path()), r#"{"format_version":99}"#).unwrap();
+
+        let result = verify_workspace(workspace.path(), &layout);
+
+        assert!(matches!(
+            result,
+            Err(WorkspaceError::UnsupportedFormat {
+                format_version: 99,
+                ..
+            })
+        ));
+    }
+
+    #[test]
+    fn it_should_accept_a_workspace_with_a_supported_marker() {
+        let workspace = TempDir::new().unwrap();
+        let layout = WorkspaceLayout::in_working_dir(workspace.path());
+        fs::write(marker_path(workspace.path()), r#"{"format_version":1}"#).unwrap();
+
+        let marker = verify_workspace(workspace.path(), &layout).unwrap();
+
+        assert_eq!(marker, WorkspaceMarker::current());
+    }
+}
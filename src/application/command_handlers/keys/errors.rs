@@ -0,0 +1,201 @@
+//! Error types for the keys command handler
+
+use crate::application::errors::{AllowedCommands, PersistenceError};
+use crate::infrastructure::tracker_api::TrackerApiError;
+use crate::shared::error::kind::ErrorKind;
+use crate::shared::error::traceable::Traceable;
+
+/// Comprehensive error type for the `KeysCommandHandler`
+#[derive(Debug, thiserror::Error)]
+pub enum KeysCommandHandlerError {
+    /// The environment does not exist
+    #[error("Environment not found: '{name}'")]
+    EnvironmentNotFound { name: String },
+
+    /// The tracker of the environment accepts announces without a key
+    #[error(
+        "The tracker of environment '{name}' is not private: it accepts announces without a key"
+    )]
+    TrackerNotPrivate { name: String },
+
+    /// The tracker API can only be called on a running tracker
+    #[error("Environment '{name}' is in state '{state}', but the tracker must be running to manage its keys; {allowed_commands}")]
+    EnvironmentNotRunning {
+        name: String,
+        state: String,
+        allowed_commands: AllowedCommands,
+    },
+
+    /// The environment has no instance to call
+    #[error("Environment '{name}' does not have an instance IP set")]
+    MissingInstanceIp { name: String },
+
+    /// The tracker API call failed
+    #[error("Tracker API call failed for environment '{name}': {source}")]
+    TrackerApi {
+        name: String,
+        #[source]
+        source: TrackerApiError,
+    },
+
+    /// Repository operation failed
+    #[error("Repository operation failed: {0}")]
+    RepositoryError(#[from] PersistenceError),
+}
+
+impl From<crate::domain::environment::repository::RepositoryError> for KeysCommandHandlerError {
+    fn from(e: crate::domain::environment::repository::RepositoryError) -> Self {
+        Self::RepositoryError(e.into())
+    }
+}
+
+impl Traceable for KeysCommandHandlerError {
+    fn trace_format(&self) -> String {
+        match self {
+            Self::EnvironmentNotFound { name } => {
+                format!("KeysCommandHandlerError: Environment not found - '{name}'")
+            }
+            Self::TrackerNotPrivate { name } => {
+                format!("KeysCommandHandlerError: Tracker not private - '{name}'")
+            }
+            Self::EnvironmentNotRunning { name, state, .. } => {
+                format!(
+                    "KeysCommandHandlerError: Environment '{name}' is not running (state: {state})"
+                )
+            }
+            Self::MissingInstanceIp { name } => {
+                format!("KeysCommandHandlerError: Missing instance IP for environment '{name}'")
+            }
+            Self::TrackerApi { name, source } => {
+                format!("KeysCommandHandlerError: Tracker API call failed for '{name}' - {source}")
+            }
+            Self::RepositoryError(e) => {
+                format!("KeysCommandHandlerError: Repository operation failed - {e}")
+            }
+        }
+    }
+
+    fn trace_source(&self) -> Option<&dyn Traceable> {
+        None
+    }
+
+    fn error_kind(&self) -> ErrorKind {
+        match self {
+            Self::EnvironmentNotFound { .. }
+            | Self::TrackerNotPrivate { .. }
+            | Self::EnvironmentNotRunning { .. }
+            | Self::MissingInstanceIp { .. } => ErrorKind::Configuration,
+            Self::TrackerApi { source, .. } => match source {
+                TrackerApiError::Unreachable { .. } => ErrorKind::NetworkConnectivity,
+                TrackerApiError::Unauthorized { .. } => ErrorKind::Configuration,
+                TrackerApiError::ClientBuild { .. }
+                | TrackerApiError::RequestFailed { .. }
+                | TrackerApiError::InvalidResponse { .. } => ErrorKind::CommandExecution,
+            },
+            Self::RepositoryError(_) => ErrorKind::StatePersistence,
+        }
+    }
+}
+
+impl KeysCommandHandlerError {
+    /// Provides detailed troubleshooting guidance for this error
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use torrust_tracker_deployer_lib::application::command_handlers::keys::KeysCommandHandlerError;
+    ///
+    /// let error = KeysCommandHandlerError::TrackerNotPrivate {
+    ///     name: "prod".to_string(),
+    /// };
+    ///
+    /// assert!(error.help().contains("\"private\": true"));
+    /// ```
+    #[must_use]
+    pub fn help(&self) -> &'static str {
+        match self {
+            Self::EnvironmentNotFound { .. } => {
+                "Environment Not Found - Troubleshooting:
+
+1. Verify the environment name is correct
+2. List the existing environments:
+   torrust-tracker-deployer list
+
+For more information, see docs/user-guide/commands/keys.md"
+            }
+            Self::TrackerNotPrivate { .. } => {
+                "Tracker Not Private - Troubleshooting:
+
+Keys are only needed by a private tracker, which rejects announces
+without a valid key. This tracker accepts every announce.
+
+1. To run a private tracker, set in the tracker section of the
+   environment configuration:
+   \"core\": { \"private\": true, ... }
+
+2. Apply the change to the deployed tracker:
+   torrust-tracker-deployer release <env-name>
+   torrust-tracker-deployer run <env-name>
+
+For more information, see docs/user-guide/commands/keys.md"
+            }
+            Self::EnvironmentNotRunning { .. } => {
+                "Environment Not Running - Troubleshooting:
+
+Keys are added and removed through the API of the deployed tracker, so
+the environment must be in the 'running' state.
+
+1. Check the environment state:
+   torrust-tracker-deployer show <env-name>
+
+2. Complete the deployment workflow:
+   torrust-tracker-deployer release <env-name>
+   torrust-tracker-deployer run <env-name>
+
+The keys already added can be listed in any state:
+   torrust-tracker-deployer keys list <env-name>
+
+For more information, see docs/user-guide/commands/keys.md"
+            }
+            Self::MissingInstanceIp { .. } => {
+                "Missing Instance IP - Troubleshooting:
+
+The environment has no instance IP address, so its tracker API cannot be
+reached. Provision or register the instance first:
+   torrust-tracker-deployer provision <env-name>
+
+For more information, see docs/user-guide/commands/keys.md"
+            }
+            Self::TrackerApi { .. } => {
+                "Tracker API Call Failed - Troubleshooting:
+
+1. Check that the tracker is running and its API answers:
+   torrust-tracker-deployer test <env-name> --check health,api
+
+2. Check that the firewall allows the API port (or 443 behind Caddy)
+
+3. If the admin token was rejected, the deployed tracker uses another
+   token than the environment: release the environment again:
+   torrust-tracker-deployer release <env-name>
+   torrust-tracker-deployer run <env-name>
+
+4. Certificates of .local domains and of the Let's Encrypt staging CA are
+   accepted; any other certificate must be trusted by this machine
+
+For more information, see docs/user-guide/commands/keys.md"
+            }
+            Self::RepositoryError(_) => {
+                "Repository Operation Failed - Troubleshooting:
+
+1. Check file permissions on the data/ directory
+2. Check that another command is not holding the environment lock
+3. Verify there is enough disk space
+
+If a key was added, it is valid on the tracker even though it was not
+recorded: revoke it with 'keys remove <env-name> <key>' if unused.
+
+For more information, see docs/user-guide/commands/keys.md"
+            }
+        }
+    }
+}
@@ -0,0 +1,463 @@
+//! Keys command handler implementation
+//!
+//! **Purpose**: Manage the authentication keys of a private tracker
+//!
+//! ## Design Strategy
+//!
+//! 1. **Validation**: Check that the tracker is private and running, and that
+//!    the environment has an instance IP
+//! 2. **API**: Add or remove the key through the API of the first tracker
+//!    replica, authenticated with the admin token of the environment
+//! 3. **Persistence**: Record the keys added in the environment secrets, so
+//!    they can be listed later (the tracker API offers no listing)
+//! 4. **Replicas**: Reload the keys on the other replicas, which share the
+//!    database but keep the keys in memory
+
+use std::sync::Arc;
+use std::time::Duration;
+
+use tracing::{info, instrument, warn};
+
+use super::errors::KeysCommandHandlerError;
+use crate::application::command_handlers::common::endpoint_builder;
+use crate::application::errors::AllowedCommands;
+use crate::domain::environment::repository::EnvironmentRepository;
+use crate::domain::environment::{AnyEnvironmentState, EnvironmentName};
+use crate::domain::https::HttpsConfig;
+use crate::domain::tracker::{AuthKey, IssuedAuthKey, TrackerConfig};
+use crate::infrastructure::tracker_api::TrackerApiClient;
+use crate::shared::Clock;
+
+/// A recorded key with its status at the time of listing
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ListedAuthKey {
+    /// The key and its expiry time
+    pub key: IssuedAuthKey,
+    /// Whether the tracker no longer accepts the key
+    pub expired: bool,
+}
+
+/// `KeysCommandHandler` adds, lists and removes the keys of a private tracker
+pub struct KeysCommandHandler {
+    repository: Arc<dyn EnvironmentRepository + Send + Sync>,
+    clock: Arc<dyn Clock>,
+}
+
+impl KeysCommandHandler {
+    /// Create a new `KeysCommandHandler`
+    #[must_use]
+    pub fn new(
+        repository: Arc<dyn EnvironmentRepository + Send + Sync>,
+        clock: Arc<dyn Clock>,
+    ) -> Self {
+        Self { repository, clock }
+    }
+
+    /// Mint a key on the tracker, valid for `valid_for` or permanent when `None`
+    ///
+    /// The key is recorded in the environment secrets.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if:
+    /// * The environment does not exist
+    /// * The tracker is not private or not running
+    /// * The tracker API call fails
+    /// * The environment cannot be saved
+    #[instrument(
+        name = "command.keys.add",
+        skip_all,
+        fields(
+            command_type = "keys",
+            environment_name = %env_name
+        )
+    )]
+    pub async fn add(
+        &self,
+        env_name: &EnvironmentName,
+        valid_for: Option<Duration>,
+    ) -> Result<IssuedAuthKey, KeysCommandHandlerError> {
+        let _lock = self.repository.lock(env_name)?;
+        let mut environment = self.load_environment(env_name)?;
+        let clients = Self::tracker_clients(&environment, env_name)?;
+
+        let issued = clients[0].add_key(valid_for).await.map_err(|source| {
+            KeysCommandHandlerError::TrackerApi {
+                name: env_name.to_string(),
+                source,
+            }
+        })?;
+
+        let mut keys = environment.tracker_auth_keys().to_vec();
+        keys.push(issued.clone());
+        environment.set_tracker_auth_keys(keys);
+        self.repository.save(&environment)?;
+
+        Self::reload_other_replicas(&clients, env_name).await;
+
+        info!(
+            command = "keys",
+            environment = %env_name,
+            valid_until = ?issued.valid_until,
+            "Tracker key added"
+        );
+
+        Ok(issued)
+    }
+
+    /// List the keys recorded for the environment, in the order they were added
+    ///
+    /// Works in any state: the tracker is not called.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the environment does not exist or cannot be loaded.
+    pub fn list(
+        &self,
+        env_name: &EnvironmentName,
+    ) -> Result<Vec<ListedAuthKey>, KeysCommandHandlerError> {
+        let environment = self.load_environment(env_name)?;
+        let now = self.clock.now();
+
+        Ok(environment
+            .tracker_auth_keys()
+            .iter()
+            .map(|key| ListedAuthKey {
+                key: key.clone(),
+                expired: key.is_expired_at(now),
+            })
+            .collect())
+    }
+
+    /// Revoke `key` on the tracker and forget it
+    ///
+    /// Keys added without this tool can be revoked too. Returns whether the
+    /// key was recorded for the environment.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if:
+    /// * The environment does not exist
+    /// * The tracker is not private or not running
+    /// * The tracker API call fails
+    /// * The environment cannot be saved
+    #[instrument(
+        name = "command.keys.remove",
+        skip_all,
+        fields(
+            command_type = "keys",
+            environment_name = %env_name
+        )
+    )]
+    pub async fn remove(
+        &self,
+        env_name: &EnvironmentName,
+        key: &AuthKey,
+    ) -> Result<bool, KeysCommandHandlerError> {
+        let _lock = self.repository.lock(env_name)?;
+        let mut environment = self.load_environment(env_name)?;
+        let clients = Self::tracker_clients(&environment, env_name)?;
+
+        clients[0]
+            .delete_key(key)
+            .await
+            .map_err(|source| KeysCommandHandlerError::TrackerApi {
+                name: env_name.to_string(),
+                source,
+            })?;
+
+        let keys: Vec<IssuedAuthKey> = environment
+            .tracker_auth_keys()
+            .iter()
+            .filter(|issued| &issued.key != key)
+            .cloned()
+            .collect();
+        let was_recorded = keys.len() != environment.tracker_auth_keys().len();
+
+        if was_recorded {
+            environment.set_tracker_auth_keys(keys);
+            self.repository.save(&environment)?;
+        }
+
+        Self::reload_other_replicas(&clients, env_name).await;
+
+        info!(
+            command = "keys",
+            environment = %env_name,
+            was_recorded,
+            "Tracker key removed"
+        );
+
+        Ok(was_recorded)
+    }
+
+    /// Load the environment from the repository
+    fn load_environment(
+        &self,
+        name: &EnvironmentName,
+    ) -> Result<AnyEnvironmentState, KeysCommandHandlerError> {
+        self.repository
+            .load(name)?
+            .ok_or_else(|| KeysCommandHandlerError::EnvironmentNotFound {
+                name: name.to_string(),
+            })
+    }
+
+    /// One API client per tracker replica, after checking the tracker can be called
+    fn tracker_clients(
+        environment: &AnyEnvironmentState,
+        env_name: &EnvironmentName,
+    ) -> Result<Vec<TrackerApiClient>, KeysCommandHandlerError> {
+        if !environment.tracker_config().core().private() {
+            return Err(KeysCommandHandlerError::TrackerNotPrivate {
+                name: env_name.to_string(),
+            });
+        }
+
+        if !matches!(environment, AnyEnvironmentState::Running(_)) {
+            return Err(KeysCommandHandlerError::EnvironmentNotRunning {
+                name: env_name.to_string(),
+                state: environment.state_name().to_string(),
+                allowed_commands: AllowedCommands::from(environment),
+            });
+        }
+
+        let instance_ip = environment.instance_ip().ok_or_else(|| {
+            KeysCommandHandlerError::MissingInstanceIp {
+                name: env_name.to_string(),
+            }
+        })?;
+
+        let staging_certs = environment
+            .https_config()
+            .is_some_and(HttpsConfig::use_staging);
+
+        Ok(environment
+            .tracker_config()
+            .replica_configs()
+            .iter()
+            .map(TrackerConfig::http_api)
+            .map(|api| {
+                TrackerApiClient::new(
+                    endpoint_builder::build_api_v1_endpoint(instance_ip, api),
+                    api.admin_token().clone(),
+                )
+                .with_untrusted_certs(staging_certs)
+            })
+            .collect())
+    }
+
+    /// Reload the keys on every replica but the first one
+    ///
+    /// A failure is only logged: the key is in the shared database, and the
+    /// replica loads it when it restarts.
+    async fn reload_other_replicas(clients: &[TrackerApiClient], env_name: &EnvironmentName) {
+        for (index, client) in clients.iter().enumerate().skip(1) {
+            if let Err(error) = client.reload_keys().await {
+                warn!(
+                    command = "keys",
+                    environment = %env_name,
+                    replica = index + 1,
+                    %error,
+                    "Failed to reload the keys of a tracker replica"
+                );
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::net::{IpAddr, Ipv4Addr};
+    use std::path::Path;
+
+    use chrono::{TimeZone, Utc};
+    use tempfile::TempDir;
+    use tokio::io::{AsyncReadExt, AsyncWriteExt};
+    use tokio::net::TcpListener;
+
+    use super::*;
+    use crate::domain::environment::runtime_outputs::ProvisionMethod;
+    use crate::domain::environment::testing::EnvironmentTestBuilder;
+    use crate::domain::tracker::{
+        DatabaseConfig, HealthCheckApiConfig, HttpApiConfig, SqliteConfig, TrackerCoreConfig,
+    };
+    use crate::infrastructure::persistence::file_repository_factory::FileRepositoryFactory;
+    use crate::shared::ApiToken;
+    use crate::testing::MockClock;
+
+    const KEY: &str = "YZSl4lMZupRuOpSRC3krIKR5BPB14nrJ";
+
+    fn repository(working_dir: &Path) -> Arc<dyn EnvironmentRepository + Send + Sync> {
+        FileRepositoryFactory::new(std::time::Duration::from_secs(30))
+            .create(working_dir.join("data"))
+    }
+
+    fn handler(repository: Arc<dyn EnvironmentRepository + Send + Sync>) -> KeysCommandHandler {
+        let now = Utc.with_ymd_and_hms(2026, 1, 1, 0, 0, 0).unwrap();
+        KeysCommandHandler::new(repository, Arc::new(MockClock::new(now)))
+    }
+
+    fn tracker_config(private: bool, api_port: u16) -> TrackerConfig {
+        TrackerConfig::new(
+            TrackerCoreConfig::new(
+                DatabaseConfig::Sqlite(SqliteConfig::new("tracker.db").unwrap()),
+                private,
+            ),
+            vec![],
+            vec![],
+            HttpApiConfig::new(
+                format!("0.0.0.0:{api_port}").parse().unwrap(),
+                ApiToken::from("MyAccessToken".to_string()),
+                None,
+                false,
+            )
+            .unwrap(),
+            HealthCheckApiConfig::new("127.0.0.1:1313".parse().unwrap(), None, false).unwrap(),
+        )
+        .unwrap()
+    }
+
+    /// Save a running environment named `tmp` whose tracker API is on `api_port`
+    fn save_running_environment(
+        repository: &Arc<dyn EnvironmentRepository + Send + Sync>,
+        private: bool,
+        api_port: u16,
+    ) -> (EnvironmentName, TempDir) {
+        let (environment, _data_dir, _build_dir, temp_dir) = EnvironmentTestBuilder::new()
+            .with_name("tmp")
+            .with_tracker_config(tracker_config(private, api_port))
+            .build_with_custom_paths();
+        let name = environment.name().clone();
+        let running = environment
+            .start_provisioning()
+            .provisioned(
+                IpAddr::V4(Ipv4Addr::LOCALHOST),
+                ProvisionMethod::Provisioned,
+            )
+            .start_configuring()
+            .configured()
+            .start_releasing()
+            .released()
+            .start_running();
+        repository.save(&running.into_any()).unwrap();
+        (name, temp_dir)
+    }
+
+    /// Answer the next request on `listener` with `body`
+    async fn answer_once(listener: TcpListener, body: &'static str) {
+        let (mut stream, _) = listener.accept().await.unwrap();
+        let mut buffer = vec![0; 4096];
+        let _ = stream.read(&mut buffer).await.unwrap();
+        let response = format!(
+            "HTTP/1.1 200 OK\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{body}",
+            body.len()
+        );
+        stream.write_all(response.as_bytes()).await.unwrap();
+    }
+
+    #[tokio::test]
+    async fn it_should_add_a_key_and_list_it() {
+        let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let port = listener.local_addr().unwrap().port();
+        let server = tokio::spawn(answer_once(
+            listener,
+            r#"{"key":"YZSl4lMZupRuOpSRC3krIKR5BPB14nrJ","valid_until":1769904000,"expiry_time":null}"#,
+        ));
+        let temp_dir = TempDir::new().unwrap();
+        let repository = repository(temp_dir.path());
+        let (name, _env_dir) = save_running_environment(&repository, true, port);
+        let handler = handler(repository);
+
+        let issued = handler
+            .add(&name, Some(Duration::from_secs(31 * 24 * 60 * 60)))
+            .await
+            .unwrap();
+        server.await.unwrap();
+
+        assert_eq!(issued.key.as_str(), KEY);
+        assert_eq!(
+            handler.list(&name).unwrap(),
+            vec![ListedAuthKey {
+                key: issued,
+                expired: false
+            }]
+        );
+    }
+
+    #[tokio::test]
+    async fn it_should_remove_a_recorded_key() {
+        let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let port = listener.local_addr().unwrap().port();
+        let server = tokio::spawn(answer_once(listener, r#"{"status":"ok"}"#));
+        let temp_dir = TempDir::new().unwrap();
+        let repository = repository(temp_dir.path());
+        let (name, _env_dir) = save_running_environment(&repository, true, port);
+        let mut environment = repository.load(&name).unwrap().unwrap();
+        environment.set_tracker_auth_keys(vec![IssuedAuthKey {
+            key: AuthKey::new(KEY).unwrap(),
+            valid_until: None,
+        }]);
+        repository.save(&environment).unwrap();
+        let handler = handler(repository);
+
+        let was_recorded = handler
+            .remove(&name, &AuthKey::new(KEY).unwrap())
+            .await
+            .unwrap();
+        server.await.unwrap();
+
+        assert!(was_recorded);
+        assert!(handler.list(&name).unwrap().is_empty());
+    }
+
+    #[test]
+    fn it_should_flag_expired_keys_when_listing() {
+        let temp_dir = TempDir::new().unwrap();
+        let repository = repository(temp_dir.path());
+        let (name, _env_dir) = save_running_environment(&repository, true, 1212);
+        let mut environment = repository.load(&name).unwrap().unwrap();
+        environment.set_tracker_auth_keys(vec![IssuedAuthKey {
+            key: AuthKey::new(KEY).unwrap(),
+            valid_until: Some(Utc.with_ymd_and_hms(2025, 12, 1, 0, 0, 0).unwrap()),
+        }]);
+        repository.save(&environment).unwrap();
+
+        let listed = handler(repository).list(&name).unwrap();
+
+        assert!(listed[0].expired);
+    }
+
+    #[tokio::test]
+    async fn it_should_refuse_to_add_a_key_to_a_public_tracker() {
+        let temp_dir = TempDir::new().unwrap();
+        let repository = repository(temp_dir.path());
+        let (name, _env_dir) = save_running_environment(&repository, false, 1212);
+
+        let result = handler(repository).add(&name, None).await;
+
+        assert!(matches!(
+            result,
+            Err(KeysCommandHandlerError::TrackerNotPrivate { .. })
+        ));
+    }
+
+    #[tokio::test]
+    async fn it_should_refuse_to_add_a_key_when_the_tracker_is_not_running() {
+        let temp_dir = TempDir::new().unwrap();
+        let repository = repository(temp_dir.path());
+        let (environment, _data_dir, _build_dir, _env_dir) = EnvironmentTestBuilder::new()
+            .with_name("tmp")
+            .with_tracker_config(tracker_config(true, 1212))
+            .build_with_custom_paths();
+        let name = environment.name().clone();
+        repository.save(&environment.into_any()).unwrap();
+
+        let result = handler(repository).add(&name, None).await;
+
+        assert!(matches!(
+            result,
+            Err(KeysCommandHandlerError::EnvironmentNotRunning { .. })
+        ));
+    }
+}
@@ -0,0 +1,25 @@
+//! Keys Command Module
+//!
+//! This module implements the delivery-agnostic `KeysCommandHandler`
+//! for managing the authentication keys of a private tracker.
+//!
+//! ## Private Trackers
+//!
+//! A tracker deployed with `"private": true` in its core configuration only
+//! accepts announces carrying a valid key (`/announce/<key>`). Keys are
+//! minted and revoked through the tracker API, authenticated with the admin
+//! token of the environment, on the API endpoint derived from the tracker
+//! configuration (HTTPS when the API is behind the TLS proxy).
+//!
+//! ## Recorded Keys
+//!
+//! The tracker API offers no listing of the keys, so the keys added with this
+//! handler are recorded in the environment secrets together with their expiry
+//! time. Listing reads these records and works in any state.
+
+pub mod errors;
+pub mod handler;
+
+// Re-export main types for convenience
+pub use errors::KeysCommandHandlerError;
+pub use handler::{KeysCommandHandler, ListedAuthKey};
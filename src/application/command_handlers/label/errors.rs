@@ -0,0 +1,157 @@
+//! Error types for the label command handler
+
+use crate::application::errors::PersistenceError;
+use crate::domain::environment::LabelError;
+use crate::shared::error::kind::ErrorKind;
+use crate::shared::error::traceable::Traceable;
+
+/// Comprehensive error type for the `LabelCommandHandler`
+#[derive(Debug, thiserror::Error)]
+pub enum LabelCommandHandlerError {
+    /// The environment to label does not exist
+    #[error("Environment not found: '{name}'")]
+    EnvironmentNotFound { name: String },
+
+    /// Another command is changing the environment
+    #[error("Environment '{name}' is in state '{state}': an operation is in progress")]
+    OperationInProgress { name: String, state: String },
+
+    /// A label or a key to remove breaks the label rules
+    #[error("{0}")]
+    InvalidLabel(#[from] LabelError),
+
+    /// Repository operation failed
+    #[error("Repository operation failed: {0}")]
+    RepositoryError(#[from] PersistenceError),
+}
+
+impl From<crate::domain::environment::repository::RepositoryError> for LabelCommandHandlerError {
+    fn from(e: crate::domain::environment::repository::RepositoryError) -> Self {
+        Self::RepositoryError(e.into())
+    }
+}
+
+impl Traceable for LabelCommandHandlerError {
+    fn trace_format(&self) -> String {
+        match self {
+            Self::EnvironmentNotFound { name } => {
+                format!("LabelCommandHandlerError: Environment not found - '{name}'")
+            }
+            Self::OperationInProgress { name, state } => {
+                format!("LabelCommandHandlerError: Operation in progress - '{name}' is {state}")
+            }
+            Self::InvalidLabel(e) => {
+                format!("LabelCommandHandlerError: Invalid label - {e}")
+            }
+            Self::RepositoryError(e) => {
+                format!("LabelCommandHandlerError: Repository operation failed - {e}")
+            }
+        }
+    }
+
+    fn trace_source(&self) -> Option<&dyn Traceable> {
+        None
+    }
+
+    fn error_kind(&self) -> ErrorKind {
+        match self {
+            Self::EnvironmentNotFound { .. }
+            | Self::OperationInProgress { .. }
+            | Self::InvalidLabel(_) => ErrorKind::Configuration,
+            Self::RepositoryError(_) => ErrorKind::StatePersistence,
+        }
+    }
+}
+
+impl LabelCommandHandlerError {
+    /// Provides detailed troubleshooting guidance for this error
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use torrust_tracker_deployer_lib::application::command_handlers::label::LabelCommandHandlerError;
+    ///
+    /// let error = LabelCommandHandlerError::EnvironmentNotFound {
+    ///     name: "tmp".to_string(),
+    /// };
+    ///
+    /// assert!(error.help().contains("list"));
+    /// ```
+    #[must_use]
+    pub fn help(&self) -> &'static str {
+        match self {
+            Self::EnvironmentNotFound { .. } => {
+                "Environment Not Found - Troubleshooting:
+
+1. Verify the environment name is correct
+2. List the existing environments:
+   torrust-tracker-deployer list
+
+For more information, see docs/user-guide/commands/label.md"
+            }
+            Self::OperationInProgress { .. } => {
+                "Operation In Progress - Troubleshooting:
+
+Another command is changing the environment and would overwrite the labels
+when it saves its state.
+
+1. Wait for the other command to finish, then label again
+2. If no command is running, the previous one was interrupted:
+   torrust-tracker-deployer repair <name>
+
+For more information, see docs/user-guide/commands/label.md"
+            }
+            Self::InvalidLabel(_) => {
+                "Invalid Label - Troubleshooting:
+
+Labels are written as key=value (e.g. team=infra).
+
+- Keys have 1 to 63 lowercase letters, digits, '-', '_' or '.', starting
+  and ending with a letter or a digit
+- Values have 1 to 63 letters, digits, '-', '_' or '.'
+
+Example:
+   torrust-tracker-deployer label my-env --set team=infra --set purpose=e2e
+
+For more information, see docs/user-guide/commands/label.md"
+            }
+            Self::RepositoryError(_) => {
+                "Repository Error - Troubleshooting:
+
+1. Check that the data directory is readable and writable
+2. Check that no other command holds the environment lock
+3. Retry the command
+
+For more information, see docs/user-guide/commands/label.md"
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn it_should_provide_help_for_every_error() {
+        let errors = [
+            LabelCommandHandlerError::EnvironmentNotFound {
+                name: "tmp".to_string(),
+            },
+            LabelCommandHandlerError::OperationInProgress {
+                name: "tmp".to_string(),
+                state: "Provisioning".to_string(),
+            },
+            LabelCommandHandlerError::InvalidLabel(LabelError::MissingValue {
+                label: "team".to_string(),
+            }),
+        ];
+
+        for error in errors {
+            assert!(
+                error.help().contains("Troubleshooting"),
+                "missing help for {error}"
+            );
+        }
+    }
+}
@@ -0,0 +1,216 @@
+//! Label command handler implementation
+//!
+//! **Purpose**: Set and remove the labels of an existing environment
+//!
+//! ## Design Strategy
+//!
+//! 1. **Validation**: Check the keys to remove against the label rules, so a
+//!    typo is reported instead of silently ignored
+//! 2. **State**: Refuse environments with an operation in progress
+//! 3. **Changes**: Remove the given keys, then set the given labels, so a key
+//!    both removed and set ends up with the new value
+//! 4. **Persistence**: Save the environment only when its labels changed
+
+use std::sync::Arc;
+
+use tracing::{info, instrument};
+
+use super::errors::LabelCommandHandlerError;
+use crate::domain::environment::labels::validate_key;
+use crate::domain::environment::repository::EnvironmentRepository;
+use crate::domain::environment::{AnyEnvironmentState, EnvironmentName, Label};
+
+/// `LabelCommandHandler` edits the labels of an environment
+pub struct LabelCommandHandler {
+    repository: Arc<dyn EnvironmentRepository + Send + Sync>,
+}
+
+impl LabelCommandHandler {
+    /// Create a new `LabelCommandHandler`
+    #[must_use]
+    pub fn new(repository: Arc<dyn EnvironmentRepository + Send + Sync>) -> Self {
+        Self { repository }
+    }
+
+    /// Set the labels `set` and remove the labels with a key in `remove`
+    ///
+    /// Setting an existing key replaces its value. Removing a key that is not
+    /// set is not an error. Without changes, the environment is returned as
+    /// loaded, which lets callers display its current labels.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if:
+    /// * A key to remove breaks the label rules
+    /// * The environment does not exist
+    /// * An operation is in progress on the environment
+    /// * The environment cannot be loaded or saved
+    #[instrument(
+        name = "command.label",
+        skip_all,
+        fields(
+            command_type = "label",
+            environment_name = %env_name
+        )
+    )]
+    pub fn execute(
+        &self,
+        env_name: &EnvironmentName,
+        set: &[Label],
+        remove: &[String],
+    ) -> Result<AnyEnvironmentState, LabelCommandHandlerError> {
+        let _lock = self.repository.lock(env_name)?;
+        for key in remove {
+            validate_key(key.clone())?;
+        }
+
+        let mut environment = self.load_environment(env_name)?;
+
+        if set.is_empty() && remove.is_empty() {
+            return Ok(environment);
+        }
+
+        if environment.is_transitional_state() {
+            return Err(LabelCommandHandlerError::OperationInProgress {
+                name: env_name.to_string(),
+                state: environment.state_display_name().to_string(),
+            });
+        }
+
+        let mut labels = environment.labels().clone();
+        for key in remove {
+            labels.remove(key);
+        }
+        for label in set {
+            labels.insert(label.clone());
+        }
+
+        if &labels == environment.labels() {
+            return Ok(environment);
+        }
+
+        environment.set_labels(labels);
+        self.repository.save(&environment)?;
+
+        info!(
+            command = "label",
+            environment = %env_name,
+            labels = ?environment.labels().as_map(),
+            "Environment labels updated"
+        );
+
+        Ok(environment)
+    }
+
+    /// Load the environment from the repository
+    fn load_environment(
+        &self,
+        name: &EnvironmentName,
+    ) -> Result<AnyEnvironmentState, LabelCommandHandlerError> {
+        self.repository
+            .load(name)?
+            .ok_or_else(|| LabelCommandHandlerError::EnvironmentNotFound {
+                name: name.to_string(),
+            })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::path::Path;
+    use std::time::Duration;
+
+    use tempfile::TempDir;
+
+    use super::*;
+    use crate::domain::environment::testing::EnvironmentTestBuilder;
+    use crate::domain::environment::LabelError;
+    use crate::infrastructure::persistence::file_repository_factory::FileRepositoryFactory;
+
+    fn repository(working_dir: &Path) -> Arc<dyn EnvironmentRepository + Send + Sync> {
+        FileRepositoryFactory::new(Duration::from_secs(30)).create(working_dir.join("data"))
+    }
+
+    /// Save a created environment named `tmp` and return its name
+    fn save_environment(
+        repository: &Arc<dyn EnvironmentRepository + Send + Sync>,
+    ) -> (EnvironmentName, TempDir) {
+        let (environment, _data_dir, _build_dir, temp_dir) = EnvironmentTestBuilder::new()
+            .with_name("tmp")
+            .build_with_custom_paths();
+        let name = environment.name().clone();
+        repository.save(&environment.into_any()).unwrap();
+        (name, temp_dir)
+    }
+
+    fn label(text: &str) -> Label {
+        text.parse().unwrap()
+    }
+
+    #[test]
+    fn it_should_set_and_persist_labels() {
+        let temp_dir = TempDir::new().unwrap();
+        let repository = repository(temp_dir.path());
+        let (name, _env_dir) = save_environment(&repository);
+        let handler = LabelCommandHandler::new(repository.clone());
+
+        handler
+            .execute(&name, &[label("team=infra"), label("purpose=e2e")], &[])
+            .unwrap();
+
+        let loaded = repository.load(&name).unwrap().unwrap();
+        assert_eq!(loaded.labels().get("team"), Some("infra"));
+        assert_eq!(loaded.labels().get("purpose"), Some("e2e"));
+    }
+
+    #[test]
+    fn it_should_remove_labels_and_replace_values() {
+        let temp_dir = TempDir::new().unwrap();
+        let repository = repository(temp_dir.path());
+        let (name, _env_dir) = save_environment(&repository);
+        let handler = LabelCommandHandler::new(repository.clone());
+        handler
+            .execute(&name, &[label("team=infra"), label("purpose=e2e")], &[])
+            .unwrap();
+
+        let labeled = handler
+            .execute(&name, &[label("team=tracker")], &["purpose".to_string()])
+            .unwrap();
+
+        assert_eq!(labeled.labels().get("team"), Some("tracker"));
+        assert_eq!(labeled.labels().get("purpose"), None);
+    }
+
+    #[test]
+    fn it_should_reject_an_invalid_key_to_remove() {
+        let temp_dir = TempDir::new().unwrap();
+        let repository = repository(temp_dir.path());
+        let (name, _env_dir) = save_environment(&repository);
+
+        let result =
+            LabelCommandHandler::new(repository).execute(&name, &[], &["Team".to_string()]);
+
+        assert!(matches!(
+            result,
+            Err(LabelCommandHandlerError::InvalidLabel(
+                LabelError::InvalidKey { .. }
+            ))
+        ));
+    }
+
+    #[test]
+    fn it_should_report_a_missing_environment() {
+        let temp_dir = TempDir::new().unwrap();
+
+        let result = LabelCommandHandler::new(repository(temp_dir.path())).execute(
+            &EnvironmentName::new("missing").unwrap(),
+            &[label("team=infra")],
+            &[],
+        );
+
+        assert!(matches!(
+            result,
+            Err(LabelCommandHandlerError::EnvironmentNotFound { .. })
+        ));
+    }
+}
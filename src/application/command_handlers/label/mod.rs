@@ -0,0 +1,23 @@
+//! Label Command Module
+//!
+//! This module implements the delivery-agnostic `LabelCommandHandler`
+//! for editing the labels of an existing environment.
+//!
+//! ## Labels
+//!
+//! Labels are `key=value` pairs (e.g. `team=infra`, `purpose=e2e`) stored
+//! with the environment. They do not affect the deployment: the list command
+//! filters on them (`list --label team=infra`) and bulk commands select their
+//! environments with them (`destroy --label purpose=e2e --yes`).
+//!
+//! ## State
+//!
+//! Labels can be edited in any state, except while another command is
+//! changing the environment: that command would overwrite them when saving.
+
+pub mod errors;
+pub mod handler;
+
+// Re-export main types for convenience
+pub use errors::LabelCommandHandlerError;
+pub use handler::LabelCommandHandler;
@@ -0,0 +1,125 @@
+//! Error types for last failure command handler
+
+use crate::application::errors::PersistenceError;
+use crate::infrastructure::trace::TraceReaderError;
+use crate::shared::error::kind::ErrorKind;
+use crate::shared::error::traceable::Traceable;
+
+/// Comprehensive error type for the `LastFailureCommandHandler`
+#[derive(Debug, thiserror::Error)]
+pub enum LastFailureCommandHandlerError {
+    #[error("Environment not found: '{name}'")]
+    EnvironmentNotFound { name: String },
+
+    #[error("Failed to load environment: {0}")]
+    LoadError(#[from] PersistenceError),
+
+    #[error("Failed to read failure trace: {0}")]
+    TraceRead(#[from] TraceReaderError),
+}
+
+impl From<crate::domain::environment::repository::RepositoryError>
+    for LastFailureCommandHandlerError
+{
+    fn from(e: crate::domain::environment::repository::RepositoryError) -> Self {
+        Self::LoadError(e.into())
+    }
+}
+
+impl Traceable for LastFailureCommandHandlerError {
+    fn trace_format(&self) -> String {
+        match self {
+            Self::EnvironmentNotFound { name } => {
+                format!("LastFailureCommandHandlerError: Environment not found - '{name}'")
+            }
+            Self::LoadError(e) => {
+                format!("LastFailureCommandHandlerError: Failed to load environment - {e}")
+            }
+            Self::TraceRead(e) => {
+                format!("LastFailureCommandHandlerError: Failed to read failure trace - {e}")
+            }
+        }
+    }
+
+    fn trace_source(&self) -> Option<&dyn Traceable> {
+        match self {
+            Self::EnvironmentNotFound { .. } | Self::LoadError(_) | Self::TraceRead(_) => None,
+        }
+    }
+
+    fn error_kind(&self) -> ErrorKind {
+        match self {
+            Self::EnvironmentNotFound { .. } => ErrorKind::Configuration,
+            Self::LoadError(_) => ErrorKind::StatePersistence,
+            Self::TraceRead(_) => ErrorKind::FileSystem,
+        }
+    }
+}
+
+impl LastFailureCommandHandlerError {
+    /// Provides detailed troubleshooting guidance for this error
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use torrust_tracker_deployer_lib::application::command_handlers::last_failure::errors::LastFailureCommandHandlerError;
+    ///
+    /// let error = LastFailureCommandHandlerError::EnvironmentNotFound {
+    ///     name: "my-env".to_string(),
+    /// };
+    ///
+    /// let help = error.help();
+    /// assert!(help.contains("Verify the environment name"));
+    /// ```
+    #[must_use]
+    pub fn help(&self) -> &'static str {
+        match self {
+            Self::EnvironmentNotFound { .. } => {
+                "Environment Not Found - Troubleshooting:
+
+1. Verify the environment name is correct
+2. Check if the environment was created:
+   ls data/
+
+Common causes:
+- Typo in environment name
+- Environment was destroyed and state was removed
+- Working in the wrong directory
+
+For more information, see docs/user-guide/commands.md"
+            }
+            Self::LoadError(_) => {
+                "Environment Load Error - Troubleshooting:
+
+1. Check if the environment state file exists:
+   ls data/<env-name>/environment.json
+
+2. Verify the file is valid JSON:
+   cat data/<env-name>/environment.json | jq .
+
+Common causes:
+- Corrupted environment state file
+- File system permissions issues
+
+For more information, see docs/user-guide/commands.md"
+            }
+            Self::TraceRead(_) => {
+                "Failure Trace Read Error - Troubleshooting:
+
+1. List the trace files of the environment:
+   ls -la data/<env-name>/traces/
+
+2. Verify the structured report is valid JSON:
+   cat data/<env-name>/traces/<trace>.json | jq .
+
+3. The human-readable trace (.log) next to it is still available for inspection
+
+Common causes:
+- Trace file was edited or truncated
+- File system permissions issues
+
+For more information, see docs/user-guide/commands.md"
+            }
+        }
+    }
+}
@@ -0,0 +1,102 @@
+//! Last failure command handler implementation
+//!
+//! **Purpose**: Report the most recent command failure of an environment
+//!
+//! This handler loads the environment and, if it is in a failed state,
+//! returns a structured `FailureReport` combining the failure context stored
+//! in the environment state with the error chain from the structured trace.
+//!
+//! ## Design Rationale
+//!
+//! - An environment without failure is a valid result (`None`), NOT an error
+//! - The environment state is the source of truth for the failure context;
+//!   the trace report only contributes the error chain
+//! - Environments whose trace has no structured report (older traces) still
+//!   get a report, with an empty error chain
+
+use std::sync::Arc;
+
+use tracing::instrument;
+
+use super::errors::LastFailureCommandHandlerError;
+use crate::domain::environment::repository::EnvironmentRepository;
+use crate::domain::environment::state::AnyEnvironmentState;
+use crate::domain::environment::FailureReport;
+use crate::domain::EnvironmentName;
+use crate::infrastructure::trace::read_failure_report;
+
+/// `LastFailureCommandHandler` reports the last failure of an environment
+///
+/// **Purpose**: Read-only failure inspection for programmatic consumers
+///
+/// This handler never modifies state or makes network calls.
+pub struct LastFailureCommandHandler {
+    repository: Arc<dyn EnvironmentRepository>,
+}
+
+impl LastFailureCommandHandler {
+    /// Create a new `LastFailureCommandHandler`
+    #[must_use]
+    pub fn new(repository: Arc<dyn EnvironmentRepository>) -> Self {
+        Self { repository }
+    }
+
+    /// Execute the last failure command workflow
+    ///
+    /// # Arguments
+    ///
+    /// * `env_name` - The name of the environment to inspect
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(Some(FailureReport))` - The environment is in a failed state
+    /// * `Ok(None)` - The environment is not in a failed state
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if:
+    /// * Environment not found
+    /// * Environment state file is corrupted or unreadable
+    /// * The structured trace report exists but cannot be read
+    #[instrument(
+        name = "command.last_failure",
+        skip_all,
+        fields(
+            command_type = "last_failure",
+            environment_name = %env_name
+        )
+    )]
+    pub fn execute(
+        &self,
+        env_name: &EnvironmentName,
+    ) -> Result<Option<FailureReport>, LastFailureCommandHandlerError> {
+        let any_env = self.load_environment(env_name)?;
+
+        let Some(report) = any_env.failure_report() else {
+            return Ok(None);
+        };
+
+        let Some(trace_file) = report.base.trace_file_path.clone() else {
+            return Ok(Some(report));
+        };
+
+        let report = match read_failure_report(&trace_file)? {
+            Some(trace_report) => report.with_error_chain(trace_report.error_chain),
+            None => report,
+        };
+
+        Ok(Some(report))
+    }
+
+    /// Load environment from repository
+    fn load_environment(
+        &self,
+        env_name: &EnvironmentName,
+    ) -> Result<AnyEnvironmentState, LastFailureCommandHandlerError> {
+        self.repository.load(env_name)?.ok_or_else(|| {
+            LastFailureCommandHandlerError::EnvironmentNotFound {
+                name: env_name.to_string(),
+            }
+        })
+    }
+}
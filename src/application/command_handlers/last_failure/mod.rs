@@ -0,0 +1,30 @@
+//! Last Failure Command Module
+//!
+//! This module implements the delivery-agnostic `LastFailureCommandHandler`
+//! for inspecting the most recent command failure of an environment.
+//!
+//! ## Architecture
+//!
+//! The `LastFailureCommandHandler` implements the Command Pattern and uses Dependency Injection
+//! to interact with infrastructure services through interfaces:
+//!
+//! - **Repository Pattern**: Loads environment state via `EnvironmentRepository`
+//! - **Domain-Driven Design**: Returns the `FailureReport` domain type
+//! - **Trace Reader**: Reads the structured JSON report written next to the trace file
+//!
+//! ## Design Principles
+//!
+//! - **Delivery-Agnostic**: Works with CLI, SDK, or any delivery mechanism
+//! - **Read-Only Operation**: Never modifies environment state
+//! - **No Network Calls**: Reads local data only
+//! - **No Failure is a Result**: An environment that is not in a failed state yields `None`, NOT an error
+
+pub mod errors;
+pub mod handler;
+
+#[cfg(test)]
+mod tests;
+
+// Re-export main types for convenience
+pub use errors::LastFailureCommandHandlerError;
+pub use handler::LastFailureCommandHandler;
@@ -0,0 +1,136 @@
+//! Tests for the `LastFailureCommandHandler`
+//!
+//! These tests verify the handler's core logic:
+//!
+//! 1. Returns an error when the environment does not exist
+//! 2. Returns `None` when the environment is not in a failed state
+//! 3. Returns the failure context and the error chain from the structured trace
+
+use std::sync::Arc;
+use std::time::Duration;
+
+use chrono::Utc;
+use tempfile::TempDir;
+
+use crate::application::command_handlers::last_failure::errors::LastFailureCommandHandlerError;
+use crate::application::command_handlers::last_failure::handler::LastFailureCommandHandler;
+use crate::domain::environment::repository::EnvironmentRepository;
+use crate::domain::environment::state::{
+    AnyEnvironmentState, BaseFailureContext, ProvisionFailureContext, ProvisionStep,
+};
+use crate::domain::environment::testing::EnvironmentTestBuilder;
+use crate::domain::environment::TraceId;
+use crate::domain::EnvironmentName;
+use crate::infrastructure::persistence::filesystem::file_environment_repository::FileEnvironmentRepository;
+use crate::infrastructure::trace::ProvisionTraceWriter;
+use crate::shared::{ErrorKind, SystemClock, Traceable};
+
+#[derive(Debug, thiserror::Error)]
+#[error("tofu apply failed")]
+struct ApplyError;
+
+impl Traceable for ApplyError {
+    fn trace_format(&self) -> String {
+        "ApplyError: tofu apply failed".to_string()
+    }
+
+    fn trace_source(&self) -> Option<&dyn Traceable> {
+        None
+    }
+
+    fn error_kind(&self) -> ErrorKind {
+        ErrorKind::InfrastructureOperation
+    }
+}
+
+fn create_test_repo() -> (Arc<FileEnvironmentRepository>, TempDir) {
+    let temp_dir = TempDir::new().expect("Failed to create temp directory");
+    let repo = Arc::new(FileEnvironmentRepository::new(
+        temp_dir.path().to_path_buf(),
+    ));
+    (repo, temp_dir)
+}
+
+fn create_provision_failure_context() -> ProvisionFailureContext {
+    let now = Utc::now();
+    ProvisionFailureContext {
+        failed_step: ProvisionStep::OpenTofuApply,
+        error_kind: ErrorKind::InfrastructureOperation,
+        base: BaseFailureContext {
+            error_summary: ApplyError.to_string(),
+            failed_at: now,
+            execution_started_at: now,
+            execution_duration: Duration::from_secs(42),
+            trace_id: TraceId::new(),
+            trace_file_path: None,
+        },
+        remote_log_excerpt: None,
+    }
+}
+
+#[test]
+fn it_should_return_error_when_environment_does_not_exist() {
+    let (repo, _temp_dir) = create_test_repo();
+    let handler = LastFailureCommandHandler::new(repo);
+
+    let env_name = EnvironmentName::new("nonexistent-env").unwrap();
+    let result = handler.execute(&env_name);
+
+    assert!(matches!(
+        result,
+        Err(LastFailureCommandHandlerError::EnvironmentNotFound { .. })
+    ));
+}
+
+#[test]
+fn it_should_return_none_when_environment_has_not_failed() {
+    let (repo, _temp_dir) = create_test_repo();
+    let handler = LastFailureCommandHandler::new(repo.clone());
+
+    let (env, _data_dir, _build_dir, _env_temp) = EnvironmentTestBuilder::new()
+        .with_name("healthy-env")
+        .build_with_custom_paths();
+    let env_name = env.name().clone();
+    repo.save(&AnyEnvironmentState::Created(env))
+        .expect("Failed to save test environment");
+
+    let report = handler.execute(&env_name).expect("Expected Ok result");
+
+    assert!(report.is_none());
+}
+
+#[test]
+fn it_should_report_failed_step_and_error_chain_of_provision_failure() {
+    let (repo, _temp_dir) = create_test_repo();
+    let handler = LastFailureCommandHandler::new(repo.clone());
+
+    let (env, _data_dir, _build_dir, _env_temp) = EnvironmentTestBuilder::new()
+        .with_name("failed-env")
+        .build_with_custom_paths();
+    let env_name = env.name().clone();
+
+    let mut context = create_provision_failure_context();
+    let writer = ProvisionTraceWriter::new(env.traces_dir(), Arc::new(SystemClock));
+    context.base.trace_file_path = Some(
+        writer
+            .write_trace(&context, &ApplyError)
+            .expect("Failed to write trace"),
+    );
+    let failed = env.start_provisioning().provision_failed(context.clone());
+    repo.save(&failed.into_any())
+        .expect("Failed to save test environment");
+
+    let report = handler
+        .execute(&env_name)
+        .expect("Expected Ok result")
+        .expect("Expected a failure report");
+
+    assert_eq!(report.failed_step, ProvisionStep::OpenTofuApply);
+    assert_eq!(report.error_kind, ErrorKind::InfrastructureOperation);
+    assert_eq!(report.base, context.base);
+    assert_eq!(report.error_chain.len(), 1);
+    assert_eq!(
+        report.error_chain[0].message,
+        "ApplyError: tofu apply failed"
+    );
+}
@@ -19,6 +19,53 @@ pub enum ListCommandHandlerError {
     /// Failed to scan environments directory
     #[error("Failed to scan environments directory: {message}")]
     ScanError { message: String },
+
+    /// The list filter is invalid
+    #[error("Invalid list filter: {0}")]
+    InvalidFilter(#[from] ListFilterError),
+}
+
+/// Errors in a list filter specification
+#[derive(Debug, Clone, PartialEq, Eq, thiserror::Error)]
+pub enum ListFilterError {
+    /// The state filter contains an unknown state
+    #[error("Unknown environment state '{state}' (valid states: {valid})")]
+    UnknownState { state: String, valid: String },
+
+    /// The age is not a number followed by a unit
+    #[error("Invalid age '{value}': expected a number followed by m, h, d or w (e.g. 7d)")]
+    InvalidAge { value: String },
+}
+
+impl ListFilterError {
+    /// Provides detailed troubleshooting guidance for this error
+    #[must_use]
+    pub fn help(&self) -> &'static str {
+        match self {
+            Self::UnknownState { .. } => {
+                "Unknown State - Troubleshooting:
+
+1. Use snake case state names, separated by commas:
+   - Example: --state running,provision_failed
+
+2. Check the states of your environments:
+   - Run: torrust-tracker-deployer list
+
+For more information, see docs/user-guide/commands/list.md"
+            }
+            Self::InvalidAge { .. } => {
+                "Invalid Age - Troubleshooting:
+
+1. Use a whole number followed by a unit:
+   - m: minutes (e.g. 30m)
+   - h: hours (e.g. 12h)
+   - d: days (e.g. 7d)
+   - w: weeks (e.g. 2w)
+
+For more information, see docs/user-guide/commands/list.md"
+            }
+        }
+    }
 }
 
 impl Traceable for ListCommandHandlerError {
@@ -39,6 +86,9 @@ impl Traceable for ListCommandHandlerError {
                     path.display()
                 )
             }
+            Self::InvalidFilter(source) => {
+                format!("ListCommandHandlerError: Invalid filter - {source}")
+            }
         }
     }
 
@@ -51,6 +101,7 @@ impl Traceable for ListCommandHandlerError {
             Self::DataDirectoryNotFound { .. }
             | Self::ScanError { .. }
             | Self::PermissionDenied { .. } => ErrorKind::FileSystem,
+            Self::InvalidFilter(_) => ErrorKind::Configuration,
         }
     }
 }
@@ -136,6 +187,7 @@ Common causes:
 
 For more information, see docs/user-guide/commands.md"
             }
+            Self::InvalidFilter(source) => source.help(),
         }
     }
 }
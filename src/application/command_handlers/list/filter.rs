@@ -0,0 +1,387 @@
+//! Filtering and sorting of listed environments
+//!
+//! A `ListFilter` selects which environments the list command returns and in
+//! which order. It lives in the application layer so the CLI and the SDK
+//! share the same semantics; the presentation layer only parses its flags
+//! into a `ListFilter` and formats the result.
+
+use std::cmp::Ordering;
+use std::time::Duration;
+
+use chrono::{DateTime, TimeDelta, Utc};
+use serde::Serialize;
+
+use super::errors::ListFilterError;
+use crate::domain::environment::state::AnyEnvironmentState;
+use crate::domain::environment::Label;
+
+/// Sort order for listed environments
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, clap::ValueEnum)]
+#[serde(rename_all = "snake_case")]
+pub enum ListSort {
+    /// Alphabetical by environment name
+    #[default]
+    Name,
+
+    /// Alphabetical by state name, then by environment name
+    State,
+
+    /// Oldest environments first, then by environment name
+    Created,
+}
+
+/// Filter and sort specification for the list command
+///
+/// An empty filter (the default) matches every environment and sorts them
+/// by name.
+///
+/// # Example
+///
+/// ```rust
+/// use std::time::Duration;
+/// use torrust_tracker_deployer_lib::application::command_handlers::list::{ListFilter, ListSort};
+///
+/// // Failed provisions older than three days, oldest first
+/// let filter = ListFilter::new()
+///     .with_states(["provision_failed"])
+///     .older_than(Duration::from_secs(3 * 24 * 60 * 60))
+///     .sorted_by(ListSort::Created);
+///
+/// assert!(filter.validate().is_ok());
+/// ```
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct ListFilter {
+    states: Vec<String>,
+    name_pattern: Option<String>,
+    older_than: Option<Duration>,
+    labels: Vec<Label>,
+    sort: ListSort,
+}
+
+impl ListFilter {
+    /// Create a filter that matches every environment
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Only match environments in one of the given states
+    ///
+    /// States use the snake case state names (e.g. `running`,
+    /// `provision_failed`). Display names such as `Provision Failed` are
+    /// accepted too.
+    #[must_use]
+    pub fn with_states<I, S>(mut self, states: I) -> Self
+    where
+        I: IntoIterator<Item = S>,
+        S: Into<String>,
+    {
+        self.states = states
+            .into_iter()
+            .map(|state| normalize_state(&state.into()))
+            .collect();
+        self
+    }
+
+    /// Only match environments whose name matches a glob pattern
+    ///
+    /// `*` matches any sequence of characters and `?` matches a single
+    /// character (e.g. `e2e-*`).
+    #[must_use]
+    pub fn with_name_pattern(mut self, pattern: impl Into<String>) -> Self {
+        self.name_pattern = Some(pattern.into());
+        self
+    }
+
+    /// Only match environments created at least `age` ago
+    #[must_use]
+    pub fn older_than(mut self, age: Duration) -> Self {
+        self.older_than = Some(age);
+        self
+    }
+
+    /// Only match environments having every given label
+    #[must_use]
+    pub fn with_labels(mut self, labels: impl IntoIterator<Item = Label>) -> Self {
+        self.labels = labels.into_iter().collect();
+        self
+    }
+
+    /// Set the sort order of the result
+    #[must_use]
+    pub fn sorted_by(mut self, sort: ListSort) -> Self {
+        self.sort = sort;
+        self
+    }
+
+    /// Sort order of the result
+    #[must_use]
+    pub fn sort(&self) -> ListSort {
+        self.sort
+    }
+
+    /// Check that every state in the filter is a known state name
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ListFilterError::UnknownState`] for the first unknown state
+    pub fn validate(&self) -> Result<(), ListFilterError> {
+        match self
+            .states
+            .iter()
+            .find(|state| !AnyEnvironmentState::STATE_NAMES.contains(&state.as_str()))
+        {
+            Some(state) => Err(ListFilterError::UnknownState {
+                state: state.clone(),
+                valid: AnyEnvironmentState::STATE_NAMES.join(", "),
+            }),
+            None => Ok(()),
+        }
+    }
+
+    /// Check whether an environment name matches the name pattern
+    ///
+    /// Used for environments that could not be loaded, for which only the
+    /// name is known. Their labels are unknown, so they never match a filter
+    /// on labels.
+    #[must_use]
+    pub fn matches_name(&self, name: &str) -> bool {
+        self.labels.is_empty() && self.name_pattern_matches(name)
+    }
+
+    /// Check whether an environment matches every criterion of the filter
+    #[must_use]
+    pub fn matches(&self, environment: &AnyEnvironmentState, now: DateTime<Utc>) -> bool {
+        let state_matches = self.states.is_empty()
+            || self
+                .states
+                .iter()
+                .any(|state| state == environment.state_name());
+
+        let age_matches = self.older_than.is_none_or(|age| {
+            now - environment.created_at() >= TimeDelta::from_std(age).unwrap_or(TimeDelta::MAX)
+        });
+
+        state_matches
+            && age_matches
+            && environment.labels().matches_all(&self.labels)
+            && self.name_pattern_matches(environment.name().as_str())
+    }
+
+    /// Check whether a name matches the glob pattern, if any
+    fn name_pattern_matches(&self, name: &str) -> bool {
+        self.name_pattern
+            .as_deref()
+            .is_none_or(|pattern| glob_matches(pattern, name))
+    }
+
+    /// Compare two environments according to the sort order
+    #[must_use]
+    pub fn compare(&self, a: &AnyEnvironmentState, b: &AnyEnvironmentState) -> Ordering {
+        let by_name = || a.name().as_str().cmp(b.name().as_str());
+
+        match self.sort {
+            ListSort::Name => by_name(),
+            ListSort::State => a.state_name().cmp(b.state_name()).then_with(by_name),
+            ListSort::Created => a.created_at().cmp(&b.created_at()).then_with(by_name),
+        }
+    }
+}
+
+/// Parse an age such as `7d`, `12h`, `30m` or `2w`
+///
+/// # Errors
+///
+/// Returns [`ListFilterError::InvalidAge`] if the value is not a positive
+/// number followed by one of the units `m`, `h`, `d` or `w`
+///
+/// # Example
+///
+/// ```rust
+/// use std::time::Duration;
+/// use torrust_tracker_deployer_lib::application::command_handlers::list::filter::parse_age;
+///
+/// assert_eq!(parse_age("7d").unwrap(), Duration::from_secs(7 * 24 * 60 * 60));
+/// assert!(parse_age("7").is_err());
+/// ```
+pub fn parse_age(value: &str) -> Result<Duration, ListFilterError> {
+    let invalid = || ListFilterError::InvalidAge {
+        value: value.to_string(),
+    };
+
+    let trimmed = value.trim();
+    let split_at = trimmed
+        .find(|c: char| !c.is_ascii_digit())
+        .ok_or_else(invalid)?;
+    let (amount, unit) = trimmed.split_at(split_at);
+    let amount: u64 = amount.parse().map_err(|_| invalid())?;
+
+    let unit_secs = match unit {
+        "m" => 60,
+        "h" => 60 * 60,
+        "d" => 24 * 60 * 60,
+        "w" => 7 * 24 * 60 * 60,
+        _ => return Err(invalid()),
+    };
+
+    amount
+        .checked_mul(unit_secs)
+        .map(Duration::from_secs)
+        .ok_or_else(invalid)
+}
+
+/// Normalize a state given by the user to its snake case state name
+fn normalize_state(state: &str) -> String {
+    state.trim().to_lowercase().replace([' ', '-'], "_")
+}
+
+/// Match `text` against a glob `pattern` supporting `*` and `?`
+fn glob_matches(pattern: &str, text: &str) -> bool {
+    let pattern: Vec<char> = pattern.chars().collect();
+    let text: Vec<char> = text.chars().collect();
+
+    let (mut p, mut t) = (0, 0);
+    let mut backtrack: Option<(usize, usize)> = None;
+
+    while t < text.len() {
+        if p < pattern.len() && (pattern[p] == '?' || pattern[p] == text[t]) {
+            p += 1;
+            t += 1;
+        } else if p < pattern.len() && pattern[p] == '*' {
+            backtrack = Some((p, t));
+            p += 1;
+        } else if let Some((star, matched)) = backtrack {
+            p = star + 1;
+            t = matched + 1;
+            backtrack = Some((star, matched + 1));
+        } else {
+            return false;
+        }
+    }
+
+    pattern[p..].iter().all(|&c| c == '*')
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::environment::testing::EnvironmentTestBuilder;
+
+    fn created_environment(name: &str) -> (AnyEnvironmentState, tempfile::TempDir) {
+        let (env, _data_dir, _build_dir, temp_dir) = EnvironmentTestBuilder::new()
+            .with_name(name)
+            .build_with_custom_paths();
+        (env.into_any(), temp_dir)
+    }
+
+    #[test]
+    fn it_should_match_every_environment_when_filter_is_empty() {
+        let (env, _temp_dir) = created_environment("any-env");
+
+        assert!(ListFilter::new().matches(&env, Utc::now()));
+    }
+
+    #[test]
+    fn it_should_match_environments_by_state() {
+        let (env, _temp_dir) = created_environment("state-env");
+
+        assert!(ListFilter::new()
+            .with_states(["running", "created"])
+            .matches(&env, Utc::now()));
+        assert!(!ListFilter::new()
+            .with_states(["provision_failed"])
+            .matches(&env, Utc::now()));
+    }
+
+    #[test]
+    fn it_should_accept_state_display_names() {
+        let filter = ListFilter::new().with_states(["Provision Failed"]);
+
+        assert!(filter.validate().is_ok());
+    }
+
+    #[test]
+    fn it_should_reject_unknown_states() {
+        let result = ListFilter::new().with_states(["paused"]).validate();
+
+        assert!(matches!(
+            result,
+            Err(ListFilterError::UnknownState { state, .. }) if state == "paused"
+        ));
+    }
+
+    #[test]
+    fn it_should_match_environments_older_than_the_given_age() {
+        let (env, _temp_dir) = created_environment("old-env");
+        let filter = ListFilter::new().older_than(parse_age("3d").unwrap());
+
+        assert!(!filter.matches(&env, env.created_at() + TimeDelta::days(1)));
+        assert!(filter.matches(&env, env.created_at() + TimeDelta::days(4)));
+    }
+
+    #[test]
+    fn it_should_match_names_with_glob_patterns() {
+        assert!(glob_matches("e2e-*", "e2e-provision"));
+        assert!(glob_matches("*-prod", "tracker-prod"));
+        assert!(glob_matches("env-?", "env-1"));
+        assert!(glob_matches("*", "anything"));
+        assert!(!glob_matches("e2e-*", "dev-e2e"));
+        assert!(!glob_matches("env-?", "env-10"));
+    }
+
+    #[test]
+    fn it_should_parse_ages_with_units() {
+        assert_eq!(parse_age("30m").unwrap(), Duration::from_secs(30 * 60));
+        assert_eq!(parse_age("12h").unwrap(), Duration::from_secs(12 * 60 * 60));
+        assert_eq!(
+            parse_age("7d").unwrap(),
+            Duration::from_secs(7 * 24 * 60 * 60)
+        );
+        assert_eq!(
+            parse_age("2w").unwrap(),
+            Duration::from_secs(14 * 24 * 60 * 60)
+        );
+    }
+
+    #[test]
+    fn it_should_reject_ages_without_a_valid_unit() {
+        for value in ["7", "d", "7y", "-7d", ""] {
+            assert!(
+                matches!(parse_age(value), Err(ListFilterError::InvalidAge { .. })),
+                "expected '{value}' to be rejected"
+            );
+        }
+    }
+
+    #[test]
+    fn it_should_match_environments_having_every_label() {
+        let (mut env, _temp_dir) = created_environment("labeled-env");
+        env.set_labels(["team=infra".parse().unwrap()].into_iter().collect());
+
+        assert!(ListFilter::new()
+            .with_labels(["team=infra".parse().unwrap()])
+            .matches(&env, Utc::now()));
+        assert!(!ListFilter::new()
+            .with_labels([
+                "team=infra".parse().unwrap(),
+                "purpose=e2e".parse().unwrap()
+            ])
+            .matches(&env, Utc::now()));
+    }
+
+    #[test]
+    fn it_should_not_match_unloaded_environments_when_filtering_by_label() {
+        let filter = ListFilter::new().with_labels(["purpose=e2e".parse().unwrap()]);
+
+        assert!(!filter.matches_name("broken-env"));
+    }
+
+    #[test]
+    fn it_should_sort_by_name_by_default() {
+        let (a, _temp_a) = created_environment("alpha");
+        let (b, _temp_b) = created_environment("beta");
+
+        assert_eq!(ListFilter::new().compare(&a, &b), Ordering::Less);
+        assert_eq!(ListFilter::new().compare(&b, &a), Ordering::Greater);
+    }
+}
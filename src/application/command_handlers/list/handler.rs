@@ -2,7 +2,7 @@
 //!
 //! **Purpose**: List all environments in the deployment workspace
 //!
-//! This handler scans the data directory for environments and extracts
+//! This handler lists the environments of the repository and extracts
 //! summary information for display. It is a read-only operation that
 //! does not modify any state or make any network calls.
 //!
@@ -10,32 +10,32 @@
 //!
 //! The list command scans local storage for environments:
 //!
-//! 1. **Directory Scan**: Find all environment directories in data/
+//! 1. **Enumerate**: List the environment names stored in the repository
 //! 2. **Load Summaries**: Extract lightweight info from each environment
 //! 3. **Graceful Degradation**: Continue on per-environment errors
 //! 4. **Report Failures**: Include failed environments in the result
+//! 5. **Filter and Sort**: Apply the `ListFilter` to the loaded environments
 //!
 //! ## Design Rationale
 //!
-//! This command works directly with the data directory rather than through
-//! the repository abstraction because:
-//!
-//! - Need to enumerate all environments (repository has no list method)
-//! - Must handle partially corrupted data gracefully
-//! - Performance: lightweight scanning without full deserialization where possible
+//! Listing the names does not read the stored environments, so an environment
+//! whose data is corrupted is still found and reported as a failure instead
+//! of being silently left out.
 
-use std::fs;
 use std::path::Path;
 use std::sync::Arc;
 
 use tracing::{instrument, warn};
 
 use super::errors::ListCommandHandlerError;
+use super::filter::ListFilter;
 use super::info::{EnvironmentList, EnvironmentSummary};
+use crate::application::command_handlers::show::{EnvironmentInfo, ShowCommandHandler};
 use crate::application::traits::RepositoryProvider;
 use crate::domain::environment::name::EnvironmentName;
-use crate::domain::environment::repository::EnvironmentRepository;
+use crate::domain::environment::repository::{EnvironmentRepository, RepositoryError};
 use crate::domain::environment::state::AnyEnvironmentState;
+use crate::shared::Clock;
 
 /// `ListCommandHandler` scans and lists all environments
 ///
@@ -53,6 +53,7 @@ use crate::domain::environment::state::AnyEnvironmentState;
 pub struct ListCommandHandler {
     file_repository_factory: Arc<dyn RepositoryProvider>,
     data_directory: Arc<Path>,
+    clock: Arc<dyn Clock>,
 }
 
 impl ListCommandHandler {
@@ -61,17 +62,22 @@ impl ListCommandHandler {
     pub fn new(
         file_repository_factory: Arc<dyn RepositoryProvider>,
         data_directory: Arc<Path>,
+        clock: Arc<dyn Clock>,
     ) -> Self {
         Self {
             file_repository_factory,
             data_directory,
+            clock,
         }
     }
 
     /// Execute the list command workflow
     ///
-    /// Scans the data directory and extracts summary information for all
-    /// environments found.
+    /// Scans the data directory and extracts summary information for the
+    /// environments matching `filter`, in the order requested by the filter.
+    ///
+    /// Environments that fail to load are reported when their name matches
+    /// the filter's name pattern, since their state and age are unknown.
     ///
     /// # Returns
     ///
@@ -83,15 +89,18 @@ impl ListCommandHandler {
     /// Returns an error if:
     /// * Data directory does not exist
     /// * Permission denied accessing data directory
+    /// * The filter contains an unknown state
     #[instrument(
-        name = "list_command",
+        name = "command.list",
         skip_all,
         fields(
             command_type = "list",
             data_directory = %self.data_directory.display()
         )
     )]
-    pub fn execute(&self) -> Result<EnvironmentList, ListCommandHandlerError> {
+    pub fn execute(&self, filter: &ListFilter) -> Result<EnvironmentList, ListCommandHandlerError> {
+        filter.validate()?;
+
         // Verify data directory exists
         if !self.data_directory.exists() {
             return Err(ListCommandHandlerError::DataDirectoryNotFound {
@@ -99,11 +108,20 @@ impl ListCommandHandler {
             });
         }
 
-        // Scan for environment directories
-        let env_dirs = self.scan_environment_directories()?;
+        // Enumerate the stored environments
+        let env_dirs = self.stored_environment_names()?;
 
-        // Load summaries for each environment
-        let (summaries, failures) = self.load_environment_summaries(&env_dirs);
+        // Load each environment
+        let (mut environments, mut failures) = self.load_environments(&env_dirs);
+
+        // Filter and sort
+        let now = self.clock.now();
+        environments.retain(|env| filter.matches(env, now));
+        environments.sort_by(|a, b| filter.compare(a, b));
+        failures.retain(|(name, _)| filter.matches_name(name));
+        failures.sort();
+
+        let summaries = environments.iter().map(Self::extract_summary).collect();
 
         Ok(EnvironmentList::new(
             summaries,
@@ -112,10 +130,59 @@ impl ListCommandHandler {
         ))
     }
 
-    /// Scan the data directory for environment subdirectories
-    fn scan_environment_directories(&self) -> Result<Vec<String>, ListCommandHandlerError> {
-        let entries = fs::read_dir(&self.data_directory).map_err(|e| {
-            if e.kind() == std::io::ErrorKind::PermissionDenied {
+    /// Load the full details of every environment in one pass over the workspace
+    ///
+    /// Returns the same information as the show command for each environment,
+    /// sorted by name. Environments that fail to load are logged and skipped,
+    /// so callers polling the whole workspace (e.g. dashboards) get every
+    /// readable environment instead of a single failure.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if:
+    /// * Data directory does not exist
+    /// * Permission denied accessing data directory
+    #[instrument(
+        name = "command.list_details",
+        skip_all,
+        fields(
+            command_type = "list",
+            data_directory = %self.data_directory.display()
+        )
+    )]
+    pub fn execute_details(&self) -> Result<Vec<EnvironmentInfo>, ListCommandHandlerError> {
+        if !self.data_directory.exists() {
+            return Err(ListCommandHandlerError::DataDirectoryNotFound {
+                path: self.data_directory.to_path_buf(),
+            });
+        }
+
+        let env_dirs = self.stored_environment_names()?;
+        let (mut environments, _failures) = self.load_environments(&env_dirs);
+
+        environments.sort_by(|a, b| a.name().as_str().cmp(b.name().as_str()));
+
+        Ok(environments
+            .iter()
+            .map(ShowCommandHandler::extract_info)
+            .collect())
+    }
+
+    /// List the names of the environments stored in the repository
+    fn stored_environment_names(&self) -> Result<Vec<String>, ListCommandHandlerError> {
+        let repository = self
+            .file_repository_factory
+            .create(self.data_directory.to_path_buf());
+
+        let names = repository.list().map_err(|e| {
+            let permission_denied = matches!(
+                &e,
+                RepositoryError::Internal(inner) if inner
+                    .downcast_ref::<std::io::Error>()
+                    .is_some_and(|io| io.kind() == std::io::ErrorKind::PermissionDenied)
+            );
+
+            if permission_denied {
                 ListCommandHandlerError::PermissionDenied {
                     path: self.data_directory.to_path_buf(),
                 }
@@ -126,51 +193,22 @@ impl ListCommandHandler {
             }
         })?;
 
-        let mut env_names = Vec::new();
-
-        for entry in entries {
-            let entry = match entry {
-                Ok(e) => e,
-                Err(e) => {
-                    warn!("Failed to read directory entry: {e}");
-                    continue;
-                }
-            };
-
-            // Only consider directories (environments are stored in subdirectories)
-            let path = entry.path();
-            if !path.is_dir() {
-                continue;
-            }
-
-            // Check if this directory contains an environment.json file
-            let env_file = path.join("environment.json");
-            if !env_file.exists() {
-                continue;
-            }
-
-            // Extract directory name as environment name
-            if let Some(name) = path.file_name().and_then(|n| n.to_str()) {
-                env_names.push(name.to_string());
-            }
-        }
-
-        Ok(env_names)
+        Ok(names.iter().map(ToString::to_string).collect())
     }
 
-    /// Load summaries for all discovered environments
+    /// Load all discovered environments
     ///
-    /// Returns a tuple of (successful summaries, failed environments)
-    fn load_environment_summaries(
+    /// Returns a tuple of (loaded environments, failed environments)
+    fn load_environments(
         &self,
         env_names: &[String],
-    ) -> (Vec<EnvironmentSummary>, Vec<(String, String)>) {
-        let mut summaries = Vec::new();
+    ) -> (Vec<AnyEnvironmentState>, Vec<(String, String)>) {
+        let mut environments = Vec::new();
         let mut failures = Vec::new();
 
         for name in env_names {
-            match self.load_environment_summary(name) {
-                Ok(summary) => summaries.push(summary),
+            match self.load_environment_by_name(name) {
+                Ok(environment) => environments.push(environment),
                 Err(error) => {
                     warn!(
                         environment = %name,
@@ -182,11 +220,11 @@ impl ListCommandHandler {
             }
         }
 
-        (summaries, failures)
+        (environments, failures)
     }
 
-    /// Load summary for a single environment
-    fn load_environment_summary(&self, name: &str) -> Result<EnvironmentSummary, String> {
+    /// Load a single environment by its name
+    fn load_environment_by_name(&self, name: &str) -> Result<AnyEnvironmentState, String> {
         // Validate environment name
         let env_name = EnvironmentName::new(name.to_string())
             .map_err(|e| format!("Invalid environment name: {e}"))?;
@@ -198,10 +236,7 @@ impl ListCommandHandler {
             .create(self.data_directory.to_path_buf());
 
         // Load environment from repository
-        let any_env = Self::load_environment(&repository, &env_name)?;
-
-        // Extract summary
-        Ok(Self::extract_summary(&any_env))
+        Self::load_environment(&repository, &env_name)
     }
 
     /// Load environment from repository
@@ -223,5 +258,6 @@ impl ListCommandHandler {
         let created_at = any_env.created_at().to_rfc3339();
 
         EnvironmentSummary::new(name, state, provider, created_at)
+            .with_labels(any_env.labels().as_map().clone())
     }
 }
@@ -4,6 +4,8 @@
 //! for list display purposes. They provide a clean separation between the domain
 //! model and the presentation layer.
 
+use std::collections::BTreeMap;
+
 use serde::Serialize;
 
 /// Lightweight environment summary for list display
@@ -24,6 +26,10 @@ pub struct EnvironmentSummary {
 
     /// When the environment was created (ISO 8601 format)
     pub created_at: String,
+
+    /// Labels of the environment (e.g. `team: infra`)
+    #[serde(skip_serializing_if = "BTreeMap::is_empty")]
+    pub labels: BTreeMap<String, String>,
 }
 
 impl EnvironmentSummary {
@@ -35,8 +41,16 @@ impl EnvironmentSummary {
             state,
             provider,
             created_at,
+            labels: BTreeMap::new(),
         }
     }
+
+    /// Set the labels of the environment
+    #[must_use]
+    pub fn with_labels(mut self, labels: BTreeMap<String, String>) -> Self {
+        self.labels = labels;
+        self
+    }
 }
 
 /// Collection of environment summaries with metadata
@@ -29,6 +29,12 @@
 //! - Provider name
 //! - Creation timestamp (ISO 8601)
 //!
+//! ## Filtering and Sorting
+//!
+//! A [`ListFilter`] restricts the result by state, name glob and age, and
+//! sets the sort order (name, state or creation time). Filtering happens
+//! here so the CLI and the SDK behave the same.
+//!
 //! ## Error Handling Strategy
 //!
 //! - **Empty directory**: Not an error - shows friendly message, exit code 0
@@ -36,6 +42,7 @@
 //! - **Partial failure**: Shows valid environments + warnings, exit code 0
 
 pub mod errors;
+pub mod filter;
 pub mod handler;
 pub mod info;
 
@@ -43,7 +50,8 @@ pub mod info;
 mod tests;
 
 // Re-export main types for convenience
-pub use errors::ListCommandHandlerError;
+pub use errors::{ListCommandHandlerError, ListFilterError};
+pub use filter::{ListFilter, ListSort};
 pub use handler::ListCommandHandler;
 pub use info::EnvironmentList;
 pub use info::EnvironmentSummary;
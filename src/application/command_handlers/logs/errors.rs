@@ -0,0 +1,122 @@
+//! Error types for logs command handler
+
+use crate::application::errors::PersistenceError;
+use crate::shared::error::kind::ErrorKind;
+use crate::shared::error::traceable::Traceable;
+
+/// Comprehensive error type for the `LogsCommandHandler`
+#[derive(Debug, thiserror::Error)]
+pub enum LogsCommandHandlerError {
+    #[error("Environment not found: '{name}'")]
+    EnvironmentNotFound { name: String },
+
+    #[error("Log '{file}' not found for environment '{name}'")]
+    LogNotFound { name: String, file: String },
+
+    #[error("Failed to load environment: {0}")]
+    LoadError(#[from] PersistenceError),
+
+    #[error("Failed to read run logs: {0}")]
+    LogRead(#[source] std::io::Error),
+}
+
+impl From<crate::domain::environment::repository::RepositoryError> for LogsCommandHandlerError {
+    fn from(e: crate::domain::environment::repository::RepositoryError) -> Self {
+        Self::LoadError(e.into())
+    }
+}
+
+impl Traceable for LogsCommandHandlerError {
+    fn trace_format(&self) -> String {
+        match self {
+            Self::EnvironmentNotFound { name } => {
+                format!("LogsCommandHandlerError: Environment not found - '{name}'")
+            }
+            Self::LogNotFound { name, file } => {
+                format!("LogsCommandHandlerError: Log not found - '{name}/{file}'")
+            }
+            Self::LoadError(e) => {
+                format!("LogsCommandHandlerError: Failed to load environment - {e}")
+            }
+            Self::LogRead(e) => {
+                format!("LogsCommandHandlerError: Failed to read run logs - {e}")
+            }
+        }
+    }
+
+    fn trace_source(&self) -> Option<&dyn Traceable> {
+        None
+    }
+
+    fn error_kind(&self) -> ErrorKind {
+        match self {
+            Self::EnvironmentNotFound { .. } | Self::LogNotFound { .. } => ErrorKind::Configuration,
+            Self::LoadError(_) => ErrorKind::StatePersistence,
+            Self::LogRead(_) => ErrorKind::FileSystem,
+        }
+    }
+}
+
+impl LogsCommandHandlerError {
+    /// Provides detailed troubleshooting guidance for this error
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use torrust_tracker_deployer_lib::application::command_handlers::logs::LogsCommandHandlerError;
+    ///
+    /// let error = LogsCommandHandlerError::LogNotFound {
+    ///     name: "my-env".to_string(),
+    ///     file: "provision/missing.log".to_string(),
+    /// };
+    ///
+    /// assert!(error.help().contains("--local"));
+    /// ```
+    #[must_use]
+    pub fn help(&self) -> &'static str {
+        match self {
+            Self::EnvironmentNotFound { .. } => {
+                "Environment Not Found - Troubleshooting:
+
+1. Verify the environment name is correct
+2. List the existing environments:
+   torrust-tracker-deployer list
+
+For more information, see docs/user-guide/commands/logs.md"
+            }
+            Self::LogNotFound { .. } => {
+                "Log Not Found - Troubleshooting:
+
+1. List the available logs:
+   torrust-tracker-deployer logs <name> --local
+
+2. Pass the path exactly as listed, e.g.:
+   torrust-tracker-deployer logs <name> --local --show provision/20251008T143045.000000000Z-tofu-apply.log
+
+3. Only the most recent runs of each command are kept
+   (kept_run_logs in deployer-workspace.json)
+
+For more information, see docs/user-guide/commands/logs.md"
+            }
+            Self::LoadError(_) => {
+                "Failed to Load Environment - Troubleshooting:
+
+1. Check file permissions on the data/ directory
+2. Verify the environment state file is not corrupted:
+   cat data/<name>/environment.json
+
+For more information, see docs/user-guide/commands/logs.md"
+            }
+            Self::LogRead(_) => {
+                "Failed to Read Run Logs - Troubleshooting:
+
+1. Check file permissions on the logs directory:
+   ls -l build/<name>/logs/
+
+2. Log files are only readable by the user who ran the command
+
+For more information, see docs/user-guide/commands/logs.md"
+            }
+        }
+    }
+}
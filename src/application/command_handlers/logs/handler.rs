@@ -0,0 +1,187 @@
+//! Logs command handler implementation
+//!
+//! **Purpose**: List and read the local run logs of an environment
+//!
+//! This handler loads the environment to locate its build directory, then
+//! reads the logs stored in `build/{env}/logs/`.
+
+use std::sync::Arc;
+
+use tracing::instrument;
+
+use super::errors::LogsCommandHandlerError;
+use crate::domain::environment::repository::EnvironmentRepository;
+use crate::domain::EnvironmentName;
+use crate::shared::command::{list_run_logs, RunLogFile};
+
+/// `LogsCommandHandler` lists and reads the run logs of an environment
+///
+/// **Purpose**: Read-only access to the output of past tool invocations
+///
+/// This handler never modifies state or makes network calls.
+pub struct LogsCommandHandler {
+    repository: Arc<dyn EnvironmentRepository + Send + Sync>,
+}
+
+impl LogsCommandHandler {
+    /// Create a new `LogsCommandHandler`
+    #[must_use]
+    pub fn new(repository: Arc<dyn EnvironmentRepository + Send + Sync>) -> Self {
+        Self { repository }
+    }
+
+    /// List the run logs of an environment, oldest run first
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if:
+    /// * Environment not found
+    /// * Environment state file is corrupted or unreadable
+    /// * The logs directory cannot be read
+    #[instrument(
+        name = "command.logs",
+        skip_all,
+        fields(
+            command_type = "logs",
+            environment_name = %env_name
+        )
+    )]
+    pub fn list(
+        &self,
+        env_name: &EnvironmentName,
+    ) -> Result<Vec<RunLogFile>, LogsCommandHandlerError> {
+        let any_env = self.repository.load(env_name)?.ok_or_else(|| {
+            LogsCommandHandlerError::EnvironmentNotFound {
+                name: env_name.to_string(),
+            }
+        })?;
+
+        list_run_logs(&any_env.run_logs_dir()).map_err(LogsCommandHandlerError::LogRead)
+    }
+
+    /// Read one run log of an environment
+    ///
+    /// # Arguments
+    ///
+    /// * `env_name` - The name of the environment
+    /// * `relative_path` - Path of the file as listed (`{command}/{file}`)
+    ///
+    /// # Returns
+    ///
+    /// The file and its content.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if:
+    /// * Environment not found
+    /// * No listed file has the given path
+    /// * The file cannot be read
+    #[instrument(
+        name = "command.logs",
+        skip_all,
+        fields(
+            command_type = "logs",
+            environment_name = %env_name
+        )
+    )]
+    pub fn show(
+        &self,
+        env_name: &EnvironmentName,
+        relative_path: &str,
+    ) -> Result<(RunLogFile, String), LogsCommandHandlerError> {
+        // Only files from the listing are accepted, never arbitrary paths
+        let file = self
+            .list(env_name)?
+            .into_iter()
+            .find(|file| file.relative_path() == relative_path)
+            .ok_or_else(|| LogsCommandHandlerError::LogNotFound {
+                name: env_name.to_string(),
+                file: relative_path.to_string(),
+            })?;
+
+        let content =
+            std::fs::read_to_string(&file.path).map_err(LogsCommandHandlerError::LogRead)?;
+
+        Ok((file, content))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::path::PathBuf;
+
+    use chrono::{TimeZone, Utc};
+
+    use super::*;
+    use crate::domain::environment::testing::EnvironmentTestBuilder;
+    use crate::infrastructure::persistence::file_repository_factory::FileRepositoryFactory;
+    use crate::shared::command::RunLog;
+
+    /// Handler with a saved `my-env` environment and its logs directory
+    fn setup() -> (LogsCommandHandler, PathBuf, tempfile::TempDir) {
+        let (env, _data_dir, _build_dir, temp_dir) = EnvironmentTestBuilder::new()
+            .with_name("my-env")
+            .build_with_custom_paths();
+        let logs_dir = env.run_logs_dir();
+        let repository = FileRepositoryFactory::new(std::time::Duration::from_secs(30))
+            .create(temp_dir.path().join("data"));
+        repository.save(&env.into_any()).unwrap();
+
+        (LogsCommandHandler::new(repository), logs_dir, temp_dir)
+    }
+
+    fn env_name() -> EnvironmentName {
+        EnvironmentName::new("my-env".to_string()).unwrap()
+    }
+
+    fn write_apply_log(logs_dir: &std::path::Path) -> RunLog {
+        let started_at = Utc.with_ymd_and_hms(2026, 10, 16, 10, 0, 0).unwrap();
+        let run_log = RunLog::new(logs_dir, "provision", started_at);
+        run_log
+            .record(
+                "tofu-apply",
+                "tofu apply",
+                "exit status: 0",
+                "Apply complete!",
+                "",
+            )
+            .unwrap();
+        run_log
+    }
+
+    #[test]
+    fn it_should_show_the_tofu_apply_log_of_a_provision_run() {
+        let (handler, logs_dir, _temp_dir) = setup();
+        write_apply_log(&logs_dir);
+
+        let (file, content) = handler
+            .show(
+                &env_name(),
+                "provision/20261016T100000.000000000Z-tofu-apply.log",
+            )
+            .unwrap();
+
+        assert_eq!(file.step, "tofu-apply");
+        assert!(content.contains("Apply complete!"));
+    }
+
+    #[test]
+    fn it_should_list_nothing_when_no_command_ran() {
+        let (handler, _logs_dir, _temp_dir) = setup();
+
+        assert!(handler.list(&env_name()).unwrap().is_empty());
+    }
+
+    #[test]
+    fn it_should_refuse_paths_that_are_not_listed() {
+        let (handler, logs_dir, _temp_dir) = setup();
+        write_apply_log(&logs_dir);
+
+        let result = handler.show(&env_name(), "provision/../../environment.json");
+
+        assert!(matches!(
+            result,
+            Err(LogsCommandHandlerError::LogNotFound { .. })
+        ));
+    }
+}
@@ -0,0 +1,24 @@
+//! Logs Command Module
+//!
+//! This module implements the delivery-agnostic `LogsCommandHandler`
+//! for listing and reading the local run logs of an environment.
+//!
+//! ## Run Logs
+//!
+//! Every external tool invocation (OpenTofu, Ansible) of a deployer command
+//! writes its complete stdout and stderr to
+//! `build/{env}/logs/{command}/{run_id}-{step}.log`, for successful runs too.
+//! Only the most recent runs of each command are kept.
+//!
+//! ## Design Principles
+//!
+//! - **Read-Only Operation**: Never modifies environment state or logs
+//! - **No Network Calls**: Reads local files only
+//! - **Listed Files Only**: A file is selected by its listed path, never by an arbitrary path
+
+pub mod errors;
+pub mod handler;
+
+// Re-export main types for convenience
+pub use errors::LogsCommandHandlerError;
+pub use handler::LogsCommandHandler;
@@ -9,49 +9,117 @@
 //!
 //! ## Available Command Handlers
 //!
+//! - `backup` - Back up the tracker database of a deployed environment
+//! - `clone` - Duplicate an environment configuration under a new name
 //! - `configure` - Infrastructure configuration and software installation
 //! - `create` - Environment creation and initialization
+//! - `deploy` - Run the deployment workflow up to a target state, resuming partial deployments
 //! - `destroy` - Infrastructure destruction and teardown
+//! - `doctor` - Diagnose the local toolchain and workspace (read-only)
+//! - `drift` - Detect configuration drift on the instance of a deployed environment
 //! - `exists` - Check whether an environment exists (read-only)
+//! - `export` - Write an environment to a bundle file that can be imported on another machine
+//! - `init` - Initialize a workspace, creating its layout and marker file
+//! - `import` - Adopt an existing instance as a new environment
+//! - `import_bundle` - Add an environment exported from another workspace
+//! - `keys` - Add, list and remove the authentication keys of a private tracker
+//! - `label` - Set and remove the labels used to organize environments
+//! - `last_failure` - Report the most recent command failure of an environment (read-only)
 //! - `list` - List all environments in the workspace (read-only)
+//! - `orphans` - Find and delete resources left behind by removed environments
 //! - `provision` - Infrastructure provisioning using `OpenTofu`
 //! - `purge` - Remove all local environment data
 //! - `register` - Register existing instances as alternative to provisioning
-//! - `release` - Software release to target instances/// - `render` - Generate deployment artifacts without executing deployment//! - `run` - Stack execution on target instances
+//! - `release` - Software release to target instances
+//! - `restore` - Restore the tracker database of a deployed environment from a backup
+//! - `rename` - Give an environment a new name, moving its local directories
+//! - `render` - Generate deployment artifacts without executing deployment
+//! - `run` - Stack execution on target instances
+//! - `reports` - List and read the deployment reports of an environment (read-only)
+//! - `rollback` - Redeploy the previously released tracker version
 //! - `show` - Display environment information and status (read-only)
+//! - `start` - Start the stopped tracker services of an environment again
+//! - `stats` - Deployment phase durations of one or all environments (read-only)
+//! - `stop` - Stop the tracker services of a running environment for maintenance
 //! - `test` - Deployment testing and validation
 //! - `validate` - Validate environment configuration files (read-only)
 //!
 //! Each command handler encapsulates a complete business workflow, handling orchestration,
 //! error management, and coordination across multiple infrastructure services.
 
+pub mod backup;
+pub mod clone;
 pub mod common;
 pub mod configure;
 pub mod create;
+pub mod deploy;
 pub mod destroy;
+pub mod diff;
+pub mod doctor;
+pub mod drift;
 pub mod exists;
+pub mod export;
+pub mod import;
+pub mod import_bundle;
+pub mod init;
+pub mod keys;
+pub mod label;
+pub mod last_failure;
 pub mod list;
+pub mod logs;
+pub mod orphans;
 pub mod provision;
 pub mod purge;
 pub mod register;
 pub mod release;
+pub mod rename;
 pub mod render;
+pub mod repair;
+pub mod reports;
+pub mod restore;
+pub mod rollback;
 pub mod run;
 pub mod show;
+pub mod start;
+pub mod stats;
+pub mod stop;
 pub mod test;
 pub mod validate;
 
+pub use backup::BackupCommandHandler;
+pub use clone::CloneCommandHandler;
 pub use configure::ConfigureCommandHandler;
 pub use create::CreateCommandHandler;
+pub use deploy::DeployCommandHandler;
 pub use destroy::DestroyCommandHandler;
+pub use diff::DiffCommandHandler;
+pub use doctor::DoctorCommandHandler;
+pub use drift::DriftCommandHandler;
 pub use exists::ExistsCommandHandler;
+pub use export::ExportCommandHandler;
+pub use import::ImportCommandHandler;
+pub use import_bundle::ImportBundleCommandHandler;
+pub use init::InitCommandHandler;
+pub use keys::KeysCommandHandler;
+pub use label::LabelCommandHandler;
+pub use last_failure::LastFailureCommandHandler;
 pub use list::ListCommandHandler;
+pub use logs::LogsCommandHandler;
+pub use orphans::OrphansCommandHandler;
 pub use provision::ProvisionCommandHandler;
 pub use purge::handler::PurgeCommandHandler;
 pub use register::RegisterCommandHandler;
 pub use release::ReleaseCommandHandler;
+pub use rename::RenameCommandHandler;
 pub use render::RenderCommandHandler;
+pub use repair::RepairCommandHandler;
+pub use reports::ReportsCommandHandler;
+pub use restore::RestoreCommandHandler;
+pub use rollback::RollbackCommandHandler;
 pub use run::RunCommandHandler;
 pub use show::ShowCommandHandler;
+pub use start::StartCommandHandler;
+pub use stats::StatsCommandHandler;
+pub use stop::StopCommandHandler;
 pub use test::TestCommandHandler;
 pub use validate::ValidateCommandHandler;
@@ -0,0 +1,96 @@
+//! Error types for the orphans command handler
+
+use std::path::PathBuf;
+
+use crate::domain::environment::repository::RepositoryError;
+use crate::shared::error::kind::ErrorKind;
+use crate::shared::error::traceable::Traceable;
+
+/// Comprehensive error type for the `OrphansCommandHandler`
+///
+/// LXD being unavailable and individual deletion failures are not errors:
+/// they are recorded in the `OrphanReport` so the remaining resources are
+/// still reported and cleaned up.
+#[derive(Debug, thiserror::Error)]
+pub enum OrphansCommandHandlerError {
+    /// Failed to read a workspace directory (`data/` or `build/`)
+    #[error("Failed to scan directory '{path}': {source}")]
+    ScanFailed {
+        /// Path of the directory that could not be read
+        path: PathBuf,
+        /// The underlying I/O error
+        #[source]
+        source: std::io::Error,
+    },
+
+    /// Failed to list the environments stored in the repository
+    #[error("Failed to list the environments of the repository: {0}")]
+    ListFailed(#[source] RepositoryError),
+}
+
+impl Traceable for OrphansCommandHandlerError {
+    fn trace_format(&self) -> String {
+        match self {
+            Self::ScanFailed { path, source } => {
+                format!(
+                    "OrphansCommandHandlerError: Failed to scan directory '{}' - {source}",
+                    path.display()
+                )
+            }
+            Self::ListFailed(source) => {
+                format!("OrphansCommandHandlerError: Failed to list environments - {source}")
+            }
+        }
+    }
+
+    fn trace_source(&self) -> Option<&dyn Traceable> {
+        None
+    }
+
+    fn error_kind(&self) -> ErrorKind {
+        match self {
+            Self::ScanFailed { .. } => ErrorKind::FileSystem,
+            Self::ListFailed(_) => ErrorKind::StatePersistence,
+        }
+    }
+}
+
+impl OrphansCommandHandlerError {
+    /// Provides detailed troubleshooting guidance for this error
+    #[must_use]
+    pub fn help(&self) -> &'static str {
+        match self {
+            Self::ScanFailed { .. } => {
+                "Directory Scan Failed - Troubleshooting:
+
+1. Verify current directory:
+   - Run: pwd
+   - Expected: Your deployer workspace directory
+
+2. Check directory permissions:
+   - Run: ls -ld data/ build/
+   - Should have read permission (r--)
+
+3. Fix permissions if needed:
+   - Run: chmod +rx data/ build/
+
+For more information, see docs/user-guide/commands/orphans.md"
+            }
+            Self::ListFailed(_) => {
+                "Environment Listing Failed - Troubleshooting:
+
+1. Check the workspace database is readable:
+   - Run: ls -l data/workspace.db
+   - Should have read and write permission (rw-)
+
+2. Retry if another deployer process is running:
+   - The repository may have been locked by it
+
+Without the list of environments, no resource can be safely reported as
+orphaned.
+
+For more information, see docs/user-guide/commands/orphans.md"
+            }
+        }
+    }
+}
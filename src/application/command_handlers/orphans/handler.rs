@@ -0,0 +1,586 @@
+//! Orphans command handler implementation
+//!
+//! **Purpose**: Find and optionally delete resources left behind by
+//! environments that no longer exist in the workspace
+//!
+//! ## Design Strategy
+//!
+//! 1. **Known Environments**: Collect the environment directories in `data/`
+//!    and the LXD resources referenced by the environments that can be loaded
+//! 2. **Candidates**: List LXD instances, LXD profiles and `build/` directories;
+//!    the LXD resources are listed on the local daemon and on every LXD
+//!    remote a loaded environment is created on
+//! 3. **Detection**: Keep the candidates whose name matches a generated
+//!    pattern and that no environment uses, plus the resources of destroyed
+//!    environments whatever their name (custom instance and profile names
+//!    are only known from the repository)
+//! 4. **Deletion** (optional): Delete instances first (profiles in use cannot
+//!    be deleted), then profiles, then build directories
+//!
+//! LXD being unavailable is not an error: build directories and the other
+//! LXD servers are still checked and the LXD errors are included in the report.
+
+use std::collections::{HashMap, HashSet};
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+use std::sync::Arc;
+
+use tracing::{info, instrument, warn};
+
+use super::errors::OrphansCommandHandlerError;
+use super::info::{OrphanKind, OrphanReport, OrphanResource, OrphanStatus};
+use crate::adapters::lxd::{InstanceName, LxdClient};
+use crate::domain::environment::repository::EnvironmentRepository;
+use crate::domain::environment::state::AnyEnvironmentState;
+use crate::domain::provider::{LxdRemote, Provider};
+use crate::domain::{EnvironmentName, ProfileName};
+
+/// Prefixes of the LXD profile names generated by the deployer and its tests
+const PROFILE_PREFIXES: &[&str] = &[ProfileName::GENERATED_PREFIX, "torrust-profile-"];
+
+/// Environments known to the workspace
+#[derive(Debug, Default)]
+struct KnownEnvironments {
+    /// Names of the directories in `data/` and of the stored environments
+    names: HashSet<String>,
+
+    /// Names of the environments whose state could be loaded
+    loaded: HashSet<String>,
+
+    /// LXD instance and profile names used by the loaded environments
+    lxd_resources: HashSet<String>,
+
+    /// LXD instance and profile names of destroyed environments, with the
+    /// environment they belonged to
+    released_lxd_resources: HashMap<String, String>,
+
+    /// LXD remotes the loaded environments are created on, by name
+    lxd_remotes: Vec<LxdRemote>,
+}
+
+impl KnownEnvironments {
+    /// Check whether a resource generated for `environment` is still in use
+    ///
+    /// A loaded environment only owns the resources it references, since
+    /// its instance and profile names may be custom. An environment that
+    /// cannot be loaded keeps every resource named after it.
+    fn owns(&self, environment: &str, resource: &str) -> bool {
+        self.lxd_resources.contains(resource)
+            || (self.names.contains(environment) && !self.loaded.contains(environment))
+    }
+
+    /// Record the LXD resources referenced by a loaded environment
+    fn add_loaded(&mut self, name: &str, environment: &AnyEnvironmentState) {
+        self.loaded.insert(name.to_string());
+
+        if let Some(remote) = environment
+            .user_inputs()
+            .provider_config()
+            .as_lxd()
+            .and_then(|lxd| lxd.remote.as_ref())
+        {
+            if !self
+                .lxd_remotes
+                .iter()
+                .any(|known| known.name() == remote.name())
+            {
+                self.lxd_remotes.push(remote.clone());
+            }
+        }
+
+        let mut resources = vec![environment.instance_name().to_string()];
+        if environment.provider_name() == Provider::Lxd.as_str() {
+            resources.push(environment.profile_name().to_string());
+        }
+
+        // A destroyed environment no longer uses its resources
+        if matches!(environment, AnyEnvironmentState::Destroyed(_)) {
+            self.released_lxd_resources.extend(
+                resources
+                    .into_iter()
+                    .map(|resource| (resource, name.to_string())),
+            );
+        } else {
+            self.lxd_resources.extend(resources);
+        }
+    }
+
+    /// Environment a released resource belonged to, unless another
+    /// environment uses the resource again
+    fn released_by(&self, resource: &str) -> Option<&String> {
+        self.released_lxd_resources
+            .get(resource)
+            .filter(|_| !self.lxd_resources.contains(resource))
+    }
+}
+
+/// `OrphansCommandHandler` finds and deletes orphaned deployer resources
+///
+/// ## Error Handling
+///
+/// - **LXD unavailable**: Recorded in the report, build directories are still checked
+/// - **Deletion failures**: Recorded per resource, remaining resources are still deleted
+/// - **Fatal errors**: `data/` or `build/` cannot be read
+pub struct OrphansCommandHandler {
+    repository: Arc<dyn EnvironmentRepository + Send + Sync>,
+    working_dir: PathBuf,
+    lxd_client: LxdClient,
+}
+
+impl OrphansCommandHandler {
+    /// Create a new `OrphansCommandHandler`
+    ///
+    /// # Arguments
+    ///
+    /// * `repository` - Repository for loading the known environments
+    /// * `working_dir` - Root directory containing `data/` and `build/` subdirectories
+    #[must_use]
+    pub fn new(
+        repository: Arc<dyn EnvironmentRepository + Send + Sync>,
+        working_dir: PathBuf,
+    ) -> Self {
+        Self {
+            repository,
+            working_dir,
+            lxd_client: LxdClient::new(),
+        }
+    }
+
+    /// Execute the orphans command workflow
+    ///
+    /// # Arguments
+    ///
+    /// * `delete` - Delete the orphaned resources instead of only reporting them
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the `data/` or `build/` directory exists but cannot
+    /// be read
+    #[instrument(
+        name = "command.orphans",
+        skip_all,
+        fields(
+            command_type = "orphans",
+            working_dir = %self.working_dir.display(),
+            delete = delete
+        )
+    )]
+    pub fn execute(&self, delete: bool) -> Result<OrphanReport, OrphansCommandHandlerError> {
+        let known = self.collect_known_environments()?;
+        let build_dirs = list_directory_names(&self.working_dir.join("build"))?;
+
+        let mut lxd_errors = Vec::new();
+
+        let (lxd_instances, lxd_profiles) =
+            list_lxd_resources(&self.lxd_client).unwrap_or_else(|error| {
+                lxd_errors.push(error);
+                (Vec::new(), Vec::new())
+            });
+        let mut orphans = detect_orphans(&known, &lxd_instances, &lxd_profiles, &build_dirs);
+
+        let remote_clients: HashMap<String, LxdClient> = known
+            .lxd_remotes
+            .iter()
+            .map(|remote| (remote.name().to_string(), remote_lxd_client(remote)))
+            .collect();
+        for remote in &known.lxd_remotes {
+            match list_lxd_resources(&remote_clients[remote.name()]) {
+                Ok((instances, profiles)) => orphans.extend(
+                    detect_orphans(&known, &instances, &profiles, &[])
+                        .into_iter()
+                        .map(|orphan| orphan.on_remote(remote.name())),
+                ),
+                Err(error) => lxd_errors.push(format!("remote '{}': {error}", remote.name())),
+            }
+        }
+        orphans.sort_by_key(|orphan| deletion_order(orphan.kind));
+
+        if delete {
+            for orphan in &mut orphans {
+                let lxd_client = match &orphan.remote {
+                    Some(remote) => &remote_clients[remote],
+                    None => &self.lxd_client,
+                };
+                orphan.status = match self.delete_orphan(orphan, lxd_client) {
+                    Ok(()) => OrphanStatus::Deleted,
+                    Err(error) => {
+                        warn!(
+                            kind = %orphan.kind,
+                            name = %orphan.name,
+                            error = %error,
+                            "Failed to delete orphaned resource"
+                        );
+                        OrphanStatus::DeleteFailed { error }
+                    }
+                };
+            }
+        }
+
+        Ok(OrphanReport {
+            orphans,
+            delete_requested: delete,
+            lxd_error: (!lxd_errors.is_empty()).then(|| lxd_errors.join("; ")),
+        })
+    }
+
+    /// Collect the environments in `data/` or in the repository and the LXD
+    /// resources they use
+    fn collect_known_environments(&self) -> Result<KnownEnvironments, OrphansCommandHandlerError> {
+        let mut known = KnownEnvironments {
+            names: list_directory_names(&self.working_dir.join("data"))?
+                .into_iter()
+                .collect(),
+            ..KnownEnvironments::default()
+        };
+
+        // Repositories that do not keep one directory per environment
+        let stored = self
+            .repository
+            .list()
+            .map_err(OrphansCommandHandlerError::ListFailed)?;
+        known.names.extend(stored.iter().map(ToString::to_string));
+
+        for name in known.names.clone() {
+            let Ok(env_name) = EnvironmentName::new(name.clone()) else {
+                continue;
+            };
+
+            match self.repository.load(&env_name) {
+                Ok(Some(environment)) => known.add_loaded(&name, &environment),
+                Ok(None) => {}
+                Err(error) => warn!(
+                    environment = %name,
+                    error = %error,
+                    "Failed to load environment, keeping its resources"
+                ),
+            }
+        }
+
+        Ok(known)
+    }
+
+    /// Delete a single orphaned resource, using `lxd_client` for LXD resources
+    fn delete_orphan(&self, orphan: &OrphanResource, lxd_client: &LxdClient) -> Result<(), String> {
+        info!(kind = %orphan.kind, name = %orphan.qualified_name(), "Deleting orphaned resource");
+
+        match orphan.kind {
+            OrphanKind::LxdInstance => {
+                let instance_name =
+                    InstanceName::new(orphan.name.clone()).map_err(|e| e.to_string())?;
+                lxd_client
+                    .delete_instance(&instance_name, true)
+                    .map_err(|e| format!("{e:#}"))
+            }
+            OrphanKind::LxdProfile => lxd_client
+                .delete_profile(&orphan.name)
+                .map_err(|e| format!("{e:#}")),
+            OrphanKind::BuildDirectory => {
+                fs::remove_dir_all(self.working_dir.join("build").join(&orphan.name))
+                    .map_err(|e| e.to_string())
+            }
+        }
+    }
+}
+
+/// List the LXD instance and profile names of the server `lxd_client` queries
+///
+/// Returns the error message if the server cannot be queried.
+fn list_lxd_resources(lxd_client: &LxdClient) -> Result<(Vec<String>, Vec<String>), String> {
+    lxd_client
+        .list_instance_names()
+        .and_then(|instances| Ok((instances, lxd_client.list_profile_names()?)))
+        .map_err(|error| {
+            warn!(error = %error, "Failed to list LXD resources, skipping LXD checks");
+            format!("{error:#}")
+        })
+}
+
+/// LXD client querying `remote`
+fn remote_lxd_client(remote: &LxdRemote) -> LxdClient {
+    let client = LxdClient::new().with_remote(remote.name());
+    match remote.config_dir() {
+        Some(config_dir) => client.with_config_dir(config_dir),
+        None => client,
+    }
+}
+
+/// Position of a resource kind in the deletion order: instances first, since
+/// profiles in use cannot be deleted, then profiles, then build directories
+fn deletion_order(kind: OrphanKind) -> u8 {
+    match kind {
+        OrphanKind::LxdInstance => 0,
+        OrphanKind::LxdProfile => 1,
+        OrphanKind::BuildDirectory => 2,
+    }
+}
+
+/// Detect orphaned resources among the candidates
+///
+/// Names following the generated patterns are considered when the
+/// environment part is a valid environment name; resources released by a
+/// destroyed environment are considered whatever their name. The result is
+/// ordered for deletion: instances, then profiles, then build directories.
+fn detect_orphans(
+    known: &KnownEnvironments,
+    lxd_instances: &[String],
+    lxd_profiles: &[String],
+    build_dirs: &[String],
+) -> Vec<OrphanResource> {
+    let generated_for = |name: &str, prefixes: &[&str]| {
+        prefixes
+            .iter()
+            .find_map(|prefix| name.strip_prefix(prefix))
+            .filter(|environment| EnvironmentName::new(*environment).is_ok())
+            .map(ToString::to_string)
+    };
+
+    let mut orphans = Vec::new();
+
+    let orphaned_environment = |name: &String, prefixes: &[&str]| {
+        known.released_by(name).cloned().or_else(|| {
+            generated_for(name, prefixes).filter(|environment| !known.owns(environment, name))
+        })
+    };
+
+    for name in lxd_instances {
+        if let Some(environment) = orphaned_environment(name, &[InstanceName::GENERATED_PREFIX]) {
+            orphans.push(OrphanResource::new(
+                OrphanKind::LxdInstance,
+                name.clone(),
+                environment,
+            ));
+        }
+    }
+
+    for name in lxd_profiles {
+        if let Some(environment) = orphaned_environment(name, PROFILE_PREFIXES) {
+            orphans.push(OrphanResource::new(
+                OrphanKind::LxdProfile,
+                name.clone(),
+                environment,
+            ));
+        }
+    }
+
+    for name in build_dirs {
+        if EnvironmentName::new(name.as_str()).is_ok() && !known.names.contains(name) {
+            orphans.push(OrphanResource::new(
+                OrphanKind::BuildDirectory,
+                name.clone(),
+                name.clone(),
+            ));
+        }
+    }
+
+    orphans
+}
+
+/// List the names of the subdirectories of `path`, sorted
+///
+/// A missing directory has no subdirectories.
+fn list_directory_names(path: &Path) -> Result<Vec<String>, OrphansCommandHandlerError> {
+    let entries = match fs::read_dir(path) {
+        Ok(entries) => entries,
+        Err(e) if e.kind() == io::ErrorKind::NotFound => return Ok(Vec::new()),
+        Err(source) => {
+            return Err(OrphansCommandHandlerError::ScanFailed {
+                path: path.to_path_buf(),
+                source,
+            })
+        }
+    };
+
+    let mut names: Vec<String> = entries
+        .filter_map(Result::ok)
+        .filter(|entry| entry.path().is_dir())
+        .filter_map(|entry| entry.file_name().to_str().map(ToString::to_string))
+        .collect();
+    names.sort();
+
+    Ok(names)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn names(values: &[&str]) -> Vec<String> {
+        values.iter().map(ToString::to_string).collect()
+    }
+
+    fn known(environments: &[&str], lxd_resources: &[&str]) -> KnownEnvironments {
+        KnownEnvironments {
+            names: environments.iter().map(ToString::to_string).collect(),
+            lxd_resources: lxd_resources.iter().map(ToString::to_string).collect(),
+            ..KnownEnvironments::default()
+        }
+    }
+
+    #[test]
+    fn it_should_detect_lxd_resources_of_unknown_environments() {
+        let orphans = detect_orphans(
+            &known(&["e2e-deployment"], &[]),
+            &names(&[
+                "torrust-tracker-vm-e2e-deployment",
+                "torrust-tracker-vm-e2e-provision",
+            ]),
+            &names(&["lxd-e2e-deployment", "torrust-profile-e2e-provision"]),
+            &[],
+        );
+
+        assert_eq!(
+            orphans,
+            [
+                OrphanResource::new(
+                    OrphanKind::LxdInstance,
+                    "torrust-tracker-vm-e2e-provision".to_string(),
+                    "e2e-provision".to_string()
+                ),
+                OrphanResource::new(
+                    OrphanKind::LxdProfile,
+                    "torrust-profile-e2e-provision".to_string(),
+                    "e2e-provision".to_string()
+                ),
+            ]
+        );
+    }
+
+    #[test]
+    fn it_should_never_report_resources_outside_the_generated_patterns() {
+        let orphans = detect_orphans(
+            &known(&[], &[]),
+            &names(&[
+                "my-database",
+                "torrust-tracker-vm-",
+                "torrust-tracker-vm-Bad_Name",
+            ]),
+            &names(&["default", "docker", "lxd-"]),
+            &names(&["Not_An_Environment"]),
+        );
+
+        assert!(orphans.is_empty());
+    }
+
+    #[test]
+    fn it_should_keep_lxd_resources_referenced_by_a_known_environment() {
+        // An environment with a custom profile name still owns its profile
+        let orphans = detect_orphans(
+            &known(&["production"], &["torrust-profile-old-name"]),
+            &[],
+            &names(&["torrust-profile-old-name"]),
+            &[],
+        );
+
+        assert!(orphans.is_empty());
+    }
+
+    #[test]
+    fn it_should_detect_generated_names_a_loaded_environment_does_not_use() {
+        // The environment uses custom names, so the generated ones are leftovers
+        let mut known = known(&["production"], &["infra-tracker-01", "infra-profile"]);
+        known.loaded.insert("production".to_string());
+
+        let orphans = detect_orphans(
+            &known,
+            &names(&["infra-tracker-01", "torrust-tracker-vm-production"]),
+            &names(&["infra-profile"]),
+            &[],
+        );
+
+        assert_eq!(
+            orphans,
+            [OrphanResource::new(
+                OrphanKind::LxdInstance,
+                "torrust-tracker-vm-production".to_string(),
+                "production".to_string()
+            )]
+        );
+    }
+
+    #[test]
+    fn it_should_detect_custom_named_resources_of_destroyed_environments() {
+        let mut known = known(&["production"], &[]);
+        known.loaded.insert("production".to_string());
+        for resource in ["infra-tracker-01", "infra-profile"] {
+            known
+                .released_lxd_resources
+                .insert(resource.to_string(), "production".to_string());
+        }
+
+        let orphans = detect_orphans(
+            &known,
+            &names(&["infra-tracker-01", "infra-tracker-02"]),
+            &names(&["infra-profile"]),
+            &[],
+        );
+
+        assert_eq!(
+            orphans,
+            [
+                OrphanResource::new(
+                    OrphanKind::LxdInstance,
+                    "infra-tracker-01".to_string(),
+                    "production".to_string()
+                ),
+                OrphanResource::new(
+                    OrphanKind::LxdProfile,
+                    "infra-profile".to_string(),
+                    "production".to_string()
+                ),
+            ]
+        );
+    }
+
+    #[test]
+    fn it_should_keep_released_resources_another_environment_uses() {
+        let mut known = known(&["production", "staging"], &["shared-profile"]);
+        known
+            .released_lxd_resources
+            .insert("shared-profile".to_string(), "production".to_string());
+
+        let orphans = detect_orphans(&known, &[], &names(&["shared-profile"]), &[]);
+
+        assert!(orphans.is_empty());
+    }
+
+    #[test]
+    fn it_should_detect_build_directories_without_data_directory() {
+        let orphans = detect_orphans(
+            &known(&["e2e-deployment"], &[]),
+            &[],
+            &[],
+            &names(&["e2e-deployment", "e2e-config"]),
+        );
+
+        assert_eq!(
+            orphans,
+            [OrphanResource::new(
+                OrphanKind::BuildDirectory,
+                "e2e-config".to_string(),
+                "e2e-config".to_string()
+            )]
+        );
+    }
+
+    #[test]
+    fn it_should_treat_missing_directories_as_empty() {
+        let temp_dir = tempfile::TempDir::new().unwrap();
+
+        let result = list_directory_names(&temp_dir.path().join("build")).unwrap();
+
+        assert!(result.is_empty());
+    }
+
+    #[test]
+    fn it_should_list_only_subdirectories() {
+        let temp_dir = tempfile::TempDir::new().unwrap();
+        fs::create_dir(temp_dir.path().join("env-b")).unwrap();
+        fs::create_dir(temp_dir.path().join("env-a")).unwrap();
+        fs::write(temp_dir.path().join("notes.txt"), "").unwrap();
+
+        let result = list_directory_names(temp_dir.path()).unwrap();
+
+        assert_eq!(result, ["env-a", "env-b"]);
+    }
+}
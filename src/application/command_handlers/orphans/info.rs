@@ -0,0 +1,187 @@
+//! Data Transfer Objects for the orphans command
+//!
+//! These DTOs describe the orphaned resources found in the workspace and on
+//! the LXD host, and what happened to each of them.
+
+use std::fmt;
+
+use serde::Serialize;
+
+/// Type of an orphaned resource
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum OrphanKind {
+    /// LXD instance (`torrust-tracker-vm-{env}`)
+    LxdInstance,
+
+    /// LXD profile (`lxd-{env}` or `torrust-profile-{env}`)
+    LxdProfile,
+
+    /// Local build directory (`build/{env}`)
+    BuildDirectory,
+}
+
+impl fmt::Display for OrphanKind {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::LxdInstance => write!(f, "LXD instance"),
+            Self::LxdProfile => write!(f, "LXD profile"),
+            Self::BuildDirectory => write!(f, "build directory"),
+        }
+    }
+}
+
+/// What happened to an orphaned resource
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+#[serde(tag = "status", rename_all = "snake_case")]
+pub enum OrphanStatus {
+    /// Detected but not deleted (deletion was not requested)
+    Found,
+
+    /// Deleted
+    Deleted,
+
+    /// Deletion was requested but failed
+    DeleteFailed {
+        /// Why the deletion failed
+        error: String,
+    },
+}
+
+/// A resource left behind by an environment that no longer exists
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct OrphanResource {
+    /// Type of the resource
+    pub kind: OrphanKind,
+
+    /// Name of the resource (LXD name or build directory name)
+    pub name: String,
+
+    /// Name of the environment the resource was created for
+    pub environment: String,
+
+    /// LXD remote holding the resource, unset for the local daemon and
+    /// build directories
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub remote: Option<String>,
+
+    /// What happened to the resource
+    #[serde(flatten)]
+    pub status: OrphanStatus,
+}
+
+impl OrphanResource {
+    /// Create a new `OrphanResource` in the `Found` status
+    #[must_use]
+    pub fn new(kind: OrphanKind, name: String, environment: String) -> Self {
+        Self {
+            kind,
+            name,
+            environment,
+            remote: None,
+            status: OrphanStatus::Found,
+        }
+    }
+
+    /// Set the LXD remote holding the resource
+    #[must_use]
+    pub fn on_remote(mut self, remote: impl Into<String>) -> Self {
+        self.remote = Some(remote.into());
+        self
+    }
+
+    /// Name of the resource, prefixed with its LXD remote (`remote:name`)
+    #[must_use]
+    pub fn qualified_name(&self) -> String {
+        match &self.remote {
+            Some(remote) => format!("{remote}:{}", self.name),
+            None => self.name.clone(),
+        }
+    }
+}
+
+/// Result of an orphans scan
+#[derive(Debug, Clone, Serialize)]
+pub struct OrphanReport {
+    /// Orphaned resources, in deletion order (instances, profiles, build directories)
+    pub orphans: Vec<OrphanResource>,
+
+    /// Whether deletion of the orphans was requested
+    pub delete_requested: bool,
+
+    /// Why LXD resources could not be listed, if they could not
+    ///
+    /// Build directories, and the LXD servers that answered, are still
+    /// checked when an LXD server is unavailable.
+    pub lxd_error: Option<String>,
+}
+
+impl OrphanReport {
+    /// Check if no orphaned resources were found
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.orphans.is_empty()
+    }
+
+    /// Number of requested deletions that failed
+    #[must_use]
+    pub fn failed_count(&self) -> usize {
+        self.orphans
+            .iter()
+            .filter(|orphan| matches!(orphan.status, OrphanStatus::DeleteFailed { .. }))
+            .count()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn it_should_report_failures_only_for_failed_deletions() {
+        let mut orphan = OrphanResource::new(
+            OrphanKind::LxdInstance,
+            "torrust-tracker-vm-e2e".to_string(),
+            "e2e".to_string(),
+        );
+        let mut report = OrphanReport {
+            orphans: vec![orphan.clone()],
+            delete_requested: true,
+            lxd_error: None,
+        };
+        assert_eq!(report.failed_count(), 0);
+
+        orphan.status = OrphanStatus::DeleteFailed {
+            error: "instance is busy".to_string(),
+        };
+        report.orphans = vec![orphan];
+        assert_eq!(report.failed_count(), 1);
+    }
+
+    #[test]
+    fn it_should_serialize_status_inline() {
+        let orphan = OrphanResource::new(
+            OrphanKind::LxdProfile,
+            "lxd-e2e".to_string(),
+            "e2e".to_string(),
+        );
+
+        let json = serde_json::to_value(&orphan).unwrap();
+
+        assert_eq!(json["kind"], "lxd_profile");
+        assert_eq!(json["status"], "found");
+        assert!(json.get("remote").is_none());
+    }
+
+    #[test]
+    fn it_should_prefix_the_name_with_the_remote() {
+        let orphan = OrphanResource::new(
+            OrphanKind::LxdInstance,
+            "torrust-tracker-vm-e2e".to_string(),
+            "e2e".to_string(),
+        )
+        .on_remote("bigbox");
+
+        assert_eq!(orphan.qualified_name(), "bigbox:torrust-tracker-vm-e2e");
+    }
+}
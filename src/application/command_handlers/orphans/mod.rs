@@ -0,0 +1,36 @@
+//! Orphans Command Module
+//!
+//! This module implements the delivery-agnostic `OrphansCommandHandler`
+//! for finding (and optionally deleting) resources left behind by
+//! environments that no longer exist in the workspace.
+//!
+//! ## Orphaned Resources
+//!
+//! When an environment is purged while provisioning had partially succeeded,
+//! its LXD instance and profile keep running without an environment to
+//! destroy them. The handler detects:
+//!
+//! - LXD instances named `torrust-tracker-vm-{env}`
+//! - LXD profiles named `lxd-{env}` or `torrust-profile-{env}`
+//! - `build/{env}` directories
+//!
+//! which are not referenced by any environment in the repository, plus the
+//! LXD resources of destroyed environments, whose custom names are only
+//! known from the repository.
+//!
+//! ## Safety
+//!
+//! - Resources whose name does not match the generated patterns are never
+//!   touched, unless a destroyed environment used them
+//! - Nothing is deleted unless explicitly requested
+//! - A resource named after an environment is kept if its `data/{env}`
+//!   directory exists but the environment state cannot be loaded
+
+pub mod errors;
+pub mod handler;
+pub mod info;
+
+// Re-export main types for convenience
+pub use errors::OrphansCommandHandlerError;
+pub use handler::OrphansCommandHandler;
+pub use info::{OrphanKind, OrphanReport, OrphanResource, OrphanStatus};
@@ -2,9 +2,14 @@
 
 use crate::adapters::ssh::SshError;
 use crate::adapters::tofu::client::OpenTofuError;
+use crate::adapters::tofu::LxdConflict;
 use crate::application::errors::{InvalidStateError, PersistenceError};
 use crate::application::services::rendering::AnsibleTemplateRenderingServiceError;
-use crate::application::steps::RenderAnsibleTemplatesError;
+use crate::application::steps::{
+    CustomStepFailure, DiskSpaceCheckError, LxdRemoteCheckError, RenderAnsibleTemplatesError,
+    SshPortCheckError,
+};
+use crate::infrastructure::remote_actions::CloudInitWaitError;
 use crate::infrastructure::templating::tofu::TofuProjectGeneratorError;
 use crate::shared::command::CommandError;
 
@@ -29,14 +34,68 @@ pub enum ProvisionCommandHandlerError {
     #[error("Command execution failed: {0}")]
     Command(#[from] CommandError),
 
+    #[error("LXD profile already exists from a previous run - run `orphans --delete` or choose a different environment name")]
+    LxdProfileExists { source: CommandError },
+
+    #[error("LXD instance already exists from a previous run - run `orphans --delete` or choose a different environment name")]
+    LxdInstanceExists { source: CommandError },
+
+    #[error(
+        "LXD storage pool not found - create the storage pool or initialize LXD with `lxd init`"
+    )]
+    LxdStoragePoolMissing { source: CommandError },
+
+    #[error("LXD image download failed - check access to the image server and provision again")]
+    LxdImageDownloadFailed { source: CommandError },
+
+    #[error("Disk space check failed: {0}")]
+    DiskSpace(#[from] DiskSpaceCheckError),
+
+    #[error("LXD remote check failed: {0}")]
+    LxdRemoteUnavailable(#[from] LxdRemoteCheckError),
+
+    #[error("SSH port check failed: {0}")]
+    SshPortUnreachable(#[from] SshPortCheckError),
+
     #[error("SSH connectivity failed: {0}")]
     SshConnectivity(#[from] SshError),
 
+    #[error("Waiting for cloud-init failed: {0}")]
+    CloudInit(#[from] CloudInitWaitError),
+
     #[error("Failed to persist environment state: {0}")]
     StatePersistence(#[from] PersistenceError),
 
     #[error("Invalid state transition: {0}")]
     StateTransition(#[from] InvalidStateError),
+
+    #[error("interrupted by user")]
+    Interrupted,
+
+    #[error("{0}")]
+    CustomStepFailed(#[from] CustomStepFailure),
+
+    #[error("Provisioning timed out after {}s", deadline.as_secs())]
+    DeadlineExceeded { deadline: std::time::Duration },
+}
+
+impl ProvisionCommandHandlerError {
+    /// Map a failed `tofu apply` to the LXD conflict it reports, if any
+    ///
+    /// Other failures are kept as [`Self::Command`]. The tofu output stays
+    /// in the source error so it is still written to the trace file.
+    #[must_use]
+    pub fn from_apply_failure(error: CommandError) -> Self {
+        match LxdConflict::from_command_error(&error) {
+            Some(LxdConflict::ProfileExists) => Self::LxdProfileExists { source: error },
+            Some(LxdConflict::InstanceExists) => Self::LxdInstanceExists { source: error },
+            Some(LxdConflict::StoragePoolMissing) => Self::LxdStoragePoolMissing { source: error },
+            Some(LxdConflict::ImageDownloadFailed) => {
+                Self::LxdImageDownloadFailed { source: error }
+            }
+            None => Self::Command(error),
+        }
+    }
 }
 
 impl From<AnsibleTemplateRenderingServiceError> for ProvisionCommandHandlerError {
@@ -80,15 +139,51 @@ impl crate::shared::Traceable for ProvisionCommandHandlerError {
             Self::Command(e) => {
                 format!("ProvisionCommandHandlerError: Command execution failed - {e}")
             }
+            Self::LxdProfileExists { .. } => {
+                "ProvisionCommandHandlerError: LXD profile already exists".to_string()
+            }
+            Self::LxdInstanceExists { .. } => {
+                "ProvisionCommandHandlerError: LXD instance already exists".to_string()
+            }
+            Self::LxdStoragePoolMissing { .. } => {
+                "ProvisionCommandHandlerError: LXD storage pool not found".to_string()
+            }
+            Self::LxdImageDownloadFailed { .. } => {
+                "ProvisionCommandHandlerError: LXD image download failed".to_string()
+            }
+            Self::DiskSpace(e) => {
+                format!("ProvisionCommandHandlerError: disk space check failed - {e}")
+            }
+            Self::LxdRemoteUnavailable(e) => {
+                format!("ProvisionCommandHandlerError: LXD remote check failed - {e}")
+            }
+            Self::SshPortUnreachable(e) => {
+                format!("ProvisionCommandHandlerError: SSH port check failed - {e}")
+            }
             Self::SshConnectivity(e) => {
                 format!("ProvisionCommandHandlerError: SSH connectivity failed - {e}")
             }
+            Self::CloudInit(e) => {
+                format!("ProvisionCommandHandlerError: Waiting for cloud-init failed - {e}")
+            }
             Self::StatePersistence(e) => {
                 format!("ProvisionCommandHandlerError: Failed to persist environment state - {e}")
             }
             Self::StateTransition(e) => {
                 format!("ProvisionCommandHandlerError: Invalid state transition - {e}")
             }
+            Self::Interrupted => {
+                "ProvisionCommandHandlerError: Interrupted by user (Ctrl-C)".to_string()
+            }
+            Self::CustomStepFailed(e) => {
+                format!("ProvisionCommandHandlerError: Custom step failed - {e}")
+            }
+            Self::DeadlineExceeded { deadline } => {
+                format!(
+                    "ProvisionCommandHandlerError: Provisioning timed out after {}s",
+                    deadline.as_secs()
+                )
+            }
         }
     }
 
@@ -97,12 +192,23 @@ impl crate::shared::Traceable for ProvisionCommandHandlerError {
             Self::OpenTofuTemplateRendering(e) => Some(e),
             Self::AnsibleTemplateRendering(e) => Some(e),
             Self::OpenTofu(e) => Some(e),
-            Self::Command(e) => Some(e),
+            Self::Command(e)
+            | Self::LxdProfileExists { source: e }
+            | Self::LxdInstanceExists { source: e }
+            | Self::LxdStoragePoolMissing { source: e }
+            | Self::LxdImageDownloadFailed { source: e } => Some(e),
+            Self::DiskSpace(e) => Some(e),
+            Self::LxdRemoteUnavailable(e) => Some(e),
+            Self::SshPortUnreachable(e) => Some(e),
             Self::SshConnectivity(e) => Some(e),
+            Self::CloudInit(e) => Some(e),
             Self::EnvironmentNotFound { .. }
             | Self::TemplateRendering(_)
             | Self::StatePersistence(_)
-            | Self::StateTransition(_) => None,
+            | Self::StateTransition(_)
+            | Self::Interrupted
+            | Self::DeadlineExceeded { .. }
+            | Self::CustomStepFailed(_) => None,
         }
     }
 
@@ -112,17 +218,43 @@ impl crate::shared::Traceable for ProvisionCommandHandlerError {
             Self::OpenTofuTemplateRendering(_)
             | Self::AnsibleTemplateRendering(_)
             | Self::TemplateRendering(_) => crate::shared::ErrorKind::TemplateRendering,
-            Self::OpenTofu(_) => crate::shared::ErrorKind::InfrastructureOperation,
-            Self::SshConnectivity(_) => crate::shared::ErrorKind::NetworkConnectivity,
-            Self::Command(_) => crate::shared::ErrorKind::CommandExecution,
+            Self::OpenTofu(e) => crate::shared::Traceable::error_kind(e),
+            Self::SshPortUnreachable(_) | Self::SshConnectivity(_) => {
+                crate::shared::ErrorKind::NetworkConnectivity
+            }
+            Self::DiskSpace(e) => crate::shared::Traceable::error_kind(e),
+            Self::LxdRemoteUnavailable(e) => crate::shared::Traceable::error_kind(e),
+            Self::Command(e) => crate::shared::Traceable::error_kind(e),
+            Self::LxdProfileExists { .. }
+            | Self::LxdInstanceExists { .. }
+            | Self::LxdImageDownloadFailed { .. } => {
+                crate::shared::ErrorKind::InfrastructureOperation
+            }
+            Self::LxdStoragePoolMissing { .. } => crate::shared::ErrorKind::Configuration,
+            Self::CloudInit(e) => crate::shared::Traceable::error_kind(e),
             Self::StatePersistence(_) | Self::StateTransition(_) => {
                 crate::shared::ErrorKind::StatePersistence
             }
+            Self::Interrupted => crate::shared::ErrorKind::Interrupted,
+            Self::DeadlineExceeded { .. } => crate::shared::ErrorKind::Timeout,
+            Self::CustomStepFailed(_) => crate::shared::ErrorKind::CommandExecution,
         }
     }
 }
 
 impl ProvisionCommandHandlerError {
+    /// Excerpt of a remote log explaining the failure, if one was captured
+    ///
+    /// Currently the tail of `/var/log/cloud-init-output.log` when waiting
+    /// for cloud-init failed.
+    #[must_use]
+    pub fn remote_log_excerpt(&self) -> Option<&str> {
+        match self {
+            Self::CloudInit(e) => e.log_tail(),
+            _ => None,
+        }
+    }
+
     /// Provides detailed troubleshooting guidance for this error
     ///
     /// Returns context-specific help text that guides users toward resolving
@@ -197,6 +329,30 @@ For template syntax issues, see the Tera template documentation."
 Template files should be in: templates/ansible/
 
 For template syntax issues, see the Tera template documentation."
+            }
+            Self::OpenTofu(OpenTofuError::CommandError(CommandError::TimedOut { .. }))
+            | Self::Command(CommandError::TimedOut { .. })
+            | Self::DeadlineExceeded { .. } => {
+                "Provisioning Timed Out - Troubleshooting:
+
+A command did not finish in time and was killed. The environment was saved
+in 'ProvisionFailed' state and infrastructure may be partially created.
+
+1. Check that your infrastructure provider is responsive, e.g. for LXD:
+   lxc list
+
+2. Review the trace file for the command that timed out:
+   data/<env-name>/traces/
+
+3. Remove any partially created infrastructure and provision again:
+   cargo run -- destroy <env-name>
+   cargo run -- create environment --env-file <config.json>
+   cargo run -- provision <env-name>
+
+4. On slow hosts, raise the step timeouts or the overall deadline
+   configured for the provision command
+
+For provisioning details, see docs/user-guide/commands/provision.md"
             }
             Self::OpenTofu(_) => {
                 "OpenTofu Command Failed - Troubleshooting:
@@ -230,6 +386,129 @@ Common issues:
 - Command not found: Install the required tool
 
 For tool installation, see the setup documentation."
+            }
+            Self::LxdProfileExists { .. } | Self::LxdInstanceExists { .. } => {
+                "LXD Resource Already Exists - Troubleshooting:
+
+A previous run of an environment with the same name left its LXD profile
+or instance behind, so OpenTofu cannot create them again.
+
+1. List and delete resources left by environments that no longer exist:
+   cargo run -- orphans
+   cargo run -- orphans --delete
+
+2. Or choose a different environment name and create it again:
+   cargo run -- create environment --env-file <config.json>
+
+3. If the environment still exists, destroy it first:
+   cargo run -- destroy <env-name>
+
+The full OpenTofu output is in the trace file:
+   data/<env-name>/traces/
+
+For provisioning details, see docs/user-guide/commands/orphans.md"
+            }
+            Self::LxdStoragePoolMissing { .. } => {
+                "LXD Storage Pool Not Found - Troubleshooting:
+
+The instance uses the 'default' storage pool, which does not exist on
+this LXD host.
+
+1. List the storage pools:
+   lxc storage list
+
+2. Create the pool, or initialize LXD with defaults:
+   lxc storage create default dir
+   lxd init --auto
+
+3. Provision again with a new environment
+
+The full OpenTofu output is in the trace file:
+   data/<env-name>/traces/
+
+For LXD setup, see docs/vm-providers.md"
+            }
+            Self::LxdImageDownloadFailed { .. } => {
+                "LXD Image Download Failed - Troubleshooting:
+
+LXD could not download the instance image from its image server.
+
+1. Check network access from the LXD host to the image server:
+   lxc image list ubuntu: 24.04
+
+2. Check the configured remotes:
+   lxc remote list
+
+3. Provision again with a new environment once the image is reachable
+
+The full OpenTofu output is in the trace file:
+   data/<env-name>/traces/
+
+For LXD setup, see docs/vm-providers.md"
+            }
+            Self::DiskSpace(_) => {
+                "Insufficient Disk Space - Troubleshooting:
+
+Provisioning was stopped before running OpenTofu, so nothing was created.
+The error names the partition or LXD storage pool that is too full.
+
+1. Check the free space of the workspace partition:
+   df -h .
+
+2. Check the free space of the LXD storage pool:
+   lxc storage info default
+
+3. Free some space, e.g. remove unused LXD images and old run logs:
+   lxc image list
+   lxc image delete <fingerprint>
+
+4. Provision again once enough space is free
+
+The thresholds are set in 'min_free_disk_space' of deployer-workspace.json.
+For more information, see docs/user-guide/commands/provision.md"
+            }
+            Self::LxdRemoteUnavailable(_) => {
+                "LXD Remote Unavailable - Troubleshooting:
+
+The instance is created on an LXD remote, which must be configured in the
+LXD client, reachable and trust this machine before provisioning.
+
+1. Check the remote is configured:
+   lxc remote list
+
+2. If it is missing, issue a trust token on the LXD server:
+   lxc config trust add --name deployer
+   then either add the remote by hand:
+   lxc remote add <remote-name> <token>
+   or set 'provider.remote.trust_token' in the environment configuration
+
+3. Check the server answers and trusts this client:
+   lxc query <remote-name>:/1.0
+
+4. Check no firewall blocks the LXD API port (8443 by default)
+
+For remote LXD servers, see docs/user-guide/providers/lxd/README.md"
+            }
+            Self::SshPortUnreachable(_) => {
+                "SSH Port Unreachable - Troubleshooting:
+
+The instance was created, but did not accept connections on the SSH port
+within a minute, so provisioning stopped before waiting for cloud-init.
+
+1. Check the port is not used by the provider or by the host, e.g. for LXD
+   the API and proxy devices listen on 8443:
+   lxc config show <instance-name> --expanded
+
+2. Check no firewall between this machine and the instance blocks the port
+
+3. With a custom SSH port, check cloud-init moved SSH to it:
+   lxc exec <instance-name> -- cat /etc/ssh/sshd_config.d/99-custom-port.conf
+
+4. Choose another 'ssh_credentials.port', then recreate the environment:
+   cargo run -- destroy <env-name>
+   cargo run -- create environment --env-file <config.json>
+
+For provisioning details, see docs/user-guide/commands/provision.md"
             }
             Self::SshConnectivity(_) => {
                 "SSH Connectivity Failed - Troubleshooting:
@@ -249,6 +528,26 @@ For tool installation, see the setup documentation."
    SSH into the server and run: cloud-init status --wait
 
 For SSH troubleshooting, see docs/debugging.md"
+            }
+            Self::CloudInit(_) => {
+                "Cloud-init Wait Failed - Troubleshooting:
+
+1. Review the cloud-init output log excerpt in the trace file:
+   data/<env-name>/traces/
+
+2. Inspect cloud-init on the instance:
+   ssh -i <key-path> <user>@<ip-address>
+   cloud-init status --long
+   sudo tail -n 100 /var/log/cloud-init-output.log
+
+3. On slow or heavily loaded hosts, increase the timeout in the
+   environment configuration and recreate the environment:
+   \"provision\": { \"cloud_init_timeout_secs\": 600 }
+
+4. If cloud-init reported an error, check custom cloud-init template
+   overrides (tofu/common/cloud-init.yml.tera) for invalid directives
+
+For provisioning details, see docs/user-guide/commands/provision.md"
             }
             Self::StatePersistence(_) => {
                 "State Persistence Failed - Troubleshooting:
@@ -286,6 +585,42 @@ Provision command requires environment in 'Created' state.
 
 For workflow details, see docs/deployment-overview.md"
             }
+            Self::Interrupted => {
+                "Provisioning Interrupted - Troubleshooting:
+
+Provisioning was stopped with Ctrl-C before it finished. The environment
+was saved in 'ProvisionFailed' state and infrastructure may be partially
+created.
+
+1. Check the current environment state:
+   cargo run -- show <env-name>
+
+2. Remove any partially created infrastructure:
+   cargo run -- destroy <env-name>
+
+3. Recreate the environment and provision again:
+   cargo run -- create environment --env-file <config.json>
+   cargo run -- provision <env-name>
+
+For workflow details, see docs/deployment-overview.md"
+            }
+            Self::CustomStepFailed(_) => {
+                "Custom Step Failed - Troubleshooting:
+
+A step registered through the SDK failed after the instance was created.
+The environment was saved in 'ProvisionFailed' state with the failed step
+recorded as 'custom:<step-name>' and the error of the step as summary.
+
+1. Check the error returned by the step and fix its cause
+
+2. Review the trace file in data/<env-name>/traces/ for the full error chain
+
+3. Destroy the environment and provision it again:
+   cargo run -- destroy <env-name>
+   cargo run -- provision <env-name>
+
+For SDK details, see packages/sdk/README.md"
+            }
         }
     }
 }
@@ -383,6 +718,59 @@ mod tests {
         assert!(help.contains("cloud-init"));
     }
 
+    #[test]
+    fn it_should_provide_help_for_an_unreachable_ssh_port() {
+        let error =
+            ProvisionCommandHandlerError::SshPortUnreachable(SshPortCheckError::Unreachable {
+                address: "10.0.0.1:8443".parse().unwrap(),
+                waited_secs: 60,
+                reason: "Connection refused".to_string(),
+            });
+
+        let help = error.help();
+        assert!(help.contains("SSH Port Unreachable"));
+        assert!(help.contains("ssh_credentials.port"));
+        assert_eq!(
+            crate::shared::Traceable::error_kind(&error),
+            crate::shared::ErrorKind::NetworkConnectivity
+        );
+    }
+
+    #[test]
+    fn it_should_provide_help_for_an_unavailable_lxd_remote() {
+        let error = ProvisionCommandHandlerError::LxdRemoteUnavailable(
+            LxdRemoteCheckError::NotConfigured {
+                remote: "bigbox".to_string(),
+            },
+        );
+
+        let help = error.help();
+        assert!(help.contains("LXD Remote Unavailable"));
+        assert!(help.contains("lxc remote add"));
+        assert_eq!(
+            crate::shared::Traceable::error_kind(&error),
+            crate::shared::ErrorKind::Configuration
+        );
+    }
+
+    #[test]
+    fn it_should_name_the_full_partition_of_an_insufficient_disk_space_error() {
+        let error =
+            ProvisionCommandHandlerError::from(DiskSpaceCheckError::InsufficientDiskSpace {
+                location: "partition '/home' holding the workspace (/home/torrust/deployer/data)"
+                    .to_string(),
+                available: 512 * 1024 * 1024,
+                required: 2 * 1024 * 1024 * 1024,
+            });
+
+        assert!(error.to_string().contains("partition '/home'"));
+        assert!(error.help().contains("min_free_disk_space"));
+        assert_eq!(
+            crate::shared::Traceable::error_kind(&error),
+            crate::shared::ErrorKind::FileSystem
+        );
+    }
+
     #[test]
     fn it_should_provide_help_for_state_persistence() {
         let error = ProvisionCommandHandlerError::StatePersistence(PersistenceError::NotFound);
@@ -405,10 +793,55 @@ mod tests {
         assert!(help.contains("environment name"));
     }
 
+    fn apply_failure(stderr: &str) -> CommandError {
+        CommandError::ExecutionFailed {
+            command: "tofu apply".to_string(),
+            exit_code: "1".to_string(),
+            stdout: "lxd_profile.torrust_profile: Creating...".to_string(),
+            stderr: format!("│ Error: {stderr}"),
+        }
+    }
+
+    #[test]
+    fn it_should_map_an_apply_failure_to_the_lxd_conflict_it_reports() {
+        let error = ProvisionCommandHandlerError::from_apply_failure(apply_failure(
+            "Failed to create profile \"torrust-profile-e2e\": The profile already exists",
+        ));
+
+        assert!(matches!(
+            error,
+            ProvisionCommandHandlerError::LxdProfileExists { .. }
+        ));
+        assert!(error.to_string().contains("orphans --delete"));
+        assert!(error.help().contains("orphans --delete"));
+    }
+
+    #[test]
+    fn it_should_keep_other_apply_failures_as_command_errors() {
+        let error =
+            ProvisionCommandHandlerError::from_apply_failure(apply_failure("Unsupported argument"));
+
+        assert!(matches!(error, ProvisionCommandHandlerError::Command(_)));
+    }
+
+    #[test]
+    fn it_should_keep_the_tofu_output_of_an_lxd_conflict_for_the_trace_file() {
+        use crate::shared::Traceable;
+
+        let error = ProvisionCommandHandlerError::from_apply_failure(apply_failure(
+            "Failed creating instance record: Instance \"torrust-tracker-vm-e2e\" already exists",
+        ));
+
+        let source = error.trace_source().expect("the tofu error should be kept");
+        assert!(source
+            .trace_format()
+            .contains("Instance \"torrust-tracker-vm-e2e\" already exists"));
+    }
+
     #[test]
     fn it_should_have_help_for_all_error_variants() {
         use crate::adapters::ssh::SshError;
-        use crate::application::steps::RenderAnsibleTemplatesError;
+        use crate::application::steps::{CustomStepFailure, RenderAnsibleTemplatesError};
         use crate::infrastructure::templating::ansible::template::wrappers::inventory::InventoryContextError;
         use crate::infrastructure::templating::tofu::TofuProjectGeneratorError;
         use crate::shared::command::CommandError;
@@ -447,7 +880,43 @@ mod tests {
                 attempts: 5,
                 timeout_seconds: 30,
             }),
+            ProvisionCommandHandlerError::SshPortUnreachable(SshPortCheckError::Unreachable {
+                address: "10.0.0.1:8443".parse().unwrap(),
+                waited_secs: 60,
+                reason: "Connection refused".to_string(),
+            }),
+            ProvisionCommandHandlerError::DiskSpace(DiskSpaceCheckError::InsufficientDiskSpace {
+                location: "LXD storage pool 'default'".to_string(),
+                available: 1024,
+                required: 2048,
+            }),
+            ProvisionCommandHandlerError::LxdRemoteUnavailable(
+                LxdRemoteCheckError::NotConfigured {
+                    remote: "bigbox".to_string(),
+                },
+            ),
+            ProvisionCommandHandlerError::LxdProfileExists {
+                source: apply_failure("The profile already exists"),
+            },
+            ProvisionCommandHandlerError::LxdInstanceExists {
+                source: apply_failure("Instance already exists"),
+            },
+            ProvisionCommandHandlerError::LxdStoragePoolMissing {
+                source: apply_failure("Storage pool not found"),
+            },
+            ProvisionCommandHandlerError::LxdImageDownloadFailed {
+                source: apply_failure("The requested image couldn't be found"),
+            },
             ProvisionCommandHandlerError::StatePersistence(PersistenceError::NotFound),
+            ProvisionCommandHandlerError::Interrupted,
+            ProvisionCommandHandlerError::CustomStepFailed(CustomStepFailure {
+                step: "register-dns".to_string(),
+                hook: crate::application::steps::HookPoint::AfterProvision,
+                source: crate::application::steps::StepError::new("DNS API unreachable"),
+            }),
+            ProvisionCommandHandlerError::DeadlineExceeded {
+                deadline: std::time::Duration::from_secs(60),
+            },
         ];
 
         for error in errors {
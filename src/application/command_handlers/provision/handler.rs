@@ -1,37 +1,54 @@
 //! Provision command handler implementation
 
 use std::net::{IpAddr, SocketAddr};
+use std::path::PathBuf;
 use std::sync::Arc;
+use std::time::{Duration, Instant};
 
-use tracing::{error, info, instrument};
+use tokio_util::sync::CancellationToken;
+use tracing::{error, info, instrument, warn};
 
 use super::errors::ProvisionCommandHandlerError;
-use crate::adapters::ansible::AnsibleClient;
-use crate::adapters::ssh::SshConfig;
+use crate::adapters::libvirt::VirshClient;
+use crate::adapters::ssh::{KnownHostsFile, SshConfig, SshMultiplexer};
 use crate::adapters::tofu::client::InstanceInfo;
+use crate::adapters::tofu::PlannedChange;
 use crate::adapters::OpenTofuClient;
-use crate::application::command_handlers::common::StepResult;
+use crate::application::command_handlers::common::{
+    prune_run_logs, prune_traces, traces_dir, DryRunReport, StepResult,
+};
 use crate::application::services::rendering::AnsibleTemplateRenderingService;
 use crate::application::steps::{
-    ApplyInfrastructureStep, GetInstanceInfoStep, InitializeInfrastructureStep,
-    PlanInfrastructureStep, RenderOpenTofuTemplatesStep, ValidateInfrastructureStep,
-    WaitForCloudInitStep, WaitForSSHConnectivityStep,
+    ApplyInfrastructureStep, CheckLxdRemoteStep, CheckLxdStoragePoolSpaceStep, CheckSshPortStep,
+    CheckWorkspaceDiskSpaceStep, CustomSteps, GetInstanceInfoStep, HookPoint,
+    InitializeInfrastructureStep, PlanInfrastructureStep, RecordHostKeysStep,
+    RenderOpenTofuTemplatesStep, StepContext, ValidateInfrastructureStep, WaitForCloudInitStep,
+    WaitForSSHConnectivityStep,
 };
 use crate::application::traits::CommandProgressListener;
+use crate::domain::environment::deployment_report::elapsed_ms;
 use crate::domain::environment::repository::{EnvironmentRepository, TypedEnvironmentRepository};
-use crate::domain::environment::runtime_outputs::ProvisionMethod;
+use crate::domain::environment::runtime_outputs::{InstanceAddresses, ProvisionMethod};
 use crate::domain::environment::state::{ProvisionFailureContext, ProvisionStep};
-use crate::domain::environment::{Environment, Provisioned, Provisioning};
+use crate::domain::environment::{
+    DeploymentPhase, DiskSpaceThresholds, Environment, Provisioned, Provisioning,
+};
+use crate::domain::provider::{ProviderConfig, LIBVIRT_URI};
 use crate::domain::EnvironmentName;
 use crate::infrastructure::templating::tofu::TofuProjectGenerator;
 use crate::shared::clock::SystemClock;
+use crate::shared::command::run_log::DEFAULT_KEPT_RUNS;
+use crate::shared::command::{CommandTimeouts, RunLog};
 use crate::shared::error::Traceable;
 
 /// Total number of steps in the provisioning workflow.
 ///
 /// This constant is used for progress reporting via `CommandProgressListener`
-/// to display step progress like "[Step 1/9] Rendering `OpenTofu` templates...".
-const TOTAL_PROVISION_STEPS: usize = 9;
+/// to display step progress like "[Step 1/10] Rendering `OpenTofu` templates...".
+const TOTAL_PROVISION_STEPS: usize = 10;
+
+/// Port SSH listens on until cloud-init applies a custom SSH port
+const DEFAULT_SSH_PORT: u16 = 22;
 
 /// `ProvisionCommandHandler` orchestrates the complete infrastructure provisioning workflow
 ///
@@ -45,8 +62,9 @@ const TOTAL_PROVISION_STEPS: usize = 9;
 /// 5. Apply infrastructure
 /// 6. Get instance information
 /// 7. Render `Ansible` templates (with runtime IP address)
-/// 8. Wait for SSH connectivity
-/// 9. Wait for cloud-init completion
+/// 8. Check that the SSH port accepts connections
+/// 9. Wait for SSH connectivity
+/// 10. Wait for cloud-init completion
 ///
 /// # State Management
 ///
@@ -58,9 +76,48 @@ const TOTAL_PROVISION_STEPS: usize = 9;
 ///
 /// State is persisted after each transition using the injected repository.
 /// Persistence failures are logged but don't fail the command handler (state remains valid in memory).
+///
+/// # Cancellation
+///
+/// The workflow checks its `CancellationToken` between steps. When the token
+/// is cancelled (e.g. the user pressed Ctrl-C) the handler stops, and the
+/// environment is persisted as `ProvisionFailed` with
+/// `failed_step = ProvisionStep::Interrupted` instead of being left in the
+/// transient `Provisioning` state.
+///
+/// # Timeouts
+///
+/// Every `OpenTofu` command is bounded by its step timeout in
+/// [`CommandTimeouts`] and killed, with its whole process group, when it
+/// runs longer. An optional overall deadline (see [`Self::with_deadline`])
+/// caps the whole workflow. A timeout fails the step like any other error, so
+/// the environment is persisted as `ProvisionFailed`.
+///
+/// # Disk Space
+///
+/// Before any `OpenTofu` command runs, the handler checks the free space of
+/// the partition holding the workspace and, for LXD, of the storage pool
+/// the instance is created in (see [`Self::with_disk_space_thresholds`]).
+/// A shortage fails the workflow with `ProvisionStep::DiskSpaceCheck`,
+/// naming the partition, before anything is written.
+///
+/// # Custom Steps
+///
+/// Steps registered with [`Self::with_custom_steps`] for
+/// [`HookPoint::AfterProvision`] run once the instance is reachable, before
+/// the environment is saved as `Provisioned`. They are not run by
+/// [`Self::dry_run`].
 pub struct ProvisionCommandHandler {
     clock: Arc<dyn crate::shared::Clock>,
     repository: TypedEnvironmentRepository,
+    cancellation: CancellationToken,
+    command_timeouts: CommandTimeouts,
+    deadline: Option<Duration>,
+    opentofu_executable: Option<String>,
+    custom_steps: CustomSteps,
+    kept_run_logs: usize,
+    trace_dir: Option<PathBuf>,
+    disk_space_thresholds: DiskSpaceThresholds,
 }
 
 impl ProvisionCommandHandler {
@@ -73,9 +130,81 @@ impl ProvisionCommandHandler {
         Self {
             clock,
             repository: TypedEnvironmentRepository::new(repository),
+            cancellation: CancellationToken::new(),
+            command_timeouts: CommandTimeouts::default(),
+            deadline: None,
+            opentofu_executable: None,
+            custom_steps: CustomSteps::new(),
+            kept_run_logs: DEFAULT_KEPT_RUNS,
+            trace_dir: None,
+            disk_space_thresholds: DiskSpaceThresholds::default(),
         }
     }
 
+    /// Stop the workflow when the given token is cancelled
+    ///
+    /// Without a token the handler runs to completion or failure.
+    #[must_use]
+    pub fn with_cancellation(mut self, cancellation: CancellationToken) -> Self {
+        self.cancellation = cancellation;
+        self
+    }
+
+    /// Bound each external command with the given per-step timeouts
+    #[must_use]
+    pub fn with_command_timeouts(mut self, command_timeouts: CommandTimeouts) -> Self {
+        self.command_timeouts = command_timeouts;
+        self
+    }
+
+    /// Fail the workflow if it has not finished `deadline` after it started
+    #[must_use]
+    pub fn with_deadline(mut self, deadline: Duration) -> Self {
+        self.deadline = Some(deadline);
+        self
+    }
+
+    /// Run the given executable instead of `tofu` from the `PATH`
+    #[must_use]
+    pub fn with_opentofu_executable(mut self, executable: impl Into<String>) -> Self {
+        self.opentofu_executable = Some(executable.into());
+        self
+    }
+
+    /// Keep the `OpenTofu` output logs of the last `runs` provision runs
+    #[must_use]
+    pub fn with_kept_run_logs(mut self, runs: usize) -> Self {
+        self.kept_run_logs = runs;
+        self
+    }
+
+    /// Write the trace files of a failure to `trace_dir`
+    ///
+    /// `None` keeps the directory of the environment configuration, or
+    /// `data/{env}/traces/` when it sets none.
+    #[must_use]
+    pub fn with_trace_dir(mut self, trace_dir: Option<PathBuf>) -> Self {
+        self.trace_dir = trace_dir;
+        self
+    }
+
+    /// Require the given free disk space before running `OpenTofu`
+    #[must_use]
+    pub fn with_disk_space_thresholds(mut self, thresholds: DiskSpaceThresholds) -> Self {
+        self.disk_space_thresholds = thresholds;
+        self
+    }
+
+    /// Run the [`HookPoint::AfterProvision`] steps of `custom_steps` once the
+    /// instance is reachable
+    ///
+    /// A failing step fails the workflow with `ProvisionStep::CustomStep` naming the step.
+    #[must_use]
+    pub fn with_custom_steps(mut self, custom_steps: CustomSteps) -> Self {
+        self.custom_steps = custom_steps;
+        self
+    }
+
     /// Execute the complete provisioning workflow
     ///
     /// # Arguments
@@ -100,12 +229,18 @@ impl ProvisionCommandHandler {
     /// * Cloud-init does not complete successfully
     ///
     /// On error, the environment transitions to `ProvisionFailed` state and is persisted.
+    /// A command that exceeds its timeout fails with `CommandError::TimedOut`,
+    /// and exceeding the overall deadline while waiting for the instance fails
+    /// with `ProvisionCommandHandlerError::DeadlineExceeded`.
+    /// If the cancellation token was cancelled, the error is
+    /// `ProvisionCommandHandlerError::Interrupted` regardless of how the
+    /// running step ended.
     #[instrument(
-        name = "provision_command",
+        name = "command.provision",
         skip_all,
         fields(
             command_type = "provision",
-            environment = %env_name
+            environment_name = %env_name
         )
     )]
     pub async fn execute(
@@ -113,6 +248,7 @@ impl ProvisionCommandHandler {
         env_name: &EnvironmentName,
         listener: Option<&dyn CommandProgressListener>,
     ) -> Result<Environment<Provisioned>, ProvisionCommandHandlerError> {
+        let _lock = self.repository.inner().lock(env_name)?;
         let environment = self.load_created_environment(env_name)?;
 
         let started_at = self.clock.now();
@@ -121,12 +257,20 @@ impl ProvisionCommandHandler {
 
         self.repository.save_provisioning(&environment)?;
 
+        let timeouts = self.run_timeouts();
+        let run_log = self.run_log(&environment, started_at);
+
         // Execute provisioning workflow with explicit step tracking
         // This allows us to know exactly which step failed if an error occurs
-        match self
-            .execute_provisioning_workflow(&environment, listener)
-            .await
-        {
+        let outcome = self
+            .execute_provisioning_workflow(&environment, &timeouts, &run_log, listener)
+            .await;
+        prune_run_logs(&run_log);
+
+        let traces_dir = traces_dir(&environment, self.trace_dir.as_deref());
+        let traces = environment.traces_config().clone();
+
+        let outcome = match outcome {
             Ok(provisioned) => {
                 info!(
                     command = "provision",
@@ -135,6 +279,10 @@ impl ProvisionCommandHandler {
                     "Infrastructure provisioning completed successfully"
                 );
 
+                let provisioned = provisioned.with_phase_duration(
+                    DeploymentPhase::Provision,
+                    elapsed_ms(started_at, self.clock.now()),
+                );
                 self.repository.save_provisioned(&provisioned)?;
 
                 Ok(provisioned)
@@ -148,15 +296,95 @@ impl ProvisionCommandHandler {
                     "Infrastructure provisioning failed"
                 );
 
-                let context =
-                    self.build_failure_context(&environment, &e, current_step, started_at);
-                let failed = environment.provision_failed(context);
-
-                self.repository.save_provision_failed(&failed)?;
+                // An external tool stopped by Ctrl-C fails its step with its own
+                // error; report the whole run as interrupted instead.
+                let (e, current_step) = if self.cancellation.is_cancelled() {
+                    warn!(
+                        command = "provision",
+                        environment = %environment.name(),
+                        "Infrastructure provisioning interrupted by user"
+                    );
+                    (
+                        ProvisionCommandHandlerError::Interrupted,
+                        ProvisionStep::Interrupted,
+                    )
+                } else {
+                    (e, current_step)
+                };
+
+                self.persist_failure(environment, &e, current_step, started_at)?;
 
                 Err(e)
             }
-        }
+        };
+        prune_traces(&traces_dir, &traces, self.clock.now());
+
+        outcome
+    }
+
+    /// Simulate the provisioning workflow without creating any infrastructure
+    ///
+    /// Renders the `OpenTofu` templates into the build directory, initializes
+    /// and validates the project, and runs `tofu plan` to report the resources
+    /// that `execute` would create. The environment stays in the `Created`
+    /// state: the `Provisioning` transition only happens in memory and is
+    /// never persisted.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if:
+    /// * Environment not found or not in `Created` state
+    /// * Template rendering fails
+    /// * `OpenTofu` initialization, validation, or planning fails
+    #[instrument(
+        name = "command.provision_dry_run",
+        skip_all,
+        fields(
+            command_type = "provision",
+            environment_name = %env_name
+        )
+    )]
+    pub async fn dry_run(
+        &self,
+        env_name: &EnvironmentName,
+        listener: Option<&dyn CommandProgressListener>,
+    ) -> Result<DryRunReport, ProvisionCommandHandlerError> {
+        let environment = self
+            .load_created_environment(env_name)?
+            .start_provisioning();
+        let run_log = self.run_log(&environment, self.clock.now());
+
+        let (tofu_template_renderer, opentofu_client) =
+            self.build_infrastructure_dependencies(&environment, &self.run_timeouts(), &run_log);
+
+        self.render_opentofu_templates(&tofu_template_renderer, listener)
+            .await?;
+
+        let changes = Self::plan_changes(&opentofu_client, listener);
+        prune_run_logs(&run_log);
+        let changes = changes?;
+
+        info!(
+            command = "provision",
+            environment = %env_name,
+            planned_changes = changes.len(),
+            "Provisioning dry run completed"
+        );
+
+        Ok(DryRunReport::new()
+            .with_infrastructure_changes(changes)
+            .with_note("Templates were rendered into the build directory to compute the plan"))
+    }
+
+    /// Initialize and validate the `OpenTofu` project, then compute its plan
+    fn plan_changes(
+        opentofu_client: &Arc<OpenTofuClient>,
+        listener: Option<&dyn CommandProgressListener>,
+    ) -> Result<Vec<PlannedChange>, ProvisionCommandHandlerError> {
+        InitializeInfrastructureStep::new(Arc::clone(opentofu_client)).execute(listener)?;
+        ValidateInfrastructureStep::new(Arc::clone(opentofu_client)).execute(listener)?;
+
+        Ok(PlanInfrastructureStep::new(Arc::clone(opentofu_client)).preview()?)
     }
 
     /// Execute the provisioning workflow
@@ -179,43 +407,107 @@ impl ProvisionCommandHandler {
     async fn execute_provisioning_workflow(
         &self,
         environment: &Environment<Provisioning>,
+        timeouts: &CommandTimeouts,
+        run_log: &RunLog,
         listener: Option<&dyn CommandProgressListener>,
     ) -> StepResult<Environment<Provisioned>, ProvisionCommandHandlerError, ProvisionStep> {
-        let instance_ip = self.provision_infrastructure(environment, listener).await?;
+        let instance_info = self
+            .provision_infrastructure(environment, timeouts, run_log, listener)
+            .await?;
+        let addresses =
+            InstanceAddresses::new(instance_info.ipv4_address, instance_info.ipv6_address);
+        // Providers like Docker publish SSH on another host port: the remaining
+        // steps and later commands connect through it
+        let environment = &environment
+            .clone()
+            .with_published_ssh_port(instance_info.ssh_port);
+        let instance_ip = addresses
+            .preferred(
+                environment
+                    .context()
+                    .user_inputs
+                    .provision()
+                    .ip_preference(),
+            )
+            .expect("OpenTofu instance info always contains at least one IP address");
 
         self.prepare_for_configuration(environment, instance_ip, listener)
             .await?;
 
-        self.wait_for_system_readiness(environment, instance_ip, listener)
+        self.wait_for_system_readiness(environment, instance_ip, timeouts, listener)
             .await?;
 
         let provisioned = environment
             .clone()
-            .provisioned(instance_ip, ProvisionMethod::Provisioned);
+            .provisioned(addresses, ProvisionMethod::Provisioned);
+
+        self.run_custom_steps(&provisioned).await?;
 
         Ok(provisioned)
     }
 
+    /// Run the custom steps registered for [`HookPoint::AfterProvision`]
+    ///
+    /// # Errors
+    ///
+    /// Returns `(CustomStepFailed, ProvisionStep::CustomStep(name))` for the first
+    /// failing step
+    pub(super) async fn run_custom_steps(
+        &self,
+        provisioned: &Environment<Provisioned>,
+    ) -> StepResult<(), ProvisionCommandHandlerError, ProvisionStep> {
+        let context = StepContext::new(provisioned.clone().into_any(), HookPoint::AfterProvision);
+
+        self.custom_steps.run(&context).await.map_err(|e| {
+            let step = ProvisionStep::CustomStep(e.step.clone());
+            (e.into(), step)
+        })
+    }
+
+    /// Persist the environment as `ProvisionFailed` with the context of `error`
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the failed state cannot be saved
+    pub(super) fn persist_failure(
+        &self,
+        environment: Environment<Provisioning>,
+        error: &ProvisionCommandHandlerError,
+        current_step: ProvisionStep,
+        started_at: chrono::DateTime<chrono::Utc>,
+    ) -> Result<(), ProvisionCommandHandlerError> {
+        let context = self.build_failure_context(&environment, error, current_step, started_at);
+        let failed = environment.provision_failed(context);
+
+        self.repository.save_provision_failed(&failed)?;
+
+        Ok(())
+    }
+
     // Private helper methods - organized from higher to lower level of abstraction
 
     /// Provision infrastructure using `OpenTofu`
     ///
     /// This method handles the complete `OpenTofu`-based infrastructure provisioning:
-    /// - Render `OpenTofu` templates (step 1/9)
-    /// - Initialize `OpenTofu` (step 2/9)
-    /// - Validate configuration (step 3/9)
-    /// - Plan infrastructure changes (step 4/9)
-    /// - Apply infrastructure changes (step 5/9)
-    /// - Retrieve instance information (step 6/9)
+    /// - Check the LXD remote, when the instance is not created on the local daemon
+    /// - Render `OpenTofu` templates (step 1/10)
+    /// - Initialize `OpenTofu` (step 2/10)
+    /// - Validate configuration (step 3/10)
+    /// - Plan infrastructure changes (step 4/10)
+    /// - Apply infrastructure changes (step 5/10)
+    /// - Retrieve instance information (step 6/10)
     ///
     /// # Arguments
     ///
     /// * `environment` - The environment in Provisioning state
+    /// * `timeouts` - Timeouts bounding each `OpenTofu` command
+    /// * `run_log` - Logs receiving the output of each `OpenTofu` command
     /// * `listener` - Optional progress listener for step-level reporting
     ///
     /// # Returns
     ///
-    /// Returns the IP address of the provisioned instance
+    /// Returns the instance information reported by `OpenTofu`, with the
+    /// IPv4 and/or IPv6 address of the provisioned instance
     ///
     /// # Errors
     ///
@@ -223,54 +515,107 @@ impl ProvisionCommandHandler {
     async fn provision_infrastructure(
         &self,
         environment: &Environment<Provisioning>,
+        timeouts: &CommandTimeouts,
+        run_log: &RunLog,
         listener: Option<&dyn CommandProgressListener>,
-    ) -> StepResult<IpAddr, ProvisionCommandHandlerError, ProvisionStep> {
+    ) -> StepResult<InstanceInfo, ProvisionCommandHandlerError, ProvisionStep> {
         let (tofu_template_renderer, opentofu_client) =
-            Self::build_infrastructure_dependencies(environment);
+            self.build_infrastructure_dependencies(environment, timeouts, run_log);
 
-        // Step 1/9: Render OpenTofu templates
+        // Preflight: enough free space for the OpenTofu state under the workspace
+        self.ensure_not_interrupted()?;
+        CheckWorkspaceDiskSpaceStep::new(
+            environment.data_dir(),
+            self.disk_space_thresholds.workspace(),
+        )
+        .execute(listener)
+        .map_err(|e| (e.into(), ProvisionStep::DiskSpaceCheck))?;
+
+        let lxd_remote = environment
+            .provider_config()
+            .as_lxd()
+            .map(|lxd| lxd.remote.clone());
+
+        // Preflight: the LXD remote must be reachable and trust this machine
+        if let Some(Some(remote)) = lxd_remote.clone() {
+            self.ensure_not_interrupted()?;
+            let current_step = ProvisionStep::LxdRemoteCheck;
+            CheckLxdRemoteStep::new(remote)
+                .execute(listener)
+                .map_err(|e| (ProvisionCommandHandlerError::from(e), current_step))?;
+        }
+
+        // Preflight: enough free space for the instance disk in the LXD storage pool
+        if let Some(remote) = lxd_remote {
+            self.ensure_not_interrupted()?;
+            CheckLxdStoragePoolSpaceStep::new(
+                remote,
+                self.disk_space_thresholds.lxd_storage_pool(),
+            )
+            .execute(listener)
+            .map_err(|e| (e.into(), ProvisionStep::DiskSpaceCheck))?;
+        }
+
+        // Step 1/10: Render OpenTofu templates
+        self.ensure_not_interrupted()?;
         let current_step = ProvisionStep::RenderOpenTofuTemplates;
         Self::notify_step_started(listener, 1, "Rendering OpenTofu templates");
         self.render_opentofu_templates(&tofu_template_renderer, listener)
             .await
             .map_err(|e| (e, current_step))?;
+        Self::notify_step_completed(listener, 1, "Rendering OpenTofu templates");
 
-        // Step 2/9: Initialize OpenTofu
+        // Step 2/10: Initialize OpenTofu
+        self.ensure_not_interrupted()?;
         let current_step = ProvisionStep::OpenTofuInit;
         Self::notify_step_started(listener, 2, "Initializing OpenTofu");
         InitializeInfrastructureStep::new(Arc::clone(&opentofu_client))
             .execute(listener)
             .map_err(|e| (ProvisionCommandHandlerError::from(e), current_step))?;
+        Self::notify_step_completed(listener, 2, "Initializing OpenTofu");
 
-        // Step 3/9: Validate infrastructure configuration
+        // Step 3/10: Validate infrastructure configuration
+        self.ensure_not_interrupted()?;
         let current_step = ProvisionStep::OpenTofuValidate;
         Self::notify_step_started(listener, 3, "Validating infrastructure configuration");
         ValidateInfrastructureStep::new(Arc::clone(&opentofu_client))
             .execute(listener)
             .map_err(|e| (ProvisionCommandHandlerError::from(e), current_step))?;
+        Self::notify_step_completed(listener, 3, "Validating infrastructure configuration");
 
-        // Step 4/9: Plan infrastructure changes
+        // Step 4/10: Plan infrastructure changes
+        self.ensure_not_interrupted()?;
         let current_step = ProvisionStep::OpenTofuPlan;
         Self::notify_step_started(listener, 4, "Planning infrastructure changes");
         PlanInfrastructureStep::new(Arc::clone(&opentofu_client))
             .execute(listener)
             .map_err(|e| (ProvisionCommandHandlerError::from(e), current_step))?;
+        Self::notify_step_completed(listener, 4, "Planning infrastructure changes");
 
-        // Step 5/9: Apply infrastructure changes
+        // Step 5/10: Apply infrastructure changes
+        self.ensure_not_interrupted()?;
         let current_step = ProvisionStep::OpenTofuApply;
         Self::notify_step_started(listener, 5, "Applying infrastructure changes");
         ApplyInfrastructureStep::new(Arc::clone(&opentofu_client))
             .execute(listener)
-            .map_err(|e| (ProvisionCommandHandlerError::from(e), current_step))?;
+            .map_err(|e| {
+                (
+                    ProvisionCommandHandlerError::from_apply_failure(e),
+                    current_step,
+                )
+            })?;
+        Self::notify_step_completed(listener, 5, "Applying infrastructure changes");
 
-        // Step 6/9: Get instance information
+        // Step 6/10: Get instance information
+        self.ensure_not_interrupted()?;
         let current_step = ProvisionStep::GetInstanceInfo;
         Self::notify_step_started(listener, 6, "Retrieving instance information");
         let instance_info =
-            Self::get_instance_info(&opentofu_client, listener).map_err(|e| (e, current_step))?;
-        let instance_ip = instance_info.ip_address;
+            Self::get_instance_info(&opentofu_client, environment.provider_config(), listener)
+                .map_err(|e| (e, current_step))?;
+        Self::notify_step_completed(listener, 6, "Retrieving instance information");
 
-        Ok(instance_ip)
+        Ok(instance_info)
     }
 
     /// Build dependencies for infrastructure provisioning
@@ -280,6 +625,8 @@ impl ProvisionCommandHandler {
     /// # Arguments
     ///
     /// * `environment` - The environment in Provisioning state
+    /// * `timeouts` - Timeouts bounding each `OpenTofu` command
+    /// * `run_log` - Logs receiving the output of each `OpenTofu` command
     ///
     /// # Returns
     ///
@@ -287,25 +634,53 @@ impl ProvisionCommandHandler {
     /// - `TofuProjectGenerator` - For rendering `OpenTofu` templates
     /// - `OpenTofuClient` - For executing `OpenTofu` operations
     fn build_infrastructure_dependencies(
+        &self,
         environment: &Environment<Provisioning>,
+        timeouts: &CommandTimeouts,
+        run_log: &RunLog,
     ) -> (Arc<TofuProjectGenerator>, Arc<OpenTofuClient>) {
-        let opentofu_client = Arc::new(OpenTofuClient::new(environment.tofu_build_dir()));
+        let mut opentofu_client = OpenTofuClient::new(environment.tofu_build_dir())
+            .with_timeouts(timeouts.clone())
+            .with_run_log(run_log.clone())
+            .with_env_vars(environment.context().user_inputs.proxy_env_vars());
+        if let Some(executable) = &self.opentofu_executable {
+            opentofu_client = opentofu_client.with_executable(executable.clone());
+        }
+        let opentofu_client = Arc::new(opentofu_client);
 
-        let template_manager = Arc::new(crate::domain::TemplateManager::new(
-            environment.templates_dir(),
-        ));
+        let template_manager = Arc::new(
+            crate::domain::TemplateManager::new(environment.templates_dir())
+                .with_override_dir(environment.templates_override_dir())
+                .with_extra_variables(environment.extra_template_variables().clone()),
+        );
 
         let clock = Arc::new(SystemClock);
 
-        let tofu_template_renderer = Arc::new(TofuProjectGenerator::new(
-            template_manager,
-            environment.build_dir(),
-            environment.ssh_credentials().clone(),
-            environment.ssh_port(),
-            environment.instance_name().clone(),
-            environment.provider_config().clone(),
-            clock,
-        ));
+        let tofu_template_renderer = Arc::new(
+            TofuProjectGenerator::new(
+                template_manager,
+                environment.build_dir(),
+                environment.ssh_credentials().clone(),
+                // The port sshd listens on inside the instance
+                environment.context().user_inputs.ssh_port(),
+                environment.instance_name().clone(),
+                environment.provider_config().clone(),
+                clock,
+            )
+            .with_public_ports(environment.context().user_inputs.public_port_bindings())
+            .with_extra_firewall_rules(
+                environment
+                    .context()
+                    .user_inputs
+                    .firewall()
+                    .extra_allow()
+                    .to_vec(),
+            )
+            .with_cloud_init_customization(environment.context().user_inputs.cloud_init().clone())
+            .with_instance_resources(*environment.context().user_inputs.instance_resources())
+            .with_offline_mode(environment.context().user_inputs.offline().cloned())
+            .with_proxy(environment.context().user_inputs.proxy().cloned()),
+        );
 
         (tofu_template_renderer, opentofu_client)
     }
@@ -313,7 +688,7 @@ impl ProvisionCommandHandler {
     /// Prepare for configuration stages
     ///
     /// This method handles preparation for future configuration stages:
-    /// - Render Ansible templates with user inputs and runtime instance IP (step 7/9)
+    /// - Render Ansible templates with user inputs and runtime instance IP (step 7/10)
     ///
     /// # Arguments
     ///
@@ -330,7 +705,8 @@ impl ProvisionCommandHandler {
         instance_ip: IpAddr,
         listener: Option<&dyn CommandProgressListener>,
     ) -> StepResult<(), ProvisionCommandHandlerError, ProvisionStep> {
-        // Step 7/9: Render Ansible templates
+        // Step 7/10: Render Ansible templates
+        self.ensure_not_interrupted()?;
         let current_step = ProvisionStep::RenderAnsibleTemplates;
         Self::notify_step_started(listener, 7, "Rendering Ansible templates");
 
@@ -348,12 +724,19 @@ impl ProvisionCommandHandler {
 
         let ansible_template_service = AnsibleTemplateRenderingService::from_paths(
             environment.templates_dir(),
+            environment.templates_override_dir(),
+            environment.extra_template_variables().clone(),
             environment.build_dir().clone(),
             self.clock.clone(),
-        );
+        )
+        .with_known_hosts_file(environment.ssh_known_hosts_path());
 
         ansible_template_service
-            .render_templates(&environment.context().user_inputs, instance_ip, None)
+            .render_templates(
+                &environment.context().user_inputs,
+                instance_ip,
+                environment.context().runtime_outputs.published_ssh_port(),
+            )
             .await
             .map_err(|e| {
                 (
@@ -369,6 +752,7 @@ impl ProvisionCommandHandler {
             ));
             l.on_detail("Generated inventory and playbooks");
         }
+        Self::notify_step_completed(listener, 7, "Rendering Ansible templates");
 
         Ok(())
     }
@@ -376,13 +760,15 @@ impl ProvisionCommandHandler {
     /// Wait for system readiness
     ///
     /// This method waits for the provisioned instance to be ready:
-    /// - Wait for SSH connectivity on the configured port (step 8/9)
-    /// - Wait for cloud-init completion (step 9/9)
+    /// - Check that the SSH port accepts TCP connections (step 8/10)
+    /// - Wait for SSH connectivity on the configured port (step 9/10)
+    /// - Wait for cloud-init completion (step 10/10)
     ///
     /// # Arguments
     ///
     /// * `environment` - The environment in Provisioning state
     /// * `instance_ip` - IP address of the provisioned instance
+    /// * `timeouts` - Carries the overall deadline, if any
     /// * `listener` - Optional progress listener for step-level reporting
     ///
     /// # Errors
@@ -392,47 +778,110 @@ impl ProvisionCommandHandler {
         &self,
         environment: &Environment<Provisioning>,
         instance_ip: IpAddr,
+        timeouts: &CommandTimeouts,
         listener: Option<&dyn CommandProgressListener>,
     ) -> StepResult<(), ProvisionCommandHandlerError, ProvisionStep> {
-        let ansible_client = Self::build_ansible_client(environment);
         let ssh_credentials = environment.ssh_credentials();
         let ssh_port = environment.ssh_port();
         let ssh_socket_addr = SocketAddr::new(instance_ip, ssh_port);
-        let ssh_config = SshConfig::new(ssh_credentials.clone(), ssh_socket_addr);
+        // Connectivity and cloud-init polling share one SSH connection
+        let multiplexer = SshMultiplexer::new(environment.ssh_control_dir());
+        let ssh_config = SshConfig::new(ssh_credentials.clone(), ssh_socket_addr)
+            .with_control_dir(multiplexer.control_dir().to_path_buf());
+
+        // Step 8/10: Check that the SSH port accepts connections
+        self.ensure_not_interrupted()?;
+        let current_step = ProvisionStep::SshConnectivity;
+        Self::notify_step_started(listener, 8, "Checking SSH port");
+        // A port the provider or a firewall blocks never opens: report it
+        // within a minute instead of after the full SSH and cloud-init waits.
+        // A custom port only opens once cloud-init has moved SSH from port 22
+        // and rebooted, so the instance answering there means it is still booting.
+        let mut check_ssh_port = CheckSshPortStep::new(ssh_socket_addr);
+        let configured_ssh_port = environment.context().user_inputs.ssh_port();
+        if ssh_port == configured_ssh_port && configured_ssh_port != DEFAULT_SSH_PORT {
+            check_ssh_port = check_ssh_port.with_boot_address(
+                SocketAddr::new(instance_ip, DEFAULT_SSH_PORT),
+                environment.provision_config().cloud_init_timeout(),
+            );
+        }
+        tokio::select! {
+            result = check_ssh_port.execute(listener) => {
+                result.map_err(|e| (ProvisionCommandHandlerError::from(e), current_step))?;
+            }
+            () = self.cancellation.cancelled() => {
+                return Err((
+                    ProvisionCommandHandlerError::Interrupted,
+                    ProvisionStep::Interrupted,
+                ));
+            }
+            () = Self::deadline_reached(timeouts.deadline) => {
+                return Err((self.deadline_exceeded(), current_step));
+            }
+        }
+        Self::notify_step_completed(listener, 8, "Checking SSH port");
 
-        // Step 8/9: Wait for SSH connectivity
+        // Step 9/10: Wait for SSH connectivity
+        self.ensure_not_interrupted()?;
         let current_step = ProvisionStep::WaitSshConnectivity;
-        Self::notify_step_started(listener, 8, "Waiting for SSH connectivity");
-        WaitForSSHConnectivityStep::new(ssh_config)
-            .execute(listener)
-            .await
+        Self::notify_step_started(listener, 9, "Waiting for SSH connectivity");
+        // The retry loop can run for minutes, so stop it as soon as the user
+        // interrupts instead of waiting for the next step boundary
+        let wait_for_ssh = WaitForSSHConnectivityStep::new(ssh_config.clone());
+        tokio::select! {
+            result = wait_for_ssh.execute(listener) => {
+                result.map_err(|e| (ProvisionCommandHandlerError::from(e), current_step.clone()))?;
+            }
+            () = self.cancellation.cancelled() => {
+                return Err((
+                    ProvisionCommandHandlerError::Interrupted,
+                    ProvisionStep::Interrupted,
+                ));
+            }
+            () = Self::deadline_reached(timeouts.deadline) => {
+                return Err((self.deadline_exceeded(), current_step.clone()));
+            }
+        }
+
+        // A fresh instance comes with new host keys: record them, replacing
+        // the ones of a previous instance, and check them from now on
+        let known_hosts = KnownHostsFile::new(environment.ssh_known_hosts_path());
+        RecordHostKeysStep::new(ssh_config.clone(), known_hosts.clone())
+            .with_rotation()
+            .execute()
             .map_err(|e| (ProvisionCommandHandlerError::from(e), current_step))?;
+        let ssh_config = ssh_config.with_known_hosts(known_hosts.path().to_path_buf());
+        Self::notify_step_completed(listener, 9, "Waiting for SSH connectivity");
 
-        // Step 9/9: Wait for cloud-init completion
+        // Step 10/10: Wait for cloud-init completion
+        self.ensure_not_interrupted()?;
         let current_step = ProvisionStep::CloudInitWait;
-        Self::notify_step_started(listener, 9, "Waiting for cloud-init completion");
-        WaitForCloudInitStep::new(Arc::clone(&ansible_client))
-            .execute(listener)
-            .map_err(|e| (ProvisionCommandHandlerError::from(e), current_step))?;
+        Self::notify_step_started(listener, 10, "Waiting for cloud-init completion");
+        // Polling backs off for up to the configured timeout, so it is
+        // interruptible the same way as the SSH wait
+        let wait_for_cloud_init = WaitForCloudInitStep::new(
+            ssh_config,
+            environment.provision_config().cloud_init_timeout(),
+        );
+        tokio::select! {
+            result = wait_for_cloud_init.execute(listener) => {
+                result.map_err(|e| (ProvisionCommandHandlerError::from(e), current_step))?;
+            }
+            () = self.cancellation.cancelled() => {
+                return Err((
+                    ProvisionCommandHandlerError::Interrupted,
+                    ProvisionStep::Interrupted,
+                ));
+            }
+            () = Self::deadline_reached(timeouts.deadline) => {
+                return Err((self.deadline_exceeded(), current_step));
+            }
+        }
+        Self::notify_step_completed(listener, 10, "Waiting for cloud-init completion");
 
         Ok(())
     }
 
-    /// Build Ansible client for playbook execution
-    ///
-    /// Creates the Ansible client needed for waiting on cloud-init completion.
-    ///
-    /// # Arguments
-    ///
-    /// * `environment` - The environment in Provisioning state
-    ///
-    /// # Returns
-    ///
-    /// Returns `AnsibleClient` for executing Ansible playbooks
-    fn build_ansible_client(environment: &Environment<Provisioning>) -> Arc<AnsibleClient> {
-        Arc::new(AnsibleClient::new(environment.ansible_build_dir()))
-    }
-
     /// Render `OpenTofu` templates
     ///
     /// Generates `OpenTofu` configuration files from templates.
@@ -464,6 +913,8 @@ impl ProvisionCommandHandler {
     /// # Arguments
     ///
     /// * `opentofu_client` - The `OpenTofu` client for executing commands
+    /// * `provider_config` - The provider of the instance; libvirt instances
+    ///   fall back to their DHCP lease when the outputs have no address
     /// * `listener` - Optional progress listener for reporting details
     ///
     /// # Errors
@@ -471,13 +922,40 @@ impl ProvisionCommandHandler {
     /// Returns an error if instance information cannot be retrieved
     fn get_instance_info(
         opentofu_client: &Arc<OpenTofuClient>,
+        provider_config: &ProviderConfig,
         listener: Option<&dyn CommandProgressListener>,
     ) -> Result<InstanceInfo, ProvisionCommandHandlerError> {
-        let instance_info =
-            GetInstanceInfoStep::new(Arc::clone(opentofu_client)).execute(listener)?;
+        let mut step = GetInstanceInfoStep::new(Arc::clone(opentofu_client));
+        if provider_config.as_libvirt().is_some() {
+            step = step.with_lease_lookup(VirshClient::new(LIBVIRT_URI));
+        }
+
+        let instance_info = step.execute(listener)?;
         Ok(instance_info)
     }
 
+    /// Fail with `Interrupted` if the user cancelled the command
+    ///
+    /// Called at every step boundary so no new step starts after Ctrl-C.
+    ///
+    /// # Errors
+    ///
+    /// Returns `(Interrupted, ProvisionStep::Interrupted)` if the
+    /// cancellation token has been cancelled
+    #[allow(clippy::result_large_err)]
+    fn ensure_not_interrupted(
+        &self,
+    ) -> StepResult<(), ProvisionCommandHandlerError, ProvisionStep> {
+        if self.cancellation.is_cancelled() {
+            return Err((
+                ProvisionCommandHandlerError::Interrupted,
+                ProvisionStep::Interrupted,
+            ));
+        }
+
+        Ok(())
+    }
+
     /// Notify the progress listener that a step has started.
     ///
     /// This is a convenience helper that handles the `Option` check,
@@ -492,6 +970,49 @@ impl ProvisionCommandHandler {
         }
     }
 
+    /// Notify the progress listener that a step has completed.
+    fn notify_step_completed(
+        listener: Option<&dyn CommandProgressListener>,
+        step_number: usize,
+        description: &str,
+    ) {
+        if let Some(l) = listener {
+            l.on_step_completed(step_number, description);
+        }
+    }
+
+    /// Output logs of the provision run started at `started_at`
+    fn run_log(
+        &self,
+        environment: &Environment<Provisioning>,
+        started_at: chrono::DateTime<chrono::Utc>,
+    ) -> RunLog {
+        RunLog::new(&environment.run_logs_dir(), "provision", started_at)
+            .keeping(self.kept_run_logs)
+    }
+
+    /// Command timeouts for a run starting now, capped by the overall deadline
+    fn run_timeouts(&self) -> CommandTimeouts {
+        self.command_timeouts
+            .clone()
+            .with_deadline_after(self.deadline)
+    }
+
+    /// Resolves once the overall deadline has passed, never without one
+    async fn deadline_reached(deadline: Option<Instant>) {
+        match deadline {
+            Some(deadline) => tokio::time::sleep_until(deadline.into()).await,
+            None => std::future::pending().await,
+        }
+    }
+
+    /// Error reported when the overall deadline passed while waiting for the instance
+    fn deadline_exceeded(&self) -> ProvisionCommandHandlerError {
+        ProvisionCommandHandlerError::DeadlineExceeded {
+            deadline: self.deadline.unwrap_or_default(),
+        }
+    }
+
     /// Build failure context for a provisioning error and generate trace file
     ///
     /// This helper method builds structured error context including the failed step,
@@ -525,17 +1046,20 @@ impl ProvisionCommandHandler {
         let error_kind = error.error_kind();
 
         // Build base failure context using common helper
-        let base = build_base_failure_context(&self.clock, started_at, error.to_string());
+        let error_summary = error.to_string();
+
+        let base = build_base_failure_context(&self.clock, started_at, error_summary);
 
         // Build handler-specific context
         let mut context = ProvisionFailureContext {
             failed_step,
             error_kind,
             base,
+            remote_log_excerpt: error.remote_log_excerpt().map(str::to_string),
         };
 
         // Generate trace file (logging handled by trace writer)
-        let traces_dir = environment.traces_dir();
+        let traces_dir = traces_dir(environment, self.trace_dir.as_deref());
         let writer = ProvisionTraceWriter::new(traces_dir, Arc::clone(&self.clock));
 
         if let Ok(trace_file) = writer.write_trace(&context, error) {
@@ -578,8 +1102,8 @@ mod tests {
     use crate::testing::{ProgressEvent, RecordingProgressListener};
 
     #[test]
-    fn it_should_have_nine_total_provision_steps() {
-        assert_eq!(TOTAL_PROVISION_STEPS, 9);
+    fn it_should_have_ten_total_provision_steps() {
+        assert_eq!(TOTAL_PROVISION_STEPS, 10);
     }
 
     #[test]
@@ -605,6 +1129,21 @@ mod tests {
         ProvisionCommandHandler::notify_step_started(None, 1, "Test step");
     }
 
+    #[test]
+    fn it_should_notify_listener_when_a_step_completes() {
+        let listener = RecordingProgressListener::new();
+
+        ProvisionCommandHandler::notify_step_completed(Some(&listener), 1, "Test step");
+
+        assert_eq!(
+            listener.events(),
+            [ProgressEvent::StepCompleted {
+                step_number: 1,
+                description: "Test step".to_string(),
+            }]
+        );
+    }
+
     #[test]
     fn it_should_pass_correct_total_steps_to_listener() {
         let listener = RecordingProgressListener::new();
@@ -614,14 +1153,14 @@ mod tests {
         let events = listener.events();
         assert_eq!(events.len(), 1);
         if let ProgressEvent::StepStarted { total_steps, .. } = &events[0] {
-            assert_eq!(*total_steps, 9);
+            assert_eq!(*total_steps, 10);
         } else {
             panic!("Expected StepStarted event");
         }
     }
 
     #[test]
-    fn it_should_record_all_nine_step_descriptions_when_notified_sequentially() {
+    fn it_should_record_all_ten_step_descriptions_when_notified_sequentially() {
         let listener = RecordingProgressListener::new();
 
         let step_descriptions = [
@@ -632,8 +1171,9 @@ mod tests {
             (5, "Applying infrastructure changes"),
             (6, "Retrieving instance information"),
             (7, "Rendering Ansible templates"),
-            (8, "Waiting for SSH connectivity"),
-            (9, "Waiting for cloud-init completion"),
+            (8, "Checking SSH port"),
+            (9, "Waiting for SSH connectivity"),
+            (10, "Waiting for cloud-init completion"),
         ];
 
         for (step_number, description) in &step_descriptions {
@@ -645,7 +1185,7 @@ mod tests {
         }
 
         let events = listener.step_started_events();
-        assert_eq!(events.len(), 9);
+        assert_eq!(events.len(), 10);
 
         for (i, (expected_number, expected_desc)) in step_descriptions.iter().enumerate() {
             if let ProgressEvent::StepStarted {
@@ -0,0 +1,55 @@
+//! Cancellation tests for Provision Command
+//!
+//! These tests verify that a cancelled `ProvisionCommandHandler` does not leave
+//! the environment in the transient `Provisioning` state.
+
+use std::sync::Arc;
+
+use tokio_util::sync::CancellationToken;
+
+use crate::application::command_handlers::provision::{
+    ProvisionCommandHandler, ProvisionCommandHandlerError,
+};
+use crate::domain::environment::repository::EnvironmentRepository;
+use crate::domain::environment::state::{AnyEnvironmentState, ProvisionStep};
+use crate::domain::environment::testing::EnvironmentTestBuilder;
+use crate::infrastructure::persistence::filesystem::file_environment_repository::FileEnvironmentRepository;
+use crate::shared::{ErrorKind, SystemClock};
+
+#[tokio::test]
+async fn it_should_persist_provision_failed_with_interrupted_step_when_cancelled() {
+    let (environment, _data_dir, _build_dir, temp_dir) = EnvironmentTestBuilder::new()
+        .with_name("interrupted-env")
+        .build_with_custom_paths();
+    let env_name = environment.name().clone();
+    let repository = Arc::new(FileEnvironmentRepository::new(temp_dir.path().join("data")));
+    repository.save(&environment.into_any()).unwrap();
+
+    let cancellation = CancellationToken::new();
+    cancellation.cancel();
+    let handler = ProvisionCommandHandler::new(Arc::new(SystemClock), repository.clone())
+        .with_cancellation(cancellation);
+
+    let result = handler.execute(&env_name, None).await;
+
+    assert!(matches!(
+        result,
+        Err(ProvisionCommandHandlerError::Interrupted)
+    ));
+
+    let persisted = repository.load(&env_name).unwrap().unwrap();
+    let AnyEnvironmentState::ProvisionFailed(failed) = &persisted else {
+        panic!(
+            "expected ProvisionFailed state, got {}",
+            persisted.state_name()
+        );
+    };
+    assert_eq!(
+        failed.state().context.failed_step,
+        ProvisionStep::Interrupted
+    );
+    assert_eq!(failed.state().context.error_kind, ErrorKind::Interrupted);
+    assert!(persisted
+        .to_string()
+        .ends_with("provision_failed (failed at: interrupted by user)"));
+}
@@ -0,0 +1,146 @@
+//! Custom step tests for Provision Command
+//!
+//! These tests verify that the `AfterProvision` custom steps see the
+//! provisioned environment, and that a failing custom step is recorded by
+//! name in the `ProvisionFailed` state.
+
+use std::net::{IpAddr, Ipv4Addr};
+use std::path::PathBuf;
+use std::sync::Arc;
+
+use crate::application::command_handlers::provision::{
+    ProvisionCommandHandler, ProvisionCommandHandlerError,
+};
+use crate::application::steps::{
+    CustomSteps, HookPoint, Step, StepContext, StepError, StepFuture, StepOutcome,
+};
+use crate::domain::environment::repository::EnvironmentRepository;
+use crate::domain::environment::runtime_outputs::ProvisionMethod;
+use crate::domain::environment::state::{AnyEnvironmentState, ProvisionStep};
+use crate::domain::environment::testing::EnvironmentTestBuilder;
+use crate::domain::environment::{Environment, Provisioning};
+use crate::infrastructure::persistence::filesystem::file_environment_repository::FileEnvironmentRepository;
+use crate::shared::{ErrorKind, SystemClock};
+
+/// Writes the instance IP to a marker file
+struct WriteMarkerStep {
+    path: PathBuf,
+}
+
+impl Step for WriteMarkerStep {
+    fn name(&self) -> &str {
+        "write-marker"
+    }
+
+    fn execute<'a>(&'a self, context: &'a StepContext) -> StepFuture<'a> {
+        Box::pin(async move {
+            let ip = context
+                .instance_ip()
+                .ok_or_else(|| StepError::new("the instance has no IP address"))?;
+            tokio::fs::write(&self.path, ip.to_string())
+                .await
+                .map_err(|e| StepError::new("cannot write the marker").with_source(e))?;
+            Ok(StepOutcome::Completed)
+        })
+    }
+}
+
+struct FailingStep;
+
+impl Step for FailingStep {
+    fn name(&self) -> &str {
+        "register-dns"
+    }
+
+    fn execute<'a>(&'a self, _context: &'a StepContext) -> StepFuture<'a> {
+        Box::pin(async { Err(StepError::new("DNS API unreachable")) })
+    }
+}
+
+/// Saves a `Provisioning` environment, as the handler does before running the steps
+fn provisioning_environment(
+    repository: &FileEnvironmentRepository,
+) -> (Environment<Provisioning>, tempfile::TempDir) {
+    let (environment, _data_dir, _build_dir, temp_dir) = EnvironmentTestBuilder::new()
+        .with_name("custom-steps-env")
+        .build_with_custom_paths();
+    let environment = environment.start_provisioning();
+    repository.save(&environment.clone().into_any()).unwrap();
+    (environment, temp_dir)
+}
+
+fn instance_ip() -> IpAddr {
+    IpAddr::V4(Ipv4Addr::new(10, 140, 190, 14))
+}
+
+#[tokio::test]
+async fn it_should_run_after_provision_steps_with_the_provisioned_environment() {
+    let temp_dir = tempfile::TempDir::new().unwrap();
+    let repository = Arc::new(FileEnvironmentRepository::new(temp_dir.path().join("data")));
+    let (environment, _env_dir) = provisioning_environment(&repository);
+    let marker = temp_dir.path().join("marker");
+    let mut custom_steps = CustomSteps::new();
+    custom_steps.register(
+        HookPoint::AfterProvision,
+        WriteMarkerStep {
+            path: marker.clone(),
+        },
+    );
+    let handler = ProvisionCommandHandler::new(Arc::new(SystemClock), repository)
+        .with_custom_steps(custom_steps);
+
+    let provisioned = environment.provisioned(instance_ip(), ProvisionMethod::Provisioned);
+    handler.run_custom_steps(&provisioned).await.unwrap();
+
+    assert_eq!(std::fs::read_to_string(marker).unwrap(), "10.140.190.14");
+}
+
+#[tokio::test]
+async fn it_should_persist_provision_failed_with_the_name_of_the_failing_custom_step() {
+    let temp_dir = tempfile::TempDir::new().unwrap();
+    let repository = Arc::new(FileEnvironmentRepository::new(temp_dir.path().join("data")));
+    let (environment, _env_dir) = provisioning_environment(&repository);
+    let env_name = environment.name().clone();
+    let mut custom_steps = CustomSteps::new();
+    custom_steps.register(HookPoint::AfterProvision, FailingStep);
+    let handler = ProvisionCommandHandler::new(Arc::new(SystemClock), repository.clone())
+        .with_custom_steps(custom_steps);
+    let started_at = chrono::Utc::now();
+
+    let provisioned = environment
+        .clone()
+        .provisioned(instance_ip(), ProvisionMethod::Provisioned);
+    let (error, step) = handler.run_custom_steps(&provisioned).await.unwrap_err();
+    handler
+        .persist_failure(environment, &error, step, started_at)
+        .unwrap();
+
+    assert!(matches!(
+        error,
+        ProvisionCommandHandlerError::CustomStepFailed(ref failure) if failure.step == "register-dns"
+    ));
+    let persisted = repository.load(&env_name).unwrap().unwrap();
+    let AnyEnvironmentState::ProvisionFailed(failed) = &persisted else {
+        panic!(
+            "expected ProvisionFailed state, got {}",
+            persisted.state_name()
+        );
+    };
+    assert_eq!(
+        failed.state().context.failed_step,
+        ProvisionStep::CustomStep("register-dns".to_string())
+    );
+    assert!(failed
+        .state()
+        .context
+        .base
+        .error_summary
+        .contains("DNS API unreachable"));
+    assert_eq!(
+        failed.state().context.error_kind,
+        ErrorKind::CommandExecution
+    );
+    assert!(persisted
+        .to_string()
+        .ends_with("provision_failed (failed at: custom:register-dns)"));
+}
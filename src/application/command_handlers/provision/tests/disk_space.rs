@@ -0,0 +1,70 @@
+//! Disk space preflight tests for Provision Command
+//!
+//! These tests verify that provisioning on a too full workspace partition
+//! stops before any `OpenTofu` command runs.
+
+use std::os::unix::fs::PermissionsExt;
+use std::sync::Arc;
+
+use crate::application::command_handlers::provision::{
+    ProvisionCommandHandler, ProvisionCommandHandlerError,
+};
+use crate::application::steps::DiskSpaceCheckError;
+use crate::domain::environment::repository::EnvironmentRepository;
+use crate::domain::environment::state::{AnyEnvironmentState, ProvisionStep};
+use crate::domain::environment::testing::EnvironmentTestBuilder;
+use crate::domain::environment::DiskSpaceThresholds;
+use crate::infrastructure::persistence::filesystem::file_environment_repository::FileEnvironmentRepository;
+use crate::shared::{ErrorKind, SystemClock};
+
+#[tokio::test]
+async fn it_should_abort_before_running_opentofu_when_the_workspace_partition_is_too_full() {
+    let (environment, _data_dir, _build_dir, temp_dir) = EnvironmentTestBuilder::new()
+        .with_name("full-disk-env")
+        .build_with_custom_paths();
+    let env_name = environment.name().clone();
+    let repository = Arc::new(FileEnvironmentRepository::new(temp_dir.path().join("data")));
+    repository.save(&environment.into_any()).unwrap();
+
+    // A fake `tofu` leaving a trace of any call
+    let tofu_calls = temp_dir.path().join("tofu-calls");
+    let fake_tofu = temp_dir.path().join("fake-tofu");
+    std::fs::write(
+        &fake_tofu,
+        format!("#!/bin/sh\necho \"$@\" >> {}\n", tofu_calls.display()),
+    )
+    .unwrap();
+    std::fs::set_permissions(&fake_tofu, std::fs::Permissions::from_mode(0o755)).unwrap();
+
+    let handler = ProvisionCommandHandler::new(Arc::new(SystemClock), repository.clone())
+        .with_opentofu_executable(fake_tofu.display().to_string())
+        .with_disk_space_thresholds(DiskSpaceThresholds {
+            workspace_gib: u64::MAX,
+            ..DiskSpaceThresholds::default()
+        });
+
+    let result = handler.execute(&env_name, None).await;
+
+    let Err(ProvisionCommandHandlerError::DiskSpace(DiskSpaceCheckError::InsufficientDiskSpace {
+        location,
+        ..
+    })) = result
+    else {
+        panic!("expected an insufficient disk space error, got {result:?}");
+    };
+    assert!(location.starts_with("partition '/"), "{location}");
+    assert!(!tofu_calls.exists(), "OpenTofu should not have been run");
+
+    let persisted = repository.load(&env_name).unwrap().unwrap();
+    let AnyEnvironmentState::ProvisionFailed(failed) = &persisted else {
+        panic!(
+            "expected ProvisionFailed state, got {}",
+            persisted.state_name()
+        );
+    };
+    assert_eq!(
+        failed.state().context.failed_step,
+        ProvisionStep::DiskSpaceCheck
+    );
+    assert_eq!(failed.state().context.error_kind, ErrorKind::FileSystem);
+}
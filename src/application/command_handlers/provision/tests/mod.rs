@@ -5,4 +5,14 @@
 #[cfg(test)]
 pub mod builders;
 #[cfg(test)]
+pub mod cancellation;
+#[cfg(test)]
+pub mod custom_steps;
+#[cfg(test)]
+#[cfg(unix)] // The fake `tofu` is a shell script made executable with Unix permissions
+pub mod disk_space;
+#[cfg(test)]
 pub mod integration;
+#[cfg(test)]
+#[cfg(unix)] // The fake `tofu` is a shell script made executable with Unix permissions
+pub mod timeout;
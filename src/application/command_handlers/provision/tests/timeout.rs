@@ -0,0 +1,140 @@
+//! Timeout tests for Provision Command
+//!
+//! These tests run the workflow against a fake `tofu` executable that hangs,
+//! and verify that it is killed and the environment persisted as
+//! `ProvisionFailed`.
+
+use std::os::unix::fs::PermissionsExt;
+use std::path::{Path, PathBuf};
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+
+use crate::application::command_handlers::provision::{
+    ProvisionCommandHandler, ProvisionCommandHandlerError,
+};
+use crate::domain::environment::repository::EnvironmentRepository;
+use crate::domain::environment::state::{AnyEnvironmentState, ProvisionStep};
+use crate::domain::environment::testing::EnvironmentTestBuilder;
+use crate::infrastructure::persistence::filesystem::file_environment_repository::FileEnvironmentRepository;
+use crate::shared::command::{CommandError, CommandTimeouts};
+use crate::shared::{ErrorKind, SystemClock};
+
+/// Writes a fake `tofu` that records the PID of a child it spawns, then hangs
+fn write_hanging_tofu(dir: &Path) -> (String, PathBuf) {
+    let script = dir.join("hanging-tofu");
+    let child_pid_file = dir.join("tofu-child.pid");
+    std::fs::write(
+        &script,
+        format!(
+            "#!/bin/sh\nsleep 600 &\necho $! > {}\nwait\n",
+            child_pid_file.display()
+        ),
+    )
+    .unwrap();
+    std::fs::set_permissions(&script, std::fs::Permissions::from_mode(0o755)).unwrap();
+
+    (script.display().to_string(), child_pid_file)
+}
+
+/// Puts the fixture SSH key pair where the test environment expects it
+///
+/// Rendering the `OpenTofu` templates reads the public key.
+fn install_ssh_keys(dir: &Path) {
+    std::fs::copy("fixtures/testing_rsa", dir.join("test_key")).unwrap();
+    std::fs::copy("fixtures/testing_rsa.pub", dir.join("test_key.pub")).unwrap();
+}
+
+/// Whether the process is still alive after giving a pending kill time to land
+///
+/// A killed process closes its pipes slightly before it becomes a zombie.
+fn is_still_running(pid: &str) -> bool {
+    let started = Instant::now();
+    while started.elapsed() < Duration::from_secs(5) {
+        let alive = std::fs::read_to_string(format!("/proc/{pid}/stat"))
+            .is_ok_and(|stat| !stat.contains(") Z "));
+        if !alive {
+            return false;
+        }
+        std::thread::sleep(Duration::from_millis(20));
+    }
+    true
+}
+
+#[tokio::test]
+async fn it_should_kill_a_hanging_opentofu_command_and_persist_provision_failed() {
+    let (environment, _data_dir, _build_dir, temp_dir) = EnvironmentTestBuilder::new()
+        .with_name("hanging-tofu-env")
+        .build_with_custom_paths();
+    let env_name = environment.name().clone();
+    let repository = Arc::new(FileEnvironmentRepository::new(temp_dir.path().join("data")));
+    repository.save(&environment.into_any()).unwrap();
+    install_ssh_keys(temp_dir.path());
+    let (hanging_tofu, child_pid_file) = write_hanging_tofu(temp_dir.path());
+
+    let handler = ProvisionCommandHandler::new(Arc::new(SystemClock), repository.clone())
+        .with_opentofu_executable(hanging_tofu)
+        .with_command_timeouts(CommandTimeouts {
+            opentofu_init: Duration::from_secs(1),
+            ..CommandTimeouts::default()
+        });
+
+    let started = Instant::now();
+    let result = handler.execute(&env_name, None).await;
+
+    assert!(started.elapsed() < Duration::from_secs(60));
+    assert!(matches!(
+        result,
+        Err(ProvisionCommandHandlerError::Command(
+            CommandError::TimedOut { .. }
+        ))
+    ));
+
+    let child_pid = std::fs::read_to_string(&child_pid_file).unwrap();
+    assert!(
+        !is_still_running(child_pid.trim()),
+        "processes spawned by the hanging command should have been killed"
+    );
+
+    let persisted = repository.load(&env_name).unwrap().unwrap();
+    let AnyEnvironmentState::ProvisionFailed(failed) = &persisted else {
+        panic!(
+            "expected ProvisionFailed state, got {}",
+            persisted.state_name()
+        );
+    };
+    let context = &failed.state().context;
+    assert_eq!(context.failed_step, ProvisionStep::OpenTofuInit);
+    assert_eq!(context.error_kind, ErrorKind::Timeout);
+    assert!(context.base.error_summary.contains("timed out after 1s"));
+}
+
+#[tokio::test]
+async fn it_should_cap_step_timeouts_with_the_overall_deadline() {
+    let (environment, _data_dir, _build_dir, temp_dir) = EnvironmentTestBuilder::new()
+        .with_name("deadline-env")
+        .build_with_custom_paths();
+    let env_name = environment.name().clone();
+    let repository = Arc::new(FileEnvironmentRepository::new(temp_dir.path().join("data")));
+    repository.save(&environment.into_any()).unwrap();
+    install_ssh_keys(temp_dir.path());
+    let (hanging_tofu, _child_pid_file) = write_hanging_tofu(temp_dir.path());
+
+    let handler = ProvisionCommandHandler::new(Arc::new(SystemClock), repository.clone())
+        .with_opentofu_executable(hanging_tofu)
+        .with_deadline(Duration::from_secs(1));
+
+    let started = Instant::now();
+    let result = handler.execute(&env_name, None).await;
+
+    assert!(started.elapsed() < Duration::from_secs(60));
+    assert!(matches!(
+        result,
+        Err(ProvisionCommandHandlerError::Command(
+            CommandError::TimedOut { .. }
+        ))
+    ));
+    assert!(matches!(
+        repository.load(&env_name).unwrap().unwrap(),
+        AnyEnvironmentState::ProvisionFailed(_)
+    ));
+}
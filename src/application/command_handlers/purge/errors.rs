@@ -15,6 +15,46 @@ pub enum PurgeCommandHandlerError {
         name: String,
     },
 
+    /// The environment may still have infrastructure and purge was not forced
+    #[error(
+        "Environment '{name}' is in state '{state}': its infrastructure may still exist
+Tip: Destroy it first, or purge with --destroy-first"
+    )]
+    EnvironmentNotDestroyed {
+        /// The name of the environment
+        name: String,
+        /// Display name of the current state
+        state: String,
+    },
+
+    /// The environment state could not be loaded to check that purging is safe
+    #[error("Failed to load environment '{name}' to check its state: {source}")]
+    EnvironmentLoadFailed {
+        /// The name of the environment
+        name: String,
+        /// The underlying repository error
+        #[source]
+        source: crate::domain::environment::repository::RepositoryError,
+    },
+
+    /// The environments could not be listed for a garbage collection
+    #[error("Failed to list the environments to garbage collect: {source}")]
+    EnvironmentListFailed {
+        /// The underlying repository error
+        #[source]
+        source: crate::domain::environment::repository::RepositoryError,
+    },
+
+    /// Failed to securely delete a generated SSH key
+    #[error("Failed to securely delete SSH key '{path}': {source}")]
+    SshKeyRemovalFailed {
+        /// Path to the key that couldn't be deleted
+        path: PathBuf,
+        /// The underlying I/O error
+        #[source]
+        source: std::io::Error,
+    },
+
     /// Failed to remove the data directory for the environment
     #[error("Failed to remove data directory at '{path}': {source}")]
     DataDirectoryRemovalFailed {
@@ -52,6 +92,23 @@ impl crate::shared::Traceable for PurgeCommandHandlerError {
             Self::EnvironmentNotFound { name } => {
                 format!("PurgeCommandHandlerError: Environment not found - {name}")
             }
+            Self::EnvironmentNotDestroyed { name, state } => {
+                format!(
+                    "PurgeCommandHandlerError: Environment '{name}' is not destroyed - state {state}"
+                )
+            }
+            Self::EnvironmentLoadFailed { name, source } => {
+                format!("PurgeCommandHandlerError: Failed to load environment '{name}' - {source}")
+            }
+            Self::EnvironmentListFailed { source } => {
+                format!("PurgeCommandHandlerError: Failed to list the environments - {source}")
+            }
+            Self::SshKeyRemovalFailed { path, source } => {
+                format!(
+                    "PurgeCommandHandlerError: Failed to securely delete SSH key '{}' - {source}",
+                    path.display()
+                )
+            }
             Self::DataDirectoryRemovalFailed { path, source } => {
                 format!(
                     "PurgeCommandHandlerError: Failed to remove data directory at '{}' - {source}",
@@ -80,10 +137,15 @@ impl crate::shared::Traceable for PurgeCommandHandlerError {
 
     fn error_kind(&self) -> ErrorKind {
         match self {
-            Self::EnvironmentNotFound { .. } => ErrorKind::Configuration,
-            Self::DataDirectoryRemovalFailed { .. } | Self::BuildDirectoryRemovalFailed { .. } => {
-                ErrorKind::FileSystem
+            Self::EnvironmentNotFound { .. } | Self::EnvironmentNotDestroyed { .. } => {
+                ErrorKind::Configuration
             }
+            Self::EnvironmentLoadFailed { .. } | Self::EnvironmentListFailed { .. } => {
+                ErrorKind::StatePersistence
+            }
+            Self::SshKeyRemovalFailed { .. }
+            | Self::DataDirectoryRemovalFailed { .. }
+            | Self::BuildDirectoryRemovalFailed { .. } => ErrorKind::FileSystem,
             Self::RepositoryRemovalFailed(_) => ErrorKind::StatePersistence,
         }
     }
@@ -129,6 +191,69 @@ Common causes:
 - Environment was already purged
 - Working in the wrong directory (check --working-dir)
 
+For more information, see docs/user-guide/commands.md"
+            }
+            Self::EnvironmentNotDestroyed { .. } => {
+                "Environment Not Destroyed - Troubleshooting:
+
+Purge only removes LOCAL data. The instance of this environment may still be
+running (and costing money), and without the local state the deployer can no
+longer destroy it.
+
+1. Destroy the infrastructure, then purge:
+   torrust-tracker-deployer destroy <env-name>
+   torrust-tracker-deployer purge <env-name>
+
+2. Or do both in one command:
+   torrust-tracker-deployer purge <env-name> --destroy-first
+
+3. If the infrastructure is already gone (e.g. removed in the provider
+   console), purge anyway:
+   torrust-tracker-deployer purge <env-name> --force
+
+For more information, see docs/user-guide/commands/purge.md"
+            }
+            Self::EnvironmentLoadFailed { .. } => {
+                "Environment Load Failed - Troubleshooting:
+
+The environment state could not be read, so purge cannot tell whether its
+infrastructure was destroyed.
+
+1. Check the state file:
+   cat data/<env-name>/environment.json
+
+2. If another command is running on this environment, wait for it to finish
+
+3. If the state file is corrupted and the infrastructure is gone, purge anyway:
+   torrust-tracker-deployer purge <env-name> --force
+
+For more information, see docs/user-guide/commands/purge.md"
+            }
+            Self::EnvironmentListFailed { .. } => {
+                "Environment List Failed - Troubleshooting:
+
+The data directory could not be read, so no environment was selected and
+nothing was purged.
+
+1. Check the permissions of the data directory:
+   ls -la data/
+
+2. Check that the working directory is the deployer workspace:
+   torrust-tracker-deployer list
+
+For more information, see docs/user-guide/commands/purge.md"
+            }
+            Self::SshKeyRemovalFailed { .. } => {
+                "SSH Key Removal Failed - Troubleshooting:
+
+1. Check the permissions of the generated keys:
+   ls -la data/<env-name>/ssh/
+
+2. The keys must be writable to be overwritten before deletion:
+   chmod u+w data/<env-name>/ssh/*
+
+3. Retry the purge. Nothing else has been removed yet.
+
 For more information, see docs/user-guide/commands.md"
             }
             Self::DataDirectoryRemovalFailed { .. } => {
@@ -0,0 +1,165 @@
+//! Garbage collection of old environments
+//!
+//! CI pipelines create an environment per run and destroy it at the end,
+//! which leaves the local data of every run behind. A garbage collection
+//! selects the environments that were destroyed (and, optionally, that
+//! failed) longer ago than a given age, and purges them in one go.
+//!
+//! The age is measured from the last state transition: when the environment
+//! was destroyed, or when its last command failed.
+
+use std::path::Path;
+use std::time::Duration;
+
+use chrono::{DateTime, TimeDelta, Utc};
+
+use crate::domain::EnvironmentName;
+
+/// Which environments a garbage collection selects
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct GcCriteria {
+    older_than: Duration,
+    include_failed: bool,
+}
+
+impl GcCriteria {
+    /// Select the environments destroyed at least `older_than` ago
+    #[must_use]
+    pub fn new(older_than: Duration) -> Self {
+        Self {
+            older_than,
+            include_failed: false,
+        }
+    }
+
+    /// Also select the environments whose last command failed at least
+    /// `older_than` ago
+    #[must_use]
+    pub fn with_failed(mut self, include_failed: bool) -> Self {
+        self.include_failed = include_failed;
+        self
+    }
+
+    /// Minimum time since the last state transition
+    #[must_use]
+    pub fn older_than(&self) -> Duration {
+        self.older_than
+    }
+
+    /// Whether environments in a failed state are selected
+    #[must_use]
+    pub fn includes_failed(&self) -> bool {
+        self.include_failed
+    }
+
+    /// Whether a transition at `since` is at least `older_than` before `now`
+    #[must_use]
+    pub fn is_old_enough(&self, since: DateTime<Utc>, now: DateTime<Utc>) -> bool {
+        now - since >= TimeDelta::from_std(self.older_than).unwrap_or(TimeDelta::MAX)
+    }
+}
+
+/// An environment selected by a garbage collection
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct GcCandidate {
+    /// Name of the environment
+    pub name: EnvironmentName,
+    /// Display name of its state (e.g. `Destroyed`)
+    pub state: String,
+    /// When it entered its state
+    pub since: DateTime<Utc>,
+    /// Size of its data and build directories, in bytes
+    pub size_bytes: u64,
+}
+
+/// An environment matching the age criteria that is not purged
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct GcSkipped {
+    /// Name of the environment
+    pub name: EnvironmentName,
+    /// Why it is left alone
+    pub reason: String,
+}
+
+/// A selected environment whose purge failed
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct GcFailure {
+    /// The environment that could not be purged
+    pub candidate: GcCandidate,
+    /// Why the purge failed
+    pub error: String,
+}
+
+/// Outcome of a garbage collection, or of its dry run
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct GcReport {
+    /// Whether nothing was removed
+    pub dry_run: bool,
+    /// Environments purged, or that would be purged by a dry run
+    pub purged: Vec<GcCandidate>,
+    /// Environments left alone, with the reason
+    pub skipped: Vec<GcSkipped>,
+    /// Environments whose purge failed
+    pub failed: Vec<GcFailure>,
+}
+
+impl GcReport {
+    /// Disk space freed by the purged environments, in bytes
+    #[must_use]
+    pub fn reclaimed_bytes(&self) -> u64 {
+        self.purged
+            .iter()
+            .map(|candidate| candidate.size_bytes)
+            .sum()
+    }
+}
+
+/// Total size of the files under `path`, in bytes
+///
+/// Symbolic links are not followed, and entries that cannot be read count
+/// as empty: the size is only reported to the user.
+pub(crate) fn directory_size(path: &Path) -> u64 {
+    let Ok(metadata) = std::fs::symlink_metadata(path) else {
+        return 0;
+    };
+
+    if !metadata.is_dir() {
+        return metadata.len();
+    }
+
+    std::fs::read_dir(path)
+        .map(|entries| {
+            entries
+                .flatten()
+                .map(|entry| directory_size(&entry.path()))
+                .sum()
+        })
+        .unwrap_or_default()
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::TimeZone;
+
+    use super::*;
+
+    #[test]
+    fn it_should_select_transitions_at_least_as_old_as_the_criteria() {
+        let criteria = GcCriteria::new(Duration::from_secs(14 * 24 * 60 * 60));
+        let now = Utc.with_ymd_and_hms(2026, 3, 15, 12, 0, 0).unwrap();
+
+        assert!(criteria.is_old_enough(now - TimeDelta::days(14), now));
+        assert!(!criteria.is_old_enough(now - TimeDelta::days(13), now));
+    }
+
+    #[test]
+    fn it_should_sum_the_size_of_nested_files() {
+        let dir = tempfile::tempdir().unwrap();
+        std::fs::create_dir_all(dir.path().join("nested")).unwrap();
+        std::fs::write(dir.path().join("a.txt"), vec![0; 100]).unwrap();
+        std::fs::write(dir.path().join("nested/b.txt"), vec![0; 24]).unwrap();
+
+        assert_eq!(directory_size(dir.path()), 124);
+        assert_eq!(directory_size(&dir.path().join("missing")), 0);
+    }
+}
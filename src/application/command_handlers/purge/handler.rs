@@ -6,21 +6,29 @@ use std::sync::Arc;
 use tracing::{info, instrument, warn};
 
 use super::errors::PurgeCommandHandlerError;
+use super::gc::{directory_size, GcCandidate, GcCriteria, GcFailure, GcReport, GcSkipped};
+use crate::adapters::ssh::securely_remove_key;
+use crate::application::command_handlers::common::DryRunReport;
 use crate::domain::environment::repository::EnvironmentRepository;
+use crate::domain::environment::state::AnyEnvironmentState;
+use crate::domain::environment::WorkspaceLayout;
 use crate::domain::EnvironmentName;
+use crate::shared::{Clock, SystemClock};
 
 /// `PurgeCommandHandler` orchestrates the removal of all local environment data
 ///
 /// This command handler removes all local files associated with an environment:
-/// 1. Removes the `data/{env-name}/` directory (environment state, configs, etc.)
+/// 1. Removes the `data/{env-name}/` directory (environment state, configs, etc.),
+///    overwriting the generated SSH keys in `data/{env-name}/ssh/` first
 /// 2. Removes the `build/{env-name}/` directory (generated templates, artifacts)
 /// 3. Removes the environment entry from the repository
 ///
 /// # State Management
 ///
 /// Unlike other commands, purge **does not transition environment state**:
-/// - Works on environments in any state
-/// - Removes all local data regardless of current state
+/// - Refuses environments that may still have infrastructure (any state other
+///   than `Created` or `Destroyed`) unless forced with [`Self::with_force`]
+/// - Removes all local data regardless of current state once allowed
 /// - Does not persist state after purge (the environment data is removed)
 ///
 /// # Idempotency
@@ -35,10 +43,13 @@ use crate::domain::EnvironmentName;
 ///
 /// - **Does NOT destroy infrastructure**: Only removes local files
 /// - **Irreversible operation**: All local environment data is permanently deleted
-/// - **Works in any state**: Can purge environments that are Created, Provisioned, Running, etc.
+/// - **Refuses live environments**: Purging a Running environment would leave its
+///   instance running with no local state to destroy it
 pub struct PurgeCommandHandler {
     repository: Arc<dyn EnvironmentRepository + Send + Sync>,
-    working_dir: PathBuf,
+    layout: WorkspaceLayout,
+    force: bool,
+    clock: Arc<dyn Clock>,
 }
 
 impl PurgeCommandHandler {
@@ -52,13 +63,46 @@ impl PurgeCommandHandler {
     pub fn new(
         repository: Arc<dyn EnvironmentRepository + Send + Sync>,
         working_dir: PathBuf,
+    ) -> Self {
+        Self::with_layout(repository, WorkspaceLayout::in_working_dir(working_dir))
+    }
+
+    /// Create a new `PurgeCommandHandler` with explicit data and build roots
+    ///
+    /// # Arguments
+    ///
+    /// * `repository` - Repository for accessing environment data
+    /// * `layout` - Roots of the per-environment data and build directories
+    #[must_use]
+    pub fn with_layout(
+        repository: Arc<dyn EnvironmentRepository + Send + Sync>,
+        layout: WorkspaceLayout,
     ) -> Self {
         Self {
             repository,
-            working_dir,
+            layout,
+            force: false,
+            clock: Arc::new(SystemClock),
         }
     }
 
+    /// Purge environments that may still have infrastructure
+    ///
+    /// Without it, only `Created` and `Destroyed` environments are purged.
+    /// Also allows purging an environment whose state cannot be loaded.
+    #[must_use]
+    pub fn with_force(mut self, force: bool) -> Self {
+        self.force = force;
+        self
+    }
+
+    /// Use `clock` to measure the age of environments in [`Self::gc`]
+    #[must_use]
+    pub fn with_clock(mut self, clock: Arc<dyn Clock>) -> Self {
+        self.clock = clock;
+        self
+    }
+
     /// Execute the complete purge workflow
     ///
     /// # Arguments
@@ -69,24 +113,28 @@ impl PurgeCommandHandler {
     ///
     /// Returns an error if:
     /// * Environment not found in repository
+    /// * Environment may still have infrastructure and purge is not forced
+    /// * Environment state cannot be loaded and purge is not forced
     /// * Unable to remove data directory due to permissions or I/O errors
     /// * Unable to remove build directory due to permissions or I/O errors
     /// * Unable to remove environment from repository
     ///
     /// If directories are already removed, the operation succeeds (idempotent).
     #[instrument(
-        name = "purge_command",
+        name = "command.purge",
         skip_all,
         fields(
             command_type = "purge",
-            environment = %env_name
+            environment_name = %env_name
         )
     )]
     pub fn execute(&self, env_name: &EnvironmentName) -> Result<(), PurgeCommandHandlerError> {
-        // Verify environment exists
-        self.verify_environment_exists(env_name)?;
+        let _lock = self.repository.lock(env_name)?;
+        // Verify environment exists and its infrastructure is gone
+        self.verify_safe_to_purge(env_name)?;
 
-        // Remove data directory
+        // Securely delete generated SSH keys, then the rest of the data directory
+        self.remove_generated_ssh_keys(env_name)?;
         self.remove_data_directory(env_name)?;
 
         // Remove build directory
@@ -104,28 +152,258 @@ impl PurgeCommandHandler {
         Ok(())
     }
 
-    /// Verify environment exists in repository
-    fn verify_environment_exists(
+    /// Simulate the purge workflow without deleting anything
+    ///
+    /// Reports the local directories that `execute` would remove. Directories
+    /// that do not exist are left out of the report.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the environment is not found in the repository, or
+    /// if `execute` would refuse to purge it.
+    #[instrument(
+        name = "command.purge_dry_run",
+        skip_all,
+        fields(
+            command_type = "purge",
+            environment_name = %env_name
+        )
+    )]
+    pub fn dry_run(
+        &self,
+        env_name: &EnvironmentName,
+    ) -> Result<DryRunReport, PurgeCommandHandlerError> {
+        self.verify_safe_to_purge(env_name)?;
+
+        let report = [
+            self.layout.environment_data_dir(env_name),
+            self.layout.environment_build_dir(env_name),
+        ]
+        .into_iter()
+        .filter(|path| path.exists())
+        .fold(DryRunReport::new(), DryRunReport::with_removed_path)
+        .with_note("The environment entry would be removed from the repository");
+
+        Ok(report)
+    }
+
+    /// Purge every environment selected by `criteria`
+    ///
+    /// Environments old enough that cannot be purged safely are reported as
+    /// skipped. A failed purge is reported and does not stop the others.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the environments cannot be listed.
+    #[instrument(
+        name = "command.purge_gc",
+        skip_all,
+        fields(command_type = "purge", older_than_secs = criteria.older_than().as_secs())
+    )]
+    pub fn gc(&self, criteria: GcCriteria) -> Result<GcReport, PurgeCommandHandlerError> {
+        let (candidates, skipped) = self.select_for_gc(criteria)?;
+
+        let mut report = GcReport {
+            skipped,
+            ..GcReport::default()
+        };
+        for candidate in candidates {
+            match self.execute(&candidate.name) {
+                Ok(()) => report.purged.push(candidate),
+                Err(e) => report.failed.push(GcFailure {
+                    candidate,
+                    error: e.to_string(),
+                }),
+            }
+        }
+
+        info!(
+            command = "purge",
+            purged = report.purged.len(),
+            skipped = report.skipped.len(),
+            failed = report.failed.len(),
+            reclaimed_bytes = report.reclaimed_bytes(),
+            "Garbage collection completed"
+        );
+
+        Ok(report)
+    }
+
+    /// Report the environments [`Self::gc`] would purge, without deleting
+    /// anything
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the environments cannot be listed.
+    #[instrument(
+        name = "command.purge_gc_dry_run",
+        skip_all,
+        fields(command_type = "purge", older_than_secs = criteria.older_than().as_secs())
+    )]
+    pub fn gc_dry_run(&self, criteria: GcCriteria) -> Result<GcReport, PurgeCommandHandlerError> {
+        let (purged, skipped) = self.select_for_gc(criteria)?;
+
+        Ok(GcReport {
+            dry_run: true,
+            purged,
+            skipped,
+            failed: Vec::new(),
+        })
+    }
+
+    /// Select the environments a garbage collection purges, in name order
+    ///
+    /// Returns the candidates, and the environments left alone although they
+    /// may be old enough: their state cannot be loaded, their destruction
+    /// time was not recorded, or they may still have infrastructure and
+    /// purge is not forced.
+    fn select_for_gc(
+        &self,
+        criteria: GcCriteria,
+    ) -> Result<(Vec<GcCandidate>, Vec<GcSkipped>), PurgeCommandHandlerError> {
+        let now = self.clock.now();
+        let names = self
+            .repository
+            .list()
+            .map_err(|source| PurgeCommandHandlerError::EnvironmentListFailed { source })?;
+
+        let mut candidates = Vec::new();
+        let mut skipped = Vec::new();
+
+        for name in names {
+            let environment = match self.repository.load(&name) {
+                Ok(Some(environment)) => environment,
+                Ok(None) => continue,
+                Err(e) => {
+                    skipped.push(GcSkipped {
+                        name,
+                        reason: format!("state cannot be loaded: {e}"),
+                    });
+                    continue;
+                }
+            };
+
+            let selectable = matches!(environment, AnyEnvironmentState::Destroyed(_))
+                || (criteria.includes_failed() && environment.is_error_state());
+            if !selectable {
+                continue;
+            }
+
+            let Some(since) = environment.last_transition_at() else {
+                skipped.push(GcSkipped {
+                    name,
+                    reason: "destruction time not recorded (destroyed by an older version)"
+                        .to_string(),
+                });
+                continue;
+            };
+
+            if !criteria.is_old_enough(since, now) {
+                continue;
+            }
+
+            if environment.may_have_infrastructure() && !self.force {
+                skipped.push(GcSkipped {
+                    name,
+                    reason: format!(
+                        "in state '{}': its infrastructure may still exist (use --force)",
+                        environment.state_display_name()
+                    ),
+                });
+                continue;
+            }
+
+            let size_bytes = directory_size(&self.layout.environment_data_dir(&name))
+                + directory_size(&self.layout.environment_build_dir(&name));
+            candidates.push(GcCandidate {
+                state: environment.state_display_name().to_string(),
+                name,
+                since,
+                size_bytes,
+            });
+        }
+
+        Ok((candidates, skipped))
+    }
+
+    /// Verify the environment exists and may be purged
+    ///
+    /// Purging an environment that may still have infrastructure would lose
+    /// the only state able to destroy it, so it needs `force`. A state that
+    /// cannot be loaded (e.g. a corrupted file) is only purged when forced.
+    fn verify_safe_to_purge(
         &self,
         env_name: &EnvironmentName,
     ) -> Result<(), PurgeCommandHandlerError> {
-        match self.repository.exists(env_name) {
-            Ok(true) => Ok(()),
-            Ok(false) => Err(PurgeCommandHandlerError::EnvironmentNotFound {
+        match self.repository.load(env_name) {
+            Ok(Some(environment)) if environment.may_have_infrastructure() => {
+                if !self.force {
+                    return Err(PurgeCommandHandlerError::EnvironmentNotDestroyed {
+                        name: env_name.to_string(),
+                        state: environment.state_display_name().to_string(),
+                    });
+                }
+
+                warn!(
+                    command = "purge",
+                    environment = %env_name,
+                    state = environment.state_name(),
+                    "Purge forced: the infrastructure of the environment may still exist"
+                );
+                Ok(())
+            }
+            Ok(Some(_)) => Ok(()),
+            Ok(None) => Err(PurgeCommandHandlerError::EnvironmentNotFound {
                 name: env_name.to_string(),
             }),
-            Err(e) => {
+            Err(e) if self.force => {
                 warn!(
                     command = "purge",
                     environment = %env_name,
                     error = %e,
-                    "Failed to check if environment exists, proceeding anyway"
+                    "Failed to load environment, purge forced: proceeding anyway"
                 );
-                // Don't fail the purge if we can't check existence
-                // The user may be trying to clean up a corrupted environment
                 Ok(())
             }
+            Err(source) => Err(PurgeCommandHandlerError::EnvironmentLoadFailed {
+                name: env_name.to_string(),
+                source,
+            }),
+        }
+    }
+
+    /// Securely delete the SSH keys generated for the environment
+    ///
+    /// Plain removal of the data directory would leave the private key
+    /// content on disk, so every file in `data/{env-name}/ssh/` is overwritten
+    /// before it is deleted.
+    fn remove_generated_ssh_keys(
+        &self,
+        env_name: &EnvironmentName,
+    ) -> Result<(), PurgeCommandHandlerError> {
+        let ssh_dir = self.layout.environment_ssh_dir(env_name);
+
+        let Ok(entries) = std::fs::read_dir(&ssh_dir) else {
+            return Ok(());
+        };
+
+        for path in entries.flatten().map(|entry| entry.path()) {
+            if !path.is_file() {
+                continue;
+            }
+
+            info!(
+                command = "purge",
+                environment = %env_name,
+                path = %path.display(),
+                "Securely deleting generated SSH key"
+            );
+
+            securely_remove_key(&path)
+                .map_err(|source| PurgeCommandHandlerError::SshKeyRemovalFailed { path, source })?;
         }
+
+        Ok(())
     }
 
     /// Remove the data directory for the environment
@@ -133,7 +411,7 @@ impl PurgeCommandHandler {
         &self,
         env_name: &EnvironmentName,
     ) -> Result<(), PurgeCommandHandlerError> {
-        let data_dir = self.working_dir.join("data").join(env_name.as_str());
+        let data_dir = self.layout.environment_data_dir(env_name);
 
         if !data_dir.exists() {
             info!(
@@ -167,7 +445,7 @@ impl PurgeCommandHandler {
         &self,
         env_name: &EnvironmentName,
     ) -> Result<(), PurgeCommandHandlerError> {
-        let build_dir = self.working_dir.join("build").join(env_name.as_str());
+        let build_dir = self.layout.environment_build_dir(env_name);
 
         if !build_dir.exists() {
             info!(
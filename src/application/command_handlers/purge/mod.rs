@@ -28,6 +28,13 @@
 //! 2. **Remove data directory** - Delete `data/{env-name}/` including all environment state
 //! 3. **Remove build directory** - Delete `build/{env-name}/` including generated templates
 //!
+//! ## Garbage Collection
+//!
+//! `gc` purges, in one go, every environment destroyed (and optionally failed)
+//! longer ago than a given age, and reports the reclaimed disk space. It keeps
+//! the workspaces of CI pipelines, which create an environment per run, from
+//! growing without bounds. See the [`gc`] module.
+//!
 //! ## State Management
 //!
 //! Unlike other commands, purge **does not transition environment state**:
@@ -62,6 +69,7 @@
 //! - **No state preservation**: The environment entry is removed from the repository
 
 pub mod errors;
+pub mod gc;
 pub mod handler;
 
 #[cfg(test)]
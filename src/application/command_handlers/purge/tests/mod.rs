@@ -7,3 +7,226 @@
 //! - Test purge when build directory doesn't exist (idempotency)
 //! - Test purge with permission errors
 //! - Test repository removal after purge
+
+use std::net::{IpAddr, Ipv4Addr};
+use std::path::Path;
+use std::sync::Arc;
+use std::time::Duration;
+
+use chrono::Utc;
+use tempfile::TempDir;
+
+use crate::application::command_handlers::create::config::EnvironmentCreationConfig;
+use crate::application::command_handlers::purge::errors::PurgeCommandHandlerError;
+use crate::application::command_handlers::purge::gc::GcCriteria;
+use crate::application::command_handlers::purge::handler::PurgeCommandHandler;
+use crate::application::command_handlers::{CreateCommandHandler, DestroyCommandHandler};
+use crate::domain::environment::repository::EnvironmentRepository;
+use crate::domain::environment::state::{BaseFailureContext, DestroyFailureContext, DestroyStep};
+use crate::domain::environment::TraceId;
+use crate::domain::EnvironmentName;
+use crate::infrastructure::persistence::file_repository_factory::FileRepositoryFactory;
+use crate::shared::{ErrorKind, SystemClock};
+use crate::testing::MockClock;
+
+/// Create an imported environment in the `Running` state
+///
+/// Imported environments are not destroyed with `OpenTofu`, so the destroy
+/// handler can run without infrastructure.
+fn create_running_environment(
+    repository: &Arc<dyn EnvironmentRepository + Send + Sync>,
+    working_dir: &Path,
+    name: &str,
+) -> EnvironmentName {
+    let config = EnvironmentCreationConfig::builder()
+        .name(name)
+        .generate_ssh_keys()
+        .provider_lxd(format!("lxd-{name}"))
+        .sqlite("tracker.db")
+        .api("0.0.0.0:1212", "MyAccessToken")
+        .build()
+        .unwrap();
+    let environment = CreateCommandHandler::new(repository.clone(), Arc::new(SystemClock))
+        .execute(config, working_dir)
+        .unwrap();
+
+    let running = environment
+        .import(IpAddr::V4(Ipv4Addr::new(10, 0, 0, 1)))
+        .start_configuring()
+        .configured()
+        .start_releasing()
+        .released()
+        .start_running()
+        .into_any();
+    repository.save(&running).unwrap();
+
+    EnvironmentName::new(name).unwrap()
+}
+
+#[test]
+fn it_should_delete_the_generated_ssh_keys_with_the_data_directory() {
+    // Arrange
+    let temp_dir = TempDir::new().unwrap();
+    let repository =
+        FileRepositoryFactory::new(Duration::from_secs(30)).create(temp_dir.path().join("data"));
+    let config = EnvironmentCreationConfig::builder()
+        .name("purge-keys")
+        .generate_ssh_keys()
+        .provider_lxd("lxd-purge-keys")
+        .sqlite("tracker.db")
+        .api("0.0.0.0:1212", "MyAccessToken")
+        .build()
+        .unwrap();
+    let environment = CreateCommandHandler::new(repository.clone(), Arc::new(SystemClock))
+        .execute(config, temp_dir.path())
+        .unwrap();
+    let private_key = environment.ssh_credentials().ssh_priv_key_path.clone();
+    assert!(private_key.exists());
+
+    // Act
+    PurgeCommandHandler::new(repository, temp_dir.path().to_path_buf())
+        .execute(&EnvironmentName::new("purge-keys").unwrap())
+        .unwrap();
+
+    // Assert
+    assert!(!private_key.exists());
+    assert!(!temp_dir.path().join("data/purge-keys").exists());
+}
+
+#[test]
+fn it_should_refuse_to_purge_an_environment_that_may_have_infrastructure() {
+    // Arrange
+    let temp_dir = TempDir::new().unwrap();
+    let repository =
+        FileRepositoryFactory::new(Duration::from_secs(30)).create(temp_dir.path().join("data"));
+    let env_name = create_running_environment(&repository, temp_dir.path(), "purge-running");
+
+    // Act
+    let result =
+        PurgeCommandHandler::new(repository, temp_dir.path().to_path_buf()).execute(&env_name);
+
+    // Assert
+    assert!(matches!(
+        result,
+        Err(PurgeCommandHandlerError::EnvironmentNotDestroyed { ref state, .. }) if state == "Running"
+    ));
+    assert!(temp_dir.path().join("data/purge-running").exists());
+}
+
+#[test]
+fn it_should_purge_an_environment_that_may_have_infrastructure_when_forced() {
+    // Arrange
+    let temp_dir = TempDir::new().unwrap();
+    let repository =
+        FileRepositoryFactory::new(Duration::from_secs(30)).create(temp_dir.path().join("data"));
+    let env_name = create_running_environment(&repository, temp_dir.path(), "purge-forced");
+
+    // Act
+    PurgeCommandHandler::new(repository, temp_dir.path().to_path_buf())
+        .with_force(true)
+        .execute(&env_name)
+        .unwrap();
+
+    // Assert
+    assert!(!temp_dir.path().join("data/purge-forced").exists());
+}
+
+#[test]
+fn it_should_purge_an_environment_once_it_has_been_destroyed() {
+    // Arrange
+    let temp_dir = TempDir::new().unwrap();
+    let repository =
+        FileRepositoryFactory::new(Duration::from_secs(30)).create(temp_dir.path().join("data"));
+    let env_name = create_running_environment(&repository, temp_dir.path(), "purge-destroyed");
+
+    // Act
+    DestroyCommandHandler::new(repository.clone(), Arc::new(SystemClock))
+        .execute(&env_name, None)
+        .unwrap();
+    PurgeCommandHandler::new(repository, temp_dir.path().to_path_buf())
+        .execute(&env_name)
+        .unwrap();
+
+    // Assert
+    assert!(!temp_dir.path().join("data/purge-destroyed").exists());
+}
+
+#[test]
+fn it_should_garbage_collect_only_environments_destroyed_long_enough_ago() {
+    // Arrange
+    let temp_dir = TempDir::new().unwrap();
+    let repository =
+        FileRepositoryFactory::new(Duration::from_secs(30)).create(temp_dir.path().join("data"));
+    let clock = Arc::new(MockClock::new(Utc::now()));
+    let old = create_running_environment(&repository, temp_dir.path(), "gc-old");
+    let recent = create_running_environment(&repository, temp_dir.path(), "gc-recent");
+    let running = create_running_environment(&repository, temp_dir.path(), "gc-running");
+    let destroy = DestroyCommandHandler::new(repository.clone(), clock.clone());
+    destroy.execute(&old, None).unwrap();
+    clock.advance(chrono::Duration::days(10));
+    destroy.execute(&recent, None).unwrap();
+    clock.advance(chrono::Duration::days(5));
+    let handler =
+        PurgeCommandHandler::new(repository, temp_dir.path().to_path_buf()).with_clock(clock);
+    let criteria = GcCriteria::new(Duration::from_secs(14 * 24 * 60 * 60));
+
+    // Act
+    let dry_run = handler.gc_dry_run(criteria).unwrap();
+    let report = handler.gc(criteria).unwrap();
+
+    // Assert
+    assert!(dry_run.dry_run);
+    assert_eq!(dry_run.purged, report.purged);
+    assert_eq!(report.purged.len(), 1);
+    assert_eq!(report.purged[0].name, old);
+    assert!(report.reclaimed_bytes() > 0);
+    assert!(!temp_dir.path().join("data/gc-old").exists());
+    assert!(temp_dir.path().join("data").join(recent.as_str()).exists());
+    assert!(temp_dir.path().join("data").join(running.as_str()).exists());
+}
+
+#[test]
+fn it_should_skip_failed_environments_unless_forced_when_they_are_included() {
+    // Arrange
+    let temp_dir = TempDir::new().unwrap();
+    let repository =
+        FileRepositoryFactory::new(Duration::from_secs(30)).create(temp_dir.path().join("data"));
+    let env_name = create_running_environment(&repository, temp_dir.path(), "gc-failed");
+    let running = repository
+        .load(&env_name)
+        .unwrap()
+        .unwrap()
+        .try_into_running()
+        .unwrap();
+    let failed = running
+        .start_destroying()
+        .destroy_failed(DestroyFailureContext {
+            failed_step: DestroyStep::DestroyInfrastructure,
+            error_kind: ErrorKind::InfrastructureOperation,
+            base: BaseFailureContext {
+                error_summary: "tofu destroy failed".to_string(),
+                failed_at: Utc::now() - chrono::Duration::days(30),
+                execution_started_at: Utc::now() - chrono::Duration::days(30),
+                execution_duration: Duration::from_secs(1),
+                trace_id: TraceId::new(),
+                trace_file_path: None,
+            },
+        });
+    repository.save(&failed.into_any()).unwrap();
+    let criteria = GcCriteria::new(Duration::from_secs(14 * 24 * 60 * 60)).with_failed(true);
+
+    // Act
+    let refused = PurgeCommandHandler::new(repository.clone(), temp_dir.path().to_path_buf())
+        .gc(criteria)
+        .unwrap();
+    let forced = PurgeCommandHandler::new(repository, temp_dir.path().to_path_buf())
+        .with_force(true)
+        .gc(criteria)
+        .unwrap();
+
+    // Assert
+    assert!(refused.purged.is_empty());
+    assert_eq!(refused.skipped.len(), 1);
+    assert_eq!(forced.purged.len(), 1);
+    assert!(!temp_dir.path().join("data/gc-failed").exists());
+}
@@ -2,6 +2,7 @@
 
 use std::net::IpAddr;
 
+use crate::application::errors::AllowedCommands;
 use crate::domain::environment::name::EnvironmentName;
 use crate::domain::environment::repository::RepositoryError;
 use crate::domain::environment::state::StateTypeError;
@@ -18,12 +19,14 @@ pub enum RegisterCommandHandlerError {
     },
 
     /// Environment is not in the expected Created state
-    #[error("Environment '{name}' is not in Created state (current: {current_state})")]
+    #[error("Environment '{name}' is not in Created state (current: {current_state}); {allowed_commands}")]
     InvalidState {
         /// The name of the environment
         name: EnvironmentName,
         /// The actual state of the environment
         current_state: String,
+        /// The commands allowed in the actual state
+        allowed_commands: AllowedCommands,
     },
 
     /// Failed to connect to the instance via SSH
@@ -67,6 +70,7 @@ impl Traceable for RegisterCommandHandlerError {
             Self::InvalidState {
                 name,
                 current_state,
+                ..
             } => {
                 format!(
                     "RegisterCommandHandlerError: Environment '{name}' is not in Created state (current: {current_state})"
@@ -273,6 +277,7 @@ mod tests {
             let error = RegisterCommandHandlerError::InvalidState {
                 name,
                 current_state: "Provisioned".to_string(),
+                allowed_commands: AllowedCommands::default(),
             };
 
             assert!(error.to_string().contains("test-env"));
@@ -303,6 +308,7 @@ mod tests {
                 RegisterCommandHandlerError::InvalidState {
                     name,
                     current_state: "Provisioned".to_string(),
+                    allowed_commands: AllowedCommands::default(),
                 },
                 RegisterCommandHandlerError::InvalidIpAddress {
                     value: "bad".to_string(),
@@ -311,6 +317,8 @@ mod tests {
                 RegisterCommandHandlerError::StateTransition(StateTypeError::UnexpectedState {
                     expected: "Created",
                     actual: "Provisioned".to_string(),
+                    allowed_commands: &[],
+                    suggested_next: None,
                 }),
             ];
 
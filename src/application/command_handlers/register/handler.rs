@@ -6,8 +6,10 @@ use std::sync::Arc;
 use tracing::{info, instrument};
 
 use super::errors::RegisterCommandHandlerError;
-use crate::adapters::ssh::{SshClient, SshConfig};
+use crate::adapters::ssh::{KnownHostsFile, SshClient, SshConfig};
+use crate::application::errors::InvalidStateError;
 use crate::application::services::rendering::AnsibleTemplateRenderingService;
+use crate::application::steps::RecordHostKeysStep;
 use crate::domain::environment::repository::{EnvironmentRepository, TypedEnvironmentRepository};
 use crate::domain::environment::state::{Created, Provisioned};
 use crate::domain::environment::Environment;
@@ -74,11 +76,11 @@ impl RegisterCommandHandler {
     /// * Ansible template rendering fails
     /// * Unable to persist the environment state
     #[instrument(
-        name = "register_command",
+        name = "command.register",
         skip_all,
         fields(
             command_type = "register",
-            environment = %env_name,
+            environment_name = %env_name,
             instance_ip = %instance_ip,
             ssh_port = ?ssh_port
         )
@@ -89,6 +91,7 @@ impl RegisterCommandHandler {
         instance_ip: IpAddr,
         ssh_port: Option<u16>,
     ) -> Result<Environment<Provisioned>, RegisterCommandHandlerError> {
+        let _lock = self.repository.inner().lock(env_name)?;
         let environment = self.load_created_environment(env_name)?;
 
         self.validate_ssh_connectivity(&environment, instance_ip, ssh_port)?;
@@ -143,7 +146,7 @@ impl RegisterCommandHandler {
 
         let ssh_socket_addr = SocketAddr::new(instance_ip, effective_ssh_port);
         let ssh_config = SshConfig::new(ssh_credentials.clone(), ssh_socket_addr);
-        let ssh_client = SshClient::new(ssh_config);
+        let ssh_client = SshClient::new(ssh_config.clone());
 
         let connected = ssh_client.test_connectivity().map_err(|source| {
             RegisterCommandHandlerError::ConnectivityFailed {
@@ -159,6 +162,19 @@ impl RegisterCommandHandler {
             });
         }
 
+        // Record the host keys of the registered instance, replacing the ones
+        // of a previous registration, so later commands check them
+        RecordHostKeysStep::new(
+            ssh_config,
+            KnownHostsFile::new(environment.ssh_known_hosts_path()),
+        )
+        .with_rotation()
+        .execute()
+        .map_err(|source| RegisterCommandHandlerError::ConnectivityFailed {
+            address: instance_ip,
+            reason: source.to_string(),
+        })?;
+
         info!(
             instance_ip = %instance_ip,
             ssh_port = effective_ssh_port,
@@ -190,9 +206,12 @@ impl RegisterCommandHandler {
     ) -> Result<(), RegisterCommandHandlerError> {
         let ansible_template_service = AnsibleTemplateRenderingService::from_paths(
             environment.templates_dir(),
+            environment.templates_override_dir(),
+            environment.extra_template_variables().clone(),
             environment.build_dir().clone(),
             self.clock.clone(),
-        );
+        )
+        .with_known_hosts_file(environment.ssh_known_hosts_path());
 
         ansible_template_service
             .render_templates(
@@ -230,12 +249,18 @@ impl RegisterCommandHandler {
             name: env_name.clone(),
         })?;
 
-        any_env
-            .try_into_created()
-            .map_err(|e| RegisterCommandHandlerError::InvalidState {
+        any_env.try_into_created().map_err(|e| {
+            let InvalidStateError {
+                actual,
+                allowed_commands,
+                ..
+            } = e.into();
+            RegisterCommandHandlerError::InvalidState {
                 name: env_name.clone(),
-                current_state: e.to_string(),
-            })
+                current_state: actual,
+                allowed_commands,
+            }
+        })
     }
 }
 
@@ -24,7 +24,10 @@
 //! **Preferred pattern**: In cases where there are fewer, well-defined error sources,
 //! prefer using concrete types with `#[source]` for better type safety and traceability.
 
+use crate::adapters::ssh::SshError;
+use crate::application::command_handlers::common::ConfigOverrideError;
 use crate::application::errors::{InvalidStateError, PersistenceError, ReleaseWorkflowStep};
+use crate::application::steps::DiskSpaceCheckError;
 use crate::shared::error::{ErrorKind, Traceable};
 
 /// Type alias for boxed step errors to reduce verbosity
@@ -54,6 +57,18 @@ pub enum ReleaseCommandHandlerError {
         name: String,
     },
 
+    /// The host keys of the instance could not be checked or have changed
+    #[error("SSH host key check failed: {0}")]
+    HostKeyCheck(#[from] SshError),
+
+    /// Not enough disk space is free on the instance for the release
+    #[error("Disk space check failed: {0}")]
+    DiskSpace(#[from] DiskSpaceCheckError),
+
+    /// The configuration override could not be applied
+    #[error("{0}")]
+    ConfigOverride(#[from] ConfigOverrideError),
+
     /// Environment is in an invalid state for release
     #[error("Environment is in an invalid state for release: {0}")]
     InvalidState(#[from] InvalidStateError),
@@ -220,6 +235,29 @@ pub enum ReleaseCommandHandlerError {
         source: BoxedStepError,
     },
 
+    /// Container image pull on the instance failed
+    #[error("Container image pull failed: {message}")]
+    ContainerImagePull {
+        /// Description of the failure
+        message: String,
+        /// The underlying error from the pull step
+        #[source]
+        source: BoxedStepError,
+    },
+
+    /// A pulled image does not have the digest it is pinned to
+    #[error(
+        "Image of service '{service}' does not match its pinned digest: expected {expected}, pulled {actual}"
+    )]
+    ImageDigestMismatch {
+        /// The service running the image
+        service: String,
+        /// The pinned digest
+        expected: String,
+        /// The digests of the pulled image
+        actual: String,
+    },
+
     /// Release operation failed
     #[error("Release operation failed for environment '{name}': {message}")]
     ReleaseOperationFailed {
@@ -251,6 +289,15 @@ impl Traceable for ReleaseCommandHandlerError {
             Self::MissingInstanceIp { name } => {
                 format!("ReleaseCommandHandlerError: Instance IP not available for environment '{name}'")
             }
+            Self::HostKeyCheck(e) => {
+                format!("ReleaseCommandHandlerError: SSH host key check failed - {e}")
+            }
+            Self::DiskSpace(e) => {
+                format!("ReleaseCommandHandlerError: Disk space check failed - {e}")
+            }
+            Self::ConfigOverride(e) => {
+                format!("ReleaseCommandHandlerError: Configuration override failed - {e}")
+            }
             Self::InvalidState(e) => {
                 format!("ReleaseCommandHandlerError: Invalid state for release - {e}")
             }
@@ -314,6 +361,18 @@ impl Traceable for ReleaseCommandHandlerError {
             Self::ComposeFilesDeployment { message, .. } => {
                 format!("ReleaseCommandHandlerError: Docker Compose deployment failed - {message}")
             }
+            Self::ContainerImagePull { message, .. } => {
+                format!("ReleaseCommandHandlerError: Container image pull failed - {message}")
+            }
+            Self::ImageDigestMismatch {
+                service,
+                expected,
+                actual,
+            } => {
+                format!(
+                    "ReleaseCommandHandlerError: Image digest mismatch for '{service}' - expected {expected}, pulled {actual}"
+                )
+            }
             Self::ReleaseOperationFailed { name, message } => {
                 format!(
                     "ReleaseCommandHandlerError: Release operation failed for '{name}' - {message}"
@@ -327,6 +386,9 @@ impl Traceable for ReleaseCommandHandlerError {
         // step-related errors. The error message is preserved via `to_string()`
         // and the trace file captures full context for debugging.
         match self {
+            Self::HostKeyCheck(e) => Some(e),
+            Self::DiskSpace(e) => Some(e),
+            Self::ConfigOverride(e) => Some(e),
             Self::EnvironmentNotFound { .. }
             | Self::MissingInstanceIp { .. }
             | Self::InvalidState(_)
@@ -346,6 +408,8 @@ impl Traceable for ReleaseCommandHandlerError {
             | Self::GrafanaProvisioningDeployment { .. }
             | Self::PrometheusConfigDeployment { .. }
             | Self::ComposeFilesDeployment { .. }
+            | Self::ContainerImagePull { .. }
+            | Self::ImageDigestMismatch { .. }
             | Self::ReleaseOperationFailed { .. } => None,
         }
     }
@@ -354,7 +418,11 @@ impl Traceable for ReleaseCommandHandlerError {
         match self {
             Self::EnvironmentNotFound { .. }
             | Self::MissingInstanceIp { .. }
-            | Self::InvalidState(_) => ErrorKind::Configuration,
+            | Self::InvalidState(_)
+            | Self::ImageDigestMismatch { .. } => ErrorKind::Configuration,
+            Self::HostKeyCheck(e) => e.error_kind(),
+            Self::DiskSpace(e) => e.error_kind(),
+            Self::ConfigOverride(e) => e.error_kind(),
             Self::StatePersistence(_) => ErrorKind::StatePersistence,
             Self::TemplateRendering { .. } => ErrorKind::TemplateRendering,
             Self::TrackerStorageCreation { .. }
@@ -371,6 +439,7 @@ impl Traceable for ReleaseCommandHandlerError {
             | Self::GrafanaProvisioningDeployment { .. }
             | Self::PrometheusConfigDeployment { .. }
             | Self::ComposeFilesDeployment { .. }
+            | Self::ContainerImagePull { .. }
             | Self::ReleaseOperationFailed { .. } => ErrorKind::InfrastructureOperation,
         }
     }
@@ -435,6 +504,28 @@ State progression for release:
    Created → Provisioned → Configured → Released
 
 For more information, see docs/user-guide/commands.md"
+            }
+            Self::HostKeyCheck(e) => e.help(),
+            Self::ConfigOverride(e) => e.help(),
+            Self::DiskSpace(_) => {
+                "Insufficient Disk Space - Troubleshooting:
+
+The release was stopped before writing to the instance. The error names the
+partition of the instance holding the Docker images.
+
+1. Check the free space of the instance:
+   torrust-tracker-deployer ssh <env-name> -- df -h /var/lib/docker
+
+2. Remove unused Docker images, containers and build cache:
+   torrust-tracker-deployer ssh <env-name> -- docker system prune
+
+3. If the disk is too small, grow it with your provider, or destroy the
+   environment and provision it again with a larger 'instance.disk_gb'
+
+4. Release again once enough space is free
+
+The threshold is set in 'min_free_disk_space' of deployer-workspace.json.
+For more information, see docs/user-guide/commands/release.md"
             }
             Self::MissingInstanceIp { .. } => {
                 "Missing Instance IP Address - Troubleshooting:
@@ -720,19 +811,20 @@ For more information, see docs/user-guide/commands.md"
 2. Check that the tracker configuration was generated in the build directory:
    ls build/<env-name>/tracker/
 
-3. Verify the Ansible playbook exists:
-   ls templates/ansible/deploy-tracker-config.yml
+3. Check that the SSH user owns the tracker configuration directory:
+   ssh <user>@<instance-ip> 'ls -la /opt/torrust/storage/tracker/'
 
 4. Check that the instance has sufficient disk space:
    df -h
 
 5. Review the error message above for specific details
 
+Running release again resumes an interrupted upload.
+
 Common causes:
 - Configuration files not generated
 - Insufficient disk space on target instance
 - Permission denied on target directories
-- Ansible playbook not found
 - Network connectivity issues
 
 For more information, see docs/user-guide/commands.md"
@@ -812,6 +904,52 @@ Common causes:
 - Disk space issues on target instance
 
 For more information, see docs/user-guide/commands.md"
+            }
+            Self::ContainerImagePull { .. } => {
+                "Container Image Pull Failed - Troubleshooting:
+
+1. Check that the instance can reach the registry:
+   ssh <user>@<instance-ip> 'curl -sI https://<registry>/v2/'
+
+2. If the images come from a private registry, verify the 'release.registry'
+   server, username and password of the environment configuration
+
+3. Verify every image in 'release.images' exists in the registry:
+   docker manifest inspect <image>
+
+4. Review the error message above for specific details
+
+Common causes:
+- Wrong registry credentials or expired access token
+- Image or tag missing in the registry
+- Registry not reachable from the instance (firewall, DNS)
+- Docker Hub rate limits
+
+Fix the configuration or the registry and run the release again.
+
+For more information, see docs/user-guide/commands/release.md"
+            }
+            Self::ImageDigestMismatch { .. } => {
+                "Image Digest Mismatch - Troubleshooting:
+
+The registry served an image whose digest differs from the digest pinned
+in 'release.images', so no container was restarted with it.
+
+1. Compare the pinned digest with the one in the registry:
+   docker buildx imagetools inspect <image>
+
+2. If the image was rebuilt or re-pushed on purpose, pin the new digest in
+   the environment configuration
+
+3. If it was not, the registry content changed unexpectedly: investigate
+   before updating the pin
+
+Common causes:
+- Tag re-pushed after the digest was pinned
+- Digest copied from another platform or another image
+- Wrong registry or repository in the image reference
+
+For more information, see docs/user-guide/commands/release.md"
             }
             Self::ReleaseOperationFailed { .. } => {
                 "Release Operation Failed - Troubleshooting:
@@ -842,6 +980,7 @@ For more information, see docs/user-guide/commands.md"
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::application::errors::AllowedCommands;
     use std::io;
 
     /// Helper function to create a boxed error for testing
@@ -865,6 +1004,7 @@ mod tests {
         let error = ReleaseCommandHandlerError::InvalidState(InvalidStateError {
             expected: "configured".to_string(),
             actual: "created".to_string(),
+            allowed_commands: AllowedCommands::default(),
         });
 
         let help = error.help();
@@ -928,6 +1068,7 @@ mod tests {
             ReleaseCommandHandlerError::InvalidState(InvalidStateError {
                 expected: "configured".to_string(),
                 actual: "created".to_string(),
+                allowed_commands: AllowedCommands::default(),
             }),
             ReleaseCommandHandlerError::StatePersistence(PersistenceError::NotFound),
             ReleaseCommandHandlerError::TemplateRendering {
@@ -974,6 +1115,20 @@ mod tests {
                 message: "test".to_string(),
                 source: make_boxed_error("test"),
             },
+            ReleaseCommandHandlerError::ContainerImagePull {
+                message: "test".to_string(),
+                source: make_boxed_error("test"),
+            },
+            ReleaseCommandHandlerError::ImageDigestMismatch {
+                service: "mysql".to_string(),
+                expected: "sha256:aaa".to_string(),
+                actual: "sha256:bbb".to_string(),
+            },
+            ReleaseCommandHandlerError::DiskSpace(DiskSpaceCheckError::InsufficientDiskSpace {
+                location: "partition '/' of the instance holding /var/lib/docker".to_string(),
+                available: 1024,
+                required: 2048,
+            }),
             ReleaseCommandHandlerError::ReleaseOperationFailed {
                 name: "test".to_string(),
                 message: "error".to_string(),
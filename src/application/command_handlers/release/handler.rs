@@ -1,23 +1,37 @@
 //! Release command handler implementation
 
+use std::path::PathBuf;
 use std::sync::Arc;
 
 use tracing::{error, info, instrument};
 
 use super::errors::ReleaseCommandHandlerError;
+use super::steps::common::ansible_client;
+use super::steps::disk_space;
 use super::workflow;
+use crate::adapters::ansible::{AnsibleClient, AnsiblePlaybookOptions};
+use crate::adapters::ssh::SshMultiplexer;
+use crate::application::command_handlers::common::{
+    apply_config_override, prune_run_logs, prune_traces, traces_dir, verify_host_keys, CheckReport,
+};
 use crate::application::traits::CommandProgressListener;
+use crate::domain::environment::deployment_report::elapsed_ms;
 use crate::domain::environment::repository::{EnvironmentRepository, TypedEnvironmentRepository};
 use crate::domain::environment::state::{ReleaseFailureContext, ReleaseStep};
-use crate::domain::environment::{Configured, Environment, Released, Releasing};
+use crate::domain::environment::{
+    ChangeImpact, Configured, DeploymentPhase, DiskSpaceThresholds, Environment, Released,
+    Releasing,
+};
 use crate::domain::EnvironmentName;
+use crate::shared::command::run_log::DEFAULT_KEPT_RUNS;
+use crate::shared::command::RunLog;
 use crate::shared::error::Traceable;
 
 /// Total number of steps in the release workflow.
 ///
 /// This constant is used for progress reporting via `CommandProgressListener`
-/// to display step progress like "[Step 1/7] Releasing Tracker service...".
-pub(super) const TOTAL_RELEASE_STEPS: usize = 7;
+/// to display step progress like "[Step 1/8] Releasing Tracker service...".
+pub(super) const TOTAL_RELEASE_STEPS: usize = 8;
 
 /// `ReleaseCommandHandler` orchestrates the software release workflow
 ///
@@ -43,13 +57,42 @@ pub(super) const TOTAL_RELEASE_STEPS: usize = 7;
 /// The command handler integrates with the type-state pattern for environment lifecycle:
 /// - Accepts environment in `Configured` state
 /// - Transitions to `Environment<Releasing>` at start
-/// - Returns `Environment<Released>` on success
+/// - Returns `Environment<Released>` on success, with the deployed tracker
+///   version added to the release history
 /// - Transitions to `Environment<ReleaseFailed>` on error
 ///
 /// State is persisted after each transition using the injected repository.
+///
+/// # Disk Space
+///
+/// Before writing to the instance, the handler checks the free space of the
+/// partition holding the Docker images (see
+/// [`Self::with_disk_space_thresholds`]). A shortage fails the workflow with
+/// `ReleaseStep::CheckDiskSpace`, naming the partition.
+///
+/// # Ansible Execution Controls
+///
+/// [`AnsiblePlaybookOptions`] set with `with_ansible_options` are used by
+/// [`Self::execute_tagged`], where `--tags`/`--skip-tags` restrict the run to
+/// some services (e.g. only `tracker`), and by [`Self::check`]. Neither
+/// changes the environment state: only a full run by [`Self::execute`], which
+/// ignores the options, makes the environment `Released`.
+///
+/// # Configuration Overrides
+///
+/// An override file set with [`Self::with_config_override`] is merged onto
+/// the persisted configuration before the templates are rendered, e.g. to
+/// rotate the API admin token or upgrade the tracker. Only changes that take
+/// effect with a release are accepted; the merged configuration is persisted
+/// with the `Releasing` state.
 pub struct ReleaseCommandHandler {
     clock: Arc<dyn crate::shared::Clock>,
     repository: TypedEnvironmentRepository,
+    ansible_options: AnsiblePlaybookOptions,
+    kept_run_logs: usize,
+    trace_dir: Option<PathBuf>,
+    disk_space_thresholds: DiskSpaceThresholds,
+    config_override: Option<PathBuf>,
 }
 
 impl ReleaseCommandHandler {
@@ -62,9 +105,55 @@ impl ReleaseCommandHandler {
         Self {
             clock,
             repository: TypedEnvironmentRepository::new(repository),
+            ansible_options: AnsiblePlaybookOptions::default(),
+            kept_run_logs: DEFAULT_KEPT_RUNS,
+            trace_dir: None,
+            disk_space_thresholds: DiskSpaceThresholds::default(),
+            config_override: None,
         }
     }
 
+    /// Pass tag selection to `ansible-playbook`
+    ///
+    /// The options are used by [`Self::execute_tagged`] and [`Self::check`];
+    /// [`Self::execute`] always runs every playbook in full.
+    #[must_use]
+    pub fn with_ansible_options(mut self, options: AnsiblePlaybookOptions) -> Self {
+        self.ansible_options = options;
+        self
+    }
+
+    /// Keep the Ansible output logs of the last `runs` runs of the command
+    #[must_use]
+    pub fn with_kept_run_logs(mut self, runs: usize) -> Self {
+        self.kept_run_logs = runs;
+        self
+    }
+
+    /// Write the trace files of a failure to `trace_dir`
+    ///
+    /// `None` keeps the directory of the environment configuration, or
+    /// `data/{env}/traces/` when it sets none.
+    #[must_use]
+    pub fn with_trace_dir(mut self, trace_dir: Option<PathBuf>) -> Self {
+        self.trace_dir = trace_dir;
+        self
+    }
+
+    /// Require the given free disk space on the instance before releasing
+    #[must_use]
+    pub fn with_disk_space_thresholds(mut self, thresholds: DiskSpaceThresholds) -> Self {
+        self.disk_space_thresholds = thresholds;
+        self
+    }
+
+    /// Merge the override file at `path` onto the persisted configuration before releasing
+    #[must_use]
+    pub fn with_config_override(mut self, path: PathBuf) -> Self {
+        self.config_override = Some(path);
+        self
+    }
+
     /// Execute the release workflow
     ///
     /// # Arguments
@@ -85,11 +174,11 @@ impl ReleaseCommandHandler {
     /// * File deployment to VM fails
     /// * State persistence fails
     #[instrument(
-        name = "release_command",
+        name = "command.release",
         skip_all,
         fields(
             command_type = "release",
-            environment = %env_name
+            environment_name = %env_name
         )
     )]
     pub async fn execute(
@@ -97,7 +186,9 @@ impl ReleaseCommandHandler {
         env_name: &EnvironmentName,
         listener: Option<&dyn CommandProgressListener>,
     ) -> Result<Environment<Released>, ReleaseCommandHandlerError> {
+        let _lock = self.repository.inner().lock(env_name)?;
         let environment = self.load_configured_environment(env_name)?;
+        let environment = self.apply_config_override(environment)?;
 
         // Validate instance IP exists before proceeding (fail early)
         let instance_ip = environment.instance_ip().ok_or_else(|| {
@@ -119,16 +210,77 @@ impl ReleaseCommandHandler {
 
         let releasing_env = environment.start_releasing();
 
+        let released = self
+            .release_environment(releasing_env, "release", started_at, listener)
+            .await?;
+        let finished_at = self.clock.now();
+        let released = released
+            .with_release_recorded(finished_at)
+            .with_phase_duration(
+                DeploymentPhase::Release,
+                elapsed_ms(started_at, finished_at),
+            );
+
+        self.repository.save_released(&released)?;
+
+        Ok(released)
+    }
+
+    /// Persist the `Releasing` state and run the release workflow
+    ///
+    /// Shared by the release and rollback commands. On failure the environment
+    /// is persisted in `ReleaseFailed` state; on success the returned
+    /// `Released` environment is not persisted yet, so the caller can update
+    /// the release history first. The playbook output is logged under the
+    /// name of the calling `command`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if a release step or state persistence fails.
+    pub(crate) async fn release_environment(
+        &self,
+        releasing_env: Environment<Releasing>,
+        command: &str,
+        started_at: chrono::DateTime<chrono::Utc>,
+        listener: Option<&dyn CommandProgressListener>,
+    ) -> Result<Environment<Released>, ReleaseCommandHandlerError> {
+        // Refuse to release to a host presenting other keys than the recorded ones
+        if let Some(instance_ip) = releasing_env.instance_ip() {
+            verify_host_keys(&releasing_env, instance_ip)?;
+        }
+
         self.repository.save_releasing(&releasing_env)?;
 
         info!(
             command = "release",
-            environment = %env_name,
+            environment = %releasing_env.name(),
             current_state = "releasing",
             "Releasing state persisted. Executing release steps."
         );
 
-        match workflow::execute(&releasing_env, listener).await {
+        let run_log = self.run_log(&releasing_env, command, started_at);
+        // A full run: tag filters only apply to `execute_tagged`
+        let ansible_client = ansible_client(
+            &releasing_env,
+            AnsiblePlaybookOptions::default(),
+            run_log.clone(),
+        );
+
+        // Playbooks share SSH connections through control sockets in
+        // `build/{env}/ssh`; they are closed when the workflow returns
+        let _multiplexer = SshMultiplexer::new(releasing_env.ssh_control_dir());
+
+        let required = self.disk_space_thresholds.instance();
+        let result = match disk_space::check(&releasing_env, required, listener) {
+            Ok(()) => workflow::execute(&releasing_env, &ansible_client, listener).await,
+            Err(failure) => Err(failure),
+        };
+        prune_run_logs(&run_log);
+
+        let traces_dir = traces_dir(&releasing_env, self.trace_dir.as_deref());
+        let traces = releasing_env.traces_config().clone();
+
+        let outcome = match result {
             Ok(released) => {
                 info!(
                     command = "release",
@@ -137,8 +289,6 @@ impl ReleaseCommandHandler {
                     "Software release completed successfully"
                 );
 
-                self.repository.save_released(&released)?;
-
                 Ok(released)
             }
             Err((e, current_step)) => {
@@ -158,13 +308,168 @@ impl ReleaseCommandHandler {
 
                 Err(e)
             }
-        }
+        };
+        prune_traces(&traces_dir, &traces, self.clock.now());
+
+        outcome
+    }
+
+    /// Report what the release workflow would change, without changing anything
+    ///
+    /// Renders the templates and runs the same playbooks as [`Self::execute`]
+    /// with `ansible-playbook --check`. The environment must be in
+    /// `Configured` state, but its state is neither transitioned nor
+    /// persisted, whatever the outcome.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the environment cannot be loaded, is not in
+    /// `Configured` state, has no instance IP, or a step fails in check mode.
+    /// Tasks that would change the host are not errors; they are counted in
+    /// the returned [`CheckReport`].
+    #[instrument(
+        name = "command.release_check",
+        skip_all,
+        fields(
+            command_type = "release",
+            environment_name = %env_name,
+            check = true
+        )
+    )]
+    pub async fn check(
+        &self,
+        env_name: &EnvironmentName,
+        listener: Option<&dyn CommandProgressListener>,
+    ) -> Result<CheckReport, ReleaseCommandHandlerError> {
+        let ansible_client = self
+            .run_in_place(
+                env_name,
+                self.ansible_options.clone().with_check(true),
+                listener,
+            )
+            .await?;
+
+        let report = CheckReport::new(ansible_client.recaps());
+
+        info!(
+            command = "release",
+            environment = %env_name,
+            changed_tasks = report.changed_tasks(),
+            "Release check completed"
+        );
+
+        Ok(report)
+    }
+
+    /// Run the playbooks restricted by the tag filters, without changing the environment state
+    ///
+    /// A run limited with `--tags`/`--skip-tags` does not release every
+    /// service, so it cannot make the environment `Released`. Like
+    /// [`Self::check`], the environment must be in `Configured` state and its
+    /// state is neither transitioned nor persisted, whatever the outcome.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the environment cannot be loaded, is not in
+    /// `Configured` state, has no instance IP, lacks free disk space, or a
+    /// release step fails.
+    #[instrument(
+        name = "command.release_tagged",
+        skip_all,
+        fields(
+            command_type = "release",
+            environment_name = %env_name
+        )
+    )]
+    pub async fn execute_tagged(
+        &self,
+        env_name: &EnvironmentName,
+        listener: Option<&dyn CommandProgressListener>,
+    ) -> Result<(), ReleaseCommandHandlerError> {
+        let _lock = self.repository.inner().lock(env_name)?;
+        self.run_in_place(
+            env_name,
+            self.ansible_options.clone().with_check(false),
+            listener,
+        )
+        .await?;
+
+        info!(
+            command = "release",
+            environment = %env_name,
+            "Tag-filtered release completed; the environment state was not changed"
+        );
+
+        Ok(())
+    }
+
+    /// Run the release workflow with `options` on an environment kept in memory
+    ///
+    /// Shared by [`Self::check`] and [`Self::execute_tagged`]. Nothing is
+    /// persisted.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the environment cannot be loaded, is not in
+    /// `Configured` state, has no instance IP, or a step fails.
+    async fn run_in_place(
+        &self,
+        env_name: &EnvironmentName,
+        options: AnsiblePlaybookOptions,
+        listener: Option<&dyn CommandProgressListener>,
+    ) -> Result<Arc<AnsibleClient>, ReleaseCommandHandlerError> {
+        let environment = self.load_configured_environment(env_name)?;
+
+        let instance_ip = environment.instance_ip().ok_or_else(|| {
+            ReleaseCommandHandlerError::MissingInstanceIp {
+                name: env_name.to_string(),
+            }
+        })?;
+
+        verify_host_keys(&environment, instance_ip)?;
+
+        // Only used in memory to run the steps; never persisted
+        let environment = self.apply_config_override(environment)?;
+        let releasing_env = environment.start_releasing();
+
+        let run_log = self.run_log(&releasing_env, "release", self.clock.now());
+        let ansible_client = ansible_client(&releasing_env, options.clone(), run_log.clone());
+
+        // Playbooks share SSH connections through control sockets in
+        // `build/{env}/ssh`; they are closed when the workflow returns
+        let _multiplexer = SshMultiplexer::new(releasing_env.ssh_control_dir());
+
+        // A dry run changes nothing, so it needs no free disk space
+        let required = self.disk_space_thresholds.instance();
+        let result = if options.is_check() {
+            Ok(())
+        } else {
+            disk_space::check(&releasing_env, required, listener)
+        };
+        let result = match result {
+            Ok(()) => workflow::execute(&releasing_env, &ansible_client, listener).await,
+            Err(failure) => Err(failure),
+        };
+        prune_run_logs(&run_log);
+        result.map_err(|(e, _)| e)?;
+
+        Ok(ansible_client)
     }
 
     // =========================================================================
     // Helper methods
     // =========================================================================
 
+    /// Output logs of the `command` run started at `started_at`
+    fn run_log(
+        &self,
+        environment: &Environment<Releasing>,
+        command: &str,
+        started_at: chrono::DateTime<chrono::Utc>,
+    ) -> RunLog {
+        RunLog::new(&environment.run_logs_dir(), command, started_at).keeping(self.kept_run_logs)
+    }
+
     /// Build failure context for a release error and generate trace file
     ///
     /// This helper method builds structured error context including the failed step,
@@ -208,7 +513,7 @@ impl ReleaseCommandHandler {
         };
 
         // Generate trace file (logging handled by trace writer)
-        let traces_dir = environment.traces_dir();
+        let traces_dir = traces_dir(environment, self.trace_dir.as_deref());
         let writer = ReleaseTraceWriter::new(traces_dir, Arc::clone(&self.clock));
 
         if let Ok(trace_file) = writer.write_trace(&context, error) {
@@ -218,6 +523,29 @@ impl ReleaseCommandHandler {
         context
     }
 
+    /// Merge the configuration override, if any, onto the environment
+    ///
+    /// Nothing is persisted; the merged configuration is saved with the
+    /// next state of the environment.
+    ///
+    /// # Errors
+    ///
+    /// Returns `ConfigOverride` if the override cannot be applied.
+    fn apply_config_override(
+        &self,
+        environment: Environment<Configured>,
+    ) -> Result<Environment<Configured>, ReleaseCommandHandlerError> {
+        match &self.config_override {
+            Some(path) => Ok(apply_config_override(
+                environment,
+                path,
+                "release",
+                ChangeImpact::RequiresRelease,
+            )?),
+            None => Ok(environment),
+        }
+    }
+
     /// Load environment from storage and validate it is in `Configured` state
     ///
     /// # Errors
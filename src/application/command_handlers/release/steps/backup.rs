@@ -8,7 +8,7 @@ use std::sync::Arc;
 
 use tracing::info;
 
-use super::common::ansible_client;
+use crate::adapters::ansible::AnsibleClient;
 use crate::application::command_handlers::common::StepResult;
 use crate::application::command_handlers::release::errors::ReleaseCommandHandlerError;
 use crate::application::steps::application::{CreateBackupStorageStep, DeployBackupConfigStep};
@@ -30,6 +30,7 @@ use crate::domain::environment::{Environment, Releasing};
 /// # Arguments
 ///
 /// * `environment` - The environment in Releasing state
+/// * `ansible_client` - Client running the playbooks, with the Ansible options applied
 /// * `listener` - Optional progress listener for detail and debug reporting
 ///
 /// # Errors
@@ -41,6 +42,7 @@ use crate::domain::environment::{Environment, Releasing};
 #[allow(clippy::result_large_err)]
 pub async fn release(
     environment: &Environment<Releasing>,
+    ansible_client: &Arc<AnsibleClient>,
     listener: Option<&dyn CommandProgressListener>,
 ) -> StepResult<(), ReleaseCommandHandlerError, ReleaseStep> {
     // Check if backup is configured
@@ -55,9 +57,9 @@ pub async fn release(
     }
 
     render_templates(environment, listener).await?;
-    create_storage(environment, listener)?;
-    deploy_config_to_remote(environment, listener)?;
-    install_crontab(environment, listener)?;
+    create_storage(environment, ansible_client, listener)?;
+    deploy_config_to_remote(ansible_client, listener)?;
+    install_crontab(ansible_client, listener)?;
 
     Ok(())
 }
@@ -121,6 +123,7 @@ async fn render_templates(
 /// # Arguments
 ///
 /// * `environment` - The environment in Releasing state
+/// * `ansible_client` - Client running the playbooks, with the Ansible options applied
 /// * `listener` - Optional progress listener for detail and debug reporting
 ///
 /// # Errors
@@ -129,6 +132,7 @@ async fn render_templates(
 #[allow(clippy::result_large_err)]
 fn create_storage(
     environment: &Environment<Releasing>,
+    ansible_client: &Arc<AnsibleClient>,
     listener: Option<&dyn CommandProgressListener>,
 ) -> StepResult<(), ReleaseCommandHandlerError, ReleaseStep> {
     let current_step = ReleaseStep::CreateBackupStorage;
@@ -141,7 +145,7 @@ fn create_storage(
         l.on_debug("Executing playbook: ansible-playbook create-backup-storage.yml");
     }
 
-    CreateBackupStorageStep::new(ansible_client(environment))
+    CreateBackupStorageStep::new(Arc::clone(ansible_client))
         .execute()
         .map_err(|e| {
             (
@@ -171,7 +175,7 @@ fn create_storage(
 ///
 /// # Arguments
 ///
-/// * `environment` - The environment in Releasing state
+/// * `ansible_client` - Client running the playbooks, with the Ansible options applied
 /// * `listener` - Optional progress listener for detail and debug reporting
 ///
 /// # Errors
@@ -179,7 +183,7 @@ fn create_storage(
 /// Returns a tuple of (error, `ReleaseStep::DeployBackupConfigToRemote`) if deployment fails
 #[allow(clippy::result_large_err)]
 fn deploy_config_to_remote(
-    environment: &Environment<Releasing>,
+    ansible_client: &Arc<AnsibleClient>,
     listener: Option<&dyn CommandProgressListener>,
 ) -> StepResult<(), ReleaseCommandHandlerError, ReleaseStep> {
     let current_step = ReleaseStep::DeployBackupConfigToRemote;
@@ -188,7 +192,7 @@ fn deploy_config_to_remote(
         l.on_debug("Executing playbook: ansible-playbook deploy-backup-config.yml");
     }
 
-    DeployBackupConfigStep::new(ansible_client(environment))
+    DeployBackupConfigStep::new(Arc::clone(ansible_client))
         .execute()
         .map_err(|e| {
             (
@@ -221,7 +225,7 @@ fn deploy_config_to_remote(
 ///
 /// # Arguments
 ///
-/// * `environment` - The environment in Releasing state
+/// * `ansible_client` - Client running the playbooks, with the Ansible options applied
 /// * `listener` - Optional progress listener for detail and debug reporting
 ///
 /// # Errors
@@ -229,7 +233,7 @@ fn deploy_config_to_remote(
 /// Returns a tuple of (error, `ReleaseStep::InstallBackupCrontab`) if installation fails
 #[allow(clippy::result_large_err)]
 fn install_crontab(
-    environment: &Environment<Releasing>,
+    ansible_client: &Arc<AnsibleClient>,
     listener: Option<&dyn CommandProgressListener>,
 ) -> StepResult<(), ReleaseCommandHandlerError, ReleaseStep> {
     let current_step = ReleaseStep::InstallBackupCrontab;
@@ -238,7 +242,7 @@ fn install_crontab(
         l.on_debug("Executing playbook: ansible-playbook install-backup-crontab.yml");
     }
 
-    InstallBackupCrontabStep::new(ansible_client(environment))
+    InstallBackupCrontabStep::new(Arc::clone(ansible_client))
         .execute()
         .map_err(|e| {
             (
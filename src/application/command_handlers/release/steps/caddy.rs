@@ -10,7 +10,7 @@ use std::sync::Arc;
 
 use tracing::info;
 
-use super::common::ansible_client;
+use crate::adapters::ansible::AnsibleClient;
 use crate::application::command_handlers::common::StepResult;
 use crate::application::command_handlers::release::errors::ReleaseCommandHandlerError;
 use crate::application::steps::application::DeployCaddyConfigStep;
@@ -31,6 +31,7 @@ use crate::shared::clock::SystemClock;
 /// # Arguments
 ///
 /// * `environment` - The environment in Releasing state
+/// * `ansible_client` - Client running the playbooks, with the Ansible options applied
 /// * `listener` - Optional progress listener for detail and debug reporting
 ///
 /// # Errors
@@ -39,6 +40,7 @@ use crate::shared::clock::SystemClock;
 #[allow(clippy::result_large_err)]
 pub fn release(
     environment: &Environment<Releasing>,
+    ansible_client: &Arc<AnsibleClient>,
     listener: Option<&dyn CommandProgressListener>,
 ) -> StepResult<(), ReleaseCommandHandlerError, ReleaseStep> {
     // Check if HTTPS is configured
@@ -53,7 +55,7 @@ pub fn release(
     }
 
     render_templates(environment, listener)?;
-    deploy_config_to_remote(environment, listener)?;
+    deploy_config_to_remote(ansible_client, listener)?;
     Ok(())
 }
 
@@ -116,7 +118,7 @@ fn render_templates(
 ///
 /// # Arguments
 ///
-/// * `environment` - The environment in Releasing state
+/// * `ansible_client` - Client running the playbooks, with the Ansible options applied
 /// * `listener` - Optional progress listener for detail and debug reporting
 ///
 /// # Errors
@@ -124,7 +126,7 @@ fn render_templates(
 /// Returns a tuple of (error, `ReleaseStep::DeployCaddyConfigToRemote`) if deployment fails
 #[allow(clippy::result_large_err)]
 fn deploy_config_to_remote(
-    environment: &Environment<Releasing>,
+    ansible_client: &Arc<AnsibleClient>,
     listener: Option<&dyn CommandProgressListener>,
 ) -> StepResult<(), ReleaseCommandHandlerError, ReleaseStep> {
     let current_step = ReleaseStep::DeployCaddyConfigToRemote;
@@ -133,7 +135,7 @@ fn deploy_config_to_remote(
         l.on_debug("Executing playbook: ansible-playbook deploy-caddy-config.yml");
     }
 
-    DeployCaddyConfigStep::new(ansible_client(environment))
+    DeployCaddyConfigStep::new(Arc::clone(ansible_client))
         .execute()
         .map_err(|e| {
             (
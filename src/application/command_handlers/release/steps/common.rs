@@ -2,13 +2,25 @@
 
 use std::sync::Arc;
 
-use crate::adapters::ansible::AnsibleClient;
+use crate::adapters::ansible::{AnsibleClient, AnsiblePlaybookOptions};
 use crate::domain::environment::{Environment, Releasing};
+use crate::shared::command::RunLog;
 
 /// Create an Ansible client configured for the environment's build directory
 ///
-/// This is a helper function to reduce duplication across step implementations.
+/// The client is shared by all release steps so that the playbook recaps of
+/// a check mode run can be collected in one place, and the output of every
+/// playbook lands in the same `run_log`.
 #[must_use]
-pub fn ansible_client(environment: &Environment<Releasing>) -> Arc<AnsibleClient> {
-    Arc::new(AnsibleClient::new(environment.build_dir().join("ansible")))
+pub fn ansible_client(
+    environment: &Environment<Releasing>,
+    options: AnsiblePlaybookOptions,
+    run_log: RunLog,
+) -> Arc<AnsibleClient> {
+    Arc::new(
+        AnsibleClient::new(environment.build_dir().join("ansible"))
+            .with_options(options)
+            .with_run_log(run_log)
+            .with_env_vars(environment.context().user_inputs.proxy_env_vars()),
+    )
 }
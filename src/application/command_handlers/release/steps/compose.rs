@@ -27,6 +27,7 @@ use crate::shared::clock::SystemClock;
 /// # Arguments
 ///
 /// * `environment` - The environment in Releasing state
+/// * `ansible_client` - Client running the playbooks, with the Ansible options applied
 /// * `listener` - Optional progress listener for detail and debug reporting
 ///
 /// # Errors
@@ -34,10 +35,11 @@ use crate::shared::clock::SystemClock;
 /// Returns a tuple of (error, step) if any Docker Compose step fails
 pub async fn release(
     environment: &Environment<Releasing>,
+    ansible_client: &Arc<AnsibleClient>,
     listener: Option<&dyn CommandProgressListener>,
 ) -> StepResult<(), ReleaseCommandHandlerError, ReleaseStep> {
     let compose_build_dir = render_templates(environment, listener).await?;
-    deploy_files_to_remote(environment, &compose_build_dir, listener)?;
+    deploy_files_to_remote(environment, ansible_client, &compose_build_dir, listener)?;
     Ok(())
 }
 
@@ -101,6 +103,7 @@ async fn render_templates(
 /// # Arguments
 ///
 /// * `environment` - The environment in Releasing state
+/// * `ansible_client` - Client running the playbooks, with the Ansible options applied
 /// * `compose_build_dir` - Path to the rendered compose files
 /// * `listener` - Optional progress listener for detail and debug reporting
 ///
@@ -110,6 +113,7 @@ async fn render_templates(
 #[allow(clippy::result_large_err)]
 fn deploy_files_to_remote(
     environment: &Environment<Releasing>,
+    ansible_client: &Arc<AnsibleClient>,
     compose_build_dir: &Path,
     listener: Option<&dyn CommandProgressListener>,
 ) -> StepResult<(), ReleaseCommandHandlerError, ReleaseStep> {
@@ -123,8 +127,8 @@ fn deploy_files_to_remote(
         l.on_debug("Executing playbook: ansible-playbook deploy-compose-files.yml");
     }
 
-    let ansible_client = Arc::new(AnsibleClient::new(environment.ansible_build_dir()));
-    let step = DeployComposeFilesStep::new(ansible_client, compose_build_dir.to_path_buf());
+    let step =
+        DeployComposeFilesStep::new(Arc::clone(ansible_client), compose_build_dir.to_path_buf());
 
     step.execute().map_err(|e| {
         (
@@ -0,0 +1,56 @@
+//! Disk space release preflight
+//!
+//! This module contains the check of the free space on the instance, run
+//! before the release writes anything to it. The container images pulled by
+//! the release land under the Docker data directory, whose partition runs
+//! full first on small instances.
+
+use std::net::SocketAddr;
+
+use crate::adapters::ssh::SshConfig;
+use crate::application::command_handlers::common::StepResult;
+use crate::application::command_handlers::release::errors::ReleaseCommandHandlerError;
+use crate::application::steps::CheckInstanceDiskSpaceStep;
+use crate::application::traits::CommandProgressListener;
+use crate::domain::environment::state::ReleaseStep;
+use crate::domain::environment::{Environment, Releasing};
+
+/// Check that `required` bytes are free on the Docker partition of the instance
+///
+/// # Arguments
+///
+/// * `environment` - The environment in Releasing state
+/// * `required` - Minimum free space, in bytes
+/// * `listener` - Optional progress listener for detail reporting
+///
+/// # Errors
+///
+/// Returns a tuple of (error, `ReleaseStep::CheckDiskSpace`) if the instance
+/// has no IP address or less than `required` bytes are free.
+#[allow(clippy::result_large_err)]
+pub fn check(
+    environment: &Environment<Releasing>,
+    required: u64,
+    listener: Option<&dyn CommandProgressListener>,
+) -> StepResult<(), ReleaseCommandHandlerError, ReleaseStep> {
+    let current_step = ReleaseStep::CheckDiskSpace;
+
+    let instance_ip = environment.instance_ip().ok_or_else(|| {
+        (
+            ReleaseCommandHandlerError::MissingInstanceIp {
+                name: environment.name().to_string(),
+            },
+            current_step,
+        )
+    })?;
+    let ssh_config = SshConfig::new(
+        environment.ssh_credentials().clone(),
+        SocketAddr::new(instance_ip, environment.ssh_port()),
+    )
+    .with_control_dir(environment.ssh_control_dir())
+    .with_known_hosts(environment.ssh_known_hosts_path());
+
+    CheckInstanceDiskSpaceStep::new(ssh_config, required)
+        .execute(listener)
+        .map_err(|e| (e.into(), current_step))
+}
@@ -12,7 +12,7 @@ use std::sync::Arc;
 
 use tracing::info;
 
-use super::common::ansible_client;
+use crate::adapters::ansible::AnsibleClient;
 use crate::application::command_handlers::common::StepResult;
 use crate::application::command_handlers::release::errors::ReleaseCommandHandlerError;
 use crate::application::steps::application::{
@@ -37,6 +37,7 @@ use crate::shared::clock::SystemClock;
 /// # Arguments
 ///
 /// * `environment` - The environment in Releasing state
+/// * `ansible_client` - Client running the playbooks, with the Ansible options applied
 /// * `listener` - Optional progress listener for detail and debug reporting
 ///
 /// # Errors
@@ -45,6 +46,7 @@ use crate::shared::clock::SystemClock;
 #[allow(clippy::result_large_err)]
 pub fn release(
     environment: &Environment<Releasing>,
+    ansible_client: &Arc<AnsibleClient>,
     listener: Option<&dyn CommandProgressListener>,
 ) -> StepResult<(), ReleaseCommandHandlerError, ReleaseStep> {
     // Check if Grafana is configured
@@ -58,7 +60,7 @@ pub fn release(
         return Ok(());
     }
 
-    create_storage(environment, listener)?;
+    create_storage(environment, ansible_client, listener)?;
 
     // Provisioning requires Prometheus for datasource configuration
     if environment.context().user_inputs.prometheus().is_none() {
@@ -72,7 +74,7 @@ pub fn release(
     }
 
     render_templates(environment, listener)?;
-    deploy_provisioning_to_remote(environment, listener)?;
+    deploy_provisioning_to_remote(ansible_client, listener)?;
     Ok(())
 }
 
@@ -81,6 +83,7 @@ pub fn release(
 /// # Arguments
 ///
 /// * `environment` - The environment in Releasing state
+/// * `ansible_client` - Client running the playbooks, with the Ansible options applied
 /// * `listener` - Optional progress listener for detail and debug reporting
 ///
 /// # Errors
@@ -89,6 +92,7 @@ pub fn release(
 #[allow(clippy::result_large_err)]
 fn create_storage(
     environment: &Environment<Releasing>,
+    ansible_client: &Arc<AnsibleClient>,
     listener: Option<&dyn CommandProgressListener>,
 ) -> StepResult<(), ReleaseCommandHandlerError, ReleaseStep> {
     let current_step = ReleaseStep::CreateGrafanaStorage;
@@ -101,7 +105,7 @@ fn create_storage(
         l.on_debug("Executing playbook: ansible-playbook create-grafana-storage.yml");
     }
 
-    CreateGrafanaStorageStep::new(ansible_client(environment))
+    CreateGrafanaStorageStep::new(Arc::clone(ansible_client))
         .execute()
         .map_err(|e| {
             (
@@ -187,7 +191,7 @@ fn render_templates(
 ///
 /// # Arguments
 ///
-/// * `environment` - The environment in Releasing state
+/// * `ansible_client` - Client running the playbooks, with the Ansible options applied
 /// * `listener` - Optional progress listener for detail and debug reporting
 ///
 /// # Errors
@@ -195,7 +199,7 @@ fn render_templates(
 /// Returns a tuple of (error, `ReleaseStep::DeployGrafanaProvisioning`) if deployment fails
 #[allow(clippy::result_large_err)]
 fn deploy_provisioning_to_remote(
-    environment: &Environment<Releasing>,
+    ansible_client: &Arc<AnsibleClient>,
     listener: Option<&dyn CommandProgressListener>,
 ) -> StepResult<(), ReleaseCommandHandlerError, ReleaseStep> {
     let current_step = ReleaseStep::DeployGrafanaProvisioning;
@@ -204,7 +208,7 @@ fn deploy_provisioning_to_remote(
         l.on_debug("Executing playbook: ansible-playbook deploy-grafana-provisioning.yml");
     }
 
-    DeployGrafanaProvisioningStep::new(ansible_client(environment))
+    DeployGrafanaProvisioningStep::new(Arc::clone(ansible_client))
         .execute()
         .map_err(|e| {
             (
@@ -0,0 +1,134 @@
+//! Container image release steps
+//!
+//! This module contains the step pulling the container images on the instance
+//! and verifying the images pinned by digest. It runs after the Docker Compose
+//! files are deployed, so `docker compose pull` sees the final image list, and
+//! before the `run` command restarts any container.
+
+use std::net::SocketAddr;
+use std::sync::Arc;
+
+use tracing::info;
+
+use crate::adapters::ansible::AnsibleClient;
+use crate::adapters::ssh::SshConfig;
+use crate::application::command_handlers::common::StepResult;
+use crate::application::command_handlers::release::errors::ReleaseCommandHandlerError;
+use crate::application::steps::application::PullContainerImagesStep;
+use crate::application::traits::CommandProgressListener;
+use crate::domain::environment::state::ReleaseStep;
+use crate::domain::environment::{Environment, Releasing};
+use crate::infrastructure::remote_actions::ContainerImagesError;
+
+/// Pull the container images and verify their pinned digests
+///
+/// The step is skipped when the environment neither uses a private registry
+/// nor pins any image by digest: the `run` command pulls the images then.
+///
+/// # Arguments
+///
+/// * `environment` - The environment in Releasing state
+/// * `ansible_client` - Client running the playbooks, with the Ansible options applied
+/// * `listener` - Optional progress listener for detail and debug reporting
+///
+/// # Errors
+///
+/// Returns a tuple of (error, `ReleaseStep::PullContainerImages`) if the login
+/// or the pull fails, and (error, `ReleaseStep::VerifyImageDigests`) if a
+/// pinned image was pulled with another digest.
+#[allow(clippy::result_large_err)]
+pub fn release(
+    environment: &Environment<Releasing>,
+    ansible_client: &Arc<AnsibleClient>,
+    listener: Option<&dyn CommandProgressListener>,
+) -> StepResult<(), ReleaseCommandHandlerError, ReleaseStep> {
+    let current_step = ReleaseStep::PullContainerImages;
+
+    let user_inputs = &environment.context().user_inputs;
+    let registry = user_inputs
+        .release()
+        .and_then(|release| release.registry())
+        .cloned();
+    let pinned_images = user_inputs.pinned_images();
+
+    if registry.is_none() && pinned_images.is_empty() {
+        if let Some(l) = listener {
+            l.on_detail("No private registry or pinned digest: images are pulled by run");
+        }
+        info!(
+            command = "release",
+            step = %current_step,
+            status = "skipped",
+            "No private registry or pinned image - skipping image pull"
+        );
+        return Ok(());
+    }
+
+    if ansible_client.options().is_check() {
+        if let Some(l) = listener {
+            l.on_detail("Check mode: container images are not pulled");
+        }
+        return Ok(());
+    }
+
+    let instance_ip = environment.instance_ip().ok_or_else(|| {
+        (
+            ReleaseCommandHandlerError::MissingInstanceIp {
+                name: environment.name().to_string(),
+            },
+            current_step,
+        )
+    })?;
+    let ssh_config = SshConfig::new(
+        environment.ssh_credentials().clone(),
+        SocketAddr::new(instance_ip, environment.ssh_port()),
+    )
+    .with_control_dir(environment.ssh_control_dir())
+    .with_known_hosts(environment.ssh_known_hosts_path());
+
+    if let Some(l) = listener {
+        l.on_debug(&format!(
+            "Pulling images over SSH on {instance_ip}, {} pinned by digest",
+            pinned_images.len()
+        ));
+    }
+
+    PullContainerImagesStep::new(ssh_config, registry, pinned_images)
+        .execute(listener)
+        .map_err(|e| match e {
+            ContainerImagesError::DigestMismatch {
+                service,
+                expected,
+                actual,
+            } => (
+                ReleaseCommandHandlerError::ImageDigestMismatch {
+                    service,
+                    expected,
+                    actual,
+                },
+                ReleaseStep::VerifyImageDigests,
+            ),
+            e @ ContainerImagesError::InspectFailed { .. } => (
+                ReleaseCommandHandlerError::ContainerImagePull {
+                    message: e.to_string(),
+                    source: Box::new(e),
+                },
+                ReleaseStep::VerifyImageDigests,
+            ),
+            e => (
+                ReleaseCommandHandlerError::ContainerImagePull {
+                    message: e.to_string(),
+                    source: Box::new(e),
+                },
+                current_step,
+            ),
+        })?;
+
+    info!(
+        command = "release",
+        step = %current_step,
+        "Container images pulled and verified successfully"
+    );
+
+    Ok(())
+}
@@ -8,7 +8,9 @@ pub mod backup;
 pub mod caddy;
 pub mod common;
 pub mod compose;
+pub mod disk_space;
 pub mod grafana;
+pub mod images;
 pub mod mysql;
 pub mod prometheus;
 pub mod tracker;
@@ -5,9 +5,11 @@
 //!
 //! All steps are optional and only execute if `MySQL` is configured as the tracker database.
 
+use std::sync::Arc;
+
 use tracing::info;
 
-use super::common::ansible_client;
+use crate::adapters::ansible::AnsibleClient;
 use crate::application::command_handlers::common::StepResult;
 use crate::application::command_handlers::release::errors::ReleaseCommandHandlerError;
 use crate::application::steps::application::CreateMysqlStorageStep;
@@ -25,6 +27,7 @@ use crate::domain::environment::{Environment, Releasing};
 /// # Arguments
 ///
 /// * `environment` - The environment in Releasing state
+/// * `ansible_client` - Client running the playbooks, with the Ansible options applied
 /// * `listener` - Optional progress listener for detail and debug reporting
 ///
 /// # Errors
@@ -33,6 +36,7 @@ use crate::domain::environment::{Environment, Releasing};
 #[allow(clippy::result_large_err)]
 pub fn release(
     environment: &Environment<Releasing>,
+    ansible_client: &Arc<AnsibleClient>,
     listener: Option<&dyn CommandProgressListener>,
 ) -> StepResult<(), ReleaseCommandHandlerError, ReleaseStep> {
     // Check if MySQL is configured (via tracker database driver)
@@ -46,7 +50,7 @@ pub fn release(
         return Ok(());
     }
 
-    create_storage(environment, listener)?;
+    create_storage(environment, ansible_client, listener)?;
     Ok(())
 }
 
@@ -55,6 +59,7 @@ pub fn release(
 /// # Arguments
 ///
 /// * `environment` - The environment in Releasing state
+/// * `ansible_client` - Client running the playbooks, with the Ansible options applied
 /// * `listener` - Optional progress listener for detail and debug reporting
 ///
 /// # Errors
@@ -63,6 +68,7 @@ pub fn release(
 #[allow(clippy::result_large_err)]
 fn create_storage(
     environment: &Environment<Releasing>,
+    ansible_client: &Arc<AnsibleClient>,
     listener: Option<&dyn CommandProgressListener>,
 ) -> StepResult<(), ReleaseCommandHandlerError, ReleaseStep> {
     let current_step = ReleaseStep::CreateMysqlStorage;
@@ -75,7 +81,7 @@ fn create_storage(
         l.on_debug("Executing playbook: ansible-playbook create-mysql-storage.yml");
     }
 
-    CreateMysqlStorageStep::new(ansible_client(environment))
+    CreateMysqlStorageStep::new(Arc::clone(ansible_client))
         .execute()
         .map_err(|e| {
             (
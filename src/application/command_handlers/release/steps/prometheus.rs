@@ -11,7 +11,7 @@ use std::sync::Arc;
 
 use tracing::info;
 
-use super::common::ansible_client;
+use crate::adapters::ansible::AnsibleClient;
 use crate::application::command_handlers::common::StepResult;
 use crate::application::command_handlers::release::errors::ReleaseCommandHandlerError;
 use crate::application::steps::application::{
@@ -35,6 +35,7 @@ use crate::shared::clock::SystemClock;
 /// # Arguments
 ///
 /// * `environment` - The environment in Releasing state
+/// * `ansible_client` - Client running the playbooks, with the Ansible options applied
 /// * `listener` - Optional progress listener for detail and debug reporting
 ///
 /// # Errors
@@ -43,6 +44,7 @@ use crate::shared::clock::SystemClock;
 #[allow(clippy::result_large_err)]
 pub fn release(
     environment: &Environment<Releasing>,
+    ansible_client: &Arc<AnsibleClient>,
     listener: Option<&dyn CommandProgressListener>,
 ) -> StepResult<(), ReleaseCommandHandlerError, ReleaseStep> {
     // Check if Prometheus is configured
@@ -56,9 +58,9 @@ pub fn release(
         return Ok(());
     }
 
-    create_storage(environment, listener)?;
+    create_storage(environment, ansible_client, listener)?;
     render_templates(environment, listener)?;
-    deploy_config_to_remote(environment, listener)?;
+    deploy_config_to_remote(ansible_client, listener)?;
     Ok(())
 }
 
@@ -67,6 +69,7 @@ pub fn release(
 /// # Arguments
 ///
 /// * `environment` - The environment in Releasing state
+/// * `ansible_client` - Client running the playbooks, with the Ansible options applied
 /// * `listener` - Optional progress listener for detail and debug reporting
 ///
 /// # Errors
@@ -75,6 +78,7 @@ pub fn release(
 #[allow(clippy::result_large_err)]
 fn create_storage(
     environment: &Environment<Releasing>,
+    ansible_client: &Arc<AnsibleClient>,
     listener: Option<&dyn CommandProgressListener>,
 ) -> StepResult<(), ReleaseCommandHandlerError, ReleaseStep> {
     let current_step = ReleaseStep::CreatePrometheusStorage;
@@ -87,7 +91,7 @@ fn create_storage(
         l.on_debug("Executing playbook: ansible-playbook create-prometheus-storage.yml");
     }
 
-    CreatePrometheusStorageStep::new(ansible_client(environment))
+    CreatePrometheusStorageStep::new(Arc::clone(ansible_client))
         .execute()
         .map_err(|e| {
             (
@@ -171,7 +175,7 @@ fn render_templates(
 ///
 /// # Arguments
 ///
-/// * `environment` - The environment in Releasing state
+/// * `ansible_client` - Client running the playbooks, with the Ansible options applied
 /// * `listener` - Optional progress listener for detail and debug reporting
 ///
 /// # Errors
@@ -179,7 +183,7 @@ fn render_templates(
 /// Returns a tuple of (error, `ReleaseStep::DeployPrometheusConfigToRemote`) if deployment fails
 #[allow(clippy::result_large_err)]
 fn deploy_config_to_remote(
-    environment: &Environment<Releasing>,
+    ansible_client: &Arc<AnsibleClient>,
     listener: Option<&dyn CommandProgressListener>,
 ) -> StepResult<(), ReleaseCommandHandlerError, ReleaseStep> {
     let current_step = ReleaseStep::DeployPrometheusConfigToRemote;
@@ -188,7 +192,7 @@ fn deploy_config_to_remote(
         l.on_debug("Executing playbook: ansible-playbook deploy-prometheus-config.yml");
     }
 
-    DeployPrometheusConfigStep::new(ansible_client(environment))
+    DeployPrometheusConfigStep::new(Arc::clone(ansible_client))
         .execute()
         .map_err(|e| {
             (
@@ -6,12 +6,14 @@
 //! - Configuration template rendering
 //! - Configuration deployment to remote
 
+use std::net::SocketAddr;
 use std::path::{Path, PathBuf};
 use std::sync::Arc;
 
 use tracing::info;
 
-use super::common::ansible_client;
+use crate::adapters::ansible::AnsibleClient;
+use crate::adapters::ssh::SshConfig;
 use crate::application::command_handlers::common::StepResult;
 use crate::application::command_handlers::release::errors::ReleaseCommandHandlerError;
 use crate::application::steps::application::{
@@ -34,6 +36,7 @@ use crate::shared::SystemClock;
 /// # Arguments
 ///
 /// * `environment` - The environment in Releasing state
+/// * `ansible_client` - Client running the playbooks, with the Ansible options applied
 /// * `listener` - Optional progress listener for detail and debug reporting
 ///
 /// # Errors
@@ -42,12 +45,13 @@ use crate::shared::SystemClock;
 #[allow(clippy::result_large_err)]
 pub fn release(
     environment: &Environment<Releasing>,
+    ansible_client: &Arc<AnsibleClient>,
     listener: Option<&dyn CommandProgressListener>,
 ) -> StepResult<(), ReleaseCommandHandlerError, ReleaseStep> {
-    create_storage(environment, listener)?;
-    init_database(environment, listener)?;
+    create_storage(environment, ansible_client, listener)?;
+    init_database(ansible_client, listener)?;
     let tracker_build_dir = render_templates(environment, listener)?;
-    deploy_config_to_remote(environment, &tracker_build_dir, listener)?;
+    deploy_config_to_remote(environment, ansible_client, &tracker_build_dir, listener)?;
     Ok(())
 }
 
@@ -56,6 +60,7 @@ pub fn release(
 /// # Arguments
 ///
 /// * `environment` - The environment in Releasing state
+/// * `ansible_client` - Client running the playbooks, with the Ansible options applied
 /// * `listener` - Optional progress listener for detail and debug reporting
 ///
 /// # Errors
@@ -64,6 +69,7 @@ pub fn release(
 #[allow(clippy::result_large_err)]
 fn create_storage(
     environment: &Environment<Releasing>,
+    ansible_client: &Arc<AnsibleClient>,
     listener: Option<&dyn CommandProgressListener>,
 ) -> StepResult<(), ReleaseCommandHandlerError, ReleaseStep> {
     let current_step = ReleaseStep::CreateTrackerStorage;
@@ -76,7 +82,7 @@ fn create_storage(
         l.on_debug("Executing playbook: ansible-playbook create-tracker-storage.yml");
     }
 
-    CreateTrackerStorageStep::new(ansible_client(environment))
+    CreateTrackerStorageStep::new(Arc::clone(ansible_client))
         .execute()
         .map_err(|e| {
             (
@@ -105,7 +111,7 @@ fn create_storage(
 ///
 /// # Arguments
 ///
-/// * `environment` - The environment in Releasing state
+/// * `ansible_client` - Client running the playbooks, with the Ansible options applied
 /// * `listener` - Optional progress listener for detail and debug reporting
 ///
 /// # Errors
@@ -113,7 +119,7 @@ fn create_storage(
 /// Returns a tuple of (error, `ReleaseStep::InitTrackerDatabase`) if initialization fails
 #[allow(clippy::result_large_err)]
 fn init_database(
-    environment: &Environment<Releasing>,
+    ansible_client: &Arc<AnsibleClient>,
     listener: Option<&dyn CommandProgressListener>,
 ) -> StepResult<(), ReleaseCommandHandlerError, ReleaseStep> {
     let current_step = ReleaseStep::InitTrackerDatabase;
@@ -122,7 +128,7 @@ fn init_database(
         l.on_debug("Executing playbook: ansible-playbook init-tracker-database.yml");
     }
 
-    InitTrackerDatabaseStep::new(ansible_client(environment))
+    InitTrackerDatabaseStep::new(Arc::clone(ansible_client))
         .execute()
         .map_err(|e| {
             (
@@ -203,11 +209,15 @@ fn render_templates(
     Ok(tracker_build_dir)
 }
 
-/// Deploy tracker configuration to the remote host via Ansible
+/// Upload the tracker configuration to the remote host
+///
+/// In check mode (`ansible_client` runs playbooks with `--check`) nothing is
+/// uploaded.
 ///
 /// # Arguments
 ///
 /// * `environment` - The environment in Releasing state
+/// * `ansible_client` - Client running the playbooks, with the Ansible options applied
 /// * `tracker_build_dir` - Path to the rendered tracker configuration
 /// * `listener` - Optional progress listener for detail and debug reporting
 ///
@@ -217,17 +227,43 @@ fn render_templates(
 #[allow(clippy::result_large_err)]
 fn deploy_config_to_remote(
     environment: &Environment<Releasing>,
+    ansible_client: &Arc<AnsibleClient>,
     tracker_build_dir: &Path,
     listener: Option<&dyn CommandProgressListener>,
 ) -> StepResult<(), ReleaseCommandHandlerError, ReleaseStep> {
     let current_step = ReleaseStep::DeployTrackerConfigToRemote;
 
+    if ansible_client.options().is_check() {
+        if let Some(l) = listener {
+            l.on_detail("Check mode: tracker.toml is not uploaded");
+        }
+        return Ok(());
+    }
+
+    let instance_ip = environment.instance_ip().ok_or_else(|| {
+        (
+            ReleaseCommandHandlerError::MissingInstanceIp {
+                name: environment.name().to_string(),
+            },
+            current_step,
+        )
+    })?;
+    let ssh_config = SshConfig::new(
+        environment.ssh_credentials().clone(),
+        SocketAddr::new(instance_ip, environment.ssh_port()),
+    )
+    .with_control_dir(environment.ssh_control_dir())
+    .with_known_hosts(environment.ssh_known_hosts_path());
+
     if let Some(l) = listener {
-        l.on_debug("Executing playbook: ansible-playbook deploy-tracker-config.yml");
+        l.on_debug(&format!(
+            "Uploading {}/tracker.toml over SSH to {instance_ip}",
+            tracker_build_dir.display()
+        ));
     }
 
-    DeployTrackerConfigStep::new(ansible_client(environment), tracker_build_dir.to_path_buf())
-        .execute()
+    DeployTrackerConfigStep::new(ssh_config, tracker_build_dir.to_path_buf())
+        .execute(listener)
         .map_err(|e| {
             (
                 ReleaseCommandHandlerError::TrackerConfigDeployment {
@@ -3,9 +3,12 @@
 //! This module orchestrates the complete release workflow by coordinating
 //! all service-specific release steps in the correct order.
 
+use std::sync::Arc;
+
 use super::errors::ReleaseCommandHandlerError;
 use super::handler::TOTAL_RELEASE_STEPS;
-use super::steps::{backup, caddy, compose, grafana, mysql, prometheus, tracker};
+use super::steps::{backup, caddy, compose, grafana, images, mysql, prometheus, tracker};
+use crate::adapters::ansible::AnsibleClient;
 use crate::application::command_handlers::common::StepResult;
 use crate::application::traits::CommandProgressListener;
 use crate::domain::environment::state::ReleaseStep;
@@ -20,6 +23,7 @@ use crate::domain::environment::{Environment, Released, Releasing};
 /// # Arguments
 ///
 /// * `environment` - The environment in Releasing state
+/// * `ansible_client` - Client running the playbooks, with the Ansible options applied
 /// * `listener` - Optional progress listener for step-level reporting
 ///
 /// # Errors
@@ -27,35 +31,48 @@ use crate::domain::environment::{Environment, Released, Releasing};
 /// Returns a tuple of (error, `current_step`) if any release step fails
 pub async fn execute(
     environment: &Environment<Releasing>,
+    ansible_client: &Arc<AnsibleClient>,
     listener: Option<&dyn CommandProgressListener>,
 ) -> StepResult<Environment<Released>, ReleaseCommandHandlerError, ReleaseStep> {
-    // Step 1/7: Release Tracker service
+    // Step 1/8: Release Tracker service
     notify_step_started(listener, 1, "Releasing Tracker service");
-    tracker::release(environment, listener)?;
+    tracker::release(environment, ansible_client, listener)?;
+    notify_step_completed(listener, 1, "Releasing Tracker service");
 
-    // Step 2/7: Release Prometheus service
+    // Step 2/8: Release Prometheus service
     notify_step_started(listener, 2, "Releasing Prometheus service");
-    prometheus::release(environment, listener)?;
+    prometheus::release(environment, ansible_client, listener)?;
+    notify_step_completed(listener, 2, "Releasing Prometheus service");
 
-    // Step 3/7: Release Grafana service
+    // Step 3/8: Release Grafana service
     notify_step_started(listener, 3, "Releasing Grafana service");
-    grafana::release(environment, listener)?;
+    grafana::release(environment, ansible_client, listener)?;
+    notify_step_completed(listener, 3, "Releasing Grafana service");
 
-    // Step 4/7: Release MySQL service
+    // Step 4/8: Release MySQL service
     notify_step_started(listener, 4, "Releasing MySQL service");
-    mysql::release(environment, listener)?;
+    mysql::release(environment, ansible_client, listener)?;
+    notify_step_completed(listener, 4, "Releasing MySQL service");
 
-    // Step 5/7: Release Backup service
+    // Step 5/8: Release Backup service
     notify_step_started(listener, 5, "Releasing Backup service");
-    backup::release(environment, listener).await?;
+    backup::release(environment, ansible_client, listener).await?;
+    notify_step_completed(listener, 5, "Releasing Backup service");
 
-    // Step 6/7: Release Caddy service
+    // Step 6/8: Release Caddy service
     notify_step_started(listener, 6, "Releasing Caddy service");
-    caddy::release(environment, listener)?;
+    caddy::release(environment, ansible_client, listener)?;
+    notify_step_completed(listener, 6, "Releasing Caddy service");
 
-    // Step 7/7: Deploy Docker Compose configuration
+    // Step 7/8: Deploy Docker Compose configuration
     notify_step_started(listener, 7, "Deploying Docker Compose configuration");
-    compose::release(environment, listener).await?;
+    compose::release(environment, ansible_client, listener).await?;
+    notify_step_completed(listener, 7, "Deploying Docker Compose configuration");
+
+    // Step 8/8: Pull container images and verify pinned digests
+    notify_step_started(listener, 8, "Pulling container images");
+    images::release(environment, ansible_client, listener)?;
+    notify_step_completed(listener, 8, "Pulling container images");
 
     Ok(environment.clone().released())
 }
@@ -73,3 +90,14 @@ fn notify_step_started(
         l.on_step_started(step_number, TOTAL_RELEASE_STEPS, description);
     }
 }
+
+/// Notify the progress listener that a step has completed.
+fn notify_step_completed(
+    listener: Option<&dyn CommandProgressListener>,
+    step_number: usize,
+    description: &str,
+) {
+    if let Some(l) = listener {
+        l.on_step_completed(step_number, description);
+    }
+}
@@ -0,0 +1,280 @@
+//! Error types for the rename command handler
+
+use std::path::PathBuf;
+
+use crate::application::errors::PersistenceError;
+use crate::domain::{EnvironmentNameError, InstanceNameError, ProfileNameError};
+use crate::shared::error::kind::ErrorKind;
+use crate::shared::error::traceable::Traceable;
+
+/// Comprehensive error type for the `RenameCommandHandler`
+#[derive(Debug, thiserror::Error)]
+pub enum RenameCommandHandlerError {
+    /// The new name is the current name of the environment
+    #[error("Environment is already named '{name}'")]
+    SameName { name: String },
+
+    /// The environment to rename does not exist
+    #[error("Environment not found: '{name}'")]
+    EnvironmentNotFound { name: String },
+
+    /// An environment with the new name already exists
+    #[error("Environment '{name}' already exists")]
+    TargetAlreadyExists { name: String },
+
+    /// A directory of the new name is already present on disk
+    #[error("Directory '{path}' already exists")]
+    TargetDirectoryExists { path: PathBuf },
+
+    /// Another command is changing the environment
+    #[error("Environment '{name}' is in state '{state}': an operation is in progress")]
+    OperationInProgress { name: String, state: String },
+
+    /// The environment may have infrastructure and the rename was not allowed
+    #[error(
+        "Environment '{name}' is in state '{state}': its infrastructure may exist
+Tip: Rename it with --allow-live to keep the current instance and profile names"
+    )]
+    EnvironmentLive { name: String, state: String },
+
+    /// The new name does not fit the provider's instance name limit
+    #[error("Invalid new environment name: {0}")]
+    InvalidNewName(#[source] Box<EnvironmentNameError>),
+
+    /// The instance name derived from the new name is not valid
+    #[error("Cannot derive an instance name for the renamed environment: {0}")]
+    InvalidInstanceName(#[source] InstanceNameError),
+
+    /// The LXD profile name derived from the new name is not valid
+    #[error("Cannot derive an LXD profile name for the renamed environment: {0}")]
+    InvalidProfileName(#[source] ProfileNameError),
+
+    /// A directory of the environment could not be moved
+    ///
+    /// Directories moved before the failure are moved back.
+    #[error("Failed to move '{from}' to '{to}': {source}")]
+    DirectoryMoveFailed {
+        from: PathBuf,
+        to: PathBuf,
+        #[source]
+        source: std::io::Error,
+    },
+
+    /// The renamed environment could not be saved
+    ///
+    /// The directories are moved back to their original location.
+    #[error("Failed to save environment '{name}', its directories were moved back: {source}")]
+    PersistenceFailed {
+        name: String,
+        #[source]
+        source: PersistenceError,
+    },
+
+    /// Repository operation failed
+    #[error("Repository operation failed: {0}")]
+    RepositoryError(#[from] PersistenceError),
+}
+
+impl From<crate::domain::environment::repository::RepositoryError> for RenameCommandHandlerError {
+    fn from(e: crate::domain::environment::repository::RepositoryError) -> Self {
+        Self::RepositoryError(e.into())
+    }
+}
+
+impl Traceable for RenameCommandHandlerError {
+    fn trace_format(&self) -> String {
+        match self {
+            Self::SameName { name } => {
+                format!("RenameCommandHandlerError: Same name - '{name}'")
+            }
+            Self::EnvironmentNotFound { name } => {
+                format!("RenameCommandHandlerError: Environment not found - '{name}'")
+            }
+            Self::TargetAlreadyExists { name } => {
+                format!("RenameCommandHandlerError: Environment already exists - '{name}'")
+            }
+            Self::TargetDirectoryExists { path } => {
+                format!(
+                    "RenameCommandHandlerError: Directory already exists - '{}'",
+                    path.display()
+                )
+            }
+            Self::OperationInProgress { name, state } => {
+                format!("RenameCommandHandlerError: Operation in progress - '{name}' is {state}")
+            }
+            Self::EnvironmentLive { name, state } => {
+                format!("RenameCommandHandlerError: Live environment - '{name}' is {state}")
+            }
+            Self::InvalidNewName(e) => {
+                format!("RenameCommandHandlerError: Invalid new environment name - {e}")
+            }
+            Self::InvalidInstanceName(e) => {
+                format!("RenameCommandHandlerError: Invalid instance name - {e}")
+            }
+            Self::InvalidProfileName(e) => {
+                format!("RenameCommandHandlerError: Invalid profile name - {e}")
+            }
+            Self::DirectoryMoveFailed { from, to, source } => {
+                format!(
+                    "RenameCommandHandlerError: Directory move failed - '{}' to '{}': {source}",
+                    from.display(),
+                    to.display()
+                )
+            }
+            Self::PersistenceFailed { name, source } => {
+                format!("RenameCommandHandlerError: Failed to save '{name}' - {source}")
+            }
+            Self::RepositoryError(e) => {
+                format!("RenameCommandHandlerError: Repository operation failed - {e}")
+            }
+        }
+    }
+
+    fn trace_source(&self) -> Option<&dyn Traceable> {
+        None
+    }
+
+    fn error_kind(&self) -> ErrorKind {
+        match self {
+            Self::SameName { .. }
+            | Self::EnvironmentNotFound { .. }
+            | Self::TargetAlreadyExists { .. }
+            | Self::TargetDirectoryExists { .. }
+            | Self::OperationInProgress { .. }
+            | Self::EnvironmentLive { .. }
+            | Self::InvalidNewName(_)
+            | Self::InvalidInstanceName(_)
+            | Self::InvalidProfileName(_) => ErrorKind::Configuration,
+            Self::DirectoryMoveFailed { .. } => ErrorKind::FileSystem,
+            Self::PersistenceFailed { .. } | Self::RepositoryError(_) => {
+                ErrorKind::StatePersistence
+            }
+        }
+    }
+}
+
+impl RenameCommandHandlerError {
+    /// Provides detailed troubleshooting guidance for this error
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use torrust_tracker_deployer_lib::application::command_handlers::rename::RenameCommandHandlerError;
+    ///
+    /// let error = RenameCommandHandlerError::EnvironmentLive {
+    ///     name: "tmp".to_string(),
+    ///     state: "Running".to_string(),
+    /// };
+    ///
+    /// assert!(error.help().contains("--allow-live"));
+    /// ```
+    #[must_use]
+    pub fn help(&self) -> &'static str {
+        match self {
+            Self::SameName { .. } => {
+                "Same Name - Troubleshooting:
+
+The new name is the current name of the environment: there is nothing to do.
+
+1. Check the names given to the command:
+   torrust-tracker-deployer rename <current-name> <new-name>
+
+For more information, see docs/user-guide/commands/rename.md"
+            }
+            Self::EnvironmentNotFound { .. } => {
+                "Environment Not Found - Troubleshooting:
+
+1. Verify the environment name is correct
+2. List the existing environments:
+   torrust-tracker-deployer list
+
+For more information, see docs/user-guide/commands/rename.md"
+            }
+            Self::TargetAlreadyExists { .. } => {
+                "Environment Already Exists - Troubleshooting:
+
+1. Choose a different new name
+2. Or remove the existing environment first:
+   torrust-tracker-deployer destroy <name>
+   torrust-tracker-deployer purge <name>
+
+Renaming never overwrites an existing environment.
+
+For more information, see docs/user-guide/commands/rename.md"
+            }
+            Self::TargetDirectoryExists { .. } => {
+                "Directory Already Exists - Troubleshooting:
+
+The data or build directory of the new name is present, but no environment
+uses it (e.g. left over by an interrupted purge).
+
+1. Inspect the directory and remove it if it is not needed
+2. Or list leftover build directories with:
+   torrust-tracker-deployer orphans
+
+3. Or choose a different new name
+
+For more information, see docs/user-guide/commands/rename.md"
+            }
+            Self::OperationInProgress { .. } => {
+                "Operation In Progress - Troubleshooting:
+
+Another command is changing the environment. Renaming it meanwhile would
+move the directories that command is using.
+
+1. Wait for the other command to finish, then rename again
+2. If no command is running, the previous one was interrupted:
+   torrust-tracker-deployer repair <name>
+
+For more information, see docs/user-guide/commands/rename.md"
+            }
+            Self::EnvironmentLive { .. } => {
+                "Live Environment - Troubleshooting:
+
+The environment may have an instance. Its instance and LXD profile names
+were derived from the current name and cannot be changed without recreating
+the infrastructure.
+
+1. Rename it anyway, keeping the current infrastructure names:
+   torrust-tracker-deployer rename <name> <new-name> --allow-live
+
+2. Or destroy it first, then rename it and provision it again
+
+For more information, see docs/user-guide/commands/rename.md"
+            }
+            Self::InvalidNewName(_)
+            | Self::InvalidInstanceName(_)
+            | Self::InvalidProfileName(_) => {
+                "Invalid Derived Name - Troubleshooting:
+
+The instance and profile names are derived from the environment name,
+e.g. 'torrust-tracker-vm-<name>'.
+
+1. Use a shorter new name
+2. Use only lowercase letters, digits and dashes
+
+For more information, see docs/user-guide/commands/rename.md"
+            }
+            Self::DirectoryMoveFailed { .. } => {
+                "Directory Move Failed - Troubleshooting:
+
+The directories moved before the failure were moved back.
+
+1. Check file system permissions for the data/ and build/ directories
+2. Check that no process is using files in the environment directories
+3. data/ and build/ must be on file systems where a rename is possible
+
+For more information, see docs/user-guide/commands/rename.md"
+            }
+            Self::PersistenceFailed { .. } | Self::RepositoryError(_) => {
+                "Repository Operation Failed - Troubleshooting:
+
+1. Check file system permissions for the data/ directory
+2. Verify sufficient disk space is available
+3. Check whether another process holds the environment lock
+
+For more information, see docs/user-guide/commands/rename.md"
+            }
+        }
+    }
+}
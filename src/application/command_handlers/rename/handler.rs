@@ -0,0 +1,562 @@
+//! Rename command handler implementation
+//!
+//! **Purpose**: Give an existing environment a new name, e.g. when a
+//! throwaway environment ends up hosting production
+//!
+//! ## Design Strategy
+//!
+//! 1. **Uniqueness**: Refuse a new name that is already in use, as an
+//!    environment or as a leftover directory
+//! 2. **State**: Rename `Created` and `Destroyed` environments; environments
+//!    that may have infrastructure need `allow_live`, and environments with an
+//!    operation in progress are always refused
+//! 3. **Identity**: Regenerate the instance and LXD profile names derived from
+//!    the old name, unless infrastructure may exist (their names are then kept
+//!    and the old name is recorded in the runtime outputs)
+//! 4. **Directories**: Move `data/{old}` and `build/{old}` to the new name
+//! 5. **Persistence**: Save the renamed environment, moving the directories
+//!    back if anything fails, then remove the entry of the old name from
+//!    repositories that do not store environments in their data directory
+
+use std::fs;
+use std::path::PathBuf;
+use std::sync::Arc;
+
+use tracing::{error, info, instrument, warn};
+
+use super::errors::RenameCommandHandlerError;
+use crate::domain::environment::repository::EnvironmentRepository;
+use crate::domain::environment::{AnyEnvironmentState, EnvironmentName, WorkspaceLayout};
+use crate::domain::provider::ProviderConfig;
+use crate::domain::{InstanceName, ProfileName};
+
+/// `RenameCommandHandler` gives an environment a new name
+pub struct RenameCommandHandler {
+    repository: Arc<dyn EnvironmentRepository + Send + Sync>,
+    layout: WorkspaceLayout,
+    allow_live: bool,
+}
+
+impl RenameCommandHandler {
+    /// Create a new `RenameCommandHandler`
+    ///
+    /// # Arguments
+    ///
+    /// * `repository` - Repository for loading and saving the environment
+    /// * `working_dir` - Root directory containing `data/` and `build/` subdirectories
+    #[must_use]
+    pub fn new(
+        repository: Arc<dyn EnvironmentRepository + Send + Sync>,
+        working_dir: PathBuf,
+    ) -> Self {
+        Self::with_layout(repository, WorkspaceLayout::in_working_dir(working_dir))
+    }
+
+    /// Create a new `RenameCommandHandler` with explicit data and build roots
+    ///
+    /// # Arguments
+    ///
+    /// * `repository` - Repository for loading and saving the environment
+    /// * `layout` - Roots of the per-environment data and build directories
+    #[must_use]
+    pub fn with_layout(
+        repository: Arc<dyn EnvironmentRepository + Send + Sync>,
+        layout: WorkspaceLayout,
+    ) -> Self {
+        Self {
+            repository,
+            layout,
+            allow_live: false,
+        }
+    }
+
+    /// Rename environments that may have infrastructure
+    ///
+    /// Without it, only `Created` and `Destroyed` environments are renamed.
+    /// Their instance and LXD profile keep the names derived from the old name.
+    #[must_use]
+    pub fn with_allow_live(mut self, allow_live: bool) -> Self {
+        self.allow_live = allow_live;
+        self
+    }
+
+    /// Rename the environment `env_name` as `new_name`
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if:
+    /// * Both names are the same
+    /// * The environment does not exist
+    /// * An environment or a directory with the new name already exists
+    /// * An operation is in progress on the environment
+    /// * The environment may have infrastructure and `allow_live` is not set
+    /// * The derived instance or profile name is invalid
+    /// * A directory cannot be moved, or the environment cannot be saved
+    ///   (the directories are moved back first)
+    #[instrument(
+        name = "command.rename",
+        skip_all,
+        fields(
+            command_type = "rename",
+            environment_name = %env_name,
+            new_name = %new_name
+        )
+    )]
+    pub fn execute(
+        &self,
+        env_name: &EnvironmentName,
+        new_name: &EnvironmentName,
+    ) -> Result<AnyEnvironmentState, RenameCommandHandlerError> {
+        if env_name == new_name {
+            return Err(RenameCommandHandlerError::SameName {
+                name: env_name.to_string(),
+            });
+        }
+
+        // Lock the new name too, so no other command creates it meanwhile
+        let _lock = self.repository.lock(env_name)?;
+        let _new_name_lock = self.repository.lock(new_name)?;
+
+        let mut environment = self.load_environment(env_name)?;
+        self.verify_renamable(&environment)?;
+        self.verify_name_available(new_name)?;
+
+        if !environment.may_have_infrastructure() {
+            let (instance_name, profile_name) =
+                Self::renamed_infrastructure_names(&environment, new_name)?;
+            environment.rename_infrastructure(instance_name, profile_name);
+        }
+        environment.rename(new_name.clone(), &self.layout);
+
+        let mut moves = DirectoryMoves::default();
+        let moved = moves
+            .perform(
+                self.layout.environment_data_dir(env_name),
+                self.layout.environment_data_dir(new_name),
+            )
+            .and_then(|()| {
+                moves.perform(
+                    self.layout.environment_build_dir(env_name),
+                    self.layout.environment_build_dir(new_name),
+                )
+            });
+
+        if let Err(e) = moved {
+            moves.roll_back();
+            return Err(e);
+        }
+
+        if let Err(e) = self.repository.save(&environment) {
+            moves.roll_back();
+            return Err(RenameCommandHandlerError::PersistenceFailed {
+                name: new_name.to_string(),
+                source: e.into(),
+            });
+        }
+
+        // The renamed environment is saved, so a leftover entry is only logged
+        if let Err(e) = self.repository.delete(env_name) {
+            warn!(
+                command = "rename",
+                environment = %env_name,
+                error = %e,
+                "Failed to remove the entry of the old name from the repository"
+            );
+        }
+
+        info!(
+            command = "rename",
+            environment = %env_name,
+            new_name = %new_name,
+            infrastructure_name = ?environment.infrastructure_name(),
+            "Environment renamed successfully"
+        );
+
+        Ok(environment)
+    }
+
+    /// Load the environment from the repository
+    fn load_environment(
+        &self,
+        name: &EnvironmentName,
+    ) -> Result<AnyEnvironmentState, RenameCommandHandlerError> {
+        self.repository
+            .load(name)?
+            .ok_or_else(|| RenameCommandHandlerError::EnvironmentNotFound {
+                name: name.to_string(),
+            })
+    }
+
+    /// Verify the state of the environment allows renaming it
+    fn verify_renamable(
+        &self,
+        environment: &AnyEnvironmentState,
+    ) -> Result<(), RenameCommandHandlerError> {
+        if environment.is_transitional_state() {
+            return Err(RenameCommandHandlerError::OperationInProgress {
+                name: environment.name().to_string(),
+                state: environment.state_display_name().to_string(),
+            });
+        }
+
+        if environment.may_have_infrastructure() {
+            if !self.allow_live {
+                return Err(RenameCommandHandlerError::EnvironmentLive {
+                    name: environment.name().to_string(),
+                    state: environment.state_display_name().to_string(),
+                });
+            }
+
+            warn!(
+                command = "rename",
+                environment = %environment.name(),
+                state = environment.state_name(),
+                "Renaming a live environment: its infrastructure keeps its current names"
+            );
+        }
+
+        Ok(())
+    }
+
+    /// Verify no environment or leftover directory uses the new name
+    fn verify_name_available(
+        &self,
+        name: &EnvironmentName,
+    ) -> Result<(), RenameCommandHandlerError> {
+        if self.repository.exists(name)? {
+            return Err(RenameCommandHandlerError::TargetAlreadyExists {
+                name: name.to_string(),
+            });
+        }
+
+        for path in [
+            self.layout.environment_data_dir(name),
+            self.layout.environment_build_dir(name),
+        ] {
+            if path.exists() {
+                return Err(RenameCommandHandlerError::TargetDirectoryExists { path });
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Derive the instance and LXD profile names of the renamed environment
+    ///
+    /// Only names derived from the old environment name are renamed: the
+    /// generated instance name (`torrust-tracker-vm-{old}`) and a profile
+    /// name ending with the old name (`lxd-{old}`). Custom names are kept.
+    fn renamed_infrastructure_names(
+        environment: &AnyEnvironmentState,
+        new_name: &EnvironmentName,
+    ) -> Result<(InstanceName, Option<ProfileName>), RenameCommandHandlerError> {
+        let old_name = environment.name().as_str();
+        let generated_prefix = InstanceName::GENERATED_PREFIX;
+
+        let instance_name =
+            if environment.instance_name().as_str() == format!("{generated_prefix}{old_name}") {
+                environment
+                    .user_inputs()
+                    .provider_config()
+                    .provider()
+                    .check_environment_name_length(new_name)
+                    .map_err(|e| RenameCommandHandlerError::InvalidNewName(Box::new(e)))?;
+
+                InstanceName::new(format!("{generated_prefix}{}", new_name.as_str()))
+                    .map_err(RenameCommandHandlerError::InvalidInstanceName)?
+            } else {
+                environment.instance_name().clone()
+            };
+
+        let profile_name = match environment.user_inputs().provider_config() {
+            ProviderConfig::Lxd(lxd) => lxd
+                .profile_name
+                .as_str()
+                .strip_suffix(old_name)
+                .map(|prefix| ProfileName::new(format!("{prefix}{}", new_name.as_str())))
+                .transpose()
+                .map_err(RenameCommandHandlerError::InvalidProfileName)?,
+            _ => None,
+        };
+
+        Ok((instance_name, profile_name))
+    }
+}
+
+/// Directory moves done so far, undone in reverse order on failure
+#[derive(Default)]
+struct DirectoryMoves {
+    done: Vec<(PathBuf, PathBuf)>,
+}
+
+impl DirectoryMoves {
+    /// Move `from` to `to`, skipping a directory that does not exist
+    fn perform(&mut self, from: PathBuf, to: PathBuf) -> Result<(), RenameCommandHandlerError> {
+        if !from.exists() {
+            return Ok(());
+        }
+
+        info!(
+            command = "rename",
+            from = %from.display(),
+            to = %to.display(),
+            "Moving environment directory"
+        );
+
+        fs::rename(&from, &to).map_err(|source| {
+            RenameCommandHandlerError::DirectoryMoveFailed {
+                from: from.clone(),
+                to: to.clone(),
+                source,
+            }
+        })?;
+
+        self.done.push((from, to));
+        Ok(())
+    }
+
+    /// Move every directory back to where it was
+    ///
+    /// A directory that cannot be moved back is logged and left in place,
+    /// so the others are still restored.
+    fn roll_back(self) {
+        for (from, to) in self.done.into_iter().rev() {
+            match fs::rename(&to, &from) {
+                Ok(()) => info!(
+                    command = "rename",
+                    path = %from.display(),
+                    "Moved environment directory back"
+                ),
+                Err(e) => error!(
+                    command = "rename",
+                    from = %to.display(),
+                    to = %from.display(),
+                    error = %e,
+                    "Failed to move environment directory back: move it manually"
+                ),
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::net::{IpAddr, Ipv4Addr};
+    use std::path::Path;
+    use std::time::Duration;
+
+    use tempfile::TempDir;
+
+    use super::*;
+    use crate::application::command_handlers::create::config::EnvironmentCreationConfig;
+    use crate::application::command_handlers::CreateCommandHandler;
+    use crate::domain::environment::repository::{
+        EnvironmentLockGuard, RepositoryError, StateBackup, StoredStateVersion,
+    };
+    use crate::domain::environment::{Created, Environment};
+    use crate::domain::EnvironmentNameError;
+    use crate::infrastructure::persistence::file_repository_factory::FileRepositoryFactory;
+    use crate::shared::SystemClock;
+
+    /// Repository that loads environments from disk but fails to save them
+    struct FailingSaveRepository(Arc<dyn EnvironmentRepository + Send + Sync>);
+
+    impl EnvironmentRepository for FailingSaveRepository {
+        fn save(&self, _env: &AnyEnvironmentState) -> Result<(), RepositoryError> {
+            Err(RepositoryError::Internal(anyhow::anyhow!(
+                "simulated disk error"
+            )))
+        }
+
+        fn load(
+            &self,
+            name: &EnvironmentName,
+        ) -> Result<Option<AnyEnvironmentState>, RepositoryError> {
+            self.0.load(name)
+        }
+
+        fn load_version(
+            &self,
+            name: &EnvironmentName,
+        ) -> Result<Option<StoredStateVersion>, RepositoryError> {
+            self.0.load_version(name)
+        }
+
+        fn change_stamp(&self, name: &EnvironmentName) -> Result<Option<String>, RepositoryError> {
+            self.0.change_stamp(name)
+        }
+
+        fn list(&self) -> Result<Vec<EnvironmentName>, RepositoryError> {
+            self.0.list()
+        }
+
+        fn exists(&self, name: &EnvironmentName) -> Result<bool, RepositoryError> {
+            self.0.exists(name)
+        }
+
+        fn delete(&self, name: &EnvironmentName) -> Result<(), RepositoryError> {
+            self.0.delete(name)
+        }
+
+        fn lock(&self, name: &EnvironmentName) -> Result<EnvironmentLockGuard, RepositoryError> {
+            self.0.lock(name)
+        }
+
+        fn list_backups(
+            &self,
+            name: &EnvironmentName,
+        ) -> Result<Vec<StateBackup>, RepositoryError> {
+            self.0.list_backups(name)
+        }
+
+        fn restore_backup(
+            &self,
+            name: &EnvironmentName,
+            backup_id: &str,
+        ) -> Result<(), RepositoryError> {
+            self.0.restore_backup(name, backup_id)
+        }
+    }
+
+    fn repository(working_dir: &Path) -> Arc<dyn EnvironmentRepository + Send + Sync> {
+        FileRepositoryFactory::new(Duration::from_secs(30)).create(working_dir.join("data"))
+    }
+
+    /// Create the `tmp` environment with generated SSH keys and a build directory
+    fn create_environment(working_dir: &Path) -> Environment<Created> {
+        let config = EnvironmentCreationConfig::builder()
+            .name("tmp")
+            .generate_ssh_keys()
+            .provider_lxd("lxd-tmp")
+            .sqlite("tracker.db")
+            .api("0.0.0.0:1212", "MyAccessToken")
+            .build()
+            .unwrap();
+        let environment = CreateCommandHandler::new(repository(working_dir), Arc::new(SystemClock))
+            .execute(config, working_dir)
+            .unwrap();
+
+        fs::create_dir_all(working_dir.join("build/tmp/tofu")).unwrap();
+
+        environment
+    }
+
+    fn env_name(name: &str) -> EnvironmentName {
+        EnvironmentName::new(name).unwrap()
+    }
+
+    #[test]
+    fn it_should_rename_a_created_environment_and_move_its_directories() {
+        let temp_dir = TempDir::new().unwrap();
+        create_environment(temp_dir.path());
+        let handler =
+            RenameCommandHandler::new(repository(temp_dir.path()), temp_dir.path().into());
+
+        let renamed = handler
+            .execute(&env_name("tmp"), &env_name("prod"))
+            .unwrap();
+
+        assert!(!temp_dir.path().join("data/tmp").exists());
+        assert!(!temp_dir.path().join("build/tmp").exists());
+        assert!(temp_dir.path().join("build/prod/tofu").exists());
+        assert!(renamed.ssh_credentials().ssh_priv_key_path.exists());
+        assert_eq!(renamed.instance_name().as_str(), "torrust-tracker-vm-prod");
+        assert_eq!(renamed.profile_name().as_str(), "lxd-prod");
+        assert_eq!(renamed.infrastructure_name(), None);
+
+        let loaded = handler.repository.load(&env_name("prod")).unwrap().unwrap();
+        assert_eq!(loaded.name(), &env_name("prod"));
+        assert_eq!(loaded.data_dir(), &temp_dir.path().join("data/prod"));
+    }
+
+    #[test]
+    fn it_should_refuse_renaming_a_live_environment_unless_allowed() {
+        let temp_dir = TempDir::new().unwrap();
+        let repository = repository(temp_dir.path());
+        let environment = create_environment(temp_dir.path());
+        repository
+            .save(
+                &environment
+                    .import(IpAddr::V4(Ipv4Addr::new(10, 0, 0, 1)))
+                    .into_any(),
+            )
+            .unwrap();
+
+        let refused = RenameCommandHandler::new(repository.clone(), temp_dir.path().into())
+            .execute(&env_name("tmp"), &env_name("prod"));
+        assert!(matches!(
+            refused,
+            Err(RenameCommandHandlerError::EnvironmentLive { .. })
+        ));
+
+        let renamed = RenameCommandHandler::new(repository, temp_dir.path().into())
+            .with_allow_live(true)
+            .execute(&env_name("tmp"), &env_name("prod"))
+            .unwrap();
+
+        assert_eq!(renamed.instance_name().as_str(), "torrust-tracker-vm-tmp");
+        assert_eq!(renamed.profile_name().as_str(), "lxd-tmp");
+        assert_eq!(renamed.infrastructure_name(), Some(&env_name("tmp")));
+    }
+
+    #[test]
+    fn it_should_move_the_directories_back_when_saving_fails() {
+        let temp_dir = TempDir::new().unwrap();
+        create_environment(temp_dir.path());
+        let handler = RenameCommandHandler::new(
+            Arc::new(FailingSaveRepository(repository(temp_dir.path()))),
+            temp_dir.path().into(),
+        );
+
+        let result = handler.execute(&env_name("tmp"), &env_name("prod"));
+
+        assert!(matches!(
+            result,
+            Err(RenameCommandHandlerError::PersistenceFailed { .. })
+        ));
+        assert!(temp_dir.path().join("data/tmp/environment.json").exists());
+        assert!(temp_dir.path().join("build/tmp/tofu").exists());
+        assert!(!temp_dir.path().join("data/prod").exists());
+        assert!(!temp_dir.path().join("build/prod").exists());
+    }
+
+    #[test]
+    fn it_should_refuse_a_new_name_that_already_exists() {
+        let temp_dir = TempDir::new().unwrap();
+        create_environment(temp_dir.path());
+        fs::create_dir_all(temp_dir.path().join("build/prod")).unwrap();
+        let handler =
+            RenameCommandHandler::new(repository(temp_dir.path()), temp_dir.path().into());
+
+        let result = handler.execute(&env_name("tmp"), &env_name("prod"));
+
+        assert!(matches!(
+            result,
+            Err(RenameCommandHandlerError::TargetDirectoryExists { .. })
+        ));
+    }
+
+    #[test]
+    fn it_should_refuse_a_new_name_too_long_for_the_provider_instance_names() {
+        let temp_dir = TempDir::new().unwrap();
+        create_environment(temp_dir.path());
+        let handler =
+            RenameCommandHandler::new(repository(temp_dir.path()), temp_dir.path().into());
+        let new_name = env_name(&"a".repeat(50));
+
+        let result = handler.execute(&env_name("tmp"), &new_name);
+
+        match result {
+            Err(RenameCommandHandlerError::InvalidNewName(error)) => assert!(matches!(
+                *error,
+                EnvironmentNameError::TooLong {
+                    length: 50,
+                    max_length: 44,
+                    ..
+                }
+            )),
+            other => panic!("Expected TooLong new name error, got: {other:?}"),
+        }
+        assert!(temp_dir.path().join("data/tmp/environment.json").exists());
+    }
+}
@@ -0,0 +1,31 @@
+//! Rename Command Module
+//!
+//! This module implements the delivery-agnostic `RenameCommandHandler`
+//! for giving an existing environment a new name.
+//!
+//! ## What Is Renamed
+//!
+//! - The environment name stored in the environment state
+//! - `data/{old}` and `build/{old}`, moved to `data/{new}` and `build/{new}`
+//! - SSH keys generated in the data directory, which move with it
+//! - Instance name (`torrust-tracker-vm-{new}`) and LXD profile name, only
+//!   when no infrastructure exists (`Created` or `Destroyed` states)
+//!
+//! ## Live Environments
+//!
+//! Environments that may have infrastructure are only renamed when allowed
+//! explicitly. Their instance and LXD profile keep their names, and the name
+//! they were derived from is recorded in the runtime outputs.
+//!
+//! ## Atomicity
+//!
+//! The directories are moved before the renamed environment is saved. If a
+//! move or the save fails, the directories already moved are moved back, so
+//! the environment stays usable under its old name.
+
+pub mod errors;
+pub mod handler;
+
+// Re-export main types for convenience
+pub use errors::RenameCommandHandlerError;
+pub use handler::RenameCommandHandler;
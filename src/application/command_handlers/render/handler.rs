@@ -19,9 +19,9 @@ use crate::application::services::rendering::{
     TrackerTemplateRenderingService,
 };
 use crate::domain::environment::repository::EnvironmentRepository;
-use crate::domain::environment::{Created, Environment, EnvironmentParams};
+use crate::domain::environment::{Created, Environment, EnvironmentParams, WorkspaceLayout};
 use crate::domain::EnvironmentName;
-use crate::shared::{Clock, SystemClock};
+use crate::shared::Clock;
 
 /// Input mode for render command
 ///
@@ -75,15 +75,23 @@ pub struct RenderResult {
 /// 3. Validate state (Created only for existing environments)
 /// 4. Parse target IP address
 /// 5. Render all deployment templates to build/{env}/ directory
+///
+/// # Determinism
+///
+/// The rendering time in the header of every artifact, and the creation time
+/// of an environment rendered from a configuration file, come from the
+/// injected clock: with a fixed clock, the same inputs render byte-identical
+/// artifacts.
 pub struct RenderCommandHandler {
     repository: Arc<dyn EnvironmentRepository>,
+    clock: Arc<dyn Clock>,
 }
 
 impl RenderCommandHandler {
     /// Create a new `RenderCommandHandler`
     #[must_use]
-    pub fn new(repository: Arc<dyn EnvironmentRepository>) -> Self {
-        Self { repository }
+    pub fn new(repository: Arc<dyn EnvironmentRepository>, clock: Arc<dyn Clock>) -> Self {
+        Self { repository, clock }
     }
 
     /// Execute the render workflow
@@ -109,7 +117,7 @@ impl RenderCommandHandler {
     /// * Output directory exists and force is false
     /// * Template rendering fails
     #[instrument(
-        name = "render_command",
+        name = "command.render",
         skip_all,
         fields(
             command_type = "render",
@@ -251,16 +259,18 @@ impl RenderCommandHandler {
             })?;
 
         // Validate configuration by converting to domain types (this moves config)
-        let params: EnvironmentParams = config.try_into().map_err(|e: CreateConfigError| {
-            RenderCommandHandlerError::DomainValidationFailed {
-                reason: e.to_string(),
-            }
-        })?;
+        let params: EnvironmentParams = config
+            .with_generated_ssh_key_paths(&WorkspaceLayout::in_working_dir(working_dir))
+            .and_then(TryInto::try_into)
+            .map_err(
+                |e: CreateConfigError| RenderCommandHandlerError::DomainValidationFailed {
+                    reason: e.to_string(),
+                },
+            )?;
 
         // Create a temporary environment for template rendering (not persisted)
         let env_name = params.environment_name.clone();
-        let clock: Arc<dyn Clock> = Arc::new(SystemClock);
-        let created_env = Environment::<Created>::create(params, working_dir, clock.now())
+        let created_env = Environment::<Created>::create(params, working_dir, self.clock.now())
             .map_err(|e| RenderCommandHandlerError::DomainValidationFailed {
                 reason: e.to_string(),
             })?;
@@ -305,14 +315,18 @@ impl RenderCommandHandler {
             "Rendering all deployment templates"
         );
 
-        let clock: Arc<dyn Clock> = Arc::new(SystemClock);
+        let clock = Arc::clone(&self.clock);
         let templates_dir = environment.templates_dir();
+        let templates_override_dir = environment.templates_override_dir();
+        let extra_template_variables = environment.extra_template_variables();
         let build_dir = output_dir.to_path_buf();
         let user_inputs = &environment.context().user_inputs;
 
         // 1. Render OpenTofu templates (infrastructure provisioning)
         OpenTofuTemplateRenderingService::from_params(
             templates_dir.clone(),
+            templates_override_dir.clone(),
+            extra_template_variables.clone(),
             build_dir.clone(),
             environment.ssh_credentials().clone(),
             environment.ssh_port(),
@@ -320,6 +334,12 @@ impl RenderCommandHandler {
             environment.provider_config().clone(),
             clock.clone(),
         )
+        .with_public_ports(user_inputs.public_port_bindings())
+        .with_extra_firewall_rules(user_inputs.firewall().extra_allow().to_vec())
+        .with_cloud_init_customization(user_inputs.cloud_init().clone())
+        .with_instance_resources(*user_inputs.instance_resources())
+        .with_offline_mode(user_inputs.offline().cloned())
+        .with_proxy(user_inputs.proxy().cloned())
         .render()
         .await
         .map_err(|e| RenderCommandHandlerError::TemplateRenderingFailed {
@@ -329,6 +349,8 @@ impl RenderCommandHandler {
         // 2. Render Ansible templates (configuration management)
         AnsibleTemplateRenderingService::from_paths(
             templates_dir.clone(),
+            templates_override_dir.clone(),
+            extra_template_variables.clone(),
             build_dir.clone(),
             clock.clone(),
         )
@@ -341,6 +363,8 @@ impl RenderCommandHandler {
         // 3. Render Docker Compose templates (container orchestration)
         DockerComposeTemplateRenderingService::from_paths(
             templates_dir.clone(),
+            templates_override_dir.clone(),
+            extra_template_variables.clone(),
             build_dir.clone(),
             clock.clone(),
         )
@@ -353,6 +377,8 @@ impl RenderCommandHandler {
         // 4. Render Tracker configuration templates
         TrackerTemplateRenderingService::from_paths(
             templates_dir.clone(),
+            templates_override_dir.clone(),
+            extra_template_variables.clone(),
             build_dir.clone(),
             clock.clone(),
         )
@@ -364,6 +390,8 @@ impl RenderCommandHandler {
         // 5. Render Prometheus configuration templates (if configured)
         PrometheusTemplateRenderingService::from_paths(
             templates_dir.clone(),
+            templates_override_dir.clone(),
+            extra_template_variables.clone(),
             build_dir.clone(),
             clock.clone(),
         )
@@ -375,6 +403,8 @@ impl RenderCommandHandler {
         // 6. Render Grafana provisioning templates (if configured)
         GrafanaTemplateRenderingService::from_paths(
             templates_dir.clone(),
+            templates_override_dir.clone(),
+            extra_template_variables.clone(),
             build_dir.clone(),
             clock.clone(),
         )
@@ -386,6 +416,8 @@ impl RenderCommandHandler {
         // 7. Render Caddy TLS proxy templates (if HTTPS configured)
         CaddyTemplateRenderingService::from_paths(
             templates_dir.clone(),
+            templates_override_dir.clone(),
+            extra_template_variables.clone(),
             build_dir.clone(),
             clock.clone(),
         )
@@ -395,16 +427,21 @@ impl RenderCommandHandler {
         })?;
 
         // 8. Render Backup configuration templates (if configured)
-        BackupTemplateRenderingService::from_paths(templates_dir.clone(), build_dir.clone())
-            .render(
-                user_inputs.backup(),
-                user_inputs.tracker().core().database(),
-                environment.context().created_at(),
-            )
-            .await
-            .map_err(|e| RenderCommandHandlerError::TemplateRenderingFailed {
-                reason: e.to_string(),
-            })?;
+        BackupTemplateRenderingService::from_paths(
+            templates_dir.clone(),
+            templates_override_dir.clone(),
+            extra_template_variables.clone(),
+            build_dir.clone(),
+        )
+        .render(
+            user_inputs.backup(),
+            user_inputs.tracker().core().database(),
+            environment.context().created_at(),
+        )
+        .await
+        .map_err(|e| RenderCommandHandlerError::TemplateRenderingFailed {
+            reason: e.to_string(),
+        })?;
 
         info!(
             environment = %environment.name(),
@@ -483,6 +520,7 @@ mod tests {
     use std::net::{Ipv4Addr, Ipv6Addr};
 
     use crate::infrastructure::persistence::file_repository_factory::FileRepositoryFactory;
+    use crate::shared::SystemClock;
 
     fn create_test_repository() -> Arc<dyn EnvironmentRepository> {
         let file_repository_factory =
@@ -493,7 +531,7 @@ mod tests {
     #[test]
     fn it_should_create_handler() {
         let repository = create_test_repository();
-        let _handler = RenderCommandHandler::new(repository);
+        let _handler = RenderCommandHandler::new(repository, Arc::new(SystemClock));
     }
 
     #[test]
@@ -526,7 +564,7 @@ mod tests {
     #[tokio::test]
     async fn it_should_return_error_for_nonexistent_environment() {
         let repository = create_test_repository();
-        let handler = RenderCommandHandler::new(repository);
+        let handler = RenderCommandHandler::new(repository, Arc::new(SystemClock));
         let working_dir = PathBuf::from(".");
 
         // Use a non-existent path for output directory (don't create it)
@@ -554,7 +592,7 @@ mod tests {
     #[tokio::test]
     async fn it_should_return_error_for_nonexistent_config_file() {
         let repository = create_test_repository();
-        let handler = RenderCommandHandler::new(repository);
+        let handler = RenderCommandHandler::new(repository, Arc::new(SystemClock));
         let working_dir = PathBuf::from(".");
 
         // Use a non-existent path for output directory (don't create it)
@@ -583,7 +621,7 @@ mod tests {
     async fn it_should_validate_ip_before_loading_environment() {
         // This test ensures fail-fast behavior: IP validation happens first
         let repository = create_test_repository();
-        let handler = RenderCommandHandler::new(repository);
+        let handler = RenderCommandHandler::new(repository, Arc::new(SystemClock));
         let working_dir = PathBuf::from(".");
 
         // Use a non-existent path for output directory (don't create it)
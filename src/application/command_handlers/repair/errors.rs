@@ -0,0 +1,176 @@
+//! Error types for the Repair command handler
+
+use crate::application::errors::PersistenceError;
+use crate::shared::error::kind::ErrorKind;
+use crate::shared::error::traceable::Traceable;
+
+/// Errors that can occur while repairing the persisted state of an environment
+#[derive(Debug, thiserror::Error)]
+pub enum RepairCommandHandlerError {
+    /// Neither a state file nor a backup exists for the environment
+    #[error("Environment not found: {name}")]
+    EnvironmentNotFound {
+        /// The name of the environment that was not found
+        name: String,
+    },
+
+    /// The state loads and no backup was selected
+    #[error("Environment '{name}' state ('{state_name}') loads correctly; select a backup to restore it anyway")]
+    StateNotCorrupted {
+        /// The name of the environment
+        name: String,
+        /// Name of the current environment state
+        state_name: String,
+    },
+
+    /// The environment has no backups to restore
+    #[error("Environment '{name}' has no state backups to restore")]
+    NoBackups {
+        /// The name of the environment
+        name: String,
+    },
+
+    /// No backup has the selected id
+    #[error("Environment '{name}' has no state backup '{backup_id}'")]
+    BackupNotFound {
+        /// The name of the environment
+        name: String,
+        /// The selected backup id
+        backup_id: String,
+    },
+
+    /// Failed to read or write environment state
+    #[error("Failed to access environment state: {0}")]
+    StatePersistence(#[from] PersistenceError),
+}
+
+impl From<crate::domain::environment::repository::RepositoryError> for RepairCommandHandlerError {
+    fn from(e: crate::domain::environment::repository::RepositoryError) -> Self {
+        Self::StatePersistence(e.into())
+    }
+}
+
+impl Traceable for RepairCommandHandlerError {
+    fn trace_format(&self) -> String {
+        match self {
+            Self::EnvironmentNotFound { name } => {
+                format!("RepairCommandHandlerError: Environment not found - '{name}'")
+            }
+            Self::StateNotCorrupted { name, state_name } => {
+                format!(
+                    "RepairCommandHandlerError: State not corrupted - '{name}' in state '{state_name}'"
+                )
+            }
+            Self::NoBackups { name } => {
+                format!("RepairCommandHandlerError: No state backups - '{name}'")
+            }
+            Self::BackupNotFound { name, backup_id } => {
+                format!("RepairCommandHandlerError: Backup not found - '{name}': '{backup_id}'")
+            }
+            Self::StatePersistence(e) => {
+                format!("RepairCommandHandlerError: State persistence failed - {e}")
+            }
+        }
+    }
+
+    fn trace_source(&self) -> Option<&dyn Traceable> {
+        None
+    }
+
+    fn error_kind(&self) -> ErrorKind {
+        match self {
+            Self::EnvironmentNotFound { .. }
+            | Self::StateNotCorrupted { .. }
+            | Self::NoBackups { .. }
+            | Self::BackupNotFound { .. } => ErrorKind::Configuration,
+            Self::StatePersistence(_) => ErrorKind::StatePersistence,
+        }
+    }
+}
+
+impl RepairCommandHandlerError {
+    /// Provides detailed troubleshooting guidance for this error
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use torrust_tracker_deployer_lib::application::command_handlers::repair::RepairCommandHandlerError;
+    ///
+    /// let error = RepairCommandHandlerError::NoBackups {
+    ///     name: "my-env".to_string(),
+    /// };
+    ///
+    /// assert!(error.help().contains("No State Backups"));
+    /// ```
+    #[must_use]
+    pub fn help(&self) -> &'static str {
+        match self {
+            Self::EnvironmentNotFound { .. } => {
+                "Environment Not Found - Troubleshooting:
+
+1. Verify the environment name is correct
+2. Check if the environment was created:
+   ls data/
+
+Common causes:
+- Typo in environment name
+- Environment was purged
+- Working in the wrong directory
+
+For more information, see docs/user-guide/commands/repair.md"
+            }
+            Self::StateNotCorrupted { .. } => {
+                "State Not Corrupted - Troubleshooting:
+
+The environment state loads correctly, so there is nothing to repair.
+
+1. List the available backups:
+   cargo run -- repair <env-name> --list
+
+2. To go back to a previous state anyway, select the backup explicitly:
+   cargo run -- repair <env-name> --backup <backup-id>
+
+For more information, see docs/user-guide/commands/repair.md"
+            }
+            Self::NoBackups { .. } => {
+                "No State Backups - Troubleshooting:
+
+A backup is kept each time the environment state is overwritten. An
+environment that was only created, or whose state was last written before
+backups were introduced, has none.
+
+1. Check the backups directory:
+   ls data/<env-name>/state-backups/
+
+2. Without a backup, fix data/<env-name>/environment.json by hand or purge the
+   environment and create it again:
+   cargo run -- purge <env-name>
+
+For more information, see docs/user-guide/commands/repair.md"
+            }
+            Self::BackupNotFound { .. } => {
+                "State Backup Not Found - Troubleshooting:
+
+1. List the available backups and copy the id:
+   cargo run -- repair <env-name> --list
+
+2. Only the most recent backups are kept; older ones are removed
+
+For more information, see docs/user-guide/commands/repair.md"
+            }
+            Self::StatePersistence(_) => {
+                "State Persistence Failed - Troubleshooting:
+
+1. Check read and write permissions on the data directory:
+   ls -la data/<env-name>/
+
+2. Check available disk space:
+   df -h
+
+3. Make sure no other command is running for this environment
+
+For more information, see docs/user-guide/commands/repair.md"
+            }
+        }
+    }
+}
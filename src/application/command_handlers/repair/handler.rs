@@ -0,0 +1,144 @@
+//! Repair command handler implementation
+
+use std::sync::Arc;
+
+use tracing::{info, instrument};
+
+use super::errors::RepairCommandHandlerError;
+use super::info::{RepairReport, StateFileStatus};
+use crate::domain::environment::repository::{EnvironmentRepository, RepositoryError};
+use crate::domain::EnvironmentName;
+
+/// `RepairCommandHandler` restores the persisted state of an environment from a backup
+///
+/// This command handler:
+/// 1. Checks whether the environment state can be loaded
+/// 2. Lists the state backups kept by the repository
+/// 3. Restores the selected backup, or the latest one when the state cannot be loaded
+///
+/// It never connects to the instance; only the local state is changed.
+pub struct RepairCommandHandler {
+    repository: Arc<dyn EnvironmentRepository + Send + Sync>,
+}
+
+impl RepairCommandHandler {
+    /// Create a new `RepairCommandHandler`
+    #[must_use]
+    pub fn new(repository: Arc<dyn EnvironmentRepository + Send + Sync>) -> Self {
+        Self { repository }
+    }
+
+    /// Report the status of the environment state and its backups
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if:
+    /// * Neither the state file nor a backup exists
+    /// * The state or the backups cannot be read (other than corrupted state)
+    #[instrument(
+        name = "command.repair_inspect",
+        skip_all,
+        fields(
+            command_type = "repair",
+            environment_name = %env_name
+        )
+    )]
+    pub fn inspect(
+        &self,
+        env_name: &EnvironmentName,
+    ) -> Result<RepairReport, RepairCommandHandlerError> {
+        let status = match self.repository.load(env_name) {
+            Ok(Some(environment)) => StateFileStatus::Healthy {
+                state_name: environment.state_name().to_string(),
+            },
+            Ok(None) => StateFileStatus::Missing,
+            Err(RepositoryError::Corrupted { reason }) => StateFileStatus::Corrupted { reason },
+            Err(error) => return Err(error.into()),
+        };
+
+        let backups = self.repository.list_backups(env_name)?;
+
+        if status == StateFileStatus::Missing && backups.is_empty() {
+            return Err(RepairCommandHandlerError::EnvironmentNotFound {
+                name: env_name.to_string(),
+            });
+        }
+
+        Ok(RepairReport {
+            environment_name: env_name.to_string(),
+            status,
+            backups,
+            restored: None,
+        })
+    }
+
+    /// Restore the environment state from a backup
+    ///
+    /// # Arguments
+    ///
+    /// * `env_name` - The name of the environment
+    /// * `backup_id` - Backup to restore as listed, `None` for the latest backup.
+    ///   Without an explicit backup, a state that loads is left untouched.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if:
+    /// * Neither the state file nor a backup exists
+    /// * The state loads and no backup was selected
+    /// * The environment has no backups, or none with the selected id
+    /// * The backup cannot be restored
+    #[instrument(
+        name = "command.repair",
+        skip_all,
+        fields(
+            command_type = "repair",
+            environment_name = %env_name
+        )
+    )]
+    pub fn execute(
+        &self,
+        env_name: &EnvironmentName,
+        backup_id: Option<&str>,
+    ) -> Result<RepairReport, RepairCommandHandlerError> {
+        let _lock = self.repository.lock(env_name)?;
+        let mut report = self.inspect(env_name)?;
+
+        if let (StateFileStatus::Healthy { state_name }, None) = (&report.status, backup_id) {
+            return Err(RepairCommandHandlerError::StateNotCorrupted {
+                name: env_name.to_string(),
+                state_name: state_name.clone(),
+            });
+        }
+
+        let backup = match backup_id {
+            Some(backup_id) => report
+                .backups
+                .iter()
+                .find(|backup| backup.id == backup_id)
+                .ok_or_else(|| RepairCommandHandlerError::BackupNotFound {
+                    name: env_name.to_string(),
+                    backup_id: backup_id.to_string(),
+                })?,
+            None => report
+                .backups
+                .first()
+                .ok_or_else(|| RepairCommandHandlerError::NoBackups {
+                    name: env_name.to_string(),
+                })?,
+        }
+        .clone();
+
+        self.repository.restore_backup(env_name, &backup.id)?;
+
+        info!(
+            command = "repair",
+            environment = %env_name,
+            backup = %backup.id,
+            state = %backup.state_name,
+            "Environment state restored from backup"
+        );
+
+        report.restored = Some(backup);
+        Ok(report)
+    }
+}
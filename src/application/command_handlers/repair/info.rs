@@ -0,0 +1,53 @@
+//! Data Transfer Objects for the repair command
+//!
+//! These DTOs describe the persisted state of an environment, its available
+//! backups and the backup that was restored, if any.
+
+use serde::Serialize;
+
+use crate::domain::environment::repository::StateBackup;
+
+/// Whether the persisted state of an environment can be loaded
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+#[serde(tag = "status", rename_all = "snake_case")]
+pub enum StateFileStatus {
+    /// The state loads
+    Healthy {
+        /// Name of the environment state (e.g. "provisioned")
+        state_name: String,
+    },
+
+    /// The state file exists but cannot be parsed
+    Corrupted {
+        /// Why the state could not be parsed
+        reason: String,
+    },
+
+    /// There is no state file, only backups
+    Missing,
+}
+
+/// Result of the repair command
+#[derive(Debug, Clone, Serialize)]
+pub struct RepairReport {
+    /// Name of the environment
+    pub environment_name: String,
+
+    /// Status of the state before any backup was restored
+    #[serde(flatten)]
+    pub status: StateFileStatus,
+
+    /// Available backups, newest first
+    pub backups: Vec<StateBackup>,
+
+    /// The backup that was restored, if any
+    pub restored: Option<StateBackup>,
+}
+
+impl RepairReport {
+    /// Check if the state needs to be restored from a backup
+    #[must_use]
+    pub fn needs_repair(&self) -> bool {
+        !matches!(self.status, StateFileStatus::Healthy { .. })
+    }
+}
@@ -0,0 +1,37 @@
+//! Repair Command Module
+//!
+//! This module implements the delivery-agnostic `RepairCommandHandler`
+//! for recovering an environment whose persisted state can no longer be
+//! loaded, for example a `data/{env}/environment.json` truncated by an
+//! interrupted process.
+//!
+//! ## State Backups
+//!
+//! The repository keeps the last few readable states of every environment
+//! under `data/{env}/state-backups/`. The handler reports whether the current
+//! state can be loaded, lists the backups with their state names and
+//! timestamps, and restores a selected backup (the latest by default).
+//!
+//! ## Safety
+//!
+//! - A readable state is only replaced when a backup is selected explicitly
+//! - A readable state that is replaced is backed up first
+//! - Secrets (`secrets.json`) are kept as they are
+//!
+//! ## Module Organization
+//!
+//! - `handler.rs` - Core handler with `inspect()` and `execute()`
+//! - `info.rs` - Report DTOs
+//! - `errors.rs` - Error types for repair operations
+
+pub mod errors;
+pub mod handler;
+pub mod info;
+
+#[cfg(test)]
+mod tests;
+
+// Re-export main types for convenience
+pub use errors::RepairCommandHandlerError;
+pub use handler::RepairCommandHandler;
+pub use info::{RepairReport, StateFileStatus};
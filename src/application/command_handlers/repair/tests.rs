@@ -0,0 +1,126 @@
+//! Test module for Repair Command
+
+use std::fs;
+use std::sync::Arc;
+
+use tempfile::TempDir;
+
+use super::errors::RepairCommandHandlerError;
+use super::handler::RepairCommandHandler;
+use super::info::StateFileStatus;
+use crate::domain::environment::repository::EnvironmentRepository;
+use crate::domain::environment::testing::EnvironmentTestBuilder;
+use crate::domain::environment::Environment;
+use crate::domain::EnvironmentName;
+use crate::infrastructure::persistence::filesystem::file_environment_repository::FileEnvironmentRepository;
+
+fn create_test_handler() -> (
+    RepairCommandHandler,
+    Arc<FileEnvironmentRepository>,
+    TempDir,
+) {
+    let temp_dir = TempDir::new().expect("Failed to create temp directory");
+    let repository = Arc::new(FileEnvironmentRepository::new(
+        temp_dir.path().to_path_buf(),
+    ));
+    let handler = RepairCommandHandler::new(repository.clone());
+    (handler, repository, temp_dir)
+}
+
+/// Save the environment in the `created` state, then in the `provisioning` state
+fn save_two_states(repository: &FileEnvironmentRepository) -> Environment {
+    let (environment, _data_dir, _build_dir, _env_temp_dir) = EnvironmentTestBuilder::new()
+        .with_name("repair-env")
+        .build_with_custom_paths();
+    repository
+        .save(&environment.clone().into_any())
+        .expect("Failed to save environment");
+    repository
+        .save(&environment.clone().start_provisioning().into_any())
+        .expect("Failed to save environment");
+    environment
+}
+
+/// Cut the environment file in half, as an interrupted write would
+fn corrupt_state_file(temp_dir: &TempDir) {
+    let file_path = temp_dir.path().join("repair-env").join("environment.json");
+    let content = fs::read_to_string(&file_path).expect("Failed to read state file");
+    fs::write(&file_path, &content[..content.len() / 2]).expect("Failed to corrupt state file");
+}
+
+#[test]
+fn it_should_return_environment_not_found_error_when_environment_does_not_exist() {
+    let (handler, _repository, _temp_dir) = create_test_handler();
+    let env_name = EnvironmentName::new("nonexistent-env").unwrap();
+
+    let result = handler.inspect(&env_name);
+
+    assert!(matches!(
+        result,
+        Err(RepairCommandHandlerError::EnvironmentNotFound { .. })
+    ));
+}
+
+#[test]
+fn it_should_report_corrupted_state_and_list_backups() {
+    let (handler, repository, temp_dir) = create_test_handler();
+    let environment = save_two_states(&repository);
+    corrupt_state_file(&temp_dir);
+
+    let report = handler.inspect(environment.name()).unwrap();
+
+    assert!(matches!(report.status, StateFileStatus::Corrupted { .. }));
+    assert_eq!(report.backups.len(), 1);
+    assert_eq!(report.backups[0].state_name, "created");
+    assert!(report.restored.is_none());
+}
+
+#[test]
+fn it_should_restore_the_latest_backup_over_corrupted_state() {
+    let (handler, repository, temp_dir) = create_test_handler();
+    let environment = save_two_states(&repository);
+    corrupt_state_file(&temp_dir);
+
+    let report = handler.execute(environment.name(), None).unwrap();
+
+    assert_eq!(report.restored.as_ref(), report.backups.first());
+    let restored = repository.load(environment.name()).unwrap().unwrap();
+    assert_eq!(restored.state_name(), "created");
+}
+
+#[test]
+fn it_should_not_replace_a_state_that_loads_unless_a_backup_is_selected() {
+    let (handler, repository, _temp_dir) = create_test_handler();
+    let environment = save_two_states(&repository);
+
+    let result = handler.execute(environment.name(), None);
+
+    assert!(matches!(
+        result,
+        Err(RepairCommandHandlerError::StateNotCorrupted { ref state_name, .. }) if state_name == "provisioning"
+    ));
+
+    let backup_id = handler.inspect(environment.name()).unwrap().backups[0]
+        .id
+        .clone();
+    handler
+        .execute(environment.name(), Some(&backup_id))
+        .unwrap();
+
+    let restored = repository.load(environment.name()).unwrap().unwrap();
+    assert_eq!(restored.state_name(), "created");
+}
+
+#[test]
+fn it_should_reject_an_unknown_backup() {
+    let (handler, repository, temp_dir) = create_test_handler();
+    let environment = save_two_states(&repository);
+    corrupt_state_file(&temp_dir);
+
+    let result = handler.execute(environment.name(), Some("19700101T000000.000000000Z"));
+
+    assert!(matches!(
+        result,
+        Err(RepairCommandHandlerError::BackupNotFound { .. })
+    ));
+}
@@ -0,0 +1,142 @@
+//! Error types for reports command handler
+
+use crate::application::errors::PersistenceError;
+use crate::infrastructure::reports::ReportStoreError;
+use crate::shared::error::kind::ErrorKind;
+use crate::shared::error::traceable::Traceable;
+
+/// Comprehensive error type for the `ReportsCommandHandler`
+#[derive(Debug, thiserror::Error)]
+pub enum ReportsCommandHandlerError {
+    #[error("Environment not found: '{name}'")]
+    EnvironmentNotFound { name: String },
+
+    #[error("Environment '{name}' has no deployment reports")]
+    NoReports { name: String },
+
+    #[error("Report '{file_name}' not found for environment '{name}'")]
+    ReportNotFound { name: String, file_name: String },
+
+    #[error("Failed to load environment: {0}")]
+    LoadError(#[from] PersistenceError),
+
+    #[error("Failed to read deployment reports: {0}")]
+    ReportRead(#[from] ReportStoreError),
+}
+
+impl From<crate::domain::environment::repository::RepositoryError> for ReportsCommandHandlerError {
+    fn from(e: crate::domain::environment::repository::RepositoryError) -> Self {
+        Self::LoadError(e.into())
+    }
+}
+
+impl Traceable for ReportsCommandHandlerError {
+    fn trace_format(&self) -> String {
+        match self {
+            Self::EnvironmentNotFound { name } => {
+                format!("ReportsCommandHandlerError: Environment not found - '{name}'")
+            }
+            Self::NoReports { name } => {
+                format!("ReportsCommandHandlerError: No deployment reports - '{name}'")
+            }
+            Self::ReportNotFound { name, file_name } => {
+                format!("ReportsCommandHandlerError: Report not found - '{name}/{file_name}'")
+            }
+            Self::LoadError(e) => {
+                format!("ReportsCommandHandlerError: Failed to load environment - {e}")
+            }
+            Self::ReportRead(e) => {
+                format!("ReportsCommandHandlerError: Failed to read deployment reports - {e}")
+            }
+        }
+    }
+
+    fn trace_source(&self) -> Option<&dyn Traceable> {
+        None
+    }
+
+    fn error_kind(&self) -> ErrorKind {
+        match self {
+            Self::EnvironmentNotFound { .. }
+            | Self::NoReports { .. }
+            | Self::ReportNotFound { .. } => ErrorKind::Configuration,
+            Self::LoadError(_) => ErrorKind::StatePersistence,
+            Self::ReportRead(_) => ErrorKind::FileSystem,
+        }
+    }
+}
+
+impl ReportsCommandHandlerError {
+    /// Provides detailed troubleshooting guidance for this error
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use torrust_tracker_deployer_lib::application::command_handlers::reports::ReportsCommandHandlerError;
+    ///
+    /// let error = ReportsCommandHandlerError::NoReports {
+    ///     name: "my-env".to_string(),
+    /// };
+    ///
+    /// assert!(error.help().contains("provision"));
+    /// ```
+    #[must_use]
+    pub fn help(&self) -> &'static str {
+        match self {
+            Self::EnvironmentNotFound { .. } => {
+                "Environment Not Found - Troubleshooting:
+
+1. Verify the environment name is correct
+2. List the existing environments:
+   torrust-tracker-deployer list
+
+For more information, see docs/user-guide/commands/reports.md"
+            }
+            Self::NoReports { .. } => {
+                "No Deployment Reports - Troubleshooting:
+
+Reports are written when a deployment command finishes:
+provision, register, configure, release, run and destroy.
+
+1. Run a deployment command first, e.g.:
+   torrust-tracker-deployer provision <name>
+
+2. Check the reports directory:
+   ls data/<name>/reports/
+
+For more information, see docs/user-guide/commands/reports.md"
+            }
+            Self::ReportNotFound { .. } => {
+                "Report Not Found - Troubleshooting:
+
+1. List the available reports:
+   torrust-tracker-deployer reports <name>
+
+2. Pass the file name exactly as listed, e.g.:
+   torrust-tracker-deployer reports <name> --show 20251008-143045-provision.json
+
+For more information, see docs/user-guide/commands/reports.md"
+            }
+            Self::LoadError(_) => {
+                "Failed to Load Environment - Troubleshooting:
+
+1. Check file permissions on the data/ directory
+2. Verify the environment state file is not corrupted:
+   cat data/<name>/environment.json
+
+For more information, see docs/user-guide/commands/reports.md"
+            }
+            Self::ReportRead(_) => {
+                "Failed to Read Deployment Reports - Troubleshooting:
+
+1. Check file permissions on the reports directory:
+   ls -l data/<name>/reports/
+
+2. A report that is not valid JSON can be removed safely;
+   reports are audit records and are never read by other commands
+
+For more information, see docs/user-guide/commands/reports.md"
+            }
+        }
+    }
+}
@@ -0,0 +1,257 @@
+//! Reports command handler implementation
+//!
+//! **Purpose**: List and read the deployment reports of an environment
+//!
+//! This handler loads the environment to locate its data directory, then
+//! reads the reports stored in `data/{env}/reports/`.
+
+use std::path::Path;
+use std::sync::Arc;
+
+use tracing::instrument;
+
+use super::errors::ReportsCommandHandlerError;
+use crate::domain::environment::repository::EnvironmentRepository;
+use crate::domain::EnvironmentName;
+use crate::infrastructure::reports::{DeploymentReportStore, StoredReport};
+use crate::infrastructure::trace::find_trace;
+
+/// `ReportsCommandHandler` lists and reads deployment reports
+///
+/// **Purpose**: Read-only access to the audit trail of an environment
+///
+/// This handler never modifies state or makes network calls.
+pub struct ReportsCommandHandler {
+    repository: Arc<dyn EnvironmentRepository + Send + Sync>,
+}
+
+impl ReportsCommandHandler {
+    /// Create a new `ReportsCommandHandler`
+    #[must_use]
+    pub fn new(repository: Arc<dyn EnvironmentRepository + Send + Sync>) -> Self {
+        Self { repository }
+    }
+
+    /// List the reports of an environment, oldest first
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if:
+    /// * Environment not found
+    /// * Environment state file is corrupted or unreadable
+    /// * A report file cannot be read or parsed
+    #[instrument(
+        name = "command.reports",
+        skip_all,
+        fields(
+            command_type = "reports",
+            environment_name = %env_name
+        )
+    )]
+    pub fn list(
+        &self,
+        env_name: &EnvironmentName,
+    ) -> Result<Vec<StoredReport>, ReportsCommandHandlerError> {
+        Ok(self.store(env_name)?.list()?)
+    }
+
+    /// Read one report of an environment
+    ///
+    /// # Arguments
+    ///
+    /// * `env_name` - The name of the environment
+    /// * `file_name` - Report file name as listed, `None` for the latest report
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if:
+    /// * Environment not found
+    /// * The environment has no reports (when reading the latest)
+    /// * No report has the given file name
+    /// * The report file cannot be read or parsed
+    #[instrument(
+        name = "command.reports",
+        skip_all,
+        fields(
+            command_type = "reports",
+            environment_name = %env_name
+        )
+    )]
+    pub fn show(
+        &self,
+        env_name: &EnvironmentName,
+        file_name: Option<&str>,
+    ) -> Result<StoredReport, ReportsCommandHandlerError> {
+        let store = self.store(env_name)?;
+
+        let Some(file_name) = file_name else {
+            return store.list()?.pop().map(with_trace).ok_or_else(|| {
+                ReportsCommandHandlerError::NoReports {
+                    name: env_name.to_string(),
+                }
+            });
+        };
+
+        // Only plain file names from the listing are accepted, never paths
+        let is_listed =
+            store.reports_dir().join(file_name).is_file() && !file_name.contains(['/', '\\']);
+        if !is_listed {
+            return Err(ReportsCommandHandlerError::ReportNotFound {
+                name: env_name.to_string(),
+                file_name: file_name.to_string(),
+            });
+        }
+
+        Ok(with_trace(store.read(file_name)?))
+    }
+
+    /// Report store of the environment
+    fn store(
+        &self,
+        env_name: &EnvironmentName,
+    ) -> Result<DeploymentReportStore, ReportsCommandHandlerError> {
+        let any_env = self.repository.load(env_name)?.ok_or_else(|| {
+            ReportsCommandHandlerError::EnvironmentNotFound {
+                name: env_name.to_string(),
+            }
+        })?;
+
+        Ok(DeploymentReportStore::for_data_dir(any_env.data_dir()))
+    }
+}
+
+/// Attach the trace index entry of the failure to a report
+///
+/// The entry is looked up in the index next to the trace file. It is missing
+/// when the trace was removed by the retention policy; an unreadable index is
+/// treated the same way, the report itself is still shown.
+fn with_trace(mut stored: StoredReport) -> StoredReport {
+    let traces_dir = stored.report.trace_file.as_deref().and_then(Path::parent);
+
+    if let (Some(traces_dir), Some(trace_id)) = (traces_dir, &stored.report.trace_id) {
+        stored.trace = find_trace(traces_dir, trace_id).ok().flatten();
+    }
+
+    stored
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::BTreeMap;
+
+    use chrono::{TimeZone, Utc};
+
+    use super::*;
+    use crate::domain::environment::testing::EnvironmentTestBuilder;
+    use crate::domain::environment::{DeploymentReport, TraceId};
+    use crate::infrastructure::persistence::file_repository_factory::FileRepositoryFactory;
+    use crate::infrastructure::trace::index::write_trace_index;
+    use crate::infrastructure::trace::TraceIndexEntry;
+
+    fn report(command: &str, second: u32) -> DeploymentReport {
+        let at = Utc.with_ymd_and_hms(2026, 1, 1, 12, 0, second).unwrap();
+        DeploymentReport {
+            command: command.to_string(),
+            environment: "my-env".to_string(),
+            started_at: at,
+            finished_at: at,
+            duration_ms: 0,
+            succeeded: true,
+            state: None,
+            config_revision: None,
+            target_host: None,
+            steps: Vec::new(),
+            tool_versions: BTreeMap::new(),
+            applied_settings: BTreeMap::new(),
+            trace_id: None,
+            trace_file: None,
+            error: None,
+        }
+    }
+
+    /// Handler with a saved `my-env` environment and the store of its reports
+    fn setup() -> (
+        ReportsCommandHandler,
+        DeploymentReportStore,
+        tempfile::TempDir,
+    ) {
+        let (env, data_dir, _build_dir, temp_dir) = EnvironmentTestBuilder::new()
+            .with_name("my-env")
+            .build_with_custom_paths();
+        let repository = FileRepositoryFactory::new(std::time::Duration::from_secs(30))
+            .create(temp_dir.path().join("data"));
+        repository.save(&env.into_any()).unwrap();
+
+        let store = DeploymentReportStore::for_data_dir(&data_dir);
+
+        (ReportsCommandHandler::new(repository), store, temp_dir)
+    }
+
+    fn env_name() -> EnvironmentName {
+        EnvironmentName::new("my-env".to_string()).unwrap()
+    }
+
+    #[test]
+    fn it_should_show_the_latest_report_when_no_file_name_is_given() {
+        let (handler, store, _temp_dir) = setup();
+        store.write(&report("provision", 1)).unwrap();
+        store.write(&report("configure", 2)).unwrap();
+
+        let latest = handler.show(&env_name(), None).unwrap();
+
+        assert_eq!(latest.report.command, "configure");
+        assert_eq!(latest.file_name, "20260101-120002-configure.json");
+    }
+
+    #[test]
+    fn it_should_fail_to_show_the_latest_report_when_there_are_none() {
+        let (handler, _store, _temp_dir) = setup();
+
+        let result = handler.show(&env_name(), None);
+
+        assert!(matches!(
+            result,
+            Err(ReportsCommandHandlerError::NoReports { .. })
+        ));
+    }
+
+    #[test]
+    fn it_should_refuse_file_names_that_are_paths() {
+        let (handler, store, _temp_dir) = setup();
+        store.write(&report("provision", 1)).unwrap();
+
+        let result = handler.show(&env_name(), Some("../environment.json"));
+
+        assert!(matches!(
+            result,
+            Err(ReportsCommandHandlerError::ReportNotFound { .. })
+        ));
+    }
+
+    #[test]
+    fn it_should_show_the_failed_step_of_a_report_from_the_trace_index() {
+        let (handler, store, temp_dir) = setup();
+        let traces_dir = temp_dir.path().join("traces");
+        std::fs::create_dir_all(&traces_dir).unwrap();
+        let entry = TraceIndexEntry {
+            trace_id: TraceId::new(),
+            command: "provision".to_string(),
+            step: "wait_cloud_init".to_string(),
+            timestamp: Utc.with_ymd_and_hms(2026, 1, 1, 12, 0, 1).unwrap(),
+            file: "20260101-120001-provision.log".to_string(),
+        };
+        write_trace_index(&traces_dir, std::slice::from_ref(&entry)).unwrap();
+        store
+            .write(&DeploymentReport {
+                succeeded: false,
+                trace_id: Some(entry.trace_id.to_string()),
+                trace_file: Some(traces_dir.join(&entry.file)),
+                ..report("provision", 1)
+            })
+            .unwrap();
+
+        let latest = handler.show(&env_name(), None).unwrap();
+
+        assert_eq!(latest.trace, Some(entry));
+    }
+}
@@ -0,0 +1,24 @@
+//! Reports Command Module
+//!
+//! This module implements the delivery-agnostic `ReportsCommandHandler`
+//! for listing and reading the deployment reports of an environment.
+//!
+//! ## Reports
+//!
+//! Every state-changing command (provision, register, configure, release,
+//! run, destroy) writes a `DeploymentReport` to
+//! `data/{env}/reports/{timestamp}-{command}.json` when it finishes,
+//! whether it succeeded or failed.
+//!
+//! ## Design Principles
+//!
+//! - **Read-Only Operation**: Never modifies environment state or reports
+//! - **No Network Calls**: Reads local data only
+//! - **No Reports is a Result**: Listing an environment without reports yields an empty list
+
+pub mod errors;
+pub mod handler;
+
+// Re-export main types for convenience
+pub use errors::ReportsCommandHandlerError;
+pub use handler::ReportsCommandHandler;
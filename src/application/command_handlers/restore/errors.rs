@@ -0,0 +1,314 @@
+//! Error types for restore command handler
+
+use std::path::PathBuf;
+
+use crate::application::errors::{AllowedCommands, PersistenceError};
+use crate::infrastructure::remote_actions::{
+    RemoteTransferError, TrackerDatabaseError, TrackerHealthWaitError,
+};
+use crate::shared::error::kind::ErrorKind;
+use crate::shared::error::traceable::Traceable;
+
+/// Comprehensive error type for the `RestoreCommandHandler`
+#[derive(Debug, thiserror::Error)]
+pub enum RestoreCommandHandlerError {
+    #[error("Environment not found: '{name}'")]
+    EnvironmentNotFound { name: String },
+
+    #[error("Environment '{name}' is in state '{state}', but the tracker must be released or running to restore its database; {allowed_commands}")]
+    InvalidState {
+        name: String,
+        state: String,
+        allowed_commands: AllowedCommands,
+    },
+
+    #[error("Environment '{name}' does not have an instance IP set")]
+    MissingInstanceIp { name: String },
+
+    #[error(
+        "Invalid backup id '{id}': expected the time the backup was taken, e.g. 20261016T143045Z"
+    )]
+    InvalidBackupId { id: String },
+
+    #[error("Backup '{id}' not found for environment '{name}'")]
+    BackupNotFound { name: String, id: String },
+
+    #[error("Failed to read backup '{}': {reason}", path.display())]
+    InvalidBackup { path: PathBuf, reason: String },
+
+    #[error("Backup '{id}' is a {backup_driver} database, but the tracker uses {database_driver}")]
+    DriverMismatch {
+        id: String,
+        backup_driver: String,
+        database_driver: String,
+    },
+
+    #[error(
+        "Backup '{id}' does not match its manifest: expected SHA-256 {expected}, got {actual}"
+    )]
+    ChecksumMismatch {
+        id: String,
+        expected: String,
+        actual: String,
+    },
+
+    #[error("Failed to upload the backup: {0}")]
+    Upload(#[source] RemoteTransferError),
+
+    #[error("Failed to restore the tracker database: {0}")]
+    Restore(#[source] TrackerDatabaseError),
+
+    #[error("The tracker did not report healthy after the restore: {0}")]
+    HealthCheckFailed(#[source] TrackerHealthWaitError),
+
+    #[error("interrupted by user")]
+    Interrupted,
+
+    #[error("Failed to load environment: {0}")]
+    LoadError(#[from] PersistenceError),
+}
+
+impl From<crate::domain::environment::repository::RepositoryError> for RestoreCommandHandlerError {
+    fn from(e: crate::domain::environment::repository::RepositoryError) -> Self {
+        Self::LoadError(e.into())
+    }
+}
+
+impl Traceable for RestoreCommandHandlerError {
+    fn trace_format(&self) -> String {
+        match self {
+            Self::EnvironmentNotFound { name } => {
+                format!("RestoreCommandHandlerError: Environment not found - '{name}'")
+            }
+            Self::InvalidState { name, state, .. } => {
+                format!("RestoreCommandHandlerError: Environment '{name}' is not released or running (state: {state})")
+            }
+            Self::MissingInstanceIp { name } => {
+                format!("RestoreCommandHandlerError: Missing instance IP for environment '{name}'")
+            }
+            Self::InvalidBackupId { id } => {
+                format!("RestoreCommandHandlerError: Invalid backup id - '{id}'")
+            }
+            Self::BackupNotFound { name, id } => {
+                format!("RestoreCommandHandlerError: Backup not found - '{name}/{id}'")
+            }
+            Self::InvalidBackup { path, reason } => {
+                format!(
+                    "RestoreCommandHandlerError: Invalid backup '{}' - {reason}",
+                    path.display()
+                )
+            }
+            Self::DriverMismatch {
+                id,
+                backup_driver,
+                database_driver,
+            } => {
+                format!("RestoreCommandHandlerError: Backup '{id}' is a {backup_driver} database, the tracker uses {database_driver}")
+            }
+            Self::ChecksumMismatch {
+                id,
+                expected,
+                actual,
+            } => {
+                format!("RestoreCommandHandlerError: Checksum mismatch for backup '{id}' - expected {expected}, got {actual}")
+            }
+            Self::Upload(e) => {
+                format!("RestoreCommandHandlerError: Failed to upload the backup - {e}")
+            }
+            Self::Restore(e) => {
+                format!("RestoreCommandHandlerError: Failed to restore the tracker database - {e}")
+            }
+            Self::HealthCheckFailed(e) => {
+                format!("RestoreCommandHandlerError: Tracker not healthy after the restore - {e}")
+            }
+            Self::Interrupted => {
+                "RestoreCommandHandlerError: Interrupted by user (Ctrl-C)".to_string()
+            }
+            Self::LoadError(e) => {
+                format!("RestoreCommandHandlerError: Failed to load environment - {e}")
+            }
+        }
+    }
+
+    fn trace_source(&self) -> Option<&dyn Traceable> {
+        match self {
+            Self::Upload(e) => Some(e),
+            Self::Restore(e) => Some(e),
+            Self::HealthCheckFailed(e) => Some(e),
+            Self::EnvironmentNotFound { .. }
+            | Self::InvalidState { .. }
+            | Self::MissingInstanceIp { .. }
+            | Self::InvalidBackupId { .. }
+            | Self::BackupNotFound { .. }
+            | Self::InvalidBackup { .. }
+            | Self::DriverMismatch { .. }
+            | Self::ChecksumMismatch { .. }
+            | Self::Interrupted
+            | Self::LoadError(_) => None,
+        }
+    }
+
+    fn error_kind(&self) -> ErrorKind {
+        match self {
+            Self::EnvironmentNotFound { .. }
+            | Self::InvalidState { .. }
+            | Self::MissingInstanceIp { .. }
+            | Self::InvalidBackupId { .. }
+            | Self::BackupNotFound { .. }
+            | Self::DriverMismatch { .. } => ErrorKind::Configuration,
+            Self::InvalidBackup { .. } | Self::ChecksumMismatch { .. } => ErrorKind::FileSystem,
+            Self::Upload(e) => e.error_kind(),
+            Self::Restore(e) => e.error_kind(),
+            Self::HealthCheckFailed(e) => e.error_kind(),
+            Self::Interrupted => ErrorKind::Interrupted,
+            Self::LoadError(_) => ErrorKind::StatePersistence,
+        }
+    }
+}
+
+impl RestoreCommandHandlerError {
+    /// Provides detailed troubleshooting guidance for this error
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use torrust_tracker_deployer_lib::application::command_handlers::restore::RestoreCommandHandlerError;
+    ///
+    /// let error = RestoreCommandHandlerError::BackupNotFound {
+    ///     name: "my-env".to_string(),
+    ///     id: "20261016T143045Z".to_string(),
+    /// };
+    ///
+    /// assert!(error.help().contains("data/<env-name>/backups"));
+    /// ```
+    #[must_use]
+    pub fn help(&self) -> &'static str {
+        match self {
+            Self::EnvironmentNotFound { .. } => {
+                "Environment Not Found - Troubleshooting:
+
+1. Verify the environment name is correct
+2. List the existing environments:
+   torrust-tracker-deployer list
+
+For more information, see docs/user-guide/commands/restore.md"
+            }
+            Self::InvalidState { .. } => {
+                "Invalid Environment State - Troubleshooting:
+
+A backup is restored into a deployed tracker, so the environment must be in
+the 'released' or 'running' state.
+
+1. Check the environment state:
+   torrust-tracker-deployer show <env-name>
+
+2. Complete the deployment workflow up to the release:
+   torrust-tracker-deployer release <env-name>
+
+For more information, see docs/user-guide/commands/restore.md"
+            }
+            Self::MissingInstanceIp { .. } => {
+                "Missing Instance IP - Troubleshooting:
+
+The environment state has no instance IP, so the instance cannot be reached.
+
+1. Check the environment details:
+   torrust-tracker-deployer show <env-name>
+
+For more information, see docs/user-guide/commands/restore.md"
+            }
+            Self::InvalidBackupId { .. } | Self::BackupNotFound { .. } => {
+                "Backup Not Found - Troubleshooting:
+
+Backups are selected by their id, the UTC time they were taken, which is
+the name of their directory.
+
+1. List the backups of the environment:
+   ls data/<env-name>/backups
+
+2. Pass the id of one of them:
+   torrust-tracker-deployer restore <env-name> --from 20261016T143045Z
+
+For more information, see docs/user-guide/commands/restore.md"
+            }
+            Self::InvalidBackup { .. } | Self::ChecksumMismatch { .. } => {
+                "Invalid Backup - Troubleshooting:
+
+The backup file or its manifest.json is missing, unreadable, or was modified
+after the backup was taken. Nothing was changed on the instance.
+
+1. Check the backup directory:
+   ls -l data/<env-name>/backups/<id>
+
+2. Restore another backup, or take a new one:
+   torrust-tracker-deployer backup <env-name>
+
+For more information, see docs/user-guide/commands/restore.md"
+            }
+            Self::DriverMismatch { .. } => {
+                "Database Driver Mismatch - Troubleshooting:
+
+The backup was taken from a tracker using another database driver (SQLite or
+MySQL) than the one of the environment. Nothing was changed on the instance.
+
+Restore a backup taken with the current database driver.
+
+For more information, see docs/user-guide/commands/restore.md"
+            }
+            Self::Upload(e) => e.help(),
+            Self::Restore(_) => {
+                "Database Restore Failed - Troubleshooting:
+
+The tracker was started again, but its database may be partially restored.
+
+1. Check SSH connectivity to the instance:
+   ssh -i <ssh-key> <user>@<instance-ip>
+
+2. Check that the containers are up:
+   ssh <user>@<instance-ip> 'cd /opt/torrust && docker compose ps'
+
+3. Run the restore again once the cause is fixed
+
+For more information, see docs/user-guide/commands/restore.md"
+            }
+            Self::HealthCheckFailed(_) => {
+                "Tracker Not Healthy After Restore - Troubleshooting:
+
+The database was restored and the tracker started, but its Health Check API
+did not report healthy in time.
+
+1. Check the tracker logs on the instance:
+   ssh <user>@<instance-ip> 'docker logs tracker'
+
+2. Restore another backup if the database is not usable by this tracker
+   version
+
+For more information, see docs/user-guide/commands/restore.md"
+            }
+            Self::Interrupted => {
+                "Restore Interrupted - Troubleshooting:
+
+The restore was stopped with Ctrl-C. When it was stopped during the upload
+the tracker database was not changed; otherwise the database was restored
+but the tracker was not yet reported healthy.
+
+1. Check the state of the containers:
+   ssh <user>@<instance-ip> 'cd /opt/torrust && docker compose ps'
+
+2. Run the restore again
+
+For more information, see docs/user-guide/commands/restore.md"
+            }
+            Self::LoadError(_) => {
+                "Failed to Load Environment - Troubleshooting:
+
+1. Check that the environment state file is readable:
+   cat data/<env-name>/environment.json
+
+2. Check that no other command is running on the environment
+
+For more information, see docs/user-guide/commands/restore.md"
+            }
+        }
+    }
+}
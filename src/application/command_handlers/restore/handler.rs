@@ -0,0 +1,322 @@
+//! Restore command handler implementation
+//!
+//! **Purpose**: Restore the tracker database of a deployed environment from a backup
+//!
+//! This handler verifies a backup taken by the `backup` command against its
+//! manifest, uploads it to the instance, replaces the tracker database with
+//! it and waits for the tracker to report healthy again.
+
+use std::fs::File;
+use std::path::Path;
+use std::sync::Arc;
+
+use sha2::{Digest, Sha256};
+use tokio_util::sync::CancellationToken;
+use tracing::{info, instrument};
+
+use super::errors::RestoreCommandHandlerError;
+use crate::application::command_handlers::backup::handler::{ssh_config, REMOTE_BACKUP_DIR};
+use crate::application::errors::AllowedCommands;
+use crate::domain::environment::repository::EnvironmentRepository;
+use crate::domain::environment::state::AnyEnvironmentState;
+use crate::domain::environment::{DatabaseBackup, BACKUP_MANIFEST_FILE_NAME};
+use crate::domain::EnvironmentName;
+use crate::infrastructure::remote_actions::{
+    RemoteFileTransfer, TrackerDatabase, TrackerHealthWaiter,
+};
+
+/// `RestoreCommandHandler` restores the tracker database of an environment
+///
+/// The environment must be `Released` or `Running`. Its state is not
+/// changed: a `Released` tracker is started by the restore, as the
+/// database is only replaced while the tracker is stopped.
+pub struct RestoreCommandHandler {
+    repository: Arc<dyn EnvironmentRepository + Send + Sync>,
+    cancellation: CancellationToken,
+}
+
+impl RestoreCommandHandler {
+    /// Create a new `RestoreCommandHandler`
+    #[must_use]
+    pub fn new(repository: Arc<dyn EnvironmentRepository + Send + Sync>) -> Self {
+        Self {
+            repository,
+            cancellation: CancellationToken::new(),
+        }
+    }
+
+    /// Stop the restore when the given token is cancelled (e.g. on Ctrl-C)
+    ///
+    /// A restore cancelled during the upload leaves the database untouched;
+    /// once the database is replaced, only the health wait is cut short.
+    #[must_use]
+    pub fn with_cancellation(mut self, cancellation: CancellationToken) -> Self {
+        self.cancellation = cancellation;
+        self
+    }
+
+    /// Restore the tracker database of an environment from the backup `id`
+    ///
+    /// Returns the restored backup. Nothing is changed on the instance when
+    /// the backup does not match its manifest or was taken from another
+    /// database driver.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if:
+    /// * Environment not found
+    /// * Environment is not `Released` or `Running`
+    /// * The backup does not exist, or does not match its manifest
+    /// * The backup was taken from another database driver
+    /// * The backup cannot be uploaded or restored
+    /// * The tracker does not report healthy after the restore
+    /// * The restore is interrupted (Ctrl-C)
+    #[instrument(
+        name = "command.restore",
+        skip_all,
+        fields(
+            command_type = "restore",
+            environment_name = %env_name,
+            backup_id = %id
+        )
+    )]
+    pub fn execute(
+        &self,
+        env_name: &EnvironmentName,
+        id: &str,
+    ) -> Result<DatabaseBackup, RestoreCommandHandlerError> {
+        let _lock = self.repository.lock(env_name)?;
+        let environment = self.repository.load(env_name)?.ok_or_else(|| {
+            RestoreCommandHandlerError::EnvironmentNotFound {
+                name: env_name.to_string(),
+            }
+        })?;
+
+        Self::check_state(&environment)?;
+
+        if !DatabaseBackup::is_valid_id(id) {
+            return Err(RestoreCommandHandlerError::InvalidBackupId { id: id.to_string() });
+        }
+
+        let backup_dir = environment.backups_dir().join(id);
+        if !backup_dir.is_dir() {
+            return Err(RestoreCommandHandlerError::BackupNotFound {
+                name: env_name.to_string(),
+                id: id.to_string(),
+            });
+        }
+
+        let backup = read_manifest(&backup_dir)?;
+        let database = environment.tracker_config().core().database().clone();
+        if backup.driver != database.driver_name() {
+            return Err(RestoreCommandHandlerError::DriverMismatch {
+                id: backup.id,
+                backup_driver: backup.driver,
+                database_driver: database.driver_name().to_string(),
+            });
+        }
+
+        let backup_file = backup_dir.join(&backup.file);
+        let actual = sha256_of(&backup_file)?;
+        if actual != backup.sha256 {
+            return Err(RestoreCommandHandlerError::ChecksumMismatch {
+                id: backup.id,
+                expected: backup.sha256,
+                actual,
+            });
+        }
+
+        let instance_ip = environment.instance_ip().ok_or_else(|| {
+            RestoreCommandHandlerError::MissingInstanceIp {
+                name: env_name.to_string(),
+            }
+        })?;
+        let ssh_config = ssh_config(&environment, instance_ip);
+        let tracker_database = TrackerDatabase::new(
+            ssh_config.clone(),
+            database,
+            environment.tracker_config().replica_names(),
+        );
+        let remote_path = format!("{REMOTE_BACKUP_DIR}/{}/{}", backup.id, backup.file);
+
+        let uploaded = RemoteFileTransfer::new(ssh_config.clone()).upload_file(
+            &backup_file,
+            &remote_path,
+            &|_| {},
+        );
+        // The upload may have been killed by the same Ctrl-C
+        if self.cancellation.is_cancelled() {
+            tracker_database.remove(&remote_path);
+            return Err(RestoreCommandHandlerError::Interrupted);
+        }
+        uploaded.map_err(RestoreCommandHandlerError::Upload)?;
+
+        let restored = tracker_database
+            .restore(&remote_path)
+            .map_err(RestoreCommandHandlerError::Restore);
+        tracker_database.remove(&remote_path);
+        restored?;
+
+        let health_check_api = environment.tracker_config().health_check_api();
+        TrackerHealthWaiter::new(
+            ssh_config,
+            health_check_api.bind_address(),
+            *health_check_api.wait(),
+            environment.tracker_config().replica_names(),
+        )
+        .with_cancellation(self.cancellation.clone())
+        .wait()
+        .map_err(|e| {
+            if e.is_interrupted() {
+                RestoreCommandHandlerError::Interrupted
+            } else {
+                RestoreCommandHandlerError::HealthCheckFailed(e)
+            }
+        })?;
+
+        info!(
+            command = "restore",
+            environment = %env_name,
+            backup_id = %backup.id,
+            "Tracker database restored"
+        );
+
+        Ok(backup)
+    }
+
+    /// Check the environment is `Released` or `Running`
+    fn check_state(environment: &AnyEnvironmentState) -> Result<(), RestoreCommandHandlerError> {
+        if matches!(
+            environment,
+            AnyEnvironmentState::Released(_) | AnyEnvironmentState::Running(_)
+        ) {
+            return Ok(());
+        }
+
+        Err(RestoreCommandHandlerError::InvalidState {
+            name: environment.name().to_string(),
+            state: environment.state_name().to_string(),
+            allowed_commands: AllowedCommands::from(environment),
+        })
+    }
+}
+
+/// Read the manifest of the backup in `backup_dir`
+fn read_manifest(backup_dir: &Path) -> Result<DatabaseBackup, RestoreCommandHandlerError> {
+    let path = backup_dir.join(BACKUP_MANIFEST_FILE_NAME);
+    let invalid = |reason: String| RestoreCommandHandlerError::InvalidBackup {
+        path: path.clone(),
+        reason,
+    };
+
+    let content = std::fs::read_to_string(&path).map_err(|e| invalid(e.to_string()))?;
+    serde_json::from_str(&content).map_err(|e| invalid(e.to_string()))
+}
+
+/// SHA-256 checksum of a local file, in lowercase hex
+fn sha256_of(path: &Path) -> Result<String, RestoreCommandHandlerError> {
+    let invalid = |e: std::io::Error| RestoreCommandHandlerError::InvalidBackup {
+        path: path.to_path_buf(),
+        reason: e.to_string(),
+    };
+
+    let mut file = File::open(path).map_err(invalid)?;
+    let mut hasher = Sha256::new();
+    std::io::copy(&mut file, &mut hasher).map_err(invalid)?;
+    Ok(format!("{:x}", hasher.finalize()))
+}
+
+#[cfg(test)]
+mod tests {
+    use std::net::{IpAddr, Ipv4Addr};
+
+    use chrono::{TimeZone, Utc};
+
+    use super::*;
+    use crate::domain::environment::testing::EnvironmentTestBuilder;
+    use crate::infrastructure::persistence::file_repository_factory::FileRepositoryFactory;
+
+    /// Handler with `my-env` saved in the `Released` state
+    fn setup() -> (RestoreCommandHandler, std::path::PathBuf, tempfile::TempDir) {
+        let (env, _data_dir, _build_dir, temp_dir) = EnvironmentTestBuilder::new()
+            .with_name("my-env")
+            .build_with_custom_paths();
+        let backups_dir = env.backups_dir();
+        let env = env
+            .import(IpAddr::V4(Ipv4Addr::new(10, 0, 0, 1)))
+            .start_configuring()
+            .configured()
+            .start_releasing()
+            .released();
+        let repository = FileRepositoryFactory::new(std::time::Duration::from_secs(30))
+            .create(temp_dir.path().join("data"));
+        repository.save(&env.into_any()).unwrap();
+
+        (
+            RestoreCommandHandler::new(repository),
+            backups_dir,
+            temp_dir,
+        )
+    }
+
+    /// Write a backup of `content` with the manifest checksum `sha256`
+    fn write_backup(backups_dir: &Path, content: &[u8], sha256: &str) {
+        let backup = DatabaseBackup {
+            id: "20261016T143045Z".to_string(),
+            created_at: Utc.with_ymd_and_hms(2026, 10, 16, 14, 30, 45).unwrap(),
+            driver: "sqlite3".to_string(),
+            file: "tracker.db".to_string(),
+            size: content.len() as u64,
+            sha256: sha256.to_string(),
+        };
+        let backup_dir = backups_dir.join(&backup.id);
+        std::fs::create_dir_all(&backup_dir).unwrap();
+        std::fs::write(backup_dir.join(&backup.file), content).unwrap();
+        std::fs::write(
+            backup_dir.join(BACKUP_MANIFEST_FILE_NAME),
+            serde_json::to_string(&backup).unwrap(),
+        )
+        .unwrap();
+    }
+
+    fn my_env() -> EnvironmentName {
+        EnvironmentName::new("my-env".to_string()).unwrap()
+    }
+
+    #[test]
+    fn it_should_refuse_a_backup_id_that_is_not_a_timestamp() {
+        let (handler, _backups_dir, _temp_dir) = setup();
+
+        let error = handler.execute(&my_env(), "../other-env").unwrap_err();
+
+        assert!(matches!(
+            error,
+            RestoreCommandHandlerError::InvalidBackupId { .. }
+        ));
+    }
+
+    #[test]
+    fn it_should_report_a_missing_backup() {
+        let (handler, _backups_dir, _temp_dir) = setup();
+
+        let error = handler.execute(&my_env(), "20261016T143045Z").unwrap_err();
+
+        assert!(matches!(
+            error,
+            RestoreCommandHandlerError::BackupNotFound { .. }
+        ));
+    }
+
+    #[test]
+    fn it_should_refuse_a_backup_that_does_not_match_its_manifest_checksum() {
+        let (handler, backups_dir, _temp_dir) = setup();
+        write_backup(&backups_dir, b"SQLite format 3\0", &"0".repeat(64));
+
+        let error = handler.execute(&my_env(), "20261016T143045Z").unwrap_err();
+
+        assert!(matches!(
+            error,
+            RestoreCommandHandlerError::ChecksumMismatch { ref expected, .. } if expected == &"0".repeat(64)
+        ));
+    }
+}
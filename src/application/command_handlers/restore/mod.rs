@@ -0,0 +1,22 @@
+//! Restore Command Module
+//!
+//! This module implements the delivery-agnostic `RestoreCommandHandler`
+//! for restoring the tracker database of a `Released` or `Running`
+//! environment from a backup taken by the `backup` command.
+//!
+//! ## Design Principles
+//!
+//! - **Verify First**: The backup is checked against its manifest (SHA-256
+//!   checksum, database driver) before anything changes on the instance
+//! - **Verified Upload**: The backup is uploaded with checksum verification
+//! - **Tracker Restarted**: The tracker is stopped while the database is
+//!   replaced and started again even when the restore fails
+//! - **Health Checked**: The restore only succeeds once the tracker reports
+//!   healthy again
+
+pub mod errors;
+pub mod handler;
+
+// Re-export main types for convenience
+pub use errors::RestoreCommandHandlerError;
+pub use handler::RestoreCommandHandler;
@@ -0,0 +1,198 @@
+//! Error types for the Rollback command handler
+
+use crate::application::command_handlers::release::ReleaseCommandHandlerError;
+use crate::application::errors::{AllowedCommands, PersistenceError};
+use crate::shared::error::kind::ErrorKind;
+use crate::shared::error::traceable::Traceable;
+
+/// Errors that can occur while rolling back a release
+#[derive(Debug, thiserror::Error)]
+pub enum RollbackCommandHandlerError {
+    /// Environment was not found in the repository
+    #[error("Environment not found: {name}")]
+    EnvironmentNotFound {
+        /// The name of the environment that was not found
+        name: String,
+    },
+
+    /// Environment is not in `Released` or `Running` state
+    #[error("Environment '{name}' is in '{current_state}' state; rollback requires a released environment; {allowed_commands}")]
+    InvalidState {
+        /// The name of the environment
+        name: String,
+        /// The actual state of the environment
+        current_state: String,
+        /// The commands allowed in the actual state
+        allowed_commands: AllowedCommands,
+    },
+
+    /// The release history holds no release before the current one
+    #[error("Environment '{name}' has no previous release to roll back to")]
+    NoPreviousRelease {
+        /// The name of the environment
+        name: String,
+    },
+
+    /// Redeploying the previous release failed
+    #[error("Rollback release failed: {0}")]
+    Release(#[from] ReleaseCommandHandlerError),
+
+    /// Failed to persist environment state
+    #[error("Failed to persist environment state: {0}")]
+    StatePersistence(#[from] PersistenceError),
+}
+
+impl From<crate::domain::environment::repository::RepositoryError> for RollbackCommandHandlerError {
+    fn from(e: crate::domain::environment::repository::RepositoryError) -> Self {
+        Self::StatePersistence(e.into())
+    }
+}
+
+impl Traceable for RollbackCommandHandlerError {
+    fn trace_format(&self) -> String {
+        match self {
+            Self::EnvironmentNotFound { name } => {
+                format!("RollbackCommandHandlerError: Environment not found - '{name}'")
+            }
+            Self::InvalidState {
+                name,
+                current_state,
+                ..
+            } => {
+                format!(
+                    "RollbackCommandHandlerError: Invalid state - '{name}' is in '{current_state}'"
+                )
+            }
+            Self::NoPreviousRelease { name } => {
+                format!("RollbackCommandHandlerError: No previous release - '{name}'")
+            }
+            Self::Release(e) => format!("RollbackCommandHandlerError: Release failed - {e}"),
+            Self::StatePersistence(e) => {
+                format!("RollbackCommandHandlerError: State persistence failed - {e}")
+            }
+        }
+    }
+
+    fn trace_source(&self) -> Option<&dyn Traceable> {
+        match self {
+            Self::Release(e) => Some(e),
+            _ => None,
+        }
+    }
+
+    fn error_kind(&self) -> ErrorKind {
+        match self {
+            Self::EnvironmentNotFound { .. }
+            | Self::InvalidState { .. }
+            | Self::NoPreviousRelease { .. } => ErrorKind::Configuration,
+            Self::Release(e) => e.error_kind(),
+            Self::StatePersistence(_) => ErrorKind::StatePersistence,
+        }
+    }
+}
+
+impl RollbackCommandHandlerError {
+    /// Provides detailed troubleshooting guidance for this error
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use torrust_tracker_deployer_lib::application::command_handlers::rollback::RollbackCommandHandlerError;
+    ///
+    /// let error = RollbackCommandHandlerError::NoPreviousRelease {
+    ///     name: "my-env".to_string(),
+    /// };
+    ///
+    /// assert!(error.help().contains("No Previous Release"));
+    /// ```
+    #[must_use]
+    pub fn help(&self) -> &'static str {
+        match self {
+            Self::EnvironmentNotFound { .. } => {
+                "Environment Not Found - Troubleshooting:
+
+1. Verify the environment name is correct
+2. Check if the environment was created:
+   ls data/
+
+Common causes:
+- Typo in environment name
+- Environment was destroyed
+- Working in the wrong directory
+
+For more information, see docs/user-guide/commands/rollback.md"
+            }
+            Self::InvalidState { .. } => {
+                "Invalid Environment State - Troubleshooting:
+
+1. The rollback command requires the environment to be in Released or
+   Running state
+2. Check the current environment state:
+   cargo run -- show <env-name>
+
+3. If a release failed, roll back from a fresh release or fix the failure
+   and release again
+
+For more information, see docs/user-guide/commands/rollback.md"
+            }
+            Self::NoPreviousRelease { .. } => {
+                "No Previous Release - Troubleshooting:
+
+Rollback redeploys the tracker version of the release before the current
+one. The release history of this environment holds a single release, or none
+if it was released before release history was recorded.
+
+1. Check the release history:
+   cargo run -- show <env-name>
+
+2. To deploy a specific version instead, set 'tracker.version' in the
+   environment configuration and release again
+
+For more information, see docs/user-guide/commands/rollback.md"
+            }
+            Self::Release(inner) => inner.help(),
+            Self::StatePersistence(_) => {
+                "State Persistence Failed - Troubleshooting:
+
+1. Check write permissions on the data directory:
+   ls -la data/<env-name>/
+
+2. Check available disk space:
+   df -h
+
+3. Make sure no other command is running for this environment
+
+For more information, see docs/user-guide/commands/rollback.md"
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn it_should_take_the_kind_of_the_failed_release() {
+        let error = RollbackCommandHandlerError::Release(
+            ReleaseCommandHandlerError::ReleaseOperationFailed {
+                name: "production".to_string(),
+                message: "connection reset".to_string(),
+            },
+        );
+
+        assert_eq!(error.error_kind(), ErrorKind::InfrastructureOperation);
+        assert!(error.is_retryable());
+        assert!(error.trace_source().is_some());
+    }
+
+    #[test]
+    fn it_should_not_retry_a_rollback_without_previous_release() {
+        let error = RollbackCommandHandlerError::NoPreviousRelease {
+            name: "production".to_string(),
+        };
+
+        assert_eq!(error.error_kind(), ErrorKind::Configuration);
+        assert!(!error.is_retryable());
+    }
+}
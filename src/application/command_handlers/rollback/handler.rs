@@ -0,0 +1,158 @@
+//! Rollback command handler implementation
+
+use std::path::PathBuf;
+use std::sync::Arc;
+
+use tracing::{info, instrument};
+
+use super::errors::RollbackCommandHandlerError;
+use crate::application::command_handlers::release::ReleaseCommandHandler;
+use crate::application::errors::AllowedCommands;
+use crate::application::traits::CommandProgressListener;
+use crate::domain::environment::repository::{EnvironmentRepository, TypedEnvironmentRepository};
+use crate::domain::environment::state::AnyEnvironmentState;
+use crate::domain::environment::{DiskSpaceThresholds, Environment, Released};
+use crate::domain::tracker::TrackerVersion;
+use crate::domain::EnvironmentName;
+
+/// `RollbackCommandHandler` redeploys the previously released tracker version
+///
+/// This command handler:
+/// 1. Loads the environment and checks it is `Released` or `Running`
+/// 2. Takes the release before the current one from the release history
+/// 3. Pins the tracker image to that version and runs the release workflow
+/// 4. Removes the rolled back release from the history
+///
+/// # State Management
+///
+/// - Accepts environment in `Released` or `Running` state
+/// - Transitions to `Environment<Releasing>` at start
+/// - Returns `Environment<Released>` on success
+/// - Transitions to `Environment<ReleaseFailed>` on error
+pub struct RollbackCommandHandler {
+    clock: Arc<dyn crate::shared::Clock>,
+    repository: TypedEnvironmentRepository,
+    release_handler: ReleaseCommandHandler,
+}
+
+impl RollbackCommandHandler {
+    /// Create a new `RollbackCommandHandler`
+    #[must_use]
+    pub fn new(
+        repository: Arc<dyn EnvironmentRepository>,
+        clock: Arc<dyn crate::shared::Clock>,
+    ) -> Self {
+        Self {
+            release_handler: ReleaseCommandHandler::new(
+                Arc::clone(&repository),
+                Arc::clone(&clock),
+            ),
+            clock,
+            repository: TypedEnvironmentRepository::new(repository),
+        }
+    }
+
+    /// Keep the Ansible output logs of the last `runs` rollback runs
+    #[must_use]
+    pub fn with_kept_run_logs(mut self, runs: usize) -> Self {
+        self.release_handler = self.release_handler.with_kept_run_logs(runs);
+        self
+    }
+
+    /// Write the trace files of a failed rollback to `trace_dir`
+    #[must_use]
+    pub fn with_trace_dir(mut self, trace_dir: Option<PathBuf>) -> Self {
+        self.release_handler = self.release_handler.with_trace_dir(trace_dir);
+        self
+    }
+
+    /// Require the given free disk space on the instance before releasing
+    #[must_use]
+    pub fn with_disk_space_thresholds(mut self, thresholds: DiskSpaceThresholds) -> Self {
+        self.release_handler = self.release_handler.with_disk_space_thresholds(thresholds);
+        self
+    }
+
+    /// Execute the rollback workflow
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if:
+    /// * Environment not found
+    /// * Environment is not in `Released` or `Running` state
+    /// * The release history has no previous release
+    /// * The release workflow fails
+    /// * State persistence fails
+    #[instrument(
+        name = "command.rollback",
+        skip_all,
+        fields(
+            command_type = "rollback",
+            environment_name = %env_name
+        )
+    )]
+    pub async fn execute(
+        &self,
+        env_name: &EnvironmentName,
+        listener: Option<&dyn CommandProgressListener>,
+    ) -> Result<Environment<Released>, RollbackCommandHandlerError> {
+        let _lock = self.repository.inner().lock(env_name)?;
+        let any_env = self.repository.inner().load(env_name)?.ok_or_else(|| {
+            RollbackCommandHandlerError::EnvironmentNotFound {
+                name: env_name.to_string(),
+            }
+        })?;
+
+        let started_at = self.clock.now();
+
+        let releasing_env = match any_env {
+            AnyEnvironmentState::Released(env) => {
+                let target = previous_version(&env, env_name)?;
+                env.start_rollback(target)
+            }
+            AnyEnvironmentState::Running(env) => {
+                let target = previous_version(&env, env_name)?;
+                env.start_rollback(target)
+            }
+            other => {
+                return Err(RollbackCommandHandlerError::InvalidState {
+                    name: env_name.to_string(),
+                    current_state: other.state_name().to_string(),
+                    allowed_commands: AllowedCommands::from(&other),
+                })
+            }
+        };
+
+        info!(
+            command = "rollback",
+            environment = %env_name,
+            target_version = %releasing_env.tracker_config().effective_version(),
+            "Rolling back to the previous release"
+        );
+
+        let released = self
+            .release_handler
+            .release_environment(releasing_env, "rollback", started_at, listener)
+            .await?
+            .rollback_completed();
+
+        self.repository.save_released(&released)?;
+
+        Ok(released)
+    }
+}
+
+/// Tracker version of the release before the current one
+fn previous_version<S>(
+    environment: &Environment<S>,
+    env_name: &EnvironmentName,
+) -> Result<TrackerVersion, RollbackCommandHandlerError> {
+    environment
+        .context()
+        .runtime_outputs
+        .previous_release()
+        .map(|release| release.version.clone())
+        .ok_or_else(|| RollbackCommandHandlerError::NoPreviousRelease {
+            name: env_name.to_string(),
+        })
+}
@@ -0,0 +1,33 @@
+//! Rollback Command Module
+//!
+//! This module implements the delivery-agnostic `RollbackCommandHandler`
+//! for redeploying the previously released tracker version.
+//!
+//! ## Release History
+//!
+//! Every successful `release` records the deployed tracker image version in
+//! the environment's release history. Rollback pins the tracker configuration
+//! to the release before the current one and runs the release workflow again,
+//! then removes the rolled back release from the history.
+//!
+//! ## State Management
+//!
+//! - Accepts environment in `Released` or `Running` state
+//! - Transitions to `Environment<Releasing>` at start
+//! - Returns `Environment<Released>` on success; `run` starts the services again
+//! - Transitions to `Environment<ReleaseFailed>` on error
+//!
+//! ## Module Organization
+//!
+//! - `handler.rs` - Core handler with `execute()`
+//! - `errors.rs` - Error types for rollback operations
+
+pub mod errors;
+pub mod handler;
+
+#[cfg(test)]
+mod tests;
+
+// Re-export main types for convenience
+pub use errors::RollbackCommandHandlerError;
+pub use handler::RollbackCommandHandler;
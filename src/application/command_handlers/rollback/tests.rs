@@ -0,0 +1,59 @@
+//! Test module for Rollback Command
+
+use std::sync::Arc;
+
+use chrono::Utc;
+use tempfile::TempDir;
+
+use super::errors::RollbackCommandHandlerError;
+use super::handler::RollbackCommandHandler;
+use crate::domain::environment::repository::EnvironmentRepository;
+use crate::domain::environment::testing::EnvironmentTestBuilder;
+use crate::domain::EnvironmentName;
+use crate::infrastructure::persistence::filesystem::file_environment_repository::FileEnvironmentRepository;
+use crate::testing::mock_clock::MockClock;
+
+fn create_test_handler() -> (
+    RollbackCommandHandler,
+    Arc<FileEnvironmentRepository>,
+    TempDir,
+) {
+    let temp_dir = TempDir::new().expect("Failed to create temp directory");
+    let clock = Arc::new(MockClock::new(Utc::now()));
+    let repository = Arc::new(FileEnvironmentRepository::new(
+        temp_dir.path().to_path_buf(),
+    ));
+    let handler = RollbackCommandHandler::new(repository.clone(), clock);
+    (handler, repository, temp_dir)
+}
+
+#[tokio::test]
+async fn it_should_return_environment_not_found_error_when_environment_does_not_exist() {
+    let (handler, _repository, _temp_dir) = create_test_handler();
+    let env_name = EnvironmentName::new("nonexistent-env").unwrap();
+
+    let result = handler.execute(&env_name, None).await;
+
+    assert!(matches!(
+        result,
+        Err(RollbackCommandHandlerError::EnvironmentNotFound { .. })
+    ));
+}
+
+#[tokio::test]
+async fn it_should_reject_an_environment_that_was_never_released() {
+    let (handler, repository, _temp_dir) = create_test_handler();
+    let (environment, _data_dir, _build_dir, _env_temp_dir) = EnvironmentTestBuilder::new()
+        .with_name("not-released")
+        .build_with_custom_paths();
+    repository
+        .save(&environment.clone().into_any())
+        .expect("Failed to save environment");
+
+    let result = handler.execute(environment.name(), None).await;
+
+    assert!(matches!(
+        result,
+        Err(RollbackCommandHandlerError::InvalidState { current_state, .. }) if current_state == "created"
+    ));
+}
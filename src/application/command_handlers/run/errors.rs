@@ -1,7 +1,9 @@
 //! Error types for the Run command handler
 
+use crate::adapters::ssh::SshError;
 use crate::application::errors::{InvalidStateError, PersistenceError};
 use crate::application::steps::application::StartServicesStepError;
+use crate::infrastructure::remote_actions::{TrackerHealthResponse, TrackerHealthWaitError};
 use crate::shared::error::{ErrorKind, Traceable};
 
 /// Comprehensive error type for the `RunCommandHandler`
@@ -28,6 +30,10 @@ pub enum RunCommandHandlerError {
         name: String,
     },
 
+    /// The host keys of the instance could not be checked or have changed
+    #[error("SSH host key check failed: {0}")]
+    HostKeyCheck(#[from] SshError),
+
     /// Environment is in an invalid state for running
     #[error("Environment is in an invalid state for running: {0}")]
     InvalidState(#[from] InvalidStateError),
@@ -46,6 +52,21 @@ pub enum RunCommandHandlerError {
         source: StartServicesStepError,
     },
 
+    /// The tracker did not report healthy after the services were started
+    ///
+    /// The message is kept short because it is shown as the failure summary
+    /// of the `run_failed` state; the last response is in the source error.
+    #[error("health check never became healthy")]
+    HealthCheckFailed {
+        /// The underlying wait error with the last response
+        #[source]
+        source: TrackerHealthWaitError,
+    },
+
+    /// The run was stopped with Ctrl-C
+    #[error("interrupted by user")]
+    Interrupted,
+
     /// Run operation failed
     #[error("Run operation failed for environment '{name}': {message}")]
     RunOperationFailed {
@@ -79,6 +100,9 @@ impl Traceable for RunCommandHandlerError {
                     "RunCommandHandlerError: Instance IP not available for environment '{name}'"
                 )
             }
+            Self::HostKeyCheck(e) => {
+                format!("RunCommandHandlerError: SSH host key check failed - {e}")
+            }
             Self::InvalidState(e) => {
                 format!("RunCommandHandlerError: Invalid state for run - {e}")
             }
@@ -88,6 +112,10 @@ impl Traceable for RunCommandHandlerError {
             Self::StartServicesFailed { message, .. } => {
                 format!("RunCommandHandlerError: Start services failed - {message}")
             }
+            Self::HealthCheckFailed { source } => {
+                format!("RunCommandHandlerError: Health check never became healthy - {source}")
+            }
+            Self::Interrupted => "RunCommandHandlerError: Interrupted by user (Ctrl-C)".to_string(),
             Self::RunOperationFailed { name, message } => {
                 format!("RunCommandHandlerError: Run operation failed for '{name}' - {message}")
             }
@@ -97,10 +125,13 @@ impl Traceable for RunCommandHandlerError {
     fn trace_source(&self) -> Option<&dyn Traceable> {
         match self {
             Self::StartServicesFailed { source, .. } => Some(source),
+            Self::HealthCheckFailed { source } => Some(source),
+            Self::HostKeyCheck(e) => Some(e),
             Self::StatePersistence(_)
             | Self::EnvironmentNotFound { .. }
             | Self::MissingInstanceIp { .. }
             | Self::InvalidState(_)
+            | Self::Interrupted
             | Self::RunOperationFailed { .. } => None,
         }
     }
@@ -112,12 +143,24 @@ impl Traceable for RunCommandHandlerError {
             | Self::InvalidState(_) => ErrorKind::Configuration,
             Self::StatePersistence(_) => ErrorKind::StatePersistence,
             Self::StartServicesFailed { source, .. } => source.error_kind(),
+            Self::HostKeyCheck(e) => e.error_kind(),
+            Self::HealthCheckFailed { source } => source.error_kind(),
+            Self::Interrupted => ErrorKind::Interrupted,
             Self::RunOperationFailed { .. } => ErrorKind::InfrastructureOperation,
         }
     }
 }
 
 impl RunCommandHandlerError {
+    /// Last Health Check API response, if the tracker never became healthy
+    #[must_use]
+    pub fn last_health_check(&self) -> Option<&TrackerHealthResponse> {
+        match self {
+            Self::HealthCheckFailed { source } => source.last_response(),
+            _ => None,
+        }
+    }
+
     /// Provides detailed troubleshooting guidance for this error
     ///
     /// Returns context-specific help text that guides users toward resolving
@@ -159,6 +202,7 @@ Common causes:
 
 For more information, see docs/user-guide/commands.md"
             }
+            Self::HostKeyCheck(e) => e.help(),
             Self::MissingInstanceIp { .. } => {
                 "Missing Instance IP Address - Troubleshooting:
 
@@ -216,6 +260,50 @@ State files are stored in: data/<env-name>/
 If the problem persists, report it with full system details."
             }
             Self::StartServicesFailed { source, .. } => source.help(),
+            Self::HealthCheckFailed { .. } => {
+                "Health Check Never Became Healthy - Troubleshooting:
+
+The services were started, but the tracker Health Check API did not answer
+the expected status before the timeout. The last response is shown above.
+
+1. Check whether the tracker container keeps restarting:
+   ssh <user>@<instance-ip> docker ps -a
+
+2. Read the tracker logs, which report configuration errors on startup:
+   ssh <user>@<instance-ip> docker logs tracker
+
+3. Review the generated tracker configuration:
+   build/<env-name>/tracker/tracker.toml
+
+4. If the tracker only needs longer to start, raise the timeout in the
+   environment configuration:
+   \"health_check_api\": { \"wait\": { \"timeout_secs\": 120 } }
+
+5. After fixing the cause, release and run the environment again
+
+Common causes:
+- Invalid tracker.toml (e.g. a bad tracker override)
+- Database not reachable by the tracker
+- Tracker image failing to start on the instance
+
+For more information, see docs/user-guide/commands/run.md"
+            }
+            Self::Interrupted => {
+                "Run Interrupted - Troubleshooting:
+
+The run was stopped with Ctrl-C before the tracker reported healthy. The
+environment was saved in 'RunFailed' state; the services may be running.
+
+1. Check the current environment state:
+   torrust-tracker-deployer show <env-name>
+
+2. Check the state of the containers:
+   ssh <user>@<instance-ip> docker ps -a
+
+3. Release and run the environment again to complete the deployment
+
+For more information, see docs/user-guide/commands/run.md"
+            }
             Self::RunOperationFailed { .. } => {
                 "Run Operation Failed - Troubleshooting:
 
@@ -246,6 +334,7 @@ For more information, see docs/user-guide/commands.md"
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::application::errors::AllowedCommands;
     use crate::shared::command::CommandError;
 
     #[test]
@@ -275,6 +364,7 @@ mod tests {
         let error = RunCommandHandlerError::InvalidState(InvalidStateError {
             expected: "Released".to_string(),
             actual: "Configured".to_string(),
+            allowed_commands: AllowedCommands::default(),
         });
 
         let help = error.help();
@@ -312,6 +402,37 @@ mod tests {
         assert!(help.contains("release"));
     }
 
+    fn health_check_failed_error() -> RunCommandHandlerError {
+        RunCommandHandlerError::HealthCheckFailed {
+            source: TrackerHealthWaitError::NeverHealthy {
+                container: "tracker".to_string(),
+                expected_status: 200,
+                timeout_secs: 60,
+                last_response: TrackerHealthResponse::Status(500),
+            },
+        }
+    }
+
+    #[test]
+    fn it_should_summarize_a_health_check_failure_in_a_short_message() {
+        let error = health_check_failed_error();
+
+        assert_eq!(error.to_string(), "health check never became healthy");
+        assert_eq!(
+            error.last_health_check(),
+            Some(&TrackerHealthResponse::Status(500))
+        );
+        assert_eq!(error.error_kind(), ErrorKind::Timeout);
+    }
+
+    #[test]
+    fn it_should_provide_help_for_health_check_failed() {
+        let help = health_check_failed_error().help();
+
+        assert!(help.contains("Health Check Never Became Healthy"));
+        assert!(help.contains("docker logs tracker"));
+    }
+
     #[test]
     fn it_should_provide_help_for_run_operation_failed() {
         let error = RunCommandHandlerError::RunOperationFailed {
@@ -342,6 +463,7 @@ mod tests {
             RunCommandHandlerError::InvalidState(InvalidStateError {
                 expected: "Released".to_string(),
                 actual: "Configured".to_string(),
+                allowed_commands: AllowedCommands::default(),
             }),
             RunCommandHandlerError::StatePersistence(PersistenceError::NotFound),
             RunCommandHandlerError::StartServicesFailed {
@@ -351,6 +473,7 @@ mod tests {
                     source: cmd_error,
                 },
             },
+            health_check_failed_error(),
             RunCommandHandlerError::RunOperationFailed {
                 name: "test".to_string(),
                 message: "error".to_string(),
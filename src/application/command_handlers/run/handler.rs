@@ -1,19 +1,27 @@
 //! Run command handler implementation
 
-use std::net::IpAddr;
+use std::net::{IpAddr, SocketAddr};
+use std::path::PathBuf;
 use std::sync::Arc;
 
+use tokio_util::sync::CancellationToken;
 use tracing::{error, info, instrument};
 
 use super::errors::RunCommandHandlerError;
 use crate::adapters::ansible::AnsibleClient;
-use crate::application::command_handlers::common::StepResult;
-use crate::application::steps::application::StartServicesStep;
+use crate::adapters::ssh::SshConfig;
+use crate::application::command_handlers::common::{
+    prune_run_logs, prune_traces, traces_dir, verify_host_keys, StepResult,
+};
+use crate::application::steps::application::{StartServicesStep, WaitForTrackerHealthStep};
+use crate::domain::environment::deployment_report::elapsed_ms;
 use crate::domain::environment::repository::{EnvironmentRepository, TypedEnvironmentRepository};
 use crate::domain::environment::runtime_outputs::ServiceEndpoints;
 use crate::domain::environment::state::{RunFailureContext, RunStep};
-use crate::domain::environment::{Environment, Released, Running};
+use crate::domain::environment::{DeploymentPhase, Environment, Released, Running};
 use crate::domain::EnvironmentName;
+use crate::shared::command::run_log::DEFAULT_KEPT_RUNS;
+use crate::shared::command::RunLog;
 use crate::shared::error::Traceable;
 
 /// `RunCommandHandler` orchestrates the stack execution workflow
@@ -25,14 +33,16 @@ use crate::shared::error::Traceable;
 /// 1. Load the environment from storage
 /// 2. Validate the environment is in the correct state
 /// 3. Start services via Ansible playbook
-/// 4. Transition environment to `Running` state
+/// 4. Wait for the tracker Health Check API to report healthy (unless disabled
+///    with [`with_wait_for_health`](Self::with_wait_for_health))
+/// 5. Transition environment to `Running` state
 ///
 /// # Architecture
 ///
 /// Follows the three-level architecture:
 /// - **Command** (Level 1): This handler orchestrates the run workflow
-/// - **Step** (Level 2): `StartServicesStep`
-/// - **Remote Action** (Level 3): Ansible playbook executes on remote host
+/// - **Step** (Level 2): `StartServicesStep`, `WaitForTrackerHealthStep`
+/// - **Remote Action** (Level 3): Ansible playbook and health checks execute on remote host
 ///
 /// # State Management
 ///
@@ -45,6 +55,10 @@ use crate::shared::error::Traceable;
 pub struct RunCommandHandler {
     pub(crate) clock: Arc<dyn crate::shared::Clock>,
     pub(crate) repository: TypedEnvironmentRepository,
+    wait_for_health: bool,
+    kept_run_logs: usize,
+    trace_dir: Option<PathBuf>,
+    cancellation: CancellationToken,
 }
 
 impl RunCommandHandler {
@@ -57,9 +71,51 @@ impl RunCommandHandler {
         Self {
             clock,
             repository: TypedEnvironmentRepository::new(repository),
+            wait_for_health: true,
+            kept_run_logs: DEFAULT_KEPT_RUNS,
+            trace_dir: None,
+            cancellation: CancellationToken::new(),
         }
     }
 
+    /// Cancel the workflow when the given token is cancelled (e.g. on Ctrl-C)
+    ///
+    /// The health wait stops right away; the environment is saved in the
+    /// `RunFailed` state with an `Interrupted` error.
+    #[must_use]
+    pub fn with_cancellation(mut self, cancellation: CancellationToken) -> Self {
+        self.cancellation = cancellation;
+        self
+    }
+
+    /// Wait for the tracker to report healthy before reporting success
+    ///
+    /// Enabled by default. When disabled, the environment is `Running` as soon
+    /// as `docker compose up` returns, while the containers may still be
+    /// starting.
+    #[must_use]
+    pub fn with_wait_for_health(mut self, wait_for_health: bool) -> Self {
+        self.wait_for_health = wait_for_health;
+        self
+    }
+
+    /// Keep the Ansible output logs of the last `runs` run command runs
+    #[must_use]
+    pub fn with_kept_run_logs(mut self, runs: usize) -> Self {
+        self.kept_run_logs = runs;
+        self
+    }
+
+    /// Write the trace files of a failure to `trace_dir`
+    ///
+    /// `None` keeps the directory of the environment configuration, or
+    /// `data/{env}/traces/` when it sets none.
+    #[must_use]
+    pub fn with_trace_dir(mut self, trace_dir: Option<PathBuf>) -> Self {
+        self.trace_dir = trace_dir;
+        self
+    }
+
     /// Execute the run workflow
     ///
     /// # Arguments
@@ -77,20 +133,23 @@ impl RunCommandHandler {
     /// * Environment is not in `Released` state
     /// * Instance IP is not available
     /// * Starting services fails
+    /// * The tracker never reports healthy
+    /// * The run is interrupted (Ctrl-C)
     /// * State persistence fails
     #[allow(clippy::result_large_err)]
     #[instrument(
-        name = "run_command",
+        name = "command.run",
         skip_all,
         fields(
             command_type = "run",
-            environment = %env_name
+            environment_name = %env_name
         )
     )]
     pub fn execute(
         &self,
         env_name: &EnvironmentName,
     ) -> Result<Environment<Running>, RunCommandHandlerError> {
+        let _lock = self.repository.inner().lock(env_name)?;
         let environment = self.load_released_environment(env_name)?;
 
         let instance_ip =
@@ -100,6 +159,9 @@ impl RunCommandHandler {
                     name: env_name.to_string(),
                 })?;
 
+        // Refuse to start services on a host presenting other keys than the recorded ones
+        verify_host_keys(&environment, instance_ip)?;
+
         let started_at = self.clock.now();
 
         info!(
@@ -111,7 +173,15 @@ impl RunCommandHandler {
             "Environment loaded and validated. Executing run steps."
         );
 
-        match self.execute_run_workflow(&environment, instance_ip) {
+        let run_log =
+            RunLog::new(&environment.run_logs_dir(), "run", started_at).keeping(self.kept_run_logs);
+        let result = self.execute_run_workflow(&environment, instance_ip, &run_log);
+        prune_run_logs(&run_log);
+
+        let traces_dir = traces_dir(&environment, self.trace_dir.as_deref());
+        let traces = environment.traces_config().clone();
+
+        let outcome = match result {
             Ok(running) => {
                 info!(
                     command = "run",
@@ -120,6 +190,10 @@ impl RunCommandHandler {
                     "Stack execution completed successfully"
                 );
 
+                let running = running.with_phase_duration(
+                    DeploymentPhase::Run,
+                    elapsed_ms(started_at, self.clock.now()),
+                );
                 self.repository.save_running(&running)?;
 
                 Ok(running)
@@ -142,14 +216,18 @@ impl RunCommandHandler {
 
                 Err(e)
             }
-        }
+        };
+        prune_traces(&traces_dir, &traces, self.clock.now());
+
+        outcome
     }
 
     /// Execute the run workflow with step tracking
     ///
     /// This method orchestrates the complete run workflow:
     /// 1. Start Docker Compose services on the remote host
-    /// 2. Build service endpoints for display
+    /// 2. Wait for the tracker Health Check API to report healthy, if enabled
+    /// 3. Build service endpoints for display
     ///
     /// If an error occurs, it returns both the error and the step that was being
     /// executed, enabling accurate failure context generation.
@@ -158,6 +236,7 @@ impl RunCommandHandler {
     ///
     /// * `environment` - The environment in Released state
     /// * `instance_ip` - The validated instance IP address (precondition checked by caller)
+    /// * `run_log` - Logs receiving the output of the playbooks
     ///
     /// # Errors
     ///
@@ -167,9 +246,21 @@ impl RunCommandHandler {
         &self,
         environment: &Environment<Released>,
         instance_ip: IpAddr,
+        run_log: &RunLog,
     ) -> StepResult<Environment<Running>, RunCommandHandlerError, RunStep> {
         // Step 1: Start Docker Compose services
-        self.start_services(environment, instance_ip)?;
+        self.start_services(environment, instance_ip, run_log)?;
+
+        // Step 2: Wait for the tracker to report healthy
+        if self.wait_for_health {
+            self.wait_for_tracker_health(environment, instance_ip)?;
+        } else {
+            info!(
+                command = "run",
+                instance_ip = %instance_ip,
+                "Not waiting for the tracker to report healthy"
+            );
+        }
 
         // Build service endpoints from tracker config and instance IP
         let service_endpoints =
@@ -188,25 +279,33 @@ impl RunCommandHandler {
     /// # Errors
     ///
     /// Returns a tuple of (error, `RunStep::StartServices`) if starting services fails
-    #[allow(clippy::result_large_err, clippy::unused_self)]
+    #[allow(clippy::result_large_err)]
     fn start_services(
         &self,
         environment: &Environment<Released>,
         instance_ip: IpAddr,
+        run_log: &RunLog,
     ) -> StepResult<(), RunCommandHandlerError, RunStep> {
         let current_step = RunStep::StartServices;
 
-        let ansible_client = Arc::new(AnsibleClient::new(environment.ansible_build_dir()));
+        let ansible_client = Arc::new(
+            AnsibleClient::new(environment.ansible_build_dir())
+                .with_run_log(run_log.clone())
+                .with_env_vars(environment.context().user_inputs.proxy_env_vars()),
+        );
         let step = StartServicesStep::new(ansible_client);
 
         step.execute().map_err(|e| {
-            (
+            // The playbook was killed by the same Ctrl-C
+            let error = if self.cancellation.is_cancelled() {
+                RunCommandHandlerError::Interrupted
+            } else {
                 RunCommandHandlerError::StartServicesFailed {
                     message: e.to_string(),
                     source: e,
-                },
-                current_step,
-            )
+                }
+            };
+            (error, current_step)
         })?;
 
         info!(
@@ -218,6 +317,45 @@ impl RunCommandHandler {
         Ok(())
     }
 
+    /// Wait for the tracker Health Check API to answer the expected status
+    ///
+    /// # Errors
+    ///
+    /// Returns a tuple of (error, `RunStep::WaitForTrackerHealth`) if the
+    /// tracker does not report healthy within the configured timeout, or if
+    /// the wait is interrupted
+    #[allow(clippy::result_large_err)]
+    fn wait_for_tracker_health(
+        &self,
+        environment: &Environment<Released>,
+        instance_ip: IpAddr,
+    ) -> StepResult<(), RunCommandHandlerError, RunStep> {
+        let current_step = RunStep::WaitForTrackerHealth;
+
+        let ssh_config = SshConfig::new(
+            environment.ssh_credentials().clone(),
+            SocketAddr::new(instance_ip, environment.ssh_port()),
+        )
+        .with_known_hosts(environment.ssh_known_hosts_path());
+        let health_check_api = environment.tracker_config().health_check_api();
+        let step = WaitForTrackerHealthStep::new(
+            ssh_config,
+            health_check_api.bind_address(),
+            *health_check_api.wait(),
+            environment.tracker_config().replica_names(),
+        )
+        .with_cancellation(self.cancellation.clone());
+
+        step.execute().map_err(|source| {
+            let error = if source.is_interrupted() {
+                RunCommandHandlerError::Interrupted
+            } else {
+                RunCommandHandlerError::HealthCheckFailed { source }
+            };
+            (error, current_step)
+        })
+    }
+
     /// Build failure context for a run error and generate trace file
     ///
     /// This helper method builds structured error context including the failed step,
@@ -258,10 +396,11 @@ impl RunCommandHandler {
             failed_step,
             error_kind,
             base,
+            last_health_check: error.last_health_check().map(ToString::to_string),
         };
 
         // Generate trace file (logging handled by trace writer)
-        let traces_dir = environment.traces_dir();
+        let traces_dir = traces_dir(environment, self.trace_dir.as_deref());
         let writer = RunTraceWriter::new(traces_dir, Arc::clone(&self.clock));
 
         if let Ok(trace_file) = writer.write_trace(&context, error) {
@@ -10,9 +10,13 @@
 //!
 //! The show command displays state-aware information:
 //!
-//! 1. **Basic Info (all states)**: Environment name, state, provider
+//! 1. **Basic Info (all states)**: Environment name, state, provider, state file versions
 //! 2. **Infrastructure (Provisioned+)**: IP, SSH port, SSH user, SSH key path
-//! 3. **Next Step**: Guidance based on current state
+//! 3. **Endpoints (Provisioned+)**: Announce, API and health check URLs
+//! 4. **Error Details (failed states)**: Failed step, failed Ansible task, trace file
+//! 5. **Next Step**: Guidance based on current state
+//! 6. **Secrets (on request)**: Tokens and passwords, only with
+//!    `with_reveal_secrets`
 //!
 //! ## Design Rationale
 //!
@@ -29,14 +33,14 @@ use tracing::instrument;
 
 use super::errors::ShowCommandHandlerError;
 use super::info::{
-    DockerImagesInfo, EnvironmentInfo, GrafanaInfo, InfrastructureInfo, PrometheusInfo, ServiceInfo,
+    DockerImagesInfo, EndpointsInfo, EnvironmentInfo, FailureInfo, GrafanaInfo, InfrastructureInfo,
+    PhaseDurationsInfo, PrometheusInfo, ReleaseInfo, SecretsInfo, ServiceInfo, StateFileInfo,
 };
 use crate::domain::environment::repository::EnvironmentRepository;
 use crate::domain::environment::state::AnyEnvironmentState;
 use crate::domain::grafana::GrafanaConfig;
 use crate::domain::mysql::MysqlServiceConfig;
 use crate::domain::prometheus::PrometheusConfig;
-use crate::domain::tracker::config::TrackerConfig;
 use crate::domain::EnvironmentName;
 
 /// Default SSH port when not specified
@@ -54,16 +58,28 @@ const DEFAULT_SSH_PORT: u16 = 22;
 ///
 /// - **All states**: Name, state name, provider
 /// - **Provisioned+**: Infrastructure details (IP, SSH credentials)
+/// - **Failed states**: Details of the failure
 /// - **All states**: Next step guidance
 pub struct ShowCommandHandler {
     repository: Arc<dyn EnvironmentRepository>,
+    reveal_secrets: bool,
 }
 
 impl ShowCommandHandler {
     /// Create a new `ShowCommandHandler`
     #[must_use]
     pub fn new(repository: Arc<dyn EnvironmentRepository>) -> Self {
-        Self { repository }
+        Self {
+            repository,
+            reveal_secrets: false,
+        }
+    }
+
+    /// Include the secrets of the environment, in plaintext
+    #[must_use]
+    pub fn with_reveal_secrets(mut self, reveal_secrets: bool) -> Self {
+        self.reveal_secrets = reveal_secrets;
+        self
     }
 
     /// Execute the show command workflow
@@ -85,11 +101,11 @@ impl ShowCommandHandler {
     /// * Environment not found
     /// * Environment state file is corrupted or unreadable
     #[instrument(
-        name = "show_command",
+        name = "command.show",
         skip_all,
         fields(
             command_type = "show",
-            environment = %env_name
+            environment_name = %env_name
         )
     )]
     pub fn execute(
@@ -97,8 +113,16 @@ impl ShowCommandHandler {
         env_name: &EnvironmentName,
     ) -> Result<EnvironmentInfo, ShowCommandHandlerError> {
         let any_env = self.load_environment(env_name)?;
+        let mut info = Self::extract_info(&any_env);
 
-        Ok(Self::extract_info(&any_env))
+        if self.reveal_secrets {
+            info = info.with_secrets(SecretsInfo::from(&any_env));
+        }
+
+        Ok(match self.repository.load_version(env_name)? {
+            Some(version) => info.with_state_file(StateFileInfo::from(version)),
+            None => info,
+        })
     }
 
     /// Load environment from repository
@@ -120,7 +144,7 @@ impl ShowCommandHandler {
     }
 
     /// Extract information from environment based on its state
-    fn extract_info(any_env: &AnyEnvironmentState) -> EnvironmentInfo {
+    pub(crate) fn extract_info(any_env: &AnyEnvironmentState) -> EnvironmentInfo {
         let name = any_env.name().to_string();
         let state = any_env.state_display_name().to_string();
         let provider = any_env.provider_display_name().to_string();
@@ -129,7 +153,7 @@ impl ShowCommandHandler {
 
         let tracker_config = any_env.tracker_config();
         let docker_images = DockerImagesInfo::new(
-            TrackerConfig::docker_image().full_reference(),
+            tracker_config.image().full_reference(),
             if tracker_config.uses_mysql() {
                 Some(MysqlServiceConfig::docker_image().full_reference())
             } else {
@@ -144,7 +168,32 @@ impl ShowCommandHandler {
         );
 
         let mut info =
-            EnvironmentInfo::new(name, state, provider, created_at, docker_images, state_name);
+            EnvironmentInfo::new(name, state, provider, created_at, docker_images, state_name)
+                .with_labels(any_env.labels().as_map().clone())
+                .with_config_revision(any_env.config_revision())
+                .with_releases(
+                    any_env
+                        .release_history()
+                        .iter()
+                        .map(ReleaseInfo::from)
+                        .collect(),
+                )
+                .with_durations(PhaseDurationsInfo::from(any_env.phase_durations()))
+                .with_commands(
+                    any_env
+                        .allowed_commands()
+                        .iter()
+                        .map(ToString::to_string)
+                        .collect(),
+                    any_env
+                        .suggested_next_command()
+                        .map(|command| command.to_string()),
+                );
+
+        // Add failure details for failed states
+        if let Some(report) = any_env.failure_report() {
+            info = info.with_failure(FailureInfo::from(&report));
+        }
 
         // Add infrastructure info if instance IP is available
         if let Some(instance_ip) = any_env.instance_ip() {
@@ -162,6 +211,10 @@ impl ShowCommandHandler {
                 ssh_creds.ssh_priv_key_path.to_string_lossy().to_string(),
             );
             info = info.with_infrastructure(infra);
+            info = info.with_endpoints(EndpointsInfo::from_tracker_config(
+                tracker_config,
+                instance_ip,
+            ));
 
             // Add service info for Released/Running states
             if Self::should_show_services(any_env.state_name()) {
@@ -190,11 +243,12 @@ impl ShowCommandHandler {
     /// Determine if services should be shown based on state
     ///
     /// Services are shown for states where the tracker configuration has been
-    /// deployed and services may be running (Released, Running, or related failed states).
+    /// deployed and services may be running (Released, Running, Stopped, or related
+    /// failed states).
     fn should_show_services(state_name: &str) -> bool {
         matches!(
             state_name,
-            "released" | "running" | "release_failed" | "run_failed"
+            "released" | "running" | "stopped" | "release_failed" | "run_failed"
         )
     }
 }
@@ -216,6 +270,11 @@ mod tests {
             assert!(ShowCommandHandler::should_show_services("running"));
         }
 
+        #[test]
+        fn it_should_show_services_for_stopped_state() {
+            assert!(ShowCommandHandler::should_show_services("stopped"));
+        }
+
         #[test]
         fn it_should_show_services_for_release_failed_state() {
             assert!(ShowCommandHandler::should_show_services("release_failed"));
@@ -241,4 +300,172 @@ mod tests {
             assert!(!ShowCommandHandler::should_show_services("configured"));
         }
     }
+
+    mod extract_info {
+        use std::net::{IpAddr, Ipv4Addr};
+        use std::time::Duration;
+
+        use chrono::Utc;
+
+        use super::*;
+        use crate::adapters::ansible::TaskFailure;
+        use crate::domain::environment::runtime_outputs::ProvisionMethod;
+        use crate::domain::environment::state::{
+            BaseFailureContext, ConfigureFailureContext, ConfigureStep, RunFailureContext, RunStep,
+        };
+        use crate::domain::environment::testing::EnvironmentTestBuilder;
+        use crate::domain::environment::TraceId;
+        use crate::shared::ErrorKind;
+
+        #[test]
+        fn it_should_include_the_failed_ansible_task_of_a_configure_failure() {
+            let (env, _data_dir, _build_dir, _temp_dir) =
+                EnvironmentTestBuilder::new().build_with_custom_paths();
+            let now = Utc::now();
+            let failed = env
+                .start_provisioning()
+                .provisioned(
+                    IpAddr::V4(Ipv4Addr::new(10, 140, 190, 14)),
+                    ProvisionMethod::Provisioned,
+                )
+                .start_configuring()
+                .configure_failed(ConfigureFailureContext {
+                    failed_step: ConfigureStep::InstallDocker,
+                    error_kind: ErrorKind::CommandExecution,
+                    base: BaseFailureContext {
+                        error_summary: "install-docker failed".to_string(),
+                        failed_at: now,
+                        execution_started_at: now,
+                        execution_duration: Duration::from_secs(30),
+                        trace_id: TraceId::new(),
+                        trace_file_path: None,
+                    },
+                    failed_task: Some(TaskFailure {
+                        playbook: "install-docker".to_string(),
+                        task: "Install docker-ce".to_string(),
+                        module: "ansible.builtin.apt".to_string(),
+                        host: "torrust-tracker-vm".to_string(),
+                        message: "No package matching 'docker-ce' is available".to_string(),
+                        stderr: None,
+                    }),
+                    attempts: Some(1),
+                });
+
+            let info = ShowCommandHandler::extract_info(&failed.into_any());
+
+            let failure = info.failure.expect("Expected failure details");
+            assert_eq!(failure.command, "configure");
+            assert_eq!(failure.failed_step, "InstallDocker");
+            assert_eq!(
+                failure.failed_task.as_deref(),
+                Some(
+                    "task 'Install docker-ce' failed: No package matching 'docker-ce' is available"
+                )
+            );
+        }
+
+        #[test]
+        fn it_should_include_the_trace_id_and_duration_of_a_run_failure() {
+            let (env, _data_dir, _build_dir, _temp_dir) =
+                EnvironmentTestBuilder::new().build_with_custom_paths();
+            let now = Utc::now();
+            let trace_id = TraceId::new();
+            let failed = env
+                .start_provisioning()
+                .provisioned(
+                    IpAddr::V4(Ipv4Addr::new(10, 140, 190, 14)),
+                    ProvisionMethod::Provisioned,
+                )
+                .start_configuring()
+                .configured()
+                .start_releasing()
+                .released()
+                .start_running()
+                .run_failed(RunFailureContext {
+                    failed_step: RunStep::WaitForTrackerHealth,
+                    error_kind: ErrorKind::Timeout,
+                    base: BaseFailureContext {
+                        error_summary: "tracker never became healthy".to_string(),
+                        failed_at: now,
+                        execution_started_at: now,
+                        execution_duration: Duration::from_millis(62_500),
+                        trace_id: trace_id.clone(),
+                        trace_file_path: None,
+                    },
+                    last_health_check: None,
+                });
+
+            let info = ShowCommandHandler::extract_info(&failed.into_any());
+
+            let failure = info.failure.expect("Expected failure details");
+            assert_eq!(failure.command, "run");
+            assert_eq!(failure.duration_ms, 62_500);
+            assert_eq!(failure.trace_id, trace_id.to_string());
+        }
+
+        #[test]
+        fn it_should_not_include_failure_details_for_a_created_environment() {
+            let (env, _data_dir, _build_dir, _temp_dir) =
+                EnvironmentTestBuilder::new().build_with_custom_paths();
+
+            let info = ShowCommandHandler::extract_info(&env.into_any());
+
+            assert!(info.failure.is_none());
+        }
+    }
+    mod execute {
+        use std::sync::Arc;
+
+        use tempfile::TempDir;
+
+        use super::*;
+        use crate::domain::environment::repository::migrations::{
+            CURRENT_STATE_SCHEMA_VERSION, DEPLOYER_VERSION,
+        };
+        use crate::domain::environment::testing::EnvironmentTestBuilder;
+        use crate::infrastructure::persistence::filesystem::file_environment_repository::FileEnvironmentRepository;
+
+        #[test]
+        fn it_should_include_the_versions_recorded_in_the_state_file() {
+            let (env, _data_dir, _build_dir, _temp_dir) =
+                EnvironmentTestBuilder::new().build_with_custom_paths();
+            let env_name = env.name().clone();
+            let data_dir = TempDir::new().unwrap();
+            let repository = FileEnvironmentRepository::new(data_dir.path().to_path_buf());
+            repository.save(&env.into_any()).unwrap();
+
+            let info = ShowCommandHandler::new(Arc::new(repository))
+                .execute(&env_name)
+                .unwrap();
+
+            let state_file = info.state_file.expect("Expected state file versions");
+            assert_eq!(state_file.schema_version, CURRENT_STATE_SCHEMA_VERSION);
+            assert_eq!(
+                state_file.deployer_version.as_deref(),
+                Some(DEPLOYER_VERSION)
+            );
+            assert!(info.secrets.is_none());
+        }
+
+        #[test]
+        fn it_should_include_the_secrets_only_when_revealing_them() {
+            let (env, _data_dir, _build_dir, _temp_dir) =
+                EnvironmentTestBuilder::new().build_with_custom_paths();
+            let env_name = env.name().clone();
+            let admin_token = env.admin_token().to_string();
+            let data_dir = TempDir::new().unwrap();
+            let repository = FileEnvironmentRepository::new(data_dir.path().to_path_buf());
+            repository.save(&env.into_any()).unwrap();
+
+            let info = ShowCommandHandler::new(Arc::new(repository))
+                .with_reveal_secrets(true)
+                .execute(&env_name)
+                .unwrap();
+
+            let secrets = info.secrets.expect("Expected the secrets");
+            assert_eq!(secrets.admin_token, admin_token);
+            assert!(secrets.grafana_admin_password.is_some());
+            assert!(secrets.provider_api_token.is_none());
+        }
+    }
 }
@@ -0,0 +1,56 @@
+//! Deployment phase durations for display purposes
+
+use serde::Serialize;
+
+use crate::domain::environment::{DeploymentPhase, PhaseDurations};
+
+/// How long the last successful run of each deployment phase took
+///
+/// Durations are in milliseconds. A phase is `None` until its command
+/// succeeds, and for environments deployed before durations were recorded.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize)]
+pub struct PhaseDurationsInfo {
+    /// Duration of the last successful `provision`
+    pub provision_ms: Option<u64>,
+
+    /// Duration of the last successful `configure`
+    pub configure_ms: Option<u64>,
+
+    /// Duration of the last successful `release`
+    pub release_ms: Option<u64>,
+
+    /// Duration of the last successful `run`
+    pub run_ms: Option<u64>,
+}
+
+impl PhaseDurationsInfo {
+    /// Returns the duration of `phase` in milliseconds, if recorded
+    #[must_use]
+    pub fn get(&self, phase: DeploymentPhase) -> Option<u64> {
+        match phase {
+            DeploymentPhase::Provision => self.provision_ms,
+            DeploymentPhase::Configure => self.configure_ms,
+            DeploymentPhase::Release => self.release_ms,
+            DeploymentPhase::Run => self.run_ms,
+        }
+    }
+
+    /// Returns `true` if no phase duration is recorded
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        DeploymentPhase::ALL
+            .iter()
+            .all(|phase| self.get(*phase).is_none())
+    }
+}
+
+impl From<&PhaseDurations> for PhaseDurationsInfo {
+    fn from(durations: &PhaseDurations) -> Self {
+        Self {
+            provision_ms: durations.get(DeploymentPhase::Provision),
+            configure_ms: durations.get(DeploymentPhase::Configure),
+            release_ms: durations.get(DeploymentPhase::Release),
+            run_ms: durations.get(DeploymentPhase::Run),
+        }
+    }
+}
@@ -0,0 +1,272 @@
+//! Derived service endpoints for display purposes
+//!
+//! This module contains DTOs for the copy-pasteable URLs of the tracker
+//! services, resolved from the tracker configuration and the instance IP.
+//! Unlike `ServiceInfo`, which groups endpoints for the human-readable
+//! service summary, these DTOs describe every endpoint as a structured object
+//! so automation can consume them from `show --format json`.
+
+use std::net::{IpAddr, SocketAddr};
+
+use serde::Serialize;
+
+use crate::domain::tracker::config::{is_localhost, TrackerConfig};
+use crate::shared::{DomainName, ServiceEndpoint};
+
+/// Path of the announce endpoint of HTTP and UDP trackers
+const ANNOUNCE_PATH: &str = "/announce";
+
+/// Base path of the tracker REST API
+const API_PATH: &str = "/api";
+
+/// Path of the health check endpoint
+const HEALTH_CHECK_PATH: &str = "/health_check";
+
+/// Resolved service endpoints of an environment
+///
+/// Only available once the environment has an instance IP, so no placeholder
+/// addresses are ever shown.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct EndpointsInfo {
+    /// Announce URLs of the UDP trackers of every replica (e.g., `udp://10.0.0.1:6969/announce`)
+    pub udp_announce: Vec<EndpointInfo>,
+
+    /// Announce URLs of the HTTP trackers of every replica (e.g., `https://http1.tracker.local/announce`)
+    pub http_announce: Vec<EndpointInfo>,
+
+    /// Base URL of the tracker REST API of the first replica (e.g., `http://10.0.0.1:1212/api`)
+    pub api: EndpointInfo,
+
+    /// URL of the health check API of the first replica (e.g., `http://10.0.0.1:1313/health_check`)
+    pub health_check: EndpointInfo,
+}
+
+/// A single resolved service endpoint
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct EndpointInfo {
+    /// Copy-pasteable URL of the endpoint
+    pub url: String,
+
+    /// Whether the endpoint is served over HTTPS via the TLS proxy
+    pub uses_tls: bool,
+
+    /// Whether the service only listens on localhost (reachable via SSH tunnel)
+    pub localhost_only: bool,
+}
+
+impl EndpointInfo {
+    fn from_service_endpoint(endpoint: &ServiceEndpoint, bind_address: SocketAddr) -> Self {
+        Self {
+            url: endpoint.url().to_string(),
+            uses_tls: endpoint.uses_tls(),
+            localhost_only: is_localhost(&bind_address),
+        }
+    }
+}
+
+impl EndpointsInfo {
+    /// Build the endpoints of all tracker services
+    ///
+    /// HTTP services behind the TLS proxy use their domain; all other
+    /// services use the instance IP. UDP trackers use their domain when one
+    /// is configured. With several replicas the announce URLs of every
+    /// replica are listed, on their offset ports.
+    ///
+    /// # Arguments
+    ///
+    /// * `tracker_config` - The tracker configuration containing service settings
+    /// * `instance_ip` - The IP address of the provisioned instance
+    ///
+    /// # Panics
+    ///
+    /// Panics if the configuration produces an invalid URL (this should never happen
+    /// with valid configuration types from the domain layer).
+    #[must_use]
+    pub fn from_tracker_config(tracker_config: &TrackerConfig, instance_ip: IpAddr) -> Self {
+        let replicas = tracker_config.replica_configs();
+
+        let udp_announce = replicas
+            .iter()
+            .flat_map(TrackerConfig::udp_trackers)
+            .map(|udp| {
+                let port = udp.bind_address().port();
+                let authority = udp.domain().map_or_else(
+                    || SocketAddr::new(instance_ip, port).to_string(),
+                    |d| format!("{}:{port}", d.as_str()),
+                );
+                EndpointInfo {
+                    url: format!("udp://{authority}{ANNOUNCE_PATH}"),
+                    uses_tls: false,
+                    localhost_only: is_localhost(&udp.bind_address()),
+                }
+            })
+            .collect();
+
+        let http_announce = replicas
+            .iter()
+            .flat_map(TrackerConfig::http_trackers)
+            .map(|http| {
+                Self::http_endpoint(
+                    instance_ip,
+                    http.bind_address(),
+                    http.tls_domain(),
+                    ANNOUNCE_PATH,
+                )
+            })
+            .collect();
+
+        let api_config = tracker_config.http_api();
+        let api = Self::http_endpoint(
+            instance_ip,
+            api_config.bind_address(),
+            api_config.tls_domain(),
+            API_PATH,
+        );
+
+        let health_check_config = tracker_config.health_check_api();
+        let health_check = Self::http_endpoint(
+            instance_ip,
+            health_check_config.bind_address(),
+            health_check_config
+                .domain()
+                .filter(|_| health_check_config.use_tls_proxy()),
+            HEALTH_CHECK_PATH,
+        );
+
+        Self {
+            udp_announce,
+            http_announce,
+            api,
+            health_check,
+        }
+    }
+
+    /// Build an HTTP(S) endpoint, using the TLS domain when one is given
+    fn http_endpoint(
+        instance_ip: IpAddr,
+        bind_address: SocketAddr,
+        tls_domain: Option<&DomainName>,
+        path: &str,
+    ) -> EndpointInfo {
+        let endpoint = if let Some(domain) = tls_domain {
+            ServiceEndpoint::https(domain, path, instance_ip)
+                .expect("Valid TLS domain should produce valid HTTPS URL")
+        } else {
+            ServiceEndpoint::http(SocketAddr::new(instance_ip, bind_address.port()), path)
+                .expect("Valid socket address should produce valid HTTP URL")
+        };
+
+        EndpointInfo::from_service_endpoint(&endpoint, bind_address)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::net::Ipv4Addr;
+
+    use super::*;
+    use crate::domain::tracker::config::HttpTrackerConfig;
+    use crate::testing::fixtures::mysql_tracker_config;
+
+    fn instance_ip() -> IpAddr {
+        IpAddr::V4(Ipv4Addr::new(10, 0, 0, 1))
+    }
+
+    #[test]
+    fn it_should_use_the_instance_ip_for_services_without_tls() {
+        let endpoints =
+            EndpointsInfo::from_tracker_config(&TrackerConfig::default(), instance_ip());
+
+        assert_eq!(
+            endpoints.udp_announce[0].url,
+            "udp://10.0.0.1:6969/announce"
+        );
+        assert_eq!(
+            endpoints.http_announce[0].url,
+            "http://10.0.0.1:7070/announce" // DevSkim: ignore DS137138
+        );
+        assert!(!endpoints.http_announce[0].uses_tls);
+    }
+
+    #[test]
+    fn it_should_bracket_an_ipv6_instance_ip() {
+        let endpoints = EndpointsInfo::from_tracker_config(
+            &TrackerConfig::default(),
+            "2001:db8::10".parse().unwrap(),
+        );
+
+        assert_eq!(
+            endpoints.udp_announce[0].url,
+            "udp://[2001:db8::10]:6969/announce"
+        );
+        assert_eq!(
+            endpoints.http_announce[0].url,
+            "http://[2001:db8::10]:7070/announce" // DevSkim: ignore DS137138
+        );
+    }
+
+    #[test]
+    fn it_should_use_the_tls_domain_for_http_trackers_behind_the_tls_proxy() {
+        let http = HttpTrackerConfig::new(
+            "0.0.0.0:7070".parse().unwrap(),
+            Some(DomainName::new("http.tracker.local").unwrap()),
+            true,
+        )
+        .unwrap();
+        let default = TrackerConfig::default();
+        let tracker_config = TrackerConfig::new(
+            default.core().clone(),
+            default.udp_trackers().to_vec(),
+            vec![http],
+            default.http_api().clone(),
+            default.health_check_api().clone(),
+        )
+        .unwrap();
+
+        let endpoints = EndpointsInfo::from_tracker_config(&tracker_config, instance_ip());
+
+        assert_eq!(
+            endpoints.http_announce[0].url,
+            "https://http.tracker.local/announce"
+        );
+        assert!(endpoints.http_announce[0].uses_tls);
+    }
+
+    #[test]
+    fn it_should_flag_localhost_only_services() {
+        let endpoints =
+            EndpointsInfo::from_tracker_config(&TrackerConfig::default(), instance_ip());
+
+        assert!(endpoints.health_check.localhost_only);
+        assert_eq!(
+            endpoints.health_check.url,
+            "http://10.0.0.1:1313/health_check" // DevSkim: ignore DS137138
+        );
+    }
+
+    #[test]
+    fn it_should_list_the_announce_urls_of_every_replica() {
+        let tracker_config = mysql_tracker_config()
+            .with_replicas(std::num::NonZeroU8::new(2).unwrap())
+            .unwrap();
+
+        let endpoints = EndpointsInfo::from_tracker_config(&tracker_config, instance_ip());
+
+        let udp_urls: Vec<&str> = endpoints
+            .udp_announce
+            .iter()
+            .map(|endpoint| endpoint.url.as_str())
+            .collect();
+        assert_eq!(
+            udp_urls,
+            vec![
+                "udp://10.0.0.1:6969/announce",
+                "udp://10.0.0.1:6970/announce"
+            ]
+        );
+        assert_eq!(
+            endpoints.http_announce[1].url,
+            "http://10.0.0.1:7071/announce" // DevSkim: ignore DS137138
+        );
+    }
+}
@@ -0,0 +1,52 @@
+//! Last failure information for display purposes
+
+use chrono::{DateTime, Utc};
+use serde::Serialize;
+
+use crate::domain::environment::FailureReport;
+
+/// Details of the command failure that left the environment in a failed state
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct FailureInfo {
+    /// Command that failed (e.g. "configure")
+    pub command: String,
+
+    /// Step of the command that failed (e.g. "`InstallDocker`")
+    pub failed_step: String,
+
+    /// When the failure happened
+    pub failed_at: DateTime<Utc>,
+
+    /// How long the command ran before failing, in milliseconds
+    pub duration_ms: u64,
+
+    /// Trace identifier of the failed command
+    pub trace_id: String,
+
+    /// The Ansible task that failed, e.g. `task 'Install docker-ce' failed: ...`
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub failed_task: Option<String>,
+
+    /// Path of the trace file with the full error chain
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub trace_file: Option<String>,
+}
+
+impl From<&FailureReport> for FailureInfo {
+    fn from(report: &FailureReport) -> Self {
+        Self {
+            command: report.failed_step.command().to_string(),
+            failed_step: report.failed_step.step_name(),
+            failed_at: report.base.failed_at,
+            duration_ms: u64::try_from(report.base.execution_duration.as_millis())
+                .unwrap_or(u64::MAX),
+            trace_id: report.base.trace_id.to_string(),
+            failed_task: report.failed_task.as_ref().map(ToString::to_string),
+            trace_file: report
+                .base
+                .trace_file_path
+                .as_ref()
+                .map(|path| path.display().to_string()),
+        }
+    }
+}
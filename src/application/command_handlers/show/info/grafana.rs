@@ -2,7 +2,7 @@
 //!
 //! This module contains DTOs for the Grafana service.
 
-use std::net::IpAddr;
+use std::net::{IpAddr, SocketAddr};
 
 use serde::Serialize;
 use url::Url;
@@ -40,7 +40,7 @@ impl GrafanaInfo {
     /// never happen since we construct a valid URL from a valid IP address.
     #[must_use]
     pub fn from_instance_ip(instance_ip: IpAddr) -> Self {
-        let url = Url::parse(&format!("http://{instance_ip}:3000")) // DevSkim: ignore DS137138
+        let url = Url::parse(&format!("http://{}", SocketAddr::new(instance_ip, 3000))) // DevSkim: ignore DS137138
             .expect("Valid IP address should produce valid URL");
         Self::new(url, false)
     }
@@ -89,6 +89,12 @@ mod tests {
         assert!(!info.uses_https);
     }
 
+    #[test]
+    fn it_should_create_grafana_info_from_an_ipv6_instance_ip() {
+        let info = GrafanaInfo::from_instance_ip("2001:db8::10".parse().unwrap());
+        assert_eq!(info.url.as_str(), "http://[2001:db8::10]:3000/"); // DevSkim: ignore DS137138
+    }
+
     #[test]
     fn it_should_create_grafana_info_with_https_from_config() {
         use crate::domain::grafana::GrafanaConfig;
@@ -8,23 +8,44 @@
 //!
 //! Each service in the deployment stack has its own submodule:
 //! - `docker_images`: Docker image references for all services
+//! - `durations`: Durations of the deployment phases
+//! - `endpoints`: Resolved service endpoint URLs (announce, API, health check)
+//! - `failure`: Details of the command failure in failed states
 //! - `tracker`: Tracker service information (UDP/HTTP trackers, API, health check)
 //! - `prometheus`: Prometheus metrics service information
 //! - `grafana`: Grafana visualization service information
+//! - `releases`: Release history (deployed tracker versions)
+//! - `secrets`: Secrets of the environment, only extracted on request
+//! - `state_file`: Versions recorded in the persisted state
 
 mod docker_images;
+mod durations;
+mod endpoints;
+mod failure;
 mod grafana;
 mod prometheus;
+mod releases;
+mod secrets;
+mod state_file;
 mod tracker;
 
+use std::collections::BTreeMap;
 use std::net::IpAddr;
 
 use chrono::{DateTime, Utc};
 use serde::Serialize;
 
+use crate::domain::environment::INITIAL_CONFIG_REVISION;
+
 pub use self::docker_images::DockerImagesInfo;
+pub use self::durations::PhaseDurationsInfo;
+pub use self::endpoints::{EndpointInfo, EndpointsInfo};
+pub use self::failure::FailureInfo;
 pub use self::grafana::GrafanaInfo;
 pub use self::prometheus::PrometheusInfo;
+pub use self::releases::ReleaseInfo;
+pub use self::secrets::SecretsInfo;
+pub use self::state_file::StateFileInfo;
 pub use self::tracker::{LocalhostServiceInfo, ServiceInfo, TlsDomainInfo};
 
 /// Environment information for display purposes
@@ -46,12 +67,23 @@ pub struct EnvironmentInfo {
     /// When the environment was created
     pub created_at: DateTime<Utc>,
 
+    /// Labels of the environment (e.g. `team: infra`)
+    #[serde(skip_serializing_if = "BTreeMap::is_empty")]
+    pub labels: BTreeMap<String, String>,
+
+    /// Revision of the configuration, incremented by every override that changed it
+    pub config_revision: u32,
+
     /// Infrastructure details, available after provisioning
     pub infrastructure: Option<InfrastructureInfo>,
 
     /// Tracker service information, available for Released/Running states
     pub services: Option<ServiceInfo>,
 
+    /// Resolved service endpoint URLs, available once the instance has an IP
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub endpoints: Option<EndpointsInfo>,
+
     /// Prometheus metrics service information, available for Released/Running states
     pub prometheus: Option<PrometheusInfo>,
 
@@ -61,6 +93,34 @@ pub struct EnvironmentInfo {
     /// Docker image references for all services in the deployment stack
     pub docker_images: DockerImagesInfo,
 
+    /// Recorded releases, oldest first; the last one is currently deployed
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub releases: Vec<ReleaseInfo>,
+
+    /// Durations of the deployment phases that completed
+    #[serde(skip_serializing_if = "PhaseDurationsInfo::is_empty")]
+    pub durations: PhaseDurationsInfo,
+
+    /// Details of the last command failure, available in failed states
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub failure: Option<FailureInfo>,
+
+    /// Versions recorded in the persisted state, available when loaded from storage
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub state_file: Option<StateFileInfo>,
+
+    /// Secrets of the environment, only when requested with `--reveal-secrets`
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub secrets: Option<SecretsInfo>,
+
+    /// Commands allowed in the current state (e.g., "configure", "destroy", "purge")
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub allowed_commands: Vec<String>,
+
+    /// Command suggested to move the deployment forward, if any
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub suggested_next: Option<String>,
+
     /// Internal state name (e.g., "created", "provisioned") for guidance generation
     pub state_name: String,
 }
@@ -81,15 +141,39 @@ impl EnvironmentInfo {
             state,
             provider,
             created_at,
+            labels: BTreeMap::new(),
+            config_revision: INITIAL_CONFIG_REVISION,
             infrastructure: None,
             services: None,
+            endpoints: None,
             prometheus: None,
             grafana: None,
             docker_images,
+            releases: Vec::new(),
+            durations: PhaseDurationsInfo::default(),
+            failure: None,
+            state_file: None,
+            secrets: None,
+            allowed_commands: Vec::new(),
+            suggested_next: None,
             state_name,
         }
     }
 
+    /// Set the labels of the environment
+    #[must_use]
+    pub fn with_labels(mut self, labels: BTreeMap<String, String>) -> Self {
+        self.labels = labels;
+        self
+    }
+
+    /// Set the configuration revision of the environment
+    #[must_use]
+    pub fn with_config_revision(mut self, config_revision: u32) -> Self {
+        self.config_revision = config_revision;
+        self
+    }
+
     /// Set infrastructure information
     #[must_use]
     pub fn with_infrastructure(mut self, infrastructure: InfrastructureInfo) -> Self {
@@ -104,6 +188,13 @@ impl EnvironmentInfo {
         self
     }
 
+    /// Set resolved service endpoints
+    #[must_use]
+    pub fn with_endpoints(mut self, endpoints: EndpointsInfo) -> Self {
+        self.endpoints = Some(endpoints);
+        self
+    }
+
     /// Set Prometheus information
     #[must_use]
     pub fn with_prometheus(mut self, prometheus: PrometheusInfo) -> Self {
@@ -117,6 +208,53 @@ impl EnvironmentInfo {
         self.grafana = Some(grafana);
         self
     }
+
+    /// Set the release history
+    #[must_use]
+    pub fn with_releases(mut self, releases: Vec<ReleaseInfo>) -> Self {
+        self.releases = releases;
+        self
+    }
+
+    /// Set the durations of the deployment phases
+    #[must_use]
+    pub fn with_durations(mut self, durations: PhaseDurationsInfo) -> Self {
+        self.durations = durations;
+        self
+    }
+
+    /// Set the details of the last command failure
+    #[must_use]
+    pub fn with_failure(mut self, failure: FailureInfo) -> Self {
+        self.failure = Some(failure);
+        self
+    }
+
+    /// Set the versions recorded in the persisted state
+    #[must_use]
+    pub fn with_state_file(mut self, state_file: StateFileInfo) -> Self {
+        self.state_file = Some(state_file);
+        self
+    }
+
+    /// Set the secrets of the environment
+    #[must_use]
+    pub fn with_secrets(mut self, secrets: SecretsInfo) -> Self {
+        self.secrets = Some(secrets);
+        self
+    }
+
+    /// Set the commands allowed in the current state and the suggested one
+    #[must_use]
+    pub fn with_commands(
+        mut self,
+        allowed_commands: Vec<String>,
+        suggested_next: Option<String>,
+    ) -> Self {
+        self.allowed_commands = allowed_commands;
+        self.suggested_next = suggested_next;
+        self
+    }
 }
 
 /// Infrastructure details for an environment
@@ -0,0 +1,25 @@
+//! Release history information for display purposes
+
+use chrono::{DateTime, Utc};
+use serde::Serialize;
+
+use crate::domain::environment::ReleaseRecord;
+
+/// A recorded release of the tracker
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct ReleaseInfo {
+    /// Tracker image version deployed by the release (tag or digest)
+    pub version: String,
+
+    /// When the release completed
+    pub released_at: DateTime<Utc>,
+}
+
+impl From<&ReleaseRecord> for ReleaseInfo {
+    fn from(record: &ReleaseRecord) -> Self {
+        Self {
+            version: record.version.to_string(),
+            released_at: record.released_at,
+        }
+    }
+}
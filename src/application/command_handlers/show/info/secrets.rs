@@ -0,0 +1,75 @@
+//! Secrets of an environment, for display with `show --reveal-secrets`
+
+use serde::Serialize;
+
+use crate::domain::environment::state::AnyEnvironmentState;
+use crate::domain::provider::ProviderConfig;
+use crate::domain::tracker::DatabaseConfig;
+
+/// Secrets of an environment, in plaintext
+///
+/// Only extracted when the user asks for them: `show` omits secrets by
+/// default so that its output can be shared.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct SecretsInfo {
+    /// Admin token of the tracker HTTP API
+    pub admin_token: String,
+
+    /// Password of the `MySQL` tracker user, when the tracker uses `MySQL`
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub database_password: Option<String>,
+
+    /// Password of the `MySQL` root user, when the tracker uses `MySQL`
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub database_root_password: Option<String>,
+
+    /// Grafana admin password, when Grafana is enabled
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub grafana_admin_password: Option<String>,
+
+    /// Password of the private container registry, when one is configured
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub registry_password: Option<String>,
+
+    /// API token of the cloud provider, for Hetzner and `DigitalOcean`
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub provider_api_token: Option<String>,
+
+    /// Trust token of the LXD remote, when one is configured with a token
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub lxd_trust_token: Option<String>,
+}
+
+impl From<&AnyEnvironmentState> for SecretsInfo {
+    fn from(any_env: &AnyEnvironmentState) -> Self {
+        let secrets = any_env.secrets();
+        let mysql = match any_env.tracker_config().core().database() {
+            DatabaseConfig::Mysql(mysql) => Some(mysql),
+            DatabaseConfig::Sqlite(_) => None,
+        };
+        let provider_api_token = match any_env.user_inputs().provider_config() {
+            ProviderConfig::Hetzner(hetzner) => Some(hetzner.api_token.expose_secret().to_string()),
+            ProviderConfig::DigitalOcean(digitalocean) => {
+                Some(digitalocean.api_token.expose_secret().to_string())
+            }
+            _ => None,
+        };
+
+        Self {
+            admin_token: any_env.admin_token().to_string(),
+            database_password: mysql.map(|mysql| mysql.password().expose_secret().to_string()),
+            database_root_password: mysql
+                .map(|mysql| mysql.root_password().expose_secret().to_string()),
+            grafana_admin_password: secrets
+                .grafana_admin_password
+                .map(|password| password.expose_secret().to_string()),
+            registry_password: secrets
+                .registry_password
+                .map(|password| password.expose_secret().to_string()),
+            provider_api_token,
+            lxd_trust_token: secrets
+                .lxd_trust_token
+                .map(|token| token.expose_secret().to_string()),
+        }
+    }
+}
@@ -0,0 +1,26 @@
+//! State file version information for display purposes
+
+use serde::Serialize;
+
+use crate::domain::environment::repository::StoredStateVersion;
+
+/// Versions recorded in the persisted state of the environment
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct StateFileInfo {
+    /// Version of the schema the state was written with
+    pub schema_version: u32,
+
+    /// Version of the deployer that last wrote the state
+    ///
+    /// `None` for states written before versions were recorded.
+    pub deployer_version: Option<String>,
+}
+
+impl From<StoredStateVersion> for StateFileInfo {
+    fn from(version: StoredStateVersion) -> Self {
+        Self {
+            schema_version: version.schema_version,
+            deployer_version: version.deployer_version,
+        }
+    }
+}
@@ -2,13 +2,13 @@
 //!
 //! This module contains DTOs for the Torrust Tracker service endpoints.
 
-use std::net::IpAddr;
+use std::net::{IpAddr, SocketAddr};
 
 use serde::Serialize;
 
 use crate::domain::grafana::GrafanaConfig;
 use crate::domain::tracker::config::is_localhost;
-use crate::domain::tracker::TrackerConfig;
+use crate::domain::tracker::{HttpTrackerConfig, TrackerConfig};
 
 /// Tracker service information for display purposes
 ///
@@ -160,22 +160,27 @@ impl ServiceInfo {
         )
     }
 
-    /// Build UDP tracker URLs from configuration
+    /// Build UDP tracker URLs of every replica from configuration
     fn build_udp_tracker_urls(tracker_config: &TrackerConfig, instance_ip: IpAddr) -> Vec<String> {
         tracker_config
-            .udp_trackers()
+            .replica_configs()
             .iter()
+            .flat_map(TrackerConfig::udp_trackers)
             .map(|udp| {
-                let host = udp
-                    .domain()
-                    .map_or_else(|| instance_ip.to_string(), |d| d.as_str().to_string());
-                format!("udp://{}:{}/announce", host, udp.bind_address().port())
+                let port = udp.bind_address().port();
+                udp.domain().map_or_else(
+                    || format!("udp://{}/announce", SocketAddr::new(instance_ip, port)),
+                    |d| format!("udp://{}:{port}/announce", d.as_str()),
+                )
             })
             .collect()
     }
 
     /// Build HTTP tracker information, separating by TLS and localhost status
     ///
+    /// Direct trackers of additional replicas are listed after the ones of
+    /// the first replica; replicas never use the TLS proxy.
+    ///
     /// Returns (`https_trackers`, `direct_trackers`, `localhost_trackers`)
     fn build_http_tracker_info(
         tracker_config: &TrackerConfig,
@@ -205,14 +210,20 @@ impl ServiceInfo {
                 });
             } else {
                 // Non-TLS, non-localhost tracker - use direct IP URL
-                direct_http_trackers.push(format!(
-                    "http://{}:{}/announce", // DevSkim: ignore DS137138
-                    instance_ip,
-                    http.bind_address().port()
-                ));
+                direct_http_trackers.push(Self::direct_http_tracker_url(http, instance_ip));
             }
         }
 
+        for replica in tracker_config.replica_configs().iter().skip(1) {
+            direct_http_trackers.extend(
+                replica
+                    .http_trackers()
+                    .iter()
+                    .filter(|http| !is_localhost(&http.bind_address()))
+                    .map(|http| Self::direct_http_tracker_url(http, instance_ip)),
+            );
+        }
+
         (
             https_http_trackers,
             direct_http_trackers,
@@ -220,6 +231,14 @@ impl ServiceInfo {
         )
     }
 
+    /// Announce URL of an HTTP tracker reached directly on the instance IP
+    fn direct_http_tracker_url(http: &HttpTrackerConfig, instance_ip: IpAddr) -> String {
+        format!(
+            "http://{}/announce", // DevSkim: ignore DS137138
+            SocketAddr::new(instance_ip, http.bind_address().port())
+        )
+    }
+
     /// Build API endpoint information
     ///
     /// Returns (`endpoint_url`, `uses_https`, `is_localhost_only`)
@@ -242,9 +261,8 @@ impl ServiceInfo {
                 // TLS proxy without domain shouldn't happen after validation
                 (
                     format!(
-                        "http://{}:{}/api", // DevSkim: ignore DS137138
-                        instance_ip,
-                        api.bind_address().port()
+                        "http://{}/api", // DevSkim: ignore DS137138
+                        SocketAddr::new(instance_ip, api.bind_address().port())
                     ),
                     false,
                 )
@@ -252,9 +270,8 @@ impl ServiceInfo {
         } else {
             (
                 format!(
-                    "http://{}:{}/api", // DevSkim: ignore DS137138
-                    instance_ip,
-                    api.bind_address().port()
+                    "http://{}/api", // DevSkim: ignore DS137138
+                    SocketAddr::new(instance_ip, api.bind_address().port())
                 ),
                 false,
             )
@@ -298,9 +315,8 @@ impl ServiceInfo {
         } else {
             (
                 format!(
-                    "http://{}:{}/health_check", // DevSkim: ignore DS137138
-                    instance_ip,
-                    health_check.bind_address().port()
+                    "http://{}/health_check", // DevSkim: ignore DS137138
+                    SocketAddr::new(instance_ip, health_check.bind_address().port())
                 ),
                 false,
             )
@@ -380,6 +396,7 @@ impl ServiceInfo {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::testing::fixtures::mysql_tracker_config;
 
     #[test]
     fn it_should_create_service_info() {
@@ -616,4 +633,29 @@ mod tests {
             "IP-only UDP trackers must not appear in all_domain_names()"
         );
     }
+
+    #[test]
+    fn it_should_list_the_trackers_of_every_replica() {
+        let tracker_config = mysql_tracker_config()
+            .with_replicas(std::num::NonZeroU8::new(2).unwrap())
+            .unwrap();
+
+        let services =
+            ServiceInfo::from_tracker_config(&tracker_config, "10.0.0.1".parse().unwrap(), None);
+
+        assert_eq!(
+            services.udp_trackers,
+            vec![
+                "udp://10.0.0.1:6969/announce".to_string(),
+                "udp://10.0.0.1:6970/announce".to_string(),
+            ]
+        );
+        assert_eq!(
+            services.direct_http_trackers,
+            vec![
+                "http://10.0.0.1:7070/announce".to_string(), // DevSkim: ignore DS137138
+                "http://10.0.0.1:7071/announce".to_string(), // DevSkim: ignore DS137138
+            ]
+        );
+    }
 }
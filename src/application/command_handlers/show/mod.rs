@@ -49,5 +49,6 @@ pub use info::DockerImagesInfo;
 pub use info::EnvironmentInfo;
 pub use info::GrafanaInfo;
 pub use info::InfrastructureInfo;
+pub use info::PhaseDurationsInfo;
 pub use info::PrometheusInfo;
 pub use info::ServiceInfo;
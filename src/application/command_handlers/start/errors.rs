@@ -0,0 +1,241 @@
+//! Error types for the Start command handler
+
+use crate::adapters::ssh::SshError;
+use crate::application::errors::{InvalidStateError, PersistenceError};
+use crate::infrastructure::remote_actions::{TrackerHealthWaitError, TrackerServicesError};
+use crate::shared::error::{ErrorKind, Traceable};
+
+/// Comprehensive error type for the `StartCommandHandler`
+#[derive(Debug, thiserror::Error)]
+pub enum StartCommandHandlerError {
+    /// Environment was not found in the repository
+    #[error("Environment not found: {name}")]
+    EnvironmentNotFound {
+        /// The name of the environment that was not found
+        name: String,
+    },
+
+    /// Environment is not stopped
+    #[error("Environment is in an invalid state for starting: {0}")]
+    InvalidState(#[from] InvalidStateError),
+
+    /// Instance IP address is not available
+    #[error("Instance IP address is not available for environment '{name}'")]
+    MissingInstanceIp {
+        /// The name of the environment missing the instance IP
+        name: String,
+    },
+
+    /// The host keys of the instance could not be checked or have changed
+    #[error("SSH host key check failed: {0}")]
+    HostKeyCheck(#[from] SshError),
+
+    /// `docker compose start` failed on the instance
+    #[error("Starting services failed: {source}")]
+    StartServicesFailed {
+        /// The underlying remote action error
+        #[source]
+        source: TrackerServicesError,
+    },
+
+    /// The tracker did not report healthy after the services were started
+    #[error("Tracker health check never became healthy: {source}")]
+    HealthCheckFailed {
+        /// The underlying wait error with the last response
+        #[source]
+        source: TrackerHealthWaitError,
+    },
+
+    /// The health wait was stopped with Ctrl-C
+    #[error("interrupted by user")]
+    Interrupted,
+
+    /// Failed to persist environment state
+    #[error("Failed to persist environment state: {0}")]
+    StatePersistence(#[from] PersistenceError),
+}
+
+impl From<crate::domain::environment::repository::RepositoryError> for StartCommandHandlerError {
+    fn from(e: crate::domain::environment::repository::RepositoryError) -> Self {
+        Self::StatePersistence(e.into())
+    }
+}
+
+impl From<crate::domain::environment::state::StateTypeError> for StartCommandHandlerError {
+    fn from(e: crate::domain::environment::state::StateTypeError) -> Self {
+        Self::InvalidState(e.into())
+    }
+}
+
+impl Traceable for StartCommandHandlerError {
+    fn trace_format(&self) -> String {
+        match self {
+            Self::EnvironmentNotFound { name } => {
+                format!("StartCommandHandlerError: Environment not found - {name}")
+            }
+            Self::InvalidState(e) => {
+                format!("StartCommandHandlerError: Invalid state for start - {e}")
+            }
+            Self::MissingInstanceIp { name } => {
+                format!(
+                    "StartCommandHandlerError: Instance IP not available for environment '{name}'"
+                )
+            }
+            Self::HostKeyCheck(e) => {
+                format!("StartCommandHandlerError: SSH host key check failed - {e}")
+            }
+            Self::StartServicesFailed { source } => {
+                format!("StartCommandHandlerError: Start services failed - {source}")
+            }
+            Self::HealthCheckFailed { source } => {
+                format!("StartCommandHandlerError: Health check never became healthy - {source}")
+            }
+            Self::Interrupted => {
+                "StartCommandHandlerError: Interrupted by user (Ctrl-C)".to_string()
+            }
+            Self::StatePersistence(e) => {
+                format!("StartCommandHandlerError: Failed to persist environment state - {e}")
+            }
+        }
+    }
+
+    fn trace_source(&self) -> Option<&dyn Traceable> {
+        match self {
+            Self::StartServicesFailed { source } => Some(source),
+            Self::HealthCheckFailed { source } => Some(source),
+            Self::HostKeyCheck(e) => Some(e),
+            Self::EnvironmentNotFound { .. }
+            | Self::InvalidState(_)
+            | Self::MissingInstanceIp { .. }
+            | Self::Interrupted
+            | Self::StatePersistence(_) => None,
+        }
+    }
+
+    fn error_kind(&self) -> ErrorKind {
+        match self {
+            Self::EnvironmentNotFound { .. }
+            | Self::InvalidState(_)
+            | Self::MissingInstanceIp { .. } => ErrorKind::Configuration,
+            Self::HostKeyCheck(e) => e.error_kind(),
+            Self::StartServicesFailed { source } => source.error_kind(),
+            Self::HealthCheckFailed { source } => source.error_kind(),
+            Self::Interrupted => ErrorKind::Interrupted,
+            Self::StatePersistence(_) => ErrorKind::StatePersistence,
+        }
+    }
+}
+
+impl StartCommandHandlerError {
+    /// Provides detailed troubleshooting guidance for this error
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use torrust_tracker_deployer_lib::application::command_handlers::start::StartCommandHandlerError;
+    ///
+    /// let error = StartCommandHandlerError::EnvironmentNotFound {
+    ///     name: "my-env".to_string(),
+    /// };
+    ///
+    /// assert!(error.help().contains("Environment Not Found"));
+    /// ```
+    #[must_use]
+    pub fn help(&self) -> &'static str {
+        match self {
+            Self::EnvironmentNotFound { .. } => {
+                "Environment Not Found - Troubleshooting:
+
+1. Verify the environment name is correct
+2. List the existing environments:
+   torrust-tracker-deployer list
+
+For more information, see docs/user-guide/commands/start.md"
+            }
+            Self::InvalidState(_) => {
+                "Invalid Environment State - Troubleshooting:
+
+Only the services of a stopped environment can be started.
+
+1. Check the environment state:
+   torrust-tracker-deployer show <env-name>
+
+2. If the environment is released but was never run, run it instead:
+   torrust-tracker-deployer run <env-name>
+
+3. If the environment is already running, nothing needs to be done
+
+For more information, see docs/user-guide/commands/start.md"
+            }
+            Self::MissingInstanceIp { .. } => {
+                "Missing Instance IP - Troubleshooting:
+
+The environment state has no instance IP, so the instance cannot be reached.
+
+1. Check the environment details:
+   torrust-tracker-deployer show <env-name>
+
+2. If the state file was edited by hand, restore it from a backup
+
+For more information, see docs/user-guide/commands/start.md"
+            }
+            Self::HostKeyCheck(e) => e.help(),
+            Self::StartServicesFailed { .. } => {
+                "Starting Services Failed - Troubleshooting:
+
+The environment is still 'stopped'; some services may have been started.
+
+1. Check SSH connectivity to the instance:
+   ssh -i <ssh-key> <user>@<instance-ip>
+
+2. Check the state of the containers:
+   ssh <user>@<instance-ip> 'cd /opt/torrust && docker compose ps -a'
+
+3. Run the start command again:
+   torrust-tracker-deployer start <env-name>
+
+For more information, see docs/user-guide/commands/start.md"
+            }
+            Self::HealthCheckFailed { .. } => {
+                "Health Check Never Became Healthy - Troubleshooting:
+
+The services were started, but the tracker Health Check API did not answer
+the expected status before the timeout.
+The environment is still 'stopped'.
+
+1. Read the tracker logs, which report errors on startup:
+   ssh <user>@<instance-ip> docker logs tracker
+
+2. If the tracker only needs longer to start, run the start command again:
+   torrust-tracker-deployer start <env-name>
+
+For more information, see docs/user-guide/commands/start.md"
+            }
+            Self::Interrupted => {
+                "Start Interrupted - Troubleshooting:
+
+The start was stopped with Ctrl-C before the tracker reported healthy.
+The environment is still 'stopped'; the services may be running.
+
+1. Check the state of the containers:
+   ssh <user>@<instance-ip> 'cd /opt/torrust && docker compose ps -a'
+
+2. Run the start command again:
+   torrust-tracker-deployer start <env-name>
+
+For more information, see docs/user-guide/commands/start.md"
+            }
+            Self::StatePersistence(_) => {
+                "State Persistence Failed - Troubleshooting:
+
+The services were started, but the environment state could not be saved.
+
+1. Check file system permissions for the data directory
+2. Verify available disk space: df -h
+3. Ensure no other command is running on the environment
+
+For more information, see docs/user-guide/commands/start.md"
+            }
+        }
+    }
+}
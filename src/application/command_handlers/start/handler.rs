@@ -0,0 +1,145 @@
+//! Start command handler implementation
+//!
+//! **Purpose**: Bring the tracker of a stopped environment back online
+//!
+//! This handler starts the Docker Compose services of a `Stopped`
+//! environment again, waits for the tracker to report healthy and records
+//! the environment as `Running`.
+
+use std::net::SocketAddr;
+use std::sync::Arc;
+
+use tokio_util::sync::CancellationToken;
+use tracing::{info, instrument};
+
+use super::errors::StartCommandHandlerError;
+use crate::adapters::ssh::SshConfig;
+use crate::application::command_handlers::common::verify_host_keys;
+use crate::application::steps::application::{ResumeServicesStep, WaitForTrackerHealthStep};
+use crate::domain::environment::repository::{EnvironmentRepository, TypedEnvironmentRepository};
+use crate::domain::environment::{Environment, Running, Stopped};
+use crate::domain::EnvironmentName;
+
+/// `StartCommandHandler` starts the stopped tracker services of an environment
+///
+/// # State Management
+///
+/// - Accepts environment in `Stopped` state
+/// - Transitions to `Environment<Running>` once the tracker reports healthy
+/// - Leaves the state unchanged on error
+pub struct StartCommandHandler {
+    pub(crate) repository: TypedEnvironmentRepository,
+    cancellation: CancellationToken,
+}
+
+impl StartCommandHandler {
+    /// Create a new `StartCommandHandler`
+    #[must_use]
+    pub fn new(repository: Arc<dyn EnvironmentRepository>) -> Self {
+        Self {
+            repository: TypedEnvironmentRepository::new(repository),
+            cancellation: CancellationToken::new(),
+        }
+    }
+
+    /// Stop waiting for the tracker health when the given token is cancelled
+    /// (e.g. on Ctrl-C); the environment stays `Stopped`
+    #[must_use]
+    pub fn with_cancellation(mut self, cancellation: CancellationToken) -> Self {
+        self.cancellation = cancellation;
+        self
+    }
+
+    /// Execute the start workflow
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if:
+    /// * Environment not found
+    /// * Environment is not in `Stopped` state
+    /// * Instance IP is not available
+    /// * The host keys of the instance changed
+    /// * Starting the services fails
+    /// * The tracker never reports healthy
+    /// * The health wait is interrupted (Ctrl-C)
+    /// * State persistence fails
+    #[allow(clippy::result_large_err)]
+    #[instrument(
+        name = "command.start",
+        skip_all,
+        fields(
+            command_type = "start",
+            environment_name = %env_name
+        )
+    )]
+    pub fn execute(
+        &self,
+        env_name: &EnvironmentName,
+    ) -> Result<Environment<Running>, StartCommandHandlerError> {
+        let _lock = self.repository.inner().lock(env_name)?;
+        let environment = self.load_stopped_environment(env_name)?;
+
+        let instance_ip = environment.instance_ip().ok_or_else(|| {
+            StartCommandHandlerError::MissingInstanceIp {
+                name: env_name.to_string(),
+            }
+        })?;
+
+        // Refuse to act on a host presenting other keys than the recorded ones
+        verify_host_keys(&environment, instance_ip)?;
+
+        let ssh_config = SshConfig::new(
+            environment.ssh_credentials().clone(),
+            SocketAddr::new(instance_ip, environment.ssh_port()),
+        )
+        .with_known_hosts(environment.ssh_known_hosts_path());
+
+        ResumeServicesStep::new(ssh_config.clone())
+            .execute()
+            .map_err(|source| StartCommandHandlerError::StartServicesFailed { source })?;
+
+        let health_check_api = environment.tracker_config().health_check_api();
+        WaitForTrackerHealthStep::new(
+            ssh_config,
+            health_check_api.bind_address(),
+            *health_check_api.wait(),
+            environment.tracker_config().replica_names(),
+        )
+        .with_cancellation(self.cancellation.clone())
+        .execute()
+        .map_err(|source| {
+            if source.is_interrupted() {
+                StartCommandHandlerError::Interrupted
+            } else {
+                StartCommandHandlerError::HealthCheckFailed { source }
+            }
+        })?;
+
+        let running = environment.start();
+        self.repository.save_running(&running)?;
+
+        info!(
+            command = "start",
+            environment = %running.name(),
+            final_state = "running",
+            "Services started and healthy"
+        );
+
+        Ok(running)
+    }
+
+    /// Load the environment and check it is `Stopped`
+    #[allow(clippy::result_large_err)]
+    fn load_stopped_environment(
+        &self,
+        env_name: &EnvironmentName,
+    ) -> Result<Environment<Stopped>, StartCommandHandlerError> {
+        let any_env = self.repository.inner().load(env_name)?.ok_or_else(|| {
+            StartCommandHandlerError::EnvironmentNotFound {
+                name: env_name.to_string(),
+            }
+        })?;
+
+        Ok(any_env.try_into_stopped()?)
+    }
+}
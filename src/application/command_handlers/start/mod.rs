@@ -0,0 +1,31 @@
+//! Start Command Module
+//!
+//! This module implements the delivery-agnostic `StartCommandHandler`
+//! for starting the tracker services of a `Stopped` environment again.
+//!
+//! ## Start Workflow
+//!
+//! 1. **Load environment** - Retrieve environment from repository
+//! 2. **Validate state** - Ensure environment is `Stopped`
+//! 3. **Start services** - `docker compose start` on the instance, bringing
+//!    back the containers of the deployed release without pulling or
+//!    recreating them
+//! 4. **Wait for health** - Wait for the tracker Health Check API to report
+//!    healthy, as `run` does
+//!
+//! ## State Management
+//!
+//! - Accepts environment in `Stopped` state
+//! - Returns `Environment<Running>` on success
+//! - Leaves the state unchanged on error, so `start` can be run again once
+//!   the cause is fixed
+
+pub mod errors;
+pub mod handler;
+
+#[cfg(test)]
+mod tests;
+
+// Re-export main types for convenience
+pub use errors::StartCommandHandlerError;
+pub use handler::StartCommandHandler;
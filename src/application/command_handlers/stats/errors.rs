@@ -0,0 +1,191 @@
+//! Error types for stats command handler
+
+use crate::application::command_handlers::list::errors::ListCommandHandlerError;
+use crate::application::errors::{AllowedCommands, PersistenceError};
+use crate::infrastructure::tracker_api::TrackerApiError;
+use crate::shared::error::kind::ErrorKind;
+use crate::shared::error::traceable::Traceable;
+
+/// Comprehensive error type for the `StatsCommandHandler`
+#[derive(Debug, thiserror::Error)]
+pub enum StatsCommandHandlerError {
+    #[error("Environment not found: '{name}'")]
+    EnvironmentNotFound { name: String },
+
+    #[error("Failed to load environment: {0}")]
+    LoadError(#[from] PersistenceError),
+
+    #[error("Failed to read the workspace environments: {0}")]
+    WorkspaceRead(#[from] ListCommandHandlerError),
+
+    /// The tracker statistics can only be read from a running tracker
+    #[error("Environment '{name}' is in state '{state}', but the tracker must be running to read its statistics; {allowed_commands}")]
+    EnvironmentNotRunning {
+        name: String,
+        state: String,
+        allowed_commands: AllowedCommands,
+    },
+
+    /// The environment has no instance to call
+    #[error("Environment '{name}' does not have an instance IP set")]
+    MissingInstanceIp { name: String },
+
+    /// The tracker API call failed
+    #[error("Failed to read the tracker statistics of environment '{name}': {source}")]
+    TrackerApi {
+        name: String,
+        #[source]
+        source: TrackerApiError,
+    },
+}
+
+impl From<crate::domain::environment::repository::RepositoryError> for StatsCommandHandlerError {
+    fn from(e: crate::domain::environment::repository::RepositoryError) -> Self {
+        Self::LoadError(e.into())
+    }
+}
+
+impl Traceable for StatsCommandHandlerError {
+    fn trace_format(&self) -> String {
+        match self {
+            Self::EnvironmentNotFound { name } => {
+                format!("StatsCommandHandlerError: Environment not found - '{name}'")
+            }
+            Self::LoadError(e) => {
+                format!("StatsCommandHandlerError: Failed to load environment - {e}")
+            }
+            Self::WorkspaceRead(e) => {
+                format!("StatsCommandHandlerError: Failed to read the workspace environments - {e}")
+            }
+            Self::EnvironmentNotRunning { name, state, .. } => {
+                format!(
+                    "StatsCommandHandlerError: Environment '{name}' is not running (state: {state})"
+                )
+            }
+            Self::MissingInstanceIp { name } => {
+                format!("StatsCommandHandlerError: Missing instance IP for environment '{name}'")
+            }
+            Self::TrackerApi { name, source } => {
+                format!("StatsCommandHandlerError: Tracker API call failed for '{name}' - {source}")
+            }
+        }
+    }
+
+    fn trace_source(&self) -> Option<&dyn Traceable> {
+        match self {
+            Self::WorkspaceRead(e) => Some(e),
+            Self::EnvironmentNotFound { .. }
+            | Self::LoadError(_)
+            | Self::EnvironmentNotRunning { .. }
+            | Self::MissingInstanceIp { .. }
+            | Self::TrackerApi { .. } => None,
+        }
+    }
+
+    fn error_kind(&self) -> ErrorKind {
+        match self {
+            Self::EnvironmentNotFound { .. } => ErrorKind::Configuration,
+            Self::LoadError(_) => ErrorKind::StatePersistence,
+            Self::WorkspaceRead(e) => e.error_kind(),
+            Self::EnvironmentNotRunning { .. } | Self::MissingInstanceIp { .. } => {
+                ErrorKind::Configuration
+            }
+            Self::TrackerApi { source, .. } => match source {
+                TrackerApiError::Unreachable { .. } => ErrorKind::NetworkConnectivity,
+                TrackerApiError::Unauthorized { .. } => ErrorKind::Configuration,
+                TrackerApiError::ClientBuild { .. }
+                | TrackerApiError::RequestFailed { .. }
+                | TrackerApiError::InvalidResponse { .. } => ErrorKind::CommandExecution,
+            },
+        }
+    }
+}
+
+impl StatsCommandHandlerError {
+    /// Provides detailed troubleshooting guidance for this error
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use torrust_tracker_deployer_lib::application::command_handlers::stats::StatsCommandHandlerError;
+    ///
+    /// let error = StatsCommandHandlerError::EnvironmentNotFound {
+    ///     name: "my-env".to_string(),
+    /// };
+    ///
+    /// assert!(error.help().contains("list"));
+    /// ```
+    #[must_use]
+    pub fn help(&self) -> &'static str {
+        match self {
+            Self::EnvironmentNotFound { .. } => {
+                "Environment Not Found - Troubleshooting:
+
+1. Verify the environment name is correct
+2. List the existing environments:
+   torrust-tracker-deployer list
+
+For more information, see docs/user-guide/commands/stats.md"
+            }
+            Self::LoadError(_) => {
+                "Failed to Load Environment - Troubleshooting:
+
+1. Check file permissions on the data/ directory
+2. Verify the environment state file is not corrupted:
+   cat data/<name>/environment.json
+
+For more information, see docs/user-guide/commands/stats.md"
+            }
+            Self::WorkspaceRead(e) => e.help(),
+            Self::EnvironmentNotRunning { .. } => {
+                "Environment Not Running - Troubleshooting:
+
+The statistics are read from the API of the deployed tracker, so the
+environment must be in the 'running' state.
+
+1. Check the environment state:
+   torrust-tracker-deployer show <env-name>
+
+2. Complete the deployment workflow, or start stopped services:
+   torrust-tracker-deployer run <env-name>
+   torrust-tracker-deployer start <env-name>
+
+The deployment phase durations can be read in any state:
+   torrust-tracker-deployer stats <env-name>
+
+For more information, see docs/user-guide/commands/stats.md"
+            }
+            Self::MissingInstanceIp { .. } => {
+                "Missing Instance IP - Troubleshooting:
+
+The environment has no instance IP address, so its tracker API cannot be
+reached. Provision or register the instance first:
+   torrust-tracker-deployer provision <env-name>
+
+For more information, see docs/user-guide/commands/stats.md"
+            }
+            Self::TrackerApi { .. } => {
+                "Tracker API Call Failed - Troubleshooting:
+
+1. Check that the environment is running and the tracker API answers:
+   torrust-tracker-deployer test <env-name> --check health,api
+
+2. Check that the API port is exposed: the firewall must allow the API
+   port, or port 443 when the API is behind the TLS proxy (Caddy)
+
+3. Check that the API binds to a public address (not 127.0.0.1) in the
+   tracker configuration of the environment
+
+4. If the admin token was rejected, the deployed tracker uses another
+   token than the environment: release the environment again:
+   torrust-tracker-deployer release <env-name>
+   torrust-tracker-deployer run <env-name>
+
+5. Certificates of .local domains and of the Let's Encrypt staging CA are
+   accepted; any other certificate must be trusted by this machine
+
+For more information, see docs/user-guide/commands/stats.md"
+            }
+        }
+    }
+}
@@ -0,0 +1,363 @@
+//! Stats command handler implementation
+//!
+//! **Purpose**: Read the deployment phase durations of the workspace
+//!
+//! A single environment is loaded through the repository. Workspace
+//! aggregates reuse the list handler to load every environment in one pass.
+//! The live statistics of a running tracker are read through its API.
+
+use std::sync::Arc;
+
+use tracing::{info, instrument};
+
+use super::errors::StatsCommandHandlerError;
+use super::info::{RemoteStats, ReplicaStats, WorkspaceStats};
+use crate::application::command_handlers::common::endpoint_builder;
+use crate::application::command_handlers::list::ListCommandHandler;
+use crate::application::command_handlers::show::PhaseDurationsInfo;
+use crate::application::errors::AllowedCommands;
+use crate::domain::environment::repository::EnvironmentRepository;
+use crate::domain::environment::AnyEnvironmentState;
+use crate::domain::https::HttpsConfig;
+use crate::domain::EnvironmentName;
+use crate::infrastructure::tracker_api::{TrackerApiClient, TrackerApiError};
+
+/// `StatsCommandHandler` reads deployment phase durations and tracker statistics
+///
+/// **Purpose**: Read-only trend tracking of deployment times and tracker load
+///
+/// This handler never modifies state. Only [`Self::remote`] makes network calls.
+pub struct StatsCommandHandler {
+    repository: Arc<dyn EnvironmentRepository + Send + Sync>,
+    list_handler: ListCommandHandler,
+}
+
+impl StatsCommandHandler {
+    /// Create a new `StatsCommandHandler`
+    #[must_use]
+    pub fn new(
+        repository: Arc<dyn EnvironmentRepository + Send + Sync>,
+        list_handler: ListCommandHandler,
+    ) -> Self {
+        Self {
+            repository,
+            list_handler,
+        }
+    }
+
+    /// Read the phase durations recorded for one environment
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if:
+    /// * Environment not found
+    /// * Environment state file is corrupted or unreadable
+    #[instrument(
+        name = "command.stats",
+        skip_all,
+        fields(
+            command_type = "stats",
+            environment_name = %env_name
+        )
+    )]
+    pub fn for_environment(
+        &self,
+        env_name: &EnvironmentName,
+    ) -> Result<PhaseDurationsInfo, StatsCommandHandlerError> {
+        let any_env = self.repository.load(env_name)?.ok_or_else(|| {
+            StatsCommandHandlerError::EnvironmentNotFound {
+                name: env_name.to_string(),
+            }
+        })?;
+
+        Ok(PhaseDurationsInfo::from(any_env.phase_durations()))
+    }
+
+    /// Aggregate the phase durations of every environment in the workspace
+    ///
+    /// Environments that fail to load are skipped, as in `list --details`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the data directory cannot be read
+    #[instrument(name = "command.stats_all", skip_all, fields(command_type = "stats"))]
+    pub fn all(&self) -> Result<WorkspaceStats, StatsCommandHandlerError> {
+        let durations: Vec<PhaseDurationsInfo> = self
+            .list_handler
+            .execute_details()?
+            .iter()
+            .map(|info| info.durations)
+            .collect();
+
+        Ok(WorkspaceStats::from_durations(&durations))
+    }
+
+    /// Read the statistics of every tracker replica of a running environment
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if:
+    /// * Environment not found or unreadable
+    /// * The environment is not running or has no instance IP
+    /// * The API of a replica cannot be reached or rejects the admin token
+    #[instrument(
+        name = "command.stats_remote",
+        skip_all,
+        fields(
+            command_type = "stats",
+            environment_name = %env_name
+        )
+    )]
+    pub async fn remote(
+        &self,
+        env_name: &EnvironmentName,
+    ) -> Result<RemoteStats, StatsCommandHandlerError> {
+        let environment = self.repository.load(env_name)?.ok_or_else(|| {
+            StatsCommandHandlerError::EnvironmentNotFound {
+                name: env_name.to_string(),
+            }
+        })?;
+
+        let api_failed = |source: TrackerApiError| StatsCommandHandlerError::TrackerApi {
+            name: env_name.to_string(),
+            source,
+        };
+
+        let mut replicas = Vec::new();
+        for client in Self::tracker_clients(&environment, env_name)? {
+            let url = client.stats_url().map_err(api_failed)?;
+            let stats = client.stats().await.map_err(api_failed)?;
+            replicas.push(ReplicaStats { url, stats });
+        }
+
+        info!(
+            command = "stats",
+            environment = %env_name,
+            replicas = replicas.len(),
+            "Tracker statistics read"
+        );
+
+        Ok(RemoteStats {
+            environment_name: env_name.to_string(),
+            replicas,
+        })
+    }
+
+    /// One API client per tracker replica, after checking the tracker can be called
+    fn tracker_clients(
+        environment: &AnyEnvironmentState,
+        env_name: &EnvironmentName,
+    ) -> Result<Vec<TrackerApiClient>, StatsCommandHandlerError> {
+        if !matches!(environment, AnyEnvironmentState::Running(_)) {
+            return Err(StatsCommandHandlerError::EnvironmentNotRunning {
+                name: env_name.to_string(),
+                state: environment.state_name().to_string(),
+                allowed_commands: AllowedCommands::from(environment),
+            });
+        }
+
+        let instance_ip = environment.instance_ip().ok_or_else(|| {
+            StatsCommandHandlerError::MissingInstanceIp {
+                name: env_name.to_string(),
+            }
+        })?;
+
+        let staging_certs = environment
+            .https_config()
+            .is_some_and(HttpsConfig::use_staging);
+
+        Ok(environment
+            .tracker_config()
+            .replica_configs()
+            .iter()
+            .map(|replica| {
+                let api = replica.http_api();
+                TrackerApiClient::new(
+                    endpoint_builder::build_api_v1_endpoint(instance_ip, api),
+                    api.admin_token().clone(),
+                )
+                .with_untrusted_certs(staging_certs)
+            })
+            .collect())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::net::{IpAddr, Ipv4Addr};
+
+    use tokio::io::{AsyncReadExt, AsyncWriteExt};
+    use tokio::net::TcpListener;
+
+    use super::*;
+    use crate::domain::environment::runtime_outputs::ProvisionMethod;
+    use crate::domain::environment::testing::EnvironmentTestBuilder;
+    use crate::domain::environment::DeploymentPhase;
+    use crate::domain::tracker::{
+        DatabaseConfig, HealthCheckApiConfig, HttpApiConfig, SqliteConfig, TrackerConfig,
+        TrackerCoreConfig,
+    };
+    use crate::infrastructure::persistence::file_repository_factory::FileRepositoryFactory;
+    use crate::shared::{ApiToken, SystemClock};
+
+    /// Handler over a workspace with an environment per entry of `provision_ms`
+    fn setup(provision_ms: &[(&str, Option<u64>)]) -> (StatsCommandHandler, tempfile::TempDir) {
+        let temp_dir = tempfile::TempDir::new().unwrap();
+        let data_dir = temp_dir.path().join("data");
+        let factory = Arc::new(FileRepositoryFactory::new(std::time::Duration::from_secs(
+            30,
+        )));
+        let repository = factory.create(data_dir.clone());
+
+        for (name, duration_ms) in provision_ms {
+            let (env, _data_dir, _build_dir, _env_dir) = EnvironmentTestBuilder::new()
+                .with_name(name)
+                .build_with_custom_paths();
+            let env = match duration_ms {
+                Some(ms) => env.with_phase_duration(DeploymentPhase::Provision, *ms),
+                None => env,
+            };
+            repository.save(&env.into_any()).unwrap();
+        }
+
+        let list_handler = ListCommandHandler::new(factory, data_dir.into(), Arc::new(SystemClock));
+
+        (StatsCommandHandler::new(repository, list_handler), temp_dir)
+    }
+
+    fn env_name(name: &str) -> EnvironmentName {
+        EnvironmentName::new(name.to_string()).unwrap()
+    }
+
+    #[test]
+    fn it_should_read_the_durations_of_one_environment() {
+        let (handler, _temp_dir) = setup(&[("my-env", Some(95_000))]);
+
+        let durations = handler.for_environment(&env_name("my-env")).unwrap();
+
+        assert_eq!(durations.provision_ms, Some(95_000));
+        assert_eq!(durations.configure_ms, None);
+    }
+
+    #[test]
+    fn it_should_fail_for_an_environment_that_does_not_exist() {
+        let (handler, _temp_dir) = setup(&[]);
+
+        let result = handler.for_environment(&env_name("missing"));
+
+        assert!(matches!(
+            result,
+            Err(StatsCommandHandlerError::EnvironmentNotFound { .. })
+        ));
+    }
+
+    #[test]
+    fn it_should_aggregate_the_durations_of_every_environment() {
+        let (handler, _temp_dir) = setup(&[
+            ("env-a", Some(90_000)),
+            ("env-b", Some(110_000)),
+            ("env-c", None),
+        ]);
+
+        let stats = handler.all().unwrap();
+
+        assert_eq!(stats.environments, 3);
+        assert_eq!(stats.phases.len(), 1);
+        assert_eq!(stats.phases[0].samples, 2);
+        assert_eq!(stats.phases[0].avg_ms, 100_000);
+    }
+
+    /// Handler over a workspace with a single environment named `tmp`
+    /// whose tracker API is on `api_port`, running when `running` is set
+    fn setup_remote(api_port: u16, running: bool) -> (StatsCommandHandler, tempfile::TempDir) {
+        let temp_dir = tempfile::TempDir::new().unwrap();
+        let data_dir = temp_dir.path().join("data");
+        let factory = Arc::new(FileRepositoryFactory::new(std::time::Duration::from_secs(
+            30,
+        )));
+        let repository = factory.create(data_dir.clone());
+
+        let tracker_config = TrackerConfig::new(
+            TrackerCoreConfig::new(
+                DatabaseConfig::Sqlite(SqliteConfig::new("tracker.db").unwrap()),
+                false,
+            ),
+            vec![],
+            vec![],
+            HttpApiConfig::new(
+                format!("0.0.0.0:{api_port}").parse().unwrap(),
+                ApiToken::from("MyAccessToken".to_string()),
+                None,
+                false,
+            )
+            .unwrap(),
+            HealthCheckApiConfig::new("127.0.0.1:1313".parse().unwrap(), None, false).unwrap(),
+        )
+        .unwrap();
+        let (env, _data_dir, _build_dir, _env_dir) = EnvironmentTestBuilder::new()
+            .with_name("tmp")
+            .with_tracker_config(tracker_config)
+            .build_with_custom_paths();
+        let env = if running {
+            env.start_provisioning()
+                .provisioned(
+                    IpAddr::V4(Ipv4Addr::LOCALHOST),
+                    ProvisionMethod::Provisioned,
+                )
+                .start_configuring()
+                .configured()
+                .start_releasing()
+                .released()
+                .start_running()
+                .into_any()
+        } else {
+            env.into_any()
+        };
+        repository.save(&env).unwrap();
+
+        let list_handler = ListCommandHandler::new(factory, data_dir.into(), Arc::new(SystemClock));
+
+        (StatsCommandHandler::new(repository, list_handler), temp_dir)
+    }
+
+    #[tokio::test]
+    async fn it_should_read_the_statistics_of_a_running_tracker() {
+        let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let port = listener.local_addr().unwrap().port();
+        let server = tokio::spawn(async move {
+            let (mut stream, _) = listener.accept().await.unwrap();
+            let mut buffer = vec![0; 4096];
+            let _ = stream.read(&mut buffer).await.unwrap();
+            let body = r#"{"torrents":1,"seeders":0,"completed":0,"leechers":1}"#;
+            let response = format!(
+                "HTTP/1.1 200 OK\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{body}",
+                body.len()
+            );
+            stream.write_all(response.as_bytes()).await.unwrap();
+        });
+        let (handler, _temp_dir) = setup_remote(port, true);
+
+        let stats = handler.remote(&env_name("tmp")).await.unwrap();
+        server.await.unwrap();
+
+        assert_eq!(stats.replicas.len(), 1);
+        assert_eq!(
+            stats.replicas[0].url,
+            format!("http://127.0.0.1:{port}/api/v1/stats")
+        );
+        assert_eq!(stats.replicas[0].stats.torrents, 1);
+        assert_eq!(stats.replicas[0].stats.peers(), 1);
+    }
+
+    #[tokio::test]
+    async fn it_should_refuse_to_read_the_statistics_of_a_tracker_that_is_not_running() {
+        let (handler, _temp_dir) = setup_remote(1212, false);
+
+        let result = handler.remote(&env_name("tmp")).await;
+
+        assert!(matches!(
+            result,
+            Err(StatsCommandHandlerError::EnvironmentNotRunning { .. })
+        ));
+    }
+}
@@ -0,0 +1,145 @@
+//! Aggregated phase durations and tracker statistics for display purposes
+
+use serde::Serialize;
+
+use crate::application::command_handlers::show::PhaseDurationsInfo;
+use crate::domain::environment::DeploymentPhase;
+use crate::infrastructure::tracker_api::TrackerStats;
+
+/// Phase durations aggregated across the environments of a workspace
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct WorkspaceStats {
+    /// Number of environments that were read
+    pub environments: usize,
+
+    /// Aggregates of every phase with at least one recorded duration,
+    /// in deployment order
+    pub phases: Vec<PhaseStats>,
+}
+
+/// Minimum, average and maximum duration of one deployment phase
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+pub struct PhaseStats {
+    /// The aggregated phase
+    pub phase: DeploymentPhase,
+
+    /// Number of environments with a recorded duration for the phase
+    pub samples: usize,
+
+    /// Shortest recorded duration, in milliseconds
+    pub min_ms: u64,
+
+    /// Average recorded duration, in milliseconds (rounded down)
+    pub avg_ms: u64,
+
+    /// Longest recorded duration, in milliseconds
+    pub max_ms: u64,
+}
+
+/// Statistics read from the API of every tracker replica of an environment
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct RemoteStats {
+    /// Name of the environment
+    pub environment_name: String,
+
+    /// Statistics of each replica, in replica order
+    pub replicas: Vec<ReplicaStats>,
+}
+
+/// Statistics of one tracker replica
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct ReplicaStats {
+    /// URL of the statistics endpoint, without the admin token
+    pub url: String,
+
+    /// Counters returned by the tracker
+    pub stats: TrackerStats,
+}
+
+impl WorkspaceStats {
+    /// Aggregate the phase durations of a set of environments
+    #[must_use]
+    pub fn from_durations(durations: &[PhaseDurationsInfo]) -> Self {
+        let phases = DeploymentPhase::ALL
+            .iter()
+            .filter_map(|phase| {
+                let samples: Vec<u64> = durations.iter().filter_map(|d| d.get(*phase)).collect();
+                PhaseStats::from_samples(*phase, &samples)
+            })
+            .collect();
+
+        Self {
+            environments: durations.len(),
+            phases,
+        }
+    }
+}
+
+impl PhaseStats {
+    /// Aggregate the samples of a phase, `None` when there are none
+    fn from_samples(phase: DeploymentPhase, samples: &[u64]) -> Option<Self> {
+        let min_ms = *samples.iter().min()?;
+        let max_ms = *samples.iter().max()?;
+        let total: u128 = samples.iter().map(|ms| u128::from(*ms)).sum();
+        let avg_ms = u64::try_from(total / samples.len() as u128).unwrap_or(u64::MAX);
+
+        Some(Self {
+            phase,
+            samples: samples.len(),
+            min_ms,
+            avg_ms,
+            max_ms,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn durations(provision_ms: Option<u64>, run_ms: Option<u64>) -> PhaseDurationsInfo {
+        PhaseDurationsInfo {
+            provision_ms,
+            run_ms,
+            ..PhaseDurationsInfo::default()
+        }
+    }
+
+    #[test]
+    fn it_should_aggregate_the_recorded_durations_of_each_phase() {
+        let stats = WorkspaceStats::from_durations(&[
+            durations(Some(90_000), Some(4_000)),
+            durations(Some(100_000), None),
+            durations(Some(125_000), None),
+        ]);
+
+        assert_eq!(stats.environments, 3);
+        assert_eq!(
+            stats.phases,
+            vec![
+                PhaseStats {
+                    phase: DeploymentPhase::Provision,
+                    samples: 3,
+                    min_ms: 90_000,
+                    avg_ms: 105_000,
+                    max_ms: 125_000,
+                },
+                PhaseStats {
+                    phase: DeploymentPhase::Run,
+                    samples: 1,
+                    min_ms: 4_000,
+                    avg_ms: 4_000,
+                    max_ms: 4_000,
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn it_should_have_no_phases_when_no_duration_was_recorded() {
+        let stats = WorkspaceStats::from_durations(&[PhaseDurationsInfo::default()]);
+
+        assert_eq!(stats.environments, 1);
+        assert!(stats.phases.is_empty());
+    }
+}
@@ -0,0 +1,34 @@
+//! Stats Command Module
+//!
+//! This module implements the delivery-agnostic `StatsCommandHandler`
+//! for reading the deployment phase durations of one environment, or
+//! aggregating them across every environment of the workspace. It also
+//! reads the live statistics of a running tracker through its API.
+//!
+//! ## Durations
+//!
+//! The provision, configure, release and run commands record in the
+//! environment state how long their last successful run took. Environments
+//! deployed before durations were recorded simply have no samples.
+//!
+//! ## Tracker Statistics
+//!
+//! `StatsCommandHandler::remote` calls `GET /api/v1/stats` on every tracker
+//! replica with the admin token of the environment, on the API endpoint
+//! derived from the tracker configuration (HTTPS when the API is behind the
+//! TLS proxy). The environment must be running.
+//!
+//! ## Design Principles
+//!
+//! - **Read-Only Operation**: Never modifies environment state
+//! - **Local Durations**: Phase durations are read from local data only
+//! - **Missing Samples Are Skipped**: Aggregates only count recorded durations
+
+pub mod errors;
+pub mod handler;
+pub mod info;
+
+// Re-export main types for convenience
+pub use errors::StatsCommandHandlerError;
+pub use handler::StatsCommandHandler;
+pub use info::{PhaseStats, RemoteStats, ReplicaStats, WorkspaceStats};
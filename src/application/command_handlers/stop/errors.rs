@@ -0,0 +1,192 @@
+//! Error types for the Stop command handler
+
+use crate::adapters::ssh::SshError;
+use crate::application::errors::{InvalidStateError, PersistenceError};
+use crate::infrastructure::remote_actions::TrackerServicesError;
+use crate::shared::error::{ErrorKind, Traceable};
+
+/// Comprehensive error type for the `StopCommandHandler`
+#[derive(Debug, thiserror::Error)]
+pub enum StopCommandHandlerError {
+    /// Environment was not found in the repository
+    #[error("Environment not found: {name}")]
+    EnvironmentNotFound {
+        /// The name of the environment that was not found
+        name: String,
+    },
+
+    /// Environment is not running
+    #[error("Environment is in an invalid state for stopping: {0}")]
+    InvalidState(#[from] InvalidStateError),
+
+    /// Instance IP address is not available
+    #[error("Instance IP address is not available for environment '{name}'")]
+    MissingInstanceIp {
+        /// The name of the environment missing the instance IP
+        name: String,
+    },
+
+    /// The host keys of the instance could not be checked or have changed
+    #[error("SSH host key check failed: {0}")]
+    HostKeyCheck(#[from] SshError),
+
+    /// `docker compose stop` failed on the instance
+    #[error("Stopping services failed: {source}")]
+    StopServicesFailed {
+        /// The underlying remote action error
+        #[source]
+        source: TrackerServicesError,
+    },
+
+    /// Failed to persist environment state
+    #[error("Failed to persist environment state: {0}")]
+    StatePersistence(#[from] PersistenceError),
+}
+
+impl From<crate::domain::environment::repository::RepositoryError> for StopCommandHandlerError {
+    fn from(e: crate::domain::environment::repository::RepositoryError) -> Self {
+        Self::StatePersistence(e.into())
+    }
+}
+
+impl From<crate::domain::environment::state::StateTypeError> for StopCommandHandlerError {
+    fn from(e: crate::domain::environment::state::StateTypeError) -> Self {
+        Self::InvalidState(e.into())
+    }
+}
+
+impl Traceable for StopCommandHandlerError {
+    fn trace_format(&self) -> String {
+        match self {
+            Self::EnvironmentNotFound { name } => {
+                format!("StopCommandHandlerError: Environment not found - {name}")
+            }
+            Self::InvalidState(e) => {
+                format!("StopCommandHandlerError: Invalid state for stop - {e}")
+            }
+            Self::MissingInstanceIp { name } => {
+                format!(
+                    "StopCommandHandlerError: Instance IP not available for environment '{name}'"
+                )
+            }
+            Self::HostKeyCheck(e) => {
+                format!("StopCommandHandlerError: SSH host key check failed - {e}")
+            }
+            Self::StopServicesFailed { source } => {
+                format!("StopCommandHandlerError: Stop services failed - {source}")
+            }
+            Self::StatePersistence(e) => {
+                format!("StopCommandHandlerError: Failed to persist environment state - {e}")
+            }
+        }
+    }
+
+    fn trace_source(&self) -> Option<&dyn Traceable> {
+        match self {
+            Self::StopServicesFailed { source } => Some(source),
+            Self::HostKeyCheck(e) => Some(e),
+            Self::EnvironmentNotFound { .. }
+            | Self::InvalidState(_)
+            | Self::MissingInstanceIp { .. }
+            | Self::StatePersistence(_) => None,
+        }
+    }
+
+    fn error_kind(&self) -> ErrorKind {
+        match self {
+            Self::EnvironmentNotFound { .. }
+            | Self::InvalidState(_)
+            | Self::MissingInstanceIp { .. } => ErrorKind::Configuration,
+            Self::HostKeyCheck(e) => e.error_kind(),
+            Self::StopServicesFailed { source } => source.error_kind(),
+            Self::StatePersistence(_) => ErrorKind::StatePersistence,
+        }
+    }
+}
+
+impl StopCommandHandlerError {
+    /// Provides detailed troubleshooting guidance for this error
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use torrust_tracker_deployer_lib::application::command_handlers::stop::StopCommandHandlerError;
+    ///
+    /// let error = StopCommandHandlerError::EnvironmentNotFound {
+    ///     name: "my-env".to_string(),
+    /// };
+    ///
+    /// assert!(error.help().contains("Environment Not Found"));
+    /// ```
+    #[must_use]
+    pub fn help(&self) -> &'static str {
+        match self {
+            Self::EnvironmentNotFound { .. } => {
+                "Environment Not Found - Troubleshooting:
+
+1. Verify the environment name is correct
+2. List the existing environments:
+   torrust-tracker-deployer list
+
+For more information, see docs/user-guide/commands/stop.md"
+            }
+            Self::InvalidState(_) => {
+                "Invalid Environment State - Troubleshooting:
+
+Only the services of a running environment can be stopped.
+
+1. Check the environment state:
+   torrust-tracker-deployer show <env-name>
+
+2. If the services are already stopped, nothing needs to be done; start
+   them again with:
+   torrust-tracker-deployer start <env-name>
+
+3. If the services were never started, run them:
+   torrust-tracker-deployer run <env-name>
+
+For more information, see docs/user-guide/commands/stop.md"
+            }
+            Self::MissingInstanceIp { .. } => {
+                "Missing Instance IP - Troubleshooting:
+
+The environment state has no instance IP, so the instance cannot be reached.
+
+1. Check the environment details:
+   torrust-tracker-deployer show <env-name>
+
+2. If the state file was edited by hand, restore it from a backup
+
+For more information, see docs/user-guide/commands/stop.md"
+            }
+            Self::HostKeyCheck(e) => e.help(),
+            Self::StopServicesFailed { .. } => {
+                "Stopping Services Failed - Troubleshooting:
+
+The environment is still 'running'; some services may have been stopped.
+
+1. Check SSH connectivity to the instance:
+   ssh -i <ssh-key> <user>@<instance-ip>
+
+2. Check the state of the containers:
+   ssh <user>@<instance-ip> 'cd /opt/torrust && docker compose ps'
+
+3. Run the stop command again:
+   torrust-tracker-deployer stop <env-name>
+
+For more information, see docs/user-guide/commands/stop.md"
+            }
+            Self::StatePersistence(_) => {
+                "State Persistence Failed - Troubleshooting:
+
+The services were stopped, but the environment state could not be saved.
+
+1. Check file system permissions for the data directory
+2. Verify available disk space: df -h
+3. Ensure no other command is running on the environment
+
+For more information, see docs/user-guide/commands/stop.md"
+            }
+        }
+    }
+}
@@ -0,0 +1,116 @@
+//! Stop command handler implementation
+//!
+//! **Purpose**: Take the tracker offline for maintenance
+//!
+//! This handler stops the Docker Compose services of a `Running`
+//! environment and records the environment as `Stopped`. The instance and
+//! the deployed release are kept, so `start` brings the tracker back without
+//! a redeploy.
+
+use std::net::SocketAddr;
+use std::sync::Arc;
+
+use tracing::{info, instrument};
+
+use super::errors::StopCommandHandlerError;
+use crate::adapters::ssh::SshConfig;
+use crate::application::command_handlers::common::verify_host_keys;
+use crate::application::steps::application::StopServicesStep;
+use crate::domain::environment::repository::{EnvironmentRepository, TypedEnvironmentRepository};
+use crate::domain::environment::{Environment, Running, Stopped};
+use crate::domain::EnvironmentName;
+
+/// `StopCommandHandler` stops the tracker services of an environment
+///
+/// # State Management
+///
+/// - Accepts environment in `Running` state
+/// - Transitions to `Environment<Stopped>` on success
+/// - Leaves the state unchanged on error
+pub struct StopCommandHandler {
+    pub(crate) repository: TypedEnvironmentRepository,
+}
+
+impl StopCommandHandler {
+    /// Create a new `StopCommandHandler`
+    #[must_use]
+    pub fn new(repository: Arc<dyn EnvironmentRepository>) -> Self {
+        Self {
+            repository: TypedEnvironmentRepository::new(repository),
+        }
+    }
+
+    /// Execute the stop workflow
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if:
+    /// * Environment not found
+    /// * Environment is not in `Running` state
+    /// * Instance IP is not available
+    /// * The host keys of the instance changed
+    /// * Stopping the services fails
+    /// * State persistence fails
+    #[allow(clippy::result_large_err)]
+    #[instrument(
+        name = "command.stop",
+        skip_all,
+        fields(
+            command_type = "stop",
+            environment_name = %env_name
+        )
+    )]
+    pub fn execute(
+        &self,
+        env_name: &EnvironmentName,
+    ) -> Result<Environment<Stopped>, StopCommandHandlerError> {
+        let _lock = self.repository.inner().lock(env_name)?;
+        let environment = self.load_running_environment(env_name)?;
+
+        let instance_ip = environment.instance_ip().ok_or_else(|| {
+            StopCommandHandlerError::MissingInstanceIp {
+                name: env_name.to_string(),
+            }
+        })?;
+
+        // Refuse to act on a host presenting other keys than the recorded ones
+        verify_host_keys(&environment, instance_ip)?;
+
+        let ssh_config = SshConfig::new(
+            environment.ssh_credentials().clone(),
+            SocketAddr::new(instance_ip, environment.ssh_port()),
+        )
+        .with_known_hosts(environment.ssh_known_hosts_path());
+
+        StopServicesStep::new(ssh_config)
+            .execute()
+            .map_err(|source| StopCommandHandlerError::StopServicesFailed { source })?;
+
+        let stopped = environment.stop();
+        self.repository.save_stopped(&stopped)?;
+
+        info!(
+            command = "stop",
+            environment = %stopped.name(),
+            final_state = "stopped",
+            "Services stopped"
+        );
+
+        Ok(stopped)
+    }
+
+    /// Load the environment and check it is `Running`
+    #[allow(clippy::result_large_err)]
+    fn load_running_environment(
+        &self,
+        env_name: &EnvironmentName,
+    ) -> Result<Environment<Running>, StopCommandHandlerError> {
+        let any_env = self.repository.inner().load(env_name)?.ok_or_else(|| {
+            StopCommandHandlerError::EnvironmentNotFound {
+                name: env_name.to_string(),
+            }
+        })?;
+
+        Ok(any_env.try_into_running()?)
+    }
+}
@@ -0,0 +1,29 @@
+//! Stop Command Module
+//!
+//! This module implements the delivery-agnostic `StopCommandHandler`
+//! for stopping the tracker services of a `Running` environment for
+//! maintenance, without destroying or redeploying anything.
+//!
+//! ## Stop Workflow
+//!
+//! 1. **Load environment** - Retrieve environment from repository
+//! 2. **Validate state** - Ensure environment is `Running`
+//! 3. **Stop services** - `docker compose stop` on the instance, keeping the
+//!    containers, their volumes and the deployed configuration
+//!
+//! ## State Management
+//!
+//! - Accepts environment in `Running` state
+//! - Returns `Environment<Stopped>` on success; `start` brings it back
+//! - Leaves the state unchanged on error: the services may still be running
+//!   and `stop` can simply be run again
+
+pub mod errors;
+pub mod handler;
+
+#[cfg(test)]
+mod tests;
+
+// Re-export main types for convenience
+pub use errors::StopCommandHandlerError;
+pub use handler::StopCommandHandler;
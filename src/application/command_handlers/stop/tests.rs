@@ -0,0 +1,52 @@
+//! Test module for Stop Command
+
+use std::sync::Arc;
+
+use tempfile::TempDir;
+
+use super::errors::StopCommandHandlerError;
+use super::handler::StopCommandHandler;
+use crate::domain::environment::repository::EnvironmentRepository;
+use crate::domain::environment::testing::EnvironmentTestBuilder;
+use crate::domain::EnvironmentName;
+use crate::infrastructure::persistence::filesystem::file_environment_repository::FileEnvironmentRepository;
+
+fn create_test_handler() -> (StopCommandHandler, Arc<FileEnvironmentRepository>, TempDir) {
+    let temp_dir = TempDir::new().expect("Failed to create temp directory");
+    let repository = Arc::new(FileEnvironmentRepository::new(
+        temp_dir.path().to_path_buf(),
+    ));
+    let handler = StopCommandHandler::new(repository.clone());
+    (handler, repository, temp_dir)
+}
+
+#[test]
+fn it_should_return_environment_not_found_error_when_environment_does_not_exist() {
+    let (handler, _repository, _temp_dir) = create_test_handler();
+    let env_name = EnvironmentName::new("nonexistent-env").unwrap();
+
+    let result = handler.execute(&env_name);
+
+    assert!(matches!(
+        result,
+        Err(StopCommandHandlerError::EnvironmentNotFound { .. })
+    ));
+}
+
+#[test]
+fn it_should_reject_an_environment_that_is_not_running() {
+    let (handler, repository, _temp_dir) = create_test_handler();
+    let (environment, _data_dir, _build_dir, _env_temp_dir) = EnvironmentTestBuilder::new()
+        .with_name("not-running")
+        .build_with_custom_paths();
+    repository
+        .save(&environment.clone().into_any())
+        .expect("Failed to save environment");
+
+    let result = handler.execute(environment.name());
+
+    assert!(matches!(
+        result,
+        Err(StopCommandHandlerError::InvalidState(e)) if e.actual == "created"
+    ));
+}
@@ -0,0 +1,168 @@
+//! Smoke checks run by the test command
+//!
+//! Each check exercises one public interface of the deployed tracker from
+//! outside the instance. The test command runs the selected checks
+//! concurrently, each one bounded by the same timeout.
+
+use std::fmt;
+use std::time::Duration;
+
+use serde::Serialize;
+
+/// Default time a single check may take before it fails
+pub const DEFAULT_CHECK_TIMEOUT: Duration = Duration::from_secs(10);
+
+/// A smoke check of the test command
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Serialize, clap::ValueEnum)]
+#[serde(rename_all = "snake_case")]
+pub enum SmokeCheck {
+    /// Health check endpoints of the tracker API and the HTTP trackers
+    Health,
+
+    /// Connect and announce round-trip on every UDP tracker
+    Udp,
+
+    /// Announce with a synthetic info-hash on every HTTP tracker
+    Http,
+
+    /// Tracker API statistics, authenticated with the admin token
+    Api,
+}
+
+impl SmokeCheck {
+    /// All checks, in reporting order
+    pub const ALL: [Self; 4] = [Self::Health, Self::Udp, Self::Http, Self::Api];
+
+    /// Name used on the command line and in reports
+    #[must_use]
+    pub fn name(self) -> &'static str {
+        match self {
+            Self::Health => "health",
+            Self::Udp => "udp",
+            Self::Http => "http",
+            Self::Api => "api",
+        }
+    }
+}
+
+impl fmt::Display for SmokeCheck {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.name())
+    }
+}
+
+/// Which checks the test command runs and how long each one may take
+///
+/// The default runs every check with [`DEFAULT_CHECK_TIMEOUT`].
+///
+/// # Example
+///
+/// ```rust
+/// use std::time::Duration;
+/// use torrust_tracker_deployer_lib::application::command_handlers::test::checks::{
+///     SmokeCheck, SmokeTestOptions,
+/// };
+///
+/// let options = SmokeTestOptions::new()
+///     .with_checks([SmokeCheck::Udp, SmokeCheck::Api])
+///     .with_timeout(Duration::from_secs(5));
+///
+/// assert!(options.includes(SmokeCheck::Udp));
+/// assert!(!options.includes(SmokeCheck::Http));
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SmokeTestOptions {
+    checks: Vec<SmokeCheck>,
+    timeout: Duration,
+}
+
+impl Default for SmokeTestOptions {
+    fn default() -> Self {
+        Self {
+            checks: SmokeCheck::ALL.to_vec(),
+            timeout: DEFAULT_CHECK_TIMEOUT,
+        }
+    }
+}
+
+impl SmokeTestOptions {
+    /// Run every check with the default timeout
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Only run the given checks
+    ///
+    /// An empty selection keeps every check.
+    #[must_use]
+    pub fn with_checks<I>(mut self, checks: I) -> Self
+    where
+        I: IntoIterator<Item = SmokeCheck>,
+    {
+        let mut checks: Vec<SmokeCheck> = checks.into_iter().collect();
+        checks.sort_unstable();
+        checks.dedup();
+
+        if !checks.is_empty() {
+            self.checks = checks;
+        }
+        self
+    }
+
+    /// Set the time a single check may take before it fails
+    #[must_use]
+    pub fn with_timeout(mut self, timeout: Duration) -> Self {
+        self.timeout = timeout;
+        self
+    }
+
+    /// Whether the check is selected
+    #[must_use]
+    pub fn includes(&self, check: SmokeCheck) -> bool {
+        self.checks.contains(&check)
+    }
+
+    /// Selected checks, in reporting order
+    #[must_use]
+    pub fn checks(&self) -> &[SmokeCheck] {
+        &self.checks
+    }
+
+    /// Time a single check may take before it fails
+    #[must_use]
+    pub fn timeout(&self) -> Duration {
+        self.timeout
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn it_should_run_every_check_by_default() {
+        let options = SmokeTestOptions::default();
+
+        assert_eq!(options.checks(), SmokeCheck::ALL);
+        assert_eq!(options.timeout(), DEFAULT_CHECK_TIMEOUT);
+    }
+
+    #[test]
+    fn it_should_keep_the_selected_checks_in_reporting_order_without_duplicates() {
+        let options = SmokeTestOptions::new().with_checks([
+            SmokeCheck::Api,
+            SmokeCheck::Udp,
+            SmokeCheck::Api,
+        ]);
+
+        assert_eq!(options.checks(), [SmokeCheck::Udp, SmokeCheck::Api]);
+    }
+
+    #[test]
+    fn it_should_keep_every_check_when_the_selection_is_empty() {
+        let options = SmokeTestOptions::new().with_checks([]);
+
+        assert_eq!(options.checks(), SmokeCheck::ALL);
+    }
+}
@@ -1,6 +1,9 @@
 //! Error types for test command handler
 
-use crate::application::errors::{InvalidStateError, PersistenceError};
+use std::fmt::Write as _;
+
+use super::result::CheckResult;
+use crate::application::errors::{AllowedCommands, InvalidStateError, PersistenceError};
 use crate::infrastructure::remote_actions::RemoteActionError;
 use crate::shared::command::CommandError;
 
@@ -19,6 +22,19 @@ pub enum TestCommandHandlerError {
     #[error("Environment '{environment_name}' does not have an instance IP set. The environment must be provisioned before running tests.")]
     MissingInstanceIp { environment_name: String },
 
+    #[error("Environment '{environment_name}' is in state '{state}', but the tracker must be running to be tested; {allowed_commands}")]
+    EnvironmentNotRunning {
+        environment_name: String,
+        state: String,
+        allowed_commands: AllowedCommands,
+    },
+
+    #[error("Smoke checks failed for environment '{environment_name}':{}", failed_checks_summary(.checks))]
+    ChecksFailed {
+        environment_name: String,
+        checks: Vec<CheckResult>,
+    },
+
     #[error("Invalid tracker configuration: {message}")]
     InvalidTrackerConfiguration { message: String },
 
@@ -41,6 +57,17 @@ impl From<crate::domain::environment::state::StateTypeError> for TestCommandHand
     }
 }
 
+/// One line per failed check, each starting on a new line
+fn failed_checks_summary(checks: &[CheckResult]) -> String {
+    checks
+        .iter()
+        .filter(|check| check.is_failure())
+        .fold(String::new(), |mut summary, check| {
+            let _ = write!(summary, "\n  - {check}");
+            summary
+        })
+}
+
 impl crate::shared::Traceable for TestCommandHandlerError {
     fn trace_format(&self) -> String {
         match self {
@@ -58,6 +85,24 @@ impl crate::shared::Traceable for TestCommandHandlerError {
                     "TestCommandHandlerError: Missing instance IP for environment '{environment_name}'"
                 )
             }
+            Self::EnvironmentNotRunning {
+                environment_name,
+                state,
+                ..
+            } => {
+                format!(
+                    "TestCommandHandlerError: Environment '{environment_name}' is not running (state: {state})"
+                )
+            }
+            Self::ChecksFailed {
+                environment_name,
+                checks,
+            } => {
+                format!(
+                    "TestCommandHandlerError: Smoke checks failed for environment '{environment_name}' -{}",
+                    failed_checks_summary(checks)
+                )
+            }
             Self::InvalidTrackerConfiguration { message } => {
                 format!("TestCommandHandlerError: Invalid tracker configuration - {message}")
             }
@@ -76,6 +121,8 @@ impl crate::shared::Traceable for TestCommandHandlerError {
             Self::EnvironmentNotFound { .. }
             | Self::RemoteAction(_)
             | Self::MissingInstanceIp { .. }
+            | Self::EnvironmentNotRunning { .. }
+            | Self::ChecksFailed { .. }
             | Self::InvalidTrackerConfiguration { .. }
             | Self::StateTransition(_)
             | Self::StatePersistence(_) => None,
@@ -86,8 +133,10 @@ impl crate::shared::Traceable for TestCommandHandlerError {
         match self {
             Self::EnvironmentNotFound { .. }
             | Self::MissingInstanceIp { .. }
+            | Self::EnvironmentNotRunning { .. }
             | Self::InvalidTrackerConfiguration { .. } => crate::shared::ErrorKind::Configuration,
             Self::Command(_) | Self::RemoteAction(_) => crate::shared::ErrorKind::CommandExecution,
+            Self::ChecksFailed { .. } => crate::shared::ErrorKind::NetworkConnectivity,
             Self::StateTransition(_) | Self::StatePersistence(_) => {
                 crate::shared::ErrorKind::StatePersistence
             }
@@ -154,6 +203,47 @@ This typically means the environment was created but not provisioned.
 3. Then run the test command
 
 For workflow details, see docs/deployment-overview.md"
+            }
+            Self::EnvironmentNotRunning { .. } => {
+                "Environment Not Running - Troubleshooting:
+
+The smoke checks talk to the deployed tracker, so the environment must be
+in the 'running' state.
+
+1. Check the environment state:
+   torrust-tracker-deployer show <env-name>
+
+2. Complete the deployment workflow:
+   torrust-tracker-deployer configure <env-name>
+   torrust-tracker-deployer release <env-name>
+   torrust-tracker-deployer run <env-name>
+
+3. If the services were stopped, start them again:
+   torrust-tracker-deployer start <env-name>
+
+4. Then run the test command
+
+For workflow details, see docs/deployment-overview.md"
+            }
+            Self::ChecksFailed { .. } => {
+                "Smoke Checks Failed - Troubleshooting:
+
+Each failed check above names the endpoint it could not use.
+
+1. Check that the tracker container is running on the instance:
+   ssh <user>@<instance-ip> 'docker ps'
+
+2. Restart the services if it stopped:
+   torrust-tracker-deployer run <env-name>
+
+3. Check that the firewall allows the tracker ports (UDP and TCP)
+
+4. For an 'api' failure with HTTP 401/500, check the admin token
+
+5. Re-run only the failing checks, with more time if needed:
+   torrust-tracker-deployer test <env-name> --check udp,api --timeout 30
+
+For more information, see docs/user-guide/commands/test.md"
             }
             Self::InvalidTrackerConfiguration { .. } => {
                 "Invalid Tracker Configuration - Troubleshooting:
@@ -228,6 +318,36 @@ mod tests {
         assert!(help.contains("provision"));
     }
 
+    #[test]
+    fn it_should_list_each_failed_check_in_the_message() {
+        use std::time::Duration;
+
+        use crate::application::command_handlers::test::checks::SmokeCheck;
+
+        let error = TestCommandHandlerError::ChecksFailed {
+            environment_name: "test-env".to_string(),
+            checks: vec![
+                CheckResult::passed(SmokeCheck::Health, Duration::from_millis(12)),
+                CheckResult::failed(
+                    SmokeCheck::Udp,
+                    "connection refused",
+                    Duration::from_millis(3),
+                ),
+                CheckResult::failed(
+                    SmokeCheck::Api,
+                    "timed out after 10s",
+                    Duration::from_secs(10),
+                ),
+            ],
+        };
+
+        let message = error.to_string();
+
+        assert!(message.contains("\n  - udp: failed after 3 ms: connection refused"));
+        assert!(message.contains("\n  - api: failed after 10000 ms: timed out after 10s"));
+        assert!(!message.contains("health"));
+    }
+
     #[test]
     fn it_should_have_help_for_all_error_variants() {
         use crate::shared::command::CommandError;
@@ -245,12 +365,22 @@ mod tests {
             TestCommandHandlerError::MissingInstanceIp {
                 environment_name: "test-env".to_string(),
             },
+            TestCommandHandlerError::EnvironmentNotRunning {
+                environment_name: "test-env".to_string(),
+                state: "provisioned".to_string(),
+                allowed_commands: AllowedCommands::default(),
+            },
+            TestCommandHandlerError::ChecksFailed {
+                environment_name: "test-env".to_string(),
+                checks: vec![],
+            },
             TestCommandHandlerError::InvalidTrackerConfiguration {
                 message: "Invalid bind address".to_string(),
             },
             TestCommandHandlerError::StateTransition(InvalidStateError {
                 expected: "Provisioned".to_string(),
                 actual: "Created".to_string(),
+                allowed_commands: AllowedCommands::default(),
             }),
             TestCommandHandlerError::StatePersistence(PersistenceError::NotFound),
         ];
@@ -6,13 +6,23 @@
 //! from external clients. The command performs comprehensive end-to-end verification
 //! including service status, health checks, and external accessibility validation.
 //!
-//! ## Validation Strategy
+//! ## Smoke Checks
 //!
-//! The test command validates deployed services through:
+//! The test command validates deployed services from outside the VM with a
+//! suite of smoke checks (see [`SmokeCheck`]):
 //!
-//! 1. **External Health Checks** - Tests service accessibility from outside the VM:
-//!    - Tracker API health endpoint (required)
-//!    - HTTP Tracker health endpoint (required)
+//! - **health** - Tracker API and HTTP tracker health check endpoints
+//! - **udp** - Connect and announce round-trip on every UDP tracker
+//! - **http** - Announce with a synthetic info-hash on every HTTP tracker
+//! - **api** - Tracker statistics (`GET /api/v1/stats`) with the admin token
+//!
+//! With several tracker replicas every check probes every replica on its
+//! offset ports.
+//!
+//! The selected checks run concurrently, each one bounded by the same timeout.
+//! Every check reports whether it passed and how long it took, so a stopped
+//! tracker shows up as one precise failure per check instead of a single
+//! connection error.
 //!
 //! ## HTTPS Support
 //!
@@ -37,25 +47,33 @@
 //!
 //! The test command extracts tracker ports from the environment's tracker configuration:
 //! - HTTP API port from `environment.context.user_inputs.tracker.http_api.bind_address`
-//! - HTTP Tracker port from `environment.context.user_inputs.tracker.http_trackers[0].bind_address`
+//! - HTTP Tracker ports from `environment.context.user_inputs.tracker.http_trackers[*].bind_address`
+//! - UDP Tracker ports from `environment.context.user_inputs.tracker.udp_trackers[*].bind_address`
 //!
 //! For rationale and alternatives, see:
 //! - `docs/decisions/test-command-as-smoke-test.md` - Architectural decision record
 
-use std::net::IpAddr;
+use std::net::{IpAddr, SocketAddr};
 use std::sync::Arc;
+use std::time::Instant;
 
 use tracing::{info, instrument};
 
+use super::checks::{SmokeCheck, SmokeTestOptions};
 use super::errors::TestCommandHandlerError;
-use super::result::{DnsIssue, DnsWarning, TestResult};
+use super::result::{CheckResult, DnsIssue, DnsWarning, TestResult};
 use crate::application::command_handlers::common::endpoint_builder;
+use crate::application::errors::AllowedCommands;
 use crate::domain::environment::repository::{EnvironmentRepository, TypedEnvironmentRepository};
 use crate::domain::environment::state::AnyEnvironmentState;
+use crate::domain::tracker::config::TrackerConfig;
 use crate::domain::EnvironmentName;
 use crate::infrastructure::dns::{DnsResolutionError, DnsResolver};
-use crate::infrastructure::external_validators::RunningServicesValidator;
-use crate::infrastructure::remote_actions::RemoteAction;
+use crate::infrastructure::external_validators::{
+    ApiStatsValidator, HttpAnnounceValidator, RunningServicesValidator, UdpAnnounceValidator,
+};
+use crate::infrastructure::remote_actions::{RemoteAction, RemoteActionError};
+use crate::infrastructure::tracker_api::TrackerApiClient;
 use crate::shared::domain_name::DomainName;
 
 /// `TestCommandHandler` orchestrates smoke testing for running Torrust Tracker services
@@ -63,20 +81,15 @@ use crate::shared::domain_name::DomainName;
 /// **Purpose**: Post-deployment smoke test to verify the application is running and accessible
 ///
 /// This handler validates that deployed services are operational and accessible from
-/// external clients by performing comprehensive health checks on the Tracker API and
-/// HTTP Tracker endpoints.
-///
-/// ## Validation Steps
-///
-/// 1. **Service Status** - Verifies Docker Compose services are running via SSH
-/// 2. **Tracker API Health** (required) - Tests external accessibility of HTTP API
-/// 3. **HTTP Tracker Health** (optional) - Tests external accessibility of HTTP tracker
+/// external clients by running a suite of smoke checks against a `Running`
+/// environment: health checks, UDP and HTTP announces, and API statistics.
 ///
 /// ## Port Discovery
 ///
 /// The handler extracts tracker ports from the environment's tracker configuration:
 /// - HTTP API port from `tracker.http_api.bind_address`
-/// - HTTP Tracker port from `tracker.http_trackers[0].bind_address`
+/// - HTTP Tracker ports from `tracker.http_trackers[*].bind_address`
+/// - UDP Tracker ports from `tracker.udp_trackers[*].bind_address`
 ///
 /// ## Design Rationale
 ///
@@ -102,45 +115,67 @@ impl TestCommandHandler {
 
     /// Execute the complete testing and validation workflow
     ///
-    /// Validates that the Torrust Tracker services are running and accessible by
-    /// performing external health checks on the deployed services. Also performs
-    /// advisory DNS resolution checks for configured domains.
+    /// Runs every smoke check with the default timeout. See
+    /// [`Self::execute_with_options`].
     ///
-    /// Returns a structured `TestResult` containing any DNS warnings found.
-    /// The presentation layer is responsible for rendering these warnings.
+    /// # Errors
     ///
-    /// # Arguments
+    /// Same as [`Self::execute_with_options`].
+    pub async fn execute(
+        &self,
+        env_name: &EnvironmentName,
+    ) -> Result<TestResult, TestCommandHandlerError> {
+        self.execute_with_options(env_name, &SmokeTestOptions::default())
+            .await
+    }
+
+    /// Execute the selected smoke checks against a running environment
     ///
-    /// * `env_name` - The name of the environment to test
+    /// Runs the selected checks concurrently, each one bounded by the timeout
+    /// of `options`. Also performs advisory DNS resolution checks for
+    /// configured domains.
+    ///
+    /// Returns a structured `TestResult` containing the outcome and latency of
+    /// every check and any DNS warnings found. The presentation layer is
+    /// responsible for rendering them.
     ///
-    /// # Returns
+    /// # Arguments
     ///
-    /// * `Ok(TestResult)` - Test passed, may contain advisory DNS warnings
+    /// * `env_name` - The name of the environment to test
+    /// * `options` - Which checks to run and their timeout
     ///
     /// # Errors
     ///
     /// Returns an error if:
     /// * Environment not found
+    /// * Environment is not in the `Running` state
     /// * Environment does not have an instance IP set
-    /// * Tracker configuration is invalid or missing required ports
-    /// * Running services validation fails:
-    ///   - Services are not running
-    ///   - Health check endpoints are not accessible
-    ///   - Firewall rules block external access
+    /// * Any smoke check fails or times out ([`TestCommandHandlerError::ChecksFailed`]
+    ///   carries the outcome of every check)
     #[instrument(
-        name = "test_command",
+        name = "command.test",
         skip_all,
         fields(
             command_type = "test",
-            environment = %env_name
+            environment_name = %env_name
         )
     )]
-    pub async fn execute(
+    pub async fn execute_with_options(
         &self,
         env_name: &EnvironmentName,
+        options: &SmokeTestOptions,
     ) -> Result<TestResult, TestCommandHandlerError> {
+        let started = Instant::now();
         let any_env = self.load_environment(env_name)?;
 
+        if !matches!(any_env, AnyEnvironmentState::Running(_)) {
+            return Err(TestCommandHandlerError::EnvironmentNotRunning {
+                environment_name: env_name.to_string(),
+                state: any_env.state_name().to_string(),
+                allowed_commands: AllowedCommands::from(&any_env),
+            });
+        }
+
         let instance_ip =
             any_env
                 .instance_ip()
@@ -148,29 +183,23 @@ impl TestCommandHandler {
                     environment_name: env_name.to_string(),
                 })?;
 
-        // Extract tracker config
-        let tracker_config = any_env.tracker_config();
-
-        // Build service endpoints from configuration (with server IP)
-        let (tracker_api_endpoint, http_tracker_endpoints) =
-            endpoint_builder::build_all_tracker_endpoints(instance_ip, tracker_config);
-
-        // Log endpoint information
         info!(
             command = "test",
             environment = %env_name,
             instance_ip = ?instance_ip,
-            api_endpoint_tls = tracker_api_endpoint.uses_tls(),
-            api_endpoint_domain = ?tracker_api_endpoint.domain(),
-            http_tracker_count = http_tracker_endpoints.len(),
-            "Starting service health checks"
+            checks = ?options.checks(),
+            timeout = ?options.timeout(),
+            "Starting smoke checks"
         );
 
-        // Validate running services with external accessibility checks
-        let services_validator =
-            RunningServicesValidator::new(tracker_api_endpoint, http_tracker_endpoints);
+        let checks = Self::run_checks(any_env.tracker_config(), instance_ip, options).await;
 
-        services_validator.execute(&instance_ip).await?;
+        if checks.iter().any(CheckResult::is_failure) {
+            return Err(TestCommandHandlerError::ChecksFailed {
+                environment_name: env_name.to_string(),
+                checks,
+            });
+        }
 
         // Perform advisory DNS checks
         let dns_warnings = Self::check_dns_resolution(&any_env, instance_ip);
@@ -183,7 +212,129 @@ impl TestCommandHandler {
             "Service testing workflow completed successfully"
         );
 
-        Ok(TestResult::with_dns_warnings(instance_ip, dns_warnings))
+        Ok(TestResult::with_dns_warnings(instance_ip, dns_warnings)
+            .with_checks(checks)
+            .with_phase_durations(*any_env.phase_durations())
+            .with_duration(started.elapsed()))
+    }
+
+    /// Run the selected smoke checks concurrently
+    ///
+    /// Checks that do not apply to the tracker configuration (e.g. `udp`
+    /// without UDP trackers) are reported as skipped.
+    async fn run_checks(
+        tracker_config: &TrackerConfig,
+        instance_ip: IpAddr,
+        options: &SmokeTestOptions,
+    ) -> Vec<CheckResult> {
+        let replicas = tracker_config.replica_configs();
+
+        let health = EveryReplica(
+            replicas
+                .iter()
+                .map(|replica| {
+                    let (api_endpoint, http_tracker_endpoints) =
+                        endpoint_builder::build_all_tracker_endpoints(instance_ip, replica);
+                    RunningServicesValidator::new(api_endpoint, http_tracker_endpoints)
+                })
+                .collect(),
+        );
+
+        let udp = UdpAnnounceValidator::new(
+            replicas
+                .iter()
+                .flat_map(TrackerConfig::udp_trackers)
+                .map(|udp| SocketAddr::new(instance_ip, udp.bind_address().port()))
+                .collect(),
+        );
+        let udp_skip = tracker_config
+            .udp_trackers()
+            .is_empty()
+            .then_some("no UDP trackers configured");
+
+        let http = HttpAnnounceValidator::new(
+            replicas
+                .iter()
+                .flat_map(TrackerConfig::http_trackers)
+                .map(|http| endpoint_builder::build_http_announce_endpoint(instance_ip, http))
+                .collect(),
+        );
+        let http_skip = if tracker_config.http_trackers().is_empty() {
+            Some("no HTTP trackers configured")
+        } else if tracker_config.core().private() {
+            Some("private tracker: announces need a peer key")
+        } else {
+            None
+        };
+
+        let api = EveryReplica(
+            replicas
+                .iter()
+                .map(|replica| {
+                    let api = replica.http_api();
+                    ApiStatsValidator::new(TrackerApiClient::new(
+                        endpoint_builder::build_api_v1_endpoint(instance_ip, api),
+                        api.admin_token().clone(),
+                    ))
+                })
+                .collect(),
+        );
+
+        let (health, udp, http, api) = tokio::join!(
+            Self::run_check(SmokeCheck::Health, options, None, &health, &instance_ip),
+            Self::run_check(SmokeCheck::Udp, options, udp_skip, &udp, &instance_ip),
+            Self::run_check(SmokeCheck::Http, options, http_skip, &http, &instance_ip),
+            Self::run_check(SmokeCheck::Api, options, None, &api, &instance_ip),
+        );
+
+        [health, udp, http, api].into_iter().flatten().collect()
+    }
+
+    /// Run one smoke check with the timeout of `options`
+    ///
+    /// Returns `None` when the check is not selected.
+    async fn run_check(
+        check: SmokeCheck,
+        options: &SmokeTestOptions,
+        skip_reason: Option<&str>,
+        action: &impl RemoteAction,
+        instance_ip: &IpAddr,
+    ) -> Option<CheckResult> {
+        if !options.includes(check) {
+            return None;
+        }
+
+        if let Some(reason) = skip_reason {
+            info!(command = "test", check = %check, reason, "Smoke check skipped");
+            return Some(CheckResult::skipped(check, reason));
+        }
+
+        let started = Instant::now();
+        let outcome = tokio::time::timeout(options.timeout(), action.execute(instance_ip)).await;
+        let latency = started.elapsed();
+
+        let result = match outcome {
+            Ok(Ok(())) => CheckResult::passed(check, latency),
+            Ok(Err(error)) => CheckResult::failed(check, Self::failure_reason(error), latency),
+            Err(_) => CheckResult::failed(
+                check,
+                format!("timed out after {:?}", options.timeout()),
+                latency,
+            ),
+        };
+
+        info!(command = "test", result = %result, "Smoke check finished");
+
+        Some(result)
+    }
+
+    /// Message of a failed validation, without the action name prefix
+    fn failure_reason(error: RemoteActionError) -> String {
+        match error {
+            RemoteActionError::ValidationFailed { message, .. }
+            | RemoteActionError::ExecutionFailed { message, .. } => message,
+            error @ RemoteActionError::SshCommandFailed { .. } => error.to_string(),
+        }
     }
 
     /// Perform advisory DNS checks for configured domains
@@ -253,3 +404,44 @@ impl TestCommandHandler {
         })
     }
 }
+
+/// Runs the same check against every tracker replica, one after the other
+///
+/// Fails on the first failing replica. With several replicas the failure
+/// message names the replica (counting from 1).
+struct EveryReplica<A>(Vec<A>);
+
+impl<A: RemoteAction> RemoteAction for EveryReplica<A> {
+    fn name(&self) -> &'static str {
+        self.0.first().map_or("every-replica", RemoteAction::name)
+    }
+
+    async fn execute(&self, server_ip: &IpAddr) -> Result<(), RemoteActionError> {
+        let several = self.0.len() > 1;
+
+        for (index, action) in self.0.iter().enumerate() {
+            action
+                .execute(server_ip)
+                .await
+                .map_err(|error| match error {
+                    RemoteActionError::ValidationFailed {
+                        action_name,
+                        message,
+                    } if several => RemoteActionError::ValidationFailed {
+                        action_name,
+                        message: format!("replica {}: {message}", index + 1),
+                    },
+                    RemoteActionError::ExecutionFailed {
+                        action_name,
+                        message,
+                    } if several => RemoteActionError::ExecutionFailed {
+                        action_name,
+                        message: format!("replica {}: {message}", index + 1),
+                    },
+                    error => error,
+                })?;
+        }
+
+        Ok(())
+    }
+}
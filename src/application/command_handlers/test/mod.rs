@@ -15,23 +15,27 @@
 //!
 //! - **Delivery-Agnostic**: Works with CLI, REST API, or any delivery mechanism
 //! - **Asynchronous**: Uses async/await for network operations
-//! - **Runtime State Validation**: Accepts any environment state, validates at runtime
+//! - **Runtime State Validation**: Loads any environment state, requires `Running` at runtime
 //! - **Explicit Errors**: All errors implement helpful error messages with actionable guidance
 //!
 //! ## Validation Workflow
 //!
-//! The command handler orchestrates a multi-step validation workflow:
+//! The command handler runs a suite of smoke checks against a `Running` environment:
 //!
-//! 1. **Validate cloud-init completion** - Ensure system initialization is complete
-//! 2. **Validate Docker installation** - Verify Docker is installed and running
-//! 3. **Validate Docker Compose installation** - Verify Docker Compose is available
+//! 1. **health** - Probe the health check endpoints of the API and HTTP trackers
+//! 2. **udp** - Announce on every UDP tracker with a minimal UDP tracker client
+//! 3. **http** - Announce on every HTTP tracker with a synthetic info-hash
+//! 4. **api** - Request the tracker statistics with the admin token
+//!
+//! The checks can be selected with `SmokeTestOptions` and run concurrently.
 //!
 //! ## State Management
 //!
 //! Unlike `provision` and `configure` handlers, the test handler does not transition
 //! environment state. It accepts an environment name, loads the environment from storage,
-//! and performs runtime validation checks regardless of the environment's compile-time state.
+//! and checks at runtime that it is running.
 
+pub mod checks;
 pub mod errors;
 pub mod handler;
 pub mod result;
@@ -40,6 +44,7 @@ pub mod result;
 mod tests;
 
 // Re-export main types for convenience
+pub use checks::{SmokeCheck, SmokeTestOptions};
 pub use errors::TestCommandHandlerError;
 pub use handler::TestCommandHandler;
-pub use result::TestResult;
+pub use result::{CheckResult, CheckStatus, TestResult};
@@ -1,12 +1,15 @@
 //! Result types for the test command handler
 //!
 //! These DTOs encapsulate the structured output from the test command,
-//! including infrastructure test results and advisory DNS warnings.
+//! including the outcome of every smoke check and advisory DNS warnings.
 //! The presentation layer is responsible for rendering these to the user.
 
 use std::fmt;
 use std::net::IpAddr;
+use std::time::Duration;
 
+use super::checks::SmokeCheck;
+use crate::domain::environment::PhaseDurations;
 use crate::shared::domain_name::DomainName;
 
 /// Result of executing the test command
@@ -20,8 +23,14 @@ use crate::shared::domain_name::DomainName;
 pub struct TestResult {
     /// IP address of the tested instance
     pub instance_ip: IpAddr,
+    /// Outcome of every smoke check that was run, in reporting order
+    pub checks: Vec<CheckResult>,
     /// Advisory DNS warnings (domains that failed to resolve or resolved to wrong IP)
     pub dns_warnings: Vec<DnsWarning>,
+    /// Time the test command took
+    pub duration: Duration,
+    /// Durations of the deployment phases recorded in the environment state
+    pub phase_durations: PhaseDurations,
 }
 
 impl TestResult {
@@ -30,7 +39,10 @@ impl TestResult {
     pub fn success(instance_ip: IpAddr) -> Self {
         Self {
             instance_ip,
+            checks: Vec::new(),
             dns_warnings: Vec::new(),
+            duration: Duration::ZERO,
+            phase_durations: PhaseDurations::default(),
         }
     }
 
@@ -39,10 +51,34 @@ impl TestResult {
     pub fn with_dns_warnings(instance_ip: IpAddr, dns_warnings: Vec<DnsWarning>) -> Self {
         Self {
             instance_ip,
+            checks: Vec::new(),
             dns_warnings,
+            duration: Duration::ZERO,
+            phase_durations: PhaseDurations::default(),
         }
     }
 
+    /// Set the outcome of the smoke checks
+    #[must_use]
+    pub fn with_checks(mut self, checks: Vec<CheckResult>) -> Self {
+        self.checks = checks;
+        self
+    }
+
+    /// Set the time the test command took
+    #[must_use]
+    pub fn with_duration(mut self, duration: Duration) -> Self {
+        self.duration = duration;
+        self
+    }
+
+    /// Set the deployment phase durations of the tested environment
+    #[must_use]
+    pub fn with_phase_durations(mut self, phase_durations: PhaseDurations) -> Self {
+        self.phase_durations = phase_durations;
+        self
+    }
+
     /// Check if there are any DNS warnings
     #[must_use]
     pub fn has_dns_warnings(&self) -> bool {
@@ -50,6 +86,88 @@ impl TestResult {
     }
 }
 
+/// Outcome of a single smoke check
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CheckResult {
+    /// The check that was run
+    pub check: SmokeCheck,
+    /// Whether it passed, failed or was skipped
+    pub status: CheckStatus,
+    /// Time the check took (zero for skipped checks)
+    pub latency: Duration,
+}
+
+impl CheckResult {
+    /// A check that passed
+    #[must_use]
+    pub fn passed(check: SmokeCheck, latency: Duration) -> Self {
+        Self {
+            check,
+            status: CheckStatus::Passed,
+            latency,
+        }
+    }
+
+    /// A check that failed, with the reason of the failure
+    #[must_use]
+    pub fn failed(check: SmokeCheck, reason: impl Into<String>, latency: Duration) -> Self {
+        Self {
+            check,
+            status: CheckStatus::Failed(reason.into()),
+            latency,
+        }
+    }
+
+    /// A check that did not apply to the deployment (e.g. no UDP trackers)
+    #[must_use]
+    pub fn skipped(check: SmokeCheck, reason: impl Into<String>) -> Self {
+        Self {
+            check,
+            status: CheckStatus::Skipped(reason.into()),
+            latency: Duration::ZERO,
+        }
+    }
+
+    /// Whether the check failed
+    #[must_use]
+    pub fn is_failure(&self) -> bool {
+        matches!(self.status, CheckStatus::Failed(_))
+    }
+}
+
+impl fmt::Display for CheckResult {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match &self.status {
+            CheckStatus::Passed => write!(
+                f,
+                "{}: passed in {} ms",
+                self.check,
+                self.latency.as_millis()
+            ),
+            CheckStatus::Failed(reason) => write!(
+                f,
+                "{}: failed after {} ms: {reason}",
+                self.check,
+                self.latency.as_millis()
+            ),
+            CheckStatus::Skipped(reason) => write!(f, "{}: skipped ({reason})", self.check),
+        }
+    }
+}
+
+/// Status of a smoke check
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum CheckStatus {
+    /// The check passed
+    Passed,
+
+    /// The check failed or timed out
+    Failed(String),
+
+    /// The check did not apply to the deployment
+    Skipped(String),
+}
+
 /// A single DNS resolution warning for a configured domain
 #[derive(Debug)]
 pub struct DnsWarning {
@@ -130,6 +248,29 @@ mod tests {
         assert_eq!(result.dns_warnings.len(), 1);
     }
 
+    #[test]
+    fn it_should_display_the_reason_and_latency_of_a_failed_check() {
+        let result = CheckResult::failed(
+            SmokeCheck::Udp,
+            "no response from 10.0.0.1:6969",
+            Duration::from_millis(1500),
+        );
+
+        assert!(result.is_failure());
+        assert_eq!(
+            result.to_string(),
+            "udp: failed after 1500 ms: no response from 10.0.0.1:6969"
+        );
+    }
+
+    #[test]
+    fn it_should_not_count_skipped_checks_as_failures() {
+        let result = CheckResult::skipped(SmokeCheck::Http, "no HTTP trackers configured");
+
+        assert!(!result.is_failure());
+        assert_eq!(result.latency, Duration::ZERO);
+    }
+
     #[test]
     fn it_should_display_resolution_failed_warning() {
         let warning = DnsWarning {
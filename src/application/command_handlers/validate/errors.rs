@@ -7,12 +7,14 @@ use std::path::PathBuf;
 use thiserror::Error;
 
 use crate::application::command_handlers::create::config::CreateConfigError;
+use crate::shared::error::kind::ErrorKind;
+use crate::shared::error::traceable::Traceable;
 
 /// Errors that can occur during validation
 ///
 /// These errors represent different stages of validation failure:
 /// - File system errors (file not found, permission denied)
-/// - JSON parsing errors (syntax errors, type mismatches)
+/// - JSON, YAML and TOML parsing errors (syntax errors, type mismatches)
 /// - Domain validation errors (SSH keys missing, invalid values)
 #[derive(Debug, Error)]
 pub enum ValidateCommandHandlerError {
@@ -44,6 +46,32 @@ pub enum ValidateCommandHandlerError {
         source: serde_json::Error,
     },
 
+    /// YAML parsing failed
+    ///
+    /// This error occurs when the file is not valid YAML or doesn't
+    /// match the expected structure.
+    #[error("YAML parsing failed for file: {path}")]
+    YamlParsingFailed {
+        /// Path to the file with invalid YAML
+        path: PathBuf,
+        /// Underlying YAML parsing error
+        #[source]
+        source: serde_yaml::Error,
+    },
+
+    /// TOML parsing failed
+    ///
+    /// This error occurs when the file is not valid TOML or doesn't
+    /// match the expected structure.
+    #[error("TOML parsing failed for file: {path}")]
+    TomlParsingFailed {
+        /// Path to the file with invalid TOML
+        path: PathBuf,
+        /// Underlying TOML parsing error (boxed, it is large)
+        #[source]
+        source: Box<toml::de::Error>,
+    },
+
     /// Domain validation failed
     ///
     /// This error occurs when the configuration violates domain rules:
@@ -55,6 +83,54 @@ pub enum ValidateCommandHandlerError {
     DomainValidationFailed(#[source] CreateConfigError),
 }
 
+impl Traceable for ValidateCommandHandlerError {
+    fn trace_format(&self) -> String {
+        match self {
+            Self::FileReadFailed { path, source } => {
+                format!(
+                    "ValidateCommandHandlerError: File read failed - '{}': {source}",
+                    path.display()
+                )
+            }
+            Self::JsonParsingFailed { path, source } => {
+                format!(
+                    "ValidateCommandHandlerError: JSON parsing failed - '{}': {source}",
+                    path.display()
+                )
+            }
+            Self::YamlParsingFailed { path, source } => {
+                format!(
+                    "ValidateCommandHandlerError: YAML parsing failed - '{}': {source}",
+                    path.display()
+                )
+            }
+            Self::TomlParsingFailed { path, source } => {
+                format!(
+                    "ValidateCommandHandlerError: TOML parsing failed - '{}': {source}",
+                    path.display()
+                )
+            }
+            Self::DomainValidationFailed(e) => {
+                format!("ValidateCommandHandlerError: Domain validation failed - {e}")
+            }
+        }
+    }
+
+    fn trace_source(&self) -> Option<&dyn Traceable> {
+        None
+    }
+
+    fn error_kind(&self) -> ErrorKind {
+        match self {
+            Self::FileReadFailed { .. } => ErrorKind::FileSystem,
+            Self::JsonParsingFailed { .. }
+            | Self::YamlParsingFailed { .. }
+            | Self::TomlParsingFailed { .. }
+            | Self::DomainValidationFailed(_) => ErrorKind::Configuration,
+        }
+    }
+}
+
 impl ValidateCommandHandlerError {
     /// Provides context-specific help for troubleshooting
     ///
@@ -94,11 +170,43 @@ impl ValidateCommandHandlerError {
                     source
                 )
             }
+            Self::YamlParsingFailed { path, source } => {
+                format!(
+                    "YAML parsing failed for file '{}'.\n\n\
+                    Error details:\n{}\n\n\
+                    Common issues:\n\
+                    - Inconsistent indentation (use spaces, not tabs)\n\
+                    - Missing space after ':' in 'key: value'\n\
+                    - Unquoted values starting with special characters ('*', '&', '@')\n\n\
+                    Tips:\n\
+                    - The YAML document has the same structure as the JSON configuration\n\
+                    - Compare with a template: 'create template --provider lxd'\n\n\
+                    For more information, see: docs/user-guide/commands/validate.md",
+                    path.display(),
+                    source
+                )
+            }
+            Self::TomlParsingFailed { path, source } => {
+                format!(
+                    "TOML parsing failed for file '{}'.\n\n\
+                    Error details:\n{}\n\n\
+                    Common issues:\n\
+                    - Unquoted string values\n\
+                    - A table ('[section]') defined twice\n\
+                    - Arrays of tables written as '[section]' instead of '[[section]]'\n\n\
+                    Tips:\n\
+                    - Each top-level section of the JSON configuration is a TOML table\n\
+                    - Compare with a template: 'create template --provider lxd'\n\n\
+                    For more information, see: docs/user-guide/commands/validate.md",
+                    path.display(),
+                    source
+                )
+            }
             Self::DomainValidationFailed(source) => {
                 format!(
                     "Configuration validation failed.\n\n\
                     Error: {source}\n\n\
-                    This means the configuration file has valid JSON syntax but violates\n\
+                    This means the configuration file has valid syntax but violates\n\
                     domain constraints or business rules.\n\n\
                     Common issues:\n\
                     - SSH key files don't exist at specified paths\n\
@@ -145,6 +253,21 @@ mod tests {
         assert!(help.contains("JSON validator"));
     }
 
+    #[test]
+    fn it_should_provide_help_text_when_yaml_parsing_fails() {
+        let yaml_error = serde_yaml::from_str::<serde_yaml::Value>("a: [b").unwrap_err();
+        let error = ValidateCommandHandlerError::YamlParsingFailed {
+            path: PathBuf::from("config.yaml"),
+            source: yaml_error,
+        };
+
+        let help = error.help();
+
+        assert!(help.contains("YAML parsing failed"));
+        assert!(help.contains("line 1 column"));
+        assert!(help.contains("indentation"));
+    }
+
     #[test]
     fn it_should_provide_help_text_when_domain_validation_fails() {
         let config_error = CreateConfigError::TemplateSerializationFailed {
@@ -157,4 +280,35 @@ mod tests {
         assert!(help.contains("domain constraints"));
         assert!(help.contains("Common issues"));
     }
+
+    #[test]
+    fn it_should_classify_every_validation_failure_as_permanent() {
+        let errors = [
+            ValidateCommandHandlerError::FileReadFailed {
+                path: PathBuf::from("/tmp/missing.json"),
+                source: std::io::Error::new(std::io::ErrorKind::NotFound, "file not found"),
+            },
+            ValidateCommandHandlerError::JsonParsingFailed {
+                path: PathBuf::from("config.json"),
+                source: serde_json::from_str::<serde_json::Value>("{").unwrap_err(),
+            },
+            ValidateCommandHandlerError::DomainValidationFailed(
+                CreateConfigError::TemplateSerializationFailed {
+                    source: serde_json::Error::io(std::io::Error::other("test error")),
+                },
+            ),
+        ];
+
+        let kinds: Vec<ErrorKind> = errors.iter().map(Traceable::error_kind).collect();
+
+        assert_eq!(
+            kinds,
+            vec![
+                ErrorKind::FileSystem,
+                ErrorKind::Configuration,
+                ErrorKind::Configuration
+            ]
+        );
+        assert!(errors.iter().all(|error| !error.is_retryable()));
+    }
 }
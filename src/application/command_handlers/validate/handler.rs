@@ -7,15 +7,20 @@ use std::convert::TryInto;
 use std::fs;
 use std::path::Path;
 
-use crate::application::command_handlers::create::config::EnvironmentCreationConfig;
-use crate::domain::environment::EnvironmentParams;
+use tracing::instrument;
+
+use crate::application::command_handlers::create::config::{
+    ConfigFormat, ConfigLoadError, ConfigValidationIssue, EnvironmentCreationConfig,
+};
+use crate::domain::environment::{EnvironmentParams, WorkspaceLayout};
+use crate::domain::provision::ProxyConfig;
 
 use super::errors::ValidateCommandHandlerError;
 
 /// Application layer handler for validate command
 ///
 /// This handler validates environment configuration files by:
-/// 1. Parsing the JSON structure
+/// 1. Parsing the JSON, YAML or TOML structure
 /// 2. Validating field types and values
 /// 3. Verifying referenced files exist (SSH keys)
 /// 4. Checking domain constraints
@@ -68,6 +73,14 @@ impl ValidateCommandHandler {
     /// println!("Environment name: {}", result.environment_name);
     /// # Ok::<(), Box<dyn std::error::Error>>(())
     /// ```
+    #[instrument(
+        name = "command.validate",
+        skip_all,
+        fields(
+            command_type = "validate",
+            config_path = %config_path.display()
+        )
+    )]
     pub fn validate(
         &self,
         config_path: &Path,
@@ -80,15 +93,11 @@ impl ValidateCommandHandler {
             }
         })?;
 
-        // Step 2: Parse JSON to EnvironmentCreationConfig
-        // This validates JSON syntax and maps to our structure
-        let config: EnvironmentCreationConfig =
-            serde_json::from_str(&content).map_err(|source| {
-                ValidateCommandHandlerError::JsonParsingFailed {
-                    path: config_path.to_path_buf(),
-                    source,
-                }
-            })?;
+        // Step 2: Parse JSON, YAML or TOML to EnvironmentCreationConfig
+        // This validates the syntax and maps to our structure
+        let config =
+            EnvironmentCreationConfig::parse(&content, ConfigFormat::detect(config_path, &content))
+                .map_err(|error| Self::parsing_failed(config_path, error))?;
 
         // Step 3: Convert to domain types (validates all constraints)
         // This includes:
@@ -96,11 +105,30 @@ impl ValidateCommandHandler {
         // - Port numbers must be valid
         // - Domain names must be well-formed
         // - All business rules must pass
-        let _validated_params: EnvironmentParams = config
+        // Generated SSH keys get their paths when the environment is created;
+        // their location does not affect the validation
+        let validated_params: EnvironmentParams = config
             .clone()
-            .try_into()
+            .with_generated_ssh_key_paths(&WorkspaceLayout::in_working_dir("."))
+            .and_then(TryInto::try_into)
             .map_err(ValidateCommandHandlerError::DomainValidationFailed)?;
 
+        // Downloads the deployment needs, so air-gapped hosts can be pre-seeded
+        let resources = validated_params.external_resources();
+        let external_resources = match &validated_params.offline {
+            Some(offline) => offline.uncovered(resources),
+            None => resources,
+        };
+
+        // Valid settings that are likely to fail the deployment
+        let issues = validated_params
+            .proxy
+            .as_ref()
+            .and_then(ProxyConfig::warning)
+            .map(|warning| ConfigValidationIssue::warning("proxy", warning))
+            .into_iter()
+            .collect();
+
         // All validation passed!
         Ok(ValidationResult {
             environment_name: config.environment.name.clone(),
@@ -109,8 +137,29 @@ impl ValidateCommandHandler {
             has_grafana: config.grafana.is_some(),
             has_https: config.https.is_some(),
             has_backup: config.backup.is_some(),
+            external_resources: external_resources.iter().map(ToString::to_string).collect(),
+            issues,
         })
     }
+
+    /// Map a parse error of the configuration at `path` to its validation error
+    fn parsing_failed(path: &Path, error: ConfigLoadError) -> ValidateCommandHandlerError {
+        let path = path.to_path_buf();
+        match error {
+            ConfigLoadError::YamlParseFailed { source } => {
+                ValidateCommandHandlerError::YamlParsingFailed { path, source }
+            }
+            ConfigLoadError::TomlParseFailed { source } => {
+                ValidateCommandHandlerError::TomlParsingFailed { path, source }
+            }
+            ConfigLoadError::JsonParseFailed { source } => {
+                ValidateCommandHandlerError::JsonParsingFailed { path, source }
+            }
+            ConfigLoadError::FileNotFound { .. } | ConfigLoadError::FileReadFailed { .. } => {
+                unreachable!("parsing an in-memory configuration does not touch the filesystem")
+            }
+        }
+    }
 }
 
 impl Default for ValidateCommandHandler {
@@ -143,11 +192,24 @@ pub struct ValidationResult {
 
     /// Whether backups are configured
     pub has_backup: bool,
+
+    /// Resources downloaded from the Internet during the deployment
+    ///
+    /// Empty when an offline mode replaces all of them.
+    pub external_resources: Vec<String>,
+
+    /// Settings that are valid but likely to fail the deployment
+    ///
+    /// E.g. a proxy for only one of HTTP and HTTPS. Every issue of a valid
+    /// configuration is a warning: errors fail the validation with
+    /// `DomainValidationFailed`, which lists them all.
+    pub issues: Vec<ConfigValidationIssue>,
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::application::command_handlers::create::config::CreateConfigError;
     use std::env;
     use std::fs;
     use tempfile::TempDir;
@@ -228,6 +290,139 @@ mod tests {
         let result = handler.validate(&config_path);
 
         assert!(result.is_ok(), "Valid configuration should pass validation");
+        let external_resources = result.unwrap().external_resources;
+        assert!(
+            external_resources.contains(&"LXD base image 'ubuntu:24.04'".to_string()),
+            "{external_resources:?}"
+        );
+    }
+
+    #[test]
+    fn it_should_reject_prometheus_enabled_on_a_port_used_by_an_http_tracker() {
+        let handler = ValidateCommandHandler::new();
+        let temp_dir = TempDir::new().expect("Failed to create temp directory");
+        let config_path = temp_dir.path().join("port-conflict.json");
+        let project_root = env::var("CARGO_MANIFEST_DIR").expect("CARGO_MANIFEST_DIR not set");
+        let config_json = format!(
+            r#"{{
+    "environment": {{ "name": "port-conflict" }},
+    "ssh_credentials": {{
+        "private_key_path": "{project_root}/fixtures/testing_rsa",
+        "public_key_path": "{project_root}/fixtures/testing_rsa.pub"
+    }},
+    "provider": {{ "provider": "lxd", "profile_name": "test-profile" }},
+    "tracker": {{
+        "core": {{
+            "database": {{ "driver": "sqlite3", "database_name": "tracker.db" }},
+            "private": false
+        }},
+        "udp_trackers": [],
+        "http_trackers": [{{ "bind_address": "0.0.0.0:9090" }}],
+        "http_api": {{ "bind_address": "0.0.0.0:1212", "admin_token": "MyAccessToken" }},
+        "health_check_api": {{ "bind_address": "127.0.0.1:1313" }}
+    }},
+    "prometheus": {{ "scrape_interval_in_secs": 15 }}
+}}"#
+        );
+        fs::write(&config_path, config_json).expect("Failed to write test config");
+
+        let result = handler.validate(&config_path);
+
+        let Err(ValidateCommandHandlerError::DomainValidationFailed(error)) = result else {
+            panic!("Expected DomainValidationFailed error, got {result:?}");
+        };
+        let message = error.to_string();
+        assert!(message.contains("'tracker'"), "{message}");
+        assert!(message.contains("'prometheus'"), "{message}");
+    }
+
+    #[test]
+    fn it_should_reject_tls_proxy_without_an_https_section_providing_the_acme_email() {
+        let handler = ValidateCommandHandler::new();
+        let temp_dir = TempDir::new().expect("Failed to create temp directory");
+        let config_path = temp_dir.path().join("tls-without-https.json");
+        let project_root = env::var("CARGO_MANIFEST_DIR").expect("CARGO_MANIFEST_DIR not set");
+        let config_json = format!(
+            r#"{{
+    "environment": {{ "name": "tls-without-https" }},
+    "ssh_credentials": {{
+        "private_key_path": "{project_root}/fixtures/testing_rsa",
+        "public_key_path": "{project_root}/fixtures/testing_rsa.pub"
+    }},
+    "provider": {{ "provider": "lxd", "profile_name": "test-profile" }},
+    "tracker": {{
+        "core": {{
+            "database": {{ "driver": "sqlite3", "database_name": "tracker.db" }},
+            "private": false
+        }},
+        "udp_trackers": [],
+        "http_trackers": [],
+        "http_api": {{
+            "bind_address": "0.0.0.0:1212",
+            "admin_token": "MyAccessToken",
+            "domain": "api.tracker.local",
+            "use_tls_proxy": true
+        }},
+        "health_check_api": {{ "bind_address": "127.0.0.1:1313" }}
+    }}
+}}"#
+        );
+        fs::write(&config_path, config_json).expect("Failed to write test config");
+
+        let result = handler.validate(&config_path);
+
+        let Err(ValidateCommandHandlerError::DomainValidationFailed(error)) = result else {
+            panic!("Expected DomainValidationFailed error, got {result:?}");
+        };
+        let message = error.to_string();
+        assert!(message.contains("HTTPS section is missing"), "{message}");
+    }
+
+    #[test]
+    fn it_should_report_every_invalid_field_with_its_json_path_at_once() {
+        let handler = ValidateCommandHandler::new();
+        let temp_dir = TempDir::new().expect("Failed to create temp directory");
+        let config_path = temp_dir.path().join("three-problems.json");
+        let project_root = env::var("CARGO_MANIFEST_DIR").expect("CARGO_MANIFEST_DIR not set");
+        let config_json = format!(
+            r#"{{
+    "environment": {{ "name": "Three_Problems" }},
+    "ssh_credentials": {{
+        "private_key_path": "fixtures/testing_rsa",
+        "public_key_path": "{project_root}/fixtures/testing_rsa.pub"
+    }},
+    "provider": {{ "provider": "lxd", "profile_name": "test-profile" }},
+    "tracker": {{
+        "core": {{
+            "database": {{ "driver": "sqlite3", "database_name": "tracker.db" }},
+            "private": false
+        }},
+        "udp_trackers": [],
+        "http_trackers": [],
+        "http_api": {{ "bind_address": "0.0.0.0:api", "admin_token": "MyAccessToken" }},
+        "health_check_api": {{ "bind_address": "127.0.0.1:1313" }}
+    }}
+}}"#
+        );
+        fs::write(&config_path, config_json).expect("Failed to write test config");
+
+        let result = handler.validate(&config_path);
+
+        let Err(ValidateCommandHandlerError::DomainValidationFailed(
+            CreateConfigError::InvalidConfiguration { issues },
+        )) = result
+        else {
+            panic!("Expected every invalid field to be reported, got {result:?}");
+        };
+        let paths: Vec<&str> = issues.iter().map(|issue| issue.path.as_str()).collect();
+        assert_eq!(
+            paths,
+            [
+                "environment.name",
+                "ssh_credentials.private_key_path",
+                "tracker.http_api.bind_address"
+            ]
+        );
     }
 
     #[test]
@@ -266,4 +461,19 @@ mod tests {
             "Malformed JSON should return JsonParsingFailed error"
         );
     }
+
+    #[test]
+    fn it_should_return_a_toml_error_when_a_toml_file_is_malformed() {
+        let handler = ValidateCommandHandler::new();
+        let temp_dir = TempDir::new().unwrap();
+        let config_path = temp_dir.path().join("config.toml");
+        fs::write(&config_path, "[environment]\nname = e2e\n").unwrap();
+
+        let result = handler.validate(&config_path);
+
+        let Err(ValidateCommandHandlerError::TomlParsingFailed { source, .. }) = result else {
+            panic!("Expected TomlParsingFailed error, got {result:?}");
+        };
+        assert!(source.to_string().contains("line 2"), "{source}");
+    }
 }
@@ -23,7 +23,7 @@ use thiserror::Error;
 
 /// Application-layer wrapper for domain `RepositoryError`.
 ///
-/// Mirrors the three variants of the domain type using plain types (no domain
+/// Mirrors the variants of the domain type using plain types (no domain
 /// imports required by SDK consumers).
 ///
 /// # Examples
@@ -49,6 +49,35 @@ pub enum PersistenceError {
     #[error("Conflict: another process is accessing this environment")]
     Conflict,
 
+    /// Stored environment state cannot be parsed
+    #[error("Environment state is corrupted: {reason}. Restore a backup with the repair command")]
+    Corrupted {
+        /// Why the stored state could not be parsed
+        reason: String,
+    },
+
+    /// Stored environment state was written by a newer version of the deployer
+    #[error(
+        "Environment state has schema version {schema_version}, newer than version {supported_version} supported by this deployer. Upgrade the deployer to use this environment"
+    )]
+    UnsupportedVersion {
+        /// Schema version of the stored state
+        schema_version: u32,
+        /// Newest schema version supported by this deployer
+        supported_version: u32,
+        /// Version of the deployer that wrote the stored state, if recorded
+        deployer_version: Option<String>,
+    },
+
+    /// A secret needed by the environment is not stored
+    #[error(
+        "Environment secret '{secret}' is missing: the stored secrets are missing or incomplete"
+    )]
+    MissingSecret {
+        /// Name of the missing secret
+        secret: String,
+    },
+
     /// Internal implementation-specific error
     #[error("Internal error: {0}")]
     Internal(#[source] anyhow::Error),
@@ -60,6 +89,17 @@ impl From<crate::domain::environment::repository::RepositoryError> for Persisten
         match e {
             RepositoryError::NotFound => Self::NotFound,
             RepositoryError::Conflict => Self::Conflict,
+            RepositoryError::Corrupted { reason } => Self::Corrupted { reason },
+            RepositoryError::UnsupportedVersion {
+                schema_version,
+                supported_version,
+                deployer_version,
+            } => Self::UnsupportedVersion {
+                schema_version,
+                supported_version,
+                deployer_version,
+            },
+            RepositoryError::MissingSecret { secret } => Self::MissingSecret { secret },
             RepositoryError::Internal(inner) => Self::Internal(inner),
         }
     }
@@ -73,35 +113,93 @@ impl From<crate::domain::environment::repository::RepositoryError> for Persisten
 /// # Examples
 ///
 /// ```rust
-/// use torrust_tracker_deployer_lib::application::errors::InvalidStateError;
+/// use torrust_tracker_deployer_lib::application::errors::{AllowedCommands, InvalidStateError};
 ///
 /// let err = InvalidStateError {
-///     expected: "provisioned".to_string(),
-///     actual: "created".to_string(),
+///     expected: "released".to_string(),
+///     actual: "provisioned".to_string(),
+///     allowed_commands: AllowedCommands {
+///         valid: vec!["configure".to_string(), "destroy".to_string(), "purge".to_string()],
+///         suggested_next: Some("configure".to_string()),
+///     },
 /// };
-/// assert!(err.to_string().contains("provisioned"));
+/// assert!(err.to_string().contains(
+///     "environment is 'provisioned'; valid commands: configure, destroy, purge; suggested next: configure"
+/// ));
 /// ```
 #[derive(Debug, Error)]
-#[error("Expected state '{expected}', but found '{actual}'")]
+#[error("Expected state '{expected}', but environment is '{actual}'; {allowed_commands}")]
 pub struct InvalidStateError {
     /// The state that was expected
     pub expected: String,
     /// The actual state at the time of the error
     pub actual: String,
+    /// The commands allowed in the actual state
+    pub allowed_commands: AllowedCommands,
 }
 
 impl From<crate::domain::environment::state::StateTypeError> for InvalidStateError {
     fn from(e: crate::domain::environment::state::StateTypeError) -> Self {
         use crate::domain::environment::state::StateTypeError;
         match e {
-            StateTypeError::UnexpectedState { expected, actual } => Self {
+            StateTypeError::UnexpectedState {
+                expected,
+                actual,
+                allowed_commands,
+                suggested_next,
+            } => Self {
                 expected: expected.to_string(),
                 actual,
+                allowed_commands: AllowedCommands::new(allowed_commands, suggested_next),
             },
         }
     }
 }
 
+/// Application-layer representation of the commands allowed in a state.
+///
+/// Mirrors [`crate::domain::environment::state::AnyEnvironmentState::allowed_commands`]
+/// and [`crate::domain::environment::state::AnyEnvironmentState::suggested_next_command`]
+/// with command names, so invalid-state errors can tell what to run instead.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct AllowedCommands {
+    /// Names of the commands allowed in the state
+    pub valid: Vec<String>,
+    /// Name of the command suggested to move the deployment forward, if any
+    pub suggested_next: Option<String>,
+}
+
+impl AllowedCommands {
+    fn new(
+        valid: &[crate::domain::environment::state::LifecycleCommand],
+        suggested_next: Option<crate::domain::environment::state::LifecycleCommand>,
+    ) -> Self {
+        Self {
+            valid: valid.iter().map(ToString::to_string).collect(),
+            suggested_next: suggested_next.map(|command| command.to_string()),
+        }
+    }
+}
+
+impl From<&crate::domain::environment::state::AnyEnvironmentState> for AllowedCommands {
+    fn from(environment: &crate::domain::environment::state::AnyEnvironmentState) -> Self {
+        Self::new(
+            environment.allowed_commands(),
+            environment.suggested_next_command(),
+        )
+    }
+}
+
+impl fmt::Display for AllowedCommands {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "valid commands: {}", self.valid.join(", "))?;
+        if let Some(next) = &self.suggested_next {
+            write!(f, "; suggested next: {next}")?;
+        }
+        Ok(())
+    }
+}
+
 /// Application-layer representation of a release workflow step.
 ///
 /// Mirrors [`crate::domain::environment::state::ReleaseStep`] for error
@@ -119,6 +217,8 @@ impl From<crate::domain::environment::state::StateTypeError> for InvalidStateErr
 /// ```
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum ReleaseWorkflowStep {
+    /// Checking the free disk space of the remote host before writing to it
+    CheckDiskSpace,
     /// Creating tracker storage directories on remote host
     CreateTrackerStorage,
     /// Initializing tracker `SQLite` database file
@@ -157,11 +257,16 @@ pub enum ReleaseWorkflowStep {
     RenderDockerComposeTemplates,
     /// Deploying compose files to the remote host via Ansible
     DeployComposeFilesToRemote,
+    /// Pulling the container images on the remote host
+    PullContainerImages,
+    /// Verifying the digests of the images pinned by digest
+    VerifyImageDigests,
 }
 
 impl fmt::Display for ReleaseWorkflowStep {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         let name = match self {
+            Self::CheckDiskSpace => "Check Disk Space",
             Self::CreateTrackerStorage => "Create Tracker Storage",
             Self::InitTrackerDatabase => "Initialize Tracker Database",
             Self::RenderTrackerTemplates => "Render Tracker Templates",
@@ -181,6 +286,8 @@ impl fmt::Display for ReleaseWorkflowStep {
             Self::DeployCaddyConfigToRemote => "Deploy Caddy Config to Remote",
             Self::RenderDockerComposeTemplates => "Render Docker Compose Templates",
             Self::DeployComposeFilesToRemote => "Deploy Compose Files to Remote",
+            Self::PullContainerImages => "Pull Container Images",
+            Self::VerifyImageDigests => "Verify Image Digests",
         };
         write!(f, "{name}")
     }
@@ -190,6 +297,7 @@ impl From<crate::domain::environment::state::ReleaseStep> for ReleaseWorkflowSte
     fn from(s: crate::domain::environment::state::ReleaseStep) -> Self {
         use crate::domain::environment::state::ReleaseStep;
         match s {
+            ReleaseStep::CheckDiskSpace => Self::CheckDiskSpace,
             ReleaseStep::CreateTrackerStorage => Self::CreateTrackerStorage,
             ReleaseStep::InitTrackerDatabase => Self::InitTrackerDatabase,
             ReleaseStep::RenderTrackerTemplates => Self::RenderTrackerTemplates,
@@ -209,6 +317,8 @@ impl From<crate::domain::environment::state::ReleaseStep> for ReleaseWorkflowSte
             ReleaseStep::DeployCaddyConfigToRemote => Self::DeployCaddyConfigToRemote,
             ReleaseStep::RenderDockerComposeTemplates => Self::RenderDockerComposeTemplates,
             ReleaseStep::DeployComposeFilesToRemote => Self::DeployComposeFilesToRemote,
+            ReleaseStep::PullContainerImages => Self::PullContainerImages,
+            ReleaseStep::VerifyImageDigests => Self::VerifyImageDigests,
         }
     }
 }
@@ -237,13 +347,26 @@ mod tests {
         let err = InvalidStateError {
             expected: "provisioned".to_string(),
             actual: "created".to_string(),
+            allowed_commands: AllowedCommands {
+                valid: vec!["provision".to_string(), "purge".to_string()],
+                suggested_next: Some("provision".to_string()),
+            },
         };
         assert_eq!(
             err.to_string(),
-            "Expected state 'provisioned', but found 'created'"
+            "Expected state 'provisioned', but environment is 'created'; valid commands: provision, purge; suggested next: provision"
         );
     }
 
+    #[test]
+    fn it_should_omit_the_suggestion_when_there_is_none() {
+        let commands = AllowedCommands {
+            valid: vec!["destroy".to_string(), "purge".to_string()],
+            suggested_next: None,
+        };
+        assert_eq!(commands.to_string(), "valid commands: destroy, purge");
+    }
+
     #[test]
     fn it_should_convert_from_repository_error_not_found() {
         use crate::domain::environment::repository::RepositoryError;
@@ -260,16 +383,35 @@ mod tests {
         assert!(matches!(app_err, PersistenceError::Conflict));
     }
 
+    #[test]
+    fn it_should_convert_from_repository_error_corrupted() {
+        use crate::domain::environment::repository::RepositoryError;
+        let domain_err = RepositoryError::Corrupted {
+            reason: "EOF while parsing".to_string(),
+        };
+        let app_err = PersistenceError::from(domain_err);
+        assert!(app_err.to_string().contains("repair"));
+        assert!(matches!(app_err, PersistenceError::Corrupted { .. }));
+    }
+
     #[test]
     fn it_should_convert_from_state_type_error() {
+        use crate::domain::environment::state::LifecycleCommand;
         use crate::domain::environment::state::StateTypeError;
         let domain_err = StateTypeError::UnexpectedState {
             expected: "provisioned",
             actual: "created".to_string(),
+            allowed_commands: &[LifecycleCommand::Provision, LifecycleCommand::Purge],
+            suggested_next: Some(LifecycleCommand::Provision),
         };
         let app_err = InvalidStateError::from(domain_err);
         assert_eq!(app_err.expected, "provisioned");
         assert_eq!(app_err.actual, "created");
+        assert_eq!(app_err.allowed_commands.valid, ["provision", "purge"]);
+        assert_eq!(
+            app_err.allowed_commands.suggested_next.as_deref(),
+            Some("provision")
+        );
     }
 
     #[test]
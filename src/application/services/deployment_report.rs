@@ -0,0 +1,339 @@
+//! Deployment report recording
+//!
+//! `DeploymentReportListener` is a `CommandProgressListener` that records the
+//! timing of every workflow step and the settings the steps applied to the
+//! host, while forwarding all events to an inner listener (typically the
+//! presentation layer's verbose listener). Once the
+//! command handler returns, `write` builds a `DeploymentReport` from the
+//! recorded steps and the resulting environment state, and stores it in
+//! `data/{env}/reports/`.
+//!
+//! Writing the report is best-effort: a report that cannot be written is
+//! logged and never turns a successful deployment into a failure.
+//!
+//! # Example
+//!
+//! ```rust,ignore
+//! let listener = VerboseProgressListener::new(output);
+//! let recorder =
+//!     DeploymentReportListener::new("provision", env_name, clock, Some(&listener));
+//!
+//! let result = handler.execute(env_name, Some(&recorder)).await;
+//! recorder.write(repository.as_ref(), &[Dependency::OpenTofu], result.as_ref().err());
+//! ```
+
+use std::collections::BTreeMap;
+use std::sync::{Arc, Mutex, PoisonError};
+
+use chrono::{DateTime, Utc};
+use torrust_tracker_deployer_dependency_installer::Dependency;
+use tracing::{info, warn};
+
+use crate::application::traits::{CommandProgressListener, ExternalCommandOutputLine};
+use crate::domain::environment::deployment_report::elapsed_ms;
+use crate::domain::environment::repository::EnvironmentRepository;
+use crate::domain::environment::state::AnyEnvironmentState;
+use crate::domain::environment::{DeploymentReport, EnvironmentName, StepReport};
+use crate::infrastructure::reports::{detect_tool_versions, DeploymentReportStore};
+use crate::shared::Clock;
+
+/// Progress listener recording the step timings of one command run
+pub struct DeploymentReportListener<'a> {
+    command: &'static str,
+    environment: EnvironmentName,
+    clock: Arc<dyn Clock>,
+    inner: Option<&'a dyn CommandProgressListener>,
+    started_at: DateTime<Utc>,
+    steps: Mutex<Vec<StepReport>>,
+    applied_settings: Mutex<BTreeMap<String, String>>,
+    target_host: Option<String>,
+}
+
+impl<'a> DeploymentReportListener<'a> {
+    /// Start recording a command run
+    ///
+    /// # Arguments
+    ///
+    /// * `command` - Name of the command (e.g. `provision`)
+    /// * `environment` - Environment the command runs against
+    /// * `clock` - Clock for the start, end and step timestamps
+    /// * `inner` - Listener receiving every forwarded progress event
+    #[must_use]
+    pub fn new(
+        command: &'static str,
+        environment: &EnvironmentName,
+        clock: Arc<dyn Clock>,
+        inner: Option<&'a dyn CommandProgressListener>,
+    ) -> Self {
+        let started_at = clock.now();
+
+        Self {
+            command,
+            environment: environment.clone(),
+            clock,
+            inner,
+            started_at,
+            steps: Mutex::new(Vec::new()),
+            applied_settings: Mutex::new(BTreeMap::new()),
+            target_host: None,
+        }
+    }
+
+    /// Mark the run as out-of-band, against `target_host` instead of the instance
+    #[must_use]
+    pub fn with_target_host(mut self, target_host: impl Into<String>) -> Self {
+        self.target_host = Some(target_host.into());
+        self
+    }
+
+    /// Build the report of the finished command run
+    ///
+    /// # Arguments
+    ///
+    /// * `environment` - The environment as persisted after the command, if it could be loaded
+    /// * `error` - The command error, `None` if the command succeeded
+    /// * `tool_versions` - Versions of the external tools used by the command
+    #[must_use]
+    pub fn finish(
+        self,
+        environment: Option<&AnyEnvironmentState>,
+        error: Option<String>,
+        tool_versions: BTreeMap<String, String>,
+    ) -> DeploymentReport {
+        let finished_at = self.clock.now();
+        let steps = self
+            .steps
+            .into_inner()
+            .unwrap_or_else(PoisonError::into_inner);
+        let applied_settings = self
+            .applied_settings
+            .into_inner()
+            .unwrap_or_else(PoisonError::into_inner);
+
+        let failure = error
+            .as_ref()
+            .and(environment)
+            .and_then(AnyEnvironmentState::failure_report);
+        let trace_id = failure
+            .as_ref()
+            .map(|report| report.base.trace_id.to_string());
+        let trace_file = failure.and_then(|report| report.base.trace_file_path);
+
+        DeploymentReport {
+            command: self.command.to_string(),
+            environment: self.environment.to_string(),
+            started_at: self.started_at,
+            finished_at,
+            duration_ms: elapsed_ms(self.started_at, finished_at),
+            succeeded: error.is_none(),
+            state: environment.map(|env| env.state_name().to_string()),
+            config_revision: environment.map(AnyEnvironmentState::config_revision),
+            target_host: self.target_host,
+            steps,
+            tool_versions,
+            applied_settings,
+            trace_id,
+            trace_file,
+            error,
+        }
+    }
+
+    /// Build the report and store it in the environment's `reports/` directory
+    ///
+    /// The environment is reloaded from the repository to capture the state
+    /// (and, on failure, the trace id and file) persisted by the command
+    /// handler. Failures are logged, never returned.
+    ///
+    /// # Arguments
+    ///
+    /// * `repository` - Repository to reload the environment from
+    /// * `tools` - External tools whose versions are recorded
+    /// * `error` - The command error, `None` if the command succeeded
+    ///
+    /// # Returns
+    ///
+    /// The report, `None` if the environment could not be loaded. It is
+    /// returned even when it could not be written.
+    pub fn write<E: std::fmt::Display>(
+        self,
+        repository: &dyn EnvironmentRepository,
+        tools: &[Dependency],
+        error: Option<&E>,
+    ) -> Option<DeploymentReport> {
+        let environment = match repository.load(&self.environment) {
+            Ok(Some(environment)) => environment,
+            Ok(None) => return None,
+            Err(e) => {
+                warn!(
+                    environment = %self.environment,
+                    error = %e,
+                    "Could not load environment to write the deployment report"
+                );
+                return None;
+            }
+        };
+
+        let store = DeploymentReportStore::for_data_dir(environment.data_dir());
+        let report = self.finish(
+            Some(&environment),
+            error.map(ToString::to_string),
+            detect_tool_versions(tools),
+        );
+
+        match store.write(&report) {
+            Ok(path) => info!(
+                command = %report.command,
+                environment = %report.environment,
+                path = %path.display(),
+                "Deployment report written"
+            ),
+            Err(e) => warn!(
+                command = %report.command,
+                environment = %report.environment,
+                error = %e,
+                "Could not write the deployment report"
+            ),
+        }
+
+        Some(report)
+    }
+}
+
+impl CommandProgressListener for DeploymentReportListener<'_> {
+    fn on_step_started(&self, step_number: usize, total_steps: usize, description: &str) {
+        self.steps
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner)
+            .push(StepReport {
+                number: step_number,
+                description: description.to_string(),
+                started_at: self.clock.now(),
+                finished_at: None,
+                duration_ms: None,
+            });
+
+        if let Some(inner) = self.inner {
+            inner.on_step_started(step_number, total_steps, description);
+        }
+    }
+
+    fn on_step_completed(&self, step_number: usize, description: &str) {
+        let finished_at = self.clock.now();
+
+        {
+            let mut steps = self.steps.lock().unwrap_or_else(PoisonError::into_inner);
+            if let Some(step) = steps
+                .iter_mut()
+                .rev()
+                .find(|step| step.number == step_number && step.finished_at.is_none())
+            {
+                step.finished_at = Some(finished_at);
+                step.duration_ms = Some(elapsed_ms(step.started_at, finished_at));
+            }
+        }
+
+        if let Some(inner) = self.inner {
+            inner.on_step_completed(step_number, description);
+        }
+    }
+
+    fn on_detail(&self, message: &str) {
+        if let Some(inner) = self.inner {
+            inner.on_detail(message);
+        }
+    }
+
+    fn on_debug(&self, message: &str) {
+        if let Some(inner) = self.inner {
+            inner.on_debug(message);
+        }
+    }
+
+    fn on_external_command_output_line(&self, output_line: &ExternalCommandOutputLine<'_>) {
+        if let Some(inner) = self.inner {
+            inner.on_external_command_output_line(output_line);
+        }
+    }
+
+    fn on_setting_applied(&self, name: &str, value: &str) {
+        self.applied_settings
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner)
+            .insert(name.to_string(), value.to_string());
+
+        if let Some(inner) = self.inner {
+            inner.on_setting_applied(name, value);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::TimeZone;
+
+    use super::*;
+    use crate::testing::{MockClock, RecordingProgressListener};
+
+    fn env_name() -> EnvironmentName {
+        EnvironmentName::new("my-env".to_string()).unwrap()
+    }
+
+    #[test]
+    fn it_should_record_the_duration_of_each_completed_step() {
+        let clock = Arc::new(MockClock::new(
+            Utc.with_ymd_and_hms(2026, 1, 1, 12, 0, 0).unwrap(),
+        ));
+        let recorder = DeploymentReportListener::new("provision", &env_name(), clock.clone(), None);
+
+        recorder.on_step_started(1, 2, "Rendering templates");
+        clock.advance_secs(3);
+        recorder.on_step_completed(1, "Rendering templates");
+        recorder.on_step_started(2, 2, "Applying infrastructure");
+        clock.advance_secs(2);
+
+        let report = recorder.finish(None, Some("apply failed".to_string()), BTreeMap::new());
+
+        assert_eq!(report.steps[0].duration_ms, Some(3000));
+        assert_eq!(
+            report.incomplete_steps()[0].description,
+            "Applying infrastructure"
+        );
+        assert_eq!(report.duration_ms, 5000);
+        assert!(!report.succeeded);
+    }
+
+    #[test]
+    fn it_should_forward_every_event_to_the_inner_listener() {
+        let inner = RecordingProgressListener::new();
+        let recorder = DeploymentReportListener::new(
+            "configure",
+            &env_name(),
+            Arc::new(MockClock::new(Utc::now())),
+            Some(&inner),
+        );
+
+        recorder.on_step_started(1, 1, "Installing Docker");
+        recorder.on_detail("detail");
+        recorder.on_step_completed(1, "Installing Docker");
+
+        assert_eq!(inner.events().len(), 3);
+    }
+
+    #[test]
+    fn it_should_record_the_settings_applied_to_the_host() {
+        let recorder = DeploymentReportListener::new(
+            "configure",
+            &env_name(),
+            Arc::new(MockClock::new(Utc::now())),
+            None,
+        );
+
+        recorder.on_setting_applied("net.core.rmem_max", "33554432");
+        recorder.on_setting_applied("ulimit.nofile", "1048576");
+
+        let report = recorder.finish(None, None, BTreeMap::new());
+
+        assert_eq!(report.applied_settings.len(), 2);
+        assert_eq!(report.applied_settings["net.core.rmem_max"], "33554432");
+    }
+}
@@ -7,5 +7,7 @@
 //! ## Services
 //!
 //! - `rendering` module - Template rendering services for all infrastructure components
+//! - `deployment_report` module - Progress listener recording a report of each command run
 
+pub mod deployment_report;
 pub mod rendering;
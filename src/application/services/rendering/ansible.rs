@@ -15,6 +15,7 @@
 //! // Create service with dependencies
 //! let service = AnsibleTemplateRenderingService::from_paths(
 //!     templates_dir,
+//!     None,
 //!     build_dir,
 //!     clock,
 //! );
@@ -32,7 +33,7 @@ use tracing::info;
 
 use crate::application::steps::RenderAnsibleTemplatesStep;
 use crate::domain::environment::UserInputs;
-use crate::domain::TemplateManager;
+use crate::domain::template::{ExtraTemplateVariables, TemplateManager};
 use crate::infrastructure::templating::ansible::AnsibleProjectGenerator;
 use crate::shared::clock::Clock;
 
@@ -63,6 +64,7 @@ pub enum AnsibleTemplateRenderingServiceError {
 /// runtime parameters.
 pub struct AnsibleTemplateRenderingService {
     ansible_template_renderer: Arc<AnsibleProjectGenerator>,
+    known_hosts_file: Option<PathBuf>,
     clock: Arc<dyn Clock>,
 }
 
@@ -80,10 +82,21 @@ impl AnsibleTemplateRenderingService {
     ) -> Self {
         Self {
             ansible_template_renderer,
+            known_hosts_file: None,
             clock,
         }
     }
 
+    /// Check host keys against the `known_hosts` file of the environment
+    ///
+    /// Without it, the inventory accepts any host key, which only suits
+    /// templates rendered for inspection.
+    #[must_use]
+    pub fn with_known_hosts_file(mut self, known_hosts_file: PathBuf) -> Self {
+        self.known_hosts_file = Some(known_hosts_file);
+        self
+    }
+
     /// Build an `AnsibleTemplateRenderingService` from environment paths
     ///
     /// This is a factory method that creates the service with all necessary
@@ -92,6 +105,8 @@ impl AnsibleTemplateRenderingService {
     /// # Arguments
     ///
     /// * `templates_dir` - Directory containing the source templates
+    /// * `templates_override_dir` - Optional directory with user template overrides
+    /// * `extra_template_variables` - User-defined variables added to every template context
     /// * `build_dir` - Directory where rendered templates will be written
     /// * `clock` - The clock for generating timestamps
     ///
@@ -114,8 +129,18 @@ impl AnsibleTemplateRenderingService {
     /// );
     /// ```
     #[must_use]
-    pub fn from_paths(templates_dir: PathBuf, build_dir: PathBuf, clock: Arc<dyn Clock>) -> Self {
-        let template_manager = Arc::new(TemplateManager::new(templates_dir));
+    pub fn from_paths(
+        templates_dir: PathBuf,
+        templates_override_dir: Option<PathBuf>,
+        extra_template_variables: ExtraTemplateVariables,
+        build_dir: PathBuf,
+        clock: Arc<dyn Clock>,
+    ) -> Self {
+        let template_manager = Arc::new(
+            TemplateManager::new(templates_dir)
+                .with_override_dir(templates_override_dir)
+                .with_extra_variables(extra_template_variables),
+        );
 
         let ansible_template_renderer =
             Arc::new(AnsibleProjectGenerator::new(build_dir, template_manager));
@@ -169,8 +194,13 @@ impl AnsibleTemplateRenderingService {
             ssh_socket_addr,
             user_inputs.tracker().clone(),
             user_inputs.grafana().cloned(),
+            user_inputs.firewall_allow_rules(),
             self.clock.clone(),
         )
+        .with_offline_mode(user_inputs.offline().cloned())
+        .with_proxy(user_inputs.proxy().cloned())
+        .with_system_tuning(user_inputs.system_tuning().clone())
+        .with_known_hosts_file(self.known_hosts_file.clone())
         .execute()
         .await
         .map_err(|e| AnsibleTemplateRenderingServiceError::RenderingFailed {
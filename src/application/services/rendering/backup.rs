@@ -10,8 +10,8 @@ use chrono::{DateTime, Utc};
 use tracing::{info, instrument};
 
 use crate::domain::backup::BackupConfig;
+use crate::domain::template::{ExtraTemplateVariables, TemplateManager};
 use crate::domain::tracker::DatabaseConfig;
-use crate::domain::TemplateManager;
 use crate::infrastructure::templating::backup::template::wrapper::backup_config::context::{
     BackupContext, BackupDatabaseConfig,
 };
@@ -29,6 +29,8 @@ use crate::infrastructure::templating::TemplateMetadata;
 /// - Conditional rendering (only when backup is configured)
 pub struct BackupTemplateRenderingService {
     templates_dir: PathBuf,
+    templates_override_dir: Option<PathBuf>,
+    extra_template_variables: ExtraTemplateVariables,
     build_dir: PathBuf,
 }
 
@@ -38,11 +40,20 @@ impl BackupTemplateRenderingService {
     /// # Arguments
     ///
     /// * `templates_dir` - Directory containing template source files
+    /// * `templates_override_dir` - Optional directory with user template overrides
+    /// * `extra_template_variables` - User-defined variables added to every template context
     /// * `build_dir` - Directory where rendered templates will be written
     #[must_use]
-    pub fn from_paths(templates_dir: PathBuf, build_dir: PathBuf) -> Self {
+    pub fn from_paths(
+        templates_dir: PathBuf,
+        templates_override_dir: Option<PathBuf>,
+        extra_template_variables: ExtraTemplateVariables,
+        build_dir: PathBuf,
+    ) -> Self {
         Self {
             templates_dir,
+            templates_override_dir,
+            extra_template_variables,
             build_dir,
         }
     }
@@ -96,7 +107,11 @@ impl BackupTemplateRenderingService {
             "Rendering backup configuration templates"
         );
 
-        let template_manager = Arc::new(TemplateManager::new(self.templates_dir.clone()));
+        let template_manager = Arc::new(
+            TemplateManager::new(self.templates_dir.clone())
+                .with_override_dir(self.templates_override_dir.clone())
+                .with_extra_variables(self.extra_template_variables.clone()),
+        );
         let generator = BackupProjectGenerator::new(self.build_dir.clone(), template_manager);
 
         let backup_database_config = convert_database_config_to_backup(database_config);
@@ -164,6 +179,8 @@ mod tests {
 
         let service = BackupTemplateRenderingService::from_paths(
             templates_dir.path().to_path_buf(),
+            None,
+            ExtraTemplateVariables::default(),
             build_dir.path().to_path_buf(),
         );
 
@@ -178,6 +195,8 @@ mod tests {
 
         let service = BackupTemplateRenderingService::from_paths(
             templates_dir.path().to_path_buf(),
+            None,
+            ExtraTemplateVariables::default(),
             build_dir.path().to_path_buf(),
         );
 
@@ -197,6 +216,8 @@ mod tests {
 
         let service = BackupTemplateRenderingService::from_paths(
             templates_dir.path().to_path_buf(),
+            None,
+            ExtraTemplateVariables::default(),
             build_dir.path().to_path_buf(),
         );
 
@@ -8,7 +8,7 @@ use std::sync::Arc;
 
 use tracing::{info, instrument};
 
-use crate::domain::TemplateManager;
+use crate::domain::template::{ExtraTemplateVariables, TemplateManager};
 use crate::infrastructure::templating::caddy::{
     CaddyContext, CaddyProjectGenerator, CaddyProjectGeneratorError, CaddyService,
 };
@@ -26,6 +26,8 @@ use crate::domain::environment::user_inputs::UserInputs;
 /// - Conditional rendering (only when HTTPS + TLS services are configured)
 pub struct CaddyTemplateRenderingService {
     templates_dir: PathBuf,
+    templates_override_dir: Option<PathBuf>,
+    extra_template_variables: ExtraTemplateVariables,
     build_dir: PathBuf,
     clock: Arc<dyn Clock>,
 }
@@ -36,12 +38,22 @@ impl CaddyTemplateRenderingService {
     /// # Arguments
     ///
     /// * `templates_dir` - Directory containing template source files
+    /// * `templates_override_dir` - Optional directory with user template overrides
+    /// * `extra_template_variables` - User-defined variables added to every template context
     /// * `build_dir` - Directory where rendered templates will be written
     /// * `clock` - Clock service for timestamps
     #[must_use]
-    pub fn from_paths(templates_dir: PathBuf, build_dir: PathBuf, clock: Arc<dyn Clock>) -> Self {
+    pub fn from_paths(
+        templates_dir: PathBuf,
+        templates_override_dir: Option<PathBuf>,
+        extra_template_variables: ExtraTemplateVariables,
+        build_dir: PathBuf,
+        clock: Arc<dyn Clock>,
+    ) -> Self {
         Self {
             templates_dir,
+            templates_override_dir,
+            extra_template_variables,
             build_dir,
             clock,
         }
@@ -107,7 +119,11 @@ impl CaddyTemplateRenderingService {
             "Rendering Caddy configuration templates"
         );
 
-        let template_manager = Arc::new(TemplateManager::new(self.templates_dir.clone()));
+        let template_manager = Arc::new(
+            TemplateManager::new(self.templates_dir.clone())
+                .with_override_dir(self.templates_override_dir.clone())
+                .with_extra_variables(self.extra_template_variables.clone()),
+        );
         let generator = CaddyProjectGenerator::new(&self.build_dir, template_manager);
 
         generator
@@ -196,6 +212,8 @@ mod tests {
 
         let service = CaddyTemplateRenderingService::from_paths(
             templates_dir.path().to_path_buf(),
+            None,
+            ExtraTemplateVariables::default(),
             build_dir.path().to_path_buf(),
             clock,
         );
@@ -212,6 +230,8 @@ mod tests {
 
         let service = CaddyTemplateRenderingService::from_paths(
             templates_dir.path().to_path_buf(),
+            None,
+            ExtraTemplateVariables::default(),
             build_dir.path().to_path_buf(),
             clock,
         );
@@ -9,9 +9,9 @@ use std::sync::Arc;
 
 use tracing::{info, instrument};
 
+use crate::domain::template::{ExtraTemplateVariables, TemplateManager};
 use crate::domain::topology::EnabledServices;
 use crate::domain::tracker::DatabaseConfig;
-use crate::domain::TemplateManager;
 use crate::infrastructure::templating::docker_compose::template::wrappers::docker_compose::{
     DockerComposeContext, DockerComposeContextBuilder, MysqlSetupConfig, TrackerServiceContext,
 };
@@ -35,6 +35,8 @@ use crate::domain::environment::user_inputs::UserInputs;
 /// - `MySQL` setup configuration
 pub struct DockerComposeTemplateRenderingService {
     templates_dir: PathBuf,
+    templates_override_dir: Option<PathBuf>,
+    extra_template_variables: ExtraTemplateVariables,
     build_dir: PathBuf,
     clock: Arc<dyn Clock>,
 }
@@ -45,12 +47,22 @@ impl DockerComposeTemplateRenderingService {
     /// # Arguments
     ///
     /// * `templates_dir` - Directory containing template source files
+    /// * `templates_override_dir` - Optional directory with user template overrides
+    /// * `extra_template_variables` - User-defined variables added to every template context
     /// * `build_dir` - Directory where rendered templates will be written
     /// * `clock` - Clock service for timestamps
     #[must_use]
-    pub fn from_paths(templates_dir: PathBuf, build_dir: PathBuf, clock: Arc<dyn Clock>) -> Self {
+    pub fn from_paths(
+        templates_dir: PathBuf,
+        templates_override_dir: Option<PathBuf>,
+        extra_template_variables: ExtraTemplateVariables,
+        build_dir: PathBuf,
+        clock: Arc<dyn Clock>,
+    ) -> Self {
         Self {
             templates_dir,
+            templates_override_dir,
+            extra_template_variables,
             build_dir,
             clock,
         }
@@ -93,7 +105,11 @@ impl DockerComposeTemplateRenderingService {
             "Rendering Docker Compose templates"
         );
 
-        let template_manager = Arc::new(TemplateManager::new(self.templates_dir.clone()));
+        let template_manager = Arc::new(
+            TemplateManager::new(self.templates_dir.clone())
+                .with_override_dir(self.templates_override_dir.clone())
+                .with_extra_variables(self.extra_template_variables.clone()),
+        );
         let generator = DockerComposeProjectGenerator::new(&self.build_dir, &template_manager);
 
         let tracker = Self::build_tracker_config(user_inputs);
@@ -121,6 +137,12 @@ impl DockerComposeTemplateRenderingService {
         let builder = Self::apply_grafana_config(builder, user_inputs);
         let builder = Self::apply_backup_config(builder, user_inputs);
         let builder = Self::apply_caddy_config(builder, user_inputs);
+        let builder = builder.with_images(
+            user_inputs
+                .release()
+                .map(|release| release.images().clone())
+                .unwrap_or_default(),
+        );
 
         let docker_compose_context = builder.build();
 
@@ -178,22 +200,8 @@ impl DockerComposeTemplateRenderingService {
 
     /// Check if Caddy is enabled (HTTPS with at least one TLS-configured service)
     fn has_caddy_enabled(user_inputs: &UserInputs) -> bool {
-        // Check if HTTPS is configured
-        if user_inputs.https().is_none() {
-            return false;
-        }
-
-        let tracker = user_inputs.tracker();
-
-        // Check if any service has TLS configured
-        let tracker_api_has_tls = tracker.http_api_tls_domain().is_some();
-        let http_trackers_have_tls = !tracker.http_trackers_with_tls().is_empty();
-        let grafana_has_tls = user_inputs
-            .grafana()
-            .is_some_and(|g| g.tls_domain().is_some());
-
         // Caddy is enabled if HTTPS is configured AND at least one service has TLS
-        tracker_api_has_tls || http_trackers_have_tls || grafana_has_tls
+        user_inputs.https().is_some() && user_inputs.has_any_tls_configured()
     }
 
     /// Create contexts for `SQLite` database configuration
@@ -290,28 +298,10 @@ impl DockerComposeTemplateRenderingService {
         builder: DockerComposeContextBuilder,
         user_inputs: &UserInputs,
     ) -> DockerComposeContextBuilder {
-        // Check if HTTPS is configured
-        let Some(_https_config) = user_inputs.https() else {
-            return builder;
-        };
-
-        let tracker = user_inputs.tracker();
-
-        // Check if any service has TLS configured
-        let has_tracker_api_tls = tracker.http_api_tls_domain().is_some();
-        let has_http_tracker_tls = !tracker.http_trackers_with_tls().is_empty();
-        let has_grafana_tls = user_inputs
-            .grafana()
-            .is_some_and(|g| g.tls_domain().is_some());
-
-        let has_any_tls = has_tracker_api_tls || has_http_tracker_tls || has_grafana_tls;
-
         // Note: The CaddyContext with full service details is built separately
         // in CaddyTemplateRenderingService. The docker-compose template only needs
         // to know if Caddy is enabled, not the service details.
-
-        // Only add Caddy if at least one service has TLS
-        if has_any_tls {
+        if Self::has_caddy_enabled(user_inputs) {
             builder.with_caddy()
         } else {
             builder
@@ -331,7 +321,10 @@ impl DockerComposeTemplateRenderingService {
             });
             env_context.with_grafana(
                 grafana_config.admin_user().to_string(),
-                grafana_config.admin_password().expose_secret().to_string(),
+                grafana_config
+                    .admin_password()
+                    .map(|password| password.expose_secret().to_string())
+                    .unwrap_or_default(),
                 server_root_url,
             )
         } else {
@@ -364,6 +357,8 @@ mod tests {
 
         let service = DockerComposeTemplateRenderingService::from_paths(
             templates_dir.path().to_path_buf(),
+            None,
+            ExtraTemplateVariables::default(),
             build_dir.path().to_path_buf(),
             clock,
         );
@@ -380,6 +375,8 @@ mod tests {
 
         let service = DockerComposeTemplateRenderingService::from_paths(
             templates_dir.path().to_path_buf(),
+            None,
+            ExtraTemplateVariables::default(),
             build_dir.path().to_path_buf(),
             clock,
         );
@@ -11,7 +11,7 @@ use thiserror::Error;
 use tracing::info;
 
 use crate::domain::prometheus::PrometheusConfig;
-use crate::domain::template::TemplateManager;
+use crate::domain::template::{ExtraTemplateVariables, TemplateManager};
 use crate::infrastructure::templating::grafana::template::renderer::{
     GrafanaProjectGenerator, GrafanaProjectGeneratorError,
 };
@@ -53,6 +53,8 @@ impl GrafanaTemplateRenderingService {
     /// # Arguments
     ///
     /// * `templates_dir` - Directory containing the source templates
+    /// * `templates_override_dir` - Optional directory with user template overrides
+    /// * `extra_template_variables` - User-defined variables added to every template context
     /// * `build_dir` - Directory where rendered templates will be written
     /// * `clock` - The clock for generating timestamps
     ///
@@ -60,8 +62,18 @@ impl GrafanaTemplateRenderingService {
     ///
     /// Returns a configured `GrafanaTemplateRenderingService` ready for template rendering
     #[must_use]
-    pub fn from_paths(templates_dir: PathBuf, build_dir: PathBuf, clock: Arc<dyn Clock>) -> Self {
-        let template_manager = Arc::new(TemplateManager::new(templates_dir));
+    pub fn from_paths(
+        templates_dir: PathBuf,
+        templates_override_dir: Option<PathBuf>,
+        extra_template_variables: ExtraTemplateVariables,
+        build_dir: PathBuf,
+        clock: Arc<dyn Clock>,
+    ) -> Self {
+        let template_manager = Arc::new(
+            TemplateManager::new(templates_dir)
+                .with_override_dir(templates_override_dir)
+                .with_extra_variables(extra_template_variables),
+        );
 
         Self {
             build_dir,
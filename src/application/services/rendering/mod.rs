@@ -50,6 +50,7 @@
 //!
 //! let service = AnsibleTemplateRenderingService::from_paths(
 //!     templates_dir,
+//!     None,
 //!     build_dir,
 //!     Arc::new(SystemClock),
 //! );
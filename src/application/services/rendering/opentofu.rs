@@ -11,9 +11,14 @@ use thiserror::Error;
 use tracing::info;
 
 use crate::adapters::ssh::SshCredentials;
+use crate::domain::firewall::FirewallRule;
 use crate::domain::provider::ProviderConfig;
+use crate::domain::provision::{
+    CloudInitCustomization, InstanceResources, OfflineMode, ProxyConfig,
+};
+use crate::domain::template::{ExtraTemplateVariables, TemplateManager};
+use crate::domain::topology::PortBinding;
 use crate::domain::InstanceName;
-use crate::domain::TemplateManager;
 use crate::infrastructure::templating::tofu::{TofuProjectGenerator, TofuProjectGeneratorError};
 use crate::shared::Clock;
 
@@ -54,6 +59,8 @@ impl OpenTofuTemplateRenderingService {
     /// # Arguments
     ///
     /// * `templates_dir` - Directory containing the source templates
+    /// * `templates_override_dir` - Optional directory with user template overrides
+    /// * `extra_template_variables` - User-defined variables added to every template context
     /// * `build_dir` - Directory where rendered templates will be written
     /// * `ssh_credentials` - SSH credentials for accessing the provisioned instance
     /// * `ssh_port` - SSH port for the instance
@@ -65,8 +72,11 @@ impl OpenTofuTemplateRenderingService {
     ///
     /// Returns a configured `OpenTofuTemplateRenderingService` ready for template rendering
     #[must_use]
+    #[allow(clippy::too_many_arguments)] // Mirrors `TofuProjectGenerator::new` plus template sources
     pub fn from_params(
         templates_dir: PathBuf,
+        templates_override_dir: Option<PathBuf>,
+        extra_template_variables: ExtraTemplateVariables,
         build_dir: PathBuf,
         ssh_credentials: SshCredentials,
         ssh_port: u16,
@@ -74,7 +84,11 @@ impl OpenTofuTemplateRenderingService {
         provider_config: ProviderConfig,
         clock: Arc<dyn Clock>,
     ) -> Self {
-        let template_manager = Arc::new(TemplateManager::new(templates_dir));
+        let template_manager = Arc::new(
+            TemplateManager::new(templates_dir)
+                .with_override_dir(templates_override_dir)
+                .with_extra_variables(extra_template_variables),
+        );
 
         let generator = TofuProjectGenerator::new(
             template_manager,
@@ -89,6 +103,68 @@ impl OpenTofuTemplateRenderingService {
         Self { generator }
     }
 
+    /// Open the given ports in the provider firewall, if the provider has one
+    ///
+    /// See [`TofuProjectGenerator::with_public_ports`].
+    #[must_use]
+    pub fn with_public_ports(self, public_ports: Vec<PortBinding>) -> Self {
+        Self {
+            generator: self.generator.with_public_ports(public_ports),
+        }
+    }
+
+    /// Open the extra allow rules in the provider firewall, if the provider has one
+    ///
+    /// See [`TofuProjectGenerator::with_extra_firewall_rules`].
+    #[must_use]
+    pub fn with_extra_firewall_rules(self, extra_firewall_rules: Vec<FirewallRule>) -> Self {
+        Self {
+            generator: self
+                .generator
+                .with_extra_firewall_rules(extra_firewall_rules),
+        }
+    }
+
+    /// Merge user additions into the cloud-init configuration of the instance
+    ///
+    /// See [`TofuProjectGenerator::with_cloud_init_customization`].
+    #[must_use]
+    pub fn with_cloud_init_customization(self, customization: CloudInitCustomization) -> Self {
+        Self {
+            generator: self.generator.with_cloud_init_customization(customization),
+        }
+    }
+
+    /// Deploy from pre-seeded artifacts instead of the Internet
+    ///
+    /// See [`TofuProjectGenerator::with_offline_mode`].
+    #[must_use]
+    pub fn with_offline_mode(self, offline: Option<OfflineMode>) -> Self {
+        Self {
+            generator: self.generator.with_offline_mode(offline),
+        }
+    }
+
+    /// Download the packages of the first boot through an outbound proxy
+    ///
+    /// See [`TofuProjectGenerator::with_proxy`].
+    #[must_use]
+    pub fn with_proxy(self, proxy: Option<ProxyConfig>) -> Self {
+        Self {
+            generator: self.generator.with_proxy(proxy),
+        }
+    }
+
+    /// Size the instance, if the provider sizes it itself
+    ///
+    /// See [`TofuProjectGenerator::with_instance_resources`].
+    #[must_use]
+    pub fn with_instance_resources(self, instance_resources: InstanceResources) -> Self {
+        Self {
+            generator: self.generator.with_instance_resources(instance_resources),
+        }
+    }
+
     /// Render `OpenTofu` infrastructure templates
     ///
     /// This renders the `OpenTofu` configuration files (main.tf, variables.tf, etc.)
@@ -10,7 +10,7 @@ use std::sync::Arc;
 use thiserror::Error;
 use tracing::info;
 
-use crate::domain::template::TemplateManager;
+use crate::domain::template::{ExtraTemplateVariables, TemplateManager};
 use crate::domain::tracker::TrackerConfig;
 use crate::infrastructure::templating::tracker::{
     TrackerProjectGenerator, TrackerProjectGeneratorError,
@@ -53,6 +53,8 @@ impl TrackerTemplateRenderingService {
     /// # Arguments
     ///
     /// * `templates_dir` - Directory containing the source templates
+    /// * `templates_override_dir` - Optional directory with user template overrides
+    /// * `extra_template_variables` - User-defined variables added to every template context
     /// * `build_dir` - Directory where rendered templates will be written
     /// * `clock` - The clock for generating timestamps
     ///
@@ -60,8 +62,18 @@ impl TrackerTemplateRenderingService {
     ///
     /// Returns a configured `TrackerTemplateRenderingService` ready for template rendering
     #[must_use]
-    pub fn from_paths(templates_dir: PathBuf, build_dir: PathBuf, clock: Arc<dyn Clock>) -> Self {
-        let template_manager = Arc::new(TemplateManager::new(templates_dir));
+    pub fn from_paths(
+        templates_dir: PathBuf,
+        templates_override_dir: Option<PathBuf>,
+        extra_template_variables: ExtraTemplateVariables,
+        build_dir: PathBuf,
+        clock: Arc<dyn Clock>,
+    ) -> Self {
+        let template_manager = Arc::new(
+            TemplateManager::new(templates_dir)
+                .with_override_dir(templates_override_dir)
+                .with_extra_variables(extra_template_variables),
+        );
 
         Self {
             build_dir,
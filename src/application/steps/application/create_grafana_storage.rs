@@ -65,7 +65,7 @@ impl CreateGrafanaStorageStep {
     /// - Directory creation fails on remote host
     /// - Permission setting fails
     #[instrument(
-        name = "create_grafana_storage",
+        name = "step.create_grafana_storage",
         skip_all,
         fields(step_type = "system", component = "grafana", method = "ansible")
     )]
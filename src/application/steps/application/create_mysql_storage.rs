@@ -65,7 +65,7 @@ impl CreateMysqlStorageStep {
     /// - Directory creation fails on remote host
     /// - Permission setting fails
     #[instrument(
-        name = "create_mysql_storage",
+        name = "step.create_mysql_storage",
         skip_all,
         fields(step_type = "system", component = "mysql", method = "ansible")
     )]
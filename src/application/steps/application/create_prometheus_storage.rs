@@ -56,7 +56,7 @@ impl CreatePrometheusStorageStep {
     /// - Directory creation fails on remote host
     /// - Permission setting fails
     #[instrument(
-        name = "create_prometheus_storage",
+        name = "step.create_prometheus_storage",
         skip_all,
         fields(step_type = "system", component = "prometheus", method = "ansible")
     )]
@@ -58,7 +58,7 @@ impl CreateTrackerStorageStep {
     /// - Directory creation fails on remote host
     /// - Permission setting fails
     #[instrument(
-        name = "create_tracker_storage",
+        name = "step.create_tracker_storage",
         skip_all,
         fields(step_type = "system", component = "tracker", method = "ansible")
     )]
@@ -59,7 +59,7 @@ impl DeployBackupConfigStep {
     /// - Permission setting fails
     /// - Verification assertions fail
     #[instrument(
-        name = "deploy_backup_config",
+        name = "step.deploy_backup_config",
         skip_all,
         fields(step_type = "deployment", component = "backup", method = "ansible")
     )]
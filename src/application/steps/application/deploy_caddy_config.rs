@@ -65,7 +65,7 @@ impl DeployCaddyConfigStep {
     /// - Permission setting fails
     /// - Verification assertions fail
     #[instrument(
-        name = "deploy_caddy_config",
+        name = "step.deploy_caddy_config",
         skip_all,
         fields(step_type = "deployment", component = "caddy", method = "ansible")
     )]
@@ -101,7 +101,7 @@ impl DeployComposeFilesStep {
     /// * The Ansible playbook execution fails
     /// * File synchronization fails
     #[instrument(
-        name = "deploy_compose_files",
+        name = "step.deploy_compose_files",
         skip_all,
         fields(
             step_type = "application",
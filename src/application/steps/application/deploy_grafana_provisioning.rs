@@ -63,7 +63,7 @@ impl DeployGrafanaProvisioningStep {
     /// - File copying fails
     /// - Permission setting fails
     #[instrument(
-        name = "deploy_grafana_provisioning",
+        name = "step.deploy_grafana_provisioning",
         skip_all,
         fields(step_type = "deployment", component = "grafana", method = "ansible")
     )]
@@ -60,7 +60,7 @@ impl DeployPrometheusConfigStep {
     /// - Permission setting fails
     /// - Verification assertions fail
     #[instrument(
-        name = "deploy_prometheus_config",
+        name = "step.deploy_prometheus_config",
         skip_all,
         fields(step_type = "deployment", component = "prometheus", method = "ansible")
     )]
@@ -1,65 +1,62 @@
 //! Deploy Tracker configuration step
 //!
 //! This module provides the `DeployTrackerConfigStep` which handles the deployment
-//! of Tracker configuration files to a remote host via Ansible.
+//! of Tracker configuration files to a remote host over SSH.
 //!
 //! ## Key Features
 //!
 //! - Deploys tracker.toml configuration file to remote host
-//! - Uses Ansible's copy module for reliable file transfer
-//! - Verifies successful deployment
-//! - Sets correct file permissions and ownership
+//! - Uses the `RemoteFileTransfer` remote action, which verifies the SHA-256
+//!   checksum of the uploaded file before replacing the deployed one
+//! - Reports upload progress as details to the progress listener
 //!
 //! ## Deployment Process
 //!
-//! The step executes the "deploy-tracker-config" Ansible playbook which:
-//! - Copies tracker.toml from the local build directory to the remote host
-//! - Places it in `/opt/torrust/storage/tracker/etc/tracker.toml`
-//! - Sets appropriate permissions (0644) and ownership
-//! - Verifies the file was deployed successfully
+//! The step uploads tracker.toml from the local build directory to
+//! `/opt/torrust/storage/tracker/etc/tracker.toml`. The directory is created
+//! by the "create-tracker-storage" playbook and owned by the SSH user, so the
+//! file is owned by that user too.
 //!
 //! ## Architecture
 //!
 //! This step follows the three-level architecture:
 //! - **Command** (Level 1): `ReleaseCommandHandler` orchestrates the release workflow
 //! - **Step** (Level 2): This `DeployTrackerConfigStep` handles file deployment
-//! - **Remote Action** (Level 3): Ansible playbook executes on the remote host
+//! - **Remote Action** (Level 3): `RemoteFileTransfer` uploads the file over SSH
 //!
 //! ## Usage
 //!
 //! ```rust,ignore
-//! use std::sync::Arc;
 //! use std::path::PathBuf;
-//! use crate::adapters::ansible::AnsibleClient;
 //! use crate::application::steps::application::DeployTrackerConfigStep;
 //!
-//! let ansible_client = Arc::new(AnsibleClient::new(PathBuf::from("/path/to/ansible/build")));
 //! let tracker_build_dir = PathBuf::from("/path/to/tracker/build");
 //!
-//! let step = DeployTrackerConfigStep::new(ansible_client, tracker_build_dir);
-//! step.execute()?;
+//! let step = DeployTrackerConfigStep::new(ssh_config, tracker_build_dir);
+//! step.execute(None)?;
 //! ```
 
 use std::path::PathBuf;
-use std::sync::Arc;
 
 use thiserror::Error;
 use tracing::{info, instrument};
 
-use crate::adapters::ansible::AnsibleClient;
-use crate::shared::command::CommandError;
+use crate::adapters::ssh::SshConfig;
+use crate::application::traits::CommandProgressListener;
+use crate::infrastructure::remote_actions::{RemoteFileTransfer, RemoteTransferError};
 use crate::shared::{ErrorKind, Traceable};
 
 /// Default remote configuration directory for tracker
 pub const DEFAULT_TRACKER_CONFIG_DIR: &str = "/opt/torrust/storage/tracker/etc";
 
-/// Step that deploys Tracker configuration file to a remote host via Ansible
+/// Step that deploys Tracker configuration file to a remote host over SSH
 ///
 /// This step handles the transfer of the tracker.toml configuration file
-/// to the remote instance using Ansible's copy module.
+/// to the remote instance using the `RemoteFileTransfer` remote action.
 pub struct DeployTrackerConfigStep {
-    ansible_client: Arc<AnsibleClient>,
+    ssh_config: SshConfig,
     tracker_build_dir: PathBuf,
+    remote_config_dir: String,
 }
 
 impl DeployTrackerConfigStep {
@@ -67,30 +64,36 @@ impl DeployTrackerConfigStep {
     ///
     /// # Arguments
     ///
-    /// * `ansible_client` - The Ansible client for executing playbooks
+    /// * `ssh_config` - SSH connection configuration of the instance
     /// * `tracker_build_dir` - Local directory containing rendered tracker.toml
     #[must_use]
-    pub fn new(ansible_client: Arc<AnsibleClient>, tracker_build_dir: PathBuf) -> Self {
+    pub fn new(ssh_config: SshConfig, tracker_build_dir: PathBuf) -> Self {
         Self {
-            ansible_client,
+            ssh_config,
             tracker_build_dir,
+            remote_config_dir: DEFAULT_TRACKER_CONFIG_DIR.to_string(),
         }
     }
 
+    /// Sets the remote directory tracker.toml is deployed to
+    #[must_use]
+    pub fn with_remote_config_dir(mut self, dir: impl Into<String>) -> Self {
+        self.remote_config_dir = dir.into();
+        self
+    }
+
     /// Execute the deployment step
     ///
-    /// This will run the "deploy-tracker-config" Ansible playbook to copy
-    /// the tracker.toml configuration file to the remote host.
+    /// This will upload the tracker.toml configuration file to the remote host.
     ///
     /// # Errors
     ///
     /// Returns an error if:
     /// * The tracker build directory does not exist
     /// * The tracker.toml file does not exist in the build directory
-    /// * The Ansible playbook execution fails
-    /// * File copying fails
+    /// * The upload fails or the uploaded file does not match the checksum
     #[instrument(
-        name = "deploy_tracker_config",
+        name = "step.deploy_tracker_config",
         skip_all,
         fields(
             step_type = "application",
@@ -98,7 +101,10 @@ impl DeployTrackerConfigStep {
             tracker_build_dir = %self.tracker_build_dir.display()
         )
     )]
-    pub fn execute(&self) -> Result<(), DeployTrackerConfigStepError> {
+    pub fn execute(
+        &self,
+        listener: Option<&dyn CommandProgressListener>,
+    ) -> Result<(), DeployTrackerConfigStepError> {
         info!(
             step = "deploy_tracker_config",
             tracker_build_dir = %self.tracker_build_dir.display(),
@@ -120,24 +126,23 @@ impl DeployTrackerConfigStep {
             });
         }
 
-        // Execute the Ansible playbook
-        // Note: The playbook uses a relative path from playbook_dir to find tracker.toml
-        // The Ansible build directory structure is: build/<env>/ansible/
-        // The tracker build directory structure is: build/<env>/tracker/
-        // So from ansible/ directory, tracker.toml is at: ../tracker/tracker.toml
-        self.ansible_client
-            .run_playbook("deploy-tracker-config", &[])
-            .map_err(
-                |source| DeployTrackerConfigStepError::AnsiblePlaybookFailed {
-                    message: source.to_string(),
-                    source,
-                },
-            )?;
+        let remote_path = format!("{}/tracker.toml", self.remote_config_dir);
+        let report = RemoteFileTransfer::new(self.ssh_config.clone())
+            .upload_file(&tracker_toml, &remote_path, &|progress| {
+                if let Some(l) = listener {
+                    l.on_detail(&format!(
+                        "Uploaded {}/{} bytes of {}",
+                        progress.bytes_transferred, progress.total_bytes, progress.remote_path
+                    ));
+                }
+            })
+            .map_err(|source| DeployTrackerConfigStepError::UploadFailed { source })?;
 
         info!(
             step = "deploy_tracker_config",
             status = "success",
-            "Tracker configuration deployed successfully to {DEFAULT_TRACKER_CONFIG_DIR}/tracker.toml"
+            sha256 = %report.sha256,
+            "Tracker configuration deployed successfully to {remote_path}"
         );
 
         Ok(())
@@ -155,12 +160,11 @@ pub enum DeployTrackerConfigStepError {
     #[error("Tracker configuration file not found: {path}")]
     TrackerConfigNotFound { path: String },
 
-    /// Ansible playbook execution failed
-    #[error("Ansible playbook execution failed: {message}")]
-    AnsiblePlaybookFailed {
-        message: String,
+    /// Uploading tracker.toml failed
+    #[error("Tracker configuration upload failed: {source}")]
+    UploadFailed {
         #[source]
-        source: CommandError,
+        source: RemoteTransferError,
     },
 }
 
@@ -170,7 +174,7 @@ impl Traceable for DeployTrackerConfigStepError {
             Self::TrackerBuildDirNotFound { .. } | Self::TrackerConfigNotFound { .. } => {
                 ErrorKind::Configuration
             }
-            Self::AnsiblePlaybookFailed { source, .. } => source.error_kind(),
+            Self::UploadFailed { source } => source.error_kind(),
         }
     }
 
@@ -182,15 +186,17 @@ impl Traceable for DeployTrackerConfigStepError {
             Self::TrackerConfigNotFound { path } => {
                 format!("TrackerConfigNotFound {{ path: {path} }}")
             }
-            Self::AnsiblePlaybookFailed { message, source } => {
-                format!("AnsiblePlaybookFailed {{ message: {message}, source: {source:?} }}")
+            Self::UploadFailed { source } => {
+                format!("UploadFailed {{ source: {source} }}")
             }
         }
     }
 
     fn trace_source(&self) -> Option<&dyn Traceable> {
-        // CommandError doesn't implement Traceable
-        None
+        match self {
+            Self::UploadFailed { source } => Some(source),
+            Self::TrackerBuildDirNotFound { .. } | Self::TrackerConfigNotFound { .. } => None,
+        }
     }
 }
 
@@ -254,36 +260,23 @@ For more information, see docs/user-guide/commands.md
 "
             )),
 
-            Self::AnsiblePlaybookFailed { source, .. } => {
-                let base_help = format!(
-                    r"Ansible Playbook Failed - Troubleshooting:
+            Self::UploadFailed { source } => Some(format!(
+                r"Tracker Configuration Upload Failed - Troubleshooting:
 
-1. Check SSH connectivity to the remote host:
-   ssh -i <ssh-key> <user>@<host>
-
-2. Verify the Ansible playbook exists:
-   ls templates/ansible/deploy-tracker-config.yml
-
-3. Check Ansible execution permissions
-
-4. Verify the tracker storage directories exist:
+1. Verify the tracker storage directories exist and belong to the SSH user:
    ssh <user>@<host> 'ls -la /opt/torrust/storage/tracker/'
 
-Common causes:
-- Ansible playbook not found
-- SSH connectivity issues
-- Remote directory permissions
-- Tracker storage not created (run create-tracker-storage.yml first)
+2. Re-run the release command: a partial upload is resumed
 
-Original Ansible error:
+{}
+
+Original error:
 {source}
 
 For more information, see docs/user-guide/commands.md
-"
-                );
-
-                Some(base_help)
-            }
+",
+                source.help()
+            )),
         }
     }
 }
@@ -295,19 +288,27 @@ mod tests {
     use tempfile::TempDir;
 
     use super::*;
+    use crate::adapters::ssh::SshCredentials;
+    use crate::shared::Username;
+
+    /// SSH configuration of an instance the tests never connect to
+    fn test_ssh_config() -> SshConfig {
+        let credentials = SshCredentials::new(
+            PathBuf::from("/path/to/key"),
+            PathBuf::from("/path/to/key.pub"),
+            Username::new("torrust").unwrap(),
+        );
+        SshConfig::with_default_port(credentials, "192.0.2.1".parse().unwrap())
+    }
 
     #[test]
     fn it_should_return_error_when_build_dir_not_found() {
         let temp_dir = TempDir::new().expect("Failed to create temp dir");
-        let ansible_build_dir = temp_dir.path().join("build/ansible");
         let tracker_build_dir = temp_dir.path().join("build/tracker");
 
-        fs::create_dir_all(&ansible_build_dir).expect("Failed to create ansible dir");
-
-        let ansible_client = Arc::new(AnsibleClient::new(ansible_build_dir));
-        let step = DeployTrackerConfigStep::new(ansible_client, tracker_build_dir.clone());
+        let step = DeployTrackerConfigStep::new(test_ssh_config(), tracker_build_dir.clone());
 
-        let result = step.execute();
+        let result = step.execute(None);
 
         assert!(result.is_err());
         match result.unwrap_err() {
@@ -321,16 +322,13 @@ mod tests {
     #[test]
     fn it_should_return_error_when_tracker_toml_not_found() {
         let temp_dir = TempDir::new().expect("Failed to create temp dir");
-        let ansible_build_dir = temp_dir.path().join("build/ansible");
         let tracker_build_dir = temp_dir.path().join("build/tracker");
 
-        fs::create_dir_all(&ansible_build_dir).expect("Failed to create ansible dir");
         fs::create_dir_all(&tracker_build_dir).expect("Failed to create tracker dir");
 
-        let ansible_client = Arc::new(AnsibleClient::new(ansible_build_dir));
-        let step = DeployTrackerConfigStep::new(ansible_client, tracker_build_dir.clone());
+        let step = DeployTrackerConfigStep::new(test_ssh_config(), tracker_build_dir.clone());
 
-        let result = step.execute();
+        let result = step.execute(None);
 
         assert!(result.is_err());
         match result.unwrap_err() {
@@ -61,7 +61,7 @@ impl InitTrackerDatabaseStep {
     /// - Permission setting fails
     /// - File verification fails
     #[instrument(
-        name = "init_tracker_database",
+        name = "step.init_tracker_database",
         skip_all,
         fields(step_type = "application", component = "tracker", method = "ansible")
     )]
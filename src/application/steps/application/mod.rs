@@ -16,14 +16,16 @@
 //! - `create_mysql_storage` - Creates `MySQL` storage directory structure on remote host
 //! - `deploy_backup_config` - Deploys backup configuration files to remote host
 //! - `deploy_compose_files` - Deploys Docker Compose files to remote host via Ansible
+//! - `pull_container_images` - Pulls the service images on the remote host and verifies pinned digests
 //! - `start_services` - Starts Docker Compose services via Ansible
+//! - `stop_services` - Stops the Docker Compose services for maintenance
+//! - `resume_services` - Starts the services stopped for maintenance again
+//! - `wait_for_tracker_health` - Waits for the tracker Health Check API to report healthy
 //! - `run` - Legacy run step (placeholder)
 //!
 //! ## Future Steps
 //!
 //! This module is prepared for future application deployment steps such as:
-//! - Application health checks and validation
-//! - Service stop and restart operations
 //! - Status monitoring and reporting
 //!
 //! ## Integration
@@ -44,8 +46,12 @@ pub mod deploy_grafana_provisioning;
 pub mod deploy_prometheus_config;
 pub mod deploy_tracker_config;
 pub mod init_tracker_database;
+pub mod pull_container_images;
+pub mod resume_services;
 pub mod run;
 pub mod start_services;
+pub mod stop_services;
+pub mod wait_for_tracker_health;
 
 pub use create_backup_storage::CreateBackupStorageStep;
 pub use create_grafana_storage::CreateGrafanaStorageStep;
@@ -59,5 +65,9 @@ pub use deploy_grafana_provisioning::DeployGrafanaProvisioningStep;
 pub use deploy_prometheus_config::DeployPrometheusConfigStep;
 pub use deploy_tracker_config::{DeployTrackerConfigStep, DeployTrackerConfigStepError};
 pub use init_tracker_database::InitTrackerDatabaseStep;
+pub use pull_container_images::PullContainerImagesStep;
+pub use resume_services::ResumeServicesStep;
 pub use run::{RunStep, RunStepError};
 pub use start_services::{StartServicesStep, StartServicesStepError};
+pub use stop_services::StopServicesStep;
+pub use wait_for_tracker_health::WaitForTrackerHealthStep;
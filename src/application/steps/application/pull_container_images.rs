@@ -0,0 +1,108 @@
+//! Container image pull step
+//!
+//! This module provides the `PullContainerImagesStep` which pulls the images
+//! of the Docker Compose stack on the instance during the release, and checks
+//! the images pinned by digest, so a wrong image fails the release instead of
+//! the `run` command starting it.
+//!
+//! ## Key Features
+//!
+//! - Logs in to the private registry first, when one is configured
+//! - Pulls the images of all services with `docker compose pull`
+//! - Compares the digest of every pinned image with the pulled one
+//!
+//! ## Architecture
+//!
+//! This step follows the three-level architecture:
+//! - **Command** (Level 1): `ReleaseCommandHandler` orchestrates the release workflow
+//! - **Step** (Level 2): This `PullContainerImagesStep` pulls and verifies the images
+//! - **Remote Action** (Level 3): `ContainerImages` runs `docker` over SSH
+
+use tracing::{info, instrument};
+
+use crate::adapters::ssh::SshConfig;
+use crate::application::traits::CommandProgressListener;
+use crate::domain::release::{ImageReference, RegistryCredentials};
+use crate::infrastructure::remote_actions::{ContainerImages, ContainerImagesError};
+
+/// Step that pulls the container images on the instance and verifies their digests
+pub struct PullContainerImagesStep {
+    ssh_config: SshConfig,
+    registry: Option<RegistryCredentials>,
+    pinned_images: Vec<(String, ImageReference)>,
+}
+
+impl PullContainerImagesStep {
+    /// Creates a new `PullContainerImagesStep`
+    ///
+    /// # Arguments
+    ///
+    /// * `ssh_config` - SSH connection configuration of the instance
+    /// * `registry` - Private registry to log in to before pulling, if any
+    /// * `pinned_images` - Service names with the image they pin by digest
+    #[must_use]
+    pub fn new(
+        ssh_config: SshConfig,
+        registry: Option<RegistryCredentials>,
+        pinned_images: Vec<(String, ImageReference)>,
+    ) -> Self {
+        Self {
+            ssh_config,
+            registry,
+            pinned_images,
+        }
+    }
+
+    /// Execute the pull and verification
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the registry login or the pull fails, or if a
+    /// pinned image was pulled with another digest.
+    #[instrument(
+        name = "step.pull_container_images",
+        skip_all,
+        fields(step_type = "application", operation = "pull_container_images")
+    )]
+    pub fn execute(
+        &self,
+        listener: Option<&dyn CommandProgressListener>,
+    ) -> Result<(), ContainerImagesError> {
+        let images = ContainerImages::new(self.ssh_config.clone());
+
+        if let Some(registry) = &self.registry {
+            images.login(registry)?;
+
+            if let Some(l) = listener {
+                l.on_detail(&format!(
+                    "Logged in to {} as {}",
+                    registry.server(),
+                    registry.username()
+                ));
+            }
+        }
+
+        images.pull()?;
+
+        if let Some(l) = listener {
+            l.on_detail("Pulled the images of the Docker Compose services");
+        }
+
+        for (service, image) in &self.pinned_images {
+            images.verify_digest(service, image)?;
+
+            if let Some(l) = listener {
+                l.on_detail(&format!("Verified the digest of {service}: {image}"));
+            }
+        }
+
+        info!(
+            step = "pull_container_images",
+            pinned_images = self.pinned_images.len(),
+            status = "success",
+            "Container images pulled and verified"
+        );
+
+        Ok(())
+    }
+}
@@ -0,0 +1,53 @@
+//! Service resume step
+//!
+//! This module provides the `ResumeServicesStep` which starts the Docker
+//! Compose services stopped by `StopServicesStep` again. Unlike
+//! `StartServicesStep`, nothing is pulled or recreated: the containers of
+//! the running release are started as they were.
+//!
+//! ## Usage Context
+//!
+//! This step runs first in the start workflow, followed by
+//! `WaitForTrackerHealthStep`.
+
+use tracing::{info, instrument};
+
+use crate::adapters::ssh::SshConfig;
+use crate::infrastructure::remote_actions::{TrackerServices, TrackerServicesError};
+
+/// Step that starts the stopped Docker Compose services on the instance
+pub struct ResumeServicesStep {
+    ssh_config: SshConfig,
+}
+
+impl ResumeServicesStep {
+    /// Create a new `ResumeServicesStep`
+    #[must_use]
+    pub fn new(ssh_config: SshConfig) -> Self {
+        Self { ssh_config }
+    }
+
+    /// Execute the service resume step
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if `docker compose start` fails on the instance.
+    #[instrument(
+        name = "step.resume_services",
+        skip_all,
+        fields(step_type = "application", operation = "resume_services")
+    )]
+    pub fn execute(&self) -> Result<(), TrackerServicesError> {
+        info!(step = "resume_services", "Starting the stopped services");
+
+        TrackerServices::new(self.ssh_config.clone()).start()?;
+
+        info!(
+            step = "resume_services",
+            status = "success",
+            "Services started"
+        );
+
+        Ok(())
+    }
+}
@@ -55,7 +55,7 @@ impl RunStep {
     /// * Service startup fails
     /// * Container creation fails
     #[instrument(
-        name = "run_application",
+        name = "step.run_application",
         skip_all,
         fields(step_type = "application", operation = "run")
     )]
@@ -71,7 +71,7 @@ impl StartServicesStep {
     /// * Docker Compose services fail to start
     /// * Container health checks fail
     #[instrument(
-        name = "start_services",
+        name = "step.start_services",
         skip_all,
         fields(step_type = "application", operation = "start_services")
     )]
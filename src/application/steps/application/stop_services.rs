@@ -0,0 +1,52 @@
+//! Service stop step
+//!
+//! This module provides the `StopServicesStep` which stops the Docker Compose
+//! services of the deployment for maintenance, keeping the containers and
+//! the deployed configuration.
+//!
+//! ## Usage Context
+//!
+//! This step is the only step of the stop workflow. `ResumeServicesStep`
+//! starts the services again.
+
+use tracing::{info, instrument};
+
+use crate::adapters::ssh::SshConfig;
+use crate::infrastructure::remote_actions::{TrackerServices, TrackerServicesError};
+
+/// Step that stops the Docker Compose services on the instance
+pub struct StopServicesStep {
+    ssh_config: SshConfig,
+}
+
+impl StopServicesStep {
+    /// Create a new `StopServicesStep`
+    #[must_use]
+    pub fn new(ssh_config: SshConfig) -> Self {
+        Self { ssh_config }
+    }
+
+    /// Execute the service stop step
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if `docker compose stop` fails on the instance.
+    #[instrument(
+        name = "step.stop_services",
+        skip_all,
+        fields(step_type = "application", operation = "stop_services")
+    )]
+    pub fn execute(&self) -> Result<(), TrackerServicesError> {
+        info!(step = "stop_services", "Stopping the services");
+
+        TrackerServices::new(self.ssh_config.clone()).stop()?;
+
+        info!(
+            step = "stop_services",
+            status = "success",
+            "Services stopped"
+        );
+
+        Ok(())
+    }
+}
@@ -0,0 +1,135 @@
+//! Tracker health waiting step
+//!
+//! This module provides the `WaitForTrackerHealthStep` which ensures the
+//! tracker reports healthy after the Docker Compose services are started.
+//! Docker starts a tracker whose configuration it cannot load just fine; only
+//! the Health Check API tells whether the tracker actually runs.
+//!
+//! ## Key Features
+//!
+//! - Polls the tracker Health Check API of every replica over SSH
+//! - Interval, timeout and expected status come from the tracker
+//!   configuration (`health_check_api.wait`)
+//! - Reports the last HTTP status or error on failure
+//! - Stops waiting when the cancellation token is cancelled (Ctrl-C)
+//!
+//! ## Usage Context
+//!
+//! This step runs right after `StartServicesStep` in the run workflow.
+
+use std::net::SocketAddr;
+
+use tokio_util::sync::CancellationToken;
+use tracing::{info, instrument};
+
+use crate::adapters::ssh::SshConfig;
+use crate::domain::tracker::HealthCheckWait;
+use crate::infrastructure::remote_actions::{TrackerHealthWaitError, TrackerHealthWaiter};
+
+/// Step that waits for the tracker Health Check API to report healthy
+pub struct WaitForTrackerHealthStep {
+    ssh_config: SshConfig,
+    bind_address: SocketAddr,
+    wait: HealthCheckWait,
+    containers: Vec<String>,
+    cancellation: CancellationToken,
+}
+
+impl WaitForTrackerHealthStep {
+    /// Create a new `WaitForTrackerHealthStep`
+    ///
+    /// # Arguments
+    ///
+    /// * `ssh_config` - SSH connection configuration of the instance
+    /// * `bind_address` - Bind address of the Health Check API
+    /// * `wait` - Interval, timeout and expected status of the checks
+    /// * `containers` - Containers of the tracker replicas
+    #[must_use]
+    pub fn new(
+        ssh_config: SshConfig,
+        bind_address: SocketAddr,
+        wait: HealthCheckWait,
+        containers: Vec<String>,
+    ) -> Self {
+        Self {
+            ssh_config,
+            bind_address,
+            wait,
+            containers,
+            cancellation: CancellationToken::new(),
+        }
+    }
+
+    /// Stop waiting when the given token is cancelled (e.g. on Ctrl-C)
+    #[must_use]
+    pub fn with_cancellation(mut self, cancellation: CancellationToken) -> Self {
+        self.cancellation = cancellation;
+        self
+    }
+
+    /// Execute the tracker health wait step
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the Health Check API does not answer the expected
+    /// status within the timeout, or if the wait is interrupted.
+    #[instrument(
+        name = "step.wait_for_tracker_health",
+        skip_all,
+        fields(step_type = "application", operation = "wait_for_tracker_health")
+    )]
+    pub fn execute(&self) -> Result<(), TrackerHealthWaitError> {
+        info!(
+            step = "wait_for_tracker_health",
+            timeout_secs = self.wait.timeout().as_secs(),
+            expected_status = self.wait.expected_status(),
+            "Waiting for the tracker health check"
+        );
+
+        TrackerHealthWaiter::new(
+            self.ssh_config.clone(),
+            self.bind_address,
+            self.wait,
+            self.containers.clone(),
+        )
+        .with_cancellation(self.cancellation.clone())
+        .wait()?;
+
+        info!(
+            step = "wait_for_tracker_health",
+            status = "success",
+            "Tracker reported healthy"
+        );
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::net::{IpAddr, Ipv4Addr};
+
+    use super::*;
+    use crate::adapters::ssh::SshCredentials;
+    use crate::shared::Username;
+
+    #[test]
+    fn it_should_create_wait_for_tracker_health_step() {
+        let credentials = SshCredentials::new(
+            "/tmp/test_key".into(),
+            "/tmp/test_key.pub".into(),
+            Username::new("testuser").unwrap(),
+        );
+        let ssh_config =
+            SshConfig::with_default_port(credentials, IpAddr::V4(Ipv4Addr::new(10, 0, 0, 1)));
+
+        let step = WaitForTrackerHealthStep::new(
+            ssh_config,
+            "127.0.0.1:1313".parse().unwrap(),
+            HealthCheckWait::default(),
+            vec!["tracker".to_string()],
+        );
+
+        assert_eq!(step.wait, HealthCheckWait::default());
+    }
+}
@@ -0,0 +1,265 @@
+//! SSH port preflight step
+//!
+//! This module provides the `CheckSshPortStep` which checks, right after the
+//! infrastructure is applied, that the instance accepts TCP connections on
+//! the configured SSH port.
+//!
+//! ## Why a Preflight
+//!
+//! Waiting for SSH and cloud-init can take ten minutes before giving up. A
+//! port that the provider or another service already uses, or that a
+//! firewall blocks, never opens, so a plain TCP connect with a short budget
+//! reports it within a minute instead.
+//!
+//! ## Custom SSH Ports
+//!
+//! A fresh instance runs SSH on port 22 until cloud-init moves it to the
+//! configured port and reboots. While the instance still answers on its boot
+//! address the step keeps waiting, up to the boot timeout, and the short
+//! budget restarts once the instance stops answering there.
+
+use std::net::SocketAddr;
+use std::time::Duration;
+
+use thiserror::Error;
+use tokio::net::TcpStream;
+use tokio::time::Instant;
+use tracing::{info, instrument};
+
+use crate::application::traits::CommandProgressListener;
+
+/// Time the SSH port is given to accept connections
+pub const DEFAULT_SSH_PORT_CHECK_TIMEOUT: Duration = Duration::from_secs(60);
+
+/// Pause between two connection attempts
+const RETRY_INTERVAL: Duration = Duration::from_secs(2);
+
+/// Maximum time a single connection attempt may take
+const CONNECT_TIMEOUT: Duration = Duration::from_secs(3);
+
+/// Errors of the SSH port preflight
+#[derive(Debug, Error)]
+pub enum SshPortCheckError {
+    /// The SSH port did not accept a connection in time
+    #[error(
+        "SSH port {address} did not accept a connection within {waited_secs} seconds: {reason}"
+    )]
+    Unreachable {
+        address: SocketAddr,
+        waited_secs: u64,
+        reason: String,
+    },
+}
+
+impl crate::shared::Traceable for SshPortCheckError {
+    fn trace_format(&self) -> String {
+        match self {
+            Self::Unreachable {
+                address,
+                waited_secs,
+                reason,
+            } => {
+                format!("SshPortCheckError: {address} unreachable after {waited_secs} seconds - {reason}")
+            }
+        }
+    }
+
+    fn trace_source(&self) -> Option<&dyn crate::shared::Traceable> {
+        None
+    }
+
+    fn error_kind(&self) -> crate::shared::ErrorKind {
+        crate::shared::ErrorKind::NetworkConnectivity
+    }
+}
+
+/// Step that checks the instance accepts TCP connections on the SSH port
+pub struct CheckSshPortStep {
+    address: SocketAddr,
+    boot_address: Option<SocketAddr>,
+    timeout: Duration,
+    boot_timeout: Duration,
+}
+
+impl CheckSshPortStep {
+    /// Create a new SSH port preflight for `address`
+    #[must_use]
+    pub fn new(address: SocketAddr) -> Self {
+        Self {
+            address,
+            boot_address: None,
+            timeout: DEFAULT_SSH_PORT_CHECK_TIMEOUT,
+            boot_timeout: DEFAULT_SSH_PORT_CHECK_TIMEOUT,
+        }
+    }
+
+    /// Keep waiting, up to `boot_timeout`, while the instance answers on
+    /// `boot_address`
+    ///
+    /// Used for custom SSH ports, which cloud-init only opens after moving
+    /// SSH away from port 22 and rebooting.
+    #[must_use]
+    pub fn with_boot_address(mut self, boot_address: SocketAddr, boot_timeout: Duration) -> Self {
+        self.boot_address = Some(boot_address);
+        self.boot_timeout = boot_timeout;
+        self
+    }
+
+    /// Set the time the SSH port is given to accept connections
+    #[must_use]
+    pub fn with_timeout(mut self, timeout: Duration) -> Self {
+        self.timeout = timeout;
+        self
+    }
+
+    /// Execute the SSH port preflight
+    ///
+    /// # Arguments
+    ///
+    /// * `listener` - Optional progress listener for reporting the target
+    ///   and the outcome
+    ///
+    /// # Errors
+    ///
+    /// Returns `SshPortCheckError::Unreachable` if the port did not accept a
+    /// connection in time.
+    #[instrument(
+        name = "step.check_ssh_port",
+        skip_all,
+        fields(step_type = "connectivity", protocol = "tcp")
+    )]
+    pub async fn execute(
+        &self,
+        listener: Option<&dyn CommandProgressListener>,
+    ) -> Result<(), SshPortCheckError> {
+        info!(
+            step = "check_ssh_port",
+            address = %self.address,
+            "Checking that the SSH port accepts connections"
+        );
+
+        if let Some(l) = listener {
+            l.on_detail(&format!("Connecting to {}", self.address));
+        }
+
+        let start = Instant::now();
+        let boot_deadline = start + self.boot_timeout;
+        let mut deadline = start + self.timeout;
+
+        loop {
+            let reason = match Self::connect(self.address).await {
+                Ok(()) => break,
+                Err(reason) => reason,
+            };
+
+            let now = Instant::now();
+            if let Some(boot_address) = self.boot_address {
+                if now < boot_deadline && Self::connect(boot_address).await.is_ok() {
+                    // Still booting with the default port: the budget starts
+                    // once cloud-init has moved SSH and rebooted
+                    deadline = deadline.max((now + self.timeout).min(boot_deadline));
+                    if let Some(l) = listener {
+                        l.on_debug(&format!(
+                            "Instance answers on {boot_address}, waiting for cloud-init to move SSH to {}",
+                            self.address
+                        ));
+                    }
+                }
+            }
+
+            if now >= deadline {
+                return Err(SshPortCheckError::Unreachable {
+                    address: self.address,
+                    waited_secs: start.elapsed().as_secs(),
+                    reason,
+                });
+            }
+
+            if let Some(l) = listener {
+                l.on_debug(&format!("{} not reachable yet: {reason}", self.address));
+            }
+
+            tokio::time::sleep(RETRY_INTERVAL.min(deadline - now)).await;
+        }
+
+        if let Some(l) = listener {
+            l.on_detail("SSH port accepts connections ✓");
+        }
+
+        info!(
+            step = "check_ssh_port",
+            address = %self.address,
+            status = "success",
+            "SSH port accepts connections"
+        );
+
+        Ok(())
+    }
+
+    /// Open and close one TCP connection, returning why it failed
+    async fn connect(address: SocketAddr) -> Result<(), String> {
+        match tokio::time::timeout(CONNECT_TIMEOUT, TcpStream::connect(address)).await {
+            Ok(Ok(_)) => Ok(()),
+            Ok(Err(e)) => Err(e.to_string()),
+            Err(_) => Err(format!(
+                "connection timed out after {}s",
+                CONNECT_TIMEOUT.as_secs()
+            )),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use tokio::net::TcpListener;
+
+    use super::*;
+
+    async fn closed_port() -> SocketAddr {
+        let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        listener.local_addr().unwrap()
+    }
+
+    #[tokio::test]
+    async fn it_should_succeed_when_the_port_accepts_connections() {
+        let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let step = CheckSshPortStep::new(listener.local_addr().unwrap());
+
+        assert!(step.execute(None).await.is_ok());
+    }
+
+    #[tokio::test]
+    async fn it_should_fail_once_the_timeout_is_exceeded() {
+        let address = closed_port().await;
+        let step = CheckSshPortStep::new(address).with_timeout(Duration::from_millis(100));
+
+        let result = step.execute(None).await;
+
+        let Err(SshPortCheckError::Unreachable {
+            address: failed, ..
+        }) = result
+        else {
+            panic!("Expected Unreachable error, got {result:?}");
+        };
+        assert_eq!(failed, address);
+    }
+
+    #[tokio::test]
+    async fn it_should_keep_waiting_while_the_instance_answers_on_its_boot_address() {
+        let address = closed_port().await;
+        let boot_listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let step = CheckSshPortStep::new(address)
+            .with_timeout(Duration::from_millis(100))
+            .with_boot_address(
+                boot_listener.local_addr().unwrap(),
+                Duration::from_millis(2500),
+            );
+
+        let result = step.execute(None).await;
+
+        let Err(SshPortCheckError::Unreachable { waited_secs, .. }) = result else {
+            panic!("Expected Unreachable error, got {result:?}");
+        };
+        assert!(waited_secs >= 2, "waited only {waited_secs}s");
+    }
+}
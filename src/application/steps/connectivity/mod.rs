@@ -6,6 +6,8 @@
 //!
 //! ## Available Steps
 //!
+//! - `check_ssh_port` - TCP preflight of the SSH port right after provisioning
+//! - `record_host_keys` - SSH host key recording and verification
 //! - `wait_ssh_connectivity` - SSH connectivity establishment and verification
 //!
 //! ## Key Features
@@ -19,6 +21,10 @@
 //! before attempting configuration or deployment operations that require
 //! remote connectivity.
 
+pub mod check_ssh_port;
+pub mod record_host_keys;
 pub mod wait_ssh_connectivity;
 
+pub use check_ssh_port::{CheckSshPortStep, SshPortCheckError, DEFAULT_SSH_PORT_CHECK_TIMEOUT};
+pub use record_host_keys::RecordHostKeysStep;
 pub use wait_ssh_connectivity::WaitForSSHConnectivityStep;
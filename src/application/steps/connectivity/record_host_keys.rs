@@ -0,0 +1,82 @@
+//! SSH host key recording step
+//!
+//! This module provides the `RecordHostKeysStep` which scans the host keys of
+//! an instance and checks them against the `known_hosts` file of its
+//! environment (`data/{env}/ssh/known_hosts`).
+//!
+//! ## Key Features
+//!
+//! - Keys are recorded on first use, so environments provisioned before the
+//!   file existed start checking host keys on their next command
+//! - Changed keys are rotated only right after (re)provisioning
+//! - Any other change fails with `SshError::HostKeyChanged` before anything
+//!   runs on the host
+
+use tracing::{info, instrument, warn};
+
+use crate::adapters::ssh::{HostKeyRecord, KnownHostsFile, SshClient, SshConfig, SshError};
+
+/// Step that records or verifies the SSH host keys of an instance
+pub struct RecordHostKeysStep {
+    ssh_config: SshConfig,
+    known_hosts: KnownHostsFile,
+    allow_rotation: bool,
+}
+
+impl RecordHostKeysStep {
+    /// Create a step that fails when the host keys changed
+    #[must_use]
+    pub fn new(ssh_config: SshConfig, known_hosts: KnownHostsFile) -> Self {
+        Self {
+            ssh_config,
+            known_hosts,
+            allow_rotation: false,
+        }
+    }
+
+    /// Replace changed host keys instead of failing
+    ///
+    /// Only meant for a freshly (re)provisioned instance, whose keys are
+    /// expected to be new.
+    #[must_use]
+    pub fn with_rotation(mut self) -> Self {
+        self.allow_rotation = true;
+        self
+    }
+
+    /// Execute the host key recording step
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if:
+    /// * The host keys changed and rotation is not allowed
+    /// * The host keys cannot be scanned
+    /// * The `known_hosts` file cannot be read or written
+    #[instrument(
+        name = "step.record_host_keys",
+        skip_all,
+        fields(step_type = "connectivity", protocol = "ssh")
+    )]
+    pub fn execute(&self) -> Result<HostKeyRecord, SshError> {
+        let record = SshClient::new(self.ssh_config.clone())
+            .record_host_keys(&self.known_hosts, self.allow_rotation)?;
+
+        if record == HostKeyRecord::Rotated {
+            warn!(
+                step = "record_host_keys",
+                instance_ip = %self.ssh_config.host_ip(),
+                known_hosts = %self.known_hosts.path().display(),
+                "SSH host keys changed after provisioning, the new keys replace the recorded ones"
+            );
+        } else {
+            info!(
+                step = "record_host_keys",
+                instance_ip = %self.ssh_config.host_ip(),
+                record = ?record,
+                "SSH host keys checked"
+            );
+        }
+
+        Ok(record)
+    }
+}
@@ -50,7 +50,7 @@ impl WaitForSSHConnectivityStep {
     /// * The SSH client fails to initialize
     /// * The SSH command execution fails
     #[instrument(
-        name = "wait_ssh_connectivity",
+        name = "step.wait_ssh_connectivity",
         skip_all,
         fields(step_type = "connectivity", protocol = "ssh")
     )]
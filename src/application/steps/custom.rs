@@ -0,0 +1,440 @@
+//! Custom steps injected into the built-in workflows
+//!
+//! SDK users extend the provision and configure workflows with their own
+//! steps (e.g. registering a DNS record once the instance has an IP) instead
+//! of wrapping the deployer calls. A custom step implements [`Step`] and is
+//! registered for a [`HookPoint`] in a [`CustomSteps`] registry, which the
+//! command handlers run at that point of their workflow.
+//!
+//! A failing custom step fails the whole command like a built-in step: the
+//! environment is persisted in the failed state of the command, with
+//! `custom:{step name}` as failed step and the [`StepError`] message in the
+//! error summary.
+//!
+//! # Example
+//!
+//! ```rust
+//! use torrust_tracker_deployer_lib::application::steps::{
+//!     CustomSteps, HookPoint, Step, StepContext, StepError, StepFuture, StepOutcome,
+//! };
+//!
+//! struct RegisterDns;
+//!
+//! impl Step for RegisterDns {
+//!     fn name(&self) -> &str {
+//!         "register-dns"
+//!     }
+//!
+//!     fn execute<'a>(&'a self, context: &'a StepContext) -> StepFuture<'a> {
+//!         Box::pin(async move {
+//!             let Some(ip) = context.instance_ip() else {
+//!                 return Err(StepError::new("the instance has no IP address"));
+//!             };
+//!             println!("{} -> {ip}", context.environment_name());
+//!             Ok(StepOutcome::Completed)
+//!         })
+//!     }
+//! }
+//!
+//! let mut steps = CustomSteps::new();
+//! steps.register(HookPoint::AfterProvision, RegisterDns);
+//! assert_eq!(steps.len(), 1);
+//! ```
+
+use std::fmt;
+use std::future::Future;
+use std::net::IpAddr;
+use std::pin::Pin;
+use std::sync::Arc;
+
+use thiserror::Error;
+use tracing::info;
+
+use crate::domain::environment::state::AnyEnvironmentState;
+use crate::domain::EnvironmentName;
+
+/// Future returned by [`Step::execute`]
+pub type StepFuture<'a> = Pin<Box<dyn Future<Output = Result<StepOutcome, StepError>> + Send + 'a>>;
+
+/// A unit of work run at a [`HookPoint`] of a built-in workflow
+///
+/// `execute` returns a boxed future so that steps can be stored as trait
+/// objects; implementations wrap their body in `Box::pin(async move { .. })`.
+pub trait Step: Send + Sync {
+    /// Name of the step, recorded as failed step `custom:{name}` when it fails
+    fn name(&self) -> &str;
+
+    /// Run the step
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`StepError`] to fail the command running the step
+    fn execute<'a>(&'a self, context: &'a StepContext) -> StepFuture<'a>;
+}
+
+/// Points of the built-in workflows where custom steps run
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum HookPoint {
+    /// After the instance is reachable, before the environment is saved as `Provisioned`
+    AfterProvision,
+    /// Before the first configuration playbook runs
+    BeforeConfigure,
+    /// After the last configuration playbook, before the environment is saved as `Configured`
+    AfterConfigure,
+}
+
+impl fmt::Display for HookPoint {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let name = match self {
+            Self::AfterProvision => "after_provision",
+            Self::BeforeConfigure => "before_configure",
+            Self::AfterConfigure => "after_configure",
+        };
+        f.write_str(name)
+    }
+}
+
+/// What a custom step sees of the running workflow
+pub struct StepContext {
+    environment: AnyEnvironmentState,
+    hook: HookPoint,
+}
+
+impl StepContext {
+    /// Create the context for the steps of `hook`
+    #[must_use]
+    pub fn new(environment: AnyEnvironmentState, hook: HookPoint) -> Self {
+        Self { environment, hook }
+    }
+
+    /// The environment as it is at the hook point
+    ///
+    /// Match on the state (e.g. `AnyEnvironmentState::Provisioned`) for typed
+    /// access to the state-specific data.
+    #[must_use]
+    pub fn environment(&self) -> &AnyEnvironmentState {
+        &self.environment
+    }
+
+    /// Name of the environment
+    #[must_use]
+    pub fn environment_name(&self) -> &EnvironmentName {
+        self.environment.name()
+    }
+
+    /// IP address of the instance, once provisioned
+    #[must_use]
+    pub fn instance_ip(&self) -> Option<IpAddr> {
+        self.environment.instance_ip()
+    }
+
+    /// The hook point running the step
+    #[must_use]
+    pub fn hook(&self) -> HookPoint {
+        self.hook
+    }
+}
+
+/// Successful result of a custom step
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum StepOutcome {
+    /// The step did its work
+    Completed,
+    /// The step had nothing to do
+    Skipped {
+        /// Why the step was skipped
+        reason: String,
+    },
+}
+
+/// Error returned by a failing custom step
+#[derive(Debug, Error)]
+#[error("{message}")]
+pub struct StepError {
+    message: String,
+    #[source]
+    source: Option<Box<dyn std::error::Error + Send + Sync>>,
+}
+
+impl StepError {
+    /// Create an error with the given message
+    #[must_use]
+    pub fn new(message: impl Into<String>) -> Self {
+        Self {
+            message: message.into(),
+            source: None,
+        }
+    }
+
+    /// Attach the error that caused the failure
+    #[must_use]
+    pub fn with_source(mut self, source: impl std::error::Error + Send + Sync + 'static) -> Self {
+        self.source = Some(Box::new(source));
+        self
+    }
+}
+
+/// A custom step that failed, with the name of the step
+#[derive(Debug, Error)]
+#[error("Custom step '{step}' failed at {hook}: {source}")]
+pub struct CustomStepFailure {
+    /// Name of the failing step
+    pub step: String,
+    /// Hook point the step was registered for
+    pub hook: HookPoint,
+    /// Error returned by the step
+    #[source]
+    pub source: StepError,
+}
+
+/// Custom steps registered for each hook point
+///
+/// Steps of the same hook point run in registration order; the first
+/// failure stops the remaining ones.
+#[derive(Clone, Default)]
+pub struct CustomSteps {
+    steps: Vec<(HookPoint, Arc<dyn Step>)>,
+}
+
+impl CustomSteps {
+    /// Create an empty registry
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Run `step` at `hook`, after the steps already registered for it
+    pub fn register(&mut self, hook: HookPoint, step: impl Step + 'static) {
+        self.steps.push((hook, Arc::new(step)));
+    }
+
+    /// Number of registered steps, all hook points included
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.steps.len()
+    }
+
+    /// Whether no step is registered
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.steps.is_empty()
+    }
+
+    /// Whether a step is registered for `hook`
+    #[must_use]
+    pub fn has_steps_for(&self, hook: HookPoint) -> bool {
+        self.steps.iter().any(|(h, _)| *h == hook)
+    }
+
+    /// Run the steps registered for the hook of `context`
+    ///
+    /// # Errors
+    ///
+    /// Returns the first failing step with its error
+    pub async fn run(&self, context: &StepContext) -> Result<(), CustomStepFailure> {
+        let hook = context.hook();
+
+        for (_, step) in self.steps.iter().filter(|(h, _)| *h == hook) {
+            let outcome = step
+                .execute(context)
+                .await
+                .map_err(|source| CustomStepFailure {
+                    step: step.name().to_string(),
+                    hook,
+                    source,
+                })?;
+
+            info!(
+                hook = %hook,
+                step = step.name(),
+                environment = %context.environment_name(),
+                outcome = ?outcome,
+                "Custom step finished"
+            );
+        }
+
+        Ok(())
+    }
+
+    /// Run the steps of [`Self::run`] from synchronous code
+    ///
+    /// The steps run on a dedicated thread with its own runtime, so this can
+    /// be called both outside and inside a Tokio runtime.
+    ///
+    /// # Errors
+    ///
+    /// Returns the first failing step with its error. A step that panics is
+    /// reported as failed.
+    pub fn run_blocking(&self, context: &StepContext) -> Result<(), CustomStepFailure> {
+        if !self.has_steps_for(context.hook()) {
+            return Ok(());
+        }
+
+        let failure = |source: StepError| CustomStepFailure {
+            step: "runtime".to_string(),
+            hook: context.hook(),
+            source,
+        };
+
+        std::thread::scope(|scope| {
+            scope
+                .spawn(|| {
+                    let runtime = tokio::runtime::Builder::new_current_thread()
+                        .enable_all()
+                        .build()
+                        .map_err(|e| {
+                            failure(
+                                StepError::new("cannot start the runtime of the custom steps")
+                                    .with_source(e),
+                            )
+                        })?;
+                    runtime.block_on(self.run(context))
+                })
+                .join()
+                .unwrap_or_else(|_| Err(failure(StepError::new("a custom step panicked"))))
+        })
+    }
+}
+
+impl fmt::Debug for CustomSteps {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_list()
+            .entries(
+                self.steps
+                    .iter()
+                    .map(|(hook, step)| format!("{hook}:{}", step.name())),
+            )
+            .finish()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::path::PathBuf;
+    use std::sync::Mutex;
+
+    use super::*;
+    use crate::domain::environment::testing::EnvironmentTestBuilder;
+
+    /// Records the hooks it ran at, then fails if asked to
+    struct RecordingStep {
+        name: &'static str,
+        calls: Arc<Mutex<Vec<String>>>,
+        fail: bool,
+    }
+
+    impl Step for RecordingStep {
+        fn name(&self) -> &str {
+            self.name
+        }
+
+        fn execute<'a>(&'a self, context: &'a StepContext) -> StepFuture<'a> {
+            Box::pin(async move {
+                self.calls
+                    .lock()
+                    .unwrap()
+                    .push(format!("{}:{}", context.hook(), self.name));
+                if self.fail {
+                    return Err(StepError::new("DNS API unreachable"));
+                }
+                Ok(StepOutcome::Completed)
+            })
+        }
+    }
+
+    struct MarkerStep {
+        path: PathBuf,
+    }
+
+    impl Step for MarkerStep {
+        fn name(&self) -> &str {
+            "write-marker"
+        }
+
+        fn execute<'a>(&'a self, context: &'a StepContext) -> StepFuture<'a> {
+            Box::pin(async move {
+                tokio::fs::write(&self.path, context.environment_name().as_str())
+                    .await
+                    .map_err(|e| StepError::new("cannot write the marker").with_source(e))?;
+                Ok(StepOutcome::Completed)
+            })
+        }
+    }
+
+    fn context(hook: HookPoint) -> (StepContext, tempfile::TempDir) {
+        let (environment, _data_dir, _build_dir, temp_dir) = EnvironmentTestBuilder::new()
+            .with_name("hooks")
+            .build_with_custom_paths();
+        (StepContext::new(environment.into_any(), hook), temp_dir)
+    }
+
+    fn recording(name: &'static str, calls: &Arc<Mutex<Vec<String>>>, fail: bool) -> RecordingStep {
+        RecordingStep {
+            name,
+            calls: Arc::clone(calls),
+            fail,
+        }
+    }
+
+    #[tokio::test]
+    async fn it_should_run_only_the_steps_of_the_hook_in_registration_order() {
+        let calls = Arc::new(Mutex::new(Vec::new()));
+        let mut steps = CustomSteps::new();
+        steps.register(HookPoint::AfterProvision, recording("first", &calls, false));
+        steps.register(
+            HookPoint::BeforeConfigure,
+            recording("other", &calls, false),
+        );
+        steps.register(
+            HookPoint::AfterProvision,
+            recording("second", &calls, false),
+        );
+        let (context, _temp_dir) = context(HookPoint::AfterProvision);
+
+        steps.run(&context).await.unwrap();
+
+        assert_eq!(
+            *calls.lock().unwrap(),
+            ["after_provision:first", "after_provision:second"]
+        );
+    }
+
+    #[tokio::test]
+    async fn it_should_stop_at_the_first_failing_step() {
+        let calls = Arc::new(Mutex::new(Vec::new()));
+        let mut steps = CustomSteps::new();
+        steps.register(
+            HookPoint::AfterProvision,
+            recording("register-dns", &calls, true),
+        );
+        steps.register(
+            HookPoint::AfterProvision,
+            recording("notify", &calls, false),
+        );
+        let (context, _temp_dir) = context(HookPoint::AfterProvision);
+
+        let failure = steps.run(&context).await.unwrap_err();
+
+        assert_eq!(failure.step, "register-dns");
+        assert_eq!(failure.hook, HookPoint::AfterProvision);
+        assert_eq!(failure.source.to_string(), "DNS API unreachable");
+        assert_eq!(*calls.lock().unwrap(), ["after_provision:register-dns"]);
+    }
+
+    #[tokio::test]
+    async fn it_should_run_async_steps_from_synchronous_code_inside_a_runtime() {
+        let (context, temp_dir) = context(HookPoint::AfterConfigure);
+        let marker = temp_dir.path().join("marker");
+        let mut steps = CustomSteps::new();
+        steps.register(
+            HookPoint::AfterConfigure,
+            MarkerStep {
+                path: marker.clone(),
+            },
+        );
+
+        steps.run_blocking(&context).unwrap();
+
+        assert_eq!(std::fs::read_to_string(marker).unwrap(), "hooks");
+    }
+}
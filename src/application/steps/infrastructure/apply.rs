@@ -26,7 +26,7 @@ use std::sync::Arc;
 use tracing::{info, instrument};
 
 use crate::adapters::tofu::client::OpenTofuClient;
-use crate::application::traits::CommandProgressListener;
+use crate::application::traits::{forward_output_lines, CommandProgressListener};
 use crate::shared::command::CommandError;
 
 /// Simple step that applies `OpenTofu` configuration by executing `tofu apply`
@@ -63,7 +63,7 @@ impl ApplyInfrastructureStep {
     /// * The working directory does not exist or is not accessible
     /// * The `OpenTofu` command execution fails
     #[instrument(
-        name = "apply_infrastructure",
+        name = "step.tofu_apply",
         skip_all,
         fields(
             step_type = "infrastructure",
@@ -97,9 +97,11 @@ impl ApplyInfrastructureStep {
         }
 
         // Execute tofu apply command with variables file
-        let output = self
-            .opentofu_client
-            .apply(self.auto_approve, &["-var-file=variables.tfvars"])?;
+        let output = self.opentofu_client.apply(
+            self.auto_approve,
+            &["-var-file=variables.tfvars"],
+            &forward_output_lines(listener, "tofu"),
+        )?;
 
         // Report apply completion details if listener is provided
         if let Some(l) = listener {
@@ -0,0 +1,180 @@
+//! LXD remote preflight step
+//!
+//! This module provides the `CheckLxdRemoteStep` which checks, before any
+//! infrastructure is planned, that the LXD server the instance is created on
+//! is reachable and trusts this machine.
+//!
+//! ## Why a Preflight
+//!
+//! `OpenTofu` only reports an unknown or untrusted remote after initializing
+//! the provider, with an error that does not say how to fix it. Checking the
+//! remote first turns that into one clear failure, before anything is
+//! created.
+//!
+//! ## Adding the Remote
+//!
+//! A remote that is not configured in the LXD client yet is added with its
+//! trust token (`lxc remote add <name> <token>`), which makes the server trust
+//! the client certificate. Without a token the user has to add it by hand.
+
+use std::time::Duration;
+
+use thiserror::Error;
+use tracing::{info, instrument};
+
+use crate::adapters::lxd::LxdClient;
+use crate::application::traits::CommandProgressListener;
+use crate::domain::provider::LxdRemote;
+
+/// Time the LXD server is given to answer
+pub const DEFAULT_LXD_REMOTE_CHECK_TIMEOUT: Duration = Duration::from_secs(30);
+
+/// Errors of the LXD remote preflight
+#[derive(Debug, Error)]
+pub enum LxdRemoteCheckError {
+    /// The remote is not configured in the LXD client and cannot be added
+    #[error("LXD remote '{remote}' is not configured in the LXD client and no trust token was given to add it")]
+    NotConfigured { remote: String },
+
+    /// Adding the remote with its trust token failed
+    #[error("Failed to add LXD remote '{remote}': {reason}")]
+    AddFailed { remote: String, reason: String },
+
+    /// The server did not answer, or does not trust this client
+    #[error("LXD remote '{remote}' is not usable: {reason}")]
+    Unavailable { remote: String, reason: String },
+}
+
+impl crate::shared::Traceable for LxdRemoteCheckError {
+    fn trace_format(&self) -> String {
+        match self {
+            Self::NotConfigured { remote } => {
+                format!("LxdRemoteCheckError: remote '{remote}' not configured")
+            }
+            Self::AddFailed { remote, reason } => {
+                format!("LxdRemoteCheckError: adding remote '{remote}' failed - {reason}")
+            }
+            Self::Unavailable { remote, reason } => {
+                format!("LxdRemoteCheckError: remote '{remote}' unavailable - {reason}")
+            }
+        }
+    }
+
+    fn trace_source(&self) -> Option<&dyn crate::shared::Traceable> {
+        None
+    }
+
+    fn error_kind(&self) -> crate::shared::ErrorKind {
+        match self {
+            Self::NotConfigured { .. } => crate::shared::ErrorKind::Configuration,
+            Self::AddFailed { .. } | Self::Unavailable { .. } => {
+                crate::shared::ErrorKind::NetworkConnectivity
+            }
+        }
+    }
+}
+
+/// Step that checks the LXD remote is configured, reachable and trusted
+pub struct CheckLxdRemoteStep {
+    remote: LxdRemote,
+    timeout: Duration,
+}
+
+impl CheckLxdRemoteStep {
+    /// Create a new preflight for `remote`
+    #[must_use]
+    pub fn new(remote: LxdRemote) -> Self {
+        Self {
+            remote,
+            timeout: DEFAULT_LXD_REMOTE_CHECK_TIMEOUT,
+        }
+    }
+
+    /// Execute the LXD remote preflight
+    ///
+    /// # Arguments
+    ///
+    /// * `listener` - Optional progress listener for reporting the remote
+    ///   and the outcome
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the remote is not configured and has no trust
+    /// token, if adding it fails, or if the server does not answer or does
+    /// not trust this client.
+    #[instrument(
+        name = "step.check_lxd_remote",
+        skip_all,
+        fields(step_type = "infrastructure", operation = "check_remote")
+    )]
+    pub fn execute(
+        &self,
+        listener: Option<&dyn CommandProgressListener>,
+    ) -> Result<(), LxdRemoteCheckError> {
+        let name = self.remote.name().to_string();
+
+        info!(
+            step = "check_lxd_remote",
+            remote = %name,
+            "Checking the LXD remote"
+        );
+
+        if let Some(l) = listener {
+            l.on_detail(&format!("Checking LXD remote '{name}'"));
+        }
+
+        let mut client = LxdClient::new().with_remote(name.clone());
+        if let Some(config_dir) = self.remote.config_dir() {
+            client = client.with_config_dir(config_dir);
+        }
+
+        let configured = client
+            .list_remote_names()
+            .map_err(|e| LxdRemoteCheckError::Unavailable {
+                remote: name.clone(),
+                reason: format!("{e:#}"),
+            })?
+            .contains(&name);
+
+        if !configured {
+            let Some(trust_token) = self.remote.trust_token() else {
+                return Err(LxdRemoteCheckError::NotConfigured { remote: name });
+            };
+
+            if let Some(l) = listener {
+                l.on_detail(&format!("Adding LXD remote '{name}' with its trust token"));
+            }
+
+            client
+                .add_remote(trust_token.expose_secret(), self.timeout)
+                .map_err(|e| LxdRemoteCheckError::AddFailed {
+                    remote: name.clone(),
+                    reason: format!("{e:#}"),
+                })?;
+        }
+
+        let version =
+            client
+                .server_version(self.timeout)
+                .map_err(|e| LxdRemoteCheckError::Unavailable {
+                    remote: name.clone(),
+                    reason: format!("{e:#}"),
+                })?;
+
+        if let Some(l) = listener {
+            l.on_detail(&format!(
+                "LXD remote '{name}' trusts this client (LXD {version}) ✓"
+            ));
+        }
+
+        info!(
+            step = "check_lxd_remote",
+            remote = %name,
+            version = %version,
+            status = "success",
+            "LXD remote is reachable and trusted"
+        );
+
+        Ok(())
+    }
+}
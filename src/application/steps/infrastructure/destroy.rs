@@ -8,6 +8,7 @@
 //!
 //! - Infrastructure teardown and resource destruction
 //! - Configurable auto-approval for automation scenarios
+//! - Optional state refresh to forget resources deleted outside `OpenTofu`
 //! - Progress tracking and status reporting
 //! - Integration with `OpenTofuClient` for command execution
 //!
@@ -32,6 +33,7 @@ use crate::shared::command::CommandError;
 pub struct DestroyInfrastructureStep {
     opentofu_client: Arc<OpenTofuClient>,
     auto_approve: bool,
+    refresh: bool,
 }
 
 impl DestroyInfrastructureStep {
@@ -40,6 +42,7 @@ impl DestroyInfrastructureStep {
         Self {
             opentofu_client,
             auto_approve: true, // Default to auto-approve for automation
+            refresh: false,
         }
     }
 
@@ -49,21 +52,30 @@ impl DestroyInfrastructureStep {
         self
     }
 
+    /// Run `tofu refresh` before destroying, so resources deleted outside
+    /// `OpenTofu` are dropped from the state first
+    #[must_use]
+    pub fn with_refresh(mut self, refresh: bool) -> Self {
+        self.refresh = refresh;
+        self
+    }
+
     /// Execute the `OpenTofu` destroy step
     ///
     /// # Errors
     ///
     /// Returns an error if:
-    /// * The `OpenTofu` destroy fails
+    /// * The `OpenTofu` refresh or destroy fails
     /// * The working directory does not exist or is not accessible
     /// * The `OpenTofu` command execution fails
     #[instrument(
-        name = "destroy_infrastructure",
+        name = "step.tofu_destroy",
         skip_all,
         fields(
             step_type = "infrastructure",
             operation = "destroy",
-            auto_approve = %self.auto_approve
+            auto_approve = %self.auto_approve,
+            refresh = %self.refresh
         )
     )]
     pub fn execute(&self) -> Result<(), CommandError> {
@@ -73,10 +85,21 @@ impl DestroyInfrastructureStep {
             "Destroying OpenTofu infrastructure"
         );
 
+        if self.refresh {
+            info!(
+                step = "destroy_infrastructure",
+                "Refreshing OpenTofu state before destroying"
+            );
+            self.opentofu_client
+                .refresh(&["-var-file=variables.tfvars"], &|_, _| {})?;
+        }
+
         // Execute tofu destroy command with variables file
-        let output = self
-            .opentofu_client
-            .destroy(self.auto_approve, &["-var-file=variables.tfvars"])?;
+        let output = self.opentofu_client.destroy(
+            self.auto_approve,
+            &["-var-file=variables.tfvars"],
+            &|_, _| {},
+        )?;
 
         info!(
             step = "destroy_infrastructure",
@@ -125,4 +148,13 @@ mod tests {
 
         assert!(step.auto_approve);
     }
+
+    #[test]
+    fn it_should_not_refresh_the_state_by_default() {
+        let opentofu_client = Arc::new(OpenTofuClient::new("/tmp"));
+
+        let step = DestroyInfrastructureStep::new(opentofu_client);
+
+        assert!(!step.refresh);
+    }
 }
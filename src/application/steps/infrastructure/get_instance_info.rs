@@ -21,12 +21,20 @@
 //!
 //! This provides a consistent interface for accessing instance information
 //! regardless of the underlying infrastructure provider.
+//!
+//! ## DHCP Lease Lookup
+//!
+//! The libvirt provider only knows the address of a domain once its DHCP
+//! lease made it into the state. When the outputs have no address, the step
+//! created with `with_lease_lookup` reads the lease with `virsh` instead.
 
 use std::sync::Arc;
 
-use tracing::{info, instrument};
+use tracing::{info, instrument, warn};
 
+use crate::adapters::libvirt::VirshClient;
 use crate::adapters::tofu::client::{InstanceInfo, OpenTofuClient, OpenTofuError};
+use crate::adapters::tofu::ParseError;
 use crate::application::traits::CommandProgressListener;
 
 /// Simple step that retrieves instance information from `OpenTofu` outputs
@@ -36,12 +44,23 @@ use crate::application::traits::CommandProgressListener;
 /// the `OpenTofu` output provides a contract that always returns the expected instance info.
 pub struct GetInstanceInfoStep {
     opentofu_client: Arc<OpenTofuClient>,
+    lease_lookup: Option<VirshClient>,
 }
 
 impl GetInstanceInfoStep {
     #[must_use]
     pub fn new(opentofu_client: Arc<OpenTofuClient>) -> Self {
-        Self { opentofu_client }
+        Self {
+            opentofu_client,
+            lease_lookup: None,
+        }
+    }
+
+    /// Read the DHCP lease of the libvirt domain when the outputs have no address
+    #[must_use]
+    pub fn with_lease_lookup(mut self, virsh_client: VirshClient) -> Self {
+        self.lease_lookup = Some(virsh_client);
+        self
     }
 
     /// Execute the get instance info step
@@ -58,7 +77,7 @@ impl GetInstanceInfoStep {
     /// * The `instance_info` section is missing or malformed
     /// * The working directory does not exist or is not accessible
     #[instrument(
-        name = "get_instance_info",
+        name = "step.tofu_output",
         skip_all,
         fields(step_type = "infrastructure", operation = "info")
     )]
@@ -86,20 +105,31 @@ impl GetInstanceInfoStep {
         //   2. Use OpenTofu for all of them, so the OpenTofu output has a contract with this app.
         //      It has to return always the instance info we expect.
         // Using OpenTofu outputs provides a consistent interface across all providers.
-        let opentofu_instance_info = self.opentofu_client.get_instance_info()?;
+        let opentofu_instance_info = match self.opentofu_client.get_instance_info() {
+            Err(OpenTofuError::ParseError(ParseError::NoIpAddress { instance }))
+                if self.lease_lookup.is_some() =>
+            {
+                self.complete_from_lease(*instance, listener)?
+            }
+            result => result?,
+        };
+
+        let ip_addresses = opentofu_instance_info
+            .ip_addresses()
+            .iter()
+            .map(ToString::to_string)
+            .collect::<Vec<_>>()
+            .join(", ");
 
         if let Some(l) = listener {
             l.on_debug(&format!("Instance name: {}", opentofu_instance_info.name));
-            l.on_detail(&format!(
-                "Instance IP: {}",
-                opentofu_instance_info.ip_address
-            ));
+            l.on_detail(&format!("Instance IP: {ip_addresses}"));
         }
 
         info!(
             step = "get_instance_info",
             status = "success",
-            ip_address = %opentofu_instance_info.ip_address,
+            ip_addresses = %ip_addresses,
             instance_name = %opentofu_instance_info.name,
             "Instance information retrieved successfully from OpenTofu outputs"
         );
@@ -109,6 +139,60 @@ impl GetInstanceInfoStep {
 
         Ok(opentofu_instance_info)
     }
+
+    /// Fill in the IPv4 address of `instance` from its libvirt DHCP lease
+    ///
+    /// # Errors
+    ///
+    /// Returns the original `NoIpAddress` error when `virsh` fails or the
+    /// domain has no lease either.
+    fn complete_from_lease(
+        &self,
+        mut instance: InstanceInfo,
+        listener: Option<&dyn CommandProgressListener>,
+    ) -> Result<InstanceInfo, OpenTofuError> {
+        let Some(virsh_client) = &self.lease_lookup else {
+            return Err(ParseError::NoIpAddress {
+                instance: Box::new(instance),
+            }
+            .into());
+        };
+
+        if let Some(l) = listener {
+            l.on_debug(&format!(
+                "Executing: virsh --connect {} domifaddr {} --source lease",
+                virsh_client.uri(),
+                instance.name
+            ));
+        }
+
+        match virsh_client.domain_ipv4_address(&instance.name) {
+            Ok(Some(ipv4_address)) => {
+                info!(
+                    step = "get_instance_info",
+                    ip_address = %ipv4_address,
+                    "Instance address read from the libvirt DHCP lease"
+                );
+                instance.ipv4_address = Some(ipv4_address);
+                Ok(instance)
+            }
+            Ok(None) => Err(ParseError::NoIpAddress {
+                instance: Box::new(instance),
+            }
+            .into()),
+            Err(e) => {
+                warn!(
+                    step = "get_instance_info",
+                    error = %format!("{e:#}"),
+                    "Cannot read the libvirt DHCP lease"
+                );
+                Err(ParseError::NoIpAddress {
+                    instance: Box::new(instance),
+                }
+                .into())
+            }
+        }
+    }
 }
 
 #[cfg(test)]
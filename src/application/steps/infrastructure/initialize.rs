@@ -26,7 +26,7 @@ use std::sync::Arc;
 use tracing::{info, instrument};
 
 use crate::adapters::tofu::client::OpenTofuClient;
-use crate::application::traits::CommandProgressListener;
+use crate::application::traits::{forward_output_lines, CommandProgressListener};
 use crate::shared::command::CommandError;
 
 /// Simple step that initializes `OpenTofu` configuration by executing `tofu init`
@@ -53,7 +53,7 @@ impl InitializeInfrastructureStep {
     /// * The working directory does not exist or is not accessible
     /// * The `OpenTofu` command execution fails
     #[instrument(
-        name = "initialize_infrastructure",
+        name = "step.tofu_init",
         skip_all,
         fields(step_type = "infrastructure", operation = "init")
     )]
@@ -75,7 +75,9 @@ impl InitializeInfrastructureStep {
         }
 
         // Execute tofu init command
-        let output = self.opentofu_client.init()?;
+        let output = self
+            .opentofu_client
+            .init(&forward_output_lines(listener, "tofu"))?;
 
         if let Some(l) = listener {
             l.on_debug("Command completed successfully");
@@ -7,6 +7,7 @@
 //!
 //! ## Available Steps
 //!
+//! - `check_lxd_remote` - Preflight of the LXD server the instance is created on
 //! - `initialize` - `OpenTofu` initialization (tofu init)
 //! - `plan` - Infrastructure planning and change preview (tofu plan)
 //! - `apply` - Infrastructure provisioning and application (tofu apply)
@@ -24,6 +25,7 @@
 //! provisioning, destroying, and managing deployment environments.
 
 pub mod apply;
+pub mod check_lxd_remote;
 pub mod destroy;
 pub mod get_instance_info;
 pub mod initialize;
@@ -31,6 +33,7 @@ pub mod plan;
 pub mod validate;
 
 pub use apply::ApplyInfrastructureStep;
+pub use check_lxd_remote::{CheckLxdRemoteStep, LxdRemoteCheckError};
 pub use destroy::DestroyInfrastructureStep;
 pub use get_instance_info::GetInstanceInfoStep;
 pub use initialize::InitializeInfrastructureStep;
@@ -26,18 +26,57 @@ use std::sync::Arc;
 use tracing::{info, instrument};
 
 use crate::adapters::tofu::client::OpenTofuClient;
-use crate::application::traits::CommandProgressListener;
+use crate::adapters::tofu::PlannedChange;
+use crate::application::traits::{forward_output_lines, CommandProgressListener};
 use crate::shared::command::CommandError;
 
 /// Simple step that plans `OpenTofu` configuration by executing `tofu plan`
 pub struct PlanInfrastructureStep {
     opentofu_client: Arc<OpenTofuClient>,
+    destroy: bool,
 }
 
 impl PlanInfrastructureStep {
     #[must_use]
     pub fn new(opentofu_client: Arc<OpenTofuClient>) -> Self {
-        Self { opentofu_client }
+        Self {
+            opentofu_client,
+            destroy: false,
+        }
+    }
+
+    /// Plan the destruction of all managed resources (`tofu plan -destroy`)
+    ///
+    /// Only affects [`Self::preview`].
+    #[must_use]
+    pub fn for_destroy(mut self) -> Self {
+        self.destroy = true;
+        self
+    }
+
+    /// List the resource changes of the plan without applying anything
+    ///
+    /// Used by dry runs. The plan is not saved, so nothing can be applied from it.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the `OpenTofu` plan fails.
+    #[instrument(
+        name = "step.tofu_plan_preview",
+        skip_all,
+        fields(step_type = "infrastructure", operation = "plan", destroy = self.destroy)
+    )]
+    pub fn preview(&self) -> Result<Vec<PlannedChange>, CommandError> {
+        let mut args = vec!["-no-color", "-var-file=variables.tfvars"];
+        if self.destroy {
+            args.push("-destroy");
+        }
+
+        let output = self.opentofu_client.plan(&args, &|_, _| {})?;
+
+        tracing::debug!(output = %output, "OpenTofu plan output");
+
+        Ok(PlannedChange::parse_plan(&output))
     }
 
     /// Execute the `OpenTofu` plan step
@@ -53,7 +92,7 @@ impl PlanInfrastructureStep {
     /// * The working directory does not exist or is not accessible
     /// * The `OpenTofu` command execution fails
     #[instrument(
-        name = "plan_infrastructure",
+        name = "step.tofu_plan",
         skip_all,
         fields(step_type = "infrastructure", operation = "plan")
     )]
@@ -75,7 +114,10 @@ impl PlanInfrastructureStep {
         }
 
         // Execute tofu plan command with variables file
-        let output = self.opentofu_client.plan(&["-var-file=variables.tfvars"])?;
+        let output = self.opentofu_client.plan(
+            &["-var-file=variables.tfvars"],
+            &forward_output_lines(listener, "tofu"),
+        )?;
 
         // Extract and report plan summary if listener is provided
         if let Some(l) = listener {
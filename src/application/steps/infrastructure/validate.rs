@@ -55,7 +55,7 @@ impl ValidateInfrastructureStep {
     /// * The `OpenTofu` command execution fails
     /// * The configuration is not initialized (providers not installed)
     #[instrument(
-        name = "validate_infrastructure",
+        name = "step.tofu_validate",
         skip_all,
         fields(step_type = "infrastructure", operation = "validate")
     )]
@@ -12,6 +12,7 @@
  * - software/      - Software installation and management  
  * - application/   - Application deployment and lifecycle
  * - connectivity/  - Network and connection operations
+ * - custom         - Steps injected by SDK users at workflow hook points
  * - validation/    - Testing and validation operations
  *
  * This organization supports the full planned command ecosystem while enabling
@@ -20,6 +21,7 @@
 
 pub mod application;
 pub mod connectivity;
+pub mod custom;
 pub mod infrastructure;
 pub mod rendering;
 pub mod software;
@@ -28,10 +30,17 @@ pub mod validation;
 
 // Re-export all steps for easy access
 pub use application::{DeployComposeFilesStep, DeployComposeFilesStepError, RunStep, RunStepError};
-pub use connectivity::WaitForSSHConnectivityStep;
+pub use connectivity::{
+    CheckSshPortStep, RecordHostKeysStep, SshPortCheckError, WaitForSSHConnectivityStep,
+};
+pub use custom::{
+    CustomStepFailure, CustomSteps, HookPoint, Step, StepContext, StepError, StepFuture,
+    StepOutcome,
+};
 pub use infrastructure::{
-    ApplyInfrastructureStep, DestroyInfrastructureStep, GetInstanceInfoStep,
-    InitializeInfrastructureStep, PlanInfrastructureStep, ValidateInfrastructureStep,
+    ApplyInfrastructureStep, CheckLxdRemoteStep, DestroyInfrastructureStep, GetInstanceInfoStep,
+    InitializeInfrastructureStep, LxdRemoteCheckError, PlanInfrastructureStep,
+    ValidateInfrastructureStep,
 };
 pub use rendering::{
     ansible_templates::RenderAnsibleTemplatesError, RenderAnsibleTemplatesStep,
@@ -39,10 +48,11 @@ pub use rendering::{
 };
 pub use software::{InstallDockerComposeStep, InstallDockerStep};
 pub use system::{
-    ConfigureFirewallStep, ConfigureSecurityUpdatesStep, InstallBackupCrontabStep,
-    WaitForCloudInitStep,
+    ConfigureFirewallStep, ConfigureSecurityUpdatesStep, ConfigureSystemTuningStep,
+    InstallBackupCrontabStep, WaitForCloudInitStep,
 };
 pub use validation::{
-    ValidateCloudInitCompletionStep, ValidateDockerComposeInstallationStep,
+    CheckInstanceDiskSpaceStep, CheckLxdStoragePoolSpaceStep, CheckWorkspaceDiskSpaceStep,
+    DiskSpaceCheckError, ValidateCloudInitCompletionStep, ValidateDockerComposeInstallationStep,
     ValidateDockerInstallationStep,
 };
@@ -19,13 +19,17 @@
 //! configurations for remote host management.
 
 use std::net::SocketAddr;
+use std::path::PathBuf;
 use std::sync::Arc;
 
 use thiserror::Error;
 use tracing::{info, instrument};
 
 use crate::adapters::ssh::credentials::SshCredentials;
+use crate::domain::firewall::FirewallRule;
 use crate::domain::grafana::GrafanaConfig;
+use crate::domain::provision::{OfflineMode, ProxyConfig};
+use crate::domain::system_tuning::SystemTuningConfig;
 use crate::domain::tracker::TrackerConfig;
 use crate::infrastructure::templating::ansible::template::renderer::AnsibleProjectGeneratorError;
 use crate::infrastructure::templating::ansible::template::wrappers::inventory::{
@@ -91,6 +95,11 @@ pub struct RenderAnsibleTemplatesStep {
     ssh_socket_addr: SocketAddr,
     tracker_config: TrackerConfig,
     grafana_config: Option<GrafanaConfig>,
+    firewall_allow_rules: Vec<FirewallRule>,
+    offline: Option<OfflineMode>,
+    proxy: Option<ProxyConfig>,
+    system_tuning: SystemTuningConfig,
+    known_hosts_file: Option<PathBuf>,
     clock: Arc<dyn Clock>,
 }
 
@@ -102,6 +111,7 @@ impl RenderAnsibleTemplatesStep {
         ssh_socket_addr: SocketAddr,
         tracker_config: TrackerConfig,
         grafana_config: Option<GrafanaConfig>,
+        firewall_allow_rules: Vec<FirewallRule>,
         clock: Arc<dyn Clock>,
     ) -> Self {
         Self {
@@ -110,10 +120,47 @@ impl RenderAnsibleTemplatesStep {
             ssh_socket_addr,
             tracker_config,
             grafana_config,
+            firewall_allow_rules,
+            offline: None,
+            proxy: None,
+            system_tuning: SystemTuningConfig::default(),
+            known_hosts_file: None,
             clock,
         }
     }
 
+    /// Sets the pre-seeded artifacts the playbooks install from instead of the Internet
+    #[must_use]
+    pub fn with_offline_mode(mut self, offline: Option<OfflineMode>) -> Self {
+        self.offline = offline;
+        self
+    }
+
+    /// Sets the outbound proxy the playbooks and Docker download through
+    #[must_use]
+    pub fn with_proxy(mut self, proxy: Option<ProxyConfig>) -> Self {
+        self.proxy = proxy;
+        self
+    }
+
+    /// Sets the sysctls, resource limits and swap file applied on configure
+    #[must_use]
+    pub fn with_system_tuning(mut self, system_tuning: SystemTuningConfig) -> Self {
+        self.system_tuning = system_tuning;
+        self
+    }
+
+    /// Sets the `known_hosts` file the playbooks check the host keys against
+    ///
+    /// Playbooks run from the build directory, so a relative path is made
+    /// absolute.
+    #[must_use]
+    pub fn with_known_hosts_file(mut self, known_hosts_file: Option<PathBuf>) -> Self {
+        self.known_hosts_file =
+            known_hosts_file.map(|path| std::path::absolute(&path).unwrap_or(path));
+        self
+    }
+
     /// Execute the template rendering step
     ///
     /// # Errors
@@ -121,7 +168,7 @@ impl RenderAnsibleTemplatesStep {
     /// Returns an error if the template rendering fails or if there are issues
     /// with the template manager or renderer.
     #[instrument(
-        name = "render_ansible_templates",
+        name = "step.render_ansible_templates",
         skip_all,
         fields(step_type = "rendering", template_type = "ansible")
     )]
@@ -140,6 +187,10 @@ impl RenderAnsibleTemplatesStep {
                 &inventory_context,
                 Some(&self.tracker_config),
                 self.grafana_config.as_ref(),
+                &self.firewall_allow_rules,
+                self.offline.as_ref(),
+                self.proxy.as_ref(),
+                &self.system_tuning,
             )
             .await?;
 
@@ -172,6 +223,7 @@ impl RenderAnsibleTemplatesStep {
             .with_ssh_priv_key_path(ssh_key)
             .with_ssh_port(ssh_port)
             .with_ansible_user(ansible_user)
+            .with_known_hosts_file(self.known_hosts_file.clone())
             .build()
             .map_err(RenderAnsibleTemplatesError::from)
     }
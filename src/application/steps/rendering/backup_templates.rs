@@ -83,7 +83,7 @@ impl<S> RenderBackupTemplatesStep<S> {
     /// * Directory creation fails
     /// * File writing fails
     #[instrument(
-        name = "render_backup_templates",
+        name = "step.render_backup_templates",
         skip_all,
         fields(
             step_type = "rendering",
@@ -111,6 +111,8 @@ impl<S> RenderBackupTemplatesStep<S> {
 
         let service = BackupTemplateRenderingService::from_paths(
             self.templates_dir.clone(),
+            self.environment.templates_override_dir(),
+            self.environment.extra_template_variables().clone(),
             self.build_dir.clone(),
         );
 
@@ -92,7 +92,7 @@ impl<S> RenderCaddyTemplatesStep<S> {
     /// * Directory creation fails
     /// * File writing fails
     #[instrument(
-        name = "render_caddy_templates",
+        name = "step.render_caddy_templates",
         skip_all,
         fields(
             step_type = "rendering",
@@ -121,6 +121,8 @@ impl<S> RenderCaddyTemplatesStep<S> {
 
         let service = CaddyTemplateRenderingService::from_paths(
             self.templates_dir.clone(),
+            self.environment.templates_override_dir(),
+            self.environment.extra_template_variables().clone(),
             self.build_dir.clone(),
             self.clock.clone(),
         );
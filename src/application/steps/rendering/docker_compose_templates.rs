@@ -85,7 +85,7 @@ impl<S> RenderDockerComposeTemplatesStep<S> {
     /// * Directory creation fails
     /// * File copying fails
     #[instrument(
-        name = "render_docker_compose_templates",
+        name = "step.render_docker_compose_templates",
         skip_all,
         fields(
             step_type = "rendering",
@@ -103,6 +103,8 @@ impl<S> RenderDockerComposeTemplatesStep<S> {
 
         let service = DockerComposeTemplateRenderingService::from_paths(
             self.templates_dir.clone(),
+            self.environment.templates_override_dir(),
+            self.environment.extra_template_variables().clone(),
             self.build_dir.clone(),
             self.clock.clone(),
         );
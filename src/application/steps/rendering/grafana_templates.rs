@@ -87,7 +87,7 @@ impl<S> RenderGrafanaTemplatesStep<S> {
     /// * Directory creation fails
     /// * File writing fails
     #[instrument(
-        name = "render_grafana_templates",
+        name = "step.render_grafana_templates",
         skip_all,
         fields(
             step_type = "rendering",
@@ -130,6 +130,8 @@ impl<S> RenderGrafanaTemplatesStep<S> {
 
         let service = GrafanaTemplateRenderingService::from_paths(
             self.templates_dir.clone(),
+            self.environment.templates_override_dir(),
+            self.environment.extra_template_variables().clone(),
             self.build_dir.clone(),
             self.clock.clone(),
         );
@@ -48,7 +48,7 @@ impl RenderOpenTofuTemplatesStep {
     /// Returns an error if the template rendering fails or if there are issues
     /// with the template manager or renderer.
     #[instrument(
-        name = "render_opentofu_templates",
+        name = "step.render_opentofu_templates",
         skip_all,
         fields(step_type = "rendering", template_type = "opentofu")
     )]
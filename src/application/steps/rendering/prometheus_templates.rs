@@ -87,7 +87,7 @@ impl<S> RenderPrometheusTemplatesStep<S> {
     /// * Directory creation fails
     /// * File writing fails
     #[instrument(
-        name = "render_prometheus_templates",
+        name = "step.render_prometheus_templates",
         skip_all,
         fields(
             step_type = "rendering",
@@ -116,6 +116,8 @@ impl<S> RenderPrometheusTemplatesStep<S> {
 
         let service = PrometheusTemplateRenderingService::from_paths(
             self.templates_dir.clone(),
+            self.environment.templates_override_dir(),
+            self.environment.extra_template_variables().clone(),
             self.build_dir.clone(),
             self.clock.clone(),
         );
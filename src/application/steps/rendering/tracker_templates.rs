@@ -92,7 +92,7 @@ impl<S> RenderTrackerTemplatesStep<S> {
     /// * Directory creation fails
     /// * File writing fails
     #[instrument(
-        name = "render_tracker_templates",
+        name = "step.render_tracker_templates",
         skip_all,
         fields(
             step_type = "rendering",
@@ -110,6 +110,8 @@ impl<S> RenderTrackerTemplatesStep<S> {
 
         let service = TrackerTemplateRenderingService::from_paths(
             self.templates_dir.clone(),
+            self.environment.templates_override_dir(),
+            self.environment.extra_template_variables().clone(),
             self.build_dir.clone(),
             self.clock.clone(),
         );
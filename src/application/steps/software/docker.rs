@@ -23,7 +23,7 @@ use std::sync::Arc;
 use tracing::{info, instrument};
 
 use crate::adapters::ansible::AnsibleClient;
-use crate::application::traits::CommandProgressListener;
+use crate::application::traits::{forward_output_lines, report_retries, CommandProgressListener};
 use crate::shared::command::CommandError;
 
 /// Step that installs Docker on a remote host via Ansible
@@ -61,7 +61,7 @@ impl InstallDockerStep {
     ///   or will handle stale cache gracefully
     /// - We skip the update-apt-cache playbook in E2E tests to avoid CI network issues
     #[instrument(
-        name = "install_docker",
+        name = "step.install_docker",
         skip_all,
         fields(step_type = "software", component = "docker", method = "ansible")
     )]
@@ -85,7 +85,12 @@ impl InstallDockerStep {
             l.on_debug("Executing playbook: ansible-playbook install-docker.yml -i inventory.ini");
         }
 
-        self.ansible_client.run_playbook("install-docker", &[])?;
+        self.ansible_client.run_playbook_with_output(
+            "install-docker",
+            &[],
+            &forward_output_lines(listener, "ansible-playbook"),
+            &report_retries(listener),
+        )?;
 
         // Report installation success with details
         if let Some(l) = listener {
@@ -25,7 +25,7 @@ use std::sync::Arc;
 use tracing::{info, instrument};
 
 use crate::adapters::ansible::AnsibleClient;
-use crate::application::traits::CommandProgressListener;
+use crate::application::traits::{forward_output_lines, report_retries, CommandProgressListener};
 use crate::shared::command::CommandError;
 
 /// Step that installs Docker Compose on a remote host via Ansible
@@ -57,7 +57,7 @@ impl InstallDockerComposeStep {
     /// * Docker Compose installation fails
     /// * The playbook execution fails for any other reason
     #[instrument(
-        name = "install_docker_compose",
+        name = "step.install_docker_compose",
         skip_all,
         fields(
             step_type = "software",
@@ -86,8 +86,12 @@ impl InstallDockerComposeStep {
             );
         }
 
-        self.ansible_client
-            .run_playbook("install-docker-compose", &[])?;
+        self.ansible_client.run_playbook_with_output(
+            "install-docker-compose",
+            &[],
+            &forward_output_lines(listener, "ansible-playbook"),
+            &report_retries(listener),
+        )?;
 
         // Report installation success with details
         if let Some(l) = listener {
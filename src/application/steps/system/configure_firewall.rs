@@ -38,7 +38,7 @@ use std::sync::Arc;
 use tracing::{info, instrument, warn};
 
 use crate::adapters::ansible::AnsibleClient;
-use crate::application::traits::CommandProgressListener;
+use crate::application::traits::{forward_output_lines, report_retries, CommandProgressListener};
 use crate::shared::command::CommandError;
 
 /// Step that configures UFW firewall on a remote host via Ansible
@@ -94,7 +94,7 @@ impl ConfigureFirewallStep {
     /// - SSH rules cannot be applied
     /// - Firewall verification fails
     #[instrument(
-        name = "configure_firewall",
+        name = "step.configure_firewall",
         skip_all,
         fields(step_type = "system", component = "firewall", method = "ansible")
     )]
@@ -120,10 +120,12 @@ impl ConfigureFirewallStep {
         // Run Ansible playbook with variables file
         // Note: The @ symbol in Ansible means "load variables from this file"
         // Equivalent to: ansible-playbook -e @variables.yml configure-firewall.yml
-        match self
-            .ansible_client
-            .run_playbook("configure-firewall", &["-e", "@variables.yml"])
-        {
+        match self.ansible_client.run_playbook_with_output(
+            "configure-firewall",
+            &["-e", "@variables.yml"],
+            &forward_output_lines(listener, "ansible-playbook"),
+            &report_retries(listener),
+        ) {
             Ok(_) => {
                 // Report configuration success with details
                 if let Some(l) = listener {
@@ -26,7 +26,7 @@ use std::sync::Arc;
 use tracing::{info, instrument};
 
 use crate::adapters::ansible::AnsibleClient;
-use crate::application::traits::CommandProgressListener;
+use crate::application::traits::{forward_output_lines, report_retries, CommandProgressListener};
 use crate::shared::command::CommandError;
 
 /// Step that configures automatic security updates on a remote host via Ansible
@@ -62,7 +62,7 @@ impl ConfigureSecurityUpdatesStep {
     /// * Configuration verification fails
     /// * The playbook execution fails for any other reason
     #[instrument(
-        name = "configure_security_updates",
+        name = "step.configure_security_updates",
         skip_all,
         fields(
             step_type = "system",
@@ -89,8 +89,12 @@ impl ConfigureSecurityUpdatesStep {
             l.on_debug("Executing playbook: ansible-playbook configure-security-updates.yml -i inventory.ini");
         }
 
-        self.ansible_client
-            .run_playbook("configure-security-updates", &[])?;
+        self.ansible_client.run_playbook_with_output(
+            "configure-security-updates",
+            &[],
+            &forward_output_lines(listener, "ansible-playbook"),
+            &report_retries(listener),
+        )?;
 
         // Report configuration success with details
         if let Some(l) = listener {
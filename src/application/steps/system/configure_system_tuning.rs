@@ -0,0 +1,136 @@
+//! System tuning configuration step
+//!
+//! This module provides the `ConfigureSystemTuningStep` which applies the
+//! `system_tuning` section of the environment configuration on remote hosts
+//! via Ansible playbooks.
+//!
+//! ## Key Features
+//!
+//! - Writes the sysctls to `/etc/sysctl.d/` and loads them
+//! - Writes the resource limits to `/etc/security/limits.d/`
+//! - Creates and enables an optional swap file
+//! - Reports every applied value, so it is recorded in the deployment report
+//!
+//! ## Idempotency
+//!
+//! The "configure-system-tuning" playbook only rewrites files whose content
+//! changed and only creates the swap file when it is missing, so re-running
+//! configure with the same settings changes nothing on the host.
+
+use std::sync::Arc;
+use tracing::{info, instrument};
+
+use crate::adapters::ansible::AnsibleClient;
+use crate::application::traits::{forward_output_lines, report_retries, CommandProgressListener};
+use crate::domain::system_tuning::SystemTuningConfig;
+use crate::shared::command::CommandError;
+
+/// Step that applies the system tuning on a remote host via Ansible
+///
+/// The values are resolved during template rendering and embedded in
+/// `variables.yml`; the configuration is only used to report them.
+pub struct ConfigureSystemTuningStep {
+    ansible_client: Arc<AnsibleClient>,
+    system_tuning: SystemTuningConfig,
+}
+
+impl ConfigureSystemTuningStep {
+    /// Create a new system tuning step
+    ///
+    /// # Arguments
+    ///
+    /// * `ansible_client` - Ansible client for running playbooks
+    /// * `system_tuning` - The settings rendered into `variables.yml`
+    #[must_use]
+    pub fn new(ansible_client: Arc<AnsibleClient>, system_tuning: SystemTuningConfig) -> Self {
+        Self {
+            ansible_client,
+            system_tuning,
+        }
+    }
+
+    /// Execute the system tuning
+    ///
+    /// # Arguments
+    ///
+    /// * `listener` - Optional progress listener. When provided, receives the
+    ///   Ansible command as debug information, and every applied value as a
+    ///   detail and as an applied setting.
+    ///
+    /// # Errors
+    ///
+    /// Returns `CommandError` if the playbook fails, including when a sysctl
+    /// read back from the host differs from the configured value.
+    #[instrument(
+        name = "step.configure_system_tuning",
+        skip_all,
+        fields(step_type = "system", component = "system_tuning", method = "ansible")
+    )]
+    pub fn execute(
+        &self,
+        listener: Option<&dyn CommandProgressListener>,
+    ) -> Result<(), CommandError> {
+        info!(
+            step = "configure_system_tuning",
+            preset = %self.system_tuning.preset(),
+            "Applying system tuning with variables from variables.yml"
+        );
+
+        if let Some(l) = listener {
+            l.on_debug(&format!(
+                "Ansible working directory: {}",
+                self.ansible_client.working_dir().display()
+            ));
+            l.on_debug("Executing playbook: ansible-playbook configure-system-tuning.yml -e @variables.yml -i inventory.ini");
+        }
+
+        self.ansible_client.run_playbook_with_output(
+            "configure-system-tuning",
+            &["-e", "@variables.yml"],
+            &forward_output_lines(listener, "ansible-playbook"),
+            &report_retries(listener),
+        )?;
+
+        // With --check nothing was applied, so nothing is recorded
+        if self.ansible_client.options().is_check() {
+            return Ok(());
+        }
+
+        let applied = self.system_tuning.applied_values();
+
+        if let Some(l) = listener {
+            for (name, value) in &applied {
+                l.on_detail(&format!("{name} = {value}"));
+                l.on_setting_applied(name, value);
+            }
+        }
+
+        info!(
+            step = "configure_system_tuning",
+            status = "success",
+            applied_values = applied.len(),
+            "System tuning applied successfully"
+        );
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::path::PathBuf;
+
+    use super::*;
+    use crate::domain::system_tuning::TuningPreset;
+
+    #[test]
+    fn it_should_create_configure_system_tuning_step() {
+        let ansible_client = Arc::new(AnsibleClient::new(PathBuf::from("test_inventory.yml")));
+        let step = ConfigureSystemTuningStep::new(
+            ansible_client,
+            SystemTuningConfig::from_preset(TuningPreset::HighUdp),
+        );
+
+        assert_eq!(step.system_tuning.preset(), TuningPreset::HighUdp);
+    }
+}
@@ -55,7 +55,7 @@ impl InstallBackupCrontabStep {
     /// - Permissions cannot be set correctly
     /// - Verification checks fail
     #[instrument(
-        name = "install_backup_crontab",
+        name = "step.install_backup_crontab",
         skip_all,
         fields(step_type = "system", component = "backup", method = "ansible")
     )]
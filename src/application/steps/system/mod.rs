@@ -8,6 +8,7 @@
  * - Cloud-init completion waiting
  * - Automatic security updates configuration
  * - UFW firewall configuration (SSH access only)
+ * - System tuning (sysctls, ulimits, swap file)
  * - Backup crontab installation
  *
  * Note: Tracker service ports are controlled via Docker port bindings in docker-compose,
@@ -22,10 +23,12 @@
 
 pub mod configure_firewall;
 pub mod configure_security_updates;
+pub mod configure_system_tuning;
 pub mod install_backup_crontab;
 pub mod wait_cloud_init;
 
 pub use configure_firewall::ConfigureFirewallStep;
 pub use configure_security_updates::ConfigureSecurityUpdatesStep;
+pub use configure_system_tuning::ConfigureSystemTuningStep;
 pub use install_backup_crontab::InstallBackupCrontabStep;
 pub use wait_cloud_init::WaitForCloudInitStep;
@@ -6,38 +6,51 @@
 //!
 //! ## Key Features
 //!
-//! - Waits for cloud-init completion via Ansible playbook execution
-//! - Provides timeout handling for cloud-init processes
-//! - Integration with the deployment step system
-//! - Comprehensive error handling and logging
+//! - Polls cloud-init over SSH with exponential backoff
+//! - Configurable overall timeout (`provision.cloud_init_timeout_secs`)
+//! - Reports the elapsed time and current status while waiting
+//! - Captures the tail of the remote cloud-init output log on failure
 //!
 //! ## Usage Context
 //!
 //! This step is typically used early in the deployment workflow after
 //! infrastructure provisioning to ensure instances are ready for configuration.
 
-use std::sync::Arc;
+use std::time::Duration;
+
 use tracing::{info, instrument};
 
-use crate::adapters::ansible::AnsibleClient;
+use crate::adapters::ssh::SshConfig;
 use crate::application::traits::CommandProgressListener;
-use crate::shared::command::CommandError;
+use crate::infrastructure::remote_actions::{
+    CloudInitWaitError, CloudInitWaitPolicy, CloudInitWaiter,
+};
 
 /// Step that waits for cloud-init completion on a remote host
 pub struct WaitForCloudInitStep {
-    ansible_client: Arc<AnsibleClient>,
+    ssh_config: SshConfig,
+    timeout: Duration,
 }
 
 impl WaitForCloudInitStep {
+    /// Create a new `WaitForCloudInitStep`
+    ///
+    /// # Arguments
+    ///
+    /// * `ssh_config` - SSH connection configuration of the instance
+    /// * `timeout` - Maximum time to wait for cloud-init to finish
     #[must_use]
-    pub fn new(ansible_client: Arc<AnsibleClient>) -> Self {
-        Self { ansible_client }
+    pub fn new(ssh_config: SshConfig, timeout: Duration) -> Self {
+        Self {
+            ssh_config,
+            timeout,
+        }
     }
 
     /// Execute the cloud-init wait step
     ///
-    /// This will run the "wait-cloud-init" Ansible playbook to ensure
-    /// cloud-init has completed on the remote host before proceeding.
+    /// This will poll the remote host until cloud-init has completed before
+    /// proceeding.
     ///
     /// # Arguments
     ///
@@ -46,36 +59,50 @@ impl WaitForCloudInitStep {
     /// # Errors
     ///
     /// Returns an error if:
-    /// * The Ansible client fails to execute the playbook
+    /// * Cloud-init reports an error
     /// * Cloud-init has not completed within the timeout period
-    /// * The playbook execution fails for any other reason
     #[instrument(
-        name = "wait_cloud_init",
+        name = "step.wait_cloud_init",
         skip_all,
         fields(step_type = "system", component = "cloud_init")
     )]
-    pub fn execute(
+    pub async fn execute(
         &self,
         listener: Option<&dyn CommandProgressListener>,
-    ) -> Result<(), CommandError> {
+    ) -> Result<(), CloudInitWaitError> {
         info!(
             step = "wait_cloud_init",
             action = "wait_cloud_init",
+            timeout_secs = self.timeout.as_secs(),
             "Waiting for cloud-init completion"
         );
 
         if let Some(l) = listener {
             l.on_debug(&format!(
-                "Ansible working directory: {}",
-                self.ansible_client.working_dir().display()
+                "Polling cloud-init status on {} (timeout: {}s)",
+                self.ssh_config.host_ip(),
+                self.timeout.as_secs()
             ));
-            l.on_debug("Executing: ansible-playbook wait-cloud-init.yml");
         }
 
-        self.ansible_client.run_playbook("wait-cloud-init", &[])?;
+        let waiter = CloudInitWaiter::new(
+            self.ssh_config.clone(),
+            CloudInitWaitPolicy::with_timeout(self.timeout),
+        );
+
+        waiter
+            .wait(|poll| {
+                if let Some(l) = listener {
+                    l.on_detail(&format!(
+                        "Cloud-init status: {} ({}s elapsed)",
+                        poll.status,
+                        poll.elapsed.as_secs()
+                    ));
+                }
+            })
+            .await?;
 
         if let Some(l) = listener {
-            l.on_debug("Playbook completed successfully");
             l.on_detail("Cloud-init status: done ✓");
         }
 
@@ -91,20 +118,24 @@ impl WaitForCloudInitStep {
 
 #[cfg(test)]
 mod tests {
-    use std::path::PathBuf;
-    use std::sync::Arc;
+    use std::net::{IpAddr, Ipv4Addr};
 
     use super::*;
+    use crate::adapters::ssh::SshCredentials;
+    use crate::shared::Username;
 
     #[test]
     fn it_should_create_wait_for_cloud_init_step() {
-        let ansible_client = Arc::new(AnsibleClient::new(PathBuf::from("test_inventory.yml")));
-        let step = WaitForCloudInitStep::new(ansible_client);
-
-        // Test that the step can be created successfully
-        assert_eq!(
-            std::mem::size_of_val(&step),
-            std::mem::size_of::<Arc<AnsibleClient>>()
+        let credentials = SshCredentials::new(
+            "/tmp/test_key".into(),
+            "/tmp/test_key.pub".into(),
+            Username::new("testuser").unwrap(),
         );
+        let ssh_config =
+            SshConfig::with_default_port(credentials, IpAddr::V4(Ipv4Addr::new(10, 0, 0, 1)));
+
+        let step = WaitForCloudInitStep::new(ssh_config, Duration::from_secs(300));
+
+        assert_eq!(step.timeout, Duration::from_secs(300));
     }
 }
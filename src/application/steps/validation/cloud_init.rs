@@ -52,7 +52,7 @@ impl ValidateCloudInitCompletionStep {
     /// - Critical for ensuring the system is ready for further configuration
     /// - Checks both cloud-init status and completion markers
     #[instrument(
-        name = "validate_cloud_init",
+        name = "step.validate_cloud_init",
         skip_all,
         fields(step_type = "validation", component = "cloud_init")
     )]
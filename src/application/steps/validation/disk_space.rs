@@ -0,0 +1,402 @@
+//! Disk space preflight steps
+//!
+//! This module provides the steps that check, before a workflow writes
+//! anything, that enough disk space is free where it is about to write:
+//!
+//! - `CheckWorkspaceDiskSpaceStep` - the partition holding the workspace,
+//!   where `OpenTofu` writes its state
+//! - `CheckLxdStoragePoolSpaceStep` - the LXD storage pool the instance disk
+//!   and image are created in
+//! - `CheckInstanceDiskSpaceStep` - the partition of the instance holding the
+//!   Docker images pulled by the release
+//!
+//! ## Why a Preflight
+//!
+//! Running out of space halfway through leaves a corrupted `OpenTofu` state
+//! or a truncated image download behind, which fails later with errors that
+//! do not mention disk space at all.
+//!
+//! ## Measurement Failures
+//!
+//! Only a measured shortage fails a step. When the space cannot be measured
+//! (e.g. `lxc` is missing or the instance does not answer), a warning is
+//! logged and the workflow goes on: the following steps report the actual
+//! problem with a better error.
+
+use std::path::{Path, PathBuf};
+use std::time::Duration;
+
+use thiserror::Error;
+use tracing::{info, instrument, warn};
+
+use crate::adapters::lxd::LxdClient;
+use crate::adapters::ssh::SshConfig;
+use crate::application::traits::CommandProgressListener;
+use crate::domain::provider::LxdRemote;
+use crate::infrastructure::host::disk_space;
+use crate::infrastructure::remote_actions::{RemoteDiskSpace, DOCKER_DATA_DIR};
+
+/// Storage pool the LXD instances are created in (see `templates/tofu/lxd/main.tf`)
+pub const LXD_STORAGE_POOL: &str = "default";
+
+/// Time the LXD server is given to report the storage pool resources
+const LXD_QUERY_TIMEOUT: Duration = Duration::from_secs(10);
+
+/// Errors of the disk space preflight
+#[derive(Debug, Error)]
+pub enum DiskSpaceCheckError {
+    /// Less space is free than the configured threshold
+    #[error(
+        "Insufficient disk space on {location}: {} available, {} required",
+        format_gib(*available),
+        format_gib(*required)
+    )]
+    InsufficientDiskSpace {
+        /// The partition or storage pool that is too full
+        location: String,
+        /// Free space, in bytes
+        available: u64,
+        /// Configured minimum free space, in bytes
+        required: u64,
+    },
+}
+
+impl crate::shared::Traceable for DiskSpaceCheckError {
+    fn trace_format(&self) -> String {
+        match self {
+            Self::InsufficientDiskSpace {
+                location,
+                available,
+                required,
+            } => format!(
+                "DiskSpaceCheckError: insufficient disk space on {location} - {available} bytes available, {required} bytes required"
+            ),
+        }
+    }
+
+    fn trace_source(&self) -> Option<&dyn crate::shared::Traceable> {
+        None
+    }
+
+    fn error_kind(&self) -> crate::shared::ErrorKind {
+        crate::shared::ErrorKind::FileSystem
+    }
+}
+
+/// Format a size in bytes as GiB with one decimal, e.g. `1.5 GiB`
+#[must_use]
+pub fn format_gib(bytes: u64) -> String {
+    #[allow(clippy::cast_precision_loss)] // Only displayed with one decimal
+    let gib = bytes as f64 / (1024.0 * 1024.0 * 1024.0);
+    format!("{gib:.1} GiB")
+}
+
+/// Compare the free space of `location` with its threshold
+///
+/// # Errors
+///
+/// Returns `InsufficientDiskSpace` when less than `required` is available.
+pub fn require_free_space(
+    location: String,
+    available: u64,
+    required: u64,
+) -> Result<(), DiskSpaceCheckError> {
+    if available < required {
+        return Err(DiskSpaceCheckError::InsufficientDiskSpace {
+            location,
+            available,
+            required,
+        });
+    }
+    Ok(())
+}
+
+/// Report a successful check to the listener
+fn report_free_space(
+    listener: Option<&dyn CommandProgressListener>,
+    location: &str,
+    available: u64,
+) {
+    if let Some(l) = listener {
+        l.on_detail(&format!("{} free on {location} ✓", format_gib(available)));
+    }
+}
+
+/// Report a check that could not run to the listener and the logs
+fn report_unmeasured(listener: Option<&dyn CommandProgressListener>, location: &str, reason: &str) {
+    warn!(location = %location, reason = %reason, "Cannot measure the free disk space");
+
+    if let Some(l) = listener {
+        l.on_detail(&format!(
+            "Cannot measure the free space on {location}, skipping the check: {reason}"
+        ));
+    }
+}
+
+/// Step that checks the free space of the partition holding the workspace
+pub struct CheckWorkspaceDiskSpaceStep {
+    path: PathBuf,
+    required: u64,
+}
+
+impl CheckWorkspaceDiskSpaceStep {
+    /// Check that `required` bytes are free on the partition holding `path`
+    #[must_use]
+    pub fn new(path: &Path, required: u64) -> Self {
+        Self {
+            path: path.to_path_buf(),
+            required,
+        }
+    }
+
+    /// Execute the workspace disk space check
+    ///
+    /// # Errors
+    ///
+    /// Returns `InsufficientDiskSpace`, naming the mount point, when less
+    /// than the threshold is free.
+    #[instrument(
+        name = "step.check_workspace_disk_space",
+        skip_all,
+        fields(step_type = "validation", path = %self.path.display())
+    )]
+    pub fn execute(
+        &self,
+        listener: Option<&dyn CommandProgressListener>,
+    ) -> Result<(), DiskSpaceCheckError> {
+        // A directory that does not exist yet is created on the same partition as its parent
+        let existing = self
+            .path
+            .ancestors()
+            .find(|ancestor| ancestor.is_dir())
+            .unwrap_or_else(|| Path::new("."));
+
+        let space = match disk_space(existing) {
+            Ok(space) => space,
+            Err(e) => {
+                report_unmeasured(listener, "the workspace partition", &e.to_string());
+                return Ok(());
+            }
+        };
+
+        let location = format!(
+            "partition '{}' holding the workspace ({})",
+            space.mount_point,
+            self.path.display()
+        );
+        require_free_space(location.clone(), space.available, self.required)?;
+
+        report_free_space(listener, &location, space.available);
+        info!(
+            step = "check_workspace_disk_space",
+            mount_point = %space.mount_point,
+            available = space.available,
+            required = self.required,
+            status = "success",
+            "Enough disk space under the workspace"
+        );
+
+        Ok(())
+    }
+}
+
+/// Step that checks the free space of the LXD storage pool of the instance
+pub struct CheckLxdStoragePoolSpaceStep {
+    remote: Option<LxdRemote>,
+    required: u64,
+}
+
+impl CheckLxdStoragePoolSpaceStep {
+    /// Check that `required` bytes are free in the storage pool of the local
+    /// LXD daemon, or of `remote` when the instance is created on one
+    #[must_use]
+    pub fn new(remote: Option<LxdRemote>, required: u64) -> Self {
+        Self { remote, required }
+    }
+
+    /// Name of the storage pool, including its remote
+    #[must_use]
+    pub fn location(&self) -> String {
+        match &self.remote {
+            Some(remote) => format!(
+                "LXD storage pool '{LXD_STORAGE_POOL}' of remote '{}'",
+                remote.name()
+            ),
+            None => format!("LXD storage pool '{LXD_STORAGE_POOL}'"),
+        }
+    }
+
+    /// LXD client talking to the server the instance is created on
+    #[must_use]
+    pub fn client(&self) -> LxdClient {
+        let Some(remote) = &self.remote else {
+            return LxdClient::new();
+        };
+
+        let mut client = LxdClient::new().with_remote(remote.name());
+        if let Some(config_dir) = remote.config_dir() {
+            client = client.with_config_dir(config_dir);
+        }
+        client
+    }
+
+    /// Execute the LXD storage pool check
+    ///
+    /// # Errors
+    ///
+    /// Returns `InsufficientDiskSpace`, naming the storage pool, when less
+    /// than the threshold is free.
+    #[instrument(
+        name = "step.check_lxd_storage_pool_space",
+        skip_all,
+        fields(step_type = "validation", pool = LXD_STORAGE_POOL)
+    )]
+    pub fn execute(
+        &self,
+        listener: Option<&dyn CommandProgressListener>,
+    ) -> Result<(), DiskSpaceCheckError> {
+        let location = self.location();
+
+        let available = match self
+            .client()
+            .storage_pool_free_space(LXD_STORAGE_POOL, LXD_QUERY_TIMEOUT)
+        {
+            Ok(available) => available,
+            Err(e) => {
+                report_unmeasured(listener, &location, &format!("{e:#}"));
+                return Ok(());
+            }
+        };
+
+        require_free_space(location.clone(), available, self.required)?;
+
+        report_free_space(listener, &location, available);
+        info!(
+            step = "check_lxd_storage_pool_space",
+            available = available,
+            required = self.required,
+            status = "success",
+            "Enough space in the LXD storage pool"
+        );
+
+        Ok(())
+    }
+}
+
+/// Step that checks the free space of the Docker partition of the instance
+pub struct CheckInstanceDiskSpaceStep {
+    ssh_config: SshConfig,
+    required: u64,
+}
+
+impl CheckInstanceDiskSpaceStep {
+    /// Check that `required` bytes are free on the instance partition holding
+    /// the Docker data directory
+    #[must_use]
+    pub fn new(ssh_config: SshConfig, required: u64) -> Self {
+        Self {
+            ssh_config,
+            required,
+        }
+    }
+
+    /// Execute the instance disk space check
+    ///
+    /// # Errors
+    ///
+    /// Returns `InsufficientDiskSpace`, naming the mount point, when less
+    /// than the threshold is free.
+    #[instrument(
+        name = "step.check_instance_disk_space",
+        skip_all,
+        fields(step_type = "validation", path = DOCKER_DATA_DIR)
+    )]
+    pub fn execute(
+        &self,
+        listener: Option<&dyn CommandProgressListener>,
+    ) -> Result<(), DiskSpaceCheckError> {
+        let space = match RemoteDiskSpace::new(self.ssh_config.clone()).measure(DOCKER_DATA_DIR) {
+            Ok(space) => space,
+            Err(e) => {
+                report_unmeasured(listener, "the instance Docker partition", &e.to_string());
+                return Ok(());
+            }
+        };
+
+        let location = format!(
+            "partition '{}' of the instance holding {DOCKER_DATA_DIR}",
+            space.mount_point
+        );
+        require_free_space(location.clone(), space.available, self.required)?;
+
+        report_free_space(listener, &location, space.available);
+        info!(
+            step = "check_instance_disk_space",
+            mount_point = %space.mount_point,
+            available = space.available,
+            required = self.required,
+            status = "success",
+            "Enough disk space on the instance"
+        );
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use tempfile::TempDir;
+
+    use super::*;
+
+    const GIB: u64 = 1024 * 1024 * 1024;
+
+    #[test]
+    fn it_should_name_the_partition_and_both_sizes_when_space_is_insufficient() {
+        let error = require_free_space(
+            "partition '/home' holding the workspace (/home/torrust/deployer)".to_string(),
+            GIB / 2,
+            2 * GIB,
+        )
+        .unwrap_err();
+
+        assert_eq!(
+            error.to_string(),
+            "Insufficient disk space on partition '/home' holding the workspace (/home/torrust/deployer): 0.5 GiB available, 2.0 GiB required"
+        );
+    }
+
+    #[test]
+    fn it_should_accept_exactly_the_required_space() {
+        assert!(require_free_space("partition '/'".to_string(), 2 * GIB, 2 * GIB).is_ok());
+    }
+
+    #[test]
+    fn it_should_abort_when_the_workspace_partition_has_less_than_the_threshold() {
+        let temp_dir = TempDir::new().unwrap();
+
+        let data_dir = temp_dir.path().join("data").join("not-created-yet");
+
+        let result = CheckWorkspaceDiskSpaceStep::new(&data_dir, u64::MAX).execute(None);
+
+        assert!(matches!(
+            result,
+            Err(DiskSpaceCheckError::InsufficientDiskSpace { location, .. })
+                if location.starts_with("partition '/")
+        ));
+    }
+
+    #[test]
+    fn it_should_name_the_remote_of_the_storage_pool() {
+        let remote = LxdRemote::new("bigbox", None, None, None).unwrap();
+
+        assert_eq!(
+            CheckLxdStoragePoolSpaceStep::new(Some(remote), GIB).location(),
+            "LXD storage pool 'default' of remote 'bigbox'"
+        );
+        assert_eq!(
+            CheckLxdStoragePoolSpaceStep::new(None, GIB).location(),
+            "LXD storage pool 'default'"
+        );
+    }
+}
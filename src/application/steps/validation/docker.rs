@@ -51,7 +51,7 @@ impl ValidateDockerInstallationStep {
     ///   validation may be skipped gracefully
     /// - The validation checks both Docker version and daemon status
     #[instrument(
-        name = "validate_docker",
+        name = "step.validate_docker",
         skip_all,
         fields(step_type = "validation", component = "docker")
     )]
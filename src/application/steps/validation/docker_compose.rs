@@ -54,7 +54,7 @@ impl ValidateDockerComposeInstallationStep {
     ///   validation may be skipped gracefully
     /// - The validation checks Docker Compose version and availability
     #[instrument(
-        name = "validate_docker_compose",
+        name = "step.validate_docker_compose",
         skip_all,
         fields(step_type = "validation", component = "docker_compose")
     )]
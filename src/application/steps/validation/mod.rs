@@ -7,6 +7,7 @@
 //! ## Available Steps
 //!
 //! - `cloud_init` - Cloud-init completion validation
+//! - `disk_space` - Free disk space preflight of the workspace, the LXD storage pool and the instance
 //! - `docker` - Docker installation and functionality validation  
 //! - `docker_compose` - Docker Compose installation and functionality validation
 //!
@@ -21,9 +22,14 @@
 //! a functional environment ready for application deployment and operation.
 
 pub mod cloud_init;
+pub mod disk_space;
 pub mod docker;
 pub mod docker_compose;
 
 pub use cloud_init::ValidateCloudInitCompletionStep;
+pub use disk_space::{
+    CheckInstanceDiskSpaceStep, CheckLxdStoragePoolSpaceStep, CheckWorkspaceDiskSpaceStep,
+    DiskSpaceCheckError,
+};
 pub use docker::ValidateDockerInstallationStep;
 pub use docker_compose::ValidateDockerComposeInstallationStep;
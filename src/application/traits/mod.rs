@@ -15,5 +15,8 @@ pub mod progress;
 pub mod repository_provider;
 
 // Re-export main types for convenience
-pub use progress::{CommandProgressListener, NullProgressListener};
+pub use progress::{
+    forward_output_lines, report_retries, CommandProgressListener, ExternalCommandOutputLine,
+    NullProgressListener,
+};
 pub use repository_provider::RepositoryProvider;
@@ -23,6 +23,7 @@
 //! - `on_step_started` / `on_step_completed` → Verbose (`-v`)
 //! - `on_detail` → `VeryVerbose` (`-vv`)
 //! - `on_debug` → Debug (`-vvv`)
+//! - `on_external_command_output_line` → `VeryVerbose` (`-vv`)
 //!
 //! The application layer reports everything; the presentation layer filters
 //! based on the user's chosen verbosity level.
@@ -48,6 +49,23 @@
 //! }
 //! ```
 
+use crate::adapters::ssh::SshRetry;
+use crate::shared::command::OutputStream;
+
+/// A line written by an external tool (`tofu`, `ansible-playbook`) while it runs
+///
+/// Reported as soon as the tool writes it, so long operations show their
+/// progress under the current step instead of all at once when they finish.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ExternalCommandOutputLine<'a> {
+    /// Name of the tool (e.g. `tofu`)
+    pub program: &'a str,
+    /// Stream the line was written to
+    pub stream: OutputStream,
+    /// The line, without line terminator or ANSI escape sequences
+    pub line: &'a str,
+}
+
 /// A listener for reporting command progress to the user interface.
 ///
 /// This trait is defined in the application layer and implemented in the
@@ -98,6 +116,66 @@ pub trait CommandProgressListener: Send + Sync {
     ///
     /// * `message` - Technical detail message
     fn on_debug(&self, message: &str);
+
+    /// Reports a line of output of an external tool while it runs.
+    ///
+    /// Maps to `VeryVerbose` (`-vv`) level in the presentation implementation.
+    /// Ignored by default, so listeners only interested in steps need not
+    /// implement it.
+    ///
+    /// # Arguments
+    ///
+    /// * `output_line` - The line and the tool and stream it comes from
+    fn on_external_command_output_line(&self, output_line: &ExternalCommandOutputLine<'_>) {
+        let _ = output_line;
+    }
+
+    /// Reports a setting the current step applied to the host.
+    ///
+    /// Recorded in the deployment report (e.g. the sysctls applied by the
+    /// system tuning step). Ignored by default.
+    ///
+    /// # Arguments
+    ///
+    /// * `name` - Name of the setting (e.g. `net.core.rmem_max`)
+    /// * `value` - The applied value
+    fn on_setting_applied(&self, name: &str, value: &str) {
+        let _ = (name, value);
+    }
+}
+
+/// Build an output line handler forwarding the lines of `program` to `listener`
+///
+/// Pass the result to the adapters running external tools (e.g.
+/// `OpenTofuClient::apply`). Without listener the lines are only logged.
+pub fn forward_output_lines<'a>(
+    listener: Option<&'a dyn CommandProgressListener>,
+    program: &'a str,
+) -> impl Fn(OutputStream, &str) + 'a {
+    move |stream, line| {
+        if let Some(l) = listener {
+            l.on_external_command_output_line(&ExternalCommandOutputLine {
+                program,
+                stream,
+                line,
+            });
+        }
+    }
+}
+
+/// Build a retry handler reporting each retry after a network failure to `listener`
+///
+/// Pass the result to the adapters retrying remote work (e.g.
+/// `AnsibleClient::run_playbook_with_output`). Without listener the retries
+/// are only logged.
+pub fn report_retries<'a>(
+    listener: Option<&'a dyn CommandProgressListener>,
+) -> impl Fn(&SshRetry) + 'a {
+    move |retry| {
+        if let Some(l) = listener {
+            l.on_detail(&retry.to_string());
+        }
+    }
 }
 
 /// A no-op listener that discards all progress events.
@@ -149,6 +227,34 @@ mod tests {
         listener.on_debug("debug");
     }
 
+    #[test]
+    fn it_should_forward_output_lines_to_the_listener() {
+        struct LastLine(std::sync::Mutex<Option<String>>);
+
+        impl CommandProgressListener for LastLine {
+            fn on_step_started(&self, _: usize, _: usize, _: &str) {}
+            fn on_step_completed(&self, _: usize, _: &str) {}
+            fn on_detail(&self, _: &str) {}
+            fn on_debug(&self, _: &str) {}
+            fn on_external_command_output_line(&self, output_line: &ExternalCommandOutputLine<'_>) {
+                *self.0.lock().unwrap() = Some(format!(
+                    "{} {} {}",
+                    output_line.program, output_line.stream, output_line.line
+                ));
+            }
+        }
+
+        let listener = LastLine(std::sync::Mutex::new(None));
+        let forward = forward_output_lines(Some(&listener), "tofu");
+
+        forward(OutputStream::Stdout, "null_resource.vm: Creating...");
+
+        assert_eq!(
+            listener.0.lock().unwrap().as_deref(),
+            Some("tofu stdout null_resource.vm: Creating...")
+        );
+    }
+
     #[test]
     fn it_should_work_as_optional_trait_object() {
         let listener: Option<&dyn CommandProgressListener> = Some(&NullProgressListener);
@@ -35,9 +35,11 @@
 //! 1. **Preflight cleanup** - Remove any artifacts from previous test runs
 //! 2. **Create environment** - Execute `create environment` CLI command
 //! 3. **Provision infrastructure** - Execute `provision` CLI command (creates LXD VM)
-//! 4. **Configure services** - Execute `configure` CLI command (runs Ansible playbooks)
-//! 5. **Validate deployment** - Execute `test` CLI command (verifies services)
-//! 6. **Destroy infrastructure** - Execute `destroy` CLI command (cleanup)
+//! 4. **Verify SSH access** - Execute `ssh` CLI command with remote commands (exit code propagation)
+//! 5. **Configure services** - Execute `configure` CLI command (runs Ansible playbooks)
+//! 6. **Validate deployment** - Execute `test` CLI command (verifies services)
+//! 7. **Verify tracker stats** - Execute `stats --remote` CLI command (sees the smoke test announce)
+//! 8. **Destroy infrastructure** - Execute `destroy` CLI command (cleanup)
 //!
 //! ## Black-Box Testing Approach
 //!
@@ -74,6 +76,10 @@ struct Cli {
         help = "Logging format: pretty, json, or compact"
     )]
     log_format: LogFormat,
+
+    /// Accept installed tools older than the minimum supported version
+    #[arg(long)]
+    allow_outdated: bool,
 }
 
 /// Main entry point for the full E2E test suite
@@ -108,7 +114,10 @@ fn main() -> Result<()> {
         "Starting full E2E tests (black-box, LOCAL ONLY)"
     );
 
-    verify_required_dependencies(&[Dependency::OpenTofu, Dependency::Ansible, Dependency::Lxd])?;
+    verify_required_dependencies(
+        &[Dependency::OpenTofu, Dependency::Ansible, Dependency::Lxd],
+        cli.allow_outdated,
+    )?;
 
     run_preflight_cleanup(ENVIRONMENT_NAME)?;
 
@@ -198,10 +207,14 @@ fn run_e2e_test_workflow(environment_name: &str, destroy: bool) -> Result<()> {
 
     let config_path = generate_environment_config(environment_name)?;
 
+    test_runner.initialize_workspace()?;
+
     test_runner.create_environment(&config_path)?;
 
     test_runner.provision_infrastructure()?;
 
+    test_runner.verify_ssh_access()?;
+
     test_runner.configure_services()?;
 
     test_runner.release_software()?;
@@ -210,6 +223,8 @@ fn run_e2e_test_workflow(environment_name: &str, destroy: bool) -> Result<()> {
 
     test_runner.validate_deployment()?;
 
+    test_runner.verify_tracker_stats()?;
+
     if destroy {
         test_runner.destroy_infrastructure()?;
     } else {
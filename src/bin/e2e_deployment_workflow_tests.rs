@@ -117,6 +117,10 @@ struct CliArgs {
         help = "Logging format: pretty, json, or compact"
     )]
     log_format: LogFormat,
+
+    /// Accept installed tools older than the minimum supported version
+    #[arg(long)]
+    allow_outdated: bool,
 }
 
 /// Main entry point for E2E configuration tests (black-box approach).
@@ -172,7 +176,7 @@ pub async fn main() -> Result<()> {
     );
 
     // Verify required dependencies before running tests
-    verify_required_dependencies(&[Dependency::Ansible])?;
+    verify_required_dependencies(&[Dependency::Ansible], cli.allow_outdated)?;
 
     let test_start = Instant::now();
 
@@ -300,6 +304,7 @@ async fn run_deployer_workflow(
     write_environment_config(config_env)?;
 
     // Create environment (CLI: cargo run -- create environment --env-file <file>)
+    test_runner.initialize_workspace()?;
     test_runner.create_environment(&config_env.config_file_path)?;
 
     // Register the container's IP as an existing instance with custom SSH port
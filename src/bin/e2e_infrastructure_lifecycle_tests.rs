@@ -30,7 +30,8 @@
 //! 1. **Preflight cleanup** - Remove any artifacts from previous test runs
 //! 2. **Create environment** - Execute `create environment` CLI command
 //! 3. **Provision infrastructure** - Execute `provision` CLI command
-//! 4. **Destroy infrastructure** - Execute `destroy` CLI command
+//! 4. **Verify instance sizing** - Check the configured memory limit in LXD
+//! 5. **Destroy infrastructure** - Execute `destroy` CLI command
 //!
 //! ## Black-Box Testing Approach
 //!
@@ -46,12 +47,16 @@ use tracing::{error, info};
 
 use torrust_tracker_deployer_lib::bootstrap::logging::{LogFormat, LogOutput, LoggingBuilder};
 use torrust_tracker_deployer_lib::testing::e2e::tasks::black_box::{
-    generate_environment_config, run_preflight_cleanup, verify_required_dependencies, E2eTestRunner,
+    build_e2e_test_config, run_preflight_cleanup, verify_required_dependencies,
+    write_environment_config, E2eTestRunner,
 };
 
 // Constants for the e2e-infrastructure environment
 const ENVIRONMENT_NAME: &str = "e2e-infrastructure";
 
+/// Memory limit requested for the instance, checked after provisioning
+const INSTANCE_MEMORY_MB: u32 = 4096;
+
 #[derive(Parser)]
 #[command(name = "e2e-infrastructure-lifecycle-tests")]
 #[command(about = "E2E infrastructure lifecycle tests for Torrust Tracker Deployer")]
@@ -67,6 +72,10 @@ struct Cli {
         help = "Logging format: pretty, json, or compact"
     )]
     log_format: LogFormat,
+
+    /// Accept installed tools older than the minimum supported version
+    #[arg(long)]
+    allow_outdated: bool,
 }
 
 /// Main entry point for the E2E provisioning and destruction test suite
@@ -101,7 +110,7 @@ fn main() -> Result<()> {
         "Starting E2E provisioning and destruction tests (black-box)"
     );
 
-    verify_required_dependencies(&[Dependency::Ansible])?;
+    verify_required_dependencies(&[Dependency::Ansible], cli.allow_outdated)?;
 
     run_preflight_cleanup(ENVIRONMENT_NAME)?;
 
@@ -174,7 +183,8 @@ fn main() -> Result<()> {
 /// Executes the following commands in sequence:
 /// 1. `create environment` - Create the environment from config
 /// 2. `provision` - Provision the infrastructure
-/// 3. `destroy` - Destroy the infrastructure (if `destroy` is true)
+/// 3. Verify the instance got the configured memory limit
+/// 4. `destroy` - Destroy the infrastructure (if `destroy` is true)
 ///
 /// # Arguments
 ///
@@ -187,12 +197,18 @@ fn main() -> Result<()> {
 fn run_e2e_test_workflow(environment_name: &str, destroy: bool) -> Result<()> {
     let test_runner = E2eTestRunner::new(environment_name).with_cleanup_on_failure(destroy);
 
-    let config_path = generate_environment_config(environment_name)?;
+    let config_env =
+        build_e2e_test_config(environment_name).with_instance_memory_mb(INSTANCE_MEMORY_MB);
+    write_environment_config(&config_env)?;
 
-    test_runner.create_environment(&config_path)?;
+    test_runner.initialize_workspace()?;
+
+    test_runner.create_environment(&config_env.config_file_path)?;
 
     test_runner.provision_infrastructure()?;
 
+    test_runner.verify_instance_memory_limit(INSTANCE_MEMORY_MB)?;
+
     if destroy {
         test_runner.destroy_infrastructure()?;
     } else {
@@ -7,9 +7,14 @@
 //! ## Responsibilities
 //!
 //! - **Application Lifecycle**: Initialize and shutdown the application
+//! - **Workspace Resolution**: Choose the workspace from `--workspace`, the
+//!   environment and the global configuration file
 //! - **Logging Setup**: Configure logging based on CLI arguments
 //! - **Command Dispatch**: Route commands to the presentation layer for execution
 //! - **Exit Handling**: Manage application exit codes and cleanup
+//! - **Workspace Check**: Refuse to operate on a directory that is not an initialized workspace
+//! - **Interrupt Handling**: Turn Ctrl-C into a cancellation of the running command
+//! - **Trace Correlation**: Run the command in a root span with the run's trace ID
 //!
 //! ## Design Principles
 //!
@@ -20,22 +25,34 @@
 use std::sync::Arc;
 
 use clap::Parser;
-use tracing::info;
+use tracing::{info, info_span, Instrument};
 
+use crate::application::command_handlers::init::verify_workspace;
 use crate::bootstrap;
+use crate::bootstrap::interrupt::{spawn_interrupt_handler, INTERRUPTED_EXIT_CODE};
+use crate::domain::environment::WorkspaceLayout;
 use crate::presentation::cli::dispatch::route_command;
 use crate::presentation::cli::dispatch::ExecutionContext;
 use crate::presentation::cli::error::handle_error;
+use crate::presentation::cli::errors::CommandError;
+use crate::presentation::cli::input::cli::args::GlobalArgs;
+use crate::presentation::cli::input::Commands;
 use crate::presentation::cli::Cli;
 
 /// Main application entry point
 ///
 /// This function serves as the application bootstrap, handling:
 /// 1. CLI argument parsing (delegated to presentation layer)
-/// 2. Logging initialization using `LoggingConfig`
-/// 3. Service container creation for dependency injection
-/// 4. Command execution (delegated to presentation layer)
-/// 5. Error handling and exit code management
+/// 2. Workspace resolution (see [`bootstrap::workspace`])
+/// 3. Logging initialization using `LoggingConfig`, including the trace export
+///    (with `--otlp-endpoint`), whose failure is reported as a command error
+/// 4. Service container creation for dependency injection
+/// 5. Ctrl-C handler installation (cancels the running command), for the
+///    commands that watch the cancellation token
+/// 6. Workspace check, unless the command does not use the workspace
+/// 7. Command execution (delegated to presentation layer) in the root span
+/// 8. Error handling and exit code management
+/// 9. Export of the pending trace spans (with `--otlp-endpoint`)
 ///
 /// # Panics
 ///
@@ -45,40 +62,131 @@ use crate::presentation::cli::Cli;
 ///
 /// Both panics are intentional as logging is critical for observability.
 pub async fn run() {
-    let cli = Cli::parse();
+    let mut cli = Cli::parse();
 
-    let logging_config = cli.global.logging_config();
+    // Resolved before logging, whose default directory is in the workspace.
+    // An invalid configuration file is reported once the output is set up
+    let workspace = bootstrap::workspace::resolve_workspace(cli.global.workspace.as_deref());
+    if let Ok(resolved) = &workspace {
+        cli.global.working_dir.clone_from(&resolved.path);
+        cli.global.workspace_source = resolved.source.clone();
+    }
 
-    bootstrap::logging::init_subscriber(logging_config);
+    // Shell completion scripts run the callback on every <TAB>: keep it free
+    // of logging, so it neither slows completion down nor writes log files.
+    // A trace export that cannot be set up is reported like the workspace
+    let logging = if matches!(cli.command, Some(Commands::CompleteEnvNames)) {
+        Ok(())
+    } else {
+        bootstrap::logging::init_subscriber(cli.global.logging_config())
+    };
 
     info!(
         app = "torrust-tracker-deployer",
         version = env!("CARGO_PKG_VERSION"),
-        log_dir = %cli.global.log_dir.display(),
+        log_dir = %cli.global.log_dir().display(),
+        workspace = %cli.global.working_dir.display(),
+        workspace_source = %cli.global.workspace_source,
         log_file_format = ?cli.global.log_file_format,
         log_stderr_format = ?cli.global.log_stderr_format,
+        log_format = ?cli.global.log_format,
         log_output = ?cli.global.log_output,
+        otlp_endpoint = ?cli.global.otlp_endpoint.as_ref().map(url::Url::as_str),
         "Application started"
     );
 
     // Initialize service container for dependency injection
-    let container = Arc::new(bootstrap::Container::new(
-        cli.global.verbosity_level(),
-        &cli.global.working_dir,
-    ));
+    let container = Arc::new(
+        bootstrap::Container::with_theme(
+            cli.global.verbosity_level(),
+            cli.global.output_theme(),
+            &cli.global.working_dir,
+        )
+        .with_trace_dir(cli.global.trace_dir.clone()),
+    );
+    let cancellation = container.cancellation_token();
+    if cli
+        .command
+        .as_ref()
+        .is_some_and(Commands::handles_interrupts)
+    {
+        spawn_interrupt_handler(cancellation.clone(), container.user_output());
+    }
+
     let context = ExecutionContext::new(container, cli.global.clone());
 
-    match cli.command {
-        Some(command) => {
-            if let Err(e) = route_command(command, &cli.global.working_dir, &context).await {
-                handle_error(&e, &context.user_output());
-                std::process::exit(1);
+    let exit_code = async {
+        match cli.command {
+            Some(command) => {
+                let result = match logging
+                    .map_err(CommandError::from)
+                    .and_then(|()| workspace.map_err(CommandError::from))
+                    .and_then(|_| check_workspace(&command, &cli.global))
+                {
+                    Ok(()) => route_command(command, &cli.global.working_dir, &context).await,
+                    Err(e) => Err(e),
+                };
+                match result {
+                    Ok(()) => None,
+                    Err(e) => {
+                        handle_error(&e, &context.user_output());
+                        if cancellation.is_cancelled() {
+                            Some(INTERRUPTED_EXIT_CODE)
+                        } else {
+                            Some(1)
+                        }
+                    }
+                }
+            }
+            None => {
+                bootstrap::help::display_getting_started();
+                None
             }
         }
-        None => {
-            bootstrap::help::display_getting_started();
-        }
+    }
+    .instrument(invocation_span())
+    .await;
+
+    if let Some(exit_code) = exit_code {
+        bootstrap::otlp::shutdown();
+        std::process::exit(exit_code);
     }
 
     info!("Application finished");
+
+    bootstrap::otlp::shutdown();
+}
+
+/// Refuse commands using the workspace when the working directory is not one
+///
+/// Reports a wrong workspace (or a missing `init`) before the command starts,
+/// instead of failing on missing files midway, together with how the
+/// workspace was chosen. Skipped with `--allow-uninitialized`.
+fn check_workspace(command: &Commands, global: &GlobalArgs) -> Result<(), CommandError> {
+    if global.allow_uninitialized || !command.requires_workspace() {
+        return Ok(());
+    }
+
+    let layout = WorkspaceLayout::in_working_dir(&global.working_dir);
+    verify_workspace(&global.working_dir, &layout).map_err(|e| CommandError::Workspace {
+        path: global.working_dir.clone(),
+        origin: global.workspace_source.clone(),
+        error: Box::new(e),
+    })?;
+
+    Ok(())
+}
+
+/// Root span of the run, carrying the ID that correlates its log lines
+///
+/// When spans are exported, the ID is the OpenTelemetry trace ID, so the log
+/// lines of a run lead to its trace in the trace backend.
+fn invocation_span() -> tracing::Span {
+    let span = info_span!("cli.invocation", trace_id = tracing::field::Empty);
+
+    let trace_id = bootstrap::otlp::trace_id(&span)
+        .unwrap_or_else(|| format!("{:032x}", rand::random::<u128>()));
+    span.record("trace_id", trace_id.as_str());
+
+    span
 }
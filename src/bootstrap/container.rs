@@ -4,34 +4,74 @@
 //! that need consistent configuration across the entire application.
 
 use std::cell::RefCell;
-use std::path::Path;
+use std::path::{Path, PathBuf};
 use std::sync::Arc;
 
 use parking_lot::ReentrantMutex;
-
-use crate::application::command_handlers::PurgeCommandHandler;
+use tokio_util::sync::CancellationToken;
+
+use crate::application::command_handlers::init::read_marker;
+use crate::application::command_handlers::{
+    BackupCommandHandler, CloneCommandHandler, DestroyCommandHandler, DiffCommandHandler,
+    DoctorCommandHandler, DriftCommandHandler, ExportCommandHandler, ImportBundleCommandHandler,
+    InitCommandHandler, KeysCommandHandler, LabelCommandHandler, ListCommandHandler,
+    LogsCommandHandler, OrphansCommandHandler, PurgeCommandHandler, RenameCommandHandler,
+    ReportsCommandHandler, RestoreCommandHandler, StartCommandHandler, StatsCommandHandler,
+    StopCommandHandler,
+};
 use crate::application::traits::RepositoryProvider;
+use crate::bootstrap::workspace::workspace_key_path;
 use crate::domain::environment::repository::EnvironmentRepository;
-use crate::infrastructure::persistence::file_repository_factory::FileRepositoryFactory;
+use crate::domain::environment::{DiskSpaceThresholds, WorkspaceLayout, WorkspaceMarker};
+use crate::infrastructure::persistence::repository_provider;
+use crate::infrastructure::persistence::workspace_key::WorkspaceKey;
+use crate::presentation::cli::controllers::backup::BackupCommandController;
+use crate::presentation::cli::controllers::clone::CloneCommandController;
+use crate::presentation::cli::controllers::completions::CompletionsCommandController;
 use crate::presentation::cli::controllers::configure::ConfigureCommandController;
 use crate::presentation::cli::controllers::constants::DEFAULT_LOCK_TIMEOUT;
 use crate::presentation::cli::controllers::create::subcommands::environment::CreateEnvironmentCommandController;
 use crate::presentation::cli::controllers::create::subcommands::schema::CreateSchemaCommandController;
 use crate::presentation::cli::controllers::create::subcommands::template::CreateTemplateCommandController;
+use crate::presentation::cli::controllers::create::subcommands::wizard::CreateWizardCommandController;
+use crate::presentation::cli::controllers::deploy::DeployCommandController;
 use crate::presentation::cli::controllers::destroy::DestroyCommandController;
+use crate::presentation::cli::controllers::diff::DiffCommandController;
 use crate::presentation::cli::controllers::docs::DocsCommandController;
+use crate::presentation::cli::controllers::doctor::DoctorCommandController;
+use crate::presentation::cli::controllers::drift::DriftCommandController;
 use crate::presentation::cli::controllers::exists::ExistsCommandController;
+use crate::presentation::cli::controllers::export::ExportCommandController;
+use crate::presentation::cli::controllers::import::ImportCommandController;
+use crate::presentation::cli::controllers::import_bundle::ImportBundleCommandController;
+use crate::presentation::cli::controllers::init::InitCommandController;
+use crate::presentation::cli::controllers::keys::subcommands::add::KeysAddCommandController;
+use crate::presentation::cli::controllers::keys::subcommands::list::KeysListCommandController;
+use crate::presentation::cli::controllers::keys::subcommands::remove::KeysRemoveCommandController;
+use crate::presentation::cli::controllers::label::LabelCommandController;
 use crate::presentation::cli::controllers::list::ListCommandController;
+use crate::presentation::cli::controllers::logs::LogsCommandController;
+use crate::presentation::cli::controllers::orphans::OrphansCommandController;
+use crate::presentation::cli::controllers::probe::subcommands::udp::ProbeUdpCommandController;
 use crate::presentation::cli::controllers::provision::ProvisionCommandController;
 use crate::presentation::cli::controllers::purge::PurgeCommandController;
 use crate::presentation::cli::controllers::register::RegisterCommandController;
 use crate::presentation::cli::controllers::release::ReleaseCommandController;
+use crate::presentation::cli::controllers::rename::RenameCommandController;
 use crate::presentation::cli::controllers::render::RenderCommandController;
+use crate::presentation::cli::controllers::repair::RepairCommandController;
+use crate::presentation::cli::controllers::reports::ReportsCommandController;
+use crate::presentation::cli::controllers::restore::RestoreCommandController;
+use crate::presentation::cli::controllers::rollback::RollbackCommandController;
 use crate::presentation::cli::controllers::run::RunCommandController;
 use crate::presentation::cli::controllers::show::ShowCommandController;
+use crate::presentation::cli::controllers::ssh::SshCommandController;
+use crate::presentation::cli::controllers::start::StartCommandController;
+use crate::presentation::cli::controllers::stats::StatsCommandController;
+use crate::presentation::cli::controllers::stop::StopCommandController;
 use crate::presentation::cli::controllers::test::handler::TestCommandController;
 use crate::presentation::cli::controllers::validate::ValidateCommandController;
-use crate::presentation::cli::views::{UserOutput, VerbosityLevel};
+use crate::presentation::cli::views::{Theme, UserOutput, VerbosityLevel};
 use crate::shared::clock::Clock;
 use crate::shared::SystemClock;
 
@@ -56,10 +96,15 @@ use crate::shared::SystemClock;
 #[derive(Clone)]
 pub struct Container {
     user_output: Arc<ReentrantMutex<RefCell<UserOutput>>>,
-    file_repository_factory: Arc<FileRepositoryFactory>,
+    repository_provider: Arc<dyn RepositoryProvider>,
     repository: Arc<dyn EnvironmentRepository + Send + Sync>,
     clock: Arc<dyn Clock>,
     data_directory: Arc<Path>,
+    cancellation: CancellationToken,
+    kept_run_logs: usize,
+    disk_space_thresholds: DiskSpaceThresholds,
+    workspace_key: Option<Arc<WorkspaceKey>>,
+    trace_dir: Option<PathBuf>,
 }
 
 impl Container {
@@ -67,9 +112,15 @@ impl Container {
     ///
     /// Initializes all services with specified verbosity level and working directory:
     /// - `UserOutput` with provided `verbosity_level`
-    /// - `FileRepositoryFactory` with `DEFAULT_LOCK_TIMEOUT`
+    /// - `RepositoryProvider` of the storage backend set in the workspace marker
+    ///   (JSON files when there is no marker), with `DEFAULT_LOCK_TIMEOUT`
     /// - `EnvironmentRepository` using `working_dir/data` as base directory
     /// - `SystemClock` for time operations
+    /// - `CancellationToken` shared by long-running commands (cancelled on Ctrl-C)
+    /// - The number of runs whose tool output logs are kept, from the marker
+    /// - The minimum free disk space checked before writing, from the marker
+    /// - The `WorkspaceKey` sealing the secrets of the environments, at
+    ///   `workspace_key_path()`
     ///
     /// # Arguments
     ///
@@ -91,27 +142,83 @@ impl Container {
     /// ```
     #[must_use]
     pub fn new(verbosity_level: VerbosityLevel, working_dir: &Path) -> Self {
-        let user_output = Arc::new(ReentrantMutex::new(RefCell::new(UserOutput::new(
+        Self::with_theme(verbosity_level, Theme::default(), working_dir)
+    }
+
+    /// Create a new container whose user output uses the given theme
+    ///
+    /// Same as [`Container::new`], for callers choosing the output symbols,
+    /// e.g. plain text labels when the output is not a terminal.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use std::path::Path;
+    /// use torrust_tracker_deployer_lib::bootstrap::container::Container;
+    /// use torrust_tracker_deployer_lib::presentation::cli::views::{Theme, VerbosityLevel};
+    ///
+    /// let container = Container::with_theme(VerbosityLevel::Quiet, Theme::plain(), Path::new("."));
+    /// ```
+    #[must_use]
+    pub fn with_theme(verbosity_level: VerbosityLevel, theme: Theme, working_dir: &Path) -> Self {
+        let user_output = Arc::new(ReentrantMutex::new(RefCell::new(UserOutput::with_theme(
             verbosity_level,
+            theme,
         ))));
-        let file_repository_factory = Arc::new(FileRepositoryFactory::new(DEFAULT_LOCK_TIMEOUT));
+        // An unreadable marker is reported by the workspace check, not here
+        let marker = read_marker(working_dir)
+            .ok()
+            .flatten()
+            .unwrap_or_else(WorkspaceMarker::current);
+        let workspace_key = workspace_key_path().map(|path| Arc::new(WorkspaceKey::new(path)));
+        let repository_provider = repository_provider(
+            marker.repository,
+            DEFAULT_LOCK_TIMEOUT,
+            workspace_key.clone(),
+        );
 
         // Create repository once for the entire application
         let data_dir = working_dir.join("data");
         let data_directory: Arc<Path> = Arc::from(data_dir.as_path());
-        let repository = file_repository_factory.create(data_dir);
+        let repository = repository_provider.create(data_dir);
 
         let clock: Arc<dyn Clock> = Arc::new(SystemClock);
 
         Self {
             user_output,
-            file_repository_factory,
+            repository_provider,
             repository,
             clock,
             data_directory,
+            cancellation: CancellationToken::new(),
+            kept_run_logs: marker.kept_run_logs,
+            disk_space_thresholds: marker.min_free_disk_space,
+            workspace_key,
+            trace_dir: None,
         }
     }
 
+    /// Write the trace files of the failed commands to `trace_dir`
+    ///
+    /// Overrides, for this invocation, the traces directory of the
+    /// environment configuration (`data/{env}/traces/` by default).
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use std::path::{Path, PathBuf};
+    /// use torrust_tracker_deployer_lib::bootstrap::container::Container;
+    /// use torrust_tracker_deployer_lib::presentation::cli::views::VerbosityLevel;
+    ///
+    /// let container = Container::new(VerbosityLevel::Normal, Path::new("."))
+    ///     .with_trace_dir(Some(PathBuf::from("artifacts/traces")));
+    /// ```
+    #[must_use]
+    pub fn with_trace_dir(mut self, trace_dir: Option<PathBuf>) -> Self {
+        self.trace_dir = trace_dir;
+        self
+    }
+
     /// Get shared reference to user output service
     ///
     /// Returns an `Arc<ReentrantMutex<RefCell<UserOutput>>>` that can be safely cloned and shared
@@ -135,10 +242,11 @@ impl Container {
         Arc::clone(&self.user_output)
     }
 
-    /// Get shared reference to repository factory service
+    /// Get shared reference to repository provider
     ///
-    /// Returns an `Arc<FileRepositoryFactory>` that can be cheaply cloned and shared
-    /// across threads and function calls.
+    /// Returns an `Arc<dyn RepositoryProvider>` that can be passed to application-layer
+    /// handlers without exposing the concrete infrastructure type. It creates
+    /// repositories of the storage backend of the workspace.
     ///
     /// # Example
     ///
@@ -148,21 +256,12 @@ impl Container {
     /// use torrust_tracker_deployer_lib::presentation::cli::views::VerbosityLevel;
     ///
     /// let container = Container::new(VerbosityLevel::Normal, Path::new("."));
-    /// let file_repository_factory = container.file_repository_factory();
-    /// // Use file_repository_factory to create repositories
+    /// let repository_provider = container.repository_provider();
+    /// // Use repository_provider to create repositories
     /// ```
     #[must_use]
-    pub fn file_repository_factory(&self) -> Arc<FileRepositoryFactory> {
-        Arc::clone(&self.file_repository_factory)
-    }
-
-    /// Get shared reference to repository provider
-    ///
-    /// Returns an `Arc<dyn RepositoryProvider>` that can be passed to application-layer
-    /// handlers without exposing the concrete infrastructure type.
-    #[must_use]
     pub fn repository_provider(&self) -> Arc<dyn RepositoryProvider> {
-        Arc::clone(&self.file_repository_factory) as Arc<dyn RepositoryProvider>
+        Arc::clone(&self.repository_provider)
     }
 
     /// Get shared reference to environment repository
@@ -208,6 +307,16 @@ impl Container {
         Arc::clone(&self.clock)
     }
 
+    /// Get the cancellation token shared by long-running commands
+    ///
+    /// The bootstrap layer cancels this token when the user presses Ctrl-C;
+    /// command handlers stop at the next step boundary and persist a failed
+    /// state.
+    #[must_use]
+    pub fn cancellation_token(&self) -> CancellationToken {
+        self.cancellation.clone()
+    }
+
     /// Create a new `CreateEnvironmentCommandController`
     #[must_use]
     pub fn create_environment_controller(&self) -> CreateEnvironmentCommandController {
@@ -218,6 +327,12 @@ impl Container {
         )
     }
 
+    /// Create a new `CreateWizardCommandController`
+    #[must_use]
+    pub fn create_wizard_controller(&self) -> CreateWizardCommandController {
+        CreateWizardCommandController::new(self.repository(), self.clock(), &self.user_output())
+    }
+
     /// Create a new `CreateTemplateCommandController`
     #[must_use]
     pub fn create_template_controller(&self) -> CreateTemplateCommandController {
@@ -236,30 +351,143 @@ impl Container {
         DocsCommandController::new(&self.user_output())
     }
 
+    /// Create a new `CompletionsCommandController`
+    #[must_use]
+    pub fn create_completions_controller(&self) -> CompletionsCommandController {
+        CompletionsCommandController::new(self.repository(), self.user_output())
+    }
+
     /// Create a new `ProvisionCommandController`
     #[must_use]
     pub fn create_provision_controller(&self) -> ProvisionCommandController {
+        let list_handler = ListCommandHandler::new(
+            self.repository_provider(),
+            self.data_directory(),
+            self.clock(),
+        );
         ProvisionCommandController::new(self.repository(), self.clock(), self.user_output())
+            .with_cancellation(self.cancellation_token())
+            .with_list_handler(list_handler)
+            .with_kept_run_logs(self.kept_run_logs)
+            .with_trace_dir(self.trace_dir.clone())
+            .with_disk_space_thresholds(self.disk_space_thresholds)
     }
 
     /// Create a new `DestroyCommandController`
     #[must_use]
     pub fn create_destroy_controller(&self) -> DestroyCommandController {
+        let list_handler = ListCommandHandler::new(
+            self.repository_provider(),
+            self.data_directory(),
+            self.clock(),
+        );
         DestroyCommandController::new(self.repository(), self.clock(), self.user_output())
+            .with_list_handler(list_handler)
+            .with_kept_run_logs(self.kept_run_logs)
     }
 
     /// Create a new `PurgeCommandController`
     #[must_use]
     pub fn create_purge_controller(&self) -> PurgeCommandController {
+        let working_dir = self
+            .data_directory
+            .parent()
+            .unwrap_or_else(|| Path::new("."))
+            .to_path_buf();
         let handler =
-            PurgeCommandHandler::new(self.repository(), (*self.data_directory).to_path_buf());
-        PurgeCommandController::new(handler, self.user_output())
+            PurgeCommandHandler::new(self.repository(), working_dir).with_clock(self.clock());
+        let destroy_handler = DestroyCommandHandler::new(self.repository(), self.clock())
+            .with_kept_run_logs(self.kept_run_logs);
+        PurgeCommandController::new(handler, destroy_handler, self.user_output())
+    }
+
+    /// Create a new `InitCommandController`
+    #[must_use]
+    pub fn create_init_controller(&self) -> InitCommandController {
+        let working_dir = self
+            .data_directory
+            .parent()
+            .unwrap_or_else(|| Path::new("."))
+            .to_path_buf();
+        let mut handler = InitCommandHandler::new(self.repository(), working_dir.clone());
+        if let Some(workspace_key) = &self.workspace_key {
+            handler = handler.with_workspace_key(Arc::clone(workspace_key));
+        }
+        InitCommandController::new(handler, working_dir, self.user_output())
+    }
+
+    /// Create a new `RenameCommandController`
+    #[must_use]
+    pub fn create_rename_controller(&self) -> RenameCommandController {
+        let working_dir = self
+            .data_directory
+            .parent()
+            .unwrap_or_else(|| Path::new("."))
+            .to_path_buf();
+        let handler = RenameCommandHandler::new(self.repository(), working_dir);
+        RenameCommandController::new(handler, self.user_output())
+    }
+
+    /// Create a new `ExportCommandController`
+    #[must_use]
+    pub fn create_export_controller(&self) -> ExportCommandController {
+        let handler = ExportCommandHandler::new(self.repository(), self.clock());
+        ExportCommandController::new(handler, self.user_output())
+    }
+
+    /// Create a new `ImportBundleCommandController`
+    #[must_use]
+    pub fn create_import_bundle_controller(&self) -> ImportBundleCommandController {
+        let working_dir = self
+            .data_directory
+            .parent()
+            .unwrap_or_else(|| Path::new("."))
+            .to_path_buf();
+        let handler = ImportBundleCommandHandler::new(self.repository(), working_dir);
+        ImportBundleCommandController::new(handler, self.user_output())
+    }
+
+    /// Create a new `LabelCommandController`
+    #[must_use]
+    pub fn create_label_controller(&self) -> LabelCommandController {
+        let handler = LabelCommandHandler::new(self.repository());
+        LabelCommandController::new(handler, self.user_output())
+    }
+
+    /// Create a new `KeysAddCommandController`
+    #[must_use]
+    pub fn create_keys_add_controller(&self) -> KeysAddCommandController {
+        let handler = KeysCommandHandler::new(self.repository(), self.clock());
+        KeysAddCommandController::new(handler, self.user_output())
+    }
+
+    /// Create a new `KeysListCommandController`
+    #[must_use]
+    pub fn create_keys_list_controller(&self) -> KeysListCommandController {
+        let handler = KeysCommandHandler::new(self.repository(), self.clock());
+        KeysListCommandController::new(handler, self.user_output())
+    }
+
+    /// Create a new `KeysRemoveCommandController`
+    #[must_use]
+    pub fn create_keys_remove_controller(&self) -> KeysRemoveCommandController {
+        let handler = KeysCommandHandler::new(self.repository(), self.clock());
+        KeysRemoveCommandController::new(handler, self.user_output())
     }
 
     /// Create a new `ConfigureCommandController`
     #[must_use]
     pub fn create_configure_controller(&self) -> ConfigureCommandController {
+        let list_handler = ListCommandHandler::new(
+            self.repository_provider(),
+            self.data_directory(),
+            self.clock(),
+        );
         ConfigureCommandController::new(self.repository(), self.clock(), self.user_output())
+            .with_cancellation(self.cancellation_token())
+            .with_list_handler(list_handler)
+            .with_kept_run_logs(self.kept_run_logs)
+            .with_trace_dir(self.trace_dir.clone())
     }
 
     /// Create a new `TestCommandController`
@@ -284,18 +512,80 @@ impl Container {
     #[must_use]
     pub fn create_release_controller(&self) -> ReleaseCommandController {
         ReleaseCommandController::new(self.repository(), self.clock(), self.user_output())
+            .with_kept_run_logs(self.kept_run_logs)
+            .with_trace_dir(self.trace_dir.clone())
+            .with_disk_space_thresholds(self.disk_space_thresholds)
+    }
+
+    /// Create a new `RollbackCommandController`
+    #[must_use]
+    pub fn create_rollback_controller(&self) -> RollbackCommandController {
+        RollbackCommandController::new(self.repository(), self.clock(), self.user_output())
+            .with_kept_run_logs(self.kept_run_logs)
+            .with_trace_dir(self.trace_dir.clone())
+            .with_disk_space_thresholds(self.disk_space_thresholds)
+    }
+
+    /// Create a new `BackupCommandController`
+    #[must_use]
+    pub fn create_backup_controller(&self) -> BackupCommandController {
+        let handler = BackupCommandHandler::new(self.repository(), self.clock());
+        BackupCommandController::new(handler, self.user_output())
+    }
+
+    /// Create a new `DriftCommandController`
+    #[must_use]
+    pub fn create_drift_controller(&self) -> DriftCommandController {
+        let handler = DriftCommandHandler::new(self.repository(), self.clock());
+        DriftCommandController::new(handler, self.user_output())
+    }
+
+    /// Create a new `RestoreCommandController`
+    #[must_use]
+    pub fn create_restore_controller(&self) -> RestoreCommandController {
+        let handler = RestoreCommandHandler::new(self.repository())
+            .with_cancellation(self.cancellation_token());
+        RestoreCommandController::new(handler, self.user_output())
     }
 
     /// Create a new `RenderCommandController`
     #[must_use]
     pub fn create_render_controller(&self) -> RenderCommandController {
-        RenderCommandController::new(self.repository(), self.user_output())
+        RenderCommandController::new(self.repository(), self.clock(), self.user_output())
     }
 
     /// Create a new `RunCommandController`
     #[must_use]
     pub fn create_run_controller(&self) -> RunCommandController {
         RunCommandController::new(self.repository(), self.clock(), self.user_output())
+            .with_cancellation(self.cancellation_token())
+            .with_kept_run_logs(self.kept_run_logs)
+            .with_trace_dir(self.trace_dir.clone())
+    }
+
+    /// Create a new `StopCommandController`
+    #[must_use]
+    pub fn create_stop_controller(&self) -> StopCommandController {
+        let handler = StopCommandHandler::new(self.repository());
+        StopCommandController::new(handler, self.user_output())
+    }
+
+    /// Create a new `StartCommandController`
+    #[must_use]
+    pub fn create_start_controller(&self) -> StartCommandController {
+        let handler = StartCommandHandler::new(self.repository())
+            .with_cancellation(self.cancellation_token());
+        StartCommandController::new(handler, self.user_output())
+    }
+
+    /// Create a new `DeployCommandController`
+    #[must_use]
+    pub fn create_deploy_controller(&self) -> DeployCommandController {
+        DeployCommandController::new(self.repository(), self.clock(), self.user_output())
+            .with_cancellation(self.cancellation_token())
+            .with_kept_run_logs(self.kept_run_logs)
+            .with_trace_dir(self.trace_dir.clone())
+            .with_disk_space_thresholds(self.disk_space_thresholds)
     }
 
     /// Create a new `ShowCommandController`
@@ -304,6 +594,12 @@ impl Container {
         ShowCommandController::new(self.repository(), self.user_output())
     }
 
+    /// Create a new `SshCommandController`
+    #[must_use]
+    pub fn create_ssh_controller(&self) -> SshCommandController {
+        SshCommandController::new(self.repository())
+    }
+
     /// Create a new `ExistsCommandController`
     #[must_use]
     pub fn create_exists_controller(&self) -> ExistsCommandController {
@@ -316,6 +612,112 @@ impl Container {
         ListCommandController::new(
             self.repository_provider(),
             self.data_directory(),
+            self.clock(),
+            self.user_output(),
+        )
+    }
+
+    /// Create a new `OrphansCommandController`
+    #[must_use]
+    pub fn create_orphans_controller(&self) -> OrphansCommandController {
+        let working_dir = self
+            .data_directory
+            .parent()
+            .unwrap_or_else(|| Path::new("."))
+            .to_path_buf();
+        let handler = OrphansCommandHandler::new(self.repository(), working_dir);
+        OrphansCommandController::new(handler, self.user_output())
+    }
+
+    /// Create a new `DoctorCommandController`
+    #[must_use]
+    pub fn create_doctor_controller(&self) -> DoctorCommandController {
+        let working_dir = self
+            .data_directory
+            .parent()
+            .unwrap_or_else(|| Path::new("."))
+            .to_path_buf();
+        let handler = DoctorCommandHandler::new(self.repository(), working_dir)
+            .with_disk_space_thresholds(self.disk_space_thresholds);
+        DoctorCommandController::new(handler, self.user_output())
+    }
+
+    /// Create a new `ProbeUdpCommandController`
+    #[must_use]
+    pub fn create_probe_udp_controller(&self) -> ProbeUdpCommandController {
+        ProbeUdpCommandController::new(self.user_output())
+    }
+
+    /// Create a new `RepairCommandController`
+    #[must_use]
+    pub fn create_repair_controller(&self) -> RepairCommandController {
+        RepairCommandController::new(self.repository(), self.user_output())
+    }
+
+    /// Create a new `ReportsCommandController`
+    #[must_use]
+    pub fn create_reports_controller(&self) -> ReportsCommandController {
+        let handler = ReportsCommandHandler::new(self.repository());
+        ReportsCommandController::new(handler, self.user_output())
+    }
+
+    /// Create a new `DiffCommandController`
+    #[must_use]
+    pub fn create_diff_controller(&self) -> DiffCommandController {
+        let working_dir = self
+            .data_directory
+            .parent()
+            .unwrap_or_else(|| Path::new("."));
+        let handler = DiffCommandHandler::new(
+            self.repository(),
+            WorkspaceLayout::in_working_dir(working_dir),
+        );
+        DiffCommandController::new(handler, self.user_output())
+    }
+
+    /// Create a new `LogsCommandController`
+    #[must_use]
+    pub fn create_logs_controller(&self) -> LogsCommandController {
+        let handler = LogsCommandHandler::new(self.repository());
+        LogsCommandController::new(handler, self.user_output())
+    }
+
+    /// Create a new `StatsCommandController`
+    #[must_use]
+    pub fn create_stats_controller(&self) -> StatsCommandController {
+        let list_handler = ListCommandHandler::new(
+            self.repository_provider(),
+            self.data_directory(),
+            self.clock(),
+        );
+        let handler = StatsCommandHandler::new(self.repository(), list_handler);
+        StatsCommandController::new(handler, self.user_output())
+    }
+
+    /// Create a new `CloneCommandController`
+    #[must_use]
+    pub fn create_clone_controller(&self) -> CloneCommandController {
+        let working_dir = self
+            .data_directory
+            .parent()
+            .unwrap_or_else(|| Path::new("."))
+            .to_path_buf();
+        let handler = CloneCommandHandler::new(self.repository(), self.clock());
+        CloneCommandController::new(handler, working_dir, self.user_output())
+    }
+
+    /// Create a new `ImportCommandController`
+    #[must_use]
+    pub fn create_import_controller(&self) -> ImportCommandController {
+        let working_dir = self
+            .data_directory
+            .parent()
+            .unwrap_or_else(|| Path::new("."))
+            .to_path_buf();
+        ImportCommandController::new(
+            self.repository(),
+            self.clock(),
+            working_dir,
             self.user_output(),
         )
     }
@@ -348,24 +750,24 @@ mod tests {
 
         // Verify we can get all services
         let user_output = container.user_output();
-        let file_repository_factory = container.file_repository_factory();
+        let repository_provider = container.repository_provider();
         let repository = container.repository();
         let clock = container.clock();
 
         assert!(Arc::strong_count(&user_output) >= 1);
-        assert!(Arc::strong_count(&file_repository_factory) >= 1);
+        assert!(Arc::strong_count(&repository_provider) >= 1);
         assert!(Arc::strong_count(&repository) >= 1);
         assert!(Arc::strong_count(&clock) >= 1);
     }
 
     #[test]
-    fn it_should_return_cloned_arc_on_file_repository_factory_access() {
+    fn it_should_return_cloned_arc_on_repository_provider_access() {
         let temp_dir = TempDir::new().unwrap();
         let container = Container::new(VerbosityLevel::Normal, temp_dir.path());
-        let factory1 = container.file_repository_factory();
-        let factory2 = container.file_repository_factory();
+        let factory1 = container.repository_provider();
+        let factory2 = container.repository_provider();
 
-        // Both should point to the same FileRepositoryFactory instance
+        // Both should point to the same RepositoryProvider instance
         assert!(Arc::ptr_eq(&factory1, &factory2));
     }
 
@@ -413,8 +815,8 @@ mod tests {
         let user_output2 = container2.user_output();
         assert!(Arc::ptr_eq(&user_output1, &user_output2));
 
-        let factory1 = container1.file_repository_factory();
-        let factory2 = container2.file_repository_factory();
+        let factory1 = container1.repository_provider();
+        let factory2 = container2.repository_provider();
         assert!(Arc::ptr_eq(&factory1, &factory2));
 
         let repo1 = container1.repository();
@@ -433,12 +835,12 @@ mod tests {
 
         // All services should be available
         let user_output = container.user_output();
-        let file_repository_factory = container.file_repository_factory();
+        let repository_provider = container.repository_provider();
         let repository = container.repository();
         let clock = container.clock();
 
         assert!(Arc::strong_count(&user_output) >= 1);
-        assert!(Arc::strong_count(&file_repository_factory) >= 1);
+        assert!(Arc::strong_count(&repository_provider) >= 1);
         assert!(Arc::strong_count(&repository) >= 1);
         assert!(Arc::strong_count(&clock) >= 1);
 
@@ -465,12 +867,12 @@ mod tests {
 
             // All services should be available regardless of verbosity level
             let user_output = container.user_output();
-            let file_repository_factory = container.file_repository_factory();
+            let repository_provider = container.repository_provider();
             let repository = container.repository();
             let clock = container.clock();
 
             assert!(Arc::strong_count(&user_output) >= 1);
-            assert!(Arc::strong_count(&file_repository_factory) >= 1);
+            assert!(Arc::strong_count(&repository_provider) >= 1);
             assert!(Arc::strong_count(&repository) >= 1);
             assert!(Arc::strong_count(&clock) >= 1);
         }
@@ -0,0 +1,66 @@
+//! Ctrl-C (SIGINT) Handling
+//!
+//! Long-running commands (`provision`, `configure`, `deploy`, `run`, `start`,
+//! `restore`) run external tools, wait on the instance and persist state
+//! transitions. Killing the process on Ctrl-C would leave the
+//! environment in a transient state (e.g. `Provisioning`) that later commands
+//! refuse to touch.
+//!
+//! Instead, the first Ctrl-C cancels a shared `CancellationToken`:
+//!
+//! - The running external tool receives the same `SIGINT` from the terminal
+//!   and shuts down on its own. Tools run with a timeout live in their own
+//!   process group, so the signal is forwarded to them
+//! - The command handler stops at the next step boundary, or stops waiting
+//!   for the tracker health, and persists its failed state
+//!
+//! The handler is only installed for commands that watch the token (see
+//! `Commands::handles_interrupts`); Ctrl-C terminates the other commands.
+//!
+//! A second Ctrl-C exits immediately for users who do not want to wait.
+
+use std::cell::RefCell;
+use std::process;
+use std::sync::Arc;
+
+use parking_lot::ReentrantMutex;
+use tokio_util::sync::CancellationToken;
+use tracing::warn;
+
+use crate::presentation::cli::views::UserOutput;
+use crate::shared::command::interrupt_running_commands;
+
+/// Exit code used when the application stops because of Ctrl-C (`128 + SIGINT`)
+pub const INTERRUPTED_EXIT_CODE: i32 = 130;
+
+/// Spawn a background task that cancels `cancellation` on the first Ctrl-C
+///
+/// A second Ctrl-C terminates the process with [`INTERRUPTED_EXIT_CODE`]
+/// without waiting for the command to persist its state.
+///
+/// The user is told through `user_output` that the command is stopping.
+///
+/// Must be called from within a Tokio runtime.
+pub fn spawn_interrupt_handler(
+    cancellation: CancellationToken,
+    user_output: Arc<ReentrantMutex<RefCell<UserOutput>>>,
+) {
+    tokio::spawn(async move {
+        if tokio::signal::ctrl_c().await.is_err() {
+            warn!("Failed to listen for Ctrl-C, interrupts will terminate the process");
+            return;
+        }
+
+        warn!("Interrupt received, stopping after the current step (press Ctrl-C again to force exit)");
+        user_output.lock().borrow_mut().warn(
+            "Interrupted: stopping after the current step and saving the environment state...",
+        );
+        cancellation.cancel();
+        interrupt_running_commands();
+
+        if tokio::signal::ctrl_c().await.is_ok() {
+            warn!("Second interrupt received, exiting immediately");
+            process::exit(INTERRUPTED_EXIT_CODE);
+        }
+    });
+}
@@ -5,6 +5,21 @@
 //! - Steps (Level 2): Mid-level execution units  
 //! - Remote Actions (Level 3): Leaf-level operations
 //!
+//! ## Span Names
+//!
+//! Spans are named after their level so that traces can be filtered by it:
+//!
+//! - `cli.invocation` - one CLI run, with the `trace_id` that correlates its log lines
+//! - `command.{name}` - command handlers (e.g. `command.provision`), with `environment_name`
+//! - `step.{name}` - steps (e.g. `step.tofu_apply`)
+//! - `remote_action.{name}` - actions run on the instance
+//! - `tool` - external tool invocations, exported as `tool.{program}` (e.g. `tool.tofu`)
+//!
+//! ## Trace Export
+//!
+//! With an OTLP endpoint (`--otlp-endpoint`), the spans are also exported to an
+//! OpenTelemetry collector. See [`crate::bootstrap::otlp`].
+//!
 //! ## Persistent Logging
 //!
 //! All logs are always written to a log file for persistent storage.
@@ -52,9 +67,14 @@
 //! init_compact(Path::new("/tmp/test-xyz/data/logs"), LogOutput::FileAndStderr);
 //! ```
 
+use opentelemetry_sdk::trace::Tracer;
 use std::io;
 use std::path::Path;
-use tracing_subscriber::{fmt, layer::SubscriberExt, util::SubscriberInitExt, EnvFilter};
+use tracing_opentelemetry::OpenTelemetryLayer;
+use tracing_subscriber::{fmt, layer::SubscriberExt, util::SubscriberInitExt, EnvFilter, Registry};
+use url::Url;
+
+use crate::bootstrap::otlp::{self, OtlpError};
 
 /// Log file name used by the logging system
 pub const LOG_FILE_NAME: &str = "log.txt";
@@ -107,6 +127,12 @@ pub struct LoggingConfig {
 
     /// Output target (file-only vs file-and-stderr)
     pub output: LogOutput,
+
+    /// OTLP/HTTP collector the spans are exported to (no export if `None`)
+    pub otlp_endpoint: Option<Url>,
+
+    /// Whether stderr output uses ANSI color codes (file output never does)
+    pub stderr_ansi: bool,
 }
 
 impl LoggingConfig {
@@ -130,8 +156,29 @@ impl LoggingConfig {
             file_format,
             stderr_format,
             output,
+            otlp_endpoint: None,
+            stderr_ansi: true,
         }
     }
+
+    /// Export the spans to an OTLP/HTTP collector, in addition to logging
+    ///
+    /// See [`crate::bootstrap::otlp`] for the endpoint format.
+    #[must_use]
+    pub fn with_otlp_endpoint(mut self, endpoint: Url) -> Self {
+        self.otlp_endpoint = Some(endpoint);
+        self
+    }
+
+    /// Enable or disable ANSI color codes in stderr output
+    ///
+    /// Enabled by default. Disabled when colors are not wanted, e.g. when
+    /// stderr is not a terminal or `NO_COLOR` is set.
+    #[must_use]
+    pub fn with_stderr_ansi(mut self, stderr_ansi: bool) -> Self {
+        self.stderr_ansi = stderr_ansi;
+        self
+    }
 }
 
 // ============================================================================
@@ -271,7 +318,7 @@ impl LoggingBuilder {
             self.stderr_format,
             self.output,
         );
-        init_subscriber(config);
+        install_subscriber(config, None);
     }
 }
 
@@ -290,18 +337,21 @@ impl LoggingBuilder {
 ///
 /// Automatically configures ANSI codes:
 /// - File output: ANSI codes disabled (clean text for parsing)
-/// - Stderr output: ANSI codes enabled (colored terminal output)
+/// - Stderr output: ANSI codes enabled (colored terminal output), unless
+///   disabled with [`LoggingConfig::with_stderr_ansi`]
 ///
-/// Note: We cannot extract the format-specific layer creation into a separate
-/// function because each format (Pretty, Json, Compact) creates a different
-/// concrete type, and Rust's type system requires all match arms to return
-/// the same type. Type erasure with boxed layers would work but adds runtime
-/// overhead for a one-time initialization cost.
+/// With an OTLP endpoint, an additional layer exports the spans. When the
+/// exporter cannot be created, logging is still set up, without the export,
+/// and the error is returned for the caller to report.
 ///
 /// # Arguments
 ///
 /// * `config` - The logging configuration containing all settings
 ///
+/// # Errors
+///
+/// Returns an error if the OTLP exporter cannot be created.
+///
 /// # Panics
 ///
 /// Panics if:
@@ -316,6 +366,7 @@ impl LoggingBuilder {
 /// use std::path::PathBuf;
 /// use torrust_tracker_deployer_lib::bootstrap::logging::{LogFormat, LogOutput, LoggingConfig, init_subscriber};
 ///
+/// # fn main() -> Result<(), Box<dyn std::error::Error>> {
 /// let config = LoggingConfig::new(
 ///     PathBuf::from("./data/logs"),
 ///     LogFormat::Compact,
@@ -323,10 +374,41 @@ impl LoggingBuilder {
 ///     LogOutput::FileAndStderr,
 /// );
 ///
-/// init_subscriber(config);
+/// init_subscriber(config)?;
+/// # Ok(())
+/// # }
 /// ```
+pub fn init_subscriber(config: LoggingConfig) -> Result<(), OtlpError> {
+    let (otlp_layer, result) = match config.otlp_endpoint.as_ref().map(otlp::layer).transpose() {
+        Ok(otlp_layer) => (otlp_layer, Ok(())),
+        Err(e) => (None, Err(e)),
+    };
+
+    install_subscriber(config, otlp_layer);
+
+    result
+}
+
+/// Install the global subscriber, with the span export layer if any
+///
+/// The export layer is the innermost layer so that it has the same type in
+/// every format combination.
+///
+/// Note: We cannot extract the format-specific layer creation into a separate
+/// function because each format (Pretty, Json, Compact) creates a different
+/// concrete type, and Rust's type system requires all match arms to return
+/// the same type. Type erasure with boxed layers would work but adds runtime
+/// overhead for a one-time initialization cost.
+///
+/// # Panics
+///
+/// Panics if the log directory cannot be created or a subscriber was already
+/// initialized.
 #[allow(clippy::too_many_lines)]
-pub fn init_subscriber(config: LoggingConfig) {
+fn install_subscriber(
+    config: LoggingConfig,
+    otlp_layer: Option<OpenTelemetryLayer<Registry, Tracer>>,
+) {
     let file_appender = create_log_file_appender(&config.log_dir);
     let env_filter = create_env_filter();
 
@@ -336,6 +418,7 @@ pub fn init_subscriber(config: LoggingConfig) {
             match config.file_format {
                 LogFormat::Pretty => {
                     tracing_subscriber::registry()
+                        .with(otlp_layer)
                         .with(
                             fmt::layer()
                                 .pretty()
@@ -347,6 +430,7 @@ pub fn init_subscriber(config: LoggingConfig) {
                 }
                 LogFormat::Json => {
                     tracing_subscriber::registry()
+                        .with(otlp_layer)
                         .with(
                             fmt::layer()
                                 .json()
@@ -358,6 +442,7 @@ pub fn init_subscriber(config: LoggingConfig) {
                 }
                 LogFormat::Compact => {
                     tracing_subscriber::registry()
+                        .with(otlp_layer)
                         .with(
                             fmt::layer()
                                 .compact()
@@ -375,6 +460,7 @@ pub fn init_subscriber(config: LoggingConfig) {
                 // Pretty file format combinations
                 (LogFormat::Pretty, LogFormat::Pretty) => {
                     tracing_subscriber::registry()
+                        .with(otlp_layer)
                         .with(
                             fmt::layer()
                                 .pretty()
@@ -384,7 +470,7 @@ pub fn init_subscriber(config: LoggingConfig) {
                         .with(
                             fmt::layer()
                                 .pretty()
-                                .with_ansi(true)
+                                .with_ansi(config.stderr_ansi)
                                 .with_writer(io::stderr),
                         )
                         .with(env_filter)
@@ -392,18 +478,25 @@ pub fn init_subscriber(config: LoggingConfig) {
                 }
                 (LogFormat::Pretty, LogFormat::Json) => {
                     tracing_subscriber::registry()
+                        .with(otlp_layer)
                         .with(
                             fmt::layer()
                                 .pretty()
                                 .with_ansi(false)
                                 .with_writer(file_appender),
                         )
-                        .with(fmt::layer().json().with_ansi(true).with_writer(io::stderr))
+                        .with(
+                            fmt::layer()
+                                .json()
+                                .with_ansi(config.stderr_ansi)
+                                .with_writer(io::stderr),
+                        )
                         .with(env_filter)
                         .init();
                 }
                 (LogFormat::Pretty, LogFormat::Compact) => {
                     tracing_subscriber::registry()
+                        .with(otlp_layer)
                         .with(
                             fmt::layer()
                                 .pretty()
@@ -413,7 +506,7 @@ pub fn init_subscriber(config: LoggingConfig) {
                         .with(
                             fmt::layer()
                                 .compact()
-                                .with_ansi(true)
+                                .with_ansi(config.stderr_ansi)
                                 .with_writer(io::stderr),
                         )
                         .with(env_filter)
@@ -422,6 +515,7 @@ pub fn init_subscriber(config: LoggingConfig) {
                 // JSON file format combinations
                 (LogFormat::Json, LogFormat::Pretty) => {
                     tracing_subscriber::registry()
+                        .with(otlp_layer)
                         .with(
                             fmt::layer()
                                 .json()
@@ -431,7 +525,7 @@ pub fn init_subscriber(config: LoggingConfig) {
                         .with(
                             fmt::layer()
                                 .pretty()
-                                .with_ansi(true)
+                                .with_ansi(config.stderr_ansi)
                                 .with_writer(io::stderr),
                         )
                         .with(env_filter)
@@ -439,18 +533,25 @@ pub fn init_subscriber(config: LoggingConfig) {
                 }
                 (LogFormat::Json, LogFormat::Json) => {
                     tracing_subscriber::registry()
+                        .with(otlp_layer)
                         .with(
                             fmt::layer()
                                 .json()
                                 .with_ansi(false)
                                 .with_writer(file_appender),
                         )
-                        .with(fmt::layer().json().with_ansi(true).with_writer(io::stderr))
+                        .with(
+                            fmt::layer()
+                                .json()
+                                .with_ansi(config.stderr_ansi)
+                                .with_writer(io::stderr),
+                        )
                         .with(env_filter)
                         .init();
                 }
                 (LogFormat::Json, LogFormat::Compact) => {
                     tracing_subscriber::registry()
+                        .with(otlp_layer)
                         .with(
                             fmt::layer()
                                 .json()
@@ -460,7 +561,7 @@ pub fn init_subscriber(config: LoggingConfig) {
                         .with(
                             fmt::layer()
                                 .compact()
-                                .with_ansi(true)
+                                .with_ansi(config.stderr_ansi)
                                 .with_writer(io::stderr),
                         )
                         .with(env_filter)
@@ -469,6 +570,7 @@ pub fn init_subscriber(config: LoggingConfig) {
                 // Compact file format combinations
                 (LogFormat::Compact, LogFormat::Pretty) => {
                     tracing_subscriber::registry()
+                        .with(otlp_layer)
                         .with(
                             fmt::layer()
                                 .compact()
@@ -478,7 +580,7 @@ pub fn init_subscriber(config: LoggingConfig) {
                         .with(
                             fmt::layer()
                                 .pretty()
-                                .with_ansi(true)
+                                .with_ansi(config.stderr_ansi)
                                 .with_writer(io::stderr),
                         )
                         .with(env_filter)
@@ -486,18 +588,25 @@ pub fn init_subscriber(config: LoggingConfig) {
                 }
                 (LogFormat::Compact, LogFormat::Json) => {
                     tracing_subscriber::registry()
+                        .with(otlp_layer)
                         .with(
                             fmt::layer()
                                 .compact()
                                 .with_ansi(false)
                                 .with_writer(file_appender),
                         )
-                        .with(fmt::layer().json().with_ansi(true).with_writer(io::stderr))
+                        .with(
+                            fmt::layer()
+                                .json()
+                                .with_ansi(config.stderr_ansi)
+                                .with_writer(io::stderr),
+                        )
                         .with(env_filter)
                         .init();
                 }
                 (LogFormat::Compact, LogFormat::Compact) => {
                     tracing_subscriber::registry()
+                        .with(otlp_layer)
                         .with(
                             fmt::layer()
                                 .compact()
@@ -507,7 +616,7 @@ pub fn init_subscriber(config: LoggingConfig) {
                         .with(
                             fmt::layer()
                                 .compact()
-                                .with_ansi(true)
+                                .with_ansi(config.stderr_ansi)
                                 .with_writer(io::stderr),
                         )
                         .with(env_filter)
@@ -8,13 +8,19 @@
 //! - `app` - Main application bootstrap and entry point logic
 //! - `container` - Application service container for dependency injection
 //! - `help` - Help and usage information display
+//! - `interrupt` - Ctrl-C handling that cancels long-running commands
 //! - `logging` - Logging configuration and initialization
+//! - `otlp` - Export of the tracing spans to an OpenTelemetry collector
+//! - `workspace` - Resolution of the workspace from the flag, environment and global configuration
 
 pub mod app;
 pub mod container;
 pub mod help;
+pub mod interrupt;
 pub mod logging;
+pub mod otlp;
 pub mod sdk;
+pub mod workspace;
 
 // Re-export commonly used types for convenience
 pub use container::Container;
@@ -0,0 +1,183 @@
+//! OpenTelemetry Trace Export
+//!
+//! With `--otlp-endpoint` the tracing spans are exported over OTLP/HTTP to a
+//! collector such as Grafana Tempo, next to the regular log output. Each CLI
+//! invocation is one trace: the invocation span is the root, with the command
+//! handler, its steps and the external tools they run as descendants.
+//!
+//! Spans are exported in batches from a background thread. [`shutdown`] must
+//! run before the process exits, otherwise the last batch is lost.
+
+use std::sync::OnceLock;
+
+use opentelemetry::trace::{TraceContextExt, TracerProvider};
+use opentelemetry_otlp::{ExporterBuildError, SpanExporter, WithExportConfig};
+use opentelemetry_sdk::trace::{SdkTracerProvider, Tracer};
+use opentelemetry_sdk::Resource;
+use tracing::Subscriber;
+use tracing_opentelemetry::{OpenTelemetryLayer, OpenTelemetrySpanExt};
+use tracing_subscriber::registry::LookupSpan;
+use url::Url;
+
+/// Service name the exported spans are reported under
+pub const SERVICE_NAME: &str = "torrust-tracker-deployer";
+
+/// Path of the OTLP/HTTP traces endpoint on a collector
+const TRACES_PATH: &str = "/v1/traces";
+
+/// Tracer provider of the running process, kept to flush it on exit
+static TRACER_PROVIDER: OnceLock<SdkTracerProvider> = OnceLock::new();
+
+/// Errors setting up the trace export
+#[derive(Debug, thiserror::Error)]
+pub enum OtlpError {
+    /// The exporter rejected the collector URL
+    #[error("Failed to create the OTLP exporter for '{endpoint}': {source}")]
+    ExporterCreationFailed {
+        endpoint: Url,
+        #[source]
+        source: ExporterBuildError,
+    },
+}
+
+impl OtlpError {
+    /// Provides detailed troubleshooting guidance for this error
+    #[must_use]
+    pub fn help(&self) -> &'static str {
+        match self {
+            Self::ExporterCreationFailed { .. } => {
+                "OTLP Exporter Creation Failed - Troubleshooting:
+
+1. Check the --otlp-endpoint value: it is the HTTP(S) URL of the collector,
+   e.g. http://localhost:4318
+2. Use the OTLP/HTTP port of the collector (4318 by default), not the
+   gRPC one (4317)
+3. Run the command without --otlp-endpoint to skip the trace export
+
+For more information, see docs/user-guide/logging.md"
+            }
+        }
+    }
+}
+
+/// Create the tracing layer that exports spans to `endpoint`
+///
+/// `endpoint` is the collector URL, e.g. `http://localhost:4318`. The OTLP
+/// traces path (`/v1/traces`) is appended when the URL has no path.
+///
+/// # Errors
+///
+/// Returns an error if the exporter cannot be created for `endpoint`. A
+/// requested export must not be silently dropped.
+///
+/// # Panics
+///
+/// Panics if a layer was already created.
+pub fn layer<S>(endpoint: &Url) -> Result<OpenTelemetryLayer<S, Tracer>, OtlpError>
+where
+    S: Subscriber + for<'span> LookupSpan<'span>,
+{
+    let traces_endpoint = traces_endpoint(endpoint);
+
+    let exporter = SpanExporter::builder()
+        .with_http()
+        .with_endpoint(traces_endpoint.as_str())
+        .build()
+        .map_err(|source| OtlpError::ExporterCreationFailed {
+            endpoint: traces_endpoint,
+            source,
+        })?;
+
+    let provider = SdkTracerProvider::builder()
+        .with_batch_exporter(exporter)
+        .with_resource(Resource::builder().with_service_name(SERVICE_NAME).build())
+        .build();
+
+    let tracer = provider.tracer(SERVICE_NAME);
+
+    assert!(
+        TRACER_PROVIDER.set(provider).is_ok(),
+        "OTLP trace export was already initialized"
+    );
+
+    Ok(tracing_opentelemetry::layer().with_tracer(tracer))
+}
+
+/// Export the pending spans and stop the exporter
+///
+/// Does nothing when spans are not exported. Spans the collector does not
+/// accept are dropped and a failed shutdown is only reported on stderr: the
+/// command result must not depend on the collector.
+pub fn shutdown() {
+    if let Some(provider) = TRACER_PROVIDER.get() {
+        if let Err(e) = provider.shutdown() {
+            eprintln!("Warning: failed to export the remaining trace spans: {e}");
+        }
+    }
+}
+
+/// OpenTelemetry trace ID of `span`, if spans are exported
+///
+/// Returns the 32 hex digit W3C trace ID, which is the ID to search for in
+/// the trace backend.
+#[must_use]
+pub fn trace_id(span: &tracing::Span) -> Option<String> {
+    TRACER_PROVIDER.get()?;
+
+    let context = span.context();
+    let span_context = context.span().span_context().clone();
+
+    span_context
+        .is_valid()
+        .then(|| span_context.trace_id().to_string())
+}
+
+/// Full traces endpoint for the collector URL given by the user
+fn traces_endpoint(endpoint: &Url) -> Url {
+    if endpoint.path() == "/" {
+        let mut traces_endpoint = endpoint.clone();
+        traces_endpoint.set_path(TRACES_PATH);
+        traces_endpoint
+    } else {
+        endpoint.clone()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn it_should_append_the_traces_path_to_a_collector_url() {
+        let endpoint = Url::parse("http://localhost:4318").unwrap();
+
+        assert_eq!(
+            traces_endpoint(&endpoint).as_str(),
+            "http://localhost:4318/v1/traces"
+        );
+    }
+
+    #[test]
+    fn it_should_keep_an_endpoint_with_a_path() {
+        let endpoint = Url::parse("https://tempo.example.com/otlp/v1/traces").unwrap();
+
+        assert_eq!(traces_endpoint(&endpoint), endpoint);
+    }
+
+    #[test]
+    fn it_should_report_a_collector_url_the_exporter_rejects() {
+        let endpoint = Url::parse("unix:/run/collector.sock").unwrap();
+
+        let result = layer::<tracing_subscriber::Registry>(&endpoint);
+
+        assert!(matches!(
+            result,
+            Err(OtlpError::ExporterCreationFailed { .. })
+        ));
+    }
+
+    #[test]
+    fn it_should_have_no_trace_id_when_spans_are_not_exported() {
+        assert_eq!(trace_id(&tracing::Span::none()), None);
+    }
+}
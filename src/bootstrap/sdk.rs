@@ -12,7 +12,10 @@ use std::sync::Arc;
 use std::time::Duration;
 
 use crate::application::traits::RepositoryProvider;
-use crate::infrastructure::persistence::file_repository_factory::FileRepositoryFactory;
+use crate::bootstrap::workspace::workspace_key_path;
+use crate::domain::environment::RepositoryBackend;
+use crate::infrastructure::persistence::repository_provider;
+use crate::infrastructure::persistence::workspace_key::WorkspaceKey;
 use crate::shared::SystemClock;
 use torrust_tracker_deployer_types::Clock;
 
@@ -29,7 +32,26 @@ pub const DEFAULT_SDK_LOCK_TIMEOUT: Duration = Duration::from_secs(30);
 /// * `lock_timeout` — How long the repository factory waits to acquire a file lock.
 #[must_use]
 pub fn default_repository_provider(lock_timeout: Duration) -> Arc<dyn RepositoryProvider> {
-    Arc::new(FileRepositoryFactory::new(lock_timeout))
+    backend_repository_provider(RepositoryBackend::default(), lock_timeout)
+}
+
+/// Create the repository provider of a storage backend.
+///
+/// The SDK builder calls this with the backend set in the workspace marker.
+/// Secrets are sealed with the workspace key of the current user, as with the
+/// CLI.
+///
+/// # Arguments
+///
+/// * `backend` — Storage backend of the workspace (JSON files or `SQLite`).
+/// * `lock_timeout` — How long the repositories wait to acquire a lock.
+#[must_use]
+pub fn backend_repository_provider(
+    backend: RepositoryBackend,
+    lock_timeout: Duration,
+) -> Arc<dyn RepositoryProvider> {
+    let workspace_key = workspace_key_path().map(|path| Arc::new(WorkspaceKey::new(path)));
+    repository_provider(backend, lock_timeout, workspace_key)
 }
 
 /// Create the default system clock.
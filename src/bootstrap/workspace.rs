@@ -0,0 +1,475 @@
+//! Workspace Resolution
+//!
+//! The CLI keeps its state in a workspace directory: `data/` holds one
+//! directory per environment, `build/` the generated artifacts and
+//! `data/logs/` the log file. Using the current directory made every run
+//! from another directory create stray `data/` folders, so the workspace is
+//! resolved once at startup, by precedence:
+//!
+//! 1. `--workspace <DIR>` on the command line
+//! 2. The `TORRUST_DEPLOYER_WORKSPACE` environment variable
+//! 3. `workspace = "<DIR>"` in the global configuration file
+//!    (`$XDG_CONFIG_HOME/torrust-deployer/config.toml`, by default
+//!    `~/.config/torrust-deployer/config.toml`)
+//! 4. The current directory
+//!
+//! The resolved path is absolute, so the environments created by the CLI
+//! never store paths relative to the directory the binary was run from. The
+//! SDK does not read any of these settings: its callers choose the working
+//! directory explicitly.
+
+use std::fmt;
+use std::path::{Path, PathBuf};
+
+use crate::shared::error::kind::ErrorKind;
+use crate::shared::error::traceable::Traceable;
+
+/// Environment variable overriding the workspace of the configuration file
+pub const WORKSPACE_ENV_VAR: &str = "TORRUST_DEPLOYER_WORKSPACE";
+
+/// Environment variable overriding the path of the workspace key
+pub const WORKSPACE_KEY_ENV_VAR: &str = "TORRUST_DEPLOYER_WORKSPACE_KEY";
+
+/// Directory of the deployer inside the user configuration directory
+const CONFIG_DIR_NAME: &str = "torrust-deployer";
+
+/// Name of the global configuration file
+const CONFIG_FILE_NAME: &str = "config.toml";
+
+/// Name of the workspace key file, next to the global configuration file
+const WORKSPACE_KEY_FILE_NAME: &str = "workspace.key";
+
+/// How the workspace of a run was chosen
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub enum WorkspaceSource {
+    /// Given with `--workspace`
+    CommandLine,
+    /// Given with the `TORRUST_DEPLOYER_WORKSPACE` environment variable
+    EnvironmentVariable,
+    /// Set in the global configuration file at this path
+    ConfigFile(PathBuf),
+    /// Nothing configured: the current directory
+    #[default]
+    CurrentDirectory,
+}
+
+impl fmt::Display for WorkspaceSource {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::CommandLine => write!(f, "given with --workspace"),
+            Self::EnvironmentVariable => {
+                write!(f, "given with the {WORKSPACE_ENV_VAR} environment variable")
+            }
+            Self::ConfigFile(path) => write!(f, "set in {}", path.display()),
+            Self::CurrentDirectory => write!(
+                f,
+                "the current directory, as no --workspace, {WORKSPACE_ENV_VAR} or configuration file sets one"
+            ),
+        }
+    }
+}
+
+/// The workspace of a run and how it was chosen
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ResolvedWorkspace {
+    /// Absolute path of the workspace
+    pub path: PathBuf,
+    /// How the path was chosen
+    pub source: WorkspaceSource,
+}
+
+/// Settings of the global configuration file
+///
+/// # Examples
+///
+/// ```rust
+/// use std::path::{Path, PathBuf};
+/// use torrust_tracker_deployer_lib::bootstrap::workspace::GlobalConfig;
+///
+/// let config = GlobalConfig::parse(
+///     "workspace = \"deployments\"",
+///     Path::new("/home/alice/.config/torrust-deployer/config.toml"),
+/// )
+/// .unwrap();
+///
+/// assert_eq!(
+///     config.workspace,
+///     Some(PathBuf::from("/home/alice/.config/torrust-deployer/deployments"))
+/// );
+/// ```
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct GlobalConfig {
+    /// Default workspace, `None` when the file does not set one
+    pub workspace: Option<PathBuf>,
+}
+
+impl GlobalConfig {
+    /// Load the configuration file at `path`, an empty configuration when it does not exist
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the file exists but cannot be read or is invalid
+    /// (see [`GlobalConfig::parse`]).
+    pub fn load(path: &Path) -> Result<Self, GlobalConfigError> {
+        match std::fs::read_to_string(path) {
+            Ok(content) => Self::parse(&content, path),
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => Ok(Self::default()),
+            Err(source) => Err(GlobalConfigError::ReadFailed {
+                path: path.to_path_buf(),
+                source,
+            }),
+        }
+    }
+
+    /// Parse the content of the configuration file at `path`
+    ///
+    /// A `workspace` starting with `~/` is relative to the home directory,
+    /// any other relative path to the directory of the file.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the content is not valid TOML, has unknown keys
+    /// (usually typos) or `workspace` is not a non-empty string.
+    pub fn parse(content: &str, path: &Path) -> Result<Self, GlobalConfigError> {
+        let invalid = |reason: String| GlobalConfigError::Invalid {
+            path: path.to_path_buf(),
+            reason,
+        };
+
+        let document = content
+            .parse::<toml_edit::Document<String>>()
+            .map_err(|e| invalid(e.message().to_string()))?;
+
+        let mut config = Self::default();
+        for (key, item) in document.iter() {
+            match key {
+                "workspace" => {
+                    let value = item
+                        .as_str()
+                        .filter(|value| !value.is_empty())
+                        .ok_or_else(|| invalid("'workspace' must be a non-empty string".into()))?;
+                    config.workspace = Some(expand_path(value, path));
+                }
+                other => return Err(invalid(format!("unknown key '{other}'"))),
+            }
+        }
+
+        Ok(config)
+    }
+}
+
+/// Errors of the global configuration file
+#[derive(Debug, thiserror::Error)]
+pub enum GlobalConfigError {
+    /// The file exists but cannot be read
+    #[error("Cannot read the configuration file '{path}': {source}")]
+    ReadFailed {
+        path: PathBuf,
+        #[source]
+        source: std::io::Error,
+    },
+
+    /// The file content is invalid
+    #[error(
+        "Invalid configuration file '{path}': {reason}
+Tip: The file only supports workspace = \"<DIR>\""
+    )]
+    Invalid { path: PathBuf, reason: String },
+}
+
+impl GlobalConfigError {
+    /// Provides detailed troubleshooting guidance for this error
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use std::path::PathBuf;
+    /// use torrust_tracker_deployer_lib::bootstrap::workspace::GlobalConfigError;
+    ///
+    /// let error = GlobalConfigError::Invalid {
+    ///     path: PathBuf::from("/home/alice/.config/torrust-deployer/config.toml"),
+    ///     reason: "unknown key 'workdir'".to_string(),
+    /// };
+    ///
+    /// assert!(error.help().contains("TORRUST_DEPLOYER_WORKSPACE"));
+    /// ```
+    #[must_use]
+    pub fn help(&self) -> &'static str {
+        match self {
+            Self::ReadFailed { .. } => {
+                "Configuration File Not Readable - Troubleshooting:
+
+1. Check the file system permissions of the configuration file
+2. Check that the path is a file and not a directory
+
+The file is only read when neither --workspace nor the
+TORRUST_DEPLOYER_WORKSPACE environment variable is given, so both can be
+used to run commands in the meantime.
+
+For more information, see docs/user-guide/commands/init.md"
+            }
+            Self::Invalid { .. } => {
+                "Invalid Configuration File - Troubleshooting:
+
+The global configuration file sets the default workspace:
+
+   workspace = \"~/torrust/workspace\"
+
+1. Check the TOML syntax (strings are quoted)
+2. Check the key name: 'workspace' is the only key
+3. A path starting with ~/ is relative to the home directory, any other
+   relative path to the directory of the configuration file
+
+The file is only read when neither --workspace nor the
+TORRUST_DEPLOYER_WORKSPACE environment variable is given.
+
+For more information, see docs/user-guide/commands/init.md"
+            }
+        }
+    }
+}
+
+impl Traceable for GlobalConfigError {
+    fn trace_format(&self) -> String {
+        match self {
+            Self::ReadFailed { path, source } => {
+                format!(
+                    "GlobalConfigError: Read failed - '{}': {source}",
+                    path.display()
+                )
+            }
+            Self::Invalid { path, reason } => {
+                format!(
+                    "GlobalConfigError: Invalid file - '{}': {reason}",
+                    path.display()
+                )
+            }
+        }
+    }
+
+    fn trace_source(&self) -> Option<&dyn Traceable> {
+        None
+    }
+
+    fn error_kind(&self) -> ErrorKind {
+        match self {
+            Self::ReadFailed { .. } => ErrorKind::FileSystem,
+            Self::Invalid { .. } => ErrorKind::Configuration,
+        }
+    }
+}
+
+/// Path of the global configuration file of the current user
+///
+/// `$XDG_CONFIG_HOME/torrust-deployer/config.toml`, falling back to
+/// `$HOME/.config/torrust-deployer/config.toml`. `None` when neither
+/// variable is set.
+#[must_use]
+pub fn global_config_path() -> Option<PathBuf> {
+    config_path_from(
+        std::env::var_os("XDG_CONFIG_HOME").map(PathBuf::from),
+        std::env::var_os("HOME").map(PathBuf::from),
+    )
+}
+
+/// Path of the key encrypting the secrets of the workspaces of the current user
+///
+/// The value of `TORRUST_DEPLOYER_WORKSPACE_KEY` when set, otherwise
+/// `workspace.key` next to the global configuration file. `None` when no
+/// configuration directory is known.
+#[must_use]
+pub fn workspace_key_path() -> Option<PathBuf> {
+    std::env::var_os(WORKSPACE_KEY_ENV_VAR)
+        .filter(|value| !value.is_empty())
+        .map(PathBuf::from)
+        .or_else(|| {
+            global_config_path()
+                .and_then(|path| path.parent().map(|dir| dir.join(WORKSPACE_KEY_FILE_NAME)))
+        })
+}
+
+/// Resolve the workspace of a run
+///
+/// `cli` is the value of `--workspace`. The configuration file is only read
+/// when neither the flag nor the environment variable is given.
+///
+/// # Errors
+///
+/// Returns an error if the configuration file is read and is invalid.
+pub fn resolve_workspace(cli: Option<&Path>) -> Result<ResolvedWorkspace, GlobalConfigError> {
+    let env = std::env::var_os(WORKSPACE_ENV_VAR)
+        .filter(|value| !value.is_empty())
+        .map(PathBuf::from);
+
+    resolve(cli, env, global_config_path().as_deref())
+}
+
+fn resolve(
+    cli: Option<&Path>,
+    env: Option<PathBuf>,
+    config_path: Option<&Path>,
+) -> Result<ResolvedWorkspace, GlobalConfigError> {
+    let (path, source) = if let Some(path) = cli {
+        (path.to_path_buf(), WorkspaceSource::CommandLine)
+    } else if let Some(path) = env {
+        (path, WorkspaceSource::EnvironmentVariable)
+    } else {
+        let configured = match config_path {
+            Some(config_path) => GlobalConfig::load(config_path)?
+                .workspace
+                .map(|path| (path, WorkspaceSource::ConfigFile(config_path.to_path_buf()))),
+            None => None,
+        };
+        configured.unwrap_or_else(|| (PathBuf::from("."), WorkspaceSource::CurrentDirectory))
+    };
+
+    Ok(ResolvedWorkspace {
+        path: absolute(&path),
+        source,
+    })
+}
+
+fn config_path_from(xdg_config_home: Option<PathBuf>, home: Option<PathBuf>) -> Option<PathBuf> {
+    // The XDG specification says to ignore relative values
+    let config_home = xdg_config_home
+        .filter(|dir| dir.is_absolute())
+        .or_else(|| home.map(|home| home.join(".config")))?;
+
+    Some(config_home.join(CONFIG_DIR_NAME).join(CONFIG_FILE_NAME))
+}
+
+/// Expand `~/` and make a relative path relative to the directory of `config_path`
+fn expand_path(value: &str, config_path: &Path) -> PathBuf {
+    if let Some(rest) = value.strip_prefix("~/") {
+        if let Some(home) = std::env::var_os("HOME") {
+            return PathBuf::from(home).join(rest);
+        }
+    }
+
+    let path = PathBuf::from(value);
+    match config_path.parent() {
+        Some(dir) if path.is_relative() => dir.join(path),
+        _ => path,
+    }
+}
+
+/// Make `path` absolute, without resolving symbolic links
+///
+/// Keeps `path` unchanged in the unlikely case the current directory cannot
+/// be read; the workspace check then reports the path as given.
+fn absolute(path: &Path) -> PathBuf {
+    std::path::absolute(path).unwrap_or_else(|_| path.to_path_buf())
+}
+
+#[cfg(test)]
+mod tests {
+    use std::fs;
+
+    use tempfile::TempDir;
+
+    use super::*;
+
+    fn write_config(dir: &TempDir, content: &str) -> PathBuf {
+        let path = dir.path().join(CONFIG_FILE_NAME);
+        fs::write(&path, content).unwrap();
+        path
+    }
+
+    #[test]
+    fn it_should_prefer_the_command_line_over_the_environment_variable_and_the_file() {
+        let dir = TempDir::new().unwrap();
+        let config = write_config(&dir, "workspace = \"/srv/from-file\"");
+
+        let resolved = resolve(
+            Some(Path::new("/srv/from-flag")),
+            Some(PathBuf::from("/srv/from-env")),
+            Some(&config),
+        )
+        .unwrap();
+
+        assert_eq!(resolved.path, PathBuf::from("/srv/from-flag"));
+        assert_eq!(resolved.source, WorkspaceSource::CommandLine);
+    }
+
+    #[test]
+    fn it_should_prefer_the_environment_variable_over_the_file() {
+        let dir = TempDir::new().unwrap();
+        let config = write_config(&dir, "workspace = \"/srv/from-file\"");
+
+        let resolved = resolve(None, Some(PathBuf::from("/srv/from-env")), Some(&config)).unwrap();
+
+        assert_eq!(resolved.path, PathBuf::from("/srv/from-env"));
+        assert_eq!(resolved.source, WorkspaceSource::EnvironmentVariable);
+    }
+
+    #[test]
+    fn it_should_use_the_workspace_of_the_configuration_file() {
+        let dir = TempDir::new().unwrap();
+        let config = write_config(&dir, "workspace = \"deployments\"");
+
+        let resolved = resolve(None, None, Some(&config)).unwrap();
+
+        assert_eq!(resolved.path, dir.path().join("deployments"));
+        assert_eq!(resolved.source, WorkspaceSource::ConfigFile(config));
+    }
+
+    #[test]
+    fn it_should_fall_back_to_the_current_directory_made_absolute() {
+        let dir = TempDir::new().unwrap();
+
+        let resolved = resolve(None, None, Some(&dir.path().join("missing.toml"))).unwrap();
+
+        assert_eq!(resolved.path, std::env::current_dir().unwrap());
+        assert_eq!(resolved.source, WorkspaceSource::CurrentDirectory);
+    }
+
+    #[test]
+    fn it_should_not_read_the_configuration_file_when_the_workspace_is_given() {
+        let dir = TempDir::new().unwrap();
+        let config = write_config(&dir, "not toml");
+
+        let resolved = resolve(Some(Path::new("/srv/from-flag")), None, Some(&config));
+
+        assert!(resolved.is_ok());
+    }
+
+    #[test]
+    fn it_should_reject_unknown_keys_of_the_configuration_file() {
+        let error =
+            GlobalConfig::parse("workdir = \"/srv\"", Path::new("config.toml")).unwrap_err();
+
+        assert!(
+            error.to_string().contains("unknown key 'workdir'"),
+            "{error}"
+        );
+    }
+
+    #[test]
+    fn it_should_reject_a_workspace_that_is_not_a_string() {
+        let error = GlobalConfig::parse("workspace = 42", Path::new("config.toml")).unwrap_err();
+
+        assert!(matches!(error, GlobalConfigError::Invalid { .. }));
+    }
+
+    #[test]
+    fn it_should_ignore_a_relative_xdg_config_home() {
+        let path = config_path_from(
+            Some(PathBuf::from("relative")),
+            Some(PathBuf::from("/home/alice")),
+        );
+
+        assert_eq!(
+            path,
+            Some(PathBuf::from(
+                "/home/alice/.config/torrust-deployer/config.toml"
+            ))
+        );
+    }
+
+    #[test]
+    fn it_should_describe_how_the_workspace_was_chosen() {
+        let source = WorkspaceSource::ConfigFile(PathBuf::from("/etc/config.toml"));
+
+        assert_eq!(source.to_string(), "set in /etc/config.toml");
+    }
+}
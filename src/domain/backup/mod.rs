@@ -18,9 +18,19 @@ use crate::domain::topology::{
     Service, ServiceDependency,
 };
 
+use crate::shared::docker_image::DockerImage;
+
 // Re-export the trait so users can import it from this module
 pub use crate::domain::topology::traits::DependencyDerivation;
 
+/// Docker image repository for the backup container
+pub const BACKUP_DOCKER_IMAGE_REPOSITORY: &str = "torrust/tracker-backup";
+
+/// Docker image tag for the backup container
+///
+/// Must match the image of `templates/docker-compose/docker-compose.yml.tera`.
+pub const BACKUP_DOCKER_IMAGE_TAG: &str = "latest";
+
 /// Backup configuration for a deployed tracker instance.
 ///
 /// Specifies when backups run (cron schedule) and how long to keep them (retention).
@@ -59,6 +69,21 @@ impl BackupConfig {
     pub const fn retention_days(&self) -> &RetentionDays {
         &self.retention_days
     }
+
+    /// Returns the Docker image for the backup container
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use torrust_tracker_deployer_lib::domain::backup::BackupConfig;
+    ///
+    /// let image = BackupConfig::docker_image();
+    /// assert_eq!(image.full_reference(), "torrust/tracker-backup:latest");
+    /// ```
+    #[must_use]
+    pub fn docker_image() -> DockerImage {
+        DockerImage::new(BACKUP_DOCKER_IMAGE_REPOSITORY, BACKUP_DOCKER_IMAGE_TAG)
+    }
 }
 
 impl Default for BackupConfig {
@@ -21,6 +21,15 @@ use serde::{Deserialize, Serialize};
 use crate::domain::topology::{
     EnabledServices, Network, NetworkDerivation, PortBinding, PortDerivation,
 };
+use crate::shared::docker_image::DockerImage;
+
+/// Docker image repository for the Caddy container
+pub const CADDY_DOCKER_IMAGE_REPOSITORY: &str = "caddy";
+
+/// Docker image tag for the Caddy container
+///
+/// Must match the image of `templates/docker-compose/docker-compose.yml.tera`.
+pub const CADDY_DOCKER_IMAGE_TAG: &str = "2.11.2";
 
 /// Caddy TLS reverse proxy configuration
 ///
@@ -62,6 +71,21 @@ impl CaddyConfig {
     pub const fn new() -> Self {
         Self {}
     }
+
+    /// Returns the Docker image for the Caddy container
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use torrust_tracker_deployer_lib::domain::caddy::CaddyConfig;
+    ///
+    /// let image = CaddyConfig::docker_image();
+    /// assert_eq!(image.full_reference(), "caddy:2.11.2");
+    /// ```
+    #[must_use]
+    pub fn docker_image() -> DockerImage {
+        DockerImage::new(CADDY_DOCKER_IMAGE_REPOSITORY, CADDY_DOCKER_IMAGE_TAG)
+    }
 }
 
 impl PortDerivation for CaddyConfig {
@@ -0,0 +1,297 @@
+//! Configuration diffs between two sets of user inputs
+//!
+//! This module provides the `ConfigDiff` returned by [`UserInputs::diff`],
+//! which lists the settings that differ between the inputs of an existing
+//! environment and new ones, typically read from an updated configuration
+//! file. Each change is categorized by its `ChangeImpact`: the earliest
+//! deployment command that has to run again for the change to take effect.
+//!
+//! ## Key Features
+//!
+//! - Changes are reported per setting (`tracker.http_api.admin_token`), not
+//!   per section
+//! - Secret values (tokens, passwords) are never included, only the fact that
+//!   they changed
+//! - Service port changes are also reported as firewall changes, since the
+//!   firewall opens the public ports of the services
+//!
+//! [`UserInputs::diff`]: super::UserInputs::diff
+
+use std::collections::BTreeSet;
+use std::fmt;
+
+use serde::Serialize;
+use serde_json::Value;
+
+/// Replacement of secret values in a diff
+pub const REDACTED: &str = "[REDACTED]";
+
+/// Earliest deployment command that has to run again for a change to take effect
+///
+/// Ordered from the least to the most disruptive impact.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ChangeImpact {
+    /// Only recorded by the deployer, nothing is redeployed (e.g. labels)
+    NoRedeploy,
+
+    /// Takes effect with a new release (tracker and service configuration)
+    RequiresRelease,
+
+    /// Takes effect when the instance is configured again (firewall, Docker)
+    RequiresConfigure,
+
+    /// Takes effect on a new instance only (provider, sizing, cloud-init)
+    RequiresProvision,
+}
+
+impl ChangeImpact {
+    /// All impacts, from the most to the least disruptive
+    pub const ALL: [Self; 4] = [
+        Self::RequiresProvision,
+        Self::RequiresConfigure,
+        Self::RequiresRelease,
+        Self::NoRedeploy,
+    ];
+
+    /// Deployment command to run again, `None` when nothing is redeployed
+    ///
+    /// The commands following it in the workflow (up to `run`) have to run
+    /// again too.
+    #[must_use]
+    pub fn command(self) -> Option<&'static str> {
+        match self {
+            Self::NoRedeploy => None,
+            Self::RequiresRelease => Some("release"),
+            Self::RequiresConfigure => Some("configure"),
+            Self::RequiresProvision => Some("provision"),
+        }
+    }
+}
+
+impl fmt::Display for ChangeImpact {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.command() {
+            Some(command) => write!(f, "requires {command}"),
+            None => write!(f, "no redeploy needed"),
+        }
+    }
+}
+
+/// One setting that differs between two sets of user inputs
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct ConfigChange {
+    /// Dotted path of the setting (e.g. `tracker.http_api.admin_token`)
+    pub path: String,
+
+    /// What has to be redeployed for the change to take effect
+    pub impact: ChangeImpact,
+
+    /// Current value, `None` when the setting is not set
+    pub before: Option<String>,
+
+    /// New value, `None` when the setting is removed
+    pub after: Option<String>,
+}
+
+/// Settings that differ between two sets of user inputs
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize)]
+pub struct ConfigDiff {
+    changes: Vec<ConfigChange>,
+}
+
+impl ConfigDiff {
+    /// All changes, in the order the settings were compared
+    #[must_use]
+    pub fn changes(&self) -> &[ConfigChange] {
+        &self.changes
+    }
+
+    /// Returns `true` if no setting changed
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.changes.is_empty()
+    }
+
+    /// The most disruptive impact of the changes, `None` when nothing changed
+    #[must_use]
+    pub fn impact(&self) -> Option<ChangeImpact> {
+        self.changes.iter().map(|change| change.impact).max()
+    }
+
+    /// The changes with the given impact
+    pub fn changes_with(&self, impact: ChangeImpact) -> impl Iterator<Item = &ConfigChange> {
+        self.changes
+            .iter()
+            .filter(move |change| change.impact == impact)
+    }
+
+    /// Compare two values of a setting, recording every differing field
+    ///
+    /// Both values are compared through their serialized form, so nested
+    /// settings are reported with their own path. Lists are compared as a
+    /// whole. A missing field and a `null` one are the same.
+    pub(crate) fn compare<T: Serialize>(
+        &mut self,
+        path: &str,
+        impact: ChangeImpact,
+        before: &T,
+        after: &T,
+    ) {
+        // User inputs are persisted as JSON, so they always serialize
+        let before = serde_json::to_value(before).unwrap_or_default();
+        let after = serde_json::to_value(after).unwrap_or_default();
+
+        self.walk(path.to_string(), impact, Some(&before), Some(&after));
+    }
+
+    fn walk(
+        &mut self,
+        path: String,
+        impact: ChangeImpact,
+        before: Option<&Value>,
+        after: Option<&Value>,
+    ) {
+        let before = before.filter(|value| !value.is_null());
+        let after = after.filter(|value| !value.is_null());
+
+        match (before, after) {
+            (Some(Value::Object(before)), Some(Value::Object(after))) => {
+                let keys: BTreeSet<&String> = before.keys().chain(after.keys()).collect();
+                for key in keys {
+                    self.walk(
+                        format!("{path}.{key}"),
+                        impact,
+                        before.get(key),
+                        after.get(key),
+                    );
+                }
+            }
+            _ if before == after => {}
+            _ => {
+                let secret = is_secret(&path);
+                self.changes.push(ConfigChange {
+                    impact,
+                    before: before.map(|value| display(value, secret)),
+                    after: after.map(|value| display(value, secret)),
+                    path,
+                });
+            }
+        }
+    }
+}
+
+/// Whether the setting at `path` holds a secret
+fn is_secret(path: &str) -> bool {
+    let name = path.rsplit('.').next().unwrap_or(path);
+    name.contains("token") || name.contains("password")
+}
+
+/// Display form of a value, without the secrets it holds
+fn display(value: &Value, secret: bool) -> String {
+    if secret {
+        return REDACTED.to_string();
+    }
+    match value {
+        Value::String(text) => text.clone(),
+        other => redact(other).to_string(),
+    }
+}
+
+/// Copy of `value` with the secrets of nested settings redacted
+fn redact(value: &Value) -> Value {
+    match value {
+        Value::Object(fields) => Value::Object(
+            fields
+                .iter()
+                .map(|(key, field)| {
+                    let field = if is_secret(key) {
+                        Value::String(REDACTED.to_string())
+                    } else {
+                        redact(field)
+                    };
+                    (key.clone(), field)
+                })
+                .collect(),
+        ),
+        Value::Array(items) => Value::Array(items.iter().map(redact).collect()),
+        other => other.clone(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use super::*;
+
+    #[test]
+    fn it_should_report_each_changed_field_with_its_path() {
+        let mut diff = ConfigDiff::default();
+
+        diff.compare(
+            "tracker",
+            ChangeImpact::RequiresRelease,
+            &json!({ "core": { "private": false, "port": 6969 } }),
+            &json!({ "core": { "private": true, "port": 6969 } }),
+        );
+
+        assert_eq!(
+            diff.changes(),
+            &[ConfigChange {
+                path: "tracker.core.private".to_string(),
+                impact: ChangeImpact::RequiresRelease,
+                before: Some("false".to_string()),
+                after: Some("true".to_string()),
+            }]
+        );
+    }
+
+    #[test]
+    fn it_should_redact_secret_values() {
+        let mut diff = ConfigDiff::default();
+
+        diff.compare(
+            "tracker",
+            ChangeImpact::RequiresRelease,
+            &json!({ "http_api": { "admin_token": "old" } }),
+            &json!({ "http_api": { "admin_token": "new" } }),
+        );
+        diff.compare(
+            "database",
+            ChangeImpact::RequiresRelease,
+            &Value::Null,
+            &json!({ "user": "tracker", "password": "secret" }),
+        );
+
+        assert_eq!(diff.changes()[0].after.as_deref(), Some(REDACTED));
+        let added = diff.changes()[1].after.as_deref().unwrap();
+        assert!(!added.contains("secret"));
+        assert!(added.contains(REDACTED));
+    }
+
+    #[test]
+    fn it_should_treat_missing_and_null_settings_alike() {
+        let mut diff = ConfigDiff::default();
+
+        diff.compare(
+            "backup",
+            ChangeImpact::RequiresRelease,
+            &json!({ "schedule": null }),
+            &json!({}),
+        );
+
+        assert!(diff.is_empty());
+    }
+
+    #[test]
+    fn it_should_report_the_most_disruptive_impact() {
+        let mut diff = ConfigDiff::default();
+        diff.compare("labels", ChangeImpact::NoRedeploy, &1, &2);
+        diff.compare("firewall", ChangeImpact::RequiresConfigure, &1, &2);
+        diff.compare("tracker", ChangeImpact::RequiresRelease, &1, &2);
+
+        assert_eq!(diff.impact(), Some(ChangeImpact::RequiresConfigure));
+        assert_eq!(diff.changes_with(ChangeImpact::RequiresRelease).count(), 1);
+    }
+}
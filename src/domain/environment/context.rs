@@ -38,14 +38,16 @@
 use crate::adapters::ssh::SshCredentials;
 use crate::domain::backup::BackupConfig;
 use crate::domain::environment::{
-    EnvironmentName, EnvironmentParams, InternalConfig, RuntimeOutputs, UserInputs,
+    EnvironmentName, EnvironmentParams, InternalConfig, RuntimeOutputs, TracesConfig, UserInputs,
 };
 use crate::domain::grafana::GrafanaConfig;
 use crate::domain::prometheus::PrometheusConfig;
 use crate::domain::provider::ProviderConfig;
+use crate::domain::provision::ProvisionConfig;
+use crate::domain::template::ExtraTemplateVariables;
 use chrono::{DateTime, TimeZone, Utc};
 use serde::{Deserialize, Serialize};
-use std::path::PathBuf;
+use std::path::{Path, PathBuf};
 
 /// Default value for `created_at` field for backward compatibility
 ///
@@ -55,6 +57,17 @@ fn default_created_at() -> DateTime<Utc> {
     Utc.timestamp_opt(0, 0).unwrap()
 }
 
+/// Revision of the user inputs of a newly created environment
+pub const INITIAL_CONFIG_REVISION: u32 = 1;
+
+/// Default value for `config_revision` field for backward compatibility
+///
+/// Environments created before the field was added have never had their
+/// configuration overridden.
+fn default_config_revision() -> u32 {
+    INITIAL_CONFIG_REVISION
+}
+
 /// Complete environment context composed of three semantic types
 ///
 /// The context is split into three logical categories:
@@ -104,6 +117,7 @@ fn default_created_at() -> DateTime<Utc> {
 /// );
 /// let provider_config = ProviderConfig::Lxd(LxdConfig {
 ///     profile_name: ProfileName::new(format!("lxd-{}", env_name.as_str())).unwrap(),
+///     remote: None,
 /// });
 ///
 /// // Environment::new() creates the EnvironmentContext internally
@@ -126,6 +140,14 @@ pub struct EnvironmentContext {
     #[serde(default = "default_created_at")]
     pub created_at: DateTime<Utc>,
 
+    /// Revision of the user inputs
+    ///
+    /// Starts at 1 when the environment is created and is incremented every
+    /// time a configuration override changes the user inputs (see the
+    /// `--override` option of `configure` and `release`).
+    #[serde(default = "default_config_revision")]
+    pub config_revision: u32,
+
     /// User-provided configuration
     pub user_inputs: UserInputs,
 
@@ -174,6 +196,7 @@ impl EnvironmentContext {
     /// );
     /// let provider_config = ProviderConfig::Lxd(LxdConfig {
     ///     profile_name: ProfileName::new("torrust-profile-production".to_string())?,
+    ///     remote: None,
     /// });
     ///
     /// let created_at = Utc.with_ymd_and_hms(2025, 1, 1, 0, 0, 0).unwrap();
@@ -202,6 +225,7 @@ impl EnvironmentContext {
     ) -> Self {
         Self {
             created_at,
+            config_revision: INITIAL_CONFIG_REVISION,
             user_inputs: UserInputs::new(name, provider_config, ssh_credentials, ssh_port)
                 .expect("UserInputs::new with defaults should never fail - default config always passes validation"),
             internal_config: InternalConfig::new(name),
@@ -231,23 +255,34 @@ impl EnvironmentContext {
         working_dir: &std::path::Path,
         created_at: DateTime<Utc>,
     ) -> Result<Self, crate::domain::environment::UserInputsError> {
+        Self::create_in_layout(
+            params,
+            &crate::domain::environment::WorkspaceLayout::in_working_dir(working_dir),
+            created_at,
+        )
+    }
+
+    /// Creates a new environment context whose directories follow `layout`
+    ///
+    /// Same as [`EnvironmentContext::create`], with the data and build roots
+    /// given explicitly instead of derived from a working directory.
+    ///
+    /// # Errors
+    ///
+    /// Returns `UserInputsError` if cross-service invariant validation fails
+    /// (see [`EnvironmentContext::create`]).
+    pub fn create_in_layout(
+        params: EnvironmentParams,
+        layout: &crate::domain::environment::WorkspaceLayout,
+        created_at: DateTime<Utc>,
+    ) -> Result<Self, crate::domain::environment::UserInputsError> {
+        let internal_config = InternalConfig::with_layout(&params.environment_name, layout);
+
         Ok(Self {
             created_at,
-            user_inputs: UserInputs::with_tracker(
-                &params.environment_name,
-                params.provider_config,
-                params.ssh_credentials,
-                params.ssh_port,
-                params.tracker_config,
-                params.prometheus_config,
-                params.grafana_config,
-                params.https_config,
-                params.backup_config,
-            )?,
-            internal_config: InternalConfig::with_working_dir(
-                &params.environment_name,
-                working_dir,
-            ),
+            config_revision: INITIAL_CONFIG_REVISION,
+            user_inputs: UserInputs::from_params(params)?,
+            internal_config,
             runtime_outputs: RuntimeOutputs::new(),
         })
     }
@@ -280,10 +315,14 @@ impl EnvironmentContext {
 
     /// Returns the traces directory for this environment
     ///
-    /// Path: `data/{env_name}/traces/`
+    /// Path: the directory set in the traces settings, `data/{env_name}/traces/`
+    /// when there is none
     #[must_use]
     pub fn traces_dir(&self) -> PathBuf {
-        self.internal_config.traces_dir()
+        self.user_inputs
+            .traces()
+            .dir()
+            .map_or_else(|| self.internal_config.traces_dir(), Path::to_path_buf)
     }
 
     /// Returns the ansible build directory
@@ -294,6 +333,38 @@ impl EnvironmentContext {
         self.internal_config.ansible_build_dir()
     }
 
+    /// Returns the SSH control socket directory
+    ///
+    /// Path: `build/{env_name}/ssh`
+    #[must_use]
+    pub fn ssh_control_dir(&self) -> PathBuf {
+        self.internal_config.ssh_control_dir()
+    }
+
+    /// Returns the file holding the recorded SSH host keys
+    ///
+    /// Path: `data/{env_name}/ssh/known_hosts`
+    #[must_use]
+    pub fn ssh_known_hosts_path(&self) -> PathBuf {
+        self.internal_config.ssh_known_hosts_path()
+    }
+
+    /// Returns the directory holding the output logs of external tools
+    ///
+    /// Path: `build/{env_name}/logs`
+    #[must_use]
+    pub fn run_logs_dir(&self) -> PathBuf {
+        self.internal_config.run_logs_dir()
+    }
+
+    /// Returns the directory holding the tracker database backups
+    ///
+    /// Path: `data/{env_name}/backups`
+    #[must_use]
+    pub fn backups_dir(&self) -> PathBuf {
+        self.internal_config.backups_dir()
+    }
+
     /// Returns the tofu build directory for the environment's provider
     ///
     /// Path: `build/{env_name}/tofu/{provider_name}`
@@ -346,10 +417,15 @@ impl EnvironmentContext {
         self.user_inputs.ssh_credentials()
     }
 
-    /// Returns the SSH port
+    /// Returns the SSH port to connect to
+    ///
+    /// This is the host port SSH is published on when the provider maps it
+    /// (e.g. the Docker provider), otherwise the configured SSH port.
     #[must_use]
     pub fn ssh_port(&self) -> u16 {
-        self.user_inputs.ssh_port()
+        self.runtime_outputs
+            .published_ssh_port()
+            .unwrap_or(self.user_inputs.ssh_port())
     }
 
     /// Returns the database configuration
@@ -392,6 +468,32 @@ impl EnvironmentContext {
         self.user_inputs.backup()
     }
 
+    /// Returns the template override directory if configured
+    #[must_use]
+    pub fn templates_override_dir(&self) -> Option<PathBuf> {
+        self.user_inputs
+            .templates_override_dir()
+            .map(std::path::Path::to_path_buf)
+    }
+
+    /// Returns the user-defined variables added to every template context
+    #[must_use]
+    pub fn extra_template_variables(&self) -> &ExtraTemplateVariables {
+        self.user_inputs.extra_template_variables()
+    }
+
+    /// Returns the provision workflow settings
+    #[must_use]
+    pub fn provision_config(&self) -> &ProvisionConfig {
+        self.user_inputs.provision()
+    }
+
+    /// Returns the location and retention of the trace files
+    #[must_use]
+    pub fn traces_config(&self) -> &TracesConfig {
+        self.user_inputs.traces()
+    }
+
     /// Returns the build directory
     #[must_use]
     pub fn build_dir(&self) -> &PathBuf {
@@ -405,9 +507,13 @@ impl EnvironmentContext {
     }
 
     /// Returns the instance IP address if available
+    ///
+    /// For dual-stack instances this is the address of the family chosen by
+    /// the provision `ip_preference`; the other family is the fallback.
     #[must_use]
     pub fn instance_ip(&self) -> Option<std::net::IpAddr> {
-        self.runtime_outputs.instance_ip()
+        self.runtime_outputs
+            .instance_ip(self.user_inputs.provision().ip_preference())
     }
 
     /// Returns the provision method
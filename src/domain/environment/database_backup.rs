@@ -0,0 +1,117 @@
+//! Tracker database backups taken by the `backup` command
+//!
+//! A backup is a copy of the tracker database downloaded from the instance
+//! to `data/{env}/backups/{id}/`, next to a `manifest.json` describing it.
+//! The id is the UTC time the backup was taken, which is also the name
+//! `restore --from` selects it by.
+
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+use crate::domain::tracker::DatabaseConfig;
+
+/// File name of the manifest within the directory of a backup
+pub const BACKUP_MANIFEST_FILE_NAME: &str = "manifest.json";
+
+/// Format of the backup ids, e.g. `20261016T143045Z`
+const BACKUP_ID_FORMAT: &str = "%Y%m%dT%H%M%SZ";
+
+/// A backup of the tracker database, as described by its manifest
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct DatabaseBackup {
+    /// Id of the backup, the UTC time it was taken
+    pub id: String,
+
+    /// When the backup was taken
+    pub created_at: DateTime<Utc>,
+
+    /// Database driver of the tracker (`sqlite3` or `mysql`)
+    pub driver: String,
+
+    /// Name of the backup file, next to the manifest
+    pub file: String,
+
+    /// Size of the backup file in bytes
+    pub size: u64,
+
+    /// SHA-256 checksum of the backup file, in lowercase hex
+    pub sha256: String,
+}
+
+impl DatabaseBackup {
+    /// Returns the id of a backup taken at `created_at`
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use chrono::{TimeZone, Utc};
+    /// use torrust_tracker_deployer_lib::domain::environment::DatabaseBackup;
+    ///
+    /// let created_at = Utc.with_ymd_and_hms(2026, 10, 16, 14, 30, 45).unwrap();
+    /// assert_eq!(DatabaseBackup::id_for(created_at), "20261016T143045Z");
+    /// ```
+    #[must_use]
+    pub fn id_for(created_at: DateTime<Utc>) -> String {
+        created_at.format(BACKUP_ID_FORMAT).to_string()
+    }
+
+    /// Returns the name of the backup file for a database
+    ///
+    /// A `SQLite` backup is a copy of the database file and keeps its name;
+    /// a `MySQL` backup is an SQL dump of the database.
+    #[must_use]
+    pub fn file_name_for(database: &DatabaseConfig) -> String {
+        match database {
+            DatabaseConfig::Sqlite(config) => config.database_name().to_string(),
+            DatabaseConfig::Mysql(config) => format!("{}.sql", config.database_name()),
+        }
+    }
+
+    /// Returns when the backup with `id` was taken, if it is well-formed
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use chrono::{TimeZone, Utc};
+    /// use torrust_tracker_deployer_lib::domain::environment::DatabaseBackup;
+    ///
+    /// let created_at = Utc.with_ymd_and_hms(2026, 10, 16, 14, 30, 45).unwrap();
+    /// assert_eq!(DatabaseBackup::created_at_of("20261016T143045Z"), Some(created_at));
+    /// assert_eq!(DatabaseBackup::created_at_of("latest"), None);
+    /// ```
+    #[must_use]
+    pub fn created_at_of(id: &str) -> Option<DateTime<Utc>> {
+        chrono::NaiveDateTime::parse_from_str(id, BACKUP_ID_FORMAT)
+            .ok()
+            .map(|naive| naive.and_utc())
+    }
+
+    /// Returns `true` if `id` is a well-formed backup id
+    ///
+    /// Used to refuse a `--from` value that could point outside the backups
+    /// directory.
+    #[must_use]
+    pub fn is_valid_id(id: &str) -> bool {
+        chrono::NaiveDateTime::parse_from_str(id, BACKUP_ID_FORMAT).is_ok()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::tracker::SqliteConfig;
+
+    #[test]
+    fn it_should_keep_the_name_of_a_sqlite_database_file() {
+        let database = DatabaseConfig::Sqlite(SqliteConfig::new("tracker.db").unwrap());
+
+        assert_eq!(DatabaseBackup::file_name_for(&database), "tracker.db");
+    }
+
+    #[test]
+    fn it_should_only_accept_ids_in_the_backup_id_format() {
+        assert!(DatabaseBackup::is_valid_id("20261016T143045Z"));
+        assert!(!DatabaseBackup::is_valid_id("../../etc"));
+        assert!(!DatabaseBackup::is_valid_id("latest"));
+    }
+}
@@ -0,0 +1,124 @@
+//! Structured report of a deployment command run
+//!
+//! A `DeploymentReport` is written to `data/{env}/reports/` after every
+//! state-changing command (success or failure), so each deployment run leaves
+//! an auditable, machine-readable artifact. Unlike a `FailureReport`, which
+//! only exists for failures, it records the timing of every workflow step.
+
+use std::collections::BTreeMap;
+use std::path::PathBuf;
+
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+/// Report of one command run against an environment
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct DeploymentReport {
+    /// Name of the command (e.g. `provision`)
+    pub command: String,
+
+    /// Name of the environment the command ran against
+    pub environment: String,
+
+    /// When the command started
+    pub started_at: DateTime<Utc>,
+
+    /// When the command finished
+    pub finished_at: DateTime<Utc>,
+
+    /// Total duration of the command in milliseconds
+    pub duration_ms: u64,
+
+    /// Whether the command succeeded
+    pub succeeded: bool,
+
+    /// State of the environment after the command (e.g. `provisioned`)
+    pub state: Option<String>,
+
+    /// Revision of the environment configuration after the command
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub config_revision: Option<u32>,
+
+    /// Host an out-of-band run targeted instead of the instance of the
+    /// environment (`configure --target-host`); such a run leaves the
+    /// environment state untouched
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub target_host: Option<String>,
+
+    /// Workflow steps in execution order
+    pub steps: Vec<StepReport>,
+
+    /// Versions of the external tools available to the command, keyed by tool
+    pub tool_versions: BTreeMap<String, String>,
+
+    /// Settings applied to the host by the command, keyed by name (e.g. the
+    /// sysctls of the system tuning applied by `configure`)
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub applied_settings: BTreeMap<String, String>,
+
+    /// Trace identifier of the failure, when the command failed
+    pub trace_id: Option<String>,
+
+    /// Trace file written for the failure, when the command failed
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        with = "crate::shared::portable_path::option"
+    )]
+    pub trace_file: Option<PathBuf>,
+
+    /// Error message, when the command failed
+    pub error: Option<String>,
+}
+
+/// Timing of one workflow step
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct StepReport {
+    /// 1-based step index within the workflow
+    pub number: usize,
+
+    /// Human-readable step description
+    pub description: String,
+
+    /// When the step started
+    pub started_at: DateTime<Utc>,
+
+    /// When the step completed, `None` if it did not complete
+    pub finished_at: Option<DateTime<Utc>>,
+
+    /// Duration of the step in milliseconds, `None` if it did not complete
+    pub duration_ms: Option<u64>,
+}
+
+impl DeploymentReport {
+    /// Steps that were started but never completed (the failing step, if any)
+    #[must_use]
+    pub fn incomplete_steps(&self) -> Vec<&StepReport> {
+        self.steps
+            .iter()
+            .filter(|step| step.finished_at.is_none())
+            .collect()
+    }
+}
+
+/// Milliseconds elapsed between two instants, zero if `end` precedes `start`
+#[must_use]
+pub fn elapsed_ms(start: DateTime<Utc>, end: DateTime<Utc>) -> u64 {
+    u64::try_from((end - start).num_milliseconds()).unwrap_or(0)
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::TimeZone;
+
+    use super::*;
+
+    #[test]
+    fn it_should_return_zero_elapsed_time_when_the_end_precedes_the_start() {
+        let start = Utc.with_ymd_and_hms(2026, 1, 1, 12, 0, 5).unwrap();
+        let end = Utc.with_ymd_and_hms(2026, 1, 1, 12, 0, 0).unwrap();
+
+        assert_eq!(elapsed_ms(start, end), 0);
+        assert_eq!(elapsed_ms(end, start), 5000);
+    }
+}
@@ -0,0 +1,267 @@
+//! Structured failure report for failed commands
+//!
+//! A `FailureReport` is the stable, machine-readable counterpart of the
+//! human-readable trace files written to `data/{env}/traces/`. It combines the
+//! failure context persisted in the environment state with the error chain
+//! captured when the failure happened.
+//!
+//! Trace writers serialize a `FailureReport` as JSON next to every `.log`
+//! trace file, so programmatic consumers (SDK, AI agents) can inspect failures
+//! without scraping the text format.
+
+use serde::{Deserialize, Serialize};
+
+use crate::adapters::ansible::TaskFailure;
+use crate::domain::environment::state::{
+    BaseFailureContext, ConfigureStep, DestroyStep, ProvisionStep, ReleaseStep, RunStep,
+};
+use crate::shared::ErrorKind;
+
+/// The step that failed, tagged with the command it belongs to
+///
+/// Compares directly against the command-specific step enums:
+///
+/// ```rust
+/// use torrust_tracker_deployer_lib::domain::environment::failure_report::FailedStep;
+/// use torrust_tracker_deployer_lib::domain::environment::state::ProvisionStep;
+///
+/// let step = FailedStep::from(ProvisionStep::OpenTofuApply);
+/// assert_eq!(step, ProvisionStep::OpenTofuApply);
+/// assert_eq!(step.command(), "provision");
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(tag = "command", content = "step", rename_all = "snake_case")]
+pub enum FailedStep {
+    /// A step of the provision command failed
+    Provision(ProvisionStep),
+    /// A step of the configure command failed
+    Configure(ConfigureStep),
+    /// A step of the release command failed
+    Release(ReleaseStep),
+    /// A step of the run command failed
+    Run(RunStep),
+    /// A step of the destroy command failed
+    Destroy(DestroyStep),
+}
+
+impl FailedStep {
+    /// Name of the command the failed step belongs to
+    #[must_use]
+    pub fn command(&self) -> &'static str {
+        match self {
+            Self::Provision(_) => "provision",
+            Self::Configure(_) => "configure",
+            Self::Release(_) => "release",
+            Self::Run(_) => "run",
+            Self::Destroy(_) => "destroy",
+        }
+    }
+
+    /// Name of the failed step (e.g. `InstallDocker`)
+    ///
+    /// Custom steps are named `custom:{step name}`.
+    #[must_use]
+    pub fn step_name(&self) -> String {
+        if let Some(name) = self.custom_step() {
+            return format!("custom:{name}");
+        }
+
+        match self {
+            Self::Provision(step) => format!("{step:?}"),
+            Self::Configure(step) => format!("{step:?}"),
+            Self::Release(step) => format!("{step:?}"),
+            Self::Run(step) => format!("{step:?}"),
+            Self::Destroy(step) => format!("{step:?}"),
+        }
+    }
+}
+
+impl FailedStep {
+    /// Name of the failed custom step, when a step registered by an SDK user failed
+    #[must_use]
+    pub fn custom_step(&self) -> Option<&str> {
+        match self {
+            Self::Provision(ProvisionStep::CustomStep(name))
+            | Self::Configure(ConfigureStep::CustomStep(name)) => Some(name),
+            _ => None,
+        }
+    }
+}
+
+macro_rules! impl_failed_step_conversions {
+    ($($variant:ident => $step:ty),* $(,)?) => {
+        $(
+            impl From<$step> for FailedStep {
+                fn from(step: $step) -> Self {
+                    Self::$variant(step)
+                }
+            }
+
+            impl PartialEq<$step> for FailedStep {
+                fn eq(&self, other: &$step) -> bool {
+                    matches!(self, Self::$variant(step) if step == other)
+                }
+            }
+        )*
+    };
+}
+
+impl_failed_step_conversions! {
+    Provision => ProvisionStep,
+    Configure => ConfigureStep,
+    Release => ReleaseStep,
+    Run => RunStep,
+    Destroy => DestroyStep,
+}
+
+/// One level of the error chain captured in a trace
+///
+/// Level `0` is the top-level error returned by the command handler; each
+/// following level is the source of the previous one.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct TraceEntry {
+    /// Depth of this error in the chain (0 = top-level error)
+    pub level: usize,
+
+    /// Error category reported by this level
+    pub kind: ErrorKind,
+
+    /// Formatted error message for this level
+    pub message: String,
+}
+
+/// Structured report of the last failure of an environment
+///
+/// This is the serde format written to `{timestamp}-{command}.json` next to
+/// each trace file.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct FailureReport {
+    /// Which step failed, tagged with its command
+    pub failed_step: FailedStep,
+
+    /// Error category for type-safe handling
+    pub error_kind: ErrorKind,
+
+    /// Base failure context with common fields (summary, timestamps, trace id)
+    #[serde(flatten)]
+    pub base: BaseFailureContext,
+
+    /// Error chain from the top-level error down to the root cause
+    ///
+    /// Empty when no structured trace file is available.
+    #[serde(default)]
+    pub error_chain: Vec<TraceEntry>,
+
+    /// The Ansible task that failed, when the step failed in a playbook task
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub failed_task: Option<TaskFailure>,
+}
+
+impl FailureReport {
+    /// Create a report without error chain entries
+    #[must_use]
+    pub fn new(
+        failed_step: impl Into<FailedStep>,
+        error_kind: ErrorKind,
+        base: BaseFailureContext,
+    ) -> Self {
+        Self {
+            failed_step: failed_step.into(),
+            error_kind,
+            base,
+            error_chain: Vec::new(),
+            failed_task: None,
+        }
+    }
+
+    /// Attach the error chain entries parsed from a trace
+    #[must_use]
+    pub fn with_error_chain(mut self, error_chain: Vec<TraceEntry>) -> Self {
+        self.error_chain = error_chain;
+        self
+    }
+
+    /// Attach the Ansible task that failed, if the failure context has one
+    #[must_use]
+    pub fn with_failed_task(mut self, failed_task: Option<TaskFailure>) -> Self {
+        self.failed_task = failed_task;
+        self
+    }
+
+    /// The deepest error in the chain, if any entries were captured
+    #[must_use]
+    pub fn root_cause(&self) -> Option<&TraceEntry> {
+        self.error_chain.last()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use chrono::Utc;
+
+    use super::*;
+    use crate::domain::environment::TraceId;
+
+    fn base_context() -> BaseFailureContext {
+        let now = Utc::now();
+        BaseFailureContext {
+            error_summary: "Infrastructure apply failed".to_string(),
+            failed_at: now,
+            execution_started_at: now,
+            execution_duration: Duration::from_secs(3),
+            trace_id: TraceId::new(),
+            trace_file_path: None,
+        }
+    }
+
+    #[test]
+    fn it_should_compare_failed_step_with_command_specific_step() {
+        let step = FailedStep::from(ProvisionStep::OpenTofuApply);
+
+        assert_eq!(step, ProvisionStep::OpenTofuApply);
+        assert_ne!(step, ProvisionStep::OpenTofuPlan);
+        assert_ne!(step, ConfigureStep::InstallDocker);
+    }
+
+    #[test]
+    fn it_should_serialize_failed_step_tagged_with_its_command() {
+        let json = serde_json::to_value(FailedStep::from(ProvisionStep::OpenTofuApply)).unwrap();
+
+        assert_eq!(
+            json,
+            serde_json::json!({ "command": "provision", "step": "OpenTofuApply" })
+        );
+    }
+
+    #[test]
+    fn it_should_round_trip_failure_report_through_json() {
+        let report = FailureReport::new(
+            ProvisionStep::OpenTofuApply,
+            ErrorKind::InfrastructureOperation,
+            base_context(),
+        )
+        .with_error_chain(vec![
+            TraceEntry {
+                level: 0,
+                kind: ErrorKind::InfrastructureOperation,
+                message: "apply failed".to_string(),
+            },
+            TraceEntry {
+                level: 1,
+                kind: ErrorKind::CommandExecution,
+                message: "tofu exited with status 1".to_string(),
+            },
+        ]);
+
+        let json = serde_json::to_string(&report).unwrap();
+        let parsed: FailureReport = serde_json::from_str(&json).unwrap();
+
+        assert_eq!(parsed, report);
+        assert_eq!(
+            parsed.root_cause().map(|e| e.message.as_str()),
+            Some("tofu exited with status 1")
+        );
+    }
+}
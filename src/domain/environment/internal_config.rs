@@ -18,23 +18,21 @@
 //!
 //! Add new fields here when: Need internal paths or derived configuration.
 
-use crate::domain::environment::EnvironmentName;
+use crate::domain::environment::workspace_layout::{BUILD_DIR_NAME, DATA_DIR_NAME};
+use crate::domain::environment::{EnvironmentName, WorkspaceLayout};
 use crate::domain::provider::Provider;
 use serde::{Deserialize, Serialize};
 use std::path::PathBuf;
 
-/// Base directory name for user data
-const DATA_DIR_NAME: &str = "data";
-
-/// Base directory name for build artifacts
-const BUILD_DIR_NAME: &str = "build";
-
 /// Internal paths and configuration derived from user inputs
 ///
 /// This struct contains fields that are derived automatically from user inputs
 /// and are not directly controlled by users. These represent internal
 /// implementation details for organizing build artifacts and data.
 ///
+/// Both directories are persisted with `/` separators so a state file written
+/// on one platform can be loaded on another (see [`crate::shared::portable_path`]).
+///
 /// # Examples
 ///
 /// ```rust
@@ -49,9 +47,11 @@ const BUILD_DIR_NAME: &str = "build";
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct InternalConfig {
     /// Build directory for this environment (derived from environment name)
+    #[serde(with = "crate::shared::portable_path")]
     pub build_dir: PathBuf,
 
     /// Data directory for this environment (derived from environment name)
+    #[serde(with = "crate::shared::portable_path")]
     pub data_dir: PathBuf,
 }
 
@@ -132,13 +132,21 @@ impl InternalConfig {
     /// ```
     #[must_use]
     pub fn with_working_dir(env_name: &EnvironmentName, working_dir: &std::path::Path) -> Self {
-        // Generate environment-specific directories relative to working directory
-        let data_dir = working_dir.join(DATA_DIR_NAME).join(env_name.as_str());
-        let build_dir = working_dir.join(BUILD_DIR_NAME).join(env_name.as_str());
+        Self::with_layout(env_name, &WorkspaceLayout::in_working_dir(working_dir))
+    }
 
+    /// Creates a new `InternalConfig` with directories under the layout roots
+    ///
+    /// # Returns
+    ///
+    /// A new `InternalConfig` with:
+    /// - `data_dir`: `{layout.data_dir}/{env_name}`
+    /// - `build_dir`: `{layout.build_dir}/{env_name}`
+    #[must_use]
+    pub fn with_layout(env_name: &EnvironmentName, layout: &WorkspaceLayout) -> Self {
         Self {
-            build_dir,
-            data_dir,
+            build_dir: layout.environment_build_dir(env_name),
+            data_dir: layout.environment_data_dir(env_name),
         }
     }
 
@@ -166,6 +174,40 @@ impl InternalConfig {
         self.build_dir.join(super::ANSIBLE_DIR_NAME)
     }
 
+    /// Returns the SSH control socket directory
+    ///
+    /// Path: `build/{env_name}/ssh`
+    #[must_use]
+    pub fn ssh_control_dir(&self) -> PathBuf {
+        self.build_dir.join(super::SSH_DIR_NAME)
+    }
+
+    /// Returns the file holding the recorded SSH host keys
+    ///
+    /// Path: `data/{env_name}/ssh/known_hosts`
+    #[must_use]
+    pub fn ssh_known_hosts_path(&self) -> PathBuf {
+        self.data_dir
+            .join(super::SSH_DIR_NAME)
+            .join(super::KNOWN_HOSTS_FILE_NAME)
+    }
+
+    /// Returns the directory holding the output logs of external tools
+    ///
+    /// Path: `build/{env_name}/logs`
+    #[must_use]
+    pub fn run_logs_dir(&self) -> PathBuf {
+        self.build_dir.join(super::RUN_LOGS_DIR_NAME)
+    }
+
+    /// Returns the directory holding the tracker database backups
+    ///
+    /// Path: `data/{env_name}/backups`
+    #[must_use]
+    pub fn backups_dir(&self) -> PathBuf {
+        self.data_dir.join(super::BACKUPS_DIR_NAME)
+    }
+
     /// Returns the `OpenTofu` build directory for a specific provider
     ///
     /// Path: `build/{env_name}/tofu/{provider_name}`
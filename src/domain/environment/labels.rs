@@ -0,0 +1,349 @@
+//! Labels for organizing environments
+//!
+//! Labels are free-form `key=value` pairs attached to an environment (e.g.
+//! `team=infra`, `purpose=e2e`). They have no effect on the deployment: they
+//! are used to group environments, filter them in the list command, and
+//! select the environments of bulk operations.
+//!
+//! ## Rules
+//!
+//! - Keys have 1 to 63 characters: lowercase letters, digits, `-`, `_` and
+//!   `.`, starting and ending with a letter or a digit
+//! - Values have 1 to 63 characters: letters, digits, `-`, `_` and `.`
+
+use std::collections::BTreeMap;
+use std::fmt;
+use std::str::FromStr;
+
+use serde::{Deserialize, Serialize};
+use thiserror::Error;
+
+/// Maximum length of a label key or value
+pub const MAX_LABEL_LENGTH: usize = 63;
+
+/// Errors that can occur when creating labels
+#[derive(Debug, Clone, Error, PartialEq, Eq)]
+pub enum LabelError {
+    /// The key is empty, too long or uses characters outside the allowed set
+    #[error(
+        "Invalid label key '{key}': expected 1 to {MAX_LABEL_LENGTH} lowercase letters, digits, '-', '_' or '.', starting and ending with a letter or a digit"
+    )]
+    InvalidKey {
+        /// The rejected key
+        key: String,
+    },
+
+    /// The value is empty, too long or uses characters outside the allowed set
+    #[error(
+        "Invalid value '{value}' for label '{key}': expected 1 to {MAX_LABEL_LENGTH} letters, digits, '-', '_' or '.'"
+    )]
+    InvalidValue {
+        /// The key of the label
+        key: String,
+        /// The rejected value
+        value: String,
+    },
+
+    /// The text is not a `key=value` pair
+    #[error("Invalid label '{label}': expected 'key=value' (e.g. 'team=infra')")]
+    MissingValue {
+        /// The rejected text
+        label: String,
+    },
+}
+
+/// A single validated `key=value` label
+///
+/// # Examples
+///
+/// ```rust
+/// use torrust_tracker_deployer_lib::domain::environment::Label;
+///
+/// let label: Label = "purpose=e2e".parse()?;
+/// assert_eq!(label.key(), "purpose");
+/// assert_eq!(label.value(), "e2e");
+///
+/// assert!("Purpose=e2e".parse::<Label>().is_err());
+/// assert!("purpose".parse::<Label>().is_err());
+/// # Ok::<(), Box<dyn std::error::Error>>(())
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Label {
+    key: String,
+    value: String,
+}
+
+impl Label {
+    /// Create a validated label
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the key or the value breaks the label rules
+    pub fn new(key: impl Into<String>, value: impl Into<String>) -> Result<Self, LabelError> {
+        let key = validate_key(key.into())?;
+        let value = value.into();
+
+        let valid_value = (1..=MAX_LABEL_LENGTH).contains(&value.len())
+            && value
+                .chars()
+                .all(|c| c.is_ascii_alphanumeric() || matches!(c, '-' | '_' | '.'));
+
+        if !valid_value {
+            return Err(LabelError::InvalidValue { key, value });
+        }
+
+        Ok(Self { key, value })
+    }
+
+    /// Returns the key of the label
+    #[must_use]
+    pub fn key(&self) -> &str {
+        &self.key
+    }
+
+    /// Returns the value of the label
+    #[must_use]
+    pub fn value(&self) -> &str {
+        &self.value
+    }
+}
+
+impl FromStr for Label {
+    type Err = LabelError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (key, value) = s.split_once('=').ok_or_else(|| LabelError::MissingValue {
+            label: s.to_string(),
+        })?;
+
+        Self::new(key.trim(), value.trim())
+    }
+}
+
+impl fmt::Display for Label {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}={}", self.key, self.value)
+    }
+}
+
+/// Check a label key against the label rules
+///
+/// # Errors
+///
+/// Returns [`LabelError::InvalidKey`] if the key breaks the rules
+pub fn validate_key(key: String) -> Result<String, LabelError> {
+    let edge_is_alphanumeric =
+        |c: Option<char>| c.is_some_and(|c| c.is_ascii_lowercase() || c.is_ascii_digit());
+
+    let valid = key.len() <= MAX_LABEL_LENGTH
+        && edge_is_alphanumeric(key.chars().next())
+        && edge_is_alphanumeric(key.chars().last())
+        && key
+            .chars()
+            .all(|c| c.is_ascii_lowercase() || c.is_ascii_digit() || matches!(c, '-' | '_' | '.'));
+
+    if valid {
+        Ok(key)
+    } else {
+        Err(LabelError::InvalidKey { key })
+    }
+}
+
+/// The labels of an environment, sorted by key
+///
+/// Serialized as a JSON object of string values (e.g.
+/// `{"purpose": "e2e", "team": "infra"}`).
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(transparent)]
+pub struct Labels(BTreeMap<String, String>);
+
+impl Labels {
+    /// Create an empty set of labels
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Set a label, replacing the value of an existing label with the same key
+    pub fn insert(&mut self, label: Label) {
+        self.0.insert(label.key, label.value);
+    }
+
+    /// Remove the label with the given key
+    ///
+    /// Returns whether the label was present.
+    pub fn remove(&mut self, key: &str) -> bool {
+        self.0.remove(key).is_some()
+    }
+
+    /// Returns the value of the label with the given key, if any
+    #[must_use]
+    pub fn get(&self, key: &str) -> Option<&str> {
+        self.0.get(key).map(String::as_str)
+    }
+
+    /// Whether every given label is set with the same value
+    ///
+    /// An empty selection matches any labels.
+    #[must_use]
+    pub fn matches_all(&self, selection: &[Label]) -> bool {
+        selection
+            .iter()
+            .all(|label| self.get(label.key()) == Some(label.value()))
+    }
+
+    /// Returns the labels as a map from key to value
+    #[must_use]
+    pub fn as_map(&self) -> &BTreeMap<String, String> {
+        &self.0
+    }
+
+    /// Whether no label is set
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+}
+
+impl TryFrom<BTreeMap<String, String>> for Labels {
+    type Error = LabelError;
+
+    fn try_from(map: BTreeMap<String, String>) -> Result<Self, Self::Error> {
+        let mut labels = Self::new();
+        for (key, value) in map {
+            labels.insert(Label::new(key, value)?);
+        }
+        Ok(labels)
+    }
+}
+
+impl FromIterator<Label> for Labels {
+    fn from_iter<I: IntoIterator<Item = Label>>(iter: I) -> Self {
+        let mut labels = Self::new();
+        for label in iter {
+            labels.insert(label);
+        }
+        labels
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn label(text: &str) -> Label {
+        text.parse().unwrap()
+    }
+
+    #[test]
+    fn it_should_parse_a_key_value_pair() {
+        let label = label(" team = infra ");
+
+        assert_eq!(label.key(), "team");
+        assert_eq!(label.value(), "infra");
+        assert_eq!(label.to_string(), "team=infra");
+    }
+
+    #[test]
+    fn it_should_accept_keys_with_dots_dashes_and_underscores() {
+        for key in ["a", "cost-center", "cost_center", "example.com.team", "x1"] {
+            assert!(
+                Label::new(key, "v").is_ok(),
+                "expected '{key}' to be accepted"
+            );
+        }
+    }
+
+    #[test]
+    fn it_should_reject_invalid_keys() {
+        let too_long = "k".repeat(MAX_LABEL_LENGTH + 1);
+
+        for key in [
+            "",
+            "Team",
+            "-team",
+            "team-",
+            "team name",
+            "team/infra",
+            &too_long,
+        ] {
+            assert_eq!(
+                Label::new(key, "v"),
+                Err(LabelError::InvalidKey {
+                    key: key.to_string()
+                }),
+                "expected '{key}' to be rejected"
+            );
+        }
+    }
+
+    #[test]
+    fn it_should_reject_invalid_values() {
+        let too_long = "v".repeat(MAX_LABEL_LENGTH + 1);
+
+        for value in ["", "two words", "a=b", &too_long] {
+            assert!(
+                matches!(
+                    Label::new("team", value),
+                    Err(LabelError::InvalidValue { .. })
+                ),
+                "expected '{value}' to be rejected"
+            );
+        }
+    }
+
+    #[test]
+    fn it_should_reject_text_without_a_value() {
+        assert_eq!(
+            "purpose".parse::<Label>(),
+            Err(LabelError::MissingValue {
+                label: "purpose".to_string()
+            })
+        );
+    }
+
+    #[test]
+    fn it_should_replace_the_value_of_an_existing_key() {
+        let mut labels: Labels = [label("team=infra")].into_iter().collect();
+
+        labels.insert(label("team=tracker"));
+
+        assert_eq!(labels.get("team"), Some("tracker"));
+        assert_eq!(labels.as_map().len(), 1);
+    }
+
+    #[test]
+    fn it_should_match_when_every_selected_label_is_set() {
+        let labels: Labels = [label("team=infra"), label("purpose=e2e")]
+            .into_iter()
+            .collect();
+
+        assert!(labels.matches_all(&[]));
+        assert!(labels.matches_all(&[label("purpose=e2e")]));
+        assert!(labels.matches_all(&[label("purpose=e2e"), label("team=infra")]));
+        assert!(!labels.matches_all(&[label("purpose=e2e"), label("team=tracker")]));
+        assert!(!labels.matches_all(&[label("owner=alice")]));
+    }
+
+    #[test]
+    fn it_should_validate_labels_read_from_a_map() {
+        let map = BTreeMap::from([("Team".to_string(), "infra".to_string())]);
+
+        assert!(matches!(
+            Labels::try_from(map),
+            Err(LabelError::InvalidKey { .. })
+        ));
+    }
+
+    #[test]
+    fn it_should_serialize_as_a_json_object() {
+        let labels: Labels = [label("team=infra"), label("purpose=e2e")]
+            .into_iter()
+            .collect();
+
+        let json = serde_json::to_string(&labels).unwrap();
+
+        assert_eq!(json, r#"{"purpose":"e2e","team":"infra"}"#);
+        assert_eq!(serde_json::from_str::<Labels>(&json).unwrap(), labels);
+    }
+}
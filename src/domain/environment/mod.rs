@@ -52,6 +52,10 @@
 //! - `runtime_outputs` - Data generated during deployment
 //! - `name` - Environment name validation and management
 //! - `state` - State marker types and type erasure for environment state machine
+//! - `failure_report` - Structured, serializable report of command failures
+//! - `deployment_report` - Structured report of every deployment command run
+//! - `config_diff` - Changed settings between two sets of user inputs and their impact
+//! - `database_backup` - Tracker database backups taken by the `backup` command
 //!
 //! ## Main Entity
 //!
@@ -87,6 +91,7 @@
 //! );
 //! let provider_config = ProviderConfig::Lxd(LxdConfig {
 //!     profile_name: ProfileName::new(format!("lxd-{}", env_name.as_str())).unwrap(),
+//!     remote: None,
 //! });
 //! let created_at = Utc.with_ymd_and_hms(2025, 1, 1, 0, 0, 0).unwrap();
 //! let environment = Environment::new(env_name, provider_config, ssh_credentials, 22, created_at);
@@ -99,15 +104,24 @@
 //! # Ok::<(), Box<dyn std::error::Error>>(())
 //! ```
 
+pub mod config_diff;
 pub mod context;
+pub mod database_backup;
+pub mod deployment_report;
+pub mod failure_report;
 pub mod internal_config;
+pub mod labels;
 pub mod name;
 pub mod params;
 pub mod repository;
 pub mod runtime_outputs;
+pub mod secrets;
 pub mod state;
 mod trace_id;
+pub mod traces_config;
 pub mod user_inputs;
+pub mod workspace_layout;
+pub mod workspace_marker;
 
 // Test utilities (only available in test configuration)
 #[cfg(test)]
@@ -117,17 +131,29 @@ pub mod testing;
 pub use trace_id::TraceId;
 
 // Re-export commonly used types for convenience
-pub use context::EnvironmentContext;
+pub use config_diff::{ChangeImpact, ConfigChange, ConfigDiff};
+pub use context::{EnvironmentContext, INITIAL_CONFIG_REVISION};
+pub use database_backup::{DatabaseBackup, BACKUP_MANIFEST_FILE_NAME};
+pub use deployment_report::{DeploymentReport, StepReport};
+pub use failure_report::{FailedStep, FailureReport, TraceEntry};
 pub use internal_config::InternalConfig;
+pub use labels::{Label, LabelError, Labels};
 pub use name::{EnvironmentName, EnvironmentNameError};
 pub use params::EnvironmentParams;
-pub use runtime_outputs::{ProvisionMethod, RuntimeOutputs};
+pub use runtime_outputs::{
+    DeploymentPhase, InstanceAddresses, PhaseDurations, ProvisionMethod, ReleaseRecord,
+    RuntimeOutputs,
+};
+pub use secrets::EnvironmentSecrets;
 pub use state::{
     AnyEnvironmentState, ConfigureFailed, Configured, Configuring, Created, DestroyFailed,
     Destroyed, Destroying, ProvisionFailed, Provisioned, Provisioning, ReleaseFailed, Released,
-    Releasing, RunFailed, Running,
+    Releasing, RunFailed, Running, Stopped,
 };
+pub use traces_config::{TracesConfig, TracesConfigError, DEFAULT_KEPT_TRACES};
 pub use user_inputs::{UserInputs, UserInputsError};
+pub use workspace_layout::WorkspaceLayout;
+pub use workspace_marker::{DiskSpaceThresholds, RepositoryBackend, WorkspaceMarker};
 
 // Re-export tracker types for convenience
 pub use crate::domain::tracker::{
@@ -146,6 +172,8 @@ pub use crate::domain::backup::BackupConfig;
 
 use crate::adapters::ssh::SshCredentials;
 use crate::domain::provider::ProviderConfig;
+use crate::domain::provision::ProvisionConfig;
+use crate::domain::template::ExtraTemplateVariables;
 use crate::domain::{InstanceName, ProfileName};
 use crate::shared::Username;
 use chrono::{DateTime, Utc};
@@ -165,6 +193,21 @@ pub const ANSIBLE_DIR_NAME: &str = "ansible";
 /// Directory name for OpenTofu-related files
 pub const TOFU_DIR_NAME: &str = "tofu";
 
+/// Directory name for SSH control sockets and host keys
+pub const SSH_DIR_NAME: &str = "ssh";
+
+/// Directory name for the files of out-of-band runs (`configure --target-host`)
+pub const OUT_OF_BAND_DIR_NAME: &str = "out-of-band";
+
+/// File name of the recorded SSH host keys within the SSH directory
+pub const KNOWN_HOSTS_FILE_NAME: &str = "known_hosts";
+
+/// Directory name for the output logs of external tools within an environment's build directory
+pub const RUN_LOGS_DIR_NAME: &str = "logs";
+
+/// Directory name for the tracker database backups within an environment's data directory
+pub const BACKUPS_DIR_NAME: &str = "backups";
+
 /// Provider name for LXD infrastructure
 pub const LXD_PROVIDER_NAME: &str = "lxd";
 
@@ -262,6 +305,7 @@ impl Environment {
     /// );
     /// let provider_config = ProviderConfig::Lxd(LxdConfig {
     ///     profile_name: ProfileName::new("torrust-profile-production".to_string())?,
+    ///     remote: None,
     /// });
     /// let ssh_port = 22;
     /// let created_at = Utc.with_ymd_and_hms(2025, 1, 1, 0, 0, 0).unwrap();
@@ -328,7 +372,29 @@ impl Environment {
         working_dir: &std::path::Path,
         created_at: DateTime<Utc>,
     ) -> Result<Environment<Created>, UserInputsError> {
-        let context = EnvironmentContext::create(params, working_dir, created_at)?;
+        Self::create_in_layout(
+            params,
+            &WorkspaceLayout::in_working_dir(working_dir),
+            created_at,
+        )
+    }
+
+    /// Creates a new environment in Created state whose directories follow `layout`
+    ///
+    /// Same as [`Environment::create`], with the data and build roots given
+    /// explicitly instead of derived from a working directory.
+    ///
+    /// # Errors
+    ///
+    /// Returns `UserInputsError` if the cross-service configuration is invalid
+    /// (see [`Environment::create`]).
+    #[allow(clippy::needless_pass_by_value)] // Public API takes ownership for ergonomics
+    pub fn create_in_layout(
+        params: EnvironmentParams,
+        layout: &WorkspaceLayout,
+        created_at: DateTime<Utc>,
+    ) -> Result<Environment<Created>, UserInputsError> {
+        let context = EnvironmentContext::create_in_layout(params, layout, created_at)?;
 
         Ok(Environment {
             context,
@@ -384,6 +450,17 @@ impl<S> Environment<S> {
         self.with_state(Destroying)
     }
 
+    /// Transitions from any state to Configuring state, for an out-of-band run
+    ///
+    /// Used by `configure --target-host`, which runs the configuration
+    /// playbooks against another host than the instance of the environment.
+    /// The returned environment only lives in memory and must never be
+    /// persisted: the recorded state of the environment is left untouched.
+    #[must_use]
+    pub fn start_configuring_out_of_band(self) -> Environment<Configuring> {
+        self.with_state(Configuring)
+    }
+
     /// Transitions from any state to Destroyed state
     ///
     /// This method can be called from any state to destroy the environment.
@@ -546,6 +623,7 @@ impl<S> Environment<S> {
     /// );
     /// let provider_config = ProviderConfig::Lxd(LxdConfig {
     ///     profile_name: ProfileName::new(format!("lxd-{}", env_name.as_str())).unwrap(),
+    ///     remote: None,
     /// });
     /// let created_at = Utc.with_ymd_and_hms(2025, 1, 1, 0, 0, 0).unwrap();
     /// let environment = Environment::new(env_name, provider_config, ssh_credentials, 22, created_at);
@@ -579,11 +657,21 @@ impl<S> Environment<S> {
         self.context.created_at()
     }
 
+    /// Returns the revision of the user inputs
+    ///
+    /// 1 when the environment is created, incremented by every configuration
+    /// override that changes the user inputs.
+    #[must_use]
+    pub fn config_revision(&self) -> u32 {
+        self.context.config_revision
+    }
+
     /// Returns the provision method for this environment
     ///
     /// This method indicates how the infrastructure was provisioned:
     /// - `Some(Provisioned)`: Created via `provision` command using `OpenTofu`
     /// - `Some(Registered)`: Connected to existing infrastructure via `register` command
+    /// - `Some(Imported)`: Imported from an existing deployment via `import` command
     /// - `None`: Unknown or legacy state (before this field was added)
     ///
     /// # Returns
@@ -601,6 +689,7 @@ impl<S> Environment<S> {
     ///
     /// Infrastructure is NOT managed if:
     /// - It was registered from existing infrastructure via the `register` command
+    /// - It was imported from an existing deployment via the `import` command
     /// - The provision method is unknown (legacy state)
     ///
     /// # Returns
@@ -629,6 +718,7 @@ impl<S> Environment<S> {
     /// );
     /// let provider_config = ProviderConfig::Lxd(LxdConfig {
     ///     profile_name: ProfileName::new(format!("lxd-{}", env_name.as_str())).unwrap(),
+    ///     remote: None,
     /// });
     ///
     /// let created_at = Utc.with_ymd_and_hms(2025, 1, 1, 0, 0, 0).unwrap();
@@ -647,12 +737,11 @@ impl<S> Environment<S> {
     #[must_use]
     pub fn is_infrastructure_managed(&self) -> bool {
         // Only infrastructure provisioned by this tool can be managed/destroyed
-        // Registered environments have external infrastructure we don't control
-        match self.provision_method() {
-            Some(ProvisionMethod::Registered) => false,
-            // Provisioned or legacy (None) environments are assumed managed
-            Some(ProvisionMethod::Provisioned) | None => true,
-        }
+        // Registered and imported environments have external infrastructure we don't control
+        // Provisioned or legacy (None) environments are assumed managed
+        !self
+            .provision_method()
+            .is_some_and(ProvisionMethod::is_externally_provisioned)
     }
 
     /// Sets the instance IP address for this environment
@@ -689,6 +778,7 @@ impl<S> Environment<S> {
     /// );
     /// let provider_config = ProviderConfig::Lxd(LxdConfig {
     ///     profile_name: ProfileName::new(format!("lxd-{}", env_name.as_str())).unwrap(),
+    ///     remote: None,
     /// });
     /// let created_at = Utc.with_ymd_and_hms(2025, 1, 1, 0, 0, 0).unwrap();
     /// let environment = Environment::new(env_name, provider_config, ssh_credentials, 22, created_at);
@@ -702,8 +792,19 @@ impl<S> Environment<S> {
     /// # Ok::<(), Box<dyn std::error::Error>>(())
     /// ```
     #[must_use]
-    pub fn with_instance_ip(mut self, ip: IpAddr) -> Self {
-        self.context_mut().runtime_outputs.set_instance_ip(ip);
+    pub fn with_instance_ip(self, ip: IpAddr) -> Self {
+        self.with_instance_addresses(ip)
+    }
+
+    /// Sets the instance addresses and returns a new environment with them set
+    ///
+    /// Dual-stack instances have an IPv4 and an IPv6 address. Both replace
+    /// any address recorded before.
+    #[must_use]
+    pub fn with_instance_addresses(mut self, addresses: impl Into<InstanceAddresses>) -> Self {
+        self.context_mut()
+            .runtime_outputs
+            .set_instance_addresses(addresses);
         self
     }
 
@@ -728,6 +829,43 @@ impl<S> Environment<S> {
         self
     }
 
+    /// Records how long the command of `phase` took and returns the environment
+    ///
+    /// Called by the `provision`, `configure`, `release` and `run` commands
+    /// when they succeed, so deployment durations can be compared over time.
+    #[must_use]
+    pub fn with_phase_duration(mut self, phase: DeploymentPhase, duration_ms: u64) -> Self {
+        self.context_mut()
+            .runtime_outputs
+            .record_phase_duration(phase, duration_ms);
+        self
+    }
+
+    /// Replaces the user inputs with overridden ones and returns the environment
+    ///
+    /// Called by the `configure` and `release` commands when a configuration
+    /// override changes the user inputs. The configuration revision is
+    /// incremented, so reports can tell which revision was deployed.
+    #[must_use]
+    pub fn with_overridden_user_inputs(mut self, user_inputs: UserInputs) -> Self {
+        let context = self.context_mut();
+        context.user_inputs = user_inputs;
+        context.config_revision += 1;
+        self
+    }
+
+    /// Sets the host port SSH is published on and returns the environment
+    ///
+    /// Used by providers that publish the SSH port of the instance on another
+    /// host port (e.g. the Docker provider). `None` keeps the configured port.
+    #[must_use]
+    pub fn with_published_ssh_port(mut self, port: Option<u16>) -> Self {
+        self.context_mut()
+            .runtime_outputs
+            .set_published_ssh_port(port);
+        self
+    }
+
     /// Returns the templates directory for this environment
     ///
     /// The templates directory is located at `data/{env_name}/templates/`
@@ -753,6 +891,7 @@ impl<S> Environment<S> {
     /// );
     /// let provider_config = ProviderConfig::Lxd(LxdConfig {
     ///     profile_name: ProfileName::new(format!("lxd-{}", env_name.as_str())).unwrap(),
+    ///     remote: None,
     /// });
     /// let created_at = Utc.with_ymd_and_hms(2025, 1, 1, 0, 0, 0).unwrap();
     /// let environment = Environment::new(env_name, provider_config, ssh_credentials, 22, created_at);
@@ -769,10 +908,41 @@ impl<S> Environment<S> {
         self.context.templates_dir()
     }
 
+    /// Returns the directory with user-provided template overrides, if configured
+    ///
+    /// Templates found under this directory take precedence over the
+    /// embedded templates with the same relative path.
+    #[must_use]
+    pub fn templates_override_dir(&self) -> Option<PathBuf> {
+        self.context.templates_override_dir()
+    }
+
+    /// Returns the user-defined variables added to the context of every template
+    ///
+    /// Variables with the same name as a variable provided by the deployer
+    /// are ignored.
+    #[must_use]
+    pub fn extra_template_variables(&self) -> &ExtraTemplateVariables {
+        self.context.extra_template_variables()
+    }
+
+    /// Returns the provision workflow settings (e.g. the cloud-init timeout)
+    #[must_use]
+    pub fn provision_config(&self) -> &ProvisionConfig {
+        self.context.provision_config()
+    }
+
+    /// Returns the location and retention of the trace files
+    #[must_use]
+    pub fn traces_config(&self) -> &TracesConfig {
+        self.context.traces_config()
+    }
+
     /// Returns the traces directory for this environment
     ///
-    /// The traces directory is located at `data/{env_name}/traces/`
-    /// and contains error trace files for failed operations.
+    /// The traces directory is located at `data/{env_name}/traces/`, unless
+    /// the environment configuration sets another one, and contains error
+    /// trace files for failed operations.
     ///
     /// # Examples
     ///
@@ -794,6 +964,7 @@ impl<S> Environment<S> {
     /// );
     /// let provider_config = ProviderConfig::Lxd(LxdConfig {
     ///     profile_name: ProfileName::new(format!("lxd-{}", env_name.as_str())).unwrap(),
+    ///     remote: None,
     /// });
     /// let created_at = Utc.with_ymd_and_hms(2025, 1, 1, 0, 0, 0).unwrap();
     /// let environment = Environment::new(env_name, provider_config, ssh_credentials, 22, created_at);
@@ -832,6 +1003,7 @@ impl<S> Environment<S> {
     /// );
     /// let provider_config = ProviderConfig::Lxd(LxdConfig {
     ///     profile_name: ProfileName::new(format!("lxd-{}", env_name.as_str())).unwrap(),
+    ///     remote: None,
     /// });
     /// let created_at = Utc.with_ymd_and_hms(2025, 1, 1, 0, 0, 0).unwrap();
     /// let environment = Environment::new(env_name, provider_config, ssh_credentials, 22, created_at);
@@ -848,6 +1020,38 @@ impl<S> Environment<S> {
         self.context.ansible_build_dir()
     }
 
+    /// Returns the directory holding the SSH control sockets of this environment
+    ///
+    /// Path: `build/{env_name}/ssh`
+    #[must_use]
+    pub fn ssh_control_dir(&self) -> PathBuf {
+        self.context.ssh_control_dir()
+    }
+
+    /// Returns the file holding the recorded SSH host keys of the instance
+    ///
+    /// Path: `data/{env_name}/ssh/known_hosts`
+    #[must_use]
+    pub fn ssh_known_hosts_path(&self) -> PathBuf {
+        self.context.ssh_known_hosts_path()
+    }
+
+    /// Returns the directory holding the output logs of the external tools run for this environment
+    ///
+    /// Path: `build/{env_name}/logs`
+    #[must_use]
+    pub fn run_logs_dir(&self) -> PathBuf {
+        self.context.run_logs_dir()
+    }
+
+    /// Returns the directory holding the tracker database backups of this environment
+    ///
+    /// Path: `data/{env_name}/backups`
+    #[must_use]
+    pub fn backups_dir(&self) -> PathBuf {
+        self.context.backups_dir()
+    }
+
     /// Returns the tofu build directory for this environment
     ///
     /// # Examples
@@ -870,6 +1074,7 @@ impl<S> Environment<S> {
     /// );
     /// let provider_config = ProviderConfig::Lxd(LxdConfig {
     ///     profile_name: ProfileName::new(format!("lxd-{}", env_name.as_str())).unwrap(),
+    ///     remote: None,
     /// });
     /// let created_at = Utc.with_ymd_and_hms(2025, 1, 1, 0, 0, 0).unwrap();
     /// let environment = Environment::new(env_name, provider_config, ssh_credentials, 22, created_at);
@@ -908,6 +1113,7 @@ impl<S> Environment<S> {
     /// );
     /// let provider_config = ProviderConfig::Lxd(LxdConfig {
     ///     profile_name: ProfileName::new(format!("lxd-{}", env_name.as_str())).unwrap(),
+    ///     remote: None,
     /// });
     /// let created_at = Utc.with_ymd_and_hms(2025, 1, 1, 0, 0, 0).unwrap();
     /// let environment = Environment::new(env_name, provider_config, ssh_credentials, 22, created_at);
@@ -946,6 +1152,7 @@ impl<S> Environment<S> {
     /// );
     /// let provider_config = ProviderConfig::Lxd(LxdConfig {
     ///     profile_name: ProfileName::new(format!("lxd-{}", env_name.as_str())).unwrap(),
+    ///     remote: None,
     /// });
     /// let created_at = Utc.with_ymd_and_hms(2025, 1, 1, 0, 0, 0).unwrap();
     /// let environment = Environment::new(env_name, provider_config, ssh_credentials, 22, created_at);
@@ -1057,6 +1264,7 @@ mod tests {
             let ssh_port = 22;
             let provider_config = ProviderConfig::Lxd(LxdConfig {
                 profile_name: ProfileName::new(format!("lxd-{}", env_name.as_str())).unwrap(),
+                remote: None,
             });
 
             Environment::new(
@@ -1087,6 +1295,7 @@ mod tests {
             let ssh_port = 22;
             let provider_config = ProviderConfig::Lxd(LxdConfig {
                 profile_name: ProfileName::new(format!("lxd-{}", env_name.as_str())).unwrap(),
+                remote: None,
             });
             let environment = Environment::new(
                 env_name,
@@ -1116,7 +1325,10 @@ mod tests {
             );
 
             let profile_name = ProfileName::new(format!("lxd-{}", env_name.as_str())).unwrap();
-            let provider_config = ProviderConfig::Lxd(LxdConfig { profile_name });
+            let provider_config = ProviderConfig::Lxd(LxdConfig {
+                profile_name,
+                remote: None,
+            });
 
             let user_inputs = UserInputs::with_tracker(
                 &env_name,
@@ -1139,6 +1351,7 @@ mod tests {
                 },
                 runtime_outputs: RuntimeOutputs::new(),
                 created_at: chrono::Utc::now(),
+                config_revision: INITIAL_CONFIG_REVISION,
             };
 
             let environment = Environment {
@@ -1577,7 +1790,7 @@ mod tests {
                     .build();
 
                 // Runtime outputs start empty
-                assert_eq!(env.context.runtime_outputs.instance_ip(), None);
+                assert!(env.context.runtime_outputs.addresses().is_empty());
             }
 
             #[test]
@@ -1590,7 +1803,7 @@ mod tests {
                 let ip = IpAddr::V4(Ipv4Addr::new(10, 0, 0, 1));
                 let env = env.with_instance_ip(ip);
 
-                assert_eq!(env.context.runtime_outputs.instance_ip(), Some(ip));
+                assert_eq!(env.context.instance_ip(), Some(ip));
             }
 
             #[test]
@@ -1618,6 +1831,17 @@ mod tests {
                 assert_eq!(env.ssh_port(), 22);
                 assert_eq!(env.instance_ip(), None);
             }
+
+            #[test]
+            fn it_should_connect_to_the_published_ssh_port_when_one_is_recorded() {
+                let env = EnvironmentTestBuilder::new()
+                    .with_name("test-published-ssh-port")
+                    .build()
+                    .with_published_ssh_port(Some(49153));
+
+                assert_eq!(env.ssh_port(), 49153);
+                assert_eq!(env.context.user_inputs.ssh_port(), 22);
+            }
         }
     }
 }
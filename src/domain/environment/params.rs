@@ -39,12 +39,25 @@
 //! // or directly in domain tests
 //! ```
 
+use std::path::PathBuf;
+
 use crate::adapters::ssh::SshCredentials;
 use crate::domain::backup::BackupConfig;
+use crate::domain::caddy::CaddyConfig;
+use crate::domain::environment::{Labels, TracesConfig};
+use crate::domain::firewall::FirewallConfig;
 use crate::domain::grafana::GrafanaConfig;
 use crate::domain::https::HttpsConfig;
+use crate::domain::mysql::MysqlServiceConfig;
 use crate::domain::prometheus::PrometheusConfig;
-use crate::domain::provider::ProviderConfig;
+use crate::domain::provider::{Provider, ProviderConfig, DEFAULT_LXD_IMAGE};
+use crate::domain::provision::{
+    CloudInitCustomization, ExternalResource, InstanceResources, OfflineMode, ProvisionConfig,
+    ProxyConfig,
+};
+use crate::domain::release::ReleaseConfig;
+use crate::domain::system_tuning::SystemTuningConfig;
+use crate::domain::template::ExtraTemplateVariables;
 use crate::domain::tracker::TrackerConfig;
 use crate::domain::{EnvironmentName, InstanceName};
 
@@ -61,6 +74,12 @@ use crate::domain::{EnvironmentName, InstanceName};
 /// - **Application**: `tracker_config`
 /// - **Observability**: `prometheus_config`, `grafana_config`
 /// - **Security**: `https_config`
+/// - **Customization**: `templates_override_dir`, `extra_template_variables`,
+///   `provision_config`, `firewall_config`, `system_tuning`, `cloud_init`,
+///   `instance_resources`, `offline`, `proxy`
+/// - **Release**: `release`
+/// - **Organization**: `labels`
+/// - **Diagnostics**: `traces`
 ///
 /// # Invariants
 ///
@@ -101,6 +120,42 @@ pub struct EnvironmentParams {
 
     /// Optional backup service configuration
     pub backup_config: Option<BackupConfig>,
+
+    /// Optional directory with user-provided template overrides
+    pub templates_override_dir: Option<PathBuf>,
+
+    /// User-defined variables added to the context of every template
+    pub extra_template_variables: ExtraTemplateVariables,
+
+    /// Provision workflow settings
+    pub provision_config: ProvisionConfig,
+
+    /// Host firewall settings
+    pub firewall_config: FirewallConfig,
+
+    /// Host kernel parameters, resource limits and swap file
+    pub system_tuning: SystemTuningConfig,
+
+    /// User additions to the cloud-init configuration
+    pub cloud_init: CloudInitCustomization,
+
+    /// CPU, memory and disk of the instance
+    pub instance_resources: InstanceResources,
+
+    /// Pre-seeded artifacts replacing the downloads, when deploying offline
+    pub offline: Option<OfflineMode>,
+
+    /// Outbound HTTP proxy the downloads go through, if any
+    pub proxy: Option<ProxyConfig>,
+
+    /// Service image overrides and private registry of the release, if any
+    pub release: Option<ReleaseConfig>,
+
+    /// Labels for organizing environments
+    pub labels: Labels,
+
+    /// Location and retention of the trace files
+    pub traces: TracesConfig,
 }
 
 impl EnvironmentParams {
@@ -147,7 +202,159 @@ impl EnvironmentParams {
             grafana_config,
             https_config,
             backup_config,
+            templates_override_dir: None,
+            extra_template_variables: ExtraTemplateVariables::default(),
+            provision_config: ProvisionConfig::default(),
+            firewall_config: FirewallConfig::default(),
+            system_tuning: SystemTuningConfig::default(),
+            cloud_init: CloudInitCustomization::default(),
+            instance_resources: InstanceResources::default(),
+            offline: None,
+            proxy: None,
+            release: None,
+            labels: Labels::default(),
+            traces: TracesConfig::default(),
+        }
+    }
+
+    /// Sets the directory with user-provided template overrides
+    #[must_use]
+    pub fn with_templates_override_dir(mut self, templates_override_dir: Option<PathBuf>) -> Self {
+        self.templates_override_dir = templates_override_dir;
+        self
+    }
+
+    /// Sets the provision workflow settings
+    #[must_use]
+    pub fn with_provision_config(mut self, provision_config: ProvisionConfig) -> Self {
+        self.provision_config = provision_config;
+        self
+    }
+
+    /// Sets the host firewall settings
+    #[must_use]
+    pub fn with_firewall_config(mut self, firewall_config: FirewallConfig) -> Self {
+        self.firewall_config = firewall_config;
+        self
+    }
+
+    /// Sets the host kernel parameters, resource limits and swap file
+    #[must_use]
+    pub fn with_system_tuning(mut self, system_tuning: SystemTuningConfig) -> Self {
+        self.system_tuning = system_tuning;
+        self
+    }
+
+    /// Sets the user additions to the cloud-init configuration
+    #[must_use]
+    pub fn with_cloud_init(mut self, cloud_init: CloudInitCustomization) -> Self {
+        self.cloud_init = cloud_init;
+        self
+    }
+
+    /// Sets the CPU, memory and disk of the instance
+    #[must_use]
+    pub fn with_instance_resources(mut self, instance_resources: InstanceResources) -> Self {
+        self.instance_resources = instance_resources;
+        self
+    }
+
+    /// Sets the pre-seeded artifacts used to deploy offline
+    #[must_use]
+    pub fn with_offline(mut self, offline: Option<OfflineMode>) -> Self {
+        self.offline = offline;
+        self
+    }
+
+    /// Sets the user-defined variables added to every template context
+    #[must_use]
+    pub fn with_extra_template_variables(
+        mut self,
+        extra_template_variables: ExtraTemplateVariables,
+    ) -> Self {
+        self.extra_template_variables = extra_template_variables;
+        self
+    }
+
+    /// Sets the outbound HTTP proxy the downloads go through
+    #[must_use]
+    pub fn with_proxy(mut self, proxy: Option<ProxyConfig>) -> Self {
+        self.proxy = proxy;
+        self
+    }
+
+    /// Sets the service image overrides and private registry of the release
+    #[must_use]
+    pub fn with_release(mut self, release: Option<ReleaseConfig>) -> Self {
+        self.release = release;
+        self
+    }
+
+    /// Sets the labels of the environment
+    #[must_use]
+    pub fn with_labels(mut self, labels: Labels) -> Self {
+        self.labels = labels;
+        self
+    }
+
+    /// Sets the location and retention of the trace files
+    #[must_use]
+    pub fn with_traces(mut self, traces: TracesConfig) -> Self {
+        self.traces = traces;
+        self
+    }
+
+    /// Returns the resources the deployment downloads from the Internet
+    ///
+    /// Lists every download regardless of the offline mode; see
+    /// [`OfflineMode::uncovered`] for the ones it does not replace.
+    #[must_use]
+    pub fn external_resources(&self) -> Vec<ExternalResource> {
+        let mut resources = Vec::new();
+        let provider = self.provider_config.provider();
+
+        if provider == Provider::Lxd {
+            resources.push(ExternalResource::BaseImage {
+                image: DEFAULT_LXD_IMAGE.to_string(),
+            });
+        }
+
+        // The Docker provider runs a container image that ships Docker
+        if provider != Provider::Docker {
+            if !self.cloud_init.extra_packages().is_empty() {
+                resources.push(ExternalResource::FirstBootPackages {
+                    packages: self.cloud_init.extra_packages().to_vec(),
+                });
+            }
+            resources.push(ExternalResource::UbuntuPackages);
+            resources.push(ExternalResource::DockerCompose);
         }
+
+        let images = [
+            Some(self.tracker_config.image()),
+            self.tracker_config
+                .uses_mysql()
+                .then(MysqlServiceConfig::docker_image),
+            self.prometheus_config
+                .as_ref()
+                .map(|_| PrometheusConfig::docker_image()),
+            self.grafana_config
+                .as_ref()
+                .map(|_| GrafanaConfig::docker_image()),
+            self.https_config
+                .as_ref()
+                .map(|_| CaddyConfig::docker_image()),
+            self.backup_config
+                .as_ref()
+                .map(|_| BackupConfig::docker_image()),
+        ];
+        resources.extend(images.into_iter().flatten().map(|image| {
+            ExternalResource::ContainerImage {
+                image: image.full_reference(),
+            }
+        }));
+
+        resources
     }
 }
 
@@ -157,7 +364,6 @@ mod tests {
     use crate::domain::provider::LxdConfig;
     use crate::domain::ProfileName;
     use crate::shared::Username;
-    use std::path::PathBuf;
 
     fn sample_ssh_credentials() -> SshCredentials {
         let project_root = env!("CARGO_MANIFEST_DIR");
@@ -179,6 +385,7 @@ mod tests {
             InstanceName::new("test-instance".to_string()).unwrap(),
             ProviderConfig::Lxd(LxdConfig {
                 profile_name: ProfileName::new("lxd-test").unwrap(),
+                remote: None,
             }),
             sample_ssh_credentials(),
             22,
@@ -201,6 +408,7 @@ mod tests {
             InstanceName::new("prod-vm".to_string()).unwrap(),
             ProviderConfig::Lxd(LxdConfig {
                 profile_name: ProfileName::new("lxd-prod").unwrap(),
+                remote: None,
             }),
             sample_ssh_credentials(),
             2222,
@@ -222,4 +430,42 @@ mod tests {
         let _grafana: &Option<GrafanaConfig> = &params.grafana_config;
         let _https: &Option<HttpsConfig> = &params.https_config;
     }
+
+    #[test]
+    fn it_should_list_the_downloads_of_an_lxd_deployment() {
+        let params = EnvironmentParams::new(
+            EnvironmentName::new("lab").unwrap(),
+            InstanceName::new("lab-vm".to_string()).unwrap(),
+            ProviderConfig::Lxd(LxdConfig {
+                profile_name: ProfileName::new("lxd-lab").unwrap(),
+                remote: None,
+            }),
+            sample_ssh_credentials(),
+            22,
+            sample_tracker_config(),
+            Some(PrometheusConfig::default()),
+            None,
+            None,
+            None,
+        );
+
+        let resources = params.external_resources();
+
+        assert_eq!(
+            resources,
+            vec![
+                ExternalResource::BaseImage {
+                    image: DEFAULT_LXD_IMAGE.to_string()
+                },
+                ExternalResource::UbuntuPackages,
+                ExternalResource::DockerCompose,
+                ExternalResource::ContainerImage {
+                    image: sample_tracker_config().image().full_reference()
+                },
+                ExternalResource::ContainerImage {
+                    image: PrometheusConfig::docker_image().full_reference()
+                },
+            ]
+        );
+    }
 }
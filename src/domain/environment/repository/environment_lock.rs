@@ -0,0 +1,45 @@
+use std::any::Any;
+use std::fmt;
+
+use crate::domain::environment::name::EnvironmentName;
+
+/// Exclusive hold of an environment, released when dropped
+///
+/// Returned by `EnvironmentRepository::lock`. A command holds it for its
+/// whole load → mutate → save sequence, so that no other command, in this
+/// process or another one, works on the same environment in between.
+///
+/// The guard is independent of the locks each repository operation takes,
+/// so the holder keeps loading and saving the environment as usual. It is
+/// not reentrant: locking an environment again while holding its guard
+/// waits for the lock timeout and fails with `RepositoryError::Conflict`.
+#[must_use = "the environment is unlocked as soon as the guard is dropped"]
+pub struct EnvironmentLockGuard {
+    name: EnvironmentName,
+    /// Backend resource releasing the lock when dropped
+    _held: Box<dyn Any + Send + Sync>,
+}
+
+impl EnvironmentLockGuard {
+    /// Create a guard of `name` releasing the lock when `held` is dropped
+    pub fn new(name: EnvironmentName, held: impl Any + Send + Sync) -> Self {
+        Self {
+            name,
+            _held: Box::new(held),
+        }
+    }
+
+    /// Name of the locked environment
+    #[must_use]
+    pub fn environment_name(&self) -> &EnvironmentName {
+        &self.name
+    }
+}
+
+impl fmt::Debug for EnvironmentLockGuard {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("EnvironmentLockGuard")
+            .field("name", &self.name)
+            .finish_non_exhaustive()
+    }
+}
@@ -1,7 +1,11 @@
 use crate::domain::environment::name::EnvironmentName;
 use crate::domain::environment::state::AnyEnvironmentState;
+use crate::domain::InstanceName;
 
+use super::environment_lock::EnvironmentLockGuard;
+use super::migrations::StoredStateVersion;
 use super::repository_error::RepositoryError;
+use super::state_backup::StateBackup;
 
 /// Repository trait for persisting environments
 ///
@@ -11,9 +15,14 @@ use super::repository_error::RepositoryError;
 ///
 /// # Concurrency
 ///
-/// Implementations must handle concurrent access safely. File-based implementations
-/// typically use locking mechanisms, while in-memory implementations might use
-/// interior mutability patterns.
+/// Implementations must handle concurrent access safely. Each operation takes
+/// the locks it needs: file-based implementations use lock files, database
+/// implementations use transactions, and in-memory implementations might use
+/// interior mutability patterns. An operation that cannot acquire its lock in
+/// time fails with `RepositoryError::Conflict`.
+///
+/// Those locks only last for one operation. Commands that load, change and
+/// save an environment hold it for the whole sequence with [`lock`](Self::lock).
 ///
 /// # Atomicity
 ///
@@ -52,12 +61,56 @@ pub trait EnvironmentRepository {
     ///
     /// Returns `RepositoryError::NotFound` if the environment does not exist.
     ///
+    /// Returns `RepositoryError::Corrupted` if the stored data cannot be parsed.
+    ///
+    /// Returns `RepositoryError::UnsupportedVersion` if the stored data was
+    /// written by a newer version of the deployer. Data written by older
+    /// versions is migrated to the current schema.
+    ///
     /// Returns `RepositoryError::Internal` for implementation-specific errors such as:
-    /// - Deserialization failures (corrupted data)
     /// - Storage access issues
     /// - Lock acquisition timeouts
     fn load(&self, name: &EnvironmentName) -> Result<Option<AnyEnvironmentState>, RepositoryError>;
 
+    /// Load the versions recorded with the persisted environment
+    ///
+    /// Returns the schema version of the stored data and the version of the
+    /// deployer that last saved it, or `None` if the environment has never
+    /// been saved. Unlike `load`, the versions of data written by a newer
+    /// version of the deployer are returned.
+    ///
+    /// # Errors
+    ///
+    /// Returns `RepositoryError::Corrupted` if the stored data cannot be parsed.
+    ///
+    /// Returns `RepositoryError::Internal` for implementation-specific errors.
+    fn load_version(
+        &self,
+        name: &EnvironmentName,
+    ) -> Result<Option<StoredStateVersion>, RepositoryError>;
+
+    /// Identify the stored version of an environment without loading it
+    ///
+    /// The stamp is opaque and changes every time the environment is saved,
+    /// so changes can be detected cheaply, e.g. to watch an environment.
+    /// Returns `None` if the environment has never been saved.
+    ///
+    /// # Errors
+    ///
+    /// Returns `RepositoryError::Internal` if there are storage access issues.
+    fn change_stamp(&self, name: &EnvironmentName) -> Result<Option<String>, RepositoryError>;
+
+    /// List the names of the stored environments, sorted
+    ///
+    /// Only the names are read, not the environments, so listing stays fast
+    /// and environments whose stored data cannot be parsed are included.
+    /// Storage that was never written to holds no environments.
+    ///
+    /// # Errors
+    ///
+    /// Returns `RepositoryError::Internal` if there are storage access issues.
+    fn list(&self) -> Result<Vec<EnvironmentName>, RepositoryError>;
+
     /// Check if environment exists
     ///
     /// Returns `true` if an environment with the given name exists in storage.
@@ -84,4 +137,100 @@ pub trait EnvironmentRepository {
     /// - Storage access issues
     /// - Lock acquisition timeouts
     fn delete(&self, name: &EnvironmentName) -> Result<(), RepositoryError>;
+
+    /// Lock an environment until the returned guard is dropped
+    ///
+    /// While the guard is held, `lock` fails for the same environment in any
+    /// process using the same storage, including this one. The other
+    /// operations are not blocked, so the holder loads and saves the
+    /// environment as usual. The environment does not need to exist, so
+    /// that its creation can be locked too. A lock left behind by a process
+    /// that died is taken over.
+    ///
+    /// # Errors
+    ///
+    /// Returns `RepositoryError::Conflict` if the environment is still locked
+    /// by someone else when the lock timeout expires.
+    ///
+    /// Returns `RepositoryError::Internal` for implementation-specific errors
+    /// such as storage access issues.
+    fn lock(&self, name: &EnvironmentName) -> Result<EnvironmentLockGuard, RepositoryError>;
+
+    /// List the backups of the persisted state of an environment, newest first
+    ///
+    /// Each time the state of an environment is overwritten, the previous
+    /// readable state is kept as a backup, so that state that can no longer be
+    /// loaded can be restored. Only a limited number of backups is kept.
+    ///
+    /// # Errors
+    ///
+    /// Returns `RepositoryError::Internal` if the backups cannot be read.
+    fn list_backups(&self, name: &EnvironmentName) -> Result<Vec<StateBackup>, RepositoryError>;
+
+    /// Replace the persisted state of an environment with one of its backups
+    ///
+    /// The replaced state is backed up first when it is readable.
+    ///
+    /// # Errors
+    ///
+    /// Returns `RepositoryError::NotFound` if no backup has the given id.
+    ///
+    /// Returns `RepositoryError::Conflict` if another process is currently accessing
+    /// the environment.
+    ///
+    /// Returns `RepositoryError::Internal` for implementation-specific errors.
+    fn restore_backup(
+        &self,
+        name: &EnvironmentName,
+        backup_id: &str,
+    ) -> Result<(), RepositoryError>;
+
+    /// Encrypt the secrets an environment still stores in plaintext
+    ///
+    /// Repositories configured with a key encrypt the secrets they write, but
+    /// the stored data and backups written before keep their plaintext until
+    /// rewritten. This rewrites them in place, without changing the state or
+    /// adding a backup. Returns `true` if anything was rewritten.
+    ///
+    /// Repositories that do not encrypt secrets have nothing to do.
+    ///
+    /// # Errors
+    ///
+    /// Returns `RepositoryError::Conflict` if another process is currently accessing
+    /// the environment.
+    ///
+    /// Returns `RepositoryError::Internal` for implementation-specific errors.
+    fn seal_secrets(&self, _name: &EnvironmentName) -> Result<bool, RepositoryError> {
+        Ok(false)
+    }
+
+    /// Find the stored environment using an instance name
+    ///
+    /// Two environments sharing an instance would provision, configure and
+    /// destroy the same machine. Environments that cannot be loaded are
+    /// skipped, since their instance name is unknown.
+    ///
+    /// # Errors
+    ///
+    /// Returns `RepositoryError::Internal` if the environments cannot be listed.
+    fn find_by_instance_name(
+        &self,
+        instance_name: &InstanceName,
+    ) -> Result<Option<EnvironmentName>, RepositoryError> {
+        for name in self.list()? {
+            match self.load(&name) {
+                Ok(Some(environment)) if environment.instance_name() == instance_name => {
+                    return Ok(Some(name));
+                }
+                Ok(_) => {}
+                Err(e) => tracing::warn!(
+                    environment = %name,
+                    error = %e,
+                    "Failed to load environment, its instance name is not checked"
+                ),
+            }
+        }
+
+        Ok(None)
+    }
 }
@@ -0,0 +1,571 @@
+//! Migrations of the persisted environment state
+//!
+//! Every state written by the deployer is stamped with the version of its
+//! schema and the version of the deployer that wrote it. When a state is
+//! loaded, the migrations between its schema version and the current one are
+//! applied in order to the raw JSON before it is deserialized, so state files
+//! written by older versions of the deployer keep loading. States written with
+//! a schema newer than the current one are rejected: this version of the
+//! deployer cannot know what changed.
+//!
+//! ## Schema Versions
+//!
+//! | Version | Changes                                                               |
+//! | ------- | --------------------------------------------------------------------- |
+//! | 1       | Files without a `schema_version`, written before versioning was added |
+//! | 2       | Version fields; the instance address is stored in `ipv4` or `ipv6`    |
+//! | 3       | `ReleaseFailed` and `RunFailed` store a full failure `context`        |
+//! | 4       | `Stopped` state                                                       |
+//! | 5       | Secret fields may hold values sealed with the workspace key           |
+//!
+//! ## Adding a Migration
+//!
+//! Bump [`CURRENT_STATE_SCHEMA_VERSION`] and append a function to `MIGRATIONS`
+//! that upgrades a state of the previous version to the new one.
+
+use std::net::IpAddr;
+
+use serde_json::{Map, Value};
+use thiserror::Error;
+
+/// Version of the state schema written by this version of the deployer
+pub const CURRENT_STATE_SCHEMA_VERSION: u32 = 5;
+
+/// First schema version whose secret fields may be sealed
+///
+/// Secrets are sealed by the repositories, which hold the workspace key: a
+/// state of an older version loaded with the key is sealed and stamped with
+/// the current versions.
+pub const SEALED_SECRETS_SCHEMA_VERSION: u32 = 5;
+
+/// Version of the deployer, recorded in every state it writes
+pub const DEPLOYER_VERSION: &str = env!("CARGO_PKG_VERSION");
+
+/// Key of the state schema version in the persisted state
+pub const SCHEMA_VERSION_KEY: &str = "schema_version";
+
+/// Key of the deployer version in the persisted state
+pub const DEPLOYER_VERSION_KEY: &str = "deployer_version";
+
+/// Schema version of the state files written before versioning was added
+const UNVERSIONED_SCHEMA_VERSION: u32 = 1;
+
+/// A migration upgrades a state of one schema version to the next
+type Migration = fn(&mut Map<String, Value>) -> Result<(), String>;
+
+/// Migrations in order: `MIGRATIONS[i]` upgrades version `i + 1` to `i + 2`
+const MIGRATIONS: [Migration; CURRENT_STATE_SCHEMA_VERSION as usize - 1] = [
+    move_instance_ip_to_address_family,
+    wrap_failed_step_in_failure_context,
+    allow_stopped_state,
+    seal_plaintext_secrets,
+];
+
+/// Steps of the release workflow at schema version 2
+///
+/// Steps added later can never be recorded in a legacy state.
+const LEGACY_RELEASE_STEPS: &[&str] = &[
+    "create_tracker_storage",
+    "init_tracker_database",
+    "render_tracker_templates",
+    "deploy_tracker_config_to_remote",
+    "create_prometheus_storage",
+    "render_prometheus_templates",
+    "deploy_prometheus_config_to_remote",
+    "create_grafana_storage",
+    "render_grafana_templates",
+    "deploy_grafana_provisioning",
+    "create_mysql_storage",
+    "render_backup_templates",
+    "create_backup_storage",
+    "deploy_backup_config_to_remote",
+    "install_backup_crontab",
+    "render_caddy_templates",
+    "deploy_caddy_config_to_remote",
+    "render_docker_compose_templates",
+    "deploy_compose_files_to_remote",
+];
+
+/// Steps of the run workflow at schema version 2
+const LEGACY_RUN_STEPS: &[&str] = &["start_services", "wait_for_tracker_health"];
+
+/// Versions recorded in a persisted environment state
+///
+/// # Examples
+///
+/// ```rust
+/// use torrust_tracker_deployer_lib::domain::environment::repository::StoredStateVersion;
+///
+/// let version = StoredStateVersion::current();
+///
+/// assert!(version.is_supported());
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct StoredStateVersion {
+    /// Version of the schema the state was written with
+    pub schema_version: u32,
+
+    /// Version of the deployer that last wrote the state
+    ///
+    /// `None` for states written before versioning was added.
+    pub deployer_version: Option<String>,
+}
+
+impl StoredStateVersion {
+    /// Versions of the states written by this version of the deployer
+    #[must_use]
+    pub fn current() -> Self {
+        Self {
+            schema_version: CURRENT_STATE_SCHEMA_VERSION,
+            deployer_version: Some(DEPLOYER_VERSION.to_string()),
+        }
+    }
+
+    /// Read the versions recorded in a raw persisted state
+    ///
+    /// # Errors
+    ///
+    /// Returns `StateMigrationError::InvalidVersion` if a version field is
+    /// present but is not of the expected type.
+    pub fn read(state: &Value) -> Result<Self, StateMigrationError> {
+        let schema_version = match state.get(SCHEMA_VERSION_KEY) {
+            None => UNVERSIONED_SCHEMA_VERSION,
+            Some(value) => value
+                .as_u64()
+                .and_then(|version| u32::try_from(version).ok())
+                .ok_or_else(|| StateMigrationError::InvalidVersion {
+                    field: SCHEMA_VERSION_KEY,
+                    value: value.to_string(),
+                })?,
+        };
+
+        let deployer_version = match state.get(DEPLOYER_VERSION_KEY) {
+            None => None,
+            Some(value) => Some(value.as_str().map(ToString::to_string).ok_or_else(|| {
+                StateMigrationError::InvalidVersion {
+                    field: DEPLOYER_VERSION_KEY,
+                    value: value.to_string(),
+                }
+            })?),
+        };
+
+        Ok(Self {
+            schema_version,
+            deployer_version,
+        })
+    }
+
+    /// Whether this version of the deployer can load the state
+    ///
+    /// States written with a newer schema are not supported.
+    #[must_use]
+    pub fn is_supported(&self) -> bool {
+        (UNVERSIONED_SCHEMA_VERSION..=CURRENT_STATE_SCHEMA_VERSION).contains(&self.schema_version)
+    }
+}
+
+/// Errors that can occur while migrating a persisted state
+#[derive(Debug, Error)]
+pub enum StateMigrationError {
+    /// The state was written with a schema newer than the current one
+    #[error(
+        "Environment state has schema version {found}, but this deployer supports up to version {supported}"
+    )]
+    UnsupportedVersion {
+        /// Schema version of the state
+        found: u32,
+        /// Newest schema version supported by this deployer
+        supported: u32,
+        /// Version of the deployer that wrote the state, if recorded
+        deployer_version: Option<String>,
+    },
+
+    /// A version field of the state has an unexpected type
+    #[error("Invalid '{field}' in environment state: {value}")]
+    InvalidVersion {
+        /// Name of the version field
+        field: &'static str,
+        /// Value found in the state
+        value: String,
+    },
+
+    /// The state is not a JSON object
+    #[error("Environment state is not a JSON object")]
+    NotAnObject,
+
+    /// A migration could not upgrade the state
+    #[error("Failed to migrate environment state from schema version {from}: {reason}")]
+    MigrationFailed {
+        /// Schema version the migration started from
+        from: u32,
+        /// Why the migration failed
+        reason: String,
+    },
+}
+
+/// Upgrade a raw persisted state to the current schema
+///
+/// The version fields are removed from the returned state, which can be
+/// deserialized as `AnyEnvironmentState`. The versions the state was stored
+/// with are returned along with it.
+///
+/// # Errors
+///
+/// Returns `StateMigrationError::UnsupportedVersion` if the state was written
+/// with a newer schema, and other variants if the state cannot be migrated.
+pub fn migrate(state: Value) -> Result<(Value, StoredStateVersion), StateMigrationError> {
+    let version = StoredStateVersion::read(&state)?;
+
+    if !version.is_supported() {
+        return Err(StateMigrationError::UnsupportedVersion {
+            found: version.schema_version,
+            supported: CURRENT_STATE_SCHEMA_VERSION,
+            deployer_version: version.deployer_version,
+        });
+    }
+
+    let Value::Object(mut object) = state else {
+        return Err(StateMigrationError::NotAnObject);
+    };
+    object.remove(SCHEMA_VERSION_KEY);
+    object.remove(DEPLOYER_VERSION_KEY);
+
+    for (from, migration) in (version.schema_version..)
+        .zip(&MIGRATIONS[(version.schema_version - UNVERSIONED_SCHEMA_VERSION) as usize..])
+    {
+        migration(&mut object)
+            .map_err(|reason| StateMigrationError::MigrationFailed { from, reason })?;
+    }
+
+    Ok((Value::Object(object), version))
+}
+
+/// Record the current versions in a raw state about to be persisted
+///
+/// # Errors
+///
+/// Returns `StateMigrationError::NotAnObject` if the state is not a JSON object.
+pub fn stamp(state: &mut Value) -> Result<(), StateMigrationError> {
+    let Value::Object(object) = state else {
+        return Err(StateMigrationError::NotAnObject);
+    };
+
+    object.insert(
+        SCHEMA_VERSION_KEY.to_string(),
+        Value::from(CURRENT_STATE_SCHEMA_VERSION),
+    );
+    object.insert(
+        DEPLOYER_VERSION_KEY.to_string(),
+        Value::from(DEPLOYER_VERSION),
+    );
+
+    Ok(())
+}
+
+/// Runtime outputs of a raw state, whatever its state variant
+fn runtime_outputs(state: &mut Map<String, Value>) -> Option<&mut Map<String, Value>> {
+    state
+        .values_mut()
+        .next()?
+        .get_mut("context")?
+        .get_mut("runtime_outputs")?
+        .as_object_mut()
+}
+
+/// Version 1 to 2: move `instance_ip` to the field of its address family
+///
+/// States written before dual-stack support store a single `instance_ip`.
+fn move_instance_ip_to_address_family(state: &mut Map<String, Value>) -> Result<(), String> {
+    let Some(outputs) = runtime_outputs(state) else {
+        return Ok(());
+    };
+
+    let Some(instance_ip) = outputs.remove("instance_ip") else {
+        return Ok(());
+    };
+
+    let family = match instance_ip.as_str().map(str::parse::<IpAddr>) {
+        None => return Ok(()),
+        Some(Ok(IpAddr::V4(_))) => "ipv4",
+        Some(Ok(IpAddr::V6(_))) => "ipv6",
+        Some(Err(error)) => return Err(format!("invalid instance_ip {instance_ip}: {error}")),
+    };
+
+    outputs.entry(family).or_insert(instance_ip);
+
+    Ok(())
+}
+
+/// Version 2 to 3: wrap the bare `failed_step` of `ReleaseFailed` and
+/// `RunFailed` in a failure context like the other failed states
+///
+/// The legacy states only recorded the name of the failed step. The time of
+/// the failure was not recorded, so the creation time of the environment
+/// stands in for it, with a zero duration and a nil trace ID. The recorded
+/// name is kept in the error summary. A name that matches no step of the
+/// workflow at schema version 2 fails the migration.
+fn wrap_failed_step_in_failure_context(state: &mut Map<String, Value>) -> Result<(), String> {
+    let Some((variant, environment)) = state.iter_mut().next() else {
+        return Ok(());
+    };
+
+    let (command, steps) = match variant.as_str() {
+        "ReleaseFailed" => ("release", LEGACY_RELEASE_STEPS),
+        "RunFailed" => ("run", LEGACY_RUN_STEPS),
+        _ => return Ok(()),
+    };
+
+    let created_at = environment
+        .get("context")
+        .and_then(|context| context.get("created_at"))
+        .cloned()
+        .ok_or_else(|| format!("{variant} state without a 'created_at'"))?;
+
+    let Some(failed_state) = environment.get_mut("state").and_then(Value::as_object_mut) else {
+        return Ok(());
+    };
+
+    let Some(failed_step) = failed_state.remove("failed_step") else {
+        return Ok(());
+    };
+
+    let recorded = failed_step
+        .as_str()
+        .ok_or_else(|| format!("invalid failed_step {failed_step}"))?;
+    let step = legacy_step(steps, recorded)
+        .ok_or_else(|| format!("unknown {command} step '{recorded}' in {variant} state"))?;
+
+    failed_state.insert(
+        "context".to_string(),
+        serde_json::json!({
+            "failed_step": step,
+            "error_kind": "CommandExecution",
+            "base": {
+                "error_summary": format!(
+                    "The {command} command failed at step '{recorded}' (recorded before failure details were kept)"
+                ),
+                "failed_at": created_at,
+                "execution_started_at": created_at,
+                "execution_duration": {"secs": 0, "nanos": 0},
+                "trace_id": "00000000-0000-0000-0000-000000000000",
+                "trace_file_path": null,
+            },
+        }),
+    );
+
+    Ok(())
+}
+
+/// Version 3 to 4: add the `Stopped` state
+///
+/// Every state of version 3 is valid at version 4. The version is bumped so
+/// that older deployers refuse a `Stopped` state instead of failing to
+/// deserialize it.
+#[allow(clippy::unnecessary_wraps)]
+fn allow_stopped_state(_state: &mut Map<String, Value>) -> Result<(), String> {
+    Ok(())
+}
+
+/// Version 4 to 5: seal the plaintext secrets
+///
+/// Sealing needs the workspace key, so the repositories seal the secrets of
+/// the states older than [`SEALED_SECRETS_SCHEMA_VERSION`] when they load
+/// them with a key. Without a key, plaintext secrets load as they are.
+#[allow(clippy::unnecessary_wraps)]
+fn seal_plaintext_secrets(_state: &mut Map<String, Value>) -> Result<(), String> {
+    Ok(())
+}
+
+/// The step of `steps` matching a legacy step name, whatever its casing
+///
+/// Legacy names were written as `snake_case`, `PascalCase` or as displayed
+/// (`Start Services`).
+fn legacy_step(steps: &[&'static str], recorded: &str) -> Option<&'static str> {
+    let normalize = |name: &str| -> String {
+        name.chars()
+            .filter(char::is_ascii_alphanumeric)
+            .map(|c| c.to_ascii_lowercase())
+            .collect()
+    };
+    let recorded = normalize(recorded);
+
+    steps
+        .iter()
+        .find(|step| normalize(step) == recorded)
+        .copied()
+}
+
+#[cfg(test)]
+mod tests {
+    use std::path::PathBuf;
+
+    use serde_json::json;
+
+    use super::*;
+    use crate::domain::environment::state::AnyEnvironmentState;
+
+    fn fixture(schema_version: u32) -> Value {
+        let path = PathBuf::from(env!("CARGO_MANIFEST_DIR")).join(format!(
+            "fixtures/environment-state/schema-v{schema_version}.json"
+        ));
+        let content = std::fs::read_to_string(&path).unwrap();
+        serde_json::from_str(&content).unwrap()
+    }
+
+    #[test]
+    fn it_should_load_a_state_file_of_every_known_schema_version() {
+        for schema_version in UNVERSIONED_SCHEMA_VERSION..=CURRENT_STATE_SCHEMA_VERSION {
+            let (state, version) = migrate(fixture(schema_version)).unwrap();
+
+            let environment: AnyEnvironmentState = serde_json::from_value(state).unwrap();
+
+            assert_eq!(version.schema_version, schema_version);
+            assert_eq!(environment.state_name(), "provisioned");
+            assert_eq!(
+                environment.instance_ip(),
+                Some("10.140.190.14".parse().unwrap()),
+                "schema version {schema_version}"
+            );
+        }
+    }
+
+    #[test]
+    fn it_should_not_record_a_deployer_version_for_unversioned_state_files() {
+        let (_, version) = migrate(fixture(1)).unwrap();
+
+        assert_eq!(version.deployer_version, None);
+    }
+
+    #[test]
+    fn it_should_move_an_ipv6_instance_ip_to_the_ipv6_field() {
+        let state = json!({
+            "Provisioned": {"context": {"runtime_outputs": {"instance_ip": "2001:db8::10"}}}
+        });
+
+        let (state, _) = migrate(state).unwrap();
+
+        let outputs = &state["Provisioned"]["context"]["runtime_outputs"];
+        assert_eq!(outputs["ipv6"], "2001:db8::10");
+        assert!(outputs.get("instance_ip").is_none());
+        assert!(outputs.get("ipv4").is_none());
+    }
+
+    fn legacy_failed_state(variant: &str, failed_step: &str) -> Value {
+        let mut state = fixture(2);
+        let environment = state
+            .as_object_mut()
+            .unwrap()
+            .remove("Provisioned")
+            .unwrap();
+        state[variant] = environment;
+        state[variant]["state"] = json!({"failed_step": failed_step});
+        state
+    }
+
+    #[test]
+    fn it_should_wrap_the_failed_step_of_a_legacy_run_failed_state_in_a_failure_context() {
+        let (state, _) =
+            migrate(legacy_failed_state("RunFailed", "Wait For Tracker Health")).unwrap();
+
+        let environment: AnyEnvironmentState = serde_json::from_value(state).unwrap();
+
+        let report = environment.failure_report().unwrap();
+        assert_eq!(report.failed_step.command(), "run");
+        assert_eq!(report.failed_step.step_name(), "WaitForTrackerHealth");
+        assert!(report
+            .base
+            .error_summary
+            .contains("step 'Wait For Tracker Health'"));
+    }
+
+    #[test]
+    fn it_should_fail_to_migrate_a_legacy_release_step_unknown_at_schema_version_2() {
+        for recorded in ["release", "verify_image_digests"] {
+            let error = migrate(legacy_failed_state("ReleaseFailed", recorded)).unwrap_err();
+
+            assert!(
+                matches!(
+                    error,
+                    StateMigrationError::MigrationFailed { from: 2, ref reason }
+                        if reason.contains(&format!("unknown release step '{recorded}'"))
+                ),
+                "{recorded}: {error}"
+            );
+        }
+    }
+
+    #[test]
+    fn it_should_load_a_stopped_state_and_keep_version_3_states_unchanged() {
+        let (migrated, _) = migrate(fixture(3)).unwrap();
+        let (current, _) = migrate(fixture(4)).unwrap();
+        assert_eq!(migrated, current);
+
+        let mut state = fixture(4);
+        let environment = state
+            .as_object_mut()
+            .unwrap()
+            .remove("Provisioned")
+            .unwrap();
+        state["Stopped"] = environment;
+
+        let (state, _) = migrate(state).unwrap();
+        let environment: AnyEnvironmentState = serde_json::from_value(state).unwrap();
+
+        assert_eq!(environment.state_name(), "stopped");
+    }
+
+    #[test]
+    fn it_should_keep_the_plaintext_secrets_of_a_version_4_state() {
+        let (state, version) = migrate(fixture(4)).unwrap();
+
+        assert!(version.schema_version < SEALED_SECRETS_SCHEMA_VERSION);
+        assert_eq!(
+            state["Provisioned"]["context"]["user_inputs"]["tracker"]["http_api"]["admin_token"],
+            "MyAccessToken"
+        );
+    }
+
+    #[test]
+    fn it_should_reject_a_state_written_with_a_newer_schema() {
+        let mut state = fixture(CURRENT_STATE_SCHEMA_VERSION);
+        state[SCHEMA_VERSION_KEY] = Value::from(CURRENT_STATE_SCHEMA_VERSION + 1);
+        state[DEPLOYER_VERSION_KEY] = Value::from("99.0.0");
+
+        let error = migrate(state).unwrap_err();
+
+        assert!(matches!(
+            error,
+            StateMigrationError::UnsupportedVersion {
+                found,
+                supported: CURRENT_STATE_SCHEMA_VERSION,
+                deployer_version: Some(ref deployer_version),
+            } if found == CURRENT_STATE_SCHEMA_VERSION + 1 && deployer_version == "99.0.0"
+        ));
+    }
+
+    #[test]
+    fn it_should_reject_a_schema_version_that_is_not_a_number() {
+        let state = json!({SCHEMA_VERSION_KEY: "2", "Created": {}});
+
+        let error = migrate(state).unwrap_err();
+
+        assert!(matches!(
+            error,
+            StateMigrationError::InvalidVersion {
+                field: SCHEMA_VERSION_KEY,
+                ..
+            }
+        ));
+    }
+
+    #[test]
+    fn it_should_stamp_the_current_versions_and_remove_them_when_migrating() {
+        let mut state = json!({"Created": {}});
+
+        stamp(&mut state).unwrap();
+        let (migrated, version) = migrate(state).unwrap();
+
+        assert_eq!(version, StoredStateVersion::current());
+        assert_eq!(migrated, json!({"Created": {}}));
+    }
+}
@@ -3,11 +3,17 @@
 //! This module provides the abstractions and implementations for persisting
 //! environment state across different storage backends.
 
+mod environment_lock;
 mod environment_repository;
+pub mod migrations;
 mod repository_error;
+mod state_backup;
 mod typed_repository;
 
 // Re-export public API
+pub use environment_lock::EnvironmentLockGuard;
 pub use environment_repository::EnvironmentRepository;
+pub use migrations::{StateMigrationError, StoredStateVersion};
 pub use repository_error::RepositoryError;
+pub use state_backup::StateBackup;
 pub use typed_repository::TypedEnvironmentRepository;
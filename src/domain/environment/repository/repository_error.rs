@@ -21,6 +21,15 @@ use thiserror::Error;
 ///         RepositoryError::Conflict => {
 ///             println!("Another process is accessing this environment");
 ///         }
+///         RepositoryError::Corrupted { reason } => {
+///             eprintln!("Stored state is corrupted: {reason}");
+///         }
+///         RepositoryError::UnsupportedVersion { schema_version, .. } => {
+///             eprintln!("Stored state has unsupported schema version {schema_version}");
+///         }
+///         RepositoryError::MissingSecret { secret } => {
+///             eprintln!("Stored secret {secret} is missing");
+///         }
 ///         RepositoryError::Internal(inner) => {
 ///             eprintln!("Internal error: {}", inner);
 ///             
@@ -49,6 +58,46 @@ pub enum RepositoryError {
     #[error("Conflict: another process is accessing this environment")]
     Conflict,
 
+    /// Stored data cannot be parsed
+    ///
+    /// The environment exists in storage but its data is not valid, for
+    /// example a state file truncated by an interrupted write. A backup of a
+    /// previous state can be restored with `EnvironmentRepository::restore_backup`.
+    #[error("Environment state is corrupted: {reason}")]
+    Corrupted {
+        /// Why the stored data could not be parsed
+        reason: String,
+    },
+
+    /// Stored data was written by a newer version of the deployer
+    ///
+    /// Its schema version is newer than the one supported by this version,
+    /// which cannot know how to read it. Upgrading the deployer fixes it.
+    #[error(
+        "Environment state has schema version {schema_version}, newer than version {supported_version} supported by this deployer ({}). Upgrade the deployer to use this environment",
+        super::migrations::DEPLOYER_VERSION
+    )]
+    UnsupportedVersion {
+        /// Schema version of the stored data
+        schema_version: u32,
+        /// Newest schema version supported by this deployer
+        supported_version: u32,
+        /// Version of the deployer that wrote the stored data, if recorded
+        deployer_version: Option<String>,
+    },
+
+    /// A secret needed by the environment is not stored
+    ///
+    /// The secrets are stored apart from the state, e.g. in `secrets.json`,
+    /// which is missing or incomplete.
+    #[error(
+        "Environment secret '{secret}' is missing: the stored secrets are missing or incomplete"
+    )]
+    MissingSecret {
+        /// Name of the missing secret
+        secret: String,
+    },
+
     /// Internal implementation-specific error
     ///
     /// This wraps errors specific to the repository implementation:
@@ -89,6 +138,18 @@ mod tests {
         assert!(message.contains("another process"));
     }
 
+    #[test]
+    fn it_should_tell_to_upgrade_the_deployer_for_an_unsupported_version() {
+        let error = RepositoryError::UnsupportedVersion {
+            schema_version: 3,
+            supported_version: 2,
+            deployer_version: Some("9.0.0".to_string()),
+        };
+        let message = error.to_string();
+        assert!(message.contains("schema version 3"));
+        assert!(message.contains("Upgrade the deployer"));
+    }
+
     #[test]
     fn it_should_display_internal_error_message() {
         let inner = anyhow::Error::from(io::Error::other("test error"));
@@ -0,0 +1,17 @@
+//! Backups of the persisted state of an environment
+
+use chrono::{DateTime, Utc};
+use serde::Serialize;
+
+/// A previous state of an environment kept by the repository
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct StateBackup {
+    /// Identifier used to select the backup when restoring it
+    pub id: String,
+
+    /// Name of the environment state stored in the backup (e.g. "provisioned")
+    pub state_name: String,
+
+    /// When the backed up state was written
+    pub created_at: DateTime<Utc>,
+}
@@ -87,6 +87,7 @@ impl_save_for_state!(
 impl_save_for_state!(save_releasing, crate::domain::environment::state::Releasing);
 impl_save_for_state!(save_released, crate::domain::environment::state::Released);
 impl_save_for_state!(save_running, crate::domain::environment::state::Running);
+impl_save_for_state!(save_stopped, crate::domain::environment::state::Stopped);
 impl_save_for_state!(
     save_destroying,
     crate::domain::environment::state::Destroying
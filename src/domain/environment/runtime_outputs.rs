@@ -23,10 +23,41 @@
 //! - `container_id: Option<String>` - Container/VM identifier
 //! - `resource_metrics: Option<ResourceMetrics>` - CPU, memory, disk usage
 
+use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
-use std::net::IpAddr;
+use std::net::{IpAddr, Ipv4Addr, Ipv6Addr, SocketAddr};
 use url::Url;
 
+use crate::domain::environment::{DatabaseBackup, EnvironmentName};
+use crate::domain::provision::IpPreference;
+use crate::domain::tracker::TrackerVersion;
+
+/// Number of releases kept in the release history
+///
+/// Bounds the environment state file; rollback only needs the latest entries.
+pub const RELEASE_HISTORY_LIMIT: usize = 5;
+
+/// A successful release of the tracker
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ReleaseRecord {
+    /// Tracker image version that was deployed
+    pub version: TrackerVersion,
+
+    /// When the release completed
+    pub released_at: DateTime<Utc>,
+}
+
+impl ReleaseRecord {
+    /// Creates a release record
+    #[must_use]
+    pub fn new(version: TrackerVersion, released_at: DateTime<Utc>) -> Self {
+        Self {
+            version,
+            released_at,
+        }
+    }
+}
+
 /// How the infrastructure instance was provisioned
 ///
 /// This enum tracks the method used to provision the infrastructure, which
@@ -46,6 +77,24 @@ pub enum ProvisionMethod {
     /// that was created externally. The infrastructure cannot be destroyed by this tool;
     /// the `destroy` command will only clean up local state, not the actual instance.
     Registered,
+
+    /// Instance was imported from an existing deployment via the `import` command
+    ///
+    /// Like `Registered`, the infrastructure was created outside this tool and
+    /// is never destroyed by it. An imported environment may start in a later
+    /// state (`Configured` or `Running`) when the existing installation is detected.
+    Imported,
+}
+
+impl ProvisionMethod {
+    /// Returns `true` if the instance was created outside this tool
+    ///
+    /// Externally provisioned instances are never destroyed with `tofu destroy`;
+    /// the `destroy` command only cleans up local state for them.
+    #[must_use]
+    pub fn is_externally_provisioned(self) -> bool {
+        matches!(self, Self::Registered | Self::Imported)
+    }
 }
 
 impl std::fmt::Display for ProvisionMethod {
@@ -53,10 +102,100 @@ impl std::fmt::Display for ProvisionMethod {
         match self {
             Self::Provisioned => write!(f, "provisioned"),
             Self::Registered => write!(f, "registered"),
+            Self::Imported => write!(f, "imported"),
+        }
+    }
+}
+
+/// A deployment command whose duration is recorded in the environment state
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum DeploymentPhase {
+    /// The `provision` command
+    Provision,
+
+    /// The `configure` command
+    Configure,
+
+    /// The `release` command
+    Release,
+
+    /// The `run` command
+    Run,
+}
+
+impl DeploymentPhase {
+    /// All phases, in deployment order
+    pub const ALL: [Self; 4] = [Self::Provision, Self::Configure, Self::Release, Self::Run];
+}
+
+impl std::fmt::Display for DeploymentPhase {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Provision => write!(f, "provision"),
+            Self::Configure => write!(f, "configure"),
+            Self::Release => write!(f, "release"),
+            Self::Run => write!(f, "run"),
         }
     }
 }
 
+/// How long the last successful run of each deployment phase took
+///
+/// Durations are in milliseconds, like the ones of the deployment reports.
+/// A phase is `None` until its command succeeds, and in state files written
+/// before the durations were recorded.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct PhaseDurations {
+    /// Duration of the last successful `provision`
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    provision_ms: Option<u64>,
+
+    /// Duration of the last successful `configure`
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    configure_ms: Option<u64>,
+
+    /// Duration of the last successful `release`
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    release_ms: Option<u64>,
+
+    /// Duration of the last successful `run`
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    run_ms: Option<u64>,
+}
+
+impl PhaseDurations {
+    /// Returns the duration of `phase` in milliseconds, if recorded
+    #[must_use]
+    pub fn get(&self, phase: DeploymentPhase) -> Option<u64> {
+        match phase {
+            DeploymentPhase::Provision => self.provision_ms,
+            DeploymentPhase::Configure => self.configure_ms,
+            DeploymentPhase::Release => self.release_ms,
+            DeploymentPhase::Run => self.run_ms,
+        }
+    }
+
+    /// Records the duration of `phase`, replacing the previous one
+    pub fn record(&mut self, phase: DeploymentPhase, duration_ms: u64) {
+        let slot = match phase {
+            DeploymentPhase::Provision => &mut self.provision_ms,
+            DeploymentPhase::Configure => &mut self.configure_ms,
+            DeploymentPhase::Release => &mut self.release_ms,
+            DeploymentPhase::Run => &mut self.run_ms,
+        };
+        *slot = Some(duration_ms);
+    }
+
+    /// Returns `true` if no phase duration is recorded
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        DeploymentPhase::ALL
+            .iter()
+            .all(|phase| self.get(*phase).is_none())
+    }
+}
+
 /// Service endpoints for deployed tracker services
 ///
 /// This struct stores the URLs for all deployed tracker services. These URLs
@@ -124,7 +263,7 @@ impl ServiceEndpoints {
     /// Build `ServiceEndpoints` from tracker configuration and instance IP
     ///
     /// Constructs service URLs by combining the configured bind addresses
-    /// with the actual instance IP address.
+    /// with the actual instance IP address. IPv6 addresses are bracketed.
     ///
     /// # Examples
     ///
@@ -164,9 +303,8 @@ impl ServiceEndpoints {
             .iter()
             .filter_map(|udp| {
                 Url::parse(&format!(
-                    "udp://{}:{}/announce",
-                    instance_ip,
-                    udp.bind_address().port()
+                    "udp://{}/announce",
+                    SocketAddr::new(instance_ip, udp.bind_address().port())
                 ))
                 .ok()
             })
@@ -181,9 +319,8 @@ impl ServiceEndpoints {
             .iter()
             .filter_map(|http| {
                 Url::parse(&format!(
-                    "http://{}:{}/announce", // DevSkim: ignore DS137138
-                    instance_ip,
-                    http.bind_address().port()
+                    "http://{}/announce", // DevSkim: ignore DS137138
+                    SocketAddr::new(instance_ip, http.bind_address().port())
                 ))
                 .ok()
             })
@@ -195,9 +332,8 @@ impl ServiceEndpoints {
         instance_ip: IpAddr,
     ) -> Option<Url> {
         Url::parse(&format!(
-            "http://{}:{}/api", // DevSkim: ignore DS137138
-            instance_ip,
-            bind_address.port()
+            "http://{}/api", // DevSkim: ignore DS137138
+            SocketAddr::new(instance_ip, bind_address.port())
         ))
         .ok()
     }
@@ -207,14 +343,66 @@ impl ServiceEndpoints {
         instance_ip: IpAddr,
     ) -> Option<Url> {
         Url::parse(&format!(
-            "http://{}:{}/health_check", // DevSkim: ignore DS137138
-            instance_ip,
-            bind_address.port()
+            "http://{}/health_check", // DevSkim: ignore DS137138
+            SocketAddr::new(instance_ip, bind_address.port())
         ))
         .ok()
     }
 }
 
+/// IP addresses of an instance, at most one per address family
+///
+/// Dual-stack instances have both; single-stack instances have one.
+///
+/// # Examples
+///
+/// ```rust
+/// use torrust_tracker_deployer_lib::domain::environment::runtime_outputs::InstanceAddresses;
+/// use torrust_tracker_deployer_lib::domain::provision::IpPreference;
+/// use std::net::{IpAddr, Ipv6Addr};
+///
+/// let addresses = InstanceAddresses::new(None, Some(Ipv6Addr::LOCALHOST));
+///
+/// assert_eq!(addresses.preferred(IpPreference::Ipv4), Some(IpAddr::V6(Ipv6Addr::LOCALHOST)));
+/// ```
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct InstanceAddresses {
+    /// IPv4 address, if the instance has one
+    pub ipv4: Option<Ipv4Addr>,
+
+    /// IPv6 address, if the instance has one
+    pub ipv6: Option<Ipv6Addr>,
+}
+
+impl InstanceAddresses {
+    /// Creates instance addresses from both families
+    #[must_use]
+    pub fn new(ipv4: Option<Ipv4Addr>, ipv6: Option<Ipv6Addr>) -> Self {
+        Self { ipv4, ipv6 }
+    }
+
+    /// Returns `true` if the instance has no address at all
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.ipv4.is_none() && self.ipv6.is_none()
+    }
+
+    /// Returns the address of the preferred family, falling back to the other
+    #[must_use]
+    pub fn preferred(&self, preference: IpPreference) -> Option<IpAddr> {
+        preference.select(self.ipv4, self.ipv6)
+    }
+}
+
+impl From<IpAddr> for InstanceAddresses {
+    fn from(ip: IpAddr) -> Self {
+        match ip {
+            IpAddr::V4(ipv4) => Self::new(Some(ipv4), None),
+            IpAddr::V6(ipv6) => Self::new(None, Some(ipv6)),
+        }
+    }
+}
+
 /// Runtime outputs generated during deployment operations
 ///
 /// This struct contains fields that are generated during deployment operations
@@ -225,10 +413,15 @@ impl ServiceEndpoints {
 ///
 /// Fields are populated at different stages of the deployment lifecycle:
 /// - **Creation**: All fields are `None` (use `RuntimeOutputs::new()`)
-/// - **After Provisioning**: `instance_ip` and `provision_method` are set
+/// - **After Provisioning**: `ipv4` and/or `ipv6` and `provision_method` are set
 ///   (use `record_provisioning()` or `record_registration()`)
 /// - **After Run Command**: `service_endpoints` is set
 ///   (use `record_services_started()`)
+/// - **After Release/Rollback**: `release_history` grows or shrinks
+///   (use `record_release()` and `drop_latest_release()`)
+/// - **After Provision/Configure/Release/Run**: `phase_durations` is updated
+///   (use `record_phase_duration()`)
+/// - **After Backup**: `last_backup` is set (use `record_backup()`)
 ///
 /// # Future Fields
 ///
@@ -240,25 +433,34 @@ impl ServiceEndpoints {
 ///
 /// ```rust
 /// use torrust_tracker_deployer_lib::domain::environment::runtime_outputs::{RuntimeOutputs, ProvisionMethod};
+/// use torrust_tracker_deployer_lib::domain::provision::IpPreference;
 /// use std::net::{IpAddr, Ipv4Addr};
 ///
 /// // Create empty runtime outputs
 /// let mut runtime_outputs = RuntimeOutputs::new();
-/// assert!(runtime_outputs.instance_ip().is_none());
+/// assert!(runtime_outputs.addresses().is_empty());
 ///
 /// // After provisioning, record the IP and method
 /// let ip = IpAddr::V4(Ipv4Addr::new(192, 168, 1, 100));
 /// runtime_outputs.record_provisioning(ip);
-/// assert_eq!(runtime_outputs.instance_ip(), Some(ip));
+/// assert_eq!(runtime_outputs.instance_ip(IpPreference::Ipv4), Some(ip));
 /// assert_eq!(runtime_outputs.provision_method(), Some(ProvisionMethod::Provisioned));
 /// ```
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct RuntimeOutputs {
-    /// Instance IP address (populated after provisioning)
+    /// IPv4 address of the instance (populated after provisioning)
+    ///
+    /// `None` until the environment has been provisioned, and for
+    /// IPv6-only instances.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    ipv4: Option<Ipv4Addr>,
+
+    /// IPv6 address of the instance (populated after provisioning)
     ///
-    /// This field stores the IP address of the provisioned instance and is
-    /// `None` until the environment has been successfully provisioned.
-    instance_ip: Option<IpAddr>,
+    /// `None` until the environment has been provisioned, and for
+    /// IPv4-only instances.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    ipv6: Option<Ipv6Addr>,
 
     /// How the instance was provisioned
     ///
@@ -281,6 +483,44 @@ pub struct RuntimeOutputs {
     /// - `Some(endpoints)`: URLs for all running services
     #[serde(default)]
     service_endpoints: Option<ServiceEndpoints>,
+
+    /// Tracker versions deployed by the last releases, oldest first
+    ///
+    /// Holds at most [`RELEASE_HISTORY_LIMIT`] entries. The last entry is the
+    /// version currently deployed; the one before it is the rollback target.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    release_history: Vec<ReleaseRecord>,
+
+    /// Name the environment had when its infrastructure was created
+    ///
+    /// Set when an environment that may have infrastructure is renamed: the
+    /// instance and LXD profile keep the names derived from this one.
+    /// `None` while the infrastructure names match the environment name.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    infrastructure_name: Option<EnvironmentName>,
+
+    /// Host port SSH is published on, when it differs from the configured port
+    ///
+    /// Set by providers that map the SSH port of the instance to another port
+    /// of the host (e.g. the Docker provider publishes it on a random port).
+    /// `None` when SSH is reached on the port of the user inputs.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    published_ssh_port: Option<u16>,
+
+    /// How long the last successful run of each deployment phase took
+    #[serde(default, skip_serializing_if = "PhaseDurations::is_empty")]
+    phase_durations: PhaseDurations,
+
+    /// Latest backup of the tracker database taken by the `backup` command
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    last_backup: Option<DatabaseBackup>,
+
+    /// When the environment was destroyed
+    ///
+    /// Set by the `destroy` command. `None` for environments that are not
+    /// destroyed, or that were destroyed before this field was added.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    destroyed_at: Option<DateTime<Utc>>,
 }
 
 impl RuntimeOutputs {
@@ -295,16 +535,23 @@ impl RuntimeOutputs {
     /// use torrust_tracker_deployer_lib::domain::environment::runtime_outputs::RuntimeOutputs;
     ///
     /// let outputs = RuntimeOutputs::new();
-    /// assert!(outputs.instance_ip().is_none());
+    /// assert!(outputs.addresses().is_empty());
     /// assert!(outputs.provision_method().is_none());
     /// assert!(outputs.service_endpoints().is_none());
     /// ```
     #[must_use]
     pub fn new() -> Self {
         Self {
-            instance_ip: None,
+            ipv4: None,
+            ipv6: None,
             provision_method: None,
             service_endpoints: None,
+            release_history: Vec::new(),
+            infrastructure_name: None,
+            published_ssh_port: None,
+            phase_durations: PhaseDurations::default(),
+            last_backup: None,
+            destroyed_at: None,
         }
     }
 
@@ -312,12 +559,20 @@ impl RuntimeOutputs {
     // Getters - Access runtime output values
     // =========================================================================
 
-    /// Returns the instance IP address if available
+    /// Returns the instance IP address of the preferred family if available
     ///
-    /// This is `None` until the environment has been provisioned or registered.
+    /// Falls back to the other family when the instance has no address of
+    /// the preferred one. This is `None` until the environment has been
+    /// provisioned or registered.
     #[must_use]
-    pub fn instance_ip(&self) -> Option<IpAddr> {
-        self.instance_ip
+    pub fn instance_ip(&self, preference: IpPreference) -> Option<IpAddr> {
+        self.addresses().preferred(preference)
+    }
+
+    /// Returns the instance addresses of both families
+    #[must_use]
+    pub fn addresses(&self) -> InstanceAddresses {
+        InstanceAddresses::new(self.ipv4, self.ipv6)
     }
 
     /// Returns how the instance was provisioned
@@ -338,33 +593,86 @@ impl RuntimeOutputs {
         self.service_endpoints.as_ref()
     }
 
+    /// Returns the recorded releases, oldest first
+    #[must_use]
+    pub fn release_history(&self) -> &[ReleaseRecord] {
+        &self.release_history
+    }
+
+    /// Returns the release currently deployed, if any
+    #[must_use]
+    pub fn current_release(&self) -> Option<&ReleaseRecord> {
+        self.release_history.last()
+    }
+
+    /// Returns the release before the current one, the target of a rollback
+    #[must_use]
+    pub fn previous_release(&self) -> Option<&ReleaseRecord> {
+        self.release_history
+            .len()
+            .checked_sub(2)
+            .map(|index| &self.release_history[index])
+    }
+
+    /// Returns the name the infrastructure was created under, if it differs
+    /// from the environment name
+    #[must_use]
+    pub fn infrastructure_name(&self) -> Option<&EnvironmentName> {
+        self.infrastructure_name.as_ref()
+    }
+
+    /// Returns the host port SSH is published on, if it differs from the
+    /// configured SSH port
+    #[must_use]
+    pub fn published_ssh_port(&self) -> Option<u16> {
+        self.published_ssh_port
+    }
+
+    /// Returns when the environment was destroyed, if recorded
+    #[must_use]
+    pub fn destroyed_at(&self) -> Option<DateTime<Utc>> {
+        self.destroyed_at
+    }
+
+    /// Returns how long the last successful run of each deployment phase took
+    #[must_use]
+    pub fn phase_durations(&self) -> &PhaseDurations {
+        &self.phase_durations
+    }
+
+    /// Returns the latest backup of the tracker database, if any
+    #[must_use]
+    pub fn last_backup(&self) -> Option<&DatabaseBackup> {
+        self.last_backup.as_ref()
+    }
+
     // =========================================================================
     // Semantic Setters - Record deployment lifecycle events
     // =========================================================================
 
-    /// Records that provisioning has completed with the given instance IP
+    /// Records that provisioning has completed with the given instance addresses
     ///
     /// Call this after the `provision` command successfully creates infrastructure.
-    /// Sets both `instance_ip` and `provision_method` to `Provisioned`.
+    /// Sets the instance addresses and `provision_method` to `Provisioned`.
     ///
     /// # Arguments
     ///
-    /// * `ip` - The IP address of the newly provisioned instance
-    pub fn record_provisioning(&mut self, ip: IpAddr) {
-        self.instance_ip = Some(ip);
+    /// * `addresses` - The IP address(es) of the newly provisioned instance
+    pub fn record_provisioning(&mut self, addresses: impl Into<InstanceAddresses>) {
+        self.set_instance_addresses(addresses);
         self.provision_method = Some(ProvisionMethod::Provisioned);
     }
 
     /// Records that an existing instance has been registered
     ///
     /// Call this after the `register` command connects to existing infrastructure.
-    /// Sets both `instance_ip` and `provision_method` to `Registered`.
+    /// Sets the instance address and `provision_method` to `Registered`.
     ///
     /// # Arguments
     ///
     /// * `ip` - The IP address of the registered instance
     pub fn record_registration(&mut self, ip: IpAddr) {
-        self.instance_ip = Some(ip);
+        self.set_instance_addresses(ip);
         self.provision_method = Some(ProvisionMethod::Registered);
     }
 
@@ -380,17 +688,75 @@ impl RuntimeOutputs {
         self.service_endpoints = Some(endpoints);
     }
 
+    /// Records a successful release
+    ///
+    /// Call this after the `release` command deploys the tracker. The oldest
+    /// entry is dropped once the history holds [`RELEASE_HISTORY_LIMIT`]
+    /// releases.
+    pub fn record_release(&mut self, record: ReleaseRecord) {
+        self.release_history.push(record);
+
+        let excess = self
+            .release_history
+            .len()
+            .saturating_sub(RELEASE_HISTORY_LIMIT);
+        self.release_history.drain(..excess);
+    }
+
+    /// Records how long a successful run of `phase` took, in milliseconds
+    ///
+    /// Call this when the `provision`, `configure`, `release` or `run`
+    /// command completes. The duration of the previous run is replaced.
+    pub fn record_phase_duration(&mut self, phase: DeploymentPhase, duration_ms: u64) {
+        self.phase_durations.record(phase, duration_ms);
+    }
+
+    /// Records a backup of the tracker database as the latest one
+    ///
+    /// Call this after the `backup` command downloads a backup.
+    pub fn record_backup(&mut self, backup: DatabaseBackup) {
+        self.last_backup = Some(backup);
+    }
+
+    /// Removes the current release from the history
+    ///
+    /// Call this after the `rollback` command redeploys the previous release,
+    /// which then becomes the current one.
+    pub fn drop_latest_release(&mut self) -> Option<ReleaseRecord> {
+        self.release_history.pop()
+    }
+
+    /// Records that the environment was renamed while keeping its infrastructure
+    ///
+    /// Call this after the `rename` command renames an environment that may
+    /// have infrastructure. The first name is kept over successive renames,
+    /// and renaming back to it clears the record.
+    pub fn record_rename(&mut self, from: &EnvironmentName, to: &EnvironmentName) {
+        let original = self
+            .infrastructure_name
+            .take()
+            .unwrap_or_else(|| from.clone());
+
+        if &original != to {
+            self.infrastructure_name = Some(original);
+        }
+    }
+
     // =========================================================================
     // Low-level setters - For backward compatibility and state restoration
     // =========================================================================
 
-    /// Sets the instance IP directly
+    /// Sets the instance addresses directly
     ///
-    /// Prefer `record_provisioning()` or `record_registration()` which also
-    /// set the provision method. This method is provided for cases where
-    /// only the IP needs to be updated (e.g., deserialization workarounds).
-    pub fn set_instance_ip(&mut self, ip: IpAddr) {
-        self.instance_ip = Some(ip);
+    /// Replaces the addresses of both families: a single `IpAddr` clears the
+    /// address of the other family. Prefer `record_provisioning()` or
+    /// `record_registration()` which also set the provision method. This
+    /// method is provided for cases where only the addresses need to be
+    /// updated (e.g., deserialization workarounds).
+    pub fn set_instance_addresses(&mut self, addresses: impl Into<InstanceAddresses>) {
+        let addresses = addresses.into();
+        self.ipv4 = addresses.ipv4;
+        self.ipv6 = addresses.ipv6;
     }
 
     /// Sets the provision method directly
@@ -400,6 +766,18 @@ impl RuntimeOutputs {
     pub fn set_provision_method(&mut self, method: ProvisionMethod) {
         self.provision_method = Some(method);
     }
+
+    /// Sets the host port SSH is published on
+    ///
+    /// `None` restores the SSH port of the user inputs.
+    pub fn set_published_ssh_port(&mut self, port: Option<u16>) {
+        self.published_ssh_port = port;
+    }
+
+    /// Records when the environment was destroyed
+    pub fn set_destroyed_at(&mut self, destroyed_at: DateTime<Utc>) {
+        self.destroyed_at = Some(destroyed_at);
+    }
 }
 
 impl Default for RuntimeOutputs {
@@ -407,3 +785,136 @@ impl Default for RuntimeOutputs {
         Self::new()
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use chrono::TimeZone;
+
+    use super::*;
+
+    fn release(version: &str, hour: u32) -> ReleaseRecord {
+        ReleaseRecord::new(
+            TrackerVersion::new(version).unwrap(),
+            Utc.with_ymd_and_hms(2026, 1, 1, hour, 0, 0).unwrap(),
+        )
+    }
+
+    #[test]
+    fn it_should_persist_the_published_ssh_port() {
+        let mut outputs = RuntimeOutputs::new();
+        outputs.set_published_ssh_port(Some(49153));
+
+        let json = serde_json::to_string(&outputs).unwrap();
+        let restored: RuntimeOutputs = serde_json::from_str(&json).unwrap();
+
+        assert_eq!(restored.published_ssh_port(), Some(49153));
+    }
+
+    #[test]
+    fn it_should_not_serialize_the_published_ssh_port_when_unset() {
+        let json = serde_json::to_string(&RuntimeOutputs::new()).unwrap();
+
+        assert!(!json.contains("published_ssh_port"));
+    }
+
+    #[test]
+    fn it_should_persist_both_addresses_of_a_dual_stack_instance() {
+        let mut outputs = RuntimeOutputs::new();
+        outputs.record_provisioning(InstanceAddresses::new(
+            Some(Ipv4Addr::new(10, 0, 0, 1)),
+            Some("2001:db8::10".parse().unwrap()),
+        ));
+
+        let json = serde_json::to_string(&outputs).unwrap();
+        let restored: RuntimeOutputs = serde_json::from_str(&json).unwrap();
+
+        assert_eq!(restored.addresses(), outputs.addresses());
+        assert!(!json.contains("instance_ip"));
+    }
+
+    #[test]
+    fn it_should_bracket_ipv6_addresses_in_service_endpoints() {
+        let tracker_config = crate::domain::tracker::TrackerConfig::default();
+
+        let endpoints =
+            ServiceEndpoints::from_tracker_config(&tracker_config, "2001:db8::10".parse().unwrap());
+
+        assert_eq!(
+            endpoints.health_check_url.unwrap().as_str(),
+            "http://[2001:db8::10]:1313/health_check" // DevSkim: ignore DS137138
+        );
+        assert_eq!(
+            endpoints.udp_trackers.len(),
+            tracker_config.udp_trackers().len()
+        );
+    }
+
+    #[test]
+    fn it_should_keep_only_the_latest_releases() {
+        let mut outputs = RuntimeOutputs::new();
+
+        for hour in 0..7 {
+            outputs.record_release(release(&format!("3.0.{hour}"), hour));
+        }
+
+        let versions: Vec<&str> = outputs
+            .release_history()
+            .iter()
+            .map(|record| record.version.as_str())
+            .collect();
+        assert_eq!(versions, ["3.0.2", "3.0.3", "3.0.4", "3.0.5", "3.0.6"]);
+    }
+
+    #[test]
+    fn it_should_make_the_previous_release_current_when_dropping_the_latest() {
+        let mut outputs = RuntimeOutputs::new();
+        outputs.record_release(release("2.0.0", 1));
+        outputs.record_release(release("3.0.0", 2));
+
+        assert_eq!(outputs.previous_release(), Some(&release("2.0.0", 1)));
+
+        outputs.drop_latest_release();
+
+        assert_eq!(outputs.current_release(), Some(&release("2.0.0", 1)));
+        assert!(outputs.previous_release().is_none());
+    }
+
+    #[test]
+    fn it_should_keep_the_first_infrastructure_name_over_successive_renames() {
+        let name = |name: &str| EnvironmentName::new(name).unwrap();
+        let mut outputs = RuntimeOutputs::new();
+
+        outputs.record_rename(&name("tmp"), &name("staging"));
+        outputs.record_rename(&name("staging"), &name("prod"));
+        assert_eq!(outputs.infrastructure_name(), Some(&name("tmp")));
+
+        outputs.record_rename(&name("prod"), &name("tmp"));
+        assert_eq!(outputs.infrastructure_name(), None);
+    }
+
+    #[test]
+    fn it_should_persist_the_phase_durations() {
+        let mut outputs = RuntimeOutputs::new();
+        outputs.record_phase_duration(DeploymentPhase::Provision, 95_000);
+        outputs.record_phase_duration(DeploymentPhase::Provision, 90_000);
+        outputs.record_phase_duration(DeploymentPhase::Run, 12_000);
+
+        let json = serde_json::to_string(&outputs).unwrap();
+        let restored: RuntimeOutputs = serde_json::from_str(&json).unwrap();
+
+        let durations = restored.phase_durations();
+        assert_eq!(durations.get(DeploymentPhase::Provision), Some(90_000));
+        assert_eq!(durations.get(DeploymentPhase::Configure), None);
+        assert_eq!(durations.get(DeploymentPhase::Run), Some(12_000));
+    }
+
+    #[test]
+    fn it_should_load_state_written_before_phase_durations_were_recorded() {
+        let json = serde_json::to_string(&RuntimeOutputs::new()).unwrap();
+        assert!(!json.contains("phase_durations"));
+
+        let restored: RuntimeOutputs = serde_json::from_str(&json).unwrap();
+
+        assert!(restored.phase_durations().is_empty());
+    }
+}
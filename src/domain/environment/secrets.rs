@@ -0,0 +1,48 @@
+//! Environment secrets
+//!
+//! Secrets provided by the user (e.g. the Grafana admin password or the
+//! password of a private container registry) and the keys minted on a private
+//! tracker are needed by later commands to
+//! render deployment templates and log in to the registry, but they must not be
+//! written in plaintext to the environment state file, which is regularly
+//! inspected, attached to bug reports and shown by tooling.
+//!
+//! Domain types holding secrets skip them when serialized. Repositories
+//! persist them separately as `EnvironmentSecrets` and restore them into the
+//! environment after loading it.
+
+use serde::{Deserialize, Serialize};
+
+use crate::domain::tracker::IssuedAuthKey;
+use crate::shared::secrets::Password;
+
+/// Secrets of an environment that are persisted apart from its state
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct EnvironmentSecrets {
+    /// Grafana admin password, when Grafana is enabled
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub grafana_admin_password: Option<Password>,
+
+    /// Password of the private container registry, when one is configured
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub registry_password: Option<Password>,
+
+    /// Keys minted on the tracker with the `keys add` command
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub tracker_auth_keys: Vec<IssuedAuthKey>,
+
+    /// Trust token of the LXD remote, when one is configured with a token
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub lxd_trust_token: Option<Password>,
+}
+
+impl EnvironmentSecrets {
+    /// Returns `true` if there are no secrets to persist
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.grafana_admin_password.is_none()
+            && self.registry_password.is_none()
+            && self.tracker_auth_keys.is_empty()
+            && self.lxd_trust_token.is_none()
+    }
+}
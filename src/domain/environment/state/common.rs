@@ -43,6 +43,7 @@ pub struct BaseFailureContext {
     pub trace_id: TraceId,
 
     /// Path to the detailed trace file (if generated)
+    #[serde(default, with = "crate::shared::portable_path::option")]
     pub trace_file_path: Option<PathBuf>,
 }
 
@@ -13,6 +13,7 @@
 
 use serde::{Deserialize, Serialize};
 
+use crate::adapters::ansible::TaskFailure;
 use crate::domain::environment::state::{AnyEnvironmentState, BaseFailureContext, StateTypeError};
 use crate::domain::environment::Environment;
 use crate::shared::ErrorKind;
@@ -36,10 +37,21 @@ pub struct ConfigureFailureContext {
     /// Base failure context with common fields
     #[serde(flatten)]
     pub base: BaseFailureContext,
+
+    /// The Ansible task that failed, when the step failed in a playbook task
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub failed_task: Option<TaskFailure>,
+
+    /// How many times the playbook of the failed step was run
+    ///
+    /// Greater than one when the host became unreachable and the playbook
+    /// was retried; `None` when the step failed before running a playbook.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub attempts: Option<u32>,
 }
 
 /// Steps in the configure workflow
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 pub enum ConfigureStep {
     /// Installing Docker
     InstallDocker,
@@ -49,6 +61,12 @@ pub enum ConfigureStep {
     ConfigureSecurityUpdates,
     /// Configuring UFW firewall (SSH access only)
     ConfigureFirewall,
+    /// Applying the system tuning (sysctls, ulimits, swap file)
+    ConfigureSystemTuning,
+    /// Configuration was interrupted by the user (Ctrl-C)
+    Interrupted,
+    /// Running the custom step with this name, registered by an SDK user
+    CustomStep(String),
 }
 
 /// Error state - Application configuration failed
@@ -86,10 +104,7 @@ impl AnyEnvironmentState {
     pub fn try_into_configure_failed(self) -> Result<Environment<ConfigureFailed>, StateTypeError> {
         match self {
             Self::ConfigureFailed(env) => Ok(env),
-            other => Err(StateTypeError::UnexpectedState {
-                expected: "configure_failed",
-                actual: other.state_name().to_string(),
-            }),
+            other => Err(StateTypeError::unexpected_state("configure_failed", &other)),
         }
     }
 }
@@ -113,6 +128,8 @@ mod tests {
                 trace_id: TraceId::new(),
                 trace_file_path: None,
             },
+            failed_task: None,
+            attempts: None,
         }
     }
 
@@ -163,6 +180,7 @@ mod tests {
         fn default_lxd_provider_config(env_name: &EnvironmentName) -> ProviderConfig {
             ProviderConfig::Lxd(LxdConfig {
                 profile_name: ProfileName::new(format!("lxd-{}", env_name.as_str())).unwrap(),
+                remote: None,
             })
         }
 
@@ -226,6 +244,8 @@ mod tests {
                     trace_id: TraceId::new(),
                     trace_file_path: None,
                 },
+                failed_task: None,
+                attempts: None,
             };
 
             let json = serde_json::to_string(&context).unwrap();
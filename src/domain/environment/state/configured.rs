@@ -53,10 +53,7 @@ impl AnyEnvironmentState {
     pub fn try_into_configured(self) -> Result<Environment<Configured>, StateTypeError> {
         match self {
             Self::Configured(env) => Ok(env),
-            other => Err(StateTypeError::UnexpectedState {
-                expected: "configured",
-                actual: other.state_name().to_string(),
-            }),
+            other => Err(StateTypeError::unexpected_state("configured", &other)),
         }
     }
 }
@@ -85,6 +82,7 @@ mod tests {
         fn default_lxd_provider_config(env_name: &EnvironmentName) -> ProviderConfig {
             ProviderConfig::Lxd(LxdConfig {
                 profile_name: ProfileName::new(format!("lxd-{}", env_name.as_str())).unwrap(),
+                remote: None,
             })
         }
 
@@ -147,6 +145,7 @@ mod tests {
         fn default_lxd_provider_config(env_name: &EnvironmentName) -> ProviderConfig {
             ProviderConfig::Lxd(LxdConfig {
                 profile_name: ProfileName::new(format!("lxd-{}", env_name.as_str())).unwrap(),
+                remote: None,
             })
         }
 
@@ -70,10 +70,7 @@ impl AnyEnvironmentState {
     pub fn try_into_configuring(self) -> Result<Environment<Configuring>, StateTypeError> {
         match self {
             Self::Configuring(env) => Ok(env),
-            other => Err(StateTypeError::UnexpectedState {
-                expected: "configuring",
-                actual: other.state_name().to_string(),
-            }),
+            other => Err(StateTypeError::unexpected_state("configuring", &other)),
         }
     }
 }
@@ -102,6 +99,7 @@ mod tests {
         fn default_lxd_provider_config(env_name: &EnvironmentName) -> ProviderConfig {
             ProviderConfig::Lxd(LxdConfig {
                 profile_name: ProfileName::new(format!("lxd-{}", env_name.as_str())).unwrap(),
+                remote: None,
             })
         }
 
@@ -163,6 +161,7 @@ mod tests {
         fn default_lxd_provider_config(env_name: &EnvironmentName) -> ProviderConfig {
             ProviderConfig::Lxd(LxdConfig {
                 profile_name: ProfileName::new(format!("lxd-{}", env_name.as_str())).unwrap(),
+                remote: None,
             })
         }
 
@@ -220,6 +219,8 @@ mod tests {
                     trace_id: TraceId::new(),
                     trace_file_path: None,
                 },
+                failed_task: None,
+                attempts: None,
             };
             let env = env.configure_failed(context.clone());
 
@@ -59,6 +59,22 @@ impl Environment<Created> {
             .with_provision_method(ProvisionMethod::Registered)
             .with_state(Provisioned)
     }
+
+    /// Imports an existing deployment and transitions directly to Provisioned state
+    ///
+    /// Like `register()`, but the provision method is set to `Imported`. Callers
+    /// that detect an existing installation continue the transitions from the
+    /// returned `Provisioned` environment.
+    ///
+    /// # Arguments
+    ///
+    /// * `instance_ip` - The IP address of the existing instance
+    #[must_use]
+    pub fn import(self, instance_ip: IpAddr) -> Environment<Provisioned> {
+        self.with_instance_ip(instance_ip)
+            .with_provision_method(ProvisionMethod::Imported)
+            .with_state(Provisioned)
+    }
 }
 
 // Type Erasure: Typed → Runtime conversion (into_any)
@@ -80,10 +96,7 @@ impl AnyEnvironmentState {
     pub fn try_into_created(self) -> Result<Environment<Created>, StateTypeError> {
         match self {
             Self::Created(env) => Ok(env),
-            other => Err(StateTypeError::UnexpectedState {
-                expected: "created",
-                actual: other.state_name().to_string(),
-            }),
+            other => Err(StateTypeError::unexpected_state("created", &other)),
         }
     }
 }
@@ -137,6 +150,7 @@ mod tests {
         fn default_lxd_provider_config(env_name: &EnvironmentName) -> ProviderConfig {
             ProviderConfig::Lxd(LxdConfig {
                 profile_name: ProfileName::new(format!("lxd-{}", env_name.as_str())).unwrap(),
+                remote: None,
             })
         }
 
@@ -215,6 +229,7 @@ mod tests {
         fn default_lxd_provider_config(env_name: &EnvironmentName) -> ProviderConfig {
             ProviderConfig::Lxd(LxdConfig {
                 profile_name: ProfileName::new(format!("lxd-{}", env_name.as_str())).unwrap(),
+                remote: None,
             })
         }
 
@@ -283,5 +298,21 @@ mod tests {
             // Verify instance IP is set
             assert_eq!(env.instance_ip(), Some(instance_ip));
         }
+
+        #[test]
+        fn it_should_import_existing_instance_as_externally_provisioned() {
+            use std::net::{IpAddr, Ipv4Addr};
+
+            use crate::domain::environment::state::Provisioned;
+
+            let env = create_test_environment_created();
+            let instance_ip = IpAddr::V4(Ipv4Addr::new(10, 0, 0, 5));
+            let env = env.import(instance_ip);
+
+            assert_eq!(*env.state(), Provisioned);
+            assert_eq!(env.instance_ip(), Some(instance_ip));
+            assert_eq!(env.provision_method(), Some(ProvisionMethod::Imported));
+            assert!(!env.is_infrastructure_managed());
+        }
     }
 }
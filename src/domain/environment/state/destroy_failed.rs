@@ -84,10 +84,7 @@ impl AnyEnvironmentState {
     pub fn try_into_destroy_failed(self) -> Result<Environment<DestroyFailed>, StateTypeError> {
         match self {
             Self::DestroyFailed(env) => Ok(env),
-            other => Err(StateTypeError::UnexpectedState {
-                expected: "destroy_failed",
-                actual: other.state_name().to_string(),
-            }),
+            other => Err(StateTypeError::unexpected_state("destroy_failed", &other)),
         }
     }
 }
@@ -174,6 +171,7 @@ mod tests {
         fn default_lxd_provider_config(env_name: &EnvironmentName) -> ProviderConfig {
             ProviderConfig::Lxd(LxdConfig {
                 profile_name: ProfileName::new(format!("lxd-{}", env_name.as_str())).unwrap(),
+                remote: None,
             })
         }
 
@@ -7,6 +7,7 @@
 //!
 //! **No Valid Transitions:** This is a terminal state.
 
+use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
 
 use crate::domain::environment::state::{AnyEnvironmentState, StateTypeError};
@@ -21,6 +22,20 @@ use crate::domain::environment::Environment;
 #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 pub struct Destroyed;
 
+impl Environment<Destroyed> {
+    /// Records when the environment was destroyed and returns it
+    ///
+    /// The time is what `purge --gc` measures the age of destroyed
+    /// environments from.
+    #[must_use]
+    pub fn with_destroyed_at(mut self, destroyed_at: DateTime<Utc>) -> Self {
+        self.context_mut()
+            .runtime_outputs
+            .set_destroyed_at(destroyed_at);
+        self
+    }
+}
+
 // Type Erasure: Typed → Runtime conversion (into_any)
 impl Environment<Destroyed> {
     /// Converts typed `Environment<Destroyed>` into type-erased `AnyEnvironmentState`
@@ -40,10 +55,7 @@ impl AnyEnvironmentState {
     pub fn try_into_destroyed(self) -> Result<Environment<Destroyed>, StateTypeError> {
         match self {
             Self::Destroyed(env) => Ok(env),
-            other => Err(StateTypeError::UnexpectedState {
-                expected: "destroyed",
-                actual: other.state_name().to_string(),
-            }),
+            other => Err(StateTypeError::unexpected_state("destroyed", &other)),
         }
     }
 }
@@ -70,6 +82,7 @@ mod tests {
         fn default_lxd_provider_config(env_name: &EnvironmentName) -> ProviderConfig {
             ProviderConfig::Lxd(LxdConfig {
                 profile_name: ProfileName::new(format!("lxd-{}", env_name.as_str())).unwrap(),
+                remote: None,
             })
         }
 
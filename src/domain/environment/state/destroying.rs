@@ -70,10 +70,7 @@ impl AnyEnvironmentState {
     pub fn try_into_destroying(self) -> Result<Environment<Destroying>, StateTypeError> {
         match self {
             Self::Destroying(env) => Ok(env),
-            other => Err(StateTypeError::UnexpectedState {
-                expected: "destroying",
-                actual: other.state_name().to_string(),
-            }),
+            other => Err(StateTypeError::unexpected_state("destroying", &other)),
         }
     }
 }
@@ -102,6 +99,7 @@ mod tests {
         fn default_lxd_provider_config(env_name: &EnvironmentName) -> ProviderConfig {
             ProviderConfig::Lxd(LxdConfig {
                 profile_name: ProfileName::new(format!("lxd-{}", env_name.as_str())).unwrap(),
+                remote: None,
             })
         }
 
@@ -182,6 +180,7 @@ mod tests {
         fn default_lxd_provider_config(env_name: &EnvironmentName) -> ProviderConfig {
             ProviderConfig::Lxd(LxdConfig {
                 profile_name: ProfileName::new(format!("lxd-{}", env_name.as_str())).unwrap(),
+                remote: None,
             })
         }
 
@@ -0,0 +1,167 @@
+//! Lifecycle commands and the states they may run in
+//!
+//! Each lifecycle command requires the environment to be in specific states
+//! (e.g. `configure` only runs on a `Provisioned` environment). The matrix
+//! mapping every state to its allowed commands lives on
+//! [`AnyEnvironmentState::allowed_commands`], together with the command
+//! suggested to move the deployment forward
+//! ([`AnyEnvironmentState::suggested_next_command`]).
+
+use std::fmt;
+
+use serde::Serialize;
+
+use super::AnyEnvironmentState;
+
+/// A command whose availability depends on the environment state
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum LifecycleCommand {
+    /// Create the infrastructure of a `Created` environment
+    Provision,
+    /// Register an existing instance for a `Created` environment
+    Register,
+    /// Configure the instance of a `Provisioned` environment
+    Configure,
+    /// Deploy the tracker software to a `Configured` environment
+    Release,
+    /// Start the services of a `Released` environment
+    Run,
+    /// Run the smoke checks against a `Running` environment
+    Test,
+    /// Stop the services of a `Running` environment
+    Stop,
+    /// Start the services of a `Stopped` environment again
+    Start,
+    /// Redeploy the previous release of a `Released` or `Running` environment
+    Rollback,
+    /// Back up the tracker database of a `Released` or `Running` environment
+    Backup,
+    /// Restore the tracker database of a `Released` or `Running` environment
+    Restore,
+    /// Detect configuration drift on the instance of a `Released` or `Running` environment
+    Drift,
+    /// Destroy the infrastructure of the environment
+    Destroy,
+    /// Remove the local data of the environment
+    Purge,
+}
+
+impl LifecycleCommand {
+    /// Returns the CLI name of the command
+    #[must_use]
+    pub fn name(self) -> &'static str {
+        match self {
+            Self::Provision => "provision",
+            Self::Register => "register",
+            Self::Configure => "configure",
+            Self::Release => "release",
+            Self::Run => "run",
+            Self::Test => "test",
+            Self::Stop => "stop",
+            Self::Start => "start",
+            Self::Rollback => "rollback",
+            Self::Backup => "backup",
+            Self::Restore => "restore",
+            Self::Drift => "drift",
+            Self::Destroy => "destroy",
+            Self::Purge => "purge",
+        }
+    }
+}
+
+impl fmt::Display for LifecycleCommand {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.name())
+    }
+}
+
+impl AnyEnvironmentState {
+    /// Returns the lifecycle commands that may run in the current state
+    ///
+    /// Every state allows `purge` (forced when the infrastructure may still
+    /// exist) and, until the environment is destroyed, `destroy`. In-progress
+    /// and failed states allow nothing else.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use torrust_tracker_deployer_lib::domain::environment::state::{
+    ///     AnyEnvironmentState, LifecycleCommand,
+    /// };
+    ///
+    /// fn can_configure(environment: &AnyEnvironmentState) -> bool {
+    ///     environment.allowed_commands().contains(&LifecycleCommand::Configure)
+    /// }
+    /// ```
+    #[must_use]
+    pub fn allowed_commands(&self) -> &'static [LifecycleCommand] {
+        use LifecycleCommand::{
+            Backup, Configure, Destroy, Drift, Provision, Purge, Register, Release, Restore,
+            Rollback, Run, Start, Stop, Test,
+        };
+
+        match self {
+            Self::Created(_) => &[Provision, Register, Destroy, Purge],
+            Self::Provisioned(_) => &[Configure, Destroy, Purge],
+            Self::Configured(_) => &[Release, Destroy, Purge],
+            Self::Released(_) => &[Run, Rollback, Backup, Restore, Drift, Destroy, Purge],
+            Self::Running(_) => &[Test, Stop, Rollback, Backup, Restore, Drift, Destroy, Purge],
+            Self::Stopped(_) => &[Start, Destroy, Purge],
+            Self::Provisioning(_)
+            | Self::Configuring(_)
+            | Self::Releasing(_)
+            | Self::Destroying(_)
+            | Self::ProvisionFailed(_)
+            | Self::ConfigureFailed(_)
+            | Self::ReleaseFailed(_)
+            | Self::RunFailed(_)
+            | Self::DestroyFailed(_) => &[Destroy, Purge],
+            Self::Destroyed(_) => &[Purge],
+        }
+    }
+
+    /// Returns the command suggested to move the deployment forward
+    ///
+    /// A failed environment is destroyed, and a destroyed one purged. There
+    /// is no suggestion while a command is in progress, except for
+    /// `Destroying`, which `destroy` resumes.
+    #[must_use]
+    pub fn suggested_next_command(&self) -> Option<LifecycleCommand> {
+        match self {
+            Self::Created(_) => Some(LifecycleCommand::Provision),
+            Self::Provisioned(_) => Some(LifecycleCommand::Configure),
+            Self::Configured(_) => Some(LifecycleCommand::Release),
+            Self::Released(_) => Some(LifecycleCommand::Run),
+            Self::Running(_) => Some(LifecycleCommand::Test),
+            Self::Stopped(_) => Some(LifecycleCommand::Start),
+            Self::Destroying(_)
+            | Self::ProvisionFailed(_)
+            | Self::ConfigureFailed(_)
+            | Self::ReleaseFailed(_)
+            | Self::RunFailed(_)
+            | Self::DestroyFailed(_) => Some(LifecycleCommand::Destroy),
+            Self::Destroyed(_) => Some(LifecycleCommand::Purge),
+            Self::Provisioning(_) | Self::Configuring(_) | Self::Releasing(_) => None,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn it_should_display_the_cli_name_of_the_command() {
+        assert_eq!(LifecycleCommand::Configure.to_string(), "configure");
+        assert_eq!(LifecycleCommand::Rollback.to_string(), "rollback");
+    }
+
+    #[test]
+    fn it_should_serialize_as_the_cli_name_of_the_command() {
+        assert_eq!(
+            serde_json::to_string(&LifecycleCommand::Provision).unwrap(),
+            "\"provision\""
+        );
+    }
+}
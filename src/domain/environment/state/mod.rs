@@ -20,6 +20,9 @@
 //!   → Releasing → Released → Running → Destroyed
 //! ```
 //!
+//! A `Running` environment can be `Stopped` for maintenance and started
+//! again, returning to `Running`.
+//!
 //! ### Error States
 //!
 //! At each operational phase, the system can transition to a corresponding
@@ -43,6 +46,7 @@
 use serde::{Deserialize, Serialize};
 use thiserror::Error;
 
+use crate::domain::environment::failure_report::FailureReport;
 use crate::domain::environment::runtime_outputs::{ProvisionMethod, ServiceEndpoints};
 use crate::shared::domain_name::DomainName;
 
@@ -55,6 +59,7 @@ mod created;
 mod destroy_failed;
 mod destroyed;
 mod destroying;
+mod lifecycle_command;
 mod provision_failed;
 mod provisioned;
 mod provisioning;
@@ -63,6 +68,7 @@ mod released;
 mod releasing;
 mod run_failed;
 mod running;
+mod stopped;
 
 // Re-export state types
 pub use common::BaseFailureContext;
@@ -73,6 +79,7 @@ pub use created::Created;
 pub use destroy_failed::{DestroyFailed, DestroyFailureContext, DestroyStep};
 pub use destroyed::Destroyed;
 pub use destroying::Destroying;
+pub use lifecycle_command::LifecycleCommand;
 pub use provision_failed::{ProvisionFailed, ProvisionFailureContext, ProvisionStep};
 pub use provisioned::Provisioned;
 pub use provisioning::Provisioning;
@@ -81,6 +88,7 @@ pub use released::Released;
 pub use releasing::Releasing;
 pub use run_failed::{RunFailed, RunFailureContext, RunStep};
 pub use running::Running;
+pub use stopped::Stopped;
 
 /// Error type for invalid type conversions when working with type-erased environments
 ///
@@ -106,9 +114,26 @@ pub enum StateTypeError {
         expected: &'static str,
         /// The actual state type that was found
         actual: String,
+        /// The commands allowed in the actual state
+        allowed_commands: &'static [LifecycleCommand],
+        /// The command suggested in the actual state, if any
+        suggested_next: Option<LifecycleCommand>,
     },
 }
 
+impl StateTypeError {
+    /// Creates the error for an environment found in another state than `expected`
+    #[must_use]
+    pub fn unexpected_state(expected: &'static str, actual: &AnyEnvironmentState) -> Self {
+        Self::UnexpectedState {
+            expected,
+            actual: actual.state_name().to_string(),
+            allowed_commands: actual.allowed_commands(),
+            suggested_next: actual.suggested_next_command(),
+        }
+    }
+}
+
 // Import Environment for type erasure enum
 use crate::domain::environment::{Environment, EnvironmentName};
 
@@ -178,6 +203,9 @@ pub enum AnyEnvironmentState {
     /// Environment in `Running` state
     Running(Environment<Running>),
 
+    /// Environment in `Stopped` state
+    Stopped(Environment<Stopped>),
+
     /// Environment in `Destroying` state
     Destroying(Environment<Destroying>),
 
@@ -224,6 +252,7 @@ impl AnyEnvironmentState {
             Self::Releasing(env) => env.context(),
             Self::Released(env) => env.context(),
             Self::Running(env) => env.context(),
+            Self::Stopped(env) => env.context(),
             Self::Destroying(env) => env.context(),
             Self::ProvisionFailed(env) => env.context(),
             Self::ConfigureFailed(env) => env.context(),
@@ -234,6 +263,28 @@ impl AnyEnvironmentState {
         }
     }
 
+    /// Get a mutable reference to the environment context regardless of current state
+    fn context_mut(&mut self) -> &mut crate::domain::environment::EnvironmentContext {
+        match self {
+            Self::Created(env) => env.context_mut(),
+            Self::Provisioning(env) => env.context_mut(),
+            Self::Provisioned(env) => env.context_mut(),
+            Self::Configuring(env) => env.context_mut(),
+            Self::Configured(env) => env.context_mut(),
+            Self::Releasing(env) => env.context_mut(),
+            Self::Released(env) => env.context_mut(),
+            Self::Running(env) => env.context_mut(),
+            Self::Stopped(env) => env.context_mut(),
+            Self::Destroying(env) => env.context_mut(),
+            Self::ProvisionFailed(env) => env.context_mut(),
+            Self::ConfigureFailed(env) => env.context_mut(),
+            Self::ReleaseFailed(env) => env.context_mut(),
+            Self::RunFailed(env) => env.context_mut(),
+            Self::DestroyFailed(env) => env.context_mut(),
+            Self::Destroyed(env) => env.context_mut(),
+        }
+    }
+
     /// Get the environment name regardless of current state
     ///
     /// This method provides access to the environment name without needing to
@@ -247,6 +298,70 @@ impl AnyEnvironmentState {
         self.context().user_inputs.name()
     }
 
+    /// Get the user-provided configuration regardless of current state
+    ///
+    /// Runtime outputs (instance IP, provision method, ...) are not part of
+    /// the user inputs, so this is the data needed to recreate the
+    /// environment from scratch.
+    #[must_use]
+    pub fn user_inputs(&self) -> &crate::domain::environment::UserInputs {
+        &self.context().user_inputs
+    }
+
+    /// Get the data directory regardless of current state
+    #[must_use]
+    pub fn data_dir(&self) -> &std::path::PathBuf {
+        self.context().data_dir()
+    }
+
+    /// Get the file holding the recorded SSH host keys regardless of current state
+    #[must_use]
+    pub fn ssh_known_hosts_path(&self) -> std::path::PathBuf {
+        self.context().ssh_known_hosts_path()
+    }
+
+    /// Get the build directory regardless of current state
+    #[must_use]
+    pub fn build_dir(&self) -> &std::path::PathBuf {
+        self.context().build_dir()
+    }
+
+    /// Get the templates directory regardless of current state
+    #[must_use]
+    pub fn templates_dir(&self) -> std::path::PathBuf {
+        self.context().templates_dir()
+    }
+
+    /// Get the directory with user-provided template overrides regardless of current state
+    #[must_use]
+    pub fn templates_override_dir(&self) -> Option<std::path::PathBuf> {
+        self.context().templates_override_dir()
+    }
+
+    /// Get the user-defined template variables regardless of current state
+    #[must_use]
+    pub fn extra_template_variables(&self) -> &crate::domain::template::ExtraTemplateVariables {
+        self.context().extra_template_variables()
+    }
+
+    /// Get the admin token for the HTTP API regardless of current state
+    #[must_use]
+    pub fn admin_token(&self) -> &str {
+        self.context().admin_token()
+    }
+
+    /// Get the directory holding the output logs of external tools regardless of current state
+    #[must_use]
+    pub fn run_logs_dir(&self) -> std::path::PathBuf {
+        self.context().run_logs_dir()
+    }
+
+    /// Get the directory holding the tracker database backups regardless of current state
+    #[must_use]
+    pub fn backups_dir(&self) -> std::path::PathBuf {
+        self.context().backups_dir()
+    }
+
     /// Get the state name as a string
     ///
     /// Returns a static string identifier for the current state. This is useful
@@ -266,6 +381,7 @@ impl AnyEnvironmentState {
             Self::Releasing(_) => "releasing",
             Self::Released(_) => "released",
             Self::Running(_) => "running",
+            Self::Stopped(_) => "stopped",
             Self::Destroying(_) => "destroying",
             Self::ProvisionFailed(_) => "provision_failed",
             Self::ConfigureFailed(_) => "configure_failed",
@@ -276,6 +392,26 @@ impl AnyEnvironmentState {
         }
     }
 
+    /// All state names returned by [`state_name`](Self::state_name)
+    pub const STATE_NAMES: &'static [&'static str] = &[
+        "created",
+        "provisioning",
+        "provisioned",
+        "configuring",
+        "configured",
+        "releasing",
+        "released",
+        "running",
+        "stopped",
+        "destroying",
+        "provision_failed",
+        "configure_failed",
+        "release_failed",
+        "run_failed",
+        "destroy_failed",
+        "destroyed",
+    ];
+
     /// Returns a human-readable display name for the current state.
     ///
     /// This provides a user-friendly representation suitable for CLI output,
@@ -296,6 +432,7 @@ impl AnyEnvironmentState {
             Self::Releasing(_) => "Releasing",
             Self::Released(_) => "Released",
             Self::Running(_) => "Running",
+            Self::Stopped(_) => "Stopped",
             Self::Destroying(_) => "Destroying",
             Self::ProvisionFailed(_) => "Provision Failed",
             Self::ConfigureFailed(_) => "Configure Failed",
@@ -327,6 +464,7 @@ impl AnyEnvironmentState {
                 | Self::Releasing(_)
                 | Self::Released(_)
                 | Self::Running(_)
+                | Self::Stopped(_)
                 | Self::Destroying(_)
                 | Self::Destroyed(_)
         )
@@ -355,7 +493,7 @@ impl AnyEnvironmentState {
     /// Check if the environment is in a terminal state
     ///
     /// Terminal states are final states where no more transitions are expected.
-    /// This includes both successful terminal states (`Running`, `Destroyed`)
+    /// This includes both successful terminal states (`Running`, `Stopped`, `Destroyed`)
     /// and error states (all `*Failed` variants).
     ///
     /// # Returns
@@ -366,6 +504,7 @@ impl AnyEnvironmentState {
         matches!(
             self,
             Self::Running(_)
+                | Self::Stopped(_)
                 | Self::Destroyed(_)
                 | Self::ProvisionFailed(_)
                 | Self::ConfigureFailed(_)
@@ -375,6 +514,37 @@ impl AnyEnvironmentState {
         )
     }
 
+    /// Check if infrastructure may still exist for the environment
+    ///
+    /// Only `Created` (nothing provisioned yet) and `Destroyed` environments
+    /// are known to have no instance. Every other state, including the failed
+    /// ones, may have a running instance that costs money.
+    ///
+    /// # Returns
+    ///
+    /// `true` unless the environment is `Created` or `Destroyed`.
+    #[must_use]
+    pub fn may_have_infrastructure(&self) -> bool {
+        !matches!(self, Self::Created(_) | Self::Destroyed(_))
+    }
+
+    /// Check if an operation is in progress on the environment
+    ///
+    /// Transitional states (`Provisioning`, `Configuring`, `Releasing`,
+    /// `Destroying`) are left by the command that entered them, so another
+    /// command changing the environment meanwhile would race with it.
+    ///
+    /// # Returns
+    ///
+    /// `true` if the environment is in a transitional state.
+    #[must_use]
+    pub fn is_transitional_state(&self) -> bool {
+        matches!(
+            self,
+            Self::Provisioning(_) | Self::Configuring(_) | Self::Releasing(_) | Self::Destroying(_)
+        )
+    }
+
     /// Get error details if the environment is in an error state
     ///
     /// For error states (`*Failed`), this returns the description of the
@@ -396,6 +566,85 @@ impl AnyEnvironmentState {
         }
     }
 
+    /// Get the name of the failed custom step if a step registered by an SDK
+    /// user failed
+    ///
+    /// # Returns
+    ///
+    /// - `Some(&str)` for `ProvisionFailed` and `ConfigureFailed` states whose
+    ///   failed step is a custom step
+    /// - `None` otherwise
+    #[must_use]
+    pub fn failed_custom_step(&self) -> Option<&str> {
+        match self {
+            Self::ProvisionFailed(env) => match &env.state().context.failed_step {
+                ProvisionStep::CustomStep(name) => Some(name),
+                _ => None,
+            },
+            Self::ConfigureFailed(env) => match &env.state().context.failed_step {
+                ConfigureStep::CustomStep(name) => Some(name),
+                _ => None,
+            },
+            _ => None,
+        }
+    }
+
+    /// Get a structured failure report if the environment is in an error state
+    ///
+    /// The report is built from the failure context persisted in the state.
+    /// Its error chain is empty; the full chain is only available in the
+    /// structured trace file referenced by `base.trace_file_path`.
+    ///
+    /// # Returns
+    ///
+    /// - `Some(FailureReport)` for error states (`*Failed`)
+    /// - `None` for success states
+    #[must_use]
+    pub fn failure_report(&self) -> Option<FailureReport> {
+        match self {
+            Self::ProvisionFailed(env) => {
+                let ctx = &env.state().context;
+                Some(FailureReport::new(
+                    ctx.failed_step.clone(),
+                    ctx.error_kind,
+                    ctx.base.clone(),
+                ))
+            }
+            Self::ConfigureFailed(env) => {
+                let ctx = &env.state().context;
+                Some(
+                    FailureReport::new(ctx.failed_step.clone(), ctx.error_kind, ctx.base.clone())
+                        .with_failed_task(ctx.failed_task.clone()),
+                )
+            }
+            Self::ReleaseFailed(env) => {
+                let ctx = &env.state().context;
+                Some(FailureReport::new(
+                    ctx.failed_step,
+                    ctx.error_kind,
+                    ctx.base.clone(),
+                ))
+            }
+            Self::RunFailed(env) => {
+                let ctx = &env.state().context;
+                Some(FailureReport::new(
+                    ctx.failed_step,
+                    ctx.error_kind,
+                    ctx.base.clone(),
+                ))
+            }
+            Self::DestroyFailed(env) => {
+                let ctx = &env.state().context;
+                Some(FailureReport::new(
+                    ctx.failed_step,
+                    ctx.error_kind,
+                    ctx.base.clone(),
+                ))
+            }
+            _ => None,
+        }
+    }
+
     /// Get the instance name regardless of current state
     ///
     /// This method provides access to the instance name without needing to
@@ -455,7 +704,7 @@ impl AnyEnvironmentState {
     /// The SSH port number.
     #[must_use]
     pub fn ssh_port(&self) -> u16 {
-        self.context().user_inputs.ssh_port()
+        self.context().ssh_port()
     }
 
     /// Get the provider name regardless of current state
@@ -511,7 +760,7 @@ impl AnyEnvironmentState {
     /// - `None` if the environment hasn't been provisioned yet
     #[must_use]
     pub fn instance_ip(&self) -> Option<std::net::IpAddr> {
-        self.context().runtime_outputs.instance_ip()
+        self.context().instance_ip()
     }
 
     /// Get when the environment was created
@@ -527,6 +776,36 @@ impl AnyEnvironmentState {
         self.context().created_at
     }
 
+    /// Get the revision of the user inputs regardless of current state
+    #[must_use]
+    pub fn config_revision(&self) -> u32 {
+        self.context().config_revision
+    }
+
+    /// Get when the environment entered its current state, if recorded
+    ///
+    /// Only the transitions that end a lifecycle are timestamped: the
+    /// destruction for `Destroyed` environments, and the failure for error
+    /// states (`*Failed`).
+    ///
+    /// # Returns
+    ///
+    /// - `Some(DateTime)` for error states and for `Destroyed` environments
+    ///   destroyed since the destruction time is recorded
+    /// - `None` for the other states
+    #[must_use]
+    pub fn last_transition_at(&self) -> Option<chrono::DateTime<chrono::Utc>> {
+        match self {
+            Self::Destroyed(env) => env.context().runtime_outputs.destroyed_at(),
+            Self::ProvisionFailed(env) => Some(env.state().context.base.failed_at),
+            Self::ConfigureFailed(env) => Some(env.state().context.base.failed_at),
+            Self::ReleaseFailed(env) => Some(env.state().context.base.failed_at),
+            Self::RunFailed(env) => Some(env.state().context.base.failed_at),
+            Self::DestroyFailed(env) => Some(env.state().context.base.failed_at),
+            _ => None,
+        }
+    }
+
     /// Get the provision method if available, regardless of current state
     ///
     /// This method provides access to the provision method without needing to
@@ -556,6 +835,30 @@ impl AnyEnvironmentState {
         self.context().runtime_outputs.service_endpoints()
     }
 
+    /// Get the recorded releases, oldest first, regardless of current state
+    #[must_use]
+    pub fn release_history(&self) -> &[crate::domain::environment::ReleaseRecord] {
+        self.context().runtime_outputs.release_history()
+    }
+
+    /// Get the latest backup of the tracker database, regardless of current state
+    #[must_use]
+    pub fn last_backup(&self) -> Option<&crate::domain::environment::DatabaseBackup> {
+        self.context().runtime_outputs.last_backup()
+    }
+
+    /// Record a backup of the tracker database as the latest one, regardless of current state
+    pub fn record_backup(&mut self, backup: crate::domain::environment::DatabaseBackup) {
+        self.context_mut().runtime_outputs.record_backup(backup);
+    }
+
+    /// Get how long the last successful run of each deployment phase took,
+    /// regardless of current state
+    #[must_use]
+    pub fn phase_durations(&self) -> &crate::domain::environment::PhaseDurations {
+        self.context().runtime_outputs.phase_durations()
+    }
+
     /// Get the Prometheus configuration if enabled, regardless of current state
     ///
     /// This method provides access to the Prometheus configuration without needing to
@@ -584,6 +887,119 @@ impl AnyEnvironmentState {
         self.context().user_inputs.grafana()
     }
 
+    /// Get the secrets of the environment, regardless of current state
+    ///
+    /// Secrets are not part of the serialized state. Repositories persist
+    /// them separately using this method.
+    #[must_use]
+    pub fn secrets(&self) -> crate::domain::environment::EnvironmentSecrets {
+        self.context().user_inputs.secrets()
+    }
+
+    /// Restore the secrets of the environment after loading its state
+    pub fn restore_secrets(&mut self, secrets: crate::domain::environment::EnvironmentSecrets) {
+        self.context_mut().user_inputs.restore_secrets(secrets);
+    }
+
+    /// Name of a secret needed by the environment that was not restored
+    #[must_use]
+    pub fn missing_secret(&self) -> Option<&'static str> {
+        self.context().user_inputs.missing_secret()
+    }
+
+    /// Rename the environment, regardless of current state
+    ///
+    /// Points the data and build directories, and the SSH keys generated in
+    /// the data directory, to the ones of `name` under `layout`. The files
+    /// themselves are not moved.
+    ///
+    /// The instance and LXD profile keep their names: when the environment
+    /// may have infrastructure, the name they were derived from is recorded
+    /// in the runtime outputs. Use [`Self::rename_infrastructure`] to rename
+    /// them when no infrastructure exists.
+    pub fn rename(
+        &mut self,
+        name: EnvironmentName,
+        layout: &crate::domain::environment::WorkspaceLayout,
+    ) {
+        let may_have_infrastructure = self.may_have_infrastructure();
+        let context = self.context_mut();
+        let previous_name = context.user_inputs.name().clone();
+        let internal_config =
+            crate::domain::environment::InternalConfig::with_layout(&name, layout);
+
+        context
+            .user_inputs
+            .relocate_ssh_keys(&context.internal_config.data_dir, &internal_config.data_dir);
+        context.user_inputs.rename(name.clone());
+        context.internal_config = internal_config;
+
+        if may_have_infrastructure {
+            context.runtime_outputs.record_rename(&previous_name, &name);
+        }
+    }
+
+    /// Move the environment to another workspace, keeping its name
+    ///
+    /// Points the data and build directories to the ones of the environment
+    /// under `layout`, and the SSH keys to the given paths. The files
+    /// themselves are not moved.
+    pub fn relocate(
+        &mut self,
+        layout: &crate::domain::environment::WorkspaceLayout,
+        ssh_priv_key_path: std::path::PathBuf,
+        ssh_pub_key_path: std::path::PathBuf,
+    ) {
+        let context = self.context_mut();
+        context.internal_config = crate::domain::environment::InternalConfig::with_layout(
+            context.user_inputs.name(),
+            layout,
+        );
+        context
+            .user_inputs
+            .replace_ssh_key_paths(ssh_priv_key_path, ssh_pub_key_path);
+    }
+
+    /// Replace the instance name and, if given, the LXD profile name
+    pub fn rename_infrastructure(
+        &mut self,
+        instance_name: crate::domain::InstanceName,
+        profile_name: Option<crate::domain::ProfileName>,
+    ) {
+        self.context_mut()
+            .user_inputs
+            .rename_infrastructure(instance_name, profile_name);
+    }
+
+    /// Get the name the infrastructure was created under, if the environment
+    /// was renamed since
+    #[must_use]
+    pub fn infrastructure_name(&self) -> Option<&EnvironmentName> {
+        self.context().runtime_outputs.infrastructure_name()
+    }
+
+    /// Get the labels of the environment, regardless of current state
+    #[must_use]
+    pub fn labels(&self) -> &crate::domain::environment::Labels {
+        self.context().user_inputs.labels()
+    }
+
+    /// Replace the labels of the environment, regardless of current state
+    pub fn set_labels(&mut self, labels: crate::domain::environment::Labels) {
+        self.context_mut().user_inputs.set_labels(labels);
+    }
+
+    /// Get the keys minted on the tracker, regardless of current state
+    #[must_use]
+    pub fn tracker_auth_keys(&self) -> &[crate::domain::tracker::IssuedAuthKey] {
+        self.context().user_inputs.tracker_auth_keys()
+    }
+
+    /// Replace the keys minted on the tracker, regardless of current state
+    pub fn set_tracker_auth_keys(&mut self, keys: Vec<crate::domain::tracker::IssuedAuthKey>) {
+        self.context_mut().user_inputs.set_tracker_auth_keys(keys);
+    }
+
     /// Get the HTTPS configuration if enabled, regardless of current state
     ///
     /// This method provides access to the HTTPS configuration without needing to
@@ -612,6 +1028,19 @@ impl AnyEnvironmentState {
         matches!(self.provision_method(), Some(ProvisionMethod::Registered))
     }
 
+    /// Check if this environment's instance was created outside this tool
+    ///
+    /// This covers both registered and imported environments.
+    ///
+    /// # Returns
+    ///
+    /// `true` if the instance is externally provisioned, `false` otherwise.
+    #[must_use]
+    pub fn is_externally_provisioned(&self) -> bool {
+        self.provision_method()
+            .is_some_and(ProvisionMethod::is_externally_provisioned)
+    }
+
     /// Collect all TLS-enabled domains from the environment configuration
     ///
     /// Gathers domains from all services that have TLS enabled:
@@ -686,16 +1115,43 @@ impl AnyEnvironmentState {
             Self::Releasing(env) => Ok(env.destroy()),
             Self::Released(env) => Ok(env.destroy()),
             Self::Running(env) => Ok(env.destroy()),
+            Self::Stopped(env) => Ok(env.destroy()),
             Self::Destroying(env) => Ok(env.destroy()),
             Self::ProvisionFailed(env) => Ok(env.destroy()),
             Self::ConfigureFailed(env) => Ok(env.destroy()),
             Self::ReleaseFailed(env) => Ok(env.destroy()),
             Self::RunFailed(env) => Ok(env.destroy()),
             Self::DestroyFailed(env) => Ok(env.destroy()),
-            Self::Destroyed(_) => Err(StateTypeError::UnexpectedState {
-                expected: "any state except destroyed",
-                actual: "destroyed".to_string(),
-            }),
+            other @ Self::Destroyed(_) => Err(StateTypeError::unexpected_state(
+                "any state except destroyed",
+                &other,
+            )),
+        }
+    }
+
+    /// Start an out-of-band configure run, regardless of the current state
+    ///
+    /// See [`Environment::start_configuring_out_of_band`]. The returned
+    /// environment must never be persisted.
+    #[must_use]
+    pub fn start_configuring_out_of_band(self) -> Environment<Configuring> {
+        match self {
+            Self::Created(env) => env.start_configuring_out_of_band(),
+            Self::Provisioning(env) => env.start_configuring_out_of_band(),
+            Self::Provisioned(env) => env.start_configuring_out_of_band(),
+            Self::Configuring(env) => env.start_configuring_out_of_band(),
+            Self::Configured(env) => env.start_configuring_out_of_band(),
+            Self::Releasing(env) => env.start_configuring_out_of_band(),
+            Self::Released(env) => env.start_configuring_out_of_band(),
+            Self::Running(env) => env.start_configuring_out_of_band(),
+            Self::Stopped(env) => env.start_configuring_out_of_band(),
+            Self::Destroying(env) => env.start_configuring_out_of_band(),
+            Self::Destroyed(env) => env.start_configuring_out_of_band(),
+            Self::ProvisionFailed(env) => env.start_configuring_out_of_band(),
+            Self::ConfigureFailed(env) => env.start_configuring_out_of_band(),
+            Self::ReleaseFailed(env) => env.start_configuring_out_of_band(),
+            Self::RunFailed(env) => env.start_configuring_out_of_band(),
+            Self::DestroyFailed(env) => env.start_configuring_out_of_band(),
         }
     }
 
@@ -728,7 +1184,10 @@ impl AnyEnvironmentState {
 /// ```text
 /// Environment 'my-env' is in state: provisioning
 /// Environment 'my-env' is in state: provision_failed (failed at: network timeout)
+/// Environment 'my-env' is in state: provision_failed (failed at: custom:register-dns)
 /// ```
+///
+/// A failed custom step is shown by name, as its error message may be long.
 impl std::fmt::Display for AnyEnvironmentState {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         write!(
@@ -738,7 +1197,9 @@ impl std::fmt::Display for AnyEnvironmentState {
             self.state_name()
         )?;
 
-        if let Some(error_details) = self.error_details() {
+        if let Some(step) = self.failed_custom_step() {
+            write!(f, " (failed at: custom:{step})")?;
+        } else if let Some(error_details) = self.error_details() {
             write!(f, " (failed at: {error_details})")?;
         }
 
@@ -759,6 +1220,7 @@ mod tests {
     fn default_lxd_provider_config(env_name: &EnvironmentName) -> ProviderConfig {
         ProviderConfig::Lxd(LxdConfig {
             profile_name: ProfileName::new(format!("lxd-{}", env_name.as_str())).unwrap(),
+            remote: None,
         })
     }
 
@@ -803,6 +1265,7 @@ mod tests {
                 trace_id: TraceId::default(),
                 trace_file_path: None,
             },
+            remote_log_excerpt: None,
         }
     }
 
@@ -824,6 +1287,8 @@ mod tests {
                 trace_id: TraceId::default(),
                 trace_file_path: None,
             },
+            failed_task: None,
+            attempts: None,
         }
     }
 
@@ -866,6 +1331,7 @@ mod tests {
                 trace_id: TraceId::default(),
                 trace_file_path: None,
             },
+            last_health_check: None,
         }
     }
 
@@ -2161,6 +2627,45 @@ mod tests {
             }
         }
 
+        mod may_have_infrastructure {
+            use std::net::{IpAddr, Ipv4Addr};
+
+            use super::super::ProvisionMethod;
+
+            #[test]
+            fn it_should_return_false_for_created_and_destroyed_states() {
+                let created = super::create_test_environment_created();
+                let destroyed = super::create_test_environment_created()
+                    .start_destroying()
+                    .destroyed();
+
+                assert!(!created.into_any().may_have_infrastructure());
+                assert!(!destroyed.into_any().may_have_infrastructure());
+            }
+
+            #[test]
+            fn it_should_return_true_for_running_and_failed_states() {
+                let provisioned = super::create_test_environment_created()
+                    .start_provisioning()
+                    .provisioned(
+                        IpAddr::V4(Ipv4Addr::new(192, 168, 1, 100)),
+                        ProvisionMethod::Provisioned,
+                    );
+                let provision_failed = super::create_test_environment_created()
+                    .start_provisioning()
+                    .provision_failed(super::super::create_test_provision_context("error"));
+                let running = provisioned
+                    .start_configuring()
+                    .configured()
+                    .start_releasing()
+                    .released()
+                    .start_running();
+
+                assert!(running.into_any().may_have_infrastructure());
+                assert!(provision_failed.into_any().may_have_infrastructure());
+            }
+        }
+
         mod error_details {
             use std::net::{IpAddr, Ipv4Addr};
 
@@ -2396,5 +2901,174 @@ mod tests {
                 assert!(output.contains("created"));
             }
         }
+
+        mod allowed_commands {
+            use std::net::{IpAddr, Ipv4Addr};
+            use std::time::Duration;
+
+            use super::super::{
+                create_test_release_context, create_test_run_context, AnyEnvironmentState,
+                BaseFailureContext, DestroyFailureContext, DestroyStep, ProvisionMethod,
+            };
+            use crate::domain::environment::TraceId;
+            use crate::shared::ErrorKind;
+
+            /// One environment in each state, in the order of `STATE_NAMES`
+            fn every_state() -> Vec<AnyEnvironmentState> {
+                let created = super::create_test_environment_created();
+                let provisioning = created.clone().start_provisioning();
+                let provisioned = provisioning.clone().provisioned(
+                    IpAddr::V4(Ipv4Addr::new(192, 168, 1, 100)),
+                    ProvisionMethod::Provisioned,
+                );
+                let configuring = provisioned.clone().start_configuring();
+                let configured = configuring.clone().configured();
+                let releasing = configured.clone().start_releasing();
+                let released = releasing.clone().released();
+                let running = released.clone().start_running();
+                let destroying = created.clone().start_destroying();
+                let destroy_context = DestroyFailureContext {
+                    failed_step: DestroyStep::DestroyInfrastructure,
+                    error_kind: ErrorKind::InfrastructureOperation,
+                    base: BaseFailureContext {
+                        error_summary: "error".to_string(),
+                        failed_at: chrono::Utc::now(),
+                        execution_started_at: chrono::Utc::now(),
+                        execution_duration: Duration::from_secs(0),
+                        trace_id: TraceId::default(),
+                        trace_file_path: None,
+                    },
+                };
+
+                vec![
+                    created.into_any(),
+                    provisioning.clone().into_any(),
+                    provisioned.into_any(),
+                    configuring.clone().into_any(),
+                    configured.into_any(),
+                    releasing.clone().into_any(),
+                    released.into_any(),
+                    running.clone().into_any(),
+                    running.clone().stop().into_any(),
+                    destroying.clone().into_any(),
+                    provisioning
+                        .provision_failed(super::create_test_provision_context("error"))
+                        .into_any(),
+                    configuring
+                        .configure_failed(super::create_test_configure_context("error"))
+                        .into_any(),
+                    releasing
+                        .release_failed(create_test_release_context("error"))
+                        .into_any(),
+                    running
+                        .run_failed(create_test_run_context("error"))
+                        .into_any(),
+                    destroying
+                        .clone()
+                        .destroy_failed(destroy_context)
+                        .into_any(),
+                    destroying.destroyed().into_any(),
+                ]
+            }
+
+            #[test]
+            fn it_should_build_an_environment_in_every_state() {
+                let names: Vec<&str> = every_state()
+                    .iter()
+                    .map(AnyEnvironmentState::state_name)
+                    .collect();
+
+                assert_eq!(names, AnyEnvironmentState::STATE_NAMES);
+            }
+
+            #[test]
+            fn it_should_follow_the_allowed_commands_matrix_in_every_state() {
+                let matrix: [(&str, &str, Option<&str>); 16] = [
+                    (
+                        "created",
+                        "provision, register, destroy, purge",
+                        Some("provision"),
+                    ),
+                    ("provisioning", "destroy, purge", None),
+                    (
+                        "provisioned",
+                        "configure, destroy, purge",
+                        Some("configure"),
+                    ),
+                    ("configuring", "destroy, purge", None),
+                    ("configured", "release, destroy, purge", Some("release")),
+                    ("releasing", "destroy, purge", None),
+                    (
+                        "released",
+                        "run, rollback, backup, restore, drift, destroy, purge",
+                        Some("run"),
+                    ),
+                    (
+                        "running",
+                        "test, stop, rollback, backup, restore, drift, destroy, purge",
+                        Some("test"),
+                    ),
+                    ("stopped", "start, destroy, purge", Some("start")),
+                    ("destroying", "destroy, purge", Some("destroy")),
+                    ("provision_failed", "destroy, purge", Some("destroy")),
+                    ("configure_failed", "destroy, purge", Some("destroy")),
+                    ("release_failed", "destroy, purge", Some("destroy")),
+                    ("run_failed", "destroy, purge", Some("destroy")),
+                    ("destroy_failed", "destroy, purge", Some("destroy")),
+                    ("destroyed", "purge", Some("purge")),
+                ];
+
+                for (environment, (state, allowed, next)) in every_state().iter().zip(matrix) {
+                    let commands: Vec<String> = environment
+                        .allowed_commands()
+                        .iter()
+                        .map(ToString::to_string)
+                        .collect();
+
+                    assert_eq!(environment.state_name(), state);
+                    assert_eq!(commands.join(", "), allowed, "allowed in '{state}'");
+                    assert_eq!(
+                        environment.suggested_next_command().map(|c| c.name()),
+                        next,
+                        "suggested in '{state}'"
+                    );
+                }
+            }
+
+            #[test]
+            fn it_should_only_suggest_an_allowed_command() {
+                for environment in every_state() {
+                    if let Some(next) = environment.suggested_next_command() {
+                        assert!(
+                            environment.allowed_commands().contains(&next),
+                            "'{next}' is not allowed in '{}'",
+                            environment.state_name()
+                        );
+                    }
+                }
+            }
+
+            #[test]
+            fn it_should_carry_the_allowed_commands_in_a_state_type_error() {
+                let provisioned = super::create_test_environment_created()
+                    .start_provisioning()
+                    .provisioned(
+                        IpAddr::V4(Ipv4Addr::new(192, 168, 1, 100)),
+                        ProvisionMethod::Provisioned,
+                    )
+                    .into_any();
+
+                let error = provisioned.try_into_released().unwrap_err();
+
+                assert!(matches!(
+                    error,
+                    super::super::StateTypeError::UnexpectedState {
+                        expected: "released",
+                        suggested_next: Some(super::super::LifecycleCommand::Configure),
+                        ..
+                    }
+                ));
+            }
+        }
     }
 }
@@ -36,11 +36,22 @@ pub struct ProvisionFailureContext {
     /// Base failure context with common fields
     #[serde(flatten)]
     pub base: BaseFailureContext,
+
+    /// Excerpt of a remote log explaining the failure
+    ///
+    /// For example the tail of `/var/log/cloud-init-output.log` when the
+    /// instance did not finish cloud-init in time.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub remote_log_excerpt: Option<String>,
 }
 
 /// Steps in the provision workflow
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 pub enum ProvisionStep {
+    /// Checking the free disk space of the workspace and the LXD storage pool
+    DiskSpaceCheck,
+    /// Checking the LXD remote the instance is created on
+    LxdRemoteCheck,
     /// Rendering `OpenTofu` templates
     RenderOpenTofuTemplates,
     /// Initializing `OpenTofu`
@@ -55,10 +66,16 @@ pub enum ProvisionStep {
     GetInstanceInfo,
     /// Rendering Ansible templates with runtime data
     RenderAnsibleTemplates,
+    /// Checking that the SSH port accepts connections
+    SshConnectivity,
     /// Waiting for SSH connectivity
     WaitSshConnectivity,
     /// Waiting for cloud-init completion
     CloudInitWait,
+    /// Provisioning was interrupted by the user (Ctrl-C)
+    Interrupted,
+    /// Running the custom step with this name, registered by an SDK user
+    CustomStep(String),
 }
 
 /// Error state - Infrastructure provisioning failed
@@ -96,10 +113,7 @@ impl AnyEnvironmentState {
     pub fn try_into_provision_failed(self) -> Result<Environment<ProvisionFailed>, StateTypeError> {
         match self {
             Self::ProvisionFailed(env) => Ok(env),
-            other => Err(StateTypeError::UnexpectedState {
-                expected: "provision_failed",
-                actual: other.state_name().to_string(),
-            }),
+            other => Err(StateTypeError::unexpected_state("provision_failed", &other)),
         }
     }
 }
@@ -124,6 +138,7 @@ mod tests {
                 trace_id: TraceId::new(),
                 trace_file_path: None,
             },
+            remote_log_excerpt: None,
         }
     }
 
@@ -181,6 +196,7 @@ mod tests {
         fn default_lxd_provider_config(env_name: &EnvironmentName) -> ProviderConfig {
             ProviderConfig::Lxd(LxdConfig {
                 profile_name: ProfileName::new(format!("lxd-{}", env_name.as_str())).unwrap(),
+                remote: None,
             })
         }
 
@@ -268,6 +284,7 @@ mod tests {
                     trace_id: TraceId::new(),
                     trace_file_path: Some(PathBuf::from("/data/env/traces/trace.log")),
                 },
+                remote_log_excerpt: None,
             };
 
             let json = serde_json::to_string(&context).unwrap();
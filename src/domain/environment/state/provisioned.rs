@@ -53,10 +53,7 @@ impl AnyEnvironmentState {
     pub fn try_into_provisioned(self) -> Result<Environment<Provisioned>, StateTypeError> {
         match self {
             Self::Provisioned(env) => Ok(env),
-            other => Err(StateTypeError::UnexpectedState {
-                expected: "provisioned",
-                actual: other.state_name().to_string(),
-            }),
+            other => Err(StateTypeError::unexpected_state("provisioned", &other)),
         }
     }
 }
@@ -86,6 +83,7 @@ mod tests {
         fn default_lxd_provider_config(env_name: &EnvironmentName) -> ProviderConfig {
             ProviderConfig::Lxd(LxdConfig {
                 profile_name: ProfileName::new(format!("lxd-{}", env_name.as_str())).unwrap(),
+                remote: None,
             })
         }
 
@@ -133,6 +131,7 @@ mod tests {
                     trace_id: TraceId::default(),
                     trace_file_path: None,
                 },
+                remote_log_excerpt: None,
             }
         }
 
@@ -188,6 +187,7 @@ mod tests {
         fn default_lxd_provider_config(env_name: &EnvironmentName) -> ProviderConfig {
             ProviderConfig::Lxd(LxdConfig {
                 profile_name: ProfileName::new(format!("lxd-{}", env_name.as_str())).unwrap(),
+                remote: None,
             })
         }
 
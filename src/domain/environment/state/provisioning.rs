@@ -9,11 +9,9 @@
 //! - Success: `Provisioned`
 //! - Failure: `ProvisionFailed`
 
-use std::net::IpAddr;
-
 use serde::{Deserialize, Serialize};
 
-use crate::domain::environment::runtime_outputs::ProvisionMethod;
+use crate::domain::environment::runtime_outputs::{InstanceAddresses, ProvisionMethod};
 use crate::domain::environment::state::{
     AnyEnvironmentState, ProvisionFailed, Provisioned, StateTypeError,
 };
@@ -40,7 +38,7 @@ impl Environment<Provisioning> {
     ///
     /// # Arguments
     ///
-    /// * `instance_ip` - The IP address of the provisioned instance
+    /// * `addresses` - The IP address(es) of the provisioned instance
     /// * `provision_method` - How the instance was provisioned (always `Provisioned` for this transition)
     ///
     /// # Returns
@@ -49,10 +47,10 @@ impl Environment<Provisioning> {
     #[must_use]
     pub fn provisioned(
         self,
-        instance_ip: IpAddr,
+        addresses: impl Into<InstanceAddresses>,
         provision_method: ProvisionMethod,
     ) -> Environment<Provisioned> {
-        self.with_instance_ip(instance_ip)
+        self.with_instance_addresses(addresses)
             .with_provision_method(provision_method)
             .with_state(Provisioned)
     }
@@ -90,10 +88,7 @@ impl AnyEnvironmentState {
     pub fn try_into_provisioning(self) -> Result<Environment<Provisioning>, StateTypeError> {
         match self {
             Self::Provisioning(env) => Ok(env),
-            other => Err(StateTypeError::UnexpectedState {
-                expected: "provisioning",
-                actual: other.state_name().to_string(),
-            }),
+            other => Err(StateTypeError::unexpected_state("provisioning", &other)),
         }
     }
 }
@@ -120,6 +115,7 @@ mod tests {
         fn default_lxd_provider_config(env_name: &EnvironmentName) -> ProviderConfig {
             ProviderConfig::Lxd(LxdConfig {
                 profile_name: ProfileName::new(format!("lxd-{}", env_name.as_str())).unwrap(),
+                remote: None,
             })
         }
 
@@ -195,6 +191,7 @@ mod tests {
         fn default_lxd_provider_config(env_name: &EnvironmentName) -> ProviderConfig {
             ProviderConfig::Lxd(LxdConfig {
                 profile_name: ProfileName::new(format!("lxd-{}", env_name.as_str())).unwrap(),
+                remote: None,
             })
         }
 
@@ -254,6 +251,7 @@ mod tests {
                     trace_id: TraceId::new(),
                     trace_file_path: None,
                 },
+                remote_log_excerpt: None,
             };
             let env = env.provision_failed(context.clone());
 
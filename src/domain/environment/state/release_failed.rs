@@ -30,6 +30,8 @@ use crate::shared::error::ErrorKind;
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
 #[serde(rename_all = "snake_case")]
 pub enum ReleaseStep {
+    /// Checking the free disk space of the remote host before writing to it
+    CheckDiskSpace,
     /// Creating tracker storage directories on remote host
     CreateTrackerStorage,
     /// Initializing tracker `SQLite` database file
@@ -68,11 +70,16 @@ pub enum ReleaseStep {
     RenderDockerComposeTemplates,
     /// Deploying compose files to the remote host via Ansible
     DeployComposeFilesToRemote,
+    /// Pulling the container images on the remote host
+    PullContainerImages,
+    /// Verifying the digests of the images pinned by digest
+    VerifyImageDigests,
 }
 
 impl fmt::Display for ReleaseStep {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         let name = match self {
+            Self::CheckDiskSpace => "Check Disk Space",
             Self::CreateTrackerStorage => "Create Tracker Storage",
             Self::InitTrackerDatabase => "Initialize Tracker Database",
             Self::RenderTrackerTemplates => "Render Tracker Templates",
@@ -92,6 +99,8 @@ impl fmt::Display for ReleaseStep {
             Self::DeployCaddyConfigToRemote => "Deploy Caddy Config to Remote",
             Self::RenderDockerComposeTemplates => "Render Docker Compose Templates",
             Self::DeployComposeFilesToRemote => "Deploy Compose Files to Remote",
+            Self::PullContainerImages => "Pull Container Images",
+            Self::VerifyImageDigests => "Verify Image Digests",
         };
         write!(f, "{name}")
     }
@@ -153,10 +162,7 @@ impl AnyEnvironmentState {
     pub fn try_into_release_failed(self) -> Result<Environment<ReleaseFailed>, StateTypeError> {
         match self {
             Self::ReleaseFailed(env) => Ok(env),
-            other => Err(StateTypeError::UnexpectedState {
-                expected: "release_failed",
-                actual: other.state_name().to_string(),
-            }),
+            other => Err(StateTypeError::unexpected_state("release_failed", &other)),
         }
     }
 }
@@ -247,6 +253,7 @@ mod tests {
         fn default_lxd_provider_config(env_name: &EnvironmentName) -> ProviderConfig {
             ProviderConfig::Lxd(LxdConfig {
                 profile_name: ProfileName::new(format!("lxd-{}", env_name.as_str())).unwrap(),
+                remote: None,
             })
         }
 
@@ -6,12 +6,15 @@
 //!
 //! **Valid Transitions:**
 //! - `Running` (start application)
+//! - `Releasing` (rollback to the previous release)
 
+use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
 
-use crate::domain::environment::runtime_outputs::ServiceEndpoints;
-use crate::domain::environment::state::{AnyEnvironmentState, Running, StateTypeError};
+use crate::domain::environment::runtime_outputs::{ReleaseRecord, ServiceEndpoints};
+use crate::domain::environment::state::{AnyEnvironmentState, Releasing, Running, StateTypeError};
 use crate::domain::environment::Environment;
+use crate::domain::tracker::TrackerVersion;
 
 /// Final state - Release preparation completed successfully
 ///
@@ -19,6 +22,7 @@ use crate::domain::environment::Environment;
 ///
 /// **Valid Transitions:**
 /// - `Running` (start application)
+/// - `Releasing` (rollback to the previous release)
 #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 pub struct Released;
 
@@ -51,6 +55,36 @@ impl Environment<Released> {
     pub fn start_running(self) -> Environment<Running> {
         self.with_state(Running)
     }
+
+    /// Records the tracker version deployed by the release that just completed
+    #[must_use]
+    pub fn with_release_recorded(mut self, released_at: DateTime<Utc>) -> Self {
+        let version = self.context.user_inputs.tracker().effective_version();
+        self.context
+            .runtime_outputs
+            .record_release(ReleaseRecord::new(version, released_at));
+        self
+    }
+
+    /// Transitions from Released to Releasing to redeploy `version`
+    ///
+    /// The tracker configuration is pinned to `version` so the release
+    /// workflow renders and pulls that image.
+    #[must_use]
+    pub fn start_rollback(mut self, version: TrackerVersion) -> Environment<Releasing> {
+        self.context.user_inputs.set_tracker_version(version);
+        self.with_state(Releasing)
+    }
+
+    /// Removes the rolled back release from the history
+    ///
+    /// Called once a rollback completes, making the redeployed release the
+    /// current one again.
+    #[must_use]
+    pub fn rollback_completed(mut self) -> Self {
+        self.context.runtime_outputs.drop_latest_release();
+        self
+    }
 }
 
 // Type Erasure: Typed → Runtime conversion (into_any)
@@ -72,10 +106,7 @@ impl AnyEnvironmentState {
     pub fn try_into_released(self) -> Result<Environment<Released>, StateTypeError> {
         match self {
             Self::Released(env) => Ok(env),
-            other => Err(StateTypeError::UnexpectedState {
-                expected: "released",
-                actual: other.state_name().to_string(),
-            }),
+            other => Err(StateTypeError::unexpected_state("released", &other)),
         }
     }
 }
@@ -104,6 +135,7 @@ mod tests {
         fn default_lxd_provider_config(env_name: &EnvironmentName) -> ProviderConfig {
             ProviderConfig::Lxd(LxdConfig {
                 profile_name: ProfileName::new(format!("lxd-{}", env_name.as_str())).unwrap(),
+                remote: None,
             })
         }
 
@@ -168,6 +200,7 @@ mod tests {
         fn default_lxd_provider_config(env_name: &EnvironmentName) -> ProviderConfig {
             ProviderConfig::Lxd(LxdConfig {
                 profile_name: ProfileName::new(format!("lxd-{}", env_name.as_str())).unwrap(),
+                remote: None,
             })
         }
 
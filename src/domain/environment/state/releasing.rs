@@ -69,10 +69,7 @@ impl AnyEnvironmentState {
     pub fn try_into_releasing(self) -> Result<Environment<Releasing>, StateTypeError> {
         match self {
             Self::Releasing(env) => Ok(env),
-            other => Err(StateTypeError::UnexpectedState {
-                expected: "releasing",
-                actual: other.state_name().to_string(),
-            }),
+            other => Err(StateTypeError::unexpected_state("releasing", &other)),
         }
     }
 }
@@ -101,6 +98,7 @@ mod tests {
         fn default_lxd_provider_config(env_name: &EnvironmentName) -> ProviderConfig {
             ProviderConfig::Lxd(LxdConfig {
                 profile_name: ProfileName::new(format!("lxd-{}", env_name.as_str())).unwrap(),
+                remote: None,
             })
         }
 
@@ -170,6 +168,7 @@ mod tests {
         fn default_lxd_provider_config(env_name: &EnvironmentName) -> ProviderConfig {
             ProviderConfig::Lxd(LxdConfig {
                 profile_name: ProfileName::new(format!("lxd-{}", env_name.as_str())).unwrap(),
+                remote: None,
             })
         }
 
@@ -32,12 +32,15 @@ use crate::shared::error::ErrorKind;
 pub enum RunStep {
     /// Starting Docker Compose services on the remote host
     StartServices,
+    /// Waiting for the tracker Health Check API to report healthy
+    WaitForTrackerHealth,
 }
 
 impl fmt::Display for RunStep {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         let name = match self {
             Self::StartServices => "Start Services",
+            Self::WaitForTrackerHealth => "Wait For Tracker Health",
         };
         write!(f, "{name}")
     }
@@ -64,6 +67,11 @@ pub struct RunFailureContext {
 
     /// Common failure metadata (timing, trace, error summary)
     pub base: BaseFailureContext,
+
+    /// Last Health Check API response when the tracker never became healthy
+    /// (e.g. `HTTP 500`, or why no response was received)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub last_health_check: Option<String>,
 }
 
 /// Error state - Application runtime failed
@@ -99,10 +107,7 @@ impl AnyEnvironmentState {
     pub fn try_into_run_failed(self) -> Result<Environment<RunFailed>, StateTypeError> {
         match self {
             Self::RunFailed(env) => Ok(env),
-            other => Err(StateTypeError::UnexpectedState {
-                expected: "run_failed",
-                actual: other.state_name().to_string(),
-            }),
+            other => Err(StateTypeError::unexpected_state("run_failed", &other)),
         }
     }
 }
@@ -129,6 +134,7 @@ mod tests {
                 trace_id: TraceId::new(),
                 trace_file_path: None,
             },
+            last_health_check: None,
         }
     }
 
@@ -160,6 +166,17 @@ mod tests {
         assert_eq!(step, RunStep::StartServices);
     }
 
+    #[test]
+    fn it_should_omit_the_last_health_check_when_there_is_none() {
+        let context = create_test_failure_context();
+
+        let json = serde_json::to_value(&context).unwrap();
+        assert!(json.get("last_health_check").is_none());
+
+        let restored: RunFailureContext = serde_json::from_value(json).unwrap();
+        assert_eq!(restored, context);
+    }
+
     mod conversion_tests {
         use super::*;
         use crate::adapters::ssh::SshCredentials;
@@ -174,6 +191,7 @@ mod tests {
         fn default_lxd_provider_config(env_name: &EnvironmentName) -> ProviderConfig {
             ProviderConfig::Lxd(LxdConfig {
                 profile_name: ProfileName::new(format!("lxd-{}", env_name.as_str())).unwrap(),
+                remote: None,
             })
         }
 
@@ -201,6 +219,7 @@ mod tests {
                     trace_id: TraceId::new(),
                     trace_file_path: None,
                 },
+                last_health_check: None,
             };
             Environment::new(
                 name.clone(),
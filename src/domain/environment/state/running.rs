@@ -7,14 +7,17 @@
 //!
 //! **Valid Transitions:**
 //! - `RunFailed` (if runtime error occurs)
+//! - `Releasing` (rollback to the previous release)
+//! - `Stopped` (services stopped for maintenance)
 //! - `Destroyed` (when shutting down)
 
 use serde::{Deserialize, Serialize};
 
 use crate::domain::environment::state::{
-    AnyEnvironmentState, RunFailed, RunFailureContext, StateTypeError,
+    AnyEnvironmentState, Releasing, RunFailed, RunFailureContext, StateTypeError, Stopped,
 };
 use crate::domain::environment::Environment;
+use crate::domain::tracker::TrackerVersion;
 
 /// Final state - Application is running
 ///
@@ -23,6 +26,8 @@ use crate::domain::environment::Environment;
 ///
 /// **Valid Transitions:**
 /// - `RunFailed` (if runtime error occurs)
+/// - `Releasing` (rollback to the previous release)
+/// - `Stopped` (services stopped for maintenance)
 /// - `Destroyed` (when shutting down)
 #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 pub struct Running;
@@ -40,6 +45,24 @@ impl Environment<Running> {
     pub fn run_failed(self, context: RunFailureContext) -> Environment<RunFailed> {
         self.with_state(RunFailed { context })
     }
+
+    /// Transitions from Running to Releasing to redeploy `version`
+    ///
+    /// The service keeps running the current version until the release
+    /// workflow replaces it.
+    #[must_use]
+    pub fn start_rollback(mut self, version: TrackerVersion) -> Environment<Releasing> {
+        self.context.user_inputs.set_tracker_version(version);
+        self.with_state(Releasing)
+    }
+
+    /// Transitions from Running to Stopped
+    ///
+    /// Called once the services have been stopped on the instance.
+    #[must_use]
+    pub fn stop(self) -> Environment<Stopped> {
+        self.with_state(Stopped)
+    }
 }
 
 // Type Erasure: Typed → Runtime conversion (into_any)
@@ -61,10 +84,7 @@ impl AnyEnvironmentState {
     pub fn try_into_running(self) -> Result<Environment<Running>, StateTypeError> {
         match self {
             Self::Running(env) => Ok(env),
-            other => Err(StateTypeError::UnexpectedState {
-                expected: "running",
-                actual: other.state_name().to_string(),
-            }),
+            other => Err(StateTypeError::unexpected_state("running", &other)),
         }
     }
 }
@@ -93,6 +113,7 @@ mod tests {
         fn default_lxd_provider_config(env_name: &EnvironmentName) -> ProviderConfig {
             ProviderConfig::Lxd(LxdConfig {
                 profile_name: ProfileName::new(format!("lxd-{}", env_name.as_str())).unwrap(),
+                remote: None,
             })
         }
 
@@ -184,6 +205,7 @@ mod tests {
         fn default_lxd_provider_config(env_name: &EnvironmentName) -> ProviderConfig {
             ProviderConfig::Lxd(LxdConfig {
                 profile_name: ProfileName::new(format!("lxd-{}", env_name.as_str())).unwrap(),
+                remote: None,
             })
         }
 
@@ -227,6 +249,7 @@ mod tests {
                     trace_id: TraceId::new(),
                     trace_file_path: None,
                 },
+                last_health_check: None,
             }
         }
 
@@ -0,0 +1,118 @@
+//! Stopped State
+//!
+//! The tracker services were stopped for maintenance
+//!
+//! The instance and the deployed release are kept; only the Docker Compose
+//! services are stopped, so the tracker is offline until they are started
+//! again.
+//!
+//! **Valid Transitions:**
+//! - `Running` (start the services again)
+//! - `Destroyed` (when shutting down)
+
+use serde::{Deserialize, Serialize};
+
+use crate::domain::environment::state::{AnyEnvironmentState, Running, StateTypeError};
+use crate::domain::environment::Environment;
+
+/// The tracker services were stopped for maintenance
+///
+/// The instance and the deployed release are kept; only the Docker Compose
+/// services are stopped.
+///
+/// **Valid Transitions:**
+/// - `Running` (start the services again)
+/// - `Destroyed` (when shutting down)
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Stopped;
+
+// State transition implementations
+impl Environment<Stopped> {
+    /// Transitions from Stopped back to Running
+    ///
+    /// Called once the services are started again and the tracker reports
+    /// healthy. The service endpoints recorded by `run` are kept.
+    #[must_use]
+    pub fn start(self) -> Environment<Running> {
+        self.with_state(Running)
+    }
+}
+
+// Type Erasure: Typed → Runtime conversion (into_any)
+impl Environment<Stopped> {
+    /// Converts typed `Environment<Stopped>` into type-erased `AnyEnvironmentState`
+    #[must_use]
+    pub fn into_any(self) -> AnyEnvironmentState {
+        AnyEnvironmentState::Stopped(self)
+    }
+}
+
+// Type Restoration: Runtime → Typed conversion (try_into_stopped)
+impl AnyEnvironmentState {
+    /// Attempts to convert `AnyEnvironmentState` to `Environment<Stopped>`
+    ///
+    /// # Errors
+    ///
+    /// Returns `StateTypeError::UnexpectedState` if the environment is not in `Stopped` state.
+    pub fn try_into_stopped(self) -> Result<Environment<Stopped>, StateTypeError> {
+        match self {
+            Self::Stopped(env) => Ok(env),
+            other => Err(StateTypeError::unexpected_state("stopped", &other)),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::net::{IpAddr, Ipv4Addr};
+
+    use super::*;
+    use crate::domain::environment::runtime_outputs::ProvisionMethod;
+    use crate::domain::environment::state::Destroyed;
+    use crate::domain::environment::testing::EnvironmentTestBuilder;
+
+    fn create_test_environment() -> Environment<Stopped> {
+        let (env, _data_dir, _build_dir, _temp_dir) = EnvironmentTestBuilder::new()
+            .with_name("test-state")
+            .build_with_custom_paths();
+
+        env.start_provisioning()
+            .provisioned(
+                IpAddr::V4(Ipv4Addr::new(192, 168, 1, 100)),
+                ProvisionMethod::Provisioned,
+            )
+            .start_configuring()
+            .configured()
+            .start_releasing()
+            .released()
+            .start_running()
+            .stop()
+    }
+
+    #[test]
+    fn it_should_transition_from_running_to_stopped_and_back() {
+        let env = create_test_environment();
+        assert_eq!(*env.state(), Stopped);
+
+        let env = env.start();
+
+        assert_eq!(*env.state(), Running);
+        assert_eq!(env.name().as_str(), "test-state");
+    }
+
+    #[test]
+    fn it_should_transition_to_destroyed_from_stopped() {
+        let env = create_test_environment().destroy();
+
+        assert_eq!(*env.state(), Destroyed);
+    }
+
+    #[test]
+    fn it_should_convert_stopped_environment_through_any() {
+        let any_env = create_test_environment().into_any();
+
+        assert_eq!(any_env.state_name(), "stopped");
+        assert!(any_env.clone().try_into_stopped().is_ok());
+        assert!(any_env.try_into_running().is_err());
+    }
+}
@@ -50,6 +50,7 @@ pub struct EnvironmentTestBuilder {
     temp_dir: TempDir,
     prometheus_config: Option<PrometheusConfig>,
     backup_config: Option<BackupConfig>,
+    tracker_config: TrackerConfig,
 }
 
 impl EnvironmentTestBuilder {
@@ -67,6 +68,7 @@ impl EnvironmentTestBuilder {
             temp_dir: TempDir::new().expect("Failed to create temp directory"),
             prometheus_config: Some(PrometheusConfig::default()),
             backup_config: None,
+            tracker_config: TrackerConfig::default(),
         }
     }
 
@@ -105,6 +107,13 @@ impl EnvironmentTestBuilder {
         self
     }
 
+    /// Sets the Tracker configuration
+    #[must_use]
+    pub fn with_tracker_config(mut self, config: TrackerConfig) -> Self {
+        self.tracker_config = config;
+        self
+    }
+
     /// Builds an Environment with custom paths inside a temporary directory
     ///
     /// This is the recommended way to create test environments as it ensures
@@ -156,14 +165,17 @@ impl EnvironmentTestBuilder {
         );
 
         let profile_name = ProfileName::new(format!("lxd-{}", env_name.as_str())).unwrap();
-        let provider_config = ProviderConfig::Lxd(LxdConfig { profile_name });
+        let provider_config = ProviderConfig::Lxd(LxdConfig {
+            profile_name,
+            remote: None,
+        });
 
         let user_inputs = UserInputs::with_tracker(
             &env_name,
             provider_config,
             ssh_credentials,
             22,
-            TrackerConfig::default(),
+            self.tracker_config,
             self.prometheus_config.clone(),
             // Grafana is only enabled when Prometheus is enabled (cross-service invariant)
             self.prometheus_config
@@ -176,6 +188,7 @@ impl EnvironmentTestBuilder {
 
         let context = EnvironmentContext {
             created_at: test_timestamp(),
+            config_revision: INITIAL_CONFIG_REVISION,
             user_inputs,
             internal_config: InternalConfig {
                 data_dir: data_dir.clone(),
@@ -0,0 +1,162 @@
+//! Trace file settings of an environment
+//!
+//! The commands write a trace file (and its structured JSON report) for every
+//! failure. By default they go to `data/{env}/traces/`; an environment can
+//! write them elsewhere, e.g. to a CI artifacts directory. Only the most
+//! recent traces are kept: older ones are removed after each command.
+
+use std::path::{Path, PathBuf};
+
+use chrono::Duration;
+use serde::{Deserialize, Serialize};
+use thiserror::Error;
+
+/// Default number of traces kept in the traces directory
+pub const DEFAULT_KEPT_TRACES: usize = 20;
+
+/// Errors that can occur when creating a `TracesConfig`
+#[derive(Debug, Clone, Error, PartialEq, Eq)]
+pub enum TracesConfigError {
+    /// At least the trace of the last failure must be kept
+    #[error("The number of kept traces must be at least 1 (got 0)")]
+    ZeroKeptTraces,
+
+    /// The maximum age of the kept traces must be at least one day
+    #[error("The maximum age of the kept traces must be at least 1 day (got 0)")]
+    ZeroMaxAge,
+}
+
+/// Where the trace files of an environment go, and how many are kept
+///
+/// Stored with the environment. The directory can still be overridden for
+/// a single invocation (`--trace-dir`).
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct TracesConfig {
+    /// Directory of the trace files, `None` for `data/{env}/traces/`
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        with = "crate::shared::portable_path::option"
+    )]
+    dir: Option<PathBuf>,
+
+    /// Number of most recent traces kept
+    #[serde(default = "default_kept_traces")]
+    keep_last: usize,
+
+    /// Maximum age of the kept traces in days, `None` for no limit
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    max_age_days: Option<u32>,
+}
+
+fn default_kept_traces() -> usize {
+    DEFAULT_KEPT_TRACES
+}
+
+impl TracesConfig {
+    /// Creates the retention policy of the traces, in the default directory
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if `keep_last` is 0 or `max_age_days` is `Some(0)`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use torrust_tracker_deployer_lib::domain::environment::TracesConfig;
+    ///
+    /// let config = TracesConfig::new(5, Some(30))?;
+    /// assert_eq!(config.keep_last(), 5);
+    /// assert_eq!(config.max_age().map(|age| age.num_days()), Some(30));
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn new(keep_last: usize, max_age_days: Option<u32>) -> Result<Self, TracesConfigError> {
+        if keep_last == 0 {
+            return Err(TracesConfigError::ZeroKeptTraces);
+        }
+        if max_age_days == Some(0) {
+            return Err(TracesConfigError::ZeroMaxAge);
+        }
+
+        Ok(Self {
+            dir: None,
+            keep_last,
+            max_age_days,
+        })
+    }
+
+    /// Writes the traces to `dir` instead of `data/{env}/traces/`
+    #[must_use]
+    pub fn with_dir(mut self, dir: impl Into<PathBuf>) -> Self {
+        self.dir = Some(dir.into());
+        self
+    }
+
+    /// Returns the configured traces directory, `None` for the default one
+    #[must_use]
+    pub fn dir(&self) -> Option<&Path> {
+        self.dir.as_deref()
+    }
+
+    /// Returns the number of most recent traces kept
+    #[must_use]
+    pub const fn keep_last(&self) -> usize {
+        self.keep_last
+    }
+
+    /// Returns the maximum age of the kept traces, `None` for no limit
+    #[must_use]
+    pub fn max_age(&self) -> Option<Duration> {
+        self.max_age_days
+            .map(|days| Duration::days(i64::from(days)))
+    }
+}
+
+impl Default for TracesConfig {
+    /// Default policy: the last 20 traces in `data/{env}/traces/`, whatever their age
+    fn default() -> Self {
+        Self {
+            dir: None,
+            keep_last: DEFAULT_KEPT_TRACES,
+            max_age_days: None,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn it_should_keep_the_last_twenty_traces_by_default() {
+        let config = TracesConfig::default();
+
+        assert_eq!(config.keep_last(), 20);
+        assert_eq!(config.max_age(), None);
+        assert_eq!(config.dir(), None);
+    }
+
+    #[test]
+    fn it_should_load_state_saved_with_only_a_directory() {
+        let config: TracesConfig = serde_json::from_str(r#"{"dir":"/ci/traces"}"#).unwrap();
+
+        assert_eq!(config.dir(), Some(Path::new("/ci/traces")));
+        assert_eq!(config.keep_last(), DEFAULT_KEPT_TRACES);
+    }
+
+    #[test]
+    fn it_should_reject_keeping_no_trace() {
+        assert_eq!(
+            TracesConfig::new(0, None),
+            Err(TracesConfigError::ZeroKeptTraces)
+        );
+    }
+
+    #[test]
+    fn it_should_reject_a_zero_max_age() {
+        assert_eq!(
+            TracesConfig::new(10, Some(0)),
+            Err(TracesConfigError::ZeroMaxAge)
+        );
+    }
+}
@@ -18,18 +18,37 @@
 //!
 //! Add new fields here when: User needs to configure something at environment creation time.
 
+use std::path::{Path, PathBuf};
+
 use serde::{Deserialize, Serialize};
 use thiserror::Error;
 
 use crate::adapters::ssh::SshCredentials;
 use crate::domain::backup::BackupConfig;
-use crate::domain::environment::EnvironmentName;
+use crate::domain::caddy::CaddyConfig;
+use crate::domain::environment::config_diff::{ChangeImpact, ConfigDiff};
+use crate::domain::environment::{
+    EnvironmentName, EnvironmentParams, EnvironmentSecrets, Labels, TracesConfig,
+};
+use crate::domain::firewall::{FirewallConfig, FirewallRule};
 use crate::domain::grafana::GrafanaConfig;
 use crate::domain::https::HttpsConfig;
+use crate::domain::mysql::MysqlServiceConfig;
 use crate::domain::prometheus::PrometheusConfig;
-use crate::domain::provider::{Provider, ProviderConfig};
-use crate::domain::tracker::TrackerConfig;
-use crate::domain::InstanceName;
+use crate::domain::provider::{LxdRemote, Provider, ProviderConfig};
+use crate::domain::provision::{
+    CloudInitCustomization, InstanceResources, OfflineMode, ProvisionConfig, ProxyConfig,
+};
+use crate::domain::release::{ImageReference, ReleaseConfig};
+use crate::domain::system_tuning::SystemTuningConfig;
+use crate::domain::template::ExtraTemplateVariables;
+use crate::domain::topology::{
+    DockerComposeTopology, PortBinding, PortConflict, PortDerivation, Service, ServiceTopology,
+};
+use crate::domain::tracker::{
+    DatabaseConfig, IssuedAuthKey, Protocol, TrackerConfig, TrackerVersion,
+};
+use crate::domain::{InstanceName, ProfileName};
 
 /// Errors for user inputs validation
 ///
@@ -63,6 +82,38 @@ Tip: Set 'use_tls_proxy: true' on at least one service, or remove the 'https' se
 Tip: Add an 'https' section with 'admin_email' for Let's Encrypt certificate management"
     )]
     TlsServicesWithoutHttpsSection,
+
+    /// Two enabled services publish the same host port and protocol
+    ///
+    /// Use `.help()` for detailed troubleshooting steps.
+    #[error("{0}")]
+    PortConflict(Box<PortConflict>),
+
+    /// The SSH port is also published by a service deployed on the instance
+    ///
+    /// Use `.help()` for detailed troubleshooting steps.
+    #[error(
+        "SSH port {ssh_port} is also used by the {service} service ({description})
+Tip: Choose another 'ssh_credentials.port', or change the port of the service"
+    )]
+    SshPortConflict {
+        ssh_port: u16,
+        service: Service,
+        description: &'static str,
+    },
+
+    /// The SSH port is used by the provider itself
+    ///
+    /// Use `.help()` for detailed troubleshooting steps.
+    #[error(
+        "SSH port {ssh_port} is reserved by the {provider} provider ({usage})
+Tip: Choose another 'ssh_credentials.port'"
+    )]
+    SshPortReserved {
+        ssh_port: u16,
+        provider: Provider,
+        usage: &'static str,
+    },
 }
 
 impl UserInputsError {
@@ -76,12 +127,27 @@ impl UserInputsError {
             }
             Self::HttpsSectionWithoutTlsServices => {
                 "Either remove the 'https' section, or set 'use_tls_proxy: true' on at least one \
-                service (http_api, http_trackers, or health_check_api)."
+                service (http_api, http_trackers, health_check_api, or grafana)."
             }
             Self::TlsServicesWithoutHttpsSection => {
                 "Add an 'https' section with 'admin_email' for Let's Encrypt certificate management. \
                 Services with 'use_tls_proxy: true' require Caddy for TLS termination."
             }
+            Self::PortConflict(_) => {
+                "Change the port of the tracker service named in the error. Prometheus (9090), \
+                Grafana (3000, without TLS) and Caddy (80, 443, with the 'https' section) use fixed \
+                host ports. UDP and TCP services can share the same port number."
+            }
+            Self::SshPortConflict { .. } => {
+                "Choose another 'ssh_credentials.port', or change the port of the service named \
+                in the error. SSH listens on every address of the instance, so it cannot share a \
+                TCP port with a published service, not even one bound to localhost."
+            }
+            Self::SshPortReserved { .. } => {
+                "Choose another 'ssh_credentials.port'. The provider uses this port on the \
+                instance's addresses, so the instance would be unreachable over SSH once \
+                cloud-init moves SSH to it."
+            }
         }
     }
 }
@@ -98,6 +164,8 @@ impl UserInputsError {
 /// - **Grafana requires Prometheus**: If Grafana is enabled, Prometheus must also be enabled
 /// - **HTTPS requires TLS services**: If HTTPS section is present, at least one service must have TLS
 /// - **TLS requires HTTPS**: If any service has TLS, HTTPS section must be present
+/// - **Unique host ports**: No two enabled services publish the same host port and protocol
+/// - **Free SSH port**: The SSH port is neither published by a service nor reserved by the provider
 ///
 /// # Examples
 ///
@@ -114,6 +182,7 @@ impl UserInputsError {
 ///
 /// let provider_config = ProviderConfig::Lxd(LxdConfig {
 ///     profile_name: ProfileName::new("torrust-profile-production".to_string())?,
+///     remote: None,
 /// });
 /// let ssh_credentials = SshCredentials::new(
 ///     PathBuf::from("keys/prod_rsa"),
@@ -178,6 +247,100 @@ pub struct UserInputs {
     /// When absent (`None`), backup service is disabled.
     /// Default: `None` in generated templates.
     backup: Option<BackupConfig>,
+
+    /// Directory with user-provided template overrides (optional)
+    ///
+    /// When present, templates found under this directory replace the
+    /// embedded ones with the same relative path (e.g.
+    /// `tofu/common/cloud-init.yml.tera`).
+    /// When absent (`None`), only the embedded templates are used.
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        with = "crate::shared::portable_path::option"
+    )]
+    templates_override_dir: Option<PathBuf>,
+
+    /// User-defined variables added to the context of every template
+    ///
+    /// Defaults to no variables for environments persisted before the
+    /// setting existed.
+    #[serde(default, skip_serializing_if = "ExtraTemplateVariables::is_empty")]
+    extra_template_variables: ExtraTemplateVariables,
+
+    /// Provision workflow settings
+    ///
+    /// Defaults to `ProvisionConfig::default()` for environments persisted
+    /// before the setting existed.
+    #[serde(default)]
+    provision: ProvisionConfig,
+
+    /// Host firewall settings
+    ///
+    /// Defaults to no extra allow rules for environments persisted before
+    /// the setting existed.
+    #[serde(default)]
+    firewall: FirewallConfig,
+
+    /// Host kernel parameters, resource limits and swap file
+    ///
+    /// Defaults to no tuning for environments persisted before the setting
+    /// existed.
+    #[serde(default)]
+    system_tuning: SystemTuningConfig,
+
+    /// User additions to the cloud-init configuration
+    ///
+    /// Defaults to no additions for environments persisted before the
+    /// setting existed.
+    #[serde(default)]
+    cloud_init: CloudInitCustomization,
+
+    /// CPU, memory and disk of the instance
+    ///
+    /// Defaults to `InstanceResources::default()`, the sizing used before
+    /// the setting existed, for environments persisted without it.
+    #[serde(default)]
+    instance_resources: InstanceResources,
+
+    /// Pre-seeded artifacts replacing the downloads, when deploying offline
+    ///
+    /// Absent for environments persisted before the setting existed.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    offline: Option<OfflineMode>,
+
+    /// Outbound HTTP proxy the downloads go through, if any
+    ///
+    /// Absent for environments persisted before the setting existed.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    proxy: Option<ProxyConfig>,
+
+    /// Service image overrides and private registry of the release, if any
+    ///
+    /// Absent for environments persisted before the setting existed.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    release: Option<ReleaseConfig>,
+
+    /// Labels for organizing environments (e.g. `team=infra`)
+    ///
+    /// Defaults to no labels for environments persisted before the setting
+    /// existed.
+    #[serde(default, skip_serializing_if = "Labels::is_empty")]
+    labels: Labels,
+
+    /// Location and retention of the trace files
+    ///
+    /// Defaults to `TracesConfig::default()` for environments persisted
+    /// before the setting existed.
+    #[serde(default)]
+    traces: TracesConfig,
+
+    /// Keys minted on the tracker with the `keys add` command
+    ///
+    /// The keys are secrets: they are persisted with the other secrets of
+    /// the environment, not in its state.
+    #[serde(skip)]
+    tracker_auth_keys: Vec<IssuedAuthKey>,
 }
 
 impl UserInputs {
@@ -224,6 +387,7 @@ impl UserInputs {
     /// );
     /// let provider_config = ProviderConfig::Lxd(LxdConfig {
     ///     profile_name: ProfileName::new("torrust-profile-production".to_string())?,
+    ///     remote: None,
     /// });
     ///
     /// let user_inputs = UserInputs::new(&env_name, provider_config, ssh_credentials, 22)?;
@@ -276,6 +440,9 @@ impl UserInputs {
     /// - `GrafanaRequiresPrometheus` if Grafana is configured without Prometheus
     /// - `HttpsSectionWithoutTlsServices` if HTTPS section exists but no service uses TLS
     /// - `TlsServicesWithoutHttpsSection` if a service uses TLS but HTTPS section is missing
+    /// - `PortConflict` if two enabled services publish the same host port and protocol
+    /// - `SshPortConflict` if an enabled service publishes the SSH port over TCP
+    /// - `SshPortReserved` if the provider uses the SSH port
     #[allow(clippy::too_many_arguments)]
     pub fn with_tracker(
         name: &EnvironmentName,
@@ -288,13 +455,153 @@ impl UserInputs {
         https: Option<HttpsConfig>,
         backup: Option<BackupConfig>,
     ) -> Result<Self, UserInputsError> {
+        Self::check_cross_service_invariants(
+            &tracker,
+            prometheus.as_ref(),
+            grafana.as_ref(),
+            https.as_ref(),
+        )?;
+        Self::check_ssh_port(
+            ssh_port,
+            provider_config.provider(),
+            &tracker,
+            prometheus.as_ref(),
+            grafana.as_ref(),
+            https.as_ref(),
+        )?;
+
+        let instance_name = Self::generate_instance_name(name);
+
+        Ok(Self {
+            name: name.clone(),
+            instance_name,
+            provider_config,
+            ssh_credentials,
+            ssh_port,
+            tracker,
+            prometheus,
+            grafana,
+            https,
+            backup,
+            templates_override_dir: None,
+            extra_template_variables: ExtraTemplateVariables::default(),
+            provision: ProvisionConfig::default(),
+            firewall: FirewallConfig::default(),
+            system_tuning: SystemTuningConfig::default(),
+            cloud_init: CloudInitCustomization::default(),
+            instance_resources: InstanceResources::default(),
+            offline: None,
+            proxy: None,
+            release: None,
+            labels: Labels::default(),
+            traces: TracesConfig::default(),
+            tracker_auth_keys: Vec::new(),
+        })
+    }
+
+    /// Creates the user inputs from validated environment parameters
+    ///
+    /// # Errors
+    ///
+    /// Returns `UserInputsError` if the cross-service invariants are violated.
+    pub fn from_params(params: EnvironmentParams) -> Result<Self, UserInputsError> {
+        Ok(Self::with_tracker(
+            &params.environment_name,
+            params.provider_config,
+            params.ssh_credentials,
+            params.ssh_port,
+            params.tracker_config,
+            params.prometheus_config,
+            params.grafana_config,
+            params.https_config,
+            params.backup_config,
+        )?
+        .with_instance_name(params.instance_name)
+        .with_templates_override_dir(params.templates_override_dir)
+        .with_extra_template_variables(params.extra_template_variables)
+        .with_provision_config(params.provision_config)
+        .with_firewall_config(params.firewall_config)
+        .with_system_tuning(params.system_tuning)
+        .with_cloud_init(params.cloud_init)
+        .with_instance_resources(params.instance_resources)
+        .with_offline(params.offline)
+        .with_proxy(params.proxy)
+        .with_release(params.release)
+        .with_labels(params.labels)
+        .with_traces(params.traces))
+    }
+
+    /// Returns the parameters these user inputs were created from
+    ///
+    /// The inverse of [`Self::from_params`], used to derive new user inputs
+    /// from the persisted ones (e.g. when applying a configuration override).
+    /// The keys minted on the tracker are not parameters and are left out.
+    #[must_use]
+    pub fn to_params(&self) -> EnvironmentParams {
+        EnvironmentParams::new(
+            self.name.clone(),
+            self.instance_name.clone(),
+            self.provider_config.clone(),
+            self.ssh_credentials.clone(),
+            self.ssh_port,
+            self.tracker.clone(),
+            self.prometheus.clone(),
+            self.grafana.clone(),
+            self.https.clone(),
+            self.backup.clone(),
+        )
+        .with_templates_override_dir(self.templates_override_dir.clone())
+        .with_extra_template_variables(self.extra_template_variables.clone())
+        .with_provision_config(self.provision)
+        .with_firewall_config(self.firewall.clone())
+        .with_system_tuning(self.system_tuning.clone())
+        .with_cloud_init(self.cloud_init.clone())
+        .with_instance_resources(self.instance_resources)
+        .with_offline(self.offline.clone())
+        .with_proxy(self.proxy.clone())
+        .with_release(self.release.clone())
+        .with_labels(self.labels.clone())
+        .with_traces(self.traces.clone())
+    }
+
+    /// Returns whether any service is served through the Caddy TLS proxy
+    ///
+    /// Covers the tracker services (HTTP API, HTTP trackers, health check API)
+    /// and Grafana. When true, the HTTPS section is present and Caddy is
+    /// deployed in front of those services.
+    #[must_use]
+    pub fn has_any_tls_configured(&self) -> bool {
+        Self::any_tls_configured(&self.tracker, self.grafana.as_ref())
+    }
+
+    /// Whether the tracker or Grafana uses the TLS proxy
+    fn any_tls_configured(tracker: &TrackerConfig, grafana: Option<&GrafanaConfig>) -> bool {
+        tracker.has_any_tls_configured() || grafana.is_some_and(GrafanaConfig::use_tls_proxy)
+    }
+
+    /// Checks the invariants that span more than one service configuration
+    ///
+    /// Grafana requires Prometheus, the HTTPS section (which carries the ACME
+    /// email) is present if and only if some service uses the TLS proxy, and
+    /// no host port is published twice. Used at construction time and by the
+    /// `validate` command, which does not build the user inputs.
+    ///
+    /// # Errors
+    ///
+    /// Returns the first violated invariant as a `UserInputsError`.
+    pub fn check_cross_service_invariants(
+        tracker: &TrackerConfig,
+        prometheus: Option<&PrometheusConfig>,
+        grafana: Option<&GrafanaConfig>,
+        https: Option<&HttpsConfig>,
+    ) -> Result<(), UserInputsError> {
         // Cross-service invariant: Grafana requires Prometheus as data source
         if grafana.is_some() && prometheus.is_none() {
             return Err(UserInputsError::GrafanaRequiresPrometheus);
         }
 
         // Cross-service invariant: HTTPS section requires at least one TLS service
-        let has_tls = tracker.has_any_tls_configured();
+        let has_tls = Self::any_tls_configured(tracker, grafana);
         if https.is_some() && !has_tls {
             return Err(UserInputsError::HttpsSectionWithoutTlsServices);
         }
@@ -304,20 +611,214 @@ impl UserInputs {
             return Err(UserInputsError::TlsServicesWithoutHttpsSection);
         }
 
-        let instance_name = Self::generate_instance_name(name);
+        // Cross-service invariant: each host port and protocol is published once
+        Self::check_port_conflicts(tracker, prometheus, grafana, https)
+    }
 
-        Ok(Self {
-            name: name.clone(),
-            instance_name,
-            provider_config,
-            ssh_credentials,
-            ssh_port,
-            tracker,
-            prometheus,
-            grafana,
-            https,
-            backup,
-        })
+    /// Checks that the SSH port is free on the instance
+    ///
+    /// SSH listens on every address of the instance, so no enabled service
+    /// may publish the port over TCP, and the provider must not use it. Used
+    /// at construction time and by the `validate` command, like
+    /// [`Self::check_cross_service_invariants`].
+    ///
+    /// # Errors
+    ///
+    /// - `SshPortReserved` if the provider uses the SSH port
+    /// - `SshPortConflict` if an enabled service publishes the SSH port over TCP
+    pub fn check_ssh_port(
+        ssh_port: u16,
+        provider: Provider,
+        tracker: &TrackerConfig,
+        prometheus: Option<&PrometheusConfig>,
+        grafana: Option<&GrafanaConfig>,
+        https: Option<&HttpsConfig>,
+    ) -> Result<(), UserInputsError> {
+        if let Some((_, usage)) = provider
+            .reserved_ports()
+            .iter()
+            .find(|(port, _)| *port == ssh_port)
+        {
+            return Err(UserInputsError::SshPortReserved {
+                ssh_port,
+                provider,
+                usage,
+            });
+        }
+
+        for service in Self::enabled_services(tracker, prometheus, grafana, https) {
+            if let Some(binding) = service.ports().iter().find(|binding| {
+                binding.protocol() == Protocol::Tcp && binding.host_port() == ssh_port
+            }) {
+                return Err(UserInputsError::SshPortConflict {
+                    ssh_port,
+                    service: service.service(),
+                    description: binding.description(),
+                });
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Checks that no two enabled services publish the same host port and protocol
+    ///
+    /// Runs the topology-level validation over the ports of the enabled
+    /// services.
+    fn check_port_conflicts(
+        tracker: &TrackerConfig,
+        prometheus: Option<&PrometheusConfig>,
+        grafana: Option<&GrafanaConfig>,
+        https: Option<&HttpsConfig>,
+    ) -> Result<(), UserInputsError> {
+        DockerComposeTopology::validate_port_uniqueness(&Self::enabled_services(
+            tracker, prometheus, grafana, https,
+        ))
+        .map_err(|conflict| UserInputsError::PortConflict(Box::new(conflict)))
+    }
+
+    /// Collects the ports derived by every enabled service
+    ///
+    /// Each service derives its ports through [`PortDerivation`]. Caddy is
+    /// enabled by the HTTPS section and `MySQL` by the tracker database
+    /// driver.
+    fn enabled_services(
+        tracker: &TrackerConfig,
+        prometheus: Option<&PrometheusConfig>,
+        grafana: Option<&GrafanaConfig>,
+        https: Option<&HttpsConfig>,
+    ) -> Vec<ServiceTopology> {
+        let mut services = vec![ServiceTopology::new(
+            Service::Tracker,
+            vec![],
+            tracker.derive_ports(),
+        )];
+
+        if let Some(prometheus) = prometheus {
+            services.push(ServiceTopology::new(
+                Service::Prometheus,
+                vec![],
+                prometheus.derive_ports(),
+            ));
+        }
+        if let Some(grafana) = grafana {
+            services.push(ServiceTopology::new(
+                Service::Grafana,
+                vec![],
+                grafana.derive_ports(),
+            ));
+        }
+        if tracker.uses_mysql() {
+            services.push(ServiceTopology::new(
+                Service::MySQL,
+                vec![],
+                MysqlServiceConfig::new().derive_ports(),
+            ));
+        }
+        if https.is_some() {
+            services.push(ServiceTopology::new(
+                Service::Caddy,
+                vec![],
+                CaddyConfig::new().derive_ports(),
+            ));
+        }
+
+        services
+    }
+
+    /// Sets the directory with user-provided template overrides
+    #[must_use]
+    pub fn with_templates_override_dir(mut self, templates_override_dir: Option<PathBuf>) -> Self {
+        self.templates_override_dir = templates_override_dir;
+        self
+    }
+
+    /// Sets the user-defined variables added to every template context
+    #[must_use]
+    pub fn with_extra_template_variables(
+        mut self,
+        extra_template_variables: ExtraTemplateVariables,
+    ) -> Self {
+        self.extra_template_variables = extra_template_variables;
+        self
+    }
+
+    /// Sets the provision workflow settings
+    #[must_use]
+    pub fn with_provision_config(mut self, provision: ProvisionConfig) -> Self {
+        self.provision = provision;
+        self
+    }
+
+    /// Sets the host firewall settings
+    #[must_use]
+    pub fn with_firewall_config(mut self, firewall: FirewallConfig) -> Self {
+        self.firewall = firewall;
+        self
+    }
+
+    /// Sets the host kernel parameters, resource limits and swap file
+    #[must_use]
+    pub fn with_system_tuning(mut self, system_tuning: SystemTuningConfig) -> Self {
+        self.system_tuning = system_tuning;
+        self
+    }
+
+    /// Sets the user additions to the cloud-init configuration
+    #[must_use]
+    pub fn with_cloud_init(mut self, cloud_init: CloudInitCustomization) -> Self {
+        self.cloud_init = cloud_init;
+        self
+    }
+
+    /// Sets the instance name, replacing the one generated from the
+    /// environment name
+    #[must_use]
+    pub fn with_instance_name(mut self, instance_name: InstanceName) -> Self {
+        self.instance_name = instance_name;
+        self
+    }
+
+    /// Sets the labels of the environment
+    #[must_use]
+    pub fn with_labels(mut self, labels: Labels) -> Self {
+        self.labels = labels;
+        self
+    }
+
+    /// Sets the location and retention of the trace files
+    #[must_use]
+    pub fn with_traces(mut self, traces: TracesConfig) -> Self {
+        self.traces = traces;
+        self
+    }
+
+    /// Sets the CPU, memory and disk of the instance
+    #[must_use]
+    pub fn with_instance_resources(mut self, instance_resources: InstanceResources) -> Self {
+        self.instance_resources = instance_resources;
+        self
+    }
+
+    /// Sets the pre-seeded artifacts used to deploy offline
+    #[must_use]
+    pub fn with_offline(mut self, offline: Option<OfflineMode>) -> Self {
+        self.offline = offline;
+        self
+    }
+
+    /// Sets the outbound HTTP proxy the downloads go through
+    #[must_use]
+    pub fn with_proxy(mut self, proxy: Option<ProxyConfig>) -> Self {
+        self.proxy = proxy;
+        self
+    }
+
+    /// Sets the service image overrides and private registry of the release
+    #[must_use]
+    pub fn with_release(mut self, release: Option<ReleaseConfig>) -> Self {
+        self.release = release;
+        self
     }
 
     // ========================================================================
@@ -354,6 +855,13 @@ impl UserInputs {
         &self.tracker
     }
 
+    /// Pins the tracker image to `version`
+    ///
+    /// Used by rollback to redeploy a previously released version.
+    pub(crate) fn set_tracker_version(&mut self, version: TrackerVersion) {
+        self.tracker = self.tracker.clone().with_version(version);
+    }
+
     /// Returns the Prometheus configuration if enabled
     #[must_use]
     pub fn prometheus(&self) -> Option<&PrometheusConfig> {
@@ -366,6 +874,254 @@ impl UserInputs {
         self.grafana.as_ref()
     }
 
+    /// Returns the template override directory if configured
+    #[must_use]
+    pub fn templates_override_dir(&self) -> Option<&Path> {
+        self.templates_override_dir.as_deref()
+    }
+
+    /// Returns the user-defined variables added to every template context
+    #[must_use]
+    pub fn extra_template_variables(&self) -> &ExtraTemplateVariables {
+        &self.extra_template_variables
+    }
+
+    /// Returns the provision workflow settings
+    #[must_use]
+    pub fn provision(&self) -> &ProvisionConfig {
+        &self.provision
+    }
+
+    /// Returns the host firewall settings
+    #[must_use]
+    pub fn firewall(&self) -> &FirewallConfig {
+        &self.firewall
+    }
+
+    /// Returns the host kernel parameters, resource limits and swap file
+    #[must_use]
+    pub fn system_tuning(&self) -> &SystemTuningConfig {
+        &self.system_tuning
+    }
+
+    /// Returns the user additions to the cloud-init configuration
+    #[must_use]
+    pub fn cloud_init(&self) -> &CloudInitCustomization {
+        &self.cloud_init
+    }
+
+    /// Returns the pre-seeded artifacts used to deploy offline, if any
+    #[must_use]
+    pub fn offline(&self) -> Option<&OfflineMode> {
+        self.offline.as_ref()
+    }
+
+    /// Returns the outbound HTTP proxy the downloads go through, if any
+    #[must_use]
+    pub fn proxy(&self) -> Option<&ProxyConfig> {
+        self.proxy.as_ref()
+    }
+
+    /// Returns the proxy environment variables of the `OpenTofu` and Ansible processes
+    ///
+    /// Empty when the environment has no proxy.
+    #[must_use]
+    pub fn proxy_env_vars(&self) -> Vec<(String, String)> {
+        self.proxy
+            .as_ref()
+            .map(ProxyConfig::env_vars)
+            .unwrap_or_default()
+    }
+
+    /// Returns the service image overrides and private registry of the release, if any
+    #[must_use]
+    pub fn release(&self) -> Option<&ReleaseConfig> {
+        self.release.as_ref()
+    }
+
+    /// Returns the images pinned by digest of the enabled services, by service name
+    ///
+    /// Covers the image overrides with a digest and the tracker image when its
+    /// version is a digest. Overrides of disabled services are left out, as
+    /// their images are never pulled.
+    #[must_use]
+    pub fn pinned_images(&self) -> Vec<(String, ImageReference)> {
+        let tracker_digest = self
+            .tracker
+            .version()
+            .filter(|version| version.is_digest())
+            .and_then(|_| ImageReference::new(self.tracker.image().full_reference()).ok())
+            .map(|image| ("tracker", image));
+
+        let overrides = self
+            .release
+            .iter()
+            .flat_map(|release| release.images().iter())
+            .filter(|(service, image)| image.digest().is_some() && self.runs_service(service))
+            .map(|(service, image)| (service, image.clone()));
+
+        tracker_digest
+            .into_iter()
+            .chain(overrides)
+            .map(|(service, image)| (service.to_string(), image))
+            .collect()
+    }
+
+    /// Whether the Docker Compose service named `service` is part of the deployment
+    fn runs_service(&self, service: &str) -> bool {
+        match service {
+            "mysql" => matches!(self.tracker.core().database(), DatabaseConfig::Mysql(..)),
+            "prometheus" => self.prometheus.is_some(),
+            "grafana" => self.grafana.is_some(),
+            "caddy" => self.https.is_some() && self.has_any_tls_configured(),
+            "backup" => self.backup.is_some(),
+            _ => true,
+        }
+    }
+
+    /// Returns the labels of the environment
+    #[must_use]
+    pub fn labels(&self) -> &Labels {
+        &self.labels
+    }
+
+    /// Returns the location and retention of the trace files
+    #[must_use]
+    pub fn traces(&self) -> &TracesConfig {
+        &self.traces
+    }
+
+    /// Replaces the labels of the environment
+    pub fn set_labels(&mut self, labels: Labels) {
+        self.labels = labels;
+    }
+
+    /// Returns the keys minted on the tracker
+    #[must_use]
+    pub fn tracker_auth_keys(&self) -> &[IssuedAuthKey] {
+        &self.tracker_auth_keys
+    }
+
+    /// Replaces the keys minted on the tracker
+    pub fn set_tracker_auth_keys(&mut self, keys: Vec<IssuedAuthKey>) {
+        self.tracker_auth_keys = keys;
+    }
+
+    /// Returns the CPU, memory and disk of the instance
+    #[must_use]
+    pub fn instance_resources(&self) -> &InstanceResources {
+        &self.instance_resources
+    }
+
+    /// Returns the secrets held by the user inputs
+    #[must_use]
+    pub fn secrets(&self) -> EnvironmentSecrets {
+        EnvironmentSecrets {
+            grafana_admin_password: self
+                .grafana
+                .as_ref()
+                .and_then(GrafanaConfig::admin_password)
+                .cloned(),
+            registry_password: self
+                .release
+                .as_ref()
+                .and_then(ReleaseConfig::registry)
+                .map(|registry| registry.password().clone()),
+            tracker_auth_keys: self.tracker_auth_keys.clone(),
+            lxd_trust_token: self
+                .provider_config
+                .as_lxd()
+                .and_then(|lxd| lxd.remote.as_ref())
+                .and_then(LxdRemote::trust_token)
+                .cloned(),
+        }
+    }
+
+    /// Restores secrets that are not part of the serialized user inputs
+    ///
+    /// Secrets for services that are not enabled are ignored.
+    pub fn restore_secrets(&mut self, secrets: EnvironmentSecrets) {
+        if let (Some(grafana), Some(password)) =
+            (self.grafana.as_mut(), secrets.grafana_admin_password)
+        {
+            grafana.restore_admin_password(password);
+        }
+        if let (Some(release), Some(password)) = (self.release.as_mut(), secrets.registry_password)
+        {
+            release.restore_registry_password(password);
+        }
+        self.tracker_auth_keys = secrets.tracker_auth_keys;
+        if let (ProviderConfig::Lxd(lxd), Some(token)) =
+            (&mut self.provider_config, secrets.lxd_trust_token)
+        {
+            if let Some(remote) = lxd.remote.as_mut() {
+                remote.restore_trust_token(token);
+            }
+        }
+    }
+
+    /// Returns the name of a secret needed by an enabled service that was
+    /// not restored, e.g. because `secrets.json` is missing or incomplete
+    #[must_use]
+    pub fn missing_secret(&self) -> Option<&'static str> {
+        if self
+            .grafana
+            .as_ref()
+            .is_some_and(|grafana| grafana.admin_password().is_none())
+        {
+            return Some("grafana_admin_password");
+        }
+        None
+    }
+
+    /// Renames the environment, keeping the names of its infrastructure
+    ///
+    /// The instance name and LXD profile name are left unchanged: use
+    /// [`Self::rename_infrastructure`] when no infrastructure exists yet.
+    pub fn rename(&mut self, name: EnvironmentName) {
+        self.name = name;
+    }
+
+    /// Replaces the instance name and, if given, the LXD profile name
+    ///
+    /// The profile name is ignored for providers other than LXD.
+    pub fn rename_infrastructure(
+        &mut self,
+        instance_name: InstanceName,
+        profile_name: Option<ProfileName>,
+    ) {
+        self.instance_name = instance_name;
+
+        if let (ProviderConfig::Lxd(lxd), Some(profile_name)) =
+            (&mut self.provider_config, profile_name)
+        {
+            lxd.profile_name = profile_name;
+        }
+    }
+
+    /// Moves the SSH key paths located inside `from` to the same place inside `to`
+    ///
+    /// Keys generated by the deployer live in the environment data directory,
+    /// so they follow it when the directory moves. Keys elsewhere are kept.
+    pub fn relocate_ssh_keys(&mut self, from: &Path, to: &Path) {
+        let relocate = |path: &mut PathBuf| {
+            if let Ok(relative) = path.strip_prefix(from) {
+                *path = to.join(relative);
+            }
+        };
+
+        relocate(&mut self.ssh_credentials.ssh_priv_key_path);
+        relocate(&mut self.ssh_credentials.ssh_pub_key_path);
+    }
+
+    /// Replaces the paths of the SSH key pair
+    ///
+    /// Used when the keys are copied to another place, e.g. by `import-bundle`.
+    pub fn replace_ssh_key_paths(&mut self, ssh_priv_key_path: PathBuf, ssh_pub_key_path: PathBuf) {
+        self.ssh_credentials.ssh_priv_key_path = ssh_priv_key_path;
+        self.ssh_credentials.ssh_pub_key_path = ssh_pub_key_path;
+    }
+
     /// Returns the HTTPS configuration if enabled
     #[must_use]
     pub fn https(&self) -> Option<&HttpsConfig> {
@@ -378,6 +1134,175 @@ impl UserInputs {
         self.backup.as_ref()
     }
 
+    /// Returns the port bindings reachable from outside the instance
+    ///
+    /// Collects the ports derived by every enabled service through
+    /// [`PortDerivation`], skipping ports bound to localhost only. Caddy is
+    /// included when the HTTPS section is present. The SSH port is not part
+    /// of the result.
+    #[must_use]
+    pub fn public_port_bindings(&self) -> Vec<PortBinding> {
+        let mut bindings = self.tracker.derive_ports();
+
+        if let Some(prometheus) = &self.prometheus {
+            bindings.extend(prometheus.derive_ports());
+        }
+        if let Some(grafana) = &self.grafana {
+            bindings.extend(grafana.derive_ports());
+        }
+        if self.https.is_some() {
+            bindings.extend(CaddyConfig::new().derive_ports());
+        }
+
+        let mut public: Vec<PortBinding> = Vec::new();
+        for binding in bindings.into_iter().filter(|b| b.host_ip().is_none()) {
+            let duplicate = public.iter().any(|existing| {
+                existing.host_port() == binding.host_port()
+                    && existing.protocol() == binding.protocol()
+            });
+            if !duplicate {
+                public.push(binding);
+            }
+        }
+        public
+    }
+
+    /// Returns the rules the host firewall allows besides SSH
+    ///
+    /// Opens every [public port binding](Self::public_port_bindings) to any
+    /// source, followed by the extra rules of the firewall configuration.
+    /// The SSH port is always allowed by the firewall playbook itself, as the
+    /// effective port may be overridden at render time.
+    #[must_use]
+    pub fn firewall_allow_rules(&self) -> Vec<FirewallRule> {
+        self.public_port_bindings()
+            .iter()
+            .map(FirewallRule::from_port_binding)
+            .chain(self.firewall.extra_allow().iter().cloned())
+            .collect()
+    }
+
+    /// Compares these inputs with `new` ones, e.g. from an updated configuration file
+    ///
+    /// Each changed setting is categorized by the earliest deployment command
+    /// that has to run again for it to take effect. The environment name is
+    /// not compared.
+    #[must_use]
+    pub fn diff(&self, new: &Self) -> ConfigDiff {
+        let mut diff = ConfigDiff::default();
+
+        // Settings applied when the instance is created
+        let provision = ChangeImpact::RequiresProvision;
+        diff.compare(
+            "provider",
+            provision,
+            &self.provider_config,
+            &new.provider_config,
+        );
+        // The trust token is only used to add the remote before the instance is created
+        diff.compare(
+            "provider.remote.trust_token",
+            provision,
+            &self.secrets().lxd_trust_token,
+            &new.secrets().lxd_trust_token,
+        );
+        diff.compare(
+            "environment.instance_name",
+            provision,
+            &self.instance_name,
+            &new.instance_name,
+        );
+        diff.compare(
+            "ssh_credentials",
+            provision,
+            &self.ssh_credentials,
+            &new.ssh_credentials,
+        );
+        diff.compare(
+            "ssh_credentials.port",
+            provision,
+            &self.ssh_port,
+            &new.ssh_port,
+        );
+        diff.compare(
+            "instance",
+            provision,
+            &self.instance_resources,
+            &new.instance_resources,
+        );
+        diff.compare("cloud_init", provision, &self.cloud_init, &new.cloud_init);
+        diff.compare("offline", provision, &self.offline, &new.offline);
+        // Overrides may replace the templates of any deployment phase
+        diff.compare(
+            "templates_override_dir",
+            provision,
+            &self.templates_override_dir,
+            &new.templates_override_dir,
+        );
+        diff.compare(
+            "extra_template_vars",
+            provision,
+            &self.extra_template_variables,
+            &new.extra_template_variables,
+        );
+
+        // Settings applied by the configuration playbooks
+        let configure = ChangeImpact::RequiresConfigure;
+        diff.compare("firewall", configure, &self.firewall, &new.firewall);
+        diff.compare(
+            "firewall.public_ports",
+            configure,
+            &self.public_firewall_rules(),
+            &new.public_firewall_rules(),
+        );
+        diff.compare(
+            "system_tuning",
+            configure,
+            &self.system_tuning,
+            &new.system_tuning,
+        );
+        // The Docker daemon and the playbook downloads pick up the proxy on
+        // configure; only the apt proxy of cloud-init waits for a new instance
+        diff.compare("proxy", configure, &self.proxy, &new.proxy);
+
+        // Settings rendered into the release artifacts
+        let release = ChangeImpact::RequiresRelease;
+        diff.compare("tracker", release, &self.tracker, &new.tracker);
+        diff.compare("prometheus", release, &self.prometheus, &new.prometheus);
+        diff.compare("grafana", release, &self.grafana, &new.grafana);
+        diff.compare(
+            "grafana.admin_password",
+            release,
+            &self.secrets().grafana_admin_password,
+            &new.secrets().grafana_admin_password,
+        );
+        diff.compare("https", release, &self.https, &new.https);
+        diff.compare("backup", release, &self.backup, &new.backup);
+        diff.compare("release", release, &self.release, &new.release);
+        diff.compare(
+            "release.registry.password",
+            release,
+            &self.secrets().registry_password,
+            &new.secrets().registry_password,
+        );
+
+        // Settings only read by the deployer itself
+        let none = ChangeImpact::NoRedeploy;
+        diff.compare("provision", none, &self.provision, &new.provision);
+        diff.compare("labels", none, &self.labels, &new.labels);
+        diff.compare("traces", none, &self.traces, &new.traces);
+
+        diff
+    }
+
+    /// Firewall rules opening the public ports of the services
+    fn public_firewall_rules(&self) -> Vec<FirewallRule> {
+        self.public_port_bindings()
+            .iter()
+            .map(FirewallRule::from_port_binding)
+            .collect()
+    }
+
     // ========================================================================
     // Provider Accessor Methods
     // ========================================================================
@@ -402,6 +1327,7 @@ impl UserInputs {
     /// );
     /// let provider_config = ProviderConfig::Lxd(LxdConfig {
     ///     profile_name: ProfileName::new("test-profile".to_string())?,
+    ///     remote: None,
     /// });
     ///
     /// let user_inputs = UserInputs::new(&env_name, provider_config, ssh_credentials, 22)?;
@@ -440,7 +1366,7 @@ impl UserInputs {
     /// This function does not panic. The generated instance name is guaranteed
     /// to be valid for any valid environment name.
     fn generate_instance_name(env_name: &EnvironmentName) -> InstanceName {
-        let instance_name_str = format!("torrust-tracker-vm-{}", env_name.as_str());
+        let instance_name_str = format!("{}{}", InstanceName::GENERATED_PREFIX, env_name.as_str());
         InstanceName::new(instance_name_str)
             .expect("Generated instance name should always be valid")
     }
@@ -451,12 +1377,14 @@ mod tests {
     use std::path::PathBuf;
 
     use super::*;
+    use crate::domain::environment::config_diff::REDACTED;
     use crate::domain::provider::LxdConfig;
     use crate::domain::tracker::{
-        DatabaseConfig, HealthCheckApiConfig, HttpApiConfig, SqliteConfig, TrackerCoreConfig,
-        UdpTrackerConfig,
+        DatabaseConfig, HealthCheckApiConfig, HttpApiConfig, HttpTrackerConfig, SqliteConfig,
+        TrackerCoreConfig, UdpTrackerConfig,
     };
     use crate::domain::ProfileName;
+    use crate::shared::secrets::Password;
     use crate::shared::{ApiToken, DomainName, Username};
 
     fn create_test_ssh_credentials() -> SshCredentials {
@@ -470,6 +1398,7 @@ mod tests {
     fn create_lxd_provider_config(profile_name: &str) -> ProviderConfig {
         ProviderConfig::Lxd(LxdConfig {
             profile_name: ProfileName::new(profile_name.to_string()).unwrap(),
+            remote: None,
         })
     }
 
@@ -667,6 +1596,74 @@ mod tests {
         );
     }
 
+    #[test]
+    fn it_should_reject_grafana_tls_proxy_without_https_section() {
+        let grafana = GrafanaConfig::new(
+            "admin".to_string(),
+            "password".to_string(),
+            Some(DomainName::new("grafana.example.com").unwrap()),
+            true, // TLS enabled
+        );
+
+        let result = UserInputs::with_tracker(
+            &create_test_env_name(),
+            create_lxd_provider_config("test-profile"),
+            create_test_ssh_credentials(),
+            22,
+            create_tracker_config_without_tls(),
+            Some(PrometheusConfig::default()),
+            Some(grafana),
+            None, // No HTTPS section, so no ACME email
+            None, // No backup
+        );
+
+        assert!(
+            matches!(result, Err(UserInputsError::TlsServicesWithoutHttpsSection)),
+            "Expected TlsServicesWithoutHttpsSection error, got {result:?}"
+        );
+    }
+
+    #[test]
+    fn it_should_report_tls_configured_when_only_the_health_check_api_uses_the_proxy() {
+        let tracker = TrackerConfig::new(
+            TrackerCoreConfig::new(
+                DatabaseConfig::Sqlite(SqliteConfig::new("tracker.db").unwrap()),
+                false,
+            ),
+            vec![UdpTrackerConfig::new("0.0.0.0:6969".parse().unwrap(), None).unwrap()],
+            vec![],
+            HttpApiConfig::new(
+                "0.0.0.0:1212".parse().unwrap(),
+                "token".to_string().into(),
+                None,
+                false,
+            )
+            .unwrap(),
+            HealthCheckApiConfig::new(
+                "0.0.0.0:1313".parse().unwrap(),
+                Some(DomainName::new("health.example.com").unwrap()),
+                true, // TLS enabled
+            )
+            .unwrap(),
+        )
+        .unwrap();
+
+        let user_inputs = UserInputs::with_tracker(
+            &create_test_env_name(),
+            create_lxd_provider_config("test-profile"),
+            create_test_ssh_credentials(),
+            22,
+            tracker,
+            None,
+            None,
+            Some(HttpsConfig::new("admin@example.com", true).expect("valid email")),
+            None, // No backup
+        )
+        .expect("health check TLS with HTTPS section should be valid");
+
+        assert!(user_inputs.has_any_tls_configured());
+    }
+
     #[test]
     fn it_should_accept_tls_services_with_https_section() {
         let env_name = create_test_env_name();
@@ -709,6 +1706,305 @@ mod tests {
         assert!(result.is_ok());
     }
 
+    #[test]
+    fn it_should_reject_a_tracker_port_already_published_by_prometheus() {
+        let tracker = TrackerConfig::new(
+            TrackerCoreConfig::new(
+                DatabaseConfig::Sqlite(SqliteConfig::new("tracker.db").unwrap()),
+                false,
+            ),
+            vec![UdpTrackerConfig::new("0.0.0.0:9090".parse().unwrap(), None).unwrap()],
+            vec![HttpTrackerConfig::new("0.0.0.0:9090".parse().unwrap(), None, false).unwrap()],
+            HttpApiConfig::new(
+                "0.0.0.0:1212".parse().unwrap(),
+                "token".to_string().into(),
+                None,
+                false,
+            )
+            .unwrap(),
+            HealthCheckApiConfig::new("127.0.0.1:1313".parse().unwrap(), None, false).unwrap(),
+        )
+        .unwrap();
+
+        let result = UserInputs::with_tracker(
+            &create_test_env_name(),
+            create_lxd_provider_config("test-profile"),
+            create_test_ssh_credentials(),
+            22,
+            tracker,
+            Some(PrometheusConfig::default()),
+            None,
+            None,
+            None,
+        );
+
+        let Err(UserInputsError::PortConflict(conflict)) = result else {
+            panic!("Expected PortConflict error, got {result:?}");
+        };
+        assert_eq!(conflict.host_port, 9090);
+        assert_eq!(conflict.first_service, Service::Tracker);
+        assert_eq!(conflict.first_binding.protocol(), Protocol::Tcp);
+        assert_eq!(conflict.second_service, Service::Prometheus);
+    }
+
+    #[test]
+    fn it_should_reject_an_ssh_port_published_by_a_service() {
+        let result = UserInputs::with_tracker(
+            &create_test_env_name(),
+            create_lxd_provider_config("test-profile"),
+            create_test_ssh_credentials(),
+            9090,
+            TrackerConfig::default(),
+            Some(PrometheusConfig::default()),
+            None,
+            None,
+            None,
+        );
+
+        let Err(UserInputsError::SshPortConflict {
+            ssh_port, service, ..
+        }) = result
+        else {
+            panic!("Expected SshPortConflict error, got {result:?}");
+        };
+        assert_eq!(ssh_port, 9090);
+        assert_eq!(service, Service::Prometheus);
+    }
+
+    #[test]
+    fn it_should_reject_an_ssh_port_reserved_by_the_provider() {
+        let result = UserInputs::with_tracker(
+            &create_test_env_name(),
+            create_lxd_provider_config("test-profile"),
+            create_test_ssh_credentials(),
+            8443,
+            TrackerConfig::default(),
+            None,
+            None,
+            None,
+            None,
+        );
+
+        assert!(matches!(
+            result,
+            Err(UserInputsError::SshPortReserved {
+                ssh_port: 8443,
+                provider: Provider::Lxd,
+                ..
+            })
+        ));
+    }
+
+    #[test]
+    fn it_should_return_public_ports_of_enabled_services_without_localhost_ports() {
+        let user_inputs = UserInputs::new(
+            &create_test_env_name(),
+            create_lxd_provider_config("test-profile"),
+            create_test_ssh_credentials(),
+            22,
+        )
+        .unwrap();
+
+        let ports: Vec<(u16, Protocol)> = user_inputs
+            .public_port_bindings()
+            .iter()
+            .map(|binding| (binding.host_port(), binding.protocol()))
+            .collect();
+
+        assert_eq!(
+            ports,
+            vec![
+                (6969, Protocol::Udp),
+                (7070, Protocol::Tcp),
+                (1212, Protocol::Tcp),
+                (3000, Protocol::Tcp),
+            ]
+        );
+    }
+
+    #[test]
+    fn it_should_allow_public_ports_followed_by_extra_firewall_rules() {
+        let extra = FirewallRule::new(9100, Protocol::Tcp, Some("10.0.0.0/8".to_string())).unwrap();
+        let user_inputs = UserInputs::new(
+            &create_test_env_name(),
+            create_lxd_provider_config("test-profile"),
+            create_test_ssh_credentials(),
+            22,
+        )
+        .unwrap()
+        .with_firewall_config(FirewallConfig::new(vec![extra.clone()]));
+
+        let rules = user_inputs.firewall_allow_rules();
+
+        assert_eq!(rules.len(), 5);
+        assert_eq!(
+            rules[0],
+            FirewallRule::new(6969, Protocol::Udp, None).unwrap()
+        );
+        assert_eq!(rules[4], extra);
+    }
+
+    #[test]
+    fn it_should_include_caddy_ports_and_hide_proxied_ports_when_https_is_enabled() {
+        let user_inputs = UserInputs::with_tracker(
+            &create_test_env_name(),
+            create_lxd_provider_config("test-profile"),
+            create_test_ssh_credentials(),
+            22,
+            create_tracker_config_with_tls(),
+            None,
+            None,
+            Some(HttpsConfig::new("admin@example.com", false).expect("valid email")),
+            None,
+        )
+        .unwrap();
+
+        let ports: Vec<(u16, Protocol)> = user_inputs
+            .public_port_bindings()
+            .iter()
+            .map(|binding| (binding.host_port(), binding.protocol()))
+            .collect();
+
+        assert_eq!(
+            ports,
+            vec![
+                (6969, Protocol::Udp),
+                (80, Protocol::Tcp),
+                (443, Protocol::Tcp),
+                (443, Protocol::Udp),
+            ]
+        );
+    }
+
+    fn create_user_inputs_with_tracker(admin_token: &str, udp_port: u16) -> UserInputs {
+        let tracker = TrackerConfig::new(
+            TrackerCoreConfig::new(
+                DatabaseConfig::Sqlite(SqliteConfig::new("tracker.db").unwrap()),
+                false,
+            ),
+            vec![
+                UdpTrackerConfig::new(format!("0.0.0.0:{udp_port}").parse().unwrap(), None)
+                    .unwrap(),
+            ],
+            vec![],
+            HttpApiConfig::new(
+                "0.0.0.0:1212".parse().unwrap(),
+                admin_token.to_string().into(),
+                None,
+                false,
+            )
+            .unwrap(),
+            HealthCheckApiConfig::new("127.0.0.1:1313".parse().unwrap(), None, false).unwrap(),
+        )
+        .unwrap();
+
+        UserInputs::with_tracker(
+            &create_test_env_name(),
+            create_lxd_provider_config("test-profile"),
+            create_test_ssh_credentials(),
+            22,
+            tracker,
+            Some(PrometheusConfig::default()),
+            Some(GrafanaConfig::default()),
+            None,
+            None,
+        )
+        .unwrap()
+    }
+
+    #[test]
+    fn it_should_find_no_difference_between_identical_inputs() {
+        let inputs = create_user_inputs_with_tracker("token", 6969);
+
+        assert!(inputs.diff(&inputs.clone()).is_empty());
+    }
+
+    #[test]
+    fn it_should_only_require_a_release_when_the_api_token_changes() {
+        let current = create_user_inputs_with_tracker("old-token", 6969);
+        let new = create_user_inputs_with_tracker("new-token", 6969);
+
+        let diff = current.diff(&new);
+
+        assert_eq!(diff.changes().len(), 1);
+        let change = &diff.changes()[0];
+        assert_eq!(change.path, "tracker.http_api.admin_token");
+        assert_eq!(change.impact, ChangeImpact::RequiresRelease);
+        assert_eq!(change.after.as_deref(), Some(REDACTED));
+        assert_eq!(diff.impact(), Some(ChangeImpact::RequiresRelease));
+    }
+
+    #[test]
+    fn it_should_require_a_provision_when_the_lxd_trust_token_changes() {
+        fn with_trust_token(token: &str) -> UserInputs {
+            let mut inputs = create_user_inputs_with_tracker("token", 6969);
+            inputs.provider_config = ProviderConfig::Lxd(LxdConfig {
+                profile_name: ProfileName::new("test-profile".to_string()).unwrap(),
+                remote: Some(
+                    LxdRemote::new(
+                        "bigbox",
+                        Some("https://bigbox:8443".to_string()),
+                        None,
+                        Some(Password::new(token)),
+                    )
+                    .unwrap(),
+                ),
+            });
+            inputs
+        }
+
+        let diff = with_trust_token("old-token").diff(&with_trust_token("new-token"));
+
+        assert_eq!(diff.changes().len(), 1);
+        let change = &diff.changes()[0];
+        assert_eq!(change.path, "provider.remote.trust_token");
+        assert_eq!(change.impact, ChangeImpact::RequiresProvision);
+        assert_eq!(change.before.as_deref(), Some(REDACTED));
+        assert_eq!(change.after.as_deref(), Some(REDACTED));
+    }
+
+    #[test]
+    fn it_should_require_a_configure_when_a_public_port_changes() {
+        let current = create_user_inputs_with_tracker("token", 6969);
+        let new = create_user_inputs_with_tracker("token", 6868);
+
+        let diff = current.diff(&new);
+
+        assert_eq!(diff.impact(), Some(ChangeImpact::RequiresConfigure));
+        assert!(diff
+            .changes_with(ChangeImpact::RequiresConfigure)
+            .any(|change| change.path == "firewall.public_ports"));
+    }
+
+    #[test]
+    fn it_should_recreate_identical_inputs_from_their_params() {
+        let inputs = create_user_inputs_with_tracker("token", 6969)
+            .with_instance_name(InstanceName::new("custom-vm".to_string()).unwrap());
+
+        let recreated = UserInputs::from_params(inputs.to_params()).unwrap();
+
+        assert!(inputs.diff(&recreated).is_empty());
+        assert_eq!(recreated.instance_name().as_str(), "custom-vm");
+    }
+
+    #[test]
+    fn it_should_not_require_a_redeploy_when_only_the_labels_change() {
+        let current = create_user_inputs_with_tracker("token", 6969);
+        let new = current.clone().with_labels(
+            Labels::try_from(std::collections::BTreeMap::from([(
+                "team".to_string(),
+                "infra".to_string(),
+            )]))
+            .unwrap(),
+        );
+
+        let diff = current.diff(&new);
+
+        assert_eq!(diff.changes().len(), 1);
+        assert_eq!(diff.changes()[0].path, "labels.team");
+        assert_eq!(diff.impact(), Some(ChangeImpact::NoRedeploy));
+    }
+
     #[test]
     fn it_should_provide_helpful_error_messages() {
         assert!(UserInputsError::GrafanaRequiresPrometheus
@@ -0,0 +1,132 @@
+//! Workspace Layout Module
+//!
+//! This module contains the `WorkspaceLayout` value object which tells where
+//! the per-environment data and build directories live.
+//!
+//! ## Purpose
+//!
+//! By default both roots are subdirectories of the working directory
+//! (`{working_dir}/data` and `{working_dir}/build`). Programmatic consumers
+//! can place them elsewhere, for example to keep build artifacts on a scratch
+//! volume. Every environment gets `{data_dir}/{env_name}` and
+//! `{build_dir}/{env_name}`.
+
+use std::path::{Path, PathBuf};
+
+use super::EnvironmentName;
+
+/// Name of the data root inside the working directory
+pub const DATA_DIR_NAME: &str = "data";
+
+/// Name of the build root inside the working directory
+pub const BUILD_DIR_NAME: &str = "build";
+
+/// Name of the generated SSH keys directory inside an environment data directory
+pub const SSH_DIR_NAME: &str = "ssh";
+
+/// Roots of the per-environment data and build directories
+///
+/// # Examples
+///
+/// ```rust
+/// use torrust_tracker_deployer_lib::domain::environment::{EnvironmentName, WorkspaceLayout};
+/// use std::path::PathBuf;
+///
+/// let layout = WorkspaceLayout::in_working_dir("/opt/deployments")
+///     .with_build_dir("/scratch/build");
+/// let env_name = EnvironmentName::new("production".to_string())?;
+///
+/// assert_eq!(layout.environment_data_dir(&env_name), PathBuf::from("/opt/deployments/data/production"));
+/// assert_eq!(layout.environment_build_dir(&env_name), PathBuf::from("/scratch/build/production"));
+/// # Ok::<(), Box<dyn std::error::Error>>(())
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct WorkspaceLayout {
+    data_dir: PathBuf,
+    build_dir: PathBuf,
+}
+
+impl WorkspaceLayout {
+    /// Creates the default layout: `data/` and `build/` inside `working_dir`
+    #[must_use]
+    pub fn in_working_dir(working_dir: impl AsRef<Path>) -> Self {
+        let working_dir = working_dir.as_ref();
+
+        Self {
+            data_dir: working_dir.join(DATA_DIR_NAME),
+            build_dir: working_dir.join(BUILD_DIR_NAME),
+        }
+    }
+
+    /// Replaces the data root
+    #[must_use]
+    pub fn with_data_dir(mut self, data_dir: impl Into<PathBuf>) -> Self {
+        self.data_dir = data_dir.into();
+        self
+    }
+
+    /// Replaces the build root
+    #[must_use]
+    pub fn with_build_dir(mut self, build_dir: impl Into<PathBuf>) -> Self {
+        self.build_dir = build_dir.into();
+        self
+    }
+
+    /// Returns the directory holding one data directory per environment
+    #[must_use]
+    pub fn data_dir(&self) -> &Path {
+        &self.data_dir
+    }
+
+    /// Returns the directory holding one build directory per environment
+    #[must_use]
+    pub fn build_dir(&self) -> &Path {
+        &self.build_dir
+    }
+
+    /// Returns `{data_dir}/{env_name}`
+    #[must_use]
+    pub fn environment_data_dir(&self, env_name: &EnvironmentName) -> PathBuf {
+        self.data_dir.join(env_name.as_str())
+    }
+
+    /// Returns `{data_dir}/{env_name}/ssh`, where generated SSH keys are kept
+    #[must_use]
+    pub fn environment_ssh_dir(&self, env_name: &EnvironmentName) -> PathBuf {
+        self.environment_data_dir(env_name).join(SSH_DIR_NAME)
+    }
+
+    /// Returns `{build_dir}/{env_name}`
+    #[must_use]
+    pub fn environment_build_dir(&self, env_name: &EnvironmentName) -> PathBuf {
+        self.build_dir.join(env_name.as_str())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn it_should_place_both_roots_in_the_working_directory_by_default() {
+        let layout = WorkspaceLayout::in_working_dir("/workspace");
+
+        assert_eq!(layout.data_dir(), Path::new("/workspace/data"));
+        assert_eq!(layout.build_dir(), Path::new("/workspace/build"));
+    }
+
+    #[test]
+    fn it_should_keep_the_other_root_when_one_is_overridden() {
+        let layout = WorkspaceLayout::in_working_dir("/workspace").with_data_dir("/state");
+        let env_name = EnvironmentName::new("staging".to_string()).unwrap();
+
+        assert_eq!(
+            layout.environment_data_dir(&env_name),
+            PathBuf::from("/state/staging")
+        );
+        assert_eq!(
+            layout.environment_build_dir(&env_name),
+            PathBuf::from("/workspace/build/staging")
+        );
+    }
+}
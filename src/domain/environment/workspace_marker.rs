@@ -0,0 +1,234 @@
+//! Workspace Marker Module
+//!
+//! This module contains the `WorkspaceMarker` value object, the content of the
+//! file that identifies a directory as a deployer workspace.
+//!
+//! ## Purpose
+//!
+//! The `init` command writes the marker to the root of the working directory.
+//! Other commands refuse to run in a directory without it, instead of failing
+//! later with scattered "No such file or directory" errors. The format version
+//! lets a newer layout be detected before an older binary misreads it.
+//!
+//! The marker also holds the settings of the workspace, such as the storage
+//! backend of the environment repository, the number of runs whose tool
+//! output logs are kept and the minimum free disk space checked before
+//! provisioning and releasing.
+
+use serde::{Deserialize, Serialize};
+
+use crate::shared::command::run_log::DEFAULT_KEPT_RUNS;
+
+/// Name of the marker file at the root of the working directory
+pub const WORKSPACE_MARKER_FILE_NAME: &str = "deployer-workspace.json";
+
+/// Workspace format version written by this version of the deployer
+pub const CURRENT_WORKSPACE_FORMAT_VERSION: u32 = 1;
+
+/// Storage backend of the environment repository of a workspace
+///
+/// The JSON backend keeps one `environment.json` file per environment
+/// directory. The `SQLite` backend keeps every environment in a single
+/// `workspace.db` database in the data directory, for control hosts shared
+/// by several users.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum RepositoryBackend {
+    /// One JSON file per environment (default)
+    #[default]
+    Json,
+
+    /// A single `SQLite` database for the whole workspace
+    Sqlite,
+}
+
+impl RepositoryBackend {
+    /// Returns `true` for the default backend
+    #[must_use]
+    pub fn is_default(&self) -> bool {
+        *self == Self::default()
+    }
+}
+
+/// One gibibyte, in bytes
+const GIB: u64 = 1024 * 1024 * 1024;
+
+/// Minimum free disk space, in GiB, required before provisioning and releasing
+///
+/// Running out of space halfway through a deployment leaves a corrupted
+/// `OpenTofu` state or a truncated image behind, so the `provision` and
+/// `release` commands refuse to start below these thresholds. The `doctor`
+/// command reports the same checks.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct DiskSpaceThresholds {
+    /// Under the workspace, which holds the `OpenTofu` state and the run logs
+    pub workspace_gib: u64,
+
+    /// In the LXD storage pool the instance disk and image are created in
+    pub lxd_storage_pool_gib: u64,
+
+    /// On the partition of the instance holding the Docker images
+    pub instance_gib: u64,
+}
+
+impl DiskSpaceThresholds {
+    /// Minimum free space under the workspace, in bytes
+    #[must_use]
+    pub fn workspace(&self) -> u64 {
+        self.workspace_gib.saturating_mul(GIB)
+    }
+
+    /// Minimum free space in the LXD storage pool, in bytes
+    #[must_use]
+    pub fn lxd_storage_pool(&self) -> u64 {
+        self.lxd_storage_pool_gib.saturating_mul(GIB)
+    }
+
+    /// Minimum free space on the Docker partition of the instance, in bytes
+    #[must_use]
+    pub fn instance(&self) -> u64 {
+        self.instance_gib.saturating_mul(GIB)
+    }
+
+    /// Returns `true` for the default thresholds
+    #[must_use]
+    pub fn is_default(&self) -> bool {
+        *self == Self::default()
+    }
+}
+
+impl Default for DiskSpaceThresholds {
+    fn default() -> Self {
+        Self {
+            workspace_gib: 2,
+            lxd_storage_pool_gib: 5,
+            instance_gib: 2,
+        }
+    }
+}
+
+/// Content of the workspace marker file
+///
+/// # Examples
+///
+/// ```rust
+/// use torrust_tracker_deployer_lib::domain::environment::WorkspaceMarker;
+///
+/// let marker = WorkspaceMarker::current();
+///
+/// assert!(marker.is_supported());
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct WorkspaceMarker {
+    /// Version of the workspace layout
+    pub format_version: u32,
+
+    /// Storage backend of the environment repository
+    #[serde(default, skip_serializing_if = "RepositoryBackend::is_default")]
+    pub repository: RepositoryBackend,
+
+    /// Number of runs of each command whose tool output logs are kept
+    #[serde(
+        default = "default_kept_run_logs",
+        skip_serializing_if = "is_default_kept_run_logs"
+    )]
+    pub kept_run_logs: usize,
+
+    /// Minimum free disk space checked before provisioning and releasing
+    #[serde(default, skip_serializing_if = "DiskSpaceThresholds::is_default")]
+    pub min_free_disk_space: DiskSpaceThresholds,
+}
+
+fn default_kept_run_logs() -> usize {
+    DEFAULT_KEPT_RUNS
+}
+
+#[allow(clippy::trivially_copy_pass_by_ref)] // Signature required by serde
+fn is_default_kept_run_logs(runs: &usize) -> bool {
+    *runs == DEFAULT_KEPT_RUNS
+}
+
+impl WorkspaceMarker {
+    /// Creates the marker of the layout written by this version
+    #[must_use]
+    pub fn current() -> Self {
+        Self {
+            format_version: CURRENT_WORKSPACE_FORMAT_VERSION,
+            repository: RepositoryBackend::default(),
+            kept_run_logs: DEFAULT_KEPT_RUNS,
+            min_free_disk_space: DiskSpaceThresholds::default(),
+        }
+    }
+
+    /// Whether this version of the deployer can operate on the workspace
+    ///
+    /// Workspaces written by a newer version are not supported: their layout
+    /// may have changed in ways this version does not know about.
+    #[must_use]
+    pub fn is_supported(&self) -> bool {
+        (1..=CURRENT_WORKSPACE_FORMAT_VERSION).contains(&self.format_version)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn it_should_not_support_a_workspace_written_by_a_newer_version() {
+        let marker = WorkspaceMarker {
+            format_version: CURRENT_WORKSPACE_FORMAT_VERSION + 1,
+            repository: RepositoryBackend::Json,
+            kept_run_logs: DEFAULT_KEPT_RUNS,
+            min_free_disk_space: DiskSpaceThresholds::default(),
+        };
+
+        assert!(!marker.is_supported());
+    }
+
+    #[test]
+    fn it_should_serialize_the_format_version() {
+        let json = serde_json::to_string(&WorkspaceMarker::current()).unwrap();
+
+        assert_eq!(json, r#"{"format_version":1}"#);
+    }
+
+    #[test]
+    fn it_should_read_the_repository_backend() {
+        let marker: WorkspaceMarker =
+            serde_json::from_str(r#"{"format_version":1,"repository":"sqlite"}"#).unwrap();
+
+        assert_eq!(marker.repository, RepositoryBackend::Sqlite);
+    }
+
+    #[test]
+    fn it_should_keep_the_default_number_of_run_logs_when_unset() {
+        let marker: WorkspaceMarker = serde_json::from_str(r#"{"format_version":1}"#).unwrap();
+
+        assert_eq!(marker.kept_run_logs, DEFAULT_KEPT_RUNS);
+    }
+
+    #[test]
+    fn it_should_read_the_number_of_kept_run_logs() {
+        let marker: WorkspaceMarker =
+            serde_json::from_str(r#"{"format_version":1,"kept_run_logs":3}"#).unwrap();
+
+        assert_eq!(marker.kept_run_logs, 3);
+    }
+
+    #[test]
+    fn it_should_read_the_disk_space_thresholds_keeping_the_defaults_of_unset_ones() {
+        let marker: WorkspaceMarker = serde_json::from_str(
+            r#"{"format_version":1,"min_free_disk_space":{"workspace_gib":10}}"#,
+        )
+        .unwrap();
+
+        assert_eq!(marker.min_free_disk_space.workspace_gib, 10);
+        assert_eq!(marker.min_free_disk_space.workspace(), 10 * GIB);
+        assert_eq!(
+            marker.min_free_disk_space.instance_gib,
+            DiskSpaceThresholds::default().instance_gib
+        );
+    }
+}
@@ -0,0 +1,188 @@
+//! Host firewall configuration.
+
+use std::net::IpAddr;
+
+use serde::{Deserialize, Serialize};
+use thiserror::Error;
+
+use crate::domain::topology::PortBinding;
+use crate::domain::tracker::Protocol;
+
+/// A rule allowing incoming traffic through the host firewall
+///
+/// Rules without a CIDR accept traffic from any source.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct FirewallRule {
+    /// Port to open
+    port: u16,
+
+    /// Protocol of the port
+    protocol: Protocol,
+
+    /// Source network allowed to connect (e.g. `10.0.0.0/8`)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    cidr: Option<String>,
+}
+
+/// Errors that can occur when creating a `FirewallRule`.
+#[derive(Debug, Error, PartialEq, Eq)]
+pub enum FirewallRuleError {
+    /// Port 0 cannot be opened
+    #[error("Firewall rule port must be greater than 0")]
+    ZeroPort,
+
+    /// The source network is not a valid CIDR
+    #[error("Invalid CIDR '{value}': expected an IP address with an optional prefix length (e.g. '10.0.0.0/8')")]
+    InvalidCidr {
+        /// The rejected value
+        value: String,
+    },
+}
+
+impl FirewallRule {
+    /// Creates a new firewall rule.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if `port` is 0 or `cidr` is not an IP address with an
+    /// optional prefix length valid for its address family.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use torrust_tracker_deployer_lib::domain::firewall::FirewallRule;
+    /// use torrust_tracker_deployer_lib::domain::tracker::Protocol;
+    ///
+    /// let rule = FirewallRule::new(9100, Protocol::Tcp, Some("10.0.0.0/8".to_string()))?;
+    /// assert_eq!(rule.cidr(), Some("10.0.0.0/8"));
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn new(
+        port: u16,
+        protocol: Protocol,
+        cidr: Option<String>,
+    ) -> Result<Self, FirewallRuleError> {
+        if port == 0 {
+            return Err(FirewallRuleError::ZeroPort);
+        }
+
+        if let Some(value) = &cidr {
+            if !is_valid_cidr(value) {
+                return Err(FirewallRuleError::InvalidCidr {
+                    value: value.clone(),
+                });
+            }
+        }
+
+        Ok(Self {
+            port,
+            protocol,
+            cidr,
+        })
+    }
+
+    /// Rule opening a public port binding to any source
+    #[must_use]
+    pub fn from_port_binding(binding: &PortBinding) -> Self {
+        Self {
+            port: binding.host_port(),
+            protocol: binding.protocol(),
+            cidr: None,
+        }
+    }
+
+    /// Returns the port to open
+    #[must_use]
+    pub fn port(&self) -> u16 {
+        self.port
+    }
+
+    /// Returns the protocol of the port
+    #[must_use]
+    pub fn protocol(&self) -> Protocol {
+        self.protocol
+    }
+
+    /// Returns the allowed source network, if restricted
+    #[must_use]
+    pub fn cidr(&self) -> Option<&str> {
+        self.cidr.as_deref()
+    }
+}
+
+/// Whether `value` is an IP address with an optional, in-range prefix length
+fn is_valid_cidr(value: &str) -> bool {
+    let (address, prefix) = match value.split_once('/') {
+        Some((address, prefix)) => (address, Some(prefix)),
+        None => (value, None),
+    };
+
+    let Ok(address) = address.parse::<IpAddr>() else {
+        return false;
+    };
+
+    let max_prefix = if address.is_ipv4() { 32 } else { 128 };
+
+    prefix.is_none_or(|prefix| prefix.parse::<u8>().is_ok_and(|p| p <= max_prefix))
+}
+
+/// Settings of the host firewall
+///
+/// The SSH port and the public ports derived from the enabled services are
+/// always allowed; this configuration only holds the rules the user adds on
+/// top of them.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct FirewallConfig {
+    /// Additional allow rules
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    extra_allow: Vec<FirewallRule>,
+}
+
+impl FirewallConfig {
+    /// Creates a firewall configuration with additional allow rules
+    #[must_use]
+    pub fn new(extra_allow: Vec<FirewallRule>) -> Self {
+        Self { extra_allow }
+    }
+
+    /// Returns the additional allow rules
+    #[must_use]
+    pub fn extra_allow(&self) -> &[FirewallRule] {
+        &self.extra_allow
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn it_should_accept_ipv4_and_ipv6_networks_and_plain_addresses() {
+        for cidr in ["10.0.0.0/8", "203.0.113.7", "2001:db8::/32"] {
+            assert!(
+                FirewallRule::new(9100, Protocol::Tcp, Some(cidr.to_string())).is_ok(),
+                "{cidr} should be accepted"
+            );
+        }
+    }
+
+    #[test]
+    fn it_should_reject_an_invalid_cidr() {
+        for cidr in ["10.0.0.0/33", "not-an-ip/8", "10.0.0.0/"] {
+            assert_eq!(
+                FirewallRule::new(9100, Protocol::Tcp, Some(cidr.to_string())),
+                Err(FirewallRuleError::InvalidCidr {
+                    value: cidr.to_string()
+                })
+            );
+        }
+    }
+
+    #[test]
+    fn it_should_reject_port_zero() {
+        assert_eq!(
+            FirewallRule::new(0, Protocol::Udp, None),
+            Err(FirewallRuleError::ZeroPort)
+        );
+    }
+}
@@ -0,0 +1,19 @@
+//! Host firewall domain types
+//!
+//! This module contains domain types for the rules of the host firewall (UFW)
+//! applied by the configure command.
+//!
+//! ## Purpose
+//!
+//! The firewall denies all incoming traffic by default. The SSH port and the
+//! public ports derived from the service topology are always allowed; the
+//! `FirewallConfig` type holds the extra allow rules added by the user.
+//!
+//! ## See Also
+//!
+//! - Application layer DTOs: `src/application/command_handlers/create/config/firewall.rs`
+//! - Firewall playbook: `templates/ansible/configure-firewall.yml`
+
+pub mod config;
+
+pub use config::{FirewallConfig, FirewallRule, FirewallRuleError};
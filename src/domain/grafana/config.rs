@@ -30,7 +30,14 @@ pub struct GrafanaConfig {
     /// - Automatic redaction in debug output (shows `[REDACTED]`)
     /// - Memory zeroing when the value is dropped
     /// - Explicit `.expose_secret()` calls required to access plaintext
-    admin_password: Password,
+    ///
+    /// Never serialized: the environment state file must not contain the
+    /// password in plaintext. It is persisted separately as part of the
+    /// `EnvironmentSecrets` and restored with `restore_admin_password`.
+    /// Still deserialized so state files written before secrets were
+    /// split out keep their password. `None` until restored.
+    #[serde(skip_serializing, default)]
+    admin_password: Option<Password>,
 
     /// Domain name for the service (optional)
     ///
@@ -71,7 +78,7 @@ impl GrafanaConfig {
     ) -> Self {
         Self {
             admin_user,
-            admin_password: Password::new(admin_password),
+            admin_password: Some(Password::new(admin_password)),
             domain,
             use_tls_proxy,
         }
@@ -84,9 +91,20 @@ impl GrafanaConfig {
     }
 
     /// Returns the admin password
+    ///
+    /// `None` for a deserialized configuration whose password was not
+    /// restored yet.
     #[must_use]
-    pub fn admin_password(&self) -> &Password {
-        &self.admin_password
+    pub fn admin_password(&self) -> Option<&Password> {
+        self.admin_password.as_ref()
+    }
+
+    /// Restores the admin password after loading the configuration
+    ///
+    /// The password is not part of the serialized configuration, so
+    /// repositories call this with the separately persisted secret.
+    pub fn restore_admin_password(&mut self, admin_password: Password) {
+        self.admin_password = Some(admin_password);
     }
 
     /// Returns the domain if configured
@@ -136,7 +154,7 @@ impl Default for GrafanaConfig {
     fn default() -> Self {
         Self {
             admin_user: "admin".to_string(),
-            admin_password: Password::new("admin"),
+            admin_password: Some(Password::new("admin")),
             domain: None,
             use_tls_proxy: false,
         }
@@ -187,7 +205,7 @@ mod tests {
         let config = GrafanaConfig::default();
 
         assert_eq!(config.admin_user, "admin");
-        assert_eq!(config.admin_password.expose_secret(), "admin");
+        assert_eq!(config.admin_password().unwrap().expose_secret(), "admin");
         assert!(config.domain.is_none());
         assert!(!config.use_tls_proxy);
     }
@@ -196,13 +214,16 @@ mod tests {
     fn it_should_create_grafana_config_with_custom_values() {
         let config = GrafanaConfig {
             admin_user: "custom_admin".to_string(),
-            admin_password: Password::new("custom_pass"),
+            admin_password: Some(Password::new("custom_pass")),
             domain: None,
             use_tls_proxy: false,
         };
 
         assert_eq!(config.admin_user, "custom_admin");
-        assert_eq!(config.admin_password.expose_secret(), "custom_pass");
+        assert_eq!(
+            config.admin_password().unwrap().expose_secret(),
+            "custom_pass"
+        );
     }
 
     #[test]
@@ -236,10 +257,10 @@ mod tests {
     }
 
     #[test]
-    fn it_should_serialize_grafana_config_to_json() {
+    fn it_should_serialize_grafana_config_to_json_without_admin_password() {
         let config = GrafanaConfig {
             admin_user: "admin".to_string(),
-            admin_password: Password::new("secret123"),
+            admin_password: Some(Password::new("secret123")),
             domain: None,
             use_tls_proxy: false,
         };
@@ -247,8 +268,9 @@ mod tests {
         let json = serde_json::to_string(&config).expect("Failed to serialize");
 
         assert!(json.contains("\"admin_user\":\"admin\""));
-        assert!(json.contains("\"admin_password\":\"secret123\""));
         assert!(json.contains("\"use_tls_proxy\":false"));
+        assert!(!json.contains("admin_password"));
+        assert!(!json.contains("secret123"));
     }
 
     #[test]
@@ -258,15 +280,33 @@ mod tests {
         let config: GrafanaConfig = serde_json::from_str(json).expect("Failed to deserialize");
 
         assert_eq!(config.admin_user, "admin");
-        assert_eq!(config.admin_password.expose_secret(), "secret123");
+        assert_eq!(
+            config.admin_password().unwrap().expose_secret(),
+            "secret123"
+        );
         assert!(!config.use_tls_proxy);
     }
 
+    #[test]
+    fn it_should_leave_admin_password_unset_when_missing_from_json() {
+        let json = r#"{"admin_user":"admin","use_tls_proxy":false}"#;
+
+        let mut config: GrafanaConfig = serde_json::from_str(json).expect("Failed to deserialize");
+        assert!(config.admin_password().is_none());
+
+        config.restore_admin_password(Password::new("secret123"));
+
+        assert_eq!(
+            config.admin_password().unwrap().expose_secret(),
+            "secret123"
+        );
+    }
+
     #[test]
     fn it_should_redact_password_in_debug_output() {
         let config = GrafanaConfig {
             admin_user: "admin".to_string(),
-            admin_password: Password::new("super_secret"),
+            admin_password: Some(Password::new("super_secret")),
             domain: None,
             use_tls_proxy: false,
         };
@@ -282,7 +322,7 @@ mod tests {
     fn it_should_clone_grafana_config() {
         let config = GrafanaConfig {
             admin_user: "admin".to_string(),
-            admin_password: Password::new("password"),
+            admin_password: Some(Password::new("password")),
             domain: None,
             use_tls_proxy: false,
         };
@@ -291,8 +331,8 @@ mod tests {
 
         assert_eq!(cloned.admin_user, config.admin_user);
         assert_eq!(
-            cloned.admin_password.expose_secret(),
-            config.admin_password.expose_secret()
+            cloned.admin_password().unwrap().expose_secret(),
+            config.admin_password().unwrap().expose_secret()
         );
     }
 
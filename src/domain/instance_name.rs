@@ -83,6 +83,12 @@ pub enum InstanceNameError {
 pub struct InstanceName(String);
 
 impl InstanceName {
+    /// Maximum length of an instance name
+    pub const MAX_LENGTH: usize = 63;
+
+    /// Prefix of the instance names generated from environment names
+    pub const GENERATED_PREFIX: &'static str = "torrust-tracker-vm-";
+
     /// Creates a new `InstanceName` from a string if it's valid.
     ///
     /// This method validates that the provided name meets the requirements for both
@@ -150,7 +156,7 @@ impl InstanceName {
         if name.is_empty() {
             return Err(InstanceNameError::Empty);
         }
-        if name.len() > 63 {
+        if name.len() > Self::MAX_LENGTH {
             return Err(InstanceNameError::TooLong { length: name.len() });
         }
 
@@ -10,16 +10,21 @@
 //! - `environment` - Environment module with entity, name validation, and state management
 //!   - `environment::name` - Environment name validation and management
 //!   - `environment::state` - State marker types and type erasure for environment state machine
+//! - `firewall` - Host firewall settings (extra allow rules)
 //! - `instance_name` - LXD instance name validation and management
 //! - `mysql` - `MySQL` database service domain types (distinct from tracker database config)
 //! - `profile_name` - LXD profile name validation and management
 //! - `provider` - Infrastructure provider types (LXD, Hetzner) and configuration
+//! - `provision` - Provision workflow settings (cloud-init readiness timeout)
+//! - `release` - Release settings (service image overrides, private registry credentials)
+//! - `system_tuning` - Host system tuning (sysctls, ulimits, swap file)
 //! - `template` - Core template domain models and business logic
 //! - `topology` - Docker Compose topology domain types (networks, services)
 
 pub mod backup;
 pub mod caddy;
 pub mod environment;
+pub mod firewall;
 pub mod grafana;
 pub mod https;
 pub mod instance_name;
@@ -27,6 +32,9 @@ pub mod mysql;
 pub mod profile_name;
 pub mod prometheus;
 pub mod provider;
+pub mod provision;
+pub mod release;
+pub mod system_tuning;
 pub mod template;
 pub mod topology;
 pub mod tracker;
@@ -39,9 +47,14 @@ pub use environment::{
     state::{AnyEnvironmentState, StateTypeError},
     Environment,
 };
+pub use firewall::FirewallConfig;
 pub use instance_name::{InstanceName, InstanceNameError};
 pub use mysql::MysqlServiceConfig;
 pub use profile_name::{ProfileName, ProfileNameError};
-pub use provider::{HetznerConfig, LxdConfig, Provider, ProviderConfig};
+pub use provider::{
+    DigitalOceanConfig, DockerConfig, HetznerConfig, LxdConfig, Provider, ProviderConfig,
+};
+pub use provision::{InstanceResources, ProvisionConfig};
+pub use system_tuning::SystemTuningConfig;
 pub use template::{TemplateEngine, TemplateEngineError, TemplateManager, TemplateManagerError};
 pub use topology::{DockerComposeTopology, Network, Service, ServiceTopology};
@@ -72,6 +72,9 @@ pub enum ProfileNameError {
 pub struct ProfileName(String);
 
 impl ProfileName {
+    /// Prefix of the profile names generated from environment names
+    pub const GENERATED_PREFIX: &'static str = "lxd-";
+
     /// Creates a new `ProfileName` from a string if it's valid.
     ///
     /// # Arguments
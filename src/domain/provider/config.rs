@@ -2,7 +2,8 @@
 //!
 //! This module contains the `ProviderConfig` enum that aggregates all
 //! provider-specific configurations. Individual provider configurations
-//! are defined in their own modules (`lxd`, `hetzner`).
+//! are defined in their own modules (`lxd`, `hetzner`, `digitalocean`, `docker`,
+//! `libvirt`).
 //!
 //! These types use validated domain types (like `ProfileName`) and represent
 //! the semantic meaning of provider configuration.
@@ -12,18 +13,23 @@
 //!
 //! # Layer Separation
 //!
-//! - **Domain types** (this module): `ProviderConfig`, `LxdConfig`, `HetznerConfig`
+//! - **Domain types** (this module): `ProviderConfig`, `LxdConfig`, `HetznerConfig`,
+//!   `DigitalOceanConfig`, `DockerConfig`, `LibvirtConfig`
 //!   - Use validated domain types (e.g., `ProfileName`)
 //!   - Represent semantic meaning of configuration
 //!
 //! - **Application config types** (`application::command_handlers::create::config::provider`):
-//!   - `ProviderSection`, `LxdProviderSection`, `HetznerProviderSection`
+//!   - `ProviderSection`, `LxdProviderSection`, `HetznerProviderSection`,
+//!     `DigitalOceanProviderSection`, `DockerProviderSection`, `LibvirtProviderSection`
 //!   - Use raw primitives (e.g., `String`)
 //!   - Handle JSON deserialization and conversion to domain types
 
 use serde::{Deserialize, Serialize};
 
+use super::digitalocean::DigitalOceanConfig;
+use super::docker::DockerConfig;
 use super::hetzner::HetznerConfig;
+use super::libvirt::LibvirtConfig;
 use super::lxd::LxdConfig;
 use super::Provider;
 
@@ -47,6 +53,7 @@ use super::Provider;
 ///
 /// let lxd_config = ProviderConfig::Lxd(LxdConfig {
 ///     profile_name: ProfileName::new("torrust-profile").unwrap(),
+///     remote: None,
 /// });
 ///
 /// assert_eq!(lxd_config.provider(), Provider::Lxd);
@@ -62,6 +69,18 @@ pub enum ProviderConfig {
     /// Hetzner provider configuration
     #[serde(rename = "hetzner")]
     Hetzner(HetznerConfig),
+
+    /// `DigitalOcean` provider configuration
+    #[serde(rename = "digitalocean")]
+    DigitalOcean(DigitalOceanConfig),
+
+    /// Docker provider configuration (testing only)
+    #[serde(rename = "docker")]
+    Docker(DockerConfig),
+
+    /// Libvirt provider configuration
+    #[serde(rename = "libvirt")]
+    Libvirt(LibvirtConfig),
 }
 
 impl ProviderConfig {
@@ -75,6 +94,7 @@ impl ProviderConfig {
     ///
     /// let config = ProviderConfig::Lxd(LxdConfig {
     ///     profile_name: ProfileName::new("test").unwrap(),
+    ///     remote: None,
     /// });
     /// assert_eq!(config.provider(), Provider::Lxd);
     /// ```
@@ -83,6 +103,9 @@ impl ProviderConfig {
         match self {
             Self::Lxd(_) => Provider::Lxd,
             Self::Hetzner(_) => Provider::Hetzner,
+            Self::DigitalOcean(_) => Provider::DigitalOcean,
+            Self::Docker(_) => Provider::Docker,
+            Self::Libvirt(_) => Provider::Libvirt,
         }
     }
 
@@ -98,6 +121,7 @@ impl ProviderConfig {
     ///
     /// let config = ProviderConfig::Lxd(LxdConfig {
     ///     profile_name: ProfileName::new("test").unwrap(),
+    ///     remote: None,
     /// });
     /// assert_eq!(config.provider_name(), "lxd");
     /// ```
@@ -120,6 +144,7 @@ impl ProviderConfig {
     ///
     /// let lxd_config = ProviderConfig::Lxd(LxdConfig {
     ///     profile_name: ProfileName::new("test").unwrap(),
+    ///     remote: None,
     /// });
     /// assert_eq!(lxd_config.provider_display_name(), "LXD");
     ///
@@ -136,6 +161,9 @@ impl ProviderConfig {
         match self {
             Self::Lxd(_) => "LXD",
             Self::Hetzner(_) => "Hetzner Cloud",
+            Self::DigitalOcean(_) => "DigitalOcean",
+            Self::Docker(_) => "Docker",
+            Self::Libvirt(_) => "libvirt",
         }
     }
 
@@ -155,6 +183,7 @@ impl ProviderConfig {
     ///
     /// let lxd_config = ProviderConfig::Lxd(LxdConfig {
     ///     profile_name: ProfileName::new("test").unwrap(),
+    ///     remote: None,
     /// });
     /// assert!(lxd_config.as_lxd().is_some());
     ///
@@ -170,7 +199,7 @@ impl ProviderConfig {
     pub fn as_lxd(&self) -> Option<&LxdConfig> {
         match self {
             Self::Lxd(config) => Some(config),
-            Self::Hetzner(_) => None,
+            Self::Hetzner(_) | Self::DigitalOcean(_) | Self::Docker(_) | Self::Libvirt(_) => None,
         }
     }
 
@@ -183,8 +212,50 @@ impl ProviderConfig {
     #[must_use]
     pub fn as_hetzner(&self) -> Option<&HetznerConfig> {
         match self {
-            Self::Lxd(_) => None,
             Self::Hetzner(config) => Some(config),
+            Self::Lxd(_) | Self::DigitalOcean(_) | Self::Docker(_) | Self::Libvirt(_) => None,
+        }
+    }
+
+    /// Returns a reference to the `DigitalOcean` configuration if this is a `DigitalOcean` provider.
+    ///
+    /// # Returns
+    ///
+    /// - `Some(&DigitalOceanConfig)` if the provider is `DigitalOcean`
+    /// - `None` otherwise
+    #[must_use]
+    pub fn as_digitalocean(&self) -> Option<&DigitalOceanConfig> {
+        match self {
+            Self::DigitalOcean(config) => Some(config),
+            Self::Lxd(_) | Self::Hetzner(_) | Self::Docker(_) | Self::Libvirt(_) => None,
+        }
+    }
+
+    /// Returns a reference to the Docker configuration if this is a Docker provider.
+    ///
+    /// # Returns
+    ///
+    /// - `Some(&DockerConfig)` if the provider is Docker
+    /// - `None` otherwise
+    #[must_use]
+    pub fn as_docker(&self) -> Option<&DockerConfig> {
+        match self {
+            Self::Docker(config) => Some(config),
+            Self::Lxd(_) | Self::Hetzner(_) | Self::DigitalOcean(_) | Self::Libvirt(_) => None,
+        }
+    }
+
+    /// Returns a reference to the libvirt configuration if this is a libvirt provider.
+    ///
+    /// # Returns
+    ///
+    /// - `Some(&LibvirtConfig)` if the provider is libvirt
+    /// - `None` otherwise
+    #[must_use]
+    pub fn as_libvirt(&self) -> Option<&LibvirtConfig> {
+        match self {
+            Self::Libvirt(config) => Some(config),
+            Self::Lxd(_) | Self::Hetzner(_) | Self::DigitalOcean(_) | Self::Docker(_) => None,
         }
     }
 }
@@ -197,6 +268,7 @@ mod tests {
     fn create_lxd_config() -> ProviderConfig {
         ProviderConfig::Lxd(LxdConfig {
             profile_name: ProfileName::new("torrust-profile").unwrap(),
+            remote: None,
         })
     }
 
@@ -284,6 +356,45 @@ mod tests {
         assert_eq!(hetzner.image, "ubuntu-24.04");
     }
 
+    #[test]
+    fn it_should_deserialize_digitalocean_config_from_json_with_provider_tag() {
+        let json = r#"{"provider":"digitalocean","api_token":"token","droplet_size":"s-1vcpu-2gb","region":"fra1","image":"ubuntu-24-04-x64"}"#;
+        let config: ProviderConfig = serde_json::from_str(json).unwrap();
+
+        assert_eq!(config.provider(), Provider::DigitalOcean);
+        assert_eq!(config.provider_display_name(), "DigitalOcean");
+        let digitalocean = config.as_digitalocean().unwrap();
+        assert_eq!(digitalocean.droplet_size, "s-1vcpu-2gb");
+        assert_eq!(digitalocean.region, "fra1");
+        assert_eq!(digitalocean.reserved_ip, None);
+        assert!(config.as_hetzner().is_none());
+    }
+
+    #[test]
+    fn it_should_deserialize_docker_config_from_json_with_provider_tag() {
+        let json = r#"{"provider":"docker","image":"torrust-provisioned-instance:latest"}"#;
+        let config: ProviderConfig = serde_json::from_str(json).unwrap();
+
+        assert_eq!(config.provider(), Provider::Docker);
+        assert_eq!(config.provider_display_name(), "Docker");
+        let docker = config.as_docker().unwrap();
+        assert_eq!(docker.image, "torrust-provisioned-instance:latest");
+        assert!(config.as_lxd().is_none());
+    }
+
+    #[test]
+    fn it_should_deserialize_libvirt_config_from_json_with_provider_tag() {
+        let json = r#"{"provider":"libvirt","pool":"default","network_name":"default","image":"/var/lib/libvirt/images/noble.img"}"#;
+        let config: ProviderConfig = serde_json::from_str(json).unwrap();
+
+        assert_eq!(config.provider(), Provider::Libvirt);
+        assert_eq!(config.provider_display_name(), "libvirt");
+        let libvirt = config.as_libvirt().unwrap();
+        assert_eq!(libvirt.network_name, "default");
+        assert_eq!(libvirt.image, "/var/lib/libvirt/images/noble.img");
+        assert!(config.as_lxd().is_none());
+    }
+
     #[test]
     fn it_should_be_cloneable_when_cloned() {
         let config = create_lxd_config();
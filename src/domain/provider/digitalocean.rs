@@ -0,0 +1,108 @@
+//! `DigitalOcean` Provider Domain Types
+//!
+//! This module contains domain types specific to the `DigitalOcean` provider.
+//! `DigitalOcean` is a public cloud for production deployments, with droplets
+//! available in regions across America, Europe and Asia.
+
+use std::net::Ipv4Addr;
+
+use serde::{Deserialize, Serialize};
+
+use crate::shared::ApiToken;
+
+/// `DigitalOcean`-specific configuration (Domain Type)
+///
+/// The instance is a droplet placed behind a `DigitalOcean` cloud firewall.
+/// With a reserved IP, the droplet is reached through that address instead
+/// of its own public IPv4, so DNS records survive re-provisioning.
+///
+/// # Examples
+///
+/// ```rust
+/// use torrust_tracker_deployer_lib::domain::provider::DigitalOceanConfig;
+/// use torrust_tracker_deployer_lib::shared::secrets::ApiToken;
+///
+/// let config = DigitalOceanConfig {
+///     api_token: ApiToken::from("your-api-token"),
+///     droplet_size: "s-1vcpu-2gb".to_string(),
+///     region: "fra1".to_string(),
+///     image: "ubuntu-24-04-x64".to_string(),
+///     reserved_ip: None,
+/// };
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct DigitalOceanConfig {
+    /// `DigitalOcean` API token for authentication.
+    ///
+    /// This value is kept secure and not exposed in debug output.
+    pub api_token: ApiToken,
+
+    /// Droplet size slug (e.g., "s-1vcpu-2gb", "s-2vcpu-4gb").
+    ///
+    /// Determines the droplet specifications (CPU, RAM, storage).
+    pub droplet_size: String,
+
+    /// `DigitalOcean` region slug (e.g., "fra1", "ams3", "nyc3").
+    ///
+    /// Determines where the droplet will be physically located.
+    pub region: String,
+
+    /// Operating system image slug (e.g., "ubuntu-24-04-x64").
+    pub image: String,
+
+    /// Existing reserved IP to assign to the droplet.
+    ///
+    /// It must belong to the account and be in the droplet's region. The
+    /// reserved IP itself is not created or deleted by the deployer, only
+    /// assigned on provision and released on destroy.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub reserved_ip: Option<Ipv4Addr>,
+}
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+
+    fn create_digitalocean_config() -> DigitalOceanConfig {
+        DigitalOceanConfig {
+            api_token: ApiToken::from("test-token"),
+            droplet_size: "s-1vcpu-2gb".to_string(),
+            region: "fra1".to_string(),
+            image: "ubuntu-24-04-x64".to_string(),
+            reserved_ip: None,
+        }
+    }
+
+    #[test]
+    fn it_should_serialize_to_json_without_reserved_ip_when_none() {
+        let config = create_digitalocean_config();
+        let json = serde_json::to_string(&config).unwrap();
+
+        assert!(json.contains("\"api_token\":\"test-token\""));
+        assert!(json.contains("\"droplet_size\":\"s-1vcpu-2gb\""));
+        assert!(json.contains("\"region\":\"fra1\""));
+        assert!(json.contains("\"image\":\"ubuntu-24-04-x64\""));
+        assert!(!json.contains("reserved_ip"));
+    }
+
+    #[test]
+    fn it_should_deserialize_from_json_with_reserved_ip() {
+        let json = r#"{"api_token":"token","droplet_size":"s-1vcpu-2gb","region":"fra1","image":"ubuntu-24-04-x64","reserved_ip":"203.0.113.10"}"#;
+        let config: DigitalOceanConfig = serde_json::from_str(json).unwrap();
+
+        assert_eq!(config.api_token.expose_secret(), "token");
+        assert_eq!(config.region, "fra1");
+        assert_eq!(config.reserved_ip, Some(Ipv4Addr::new(203, 0, 113, 10)));
+    }
+
+    #[test]
+    fn it_should_redact_the_api_token_in_debug_output() {
+        let config = create_digitalocean_config();
+        let debug = format!("{config:?}");
+
+        assert!(debug.contains("DigitalOceanConfig"));
+        assert!(debug.contains("[REDACTED]"));
+        assert!(!debug.contains("test-token"));
+    }
+}
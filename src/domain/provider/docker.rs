@@ -0,0 +1,48 @@
+//! Docker Provider Domain Types
+//!
+//! This module contains domain types specific to the Docker provider.
+//! Docker runs the instance as a local container instead of a VM. It is meant
+//! for fast smoke tests of templates and configuration, not for production.
+
+use serde::{Deserialize, Serialize};
+
+/// Docker-specific configuration (Domain Type)
+///
+/// The instance is a privileged container of a local image that already
+/// contains the SSH user, sshd and the Docker daemon (see
+/// `docker/provisioned-instance`). SSH is published on a random loopback
+/// port of the host, recorded after provisioning.
+///
+/// # Examples
+///
+/// ```rust
+/// use torrust_tracker_deployer_lib::domain::provider::DockerConfig;
+///
+/// let config = DockerConfig {
+///     image: "torrust-provisioned-instance:latest".to_string(),
+/// };
+/// assert_eq!(config.image, "torrust-provisioned-instance:latest");
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct DockerConfig {
+    /// Local Docker image of the instance (e.g., "torrust-provisioned-instance:latest").
+    ///
+    /// The image is not pulled nor removed by the deployer; it must be built
+    /// beforehand.
+    pub image: String,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn it_should_serialize_to_json() {
+        let config = DockerConfig {
+            image: "torrust-provisioned-instance:latest".to_string(),
+        };
+        let json = serde_json::to_string(&config).unwrap();
+
+        assert_eq!(json, r#"{"image":"torrust-provisioned-instance:latest"}"#);
+    }
+}
@@ -0,0 +1,86 @@
+//! Libvirt Provider Domain Types
+//!
+//! This module contains domain types specific to the libvirt provider.
+//! Libvirt runs the instance as a local KVM virtual machine. Like LXD, it is
+//! meant for local development and testing, on hosts where LXD is not
+//! available (e.g. Fedora).
+
+use serde::{Deserialize, Serialize};
+
+/// Storage pool the instance disks are created in when none is configured
+pub const DEFAULT_LIBVIRT_POOL: &str = "default";
+
+/// Network the instance is attached to when none is configured
+pub const DEFAULT_LIBVIRT_NETWORK: &str = "default";
+
+/// Cloud image the instance disk is created from when none is configured
+pub const DEFAULT_LIBVIRT_IMAGE: &str =
+    "https://cloud-images.ubuntu.com/noble/current/noble-server-cloudimg-amd64.img";
+
+/// Connection URI of the system libvirt daemon the instance is created on
+pub const LIBVIRT_URI: &str = "qemu:///system";
+
+/// Libvirt-specific configuration (Domain Type)
+///
+/// The instance is a KVM domain of the system libvirt daemon
+/// (`qemu:///system`). Its disk is a copy-on-write volume on top of a cloud
+/// image, and cloud-init receives its configuration from an attached ISO.
+///
+/// # Examples
+///
+/// ```rust
+/// use torrust_tracker_deployer_lib::domain::provider::LibvirtConfig;
+///
+/// let config = LibvirtConfig::default();
+/// assert_eq!(config.pool, "default");
+/// assert_eq!(config.network_name, "default");
+/// assert!(config.image.ends_with("noble-server-cloudimg-amd64.img"));
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct LibvirtConfig {
+    /// Storage pool the base image, the instance disk and the cloud-init ISO
+    /// are created in (e.g., "default").
+    pub pool: String,
+
+    /// Libvirt network the instance is attached to (e.g., "default").
+    ///
+    /// The network must exist and hand out addresses with DHCP: the instance
+    /// address is read from its leases.
+    pub network_name: String,
+
+    /// Cloud image the instance disk is created from.
+    ///
+    /// Either an absolute path on the host or an HTTP(S) URL, downloaded by
+    /// the libvirt provider on every provision.
+    pub image: String,
+}
+
+impl Default for LibvirtConfig {
+    fn default() -> Self {
+        Self {
+            pool: DEFAULT_LIBVIRT_POOL.to_string(),
+            network_name: DEFAULT_LIBVIRT_NETWORK.to_string(),
+            image: DEFAULT_LIBVIRT_IMAGE.to_string(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn it_should_serialize_to_json() {
+        let config = LibvirtConfig {
+            pool: "images".to_string(),
+            network_name: "torrust".to_string(),
+            image: "/var/lib/libvirt/images/noble.img".to_string(),
+        };
+        let json = serde_json::to_string(&config).unwrap();
+
+        assert_eq!(
+            json,
+            r#"{"pool":"images","network_name":"torrust","image":"/var/lib/libvirt/images/noble.img"}"#
+        );
+    }
+}
@@ -3,10 +3,20 @@
 //! This module contains domain types specific to the LXD provider.
 //! LXD is used for local development and testing, providing fast VM creation
 //! with no cloud costs, ideal for E2E tests and CI environments.
+//!
+//! The instance is created on the local LXD daemon unless an [`LxdRemote`]
+//! names another LXD server, such as a shared host.
+
+use std::path::PathBuf;
 
 use serde::{Deserialize, Serialize};
+use thiserror::Error;
 
 use crate::domain::ProfileName;
+use crate::shared::secrets::Password;
+
+/// Image the LXD instance is created from, downloaded from the Ubuntu image server
+pub const DEFAULT_LXD_IMAGE: &str = "ubuntu:24.04";
 
 /// LXD-specific configuration (Domain Type)
 ///
@@ -23,6 +33,7 @@ use crate::domain::ProfileName;
 ///
 /// let config = LxdConfig {
 ///     profile_name: ProfileName::new("torrust-profile-dev").unwrap(),
+///     remote: None,
 /// };
 /// assert_eq!(config.profile_name.as_str(), "torrust-profile-dev");
 /// ```
@@ -33,6 +44,152 @@ pub struct LxdConfig {
     /// This profile must exist in LXD and typically configures
     /// networking, storage, and resource limits.
     pub profile_name: ProfileName,
+
+    /// LXD server the instance is created on, when it is not the local daemon
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub remote: Option<LxdRemote>,
+}
+
+impl LxdConfig {
+    /// Returns the name of the remote LXD server, if any
+    #[must_use]
+    pub fn remote_name(&self) -> Option<&str> {
+        self.remote.as_ref().map(LxdRemote::name)
+    }
+}
+
+/// Errors that can occur when creating an `LxdRemote`
+#[derive(Debug, Clone, PartialEq, Eq, Error)]
+pub enum LxdRemoteError {
+    /// The name cannot be used as an LXD remote name
+    #[error("invalid LXD remote name '{0}': it must be non-empty, must not be 'local' and may only contain letters, digits, '-', '_' and '.'")]
+    InvalidName(String),
+
+    /// The address is not an HTTPS URL
+    #[error("invalid LXD remote address '{0}': expected an HTTPS URL such as https://lxd.example.com:8443")]
+    InvalidAddress(String),
+
+    /// The trust token is empty
+    #[error("the LXD remote trust token cannot be empty")]
+    EmptyTrustToken,
+}
+
+/// An LXD server other than the local daemon (Domain Type)
+///
+/// The remote is known to the LXD client by its name, as listed by
+/// `lxc remote list`. A remote that is not configured yet is added with its
+/// trust token, issued on the server with `lxc config trust add`; the client
+/// certificate is then trusted by the server.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct LxdRemote {
+    /// Name of the remote in the LXD client configuration (e.g. `bigbox`)
+    name: String,
+
+    /// HTTPS address of the LXD server API (e.g. `https://bigbox:8443`)
+    ///
+    /// Only needed when the remote is not configured in the LXD client.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    address: Option<String>,
+
+    /// Directory of the LXD client configuration
+    ///
+    /// Holds the remotes (`config.yml`) and the client certificate
+    /// (`client.crt` and `client.key`). The default directory of the LXD
+    /// client is used when unset.
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        with = "crate::shared::portable_path::option"
+    )]
+    config_dir: Option<PathBuf>,
+
+    /// One-time token the server issued to trust this client
+    ///
+    /// Never serialized: the environment state file must not contain the
+    /// token. It is persisted separately as part of the
+    /// `EnvironmentSecrets` and restored with `restore_trust_token`.
+    #[serde(skip)]
+    trust_token: Option<Password>,
+}
+
+impl LxdRemote {
+    /// Creates a validated LXD remote
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the name is empty, is `local` (the local daemon)
+    /// or holds characters LXD does not accept in remote names, if the
+    /// address is not an HTTPS URL, or if the trust token is empty.
+    pub fn new(
+        name: impl Into<String>,
+        address: Option<String>,
+        config_dir: Option<PathBuf>,
+        trust_token: Option<Password>,
+    ) -> Result<Self, LxdRemoteError> {
+        let name = name.into();
+
+        let valid_name = !name.is_empty()
+            && name != "local"
+            && name
+                .chars()
+                .all(|c| c.is_ascii_alphanumeric() || matches!(c, '-' | '_' | '.'));
+        if !valid_name {
+            return Err(LxdRemoteError::InvalidName(name));
+        }
+
+        if let Some(address) = &address {
+            let host = address.strip_prefix("https://").unwrap_or_default();
+            if host.is_empty() || host.contains(char::is_whitespace) {
+                return Err(LxdRemoteError::InvalidAddress(address.clone()));
+            }
+        }
+
+        if trust_token
+            .as_ref()
+            .is_some_and(|token| token.expose_secret().is_empty())
+        {
+            return Err(LxdRemoteError::EmptyTrustToken);
+        }
+
+        Ok(Self {
+            name,
+            address,
+            config_dir,
+            trust_token,
+        })
+    }
+
+    /// Returns the name of the remote in the LXD client configuration
+    #[must_use]
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    /// Returns the HTTPS address of the LXD server API, if given
+    #[must_use]
+    pub fn address(&self) -> Option<&str> {
+        self.address.as_deref()
+    }
+
+    /// Returns the directory of the LXD client configuration, if given
+    #[must_use]
+    pub fn config_dir(&self) -> Option<&PathBuf> {
+        self.config_dir.as_ref()
+    }
+
+    /// Returns the trust token, if given
+    #[must_use]
+    pub fn trust_token(&self) -> Option<&Password> {
+        self.trust_token.as_ref()
+    }
+
+    /// Restores the trust token after loading the remote
+    ///
+    /// The token is not part of the serialized remote, so repositories call
+    /// this with the separately persisted secret.
+    pub fn restore_trust_token(&mut self, trust_token: Password) {
+        self.trust_token = Some(trust_token);
+    }
 }
 
 #[cfg(test)]
@@ -44,6 +201,7 @@ mod tests {
         let profile_name = ProfileName::new("test-profile").unwrap();
         let config = LxdConfig {
             profile_name: profile_name.clone(),
+            remote: None,
         };
         assert_eq!(config.profile_name, profile_name);
     }
@@ -52,6 +210,7 @@ mod tests {
     fn it_should_serialize_to_json_when_valid_config_exists() {
         let config = LxdConfig {
             profile_name: ProfileName::new("torrust-profile").unwrap(),
+            remote: None,
         };
         let json = serde_json::to_string(&config).unwrap();
 
@@ -70,6 +229,7 @@ mod tests {
     fn it_should_be_cloneable_when_cloned() {
         let config = LxdConfig {
             profile_name: ProfileName::new("test").unwrap(),
+            remote: None,
         };
         let cloned = config.clone();
         assert_eq!(config, cloned);
@@ -79,9 +239,63 @@ mod tests {
     fn it_should_implement_debug_trait_when_formatted() {
         let config = LxdConfig {
             profile_name: ProfileName::new("test").unwrap(),
+            remote: None,
         };
         let debug = format!("{config:?}");
         assert!(debug.contains("LxdConfig"));
         assert!(debug.contains("profile_name"));
     }
+
+    #[test]
+    fn it_should_not_serialize_the_trust_token_of_a_remote() {
+        let remote = LxdRemote::new(
+            "bigbox",
+            Some("https://bigbox:8443".to_string()),
+            None,
+            Some(Password::new("eyJjbGllbnRfbmFtZSI6")),
+        )
+        .unwrap();
+        let config = LxdConfig {
+            profile_name: ProfileName::new("torrust-profile").unwrap(),
+            remote: Some(remote),
+        };
+
+        let json = serde_json::to_string(&config).unwrap();
+
+        assert!(
+            json.contains("\"remote\":{\"name\":\"bigbox\",\"address\":\"https://bigbox:8443\"}")
+        );
+        assert!(!json.contains("eyJjbGllbnRfbmFtZSI6"));
+    }
+
+    #[test]
+    fn it_should_reject_the_local_daemon_as_remote_name() {
+        assert_eq!(
+            LxdRemote::new("local", None, None, None),
+            Err(LxdRemoteError::InvalidName("local".to_string()))
+        );
+        assert!(LxdRemote::new("big box", None, None, None).is_err());
+    }
+
+    #[test]
+    fn it_should_reject_a_remote_address_that_is_not_https() {
+        assert_eq!(
+            LxdRemote::new("bigbox", Some("bigbox:8443".to_string()), None, None),
+            Err(LxdRemoteError::InvalidAddress("bigbox:8443".to_string()))
+        );
+    }
+
+    #[test]
+    fn it_should_store_the_client_configuration_directory_with_forward_slashes() {
+        use crate::shared::portable_path::to_portable_string;
+
+        let config_dir = PathBuf::from("lxd").join("bigbox").join("client");
+        let remote = LxdRemote::new("bigbox", None, Some(config_dir.clone()), None).unwrap();
+
+        let json = serde_json::to_string(&remote).unwrap();
+        let loaded: LxdRemote = serde_json::from_str(&json).unwrap();
+
+        assert!(json.contains(&to_portable_string(&config_dir)));
+        assert_eq!(loaded, remote);
+    }
 }
@@ -8,15 +8,20 @@
 //! Each provider has its own submodule for extensibility:
 //! - `lxd` - LXD local development provider configuration
 //! - `hetzner` - Hetzner cloud production provider configuration
+//! - `digitalocean` - `DigitalOcean` cloud production provider configuration
+//! - `docker` - Local Docker container provider configuration (testing only)
+//! - `libvirt` - Local libvirt/KVM development provider configuration
 //!
 //! # Layer Separation
 //!
-//! - **Domain types** (this module): `Provider`, `ProviderConfig`, `LxdConfig`, `HetznerConfig`
+//! - **Domain types** (this module): `Provider`, `ProviderConfig`, `LxdConfig`, `HetznerConfig`,
+//!   `DigitalOceanConfig`, `DockerConfig`, `LibvirtConfig`
 //!   - Use validated domain types (e.g., `ProfileName`)
 //!   - Represent semantic meaning of configuration
 //!
 //! - **Application config types** (`application::command_handlers::create::config::provider`):
-//!   - `ProviderSection`, `LxdProviderSection`, `HetznerProviderSection`
+//!   - `ProviderSection`, `LxdProviderSection`, `HetznerProviderSection`,
+//!     `DigitalOceanProviderSection`, `DockerProviderSection`, `LibvirtProviderSection`
 //!   - Use raw primitives (e.g., `String`)
 //!   - Handle JSON deserialization and conversion to domain types
 //!
@@ -29,6 +34,7 @@
 //! // Create a provider configuration
 //! let config = ProviderConfig::Lxd(LxdConfig {
 //!     profile_name: ProfileName::new("torrust-profile").unwrap(),
+//!     remote: None,
 //! });
 //!
 //! // Access provider information
@@ -37,11 +43,20 @@
 //! ```
 
 mod config;
+mod digitalocean;
+mod docker;
 mod hetzner;
+mod libvirt;
 mod lxd;
 mod provider_type;
 
 pub use config::ProviderConfig;
+pub use digitalocean::DigitalOceanConfig;
+pub use docker::DockerConfig;
 pub use hetzner::HetznerConfig;
-pub use lxd::LxdConfig;
+pub use libvirt::{
+    LibvirtConfig, DEFAULT_LIBVIRT_IMAGE, DEFAULT_LIBVIRT_NETWORK, DEFAULT_LIBVIRT_POOL,
+    LIBVIRT_URI,
+};
+pub use lxd::{LxdConfig, LxdRemote, LxdRemoteError, DEFAULT_LXD_IMAGE};
 pub use provider_type::Provider;
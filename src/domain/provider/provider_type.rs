@@ -6,6 +6,8 @@
 use clap::ValueEnum;
 use serde::{Deserialize, Serialize};
 
+use crate::domain::{EnvironmentName, EnvironmentNameError, InstanceName};
+
 /// Supported infrastructure providers
 ///
 /// This enum represents the available infrastructure providers for deploying
@@ -18,6 +20,12 @@ use serde::{Deserialize, Serialize};
 ///   cloud costs, ideal for E2E tests and CI environments.
 /// - **Hetzner**: Production cloud provider. Cost-effective with good European
 ///   presence, suitable for production deployments.
+/// - **DigitalOcean**: Production cloud provider with regions across America,
+///   Europe and Asia, and reserved IPs for stable addresses.
+/// - **Docker**: Testing-only provider running the instance as a local
+///   container. Deploys in seconds, for smoke tests of templates and configuration.
+/// - **Libvirt**: Local development and testing provider running a KVM
+///   virtual machine, for hosts where LXD is not available.
 ///
 /// # Examples
 ///
@@ -35,9 +43,33 @@ pub enum Provider {
     Lxd,
     /// Hetzner Cloud - Production deployments
     Hetzner,
+    /// `DigitalOcean` - Production deployments
+    #[value(name = "digitalocean")]
+    DigitalOcean,
+    /// Docker - Local container for fast smoke tests (testing only)
+    Docker,
+    /// Libvirt - Local KVM virtual machine for development and testing
+    Libvirt,
 }
 
 impl Provider {
+    /// Maximum length of an environment name whose generated instance name
+    /// (`torrust-tracker-vm-{env_name}`) the providers accept
+    ///
+    /// The same for every provider: all of them use the instance name as host
+    /// name, so it must fit in a single DNS label
+    /// ([`InstanceName::MAX_LENGTH`] characters).
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use torrust_tracker_deployer_lib::domain::provider::Provider;
+    ///
+    /// assert_eq!(Provider::MAX_ENVIRONMENT_NAME_LENGTH, 44);
+    /// ```
+    pub const MAX_ENVIRONMENT_NAME_LENGTH: usize =
+        InstanceName::MAX_LENGTH - InstanceName::GENERATED_PREFIX.len();
+
     /// Returns the provider name as used in directory paths.
     ///
     /// This is used to construct paths like `templates/tofu/{provider}/`.
@@ -49,14 +81,70 @@ impl Provider {
     ///
     /// assert_eq!(Provider::Lxd.as_str(), "lxd");
     /// assert_eq!(Provider::Hetzner.as_str(), "hetzner");
+    /// assert_eq!(Provider::DigitalOcean.as_str(), "digitalocean");
+    /// assert_eq!(Provider::Docker.as_str(), "docker");
+    /// assert_eq!(Provider::Libvirt.as_str(), "libvirt");
     /// ```
     #[must_use]
     pub fn as_str(&self) -> &'static str {
         match self {
             Self::Lxd => "lxd",
             Self::Hetzner => "hetzner",
+            Self::DigitalOcean => "digitalocean",
+            Self::Docker => "docker",
+            Self::Libvirt => "libvirt",
         }
     }
+
+    /// Returns the TCP ports the provider itself uses on the instance's
+    /// addresses, with what uses them
+    ///
+    /// SSH cannot be moved to one of these ports: the instance would be
+    /// unreachable once cloud-init applies the port.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use torrust_tracker_deployer_lib::domain::provider::Provider;
+    ///
+    /// assert!(Provider::Lxd.reserved_ports().iter().any(|(port, _)| *port == 8443));
+    /// assert!(Provider::Hetzner.reserved_ports().is_empty());
+    /// ```
+    #[must_use]
+    pub fn reserved_ports(&self) -> &'static [(u16, &'static str)] {
+        match self {
+            Self::Lxd => &[(8443, "LXD API and proxy devices")],
+            Self::Hetzner | Self::DigitalOcean | Self::Docker | Self::Libvirt => &[],
+        }
+    }
+
+    /// Checks that the instance name generated for `env_name` fits in a
+    /// host name
+    ///
+    /// The limit is [`Self::MAX_ENVIRONMENT_NAME_LENGTH`] for every provider;
+    /// the error names this one, the provider the user chose.
+    ///
+    /// # Errors
+    ///
+    /// Returns `EnvironmentNameError::TooLong`, explaining the character
+    /// budget, if the environment name is longer than
+    /// [`Self::MAX_ENVIRONMENT_NAME_LENGTH`].
+    pub fn check_environment_name_length(
+        &self,
+        env_name: &EnvironmentName,
+    ) -> Result<(), EnvironmentNameError> {
+        let max_length = Self::MAX_ENVIRONMENT_NAME_LENGTH;
+
+        env_name.check_max_length(
+            max_length,
+            self.as_str(),
+            &format!(
+                "instance names are generated as '{}{{env_name}}' and limited to {} characters, which leaves {max_length} characters for the environment name",
+                InstanceName::GENERATED_PREFIX,
+                InstanceName::MAX_LENGTH
+            ),
+        )
+    }
 }
 
 impl std::fmt::Display for Provider {
@@ -73,6 +161,67 @@ mod tests {
     fn it_should_return_lowercase_string_when_as_str_called() {
         assert_eq!(Provider::Lxd.as_str(), "lxd");
         assert_eq!(Provider::Hetzner.as_str(), "hetzner");
+        assert_eq!(Provider::DigitalOcean.as_str(), "digitalocean");
+        assert_eq!(Provider::Docker.as_str(), "docker");
+        assert_eq!(Provider::Libvirt.as_str(), "libvirt");
+    }
+
+    #[test]
+    fn it_should_accept_an_environment_name_of_exactly_the_lxd_budget() {
+        let env_name = EnvironmentName::new("a".repeat(44)).unwrap();
+
+        assert!(Provider::Lxd
+            .check_environment_name_length(&env_name)
+            .is_ok());
+    }
+
+    #[test]
+    fn it_should_reject_an_environment_name_one_character_over_the_lxd_budget() {
+        let env_name = EnvironmentName::new("a".repeat(45)).unwrap();
+
+        let result = Provider::Lxd.check_environment_name_length(&env_name);
+
+        assert!(matches!(
+            result,
+            Err(EnvironmentNameError::TooLong {
+                length: 45,
+                max_length: 44,
+                ..
+            })
+        ));
+    }
+
+    #[test]
+    fn it_should_explain_the_character_budget_when_the_environment_name_is_too_long() {
+        let env_name = EnvironmentName::new("a".repeat(60)).unwrap();
+
+        let message = Provider::Lxd
+            .check_environment_name_length(&env_name)
+            .unwrap_err()
+            .to_string();
+
+        assert!(message.contains("is 60 characters long"));
+        assert!(message.contains("the lxd provider allows at most 44"));
+        assert!(message.contains("'torrust-tracker-vm-{env_name}'"));
+        assert!(message.contains("limited to 63 characters"));
+    }
+
+    #[test]
+    fn it_should_name_the_chosen_provider_when_the_environment_name_is_too_long() {
+        let env_name = EnvironmentName::new("a".repeat(60)).unwrap();
+
+        for provider in Provider::value_variants() {
+            let result = provider.check_environment_name_length(&env_name);
+
+            assert!(
+                matches!(
+                    &result,
+                    Err(EnvironmentNameError::TooLong { max_length: 44, provider: name, .. })
+                        if name == provider.as_str()
+                ),
+                "unexpected result for the {provider} provider: {result:?}"
+            );
+        }
     }
 
     #[test]
@@ -94,9 +243,11 @@ mod tests {
     fn it_should_deserialize_from_lowercase_json_string() {
         let lxd: Provider = serde_json::from_str("\"lxd\"").unwrap();
         let hetzner: Provider = serde_json::from_str("\"hetzner\"").unwrap();
+        let digitalocean: Provider = serde_json::from_str("\"digitalocean\"").unwrap();
 
         assert_eq!(lxd, Provider::Lxd);
         assert_eq!(hetzner, Provider::Hetzner);
+        assert_eq!(digitalocean, Provider::DigitalOcean);
     }
 
     #[test]
@@ -0,0 +1,268 @@
+//! User additions to the generated cloud-init configuration.
+
+use serde::{Deserialize, Serialize};
+use serde_yaml::Value;
+use thiserror::Error;
+
+/// Top-level cloud-config keys written by the deployer itself
+///
+/// They create the SSH user the deployer connects with, so user additions
+/// must not set them.
+pub const DEPLOYER_MANAGED_KEYS: [&str; 5] = [
+    "users",
+    "user",
+    "ssh_authorized_keys",
+    "ssh_keys",
+    "disable_root",
+];
+
+/// Cloud-config keys holding a list that user additions are appended to
+pub const LIST_KEYS: [&str; 3] = ["packages", "runcmd", "write_files"];
+
+/// Additions merged into the cloud-init configuration of new instances
+///
+/// Packages are installed and commands run on the first boot, before the
+/// deployer connects to the instance. `raw_append` holds a YAML mapping of
+/// further cloud-config keys.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct CloudInitCustomization {
+    /// Packages installed on the first boot
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    extra_packages: Vec<String>,
+
+    /// Shell commands run on the first boot
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    extra_runcmd: Vec<String>,
+
+    /// YAML mapping of further cloud-config keys
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    raw_append: Option<String>,
+}
+
+/// Errors that can occur when creating a `CloudInitCustomization`.
+#[derive(Debug, Error, PartialEq, Eq)]
+pub enum CloudInitCustomizationError {
+    /// A package name is empty or contains whitespace
+    #[error("Invalid package name '{name}': expected a name like 'htop' or 'htop=3.3.0-4'")]
+    InvalidPackageName {
+        /// The rejected name
+        name: String,
+    },
+
+    /// A command is empty
+    #[error("Commands in 'extra_runcmd' must not be empty")]
+    EmptyCommand,
+
+    /// `raw_append` is not valid YAML
+    #[error("'raw_append' is not valid YAML: {reason}")]
+    InvalidYaml {
+        /// Parser error
+        reason: String,
+    },
+
+    /// `raw_append` is valid YAML but not a mapping of cloud-config keys
+    #[error("'raw_append' must be a YAML mapping of cloud-config keys")]
+    NotAMapping,
+
+    /// `raw_append` sets a key written by the deployer
+    #[error("'raw_append' must not set '{key}', it is managed by the deployer")]
+    ManagedKey {
+        /// The rejected key
+        key: String,
+    },
+
+    /// `raw_append` sets a list key to something else than a list
+    #[error("'{key}' in 'raw_append' must be a list")]
+    NotAList {
+        /// The rejected key
+        key: String,
+    },
+}
+
+impl CloudInitCustomization {
+    /// Creates validated cloud-init additions.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if a package name is empty or contains whitespace,
+    /// a command is empty, or `raw_append` is not a YAML mapping, sets a key
+    /// in [`DEPLOYER_MANAGED_KEYS`] or sets a key in [`LIST_KEYS`] to
+    /// something else than a list.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use torrust_tracker_deployer_lib::domain::provision::CloudInitCustomization;
+    ///
+    /// let customization = CloudInitCustomization::new(
+    ///     vec!["htop".to_string()],
+    ///     vec!["echo 'Managed by Torrust' > /etc/motd".to_string()],
+    ///     Some("timezone: Europe/Madrid".to_string()),
+    /// )?;
+    /// assert_eq!(customization.extra_packages(), ["htop"]);
+    ///
+    /// assert!(CloudInitCustomization::new(vec![], vec![], Some("users: []".to_string())).is_err());
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn new(
+        extra_packages: Vec<String>,
+        extra_runcmd: Vec<String>,
+        raw_append: Option<String>,
+    ) -> Result<Self, CloudInitCustomizationError> {
+        if let Some(name) = extra_packages
+            .iter()
+            .find(|name| name.is_empty() || name.contains(char::is_whitespace))
+        {
+            return Err(CloudInitCustomizationError::InvalidPackageName { name: name.clone() });
+        }
+
+        if extra_runcmd.iter().any(|command| command.trim().is_empty()) {
+            return Err(CloudInitCustomizationError::EmptyCommand);
+        }
+
+        if let Some(raw_append) = &raw_append {
+            validate_raw_append(raw_append)?;
+        }
+
+        Ok(Self {
+            extra_packages,
+            extra_runcmd,
+            raw_append,
+        })
+    }
+
+    /// Returns the packages installed on the first boot
+    #[must_use]
+    pub fn extra_packages(&self) -> &[String] {
+        &self.extra_packages
+    }
+
+    /// Returns the commands run on the first boot
+    #[must_use]
+    pub fn extra_runcmd(&self) -> &[String] {
+        &self.extra_runcmd
+    }
+
+    /// Returns the YAML mapping of further cloud-config keys, if any
+    #[must_use]
+    pub fn raw_append(&self) -> Option<&str> {
+        self.raw_append.as_deref()
+    }
+
+    /// Whether the generated configuration is used unchanged
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.extra_packages.is_empty() && self.extra_runcmd.is_empty() && self.raw_append.is_none()
+    }
+}
+
+/// Checks that `raw_append` is a mapping that leaves the deployer keys alone
+fn validate_raw_append(raw_append: &str) -> Result<(), CloudInitCustomizationError> {
+    let value: Value =
+        serde_yaml::from_str(raw_append).map_err(|e| CloudInitCustomizationError::InvalidYaml {
+            reason: e.to_string(),
+        })?;
+
+    let Value::Mapping(mapping) = value else {
+        return Err(CloudInitCustomizationError::NotAMapping);
+    };
+
+    for (key, value) in &mapping {
+        let Some(key) = key.as_str() else {
+            return Err(CloudInitCustomizationError::NotAMapping);
+        };
+
+        if DEPLOYER_MANAGED_KEYS.contains(&key) {
+            return Err(CloudInitCustomizationError::ManagedKey {
+                key: key.to_string(),
+            });
+        }
+
+        if LIST_KEYS.contains(&key) && !value.is_sequence() {
+            return Err(CloudInitCustomizationError::NotAList {
+                key: key.to_string(),
+            });
+        }
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn with_raw_append(
+        raw_append: &str,
+    ) -> Result<CloudInitCustomization, CloudInitCustomizationError> {
+        CloudInitCustomization::new(vec![], vec![], Some(raw_append.to_string()))
+    }
+
+    #[test]
+    fn it_should_accept_packages_commands_and_a_mapping() {
+        let customization = CloudInitCustomization::new(
+            vec!["htop".to_string(), "jq=1.7.1-3".to_string()],
+            vec!["touch /root/ready".to_string()],
+            Some("timezone: UTC\npackages: [vim]".to_string()),
+        )
+        .unwrap();
+
+        assert!(!customization.is_empty());
+        assert_eq!(
+            customization.raw_append(),
+            Some("timezone: UTC\npackages: [vim]")
+        );
+    }
+
+    #[test]
+    fn it_should_reject_a_package_name_with_whitespace() {
+        assert_eq!(
+            CloudInitCustomization::new(vec!["htop vim".to_string()], vec![], None),
+            Err(CloudInitCustomizationError::InvalidPackageName {
+                name: "htop vim".to_string()
+            })
+        );
+    }
+
+    #[test]
+    fn it_should_reject_an_empty_command() {
+        assert_eq!(
+            CloudInitCustomization::new(vec![], vec!["  ".to_string()], None),
+            Err(CloudInitCustomizationError::EmptyCommand)
+        );
+    }
+
+    #[test]
+    fn it_should_reject_keys_managed_by_the_deployer() {
+        for key in DEPLOYER_MANAGED_KEYS {
+            assert_eq!(
+                with_raw_append(&format!("{key}: []")),
+                Err(CloudInitCustomizationError::ManagedKey {
+                    key: key.to_string()
+                })
+            );
+        }
+    }
+
+    #[test]
+    fn it_should_reject_a_raw_append_that_is_not_a_mapping() {
+        assert_eq!(
+            with_raw_append("- htop"),
+            Err(CloudInitCustomizationError::NotAMapping)
+        );
+        assert!(matches!(
+            with_raw_append("runcmd: [unclosed"),
+            Err(CloudInitCustomizationError::InvalidYaml { .. })
+        ));
+    }
+
+    #[test]
+    fn it_should_reject_a_list_key_that_is_not_a_list() {
+        assert_eq!(
+            with_raw_append("runcmd: reboot"),
+            Err(CloudInitCustomizationError::NotAList {
+                key: "runcmd".to_string()
+            })
+        );
+    }
+}
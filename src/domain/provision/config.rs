@@ -0,0 +1,120 @@
+//! Provision workflow configuration.
+
+use std::time::Duration;
+
+use serde::{Deserialize, Serialize};
+use thiserror::Error;
+
+use super::IpPreference;
+
+/// Default time to wait for cloud-init to finish, in seconds
+pub const DEFAULT_CLOUD_INIT_TIMEOUT_SECS: u64 = 300;
+
+/// Settings of the provision workflow
+///
+/// Stored with the environment so that every provision run of the
+/// environment uses the same limits.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ProvisionConfig {
+    /// Maximum time to wait for cloud-init to finish, in seconds
+    cloud_init_timeout_secs: u64,
+
+    /// Address family used to reach a dual-stack instance
+    #[serde(default)]
+    ip_preference: IpPreference,
+}
+
+/// Errors that can occur when creating a `ProvisionConfig`.
+#[derive(Debug, Error, PartialEq, Eq)]
+pub enum ProvisionConfigError {
+    /// The cloud-init timeout must be at least one second
+    #[error("Cloud-init timeout must be at least 1 second (got 0)")]
+    ZeroCloudInitTimeout,
+}
+
+impl ProvisionConfig {
+    /// Creates a new provision configuration.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if `cloud_init_timeout_secs` is 0.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::time::Duration;
+    /// use torrust_tracker_deployer_lib::domain::provision::ProvisionConfig;
+    ///
+    /// let config = ProvisionConfig::new(600)?;
+    /// assert_eq!(config.cloud_init_timeout(), Duration::from_secs(600));
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn new(cloud_init_timeout_secs: u64) -> Result<Self, ProvisionConfigError> {
+        if cloud_init_timeout_secs == 0 {
+            return Err(ProvisionConfigError::ZeroCloudInitTimeout);
+        }
+
+        Ok(Self {
+            cloud_init_timeout_secs,
+            ip_preference: IpPreference::default(),
+        })
+    }
+
+    /// Sets the address family used to reach a dual-stack instance.
+    #[must_use]
+    pub const fn with_ip_preference(mut self, ip_preference: IpPreference) -> Self {
+        self.ip_preference = ip_preference;
+        self
+    }
+
+    /// Returns the maximum time to wait for cloud-init to finish.
+    #[must_use]
+    pub const fn cloud_init_timeout(&self) -> Duration {
+        Duration::from_secs(self.cloud_init_timeout_secs)
+    }
+
+    /// Returns the address family used to reach a dual-stack instance.
+    #[must_use]
+    pub const fn ip_preference(&self) -> IpPreference {
+        self.ip_preference
+    }
+}
+
+impl Default for ProvisionConfig {
+    /// Default configuration: wait up to 300 seconds for cloud-init, prefer IPv4
+    fn default() -> Self {
+        Self {
+            cloud_init_timeout_secs: DEFAULT_CLOUD_INIT_TIMEOUT_SECS,
+            ip_preference: IpPreference::default(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn it_should_wait_five_minutes_for_cloud_init_by_default() {
+        assert_eq!(
+            ProvisionConfig::default().cloud_init_timeout(),
+            Duration::from_secs(300)
+        );
+    }
+
+    #[test]
+    fn it_should_load_state_saved_without_an_ip_preference() {
+        let config: ProvisionConfig =
+            serde_json::from_str(r#"{"cloud_init_timeout_secs":600}"#).unwrap();
+
+        assert_eq!(config.ip_preference(), IpPreference::Ipv4);
+    }
+
+    #[test]
+    fn it_should_reject_a_zero_cloud_init_timeout() {
+        assert_eq!(
+            ProvisionConfig::new(0),
+            Err(ProvisionConfigError::ZeroCloudInitTimeout)
+        );
+    }
+}
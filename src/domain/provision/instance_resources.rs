@@ -0,0 +1,158 @@
+//! Resources of the provisioned instance.
+
+use std::ops::RangeInclusive;
+
+use serde::{Deserialize, Serialize};
+use thiserror::Error;
+
+/// Default number of virtual CPUs
+pub const DEFAULT_CPUS: u32 = 2;
+
+/// Default memory limit, in MiB
+pub const DEFAULT_MEMORY_MB: u32 = 2048;
+
+/// Default root disk size, in GiB
+pub const DEFAULT_DISK_GB: u32 = 10;
+
+/// Accepted number of virtual CPUs
+pub const CPUS_RANGE: RangeInclusive<u32> = 1..=64;
+
+/// Accepted memory limit, in MiB (512 MiB to 256 GiB)
+pub const MEMORY_MB_RANGE: RangeInclusive<u32> = 512..=262_144;
+
+/// Accepted root disk size, in GiB
+pub const DISK_GB_RANGE: RangeInclusive<u32> = 5..=2048;
+
+/// CPU, memory and disk of the provisioned instance
+///
+/// Applied by providers that size the instance themselves (LXD). Cloud
+/// providers size it through their server type instead.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct InstanceResources {
+    /// Number of virtual CPUs
+    cpus: u32,
+
+    /// Memory limit, in MiB
+    memory_mb: u32,
+
+    /// Root disk size, in GiB
+    disk_gb: u32,
+}
+
+/// Errors that can occur when creating `InstanceResources`.
+#[derive(Debug, Error, PartialEq, Eq)]
+pub enum InstanceResourcesError {
+    /// The number of CPUs is outside [`CPUS_RANGE`]
+    #[error("cpus must be between {} and {} (got {cpus})", CPUS_RANGE.start(), CPUS_RANGE.end())]
+    CpusOutOfRange { cpus: u32 },
+
+    /// The memory limit is outside [`MEMORY_MB_RANGE`]
+    #[error("memory_mb must be between {} and {} (got {memory_mb})", MEMORY_MB_RANGE.start(), MEMORY_MB_RANGE.end())]
+    MemoryOutOfRange { memory_mb: u32 },
+
+    /// The disk size is outside [`DISK_GB_RANGE`]
+    #[error("disk_gb must be between {} and {} (got {disk_gb})", DISK_GB_RANGE.start(), DISK_GB_RANGE.end())]
+    DiskOutOfRange { disk_gb: u32 },
+}
+
+impl InstanceResources {
+    /// Creates validated instance resources.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if a value is outside its accepted range.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use torrust_tracker_deployer_lib::domain::provision::InstanceResources;
+    ///
+    /// let resources = InstanceResources::new(4, 4096, 40)?;
+    /// assert_eq!(resources.memory_mb(), 4096);
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn new(cpus: u32, memory_mb: u32, disk_gb: u32) -> Result<Self, InstanceResourcesError> {
+        if !CPUS_RANGE.contains(&cpus) {
+            return Err(InstanceResourcesError::CpusOutOfRange { cpus });
+        }
+        if !MEMORY_MB_RANGE.contains(&memory_mb) {
+            return Err(InstanceResourcesError::MemoryOutOfRange { memory_mb });
+        }
+        if !DISK_GB_RANGE.contains(&disk_gb) {
+            return Err(InstanceResourcesError::DiskOutOfRange { disk_gb });
+        }
+
+        Ok(Self {
+            cpus,
+            memory_mb,
+            disk_gb,
+        })
+    }
+
+    /// Returns the number of virtual CPUs.
+    #[must_use]
+    pub const fn cpus(&self) -> u32 {
+        self.cpus
+    }
+
+    /// Returns the memory limit, in MiB.
+    #[must_use]
+    pub const fn memory_mb(&self) -> u32 {
+        self.memory_mb
+    }
+
+    /// Returns the root disk size, in GiB.
+    #[must_use]
+    pub const fn disk_gb(&self) -> u32 {
+        self.disk_gb
+    }
+}
+
+impl Default for InstanceResources {
+    /// Default resources: 2 CPUs, 2048 MiB of memory and a 10 GiB disk
+    fn default() -> Self {
+        Self {
+            cpus: DEFAULT_CPUS,
+            memory_mb: DEFAULT_MEMORY_MB,
+            disk_gb: DEFAULT_DISK_GB,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn it_should_accept_resources_within_the_ranges() {
+        let resources = InstanceResources::new(1, 1024, 20).unwrap();
+
+        assert_eq!(resources.cpus(), 1);
+        assert_eq!(resources.memory_mb(), 1024);
+        assert_eq!(resources.disk_gb(), 20);
+    }
+
+    #[test]
+    fn it_should_reject_zero_cpus() {
+        assert_eq!(
+            InstanceResources::new(0, 2048, 10),
+            Err(InstanceResourcesError::CpusOutOfRange { cpus: 0 })
+        );
+    }
+
+    #[test]
+    fn it_should_reject_too_little_memory() {
+        assert_eq!(
+            InstanceResources::new(2, 256, 10),
+            Err(InstanceResourcesError::MemoryOutOfRange { memory_mb: 256 })
+        );
+    }
+
+    #[test]
+    fn it_should_reject_a_too_large_disk() {
+        assert_eq!(
+            InstanceResources::new(2, 2048, 4096),
+            Err(InstanceResourcesError::DiskOutOfRange { disk_gb: 4096 })
+        );
+    }
+}
@@ -0,0 +1,130 @@
+//! IP address family preference
+//!
+//! A dual-stack instance has both an IPv4 and an IPv6 address. The preference
+//! decides which one the deployer uses to connect over SSH and to build the
+//! service endpoints. The other family is the fallback, so an IPv6-only
+//! instance works with the default preference.
+
+use std::fmt;
+use std::net::{IpAddr, Ipv4Addr, Ipv6Addr};
+use std::str::FromStr;
+
+use serde::{Deserialize, Serialize};
+
+/// Which address family to use when the instance has both
+///
+/// # Examples
+///
+/// ```rust
+/// use std::net::{IpAddr, Ipv4Addr, Ipv6Addr};
+/// use torrust_tracker_deployer_lib::domain::provision::IpPreference;
+///
+/// let ipv4 = Some(Ipv4Addr::new(10, 0, 0, 1));
+/// let ipv6 = Some(Ipv6Addr::LOCALHOST);
+///
+/// assert_eq!(IpPreference::Ipv4.select(ipv4, ipv6), ipv4.map(IpAddr::V4));
+/// assert_eq!(IpPreference::Ipv6.select(ipv4, ipv6), ipv6.map(IpAddr::V6));
+///
+/// // The other family is the fallback
+/// assert_eq!(IpPreference::Ipv4.select(None, ipv6), ipv6.map(IpAddr::V6));
+/// ```
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum IpPreference {
+    /// Use the IPv4 address, fall back to IPv6
+    #[default]
+    Ipv4,
+
+    /// Use the IPv6 address, fall back to IPv4
+    Ipv6,
+}
+
+impl IpPreference {
+    /// Returns the preferred address, or the other one if it is missing
+    #[must_use]
+    pub fn select(self, ipv4: Option<Ipv4Addr>, ipv6: Option<Ipv6Addr>) -> Option<IpAddr> {
+        let ipv4 = ipv4.map(IpAddr::V4);
+        let ipv6 = ipv6.map(IpAddr::V6);
+
+        match self {
+            Self::Ipv4 => ipv4.or(ipv6),
+            Self::Ipv6 => ipv6.or(ipv4),
+        }
+    }
+}
+
+/// Error type for IP preference parsing failures
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct IpPreferenceParseError(String);
+
+impl fmt::Display for IpPreference {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Ipv4 => write!(f, "ipv4"),
+            Self::Ipv6 => write!(f, "ipv6"),
+        }
+    }
+}
+
+impl FromStr for IpPreference {
+    type Err = IpPreferenceParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_lowercase().as_str() {
+            "ipv4" => Ok(Self::Ipv4),
+            "ipv6" => Ok(Self::Ipv6),
+            _ => Err(IpPreferenceParseError(s.to_string())),
+        }
+    }
+}
+
+impl fmt::Display for IpPreferenceParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "Unknown IP preference: '{}'. Expected 'ipv4' or 'ipv6'",
+            self.0
+        )
+    }
+}
+
+impl std::error::Error for IpPreferenceParseError {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn it_should_prefer_ipv4_by_default() {
+        let ipv4 = Ipv4Addr::new(10, 0, 0, 1);
+
+        let selected = IpPreference::default().select(Some(ipv4), Some(Ipv6Addr::LOCALHOST));
+
+        assert_eq!(selected, Some(IpAddr::V4(ipv4)));
+    }
+
+    #[test]
+    fn it_should_fall_back_to_the_other_family() {
+        let ipv4 = Ipv4Addr::new(10, 0, 0, 1);
+
+        assert_eq!(
+            IpPreference::Ipv6.select(Some(ipv4), None),
+            Some(IpAddr::V4(ipv4))
+        );
+        assert_eq!(
+            IpPreference::Ipv4.select(None, Some(Ipv6Addr::LOCALHOST)),
+            Some(IpAddr::V6(Ipv6Addr::LOCALHOST))
+        );
+    }
+
+    #[test]
+    fn it_should_return_none_when_there_is_no_address() {
+        assert_eq!(IpPreference::Ipv4.select(None, None), None);
+    }
+
+    #[test]
+    fn it_should_parse_case_insensitively() {
+        assert_eq!("IPv6".parse::<IpPreference>(), Ok(IpPreference::Ipv6));
+        assert!("ipv5".parse::<IpPreference>().is_err());
+    }
+}
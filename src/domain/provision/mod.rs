@@ -0,0 +1,33 @@
+//! Provisioning domain types
+//!
+//! This module contains domain types for tuning the provision workflow.
+//!
+//! ## Purpose
+//!
+//! The `ProvisionConfig` type holds the validated settings that control how
+//! long the provision command waits for a new instance to become ready, and
+//! which address family (`IpPreference`) is used to reach it.
+//! `InstanceResources` holds the CPU, memory and disk of the instance.
+//! `CloudInitCustomization` holds the user additions to the cloud-init
+//! configuration of new instances. `OfflineMode` replaces the downloads of
+//! a deployment (`ExternalResource`) with artifacts pre-seeded on the local
+//! network. `ProxyConfig` routes them through an outbound HTTP proxy.
+//!
+//! ## See Also
+//!
+//! - Application layer DTOs: `src/application/command_handlers/create/config/provision.rs`
+//! - Cloud-init readiness polling: `src/infrastructure/remote_actions/cloud_init_wait.rs`
+
+pub mod cloud_init;
+pub mod config;
+pub mod instance_resources;
+pub mod ip_preference;
+pub mod offline;
+pub mod proxy;
+
+pub use cloud_init::{CloudInitCustomization, CloudInitCustomizationError};
+pub use config::{ProvisionConfig, ProvisionConfigError};
+pub use instance_resources::{InstanceResources, InstanceResourcesError};
+pub use ip_preference::{IpPreference, IpPreferenceParseError};
+pub use offline::{ExternalResource, OfflineMode, OfflineModeError};
+pub use proxy::{ProxyConfig, ProxyConfigError};
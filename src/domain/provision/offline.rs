@@ -0,0 +1,405 @@
+//! Offline mode for networks without Internet access.
+//!
+//! A deployment downloads a few resources from the Internet: the LXD base
+//! image, Ubuntu packages, the Docker Compose plugin and the container
+//! images of the services. [`ExternalResource`] names them, and
+//! [`OfflineMode`] replaces them with artifacts pre-seeded on the local
+//! network.
+
+use std::fmt;
+use std::path::{Path, PathBuf};
+
+use serde::{Deserialize, Serialize};
+use thiserror::Error;
+use url::Url;
+
+/// Ubuntu package archive used by the base image
+pub const UBUNTU_ARCHIVE_URL: &str = "http://archive.ubuntu.com/ubuntu";
+
+/// Docker Compose plugin downloaded by `install-docker-compose.yml`
+pub const DOCKER_COMPOSE_RELEASE_URL: &str =
+    "https://github.com/docker/compose/releases/download/v2.29.2/docker-compose-linux-x86_64";
+
+/// Prefix of image sources that refer to images already imported into LXD
+const LOCAL_IMAGE_REMOTE: &str = "local:";
+
+/// A resource a deployment downloads from the Internet
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ExternalResource {
+    /// Base image of the LXD instance, e.g. `ubuntu:24.04`
+    BaseImage {
+        /// Image source as given to LXD
+        image: String,
+    },
+
+    /// Ubuntu packages installed by the configure command (Docker, UFW, ...)
+    UbuntuPackages,
+
+    /// Packages of the `cloud_init` section, installed on the first boot
+    FirstBootPackages {
+        /// The package names
+        packages: Vec<String>,
+    },
+
+    /// Docker Compose plugin release
+    DockerCompose,
+
+    /// Container image of a service, pulled from Docker Hub
+    ContainerImage {
+        /// Full image reference, e.g. `mysql:8.4`
+        image: String,
+    },
+}
+
+impl fmt::Display for ExternalResource {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::BaseImage { image } => write!(f, "LXD base image '{image}'"),
+            Self::UbuntuPackages => write!(f, "Ubuntu packages from {UBUNTU_ARCHIVE_URL}"),
+            Self::FirstBootPackages { packages } => write!(
+                f,
+                "first boot packages from {UBUNTU_ARCHIVE_URL}: {}",
+                packages.join(", ")
+            ),
+            Self::DockerCompose => {
+                write!(f, "Docker Compose plugin from {DOCKER_COMPOSE_RELEASE_URL}")
+            }
+            Self::ContainerImage { image } => {
+                write!(f, "container image '{image}' from Docker Hub")
+            }
+        }
+    }
+}
+
+/// Pre-seeded artifacts replacing the downloads of a deployment
+///
+/// The instance is created from an image already imported into LXD.
+/// Packages come from a local apt mirror, or from `.deb` files copied from
+/// the deployer machine, and container images from a registry mirror.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct OfflineMode {
+    /// Alias of the local LXD image the instance is created from
+    image_alias: String,
+
+    /// Local apt mirror replacing the Ubuntu archive
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    apt_mirror: Option<Url>,
+
+    /// Directory of `.deb` files on the deployer machine
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        with = "crate::shared::portable_path::option"
+    )]
+    packages_dir: Option<PathBuf>,
+
+    /// Registry mirror replacing Docker Hub
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    registry_mirror: Option<Url>,
+}
+
+/// Errors that can occur when creating an `OfflineMode`.
+#[derive(Debug, Error, PartialEq, Eq)]
+pub enum OfflineModeError {
+    /// The image source is empty or contains whitespace
+    #[error(
+        "Invalid image source '{image_source}': expected the alias of an image imported into LXD"
+    )]
+    InvalidImageSource {
+        /// The rejected source
+        image_source: String,
+    },
+
+    /// The image source names a remote image server
+    #[error("Image source '{image_source}' refers to the remote '{remote}', offline mode needs a local image alias")]
+    RemoteImageSource {
+        /// The rejected source
+        image_source: String,
+        /// The remote part of the source
+        remote: String,
+    },
+
+    /// A mirror is not an HTTP or HTTPS URL
+    #[error("'{field}' must be an http:// or https:// URL (got '{url}')")]
+    InvalidMirrorUrl {
+        /// Name of the rejected field
+        field: &'static str,
+        /// The rejected URL
+        url: String,
+    },
+
+    /// The packages directory is a relative path
+    #[error("'packages_dir' must be an absolute path (got '{}')", path.display())]
+    RelativePackagesDir {
+        /// The rejected path
+        path: PathBuf,
+    },
+}
+
+impl OfflineMode {
+    /// Creates a validated offline mode.
+    ///
+    /// `image_source` is an LXD image alias, optionally prefixed with
+    /// `local:`. Mirrors must be HTTP or HTTPS URLs and `packages_dir` an
+    /// absolute path.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the image source is empty, contains whitespace
+    /// or names a remote image server, a mirror is not an HTTP or HTTPS
+    /// URL, or `packages_dir` is relative.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use torrust_tracker_deployer_lib::domain::provision::OfflineMode;
+    ///
+    /// let offline = OfflineMode::new(
+    ///     "local:torrust-ubuntu-24.04",
+    ///     Some("http://mirror.lab.internal/ubuntu"),
+    ///     None,
+    ///     Some("https://registry.lab.internal"),
+    /// )?;
+    /// assert_eq!(offline.image_alias(), "torrust-ubuntu-24.04");
+    ///
+    /// assert!(OfflineMode::new("ubuntu:24.04", None, None, None).is_err());
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn new(
+        image_source: &str,
+        apt_mirror: Option<&str>,
+        packages_dir: Option<PathBuf>,
+        registry_mirror: Option<&str>,
+    ) -> Result<Self, OfflineModeError> {
+        let image_alias = parse_image_source(image_source)?;
+        let apt_mirror = apt_mirror
+            .map(|url| parse_mirror_url("apt_mirror", url))
+            .transpose()?;
+        let registry_mirror = registry_mirror
+            .map(|url| parse_mirror_url("registry_mirror", url))
+            .transpose()?;
+
+        if let Some(path) = packages_dir.as_ref().filter(|path| !path.is_absolute()) {
+            return Err(OfflineModeError::RelativePackagesDir { path: path.clone() });
+        }
+
+        Ok(Self {
+            image_alias,
+            apt_mirror,
+            packages_dir,
+            registry_mirror,
+        })
+    }
+
+    /// Returns the alias of the local LXD image
+    #[must_use]
+    pub fn image_alias(&self) -> &str {
+        &self.image_alias
+    }
+
+    /// Returns the local apt mirror, if any
+    #[must_use]
+    pub fn apt_mirror(&self) -> Option<&Url> {
+        self.apt_mirror.as_ref()
+    }
+
+    /// Returns the directory of `.deb` files on the deployer machine, if any
+    #[must_use]
+    pub fn packages_dir(&self) -> Option<&Path> {
+        self.packages_dir.as_deref()
+    }
+
+    /// Returns the registry mirror, if any
+    #[must_use]
+    pub fn registry_mirror(&self) -> Option<&Url> {
+        self.registry_mirror.as_ref()
+    }
+
+    /// Whether the artifacts of this offline mode replace `resource`
+    ///
+    /// The Docker Compose plugin is installed from the `docker-compose-v2`
+    /// package in offline mode, so it is covered like any other package.
+    #[must_use]
+    pub fn covers(&self, resource: &ExternalResource) -> bool {
+        match resource {
+            ExternalResource::BaseImage { .. } => true,
+            ExternalResource::UbuntuPackages | ExternalResource::DockerCompose => {
+                self.apt_mirror.is_some() || self.packages_dir.is_some()
+            }
+            // cloud-init installs them before the deployer can copy any file
+            ExternalResource::FirstBootPackages { .. } => self.apt_mirror.is_some(),
+            ExternalResource::ContainerImage { .. } => self.registry_mirror.is_some(),
+        }
+    }
+
+    /// Returns the resources this offline mode does not replace
+    #[must_use]
+    pub fn uncovered(&self, resources: Vec<ExternalResource>) -> Vec<ExternalResource> {
+        resources
+            .into_iter()
+            .filter(|resource| !self.covers(resource))
+            .collect()
+    }
+}
+
+/// Extracts the image alias from `local:<alias>` or `<alias>`
+fn parse_image_source(source: &str) -> Result<String, OfflineModeError> {
+    let alias = source.strip_prefix(LOCAL_IMAGE_REMOTE).unwrap_or(source);
+
+    if alias.is_empty() || alias.contains(char::is_whitespace) {
+        return Err(OfflineModeError::InvalidImageSource {
+            image_source: source.to_string(),
+        });
+    }
+
+    if let Some((remote, _)) = alias.split_once(':') {
+        return Err(OfflineModeError::RemoteImageSource {
+            image_source: source.to_string(),
+            remote: remote.to_string(),
+        });
+    }
+
+    Ok(alias.to_string())
+}
+
+fn parse_mirror_url(field: &'static str, url: &str) -> Result<Url, OfflineModeError> {
+    Url::parse(url)
+        .ok()
+        .filter(|parsed| matches!(parsed.scheme(), "http" | "https") && parsed.has_host())
+        .ok_or_else(|| OfflineModeError::InvalidMirrorUrl {
+            field,
+            url: url.to_string(),
+        })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn offline(
+        apt_mirror: Option<&str>,
+        packages_dir: Option<&str>,
+        registry_mirror: Option<&str>,
+    ) -> OfflineMode {
+        OfflineMode::new(
+            "torrust-ubuntu",
+            apt_mirror,
+            packages_dir.map(PathBuf::from),
+            registry_mirror,
+        )
+        .unwrap()
+    }
+
+    #[test]
+    fn it_should_accept_a_local_image_alias_with_or_without_the_local_remote() {
+        for source in ["torrust-ubuntu", "local:torrust-ubuntu"] {
+            let offline = OfflineMode::new(source, None, None, None).unwrap();
+
+            assert_eq!(offline.image_alias(), "torrust-ubuntu");
+        }
+    }
+
+    #[test]
+    fn it_should_reject_an_image_from_a_remote_server() {
+        assert_eq!(
+            OfflineMode::new("images:ubuntu/24.04", None, None, None),
+            Err(OfflineModeError::RemoteImageSource {
+                image_source: "images:ubuntu/24.04".to_string(),
+                remote: "images".to_string(),
+            })
+        );
+    }
+
+    #[test]
+    fn it_should_reject_an_empty_image_source() {
+        assert!(matches!(
+            OfflineMode::new("local:", None, None, None),
+            Err(OfflineModeError::InvalidImageSource { .. })
+        ));
+    }
+
+    #[test]
+    fn it_should_reject_a_mirror_that_is_not_an_http_url() {
+        assert_eq!(
+            OfflineMode::new("torrust-ubuntu", Some("ftp://mirror.lab"), None, None),
+            Err(OfflineModeError::InvalidMirrorUrl {
+                field: "apt_mirror",
+                url: "ftp://mirror.lab".to_string(),
+            })
+        );
+        assert!(matches!(
+            OfflineMode::new("torrust-ubuntu", None, None, Some("registry.lab:5000")),
+            Err(OfflineModeError::InvalidMirrorUrl {
+                field: "registry_mirror",
+                ..
+            })
+        ));
+    }
+
+    #[test]
+    fn it_should_reject_a_relative_packages_dir() {
+        assert!(matches!(
+            OfflineMode::new("torrust-ubuntu", None, Some(PathBuf::from("debs")), None),
+            Err(OfflineModeError::RelativePackagesDir { .. })
+        ));
+    }
+
+    #[test]
+    fn it_should_cover_packages_with_a_mirror_or_a_packages_dir() {
+        for offline in [
+            offline(Some("http://mirror.lab/ubuntu"), None, None),
+            offline(None, Some("/srv/debs"), None),
+        ] {
+            assert!(offline.covers(&ExternalResource::UbuntuPackages));
+            assert!(offline.covers(&ExternalResource::DockerCompose));
+        }
+        assert!(!offline(None, None, None).covers(&ExternalResource::UbuntuPackages));
+    }
+
+    #[test]
+    fn it_should_only_cover_first_boot_packages_with_a_mirror() {
+        let packages = ExternalResource::FirstBootPackages {
+            packages: vec!["htop".to_string()],
+        };
+
+        assert!(offline(Some("http://mirror.lab/ubuntu"), None, None).covers(&packages));
+        assert!(!offline(None, Some("/srv/debs"), None).covers(&packages));
+    }
+
+    #[test]
+    fn it_should_list_the_resources_left_uncovered() {
+        let resources = vec![
+            ExternalResource::BaseImage {
+                image: "ubuntu:24.04".to_string(),
+            },
+            ExternalResource::UbuntuPackages,
+            ExternalResource::ContainerImage {
+                image: "mysql:8.4".to_string(),
+            },
+        ];
+
+        let uncovered = offline(None, Some("/srv/debs"), None).uncovered(resources);
+
+        assert_eq!(
+            uncovered,
+            vec![ExternalResource::ContainerImage {
+                image: "mysql:8.4".to_string()
+            }]
+        );
+    }
+
+    #[test]
+    fn it_should_store_the_packages_directory_with_forward_slashes() {
+        use crate::shared::portable_path::to_portable_string;
+
+        let packages_dir = std::env::temp_dir().join("offline").join("debs");
+        let offline =
+            OfflineMode::new("torrust-ubuntu", None, Some(packages_dir.clone()), None).unwrap();
+
+        let json = serde_json::to_string(&offline).unwrap();
+        let loaded: OfflineMode = serde_json::from_str(&json).unwrap();
+
+        assert!(json.contains(&to_portable_string(&packages_dir)));
+        assert_eq!(loaded, offline);
+    }
+}
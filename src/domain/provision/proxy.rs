@@ -0,0 +1,255 @@
+//! Outbound HTTP proxy for networks that only reach the Internet through it.
+//!
+//! Behind a corporate proxy every download of a deployment has to go
+//! through it: the provider plugins and APIs used by `OpenTofu` on the
+//! deployer machine, and the packages and container images pulled by the
+//! instance. [`ProxyConfig`] holds the proxy URLs and the hosts reached
+//! directly.
+
+use serde::{Deserialize, Serialize};
+use thiserror::Error;
+use url::Url;
+
+/// Outbound HTTP proxy of a deployment
+///
+/// The proxy is exported to the `OpenTofu` and Ansible processes run by the
+/// deployer, configured for apt by cloud-init on the first boot, and set in
+/// the Docker daemon configuration of the instance.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ProxyConfig {
+    /// Proxy for plain HTTP requests
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    http: Option<Url>,
+
+    /// Proxy for HTTPS requests
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    https: Option<Url>,
+
+    /// Hosts, domains and networks reached without the proxy
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    no_proxy: Vec<String>,
+}
+
+/// Errors that can occur when creating a `ProxyConfig`.
+#[derive(Debug, Error, PartialEq, Eq)]
+pub enum ProxyConfigError {
+    /// Neither `http` nor `https` is set
+    #[error("The proxy section must set 'http', 'https' or both")]
+    NoProxyUrl,
+
+    /// A proxy is not an HTTP or HTTPS URL
+    #[error("'{field}' must be an http:// or https:// URL (got '{url}')")]
+    InvalidProxyUrl {
+        /// Name of the rejected field
+        field: &'static str,
+        /// The rejected URL
+        url: String,
+    },
+
+    /// A `no_proxy` entry is empty or contains a separator
+    #[error("Invalid 'no_proxy' entry '{entry}': expected one host, domain or network per entry")]
+    InvalidNoProxyEntry {
+        /// The rejected entry
+        entry: String,
+    },
+}
+
+impl ProxyConfig {
+    /// Creates a validated proxy configuration.
+    ///
+    /// At least one of `http` and `https` must be set, each to an HTTP or
+    /// HTTPS URL. Each `no_proxy` entry is one host, domain (`.internal`)
+    /// or network (`10.0.0.0/8`).
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if no proxy URL is set, a proxy is not an HTTP or
+    /// HTTPS URL, or a `no_proxy` entry is empty or holds several entries.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use torrust_tracker_deployer_lib::domain::provision::ProxyConfig;
+    ///
+    /// let proxy = ProxyConfig::new(
+    ///     Some("http://proxy.corp.internal:3128"),
+    ///     Some("http://proxy.corp.internal:3128"),
+    ///     vec![".corp.internal".to_string()],
+    /// )?;
+    /// assert_eq!(proxy.no_proxy(), [".corp.internal"]);
+    ///
+    /// assert!(ProxyConfig::new(None, None, vec![]).is_err());
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn new(
+        http: Option<&str>,
+        https: Option<&str>,
+        no_proxy: Vec<String>,
+    ) -> Result<Self, ProxyConfigError> {
+        if http.is_none() && https.is_none() {
+            return Err(ProxyConfigError::NoProxyUrl);
+        }
+
+        let http = http.map(|url| parse_proxy_url("http", url)).transpose()?;
+        let https = https.map(|url| parse_proxy_url("https", url)).transpose()?;
+
+        if let Some(entry) = no_proxy
+            .iter()
+            .find(|entry| entry.is_empty() || entry.contains([',', ' ']))
+        {
+            return Err(ProxyConfigError::InvalidNoProxyEntry {
+                entry: entry.clone(),
+            });
+        }
+
+        Ok(Self {
+            http,
+            https,
+            no_proxy,
+        })
+    }
+
+    /// Returns the proxy for plain HTTP requests, if any
+    #[must_use]
+    pub fn http(&self) -> Option<&Url> {
+        self.http.as_ref()
+    }
+
+    /// Returns the proxy for HTTPS requests, if any
+    #[must_use]
+    pub fn https(&self) -> Option<&Url> {
+        self.https.as_ref()
+    }
+
+    /// Returns the hosts, domains and networks reached without the proxy
+    #[must_use]
+    pub fn no_proxy(&self) -> &[String] {
+        &self.no_proxy
+    }
+
+    /// Returns a warning when only one of the proxies is set
+    ///
+    /// Requests of the other scheme bypass the proxy, which usually fails
+    /// on a network that only reaches the Internet through it: provider
+    /// plugins, Docker Compose and container images are downloaded over
+    /// HTTPS, Ubuntu packages over HTTP.
+    #[must_use]
+    pub fn warning(&self) -> Option<String> {
+        match (&self.http, &self.https) {
+            (Some(_), None) => Some(
+                "Only the 'http' proxy is set: HTTPS downloads (provider plugins, Docker Compose, container images) bypass the proxy".to_string(),
+            ),
+            (None, Some(_)) => Some(
+                "Only the 'https' proxy is set: HTTP downloads (Ubuntu packages) bypass the proxy"
+                    .to_string(),
+            ),
+            _ => None,
+        }
+    }
+
+    /// Returns the proxy environment variables of the processes run behind the proxy
+    ///
+    /// Each variable is given in lower and upper case, as tools read one or
+    /// the other.
+    #[must_use]
+    pub fn env_vars(&self) -> Vec<(String, String)> {
+        let no_proxy = (!self.no_proxy.is_empty()).then(|| self.no_proxy.join(","));
+        let variables = [
+            ("http_proxy", self.http.as_ref().map(Url::to_string)),
+            ("https_proxy", self.https.as_ref().map(Url::to_string)),
+            ("no_proxy", no_proxy),
+        ];
+
+        variables
+            .into_iter()
+            .filter_map(|(name, value)| value.map(|value| (name, value)))
+            .flat_map(|(name, value)| {
+                [
+                    (name.to_string(), value.clone()),
+                    (name.to_uppercase(), value),
+                ]
+            })
+            .collect()
+    }
+}
+
+/// Parse a proxy URL, accepting only HTTP and HTTPS
+fn parse_proxy_url(field: &'static str, url: &str) -> Result<Url, ProxyConfigError> {
+    Url::parse(url)
+        .ok()
+        .filter(|parsed| matches!(parsed.scheme(), "http" | "https") && parsed.has_host())
+        .ok_or_else(|| ProxyConfigError::InvalidProxyUrl {
+            field,
+            url: url.to_string(),
+        })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const PROXY: &str = "http://proxy.corp.internal:3128";
+
+    #[test]
+    fn it_should_export_each_set_variable_in_lower_and_upper_case() {
+        let proxy = ProxyConfig::new(
+            None,
+            Some(PROXY),
+            vec!["localhost".to_string(), ".corp.internal".to_string()],
+        )
+        .unwrap();
+
+        assert_eq!(
+            proxy.env_vars(),
+            vec![
+                ("https_proxy".to_string(), format!("{PROXY}/")),
+                ("HTTPS_PROXY".to_string(), format!("{PROXY}/")),
+                (
+                    "no_proxy".to_string(),
+                    "localhost,.corp.internal".to_string()
+                ),
+                (
+                    "NO_PROXY".to_string(),
+                    "localhost,.corp.internal".to_string()
+                ),
+            ]
+        );
+    }
+
+    #[test]
+    fn it_should_require_a_proxy_url() {
+        assert_eq!(
+            ProxyConfig::new(None, None, vec!["localhost".to_string()]),
+            Err(ProxyConfigError::NoProxyUrl)
+        );
+    }
+
+    #[test]
+    fn it_should_reject_a_proxy_that_is_not_an_http_url() {
+        assert!(matches!(
+            ProxyConfig::new(Some("socks5://proxy.corp.internal:1080"), None, vec![]),
+            Err(ProxyConfigError::InvalidProxyUrl { field: "http", .. })
+        ));
+    }
+
+    #[test]
+    fn it_should_reject_several_hosts_in_one_no_proxy_entry() {
+        assert!(matches!(
+            ProxyConfig::new(
+                Some(PROXY),
+                Some(PROXY),
+                vec!["a.internal,b.internal".to_string()]
+            ),
+            Err(ProxyConfigError::InvalidNoProxyEntry { .. })
+        ));
+    }
+
+    #[test]
+    fn it_should_warn_when_only_one_proxy_is_set() {
+        let http_only = ProxyConfig::new(Some(PROXY), None, vec![]).unwrap();
+        let both = ProxyConfig::new(Some(PROXY), Some(PROXY), vec![]).unwrap();
+
+        assert!(http_only.warning().unwrap().contains("HTTPS downloads"));
+        assert_eq!(both.warning(), None);
+    }
+}
@@ -0,0 +1,338 @@
+//! Container image reference (repository, tag and digest)
+//!
+//! An image reference names the image a Docker Compose service runs, in the
+//! form accepted by `docker pull`: `[registry[:port]/]repository[:tag][@sha256:<hex>]`.
+//! A reference with a digest pins the exact image content, whatever the tag
+//! points to at pull time.
+//!
+//! ## Example
+//!
+//! ```rust
+//! use torrust_tracker_deployer_lib::domain::release::ImageReference;
+//!
+//! let pinned = ImageReference::new(format!(
+//!     "registry.corp.internal:5000/torrust/tracker:3.0.0@sha256:{}",
+//!     "a".repeat(64)
+//! ))
+//! .expect("valid reference");
+//! assert_eq!(pinned.repository(), "registry.corp.internal:5000/torrust/tracker");
+//! assert_eq!(pinned.tag(), Some("3.0.0"));
+//! assert!(pinned.digest().is_some());
+//!
+//! // Repositories are lowercase and digests are SHA-256
+//! assert!(ImageReference::new("Torrust/Tracker").is_err());
+//! assert!(ImageReference::new("torrust/tracker@sha256:abc").is_err());
+//! ```
+
+use std::fmt;
+
+use serde::{Deserialize, Serialize};
+use thiserror::Error;
+
+/// Prefix of image content digests
+const DIGEST_PREFIX: &str = "sha256:";
+
+/// Number of hex characters in a SHA-256 digest
+const DIGEST_HEX_LENGTH: usize = 64;
+
+/// Longest tag accepted by Docker registries
+const MAX_TAG_LENGTH: usize = 128;
+
+/// Errors that can occur when creating an `ImageReference`
+#[derive(Debug, Clone, PartialEq, Eq, Error)]
+pub enum ImageReferenceError {
+    /// The reference is empty
+    #[error("image reference cannot be empty")]
+    Empty,
+
+    /// The repository part is not a valid image name
+    #[error(
+        "invalid image repository in '{0}': expected lowercase path components of letters, digits, '.', '_' and '-', optionally prefixed with a registry host"
+    )]
+    InvalidRepository(String),
+
+    /// The tag part is not a valid Docker image tag
+    #[error(
+        "invalid image tag in '{0}': a tag may contain letters, digits, '_', '.' and '-', must not start with '.' or '-' and is at most 128 characters long"
+    )]
+    InvalidTag(String),
+
+    /// The digest part is not `sha256:` followed by 64 hex characters
+    #[error("invalid image digest in '{0}': expected 'sha256:' followed by 64 hex characters")]
+    InvalidDigest(String),
+}
+
+impl ImageReferenceError {
+    /// Returns troubleshooting guidance for this error
+    #[must_use]
+    pub fn help(&self) -> &'static str {
+        match self {
+            Self::Empty | Self::InvalidRepository(_) | Self::InvalidTag(_) => {
+                "An image reference names the image of a service as accepted by `docker pull`.\n\
+                 \n\
+                 Fix: Use '[registry[:port]/]repository[:tag][@sha256:<digest>]', e.g.\n\
+                 \"registry.corp.internal/torrust/tracker:3.0.0\"."
+            }
+            Self::InvalidDigest(_) => {
+                "A digest pins the exact image content.\n\
+                 \n\
+                 Fix: Copy the full digest, e.g. from `docker images --digests <repository>`.\n\
+                 \n\
+                 Example: \"registry.corp.internal/torrust/tracker@sha256:<64 hex characters>\""
+            }
+        }
+    }
+}
+
+/// Validated reference of a container image
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(try_from = "String", into = "String")]
+pub struct ImageReference {
+    reference: String,
+    repository_end: usize,
+    tag_end: usize,
+}
+
+impl ImageReference {
+    /// Creates an image reference from its `docker pull` form
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the value is empty, or its repository, tag or
+    /// digest is malformed.
+    pub fn new(value: impl Into<String>) -> Result<Self, ImageReferenceError> {
+        let reference = value.into();
+
+        if reference.is_empty() {
+            return Err(ImageReferenceError::Empty);
+        }
+
+        let (name, digest) = match reference.split_once('@') {
+            Some((name, digest)) => (name, Some(digest)),
+            None => (reference.as_str(), None),
+        };
+
+        if let Some(digest) = digest {
+            if !is_valid_digest(digest) {
+                return Err(ImageReferenceError::InvalidDigest(reference));
+            }
+        }
+
+        // A colon after the last slash separates the tag; an earlier one is a registry port
+        let last_slash = name.rfind('/').map_or(0, |i| i + 1);
+        let (repository, tag) = match name[last_slash..].find(':') {
+            Some(i) => (&name[..last_slash + i], Some(&name[last_slash + i + 1..])),
+            None => (name, None),
+        };
+
+        if !is_valid_repository(repository) {
+            return Err(ImageReferenceError::InvalidRepository(reference));
+        }
+
+        if tag.is_some_and(|tag| !is_valid_tag(tag)) {
+            return Err(ImageReferenceError::InvalidTag(reference));
+        }
+
+        let repository_end = repository.len();
+        let tag_end = name.len();
+
+        Ok(Self {
+            reference,
+            repository_end,
+            tag_end,
+        })
+    }
+
+    /// Returns the repository, including the registry host if any
+    #[must_use]
+    pub fn repository(&self) -> &str {
+        &self.reference[..self.repository_end]
+    }
+
+    /// Returns the tag, if the reference has one
+    #[must_use]
+    pub fn tag(&self) -> Option<&str> {
+        (self.tag_end > self.repository_end)
+            .then(|| &self.reference[self.repository_end + 1..self.tag_end])
+    }
+
+    /// Returns the pinned `sha256:` digest, if the reference has one
+    #[must_use]
+    pub fn digest(&self) -> Option<&str> {
+        (self.tag_end < self.reference.len()).then(|| &self.reference[self.tag_end + 1..])
+    }
+
+    /// Returns the full reference
+    #[must_use]
+    pub fn as_str(&self) -> &str {
+        &self.reference
+    }
+}
+
+/// `sha256:` followed by 64 lowercase hex characters
+fn is_valid_digest(value: &str) -> bool {
+    value.strip_prefix(DIGEST_PREFIX).is_some_and(|hex| {
+        hex.len() == DIGEST_HEX_LENGTH
+            && hex
+                .chars()
+                .all(|c| c.is_ascii_digit() || ('a'..='f').contains(&c))
+    })
+}
+
+/// Slash-separated lowercase path components, the first of which may be a registry host
+fn is_valid_repository(value: &str) -> bool {
+    let mut components = value.split('/').peekable();
+
+    if let Some(first) = components.peek() {
+        // `registry.corp.internal:5000/...` or `localhost/...`: hosts may hold uppercase and a port
+        let is_registry = value.contains('/')
+            && (first.contains('.') || first.contains(':') || *first == "localhost");
+        if is_registry {
+            let host = components.next().unwrap_or_default();
+            let (name, port) = host.split_once(':').unwrap_or((host, ""));
+            let valid_host = !name.is_empty()
+                && name
+                    .chars()
+                    .all(|c| c.is_ascii_alphanumeric() || c == '.' || c == '-');
+            let valid_port = port.is_empty() || port.chars().all(|c| c.is_ascii_digit());
+            if !(valid_host && valid_port) {
+                return false;
+            }
+        }
+    }
+
+    let mut any = false;
+    for component in components {
+        any = true;
+        let valid = component
+            .chars()
+            .next()
+            .is_some_and(|c| c.is_ascii_lowercase() || c.is_ascii_digit())
+            && component.chars().all(|c| {
+                c.is_ascii_lowercase() || c.is_ascii_digit() || matches!(c, '.' | '_' | '-')
+            });
+        if !valid {
+            return false;
+        }
+    }
+    any
+}
+
+/// Docker tag grammar: `[A-Za-z0-9_][A-Za-z0-9_.-]{0,127}`
+fn is_valid_tag(value: &str) -> bool {
+    let mut chars = value.chars();
+
+    let Some(first) = chars.next() else {
+        return false;
+    };
+
+    value.len() <= MAX_TAG_LENGTH
+        && (first.is_ascii_alphanumeric() || first == '_')
+        && chars.all(|c| c.is_ascii_alphanumeric() || matches!(c, '_' | '.' | '-'))
+}
+
+impl TryFrom<String> for ImageReference {
+    type Error = ImageReferenceError;
+
+    fn try_from(value: String) -> Result<Self, Self::Error> {
+        Self::new(value)
+    }
+}
+
+impl From<ImageReference> for String {
+    fn from(reference: ImageReference) -> Self {
+        reference.reference
+    }
+}
+
+impl fmt::Display for ImageReference {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.reference)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn digest() -> String {
+        format!("sha256:{}", "0123456789abcdef".repeat(4))
+    }
+
+    #[test]
+    fn it_should_split_a_reference_with_registry_port_tag_and_digest() {
+        let reference = ImageReference::new(format!(
+            "registry.corp.internal:5000/torrust/tracker:3.0.0@{}",
+            digest()
+        ))
+        .unwrap();
+
+        assert_eq!(
+            reference.repository(),
+            "registry.corp.internal:5000/torrust/tracker"
+        );
+        assert_eq!(reference.tag(), Some("3.0.0"));
+        assert_eq!(reference.digest(), Some(digest().as_str()));
+    }
+
+    #[test]
+    fn it_should_accept_a_reference_without_tag_or_digest() {
+        let reference = ImageReference::new("mysql").unwrap();
+
+        assert_eq!(reference.repository(), "mysql");
+        assert_eq!(reference.tag(), None);
+        assert_eq!(reference.digest(), None);
+    }
+
+    #[test]
+    fn it_should_not_take_a_registry_port_for_a_tag() {
+        let reference = ImageReference::new("localhost:5000/caddy").unwrap();
+
+        assert_eq!(reference.repository(), "localhost:5000/caddy");
+        assert_eq!(reference.tag(), None);
+    }
+
+    #[test]
+    fn it_should_pin_a_digest_without_tag() {
+        let reference = ImageReference::new(format!("prom/prometheus@{}", digest())).unwrap();
+
+        assert_eq!(reference.tag(), None);
+        assert_eq!(reference.digest(), Some(digest().as_str()));
+    }
+
+    #[test]
+    fn it_should_reject_malformed_references() {
+        assert_eq!(ImageReference::new(""), Err(ImageReferenceError::Empty));
+        assert!(matches!(
+            ImageReference::new("torrust/Tracker"),
+            Err(ImageReferenceError::InvalidRepository(_))
+        ));
+        assert!(matches!(
+            ImageReference::new("torrust//tracker"),
+            Err(ImageReferenceError::InvalidRepository(_))
+        ));
+        assert!(matches!(
+            ImageReference::new("torrust/tracker:.hidden"),
+            Err(ImageReferenceError::InvalidTag(_))
+        ));
+        assert!(matches!(
+            ImageReference::new("torrust/tracker@sha256:ABC"),
+            Err(ImageReferenceError::InvalidDigest(_))
+        ));
+        assert!(matches!(
+            ImageReference::new("torrust/tracker@md5:0123"),
+            Err(ImageReferenceError::InvalidDigest(_))
+        ));
+    }
+
+    #[test]
+    fn it_should_round_trip_through_serde() {
+        let reference = ImageReference::new(format!("mysql:8.4@{}", digest())).unwrap();
+
+        let json = serde_json::to_string(&reference).unwrap();
+        let restored: ImageReference = serde_json::from_str(&json).unwrap();
+
+        assert_eq!(json, format!("\"mysql:8.4@{}\"", digest()));
+        assert_eq!(restored, reference);
+    }
+}
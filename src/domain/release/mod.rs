@@ -0,0 +1,131 @@
+//! Release settings: container images and the registry they are pulled from
+//!
+//! By default every service runs the image pinned by the deployer, pulled
+//! from Docker Hub. [`ReleaseConfig`] replaces those images, e.g. with
+//! copies in an internal registry pinned by digest, and carries the
+//! credentials of that registry.
+//!
+//! When an image is pinned by digest, the release pulls the images on the
+//! instance and fails with a digest mismatch before any container is
+//! restarted if a pulled image does not have the pinned digest.
+
+pub mod image_reference;
+pub mod registry;
+
+use serde::{Deserialize, Serialize};
+
+pub use image_reference::{ImageReference, ImageReferenceError};
+pub use registry::{RegistryCredentials, RegistryCredentialsError};
+
+/// Image overrides of the Docker Compose services
+///
+/// Services without override run the image pinned by the deployer.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ReleaseImages {
+    /// Image of the tracker (all replicas)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub tracker: Option<ImageReference>,
+
+    /// Image of the `MySQL` service
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub mysql: Option<ImageReference>,
+
+    /// Image of the Prometheus service
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub prometheus: Option<ImageReference>,
+
+    /// Image of the Grafana service
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub grafana: Option<ImageReference>,
+
+    /// Image of the Caddy TLS proxy
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub caddy: Option<ImageReference>,
+
+    /// Image of the backup service
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub backup: Option<ImageReference>,
+}
+
+impl ReleaseImages {
+    /// Returns `true` if no service image is overridden
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.iter().next().is_none()
+    }
+
+    /// Returns the overridden images with the name of their service
+    pub fn iter(&self) -> impl Iterator<Item = (&'static str, &ImageReference)> {
+        [
+            ("tracker", &self.tracker),
+            ("mysql", &self.mysql),
+            ("prometheus", &self.prometheus),
+            ("grafana", &self.grafana),
+            ("caddy", &self.caddy),
+            ("backup", &self.backup),
+        ]
+        .into_iter()
+        .filter_map(|(service, image)| image.as_ref().map(|image| (service, image)))
+    }
+}
+
+/// Container images of the release and the private registry they come from
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ReleaseConfig {
+    /// Image overrides of the services
+    #[serde(default, skip_serializing_if = "ReleaseImages::is_empty")]
+    images: ReleaseImages,
+
+    /// Private registry the instance logs in to before pulling
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    registry: Option<RegistryCredentials>,
+}
+
+impl ReleaseConfig {
+    /// Creates the release settings
+    #[must_use]
+    pub fn new(images: ReleaseImages, registry: Option<RegistryCredentials>) -> Self {
+        Self { images, registry }
+    }
+
+    /// Returns the image overrides of the services
+    #[must_use]
+    pub fn images(&self) -> &ReleaseImages {
+        &self.images
+    }
+
+    /// Returns the private registry credentials, if any
+    #[must_use]
+    pub fn registry(&self) -> Option<&RegistryCredentials> {
+        self.registry.as_ref()
+    }
+
+    /// Restores the registry password after loading the settings
+    ///
+    /// Ignored when no registry is configured.
+    pub fn restore_registry_password(&mut self, password: crate::shared::secrets::Password) {
+        if let Some(registry) = self.registry.as_mut() {
+            registry.restore_password(password);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn it_should_list_only_the_overridden_images() {
+        let images = ReleaseImages {
+            mysql: Some(ImageReference::new("registry.corp.internal/mysql:8.4").unwrap()),
+            caddy: Some(ImageReference::new("registry.corp.internal/caddy:2").unwrap()),
+            ..ReleaseImages::default()
+        };
+
+        let services: Vec<&str> = images.iter().map(|(service, _)| service).collect();
+
+        assert_eq!(services, ["mysql", "caddy"]);
+        assert!(!images.is_empty());
+        assert!(ReleaseImages::default().is_empty());
+    }
+}
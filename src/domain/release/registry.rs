@@ -0,0 +1,187 @@
+//! Credentials of a private container registry
+//!
+//! Images pulled from a private registry need a `docker login` on the
+//! instance first. The login stores the credentials in the Docker client
+//! configuration of the instance; the deployer keeps the password with the
+//! other environment secrets, never in the environment state file.
+
+use serde::{Deserialize, Serialize};
+use thiserror::Error;
+
+use crate::shared::secrets::Password;
+
+/// Errors that can occur when creating `RegistryCredentials`
+#[derive(Debug, Clone, PartialEq, Eq, Error)]
+pub enum RegistryCredentialsError {
+    /// The server is not a `host[:port]` address
+    #[error("invalid registry server '{0}': expected a host name with an optional port, without scheme or path")]
+    InvalidServer(String),
+
+    /// The username is empty or contains whitespace
+    #[error(
+        "invalid registry username '{0}': it must be non-empty and must not contain whitespace"
+    )]
+    InvalidUsername(String),
+
+    /// The password is empty
+    #[error("the registry password cannot be empty")]
+    EmptyPassword,
+}
+
+/// Server and credentials of a private container registry
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct RegistryCredentials {
+    /// Registry host, with an optional port (e.g. `registry.corp.internal:5000`)
+    server: String,
+
+    /// Registry user
+    username: String,
+
+    /// Registry password or access token
+    ///
+    /// Never serialized: the environment state file must not contain the
+    /// password. It is persisted separately as part of the
+    /// `EnvironmentSecrets` and restored with `restore_password`.
+    #[serde(skip_serializing, default = "unset_password")]
+    password: Password,
+}
+
+impl RegistryCredentials {
+    /// Creates validated registry credentials
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the server is not a `host[:port]` address, the
+    /// username is empty or holds whitespace, or the password is empty.
+    pub fn new(
+        server: impl Into<String>,
+        username: impl Into<String>,
+        password: Password,
+    ) -> Result<Self, RegistryCredentialsError> {
+        let server = server.into();
+        let username = username.into();
+
+        if !is_valid_server(&server) {
+            return Err(RegistryCredentialsError::InvalidServer(server));
+        }
+
+        if username.is_empty() || username.chars().any(char::is_whitespace) {
+            return Err(RegistryCredentialsError::InvalidUsername(username));
+        }
+
+        if password.expose_secret().is_empty() {
+            return Err(RegistryCredentialsError::EmptyPassword);
+        }
+
+        Ok(Self {
+            server,
+            username,
+            password,
+        })
+    }
+
+    /// Returns the registry host, with its port if any
+    #[must_use]
+    pub fn server(&self) -> &str {
+        &self.server
+    }
+
+    /// Returns the registry user
+    #[must_use]
+    pub fn username(&self) -> &str {
+        &self.username
+    }
+
+    /// Returns the registry password
+    #[must_use]
+    pub fn password(&self) -> &Password {
+        &self.password
+    }
+
+    /// Restores the password after loading the credentials
+    ///
+    /// The password is not part of the serialized credentials, so
+    /// repositories call this with the separately persisted secret.
+    pub fn restore_password(&mut self, password: Password) {
+        self.password = password;
+    }
+}
+
+/// Placeholder for credentials deserialized without their password
+fn unset_password() -> Password {
+    Password::new(String::new())
+}
+
+/// `host[:port]` with a DNS name or IPv4 address as host
+fn is_valid_server(value: &str) -> bool {
+    let (host, port) = value.split_once(':').unwrap_or((value, ""));
+
+    let valid_host = !host.is_empty()
+        && !host.starts_with(['.', '-'])
+        && host
+            .chars()
+            .all(|c| c.is_ascii_alphanumeric() || c == '.' || c == '-');
+    let valid_port = value.split_once(':').is_none() || port.parse::<u16>().is_ok_and(|p| p > 0);
+
+    valid_host && valid_port
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn it_should_create_credentials_for_a_registry_with_port() {
+        let credentials = RegistryCredentials::new(
+            "registry.corp.internal:5000",
+            "deployer",
+            Password::new("s3cret"),
+        )
+        .unwrap();
+
+        assert_eq!(credentials.server(), "registry.corp.internal:5000");
+        assert_eq!(credentials.username(), "deployer");
+        assert_eq!(credentials.password().expose_secret(), "s3cret");
+    }
+
+    #[test]
+    fn it_should_reject_a_server_with_scheme_or_path() {
+        for server in [
+            "https://registry.corp.internal",
+            "registry.corp.internal/v2",
+            "",
+        ] {
+            assert_eq!(
+                RegistryCredentials::new(server, "deployer", Password::new("s3cret")),
+                Err(RegistryCredentialsError::InvalidServer(server.to_string()))
+            );
+        }
+    }
+
+    #[test]
+    fn it_should_reject_an_empty_username_or_password() {
+        assert!(matches!(
+            RegistryCredentials::new("registry.corp.internal", "", Password::new("s3cret")),
+            Err(RegistryCredentialsError::InvalidUsername(_))
+        ));
+        assert_eq!(
+            RegistryCredentials::new("registry.corp.internal", "deployer", Password::new("")),
+            Err(RegistryCredentialsError::EmptyPassword)
+        );
+    }
+
+    #[test]
+    fn it_should_not_serialize_the_password() {
+        let credentials = RegistryCredentials::new(
+            "registry.corp.internal",
+            "deployer",
+            Password::new("s3cret"),
+        )
+        .unwrap();
+
+        let json = serde_json::to_string(&credentials).unwrap();
+
+        assert!(!json.contains("s3cret"));
+        assert!(!json.contains("password"));
+    }
+}
@@ -0,0 +1,465 @@
+//! Host system tuning configuration.
+
+use std::collections::BTreeMap;
+use std::fmt;
+use std::str::FromStr;
+
+use serde::{Deserialize, Serialize};
+use thiserror::Error;
+
+/// Sysctl keys that may be set, exactly or as a prefix when ending with `.`
+///
+/// Limited to network and file system parameters; keys such as
+/// `fs.suid_dumpable` or `fs.protected_symlinks`, which weaken the kernel's
+/// security settings, are deliberately left out.
+pub const ALLOWED_SYSCTLS: &[&str] = &[
+    "net.core.",
+    "net.ipv4.",
+    "net.ipv6.",
+    "net.netfilter.",
+    "net.unix.",
+    "fs.aio-max-nr",
+    "fs.file-max",
+    "fs.inotify.",
+    "fs.nr_open",
+];
+
+/// Resource limits that may be raised
+pub const ALLOWED_ULIMITS: &[&str] = &["nofile", "nproc"];
+
+/// Named set of tuning values the explicit settings are merged into
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum TuningPreset {
+    /// The image's own settings, nothing changed
+    #[default]
+    Default,
+
+    /// Larger UDP socket buffers and backlog, and more file descriptors
+    HighUdp,
+}
+
+impl TuningPreset {
+    /// All presets, in documentation order
+    pub const ALL: [Self; 2] = [Self::Default, Self::HighUdp];
+
+    /// Returns the preset name used in the environment configuration
+    #[must_use]
+    pub fn name(self) -> &'static str {
+        match self {
+            Self::Default => "default",
+            Self::HighUdp => "high-udp",
+        }
+    }
+
+    /// Returns the sysctls set by the preset
+    #[must_use]
+    pub fn sysctls(self) -> &'static [(&'static str, &'static str)] {
+        match self {
+            Self::Default => &[],
+            Self::HighUdp => &[
+                ("fs.file-max", "2097152"),
+                ("net.core.netdev_max_backlog", "65536"),
+                ("net.core.rmem_default", "8388608"),
+                ("net.core.rmem_max", "33554432"),
+                ("net.core.wmem_default", "8388608"),
+                ("net.core.wmem_max", "33554432"),
+                ("net.ipv4.udp_rmem_min", "16384"),
+                ("net.ipv4.udp_wmem_min", "16384"),
+            ],
+        }
+    }
+
+    /// Returns the resource limits raised by the preset
+    #[must_use]
+    pub fn ulimits(self) -> &'static [(&'static str, u64)] {
+        match self {
+            Self::Default => &[],
+            Self::HighUdp => &[("nofile", 1_048_576)],
+        }
+    }
+}
+
+impl fmt::Display for TuningPreset {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.name())
+    }
+}
+
+impl FromStr for TuningPreset {
+    type Err = SystemTuningError;
+
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        Self::ALL
+            .into_iter()
+            .find(|preset| preset.name() == value)
+            .ok_or_else(|| SystemTuningError::UnknownPreset {
+                value: value.to_string(),
+            })
+    }
+}
+
+/// Errors that can occur when creating a `SystemTuningConfig`.
+#[derive(Debug, Error, PartialEq, Eq)]
+pub enum SystemTuningError {
+    /// The preset name is not known
+    #[error("Unknown system tuning preset '{value}': expected 'default' or 'high-udp'")]
+    UnknownPreset {
+        /// The rejected value
+        value: String,
+    },
+
+    /// The sysctl key is outside the allowlist
+    #[error("Sysctl '{key}' is not allowed: only network and file system keys can be tuned (net.core.*, net.ipv4.*, net.ipv6.*, net.netfilter.*, net.unix.*, fs.aio-max-nr, fs.file-max, fs.inotify.*, fs.nr_open)")]
+    DisallowedSysctl {
+        /// The rejected key
+        key: String,
+    },
+
+    /// The sysctl value is empty or contains unexpected characters
+    #[error(
+        "Invalid value '{value}' for sysctl '{key}': expected numbers or words separated by spaces"
+    )]
+    InvalidSysctlValue {
+        /// The sysctl key
+        key: String,
+        /// The rejected value
+        value: String,
+    },
+
+    /// The resource limit is outside the allowlist
+    #[error("Ulimit '{name}' is not allowed: expected 'nofile' or 'nproc'")]
+    DisallowedUlimit {
+        /// The rejected name
+        name: String,
+    },
+
+    /// A resource limit of 0 would stop every process
+    #[error("Ulimit '{name}' must be greater than 0")]
+    ZeroUlimit {
+        /// The limit name
+        name: String,
+    },
+
+    /// A swap file of 0 MB cannot be created
+    #[error("Swap size must be greater than 0 MB")]
+    ZeroSwapSize,
+}
+
+/// Settings of the host system tuning
+///
+/// Explicit sysctls and ulimits override the values of the preset. The
+/// default configuration changes nothing.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct SystemTuningConfig {
+    /// Preset the explicit settings are merged into
+    #[serde(default)]
+    preset: TuningPreset,
+
+    /// Explicit sysctls, by key
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    sysctls: BTreeMap<String, String>,
+
+    /// Explicit resource limits (soft and hard), by name
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    ulimits: BTreeMap<String, u64>,
+
+    /// Size of the swap file to create, no swap file when `None`
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    swap_size_mb: Option<u32>,
+}
+
+impl SystemTuningConfig {
+    /// Creates a system tuning configuration.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if a sysctl key is outside [`ALLOWED_SYSCTLS`], a
+    /// sysctl value is not made of numbers and words, a ulimit is outside
+    /// [`ALLOWED_ULIMITS`] or 0, or the swap size is 0.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::collections::BTreeMap;
+    ///
+    /// use torrust_tracker_deployer_lib::domain::system_tuning::{SystemTuningConfig, TuningPreset};
+    ///
+    /// let sysctls = BTreeMap::from([("net.core.rmem_max".to_string(), "67108864".to_string())]);
+    /// let config = SystemTuningConfig::new(TuningPreset::HighUdp, sysctls, BTreeMap::new(), None)?;
+    /// assert_eq!(config.effective_sysctls()["net.core.rmem_max"], "67108864");
+    ///
+    /// let sysctls = BTreeMap::from([("kernel.randomize_va_space".to_string(), "0".to_string())]);
+    /// assert!(SystemTuningConfig::new(TuningPreset::Default, sysctls, BTreeMap::new(), None).is_err());
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn new(
+        preset: TuningPreset,
+        sysctls: BTreeMap<String, String>,
+        ulimits: BTreeMap<String, u64>,
+        swap_size_mb: Option<u32>,
+    ) -> Result<Self, SystemTuningError> {
+        for (key, value) in &sysctls {
+            if !is_allowed_sysctl(key) {
+                return Err(SystemTuningError::DisallowedSysctl { key: key.clone() });
+            }
+
+            if !is_valid_sysctl_value(value) {
+                return Err(SystemTuningError::InvalidSysctlValue {
+                    key: key.clone(),
+                    value: value.clone(),
+                });
+            }
+        }
+
+        for (name, limit) in &ulimits {
+            if !ALLOWED_ULIMITS.contains(&name.as_str()) {
+                return Err(SystemTuningError::DisallowedUlimit { name: name.clone() });
+            }
+
+            if *limit == 0 {
+                return Err(SystemTuningError::ZeroUlimit { name: name.clone() });
+            }
+        }
+
+        if swap_size_mb == Some(0) {
+            return Err(SystemTuningError::ZeroSwapSize);
+        }
+
+        Ok(Self {
+            preset,
+            sysctls,
+            ulimits,
+            swap_size_mb,
+        })
+    }
+
+    /// Creates the configuration of a preset without explicit settings
+    #[must_use]
+    pub fn from_preset(preset: TuningPreset) -> Self {
+        Self {
+            preset,
+            ..Self::default()
+        }
+    }
+
+    /// Returns the preset the explicit settings are merged into
+    #[must_use]
+    pub fn preset(&self) -> TuningPreset {
+        self.preset
+    }
+
+    /// Returns the size of the swap file to create, if any
+    #[must_use]
+    pub fn swap_size_mb(&self) -> Option<u32> {
+        self.swap_size_mb
+    }
+
+    /// Returns the sysctls to apply: the preset's, overridden by the explicit ones
+    #[must_use]
+    pub fn effective_sysctls(&self) -> BTreeMap<String, String> {
+        let mut sysctls: BTreeMap<String, String> = self
+            .preset
+            .sysctls()
+            .iter()
+            .map(|(key, value)| ((*key).to_string(), (*value).to_string()))
+            .collect();
+        sysctls.extend(self.sysctls.clone());
+        sysctls
+    }
+
+    /// Returns the resource limits to apply: the preset's, overridden by the explicit ones
+    #[must_use]
+    pub fn effective_ulimits(&self) -> BTreeMap<String, u64> {
+        let mut ulimits: BTreeMap<String, u64> = self
+            .preset
+            .ulimits()
+            .iter()
+            .map(|(name, limit)| ((*name).to_string(), *limit))
+            .collect();
+        ulimits.extend(self.ulimits.clone());
+        ulimits
+    }
+
+    /// Whether the configuration leaves the host unchanged
+    #[must_use]
+    pub fn is_noop(&self) -> bool {
+        self.effective_sysctls().is_empty()
+            && self.effective_ulimits().is_empty()
+            && self.swap_size_mb.is_none()
+    }
+
+    /// Returns every applied value, as recorded in the deployment report
+    ///
+    /// Sysctls are keyed by their name, resource limits by `ulimit.<name>`
+    /// and the swap file size by `swap_size_mb`.
+    #[must_use]
+    pub fn applied_values(&self) -> BTreeMap<String, String> {
+        let mut values = self.effective_sysctls();
+        values.extend(
+            self.effective_ulimits()
+                .into_iter()
+                .map(|(name, limit)| (format!("ulimit.{name}"), limit.to_string())),
+        );
+        if let Some(size) = self.swap_size_mb {
+            values.insert("swap_size_mb".to_string(), size.to_string());
+        }
+        values
+    }
+}
+
+/// Whether `key` is a well-formed sysctl key matching [`ALLOWED_SYSCTLS`]
+fn is_allowed_sysctl(key: &str) -> bool {
+    let well_formed = key.split('.').all(|segment| {
+        !segment.is_empty()
+            && segment
+                .chars()
+                .all(|c| c.is_ascii_lowercase() || c.is_ascii_digit() || c == '_' || c == '-')
+    });
+
+    well_formed
+        && ALLOWED_SYSCTLS
+            .iter()
+            .any(|allowed| key == *allowed || (allowed.ends_with('.') && key.starts_with(allowed)))
+}
+
+/// Whether `value` is made of numbers and words separated by single spaces
+fn is_valid_sysctl_value(value: &str) -> bool {
+    !value.is_empty()
+        && value.split(' ').all(|word| {
+            !word.is_empty()
+                && word
+                    .chars()
+                    .all(|c| c.is_ascii_alphanumeric() || c == '_' || c == '-' || c == '.')
+        })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn sysctl(key: &str, value: &str) -> BTreeMap<String, String> {
+        BTreeMap::from([(key.to_string(), value.to_string())])
+    }
+
+    #[test]
+    fn it_should_override_the_preset_values_with_the_explicit_ones() {
+        let config = SystemTuningConfig::new(
+            TuningPreset::HighUdp,
+            sysctl("net.core.rmem_max", "67108864"),
+            BTreeMap::from([("nofile".to_string(), 65536)]),
+            Some(2048),
+        )
+        .unwrap();
+
+        let values = config.applied_values();
+
+        assert_eq!(values["net.core.rmem_max"], "67108864");
+        assert_eq!(values["net.core.netdev_max_backlog"], "65536");
+        assert_eq!(values["ulimit.nofile"], "65536");
+        assert_eq!(values["swap_size_mb"], "2048");
+    }
+
+    #[test]
+    fn it_should_change_nothing_with_the_default_preset() {
+        assert!(SystemTuningConfig::default().is_noop());
+        assert!(!SystemTuningConfig::from_preset(TuningPreset::HighUdp).is_noop());
+    }
+
+    #[test]
+    fn it_should_reject_sysctls_outside_the_allowlist() {
+        for key in [
+            "kernel.randomize_va_space",
+            "fs.suid_dumpable",
+            "vm.swappiness",
+            "net",
+            "net.core..rmem_max",
+            "net.core.rmem_max\n",
+        ] {
+            assert_eq!(
+                SystemTuningConfig::new(
+                    TuningPreset::Default,
+                    sysctl(key, "1"),
+                    BTreeMap::new(),
+                    None
+                ),
+                Err(SystemTuningError::DisallowedSysctl {
+                    key: key.to_string()
+                }),
+                "{key:?} should be rejected"
+            );
+        }
+    }
+
+    #[test]
+    fn it_should_accept_multi_word_values_and_reject_others() {
+        assert!(SystemTuningConfig::new(
+            TuningPreset::Default,
+            sysctl("net.ipv4.tcp_rmem", "4096 87380 16777216"),
+            BTreeMap::new(),
+            None
+        )
+        .is_ok());
+
+        for value in ["", "1  2", "1; reboot", "$(id)"] {
+            assert!(
+                SystemTuningConfig::new(
+                    TuningPreset::Default,
+                    sysctl("net.core.somaxconn", value),
+                    BTreeMap::new(),
+                    None
+                )
+                .is_err(),
+                "{value:?} should be rejected"
+            );
+        }
+    }
+
+    #[test]
+    fn it_should_reject_unknown_or_zero_ulimits_and_a_zero_swap_size() {
+        let ulimit = |name: &str, limit| BTreeMap::from([(name.to_string(), limit)]);
+
+        assert_eq!(
+            SystemTuningConfig::new(
+                TuningPreset::Default,
+                BTreeMap::new(),
+                ulimit("core", 1),
+                None
+            ),
+            Err(SystemTuningError::DisallowedUlimit {
+                name: "core".to_string()
+            })
+        );
+        assert_eq!(
+            SystemTuningConfig::new(
+                TuningPreset::Default,
+                BTreeMap::new(),
+                ulimit("nofile", 0),
+                None
+            ),
+            Err(SystemTuningError::ZeroUlimit {
+                name: "nofile".to_string()
+            })
+        );
+        assert_eq!(
+            SystemTuningConfig::new(
+                TuningPreset::Default,
+                BTreeMap::new(),
+                BTreeMap::new(),
+                Some(0)
+            ),
+            Err(SystemTuningError::ZeroSwapSize)
+        );
+    }
+
+    #[test]
+    fn it_should_parse_the_preset_names() {
+        assert_eq!("high-udp".parse(), Ok(TuningPreset::HighUdp));
+        assert_eq!(
+            "turbo".parse::<TuningPreset>(),
+            Err(SystemTuningError::UnknownPreset {
+                value: "turbo".to_string()
+            })
+        );
+    }
+}
@@ -0,0 +1,23 @@
+//! Host system tuning domain types
+//!
+//! This module contains domain types for the kernel parameters (sysctls),
+//! resource limits (ulimits) and swap file applied to the instance by the
+//! configure command.
+//!
+//! ## Purpose
+//!
+//! High-traffic UDP trackers need larger socket buffers, a longer network
+//! backlog and more file descriptors than a stock Ubuntu image provides. The
+//! `SystemTuningConfig` type holds a named preset plus explicit overrides;
+//! only keys of an allowlist of network and file system parameters are
+//! accepted, so the configuration cannot weaken the kernel's security
+//! settings.
+//!
+//! ## See Also
+//!
+//! - Application layer DTOs: `src/application/command_handlers/create/config/system_tuning.rs`
+//! - System tuning playbook: `templates/ansible/configure-system-tuning.yml`
+
+pub mod config;
+
+pub use config::{SystemTuningConfig, SystemTuningError, TuningPreset};
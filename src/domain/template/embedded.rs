@@ -10,6 +10,7 @@
 //! - Template extraction to filesystem for processing
 //! - Directory structure creation and management
 //! - Template cleanup and reset functionality for testing
+//! - Optional override directory to customize templates without forking
 //! - Comprehensive error handling with detailed context
 //!
 //! ## Template Organization
@@ -18,17 +19,39 @@
 //! into the binary at compile time. The manager can extract these templates
 //! to a working directory for use by template engines and deployment tools.
 //!
+//! ## Template Overrides
+//!
+//! A manager created with [`TemplateManager::with_override_dir`] resolves every
+//! template path against `{override_dir}/{relative_path}` first and only falls
+//! back to the embedded copy when no override exists. Overridden Tera
+//! templates (`.tera`) must still reference every variable used by the
+//! embedded template, so a stale override fails fast instead of silently
+//! rendering an incomplete file.
+//!
+//! The user-defined variables set with
+//! [`TemplateManager::with_extra_variables`] are handed to the renderers, which
+//! add them to the context of every template they render.
+//!
 //! ## Usage Scenarios
 //!
 //! - Initial template setup for deployment operations
 //! - Template refresh for testing environments
 //! - Development and debugging support with accessible template files
 
+use super::ExtraTemplateVariables;
 use rust_embed::RustEmbed;
+use std::collections::BTreeSet;
 use std::fs;
 use std::path::{Path, PathBuf};
 use thiserror::Error;
 
+/// Variables an override of a Tera template may leave out
+///
+/// The LXD instance sizing variables came after template overrides: overrides
+/// written without them keep working, and `OpenTofu` falls back to the
+/// defaults declared in `main.tf`.
+const OPTIONAL_OVERRIDE_VARIABLES: &[&str] = &["cpus", "memory_mb", "disk_gb"];
+
 /// Errors that can occur during template manager operations
 #[derive(Debug, Error)]
 pub enum TemplateManagerError {
@@ -65,6 +88,22 @@ pub enum TemplateManagerError {
 
     #[error("Template file already exists: {path}")]
     TemplateAlreadyExists { path: String },
+
+    #[error("Template override directory not found: {path}")]
+    OverrideDirNotFound { path: String },
+
+    #[error("Failed to read template override: {path}")]
+    OverrideRead {
+        path: String,
+        #[source]
+        source: std::io::Error,
+    },
+
+    #[error(
+        "Template override {path} is missing required variables: {}",
+        missing.join(", ")
+    )]
+    OverrideMissingVariables { path: String, missing: Vec<String> },
 }
 
 /// Embedded template files from the ./templates directory
@@ -75,6 +114,8 @@ pub struct EmbeddedTemplates;
 /// Template manager that handles on-demand creation of templates from embedded resources
 pub struct TemplateManager {
     templates_dir: PathBuf,
+    override_dir: Option<PathBuf>,
+    extra_variables: ExtraTemplateVariables,
 }
 
 impl TemplateManager {
@@ -82,9 +123,39 @@ impl TemplateManager {
     pub fn new<P: Into<PathBuf>>(templates_dir: P) -> Self {
         Self {
             templates_dir: templates_dir.into(),
+            override_dir: None,
+            extra_variables: ExtraTemplateVariables::default(),
         }
     }
 
+    /// Set the directory holding user-provided template overrides
+    ///
+    /// `None` keeps the default behavior of using only embedded templates.
+    #[must_use]
+    pub fn with_override_dir(mut self, override_dir: Option<PathBuf>) -> Self {
+        self.override_dir = override_dir;
+        self
+    }
+
+    /// Get the template override directory, if any
+    #[must_use]
+    pub fn override_dir(&self) -> Option<&Path> {
+        self.override_dir.as_deref()
+    }
+
+    /// Set the user-defined variables added to the context of every template
+    #[must_use]
+    pub fn with_extra_variables(mut self, extra_variables: ExtraTemplateVariables) -> Self {
+        self.extra_variables = extra_variables;
+        self
+    }
+
+    /// Get the user-defined variables added to the context of every template
+    #[must_use]
+    pub fn extra_variables(&self) -> &ExtraTemplateVariables {
+        &self.extra_variables
+    }
+
     /// Create the templates directory if it doesn't exist
     ///
     /// # Errors
@@ -120,13 +191,32 @@ impl TemplateManager {
 
     /// Get the path to a template file, creating it from embedded resources if it doesn't exist
     ///
+    /// When an override directory is configured and contains the template,
+    /// the override path is returned instead.
+    ///
     /// # Errors
     ///
     /// Returns an error if:
+    /// - The configured override directory does not exist
+    /// - The template override cannot be read or misses required variables
     /// - The template is not found in embedded resources
     /// - The embedded template contains invalid UTF-8
     /// - File system operations fail (directory creation or file writing)
     pub fn get_template_path(&self, relative_path: &str) -> Result<PathBuf, TemplateManagerError> {
+        if let Some(override_path) = self.find_override(relative_path)? {
+            self.validate_override(relative_path, &override_path)?;
+
+            tracing::info!(
+                template = relative_path,
+                source = %override_path.display(),
+                "Using template override"
+            );
+
+            return Ok(override_path);
+        }
+
+        tracing::debug!(template = relative_path, "Using embedded template");
+
         let template_path = self.templates_dir.join(relative_path);
 
         // If the template file already exists, return its path
@@ -140,6 +230,75 @@ impl TemplateManager {
         Ok(template_path)
     }
 
+    /// Find the override for a template, if the override directory contains one
+    ///
+    /// A configured directory that does not exist is an error rather than a
+    /// fallback to the embedded templates, so a moved or mistyped directory
+    /// is not silently ignored.
+    fn find_override(&self, relative_path: &str) -> Result<Option<PathBuf>, TemplateManagerError> {
+        let Some(dir) = &self.override_dir else {
+            return Ok(None);
+        };
+
+        if !dir.is_dir() {
+            return Err(TemplateManagerError::OverrideDirNotFound {
+                path: dir.display().to_string(),
+            });
+        }
+
+        Ok(Some(dir.join(relative_path)).filter(|path| path.is_file()))
+    }
+
+    /// Check that an overridden Tera template still uses the embedded template's variables
+    ///
+    /// Static files and templates without an embedded counterpart are not
+    /// checked, and [`OPTIONAL_OVERRIDE_VARIABLES`] are not required.
+    fn validate_override(
+        &self,
+        relative_path: &str,
+        override_path: &Path,
+    ) -> Result<(), TemplateManagerError> {
+        if !relative_path.ends_with(".tera") {
+            return Ok(());
+        }
+
+        let Some(embedded_file) = EmbeddedTemplates::get(relative_path) else {
+            return Ok(());
+        };
+
+        let embedded_content = std::str::from_utf8(&embedded_file.data).map_err(|source| {
+            TemplateManagerError::InvalidUtf8 {
+                relative_path: relative_path.to_string(),
+                source,
+            }
+        })?;
+
+        let override_content = fs::read_to_string(override_path).map_err(|source| {
+            TemplateManagerError::OverrideRead {
+                path: override_path.display().to_string(),
+                source,
+            }
+        })?;
+
+        let provided = template_variables(&override_content);
+        let missing: Vec<String> = template_variables(embedded_content)
+            .into_iter()
+            .filter(|variable| {
+                !provided.contains(variable)
+                    && !OPTIONAL_OVERRIDE_VARIABLES.contains(&variable.as_str())
+            })
+            .collect();
+
+        if missing.is_empty() {
+            Ok(())
+        } else {
+            Err(TemplateManagerError::OverrideMissingVariables {
+                path: override_path.display().to_string(),
+                missing,
+            })
+        }
+    }
+
     /// Create a template file from embedded resources
     fn create_template_from_embedded(
         &self,
@@ -222,6 +381,29 @@ impl TemplateManager {
     }
 }
 
+/// Collect the variables referenced by `{{ ... }}` expressions in a Tera template
+///
+/// Only the leading variable path of each expression is collected (e.g.
+/// `{{ ssh_public_key | trim }}` yields `ssh_public_key`). Literals are
+/// ignored.
+fn template_variables(content: &str) -> BTreeSet<String> {
+    content
+        .split("{{")
+        .skip(1)
+        .filter_map(|expression| {
+            let expression = expression.trim_start_matches('-').trim_start();
+            let variable: String = expression
+                .chars()
+                .take_while(|c| c.is_ascii_alphanumeric() || *c == '_' || *c == '.')
+                .collect();
+
+            variable
+                .starts_with(|c: char| c.is_ascii_alphabetic() || c == '_')
+                .then_some(variable)
+        })
+        .collect()
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -561,4 +743,115 @@ mod tests {
         assert!(templates_path.exists());
         assert!(templates_path.is_dir());
     }
+
+    #[test]
+    fn it_should_use_template_override_when_present() {
+        let temp_dir = TempDir::new().unwrap();
+        let override_dir = temp_dir.path().join("overrides");
+        let override_path = override_dir.join("tofu/lxd/main.tf");
+        fs::create_dir_all(override_path.parent().unwrap()).unwrap();
+        fs::write(&override_path, "# custom main.tf").unwrap();
+
+        let manager = TemplateManager::new(temp_dir.path().join("templates"))
+            .with_override_dir(Some(override_dir));
+
+        let template_path = manager.get_template_path("tofu/lxd/main.tf").unwrap();
+
+        assert_eq!(template_path, override_path);
+    }
+
+    #[test]
+    fn it_should_fall_back_to_embedded_template_when_override_is_missing() {
+        let temp_dir = TempDir::new().unwrap();
+        let templates_path = temp_dir.path().join("templates");
+        let override_dir = temp_dir.path().join("overrides");
+        fs::create_dir_all(&override_dir).unwrap();
+
+        let manager = TemplateManager::new(&templates_path).with_override_dir(Some(override_dir));
+
+        let template_path = manager.get_template_path("tofu/lxd/main.tf").unwrap();
+
+        assert_eq!(template_path, templates_path.join("tofu/lxd/main.tf"));
+    }
+
+    #[test]
+    fn it_should_fail_when_override_directory_is_missing() {
+        let temp_dir = TempDir::new().unwrap();
+
+        let manager = TemplateManager::new(temp_dir.path().join("templates"))
+            .with_override_dir(Some(temp_dir.path().join("overrides")));
+
+        let result = manager.get_template_path("tofu/lxd/main.tf");
+
+        assert!(matches!(
+            result,
+            Err(TemplateManagerError::OverrideDirNotFound { path }) if path.ends_with("overrides")
+        ));
+    }
+
+    #[test]
+    fn it_should_accept_tera_override_using_all_required_variables() {
+        let temp_dir = TempDir::new().unwrap();
+        let override_dir = temp_dir.path().join("overrides");
+        let override_path = override_dir.join("tofu/lxd/variables.tfvars.tera");
+        fs::create_dir_all(override_path.parent().unwrap()).unwrap();
+        fs::write(
+            &override_path,
+            "# {{ generated_at }}\ninstance_name = \"{{ instance_name }}\"\nprofile_name = \"{{profile_name}}\"\nimage = \"{{ image }}\"\nremote = \"{{ remote }}\"\nremote_address = \"{{ remote_address }}\"\nconfig_dir = \"{{ config_dir }}\"\nextra = true\n",
+        )
+        .unwrap();
+
+        let manager = TemplateManager::new(temp_dir.path().join("templates"))
+            .with_override_dir(Some(override_dir));
+
+        let template_path = manager
+            .get_template_path("tofu/lxd/variables.tfvars.tera")
+            .unwrap();
+
+        assert_eq!(template_path, override_path);
+    }
+
+    #[test]
+    fn it_should_fail_when_tera_override_misses_required_variables() {
+        let temp_dir = TempDir::new().unwrap();
+        let override_dir = temp_dir.path().join("overrides");
+        let override_path = override_dir.join("tofu/lxd/variables.tfvars.tera");
+        fs::create_dir_all(override_path.parent().unwrap()).unwrap();
+        fs::write(&override_path, "instance_name = \"{{ instance_name }}\"\n").unwrap();
+
+        let manager = TemplateManager::new(temp_dir.path().join("templates"))
+            .with_override_dir(Some(override_dir));
+
+        let result = manager.get_template_path("tofu/lxd/variables.tfvars.tera");
+
+        match result.unwrap_err() {
+            TemplateManagerError::OverrideMissingVariables { path, missing } => {
+                assert!(path.contains("variables.tfvars.tera"));
+                assert_eq!(
+                    missing,
+                    vec![
+                        "config_dir",
+                        "generated_at",
+                        "image",
+                        "profile_name",
+                        "remote",
+                        "remote_address"
+                    ]
+                );
+            }
+            error => panic!("Expected OverrideMissingVariables error, got: {error:?}"),
+        }
+    }
+
+    #[test]
+    fn it_should_collect_variables_from_tera_expressions() {
+        let variables = template_variables(
+            "{{ username }} {{- ssh.port | default(value=22) }} {{ \"literal\" }} {{ 42 }}",
+        );
+
+        assert_eq!(
+            variables.into_iter().collect::<Vec<_>>(),
+            vec!["ssh.port".to_string(), "username".to_string()]
+        );
+    }
 }
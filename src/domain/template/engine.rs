@@ -2,6 +2,7 @@
 //!
 //! Provides the `TemplateEngine` struct that handles template validation and rendering with Tera.
 
+use super::ExtraTemplateVariables;
 use serde::Serialize;
 use std::error::Error as StdError;
 use tera::Tera;
@@ -58,6 +59,7 @@ pub enum TemplateEngineError {
 #[derive(Debug, Default)]
 pub struct TemplateEngine {
     tera: Tera,
+    extra_variables: ExtraTemplateVariables,
 }
 
 impl TemplateEngine {
@@ -66,9 +68,19 @@ impl TemplateEngine {
     pub fn new() -> Self {
         Self {
             tera: Tera::default(),
+            extra_variables: ExtraTemplateVariables::default(),
         }
     }
 
+    /// Sets the user-defined variables added to the context of every render
+    ///
+    /// See [`ExtraTemplateVariables::merge_into`] for the precedence rules.
+    #[must_use]
+    pub fn with_extra_variables(mut self, extra_variables: &ExtraTemplateVariables) -> Self {
+        self.extra_variables = extra_variables.clone();
+        self
+    }
+
     /// Creates a new `TemplateEngine` with template content and validates it with the given context
     ///
     /// This method combines template creation and validation to ensure templates are always
@@ -122,8 +134,10 @@ impl TemplateEngine {
         template_name: &str,
         context: &T,
     ) -> Result<String, TemplateEngineError> {
-        let tera_context = tera::Context::from_serialize(context)
+        let mut tera_context = tera::Context::from_serialize(context)
             .map_err(|source| TemplateEngineError::ContextSerialization { source })?;
+        self.extra_variables
+            .merge_into(&mut tera_context, template_name);
 
         let rendered_content =
             self.tera
@@ -10,13 +10,16 @@
 //! - `file` - Template file utilities
 //! - `file_ops` - File operation utilities
 //! - `embedded` - Embedded template management for distribution
+//! - `variables` - User-defined variables added to every template context
 
 pub mod embedded;
 pub mod engine;
 pub mod file;
 pub mod file_ops;
+pub mod variables;
 
 // Re-export commonly used items
 pub use embedded::{TemplateManager, TemplateManagerError};
 pub use engine::{TemplateEngine, TemplateEngineError};
 pub use file_ops::{copy_file_with_dir_creation, write_file_with_dir_creation, FileOperationError};
+pub use variables::{ExtraTemplateVariableError, ExtraTemplateVariables};
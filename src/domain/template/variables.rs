@@ -0,0 +1,182 @@
+//! User-defined template variables
+//!
+//! Extra variables are set in the environment configuration
+//! (`extra_template_vars`) and added to the Tera context of every rendered
+//! template, so overridden templates can reference values the deployer does
+//! not know about (e.g. `{{ motd }}` in a custom cloud-init template).
+//!
+//! ## Rules
+//!
+//! - Names must be valid Tera identifiers: ASCII letters, digits and `_`,
+//!   not starting with a digit
+//! - Variables provided by the deployer take precedence: an extra variable
+//!   with the same name as a deployer variable is ignored for that template,
+//!   and a warning is logged
+
+use std::collections::BTreeMap;
+
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use thiserror::Error;
+use tracing::warn;
+
+/// Errors that can occur when creating extra template variables
+#[derive(Debug, Clone, Error, PartialEq, Eq)]
+pub enum ExtraTemplateVariableError {
+    /// The name cannot be referenced from a Tera template
+    #[error(
+        "Invalid template variable name '{name}': expected ASCII letters, digits or '_', not starting with a digit"
+    )]
+    InvalidName {
+        /// The rejected name
+        name: String,
+    },
+}
+
+/// User-defined variables added to the context of every rendered template
+///
+/// Serialized as a JSON object (e.g. `{"motd": "hello", "ntp_servers": ["a", "b"]}`).
+///
+/// # Examples
+///
+/// ```rust
+/// use std::collections::BTreeMap;
+/// use torrust_tracker_deployer_lib::domain::template::ExtraTemplateVariables;
+///
+/// let variables = ExtraTemplateVariables::try_from(BTreeMap::from([(
+///     "motd".to_string(),
+///     serde_json::json!("hello"),
+/// )]))?;
+/// assert_eq!(variables.get("motd"), Some(&serde_json::json!("hello")));
+///
+/// let invalid = BTreeMap::from([("my-motd".to_string(), serde_json::json!("hello"))]);
+/// assert!(ExtraTemplateVariables::try_from(invalid).is_err());
+/// # Ok::<(), Box<dyn std::error::Error>>(())
+/// ```
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(transparent)]
+pub struct ExtraTemplateVariables(BTreeMap<String, Value>);
+
+impl ExtraTemplateVariables {
+    /// Create an empty set of variables
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Returns the value of the variable with the given name, if any
+    #[must_use]
+    pub fn get(&self, name: &str) -> Option<&Value> {
+        self.0.get(name)
+    }
+
+    /// Returns the variables as a map from name to value
+    #[must_use]
+    pub fn as_map(&self) -> &BTreeMap<String, Value> {
+        &self.0
+    }
+
+    /// Whether no variable is set
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+
+    /// Add the variables to the Tera context of `template_name`
+    ///
+    /// Variables already in the context were provided by the deployer and
+    /// keep their value; each collision is logged as a warning.
+    pub fn merge_into(&self, context: &mut tera::Context, template_name: &str) {
+        for (name, value) in &self.0 {
+            if context.contains_key(name) {
+                warn!(
+                    template = template_name,
+                    variable = %name,
+                    "Extra template variable collides with a deployer variable and is ignored"
+                );
+                continue;
+            }
+
+            context.insert(name, value);
+        }
+    }
+}
+
+impl TryFrom<BTreeMap<String, Value>> for ExtraTemplateVariables {
+    type Error = ExtraTemplateVariableError;
+
+    fn try_from(map: BTreeMap<String, Value>) -> Result<Self, Self::Error> {
+        if let Some(name) = map.keys().find(|name| !is_identifier(name)) {
+            return Err(ExtraTemplateVariableError::InvalidName { name: name.clone() });
+        }
+
+        Ok(Self(map))
+    }
+}
+
+/// Whether `name` can be referenced as a variable from a Tera template
+fn is_identifier(name: &str) -> bool {
+    let mut chars = name.chars();
+
+    chars
+        .next()
+        .is_some_and(|c| c.is_ascii_alphabetic() || c == '_')
+        && chars.all(|c| c.is_ascii_alphanumeric() || c == '_')
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use super::*;
+
+    fn variables(entries: &[(&str, Value)]) -> ExtraTemplateVariables {
+        entries
+            .iter()
+            .map(|(name, value)| ((*name).to_string(), value.clone()))
+            .collect::<BTreeMap<_, _>>()
+            .try_into()
+            .unwrap()
+    }
+
+    #[test]
+    fn it_should_reject_names_that_are_not_tera_identifiers() {
+        for name in ["", "1motd", "my-motd", "motd.text", "motd text"] {
+            let result =
+                ExtraTemplateVariables::try_from(BTreeMap::from([(name.to_string(), json!(1))]));
+
+            assert_eq!(
+                result,
+                Err(ExtraTemplateVariableError::InvalidName {
+                    name: name.to_string()
+                }),
+                "expected '{name}' to be rejected"
+            );
+        }
+    }
+
+    #[test]
+    fn it_should_add_the_variables_to_the_context() {
+        let mut context = tera::Context::new();
+
+        variables(&[("motd", json!("hello")), ("ntp", json!(["a", "b"]))])
+            .merge_into(&mut context, "cloud-init.yml.tera");
+
+        assert_eq!(context.get("motd"), Some(&json!("hello")));
+        assert_eq!(context.get("ntp"), Some(&json!(["a", "b"])));
+    }
+
+    #[test]
+    fn it_should_keep_the_deployer_value_when_a_variable_collides() {
+        let mut context = tera::Context::new();
+        context.insert("ssh_public_key", "ssh-ed25519 AAAA");
+
+        variables(&[("ssh_public_key", json!("overridden"))])
+            .merge_into(&mut context, "cloud-init.yml.tera");
+
+        assert_eq!(
+            context.get("ssh_public_key"),
+            Some(&json!("ssh-ed25519 AAAA"))
+        );
+    }
+}
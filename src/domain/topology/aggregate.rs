@@ -24,6 +24,7 @@ use super::error::PortConflict;
 use super::network::Network;
 use super::port::PortBinding;
 use super::service::Service;
+use crate::domain::tracker::Protocol;
 
 /// Topology information for a single service
 ///
@@ -143,13 +144,14 @@ pub struct DockerComposeTopology {
 impl DockerComposeTopology {
     /// Creates a new topology aggregate from service topologies
     ///
-    /// Validates that no two services bind to the same host port.
+    /// Validates that no two services bind to the same host port and protocol.
     /// This enforces the "always valid" invariant - a `DockerComposeTopology`
     /// instance is guaranteed to have no port conflicts.
     ///
     /// # Errors
     ///
-    /// Returns [`PortConflict`] if two services try to bind the same host port.
+    /// Returns [`PortConflict`] if two services try to bind the same host port
+    /// with the same protocol.
     ///
     /// # Examples
     ///
@@ -173,9 +175,8 @@ impl DockerComposeTopology {
     /// assert!(invalid.is_err());
     /// ```
     pub fn new(services: Vec<ServiceTopology>) -> Result<Self, PortConflict> {
-        let topology = Self { services };
-        topology.validate_port_uniqueness_internal()?;
-        Ok(topology)
+        Self::validate_port_uniqueness(&services)?;
+        Ok(Self { services })
     }
 
     /// Returns all networks required by enabled services
@@ -210,44 +211,59 @@ impl DockerComposeTopology {
         &self.services
     }
 
-    /// Validates that no two services bind to the same host port
+    /// Validates that no two services bind to the same host port and protocol
     ///
     /// Docker Compose will fail at startup if two services try to bind
-    /// to the same host port. This method detects such conflicts early,
-    /// providing a clear error message.
+    /// to the same host port. This aggregate pass detects such conflicts
+    /// across all enabled services before any file is rendered, e.g. when
+    /// an environment is created or its configuration validated.
     ///
     /// Note: Binding to different IPs (e.g., 127.0.0.1:8080 and 0.0.0.0:8080)
     /// is still considered a conflict since 0.0.0.0 includes all interfaces.
-    ///
-    /// Note: This method is primarily used internally by the constructor.
-    /// Since `DockerComposeTopology` is "always valid" (validated at construction),
-    /// a valid instance will always return `Ok(())`. This method remains available
-    /// for cases where you need to re-validate or for testing purposes.
+    /// Bindings with different protocols (e.g., 443/tcp and 443/udp) do not
+    /// conflict, as UDP and TCP have separate port spaces.
     ///
     /// # Errors
     ///
-    /// Returns [`PortConflict`] when two services expose the same host port.
-    /// The error includes details about both conflicting services and their
-    /// port bindings, enabling actionable error messages.
-    fn validate_port_uniqueness_internal(&self) -> Result<(), PortConflict> {
-        // Track which service has bound each host port
-        let mut port_bindings: HashMap<u16, (Service, PortBinding)> = HashMap::new();
-
-        for service_topology in &self.services {
+    /// Returns [`PortConflict`] when two bindings share the host port and
+    /// protocol. The error includes details about both conflicting services
+    /// and their port bindings, enabling actionable error messages.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use torrust_tracker_deployer_lib::domain::topology::{
+    ///     DockerComposeTopology, ServiceTopology, Service, PortBinding
+    /// };
+    ///
+    /// let result = DockerComposeTopology::validate_port_uniqueness(&[
+    ///     ServiceTopology::new(Service::Tracker, vec![], vec![PortBinding::tcp(9090, "HTTP tracker")]),
+    ///     ServiceTopology::new(Service::Prometheus, vec![], vec![PortBinding::localhost_tcp(9090, "Prometheus")]),
+    /// ]);
+    ///
+    /// let conflict = result.unwrap_err();
+    /// assert_eq!(conflict.first_service, Service::Tracker);
+    /// assert_eq!(conflict.second_service, Service::Prometheus);
+    /// ```
+    pub fn validate_port_uniqueness(services: &[ServiceTopology]) -> Result<(), PortConflict> {
+        // Track which service has bound each host port and protocol
+        let mut port_bindings: HashMap<(u16, Protocol), (Service, &PortBinding)> = HashMap::new();
+
+        for service_topology in services {
             for binding in service_topology.ports() {
-                let host_port = binding.host_port();
+                let key = (binding.host_port(), binding.protocol());
 
-                if let Some((first_service, first_binding)) = port_bindings.get(&host_port) {
+                if let Some((first_service, first_binding)) = port_bindings.get(&key) {
                     return Err(PortConflict {
-                        host_port,
+                        host_port: binding.host_port(),
                         first_service: *first_service,
-                        first_binding: first_binding.clone(),
+                        first_binding: (*first_binding).clone(),
                         second_service: service_topology.service(),
                         second_binding: binding.clone(),
                     });
                 }
 
-                port_bindings.insert(host_port, (service_topology.service(), binding.clone()));
+                port_bindings.insert(key, (service_topology.service(), binding));
             }
         }
 
@@ -623,6 +639,45 @@ mod tests {
             assert!(result.is_ok());
         }
 
+        #[test]
+        fn it_should_allow_same_host_port_with_different_protocols() {
+            let result = DockerComposeTopology::new(vec![
+                ServiceTopology::new(
+                    Service::Tracker,
+                    vec![],
+                    vec![PortBinding::udp(6969, "UDP announce")],
+                ),
+                ServiceTopology::new(
+                    Service::Caddy,
+                    vec![],
+                    vec![
+                        PortBinding::tcp(443, "HTTPS"),
+                        PortBinding::udp(443, "HTTP/3 (QUIC)"),
+                    ],
+                ),
+            ]);
+
+            assert!(result.is_ok());
+        }
+
+        #[test]
+        fn it_should_detect_conflict_between_localhost_and_all_interfaces_bindings() {
+            let result = DockerComposeTopology::validate_port_uniqueness(&[
+                ServiceTopology::new(
+                    Service::Tracker,
+                    vec![],
+                    vec![PortBinding::tcp(9090, "HTTP tracker announce")],
+                ),
+                ServiceTopology::new(
+                    Service::Prometheus,
+                    vec![],
+                    vec![PortBinding::localhost_tcp(9090, "Prometheus metrics")],
+                ),
+            ]);
+
+            assert_eq!(result.unwrap_err().host_port, 9090);
+        }
+
         #[test]
         fn it_should_include_conflict_details_in_error() {
             let result = DockerComposeTopology::new(vec![
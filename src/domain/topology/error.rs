@@ -10,9 +10,10 @@ use super::service::Service;
 
 /// Error indicating a port conflict between services
 ///
-/// Occurs when two services expose the same host port, which would cause
-/// a bind error when starting the Docker Compose stack.
-#[derive(Debug, Clone)]
+/// Occurs when two services expose the same host port with the same
+/// protocol, which would cause a bind error when starting the Docker
+/// Compose stack.
+#[derive(Debug, Clone, PartialEq, Eq)]
 pub struct PortConflict {
     /// The host port that is bound multiple times
     pub host_port: u16,
@@ -30,12 +31,14 @@ impl fmt::Display for PortConflict {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         write!(
             f,
-            "Port {} is bound by both {} ({}) and {} ({})",
-            self.host_port,
+            "Host port conflict: '{}' ({}) and '{}' ({}) cannot both bind to port {} ({})\n\
+            Tip: Assign different port numbers to each service",
             self.first_service.name(),
             self.first_binding.docker_compose_binding(),
             self.second_service.name(),
             self.second_binding.docker_compose_binding(),
+            self.host_port,
+            self.first_binding.protocol(),
         )
     }
 }
@@ -80,15 +83,48 @@ impl TopologyError {
     pub fn help(&self) -> String {
         match self {
             TopologyError::PortConflict(conflict) => {
-                format!(
-                    "Two services are trying to bind to the same host port {}. \
-                     Either change the host port for '{}' or '{}', \
-                     or modify the configuration to avoid the conflict. \
-                     Docker cannot start if two services bind the same port.",
-                    conflict.host_port,
-                    conflict.first_service.name(),
-                    conflict.second_service.name(),
-                )
+                use std::fmt::Write;
+
+                let protocol = conflict.first_binding.protocol();
+                let mut help = String::from("Host Port Conflict - Detailed Troubleshooting:\n\n");
+
+                help.push_str("Conflicting services:\n");
+                for (service, binding) in [
+                    (conflict.first_service, &conflict.first_binding),
+                    (conflict.second_service, &conflict.second_binding),
+                ] {
+                    let _ = writeln!(
+                        help,
+                        "  - {}: {} ({})",
+                        service.name(),
+                        binding.docker_compose_binding(),
+                        binding.description()
+                    );
+                }
+                help.push('\n');
+
+                help.push_str("Why this fails:\n");
+                let _ = write!(
+                    help,
+                    "Docker publishes both services on host port {} ({protocol}). A port bound\n\
+                    to all interfaces also covers localhost, so the second service fails\n\
+                    with \"Address already in use\" when the stack starts.\n\n",
+                    conflict.host_port
+                );
+
+                help.push_str("How to fix:\n");
+                help.push_str(
+                    "1. Change the tracker port, the ports of Prometheus (9090), Grafana (3000)\n\
+                    and Caddy (80, 443) are fixed\n\
+                    2. Or change the configuration to remove a service that is not needed\n\n",
+                );
+
+                help.push_str("Note:\n");
+                help.push_str(
+                    "Services using different protocols (UDP vs TCP) CAN share the same port.\n",
+                );
+
+                help
             }
         }
     }
@@ -0,0 +1,187 @@
+//! Authentication keys of a private tracker
+//!
+//! A private tracker (`core.private = true`) only accepts announces whose
+//! URL carries a valid key (`/announce/<key>`). Keys are minted and revoked
+//! through the tracker API after deployment; the tracker stores them in its
+//! database.
+//!
+//! ## Rules
+//!
+//! - A key has exactly 32 ASCII letters and digits, the format generated by
+//!   the tracker
+//! - A key either expires at a given time or never expires
+
+use std::fmt;
+use std::str::FromStr;
+
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use thiserror::Error;
+
+/// Length of the keys generated by the tracker
+pub const AUTH_KEY_LENGTH: usize = 32;
+
+/// Errors that can occur when creating an authentication key
+#[derive(Debug, Clone, Error, PartialEq, Eq)]
+pub enum AuthKeyError {
+    /// The key does not have the length or the characters of a tracker key
+    #[error("Invalid tracker key '{key}': expected {AUTH_KEY_LENGTH} ASCII letters and digits")]
+    InvalidKey {
+        /// The rejected key
+        key: String,
+    },
+}
+
+/// A validated authentication key of a private tracker
+///
+/// # Examples
+///
+/// ```rust
+/// use torrust_tracker_deployer_lib::domain::tracker::AuthKey;
+///
+/// let key: AuthKey = "YZSl4lMZupRuOpSRC3krIKR5BPB14nrJ".parse()?;
+/// assert_eq!(key.as_str(), "YZSl4lMZupRuOpSRC3krIKR5BPB14nrJ");
+///
+/// assert!("too-short".parse::<AuthKey>().is_err());
+/// # Ok::<(), Box<dyn std::error::Error>>(())
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
+#[serde(try_from = "String", into = "String")]
+pub struct AuthKey(String);
+
+impl AuthKey {
+    /// Create a validated authentication key
+    ///
+    /// # Errors
+    ///
+    /// Returns [`AuthKeyError::InvalidKey`] if `key` is not made of
+    /// [`AUTH_KEY_LENGTH`] ASCII letters and digits
+    pub fn new(key: impl Into<String>) -> Result<Self, AuthKeyError> {
+        let key = key.into();
+
+        if key.len() == AUTH_KEY_LENGTH && key.chars().all(|c| c.is_ascii_alphanumeric()) {
+            Ok(Self(key))
+        } else {
+            Err(AuthKeyError::InvalidKey { key })
+        }
+    }
+
+    /// Returns the key as a string slice
+    #[must_use]
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+}
+
+impl fmt::Display for AuthKey {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.0)
+    }
+}
+
+impl FromStr for AuthKey {
+    type Err = AuthKeyError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::new(s)
+    }
+}
+
+impl TryFrom<String> for AuthKey {
+    type Error = AuthKeyError;
+
+    fn try_from(value: String) -> Result<Self, Self::Error> {
+        Self::new(value)
+    }
+}
+
+impl From<AuthKey> for String {
+    fn from(key: AuthKey) -> Self {
+        key.0
+    }
+}
+
+/// A key issued by the tracker of an environment
+///
+/// The tracker keeps no listing of its keys, so the environment records the
+/// keys minted through the deployer together with their expiry time.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct IssuedAuthKey {
+    /// The key to append to the announce URL
+    pub key: AuthKey,
+
+    /// When the tracker stops accepting the key, `None` for a permanent key
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub valid_until: Option<DateTime<Utc>>,
+}
+
+impl IssuedAuthKey {
+    /// Returns `true` if the key is no longer accepted at `now`
+    #[must_use]
+    pub fn is_expired_at(&self, now: DateTime<Utc>) -> bool {
+        self.valid_until
+            .is_some_and(|valid_until| valid_until <= now)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::TimeZone;
+
+    use super::*;
+
+    const KEY: &str = "YZSl4lMZupRuOpSRC3krIKR5BPB14nrJ";
+
+    #[test]
+    fn it_should_accept_a_key_generated_by_the_tracker() {
+        let key = AuthKey::new(KEY).unwrap();
+
+        assert_eq!(key.to_string(), KEY);
+    }
+
+    #[test]
+    fn it_should_reject_keys_with_another_length_or_other_characters() {
+        for key in [
+            "",
+            "abc",
+            &format!("{KEY}x"),
+            "YZSl4lMZupRuOpSRC3krIKR5BPB14nr-",
+        ] {
+            assert_eq!(
+                AuthKey::new(key),
+                Err(AuthKeyError::InvalidKey {
+                    key: key.to_string()
+                })
+            );
+        }
+    }
+
+    #[test]
+    fn it_should_reject_an_invalid_key_when_deserializing() {
+        let result: Result<AuthKey, _> = serde_json::from_str("\"not-a-key\"");
+
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn it_should_expire_a_key_once_its_validity_is_over() {
+        let valid_until = Utc.with_ymd_and_hms(2026, 1, 1, 0, 0, 0).unwrap();
+        let issued = IssuedAuthKey {
+            key: AuthKey::new(KEY).unwrap(),
+            valid_until: Some(valid_until),
+        };
+
+        assert!(!issued.is_expired_at(valid_until - chrono::Duration::seconds(1)));
+        assert!(issued.is_expired_at(valid_until));
+    }
+
+    #[test]
+    fn it_should_never_expire_a_permanent_key() {
+        let issued = IssuedAuthKey {
+            key: AuthKey::new(KEY).unwrap(),
+            valid_until: None,
+        };
+
+        assert!(!issued.is_expired_at(Utc::now()));
+    }
+}
@@ -19,7 +19,7 @@ use crate::shared::docker_image::DockerImage;
 mod mysql;
 mod sqlite;
 
-pub use mysql::{MysqlConfig, MysqlConfigError};
+pub use mysql::{MysqlConfig, MysqlConfigError, MysqlConfigOptions};
 pub use sqlite::{SqliteConfig, SqliteConfigError};
 
 /// `SQLite` driver name constant
@@ -112,7 +112,7 @@ impl DatabaseConfig {
     /// assert!(sqlite.docker_image().is_none());
     ///
     /// let mysql = DatabaseConfig::Mysql(
-    ///     MysqlConfig::new("localhost", 3306, "tracker", "user", "pass".to_string().into(), "root_pass".to_string().into()).unwrap()
+    ///     MysqlConfig::new("localhost", 3306, "tracker", "user", "tracker_password".to_string().into(), "root_secure_password".to_string().into()).unwrap()
     /// );
     /// assert_eq!(mysql.docker_image().unwrap().full_reference(), "mysql:8.4");
     /// ```
@@ -169,7 +169,7 @@ mod tests {
                 "tracker",
                 "tracker_user",
                 Password::from("secure_password"),
-                Password::from("root_pass"),
+                Password::from("root_secure_password"),
             )
             .unwrap(),
         );
@@ -186,8 +186,8 @@ mod tests {
                 3306,
                 "tracker",
                 "tracker_user",
-                Password::from("pass123"),
-                Password::from("root123"),
+                Password::from("tracker_pass123"),
+                Password::from("root_secure_123"),
             )
             .unwrap(),
         );
@@ -198,7 +198,7 @@ mod tests {
         assert_eq!(json["config"]["port"], 3306);
         assert_eq!(json["config"]["database_name"], "tracker");
         assert_eq!(json["config"]["username"], "tracker_user");
-        assert_eq!(json["config"]["password"], "pass123");
+        assert_eq!(json["config"]["password"], "tracker_pass123");
     }
 
     #[test]
@@ -1,9 +1,28 @@
 //! `MySQL` database configuration
+//!
+//! ## Validation Rules
+//!
+//! - The database name is an unquoted `MySQL` identifier: 1 to 64 ASCII
+//!   letters, digits, `_` or `$`, not made of digits only
+//! - The user and root passwords have at least
+//!   [`DEFAULT_MIN_PASSWORD_LENGTH`] characters, unless another minimum is
+//!   set with [`MysqlConfigOptions`]
+//! - The port is in `1..=65535`
+//! - A host that is a public or unspecified IP address (e.g. `0.0.0.0`)
+//!   requires [`MysqlConfigOptions::expose_publicly`]
+
+use std::net::IpAddr;
 
 use serde::{Deserialize, Deserializer, Serialize};
 
 use crate::shared::{generate_random_password, Password};
 
+/// Default minimum length of the `MySQL` user and root passwords
+pub const DEFAULT_MIN_PASSWORD_LENGTH: usize = 12;
+
+/// Maximum length of a `MySQL` database name
+pub const MAX_DATABASE_NAME_LENGTH: usize = 64;
+
 /// Error type for `MySQL` configuration validation
 #[derive(Debug, Clone, PartialEq, thiserror::Error)]
 pub enum MysqlConfigError {
@@ -22,6 +41,36 @@ pub enum MysqlConfigError {
     /// Username `root` is reserved by the `MySQL` Docker image
     #[error("MySQL username \"root\" is reserved and cannot be used as the app database username")]
     ReservedUsername,
+    /// Database name breaks the `MySQL` identifier rules
+    #[error("MySQL database name '{name}' is not a valid identifier: expected 1 to {MAX_DATABASE_NAME_LENGTH} letters, digits, '_' or '$', not only digits")]
+    InvalidDatabaseName {
+        /// The rejected database name
+        name: String,
+    },
+    /// Password cannot be empty
+    #[error("MySQL password cannot be empty")]
+    EmptyPassword,
+    /// Password is shorter than the minimum length
+    #[error("MySQL password must have at least {min_length} characters")]
+    PasswordTooShort {
+        /// The required minimum length
+        min_length: usize,
+    },
+    /// Root password cannot be empty
+    #[error("MySQL root password cannot be empty")]
+    EmptyRootPassword,
+    /// Root password is shorter than the minimum length
+    #[error("MySQL root password must have at least {min_length} characters")]
+    RootPasswordTooShort {
+        /// The required minimum length
+        min_length: usize,
+    },
+    /// Host is a public address but public exposure was not requested
+    #[error("MySQL host '{host}' is a public address: set \"expose_publicly\": true to allow it")]
+    PubliclyExposed {
+        /// The rejected host
+        host: String,
+    },
 }
 
 impl MysqlConfigError {
@@ -93,6 +142,76 @@ impl MysqlConfigError {
                    ...\n\
                  }"
             }
+            Self::InvalidDatabaseName { .. } => {
+                "MySQL database name is not a valid identifier.\n\
+                 \n\
+                 The database is created by the MySQL container from the\n\
+                 MYSQL_DATABASE variable, which only accepts unquoted identifiers:\n\
+                 - 1 to 64 characters\n\
+                 - ASCII letters, digits, '_' or '$' (no spaces, '-' or '.')\n\
+                 - Not made of digits only\n\
+                 \n\
+                 Fix:\n\
+                 Use a simple name in your database configuration:\n\
+                 \n\
+                 \"database\": {\n\
+                   \"driver\": \"mysql\",\n\
+                   \"database_name\": \"torrust_tracker\",\n\
+                   ...\n\
+                 }"
+            }
+            Self::EmptyPassword | Self::PasswordTooShort { .. } => {
+                "MySQL password is empty or too short.\n\
+                 \n\
+                 The password field is the password of the application user.\n\
+                 It must have at least 12 characters, unless another minimum\n\
+                 is set with min_password_length.\n\
+                 \n\
+                 Fix:\n\
+                 Set a longer password, preferably from an environment variable:\n\
+                 \n\
+                 \"database\": {\n\
+                   \"driver\": \"mysql\",\n\
+                   \"password\": { \"env\": \"TRACKER_DB_PASSWORD\" },\n\
+                   ...\n\
+                 }"
+            }
+            Self::EmptyRootPassword | Self::RootPasswordTooShort { .. } => {
+                "MySQL root password is empty or too short.\n\
+                 \n\
+                 The root_password field is used as MYSQL_ROOT_PASSWORD.\n\
+                 It must have at least 12 characters, unless another minimum\n\
+                 is set with min_password_length.\n\
+                 \n\
+                 Fix:\n\
+                 Remove root_password to generate a random one at creation time,\n\
+                 or set a longer password:\n\
+                 \n\
+                 \"database\": {\n\
+                   \"driver\": \"mysql\",\n\
+                   \"root_password\": { \"env\": \"TRACKER_DB_ROOT_PASSWORD\" },\n\
+                   ...\n\
+                 }"
+            }
+            Self::PubliclyExposed { .. } => {
+                "MySQL host is a public address.\n\
+                 \n\
+                 The host is a public IP address, or an unspecified address like\n\
+                 0.0.0.0 that accepts connections on every interface. Database\n\
+                 traffic to a public address can be reached from the Internet.\n\
+                 \n\
+                 Fix:\n\
+                 Use the bundled MySQL service or a private address:\n\
+                 \n\
+                 \"database\": {\n\
+                   \"driver\": \"mysql\",\n\
+                   \"host\": \"mysql\",\n\
+                   ...\n\
+                 }\n\
+                 \n\
+                 If the database must be reached over a public address, confirm\n\
+                 it explicitly with \"expose_publicly\": true."
+            }
             Self::ReservedUsername => {
                 "MySQL username \"root\" is reserved by the MySQL Docker image and cannot\n\
                  be used as the application database username.\n\
@@ -132,18 +251,35 @@ pub struct MysqlConfig {
     /// Used as `MYSQL_ROOT_PASSWORD` in the rendered `.env` file.
     /// Always present — generated randomly at environment creation time if not supplied.
     root_password: Password,
+    /// Whether the host may be a public address
+    #[serde(skip_serializing_if = "std::ops::Not::not")]
+    expose_publicly: bool,
+}
+
+/// Validation options of [`MysqlConfig::with_options`]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct MysqlConfigOptions {
+    /// Minimum length of the user and root passwords
+    pub min_password_length: usize,
+    /// Accept a public or unspecified IP address as host
+    pub expose_publicly: bool,
+}
+
+impl Default for MysqlConfigOptions {
+    fn default() -> Self {
+        Self {
+            min_password_length: DEFAULT_MIN_PASSWORD_LENGTH,
+            expose_publicly: false,
+        }
+    }
 }
 
 impl MysqlConfig {
-    /// Creates a new `MySQL` configuration with validation
+    /// Creates a new `MySQL` configuration with the default validation options
     ///
     /// # Errors
     ///
-    /// - `EmptyDatabaseName` if database name is empty
-    /// - `EmptyHost` if host is empty
-    /// - `InvalidPort` if port is 0
-    /// - `EmptyUsername` if username is empty
-    /// - `ReservedUsername` if username is `"root"`
+    /// See [`Self::with_options`].
     ///
     /// # Examples
     ///
@@ -171,6 +307,68 @@ impl MysqlConfig {
         username: impl Into<String>,
         password: Password,
         root_password: Password,
+    ) -> Result<Self, MysqlConfigError> {
+        Self::with_options(
+            host,
+            port,
+            database_name,
+            username,
+            password,
+            root_password,
+            MysqlConfigOptions::default(),
+        )
+    }
+
+    /// Creates a new `MySQL` configuration validated with the given options
+    ///
+    /// # Errors
+    ///
+    /// - `EmptyHost` if host is empty
+    /// - `PubliclyExposed` if host is a public or unspecified IP address and
+    ///   `expose_publicly` is not set
+    /// - `InvalidPort` if port is 0
+    /// - `EmptyDatabaseName` if database name is empty
+    /// - `InvalidDatabaseName` if database name is not a `MySQL` identifier
+    /// - `EmptyUsername` if username is empty
+    /// - `ReservedUsername` if username is `"root"`
+    /// - `EmptyPassword`/`PasswordTooShort` if the password is empty or
+    ///   shorter than `min_password_length`
+    /// - `EmptyRootPassword`/`RootPasswordTooShort` for the same rules on
+    ///   the root password
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use torrust_tracker_deployer_lib::domain::tracker::{
+    ///     MysqlConfig, MysqlConfigError, MysqlConfigOptions,
+    /// };
+    /// use torrust_tracker_deployer_lib::shared::Password;
+    ///
+    /// let options = MysqlConfigOptions {
+    ///     min_password_length: 16,
+    ///     expose_publicly: false,
+    /// };
+    ///
+    /// let result = MysqlConfig::with_options(
+    ///     "mysql",
+    ///     3306,
+    ///     "tracker",
+    ///     "tracker_user",
+    ///     Password::from("secure_password"),
+    ///     Password::from("root_secure_password"),
+    ///     options,
+    /// );
+    ///
+    /// assert_eq!(result, Err(MysqlConfigError::PasswordTooShort { min_length: 16 }));
+    /// ```
+    pub fn with_options(
+        host: impl Into<String>,
+        port: u16,
+        database_name: impl Into<String>,
+        username: impl Into<String>,
+        password: Password,
+        root_password: Password,
+        options: MysqlConfigOptions,
     ) -> Result<Self, MysqlConfigError> {
         let host = host.into();
         let database_name = database_name.into();
@@ -191,6 +389,30 @@ impl MysqlConfig {
         if username == "root" {
             return Err(MysqlConfigError::ReservedUsername);
         }
+        if !options.expose_publicly && is_public_address(&host) {
+            return Err(MysqlConfigError::PubliclyExposed { host });
+        }
+        if !is_valid_identifier(&database_name) {
+            return Err(MysqlConfigError::InvalidDatabaseName {
+                name: database_name,
+            });
+        }
+
+        let min_length = options.min_password_length;
+        match password.expose_secret().chars().count() {
+            0 => return Err(MysqlConfigError::EmptyPassword),
+            length if length < min_length => {
+                return Err(MysqlConfigError::PasswordTooShort { min_length })
+            }
+            _ => {}
+        }
+        match root_password.expose_secret().chars().count() {
+            0 => return Err(MysqlConfigError::EmptyRootPassword),
+            length if length < min_length => {
+                return Err(MysqlConfigError::RootPasswordTooShort { min_length })
+            }
+            _ => {}
+        }
 
         Ok(Self {
             host,
@@ -199,6 +421,7 @@ impl MysqlConfig {
             username,
             password,
             root_password,
+            expose_publicly: options.expose_publicly,
         })
     }
 
@@ -239,6 +462,43 @@ impl MysqlConfig {
     pub fn root_password(&self) -> &Password {
         &self.root_password
     }
+
+    /// Returns whether the host may be a public address
+    #[must_use]
+    pub fn expose_publicly(&self) -> bool {
+        self.expose_publicly
+    }
+}
+
+/// Whether `name` is an unquoted `MySQL` identifier
+fn is_valid_identifier(name: &str) -> bool {
+    (1..=MAX_DATABASE_NAME_LENGTH).contains(&name.len())
+        && name
+            .chars()
+            .all(|c| c.is_ascii_alphanumeric() || matches!(c, '_' | '$'))
+        && !name.chars().all(|c| c.is_ascii_digit())
+}
+
+/// Whether `host` is an IP address reachable from the Internet
+///
+/// Unspecified addresses (`0.0.0.0`, `::`) count as public because they
+/// accept connections on every interface. Host names are not resolved.
+fn is_public_address(host: &str) -> bool {
+    let Ok(ip) = host.trim_matches(['[', ']']).parse::<IpAddr>() else {
+        return false;
+    };
+
+    match ip {
+        IpAddr::V4(ip) => {
+            let shared = ip.octets()[0] == 100 && (ip.octets()[1] & 0xc0) == 64;
+            !(ip.is_private() || ip.is_loopback() || ip.is_link_local() || shared)
+        }
+        IpAddr::V6(ip) => {
+            let unique_local = (ip.segments()[0] & 0xfe00) == 0xfc00;
+            let link_local = (ip.segments()[0] & 0xffc0) == 0xfe80;
+            !(ip.is_loopback() || unique_local || link_local)
+        }
+    }
 }
 
 /// Intermediate struct for deserialization
@@ -254,6 +514,8 @@ struct MysqlConfigRaw {
     /// generated when the field is absent.
     #[serde(default)]
     root_password: Option<Password>,
+    #[serde(default)]
+    expose_publicly: bool,
 }
 
 impl<'de> Deserialize<'de> for MysqlConfig {
@@ -263,13 +525,20 @@ impl<'de> Deserialize<'de> for MysqlConfig {
     {
         let raw = MysqlConfigRaw::deserialize(deserializer)?;
         let root_password = raw.root_password.unwrap_or_else(generate_random_password);
-        Self::new(
+        // Persisted environments may predate the password length rule: only
+        // reject empty passwords when loading them
+        let options = MysqlConfigOptions {
+            min_password_length: 1,
+            expose_publicly: raw.expose_publicly,
+        };
+        Self::with_options(
             raw.host,
             raw.port,
             raw.database_name,
             raw.username,
             raw.password,
             root_password,
+            options,
         )
         .map_err(serde::de::Error::custom)
     }
@@ -287,7 +556,7 @@ mod tests {
             "tracker",
             "tracker_user",
             Password::from("secure_password"),
-            Password::from("root_pass"),
+            Password::from("root_secure_password"),
         )
         .unwrap();
 
@@ -378,8 +647,8 @@ mod tests {
             3306,
             "tracker",
             "tracker_user",
-            Password::from("pass123"),
-            Password::from("root123"),
+            Password::from("tracker_pass123"),
+            Password::from("root_secure_123"),
         )
         .unwrap();
 
@@ -388,7 +657,7 @@ mod tests {
         assert_eq!(json["port"], 3306);
         assert_eq!(json["database_name"], "tracker");
         assert_eq!(json["username"], "tracker_user");
-        assert_eq!(json["password"], "pass123");
+        assert_eq!(json["password"], "tracker_pass123");
     }
 
     #[test]
@@ -421,4 +690,243 @@ mod tests {
         let result: Result<MysqlConfig, _> = serde_json::from_str(json);
         assert!(result.is_err());
     }
+
+    /// Build a config with valid fields except the given host, database name and passwords
+    fn config_with(
+        host: &str,
+        database_name: &str,
+        password: &str,
+        root_password: &str,
+        options: MysqlConfigOptions,
+    ) -> Result<MysqlConfig, MysqlConfigError> {
+        MysqlConfig::with_options(
+            host,
+            3306,
+            database_name,
+            "tracker_user",
+            Password::from(password),
+            Password::from(root_password),
+            options,
+        )
+    }
+
+    fn valid_config_with_host(
+        host: &str,
+        options: MysqlConfigOptions,
+    ) -> Result<MysqlConfig, MysqlConfigError> {
+        config_with(
+            host,
+            "tracker",
+            "secure_password",
+            "root_secure_password",
+            options,
+        )
+    }
+
+    #[test]
+    fn it_should_accept_database_names_following_the_identifier_rules() {
+        let longest = "d".repeat(MAX_DATABASE_NAME_LENGTH);
+
+        for name in [
+            "tracker",
+            "torrust_tracker",
+            "Tracker2",
+            "db$1",
+            "1tracker",
+            &longest,
+        ] {
+            assert!(
+                config_with(
+                    "mysql",
+                    name,
+                    "secure_password",
+                    "root_secure_password",
+                    MysqlConfigOptions::default()
+                )
+                .is_ok(),
+                "expected '{name}' to be accepted"
+            );
+        }
+    }
+
+    #[test]
+    fn it_should_reject_database_names_breaking_the_identifier_rules() {
+        let too_long = "d".repeat(MAX_DATABASE_NAME_LENGTH + 1);
+
+        for name in [
+            "my tracker",
+            "tracker-db",
+            "tracker.db",
+            "12345",
+            "tracker ",
+            &too_long,
+        ] {
+            assert_eq!(
+                config_with(
+                    "mysql",
+                    name,
+                    "secure_password",
+                    "root_secure_password",
+                    MysqlConfigOptions::default()
+                ),
+                Err(MysqlConfigError::InvalidDatabaseName {
+                    name: name.to_string()
+                }),
+                "expected '{name}' to be rejected"
+            );
+        }
+    }
+
+    #[test]
+    fn it_should_reject_empty_passwords() {
+        let options = MysqlConfigOptions::default();
+
+        assert_eq!(
+            config_with("mysql", "tracker", "", "root_secure_password", options),
+            Err(MysqlConfigError::EmptyPassword)
+        );
+        assert_eq!(
+            config_with("mysql", "tracker", "secure_password", "", options),
+            Err(MysqlConfigError::EmptyRootPassword)
+        );
+    }
+
+    #[test]
+    fn it_should_reject_passwords_shorter_than_the_default_minimum() {
+        let options = MysqlConfigOptions::default();
+        let short = "p".repeat(DEFAULT_MIN_PASSWORD_LENGTH - 1);
+
+        assert_eq!(
+            config_with("mysql", "tracker", &short, "root_secure_password", options),
+            Err(MysqlConfigError::PasswordTooShort {
+                min_length: DEFAULT_MIN_PASSWORD_LENGTH
+            })
+        );
+        assert_eq!(
+            config_with("mysql", "tracker", "secure_password", &short, options),
+            Err(MysqlConfigError::RootPasswordTooShort {
+                min_length: DEFAULT_MIN_PASSWORD_LENGTH
+            })
+        );
+    }
+
+    #[test]
+    fn it_should_apply_a_configured_minimum_password_length() {
+        let options = MysqlConfigOptions {
+            min_password_length: 4,
+            expose_publicly: false,
+        };
+
+        assert!(config_with("mysql", "tracker", "pass", "root", options).is_ok());
+        assert_eq!(
+            config_with("mysql", "tracker", "pas", "root", options),
+            Err(MysqlConfigError::PasswordTooShort { min_length: 4 })
+        );
+    }
+
+    #[test]
+    fn it_should_reject_public_hosts_unless_exposure_is_requested() {
+        let exposed = MysqlConfigOptions {
+            expose_publicly: true,
+            ..MysqlConfigOptions::default()
+        };
+
+        for host in ["0.0.0.0", "::", "[::]", "203.0.113.10", "2001:db8::10"] {
+            assert_eq!(
+                valid_config_with_host(host, MysqlConfigOptions::default()),
+                Err(MysqlConfigError::PubliclyExposed {
+                    host: host.to_string()
+                }),
+                "expected '{host}' to be rejected"
+            );
+            assert!(
+                valid_config_with_host(host, exposed)
+                    .unwrap()
+                    .expose_publicly(),
+                "expected '{host}' to be accepted with expose_publicly"
+            );
+        }
+    }
+
+    #[test]
+    fn it_should_accept_private_addresses_and_host_names() {
+        for host in [
+            "mysql",
+            "localhost",
+            "db.example.com",
+            "127.0.0.1",
+            "10.0.0.5",
+            "172.16.1.1",
+            "192.168.1.10",
+            "100.64.0.1",
+            "::1",
+            "fd00::5",
+            "fe80::1",
+        ] {
+            assert!(
+                valid_config_with_host(host, MysqlConfigOptions::default()).is_ok(),
+                "expected '{host}' to be accepted"
+            );
+        }
+    }
+
+    #[test]
+    fn it_should_provide_help_for_every_validation_error() {
+        let errors = [
+            MysqlConfigError::InvalidDatabaseName {
+                name: "my tracker".to_string(),
+            },
+            MysqlConfigError::EmptyPassword,
+            MysqlConfigError::PasswordTooShort { min_length: 12 },
+            MysqlConfigError::EmptyRootPassword,
+            MysqlConfigError::RootPasswordTooShort { min_length: 12 },
+            MysqlConfigError::PubliclyExposed {
+                host: "0.0.0.0".to_string(),
+            },
+        ];
+
+        for error in errors {
+            assert!(error.help().contains("Fix:"), "missing help for {error}");
+        }
+        assert!(MysqlConfigError::PubliclyExposed {
+            host: "0.0.0.0".to_string()
+        }
+        .help()
+        .contains("expose_publicly"));
+    }
+
+    #[test]
+    fn it_should_only_reject_empty_passwords_when_loading_persisted_state() {
+        let json = r#"{
+            "host": "mysql",
+            "port": 3306,
+            "database_name": "tracker",
+            "username": "tracker_user",
+            "password": "short",
+            "root_password": "root"
+        }"#;
+
+        let config: MysqlConfig = serde_json::from_str(json).unwrap();
+
+        assert_eq!(config.password().expose_secret(), "short");
+    }
+
+    #[test]
+    fn it_should_keep_public_exposure_when_round_tripping_through_json() {
+        let exposed = MysqlConfigOptions {
+            expose_publicly: true,
+            ..MysqlConfigOptions::default()
+        };
+        let config = valid_config_with_host("0.0.0.0", exposed).unwrap();
+
+        let json = serde_json::to_string(&config).unwrap();
+        let loaded: MysqlConfig = serde_json::from_str(&json).unwrap();
+
+        assert!(loaded.expose_publicly());
+        assert!(!serde_json::to_string(
+            &valid_config_with_host("mysql", MysqlConfigOptions::default()).unwrap()
+        )
+        .unwrap()
+        .contains("expose_publicly"));
+    }
 }
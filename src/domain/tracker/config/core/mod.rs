@@ -5,7 +5,8 @@ use serde::{Deserialize, Deserializer, Serialize};
 mod database;
 
 pub use database::{
-    DatabaseConfig, MysqlConfig, MysqlConfigError, SqliteConfig, SqliteConfigError,
+    DatabaseConfig, MysqlConfig, MysqlConfigError, MysqlConfigOptions, SqliteConfig,
+    SqliteConfigError,
 };
 
 /// Core tracker configuration options
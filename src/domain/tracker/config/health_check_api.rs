@@ -40,10 +40,12 @@
 
 use std::fmt;
 use std::net::SocketAddr;
+use std::num::NonZeroU16;
 
 use serde::{Deserialize, Serialize};
 use thiserror::Error;
 
+use super::health_check_wait::HealthCheckWait;
 use super::is_localhost;
 use crate::shared::DomainName;
 
@@ -141,6 +143,8 @@ struct HealthCheckApiConfigRaw {
     domain: Option<DomainName>,
     #[serde(default)]
     use_tls_proxy: bool,
+    #[serde(default)]
+    wait: HealthCheckWait,
 }
 
 /// Health Check API configuration with domain invariants enforced at construction
@@ -207,6 +211,10 @@ pub struct HealthCheckApiConfig {
     /// - Service receives plain HTTP from Caddy internally
     #[serde(default)]
     use_tls_proxy: bool,
+
+    /// How the `run` command waits for this API to report healthy
+    #[serde(skip_serializing_if = "HealthCheckWait::is_default")]
+    wait: HealthCheckWait,
 }
 
 impl HealthCheckApiConfig {
@@ -278,9 +286,28 @@ impl HealthCheckApiConfig {
             bind_address,
             domain,
             use_tls_proxy,
+            wait: HealthCheckWait::default(),
         })
     }
 
+    /// Returns this configuration with another health check wait strategy
+    #[must_use]
+    pub fn with_wait(mut self, wait: HealthCheckWait) -> Self {
+        self.wait = wait;
+        self
+    }
+
+    /// Returns a copy of this configuration bound to another port
+    ///
+    /// The IP address and the domain and TLS settings are kept. Any non-zero
+    /// port keeps the invariants satisfied.
+    #[must_use]
+    pub fn with_port(&self, port: NonZeroU16) -> Self {
+        let mut config = self.clone();
+        config.bind_address.set_port(port.get());
+        config
+    }
+
     // -------------------------------------------------------------------------
     // Getter methods - provide read-only access to fields
     // -------------------------------------------------------------------------
@@ -303,6 +330,12 @@ impl HealthCheckApiConfig {
         self.use_tls_proxy
     }
 
+    /// Returns how the `run` command waits for this API to report healthy
+    #[must_use]
+    pub fn wait(&self) -> &HealthCheckWait {
+        &self.wait
+    }
+
     // -------------------------------------------------------------------------
     // Convenience methods
     // -------------------------------------------------------------------------
@@ -339,7 +372,10 @@ impl<'de> Deserialize<'de> for HealthCheckApiConfig {
         D: serde::Deserializer<'de>,
     {
         let raw = HealthCheckApiConfigRaw::deserialize(deserializer)?;
-        Self::new(raw.bind_address, raw.domain, raw.use_tls_proxy).map_err(serde::de::Error::custom)
+        let config = Self::new(raw.bind_address, raw.domain, raw.use_tls_proxy)
+            .map_err(serde::de::Error::custom)?;
+
+        Ok(config.with_wait(raw.wait))
     }
 }
 
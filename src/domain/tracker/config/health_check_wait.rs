@@ -0,0 +1,259 @@
+//! Health check wait strategy
+//!
+//! After the `run` command starts the services, the deployer polls the
+//! tracker Health Check API until it answers with the expected HTTP status.
+//! This module defines how long and how often it polls.
+//!
+//! ## Example
+//!
+//! ```rust
+//! use std::time::Duration;
+//!
+//! use torrust_tracker_deployer_lib::domain::tracker::HealthCheckWait;
+//!
+//! let wait = HealthCheckWait::default();
+//! assert_eq!(wait.interval(), Duration::from_secs(2));
+//! assert_eq!(wait.timeout(), Duration::from_secs(60));
+//! assert_eq!(wait.expected_status(), 200);
+//!
+//! // The interval cannot be longer than the timeout
+//! assert!(HealthCheckWait::new(Duration::from_secs(10), Duration::from_secs(5), 200).is_err());
+//! ```
+
+use std::time::Duration;
+
+use serde::{Deserialize, Serialize};
+use thiserror::Error;
+
+/// Time between two health check requests when none is configured
+pub const DEFAULT_HEALTH_CHECK_INTERVAL: Duration = Duration::from_secs(2);
+
+/// Time the services get to become healthy when none is configured
+pub const DEFAULT_HEALTH_CHECK_TIMEOUT: Duration = Duration::from_secs(60);
+
+/// HTTP status of a healthy tracker when none is configured
+pub const DEFAULT_HEALTH_CHECK_EXPECTED_STATUS: u16 = 200;
+
+/// Errors that can occur when creating a `HealthCheckWait`
+#[derive(Debug, Clone, PartialEq, Eq, Error)]
+pub enum HealthCheckWaitError {
+    /// The interval or the timeout is zero
+    #[error("health check wait '{field}' must be greater than zero")]
+    Zero {
+        /// Name of the setting
+        field: &'static str,
+    },
+
+    /// The interval is longer than the timeout, so no retry would ever happen
+    #[error(
+        "health check wait interval ({interval:?}) cannot be longer than the timeout ({timeout:?})"
+    )]
+    IntervalLongerThanTimeout {
+        /// Configured interval
+        interval: Duration,
+        /// Configured timeout
+        timeout: Duration,
+    },
+
+    /// The expected status is not an HTTP status code
+    #[error("health check expected status {0} is not an HTTP status code (100-599)")]
+    InvalidExpectedStatus(u16),
+}
+
+impl HealthCheckWaitError {
+    /// Returns troubleshooting guidance for this error
+    #[must_use]
+    pub fn help(&self) -> &'static str {
+        match self {
+            Self::Zero { .. } | Self::IntervalLongerThanTimeout { .. } => {
+                "The health check wait interval and timeout are durations in seconds.\n\
+                 \n\
+                 Fix: Use positive values and keep 'interval_secs' at or below 'timeout_secs'.\n\
+                 \n\
+                 Example: \"wait\": { \"interval_secs\": 2, \"timeout_secs\": 60 }"
+            }
+            Self::InvalidExpectedStatus(_) => {
+                "The expected status is the HTTP status code the Health Check API returns\n\
+                 when the tracker is healthy.\n\
+                 \n\
+                 Fix: Use a status code between 100 and 599, or remove 'expected_status'\n\
+                 to use the default (200)."
+            }
+        }
+    }
+}
+
+/// How the `run` command waits for the tracker to report healthy
+///
+/// The Health Check API is polled every `interval` until it answers with
+/// `expected_status` or `timeout` has elapsed since the first request.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+pub struct HealthCheckWait {
+    interval: Duration,
+    timeout: Duration,
+    expected_status: u16,
+}
+
+impl HealthCheckWait {
+    /// Creates a validated wait strategy
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the interval or the timeout is zero, the interval
+    /// is longer than the timeout, or the expected status is not an HTTP
+    /// status code.
+    pub fn new(
+        interval: Duration,
+        timeout: Duration,
+        expected_status: u16,
+    ) -> Result<Self, HealthCheckWaitError> {
+        if interval.is_zero() {
+            return Err(HealthCheckWaitError::Zero { field: "interval" });
+        }
+
+        if timeout.is_zero() {
+            return Err(HealthCheckWaitError::Zero { field: "timeout" });
+        }
+
+        if interval > timeout {
+            return Err(HealthCheckWaitError::IntervalLongerThanTimeout { interval, timeout });
+        }
+
+        if !(100..=599).contains(&expected_status) {
+            return Err(HealthCheckWaitError::InvalidExpectedStatus(expected_status));
+        }
+
+        Ok(Self {
+            interval,
+            timeout,
+            expected_status,
+        })
+    }
+
+    /// Returns the time between two health check requests
+    #[must_use]
+    pub fn interval(&self) -> Duration {
+        self.interval
+    }
+
+    /// Returns the time the services get to become healthy
+    #[must_use]
+    pub fn timeout(&self) -> Duration {
+        self.timeout
+    }
+
+    /// Returns the HTTP status of a healthy tracker
+    #[must_use]
+    pub fn expected_status(&self) -> u16 {
+        self.expected_status
+    }
+
+    /// Returns true if every setting has its default value
+    #[must_use]
+    pub fn is_default(&self) -> bool {
+        *self == Self::default()
+    }
+}
+
+impl Default for HealthCheckWait {
+    fn default() -> Self {
+        Self {
+            interval: DEFAULT_HEALTH_CHECK_INTERVAL,
+            timeout: DEFAULT_HEALTH_CHECK_TIMEOUT,
+            expected_status: DEFAULT_HEALTH_CHECK_EXPECTED_STATUS,
+        }
+    }
+}
+
+/// Raw struct for deserializing `HealthCheckWait` before validation
+#[derive(Deserialize)]
+struct HealthCheckWaitRaw {
+    interval: Duration,
+    timeout: Duration,
+    expected_status: u16,
+}
+
+impl<'de> Deserialize<'de> for HealthCheckWait {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let raw = HealthCheckWaitRaw::deserialize(deserializer)?;
+        Self::new(raw.interval, raw.timeout, raw.expected_status).map_err(serde::de::Error::custom)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn it_should_default_to_polling_every_two_seconds_for_one_minute() {
+        let wait = HealthCheckWait::default();
+
+        assert_eq!(wait.interval(), Duration::from_secs(2));
+        assert_eq!(wait.timeout(), Duration::from_secs(60));
+        assert_eq!(wait.expected_status(), 200);
+        assert!(wait.is_default());
+    }
+
+    #[test]
+    fn it_should_reject_a_zero_interval() {
+        let result = HealthCheckWait::new(Duration::ZERO, Duration::from_secs(60), 200);
+
+        assert_eq!(
+            result,
+            Err(HealthCheckWaitError::Zero { field: "interval" })
+        );
+    }
+
+    #[test]
+    fn it_should_reject_a_zero_timeout() {
+        let result = HealthCheckWait::new(Duration::from_secs(2), Duration::ZERO, 200);
+
+        assert!(matches!(result, Err(HealthCheckWaitError::Zero { .. })));
+    }
+
+    #[test]
+    fn it_should_reject_an_interval_longer_than_the_timeout() {
+        let result = HealthCheckWait::new(Duration::from_secs(30), Duration::from_secs(10), 200);
+
+        assert!(matches!(
+            result,
+            Err(HealthCheckWaitError::IntervalLongerThanTimeout { .. })
+        ));
+    }
+
+    #[test]
+    fn it_should_reject_an_expected_status_that_is_not_an_http_status() {
+        for status in [0, 99, 600] {
+            let result =
+                HealthCheckWait::new(Duration::from_secs(2), Duration::from_secs(60), status);
+
+            assert_eq!(
+                result,
+                Err(HealthCheckWaitError::InvalidExpectedStatus(status))
+            );
+        }
+    }
+
+    #[test]
+    fn it_should_validate_when_deserializing() {
+        let json = r#"{"interval":{"secs":0,"nanos":0},"timeout":{"secs":60,"nanos":0},"expected_status":200}"#;
+
+        let result: Result<HealthCheckWait, _> = serde_json::from_str(json);
+
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn it_should_round_trip_through_json() {
+        let wait =
+            HealthCheckWait::new(Duration::from_millis(500), Duration::from_secs(30), 204).unwrap();
+
+        let json = serde_json::to_string(&wait).unwrap();
+        let restored: HealthCheckWait = serde_json::from_str(&json).unwrap();
+
+        assert_eq!(restored, wait);
+    }
+}
@@ -6,6 +6,7 @@
 use std::collections::HashMap;
 use std::fmt;
 use std::net::{IpAddr, Ipv4Addr, Ipv6Addr, SocketAddr};
+use std::num::{NonZeroU16, NonZeroU8};
 
 use serde::{Deserialize, Serialize};
 
@@ -24,18 +25,27 @@ pub const TRACKER_DOCKER_IMAGE_TAG: &str = "develop";
 
 mod core;
 mod health_check_api;
+mod health_check_wait;
 mod http;
 mod http_api;
+mod overrides;
 mod udp;
+mod version;
 
 pub use core::{
-    DatabaseConfig, MysqlConfig, MysqlConfigError, SqliteConfig, SqliteConfigError,
-    TrackerCoreConfig,
+    DatabaseConfig, MysqlConfig, MysqlConfigError, MysqlConfigOptions, SqliteConfig,
+    SqliteConfigError, TrackerCoreConfig,
 };
 pub use health_check_api::{HealthCheckApiConfig, HealthCheckApiConfigError};
+pub use health_check_wait::{
+    HealthCheckWait, HealthCheckWaitError, DEFAULT_HEALTH_CHECK_EXPECTED_STATUS,
+    DEFAULT_HEALTH_CHECK_INTERVAL, DEFAULT_HEALTH_CHECK_TIMEOUT,
+};
 pub use http::{HttpTrackerConfig, HttpTrackerConfigError};
 pub use http_api::{HttpApiConfig, HttpApiConfigError};
+pub use overrides::{TrackerOverrides, TrackerOverridesError, DEFAULT_ANNOUNCE_INTERVAL};
 pub use udp::{UdpTrackerConfig, UdpTrackerConfigError};
+pub use version::{TrackerVersion, TrackerVersionError};
 
 /// Checks if a socket address is bound to localhost (127.0.0.1 or `::1`).
 ///
@@ -108,6 +118,26 @@ pub struct TrackerConfig {
 
     /// Health Check API configuration
     health_check_api: HealthCheckApiConfig,
+
+    /// Tracker image version to deploy (defaults to the pinned tag)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    version: Option<TrackerVersion>,
+
+    /// Tuning settings rendered into `tracker.toml` after the structured fields
+    #[serde(skip_serializing_if = "TrackerOverrides::is_empty")]
+    overrides: TrackerOverrides,
+
+    /// Number of tracker containers running this configuration on the instance
+    #[serde(skip_serializing_if = "is_single_replica")]
+    replicas: NonZeroU8,
+}
+
+/// A single tracker container, the default
+const SINGLE_REPLICA: NonZeroU8 = NonZeroU8::MIN;
+
+#[allow(clippy::trivially_copy_pass_by_ref)] // serde passes fields by reference
+fn is_single_replica(replicas: &NonZeroU8) -> bool {
+    *replicas == SINGLE_REPLICA
 }
 
 /// Error type for tracker configuration validation failures
@@ -122,6 +152,34 @@ pub enum TrackerConfigError {
         /// Names of services attempting to bind to this address
         services: Vec<String>,
     },
+
+    /// Shifting a bind address by a port offset goes beyond port 65535
+    PortOutOfRange {
+        /// The bind address that cannot be shifted
+        address: SocketAddr,
+        /// The requested port offset
+        offset: u16,
+    },
+
+    /// Offsetting the ports of the last replica goes beyond port 65535
+    ReplicaPortOutOfRange {
+        /// The bind address of the first replica that cannot be shifted
+        address: SocketAddr,
+        /// The requested number of replicas
+        replicas: NonZeroU8,
+    },
+
+    /// Several replicas are requested for a configuration with TLS-proxied services
+    ReplicasWithTlsProxy {
+        /// The requested number of replicas
+        replicas: NonZeroU8,
+    },
+
+    /// Several replicas are requested for a configuration with a `SQLite` database
+    ReplicasWithSqlite {
+        /// The requested number of replicas
+        replicas: NonZeroU8,
+    },
 }
 
 impl fmt::Display for TrackerConfigError {
@@ -143,6 +201,34 @@ impl fmt::Display for TrackerConfigError {
                     Tip: Assign different port numbers to each service"
                 )
             }
+            Self::PortOutOfRange { address, offset } => {
+                write!(
+                    f,
+                    "Port offset {offset} moves bind address {address} beyond port 65535\n\
+                    Tip: Use a smaller offset"
+                )
+            }
+            Self::ReplicaPortOutOfRange { address, replicas } => {
+                write!(
+                    f,
+                    "Running {replicas} tracker replicas moves bind address {address} beyond port 65535\n\
+                    Tip: Use fewer replicas or lower tracker ports"
+                )
+            }
+            Self::ReplicasWithTlsProxy { replicas } => {
+                write!(
+                    f,
+                    "Cannot run {replicas} tracker replicas with TLS-proxied services\n\
+                    Tip: Disable 'use_tls_proxy' on the tracker services or use a single replica"
+                )
+            }
+            Self::ReplicasWithSqlite { replicas } => {
+                write!(
+                    f,
+                    "Cannot run {replicas} tracker replicas with a SQLite database\n\
+                    Tip: Use a MySQL database or a single replica"
+                )
+            }
         }
     }
 }
@@ -195,6 +281,49 @@ impl TrackerConfigError {
 
                 help
             }
+            Self::PortOutOfRange { address, offset } => {
+                format!(
+                    "Port Offset Out of Range - Detailed Troubleshooting:\n\n\
+                    Adding the offset {offset} to the port of {address} gives a port above 65535.\n\n\
+                    How to fix:\n\
+                    1. Use a smaller offset so that every tracker port stays at or below 65535\n\
+                    2. Or lower the highest port in the source environment configuration\n"
+                )
+            }
+            Self::ReplicaPortOutOfRange { address, replicas } => {
+                format!(
+                    "Replica Ports Out of Range - Detailed Troubleshooting:\n\n\
+                    Replica N publishes every tracker port shifted by N - 1, so with\n\
+                    {replicas} replicas the port of {address} goes above 65535.\n\n\
+                    How to fix:\n\
+                    1. Reduce the number of replicas in the tracker section\n\
+                    2. Or lower the highest tracker port in the environment configuration\n"
+                )
+            }
+            Self::ReplicasWithTlsProxy { replicas } => {
+                format!(
+                    "Replicas With TLS Proxy - Detailed Troubleshooting:\n\n\
+                    The Caddy reverse proxy forwards HTTPS traffic to a single tracker\n\
+                    container, so {replicas} replicas cannot share the TLS-proxied\n\
+                    HTTP trackers, HTTP API or Health Check API.\n\n\
+                    How to fix:\n\
+                    1. Set 'use_tls_proxy' to false on every tracker service, so each\n\
+                       replica publishes its own ports\n\
+                    2. Or remove 'replicas' from the tracker section to run one container\n"
+                )
+            }
+            Self::ReplicasWithSqlite { replicas } => {
+                format!(
+                    "Replicas With SQLite - Detailed Troubleshooting:\n\n\
+                    Every replica mounts the same tracker storage, so {replicas} replicas\n\
+                    would all write to one SQLite database file. SQLite does not support\n\
+                    concurrent writers from several processes.\n\n\
+                    How to fix:\n\
+                    1. Set the database driver to 'mysql' in the tracker core section,\n\
+                       which every replica can share\n\
+                    2. Or remove 'replicas' from the tracker section to run one container\n"
+                )
+            }
         }
     }
 }
@@ -257,6 +386,9 @@ impl TrackerConfig {
             http_trackers,
             http_api,
             health_check_api,
+            version: None,
+            overrides: TrackerOverrides::default(),
+            replicas: SINGLE_REPLICA,
         };
 
         // Validate aggregate-level invariants
@@ -266,6 +398,265 @@ impl TrackerConfig {
         Ok(config)
     }
 
+    /// Returns a copy of this configuration with every bind port shifted by `offset`
+    ///
+    /// Used to run a second deployment of the same configuration (e.g. a
+    /// staging clone) without port conflicts: with an offset of 1000 the UDP
+    /// tracker on `0.0.0.0:6969` moves to `0.0.0.0:7969`. IP addresses,
+    /// domains and TLS settings are kept.
+    ///
+    /// # Errors
+    ///
+    /// Returns `TrackerConfigError::PortOutOfRange` if a shifted port would
+    /// exceed 65535.
+    pub fn with_port_offset(&self, offset: u16) -> Result<Self, TrackerConfigError> {
+        let config = self.shift_ports(offset)?;
+        config.check_replica_ports()?;
+        Ok(config)
+    }
+
+    /// Shifts every bind port by `offset` without checking the replica ports
+    fn shift_ports(&self, offset: u16) -> Result<Self, TrackerConfigError> {
+        let shift = |address: SocketAddr| {
+            address
+                .port()
+                .checked_add(offset)
+                .and_then(NonZeroU16::new)
+                .ok_or(TrackerConfigError::PortOutOfRange { address, offset })
+        };
+
+        let udp_trackers = self
+            .udp_trackers
+            .iter()
+            .map(|tracker| Ok(tracker.with_port(shift(tracker.bind_address())?)))
+            .collect::<Result<Vec<_>, TrackerConfigError>>()?;
+
+        let http_trackers = self
+            .http_trackers
+            .iter()
+            .map(|tracker| Ok(tracker.with_port(shift(tracker.bind_address())?)))
+            .collect::<Result<Vec<_>, TrackerConfigError>>()?;
+
+        Ok(Self {
+            core: self.core.clone(),
+            udp_trackers,
+            http_trackers,
+            http_api: self
+                .http_api
+                .with_port(shift(self.http_api.bind_address())?),
+            health_check_api: self
+                .health_check_api
+                .with_port(shift(self.health_check_api.bind_address())?),
+            version: self.version.clone(),
+            overrides: self.overrides.clone(),
+            replicas: self.replicas,
+        })
+    }
+
+    /// Returns a copy of this configuration running `replicas` tracker containers
+    ///
+    /// Every replica uses the same `tracker.toml` and database, so several
+    /// replicas require a `MySQL` database. Replica N (counting from 1)
+    /// publishes each tracker port shifted by N - 1 on the host: with two
+    /// replicas the UDP tracker is announced on 6969 and 6970.
+    ///
+    /// Replicas publish their ports directly, so several replicas cannot be
+    /// combined with TLS-proxied services: Caddy forwards to a single container.
+    ///
+    /// # Errors
+    ///
+    /// Returns `TrackerConfigError::ReplicasWithTlsProxy` if more than one
+    /// replica is requested and a service uses the TLS proxy,
+    /// `TrackerConfigError::ReplicasWithSqlite` if more than one replica is
+    /// requested with a `SQLite` database, or
+    /// `TrackerConfigError::ReplicaPortOutOfRange` if a port of the last
+    /// replica would exceed 65535.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use std::num::NonZeroU8;
+    /// use torrust_tracker_deployer_lib::domain::tracker::{
+    ///     DatabaseConfig, MysqlConfig, TrackerConfig, TrackerCoreConfig,
+    /// };
+    ///
+    /// let defaults = TrackerConfig::default();
+    /// let mysql = MysqlConfig::new(
+    ///     "mysql",
+    ///     3306,
+    ///     "torrust_tracker",
+    ///     "tracker_user",
+    ///     "tracker_password".to_string().into(),
+    ///     "root_password".to_string().into(),
+    /// )
+    /// .unwrap();
+    /// let config = TrackerConfig::new(
+    ///     TrackerCoreConfig::new(DatabaseConfig::Mysql(mysql), false),
+    ///     defaults.udp_trackers().to_vec(),
+    ///     defaults.http_trackers().to_vec(),
+    ///     defaults.http_api().clone(),
+    ///     defaults.health_check_api().clone(),
+    /// )
+    /// .unwrap()
+    /// .with_replicas(NonZeroU8::new(2).unwrap())
+    /// .unwrap();
+    ///
+    /// let ports: Vec<u16> = config
+    ///     .replica_configs()
+    ///     .iter()
+    ///     .map(|replica| replica.udp_trackers()[0].bind_address().port())
+    ///     .collect();
+    /// assert_eq!(ports, vec![6969, 6970]);
+    /// ```
+    pub fn with_replicas(mut self, replicas: NonZeroU8) -> Result<Self, TrackerConfigError> {
+        if replicas > SINGLE_REPLICA && self.has_any_tls_configured() {
+            return Err(TrackerConfigError::ReplicasWithTlsProxy { replicas });
+        }
+
+        if replicas > SINGLE_REPLICA && !self.uses_mysql() {
+            return Err(TrackerConfigError::ReplicasWithSqlite { replicas });
+        }
+
+        self.replicas = replicas;
+        self.check_replica_ports()?;
+        Ok(self)
+    }
+
+    /// Returns the number of tracker containers running this configuration
+    #[must_use]
+    pub fn replicas(&self) -> NonZeroU8 {
+        self.replicas
+    }
+
+    /// Returns the Docker Compose service name of the replica at `index` (from 0)
+    ///
+    /// The first replica is `tracker`, replica N is `tracker-N`. Services are
+    /// named after their containers.
+    #[must_use]
+    pub fn replica_name(index: u8) -> String {
+        match index {
+            0 => "tracker".to_string(),
+            _ => format!("tracker-{}", u16::from(index) + 1),
+        }
+    }
+
+    /// Returns the Docker Compose service names of every replica, in order
+    #[must_use]
+    pub fn replica_names(&self) -> Vec<String> {
+        (0..self.replicas.get()).map(Self::replica_name).collect()
+    }
+
+    /// Returns the configuration of each replica as reached from outside the instance
+    ///
+    /// The first entry is this configuration with a single replica; replica N
+    /// has every port shifted by N - 1.
+    #[must_use]
+    pub fn replica_configs(&self) -> Vec<Self> {
+        (0..self.replicas.get())
+            .map(|index| {
+                let mut replica = self
+                    .shift_ports(u16::from(index))
+                    .expect("replica ports are checked when the replicas are set");
+                replica.replicas = SINGLE_REPLICA;
+                replica
+            })
+            .collect()
+    }
+
+    /// Returns the port bindings published by the replica at `index` (from 0)
+    ///
+    /// Host ports are shifted by `index`; container ports are the configured
+    /// ones, since all replicas share the same `tracker.toml`.
+    #[must_use]
+    pub fn replica_port_bindings(&self, index: u8) -> Vec<PortBinding> {
+        self.single_container_ports()
+            .into_iter()
+            .map(|binding| {
+                PortBinding::new(
+                    binding.host_port() + u16::from(index),
+                    binding.container_port(),
+                    binding.protocol(),
+                    binding.host_ip(),
+                    binding.description(),
+                )
+            })
+            .collect()
+    }
+
+    /// Checks that the ports of the last replica stay within range
+    fn check_replica_ports(&self) -> Result<(), TrackerConfigError> {
+        let last_offset = u16::from(self.replicas.get() - 1);
+
+        match self.shift_ports(last_offset) {
+            Ok(_) => Ok(()),
+            Err(TrackerConfigError::PortOutOfRange { address, .. }) => {
+                Err(TrackerConfigError::ReplicaPortOutOfRange {
+                    address,
+                    replicas: self.replicas,
+                })
+            }
+            Err(error) => Err(error),
+        }
+    }
+
+    /// Returns a copy of this configuration deploying the given tracker version
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use torrust_tracker_deployer_lib::domain::tracker::{TrackerConfig, TrackerVersion};
+    ///
+    /// let config = TrackerConfig::default().with_version(TrackerVersion::new("3.0.0").unwrap());
+    /// assert_eq!(config.image().full_reference(), "torrust/tracker:3.0.0");
+    /// ```
+    #[must_use]
+    pub fn with_version(mut self, version: TrackerVersion) -> Self {
+        self.version = Some(version);
+        self
+    }
+
+    /// Returns a copy of this configuration with the given tuning overrides
+    #[must_use]
+    pub fn with_overrides(mut self, overrides: TrackerOverrides) -> Self {
+        self.overrides = overrides;
+        self
+    }
+
+    /// Returns the tuning settings rendered into `tracker.toml`
+    #[must_use]
+    pub fn overrides(&self) -> &TrackerOverrides {
+        &self.overrides
+    }
+
+    /// Returns the configured tracker version, if any
+    #[must_use]
+    pub fn version(&self) -> Option<&TrackerVersion> {
+        self.version.as_ref()
+    }
+
+    /// Returns the tracker version that a release deploys
+    ///
+    /// This is the configured version, or the pinned default tag when none
+    /// is configured.
+    #[must_use]
+    pub fn effective_version(&self) -> TrackerVersion {
+        self.version.clone().unwrap_or_else(|| {
+            TrackerVersion::new(TRACKER_DOCKER_IMAGE_TAG).expect("default tag is a valid version")
+        })
+    }
+
+    /// Returns the Docker image deployed for this configuration
+    ///
+    /// Uses the configured version instead of the pinned default tag of
+    /// [`TrackerConfig::docker_image`] when one is set.
+    #[must_use]
+    pub fn image(&self) -> DockerImage {
+        DockerImage::new(
+            TRACKER_DOCKER_IMAGE_REPOSITORY,
+            self.effective_version().as_str(),
+        )
+    }
+
     /// Returns the core tracker configuration.
     #[must_use]
     pub fn core(&self) -> &TrackerCoreConfig {
@@ -337,9 +728,10 @@ impl TrackerConfig {
         matches!(self.core.database(), DatabaseConfig::Mysql(_))
     }
 
-    /// Returns the Docker image used for the tracker service.
+    /// Returns the default Docker image used for the tracker service.
     ///
-    /// This is a pinned constant — not user-configurable.
+    /// This is the pinned image deployed when no version is configured; use
+    /// [`TrackerConfig::image`] for the image of a given configuration.
     ///
     /// # Examples
     ///
@@ -546,7 +938,19 @@ impl PortDerivation for TrackerConfig {
     /// - PORT-04: HTTP ports WITH TLS NOT exposed (Caddy handles)
     /// - PORT-05: API port exposed only when no TLS
     /// - PORT-06: API port NOT exposed when TLS
+    ///
+    /// With several replicas the bindings of every replica are returned, each
+    /// shifted by its offset (see [`TrackerConfig::replica_port_bindings`]).
     fn derive_ports(&self) -> Vec<PortBinding> {
+        (0..self.replicas.get())
+            .flat_map(|index| self.replica_port_bindings(index))
+            .collect()
+    }
+}
+
+impl TrackerConfig {
+    /// Port bindings of a single tracker container
+    fn single_container_ports(&self) -> Vec<PortBinding> {
         let mut ports = Vec::new();
 
         // PORT-02: UDP ports always exposed (UDP doesn't use TLS)
@@ -700,6 +1104,12 @@ struct TrackerConfigRaw {
     http_trackers: Vec<HttpTrackerConfig>,
     http_api: HttpApiConfig,
     health_check_api: HealthCheckApiConfig,
+    #[serde(default)]
+    version: Option<TrackerVersion>,
+    #[serde(default)]
+    overrides: TrackerOverrides,
+    #[serde(default)]
+    replicas: Option<NonZeroU8>,
 }
 
 impl<'de> Deserialize<'de> for TrackerConfig {
@@ -708,14 +1118,24 @@ impl<'de> Deserialize<'de> for TrackerConfig {
         D: serde::Deserializer<'de>,
     {
         let raw = TrackerConfigRaw::deserialize(deserializer)?;
-        TrackerConfig::new(
+        let config = TrackerConfig::new(
             raw.core,
             raw.udp_trackers,
             raw.http_trackers,
             raw.http_api,
             raw.health_check_api,
         )
-        .map_err(serde::de::Error::custom)
+        .map_err(serde::de::Error::custom)?;
+
+        let config = config
+            .with_overrides(raw.overrides)
+            .with_replicas(raw.replicas.unwrap_or(SINGLE_REPLICA))
+            .map_err(serde::de::Error::custom)?;
+
+        Ok(match raw.version {
+            Some(version) => config.with_version(version),
+            None => config,
+        })
     }
 }
 
@@ -1400,4 +1820,170 @@ mod tests {
             }
         }
     }
+
+    mod port_offset {
+        use super::*;
+
+        #[test]
+        fn it_should_shift_every_bind_port_by_the_offset() {
+            let config = TrackerConfig::default().with_port_offset(1000).unwrap();
+
+            assert_eq!(
+                config.udp_trackers()[0].bind_address(),
+                "0.0.0.0:7969".parse::<SocketAddr>().unwrap()
+            );
+            assert_eq!(config.http_trackers()[0].bind_address().port(), 8070);
+            assert_eq!(config.http_api().bind_address().port(), 2212);
+            assert_eq!(
+                config.health_check_api().bind_address(),
+                "127.0.0.1:2313".parse::<SocketAddr>().unwrap()
+            );
+        }
+
+        #[test]
+        fn it_should_reject_an_offset_that_moves_a_port_beyond_65535() {
+            let result = TrackerConfig::default().with_port_offset(60000);
+
+            assert!(matches!(
+                result,
+                Err(TrackerConfigError::PortOutOfRange { offset: 60000, .. })
+            ));
+        }
+    }
+
+    mod replicas {
+        use super::*;
+
+        use crate::testing::fixtures::mysql_tracker_config;
+
+        fn two_replicas() -> NonZeroU8 {
+            NonZeroU8::new(2).unwrap()
+        }
+
+        #[test]
+        fn it_should_run_a_single_replica_by_default() {
+            let config = TrackerConfig::default();
+
+            assert_eq!(config.replicas(), SINGLE_REPLICA);
+            assert_eq!(config.replica_configs(), vec![config.clone()]);
+        }
+
+        #[test]
+        fn it_should_offset_the_ports_of_each_replica() {
+            let config = mysql_tracker_config()
+                .with_replicas(two_replicas())
+                .unwrap();
+
+            let udp_ports: Vec<u16> = config
+                .replica_configs()
+                .iter()
+                .map(|replica| replica.udp_trackers()[0].bind_address().port())
+                .collect();
+
+            assert_eq!(udp_ports, vec![6969, 6970]);
+        }
+
+        #[test]
+        fn it_should_derive_the_ports_of_every_replica_with_unchanged_container_ports() {
+            let config = mysql_tracker_config()
+                .with_replicas(two_replicas())
+                .unwrap();
+
+            let bindings: Vec<String> = config
+                .derive_ports()
+                .iter()
+                .map(PortBinding::docker_compose_binding)
+                .collect();
+
+            assert_eq!(
+                bindings,
+                vec![
+                    "6969:6969/udp",
+                    "7070:7070",
+                    "1212:1212",
+                    "6970:6969/udp",
+                    "7071:7070",
+                    "1213:1212"
+                ]
+            );
+        }
+
+        #[test]
+        fn it_should_reject_replicas_whose_ports_go_beyond_65535() {
+            let config = test_tracker_config_with_core(
+                mysql_tracker_config().core().clone(),
+                vec![test_udp_tracker_config("0.0.0.0:65535")],
+                vec![],
+                test_http_api_config("0.0.0.0:1212", "token"),
+                test_health_check_api_config("127.0.0.1:1313"),
+            );
+
+            let result = config.with_replicas(two_replicas());
+
+            assert!(matches!(
+                result,
+                Err(TrackerConfigError::ReplicaPortOutOfRange { address, .. })
+                    if address.port() == 65535
+            ));
+        }
+
+        #[test]
+        fn it_should_reject_several_replicas_with_tls_proxied_services() {
+            let domain = crate::shared::DomainName::new("api.example.com").unwrap();
+            let config = test_tracker_config(
+                vec![],
+                vec![],
+                test_http_api_config_with_tls("0.0.0.0:1212", "token", Some(domain), true),
+                test_health_check_api_config("127.0.0.1:1313"),
+            );
+
+            let result = config.with_replicas(two_replicas());
+
+            assert!(matches!(
+                result,
+                Err(TrackerConfigError::ReplicasWithTlsProxy { .. })
+            ));
+        }
+
+        #[test]
+        fn it_should_name_the_compose_service_of_every_replica() {
+            let config = mysql_tracker_config()
+                .with_replicas(NonZeroU8::new(3).unwrap())
+                .unwrap();
+
+            assert_eq!(
+                config.replica_names(),
+                vec!["tracker", "tracker-2", "tracker-3"]
+            );
+        }
+
+        #[test]
+        fn it_should_reject_several_replicas_with_a_sqlite_database() {
+            let result = TrackerConfig::default().with_replicas(two_replicas());
+
+            assert!(matches!(
+                result,
+                Err(TrackerConfigError::ReplicasWithSqlite { replicas }) if replicas == two_replicas()
+            ));
+        }
+
+        #[test]
+        fn it_should_run_a_single_replica_with_a_sqlite_database() {
+            let result = TrackerConfig::default().with_replicas(SINGLE_REPLICA);
+
+            assert!(result.is_ok());
+        }
+
+        #[test]
+        fn it_should_keep_the_replicas_through_a_serialization_round_trip() {
+            let config = mysql_tracker_config()
+                .with_replicas(two_replicas())
+                .unwrap();
+
+            let json = serde_json::to_string(&config).unwrap();
+            let restored: TrackerConfig = serde_json::from_str(&json).unwrap();
+
+            assert_eq!(restored, config);
+        }
+    }
 }
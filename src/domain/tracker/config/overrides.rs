@@ -0,0 +1,311 @@
+//! Tracker tuning overrides
+//!
+//! The structured tracker configuration covers the settings the deployer
+//! derives the deployment topology from (database, bind addresses, TLS).
+//! Overrides tune the remaining tracker behaviour that is rendered into
+//! `tracker.toml` on top of the structured fields:
+//!
+//! | Override                   | `tracker.toml` key                      |
+//! |----------------------------|-----------------------------------------|
+//! | `announce_interval`        | `core.announce_policy.interval`         |
+//! | `min_announce_interval`    | `core.announce_policy.interval_min`     |
+//! | `max_peer_timeout`         | `core.tracker_policy.max_peer_timeout`  |
+//! | `tracker_usage_statistics` | `core.tracker_usage_statistics`         |
+//!
+//! ## Example
+//!
+//! ```rust
+//! use torrust_tracker_deployer_lib::domain::tracker::{TrackerOverrides, TrackerOverridesError};
+//!
+//! let overrides = TrackerOverrides::default().with_announce_interval(120).unwrap();
+//! assert_eq!(overrides.announce_interval(), 120);
+//! assert_eq!(overrides.min_announce_interval(), 120);
+//!
+//! // Bind addresses are derived from the tracker sections and cannot be overridden
+//! let error = TrackerOverridesError::for_unknown_key("http_api.bind_address");
+//! assert!(matches!(error, TrackerOverridesError::ManagedKey { .. }));
+//! ```
+
+use serde::{Deserialize, Serialize};
+use thiserror::Error;
+
+/// Announce interval (seconds) rendered when no override is set
+pub const DEFAULT_ANNOUNCE_INTERVAL: u32 = 300;
+
+/// `tracker.toml` keys rendered from the structured configuration
+///
+/// An override of one of these keys, or of a key nested below one of them,
+/// would silently change a value the deployer relies on (firewall rules,
+/// Docker port mappings, reverse proxy and database wiring).
+const MANAGED_KEYS: &[&str] = &[
+    "metadata",
+    "core.private",
+    "core.net",
+    "core.database",
+    "udp_trackers",
+    "http_trackers",
+    "http_api",
+    "health_check_api",
+];
+
+/// Errors that can occur when building `TrackerOverrides`
+#[derive(Debug, Clone, PartialEq, Eq, Error)]
+pub enum TrackerOverridesError {
+    /// An interval or timeout is zero
+    #[error("tracker override '{field}' must be greater than zero")]
+    Zero {
+        /// Name of the override
+        field: &'static str,
+    },
+
+    /// The minimum announce interval is longer than the announce interval
+    #[error(
+        "tracker override 'min_announce_interval' ({min}) cannot be greater than 'announce_interval' ({interval})"
+    )]
+    MinAnnounceIntervalTooLong {
+        /// Configured minimum announce interval
+        min: u32,
+        /// Configured announce interval
+        interval: u32,
+    },
+
+    /// The override targets a setting derived from the structured configuration
+    #[error("tracker override '{key}' conflicts with a setting managed by the deployer")]
+    ManagedKey {
+        /// The rejected key
+        key: String,
+    },
+
+    /// The override is not one of the supported settings
+    #[error("unsupported tracker override '{key}'")]
+    UnsupportedKey {
+        /// The rejected key
+        key: String,
+    },
+}
+
+impl TrackerOverridesError {
+    /// Returns the error for a `tracker.toml` key (dotted path) that is not a
+    /// supported override
+    ///
+    /// Keys derived from the structured configuration, or nested below or
+    /// above one of them, give `ManagedKey`; any other key gives
+    /// `UnsupportedKey`.
+    #[must_use]
+    pub fn for_unknown_key(key: &str) -> Self {
+        let overlaps = |managed: &&str| {
+            key == *managed
+                || key.starts_with(&format!("{managed}."))
+                || managed.starts_with(&format!("{key}."))
+        };
+
+        if MANAGED_KEYS.iter().any(overlaps) {
+            Self::ManagedKey {
+                key: key.to_string(),
+            }
+        } else {
+            Self::UnsupportedKey {
+                key: key.to_string(),
+            }
+        }
+    }
+
+    /// Returns troubleshooting guidance for this error
+    #[must_use]
+    pub fn help(&self) -> &'static str {
+        match self {
+            Self::Zero { .. } | Self::MinAnnounceIntervalTooLong { .. } => {
+                "Announce intervals and the peer timeout are durations in seconds.\n\
+                 \n\
+                 Fix: Use positive values and keep 'min_announce_interval' at or below\n\
+                 'announce_interval'.\n\
+                 \n\
+                 Example: \"overrides\": { \"announce_interval\": 300, \"min_announce_interval\": 120 }"
+            }
+            Self::ManagedKey { .. } => {
+                "This setting is generated from the structured tracker configuration.\n\
+                 The deployer derives firewall rules, Docker port mappings and the reverse\n\
+                 proxy from it, so an override would leave them out of sync.\n\
+                 \n\
+                 Fix: Change the corresponding field of the 'tracker' section instead\n\
+                 (e.g. 'tracker.http_api.bind_address') and remove the override."
+            }
+            Self::UnsupportedKey { .. } => {
+                "Supported tracker overrides:\n\
+                 - announce_interval (seconds)\n\
+                 - min_announce_interval (seconds)\n\
+                 - max_peer_timeout (seconds)\n\
+                 - tracker_usage_statistics (true/false)\n\
+                 \n\
+                 Fix: Remove the unsupported key from 'tracker.overrides'."
+            }
+        }
+    }
+}
+
+/// Tracker settings rendered into `tracker.toml` after the structured fields
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct TrackerOverrides {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    announce_interval: Option<u32>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    min_announce_interval: Option<u32>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    max_peer_timeout: Option<u32>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    tracker_usage_statistics: Option<bool>,
+}
+
+impl TrackerOverrides {
+    /// Sets the announce interval in seconds
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the interval is zero or shorter than a configured
+    /// minimum announce interval.
+    pub fn with_announce_interval(mut self, seconds: u32) -> Result<Self, TrackerOverridesError> {
+        self.announce_interval = Some(non_zero("announce_interval", seconds)?);
+        self.check_intervals()?;
+        Ok(self)
+    }
+
+    /// Sets the minimum announce interval in seconds
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the interval is zero or longer than a configured
+    /// announce interval.
+    pub fn with_min_announce_interval(
+        mut self,
+        seconds: u32,
+    ) -> Result<Self, TrackerOverridesError> {
+        self.min_announce_interval = Some(non_zero("min_announce_interval", seconds)?);
+        self.check_intervals()?;
+        Ok(self)
+    }
+
+    /// Sets the time in seconds after which an inactive peer is removed
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the timeout is zero.
+    pub fn with_max_peer_timeout(mut self, seconds: u32) -> Result<Self, TrackerOverridesError> {
+        self.max_peer_timeout = Some(non_zero("max_peer_timeout", seconds)?);
+        Ok(self)
+    }
+
+    /// Enables or disables the tracker usage statistics
+    #[must_use]
+    pub fn with_tracker_usage_statistics(mut self, enabled: bool) -> Self {
+        self.tracker_usage_statistics = Some(enabled);
+        self
+    }
+
+    /// Announce interval in seconds
+    #[must_use]
+    pub fn announce_interval(&self) -> u32 {
+        self.announce_interval.unwrap_or(DEFAULT_ANNOUNCE_INTERVAL)
+    }
+
+    /// Minimum announce interval in seconds
+    ///
+    /// Defaults to the announce interval when that is shorter than the
+    /// default, so that shortening only the interval stays valid.
+    #[must_use]
+    pub fn min_announce_interval(&self) -> u32 {
+        self.min_announce_interval
+            .unwrap_or_else(|| self.announce_interval().min(DEFAULT_ANNOUNCE_INTERVAL))
+    }
+
+    /// Peer timeout in seconds, when overridden
+    #[must_use]
+    pub fn max_peer_timeout(&self) -> Option<u32> {
+        self.max_peer_timeout
+    }
+
+    /// Whether tracker usage statistics are enabled, when overridden
+    #[must_use]
+    pub fn tracker_usage_statistics(&self) -> Option<bool> {
+        self.tracker_usage_statistics
+    }
+
+    /// Returns `true` when no override is set
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        *self == Self::default()
+    }
+
+    fn check_intervals(&self) -> Result<(), TrackerOverridesError> {
+        let (min, interval) = (self.min_announce_interval(), self.announce_interval());
+        if min > interval {
+            return Err(TrackerOverridesError::MinAnnounceIntervalTooLong { min, interval });
+        }
+        Ok(())
+    }
+}
+
+fn non_zero(field: &'static str, value: u32) -> Result<u32, TrackerOverridesError> {
+    if value == 0 {
+        Err(TrackerOverridesError::Zero { field })
+    } else {
+        Ok(value)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn it_should_render_the_defaults_when_nothing_is_overridden() {
+        let overrides = TrackerOverrides::default();
+
+        assert!(overrides.is_empty());
+        assert_eq!(overrides.announce_interval(), DEFAULT_ANNOUNCE_INTERVAL);
+        assert_eq!(overrides.min_announce_interval(), DEFAULT_ANNOUNCE_INTERVAL);
+        assert_eq!(overrides.max_peer_timeout(), None);
+    }
+
+    #[test]
+    fn it_should_reject_a_minimum_announce_interval_above_the_interval() {
+        let result = TrackerOverrides::default()
+            .with_announce_interval(600)
+            .and_then(|overrides| overrides.with_min_announce_interval(900));
+
+        assert_eq!(
+            result,
+            Err(TrackerOverridesError::MinAnnounceIntervalTooLong {
+                min: 900,
+                interval: 600
+            })
+        );
+    }
+
+    #[test]
+    fn it_should_reject_a_zero_peer_timeout() {
+        assert_eq!(
+            TrackerOverrides::default().with_max_peer_timeout(0),
+            Err(TrackerOverridesError::Zero {
+                field: "max_peer_timeout"
+            })
+        );
+    }
+
+    #[test]
+    fn it_should_reject_keys_managed_by_the_deployer() {
+        for key in ["http_api.bind_address", "http_api", "core", "udp_trackers"] {
+            assert!(matches!(
+                TrackerOverridesError::for_unknown_key(key),
+                TrackerOverridesError::ManagedKey { .. }
+            ));
+        }
+    }
+
+    #[test]
+    fn it_should_reject_unknown_keys() {
+        assert!(matches!(
+            TrackerOverridesError::for_unknown_key("logging.threshold"),
+            TrackerOverridesError::UnsupportedKey { .. }
+        ));
+    }
+}
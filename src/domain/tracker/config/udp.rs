@@ -38,6 +38,7 @@
 
 use std::fmt;
 use std::net::SocketAddr;
+use std::num::NonZeroU16;
 
 use serde::{Deserialize, Serialize};
 use thiserror::Error;
@@ -190,6 +191,17 @@ impl UdpTrackerConfig {
         })
     }
 
+    /// Returns a copy of this configuration bound to another port
+    ///
+    /// The IP address and the domain and TLS settings are kept. Any non-zero
+    /// port keeps the invariants satisfied.
+    #[must_use]
+    pub fn with_port(&self, port: NonZeroU16) -> Self {
+        let mut config = self.clone();
+        config.bind_address.set_port(port.get());
+        config
+    }
+
     // -------------------------------------------------------------------------
     // Getter methods - provide read-only access to fields
     // -------------------------------------------------------------------------
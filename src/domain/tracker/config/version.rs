@@ -0,0 +1,222 @@
+//! Tracker version (Docker image tag or digest)
+//!
+//! The tracker version selects which `torrust/tracker` image the release step
+//! deploys. It is either a tag (`3.0.0`, `develop`) or a content digest
+//! (`sha256:<64 hex characters>`). When no version is configured the pinned
+//! default tag is deployed.
+//!
+//! ## Example
+//!
+//! ```rust
+//! use torrust_tracker_deployer_lib::domain::tracker::TrackerVersion;
+//!
+//! let version = TrackerVersion::new("3.0.0").expect("valid tag");
+//! assert!(!version.is_digest());
+//!
+//! // Tags cannot contain spaces or start with a dot
+//! assert!(TrackerVersion::new("3.0 beta").is_err());
+//! assert!(TrackerVersion::new(".hidden").is_err());
+//! ```
+
+use std::fmt;
+
+use serde::{Deserialize, Serialize};
+use thiserror::Error;
+
+/// Longest tag accepted by Docker registries
+const MAX_TAG_LENGTH: usize = 128;
+
+/// Prefix of image content digests
+const DIGEST_PREFIX: &str = "sha256:";
+
+/// Number of hex characters in a SHA-256 digest
+const DIGEST_HEX_LENGTH: usize = 64;
+
+/// Errors that can occur when creating a `TrackerVersion`
+#[derive(Debug, Clone, PartialEq, Eq, Error)]
+pub enum TrackerVersionError {
+    /// The version is empty
+    #[error("tracker version cannot be empty")]
+    Empty,
+
+    /// The version is not a valid Docker image tag
+    #[error(
+        "invalid tracker version '{0}': a tag may contain letters, digits, '_', '.' and '-', must not start with '.' or '-' and is at most 128 characters long"
+    )]
+    InvalidTag(String),
+
+    /// The version looks like a digest but is not `sha256:` followed by 64 hex characters
+    #[error(
+        "invalid tracker image digest '{0}': expected 'sha256:' followed by 64 hex characters"
+    )]
+    InvalidDigest(String),
+}
+
+impl TrackerVersionError {
+    /// Returns troubleshooting guidance for this error
+    #[must_use]
+    pub fn help(&self) -> &'static str {
+        match self {
+            Self::Empty | Self::InvalidTag(_) => {
+                "The tracker version must be a Docker image tag of the torrust/tracker image.\n\
+                 \n\
+                 Fix: Use a published tag such as \"3.0.0\" or \"develop\", or remove the\n\
+                 \"version\" field to deploy the default tag.\n\
+                 \n\
+                 Example: \"tracker\": { \"version\": \"3.0.0\", ... }"
+            }
+            Self::InvalidDigest(_) => {
+                "An image digest pins the exact tracker image content.\n\
+                 \n\
+                 Fix: Copy the full digest, e.g. from `docker images --digests torrust/tracker`.\n\
+                 \n\
+                 Example: \"version\": \"sha256:<64 hex characters>\""
+            }
+        }
+    }
+}
+
+/// Version of the tracker Docker image to deploy
+///
+/// Holds a validated image tag or `sha256:` digest.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(try_from = "String", into = "String")]
+pub struct TrackerVersion(String);
+
+impl TrackerVersion {
+    /// Creates a tracker version from an image tag or `sha256:` digest
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the value is empty, a malformed digest, or not a
+    /// valid Docker image tag.
+    pub fn new(value: impl Into<String>) -> Result<Self, TrackerVersionError> {
+        let value = value.into();
+
+        if value.is_empty() {
+            return Err(TrackerVersionError::Empty);
+        }
+
+        if let Some(hex) = value.strip_prefix(DIGEST_PREFIX) {
+            let valid = hex.len() == DIGEST_HEX_LENGTH
+                && hex
+                    .chars()
+                    .all(|c| c.is_ascii_digit() || ('a'..='f').contains(&c));
+
+            return if valid {
+                Ok(Self(value))
+            } else {
+                Err(TrackerVersionError::InvalidDigest(value))
+            };
+        }
+
+        if !is_valid_tag(&value) {
+            return Err(TrackerVersionError::InvalidTag(value));
+        }
+
+        Ok(Self(value))
+    }
+
+    /// Whether the version pins an image digest rather than a tag
+    #[must_use]
+    pub fn is_digest(&self) -> bool {
+        self.0.starts_with(DIGEST_PREFIX)
+    }
+
+    /// Returns the tag or digest
+    #[must_use]
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+}
+
+/// Docker tag grammar: `[A-Za-z0-9_][A-Za-z0-9_.-]{0,127}`
+fn is_valid_tag(value: &str) -> bool {
+    let mut chars = value.chars();
+
+    let Some(first) = chars.next() else {
+        return false;
+    };
+
+    value.len() <= MAX_TAG_LENGTH
+        && (first.is_ascii_alphanumeric() || first == '_')
+        && chars.all(|c| c.is_ascii_alphanumeric() || matches!(c, '_' | '.' | '-'))
+}
+
+impl fmt::Display for TrackerVersion {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.0)
+    }
+}
+
+impl TryFrom<String> for TrackerVersion {
+    type Error = TrackerVersionError;
+
+    fn try_from(value: String) -> Result<Self, Self::Error> {
+        Self::new(value)
+    }
+}
+
+impl From<TrackerVersion> for String {
+    fn from(version: TrackerVersion) -> Self {
+        version.0
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn it_should_accept_release_and_branch_tags() {
+        for tag in ["3.0.0", "v3.0.0-rc.1", "develop", "latest", "_internal"] {
+            assert!(TrackerVersion::new(tag).is_ok(), "{tag} should be accepted");
+        }
+    }
+
+    #[test]
+    fn it_should_reject_malformed_tags() {
+        for tag in ["3.0 beta", ".hidden", "-dash", "a:b", "tag/with/slash"] {
+            assert_eq!(
+                TrackerVersion::new(tag),
+                Err(TrackerVersionError::InvalidTag(tag.to_string()))
+            );
+        }
+    }
+
+    #[test]
+    fn it_should_reject_a_tag_longer_than_128_characters() {
+        let tag = "a".repeat(129);
+
+        assert!(TrackerVersion::new(tag).is_err());
+    }
+
+    #[test]
+    fn it_should_reject_an_empty_version() {
+        assert_eq!(TrackerVersion::new(""), Err(TrackerVersionError::Empty));
+    }
+
+    #[test]
+    fn it_should_accept_a_sha256_digest() {
+        let digest = format!("sha256:{}", "ab12".repeat(16));
+
+        let version = TrackerVersion::new(digest).unwrap();
+
+        assert!(version.is_digest());
+    }
+
+    #[test]
+    fn it_should_reject_a_truncated_digest() {
+        assert!(matches!(
+            TrackerVersion::new("sha256:ab12"),
+            Err(TrackerVersionError::InvalidDigest(_))
+        ));
+    }
+
+    #[test]
+    fn it_should_reject_invalid_versions_when_deserializing() {
+        let result: Result<TrackerVersion, _> = serde_json::from_str(r#""3.0 beta""#);
+
+        assert!(result.is_err());
+    }
+}
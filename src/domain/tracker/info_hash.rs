@@ -0,0 +1,131 @@
+//! Info hashes of the torrents served by a tracker
+//!
+//! A tracker running in listed mode only serves the torrents whose info hash
+//! is on its whitelist. The whitelist is managed through the tracker API,
+//! which identifies a torrent by its info hash in hexadecimal.
+//!
+//! ## Rules
+//!
+//! - An info hash is the 20-byte SHA-1 of the torrent info dictionary,
+//!   written as 40 hexadecimal digits
+//! - Info hashes are stored in lowercase, as the tracker reports them
+
+use std::fmt;
+use std::str::FromStr;
+
+use serde::{Deserialize, Serialize};
+use thiserror::Error;
+
+/// Number of hexadecimal digits of an info hash
+pub const INFO_HASH_HEX_LENGTH: usize = 40;
+
+/// Errors that can occur when creating an info hash
+#[derive(Debug, Clone, Error, PartialEq, Eq)]
+pub enum InfoHashError {
+    /// The value is not 40 hexadecimal digits
+    #[error("Invalid info hash '{value}': expected {INFO_HASH_HEX_LENGTH} hexadecimal digits")]
+    InvalidInfoHash {
+        /// The rejected value
+        value: String,
+    },
+}
+
+/// A validated info hash, in lowercase hexadecimal
+///
+/// # Examples
+///
+/// ```rust
+/// use torrust_tracker_deployer_lib::domain::tracker::InfoHash;
+///
+/// let info_hash: InfoHash = "9C38422213E30BFF212B30C360D26F9A02136422".parse()?;
+/// assert_eq!(info_hash.as_str(), "9c38422213e30bff212b30c360d26f9a02136422");
+///
+/// assert!("not-an-info-hash".parse::<InfoHash>().is_err());
+/// # Ok::<(), Box<dyn std::error::Error>>(())
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
+#[serde(try_from = "String", into = "String")]
+pub struct InfoHash(String);
+
+impl InfoHash {
+    /// Create a validated info hash
+    ///
+    /// # Errors
+    ///
+    /// Returns [`InfoHashError::InvalidInfoHash`] if `value` is not made of
+    /// [`INFO_HASH_HEX_LENGTH`] hexadecimal digits
+    pub fn new(value: impl Into<String>) -> Result<Self, InfoHashError> {
+        let value = value.into();
+
+        if value.len() == INFO_HASH_HEX_LENGTH && value.chars().all(|c| c.is_ascii_hexdigit()) {
+            Ok(Self(value.to_ascii_lowercase()))
+        } else {
+            Err(InfoHashError::InvalidInfoHash { value })
+        }
+    }
+
+    /// Returns the info hash as a lowercase hexadecimal string slice
+    #[must_use]
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+}
+
+impl fmt::Display for InfoHash {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.0)
+    }
+}
+
+impl FromStr for InfoHash {
+    type Err = InfoHashError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::new(s)
+    }
+}
+
+impl TryFrom<String> for InfoHash {
+    type Error = InfoHashError;
+
+    fn try_from(value: String) -> Result<Self, Self::Error> {
+        Self::new(value)
+    }
+}
+
+impl From<InfoHash> for String {
+    fn from(info_hash: InfoHash) -> Self {
+        info_hash.0
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const INFO_HASH: &str = "9c38422213e30bff212b30c360d26f9a02136422";
+
+    #[test]
+    fn it_should_store_an_info_hash_in_lowercase() {
+        let info_hash = InfoHash::new(INFO_HASH.to_uppercase()).unwrap();
+
+        assert_eq!(info_hash.to_string(), INFO_HASH);
+    }
+
+    #[test]
+    fn it_should_reject_values_with_another_length_or_other_characters() {
+        for value in [
+            "",
+            "abc",
+            &format!("{INFO_HASH}0"),
+            "9c38422213e30bff212b30c360d26f9a0213642g",
+        ] {
+            assert_eq!(
+                InfoHash::new(value),
+                Err(InfoHashError::InvalidInfoHash {
+                    value: value.to_string()
+                })
+            );
+        }
+    }
+}
@@ -6,7 +6,9 @@
 //! # Module Structure
 //!
 //! - `config` - Main `TrackerConfig` and component configurations (includes database)
+//! - `auth_key` - Authentication keys of a private tracker
 //! - `binding_address` - Socket binding address with protocol information
+//! - `info_hash` - Info hashes of the torrents on the whitelist of a tracker
 //! - `protocol` - Network protocol types (UDP, TCP)
 //!
 //! # Layer Separation
@@ -48,15 +50,21 @@
 //! ).expect("valid tracker config");
 //! ```
 
+pub mod auth_key;
 mod binding_address;
 pub mod config;
+pub mod info_hash;
 mod protocol;
 
+pub use auth_key::{AuthKey, AuthKeyError, IssuedAuthKey};
 pub use binding_address::BindingAddress;
 pub use config::{
-    is_localhost, DatabaseConfig, HealthCheckApiConfig, HealthCheckApiConfigError, HttpApiConfig,
-    HttpApiConfigError, HttpTrackerConfig, HttpTrackerConfigError, MysqlConfig, MysqlConfigError,
-    SqliteConfig, SqliteConfigError, TrackerConfig, TrackerConfigError, TrackerCoreConfig,
-    UdpTrackerConfig, UdpTrackerConfigError,
+    is_localhost, DatabaseConfig, HealthCheckApiConfig, HealthCheckApiConfigError, HealthCheckWait,
+    HealthCheckWaitError, HttpApiConfig, HttpApiConfigError, HttpTrackerConfig,
+    HttpTrackerConfigError, MysqlConfig, MysqlConfigError, MysqlConfigOptions, SqliteConfig,
+    SqliteConfigError, TrackerConfig, TrackerConfigError, TrackerCoreConfig, TrackerOverrides,
+    TrackerOverridesError, TrackerVersion, TrackerVersionError, UdpTrackerConfig,
+    UdpTrackerConfigError,
 };
+pub use info_hash::{InfoHash, InfoHashError};
 pub use protocol::{Protocol, ProtocolParseError};
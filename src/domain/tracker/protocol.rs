@@ -6,6 +6,8 @@
 use std::fmt;
 use std::str::FromStr;
 
+use serde::{Deserialize, Serialize};
+
 /// Network protocol used by tracker services
 ///
 /// Distinguishes between UDP and TCP protocols for socket binding validation.
@@ -23,7 +25,8 @@ use std::str::FromStr;
 /// assert_eq!(udp.to_string(), "UDP");
 /// assert_eq!(tcp.to_string(), "TCP");
 /// ```
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
 pub enum Protocol {
     /// User Datagram Protocol - connectionless protocol
     Udp,
@@ -0,0 +1,62 @@
+//! Passphrase encryption of bundle secrets
+//!
+//! Secrets are encrypted with the `age` scrypt recipient, so a bundle can be
+//! decrypted with the passphrase alone, or with the `age` CLI if needed.
+
+use age::secrecy::SecretString;
+
+use super::archive::BundleError;
+use crate::shared::secrets::Password;
+
+/// Encrypts `plaintext` with `passphrase`
+///
+/// # Errors
+///
+/// Returns `BundleError::Encryption` if encryption fails.
+pub fn encrypt(plaintext: &[u8], passphrase: &Password) -> Result<Vec<u8>, BundleError> {
+    let recipient = age::scrypt::Recipient::new(secret(passphrase));
+
+    age::encrypt(&recipient, plaintext).map_err(|e| BundleError::Encryption {
+        reason: e.to_string(),
+    })
+}
+
+/// Decrypts `ciphertext` produced by [`encrypt`] with `passphrase`
+///
+/// # Errors
+///
+/// Returns `BundleError::DecryptionFailed` if the passphrase is wrong or the
+/// ciphertext is corrupted.
+pub fn decrypt(ciphertext: &[u8], passphrase: &Password) -> Result<Vec<u8>, BundleError> {
+    let identity = age::scrypt::Identity::new(secret(passphrase));
+
+    age::decrypt(&identity, ciphertext).map_err(|_| BundleError::DecryptionFailed)
+}
+
+fn secret(passphrase: &Password) -> SecretString {
+    SecretString::from(passphrase.expose_secret().to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn it_should_decrypt_what_it_encrypted_with_the_same_passphrase() {
+        let passphrase = Password::new("correct horse battery staple");
+
+        let ciphertext = encrypt(b"private key", &passphrase).unwrap();
+
+        assert_ne!(ciphertext, b"private key");
+        assert_eq!(decrypt(&ciphertext, &passphrase).unwrap(), b"private key");
+    }
+
+    #[test]
+    fn it_should_fail_to_decrypt_with_a_wrong_passphrase() {
+        let ciphertext = encrypt(b"private key", &Password::new("right")).unwrap();
+
+        let result = decrypt(&ciphertext, &Password::new("wrong"));
+
+        assert!(matches!(result, Err(BundleError::DecryptionFailed)));
+    }
+}
@@ -0,0 +1,104 @@
+//! Bundle manifest
+//!
+//! The manifest is the last entry written to a bundle. It records the
+//! versions needed to interpret the bundle and the SHA-256 checksum of every
+//! other file, which `import-bundle` verifies before touching the workspace.
+
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+/// Version of the bundle layout written by this deployer
+///
+/// Bump it whenever the layout of a bundle changes in a way older
+/// deployers cannot read.
+pub const BUNDLE_FORMAT_VERSION: u32 = 2;
+
+/// Path of the manifest inside the bundle
+pub const MANIFEST_FILE: &str = "manifest.json";
+
+/// Path of the environment state inside the bundle
+///
+/// Only present in bundles without secrets, with the secret fields removed.
+pub const STATE_FILE: &str = "state.json";
+
+/// Path of the environment state, encrypted with a passphrase, inside the bundle
+///
+/// Only present in bundles with secrets.
+pub const ENCRYPTED_STATE_FILE: &str = "state.json.age";
+
+/// Path of the encrypted environment secrets inside the bundle
+///
+/// Only present when secrets are included and the environment has any.
+pub const SECRETS_FILE: &str = "secrets.json.age";
+
+/// Directory holding the build directory of the environment inside the bundle
+pub const BUILD_DIR: &str = "build";
+
+/// Directory holding the SSH keys of the environment inside the bundle
+pub const SSH_DIR: &str = "ssh";
+
+/// Extension appended to files encrypted with a passphrase
+pub const ENCRYPTED_EXTENSION: &str = "age";
+
+/// Whether the secrets of the environment travel with the bundle
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum BundleSecrets {
+    /// The secrets are included, encrypted with a passphrase
+    Encrypted,
+
+    /// The secrets were left out (`--no-secrets`): the bundle cannot be imported
+    Excluded,
+}
+
+/// A file stored in the bundle
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct BundleFile {
+    /// Path of the file inside the bundle, using `/` separators
+    pub path: String,
+
+    /// Size of the file in bytes
+    pub size: u64,
+
+    /// Hex encoded SHA-256 checksum of the file content
+    pub sha256: String,
+}
+
+/// Description of the content of a bundle
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct BundleManifest {
+    /// Version of the bundle layout
+    pub format_version: u32,
+
+    /// Name of the exported environment
+    pub environment: String,
+
+    /// Schema version of `state.json`
+    pub state_schema_version: u32,
+
+    /// Version of the deployer that wrote the bundle
+    pub deployer_version: String,
+
+    /// When the bundle was written
+    pub exported_at: DateTime<Utc>,
+
+    /// Whether the secrets of the environment are included
+    pub secrets: BundleSecrets,
+
+    /// Path of the (encrypted) SSH private key inside the bundle, if included
+    pub ssh_private_key: Option<String>,
+
+    /// Path of the SSH public key inside the bundle, if included
+    pub ssh_public_key: Option<String>,
+
+    /// Every file of the bundle except the manifest itself
+    pub files: Vec<BundleFile>,
+}
+
+impl BundleManifest {
+    /// Returns the manifest entry of the file at `path`, if any
+    #[must_use]
+    pub fn file(&self, path: &str) -> Option<&BundleFile> {
+        self.files.iter().find(|file| file.path == path)
+    }
+}
@@ -0,0 +1,38 @@
+//! Environment bundles
+//!
+//! A bundle is a single `.tar.gz` file holding everything needed to manage
+//! an environment from another machine, written by `export` and read by
+//! `import-bundle`.
+//!
+//! ## Bundle Layout
+//!
+//! ```text
+//! manifest.json          Format and state schema versions, checksum of every file
+//! state.json.age         The environment state, encrypted with a passphrase
+//! secrets.json.age       The environment secrets, encrypted with a passphrase
+//! build/...              The build directory, including the OpenTofu state
+//! build/.../<file>.age   A build file holding secrets, encrypted with a passphrase
+//! ssh/<key>.pub          The SSH public key
+//! ssh/<key>.age          The SSH private key, encrypted with a passphrase
+//! ```
+//!
+//! Everything holding a secret is encrypted with the `age` passphrase
+//! (scrypt) recipient, so the bundle can travel over untrusted channels.
+//!
+//! A bundle exported without a passphrase has no secrets at all: its
+//! `state.json` lacks the secret fields, and the private key, the secrets
+//! and the build files holding secrets are left out. It cannot be imported.
+//!
+//! ## Module Structure
+//!
+//! - `manifest` - The manifest describing the content of a bundle
+//! - `archive` - Writing a bundle and extracting it with checksum validation
+//! - `encryption` - Passphrase encryption of the secrets of a bundle
+
+pub mod archive;
+pub mod encryption;
+pub mod manifest;
+
+pub use archive::{extract_bundle, BundleError, BundleWriter};
+pub use encryption::{decrypt, encrypt};
+pub use manifest::{BundleFile, BundleManifest, BundleSecrets, BUNDLE_FORMAT_VERSION};
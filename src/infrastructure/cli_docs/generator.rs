@@ -119,10 +119,10 @@ impl CliDocsGenerator {
             cli.insert("global_arguments".to_string(), json!(global_args));
         }
 
-        // Extract subcommands (excluding built-in help)
+        // Extract subcommands (excluding built-in help and hidden internal commands)
         let subcommands: Vec<Value> = command
             .get_subcommands()
-            .filter(|cmd| cmd.get_name() != "help")
+            .filter(|cmd| cmd.get_name() != "help" && !cmd.is_hide_set())
             .map(|cmd| Value::Object(schema_builder::build_subcommand_schema(cmd)))
             .collect();
 
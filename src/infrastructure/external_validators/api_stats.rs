@@ -0,0 +1,128 @@
+//! Tracker API statistics external validation
+//!
+//! This module provides the `ApiStatsValidator` which requests the tracker
+//! statistics (`GET /api/v1/stats`) from OUTSIDE the VM through the tracker
+//! API client, authenticated with the admin token.
+//!
+//! Unlike the health check endpoint, the statistics endpoint requires a valid
+//! token, so it also proves that the deployed admin token is the configured one.
+
+use std::net::IpAddr;
+
+use tracing::{info, instrument};
+
+use crate::infrastructure::remote_actions::{RemoteAction, RemoteActionError};
+use crate::infrastructure::tracker_api::{TrackerApi, TrackerApiClient, TrackerApiError};
+
+/// Action that requests the tracker statistics with the admin token
+pub struct ApiStatsValidator<A = TrackerApiClient> {
+    api: A,
+}
+
+impl<A: TrackerApi> ApiStatsValidator<A> {
+    /// Create a new `ApiStatsValidator`
+    ///
+    /// # Arguments
+    /// * `api` - Client of the tracker API, with the admin token of the tracker
+    #[must_use]
+    pub fn new(api: A) -> Self {
+        Self { api }
+    }
+}
+
+impl<A: TrackerApi> RemoteAction for ApiStatsValidator<A> {
+    fn name(&self) -> &'static str {
+        "api-stats-validation"
+    }
+
+    #[instrument(
+        name = "api_stats_validation",
+        skip(self),
+        fields(
+            action_type = "validation",
+            component = "tracker_api",
+            server_ip = %server_ip,
+        )
+    )]
+    async fn execute(&self, server_ip: &IpAddr) -> Result<(), RemoteActionError> {
+        let stats = self.api.stats().await.map_err(|error| {
+            let message = match error {
+                TrackerApiError::Unauthorized { .. } => format!(
+                    "{error}. Check that the admin token matches the tracker configuration."
+                ),
+                error => error.to_string(),
+            };
+            RemoteActionError::ValidationFailed {
+                action_name: self.name().to_string(),
+                message,
+            }
+        })?;
+
+        info!(
+            action = "api_stats_validation",
+            torrents = stats.torrents,
+            "Tracker API statistics retrieved"
+        );
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::net::Ipv4Addr;
+
+    use wiremock::matchers::{method, path, query_param};
+    use wiremock::{Mock, MockServer, ResponseTemplate};
+
+    use super::*;
+    use crate::shared::{ApiToken, ServiceEndpoint};
+
+    /// Validator requesting the statistics from `server` with the admin token
+    fn validator(server: &MockServer) -> ApiStatsValidator {
+        ApiStatsValidator::new(TrackerApiClient::new(
+            ServiceEndpoint::http(*server.address(), "/api/v1/").unwrap(),
+            ApiToken::from("MyAccessToken".to_string()),
+        ))
+    }
+
+    fn server_ip() -> IpAddr {
+        IpAddr::V4(Ipv4Addr::LOCALHOST)
+    }
+
+    #[tokio::test]
+    async fn it_should_pass_when_the_api_returns_the_statistics() {
+        let server = MockServer::start().await;
+        Mock::given(method("GET"))
+            .and(path("/api/v1/stats"))
+            .and(query_param("token", "MyAccessToken"))
+            .respond_with(
+                ResponseTemplate::new(200)
+                    .set_body_string(r#"{"torrents":0,"seeders":0,"completed":0,"leechers":0}"#),
+            )
+            .expect(1)
+            .mount(&server)
+            .await;
+
+        assert!(validator(&server).execute(&server_ip()).await.is_ok());
+    }
+
+    #[tokio::test]
+    async fn it_should_point_at_the_admin_token_when_it_is_rejected() {
+        let server = MockServer::start().await;
+        Mock::given(method("GET"))
+            .and(path("/api/v1/stats"))
+            .respond_with(ResponseTemplate::new(401).set_body_string("Unauthorized"))
+            .expect(1)
+            .mount(&server)
+            .await;
+
+        let result = validator(&server).execute(&server_ip()).await;
+
+        assert!(matches!(
+            result,
+            Err(RemoteActionError::ValidationFailed { message, .. })
+                if message.contains("Check that the admin token matches")
+        ));
+    }
+}
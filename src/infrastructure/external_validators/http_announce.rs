@@ -0,0 +1,183 @@
+//! HTTP tracker announce external validation
+//!
+//! This module provides the `HttpAnnounceValidator` which sends an announce
+//! request with a synthetic info-hash to every HTTP tracker from OUTSIDE the
+//! VM, and checks that the tracker answers with a bencoded announce response
+//! instead of a failure.
+//!
+//! Unlike the health check endpoint, an announce goes through the tracker
+//! core, so it also proves that the tracker can read and write its database.
+
+use std::net::IpAddr;
+use std::time::Duration;
+
+use percent_encoding::{percent_encode, NON_ALPHANUMERIC};
+use tracing::{info, instrument};
+
+use super::http_client;
+use super::{SMOKE_TEST_INFO_HASH, SMOKE_TEST_PEER_ID, SMOKE_TEST_PEER_PORT};
+use crate::infrastructure::remote_actions::{RemoteAction, RemoteActionError};
+use crate::shared::ServiceEndpoint;
+
+/// HTTP client request timeout
+const REQUEST_TIMEOUT_SECS: u64 = 10;
+
+/// Action that announces a synthetic peer on every HTTP tracker
+pub struct HttpAnnounceValidator {
+    announce_endpoints: Vec<ServiceEndpoint>,
+}
+
+impl HttpAnnounceValidator {
+    /// Create a new `HttpAnnounceValidator`
+    ///
+    /// # Arguments
+    /// * `announce_endpoints` - Announce endpoints (`/announce`) of the HTTP trackers
+    #[must_use]
+    pub fn new(announce_endpoints: Vec<ServiceEndpoint>) -> Self {
+        Self { announce_endpoints }
+    }
+
+    /// Announce on one tracker and check its response
+    async fn announce(&self, endpoint: &ServiceEndpoint) -> Result<(), RemoteActionError> {
+        let failed = |message: String| RemoteActionError::ValidationFailed {
+            action_name: self.name().to_string(),
+            message,
+        };
+
+        let client = http_client::client_for(endpoint, Duration::from_secs(REQUEST_TIMEOUT_SECS))
+            .map_err(|e| failed(format!("Failed to build HTTP client: {e}")))?;
+
+        let mut url = endpoint.url().clone();
+        url.set_query(Some(&announce_query()));
+
+        let response = client
+            .get(url)
+            .send()
+            .await
+            .map_err(|e| failed(http_client::request_failure_message(endpoint, &e)))?;
+
+        let status = response.status();
+        if !status.is_success() {
+            return Err(failed(format!(
+                "Announce to '{}' returned HTTP {status}",
+                endpoint.url()
+            )));
+        }
+
+        let body = response.bytes().await.map_err(|e| {
+            failed(format!(
+                "Failed to read announce response from '{}': {e}",
+                endpoint.url()
+            ))
+        })?;
+
+        check_announce_response(&body)
+            .map_err(|reason| failed(format!("Announce to '{}' {reason}", endpoint.url())))
+    }
+}
+
+impl RemoteAction for HttpAnnounceValidator {
+    fn name(&self) -> &'static str {
+        "http-announce-validation"
+    }
+
+    #[instrument(
+        name = "http_announce_validation",
+        skip(self),
+        fields(
+            action_type = "validation",
+            component = "http_tracker",
+            server_ip = %server_ip,
+        )
+    )]
+    async fn execute(&self, server_ip: &IpAddr) -> Result<(), RemoteActionError> {
+        for endpoint in &self.announce_endpoints {
+            self.announce(endpoint).await?;
+
+            info!(
+                action = "http_announce_validation",
+                url = %endpoint.url(),
+                "HTTP announce succeeded"
+            );
+        }
+
+        Ok(())
+    }
+}
+
+/// Query string of an announce for the synthetic peer, as defined by BEP 3
+fn announce_query() -> String {
+    format!(
+        "info_hash={}&peer_id={}&port={SMOKE_TEST_PEER_PORT}&uploaded=0&downloaded=0&left=0&compact=1",
+        percent_encode(&SMOKE_TEST_INFO_HASH, NON_ALPHANUMERIC),
+        percent_encode(&SMOKE_TEST_PEER_ID, NON_ALPHANUMERIC),
+    )
+}
+
+/// Check that a bencoded body is an announce response and not a failure
+fn check_announce_response(body: &[u8]) -> Result<(), String> {
+    const FAILURE_KEY: &[u8] = b"14:failure reason";
+
+    if let Some(start) = find(body, FAILURE_KEY) {
+        let reason = bencoded_string(&body[start + FAILURE_KEY.len()..])
+            .unwrap_or_else(|| "unknown reason".to_string());
+        return Err(format!("was rejected by the tracker: {reason}"));
+    }
+
+    if find(body, b"8:interval").is_none() {
+        return Err("returned a response that is not an announce response".to_string());
+    }
+
+    Ok(())
+}
+
+/// Decode the bencoded string (`<length>:<bytes>`) at the start of `data`
+fn bencoded_string(data: &[u8]) -> Option<String> {
+    let colon = data.iter().position(|&byte| byte == b':')?;
+    let length: usize = std::str::from_utf8(&data[..colon]).ok()?.parse().ok()?;
+    let value = data.get(colon + 1..colon + 1 + length)?;
+    Some(String::from_utf8_lossy(value).into_owned())
+}
+
+fn find(haystack: &[u8], needle: &[u8]) -> Option<usize> {
+    haystack
+        .windows(needle.len())
+        .position(|window| window == needle)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn it_should_accept_an_announce_response() {
+        let body = b"d8:completei0e10:incompletei1e8:intervali120e5:peers0:e";
+
+        assert_eq!(check_announce_response(body), Ok(()));
+    }
+
+    #[test]
+    fn it_should_report_the_failure_reason_of_a_rejected_announce() {
+        let body = b"d14:failure reason23:torrent not whitelistede";
+
+        assert_eq!(
+            check_announce_response(body),
+            Err("was rejected by the tracker: torrent not whitelisted".to_string())
+        );
+    }
+
+    #[test]
+    fn it_should_reject_a_body_that_is_not_an_announce_response() {
+        let body = b"<html>Not Found</html>";
+
+        assert!(check_announce_response(body).is_err());
+    }
+
+    #[test]
+    fn it_should_percent_encode_the_binary_info_hash_in_the_query() {
+        let query = announce_query();
+
+        assert!(query.starts_with("info_hash=%"));
+        assert!(query.contains(&format!("port={SMOKE_TEST_PEER_PORT}")));
+    }
+}
@@ -0,0 +1,86 @@
+//! HTTP client shared by the external validators
+//!
+//! Builds a `reqwest` client able to reach a [`ServiceEndpoint`] from
+//! outside the VM, whether it is served over plain HTTP or over HTTPS by
+//! the Caddy reverse proxy.
+
+use std::time::Duration;
+
+use reqwest::{Client, ClientBuilder};
+use tracing::warn;
+
+use crate::shared::ServiceEndpoint;
+
+/// Build an HTTP client for requests to `endpoint`
+///
+/// For HTTPS endpoints, this:
+/// - Uses reqwest's `resolve()` to map the domain to the instance IP (like curl --resolve)
+/// - Accepts self-signed certificates for `.local` domains (Caddy's internal CA)
+///
+/// # Errors
+///
+/// Returns an error if the TLS backend cannot be initialized.
+pub(crate) fn client_for(endpoint: &ServiceEndpoint, timeout: Duration) -> reqwest::Result<Client> {
+    client_accepting_untrusted_certs(endpoint, timeout, false)
+}
+
+/// Build an HTTP client for requests to `endpoint`, optionally trusting any certificate
+///
+/// Same as [`client_for`], but with `accept_untrusted_certs` certificates
+/// that do not chain to a trusted root are accepted for every domain, e.g.
+/// the Let's Encrypt staging certificates.
+///
+/// # Errors
+///
+/// Returns an error if the TLS backend cannot be initialized.
+pub(crate) fn client_accepting_untrusted_certs(
+    endpoint: &ServiceEndpoint,
+    timeout: Duration,
+    accept_untrusted_certs: bool,
+) -> reqwest::Result<Client> {
+    let mut client_builder = ClientBuilder::new().timeout(timeout);
+
+    if let Some(domain) = endpoint.domain() {
+        client_builder = client_builder.resolve(domain, endpoint.socket_addr());
+
+        if endpoint.is_local_domain() {
+            warn!(
+                domain = domain,
+                "Accepting self-signed certificates for .local domain"
+            );
+            client_builder = client_builder.danger_accept_invalid_certs(true);
+        } else if accept_untrusted_certs {
+            warn!(
+                domain = domain,
+                "Accepting untrusted certificates (Let's Encrypt staging)"
+            );
+            client_builder = client_builder.danger_accept_invalid_certs(true);
+        }
+    }
+
+    client_builder.build()
+}
+
+/// Describe a failed request to `endpoint` with a hint about the likely cause
+pub(crate) fn request_failure_message(
+    endpoint: &ServiceEndpoint,
+    error: &reqwest::Error,
+) -> String {
+    let url = endpoint.url();
+
+    if endpoint.uses_tls() {
+        format!(
+            "HTTPS request to '{url}' failed: {error}. \
+             Check that Caddy is running and port 443 is open. \
+             Domain '{}' was resolved to {} for testing.",
+            endpoint.domain().unwrap_or("unknown"),
+            endpoint.server_ip()
+        )
+    } else {
+        format!(
+            "HTTP request to '{url}' failed: {error}. \
+             Check that service is running and firewall allows port {}.",
+            endpoint.port()
+        )
+    }
+}
@@ -26,7 +26,31 @@
 //! ## Available Validators
 //!
 //! - `running_services` - Validates Docker Compose services via external HTTP/HTTPS health checks
+//! - `udp_announce` - Announces on every UDP tracker with the UDP tracker client of `shared`
+//! - `http_announce` - Announces on every HTTP tracker with a synthetic info-hash
+//! - `api_stats` - Requests the tracker statistics with the admin token
 
+pub mod api_stats;
+pub mod http_announce;
+pub(crate) mod http_client;
 pub mod running_services;
+pub mod udp_announce;
 
+pub use api_stats::ApiStatsValidator;
+pub use http_announce::HttpAnnounceValidator;
 pub use running_services::RunningServicesValidator;
+pub use udp_announce::UdpAnnounceValidator;
+
+/// Info-hash of the synthetic torrent announced by the smoke checks
+///
+/// No real torrent has it, so announcing it never disturbs real swarms.
+pub const SMOKE_TEST_INFO_HASH: [u8; 20] = [
+    0x9c, 0x38, 0x42, 0x2b, 0x9d, 0x6a, 0x1f, 0x04, 0xe5, 0x7d, 0x11, 0xc8, 0x33, 0x5a, 0x87, 0x0e,
+    0xf2, 0x64, 0xb9, 0x01,
+];
+
+/// Peer ID of the synthetic peer announced by the smoke checks
+pub const SMOKE_TEST_PEER_ID: [u8; 20] = *b"-TD0001-smoketest000";
+
+/// Port of the synthetic peer announced by the smoke checks
+pub const SMOKE_TEST_PEER_PORT: u16 = 6881;
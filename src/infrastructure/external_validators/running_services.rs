@@ -47,9 +47,9 @@ use std::net::IpAddr;
 use std::path::PathBuf;
 use std::time::Duration;
 
-use reqwest::ClientBuilder;
-use tracing::{info, instrument, warn};
+use tracing::{info, instrument};
 
+use super::http_client;
 use crate::infrastructure::remote_actions::{RemoteAction, RemoteActionError};
 use crate::shared::ServiceEndpoint;
 
@@ -184,62 +184,26 @@ impl RunningServicesValidator {
 
     /// Make an HTTP/HTTPS request to the endpoint
     ///
-    /// For HTTPS endpoints, this:
-    /// - Uses reqwest's `resolve()` to map domain to IP (like curl --resolve)
-    /// - Accepts self-signed certificates for `.local` domains
+    /// For HTTPS endpoints, the domain is resolved to the instance IP locally
+    /// and self-signed certificates are accepted for `.local` domains.
     async fn make_request(
         &self,
         endpoint: &ServiceEndpoint,
     ) -> Result<reqwest::Response, RemoteActionError> {
-        let url = endpoint.url();
-        let mut client_builder =
-            ClientBuilder::new().timeout(Duration::from_secs(REQUEST_TIMEOUT_SECS));
-
-        // For HTTPS endpoints, configure domain resolution and certificate handling
-        if let Some(domain) = endpoint.domain() {
-            // Resolve domain to IP locally (like curl --resolve)
-            client_builder = client_builder.resolve(domain, endpoint.socket_addr());
-
-            // Accept self-signed certs for .local domains (Caddy's internal CA)
-            if endpoint.is_local_domain() {
-                warn!(
-                    action = "running_services_validation",
-                    domain = domain,
-                    "Accepting self-signed certificates for .local domain"
-                );
-                client_builder = client_builder.danger_accept_invalid_certs(true);
-            }
-        }
-
-        let client = client_builder
-            .build()
+        let client = http_client::client_for(endpoint, Duration::from_secs(REQUEST_TIMEOUT_SECS))
+            .map_err(|e| RemoteActionError::ValidationFailed {
+            action_name: self.name().to_string(),
+            message: format!("Failed to build HTTP client: {e}"),
+        })?;
+
+        client
+            .get(endpoint.url().clone())
+            .send()
+            .await
             .map_err(|e| RemoteActionError::ValidationFailed {
                 action_name: self.name().to_string(),
-                message: format!("Failed to build HTTP client: {e}"),
-            })?;
-
-        client.get(url.clone()).send().await.map_err(|e| {
-            let help_message = if endpoint.uses_tls() {
-                format!(
-                    "HTTPS request to '{url}' failed: {e}. \
-                     Check that Caddy is running and port 443 is open. \
-                     Domain '{}' was resolved to {} for testing.",
-                    endpoint.domain().unwrap_or("unknown"),
-                    endpoint.server_ip()
-                )
-            } else {
-                format!(
-                    "HTTP request to '{url}' failed: {e}. \
-                     Check that service is running and firewall allows port {}.",
-                    endpoint.port()
-                )
-            };
-
-            RemoteActionError::ValidationFailed {
-                action_name: self.name().to_string(),
-                message: help_message,
-            }
-        })
+                message: http_client::request_failure_message(endpoint, &e),
+            })
     }
 }
 
@@ -0,0 +1,85 @@
+//! UDP tracker announce external validation
+//!
+//! This module provides the `UdpAnnounceValidator` which verifies from OUTSIDE
+//! the VM that every UDP tracker completes a connect and announce round-trip
+//! for a synthetic torrent.
+//!
+//! A UDP tracker has no health check endpoint, so an announce is the only way
+//! to prove that it is running and that the firewall lets UDP traffic through.
+
+use std::net::{IpAddr, SocketAddr};
+use std::time::Duration;
+
+use tracing::{info, instrument};
+
+use super::{SMOKE_TEST_INFO_HASH, SMOKE_TEST_PEER_ID, SMOKE_TEST_PEER_PORT};
+use crate::infrastructure::remote_actions::{RemoteAction, RemoteActionError};
+use crate::shared::udp_tracker_client::{UdpRetryPolicy, UdpTrackerClient};
+
+/// Retries of the smoke check: 1, 2 then 4 seconds per attempt
+///
+/// Much shorter than the BEP 15 policy, the check has a timeout of its own.
+const SMOKE_TEST_RETRY_POLICY: UdpRetryPolicy = UdpRetryPolicy::new(Duration::from_secs(1), 2);
+
+/// Action that announces a synthetic peer on every UDP tracker
+pub struct UdpAnnounceValidator {
+    tracker_addrs: Vec<SocketAddr>,
+}
+
+impl UdpAnnounceValidator {
+    /// Create a new `UdpAnnounceValidator`
+    ///
+    /// # Arguments
+    /// * `tracker_addrs` - Public addresses of the UDP trackers
+    #[must_use]
+    pub fn new(tracker_addrs: Vec<SocketAddr>) -> Self {
+        Self { tracker_addrs }
+    }
+}
+
+impl RemoteAction for UdpAnnounceValidator {
+    fn name(&self) -> &'static str {
+        "udp-announce-validation"
+    }
+
+    #[instrument(
+        name = "udp_announce_validation",
+        skip(self),
+        fields(
+            action_type = "validation",
+            component = "udp_tracker",
+            server_ip = %server_ip,
+        )
+    )]
+    async fn execute(&self, server_ip: &IpAddr) -> Result<(), RemoteActionError> {
+        for tracker_addr in &self.tracker_addrs {
+            let response = UdpTrackerClient::new(*tracker_addr)
+                .with_retry_policy(SMOKE_TEST_RETRY_POLICY)
+                .announce(
+                    &SMOKE_TEST_INFO_HASH,
+                    &SMOKE_TEST_PEER_ID,
+                    SMOKE_TEST_PEER_PORT,
+                )
+                .await
+                .map_err(|e| RemoteActionError::ValidationFailed {
+                    action_name: self.name().to_string(),
+                    message: format!(
+                        "UDP announce to udp://{tracker_addr} failed: {e}. \
+                         Check that the tracker container is running and firewall allows UDP port {}.",
+                        tracker_addr.port()
+                    ),
+                })?;
+
+            info!(
+                action = "udp_announce_validation",
+                tracker = %tracker_addr,
+                interval = response.interval,
+                seeders = response.seeders,
+                leechers = response.leechers,
+                "UDP announce succeeded"
+            );
+        }
+
+        Ok(())
+    }
+}
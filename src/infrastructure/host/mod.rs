@@ -0,0 +1,17 @@
+//! Local host inspection module
+//!
+//! This module inspects the machine running the deployer, for the `doctor`
+//! command: free disk space, write permissions and group membership of the
+//! current user.
+//!
+//! ## Design Philosophy
+//!
+//! Every probe reports what it found instead of deciding whether it is a
+//! problem. Thresholds and severities belong to the application layer.
+
+pub mod probe;
+
+pub use probe::{
+    check_writable, current_user, disk_space, free_disk_space, DiskSpace, HostProbeError,
+    UserAccount,
+};
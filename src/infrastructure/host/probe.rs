@@ -0,0 +1,237 @@
+//! Probes of the local host
+//!
+//! Disk space and user information come from the standard `df` and `id`
+//! tools, which are available on every supported control host.
+
+use std::io;
+use std::path::{Path, PathBuf};
+
+use thiserror::Error;
+
+use crate::shared::command::{CommandError, CommandExecutor};
+
+/// Errors that can occur while probing the local host
+#[derive(Debug, Error)]
+pub enum HostProbeError {
+    /// A system tool could not be run
+    #[error("Failed to run '{command}': {source}")]
+    CommandFailed {
+        /// The command that failed
+        command: String,
+        /// The underlying command error
+        #[source]
+        source: CommandError,
+    },
+
+    /// A system tool printed something that could not be parsed
+    #[error("Unexpected output of '{command}': {output}")]
+    UnexpectedOutput {
+        /// The command whose output could not be parsed
+        command: String,
+        /// The unparsed output
+        output: String,
+    },
+
+    /// A file could not be created in a directory
+    #[error("Cannot write to '{path}': {source}")]
+    NotWritable {
+        /// The directory that is not writable
+        path: PathBuf,
+        /// The underlying I/O error
+        #[source]
+        source: io::Error,
+    },
+}
+
+/// The user running the deployer
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct UserAccount {
+    /// Login name
+    pub name: String,
+
+    /// Names of the groups the current session belongs to
+    pub groups: Vec<String>,
+}
+
+impl UserAccount {
+    /// Whether the user is `root`
+    #[must_use]
+    pub fn is_root(&self) -> bool {
+        self.name == "root"
+    }
+
+    /// Whether the current session belongs to `group`
+    ///
+    /// A group added with `usermod` only shows up in sessions started after
+    /// the change.
+    #[must_use]
+    pub fn in_group(&self, group: &str) -> bool {
+        self.groups.iter().any(|name| name == group)
+    }
+}
+
+/// Free space of the file system holding a path
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DiskSpace {
+    /// Mount point of the file system, e.g. `/` or `/home`
+    pub mount_point: String,
+
+    /// Space available to unprivileged users, in bytes
+    pub available: u64,
+}
+
+impl DiskSpace {
+    /// Parse the POSIX output of `df -Pk <path>`
+    ///
+    /// The output has a header line and one line for the file system, whose
+    /// fourth column is the available space in 1024-byte blocks and whose
+    /// sixth column onwards is the mount point (which may contain spaces).
+    #[must_use]
+    pub fn parse_df_output(output: &str) -> Option<Self> {
+        let line = output.lines().nth(1)?;
+        let columns: Vec<&str> = line.split_whitespace().collect();
+        let available_kib: u64 = columns.get(3)?.parse().ok()?;
+        let mount_point = columns.get(5..).filter(|rest| !rest.is_empty())?.join(" ");
+
+        Some(Self {
+            mount_point,
+            available: available_kib * 1024,
+        })
+    }
+}
+
+/// Free disk space, in bytes, of the file system holding `path`
+///
+/// # Errors
+///
+/// Returns an error if `df` cannot be run or its output cannot be parsed.
+pub fn free_disk_space(path: &Path) -> Result<u64, HostProbeError> {
+    disk_space(path).map(|space| space.available)
+}
+
+/// Free space and mount point of the file system holding `path`
+///
+/// # Errors
+///
+/// Returns an error if `df` cannot be run or its output cannot be parsed.
+pub fn disk_space(path: &Path) -> Result<DiskSpace, HostProbeError> {
+    let path = path.to_string_lossy();
+    let output = run("df", &["-Pk", &path])?;
+
+    DiskSpace::parse_df_output(&output).ok_or_else(|| HostProbeError::UnexpectedOutput {
+        command: format!("df -Pk {path}"),
+        output,
+    })
+}
+
+/// The user running the deployer and the groups of the current session
+///
+/// # Errors
+///
+/// Returns an error if `id` cannot be run.
+pub fn current_user() -> Result<UserAccount, HostProbeError> {
+    let name = run("id", &["-un"])?.trim().to_string();
+    let groups = run("id", &["-nG"])?
+        .split_whitespace()
+        .map(ToString::to_string)
+        .collect();
+
+    Ok(UserAccount { name, groups })
+}
+
+/// Check that files can be created in `dir`
+///
+/// A temporary file is created and removed right away.
+///
+/// # Errors
+///
+/// Returns `HostProbeError::NotWritable` if the file cannot be created.
+pub fn check_writable(dir: &Path) -> Result<(), HostProbeError> {
+    tempfile::Builder::new()
+        .prefix(".write-check-")
+        .tempfile_in(dir)
+        .map(drop)
+        .map_err(|source| HostProbeError::NotWritable {
+            path: dir.to_path_buf(),
+            source,
+        })
+}
+
+/// Run a system tool and return its standard output
+fn run(command: &str, args: &[&str]) -> Result<String, HostProbeError> {
+    CommandExecutor::new()
+        .run_command(command, args, None)
+        .map(|result| result.stdout)
+        .map_err(|source| HostProbeError::CommandFailed {
+            command: command.to_string(),
+            source,
+        })
+}
+
+#[cfg(test)]
+mod tests {
+    use tempfile::TempDir;
+
+    use super::*;
+
+    #[test]
+    fn it_should_parse_the_available_space_from_df_output() {
+        let output = "Filesystem     1024-blocks      Used Available Capacity Mounted on\n\
+                      /dev/nvme0n1p2   479597248 201234560 253912345      45% /\n";
+
+        assert_eq!(
+            DiskSpace::parse_df_output(output),
+            Some(DiskSpace {
+                mount_point: "/".to_string(),
+                available: 253_912_345 * 1024,
+            })
+        );
+    }
+
+    #[test]
+    fn it_should_parse_a_mount_point_with_spaces() {
+        let output = "Filesystem     1024-blocks      Used Available Capacity Mounted on\n\
+                      /dev/sdb1         10255636   8136524   1578440      84% /media/My Disk\n";
+
+        let space = DiskSpace::parse_df_output(output).unwrap();
+
+        assert_eq!(space.mount_point, "/media/My Disk");
+    }
+
+    #[test]
+    fn it_should_not_parse_df_output_without_a_file_system_line() {
+        let output = "Filesystem     1024-blocks      Used Available Capacity Mounted on\n";
+
+        assert_eq!(DiskSpace::parse_df_output(output), None);
+    }
+
+    #[test]
+    fn it_should_accept_a_writable_directory() {
+        let temp_dir = TempDir::new().unwrap();
+
+        check_writable(temp_dir.path()).unwrap();
+
+        assert_eq!(std::fs::read_dir(temp_dir.path()).unwrap().count(), 0);
+    }
+
+    #[test]
+    fn it_should_reject_a_missing_directory() {
+        let temp_dir = TempDir::new().unwrap();
+
+        let result = check_writable(&temp_dir.path().join("missing"));
+
+        assert!(matches!(result, Err(HostProbeError::NotWritable { .. })));
+    }
+
+    #[test]
+    fn it_should_find_the_groups_of_the_user() {
+        let user = UserAccount {
+            name: "torrust".to_string(),
+            groups: vec!["torrust".to_string(), "lxd".to_string()],
+        };
+
+        assert!(user.in_group("lxd"));
+        assert!(!user.in_group("docker"));
+        assert!(!user.is_root());
+    }
+}
@@ -15,15 +15,25 @@
 //! - `external_validators` - E2E validation from outside VMs (HTTP health checks)
 //! - `persistence` - Persistence infrastructure (repositories, file locking, storage)
 //! - `trace` - Trace file generation for error analysis
+//! - `reports` - Deployment report storage for auditing command runs
 //! - `schema` - JSON Schema generation from Rust types
 //! - `cli_docs` - CLI JSON documentation generation from Clap structures
+//! - `shell_completion` - Shell completion scripts generated from Clap structures
 //! - `dns` - DNS resolution for domain validation
+//! - `host` - Inspection of the local host (disk space, permissions, user groups)
+//! - `bundle` - Portable environment bundles written by `export` and read by `import-bundle`
+//! - `tracker_api` - Calls to the HTTP API of a deployed tracker (authentication keys)
 
+pub mod bundle;
 pub mod cli_docs;
 pub mod dns;
 pub mod external_validators;
+pub mod host;
 pub mod persistence;
 pub mod remote_actions;
+pub mod reports;
 pub mod schema;
+pub mod shell_completion;
 pub mod templating;
 pub mod trace;
+pub mod tracker_api;
@@ -0,0 +1,319 @@
+//! Contract tests shared by the `EnvironmentRepository` backends
+//!
+//! Every backend must behave the same for the commands, so each test runs
+//! against each backend created through `repository_provider`.
+
+use std::path::Path;
+use std::sync::Arc;
+use std::time::Duration;
+
+use rstest::rstest;
+use tempfile::TempDir;
+
+use super::repository_provider;
+use crate::domain::environment::repository::{
+    EnvironmentRepository, RepositoryError, StoredStateVersion,
+};
+use crate::domain::environment::state::AnyEnvironmentState;
+use crate::domain::environment::testing::EnvironmentTestBuilder;
+use crate::domain::environment::{Environment, EnvironmentName, RepositoryBackend};
+
+fn repository(
+    backend: RepositoryBackend,
+    data_dir: &Path,
+) -> Arc<dyn EnvironmentRepository + Send + Sync> {
+    repository_provider(backend, Duration::from_secs(1), None).create(data_dir.to_path_buf())
+}
+
+fn environment(name: &str) -> Environment {
+    let (env, _data_dir, _build_dir, _env_temp_dir) = EnvironmentTestBuilder::new()
+        .with_name(name)
+        .build_with_custom_paths();
+    env
+}
+
+fn name(name: &str) -> EnvironmentName {
+    EnvironmentName::new(name.to_string()).unwrap()
+}
+
+#[rstest]
+#[case::json(RepositoryBackend::Json)]
+#[case::sqlite(RepositoryBackend::Sqlite)]
+fn it_should_load_a_saved_environment(#[case] backend: RepositoryBackend) {
+    let temp_dir = TempDir::new().unwrap();
+    let repo = repository(backend, temp_dir.path());
+
+    repo.save(&AnyEnvironmentState::Created(environment("staging")))
+        .unwrap();
+
+    let loaded = repo.load(&name("staging")).unwrap().unwrap();
+    assert_eq!(loaded.name(), &name("staging"));
+    assert_eq!(loaded.state_name(), "created");
+}
+
+#[rstest]
+#[case::json(RepositoryBackend::Json)]
+#[case::sqlite(RepositoryBackend::Sqlite)]
+fn it_should_return_none_for_an_unknown_environment(#[case] backend: RepositoryBackend) {
+    let temp_dir = TempDir::new().unwrap();
+    let repo = repository(backend, temp_dir.path());
+
+    assert!(repo.load(&name("unknown")).unwrap().is_none());
+    assert!(repo.load_version(&name("unknown")).unwrap().is_none());
+    assert!(!repo.exists(&name("unknown")).unwrap());
+}
+
+#[rstest]
+#[case::json(RepositoryBackend::Json)]
+#[case::sqlite(RepositoryBackend::Sqlite)]
+fn it_should_change_the_stamp_of_an_environment_each_time_it_is_saved(
+    #[case] backend: RepositoryBackend,
+) {
+    let temp_dir = TempDir::new().unwrap();
+    let repo = repository(backend, temp_dir.path());
+    let env = environment("staging");
+    assert_eq!(repo.change_stamp(&name("staging")).unwrap(), None);
+
+    repo.save(&AnyEnvironmentState::Created(env.clone()))
+        .unwrap();
+    let created = repo.change_stamp(&name("staging")).unwrap();
+    repo.save(&AnyEnvironmentState::Provisioning(env.start_provisioning()))
+        .unwrap();
+    let provisioning = repo.change_stamp(&name("staging")).unwrap();
+
+    assert!(created.is_some());
+    assert_ne!(created, provisioning);
+    assert_eq!(repo.change_stamp(&name("staging")).unwrap(), provisioning);
+
+    repo.delete(&name("staging")).unwrap();
+    assert_eq!(repo.change_stamp(&name("staging")).unwrap(), None);
+}
+
+#[rstest]
+#[case::json(RepositoryBackend::Json)]
+#[case::sqlite(RepositoryBackend::Sqlite)]
+fn it_should_overwrite_the_stored_state(#[case] backend: RepositoryBackend) {
+    let temp_dir = TempDir::new().unwrap();
+    let repo = repository(backend, temp_dir.path());
+    let env = environment("staging");
+
+    repo.save(&AnyEnvironmentState::Created(env.clone()))
+        .unwrap();
+    repo.save(&AnyEnvironmentState::Provisioning(env.start_provisioning()))
+        .unwrap();
+
+    let loaded = repo.load(&name("staging")).unwrap().unwrap();
+    assert_eq!(loaded.state_name(), "provisioning");
+}
+
+#[rstest]
+#[case::json(RepositoryBackend::Json)]
+#[case::sqlite(RepositoryBackend::Sqlite)]
+fn it_should_delete_an_environment_idempotently(#[case] backend: RepositoryBackend) {
+    let temp_dir = TempDir::new().unwrap();
+    let repo = repository(backend, temp_dir.path());
+    repo.save(&AnyEnvironmentState::Created(environment("staging")))
+        .unwrap();
+    assert!(repo.exists(&name("staging")).unwrap());
+
+    repo.delete(&name("staging")).unwrap();
+    repo.delete(&name("staging")).unwrap();
+
+    assert!(!repo.exists(&name("staging")).unwrap());
+    assert!(repo.list_backups(&name("staging")).unwrap().is_empty());
+}
+
+#[rstest]
+#[case::json(RepositoryBackend::Json)]
+#[case::sqlite(RepositoryBackend::Sqlite)]
+fn it_should_list_the_stored_environments_sorted(#[case] backend: RepositoryBackend) {
+    let temp_dir = TempDir::new().unwrap();
+    let repo = repository(backend, temp_dir.path());
+    assert!(repo.list().unwrap().is_empty());
+
+    for env_name in ["staging", "production", "dev"] {
+        repo.save(&AnyEnvironmentState::Created(environment(env_name)))
+            .unwrap();
+    }
+    repo.delete(&name("dev")).unwrap();
+
+    assert_eq!(
+        repo.list().unwrap(),
+        vec![name("production"), name("staging")]
+    );
+}
+
+#[rstest]
+#[case::json(RepositoryBackend::Json)]
+#[case::sqlite(RepositoryBackend::Sqlite)]
+fn it_should_keep_the_secrets_of_an_environment(#[case] backend: RepositoryBackend) {
+    let temp_dir = TempDir::new().unwrap();
+    let repo = repository(backend, temp_dir.path());
+    let state = AnyEnvironmentState::Created(environment("staging"));
+    let password = state
+        .grafana_config()
+        .unwrap()
+        .admin_password()
+        .unwrap()
+        .clone();
+
+    repo.save(&state).unwrap();
+
+    let loaded = repo.load(&name("staging")).unwrap().unwrap();
+    assert_eq!(
+        loaded.grafana_config().unwrap().admin_password(),
+        Some(&password)
+    );
+}
+
+#[rstest]
+#[case::json(RepositoryBackend::Json)]
+#[case::sqlite(RepositoryBackend::Sqlite)]
+fn it_should_record_the_current_versions(#[case] backend: RepositoryBackend) {
+    let temp_dir = TempDir::new().unwrap();
+    let repo = repository(backend, temp_dir.path());
+
+    repo.save(&AnyEnvironmentState::Created(environment("staging")))
+        .unwrap();
+
+    assert_eq!(
+        repo.load_version(&name("staging")).unwrap(),
+        Some(StoredStateVersion::current())
+    );
+}
+
+#[rstest]
+#[case::json(RepositoryBackend::Json)]
+#[case::sqlite(RepositoryBackend::Sqlite)]
+fn it_should_keep_the_most_recent_previous_states(#[case] backend: RepositoryBackend) {
+    let temp_dir = TempDir::new().unwrap();
+    let repo = repository(backend, temp_dir.path());
+    let env = environment("staging");
+    let provisioning = env.clone().start_provisioning();
+
+    repo.save(&AnyEnvironmentState::Created(env.clone()))
+        .unwrap();
+    assert!(repo.list_backups(&name("staging")).unwrap().is_empty());
+    for _ in 0..6 {
+        repo.save(&AnyEnvironmentState::Created(env.clone()))
+            .unwrap();
+    }
+    repo.save(&AnyEnvironmentState::Provisioning(provisioning))
+        .unwrap();
+
+    let backups = repo.list_backups(&name("staging")).unwrap();
+    assert_eq!(backups.len(), 5);
+    assert!(backups.iter().all(|backup| backup.state_name == "created"));
+    assert!(
+        backups.windows(2).all(|pair| pair[0].id > pair[1].id),
+        "backups should be newest first"
+    );
+}
+
+#[rstest]
+#[case::json(RepositoryBackend::Json)]
+#[case::sqlite(RepositoryBackend::Sqlite)]
+fn it_should_restore_a_previous_state(#[case] backend: RepositoryBackend) {
+    let temp_dir = TempDir::new().unwrap();
+    let repo = repository(backend, temp_dir.path());
+    let env = environment("staging");
+    let password = env
+        .grafana_config()
+        .unwrap()
+        .admin_password()
+        .unwrap()
+        .clone();
+    repo.save(&AnyEnvironmentState::Created(env.clone()))
+        .unwrap();
+    repo.save(&AnyEnvironmentState::Provisioning(env.start_provisioning()))
+        .unwrap();
+
+    let backups = repo.list_backups(&name("staging")).unwrap();
+    repo.restore_backup(&name("staging"), &backups[0].id)
+        .unwrap();
+
+    let loaded = repo.load(&name("staging")).unwrap().unwrap();
+    assert_eq!(loaded.state_name(), "created");
+    assert_eq!(
+        loaded.grafana_config().unwrap().admin_password(),
+        Some(&password)
+    );
+}
+
+#[rstest]
+#[case::json(RepositoryBackend::Json)]
+#[case::sqlite(RepositoryBackend::Sqlite)]
+fn it_should_return_not_found_when_restoring_an_unknown_backup(#[case] backend: RepositoryBackend) {
+    let temp_dir = TempDir::new().unwrap();
+    let repo = repository(backend, temp_dir.path());
+    repo.save(&AnyEnvironmentState::Created(environment("staging")))
+        .unwrap();
+
+    let result = repo.restore_backup(&name("staging"), "20260101T000000.000000000Z");
+
+    assert!(matches!(result, Err(RepositoryError::NotFound)));
+}
+
+#[rstest]
+#[case::json(RepositoryBackend::Json)]
+#[case::sqlite(RepositoryBackend::Sqlite)]
+fn it_should_refuse_to_lock_an_environment_that_is_already_locked(
+    #[case] backend: RepositoryBackend,
+) {
+    let temp_dir = TempDir::new().unwrap();
+    let repo = repository(backend, temp_dir.path());
+    let other_repo = repository(backend, temp_dir.path());
+
+    let guard = repo.lock(&name("staging")).unwrap();
+
+    assert_eq!(guard.environment_name(), &name("staging"));
+    assert!(matches!(
+        other_repo.lock(&name("staging")),
+        Err(RepositoryError::Conflict)
+    ));
+    assert!(other_repo.lock(&name("production")).is_ok());
+}
+
+#[rstest]
+#[case::json(RepositoryBackend::Json)]
+#[case::sqlite(RepositoryBackend::Sqlite)]
+fn it_should_unlock_an_environment_when_its_guard_is_dropped(#[case] backend: RepositoryBackend) {
+    let temp_dir = TempDir::new().unwrap();
+    let repo = repository(backend, temp_dir.path());
+    let guard = repo.lock(&name("staging")).unwrap();
+
+    let waiter = std::thread::spawn({
+        let repo = repository(backend, temp_dir.path());
+        move || repo.lock(&name("staging")).map(|_| ())
+    });
+    std::thread::sleep(Duration::from_millis(200));
+    drop(guard);
+
+    assert!(waiter.join().unwrap().is_ok());
+    assert!(repo.lock(&name("staging")).is_ok());
+}
+
+#[rstest]
+#[case::json(RepositoryBackend::Json)]
+#[case::sqlite(RepositoryBackend::Sqlite)]
+fn it_should_load_save_and_delete_a_locked_environment(#[case] backend: RepositoryBackend) {
+    let temp_dir = TempDir::new().unwrap();
+    let repo = repository(backend, temp_dir.path());
+    let env = environment("staging");
+
+    let _guard = repo.lock(&name("staging")).unwrap();
+    assert!(repo.list().unwrap().is_empty());
+
+    repo.save(&AnyEnvironmentState::Created(env.clone()))
+        .unwrap();
+    repo.save(&AnyEnvironmentState::Provisioning(env.start_provisioning()))
+        .unwrap();
+    assert_eq!(
+        repo.load(&name("staging")).unwrap().unwrap().state_name(),
+        "provisioning"
+    );
+    assert_eq!(repo.list().unwrap(), vec![name("staging")]);
+
+    repo.delete(&name("staging")).unwrap();
+    assert!(!repo.exists(&name("staging")).unwrap());
+}
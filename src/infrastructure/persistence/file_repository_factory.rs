@@ -35,6 +35,7 @@ use std::time::Duration;
 use crate::application::traits::RepositoryProvider;
 use crate::domain::environment::repository::EnvironmentRepository;
 use crate::infrastructure::persistence::filesystem::file_environment_repository::FileEnvironmentRepository;
+use crate::infrastructure::persistence::workspace_key::WorkspaceKey;
 
 /// Factory for creating `FileEnvironmentRepository` instances
 ///
@@ -44,6 +45,8 @@ use crate::infrastructure::persistence::filesystem::file_environment_repository:
 pub struct FileRepositoryFactory {
     /// Lock acquisition timeout for all repositories created by this factory
     lock_timeout: Duration,
+    /// Key sealing the secrets written by the created repositories, if any
+    workspace_key: Option<Arc<WorkspaceKey>>,
 }
 
 impl FileRepositoryFactory {
@@ -63,7 +66,17 @@ impl FileRepositoryFactory {
     /// ```
     #[must_use]
     pub fn new(lock_timeout: Duration) -> Self {
-        Self { lock_timeout }
+        Self {
+            lock_timeout,
+            workspace_key: None,
+        }
+    }
+
+    /// Seal the secrets written by the created repositories with `workspace_key`
+    #[must_use]
+    pub fn with_workspace_key(mut self, workspace_key: Option<Arc<WorkspaceKey>>) -> Self {
+        self.workspace_key = workspace_key;
+        self
     }
 
     /// Create a new `FileEnvironmentRepository` for a specific data directory
@@ -90,6 +103,10 @@ impl FileRepositoryFactory {
     pub fn create(&self, data_dir: PathBuf) -> Arc<dyn EnvironmentRepository + Send + Sync> {
         let repository =
             FileEnvironmentRepository::new(data_dir).with_lock_timeout(self.lock_timeout);
+        let repository = match &self.workspace_key {
+            Some(workspace_key) => repository.with_workspace_key(Arc::clone(workspace_key)),
+            None => repository,
+        };
         Arc::new(repository)
     }
 }
@@ -171,6 +188,7 @@ mod tests {
         );
         let provider_config = ProviderConfig::Lxd(LxdConfig {
             profile_name: ProfileName::new(format!("lxd-{}", env_name.as_str())).unwrap(),
+            remote: None,
         });
         let environment = Environment::new(
             env_name.clone(),
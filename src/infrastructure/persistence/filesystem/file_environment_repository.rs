@@ -14,8 +14,34 @@
 //! ```text
 //! ./data/{env_name}/environment.json       # Environment state
 //! ./data/{env_name}/environment.json.lock  # Lock file (contains process ID)
+//! ./data/{env_name}/secrets.json           # Environment secrets (owner-only, 0600)
+//! ./data/{env_name}/state-backups/         # Previous states (environment-{timestamp}.json)
+//! ./data/.locks/{env_name}.lock            # Environment lock held by a command (`lock`)
 //! ```
 //!
+//! The environment locks live outside the environment directories, so that
+//! they survive the environment being deleted or renamed while locked.
+//!
+//! Before the environment state is overwritten, the previous state is copied to
+//! `state-backups/` when it is readable. Only the most recent backups are kept
+//! (`DEFAULT_STATE_BACKUP_LIMIT` unless configured with `with_backup_limit`).
+//! A backup is named after the time its state was written, which is also its
+//! id for `restore_backup`.
+//!
+//! The environment file records the version of its schema and of the deployer
+//! that wrote it (`schema_version` and `deployer_version`, next to the state).
+//! Files written with an older schema are migrated when loaded (see
+//! `domain::environment::repository::migrations`); files written with a newer
+//! schema are rejected.
+//!
+//! Secrets such as the Grafana admin password are not serialized with the
+//! environment state. They are stored in `secrets.json` and restored into the
+//! environment when it is loaded.
+//!
+//! With a workspace key (`with_workspace_key`), the secrets left in
+//! `environment.json` and every value of `secrets.json` are written encrypted
+//! (see `workspace_key`). Plaintext files written without a key still load.
+//!
 //! # Usage
 //!
 //! ```rust,no_run
@@ -32,15 +58,34 @@
 //! ```
 
 use std::fs;
-use std::path::PathBuf;
-use std::time::Duration;
+use std::path::{Path, PathBuf};
+use std::sync::Arc;
+use std::time::{Duration, UNIX_EPOCH};
+
+use anyhow::Context;
+use chrono::{DateTime, Utc};
+use serde_json::Value;
+use tracing::warn;
 
 use crate::domain::environment::name::EnvironmentName;
-use crate::domain::environment::repository::{EnvironmentRepository, RepositoryError};
+use crate::domain::environment::repository::{
+    EnvironmentLockGuard, EnvironmentRepository, RepositoryError, StateBackup, StoredStateVersion,
+};
 use crate::domain::environment::state::AnyEnvironmentState;
+use crate::domain::environment::EnvironmentSecrets;
+use crate::infrastructure::persistence::filesystem::file_lock::{FileLock, FileLockError};
 use crate::infrastructure::persistence::filesystem::json_file_repository::{
     JsonFileError, JsonFileRepository,
 };
+use crate::infrastructure::persistence::stored_environment::{
+    backup_created_at, backup_id, open_secrets_document, seal_document, secrets_document,
+    StoredEnvironment,
+};
+use crate::infrastructure::persistence::workspace_key::{
+    holds_plaintext_secrets, SecretValues, WorkspaceKey,
+};
+
+pub use crate::infrastructure::persistence::stored_environment::DEFAULT_STATE_BACKUP_LIMIT;
 
 /// File-based implementation of `EnvironmentRepository`
 ///
@@ -52,13 +97,31 @@ use crate::infrastructure::persistence::filesystem::json_file_repository::{
 /// Each environment gets its own directory under the base directory:
 /// - `{base_dir}/{env_name}/environment.json` - Environment file
 /// - `{base_dir}/{env_name}/environment.json.lock` - Lock file
+/// - `{base_dir}/{env_name}/secrets.json` - Secrets file (only when there are secrets)
+/// - `{base_dir}/{env_name}/state-backups/` - Backups of previous environment files
+/// - `{base_dir}/.locks/{env_name}.lock` - Environment lock held by a command
 pub struct FileEnvironmentRepository {
     /// Base directory for environment state files (typically "./data")
     base_dir: PathBuf,
     /// Generic JSON file repository for file operations
     json_repo: JsonFileRepository,
+    /// Number of state backups kept per environment
+    backup_limit: usize,
+    /// Key sealing the secrets written to the files, if any
+    workspace_key: Option<Arc<WorkspaceKey>>,
 }
 
+/// Directory holding the state backups inside an environment directory
+const STATE_BACKUPS_DIR: &str = "state-backups";
+
+/// File name prefix of a state backup
+const STATE_BACKUP_PREFIX: &str = "environment-";
+
+/// Directory holding the environment locks inside the base directory
+///
+/// Its name is not a valid environment name, so it is never listed.
+const ENVIRONMENT_LOCKS_DIR: &str = ".locks";
+
 impl FileEnvironmentRepository {
     /// Create a new file-based environment repository
     ///
@@ -80,6 +143,8 @@ impl FileEnvironmentRepository {
         Self {
             base_dir,
             json_repo: JsonFileRepository::new(Duration::from_secs(10)),
+            backup_limit: DEFAULT_STATE_BACKUP_LIMIT,
+            workspace_key: None,
         }
     }
 
@@ -105,21 +170,217 @@ impl FileEnvironmentRepository {
         self
     }
 
+    /// Set the number of state backups kept per environment
+    ///
+    /// A limit of zero disables state backups.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use std::path::PathBuf;
+    /// use torrust_tracker_deployer_lib::infrastructure::persistence::filesystem::file_environment_repository::FileEnvironmentRepository;
+    ///
+    /// let repo = FileEnvironmentRepository::new(PathBuf::from("./data"))
+    ///     .with_backup_limit(10);
+    /// ```
+    #[must_use]
+    pub fn with_backup_limit(mut self, limit: usize) -> Self {
+        self.backup_limit = limit;
+        self
+    }
+
+    /// Seal the secrets written to the files with `workspace_key`
+    ///
+    /// Files holding sealed secrets can only be loaded with the same key.
+    #[must_use]
+    pub fn with_workspace_key(mut self, workspace_key: Arc<WorkspaceKey>) -> Self {
+        self.workspace_key = Some(workspace_key);
+        self
+    }
+
     /// Get the environment file path for an environment
     fn environment_file_path(&self, name: &EnvironmentName) -> PathBuf {
         self.base_dir.join(name.as_str()).join("environment.json")
     }
 
+    /// Get the secrets file path for an environment
+    fn secrets_file_path(&self, name: &EnvironmentName) -> PathBuf {
+        self.base_dir.join(name.as_str()).join("secrets.json")
+    }
+
     /// Get the directory path for an environment
     fn env_dir_path(&self, name: &EnvironmentName) -> PathBuf {
         self.base_dir.join(name.as_str())
     }
 
+    /// Get the path locked by the environment lock, `{name}.lock` once locked
+    fn environment_lock_path(&self, name: &EnvironmentName) -> PathBuf {
+        self.base_dir
+            .join(ENVIRONMENT_LOCKS_DIR)
+            .join(name.as_str())
+    }
+
+    /// Get the state backups directory path for an environment
+    fn backups_dir_path(&self, name: &EnvironmentName) -> PathBuf {
+        self.env_dir_path(name).join(STATE_BACKUPS_DIR)
+    }
+
+    /// Get the path of the backup with the given id
+    fn backup_file_path(&self, name: &EnvironmentName, backup_id: &str) -> PathBuf {
+        self.backups_dir_path(name)
+            .join(format!("{STATE_BACKUP_PREFIX}{backup_id}.json"))
+    }
+
+    /// Backup id for the state currently stored in `file_path`
+    ///
+    /// The id is the time the file was last written. File systems with coarse
+    /// timestamps can give two consecutive writes the same time, so the time
+    /// is moved forward to the next free id. Returns `None` when the file does
+    /// not exist or its modification time is unavailable.
+    fn next_backup_id(&self, name: &EnvironmentName, file_path: &Path) -> Option<String> {
+        let modified = fs::metadata(file_path).ok()?.modified().ok()?;
+        let mut written_at = DateTime::<Utc>::from(modified);
+
+        loop {
+            let backup_id = backup_id(written_at);
+            if !self.backup_file_path(name, &backup_id).exists() {
+                return Some(backup_id);
+            }
+            written_at += chrono::Duration::nanoseconds(1);
+        }
+    }
+
+    /// Ids of the stored backups of an environment, newest first
+    fn backup_ids(&self, name: &EnvironmentName) -> Result<Vec<String>, RepositoryError> {
+        let backups_dir = self.backups_dir_path(name);
+        if !backups_dir.exists() {
+            return Ok(Vec::new());
+        }
+
+        let entries = fs::read_dir(&backups_dir).with_context(|| {
+            format!(
+                "Failed to read state backups directory: {}",
+                backups_dir.display()
+            )
+        })?;
+
+        let mut ids: Vec<String> = entries
+            .filter_map(Result::ok)
+            .filter_map(|entry| {
+                let file_name = entry.file_name().into_string().ok()?;
+                let id = file_name
+                    .strip_prefix(STATE_BACKUP_PREFIX)?
+                    .strip_suffix(".json")?;
+                backup_created_at(id).map(|_| id.to_string())
+            })
+            .collect();
+
+        ids.sort_unstable_by(|a, b| b.cmp(a));
+        Ok(ids)
+    }
+
+    /// Save the environment file, backing up the state it replaces
+    fn save_environment_file(
+        &self,
+        name: &EnvironmentName,
+        env: &AnyEnvironmentState,
+    ) -> Result<(), RepositoryError> {
+        let file_path = self.environment_file_path(name);
+
+        let backup_id = if self.backup_limit == 0 {
+            None
+        } else {
+            self.next_backup_id(name, &file_path)
+        };
+
+        let stored = StoredEnvironment::current(env).sealed_with(self.workspace_key.as_deref());
+
+        match backup_id {
+            Some(backup_id) => {
+                let backup_path = self.backup_file_path(name, &backup_id);
+                self.json_repo
+                    .save_with_backup(&file_path, &stored, &backup_path)
+                    .map_err(Self::convert_json_error)?;
+                self.prune_backups(name)
+            }
+            None => self
+                .json_repo
+                .save(&file_path, &stored)
+                .map_err(Self::convert_json_error),
+        }
+    }
+
+    /// Remove the oldest backups beyond the backup limit
+    fn prune_backups(&self, name: &EnvironmentName) -> Result<(), RepositoryError> {
+        for backup_id in self.backup_ids(name)?.iter().skip(self.backup_limit) {
+            let backup_path = self.backup_file_path(name, backup_id);
+            fs::remove_file(&backup_path).with_context(|| {
+                format!("Failed to remove state backup: {}", backup_path.display())
+            })?;
+        }
+        Ok(())
+    }
+
+    /// Load and migrate an environment file
+    fn load_environment_file(
+        &self,
+        file_path: &Path,
+    ) -> Result<Option<StoredEnvironment<'static>>, RepositoryError> {
+        self.json_repo
+            .load::<Value>(file_path)
+            .map_err(Self::convert_json_error)?
+            .map(|value| StoredEnvironment::from_sealed_value(value, self.workspace_key.as_deref()))
+            .transpose()
+    }
+
+    /// Rewrite a file with its plaintext secrets sealed
+    ///
+    /// Returns `true` if the file held plaintext secrets, or was stored
+    /// before secrets could be sealed.
+    fn seal_file(
+        &self,
+        path: &Path,
+        workspace_key: &WorkspaceKey,
+        secrets: SecretValues,
+    ) -> Result<bool, RepositoryError> {
+        let Some(mut document) = self
+            .json_repo
+            .load::<Value>(path)
+            .map_err(Self::convert_json_error)?
+        else {
+            return Ok(false);
+        };
+
+        match secrets {
+            SecretValues::Environment => match seal_document(document, workspace_key)? {
+                Some(sealed) => document = sealed,
+                None => return Ok(false),
+            },
+            SecretValues::All => {
+                if !holds_plaintext_secrets(&document, secrets) {
+                    return Ok(false);
+                }
+                workspace_key
+                    .seal(&mut document, secrets)
+                    .map_err(|error| RepositoryError::Internal(error.into()))?;
+            }
+        }
+
+        self.json_repo
+            .save_private(path, &document)
+            .map_err(Self::convert_json_error)?;
+
+        Ok(true)
+    }
+
     /// Convert `JsonFileError` to `RepositoryError`
     fn convert_json_error(error: JsonFileError) -> RepositoryError {
         match error {
             JsonFileError::NotFound { .. } => RepositoryError::NotFound,
             JsonFileError::Conflict { .. } => RepositoryError::Conflict,
+            error @ JsonFileError::Corrupted { .. } => RepositoryError::Corrupted {
+                reason: error.to_string(),
+            },
             JsonFileError::Internal(e) => RepositoryError::Internal(e),
         }
     }
@@ -127,19 +388,118 @@ impl FileEnvironmentRepository {
 
 impl EnvironmentRepository for FileEnvironmentRepository {
     fn save(&self, env: &AnyEnvironmentState) -> Result<(), RepositoryError> {
-        let file_path = self.environment_file_path(env.name());
+        self.save_environment_file(env.name(), env)?;
 
-        self.json_repo
-            .save(&file_path, env)
-            .map_err(Self::convert_json_error)
+        let secrets_path = self.secrets_file_path(env.name());
+        let secrets = env.secrets();
+
+        if secrets.is_empty() {
+            self.json_repo.delete(&secrets_path)
+        } else {
+            let document = secrets_document(&secrets, self.workspace_key.as_deref())?;
+            self.json_repo.save_private(&secrets_path, &document)
+        }
+        .map_err(Self::convert_json_error)
     }
 
     fn load(&self, name: &EnvironmentName) -> Result<Option<AnyEnvironmentState>, RepositoryError> {
         let file_path = self.environment_file_path(name);
 
+        let Some(stored) = self.load_environment_file(&file_path)? else {
+            return Ok(None);
+        };
+        if stored.predates_sealed_secrets() {
+            self.seal_secrets(name)?;
+        }
+        let mut env = stored.into_state();
+
+        let secrets: Option<Value> = self
+            .json_repo
+            .load(&self.secrets_file_path(name))
+            .map_err(Self::convert_json_error)?;
+
+        if let Some(secrets) = secrets {
+            let secrets: EnvironmentSecrets =
+                open_secrets_document(secrets, self.workspace_key.as_deref())?;
+            env.restore_secrets(secrets);
+        }
+
+        if let Some(secret) = env.missing_secret() {
+            return Err(RepositoryError::MissingSecret {
+                secret: secret.to_string(),
+            });
+        }
+
+        Ok(Some(env))
+    }
+
+    fn load_version(
+        &self,
+        name: &EnvironmentName,
+    ) -> Result<Option<StoredStateVersion>, RepositoryError> {
+        let file_path = self.environment_file_path(name);
+
         self.json_repo
-            .load(&file_path)
-            .map_err(Self::convert_json_error)
+            .load::<Value>(&file_path)
+            .map_err(Self::convert_json_error)?
+            .map(|value| {
+                StoredStateVersion::read(&value).map_err(|error| RepositoryError::Corrupted {
+                    reason: error.to_string(),
+                })
+            })
+            .transpose()
+    }
+
+    /// Stamped with the modification time and size of `environment.json`.
+    fn change_stamp(&self, name: &EnvironmentName) -> Result<Option<String>, RepositoryError> {
+        let file_path = self.environment_file_path(name);
+
+        let metadata = match fs::metadata(&file_path) {
+            Ok(metadata) => metadata,
+            Err(error) if error.kind() == std::io::ErrorKind::NotFound => return Ok(None),
+            Err(error) => {
+                return Err(RepositoryError::Internal(
+                    anyhow::Error::from(error).context(format!(
+                        "Failed to read metadata of: {}",
+                        file_path.display()
+                    )),
+                ))
+            }
+        };
+
+        let modified = metadata
+            .modified()
+            .ok()
+            .and_then(|time| time.duration_since(UNIX_EPOCH).ok())
+            .map_or(0, |since_epoch| since_epoch.as_nanos());
+
+        Ok(Some(format!("{modified}:{}", metadata.len())))
+    }
+
+    /// Only the directory layout is inspected: a subdirectory counts when its
+    /// name is a valid `EnvironmentName` and it holds an `environment.json`.
+    fn list(&self) -> Result<Vec<EnvironmentName>, RepositoryError> {
+        let entries = match fs::read_dir(&self.base_dir) {
+            Ok(entries) => entries,
+            Err(error) if error.kind() == std::io::ErrorKind::NotFound => return Ok(Vec::new()),
+            Err(error) => {
+                return Err(RepositoryError::Internal(
+                    anyhow::Error::from(error).context(format!(
+                        "Failed to read data directory: {}",
+                        self.base_dir.display()
+                    )),
+                ))
+            }
+        };
+
+        let mut names: Vec<EnvironmentName> = entries
+            .filter_map(Result::ok)
+            .filter_map(|entry| EnvironmentName::new(entry.file_name().to_str()?).ok())
+            .filter(|name| self.environment_file_path(name).is_file())
+            .collect();
+        names.sort_by(|a, b| a.as_str().cmp(b.as_str()));
+
+        Ok(names)
     }
 
     fn exists(&self, name: &EnvironmentName) -> Result<bool, RepositoryError> {
@@ -154,6 +514,20 @@ impl EnvironmentRepository for FileEnvironmentRepository {
             .delete(&file_path)
             .map_err(Self::convert_json_error)?;
 
+        self.json_repo
+            .delete(&self.secrets_file_path(name))
+            .map_err(Self::convert_json_error)?;
+
+        let backups_dir = self.backups_dir_path(name);
+        if backups_dir.exists() {
+            fs::remove_dir_all(&backups_dir).with_context(|| {
+                format!(
+                    "Failed to remove state backups directory: {}",
+                    backups_dir.display()
+                )
+            })?;
+        }
+
         // Optionally, remove the environment directory if it's empty
         let env_dir = self.env_dir_path(name);
         if let Ok(mut entries) = fs::read_dir(&env_dir) {
@@ -165,12 +539,106 @@ impl EnvironmentRepository for FileEnvironmentRepository {
 
         Ok(())
     }
+
+    /// Held with a lock file in `.locks/`, separate from the lock files of
+    /// the other operations.
+    fn lock(&self, name: &EnvironmentName) -> Result<EnvironmentLockGuard, RepositoryError> {
+        let lock_path = self.environment_lock_path(name);
+        if let Some(locks_dir) = lock_path.parent() {
+            fs::create_dir_all(locks_dir).with_context(|| {
+                format!("Failed to create locks directory: {}", locks_dir.display())
+            })?;
+        }
+
+        match FileLock::acquire(&lock_path, self.json_repo.lock_timeout) {
+            Ok(file_lock) => Ok(EnvironmentLockGuard::new(name.clone(), file_lock)),
+            Err(
+                FileLockError::AcquisitionTimeout { .. } | FileLockError::LockHeldByProcess { .. },
+            ) => Err(RepositoryError::Conflict),
+            Err(e) => Err(RepositoryError::Internal(
+                anyhow::Error::from(e).context(format!("Failed to lock environment '{name}'")),
+            )),
+        }
+    }
+
+    fn list_backups(&self, name: &EnvironmentName) -> Result<Vec<StateBackup>, RepositoryError> {
+        let mut backups = Vec::new();
+
+        for backup_id in self.backup_ids(name)? {
+            let backup_path = self.backup_file_path(name, &backup_id);
+            let Some(created_at) = backup_created_at(&backup_id) else {
+                continue;
+            };
+
+            match self.load_environment_file(&backup_path) {
+                Ok(Some(stored)) => backups.push(StateBackup {
+                    id: backup_id,
+                    state_name: stored.state().state_name().to_string(),
+                    created_at,
+                }),
+                Ok(None) => {}
+                Err(error) => warn!(
+                    backup = %backup_path.display(),
+                    error = %error,
+                    "Skipping unreadable state backup"
+                ),
+            }
+        }
+
+        Ok(backups)
+    }
+
+    fn restore_backup(
+        &self,
+        name: &EnvironmentName,
+        backup_id: &str,
+    ) -> Result<(), RepositoryError> {
+        if !self.backup_ids(name)?.iter().any(|id| id == backup_id) {
+            return Err(RepositoryError::NotFound);
+        }
+
+        let state = self
+            .load_environment_file(&self.backup_file_path(name, backup_id))?
+            .ok_or(RepositoryError::NotFound)?
+            .into_state();
+
+        // Secrets are stored separately and kept as they are
+        self.save_environment_file(name, &state)
+    }
+
+    /// Seals `environment.json`, `secrets.json` and the state backups
+    fn seal_secrets(&self, name: &EnvironmentName) -> Result<bool, RepositoryError> {
+        let Some(workspace_key) = self.workspace_key.as_deref() else {
+            return Ok(false);
+        };
+
+        let files = [
+            (self.environment_file_path(name), SecretValues::Environment),
+            (self.secrets_file_path(name), SecretValues::All),
+        ];
+
+        let mut sealed = false;
+        for (path, secrets) in files {
+            sealed |= self.seal_file(&path, workspace_key, secrets)?;
+        }
+        for backup_id in self.backup_ids(name)? {
+            // Unreadable backups are never restored; they are left as they are
+            let path = self.backup_file_path(name, &backup_id);
+            sealed |= self
+                .seal_file(&path, workspace_key, SecretValues::Environment)
+                .unwrap_or(false);
+        }
+
+        Ok(sealed)
+    }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
     use crate::adapters::ssh::credentials::SshCredentials;
+    use crate::domain::environment::repository::migrations;
+    use crate::domain::environment::testing::EnvironmentTestBuilder;
     use crate::domain::environment::Environment;
     use crate::domain::provider::{LxdConfig, ProviderConfig};
     use crate::domain::ProfileName;
@@ -183,6 +651,7 @@ mod tests {
     fn default_lxd_provider_config(env_name: &EnvironmentName) -> ProviderConfig {
         ProviderConfig::Lxd(LxdConfig {
             profile_name: ProfileName::new(format!("lxd-{}", env_name.as_str())).unwrap(),
+            remote: None,
         })
     }
 
@@ -556,6 +1025,287 @@ mod tests {
         }
     }
 
+    #[test]
+    fn it_should_list_the_names_of_stored_environments_sorted() {
+        let temp_dir = TempDir::new().unwrap();
+        let repo = FileEnvironmentRepository::new(temp_dir.path().to_path_buf());
+
+        for name in ["staging", "production"] {
+            repo.save(&AnyEnvironmentState::Created(create_test_environment(name)))
+                .unwrap();
+        }
+        // Not environments: no state file, or a directory name that is not valid
+        fs::create_dir_all(temp_dir.path().join("logs")).unwrap();
+        fs::create_dir_all(temp_dir.path().join("Invalid_Name")).unwrap();
+        fs::write(
+            temp_dir
+                .path()
+                .join("Invalid_Name")
+                .join("environment.json"),
+            "{}",
+        )
+        .unwrap();
+
+        let names: Vec<String> = repo
+            .list()
+            .unwrap()
+            .iter()
+            .map(|name| name.as_str().to_string())
+            .collect();
+
+        assert_eq!(names, vec!["production", "staging"]);
+    }
+
+    #[test]
+    fn it_should_list_no_environments_when_the_base_directory_is_missing() {
+        let temp_dir = TempDir::new().unwrap();
+        let repo = FileEnvironmentRepository::new(temp_dir.path().join("missing"));
+
+        assert!(repo.list().unwrap().is_empty());
+    }
+
+    /// Truncate the environment file in the middle, as an interrupted write would
+    fn truncate_environment_file(temp_dir: &TempDir, name: &str) {
+        let file_path = temp_dir.path().join(name).join("environment.json");
+        let content = fs::read_to_string(&file_path).unwrap();
+        fs::write(&file_path, &content[..content.len() / 2]).unwrap();
+    }
+
+    #[test]
+    fn it_should_back_up_the_previous_state_when_saving() {
+        let temp_dir = TempDir::new().unwrap();
+        let repo = FileEnvironmentRepository::new(temp_dir.path().to_path_buf());
+
+        let env = create_test_environment("test-env");
+        let env_name = env.name().clone();
+        repo.save(&AnyEnvironmentState::Created(env.clone()))
+            .unwrap();
+        assert!(repo.list_backups(&env_name).unwrap().is_empty());
+
+        repo.save(&AnyEnvironmentState::Provisioning(env.start_provisioning()))
+            .unwrap();
+
+        let backups = repo.list_backups(&env_name).unwrap();
+        assert_eq!(backups.len(), 1);
+        assert_eq!(backups[0].state_name, "created");
+        assert!(temp_dir
+            .path()
+            .join("test-env")
+            .join("state-backups")
+            .join(format!("environment-{}.json", backups[0].id))
+            .exists());
+    }
+
+    #[test]
+    fn it_should_keep_only_the_most_recent_backups() {
+        let temp_dir = TempDir::new().unwrap();
+        let repo =
+            FileEnvironmentRepository::new(temp_dir.path().to_path_buf()).with_backup_limit(2);
+
+        let env = create_test_environment("test-env");
+        let env_name = env.name().clone();
+        let provisioning = env.clone().start_provisioning();
+        repo.save(&AnyEnvironmentState::Created(env.clone()))
+            .unwrap();
+        repo.save(&AnyEnvironmentState::Created(env)).unwrap();
+        repo.save(&AnyEnvironmentState::Provisioning(provisioning.clone()))
+            .unwrap();
+        repo.save(&AnyEnvironmentState::Provisioning(provisioning))
+            .unwrap();
+
+        let backups = repo.list_backups(&env_name).unwrap();
+        let states: Vec<&str> = backups.iter().map(|b| b.state_name.as_str()).collect();
+        assert_eq!(states, vec!["provisioning", "created"]);
+        assert!(
+            backups[0].id > backups[1].id,
+            "backups should be newest first"
+        );
+    }
+
+    #[test]
+    fn it_should_report_corrupted_state_when_the_environment_file_is_truncated() {
+        let temp_dir = TempDir::new().unwrap();
+        let repo = FileEnvironmentRepository::new(temp_dir.path().to_path_buf());
+
+        let env = create_test_environment("test-env");
+        let env_name = env.name().clone();
+        repo.save(&AnyEnvironmentState::Created(env)).unwrap();
+        truncate_environment_file(&temp_dir, "test-env");
+
+        let result = repo.load(&env_name);
+
+        assert!(
+            matches!(result, Err(RepositoryError::Corrupted { .. })),
+            "Expected Corrupted error, got: {result:?}"
+        );
+    }
+
+    #[test]
+    fn it_should_record_the_schema_and_deployer_versions_in_the_environment_file() {
+        let temp_dir = TempDir::new().unwrap();
+        let repo = FileEnvironmentRepository::new(temp_dir.path().to_path_buf());
+
+        let env = create_test_environment("test-env");
+        let env_name = env.name().clone();
+        repo.save(&AnyEnvironmentState::Created(env)).unwrap();
+
+        let environment_file = temp_dir.path().join("test-env").join("environment.json");
+        let parsed: serde_json::Value =
+            serde_json::from_str(&fs::read_to_string(environment_file).unwrap()).unwrap();
+        assert_eq!(
+            parsed["schema_version"],
+            migrations::CURRENT_STATE_SCHEMA_VERSION
+        );
+        assert_eq!(parsed["deployer_version"], migrations::DEPLOYER_VERSION);
+        assert_eq!(
+            repo.load_version(&env_name).unwrap(),
+            Some(StoredStateVersion::current())
+        );
+    }
+
+    #[test]
+    fn it_should_migrate_an_environment_file_written_before_versioning() {
+        let temp_dir = TempDir::new().unwrap();
+        let repo = FileEnvironmentRepository::new(temp_dir.path().to_path_buf());
+        let env_dir = temp_dir.path().join("legacy-env");
+        fs::create_dir_all(&env_dir).unwrap();
+        fs::copy(
+            PathBuf::from(env!("CARGO_MANIFEST_DIR"))
+                .join("fixtures/environment-state/schema-v1.json"),
+            env_dir.join("environment.json"),
+        )
+        .unwrap();
+        let env_name = EnvironmentName::new("legacy-env".to_string()).unwrap();
+
+        let loaded = repo.load(&env_name).unwrap().unwrap();
+
+        assert_eq!(loaded.instance_ip(), Some("10.140.190.14".parse().unwrap()));
+        assert_eq!(
+            repo.load_version(&env_name).unwrap(),
+            Some(StoredStateVersion {
+                schema_version: 1,
+                deployer_version: None,
+            })
+        );
+    }
+
+    #[test]
+    fn it_should_refuse_to_load_an_environment_file_written_with_a_newer_schema() {
+        let temp_dir = TempDir::new().unwrap();
+        let repo = FileEnvironmentRepository::new(temp_dir.path().to_path_buf());
+
+        let env = create_test_environment("test-env");
+        let env_name = env.name().clone();
+        repo.save(&AnyEnvironmentState::Created(env)).unwrap();
+        let environment_file = temp_dir.path().join("test-env").join("environment.json");
+        let mut parsed: serde_json::Value =
+            serde_json::from_str(&fs::read_to_string(&environment_file).unwrap()).unwrap();
+        parsed["schema_version"] = (migrations::CURRENT_STATE_SCHEMA_VERSION + 1).into();
+        fs::write(&environment_file, parsed.to_string()).unwrap();
+
+        let result = repo.load(&env_name);
+
+        assert!(
+            matches!(
+                result,
+                Err(RepositoryError::UnsupportedVersion { schema_version, .. })
+                    if schema_version == migrations::CURRENT_STATE_SCHEMA_VERSION + 1
+            ),
+            "Expected UnsupportedVersion error, got: {result:?}"
+        );
+        assert_eq!(
+            repo.load_version(&env_name)
+                .unwrap()
+                .unwrap()
+                .schema_version,
+            migrations::CURRENT_STATE_SCHEMA_VERSION + 1
+        );
+    }
+
+    #[test]
+    fn it_should_keep_the_saved_state_when_a_write_is_interrupted() {
+        let temp_dir = TempDir::new().unwrap();
+        let repo = FileEnvironmentRepository::new(temp_dir.path().to_path_buf());
+
+        let env = create_test_environment("test-env");
+        let env_name = env.name().clone();
+        repo.save(&AnyEnvironmentState::Created(env.clone()))
+            .unwrap();
+
+        // An interrupted write only leaves a partial temporary file behind
+        let env_dir = temp_dir.path().join("test-env");
+        let content = fs::read_to_string(env_dir.join("environment.json")).unwrap();
+        fs::write(
+            env_dir.join("environment.json.tmp"),
+            &content[..content.len() / 2],
+        )
+        .unwrap();
+
+        let loaded = repo.load(&env_name).unwrap().unwrap();
+        assert_eq!(loaded.state_name(), "created");
+
+        repo.save(&AnyEnvironmentState::Provisioning(env.start_provisioning()))
+            .unwrap();
+        let loaded = repo.load(&env_name).unwrap().unwrap();
+        assert_eq!(loaded.state_name(), "provisioning");
+        assert!(!env_dir.join("environment.json.tmp").exists());
+    }
+
+    #[test]
+    fn it_should_restore_a_backup_over_corrupted_state() {
+        let temp_dir = TempDir::new().unwrap();
+        let repo = FileEnvironmentRepository::new(temp_dir.path().to_path_buf());
+
+        let env = create_test_environment("test-env");
+        let env_name = env.name().clone();
+        repo.save(&AnyEnvironmentState::Created(env.clone()))
+            .unwrap();
+        repo.save(&AnyEnvironmentState::Provisioning(env.start_provisioning()))
+            .unwrap();
+        truncate_environment_file(&temp_dir, "test-env");
+
+        let backups = repo.list_backups(&env_name).unwrap();
+        repo.restore_backup(&env_name, &backups[0].id).unwrap();
+
+        let loaded = repo.load(&env_name).unwrap().unwrap();
+        assert_eq!(loaded.state_name(), "created");
+        assert_eq!(
+            repo.list_backups(&env_name).unwrap(),
+            backups,
+            "corrupted state should not be backed up"
+        );
+    }
+
+    #[test]
+    fn it_should_return_not_found_when_restoring_an_unknown_backup() {
+        let temp_dir = TempDir::new().unwrap();
+        let repo = FileEnvironmentRepository::new(temp_dir.path().to_path_buf());
+
+        let env = create_test_environment("test-env");
+        let env_name = env.name().clone();
+        repo.save(&AnyEnvironmentState::Created(env)).unwrap();
+
+        let result = repo.restore_backup(&env_name, "20260101T000000.000000000Z");
+
+        assert!(matches!(result, Err(RepositoryError::NotFound)));
+    }
+
+    #[test]
+    fn it_should_delete_state_backups_with_environment() {
+        let temp_dir = TempDir::new().unwrap();
+        let repo = FileEnvironmentRepository::new(temp_dir.path().to_path_buf());
+
+        let env = create_test_environment("test-env");
+        let env_name = env.name().clone();
+        repo.save(&AnyEnvironmentState::Created(env.clone()))
+            .unwrap();
+        repo.save(&AnyEnvironmentState::Created(env)).unwrap();
+
+        repo.delete(&env_name).unwrap();
+
+        assert!(!temp_dir.path().join("test-env").exists());
+    }
+
     #[test]
     fn it_should_handle_state_transitions() {
         let temp_dir = TempDir::new().unwrap();
@@ -576,4 +1326,305 @@ mod tests {
         let loaded = repo.load(&env_name).unwrap().unwrap();
         assert_eq!(loaded.state_name(), "provisioning");
     }
+
+    #[test]
+    fn it_should_store_grafana_admin_password_outside_the_environment_file() {
+        let temp_dir = TempDir::new().unwrap();
+        let repo = FileEnvironmentRepository::new(temp_dir.path().to_path_buf());
+
+        let (env, _data_dir, _build_dir, _env_temp_dir) = EnvironmentTestBuilder::new()
+            .with_name("grafana-env")
+            .build_with_custom_paths();
+        let env_name = env.name().clone();
+        let state = AnyEnvironmentState::Created(env);
+        let password = state
+            .grafana_config()
+            .unwrap()
+            .admin_password()
+            .unwrap()
+            .clone();
+
+        repo.save(&state).unwrap();
+
+        let environment_json =
+            fs::read_to_string(temp_dir.path().join("grafana-env/environment.json")).unwrap();
+        assert!(!environment_json.contains("admin_password"));
+
+        let secrets_json =
+            fs::read_to_string(temp_dir.path().join("grafana-env/secrets.json")).unwrap();
+        assert!(secrets_json.contains("grafana_admin_password"));
+
+        let loaded = repo.load(&env_name).unwrap().unwrap();
+        assert_eq!(
+            loaded.grafana_config().unwrap().admin_password(),
+            Some(&password)
+        );
+    }
+
+    fn save_grafana_environment(repo: &FileEnvironmentRepository) -> EnvironmentName {
+        let (env, _data_dir, _build_dir, _env_temp_dir) = EnvironmentTestBuilder::new()
+            .with_name("grafana-env")
+            .build_with_custom_paths();
+        let env_name = env.name().clone();
+        repo.save(&AnyEnvironmentState::Created(env)).unwrap();
+        env_name
+    }
+
+    #[test]
+    fn it_should_fail_to_load_an_environment_with_grafana_without_its_secrets_file() {
+        let temp_dir = TempDir::new().unwrap();
+        let repo = FileEnvironmentRepository::new(temp_dir.path().to_path_buf());
+        let env_name = save_grafana_environment(&repo);
+
+        fs::remove_file(temp_dir.path().join("grafana-env/secrets.json")).unwrap();
+
+        let result = repo.load(&env_name);
+        assert!(matches!(
+            result,
+            Err(RepositoryError::MissingSecret { ref secret }) if secret == "grafana_admin_password"
+        ));
+    }
+
+    #[test]
+    fn it_should_fail_to_load_an_environment_with_grafana_from_an_incomplete_secrets_file() {
+        let temp_dir = TempDir::new().unwrap();
+        let repo = FileEnvironmentRepository::new(temp_dir.path().to_path_buf());
+        let env_name = save_grafana_environment(&repo);
+
+        fs::write(
+            temp_dir.path().join("grafana-env/secrets.json"),
+            r#"{"tracker_auth_keys": []}"#,
+        )
+        .unwrap();
+
+        let result = repo.load(&env_name);
+        assert!(matches!(
+            result,
+            Err(RepositoryError::MissingSecret { ref secret }) if secret == "grafana_admin_password"
+        ));
+    }
+
+    #[test]
+    fn it_should_encrypt_the_secrets_written_with_a_workspace_key() {
+        let temp_dir = TempDir::new().unwrap();
+        let workspace_key = Arc::new(WorkspaceKey::new(temp_dir.path().join("workspace.key")));
+        let repo = FileEnvironmentRepository::new(temp_dir.path().join("data"))
+            .with_workspace_key(workspace_key);
+
+        let (env, _data_dir, _build_dir, _env_temp_dir) = EnvironmentTestBuilder::new()
+            .with_name("sealed-env")
+            .build_with_custom_paths();
+        let env_name = env.name().clone();
+        let state = AnyEnvironmentState::Created(env);
+        let token = state.tracker_config().http_api().admin_token().clone();
+        let password = state
+            .grafana_config()
+            .unwrap()
+            .admin_password()
+            .unwrap()
+            .clone();
+
+        repo.save(&state).unwrap();
+
+        let environment_json =
+            fs::read_to_string(temp_dir.path().join("data/sealed-env/environment.json")).unwrap();
+        assert!(!environment_json.contains(token.expose_secret()));
+
+        let secrets: Value = serde_json::from_str(
+            &fs::read_to_string(temp_dir.path().join("data/sealed-env/secrets.json")).unwrap(),
+        )
+        .unwrap();
+        assert!(secrets["grafana_admin_password"]["sealed"].is_string());
+
+        let loaded = repo.load(&env_name).unwrap().unwrap();
+        assert_eq!(loaded.tracker_config().http_api().admin_token(), &token);
+        assert_eq!(
+            loaded.grafana_config().unwrap().admin_password(),
+            Some(&password)
+        );
+    }
+
+    #[test]
+    fn it_should_load_a_sealed_secret_that_looks_like_a_sealed_value() {
+        use crate::domain::tracker::{HttpApiConfig, TrackerConfig};
+
+        let temp_dir = TempDir::new().unwrap();
+        let workspace_key = Arc::new(WorkspaceKey::new(temp_dir.path().join("workspace.key")));
+        let repo = FileEnvironmentRepository::new(temp_dir.path().join("data"))
+            .with_workspace_key(workspace_key);
+
+        let defaults = TrackerConfig::default();
+        let tracker_config = TrackerConfig::new(
+            defaults.core().clone(),
+            defaults.udp_trackers().to_vec(),
+            defaults.http_trackers().to_vec(),
+            HttpApiConfig::new(
+                "0.0.0.0:1212".parse().unwrap(),
+                "age:MyAccessToken".to_string().into(),
+                None,
+                false,
+            )
+            .unwrap(),
+            defaults.health_check_api().clone(),
+        )
+        .unwrap();
+        let (env, _data_dir, _build_dir, _env_temp_dir) = EnvironmentTestBuilder::new()
+            .with_name("prefixed-env")
+            .with_tracker_config(tracker_config)
+            .build_with_custom_paths();
+        let env_name = env.name().clone();
+        let state = AnyEnvironmentState::Created(env);
+
+        repo.save(&state).unwrap();
+        repo.save(&state).unwrap();
+
+        let loaded = repo.load(&env_name).unwrap().unwrap();
+        assert_eq!(
+            loaded
+                .tracker_config()
+                .http_api()
+                .admin_token()
+                .expose_secret(),
+            "age:MyAccessToken"
+        );
+        assert_eq!(repo.backup_ids(&env_name).unwrap().len(), 1);
+    }
+
+    #[test]
+    fn it_should_seal_the_secrets_of_an_environment_saved_without_key() {
+        let temp_dir = TempDir::new().unwrap();
+        let (env, _data_dir, _build_dir, _env_temp_dir) = EnvironmentTestBuilder::new()
+            .with_name("plain-env")
+            .build_with_custom_paths();
+        let env_name = env.name().clone();
+        let state = AnyEnvironmentState::Created(env);
+        let token = state.tracker_config().http_api().admin_token().clone();
+        FileEnvironmentRepository::new(temp_dir.path().join("data"))
+            .save(&state)
+            .unwrap();
+        let workspace_key = Arc::new(WorkspaceKey::new(temp_dir.path().join("workspace.key")));
+        let repo = FileEnvironmentRepository::new(temp_dir.path().join("data"))
+            .with_workspace_key(workspace_key);
+
+        assert!(repo.seal_secrets(&env_name).unwrap());
+        assert!(!repo.seal_secrets(&env_name).unwrap());
+
+        let environment_json =
+            fs::read_to_string(temp_dir.path().join("data/plain-env/environment.json")).unwrap();
+        assert!(!environment_json.contains(token.expose_secret()));
+        let loaded = repo.load(&env_name).unwrap().unwrap();
+        assert_eq!(loaded.tracker_config().http_api().admin_token(), &token);
+    }
+
+    #[test]
+    fn it_should_seal_and_stamp_an_environment_file_stored_before_secrets_were_sealed_when_loaded()
+    {
+        let temp_dir = TempDir::new().unwrap();
+        let env_dir = temp_dir.path().join("data/legacy-env");
+        fs::create_dir_all(&env_dir).unwrap();
+        fs::copy(
+            PathBuf::from(env!("CARGO_MANIFEST_DIR"))
+                .join("fixtures/environment-state/schema-v4.json"),
+            env_dir.join("environment.json"),
+        )
+        .unwrap();
+        fs::write(
+            env_dir.join("secrets.json"),
+            r#"{"grafana_admin_password": "admin-pass"}"#,
+        )
+        .unwrap();
+        let workspace_key = Arc::new(WorkspaceKey::new(temp_dir.path().join("workspace.key")));
+        let repo = FileEnvironmentRepository::new(temp_dir.path().join("data"))
+            .with_workspace_key(workspace_key);
+        let env_name = EnvironmentName::new("legacy-env".to_string()).unwrap();
+
+        let loaded = repo.load(&env_name).unwrap().unwrap();
+
+        assert_eq!(
+            loaded
+                .tracker_config()
+                .http_api()
+                .admin_token()
+                .expose_secret(),
+            "MyAccessToken"
+        );
+        let environment_json = fs::read_to_string(env_dir.join("environment.json")).unwrap();
+        assert!(!environment_json.contains("MyAccessToken"));
+        let secrets_json = fs::read_to_string(env_dir.join("secrets.json")).unwrap();
+        assert!(!secrets_json.contains("admin-pass"));
+        assert_eq!(
+            repo.load_version(&env_name).unwrap(),
+            Some(StoredStateVersion::current())
+        );
+    }
+
+    #[test]
+    fn it_should_not_write_secrets_file_when_environment_has_no_secrets() {
+        let temp_dir = TempDir::new().unwrap();
+        let repo = FileEnvironmentRepository::new(temp_dir.path().to_path_buf());
+
+        let (env, _data_dir, _build_dir, _env_temp_dir) = EnvironmentTestBuilder::new()
+            .with_name("no-grafana-env")
+            .with_prometheus_config(None)
+            .build_with_custom_paths();
+
+        repo.save(&AnyEnvironmentState::Created(env)).unwrap();
+
+        assert!(!temp_dir.path().join("no-grafana-env/secrets.json").exists());
+    }
+
+    #[test]
+    fn it_should_delete_secrets_file_with_environment() {
+        let temp_dir = TempDir::new().unwrap();
+        let repo = FileEnvironmentRepository::new(temp_dir.path().to_path_buf());
+
+        let (env, _data_dir, _build_dir, _env_temp_dir) = EnvironmentTestBuilder::new()
+            .with_name("grafana-env")
+            .build_with_custom_paths();
+        let env_name = env.name().clone();
+        repo.save(&AnyEnvironmentState::Created(env)).unwrap();
+
+        repo.delete(&env_name).unwrap();
+
+        assert!(!temp_dir.path().join("grafana-env/secrets.json").exists());
+    }
+
+    #[test]
+    fn it_should_store_paths_with_forward_slashes_and_load_them_as_native_paths() {
+        use crate::shared::portable_path::to_portable_string;
+
+        let temp_dir = TempDir::new().unwrap();
+        let repo = FileEnvironmentRepository::new(temp_dir.path().to_path_buf());
+
+        let (env, data_dir, build_dir, _env_temp_dir) = EnvironmentTestBuilder::new()
+            .with_name("paths-env")
+            .build_with_custom_paths();
+        let env_name = env.name().clone();
+        repo.save(&AnyEnvironmentState::Created(env)).unwrap();
+
+        let environment_json =
+            fs::read_to_string(temp_dir.path().join("paths-env").join("environment.json")).unwrap();
+        let json: serde_json::Value = serde_json::from_str(&environment_json).unwrap();
+        let stored = json.to_string();
+        assert!(stored.contains(&to_portable_string(&data_dir)));
+        assert!(stored.contains(&to_portable_string(&build_dir)));
+
+        let loaded = repo.load(&env_name).unwrap().unwrap();
+        assert_eq!(loaded.data_dir(), &data_dir);
+    }
+
+    #[test]
+    fn it_should_keep_the_environment_lock_outside_the_environment_directory() {
+        let temp_dir = TempDir::new().unwrap();
+        let repo = FileEnvironmentRepository::new(temp_dir.path().to_path_buf());
+        let env_name = EnvironmentName::new("test-env".to_string()).unwrap();
+
+        let guard = repo.lock(&env_name).unwrap();
+
+        assert!(temp_dir.path().join(".locks/test-env.lock").is_file());
+        assert!(!temp_dir.path().join("test-env").exists());
+
+        drop(guard);
+        assert!(!temp_dir.path().join(".locks/test-env.lock").exists());
+    }
 }
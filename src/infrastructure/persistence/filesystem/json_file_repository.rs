@@ -150,6 +150,7 @@
 //!
 //! - `NotFound`: File doesn't exist (only used internally)
 //! - `Conflict`: File is locked by another process (timeout or held by another PID)
+//! - `Corrupted`: File content is not valid JSON for the entity type
 //! - `Internal`: I/O errors, serialization errors, or unexpected failures
 //!
 //! Domain-specific repositories should map these to their own error types
@@ -184,6 +185,14 @@ pub enum JsonFileError {
     #[error("Lock conflict: another process is accessing {path}")]
     Conflict { path: String },
 
+    /// File content cannot be deserialized
+    #[error("Corrupted JSON in {path}: {source}")]
+    Corrupted {
+        path: String,
+        #[source]
+        source: serde_json::Error,
+    },
+
     /// Internal error with context
     #[error("Internal error: {0}")]
     Internal(#[from] anyhow::Error),
@@ -277,7 +286,71 @@ impl JsonFileRepository {
 
         let json_content = Self::serialize_to_json(entity)?;
 
-        Self::write_atomic(file_path, &json_content)?;
+        Self::write_atomic(file_path, &json_content, false)?;
+
+        Ok(())
+        // Lock is automatically released when _lock goes out of scope
+    }
+
+    /// Save an entity to a JSON file readable only by its owner
+    ///
+    /// Same as [`save`](Self::save), but on Unix the file is created with
+    /// `0600` permissions before it is moved into place. Use it for files
+    /// containing secrets.
+    ///
+    /// # Errors
+    ///
+    /// Returns `JsonFileError::Conflict` if the file is locked by another process.
+    /// Returns `JsonFileError::Internal` for I/O or serialization errors.
+    pub fn save_private<T: Serialize>(
+        &self,
+        file_path: &Path,
+        entity: &T,
+    ) -> Result<(), JsonFileError> {
+        Self::ensure_parent_dir(file_path)?;
+
+        let _lock = self.acquire_lock(file_path, "save")?;
+
+        let json_content = Self::serialize_to_json(entity)?;
+
+        Self::write_atomic(file_path, &json_content, true)?;
+
+        Ok(())
+        // Lock is automatically released when _lock goes out of scope
+    }
+
+    /// Save an entity to a JSON file, keeping a copy of the content it replaces
+    ///
+    /// Before the file is overwritten, its current content is written to
+    /// `backup_path` if it deserializes as `T`. Content that cannot be parsed
+    /// is never backed up, so a backup always holds a readable entity.
+    ///
+    /// # Errors
+    ///
+    /// Returns `JsonFileError::Conflict` if the file is locked by another process.
+    /// Returns `JsonFileError::Internal` for I/O or serialization errors.
+    pub fn save_with_backup<T: Serialize + for<'de> Deserialize<'de>>(
+        &self,
+        file_path: &Path,
+        entity: &T,
+        backup_path: &Path,
+    ) -> Result<(), JsonFileError> {
+        Self::ensure_parent_dir(file_path)?;
+
+        let _lock = self.acquire_lock(file_path, "save")?;
+
+        if file_path.exists() {
+            let current_content = Self::read_file_content(file_path)?;
+
+            if Self::deserialize_from_json::<T>(&current_content, file_path).is_ok() {
+                Self::ensure_parent_dir(backup_path)?;
+                Self::write_atomic(backup_path, &current_content, false)?;
+            }
+        }
+
+        let json_content = Self::serialize_to_json(entity)?;
+
+        Self::write_atomic(file_path, &json_content, false)?;
 
         Ok(())
         // Lock is automatically released when _lock goes out of scope
@@ -298,7 +371,8 @@ impl JsonFileRepository {
     /// # Errors
     ///
     /// Returns `JsonFileError::Conflict` if the file is locked by another process.
-    /// Returns `JsonFileError::Internal` for I/O or deserialization errors.
+    /// Returns `JsonFileError::Corrupted` if the content cannot be deserialized.
+    /// Returns `JsonFileError::Internal` for I/O errors.
     pub fn load<T: for<'de> Deserialize<'de>>(
         &self,
         file_path: &Path,
@@ -373,10 +447,15 @@ impl JsonFileRepository {
     /// Write content to file atomically using temp file + rename pattern
     ///
     /// This ensures that the file is never in a partially written state, even if
-    /// a crash occurs during writing.
-    fn write_atomic(file_path: &Path, content: &str) -> Result<(), JsonFileError> {
+    /// a crash occurs during writing. When `private` is set, the temporary file
+    /// is restricted to its owner (Unix only) before any content is written.
+    fn write_atomic(file_path: &Path, content: &str, private: bool) -> Result<(), JsonFileError> {
         let temp_path = file_path.with_extension(Self::TEMP_FILE_EXTENSION);
 
+        if private {
+            Self::create_private_file(&temp_path)?;
+        }
+
         // Write to temporary file
         fs::write(&temp_path, content)
             .with_context(|| format!("Failed to write temporary file: {}", temp_path.display()))
@@ -414,6 +493,39 @@ impl JsonFileRepository {
             .map_err(JsonFileError::Internal)
     }
 
+    /// Create an empty file readable and writable only by its owner
+    ///
+    /// Existing files are truncated and their permissions reset to `0600`.
+    /// On non-Unix platforms the file is created with default permissions.
+    fn create_private_file(path: &Path) -> Result<(), JsonFileError> {
+        let mut options = fs::OpenOptions::new();
+        options.write(true).create(true).truncate(true);
+
+        #[cfg(unix)]
+        {
+            use std::os::unix::fs::OpenOptionsExt;
+            options.mode(0o600);
+        }
+
+        let file = options
+            .open(path)
+            .with_context(|| format!("Failed to create private file: {}", path.display()))
+            .map_err(JsonFileError::Internal)?;
+
+        #[cfg(unix)]
+        {
+            use std::os::unix::fs::PermissionsExt;
+            file.set_permissions(fs::Permissions::from_mode(0o600))
+                .with_context(|| format!("Failed to restrict permissions of: {}", path.display()))
+                .map_err(JsonFileError::Internal)?;
+        }
+
+        #[cfg(not(unix))]
+        drop(file);
+
+        Ok(())
+    }
+
     /// Acquire lock for file operation
     ///
     /// Acquires a file lock with timeout and converts lock errors to JSON file errors.
@@ -452,9 +564,10 @@ impl JsonFileRepository {
         content: &str,
         file_path: &Path,
     ) -> Result<T, JsonFileError> {
-        serde_json::from_str(content)
-            .with_context(|| format!("Failed to deserialize JSON from: {}", file_path.display()))
-            .map_err(JsonFileError::Internal)
+        serde_json::from_str(content).map_err(|source| JsonFileError::Corrupted {
+            path: file_path.display().to_string(),
+            source,
+        })
     }
 
     /// Convert `FileLockError` to `JsonFileError` with operation context
@@ -853,6 +966,104 @@ mod tests {
         assert_atomic_write_completed(&scenario.file_path());
     }
 
+    #[cfg(unix)]
+    #[test]
+    fn it_should_save_private_files_readable_only_by_owner() {
+        use std::os::unix::fs::PermissionsExt;
+
+        // Arrange
+        let scenario = TestRepositoryScenario::new();
+        let entity = TestEntity::new("secret", 7);
+
+        // Act
+        scenario
+            .repo()
+            .save_private(&scenario.file_path(), &entity)
+            .expect("Failed to save private entity to file");
+
+        // Assert
+        let mode = fs::metadata(scenario.file_path())
+            .expect("Failed to read file metadata")
+            .permissions()
+            .mode();
+        assert_eq!(mode & 0o777, 0o600);
+        assert_atomic_write_completed(&scenario.file_path());
+
+        let loaded: Option<TestEntity> = scenario.load().expect("Failed to load entity from file");
+        assert_eq!(loaded, Some(entity));
+    }
+
+    #[test]
+    fn it_should_return_corrupted_error_when_loading_truncated_json() {
+        // Arrange
+        let scenario = TestRepositoryScenario::new();
+        let entity = TestEntity::new("test", 100);
+        scenario
+            .save(&entity)
+            .expect("Failed to save entity to file");
+        let content = fs::read_to_string(scenario.file_path()).expect("Failed to read file");
+        fs::write(scenario.file_path(), &content[..content.len() / 2])
+            .expect("Failed to truncate file");
+
+        // Act
+        let result: Result<Option<TestEntity>, JsonFileError> = scenario.load();
+
+        // Assert
+        assert!(
+            matches!(result, Err(JsonFileError::Corrupted { .. })),
+            "Expected Corrupted error, got: {result:?}"
+        );
+    }
+
+    #[test]
+    fn it_should_back_up_the_replaced_content_when_saving_with_backup() {
+        // Arrange
+        let scenario = TestRepositoryScenario::new();
+        let backup_path = scenario.temp_dir.path().join("backups").join("test.json");
+        let entity1 = TestEntity::new("first", 1);
+        let entity2 = TestEntity::new("second", 2);
+        scenario
+            .save(&entity1)
+            .expect("Failed to save first entity version");
+
+        // Act
+        scenario
+            .repo()
+            .save_with_backup(&scenario.file_path(), &entity2, &backup_path)
+            .expect("Failed to save second entity version");
+
+        // Assert
+        let backup: Option<TestEntity> = scenario
+            .repo()
+            .load(&backup_path)
+            .expect("Failed to load backup");
+        assert_eq!(backup, Some(entity1));
+        let loaded: Option<TestEntity> = scenario.load().expect("Failed to load entity from file");
+        assert_eq!(loaded, Some(entity2));
+    }
+
+    #[test]
+    fn it_should_not_back_up_content_that_cannot_be_parsed() {
+        // Arrange
+        let scenario = TestRepositoryScenario::new();
+        let backup_path = scenario.temp_dir.path().join("backups").join("test.json");
+        fs::write(scenario.file_path(), r#"{"id": "first", "val"#).expect("Failed to write file");
+
+        // Act
+        scenario
+            .repo()
+            .save_with_backup(
+                &scenario.file_path(),
+                &TestEntity::new("second", 2),
+                &backup_path,
+            )
+            .expect("Failed to save entity");
+
+        // Assert
+        assert!(!backup_path.exists());
+        assert_atomic_write_completed(&scenario.file_path());
+    }
+
     #[test]
     fn it_should_preserve_json_structure() {
         // Arrange
@@ -2,6 +2,47 @@
 //!
 //! This module provides infrastructure components for data persistence,
 //! including repositories and file-based storage implementations.
+//!
+//! Two backends implement `EnvironmentRepository`, selected per workspace by
+//! the `repository` setting of its marker (`RepositoryBackend`):
+//!
+//! - `filesystem` - One JSON file per environment directory (default)
+//! - `sqlite` - A single `SQLite` database for the whole workspace
 
 pub mod file_repository_factory;
 pub mod filesystem;
+pub mod sqlite;
+pub mod sqlite_repository_factory;
+pub(crate) mod stored_environment;
+pub mod workspace_key;
+
+#[cfg(test)]
+mod contract_tests;
+
+use std::sync::Arc;
+use std::time::Duration;
+
+use crate::application::traits::RepositoryProvider;
+use crate::domain::environment::RepositoryBackend;
+use file_repository_factory::FileRepositoryFactory;
+use sqlite_repository_factory::SqliteRepositoryFactory;
+use workspace_key::WorkspaceKey;
+
+/// Create the repository provider of a storage backend
+///
+/// With a `workspace_key`, the repositories seal the secrets they write.
+#[must_use]
+pub fn repository_provider(
+    backend: RepositoryBackend,
+    lock_timeout: Duration,
+    workspace_key: Option<Arc<WorkspaceKey>>,
+) -> Arc<dyn RepositoryProvider> {
+    match backend {
+        RepositoryBackend::Json => {
+            Arc::new(FileRepositoryFactory::new(lock_timeout).with_workspace_key(workspace_key))
+        }
+        RepositoryBackend::Sqlite => {
+            Arc::new(SqliteRepositoryFactory::new(lock_timeout).with_workspace_key(workspace_key))
+        }
+    }
+}
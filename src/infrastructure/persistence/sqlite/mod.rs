@@ -0,0 +1,10 @@
+//! SQLite-based persistence infrastructure
+//!
+//! This module provides an environment repository storing every environment
+//! of a workspace in a single `SQLite` database, for control hosts shared by
+//! several users. Each operation runs in a transaction, so concurrent
+//! deployer processes never see a partially written environment.
+
+pub mod sqlite_environment_repository;
+
+pub use sqlite_environment_repository::{SqliteEnvironmentRepository, DATABASE_FILE_NAME};
@@ -0,0 +1,1099 @@
+//! SQLite-based implementation of the `EnvironmentRepository` trait
+//!
+//! This module provides an implementation that persists every environment of
+//! a workspace in a single `SQLite` database, instead of one directory per
+//! environment.
+//!
+//! # Database Structure
+//!
+//! ```text
+//! ./data/workspace.db        # Database (owner-only, 0600)
+//! ./data/workspace.db-wal    # Write-ahead log, while the database is in use
+//! ```
+//!
+//! The `environments` table holds one row per environment: the same JSON
+//! document the file-based repository writes to `environment.json`, plus the
+//! name and state name as indexed columns so that listing does not parse the
+//! documents. Secrets are kept in their own column, never in the document.
+//!
+//! With a workspace key (`with_workspace_key`), the secrets left in the
+//! documents and every value of the secrets column are written encrypted
+//! (see `workspace_key`). Plaintext rows written without a key still load.
+//!
+//! The `state_backups` table holds the previous states of each environment.
+//! Before an environment is overwritten, its previous state is copied there
+//! when it is readable, and only the most recent backups are kept
+//! (`DEFAULT_STATE_BACKUP_LIMIT` unless configured with `with_backup_limit`).
+//!
+//! The `environment_locks` table holds one row per environment locked by a
+//! command (`lock`), with the process ID of the holder.
+//!
+//! # Concurrency
+//!
+//! Every write runs in an immediate transaction. A process waits up to the
+//! lock timeout for the transactions of other processes, then fails with
+//! `RepositoryError::Conflict`.
+//!
+//! An environment lock is a row of `environment_locks`, inserted when it is
+//! acquired and deleted when its guard is dropped. A row left by a process
+//! that is no longer running is taken over.
+//!
+//! # Usage
+//!
+//! ```rust,no_run
+//! use std::path::PathBuf;
+//! use torrust_tracker_deployer_lib::infrastructure::persistence::sqlite::SqliteEnvironmentRepository;
+//! use torrust_tracker_deployer_lib::domain::environment::repository::EnvironmentRepository;
+//!
+//! let repo = SqliteEnvironmentRepository::new(PathBuf::from("./data"));
+//!
+//! // Every operation runs in its own transaction
+//! // repo.save(&env)?;
+//! // let loaded = repo.load(&env_name)?;
+//! # Ok::<(), Box<dyn std::error::Error>>(())
+//! ```
+
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+
+use anyhow::Context;
+use chrono::Utc;
+use rusqlite::{
+    params, Connection, ErrorCode, OptionalExtension, Transaction, TransactionBehavior,
+};
+use serde_json::Value;
+use tracing::warn;
+
+use crate::domain::environment::name::EnvironmentName;
+use crate::domain::environment::repository::{
+    EnvironmentLockGuard, EnvironmentRepository, RepositoryError, StateBackup, StoredStateVersion,
+};
+use crate::domain::environment::state::AnyEnvironmentState;
+use crate::domain::environment::EnvironmentSecrets;
+use crate::infrastructure::persistence::filesystem::ProcessId;
+use crate::infrastructure::persistence::stored_environment::{
+    backup_created_at, backup_id, open_secrets_document, seal_document, secrets_document,
+    StoredEnvironment, DEFAULT_STATE_BACKUP_LIMIT, STATE_BACKUP_ID_FORMAT,
+};
+use crate::infrastructure::persistence::workspace_key::{
+    holds_plaintext_secrets, SecretValues, WorkspaceKey,
+};
+
+/// Name of the database file inside the data directory
+pub const DATABASE_FILE_NAME: &str = "workspace.db";
+
+/// Tables and indexes of the database, created when missing
+const SCHEMA: &str = "
+    CREATE TABLE IF NOT EXISTS environments (
+        name TEXT PRIMARY KEY NOT NULL,
+        state_name TEXT NOT NULL,
+        document TEXT NOT NULL,
+        secrets TEXT,
+        updated_at TEXT NOT NULL
+    );
+    CREATE INDEX IF NOT EXISTS environments_state_name ON environments (state_name);
+    CREATE TABLE IF NOT EXISTS state_backups (
+        name TEXT NOT NULL,
+        id TEXT NOT NULL,
+        state_name TEXT NOT NULL,
+        document TEXT NOT NULL,
+        PRIMARY KEY (name, id)
+    );
+    CREATE TABLE IF NOT EXISTS environment_locks (
+        name TEXT PRIMARY KEY NOT NULL,
+        pid INTEGER NOT NULL,
+        acquired_at TEXT NOT NULL
+    );
+";
+
+/// Time to wait between two attempts to take an environment lock
+const LOCK_RETRY_INTERVAL: Duration = Duration::from_millis(100);
+
+/// Row of the `environments` table, as read for loading
+struct EnvironmentRow {
+    document: String,
+    secrets: Option<String>,
+}
+
+/// Row of `environment_locks` owned by this process, deleted when dropped
+struct EnvironmentLockRow {
+    database_path: PathBuf,
+    name: EnvironmentName,
+    pid: ProcessId,
+}
+
+impl Drop for EnvironmentLockRow {
+    fn drop(&mut self) {
+        let released = Connection::open(&self.database_path).and_then(|connection| {
+            connection.busy_timeout(Duration::from_secs(10))?;
+            connection.execute(
+                "DELETE FROM environment_locks WHERE name = ?1 AND pid = ?2",
+                params![self.name.as_str(), self.pid.as_u32()],
+            )
+        });
+
+        if let Err(e) = released {
+            warn!(
+                environment = %self.name,
+                error = %e,
+                "Failed to release the environment lock"
+            );
+        }
+    }
+}
+
+/// SQLite-based implementation of `EnvironmentRepository`
+///
+/// Persists every environment in `{base_dir}/workspace.db`. The database is
+/// created on the first save; until then the repository holds no environments.
+pub struct SqliteEnvironmentRepository {
+    /// Path of the database file (typically "./data/workspace.db")
+    database_path: PathBuf,
+    /// Maximum time to wait for the transactions of other processes
+    lock_timeout: Duration,
+    /// Number of state backups kept per environment
+    backup_limit: usize,
+    /// Key sealing the secrets written to the database, if any
+    workspace_key: Option<Arc<WorkspaceKey>>,
+}
+
+impl SqliteEnvironmentRepository {
+    /// Create a new SQLite-based environment repository
+    ///
+    /// # Arguments
+    ///
+    /// * `base_dir` - Directory of the database file. Directory will be
+    ///   created if it doesn't exist.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use std::path::PathBuf;
+    /// use torrust_tracker_deployer_lib::infrastructure::persistence::sqlite::SqliteEnvironmentRepository;
+    ///
+    /// let repo = SqliteEnvironmentRepository::new(PathBuf::from("./data"));
+    /// ```
+    #[must_use]
+    pub fn new(base_dir: PathBuf) -> Self {
+        Self {
+            database_path: base_dir.join(DATABASE_FILE_NAME),
+            lock_timeout: Duration::from_secs(10),
+            backup_limit: DEFAULT_STATE_BACKUP_LIMIT,
+            workspace_key: None,
+        }
+    }
+
+    /// Create repository with custom lock timeout
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use std::path::PathBuf;
+    /// use std::time::Duration;
+    /// use torrust_tracker_deployer_lib::infrastructure::persistence::sqlite::SqliteEnvironmentRepository;
+    ///
+    /// let repo = SqliteEnvironmentRepository::new(PathBuf::from("./data"))
+    ///     .with_lock_timeout(Duration::from_secs(30));
+    /// ```
+    #[must_use]
+    pub fn with_lock_timeout(mut self, timeout: Duration) -> Self {
+        self.lock_timeout = timeout;
+        self
+    }
+
+    /// Set the number of state backups kept per environment
+    ///
+    /// A limit of zero disables state backups.
+    #[must_use]
+    pub fn with_backup_limit(mut self, limit: usize) -> Self {
+        self.backup_limit = limit;
+        self
+    }
+
+    /// Seal the secrets written to the database with `workspace_key`
+    ///
+    /// Rows holding sealed secrets can only be loaded with the same key.
+    #[must_use]
+    pub fn with_workspace_key(mut self, workspace_key: Arc<WorkspaceKey>) -> Self {
+        self.workspace_key = Some(workspace_key);
+        self
+    }
+
+    /// Path of the database file
+    #[must_use]
+    pub fn database_path(&self) -> &Path {
+        &self.database_path
+    }
+
+    /// Open the database, creating it when `create` is set
+    ///
+    /// Returns `None` when the database does not exist and `create` is not
+    /// set, so that reads never create an empty database.
+    fn open(&self, create: bool) -> Result<Option<Connection>, RepositoryError> {
+        if !self.database_path.exists() {
+            if !create {
+                return Ok(None);
+            }
+            self.create_database_file()?;
+        }
+
+        let connection = Connection::open(&self.database_path)
+            .and_then(|connection| {
+                connection.busy_timeout(self.lock_timeout)?;
+                connection.pragma_update_and_check(None, "journal_mode", "WAL", |row| {
+                    row.get::<_, String>(0)
+                })?;
+                connection.execute_batch(SCHEMA)?;
+                Ok(connection)
+            })
+            .map_err(|e| self.convert_sqlite_error(e))?;
+
+        Ok(Some(connection))
+    }
+
+    /// Create an empty database file readable and writable only by its owner
+    ///
+    /// `SQLite` gives its journal files the permissions of the database file.
+    /// On non-Unix platforms the file is created with default permissions.
+    fn create_database_file(&self) -> Result<(), RepositoryError> {
+        if let Some(parent) = self.database_path.parent() {
+            fs::create_dir_all(parent)
+                .with_context(|| format!("Failed to create directory: {}", parent.display()))?;
+        }
+
+        let mut options = fs::OpenOptions::new();
+        options.write(true).create(true).truncate(false);
+        #[cfg(unix)]
+        {
+            use std::os::unix::fs::OpenOptionsExt;
+            options.mode(0o600);
+        }
+
+        options.open(&self.database_path).with_context(|| {
+            format!(
+                "Failed to create database file: {}",
+                self.database_path.display()
+            )
+        })?;
+
+        Ok(())
+    }
+
+    /// Start a transaction that holds the write lock until it ends
+    fn begin_write<'c>(
+        &self,
+        connection: &'c mut Connection,
+    ) -> Result<Transaction<'c>, RepositoryError> {
+        connection
+            .transaction_with_behavior(TransactionBehavior::Immediate)
+            .map_err(|e| self.convert_sqlite_error(e))
+    }
+
+    /// Commit a write transaction
+    fn commit(&self, transaction: Transaction<'_>) -> Result<(), RepositoryError> {
+        transaction
+            .commit()
+            .map_err(|e| self.convert_sqlite_error(e))
+    }
+
+    /// Insert the lock row of an environment unless a running process holds it
+    ///
+    /// Returns whether the lock was taken.
+    fn try_lock(
+        &self,
+        connection: &mut Connection,
+        name: &EnvironmentName,
+        pid: ProcessId,
+    ) -> Result<bool, RepositoryError> {
+        let transaction = self.begin_write(connection)?;
+
+        let holder = transaction
+            .query_row(
+                "SELECT pid FROM environment_locks WHERE name = ?1",
+                params![name.as_str()],
+                |row| row.get::<_, u32>(0),
+            )
+            .optional()
+            .map_err(|e| self.convert_sqlite_error(e))?
+            .map(ProcessId::from_raw);
+
+        if let Some(holder) = holder {
+            if holder.is_alive() {
+                return Ok(false);
+            }
+            warn!(
+                environment = %name,
+                stale_pid = %holder,
+                "Taking over the environment lock of a process that is no longer running"
+            );
+        }
+
+        transaction
+            .execute(
+                "INSERT OR REPLACE INTO environment_locks (name, pid, acquired_at)
+                 VALUES (?1, ?2, ?3)",
+                params![name.as_str(), pid.as_u32(), backup_id(Utc::now())],
+            )
+            .map_err(|e| self.convert_sqlite_error(e))?;
+        self.commit(transaction)?;
+
+        Ok(true)
+    }
+
+    /// Read the row of an environment
+    fn read_row(
+        &self,
+        connection: &Connection,
+        name: &EnvironmentName,
+    ) -> Result<Option<EnvironmentRow>, RepositoryError> {
+        connection
+            .query_row(
+                "SELECT document, secrets FROM environments WHERE name = ?1",
+                params![name.as_str()],
+                |row| {
+                    Ok(EnvironmentRow {
+                        document: row.get(0)?,
+                        secrets: row.get(1)?,
+                    })
+                },
+            )
+            .optional()
+            .map_err(|e| self.convert_sqlite_error(e))
+    }
+
+    /// Write the state of an environment, backing up the state it replaces
+    ///
+    /// The secrets column is replaced when `secrets` is given, and kept as it
+    /// is otherwise.
+    fn write_state(
+        &self,
+        transaction: &Transaction<'_>,
+        state: &AnyEnvironmentState,
+        secrets: Option<Option<String>>,
+    ) -> Result<(), RepositoryError> {
+        let name = state.name();
+
+        if self.backup_limit > 0 {
+            self.back_up_current_state(transaction, name)?;
+        }
+
+        let stored = StoredEnvironment::current(state).sealed_with(self.workspace_key.as_deref());
+        let document =
+            serde_json::to_string_pretty(&stored).context("Failed to serialize environment")?;
+        let updated_at = backup_id(Utc::now());
+
+        let result = match secrets {
+            Some(secrets) => transaction.execute(
+                "INSERT INTO environments (name, state_name, document, secrets, updated_at)
+                 VALUES (?1, ?2, ?3, ?4, ?5)
+                 ON CONFLICT (name) DO UPDATE SET
+                     state_name = excluded.state_name,
+                     document = excluded.document,
+                     secrets = excluded.secrets,
+                     updated_at = excluded.updated_at",
+                params![
+                    name.as_str(),
+                    state.state_name(),
+                    document,
+                    secrets,
+                    updated_at
+                ],
+            ),
+            None => transaction.execute(
+                "INSERT INTO environments (name, state_name, document, updated_at)
+                 VALUES (?1, ?2, ?3, ?4)
+                 ON CONFLICT (name) DO UPDATE SET
+                     state_name = excluded.state_name,
+                     document = excluded.document,
+                     updated_at = excluded.updated_at",
+                params![name.as_str(), state.state_name(), document, updated_at],
+            ),
+        };
+        result.map_err(|e| self.convert_sqlite_error(e))?;
+
+        if self.backup_limit > 0 {
+            self.prune_backups(transaction, name)?;
+        }
+
+        Ok(())
+    }
+
+    /// Copy the stored state of an environment to its backups, if readable
+    ///
+    /// The backup id is the time the state was written, moved forward to the
+    /// next free id when two writes got the same time.
+    fn back_up_current_state(
+        &self,
+        transaction: &Transaction<'_>,
+        name: &EnvironmentName,
+    ) -> Result<(), RepositoryError> {
+        let current = transaction
+            .query_row(
+                "SELECT state_name, document, updated_at FROM environments WHERE name = ?1",
+                params![name.as_str()],
+                |row| {
+                    Ok((
+                        row.get::<_, String>(0)?,
+                        row.get::<_, String>(1)?,
+                        row.get::<_, String>(2)?,
+                    ))
+                },
+            )
+            .optional()
+            .map_err(|e| self.convert_sqlite_error(e))?;
+
+        let Some((state_name, document, updated_at)) = current else {
+            return Ok(());
+        };
+
+        // Content that cannot be parsed is never backed up
+        if self.parse_document(&document).is_err() {
+            return Ok(());
+        }
+
+        let Some(mut written_at) = backup_created_at(&updated_at) else {
+            return Ok(());
+        };
+        let backup_id = loop {
+            let candidate = written_at.format(STATE_BACKUP_ID_FORMAT).to_string();
+            let taken = transaction
+                .query_row(
+                    "SELECT 1 FROM state_backups WHERE name = ?1 AND id = ?2",
+                    params![name.as_str(), candidate],
+                    |_| Ok(()),
+                )
+                .optional()
+                .map_err(|e| self.convert_sqlite_error(e))?
+                .is_some();
+            if !taken {
+                break candidate;
+            }
+            written_at += chrono::Duration::nanoseconds(1);
+        };
+
+        transaction
+            .execute(
+                "INSERT INTO state_backups (name, id, state_name, document) VALUES (?1, ?2, ?3, ?4)",
+                params![name.as_str(), backup_id, state_name, document],
+            )
+            .map_err(|e| self.convert_sqlite_error(e))?;
+
+        Ok(())
+    }
+
+    /// Remove the oldest backups beyond the backup limit
+    fn prune_backups(
+        &self,
+        transaction: &Transaction<'_>,
+        name: &EnvironmentName,
+    ) -> Result<(), RepositoryError> {
+        let limit = i64::try_from(self.backup_limit).unwrap_or(i64::MAX);
+
+        transaction
+            .execute(
+                "DELETE FROM state_backups WHERE name = ?1 AND id NOT IN (
+                     SELECT id FROM state_backups WHERE name = ?1 ORDER BY id DESC LIMIT ?2
+                 )",
+                params![name.as_str(), limit],
+            )
+            .map_err(|e| self.convert_sqlite_error(e))?;
+
+        Ok(())
+    }
+
+    /// Parse a stored document and migrate it to the current schema
+    fn parse_document(
+        &self,
+        document: &str,
+    ) -> Result<StoredEnvironment<'static>, RepositoryError> {
+        StoredEnvironment::from_sealed_value(
+            Self::parse_json(document)?,
+            self.workspace_key.as_deref(),
+        )
+    }
+
+    /// Parse a stored JSON column
+    fn parse_json(json: &str) -> Result<Value, RepositoryError> {
+        serde_json::from_str(json).map_err(|error| RepositoryError::Corrupted {
+            reason: error.to_string(),
+        })
+    }
+
+    /// Serialize the secrets of an environment for the secrets column
+    fn secrets_column(&self, env: &AnyEnvironmentState) -> Result<Option<String>, RepositoryError> {
+        let secrets = env.secrets();
+        if secrets.is_empty() {
+            return Ok(None);
+        }
+
+        let document = secrets_document(&secrets, self.workspace_key.as_deref())?;
+        let json = serde_json::to_string(&document).context("Failed to serialize secrets")?;
+        Ok(Some(json))
+    }
+
+    /// Seal the plaintext secrets of a stored JSON column
+    ///
+    /// Returns the sealed JSON, or `None` if the column held no plaintext
+    /// secrets and was not stored before secrets could be sealed.
+    fn seal_column(
+        json: &str,
+        workspace_key: &WorkspaceKey,
+        secrets: SecretValues,
+    ) -> Result<Option<String>, RepositoryError> {
+        let mut document = Self::parse_json(json)?;
+        match secrets {
+            SecretValues::Environment => match seal_document(document, workspace_key)? {
+                Some(sealed) => document = sealed,
+                None => return Ok(None),
+            },
+            SecretValues::All => {
+                if !holds_plaintext_secrets(&document, secrets) {
+                    return Ok(None);
+                }
+                workspace_key
+                    .seal(&mut document, secrets)
+                    .map_err(|error| RepositoryError::Internal(error.into()))?;
+            }
+        }
+
+        let json = match secrets {
+            SecretValues::Environment => serde_json::to_string_pretty(&document),
+            SecretValues::All => serde_json::to_string(&document),
+        }
+        .context("Failed to serialize sealed secrets")?;
+
+        Ok(Some(json))
+    }
+
+    /// Convert `rusqlite::Error` to `RepositoryError`
+    fn convert_sqlite_error(&self, error: rusqlite::Error) -> RepositoryError {
+        match error.sqlite_error_code() {
+            Some(ErrorCode::DatabaseBusy | ErrorCode::DatabaseLocked) => RepositoryError::Conflict,
+            _ => RepositoryError::Internal(anyhow::Error::from(error).context(format!(
+                "Database operation failed: {}",
+                self.database_path.display()
+            ))),
+        }
+    }
+}
+
+impl EnvironmentRepository for SqliteEnvironmentRepository {
+    fn save(&self, env: &AnyEnvironmentState) -> Result<(), RepositoryError> {
+        let secrets = self.secrets_column(env)?;
+
+        let mut connection = self.open(true)?.context("Database was not created")?;
+        let transaction = self.begin_write(&mut connection)?;
+        self.write_state(&transaction, env, Some(secrets))?;
+        self.commit(transaction)
+    }
+
+    fn load(&self, name: &EnvironmentName) -> Result<Option<AnyEnvironmentState>, RepositoryError> {
+        let Some(connection) = self.open(false)? else {
+            return Ok(None);
+        };
+        let Some(row) = self.read_row(&connection, name)? else {
+            return Ok(None);
+        };
+
+        let stored = self.parse_document(&row.document)?;
+        if stored.predates_sealed_secrets() {
+            self.seal_secrets(name)?;
+        }
+        let mut env = stored.into_state();
+
+        if let Some(secrets) = row.secrets {
+            let secrets: Value =
+                serde_json::from_str(&secrets).map_err(|error| RepositoryError::Corrupted {
+                    reason: format!("invalid secrets: {error}"),
+                })?;
+            let secrets: EnvironmentSecrets =
+                open_secrets_document(secrets, self.workspace_key.as_deref())?;
+            env.restore_secrets(secrets);
+        }
+
+        if let Some(secret) = env.missing_secret() {
+            return Err(RepositoryError::MissingSecret {
+                secret: secret.to_string(),
+            });
+        }
+
+        Ok(Some(env))
+    }
+
+    fn load_version(
+        &self,
+        name: &EnvironmentName,
+    ) -> Result<Option<StoredStateVersion>, RepositoryError> {
+        let Some(connection) = self.open(false)? else {
+            return Ok(None);
+        };
+
+        self.read_row(&connection, name)?
+            .map(|row| {
+                let value: Value = serde_json::from_str(&row.document).map_err(|error| {
+                    RepositoryError::Corrupted {
+                        reason: error.to_string(),
+                    }
+                })?;
+                StoredStateVersion::read(&value).map_err(|error| RepositoryError::Corrupted {
+                    reason: error.to_string(),
+                })
+            })
+            .transpose()
+    }
+
+    /// Stamped with the time the environment was last saved.
+    fn change_stamp(&self, name: &EnvironmentName) -> Result<Option<String>, RepositoryError> {
+        let Some(connection) = self.open(false)? else {
+            return Ok(None);
+        };
+
+        connection
+            .query_row(
+                "SELECT updated_at FROM environments WHERE name = ?1",
+                params![name.as_str()],
+                |row| row.get::<_, String>(0),
+            )
+            .optional()
+            .map_err(|e| self.convert_sqlite_error(e))
+    }
+
+    /// Only the indexed name column is read.
+    fn list(&self) -> Result<Vec<EnvironmentName>, RepositoryError> {
+        let Some(connection) = self.open(false)? else {
+            return Ok(Vec::new());
+        };
+
+        let mut statement = connection
+            .prepare("SELECT name FROM environments ORDER BY name")
+            .map_err(|e| self.convert_sqlite_error(e))?;
+        let names = statement
+            .query_map([], |row| row.get::<_, String>(0))
+            .and_then(Iterator::collect::<Result<Vec<String>, _>>)
+            .map_err(|e| self.convert_sqlite_error(e))?;
+
+        Ok(names
+            .into_iter()
+            .filter_map(|name| EnvironmentName::new(name).ok())
+            .collect())
+    }
+
+    fn exists(&self, name: &EnvironmentName) -> Result<bool, RepositoryError> {
+        let Some(connection) = self.open(false)? else {
+            return Ok(false);
+        };
+
+        connection
+            .query_row(
+                "SELECT 1 FROM environments WHERE name = ?1",
+                params![name.as_str()],
+                |_| Ok(()),
+            )
+            .optional()
+            .map(|row| row.is_some())
+            .map_err(|e| self.convert_sqlite_error(e))
+    }
+
+    fn delete(&self, name: &EnvironmentName) -> Result<(), RepositoryError> {
+        let Some(mut connection) = self.open(false)? else {
+            return Ok(());
+        };
+
+        let transaction = self.begin_write(&mut connection)?;
+        for statement in [
+            "DELETE FROM state_backups WHERE name = ?1",
+            "DELETE FROM environments WHERE name = ?1",
+        ] {
+            transaction
+                .execute(statement, params![name.as_str()])
+                .map_err(|e| self.convert_sqlite_error(e))?;
+        }
+        self.commit(transaction)
+    }
+
+    /// Held with a row of `environment_locks`, which creates the database
+    /// when missing.
+    fn lock(&self, name: &EnvironmentName) -> Result<EnvironmentLockGuard, RepositoryError> {
+        let mut connection = self.open(true)?.context("Database was not created")?;
+        let pid = ProcessId::current();
+        let started = Instant::now();
+
+        while !self.try_lock(&mut connection, name, pid)? {
+            if started.elapsed() >= self.lock_timeout {
+                return Err(RepositoryError::Conflict);
+            }
+            std::thread::sleep(LOCK_RETRY_INTERVAL);
+        }
+
+        Ok(EnvironmentLockGuard::new(
+            name.clone(),
+            EnvironmentLockRow {
+                database_path: self.database_path.clone(),
+                name: name.clone(),
+                pid,
+            },
+        ))
+    }
+
+    fn list_backups(&self, name: &EnvironmentName) -> Result<Vec<StateBackup>, RepositoryError> {
+        let Some(connection) = self.open(false)? else {
+            return Ok(Vec::new());
+        };
+
+        let mut statement = connection
+            .prepare("SELECT id, document FROM state_backups WHERE name = ?1 ORDER BY id DESC")
+            .map_err(|e| self.convert_sqlite_error(e))?;
+        let rows = statement
+            .query_map(params![name.as_str()], |row| {
+                Ok((row.get::<_, String>(0)?, row.get::<_, String>(1)?))
+            })
+            .and_then(Iterator::collect::<Result<Vec<_>, _>>)
+            .map_err(|e| self.convert_sqlite_error(e))?;
+
+        let mut backups = Vec::new();
+        for (id, document) in rows {
+            let Some(created_at) = backup_created_at(&id) else {
+                continue;
+            };
+
+            match self.parse_document(&document) {
+                Ok(stored) => backups.push(StateBackup {
+                    state_name: stored.state().state_name().to_string(),
+                    id,
+                    created_at,
+                }),
+                Err(error) => warn!(
+                    environment = %name,
+                    backup = %id,
+                    error = %error,
+                    "Skipping unreadable state backup"
+                ),
+            }
+        }
+
+        Ok(backups)
+    }
+
+    fn restore_backup(
+        &self,
+        name: &EnvironmentName,
+        backup_id: &str,
+    ) -> Result<(), RepositoryError> {
+        let Some(mut connection) = self.open(false)? else {
+            return Err(RepositoryError::NotFound);
+        };
+
+        let transaction = self.begin_write(&mut connection)?;
+        let document: String = transaction
+            .query_row(
+                "SELECT document FROM state_backups WHERE name = ?1 AND id = ?2",
+                params![name.as_str(), backup_id],
+                |row| row.get(0),
+            )
+            .optional()
+            .map_err(|e| self.convert_sqlite_error(e))?
+            .ok_or(RepositoryError::NotFound)?;
+
+        let state = self.parse_document(&document)?.into_state();
+
+        // Secrets are stored separately and kept as they are
+        self.write_state(&transaction, &state, None)?;
+        self.commit(transaction)
+    }
+
+    /// Seals the document, the secrets column and the state backups
+    fn seal_secrets(&self, name: &EnvironmentName) -> Result<bool, RepositoryError> {
+        let Some(workspace_key) = self.workspace_key.as_deref() else {
+            return Ok(false);
+        };
+        let Some(mut connection) = self.open(false)? else {
+            return Ok(false);
+        };
+
+        let transaction = self.begin_write(&mut connection)?;
+        let mut sealed = false;
+
+        if let Some(row) = self.read_row(&transaction, name)? {
+            if let Some(document) =
+                Self::seal_column(&row.document, workspace_key, SecretValues::Environment)?
+            {
+                transaction
+                    .execute(
+                        "UPDATE environments SET document = ?2 WHERE name = ?1",
+                        params![name.as_str(), document],
+                    )
+                    .map_err(|e| self.convert_sqlite_error(e))?;
+                sealed = true;
+            }
+
+            if let Some(secrets) = row.secrets {
+                if let Some(secrets) =
+                    Self::seal_column(&secrets, workspace_key, SecretValues::All)?
+                {
+                    transaction
+                        .execute(
+                            "UPDATE environments SET secrets = ?2 WHERE name = ?1",
+                            params![name.as_str(), secrets],
+                        )
+                        .map_err(|e| self.convert_sqlite_error(e))?;
+                    sealed = true;
+                }
+            }
+        }
+
+        let backups = {
+            let mut statement = transaction
+                .prepare("SELECT id, document FROM state_backups WHERE name = ?1")
+                .map_err(|e| self.convert_sqlite_error(e))?;
+            statement
+                .query_map(params![name.as_str()], |row| {
+                    Ok((row.get::<_, String>(0)?, row.get::<_, String>(1)?))
+                })
+                .and_then(Iterator::collect::<Result<Vec<_>, _>>)
+                .map_err(|e| self.convert_sqlite_error(e))?
+        };
+        for (id, document) in backups {
+            // Unreadable backups are never restored; they are left as they are
+            let Ok(Some(document)) =
+                Self::seal_column(&document, workspace_key, SecretValues::Environment)
+            else {
+                continue;
+            };
+            transaction
+                .execute(
+                    "UPDATE state_backups SET document = ?3 WHERE name = ?1 AND id = ?2",
+                    params![name.as_str(), id, document],
+                )
+                .map_err(|e| self.convert_sqlite_error(e))?;
+            sealed = true;
+        }
+
+        self.commit(transaction)?;
+        Ok(sealed)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::environment::repository::migrations::SEALED_SECRETS_SCHEMA_VERSION;
+    use crate::domain::environment::testing::EnvironmentTestBuilder;
+    use tempfile::TempDir;
+
+    fn environment(name: &str) -> AnyEnvironmentState {
+        let (env, _data_dir, _build_dir, _env_temp_dir) = EnvironmentTestBuilder::new()
+            .with_name(name)
+            .build_with_custom_paths();
+        AnyEnvironmentState::Created(env)
+    }
+
+    #[test]
+    fn it_should_store_environments_in_a_single_database_file() {
+        let temp_dir = TempDir::new().unwrap();
+        let repo = SqliteEnvironmentRepository::new(temp_dir.path().to_path_buf());
+
+        repo.save(&environment("staging")).unwrap();
+        repo.save(&environment("production")).unwrap();
+
+        assert!(temp_dir.path().join(DATABASE_FILE_NAME).is_file());
+        assert!(!temp_dir.path().join("staging").exists());
+        assert!(!temp_dir.path().join("production").exists());
+    }
+
+    #[test]
+    fn it_should_not_create_the_database_when_reading() {
+        let temp_dir = TempDir::new().unwrap();
+        let repo = SqliteEnvironmentRepository::new(temp_dir.path().to_path_buf());
+        let name = EnvironmentName::new("staging".to_string()).unwrap();
+
+        assert!(repo.load(&name).unwrap().is_none());
+        assert!(repo.list().unwrap().is_empty());
+        repo.delete(&name).unwrap();
+
+        assert!(!temp_dir.path().join(DATABASE_FILE_NAME).exists());
+    }
+
+    #[test]
+    fn it_should_index_the_name_and_state_of_each_environment() {
+        let temp_dir = TempDir::new().unwrap();
+        let repo = SqliteEnvironmentRepository::new(temp_dir.path().to_path_buf());
+        repo.save(&environment("staging")).unwrap();
+
+        let connection = Connection::open(repo.database_path()).unwrap();
+        let (name, state_name, secrets): (String, String, Option<String>) = connection
+            .query_row(
+                "SELECT name, state_name, secrets FROM environments",
+                [],
+                |row| Ok((row.get(0)?, row.get(1)?, row.get(2)?)),
+            )
+            .unwrap();
+        let document: String = connection
+            .query_row("SELECT document FROM environments", [], |row| row.get(0))
+            .unwrap();
+
+        assert_eq!(name, "staging");
+        assert_eq!(state_name, "created");
+        assert!(secrets.unwrap().contains("grafana_admin_password"));
+        assert!(!document.contains("admin_password"));
+    }
+
+    #[test]
+    fn it_should_seal_the_document_and_secrets_of_an_environment_saved_without_key() {
+        let temp_dir = TempDir::new().unwrap();
+        let state = environment("staging");
+        let token = state.admin_token().to_string();
+        SqliteEnvironmentRepository::new(temp_dir.path().to_path_buf())
+            .save(&state)
+            .unwrap();
+        let workspace_key = Arc::new(WorkspaceKey::new(temp_dir.path().join("workspace.key")));
+        let repo = SqliteEnvironmentRepository::new(temp_dir.path().to_path_buf())
+            .with_workspace_key(workspace_key);
+        let name = state.name().clone();
+
+        assert!(repo.seal_secrets(&name).unwrap());
+
+        let connection = Connection::open(repo.database_path()).unwrap();
+        let (document, secrets): (String, String) = connection
+            .query_row("SELECT document, secrets FROM environments", [], |row| {
+                Ok((row.get(0)?, row.get(1)?))
+            })
+            .unwrap();
+        assert!(!document.contains(&token));
+        assert!(!holds_plaintext_secrets(
+            &serde_json::from_str(&secrets).unwrap(),
+            SecretValues::All
+        ));
+        assert_eq!(repo.load(&name).unwrap().unwrap().admin_token(), token);
+    }
+
+    #[test]
+    fn it_should_seal_and_stamp_a_document_stored_before_secrets_were_sealed_when_loaded() {
+        let temp_dir = TempDir::new().unwrap();
+        let state = environment("staging");
+        let token = state.admin_token().to_string();
+        SqliteEnvironmentRepository::new(temp_dir.path().to_path_buf())
+            .save(&state)
+            .unwrap();
+        let connection = Connection::open(temp_dir.path().join("workspace.db")).unwrap();
+        let document: String = connection
+            .query_row("SELECT document FROM environments", [], |row| row.get(0))
+            .unwrap();
+        let mut document: Value = serde_json::from_str(&document).unwrap();
+        document["schema_version"] = Value::from(SEALED_SECRETS_SCHEMA_VERSION - 1);
+        connection
+            .execute(
+                "UPDATE environments SET document = ?1",
+                params![document.to_string()],
+            )
+            .unwrap();
+        let workspace_key = Arc::new(WorkspaceKey::new(temp_dir.path().join("workspace.key")));
+        let repo = SqliteEnvironmentRepository::new(temp_dir.path().to_path_buf())
+            .with_workspace_key(workspace_key);
+        let name = state.name().clone();
+
+        assert_eq!(repo.load(&name).unwrap().unwrap().admin_token(), token);
+
+        let document: String = connection
+            .query_row("SELECT document FROM environments", [], |row| row.get(0))
+            .unwrap();
+        assert!(!document.contains(&token));
+        assert_eq!(
+            repo.load_version(&name).unwrap(),
+            Some(StoredStateVersion::current())
+        );
+    }
+
+    #[test]
+    fn it_should_return_conflict_when_another_process_holds_the_write_lock() {
+        let temp_dir = TempDir::new().unwrap();
+        let repo = SqliteEnvironmentRepository::new(temp_dir.path().to_path_buf())
+            .with_lock_timeout(Duration::from_millis(50));
+        repo.save(&environment("staging")).unwrap();
+
+        let mut other = Connection::open(repo.database_path()).unwrap();
+        let _transaction = other
+            .transaction_with_behavior(TransactionBehavior::Immediate)
+            .unwrap();
+
+        let result = repo.save(&environment("staging"));
+
+        assert!(
+            matches!(result, Err(RepositoryError::Conflict)),
+            "Expected Conflict error, got: {result:?}"
+        );
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn it_should_create_the_database_readable_only_by_its_owner() {
+        use std::os::unix::fs::PermissionsExt;
+
+        let temp_dir = TempDir::new().unwrap();
+        let repo = SqliteEnvironmentRepository::new(temp_dir.path().to_path_buf());
+
+        repo.save(&environment("staging")).unwrap();
+
+        let mode = fs::metadata(repo.database_path())
+            .unwrap()
+            .permissions()
+            .mode();
+        assert_eq!(mode & 0o777, 0o600);
+    }
+
+    #[test]
+    fn it_should_report_corrupted_state_when_the_document_is_invalid() {
+        let temp_dir = TempDir::new().unwrap();
+        let repo = SqliteEnvironmentRepository::new(temp_dir.path().to_path_buf());
+        repo.save(&environment("staging")).unwrap();
+        Connection::open(repo.database_path())
+            .unwrap()
+            .execute("UPDATE environments SET document = '{\"Created\": '", [])
+            .unwrap();
+
+        let result = repo.load(&EnvironmentName::new("staging".to_string()).unwrap());
+
+        assert!(
+            matches!(result, Err(RepositoryError::Corrupted { .. })),
+            "Expected Corrupted error, got: {result:?}"
+        );
+    }
+
+    #[test]
+    fn it_should_take_over_the_environment_lock_of_a_process_that_is_no_longer_running() {
+        let temp_dir = TempDir::new().unwrap();
+        let repo = SqliteEnvironmentRepository::new(temp_dir.path().to_path_buf())
+            .with_lock_timeout(Duration::from_millis(200));
+        let name = EnvironmentName::new("staging".to_string()).unwrap();
+        drop(repo.lock(&name).unwrap());
+        Connection::open(repo.database_path())
+            .unwrap()
+            .execute(
+                "INSERT INTO environment_locks (name, pid, acquired_at) VALUES ('staging', 999999, '')",
+                [],
+            )
+            .unwrap();
+
+        let guard = repo.lock(&name);
+
+        assert!(guard.is_ok(), "Expected the stale lock to be taken over");
+    }
+
+    #[test]
+    fn it_should_delete_the_lock_row_when_the_guard_is_dropped() {
+        let temp_dir = TempDir::new().unwrap();
+        let repo = SqliteEnvironmentRepository::new(temp_dir.path().to_path_buf());
+        let name = EnvironmentName::new("staging".to_string()).unwrap();
+
+        drop(repo.lock(&name).unwrap());
+
+        let rows: i64 = Connection::open(repo.database_path())
+            .unwrap()
+            .query_row("SELECT COUNT(*) FROM environment_locks", [], |row| {
+                row.get(0)
+            })
+            .unwrap();
+        assert_eq!(rows, 0);
+    }
+}
@@ -0,0 +1,72 @@
+//! Repository factory for creating SQLite-based environment repositories
+//!
+//! The counterpart of `FileRepositoryFactory` for workspaces that store their
+//! environments in a `SQLite` database (`"repository": "sqlite"` in the
+//! workspace marker).
+//!
+//! # Usage
+//!
+//! ```rust,no_run
+//! use std::time::Duration;
+//! use std::path::PathBuf;
+//! use torrust_tracker_deployer_lib::infrastructure::persistence::sqlite_repository_factory::SqliteRepositoryFactory;
+//!
+//! let factory = SqliteRepositoryFactory::new(Duration::from_secs(30));
+//!
+//! // The database is `data/workspace.db`
+//! let repo = factory.create(PathBuf::from("data"));
+//! ```
+
+use std::path::PathBuf;
+use std::sync::Arc;
+use std::time::Duration;
+
+use crate::application::traits::RepositoryProvider;
+use crate::domain::environment::repository::EnvironmentRepository;
+use crate::infrastructure::persistence::sqlite::SqliteEnvironmentRepository;
+use crate::infrastructure::persistence::workspace_key::WorkspaceKey;
+
+/// Factory for creating `SqliteEnvironmentRepository` instances
+#[derive(Clone)]
+pub struct SqliteRepositoryFactory {
+    /// Maximum time to wait for the transactions of other processes
+    lock_timeout: Duration,
+    /// Key sealing the secrets written by the created repositories, if any
+    workspace_key: Option<Arc<WorkspaceKey>>,
+}
+
+impl SqliteRepositoryFactory {
+    /// Create a new repository factory with the specified lock timeout
+    #[must_use]
+    pub fn new(lock_timeout: Duration) -> Self {
+        Self {
+            lock_timeout,
+            workspace_key: None,
+        }
+    }
+
+    /// Seal the secrets written by the created repositories with `workspace_key`
+    #[must_use]
+    pub fn with_workspace_key(mut self, workspace_key: Option<Arc<WorkspaceKey>>) -> Self {
+        self.workspace_key = workspace_key;
+        self
+    }
+
+    /// Create a new `SqliteEnvironmentRepository` storing its database in `data_dir`
+    #[must_use]
+    pub fn create(&self, data_dir: PathBuf) -> Arc<dyn EnvironmentRepository + Send + Sync> {
+        let repository =
+            SqliteEnvironmentRepository::new(data_dir).with_lock_timeout(self.lock_timeout);
+        let repository = match &self.workspace_key {
+            Some(workspace_key) => repository.with_workspace_key(Arc::clone(workspace_key)),
+            None => repository,
+        };
+        Arc::new(repository)
+    }
+}
+
+impl RepositoryProvider for SqliteRepositoryFactory {
+    fn create(&self, data_dir: PathBuf) -> Arc<dyn EnvironmentRepository + Send + Sync> {
+        SqliteRepositoryFactory::create(self, data_dir)
+    }
+}
@@ -0,0 +1,226 @@
+//! Stored form of an environment, shared by the repository backends
+//!
+//! Every backend stores the same JSON document for an environment: the
+//! serialized `AnyEnvironmentState` stamped with the schema and deployer
+//! versions (see `domain::environment::repository::migrations`). Documents
+//! written with an older schema are migrated when loaded; documents written
+//! with a newer schema are rejected.
+//!
+//! State backups are identified by the time the backed up state was written,
+//! formatted with `STATE_BACKUP_ID_FORMAT`.
+//!
+//! With a workspace key, the secret values of the document are sealed when it
+//! is written and opened when it is read (see `workspace_key`). Documents
+//! stored before secrets could be sealed are sealed when loaded with a key.
+
+use std::borrow::Cow;
+
+use anyhow::Context as _;
+use chrono::{DateTime, NaiveDateTime, Utc};
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+
+use crate::domain::environment::repository::migrations::{
+    self, StateMigrationError, SEALED_SECRETS_SCHEMA_VERSION,
+};
+use crate::domain::environment::repository::{RepositoryError, StoredStateVersion};
+use crate::domain::environment::state::AnyEnvironmentState;
+use crate::infrastructure::persistence::workspace_key::{
+    holds_plaintext_secrets, open_sealed_values, unwrap_sealed_values, SecretValues, WorkspaceKey,
+};
+
+/// Number of state backups kept per environment by default
+pub const DEFAULT_STATE_BACKUP_LIMIT: usize = 5;
+
+/// Format of the timestamp of a state backup (also the backup id)
+///
+/// Fixed width, so that backup ids sort chronologically.
+pub(crate) const STATE_BACKUP_ID_FORMAT: &str = "%Y%m%dT%H%M%S%.9fZ";
+
+/// Format a write time as a backup id
+pub(crate) fn backup_id(written_at: DateTime<Utc>) -> String {
+    written_at.format(STATE_BACKUP_ID_FORMAT).to_string()
+}
+
+/// Parse the creation time encoded in a backup id
+pub(crate) fn backup_created_at(backup_id: &str) -> Option<DateTime<Utc>> {
+    NaiveDateTime::parse_from_str(backup_id, STATE_BACKUP_ID_FORMAT)
+        .ok()
+        .map(|time| time.and_utc())
+}
+
+/// Content of a stored environment
+///
+/// Serializes the state stamped with the current versions, and deserializes
+/// it after migrating it to the current schema.
+pub(crate) struct StoredEnvironment<'a> {
+    state: Cow<'a, AnyEnvironmentState>,
+    version: StoredStateVersion,
+    workspace_key: Option<&'a WorkspaceKey>,
+}
+
+impl<'a> StoredEnvironment<'a> {
+    /// Wrap a state to be written by this version of the deployer
+    pub(crate) fn current(state: &'a AnyEnvironmentState) -> Self {
+        Self {
+            state: Cow::Borrowed(state),
+            version: StoredStateVersion::current(),
+            workspace_key: None,
+        }
+    }
+
+    /// Seal the secrets of the state with `workspace_key` when it is written
+    pub(crate) fn sealed_with(mut self, workspace_key: Option<&'a WorkspaceKey>) -> Self {
+        self.workspace_key = workspace_key;
+        self
+    }
+
+    /// Migrate the raw content of a stored environment and deserialize it
+    ///
+    /// Content with sealed secrets is rejected: use [`Self::from_sealed_value`].
+    pub(crate) fn from_value(value: Value) -> Result<Self, RepositoryError> {
+        Self::from_sealed_value(value, None)
+    }
+
+    /// Open the sealed secrets of the raw content of a stored environment
+    /// with `workspace_key`, then migrate and deserialize it
+    pub(crate) fn from_sealed_value(
+        mut value: Value,
+        workspace_key: Option<&WorkspaceKey>,
+    ) -> Result<Self, RepositoryError> {
+        open_sealed_values(&mut value, SecretValues::Environment, workspace_key)
+            .map_err(|error| RepositoryError::Internal(error.into()))?;
+
+        Self::parse(value)
+    }
+
+    /// Migrate and deserialize content, leaving sealed secrets as they are
+    fn parse(value: Value) -> Result<Self, RepositoryError> {
+        let (value, version) = migrations::migrate(value).map_err(|error| match error {
+            StateMigrationError::UnsupportedVersion {
+                found,
+                supported,
+                deployer_version,
+            } => RepositoryError::UnsupportedVersion {
+                schema_version: found,
+                supported_version: supported,
+                deployer_version,
+            },
+            error => RepositoryError::Corrupted {
+                reason: error.to_string(),
+            },
+        })?;
+
+        let state = serde_json::from_value(value).map_err(|error| RepositoryError::Corrupted {
+            reason: error.to_string(),
+        })?;
+
+        Ok(Self {
+            state: Cow::Owned(state),
+            version,
+            workspace_key: None,
+        })
+    }
+
+    /// The stored state
+    pub(crate) fn state(&self) -> &AnyEnvironmentState {
+        &self.state
+    }
+
+    /// Whether the state was stored before secrets could be sealed
+    ///
+    /// Such states are sealed with [`seal_document`] when loaded with a key.
+    pub(crate) fn predates_sealed_secrets(&self) -> bool {
+        self.version.schema_version < SEALED_SECRETS_SCHEMA_VERSION
+    }
+
+    pub(crate) fn into_state(self) -> AnyEnvironmentState {
+        self.state.into_owned()
+    }
+}
+
+impl Serialize for StoredEnvironment<'_> {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        let mut value = serde_json::to_value(&*self.state).map_err(serde::ser::Error::custom)?;
+        migrations::stamp(&mut value).map_err(serde::ser::Error::custom)?;
+        if let Some(workspace_key) = self.workspace_key {
+            workspace_key
+                .seal(&mut value, SecretValues::Environment)
+                .map_err(serde::ser::Error::custom)?;
+        }
+        value.serialize(serializer)
+    }
+}
+
+/// Serialize the secrets document of an environment, sealed with `workspace_key`
+pub(crate) fn secrets_document<T: Serialize>(
+    secrets: &T,
+    workspace_key: Option<&WorkspaceKey>,
+) -> Result<Value, RepositoryError> {
+    let mut value = serde_json::to_value(secrets).map_err(|error| {
+        RepositoryError::Internal(anyhow::Error::from(error).context("Failed to serialize secrets"))
+    })?;
+    if let Some(workspace_key) = workspace_key {
+        workspace_key
+            .seal(&mut value, SecretValues::All)
+            .map_err(|error| RepositoryError::Internal(error.into()))?;
+    }
+    Ok(value)
+}
+
+/// Seal the plaintext secrets of the raw content of a stored environment
+///
+/// Content stored before secrets could be sealed is migrated and stamped with
+/// the current versions, so that older deployers refuse it instead of failing
+/// on its sealed values. Returns `None` if the content needs no change.
+pub(crate) fn seal_document(
+    document: Value,
+    workspace_key: &WorkspaceKey,
+) -> Result<Option<Value>, RepositoryError> {
+    let stored = StoredEnvironment::from_sealed_value(document.clone(), Some(workspace_key))?;
+
+    if stored.predates_sealed_secrets() {
+        let sealed = StoredEnvironment::current(stored.state()).sealed_with(Some(workspace_key));
+        let value = serde_json::to_value(&sealed).context("Failed to seal environment state")?;
+        return Ok(Some(value));
+    }
+
+    if !holds_plaintext_secrets(&document, SecretValues::Environment) {
+        return Ok(None);
+    }
+
+    let mut document = document;
+    workspace_key
+        .seal(&mut document, SecretValues::Environment)
+        .map_err(|error| RepositoryError::Internal(error.into()))?;
+    Ok(Some(document))
+}
+
+/// Open the sealed values of a secrets document and deserialize it
+pub(crate) fn open_secrets_document<T: for<'de> Deserialize<'de>>(
+    mut value: Value,
+    workspace_key: Option<&WorkspaceKey>,
+) -> Result<T, RepositoryError> {
+    open_sealed_values(&mut value, SecretValues::All, workspace_key)
+        .map_err(|error| RepositoryError::Internal(error.into()))?;
+
+    serde_json::from_value(value).map_err(|error| RepositoryError::Corrupted {
+        reason: format!("invalid secrets: {error}"),
+    })
+}
+
+impl<'de> Deserialize<'de> for StoredEnvironment<'_> {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        // Only checks that stored content is readable: sealed secrets are
+        // kept as their ciphertext, no key is needed
+        let mut value = Value::deserialize(deserializer)?;
+        unwrap_sealed_values(&mut value, SecretValues::Environment);
+        Self::parse(value).map_err(serde::de::Error::custom)
+    }
+}
@@ -0,0 +1,596 @@
+//! Encryption at rest of the secrets of persisted environments
+//!
+//! Environment files are kept in workspaces that end up in bug reports,
+//! backups and sometimes git. The secrets they hold (the tracker API admin
+//! token, database passwords, provider API tokens, and the values of the
+//! secrets file) are therefore sealed before they are written: each value is
+//! replaced by its `age` encryption for the workspace key, stored as an object
+//! `{"sealed": "<base64>"}` that no string given by the user can be mistaken
+//! for. Only the known secret fields of an environment document are sealed
+//! and opened (see `ENVIRONMENT_SECRET_FIELDS`). Sealed values are opened
+//! again when the environment is loaded, so the rest of the deployer only ever
+//! sees plaintext. The files rendered to `build/` for deployment hold the
+//! secrets in plaintext.
+//!
+//! The workspace key is an `age` X25519 identity stored outside the
+//! workspace, by default in `~/.config/torrust-deployer/workspace.key`. It is
+//! created by `init`, or by the first save of a secret when it does not exist
+//! yet. Without the key, sealed environments cannot be loaded: back it up
+//! with the workspaces it protects.
+//!
+//! Values stored in plaintext before the key existed still load. They are
+//! sealed the next time the environment is saved, or for every environment at
+//! once by `init` (see `EnvironmentRepository::seal_secrets`). States stored
+//! with a schema older than `SEALED_SECRETS_SCHEMA_VERSION` are sealed, and
+//! stamped with the current schema, as soon as they are loaded with the key.
+
+use std::fs;
+use std::io::Write;
+use std::path::{Path, PathBuf};
+use std::sync::OnceLock;
+
+use age::secrecy::ExposeSecret;
+use age::x25519::Identity;
+use base64::engine::general_purpose::STANDARD;
+use base64::Engine as _;
+use serde_json::{Map, Value};
+use thiserror::Error;
+
+/// Field of the object holding a sealed value in a persisted document
+const SEALED_VALUE_FIELD: &str = "sealed";
+
+/// Secret fields of an environment document
+///
+/// Paths are relative to the user inputs of the state, found at
+/// `<state>.context.user_inputs` whatever the state of the environment.
+const ENVIRONMENT_SECRET_FIELDS: &[&[&str]] = &[
+    &["tracker", "http_api", "admin_token"],
+    &["tracker", "core", "database", "config", "password"],
+    &["tracker", "core", "database", "config", "root_password"],
+    &["provider_config", "api_token"],
+];
+
+/// Errors of the workspace key
+#[derive(Debug, Error)]
+pub enum WorkspaceKeyError {
+    #[error("Failed to read the workspace key '{path}': {source}")]
+    ReadFailed {
+        path: PathBuf,
+        #[source]
+        source: std::io::Error,
+    },
+
+    #[error("Failed to create the workspace key '{path}': {source}")]
+    WriteFailed {
+        path: PathBuf,
+        #[source]
+        source: std::io::Error,
+    },
+
+    #[error("The workspace key '{path}' is not a valid age identity")]
+    Invalid { path: PathBuf },
+
+    #[error(
+        "The environment secrets are encrypted, but the workspace key '{path}' does not exist. Restore the key file that was used to save them"
+    )]
+    Missing { path: PathBuf },
+
+    #[error(
+        "The environment secrets are encrypted, but no workspace key is configured. Set HOME or TORRUST_DEPLOYER_WORKSPACE_KEY"
+    )]
+    NotConfigured,
+
+    #[error("Failed to decrypt a secret with the workspace key '{path}': {reason}")]
+    DecryptionFailed { path: PathBuf, reason: String },
+
+    #[error("Failed to encrypt a secret: {reason}")]
+    EncryptionFailed { reason: String },
+}
+
+/// Which string values of a persisted document are secrets
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum SecretValues {
+    /// Values of the [`ENVIRONMENT_SECRET_FIELDS`] of an environment document
+    Environment,
+
+    /// Every string value, as in the secrets document
+    All,
+}
+
+/// Key encrypting the secrets of the environments of a workspace
+///
+/// The key file is read, or created, the first time it is needed.
+pub struct WorkspaceKey {
+    path: PathBuf,
+    identity: OnceLock<Identity>,
+}
+
+impl std::fmt::Debug for WorkspaceKey {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        // The identity is a secret and is never printed
+        f.debug_struct("WorkspaceKey")
+            .field("path", &self.path)
+            .finish_non_exhaustive()
+    }
+}
+
+impl WorkspaceKey {
+    /// Workspace key stored in the file at `path`
+    ///
+    /// Nothing is read until a secret is sealed or opened.
+    #[must_use]
+    pub fn new(path: impl Into<PathBuf>) -> Self {
+        Self {
+            path: path.into(),
+            identity: OnceLock::new(),
+        }
+    }
+
+    /// Path of the key file
+    #[must_use]
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+
+    /// Create the key file if it does not exist yet
+    ///
+    /// Returns `true` if the key was created.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the key file cannot be read or written, or holds
+    /// no valid key.
+    pub fn create_if_missing(&self) -> Result<bool, WorkspaceKeyError> {
+        let existed = self.path.exists();
+        self.identity(true)?;
+        Ok(!existed)
+    }
+
+    /// Replace the secret values of `document` with their sealed form
+    ///
+    /// Values that are already sealed are kept as they are.
+    pub(crate) fn seal(
+        &self,
+        document: &mut Value,
+        secrets: SecretValues,
+    ) -> Result<(), WorkspaceKeyError> {
+        let mut result = Ok(());
+        visit_secrets(document, secrets, &mut |value| {
+            let Value::String(plaintext) = value else {
+                return;
+            };
+            if result.is_err() {
+                return;
+            }
+            match self.encrypt(plaintext) {
+                Ok(sealed) => *value = sealed,
+                Err(error) => result = Err(error),
+            }
+        });
+        result
+    }
+
+    fn encrypt(&self, plaintext: &str) -> Result<Value, WorkspaceKeyError> {
+        let recipient = self.identity(true)?.to_public();
+        let ciphertext = age::encrypt(&recipient, plaintext.as_bytes()).map_err(|e| {
+            WorkspaceKeyError::EncryptionFailed {
+                reason: e.to_string(),
+            }
+        })?;
+
+        let mut sealed = Map::new();
+        sealed.insert(
+            SEALED_VALUE_FIELD.to_string(),
+            Value::String(STANDARD.encode(ciphertext)),
+        );
+        Ok(Value::Object(sealed))
+    }
+
+    fn decrypt(&self, sealed: &str) -> Result<String, WorkspaceKeyError> {
+        let failed = |reason: String| WorkspaceKeyError::DecryptionFailed {
+            path: self.path.clone(),
+            reason,
+        };
+
+        let ciphertext = STANDARD.decode(sealed).map_err(|e| failed(e.to_string()))?;
+        let plaintext =
+            age::decrypt(self.identity(false)?, &ciphertext).map_err(|e| failed(e.to_string()))?;
+
+        String::from_utf8(plaintext).map_err(|e| failed(e.to_string()))
+    }
+
+    /// The key, read from its file or created when `create` is set
+    fn identity(&self, create: bool) -> Result<&Identity, WorkspaceKeyError> {
+        if let Some(identity) = self.identity.get() {
+            return Ok(identity);
+        }
+
+        let identity = match fs::read_to_string(&self.path) {
+            Ok(content) => self.parse(&content)?,
+            Err(error) if error.kind() == std::io::ErrorKind::NotFound => {
+                if !create {
+                    return Err(WorkspaceKeyError::Missing {
+                        path: self.path.clone(),
+                    });
+                }
+                self.generate()?
+            }
+            Err(source) => {
+                return Err(WorkspaceKeyError::ReadFailed {
+                    path: self.path.clone(),
+                    source,
+                })
+            }
+        };
+
+        Ok(self.identity.get_or_init(|| identity))
+    }
+
+    fn parse(&self, content: &str) -> Result<Identity, WorkspaceKeyError> {
+        content
+            .lines()
+            .map(str::trim)
+            .find(|line| !line.is_empty() && !line.starts_with('#'))
+            .and_then(|line| line.parse().ok())
+            .ok_or_else(|| WorkspaceKeyError::Invalid {
+                path: self.path.clone(),
+            })
+    }
+
+    /// Write a new key, keeping the one another process may have written first
+    fn generate(&self) -> Result<Identity, WorkspaceKeyError> {
+        let write_failed = |source| WorkspaceKeyError::WriteFailed {
+            path: self.path.clone(),
+            source,
+        };
+
+        if let Some(dir) = self.path.parent() {
+            fs::create_dir_all(dir).map_err(write_failed)?;
+        }
+
+        let identity = Identity::generate();
+        let content = format!(
+            "# Torrust Tracker Deployer workspace key: keep it private and backed up\n{}\n",
+            identity.to_string().expose_secret()
+        );
+
+        // The key is written aside and linked into place, which fails if
+        // another process created the key in the meantime
+        let temp_path = self
+            .path
+            .with_extension(format!("{}.tmp", std::process::id()));
+        let mut options = fs::OpenOptions::new();
+        options.write(true).create(true).truncate(true);
+        #[cfg(unix)]
+        {
+            use std::os::unix::fs::OpenOptionsExt;
+            options.mode(0o600);
+        }
+        let written = options
+            .open(&temp_path)
+            .and_then(|mut file| file.write_all(content.as_bytes()))
+            .and_then(|()| fs::hard_link(&temp_path, &self.path));
+        fs::remove_file(&temp_path).ok();
+
+        match written {
+            Ok(()) => Ok(identity),
+            Err(error) if error.kind() == std::io::ErrorKind::AlreadyExists => {
+                let content = fs::read_to_string(&self.path).map_err(|source| {
+                    WorkspaceKeyError::ReadFailed {
+                        path: self.path.clone(),
+                        source,
+                    }
+                })?;
+                self.parse(&content)
+            }
+            Err(error) => Err(write_failed(error)),
+        }
+    }
+}
+
+/// Replace the sealed values of the `secrets` of `document` with their plaintext
+///
+/// # Errors
+///
+/// Returns an error if `document` holds sealed values and `key` is `None`,
+/// or cannot open them.
+pub(crate) fn open_sealed_values(
+    document: &mut Value,
+    secrets: SecretValues,
+    key: Option<&WorkspaceKey>,
+) -> Result<(), WorkspaceKeyError> {
+    let mut result = Ok(());
+    visit_secrets(document, secrets, &mut |value| {
+        let Some(sealed) = sealed_value(value) else {
+            return;
+        };
+        if result.is_err() {
+            return;
+        }
+        match key.map(|key| key.decrypt(sealed)) {
+            Some(Ok(plaintext)) => *value = Value::String(plaintext),
+            Some(Err(error)) => result = Err(error),
+            None => result = Err(WorkspaceKeyError::NotConfigured),
+        }
+    });
+    result
+}
+
+/// Replace the sealed values of the `secrets` of `document` with their
+/// encoded ciphertext
+///
+/// Lets a document be checked for readability without the workspace key.
+pub(crate) fn unwrap_sealed_values(document: &mut Value, secrets: SecretValues) {
+    visit_secrets(document, secrets, &mut |value| {
+        if let Some(sealed) = sealed_value(value) {
+            *value = Value::String(sealed.to_string());
+        }
+    });
+}
+
+/// Remove the [`ENVIRONMENT_SECRET_FIELDS`] from every state of an
+/// environment document
+pub(crate) fn remove_secret_fields(document: &mut Value) {
+    let Value::Object(states) = document else {
+        return;
+    };
+    for state in states.values_mut() {
+        let Some(user_inputs) = state.pointer_mut("/context/user_inputs") else {
+            continue;
+        };
+        for path in ENVIRONMENT_SECRET_FIELDS {
+            let Some((name, parents)) = path.split_last() else {
+                continue;
+            };
+            let parent = parents
+                .iter()
+                .try_fold(&mut *user_inputs, |value, parent| value.get_mut(*parent));
+            if let Some(Value::Object(fields)) = parent {
+                fields.remove(*name);
+            }
+        }
+    }
+}
+
+/// Whether `document` holds secret values that are not sealed
+pub(crate) fn holds_plaintext_secrets(document: &Value, secrets: SecretValues) -> bool {
+    let mut document = document.clone();
+    let mut found = false;
+    visit_secrets(&mut document, secrets, &mut |value| {
+        found |= value.is_string();
+    });
+    found
+}
+
+/// The encoded ciphertext of a sealed value, `None` for any other value
+fn sealed_value(value: &Value) -> Option<&str> {
+    match value {
+        Value::Object(fields) if fields.len() == 1 => {
+            fields.get(SEALED_VALUE_FIELD).and_then(Value::as_str)
+        }
+        _ => None,
+    }
+}
+
+/// Call `visit` with every secret value of `document`, sealed or not
+fn visit_secrets(document: &mut Value, secrets: SecretValues, visit: &mut dyn FnMut(&mut Value)) {
+    match secrets {
+        SecretValues::Environment => {
+            let Value::Object(states) = document else {
+                return;
+            };
+            for state in states.values_mut() {
+                let Some(user_inputs) = state.pointer_mut("/context/user_inputs") else {
+                    continue;
+                };
+                for path in ENVIRONMENT_SECRET_FIELDS {
+                    let field = path
+                        .iter()
+                        .try_fold(&mut *user_inputs, |value, name| value.get_mut(*name));
+                    if let Some(field) = field {
+                        visit(field);
+                    }
+                }
+            }
+        }
+        SecretValues::All => visit_all(document, visit),
+    }
+}
+
+/// Call `visit` with every sealed value and every string value of `value`
+fn visit_all(value: &mut Value, visit: &mut dyn FnMut(&mut Value)) {
+    if value.is_string() || sealed_value(value).is_some() {
+        visit(value);
+        return;
+    }
+    match value {
+        Value::Array(items) => {
+            for item in items {
+                visit_all(item, visit);
+            }
+        }
+        Value::Object(fields) => {
+            for field in fields.values_mut() {
+                visit_all(field, visit);
+            }
+        }
+        _ => {}
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+    use tempfile::TempDir;
+
+    use super::*;
+
+    fn environment_document_with_admin_token(admin_token: &str) -> Value {
+        json!({
+            "Created": {
+                "context": {
+                    "user_inputs": {
+                        "name": "my-env",
+                        "tracker": {
+                            "http_api": { "admin_token": admin_token, "bind_address": "0.0.0.0:1212" },
+                            "core": {
+                                "database": {
+                                    "driver": "mysql",
+                                    "config": { "password": "db-pass", "root_password": "root-pass", "min_password_length": 12 }
+                                }
+                            }
+                        }
+                    }
+                }
+            },
+            "schema_version": 3
+        })
+    }
+
+    fn environment_document() -> Value {
+        environment_document_with_admin_token("MyAccessToken")
+    }
+
+    #[test]
+    fn it_should_seal_the_secret_fields_and_open_them_again() {
+        let temp_dir = TempDir::new().unwrap();
+        let key = WorkspaceKey::new(temp_dir.path().join("workspace.key"));
+        let mut document = environment_document();
+
+        key.seal(&mut document, SecretValues::Environment).unwrap();
+
+        let sealed = document.to_string();
+        assert!(!sealed.contains("MyAccessToken"));
+        assert!(!sealed.contains("db-pass"));
+        assert!(!sealed.contains("root-pass"));
+        assert!(sealed.contains("0.0.0.0:1212"));
+        assert!(!holds_plaintext_secrets(
+            &document,
+            SecretValues::Environment
+        ));
+
+        open_sealed_values(&mut document, SecretValues::Environment, Some(&key)).unwrap();
+
+        assert_eq!(document, environment_document());
+    }
+
+    #[test]
+    fn it_should_create_the_key_once_with_owner_only_permissions() {
+        let temp_dir = TempDir::new().unwrap();
+        let path = temp_dir.path().join("config/workspace.key");
+
+        assert!(WorkspaceKey::new(&path).create_if_missing().unwrap());
+        assert!(!WorkspaceKey::new(&path).create_if_missing().unwrap());
+
+        #[cfg(unix)]
+        {
+            use std::os::unix::fs::PermissionsExt;
+            let mode = fs::metadata(&path).unwrap().permissions().mode();
+            assert_eq!(mode & 0o777, 0o600);
+        }
+    }
+
+    #[test]
+    fn it_should_open_values_sealed_by_another_instance_of_the_same_key() {
+        let temp_dir = TempDir::new().unwrap();
+        let path = temp_dir.path().join("workspace.key");
+        let mut document = environment_document();
+
+        WorkspaceKey::new(&path)
+            .seal(&mut document, SecretValues::Environment)
+            .unwrap();
+        open_sealed_values(
+            &mut document,
+            SecretValues::Environment,
+            Some(&WorkspaceKey::new(&path)),
+        )
+        .unwrap();
+
+        assert_eq!(document, environment_document());
+    }
+
+    #[test]
+    fn it_should_fail_to_open_sealed_values_without_a_key() {
+        let temp_dir = TempDir::new().unwrap();
+        let key = WorkspaceKey::new(temp_dir.path().join("workspace.key"));
+        let mut document = environment_document();
+        key.seal(&mut document, SecretValues::Environment).unwrap();
+
+        let result = open_sealed_values(&mut document, SecretValues::Environment, None);
+
+        assert!(matches!(result, Err(WorkspaceKeyError::NotConfigured)));
+    }
+
+    #[test]
+    fn it_should_round_trip_secrets_that_look_like_sealed_values() {
+        let temp_dir = TempDir::new().unwrap();
+        let key = WorkspaceKey::new(temp_dir.path().join("workspace.key"));
+        let mut document = environment_document_with_admin_token("age:MyAccessToken");
+        document["Created"]["context"]["user_inputs"]["name"] = json!("age:my-env");
+
+        key.seal(&mut document, SecretValues::Environment).unwrap();
+
+        assert!(!document.to_string().contains("age:MyAccessToken"));
+
+        open_sealed_values(&mut document, SecretValues::Environment, Some(&key)).unwrap();
+
+        let mut expected = environment_document_with_admin_token("age:MyAccessToken");
+        expected["Created"]["context"]["user_inputs"]["name"] = json!("age:my-env");
+        assert_eq!(document, expected);
+    }
+
+    #[test]
+    fn it_should_only_open_sealed_values_of_the_secret_fields() {
+        let temp_dir = TempDir::new().unwrap();
+        let key = WorkspaceKey::new(temp_dir.path().join("workspace.key"));
+        let mut document = environment_document();
+        document["Created"]["context"]["user_inputs"]["labels"] =
+            json!({ "sealed": "bm90IGEgY2lwaGVydGV4dA==" });
+        let expected = document.clone();
+
+        key.seal(&mut document, SecretValues::Environment).unwrap();
+        open_sealed_values(&mut document, SecretValues::Environment, Some(&key)).unwrap();
+
+        assert_eq!(document, expected);
+    }
+
+    #[test]
+    fn it_should_remove_the_secret_fields_and_keep_the_others() {
+        let mut document = environment_document();
+
+        remove_secret_fields(&mut document);
+
+        let user_inputs = &document["Created"]["context"]["user_inputs"];
+        assert!(user_inputs["tracker"]["http_api"]
+            .get("admin_token")
+            .is_none());
+        assert_eq!(
+            user_inputs["tracker"]["core"]["database"]["config"],
+            json!({ "min_password_length": 12 })
+        );
+        assert_eq!(
+            user_inputs["tracker"]["http_api"]["bind_address"],
+            "0.0.0.0:1212"
+        );
+        assert!(!holds_plaintext_secrets(
+            &document,
+            SecretValues::Environment
+        ));
+    }
+
+    #[test]
+    fn it_should_report_plaintext_secrets() {
+        assert!(holds_plaintext_secrets(
+            &environment_document(),
+            SecretValues::Environment
+        ));
+        assert!(!holds_plaintext_secrets(
+            &json!({ "name": "my-env" }),
+            SecretValues::Environment
+        ));
+        assert!(holds_plaintext_secrets(
+            &json!({ "tracker_auth_keys": [{ "key": "abc" }] }),
+            SecretValues::All
+        ));
+    }
+}
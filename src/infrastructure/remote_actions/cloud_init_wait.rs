@@ -0,0 +1,375 @@
+//! Cloud-init readiness polling remote action
+//!
+//! This module provides the `CloudInitWaiter` which waits for cloud-init to
+//! finish on a newly provisioned instance. Unlike `CloudInitValidator`, which
+//! checks the status once, the waiter polls the instance over SSH until
+//! cloud-init is done, it reports an error, or the configured timeout expires.
+//!
+//! ## Key Features
+//!
+//! - One SSH round trip per check (completion marker and `cloud-init status`)
+//! - Exponential backoff between checks, capped at `CloudInitWaitPolicy::max_interval`
+//! - Transient SSH failures (e.g. an instance rebooting) are retried, not fatal
+//! - A tail of `/var/log/cloud-init-output.log` is captured when waiting fails
+//!
+//! ## Usage Context
+//!
+//! Used by the provision workflow after SSH connectivity is established, so
+//! that configuration never starts on a half-initialized instance.
+
+use std::time::{Duration, Instant};
+
+use thiserror::Error;
+use tracing::{info, instrument, warn};
+
+use crate::adapters::ssh::{SshClient, SshConfig};
+
+/// Remote file whose tail is captured when waiting for cloud-init fails
+pub const CLOUD_INIT_OUTPUT_LOG: &str = "/var/log/cloud-init-output.log";
+
+/// Number of lines of the cloud-init output log captured on failure
+const LOG_TAIL_LINES: usize = 50;
+
+/// Single SSH command reporting the completion marker and the cloud-init status
+///
+/// Containers built without cloud-init only get the marker check.
+/// `cloud-init status` exits non-zero on errors, so its exit code is ignored
+/// and the printed status is parsed instead.
+const PROBE_COMMAND: &str = "if [ -f /var/lib/cloud/instance/boot-finished ]; then echo 'boot-finished: yes'; else echo 'boot-finished: no'; fi; if command -v cloud-init >/dev/null 2>&1; then cloud-init status 2>&1 || true; else echo 'status: not-installed'; fi";
+
+/// Timing of the cloud-init readiness checks
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct CloudInitWaitPolicy {
+    /// Maximum total time to wait for cloud-init
+    pub timeout: Duration,
+
+    /// Delay before the second check
+    pub initial_interval: Duration,
+
+    /// Upper bound of the delay between two checks
+    pub max_interval: Duration,
+}
+
+impl CloudInitWaitPolicy {
+    /// Policy with the given timeout and the default backoff (2s doubling up to 15s)
+    #[must_use]
+    pub const fn with_timeout(timeout: Duration) -> Self {
+        Self {
+            timeout,
+            initial_interval: Duration::from_secs(2),
+            max_interval: Duration::from_secs(15),
+        }
+    }
+
+    /// Delay following `current`, doubled and capped at `max_interval`
+    #[must_use]
+    pub fn next_interval(&self, current: Duration) -> Duration {
+        current.saturating_mul(2).min(self.max_interval)
+    }
+}
+
+/// Cloud-init status observed by one readiness check
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum CloudInitStatus {
+    /// Cloud-init finished (or is not installed) and the completion marker exists
+    Done,
+
+    /// Cloud-init is still working, or the instance could not be checked
+    Pending(String),
+
+    /// Cloud-init reported an error; waiting longer will not help
+    Failed(String),
+}
+
+impl CloudInitStatus {
+    /// Interpret the output of the readiness probe
+    #[must_use]
+    pub fn parse(output: &str) -> Self {
+        let marker_exists = output
+            .lines()
+            .any(|line| line.trim() == "boot-finished: yes");
+
+        let status = output
+            .lines()
+            .find_map(|line| line.trim().strip_prefix("status:"))
+            .map_or("unknown", str::trim);
+
+        match status {
+            "error" => Self::Failed(output.trim().to_string()),
+            "done" | "not-installed" if marker_exists => Self::Done,
+            "done" | "not-installed" => {
+                Self::Pending("waiting for the boot-finished marker".to_string())
+            }
+            other => Self::Pending(other.to_string()),
+        }
+    }
+}
+
+impl std::fmt::Display for CloudInitStatus {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Done => write!(f, "done"),
+            Self::Pending(status) => write!(f, "{status}"),
+            Self::Failed(status) => write!(f, "error ({status})"),
+        }
+    }
+}
+
+/// Outcome of one readiness check, reported while waiting
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CloudInitPoll {
+    /// 1-based check number
+    pub attempt: u32,
+
+    /// Time elapsed since waiting started
+    pub elapsed: Duration,
+
+    /// Status observed by the check
+    pub status: CloudInitStatus,
+}
+
+/// Errors that can occur while waiting for cloud-init
+#[derive(Debug, Error)]
+pub enum CloudInitWaitError {
+    /// Cloud-init did not finish within the timeout
+    #[error(
+        "Cloud-init did not finish within {timeout_secs} seconds (last status: {last_status})"
+    )]
+    Timeout {
+        timeout_secs: u64,
+        last_status: String,
+        log_tail: Option<String>,
+    },
+
+    /// Cloud-init reported an error
+    #[error("Cloud-init reported an error: {status}")]
+    Failed {
+        status: String,
+        log_tail: Option<String>,
+    },
+}
+
+impl CloudInitWaitError {
+    /// Tail of the remote cloud-init output log, if it could be read
+    #[must_use]
+    pub fn log_tail(&self) -> Option<&str> {
+        match self {
+            Self::Timeout { log_tail, .. } | Self::Failed { log_tail, .. } => log_tail.as_deref(),
+        }
+    }
+}
+
+impl crate::shared::Traceable for CloudInitWaitError {
+    fn trace_format(&self) -> String {
+        match self {
+            Self::Timeout {
+                timeout_secs,
+                last_status,
+                ..
+            } => {
+                format!("CloudInitWaitError: Timeout after {timeout_secs} seconds - last status: {last_status}")
+            }
+            Self::Failed { status, .. } => {
+                format!("CloudInitWaitError: Cloud-init reported an error - {status}")
+            }
+        }
+    }
+
+    fn trace_source(&self) -> Option<&dyn crate::shared::Traceable> {
+        None
+    }
+
+    fn error_kind(&self) -> crate::shared::ErrorKind {
+        match self {
+            Self::Timeout { .. } => crate::shared::ErrorKind::Timeout,
+            Self::Failed { .. } => crate::shared::ErrorKind::InfrastructureOperation,
+        }
+    }
+}
+
+/// Action that waits until cloud-init has finished on the server
+pub struct CloudInitWaiter {
+    ssh_client: SshClient,
+    policy: CloudInitWaitPolicy,
+}
+
+impl CloudInitWaiter {
+    /// Create a new `CloudInitWaiter`
+    ///
+    /// # Arguments
+    /// * `ssh_config` - SSH connection configuration containing credentials and host IP
+    /// * `policy` - Timeout and backoff of the readiness checks
+    #[must_use]
+    pub fn new(ssh_config: SshConfig, policy: CloudInitWaitPolicy) -> Self {
+        Self {
+            ssh_client: SshClient::new(ssh_config),
+            policy,
+        }
+    }
+
+    /// Poll the server until cloud-init has finished
+    ///
+    /// `on_poll` is called after every check that did not finish the wait,
+    /// so callers can report progress.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if cloud-init reports an error or does not finish
+    /// within the policy timeout. Both errors carry the tail of the remote
+    /// cloud-init output log when it can be read.
+    #[instrument(
+        name = "remote_action.cloud_init_wait",
+        skip_all,
+        fields(
+            action_type = "wait",
+            component = "cloud_init",
+            timeout_secs = self.policy.timeout.as_secs()
+        )
+    )]
+    pub async fn wait(
+        &self,
+        mut on_poll: impl FnMut(&CloudInitPoll),
+    ) -> Result<(), CloudInitWaitError> {
+        let started = Instant::now();
+        let mut interval = self.policy.initial_interval;
+        let mut attempt = 0;
+
+        loop {
+            attempt += 1;
+            let status = self.probe();
+            let elapsed = started.elapsed();
+
+            match status {
+                CloudInitStatus::Done => {
+                    info!(
+                        action = "cloud_init_wait",
+                        attempt,
+                        elapsed_secs = elapsed.as_secs(),
+                        "Cloud-init finished"
+                    );
+                    return Ok(());
+                }
+                CloudInitStatus::Failed(status) => {
+                    warn!(
+                        action = "cloud_init_wait",
+                        attempt,
+                        status = %status,
+                        "Cloud-init reported an error"
+                    );
+                    return Err(CloudInitWaitError::Failed {
+                        status,
+                        log_tail: self.output_log_tail(),
+                    });
+                }
+                CloudInitStatus::Pending(ref last_status) => {
+                    let remaining = self.policy.timeout.saturating_sub(elapsed);
+                    if remaining.is_zero() {
+                        warn!(
+                            action = "cloud_init_wait",
+                            attempt,
+                            elapsed_secs = elapsed.as_secs(),
+                            last_status = %last_status,
+                            "Timed out waiting for cloud-init"
+                        );
+                        return Err(CloudInitWaitError::Timeout {
+                            timeout_secs: self.policy.timeout.as_secs(),
+                            last_status: last_status.clone(),
+                            log_tail: self.output_log_tail(),
+                        });
+                    }
+
+                    on_poll(&CloudInitPoll {
+                        attempt,
+                        elapsed,
+                        status: status.clone(),
+                    });
+
+                    tokio::time::sleep(interval.min(remaining)).await;
+                    interval = self.policy.next_interval(interval);
+                }
+            }
+        }
+    }
+
+    /// Run one readiness check, treating SSH failures as "still pending"
+    fn probe(&self) -> CloudInitStatus {
+        match self.ssh_client.execute(PROBE_COMMAND) {
+            Ok(output) => CloudInitStatus::parse(&output),
+            Err(e) => CloudInitStatus::Pending(format!("instance not reachable: {e}")),
+        }
+    }
+
+    /// Read the last lines of the cloud-init output log, best-effort
+    fn output_log_tail(&self) -> Option<String> {
+        let command = format!(
+            "sudo -n tail -n {LOG_TAIL_LINES} {CLOUD_INIT_OUTPUT_LOG} 2>/dev/null || tail -n {LOG_TAIL_LINES} {CLOUD_INIT_OUTPUT_LOG}"
+        );
+
+        match self.ssh_client.execute(&command) {
+            Ok(output) if !output.trim().is_empty() => Some(output),
+            Ok(_) => None,
+            Err(e) => {
+                warn!(
+                    action = "cloud_init_wait",
+                    error = %e,
+                    "Could not read the cloud-init output log"
+                );
+                None
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn it_should_be_done_when_cloud_init_is_done_and_the_marker_exists() {
+        let status = CloudInitStatus::parse("boot-finished: yes\nstatus: done\n");
+
+        assert_eq!(status, CloudInitStatus::Done);
+    }
+
+    #[test]
+    fn it_should_be_done_when_cloud_init_is_not_installed_and_the_marker_exists() {
+        let status = CloudInitStatus::parse("boot-finished: yes\nstatus: not-installed\n");
+
+        assert_eq!(status, CloudInitStatus::Done);
+    }
+
+    #[test]
+    fn it_should_keep_waiting_while_cloud_init_is_running() {
+        let status = CloudInitStatus::parse("boot-finished: no\nstatus: running\n");
+
+        assert_eq!(status, CloudInitStatus::Pending("running".to_string()));
+    }
+
+    #[test]
+    fn it_should_keep_waiting_for_the_marker_after_cloud_init_is_done() {
+        let status = CloudInitStatus::parse("boot-finished: no\nstatus: done\n");
+
+        assert!(matches!(status, CloudInitStatus::Pending(_)));
+    }
+
+    #[test]
+    fn it_should_fail_when_cloud_init_reports_an_error() {
+        let status = CloudInitStatus::parse("boot-finished: yes\nstatus: error\n");
+
+        assert!(matches!(status, CloudInitStatus::Failed(_)));
+    }
+
+    #[test]
+    fn it_should_double_the_interval_up_to_the_maximum() {
+        let policy = CloudInitWaitPolicy::with_timeout(Duration::from_secs(300));
+
+        let intervals: Vec<u64> = std::iter::successors(Some(policy.initial_interval), |i| {
+            Some(policy.next_interval(*i))
+        })
+        .take(5)
+        .map(|i| i.as_secs())
+        .collect();
+
+        assert_eq!(intervals, vec![2, 4, 8, 15, 15]);
+    }
+}
@@ -0,0 +1,400 @@
+//! Container image pull and digest verification remote actions
+//!
+//! This module provides the `ContainerImages` action which logs the instance
+//! in to a private registry, pulls the images of the Docker Compose stack,
+//! checks that the images pinned by digest were pulled with that digest, and
+//! lists the images the running containers were created from.
+//!
+//! ## Key Features
+//!
+//! - `docker login` reads the password from stdin, so it never appears in a
+//!   command line, and stores the credentials in the Docker client
+//!   configuration of root on the instance only
+//! - Commands run as root, like the playbook starting the services, so the
+//!   `docker compose pull` of the `run` command finds the same credentials
+//! - The images of the `backup` profile are pulled along with the others
+//!
+//! ## Usage Context
+//!
+//! Used by the release command after the Docker Compose files are deployed,
+//! so a wrong digest fails the release before any container is restarted,
+//! and by the drift command to compare the running containers with the
+//! expected Docker Compose file.
+
+use thiserror::Error;
+use tracing::{info, instrument};
+
+use crate::adapters::ssh::{SshClient, SshConfig};
+use crate::domain::release::{ImageReference, RegistryCredentials};
+use crate::shared::command::CommandError;
+
+/// Directory of the Docker Compose stack on the instance
+const DEPLOY_DIR: &str = "/opt/torrust";
+
+/// Errors that can occur while pulling or verifying the container images
+#[derive(Debug, Error)]
+pub enum ContainerImagesError {
+    /// `docker login` failed on the instance
+    #[error("Failed to log in to the container registry '{server}': {source}")]
+    LoginFailed {
+        server: String,
+        #[source]
+        source: Box<CommandError>,
+    },
+
+    /// `docker compose pull` failed on the instance
+    #[error("Failed to pull the container images: {source}")]
+    PullFailed {
+        #[source]
+        source: Box<CommandError>,
+    },
+
+    /// The digests of a pulled image could not be read
+    #[error("Failed to read the digests of the image of service '{service}': {source}")]
+    InspectFailed {
+        service: String,
+        #[source]
+        source: Box<CommandError>,
+    },
+
+    /// The running containers could not be listed
+    #[error("Failed to list the running containers: {source}")]
+    ListFailed {
+        #[source]
+        source: Box<CommandError>,
+    },
+
+    /// A pulled image does not have the pinned digest
+    #[error(
+        "Image of service '{service}' does not match its pinned digest: expected {expected}, pulled {actual}"
+    )]
+    DigestMismatch {
+        service: String,
+        expected: String,
+        actual: String,
+    },
+}
+
+impl crate::shared::Traceable for ContainerImagesError {
+    fn trace_format(&self) -> String {
+        format!("ContainerImagesError: {self}")
+    }
+
+    fn trace_source(&self) -> Option<&dyn crate::shared::Traceable> {
+        None
+    }
+
+    fn error_kind(&self) -> crate::shared::ErrorKind {
+        match self {
+            Self::LoginFailed { source, .. }
+            | Self::PullFailed { source }
+            | Self::ListFailed { source }
+            | Self::InspectFailed { source, .. } => source.error_kind(),
+            Self::DigestMismatch { .. } => crate::shared::ErrorKind::Configuration,
+        }
+    }
+}
+
+/// A container of the Docker Compose stack running on the instance
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RunningContainer {
+    /// Name of the Docker Compose service
+    pub service: String,
+    /// Image the container was created from, as written in the compose file
+    pub image: String,
+    /// Repository digests of the image (`sha256:...`)
+    pub digests: Vec<String>,
+}
+
+/// Action that pulls and verifies the container images on the instance
+pub struct ContainerImages {
+    ssh_client: SshClient,
+}
+
+impl ContainerImages {
+    /// Create a new `ContainerImages`
+    ///
+    /// # Arguments
+    /// * `ssh_config` - SSH connection configuration containing credentials and host IP
+    #[must_use]
+    pub fn new(ssh_config: SshConfig) -> Self {
+        Self {
+            ssh_client: SshClient::new(ssh_config),
+        }
+    }
+
+    /// Log the instance in to a private registry
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if `docker login` fails, e.g. on wrong credentials.
+    #[instrument(
+        name = "remote_action.container_registry_login",
+        skip_all,
+        fields(action_type = "release", server = %registry.server())
+    )]
+    pub fn login(&self, registry: &RegistryCredentials) -> Result<(), ContainerImagesError> {
+        self.ssh_client
+            .execute_with_input(
+                &login_command(registry),
+                registry.password().expose_secret().as_bytes(),
+            )
+            .map_err(|source| ContainerImagesError::LoginFailed {
+                server: registry.server().to_string(),
+                source: Box::new(source),
+            })?;
+
+        info!(
+            action = "container_registry_login",
+            server = %registry.server(),
+            "Logged in to the container registry"
+        );
+
+        Ok(())
+    }
+
+    /// Pull the images of every service of the Docker Compose stack
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if `docker compose pull` fails.
+    #[instrument(
+        name = "remote_action.container_images_pull",
+        skip_all,
+        fields(action_type = "release")
+    )]
+    pub fn pull(&self) -> Result<(), ContainerImagesError> {
+        self.ssh_client.execute(&pull_command()).map_err(|source| {
+            ContainerImagesError::PullFailed {
+                source: Box::new(source),
+            }
+        })?;
+
+        info!(action = "container_images_pull", "Container images pulled");
+
+        Ok(())
+    }
+
+    /// Check that the pulled `image` of `service` has its pinned digest
+    ///
+    /// Images without digest are not checked.
+    ///
+    /// # Errors
+    ///
+    /// Returns `DigestMismatch` if the image is missing on the instance or
+    /// none of its repository digests is the pinned one, and
+    /// `InspectFailed` if the digests cannot be read.
+    #[instrument(
+        name = "remote_action.container_image_verify",
+        skip_all,
+        fields(action_type = "release", service = %service, image = %image)
+    )]
+    pub fn verify_digest(
+        &self,
+        service: &str,
+        image: &ImageReference,
+    ) -> Result<(), ContainerImagesError> {
+        let Some(expected) = image.digest() else {
+            return Ok(());
+        };
+
+        let repo_digests = self
+            .ssh_client
+            .execute(&inspect_command(image.as_str()))
+            .map_err(|source| ContainerImagesError::InspectFailed {
+                service: service.to_string(),
+                source: Box::new(source),
+            })?;
+
+        let pulled = pulled_digests(&repo_digests);
+        if !pulled.contains(&expected) {
+            return Err(ContainerImagesError::DigestMismatch {
+                service: service.to_string(),
+                expected: expected.to_string(),
+                actual: if pulled.is_empty() {
+                    "no image".to_string()
+                } else {
+                    pulled.join(", ")
+                },
+            });
+        }
+
+        info!(
+            action = "container_image_verify",
+            service = %service,
+            digest = %expected,
+            "Container image digest verified"
+        );
+
+        Ok(())
+    }
+
+    /// List the running containers of the Docker Compose stack with their images
+    ///
+    /// # Errors
+    ///
+    /// Returns `ListFailed` if the containers cannot be listed, and
+    /// `InspectFailed` if the digests of an image cannot be read.
+    #[instrument(
+        name = "remote_action.running_containers",
+        skip_all,
+        fields(action_type = "drift")
+    )]
+    pub fn running(&self) -> Result<Vec<RunningContainer>, ContainerImagesError> {
+        let output = self
+            .ssh_client
+            .execute(&running_command())
+            .map_err(|source| ContainerImagesError::ListFailed {
+                source: Box::new(source),
+            })?;
+
+        let mut containers = Vec::new();
+        for (service, image, image_id) in parse_running(&output) {
+            let repo_digests = self
+                .ssh_client
+                .execute(&inspect_command(image_id))
+                .map_err(|source| ContainerImagesError::InspectFailed {
+                    service: service.to_string(),
+                    source: Box::new(source),
+                })?;
+
+            containers.push(RunningContainer {
+                service: service.to_string(),
+                image: image.to_string(),
+                digests: pulled_digests(&repo_digests)
+                    .into_iter()
+                    .map(ToString::to_string)
+                    .collect(),
+            });
+        }
+
+        info!(
+            action = "running_containers",
+            count = containers.len(),
+            "Running containers listed"
+        );
+
+        Ok(containers)
+    }
+}
+
+/// Shell command logging root in to `registry`, reading the password from stdin
+fn login_command(registry: &RegistryCredentials) -> String {
+    format!(
+        "sudo -n docker login {} --username {} --password-stdin",
+        quote(registry.server()),
+        quote(registry.username())
+    )
+}
+
+/// Shell command pulling the images of all services, including profiled ones
+fn pull_command() -> String {
+    format!("cd {DEPLOY_DIR} && sudo -n docker compose --profile backup pull --quiet")
+}
+
+/// Shell command printing the repository digests of `image` (a reference or
+/// an image ID), space-separated
+///
+/// Prints nothing when the image is missing, which is reported as a mismatch.
+fn inspect_command(image: &str) -> String {
+    format!(
+        "sudo -n docker image inspect --format '{{{{join .RepoDigests \" \"}}}}' {} 2>/dev/null || true",
+        quote(image)
+    )
+}
+
+/// Shell command printing `<service> <image> <image id>` for each running
+/// container of the stack
+fn running_command() -> String {
+    format!(
+        "cd {DEPLOY_DIR} && sudo -n docker compose ps --quiet | xargs --no-run-if-empty sudo -n docker inspect --format '{{{{index .Config.Labels \"com.docker.compose.service\"}}}} {{{{.Config.Image}}}} {{{{.Image}}}}'"
+    )
+}
+
+/// The `(service, image, image id)` of each line printed by `running_command`
+fn parse_running(output: &str) -> Vec<(&str, &str, &str)> {
+    output
+        .lines()
+        .filter_map(|line| {
+            let mut fields = line.split_whitespace();
+            Some((fields.next()?, fields.next()?, fields.next()?))
+        })
+        .collect()
+}
+
+/// Digests of the `repository@sha256:...` entries printed by `inspect_command`
+fn pulled_digests(repo_digests: &str) -> Vec<&str> {
+    repo_digests
+        .split_whitespace()
+        .filter_map(|entry| entry.split_once('@').map(|(_, digest)| digest))
+        .collect()
+}
+
+/// Quote a value for the remote shell
+fn quote(value: &str) -> String {
+    format!("'{}'", value.replace('\'', r"'\''"))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::shared::secrets::Password;
+
+    #[test]
+    fn it_should_pass_the_registry_password_through_stdin() {
+        let registry = RegistryCredentials::new(
+            "registry.corp.internal:5000",
+            "deployer",
+            Password::new("s3cret"),
+        )
+        .unwrap();
+
+        let command = login_command(&registry);
+
+        assert_eq!(
+            command,
+            "sudo -n docker login 'registry.corp.internal:5000' --username 'deployer' --password-stdin"
+        );
+        assert!(!command.contains("s3cret"));
+    }
+
+    #[test]
+    fn it_should_pull_the_images_of_the_backup_profile_too() {
+        assert_eq!(
+            pull_command(),
+            "cd /opt/torrust && sudo -n docker compose --profile backup pull --quiet"
+        );
+    }
+
+    #[test]
+    fn it_should_inspect_the_repository_digests_of_the_image() {
+        let image = ImageReference::new("registry.corp.internal/mysql:8.4").unwrap();
+
+        assert_eq!(
+            inspect_command(image.as_str()),
+            "sudo -n docker image inspect --format '{{join .RepoDigests \" \"}}' 'registry.corp.internal/mysql:8.4' 2>/dev/null || true"
+        );
+    }
+
+    #[test]
+    fn it_should_parse_the_service_image_and_image_id_of_the_running_containers() {
+        let output = "tracker torrust/tracker:develop sha256:aaa\nmysql mysql:8.4 sha256:bbb\n\n";
+
+        assert_eq!(
+            parse_running(output),
+            [
+                ("tracker", "torrust/tracker:develop", "sha256:aaa"),
+                ("mysql", "mysql:8.4", "sha256:bbb"),
+            ]
+        );
+    }
+
+    #[test]
+    fn it_should_extract_the_digests_of_the_repository_digests() {
+        let digest = format!("sha256:{}", "a".repeat(64));
+        let output = format!("registry.corp.internal/mysql@{digest} mysql@sha256:bad\n");
+
+        assert_eq!(pulled_digests(&output), [digest.as_str(), "sha256:bad"]);
+        assert!(pulled_digests("").is_empty());
+    }
+}
@@ -0,0 +1,91 @@
+//! Remote disk space probe
+//!
+//! This module provides the `RemoteDiskSpace` action which measures the free
+//! space of the partition holding a directory of the instance, with the same
+//! `df -Pk` output parsing as the local host probe.
+//!
+//! ## Usage Context
+//!
+//! Used by the release command handler before pulling the container images,
+//! which land under the Docker data directory.
+
+use thiserror::Error;
+use tracing::{info, instrument};
+
+use crate::adapters::ssh::{SshClient, SshConfig};
+use crate::infrastructure::host::DiskSpace;
+use crate::shared::command::CommandError;
+
+/// Directory holding the Docker images, containers and volumes of the instance
+pub const DOCKER_DATA_DIR: &str = "/var/lib/docker";
+
+/// Errors that can occur while measuring the free space of the instance
+#[derive(Debug, Error)]
+pub enum RemoteDiskSpaceError {
+    /// `df` could not be run on the instance
+    #[error("Failed to measure the free space of '{path}' on the instance: {source}")]
+    CommandFailed {
+        path: String,
+        #[source]
+        source: Box<CommandError>,
+    },
+
+    /// `df` printed something that could not be parsed
+    #[error("Unexpected output of 'df -Pk {path}' on the instance: {output}")]
+    UnexpectedOutput { path: String, output: String },
+}
+
+/// Action that measures the free space of a partition of the instance
+pub struct RemoteDiskSpace {
+    ssh_client: SshClient,
+}
+
+impl RemoteDiskSpace {
+    /// Create a new `RemoteDiskSpace`
+    ///
+    /// # Arguments
+    /// * `ssh_config` - SSH connection configuration containing credentials and host IP
+    #[must_use]
+    pub fn new(ssh_config: SshConfig) -> Self {
+        Self {
+            ssh_client: SshClient::new(ssh_config),
+        }
+    }
+
+    /// Free space and mount point of the partition holding `path`
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if `df` fails on the instance or its output cannot
+    /// be parsed.
+    #[instrument(
+        name = "remote_action.disk_space",
+        skip_all,
+        fields(action_type = "measure", path = %path)
+    )]
+    pub fn measure(&self, path: &str) -> Result<DiskSpace, RemoteDiskSpaceError> {
+        let output = self
+            .ssh_client
+            .execute(&format!("df -Pk {path}"))
+            .map_err(|source| RemoteDiskSpaceError::CommandFailed {
+                path: path.to_string(),
+                source: Box::new(source),
+            })?;
+
+        let space = DiskSpace::parse_df_output(&output).ok_or_else(|| {
+            RemoteDiskSpaceError::UnexpectedOutput {
+                path: path.to_string(),
+                output,
+            }
+        })?;
+
+        info!(
+            action = "disk_space",
+            mount_point = %space.mount_point,
+            available = space.available,
+            "Measured the free space of the instance"
+        );
+
+        Ok(space)
+    }
+}
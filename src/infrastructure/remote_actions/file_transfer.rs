@@ -0,0 +1,896 @@
+//! File transfer remote actions
+//!
+//! This module provides the `RemoteFileTransfer` which uploads files to and
+//! downloads files from the remote host over the SSH adapter, without `scp`
+//! or `sftp`: file contents are streamed in chunks through the stdin and
+//! stdout of remote `dd` commands.
+//!
+//! ## Key Features
+//!
+//! - SHA-256 checksum verification once the whole file is transferred
+//! - Resumable transfers: data is written to a `.part` file next to the
+//!   destination, and a later transfer continues from its size
+//! - Verified resumption of downloads: a progress manifest next to the
+//!   `.part` file records the checksum of every chunk, and a later download
+//!   continues after the last chunk that still matches
+//! - Progress reported after each chunk with the bytes transferred so far
+//! - Failures report the remote path, the direction and the bytes completed
+//!
+//! The destination is only replaced once the checksum matches, so an
+//! interrupted or corrupted transfer never leaves a partial file in place.
+//! A `.part` file that does not match is removed, and the next transfer
+//! starts over.
+//!
+//! ## Usage Context
+//!
+//! Used by release steps to push rendered configuration files (e.g.
+//! `tracker.toml`) to the instance, and by the drift command to fetch the
+//! deployed copies back.
+
+use std::fmt;
+use std::fs::{File, OpenOptions};
+use std::io::{BufRead, BufReader, Read, Seek, SeekFrom, Write};
+use std::path::{Path, PathBuf};
+
+use base64::engine::general_purpose::STANDARD;
+use base64::Engine as _;
+use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+use thiserror::Error;
+use tracing::{info, instrument};
+
+use crate::adapters::ssh::{SshClient, SshConfig};
+use crate::shared::command::CommandError;
+
+/// Default number of bytes sent or received per SSH round trip
+pub const DEFAULT_CHUNK_SIZE: usize = 1024 * 1024;
+
+/// Suffix of the file a transfer writes to before it is verified
+const PARTIAL_SUFFIX: &str = ".part";
+
+/// Suffix of the progress manifest kept next to the `.part` file of a download
+const PROGRESS_SUFFIX: &str = ".progress";
+
+/// Direction of a file transfer
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TransferDirection {
+    /// From the deployer host to the instance
+    Upload,
+
+    /// From the instance to the deployer host
+    Download,
+}
+
+impl fmt::Display for TransferDirection {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Upload => write!(f, "upload"),
+            Self::Download => write!(f, "download"),
+        }
+    }
+}
+
+/// Progress of a transfer, reported after each chunk
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TransferProgress {
+    /// Direction of the transfer
+    pub direction: TransferDirection,
+    /// Path of the file on the instance
+    pub remote_path: String,
+    /// Bytes of the file transferred so far, including resumed bytes
+    pub bytes_transferred: u64,
+    /// Size of the file
+    pub total_bytes: u64,
+}
+
+/// Summary of a completed transfer
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TransferReport {
+    /// Size of the file
+    pub total_bytes: u64,
+    /// Bytes already present from an earlier, interrupted transfer
+    pub resumed_from: u64,
+    /// SHA-256 checksum of the file, in lowercase hex
+    pub sha256: String,
+}
+
+/// Errors that can occur while transferring a file
+#[derive(Debug, Error)]
+pub enum RemoteTransferError {
+    /// The local file could not be read or written
+    #[error("Failed to {direction} '{remote_path}' after {bytes_completed} bytes: local file '{local_path}': {source}")]
+    LocalFile {
+        direction: TransferDirection,
+        remote_path: String,
+        bytes_completed: u64,
+        local_path: PathBuf,
+        #[source]
+        source: std::io::Error,
+    },
+
+    /// A remote command of the transfer failed
+    #[error("Failed to {direction} '{remote_path}' after {bytes_completed} bytes: {source}")]
+    RemoteCommand {
+        direction: TransferDirection,
+        remote_path: String,
+        bytes_completed: u64,
+        #[source]
+        source: Box<CommandError>,
+    },
+
+    /// A remote command printed something the transfer could not interpret
+    #[error("Failed to {direction} '{remote_path}' after {bytes_completed} bytes: unexpected remote output '{output}'")]
+    UnexpectedOutput {
+        direction: TransferDirection,
+        remote_path: String,
+        bytes_completed: u64,
+        output: String,
+    },
+
+    /// The transferred file does not have the checksum of the source
+    #[error("Checksum mismatch after {direction} of '{remote_path}' ({bytes_completed} bytes): expected {expected}, got {actual}")]
+    ChecksumMismatch {
+        direction: TransferDirection,
+        remote_path: String,
+        bytes_completed: u64,
+        expected: String,
+        actual: String,
+    },
+}
+
+impl RemoteTransferError {
+    /// Direction of the failed transfer
+    #[must_use]
+    pub fn direction(&self) -> TransferDirection {
+        match self {
+            Self::LocalFile { direction, .. }
+            | Self::RemoteCommand { direction, .. }
+            | Self::UnexpectedOutput { direction, .. }
+            | Self::ChecksumMismatch { direction, .. } => *direction,
+        }
+    }
+
+    /// Path of the file on the instance
+    #[must_use]
+    pub fn remote_path(&self) -> &str {
+        match self {
+            Self::LocalFile { remote_path, .. }
+            | Self::RemoteCommand { remote_path, .. }
+            | Self::UnexpectedOutput { remote_path, .. }
+            | Self::ChecksumMismatch { remote_path, .. } => remote_path,
+        }
+    }
+
+    /// Bytes of the file transferred before the failure
+    ///
+    /// These bytes are kept in the `.part` file, and a later transfer
+    /// resumes from there (except after a checksum mismatch).
+    #[must_use]
+    pub fn bytes_completed(&self) -> u64 {
+        match self {
+            Self::LocalFile {
+                bytes_completed, ..
+            }
+            | Self::RemoteCommand {
+                bytes_completed, ..
+            }
+            | Self::UnexpectedOutput {
+                bytes_completed, ..
+            }
+            | Self::ChecksumMismatch {
+                bytes_completed, ..
+            } => *bytes_completed,
+        }
+    }
+
+    /// Provides troubleshooting guidance for this error
+    #[must_use]
+    pub fn help(&self) -> &'static str {
+        match self {
+            Self::LocalFile { .. } => {
+                "File Transfer Failed - Local File:
+
+1. Check that the local file exists and is readable
+2. For downloads, check that the destination directory is writable
+3. Verify available disk space: df -h"
+            }
+            Self::RemoteCommand { .. } | Self::UnexpectedOutput { .. } => {
+                "File Transfer Failed - Remote Host:
+
+1. Check SSH connectivity to the instance:
+   ssh -i <ssh-key> <user>@<host>
+2. Check that the SSH user can write to the destination directory
+3. Verify available disk space on the instance: df -h
+
+Transferred bytes are kept in the .part file: running the command again
+resumes the transfer."
+            }
+            Self::ChecksumMismatch { .. } => {
+                "File Transfer Failed - Checksum Mismatch:
+
+The transferred file differs from the source. The partial file was removed,
+so running the command again transfers the whole file.
+
+If the mismatch persists, check that the source file is not being modified
+during the transfer and that the disks of both hosts are healthy."
+            }
+        }
+    }
+}
+
+impl crate::shared::Traceable for RemoteTransferError {
+    fn trace_format(&self) -> String {
+        format!("RemoteTransferError: {self}")
+    }
+
+    fn trace_source(&self) -> Option<&dyn crate::shared::Traceable> {
+        None
+    }
+
+    fn error_kind(&self) -> crate::shared::ErrorKind {
+        match self {
+            Self::LocalFile { .. } => crate::shared::ErrorKind::FileSystem,
+            Self::RemoteCommand { source, .. } => source.error_kind(),
+            Self::UnexpectedOutput { .. } => crate::shared::ErrorKind::CommandExecution,
+            // Data corrupted in transit (or modified during the transfer)
+            Self::ChecksumMismatch { .. } => crate::shared::ErrorKind::NetworkConnectivity,
+        }
+    }
+}
+
+/// Action that transfers files between the deployer host and the instance
+pub struct RemoteFileTransfer {
+    ssh_client: SshClient,
+    chunk_size: usize,
+}
+
+impl RemoteFileTransfer {
+    /// Create a new `RemoteFileTransfer`
+    ///
+    /// # Arguments
+    /// * `ssh_config` - SSH connection configuration containing credentials and host IP
+    #[must_use]
+    pub fn new(ssh_config: SshConfig) -> Self {
+        Self {
+            ssh_client: SshClient::new(ssh_config),
+            chunk_size: DEFAULT_CHUNK_SIZE,
+        }
+    }
+
+    /// Transfer `chunk_size` bytes per SSH round trip (at least one)
+    #[must_use]
+    pub fn with_chunk_size(mut self, chunk_size: usize) -> Self {
+        self.chunk_size = chunk_size.max(1);
+        self
+    }
+
+    /// Upload `local_path` to `remote_path` on the instance
+    ///
+    /// Missing remote directories are created. The remote file is replaced
+    /// once the whole file is transferred and its checksum matches; it keeps
+    /// the permissions of a new file created by the SSH user.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the local file cannot be read, a remote command
+    /// fails, or the uploaded file does not have the checksum of the local one.
+    #[instrument(
+        name = "remote_action.upload_file",
+        skip_all,
+        fields(local_path = %local_path.display(), remote_path = %remote_path)
+    )]
+    pub fn upload_file(
+        &self,
+        local_path: &Path,
+        remote_path: &str,
+        on_progress: &dyn Fn(&TransferProgress),
+    ) -> Result<TransferReport, RemoteTransferError> {
+        let mut transfer = Transfer::new(TransferDirection::Upload, remote_path);
+        let partial = format!("{remote_path}{PARTIAL_SUFFIX}");
+
+        let mut file = File::open(local_path).map_err(|e| transfer.local_error(local_path, e))?;
+        let total_bytes = file
+            .metadata()
+            .map_err(|e| transfer.local_error(local_path, e))?
+            .len();
+        let expected = sha256_of(&mut file).map_err(|e| transfer.local_error(local_path, e))?;
+
+        let parent = Path::new(remote_path)
+            .parent()
+            .map_or_else(|| ".".to_string(), |p| p.to_string_lossy().to_string());
+        let output = self.run(
+            &transfer,
+            &format!(
+                "mkdir -p {} && (stat -c %s {} 2>/dev/null || echo 0)",
+                quote(&parent),
+                quote(&partial)
+            ),
+            None,
+        )?;
+        let resumed_from = parse_size(&transfer, &output)?;
+        // A longer partial file cannot be a prefix of this one
+        transfer.completed = if resumed_from > total_bytes {
+            0
+        } else {
+            resumed_from
+        };
+        let resumed_from = transfer.completed;
+
+        file.seek(SeekFrom::Start(transfer.completed))
+            .map_err(|e| transfer.local_error(local_path, e))?;
+        let mut buffer = vec![0; self.chunk_size];
+
+        while transfer.completed < total_bytes {
+            let read = file
+                .read(&mut buffer)
+                .map_err(|e| transfer.local_error(local_path, e))?;
+            if read == 0 {
+                break;
+            }
+
+            // Writing at an explicit offset keeps a retried chunk idempotent
+            self.run(
+                &transfer,
+                &format!(
+                    "dd of={} bs=64K seek={} oflag=seek_bytes conv=notrunc status=none",
+                    quote(&partial),
+                    transfer.completed
+                ),
+                Some(&buffer[..read]),
+            )?;
+
+            transfer.completed += read as u64;
+            on_progress(&transfer.progress(total_bytes));
+        }
+
+        let output = self.run(
+            &transfer,
+            &format!(
+                "truncate -s {total_bytes} {partial} && sha256sum {partial}",
+                partial = quote(&partial)
+            ),
+            None,
+        )?;
+        let actual = parse_checksum(&transfer, &output)?;
+
+        if actual != expected {
+            // Best effort: a partial file that does not match must not be resumed
+            self.ssh_client
+                .execute(&format!("rm -f {}", quote(&partial)))
+                .ok();
+            return Err(transfer.checksum_mismatch(expected, actual));
+        }
+
+        self.run(
+            &transfer,
+            &format!("mv -f {} {}", quote(&partial), quote(remote_path)),
+            None,
+        )?;
+
+        info!(
+            action = "upload_file",
+            remote_path = %remote_path,
+            total_bytes,
+            resumed_from,
+            "File uploaded and verified"
+        );
+
+        Ok(TransferReport {
+            total_bytes,
+            resumed_from,
+            sha256: expected,
+        })
+    }
+
+    /// Whether a regular file exists at `remote_path` on the instance
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the remote command fails.
+    pub fn exists(&self, remote_path: &str) -> Result<bool, RemoteTransferError> {
+        let transfer = Transfer::new(TransferDirection::Download, remote_path);
+
+        let output = self.run(
+            &transfer,
+            &format!(
+                "test -f {} && echo present || echo missing",
+                quote(remote_path)
+            ),
+            None,
+        )?;
+
+        parse_presence(&transfer, &output)
+    }
+
+    /// Download `remote_path` from the instance to `local_path`
+    ///
+    /// Missing local directories are created. The local file is replaced
+    /// once the whole file is transferred and its checksum matches.
+    ///
+    /// An interrupted download resumes after the last chunk of the `.part`
+    /// file whose checksum matches its progress manifest, provided the remote
+    /// file did not change in between; otherwise it starts over.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the local file cannot be written, a remote command
+    /// fails, or the downloaded file does not have the checksum of the remote one.
+    #[instrument(
+        name = "remote_action.download_file",
+        skip_all,
+        fields(local_path = %local_path.display(), remote_path = %remote_path)
+    )]
+    pub fn download_file(
+        &self,
+        remote_path: &str,
+        local_path: &Path,
+        on_progress: &dyn Fn(&TransferProgress),
+    ) -> Result<TransferReport, RemoteTransferError> {
+        let mut transfer = Transfer::new(TransferDirection::Download, remote_path);
+        let partial = PathBuf::from(format!("{}{PARTIAL_SUFFIX}", local_path.display()));
+        let manifest = PathBuf::from(format!("{}{PROGRESS_SUFFIX}", partial.display()));
+
+        let output = self.run(
+            &transfer,
+            &format!(
+                "stat -c %s {remote} && sha256sum {remote}",
+                remote = quote(remote_path)
+            ),
+            None,
+        )?;
+        let mut lines = output.lines();
+        let total_bytes = parse_size(&transfer, lines.next().unwrap_or_default())?;
+        let expected = parse_checksum(&transfer, lines.next().unwrap_or_default())?;
+
+        if let Some(parent) = local_path.parent() {
+            std::fs::create_dir_all(parent).map_err(|e| transfer.local_error(parent, e))?;
+        }
+        let mut file = OpenOptions::new()
+            .create(true)
+            .truncate(false)
+            .read(true)
+            .write(true)
+            .open(&partial)
+            .map_err(|e| transfer.local_error(&partial, e))?;
+        let header = ProgressHeader {
+            remote_path: remote_path.to_string(),
+            total_bytes,
+            sha256: expected.clone(),
+        };
+        let (mut progress, verified) = DownloadProgress::resume(&manifest, header, &mut file)
+            .map_err(|e| transfer.local_error(&manifest, e))?;
+        transfer.completed = verified;
+        let resumed_from = transfer.completed;
+
+        while transfer.completed < total_bytes {
+            let output = self.run(
+                &transfer,
+                &format!(
+                    "dd if={} bs=64K skip={} count={} iflag=skip_bytes,count_bytes status=none | base64 -w0",
+                    quote(remote_path),
+                    transfer.completed,
+                    self.chunk_size
+                ),
+                None,
+            )?;
+            let chunk = STANDARD
+                .decode(output.trim())
+                .map_err(|_| transfer.unexpected_output(&output))?;
+            if chunk.is_empty() {
+                // The remote file shrank during the transfer
+                return Err(transfer.unexpected_output("no data"));
+            }
+
+            file.seek(SeekFrom::Start(transfer.completed))
+                .and_then(|_| file.write_all(&chunk))
+                .map_err(|e| transfer.local_error(&partial, e))?;
+            // Recorded once the data is written, so the manifest never vouches for missing bytes
+            progress
+                .record(transfer.completed, &chunk)
+                .map_err(|e| transfer.local_error(&manifest, e))?;
+
+            transfer.completed += chunk.len() as u64;
+            on_progress(&transfer.progress(total_bytes));
+        }
+
+        file.set_len(total_bytes)
+            .and_then(|()| file.seek(SeekFrom::Start(0)))
+            .map_err(|e| transfer.local_error(&partial, e))?;
+        let actual = sha256_of(&mut file).map_err(|e| transfer.local_error(&partial, e))?;
+        drop(file);
+
+        if actual != expected {
+            // A partial file that does not match must not be resumed
+            std::fs::remove_file(&partial).ok();
+            progress.remove();
+            return Err(transfer.checksum_mismatch(expected, actual));
+        }
+
+        std::fs::rename(&partial, local_path).map_err(|e| transfer.local_error(local_path, e))?;
+        progress.remove();
+
+        info!(
+            action = "download_file",
+            remote_path = %remote_path,
+            total_bytes,
+            resumed_from,
+            "File downloaded and verified"
+        );
+
+        Ok(TransferReport {
+            total_bytes,
+            resumed_from,
+            sha256: expected,
+        })
+    }
+
+    /// Run a remote command of the transfer, mapping its failure
+    fn run(
+        &self,
+        transfer: &Transfer,
+        remote_command: &str,
+        input: Option<&[u8]>,
+    ) -> Result<String, RemoteTransferError> {
+        let result = match input {
+            Some(input) => self.ssh_client.execute_with_input(remote_command, input),
+            None => self.ssh_client.execute(remote_command),
+        };
+
+        result.map_err(|source| RemoteTransferError::RemoteCommand {
+            direction: transfer.direction,
+            remote_path: transfer.remote_path.clone(),
+            bytes_completed: transfer.completed,
+            source: Box::new(source),
+        })
+    }
+}
+
+/// State of a transfer in progress, used to build progress events and errors
+struct Transfer {
+    direction: TransferDirection,
+    remote_path: String,
+    completed: u64,
+}
+
+impl Transfer {
+    fn new(direction: TransferDirection, remote_path: &str) -> Self {
+        Self {
+            direction,
+            remote_path: remote_path.to_string(),
+            completed: 0,
+        }
+    }
+
+    fn progress(&self, total_bytes: u64) -> TransferProgress {
+        TransferProgress {
+            direction: self.direction,
+            remote_path: self.remote_path.clone(),
+            bytes_transferred: self.completed,
+            total_bytes,
+        }
+    }
+
+    fn local_error(&self, local_path: &Path, source: std::io::Error) -> RemoteTransferError {
+        RemoteTransferError::LocalFile {
+            direction: self.direction,
+            remote_path: self.remote_path.clone(),
+            bytes_completed: self.completed,
+            local_path: local_path.to_path_buf(),
+            source,
+        }
+    }
+
+    fn unexpected_output(&self, output: &str) -> RemoteTransferError {
+        RemoteTransferError::UnexpectedOutput {
+            direction: self.direction,
+            remote_path: self.remote_path.clone(),
+            bytes_completed: self.completed,
+            output: output.trim().chars().take(200).collect(),
+        }
+    }
+
+    fn checksum_mismatch(&self, expected: String, actual: String) -> RemoteTransferError {
+        RemoteTransferError::ChecksumMismatch {
+            direction: self.direction,
+            remote_path: self.remote_path.clone(),
+            bytes_completed: self.completed,
+            expected,
+            actual,
+        }
+    }
+}
+
+/// First line of a download progress manifest: the remote file its chunks belong to
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+struct ProgressHeader {
+    remote_path: String,
+    total_bytes: u64,
+    sha256: String,
+}
+
+/// A chunk written to the `.part` file of a download, one line of the manifest
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+struct ProgressChunk {
+    offset: u64,
+    length: u64,
+    sha256: String,
+}
+
+/// Progress manifest of a download, in JSON Lines
+///
+/// A header line identifies the remote file by path, size and checksum,
+/// followed by one line per chunk written to the `.part` file. Lines are
+/// only appended, so recording a chunk does not rewrite the manifest.
+struct DownloadProgress {
+    path: PathBuf,
+    file: File,
+}
+
+impl DownloadProgress {
+    /// Open the manifest at `path` for the download described by `header`
+    ///
+    /// Returns the manifest and the number of leading bytes of `partial`
+    /// it vouches for: the chunks recorded for the same remote file whose
+    /// data still has the recorded checksum, up to the first gap or
+    /// mismatch. The manifest is rewritten with those chunks only.
+    fn resume(
+        path: &Path,
+        header: ProgressHeader,
+        partial: &mut File,
+    ) -> std::io::Result<(Self, u64)> {
+        let mut verified_chunks = Vec::new();
+        let mut verified = 0;
+
+        match File::open(path) {
+            Ok(existing) => {
+                let mut lines = BufReader::new(existing).lines();
+                let same_file = lines
+                    .next()
+                    .transpose()?
+                    .and_then(|line| serde_json::from_str::<ProgressHeader>(&line).ok())
+                    .is_some_and(|recorded| recorded == header);
+
+                if same_file {
+                    for line in lines {
+                        // A line cut short by an interruption ends the verified chunks
+                        let Some(chunk) = serde_json::from_str::<ProgressChunk>(&line?).ok() else {
+                            break;
+                        };
+                        if chunk.offset != verified
+                            || chunk.offset + chunk.length > header.total_bytes
+                            || !chunk_matches(partial, &chunk)?
+                        {
+                            break;
+                        }
+                        verified += chunk.length;
+                        verified_chunks.push(chunk);
+                    }
+                }
+            }
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => {}
+            Err(e) => return Err(e),
+        }
+
+        let mut content = to_json_line(&header)?;
+        for chunk in &verified_chunks {
+            content.push_str(&to_json_line(chunk)?);
+        }
+        std::fs::write(path, content)?;
+
+        let file = OpenOptions::new().append(true).open(path)?;
+
+        Ok((
+            Self {
+                path: path.to_path_buf(),
+                file,
+            },
+            verified,
+        ))
+    }
+
+    /// Record a chunk written to the `.part` file at `offset`
+    fn record(&mut self, offset: u64, data: &[u8]) -> std::io::Result<()> {
+        let chunk = ProgressChunk {
+            offset,
+            length: data.len() as u64,
+            sha256: sha256_of(&mut &data[..])?,
+        };
+        self.file.write_all(to_json_line(&chunk)?.as_bytes())
+    }
+
+    /// Remove the manifest once the download no longer needs it (best effort)
+    fn remove(self) {
+        drop(self.file);
+        std::fs::remove_file(&self.path).ok();
+    }
+}
+
+/// Whether the data of `partial` at the position of `chunk` has its checksum
+fn chunk_matches(partial: &mut File, chunk: &ProgressChunk) -> std::io::Result<bool> {
+    partial.seek(SeekFrom::Start(chunk.offset))?;
+    let mut data = Read::take(&mut *partial, chunk.length);
+    let mut hasher = Sha256::new();
+    let read = std::io::copy(&mut data, &mut hasher)?;
+
+    Ok(read == chunk.length && format!("{:x}", hasher.finalize()) == chunk.sha256)
+}
+
+/// Serialize a manifest line, terminated by a newline
+fn to_json_line(value: &impl Serialize) -> std::io::Result<String> {
+    Ok(format!("{}\n", serde_json::to_string(value)?))
+}
+
+/// Quote a path for the remote shell
+fn quote(path: &str) -> String {
+    format!("'{}'", path.replace('\'', r"'\''"))
+}
+
+/// Parse a file size printed by `stat -c %s`
+fn parse_size(transfer: &Transfer, output: &str) -> Result<u64, RemoteTransferError> {
+    output
+        .trim()
+        .parse()
+        .map_err(|_| transfer.unexpected_output(output))
+}
+
+/// Parse the `present` or `missing` printed by the existence check
+fn parse_presence(transfer: &Transfer, output: &str) -> Result<bool, RemoteTransferError> {
+    match output.trim() {
+        "present" => Ok(true),
+        "missing" => Ok(false),
+        _ => Err(transfer.unexpected_output(output)),
+    }
+}
+
+/// Parse the checksum printed by `sha256sum` (`<hex>  <path>`)
+fn parse_checksum(transfer: &Transfer, output: &str) -> Result<String, RemoteTransferError> {
+    output
+        .split_whitespace()
+        .next()
+        .filter(|hex| hex.len() == 64 && hex.chars().all(|c| c.is_ascii_hexdigit()))
+        .map(str::to_ascii_lowercase)
+        .ok_or_else(|| transfer.unexpected_output(output))
+}
+
+/// SHA-256 checksum of the rest of `reader`, in lowercase hex
+fn sha256_of(reader: &mut impl Read) -> std::io::Result<String> {
+    let mut hasher = Sha256::new();
+    std::io::copy(reader, &mut hasher)?;
+    Ok(format!("{:x}", hasher.finalize()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn it_should_quote_paths_for_the_remote_shell() {
+        assert_eq!(
+            quote("/opt/torrust/tracker.toml"),
+            "'/opt/torrust/tracker.toml'"
+        );
+        assert_eq!(quote("/tmp/it's"), r"'/tmp/it'\''s'");
+    }
+
+    #[test]
+    fn it_should_parse_the_checksum_printed_by_sha256sum() {
+        let transfer = Transfer::new(TransferDirection::Upload, "/tmp/file");
+        let hex = "e3b0c44298fc1c149afbf4c8996fb92427ae41e4649b934ca495991b7852b855";
+
+        let checksum = parse_checksum(&transfer, &format!("{hex}  /tmp/file.part\n")).unwrap();
+
+        assert_eq!(checksum, hex);
+        assert!(matches!(
+            parse_checksum(&transfer, "sha256sum: /tmp/file.part: No such file"),
+            Err(RemoteTransferError::UnexpectedOutput { .. })
+        ));
+    }
+
+    #[test]
+    fn it_should_parse_the_output_of_the_existence_check() {
+        let transfer = Transfer::new(TransferDirection::Download, "/tmp/file");
+
+        assert!(parse_presence(&transfer, "present\n").unwrap());
+        assert!(!parse_presence(&transfer, "missing\n").unwrap());
+        assert!(parse_presence(&transfer, "").is_err());
+    }
+
+    #[test]
+    fn it_should_compute_the_sha256_checksum_of_a_file() {
+        let checksum = sha256_of(&mut "abc".as_bytes()).unwrap();
+
+        assert_eq!(
+            checksum,
+            "ba7816bf8f01cfea414140de5dae2223b00361a396177a9cb410ff61f20015ad"
+        );
+    }
+
+    #[test]
+    fn it_should_report_the_remote_path_direction_and_bytes_completed_of_a_failure() {
+        let mut transfer = Transfer::new(TransferDirection::Download, "/var/log/tracker.log");
+        transfer.completed = 4096;
+
+        let error = transfer.unexpected_output("garbage");
+
+        assert_eq!(error.direction(), TransferDirection::Download);
+        assert_eq!(error.remote_path(), "/var/log/tracker.log");
+        assert_eq!(error.bytes_completed(), 4096);
+        assert!(error
+            .to_string()
+            .starts_with("Failed to download '/var/log/tracker.log' after 4096 bytes"));
+    }
+
+    fn header(total_bytes: u64) -> ProgressHeader {
+        ProgressHeader {
+            remote_path: "/tmp/backup.sql".to_string(),
+            total_bytes,
+            sha256: "a".repeat(64),
+        }
+    }
+
+    /// Write `data` to a `.part` file in chunks of 4 bytes, recording them in its manifest
+    fn interrupted_download(dir: &Path, data: &[u8]) -> (PathBuf, File) {
+        let manifest = dir.join("backup.sql.part.progress");
+        let mut partial = tempfile::tempfile_in(dir).unwrap();
+        let (mut progress, _) =
+            DownloadProgress::resume(&manifest, header(12), &mut partial).unwrap();
+        for (index, chunk) in data.chunks(4).enumerate() {
+            partial.write_all(chunk).unwrap();
+            progress.record(index as u64 * 4, chunk).unwrap();
+        }
+        (manifest, partial)
+    }
+
+    #[test]
+    fn it_should_resume_a_download_after_the_recorded_chunks() {
+        let dir = tempfile::tempdir().unwrap();
+        let (manifest, mut partial) = interrupted_download(dir.path(), b"abcdefgh");
+
+        let (_, verified) = DownloadProgress::resume(&manifest, header(12), &mut partial).unwrap();
+
+        assert_eq!(verified, 8);
+    }
+
+    #[test]
+    fn it_should_resume_a_download_from_the_last_chunk_that_still_matches() {
+        let dir = tempfile::tempdir().unwrap();
+        let (manifest, mut partial) = interrupted_download(dir.path(), b"abcdefgh");
+        partial.seek(SeekFrom::Start(5)).unwrap();
+        partial.write_all(b"X").unwrap();
+
+        let (_, verified) = DownloadProgress::resume(&manifest, header(12), &mut partial).unwrap();
+
+        assert_eq!(verified, 4);
+        assert_eq!(
+            std::fs::read_to_string(&manifest).unwrap().lines().count(),
+            2
+        );
+    }
+
+    #[test]
+    fn it_should_restart_a_download_when_the_remote_file_changed() {
+        let dir = tempfile::tempdir().unwrap();
+        let (manifest, mut partial) = interrupted_download(dir.path(), b"abcdefgh");
+        let changed = ProgressHeader {
+            sha256: "b".repeat(64),
+            ..header(12)
+        };
+
+        let (_, verified) = DownloadProgress::resume(&manifest, changed, &mut partial).unwrap();
+
+        assert_eq!(verified, 0);
+    }
+
+    #[test]
+    fn it_should_ignore_a_manifest_line_cut_short_by_an_interruption() {
+        let dir = tempfile::tempdir().unwrap();
+        let (manifest, mut partial) = interrupted_download(dir.path(), b"abcdefgh");
+        let mut content = std::fs::read_to_string(&manifest).unwrap();
+        content.truncate(content.len() - 10);
+        std::fs::write(&manifest, content).unwrap();
+
+        let (_, verified) = DownloadProgress::resume(&manifest, header(12), &mut partial).unwrap();
+
+        assert_eq!(verified, 4);
+    }
+}
@@ -0,0 +1,137 @@
+//! Existing installation detection remote action
+//!
+//! This module provides the `InstallationProbe` which inspects an instance
+//! that was deployed outside this tool, so that an imported environment can
+//! start in the state matching what is already installed.
+//!
+//! ## Key Features
+//!
+//! - One SSH round trip reporting Docker, Docker Compose and running containers
+//! - Falls back to `sudo -n docker ps` when the SSH user is not in the `docker` group
+//! - Detection is read-only: nothing is installed or started
+//!
+//! ## Usage Context
+//!
+//! Used by the import workflow after SSH connectivity has been verified.
+
+use tracing::{info, instrument};
+
+use crate::adapters::ssh::{SshClient, SshConfig};
+use crate::shared::command::CommandError;
+
+/// Image name of the tracker container (without tag)
+const TRACKER_IMAGE: &str = "torrust/tracker";
+
+/// Single SSH command reporting the Docker tooling and the running container images
+const PROBE_COMMAND: &str = "if command -v docker >/dev/null 2>&1; then echo 'docker: installed'; if docker compose version >/dev/null 2>&1; then echo 'compose: installed'; fi; (docker ps --format '{{.Image}}' 2>/dev/null || sudo -n docker ps --format '{{.Image}}' 2>/dev/null) | sed 's/^/container: /'; else echo 'docker: missing'; fi";
+
+/// Software found on an existing instance
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct ExistingInstallation {
+    /// Docker engine is installed
+    pub docker_installed: bool,
+
+    /// Docker Compose plugin is installed
+    pub compose_installed: bool,
+
+    /// A tracker container is running
+    pub tracker_running: bool,
+}
+
+impl ExistingInstallation {
+    /// Interpret the output of the installation probe
+    #[must_use]
+    pub fn parse(output: &str) -> Self {
+        let lines: Vec<&str> = output.lines().map(str::trim).collect();
+
+        Self {
+            docker_installed: lines.contains(&"docker: installed"),
+            compose_installed: lines.contains(&"compose: installed"),
+            tracker_running: lines.iter().any(|line| {
+                line.strip_prefix("container:")
+                    .is_some_and(|image| image.trim().starts_with(TRACKER_IMAGE))
+            }),
+        }
+    }
+
+    /// Whether the instance has everything the `configure` command installs
+    #[must_use]
+    pub fn is_configured(&self) -> bool {
+        self.docker_installed && self.compose_installed
+    }
+}
+
+/// Action that detects an existing Docker and tracker installation
+pub struct InstallationProbe {
+    ssh_client: SshClient,
+}
+
+impl InstallationProbe {
+    /// Create a new `InstallationProbe`
+    ///
+    /// # Arguments
+    /// * `ssh_config` - SSH connection configuration containing credentials and host IP
+    #[must_use]
+    pub fn new(ssh_config: SshConfig) -> Self {
+        Self {
+            ssh_client: SshClient::new(ssh_config),
+        }
+    }
+
+    /// Inspect the instance
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the probe command cannot be run over SSH.
+    #[instrument(
+        name = "remote_action.installation_probe",
+        skip_all,
+        fields(action_type = "detection", component = "installation")
+    )]
+    pub fn probe(&self) -> Result<ExistingInstallation, CommandError> {
+        let output = self.ssh_client.execute(PROBE_COMMAND)?;
+        let installation = ExistingInstallation::parse(&output);
+
+        info!(
+            action = "installation_probe",
+            docker_installed = installation.docker_installed,
+            compose_installed = installation.compose_installed,
+            tracker_running = installation.tracker_running,
+            "Existing installation detected"
+        );
+
+        Ok(installation)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn it_should_detect_a_bare_instance() {
+        let installation = ExistingInstallation::parse("docker: missing\n");
+
+        assert_eq!(installation, ExistingInstallation::default());
+        assert!(!installation.is_configured());
+    }
+
+    #[test]
+    fn it_should_detect_docker_and_compose_without_a_tracker() {
+        let installation = ExistingInstallation::parse(
+            "docker: installed\ncompose: installed\ncontainer: prom/prometheus:v3.0.0\n",
+        );
+
+        assert!(installation.is_configured());
+        assert!(!installation.tracker_running);
+    }
+
+    #[test]
+    fn it_should_detect_a_running_tracker_container() {
+        let installation = ExistingInstallation::parse(
+            "docker: installed\ncompose: installed\ncontainer: torrust/tracker:develop\n",
+        );
+
+        assert!(installation.tracker_running);
+    }
+}
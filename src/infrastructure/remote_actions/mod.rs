@@ -15,6 +15,14 @@
 //!
 //! ## Available Remote Actions
 //!
+//! - `cloud_init_wait` - Cloud-init readiness polling with backoff and timeout
+//! - `container_images` - Registry login, image pull, digest verification and running container images
+//! - `disk_space` - Free space of the partition holding a directory of the instance
+//! - `file_transfer` - Resumable file upload and download with checksum verification
+//! - `installation_probe` - Detection of an existing Docker and tracker installation
+//! - `tracker_database` - Tracker database dump and restore for backups
+//! - `tracker_health_wait` - Tracker Health Check API polling after the services start
+//! - `tracker_services` - Docker Compose services stop and start for maintenance
 //! - `validators::cloud_init` - Cloud-init status checking and validation
 //! - `validators::docker` - Docker installation and service management
 //! - `validators::docker_compose` - Docker Compose installation and validation
@@ -35,8 +43,28 @@ use thiserror::Error;
 
 use crate::shared::command::CommandError;
 
+pub mod cloud_init_wait;
+pub mod container_images;
+pub mod disk_space;
+pub mod file_transfer;
+pub mod installation_probe;
+pub mod tracker_database;
+pub mod tracker_health_wait;
+pub mod tracker_services;
 pub mod validators;
 
+pub use cloud_init_wait::{
+    CloudInitPoll, CloudInitStatus, CloudInitWaitError, CloudInitWaitPolicy, CloudInitWaiter,
+};
+pub use container_images::{ContainerImages, ContainerImagesError, RunningContainer};
+pub use disk_space::{RemoteDiskSpace, RemoteDiskSpaceError, DOCKER_DATA_DIR};
+pub use file_transfer::{
+    RemoteFileTransfer, RemoteTransferError, TransferDirection, TransferProgress, TransferReport,
+};
+pub use installation_probe::{ExistingInstallation, InstallationProbe};
+pub use tracker_database::{TrackerDatabase, TrackerDatabaseError};
+pub use tracker_health_wait::{TrackerHealthResponse, TrackerHealthWaitError, TrackerHealthWaiter};
+pub use tracker_services::{TrackerServices, TrackerServicesError};
 pub use validators::cloud_init::CloudInitValidator;
 pub use validators::docker::DockerValidator;
 pub use validators::docker_compose::DockerComposeValidator;
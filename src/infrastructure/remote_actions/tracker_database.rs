@@ -0,0 +1,323 @@
+//! Tracker database dump and restore remote actions
+//!
+//! This module provides the `TrackerDatabase` action which dumps the tracker
+//! database to a file on the instance, and restores it from one, for the
+//! `backup` and `restore` commands. Moving the file between the deployer and
+//! the instance is left to `RemoteFileTransfer`.
+//!
+//! ## Key Features
+//!
+//! - `SQLite`: the tracker is stopped while its database file is copied, so
+//!   no write lands in the middle of the copy, and started again right after
+//! - `MySQL`: `mysqldump --single-transaction` takes a consistent dump while
+//!   the tracker keeps running
+//! - Restores stop the tracker, replace the database and start the tracker
+//!   again, also when the restore fails
+//! - Every tracker replica is stopped and started again, so no replica keeps
+//!   writing to the database being copied or replaced
+//!
+//! ## Usage Context
+//!
+//! Used by the backup and restore command handlers on `Released` and
+//! `Running` environments.
+
+use thiserror::Error;
+use tracing::{info, instrument};
+
+use crate::adapters::ssh::{SshClient, SshConfig};
+use crate::domain::tracker::DatabaseConfig;
+use crate::shared::command::CommandError;
+
+/// Directory of the Docker Compose stack on the instance
+const DEPLOY_DIR: &str = "/opt/torrust";
+
+/// Directory of the `SQLite` database relative to the deploy directory
+const SQLITE_DIR: &str = "storage/tracker/lib/database";
+
+/// Name of the `MySQL` container in the generated `docker-compose.yml`
+const MYSQL_CONTAINER: &str = "mysql";
+
+/// Errors that can occur while dumping or restoring the tracker database
+#[derive(Debug, Error)]
+pub enum TrackerDatabaseError {
+    /// The dump command failed on the instance
+    #[error("Failed to dump the {driver} tracker database to '{remote_path}': {source}")]
+    DumpFailed {
+        driver: String,
+        remote_path: String,
+        #[source]
+        source: Box<CommandError>,
+    },
+
+    /// The restore command failed on the instance
+    #[error("Failed to restore the {driver} tracker database from '{remote_path}': {source}")]
+    RestoreFailed {
+        driver: String,
+        remote_path: String,
+        #[source]
+        source: Box<CommandError>,
+    },
+}
+
+impl crate::shared::Traceable for TrackerDatabaseError {
+    fn trace_format(&self) -> String {
+        format!("TrackerDatabaseError: {self}")
+    }
+
+    fn trace_source(&self) -> Option<&dyn crate::shared::Traceable> {
+        None
+    }
+
+    fn error_kind(&self) -> crate::shared::ErrorKind {
+        match self {
+            Self::DumpFailed { source, .. } | Self::RestoreFailed { source, .. } => {
+                source.error_kind()
+            }
+        }
+    }
+}
+
+/// Action that dumps and restores the tracker database on the instance
+pub struct TrackerDatabase {
+    ssh_client: SshClient,
+    database: DatabaseConfig,
+    tracker_services: Vec<String>,
+}
+
+impl TrackerDatabase {
+    /// Create a new `TrackerDatabase`
+    ///
+    /// # Arguments
+    /// * `ssh_config` - SSH connection configuration containing credentials and host IP
+    /// * `database` - Database configuration of the tracker
+    /// * `tracker_services` - Compose services of the tracker replicas
+    #[must_use]
+    pub fn new(
+        ssh_config: SshConfig,
+        database: DatabaseConfig,
+        tracker_services: Vec<String>,
+    ) -> Self {
+        Self {
+            ssh_client: SshClient::new(ssh_config),
+            database,
+            tracker_services,
+        }
+    }
+
+    /// Dump the tracker database to `remote_path` on the instance
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the dump command fails. The tracker is started
+    /// again even when the copy of a `SQLite` database fails.
+    #[instrument(
+        name = "remote_action.tracker_database_dump",
+        skip_all,
+        fields(action_type = "backup", driver = %self.database.driver_name(), remote_path = %remote_path)
+    )]
+    pub fn dump(&self, remote_path: &str) -> Result<(), TrackerDatabaseError> {
+        self.ssh_client
+            .execute(&dump_command(
+                &self.database,
+                &self.tracker_services,
+                remote_path,
+            ))
+            .map_err(|source| TrackerDatabaseError::DumpFailed {
+                driver: self.database.driver_name().to_string(),
+                remote_path: remote_path.to_string(),
+                source: Box::new(source),
+            })?;
+
+        info!(
+            action = "tracker_database_dump",
+            remote_path = %remote_path,
+            "Tracker database dumped"
+        );
+
+        Ok(())
+    }
+
+    /// Replace the tracker database with the dump at `remote_path`
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the restore command fails. The tracker is started
+    /// again in any case.
+    #[instrument(
+        name = "remote_action.tracker_database_restore",
+        skip_all,
+        fields(action_type = "restore", driver = %self.database.driver_name(), remote_path = %remote_path)
+    )]
+    pub fn restore(&self, remote_path: &str) -> Result<(), TrackerDatabaseError> {
+        self.ssh_client
+            .execute(&restore_command(
+                &self.database,
+                &self.tracker_services,
+                remote_path,
+            ))
+            .map_err(|source| TrackerDatabaseError::RestoreFailed {
+                driver: self.database.driver_name().to_string(),
+                remote_path: remote_path.to_string(),
+                source: Box::new(source),
+            })?;
+
+        info!(
+            action = "tracker_database_restore",
+            remote_path = %remote_path,
+            "Tracker database restored"
+        );
+
+        Ok(())
+    }
+
+    /// Remove a dump from the instance, ignoring failures
+    ///
+    /// A leftover dump only takes disk space, so it never fails a backup or
+    /// restore that otherwise succeeded.
+    pub fn remove(&self, remote_path: &str) {
+        if let Err(e) = self.ssh_client.execute(&format!("rm -f '{remote_path}'")) {
+            tracing::warn!(
+                action = "tracker_database_remove",
+                remote_path = %remote_path,
+                error = %e,
+                "Failed to remove the database dump from the instance"
+            );
+        }
+    }
+}
+
+/// Run `command` with the tracker services stopped, starting them again
+/// whatever the outcome
+fn with_tracker_stopped(services: &[String], command: &str) -> String {
+    let services = services.join(" ");
+    format!(
+        "cd {DEPLOY_DIR} && docker compose stop {services} >/dev/null 2>&1 && {{ {command}; status=$?; docker compose start {services} >/dev/null 2>&1; exit $status; }}"
+    )
+}
+
+/// Shell command that dumps `database` to `remote_path`
+fn dump_command(database: &DatabaseConfig, services: &[String], remote_path: &str) -> String {
+    let target_dir = remote_parent(remote_path);
+
+    match database {
+        DatabaseConfig::Sqlite(config) => format!(
+            "mkdir -p '{target_dir}' && {}",
+            with_tracker_stopped(
+                services,
+                &format!("cp '{SQLITE_DIR}/{}' '{remote_path}'", config.database_name())
+            )
+        ),
+        DatabaseConfig::Mysql(_) => format!(
+            "mkdir -p '{target_dir}' && docker exec {MYSQL_CONTAINER} sh -c 'MYSQL_PWD=\"$MYSQL_ROOT_PASSWORD\" exec mysqldump --single-transaction --routines -uroot \"$MYSQL_DATABASE\"' > '{remote_path}'"
+        ),
+    }
+}
+
+/// Shell command that replaces `database` with the dump at `remote_path`
+///
+/// The write-ahead log of a `SQLite` database is removed with the database
+/// file, as it belongs to the replaced database.
+fn restore_command(database: &DatabaseConfig, services: &[String], remote_path: &str) -> String {
+    match database {
+        DatabaseConfig::Sqlite(config) => {
+            let database_path = format!("{SQLITE_DIR}/{}", config.database_name());
+            with_tracker_stopped(services, &format!(
+                "rm -f '{database_path}-wal' '{database_path}-shm' && cp '{remote_path}' '{database_path}'"
+            ))
+        }
+        DatabaseConfig::Mysql(_) => with_tracker_stopped(services, &format!(
+            "docker exec -i {MYSQL_CONTAINER} sh -c 'MYSQL_PWD=\"$MYSQL_ROOT_PASSWORD\" exec mysql -uroot \"$MYSQL_DATABASE\"' < '{remote_path}'"
+        )),
+    }
+}
+
+/// Directory of a remote path, `.` for a bare file name
+fn remote_parent(remote_path: &str) -> &str {
+    match remote_path.rsplit_once('/') {
+        Some(("", _)) => "/",
+        Some((parent, _)) => parent,
+        None => ".",
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::tracker::{MysqlConfig, SqliteConfig};
+
+    fn sqlite() -> DatabaseConfig {
+        DatabaseConfig::Sqlite(SqliteConfig::new("tracker.db").unwrap())
+    }
+
+    fn single_tracker() -> Vec<String> {
+        vec!["tracker".to_string()]
+    }
+
+    fn mysql() -> DatabaseConfig {
+        DatabaseConfig::Mysql(
+            MysqlConfig::new(
+                "mysql",
+                3306,
+                "torrust_tracker",
+                "tracker_user",
+                "tracker_password".to_string().into(),
+                "root_password".to_string().into(),
+            )
+            .unwrap(),
+        )
+    }
+
+    #[test]
+    fn it_should_copy_a_sqlite_database_with_the_tracker_stopped() {
+        let command = dump_command(&sqlite(), &single_tracker(), "/tmp/backups/tracker.db");
+
+        assert_eq!(
+            command,
+            "mkdir -p '/tmp/backups' && cd /opt/torrust && docker compose stop tracker >/dev/null 2>&1 && { cp 'storage/tracker/lib/database/tracker.db' '/tmp/backups/tracker.db'; status=$?; docker compose start tracker >/dev/null 2>&1; exit $status; }"
+        );
+    }
+
+    #[test]
+    fn it_should_dump_a_mysql_database_in_a_single_transaction_without_stopping_the_tracker() {
+        let command = dump_command(
+            &mysql(),
+            &single_tracker(),
+            "/tmp/backups/torrust_tracker.sql",
+        );
+
+        assert!(command.contains("mysqldump --single-transaction"));
+        assert!(!command.contains("docker compose stop tracker"));
+        assert!(!command.contains("root_password"));
+    }
+
+    #[test]
+    fn it_should_drop_the_write_ahead_log_when_restoring_a_sqlite_database() {
+        let command = restore_command(&sqlite(), &single_tracker(), "/tmp/backups/tracker.db");
+
+        assert!(command.contains("rm -f 'storage/tracker/lib/database/tracker.db-wal'"));
+        assert!(command.contains("docker compose start tracker"));
+    }
+
+    #[test]
+    fn it_should_start_the_tracker_again_after_restoring_a_mysql_database() {
+        let command = restore_command(
+            &mysql(),
+            &single_tracker(),
+            "/tmp/backups/torrust_tracker.sql",
+        );
+
+        assert!(command.contains("docker exec -i mysql"));
+        assert!(command.ends_with("docker compose start tracker >/dev/null 2>&1; exit $status; }"));
+    }
+
+    #[test]
+    fn it_should_stop_and_start_every_tracker_replica_around_a_restore() {
+        let services = vec!["tracker".to_string(), "tracker-2".to_string()];
+
+        let command = restore_command(&mysql(), &services, "/tmp/backups/torrust_tracker.sql");
+
+        assert!(command.contains("docker compose stop tracker tracker-2 >/dev/null 2>&1 && {"));
+        assert!(command
+            .ends_with("docker compose start tracker tracker-2 >/dev/null 2>&1; exit $status; }"));
+    }
+}
@@ -0,0 +1,519 @@
+//! Tracker health polling remote action
+//!
+//! This module provides the `TrackerHealthWaiter` which waits for the tracker
+//! Health Check API to report healthy after the services have been started.
+//! Starting the containers only proves Docker accepted them: a tracker with a
+//! broken `tracker.toml` exits right away and keeps being restarted.
+//!
+//! ## Key Features
+//!
+//! - The request is sent from inside the tracker container over SSH, so it
+//!   works whether the API is bound to localhost or exposed
+//! - With several tracker replicas, every replica container is checked in
+//!   turn, each within the configured timeout
+//! - One SSH round trip per check, polled at a fixed interval until the
+//!   configured timeout (`HealthCheckWait`)
+//! - The last HTTP status, or the reason no status was received, is kept for
+//!   the failure report
+//! - Polling stops as soon as the cancellation token is cancelled (Ctrl-C)
+//!
+//! ## Usage Context
+//!
+//! Used by the run workflow after the Docker Compose services are started,
+//! so that the environment only becomes `Running` once the tracker is healthy.
+
+use std::fmt;
+use std::net::{IpAddr, Ipv4Addr, Ipv6Addr, SocketAddr};
+use std::time::{Duration, Instant};
+
+use thiserror::Error;
+use tokio_util::sync::CancellationToken;
+use tracing::{info, instrument, warn};
+
+use crate::adapters::ssh::{SshClient, SshConfig};
+use crate::domain::tracker::HealthCheckWait;
+
+/// `PATH` used inside the tracker container
+///
+/// The tracker image is based on a distroless image whose `wget` lives in
+/// `/busybox`, which is not on the default `PATH` of `docker exec`.
+const CONTAINER_PATH: &str =
+    "/usr/local/sbin:/usr/local/bin:/usr/sbin:/usr/bin:/sbin:/bin:/busybox";
+
+/// Longest sleep between two checks of the cancellation token
+const CANCELLATION_CHECK_INTERVAL: Duration = Duration::from_millis(100);
+
+/// Health Check API response observed by one check
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum TrackerHealthResponse {
+    /// The API answered with this HTTP status
+    Status(u16),
+
+    /// No HTTP status was received (container down, connection refused, ...)
+    Unreachable(String),
+}
+
+impl TrackerHealthResponse {
+    /// Interpret the output of the health probe (`wget -S`)
+    ///
+    /// `wget` prints the status line of the response (e.g. `HTTP/1.1 200 OK`),
+    /// including for error statuses. The last status line wins, so redirects
+    /// report the final response.
+    #[must_use]
+    pub fn parse(output: &str) -> Self {
+        let status = output
+            .split_whitespace()
+            .collect::<Vec<_>>()
+            .windows(2)
+            .filter(|pair| pair[0].starts_with("HTTP/"))
+            .filter_map(|pair| pair[1].parse::<u16>().ok())
+            .next_back();
+
+        match status {
+            Some(status) => Self::Status(status),
+            None => {
+                let reason = output
+                    .lines()
+                    .map(str::trim)
+                    .rfind(|line| !line.is_empty())
+                    .unwrap_or("no response");
+                Self::Unreachable(reason.to_string())
+            }
+        }
+    }
+}
+
+impl fmt::Display for TrackerHealthResponse {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Status(status) => write!(f, "HTTP {status}"),
+            Self::Unreachable(reason) => write!(f, "unreachable: {reason}"),
+        }
+    }
+}
+
+/// Errors that can occur while waiting for the tracker to report healthy
+#[derive(Debug, Error)]
+pub enum TrackerHealthWaitError {
+    /// The Health Check API did not answer the expected status in time
+    #[error(
+        "Health check of '{container}' did not return HTTP {expected_status} within {timeout_secs} seconds (last response: {last_response})"
+    )]
+    NeverHealthy {
+        container: String,
+        expected_status: u16,
+        timeout_secs: u64,
+        last_response: TrackerHealthResponse,
+    },
+
+    /// The wait was cancelled (Ctrl-C) before the tracker reported healthy
+    #[error("Health check of '{container}' interrupted by user")]
+    Interrupted { container: String },
+}
+
+impl TrackerHealthWaitError {
+    /// Last response of the Health Check API, if the wait was not interrupted
+    #[must_use]
+    pub fn last_response(&self) -> Option<&TrackerHealthResponse> {
+        match self {
+            Self::NeverHealthy { last_response, .. } => Some(last_response),
+            Self::Interrupted { .. } => None,
+        }
+    }
+
+    /// Whether the wait was cancelled rather than timed out
+    #[must_use]
+    pub fn is_interrupted(&self) -> bool {
+        matches!(self, Self::Interrupted { .. })
+    }
+}
+
+impl crate::shared::Traceable for TrackerHealthWaitError {
+    fn trace_format(&self) -> String {
+        match self {
+            Self::NeverHealthy {
+                container,
+                expected_status,
+                timeout_secs,
+                last_response,
+            } => {
+                format!("TrackerHealthWaitError: No HTTP {expected_status} from '{container}' within {timeout_secs} seconds - last response: {last_response}")
+            }
+            Self::Interrupted { container } => {
+                format!("TrackerHealthWaitError: Health check of '{container}' interrupted by user (Ctrl-C)")
+            }
+        }
+    }
+
+    fn trace_source(&self) -> Option<&dyn crate::shared::Traceable> {
+        None
+    }
+
+    fn error_kind(&self) -> crate::shared::ErrorKind {
+        match self {
+            Self::NeverHealthy { .. } => crate::shared::ErrorKind::Timeout,
+            Self::Interrupted { .. } => crate::shared::ErrorKind::Interrupted,
+        }
+    }
+}
+
+/// Action that waits until the tracker Health Check API reports healthy
+pub struct TrackerHealthWaiter {
+    ssh_client: SshClient,
+    health_check_url: String,
+    wait: HealthCheckWait,
+    containers: Vec<String>,
+    cancellation: CancellationToken,
+}
+
+impl TrackerHealthWaiter {
+    /// Create a new `TrackerHealthWaiter`
+    ///
+    /// # Arguments
+    /// * `ssh_config` - SSH connection configuration containing credentials and host IP
+    /// * `bind_address` - Bind address of the Health Check API in `tracker.toml`
+    /// * `wait` - Interval, timeout and expected status of the checks
+    /// * `containers` - Containers of the tracker replicas
+    #[must_use]
+    pub fn new(
+        ssh_config: SshConfig,
+        bind_address: SocketAddr,
+        wait: HealthCheckWait,
+        containers: Vec<String>,
+    ) -> Self {
+        Self {
+            ssh_client: SshClient::new(ssh_config),
+            health_check_url: Self::url_inside_container(bind_address),
+            wait,
+            containers,
+            cancellation: CancellationToken::new(),
+        }
+    }
+
+    /// Stop polling when the given token is cancelled (e.g. on Ctrl-C)
+    #[must_use]
+    pub fn with_cancellation(mut self, cancellation: CancellationToken) -> Self {
+        self.cancellation = cancellation;
+        self
+    }
+
+    /// Poll the Health Check API of every replica until it answers the
+    /// expected status
+    ///
+    /// # Errors
+    ///
+    /// Returns an error carrying the last response of the first replica that
+    /// does not answer the expected status within the configured timeout, or
+    /// an `Interrupted` error once the cancellation token is cancelled.
+    #[instrument(
+        name = "remote_action.tracker_health_wait",
+        skip_all,
+        fields(
+            action_type = "wait",
+            component = "tracker",
+            timeout_secs = self.wait.timeout().as_secs()
+        )
+    )]
+    pub fn wait(&self) -> Result<(), TrackerHealthWaitError> {
+        poll_every_container_until_healthy(
+            &self.wait,
+            &self.containers,
+            &self.cancellation,
+            |container| self.probe(container),
+        )
+    }
+
+    /// Run one check in `container`, treating SSH failures as "unreachable"
+    fn probe(&self, container: &str) -> TrackerHealthResponse {
+        let command = format!(
+            "docker exec -e PATH={CONTAINER_PATH} {container} wget -S -q -O /dev/null '{}' 2>&1 || true",
+            self.health_check_url
+        );
+
+        match self.ssh_client.execute(&command) {
+            Ok(output) => TrackerHealthResponse::parse(&output),
+            Err(e) => TrackerHealthResponse::Unreachable(format!("instance not reachable: {e}")),
+        }
+    }
+
+    /// URL of the Health Check API as seen from inside the tracker container
+    ///
+    /// An unspecified bind address (`0.0.0.0`, `::`) accepts connections on
+    /// the loopback interface too.
+    fn url_inside_container(bind_address: SocketAddr) -> String {
+        let ip = match bind_address.ip() {
+            IpAddr::V4(ip) if ip.is_unspecified() => IpAddr::V4(Ipv4Addr::LOCALHOST),
+            IpAddr::V6(ip) if ip.is_unspecified() => IpAddr::V6(Ipv6Addr::LOCALHOST),
+            ip => ip,
+        };
+
+        format!(
+            "http://{}/health_check", // DevSkim: ignore DS137138
+            SocketAddr::new(ip, bind_address.port())
+        )
+    }
+}
+
+/// Poll each of `containers` in turn until it returns the expected status
+fn poll_every_container_until_healthy(
+    wait: &HealthCheckWait,
+    containers: &[String],
+    cancellation: &CancellationToken,
+    mut probe: impl FnMut(&str) -> TrackerHealthResponse,
+) -> Result<(), TrackerHealthWaitError> {
+    containers.iter().try_for_each(|container| {
+        poll_until_healthy(wait, container, cancellation, || probe(container))
+    })
+}
+
+/// Call `probe` every `wait.interval()` until it returns the expected status
+///
+/// The probe is always called at least once, and once more right before the
+/// timeout when the interval does not divide it. A cancelled token stops the
+/// polling before the next probe, including one whose SSH call was killed
+/// by the same Ctrl-C.
+fn poll_until_healthy(
+    wait: &HealthCheckWait,
+    container: &str,
+    cancellation: &CancellationToken,
+    mut probe: impl FnMut() -> TrackerHealthResponse,
+) -> Result<(), TrackerHealthWaitError> {
+    let started = Instant::now();
+    let mut attempt: u32 = 0;
+    let interrupted = || TrackerHealthWaitError::Interrupted {
+        container: container.to_string(),
+    };
+
+    loop {
+        if cancellation.is_cancelled() {
+            return Err(interrupted());
+        }
+
+        attempt += 1;
+        let response = probe();
+        let elapsed = started.elapsed();
+
+        if cancellation.is_cancelled() {
+            warn!(
+                action = "tracker_health_wait",
+                container, attempt, "Interrupted while waiting for the tracker to report healthy"
+            );
+            return Err(interrupted());
+        }
+
+        if response == TrackerHealthResponse::Status(wait.expected_status()) {
+            info!(
+                action = "tracker_health_wait",
+                container,
+                attempt,
+                elapsed_secs = elapsed.as_secs(),
+                "Tracker health check passed"
+            );
+            return Ok(());
+        }
+
+        let remaining = wait.timeout().saturating_sub(elapsed);
+        if remaining.is_zero() {
+            warn!(
+                action = "tracker_health_wait",
+                container,
+                attempt,
+                elapsed_secs = elapsed.as_secs(),
+                last_response = %response,
+                "Timed out waiting for the tracker to report healthy"
+            );
+            return Err(TrackerHealthWaitError::NeverHealthy {
+                container: container.to_string(),
+                expected_status: wait.expected_status(),
+                timeout_secs: wait.timeout().as_secs(),
+                last_response: response,
+            });
+        }
+
+        info!(
+            action = "tracker_health_wait",
+            container,
+            attempt,
+            response = %response,
+            "Tracker not healthy yet, retrying..."
+        );
+
+        sleep_unless_cancelled(wait.interval().min(remaining), cancellation);
+    }
+}
+
+/// Sleep for `duration`, waking up early when `cancellation` is cancelled
+fn sleep_unless_cancelled(duration: Duration, cancellation: &CancellationToken) {
+    let deadline = Instant::now() + duration;
+
+    while !cancellation.is_cancelled() {
+        let remaining = deadline.saturating_duration_since(Instant::now());
+        if remaining.is_zero() {
+            return;
+        }
+        std::thread::sleep(remaining.min(CANCELLATION_CHECK_INTERVAL));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn fast_wait() -> HealthCheckWait {
+        HealthCheckWait::new(Duration::from_millis(10), Duration::from_millis(200), 200).unwrap()
+    }
+
+    #[test]
+    fn it_should_read_the_status_of_a_healthy_response() {
+        let output = "  HTTP/1.1 200 OK\n  content-type: application/json\n";
+
+        assert_eq!(
+            TrackerHealthResponse::parse(output),
+            TrackerHealthResponse::Status(200)
+        );
+    }
+
+    #[test]
+    fn it_should_read_the_status_of_an_error_response() {
+        let output = "  HTTP/1.1 500 Internal Server Error\nwget: server returned error: HTTP/1.1 500 Internal Server Error\n";
+
+        assert_eq!(
+            TrackerHealthResponse::parse(output),
+            TrackerHealthResponse::Status(500)
+        );
+    }
+
+    #[test]
+    fn it_should_keep_the_reason_when_no_status_is_received() {
+        let output = "Error response from daemon: Container abc is restarting, wait until the container is running\n";
+
+        assert_eq!(
+            TrackerHealthResponse::parse(output),
+            TrackerHealthResponse::Unreachable(
+                "Error response from daemon: Container abc is restarting, wait until the container is running".to_string()
+            )
+        );
+    }
+
+    #[test]
+    fn it_should_probe_the_loopback_address_when_bound_to_all_interfaces() {
+        let url = TrackerHealthWaiter::url_inside_container("0.0.0.0:1313".parse().unwrap());
+
+        assert_eq!(url, "http://127.0.0.1:1313/health_check"); // DevSkim: ignore DS137138
+    }
+
+    #[test]
+    fn it_should_stop_polling_once_the_expected_status_is_returned() {
+        let mut responses = vec![
+            TrackerHealthResponse::Unreachable("connection refused".to_string()),
+            TrackerHealthResponse::Status(503),
+            TrackerHealthResponse::Status(200),
+        ]
+        .into_iter();
+        let mut calls = 0;
+
+        let result = poll_until_healthy(&fast_wait(), "tracker", &CancellationToken::new(), || {
+            calls += 1;
+            responses.next().expect("no more polls expected")
+        });
+
+        assert!(result.is_ok());
+        assert_eq!(calls, 3);
+    }
+
+    #[test]
+    fn it_should_fail_with_the_last_response_when_the_timeout_elapses() {
+        let result = poll_until_healthy(&fast_wait(), "tracker", &CancellationToken::new(), || {
+            TrackerHealthResponse::Status(500)
+        });
+
+        let error = result.unwrap_err();
+        assert_eq!(
+            error.last_response(),
+            Some(&TrackerHealthResponse::Status(500))
+        );
+        assert!(error.to_string().contains("last response: HTTP 500"));
+        assert!(error.to_string().contains("Health check of 'tracker'"));
+    }
+
+    #[test]
+    fn it_should_accept_a_configured_expected_status() {
+        let wait = HealthCheckWait::new(Duration::from_millis(10), Duration::from_millis(50), 204)
+            .unwrap();
+
+        let result = poll_until_healthy(&wait, "tracker", &CancellationToken::new(), || {
+            TrackerHealthResponse::Status(204)
+        });
+
+        assert!(result.is_ok());
+    }
+
+    #[test]
+    fn it_should_wait_for_every_replica_and_report_the_one_that_never_became_healthy() {
+        let containers = vec!["tracker".to_string(), "tracker-2".to_string()];
+        let mut probed = Vec::new();
+
+        let result = poll_every_container_until_healthy(
+            &fast_wait(),
+            &containers,
+            &CancellationToken::new(),
+            |container| {
+                probed.push(container.to_string());
+                match container {
+                    "tracker" => TrackerHealthResponse::Status(200),
+                    _ => TrackerHealthResponse::Status(503),
+                }
+            },
+        );
+
+        assert!(matches!(
+            result,
+            Err(TrackerHealthWaitError::NeverHealthy { ref container, .. }) if container == "tracker-2"
+        ));
+        assert_eq!(probed[0], "tracker");
+        assert!(probed[1..].iter().all(|container| container == "tracker-2"));
+    }
+
+    #[test]
+    fn it_should_stop_polling_when_cancelled_during_a_probe() {
+        let wait =
+            HealthCheckWait::new(Duration::from_secs(1), Duration::from_secs(60), 200).unwrap();
+        let cancellation = CancellationToken::new();
+        let mut calls = 0;
+
+        let started = Instant::now();
+        let result = poll_until_healthy(&wait, "tracker", &cancellation, || {
+            calls += 1;
+            // The SSH call killed by Ctrl-C reads as an unreachable tracker
+            cancellation.cancel();
+            TrackerHealthResponse::Unreachable("instance not reachable".to_string())
+        });
+
+        let error = result.unwrap_err();
+        assert!(error.is_interrupted());
+        assert_eq!(error.last_response(), None);
+        assert_eq!(calls, 1);
+        assert!(started.elapsed() < Duration::from_secs(1));
+    }
+
+    #[test]
+    fn it_should_wake_up_from_the_interval_sleep_when_cancelled() {
+        let wait =
+            HealthCheckWait::new(Duration::from_secs(30), Duration::from_secs(60), 200).unwrap();
+        let cancellation = CancellationToken::new();
+        let canceller = cancellation.clone();
+        let handle = std::thread::spawn(move || {
+            std::thread::sleep(Duration::from_millis(50));
+            canceller.cancel();
+        });
+
+        let started = Instant::now();
+        let result = poll_until_healthy(&wait, "tracker", &cancellation, || {
+            TrackerHealthResponse::Status(503)
+        });
+        handle.join().unwrap();
+
+        assert!(result.unwrap_err().is_interrupted());
+        assert!(started.elapsed() < Duration::from_secs(5));
+    }
+}
@@ -0,0 +1,140 @@
+//! Tracker services stop and start remote actions
+//!
+//! This module provides the `TrackerServices` action which stops the Docker
+//! Compose services of the deployment and starts them again, for the `stop`
+//! and `start` commands.
+//!
+//! ## Key Features
+//!
+//! - `docker compose stop` keeps the containers, their volumes and the
+//!   deployed configuration, so `docker compose start` brings back exactly
+//!   the release that was running
+//! - Nothing is pulled or recreated, unlike the `run` command
+//!
+//! ## Usage Context
+//!
+//! Used by the stop command handler on `Running` environments and by the
+//! start command handler on `Stopped` environments.
+
+use thiserror::Error;
+use tracing::{info, instrument};
+
+use crate::adapters::ssh::{SshClient, SshConfig};
+use crate::shared::command::CommandError;
+
+/// Directory of the Docker Compose stack on the instance
+const DEPLOY_DIR: &str = "/opt/torrust";
+
+/// Errors that can occur while stopping or starting the services
+#[derive(Debug, Error)]
+pub enum TrackerServicesError {
+    /// `docker compose stop` failed on the instance
+    #[error("Failed to stop the services in '{DEPLOY_DIR}': {source}")]
+    StopFailed {
+        #[source]
+        source: Box<CommandError>,
+    },
+
+    /// `docker compose start` failed on the instance
+    #[error("Failed to start the services in '{DEPLOY_DIR}': {source}")]
+    StartFailed {
+        #[source]
+        source: Box<CommandError>,
+    },
+}
+
+impl crate::shared::Traceable for TrackerServicesError {
+    fn trace_format(&self) -> String {
+        format!("TrackerServicesError: {self}")
+    }
+
+    fn trace_source(&self) -> Option<&dyn crate::shared::Traceable> {
+        None
+    }
+
+    fn error_kind(&self) -> crate::shared::ErrorKind {
+        match self {
+            Self::StopFailed { source } | Self::StartFailed { source } => source.error_kind(),
+        }
+    }
+}
+
+/// Action that stops and starts the Docker Compose services on the instance
+pub struct TrackerServices {
+    ssh_client: SshClient,
+}
+
+impl TrackerServices {
+    /// Create a new `TrackerServices`
+    ///
+    /// # Arguments
+    /// * `ssh_config` - SSH connection configuration containing credentials and host IP
+    #[must_use]
+    pub fn new(ssh_config: SshConfig) -> Self {
+        Self {
+            ssh_client: SshClient::new(ssh_config),
+        }
+    }
+
+    /// Stop every service of the deployment, keeping the containers
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if `docker compose stop` fails.
+    #[instrument(
+        name = "remote_action.tracker_services_stop",
+        skip_all,
+        fields(action_type = "stop")
+    )]
+    pub fn stop(&self) -> Result<(), TrackerServicesError> {
+        self.ssh_client
+            .execute(&compose_command("stop"))
+            .map_err(|source| TrackerServicesError::StopFailed {
+                source: Box::new(source),
+            })?;
+
+        info!(action = "tracker_services_stop", "Services stopped");
+
+        Ok(())
+    }
+
+    /// Start the stopped services of the deployment again
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if `docker compose start` fails.
+    #[instrument(
+        name = "remote_action.tracker_services_start",
+        skip_all,
+        fields(action_type = "start")
+    )]
+    pub fn start(&self) -> Result<(), TrackerServicesError> {
+        self.ssh_client
+            .execute(&compose_command("start"))
+            .map_err(|source| TrackerServicesError::StartFailed {
+                source: Box::new(source),
+            })?;
+
+        info!(action = "tracker_services_start", "Services started");
+
+        Ok(())
+    }
+}
+
+/// Shell command running `docker compose <subcommand>` in the deploy directory
+fn compose_command(subcommand: &str) -> String {
+    format!("cd {DEPLOY_DIR} && docker compose {subcommand}")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn it_should_run_docker_compose_in_the_deploy_directory() {
+        assert_eq!(
+            compose_command("stop"),
+            "cd /opt/torrust && docker compose stop"
+        );
+    }
+}
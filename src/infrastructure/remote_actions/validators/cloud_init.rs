@@ -47,7 +47,7 @@ impl RemoteAction for CloudInitValidator {
     }
 
     #[instrument(
-        name = "cloud_init_validation",
+        name = "remote_action.cloud_init_validation",
         skip(self),
         fields(
             action_type = "validation",
@@ -51,7 +51,7 @@ impl RemoteAction for DockerValidator {
     }
 
     #[instrument(
-        name = "docker_validation",
+        name = "remote_action.docker_validation",
         skip(self),
         fields(
             action_type = "validation",
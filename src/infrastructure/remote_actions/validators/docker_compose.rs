@@ -53,7 +53,7 @@ impl RemoteAction for DockerComposeValidator {
 
     #[allow(clippy::too_many_lines)]
     #[instrument(
-        name = "docker_compose_validation",
+        name = "remote_action.docker_compose_validation",
         skip(self),
         fields(
             action_type = "validation",
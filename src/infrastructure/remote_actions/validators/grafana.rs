@@ -103,7 +103,7 @@ impl RemoteAction for GrafanaValidator {
     }
 
     #[instrument(
-        name = "grafana_smoke_test",
+        name = "remote_action.grafana_smoke_test",
         skip(self),
         fields(
             action_type = "validation",
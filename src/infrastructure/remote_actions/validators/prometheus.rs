@@ -89,7 +89,7 @@ impl RemoteAction for PrometheusValidator {
     }
 
     #[instrument(
-        name = "prometheus_smoke_test",
+        name = "remote_action.prometheus_smoke_test",
         skip(self),
         fields(
             action_type = "validation",
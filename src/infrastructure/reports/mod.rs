@@ -0,0 +1,15 @@
+//! Deployment report persistence
+//!
+//! This module stores the structured `DeploymentReport` written after every
+//! deployment command in `data/{env}/reports/{timestamp}-{command}.json`.
+//!
+//! ## Module Structure
+//!
+//! - `store` - Writes, lists and reads the report files of an environment
+//! - `tool_versions` - Detects the versions of the external tools recorded in reports
+
+pub mod store;
+pub mod tool_versions;
+
+pub use store::{DeploymentReportStore, ReportStoreError, StoredReport};
+pub use tool_versions::detect_tool_versions;
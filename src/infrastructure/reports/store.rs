@@ -0,0 +1,267 @@
+//! File-based storage of deployment reports
+
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+
+use thiserror::Error;
+
+use crate::domain::environment::DeploymentReport;
+use crate::infrastructure::trace::TraceIndexEntry;
+
+/// Name of the reports directory inside the environment data directory
+pub const REPORTS_DIR_NAME: &str = "reports";
+
+/// Timestamp format for report filenames: YYYYmmdd-HHMMSS
+/// Example: 20251008-143045
+const REPORT_FILENAME_TIMESTAMP_FORMAT: &str = "%Y%m%d-%H%M%S";
+
+/// Errors that can occur while storing or reading deployment reports
+#[derive(Debug, Error)]
+pub enum ReportStoreError {
+    /// Failed to create the reports directory or write a report file
+    #[error("Failed to write deployment report at {path}: {source}")]
+    FileWrite {
+        /// Path where the write was attempted
+        path: String,
+
+        /// Underlying I/O error
+        #[source]
+        source: io::Error,
+    },
+
+    /// Failed to list or read report files
+    #[error("Failed to read deployment report at {path}: {source}")]
+    FileRead {
+        /// Path of the file or directory that could not be read
+        path: String,
+
+        /// Underlying I/O error
+        #[source]
+        source: io::Error,
+    },
+
+    /// Failed to serialize or parse a report
+    #[error("Invalid deployment report at {path}: {source}")]
+    Serialization {
+        /// Path of the report file
+        path: String,
+
+        /// Underlying (de)serialization error
+        #[source]
+        source: serde_json::Error,
+    },
+}
+
+/// A report together with the name of the file it was read from
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct StoredReport {
+    /// Report file name (e.g. `20251008-143045-provision.json`)
+    pub file_name: String,
+
+    /// The parsed report
+    pub report: DeploymentReport,
+
+    /// Trace index entry of the failure, when the command failed and its
+    /// trace is still kept
+    pub trace: Option<TraceIndexEntry>,
+}
+
+/// Stores the deployment reports of one environment
+///
+/// Report file names start with the finish timestamp of the command, so
+/// sorting them by name sorts them chronologically.
+pub struct DeploymentReportStore {
+    reports_dir: PathBuf,
+}
+
+impl DeploymentReportStore {
+    /// Create a store for the environment with the given data directory
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use std::path::Path;
+    /// use torrust_tracker_deployer_lib::infrastructure::reports::DeploymentReportStore;
+    ///
+    /// let store = DeploymentReportStore::for_data_dir(Path::new("data/my-env"));
+    /// assert_eq!(store.reports_dir(), Path::new("data/my-env/reports"));
+    /// ```
+    #[must_use]
+    pub fn for_data_dir(data_dir: &Path) -> Self {
+        Self {
+            reports_dir: data_dir.join(REPORTS_DIR_NAME),
+        }
+    }
+
+    /// Directory holding the report files
+    #[must_use]
+    pub fn reports_dir(&self) -> &Path {
+        &self.reports_dir
+    }
+
+    /// Write a report as `{timestamp}-{command}.json`
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the directory cannot be created or the file cannot be written
+    pub fn write(&self, report: &DeploymentReport) -> Result<PathBuf, ReportStoreError> {
+        fs::create_dir_all(&self.reports_dir).map_err(|source| ReportStoreError::FileWrite {
+            path: self.reports_dir.display().to_string(),
+            source,
+        })?;
+
+        let timestamp = report.finished_at.format(REPORT_FILENAME_TIMESTAMP_FORMAT);
+        let path = self
+            .reports_dir
+            .join(format!("{timestamp}-{}.json", report.command));
+
+        let content = serde_json::to_string_pretty(report).map_err(|source| {
+            ReportStoreError::Serialization {
+                path: path.display().to_string(),
+                source,
+            }
+        })?;
+
+        fs::write(&path, content).map_err(|source| ReportStoreError::FileWrite {
+            path: path.display().to_string(),
+            source,
+        })?;
+
+        Ok(path)
+    }
+
+    /// Read all reports, oldest first
+    ///
+    /// An environment without a reports directory has no reports.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the directory or a report file cannot be read or parsed
+    pub fn list(&self) -> Result<Vec<StoredReport>, ReportStoreError> {
+        let entries = match fs::read_dir(&self.reports_dir) {
+            Ok(entries) => entries,
+            Err(e) if e.kind() == io::ErrorKind::NotFound => return Ok(Vec::new()),
+            Err(source) => {
+                return Err(ReportStoreError::FileRead {
+                    path: self.reports_dir.display().to_string(),
+                    source,
+                })
+            }
+        };
+
+        let mut file_names = Vec::new();
+        for entry in entries {
+            let entry = entry.map_err(|source| ReportStoreError::FileRead {
+                path: self.reports_dir.display().to_string(),
+                source,
+            })?;
+            let file_name = entry.file_name().to_string_lossy().to_string();
+            if Path::new(&file_name)
+                .extension()
+                .is_some_and(|extension| extension == "json")
+            {
+                file_names.push(file_name);
+            }
+        }
+        file_names.sort();
+
+        file_names
+            .into_iter()
+            .map(|file_name| self.read(&file_name))
+            .collect()
+    }
+
+    /// Read the report with the given file name
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the file cannot be read or parsed
+    pub fn read(&self, file_name: &str) -> Result<StoredReport, ReportStoreError> {
+        let path = self.reports_dir.join(file_name);
+
+        let content = fs::read_to_string(&path).map_err(|source| ReportStoreError::FileRead {
+            path: path.display().to_string(),
+            source,
+        })?;
+
+        let report =
+            serde_json::from_str(&content).map_err(|source| ReportStoreError::Serialization {
+                path: path.display().to_string(),
+                source,
+            })?;
+
+        Ok(StoredReport {
+            file_name: file_name.to_string(),
+            report,
+            trace: None,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::BTreeMap;
+
+    use chrono::{TimeZone, Utc};
+    use tempfile::TempDir;
+
+    use super::*;
+
+    fn report(command: &str, second: u32) -> DeploymentReport {
+        let at = Utc.with_ymd_and_hms(2026, 1, 1, 12, 0, second).unwrap();
+        DeploymentReport {
+            command: command.to_string(),
+            environment: "my-env".to_string(),
+            started_at: at,
+            finished_at: at,
+            duration_ms: 0,
+            succeeded: true,
+            state: Some("provisioned".to_string()),
+            config_revision: Some(1),
+            target_host: None,
+            steps: Vec::new(),
+            tool_versions: BTreeMap::new(),
+            applied_settings: BTreeMap::new(),
+            trace_id: None,
+            trace_file: None,
+            error: None,
+        }
+    }
+
+    #[test]
+    fn it_should_write_reports_named_after_the_finish_time_and_command() {
+        let temp_dir = TempDir::new().unwrap();
+        let store = DeploymentReportStore::for_data_dir(temp_dir.path());
+
+        let path = store.write(&report("provision", 5)).unwrap();
+
+        assert_eq!(
+            path,
+            temp_dir
+                .path()
+                .join("reports/20260101-120005-provision.json")
+        );
+    }
+
+    #[test]
+    fn it_should_list_reports_oldest_first() {
+        let temp_dir = TempDir::new().unwrap();
+        let store = DeploymentReportStore::for_data_dir(temp_dir.path());
+        store.write(&report("configure", 9)).unwrap();
+        store.write(&report("provision", 1)).unwrap();
+
+        let reports = store.list().unwrap();
+
+        let commands: Vec<&str> = reports.iter().map(|r| r.report.command.as_str()).collect();
+        assert_eq!(commands, vec!["provision", "configure"]);
+    }
+
+    #[test]
+    fn it_should_return_no_reports_when_the_directory_does_not_exist() {
+        let temp_dir = TempDir::new().unwrap();
+        let store = DeploymentReportStore::for_data_dir(temp_dir.path());
+
+        assert!(store.list().unwrap().is_empty());
+    }
+}
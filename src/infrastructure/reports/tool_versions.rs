@@ -0,0 +1,30 @@
+//! Detection of the external tool versions recorded in deployment reports
+
+use std::collections::BTreeMap;
+
+use torrust_tracker_deployer_dependency_installer::{Dependency, DependencyManager};
+use tracing::debug;
+
+/// Detect the installed versions of the given tools
+///
+/// Tools that are not installed, or whose version cannot be determined, are
+/// left out of the result: a missing version must never fail a deployment.
+#[must_use]
+pub fn detect_tool_versions(tools: &[Dependency]) -> BTreeMap<String, String> {
+    let manager = DependencyManager::new();
+
+    tools
+        .iter()
+        .filter_map(|&tool| {
+            let detector = manager.get_detector(tool);
+            match detector.installed_version() {
+                Ok(Some(version)) => Some((tool.canonical_name().to_string(), version.to_string())),
+                Ok(None) => None,
+                Err(e) => {
+                    debug!(tool = %tool.canonical_name(), error = %e, "Could not detect tool version");
+                    None
+                }
+            }
+        })
+        .collect()
+}
@@ -0,0 +1,175 @@
+//! Schema Descriptions
+//!
+//! Schemars copies the rustdoc comments of the types verbatim into the
+//! `description` fields. Rust code examples and intra-doc links mean nothing
+//! to an editor showing the description of a JSON field, so they are removed.
+
+use serde_json::Value;
+
+/// Cleans every `description` string of a JSON Schema, recursively
+pub(super) fn clean_descriptions(schema: &mut Value) {
+    match schema {
+        Value::Object(map) => {
+            for (key, value) in map.iter_mut() {
+                match value {
+                    // A property named `description` holds a schema, not a string
+                    Value::String(text) if key == "description" => *text = plain_description(text),
+                    _ => clean_descriptions(value),
+                }
+            }
+        }
+        Value::Array(items) => items.iter_mut().for_each(clean_descriptions),
+        _ => {}
+    }
+}
+
+/// Turns a rustdoc comment into a plain description
+///
+/// Fenced code blocks are dropped, along with the headings whose section is
+/// left empty (usually `# Examples`), and intra-doc links like
+/// ``[`SecretSource`]`` keep only their label.
+fn plain_description(doc: &str) -> String {
+    let mut lines: Vec<&str> = Vec::new();
+    let mut in_code_block = false;
+    for line in doc.lines() {
+        if line.trim_start().starts_with("```") {
+            if !in_code_block {
+                drop_code_block_introduction(&mut lines);
+            }
+            in_code_block = !in_code_block;
+        } else if !in_code_block {
+            lines.push(line);
+        }
+    }
+
+    let mut kept: Vec<String> = Vec::new();
+    for (index, line) in lines.iter().enumerate() {
+        if is_heading(line) && section_is_empty(&lines[index + 1..]) {
+            continue;
+        }
+        if line.trim().is_empty() && kept.last().is_none_or(|last| last.trim().is_empty()) {
+            continue;
+        }
+        kept.push(unwrap_intra_doc_links(line));
+    }
+
+    kept.join("\n").trim().to_string()
+}
+
+/// Drops the line introducing a code block, like `With TLS proxy configuration:`
+fn drop_code_block_introduction(lines: &mut Vec<&str>) {
+    while lines.last().is_some_and(|line| line.trim().is_empty()) {
+        lines.pop();
+    }
+    if lines
+        .last()
+        .is_some_and(|line| line.trim_end().ends_with(':'))
+    {
+        lines.pop();
+    }
+}
+
+fn is_heading(line: &str) -> bool {
+    line.starts_with('#')
+}
+
+/// Whether only blank lines come before the next heading
+fn section_is_empty(rest: &[&str]) -> bool {
+    rest.iter()
+        .take_while(|line| !is_heading(line))
+        .all(|line| line.trim().is_empty())
+}
+
+/// Replaces ``[`Label`]``, ``[`Label`](path)`` and ``[`Label`][path]`` with `` `Label` ``
+///
+/// Brackets without a code label are left alone: descriptions use them for
+/// syntax like `registry[:port]`.
+fn unwrap_intra_doc_links(line: &str) -> String {
+    let mut result = String::with_capacity(line.len());
+    let mut rest = line;
+    while let Some(start) = rest.find("[`") {
+        result.push_str(&rest[..start]);
+        let after_open = &rest[start + 1..];
+        let label_end = after_open
+            .find(']')
+            .filter(|&end| is_code_label(&after_open[..end]));
+        let Some(label_end) = label_end else {
+            result.push('[');
+            rest = after_open;
+            continue;
+        };
+        result.push_str(&after_open[..label_end]);
+        rest = skip_link_target(&after_open[label_end + 1..]);
+    }
+    result.push_str(rest);
+    result
+}
+
+fn is_code_label(label: &str) -> bool {
+    label.len() > 2
+        && label.starts_with('`')
+        && label.ends_with('`')
+        && !label[1..label.len() - 1].contains('`')
+}
+
+/// Skips the `(path)` or `[path]` following a link label, if any
+fn skip_link_target(text: &str) -> &str {
+    let close = match text.chars().next() {
+        Some('(') => ')',
+        Some('[') => ']',
+        _ => return text,
+    };
+    text.find(close).map_or(text, |end| &text[end + 1..])
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use super::*;
+
+    #[test]
+    fn it_should_drop_code_blocks_with_their_introduction_and_the_headings_they_leave_empty() {
+        let doc = "Proxy section\n\n# Examples\n\nWith a proxy:\n\n```rust\nlet x = 1;\n```\n\n# Security\n\nKept.";
+
+        assert_eq!(
+            plain_description(doc),
+            "Proxy section\n\n# Security\n\nKept."
+        );
+    }
+
+    #[test]
+    fn it_should_keep_only_the_label_of_intra_doc_links() {
+        let doc = "A [`SecretSource`], a [`Path`](std::path::Path) and a [`Url`][url].";
+
+        assert_eq!(
+            plain_description(doc),
+            "A `SecretSource`, a `Path` and a `Url`."
+        );
+    }
+
+    #[test]
+    fn it_should_leave_brackets_without_a_code_label_alone() {
+        let doc = "Given as [registry[:port]/]name[:tag][@sha256:<digest>]";
+
+        assert_eq!(plain_description(doc), doc);
+    }
+
+    #[test]
+    fn it_should_clean_nested_descriptions_but_not_a_property_named_description() {
+        let mut schema = json!({
+            "description": "Root\n\n# Examples\n\n```json\n{}\n```",
+            "properties": {
+                "description": { "description": "See [`Environment`]", "type": "string" }
+            }
+        });
+
+        clean_descriptions(&mut schema);
+
+        assert_eq!(schema["description"], "Root");
+        assert_eq!(
+            schema["properties"]["description"]["description"],
+            "See `Environment`"
+        );
+    }
+}
@@ -14,6 +14,7 @@
 //! See [docs/features/json-schema-generation/specification.md](../../../docs/features/json-schema-generation/specification.md)
 //! for architectural rationale.
 
+mod description;
 mod schema_generator;
 
 pub use schema_generator::{SchemaGenerationError, SchemaGenerator};
@@ -6,6 +6,8 @@
 use schemars::{schema_for, JsonSchema};
 use thiserror::Error;
 
+use super::description::clean_descriptions;
+
 /// Errors that can occur during schema generation
 #[derive(Debug, Error)]
 pub enum SchemaGenerationError {
@@ -57,7 +59,9 @@ pub struct SchemaGenerator;
 impl SchemaGenerator {
     /// Generates a JSON Schema for the given type
     ///
-    /// The type must implement `JsonSchema` from the Schemars library.
+    /// The type must implement `JsonSchema` from the Schemars library. The
+    /// descriptions come from its doc comments, without code examples and
+    /// intra-doc links.
     ///
     /// # Type Parameters
     ///
@@ -86,7 +90,8 @@ impl SchemaGenerator {
     /// cannot be serialized to JSON (this should be extremely rare).
     pub fn generate<T: JsonSchema>() -> Result<String, SchemaGenerationError> {
         // Generate schema using Schemars
-        let schema = schema_for!(T);
+        let mut schema = schema_for!(T).to_value();
+        clean_descriptions(&mut schema);
 
         // Serialize to pretty-printed JSON
         serde_json::to_string_pretty(&schema)
@@ -0,0 +1,240 @@
+//! Shell Completion Script Generator
+//!
+//! Generates completion scripts from a Clap CLI structure and appends the
+//! hook that completes environment names dynamically.
+
+use clap::{Arg, Command, CommandFactory};
+
+use super::hooks::{self, EnvironmentNameTargets};
+use super::shell::CompletionShell;
+
+/// Hidden subcommand the completion scripts call to list environment names
+///
+/// It must print one environment name per line and nothing else.
+pub const COMPLETE_ENV_NAMES_COMMAND: &str = "__complete-env-names";
+
+/// Value name of the positional arguments that name an existing environment
+///
+/// A subcommand gets environment-name completion when its first positional
+/// argument has this value name (Clap derives it from the field name
+/// `environment` unless `value_name` says otherwise).
+pub const ENVIRONMENT_VALUE_NAME: &str = "ENVIRONMENT";
+
+/// Completion script generator for Clap CLI structures
+///
+/// This is a stateless utility: the static completions are generated by
+/// `clap_complete`, and the subcommands taking an environment name are found
+/// by introspecting the CLI, so the script never drifts from the CLI
+/// definition.
+///
+/// # Examples
+///
+/// ```rust
+/// use clap::Parser;
+/// use torrust_tracker_deployer_lib::infrastructure::shell_completion::{
+///     CompletionShell, ShellCompletionGenerator, COMPLETE_ENV_NAMES_COMMAND,
+/// };
+///
+/// #[derive(Parser)]
+/// #[command(name = "my-app")]
+/// enum MyCli {
+///     Show { environment: String },
+/// }
+///
+/// let script = ShellCompletionGenerator::generate::<MyCli>(CompletionShell::Fish);
+/// assert!(script.contains(COMPLETE_ENV_NAMES_COMMAND));
+/// ```
+pub struct ShellCompletionGenerator;
+
+impl ShellCompletionGenerator {
+    /// Generates the completion script of the given CLI type for `shell`
+    ///
+    /// The binary name completed by the script is the name of the root
+    /// command.
+    #[must_use]
+    pub fn generate<T: CommandFactory>(shell: CompletionShell) -> String {
+        let mut command = Self::without_hidden_subcommands(&T::command());
+        let bin_name = command.get_name().to_string();
+
+        let mut script = Vec::new();
+        clap_complete::generate(shell.generator(), &mut command, &bin_name, &mut script);
+        let mut script = String::from_utf8_lossy(&script).into_owned();
+
+        let targets = Self::environment_name_targets(&command);
+        script.push_str(&hooks::environment_name_hook(shell, &bin_name, &targets));
+
+        script
+    }
+
+    /// Copy of the root command without its hidden subcommands
+    ///
+    /// `clap_complete` offers hidden subcommands like any other, and Clap
+    /// cannot remove a subcommand, so the root is rebuilt from its arguments
+    /// and visible subcommands.
+    fn without_hidden_subcommands(command: &Command) -> Command {
+        let mut visible = Command::new(command.get_name().to_string())
+            .args(command.get_arguments().cloned())
+            .subcommands(
+                command
+                    .get_subcommands()
+                    .filter(|subcommand| !subcommand.is_hide_set())
+                    .cloned(),
+            );
+        if let Some(about) = command.get_about() {
+            visible = visible.about(about.clone());
+        }
+        if let Some(version) = command.get_version() {
+            visible = visible.version(version.to_string());
+        }
+        visible
+    }
+
+    /// Collects what the hook needs to know to spot an environment-name argument
+    fn environment_name_targets(command: &Command) -> EnvironmentNameTargets {
+        let subcommands: Vec<&Command> = command
+            .get_subcommands()
+            .filter(|subcommand| Self::takes_environment_name(subcommand))
+            .collect();
+
+        let mut value_options: Vec<String> = command
+            .get_arguments()
+            .chain(
+                subcommands
+                    .iter()
+                    .flat_map(|subcommand| subcommand.get_arguments()),
+            )
+            .filter(|arg| !arg.is_positional() && arg.get_action().takes_values())
+            .flat_map(Self::option_spellings)
+            .collect();
+        value_options.sort();
+        value_options.dedup();
+
+        EnvironmentNameTargets {
+            subcommands: subcommands
+                .iter()
+                .map(|subcommand| subcommand.get_name().to_string())
+                .collect(),
+            value_options,
+        }
+    }
+
+    /// Whether the first positional argument of the subcommand is an environment name
+    fn takes_environment_name(subcommand: &Command) -> bool {
+        subcommand
+            .get_positionals()
+            .next()
+            .is_some_and(|arg| Self::value_name(arg) == ENVIRONMENT_VALUE_NAME)
+    }
+
+    /// The value name shown in help, defaulting to the upper-cased argument id
+    fn value_name(arg: &Arg) -> String {
+        arg.get_value_names()
+            .and_then(|names| names.first())
+            .map_or_else(|| arg.get_id().as_str().to_uppercase(), ToString::to_string)
+    }
+
+    /// The `--long` and `-s` spellings of an option
+    fn option_spellings(arg: &Arg) -> Vec<String> {
+        let long = arg.get_long().map(|long| format!("--{long}"));
+        let short = arg.get_short().map(|short| format!("-{short}"));
+        long.into_iter().chain(short).collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use clap::Parser;
+
+    use super::*;
+
+    #[derive(Parser)]
+    #[command(name = "test-cli")]
+    struct TestCli {
+        #[arg(long, global = true)]
+        log_dir: Option<String>,
+
+        #[arg(short, long, global = true)]
+        verbose: bool,
+
+        #[command(subcommand)]
+        command: TestCommands,
+    }
+
+    #[derive(clap::Subcommand)]
+    enum TestCommands {
+        Show {
+            environment: String,
+        },
+        Clone {
+            #[arg(value_name = "ENVIRONMENT")]
+            source: String,
+            target: String,
+        },
+        Import {
+            #[arg(value_name = "NAME")]
+            environment: String,
+        },
+        Validate {
+            #[arg(long)]
+            env_file: String,
+        },
+        Repair {
+            environment: String,
+            #[arg(long)]
+            backup: Option<String>,
+        },
+        #[command(name = "__hidden-helper", hide = true)]
+        HiddenHelper,
+    }
+
+    #[test]
+    fn it_should_find_subcommands_whose_first_positional_is_an_environment_name() {
+        let targets = ShellCompletionGenerator::environment_name_targets(&TestCli::command());
+
+        assert_eq!(targets.subcommands, vec!["show", "clone", "repair"]);
+    }
+
+    #[test]
+    fn it_should_collect_options_taking_a_value_but_not_flags() {
+        let targets = ShellCompletionGenerator::environment_name_targets(&TestCli::command());
+
+        assert_eq!(targets.value_options, vec!["--backup", "--log-dir"]);
+    }
+
+    #[test]
+    fn it_should_append_the_environment_name_hook_for_every_shell() {
+        for shell in [
+            CompletionShell::Bash,
+            CompletionShell::Zsh,
+            CompletionShell::Fish,
+        ] {
+            let script = ShellCompletionGenerator::generate::<TestCli>(shell);
+
+            assert!(
+                script.contains("test-cli"),
+                "{shell:?}: missing static part"
+            );
+            assert!(
+                script.contains(COMPLETE_ENV_NAMES_COMMAND),
+                "{shell:?}: missing environment name hook"
+            );
+        }
+    }
+
+    #[test]
+    fn it_should_not_offer_hidden_subcommands_in_any_shell() {
+        for shell in [
+            CompletionShell::Bash,
+            CompletionShell::Zsh,
+            CompletionShell::Fish,
+        ] {
+            let script = ShellCompletionGenerator::generate::<TestCli>(shell);
+
+            assert!(script.contains("repair"), "{shell:?}: missing subcommand");
+            assert!(
+                !script.contains("__hidden-helper"),
+                "{shell:?}: offers a hidden subcommand"
+            );
+        }
+    }
+}
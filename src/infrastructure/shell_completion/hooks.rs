@@ -0,0 +1,222 @@
+//! Environment Name Completion Hooks
+//!
+//! Shell snippets appended to the generated completion scripts. Each hook
+//! wraps the `clap_complete` completion function: when the word being
+//! completed is the environment-name argument of a subcommand, it offers the
+//! names printed by the hidden [`COMPLETE_ENV_NAMES_COMMAND`]; otherwise it
+//! delegates to the static completions.
+//!
+//! The hooks find the subcommand by skipping options, and the values of the
+//! options listed in [`EnvironmentNameTargets::value_options`].
+
+use super::generator::COMPLETE_ENV_NAMES_COMMAND;
+use super::shell::CompletionShell;
+
+/// What a hook needs to know to spot an environment-name argument
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(super) struct EnvironmentNameTargets {
+    /// Subcommands whose first positional argument is an environment name
+    pub subcommands: Vec<String>,
+    /// Options (global or of those subcommands) followed by a value
+    pub value_options: Vec<String>,
+}
+
+const BASH_HOOK: &str = r#"
+__@PREFIX@_complete_environments() {
+    local cur="${COMP_WORDS[COMP_CWORD]}" subcommand="" word
+    local -i i positionals=0
+    for (( i = 1; i < COMP_CWORD; i++ )); do
+        word="${COMP_WORDS[i]}"
+        case "${word}" in@VALUE_OPTIONS_BRANCH@
+            -*)
+                ;;
+            *)
+                if [[ -z "${subcommand}" ]]; then
+                    subcommand="${word}"
+                else
+                    (( positionals++ ))
+                fi
+                ;;
+        esac
+    done
+    if [[ ${positionals} -eq 0 && "${cur}" != -* ]]; then
+        case "${subcommand}" in
+            @SUBCOMMANDS@)
+                COMPREPLY=( $(compgen -W "$("${COMP_WORDS[0]}" @CALLBACK@ 2>/dev/null)" -- "${cur}") )
+                return 0
+                ;;
+        esac
+    fi
+    @STATIC_FUNCTION@ "$@"
+}
+
+complete -F __@PREFIX@_complete_environments -o bashdefault -o default @BIN@
+"#;
+
+const BASH_VALUE_OPTIONS_BRANCH: &str = r"
+            @VALUE_OPTIONS@)
+                (( i++ ))
+                ;;";
+
+const ZSH_HOOK: &str = r#"
+__@PREFIX@_complete_environments() {
+    local subcommand="" word
+    integer i positionals=0
+    for (( i = 2; i < CURRENT; i++ )); do
+        word="${words[i]}"
+        case "${word}" in@VALUE_OPTIONS_BRANCH@
+            (-*)
+                ;;
+            (*)
+                if [[ -z "${subcommand}" ]]; then
+                    subcommand="${word}"
+                else
+                    (( positionals++ ))
+                fi
+                ;;
+        esac
+    done
+    if (( positionals == 0 )) && [[ "${words[CURRENT]}" != -* ]]; then
+        case "${subcommand}" in
+            (@SUBCOMMANDS@)
+                local -a environments
+                environments=( ${(f)"$("${words[1]}" @CALLBACK@ 2>/dev/null)"} )
+                compadd -a environments
+                return
+                ;;
+        esac
+    fi
+    @STATIC_FUNCTION@ "$@"
+}
+
+compdef __@PREFIX@_complete_environments @BIN@
+"#;
+
+const ZSH_VALUE_OPTIONS_BRANCH: &str = r"
+            (@VALUE_OPTIONS@)
+                (( i++ ))
+                ;;";
+
+const FISH_HOOK: &str = r#"
+function __fish_@PREFIX@_needs_environment
+    set -l tokens (commandline -opc)
+    set -e tokens[1]
+    set -l subcommand
+    set -l positionals 0
+    set -l skip_value 0
+    for token in $tokens
+        if test $skip_value -eq 1
+            set skip_value 0
+            continue
+        end
+        switch $token@VALUE_OPTIONS_BRANCH@
+            case '-*'
+            case '*'
+                if test -z "$subcommand"
+                    set subcommand $token
+                else
+                    set positionals (math $positionals + 1)
+                end
+        end
+    end
+    test $positionals -eq 0; and contains -- "$subcommand" @SUBCOMMANDS@
+end
+
+complete -c @BIN@ -n __fish_@PREFIX@_needs_environment -f -a '(@BIN@ @CALLBACK@ 2>/dev/null)'
+"#;
+
+const FISH_VALUE_OPTIONS_BRANCH: &str = r"
+            case @VALUE_OPTIONS@
+                set skip_value 1";
+
+/// Builds the hook completing environment names for `shell`
+///
+/// Returns an empty string when no subcommand takes an environment name.
+pub(super) fn environment_name_hook(
+    shell: CompletionShell,
+    bin_name: &str,
+    targets: &EnvironmentNameTargets,
+) -> String {
+    if targets.subcommands.is_empty() {
+        return String::new();
+    }
+
+    let (hook, value_options_branch, separator, static_function) = match shell {
+        CompletionShell::Bash => (
+            BASH_HOOK,
+            BASH_VALUE_OPTIONS_BRANCH,
+            "|",
+            format!("_{}", bin_name.replace('-', "__")),
+        ),
+        CompletionShell::Zsh => (
+            ZSH_HOOK,
+            ZSH_VALUE_OPTIONS_BRANCH,
+            "|",
+            format!("_{bin_name}"),
+        ),
+        CompletionShell::Fish => (FISH_HOOK, FISH_VALUE_OPTIONS_BRANCH, " ", String::new()),
+    };
+
+    let value_options_branch = if targets.value_options.is_empty() {
+        String::new()
+    } else {
+        value_options_branch.replace("@VALUE_OPTIONS@", &targets.value_options.join(separator))
+    };
+
+    hook.replace("@VALUE_OPTIONS_BRANCH@", &value_options_branch)
+        .replace("@SUBCOMMANDS@", &targets.subcommands.join(separator))
+        .replace("@STATIC_FUNCTION@", &static_function)
+        .replace("@CALLBACK@", COMPLETE_ENV_NAMES_COMMAND)
+        .replace("@PREFIX@", &bin_name.replace('-', "_"))
+        .replace("@BIN@", bin_name)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn targets() -> EnvironmentNameTargets {
+        EnvironmentNameTargets {
+            subcommands: vec!["show".to_string(), "destroy".to_string()],
+            value_options: vec!["--log-dir".to_string(), "-o".to_string()],
+        }
+    }
+
+    #[test]
+    fn it_should_delegate_to_the_static_bash_completion_function() {
+        let hook = environment_name_hook(CompletionShell::Bash, "my-app", &targets());
+
+        assert!(hook.contains("            show|destroy)"));
+        assert!(hook.contains("            --log-dir|-o)"));
+        assert!(hook.contains(r#"_my__app "$@""#));
+        assert!(hook.contains("complete -F __my_app_complete_environments"));
+    }
+
+    #[test]
+    fn it_should_delegate_to_the_static_zsh_completion_function() {
+        let hook = environment_name_hook(CompletionShell::Zsh, "my-app", &targets());
+
+        assert!(hook.contains("            (show|destroy)"));
+        assert!(hook.contains(r#"_my-app "$@""#));
+        assert!(hook.contains("compdef __my_app_complete_environments my-app"));
+    }
+
+    #[test]
+    fn it_should_add_a_fish_completion_calling_the_hidden_command() {
+        let hook = environment_name_hook(CompletionShell::Fish, "my-app", &targets());
+
+        assert!(hook.contains(r#"contains -- "$subcommand" show destroy"#));
+        assert!(hook.contains("case --log-dir -o"));
+        assert!(hook.contains("-a '(my-app __complete-env-names 2>/dev/null)'"));
+    }
+
+    #[test]
+    fn it_should_not_add_a_hook_when_no_subcommand_takes_an_environment_name() {
+        let targets = EnvironmentNameTargets {
+            subcommands: Vec::new(),
+            value_options: Vec::new(),
+        };
+
+        assert!(environment_name_hook(CompletionShell::Bash, "my-app", &targets).is_empty());
+    }
+}
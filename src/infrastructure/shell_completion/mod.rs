@@ -0,0 +1,46 @@
+//! Shell Completion Script Generation Infrastructure
+//!
+//! This module generates bash, zsh and fish completion scripts from Clap CLI
+//! structures. The static part (subcommands, options, possible values) comes
+//! from `clap_complete`. On top of it, every script installs a hook that
+//! completes environment-name arguments with the environments that actually
+//! exist, by calling back into the CLI through a hidden subcommand
+//! ([`COMPLETE_ENV_NAMES_COMMAND`]).
+//!
+//! ## Architecture
+//!
+//! This is an **Infrastructure Layer** component because:
+//! - Uses external dependencies (Clap introspection and `clap_complete`)
+//! - Pure technical mechanism with no business logic
+//!
+//! ## Usage
+//!
+//! ```rust
+//! use clap::Parser;
+//! use torrust_tracker_deployer_lib::infrastructure::shell_completion::{
+//!     CompletionShell, ShellCompletionGenerator,
+//! };
+//!
+//! #[derive(Parser)]
+//! #[command(name = "my-app")]
+//! struct MyCli {
+//!     #[arg(short, long)]
+//!     verbose: bool,
+//! }
+//!
+//! let script = ShellCompletionGenerator::generate::<MyCli>(CompletionShell::Bash);
+//! assert!(script.contains("my-app"));
+//! ```
+//!
+//! ## Module Structure
+//!
+//! - `generator` - Main script generator (`ShellCompletionGenerator`)
+//! - `hooks` - Shell snippets completing environment names dynamically
+//! - `shell` - Supported shells (`CompletionShell`)
+
+mod generator;
+mod hooks;
+mod shell;
+
+pub use generator::{ShellCompletionGenerator, COMPLETE_ENV_NAMES_COMMAND, ENVIRONMENT_VALUE_NAME};
+pub use shell::CompletionShell;
@@ -0,0 +1,28 @@
+//! Supported Shells
+//!
+//! Shells for which completion scripts can be generated.
+
+/// Shell to generate a completion script for
+///
+/// Only shells for which the environment-name hook is implemented are
+/// supported.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, clap::ValueEnum)]
+pub enum CompletionShell {
+    /// GNU Bash
+    Bash,
+    /// Z shell
+    Zsh,
+    /// Friendly interactive shell
+    Fish,
+}
+
+impl CompletionShell {
+    /// The `clap_complete` generator producing the static part of the script
+    pub(super) fn generator(self) -> clap_complete::Shell {
+        match self {
+            Self::Bash => clap_complete::Shell::Bash,
+            Self::Zsh => clap_complete::Shell::Zsh,
+            Self::Fish => clap_complete::Shell::Fish,
+        }
+    }
+}
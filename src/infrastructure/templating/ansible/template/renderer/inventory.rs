@@ -169,10 +169,12 @@ impl InventoryRenderer {
             )?;
 
         // Create InventoryTemplate with runtime context
-        let inventory_template =
-            InventoryTemplate::new(&inventory_template_file, inventory_context.clone()).map_err(
-                |source| InventoryRendererError::InventoryTemplateCreationFailed { source },
-            )?;
+        let inventory_template = InventoryTemplate::new(
+            &inventory_template_file,
+            inventory_context.clone(),
+            self.template_manager.extra_variables(),
+        )
+        .map_err(|source| InventoryRendererError::InventoryTemplateCreationFailed { source })?;
 
         // Render to output file
         let inventory_output_path = output_dir.join(Self::INVENTORY_OUTPUT_FILE);
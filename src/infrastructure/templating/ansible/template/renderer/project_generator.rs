@@ -155,6 +155,10 @@ impl AnsibleProjectGenerator {
     /// * `inventory_context` - Runtime context for inventory template rendering (IP, SSH keys)
     /// * `tracker_config` - Optional tracker configuration for firewall port extraction
     /// * `grafana_config` - Optional Grafana configuration for conditional firewall setup
+    /// * `firewall_allow_rules` - Rules the firewall allows besides SSH
+    /// * `offline` - Pre-seeded artifacts replacing the downloads, if deployed offline
+    /// * `proxy` - Outbound proxy the playbooks and Docker download through, if any
+    /// * `system_tuning` - Sysctls, resource limits and swap file applied on configure
     ///
     /// # Returns
     ///
@@ -168,11 +172,16 @@ impl AnsibleProjectGenerator {
     /// - Template manager cannot provide required templates
     /// - Dynamic template rendering fails
     /// - Runtime variable substitution fails
+    #[allow(clippy::too_many_arguments)]
     pub async fn render(
         &self,
         inventory_context: &InventoryContext,
         tracker_config: Option<&crate::domain::tracker::TrackerConfig>,
         grafana_config: Option<&crate::domain::grafana::GrafanaConfig>,
+        firewall_allow_rules: &[crate::domain::firewall::FirewallRule],
+        offline: Option<&crate::domain::provision::OfflineMode>,
+        proxy: Option<&crate::domain::provision::ProxyConfig>,
+        system_tuning: &crate::domain::system_tuning::SystemTuningConfig,
     ) -> Result<(), AnsibleProjectGeneratorError> {
         tracing::info!(
             template_type = "ansible",
@@ -189,7 +198,11 @@ impl AnsibleProjectGenerator {
 
         // Render dynamic variables template with system configuration using collaborator
         let variables_context =
-            Self::create_variables_context(inventory_context, tracker_config, grafana_config)?;
+            Self::create_variables_context(inventory_context, tracker_config, grafana_config)?
+                .with_firewall_allow_rules(firewall_allow_rules)
+                .with_offline_mode(offline)
+                .with_proxy(proxy)
+                .with_system_tuning(system_tuning);
         self.variables_renderer
             .render(&variables_context, &build_ansible_dir)
             .map_err(|source| AnsibleProjectGeneratorError::VariablesRenderingFailed { source })?;
@@ -306,6 +319,7 @@ impl AnsibleProjectGenerator {
             "wait-cloud-init.yml",
             "configure-security-updates.yml",
             "configure-firewall.yml",
+            "configure-system-tuning.yml",
             "create-tracker-storage.yml",
             "init-tracker-database.yml",
             "deploy-tracker-config.yml",
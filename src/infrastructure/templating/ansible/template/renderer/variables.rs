@@ -173,10 +173,12 @@ impl VariablesRenderer {
             )?;
 
         // Create AnsibleVariablesTemplate with system configuration context
-        let variables_template =
-            AnsibleVariablesTemplate::new(&variables_template_file, variables_context).map_err(
-                |source| VariablesRendererError::VariablesTemplateCreationFailed { source },
-            )?;
+        let variables_template = AnsibleVariablesTemplate::new(
+            &variables_template_file,
+            variables_context,
+            self.template_manager.extra_variables(),
+        )
+        .map_err(|source| VariablesRendererError::VariablesTemplateCreationFailed { source })?;
 
         // Render to output file
         let variables_output_path = output_dir.join(Self::VARIABLES_OUTPUT_FILE);
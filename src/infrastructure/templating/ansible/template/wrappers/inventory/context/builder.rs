@@ -1,3 +1,5 @@
+use std::path::PathBuf;
+
 use super::{AnsibleHost, AnsiblePort, InventoryContext, InventoryContextError, SshPrivateKeyFile};
 use crate::infrastructure::templating::TemplateMetadata;
 
@@ -10,6 +12,7 @@ pub struct InventoryContextBuilder {
     ansible_ssh_private_key_file: Option<SshPrivateKeyFile>,
     ansible_port: Option<AnsiblePort>,
     ansible_user: Option<String>,
+    known_hosts_file: Option<PathBuf>,
 }
 
 impl InventoryContextBuilder {
@@ -54,6 +57,13 @@ impl InventoryContextBuilder {
         self
     }
 
+    /// Sets the `known_hosts` file host keys are checked against.
+    #[must_use]
+    pub fn with_known_hosts_file(mut self, known_hosts_file: Option<PathBuf>) -> Self {
+        self.known_hosts_file = known_hosts_file;
+        self
+    }
+
     /// Builds the `InventoryContext`
     ///
     /// # Errors
@@ -82,12 +92,17 @@ impl InventoryContextBuilder {
             .ansible_user
             .ok_or(InventoryContextError::MissingAnsibleUser)?;
 
-        InventoryContext::new(
+        let context = InventoryContext::new(
             metadata,
             ansible_host,
             ansible_ssh_private_key_file,
             ansible_port,
             ansible_user,
-        )
+        )?;
+
+        Ok(match self.known_hosts_file {
+            Some(path) => context.with_known_hosts_file(&path),
+            None => context,
+        })
     }
 }
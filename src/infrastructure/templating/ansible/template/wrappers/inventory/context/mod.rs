@@ -2,6 +2,8 @@ pub mod ansible_host;
 pub mod ansible_port;
 pub mod ssh_private_key_file;
 
+use std::path::Path;
+
 use serde::Serialize;
 use thiserror::Error;
 
@@ -57,6 +59,8 @@ pub struct InventoryContext {
     ansible_ssh_private_key_file: SshPrivateKeyFile,
     ansible_port: AnsiblePort,
     ansible_user: String,
+    /// `known_hosts` file checked strictly; host keys are not checked without it
+    ansible_ssh_known_hosts_file: Option<String>,
 }
 
 impl InventoryContext {
@@ -79,9 +83,17 @@ impl InventoryContext {
             ansible_ssh_private_key_file,
             ansible_port,
             ansible_user,
+            ansible_ssh_known_hosts_file: None,
         })
     }
 
+    /// Check host keys strictly against the `known_hosts` file at `path`
+    #[must_use]
+    pub fn with_known_hosts_file(mut self, path: &Path) -> Self {
+        self.ansible_ssh_known_hosts_file = Some(path.to_string_lossy().to_string());
+        self
+    }
+
     /// Creates a new builder for `InventoryContext` with fluent interface
     #[must_use]
     pub fn builder() -> InventoryContextBuilder {
@@ -112,6 +124,12 @@ impl InventoryContext {
         &self.ansible_user
     }
 
+    /// Get the `known_hosts` file path, if host keys are checked
+    #[must_use]
+    pub fn ansible_ssh_known_hosts_file(&self) -> Option<&str> {
+        self.ansible_ssh_known_hosts_file.as_deref()
+    }
+
     /// Get the template metadata
     #[must_use]
     pub fn metadata(&self) -> &TemplateMetadata {
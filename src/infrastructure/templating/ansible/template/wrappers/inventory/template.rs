@@ -1,6 +1,6 @@
 use crate::domain::template::file::File;
 use crate::domain::template::{
-    write_file_with_dir_creation, FileOperationError, TemplateEngineError,
+    write_file_with_dir_creation, ExtraTemplateVariables, FileOperationError, TemplateEngineError,
 };
 use anyhow::Result;
 use std::path::Path;
@@ -30,8 +30,10 @@ impl InventoryTemplate {
     pub fn new(
         template_file: &File,
         inventory_context: InventoryContext,
+        extra_variables: &ExtraTemplateVariables,
     ) -> Result<Self, TemplateEngineError> {
-        let mut engine = crate::domain::template::TemplateEngine::new();
+        let mut engine =
+            crate::domain::template::TemplateEngine::new().with_extra_variables(extra_variables);
 
         let validated_content = engine.render(
             template_file.filename(),
@@ -89,6 +91,42 @@ mod tests {
             .unwrap()
     }
 
+    fn render_inventory_template(context: InventoryContext) -> String {
+        let template_path = std::path::Path::new(env!("CARGO_MANIFEST_DIR"))
+            .join("templates/ansible/inventory.yml.tera");
+        let template_file = File::new(
+            "inventory.yml.tera",
+            std::fs::read_to_string(template_path).unwrap(),
+        )
+        .unwrap();
+
+        InventoryTemplate::new(&template_file, context, &ExtraTemplateVariables::default())
+            .unwrap()
+            .content
+    }
+
+    #[test]
+    fn it_should_check_host_keys_against_the_known_hosts_file_of_the_environment() {
+        let context = create_inventory_context("10.0.0.1", "/path/to/key")
+            .with_known_hosts_file(Path::new("/deployer/data/dev/ssh/known_hosts"));
+
+        let rendered = render_inventory_template(context);
+
+        assert!(rendered.contains(
+            "ansible_ssh_common_args: \"-o StrictHostKeyChecking=yes -o UserKnownHostsFile=/deployer/data/dev/ssh/known_hosts\""
+        ));
+    }
+
+    #[test]
+    fn it_should_not_check_host_keys_without_a_known_hosts_file() {
+        let rendered =
+            render_inventory_template(create_inventory_context("10.0.0.1", "/path/to/key"));
+
+        assert!(rendered.contains(
+            "ansible_ssh_common_args: \"-o StrictHostKeyChecking=no -o UserKnownHostsFile=/dev/null\""
+        ));
+    }
+
     #[test]
     fn it_should_create_inventory_template_successfully() {
         // Use template content directly instead of file
@@ -97,7 +135,12 @@ mod tests {
         let template_file = File::new("inventory.yml.tera", template_content.to_string()).unwrap();
 
         let inventory_context = create_inventory_context("192.168.1.100", "/path/to/key");
-        let template = InventoryTemplate::new(&template_file, inventory_context).unwrap();
+        let template = InventoryTemplate::new(
+            &template_file,
+            inventory_context,
+            &ExtraTemplateVariables::default(),
+        )
+        .unwrap();
 
         assert_eq!(template.ansible_host(), "192.168.1.100");
         assert_eq!(template.ansible_ssh_private_key_file(), "/path/to/key");
@@ -111,7 +154,12 @@ mod tests {
         let template_file = File::new("inventory.yml.tera", template_content.to_string()).unwrap();
 
         let inventory_context = create_inventory_context("10.0.0.1", "/home/user/.ssh/id_rsa");
-        let template = InventoryTemplate::new(&template_file, inventory_context).unwrap();
+        let template = InventoryTemplate::new(
+            &template_file,
+            inventory_context,
+            &ExtraTemplateVariables::default(),
+        )
+        .unwrap();
 
         assert_eq!(template.ansible_host(), "10.0.0.1");
         assert_eq!(
@@ -126,7 +174,11 @@ mod tests {
         let template_file = File::new("inventory.yml.tera", String::new()).unwrap();
 
         let inventory_context = create_inventory_context("10.0.0.1", "/home/user/.ssh/id_rsa");
-        let result = InventoryTemplate::new(&template_file, inventory_context);
+        let result = InventoryTemplate::new(
+            &template_file,
+            inventory_context,
+            &ExtraTemplateVariables::default(),
+        );
 
         // Empty templates are valid in Tera - they just render as empty strings
         assert!(result.is_ok());
@@ -142,7 +194,11 @@ mod tests {
         let template_file = File::new("inventory.yml.tera", template_content.to_string()).unwrap();
 
         let inventory_context = create_inventory_context("10.0.0.1", "/home/user/.ssh/id_rsa");
-        let result = InventoryTemplate::new(&template_file, inventory_context);
+        let result = InventoryTemplate::new(
+            &template_file,
+            inventory_context,
+            &ExtraTemplateVariables::default(),
+        );
 
         // This is valid - templates don't need to use all available context variables
         assert!(result.is_ok());
@@ -158,7 +214,11 @@ mod tests {
         let template_file = File::new("inventory.yml.tera", template_content.to_string()).unwrap();
 
         let inventory_context = create_inventory_context("10.0.0.1", "/home/user/.ssh/id_rsa");
-        let result = InventoryTemplate::new(&template_file, inventory_context);
+        let result = InventoryTemplate::new(
+            &template_file,
+            inventory_context,
+            &ExtraTemplateVariables::default(),
+        );
 
         // Static templates are valid - they just don't use template variables
         assert!(result.is_ok());
@@ -174,7 +234,11 @@ mod tests {
         let template_file = File::new("inventory.yml.tera", template_content.to_string()).unwrap();
 
         let inventory_context = create_inventory_context("10.0.0.1", "/home/user/.ssh/id_rsa");
-        let result = InventoryTemplate::new(&template_file, inventory_context);
+        let result = InventoryTemplate::new(
+            &template_file,
+            inventory_context,
+            &ExtraTemplateVariables::default(),
+        );
 
         // This should fail because the template references an undefined variable
         assert!(result.is_err());
@@ -190,7 +254,11 @@ mod tests {
         let template_file = File::new("inventory.yml.tera", template_content.to_string()).unwrap();
 
         let inventory_context = create_inventory_context("10.0.0.1", "/home/user/.ssh/id_rsa");
-        let result = InventoryTemplate::new(&template_file, inventory_context);
+        let result = InventoryTemplate::new(
+            &template_file,
+            inventory_context,
+            &ExtraTemplateVariables::default(),
+        );
 
         // Should fail during template validation
         assert!(result.is_err());
@@ -204,7 +272,11 @@ mod tests {
         let template_file = File::new("inventory.yml.tera", template_content.to_string()).unwrap();
 
         let inventory_context = create_inventory_context("10.0.0.1", "/home/user/.ssh/id_rsa");
-        let result = InventoryTemplate::new(&template_file, inventory_context);
+        let result = InventoryTemplate::new(
+            &template_file,
+            inventory_context,
+            &ExtraTemplateVariables::default(),
+        );
 
         assert!(result.is_err());
     }
@@ -219,7 +291,12 @@ mod tests {
         // Template validation happens during construction, not during render
         let inventory_context =
             create_inventory_context("192.168.1.100", "/home/user/.ssh/test_key");
-        let template = InventoryTemplate::new(&template_file, inventory_context).unwrap();
+        let template = InventoryTemplate::new(
+            &template_file,
+            inventory_context,
+            &ExtraTemplateVariables::default(),
+        )
+        .unwrap();
 
         // Verify that the template was pre-validated and contains rendered content
         assert_eq!(template.ansible_host(), "192.168.1.100");
@@ -1,10 +1,14 @@
+use std::collections::BTreeMap;
 use std::net::SocketAddr;
 
 use serde::Serialize;
 use thiserror::Error;
 
+use crate::domain::firewall::FirewallRule;
 use crate::domain::grafana::GrafanaConfig;
-use crate::domain::tracker::TrackerConfig;
+use crate::domain::provision::{OfflineMode, ProxyConfig};
+use crate::domain::system_tuning::SystemTuningConfig;
+use crate::domain::tracker::{Protocol, TrackerConfig};
 use crate::infrastructure::templating::TemplateMetadata;
 
 /// Errors that can occur when creating an `AnsibleVariablesContext`
@@ -15,6 +19,32 @@ pub enum AnsibleVariablesContextError {
     InvalidSshPort(#[from] crate::infrastructure::templating::ansible::template::wrappers::inventory::context::AnsiblePortError),
 }
 
+/// A firewall allow rule in the form expected by the `ufw` Ansible module
+#[derive(Serialize, Debug, Clone, PartialEq, Eq)]
+pub struct AnsibleFirewallRule {
+    /// Port to open
+    port: u16,
+
+    /// `tcp` or `udp`
+    protocol: &'static str,
+
+    /// Allowed source network, `any` when unrestricted
+    source: String,
+}
+
+impl From<&FirewallRule> for AnsibleFirewallRule {
+    fn from(rule: &FirewallRule) -> Self {
+        Self {
+            port: rule.port(),
+            protocol: match rule.protocol() {
+                Protocol::Tcp => "tcp",
+                Protocol::Udp => "udp",
+            },
+            source: rule.cidr().unwrap_or("any").to_string(),
+        }
+    }
+}
+
 /// Context for rendering the variables.yml.tera template
 ///
 /// This context contains system configuration variables used across
@@ -43,6 +73,39 @@ pub struct AnsibleVariablesContext {
     /// Grafana configuration (optional)
     #[serde(skip_serializing_if = "Option::is_none")]
     grafana_config: Option<GrafanaConfig>,
+
+    /// Rules the firewall allows besides SSH
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    firewall_allow_rules: Vec<AnsibleFirewallRule>,
+
+    /// Whether the instance is deployed without Internet access
+    offline_mode: bool,
+
+    /// Directory of `.deb` files on the deployer machine (offline mode only)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    offline_packages_dir: Option<String>,
+
+    /// Registry mirror Docker pulls images from (offline mode only)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    docker_registry_mirror: Option<String>,
+
+    /// Proxy environment variables of the tasks that download (proxy section only)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    proxy_env: Option<BTreeMap<String, String>>,
+
+    /// `proxies` of the Docker daemon configuration (proxy section only)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    docker_proxies: Option<BTreeMap<String, String>>,
+
+    /// Sysctls of the system tuning, by key
+    system_tuning_sysctls: BTreeMap<String, String>,
+
+    /// Resource limits of the system tuning, by name
+    system_tuning_ulimits: BTreeMap<String, u64>,
+
+    /// Size of the swap file of the system tuning, if any
+    #[serde(skip_serializing_if = "Option::is_none")]
+    system_tuning_swap_size_mb: Option<u32>,
 }
 
 impl AnsibleVariablesContext {
@@ -70,9 +133,72 @@ impl AnsibleVariablesContext {
             tracker_http_ports,
             tracker_api_port,
             grafana_config: grafana_config.cloned(),
+            firewall_allow_rules: Vec::new(),
+            offline_mode: false,
+            offline_packages_dir: None,
+            docker_registry_mirror: None,
+            proxy_env: None,
+            docker_proxies: None,
+            system_tuning_sysctls: BTreeMap::new(),
+            system_tuning_ulimits: BTreeMap::new(),
+            system_tuning_swap_size_mb: None,
         })
     }
 
+    /// Sets the rules the firewall allows besides SSH
+    #[must_use]
+    pub fn with_firewall_allow_rules(mut self, rules: &[FirewallRule]) -> Self {
+        self.firewall_allow_rules = rules.iter().map(AnsibleFirewallRule::from).collect();
+        self
+    }
+
+    /// Sets the pre-seeded artifacts the playbooks install from instead of the Internet
+    #[must_use]
+    pub fn with_offline_mode(mut self, offline: Option<&OfflineMode>) -> Self {
+        self.offline_mode = offline.is_some();
+        self.offline_packages_dir = offline
+            .and_then(OfflineMode::packages_dir)
+            .map(|path| path.display().to_string());
+        self.docker_registry_mirror = offline
+            .and_then(OfflineMode::registry_mirror)
+            .map(|url| url.as_str().trim_end_matches('/').to_string());
+        self
+    }
+
+    /// Sets the outbound proxy the playbooks and Docker download through
+    #[must_use]
+    pub fn with_proxy(mut self, proxy: Option<&ProxyConfig>) -> Self {
+        self.proxy_env = proxy.map(|proxy| {
+            // Remote tools read the lower case variables; the upper case ones are duplicates
+            proxy
+                .env_vars()
+                .into_iter()
+                .filter(|(name, _)| *name == name.to_lowercase())
+                .collect()
+        });
+        self.docker_proxies = proxy.map(|proxy| {
+            let no_proxy = (!proxy.no_proxy().is_empty()).then(|| proxy.no_proxy().join(","));
+            [
+                ("http-proxy", proxy.http().map(ToString::to_string)),
+                ("https-proxy", proxy.https().map(ToString::to_string)),
+                ("no-proxy", no_proxy),
+            ]
+            .into_iter()
+            .filter_map(|(key, value)| value.map(|value| (key.to_string(), value)))
+            .collect()
+        });
+        self
+    }
+
+    /// Sets the sysctls, resource limits and swap file the system tuning applies
+    #[must_use]
+    pub fn with_system_tuning(mut self, system_tuning: &SystemTuningConfig) -> Self {
+        self.system_tuning_sysctls = system_tuning.effective_sysctls();
+        self.system_tuning_ulimits = system_tuning.effective_ulimits();
+        self.system_tuning_swap_size_mb = system_tuning.swap_size_mb();
+        self
+    }
+
     /// Extract port numbers from tracker configuration
     ///
     /// Returns a tuple of (`udp_ports`, `http_ports`, `api_port`)
@@ -131,6 +257,48 @@ impl AnsibleVariablesContext {
     pub fn tracker_api_port(&self) -> Option<u16> {
         self.tracker_api_port
     }
+
+    /// Get the rules the firewall allows besides SSH
+    #[must_use]
+    pub fn firewall_allow_rules(&self) -> &[AnsibleFirewallRule] {
+        &self.firewall_allow_rules
+    }
+
+    /// Get the directory of `.deb` files installed in offline mode
+    #[must_use]
+    pub fn offline_packages_dir(&self) -> Option<&str> {
+        self.offline_packages_dir.as_deref()
+    }
+
+    /// Get the registry mirror Docker pulls images from in offline mode
+    #[must_use]
+    pub fn docker_registry_mirror(&self) -> Option<&str> {
+        self.docker_registry_mirror.as_deref()
+    }
+
+    /// Get the proxy environment variables of the tasks that download
+    #[must_use]
+    pub fn proxy_env(&self) -> Option<&BTreeMap<String, String>> {
+        self.proxy_env.as_ref()
+    }
+
+    /// Get the `proxies` of the Docker daemon configuration
+    #[must_use]
+    pub fn docker_proxies(&self) -> Option<&BTreeMap<String, String>> {
+        self.docker_proxies.as_ref()
+    }
+
+    /// Get the sysctls the system tuning applies
+    #[must_use]
+    pub fn system_tuning_sysctls(&self) -> &BTreeMap<String, String> {
+        &self.system_tuning_sysctls
+    }
+
+    /// Get the resource limits the system tuning applies
+    #[must_use]
+    pub fn system_tuning_ulimits(&self) -> &BTreeMap<String, u64> {
+        &self.system_tuning_ulimits
+    }
 }
 
 #[cfg(test)]
@@ -190,6 +358,87 @@ mod tests {
         assert!(json.contains("\"ssh_port\":8022"));
     }
 
+    #[test]
+    fn it_should_expose_the_offline_artifacts_to_the_playbooks() {
+        let offline = OfflineMode::new(
+            "local:torrust-ubuntu-24.04",
+            None,
+            Some("/srv/offline/debs".into()),
+            Some("https://registry.lab.internal/"),
+        )
+        .unwrap();
+
+        let context = AnsibleVariablesContext::new(create_test_metadata(), 22, None, None)
+            .unwrap()
+            .with_offline_mode(Some(&offline));
+
+        assert_eq!(context.offline_packages_dir(), Some("/srv/offline/debs"));
+        assert_eq!(
+            context.docker_registry_mirror(),
+            Some("https://registry.lab.internal")
+        );
+        let json = serde_json::to_string(&context).unwrap();
+        assert!(json.contains("\"offline_mode\":true"));
+    }
+
+    #[test]
+    fn it_should_expose_the_proxy_to_the_playbooks_and_the_docker_daemon() {
+        let proxy = ProxyConfig::new(
+            Some("http://proxy.corp.internal:3128"),
+            None,
+            vec![".corp.internal".to_string()],
+        )
+        .unwrap();
+
+        let context = AnsibleVariablesContext::new(create_test_metadata(), 22, None, None)
+            .unwrap()
+            .with_proxy(Some(&proxy));
+
+        let proxy_env = context.proxy_env().unwrap();
+        assert_eq!(
+            proxy_env.keys().collect::<Vec<_>>(),
+            ["http_proxy", "no_proxy"]
+        );
+        let docker_proxies = context.docker_proxies().unwrap();
+        assert_eq!(
+            docker_proxies.get("http-proxy").map(String::as_str),
+            Some("http://proxy.corp.internal:3128/")
+        );
+        assert_eq!(
+            docker_proxies.get("no-proxy").map(String::as_str),
+            Some(".corp.internal")
+        );
+    }
+
+    #[test]
+    fn it_should_expose_the_preset_and_explicit_system_tuning_to_the_playbooks() {
+        use crate::domain::system_tuning::TuningPreset;
+
+        let system_tuning = SystemTuningConfig::new(
+            TuningPreset::HighUdp,
+            BTreeMap::from([("net.core.somaxconn".to_string(), "4096".to_string())]),
+            BTreeMap::new(),
+            Some(1024),
+        )
+        .unwrap();
+
+        let context = AnsibleVariablesContext::new(create_test_metadata(), 22, None, None)
+            .unwrap()
+            .with_system_tuning(&system_tuning);
+
+        assert_eq!(
+            context.system_tuning_sysctls()["net.core.rmem_max"],
+            "33554432"
+        );
+        assert_eq!(
+            context.system_tuning_sysctls()["net.core.somaxconn"],
+            "4096"
+        );
+        assert_eq!(context.system_tuning_ulimits()["nofile"], 1_048_576);
+        let json = serde_json::to_string(&context).unwrap();
+        assert!(json.contains("\"system_tuning_swap_size_mb\":1024"));
+    }
+
     #[test]
     fn it_should_display_error_message_correctly() {
         let error =
@@ -314,4 +563,25 @@ mod tests {
         assert_eq!(context.tracker_udp_ports(), &[6868, 6969]);
         assert_eq!(context.tracker_http_ports(), &[7070]);
     }
+
+    #[test]
+    fn it_should_render_unrestricted_firewall_rules_with_any_source() {
+        let rules = vec![
+            FirewallRule::new(6969, Protocol::Udp, None).unwrap(),
+            FirewallRule::new(9100, Protocol::Tcp, Some("10.0.0.0/8".to_string())).unwrap(),
+        ];
+
+        let context = AnsibleVariablesContext::new(create_test_metadata(), 22, None, None)
+            .unwrap()
+            .with_firewall_allow_rules(&rules);
+
+        let json = serde_json::to_value(&context).unwrap();
+        assert_eq!(
+            json["firewall_allow_rules"],
+            serde_json::json!([
+                { "port": 6969, "protocol": "udp", "source": "any" },
+                { "port": 9100, "protocol": "tcp", "source": "10.0.0.0/8" },
+            ])
+        );
+    }
 }
@@ -3,5 +3,5 @@
 pub mod context;
 pub mod template;
 
-pub use context::{AnsibleVariablesContext, AnsibleVariablesContextError};
+pub use context::{AnsibleFirewallRule, AnsibleVariablesContext, AnsibleVariablesContextError};
 pub use template::AnsibleVariablesTemplate;
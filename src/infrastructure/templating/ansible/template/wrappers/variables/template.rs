@@ -1,6 +1,6 @@
 use crate::domain::template::file::File;
 use crate::domain::template::{
-    write_file_with_dir_creation, FileOperationError, TemplateEngineError,
+    write_file_with_dir_creation, ExtraTemplateVariables, FileOperationError, TemplateEngineError,
 };
 use std::path::Path;
 
@@ -21,8 +21,10 @@ impl AnsibleVariablesTemplate {
     pub fn new(
         template_file: &File,
         context: &AnsibleVariablesContext,
+        extra_variables: &ExtraTemplateVariables,
     ) -> Result<Self, TemplateEngineError> {
-        let mut engine = crate::domain::template::TemplateEngine::new();
+        let mut engine =
+            crate::domain::template::TemplateEngine::new().with_extra_variables(extra_variables);
         let validated_content =
             engine.render(template_file.filename(), template_file.content(), context)?;
 
@@ -67,7 +69,11 @@ ssh_port: {{ ssh_port }}
         let context = create_variables_context(22);
         let template_file = create_minimal_template();
 
-        let template = AnsibleVariablesTemplate::new(&template_file, &context);
+        let template = AnsibleVariablesTemplate::new(
+            &template_file,
+            &context,
+            &ExtraTemplateVariables::default(),
+        );
 
         assert!(template.is_ok());
     }
@@ -76,7 +82,12 @@ ssh_port: {{ ssh_port }}
     fn it_should_render_template_with_ssh_port() {
         let context = create_variables_context(2222);
         let template_file = create_minimal_template();
-        let template = AnsibleVariablesTemplate::new(&template_file, &context).unwrap();
+        let template = AnsibleVariablesTemplate::new(
+            &template_file,
+            &context,
+            &ExtraTemplateVariables::default(),
+        )
+        .unwrap();
 
         // The rendered content should have the port substituted
         assert!(template.content.contains("2222"));
@@ -89,7 +100,11 @@ ssh_port: {{ ssh_port }}
         let invalid_template =
             File::new("variables.yml.tera", "{{ unclosed_variable".to_string()).unwrap();
 
-        let result = AnsibleVariablesTemplate::new(&invalid_template, &context);
+        let result = AnsibleVariablesTemplate::new(
+            &invalid_template,
+            &context,
+            &ExtraTemplateVariables::default(),
+        );
 
         assert!(result.is_err());
     }
@@ -104,7 +119,11 @@ ssh_port: {{ ssh_port }}
         )
         .unwrap();
 
-        let result = AnsibleVariablesTemplate::new(&template_with_missing_var, &context);
+        let result = AnsibleVariablesTemplate::new(
+            &template_with_missing_var,
+            &context,
+            &ExtraTemplateVariables::default(),
+        );
 
         assert!(result.is_err());
     }
@@ -113,7 +132,12 @@ ssh_port: {{ ssh_port }}
     fn it_should_support_custom_ssh_ports() {
         let context = create_variables_context(8022);
         let template_file = create_minimal_template();
-        let template = AnsibleVariablesTemplate::new(&template_file, &context).unwrap();
+        let template = AnsibleVariablesTemplate::new(
+            &template_file,
+            &context,
+            &ExtraTemplateVariables::default(),
+        )
+        .unwrap();
 
         assert!(template.content.contains("8022"));
     }
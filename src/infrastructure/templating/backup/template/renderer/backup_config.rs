@@ -98,7 +98,8 @@ impl BackupConfigRenderer {
         })?;
 
         // 2. Create template with context
-        let template = BackupTemplate::new(template_content, context.clone())?;
+        let template = BackupTemplate::new(template_content, context.clone())?
+            .with_extra_variables(self.template_manager.extra_variables());
 
         // 3. Render to output file
         let output_path = output_dir.join(Self::BACKUP_OUTPUT_FILE);
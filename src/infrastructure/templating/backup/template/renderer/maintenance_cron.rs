@@ -100,7 +100,8 @@ impl MaintenanceCronRenderer {
 
         // 2. Create template with context
         let context = MaintenanceCronContext::new(schedule);
-        let template = MaintenanceCronTemplate::new(template_content, context)?;
+        let template = MaintenanceCronTemplate::new(template_content, context)?
+            .with_extra_variables(self.template_manager.extra_variables());
 
         // 3. Render to output file
         let output_path = output_dir.join(Self::MAINTENANCE_CRON_OUTPUT_FILE);
@@ -7,6 +7,8 @@ use std::path::Path;
 use tera::Tera;
 use thiserror::Error;
 
+use crate::domain::template::ExtraTemplateVariables;
+
 use super::context::BackupContext;
 
 /// Errors that can occur during backup template operations
@@ -38,6 +40,8 @@ pub struct BackupTemplate {
     content: String,
     /// The rendering context
     context: BackupContext,
+    /// User-defined variables added to the rendering context
+    extra_variables: ExtraTemplateVariables,
 }
 
 impl BackupTemplate {
@@ -62,9 +66,17 @@ impl BackupTemplate {
         Ok(Self {
             content: template_content,
             context,
+            extra_variables: ExtraTemplateVariables::default(),
         })
     }
 
+    /// Sets the user-defined variables added to the rendering context
+    #[must_use]
+    pub fn with_extra_variables(mut self, extra_variables: &ExtraTemplateVariables) -> Self {
+        self.extra_variables = extra_variables.clone();
+        self
+    }
+
     /// Renders the template with the context
     ///
     /// # Returns
@@ -79,8 +91,9 @@ impl BackupTemplate {
         tera.add_raw_template("backup.conf", &self.content)
             .map_err(|e| BackupTemplateError::RenderingFailed(e.to_string()))?;
 
-        let context = tera::Context::from_serialize(&self.context)
+        let mut context = tera::Context::from_serialize(&self.context)
             .map_err(|e| BackupTemplateError::RenderingFailed(e.to_string()))?;
+        self.extra_variables.merge_into(&mut context, "backup.conf");
 
         tera.render("backup.conf", &context)
             .map_err(|e| BackupTemplateError::RenderingFailed(e.to_string()))
@@ -7,6 +7,8 @@ use std::path::Path;
 use tera::Tera;
 use thiserror::Error;
 
+use crate::domain::template::ExtraTemplateVariables;
+
 use super::context::MaintenanceCronContext;
 
 /// Errors that can occur during maintenance cron template operations
@@ -38,6 +40,8 @@ pub struct MaintenanceCronTemplate {
     content: String,
     /// The rendering context
     context: MaintenanceCronContext,
+    /// User-defined variables added to the rendering context
+    extra_variables: ExtraTemplateVariables,
 }
 
 impl MaintenanceCronTemplate {
@@ -63,9 +67,17 @@ impl MaintenanceCronTemplate {
         Ok(Self {
             content,
             context: ctx,
+            extra_variables: ExtraTemplateVariables::default(),
         })
     }
 
+    /// Sets the user-defined variables added to the rendering context
+    #[must_use]
+    pub fn with_extra_variables(mut self, extra_variables: &ExtraTemplateVariables) -> Self {
+        self.extra_variables = extra_variables.clone();
+        self
+    }
+
     /// Renders the template to a file
     ///
     /// # Arguments
@@ -85,11 +97,11 @@ impl MaintenanceCronTemplate {
         tera.add_raw_template("maintenance-backup.cron", &self.content)?;
 
         // Render template with context
+        let mut context = tera::Context::from_serialize(&self.context)?;
+        self.extra_variables
+            .merge_into(&mut context, "maintenance-backup.cron");
         let rendered = tera
-            .render(
-                "maintenance-backup.cron",
-                &tera::Context::from_serialize(&self.context)?,
-            )
+            .render("maintenance-backup.cron", &context)
             .map_err(|e| MaintenanceCronTemplateError::RenderingFailed(e.to_string()))?;
 
         // Write to file
@@ -113,9 +113,12 @@ impl CaddyfileRenderer {
         tera.add_raw_template(Self::CADDYFILE_TEMPLATE_FILE, &template_content)
             .map_err(CaddyfileRendererError::TeraCreationFailed)?;
 
-        // 4. Convert context to Tera context
-        let tera_context =
+        // 4. Convert context to Tera context, with the user-defined variables
+        let mut tera_context =
             tera::Context::from_serialize(context).map_err(CaddyfileRendererError::RenderFailed)?;
+        self.template_manager
+            .extra_variables()
+            .merge_into(&mut tera_context, Self::CADDYFILE_TEMPLATE_FILE);
 
         // 5. Render template
         let rendered = tera
@@ -148,8 +148,12 @@ impl DockerComposeRenderer {
             })?;
 
         // Create the template with context
-        let docker_compose_template = DockerComposeTemplate::new(&template_file, context.clone())
-            .map_err(|source| {
+        let docker_compose_template = DockerComposeTemplate::new(
+            &template_file,
+            context.clone(),
+            self.template_manager.extra_variables(),
+        )
+        .map_err(|source| {
             DockerComposeRendererError::DockerComposeTemplateCreationFailed { source }
         })?;
 
@@ -186,6 +190,7 @@ impl DockerComposeRenderer {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::testing::fixtures::mysql_tracker_config;
     use tempfile::TempDir;
 
     use crate::domain::topology::EnabledServices;
@@ -246,6 +251,37 @@ mod tests {
         assert_eq!(path, "docker-compose/docker-compose.yml.tera");
     }
 
+    #[test]
+    fn it_should_render_the_image_overrides_of_the_release_configuration() {
+        use crate::domain::release::{ImageReference, ReleaseImages};
+
+        let temp_dir = TempDir::new().unwrap();
+        let template_manager = Arc::new(TemplateManager::new(temp_dir.path()));
+
+        let pinned_tracker = format!(
+            "registry.corp.internal/torrust/tracker@sha256:{}",
+            "a".repeat(64)
+        );
+        let images = ReleaseImages {
+            tracker: Some(ImageReference::new(pinned_tracker.clone()).unwrap()),
+            mysql: Some(ImageReference::new("registry.corp.internal/mysql:8.4").unwrap()),
+            ..ReleaseImages::default()
+        };
+        let context = DockerComposeContext::builder(test_tracker_config())
+            .with_images(images)
+            .build();
+
+        let renderer = DockerComposeRenderer::new(template_manager);
+        let output_dir = TempDir::new().unwrap();
+        renderer.render(&context, output_dir.path()).unwrap();
+
+        let content =
+            std::fs::read_to_string(output_dir.path().join("docker-compose.yml")).unwrap();
+        assert!(content.contains(&format!("image: {pinned_tracker}")));
+        // MySQL is not enabled, so its override is not rendered
+        assert!(!content.contains("registry.corp.internal/mysql"));
+    }
+
     #[test]
     fn it_should_render_docker_compose_with_mysql_service_when_driver_is_mysql() {
         let temp_dir = TempDir::new().unwrap();
@@ -478,4 +514,35 @@ mod tests {
             "Should not have prometheus volume mount"
         );
     }
+
+    #[test]
+    fn it_should_render_one_tracker_service_per_replica_with_offset_host_ports() {
+        let temp_dir = TempDir::new().unwrap();
+        let template_manager = Arc::new(TemplateManager::new(temp_dir.path()));
+
+        let domain_config = mysql_tracker_config()
+            .with_replicas(std::num::NonZeroU8::new(2).unwrap())
+            .unwrap();
+        let tracker =
+            TrackerServiceContext::from_domain_config(&domain_config, &EnabledServices::from(&[]));
+        let context = DockerComposeContext::builder(tracker).build();
+
+        let renderer = DockerComposeRenderer::new(template_manager);
+        let output_dir = TempDir::new().unwrap();
+
+        renderer
+            .render(&context, output_dir.path())
+            .expect("Rendering with two tracker replicas should succeed");
+
+        let rendered_content =
+            std::fs::read_to_string(output_dir.path().join("docker-compose.yml"))
+                .expect("Should be able to read rendered docker-compose.yml");
+
+        assert!(rendered_content.contains("container_name: tracker\n"));
+        assert!(rendered_content.contains("container_name: tracker-2\n"));
+        assert!(rendered_content.contains(r#"- "6969:6969/udp""#));
+        assert!(rendered_content.contains(r#"- "6970:6969/udp""#));
+        assert!(rendered_content.contains(r#"- "7071:7070""#));
+        assert!(rendered_content.contains(r#"- "1213:1212""#));
+    }
 }
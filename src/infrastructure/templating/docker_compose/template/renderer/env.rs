@@ -174,8 +174,12 @@ impl EnvRenderer {
             })?;
 
         // Create EnvTemplate with runtime context
-        let env_template = EnvTemplate::new(&env_template_file, env_context.clone())
-            .map_err(|source| EnvRendererError::EnvTemplateCreationFailed { source })?;
+        let env_template = EnvTemplate::new(
+            &env_template_file,
+            env_context.clone(),
+            self.template_manager.extra_variables(),
+        )
+        .map_err(|source| EnvRendererError::EnvTemplateCreationFailed { source })?;
 
         // Render to output file
         let env_output_path = output_dir.join(Self::ENV_OUTPUT_FILE);
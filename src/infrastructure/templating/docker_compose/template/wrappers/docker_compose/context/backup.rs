@@ -61,6 +61,9 @@ use super::service_topology::ServiceTopology;
 /// ```
 #[derive(Debug, Clone, Serialize, PartialEq)]
 pub struct BackupServiceContext {
+    /// Docker image reference (e.g. `torrust/tracker-backup:latest`)
+    pub image: String,
+
     /// Service topology (ports and networks)
     ///
     /// Flattened for template compatibility - serializes ports/networks at top level.
@@ -99,6 +102,7 @@ impl BackupServiceContext {
             .collect();
 
         Self {
+            image: DomainBackupConfig::docker_image().full_reference(),
             topology: ServiceTopology::new(ports, networks),
             dependencies,
         }
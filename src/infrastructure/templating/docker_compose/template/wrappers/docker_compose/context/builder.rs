@@ -6,6 +6,7 @@ use std::collections::{HashMap, HashSet};
 use crate::domain::backup::BackupConfig;
 use crate::domain::grafana::GrafanaConfig;
 use crate::domain::prometheus::PrometheusConfig;
+use crate::domain::release::{ImageReference, ReleaseImages};
 use crate::domain::topology::{EnabledServices, Network, Service};
 use crate::infrastructure::templating::TemplateMetadata;
 
@@ -34,6 +35,7 @@ pub struct DockerComposeContextBuilder {
     grafana_config: Option<GrafanaConfig>,
     backup_config: Option<BackupConfig>,
     has_caddy: bool,
+    images: ReleaseImages,
 }
 
 impl DockerComposeContextBuilder {
@@ -50,6 +52,7 @@ impl DockerComposeContextBuilder {
             grafana_config: None,
             backup_config: None,
             has_caddy: false,
+            images: ReleaseImages::default(),
         }
     }
 
@@ -110,6 +113,19 @@ impl DockerComposeContextBuilder {
         self
     }
 
+    /// Replaces the images of the services
+    ///
+    /// Services without override keep the image pinned by the deployer.
+    ///
+    /// # Arguments
+    ///
+    /// * `images` - Image overrides of the release configuration
+    #[must_use]
+    pub fn with_images(mut self, images: ReleaseImages) -> Self {
+        self.images = images;
+        self
+    }
+
     /// Sets the template metadata
     ///
     /// # Arguments
@@ -195,40 +211,59 @@ impl DockerComposeContextBuilder {
         let topology_context = EnabledServices::from(&enabled_services);
 
         // Build Prometheus service config if enabled
-        let prometheus = self
+        let mut prometheus = self
             .prometheus_config
             .as_ref()
             .map(|config| PrometheusServiceContext::from_domain_config(config, &topology_context));
 
         // Build Grafana service config if enabled
-        let grafana = self
+        let mut grafana = self
             .grafana_config
             .as_ref()
             .map(|config| GrafanaServiceContext::from_domain_config(config, &topology_context));
 
         // Build Caddy service config if enabled
-        let caddy = if has_caddy {
+        let mut caddy = if has_caddy {
             Some(CaddyServiceContext::new())
         } else {
             None
         };
 
         // Build MySQL service config if enabled
-        let mysql = if self.database.driver == DRIVER_MYSQL {
+        let mut mysql = if self.database.driver == DRIVER_MYSQL {
             Some(MysqlServiceContext::new())
         } else {
             None
         };
 
         // Build Backup service config if enabled
-        let backup = self
+        let mut backup = self
             .backup_config
             .as_ref()
             .map(|config| BackupServiceContext::from_domain_config(config, &topology_context));
 
+        // Replace the images overridden by the release configuration
+        let mut tracker = self.tracker;
+        let images = &self.images;
+        override_image(Some(&mut tracker.image), images.tracker.as_ref());
+        override_image(
+            prometheus.as_mut().map(|s| &mut s.image),
+            images.prometheus.as_ref(),
+        );
+        override_image(
+            grafana.as_mut().map(|s| &mut s.image),
+            images.grafana.as_ref(),
+        );
+        override_image(caddy.as_mut().map(|s| &mut s.image), images.caddy.as_ref());
+        override_image(mysql.as_mut().map(|s| &mut s.image), images.mysql.as_ref());
+        override_image(
+            backup.as_mut().map(|s| &mut s.image),
+            images.backup.as_ref(),
+        );
+
         // Derive required networks from all service configurations
         let required_networks = Self::derive_required_networks(
-            &self.tracker,
+            &tracker,
             prometheus.as_ref(),
             grafana.as_ref(),
             caddy.as_ref(),
@@ -239,7 +274,7 @@ impl DockerComposeContextBuilder {
         DockerComposeContext {
             metadata,
             database: self.database,
-            tracker: self.tracker,
+            tracker,
             prometheus,
             grafana,
             caddy,
@@ -434,6 +469,13 @@ impl std::fmt::Display for PortConflictError {
 
 impl std::error::Error for PortConflictError {}
 
+/// Replaces the image of an enabled service with its override, if any
+fn override_image(image: Option<&mut String>, replacement: Option<&ImageReference>) {
+    if let (Some(image), Some(replacement)) = (image, replacement) {
+        *image = replacement.to_string();
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -43,6 +43,9 @@ use super::service_topology::ServiceTopology;
 /// ```
 #[derive(Debug, Clone, Serialize, PartialEq)]
 pub struct CaddyServiceContext {
+    /// Docker image reference (e.g. `caddy:2.11.2`)
+    pub image: String,
+
     /// Service topology (ports and networks)
     ///
     /// Flattened for template compatibility - serializes ports/networks at top level.
@@ -67,6 +70,7 @@ impl CaddyServiceContext {
         let networks = config.derive_networks(enabled_services);
 
         Self {
+            image: CaddyConfig::docker_image().full_reference(),
             topology: ServiceTopology::new(ports, networks),
         }
     }
@@ -28,7 +28,7 @@ pub use caddy::CaddyServiceContext;
 pub use grafana::GrafanaServiceContext;
 pub use mysql::MysqlServiceContext;
 pub use prometheus::PrometheusServiceContext;
-pub use tracker::TrackerServiceContext;
+pub use tracker::{TrackerReplicaContext, TrackerServiceContext};
 
 // Re-exports - other types
 pub use builder::{DockerComposeContextBuilder, PortConflictError};
@@ -24,8 +24,25 @@ pub struct TrackerServiceContext {
     /// Service topology (ports and networks)
     ///
     /// Flattened for template compatibility - serializes ports/networks at top level.
+    /// The ports are those of all replicas, used for port conflict validation.
     #[serde(flatten)]
     pub topology: ServiceTopology,
+
+    /// One entry per tracker container rendered in the compose file
+    pub replicas: Vec<TrackerReplicaContext>,
+}
+
+/// A single tracker container of the Docker Compose template
+///
+/// The first replica keeps the `tracker` service name so that other services
+/// (Caddy, Prometheus, backups) keep reaching it; replica N is `tracker-N`.
+#[derive(Serialize, Debug, Clone, PartialEq)]
+pub struct TrackerReplicaContext {
+    /// Compose service and container name
+    pub name: String,
+
+    /// Ports published by this replica, shifted by its offset
+    pub ports: Vec<PortDefinition>,
 }
 
 impl TrackerServiceContext {
@@ -47,12 +64,30 @@ impl TrackerServiceContext {
             .map(PortDefinition::from)
             .collect();
 
+        let replicas = (0..config.replicas().get())
+            .map(|index| TrackerReplicaContext {
+                name: TrackerConfig::replica_name(index),
+                ports: config
+                    .replica_port_bindings(index)
+                    .iter()
+                    .map(PortDefinition::from)
+                    .collect(),
+            })
+            .collect();
+
         Self {
-            image: TrackerConfig::docker_image().full_reference(),
+            image: config.image().full_reference(),
             topology: ServiceTopology::new(ports, networks),
+            replicas,
         }
     }
 
+    /// Returns the tracker containers rendered in the compose file
+    #[must_use]
+    pub fn replicas(&self) -> &[TrackerReplicaContext] {
+        &self.replicas
+    }
+
     /// Returns a reference to the port bindings
     #[must_use]
     pub fn ports(&self) -> &[PortDefinition] {
@@ -69,6 +104,7 @@ impl TrackerServiceContext {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::testing::fixtures::mysql_tracker_config;
 
     use crate::domain::tracker::{
         DatabaseConfig as TrackerDatabaseConfig, HealthCheckApiConfig, HttpApiConfig,
@@ -373,4 +409,66 @@ mod tests {
         assert!(json["ports"][0]["binding"].is_string());
         assert!(json["ports"][0]["description"].is_string());
     }
+
+    // ==========================================================================
+    // Replica tests
+    // ==========================================================================
+
+    #[test]
+    fn it_should_render_a_single_tracker_replica_by_default() {
+        let domain_config = basic_domain_tracker_config();
+        let context = make_context(false, false, false);
+        let config = TrackerServiceContext::from_domain_config(&domain_config, &context);
+
+        assert_eq!(config.replicas().len(), 1);
+        assert_eq!(config.replicas()[0].name, "tracker");
+        assert_eq!(config.replicas()[0].ports, config.ports());
+    }
+
+    #[test]
+    fn it_should_offset_the_host_ports_of_each_replica() {
+        let domain_config = mysql_tracker_config()
+            .with_replicas(std::num::NonZeroU8::new(2).unwrap())
+            .unwrap();
+        let context = make_context(false, false, false);
+        let config = TrackerServiceContext::from_domain_config(&domain_config, &context);
+
+        let replicas: Vec<(&str, Vec<String>)> = config
+            .replicas()
+            .iter()
+            .map(|replica| {
+                (
+                    replica.name.as_str(),
+                    replica
+                        .ports
+                        .iter()
+                        .map(|p| p.binding().to_string())
+                        .collect(),
+                )
+            })
+            .collect();
+
+        assert_eq!(
+            replicas,
+            vec![
+                (
+                    "tracker",
+                    vec![
+                        "6969:6969/udp".to_string(),
+                        "7070:7070".to_string(),
+                        "1212:1212".to_string()
+                    ]
+                ),
+                (
+                    "tracker-2",
+                    vec![
+                        "6970:6969/udp".to_string(),
+                        "7071:7070".to_string(),
+                        "1213:1212".to_string()
+                    ]
+                ),
+            ]
+        );
+        assert_eq!(config.ports().len(), 6);
+    }
 }
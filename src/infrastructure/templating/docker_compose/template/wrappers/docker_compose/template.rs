@@ -7,7 +7,7 @@ use std::path::Path;
 
 use crate::domain::template::file::File;
 use crate::domain::template::{
-    write_file_with_dir_creation, FileOperationError, TemplateEngineError,
+    write_file_with_dir_creation, ExtraTemplateVariables, FileOperationError, TemplateEngineError,
 };
 
 use super::context::DockerComposeContext;
@@ -42,8 +42,10 @@ impl DockerComposeTemplate {
     pub fn new(
         template_file: &File,
         context: DockerComposeContext,
+        extra_variables: &ExtraTemplateVariables,
     ) -> Result<Self, TemplateEngineError> {
-        let mut engine = crate::domain::template::TemplateEngine::new();
+        let mut engine =
+            crate::domain::template::TemplateEngine::new().with_extra_variables(extra_variables);
 
         let validated_content =
             engine.render(template_file.filename(), template_file.content(), &context)?;
@@ -138,7 +140,9 @@ services:
 
         let tracker = test_tracker_config();
         let context = DockerComposeContext::builder(tracker).build();
-        let template = DockerComposeTemplate::new(&template_file, context).unwrap();
+        let template =
+            DockerComposeTemplate::new(&template_file, context, &ExtraTemplateVariables::default())
+                .unwrap();
 
         assert_eq!(template.database().driver(), "sqlite3");
         // MySQL service should not be in the rendered content
@@ -173,7 +177,9 @@ services:
         let context = DockerComposeContext::builder(tracker)
             .with_mysql(mysql_config)
             .build();
-        let template = DockerComposeTemplate::new(&template_file, context).unwrap();
+        let template =
+            DockerComposeTemplate::new(&template_file, context, &ExtraTemplateVariables::default())
+                .unwrap();
 
         assert_eq!(template.database().driver(), "mysql");
         // MySQL service should be in the rendered content
@@ -194,7 +200,9 @@ services:
             File::new("docker-compose.yml.tera", template_content.to_string()).unwrap();
         let tracker = test_tracker_config();
         let context = DockerComposeContext::builder(tracker).build();
-        let template = DockerComposeTemplate::new(&template_file, context).unwrap();
+        let template =
+            DockerComposeTemplate::new(&template_file, context, &ExtraTemplateVariables::default())
+                .unwrap();
 
         // Create temp directory for output
         // Create temp directory for output
@@ -219,7 +227,8 @@ services:
 
         let tracker = test_tracker_config();
         let context = DockerComposeContext::builder(tracker).build();
-        let result = DockerComposeTemplate::new(&template_file, context);
+        let result =
+            DockerComposeTemplate::new(&template_file, context, &ExtraTemplateVariables::default());
 
         assert!(result.is_err());
     }
@@ -261,7 +270,9 @@ services:
         let context = DockerComposeContext::builder(tracker).build();
 
         // Act
-        let template = DockerComposeTemplate::new(&template_file, context).unwrap();
+        let template =
+            DockerComposeTemplate::new(&template_file, context, &ExtraTemplateVariables::default())
+                .unwrap();
 
         // Assert: an empty `networks:` key must not appear in the output.
         // Before the fix this rendered:
@@ -301,7 +312,9 @@ services:
             .build();
 
         // Act
-        let template = DockerComposeTemplate::new(&template_file, context).unwrap();
+        let template =
+            DockerComposeTemplate::new(&template_file, context, &ExtraTemplateVariables::default())
+                .unwrap();
 
         // Assert: `networks:` must appear and list the metrics network.
         assert!(
@@ -7,7 +7,7 @@ use std::path::Path;
 
 use crate::domain::template::file::File;
 use crate::domain::template::{
-    write_file_with_dir_creation, FileOperationError, TemplateEngineError,
+    write_file_with_dir_creation, ExtraTemplateVariables, FileOperationError, TemplateEngineError,
 };
 
 use super::context::EnvContext;
@@ -39,8 +39,13 @@ impl EnvTemplate {
     /// - Template syntax is invalid
     /// - Required variables cannot be substituted
     /// - Template validation fails
-    pub fn new(template_file: &File, env_context: EnvContext) -> Result<Self, TemplateEngineError> {
-        let mut engine = crate::domain::template::TemplateEngine::new();
+    pub fn new(
+        template_file: &File,
+        env_context: EnvContext,
+        extra_variables: &ExtraTemplateVariables,
+    ) -> Result<Self, TemplateEngineError> {
+        let mut engine =
+            crate::domain::template::TemplateEngine::new().with_extra_variables(extra_variables);
 
         let validated_content = engine.render(
             template_file.filename(),
@@ -98,7 +103,12 @@ mod tests {
 
         let metadata = create_test_metadata();
         let env_context = EnvContext::new(metadata, "MyToken123".to_string());
-        let template = EnvTemplate::new(&template_file, env_context).unwrap();
+        let template = EnvTemplate::new(
+            &template_file,
+            env_context,
+            &ExtraTemplateVariables::default(),
+        )
+        .unwrap();
 
         assert_eq!(template.tracker_api_admin_token(), "MyToken123");
     }
@@ -111,7 +121,12 @@ mod tests {
 
         let metadata = create_test_metadata();
         let env_context = EnvContext::new(metadata, "SecretToken".to_string());
-        let template = EnvTemplate::new(&template_file, env_context).unwrap();
+        let template = EnvTemplate::new(
+            &template_file,
+            env_context,
+            &ExtraTemplateVariables::default(),
+        )
+        .unwrap();
 
         // Verify the content has the substituted value
         assert!(template.content.contains("TOKEN=SecretToken"));
@@ -123,7 +138,11 @@ mod tests {
 
         let metadata = create_test_metadata();
         let env_context = EnvContext::new(metadata, "TestToken".to_string());
-        let result = EnvTemplate::new(&template_file, env_context);
+        let result = EnvTemplate::new(
+            &template_file,
+            env_context,
+            &ExtraTemplateVariables::default(),
+        );
 
         // Empty templates are valid in Tera
         assert!(result.is_ok());
@@ -140,7 +159,11 @@ mod tests {
 
         let metadata = create_test_metadata();
         let env_context = EnvContext::new(metadata, "UnusedToken".to_string());
-        let result = EnvTemplate::new(&template_file, env_context);
+        let result = EnvTemplate::new(
+            &template_file,
+            env_context,
+            &ExtraTemplateVariables::default(),
+        );
 
         // Templates don't need to use all available context variables
         assert!(result.is_ok());
@@ -157,7 +180,12 @@ mod tests {
 
         let metadata = create_test_metadata();
         let env_context = EnvContext::new(metadata, "FileTestToken".to_string());
-        let template = EnvTemplate::new(&template_file, env_context).unwrap();
+        let template = EnvTemplate::new(
+            &template_file,
+            env_context,
+            &ExtraTemplateVariables::default(),
+        )
+        .unwrap();
 
         // Create temp directory for output
         let temp_dir = TempDir::new().unwrap();
@@ -102,7 +102,8 @@ impl DatasourceRenderer {
         })?;
 
         // 3. Create template wrapper
-        let template = DatasourceTemplate::new(template_content, context.clone());
+        let template = DatasourceTemplate::new(template_content, context.clone())
+            .with_extra_variables(self.template_manager.extra_variables());
 
         // 4. Render to output file
         let output_path = output_dir.join(Self::DATASOURCE_OUTPUT_FILE);
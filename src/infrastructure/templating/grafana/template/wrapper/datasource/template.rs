@@ -6,6 +6,8 @@ use std::path::Path;
 
 use thiserror::Error;
 
+use crate::domain::template::ExtraTemplateVariables;
+
 use super::DatasourceContext;
 
 /// Errors that can occur during datasource template processing
@@ -36,6 +38,7 @@ pub enum DatasourceTemplateError {
 pub struct DatasourceTemplate {
     content: String,
     context: DatasourceContext,
+    extra_variables: ExtraTemplateVariables,
 }
 
 impl DatasourceTemplate {
@@ -53,9 +56,17 @@ impl DatasourceTemplate {
         Self {
             content: template_content,
             context,
+            extra_variables: ExtraTemplateVariables::default(),
         }
     }
 
+    /// Sets the user-defined variables added to the rendering context
+    #[must_use]
+    pub fn with_extra_variables(mut self, extra_variables: &ExtraTemplateVariables) -> Self {
+        self.extra_variables = extra_variables.clone();
+        self
+    }
+
     /// Renders the template to an output file
     ///
     /// # Arguments
@@ -74,10 +85,10 @@ impl DatasourceTemplate {
         tera.add_raw_template(Self::TEMPLATE_NAME, &self.content)?;
 
         // Render template with context
-        let rendered_content = tera.render(
-            Self::TEMPLATE_NAME,
-            &tera::Context::from_serialize(&self.context)?,
-        )?;
+        let mut context = tera::Context::from_serialize(&self.context)?;
+        self.extra_variables
+            .merge_into(&mut context, Self::TEMPLATE_NAME);
+        let rendered_content = tera.render(Self::TEMPLATE_NAME, &context)?;
 
         // Write to file
         std::fs::write(output_path, rendered_content).map_err(|source| {
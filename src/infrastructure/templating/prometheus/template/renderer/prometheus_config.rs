@@ -104,7 +104,8 @@ impl PrometheusConfigRenderer {
         })?;
 
         // 3. Create PrometheusTemplate with context
-        let template = PrometheusTemplate::new(template_content, context.clone())?;
+        let template = PrometheusTemplate::new(template_content, context.clone())?
+            .with_extra_variables(self.template_manager.extra_variables());
 
         // 4. Render to output file
         let output_path = output_dir.join(Self::PROMETHEUS_OUTPUT_FILE);
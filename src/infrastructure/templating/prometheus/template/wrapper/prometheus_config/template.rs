@@ -7,6 +7,8 @@ use std::path::Path;
 use tera::Tera;
 use thiserror::Error;
 
+use crate::domain::template::ExtraTemplateVariables;
+
 use super::context::PrometheusContext;
 
 /// Errors that can occur during Prometheus template operations
@@ -43,6 +45,8 @@ pub struct PrometheusTemplate {
     content: String,
     /// The rendering context
     context: PrometheusContext,
+    /// User-defined variables added to the rendering context
+    extra_variables: ExtraTemplateVariables,
 }
 
 impl PrometheusTemplate {
@@ -67,9 +71,17 @@ impl PrometheusTemplate {
         Ok(Self {
             content: template_content,
             context,
+            extra_variables: ExtraTemplateVariables::default(),
         })
     }
 
+    /// Sets the user-defined variables added to the rendering context
+    #[must_use]
+    pub fn with_extra_variables(mut self, extra_variables: &ExtraTemplateVariables) -> Self {
+        self.extra_variables = extra_variables.clone();
+        self
+    }
+
     /// Renders the template with the context
     ///
     /// # Returns
@@ -84,8 +96,10 @@ impl PrometheusTemplate {
         tera.add_raw_template("prometheus.yml", &self.content)
             .map_err(|e| PrometheusTemplateError::RenderingFailed(e.to_string()))?;
 
-        let context = tera::Context::from_serialize(&self.context)
+        let mut context = tera::Context::from_serialize(&self.context)
             .map_err(|e| PrometheusTemplateError::RenderingFailed(e.to_string()))?;
+        self.extra_variables
+            .merge_into(&mut context, "prometheus.yml");
 
         tera.render("prometheus.yml", &context)
             .map_err(|e| PrometheusTemplateError::RenderingFailed(e.to_string()))
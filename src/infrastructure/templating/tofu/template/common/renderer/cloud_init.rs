@@ -11,7 +11,7 @@
 //! - Managing SSH public key injection into cloud-init configuration
 //! - Creating appropriate contexts from SSH credentials
 //! - Rendering the template to the output directory
-//! - Using a common cloud-init template shared by all providers (LXD, Hetzner)
+//! - Using a common cloud-init template shared by all providers (LXD, Hetzner, `DigitalOcean`, libvirt)
 //!
 //! This follows the collaborator pattern established in the Ansible template renderer refactoring.
 //!
@@ -50,9 +50,11 @@ use std::sync::Arc;
 use thiserror::Error;
 
 use crate::adapters::ssh::credentials::SshCredentials;
+use crate::domain::provision::{CloudInitCustomization, OfflineMode, ProxyConfig};
 use crate::domain::template::file::File;
 use crate::domain::template::{TemplateManager, TemplateManagerError};
 use crate::infrastructure::templating::metadata::TemplateMetadata;
+use crate::infrastructure::templating::tofu::template::common::wrappers::cloud_init::CloudInitMergeError;
 use crate::shared::clock::Clock;
 
 /// Errors that can occur during cloud-init template rendering
@@ -88,6 +90,13 @@ pub enum CloudInitRendererError {
     #[error("Failed to create cloud-init template: Template validation or context binding failed")]
     CloudInitTemplateCreationFailed,
 
+    /// User additions could not be merged into the rendered cloud-init configuration
+    #[error("Failed to merge the 'cloud_init' additions: {source}")]
+    CustomizationMergeFailed {
+        #[source]
+        source: CloudInitMergeError,
+    },
+
     /// Failed to render cloud-init template to output file
     #[error("Failed to render cloud-init template: Template rendering or file write failed")]
     CloudInitTemplateRenderFailed,
@@ -98,16 +107,20 @@ pub enum CloudInitRendererError {
 /// This collaborator handles all cloud-init template specific logic, including:
 /// - Template path resolution (using common template shared by all providers)
 /// - SSH public key reading and context creation
+/// - Merging the user additions into the rendered configuration
 /// - Template rendering and output file writing
 ///
 /// It follows the Single Responsibility Principle by focusing solely on cloud-init
 /// template operations, making the main `TofuProjectGenerator` simpler and more focused.
 ///
-/// All providers (LXD, Hetzner) use the same common cloud-init template, so no
+/// All providers (LXD, Hetzner, `DigitalOcean`, libvirt) use the same common cloud-init template, so no
 /// provider-specific logic is needed.
 pub struct CloudInitRenderer {
     template_manager: Arc<TemplateManager>,
     clock: Arc<dyn Clock>,
+    customization: CloudInitCustomization,
+    offline: Option<OfflineMode>,
+    proxy: Option<ProxyConfig>,
 }
 
 impl CloudInitRenderer {
@@ -119,7 +132,7 @@ impl CloudInitRenderer {
 
     /// Base path for common `OpenTofu` templates shared by all providers
     ///
-    /// Templates in this directory are used by all infrastructure providers (LXD, Hetzner).
+    /// Templates in this directory are used by all infrastructure providers (LXD, Hetzner, `DigitalOcean`, libvirt).
     const COMMON_TEMPLATES_PATH: &'static str = "tofu/common";
 
     /// Creates a new cloud-init template renderer
@@ -137,16 +150,48 @@ impl CloudInitRenderer {
         Self {
             template_manager,
             clock,
+            customization: CloudInitCustomization::default(),
+            offline: None,
+            proxy: None,
         }
     }
 
+    /// Sets the user additions merged into the rendered configuration
+    ///
+    /// Without additions the rendered template is written unchanged.
+    #[must_use]
+    pub fn with_customization(mut self, customization: CloudInitCustomization) -> Self {
+        self.customization = customization;
+        self
+    }
+
+    /// Sets the offline mode the instance is deployed with
+    ///
+    /// In offline mode the first boot never refreshes the package index from
+    /// the Ubuntu archive.
+    #[must_use]
+    pub fn with_offline_mode(mut self, offline: Option<OfflineMode>) -> Self {
+        self.offline = offline;
+        self
+    }
+
+    /// Sets the outbound proxy the instance downloads packages through
+    ///
+    /// The first boot configures apt to use it.
+    #[must_use]
+    pub fn with_proxy(mut self, proxy: Option<ProxyConfig>) -> Self {
+        self.proxy = proxy;
+        self
+    }
+
     /// Renders the cloud-init.yml.tera template with SSH credentials
     ///
     /// This method performs the complete cloud-init template rendering workflow:
     /// 1. Resolves the template path and reads template content
     /// 2. Creates a cloud-init context from SSH credentials
     /// 3. Renders the template with the context
-    /// 4. Writes the rendered output to the destination directory
+    /// 4. Merges the user additions into the rendered configuration
+    /// 5. Writes the result to the destination directory
     ///
     /// # Arguments
     ///
@@ -168,6 +213,7 @@ impl CloudInitRenderer {
     /// - `SshKeyReadError` if the SSH public key file cannot be read
     /// - `ContextCreationFailed` if the cloud-init context cannot be built
     /// - `CloudInitTemplateCreationFailed` if template creation fails
+    /// - `CustomizationMergeFailed` if the user additions conflict with the rendered configuration
     /// - `CloudInitTemplateRenderFailed` if template rendering or file writing fails
     pub async fn render(
         &self,
@@ -200,7 +246,7 @@ impl CloudInitRenderer {
         let metadata = TemplateMetadata::new(self.clock.now());
 
         // Render cloud-init template (shared logic for all providers)
-        Self::render_cloud_init(
+        self.render_cloud_init(
             &template_file,
             ssh_credentials,
             ssh_port,
@@ -211,6 +257,7 @@ impl CloudInitRenderer {
 
     /// Renders cloud-init template (shared logic for all providers)
     fn render_cloud_init(
+        &self,
         template_file: &File,
         ssh_credentials: &SshCredentials,
         ssh_port: u16,
@@ -229,12 +276,20 @@ impl CloudInitRenderer {
             .with_username(ssh_credentials.ssh_username.as_str())
             .map_err(|_| CloudInitRendererError::ContextCreationFailed)?
             .with_ssh_port(ssh_port)
+            .with_offline_mode(self.offline.as_ref())
+            .with_proxy(self.proxy.as_ref())
             .build()
             .map_err(|_| CloudInitRendererError::ContextCreationFailed)?;
 
         // Create CloudInitTemplate with context
-        let cloud_init_template = CloudInitTemplate::new(template_file, cloud_init_context)
-            .map_err(|_| CloudInitRendererError::CloudInitTemplateCreationFailed)?;
+        let cloud_init_template = CloudInitTemplate::new(
+            template_file,
+            cloud_init_context,
+            self.template_manager.extra_variables(),
+        )
+        .map_err(|_| CloudInitRendererError::CloudInitTemplateCreationFailed)?
+        .with_customization(&self.customization)
+        .map_err(|source| CloudInitRendererError::CustomizationMergeFailed { source })?;
 
         // Render template to output file
         let output_path = output_dir.join(Self::CLOUD_INIT_OUTPUT_FILE);
@@ -272,6 +327,8 @@ mod tests {
     use std::fs;
     use tempfile::TempDir;
 
+    use crate::domain::template::embedded::EmbeddedTemplates;
+    use crate::domain::template::ExtraTemplateVariables;
     use crate::shared::Username;
     use crate::testing::mock_clock::MockClock;
     use chrono::DateTime;
@@ -378,6 +435,82 @@ users:
         );
     }
 
+    #[tokio::test]
+    async fn it_should_merge_extra_packages_into_the_rendered_configuration() {
+        let template_manager = create_mock_template_manager_with_cloud_init();
+        let clock = Arc::new(MockClock::new(DateTime::UNIX_EPOCH));
+        let customization =
+            CloudInitCustomization::new(vec!["htop".to_string()], vec![], None).unwrap();
+        let renderer =
+            CloudInitRenderer::new(template_manager, clock).with_customization(customization);
+
+        let temp_dir = TempDir::new().expect("Failed to create temp dir");
+        let ssh_credentials = create_mock_ssh_credentials(temp_dir.path());
+        let output_dir = TempDir::new().expect("Failed to create output dir");
+
+        renderer
+            .render(&ssh_credentials, 22, output_dir.path())
+            .await
+            .expect("Cloud-init template rendering should succeed");
+
+        let content = fs::read_to_string(output_dir.path().join("cloud-init.yml"))
+            .expect("Failed to read rendered file");
+        assert!(content.starts_with("#cloud-config\n"));
+        assert!(
+            content.contains("packages:\n- htop\n"),
+            "Rendered content should install htop: {content}"
+        );
+        assert!(content.contains("ssh-rsa AAAAB3NzaC1yc2EAAAADAQABAAABAQC7"));
+    }
+
+    #[tokio::test]
+    async fn it_should_render_extra_template_variables_in_an_overridden_template() {
+        let override_dir = TempDir::new().expect("Failed to create override dir");
+        let relative_path = CloudInitRenderer::build_template_path("cloud-init.yml.tera");
+        let embedded = EmbeddedTemplates::get(&relative_path).expect("Embedded cloud-init");
+        let override_path = override_dir.path().join(&relative_path);
+        fs::create_dir_all(override_path.parent().unwrap()).unwrap();
+        fs::write(
+            &override_path,
+            format!(
+                "{}\n# motd: {{{{ motd }}}}\n",
+                std::str::from_utf8(&embedded.data).unwrap()
+            ),
+        )
+        .unwrap();
+
+        let templates_dir = TempDir::new().expect("Failed to create templates dir");
+        let extra_variables =
+            ExtraTemplateVariables::try_from(std::collections::BTreeMap::from([(
+                "motd".to_string(),
+                serde_json::json!("hello"),
+            )]))
+            .unwrap();
+        let template_manager = Arc::new(
+            TemplateManager::new(templates_dir.path())
+                .with_override_dir(Some(override_dir.path().to_path_buf()))
+                .with_extra_variables(extra_variables),
+        );
+        let clock = Arc::new(MockClock::new(DateTime::UNIX_EPOCH));
+        let renderer = CloudInitRenderer::new(template_manager, clock);
+
+        let temp_dir = TempDir::new().expect("Failed to create temp dir");
+        let ssh_credentials = create_mock_ssh_credentials(temp_dir.path());
+        let output_dir = TempDir::new().expect("Failed to create output dir");
+
+        renderer
+            .render(&ssh_credentials, 22, output_dir.path())
+            .await
+            .expect("Cloud-init template rendering should succeed");
+
+        let content = fs::read_to_string(output_dir.path().join("cloud-init.yml"))
+            .expect("Failed to read rendered file");
+        assert!(
+            content.contains("# motd: hello"),
+            "Rendered content should contain the extra variable: {content}"
+        );
+    }
+
     // #[tokio::test]
     // async fn it_should_fail_when_template_manager_cannot_find_template() {
     //     // This test is disabled for now as template manager behavior may vary
@@ -414,6 +547,8 @@ users:
         }
     }
 
+    // The read-only attribute does not prevent creating files in a directory on Windows
+    #[cfg(unix)]
     #[tokio::test]
     async fn it_should_fail_when_output_directory_is_readonly() {
         let template_manager = create_mock_template_manager_with_cloud_init();
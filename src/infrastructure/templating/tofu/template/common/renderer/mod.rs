@@ -6,7 +6,7 @@
 //!
 //! ## Provider Support
 //!
-//! The renderer supports multiple infrastructure providers (LXD, Hetzner) with independent
+//! The renderer supports multiple infrastructure providers (LXD, Hetzner, `DigitalOcean`) with independent
 //! template sets for each provider. Templates are not shared between providers to allow
 //! provider-specific customization.
 //!
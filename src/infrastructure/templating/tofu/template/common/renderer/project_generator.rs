@@ -6,7 +6,7 @@
 //!
 //! ## Provider Support
 //!
-//! The generator supports multiple infrastructure providers (LXD, Hetzner) with independent
+//! The generator supports multiple infrastructure providers (LXD, Hetzner, `DigitalOcean`, Docker, libvirt) with independent
 //! template sets for each provider. Templates are not shared between providers to allow
 //! provider-specific customization.
 
@@ -15,13 +15,21 @@ use std::sync::Arc;
 use thiserror::Error;
 
 use crate::adapters::ssh::credentials::SshCredentials;
+use crate::domain::firewall::FirewallRule as HostFirewallRule;
+use crate::domain::provision::{
+    CloudInitCustomization, InstanceResources, OfflineMode, ProxyConfig,
+};
 use crate::domain::provider::{Provider, ProviderConfig};
 use crate::domain::template::{TemplateManager, TemplateManagerError};
+use crate::domain::topology::PortBinding;
 use crate::domain::InstanceName;
 use crate::infrastructure::templating::metadata::TemplateMetadata;
 use crate::infrastructure::templating::tofu::template::common::renderer::cloud_init::{
     CloudInitRenderer, CloudInitRendererError,
 };
+use crate::infrastructure::templating::tofu::template::providers::digitalocean::wrappers::variables::VariablesTemplateError as DigitalOceanVariablesTemplateError;
+use crate::infrastructure::templating::tofu::template::providers::docker::wrappers::variables::VariablesTemplateError as DockerVariablesTemplateError;
+use crate::infrastructure::templating::tofu::template::providers::libvirt::wrappers::variables::VariablesTemplateError as LibvirtVariablesTemplateError;
 use crate::infrastructure::templating::tofu::template::providers::hetzner::wrappers::variables::VariablesTemplateError as HetznerVariablesTemplateError;
 use crate::infrastructure::templating::tofu::template::providers::lxd::wrappers::variables::{
     VariablesContextBuilder as LxdVariablesContextBuilder,
@@ -81,6 +89,39 @@ pub enum TofuProjectGeneratorError {
     #[error("Failed to build Hetzner template context: {message}")]
     HetznerContextBuildFailed { message: String },
 
+    /// Failed to render `DigitalOcean` variables template
+    #[error("Failed to render DigitalOcean variables template: {source}")]
+    DigitalOceanVariablesRenderingFailed {
+        #[source]
+        source: DigitalOceanVariablesTemplateError,
+    },
+
+    /// Failed to build `DigitalOcean` template context
+    #[error("Failed to build DigitalOcean template context: {message}")]
+    DigitalOceanContextBuildFailed { message: String },
+
+    /// Failed to render Docker variables template
+    #[error("Failed to render Docker variables template: {source}")]
+    DockerVariablesRenderingFailed {
+        #[source]
+        source: DockerVariablesTemplateError,
+    },
+
+    /// Failed to build Docker template context
+    #[error("Failed to build Docker template context: {message}")]
+    DockerContextBuildFailed { message: String },
+
+    /// Failed to render libvirt variables template
+    #[error("Failed to render libvirt variables template: {source}")]
+    LibvirtVariablesRenderingFailed {
+        #[source]
+        source: LibvirtVariablesTemplateError,
+    },
+
+    /// Failed to build libvirt template context
+    #[error("Failed to build libvirt template context: {message}")]
+    LibvirtContextBuildFailed { message: String },
+
     /// Provider configuration mismatch
     #[error("Provider configuration mismatch: expected {expected} provider but got different configuration")]
     ProviderConfigMismatch { expected: String },
@@ -114,6 +155,25 @@ impl crate::shared::Traceable for TofuProjectGeneratorError {
             Self::HetznerContextBuildFailed { message } => {
                 format!("TofuProjectGeneratorError: Hetzner context build failed: {message}")
             }
+            Self::DigitalOceanVariablesRenderingFailed { .. } => {
+                "TofuProjectGeneratorError: DigitalOcean variables template rendering failed"
+                    .to_string()
+            }
+            Self::DigitalOceanContextBuildFailed { message } => {
+                format!("TofuProjectGeneratorError: DigitalOcean context build failed: {message}")
+            }
+            Self::DockerVariablesRenderingFailed { .. } => {
+                "TofuProjectGeneratorError: Docker variables template rendering failed".to_string()
+            }
+            Self::DockerContextBuildFailed { message } => {
+                format!("TofuProjectGeneratorError: Docker context build failed: {message}")
+            }
+            Self::LibvirtVariablesRenderingFailed { .. } => {
+                "TofuProjectGeneratorError: libvirt variables template rendering failed".to_string()
+            }
+            Self::LibvirtContextBuildFailed { message } => {
+                format!("TofuProjectGeneratorError: libvirt context build failed: {message}")
+            }
             Self::ProviderConfigMismatch { expected } => {
                 format!("TofuProjectGeneratorError: Expected {expected} provider configuration")
             }
@@ -150,6 +210,10 @@ pub struct TofuProjectGenerator {
     instance_name: InstanceName,
     provider: Provider,
     provider_config: ProviderConfig,
+    public_ports: Vec<PortBinding>,
+    extra_firewall_rules: Vec<HostFirewallRule>,
+    instance_resources: InstanceResources,
+    offline: Option<OfflineMode>,
     _clock: Arc<dyn Clock>,
 }
 
@@ -188,13 +252,81 @@ impl TofuProjectGenerator {
             instance_name,
             provider,
             provider_config,
+            public_ports: Vec::new(),
+            extra_firewall_rules: Vec::new(),
+            instance_resources: InstanceResources::default(),
+            offline: None,
             _clock: clock,
         }
     }
 
+    /// Sets the ports that must be reachable from outside the instance
+    ///
+    /// Used by providers with a cloud firewall (Hetzner, `DigitalOcean`) to open these ports
+    /// in addition to the SSH port, and by Docker to publish them on the host. Ignored by LXD.
+    #[must_use]
+    pub fn with_public_ports(mut self, public_ports: Vec<PortBinding>) -> Self {
+        self.public_ports = public_ports;
+        self
+    }
+
+    /// Sets the extra allow rules of the environment's firewall configuration
+    ///
+    /// Opened in the cloud firewall after the public ports so traffic the
+    /// host firewall allows is not dropped before reaching the instance.
+    /// Ignored by LXD.
+    #[must_use]
+    pub fn with_extra_firewall_rules(
+        mut self,
+        extra_firewall_rules: Vec<HostFirewallRule>,
+    ) -> Self {
+        self.extra_firewall_rules = extra_firewall_rules;
+        self
+    }
+
+    /// Sets the user additions merged into the cloud-init configuration
+    ///
+    /// Ignored by Docker, whose containers do not run cloud-init.
+    #[must_use]
+    pub fn with_cloud_init_customization(mut self, customization: CloudInitCustomization) -> Self {
+        self.cloud_init_renderer = self.cloud_init_renderer.with_customization(customization);
+        self
+    }
+
+    /// Sets the CPU, memory and disk of the instance
+    ///
+    /// Used by LXD, which sizes the instance in its profile. Ignored by cloud
+    /// providers, which size it through their server type.
+    #[must_use]
+    pub fn with_instance_resources(mut self, instance_resources: InstanceResources) -> Self {
+        self.instance_resources = instance_resources;
+        self
+    }
+
+    /// Sets the pre-seeded artifacts used to deploy offline
+    ///
+    /// LXD creates the instance from the local image alias, and cloud-init
+    /// uses the apt mirror instead of refreshing the package index from the
+    /// Ubuntu archive.
+    #[must_use]
+    pub fn with_offline_mode(mut self, offline: Option<OfflineMode>) -> Self {
+        self.cloud_init_renderer = self.cloud_init_renderer.with_offline_mode(offline.clone());
+        self.offline = offline;
+        self
+    }
+
+    /// Sets the outbound proxy the instance downloads packages through
+    ///
+    /// Cloud-init configures apt to use it on the first boot.
+    #[must_use]
+    pub fn with_proxy(mut self, proxy: Option<ProxyConfig>) -> Self {
+        self.cloud_init_renderer = self.cloud_init_renderer.with_proxy(proxy);
+        self
+    }
+
     /// Returns the relative path for `OpenTofu` configuration files based on provider
-    fn opentofu_build_path(&self) -> String {
-        format!("tofu/{}", self.provider.as_str())
+    fn opentofu_build_path(&self) -> PathBuf {
+        Path::new("tofu").join(self.provider.as_str())
     }
 
     /// Returns the template path prefix for `OpenTofu` templates based on provider
@@ -259,7 +391,7 @@ impl TofuProjectGenerator {
 
     /// Returns the list of static template files for the current provider
     ///
-    /// Both LXD and Hetzner currently use the same static template file (main.tf).
+    /// All providers currently use the same static template file (main.tf).
     /// This method exists to allow provider-specific customization in the future
     /// if different providers need different static files.
     #[allow(clippy::match_same_arms)]
@@ -267,6 +399,9 @@ impl TofuProjectGenerator {
         match self.provider {
             Provider::Lxd => vec!["main.tf"],
             Provider::Hetzner => vec!["main.tf"],
+            Provider::DigitalOcean => vec!["main.tf"],
+            Provider::Docker => vec!["main.tf"],
+            Provider::Libvirt => vec!["main.tf"],
         }
     }
 
@@ -462,6 +597,17 @@ impl TofuProjectGenerator {
                 self.render_hetzner_variables_template(&template_file, destination_dir)
                     .await
             }
+            Provider::DigitalOcean => {
+                self.render_digitalocean_variables_template(&template_file, destination_dir)
+                    .await
+            }
+            Provider::Docker => {
+                self.render_docker_variables_template(&template_file, destination_dir)
+                    .await
+            }
+            Provider::Libvirt => {
+                self.render_libvirt_variables_template(&template_file, destination_dir)
+            }
         }
     }
 
@@ -481,25 +627,34 @@ impl TofuProjectGenerator {
         // Build LXD context for template rendering
         #[allow(clippy::used_underscore_binding)]
         let metadata = TemplateMetadata::new(self._clock.now());
-        let context = LxdVariablesContextBuilder::new()
+        let mut builder = LxdVariablesContextBuilder::new()
             .with_metadata(metadata)
             .with_instance_name(self.instance_name.clone())
             .with_profile_name(lxd_config.profile_name.clone())
-            .build()
-            .map_err(
-                |err| TofuProjectGeneratorError::LxdVariablesRenderingFailed {
-                    source: LxdVariablesTemplateError::TemplateEngineError {
-                        source:
-                            crate::domain::template::TemplateEngineError::ContextSerialization {
-                                source: tera::Error::msg(err.to_string()),
-                            },
+            .with_instance_resources(self.instance_resources);
+        if let Some(offline) = &self.offline {
+            builder = builder.with_image(offline.image_alias());
+        }
+        if let Some(remote) = &lxd_config.remote {
+            builder = builder.with_remote(remote.clone());
+        }
+        let context = builder.build().map_err(|err| {
+            TofuProjectGeneratorError::LxdVariablesRenderingFailed {
+                source: LxdVariablesTemplateError::TemplateEngineError {
+                    source: crate::domain::template::TemplateEngineError::ContextSerialization {
+                        source: tera::Error::msg(err.to_string()),
                     },
                 },
-            )?;
+            }
+        })?;
 
         // Create and render the variables template
-        let variables_template = LxdVariablesTemplate::new(template_file, context)
-            .map_err(|source| TofuProjectGeneratorError::LxdVariablesRenderingFailed { source })?;
+        let variables_template = LxdVariablesTemplate::new(
+            template_file,
+            context,
+            self.template_manager.extra_variables(),
+        )
+        .map_err(|source| TofuProjectGeneratorError::LxdVariablesRenderingFailed { source })?;
 
         // Write the rendered template to the destination directory
         let output_path = destination_dir.join("variables.tfvars");
@@ -518,7 +673,7 @@ impl TofuProjectGenerator {
         destination_dir: &Path,
     ) -> Result<(), TofuProjectGeneratorError> {
         use crate::infrastructure::templating::tofu::template::providers::hetzner::wrappers::variables::{
-            VariablesContextBuilder as HetznerVariablesContextBuilder,
+            FirewallRule, VariablesContextBuilder as HetznerVariablesContextBuilder,
             VariablesTemplate as HetznerVariablesTemplate,
         };
 
@@ -538,6 +693,12 @@ impl TofuProjectGenerator {
                     source,
                 })?;
 
+        // SSH is always open; then the services' port derivation and the user's extra rules
+        let firewall_rules = std::iter::once(FirewallRule::ssh(self.ssh_port))
+            .chain(self.public_ports.iter().map(FirewallRule::from))
+            .chain(self.extra_firewall_rules.iter().map(FirewallRule::from))
+            .collect();
+
         // Build Hetzner context for template rendering
         #[allow(clippy::used_underscore_binding)]
         let metadata = TemplateMetadata::new(self._clock.now());
@@ -549,16 +710,19 @@ impl TofuProjectGenerator {
             .with_server_location(hetzner_config.location.clone())
             .with_server_image(hetzner_config.image.clone())
             .with_ssh_public_key_content(ssh_public_key_content.trim().to_string())
+            .with_firewall_rules(firewall_rules)
             .build()
             .map_err(|err| TofuProjectGeneratorError::HetznerContextBuildFailed {
                 message: err.to_string(),
             })?;
 
         // Create and render the variables template
-        let variables_template =
-            HetznerVariablesTemplate::new(template_file, context).map_err(|source| {
-                TofuProjectGeneratorError::HetznerVariablesRenderingFailed { source }
-            })?;
+        let variables_template = HetznerVariablesTemplate::new(
+            template_file,
+            context,
+            self.template_manager.extra_variables(),
+        )
+        .map_err(|source| TofuProjectGeneratorError::HetznerVariablesRenderingFailed { source })?;
 
         // Write the rendered template to the destination directory
         let output_path = destination_dir.join("variables.tfvars");
@@ -569,6 +733,190 @@ impl TofuProjectGenerator {
         tracing::debug!("Hetzner variables template rendered successfully");
         Ok(())
     }
+
+    /// Renders `DigitalOcean`-specific variables template
+    async fn render_digitalocean_variables_template(
+        &self,
+        template_file: &crate::domain::template::file::File,
+        destination_dir: &Path,
+    ) -> Result<(), TofuProjectGeneratorError> {
+        use crate::infrastructure::templating::tofu::template::providers::digitalocean::wrappers::variables::{
+            FirewallRule, VariablesContextBuilder as DigitalOceanVariablesContextBuilder,
+            VariablesTemplate as DigitalOceanVariablesTemplate,
+        };
+
+        // Get DigitalOcean config
+        let digitalocean_config = self.provider_config.as_digitalocean().ok_or_else(|| {
+            TofuProjectGeneratorError::ProviderConfigMismatch {
+                expected: "DigitalOcean".to_string(),
+            }
+        })?;
+
+        // Read SSH public key content
+        let ssh_public_key_content =
+            tokio::fs::read_to_string(&self.ssh_credentials.ssh_pub_key_path)
+                .await
+                .map_err(|source| TofuProjectGeneratorError::FileCopyFailed {
+                    file_name: "ssh public key".to_string(),
+                    source,
+                })?;
+
+        // SSH is always open; then the services' port derivation and the user's extra rules
+        let firewall_rules = std::iter::once(FirewallRule::ssh(self.ssh_port))
+            .chain(self.public_ports.iter().map(FirewallRule::from))
+            .chain(self.extra_firewall_rules.iter().map(FirewallRule::from))
+            .collect();
+
+        // Build DigitalOcean context for template rendering
+        #[allow(clippy::used_underscore_binding)]
+        let metadata = TemplateMetadata::new(self._clock.now());
+        let context = DigitalOceanVariablesContextBuilder::new()
+            .with_metadata(metadata)
+            .with_instance_name(self.instance_name.clone())
+            .with_do_api_token(digitalocean_config.api_token.expose_secret().to_string())
+            .with_droplet_size(digitalocean_config.droplet_size.clone())
+            .with_droplet_region(digitalocean_config.region.clone())
+            .with_droplet_image(digitalocean_config.image.clone())
+            .with_ssh_public_key_content(ssh_public_key_content.trim().to_string())
+            .with_reserved_ip(digitalocean_config.reserved_ip)
+            .with_firewall_rules(firewall_rules)
+            .build()
+            .map_err(
+                |err| TofuProjectGeneratorError::DigitalOceanContextBuildFailed {
+                    message: err.to_string(),
+                },
+            )?;
+
+        // Create and render the variables template
+        let variables_template = DigitalOceanVariablesTemplate::new(
+            template_file,
+            context,
+            self.template_manager.extra_variables(),
+        )
+        .map_err(|source| {
+            TofuProjectGeneratorError::DigitalOceanVariablesRenderingFailed { source }
+        })?;
+
+        // Write the rendered template to the destination directory
+        let output_path = destination_dir.join("variables.tfvars");
+        variables_template.render(&output_path).map_err(|source| {
+            TofuProjectGeneratorError::DigitalOceanVariablesRenderingFailed { source }
+        })?;
+
+        tracing::debug!("DigitalOcean variables template rendered successfully");
+        Ok(())
+    }
+
+    /// Renders Docker-specific variables template
+    async fn render_docker_variables_template(
+        &self,
+        template_file: &crate::domain::template::file::File,
+        destination_dir: &Path,
+    ) -> Result<(), TofuProjectGeneratorError> {
+        use crate::infrastructure::templating::tofu::template::providers::docker::wrappers::variables::{
+            PublishedPort, VariablesContextBuilder as DockerVariablesContextBuilder,
+            VariablesTemplate as DockerVariablesTemplate,
+        };
+
+        // Get Docker config
+        let docker_config = self.provider_config.as_docker().ok_or_else(|| {
+            TofuProjectGeneratorError::ProviderConfigMismatch {
+                expected: "Docker".to_string(),
+            }
+        })?;
+
+        // Read SSH public key content
+        let ssh_public_key_content =
+            tokio::fs::read_to_string(&self.ssh_credentials.ssh_pub_key_path)
+                .await
+                .map_err(|source| TofuProjectGeneratorError::FileCopyFailed {
+                    file_name: "ssh public key".to_string(),
+                    source,
+                })?;
+
+        // Build Docker context for template rendering
+        #[allow(clippy::used_underscore_binding)]
+        let metadata = TemplateMetadata::new(self._clock.now());
+        let context = DockerVariablesContextBuilder::new()
+            .with_metadata(metadata)
+            .with_instance_name(self.instance_name.clone())
+            .with_image(docker_config.image.clone())
+            .with_ssh_public_key_content(ssh_public_key_content.trim().to_string())
+            .with_ssh_username(self.ssh_credentials.ssh_username.as_str().to_string())
+            .with_ssh_port(self.ssh_port)
+            .with_published_ports(self.public_ports.iter().map(PublishedPort::from).collect())
+            .build()
+            .map_err(|err| TofuProjectGeneratorError::DockerContextBuildFailed {
+                message: err.to_string(),
+            })?;
+
+        // Create and render the variables template
+        let variables_template = DockerVariablesTemplate::new(
+            template_file,
+            context,
+            self.template_manager.extra_variables(),
+        )
+        .map_err(|source| TofuProjectGeneratorError::DockerVariablesRenderingFailed { source })?;
+
+        // Write the rendered template to the destination directory
+        let output_path = destination_dir.join("variables.tfvars");
+        variables_template.render(&output_path).map_err(|source| {
+            TofuProjectGeneratorError::DockerVariablesRenderingFailed { source }
+        })?;
+
+        tracing::debug!("Docker variables template rendered successfully");
+        Ok(())
+    }
+
+    /// Renders libvirt-specific variables template
+    fn render_libvirt_variables_template(
+        &self,
+        template_file: &crate::domain::template::file::File,
+        destination_dir: &Path,
+    ) -> Result<(), TofuProjectGeneratorError> {
+        use crate::infrastructure::templating::tofu::template::providers::libvirt::wrappers::variables::{
+            VariablesContextBuilder as LibvirtVariablesContextBuilder,
+            VariablesTemplate as LibvirtVariablesTemplate,
+        };
+
+        // Get libvirt config
+        let libvirt_config = self.provider_config.as_libvirt().ok_or_else(|| {
+            TofuProjectGeneratorError::ProviderConfigMismatch {
+                expected: "libvirt".to_string(),
+            }
+        })?;
+
+        // Build libvirt context for template rendering
+        #[allow(clippy::used_underscore_binding)]
+        let metadata = TemplateMetadata::new(self._clock.now());
+        let context = LibvirtVariablesContextBuilder::new()
+            .with_metadata(metadata)
+            .with_instance_name(self.instance_name.clone())
+            .with_pool(libvirt_config.pool.clone())
+            .with_network_name(libvirt_config.network_name.clone())
+            .with_image(libvirt_config.image.clone())
+            .build()
+            .map_err(|err| TofuProjectGeneratorError::LibvirtContextBuildFailed {
+                message: err.to_string(),
+            })?;
+
+        // Create and render the variables template
+        let variables_template = LibvirtVariablesTemplate::new(
+            template_file,
+            context,
+            self.template_manager.extra_variables(),
+        )
+        .map_err(|source| TofuProjectGeneratorError::LibvirtVariablesRenderingFailed { source })?;
+
+        // Write the rendered template to the destination directory
+        let output_path = destination_dir.join("variables.tfvars");
+        variables_template.render(&output_path).map_err(|source| {
+            TofuProjectGeneratorError::LibvirtVariablesRenderingFailed { source }
+        })?;
+
+        tracing::debug!("libvirt variables template rendered successfully");
+        Ok(())
+    }
 }
 
 #[cfg(test)]
@@ -616,6 +964,7 @@ mod tests {
         use crate::domain::provider::LxdConfig;
         ProviderConfig::Lxd(LxdConfig {
             profile_name: fixture_profile_name(),
+            remote: None,
         })
     }
 
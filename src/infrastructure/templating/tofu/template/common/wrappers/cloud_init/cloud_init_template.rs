@@ -1,13 +1,14 @@
 //! `CloudInitTemplate` type and implementation.
 
+use crate::domain::provision::CloudInitCustomization;
 use crate::domain::template::file::File;
 use crate::domain::template::{
-    write_file_with_dir_creation, FileOperationError, TemplateEngineError,
+    write_file_with_dir_creation, ExtraTemplateVariables, FileOperationError, TemplateEngineError,
 };
 use anyhow::Result;
 use std::path::Path;
 
-use super::CloudInitContext;
+use super::{merge_customization, CloudInitContext, CloudInitMergeError};
 
 #[derive(Debug)]
 pub struct CloudInitTemplate {
@@ -32,8 +33,10 @@ impl CloudInitTemplate {
     pub fn new(
         template_file: &File,
         cloud_init_context: CloudInitContext,
+        extra_variables: &ExtraTemplateVariables,
     ) -> Result<Self, TemplateEngineError> {
-        let mut engine = crate::domain::template::TemplateEngine::new();
+        let mut engine =
+            crate::domain::template::TemplateEngine::new().with_extra_variables(extra_variables);
 
         let validated_content = engine.render(
             template_file.filename(),
@@ -47,6 +50,21 @@ impl CloudInitTemplate {
         })
     }
 
+    /// Merges user additions into the rendered content
+    ///
+    /// See [`merge_customization`] for the merge rules.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the additions conflict with the rendered content.
+    pub fn with_customization(
+        mut self,
+        customization: &CloudInitCustomization,
+    ) -> Result<Self, CloudInitMergeError> {
+        self.content = merge_customization(&self.content, customization)?;
+        Ok(self)
+    }
+
     /// Get the SSH public key value
     #[must_use]
     pub fn ssh_public_key(&self) -> &str {
@@ -95,7 +113,12 @@ mod tests {
 
         let ssh_key = "ssh-rsa AAAAB3NzaC1yc2EAAAADAQABAAABAQC... test@example.com";
         let cloud_init_context = create_cloud_init_context(ssh_key);
-        let template = CloudInitTemplate::new(&template_file, cloud_init_context).unwrap();
+        let template = CloudInitTemplate::new(
+            &template_file,
+            cloud_init_context,
+            &ExtraTemplateVariables::default(),
+        )
+        .unwrap();
 
         assert_eq!(template.ssh_public_key(), ssh_key);
     }
@@ -110,7 +133,12 @@ mod tests {
 
         let ssh_key = "ssh-rsa AAAAB3NzaC1yc2EAAAADAQABAAABAQC... test@example.com";
         let cloud_init_context = create_cloud_init_context(ssh_key);
-        let template = CloudInitTemplate::new(&template_file, cloud_init_context).unwrap();
+        let template = CloudInitTemplate::new(
+            &template_file,
+            cloud_init_context,
+            &ExtraTemplateVariables::default(),
+        )
+        .unwrap();
 
         assert_eq!(template.ssh_public_key(), ssh_key);
     }
@@ -122,7 +150,11 @@ mod tests {
 
         let ssh_key = "ssh-rsa AAAAB3NzaC1yc2EAAAADAQABAAABAQC... test@example.com";
         let cloud_init_context = create_cloud_init_context(ssh_key);
-        let result = CloudInitTemplate::new(&template_file, cloud_init_context);
+        let result = CloudInitTemplate::new(
+            &template_file,
+            cloud_init_context,
+            &ExtraTemplateVariables::default(),
+        );
 
         // Empty templates are valid in Tera - they just render as empty strings
         assert!(result.is_ok());
@@ -139,7 +171,11 @@ mod tests {
 
         let ssh_key = "ssh-rsa AAAAB3NzaC1yc2EAAAADAQABAAABAQC... test@example.com";
         let cloud_init_context = create_cloud_init_context(ssh_key);
-        let result = CloudInitTemplate::new(&template_file, cloud_init_context);
+        let result = CloudInitTemplate::new(
+            &template_file,
+            cloud_init_context,
+            &ExtraTemplateVariables::default(),
+        );
 
         // This is valid - templates don't need to use all available context variables
         assert!(result.is_ok());
@@ -156,7 +192,11 @@ mod tests {
 
         let ssh_key = "ssh-rsa AAAAB3NzaC1yc2EAAAADAQABAAABAQC... test@example.com";
         let cloud_init_context = create_cloud_init_context(ssh_key);
-        let result = CloudInitTemplate::new(&template_file, cloud_init_context);
+        let result = CloudInitTemplate::new(
+            &template_file,
+            cloud_init_context,
+            &ExtraTemplateVariables::default(),
+        );
 
         // Static templates are valid - they just don't use template variables
         assert!(result.is_ok());
@@ -174,7 +214,11 @@ mod tests {
 
         let ssh_key = "ssh-rsa AAAAB3NzaC1yc2EAAAADAQABAAABAQC... test@example.com";
         let cloud_init_context = create_cloud_init_context(ssh_key);
-        let result = CloudInitTemplate::new(&template_file, cloud_init_context);
+        let result = CloudInitTemplate::new(
+            &template_file,
+            cloud_init_context,
+            &ExtraTemplateVariables::default(),
+        );
 
         // This should fail because the template references an undefined variable
         assert!(result.is_err());
@@ -191,7 +235,11 @@ mod tests {
 
         let ssh_key = "ssh-rsa AAAAB3NzaC1yc2EAAAADAQABAAABAQC... test@example.com";
         let cloud_init_context = create_cloud_init_context(ssh_key);
-        let result = CloudInitTemplate::new(&template_file, cloud_init_context);
+        let result = CloudInitTemplate::new(
+            &template_file,
+            cloud_init_context,
+            &ExtraTemplateVariables::default(),
+        );
 
         // Should fail during template validation
         assert!(result.is_err());
@@ -206,7 +254,11 @@ mod tests {
 
         let ssh_key = "ssh-rsa AAAAB3NzaC1yc2EAAAADAQABAAABAQC... test@example.com";
         let cloud_init_context = create_cloud_init_context(ssh_key);
-        let result = CloudInitTemplate::new(&template_file, cloud_init_context);
+        let result = CloudInitTemplate::new(
+            &template_file,
+            cloud_init_context,
+            &ExtraTemplateVariables::default(),
+        );
 
         assert!(result.is_err());
     }
@@ -222,10 +274,97 @@ mod tests {
         // Template validation happens during construction, not during render
         let ssh_key = "ssh-rsa AAAAB3NzaC1yc2EAAAADAQABAAABAQC... test@example.com";
         let cloud_init_context = create_cloud_init_context(ssh_key);
-        let template = CloudInitTemplate::new(&template_file, cloud_init_context).unwrap();
+        let template = CloudInitTemplate::new(
+            &template_file,
+            cloud_init_context,
+            &ExtraTemplateVariables::default(),
+        )
+        .unwrap();
 
         // Verify that the template was pre-validated and contains rendered content
         assert_eq!(template.ssh_public_key(), ssh_key);
         assert!(template.content.contains(ssh_key));
     }
+
+    #[test]
+    fn it_should_point_the_shared_template_at_the_apt_mirror_in_offline_mode() {
+        use crate::domain::provision::OfflineMode;
+
+        let template_path = std::path::Path::new(env!("CARGO_MANIFEST_DIR"))
+            .join("templates/tofu/common/cloud-init.yml.tera");
+        let template_file = File::new(
+            "cloud-init.yml.tera",
+            std::fs::read_to_string(template_path).unwrap(),
+        )
+        .unwrap();
+        let offline = OfflineMode::new(
+            "local:torrust-ubuntu-24.04",
+            Some("http://mirror.lab.internal/ubuntu"),
+            None,
+            None,
+        )
+        .unwrap();
+        let context = CloudInitContext::builder(create_test_metadata())
+            .with_ssh_public_key("ssh-rsa AAAA test@example.com")
+            .unwrap()
+            .with_username("testuser")
+            .unwrap()
+            .with_offline_mode(Some(&offline))
+            .build()
+            .unwrap();
+
+        let template =
+            CloudInitTemplate::new(&template_file, context, &ExtraTemplateVariables::default())
+                .unwrap();
+
+        let document: serde_yaml::Value = serde_yaml::from_str(&template.content).unwrap();
+        assert_eq!(document["package_update"], false);
+        assert_eq!(document["package_upgrade"], false);
+        assert_eq!(
+            document["apt"]["primary"][0]["uri"],
+            "http://mirror.lab.internal/ubuntu"
+        );
+    }
+
+    #[test]
+    fn it_should_configure_the_apt_proxy_in_the_shared_template() {
+        use crate::domain::provision::ProxyConfig;
+
+        let template_path = std::path::Path::new(env!("CARGO_MANIFEST_DIR"))
+            .join("templates/tofu/common/cloud-init.yml.tera");
+        let template_file = File::new(
+            "cloud-init.yml.tera",
+            std::fs::read_to_string(template_path).unwrap(),
+        )
+        .unwrap();
+        let proxy = ProxyConfig::new(
+            Some("http://proxy.corp.internal:3128"),
+            Some("http://proxy.corp.internal:3128"),
+            vec![],
+        )
+        .unwrap();
+        let context = CloudInitContext::builder(create_test_metadata())
+            .with_ssh_public_key("ssh-rsa AAAA test@example.com")
+            .unwrap()
+            .with_username("testuser")
+            .unwrap()
+            .with_proxy(Some(&proxy))
+            .build()
+            .unwrap();
+
+        let template =
+            CloudInitTemplate::new(&template_file, context, &ExtraTemplateVariables::default())
+                .unwrap();
+
+        let document: serde_yaml::Value = serde_yaml::from_str(&template.content).unwrap();
+        assert_eq!(
+            document["apt"]["http_proxy"],
+            "http://proxy.corp.internal:3128/"
+        );
+        assert_eq!(
+            document["apt"]["https_proxy"],
+            "http://proxy.corp.internal:3128/"
+        );
+        assert!(document["apt"]["primary"].is_null());
+    }
 }
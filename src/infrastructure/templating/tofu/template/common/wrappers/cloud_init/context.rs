@@ -10,6 +10,7 @@ use std::path::Path;
 use thiserror::Error;
 
 use crate::adapters::ssh::SshPublicKey;
+use crate::domain::provision::{OfflineMode, ProxyConfig};
 use crate::infrastructure::templating::metadata::TemplateMetadata;
 use crate::shared::Username;
 
@@ -44,6 +45,17 @@ pub struct CloudInitContext {
     pub username: Username,
     /// SSH service port (default: 22)
     pub ssh_port: u16,
+    /// Whether the instance is deployed without Internet access
+    pub offline: bool,
+    /// Local apt mirror replacing the Ubuntu archive, only in offline mode
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub apt_mirror: Option<String>,
+    /// Outbound proxy of apt for plain HTTP requests, if any
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub http_proxy: Option<String>,
+    /// Outbound proxy of apt for HTTPS requests, if any
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub https_proxy: Option<String>,
 }
 
 /// Builder for `CloudInitContext` with fluent interface
@@ -53,6 +65,8 @@ pub struct CloudInitContextBuilder {
     ssh_public_key: Option<SshPublicKey>,
     username: Option<Username>,
     ssh_port: Option<u16>,
+    offline: Option<OfflineMode>,
+    proxy: Option<ProxyConfig>,
 }
 
 impl CloudInitContextBuilder {
@@ -93,6 +107,26 @@ impl CloudInitContextBuilder {
         self
     }
 
+    /// Set the offline mode for the cloud-init configuration
+    ///
+    /// Cloud-init then never refreshes the package index from the Ubuntu
+    /// archive, and uses the apt mirror of the offline mode if it has one.
+    #[must_use]
+    pub fn with_offline_mode(mut self, offline: Option<&OfflineMode>) -> Self {
+        self.offline = offline.cloned();
+        self
+    }
+
+    /// Set the outbound proxy for the cloud-init configuration
+    ///
+    /// Cloud-init then configures apt to reach the package archive through
+    /// the proxy.
+    #[must_use]
+    pub fn with_proxy(mut self, proxy: Option<&ProxyConfig>) -> Self {
+        self.proxy = proxy.cloned();
+        self
+    }
+
     /// Set the SSH public key by reading from a file path
     ///
     /// # Errors
@@ -131,11 +165,29 @@ impl CloudInitContextBuilder {
 
         let ssh_port = self.ssh_port.unwrap_or(22);
 
+        let apt_mirror = self
+            .offline
+            .as_ref()
+            .and_then(OfflineMode::apt_mirror)
+            .map(|url| url.as_str().trim_end_matches('/').to_string());
+
         Ok(CloudInitContext {
             metadata: self.metadata,
             ssh_public_key,
             username,
             ssh_port,
+            offline: self.offline.is_some(),
+            apt_mirror,
+            http_proxy: self
+                .proxy
+                .as_ref()
+                .and_then(ProxyConfig::http)
+                .map(ToString::to_string),
+            https_proxy: self
+                .proxy
+                .as_ref()
+                .and_then(ProxyConfig::https)
+                .map(ToString::to_string),
         })
     }
 }
@@ -160,6 +212,10 @@ impl CloudInitContext {
             ssh_public_key: key,
             username,
             ssh_port: 22, // Default SSH port
+            offline: false,
+            apt_mirror: None,
+            http_proxy: None,
+            https_proxy: None,
         })
     }
 
@@ -171,6 +227,8 @@ impl CloudInitContext {
             ssh_public_key: None,
             username: None,
             ssh_port: None,
+            offline: None,
+            proxy: None,
         }
     }
 
@@ -305,6 +363,33 @@ mod tests {
         assert_eq!(json["username"], username);
     }
 
+    #[test]
+    fn it_should_point_cloud_init_at_the_apt_mirror_in_offline_mode() {
+        let ssh_key = "ssh-rsa AAAAB3NzaC1yc2EAAAADAQABAAABAQC... test@example.com";
+        let offline = OfflineMode::new(
+            "local:torrust-ubuntu-24.04",
+            Some("http://mirror.lab.internal/ubuntu/"),
+            None,
+            None,
+        )
+        .unwrap();
+
+        let context = CloudInitContext::builder(create_test_metadata())
+            .with_ssh_public_key(ssh_key)
+            .unwrap()
+            .with_username("testuser")
+            .unwrap()
+            .with_offline_mode(Some(&offline))
+            .build()
+            .unwrap();
+
+        assert!(context.offline);
+        assert_eq!(
+            context.apt_mirror.as_deref(),
+            Some("http://mirror.lab.internal/ubuntu")
+        );
+    }
+
     #[test]
     fn it_should_fail_with_invalid_username() {
         let ssh_key = "ssh-rsa AAAAB3NzaC1yc2EAAAADAQABAAABAQC... test@example.com";
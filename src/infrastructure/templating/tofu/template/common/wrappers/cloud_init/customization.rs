@@ -0,0 +1,418 @@
+//! Merging of user additions into the rendered cloud-init configuration
+//!
+//! The rendered template and the user additions are merged as YAML documents,
+//! not as text, so an addition cannot break the structure of the document:
+//!
+//! - `extra_packages` and `packages` from `raw_append` are appended to the
+//!   `packages` list, skipping packages already listed
+//! - `extra_runcmd` and `runcmd` from `raw_append` run before the commands of
+//!   the deployer, which may reboot the instance to apply the SSH port
+//! - `write_files` from `raw_append` is appended, but may not write a file the
+//!   deployer writes
+//! - Any other key from `raw_append` is added, but may not replace a key the
+//!   deployer sets
+
+use serde_yaml::{Mapping, Value};
+use thiserror::Error;
+
+use crate::domain::provision::CloudInitCustomization;
+
+/// First line cloud-init requires to recognize a cloud-config document
+const CLOUD_CONFIG_HEADER: &str = "#cloud-config";
+
+/// Errors that can occur when merging user additions into cloud-init
+#[derive(Error, Debug, Clone, PartialEq, Eq)]
+pub enum CloudInitMergeError {
+    /// The rendered template or `raw_append` is not a YAML mapping
+    #[error("Invalid cloud-config document: {reason}")]
+    InvalidDocument { reason: String },
+
+    /// `raw_append` sets a key the deployer already sets
+    #[error("'{key}' is already set by the deployer and cannot be overridden")]
+    ConflictingKey { key: String },
+
+    /// `raw_append` writes a file the deployer already writes
+    #[error("'{path}' in 'write_files' is already written by the deployer")]
+    ConflictingFile { path: String },
+
+    /// An entry of a list key does not have the form cloud-init expects
+    #[error("Invalid '{key}' entry: {reason}")]
+    InvalidEntry { key: String, reason: String },
+}
+
+/// Merges user additions into a rendered cloud-config document
+///
+/// Returns the rendered document unchanged when there are no additions.
+/// Otherwise the comment header of the template is kept and the merged
+/// document is written below it.
+///
+/// # Errors
+///
+/// Returns an error if either document is not a YAML mapping, `raw_append`
+/// sets a key or writes a file the deployer already sets, or an entry of
+/// `packages`, `runcmd` or `write_files` has the wrong form.
+pub fn merge_customization(
+    rendered: &str,
+    customization: &CloudInitCustomization,
+) -> Result<String, CloudInitMergeError> {
+    if customization.is_empty() {
+        return Ok(rendered.to_string());
+    }
+
+    let mut document = parse_mapping(rendered)?;
+    let mut additions = customization
+        .raw_append()
+        .map(parse_mapping)
+        .transpose()?
+        .unwrap_or_default();
+
+    let mut packages = take_list(&mut document, "packages");
+    for package in customization
+        .extra_packages()
+        .iter()
+        .cloned()
+        .map(Value::String)
+        .chain(take_list(&mut additions, "packages"))
+    {
+        if !packages.contains(&package) {
+            packages.push(package);
+        }
+    }
+    append(&mut document, "packages", packages);
+
+    let deployer_commands = take_list(&mut document, "runcmd");
+    let commands = customization
+        .extra_runcmd()
+        .iter()
+        .cloned()
+        .map(Value::String)
+        .chain(take_list(&mut additions, "runcmd"))
+        .chain(deployer_commands);
+    append(&mut document, "runcmd", commands);
+
+    let files = take_list(&mut additions, "write_files");
+    for file in &files {
+        if let Some(path) = file.get("path").and_then(Value::as_str) {
+            if written_paths(&document).any(|written| written == path) {
+                return Err(CloudInitMergeError::ConflictingFile {
+                    path: path.to_string(),
+                });
+            }
+        }
+    }
+    append(&mut document, "write_files", files);
+
+    for (key, value) in additions {
+        if document.contains_key(&key) {
+            return Err(CloudInitMergeError::ConflictingKey {
+                key: key.as_str().unwrap_or_default().to_string(),
+            });
+        }
+        document.insert(key, value);
+    }
+
+    validate_list_entries(&document)?;
+
+    let body =
+        serde_yaml::to_string(&document).map_err(|e| CloudInitMergeError::InvalidDocument {
+            reason: e.to_string(),
+        })?;
+
+    Ok(format!(
+        "{}#\n# User additions from the 'cloud_init' section are merged below.\n\n{body}",
+        comment_header(rendered)
+    ))
+}
+
+/// Parses a YAML document that must be a mapping (an empty document is one)
+fn parse_mapping(yaml: &str) -> Result<Mapping, CloudInitMergeError> {
+    match serde_yaml::from_str(yaml) {
+        Ok(Value::Mapping(mapping)) => Ok(mapping),
+        Ok(Value::Null) => Ok(Mapping::new()),
+        Ok(_) => Err(CloudInitMergeError::InvalidDocument {
+            reason: "expected a mapping of cloud-config keys".to_string(),
+        }),
+        Err(e) => Err(CloudInitMergeError::InvalidDocument {
+            reason: e.to_string(),
+        }),
+    }
+}
+
+/// Removes a list key from the mapping, returning its entries
+fn take_list(mapping: &mut Mapping, key: &str) -> Vec<Value> {
+    match mapping.remove(key) {
+        Some(Value::Sequence(entries)) => entries,
+        _ => Vec::new(),
+    }
+}
+
+/// Appends entries to a list key, creating the key only when there are entries
+fn append(document: &mut Mapping, key: &str, entries: impl IntoIterator<Item = Value>) {
+    let mut list = take_list(document, key);
+    list.extend(entries);
+
+    if !list.is_empty() {
+        document.insert(Value::from(key), Value::Sequence(list));
+    }
+}
+
+/// Paths of the files in the `write_files` list of the document
+fn written_paths(document: &Mapping) -> impl Iterator<Item = &str> {
+    document
+        .get("write_files")
+        .and_then(Value::as_sequence)
+        .into_iter()
+        .flatten()
+        .filter_map(|file| file.get("path").and_then(Value::as_str))
+}
+
+/// Checks the list entries against the forms cloud-init accepts
+///
+/// - `packages`: a name, or a `[name, version]` list
+/// - `runcmd`: a shell command, or an argument list
+/// - `write_files`: a mapping with a `path`
+fn validate_list_entries(document: &Mapping) -> Result<(), CloudInitMergeError> {
+    let invalid = |key: &str, reason: &str| CloudInitMergeError::InvalidEntry {
+        key: key.to_string(),
+        reason: reason.to_string(),
+    };
+    let entries = |key: &str| {
+        document
+            .get(key)
+            .and_then(Value::as_sequence)
+            .into_iter()
+            .flatten()
+    };
+    let is_string_list = |entry: &Value| {
+        entry
+            .as_sequence()
+            .is_some_and(|items| !items.is_empty() && items.iter().all(Value::is_string))
+    };
+
+    for entry in entries("packages") {
+        if !entry.is_string() && !is_string_list(entry) {
+            return Err(invalid(
+                "packages",
+                "expected a name or a [name, version] list",
+            ));
+        }
+    }
+
+    for entry in entries("runcmd") {
+        if !entry.is_string() && !is_string_list(entry) {
+            return Err(invalid("runcmd", "expected a command or an argument list"));
+        }
+    }
+
+    for entry in entries("write_files") {
+        if !entry.get("path").is_some_and(Value::is_string) {
+            return Err(invalid("write_files", "expected a mapping with a 'path'"));
+        }
+    }
+
+    Ok(())
+}
+
+/// Leading comment lines of the rendered template, `#cloud-config` first
+fn comment_header(rendered: &str) -> String {
+    let header: String = rendered
+        .lines()
+        .take_while(|line| line.starts_with('#'))
+        .map(|line| format!("{line}\n"))
+        .collect();
+
+    if header.starts_with(CLOUD_CONFIG_HEADER) {
+        header
+    } else {
+        format!("{CLOUD_CONFIG_HEADER}\n{header}")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::template::ExtraTemplateVariables;
+
+    const RENDERED: &str = "#cloud-config
+# Generated at: 1970-01-01
+users:
+  - name: torrust
+    ssh_authorized_keys:
+      - ssh-rsa AAAA
+write_files:
+  - path: /etc/ssh/sshd_config.d/99-custom-port.conf
+    content: Port 2222
+runcmd:
+  - reboot
+";
+
+    fn customization(
+        packages: &[&str],
+        commands: &[&str],
+        raw_append: Option<&str>,
+    ) -> CloudInitCustomization {
+        CloudInitCustomization::new(
+            packages.iter().map(ToString::to_string).collect(),
+            commands.iter().map(ToString::to_string).collect(),
+            raw_append.map(ToString::to_string),
+        )
+        .unwrap()
+    }
+
+    fn merged_document(customization: &CloudInitCustomization) -> Value {
+        let merged = merge_customization(RENDERED, customization).unwrap();
+        serde_yaml::from_str(&merged).unwrap()
+    }
+
+    #[test]
+    fn it_should_leave_the_rendered_document_unchanged_without_additions() {
+        let merged = merge_customization(RENDERED, &CloudInitCustomization::default()).unwrap();
+
+        assert_eq!(merged, RENDERED);
+    }
+
+    #[test]
+    fn it_should_add_extra_packages_and_keep_the_cloud_config_header() {
+        let merged = merge_customization(RENDERED, &customization(&["htop"], &[], None)).unwrap();
+
+        assert!(merged.starts_with("#cloud-config\n# Generated at: 1970-01-01\n"));
+        let document: Value = serde_yaml::from_str(&merged).unwrap();
+        assert_eq!(
+            document["packages"],
+            serde_yaml::from_str::<Value>("[htop]").unwrap()
+        );
+        assert_eq!(document["users"][0]["name"], "torrust");
+    }
+
+    #[test]
+    fn it_should_run_user_commands_before_the_deployer_reboot() {
+        let document = merged_document(&customization(
+            &[],
+            &["echo hello > /etc/motd"],
+            Some("runcmd: [[touch, /root/ready]]"),
+        ));
+
+        assert_eq!(
+            document["runcmd"],
+            serde_yaml::from_str::<Value>(
+                "['echo hello > /etc/motd', [touch, /root/ready], reboot]"
+            )
+            .unwrap()
+        );
+    }
+
+    #[test]
+    fn it_should_append_packages_and_files_from_raw_append() {
+        let document = merged_document(&customization(
+            &["htop"],
+            &[],
+            Some("packages: [vim, htop]\nwrite_files:\n  - path: /etc/motd\n    content: hi\ntimezone: UTC"),
+        ));
+
+        assert_eq!(
+            document["packages"],
+            serde_yaml::from_str::<Value>("[htop, vim]").unwrap()
+        );
+        assert_eq!(document["write_files"][1]["path"], "/etc/motd");
+        assert_eq!(document["timezone"], "UTC");
+    }
+
+    #[test]
+    fn it_should_merge_into_the_shared_cloud_init_template() {
+        use chrono::DateTime;
+
+        use crate::domain::template::file::File;
+        use crate::infrastructure::templating::metadata::TemplateMetadata;
+        use crate::infrastructure::templating::tofu::template::common::wrappers::cloud_init::{
+            CloudInitContext, CloudInitTemplate,
+        };
+
+        let template_path = std::path::Path::new(env!("CARGO_MANIFEST_DIR"))
+            .join("templates/tofu/common/cloud-init.yml.tera");
+        let template_file = File::new(
+            "cloud-init.yml.tera",
+            std::fs::read_to_string(template_path).unwrap(),
+        )
+        .unwrap();
+        let context = CloudInitContext::builder(TemplateMetadata::new(DateTime::UNIX_EPOCH))
+            .with_ssh_public_key("ssh-rsa AAAA test@example.com")
+            .unwrap()
+            .with_username("torrust")
+            .unwrap()
+            .with_ssh_port(2222)
+            .build()
+            .unwrap();
+        let template =
+            CloudInitTemplate::new(&template_file, context, &ExtraTemplateVariables::default())
+                .unwrap();
+        let output = tempfile::NamedTempFile::new().unwrap();
+
+        template
+            .with_customization(&customization(&["htop"], &["touch /root/ready"], None))
+            .unwrap()
+            .render(output.path())
+            .unwrap();
+
+        let merged = std::fs::read_to_string(output.path()).unwrap();
+        assert!(merged.starts_with("#cloud-config\n"));
+        let document: Value = serde_yaml::from_str(&merged).unwrap();
+        assert_eq!(
+            document["packages"],
+            serde_yaml::from_str::<Value>("[htop]").unwrap()
+        );
+        assert_eq!(
+            document["runcmd"],
+            serde_yaml::from_str::<Value>("['touch /root/ready', reboot]").unwrap()
+        );
+        assert_eq!(document["users"][0]["name"], "torrust");
+    }
+
+    #[test]
+    fn it_should_reject_a_file_written_by_the_deployer() {
+        let result = merge_customization(
+            RENDERED,
+            &customization(
+                &[],
+                &[],
+                Some("write_files:\n  - path: /etc/ssh/sshd_config.d/99-custom-port.conf"),
+            ),
+        );
+
+        assert_eq!(
+            result,
+            Err(CloudInitMergeError::ConflictingFile {
+                path: "/etc/ssh/sshd_config.d/99-custom-port.conf".to_string()
+            })
+        );
+    }
+
+    #[test]
+    fn it_should_reject_a_key_set_by_the_deployer() {
+        let rendered = format!("{RENDERED}package_upgrade: true\n");
+
+        let result = merge_customization(
+            &rendered,
+            &customization(&[], &[], Some("package_upgrade: false")),
+        );
+
+        assert_eq!(
+            result,
+            Err(CloudInitMergeError::ConflictingKey {
+                key: "package_upgrade".to_string()
+            })
+        );
+    }
+
+    #[test]
+    fn it_should_reject_an_entry_cloud_init_does_not_accept() {
+        let result = merge_customization(
+            RENDERED,
+            &customization(&[], &[], Some("packages: [{ name: htop }]")),
+        );
+
+        assert!(matches!(
+            result,
+            Err(CloudInitMergeError::InvalidEntry { key, .. }) if key == "packages"
+        ));
+    }
+}
@@ -5,6 +5,8 @@
 
 mod cloud_init_template;
 pub mod context;
+pub mod customization;
 
 pub use cloud_init_template::CloudInitTemplate;
 pub use context::{CloudInitContext, CloudInitContextBuilder, CloudInitContextError};
+pub use customization::{merge_customization, CloudInitMergeError};
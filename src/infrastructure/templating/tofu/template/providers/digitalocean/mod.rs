@@ -0,0 +1,10 @@
+//! `DigitalOcean` provider-specific `OpenTofu` template functionality.
+//!
+//! This module contains template wrappers and utilities specific to the `DigitalOcean` provider.
+//!
+//! The cloud-init template is shared with the other providers, see
+//! `common::wrappers::cloud_init`.
+
+pub mod wrappers;
+
+pub use wrappers::variables;
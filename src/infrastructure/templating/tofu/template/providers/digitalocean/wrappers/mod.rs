@@ -0,0 +1,14 @@
+//! `OpenTofu` `DigitalOcean` template wrappers
+//!
+//! Contains template wrappers for `DigitalOcean`-specific configuration files.
+//!
+//! - `variables` - templates/tofu/digitalocean/variables.tfvars.tera (with runtime variables: `do_api_token`, `instance_name`, etc.)
+//!
+//! The cloud-init template is shared with the other providers, see
+//! `common::wrappers::cloud_init`.
+
+pub mod variables;
+
+pub use variables::{
+    VariablesContext, VariablesContextBuilder, VariablesContextError, VariablesTemplate,
+};
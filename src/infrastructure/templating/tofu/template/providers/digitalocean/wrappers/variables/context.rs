@@ -0,0 +1,492 @@
+//! # `DigitalOcean` `OpenTofu` Variables Context
+//!
+//! Provides context structures for `DigitalOcean` `OpenTofu` variables template rendering.
+//!
+//! This module contains the context object that holds runtime values for variable template rendering,
+//! specifically for the `variables.tfvars.tera` template used in `DigitalOcean` infrastructure provisioning.
+//!
+//! ## Context Structure
+//!
+//! The `VariablesContext` holds:
+//! - `instance_name` - The dynamic name for the droplet
+//! - `do_api_token` - `DigitalOcean` API token for authentication
+//! - `droplet_size` - Droplet size slug (e.g., s-1vcpu-2gb)
+//! - `droplet_region` - Region slug (e.g., fra1, ams3)
+//! - `droplet_image` - OS image slug (e.g., ubuntu-24-04-x64)
+//! - `ssh_public_key_content` - SSH public key content for droplet access
+//! - `reserved_ip` - Optional existing reserved IP assigned to the droplet
+//! - `firewall_rules` - Inbound ports opened in the `DigitalOcean` cloud firewall
+//!
+//! ## Example Usage
+//!
+//! ```rust
+//! use torrust_tracker_deployer_lib::infrastructure::templating::tofu::template::providers::digitalocean::wrappers::variables::VariablesContext;
+//! use torrust_tracker_deployer_lib::infrastructure::templating::metadata::TemplateMetadata;
+//! use torrust_tracker_deployer_lib::domain::InstanceName;
+//! use chrono::Utc;
+//!
+//! let metadata = TemplateMetadata::new(Utc::now());
+//! let context = VariablesContext::builder()
+//!     .with_metadata(metadata)
+//!     .with_instance_name(InstanceName::new("my-test-vm".to_string()).unwrap())
+//!     .with_do_api_token("my-api-token".to_string())
+//!     .with_droplet_size("s-1vcpu-2gb".to_string())
+//!     .with_droplet_region("fra1".to_string())
+//!     .with_droplet_image("ubuntu-24-04-x64".to_string())
+//!     .with_ssh_public_key_content("ssh-rsa AAAA...".to_string())
+//!     .build()
+//!     .unwrap();
+//! ```
+
+use std::net::Ipv4Addr;
+
+use serde::Serialize;
+use thiserror::Error;
+
+use crate::domain::firewall::FirewallRule as DomainFirewallRule;
+use crate::domain::topology::PortBinding;
+use crate::domain::tracker::Protocol;
+use crate::domain::InstanceName;
+use crate::infrastructure::templating::metadata::TemplateMetadata;
+use crate::shared::ApiToken;
+
+/// Errors that can occur when building the `DigitalOcean` variables context
+#[derive(Error, Debug)]
+pub enum VariablesContextError {
+    /// Template metadata is required but was not provided
+    #[error("Template metadata is required but was not provided")]
+    MissingMetadata,
+
+    /// Instance name is required but was not provided
+    #[error("Instance name is required but was not provided")]
+    MissingInstanceName,
+
+    /// `DigitalOcean` API token is required but was not provided
+    #[error("DigitalOcean API token is required but was not provided")]
+    MissingDoApiToken,
+
+    /// Droplet size is required but was not provided
+    #[error("Droplet size is required but was not provided")]
+    MissingDropletSize,
+
+    /// Droplet region is required but was not provided
+    #[error("Droplet region is required but was not provided")]
+    MissingDropletRegion,
+
+    /// Droplet image is required but was not provided
+    #[error("Droplet image is required but was not provided")]
+    MissingDropletImage,
+
+    /// SSH public key content is required but was not provided
+    #[error("SSH public key content is required but was not provided")]
+    MissingSshPublicKeyContent,
+}
+
+/// Inbound rule of the `DigitalOcean` cloud firewall
+///
+/// Rendered into the `firewall_rules` variable. Rules derived from the
+/// services allow traffic from any IPv4 and IPv6 address; extra rules from the
+/// environment's firewall configuration may restrict the source addresses.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct FirewallRule {
+    /// Protocol in the form expected by the digitalocean provider (`tcp` or `udp`)
+    pub protocol: String,
+    /// Port to open
+    pub port: u16,
+    /// Description kept as a comment next to the rule
+    pub description: String,
+    /// Source addresses allowed to connect (addresses or CIDR networks)
+    pub source_addresses: Vec<String>,
+}
+
+impl FirewallRule {
+    /// Rule allowing SSH access on the given port
+    #[must_use]
+    pub fn ssh(port: u16) -> Self {
+        Self {
+            protocol: "tcp".to_string(),
+            port,
+            description: "SSH".to_string(),
+            source_addresses: any_source(),
+        }
+    }
+}
+
+/// Source networks matching every IPv4 and IPv6 address
+fn any_source() -> Vec<String> {
+    vec!["0.0.0.0/0".to_string(), "::/0".to_string()]
+}
+
+impl From<&PortBinding> for FirewallRule {
+    fn from(binding: &PortBinding) -> Self {
+        let protocol = match binding.protocol() {
+            Protocol::Tcp => "tcp",
+            Protocol::Udp => "udp",
+        };
+
+        Self {
+            protocol: protocol.to_string(),
+            port: binding.host_port(),
+            description: binding.description().to_string(),
+            source_addresses: any_source(),
+        }
+    }
+}
+
+impl From<&DomainFirewallRule> for FirewallRule {
+    fn from(rule: &DomainFirewallRule) -> Self {
+        let protocol = match rule.protocol() {
+            Protocol::Tcp => "tcp",
+            Protocol::Udp => "udp",
+        };
+
+        // DigitalOcean accepts plain addresses as well as networks
+        let source_addresses = match rule.cidr() {
+            Some(source) => vec![source.to_string()],
+            None => any_source(),
+        };
+
+        Self {
+            protocol: protocol.to_string(),
+            port: rule.port(),
+            description: "Extra allow rule".to_string(),
+            source_addresses,
+        }
+    }
+}
+
+/// Context for `DigitalOcean` `OpenTofu` variables template rendering
+///
+/// Contains all runtime values needed to render `variables.tfvars.tera`
+/// with `DigitalOcean`-specific configuration parameters.
+#[derive(Debug, Clone, Serialize)]
+pub struct VariablesContext {
+    /// Template metadata (generation timestamp, etc.)
+    #[serde(flatten)]
+    pub metadata: TemplateMetadata,
+    /// The name of the droplet to be created
+    pub instance_name: InstanceName,
+    /// `DigitalOcean` API token for authentication (redacted in debug output)
+    pub do_api_token: ApiToken,
+    /// Droplet size slug (e.g., s-1vcpu-2gb, s-2vcpu-4gb)
+    pub droplet_size: String,
+    /// Region slug (e.g., fra1, ams3, nyc3)
+    pub droplet_region: String,
+    /// Operating system image slug (e.g., ubuntu-24-04-x64)
+    pub droplet_image: String,
+    /// SSH public key content for droplet access
+    pub ssh_public_key_content: String,
+    /// Existing reserved IP assigned to the droplet, if any
+    pub reserved_ip: Option<Ipv4Addr>,
+    /// Inbound rules of the `DigitalOcean` cloud firewall attached to the droplet
+    pub firewall_rules: Vec<FirewallRule>,
+}
+
+/// Builder for creating `DigitalOcean` `VariablesContext` instances
+///
+/// Provides a fluent interface for constructing the context with validation
+/// to ensure all required fields are provided.
+#[derive(Debug, Default)]
+pub struct VariablesContextBuilder {
+    metadata: Option<TemplateMetadata>,
+    instance_name: Option<InstanceName>,
+    do_api_token: Option<ApiToken>,
+    droplet_size: Option<String>,
+    droplet_region: Option<String>,
+    droplet_image: Option<String>,
+    ssh_public_key_content: Option<String>,
+    reserved_ip: Option<Ipv4Addr>,
+    firewall_rules: Vec<FirewallRule>,
+}
+
+impl VariablesContextBuilder {
+    /// Creates a new builder instance
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Sets the template metadata
+    ///
+    /// # Arguments
+    ///
+    /// * `metadata` - The template metadata containing generation timestamp
+    #[must_use]
+    pub fn with_metadata(mut self, metadata: TemplateMetadata) -> Self {
+        self.metadata = Some(metadata);
+        self
+    }
+
+    /// Sets the instance name for the droplet
+    ///
+    /// # Arguments
+    ///
+    /// * `instance_name` - The name to assign to the created droplet
+    #[must_use]
+    pub fn with_instance_name(mut self, instance_name: InstanceName) -> Self {
+        self.instance_name = Some(instance_name);
+        self
+    }
+
+    /// Sets the `DigitalOcean` API token
+    ///
+    /// # Arguments
+    ///
+    /// * `do_api_token` - The API token for `DigitalOcean` authentication
+    #[must_use]
+    pub fn with_do_api_token(mut self, do_api_token: String) -> Self {
+        self.do_api_token = Some(ApiToken::from(do_api_token));
+        self
+    }
+
+    /// Sets the droplet size
+    ///
+    /// # Arguments
+    ///
+    /// * `droplet_size` - The droplet size slug (e.g., s-1vcpu-2gb)
+    #[must_use]
+    pub fn with_droplet_size(mut self, droplet_size: String) -> Self {
+        self.droplet_size = Some(droplet_size);
+        self
+    }
+
+    /// Sets the droplet region
+    ///
+    /// # Arguments
+    ///
+    /// * `droplet_region` - The region slug (e.g., fra1)
+    #[must_use]
+    pub fn with_droplet_region(mut self, droplet_region: String) -> Self {
+        self.droplet_region = Some(droplet_region);
+        self
+    }
+
+    /// Sets the droplet image
+    ///
+    /// # Arguments
+    ///
+    /// * `droplet_image` - The OS image slug (e.g., ubuntu-24-04-x64)
+    #[must_use]
+    pub fn with_droplet_image(mut self, droplet_image: String) -> Self {
+        self.droplet_image = Some(droplet_image);
+        self
+    }
+
+    /// Sets the SSH public key content
+    ///
+    /// # Arguments
+    ///
+    /// * `ssh_public_key_content` - The content of the SSH public key
+    #[must_use]
+    pub fn with_ssh_public_key_content(mut self, ssh_public_key_content: String) -> Self {
+        self.ssh_public_key_content = Some(ssh_public_key_content);
+        self
+    }
+
+    /// Sets the reserved IP assigned to the droplet
+    ///
+    /// Optional. Without a reserved IP the droplet is reached through its
+    /// own public IPv4 address.
+    ///
+    /// # Arguments
+    ///
+    /// * `reserved_ip` - An existing reserved IP in the droplet's region
+    #[must_use]
+    pub fn with_reserved_ip(mut self, reserved_ip: Option<Ipv4Addr>) -> Self {
+        self.reserved_ip = reserved_ip;
+        self
+    }
+
+    /// Sets the inbound firewall rules
+    ///
+    /// Optional. Without rules the firewall only allows ICMP.
+    ///
+    /// # Arguments
+    ///
+    /// * `firewall_rules` - The ports to open in the `DigitalOcean` cloud firewall
+    #[must_use]
+    pub fn with_firewall_rules(mut self, firewall_rules: Vec<FirewallRule>) -> Self {
+        self.firewall_rules = firewall_rules;
+        self
+    }
+
+    /// Builds the `VariablesContext` with validation
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(VariablesContext)` if all required fields are present
+    /// * `Err(VariablesContextError)` if validation fails
+    ///
+    /// # Errors
+    ///
+    /// Returns appropriate error variant for each missing required field
+    pub fn build(self) -> Result<VariablesContext, VariablesContextError> {
+        let metadata = self
+            .metadata
+            .ok_or(VariablesContextError::MissingMetadata)?;
+
+        let instance_name = self
+            .instance_name
+            .ok_or(VariablesContextError::MissingInstanceName)?;
+
+        let do_api_token = self
+            .do_api_token
+            .ok_or(VariablesContextError::MissingDoApiToken)?;
+
+        let droplet_size = self
+            .droplet_size
+            .ok_or(VariablesContextError::MissingDropletSize)?;
+
+        let droplet_region = self
+            .droplet_region
+            .ok_or(VariablesContextError::MissingDropletRegion)?;
+
+        let droplet_image = self
+            .droplet_image
+            .ok_or(VariablesContextError::MissingDropletImage)?;
+
+        let ssh_public_key_content = self
+            .ssh_public_key_content
+            .ok_or(VariablesContextError::MissingSshPublicKeyContent)?;
+
+        Ok(VariablesContext {
+            metadata,
+            instance_name,
+            do_api_token,
+            droplet_size,
+            droplet_region,
+            droplet_image,
+            ssh_public_key_content,
+            reserved_ip: self.reserved_ip,
+            firewall_rules: self.firewall_rules,
+        })
+    }
+}
+
+impl VariablesContext {
+    /// Creates a new builder for constructing `VariablesContext`
+    #[must_use]
+    pub fn builder() -> VariablesContextBuilder {
+        VariablesContextBuilder::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::Utc;
+
+    use super::*;
+
+    fn create_test_metadata() -> TemplateMetadata {
+        TemplateMetadata::new(Utc::now())
+    }
+
+    fn create_valid_builder() -> VariablesContextBuilder {
+        VariablesContext::builder()
+            .with_metadata(create_test_metadata())
+            .with_instance_name(InstanceName::new("test-vm".to_string()).unwrap())
+            .with_do_api_token("test-token".to_string())
+            .with_droplet_size("s-1vcpu-2gb".to_string())
+            .with_droplet_region("fra1".to_string())
+            .with_droplet_image("ubuntu-24-04-x64".to_string())
+            .with_ssh_public_key_content("ssh-rsa AAAA... test@example.com".to_string())
+    }
+
+    #[test]
+    fn it_should_create_variables_context_with_all_required_fields() {
+        let context = create_valid_builder().build().unwrap();
+
+        assert_eq!(context.instance_name.as_str(), "test-vm");
+        assert_eq!(context.do_api_token.expose_secret(), "test-token");
+        assert_eq!(context.droplet_size, "s-1vcpu-2gb");
+        assert_eq!(context.droplet_region, "fra1");
+        assert_eq!(context.droplet_image, "ubuntu-24-04-x64");
+        assert_eq!(context.reserved_ip, None);
+        assert!(context.firewall_rules.is_empty());
+    }
+
+    #[test]
+    fn it_should_keep_the_reserved_ip() {
+        let context = create_valid_builder()
+            .with_reserved_ip(Some(Ipv4Addr::new(203, 0, 113, 10)))
+            .build()
+            .unwrap();
+
+        assert_eq!(context.reserved_ip, Some(Ipv4Addr::new(203, 0, 113, 10)));
+    }
+
+    #[test]
+    fn it_should_convert_port_bindings_to_firewall_rules() {
+        let rule = FirewallRule::from(&PortBinding::udp(6969, "BitTorrent UDP announce"));
+
+        assert_eq!(
+            rule,
+            FirewallRule {
+                protocol: "udp".to_string(),
+                port: 6969,
+                description: "BitTorrent UDP announce".to_string(),
+                source_addresses: vec!["0.0.0.0/0".to_string(), "::/0".to_string()],
+            }
+        );
+    }
+
+    #[test]
+    fn it_should_restrict_extra_allow_rules_to_their_source_address() {
+        let host =
+            DomainFirewallRule::new(9100, Protocol::Tcp, Some("203.0.113.7".to_string())).unwrap();
+        let any = DomainFirewallRule::new(9100, Protocol::Tcp, None).unwrap();
+
+        assert_eq!(
+            FirewallRule::from(&host).source_addresses,
+            vec!["203.0.113.7"]
+        );
+        assert_eq!(
+            FirewallRule::from(&any).source_addresses,
+            vec!["0.0.0.0/0", "::/0"]
+        );
+    }
+
+    #[test]
+    fn it_should_fail_when_do_api_token_is_missing() {
+        let result = VariablesContext::builder()
+            .with_metadata(create_test_metadata())
+            .with_instance_name(InstanceName::new("test-vm".to_string()).unwrap())
+            .with_droplet_size("s-1vcpu-2gb".to_string())
+            .with_droplet_region("fra1".to_string())
+            .with_droplet_image("ubuntu-24-04-x64".to_string())
+            .with_ssh_public_key_content("ssh-rsa AAAA...".to_string())
+            .build();
+
+        assert!(matches!(
+            result.unwrap_err(),
+            VariablesContextError::MissingDoApiToken
+        ));
+    }
+
+    #[test]
+    fn it_should_fail_when_droplet_region_is_missing() {
+        let result = VariablesContext::builder()
+            .with_metadata(create_test_metadata())
+            .with_instance_name(InstanceName::new("test-vm".to_string()).unwrap())
+            .with_do_api_token("test-token".to_string())
+            .with_droplet_size("s-1vcpu-2gb".to_string())
+            .with_droplet_image("ubuntu-24-04-x64".to_string())
+            .with_ssh_public_key_content("ssh-rsa AAAA...".to_string())
+            .build();
+
+        assert!(matches!(
+            result.unwrap_err(),
+            VariablesContextError::MissingDropletRegion
+        ));
+    }
+
+    #[test]
+    fn it_should_redact_the_api_token_in_debug_output() {
+        let context = create_valid_builder().build().unwrap();
+        let debug = format!("{context:?}");
+
+        assert!(debug.contains("VariablesContext"));
+        assert!(debug.contains("[REDACTED]"));
+        assert!(!debug.contains("test-token"));
+    }
+}
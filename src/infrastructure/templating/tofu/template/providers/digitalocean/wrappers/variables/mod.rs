@@ -0,0 +1,14 @@
+//! # `DigitalOcean` `OpenTofu` Variables Templates
+//!
+//! Template wrappers for rendering `variables.tfvars.tera` with `DigitalOcean`-specific configuration.
+//!
+//! This module provides the `VariablesTemplate` and `VariablesContext` for validating and rendering `OpenTofu`
+//! variable files with runtime context injection, specifically for parameterizing
+//! `DigitalOcean` infrastructure provisioning.
+
+pub mod context;
+mod variables_template;
+
+pub use crate::infrastructure::templating::tofu::template::common::wrappers::VariablesTemplateError;
+pub use context::{FirewallRule, VariablesContext, VariablesContextBuilder, VariablesContextError};
+pub use variables_template::VariablesTemplate;
@@ -0,0 +1,195 @@
+//! `VariablesTemplate` type and implementation for `DigitalOcean`.
+
+use std::path::Path;
+
+use crate::domain::template::file::File;
+use crate::domain::template::{
+    write_file_with_dir_creation, ExtraTemplateVariables, TemplateEngine,
+};
+use crate::infrastructure::templating::tofu::template::common::wrappers::VariablesTemplateError;
+
+use super::context::VariablesContext;
+
+/// Template wrapper for `DigitalOcean` `OpenTofu` variables rendering
+///
+/// Validates and renders `variables.tfvars.tera` templates with `VariablesContext`
+/// to produce dynamic infrastructure variable files for `DigitalOcean`.
+#[derive(Debug)]
+pub struct VariablesTemplate {
+    context: VariablesContext,
+    content: String,
+}
+
+impl VariablesTemplate {
+    /// Creates a new `DigitalOcean` variables template with validation
+    ///
+    /// # Arguments
+    ///
+    /// * `template_file` - The template file containing variables.tfvars.tera content
+    /// * `context` - The context containing `DigitalOcean`-specific runtime values
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(VariablesTemplate)` if template validation succeeds
+    /// * `Err(VariablesTemplateError)` if validation fails
+    ///
+    /// # Errors
+    ///
+    /// Returns `TemplateEngineError` if the template has syntax errors or validation fails
+    pub fn new(
+        template_file: &File,
+        context: VariablesContext,
+        extra_variables: &ExtraTemplateVariables,
+    ) -> Result<Self, VariablesTemplateError> {
+        let mut engine = TemplateEngine::new().with_extra_variables(extra_variables);
+
+        let validated_content =
+            engine.render(template_file.filename(), template_file.content(), &context)?;
+
+        Ok(Self {
+            context,
+            content: validated_content,
+        })
+    }
+
+    /// Get the instance name value
+    #[must_use]
+    pub fn instance_name(&self) -> &str {
+        self.context.instance_name.as_str()
+    }
+
+    /// Render the template to a file at the specified output path
+    ///
+    /// # Errors
+    /// Returns `FileOperationError::DirectoryCreation` if the parent directory cannot be created,
+    /// or `FileOperationError::FileWrite` if the file cannot be written
+    pub fn render(&self, output_path: &Path) -> Result<(), VariablesTemplateError> {
+        write_file_with_dir_creation(output_path, &self.content)?;
+        Ok(())
+    }
+
+    /// Gets the context used by this template
+    #[must_use]
+    pub fn context(&self) -> &VariablesContext {
+        &self.context
+    }
+
+    /// Gets the rendered content
+    #[must_use]
+    pub fn content(&self) -> &str {
+        &self.content
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::net::Ipv4Addr;
+
+    use chrono::Utc;
+
+    use super::*;
+    use crate::domain::topology::PortBinding;
+    use crate::domain::InstanceName;
+    use crate::infrastructure::templating::metadata::TemplateMetadata;
+    use crate::infrastructure::templating::tofu::template::providers::digitalocean::wrappers::variables::{
+        FirewallRule, VariablesContextBuilder,
+    };
+    use tempfile::NamedTempFile;
+
+    fn create_test_context_builder() -> VariablesContextBuilder {
+        VariablesContext::builder()
+            .with_metadata(TemplateMetadata::new(Utc::now()))
+            .with_instance_name(InstanceName::new("test-instance".to_string()).unwrap())
+            .with_do_api_token("test-api-token".to_string())
+            .with_droplet_size("s-1vcpu-2gb".to_string())
+            .with_droplet_region("fra1".to_string())
+            .with_droplet_image("ubuntu-24-04-x64".to_string())
+            .with_ssh_public_key_content("ssh-rsa AAAA... test@example.com".to_string())
+    }
+
+    fn digitalocean_template_file() -> File {
+        let template_path = std::path::Path::new(env!("CARGO_MANIFEST_DIR"))
+            .join("templates/tofu/digitalocean/variables.tfvars.tera");
+        File::new(
+            "variables.tfvars.tera",
+            std::fs::read_to_string(template_path).unwrap(),
+        )
+        .unwrap()
+    }
+
+    fn render(context: VariablesContext) -> String {
+        let temp_file = NamedTempFile::new().unwrap();
+        VariablesTemplate::new(
+            &digitalocean_template_file(),
+            context,
+            &ExtraTemplateVariables::default(),
+        )
+        .unwrap()
+        .render(temp_file.path())
+        .unwrap();
+        std::fs::read_to_string(temp_file.path()).unwrap()
+    }
+
+    #[test]
+    fn it_should_fail_when_template_has_malformed_syntax() {
+        let template_file = File::new(
+            "variables.tfvars.tera",
+            r#"do_token = "{{ do_api_token"#.to_string(),
+        )
+        .unwrap();
+
+        let result = VariablesTemplate::new(
+            &template_file,
+            create_test_context_builder().build().unwrap(),
+            &ExtraTemplateVariables::default(),
+        );
+
+        assert!(matches!(
+            result.unwrap_err(),
+            VariablesTemplateError::TemplateEngineError { .. }
+        ));
+    }
+
+    #[test]
+    fn it_should_render_the_droplet_settings_with_the_digitalocean_template() {
+        let rendered = render(create_test_context_builder().build().unwrap());
+
+        assert!(rendered.contains(r#"do_token = "test-api-token""#));
+        assert!(rendered.contains(r#"droplet_name   = "test-instance""#));
+        assert!(rendered.contains(r#"droplet_size   = "s-1vcpu-2gb""#));
+        assert!(rendered.contains(r#"droplet_region = "fra1""#));
+        assert!(rendered.contains(r#"reserved_ip = """#));
+    }
+
+    #[test]
+    fn it_should_render_the_reserved_ip_with_the_digitalocean_template() {
+        let context = create_test_context_builder()
+            .with_reserved_ip(Some(Ipv4Addr::new(203, 0, 113, 10)))
+            .build()
+            .unwrap();
+
+        let rendered = render(context);
+
+        assert!(rendered.contains(r#"reserved_ip = "203.0.113.10""#));
+    }
+
+    #[test]
+    fn it_should_render_firewall_rules_with_the_digitalocean_template() {
+        let context = create_test_context_builder()
+            .with_firewall_rules(vec![
+                FirewallRule::ssh(22),
+                FirewallRule::from(&PortBinding::udp(6969, "BitTorrent UDP announce")),
+            ])
+            .build()
+            .unwrap();
+
+        let rendered = render(context);
+
+        assert!(rendered.contains(
+            r#"{ protocol = "tcp", port = "22", description = "SSH", source_addresses = ["0.0.0.0/0", "::/0"] },"#
+        ));
+        assert!(rendered.contains(
+            r#"{ protocol = "udp", port = "6969", description = "BitTorrent UDP announce", source_addresses = ["0.0.0.0/0", "::/0"] },"#
+        ));
+    }
+}
@@ -0,0 +1,10 @@
+//! Docker provider-specific `OpenTofu` template functionality.
+//!
+//! This module contains template wrappers and utilities specific to the Docker provider.
+//!
+//! The container does not run cloud-init, so the shared cloud-init template
+//! is rendered but not used by the Docker `main.tf`.
+
+pub mod wrappers;
+
+pub use wrappers::variables;
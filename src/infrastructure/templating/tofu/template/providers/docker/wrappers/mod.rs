@@ -0,0 +1,11 @@
+//! `OpenTofu` Docker template wrappers
+//!
+//! Contains template wrappers for Docker-specific configuration files.
+//!
+//! - `variables` - templates/tofu/docker/variables.tfvars.tera (with runtime variables: `instance_name`, `image`, etc.)
+
+pub mod variables;
+
+pub use variables::{
+    VariablesContext, VariablesContextBuilder, VariablesContextError, VariablesTemplate,
+};
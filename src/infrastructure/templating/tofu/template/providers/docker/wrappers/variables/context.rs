@@ -0,0 +1,343 @@
+//! # Docker `OpenTofu` Variables Context
+//!
+//! Provides context structures for Docker `OpenTofu` variables template rendering.
+//!
+//! This module contains the context object that holds runtime values for variable template rendering,
+//! specifically for the `variables.tfvars.tera` template used in Docker infrastructure provisioning.
+//!
+//! ## Context Structure
+//!
+//! The `VariablesContext` holds:
+//! - `instance_name` - The dynamic name for the container
+//! - `image` - The local Docker image of the instance
+//! - `ssh_public_key_content` - SSH public key content authorized in the container
+//! - `ssh_username` - The SSH user existing in the image
+//! - `ssh_port` - The port sshd listens on inside the container
+//! - `published_ports` - Service ports published on the host
+//!
+//! ## Example Usage
+//!
+//! ```rust
+//! use torrust_tracker_deployer_lib::infrastructure::templating::tofu::template::providers::docker::wrappers::variables::VariablesContext;
+//! use torrust_tracker_deployer_lib::infrastructure::templating::metadata::TemplateMetadata;
+//! use torrust_tracker_deployer_lib::domain::InstanceName;
+//! use chrono::Utc;
+//!
+//! let metadata = TemplateMetadata::new(Utc::now());
+//! let context = VariablesContext::builder()
+//!     .with_metadata(metadata)
+//!     .with_instance_name(InstanceName::new("my-test-vm".to_string()).unwrap())
+//!     .with_image("torrust-provisioned-instance:latest".to_string())
+//!     .with_ssh_public_key_content("ssh-rsa AAAA...".to_string())
+//!     .with_ssh_username("torrust".to_string())
+//!     .build()
+//!     .unwrap();
+//! ```
+
+use serde::Serialize;
+use thiserror::Error;
+
+use crate::domain::topology::PortBinding;
+use crate::domain::tracker::Protocol;
+use crate::domain::InstanceName;
+use crate::infrastructure::templating::metadata::TemplateMetadata;
+
+/// Default port sshd listens on inside the container
+const DEFAULT_SSH_PORT: u16 = 22;
+
+/// Errors that can occur when building the Docker variables context
+#[derive(Error, Debug)]
+pub enum VariablesContextError {
+    /// Template metadata is required but was not provided
+    #[error("Template metadata is required but was not provided")]
+    MissingMetadata,
+
+    /// Instance name is required but was not provided
+    #[error("Instance name is required but was not provided")]
+    MissingInstanceName,
+
+    /// Image is required but was not provided
+    #[error("Image is required but was not provided")]
+    MissingImage,
+
+    /// SSH public key content is required but was not provided
+    #[error("SSH public key content is required but was not provided")]
+    MissingSshPublicKeyContent,
+
+    /// SSH username is required but was not provided
+    #[error("SSH username is required but was not provided")]
+    MissingSshUsername,
+}
+
+/// Service port published by the container on the same loopback port of the host
+///
+/// Rendered into the `published_ports` variable.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct PublishedPort {
+    /// Protocol in the form expected by the docker provider (`tcp` or `udp`)
+    pub protocol: String,
+    /// Port published on the host and inside the container
+    pub port: u16,
+    /// Description kept next to the port
+    pub description: String,
+}
+
+impl From<&PortBinding> for PublishedPort {
+    fn from(binding: &PortBinding) -> Self {
+        let protocol = match binding.protocol() {
+            Protocol::Tcp => "tcp",
+            Protocol::Udp => "udp",
+        };
+
+        Self {
+            protocol: protocol.to_string(),
+            port: binding.host_port(),
+            description: binding.description().to_string(),
+        }
+    }
+}
+
+/// Context for Docker `OpenTofu` variables template rendering
+///
+/// Contains all runtime values needed to render `variables.tfvars.tera`
+/// with Docker-specific configuration parameters.
+#[derive(Debug, Clone, Serialize)]
+pub struct VariablesContext {
+    /// Template metadata (generation timestamp, etc.)
+    #[serde(flatten)]
+    pub metadata: TemplateMetadata,
+    /// The name of the container to be created
+    pub instance_name: InstanceName,
+    /// Local Docker image of the instance
+    pub image: String,
+    /// SSH public key content authorized for the SSH user
+    pub ssh_public_key_content: String,
+    /// SSH user existing in the image
+    pub ssh_username: String,
+    /// Port sshd listens on inside the container
+    pub ssh_port: u16,
+    /// Service ports published on the host
+    pub published_ports: Vec<PublishedPort>,
+}
+
+/// Builder for creating Docker `VariablesContext` instances
+///
+/// Provides a fluent interface for constructing the context with validation
+/// to ensure all required fields are provided.
+#[derive(Debug)]
+pub struct VariablesContextBuilder {
+    metadata: Option<TemplateMetadata>,
+    instance_name: Option<InstanceName>,
+    image: Option<String>,
+    ssh_public_key_content: Option<String>,
+    ssh_username: Option<String>,
+    ssh_port: u16,
+    published_ports: Vec<PublishedPort>,
+}
+
+impl Default for VariablesContextBuilder {
+    fn default() -> Self {
+        Self {
+            metadata: None,
+            instance_name: None,
+            image: None,
+            ssh_public_key_content: None,
+            ssh_username: None,
+            ssh_port: DEFAULT_SSH_PORT,
+            published_ports: Vec::new(),
+        }
+    }
+}
+
+impl VariablesContextBuilder {
+    /// Creates a new builder instance
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Sets the template metadata
+    ///
+    /// # Arguments
+    ///
+    /// * `metadata` - The template metadata containing generation timestamp
+    #[must_use]
+    pub fn with_metadata(mut self, metadata: TemplateMetadata) -> Self {
+        self.metadata = Some(metadata);
+        self
+    }
+
+    /// Sets the instance name for the container
+    ///
+    /// # Arguments
+    ///
+    /// * `instance_name` - The name to assign to the created container
+    #[must_use]
+    pub fn with_instance_name(mut self, instance_name: InstanceName) -> Self {
+        self.instance_name = Some(instance_name);
+        self
+    }
+
+    /// Sets the local Docker image of the instance
+    ///
+    /// # Arguments
+    ///
+    /// * `image` - The image reference (e.g., torrust-provisioned-instance:latest)
+    #[must_use]
+    pub fn with_image(mut self, image: String) -> Self {
+        self.image = Some(image);
+        self
+    }
+
+    /// Sets the SSH public key content
+    ///
+    /// # Arguments
+    ///
+    /// * `ssh_public_key_content` - The content of the SSH public key
+    #[must_use]
+    pub fn with_ssh_public_key_content(mut self, ssh_public_key_content: String) -> Self {
+        self.ssh_public_key_content = Some(ssh_public_key_content);
+        self
+    }
+
+    /// Sets the SSH user whose authorized keys are written
+    ///
+    /// # Arguments
+    ///
+    /// * `ssh_username` - A user existing in the image
+    #[must_use]
+    pub fn with_ssh_username(mut self, ssh_username: String) -> Self {
+        self.ssh_username = Some(ssh_username);
+        self
+    }
+
+    /// Sets the port sshd listens on inside the container
+    ///
+    /// Optional, defaults to 22. Docker publishes it on a random host port.
+    ///
+    /// # Arguments
+    ///
+    /// * `ssh_port` - The SSH port inside the container
+    #[must_use]
+    pub fn with_ssh_port(mut self, ssh_port: u16) -> Self {
+        self.ssh_port = ssh_port;
+        self
+    }
+
+    /// Sets the service ports published on the host
+    ///
+    /// Optional. Without ports only SSH is reachable from the host.
+    ///
+    /// # Arguments
+    ///
+    /// * `published_ports` - The ports of the enabled services
+    #[must_use]
+    pub fn with_published_ports(mut self, published_ports: Vec<PublishedPort>) -> Self {
+        self.published_ports = published_ports;
+        self
+    }
+
+    /// Builds the `VariablesContext` with validation
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(VariablesContext)` if all required fields are present
+    /// * `Err(VariablesContextError)` if validation fails
+    ///
+    /// # Errors
+    ///
+    /// Returns appropriate error variant for each missing required field
+    pub fn build(self) -> Result<VariablesContext, VariablesContextError> {
+        let metadata = self
+            .metadata
+            .ok_or(VariablesContextError::MissingMetadata)?;
+
+        let instance_name = self
+            .instance_name
+            .ok_or(VariablesContextError::MissingInstanceName)?;
+
+        let image = self.image.ok_or(VariablesContextError::MissingImage)?;
+
+        let ssh_public_key_content = self
+            .ssh_public_key_content
+            .ok_or(VariablesContextError::MissingSshPublicKeyContent)?;
+
+        let ssh_username = self
+            .ssh_username
+            .ok_or(VariablesContextError::MissingSshUsername)?;
+
+        Ok(VariablesContext {
+            metadata,
+            instance_name,
+            image,
+            ssh_public_key_content,
+            ssh_username,
+            ssh_port: self.ssh_port,
+            published_ports: self.published_ports,
+        })
+    }
+}
+
+impl VariablesContext {
+    /// Creates a new builder for constructing `VariablesContext`
+    #[must_use]
+    pub fn builder() -> VariablesContextBuilder {
+        VariablesContextBuilder::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::Utc;
+
+    use super::*;
+
+    fn create_valid_builder() -> VariablesContextBuilder {
+        VariablesContext::builder()
+            .with_metadata(TemplateMetadata::new(Utc::now()))
+            .with_instance_name(InstanceName::new("test-vm".to_string()).unwrap())
+            .with_image("torrust-provisioned-instance:latest".to_string())
+            .with_ssh_public_key_content("ssh-rsa AAAA... test@example.com".to_string())
+            .with_ssh_username("torrust".to_string())
+    }
+
+    #[test]
+    fn it_should_create_variables_context_with_all_required_fields() {
+        let context = create_valid_builder().build().unwrap();
+
+        assert_eq!(context.instance_name.as_str(), "test-vm");
+        assert_eq!(context.image, "torrust-provisioned-instance:latest");
+        assert_eq!(context.ssh_username, "torrust");
+        assert_eq!(context.ssh_port, 22);
+        assert!(context.published_ports.is_empty());
+    }
+
+    #[test]
+    fn it_should_convert_port_bindings_to_published_ports() {
+        let port = PublishedPort::from(&PortBinding::udp(6969, "BitTorrent UDP announce"));
+
+        assert_eq!(
+            port,
+            PublishedPort {
+                protocol: "udp".to_string(),
+                port: 6969,
+                description: "BitTorrent UDP announce".to_string(),
+            }
+        );
+    }
+
+    #[test]
+    fn it_should_fail_when_image_is_missing() {
+        let result = VariablesContext::builder()
+            .with_metadata(TemplateMetadata::new(Utc::now()))
+            .with_instance_name(InstanceName::new("test-vm".to_string()).unwrap())
+            .with_ssh_public_key_content("ssh-rsa AAAA...".to_string())
+            .with_ssh_username("torrust".to_string())
+            .build();
+
+        assert!(matches!(
+            result.unwrap_err(),
+            VariablesContextError::MissingImage
+        ));
+    }
+}
@@ -0,0 +1,16 @@
+//! # Docker `OpenTofu` Variables Templates
+//!
+//! Template wrappers for rendering `variables.tfvars.tera` with Docker-specific configuration.
+//!
+//! This module provides the `VariablesTemplate` and `VariablesContext` for validating and rendering `OpenTofu`
+//! variable files with runtime context injection, specifically for parameterizing
+//! the local Docker container used as instance.
+
+pub mod context;
+mod variables_template;
+
+pub use crate::infrastructure::templating::tofu::template::common::wrappers::VariablesTemplateError;
+pub use context::{
+    PublishedPort, VariablesContext, VariablesContextBuilder, VariablesContextError,
+};
+pub use variables_template::VariablesTemplate;
@@ -0,0 +1,158 @@
+//! `VariablesTemplate` type and implementation for Docker.
+
+use std::path::Path;
+
+use crate::domain::template::file::File;
+use crate::domain::template::{
+    write_file_with_dir_creation, ExtraTemplateVariables, TemplateEngine,
+};
+use crate::infrastructure::templating::tofu::template::common::wrappers::VariablesTemplateError;
+
+use super::context::VariablesContext;
+
+/// Template wrapper for Docker `OpenTofu` variables rendering
+///
+/// Validates and renders `variables.tfvars.tera` templates with `VariablesContext`
+/// to produce dynamic infrastructure variable files for Docker.
+#[derive(Debug)]
+pub struct VariablesTemplate {
+    context: VariablesContext,
+    content: String,
+}
+
+impl VariablesTemplate {
+    /// Creates a new Docker variables template with validation
+    ///
+    /// # Arguments
+    ///
+    /// * `template_file` - The template file containing variables.tfvars.tera content
+    /// * `context` - The context containing Docker-specific runtime values
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(VariablesTemplate)` if template validation succeeds
+    /// * `Err(VariablesTemplateError)` if validation fails
+    ///
+    /// # Errors
+    ///
+    /// Returns `TemplateEngineError` if the template has syntax errors or validation fails
+    pub fn new(
+        template_file: &File,
+        context: VariablesContext,
+        extra_variables: &ExtraTemplateVariables,
+    ) -> Result<Self, VariablesTemplateError> {
+        let mut engine = TemplateEngine::new().with_extra_variables(extra_variables);
+
+        let validated_content =
+            engine.render(template_file.filename(), template_file.content(), &context)?;
+
+        Ok(Self {
+            context,
+            content: validated_content,
+        })
+    }
+
+    /// Get the instance name value
+    #[must_use]
+    pub fn instance_name(&self) -> &str {
+        self.context.instance_name.as_str()
+    }
+
+    /// Render the template to a file at the specified output path
+    ///
+    /// # Errors
+    /// Returns `FileOperationError::DirectoryCreation` if the parent directory cannot be created,
+    /// or `FileOperationError::FileWrite` if the file cannot be written
+    pub fn render(&self, output_path: &Path) -> Result<(), VariablesTemplateError> {
+        write_file_with_dir_creation(output_path, &self.content)?;
+        Ok(())
+    }
+
+    /// Gets the context used by this template
+    #[must_use]
+    pub fn context(&self) -> &VariablesContext {
+        &self.context
+    }
+
+    /// Gets the rendered content
+    #[must_use]
+    pub fn content(&self) -> &str {
+        &self.content
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::Utc;
+
+    use super::*;
+    use crate::domain::topology::PortBinding;
+    use crate::domain::InstanceName;
+    use crate::infrastructure::templating::metadata::TemplateMetadata;
+    use crate::infrastructure::templating::tofu::template::providers::docker::wrappers::variables::{
+        PublishedPort, VariablesContextBuilder,
+    };
+    use tempfile::NamedTempFile;
+
+    fn create_test_context_builder() -> VariablesContextBuilder {
+        VariablesContext::builder()
+            .with_metadata(TemplateMetadata::new(Utc::now()))
+            .with_instance_name(InstanceName::new("test-instance".to_string()).unwrap())
+            .with_image("torrust-provisioned-instance:latest".to_string())
+            .with_ssh_public_key_content("ssh-rsa AAAA... test@example.com".to_string())
+            .with_ssh_username("torrust".to_string())
+    }
+
+    fn docker_template_file() -> File {
+        let template_path = std::path::Path::new(env!("CARGO_MANIFEST_DIR"))
+            .join("templates/tofu/docker/variables.tfvars.tera");
+        File::new(
+            "variables.tfvars.tera",
+            std::fs::read_to_string(template_path).unwrap(),
+        )
+        .unwrap()
+    }
+
+    fn render(context: VariablesContext) -> String {
+        let temp_file = NamedTempFile::new().unwrap();
+        VariablesTemplate::new(
+            &docker_template_file(),
+            context,
+            &ExtraTemplateVariables::default(),
+        )
+        .unwrap()
+        .render(temp_file.path())
+        .unwrap();
+        std::fs::read_to_string(temp_file.path()).unwrap()
+    }
+
+    #[test]
+    fn it_should_render_the_container_settings_with_the_docker_template() {
+        let rendered = render(create_test_context_builder().build().unwrap());
+
+        assert!(rendered.contains(r#"instance_name = "test-instance""#));
+        assert!(rendered.contains(r#"image = "torrust-provisioned-instance:latest""#));
+        assert!(rendered.contains(r#"ssh_username   = "torrust""#));
+        assert!(rendered.contains("ssh_port       = 22"));
+    }
+
+    #[test]
+    fn it_should_render_published_ports_with_the_docker_template() {
+        let context = create_test_context_builder()
+            .with_published_ports(vec![
+                PublishedPort::from(&PortBinding::udp(6969, "BitTorrent UDP announce")),
+                PublishedPort::from(&PortBinding::tcp(7070, "HTTP tracker announce")),
+            ])
+            .build()
+            .unwrap();
+
+        let rendered = render(context);
+
+        assert!(rendered.contains(
+            r#"{ protocol = "udp", port = 6969, description = "BitTorrent UDP announce" },"#
+        ));
+        assert!(rendered.contains(
+            r#"{ protocol = "tcp", port = 7070, description = "HTTP tracker announce" },"#
+        ));
+    }
+}
@@ -14,6 +14,7 @@
 //! - `server_location` - Datacenter location (e.g., nbg1, fsn1)
 //! - `server_image` - OS image (e.g., ubuntu-24.04)
 //! - `ssh_public_key_content` - SSH public key content for server access
+//! - `firewall_rules` - Inbound ports opened in the Hetzner Cloud firewall
 //!
 //! ## Example Usage
 //!
@@ -39,6 +40,9 @@
 use serde::Serialize;
 use thiserror::Error;
 
+use crate::domain::firewall::FirewallRule as DomainFirewallRule;
+use crate::domain::topology::PortBinding;
+use crate::domain::tracker::Protocol;
 use crate::domain::InstanceName;
 use crate::infrastructure::templating::metadata::TemplateMetadata;
 use crate::shared::ApiToken;
@@ -75,6 +79,81 @@ pub enum VariablesContextError {
     MissingSshPublicKeyContent,
 }
 
+/// Inbound rule of the Hetzner Cloud firewall
+///
+/// Rendered into the `firewall_rules` variable. Rules derived from the
+/// services allow traffic from any IPv4 and IPv6 address; extra rules from the
+/// environment's firewall configuration may restrict the source network.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct FirewallRule {
+    /// Protocol in the form expected by the hcloud provider (`tcp` or `udp`)
+    pub protocol: String,
+    /// Port to open
+    pub port: u16,
+    /// Description shown in the Hetzner Console
+    pub description: String,
+    /// Source networks allowed to connect, in CIDR notation
+    pub source_ips: Vec<String>,
+}
+
+impl FirewallRule {
+    /// Rule allowing SSH access on the given port
+    #[must_use]
+    pub fn ssh(port: u16) -> Self {
+        Self {
+            protocol: "tcp".to_string(),
+            port,
+            description: "SSH".to_string(),
+            source_ips: any_source(),
+        }
+    }
+}
+
+/// Source networks matching every IPv4 and IPv6 address
+fn any_source() -> Vec<String> {
+    vec!["0.0.0.0/0".to_string(), "::/0".to_string()]
+}
+
+impl From<&PortBinding> for FirewallRule {
+    fn from(binding: &PortBinding) -> Self {
+        let protocol = match binding.protocol() {
+            Protocol::Tcp => "tcp",
+            Protocol::Udp => "udp",
+        };
+
+        Self {
+            protocol: protocol.to_string(),
+            port: binding.host_port(),
+            description: binding.description().to_string(),
+            source_ips: any_source(),
+        }
+    }
+}
+
+impl From<&DomainFirewallRule> for FirewallRule {
+    fn from(rule: &DomainFirewallRule) -> Self {
+        let protocol = match rule.protocol() {
+            Protocol::Tcp => "tcp",
+            Protocol::Udp => "udp",
+        };
+
+        // Hetzner only accepts networks: a plain address becomes a host route
+        let source_ips = match rule.cidr() {
+            Some(cidr) if cidr.contains('/') => vec![cidr.to_string()],
+            Some(address) if address.contains(':') => vec![format!("{address}/128")],
+            Some(address) => vec![format!("{address}/32")],
+            None => any_source(),
+        };
+
+        Self {
+            protocol: protocol.to_string(),
+            port: rule.port(),
+            description: "Extra allow rule".to_string(),
+            source_ips,
+        }
+    }
+}
+
 /// Context for Hetzner Cloud `OpenTofu` variables template rendering
 ///
 /// Contains all runtime values needed to render `variables.tfvars.tera`
@@ -96,6 +175,8 @@ pub struct VariablesContext {
     pub server_image: String,
     /// SSH public key content for server access
     pub ssh_public_key_content: String,
+    /// Inbound rules of the Hetzner Cloud firewall attached to the server
+    pub firewall_rules: Vec<FirewallRule>,
 }
 
 /// Builder for creating Hetzner `VariablesContext` instances
@@ -111,6 +192,7 @@ pub struct VariablesContextBuilder {
     server_location: Option<String>,
     server_image: Option<String>,
     ssh_public_key_content: Option<String>,
+    firewall_rules: Vec<FirewallRule>,
 }
 
 impl VariablesContextBuilder {
@@ -197,6 +279,19 @@ impl VariablesContextBuilder {
         self
     }
 
+    /// Sets the inbound firewall rules
+    ///
+    /// Optional. Without rules the firewall blocks all inbound traffic.
+    ///
+    /// # Arguments
+    ///
+    /// * `firewall_rules` - The ports to open in the Hetzner Cloud firewall
+    #[must_use]
+    pub fn with_firewall_rules(mut self, firewall_rules: Vec<FirewallRule>) -> Self {
+        self.firewall_rules = firewall_rules;
+        self
+    }
+
     /// Builds the `VariablesContext` with validation
     ///
     /// # Returns
@@ -244,6 +339,7 @@ impl VariablesContextBuilder {
             server_location,
             server_image,
             ssh_public_key_content,
+            firewall_rules: self.firewall_rules,
         })
     }
 }
@@ -307,6 +403,58 @@ mod tests {
         assert!(json.contains("generated_at"));
     }
 
+    #[test]
+    fn it_should_have_no_firewall_rules_by_default() {
+        let context = create_valid_builder().build().unwrap();
+
+        assert!(context.firewall_rules.is_empty());
+    }
+
+    #[test]
+    fn it_should_convert_port_bindings_to_firewall_rules() {
+        let rules: Vec<FirewallRule> = [
+            PortBinding::udp(6969, "BitTorrent UDP announce"),
+            PortBinding::tcp(7070, "HTTP tracker announce"),
+        ]
+        .iter()
+        .map(FirewallRule::from)
+        .collect();
+
+        let context = create_valid_builder()
+            .with_firewall_rules(rules)
+            .build()
+            .unwrap();
+
+        assert_eq!(
+            context.firewall_rules,
+            vec![
+                FirewallRule {
+                    protocol: "udp".to_string(),
+                    port: 6969,
+                    description: "BitTorrent UDP announce".to_string(),
+                    source_ips: vec!["0.0.0.0/0".to_string(), "::/0".to_string()],
+                },
+                FirewallRule {
+                    protocol: "tcp".to_string(),
+                    port: 7070,
+                    description: "HTTP tracker announce".to_string(),
+                    source_ips: vec!["0.0.0.0/0".to_string(), "::/0".to_string()],
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn it_should_restrict_extra_allow_rules_to_their_source_network() {
+        let network =
+            DomainFirewallRule::new(9100, Protocol::Tcp, Some("10.0.0.0/8".to_string())).unwrap();
+        let host =
+            DomainFirewallRule::new(9100, Protocol::Tcp, Some("203.0.113.7".to_string())).unwrap();
+
+        assert_eq!(FirewallRule::from(&network).source_ips, vec!["10.0.0.0/8"]);
+        assert_eq!(FirewallRule::from(&host).source_ips, vec!["203.0.113.7/32"]);
+    }
+
     #[test]
     fn it_should_fail_when_instance_name_is_missing() {
         let metadata = create_test_metadata();
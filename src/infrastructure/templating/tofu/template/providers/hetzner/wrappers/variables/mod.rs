@@ -10,5 +10,5 @@ pub mod context;
 mod variables_template;
 
 pub use crate::infrastructure::templating::tofu::template::common::wrappers::VariablesTemplateError;
-pub use context::{VariablesContext, VariablesContextBuilder, VariablesContextError};
+pub use context::{FirewallRule, VariablesContext, VariablesContextBuilder, VariablesContextError};
 pub use variables_template::VariablesTemplate;
@@ -3,7 +3,9 @@
 use std::path::Path;
 
 use crate::domain::template::file::File;
-use crate::domain::template::{write_file_with_dir_creation, TemplateEngine};
+use crate::domain::template::{
+    write_file_with_dir_creation, ExtraTemplateVariables, TemplateEngine,
+};
 use crate::infrastructure::templating::tofu::template::common::wrappers::VariablesTemplateError;
 
 use super::context::VariablesContext;
@@ -37,8 +39,9 @@ impl VariablesTemplate {
     pub fn new(
         template_file: &File,
         context: VariablesContext,
+        extra_variables: &ExtraTemplateVariables,
     ) -> Result<Self, VariablesTemplateError> {
-        let mut engine = TemplateEngine::new();
+        let mut engine = TemplateEngine::new().with_extra_variables(extra_variables);
 
         let validated_content =
             engine.render(template_file.filename(), template_file.content(), &context)?;
@@ -83,8 +86,10 @@ mod tests {
     use chrono::Utc;
 
     use super::*;
+    use crate::domain::topology::PortBinding;
     use crate::domain::InstanceName;
     use crate::infrastructure::templating::metadata::TemplateMetadata;
+    use crate::infrastructure::templating::tofu::template::providers::hetzner::wrappers::variables::FirewallRule;
     use tempfile::NamedTempFile;
 
     fn create_test_context() -> VariablesContext {
@@ -110,7 +115,8 @@ server_name = "{{ instance_name }}""#;
             File::new("variables.tfvars.tera", template_content.to_string()).unwrap();
         let context = create_test_context();
 
-        let result = VariablesTemplate::new(&template_file, context);
+        let result =
+            VariablesTemplate::new(&template_file, context, &ExtraTemplateVariables::default());
         assert!(result.is_ok());
     }
 
@@ -123,7 +129,8 @@ server_name = "{{ instance_name }}""#; // Missing closing }}
             File::new("variables.tfvars.tera", template_content.to_string()).unwrap();
         let context = create_test_context();
 
-        let result = VariablesTemplate::new(&template_file, context);
+        let result =
+            VariablesTemplate::new(&template_file, context, &ExtraTemplateVariables::default());
         assert!(matches!(
             result.unwrap_err(),
             VariablesTemplateError::TemplateEngineError { .. }
@@ -139,7 +146,8 @@ server_type = "cx22""#;
             File::new("variables.tfvars.tera", template_content.to_string()).unwrap();
         let context = create_test_context();
 
-        let result = VariablesTemplate::new(&template_file, context);
+        let result =
+            VariablesTemplate::new(&template_file, context, &ExtraTemplateVariables::default());
         assert!(result.is_ok());
     }
 
@@ -151,7 +159,8 @@ server_type = "cx22""#;
             File::new("variables.tfvars.tera", template_content.to_string()).unwrap();
         let context = create_test_context();
 
-        let result = VariablesTemplate::new(&template_file, context);
+        let result =
+            VariablesTemplate::new(&template_file, context, &ExtraTemplateVariables::default());
         assert!(result.is_ok());
     }
 
@@ -165,7 +174,9 @@ server_type = "{{ server_type }}""#;
         let template_file =
             File::new("variables.tfvars.tera", template_content.to_string()).unwrap();
         let context = create_test_context();
-        let variables_template = VariablesTemplate::new(&template_file, context).unwrap();
+        let variables_template =
+            VariablesTemplate::new(&template_file, context, &ExtraTemplateVariables::default())
+                .unwrap();
 
         let temp_file = NamedTempFile::new().unwrap();
         let result = variables_template.render(temp_file.path());
@@ -183,7 +194,9 @@ server_type = "{{ server_type }}""#;
     fn it_should_provide_access_to_context() {
         let template_file = File::new("variables.tfvars.tera", String::new()).unwrap();
         let context = create_test_context();
-        let variables_template = VariablesTemplate::new(&template_file, context).unwrap();
+        let variables_template =
+            VariablesTemplate::new(&template_file, context, &ExtraTemplateVariables::default())
+                .unwrap();
 
         assert_eq!(
             variables_template.context().instance_name.as_str(),
@@ -197,7 +210,9 @@ server_type = "{{ server_type }}""#;
         let template_file =
             File::new("variables.tfvars.tera", template_content.to_string()).unwrap();
         let context = create_test_context();
-        let variables_template = VariablesTemplate::new(&template_file, context).unwrap();
+        let variables_template =
+            VariablesTemplate::new(&template_file, context, &ExtraTemplateVariables::default())
+                .unwrap();
 
         assert!(variables_template.content().contains("test-instance"));
     }
@@ -211,7 +226,9 @@ server_type = "cx22""#;
         let template_file =
             File::new("variables.tfvars.tera", template_content.to_string()).unwrap();
         let context = create_test_context();
-        let variables_template = VariablesTemplate::new(&template_file, context).unwrap();
+        let variables_template =
+            VariablesTemplate::new(&template_file, context, &ExtraTemplateVariables::default())
+                .unwrap();
 
         let temp_file = NamedTempFile::new().unwrap();
         let result = variables_template.render(temp_file.path());
@@ -232,7 +249,8 @@ server_type = "cx22""#;
         let context = create_test_context();
 
         // Should fail at construction, not during render
-        let result = VariablesTemplate::new(&template_file, context);
+        let result =
+            VariablesTemplate::new(&template_file, context, &ExtraTemplateVariables::default());
         assert!(matches!(
             result.unwrap_err(),
             VariablesTemplateError::TemplateEngineError { .. }
@@ -255,10 +273,51 @@ server_type = "cx22""#;
             .build()
             .unwrap();
 
-        let variables_template = VariablesTemplate::new(&template_file, context).unwrap();
+        let variables_template =
+            VariablesTemplate::new(&template_file, context, &ExtraTemplateVariables::default())
+                .unwrap();
         assert_eq!(
             variables_template.context().instance_name.as_str(),
             "dynamic-vm"
         );
     }
+
+    #[test]
+    fn it_should_render_firewall_rules_with_the_hetzner_template() {
+        let template_path = std::path::Path::new(env!("CARGO_MANIFEST_DIR"))
+            .join("templates/tofu/hetzner/variables.tfvars.tera");
+        let template_file = File::new(
+            "variables.tfvars.tera",
+            std::fs::read_to_string(template_path).unwrap(),
+        )
+        .unwrap();
+        let context = VariablesContext::builder()
+            .with_metadata(TemplateMetadata::new(Utc::now()))
+            .with_instance_name(InstanceName::new("test-instance".to_string()).unwrap())
+            .with_hcloud_api_token("test-api-token".to_string())
+            .with_server_type("cx22".to_string())
+            .with_server_location("nbg1".to_string())
+            .with_server_image("ubuntu-24.04".to_string())
+            .with_ssh_public_key_content("ssh-rsa AAAA...".to_string())
+            .with_firewall_rules(vec![
+                FirewallRule::ssh(22),
+                FirewallRule::from(&PortBinding::udp(6969, "BitTorrent UDP announce")),
+            ])
+            .build()
+            .unwrap();
+
+        let temp_file = NamedTempFile::new().unwrap();
+        VariablesTemplate::new(&template_file, context, &ExtraTemplateVariables::default())
+            .unwrap()
+            .render(temp_file.path())
+            .unwrap();
+
+        let rendered = std::fs::read_to_string(temp_file.path()).unwrap();
+        assert!(rendered.contains(
+            r#"{ protocol = "tcp", port = "22", description = "SSH", source_ips = ["0.0.0.0/0", "::/0"] },"#
+        ));
+        assert!(rendered.contains(
+            r#"{ protocol = "udp", port = "6969", description = "BitTorrent UDP announce", source_ips = ["0.0.0.0/0", "::/0"] },"#
+        ));
+    }
 }
@@ -0,0 +1,10 @@
+//! Libvirt provider-specific `OpenTofu` template functionality.
+//!
+//! This module contains template wrappers and utilities specific to the libvirt provider.
+//!
+//! The virtual machine runs the shared cloud-init template, attached to it as
+//! a cloud-init ISO by the libvirt `main.tf`.
+
+pub mod wrappers;
+
+pub use wrappers::variables;
@@ -0,0 +1,11 @@
+//! `OpenTofu` libvirt template wrappers
+//!
+//! Contains template wrappers for libvirt-specific configuration files.
+//!
+//! - `variables` - templates/tofu/libvirt/variables.tfvars.tera (with runtime variables: `instance_name`, `pool`, etc.)
+
+pub mod variables;
+
+pub use variables::{
+    VariablesContext, VariablesContextBuilder, VariablesContextError, VariablesTemplate,
+};
@@ -0,0 +1,246 @@
+//! # Libvirt `OpenTofu` Variables Context
+//!
+//! Provides context structures for libvirt `OpenTofu` variables template rendering.
+//!
+//! This module contains the context object that holds runtime values for variable template rendering,
+//! specifically for the `variables.tfvars.tera` template used in libvirt infrastructure provisioning.
+//!
+//! ## Context Structure
+//!
+//! The `VariablesContext` holds:
+//! - `instance_name` - The dynamic name for the virtual machine
+//! - `uri` - The connection URI of the libvirt daemon
+//! - `pool` - The storage pool of the instance disk and cloud-init ISO
+//! - `network_name` - The libvirt network the instance is attached to
+//! - `image` - The cloud image the instance disk is created from
+//!
+//! ## Example Usage
+//!
+//! ```rust
+//! use torrust_tracker_deployer_lib::infrastructure::templating::tofu::template::providers::libvirt::wrappers::variables::VariablesContext;
+//! use torrust_tracker_deployer_lib::infrastructure::templating::metadata::TemplateMetadata;
+//! use torrust_tracker_deployer_lib::domain::InstanceName;
+//! use chrono::Utc;
+//!
+//! let metadata = TemplateMetadata::new(Utc::now());
+//! let context = VariablesContext::builder()
+//!     .with_metadata(metadata)
+//!     .with_instance_name(InstanceName::new("my-test-vm".to_string()).unwrap())
+//!     .with_pool("default".to_string())
+//!     .with_network_name("default".to_string())
+//!     .with_image("/var/lib/libvirt/images/noble-server-cloudimg-amd64.img".to_string())
+//!     .build()
+//!     .unwrap();
+//! ```
+
+use serde::Serialize;
+use thiserror::Error;
+
+use crate::domain::provider::LIBVIRT_URI;
+use crate::domain::InstanceName;
+use crate::infrastructure::templating::metadata::TemplateMetadata;
+
+/// Errors that can occur when building the libvirt variables context
+#[derive(Error, Debug)]
+pub enum VariablesContextError {
+    /// Template metadata is required but was not provided
+    #[error("Template metadata is required but was not provided")]
+    MissingMetadata,
+
+    /// Instance name is required but was not provided
+    #[error("Instance name is required but was not provided")]
+    MissingInstanceName,
+
+    /// Storage pool is required but was not provided
+    #[error("Storage pool is required but was not provided")]
+    MissingPool,
+
+    /// Network name is required but was not provided
+    #[error("Network name is required but was not provided")]
+    MissingNetworkName,
+
+    /// Image is required but was not provided
+    #[error("Image is required but was not provided")]
+    MissingImage,
+}
+
+/// Context for libvirt `OpenTofu` variables template rendering
+///
+/// Contains all runtime values needed to render `variables.tfvars.tera`
+/// with libvirt-specific configuration parameters.
+#[derive(Debug, Clone, Serialize)]
+pub struct VariablesContext {
+    /// Template metadata (generation timestamp, etc.)
+    #[serde(flatten)]
+    pub metadata: TemplateMetadata,
+    /// The name of the virtual machine to be created
+    pub instance_name: InstanceName,
+    /// Connection URI of the libvirt daemon
+    pub uri: String,
+    /// Storage pool of the instance disk and cloud-init ISO
+    pub pool: String,
+    /// Libvirt network the instance is attached to
+    pub network_name: String,
+    /// Cloud image the instance disk is created from (path or URL)
+    pub image: String,
+}
+
+/// Builder for creating libvirt `VariablesContext` instances
+///
+/// Provides a fluent interface for constructing the context with validation
+/// to ensure all required fields are provided.
+#[derive(Debug, Default)]
+pub struct VariablesContextBuilder {
+    metadata: Option<TemplateMetadata>,
+    instance_name: Option<InstanceName>,
+    pool: Option<String>,
+    network_name: Option<String>,
+    image: Option<String>,
+}
+
+impl VariablesContextBuilder {
+    /// Creates a new builder instance
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Sets the template metadata
+    ///
+    /// # Arguments
+    ///
+    /// * `metadata` - The template metadata containing generation timestamp
+    #[must_use]
+    pub fn with_metadata(mut self, metadata: TemplateMetadata) -> Self {
+        self.metadata = Some(metadata);
+        self
+    }
+
+    /// Sets the instance name for the virtual machine
+    ///
+    /// # Arguments
+    ///
+    /// * `instance_name` - The name to assign to the created domain
+    #[must_use]
+    pub fn with_instance_name(mut self, instance_name: InstanceName) -> Self {
+        self.instance_name = Some(instance_name);
+        self
+    }
+
+    /// Sets the storage pool of the instance volumes
+    ///
+    /// # Arguments
+    ///
+    /// * `pool` - An existing storage pool (e.g., default)
+    #[must_use]
+    pub fn with_pool(mut self, pool: String) -> Self {
+        self.pool = Some(pool);
+        self
+    }
+
+    /// Sets the network the instance is attached to
+    ///
+    /// # Arguments
+    ///
+    /// * `network_name` - An existing libvirt network with DHCP (e.g., default)
+    #[must_use]
+    pub fn with_network_name(mut self, network_name: String) -> Self {
+        self.network_name = Some(network_name);
+        self
+    }
+
+    /// Sets the cloud image the instance disk is created from
+    ///
+    /// # Arguments
+    ///
+    /// * `image` - An absolute path on the host or an HTTP(S) URL
+    #[must_use]
+    pub fn with_image(mut self, image: String) -> Self {
+        self.image = Some(image);
+        self
+    }
+
+    /// Builds the `VariablesContext` with validation
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(VariablesContext)` if all required fields are present
+    /// * `Err(VariablesContextError)` if validation fails
+    ///
+    /// # Errors
+    ///
+    /// Returns appropriate error variant for each missing required field
+    pub fn build(self) -> Result<VariablesContext, VariablesContextError> {
+        let metadata = self
+            .metadata
+            .ok_or(VariablesContextError::MissingMetadata)?;
+
+        let instance_name = self
+            .instance_name
+            .ok_or(VariablesContextError::MissingInstanceName)?;
+
+        let pool = self.pool.ok_or(VariablesContextError::MissingPool)?;
+
+        let network_name = self
+            .network_name
+            .ok_or(VariablesContextError::MissingNetworkName)?;
+
+        let image = self.image.ok_or(VariablesContextError::MissingImage)?;
+
+        Ok(VariablesContext {
+            metadata,
+            instance_name,
+            uri: LIBVIRT_URI.to_string(),
+            pool,
+            network_name,
+            image,
+        })
+    }
+}
+
+impl VariablesContext {
+    /// Creates a new builder for constructing `VariablesContext`
+    #[must_use]
+    pub fn builder() -> VariablesContextBuilder {
+        VariablesContextBuilder::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::Utc;
+
+    use super::*;
+
+    #[test]
+    fn it_should_create_variables_context_with_all_required_fields() {
+        let context = VariablesContext::builder()
+            .with_metadata(TemplateMetadata::new(Utc::now()))
+            .with_instance_name(InstanceName::new("test-vm".to_string()).unwrap())
+            .with_pool("default".to_string())
+            .with_network_name("default".to_string())
+            .with_image("/var/lib/libvirt/images/noble.img".to_string())
+            .build()
+            .unwrap();
+
+        assert_eq!(context.instance_name.as_str(), "test-vm");
+        assert_eq!(context.uri, "qemu:///system");
+        assert_eq!(context.pool, "default");
+        assert_eq!(context.image, "/var/lib/libvirt/images/noble.img");
+    }
+
+    #[test]
+    fn it_should_fail_when_network_name_is_missing() {
+        let result = VariablesContext::builder()
+            .with_metadata(TemplateMetadata::new(Utc::now()))
+            .with_instance_name(InstanceName::new("test-vm".to_string()).unwrap())
+            .with_pool("default".to_string())
+            .with_image("/var/lib/libvirt/images/noble.img".to_string())
+            .build();
+
+        assert!(matches!(
+            result.unwrap_err(),
+            VariablesContextError::MissingNetworkName
+        ));
+    }
+}
@@ -0,0 +1,14 @@
+//! # Libvirt `OpenTofu` Variables Templates
+//!
+//! Template wrappers for rendering `variables.tfvars.tera` with libvirt-specific configuration.
+//!
+//! This module provides the `VariablesTemplate` and `VariablesContext` for validating and rendering `OpenTofu`
+//! variable files with runtime context injection, specifically for parameterizing
+//! the local KVM virtual machine used as instance.
+
+pub mod context;
+mod variables_template;
+
+pub use crate::infrastructure::templating::tofu::template::common::wrappers::VariablesTemplateError;
+pub use context::{VariablesContext, VariablesContextBuilder, VariablesContextError};
+pub use variables_template::VariablesTemplate;
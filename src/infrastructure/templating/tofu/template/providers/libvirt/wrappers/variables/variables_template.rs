@@ -0,0 +1,131 @@
+//! `VariablesTemplate` type and implementation for libvirt.
+
+use std::path::Path;
+
+use crate::domain::template::file::File;
+use crate::domain::template::{
+    write_file_with_dir_creation, ExtraTemplateVariables, TemplateEngine,
+};
+use crate::infrastructure::templating::tofu::template::common::wrappers::VariablesTemplateError;
+
+use super::context::VariablesContext;
+
+/// Template wrapper for libvirt `OpenTofu` variables rendering
+///
+/// Validates and renders `variables.tfvars.tera` templates with `VariablesContext`
+/// to produce dynamic infrastructure variable files for libvirt.
+#[derive(Debug)]
+pub struct VariablesTemplate {
+    context: VariablesContext,
+    content: String,
+}
+
+impl VariablesTemplate {
+    /// Creates a new libvirt variables template with validation
+    ///
+    /// # Arguments
+    ///
+    /// * `template_file` - The template file containing variables.tfvars.tera content
+    /// * `context` - The context containing libvirt-specific runtime values
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(VariablesTemplate)` if template validation succeeds
+    /// * `Err(VariablesTemplateError)` if validation fails
+    ///
+    /// # Errors
+    ///
+    /// Returns `TemplateEngineError` if the template has syntax errors or validation fails
+    pub fn new(
+        template_file: &File,
+        context: VariablesContext,
+        extra_variables: &ExtraTemplateVariables,
+    ) -> Result<Self, VariablesTemplateError> {
+        let mut engine = TemplateEngine::new().with_extra_variables(extra_variables);
+
+        let validated_content =
+            engine.render(template_file.filename(), template_file.content(), &context)?;
+
+        Ok(Self {
+            context,
+            content: validated_content,
+        })
+    }
+
+    /// Get the instance name value
+    #[must_use]
+    pub fn instance_name(&self) -> &str {
+        self.context.instance_name.as_str()
+    }
+
+    /// Render the template to a file at the specified output path
+    ///
+    /// # Errors
+    /// Returns `FileOperationError::DirectoryCreation` if the parent directory cannot be created,
+    /// or `FileOperationError::FileWrite` if the file cannot be written
+    pub fn render(&self, output_path: &Path) -> Result<(), VariablesTemplateError> {
+        write_file_with_dir_creation(output_path, &self.content)?;
+        Ok(())
+    }
+
+    /// Gets the context used by this template
+    #[must_use]
+    pub fn context(&self) -> &VariablesContext {
+        &self.context
+    }
+
+    /// Gets the rendered content
+    #[must_use]
+    pub fn content(&self) -> &str {
+        &self.content
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::Utc;
+
+    use super::*;
+    use crate::domain::InstanceName;
+    use crate::infrastructure::templating::metadata::TemplateMetadata;
+    use tempfile::NamedTempFile;
+
+    fn libvirt_template_file() -> File {
+        let template_path = std::path::Path::new(env!("CARGO_MANIFEST_DIR"))
+            .join("templates/tofu/libvirt/variables.tfvars.tera");
+        File::new(
+            "variables.tfvars.tera",
+            std::fs::read_to_string(template_path).unwrap(),
+        )
+        .unwrap()
+    }
+
+    #[test]
+    fn it_should_render_the_virtual_machine_settings_with_the_libvirt_template() {
+        let context = VariablesContext::builder()
+            .with_metadata(TemplateMetadata::new(Utc::now()))
+            .with_instance_name(InstanceName::new("test-instance".to_string()).unwrap())
+            .with_pool("images".to_string())
+            .with_network_name("torrust".to_string())
+            .with_image("/var/lib/libvirt/images/noble.img".to_string())
+            .build()
+            .unwrap();
+        let temp_file = NamedTempFile::new().unwrap();
+
+        VariablesTemplate::new(
+            &libvirt_template_file(),
+            context,
+            &ExtraTemplateVariables::default(),
+        )
+        .unwrap()
+        .render(temp_file.path())
+        .unwrap();
+
+        let rendered = std::fs::read_to_string(temp_file.path()).unwrap();
+        assert!(rendered.contains(r#"instance_name = "test-instance""#));
+        assert!(rendered.contains(r#"uri = "qemu:///system""#));
+        assert!(rendered.contains(r#"pool = "images""#));
+        assert!(rendered.contains(r#"network_name = "torrust""#));
+        assert!(rendered.contains(r#"image = "/var/lib/libvirt/images/noble.img""#));
+    }
+}
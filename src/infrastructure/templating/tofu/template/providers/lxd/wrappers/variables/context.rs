@@ -9,6 +9,11 @@
 //!
 //! The `VariablesContext` holds:
 //! - `instance_name` - The dynamic name for the VM/container instance
+//! - `profile_name` - The name of the LXD profile
+//! - `image` - The image the instance is created from
+//! - `cpus`, `memory_mb`, `disk_gb` - The resources of the instance
+//! - `remote`, `remote_address`, `config_dir` - The LXD server the instance
+//!   is created on, empty for the local daemon
 //!
 //! ## Example Usage
 //!
@@ -30,6 +35,8 @@
 use serde::Serialize;
 use thiserror::Error;
 
+use crate::domain::provider::{LxdRemote, DEFAULT_LXD_IMAGE};
+use crate::domain::provision::InstanceResources;
 use crate::domain::{InstanceName, ProfileName};
 use crate::infrastructure::templating::metadata::TemplateMetadata;
 
@@ -62,6 +69,20 @@ pub struct VariablesContext {
     pub instance_name: InstanceName,
     /// The name of the LXD profile to be created  
     pub profile_name: ProfileName,
+    /// The image the instance is created from
+    pub image: String,
+    /// Number of virtual CPUs of the instance
+    pub cpus: u32,
+    /// Memory limit of the instance, in MiB
+    pub memory_mb: u32,
+    /// Root disk size of the instance, in GiB
+    pub disk_gb: u32,
+    /// Name of the LXD remote, empty for the local daemon
+    pub remote: String,
+    /// HTTPS address of the LXD remote, empty when the LXD client knows it
+    pub remote_address: String,
+    /// Directory of the LXD client configuration, empty for the default one
+    pub config_dir: String,
 }
 
 /// Builder for creating `VariablesContext` instances
@@ -73,6 +94,9 @@ pub struct VariablesContextBuilder {
     metadata: Option<TemplateMetadata>,
     instance_name: Option<InstanceName>,
     profile_name: Option<ProfileName>,
+    image: Option<String>,
+    instance_resources: InstanceResources,
+    remote: Option<LxdRemote>,
 }
 
 impl VariablesContextBuilder {
@@ -115,6 +139,46 @@ impl VariablesContextBuilder {
         self
     }
 
+    /// Sets the image the instance is created from
+    ///
+    /// Defaults to [`DEFAULT_LXD_IMAGE`] when not set. An image alias
+    /// without a remote refers to an image imported into LXD beforehand.
+    ///
+    /// # Arguments
+    ///
+    /// * `image` - The image source as given to LXD
+    #[must_use]
+    pub fn with_image(mut self, image: impl Into<String>) -> Self {
+        self.image = Some(image.into());
+        self
+    }
+
+    /// Sets the CPU, memory and disk of the instance
+    ///
+    /// Defaults to [`InstanceResources::default`] when not set.
+    ///
+    /// # Arguments
+    ///
+    /// * `instance_resources` - The resources to assign to the instance
+    #[must_use]
+    pub fn with_instance_resources(mut self, instance_resources: InstanceResources) -> Self {
+        self.instance_resources = instance_resources;
+        self
+    }
+
+    /// Sets the LXD server the instance is created on
+    ///
+    /// The instance is created on the local daemon when not set.
+    ///
+    /// # Arguments
+    ///
+    /// * `remote` - The LXD remote
+    #[must_use]
+    pub fn with_remote(mut self, remote: LxdRemote) -> Self {
+        self.remote = Some(remote);
+        self
+    }
+
     /// Builds the `VariablesContext` with validation
     ///
     /// # Returns
@@ -143,6 +207,27 @@ impl VariablesContextBuilder {
             metadata,
             instance_name,
             profile_name,
+            image: self.image.unwrap_or_else(|| DEFAULT_LXD_IMAGE.to_string()),
+            cpus: self.instance_resources.cpus(),
+            memory_mb: self.instance_resources.memory_mb(),
+            disk_gb: self.instance_resources.disk_gb(),
+            remote: self
+                .remote
+                .as_ref()
+                .map(|remote| remote.name().to_string())
+                .unwrap_or_default(),
+            remote_address: self
+                .remote
+                .as_ref()
+                .and_then(LxdRemote::address)
+                .unwrap_or_default()
+                .to_string(),
+            config_dir: self
+                .remote
+                .as_ref()
+                .and_then(LxdRemote::config_dir)
+                .map(|dir| dir.display().to_string())
+                .unwrap_or_default(),
         })
     }
 }
@@ -212,6 +297,97 @@ mod tests {
         assert_eq!(context.profile_name.as_str(), "my-profile");
     }
 
+    #[test]
+    fn it_should_use_the_default_instance_resources_when_not_set() {
+        let context = VariablesContext::builder()
+            .with_metadata(create_test_metadata())
+            .with_instance_name(InstanceName::new("test-vm".to_string()).unwrap())
+            .with_profile_name(ProfileName::new("test-profile".to_string()).unwrap())
+            .build()
+            .unwrap();
+
+        assert_eq!(context.cpus, 2);
+        assert_eq!(context.memory_mb, 2048);
+        assert_eq!(context.disk_gb, 10);
+    }
+
+    #[test]
+    fn it_should_use_the_ubuntu_image_server_when_no_image_is_set() {
+        let context = VariablesContext::builder()
+            .with_metadata(create_test_metadata())
+            .with_instance_name(InstanceName::new("test-vm".to_string()).unwrap())
+            .with_profile_name(ProfileName::new("test-profile".to_string()).unwrap())
+            .build()
+            .unwrap();
+
+        assert_eq!(context.image, "ubuntu:24.04");
+    }
+
+    #[test]
+    fn it_should_use_the_given_image() {
+        let context = VariablesContext::builder()
+            .with_metadata(create_test_metadata())
+            .with_instance_name(InstanceName::new("test-vm".to_string()).unwrap())
+            .with_profile_name(ProfileName::new("test-profile".to_string()).unwrap())
+            .with_image("torrust-ubuntu-24.04")
+            .build()
+            .unwrap();
+
+        assert_eq!(context.image, "torrust-ubuntu-24.04");
+    }
+
+    #[test]
+    fn it_should_use_the_given_instance_resources() {
+        let context = VariablesContext::builder()
+            .with_metadata(create_test_metadata())
+            .with_instance_name(InstanceName::new("test-vm".to_string()).unwrap())
+            .with_profile_name(ProfileName::new("test-profile".to_string()).unwrap())
+            .with_instance_resources(InstanceResources::new(1, 4096, 20).unwrap())
+            .build()
+            .unwrap();
+
+        assert_eq!(context.cpus, 1);
+        assert_eq!(context.memory_mb, 4096);
+        assert_eq!(context.disk_gb, 20);
+    }
+
+    #[test]
+    fn it_should_target_the_local_daemon_when_no_remote_is_set() {
+        let context = VariablesContext::builder()
+            .with_metadata(create_test_metadata())
+            .with_instance_name(InstanceName::new("test-vm".to_string()).unwrap())
+            .with_profile_name(ProfileName::new("test-profile".to_string()).unwrap())
+            .build()
+            .unwrap();
+
+        assert_eq!(context.remote, "");
+        assert_eq!(context.remote_address, "");
+        assert_eq!(context.config_dir, "");
+    }
+
+    #[test]
+    fn it_should_target_the_given_remote() {
+        let remote = LxdRemote::new(
+            "bigbox",
+            Some("https://bigbox.lan:8443".to_string()),
+            Some("/home/deployer/.config/lxc".into()),
+            None,
+        )
+        .unwrap();
+
+        let context = VariablesContext::builder()
+            .with_metadata(create_test_metadata())
+            .with_instance_name(InstanceName::new("test-vm".to_string()).unwrap())
+            .with_profile_name(ProfileName::new("test-profile".to_string()).unwrap())
+            .with_remote(remote)
+            .build()
+            .unwrap();
+
+        assert_eq!(context.remote, "bigbox");
+        assert_eq!(context.remote_address, "https://bigbox.lan:8443");
+        assert_eq!(context.config_dir, "/home/deployer/.config/lxc");
+    }
+
     #[test]
     fn it_should_fail_when_instance_name_is_missing() {
         let metadata = create_test_metadata();
@@ -3,7 +3,9 @@
 use std::path::Path;
 
 use crate::domain::template::file::File;
-use crate::domain::template::{write_file_with_dir_creation, TemplateEngine};
+use crate::domain::template::{
+    write_file_with_dir_creation, ExtraTemplateVariables, TemplateEngine,
+};
 use crate::infrastructure::templating::tofu::template::common::wrappers::VariablesTemplateError;
 
 use super::context::VariablesContext;
@@ -37,8 +39,9 @@ impl VariablesTemplate {
     pub fn new(
         template_file: &File,
         context: VariablesContext,
+        extra_variables: &ExtraTemplateVariables,
     ) -> Result<Self, VariablesTemplateError> {
-        let mut engine = TemplateEngine::new();
+        let mut engine = TemplateEngine::new().with_extra_variables(extra_variables);
 
         let validated_content =
             engine.render(template_file.filename(), template_file.content(), &context)?;
@@ -106,7 +109,8 @@ image = "ubuntu:24.04""#;
             File::new("variables.tfvars.tera", template_content.to_string()).unwrap();
         let context = create_test_context();
 
-        let result = VariablesTemplate::new(&template_file, context);
+        let result =
+            VariablesTemplate::new(&template_file, context, &ExtraTemplateVariables::default());
         assert!(result.is_ok());
     }
 
@@ -119,7 +123,8 @@ image = "ubuntu:24.04""#; // Missing closing }}
             File::new("variables.tfvars.tera", template_content.to_string()).unwrap();
         let context = create_test_context();
 
-        let result = VariablesTemplate::new(&template_file, context);
+        let result =
+            VariablesTemplate::new(&template_file, context, &ExtraTemplateVariables::default());
         assert!(matches!(
             result.unwrap_err(),
             VariablesTemplateError::TemplateEngineError { .. }
@@ -135,7 +140,8 @@ image = "ubuntu:24.04""#;
             File::new("variables.tfvars.tera", template_content.to_string()).unwrap();
         let context = create_test_context();
 
-        let result = VariablesTemplate::new(&template_file, context);
+        let result =
+            VariablesTemplate::new(&template_file, context, &ExtraTemplateVariables::default());
         assert!(result.is_ok());
     }
 
@@ -147,7 +153,8 @@ image = "ubuntu:24.04""#;
             File::new("variables.tfvars.tera", template_content.to_string()).unwrap();
         let context = create_test_context();
 
-        let result = VariablesTemplate::new(&template_file, context);
+        let result =
+            VariablesTemplate::new(&template_file, context, &ExtraTemplateVariables::default());
         assert!(result.is_ok());
     }
 
@@ -160,7 +167,9 @@ image = "ubuntu:24.04""#;
         let template_file =
             File::new("variables.tfvars.tera", template_content.to_string()).unwrap();
         let context = create_test_context();
-        let variables_template = VariablesTemplate::new(&template_file, context).unwrap();
+        let variables_template =
+            VariablesTemplate::new(&template_file, context, &ExtraTemplateVariables::default())
+                .unwrap();
 
         let temp_file = NamedTempFile::new().unwrap();
         let result = variables_template.render(temp_file.path());
@@ -177,7 +186,9 @@ image = "ubuntu:24.04""#;
     fn it_should_provide_access_to_context() {
         let template_file = File::new("variables.tfvars.tera", String::new()).unwrap();
         let context = create_test_context();
-        let variables_template = VariablesTemplate::new(&template_file, context).unwrap();
+        let variables_template =
+            VariablesTemplate::new(&template_file, context, &ExtraTemplateVariables::default())
+                .unwrap();
 
         assert_eq!(
             variables_template.context().instance_name.as_str(),
@@ -191,7 +202,9 @@ image = "ubuntu:24.04""#;
         let template_file =
             File::new("variables.tfvars.tera", template_content.to_string()).unwrap();
         let context = create_test_context();
-        let variables_template = VariablesTemplate::new(&template_file, context).unwrap();
+        let variables_template =
+            VariablesTemplate::new(&template_file, context, &ExtraTemplateVariables::default())
+                .unwrap();
 
         assert!(variables_template.content().contains("test-instance"));
     }
@@ -205,7 +218,9 @@ image = "ubuntu:24.04""#;
         let template_file =
             File::new("variables.tfvars.tera", template_content.to_string()).unwrap();
         let context = create_test_context();
-        let variables_template = VariablesTemplate::new(&template_file, context).unwrap();
+        let variables_template =
+            VariablesTemplate::new(&template_file, context, &ExtraTemplateVariables::default())
+                .unwrap();
 
         let temp_file = NamedTempFile::new().unwrap();
         let result = variables_template.render(temp_file.path());
@@ -226,13 +241,45 @@ image = "ubuntu:24.04""#;
         let context = create_test_context();
 
         // Should fail at construction, not during render
-        let result = VariablesTemplate::new(&template_file, context);
+        let result =
+            VariablesTemplate::new(&template_file, context, &ExtraTemplateVariables::default());
         assert!(matches!(
             result.unwrap_err(),
             VariablesTemplateError::TemplateEngineError { .. }
         ));
     }
 
+    #[test]
+    fn it_should_render_the_instance_resources_with_the_lxd_template() {
+        let template_path = std::path::Path::new(env!("CARGO_MANIFEST_DIR"))
+            .join("templates/tofu/lxd/variables.tfvars.tera");
+        let template_file = File::new(
+            "variables.tfvars.tera",
+            std::fs::read_to_string(template_path).unwrap(),
+        )
+        .unwrap();
+        let context = VariablesContext::builder()
+            .with_metadata(TemplateMetadata::new(Utc::now()))
+            .with_instance_name(InstanceName::new("test-instance".to_string()).unwrap())
+            .with_profile_name(crate::domain::ProfileName::new("test-profile".to_string()).unwrap())
+            .with_instance_resources(
+                crate::domain::provision::InstanceResources::new(1, 4096, 20).unwrap(),
+            )
+            .build()
+            .unwrap();
+        let temp_file = NamedTempFile::new().unwrap();
+
+        VariablesTemplate::new(&template_file, context, &ExtraTemplateVariables::default())
+            .unwrap()
+            .render(temp_file.path())
+            .unwrap();
+
+        let rendered = std::fs::read_to_string(temp_file.path()).unwrap();
+        assert!(rendered.contains("cpus      = 1"));
+        assert!(rendered.contains("memory_mb = 4096"));
+        assert!(rendered.contains("disk_gb   = 20"));
+    }
+
     #[test]
     fn it_should_generate_variables_template_context() {
         let template_file =
@@ -247,7 +294,9 @@ image = "ubuntu:24.04""#;
             .build()
             .unwrap();
 
-        let variables_template = VariablesTemplate::new(&template_file, context).unwrap();
+        let variables_template =
+            VariablesTemplate::new(&template_file, context, &ExtraTemplateVariables::default())
+                .unwrap();
         assert_eq!(
             variables_template.context().instance_name.as_str(),
             "dynamic-vm"
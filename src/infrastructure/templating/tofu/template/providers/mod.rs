@@ -1,7 +1,7 @@
 //! Provider-specific `OpenTofu` template functionality.
 //!
 //! This module contains template implementations that are specific to
-//! individual infrastructure providers (LXD, Hetzner, etc.).
+//! individual infrastructure providers (LXD, Hetzner, `DigitalOcean`, Docker, libvirt, etc.).
 //!
 //! Each provider has its own independent template wrappers for:
 //! - `cloud_init` - Cloud-init configuration templates
@@ -9,5 +9,8 @@
 //!
 //! Templates are not shared between providers to allow provider-specific customization.
 
+pub mod digitalocean;
+pub mod docker;
 pub mod hetzner;
+pub mod libvirt;
 pub mod lxd;
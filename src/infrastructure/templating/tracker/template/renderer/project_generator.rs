@@ -282,8 +282,8 @@ mod tests {
                         3306,
                         "tracker_db",
                         "tracker_user",
-                        Password::from("secure_pass"),
-                        Password::from("root_pass"),
+                        Password::from("secure_password"),
+                        Password::from("root_secure_password"),
                     )
                     .unwrap(),
                 ),
@@ -319,7 +319,41 @@ mod tests {
         // DSN is no longer in tracker.toml — it is injected via
         // TORRUST_TRACKER_CONFIG_OVERRIDE_CORE__DATABASE__PATH in .env
         assert!(!content.contains("path = \"mysql://"));
-        assert!(!content.contains("secure_pass"));
+        assert!(!content.contains("secure_password"));
+    }
+
+    #[test]
+    fn it_should_render_tracker_toml_with_overrides() {
+        use crate::domain::environment::TrackerConfig;
+        use crate::domain::tracker::TrackerOverrides;
+
+        let temp_dir = tempfile::tempdir().expect("Failed to create temp dir");
+        let build_dir = temp_dir.path().join("build");
+
+        // Render the embedded template, which defines where the overrides go
+        let templates_dir = temp_dir.path().join("empty_templates");
+        fs::create_dir_all(&templates_dir).expect("Failed to create templates dir");
+        let template_manager = Arc::new(TemplateManager::new(templates_dir));
+        let generator =
+            TrackerProjectGenerator::new(&build_dir, template_manager, Arc::new(SystemClock));
+
+        let overrides = TrackerOverrides::default()
+            .with_announce_interval(120)
+            .and_then(|overrides| overrides.with_max_peer_timeout(900))
+            .expect("valid overrides")
+            .with_tracker_usage_statistics(false);
+        let tracker_config = TrackerConfig::default().with_overrides(overrides);
+
+        generator
+            .render(Some(&tracker_config))
+            .expect("Failed to render templates");
+
+        let content = fs::read_to_string(build_dir.join("tracker/tracker.toml"))
+            .expect("Failed to read tracker.toml");
+
+        assert!(content.contains("interval = 120\ninterval_min = 120\n"));
+        assert!(content.contains("max_peer_timeout = 900"));
+        assert!(content.contains("tracker_usage_statistics = false"));
     }
 
     #[test]
@@ -113,7 +113,8 @@ impl TrackerConfigRenderer {
         })?;
 
         // 3. Create TrackerTemplate with context
-        let template = TrackerTemplate::new(template_content, context.clone())?;
+        let template = TrackerTemplate::new(template_content, context.clone())?
+            .with_extra_variables(self.template_manager.extra_variables());
 
         // 4. Render to output file
         let output_path = output_dir.join(Self::TRACKER_OUTPUT_FILE);
@@ -12,6 +12,7 @@
 use serde::Serialize;
 
 use crate::domain::environment::TrackerConfig;
+use crate::domain::tracker::config::DEFAULT_ANNOUNCE_INTERVAL;
 use crate::infrastructure::templating::TemplateMetadata;
 
 /// Context for rendering tracker.toml.tera template
@@ -100,6 +101,20 @@ pub struct TrackerContext {
 
     /// Health check API bind address
     pub health_check_api_bind_address: String,
+
+    /// Announce interval in seconds (`core.announce_policy.interval`)
+    pub announce_interval: u32,
+
+    /// Minimum announce interval in seconds (`core.announce_policy.interval_min`)
+    pub min_announce_interval: u32,
+
+    /// Peer timeout in seconds, rendered only when overridden
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub max_peer_timeout: Option<u32>,
+
+    /// Usage statistics switch, rendered only when overridden
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub tracker_usage_statistics: Option<bool>,
 }
 
 /// Database driver type for tracker configuration
@@ -182,6 +197,10 @@ impl TrackerContext {
                 .collect(),
             http_api_bind_address: config.http_api().bind_address().to_string(),
             health_check_api_bind_address: config.health_check_api().bind_address().to_string(),
+            announce_interval: config.overrides().announce_interval(),
+            min_announce_interval: config.overrides().min_announce_interval(),
+            max_peer_timeout: config.overrides().max_peer_timeout(),
+            tracker_usage_statistics: config.overrides().tracker_usage_statistics(),
         }
     }
 
@@ -220,6 +239,10 @@ impl TrackerContext {
             }],
             http_api_bind_address: "0.0.0.0:1212".parse().unwrap(),
             health_check_api_bind_address: "127.0.0.1:1313".parse().unwrap(),
+            announce_interval: DEFAULT_ANNOUNCE_INTERVAL,
+            min_announce_interval: DEFAULT_ANNOUNCE_INTERVAL,
+            max_peer_timeout: None,
+            tracker_usage_statistics: None,
         }
     }
 }
@@ -299,8 +322,8 @@ mod tests {
                         3306,
                         "tracker_db",
                         "tracker_user",
-                        Password::from("secure_pass"),
-                        Password::from("root_pass"),
+                        Password::from("secure_password"),
+                        Password::from("root_secure_password"),
                     )
                     .unwrap(),
                 ),
@@ -7,6 +7,8 @@ use std::path::Path;
 use tera::Tera;
 use thiserror::Error;
 
+use crate::domain::template::ExtraTemplateVariables;
+
 use super::context::TrackerContext;
 
 /// Errors that can occur during tracker template operations
@@ -48,6 +50,8 @@ pub struct TrackerTemplate {
     content: String,
     /// The rendering context (empty in Phase 4)
     context: TrackerContext,
+    /// User-defined variables added to the rendering context
+    extra_variables: ExtraTemplateVariables,
 }
 
 impl TrackerTemplate {
@@ -73,9 +77,17 @@ impl TrackerTemplate {
         Ok(Self {
             content: template_content,
             context,
+            extra_variables: ExtraTemplateVariables::default(),
         })
     }
 
+    /// Sets the user-defined variables added to the rendering context
+    #[must_use]
+    pub fn with_extra_variables(mut self, extra_variables: &ExtraTemplateVariables) -> Self {
+        self.extra_variables = extra_variables.clone();
+        self
+    }
+
     /// Renders the template with the context
     ///
     /// # Returns
@@ -95,8 +107,10 @@ impl TrackerTemplate {
         tera.add_raw_template("tracker.toml", &self.content)
             .map_err(|e| TrackerTemplateError::RenderingFailed(e.to_string()))?;
 
-        let context = tera::Context::from_serialize(&self.context)
+        let mut context = tera::Context::from_serialize(&self.context)
             .map_err(|e| TrackerTemplateError::RenderingFailed(e.to_string()))?;
+        self.extra_variables
+            .merge_into(&mut context, "tracker.toml");
 
         tera.render("tracker.toml", &context)
             .map_err(|e| TrackerTemplateError::RenderingFailed(e.to_string()))
@@ -0,0 +1,116 @@
+//! Index of the trace files of a traces directory
+//!
+//! Every trace written by the trace writers is recorded in `index.json` next
+//! to the trace files, so that its trace id can be mapped back to the command
+//! and step that failed without parsing the trace files themselves. Entries
+//! are removed together with their trace file by the retention policy.
+
+use std::fs;
+use std::io;
+use std::path::Path;
+
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+use crate::domain::environment::TraceId;
+
+/// Name of the index file in the traces directory
+pub const TRACE_INDEX_FILE_NAME: &str = "index.json";
+
+/// One trace of the index
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct TraceIndexEntry {
+    /// Trace id of the failure, as recorded in the environment state
+    pub trace_id: TraceId,
+
+    /// Command that failed (e.g. `provision`)
+    pub command: String,
+
+    /// Step of the command that failed (e.g. `wait_cloud_init`)
+    pub step: String,
+
+    /// When the failure happened
+    pub timestamp: DateTime<Utc>,
+
+    /// Name of the trace file in the traces directory
+    pub file: String,
+}
+
+/// Read the index of a traces directory
+///
+/// # Returns
+///
+/// The entries, oldest first. A directory without index has no entries.
+///
+/// # Errors
+///
+/// Returns an error if the index exists but cannot be read or parsed.
+pub fn read_trace_index(traces_dir: &Path) -> io::Result<Vec<TraceIndexEntry>> {
+    let content = match fs::read_to_string(traces_dir.join(TRACE_INDEX_FILE_NAME)) {
+        Ok(content) => content,
+        Err(e) if e.kind() == io::ErrorKind::NotFound => return Ok(Vec::new()),
+        Err(e) => return Err(e),
+    };
+
+    serde_json::from_str(&content).map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))
+}
+
+/// Find the entry of a trace in the index of a traces directory
+///
+/// # Errors
+///
+/// Returns an error if the index exists but cannot be read or parsed.
+pub fn find_trace(traces_dir: &Path, trace_id: &str) -> io::Result<Option<TraceIndexEntry>> {
+    Ok(read_trace_index(traces_dir)?
+        .into_iter()
+        .find(|entry| entry.trace_id.to_string() == trace_id))
+}
+
+/// Replace the index of a traces directory
+///
+/// # Errors
+///
+/// Returns an error if the index cannot be serialized or written.
+pub(crate) fn write_trace_index(traces_dir: &Path, entries: &[TraceIndexEntry]) -> io::Result<()> {
+    let content = serde_json::to_string_pretty(entries)
+        .map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))?;
+
+    fs::write(traces_dir.join(TRACE_INDEX_FILE_NAME), content)
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::TimeZone;
+    use tempfile::TempDir;
+
+    use super::*;
+
+    fn entry(file: &str) -> TraceIndexEntry {
+        TraceIndexEntry {
+            trace_id: TraceId::new(),
+            command: "provision".to_string(),
+            step: "wait_cloud_init".to_string(),
+            timestamp: Utc.with_ymd_and_hms(2025, 10, 8, 14, 30, 45).unwrap(),
+            file: file.to_string(),
+        }
+    }
+
+    #[test]
+    fn it_should_have_no_entries_without_index_file() {
+        let temp_dir = TempDir::new().unwrap();
+
+        assert!(read_trace_index(temp_dir.path()).unwrap().is_empty());
+    }
+
+    #[test]
+    fn it_should_find_a_written_trace_by_its_id() {
+        let temp_dir = TempDir::new().unwrap();
+        let first = entry("20251008-143045-provision.log");
+        let second = entry("20251008-150000-configure.log");
+        write_trace_index(temp_dir.path(), &[first, second.clone()]).unwrap();
+
+        let found = find_trace(temp_dir.path(), &second.trace_id.to_string()).unwrap();
+
+        assert_eq!(found, Some(second));
+    }
+}
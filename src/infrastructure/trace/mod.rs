@@ -10,9 +10,23 @@
 //!   - `error` - Error types for trace writing operations
 //!   - `common` - Shared file I/O operations
 //!   - `commands` - Command-specific trace writers (provision, configure, release, run)
+//! - `reader` - Reads the structured JSON failure reports written next to trace files
+//! - `index` - Index mapping the trace ids to the failed command and step
+//! - `retention` - Removes the traces beyond the retention policy
 
+pub mod index;
+pub mod reader;
+pub mod retention;
 pub mod writer;
 
+pub use index::{find_trace, read_trace_index, TraceIndexEntry, TRACE_INDEX_FILE_NAME};
+pub use reader::{read_failure_report, TraceReaderError};
+pub use retention::prune_traces;
+
+/// Timestamp format of the trace file names: YYYYmmdd-HHMMSS
+/// Example: 20251008-143045
+const TRACE_FILENAME_TIMESTAMP_FORMAT: &str = "%Y%m%d-%H%M%S";
+
 pub use writer::{
     ConfigureTraceWriter, ProvisionTraceWriter, ReleaseTraceWriter, RunTraceWriter,
     TraceWriterError,
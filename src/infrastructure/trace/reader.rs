@@ -0,0 +1,119 @@
+//! Structured trace reader
+//!
+//! Reads the JSON failure reports written by the trace writers next to each
+//! human-readable `.log` trace file.
+
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+
+use thiserror::Error;
+
+use crate::domain::environment::FailureReport;
+
+/// Errors that can occur while reading a structured failure report
+#[derive(Debug, Error)]
+pub enum TraceReaderError {
+    /// Failed to read the report file
+    #[error("Failed to read failure report at {path}: {source}")]
+    FileRead {
+        /// Path of the report file
+        path: String,
+
+        /// Underlying I/O error
+        #[source]
+        source: io::Error,
+    },
+
+    /// The report file does not contain a valid failure report
+    #[error("Failed to parse failure report at {path}: {source}")]
+    Parse {
+        /// Path of the report file
+        path: String,
+
+        /// Underlying deserialization error
+        #[source]
+        source: serde_json::Error,
+    },
+}
+
+/// Get the path of the structured report belonging to a trace file
+///
+/// The report is a sibling of the trace file with the `.json` extension.
+///
+/// # Example
+///
+/// ```rust
+/// use std::path::{Path, PathBuf};
+/// use torrust_tracker_deployer_lib::infrastructure::trace::reader::report_path;
+///
+/// let trace = Path::new("data/my-env/traces/20251008-143045-provision.log");
+/// assert_eq!(
+///     report_path(trace),
+///     PathBuf::from("data/my-env/traces/20251008-143045-provision.json")
+/// );
+/// ```
+#[must_use]
+pub fn report_path(trace_file: &Path) -> PathBuf {
+    trace_file.with_extension("json")
+}
+
+/// Read the structured failure report belonging to a trace file
+///
+/// # Returns
+///
+/// - `Ok(Some(report))` if the report exists and could be parsed
+/// - `Ok(None)` if no report was written for this trace (e.g. traces created
+///   before structured reports were introduced)
+///
+/// # Errors
+///
+/// Returns an error if the report exists but cannot be read or parsed
+pub fn read_failure_report(trace_file: &Path) -> Result<Option<FailureReport>, TraceReaderError> {
+    let report_file = report_path(trace_file);
+
+    let content = match fs::read_to_string(&report_file) {
+        Ok(content) => content,
+        Err(e) if e.kind() == io::ErrorKind::NotFound => return Ok(None),
+        Err(source) => {
+            return Err(TraceReaderError::FileRead {
+                path: report_file.display().to_string(),
+                source,
+            })
+        }
+    };
+
+    serde_json::from_str(&content)
+        .map(Some)
+        .map_err(|source| TraceReaderError::Parse {
+            path: report_file.display().to_string(),
+            source,
+        })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::TempDir;
+
+    #[test]
+    fn it_should_return_none_when_no_report_exists_for_trace() {
+        let temp_dir = TempDir::new().unwrap();
+        let trace_file = temp_dir.path().join("20251008-143045-provision.log");
+
+        let report = read_failure_report(&trace_file).unwrap();
+
+        assert!(report.is_none());
+    }
+
+    #[test]
+    fn it_should_fail_when_report_is_not_valid_json() {
+        let temp_dir = TempDir::new().unwrap();
+        let trace_file = temp_dir.path().join("20251008-143045-provision.log");
+        fs::write(report_path(&trace_file), "not json").unwrap();
+
+        let result = read_failure_report(&trace_file);
+
+        assert!(matches!(result, Err(TraceReaderError::Parse { .. })));
+    }
+}
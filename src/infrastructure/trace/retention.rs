@@ -0,0 +1,235 @@
+//! Retention policy of the trace files
+//!
+//! A trace is the `{timestamp}-{command}.log` file written on a failure
+//! together with its `.json` report. Only the most recent traces are kept,
+//! optionally only while they are younger than a maximum age; the others
+//! are removed along with their entries in the trace index.
+
+use std::collections::BTreeSet;
+use std::fs;
+use std::io;
+use std::path::Path;
+
+use chrono::{DateTime, Duration, NaiveDateTime, Utc};
+
+use super::index::{read_trace_index, write_trace_index, TRACE_INDEX_FILE_NAME};
+use super::TRACE_FILENAME_TIMESTAMP_FORMAT;
+
+/// Length of the timestamp prefix of the trace file names (`YYYYmmdd-HHMMSS`)
+const TIMESTAMP_LENGTH: usize = 15;
+
+/// Extensions of the files making up a trace
+const TRACE_EXTENSIONS: [&str; 2] = ["log", "json"];
+
+/// Remove the traces beyond the retention policy
+///
+/// Traces are sorted by the timestamp of their file name: the `keep_last`
+/// most recent ones are kept, unless they are older than `max_age`. Files
+/// that do not belong to a trace, and a missing directory, are left alone.
+///
+/// # Arguments
+///
+/// * `traces_dir` - Directory holding the trace files
+/// * `keep_last` - Number of most recent traces kept
+/// * `max_age` - Maximum age of the kept traces, `None` for no limit
+/// * `now` - Current time, for the age of the traces
+///
+/// # Returns
+///
+/// The number of removed traces.
+///
+/// # Errors
+///
+/// Returns an error if the directory cannot be read, a file cannot be
+/// removed, or the index cannot be rewritten.
+pub fn prune_traces(
+    traces_dir: &Path,
+    keep_last: usize,
+    max_age: Option<Duration>,
+    now: DateTime<Utc>,
+) -> io::Result<usize> {
+    let stems = match list_trace_stems(traces_dir) {
+        Ok(stems) => stems,
+        Err(e) if e.kind() == io::ErrorKind::NotFound => return Ok(0),
+        Err(e) => return Err(e),
+    };
+
+    let removed: Vec<&String> = stems
+        .iter()
+        .rev()
+        .enumerate()
+        .filter(|(position, stem)| {
+            *position >= keep_last
+                || max_age.is_some_and(|max_age| {
+                    trace_timestamp(stem).is_some_and(|timestamp| now - timestamp > max_age)
+                })
+        })
+        .map(|(_, stem)| stem)
+        .collect();
+
+    if removed.is_empty() {
+        return Ok(0);
+    }
+
+    for stem in &removed {
+        for extension in TRACE_EXTENSIONS {
+            match fs::remove_file(traces_dir.join(format!("{stem}.{extension}"))) {
+                Ok(()) => {}
+                Err(e) if e.kind() == io::ErrorKind::NotFound => {}
+                Err(e) => return Err(e),
+            }
+        }
+    }
+
+    if traces_dir.join(TRACE_INDEX_FILE_NAME).exists() {
+        let entries: Vec<_> = read_trace_index(traces_dir)?
+            .into_iter()
+            .filter(|entry| {
+                Path::new(&entry.file)
+                    .file_stem()
+                    .and_then(|stem| stem.to_str())
+                    .is_none_or(|stem| !removed.iter().any(|removed| *removed == stem))
+            })
+            .collect();
+        write_trace_index(traces_dir, &entries)?;
+    }
+
+    Ok(removed.len())
+}
+
+/// File names without extension of the traces in the directory, oldest first
+fn list_trace_stems(traces_dir: &Path) -> io::Result<BTreeSet<String>> {
+    let mut stems = BTreeSet::new();
+
+    for entry in fs::read_dir(traces_dir)? {
+        let path = entry?.path();
+        let is_trace_file = path
+            .extension()
+            .and_then(|extension| extension.to_str())
+            .is_some_and(|extension| TRACE_EXTENSIONS.contains(&extension));
+        let Some(stem) = path.file_stem().and_then(|stem| stem.to_str()) else {
+            continue;
+        };
+
+        if is_trace_file && trace_timestamp(stem).is_some() {
+            stems.insert(stem.to_string());
+        }
+    }
+
+    Ok(stems)
+}
+
+/// Timestamp of a trace from its file name, `None` if it is not a trace name
+fn trace_timestamp(stem: &str) -> Option<DateTime<Utc>> {
+    let (timestamp, command) = stem.split_at_checked(TIMESTAMP_LENGTH)?;
+    if !command.starts_with('-') {
+        return None;
+    }
+
+    NaiveDateTime::parse_from_str(timestamp, TRACE_FILENAME_TIMESTAMP_FORMAT)
+        .ok()
+        .map(|timestamp| timestamp.and_utc())
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::TimeZone;
+    use tempfile::TempDir;
+
+    use super::*;
+    use crate::domain::environment::TraceId;
+    use crate::infrastructure::trace::index::TraceIndexEntry;
+
+    fn now() -> DateTime<Utc> {
+        Utc.with_ymd_and_hms(2025, 10, 10, 12, 0, 0).unwrap()
+    }
+
+    fn write_trace(traces_dir: &Path, stem: &str) {
+        fs::write(traces_dir.join(format!("{stem}.log")), "trace").unwrap();
+        fs::write(traces_dir.join(format!("{stem}.json")), "{}").unwrap();
+    }
+
+    #[test]
+    fn it_should_keep_only_the_most_recent_traces() {
+        let temp_dir = TempDir::new().unwrap();
+        for stem in [
+            "20251001-100000-provision",
+            "20251002-100000-configure",
+            "20251003-100000-release",
+        ] {
+            write_trace(temp_dir.path(), stem);
+        }
+
+        let removed = prune_traces(temp_dir.path(), 2, None, now()).unwrap();
+
+        assert_eq!(removed, 1);
+        assert!(!temp_dir
+            .path()
+            .join("20251001-100000-provision.log")
+            .exists());
+        assert!(!temp_dir
+            .path()
+            .join("20251001-100000-provision.json")
+            .exists());
+        assert!(temp_dir
+            .path()
+            .join("20251002-100000-configure.log")
+            .exists());
+        assert!(temp_dir.path().join("20251003-100000-release.log").exists());
+    }
+
+    #[test]
+    fn it_should_remove_the_traces_older_than_the_max_age() {
+        let temp_dir = TempDir::new().unwrap();
+        write_trace(temp_dir.path(), "20251001-100000-provision");
+        write_trace(temp_dir.path(), "20251009-100000-configure");
+
+        let removed = prune_traces(temp_dir.path(), 20, Some(Duration::days(7)), now()).unwrap();
+
+        assert_eq!(removed, 1);
+        assert!(temp_dir
+            .path()
+            .join("20251009-100000-configure.log")
+            .exists());
+    }
+
+    #[test]
+    fn it_should_remove_the_index_entries_of_removed_traces() {
+        let temp_dir = TempDir::new().unwrap();
+        let entries: Vec<TraceIndexEntry> =
+            ["20251001-100000-provision", "20251002-100000-configure"]
+                .into_iter()
+                .map(|stem| {
+                    write_trace(temp_dir.path(), stem);
+                    TraceIndexEntry {
+                        trace_id: TraceId::new(),
+                        command: "provision".to_string(),
+                        step: "render_templates".to_string(),
+                        timestamp: now(),
+                        file: format!("{stem}.log"),
+                    }
+                })
+                .collect();
+        write_trace_index(temp_dir.path(), &entries).unwrap();
+
+        prune_traces(temp_dir.path(), 1, None, now()).unwrap();
+
+        assert_eq!(
+            read_trace_index(temp_dir.path()).unwrap(),
+            vec![entries[1].clone()]
+        );
+    }
+
+    #[test]
+    fn it_should_leave_other_files_and_a_missing_directory_alone() {
+        let temp_dir = TempDir::new().unwrap();
+        fs::write(temp_dir.path().join("notes.log"), "notes").unwrap();
+
+        assert_eq!(prune_traces(temp_dir.path(), 1, None, now()).unwrap(), 0);
+        assert!(temp_dir.path().join("notes.log").exists());
+        assert_eq!(
+            prune_traces(&temp_dir.path().join("missing"), 1, None, now()).unwrap(),
+            0
+        );
+    }
+}
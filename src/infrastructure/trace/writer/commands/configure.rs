@@ -7,6 +7,7 @@ use std::path::{Path, PathBuf};
 use std::sync::Arc;
 
 use crate::domain::environment::state::ConfigureFailureContext;
+use crate::domain::environment::FailureReport;
 use crate::shared::{Clock, Traceable};
 
 use super::super::common::CommonTraceWriter;
@@ -45,7 +46,8 @@ impl ConfigureTraceWriter {
 
     /// Write a configure failure trace file
     ///
-    /// Generates a trace file with configure-specific context and logs the outcome.
+    /// Generates a trace file with configure-specific context, writes the structured
+    /// JSON failure report next to it, and logs the outcome.
     /// Success is logged at INFO level, failures at WARN level.
     ///
     /// # Arguments
@@ -68,8 +70,17 @@ impl ConfigureTraceWriter {
         use tracing::{info, warn};
 
         let trace_content = Self::format_trace(ctx, error);
+        let report = Self::build_report(ctx, error);
 
-        match self.common.write_trace("configure", &trace_content) {
+        let result = self
+            .common
+            .write_trace("configure", &trace_content)
+            .and_then(|trace_file_path| {
+                self.common.write_report(&trace_file_path, report)?;
+                Ok(trace_file_path)
+            });
+
+        match result {
             Ok(trace_file_path) => {
                 info!(
                     command = "configure",
@@ -105,7 +116,16 @@ impl ConfigureTraceWriter {
 
         // Command-specific metadata
         let _ = writeln!(trace, "Failed Step: {:?}", ctx.failed_step);
-        let _ = writeln!(trace, "Error Kind: {:?}\n", ctx.error_kind);
+        let _ = writeln!(trace, "Error Kind: {:?}", ctx.error_kind);
+        if let Some(attempts) = ctx.attempts {
+            let _ = writeln!(trace, "Attempts: {attempts}");
+        }
+        trace.push('\n');
+
+        // Ansible task that failed (if the step failed in a playbook task)
+        if let Some(failure) = &ctx.failed_task {
+            trace.push_str(&TraceSections::failed_task(failure));
+        }
 
         // Error chain
         trace.push_str(TraceSections::error_chain_header());
@@ -117,6 +137,13 @@ impl ConfigureTraceWriter {
         trace
     }
 
+    /// Build the structured failure report written next to the trace file
+    fn build_report<E: Traceable>(ctx: &ConfigureFailureContext, error: &E) -> FailureReport {
+        FailureReport::new(ctx.failed_step.clone(), ctx.error_kind, ctx.base.clone())
+            .with_error_chain(TraceSections::collect_error_chain(error))
+            .with_failed_task(ctx.failed_task.clone())
+    }
+
     /// Get the traces directory path
     #[must_use]
     pub fn traces_dir(&self) -> &Path {
@@ -131,6 +158,7 @@ mod tests {
     use std::time::Duration;
     use tempfile::TempDir;
 
+    use crate::adapters::ansible::TaskFailure;
     use crate::domain::environment::state::{
         BaseFailureContext, ConfigureFailureContext, ConfigureStep,
     };
@@ -213,6 +241,8 @@ mod tests {
                 trace_id: TraceId::new(),
                 trace_file_path: None,
             },
+            failed_task: None,
+            attempts: None,
         }
     }
 
@@ -235,6 +265,8 @@ mod tests {
                 trace_id,
                 trace_file_path: None,
             },
+            failed_task: None,
+            attempts: None,
         }
     }
 
@@ -332,6 +364,52 @@ mod tests {
         assert!(trace_content.contains("Error Summary: Test configure error"));
     }
 
+    #[test]
+    fn it_should_include_the_failed_task_in_the_trace_and_report() {
+        // Arrange
+        let (writer, _temp_dir, _traces_dir) = create_test_writer();
+        let error = create_test_error("install-docker failed");
+        let mut context = create_test_context(&error.to_string());
+        context.failed_task = Some(TaskFailure {
+            playbook: "install-docker".to_string(),
+            task: "Install docker-ce".to_string(),
+            module: "ansible.builtin.apt".to_string(),
+            host: "torrust-tracker-vm".to_string(),
+            message: "No package matching 'docker-ce' is available".to_string(),
+            stderr: None,
+        });
+
+        // Act
+        let trace_file = writer.write_trace(&context, &error).unwrap();
+
+        // Assert
+        let content = std::fs::read_to_string(&trace_file).unwrap();
+        assert!(content.contains("FAILED TASK"));
+        assert!(content.contains("Task: Install docker-ce"));
+        assert!(content.contains("Module: ansible.builtin.apt"));
+
+        let report = crate::infrastructure::trace::read_failure_report(&trace_file)
+            .unwrap()
+            .unwrap();
+        assert_eq!(report.failed_task, context.failed_task);
+    }
+
+    #[test]
+    fn it_should_include_the_attempts_of_a_retried_playbook_in_the_trace() {
+        // Arrange
+        let (writer, _temp_dir, _traces_dir) = create_test_writer();
+        let error = create_test_error("install-docker failed");
+        let mut context = create_test_context(&error.to_string());
+        context.attempts = Some(4);
+
+        // Act
+        let trace_file = writer.write_trace(&context, &error).unwrap();
+
+        // Assert
+        let content = std::fs::read_to_string(&trace_file).unwrap();
+        assert!(content.contains("Error Kind: CommandExecution\nAttempts: 4\n"));
+    }
+
     #[test]
     fn it_should_generate_trace_files_with_correct_naming() {
         // This test verifies that trace files are created with correct naming convention
@@ -360,6 +438,7 @@ mod tests {
             .filter_map(Result::ok)
             .map(|entry| entry.path())
             .filter(|path| path.is_file())
+            .filter(|path| path.extension().is_some_and(|ext| ext == "log"))
             .collect();
 
         assert_eq!(
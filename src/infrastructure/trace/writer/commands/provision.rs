@@ -7,6 +7,7 @@ use std::path::{Path, PathBuf};
 use std::sync::Arc;
 
 use crate::domain::environment::state::ProvisionFailureContext;
+use crate::domain::environment::FailureReport;
 use crate::shared::{Clock, Traceable};
 
 use super::super::common::CommonTraceWriter;
@@ -45,7 +46,8 @@ impl ProvisionTraceWriter {
 
     /// Write a provision failure trace file
     ///
-    /// Generates a trace file with provision-specific context and logs the outcome.
+    /// Generates a trace file with provision-specific context, writes the structured
+    /// JSON failure report next to it, and logs the outcome.
     /// Success is logged at INFO level, failures at WARN level.
     ///
     /// # Arguments
@@ -68,8 +70,17 @@ impl ProvisionTraceWriter {
         use tracing::{info, warn};
 
         let trace_content = Self::format_trace(ctx, error);
+        let report = Self::build_report(ctx, error);
 
-        match self.common.write_trace("provision", &trace_content) {
+        let result = self
+            .common
+            .write_trace("provision", &trace_content)
+            .and_then(|trace_file_path| {
+                self.common.write_report(&trace_file_path, report)?;
+                Ok(trace_file_path)
+            });
+
+        match result {
             Ok(trace_file_path) => {
                 info!(
                     trace_id = %ctx.base.trace_id,
@@ -109,12 +120,23 @@ impl ProvisionTraceWriter {
         trace.push_str(TraceSections::error_chain_header());
         trace.push_str(&TraceSections::format_error_chain(error));
 
+        // Remote log excerpt (e.g. cloud-init output), when captured
+        if let Some(excerpt) = &ctx.remote_log_excerpt {
+            trace.push_str(&TraceSections::remote_log_excerpt(excerpt));
+        }
+
         // Footer
         trace.push_str(TraceSections::footer());
 
         trace
     }
 
+    /// Build the structured failure report written next to the trace file
+    fn build_report<E: Traceable>(ctx: &ProvisionFailureContext, error: &E) -> FailureReport {
+        FailureReport::new(ctx.failed_step.clone(), ctx.error_kind, ctx.base.clone())
+            .with_error_chain(TraceSections::collect_error_chain(error))
+    }
+
     /// Get the traces directory path
     #[must_use]
     pub fn traces_dir(&self) -> &Path {
@@ -213,6 +235,7 @@ mod tests {
                 trace_id: TraceId::new(),
                 trace_file_path: None,
             },
+            remote_log_excerpt: None,
         }
     }
 
@@ -235,6 +258,7 @@ mod tests {
                 trace_id,
                 trace_file_path: None,
             },
+            remote_log_excerpt: None,
         }
     }
 
@@ -264,6 +288,54 @@ mod tests {
         assert!(traces_dir.exists());
     }
 
+    #[test]
+    fn it_should_write_structured_report_next_to_provision_trace() {
+        use crate::infrastructure::trace::read_failure_report;
+
+        // Arrange
+        let (writer, _temp_dir, _traces_dir) = create_test_writer();
+        let error = TestError {
+            message: "apply failed".to_string(),
+            source: Some(Box::new(create_test_error("tofu exited with status 1"))),
+        };
+        let mut context = create_test_context(&error.to_string());
+        context.failed_step = ProvisionStep::OpenTofuApply;
+
+        // Act
+        let trace_file = writer.write_trace(&context, &error).unwrap();
+
+        // Assert
+        let report = read_failure_report(&trace_file)
+            .unwrap()
+            .expect("Structured report should be written next to the trace file");
+        assert_eq!(report.failed_step, ProvisionStep::OpenTofuApply);
+        assert_eq!(report.error_kind, context.error_kind);
+        assert_eq!(report.base.trace_id, context.base.trace_id);
+        assert_eq!(report.error_chain.len(), 2);
+        assert_eq!(
+            report.root_cause().map(|e| e.message.as_str()),
+            Some("TestError: tofu exited with status 1")
+        );
+    }
+
+    #[test]
+    fn it_should_include_the_remote_log_excerpt_in_the_trace() {
+        // Arrange
+        let (writer, _temp_dir, _traces_dir) = create_test_writer();
+        let error = create_test_error("cloud-init timed out");
+        let mut context = create_test_context(&error.to_string());
+        context.failed_step = ProvisionStep::CloudInitWait;
+        context.remote_log_excerpt = Some("Setting up docker.io ...\n".to_string());
+
+        // Act
+        let trace_file = writer.write_trace(&context, &error).unwrap();
+
+        // Assert
+        let content = std::fs::read_to_string(trace_file).unwrap();
+        assert!(content.contains("REMOTE LOG EXCERPT"));
+        assert!(content.contains("Setting up docker.io ..."));
+    }
+
     #[test]
     fn it_should_write_provision_trace_with_correct_naming() {
         // Arrange
@@ -360,6 +432,7 @@ mod tests {
             .filter_map(Result::ok)
             .map(|entry| entry.path())
             .filter(|path| path.is_file())
+            .filter(|path| path.extension().is_some_and(|ext| ext == "log"))
             .collect();
 
         assert_eq!(
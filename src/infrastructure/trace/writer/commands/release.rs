@@ -7,6 +7,7 @@ use std::path::{Path, PathBuf};
 use std::sync::Arc;
 
 use crate::domain::environment::state::ReleaseFailureContext;
+use crate::domain::environment::FailureReport;
 use crate::shared::{Clock, Traceable};
 
 use super::super::common::CommonTraceWriter;
@@ -45,7 +46,8 @@ impl ReleaseTraceWriter {
 
     /// Write a release failure trace file
     ///
-    /// Generates a trace file with release-specific context and logs the outcome.
+    /// Generates a trace file with release-specific context, writes the structured
+    /// JSON failure report next to it, and logs the outcome.
     /// Success is logged at INFO level, failures at WARN level.
     ///
     /// # Arguments
@@ -68,8 +70,17 @@ impl ReleaseTraceWriter {
         use tracing::{info, warn};
 
         let trace_content = Self::format_trace(ctx, error);
+        let report = Self::build_report(ctx, error);
 
-        match self.common.write_trace("release", &trace_content) {
+        let result =
+            self.common
+                .write_trace("release", &trace_content)
+                .and_then(|trace_file_path| {
+                    self.common.write_report(&trace_file_path, report)?;
+                    Ok(trace_file_path)
+                });
+
+        match result {
             Ok(trace_file_path) => {
                 info!(
                     trace_id = %ctx.base.trace_id,
@@ -115,6 +126,12 @@ impl ReleaseTraceWriter {
         trace
     }
 
+    /// Build the structured failure report written next to the trace file
+    fn build_report<E: Traceable>(ctx: &ReleaseFailureContext, error: &E) -> FailureReport {
+        FailureReport::new(ctx.failed_step, ctx.error_kind, ctx.base.clone())
+            .with_error_chain(TraceSections::collect_error_chain(error))
+    }
+
     /// Get the traces directory path
     #[must_use]
     pub fn traces_dir(&self) -> &Path {
@@ -359,6 +376,7 @@ mod tests {
             .filter_map(Result::ok)
             .map(|entry| entry.path())
             .filter(|path| path.is_file())
+            .filter(|path| path.extension().is_some_and(|ext| ext == "log"))
             .collect();
 
         assert_eq!(
@@ -7,6 +7,7 @@ use std::path::{Path, PathBuf};
 use std::sync::Arc;
 
 use crate::domain::environment::state::RunFailureContext;
+use crate::domain::environment::FailureReport;
 use crate::shared::{Clock, Traceable};
 
 use super::super::common::CommonTraceWriter;
@@ -45,7 +46,8 @@ impl RunTraceWriter {
 
     /// Write a run failure trace file
     ///
-    /// Generates a trace file with run-specific context and logs the outcome.
+    /// Generates a trace file with run-specific context, writes the structured
+    /// JSON failure report next to it, and logs the outcome.
     /// Success is logged at INFO level, failures at WARN level.
     ///
     /// # Arguments
@@ -68,8 +70,17 @@ impl RunTraceWriter {
         use tracing::{info, warn};
 
         let trace_content = Self::format_trace(ctx, error);
+        let report = Self::build_report(ctx, error);
 
-        match self.common.write_trace("run", &trace_content) {
+        let result = self
+            .common
+            .write_trace("run", &trace_content)
+            .and_then(|trace_file_path| {
+                self.common.write_report(&trace_file_path, report)?;
+                Ok(trace_file_path)
+            });
+
+        match result {
             Ok(trace_file_path) => {
                 info!(
                     trace_id = %ctx.base.trace_id,
@@ -103,7 +114,11 @@ impl RunTraceWriter {
 
         // Command-specific metadata
         let _ = writeln!(trace, "Failed Step: {}", ctx.failed_step);
-        let _ = writeln!(trace, "Error Kind: {:?}\n", ctx.error_kind);
+        let _ = writeln!(trace, "Error Kind: {:?}", ctx.error_kind);
+        if let Some(last_health_check) = &ctx.last_health_check {
+            let _ = writeln!(trace, "Last Health Check: {last_health_check}");
+        }
+        trace.push('\n');
 
         // Error chain
         trace.push_str(TraceSections::error_chain_header());
@@ -115,6 +130,12 @@ impl RunTraceWriter {
         trace
     }
 
+    /// Build the structured failure report written next to the trace file
+    fn build_report<E: Traceable>(ctx: &RunFailureContext, error: &E) -> FailureReport {
+        FailureReport::new(ctx.failed_step, ctx.error_kind, ctx.base.clone())
+            .with_error_chain(TraceSections::collect_error_chain(error))
+    }
+
     /// Get the traces directory path
     #[must_use]
     pub fn traces_dir(&self) -> &Path {
@@ -211,6 +232,7 @@ mod tests {
                 trace_id: TraceId::new(),
                 trace_file_path: None,
             },
+            last_health_check: None,
         }
     }
 
@@ -233,6 +255,7 @@ mod tests {
                 trace_id,
                 trace_file_path: None,
             },
+            last_health_check: None,
         }
     }
 
@@ -357,6 +380,7 @@ mod tests {
             .filter_map(Result::ok)
             .map(|entry| entry.path())
             .filter(|path| path.is_file())
+            .filter(|path| path.extension().is_some_and(|ext| ext == "log"))
             .collect();
 
         assert_eq!(
@@ -402,6 +426,7 @@ mod tests {
                 trace_id: TraceId::new(),
                 trace_file_path: None,
             },
+            last_health_check: None,
         };
 
         // Act
@@ -412,4 +437,22 @@ mod tests {
         assert!(trace_data.contains("Failed Step: Start Services"));
         assert!(trace_data.contains("Error Kind: InfrastructureOperation"));
     }
+
+    #[test]
+    fn it_should_include_the_last_health_check_response_in_the_trace() {
+        // Arrange
+        let (writer, _temp_dir, _traces_dir) = create_test_writer();
+        let error = create_test_error("health check never became healthy");
+        let mut context = create_test_context("health check never became healthy");
+        context.failed_step = RunStep::WaitForTrackerHealth;
+        context.last_health_check = Some("HTTP 500".to_string());
+
+        // Act
+        let trace_file = writer.write_trace(&context, &error).unwrap();
+        let trace_data = std::fs::read_to_string(trace_file).unwrap();
+
+        // Assert
+        assert!(trace_data.contains("Failed Step: Wait For Tracker Health"));
+        assert!(trace_data.contains("Last Health Check: HTTP 500"));
+    }
 }
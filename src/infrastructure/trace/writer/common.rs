@@ -2,6 +2,8 @@
 //!
 //! Provides shared file I/O operations for all command-specific trace writers:
 //! - File creation and writing
+//! - Structured JSON report generation next to each trace file
+//! - Trace index maintenance
 //! - Directory management
 //! - Timestamp-based filename generation
 
@@ -11,12 +13,14 @@ use std::path::{Path, PathBuf};
 use std::sync::Arc;
 
 use super::error::TraceWriterError;
+use crate::domain::environment::FailureReport;
+use crate::infrastructure::trace::index::{
+    read_trace_index, write_trace_index, TraceIndexEntry, TRACE_INDEX_FILE_NAME,
+};
+use crate::infrastructure::trace::reader::report_path;
+use crate::infrastructure::trace::TRACE_FILENAME_TIMESTAMP_FORMAT;
 use crate::shared::Clock;
 
-/// Timestamp format for trace filenames: YYYYmmdd-HHMMSS
-/// Example: 20251008-143045
-const TRACE_FILENAME_TIMESTAMP_FORMAT: &str = "%Y%m%d-%H%M%S";
-
 /// Common trace file writer infrastructure
 ///
 /// Provides shared functionality for all command-specific trace writers:
@@ -75,6 +79,78 @@ impl CommonTraceWriter {
         Ok(trace_file)
     }
 
+    /// Write the structured failure report for a trace file
+    ///
+    /// The report is serialized as pretty-printed JSON to a sibling of the
+    /// trace file with the `.json` extension (e.g. `20251008-143045-provision.json`),
+    /// with the path of the trace file, and the trace is added to the index
+    /// of the traces directory.
+    ///
+    /// # Arguments
+    ///
+    /// * `trace_file` - Path of the human-readable trace file the report belongs to
+    /// * `report` - Structured failure report to serialize
+    ///
+    /// # Returns
+    ///
+    /// Path to the created report file
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if serialization or file writing fails
+    pub(super) fn write_report(
+        &self,
+        trace_file: &Path,
+        mut report: FailureReport,
+    ) -> Result<PathBuf, TraceWriterError> {
+        let report_file = report_path(trace_file);
+        report.base.trace_file_path = Some(trace_file.to_path_buf());
+
+        let content = serde_json::to_string_pretty(&report).map_err(|source| {
+            TraceWriterError::Serialization {
+                path: report_file.display().to_string(),
+                source,
+            }
+        })?;
+
+        self.write_trace_file(&report_file, &content)?;
+        self.add_to_index(trace_file, &report)?;
+
+        Ok(report_file)
+    }
+
+    /// Add the trace of a report to the index of the traces directory
+    ///
+    /// An unreadable index is replaced rather than failing the trace.
+    fn add_to_index(
+        &self,
+        trace_file: &Path,
+        report: &FailureReport,
+    ) -> Result<(), TraceWriterError> {
+        let mut entries = read_trace_index(&self.traces_dir).unwrap_or_default();
+        entries.push(TraceIndexEntry {
+            trace_id: report.base.trace_id.clone(),
+            command: report.failed_step.command().to_string(),
+            step: report.failed_step.step_name(),
+            timestamp: report.base.failed_at,
+            file: trace_file
+                .file_name()
+                .map(|name| name.to_string_lossy().into_owned())
+                .unwrap_or_default(),
+        });
+
+        write_trace_index(&self.traces_dir, &entries).map_err(|source| {
+            TraceWriterError::FileWrite {
+                path: self
+                    .traces_dir
+                    .join(TRACE_INDEX_FILE_NAME)
+                    .display()
+                    .to_string(),
+                source,
+            }
+        })
+    }
+
     /// Generate a timestamp-based trace filename
     ///
     /// Creates a filename in the format: `{timestamp}-{command_name}.log`
@@ -28,4 +28,15 @@ pub enum TraceWriterError {
         #[source]
         source: std::io::Error,
     },
+
+    /// Failed to serialize a structured failure report
+    #[error("Failed to serialize failure report for {path}: {source}")]
+    Serialization {
+        /// Path where the report was going to be written
+        path: String,
+
+        /// Underlying serialization error
+        #[source]
+        source: serde_json::Error,
+    },
 }
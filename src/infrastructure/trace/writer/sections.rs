@@ -3,9 +3,12 @@
 //! Provides formatting utilities for trace file sections:
 //! - Headers and footers
 //! - Error chain formatting
+//! - Failed Ansible task and remote log excerpt sections
 //! - Base metadata formatting
 
+use crate::adapters::ansible::TaskFailure;
 use crate::domain::environment::state::BaseFailureContext;
+use crate::domain::environment::TraceEntry;
 use crate::shared::Traceable;
 
 /// Common sections for all trace files
@@ -52,6 +55,47 @@ impl TraceSections {
          ───────────────────────────────────────────────────────────────\n\n"
     }
 
+    /// Format a remote log excerpt section
+    ///
+    /// Creates a section with log lines captured from the instance (e.g. the
+    /// tail of the cloud-init output log), placed after the error chain.
+    pub(super) fn remote_log_excerpt(excerpt: &str) -> String {
+        format!(
+            "\n───────────────────────────────────────────────────────────────\n\
+                                 REMOTE LOG EXCERPT\n\
+             ───────────────────────────────────────────────────────────────\n\n\
+             {}\n",
+            excerpt.trim_end()
+        )
+    }
+
+    /// Format a failed Ansible task section
+    ///
+    /// Creates a section naming the task that stopped a playbook, with its
+    /// module, host, message and standard error, placed before the error chain.
+    pub(super) fn failed_task(failure: &TaskFailure) -> String {
+        use std::fmt::Write;
+
+        let mut section = format!(
+            "───────────────────────────────────────────────────────────────\n\
+                                  FAILED TASK\n\
+             ───────────────────────────────────────────────────────────────\n\n\
+             Playbook: {}\n\
+             Task: {}\n\
+             Module: {}\n\
+             Host: {}\n\
+             Message: {}\n",
+            failure.playbook, failure.task, failure.module, failure.host, failure.message
+        );
+
+        if let Some(stderr) = &failure.stderr {
+            let _ = writeln!(section, "Stderr:\n{}", stderr.trim_end());
+        }
+
+        section.push('\n');
+        section
+    }
+
     /// Format a complete error chain by walking the `Traceable` hierarchy
     ///
     /// Recursively formats all errors in the error chain, numbering each
@@ -84,6 +128,30 @@ impl TraceSections {
         }
     }
 
+    /// Collect the error chain as structured entries by walking the `Traceable` hierarchy
+    ///
+    /// Produces the same levels as [`Self::format_error_chain`], for the
+    /// structured failure report.
+    pub(super) fn collect_error_chain<E: Traceable>(error: &E) -> Vec<TraceEntry> {
+        let mut entries = vec![TraceEntry {
+            level: 0,
+            kind: error.error_kind(),
+            message: error.trace_format(),
+        }];
+
+        let mut source = error.trace_source();
+        while let Some(current) = source {
+            entries.push(TraceEntry {
+                level: entries.len(),
+                kind: current.error_kind(),
+                message: current.trace_format(),
+            });
+            source = current.trace_source();
+        }
+
+        entries
+    }
+
     /// Format base metadata section common to all failure contexts
     ///
     /// Formats the metadata fields from `BaseFailureContext`:
@@ -178,4 +246,24 @@ mod tests {
         assert!(chain.contains("intermediate error"));
         assert!(chain.contains("root cause"));
     }
+
+    #[test]
+    fn it_should_collect_error_chain_entries_for_every_level() {
+        let root = TestError {
+            message: "root cause".to_string(),
+            source: None,
+        };
+        let top = TestError {
+            message: "top level error".to_string(),
+            source: Some(Box::new(root)),
+        };
+
+        let entries = TraceSections::collect_error_chain(&top);
+
+        assert_eq!(entries.len(), 2);
+        assert_eq!(entries[0].level, 0);
+        assert_eq!(entries[0].message, "TestError: top level error");
+        assert_eq!(entries[1].level, 1);
+        assert_eq!(entries[1].message, "TestError: root cause");
+    }
 }
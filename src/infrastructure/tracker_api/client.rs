@@ -0,0 +1,340 @@
+//! Requests to the tracker API
+//!
+//! Every endpoint of the version 1 API is authenticated with the admin token,
+//! passed as the `token` query parameter. The token must never end up in
+//! error messages, so the URL of the resource is quoted instead of the URL
+//! of the request. Only the health check (`/api/health_check`) is public.
+//!
+//! The requests of each resource are implemented next to its types: `keys`,
+//! `stats` and `whitelist`.
+
+use std::time::Duration;
+
+use reqwest::{Client, Method, RequestBuilder, StatusCode};
+use thiserror::Error;
+use tracing::info;
+use url::Url;
+
+use super::{TrackerApi, TrackerStats};
+use crate::domain::tracker::{AuthKey, InfoHash, IssuedAuthKey};
+use crate::infrastructure::external_validators::http_client;
+use crate::shared::{ApiToken, ServiceEndpoint};
+
+/// HTTP client request timeout
+const REQUEST_TIMEOUT_SECS: u64 = 10;
+
+/// Longest part of an error response body quoted in error messages
+const MAX_QUOTED_BODY_LEN: usize = 200;
+
+/// Path of the health check, relative to the version 1 API root
+const HEALTH_CHECK_PATH: &str = "../health_check";
+
+/// Errors returned by the tracker API client
+///
+/// Callers tell apart a tracker that cannot be reached ([`Self::Unreachable`]),
+/// a wrong admin token ([`Self::Unauthorized`]) and a request the API refused
+/// or answered unexpectedly ([`Self::RequestFailed`], [`Self::InvalidResponse`]).
+#[derive(Debug, Error)]
+pub enum TrackerApiError {
+    /// The HTTP client could not be initialized
+    #[error("Failed to build HTTP client: {source}")]
+    ClientBuild {
+        #[source]
+        source: reqwest::Error,
+    },
+
+    /// The request did not reach the API or got no response
+    #[error("{message}")]
+    Unreachable { message: String },
+
+    /// The API rejected the admin token
+    #[error("Tracker API '{url}' rejected the admin token (HTTP {status})")]
+    Unauthorized { url: String, status: u16 },
+
+    /// The API answered with an error status
+    #[error("Tracker API '{url}' returned HTTP {status}: {body}")]
+    RequestFailed {
+        url: String,
+        status: u16,
+        body: String,
+    },
+
+    /// The API answered with a body that is not the expected resource
+    #[error("Tracker API '{url}' returned an invalid response: {reason}")]
+    InvalidResponse { url: String, reason: String },
+}
+
+/// Client of the HTTP API of a deployed tracker
+///
+/// Sends the requests authenticated with the admin token to the version 1
+/// API. The requests of the stats, keys and whitelist resources are exposed
+/// both as methods and through the [`TrackerApi`](super::TrackerApi) trait,
+/// for the callers that take a stand-in in tests.
+pub struct TrackerApiClient {
+    api_endpoint: ServiceEndpoint,
+    admin_token: ApiToken,
+    accept_untrusted_certs: bool,
+}
+
+impl TrackerApiClient {
+    /// Create a new `TrackerApiClient`
+    ///
+    /// # Arguments
+    /// * `api_endpoint` - Root of the version 1 API (`/api/v1/`) of the tracker
+    /// * `admin_token` - Admin token of the tracker API
+    #[must_use]
+    pub fn new(api_endpoint: ServiceEndpoint, admin_token: ApiToken) -> Self {
+        Self {
+            api_endpoint,
+            admin_token,
+            accept_untrusted_certs: false,
+        }
+    }
+
+    /// Accept certificates that do not chain to a trusted root
+    ///
+    /// Needed for certificates issued by the Let's Encrypt staging CA.
+    #[must_use]
+    pub fn with_untrusted_certs(mut self, accept_untrusted_certs: bool) -> Self {
+        self.accept_untrusted_certs = accept_untrusted_certs;
+        self
+    }
+
+    /// Root of the version 1 API
+    #[must_use]
+    pub fn api_endpoint(&self) -> &ServiceEndpoint {
+        &self.api_endpoint
+    }
+
+    /// URL of `path` relative to the API root
+    pub(super) fn url(&self, path: &str) -> Result<Url, TrackerApiError> {
+        self.api_endpoint
+            .url()
+            .join(path)
+            .map_err(|e| TrackerApiError::InvalidResponse {
+                url: self.api_endpoint.url().to_string(),
+                reason: format!("cannot build the URL of '{path}': {e}"),
+            })
+    }
+
+    /// Check that the API is up
+    ///
+    /// The health check is public: the admin token is not sent.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the API cannot be reached or does not answer with
+    /// a success status.
+    pub async fn health_check(&self) -> Result<(), TrackerApiError> {
+        let url = self.url(HEALTH_CHECK_PATH)?;
+
+        self.send(&url, self.http_client()?.get(url.clone()))
+            .await?;
+
+        info!(url = %url, "Tracker API health check passed");
+
+        Ok(())
+    }
+
+    /// Request to `url` authenticated with the admin token
+    pub(super) fn request(
+        &self,
+        method: Method,
+        url: &Url,
+    ) -> Result<RequestBuilder, TrackerApiError> {
+        Ok(self
+            .http_client()?
+            .request(method, url.clone())
+            .query(&[("token", self.admin_token.expose_secret())]))
+    }
+
+    /// HTTP client resolving the domain of the API to the instance IP
+    fn http_client(&self) -> Result<Client, TrackerApiError> {
+        http_client::client_accepting_untrusted_certs(
+            &self.api_endpoint,
+            Duration::from_secs(REQUEST_TIMEOUT_SECS),
+            self.accept_untrusted_certs,
+        )
+        .map_err(|source| TrackerApiError::ClientBuild { source })
+    }
+
+    /// Send `request` and return the body of a successful response
+    ///
+    /// The token is a query parameter: `url` is quoted in errors instead of
+    /// the URL of the request.
+    pub(super) async fn send(
+        &self,
+        url: &Url,
+        request: RequestBuilder,
+    ) -> Result<Vec<u8>, TrackerApiError> {
+        let response = request
+            .send()
+            .await
+            .map_err(|e| TrackerApiError::Unreachable {
+                message: http_client::request_failure_message(&self.api_endpoint, &e.without_url()),
+            })?;
+
+        let status = response.status();
+        let body = response
+            .bytes()
+            .await
+            .map_err(|e| TrackerApiError::Unreachable {
+                message: format!(
+                    "Failed to read the response of '{url}': {}",
+                    e.without_url()
+                ),
+            })?;
+
+        if status.is_success() {
+            return Ok(body.to_vec());
+        }
+
+        let quoted: String = String::from_utf8_lossy(&body)
+            .chars()
+            .take(MAX_QUOTED_BODY_LEN)
+            .collect();
+
+        // Version 3 answers an invalid token with a 500 "unauthorized" rejection
+        if matches!(status, StatusCode::UNAUTHORIZED | StatusCode::FORBIDDEN)
+            || quoted.contains("unauthorized")
+        {
+            return Err(TrackerApiError::Unauthorized {
+                url: url.to_string(),
+                status: status.as_u16(),
+            });
+        }
+
+        Err(TrackerApiError::RequestFailed {
+            url: url.to_string(),
+            status: status.as_u16(),
+            body: quoted.trim().to_string(),
+        })
+    }
+}
+
+impl TrackerApi for TrackerApiClient {
+    async fn health_check(&self) -> Result<(), TrackerApiError> {
+        TrackerApiClient::health_check(self).await
+    }
+
+    async fn stats(&self) -> Result<TrackerStats, TrackerApiError> {
+        TrackerApiClient::stats(self).await
+    }
+
+    async fn add_key(&self, valid_for: Option<Duration>) -> Result<IssuedAuthKey, TrackerApiError> {
+        TrackerApiClient::add_key(self, valid_for).await
+    }
+
+    async fn delete_key(&self, key: &AuthKey) -> Result<(), TrackerApiError> {
+        TrackerApiClient::delete_key(self, key).await
+    }
+
+    async fn reload_keys(&self) -> Result<(), TrackerApiError> {
+        TrackerApiClient::reload_keys(self).await
+    }
+
+    async fn whitelist_add(&self, info_hash: &InfoHash) -> Result<(), TrackerApiError> {
+        TrackerApiClient::whitelist_add(self, info_hash).await
+    }
+
+    async fn whitelist_remove(&self, info_hash: &InfoHash) -> Result<(), TrackerApiError> {
+        TrackerApiClient::whitelist_remove(self, info_hash).await
+    }
+
+    async fn reload_whitelist(&self) -> Result<(), TrackerApiError> {
+        TrackerApiClient::reload_whitelist(self).await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use wiremock::matchers::{method, path, query_param};
+    use wiremock::{Mock, MockServer, ResponseTemplate};
+
+    use super::*;
+
+    fn client(server: &MockServer) -> TrackerApiClient {
+        TrackerApiClient::new(
+            ServiceEndpoint::http(*server.address(), "/api/v1/").unwrap(),
+            ApiToken::from("MyAccessToken".to_string()),
+        )
+    }
+
+    #[tokio::test]
+    async fn it_should_check_the_health_of_the_api_without_the_token() {
+        let server = MockServer::start().await;
+        Mock::given(method("GET"))
+            .and(path("/api/health_check"))
+            .respond_with(ResponseTemplate::new(200).set_body_string(r#"{"status":"Ok"}"#))
+            .expect(1)
+            .mount(&server)
+            .await;
+
+        client(&server).health_check().await.unwrap();
+
+        let requests = server.received_requests().await.unwrap();
+        assert_eq!(requests[0].url.query(), None);
+    }
+
+    #[tokio::test]
+    async fn it_should_send_the_admin_token_with_the_authenticated_requests() {
+        let server = MockServer::start().await;
+        Mock::given(method("GET"))
+            .and(path("/api/v1/keys/reload"))
+            .and(query_param("token", "MyAccessToken"))
+            .respond_with(ResponseTemplate::new(200).set_body_string(r#"{"status":"ok"}"#))
+            .expect(1)
+            .mount(&server)
+            .await;
+
+        client(&server).reload_keys().await.unwrap();
+    }
+
+    #[tokio::test]
+    async fn it_should_report_a_rejected_admin_token() {
+        let server = MockServer::start().await;
+        Mock::given(method("GET"))
+            .respond_with(ResponseTemplate::new(401).set_body_string("Unauthorized"))
+            .mount(&server)
+            .await;
+
+        let result = client(&server).stats().await;
+
+        assert!(matches!(
+            result,
+            Err(TrackerApiError::Unauthorized { url, status: 401 }) if !url.contains("MyAccessToken")
+        ));
+    }
+
+    #[tokio::test]
+    async fn it_should_report_a_request_refused_by_the_api() {
+        let server = MockServer::start().await;
+        Mock::given(method("GET"))
+            .respond_with(ResponseTemplate::new(500).set_body_string("failed to reload keys"))
+            .mount(&server)
+            .await;
+
+        let result = client(&server).reload_keys().await;
+
+        assert!(matches!(
+            result,
+            Err(TrackerApiError::RequestFailed { status: 500, body, .. }) if body == "failed to reload keys"
+        ));
+    }
+
+    #[tokio::test]
+    async fn it_should_report_an_api_that_cannot_be_reached() {
+        let address = std::net::TcpListener::bind("127.0.0.1:0")
+            .unwrap()
+            .local_addr()
+            .unwrap();
+        let client = TrackerApiClient::new(
+            ServiceEndpoint::http(address, "/api/v1/").unwrap(),
+            ApiToken::from("MyAccessToken".to_string()),
+        );
+
+        let result = client.health_check().await;
+
+        assert!(matches!(result, Err(TrackerApiError::Unreachable { .. })));
+    }
+}
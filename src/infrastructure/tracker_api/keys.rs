@@ -0,0 +1,224 @@
+//! Authentication keys management through the tracker API
+//!
+//! The tracker API of version 3 manages keys with:
+//!
+//! - `POST /api/v1/keys` with `{"key": null, "seconds_valid": <n>}` mints a
+//!   key, permanent when `seconds_valid` is `null`
+//! - `DELETE /api/v1/key/<key>` revokes a key
+//! - `GET /api/v1/keys/reload` reloads the keys from the database
+//!
+//! Tracker replicas share the database but keep the keys in memory, so keys
+//! changed through one replica are reloaded on the others.
+
+use std::time::Duration;
+
+use chrono::{DateTime, Utc};
+use reqwest::header::CONTENT_TYPE;
+use reqwest::Method;
+use serde::Deserialize;
+use tracing::info;
+
+use super::client::{TrackerApiClient, TrackerApiError};
+use crate::domain::tracker::{AuthKey, IssuedAuthKey};
+
+/// Key resource returned by `POST /api/v1/keys`
+#[derive(Debug, Deserialize)]
+struct AuthKeyResource {
+    key: String,
+    valid_until: Option<i64>,
+}
+
+impl TrackerApiClient {
+    /// Mint a new key, valid for `valid_for` or permanent when `None`
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the API cannot be reached, rejects the admin token
+    /// or the request, or answers with something other than a key.
+    pub async fn add_key(
+        &self,
+        valid_for: Option<Duration>,
+    ) -> Result<IssuedAuthKey, TrackerApiError> {
+        let url = self.url("keys")?;
+        let form = serde_json::json!({
+            "key": null,
+            "seconds_valid": valid_for.map(|duration| duration.as_secs()),
+        });
+
+        let body = self
+            .send(
+                &url,
+                self.request(Method::POST, &url)?
+                    .header(CONTENT_TYPE, "application/json")
+                    .body(form.to_string()),
+            )
+            .await?;
+
+        let invalid = |reason: String| TrackerApiError::InvalidResponse {
+            url: url.to_string(),
+            reason,
+        };
+        let resource: AuthKeyResource =
+            serde_json::from_slice(&body).map_err(|e| invalid(e.to_string()))?;
+        let key = AuthKey::new(resource.key).map_err(|e| invalid(e.to_string()))?;
+        let valid_until = resource
+            .valid_until
+            .map(|secs| {
+                DateTime::<Utc>::from_timestamp(secs, 0)
+                    .ok_or_else(|| invalid(format!("invalid expiry timestamp {secs}")))
+            })
+            .transpose()?;
+
+        info!(url = %url, valid_until = ?valid_until, "Tracker key added");
+
+        Ok(IssuedAuthKey { key, valid_until })
+    }
+
+    /// Revoke `key`
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the API cannot be reached, rejects the admin token
+    /// or does not delete the key.
+    pub async fn delete_key(&self, key: &AuthKey) -> Result<(), TrackerApiError> {
+        let url = self.url(&format!("key/{key}"))?;
+
+        self.send(&url, self.request(Method::DELETE, &url)?).await?;
+
+        info!(url = %self.api_endpoint().url(), "Tracker key deleted");
+
+        Ok(())
+    }
+
+    /// Reload the keys from the tracker database
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the API cannot be reached, rejects the admin token
+    /// or fails to reload the keys.
+    pub async fn reload_keys(&self) -> Result<(), TrackerApiError> {
+        let url = self.url("keys/reload")?;
+
+        self.send(&url, self.request(Method::GET, &url)?).await?;
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+    use wiremock::matchers::{body_json, method, path, query_param};
+    use wiremock::{Mock, MockServer, ResponseTemplate};
+
+    use super::*;
+    use crate::shared::{ApiToken, ServiceEndpoint};
+
+    const KEY: &str = "YZSl4lMZupRuOpSRC3krIKR5BPB14nrJ";
+
+    fn client(server: &MockServer) -> TrackerApiClient {
+        TrackerApiClient::new(
+            ServiceEndpoint::http(*server.address(), "/api/v1/").unwrap(),
+            ApiToken::from("MyAccessToken".to_string()),
+        )
+    }
+
+    #[tokio::test]
+    async fn it_should_mint_a_key_valid_for_the_given_duration() {
+        let server = MockServer::start().await;
+        Mock::given(method("POST"))
+            .and(path("/api/v1/keys"))
+            .and(query_param("token", "MyAccessToken"))
+            .and(body_json(json!({ "key": null, "seconds_valid": 3600 })))
+            .respond_with(ResponseTemplate::new(200).set_body_string(
+                r#"{"key":"YZSl4lMZupRuOpSRC3krIKR5BPB14nrJ","valid_until":1767225600,"expiry_time":"2026-01-01 00:00:00 UTC"}"#,
+            ))
+            .expect(1)
+            .mount(&server)
+            .await;
+
+        let issued = client(&server)
+            .add_key(Some(Duration::from_secs(3600)))
+            .await
+            .unwrap();
+
+        assert_eq!(issued.key.as_str(), KEY);
+        assert_eq!(
+            issued.valid_until,
+            DateTime::<Utc>::from_timestamp(1_767_225_600, 0)
+        );
+    }
+
+    #[tokio::test]
+    async fn it_should_mint_a_permanent_key_without_a_duration() {
+        let server = MockServer::start().await;
+        Mock::given(method("POST"))
+            .and(path("/api/v1/keys"))
+            .and(query_param("token", "MyAccessToken"))
+            .and(body_json(json!({ "key": null, "seconds_valid": null })))
+            .respond_with(ResponseTemplate::new(200).set_body_string(
+                r#"{"key":"YZSl4lMZupRuOpSRC3krIKR5BPB14nrJ","valid_until":null,"expiry_time":null}"#,
+            ))
+            .expect(1)
+            .mount(&server)
+            .await;
+
+        let issued = client(&server).add_key(None).await.unwrap();
+
+        assert_eq!(issued.valid_until, None);
+    }
+
+    #[tokio::test]
+    async fn it_should_delete_a_key() {
+        let server = MockServer::start().await;
+        Mock::given(method("DELETE"))
+            .and(path(format!("/api/v1/key/{KEY}")))
+            .and(query_param("token", "MyAccessToken"))
+            .respond_with(ResponseTemplate::new(200).set_body_string(r#"{"status":"ok"}"#))
+            .expect(1)
+            .mount(&server)
+            .await;
+
+        client(&server)
+            .delete_key(&AuthKey::new(KEY).unwrap())
+            .await
+            .unwrap();
+    }
+
+    #[tokio::test]
+    async fn it_should_report_a_rejected_admin_token() {
+        let server = MockServer::start().await;
+        Mock::given(method("GET"))
+            .and(path("/api/v1/keys/reload"))
+            .respond_with(
+                ResponseTemplate::new(500)
+                    .set_body_string(r#"Unhandled rejection: Err { reason: "unauthorized" }"#),
+            )
+            .mount(&server)
+            .await;
+
+        let result = client(&server).reload_keys().await;
+
+        assert!(matches!(
+            result,
+            Err(TrackerApiError::Unauthorized { status: 500, .. })
+        ));
+    }
+
+    #[tokio::test]
+    async fn it_should_report_an_invalid_key_resource() {
+        let server = MockServer::start().await;
+        Mock::given(method("POST"))
+            .and(path("/api/v1/keys"))
+            .respond_with(ResponseTemplate::new(200).set_body_string(r#"{"key":"short"}"#))
+            .mount(&server)
+            .await;
+
+        let result = client(&server).add_key(None).await;
+
+        assert!(matches!(
+            result,
+            Err(TrackerApiError::InvalidResponse { .. })
+        ));
+    }
+}
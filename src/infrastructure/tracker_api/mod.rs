@@ -0,0 +1,109 @@
+//! Tracker API client
+//!
+//! This module calls the HTTP API of a deployed Torrust Tracker from outside
+//! the VM, authenticated with the admin token of the environment. Every
+//! command talking to the tracker API (`test`, `keys`, `stats`) goes through
+//! the same client, so authentication, TLS handling and error mapping live in
+//! one place.
+//!
+//! ## Components
+//!
+//! - `client` - The client, its health check and the errors of every request
+//! - `keys` - Minting, revoking and reloading the authentication keys of a
+//!   private tracker (`/api/v1/keys`, `/api/v1/key/<key>`)
+//! - `stats` - Reading the torrent, peer and request counters of the tracker
+//!   (`/api/v1/stats`)
+//! - `whitelist` - Adding and removing torrents on the whitelist of a listed
+//!   tracker (`/api/v1/whitelist/<info_hash>`)
+//!
+//! The API offers no listing of the keys: the `keys` command lists the keys
+//! recorded in the environment instead.
+//!
+//! ## HTTPS Support
+//!
+//! Like the external validators, the client resolves TLS domains to the
+//! instance IP (no DNS dependency) and accepts the self-signed certificates of
+//! `.local` domains. Certificates issued by the Let's Encrypt staging CA are
+//! accepted when requested by the caller.
+
+use std::time::Duration;
+
+use crate::domain::tracker::{AuthKey, InfoHash, IssuedAuthKey};
+
+pub mod client;
+pub mod keys;
+pub mod stats;
+pub mod whitelist;
+
+pub use client::{TrackerApiClient, TrackerApiError};
+pub use stats::TrackerStats;
+
+/// Requests to the HTTP API of a tracker
+///
+/// Implemented by [`TrackerApiClient`]. Callers that take any `TrackerApi`
+/// can be tested against a stand-in instead of a running tracker.
+#[allow(async_fn_in_trait)]
+pub trait TrackerApi {
+    /// Check that the API is up, without authentication
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the API cannot be reached or is not healthy.
+    async fn health_check(&self) -> Result<(), TrackerApiError>;
+
+    /// Read the current statistics of the tracker
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the API cannot be reached, rejects the admin token
+    /// or answers with something other than the statistics.
+    async fn stats(&self) -> Result<TrackerStats, TrackerApiError>;
+
+    /// Mint a new key, valid for `valid_for` or permanent when `None`
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the API cannot be reached, rejects the admin token
+    /// or the request, or answers with something other than a key.
+    async fn add_key(&self, valid_for: Option<Duration>) -> Result<IssuedAuthKey, TrackerApiError>;
+
+    /// Revoke `key`
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the API cannot be reached, rejects the admin token
+    /// or does not delete the key.
+    async fn delete_key(&self, key: &AuthKey) -> Result<(), TrackerApiError>;
+
+    /// Reload the keys from the tracker database
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the API cannot be reached, rejects the admin token
+    /// or fails to reload the keys.
+    async fn reload_keys(&self) -> Result<(), TrackerApiError>;
+
+    /// Add the torrent `info_hash` to the whitelist
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the API cannot be reached, rejects the admin token
+    /// or does not add the torrent.
+    async fn whitelist_add(&self, info_hash: &InfoHash) -> Result<(), TrackerApiError>;
+
+    /// Remove the torrent `info_hash` from the whitelist
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the API cannot be reached, rejects the admin token
+    /// or does not remove the torrent.
+    async fn whitelist_remove(&self, info_hash: &InfoHash) -> Result<(), TrackerApiError>;
+
+    /// Reload the whitelist from the tracker database
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the API cannot be reached, rejects the admin token
+    /// or fails to reload the whitelist.
+    async fn reload_whitelist(&self) -> Result<(), TrackerApiError>;
+}
@@ -0,0 +1,179 @@
+//! Tracker statistics through the tracker API
+//!
+//! `GET /api/v1/stats` returns the counters of a running tracker: the
+//! torrents and peers it keeps in memory, and the number of requests handled
+//! by each protocol since it started.
+//!
+//! Only the counters shown by the deployer are deserialized. The request
+//! counters are missing in some tracker versions and default to zero.
+
+use reqwest::Method;
+use serde::{Deserialize, Serialize};
+use tracing::info;
+
+use super::client::{TrackerApiClient, TrackerApiError};
+
+/// Statistics resource returned by `GET /api/v1/stats`
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct TrackerStats {
+    /// Torrents with at least one peer, or kept by the tracker policy
+    pub torrents: u64,
+    /// Peers that have the complete content
+    pub seeders: u64,
+    /// Downloads completed, as reported by the peers
+    pub completed: u64,
+    /// Peers that are still downloading
+    pub leechers: u64,
+    /// HTTP announces over IPv4
+    #[serde(default)]
+    pub tcp4_announces_handled: u64,
+    /// HTTP scrapes over IPv4
+    #[serde(default)]
+    pub tcp4_scrapes_handled: u64,
+    /// HTTP announces over IPv6
+    #[serde(default)]
+    pub tcp6_announces_handled: u64,
+    /// HTTP scrapes over IPv6
+    #[serde(default)]
+    pub tcp6_scrapes_handled: u64,
+    /// UDP announces over IPv4
+    #[serde(default)]
+    pub udp4_announces_handled: u64,
+    /// UDP scrapes over IPv4
+    #[serde(default)]
+    pub udp4_scrapes_handled: u64,
+    /// UDP announces over IPv6
+    #[serde(default)]
+    pub udp6_announces_handled: u64,
+    /// UDP scrapes over IPv6
+    #[serde(default)]
+    pub udp6_scrapes_handled: u64,
+}
+
+impl TrackerStats {
+    /// Peers of every torrent, seeding or not
+    #[must_use]
+    pub fn peers(&self) -> u64 {
+        self.seeders + self.leechers
+    }
+
+    /// Announces handled over HTTP, both IP versions
+    #[must_use]
+    pub fn http_announces(&self) -> u64 {
+        self.tcp4_announces_handled + self.tcp6_announces_handled
+    }
+
+    /// Announces handled over UDP, both IP versions
+    #[must_use]
+    pub fn udp_announces(&self) -> u64 {
+        self.udp4_announces_handled + self.udp6_announces_handled
+    }
+}
+
+impl TrackerApiClient {
+    /// URL of the statistics endpoint, without the admin token
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the URL cannot be built from the API root.
+    pub fn stats_url(&self) -> Result<String, TrackerApiError> {
+        Ok(self.url("stats")?.to_string())
+    }
+
+    /// Read the current statistics of the tracker
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the API cannot be reached, rejects the admin token
+    /// or answers with something other than the statistics.
+    pub async fn stats(&self) -> Result<TrackerStats, TrackerApiError> {
+        let url = self.url("stats")?;
+
+        let body = self.send(&url, self.request(Method::GET, &url)?).await?;
+
+        let stats: TrackerStats =
+            serde_json::from_slice(&body).map_err(|e| TrackerApiError::InvalidResponse {
+                url: url.to_string(),
+                reason: e.to_string(),
+            })?;
+
+        info!(
+            url = %url,
+            torrents = stats.torrents,
+            peers = stats.peers(),
+            "Tracker statistics retrieved"
+        );
+
+        Ok(stats)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use wiremock::matchers::{method, path, query_param};
+    use wiremock::{Mock, MockServer, ResponseTemplate};
+
+    use super::*;
+    use crate::shared::{ApiToken, ServiceEndpoint};
+
+    fn client(server: &MockServer) -> TrackerApiClient {
+        TrackerApiClient::new(
+            ServiceEndpoint::http(*server.address(), "/api/v1/").unwrap(),
+            ApiToken::from("MyAccessToken".to_string()),
+        )
+    }
+
+    async fn mock_stats(server: &MockServer, body: &str) {
+        Mock::given(method("GET"))
+            .and(path("/api/v1/stats"))
+            .and(query_param("token", "MyAccessToken"))
+            .respond_with(ResponseTemplate::new(200).set_body_string(body))
+            .expect(1)
+            .mount(server)
+            .await;
+    }
+
+    #[tokio::test]
+    async fn it_should_read_the_statistics_of_the_tracker() {
+        let server = MockServer::start().await;
+        mock_stats(
+            &server,
+            r#"{"torrents":2,"seeders":1,"completed":3,"leechers":4,"tcp4_connections_handled":5,"tcp4_announces_handled":5,"tcp4_scrapes_handled":0,"tcp6_connections_handled":0,"tcp6_announces_handled":0,"tcp6_scrapes_handled":0,"udp4_requests":4,"udp4_connections_handled":2,"udp4_announces_handled":2,"udp4_scrapes_handled":0,"udp6_requests":0,"udp6_connections_handled":0,"udp6_announces_handled":0,"udp6_scrapes_handled":0}"#,
+        )
+        .await;
+
+        let stats = client(&server).stats().await.unwrap();
+
+        assert_eq!(stats.torrents, 2);
+        assert_eq!(stats.peers(), 5);
+        assert_eq!(stats.http_announces(), 5);
+        assert_eq!(stats.udp_announces(), 2);
+    }
+
+    #[tokio::test]
+    async fn it_should_default_the_counters_missing_in_older_trackers() {
+        let server = MockServer::start().await;
+        mock_stats(
+            &server,
+            r#"{"torrents":0,"seeders":0,"completed":0,"leechers":0}"#,
+        )
+        .await;
+
+        let stats = client(&server).stats().await.unwrap();
+
+        assert_eq!(stats, TrackerStats::default());
+    }
+
+    #[tokio::test]
+    async fn it_should_report_a_response_that_is_not_the_statistics() {
+        let server = MockServer::start().await;
+        mock_stats(&server, r#"{"status":"ok"}"#).await;
+
+        let result = client(&server).stats().await;
+
+        assert!(matches!(
+            result,
+            Err(TrackerApiError::InvalidResponse { url, .. }) if !url.contains("MyAccessToken")
+        ));
+    }
+}
@@ -0,0 +1,122 @@
+//! Torrent whitelist management through the tracker API
+//!
+//! The tracker API of version 3 manages the whitelist with:
+//!
+//! - `POST /api/v1/whitelist/<info_hash>` adds a torrent
+//! - `DELETE /api/v1/whitelist/<info_hash>` removes a torrent
+//! - `GET /api/v1/whitelist/reload` reloads the whitelist from the database
+//!
+//! Like the keys, the whitelist is shared through the database but kept in
+//! memory by each tracker replica.
+
+use reqwest::Method;
+use tracing::info;
+
+use super::client::{TrackerApiClient, TrackerApiError};
+use crate::domain::tracker::InfoHash;
+
+impl TrackerApiClient {
+    /// Add the torrent `info_hash` to the whitelist
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the API cannot be reached, rejects the admin token
+    /// or does not add the torrent.
+    pub async fn whitelist_add(&self, info_hash: &InfoHash) -> Result<(), TrackerApiError> {
+        let url = self.url(&format!("whitelist/{info_hash}"))?;
+
+        self.send(&url, self.request(Method::POST, &url)?).await?;
+
+        info!(url = %url, "Torrent added to the tracker whitelist");
+
+        Ok(())
+    }
+
+    /// Remove the torrent `info_hash` from the whitelist
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the API cannot be reached, rejects the admin token
+    /// or does not remove the torrent.
+    pub async fn whitelist_remove(&self, info_hash: &InfoHash) -> Result<(), TrackerApiError> {
+        let url = self.url(&format!("whitelist/{info_hash}"))?;
+
+        self.send(&url, self.request(Method::DELETE, &url)?).await?;
+
+        info!(url = %url, "Torrent removed from the tracker whitelist");
+
+        Ok(())
+    }
+
+    /// Reload the whitelist from the tracker database
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the API cannot be reached, rejects the admin token
+    /// or fails to reload the whitelist.
+    pub async fn reload_whitelist(&self) -> Result<(), TrackerApiError> {
+        let url = self.url("whitelist/reload")?;
+
+        self.send(&url, self.request(Method::GET, &url)?).await?;
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use wiremock::matchers::{method, path, query_param};
+    use wiremock::{Mock, MockServer, ResponseTemplate};
+
+    use super::*;
+    use crate::shared::{ApiToken, ServiceEndpoint};
+
+    const INFO_HASH: &str = "9c38422213e30bff212b30c360d26f9a02136422";
+
+    fn client(server: &MockServer) -> TrackerApiClient {
+        TrackerApiClient::new(
+            ServiceEndpoint::http(*server.address(), "/api/v1/").unwrap(),
+            ApiToken::from("MyAccessToken".to_string()),
+        )
+    }
+
+    async fn mock_ok(server: &MockServer, http_method: &str, url_path: String) {
+        Mock::given(method(http_method))
+            .and(path(url_path))
+            .and(query_param("token", "MyAccessToken"))
+            .respond_with(ResponseTemplate::new(200).set_body_string(r#"{"status":"ok"}"#))
+            .expect(1)
+            .mount(server)
+            .await;
+    }
+
+    #[tokio::test]
+    async fn it_should_add_a_torrent_to_the_whitelist() {
+        let server = MockServer::start().await;
+        mock_ok(&server, "POST", format!("/api/v1/whitelist/{INFO_HASH}")).await;
+
+        client(&server)
+            .whitelist_add(&InfoHash::new(INFO_HASH).unwrap())
+            .await
+            .unwrap();
+    }
+
+    #[tokio::test]
+    async fn it_should_remove_a_torrent_from_the_whitelist() {
+        let server = MockServer::start().await;
+        mock_ok(&server, "DELETE", format!("/api/v1/whitelist/{INFO_HASH}")).await;
+
+        client(&server)
+            .whitelist_remove(&InfoHash::new(INFO_HASH).unwrap())
+            .await
+            .unwrap();
+    }
+
+    #[tokio::test]
+    async fn it_should_reload_the_whitelist() {
+        let server = MockServer::start().await;
+        mock_ok(&server, "GET", "/api/v1/whitelist/reload".to_string()).await;
+
+        client(&server).reload_whitelist().await.unwrap();
+    }
+}
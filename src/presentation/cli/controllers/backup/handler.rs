@@ -0,0 +1,138 @@
+//! Backup Command Handler
+//!
+//! This module handles the backup command execution at the presentation layer,
+//! backing up the tracker database of an environment and displaying the backup.
+
+use std::cell::RefCell;
+use std::sync::Arc;
+
+use parking_lot::ReentrantMutex;
+
+use crate::application::command_handlers::backup::BackupCommandHandler;
+use crate::domain::environment::name::EnvironmentName;
+use crate::presentation::cli::input::cli::output_format::OutputFormat;
+use crate::presentation::cli::views::commands::backup::{BackupData, JsonView, TextView};
+use crate::presentation::cli::views::progress::ProgressReporter;
+use crate::presentation::cli::views::Render;
+use crate::presentation::cli::views::UserOutput;
+
+use super::errors::BackupSubcommandError;
+
+/// Steps in the backup workflow
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum BackupStep {
+    ValidateEnvironment,
+    BackupDatabase,
+}
+
+impl BackupStep {
+    /// All steps in execution order
+    const ALL: &'static [Self] = &[Self::ValidateEnvironment, Self::BackupDatabase];
+
+    /// Total number of steps
+    const fn count() -> usize {
+        Self::ALL.len()
+    }
+
+    /// User-facing description for the step
+    fn description(self) -> &'static str {
+        match self {
+            Self::ValidateEnvironment => "Validating environment",
+            Self::BackupDatabase => "Backing up the tracker database",
+        }
+    }
+}
+
+/// Presentation layer controller for backup command workflow
+pub struct BackupCommandController {
+    handler: BackupCommandHandler,
+    progress: ProgressReporter,
+}
+
+impl BackupCommandController {
+    /// Create a new `BackupCommandController` with dependencies
+    #[allow(clippy::needless_pass_by_value)] // Arc parameters are moved to constructor for ownership
+    pub fn new(
+        handler: BackupCommandHandler,
+        user_output: Arc<ReentrantMutex<RefCell<UserOutput>>>,
+    ) -> Self {
+        let progress = ProgressReporter::new(user_output, BackupStep::count());
+
+        Self { handler, progress }
+    }
+
+    /// Execute the backup command workflow
+    ///
+    /// # Arguments
+    ///
+    /// * `environment_name` - Name of the environment
+    /// * `resume` - Complete the most recent interrupted backup, if any
+    /// * `output_format` - Output format (Text or Json)
+    ///
+    /// # Errors
+    ///
+    /// Returns `BackupSubcommandError` if the name is invalid or the backup fails
+    pub fn execute(
+        &mut self,
+        environment_name: &str,
+        resume: bool,
+        output_format: OutputFormat,
+    ) -> Result<(), BackupSubcommandError> {
+        self.progress.set_output_format(output_format);
+        let result = self.execute_steps(environment_name, resume, output_format);
+        self.progress.finish(result)
+    }
+
+    /// Steps of [`Self::execute`], before the timing summary
+    fn execute_steps(
+        &mut self,
+        environment_name: &str,
+        resume: bool,
+        output_format: OutputFormat,
+    ) -> Result<(), BackupSubcommandError> {
+        let env_name = self.validate_environment_name(environment_name)?;
+
+        self.progress
+            .start_step(BackupStep::BackupDatabase.description())?;
+
+        let (backup, backup_file) = self.handler.execute(&env_name, resume).map_err(|source| {
+            BackupSubcommandError::BackupFailed {
+                name: environment_name.to_string(),
+                source: Box::new(source),
+            }
+        })?;
+
+        self.progress
+            .complete_step(Some(&format!("Backup {} saved", backup.id)))?;
+
+        let data = BackupData::new(environment_name, &backup, &backup_file);
+        let output = match output_format {
+            OutputFormat::Text => TextView::render(&data)?,
+            OutputFormat::Json => JsonView::render(&data)?,
+        };
+
+        self.progress.result(&output)?;
+
+        Ok(())
+    }
+
+    /// Validate the environment name format
+    fn validate_environment_name(
+        &mut self,
+        name: &str,
+    ) -> Result<EnvironmentName, BackupSubcommandError> {
+        self.progress
+            .start_step(BackupStep::ValidateEnvironment.description())?;
+
+        let env_name = EnvironmentName::new(name.to_string()).map_err(|source| {
+            BackupSubcommandError::InvalidEnvironmentName {
+                name: name.to_string(),
+                source,
+            }
+        })?;
+
+        self.progress.complete_step(None)?;
+
+        Ok(env_name)
+    }
+}
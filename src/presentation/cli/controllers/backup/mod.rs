@@ -0,0 +1,17 @@
+//! Backup Command Presentation Module
+//!
+//! This module implements the CLI presentation layer for the backup command,
+//! which backs up the tracker database of a released or running environment
+//! to `data/{env}/backups/`.
+//!
+//! ## Components
+//!
+//! - `errors` - Presentation layer error types with `.help()` methods
+//! - `handler` - Main command handler orchestrating the workflow
+
+pub mod errors;
+pub mod handler;
+pub use handler::BackupCommandController;
+
+// Re-export commonly used types for convenience
+pub use errors::BackupSubcommandError;
@@ -0,0 +1,111 @@
+//! Error types for the Clone Subcommand
+//!
+//! This module defines error types that can occur during CLI clone command execution.
+//! All errors follow the project's error handling principles by providing clear,
+//! contextual, and actionable error messages with `.help()` methods.
+
+use thiserror::Error;
+
+use crate::application::command_handlers::clone::CloneCommandHandlerError;
+use crate::domain::environment::name::EnvironmentNameError;
+use crate::presentation::cli::views::progress::ProgressReporterError;
+use crate::presentation::cli::views::ViewRenderError;
+
+/// Clone command specific errors
+#[derive(Debug, Error)]
+pub enum CloneSubcommandError {
+    // ===== Environment Validation Errors =====
+    /// Source or target environment name validation failed
+    #[error("Invalid environment name '{name}': {source}
+Tip: Environment names must be 1-63 characters, start with letter/digit, contain only letters/digits/hyphens")]
+    InvalidEnvironmentName {
+        name: String,
+        #[source]
+        source: EnvironmentNameError,
+    },
+
+    /// The environment could not be cloned
+    #[error(
+        "Failed to clone environment '{source_name}' as '{target_name}': {source}
+Tip: Check the source with 'show {source_name}' and use a target name not listed by 'list'"
+    )]
+    CloneFailed {
+        source_name: String,
+        target_name: String,
+        #[source]
+        source: CloneCommandHandlerError,
+    },
+
+    // ===== Internal Errors =====
+    /// Progress reporting failed
+    #[error(
+        "Failed to report progress: {source}
+Tip: This is a critical bug - please report it with full logs using --log-output file-and-stderr"
+    )]
+    ProgressReportingFailed {
+        #[source]
+        source: ProgressReporterError,
+    },
+
+    /// Output formatting failed (JSON serialization error).
+    #[error(
+        "Failed to format output: {reason}\nTip: This is a critical bug - please report it with full logs using --log-output file-and-stderr"
+    )]
+    OutputFormatting { reason: String },
+}
+
+// ============================================================================
+// ERROR CONVERSIONS
+// ============================================================================
+
+impl From<ProgressReporterError> for CloneSubcommandError {
+    fn from(source: ProgressReporterError) -> Self {
+        Self::ProgressReportingFailed { source }
+    }
+}
+
+impl From<ViewRenderError> for CloneSubcommandError {
+    fn from(e: ViewRenderError) -> Self {
+        Self::OutputFormatting {
+            reason: e.to_string(),
+        }
+    }
+}
+
+impl CloneSubcommandError {
+    /// Get detailed troubleshooting guidance for this error
+    #[must_use]
+    pub fn help(&self) -> &'static str {
+        match self {
+            Self::InvalidEnvironmentName { .. } => {
+                "Invalid Environment Name - Detailed Troubleshooting:
+
+1. Check name format requirements:
+   - Length: 1-63 characters
+   - Start: Must begin with a letter or digit
+   - Characters: Only lowercase letters, digits, and hyphens allowed
+
+2. Valid examples:
+   - clone production staging
+   - clone prod prod-copy --offset 1000
+
+For more information, see docs/user-guide/commands/clone.md"
+            }
+            Self::CloneFailed { source, .. } => source.help(),
+            Self::ProgressReportingFailed { .. } => {
+                "Progress Reporting Failed - This is an internal error:
+
+1. This indicates a bug in the application
+2. Please report this issue with:
+   - Full command output
+   - Log file contents (use --log-output file-and-stderr)
+   - Steps to reproduce
+
+Report issues at: https://github.com/torrust/torrust-tracker-deployer/issues"
+            }
+            Self::OutputFormatting { .. } => {
+                "Output Formatting Failed - Critical Internal Error:\n\nThis error should not occur during normal operation. It indicates a bug in the output formatting system.\n\nPlease report it with the command, output format (--output-format) and logs so we can fix it."
+            }
+        }
+    }
+}
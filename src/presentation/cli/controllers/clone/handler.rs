@@ -0,0 +1,260 @@
+//! Clone Command Handler
+//!
+//! This module handles the clone command execution at the presentation layer,
+//! creating a new environment from the configuration of an existing one.
+
+use std::cell::RefCell;
+use std::path::PathBuf;
+use std::sync::Arc;
+
+use parking_lot::ReentrantMutex;
+
+use crate::application::command_handlers::clone::CloneCommandHandler;
+use crate::domain::environment::{Created, Environment, EnvironmentName};
+use crate::presentation::cli::input::cli::output_format::OutputFormat;
+use crate::presentation::cli::views::commands::create::{
+    EnvironmentDetailsData, JsonView, TextView,
+};
+use crate::presentation::cli::views::progress::ProgressReporter;
+use crate::presentation::cli::views::Render;
+use crate::presentation::cli::views::UserOutput;
+
+use super::errors::CloneSubcommandError;
+
+/// Steps in the clone workflow
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum CloneStep {
+    ValidateNames,
+    CloneEnvironment,
+}
+
+impl CloneStep {
+    /// All steps in execution order
+    const ALL: &'static [Self] = &[Self::ValidateNames, Self::CloneEnvironment];
+
+    /// Total number of steps
+    const fn count() -> usize {
+        Self::ALL.len()
+    }
+
+    /// User-facing description for the step
+    fn description(self) -> &'static str {
+        match self {
+            Self::ValidateNames => "Validating environment names",
+            Self::CloneEnvironment => "Cloning environment",
+        }
+    }
+}
+
+/// Presentation layer controller for clone command workflow
+///
+/// ## Responsibilities
+///
+/// - Validate the source and target environment names
+/// - Delegate the cloning to the application layer
+/// - Display the details of the new environment
+pub struct CloneCommandController {
+    handler: CloneCommandHandler,
+    working_dir: PathBuf,
+    progress: ProgressReporter,
+}
+
+impl CloneCommandController {
+    /// Create a new `CloneCommandController` with dependencies
+    #[allow(clippy::needless_pass_by_value)] // Arc parameters are moved to constructor for ownership
+    pub fn new(
+        handler: CloneCommandHandler,
+        working_dir: PathBuf,
+        user_output: Arc<ReentrantMutex<RefCell<UserOutput>>>,
+    ) -> Self {
+        let progress = ProgressReporter::new(user_output, CloneStep::count());
+
+        Self {
+            handler,
+            working_dir,
+            progress,
+        }
+    }
+
+    /// Execute the clone command workflow
+    ///
+    /// # Arguments
+    ///
+    /// * `source` - Name of the environment to copy
+    /// * `target` - Name of the new environment
+    /// * `offset` - Offset added to every tracker port
+    /// * `output_format` - Output format (Text or Json)
+    ///
+    /// # Errors
+    ///
+    /// Returns `CloneSubcommandError` if a name is invalid or the environment
+    /// cannot be cloned
+    pub fn execute(
+        &mut self,
+        source: &str,
+        target: &str,
+        offset: u16,
+        output_format: OutputFormat,
+    ) -> Result<(), CloneSubcommandError> {
+        self.progress.set_output_format(output_format);
+        let result = self.execute_steps(source, target, offset, output_format);
+        self.progress.finish(result)
+    }
+
+    /// Steps of [`Self::execute`], before the timing summary
+    fn execute_steps(
+        &mut self,
+        source: &str,
+        target: &str,
+        offset: u16,
+        output_format: OutputFormat,
+    ) -> Result<(), CloneSubcommandError> {
+        let (source_name, target_name) = self.validate_names(source, target)?;
+
+        let environment = self.clone_environment(&source_name, &target_name, offset)?;
+
+        self.display_results(&environment, output_format)
+    }
+
+    /// Step 1: Validate the source and target environment names
+    fn validate_names(
+        &mut self,
+        source: &str,
+        target: &str,
+    ) -> Result<(EnvironmentName, EnvironmentName), CloneSubcommandError> {
+        self.progress
+            .start_step(CloneStep::ValidateNames.description())?;
+
+        let parse = |name: &str| {
+            EnvironmentName::new(name.to_string()).map_err(|source| {
+                CloneSubcommandError::InvalidEnvironmentName {
+                    name: name.to_string(),
+                    source,
+                }
+            })
+        };
+        let names = (parse(source)?, parse(target)?);
+
+        self.progress.complete_step(None)?;
+
+        Ok(names)
+    }
+
+    /// Step 2: Clone the environment via application layer
+    fn clone_environment(
+        &mut self,
+        source: &EnvironmentName,
+        target: &EnvironmentName,
+        offset: u16,
+    ) -> Result<Environment<Created>, CloneSubcommandError> {
+        self.progress
+            .start_step(CloneStep::CloneEnvironment.description())?;
+
+        let environment = self
+            .handler
+            .execute(source, target, offset, &self.working_dir)
+            .map_err(|e| CloneSubcommandError::CloneFailed {
+                source_name: source.to_string(),
+                target_name: target.to_string(),
+                source: e,
+            })?;
+
+        self.progress.complete_step(Some(&format!(
+            "Cloned '{source}' with a port offset of {offset}"
+        )))?;
+
+        Ok(environment)
+    }
+
+    /// Display the details of the new environment
+    fn display_results(
+        &mut self,
+        environment: &Environment<Created>,
+        output_format: OutputFormat,
+    ) -> Result<(), CloneSubcommandError> {
+        self.progress.complete(&format!(
+            "Environment '{}' created successfully",
+            environment.name().as_str()
+        ))?;
+
+        self.progress.blank_line()?;
+
+        let details = EnvironmentDetailsData::from(environment);
+
+        let output = match output_format {
+            OutputFormat::Text => TextView::render(&details)?,
+            OutputFormat::Json => JsonView::render(&details)?,
+        };
+
+        self.progress.result(&output)?;
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::Utc;
+    use tempfile::TempDir;
+
+    use super::*;
+    use crate::application::command_handlers::clone::CloneCommandHandlerError;
+    use crate::domain::environment::name::EnvironmentNameError;
+    use crate::domain::environment::testing::EnvironmentTestBuilder;
+    use crate::infrastructure::persistence::file_repository_factory::FileRepositoryFactory;
+    use crate::presentation::cli::controllers::constants::DEFAULT_LOCK_TIMEOUT;
+    use crate::presentation::cli::views::testing::TestUserOutput;
+    use crate::presentation::cli::views::VerbosityLevel;
+    use crate::testing::MockClock;
+
+    /// Controller whose repository already holds the `prod` environment
+    fn controller_with_source(temp_dir: &TempDir) -> CloneCommandController {
+        let repository =
+            FileRepositoryFactory::new(DEFAULT_LOCK_TIMEOUT).create(temp_dir.path().join("data"));
+        let (source, _data_dir, _build_dir, _source_temp_dir) = EnvironmentTestBuilder::new()
+            .with_name("prod")
+            .build_with_custom_paths();
+        repository.save(&source.into_any()).unwrap();
+
+        let (user_output, _, _) =
+            TestUserOutput::new(VerbosityLevel::Normal).into_reentrant_wrapped();
+        let handler = CloneCommandHandler::new(repository, Arc::new(MockClock::new(Utc::now())));
+
+        CloneCommandController::new(handler, temp_dir.path().to_path_buf(), user_output)
+    }
+
+    #[test]
+    fn it_should_reject_a_reserved_target_name() {
+        let temp_dir = TempDir::new().unwrap();
+        let mut controller = controller_with_source(&temp_dir);
+
+        let result = controller.execute("prod", "traces", 0, OutputFormat::Text);
+
+        match result {
+            Err(CloneSubcommandError::InvalidEnvironmentName {
+                name,
+                source: EnvironmentNameError::Reserved { .. },
+            }) => assert_eq!(name, "traces"),
+            other => panic!("Expected a reserved environment name error, got: {other:?}"),
+        }
+    }
+
+    #[test]
+    fn it_should_reject_a_target_name_too_long_for_the_provider_instance_names() {
+        let temp_dir = TempDir::new().unwrap();
+        let mut controller = controller_with_source(&temp_dir);
+        let target = format!("env-{}", "a".repeat(56));
+
+        let result = controller.execute("prod", &target, 0, OutputFormat::Text);
+
+        match result {
+            Err(CloneSubcommandError::CloneFailed {
+                source: source @ CloneCommandHandlerError::InvalidTargetName(_),
+                ..
+            }) => assert!(source
+                .to_string()
+                .contains("is 60 characters long, but the lxd provider allows at most 44")),
+            other => panic!("Expected an invalid target name error, got: {other:?}"),
+        }
+    }
+}
@@ -0,0 +1,17 @@
+//! Clone Command Presentation Module
+//!
+//! This module implements the CLI presentation layer for the clone command,
+//! which creates a new environment from the configuration of an existing one,
+//! optionally shifting the tracker ports with `--offset`.
+//!
+//! ## Components
+//!
+//! - `errors` - Presentation layer error types with `.help()` methods
+//! - `handler` - Main command handler orchestrating the workflow
+
+pub mod errors;
+pub mod handler;
+pub use handler::CloneCommandController;
+
+// Re-export commonly used types for convenience
+pub use errors::CloneSubcommandError;
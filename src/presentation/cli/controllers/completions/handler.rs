@@ -0,0 +1,163 @@
+//! Completions Command Handler
+//!
+//! This module prints shell completion scripts and the environment names the
+//! scripts complete.
+
+use std::cell::RefCell;
+use std::sync::Arc;
+
+use parking_lot::ReentrantMutex;
+
+use crate::domain::environment::repository::EnvironmentRepository;
+use crate::infrastructure::shell_completion::{CompletionShell, ShellCompletionGenerator};
+use crate::presentation::cli::input::cli::Cli;
+use crate::presentation::cli::views::UserOutput;
+
+/// Presentation layer controller for shell completion
+///
+/// ## Architecture
+///
+/// Like the docs controller, this controller uses infrastructure services
+/// directly: completion scripts are a presentation concern with no use case
+/// behind them. Only the environment names are listed, without loading any
+/// environment state, so completion stays fast.
+pub struct CompletionsCommandController {
+    repository: Arc<dyn EnvironmentRepository + Send + Sync>,
+    user_output: Arc<ReentrantMutex<RefCell<UserOutput>>>,
+}
+
+impl CompletionsCommandController {
+    /// Create a new `CompletionsCommandController`
+    ///
+    /// # Arguments
+    ///
+    /// * `repository` - Repository listing the environment names
+    /// * `user_output` - Shared output service for result display
+    pub fn new(
+        repository: Arc<dyn EnvironmentRepository + Send + Sync>,
+        user_output: Arc<ReentrantMutex<RefCell<UserOutput>>>,
+    ) -> Self {
+        Self {
+            repository,
+            user_output,
+        }
+    }
+
+    /// Print the completion script for `shell` to stdout
+    pub fn execute(&self, shell: CompletionShell) {
+        let script = ShellCompletionGenerator::generate::<Cli>(shell);
+
+        self.user_output
+            .lock()
+            .borrow_mut()
+            .result(script.trim_end());
+    }
+
+    /// Print the names of the existing environments to stdout, one per line
+    ///
+    /// Nothing is printed when the names cannot be listed: a completion
+    /// script has no way to show an error.
+    pub fn list_environment_names(&self) {
+        let names: Vec<String> = self
+            .repository
+            .list()
+            .unwrap_or_default()
+            .iter()
+            .map(|name| name.as_str().to_string())
+            .collect();
+
+        if !names.is_empty() {
+            self.user_output
+                .lock()
+                .borrow_mut()
+                .result(&names.join("\n"));
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::path::PathBuf;
+
+    use tempfile::TempDir;
+
+    use super::*;
+    use crate::infrastructure::persistence::filesystem::file_environment_repository::FileEnvironmentRepository;
+    use crate::presentation::cli::views::testing::test_user_output::TestUserOutput;
+    use crate::presentation::cli::views::VerbosityLevel;
+
+    fn controller(
+        data_directory: PathBuf,
+    ) -> (
+        CompletionsCommandController,
+        Arc<parking_lot::Mutex<Vec<u8>>>,
+    ) {
+        let (user_output, capture, _capture_stderr) =
+            TestUserOutput::new(VerbosityLevel::Normal).into_reentrant_wrapped();
+
+        (
+            CompletionsCommandController::new(
+                Arc::new(FileEnvironmentRepository::new(data_directory)),
+                user_output,
+            ),
+            capture,
+        )
+    }
+
+    #[test]
+    fn it_should_print_a_script_completing_environment_names_for_show() {
+        let temp_dir = TempDir::new().unwrap();
+        let (controller, capture) = controller(temp_dir.path().to_path_buf());
+
+        controller.execute(CompletionShell::Bash);
+
+        let script = String::from_utf8(capture.lock().clone()).unwrap();
+        assert!(script.contains("complete -F __torrust_tracker_deployer_complete_environments"));
+        assert!(script.contains("__complete-env-names"));
+        assert!(script
+            .lines()
+            .any(|line| line.trim_start().starts_with("destroy|") && line.contains("|show|")));
+    }
+
+    #[test]
+    fn it_should_only_mention_the_hidden_subcommand_where_the_hook_calls_it() {
+        let temp_dir = TempDir::new().unwrap();
+        let (controller, capture) = controller(temp_dir.path().to_path_buf());
+
+        controller.execute(CompletionShell::Bash);
+
+        let script = String::from_utf8(capture.lock().clone()).unwrap();
+        let mentions: Vec<&str> = script
+            .lines()
+            .filter(|line| line.contains("__complete-env-names"))
+            .collect();
+        assert_eq!(mentions.len(), 1, "{mentions:#?}");
+        assert!(mentions[0].contains("\"${COMP_WORDS[0]}\" __complete-env-names"));
+    }
+
+    #[test]
+    fn it_should_print_one_environment_name_per_line() {
+        let temp_dir = TempDir::new().unwrap();
+        for name in ["staging", "production"] {
+            let env_dir = temp_dir.path().join(name);
+            std::fs::create_dir_all(&env_dir).unwrap();
+            std::fs::write(env_dir.join("environment.json"), "{}").unwrap();
+        }
+        let (controller, capture) = controller(temp_dir.path().to_path_buf());
+
+        controller.list_environment_names();
+
+        let output = String::from_utf8(capture.lock().clone()).unwrap();
+        assert_eq!(output, "production\nstaging\n");
+    }
+
+    #[test]
+    fn it_should_print_nothing_when_there_are_no_environments() {
+        let temp_dir = TempDir::new().unwrap();
+        let (controller, capture) = controller(temp_dir.path().join("data"));
+
+        controller.list_environment_names();
+
+        assert!(capture.lock().is_empty());
+    }
+}
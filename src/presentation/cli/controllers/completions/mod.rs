@@ -0,0 +1,15 @@
+//! Completions Command Controller (Presentation Layer)
+//!
+//! This module handles the `completions` command, which prints a shell
+//! completion script, and the hidden `__complete-env-names` callback those
+//! scripts run to complete environment names.
+//!
+//! ## Output Contract
+//!
+//! - `completions <shell>`: the completion script on stdout, nothing else
+//! - `__complete-env-names`: one environment name per line on stdout, and no
+//!   output at all when there are none (it runs on every `<TAB>`)
+
+pub mod handler;
+
+pub use handler::CompletionsCommandController;
@@ -0,0 +1,42 @@
+//! Unified Config Command Errors
+//!
+//! This module defines the error type of every config subcommand.
+
+use thiserror::Error;
+
+use crate::presentation::cli::controllers::create::CreateSchemaCommandError;
+
+/// Unified error type for all config subcommands
+#[derive(Debug, Error)]
+pub enum ConfigCommandError {
+    /// Schema generation errors
+    #[error(transparent)]
+    Schema(#[from] CreateSchemaCommandError),
+}
+
+impl ConfigCommandError {
+    /// Get detailed troubleshooting guidance for this error
+    ///
+    /// Delegates to the help of the failed subcommand.
+    #[must_use]
+    pub fn help(&self) -> String {
+        match self {
+            Self::Schema(err) => err.help(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn it_should_delegate_help_to_the_schema_error() {
+        let error = ConfigCommandError::Schema(CreateSchemaCommandError::UserOutputLockFailed);
+
+        assert_eq!(
+            error.help(),
+            CreateSchemaCommandError::UserOutputLockFailed.help()
+        );
+    }
+}
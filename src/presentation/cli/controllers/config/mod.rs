@@ -0,0 +1,16 @@
+//! Config Command Controller (Presentation Layer)
+//!
+//! This module handles the `config` command, which groups utilities for
+//! writing environment configuration files by hand.
+//!
+//! ## Subcommands
+//!
+//! - `config schema` - Prints the JSON Schema of environment configuration
+//!   files to stdout. It reuses the `create schema` controller.
+
+pub mod errors;
+pub mod router;
+
+// Re-export commonly used types for convenience
+pub use errors::ConfigCommandError;
+pub use router::route_command;
@@ -0,0 +1,31 @@
+//! Config Command Router
+//!
+//! This module routes the config command to its subcommands.
+
+use crate::presentation::cli::dispatch::ExecutionContext;
+use crate::presentation::cli::input::cli::commands::ConfigAction;
+
+use super::errors::ConfigCommandError;
+
+/// Route the config command to its appropriate subcommand
+///
+/// # Arguments
+///
+/// * `action` - The config action to perform
+/// * `context` - Execution context providing access to application services
+///
+/// # Errors
+///
+/// Returns an error if the subcommand execution fails.
+pub fn route_command(
+    action: &ConfigAction,
+    context: &ExecutionContext,
+) -> Result<(), ConfigCommandError> {
+    match action {
+        ConfigAction::Schema => context
+            .container()
+            .create_schema_controller()
+            .execute(None)
+            .map_err(ConfigCommandError::Schema),
+    }
+}
@@ -77,6 +77,29 @@ Tip: Check logs and try running with --log-output file-and-stderr for more detai
         source: Box<ConfigureCommandHandlerError>,
     },
 
+    // ===== Bulk Configure Errors =====
+    /// The environments matching `--label` could not be listed
+    #[error(
+        "Failed to select the environments with the labels '{labels}': {reason}
+Tip: Check the selection with 'list --label <key=value> --state provisioned'"
+    )]
+    EnvironmentSelectionFailed { labels: String, reason: String },
+
+    /// Some of the environments selected with `--label` were not configured
+    #[error(
+        "Failed to configure {failed} of {total} environments
+Tip: The report above lists the error of each environment"
+    )]
+    BulkConfigureFailed { failed: usize, total: usize },
+
+    // ===== Out-of-Band Errors =====
+    /// The host given with `--target-host` could not be resolved
+    #[error(
+        "Failed to resolve the target host '{host}': {reason}
+Tip: Pass an IP address or a hostname that resolves from this machine"
+    )]
+    TargetHostResolutionFailed { host: String, reason: String },
+
     // ===== Internal Errors =====
     /// Progress reporting failed
     ///
@@ -321,6 +344,46 @@ For persistent issues, check system logs and file system health."
 For more information, see the configuration troubleshooting guide."
             }
 
+            Self::EnvironmentSelectionFailed { .. } => {
+                "Environment Selection Failed - Detailed Troubleshooting:
+
+1. Check that the data directory exists and is readable:
+   ls -la data/
+
+2. Preview the selection:
+   torrust-tracker-deployer list --label purpose=nightly --state provisioned
+
+For more information, see docs/user-guide/commands/configure.md"
+            }
+
+            Self::BulkConfigureFailed { .. } => {
+                "Bulk Configure Failed - Detailed Troubleshooting:
+
+The other environments were configured: only the ones reported with an
+error failed. Their state is 'configure_failed'.
+
+1. Review the failure of an environment:
+   torrust-tracker-deployer show <env-name>
+
+2. Fix the cause and configure a failed environment on its own:
+   torrust-tracker-deployer configure <env-name>
+
+For more information, see docs/user-guide/commands/configure.md"
+            }
+
+            Self::TargetHostResolutionFailed { .. } => {
+                "Target Host Resolution Failed - Detailed Troubleshooting:
+
+1. Check the host name:
+   getent hosts <target-host>
+
+2. Pass the IP address instead, with the SSH port of the target:
+   torrust-tracker-deployer configure <env-name> --experimental \\
+     --target-host 127.0.0.1 --ssh-port 2222
+
+For more information, see docs/user-guide/commands/configure.md"
+            }
+
             Self::ProgressReportingFailed { .. } => {
                 "Progress Reporting Failed - Critical Internal Error:
 
@@ -4,27 +4,47 @@
 //! including environment validation, repository initialization, and user interaction.
 
 use std::cell::RefCell;
+use std::net::{SocketAddr, ToSocketAddrs};
+use std::num::NonZeroUsize;
+use std::path::PathBuf;
 use std::sync::Arc;
 
 use parking_lot::ReentrantMutex;
-
-use crate::application::command_handlers::ConfigureCommandHandler;
+use tokio_util::sync::CancellationToken;
+use torrust_tracker_deployer_dependency_installer::Dependency;
+
+use crate::adapters::ansible::AnsiblePlaybookOptions;
+use crate::application::command_handlers::common::{CheckReport, MultiEnvironmentRunner};
+use crate::application::command_handlers::list::ListFilter;
+use crate::application::command_handlers::{ConfigureCommandHandler, ListCommandHandler};
+use crate::application::services::deployment_report::DeploymentReportListener;
 use crate::domain::environment::name::EnvironmentName;
 use crate::domain::environment::repository::EnvironmentRepository;
 use crate::domain::environment::state::Configured;
-use crate::domain::environment::Environment;
+use crate::domain::environment::{Environment, Label};
 use crate::presentation::cli::input::cli::OutputFormat;
 use crate::presentation::cli::views::commands::configure::{
     ConfigureDetailsData, JsonView, TextView,
 };
-use crate::presentation::cli::views::progress::ProgressReporter;
-use crate::presentation::cli::views::progress::VerboseProgressListener;
+use crate::presentation::cli::views::commands::shared::check_report::{
+    CheckReportData, CheckReportJsonView, CheckReportTextView,
+};
+use crate::presentation::cli::views::commands::shared::multi_environment_report::{
+    MultiEnvironmentReportData, MultiEnvironmentReportJsonView, MultiEnvironmentReportTextView,
+};
+use crate::presentation::cli::views::progress::{
+    MultiEnvironmentProgressListener, ProgressReporter, VerboseProgressListener,
+};
 use crate::presentation::cli::views::Render;
 use crate::presentation::cli::views::UserOutput;
 use crate::shared::clock::Clock;
+use crate::shared::command::run_log::DEFAULT_KEPT_RUNS;
 
 use super::errors::ConfigureSubcommandError;
 
+/// SSH port of an out-of-band target host when `--ssh-port` is not given
+const DEFAULT_SSH_PORT: u16 = 22;
+
 /// Steps in the configure workflow
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 enum ConfigureStep {
@@ -77,6 +97,13 @@ pub struct ConfigureCommandController {
     repository: Arc<dyn EnvironmentRepository + Send + Sync>,
     clock: Arc<dyn Clock>,
     progress: ProgressReporter,
+    cancellation: CancellationToken,
+    ansible_options: AnsiblePlaybookOptions,
+    list_handler: Option<ListCommandHandler>,
+    runner: MultiEnvironmentRunner,
+    kept_run_logs: usize,
+    trace_dir: Option<PathBuf>,
+    config_override: Option<PathBuf>,
 }
 
 impl ConfigureCommandController {
@@ -96,9 +123,65 @@ impl ConfigureCommandController {
             repository,
             clock,
             progress,
+            cancellation: CancellationToken::new(),
+            ansible_options: AnsiblePlaybookOptions::default(),
+            list_handler: None,
+            runner: MultiEnvironmentRunner::default(),
+            kept_run_logs: DEFAULT_KEPT_RUNS,
+            trace_dir: None,
+            config_override: None,
         }
     }
 
+    /// Keep the Ansible output logs of the last `runs` runs of the command
+    #[must_use]
+    pub fn with_kept_run_logs(mut self, runs: usize) -> Self {
+        self.kept_run_logs = runs;
+        self
+    }
+
+    /// Write the trace files of a failure to `trace_dir` (`--trace-dir`)
+    #[must_use]
+    pub fn with_trace_dir(mut self, trace_dir: Option<PathBuf>) -> Self {
+        self.trace_dir = trace_dir;
+        self
+    }
+
+    /// Cancel the workflow when the given token is cancelled (e.g. on Ctrl-C)
+    #[must_use]
+    pub fn with_cancellation(mut self, cancellation: CancellationToken) -> Self {
+        self.cancellation = cancellation;
+        self
+    }
+
+    /// Pass `--tags`/`--skip-tags` through to `ansible-playbook`
+    #[must_use]
+    pub fn with_ansible_options(mut self, options: AnsiblePlaybookOptions) -> Self {
+        self.ansible_options = options;
+        self
+    }
+
+    /// Merge the override file at `path` onto the configuration of the environment
+    #[must_use]
+    pub fn with_config_override(mut self, path: Option<PathBuf>) -> Self {
+        self.config_override = path;
+        self
+    }
+
+    /// Configure up to `parallelism` of the environments selected with `--label` at once
+    #[must_use]
+    pub fn with_parallelism(mut self, parallelism: NonZeroUsize) -> Self {
+        self.runner = MultiEnvironmentRunner::new(parallelism);
+        self
+    }
+
+    /// Select the environments of `configure --label` with this handler
+    #[must_use]
+    pub fn with_list_handler(mut self, list_handler: ListCommandHandler) -> Self {
+        self.list_handler = Some(list_handler);
+        self
+    }
+
     /// Execute the complete configure workflow
     ///
     /// Orchestrates all steps of the configure command:
@@ -131,6 +214,18 @@ impl ConfigureCommandController {
         &mut self,
         environment_name: &str,
         output_format: OutputFormat,
+    ) -> Result<Environment<Configured>, ConfigureSubcommandError> {
+        self.progress.set_output_format(output_format);
+        let result = self.execute_steps(environment_name, output_format);
+        self.progress.finish(result)
+    }
+
+    /// Steps of [`Self::execute`], before the timing summary
+    #[allow(clippy::result_large_err)]
+    fn execute_steps(
+        &mut self,
+        environment_name: &str,
+        output_format: OutputFormat,
     ) -> Result<Environment<Configured>, ConfigureSubcommandError> {
         let env_name = self.validate_environment_name(environment_name)?;
 
@@ -145,6 +240,330 @@ impl ConfigureCommandController {
         Ok(configured)
     }
 
+    /// Execute the configure workflow in check (dry-run) mode
+    ///
+    /// Runs the configuration playbooks with `ansible-playbook --check` and
+    /// displays what would change. The environment state is not changed.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the environment name is invalid, the environment
+    /// is not in "Provisioned" state, or a playbook fails in check mode.
+    #[allow(clippy::result_large_err)]
+    pub fn check(
+        &mut self,
+        environment_name: &str,
+        output_format: OutputFormat,
+    ) -> Result<CheckReport, ConfigureSubcommandError> {
+        self.progress.set_output_format(output_format);
+        let result = self.check_steps(environment_name, output_format);
+        self.progress.finish(result)
+    }
+
+    /// Steps of [`Self::check`], before the timing summary
+    #[allow(clippy::result_large_err)]
+    fn check_steps(
+        &mut self,
+        environment_name: &str,
+        output_format: OutputFormat,
+    ) -> Result<CheckReport, ConfigureSubcommandError> {
+        let env_name = self.validate_environment_name(environment_name)?;
+
+        let handler = self.create_command_handler()?;
+
+        self.progress
+            .start_step(ConfigureStep::ConfigureInfrastructure.description())?;
+
+        let listener = VerboseProgressListener::new(self.progress.output().clone());
+
+        let report = handler
+            .check(&env_name, Some(&listener))
+            .map_err(
+                |source| ConfigureSubcommandError::ConfigureOperationFailed {
+                    name: env_name.to_string(),
+                    source: Box::new(source),
+                },
+            )?;
+
+        self.progress
+            .complete_step(Some("Check completed, no changes applied"))?;
+
+        self.progress.blank_line()?;
+        let data = CheckReportData::new(environment_name, "configure", &report);
+        let output = match output_format {
+            OutputFormat::Text => CheckReportTextView::render(&data)?,
+            OutputFormat::Json => CheckReportJsonView::render(&data)?,
+        };
+        self.progress.result(&output)?;
+
+        Ok(report)
+    }
+
+    /// Run the configuration playbooks against another host (`--target-host`)
+    ///
+    /// Experimental developer mode: the playbooks of the environment run
+    /// against `target_host` on `ssh_port` (22 by default) with the SSH
+    /// credentials of the environment. The environment state is not changed;
+    /// the deployment report records the target host.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the environment name is invalid, the target host
+    /// cannot be resolved, or a playbook fails.
+    #[allow(clippy::result_large_err)]
+    pub fn execute_out_of_band(
+        &mut self,
+        environment_name: &str,
+        target_host: &str,
+        ssh_port: Option<u16>,
+        output_format: OutputFormat,
+    ) -> Result<SocketAddr, ConfigureSubcommandError> {
+        self.progress.set_output_format(output_format);
+        let result = self.execute_out_of_band_steps(
+            environment_name,
+            target_host,
+            ssh_port.unwrap_or(DEFAULT_SSH_PORT),
+        );
+        self.progress.finish(result)
+    }
+
+    /// Steps of [`Self::execute_out_of_band`], before the timing summary
+    #[allow(clippy::result_large_err)]
+    fn execute_out_of_band_steps(
+        &mut self,
+        environment_name: &str,
+        target_host: &str,
+        ssh_port: u16,
+    ) -> Result<SocketAddr, ConfigureSubcommandError> {
+        let env_name = self.validate_environment_name(environment_name)?;
+        let target = resolve_target_host(target_host, ssh_port)?;
+
+        let handler = self.create_command_handler()?;
+
+        self.progress
+            .start_step(ConfigureStep::ConfigureInfrastructure.description())?;
+
+        let listener = VerboseProgressListener::new(self.progress.output().clone());
+        let recorder = DeploymentReportListener::new(
+            "configure",
+            &env_name,
+            self.clock.clone(),
+            Some(&listener),
+        )
+        .with_target_host(target.to_string());
+
+        let result = handler.execute_out_of_band(&env_name, target, Some(&recorder));
+        let report = recorder.write(
+            self.repository.as_ref(),
+            &[Dependency::Ansible],
+            result.as_ref().err(),
+        );
+        if let Some(trace_file) = report.and_then(|report| report.trace_file) {
+            self.progress
+                .warn(&format!("Trace file: {}", trace_file.display()))?;
+        }
+        result.map_err(
+            |source| ConfigureSubcommandError::ConfigureOperationFailed {
+                name: env_name.to_string(),
+                source: Box::new(source),
+            },
+        )?;
+
+        self.progress.complete_step(Some(&format!(
+            "Target host {target} configured, environment state unchanged"
+        )))?;
+        self.progress.complete(&format!(
+            "Out-of-band configuration of '{environment_name}' completed"
+        ))?;
+
+        Ok(target)
+    }
+
+    /// Run only the configuration playbook tasks selected by `--tags`/`--skip-tags`
+    ///
+    /// A tag-filtered run does not apply the whole configuration, so the
+    /// environment stays `Provisioned`; run configure without tags to make it
+    /// `Configured`. The deployment report records the run.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the environment name is invalid, the environment
+    /// is not in "Provisioned" state, or a playbook fails.
+    #[allow(clippy::result_large_err)]
+    pub fn execute_tagged(
+        &mut self,
+        environment_name: &str,
+        output_format: OutputFormat,
+    ) -> Result<(), ConfigureSubcommandError> {
+        self.progress.set_output_format(output_format);
+        let result = self.execute_tagged_steps(environment_name);
+        self.progress.finish(result)
+    }
+
+    /// Steps of [`Self::execute_tagged`], before the timing summary
+    #[allow(clippy::result_large_err)]
+    fn execute_tagged_steps(
+        &mut self,
+        environment_name: &str,
+    ) -> Result<(), ConfigureSubcommandError> {
+        let env_name = self.validate_environment_name(environment_name)?;
+
+        let handler = self.create_command_handler()?;
+
+        self.progress
+            .start_step(ConfigureStep::ConfigureInfrastructure.description())?;
+
+        let listener = VerboseProgressListener::new(self.progress.output().clone());
+        let recorder = DeploymentReportListener::new(
+            "configure",
+            &env_name,
+            self.clock.clone(),
+            Some(&listener),
+        );
+
+        let result = handler.execute_tagged(&env_name, Some(&recorder));
+        let report = recorder.write(
+            self.repository.as_ref(),
+            &[Dependency::Ansible],
+            result.as_ref().err(),
+        );
+        if let Some(trace_file) = report.and_then(|report| report.trace_file) {
+            self.progress
+                .warn(&format!("Trace file: {}", trace_file.display()))?;
+        }
+        result.map_err(
+            |source| ConfigureSubcommandError::ConfigureOperationFailed {
+                name: env_name.to_string(),
+                source: Box::new(source),
+            },
+        )?;
+
+        self.progress.complete_step(Some(
+            "Selected tasks configured, environment state unchanged",
+        ))?;
+        self.progress.complete(&format!(
+            "Tag-filtered configuration of '{environment_name}' completed"
+        ))?;
+
+        Ok(())
+    }
+
+    /// Configure every provisioned environment with the given labels (`--label`)
+    ///
+    /// Up to the configured parallelism (`--parallel`) environments are
+    /// configured at the same time. The progress of each one is prefixed
+    /// with its name. A failure does not stop the other environments: the
+    /// results are reported in a summary table once all were attempted.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the environments cannot be listed, or any
+    /// selected environment fails to be configured.
+    #[allow(clippy::result_large_err)]
+    pub fn execute_by_labels(
+        &mut self,
+        labels: &[Label],
+        output_format: OutputFormat,
+    ) -> Result<MultiEnvironmentReportData, ConfigureSubcommandError> {
+        self.progress.set_output_format(output_format);
+        let result = self.execute_by_labels_steps(labels, output_format);
+        self.progress.finish(result)
+    }
+
+    /// Steps of [`Self::execute_by_labels`], before the timing summary
+    #[allow(clippy::result_large_err)]
+    fn execute_by_labels_steps(
+        &mut self,
+        labels: &[Label],
+        output_format: OutputFormat,
+    ) -> Result<MultiEnvironmentReportData, ConfigureSubcommandError> {
+        let names = self.select_environments(labels)?;
+
+        let output = self.progress.output().clone();
+        let listener = MultiEnvironmentProgressListener::new(output.clone(), "configure");
+
+        // Each worker builds its own handler: handlers are not shared between threads
+        let report = self.runner.run(&names, Some(&listener), |env_name| {
+            let handler = ConfigureCommandHandler::new(self.clock.clone(), self.repository.clone())
+                .with_kept_run_logs(self.kept_run_logs)
+                .with_trace_dir(self.trace_dir.clone())
+                .with_cancellation(self.cancellation.clone())
+                .with_ansible_options(self.ansible_options.clone());
+            let verbose = VerboseProgressListener::new(output.clone())
+                .with_environment_prefix(env_name.as_str());
+            let recorder = DeploymentReportListener::new(
+                "configure",
+                env_name,
+                self.clock.clone(),
+                Some(&verbose),
+            );
+
+            let result = handler.execute(env_name, Some(&recorder));
+            recorder.write(
+                self.repository.as_ref(),
+                &[Dependency::Ansible],
+                result.as_ref().err(),
+            );
+            result
+        });
+
+        let data = MultiEnvironmentReportData::new(
+            "configure",
+            labels,
+            self.runner.parallelism().get(),
+            &report,
+        );
+        let output = match output_format {
+            OutputFormat::Text => MultiEnvironmentReportTextView::render(&data)?,
+            OutputFormat::Json => MultiEnvironmentReportJsonView::render(&data)?,
+        };
+        self.progress.result(&output)?;
+
+        match data.failed_count() {
+            0 => Ok(data),
+            failed => Err(ConfigureSubcommandError::BulkConfigureFailed {
+                failed,
+                total: data.results.len(),
+            }),
+        }
+    }
+
+    /// List the environments with all the given labels that are in the provisioned state
+    #[allow(clippy::result_large_err)]
+    fn select_environments(
+        &self,
+        labels: &[Label],
+    ) -> Result<Vec<EnvironmentName>, ConfigureSubcommandError> {
+        let selection_failed =
+            |reason: String| ConfigureSubcommandError::EnvironmentSelectionFailed {
+                labels: labels
+                    .iter()
+                    .map(ToString::to_string)
+                    .collect::<Vec<_>>()
+                    .join(", "),
+                reason,
+            };
+
+        let list_handler = self
+            .list_handler
+            .as_ref()
+            .ok_or_else(|| selection_failed("environment listing is not available".to_string()))?;
+
+        let filter = ListFilter::new()
+            .with_labels(labels.iter().cloned())
+            .with_states(["provisioned"]);
+        let list = list_handler
+            .execute(&filter)
+            .map_err(|e| selection_failed(e.to_string()))?;
+
+        list.environments
+            .into_iter()
+            .map(|summary| {
+                EnvironmentName::new(summary.name).map_err(|e| selection_failed(e.to_string()))
+            })
+            .collect()
+    }
+
     /// Validate the environment name format
     ///
     /// Shows progress to user and validates that the environment name
@@ -181,7 +600,15 @@ impl ConfigureCommandController {
         self.progress
             .start_step(ConfigureStep::CreateCommandHandler.description())?;
 
-        let handler = ConfigureCommandHandler::new(self.clock.clone(), self.repository.clone());
+        let handler = ConfigureCommandHandler::new(self.clock.clone(), self.repository.clone())
+            .with_kept_run_logs(self.kept_run_logs)
+            .with_trace_dir(self.trace_dir.clone())
+            .with_cancellation(self.cancellation.clone())
+            .with_ansible_options(self.ansible_options.clone());
+        let handler = match &self.config_override {
+            Some(path) => handler.with_config_override(path.clone()),
+            None => handler,
+        };
         self.progress.complete_step(None)?;
 
         Ok(handler)
@@ -210,10 +637,26 @@ impl ConfigureCommandController {
         // The VerboseProgressListener translates step events into
         // user-facing detail messages via UserOutput's verbosity filter.
         let listener = VerboseProgressListener::new(self.progress.output().clone());
+        let recorder = DeploymentReportListener::new(
+            "configure",
+            env_name,
+            self.clock.clone(),
+            Some(&listener),
+        );
+
+        let result = handler.execute(env_name, Some(&recorder));
+        let report = recorder.write(
+            self.repository.as_ref(),
+            &[Dependency::Ansible],
+            result.as_ref().err(),
+        );
+        if let Some(trace_file) = report.and_then(|report| report.trace_file) {
+            self.progress
+                .warn(&format!("Trace file: {}", trace_file.display()))?;
+        }
 
-        let configured = handler
-            .execute(env_name, Some(&listener))
-            .map_err(
+        let configured =
+            result.map_err(
                 |source| ConfigureSubcommandError::ConfigureOperationFailed {
                     name: env_name.to_string(),
                     source: Box::new(source),
@@ -270,6 +713,21 @@ impl ConfigureCommandController {
     }
 }
 
+/// Resolve the `--target-host` of an out-of-band run to the address of its SSH server
+#[allow(clippy::result_large_err)]
+fn resolve_target_host(host: &str, ssh_port: u16) -> Result<SocketAddr, ConfigureSubcommandError> {
+    let failed = |reason: String| ConfigureSubcommandError::TargetHostResolutionFailed {
+        host: host.to_string(),
+        reason,
+    };
+
+    (host, ssh_port)
+        .to_socket_addrs()
+        .map_err(|e| failed(e.to_string()))?
+        .next()
+        .ok_or_else(|| failed("no address found".to_string()))
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -391,4 +849,11 @@ mod tests {
         }
         // Expected - valid name but operation fails or other errors acceptable in test context
     }
+
+    #[test]
+    fn it_should_resolve_an_ip_target_host_with_the_ssh_port() {
+        let target = resolve_target_host("127.0.0.1", 2222).unwrap();
+
+        assert_eq!(target, "127.0.0.1:2222".parse::<SocketAddr>().unwrap());
+    }
 }
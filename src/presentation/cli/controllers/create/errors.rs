@@ -1,13 +1,13 @@
 //! Unified Create Command Errors
 //!
 //! This module defines a unified error type that encompasses all create subcommand errors,
-//! providing a single interface for environment, template, schema, and CLI schema command errors.
+//! providing a single interface for environment, template, schema, CLI schema and wizard command errors.
 
 use thiserror::Error;
 
 use super::subcommands::{
     environment::CreateEnvironmentCommandError, schema::CreateSchemaCommandError,
-    template::CreateEnvironmentTemplateCommandError,
+    template::CreateEnvironmentTemplateCommandError, wizard::CreateWizardCommandError,
 };
 
 /// Unified error type for all create subcommands
@@ -29,6 +29,10 @@ pub enum CreateCommandError {
     /// Schema generation errors
     #[error(transparent)]
     Schema(#[from] CreateSchemaCommandError),
+
+    /// Interactive wizard errors
+    #[error(transparent)]
+    Wizard(#[from] CreateWizardCommandError),
 }
 
 impl CreateCommandError {
@@ -42,6 +46,7 @@ impl CreateCommandError {
             Self::Environment(err) => err.help().to_string(),
             Self::Template(err) => err.help().to_string(),
             Self::Schema(err) => err.help(),
+            Self::Wizard(err) => err.help().to_string(),
         }
     }
 }
@@ -16,6 +16,7 @@
 //! - `router` - Main command router routing between subcommands
 //! - `subcommands` - Individual subcommand implementations (environment, template)
 //!   - `environment` - Contains environment creation logic, error types, and config loading
+//!   - `wizard` - Interactive wizard (`create --interactive`) writing the configuration file
 //! - `errors` - Unified error types for all create subcommands
 //!
 //! ## Usage Example
@@ -30,7 +31,8 @@
 //! # #[tokio::main]
 //! # async fn main() {
 //! let action = CreateAction::Environment {
-//!     env_file: PathBuf::from("config/environment.json")
+//!     env_file: PathBuf::from("config/environment.json"),
+//!     generate_ssh_keys: false,
 //! };
 //! // Note: ExecutionContext would be provided by the application bootstrap
 //! # let context = todo!(); // Mock for documentation example
@@ -51,7 +53,8 @@ mod tests;
 
 // Re-export commonly used types for convenience
 pub use errors::CreateCommandError;
-pub use router::route_command;
+pub use router::{route_command, route_wizard};
 pub use subcommands::environment::{ConfigFormat, ConfigLoader, CreateEnvironmentCommandError};
 pub use subcommands::schema::CreateSchemaCommandError;
 pub use subcommands::template::CreateEnvironmentTemplateCommandError;
+pub use subcommands::wizard::CreateWizardCommandError;
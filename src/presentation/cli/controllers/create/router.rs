@@ -7,6 +7,7 @@ use std::path::Path;
 
 use crate::presentation::cli::dispatch::ExecutionContext;
 use crate::presentation::cli::input::cli::commands::CreateAction;
+use crate::presentation::cli::input::cli::CreateWizardArgs;
 
 use super::errors::CreateCommandError;
 
@@ -34,14 +35,18 @@ pub async fn route_command(
     context: &ExecutionContext,
 ) -> Result<(), CreateCommandError> {
     match action {
-        CreateAction::Environment { env_file } => {
+        CreateAction::Environment {
+            env_file,
+            generate_ssh_keys,
+        } => {
             let output_format = context.output_format();
             context
                 .container()
                 .create_environment_controller()
+                .with_generated_ssh_keys(generate_ssh_keys)
                 .execute(&env_file, working_dir, output_format)
                 .await
-                .map(|_| ()) // Convert Environment<Created> to ()
+                .map(|_| ()) // Convert CreateResult to ()
                 .map_err(CreateCommandError::Environment)
         }
         CreateAction::Template {
@@ -63,3 +68,30 @@ pub async fn route_command(
             .map_err(CreateCommandError::Schema),
     }
 }
+
+/// Run the interactive create wizard (`create --interactive`)
+///
+/// # Arguments
+///
+/// * `wizard` - Answers given as flags and the wizard options
+/// * `working_dir` - Root directory for environment data storage
+/// * `context` - Execution context providing access to application services
+///
+/// # Errors
+///
+/// Returns an error if the wizard cannot complete or the environment creation fails.
+#[allow(clippy::result_large_err)] // Error contains detailed context for user guidance
+pub async fn route_wizard(
+    wizard: CreateWizardArgs,
+    working_dir: &Path,
+    context: &ExecutionContext,
+) -> Result<(), CreateCommandError> {
+    let output_format = context.output_format();
+    context
+        .container()
+        .create_wizard_controller()
+        .execute(&wizard, working_dir, output_format)
+        .await
+        .map(|_| ())
+        .map_err(CreateCommandError::Wizard)
+}
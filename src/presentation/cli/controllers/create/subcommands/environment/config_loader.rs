@@ -1,6 +1,7 @@
 //! Configuration Loader with Figment Integration
 //!
-//! This module provides configuration loading from JSON files using Figment.
+//! This module provides configuration loading from JSON, YAML and TOML files
+//! using Figment.
 //! Figment is used only in the presentation layer as a delivery mechanism,
 //! following DDD architecture boundaries.
 
@@ -8,16 +9,16 @@ use std::convert::TryInto;
 use std::path::Path;
 
 use figment::{
-    providers::{Format, Json},
+    providers::{Format, Json, Toml, Yaml},
     Figment,
 };
 
 use crate::application::command_handlers::create::config::EnvironmentCreationConfig;
-use crate::domain::environment::EnvironmentParams;
+use crate::domain::environment::{EnvironmentParams, WorkspaceLayout};
 
 use super::errors::{ConfigFormat, CreateEnvironmentCommandError};
 
-/// Configuration loader using Figment for JSON file parsing
+/// Configuration loader using Figment for JSON, YAML and TOML file parsing
 ///
 /// This loader is part of the presentation layer and handles the specifics
 /// of loading and parsing configuration files. It uses Figment for flexible
@@ -30,15 +31,16 @@ use super::errors::{ConfigFormat, CreateEnvironmentCommandError};
 pub struct ConfigLoader;
 
 impl ConfigLoader {
-    /// Load environment creation configuration from a JSON file
+    /// Load environment creation configuration from a JSON, YAML or TOML file
     ///
-    /// This method loads and parses a JSON configuration file, then validates
+    /// This method loads and parses a configuration file, then validates
     /// it according to domain rules. All errors are wrapped in presentation
     /// layer error types with helpful guidance.
     ///
     /// # Arguments
     ///
-    /// * `config_path` - Path to the JSON configuration file
+    /// * `config_path` - Path to the configuration file (`.json`, `.yaml`,
+    ///   `.yml` or `.toml`; other extensions are detected from the content)
     ///
     /// # Returns
     ///
@@ -49,7 +51,7 @@ impl ConfigLoader {
     ///
     /// Returns an error if:
     /// - Configuration file doesn't exist
-    /// - JSON parsing fails (syntax errors, type mismatches)
+    /// - Parsing fails (syntax errors, type mismatches)
     /// - Domain validation fails (invalid names, missing SSH keys, etc.)
     ///
     /// All errors include detailed troubleshooting guidance via `.help()`.
@@ -69,6 +71,25 @@ impl ConfigLoader {
     pub fn load_from_file(
         &self,
         config_path: &Path,
+    ) -> Result<EnvironmentCreationConfig, CreateEnvironmentCommandError> {
+        let config = self.parse_file(config_path)?;
+
+        self.validate(&config)?;
+
+        Ok(config)
+    }
+
+    /// Load environment creation configuration from a file without validating it
+    ///
+    /// Lets the caller adjust the configuration (e.g. `--generate-ssh-keys`)
+    /// before calling [`ConfigLoader::validate`].
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the file doesn't exist, cannot be read or parsing fails.
+    pub fn parse_file(
+        &self,
+        config_path: &Path,
     ) -> Result<EnvironmentCreationConfig, CreateEnvironmentCommandError> {
         // Step 1: Verify file exists
         if !config_path.exists() {
@@ -77,27 +98,52 @@ impl ConfigLoader {
             });
         }
 
-        // Step 2: Load with Figment
+        // Step 2: Detect the format from the extension, or else the content
+        let content = std::fs::read_to_string(config_path).map_err(|source| {
+            CreateEnvironmentCommandError::ConfigFileReadFailed {
+                path: config_path.to_path_buf(),
+                source,
+            }
+        })?;
+        let format = ConfigFormat::detect(config_path, &content);
+
+        // Step 3: Load with Figment
         // We don't use defaults here because we want explicit configuration
-        let config: EnvironmentCreationConfig = Figment::new()
-            .merge(Json::file(config_path))
-            .extract()
-            .map_err(
-                |source| CreateEnvironmentCommandError::ConfigParsingFailed {
-                    path: config_path.to_path_buf(),
-                    format: ConfigFormat::Json,
-                    source: Box::new(source),
-                },
-            )?;
+        let figment = match format {
+            ConfigFormat::Json => Figment::new().merge(Json::string(&content)),
+            ConfigFormat::Yaml => Figment::new().merge(Yaml::string(&content)),
+            ConfigFormat::Toml => Figment::new().merge(Toml::string(&content)),
+        };
+
+        figment.extract().map_err(
+            |source| CreateEnvironmentCommandError::ConfigParsingFailed {
+                path: config_path.to_path_buf(),
+                format,
+                source: Box::new(source),
+            },
+        )
+    }
 
-        // Step 3: Validate using domain rules
-        // This converts string-based config to domain types and validates
+    /// Validate a configuration using domain rules
+    ///
+    /// This converts string-based config to domain types and validates.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if domain validation fails.
+    pub fn validate(
+        &self,
+        config: &EnvironmentCreationConfig,
+    ) -> Result<(), CreateEnvironmentCommandError> {
+        // Generated keys only get their paths when the environment is created;
+        // their location does not affect the validation
         let _validated: EnvironmentParams = config
             .clone()
-            .try_into()
+            .with_generated_ssh_key_paths(&WorkspaceLayout::in_working_dir("."))
+            .and_then(TryInto::try_into)
             .map_err(|source| CreateEnvironmentCommandError::ConfigValidationFailed { source })?;
 
-        Ok(config)
+        Ok(())
     }
 }
 
@@ -411,4 +457,81 @@ mod tests {
             "Should load config with default values for optional fields"
         );
     }
+
+    #[test]
+    fn it_should_load_yaml_and_toml_configurations() {
+        let temp_dir = TempDir::new().unwrap();
+        let project_root = env!("CARGO_MANIFEST_DIR");
+
+        let yaml = format!(
+            r"
+environment:
+  name: yaml-env
+ssh_credentials:
+  private_key_path: {project_root}/fixtures/testing_rsa
+  public_key_path: {project_root}/fixtures/testing_rsa.pub
+provider:
+  provider: lxd
+  profile_name: lxd-yaml-env
+tracker:
+  core:
+    database: {{ driver: sqlite3, database_name: tracker.db }}
+    private: false
+  udp_trackers: [{{ bind_address: 0.0.0.0:6969 }}]
+  http_trackers: [{{ bind_address: 0.0.0.0:7070 }}]
+  http_api: {{ bind_address: 0.0.0.0:1212, admin_token: MyAccessToken }}
+  health_check_api: {{ bind_address: 127.0.0.1:1313 }}
+"
+        );
+        let toml = format!(
+            r#"
+[environment]
+name = "toml-env"
+
+[ssh_credentials]
+private_key_path = "{project_root}/fixtures/testing_rsa"
+public_key_path = "{project_root}/fixtures/testing_rsa.pub"
+
+[provider]
+provider = "lxd"
+profile_name = "lxd-toml-env"
+
+[tracker]
+core = {{ database = {{ driver = "sqlite3", database_name = "tracker.db" }}, private = false }}
+udp_trackers = [{{ bind_address = "0.0.0.0:6969" }}]
+http_trackers = [{{ bind_address = "0.0.0.0:7070" }}]
+http_api = {{ bind_address = "0.0.0.0:1212", admin_token = "MyAccessToken" }}
+health_check_api = {{ bind_address = "127.0.0.1:1313" }}
+"#
+        );
+        // The format of a file without a known extension comes from its content
+        let files = [
+            ("config.yml", yaml.as_str(), "yaml-env"),
+            ("config.toml", toml.as_str(), "toml-env"),
+            ("config.conf", toml.as_str(), "toml-env"),
+        ];
+
+        for (file_name, content, env_name) in files {
+            let config_path = temp_dir.path().join(file_name);
+            fs::write(&config_path, content).unwrap();
+
+            let config = ConfigLoader.load_from_file(&config_path).unwrap();
+
+            assert_eq!(config.environment.name, env_name, "{file_name}");
+        }
+    }
+
+    #[test]
+    fn it_should_report_the_detected_format_when_parsing_fails() {
+        let temp_dir = TempDir::new().unwrap();
+        let config_path = temp_dir.path().join("config.yaml");
+        fs::write(&config_path, "environment:\n  name: [yaml-env\n").unwrap();
+
+        let result = ConfigLoader.parse_file(&config_path);
+
+        let Err(CreateEnvironmentCommandError::ConfigParsingFailed { format, .. }) = result else {
+            panic!("Expected ConfigParsingFailed error, got {result:?}");
+        };
+        assert_eq!(format, ConfigFormat::Yaml);
+    }
 }
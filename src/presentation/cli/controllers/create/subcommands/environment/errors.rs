@@ -4,25 +4,21 @@
 //! command execution. All errors follow the project's error handling principles
 //! by providing clear, contextual, and actionable error messages with `.help()` methods.
 
-use std::path::PathBuf;
+use std::path::{Path, PathBuf};
 use thiserror::Error;
 
+pub use crate::application::command_handlers::create::config::ConfigFormat;
 use crate::application::command_handlers::create::CreateCommandHandlerError;
 use crate::presentation::cli::views::progress::ProgressReporterError;
 use crate::presentation::cli::views::ViewRenderError;
 
-/// Format of configuration file
-#[derive(Debug, Clone, Copy)]
-pub enum ConfigFormat {
-    /// JSON format
-    Json,
-}
-
-impl std::fmt::Display for ConfigFormat {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        match self {
-            Self::Json => write!(f, "JSON"),
-        }
+/// Command that checks the syntax of a configuration file in `format`
+fn syntax_check_tip(format: ConfigFormat, path: &Path) -> String {
+    let path = path.display();
+    match format {
+        ConfigFormat::Json => format!("Validate JSON syntax with: jq . < {path}"),
+        ConfigFormat::Yaml => format!("Validate YAML syntax with: yq . {path}"),
+        ConfigFormat::Toml => format!("Validate TOML syntax with: yq -p toml . {path}"),
     }
 }
 
@@ -47,13 +43,30 @@ Tip: Check that the file path is correct: ls -la {path}"
         path: PathBuf,
     },
 
+    /// Failed to read configuration file
+    ///
+    /// The configuration file exists but could not be read.
+    /// Use `.help()` for detailed troubleshooting steps.
+    #[error(
+        "Failed to read configuration file '{path}': {source}
+Tip: Check the file permissions: ls -la {path}"
+    )]
+    ConfigFileReadFailed {
+        /// Path to the configuration file
+        path: PathBuf,
+        /// Underlying I/O error
+        #[source]
+        source: std::io::Error,
+    },
+
     /// Failed to parse configuration file
     ///
     /// The configuration file exists but could not be parsed in the expected format.
     /// Use `.help()` for detailed troubleshooting steps.
     #[error(
         "Failed to parse configuration file '{path}' as {format}: {source}
-Tip: Validate {format} syntax with: jq . < {path}"
+Tip: {tip}",
+        tip = syntax_check_tip(*.format, .path)
     )]
     ConfigParsingFailed {
         /// Path to the configuration file
@@ -199,6 +212,15 @@ impl CreateEnvironmentCommandError {
 Example:
   torrust-tracker-deployer create environment --env-file ./config/environment.json
 
+For more information about configuration format, see the documentation."
+            }
+            Self::ConfigFileReadFailed { .. } => {
+                "Configuration File Read Failed - Troubleshooting:
+
+1. Ensure you have read permissions on the file: ls -la <path>
+2. Check that the path points to a file, not a directory
+3. Check that the file is UTF-8 text
+
 For more information about configuration format, see the documentation."
             }
             Self::ConfigParsingFailed { format, .. } => match format {
@@ -219,9 +241,10 @@ For more information about configuration format, see the documentation."
    - environment.name
    - ssh_credentials.private_key_path
    - ssh_credentials.public_key_path
-   - provider.provider (\"lxd\" or \"hetzner\")
+   - provider.provider (\"lxd\", \"hetzner\", \"digitalocean\", \"docker\" or \"libvirt\")
    - provider.profile_name (for LXD)
-   - provider.api_token (for Hetzner)
+   - provider.api_token (for Hetzner and DigitalOcean)
+   - provider.image (for Docker)
 
 4. Check field types match expectations:
    - Strings must be in quotes
@@ -235,8 +258,59 @@ For more information about configuration format, see the documentation."
    # For Hetzner:
    torrust-tracker-deployer create template --provider hetzner ./environment.json
 
+   # For DigitalOcean:
+   torrust-tracker-deployer create template --provider digitalocean ./environment.json
+
+   # For libvirt:
+   torrust-tracker-deployer create template --provider libvirt ./environment.json
+
    Then edit the generated file to replace placeholder values with your actual configuration.
 
+For more information, see the configuration documentation."
+                }
+                ConfigFormat::Yaml => {
+                    "YAML Configuration Parsing Failed - Troubleshooting:
+
+1. Look at the line and column reported in the error, and check the syntax:
+   - Command line: yq . your-config.yaml
+
+2. Common YAML syntax errors:
+   - Inconsistent indentation (use spaces, not tabs)
+   - Missing space after ':' in 'key: value'
+   - List items not starting with '- '
+   - Unquoted values starting with special characters ('*', '&', '@')
+
+3. The YAML document has the same structure as the JSON configuration:
+   - environment.name
+   - ssh_credentials.private_key_path
+   - ssh_credentials.public_key_path
+   - provider.provider (\"lxd\", \"hetzner\", \"digitalocean\", \"docker\" or \"libvirt\")
+
+4. Generate a JSON template and convert it:
+   torrust-tracker-deployer create template --provider lxd ./environment.json
+   yq -P . ./environment.json > ./environment.yaml
+
+For more information, see the configuration documentation."
+                }
+                ConfigFormat::Toml => {
+                    "TOML Configuration Parsing Failed - Troubleshooting:
+
+1. Look at the line and column reported in the error, and check the syntax:
+   - Command line: yq -p toml . your-config.toml
+
+2. Common TOML syntax errors:
+   - Unquoted string values
+   - A table ('[section]') defined twice
+   - Arrays of tables written as '[section]' instead of '[[section]]'
+
+3. Each top-level section of the JSON configuration is a TOML table:
+   [environment], [ssh_credentials], [provider], [tracker.core], ...
+   The trackers are arrays of tables: [[tracker.udp_trackers]]
+
+4. Generate a JSON template and convert it:
+   torrust-tracker-deployer create template --provider lxd ./environment.json
+   yq -o toml . ./environment.json > ./environment.toml
+
 For more information, see the configuration documentation."
                 }
             },
@@ -358,6 +432,23 @@ mod tests {
         assert!(help.contains("jq"));
     }
 
+    #[test]
+    fn it_should_suggest_a_syntax_check_for_the_format_of_the_file() {
+        let error = |format| CreateEnvironmentCommandError::ConfigParsingFailed {
+            path: PathBuf::from("config.yaml"),
+            format,
+            source: Box::new(std::io::Error::new(std::io::ErrorKind::InvalidData, "test")),
+        };
+
+        let yaml = error(ConfigFormat::Yaml);
+        let toml = error(ConfigFormat::Toml);
+
+        assert!(yaml.to_string().contains("yq . config.yaml"));
+        assert!(yaml.help().contains("YAML Configuration Parsing Failed"));
+        assert!(toml.to_string().contains("yq -p toml"));
+        assert!(toml.help().contains("[[tracker.udp_trackers]]"));
+    }
+
     #[test]
     fn it_should_display_config_file_path_in_error() {
         let error = CreateEnvironmentCommandError::ConfigFileNotFound {
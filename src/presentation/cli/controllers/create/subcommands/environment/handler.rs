@@ -8,13 +8,13 @@ use std::path::Path;
 use std::sync::Arc;
 
 use parking_lot::ReentrantMutex;
+use tracing::info;
 
-use crate::adapters::ssh::is_passphrase_protected;
+use crate::adapters::ssh::{check_key_permissions, is_passphrase_protected, KeyPermissionsCheck};
 use crate::application::command_handlers::create::config::EnvironmentCreationConfig;
+use crate::application::command_handlers::create::CreateResult;
 use crate::application::command_handlers::CreateCommandHandler;
 use crate::domain::environment::repository::EnvironmentRepository;
-use crate::domain::environment::state::Created;
-use crate::domain::Environment;
 use crate::presentation::cli::input::cli::OutputFormat;
 use crate::presentation::cli::views::commands::create::{
     EnvironmentDetailsData, JsonView, TextView,
@@ -81,6 +81,7 @@ pub struct CreateEnvironmentCommandController {
     repository: Arc<dyn EnvironmentRepository + Send + Sync>,
     clock: Arc<dyn Clock>,
     progress: ProgressReporter,
+    generate_ssh_keys: bool,
 }
 
 impl CreateEnvironmentCommandController {
@@ -99,9 +100,18 @@ impl CreateEnvironmentCommandController {
             repository,
             clock,
             progress,
+            generate_ssh_keys: false,
         }
     }
 
+    /// Generate the SSH key pair (`--generate-ssh-keys`), as if the
+    /// configuration file set `generate_ssh_keys`
+    #[must_use]
+    pub fn with_generated_ssh_keys(mut self, generate_ssh_keys: bool) -> Self {
+        self.generate_ssh_keys = generate_ssh_keys;
+        self
+    }
+
     /// Execute the complete environment creation workflow
     ///
     /// Orchestrates all steps of the environment creation command:
@@ -125,7 +135,9 @@ impl CreateEnvironmentCommandController {
     ///
     /// # Returns
     ///
-    /// Returns `Ok(Environment<Created>)` on success, or a `CreateEnvironmentCommandError` if any step fails.
+    /// Returns `Ok(CreateResult)` on success, with the created environment or
+    /// the existing one when it already exists with the same configuration,
+    /// or a `CreateEnvironmentCommandError` if any step fails.
     #[allow(clippy::result_large_err)]
     #[allow(clippy::unused_async)] // Part of uniform async presentation layer interface
     pub async fn execute(
@@ -133,18 +145,36 @@ impl CreateEnvironmentCommandController {
         env_file: &Path,
         working_dir: &Path,
         output_format: OutputFormat,
-    ) -> Result<Environment<Created>, CreateEnvironmentCommandError> {
+    ) -> Result<CreateResult, CreateEnvironmentCommandError> {
+        self.progress.set_output_format(output_format);
+        let result = self
+            .execute_steps(env_file, working_dir, output_format)
+            .await;
+        self.progress.finish(result)
+    }
+
+    /// Steps of [`Self::execute`], before the timing summary
+    #[allow(clippy::result_large_err)]
+    #[allow(clippy::unused_async)] // Part of uniform async presentation layer interface
+    async fn execute_steps(
+        &mut self,
+        env_file: &Path,
+        working_dir: &Path,
+        output_format: OutputFormat,
+    ) -> Result<CreateResult, CreateEnvironmentCommandError> {
         let config = self.load_configuration(env_file)?;
 
         self.warn_if_ssh_key_passphrase_protected(&config)?;
 
+        self.warn_if_ssh_key_readable_by_others(&config)?;
+
         let command_handler = self.create_command_handler()?;
 
-        let environment = self.execute_create_command(&command_handler, config, working_dir)?;
+        let result = self.execute_create_command(&command_handler, config, working_dir)?;
 
-        self.display_creation_results(&environment, output_format)?;
+        self.display_creation_results(&result, output_format)?;
 
-        Ok(environment)
+        Ok(result)
     }
 
     /// Emit a warning if the configured SSH private key appears to be passphrase-protected.
@@ -191,6 +221,52 @@ impl CreateEnvironmentCommandController {
             .map_err(CreateEnvironmentCommandError::from)
     }
 
+    /// Emit a warning if other users can read the configured SSH private key.
+    ///
+    /// OpenSSH refuses such keys, so `provision` would fail later. Generated keys
+    /// are skipped: they are created with `0600`. When the permissions cannot be
+    /// checked (e.g. on Windows) the check is only logged.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error only if the `UserOutput` mutex is poisoned (critical internal error).
+    fn warn_if_ssh_key_readable_by_others(
+        &self,
+        config: &EnvironmentCreationConfig,
+    ) -> Result<(), CreateEnvironmentCommandError> {
+        let key_path = Path::new(&config.ssh_credentials.private_key_path);
+
+        if config.generate_ssh_keys {
+            return Ok(());
+        }
+
+        match check_key_permissions(key_path) {
+            KeyPermissionsCheck::OwnerOnly => return Ok(()),
+            KeyPermissionsCheck::Undetermined { reason } => {
+                info!(
+                    key = %key_path.display(),
+                    reason = %reason,
+                    "Skipped the SSH private key permissions check"
+                );
+                return Ok(());
+            }
+            KeyPermissionsCheck::ReadableByOthers => {}
+        }
+
+        let message = format!(
+            "SSH private key is readable by other users.\n  \
+             Key: {key}\n\n  \
+             SSH refuses private keys that other users can access, so the `provision`\n  \
+             step will fail until the permissions are restricted:\n    \
+             chmod 600 {key}",
+            key = key_path.display()
+        );
+
+        self.progress
+            .warn(&message)
+            .map_err(CreateEnvironmentCommandError::from)
+    }
+
     /// Load and validate configuration from file
     ///
     /// This step handles:    /// - Loading configuration file using `ConfigLoader`    /// - Parsing JSON content
@@ -224,16 +300,21 @@ impl CreateEnvironmentCommandController {
 
         let loader = ConfigLoader;
 
-        let config = loader.load_from_file(env_file).inspect_err(
-            |err: &CreateEnvironmentCommandError| {
+        let config = loader
+            .parse_file(env_file)
+            .map(|mut config| {
+                config.generate_ssh_keys |= self.generate_ssh_keys;
+                config
+            })
+            .and_then(|config| loader.validate(&config).map(|()| config))
+            .inspect_err(|err: &CreateEnvironmentCommandError| {
                 // Log error details for debugging
                 tracing::error!(
                     error = %err,
                     config_file = %env_file.display(),
                     "Configuration loading failed"
                 );
-            },
-        )?;
+            })?;
 
         self.progress.complete_step(Some(&format!(
             "Configuration loaded: {}",
@@ -269,6 +350,7 @@ impl CreateEnvironmentCommandController {
     ///
     /// This step handles:
     /// - Executing the create command with the given handler
+    /// - Reusing the environment when it already exists with the same configuration
     /// - Handling command execution errors
     ///
     /// # Arguments
@@ -279,17 +361,18 @@ impl CreateEnvironmentCommandController {
     ///
     /// # Returns
     ///
-    /// Returns the created `Environment` on success.
+    /// Returns the created or existing environment on success.
     ///
     /// # Errors
     ///
-    /// Returns an error if command execution fails (e.g., environment already exists).
+    /// Returns an error if command execution fails (e.g., environment already
+    /// exists with a different configuration).
     fn execute_create_command(
         &mut self,
         command_handler: &CreateCommandHandler,
         config: EnvironmentCreationConfig,
         working_dir: &Path,
-    ) -> Result<Environment<Created>, CreateEnvironmentCommandError> {
+    ) -> Result<CreateResult, CreateEnvironmentCommandError> {
         self.progress
             .start_step(CreateEnvironmentStep::CreateEnvironment.description())?;
 
@@ -301,16 +384,21 @@ impl CreateEnvironmentCommandController {
         self.progress
             .sub_step("Validating configuration and creating environment...")?;
 
-        let environment = command_handler
-            .execute(config, working_dir)
+        let result = command_handler
+            .execute_or_reuse(config, working_dir)
             .map_err(|source| CreateEnvironmentCommandError::CommandFailed { source })?;
 
-        self.progress.complete_step(Some(&format!(
-            "Environment created: {}",
-            environment.name().as_str()
-        )))?;
+        let message = if result.already_existed {
+            format!(
+                "Environment already exists with the same configuration: {}",
+                result.name().as_str()
+            )
+        } else {
+            format!("Environment created: {}", result.name().as_str())
+        };
+        self.progress.complete_step(Some(&message))?;
 
-        Ok(environment)
+        Ok(result)
     }
 
     /// Display the results of successful environment creation
@@ -325,7 +413,7 @@ impl CreateEnvironmentCommandController {
     ///
     /// # Arguments
     ///
-    /// * `environment` - The successfully created environment
+    /// * `result` - The created environment, or the existing one
     /// * `output_format` - The format to use for rendering output (Text or Json)
     ///
     /// # Returns
@@ -338,18 +426,23 @@ impl CreateEnvironmentCommandController {
     /// which indicates the environment was created but we couldn't display results.
     fn display_creation_results(
         &mut self,
-        environment: &Environment<Created>,
+        result: &CreateResult,
         output_format: OutputFormat,
     ) -> Result<(), CreateEnvironmentCommandError> {
-        self.progress.complete(&format!(
-            "Environment '{}' created successfully",
-            environment.name().as_str()
-        ))?;
+        let name = result.name().as_str();
+        if result.already_existed {
+            self.progress.complete(&format!(
+                "Environment '{name}' already exists with the same configuration"
+            ))?;
+        } else {
+            self.progress
+                .complete(&format!("Environment '{name}' created successfully"))?;
+        }
 
         self.progress.blank_line()?;
 
         // Convert domain model to presentation DTO
-        let details = EnvironmentDetailsData::from(environment);
+        let details = EnvironmentDetailsData::from(result);
 
         // Render using appropriate view based on output format (Strategy Pattern)
         let output = match output_format {
@@ -148,7 +148,7 @@ async fn it_should_return_error_for_invalid_json() {
 }
 
 #[tokio::test]
-async fn it_should_return_error_for_duplicate_environment() {
+async fn it_should_reuse_the_environment_when_created_again_with_the_same_configuration() {
     let temp_dir = TempDir::new().unwrap();
     let config_path = temp_dir.path().join("config.json");
 
@@ -211,21 +211,16 @@ async fn it_should_return_error_for_duplicate_environment() {
         .await;
     assert!(result1.is_ok(), "First create should succeed");
 
-    // Try to create same environment again (use new context to avoid any state issues)
+    // Create same environment again (use new context to avoid any state issues)
     let context2 = create_test_context(working_dir);
     let result2 = context2
         .container()
         .create_environment_controller()
         .execute(&config_path, working_dir, OutputFormat::Text)
         .await;
-    assert!(result2.is_err(), "Second create should fail");
 
-    match result2.unwrap_err() {
-        CreateEnvironmentCommandError::CommandFailed { .. } => {
-            // Expected - environment already exists
-        }
-        other => panic!("Expected CommandFailed, got: {other:?}"),
-    }
+    let result2 = result2.expect("Second create with the same configuration should succeed");
+    assert!(result2.already_existed);
 }
 
 #[tokio::test]
@@ -5,3 +5,4 @@
 pub mod environment;
 pub mod schema;
 pub mod template;
+pub mod wizard;
@@ -69,6 +69,15 @@ impl CreateSchemaCommandController {
     pub fn execute(
         &mut self,
         output_path: Option<&PathBuf>,
+    ) -> Result<(), CreateSchemaCommandError> {
+        let result = self.execute_steps(output_path);
+        self.progress.finish(result)
+    }
+
+    /// Steps of [`Self::execute`], before the timing summary
+    fn execute_steps(
+        &mut self,
+        output_path: Option<&PathBuf>,
     ) -> Result<(), CreateSchemaCommandError> {
         // Generate schema using application layer handler
         let schema = CreateSchemaCommandHandler::execute(output_path.cloned())
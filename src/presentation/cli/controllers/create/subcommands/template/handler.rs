@@ -101,6 +101,18 @@ impl CreateTemplateCommandController {
         &mut self,
         output_path: &Path,
         provider: Provider,
+    ) -> Result<(), CreateEnvironmentTemplateCommandError> {
+        let result = self.execute_steps(output_path, provider).await;
+        self.progress.finish(result)
+    }
+
+    /// Steps of [`Self::execute`], before the timing summary
+    #[allow(clippy::result_large_err)]
+    #[allow(clippy::unused_async)] // Part of uniform async presentation layer interface
+    async fn execute_steps(
+        &mut self,
+        output_path: &Path,
+        provider: Provider,
     ) -> Result<(), CreateEnvironmentTemplateCommandError> {
         self.generate_template_file(output_path, provider)?;
         self.display_success_and_guidance(output_path)?;
@@ -0,0 +1,151 @@
+//! Interactive Create Wizard Errors
+//!
+//! This module defines error types for `create --interactive`. Invalid
+//! answers are not errors: the wizard shows the domain error and asks again.
+//! These errors end the wizard.
+
+use std::path::PathBuf;
+
+use thiserror::Error;
+
+use crate::adapters::ssh::SshKeyGenerationError;
+use crate::application::command_handlers::create::config::CreateConfigError;
+use crate::presentation::cli::views::progress::ProgressReporterError;
+
+use super::super::environment::CreateEnvironmentCommandError;
+
+/// Errors that can occur while running the interactive create wizard
+#[derive(Debug, Error)]
+pub enum CreateWizardCommandError {
+    /// Standard input ended before a question was answered
+    #[error(
+        "No answer for '{question}': standard input ended
+Tip: Pass {flag} (or --defaults to accept the default answers)"
+    )]
+    InputEnded {
+        /// The unanswered question
+        question: String,
+        /// Flag that answers the question
+        flag: String,
+    },
+
+    /// Standard input could not be read
+    #[error("Failed to read the answer from standard input: {source}")]
+    InputReadFailed {
+        #[source]
+        source: std::io::Error,
+    },
+
+    /// A new SSH key pair could not be generated
+    #[error(
+        "Failed to generate an SSH key pair: {source}
+Tip: Pass --ssh-private-key with the path to an existing key instead"
+    )]
+    KeyGenerationFailed {
+        #[source]
+        source: SshKeyGenerationError,
+    },
+
+    /// The answers do not form a valid environment configuration
+    #[error("Invalid environment configuration: {source}")]
+    InvalidConfiguration {
+        #[source]
+        source: CreateConfigError,
+    },
+
+    /// A file already exists where the configuration would be written
+    #[error(
+        "Configuration file '{path}' already exists
+Tip: Pass --output with another path, or remove the existing file"
+    )]
+    ConfigFileExists {
+        /// Path of the existing file
+        path: PathBuf,
+    },
+
+    /// The configuration file could not be written
+    #[error("Failed to write configuration file '{path}': {source}")]
+    ConfigWriteFailed {
+        /// Path of the configuration file
+        path: PathBuf,
+        #[source]
+        source: std::io::Error,
+    },
+
+    /// Creating the environment from the written configuration failed
+    #[error(transparent)]
+    Creation(#[from] CreateEnvironmentCommandError),
+
+    /// Progress reporting failed
+    #[error(
+        "Failed to report progress: {source}
+Tip: This is a critical bug - please report it with full logs using --log-output file-and-stderr"
+    )]
+    ProgressReportingFailed {
+        #[source]
+        source: ProgressReporterError,
+    },
+}
+
+impl CreateWizardCommandError {
+    /// Get detailed troubleshooting guidance for this error
+    #[must_use]
+    pub fn help(&self) -> &'static str {
+        match self {
+            Self::InputEnded { .. } | Self::InputReadFailed { .. } => {
+                "The wizard reads its answers from standard input.
+
+Without a terminal (CI, scripts, pipes), answer every question with a flag
+and accept the defaults for the rest:
+
+  torrust-tracker-deployer create --interactive --defaults \\
+    --name my-env --provider lxd --ssh-private-key ~/.ssh/id_ed25519
+
+Run 'torrust-tracker-deployer create --help' for the list of flags."
+            }
+            Self::KeyGenerationFailed { source } => source.help(),
+            Self::InvalidConfiguration { source } => source.help(),
+            Self::ConfigFileExists { .. } => {
+                "The wizard never overwrites an existing configuration file.
+
+- Create the environment from the existing file:
+  torrust-tracker-deployer create environment --env-file <file>
+- Or choose another file with --output <file>"
+            }
+            Self::ConfigWriteFailed { .. } => {
+                "Check the permissions of the target directory and the available disk
+space, or choose another file with --output <file>."
+            }
+            Self::Creation(source) => source.help(),
+            Self::ProgressReportingFailed { .. } => {
+                "This is a critical system-level error that occurs when the progress
+reporting system fails, typically due to mutex poisoning.
+
+Restart the command. If the problem continues, report the issue with full
+logs using --log-output file-and-stderr."
+            }
+        }
+    }
+}
+
+impl From<ProgressReporterError> for CreateWizardCommandError {
+    fn from(source: ProgressReporterError) -> Self {
+        Self::ProgressReportingFailed { source }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn it_should_name_the_flag_when_input_ends() {
+        let error = CreateWizardCommandError::InputEnded {
+            question: "Environment name".to_string(),
+            flag: "--name".to_string(),
+        };
+
+        assert!(error.to_string().contains("Pass --name"));
+        assert!(error.help().contains("--defaults"));
+    }
+}
@@ -0,0 +1,612 @@
+//! Interactive Create Wizard Controller
+//!
+//! This module implements `create --interactive`: it asks for the settings a
+//! new user has to decide on, builds the configuration with
+//! `EnvironmentCreationConfigBuilder`, writes it to `envs/{env}.json` and
+//! optionally creates the environment from that file.
+//!
+//! Every answer is validated with the domain value objects before the next
+//! question, so an invalid answer is rejected at the prompt with the domain
+//! error message.
+
+use std::cell::RefCell;
+use std::net::Ipv4Addr;
+use std::path::{Path, PathBuf};
+use std::sync::Arc;
+
+use clap::ValueEnum;
+use parking_lot::ReentrantMutex;
+use rand::distr::{Alphanumeric, SampleString};
+
+use crate::adapters::ssh::key_generator::{
+    public_key_path_of, SshKeyGenerator, GENERATED_PRIVATE_KEY_FILE_NAME,
+};
+use crate::application::command_handlers::create::config::{
+    EnvironmentCreationConfig, EnvironmentCreationConfigBuilder,
+};
+use crate::application::command_handlers::create::CreateResult;
+use crate::domain::environment::repository::EnvironmentRepository;
+use crate::domain::environment::{EnvironmentParams, WorkspaceLayout};
+use crate::domain::provider::{
+    Provider, DEFAULT_LIBVIRT_IMAGE, DEFAULT_LIBVIRT_NETWORK, DEFAULT_LIBVIRT_POOL,
+};
+use crate::domain::tracker::MysqlConfigOptions;
+use crate::domain::{EnvironmentName, ProfileName};
+use crate::presentation::cli::input::cli::{CreateWizardArgs, OutputFormat, WizardDatabase};
+use crate::presentation::cli::views::progress::ProgressReporter;
+use crate::presentation::cli::views::UserOutput;
+use crate::shared::clock::Clock;
+use crate::shared::generate_random_password;
+
+use super::super::environment::CreateEnvironmentCommandController;
+use super::errors::CreateWizardCommandError;
+use super::prompt::Prompter;
+
+/// Directory of the configuration files written by the wizard
+const ENV_FILES_DIR: &str = "envs";
+
+/// Length of the generated REST API admin token
+const ADMIN_TOKEN_LENGTH: usize = 32;
+
+/// Steps in the wizard workflow
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum CreateWizardStep {
+    AskSettings,
+    WriteConfiguration,
+}
+
+impl CreateWizardStep {
+    /// All steps in execution order
+    const ALL: &'static [Self] = &[Self::AskSettings, Self::WriteConfiguration];
+
+    /// Total number of steps
+    const fn count() -> usize {
+        Self::ALL.len()
+    }
+
+    /// User-facing description for the step
+    fn description(self) -> &'static str {
+        match self {
+            Self::AskSettings => "Asking for the environment settings",
+            Self::WriteConfiguration => "Writing configuration file",
+        }
+    }
+}
+
+/// Presentation layer controller for the interactive create wizard
+///
+/// # Responsibilities
+///
+/// - Ask for the environment settings, or take them from the flags
+/// - Generate a deployment SSH key pair on request
+/// - Validate the answers with the domain value objects
+/// - Write the configuration file
+/// - Hand over to `CreateEnvironmentCommandController` to create the environment
+pub struct CreateWizardCommandController {
+    repository: Arc<dyn EnvironmentRepository + Send + Sync>,
+    clock: Arc<dyn Clock>,
+    progress: ProgressReporter,
+}
+
+impl CreateWizardCommandController {
+    /// Create a new wizard controller
+    pub fn new(
+        repository: Arc<dyn EnvironmentRepository + Send + Sync>,
+        clock: Arc<dyn Clock>,
+        user_output: &Arc<ReentrantMutex<RefCell<UserOutput>>>,
+    ) -> Self {
+        let progress = ProgressReporter::new(user_output.clone(), CreateWizardStep::count());
+
+        Self {
+            repository,
+            clock,
+            progress,
+        }
+    }
+
+    /// Run the wizard on stdin/stderr
+    ///
+    /// Returns the created (or already existing) environment, or `None` when
+    /// only the configuration file was written.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if a question cannot be answered (stdin ended), the
+    /// SSH key pair cannot be generated, the configuration file cannot be
+    /// written, or the environment creation fails.
+    #[allow(clippy::result_large_err)]
+    pub async fn execute(
+        &mut self,
+        args: &CreateWizardArgs,
+        working_dir: &Path,
+        output_format: OutputFormat,
+    ) -> Result<Option<CreateResult>, CreateWizardCommandError> {
+        let mut prompter = Prompter::stdio(args.defaults);
+
+        self.execute_with(&mut prompter, args, working_dir, output_format)
+            .await
+    }
+
+    /// Run the wizard with the given prompter
+    ///
+    /// # Errors
+    ///
+    /// Same as [`CreateWizardCommandController::execute`].
+    #[allow(clippy::result_large_err)]
+    pub async fn execute_with(
+        &mut self,
+        prompter: &mut Prompter,
+        args: &CreateWizardArgs,
+        working_dir: &Path,
+        output_format: OutputFormat,
+    ) -> Result<Option<CreateResult>, CreateWizardCommandError> {
+        self.progress.set_output_format(output_format);
+        let result = self
+            .execute_with_steps(prompter, args, working_dir, output_format)
+            .await;
+        self.progress.finish(result)
+    }
+
+    /// Steps of [`Self::execute_with`], before the timing summary
+    #[allow(clippy::result_large_err)]
+    async fn execute_with_steps(
+        &mut self,
+        prompter: &mut Prompter,
+        args: &CreateWizardArgs,
+        working_dir: &Path,
+        output_format: OutputFormat,
+    ) -> Result<Option<CreateResult>, CreateWizardCommandError> {
+        self.progress
+            .start_step(CreateWizardStep::AskSettings.description())?;
+        let config = self.ask_configuration(prompter, args, working_dir)?;
+        self.progress.complete_step(None)?;
+
+        let env_file = self.write_configuration(&config, args, working_dir)?;
+
+        let create_now = !args.no_create
+            && prompter.confirm("Create the environment now?", "--no-create", true)?;
+
+        if !create_now {
+            self.display_next_steps(&env_file)?;
+            return Ok(None);
+        }
+
+        let result = CreateEnvironmentCommandController::new(
+            self.repository.clone(),
+            self.clock.clone(),
+            self.progress.output(),
+        )
+        .execute(&env_file, working_dir, output_format)
+        .await?;
+
+        Ok(Some(result))
+    }
+
+    /// Ask every question and build the validated configuration
+    #[allow(clippy::result_large_err)]
+    fn ask_configuration(
+        &self,
+        prompter: &mut Prompter,
+        args: &CreateWizardArgs,
+        working_dir: &Path,
+    ) -> Result<EnvironmentCreationConfig, CreateWizardCommandError> {
+        let name = self.ask_name(prompter, args)?;
+
+        let (private_key, public_key) = Self::ask_ssh_keys(prompter, args, working_dir, &name)?;
+
+        let builder = EnvironmentCreationConfigBuilder::new()
+            .name(name.as_str())
+            .ssh_keys(private_key.to_string_lossy(), public_key.to_string_lossy());
+        let builder = Self::ask_provider(prompter, args, &name, builder)?;
+        let builder = Self::ask_database(prompter, args, builder)?;
+        let config = Self::ask_ports(prompter, args, &builder)?;
+
+        EnvironmentParams::try_from(config.clone())
+            .map_err(|source| CreateWizardCommandError::InvalidConfiguration { source })?;
+
+        Ok(config)
+    }
+
+    #[allow(clippy::result_large_err)]
+    fn ask_name(
+        &self,
+        prompter: &mut Prompter,
+        args: &CreateWizardArgs,
+    ) -> Result<EnvironmentName, CreateWizardCommandError> {
+        prompter.answer(
+            args.name.as_deref(),
+            "Environment name",
+            "--name",
+            None,
+            |answer| {
+                let name = EnvironmentName::new(answer).map_err(|e| e.to_string())?;
+
+                match self.repository.exists(&name) {
+                    Ok(false) => Ok(name),
+                    Ok(true) => Err(format!("environment '{name}' already exists")),
+                    Err(e) => Err(e.to_string()),
+                }
+            },
+        )
+    }
+
+    /// Generate a key pair or ask for an existing one
+    #[allow(clippy::result_large_err)]
+    fn ask_ssh_keys(
+        prompter: &mut Prompter,
+        args: &CreateWizardArgs,
+        working_dir: &Path,
+        name: &EnvironmentName,
+    ) -> Result<(PathBuf, PathBuf), CreateWizardCommandError> {
+        let key_dir = WorkspaceLayout::in_working_dir(absolute(working_dir))
+            .environment_data_dir(name)
+            .join("ssh");
+
+        let generate = args.generate_ssh_key
+            || (args.ssh_private_key.is_none()
+                && prompter.confirm(
+                    &format!("Generate a new SSH key pair in {}?", key_dir.display()),
+                    "--generate-ssh-key",
+                    true,
+                )?);
+
+        if generate {
+            let private_key = key_dir.join(GENERATED_PRIVATE_KEY_FILE_NAME);
+            let public_key = SshKeyGenerator::new()
+                .generate(&private_key, &format!("torrust-tracker-deployer-{name}"))
+                .map_err(|source| CreateWizardCommandError::KeyGenerationFailed { source })?;
+            prompter.note(&format!(
+                "Generated SSH key pair: {}",
+                private_key.display()
+            ));
+            return Ok((private_key, public_key));
+        }
+
+        let given = args
+            .ssh_private_key
+            .as_ref()
+            .map(|path| path.to_string_lossy().to_string());
+        let private_key = prompter.answer(
+            given.as_deref(),
+            "SSH private key path",
+            "--ssh-private-key",
+            None,
+            existing_file,
+        )?;
+
+        let given = args
+            .ssh_public_key
+            .as_ref()
+            .map(|path| path.to_string_lossy().to_string());
+        let default = public_key_path_of(&private_key);
+        let public_key = prompter.answer(
+            given.as_deref(),
+            "SSH public key path",
+            "--ssh-public-key",
+            Some(&default.to_string_lossy()),
+            existing_file,
+        )?;
+
+        Ok((private_key, public_key))
+    }
+
+    #[allow(clippy::result_large_err)]
+    fn ask_provider(
+        prompter: &mut Prompter,
+        args: &CreateWizardArgs,
+        name: &EnvironmentName,
+        builder: EnvironmentCreationConfigBuilder,
+    ) -> Result<EnvironmentCreationConfigBuilder, CreateWizardCommandError> {
+        let provider = prompter.answer(
+            args.provider.map(|provider| provider.as_str()),
+            "Provider (lxd, hetzner, digitalocean, docker, libvirt)",
+            "--provider",
+            Some(Provider::Lxd.as_str()),
+            |answer| Provider::from_str(answer, true),
+        )?;
+
+        match provider {
+            Provider::Lxd => {
+                let profile = prompter.answer(
+                    args.lxd_profile.as_deref(),
+                    "LXD profile name",
+                    "--lxd-profile",
+                    Some(&format!("torrust-profile-{name}")),
+                    |answer| ProfileName::new(answer).map_err(|e| e.to_string()),
+                )?;
+
+                Ok(builder.provider_lxd(profile.as_str()))
+            }
+            Provider::Hetzner => {
+                let api_token = prompter.answer(
+                    args.hetzner_api_token.as_deref(),
+                    "Hetzner API token",
+                    "--hetzner-api-token",
+                    None,
+                    not_empty,
+                )?;
+                let server_type =
+                    prompter.ask("Hetzner server type", "--defaults", Some("cx22"), not_empty)?;
+                let location =
+                    prompter.ask("Hetzner location", "--defaults", Some("nbg1"), not_empty)?;
+                let image = prompter.ask(
+                    "Server image",
+                    "--defaults",
+                    Some("ubuntu-24.04"),
+                    not_empty,
+                )?;
+
+                Ok(builder.provider_hetzner(api_token, server_type, location, image))
+            }
+            Provider::DigitalOcean => {
+                let api_token = prompter.answer(
+                    args.digitalocean_api_token.as_deref(),
+                    "DigitalOcean API token",
+                    "--digitalocean-api-token",
+                    None,
+                    not_empty,
+                )?;
+                let droplet_size = prompter.ask(
+                    "DigitalOcean droplet size",
+                    "--defaults",
+                    Some("s-1vcpu-2gb"),
+                    not_empty,
+                )?;
+                let region =
+                    prompter.ask("DigitalOcean region", "--defaults", Some("fra1"), not_empty)?;
+                let image = prompter.ask(
+                    "Droplet image",
+                    "--defaults",
+                    Some("ubuntu-24-04-x64"),
+                    not_empty,
+                )?;
+                let reserved_ip = prompter.ask(
+                    "Reserved IP (empty for none)",
+                    "--defaults",
+                    Some(""),
+                    |answer| {
+                        if answer.is_empty() {
+                            return Ok(None);
+                        }
+                        answer
+                            .parse::<Ipv4Addr>()
+                            .map(|ip| Some(ip.to_string()))
+                            .map_err(|_| "must be an IPv4 address".to_string())
+                    },
+                )?;
+
+                Ok(builder.provider_digitalocean(
+                    api_token,
+                    droplet_size,
+                    region,
+                    image,
+                    reserved_ip,
+                ))
+            }
+            Provider::Docker => {
+                let image = prompter.ask(
+                    "Instance image",
+                    "--defaults",
+                    Some("torrust-provisioned-instance:latest"),
+                    not_empty,
+                )?;
+
+                Ok(builder.provider_docker(image))
+            }
+            Provider::Libvirt => {
+                let pool = prompter.ask(
+                    "Libvirt storage pool",
+                    "--defaults",
+                    Some(DEFAULT_LIBVIRT_POOL),
+                    not_empty,
+                )?;
+                let network_name = prompter.ask(
+                    "Libvirt network",
+                    "--defaults",
+                    Some(DEFAULT_LIBVIRT_NETWORK),
+                    not_empty,
+                )?;
+                let image = prompter.ask(
+                    "Cloud image (absolute path or URL)",
+                    "--defaults",
+                    Some(DEFAULT_LIBVIRT_IMAGE),
+                    not_empty,
+                )?;
+
+                Ok(builder.provider_libvirt(pool, network_name, image))
+            }
+        }
+    }
+
+    #[allow(clippy::result_large_err)]
+    fn ask_database(
+        prompter: &mut Prompter,
+        args: &CreateWizardArgs,
+        builder: EnvironmentCreationConfigBuilder,
+    ) -> Result<EnvironmentCreationConfigBuilder, CreateWizardCommandError> {
+        let given = args
+            .database
+            .and_then(|database| database.to_possible_value())
+            .map(|value| value.get_name().to_string());
+        let database = prompter.answer(
+            given.as_deref(),
+            "Tracker database (sqlite3, mysql)",
+            "--database",
+            Some("sqlite3"),
+            |answer| WizardDatabase::from_str(answer, true),
+        )?;
+
+        match database {
+            WizardDatabase::Sqlite3 => Ok(builder.sqlite("tracker.db")),
+            WizardDatabase::Mysql => {
+                let password = prompter.ask(
+                    "MySQL password (leave empty to generate one)",
+                    "--defaults",
+                    Some(""),
+                    |answer| {
+                        let min_length = MysqlConfigOptions::default().min_password_length;
+                        if !answer.is_empty() && answer.chars().count() < min_length {
+                            return Err(format!(
+                                "the password must have at least {min_length} characters"
+                            ));
+                        }
+                        Ok(answer.to_string())
+                    },
+                )?;
+                let password = if password.is_empty() {
+                    generate_random_password().expose_secret().to_string()
+                } else {
+                    password
+                };
+
+                Ok(builder.mysql("mysql", 3306, "tracker", "tracker_user", password))
+            }
+        }
+    }
+
+    /// Ask for the listener ports until the tracker configuration is valid
+    ///
+    /// Each port is valid on its own; the builder rejects combinations such
+    /// as two services on the same port. The ports are then asked again.
+    ///
+    /// The REST API admin token is generated: it can be changed in the
+    /// configuration file before the environment is created.
+    #[allow(clippy::result_large_err)]
+    fn ask_ports(
+        prompter: &mut Prompter,
+        args: &CreateWizardArgs,
+        builder: &EnvironmentCreationConfigBuilder,
+    ) -> Result<EnvironmentCreationConfig, CreateWizardCommandError> {
+        let questions = [
+            ("UDP tracker port", "--udp-port", "6969", args.udp_port),
+            ("HTTP tracker port", "--http-port", "7070", args.http_port),
+            ("REST API port", "--api-port", "1212", args.api_port),
+        ];
+
+        let admin_token = Alphanumeric.sample_string(&mut rand::rng(), ADMIN_TOKEN_LENGTH);
+
+        let mut ports = Vec::with_capacity(questions.len());
+        for (question, flag, default, given) in questions {
+            let given = given.map(|port| port.to_string());
+            ports.push(prompter.answer(given.as_deref(), question, flag, Some(default), port)?);
+        }
+
+        loop {
+            let [udp, http, api] = [ports[0], ports[1], ports[2]];
+
+            match builder
+                .clone()
+                .udp(format!("0.0.0.0:{udp}"))
+                .http(format!("0.0.0.0:{http}"))
+                .api(format!("0.0.0.0:{api}"), admin_token.as_str())
+                .build()
+            {
+                Ok(config) => return Ok(config),
+                Err(error) => prompter.reject(&error.to_string()),
+            }
+
+            for (index, (question, flag, _, _)) in questions.iter().enumerate() {
+                let current = ports[index].to_string();
+                ports[index] = prompter.ask_again(question, flag, Some(&current), port)?;
+            }
+        }
+    }
+
+    /// Write the configuration to the chosen file, never overwriting one
+    #[allow(clippy::result_large_err)]
+    fn write_configuration(
+        &mut self,
+        config: &EnvironmentCreationConfig,
+        args: &CreateWizardArgs,
+        working_dir: &Path,
+    ) -> Result<PathBuf, CreateWizardCommandError> {
+        self.progress
+            .start_step(CreateWizardStep::WriteConfiguration.description())?;
+
+        let path = args.output.clone().unwrap_or_else(|| {
+            working_dir
+                .join(ENV_FILES_DIR)
+                .join(format!("{}.json", config.environment.name))
+        });
+
+        if path.exists() {
+            return Err(CreateWizardCommandError::ConfigFileExists { path });
+        }
+
+        let write = || -> std::io::Result<()> {
+            if let Some(parent) = path.parent() {
+                std::fs::create_dir_all(parent)?;
+            }
+            let json = serde_json::to_string_pretty(config)?;
+            std::fs::write(&path, json)
+        };
+
+        write().map_err(|source| CreateWizardCommandError::ConfigWriteFailed {
+            path: path.clone(),
+            source,
+        })?;
+
+        self.progress
+            .complete_step(Some(&format!("Configuration written: {}", path.display())))?;
+
+        Ok(path)
+    }
+
+    #[allow(clippy::result_large_err)]
+    fn display_next_steps(&mut self, env_file: &Path) -> Result<(), CreateWizardCommandError> {
+        self.progress.blank_line()?;
+        self.progress.steps(
+            "Next steps:",
+            &[
+                "Review the configuration file (e.g. add HTTPS, monitoring or backups):\n   torrust-tracker-deployer create schema",
+                &format!(
+                    "Create the environment:\n   torrust-tracker-deployer create environment --env-file {}",
+                    env_file.display()
+                ),
+            ],
+        )?;
+
+        self.progress
+            .complete(&format!("Configuration ready: {}", env_file.display()))?;
+
+        Ok(())
+    }
+}
+
+/// Absolute form of `path`, relative to the current directory
+fn absolute(path: &Path) -> PathBuf {
+    std::path::absolute(path).unwrap_or_else(|_| path.to_path_buf())
+}
+
+/// Parse a path to an existing file, expanding `~/` and making it absolute
+///
+/// The configuration requires absolute key paths.
+fn existing_file(answer: &str) -> Result<PathBuf, String> {
+    let path = match (answer.strip_prefix("~/"), std::env::var_os("HOME")) {
+        (Some(rest), Some(home)) => PathBuf::from(home).join(rest),
+        _ => PathBuf::from(answer),
+    };
+    let path = absolute(&path);
+
+    if path.is_file() {
+        Ok(path)
+    } else {
+        Err(format!("file not found: {}", path.display()))
+    }
+}
+
+fn not_empty(answer: &str) -> Result<String, String> {
+    if answer.is_empty() {
+        Err("the value cannot be empty".to_string())
+    } else {
+        Ok(answer.to_string())
+    }
+}
+
+fn port(answer: &str) -> Result<u16, String> {
+    match answer.parse::<u16>() {
+        Ok(0) => Err("port 0 is not allowed".to_string()),
+        Ok(port) => Ok(port),
+        Err(_) => Err(format!("'{answer}' is not a port number (1-65535)")),
+    }
+}
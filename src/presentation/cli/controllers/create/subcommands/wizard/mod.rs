@@ -0,0 +1,14 @@
+//! Interactive Create Wizard Subcommand
+//!
+//! This module handles `create --interactive`, which asks for the environment
+//! settings, writes the configuration file and optionally creates the
+//! environment from it.
+
+pub mod errors;
+pub mod handler;
+pub mod prompt;
+
+// Re-export the controller, error type and prompter
+pub use errors::CreateWizardCommandError;
+pub use handler::CreateWizardCommandController;
+pub use prompt::Prompter;
@@ -0,0 +1,297 @@
+//! Line-based prompts for the interactive create wizard
+//!
+//! Questions are written to stderr and answers read line by line from stdin,
+//! so stdout stays reserved for the command result. An invalid answer is
+//! rejected with the validation message and the question is asked again.
+
+use std::io::{BufRead, Write};
+
+use super::errors::CreateWizardCommandError;
+
+/// Asks questions and reads the answers
+pub struct Prompter {
+    input: Box<dyn BufRead + Send>,
+    output: Box<dyn Write + Send>,
+    accept_defaults: bool,
+}
+
+impl Prompter {
+    /// Prompter reading stdin and writing to stderr
+    ///
+    /// With `accept_defaults` every question with a valid default is answered
+    /// with it without being shown.
+    #[must_use]
+    pub fn stdio(accept_defaults: bool) -> Self {
+        Self::new(
+            Box::new(std::io::BufReader::new(std::io::stdin())),
+            Box::new(std::io::stderr()),
+            accept_defaults,
+        )
+    }
+
+    /// Prompter with custom input and output (for testing)
+    #[must_use]
+    pub fn new(
+        input: Box<dyn BufRead + Send>,
+        output: Box<dyn Write + Send>,
+        accept_defaults: bool,
+    ) -> Self {
+        Self {
+            input,
+            output,
+            accept_defaults,
+        }
+    }
+
+    /// Ask a question until `parse` accepts the answer
+    ///
+    /// An empty answer selects `default`. An empty default is not displayed,
+    /// which lets optional questions describe their empty answer in the
+    /// question itself.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if stdin ends or cannot be read. The error names
+    /// `flag`, which answers the question without a prompt.
+    pub fn ask<T>(
+        &mut self,
+        question: &str,
+        flag: &str,
+        default: Option<&str>,
+        parse: impl Fn(&str) -> Result<T, String>,
+    ) -> Result<T, CreateWizardCommandError> {
+        if self.accept_defaults {
+            if let Some(Ok(value)) = default.map(&parse) {
+                return Ok(value);
+            }
+        }
+
+        loop {
+            let answer = self.read_answer(question, flag, default)?;
+
+            let answer = match (answer.as_str(), default) {
+                ("", Some(default)) => default.to_string(),
+                ("", None) => {
+                    self.reject("an answer is required");
+                    continue;
+                }
+                _ => answer,
+            };
+
+            match parse(&answer) {
+                Ok(value) => return Ok(value),
+                Err(reason) => self.reject(&reason),
+            }
+        }
+    }
+
+    /// Use the value given with `flag`, or ask when there is none
+    ///
+    /// A given value that `parse` rejects is reported and the question is
+    /// asked instead.
+    ///
+    /// # Errors
+    ///
+    /// Same as [`Prompter::ask`].
+    pub fn answer<T>(
+        &mut self,
+        given: Option<&str>,
+        question: &str,
+        flag: &str,
+        default: Option<&str>,
+        parse: impl Fn(&str) -> Result<T, String>,
+    ) -> Result<T, CreateWizardCommandError> {
+        if let Some(given) = given {
+            match parse(given) {
+                Ok(value) => return Ok(value),
+                Err(reason) => self.reject(&format!("{flag}: {reason}")),
+            }
+        }
+
+        self.ask(question, flag, default, parse)
+    }
+
+    /// Ask a question even when defaults are accepted
+    ///
+    /// Used to correct answers that are valid one by one but not together.
+    ///
+    /// # Errors
+    ///
+    /// Same as [`Prompter::ask`].
+    pub fn ask_again<T>(
+        &mut self,
+        question: &str,
+        flag: &str,
+        default: Option<&str>,
+        parse: impl Fn(&str) -> Result<T, String>,
+    ) -> Result<T, CreateWizardCommandError> {
+        let accept_defaults = std::mem::replace(&mut self.accept_defaults, false);
+        let result = self.ask(question, flag, default, parse);
+        self.accept_defaults = accept_defaults;
+        result
+    }
+
+    /// Ask a yes/no question
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if stdin ends or cannot be read.
+    pub fn confirm(
+        &mut self,
+        question: &str,
+        flag: &str,
+        default: bool,
+    ) -> Result<bool, CreateWizardCommandError> {
+        let default = if default { "yes" } else { "no" };
+
+        self.ask(question, flag, Some(default), |answer| {
+            match answer.to_lowercase().as_str() {
+                "y" | "yes" => Ok(true),
+                "n" | "no" => Ok(false),
+                _ => Err("answer 'yes' or 'no'".to_string()),
+            }
+        })
+    }
+
+    /// Show an informational line between questions
+    pub fn note(&mut self, message: &str) {
+        // Prompts are best effort: a closed stderr must not abort the wizard
+        writeln!(self.output, "{message}").ok();
+    }
+
+    fn read_answer(
+        &mut self,
+        question: &str,
+        flag: &str,
+        default: Option<&str>,
+    ) -> Result<String, CreateWizardCommandError> {
+        match default {
+            Some(default) if !default.is_empty() => write!(self.output, "{question} [{default}]: "),
+            _ => write!(self.output, "{question}: "),
+        }
+        .ok();
+        self.output.flush().ok();
+
+        let mut line = String::new();
+        let bytes = self
+            .input
+            .read_line(&mut line)
+            .map_err(|source| CreateWizardCommandError::InputReadFailed { source })?;
+
+        if bytes == 0 {
+            writeln!(self.output).ok();
+            return Err(CreateWizardCommandError::InputEnded {
+                question: question.to_string(),
+                flag: flag.to_string(),
+            });
+        }
+
+        Ok(line.trim().to_string())
+    }
+
+    /// Report a rejected answer
+    pub fn reject(&mut self, reason: &str) {
+        writeln!(self.output, "  ✗ {reason}").ok();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::io::Cursor;
+    use std::sync::{Arc, Mutex};
+
+    use super::*;
+
+    /// Writer whose content stays readable after the prompter took it
+    #[derive(Clone, Default)]
+    struct SharedOutput(Arc<Mutex<Vec<u8>>>);
+
+    impl Write for SharedOutput {
+        fn write(&mut self, buf: &[u8]) -> std::io::Result<usize> {
+            self.0.lock().unwrap().write(buf)
+        }
+
+        fn flush(&mut self) -> std::io::Result<()> {
+            Ok(())
+        }
+    }
+
+    impl SharedOutput {
+        fn content(&self) -> String {
+            String::from_utf8(self.0.lock().unwrap().clone()).unwrap()
+        }
+    }
+
+    fn prompter(input: &str, accept_defaults: bool) -> (Prompter, SharedOutput) {
+        let output = SharedOutput::default();
+        let prompter = Prompter::new(
+            Box::new(Cursor::new(input.to_string())),
+            Box::new(output.clone()),
+            accept_defaults,
+        );
+        (prompter, output)
+    }
+
+    fn parse_port(answer: &str) -> Result<u16, String> {
+        answer
+            .parse()
+            .map_err(|_| format!("'{answer}' is not a port"))
+    }
+
+    #[test]
+    fn it_should_ask_again_after_an_invalid_answer() {
+        let (mut prompter, output) = prompter("abc\n7070\n", false);
+
+        let port = prompter.ask("Port", "--port", None, parse_port).unwrap();
+
+        assert_eq!(port, 7070);
+        assert!(output.content().contains("✗ 'abc' is not a port"));
+    }
+
+    #[test]
+    fn it_should_use_the_default_for_an_empty_answer() {
+        let (mut prompter, output) = prompter("\n", false);
+
+        let port = prompter
+            .ask("Port", "--port", Some("6969"), parse_port)
+            .unwrap();
+
+        assert_eq!(port, 6969);
+        assert!(output.content().contains("Port [6969]: "));
+    }
+
+    #[test]
+    fn it_should_not_ask_when_accepting_defaults() {
+        let (mut prompter, output) = prompter("", true);
+
+        let port = prompter
+            .ask("Port", "--port", Some("6969"), parse_port)
+            .unwrap();
+
+        assert_eq!(port, 6969);
+        assert_eq!(output.content(), "");
+    }
+
+    #[test]
+    fn it_should_name_the_flag_when_input_ends() {
+        let (mut prompter, _) = prompter("", true);
+
+        let result = prompter.ask("Environment name", "--name", None, |name| {
+            Ok::<_, String>(name.to_string())
+        });
+
+        assert!(matches!(
+            result,
+            Err(CreateWizardCommandError::InputEnded { flag, .. }) if flag == "--name"
+        ));
+    }
+
+    #[test]
+    fn it_should_read_yes_and_no_answers() {
+        let (mut prompter, _) = prompter("maybe\nN\n", false);
+
+        let answer = prompter.confirm("Continue?", "--no-create", true).unwrap();
+
+        assert!(!answer);
+    }
+}
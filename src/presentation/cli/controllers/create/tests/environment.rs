@@ -20,6 +20,7 @@ async fn handle_environment_creation(
 ) -> Result<(), create::CreateCommandError> {
     let action = CreateAction::Environment {
         env_file: config_path.to_path_buf(),
+        generate_ssh_keys: false,
     };
     let container = Container::new(VerbosityLevel::Silent, working_dir);
     let global_args = default_global_args(working_dir);
@@ -127,7 +128,7 @@ async fn it_should_reject_missing_ssh_keys() {
 }
 
 #[tokio::test]
-async fn it_should_reject_duplicate_environment() {
+async fn it_should_accept_duplicate_environment_with_the_same_configuration() {
     let context = TestContext::new();
     let config_path = create_valid_config(context.working_dir(), "duplicate-test-env");
 
@@ -135,18 +136,12 @@ async fn it_should_reject_duplicate_environment() {
     let result1 = handle_environment_creation(&config_path, context.working_dir()).await;
     assert!(result1.is_ok(), "First create should succeed");
 
-    // Try to create same environment again
+    // Create same environment again
     let result2 = handle_environment_creation(&config_path, context.working_dir()).await;
-    assert!(result2.is_err(), "Second create should fail");
-
-    match result2.unwrap_err() {
-        create::CreateCommandError::Environment(
-            create::CreateEnvironmentCommandError::CommandFailed { .. },
-        ) => {
-            // Expected - environment already exists
-        }
-        other => panic!("Expected CommandFailed, got: {other:?}"),
-    }
+    assert!(
+        result2.is_ok(),
+        "Second create with the same configuration should succeed: {result2:?}"
+    );
 }
 
 #[tokio::test]
@@ -3,6 +3,8 @@
 //! This module contains tests for the create command presentation layer:
 //! - `environment` - Tests for environment creation functionality
 //! - `template` - Tests for configuration template generation
+//! - `wizard` - Tests for the interactive create wizard
 
 pub mod environment;
 pub mod template;
+pub mod wizard;
@@ -0,0 +1,200 @@
+//! Interactive Create Wizard Tests
+//!
+//! This module tests `create --interactive`: answers given as flags, answers
+//! read from the prompt, and the configuration file written by the wizard.
+
+use std::io::Cursor;
+use std::path::{Path, PathBuf};
+
+use crate::application::command_handlers::create::config::EnvironmentCreationConfig;
+use crate::bootstrap::Container;
+use crate::domain::provider::Provider;
+use crate::presentation::cli::controllers::create;
+use crate::presentation::cli::controllers::create::subcommands::wizard::Prompter;
+use crate::presentation::cli::controllers::tests::{default_global_args, TestContext};
+use crate::presentation::cli::dispatch::ExecutionContext;
+use crate::presentation::cli::input::cli::{CreateWizardArgs, OutputFormat};
+use crate::presentation::cli::views::VerbosityLevel;
+
+fn fixture_private_key() -> PathBuf {
+    PathBuf::from(env!("CARGO_MANIFEST_DIR")).join("fixtures/testing_rsa")
+}
+
+/// Wizard flags answering every question that has no default
+fn wizard_args(name: &str) -> CreateWizardArgs {
+    CreateWizardArgs {
+        interactive: true,
+        defaults: true,
+        name: Some(name.to_string()),
+        ssh_private_key: Some(fixture_private_key()),
+        provider: Some(Provider::Lxd),
+        ..CreateWizardArgs::default()
+    }
+}
+
+async fn handle_wizard(
+    args: CreateWizardArgs,
+    working_dir: &Path,
+) -> Result<(), create::CreateCommandError> {
+    let container = Container::new(VerbosityLevel::Silent, working_dir);
+    let global_args = default_global_args(working_dir);
+    let context = ExecutionContext::new(std::sync::Arc::new(container), global_args);
+    create::route_wizard(args, working_dir, &context).await
+}
+
+/// Run the wizard answering the prompts with `answers`
+async fn handle_wizard_with_answers(
+    args: &CreateWizardArgs,
+    answers: &str,
+    working_dir: &Path,
+) -> Result<(), create::CreateWizardCommandError> {
+    let container = Container::new(VerbosityLevel::Silent, working_dir);
+    let mut prompter = Prompter::new(
+        Box::new(Cursor::new(answers.to_string())),
+        Box::new(std::io::sink()),
+        args.defaults,
+    );
+    container
+        .create_wizard_controller()
+        .execute_with(&mut prompter, args, working_dir, OutputFormat::Text)
+        .await
+        .map(|_| ())
+}
+
+fn read_config(path: &Path) -> EnvironmentCreationConfig {
+    serde_json::from_str(&std::fs::read_to_string(path).unwrap()).unwrap()
+}
+
+#[tokio::test]
+async fn it_should_write_the_configuration_and_create_the_environment() {
+    let context = TestContext::new();
+
+    let result = handle_wizard(wizard_args("wizard-env"), context.working_dir()).await;
+
+    assert!(result.is_ok(), "Wizard should succeed: {:?}", result.err());
+    let config = read_config(&context.working_dir().join("envs/wizard-env.json"));
+    assert_eq!(config.environment.name, "wizard-env");
+    assert!(context
+        .working_dir()
+        .join("data/wizard-env/environment.json")
+        .exists());
+}
+
+#[tokio::test]
+async fn it_should_only_write_the_configuration_with_no_create() {
+    let context = TestContext::new();
+    let output = context.working_dir().join("custom/wizard-env.json");
+    let args = CreateWizardArgs {
+        no_create: true,
+        output: Some(output.clone()),
+        ..wizard_args("wizard-env")
+    };
+
+    let result = handle_wizard(args, context.working_dir()).await;
+
+    assert!(result.is_ok(), "Wizard should succeed: {:?}", result.err());
+    assert!(output.exists());
+    assert!(!context.working_dir().join("data/wizard-env").exists());
+}
+
+#[tokio::test]
+async fn it_should_not_overwrite_an_existing_configuration_file() {
+    let context = TestContext::new();
+    let output = context.working_dir().join("existing.json");
+    std::fs::write(&output, "{}").unwrap();
+    let args = CreateWizardArgs {
+        output: Some(output.clone()),
+        ..wizard_args("wizard-env")
+    };
+
+    let result = handle_wizard(args, context.working_dir()).await;
+
+    assert!(matches!(
+        result,
+        Err(create::CreateCommandError::Wizard(
+            create::CreateWizardCommandError::ConfigFileExists { .. }
+        ))
+    ));
+    assert_eq!(std::fs::read_to_string(&output).unwrap(), "{}");
+}
+
+#[tokio::test]
+async fn it_should_ask_again_for_an_invalid_environment_name() {
+    let context = TestContext::new();
+    let args = CreateWizardArgs {
+        name: Some("Invalid_Name".to_string()),
+        no_create: true,
+        ..wizard_args("unused")
+    };
+
+    let result = handle_wizard_with_answers(&args, "valid-name\n", context.working_dir()).await;
+
+    assert!(result.is_ok(), "Wizard should succeed: {:?}", result.err());
+    assert!(context.working_dir().join("envs/valid-name.json").exists());
+}
+
+#[tokio::test]
+async fn it_should_ask_again_for_conflicting_ports() {
+    let context = TestContext::new();
+    let args = CreateWizardArgs {
+        http_port: Some(1212),
+        no_create: true,
+        ..wizard_args("wizard-env")
+    };
+
+    // UDP, HTTP and API ports asked again, keeping UDP and API
+    let result = handle_wizard_with_answers(&args, "\n7070\n\n", context.working_dir()).await;
+
+    assert!(result.is_ok(), "Wizard should succeed: {:?}", result.err());
+    let config = read_config(&context.working_dir().join("envs/wizard-env.json"));
+    assert_eq!(config.tracker.http_trackers[0].bind_address, "0.0.0.0:7070");
+}
+
+#[tokio::test]
+async fn it_should_fail_when_input_ends_before_a_required_answer() {
+    let context = TestContext::new();
+    let args = CreateWizardArgs {
+        name: None,
+        ..wizard_args("unused")
+    };
+
+    let result = handle_wizard_with_answers(&args, "", context.working_dir()).await;
+
+    assert!(matches!(
+        result,
+        Err(create::CreateWizardCommandError::InputEnded { flag, .. }) if flag == "--name"
+    ));
+}
+
+#[tokio::test]
+async fn it_should_write_a_digitalocean_configuration() {
+    let context = TestContext::new();
+    let args = CreateWizardArgs {
+        provider: Some(Provider::DigitalOcean),
+        digitalocean_api_token: Some("do-token".to_string()),
+        no_create: true,
+        ..wizard_args("do-env")
+    };
+
+    let result = handle_wizard(args, context.working_dir()).await;
+
+    assert!(result.is_ok(), "Wizard should succeed: {:?}", result.err());
+    let config = read_config(&context.working_dir().join("envs/do-env.json"));
+    assert_eq!(config.provider.provider(), Provider::DigitalOcean);
+}
+
+#[tokio::test]
+async fn it_should_write_a_libvirt_configuration_with_the_default_pool_network_and_image() {
+    let context = TestContext::new();
+    let args = CreateWizardArgs {
+        provider: Some(Provider::Libvirt),
+        no_create: true,
+        ..wizard_args("kvm-env")
+    };
+
+    let result = handle_wizard(args, context.working_dir()).await;
+
+    assert!(result.is_ok(), "Wizard should succeed: {:?}", result.err());
+    let config = read_config(&context.working_dir().join("envs/kvm-env.json"));
+    assert_eq!(config.provider.provider(), Provider::Libvirt);
+}
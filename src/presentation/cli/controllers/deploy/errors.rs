@@ -0,0 +1,98 @@
+//! Error types for the Deploy Subcommand
+//!
+//! This module defines error types that can occur during CLI deploy command
+//! execution. All errors provide actionable messages through `.help()`.
+
+use thiserror::Error;
+
+use crate::application::command_handlers::deploy::DeployCommandHandlerError;
+use crate::domain::environment::name::EnvironmentNameError;
+use crate::presentation::cli::views::progress::ProgressReporterError;
+use crate::presentation::cli::views::ViewRenderError;
+
+/// Deploy command specific errors
+#[derive(Debug, Error)]
+pub enum DeploySubcommandError {
+    /// Environment name validation failed
+    #[error("Invalid environment name '{name}': {source}
+Tip: Environment names must be 1-63 characters, start with letter/digit, contain only letters/digits/hyphens")]
+    InvalidEnvironmentName {
+        name: String,
+        #[source]
+        source: EnvironmentNameError,
+    },
+
+    /// Progress reporting failed
+    #[error(
+        "Failed to report progress: {source}
+Tip: This is a critical bug - please report it with full logs using --log-output file-and-stderr"
+    )]
+    ProgressReportingFailed {
+        #[source]
+        source: ProgressReporterError,
+    },
+
+    /// Application layer error during the deployment
+    #[error("Deploy command failed: {source}")]
+    ApplicationLayerError {
+        #[source]
+        source: DeployCommandHandlerError,
+    },
+
+    /// Output formatting failed (JSON serialization error).
+    #[error(
+        "Failed to format output: {reason}\nTip: This is a critical bug - please report it with full logs using --log-output file-and-stderr"
+    )]
+    OutputFormatting { reason: String },
+}
+
+impl From<ProgressReporterError> for DeploySubcommandError {
+    fn from(source: ProgressReporterError) -> Self {
+        Self::ProgressReportingFailed { source }
+    }
+}
+
+impl From<ViewRenderError> for DeploySubcommandError {
+    fn from(e: ViewRenderError) -> Self {
+        Self::OutputFormatting {
+            reason: e.to_string(),
+        }
+    }
+}
+
+impl DeploySubcommandError {
+    /// Get detailed troubleshooting guidance for this error
+    #[must_use]
+    pub fn help(&self) -> &'static str {
+        match self {
+            Self::InvalidEnvironmentName { .. } => {
+                "Invalid Environment Name - Detailed Troubleshooting:
+
+1. Check environment name format:
+   - Length: Must be 1-63 characters
+   - Start: Must begin with letter (a-z, A-Z) or digit (0-9)
+   - Characters: Only letters, digits, and hyphens allowed
+   - End: Must not end with a hyphen
+
+For more information, see the environment naming conventions in the documentation."
+            }
+            Self::ProgressReportingFailed { .. } => {
+                "Progress Reporting Failed - Critical Internal Error:
+
+This error should not occur during normal operation. It indicates a bug
+in the progress reporting system.
+
+Please report it with full logs using --log-output file-and-stderr."
+            }
+            Self::ApplicationLayerError { source } => source.help(),
+            Self::OutputFormatting { .. } => {
+                "Output Formatting Failed - Critical Internal Error:
+
+This error should not occur during normal operation. It indicates a bug
+in the output formatting system.
+
+Please report it with full logs using --log-output file-and-stderr."
+            }
+        }
+    }
+}
@@ -0,0 +1,267 @@
+//! Deploy Command Handler
+//!
+//! This module handles the deploy command execution at the presentation
+//! layer, including environment name validation and user interaction.
+
+use std::cell::RefCell;
+use std::path::PathBuf;
+use std::sync::Arc;
+
+use parking_lot::ReentrantMutex;
+use tokio_util::sync::CancellationToken;
+use torrust_tracker_deployer_dependency_installer::Dependency;
+use tracing::info;
+
+use crate::application::command_handlers::deploy::{
+    DeployCommandHandler, DeployOutcome, DeployTarget,
+};
+use crate::application::services::deployment_report::DeploymentReportListener;
+use crate::domain::environment::name::EnvironmentName;
+use crate::domain::environment::repository::EnvironmentRepository;
+use crate::domain::environment::DiskSpaceThresholds;
+use crate::presentation::cli::input::cli::OutputFormat;
+use crate::presentation::cli::views::commands::deploy::{DeployDetailsData, JsonView, TextView};
+use crate::presentation::cli::views::progress::{ProgressReporter, VerboseProgressListener};
+use crate::presentation::cli::views::Render;
+use crate::presentation::cli::views::UserOutput;
+use crate::shared::clock::Clock;
+use crate::shared::command::run_log::DEFAULT_KEPT_RUNS;
+
+use super::errors::DeploySubcommandError;
+
+/// Steps in the deploy workflow
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum DeployStep {
+    ValidateEnvironment,
+    DeployEnvironment,
+}
+
+impl DeployStep {
+    /// All steps in execution order
+    const ALL: &'static [Self] = &[Self::ValidateEnvironment, Self::DeployEnvironment];
+
+    /// Total number of steps
+    const fn count() -> usize {
+        Self::ALL.len()
+    }
+
+    /// User-facing description for the step
+    fn description(self) -> &'static str {
+        match self {
+            Self::ValidateEnvironment => "Validating environment",
+            Self::DeployEnvironment => "Deploying environment",
+        }
+    }
+}
+
+/// Presentation layer controller for the deploy command workflow
+///
+/// Validates the environment name, reports progress and delegates to the
+/// application layer `DeployCommandHandler`. Each deployment phase is shown
+/// as a step by the verbose progress listener (`-v`).
+pub struct DeployCommandController {
+    repository: Arc<dyn EnvironmentRepository + Send + Sync>,
+    clock: Arc<dyn Clock>,
+    progress: ProgressReporter,
+    cancellation: CancellationToken,
+    wait_for_health: bool,
+    kept_run_logs: usize,
+    trace_dir: Option<PathBuf>,
+    disk_space_thresholds: DiskSpaceThresholds,
+}
+
+impl DeployCommandController {
+    /// Create a new deploy command controller
+    #[allow(clippy::needless_pass_by_value)] // Constructor takes ownership of Arc parameters
+    pub fn new(
+        repository: Arc<dyn EnvironmentRepository + Send + Sync>,
+        clock: Arc<dyn Clock>,
+        user_output: Arc<ReentrantMutex<RefCell<UserOutput>>>,
+    ) -> Self {
+        let progress = ProgressReporter::new(user_output, DeployStep::count());
+
+        Self {
+            repository,
+            clock,
+            progress,
+            cancellation: CancellationToken::new(),
+            wait_for_health: true,
+            kept_run_logs: DEFAULT_KEPT_RUNS,
+            trace_dir: None,
+            disk_space_thresholds: DiskSpaceThresholds::default(),
+        }
+    }
+
+    /// Keep the output logs of the last `runs` runs of the command
+    #[must_use]
+    pub fn with_kept_run_logs(mut self, runs: usize) -> Self {
+        self.kept_run_logs = runs;
+        self
+    }
+
+    /// Write the trace files of a failure to `trace_dir` (`--trace-dir`)
+    #[must_use]
+    pub fn with_trace_dir(mut self, trace_dir: Option<PathBuf>) -> Self {
+        self.trace_dir = trace_dir;
+        self
+    }
+
+    /// Require the given free disk space before provisioning and releasing
+    #[must_use]
+    pub fn with_disk_space_thresholds(mut self, thresholds: DiskSpaceThresholds) -> Self {
+        self.disk_space_thresholds = thresholds;
+        self
+    }
+
+    /// Cancel the workflow when the given token is cancelled (e.g. on Ctrl-C)
+    #[must_use]
+    pub fn with_cancellation(mut self, cancellation: CancellationToken) -> Self {
+        self.cancellation = cancellation;
+        self
+    }
+
+    /// Wait for the tracker to report healthy in the run phase (the default)
+    #[must_use]
+    pub fn with_wait_for_health(mut self, wait_for_health: bool) -> Self {
+        self.wait_for_health = wait_for_health;
+        self
+    }
+
+    /// Execute the complete deploy workflow
+    ///
+    /// # Arguments
+    ///
+    /// * `environment_name` - The name of the environment to deploy
+    /// * `until` - The state at which the deployment stops
+    /// * `output_format` - Output format (Text or Json)
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if:
+    /// - Environment name is invalid (format validation fails)
+    /// - The environment cannot resume a deployment from its current state
+    /// - A deployment phase fails
+    #[allow(clippy::result_large_err)]
+    pub async fn execute(
+        &mut self,
+        environment_name: &str,
+        until: DeployTarget,
+        output_format: OutputFormat,
+    ) -> Result<(), DeploySubcommandError> {
+        self.progress.set_output_format(output_format);
+        let result = self
+            .execute_steps(environment_name, until, output_format)
+            .await;
+        self.progress.finish(result)
+    }
+
+    /// Steps of [`Self::execute`], before the timing summary
+    #[allow(clippy::result_large_err)]
+    async fn execute_steps(
+        &mut self,
+        environment_name: &str,
+        until: DeployTarget,
+        output_format: OutputFormat,
+    ) -> Result<(), DeploySubcommandError> {
+        let env_name = self.validate_environment_name(environment_name)?;
+
+        let outcome = self.deploy_environment(&env_name, until).await?;
+
+        self.complete_workflow(until, &outcome, output_format)?;
+
+        Ok(())
+    }
+
+    /// Validate the environment name format
+    #[allow(clippy::result_large_err)]
+    fn validate_environment_name(
+        &mut self,
+        name: &str,
+    ) -> Result<EnvironmentName, DeploySubcommandError> {
+        self.progress
+            .start_step(DeployStep::ValidateEnvironment.description())?;
+
+        let env_name = EnvironmentName::new(name.to_string()).map_err(|source| {
+            DeploySubcommandError::InvalidEnvironmentName {
+                name: name.to_string(),
+                source,
+            }
+        })?;
+
+        self.progress
+            .complete_step(Some(&format!("Environment name validated: {name}")))?;
+
+        Ok(env_name)
+    }
+
+    /// Run the pending deployment phases through the application layer handler
+    #[allow(clippy::result_large_err)]
+    async fn deploy_environment(
+        &mut self,
+        env_name: &EnvironmentName,
+        until: DeployTarget,
+    ) -> Result<DeployOutcome, DeploySubcommandError> {
+        self.progress
+            .start_step(DeployStep::DeployEnvironment.description())?;
+
+        let handler = DeployCommandHandler::new(self.repository.clone(), self.clock.clone())
+            .with_kept_run_logs(self.kept_run_logs)
+            .with_trace_dir(self.trace_dir.clone())
+            .with_disk_space_thresholds(self.disk_space_thresholds)
+            .with_cancellation(self.cancellation.clone())
+            .with_wait_for_health(self.wait_for_health);
+
+        let listener = VerboseProgressListener::new(self.progress.output().clone());
+        let recorder =
+            DeploymentReportListener::new("deploy", env_name, self.clock.clone(), Some(&listener));
+
+        let result = handler.execute(env_name, until, Some(&recorder)).await;
+        let report = recorder.write(
+            self.repository.as_ref(),
+            &[Dependency::OpenTofu, Dependency::Ansible],
+            result.as_ref().err(),
+        );
+        if let Some(trace_file) = report.and_then(|report| report.trace_file) {
+            self.progress
+                .warn(&format!("Trace file: {}", trace_file.display()))?;
+        }
+
+        let outcome =
+            result.map_err(|source| DeploySubcommandError::ApplicationLayerError { source })?;
+
+        info!(
+            environment = %env_name,
+            initial_state = outcome.initial_state,
+            final_state = outcome.environment.state_name(),
+            phases = outcome.performed.len(),
+            "Deployment completed successfully"
+        );
+
+        self.progress.complete_step(Some(&format!(
+            "Environment is {}",
+            outcome.environment.state_name()
+        )))?;
+
+        Ok(outcome)
+    }
+
+    /// Render the deployment details
+    #[allow(clippy::result_large_err)]
+    fn complete_workflow(
+        &mut self,
+        until: DeployTarget,
+        outcome: &DeployOutcome,
+        output_format: OutputFormat,
+    ) -> Result<(), DeploySubcommandError> {
+        let details = DeployDetailsData::from_outcome(until, outcome);
+
+        let output = match output_format {
+            OutputFormat::Text => TextView::render(&details)?,
+            OutputFormat::Json => JsonView::render(&details)?,
+        };
+
+        self.progress.result(&output)?;
+
+        Ok(())
+    }
+}
@@ -0,0 +1,20 @@
+//! Deploy Command Presentation Module
+//!
+//! This module implements the CLI presentation layer for the deploy command,
+//! which runs the provision, configure, release and run phases with a single
+//! command, resuming from the current state of the environment.
+//!
+//! ## Components
+//!
+//! - `errors` - Presentation layer error types with `.help()` methods
+//! - `handler` - Main command handler orchestrating the workflow
+
+pub mod errors;
+pub mod handler;
+pub use handler::DeployCommandController;
+
+#[cfg(test)]
+mod tests;
+
+// Re-export commonly used types for convenience
+pub use errors::DeploySubcommandError;
@@ -0,0 +1,69 @@
+//! Tests for the Deploy Command Controller
+
+use std::cell::RefCell;
+use std::sync::Arc;
+
+use parking_lot::ReentrantMutex;
+use tempfile::TempDir;
+
+use crate::application::command_handlers::deploy::{DeployCommandHandlerError, DeployTarget};
+use crate::domain::environment::repository::EnvironmentRepository;
+use crate::infrastructure::persistence::file_repository_factory::FileRepositoryFactory;
+use crate::presentation::cli::controllers::constants::DEFAULT_LOCK_TIMEOUT;
+use crate::presentation::cli::controllers::deploy::errors::DeploySubcommandError;
+use crate::presentation::cli::controllers::deploy::handler::DeployCommandController;
+use crate::presentation::cli::input::cli::OutputFormat;
+use crate::presentation::cli::views::testing::TestUserOutput;
+use crate::presentation::cli::views::{UserOutput, VerbosityLevel};
+use crate::shared::clock::Clock;
+use crate::shared::SystemClock;
+
+/// Create test dependencies for deploy command controller tests
+#[allow(clippy::type_complexity)]
+fn create_test_dependencies(
+    temp_dir: &TempDir,
+) -> (
+    Arc<ReentrantMutex<RefCell<UserOutput>>>,
+    Arc<dyn EnvironmentRepository + Send + Sync>,
+    Arc<dyn Clock>,
+) {
+    let (user_output, _, _) = TestUserOutput::new(VerbosityLevel::Normal).into_reentrant_wrapped();
+    let data_dir = temp_dir.path().join("data");
+    let file_repository_factory = FileRepositoryFactory::new(DEFAULT_LOCK_TIMEOUT);
+    let repository = file_repository_factory.create(data_dir);
+    let clock = Arc::new(SystemClock);
+
+    (user_output, repository, clock)
+}
+
+#[tokio::test]
+async fn it_should_reject_an_invalid_environment_name() {
+    let temp_dir = TempDir::new().unwrap();
+    let (user_output, repository, clock) = create_test_dependencies(&temp_dir);
+
+    let result = DeployCommandController::new(repository, clock, user_output)
+        .execute("invalid_name", DeployTarget::Running, OutputFormat::Text)
+        .await;
+
+    assert!(matches!(
+        result,
+        Err(DeploySubcommandError::InvalidEnvironmentName { .. })
+    ));
+}
+
+#[tokio::test]
+async fn it_should_fail_when_the_environment_does_not_exist() {
+    let temp_dir = TempDir::new().unwrap();
+    let (user_output, repository, clock) = create_test_dependencies(&temp_dir);
+
+    let result = DeployCommandController::new(repository, clock, user_output)
+        .execute("test-env", DeployTarget::Running, OutputFormat::Text)
+        .await;
+
+    assert!(matches!(
+        result,
+        Err(DeploySubcommandError::ApplicationLayerError {
+            source: DeployCommandHandlerError::EnvironmentNotFound { .. }
+        })
+    ));
+}
@@ -67,6 +67,32 @@ Tip: Check logs and try running with --log-output file-and-stderr for more detai
         source: DestroyCommandHandlerError,
     },
 
+    // ===== Bulk Destroy Errors =====
+    /// The environments matching `--label` could not be listed
+    #[error(
+        "Failed to select the environments with the labels '{labels}': {reason}
+Tip: Check the selection with 'list --label <key=value>'"
+    )]
+    EnvironmentSelectionFailed { labels: String, reason: String },
+
+    /// `--label` selected environments but `--yes` was not given
+    #[error(
+        "Refusing to destroy {count} environments with the labels '{labels}' without --yes: {environments}
+Tip: Re-run with --yes to destroy them"
+    )]
+    ConfirmationRequired {
+        labels: String,
+        count: usize,
+        environments: String,
+    },
+
+    /// Some of the environments selected with `--label` were not destroyed
+    #[error(
+        "Failed to destroy {failed} of {total} environments
+Tip: The report above lists the error of each environment"
+    )]
+    BulkDestroyFailed { failed: usize, total: usize },
+
     // ===== Internal Errors =====
     /// Progress reporting failed
     ///
@@ -275,6 +301,47 @@ For persistent issues, check the infrastructure documentation."
 If the problem persists, check system logs and contact administrator."
             }
 
+            Self::EnvironmentSelectionFailed { .. } => {
+                "Environment Selection Failed - Detailed Troubleshooting:
+
+1. Check that the data directory exists and is readable:
+   ls -la data/
+
+2. Preview the selection:
+   torrust-tracker-deployer list --label purpose=e2e
+
+For more information, see docs/user-guide/commands/destroy.md"
+            }
+
+            Self::ConfirmationRequired { .. } => {
+                "Confirmation Required - Detailed Troubleshooting:
+
+Destroying by label can remove many environments at once, so the selected
+environments are only listed until the command is confirmed.
+
+1. Check that the listed environments are the ones to destroy
+2. Re-run the command with --yes:
+   torrust-tracker-deployer destroy --label purpose=e2e --yes
+
+For more information, see docs/user-guide/commands/destroy.md"
+            }
+
+            Self::BulkDestroyFailed { .. } => {
+                "Bulk Destroy Failed - Detailed Troubleshooting:
+
+The other environments were destroyed: only the ones reported with an error
+are left.
+
+1. Retry a failed environment on its own to see the full error:
+   torrust-tracker-deployer destroy <env-name>
+
+2. Re-running the bulk destroy only retries the environments that are not
+   destroyed yet:
+   torrust-tracker-deployer destroy --label purpose=e2e --yes
+
+For more information, see docs/user-guide/commands/destroy.md"
+            }
+
             Self::ProgressReportingFailed { .. } => {
                 "Progress Reporting Failed - Critical Internal Error:
 
@@ -367,6 +434,19 @@ mod tests {
                 data_dir: "/tmp".to_string(),
                 reason: "permission denied".to_string(),
             },
+            DestroySubcommandError::EnvironmentSelectionFailed {
+                labels: "purpose=e2e".to_string(),
+                reason: "data directory not found".to_string(),
+            },
+            DestroySubcommandError::ConfirmationRequired {
+                labels: "purpose=e2e".to_string(),
+                count: 2,
+                environments: "e2e-a, e2e-b".to_string(),
+            },
+            DestroySubcommandError::BulkDestroyFailed {
+                failed: 1,
+                total: 2,
+            },
         ];
 
         for error in errors {
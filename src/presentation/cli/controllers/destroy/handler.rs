@@ -4,21 +4,37 @@
 //! including environment validation, repository initialization, and user interaction.
 
 use std::cell::RefCell;
+use std::num::NonZeroUsize;
 use std::sync::Arc;
 
 use parking_lot::ReentrantMutex;
-
-use crate::application::command_handlers::DestroyCommandHandler;
+use torrust_tracker_deployer_dependency_installer::Dependency;
+
+use crate::application::command_handlers::common::{DryRunReport, MultiEnvironmentRunner};
+use crate::application::command_handlers::destroy::DestroyCommandHandlerError;
+use crate::application::command_handlers::list::ListFilter;
+use crate::application::command_handlers::{DestroyCommandHandler, ListCommandHandler};
+use crate::application::services::deployment_report::DeploymentReportListener;
+use crate::application::traits::CommandProgressListener;
 use crate::domain::environment::name::EnvironmentName;
 use crate::domain::environment::repository::EnvironmentRepository;
 use crate::domain::environment::state::Destroyed;
-use crate::domain::environment::Environment;
+use crate::domain::environment::{Environment, Label};
 use crate::presentation::cli::input::cli::OutputFormat;
 use crate::presentation::cli::views::commands::destroy::{DestroyDetailsData, JsonView, TextView};
-use crate::presentation::cli::views::progress::ProgressReporter;
+use crate::presentation::cli::views::commands::shared::dry_run_report::{
+    DryRunReportData, DryRunReportJsonView, DryRunReportTextView,
+};
+use crate::presentation::cli::views::commands::shared::multi_environment_report::{
+    MultiEnvironmentReportData, MultiEnvironmentReportJsonView, MultiEnvironmentReportTextView,
+};
+use crate::presentation::cli::views::progress::{
+    MultiEnvironmentProgressListener, ProgressReporter, VerboseProgressListener,
+};
 use crate::presentation::cli::views::Render;
 use crate::presentation::cli::views::UserOutput;
 use crate::shared::clock::Clock;
+use crate::shared::command::run_log::DEFAULT_KEPT_RUNS;
 
 use super::errors::DestroySubcommandError;
 
@@ -74,6 +90,10 @@ pub struct DestroyCommandController {
     repository: Arc<dyn EnvironmentRepository + Send + Sync>,
     clock: Arc<dyn Clock>,
     progress: ProgressReporter,
+    refresh: bool,
+    list_handler: Option<ListCommandHandler>,
+    runner: MultiEnvironmentRunner,
+    kept_run_logs: usize,
 }
 
 impl DestroyCommandController {
@@ -93,9 +113,41 @@ impl DestroyCommandController {
             repository,
             clock,
             progress,
+            refresh: false,
+            list_handler: None,
+            runner: MultiEnvironmentRunner::default(),
+            kept_run_logs: DEFAULT_KEPT_RUNS,
         }
     }
 
+    /// Keep the `OpenTofu` output logs of the last `runs` runs of the command
+    #[must_use]
+    pub fn with_kept_run_logs(mut self, runs: usize) -> Self {
+        self.kept_run_logs = runs;
+        self
+    }
+
+    /// Reconcile the `OpenTofu` state before destroying (`--refresh`)
+    #[must_use]
+    pub fn with_refresh(mut self, refresh: bool) -> Self {
+        self.refresh = refresh;
+        self
+    }
+
+    /// Destroy up to `parallelism` of the environments selected with `--label` at once
+    #[must_use]
+    pub fn with_parallelism(mut self, parallelism: NonZeroUsize) -> Self {
+        self.runner = MultiEnvironmentRunner::new(parallelism);
+        self
+    }
+
+    /// Select the environments of `destroy --label` with this handler
+    #[must_use]
+    pub fn with_list_handler(mut self, list_handler: ListCommandHandler) -> Self {
+        self.list_handler = Some(list_handler);
+        self
+    }
+
     /// Execute the complete destroy workflow
     ///
     /// Orchestrates all steps of the destroy command:
@@ -125,6 +177,19 @@ impl DestroyCommandController {
         &mut self,
         environment_name: &str,
         output_format: OutputFormat,
+    ) -> Result<(), DestroySubcommandError> {
+        self.progress.set_output_format(output_format);
+        let result = self.execute_steps(environment_name, output_format).await;
+        self.progress.finish(result)
+    }
+
+    /// Steps of [`Self::execute`], before the timing summary
+    #[allow(clippy::result_large_err)]
+    #[allow(clippy::unused_async)] // Part of uniform async presentation layer interface
+    async fn execute_steps(
+        &mut self,
+        environment_name: &str,
+        output_format: OutputFormat,
     ) -> Result<(), DestroySubcommandError> {
         let env_name = self.validate_environment_name(environment_name)?;
 
@@ -137,6 +202,183 @@ impl DestroyCommandController {
         Ok(())
     }
 
+    /// Simulate the destroy workflow (`--dry-run`)
+    ///
+    /// Runs `tofu plan -destroy` and displays the resources and local files
+    /// that would be deleted. Nothing is destroyed and the environment state
+    /// is not changed.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the environment name is invalid, the environment
+    /// cannot be loaded, or the destroy plan fails.
+    #[allow(clippy::result_large_err)]
+    #[allow(clippy::unused_async)] // Part of uniform async presentation layer interface
+    pub async fn dry_run(
+        &mut self,
+        environment_name: &str,
+        output_format: OutputFormat,
+    ) -> Result<DryRunReport, DestroySubcommandError> {
+        self.progress.set_output_format(output_format);
+        let result = self.dry_run_steps(environment_name, output_format).await;
+        self.progress.finish(result)
+    }
+
+    /// Steps of [`Self::dry_run`], before the timing summary
+    #[allow(clippy::result_large_err)]
+    #[allow(clippy::unused_async)] // Part of uniform async presentation layer interface
+    async fn dry_run_steps(
+        &mut self,
+        environment_name: &str,
+        output_format: OutputFormat,
+    ) -> Result<DryRunReport, DestroySubcommandError> {
+        let env_name = self.validate_environment_name(environment_name)?;
+
+        let handler = self.create_command_handler()?;
+
+        self.progress
+            .start_step(DestroyStep::TearDownInfrastructure.description())?;
+
+        let report = handler.dry_run(&env_name).map_err(|source| {
+            DestroySubcommandError::DestroyOperationFailed {
+                name: env_name.to_string(),
+                source,
+            }
+        })?;
+
+        self.progress
+            .complete_step(Some("Dry run completed, nothing was destroyed"))?;
+
+        self.progress.blank_line()?;
+        let data = DryRunReportData::new(environment_name, "destroy", &report);
+        let output = match output_format {
+            OutputFormat::Text => DryRunReportTextView::render(&data)?,
+            OutputFormat::Json => DryRunReportJsonView::render(&data)?,
+        };
+        self.progress.result(&output)?;
+
+        Ok(report)
+    }
+
+    /// Destroy every environment with the given labels (`--label`)
+    ///
+    /// Environments that are already destroyed are skipped. Without
+    /// `confirmed` (`--yes`), the selected environments are only listed in
+    /// the returned error. Up to the configured parallelism (`--parallel`)
+    /// environments are destroyed at the same time. A failure does not stop
+    /// the other environments: the results are reported per environment
+    /// once all were attempted.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the environments cannot be listed, the command is
+    /// not confirmed, or any selected environment fails to be destroyed.
+    #[allow(clippy::result_large_err)]
+    #[allow(clippy::unused_async)] // Part of uniform async presentation layer interface
+    pub async fn execute_by_labels(
+        &mut self,
+        labels: &[Label],
+        confirmed: bool,
+        output_format: OutputFormat,
+    ) -> Result<MultiEnvironmentReportData, DestroySubcommandError> {
+        self.progress.set_output_format(output_format);
+        let result = self
+            .execute_by_labels_steps(labels, confirmed, output_format)
+            .await;
+        self.progress.finish(result)
+    }
+
+    /// Steps of [`Self::execute_by_labels`], before the timing summary
+    #[allow(clippy::result_large_err)]
+    #[allow(clippy::unused_async)] // Part of uniform async presentation layer interface
+    async fn execute_by_labels_steps(
+        &mut self,
+        labels: &[Label],
+        confirmed: bool,
+        output_format: OutputFormat,
+    ) -> Result<MultiEnvironmentReportData, DestroySubcommandError> {
+        let selection: Vec<String> = labels.iter().map(ToString::to_string).collect();
+
+        let names = self.select_environments(labels, &selection)?;
+
+        if !names.is_empty() && !confirmed {
+            return Err(DestroySubcommandError::ConfirmationRequired {
+                labels: selection.join(", "),
+                count: names.len(),
+                environments: names
+                    .iter()
+                    .map(ToString::to_string)
+                    .collect::<Vec<_>>()
+                    .join(", "),
+            });
+        }
+
+        let listener =
+            MultiEnvironmentProgressListener::new(self.progress.output().clone(), "destroy");
+
+        // Each worker builds its own handler: handlers are not shared between threads
+        let report = self.runner.run(&names, Some(&listener), |env_name| {
+            let handler = DestroyCommandHandler::new(self.repository.clone(), self.clock.clone())
+                .with_kept_run_logs(self.kept_run_logs)
+                .with_refresh(self.refresh);
+            let verbose = VerboseProgressListener::new(self.progress.output().clone())
+                .with_environment_prefix(env_name.as_str());
+            Self::destroy_environment(&self.repository, &self.clock, &handler, env_name, &verbose)
+        });
+
+        let data = MultiEnvironmentReportData::new(
+            "destroy",
+            labels,
+            self.runner.parallelism().get(),
+            &report,
+        );
+        let output = match output_format {
+            OutputFormat::Text => MultiEnvironmentReportTextView::render(&data)?,
+            OutputFormat::Json => MultiEnvironmentReportJsonView::render(&data)?,
+        };
+        self.progress.result(&output)?;
+
+        match data.failed_count() {
+            0 => Ok(data),
+            failed => Err(DestroySubcommandError::BulkDestroyFailed {
+                failed,
+                total: data.results.len(),
+            }),
+        }
+    }
+
+    /// List the environments with all the given labels that are not destroyed yet
+    #[allow(clippy::result_large_err)]
+    fn select_environments(
+        &self,
+        labels: &[Label],
+        selection: &[String],
+    ) -> Result<Vec<EnvironmentName>, DestroySubcommandError> {
+        let selection_failed =
+            |reason: String| DestroySubcommandError::EnvironmentSelectionFailed {
+                labels: selection.join(", "),
+                reason,
+            };
+
+        let list_handler = self
+            .list_handler
+            .as_ref()
+            .ok_or_else(|| selection_failed("environment listing is not available".to_string()))?;
+
+        let filter = ListFilter::new().with_labels(labels.iter().cloned());
+        let list = list_handler
+            .execute(&filter)
+            .map_err(|e| selection_failed(e.to_string()))?;
+
+        list.environments
+            .into_iter()
+            .filter(|summary| summary.state != "Destroyed")
+            .map(|summary| {
+                EnvironmentName::new(summary.name).map_err(|e| selection_failed(e.to_string()))
+            })
+            .collect()
+    }
+
     /// Validate the environment name format
     ///
     /// Shows progress to user and validates that the environment name
@@ -170,7 +412,9 @@ impl DestroyCommandController {
     fn create_command_handler(&mut self) -> Result<DestroyCommandHandler, DestroySubcommandError> {
         self.progress
             .start_step(DestroyStep::CreateCommandHandler.description())?;
-        let handler = DestroyCommandHandler::new(self.repository.clone(), self.clock.clone());
+        let handler = DestroyCommandHandler::new(self.repository.clone(), self.clock.clone())
+            .with_kept_run_logs(self.kept_run_logs)
+            .with_refresh(self.refresh);
         self.progress.complete_step(None)?;
 
         Ok(handler)
@@ -189,18 +433,44 @@ impl DestroyCommandController {
         self.progress
             .start_step(DestroyStep::TearDownInfrastructure.description())?;
 
-        let destroyed = handler.execute(env_name).map_err(|source| {
-            DestroySubcommandError::DestroyOperationFailed {
-                name: env_name.to_string(),
-                source,
-            }
-        })?;
+        // The VerboseProgressListener translates step events into
+        // user-facing detail messages via UserOutput's verbosity filter.
+        let listener = VerboseProgressListener::new(self.progress.output().clone());
+        let destroyed =
+            Self::destroy_environment(&self.repository, &self.clock, handler, env_name, &listener)
+                .map_err(|source| DestroySubcommandError::DestroyOperationFailed {
+                    name: env_name.to_string(),
+                    source,
+                })?;
 
         self.progress
             .complete_step(Some("Infrastructure torn down"))?;
         Ok(destroyed)
     }
 
+    /// Destroy one environment, recording the deployment report of the run
+    ///
+    /// Takes the repository and clock rather than `self`, so it can be run
+    /// from the worker threads of `destroy --label`. The step events are
+    /// recorded for the report and forwarded to `listener`.
+    fn destroy_environment(
+        repository: &Arc<dyn EnvironmentRepository + Send + Sync>,
+        clock: &Arc<dyn Clock>,
+        handler: &DestroyCommandHandler,
+        env_name: &EnvironmentName,
+        listener: &dyn CommandProgressListener,
+    ) -> Result<Environment<Destroyed>, DestroyCommandHandlerError> {
+        let recorder =
+            DeploymentReportListener::new("destroy", env_name, clock.clone(), Some(listener));
+        let result = handler.execute(env_name, Some(&recorder));
+        recorder.write(
+            repository.as_ref(),
+            &[Dependency::OpenTofu],
+            result.as_ref().err(),
+        );
+        result
+    }
+
     /// Complete the workflow with environment details output
     ///
     /// Renders the destroyed environment details using the chosen output format
@@ -226,7 +496,7 @@ impl DestroyCommandController {
         if matches!(output_format, OutputFormat::Text) {
             self.progress.blank_line()?;
             self.progress.output().lock().borrow_mut().result(&format!(
-                "💡 Local data preserved for debugging. To completely remove and reuse the name:\n   torrust-tracker-deployer purge {name} --force"
+                "💡 Local data preserved for debugging. To completely remove and reuse the name:\n   torrust-tracker-deployer purge {name} --yes"
             ));
         }
 
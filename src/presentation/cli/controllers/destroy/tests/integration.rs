@@ -6,6 +6,9 @@
 use std::fs;
 use std::sync::Arc;
 
+use crate::application::command_handlers::ListCommandHandler;
+use crate::domain::environment::testing::EnvironmentTestBuilder;
+use crate::domain::environment::{Label, Labels};
 use crate::infrastructure::persistence::file_repository_factory::FileRepositoryFactory;
 use crate::presentation::cli::controllers::constants::DEFAULT_LOCK_TIMEOUT;
 use crate::presentation::cli::controllers::destroy::handler::DestroyCommandController;
@@ -177,3 +180,62 @@ async fn it_should_work_with_custom_working_directory() {
     assert!(result.is_err());
     // Accept any error in test context - we're testing path handling, not full command behavior
 }
+
+#[tokio::test]
+async fn it_should_require_confirmation_to_destroy_the_environments_with_a_label() {
+    let context = TestContext::new();
+    let data_dir = context.working_dir().join("data");
+    let file_repository_factory = Arc::new(FileRepositoryFactory::new(DEFAULT_LOCK_TIMEOUT));
+    let repository = file_repository_factory.create(data_dir.clone());
+    let clock = Arc::new(SystemClock);
+
+    let mut env_dirs = Vec::new();
+    for (name, labels) in [("e2e-a", "purpose=e2e"), ("prod", "purpose=prod")] {
+        let (environment, _data_dir, _build_dir, env_dir) = EnvironmentTestBuilder::new()
+            .with_name(name)
+            .build_with_custom_paths();
+        let mut environment = environment.into_any();
+        environment.set_labels(Labels::from_iter([labels.parse::<Label>().unwrap()]));
+        repository.save(&environment).unwrap();
+        env_dirs.push(env_dir);
+    }
+
+    let list_handler =
+        ListCommandHandler::new(file_repository_factory, Arc::from(data_dir), clock.clone());
+    let result = DestroyCommandController::new(repository, clock, context.user_output().clone())
+        .with_list_handler(list_handler)
+        .execute_by_labels(&["purpose=e2e".parse().unwrap()], false, OutputFormat::Text)
+        .await;
+
+    match result {
+        Err(DestroySubcommandError::ConfirmationRequired {
+            count,
+            environments,
+            ..
+        }) => {
+            assert_eq!(count, 1);
+            assert_eq!(environments, "e2e-a");
+        }
+        other => panic!("Expected ConfirmationRequired, got: {other:?}"),
+    }
+}
+
+#[tokio::test]
+async fn it_should_report_no_environment_when_no_label_matches() {
+    let context = TestContext::new();
+    let data_dir = context.working_dir().join("data");
+    fs::create_dir_all(&data_dir).unwrap();
+    let file_repository_factory = Arc::new(FileRepositoryFactory::new(DEFAULT_LOCK_TIMEOUT));
+    let repository = file_repository_factory.create(data_dir.clone());
+    let clock = Arc::new(SystemClock);
+
+    let list_handler =
+        ListCommandHandler::new(file_repository_factory, Arc::from(data_dir), clock.clone());
+    let report = DestroyCommandController::new(repository, clock, context.user_output().clone())
+        .with_list_handler(list_handler)
+        .execute_by_labels(&["purpose=e2e".parse().unwrap()], true, OutputFormat::Json)
+        .await
+        .unwrap();
+
+    assert!(report.results.is_empty());
+}
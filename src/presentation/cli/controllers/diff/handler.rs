@@ -0,0 +1,140 @@
+//! Diff Command Handler
+//!
+//! This module handles the diff command execution at the presentation layer,
+//! comparing an environment with a configuration file.
+
+use std::cell::RefCell;
+use std::path::Path;
+use std::sync::Arc;
+
+use parking_lot::ReentrantMutex;
+
+use crate::application::command_handlers::diff::DiffCommandHandler;
+use crate::domain::environment::name::EnvironmentName;
+use crate::presentation::cli::input::cli::output_format::OutputFormat;
+use crate::presentation::cli::views::commands::diff::{DiffData, JsonView, TextView};
+use crate::presentation::cli::views::progress::ProgressReporter;
+use crate::presentation::cli::views::Render;
+use crate::presentation::cli::views::UserOutput;
+
+use super::errors::DiffSubcommandError;
+
+/// Steps in the diff workflow
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum DiffStep {
+    ValidateEnvironment,
+    CompareConfiguration,
+}
+
+impl DiffStep {
+    /// All steps in execution order
+    const ALL: &'static [Self] = &[Self::ValidateEnvironment, Self::CompareConfiguration];
+
+    /// Total number of steps
+    const fn count() -> usize {
+        Self::ALL.len()
+    }
+
+    /// User-facing description for the step
+    fn description(self) -> &'static str {
+        match self {
+            Self::ValidateEnvironment => "Validating environment",
+            Self::CompareConfiguration => "Comparing configuration",
+        }
+    }
+}
+
+/// Presentation layer controller for diff command workflow
+pub struct DiffCommandController {
+    handler: DiffCommandHandler,
+    progress: ProgressReporter,
+}
+
+impl DiffCommandController {
+    /// Create a new `DiffCommandController` with dependencies
+    #[allow(clippy::needless_pass_by_value)] // Arc parameters are moved to constructor for ownership
+    pub fn new(
+        handler: DiffCommandHandler,
+        user_output: Arc<ReentrantMutex<RefCell<UserOutput>>>,
+    ) -> Self {
+        let progress = ProgressReporter::new(user_output, DiffStep::count());
+
+        Self { handler, progress }
+    }
+
+    /// Execute the diff command workflow
+    ///
+    /// # Arguments
+    ///
+    /// * `environment_name` - Name of the environment
+    /// * `config_file` - Path to the updated configuration file
+    /// * `output_format` - Output format (Text or Json)
+    ///
+    /// # Errors
+    ///
+    /// Returns `DiffSubcommandError` if the name is invalid or the comparison
+    /// fails
+    pub fn execute(
+        &mut self,
+        environment_name: &str,
+        config_file: &Path,
+        output_format: OutputFormat,
+    ) -> Result<(), DiffSubcommandError> {
+        self.progress.set_output_format(output_format);
+        let result = self.execute_steps(environment_name, config_file, output_format);
+        self.progress.finish(result)
+    }
+
+    /// Steps of [`Self::execute`], before the timing summary
+    fn execute_steps(
+        &mut self,
+        environment_name: &str,
+        config_file: &Path,
+        output_format: OutputFormat,
+    ) -> Result<(), DiffSubcommandError> {
+        let env_name = self.validate_environment_name(environment_name)?;
+
+        self.progress
+            .start_step(DiffStep::CompareConfiguration.description())?;
+
+        let diff = self
+            .handler
+            .execute(&env_name, config_file)
+            .map_err(|source| DiffSubcommandError::DiffFailed {
+                name: environment_name.to_string(),
+                source,
+            })?;
+
+        self.progress.complete_step(None)?;
+
+        let data = DiffData::new(environment_name, &config_file.display().to_string(), &diff);
+        let output = match output_format {
+            OutputFormat::Text => TextView::render(&data)?,
+            OutputFormat::Json => JsonView::render(&data)?,
+        };
+
+        self.progress.result(&output)?;
+
+        Ok(())
+    }
+
+    /// Validate the environment name format
+    fn validate_environment_name(
+        &mut self,
+        name: &str,
+    ) -> Result<EnvironmentName, DiffSubcommandError> {
+        self.progress
+            .start_step(DiffStep::ValidateEnvironment.description())?;
+
+        let env_name = EnvironmentName::new(name.to_string()).map_err(|source| {
+            DiffSubcommandError::InvalidEnvironmentName {
+                name: name.to_string(),
+                source,
+            }
+        })?;
+
+        self.progress.complete_step(None)?;
+
+        Ok(env_name)
+    }
+}
@@ -0,0 +1,17 @@
+//! Diff Command Presentation Module
+//!
+//! This module implements the CLI presentation layer for the diff command,
+//! which compares the persisted configuration of an environment with an
+//! updated configuration file and tells what has to be redeployed.
+//!
+//! ## Components
+//!
+//! - `errors` - Presentation layer error types with `.help()` methods
+//! - `handler` - Main command handler orchestrating the workflow
+
+pub mod errors;
+pub mod handler;
+pub use handler::DiffCommandController;
+
+// Re-export commonly used types for convenience
+pub use errors::DiffSubcommandError;
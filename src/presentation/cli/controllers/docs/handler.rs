@@ -81,6 +81,12 @@ impl DocsCommandController {
     /// - Parent directory creation fails (when path provided)
     /// - Stdout write fails (when no path provided)
     pub fn execute(&mut self, output_path: Option<&PathBuf>) -> Result<(), DocsCommandError> {
+        let result = self.execute_steps(output_path);
+        self.progress.finish(result)
+    }
+
+    /// Steps of [`Self::execute`], before the timing summary
+    fn execute_steps(&mut self, output_path: Option<&PathBuf>) -> Result<(), DocsCommandError> {
         // Generate CLI documentation using infrastructure layer directly
         let docs = CliDocsGenerator::generate::<Cli>()
             .map_err(|source| DocsCommandError::SchemaGenerationFailed { source })?;
@@ -0,0 +1,118 @@
+//! Error types for the Doctor Subcommand
+//!
+//! This module defines error types that can occur during CLI doctor command execution.
+//! All errors follow the project's error handling principles by providing clear,
+//! contextual, and actionable error messages with `.help()` methods.
+
+use thiserror::Error;
+
+use crate::presentation::cli::views::progress::ProgressReporterError;
+use crate::presentation::cli::views::ViewRenderError;
+
+/// Doctor command specific errors
+#[derive(Debug, Error)]
+pub enum DoctorSubcommandError {
+    /// Some critical checks failed
+    ///
+    /// The report with the cause of each failure has already been shown.
+    #[error(
+        "{count} critical check(s) failed
+Tip: Follow the hint shown next to each failed check"
+    )]
+    ChecksFailed { count: usize },
+
+    // ===== Internal Errors =====
+    /// Progress reporting failed
+    #[error(
+        "Failed to report progress: {source}
+Tip: This is a critical bug - please report it with full logs using --log-output file-and-stderr"
+    )]
+    ProgressReportingFailed {
+        #[source]
+        source: ProgressReporterError,
+    },
+
+    /// Output formatting failed (JSON serialization error).
+    #[error(
+        "Failed to format output: {reason}\nTip: This is a critical bug - please report it with full logs using --log-output file-and-stderr"
+    )]
+    OutputFormatting { reason: String },
+}
+
+// ============================================================================
+// ERROR CONVERSIONS
+// ============================================================================
+
+impl From<ProgressReporterError> for DoctorSubcommandError {
+    fn from(source: ProgressReporterError) -> Self {
+        Self::ProgressReportingFailed { source }
+    }
+}
+
+impl From<ViewRenderError> for DoctorSubcommandError {
+    fn from(e: ViewRenderError) -> Self {
+        Self::OutputFormatting {
+            reason: e.to_string(),
+        }
+    }
+}
+
+impl DoctorSubcommandError {
+    /// Get detailed troubleshooting guidance for this error
+    #[must_use]
+    pub fn help(&self) -> &'static str {
+        match self {
+            Self::ChecksFailed { .. } => {
+                "Doctor Checks Failed - Detailed Troubleshooting:
+
+1. Missing or outdated tools:
+   - Install them: cargo run --bin dependency-installer install
+   - Check them: cargo run --bin dependency-installer check
+
+2. LXD daemon or permissions:
+   - Check the daemon: sudo snap services lxd
+   - Initialize it: sudo lxd init --auto
+   - Join the group: sudo usermod -aG lxd $USER && newgrp lxd
+
+3. Workspace:
+   - Free some disk space (build/ and old LXD images are good candidates)
+   - Fix the ownership: sudo chown -R $USER data build
+
+4. Run the command again:
+   - torrust-tracker-deployer doctor
+
+When reporting an issue, attach the output of:
+   torrust-tracker-deployer doctor --output-format json
+
+For more information, see docs/user-guide/commands/doctor.md"
+            }
+            Self::ProgressReportingFailed { .. } => {
+                "Progress Reporting Failed - This is an internal error:
+
+1. This indicates a bug in the application
+2. Please report this issue with:
+   - Full command output
+   - Log file contents (use --log-output file-and-stderr)
+   - Steps to reproduce
+
+Report issues at: https://github.com/torrust/torrust-tracker-deployer/issues"
+            }
+            Self::OutputFormatting { .. } => {
+                "Output Formatting Failed - Critical Internal Error:\n\nThis error should not occur during normal operation. It indicates a bug in the output formatting system.\n\nPlease report it with the command, output format (--output-format) and logs so we can fix it."
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn it_should_provide_help_for_failed_checks() {
+        let error = DoctorSubcommandError::ChecksFailed { count: 2 };
+
+        assert!(error.to_string().contains("2 critical check(s) failed"));
+        assert!(error.help().contains("dependency-installer install"));
+    }
+}
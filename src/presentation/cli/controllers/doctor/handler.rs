@@ -0,0 +1,141 @@
+//! Doctor Command Handler
+//!
+//! This module handles the doctor command execution at the presentation layer,
+//! running the diagnostic checks and displaying their results.
+
+use std::cell::RefCell;
+use std::sync::Arc;
+
+use parking_lot::ReentrantMutex;
+
+use crate::application::command_handlers::doctor::{
+    CheckStatus, DoctorCommandHandler, DoctorReport,
+};
+use crate::presentation::cli::input::cli::output_format::OutputFormat;
+use crate::presentation::cli::views::commands::doctor::{JsonView, TextView};
+use crate::presentation::cli::views::progress::ProgressReporter;
+use crate::presentation::cli::views::Render;
+use crate::presentation::cli::views::UserOutput;
+
+use super::errors::DoctorSubcommandError;
+
+/// Steps in the doctor workflow
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum DoctorStep {
+    RunChecks,
+    DisplayResults,
+}
+
+impl DoctorStep {
+    /// All steps in execution order
+    const ALL: &'static [Self] = &[Self::RunChecks, Self::DisplayResults];
+
+    /// Total number of steps
+    const fn count() -> usize {
+        Self::ALL.len()
+    }
+
+    /// User-facing description for the step
+    fn description(self) -> &'static str {
+        match self {
+            Self::RunChecks => "Running diagnostic checks",
+            Self::DisplayResults => "Displaying results",
+        }
+    }
+}
+
+/// Presentation layer controller for doctor command workflow
+///
+/// ## Responsibilities
+///
+/// - Delegate the checks to the application layer
+/// - Display one line per check with its status and detail
+/// - Fail with a non-zero exit code when a critical check failed
+pub struct DoctorCommandController {
+    handler: DoctorCommandHandler,
+    progress: ProgressReporter,
+}
+
+impl DoctorCommandController {
+    /// Create a new `DoctorCommandController` with dependencies
+    #[allow(clippy::needless_pass_by_value)] // Arc parameters are moved to constructor for ownership
+    pub fn new(
+        handler: DoctorCommandHandler,
+        user_output: Arc<ReentrantMutex<RefCell<UserOutput>>>,
+    ) -> Self {
+        let progress = ProgressReporter::new(user_output, DoctorStep::count());
+
+        Self { handler, progress }
+    }
+
+    /// Execute the doctor command workflow
+    ///
+    /// # Arguments
+    ///
+    /// * `output_format` - Output format (Text or Json)
+    ///
+    /// # Errors
+    ///
+    /// Returns `DoctorSubcommandError` if any critical check failed (after
+    /// displaying the report)
+    pub fn execute(&mut self, output_format: OutputFormat) -> Result<(), DoctorSubcommandError> {
+        self.progress.set_output_format(output_format);
+        let result = self.execute_steps(output_format);
+        self.progress.finish(result)
+    }
+
+    /// Steps of [`Self::execute`], before the timing summary
+    fn execute_steps(&mut self, output_format: OutputFormat) -> Result<(), DoctorSubcommandError> {
+        // Step 1: Run the checks via application layer
+        let report = self.run_checks()?;
+
+        // Step 2: Display results
+        self.display_results(&report, output_format)?;
+
+        if report.has_failures() {
+            return Err(DoctorSubcommandError::ChecksFailed {
+                count: report.count(CheckStatus::Failed),
+            });
+        }
+
+        Ok(())
+    }
+
+    /// Step 1: Run the checks via application layer
+    fn run_checks(&mut self) -> Result<DoctorReport, DoctorSubcommandError> {
+        self.progress
+            .start_step(DoctorStep::RunChecks.description())?;
+
+        let report = self.handler.execute();
+
+        self.progress.complete_step(Some(&format!(
+            "{} check(s), {} failed, {} warning(s)",
+            report.checks.len(),
+            report.count(CheckStatus::Failed),
+            report.count(CheckStatus::Warning)
+        )))?;
+
+        Ok(report)
+    }
+
+    /// Step 2: Display the report on stdout
+    fn display_results(
+        &mut self,
+        report: &DoctorReport,
+        output_format: OutputFormat,
+    ) -> Result<(), DoctorSubcommandError> {
+        self.progress
+            .start_step(DoctorStep::DisplayResults.description())?;
+
+        let output = match output_format {
+            OutputFormat::Text => TextView::render(report)?,
+            OutputFormat::Json => JsonView::render(report)?,
+        };
+
+        self.progress.result(&output)?;
+
+        self.progress.complete_step(Some("Results displayed"))?;
+
+        Ok(())
+    }
+}
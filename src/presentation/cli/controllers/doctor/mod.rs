@@ -0,0 +1,17 @@
+//! Doctor Command Presentation Module
+//!
+//! This module implements the CLI presentation layer for the doctor command,
+//! which diagnoses the local toolchain and workspace and exits with a
+//! non-zero code when a critical check fails.
+//!
+//! ## Components
+//!
+//! - `errors` - Presentation layer error types with `.help()` methods
+//! - `handler` - Main command handler orchestrating the workflow
+
+pub mod errors;
+pub mod handler;
+pub use handler::DoctorCommandController;
+
+// Re-export commonly used types for convenience
+pub use errors::DoctorSubcommandError;
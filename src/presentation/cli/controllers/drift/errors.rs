@@ -0,0 +1,151 @@
+//! Error types for the Drift Subcommand
+//!
+//! This module defines error types that can occur during CLI drift command execution.
+//! All errors follow the project's error handling principles by providing clear,
+//! contextual, and actionable error messages with `.help()` methods.
+
+use thiserror::Error;
+
+use crate::application::command_handlers::drift::DriftCommandHandlerError;
+use crate::domain::environment::name::EnvironmentNameError;
+use crate::presentation::cli::views::progress::ProgressReporterError;
+use crate::presentation::cli::views::ViewRenderError;
+
+/// Drift command specific errors
+#[derive(Debug, Error)]
+pub enum DriftSubcommandError {
+    // ===== Environment Validation Errors =====
+    /// Environment name validation failed
+    #[error("Invalid environment name '{name}': {source}
+Tip: Environment names must be 1-63 characters, start with letter/digit, contain only letters/digits/hyphens")]
+    InvalidEnvironmentName {
+        name: String,
+        #[source]
+        source: EnvironmentNameError,
+    },
+
+    /// The drift could not be detected
+    #[error(
+        "Failed to detect configuration drift of environment '{name}': {source}
+Tip: Use 'show {name}' to check the environment state"
+    )]
+    DriftDetectionFailed {
+        name: String,
+        #[source]
+        source: Box<DriftCommandHandlerError>,
+    },
+
+    /// The instance drifted and `--exit-code` was given
+    #[error(
+        "Environment '{name}' drifted: {count} difference(s) with its configuration
+Tip: Run 'release {name}' to deploy the configuration again"
+    )]
+    DriftDetected { name: String, count: usize },
+
+    // ===== Internal Errors =====
+    /// Progress reporting failed
+    #[error(
+        "Failed to report progress: {source}
+Tip: This is a critical bug - please report it with full logs using --log-output file-and-stderr"
+    )]
+    ProgressReportingFailed {
+        #[source]
+        source: ProgressReporterError,
+    },
+
+    /// Output formatting failed (JSON serialization error).
+    #[error(
+        "Failed to format output: {reason}\nTip: This is a critical bug - please report it with full logs using --log-output file-and-stderr"
+    )]
+    OutputFormatting { reason: String },
+}
+
+// ============================================================================
+// ERROR CONVERSIONS
+// ============================================================================
+
+impl From<ProgressReporterError> for DriftSubcommandError {
+    fn from(source: ProgressReporterError) -> Self {
+        Self::ProgressReportingFailed { source }
+    }
+}
+
+impl From<ViewRenderError> for DriftSubcommandError {
+    fn from(e: ViewRenderError) -> Self {
+        Self::OutputFormatting {
+            reason: e.to_string(),
+        }
+    }
+}
+
+impl DriftSubcommandError {
+    /// Get detailed troubleshooting guidance for this error
+    #[must_use]
+    pub fn help(&self) -> &'static str {
+        match self {
+            Self::InvalidEnvironmentName { .. } => {
+                "Invalid Environment Name - Detailed Troubleshooting:
+
+1. Check name format requirements:
+   - Length: 1-63 characters
+   - Start: Must begin with a letter or digit
+   - Characters: Only lowercase letters, digits, and hyphens allowed
+
+2. List the existing environments:
+   torrust-tracker-deployer list
+
+For more information, see docs/user-guide/commands/drift.md"
+            }
+            Self::DriftDetectionFailed { source, .. } => source.help(),
+            Self::DriftDetected { .. } => {
+                "Configuration Drift Detected - Detailed Troubleshooting:
+
+Something on the instance differs from what the deployer would deploy.
+The report above lists every difference, with the diff of each modified file.
+
+1. To discard the changes made on the instance, deploy again:
+   torrust-tracker-deployer release <env-name>
+   torrust-tracker-deployer run <env-name>
+
+2. To keep a change, make it in the environment configuration instead,
+   then release again
+
+The compared files are kept in build/<env-name>/drift/.
+
+For more information, see docs/user-guide/commands/drift.md"
+            }
+            Self::ProgressReportingFailed { .. } => {
+                "Progress Reporting Failed - This is an internal error:
+
+1. This indicates a bug in the application
+2. Please report this issue with:
+   - Full command output
+   - Log file contents (use --log-output file-and-stderr)
+   - Steps to reproduce
+
+Report issues at: https://github.com/torrust/torrust-tracker-deployer/issues"
+            }
+            Self::OutputFormatting { .. } => {
+                "Output Formatting Failed - Critical Internal Error:\n\nThis error should not occur during normal operation. It indicates a bug in the output formatting system.\n\nPlease report it with the command, output format (--output-format) and logs so we can fix it."
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn it_should_count_the_differences_when_the_environment_drifted() {
+        let error = DriftSubcommandError::DriftDetected {
+            name: "my-env".to_string(),
+            count: 2,
+        };
+
+        assert!(error
+            .to_string()
+            .starts_with("Environment 'my-env' drifted: 2 difference(s)"));
+        assert!(error.help().contains("release <env-name>"));
+    }
+}
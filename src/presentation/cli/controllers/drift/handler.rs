@@ -0,0 +1,170 @@
+//! Drift Command Handler
+//!
+//! This module handles the drift command execution at the presentation layer,
+//! comparing what is deployed on the instance of an environment with what
+//! the deployer would deploy and displaying the differences.
+
+use std::cell::RefCell;
+use std::sync::Arc;
+
+use parking_lot::ReentrantMutex;
+
+use crate::application::command_handlers::drift::{DriftCommandHandler, DriftReport};
+use crate::domain::environment::name::EnvironmentName;
+use crate::presentation::cli::input::cli::output_format::OutputFormat;
+use crate::presentation::cli::views::commands::drift::{DriftData, JsonView, TextView};
+use crate::presentation::cli::views::progress::ProgressReporter;
+use crate::presentation::cli::views::Render;
+use crate::presentation::cli::views::UserOutput;
+
+use super::errors::DriftSubcommandError;
+
+/// Steps in the drift workflow
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum DriftStep {
+    ValidateEnvironment,
+    DetectDrift,
+}
+
+impl DriftStep {
+    /// All steps in execution order
+    const ALL: &'static [Self] = &[Self::ValidateEnvironment, Self::DetectDrift];
+
+    /// Total number of steps
+    const fn count() -> usize {
+        Self::ALL.len()
+    }
+
+    /// User-facing description for the step
+    fn description(self) -> &'static str {
+        match self {
+            Self::ValidateEnvironment => "Validating environment",
+            Self::DetectDrift => "Comparing the instance with the environment configuration",
+        }
+    }
+}
+
+/// Presentation layer controller for drift command workflow
+///
+/// ## Responsibilities
+///
+/// - Delegate the comparison to the application layer
+/// - Display the drift of each artifact and container, with the diffs
+/// - Fail with a non-zero exit code on drift when `--exit-code` is given
+pub struct DriftCommandController {
+    handler: DriftCommandHandler,
+    progress: ProgressReporter,
+}
+
+impl DriftCommandController {
+    /// Create a new `DriftCommandController` with dependencies
+    #[allow(clippy::needless_pass_by_value)] // Arc parameters are moved to constructor for ownership
+    pub fn new(
+        handler: DriftCommandHandler,
+        user_output: Arc<ReentrantMutex<RefCell<UserOutput>>>,
+    ) -> Self {
+        let progress = ProgressReporter::new(user_output, DriftStep::count());
+
+        Self { handler, progress }
+    }
+
+    /// Execute the drift command workflow
+    ///
+    /// # Arguments
+    ///
+    /// * `environment_name` - Name of the environment
+    /// * `exit_code` - Whether a drift is reported as an error
+    /// * `output_format` - Output format (Text or Json)
+    ///
+    /// # Errors
+    ///
+    /// Returns `DriftSubcommandError` if the name is invalid, the drift
+    /// cannot be detected, or the instance drifted and `exit_code` is set
+    /// (after displaying the report)
+    pub async fn execute(
+        &mut self,
+        environment_name: &str,
+        exit_code: bool,
+        output_format: OutputFormat,
+    ) -> Result<(), DriftSubcommandError> {
+        self.progress.set_output_format(output_format);
+        let result = self
+            .execute_steps(environment_name, exit_code, output_format)
+            .await;
+        self.progress.finish(result)
+    }
+
+    /// Steps of [`Self::execute`], before the timing summary
+    async fn execute_steps(
+        &mut self,
+        environment_name: &str,
+        exit_code: bool,
+        output_format: OutputFormat,
+    ) -> Result<(), DriftSubcommandError> {
+        let env_name = self.validate_environment_name(environment_name)?;
+
+        let report = self.detect_drift(&env_name).await?;
+
+        let data = DriftData::new(environment_name, &report);
+        let output = match output_format {
+            OutputFormat::Text => TextView::render(&data)?,
+            OutputFormat::Json => JsonView::render(&data)?,
+        };
+
+        self.progress.result(&output)?;
+
+        if exit_code && report.is_drifted() {
+            return Err(DriftSubcommandError::DriftDetected {
+                name: environment_name.to_string(),
+                count: report.drifted_count(),
+            });
+        }
+
+        Ok(())
+    }
+
+    /// Validate the environment name format
+    fn validate_environment_name(
+        &mut self,
+        name: &str,
+    ) -> Result<EnvironmentName, DriftSubcommandError> {
+        self.progress
+            .start_step(DriftStep::ValidateEnvironment.description())?;
+
+        let env_name = EnvironmentName::new(name.to_string()).map_err(|source| {
+            DriftSubcommandError::InvalidEnvironmentName {
+                name: name.to_string(),
+                source,
+            }
+        })?;
+
+        self.progress.complete_step(None)?;
+
+        Ok(env_name)
+    }
+
+    /// Compare the instance with the environment configuration
+    async fn detect_drift(
+        &mut self,
+        env_name: &EnvironmentName,
+    ) -> Result<DriftReport, DriftSubcommandError> {
+        self.progress
+            .start_step(DriftStep::DetectDrift.description())?;
+
+        let report = self.handler.execute(env_name).await.map_err(|source| {
+            DriftSubcommandError::DriftDetectionFailed {
+                name: env_name.to_string(),
+                source: Box::new(source),
+            }
+        })?;
+
+        let summary = if report.is_drifted() {
+            format!("{} difference(s) found", report.drifted_count())
+        } else {
+            "No drift".to_string()
+        };
+        self.progress.complete_step(Some(&summary))?;
+
+        Ok(report)
+    }
+}
@@ -0,0 +1,17 @@
+//! Drift Command Presentation Module
+//!
+//! This module implements the CLI presentation layer for the drift command,
+//! which compares what is deployed on the instance of a released or running
+//! environment with what the deployer would deploy.
+//!
+//! ## Components
+//!
+//! - `errors` - Presentation layer error types with `.help()` methods
+//! - `handler` - Main command handler orchestrating the workflow
+
+pub mod errors;
+pub mod handler;
+pub use handler::DriftCommandController;
+
+// Re-export commonly used types for convenience
+pub use errors::DriftSubcommandError;
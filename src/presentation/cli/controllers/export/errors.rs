@@ -0,0 +1,137 @@
+//! Error types for the Export Subcommand
+//!
+//! This module defines error types that can occur during CLI export command execution.
+//! All errors follow the project's error handling principles by providing clear,
+//! contextual, and actionable error messages with `.help()` methods.
+
+use thiserror::Error;
+
+use crate::application::command_handlers::create::config::SecretResolutionError;
+use crate::application::command_handlers::export::ExportCommandHandlerError;
+use crate::domain::environment::name::EnvironmentNameError;
+use crate::presentation::cli::views::progress::ProgressReporterError;
+use crate::presentation::cli::views::ViewRenderError;
+
+/// Export command specific errors
+#[derive(Debug, Error)]
+pub enum ExportSubcommandError {
+    // ===== Environment Validation Errors =====
+    /// Environment name validation failed
+    #[error("Invalid environment name '{name}': {source}
+Tip: Environment names must be 1-63 characters, start with letter/digit, contain only letters/digits/hyphens")]
+    InvalidEnvironmentName {
+        name: String,
+        #[source]
+        source: EnvironmentNameError,
+    },
+
+    /// The passphrase could not be read from its environment variable
+    #[error(
+        "Failed to read the bundle passphrase: {source}
+Tip: Export the variable named by --passphrase-env before running the command"
+    )]
+    PassphraseUnavailable {
+        #[source]
+        source: SecretResolutionError,
+    },
+
+    /// The environment could not be exported
+    #[error(
+        "Failed to export environment '{name}': {source}
+Tip: Check the environment with 'show {name}'"
+    )]
+    ExportFailed {
+        name: String,
+        #[source]
+        source: ExportCommandHandlerError,
+    },
+
+    // ===== Internal Errors =====
+    /// Progress reporting failed
+    #[error(
+        "Failed to report progress: {source}
+Tip: This is a critical bug - please report it with full logs using --log-output file-and-stderr"
+    )]
+    ProgressReportingFailed {
+        #[source]
+        source: ProgressReporterError,
+    },
+
+    /// Output formatting failed (JSON serialization error).
+    #[error(
+        "Failed to format output: {reason}\nTip: This is a critical bug - please report it with full logs using --log-output file-and-stderr"
+    )]
+    OutputFormatting { reason: String },
+}
+
+// ============================================================================
+// ERROR CONVERSIONS
+// ============================================================================
+
+impl From<ProgressReporterError> for ExportSubcommandError {
+    fn from(source: ProgressReporterError) -> Self {
+        Self::ProgressReportingFailed { source }
+    }
+}
+
+impl From<ViewRenderError> for ExportSubcommandError {
+    fn from(e: ViewRenderError) -> Self {
+        Self::OutputFormatting {
+            reason: e.to_string(),
+        }
+    }
+}
+
+impl ExportSubcommandError {
+    /// Get detailed troubleshooting guidance for this error
+    #[must_use]
+    pub fn help(&self) -> &'static str {
+        match self {
+            Self::InvalidEnvironmentName { .. } => {
+                "Invalid Environment Name - Detailed Troubleshooting:
+
+1. Check name format requirements:
+   - Length: 1-63 characters
+   - Start: Must begin with a letter or digit
+   - Characters: Only lowercase letters, digits, and hyphens allowed
+
+2. Valid examples:
+   - export prod --passphrase-env BUNDLE_PASSPHRASE
+   - export staging --no-secrets
+
+For more information, see docs/user-guide/commands/export.md"
+            }
+            Self::PassphraseUnavailable { .. } => {
+                "Passphrase Unavailable - Troubleshooting:
+
+The passphrase is read from the environment variable named by
+--passphrase-env, so it never appears in the shell history.
+
+1. Set the variable and name it:
+   read -rs BUNDLE_PASSPHRASE && export BUNDLE_PASSPHRASE
+   torrust-tracker-deployer export <name> --passphrase-env BUNDLE_PASSPHRASE
+
+2. Or export without secrets, e.g. to share the environment for
+   inspection (such a bundle cannot be imported):
+   torrust-tracker-deployer export <name> --no-secrets
+
+For more information, see docs/user-guide/commands/export.md"
+            }
+            Self::ExportFailed { source, .. } => source.help(),
+            Self::ProgressReportingFailed { .. } => {
+                "Progress Reporting Failed - This is an internal error:
+
+1. This indicates a bug in the application
+2. Please report this issue with:
+   - Full command output
+   - Log file contents (use --log-output file-and-stderr)
+   - Steps to reproduce
+
+Report issues at: https://github.com/torrust/torrust-tracker-deployer/issues"
+            }
+            Self::OutputFormatting { .. } => {
+                "Output Formatting Failed - Critical Internal Error:\n\nThis error should not occur during normal operation. It indicates a bug in the output formatting system.\n\nPlease report it with the command, output format (--output-format) and logs so we can fix it."
+            }
+        }
+    }
+}
@@ -0,0 +1,190 @@
+//! Export Command Handler
+//!
+//! This module handles the export command execution at the presentation layer,
+//! writing an environment to a bundle file.
+
+use std::cell::RefCell;
+use std::path::Path;
+use std::sync::Arc;
+
+use parking_lot::ReentrantMutex;
+
+use crate::application::command_handlers::create::config::SecretSource;
+use crate::application::command_handlers::export::{ExportCommandHandler, ExportedBundle};
+use crate::domain::environment::EnvironmentName;
+use crate::presentation::cli::input::cli::output_format::OutputFormat;
+use crate::presentation::cli::views::commands::export::{ExportDetailsData, JsonView, TextView};
+use crate::presentation::cli::views::progress::ProgressReporter;
+use crate::presentation::cli::views::Render;
+use crate::presentation::cli::views::UserOutput;
+use crate::shared::secrets::Password;
+
+use super::errors::ExportSubcommandError;
+
+/// Steps in the export workflow
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum ExportStep {
+    ValidateInput,
+    WriteBundle,
+}
+
+impl ExportStep {
+    /// All steps in execution order
+    const ALL: &'static [Self] = &[Self::ValidateInput, Self::WriteBundle];
+
+    /// Total number of steps
+    const fn count() -> usize {
+        Self::ALL.len()
+    }
+
+    /// User-facing description for the step
+    fn description(self) -> &'static str {
+        match self {
+            Self::ValidateInput => "Validating environment name and passphrase",
+            Self::WriteBundle => "Writing bundle",
+        }
+    }
+}
+
+/// Presentation layer controller for export command workflow
+///
+/// ## Responsibilities
+///
+/// - Validate the environment name and read the passphrase from its variable
+/// - Delegate writing the bundle to the application layer
+/// - Display the bundle path and whether secrets are included
+pub struct ExportCommandController {
+    handler: ExportCommandHandler,
+    progress: ProgressReporter,
+}
+
+impl ExportCommandController {
+    /// Create a new `ExportCommandController` with dependencies
+    #[allow(clippy::needless_pass_by_value)] // Arc parameters are moved to constructor for ownership
+    pub fn new(
+        handler: ExportCommandHandler,
+        user_output: Arc<ReentrantMutex<RefCell<UserOutput>>>,
+    ) -> Self {
+        let progress = ProgressReporter::new(user_output, ExportStep::count());
+
+        Self { handler, progress }
+    }
+
+    /// Execute the export command workflow
+    ///
+    /// # Arguments
+    ///
+    /// * `name` - Name of the environment to export
+    /// * `output` - Path of the bundle file to write
+    /// * `passphrase_env` - Variable holding the passphrase, `None` to exclude secrets
+    /// * `output_format` - Output format (Text or Json)
+    ///
+    /// # Errors
+    ///
+    /// Returns `ExportSubcommandError` if the name is invalid, the passphrase
+    /// cannot be read or the bundle cannot be written
+    pub fn execute(
+        &mut self,
+        name: &str,
+        output: &Path,
+        passphrase_env: Option<&str>,
+        output_format: OutputFormat,
+    ) -> Result<(), ExportSubcommandError> {
+        self.progress.set_output_format(output_format);
+        let result = self.execute_steps(name, output, passphrase_env, output_format);
+        self.progress.finish(result)
+    }
+
+    /// Steps of [`Self::execute`], before the timing summary
+    fn execute_steps(
+        &mut self,
+        name: &str,
+        output: &Path,
+        passphrase_env: Option<&str>,
+        output_format: OutputFormat,
+    ) -> Result<(), ExportSubcommandError> {
+        let (env_name, passphrase) = self.validate_input(name, passphrase_env)?;
+
+        let bundle = self.write_bundle(&env_name, output, passphrase.as_ref())?;
+
+        self.display_results(&bundle, output_format)
+    }
+
+    /// Step 1: Validate the environment name and read the passphrase
+    fn validate_input(
+        &mut self,
+        name: &str,
+        passphrase_env: Option<&str>,
+    ) -> Result<(EnvironmentName, Option<Password>), ExportSubcommandError> {
+        self.progress
+            .start_step(ExportStep::ValidateInput.description())?;
+
+        let env_name = EnvironmentName::new(name.to_string()).map_err(|source| {
+            ExportSubcommandError::InvalidEnvironmentName {
+                name: name.to_string(),
+                source,
+            }
+        })?;
+
+        let passphrase = passphrase_env
+            .map(|env| {
+                SecretSource::Env {
+                    env: env.to_string(),
+                }
+                .resolve()
+                .map(Password::from)
+                .map_err(|source| ExportSubcommandError::PassphraseUnavailable { source })
+            })
+            .transpose()?;
+
+        self.progress.complete_step(None)?;
+
+        Ok((env_name, passphrase))
+    }
+
+    /// Step 2: Write the bundle via application layer
+    fn write_bundle(
+        &mut self,
+        env_name: &EnvironmentName,
+        output: &Path,
+        passphrase: Option<&Password>,
+    ) -> Result<ExportedBundle, ExportSubcommandError> {
+        self.progress
+            .start_step(ExportStep::WriteBundle.description())?;
+
+        let bundle = self
+            .handler
+            .execute(env_name, output, passphrase)
+            .map_err(|source| ExportSubcommandError::ExportFailed {
+                name: env_name.to_string(),
+                source,
+            })?;
+
+        self.progress.complete_step(Some(&format!(
+            "Wrote {} files",
+            bundle.manifest.files.len()
+        )))?;
+
+        Ok(bundle)
+    }
+
+    /// Display the bundle path and whether secrets are included
+    fn display_results(
+        &mut self,
+        bundle: &ExportedBundle,
+        output_format: OutputFormat,
+    ) -> Result<(), ExportSubcommandError> {
+        let data = ExportDetailsData::from(bundle);
+
+        match output_format {
+            OutputFormat::Text => {
+                self.progress.complete(&TextView::render(&data)?)?;
+            }
+            OutputFormat::Json => {
+                self.progress.result(&JsonView::render(&data)?)?;
+            }
+        }
+
+        Ok(())
+    }
+}
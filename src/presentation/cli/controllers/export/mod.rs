@@ -0,0 +1,18 @@
+//! Export Command Presentation Module
+//!
+//! This module implements the CLI presentation layer for the export command,
+//! which writes an environment to a single bundle file that `import-bundle`
+//! adds to another workspace. Everything holding a secret is encrypted with a
+//! passphrase, or left out with `--no-secrets`.
+//!
+//! ## Components
+//!
+//! - `errors` - Presentation layer error types with `.help()` methods
+//! - `handler` - Main command handler orchestrating the workflow
+
+pub mod errors;
+pub mod handler;
+pub use handler::ExportCommandController;
+
+// Re-export commonly used types for convenience
+pub use errors::ExportSubcommandError;
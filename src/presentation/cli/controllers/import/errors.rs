@@ -0,0 +1,164 @@
+//! Error types for the Import Subcommand
+//!
+//! This module defines error types that can occur during CLI import command execution.
+//! All errors follow the project's error handling principles by providing clear,
+//! contextual, and actionable error messages with `.help()` methods.
+
+use std::path::PathBuf;
+
+use thiserror::Error;
+
+use crate::application::command_handlers::import::ImportCommandHandlerError;
+use crate::domain::environment::name::EnvironmentNameError;
+use crate::presentation::cli::views::progress::ProgressReporterError;
+use crate::presentation::cli::views::ViewRenderError;
+use crate::shared::UsernameError;
+
+/// Import command specific errors
+#[derive(Debug, Error)]
+pub enum ImportSubcommandError {
+    // ===== Input Validation Errors =====
+    /// Environment name validation failed
+    #[error("Invalid environment name '{name}': {source}
+Tip: Environment names must be 1-63 characters, start with letter/digit, contain only letters/digits/hyphens")]
+    InvalidEnvironmentName {
+        name: String,
+        #[source]
+        source: EnvironmentNameError,
+    },
+
+    /// Invalid IP address format
+    #[error(
+        "Invalid IP address '{value}': {reason}
+Tip: Use IPv4 (e.g., 192.168.1.100) or IPv6 (e.g., 2001:db8::1) format without port numbers"
+    )]
+    InvalidIpAddress { value: String, reason: String },
+
+    /// Invalid SSH username
+    #[error(
+        "Invalid SSH user '{value}': {source}
+Tip: Use the name of an existing user with sudo access on the instance"
+    )]
+    InvalidSshUser {
+        value: String,
+        #[source]
+        source: UsernameError,
+    },
+
+    /// An SSH key file does not exist
+    #[error("SSH key file not found: {path}
+Tip: Pass the private key with --ssh-key and, if it is not next to it, the public key with --ssh-public-key")]
+    SshKeyNotFound { path: PathBuf },
+
+    // ===== Import Operation Errors =====
+    /// Import operation failed
+    #[error(
+        "Failed to import instance as environment '{name}': {source}
+Tip: Check logs and try running with --log-output file-and-stderr for more details"
+    )]
+    ImportFailed {
+        name: String,
+        #[source]
+        source: Box<ImportCommandHandlerError>,
+    },
+
+    // ===== Internal Errors =====
+    /// Progress reporting failed
+    #[error(
+        "Failed to report progress: {source}
+Tip: This is a critical bug - please report it with full logs using --log-output file-and-stderr"
+    )]
+    ProgressReportingFailed {
+        #[source]
+        source: ProgressReporterError,
+    },
+
+    /// Output formatting failed (JSON serialization error).
+    #[error(
+        "Failed to format output: {reason}\nTip: This is a critical bug - please report it with full logs using --log-output file-and-stderr"
+    )]
+    OutputFormatting { reason: String },
+}
+
+// ============================================================================
+// ERROR CONVERSIONS
+// ============================================================================
+
+impl From<ProgressReporterError> for ImportSubcommandError {
+    fn from(source: ProgressReporterError) -> Self {
+        Self::ProgressReportingFailed { source }
+    }
+}
+
+impl From<ViewRenderError> for ImportSubcommandError {
+    fn from(e: ViewRenderError) -> Self {
+        Self::OutputFormatting {
+            reason: e.to_string(),
+        }
+    }
+}
+
+impl ImportSubcommandError {
+    /// Get detailed troubleshooting guidance for this error
+    #[must_use]
+    pub fn help(&self) -> &'static str {
+        match self {
+            Self::InvalidEnvironmentName { .. } => {
+                "Invalid Environment Name - Detailed Troubleshooting:
+
+1. Check name format requirements:
+   - Length: 1-63 characters
+   - Start: Must begin with a letter or digit
+   - Characters: Only lowercase letters, digits, and hyphens allowed
+
+2. Valid examples:
+   - import legacy --ip 10.0.0.5 --ssh-key ~/.ssh/id_ed25519
+
+For more information, see docs/user-guide/commands/import.md"
+            }
+            Self::InvalidIpAddress { .. } => {
+                "Invalid IP Address - Detailed Troubleshooting:
+
+1. Use a plain IP address without port or scheme:
+   - IPv4: 10.0.0.5
+   - IPv6: 2001:db8::1
+
+2. Pass a non-standard SSH port with --ssh-port instead
+
+For more information, see docs/user-guide/commands/import.md"
+            }
+            Self::InvalidSshUser { .. } => {
+                "Invalid SSH User - Detailed Troubleshooting:
+
+1. Use a valid Linux username (lowercase letters, digits, '-' and '_')
+2. The user must exist on the instance and have sudo access
+
+For more information, see docs/user-guide/commands/import.md"
+            }
+            Self::SshKeyNotFound { .. } => {
+                "SSH Key Not Found - Detailed Troubleshooting:
+
+1. Check the path passed with --ssh-key exists and is readable
+2. The public key defaults to the private key path with a '.pub' suffix;
+   pass --ssh-public-key if it lives elsewhere
+
+For more information, see docs/user-guide/commands/import.md"
+            }
+            Self::ImportFailed { source, .. } => source.help(),
+            Self::ProgressReportingFailed { .. } => {
+                "Progress Reporting Failed - This is an internal error:
+
+1. This indicates a bug in the application
+2. Please report this issue with:
+   - Full command output
+   - Log file contents (use --log-output file-and-stderr)
+   - Steps to reproduce
+
+Report issues at: https://github.com/torrust/torrust-tracker-deployer/issues"
+            }
+            Self::OutputFormatting { .. } => {
+                "Output Formatting Failed - Critical Internal Error:\n\nThis error should not occur during normal operation. It indicates a bug in the output formatting system.\n\nPlease report it with the command, output format (--output-format) and logs so we can fix it."
+            }
+        }
+    }
+}
@@ -0,0 +1,246 @@
+//! Import Command Handler
+//!
+//! This module handles the import command execution at the presentation layer,
+//! including input validation, progress reporting and result rendering.
+
+use std::cell::RefCell;
+use std::net::IpAddr;
+use std::path::PathBuf;
+use std::sync::Arc;
+
+use parking_lot::ReentrantMutex;
+use torrust_tracker_deployer_dependency_installer::Dependency;
+
+use crate::adapters::ssh::SshCredentials;
+use crate::application::command_handlers::import::{
+    ImportCommandHandler, ImportOutcome, ImportTarget,
+};
+use crate::application::services::deployment_report::DeploymentReportListener;
+use crate::domain::environment::name::EnvironmentName;
+use crate::domain::environment::repository::EnvironmentRepository;
+use crate::presentation::cli::input::cli::OutputFormat;
+use crate::presentation::cli::views::commands::import::{ImportDetailsData, JsonView, TextView};
+use crate::presentation::cli::views::progress::ProgressReporter;
+use crate::presentation::cli::views::Render;
+use crate::presentation::cli::views::UserOutput;
+use crate::shared::clock::Clock;
+use crate::shared::Username;
+
+use super::errors::ImportSubcommandError;
+
+/// Steps in the import workflow
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum ImportStep {
+    ValidateInput,
+    ImportInstance,
+}
+
+impl ImportStep {
+    /// All steps in execution order
+    const ALL: &'static [Self] = &[Self::ValidateInput, Self::ImportInstance];
+
+    /// Total number of steps
+    const fn count() -> usize {
+        Self::ALL.len()
+    }
+
+    /// User-facing description for the step
+    fn description(self) -> &'static str {
+        match self {
+            Self::ValidateInput => "Validating input",
+            Self::ImportInstance => "Importing instance",
+        }
+    }
+}
+
+/// Raw command-line arguments of the import command
+#[derive(Debug, Clone)]
+pub struct ImportArgs {
+    /// Name of the new environment
+    pub environment: String,
+    /// IP address of the existing instance
+    pub ip: String,
+    /// Path to the SSH private key
+    pub ssh_key: PathBuf,
+    /// Path to the SSH public key (defaults to `{ssh_key}.pub`)
+    pub ssh_public_key: Option<PathBuf>,
+    /// SSH username
+    pub ssh_user: String,
+    /// SSH port
+    pub ssh_port: u16,
+    /// Probe the instance for an existing installation
+    pub detect_existing: bool,
+}
+
+/// Presentation layer controller for import command workflow
+///
+/// ## Responsibilities
+///
+/// - Validate the environment name, IP address and SSH credentials
+/// - Delegate the import to the application layer
+/// - Record a deployment report and display the imported environment
+pub struct ImportCommandController {
+    repository: Arc<dyn EnvironmentRepository + Send + Sync>,
+    clock: Arc<dyn Clock>,
+    working_dir: PathBuf,
+    progress: ProgressReporter,
+}
+
+impl ImportCommandController {
+    /// Create a new `ImportCommandController` with dependencies
+    #[allow(clippy::needless_pass_by_value)] // Arc parameters are moved to constructor for ownership
+    pub fn new(
+        repository: Arc<dyn EnvironmentRepository + Send + Sync>,
+        clock: Arc<dyn Clock>,
+        working_dir: PathBuf,
+        user_output: Arc<ReentrantMutex<RefCell<UserOutput>>>,
+    ) -> Self {
+        let progress = ProgressReporter::new(user_output, ImportStep::count());
+
+        Self {
+            repository,
+            clock,
+            working_dir,
+            progress,
+        }
+    }
+
+    /// Execute the import command workflow
+    ///
+    /// # Errors
+    ///
+    /// Returns `ImportSubcommandError` if an argument is invalid or the
+    /// instance cannot be imported
+    pub async fn execute(
+        &mut self,
+        args: &ImportArgs,
+        output_format: OutputFormat,
+    ) -> Result<(), ImportSubcommandError> {
+        self.progress.set_output_format(output_format);
+        let result = self.execute_steps(args, output_format).await;
+        self.progress.finish(result)
+    }
+
+    /// Steps of [`Self::execute`], before the timing summary
+    async fn execute_steps(
+        &mut self,
+        args: &ImportArgs,
+        output_format: OutputFormat,
+    ) -> Result<(), ImportSubcommandError> {
+        let (env_name, target) = self.validate_input(args)?;
+
+        let outcome = self
+            .import_instance(&env_name, target, args.detect_existing)
+            .await?;
+
+        self.display_results(&outcome, output_format)
+    }
+
+    /// Step 1: Validate the name, IP address and SSH credentials
+    fn validate_input(
+        &mut self,
+        args: &ImportArgs,
+    ) -> Result<(EnvironmentName, ImportTarget), ImportSubcommandError> {
+        self.progress
+            .start_step(ImportStep::ValidateInput.description())?;
+
+        let env_name = EnvironmentName::new(args.environment.clone()).map_err(|source| {
+            ImportSubcommandError::InvalidEnvironmentName {
+                name: args.environment.clone(),
+                source,
+            }
+        })?;
+
+        let instance_ip: IpAddr = args.ip.parse().map_err(|e: std::net::AddrParseError| {
+            ImportSubcommandError::InvalidIpAddress {
+                value: args.ip.clone(),
+                reason: e.to_string(),
+            }
+        })?;
+
+        let ssh_username = Username::new(args.ssh_user.clone()).map_err(|source| {
+            ImportSubcommandError::InvalidSshUser {
+                value: args.ssh_user.clone(),
+                source,
+            }
+        })?;
+
+        let ssh_public_key = args.ssh_public_key.clone().unwrap_or_else(|| {
+            let mut path = args.ssh_key.clone().into_os_string();
+            path.push(".pub");
+            PathBuf::from(path)
+        });
+
+        for path in [&args.ssh_key, &ssh_public_key] {
+            if !path.is_file() {
+                return Err(ImportSubcommandError::SshKeyNotFound { path: path.clone() });
+            }
+        }
+
+        self.progress.complete_step(None)?;
+
+        Ok((
+            env_name,
+            ImportTarget {
+                instance_ip,
+                ssh_credentials: SshCredentials::new(
+                    args.ssh_key.clone(),
+                    ssh_public_key,
+                    ssh_username,
+                ),
+                ssh_port: args.ssh_port,
+            },
+        ))
+    }
+
+    /// Step 2: Import the instance via application layer
+    async fn import_instance(
+        &mut self,
+        env_name: &EnvironmentName,
+        target: ImportTarget,
+        detect_existing: bool,
+    ) -> Result<ImportOutcome, ImportSubcommandError> {
+        self.progress
+            .start_step(ImportStep::ImportInstance.description())?;
+
+        let handler = ImportCommandHandler::new(Arc::clone(&self.repository), self.clock.clone());
+        let recorder = DeploymentReportListener::new("import", env_name, self.clock.clone(), None);
+        let result = handler
+            .execute(env_name, target, detect_existing, &self.working_dir)
+            .await;
+        recorder.write(
+            self.repository.as_ref(),
+            &[Dependency::Ansible],
+            result.as_ref().err(),
+        );
+
+        let outcome = result.map_err(|source| ImportSubcommandError::ImportFailed {
+            name: env_name.to_string(),
+            source: Box::new(source),
+        })?;
+
+        self.progress.complete_step(None)?;
+
+        Ok(outcome)
+    }
+
+    /// Display the imported environment
+    ///
+    /// Dispatches to `TextView` or `JsonView` based on `output_format`.
+    fn display_results(
+        &mut self,
+        outcome: &ImportOutcome,
+        output_format: OutputFormat,
+    ) -> Result<(), ImportSubcommandError> {
+        let data = ImportDetailsData::from(outcome);
+        match output_format {
+            OutputFormat::Text => {
+                self.progress.complete(&TextView::render(&data)?)?;
+            }
+            OutputFormat::Json => {
+                self.progress.result(&JsonView::render(&data)?)?;
+            }
+        }
+        Ok(())
+    }
+}
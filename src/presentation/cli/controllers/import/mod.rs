@@ -0,0 +1,16 @@
+//! Import Command Presentation Module
+//!
+//! This module implements the CLI presentation layer for the import command,
+//! which adopts an instance deployed outside this tool as a new environment.
+//!
+//! ## Components
+//!
+//! - `errors` - Presentation layer error types with `.help()` methods
+//! - `handler` - Main command handler orchestrating the workflow
+
+pub mod errors;
+pub mod handler;
+pub use handler::{ImportArgs, ImportCommandController};
+
+// Re-export commonly used types for convenience
+pub use errors::ImportSubcommandError;
@@ -0,0 +1,110 @@
+//! Error types for the Import Bundle Subcommand
+//!
+//! This module defines error types that can occur during CLI import-bundle command execution.
+//! All errors follow the project's error handling principles by providing clear,
+//! contextual, and actionable error messages with `.help()` methods.
+
+use std::path::PathBuf;
+
+use thiserror::Error;
+
+use crate::application::command_handlers::create::config::SecretResolutionError;
+use crate::application::command_handlers::import_bundle::ImportBundleCommandHandlerError;
+use crate::presentation::cli::views::progress::ProgressReporterError;
+use crate::presentation::cli::views::ViewRenderError;
+
+/// Import bundle command specific errors
+#[derive(Debug, Error)]
+pub enum ImportBundleSubcommandError {
+    /// The passphrase could not be read from its environment variable
+    #[error(
+        "Failed to read the bundle passphrase: {source}
+Tip: Export the variable named by --passphrase-env before running the command"
+    )]
+    PassphraseUnavailable {
+        #[source]
+        source: SecretResolutionError,
+    },
+
+    /// The bundle could not be imported
+    #[error(
+        "Failed to import bundle '{path}': {source}
+Tip: Nothing was added to the workspace; fix the problem and import the bundle again"
+    )]
+    ImportFailed {
+        path: PathBuf,
+        #[source]
+        source: ImportBundleCommandHandlerError,
+    },
+
+    // ===== Internal Errors =====
+    /// Progress reporting failed
+    #[error(
+        "Failed to report progress: {source}
+Tip: This is a critical bug - please report it with full logs using --log-output file-and-stderr"
+    )]
+    ProgressReportingFailed {
+        #[source]
+        source: ProgressReporterError,
+    },
+
+    /// Output formatting failed (JSON serialization error).
+    #[error(
+        "Failed to format output: {reason}\nTip: This is a critical bug - please report it with full logs using --log-output file-and-stderr"
+    )]
+    OutputFormatting { reason: String },
+}
+
+// ============================================================================
+// ERROR CONVERSIONS
+// ============================================================================
+
+impl From<ProgressReporterError> for ImportBundleSubcommandError {
+    fn from(source: ProgressReporterError) -> Self {
+        Self::ProgressReportingFailed { source }
+    }
+}
+
+impl From<ViewRenderError> for ImportBundleSubcommandError {
+    fn from(e: ViewRenderError) -> Self {
+        Self::OutputFormatting {
+            reason: e.to_string(),
+        }
+    }
+}
+
+impl ImportBundleSubcommandError {
+    /// Get detailed troubleshooting guidance for this error
+    #[must_use]
+    pub fn help(&self) -> &'static str {
+        match self {
+            Self::PassphraseUnavailable { .. } => {
+                "Passphrase Unavailable - Troubleshooting:
+
+The passphrase is read from the environment variable named by
+--passphrase-env, so it never appears in the shell history.
+
+1. Set the variable and name it:
+   read -rs BUNDLE_PASSPHRASE && export BUNDLE_PASSPHRASE
+   torrust-tracker-deployer import-bundle <file> --passphrase-env BUNDLE_PASSPHRASE
+
+For more information, see docs/user-guide/commands/import-bundle.md"
+            }
+            Self::ImportFailed { source, .. } => source.help(),
+            Self::ProgressReportingFailed { .. } => {
+                "Progress Reporting Failed - This is an internal error:
+
+1. This indicates a bug in the application
+2. Please report this issue with:
+   - Full command output
+   - Log file contents (use --log-output file-and-stderr)
+   - Steps to reproduce
+
+Report issues at: https://github.com/torrust/torrust-tracker-deployer/issues"
+            }
+            Self::OutputFormatting { .. } => {
+                "Output Formatting Failed - Critical Internal Error:\n\nThis error should not occur during normal operation. It indicates a bug in the output formatting system.\n\nPlease report it with the command, output format (--output-format) and logs so we can fix it."
+            }
+        }
+    }
+}
@@ -0,0 +1,180 @@
+//! Import Bundle Command Handler
+//!
+//! This module handles the import-bundle command execution at the presentation
+//! layer, adding an environment exported on another machine to the workspace.
+
+use std::cell::RefCell;
+use std::path::Path;
+use std::sync::Arc;
+
+use parking_lot::ReentrantMutex;
+
+use crate::application::command_handlers::create::config::SecretSource;
+use crate::application::command_handlers::import_bundle::{
+    ImportBundleCommandHandler, ImportedBundle,
+};
+use crate::presentation::cli::input::cli::output_format::OutputFormat;
+use crate::presentation::cli::views::commands::import_bundle::{
+    ImportBundleDetailsData, JsonView, TextView,
+};
+use crate::presentation::cli::views::progress::ProgressReporter;
+use crate::presentation::cli::views::Render;
+use crate::presentation::cli::views::UserOutput;
+use crate::shared::secrets::Password;
+
+use super::errors::ImportBundleSubcommandError;
+
+/// Steps in the import-bundle workflow
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum ImportBundleStep {
+    ReadPassphrase,
+    ImportBundle,
+}
+
+impl ImportBundleStep {
+    /// All steps in execution order
+    const ALL: &'static [Self] = &[Self::ReadPassphrase, Self::ImportBundle];
+
+    /// Total number of steps
+    const fn count() -> usize {
+        Self::ALL.len()
+    }
+
+    /// User-facing description for the step
+    fn description(self) -> &'static str {
+        match self {
+            Self::ReadPassphrase => "Reading passphrase",
+            Self::ImportBundle => "Validating and importing bundle",
+        }
+    }
+}
+
+/// Presentation layer controller for import-bundle command workflow
+///
+/// ## Responsibilities
+///
+/// - Read the passphrase from its variable, if given
+/// - Delegate validating and importing the bundle to the application layer
+/// - Display the directories of the imported environment
+pub struct ImportBundleCommandController {
+    handler: ImportBundleCommandHandler,
+    progress: ProgressReporter,
+}
+
+impl ImportBundleCommandController {
+    /// Create a new `ImportBundleCommandController` with dependencies
+    #[allow(clippy::needless_pass_by_value)] // Arc parameters are moved to constructor for ownership
+    pub fn new(
+        handler: ImportBundleCommandHandler,
+        user_output: Arc<ReentrantMutex<RefCell<UserOutput>>>,
+    ) -> Self {
+        let progress = ProgressReporter::new(user_output, ImportBundleStep::count());
+
+        Self { handler, progress }
+    }
+
+    /// Execute the import-bundle command workflow
+    ///
+    /// # Arguments
+    ///
+    /// * `bundle` - Path of the bundle file written by `export`
+    /// * `passphrase_env` - Variable holding the passphrase of the bundle secrets
+    /// * `output_format` - Output format (Text or Json)
+    ///
+    /// # Errors
+    ///
+    /// Returns `ImportBundleSubcommandError` if the passphrase cannot be read
+    /// or the bundle cannot be imported
+    pub fn execute(
+        &mut self,
+        bundle: &Path,
+        passphrase_env: Option<&str>,
+        output_format: OutputFormat,
+    ) -> Result<(), ImportBundleSubcommandError> {
+        self.progress.set_output_format(output_format);
+        let result = self.execute_steps(bundle, passphrase_env, output_format);
+        self.progress.finish(result)
+    }
+
+    /// Steps of [`Self::execute`], before the timing summary
+    fn execute_steps(
+        &mut self,
+        bundle: &Path,
+        passphrase_env: Option<&str>,
+        output_format: OutputFormat,
+    ) -> Result<(), ImportBundleSubcommandError> {
+        let passphrase = self.read_passphrase(passphrase_env)?;
+
+        let imported = self.import_bundle(bundle, passphrase.as_ref())?;
+
+        self.display_results(&imported, output_format)
+    }
+
+    /// Step 1: Read the passphrase from its variable
+    fn read_passphrase(
+        &mut self,
+        passphrase_env: Option<&str>,
+    ) -> Result<Option<Password>, ImportBundleSubcommandError> {
+        self.progress
+            .start_step(ImportBundleStep::ReadPassphrase.description())?;
+
+        let passphrase = passphrase_env
+            .map(|env| {
+                SecretSource::Env {
+                    env: env.to_string(),
+                }
+                .resolve()
+                .map(Password::from)
+                .map_err(|source| ImportBundleSubcommandError::PassphraseUnavailable { source })
+            })
+            .transpose()?;
+
+        self.progress.complete_step(None)?;
+
+        Ok(passphrase)
+    }
+
+    /// Step 2: Import the bundle via application layer
+    fn import_bundle(
+        &mut self,
+        bundle: &Path,
+        passphrase: Option<&Password>,
+    ) -> Result<ImportedBundle, ImportBundleSubcommandError> {
+        self.progress
+            .start_step(ImportBundleStep::ImportBundle.description())?;
+
+        let imported = self.handler.execute(bundle, passphrase).map_err(|source| {
+            ImportBundleSubcommandError::ImportFailed {
+                path: bundle.to_path_buf(),
+                source,
+            }
+        })?;
+
+        self.progress.complete_step(Some(&format!(
+            "Verified {} files",
+            imported.manifest.files.len()
+        )))?;
+
+        Ok(imported)
+    }
+
+    /// Display the directories of the imported environment
+    fn display_results(
+        &mut self,
+        imported: &ImportedBundle,
+        output_format: OutputFormat,
+    ) -> Result<(), ImportBundleSubcommandError> {
+        let data = ImportBundleDetailsData::from(imported);
+
+        match output_format {
+            OutputFormat::Text => {
+                self.progress.complete(&TextView::render(&data)?)?;
+            }
+            OutputFormat::Json => {
+                self.progress.result(&JsonView::render(&data)?)?;
+            }
+        }
+
+        Ok(())
+    }
+}
@@ -0,0 +1,17 @@
+//! Import Bundle Command Presentation Module
+//!
+//! This module implements the CLI presentation layer for the import-bundle
+//! command, which adds an environment written by `export` on another machine
+//! to this workspace.
+//!
+//! ## Components
+//!
+//! - `errors` - Presentation layer error types with `.help()` methods
+//! - `handler` - Main command handler orchestrating the workflow
+
+pub mod errors;
+pub mod handler;
+pub use handler::ImportBundleCommandController;
+
+// Re-export commonly used types for convenience
+pub use errors::ImportBundleSubcommandError;
@@ -0,0 +1,136 @@
+//! Init Command Handler
+//!
+//! This module handles the init command execution at the presentation layer,
+//! turning the working directory into a deployer workspace.
+
+use std::cell::RefCell;
+use std::path::PathBuf;
+use std::sync::Arc;
+
+use parking_lot::ReentrantMutex;
+
+use crate::application::command_handlers::init::{InitCommandHandler, WorkspaceInitialization};
+use crate::presentation::cli::input::cli::output_format::OutputFormat;
+use crate::presentation::cli::views::commands::init::{InitDetailsData, JsonView, TextView};
+use crate::presentation::cli::views::progress::ProgressReporter;
+use crate::presentation::cli::views::Render;
+use crate::presentation::cli::views::UserOutput;
+
+use super::errors::InitSubcommandError;
+
+/// Steps in the init workflow
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum InitStep {
+    InitializeWorkspace,
+}
+
+impl InitStep {
+    /// All steps in execution order
+    const ALL: &'static [Self] = &[Self::InitializeWorkspace];
+
+    /// Total number of steps
+    const fn count() -> usize {
+        Self::ALL.len()
+    }
+
+    /// User-facing description for the step
+    fn description(self) -> &'static str {
+        match self {
+            Self::InitializeWorkspace => "Initializing workspace",
+        }
+    }
+}
+
+/// Presentation layer controller for init command workflow
+///
+/// ## Responsibilities
+///
+/// - Delegate the initialization to the application layer
+/// - Display the layout of the workspace and the migrated environments
+pub struct InitCommandController {
+    handler: InitCommandHandler,
+    working_dir: PathBuf,
+    progress: ProgressReporter,
+}
+
+impl InitCommandController {
+    /// Create a new `InitCommandController` with dependencies
+    #[allow(clippy::needless_pass_by_value)] // Arc parameters are moved to constructor for ownership
+    pub fn new(
+        handler: InitCommandHandler,
+        working_dir: PathBuf,
+        user_output: Arc<ReentrantMutex<RefCell<UserOutput>>>,
+    ) -> Self {
+        let progress = ProgressReporter::new(user_output, InitStep::count());
+
+        Self {
+            handler,
+            working_dir,
+            progress,
+        }
+    }
+
+    /// Execute the init command workflow
+    ///
+    /// # Errors
+    ///
+    /// Returns `InitSubcommandError` if the workspace cannot be initialized
+    pub fn execute(&mut self, output_format: OutputFormat) -> Result<(), InitSubcommandError> {
+        self.progress.set_output_format(output_format);
+        let result = self.execute_steps(output_format);
+        self.progress.finish(result)
+    }
+
+    /// Steps of [`Self::execute`], before the timing summary
+    fn execute_steps(&mut self, output_format: OutputFormat) -> Result<(), InitSubcommandError> {
+        let initialization = self.initialize_workspace()?;
+
+        self.display_results(&initialization, output_format)
+    }
+
+    /// Step 1: Initialize the workspace via application layer
+    fn initialize_workspace(&mut self) -> Result<WorkspaceInitialization, InitSubcommandError> {
+        self.progress
+            .start_step(InitStep::InitializeWorkspace.description())?;
+
+        let initialization =
+            self.handler
+                .execute()
+                .map_err(|source| InitSubcommandError::InitFailed {
+                    path: self.working_dir.clone(),
+                    source,
+                })?;
+
+        let detail = if initialization.already_initialized {
+            "Workspace already initialized".to_string()
+        } else {
+            format!(
+                "Wrote workspace marker (format version {})",
+                initialization.format_version
+            )
+        };
+        self.progress.complete_step(Some(&detail))?;
+
+        Ok(initialization)
+    }
+
+    /// Display the layout of the workspace
+    fn display_results(
+        &mut self,
+        initialization: &WorkspaceInitialization,
+        output_format: OutputFormat,
+    ) -> Result<(), InitSubcommandError> {
+        let data = InitDetailsData::from(initialization);
+
+        match output_format {
+            OutputFormat::Text => {
+                self.progress.complete(&TextView::render(&data)?)?;
+            }
+            OutputFormat::Json => {
+                self.progress.result(&JsonView::render(&data)?)?;
+            }
+        }
+
+        Ok(())
+    }
+}
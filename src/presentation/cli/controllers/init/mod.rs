@@ -0,0 +1,17 @@
+//! Init Command Presentation Module
+//!
+//! This module implements the CLI presentation layer for the init command,
+//! which creates the data/ and build/ directories and the workspace marker
+//! in the working directory. Running it again on a workspace is safe.
+//!
+//! ## Components
+//!
+//! - `errors` - Presentation layer error types with `.help()` methods
+//! - `handler` - Main command handler orchestrating the workflow
+
+pub mod errors;
+pub mod handler;
+pub use handler::InitCommandController;
+
+// Re-export commonly used types for convenience
+pub use errors::InitSubcommandError;
@@ -0,0 +1,39 @@
+//! Unified Keys Command Errors
+//!
+//! This module defines the error type of every keys subcommand.
+
+use thiserror::Error;
+
+use super::subcommands::add::KeysAddCommandError;
+use super::subcommands::list::KeysListCommandError;
+use super::subcommands::remove::KeysRemoveCommandError;
+
+/// Unified error type for all keys subcommands
+#[derive(Debug, Error)]
+pub enum KeysCommandError {
+    /// Errors adding a key
+    #[error(transparent)]
+    Add(#[from] KeysAddCommandError),
+
+    /// Errors listing the keys
+    #[error(transparent)]
+    List(#[from] KeysListCommandError),
+
+    /// Errors removing a key
+    #[error(transparent)]
+    Remove(#[from] KeysRemoveCommandError),
+}
+
+impl KeysCommandError {
+    /// Get detailed troubleshooting guidance for this error
+    ///
+    /// Delegates to the help of the failed subcommand.
+    #[must_use]
+    pub fn help(&self) -> String {
+        match self {
+            Self::Add(err) => err.help().to_string(),
+            Self::List(err) => err.help().to_string(),
+            Self::Remove(err) => err.help().to_string(),
+        }
+    }
+}
@@ -0,0 +1,19 @@
+//! Keys Command Controller (Presentation Layer)
+//!
+//! This module handles the `keys` command, which manages the authentication
+//! keys of a private tracker through the API of the deployed tracker.
+//!
+//! ## Subcommands
+//!
+//! - `keys add <env> [--duration 30d]` - Mints a key, permanent without
+//!   `--duration`, and records it for the environment
+//! - `keys list <env>` - Lists the recorded keys with their expiry times
+//! - `keys remove <env> <key>` - Revokes a key
+
+pub mod errors;
+pub mod router;
+pub mod subcommands;
+
+// Re-export commonly used types for convenience
+pub use errors::KeysCommandError;
+pub use router::route_command;
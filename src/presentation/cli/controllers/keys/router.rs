@@ -0,0 +1,48 @@
+//! Keys Command Router
+//!
+//! This module routes the keys command to its subcommands.
+
+use crate::presentation::cli::dispatch::ExecutionContext;
+use crate::presentation::cli::input::cli::commands::KeysAction;
+
+use super::errors::KeysCommandError;
+
+/// Route the keys command to its appropriate subcommand
+///
+/// # Arguments
+///
+/// * `action` - The keys action to perform
+/// * `context` - Execution context providing access to application services
+///
+/// # Errors
+///
+/// Returns an error if the subcommand execution fails.
+pub async fn route_command(
+    action: KeysAction,
+    context: &ExecutionContext,
+) -> Result<(), KeysCommandError> {
+    let output_format = context.output_format();
+
+    match action {
+        KeysAction::Add {
+            environment,
+            duration,
+        } => context
+            .container()
+            .create_keys_add_controller()
+            .execute(&environment, duration, output_format)
+            .await
+            .map_err(KeysCommandError::Add),
+        KeysAction::List { environment } => context
+            .container()
+            .create_keys_list_controller()
+            .execute(&environment, output_format)
+            .map_err(KeysCommandError::List),
+        KeysAction::Remove { environment, key } => context
+            .container()
+            .create_keys_remove_controller()
+            .execute(&environment, &key, output_format)
+            .await
+            .map_err(KeysCommandError::Remove),
+    }
+}
@@ -0,0 +1,172 @@
+//! Keys Add Command Controller (Presentation Layer)
+//!
+//! This module handles `keys add` at the presentation layer: it mints a key
+//! on the tracker of an environment and displays it.
+
+use std::cell::RefCell;
+use std::sync::Arc;
+use std::time::Duration;
+
+use parking_lot::ReentrantMutex;
+
+use crate::application::command_handlers::keys::KeysCommandHandler;
+use crate::domain::tracker::IssuedAuthKey;
+use crate::domain::EnvironmentName;
+use crate::presentation::cli::input::cli::output_format::OutputFormat;
+use crate::presentation::cli::views::commands::keys::{AddedKeyData, JsonView, TextView};
+use crate::presentation::cli::views::progress::ProgressReporter;
+use crate::presentation::cli::views::Render;
+use crate::presentation::cli::views::UserOutput;
+
+use super::errors::KeysAddCommandError;
+
+/// Steps in the `keys add` workflow
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum KeysAddStep {
+    ValidateEnvironment,
+    AddKey,
+}
+
+impl KeysAddStep {
+    /// All steps in execution order
+    const ALL: &'static [Self] = &[Self::ValidateEnvironment, Self::AddKey];
+
+    /// Total number of steps
+    const fn count() -> usize {
+        Self::ALL.len()
+    }
+
+    /// User-facing description for the step
+    fn description(self) -> &'static str {
+        match self {
+            Self::ValidateEnvironment => "Validating environment",
+            Self::AddKey => "Adding key through the tracker API",
+        }
+    }
+}
+
+/// Presentation layer controller for the `keys add` workflow
+///
+/// ## Responsibilities
+///
+/// - Validate the environment name
+/// - Delegate minting and recording the key to the application layer
+/// - Display the key and its expiry time
+pub struct KeysAddCommandController {
+    handler: KeysCommandHandler,
+    progress: ProgressReporter,
+}
+
+impl KeysAddCommandController {
+    /// Create a new `KeysAddCommandController` with dependencies
+    #[allow(clippy::needless_pass_by_value)] // Arc parameters are moved to constructor for ownership
+    pub fn new(
+        handler: KeysCommandHandler,
+        user_output: Arc<ReentrantMutex<RefCell<UserOutput>>>,
+    ) -> Self {
+        let progress = ProgressReporter::new(user_output, KeysAddStep::count());
+
+        Self { handler, progress }
+    }
+
+    /// Execute the `keys add` workflow
+    ///
+    /// # Arguments
+    ///
+    /// * `name` - Name of the environment
+    /// * `duration` - How long the key is valid, permanent when `None`
+    /// * `output_format` - Output format (Text or Json)
+    ///
+    /// # Errors
+    ///
+    /// Returns `KeysAddCommandError` if the name is invalid or the key cannot
+    /// be added
+    pub async fn execute(
+        &mut self,
+        name: &str,
+        duration: Option<Duration>,
+        output_format: OutputFormat,
+    ) -> Result<(), KeysAddCommandError> {
+        self.progress.set_output_format(output_format);
+        let result = self.execute_steps(name, duration, output_format).await;
+        self.progress.finish(result)
+    }
+
+    /// Steps of [`Self::execute`], before the timing summary
+    async fn execute_steps(
+        &mut self,
+        name: &str,
+        duration: Option<Duration>,
+        output_format: OutputFormat,
+    ) -> Result<(), KeysAddCommandError> {
+        let env_name = self.validate_environment_name(name)?;
+
+        let issued = self.add_key(&env_name, duration).await?;
+
+        self.display_results(&env_name, &issued, output_format)
+    }
+
+    /// Step 1: Validate the environment name
+    fn validate_environment_name(
+        &mut self,
+        name: &str,
+    ) -> Result<EnvironmentName, KeysAddCommandError> {
+        self.progress
+            .start_step(KeysAddStep::ValidateEnvironment.description())?;
+
+        let env_name = EnvironmentName::new(name.to_string()).map_err(|source| {
+            KeysAddCommandError::InvalidEnvironmentName {
+                name: name.to_string(),
+                source,
+            }
+        })?;
+
+        self.progress.complete_step(None)?;
+
+        Ok(env_name)
+    }
+
+    /// Step 2: Mint the key via application layer
+    async fn add_key(
+        &mut self,
+        env_name: &EnvironmentName,
+        duration: Option<Duration>,
+    ) -> Result<IssuedAuthKey, KeysAddCommandError> {
+        self.progress
+            .start_step(KeysAddStep::AddKey.description())?;
+
+        let issued = self
+            .handler
+            .add(env_name, duration)
+            .await
+            .map_err(|source| KeysAddCommandError::AddFailed {
+                name: env_name.to_string(),
+                source,
+            })?;
+
+        self.progress.complete_step(None)?;
+
+        Ok(issued)
+    }
+
+    /// Display the new key
+    fn display_results(
+        &mut self,
+        env_name: &EnvironmentName,
+        issued: &IssuedAuthKey,
+        output_format: OutputFormat,
+    ) -> Result<(), KeysAddCommandError> {
+        let data = AddedKeyData::new(env_name.as_str(), issued);
+
+        match output_format {
+            OutputFormat::Text => {
+                self.progress.complete(&TextView::render(&data)?)?;
+            }
+            OutputFormat::Json => {
+                self.progress.result(&JsonView::render(&data)?)?;
+            }
+        }
+
+        Ok(())
+    }
+}
@@ -0,0 +1,11 @@
+//! Keys Add Subcommand
+//!
+//! This module handles `keys add`, which mints a key on the tracker of an
+//! environment and records it.
+
+pub mod errors;
+pub mod handler;
+
+// Re-export the main handler and error types
+pub use errors::KeysAddCommandError;
+pub use handler::KeysAddCommandController;
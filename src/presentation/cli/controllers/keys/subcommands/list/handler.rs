@@ -0,0 +1,166 @@
+//! Keys List Command Controller (Presentation Layer)
+//!
+//! This module handles `keys list` at the presentation layer: it displays the
+//! keys recorded for an environment with their expiry times.
+
+use std::cell::RefCell;
+use std::sync::Arc;
+
+use parking_lot::ReentrantMutex;
+
+use crate::application::command_handlers::keys::{KeysCommandHandler, ListedAuthKey};
+use crate::domain::EnvironmentName;
+use crate::presentation::cli::input::cli::output_format::OutputFormat;
+use crate::presentation::cli::views::commands::keys::{JsonView, KeyListData, TextView};
+use crate::presentation::cli::views::progress::ProgressReporter;
+use crate::presentation::cli::views::Render;
+use crate::presentation::cli::views::UserOutput;
+
+use super::errors::KeysListCommandError;
+
+/// Steps in the `keys list` workflow
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum KeysListStep {
+    ValidateEnvironment,
+    LoadKeys,
+}
+
+impl KeysListStep {
+    /// All steps in execution order
+    const ALL: &'static [Self] = &[Self::ValidateEnvironment, Self::LoadKeys];
+
+    /// Total number of steps
+    const fn count() -> usize {
+        Self::ALL.len()
+    }
+
+    /// User-facing description for the step
+    fn description(self) -> &'static str {
+        match self {
+            Self::ValidateEnvironment => "Validating environment",
+            Self::LoadKeys => "Loading recorded keys",
+        }
+    }
+}
+
+/// Presentation layer controller for the `keys list` workflow
+///
+/// ## Responsibilities
+///
+/// - Validate the environment name
+/// - Load the recorded keys via the application layer
+/// - Display the keys with their expiry times
+pub struct KeysListCommandController {
+    handler: KeysCommandHandler,
+    progress: ProgressReporter,
+}
+
+impl KeysListCommandController {
+    /// Create a new `KeysListCommandController` with dependencies
+    #[allow(clippy::needless_pass_by_value)] // Arc parameters are moved to constructor for ownership
+    pub fn new(
+        handler: KeysCommandHandler,
+        user_output: Arc<ReentrantMutex<RefCell<UserOutput>>>,
+    ) -> Self {
+        let progress = ProgressReporter::new(user_output, KeysListStep::count());
+
+        Self { handler, progress }
+    }
+
+    /// Execute the `keys list` workflow
+    ///
+    /// # Arguments
+    ///
+    /// * `name` - Name of the environment
+    /// * `output_format` - Output format (Text or Json)
+    ///
+    /// # Errors
+    ///
+    /// Returns `KeysListCommandError` if the name is invalid or the
+    /// environment cannot be loaded
+    pub fn execute(
+        &mut self,
+        name: &str,
+        output_format: OutputFormat,
+    ) -> Result<(), KeysListCommandError> {
+        self.progress.set_output_format(output_format);
+        let result = self.execute_steps(name, output_format);
+        self.progress.finish(result)
+    }
+
+    /// Steps of [`Self::execute`], before the timing summary
+    fn execute_steps(
+        &mut self,
+        name: &str,
+        output_format: OutputFormat,
+    ) -> Result<(), KeysListCommandError> {
+        let env_name = self.validate_environment_name(name)?;
+
+        let keys = self.load_keys(&env_name)?;
+
+        self.display_results(&env_name, &keys, output_format)
+    }
+
+    /// Step 1: Validate the environment name
+    fn validate_environment_name(
+        &mut self,
+        name: &str,
+    ) -> Result<EnvironmentName, KeysListCommandError> {
+        self.progress
+            .start_step(KeysListStep::ValidateEnvironment.description())?;
+
+        let env_name = EnvironmentName::new(name.to_string()).map_err(|source| {
+            KeysListCommandError::InvalidEnvironmentName {
+                name: name.to_string(),
+                source,
+            }
+        })?;
+
+        self.progress.complete_step(None)?;
+
+        Ok(env_name)
+    }
+
+    /// Step 2: Load the recorded keys via application layer
+    fn load_keys(
+        &mut self,
+        env_name: &EnvironmentName,
+    ) -> Result<Vec<ListedAuthKey>, KeysListCommandError> {
+        self.progress
+            .start_step(KeysListStep::LoadKeys.description())?;
+
+        let keys =
+            self.handler
+                .list(env_name)
+                .map_err(|source| KeysListCommandError::ListFailed {
+                    name: env_name.to_string(),
+                    source,
+                })?;
+
+        self.progress
+            .complete_step(Some(&format!("Found {} keys", keys.len())))?;
+
+        Ok(keys)
+    }
+
+    /// Display the recorded keys
+    fn display_results(
+        &mut self,
+        env_name: &EnvironmentName,
+        keys: &[ListedAuthKey],
+        output_format: OutputFormat,
+    ) -> Result<(), KeysListCommandError> {
+        let data = KeyListData::new(env_name.as_str(), keys);
+
+        match output_format {
+            OutputFormat::Text => {
+                self.progress.complete(&TextView::render(&data)?)?;
+            }
+            OutputFormat::Json => {
+                self.progress.result(&JsonView::render(&data)?)?;
+            }
+        }
+
+        Ok(())
+    }
+}
@@ -0,0 +1,11 @@
+//! Keys List Subcommand
+//!
+//! This module handles `keys list`, which shows the keys recorded for an
+//! environment with their expiry times.
+
+pub mod errors;
+pub mod handler;
+
+// Re-export the main handler and error types
+pub use errors::KeysListCommandError;
+pub use handler::KeysListCommandController;
@@ -0,0 +1,7 @@
+//! Keys Subcommands Module
+//!
+//! This module contains the individual subcommands for the keys command.
+
+pub mod add;
+pub mod list;
+pub mod remove;
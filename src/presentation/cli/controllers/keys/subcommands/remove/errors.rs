@@ -0,0 +1,133 @@
+//! Error types for the Keys Remove Subcommand
+//!
+//! This module defines error types that can occur during CLI `keys remove` execution.
+//! All errors follow the project's error handling principles by providing clear,
+//! contextual, and actionable error messages with `.help()` methods.
+
+use thiserror::Error;
+
+use crate::application::command_handlers::keys::KeysCommandHandlerError;
+use crate::domain::environment::name::EnvironmentNameError;
+use crate::domain::tracker::AuthKeyError;
+use crate::presentation::cli::views::progress::ProgressReporterError;
+use crate::presentation::cli::views::ViewRenderError;
+
+/// `keys remove` specific errors
+#[derive(Debug, Error)]
+pub enum KeysRemoveCommandError {
+    // ===== Environment Validation Errors =====
+    /// Environment name validation failed
+    #[error("Invalid environment name '{name}': {source}
+Tip: Environment names must be 1-63 characters, start with letter/digit, contain only letters/digits/hyphens")]
+    InvalidEnvironmentName {
+        name: String,
+        #[source]
+        source: EnvironmentNameError,
+    },
+
+    /// The key given on the command line is not a tracker key
+    #[error(
+        "{source}
+Tip: Copy the key from 'keys list <environment>'"
+    )]
+    InvalidKey {
+        #[source]
+        source: AuthKeyError,
+    },
+
+    /// The key could not be removed
+    #[error(
+        "Failed to remove a key from the tracker of environment '{name}': {source}
+Tip: Check the environment with 'show {name}'"
+    )]
+    RemoveFailed {
+        name: String,
+        #[source]
+        source: KeysCommandHandlerError,
+    },
+
+    // ===== Internal Errors =====
+    /// Progress reporting failed
+    #[error(
+        "Failed to report progress: {source}
+Tip: This is a critical bug - please report it with full logs using --log-output file-and-stderr"
+    )]
+    ProgressReportingFailed {
+        #[source]
+        source: ProgressReporterError,
+    },
+
+    /// Output formatting failed (JSON serialization error).
+    #[error(
+        "Failed to format output: {reason}\nTip: This is a critical bug - please report it with full logs using --log-output file-and-stderr"
+    )]
+    OutputFormatting { reason: String },
+}
+
+// ============================================================================
+// ERROR CONVERSIONS
+// ============================================================================
+
+impl From<ProgressReporterError> for KeysRemoveCommandError {
+    fn from(source: ProgressReporterError) -> Self {
+        Self::ProgressReportingFailed { source }
+    }
+}
+
+impl From<ViewRenderError> for KeysRemoveCommandError {
+    fn from(e: ViewRenderError) -> Self {
+        Self::OutputFormatting {
+            reason: e.to_string(),
+        }
+    }
+}
+
+impl KeysRemoveCommandError {
+    /// Get detailed troubleshooting guidance for this error
+    #[must_use]
+    pub fn help(&self) -> &'static str {
+        match self {
+            Self::InvalidEnvironmentName { .. } => {
+                "Invalid Environment Name - Detailed Troubleshooting:
+
+1. Check name format requirements:
+   - Length: 1-63 characters
+   - Start: Must begin with a letter or digit
+   - Characters: Only lowercase letters, digits, and hyphens allowed
+
+2. Valid examples:
+   - keys remove prod YZSl4lMZupRuOpSRC3krIKR5BPB14nrJ
+
+For more information, see docs/user-guide/commands/keys.md"
+            }
+            Self::InvalidKey { .. } => {
+                "Invalid Key - Troubleshooting:
+
+Tracker keys have exactly 32 letters and digits, e.g.
+YZSl4lMZupRuOpSRC3krIKR5BPB14nrJ
+
+1. List the keys recorded for the environment:
+   torrust-tracker-deployer keys list <env-name>
+
+2. Pass the key without the announce URL around it
+
+For more information, see docs/user-guide/commands/keys.md"
+            }
+            Self::RemoveFailed { source, .. } => source.help(),
+            Self::ProgressReportingFailed { .. } => {
+                "Progress Reporting Failed - This is an internal error:
+
+1. This indicates a bug in the application
+2. Please report this issue with:
+   - Full command output
+   - Log file contents (use --log-output file-and-stderr)
+   - Steps to reproduce
+
+Report issues at: https://github.com/torrust/torrust-tracker-deployer/issues"
+            }
+            Self::OutputFormatting { .. } => {
+                "Output Formatting Failed - Critical Internal Error:\n\nThis error should not occur during normal operation. It indicates a bug in the output formatting system.\n\nPlease report it with the command, output format (--output-format) and logs so we can fix it."
+            }
+        }
+    }
+}
@@ -0,0 +1,177 @@
+//! Keys Remove Command Controller (Presentation Layer)
+//!
+//! This module handles `keys remove` at the presentation layer: it revokes a
+//! key on the tracker of an environment and confirms it.
+
+use std::cell::RefCell;
+use std::sync::Arc;
+
+use parking_lot::ReentrantMutex;
+
+use crate::application::command_handlers::keys::KeysCommandHandler;
+use crate::domain::tracker::AuthKey;
+use crate::domain::EnvironmentName;
+use crate::presentation::cli::input::cli::output_format::OutputFormat;
+use crate::presentation::cli::views::commands::keys::{JsonView, RemovedKeyData, TextView};
+use crate::presentation::cli::views::progress::ProgressReporter;
+use crate::presentation::cli::views::Render;
+use crate::presentation::cli::views::UserOutput;
+
+use super::errors::KeysRemoveCommandError;
+
+/// Steps in the `keys remove` workflow
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum KeysRemoveStep {
+    ValidateInput,
+    RemoveKey,
+}
+
+impl KeysRemoveStep {
+    /// All steps in execution order
+    const ALL: &'static [Self] = &[Self::ValidateInput, Self::RemoveKey];
+
+    /// Total number of steps
+    const fn count() -> usize {
+        Self::ALL.len()
+    }
+
+    /// User-facing description for the step
+    fn description(self) -> &'static str {
+        match self {
+            Self::ValidateInput => "Validating environment and key",
+            Self::RemoveKey => "Removing key through the tracker API",
+        }
+    }
+}
+
+/// Presentation layer controller for the `keys remove` workflow
+///
+/// ## Responsibilities
+///
+/// - Validate the environment name and the key
+/// - Delegate revoking and forgetting the key to the application layer
+/// - Confirm the removal
+pub struct KeysRemoveCommandController {
+    handler: KeysCommandHandler,
+    progress: ProgressReporter,
+}
+
+impl KeysRemoveCommandController {
+    /// Create a new `KeysRemoveCommandController` with dependencies
+    #[allow(clippy::needless_pass_by_value)] // Arc parameters are moved to constructor for ownership
+    pub fn new(
+        handler: KeysCommandHandler,
+        user_output: Arc<ReentrantMutex<RefCell<UserOutput>>>,
+    ) -> Self {
+        let progress = ProgressReporter::new(user_output, KeysRemoveStep::count());
+
+        Self { handler, progress }
+    }
+
+    /// Execute the `keys remove` workflow
+    ///
+    /// # Arguments
+    ///
+    /// * `name` - Name of the environment
+    /// * `key` - The key to revoke
+    /// * `output_format` - Output format (Text or Json)
+    ///
+    /// # Errors
+    ///
+    /// Returns `KeysRemoveCommandError` if the name or the key is invalid or
+    /// the key cannot be removed
+    pub async fn execute(
+        &mut self,
+        name: &str,
+        key: &str,
+        output_format: OutputFormat,
+    ) -> Result<(), KeysRemoveCommandError> {
+        self.progress.set_output_format(output_format);
+        let result = self.execute_steps(name, key, output_format).await;
+        self.progress.finish(result)
+    }
+
+    /// Steps of [`Self::execute`], before the timing summary
+    async fn execute_steps(
+        &mut self,
+        name: &str,
+        key: &str,
+        output_format: OutputFormat,
+    ) -> Result<(), KeysRemoveCommandError> {
+        let (env_name, key) = self.validate_input(name, key)?;
+
+        let was_recorded = self.remove_key(&env_name, &key).await?;
+
+        self.display_results(&env_name, &key, was_recorded, output_format)
+    }
+
+    /// Step 1: Validate the environment name and the key
+    fn validate_input(
+        &mut self,
+        name: &str,
+        key: &str,
+    ) -> Result<(EnvironmentName, AuthKey), KeysRemoveCommandError> {
+        self.progress
+            .start_step(KeysRemoveStep::ValidateInput.description())?;
+
+        let env_name = EnvironmentName::new(name.to_string()).map_err(|source| {
+            KeysRemoveCommandError::InvalidEnvironmentName {
+                name: name.to_string(),
+                source,
+            }
+        })?;
+        let key = AuthKey::new(key.trim())
+            .map_err(|source| KeysRemoveCommandError::InvalidKey { source })?;
+
+        self.progress.complete_step(None)?;
+
+        Ok((env_name, key))
+    }
+
+    /// Step 2: Revoke the key via application layer
+    async fn remove_key(
+        &mut self,
+        env_name: &EnvironmentName,
+        key: &AuthKey,
+    ) -> Result<bool, KeysRemoveCommandError> {
+        self.progress
+            .start_step(KeysRemoveStep::RemoveKey.description())?;
+
+        let was_recorded = self.handler.remove(env_name, key).await.map_err(|source| {
+            KeysRemoveCommandError::RemoveFailed {
+                name: env_name.to_string(),
+                source,
+            }
+        })?;
+
+        self.progress.complete_step(None)?;
+
+        Ok(was_recorded)
+    }
+
+    /// Confirm the removal
+    fn display_results(
+        &mut self,
+        env_name: &EnvironmentName,
+        key: &AuthKey,
+        was_recorded: bool,
+        output_format: OutputFormat,
+    ) -> Result<(), KeysRemoveCommandError> {
+        let data = RemovedKeyData {
+            environment: env_name.to_string(),
+            key: key.to_string(),
+            was_recorded,
+        };
+
+        match output_format {
+            OutputFormat::Text => {
+                self.progress.complete(&TextView::render(&data)?)?;
+            }
+            OutputFormat::Json => {
+                self.progress.result(&JsonView::render(&data)?)?;
+            }
+        }
+
+        Ok(())
+    }
+}
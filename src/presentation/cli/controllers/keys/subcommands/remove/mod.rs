@@ -0,0 +1,11 @@
+//! Keys Remove Subcommand
+//!
+//! This module handles `keys remove`, which revokes a key on the tracker of
+//! an environment and forgets it.
+
+pub mod errors;
+pub mod handler;
+
+// Re-export the main handler and error types
+pub use errors::KeysRemoveCommandError;
+pub use handler::KeysRemoveCommandController;
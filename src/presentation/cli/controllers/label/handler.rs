@@ -0,0 +1,173 @@
+//! Label Command Handler
+//!
+//! This module handles the label command execution at the presentation layer,
+//! setting and removing the labels of an existing environment.
+
+use std::cell::RefCell;
+use std::sync::Arc;
+
+use parking_lot::ReentrantMutex;
+
+use crate::application::command_handlers::label::LabelCommandHandler;
+use crate::domain::environment::state::AnyEnvironmentState;
+use crate::domain::environment::{EnvironmentName, Label};
+use crate::presentation::cli::input::cli::output_format::OutputFormat;
+use crate::presentation::cli::views::commands::label::{JsonView, LabelDetailsData, TextView};
+use crate::presentation::cli::views::progress::ProgressReporter;
+use crate::presentation::cli::views::Render;
+use crate::presentation::cli::views::UserOutput;
+
+use super::errors::LabelSubcommandError;
+
+/// Steps in the label workflow
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum LabelStep {
+    ValidateEnvironment,
+    UpdateLabels,
+}
+
+impl LabelStep {
+    /// All steps in execution order
+    const ALL: &'static [Self] = &[Self::ValidateEnvironment, Self::UpdateLabels];
+
+    /// Total number of steps
+    const fn count() -> usize {
+        Self::ALL.len()
+    }
+
+    /// User-facing description for the step
+    fn description(self) -> &'static str {
+        match self {
+            Self::ValidateEnvironment => "Validating environment",
+            Self::UpdateLabels => "Updating labels",
+        }
+    }
+}
+
+/// Presentation layer controller for label command workflow
+///
+/// ## Responsibilities
+///
+/// - Validate the environment name
+/// - Delegate the label changes to the application layer
+/// - Display the labels of the environment
+pub struct LabelCommandController {
+    handler: LabelCommandHandler,
+    progress: ProgressReporter,
+}
+
+impl LabelCommandController {
+    /// Create a new `LabelCommandController` with dependencies
+    #[allow(clippy::needless_pass_by_value)] // Arc parameters are moved to constructor for ownership
+    pub fn new(
+        handler: LabelCommandHandler,
+        user_output: Arc<ReentrantMutex<RefCell<UserOutput>>>,
+    ) -> Self {
+        let progress = ProgressReporter::new(user_output, LabelStep::count());
+
+        Self { handler, progress }
+    }
+
+    /// Execute the label command workflow
+    ///
+    /// # Arguments
+    ///
+    /// * `name` - Name of the environment
+    /// * `set` - Labels to set, replacing the values of existing keys
+    /// * `remove` - Keys of the labels to remove
+    /// * `output_format` - Output format (Text or Json)
+    ///
+    /// # Errors
+    ///
+    /// Returns `LabelSubcommandError` if the name is invalid or the labels
+    /// cannot be changed
+    pub fn execute(
+        &mut self,
+        name: &str,
+        set: &[Label],
+        remove: &[String],
+        output_format: OutputFormat,
+    ) -> Result<(), LabelSubcommandError> {
+        self.progress.set_output_format(output_format);
+        let result = self.execute_steps(name, set, remove, output_format);
+        self.progress.finish(result)
+    }
+
+    /// Steps of [`Self::execute`], before the timing summary
+    fn execute_steps(
+        &mut self,
+        name: &str,
+        set: &[Label],
+        remove: &[String],
+        output_format: OutputFormat,
+    ) -> Result<(), LabelSubcommandError> {
+        let env_name = self.validate_environment_name(name)?;
+
+        let environment = self.update_labels(&env_name, set, remove)?;
+
+        self.display_results(&environment, output_format)
+    }
+
+    /// Step 1: Validate the environment name
+    fn validate_environment_name(
+        &mut self,
+        name: &str,
+    ) -> Result<EnvironmentName, LabelSubcommandError> {
+        self.progress
+            .start_step(LabelStep::ValidateEnvironment.description())?;
+
+        let env_name = EnvironmentName::new(name.to_string()).map_err(|source| {
+            LabelSubcommandError::InvalidEnvironmentName {
+                name: name.to_string(),
+                source,
+            }
+        })?;
+
+        self.progress.complete_step(None)?;
+
+        Ok(env_name)
+    }
+
+    /// Step 2: Change the labels via application layer
+    fn update_labels(
+        &mut self,
+        env_name: &EnvironmentName,
+        set: &[Label],
+        remove: &[String],
+    ) -> Result<AnyEnvironmentState, LabelSubcommandError> {
+        self.progress
+            .start_step(LabelStep::UpdateLabels.description())?;
+
+        let environment = self
+            .handler
+            .execute(env_name, set, remove)
+            .map_err(|source| LabelSubcommandError::LabelFailed {
+                name: env_name.to_string(),
+                source,
+            })?;
+
+        self.progress.complete_step(None)?;
+
+        Ok(environment)
+    }
+
+    /// Display the labels of the environment
+    fn display_results(
+        &mut self,
+        environment: &AnyEnvironmentState,
+        output_format: OutputFormat,
+    ) -> Result<(), LabelSubcommandError> {
+        let data = LabelDetailsData::from_environment(environment);
+
+        match output_format {
+            OutputFormat::Text => {
+                self.progress.complete(&TextView::render(&data)?)?;
+            }
+            OutputFormat::Json => {
+                self.progress.result(&JsonView::render(&data)?)?;
+            }
+        }
+
+        Ok(())
+    }
+}
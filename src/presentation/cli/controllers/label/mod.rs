@@ -0,0 +1,16 @@
+//! Label Command Presentation Module
+//!
+//! This module implements the CLI presentation layer for the label command,
+//! which sets and removes the labels used to organize environments.
+//!
+//! ## Components
+//!
+//! - `errors` - Presentation layer error types with `.help()` methods
+//! - `handler` - Main command handler orchestrating the workflow
+
+pub mod errors;
+pub mod handler;
+pub use handler::LabelCommandController;
+
+// Re-export commonly used types for convenience
+pub use errors::LabelSubcommandError;
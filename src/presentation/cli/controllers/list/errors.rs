@@ -8,6 +8,7 @@ use std::path::PathBuf;
 
 use thiserror::Error;
 
+use crate::application::command_handlers::list::ListFilterError;
 use crate::presentation::cli::views::progress::ProgressReporterError;
 use crate::presentation::cli::views::ViewRenderError;
 
@@ -48,6 +49,20 @@ Tip: Check filesystem health and permissions"
     )]
     ScanError { message: String },
 
+    // ===== Filter Errors =====
+    /// Invalid filter flags
+    ///
+    /// A `--state` value is not a known environment state.
+    /// Use `.help()` for detailed troubleshooting steps.
+    #[error(
+        "Invalid list filter: {source}
+Tip: Run 'torrust-tracker-deployer list --help' for the filter syntax"
+    )]
+    InvalidFilter {
+        #[source]
+        source: ListFilterError,
+    },
+
     // ===== Internal Errors =====
     /// Progress reporting failed
     ///
@@ -156,6 +171,7 @@ Common causes:
 
 For more information, see docs/user-guide/commands.md"
             }
+            Self::InvalidFilter { source } => source.help(),
             Self::ProgressReportingFailed { .. } => {
                 "Progress Reporting Failed - This is an internal error:
 
@@ -10,13 +10,16 @@ use std::sync::Arc;
 use parking_lot::ReentrantMutex;
 
 use crate::application::command_handlers::list::info::EnvironmentList;
-use crate::application::command_handlers::list::{ListCommandHandler, ListCommandHandlerError};
+use crate::application::command_handlers::list::{
+    ListCommandHandler, ListCommandHandlerError, ListFilter,
+};
 use crate::application::traits::RepositoryProvider;
 use crate::presentation::cli::input::cli::output_format::OutputFormat;
 use crate::presentation::cli::views::commands::list::{JsonView, TextView};
 use crate::presentation::cli::views::progress::ProgressReporter;
 use crate::presentation::cli::views::Render;
 use crate::presentation::cli::views::UserOutput;
+use crate::shared::Clock;
 
 use super::errors::ListSubcommandError;
 
@@ -73,14 +76,16 @@ impl ListCommandController {
     ///
     /// * `file_repository_factory` - Factory for creating environment repositories
     /// * `data_directory` - Path to the data directory
+    /// * `clock` - Clock used to evaluate age filters
     /// * `user_output` - Shared output service for user feedback
     #[allow(clippy::needless_pass_by_value)] // Arc parameters are moved to constructor for ownership
     pub fn new(
         file_repository_factory: Arc<dyn RepositoryProvider>,
         data_directory: Arc<Path>,
+        clock: Arc<dyn Clock>,
         user_output: Arc<ReentrantMutex<RefCell<UserOutput>>>,
     ) -> Self {
-        let handler = ListCommandHandler::new(file_repository_factory, data_directory);
+        let handler = ListCommandHandler::new(file_repository_factory, data_directory, clock);
         let progress = ProgressReporter::new(user_output, ListStep::count());
 
         Self { handler, progress }
@@ -94,14 +99,30 @@ impl ListCommandController {
     ///
     /// # Arguments
     ///
+    /// * `filter` - Filter and sort order for the listed environments
     /// * `output_format` - Output format (Text or Json)
     ///
     /// # Errors
     ///
     /// Returns `ListSubcommandError` if any step fails
-    pub fn execute(&mut self, output_format: OutputFormat) -> Result<(), ListSubcommandError> {
+    pub fn execute(
+        &mut self,
+        filter: &ListFilter,
+        output_format: OutputFormat,
+    ) -> Result<(), ListSubcommandError> {
+        self.progress.set_output_format(output_format);
+        let result = self.execute_steps(filter, output_format);
+        self.progress.finish(result)
+    }
+
+    /// Steps of [`Self::execute`], before the timing summary
+    fn execute_steps(
+        &mut self,
+        filter: &ListFilter,
+        output_format: OutputFormat,
+    ) -> Result<(), ListSubcommandError> {
         // Step 1: Scan for environments via application layer
-        let env_list = self.scan_environments()?;
+        let env_list = self.scan_environments(filter)?;
 
         // Step 2: Display results
         self.display_results(&env_list, output_format)?;
@@ -110,11 +131,17 @@ impl ListCommandController {
     }
 
     /// Step 1: Scan for environments via application layer
-    fn scan_environments(&mut self) -> Result<EnvironmentList, ListSubcommandError> {
+    fn scan_environments(
+        &mut self,
+        filter: &ListFilter,
+    ) -> Result<EnvironmentList, ListSubcommandError> {
         self.progress
             .start_step(ListStep::ScanEnvironments.description())?;
 
-        let env_list = self.handler.execute().map_err(Self::map_handler_error)?;
+        let env_list = self
+            .handler
+            .execute(filter)
+            .map_err(Self::map_handler_error)?;
 
         let count = env_list.total_count;
         self.progress
@@ -135,6 +162,9 @@ impl ListCommandController {
             ListCommandHandlerError::ScanError { message } => {
                 ListSubcommandError::ScanError { message }
             }
+            ListCommandHandlerError::InvalidFilter(source) => {
+                ListSubcommandError::InvalidFilter { source }
+            }
         }
     }
 
@@ -0,0 +1,147 @@
+//! Logs Command Handler
+//!
+//! This module handles the logs command execution at the presentation layer,
+//! listing the local run logs of an environment or displaying one of them.
+
+use std::cell::RefCell;
+use std::sync::Arc;
+
+use parking_lot::ReentrantMutex;
+
+use crate::application::command_handlers::logs::LogsCommandHandler;
+use crate::domain::environment::name::EnvironmentName;
+use crate::presentation::cli::input::cli::output_format::OutputFormat;
+use crate::presentation::cli::views::commands::logs::{
+    JsonView, LogContentData, LogListData, TextView,
+};
+use crate::presentation::cli::views::progress::ProgressReporter;
+use crate::presentation::cli::views::Render;
+use crate::presentation::cli::views::UserOutput;
+
+use super::errors::LogsSubcommandError;
+
+/// Steps in the logs workflow
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum LogsStep {
+    ValidateEnvironment,
+    ReadLogs,
+}
+
+impl LogsStep {
+    /// All steps in execution order
+    const ALL: &'static [Self] = &[Self::ValidateEnvironment, Self::ReadLogs];
+
+    /// Total number of steps
+    const fn count() -> usize {
+        Self::ALL.len()
+    }
+
+    /// User-facing description for the step
+    fn description(self) -> &'static str {
+        match self {
+            Self::ValidateEnvironment => "Validating environment",
+            Self::ReadLogs => "Reading run logs",
+        }
+    }
+}
+
+/// Presentation layer controller for logs command workflow
+pub struct LogsCommandController {
+    handler: LogsCommandHandler,
+    progress: ProgressReporter,
+}
+
+impl LogsCommandController {
+    /// Create a new `LogsCommandController` with dependencies
+    #[allow(clippy::needless_pass_by_value)] // Arc parameters are moved to constructor for ownership
+    pub fn new(
+        handler: LogsCommandHandler,
+        user_output: Arc<ReentrantMutex<RefCell<UserOutput>>>,
+    ) -> Self {
+        let progress = ProgressReporter::new(user_output, LogsStep::count());
+
+        Self { handler, progress }
+    }
+
+    /// Execute the logs command workflow
+    ///
+    /// # Arguments
+    ///
+    /// * `environment_name` - Name of the environment
+    /// * `file` - Log to display as listed, `None` to list all logs
+    /// * `output_format` - Output format (Text or Json)
+    ///
+    /// # Errors
+    ///
+    /// Returns `LogsSubcommandError` if the name is invalid or the logs
+    /// cannot be read
+    pub fn execute(
+        &mut self,
+        environment_name: &str,
+        file: Option<&str>,
+        output_format: OutputFormat,
+    ) -> Result<(), LogsSubcommandError> {
+        self.progress.set_output_format(output_format);
+        let result = self.execute_steps(environment_name, file, output_format);
+        self.progress.finish(result)
+    }
+
+    /// Steps of [`Self::execute`], before the timing summary
+    fn execute_steps(
+        &mut self,
+        environment_name: &str,
+        file: Option<&str>,
+        output_format: OutputFormat,
+    ) -> Result<(), LogsSubcommandError> {
+        let env_name = self.validate_environment_name(environment_name)?;
+
+        self.progress.start_step(LogsStep::ReadLogs.description())?;
+
+        let read_failed = |source| LogsSubcommandError::ReadFailed {
+            name: environment_name.to_string(),
+            source,
+        };
+
+        let output = if let Some(file) = file {
+            let (file, content) = self.handler.show(&env_name, file).map_err(read_failed)?;
+            let data = LogContentData::new(&file, content);
+            match output_format {
+                OutputFormat::Text => TextView::render(&data)?,
+                OutputFormat::Json => JsonView::render(&data)?,
+            }
+        } else {
+            let files = self.handler.list(&env_name).map_err(read_failed)?;
+            let data = LogListData::new(environment_name, &files);
+            match output_format {
+                OutputFormat::Text => TextView::render(&data)?,
+                OutputFormat::Json => JsonView::render(&data)?,
+            }
+        };
+
+        self.progress.complete_step(None)?;
+
+        self.progress.result(&output)?;
+
+        Ok(())
+    }
+
+    /// Validate the environment name format
+    fn validate_environment_name(
+        &mut self,
+        name: &str,
+    ) -> Result<EnvironmentName, LogsSubcommandError> {
+        self.progress
+            .start_step(LogsStep::ValidateEnvironment.description())?;
+
+        let env_name = EnvironmentName::new(name.to_string()).map_err(|source| {
+            LogsSubcommandError::InvalidEnvironmentName {
+                name: name.to_string(),
+                source,
+            }
+        })?;
+
+        self.progress.complete_step(None)?;
+
+        Ok(env_name)
+    }
+}
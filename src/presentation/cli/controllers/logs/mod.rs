@@ -0,0 +1,17 @@
+//! Logs Command Presentation Module
+//!
+//! This module implements the CLI presentation layer for the logs command,
+//! which lists the local run logs of an environment (`--local`) or displays
+//! one of them (`--show <FILE>`).
+//!
+//! ## Components
+//!
+//! - `errors` - Presentation layer error types with `.help()` methods
+//! - `handler` - Main command handler orchestrating the workflow
+
+pub mod errors;
+pub mod handler;
+pub use handler::LogsCommandController;
+
+// Re-export commonly used types for convenience
+pub use errors::LogsSubcommandError;
@@ -250,20 +250,46 @@
 //! - Maintain comprehensive test coverage
 
 // Re-export command modules
+pub mod backup;
+pub mod clone;
+pub mod completions;
+pub mod config;
 pub mod configure;
 pub mod constants;
 pub mod create;
+pub mod deploy;
 pub mod destroy;
+pub mod diff;
 pub mod docs;
+pub mod doctor;
+pub mod drift;
 pub mod exists;
+pub mod export;
+pub mod import;
+pub mod import_bundle;
+pub mod init;
+pub mod keys;
+pub mod label;
 pub mod list;
+pub mod logs;
+pub mod orphans;
+pub mod probe;
 pub mod provision;
 pub mod purge;
 pub mod register;
 pub mod release;
+pub mod rename;
 pub mod render;
+pub mod repair;
+pub mod reports;
+pub mod restore;
+pub mod rollback;
 pub mod run;
 pub mod show;
+pub mod ssh;
+pub mod start;
+pub mod stats;
+pub mod stop;
 pub mod test;
 pub mod validate;
 
@@ -0,0 +1,123 @@
+//! Error types for the Orphans Subcommand
+//!
+//! This module defines error types that can occur during CLI orphans command execution.
+//! All errors follow the project's error handling principles by providing clear,
+//! contextual, and actionable error messages with `.help()` methods.
+
+use thiserror::Error;
+
+use crate::application::command_handlers::orphans::OrphansCommandHandlerError;
+use crate::presentation::cli::views::progress::ProgressReporterError;
+use crate::presentation::cli::views::ViewRenderError;
+
+/// Orphans command specific errors
+#[derive(Debug, Error)]
+pub enum OrphansSubcommandError {
+    /// The workspace could not be scanned
+    #[error(
+        "Failed to scan for orphaned resources: {source}
+Tip: Run from the deployer workspace directory or specify --working-dir"
+    )]
+    ScanFailed {
+        #[source]
+        source: OrphansCommandHandlerError,
+    },
+
+    /// Some orphaned resources could not be deleted
+    ///
+    /// The report with the failure of each resource has already been shown.
+    #[error(
+        "Failed to delete {count} orphaned resource(s)
+Tip: Check the status column of the report for the cause of each failure"
+    )]
+    DeletionFailed { count: usize },
+
+    // ===== Internal Errors =====
+    /// Progress reporting failed
+    #[error(
+        "Failed to report progress: {source}
+Tip: This is a critical bug - please report it with full logs using --log-output file-and-stderr"
+    )]
+    ProgressReportingFailed {
+        #[source]
+        source: ProgressReporterError,
+    },
+
+    /// Output formatting failed (JSON serialization error).
+    #[error(
+        "Failed to format output: {reason}\nTip: This is a critical bug - please report it with full logs using --log-output file-and-stderr"
+    )]
+    OutputFormatting { reason: String },
+}
+
+// ============================================================================
+// ERROR CONVERSIONS
+// ============================================================================
+
+impl From<ProgressReporterError> for OrphansSubcommandError {
+    fn from(source: ProgressReporterError) -> Self {
+        Self::ProgressReportingFailed { source }
+    }
+}
+
+impl From<ViewRenderError> for OrphansSubcommandError {
+    fn from(e: ViewRenderError) -> Self {
+        Self::OutputFormatting {
+            reason: e.to_string(),
+        }
+    }
+}
+
+impl OrphansSubcommandError {
+    /// Get detailed troubleshooting guidance for this error
+    #[must_use]
+    pub fn help(&self) -> &'static str {
+        match self {
+            Self::ScanFailed { source } => source.help(),
+            Self::DeletionFailed { .. } => {
+                "Orphan Deletion Failed - Detailed Troubleshooting:
+
+1. LXD instances and profiles:
+   - Run: lxc list && lxc profile list
+   - A profile cannot be deleted while an instance still uses it
+   - Delete it manually: lxc delete --force <instance> / lxc profile delete <profile>
+
+2. Build directories:
+   - Check permissions: ls -ld build/<env>
+   - Delete it manually: rm -rf build/<env>
+
+3. Run the command again:
+   - torrust-tracker-deployer orphans --delete
+
+For more information, see docs/user-guide/commands/orphans.md"
+            }
+            Self::ProgressReportingFailed { .. } => {
+                "Progress Reporting Failed - This is an internal error:
+
+1. This indicates a bug in the application
+2. Please report this issue with:
+   - Full command output
+   - Log file contents (use --log-output file-and-stderr)
+   - Steps to reproduce
+
+Report issues at: https://github.com/torrust/torrust-tracker-deployer/issues"
+            }
+            Self::OutputFormatting { .. } => {
+                "Output Formatting Failed - Critical Internal Error:\n\nThis error should not occur during normal operation. It indicates a bug in the output formatting system.\n\nPlease report it with the command, output format (--output-format) and logs so we can fix it."
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn it_should_provide_help_for_deletion_failures() {
+        let error = OrphansSubcommandError::DeletionFailed { count: 2 };
+
+        assert!(error.to_string().contains("2 orphaned resource(s)"));
+        assert!(error.help().contains("lxc profile delete"));
+    }
+}
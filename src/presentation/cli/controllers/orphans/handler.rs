@@ -0,0 +1,149 @@
+//! Orphans Command Handler
+//!
+//! This module handles the orphans command execution at the presentation layer,
+//! reporting (and optionally deleting) resources left behind by environments
+//! that no longer exist.
+
+use std::cell::RefCell;
+use std::sync::Arc;
+
+use parking_lot::ReentrantMutex;
+
+use crate::application::command_handlers::orphans::{OrphanReport, OrphansCommandHandler};
+use crate::presentation::cli::input::cli::output_format::OutputFormat;
+use crate::presentation::cli::views::commands::orphans::{JsonView, TextView};
+use crate::presentation::cli::views::progress::ProgressReporter;
+use crate::presentation::cli::views::Render;
+use crate::presentation::cli::views::UserOutput;
+
+use super::errors::OrphansSubcommandError;
+
+/// Steps in the orphans workflow
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum OrphansStep {
+    ScanResources,
+    DisplayResults,
+}
+
+impl OrphansStep {
+    /// All steps in execution order
+    const ALL: &'static [Self] = &[Self::ScanResources, Self::DisplayResults];
+
+    /// Total number of steps
+    const fn count() -> usize {
+        Self::ALL.len()
+    }
+
+    /// User-facing description for the step
+    fn description(self, delete: bool) -> &'static str {
+        match self {
+            Self::ScanResources if delete => "Finding and deleting orphaned resources",
+            Self::ScanResources => "Finding orphaned resources",
+            Self::DisplayResults => "Displaying results",
+        }
+    }
+}
+
+/// Presentation layer controller for orphans command workflow
+///
+/// ## Responsibilities
+///
+/// - Delegate detection and deletion to the application layer
+/// - Display one line per orphaned resource with the action taken
+/// - Fail with a non-zero exit code when a requested deletion failed
+pub struct OrphansCommandController {
+    handler: OrphansCommandHandler,
+    progress: ProgressReporter,
+}
+
+impl OrphansCommandController {
+    /// Create a new `OrphansCommandController` with dependencies
+    #[allow(clippy::needless_pass_by_value)] // Arc parameters are moved to constructor for ownership
+    pub fn new(
+        handler: OrphansCommandHandler,
+        user_output: Arc<ReentrantMutex<RefCell<UserOutput>>>,
+    ) -> Self {
+        let progress = ProgressReporter::new(user_output, OrphansStep::count());
+
+        Self { handler, progress }
+    }
+
+    /// Execute the orphans command workflow
+    ///
+    /// # Arguments
+    ///
+    /// * `delete` - Delete the orphaned resources instead of only reporting them
+    /// * `output_format` - Output format (Text or Json)
+    ///
+    /// # Errors
+    ///
+    /// Returns `OrphansSubcommandError` if the workspace cannot be scanned or
+    /// if any requested deletion failed (after displaying the report)
+    pub fn execute(
+        &mut self,
+        delete: bool,
+        output_format: OutputFormat,
+    ) -> Result<(), OrphansSubcommandError> {
+        self.progress.set_output_format(output_format);
+        let result = self.execute_steps(delete, output_format);
+        self.progress.finish(result)
+    }
+
+    /// Steps of [`Self::execute`], before the timing summary
+    fn execute_steps(
+        &mut self,
+        delete: bool,
+        output_format: OutputFormat,
+    ) -> Result<(), OrphansSubcommandError> {
+        // Step 1: Find (and delete) orphaned resources via application layer
+        let report = self.scan_resources(delete)?;
+
+        // Step 2: Display results
+        self.display_results(&report, output_format)?;
+
+        let failed = report.failed_count();
+        if failed > 0 {
+            return Err(OrphansSubcommandError::DeletionFailed { count: failed });
+        }
+
+        Ok(())
+    }
+
+    /// Step 1: Find (and delete) orphaned resources via application layer
+    fn scan_resources(&mut self, delete: bool) -> Result<OrphanReport, OrphansSubcommandError> {
+        self.progress
+            .start_step(OrphansStep::ScanResources.description(delete))?;
+
+        let report = self
+            .handler
+            .execute(delete)
+            .map_err(|source| OrphansSubcommandError::ScanFailed { source })?;
+
+        let count = report.orphans.len();
+        self.progress
+            .complete_step(Some(&format!("Found {count} orphaned resource(s)")))?;
+
+        Ok(report)
+    }
+
+    /// Step 2: Display the report on stdout
+    fn display_results(
+        &mut self,
+        report: &OrphanReport,
+        output_format: OutputFormat,
+    ) -> Result<(), OrphansSubcommandError> {
+        self.progress
+            .start_step(OrphansStep::DisplayResults.description(false))?;
+
+        let output = match output_format {
+            OutputFormat::Text => TextView::render(report)?,
+            OutputFormat::Json => JsonView::render(report)?,
+        };
+
+        self.progress.result(&output)?;
+
+        self.progress.complete_step(Some("Results displayed"))?;
+
+        Ok(())
+    }
+}
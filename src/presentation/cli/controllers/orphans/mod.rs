@@ -0,0 +1,22 @@
+//! Orphans Command Presentation Module
+//!
+//! This module implements the CLI presentation layer for the orphans command,
+//! which reports (and with `--delete` removes) LXD instances, LXD profiles and
+//! build directories left behind by environments that no longer exist.
+//!
+//! ## Components
+//!
+//! - `errors` - Presentation layer error types with `.help()` methods
+//! - `handler` - Main command handler orchestrating the workflow
+//!
+//! ## Exit Code Contract
+//!
+//! - Exit code 0 when the scan succeeds and every requested deletion succeeds
+//! - Exit code 1 when a workspace directory cannot be read or a deletion fails
+
+pub mod errors;
+pub mod handler;
+pub use handler::OrphansCommandController;
+
+// Re-export commonly used types for convenience
+pub use errors::OrphansSubcommandError;
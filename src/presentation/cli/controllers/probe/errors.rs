@@ -0,0 +1,27 @@
+//! Unified Probe Command Errors
+//!
+//! This module defines the error type of every probe subcommand.
+
+use thiserror::Error;
+
+use super::subcommands::udp::ProbeUdpCommandError;
+
+/// Unified error type for all probe subcommands
+#[derive(Debug, Error)]
+pub enum ProbeCommandError {
+    /// UDP probe errors
+    #[error(transparent)]
+    Udp(#[from] ProbeUdpCommandError),
+}
+
+impl ProbeCommandError {
+    /// Get detailed troubleshooting guidance for this error
+    ///
+    /// Delegates to the help of the failed subcommand.
+    #[must_use]
+    pub fn help(&self) -> String {
+        match self {
+            Self::Udp(err) => err.help().to_string(),
+        }
+    }
+}
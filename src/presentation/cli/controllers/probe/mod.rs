@@ -0,0 +1,18 @@
+//! Probe Command Controller (Presentation Layer)
+//!
+//! This module handles the `probe` command, which checks from this machine
+//! that a tracker answers. It reads no workspace, so it works with any
+//! tracker, deployed with the deployer or not.
+//!
+//! ## Subcommands
+//!
+//! - `probe udp <host:port>` - Announces a random torrent to a UDP tracker
+//!   and prints the announce interval and the peer counts
+
+pub mod errors;
+pub mod router;
+pub mod subcommands;
+
+// Re-export commonly used types for convenience
+pub use errors::ProbeCommandError;
+pub use router::route_command;
@@ -0,0 +1,47 @@
+//! Probe Command Router
+//!
+//! This module routes the probe command to its subcommands.
+
+use std::time::Duration;
+
+use crate::presentation::cli::dispatch::ExecutionContext;
+use crate::presentation::cli::input::cli::commands::ProbeAction;
+use crate::shared::udp_tracker_client::UdpRetryPolicy;
+
+use super::errors::ProbeCommandError;
+
+/// Route the probe command to its appropriate subcommand
+///
+/// # Arguments
+///
+/// * `action` - The probe action to perform
+/// * `context` - Execution context providing access to application services
+///
+/// # Errors
+///
+/// Returns an error if the subcommand execution fails.
+pub async fn route_command(
+    action: ProbeAction,
+    context: &ExecutionContext,
+) -> Result<(), ProbeCommandError> {
+    match action {
+        ProbeAction::Udp {
+            tracker,
+            timeout,
+            retries,
+        } => {
+            let output_format = context.output_format();
+            context
+                .container()
+                .create_probe_udp_controller()
+                .execute(
+                    &tracker,
+                    UdpRetryPolicy::new(Duration::from_secs(timeout), retries),
+                    output_format,
+                )
+                .await
+                .map(|_| ()) // The result was displayed by the controller
+                .map_err(ProbeCommandError::Udp)
+        }
+    }
+}
@@ -0,0 +1,5 @@
+//! Probe Subcommands Module
+//!
+//! This module contains the individual subcommands for the probe command.
+
+pub mod udp;
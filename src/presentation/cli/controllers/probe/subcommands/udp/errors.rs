@@ -0,0 +1,133 @@
+//! Error types for the UDP Probe Subcommand
+//!
+//! This module defines error types that can occur during `probe udp`
+//! execution. The protocol errors of the tracker are kept as the source, so
+//! the message says which request was not answered or what was wrong with
+//! the response.
+
+use std::net::SocketAddr;
+
+use thiserror::Error;
+
+use crate::presentation::cli::views::progress::ProgressReporterError;
+use crate::presentation::cli::views::ViewRenderError;
+use crate::shared::udp_tracker_client::UdpTrackerClientError;
+
+/// UDP probe specific errors
+#[derive(Debug, Error)]
+pub enum ProbeUdpCommandError {
+    /// The tracker address is not a `host:port` that resolves
+    #[error(
+        "Invalid tracker address '{tracker}': {reason}
+Tip: Give the address as host:port, e.g. tracker.example.com:6969"
+    )]
+    InvalidTrackerAddress { tracker: String, reason: String },
+
+    /// The connect or announce exchange failed
+    #[error(
+        "UDP announce to udp://{tracker} ({address}) failed: {source}
+Tip: Run 'torrust-tracker-deployer probe udp --help' for the retry options"
+    )]
+    AnnounceFailed {
+        tracker: String,
+        address: SocketAddr,
+        #[source]
+        source: UdpTrackerClientError,
+    },
+
+    // ===== Internal Errors =====
+    /// Progress reporting failed
+    #[error(
+        "Failed to report progress: {source}
+Tip: This is a critical bug - please report it with full logs using --log-output file-and-stderr"
+    )]
+    ProgressReportingFailed {
+        #[source]
+        source: ProgressReporterError,
+    },
+
+    /// Output formatting failed (JSON serialization error).
+    #[error(
+        "Failed to format output: {reason}\nTip: This is a critical bug - please report it with full logs using --log-output file-and-stderr"
+    )]
+    OutputFormatting { reason: String },
+}
+
+// ============================================================================
+// ERROR CONVERSIONS
+// ============================================================================
+
+impl From<ProgressReporterError> for ProbeUdpCommandError {
+    fn from(source: ProgressReporterError) -> Self {
+        Self::ProgressReportingFailed { source }
+    }
+}
+
+impl From<ViewRenderError> for ProbeUdpCommandError {
+    fn from(e: ViewRenderError) -> Self {
+        Self::OutputFormatting {
+            reason: e.to_string(),
+        }
+    }
+}
+
+impl ProbeUdpCommandError {
+    /// Get detailed troubleshooting guidance for this error
+    #[must_use]
+    pub fn help(&self) -> &'static str {
+        match self {
+            Self::InvalidTrackerAddress { .. } => {
+                "Invalid Tracker Address - Detailed Troubleshooting:
+
+1. Include the port: UDP trackers have no default port
+   - torrust-tracker-deployer probe udp tracker.example.com:6969
+
+2. Check that the host name resolves:
+   - getent hosts tracker.example.com
+
+3. Do not include the scheme or a path: 'udp://host:6969/announce' is
+   given as 'host:6969'"
+            }
+            Self::AnnounceFailed { source, .. } => match source {
+                UdpTrackerClientError::Timeout { .. } | UdpTrackerClientError::Socket(_) => {
+                    "UDP Tracker Not Answering - Detailed Troubleshooting:
+
+1. Check that the tracker is running and listening on the port:
+   - ss -ulpn | grep 6969 (on the tracker host)
+
+2. Check that firewalls let UDP traffic through, on the tracker host and
+   the cloud provider (UDP is often blocked when TCP is open)
+
+3. A 'connection refused' error means the host answered that nothing
+   listens on the port
+
+4. On slow or lossy links, wait longer:
+   - torrust-tracker-deployer probe udp <host:port> --timeout 15 --retries 4"
+                }
+                UdpTrackerClientError::TrackerError { .. } => {
+                    "UDP Tracker Returned An Error - Detailed Troubleshooting:
+
+The tracker answered, so it is reachable. It rejected the announce:
+
+1. Whitelisted trackers reject torrents that are not on the whitelist,
+   and the probe announces a random torrent
+
+2. Check the tracker logs for the reason of the rejection"
+                }
+                UdpTrackerClientError::InvalidResponse { .. } => {
+                    "Invalid UDP Tracker Response - Detailed Troubleshooting:
+
+Something answered on the port, but not with the UDP tracker protocol
+(BEP 15):
+
+1. Check that the port is the UDP tracker port, not another UDP service
+
+2. Check the tracker version and logs"
+                }
+            },
+            Self::ProgressReportingFailed { .. } | Self::OutputFormatting { .. } => {
+                "This is an internal error. Please report it with full logs using --log-output file-and-stderr"
+            }
+        }
+    }
+}
@@ -0,0 +1,190 @@
+//! UDP Probe Command Controller (Presentation Layer)
+//!
+//! This module handles `probe udp` at the presentation layer: it resolves
+//! the tracker address, announces a random torrent and displays the
+//! response.
+
+use std::cell::RefCell;
+use std::net::SocketAddr;
+use std::sync::Arc;
+use std::time::Instant;
+
+use parking_lot::ReentrantMutex;
+
+use crate::presentation::cli::input::cli::output_format::OutputFormat;
+use crate::presentation::cli::views::commands::probe::{JsonView, TextView, UdpProbeData};
+use crate::presentation::cli::views::progress::ProgressReporter;
+use crate::presentation::cli::views::Render;
+use crate::presentation::cli::views::UserOutput;
+use crate::shared::udp_tracker_client::{
+    random_info_hash, random_peer_id, UdpRetryPolicy, UdpTrackerClient,
+};
+
+use super::errors::ProbeUdpCommandError;
+
+/// Port announced for the random peer of the probe
+const PROBE_PEER_PORT: u16 = 6881;
+
+/// Steps in the UDP probe workflow
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum ProbeUdpStep {
+    ResolveAddress,
+    Announce,
+    DisplayResults,
+}
+
+impl ProbeUdpStep {
+    /// All steps in execution order
+    const ALL: &'static [Self] = &[Self::ResolveAddress, Self::Announce, Self::DisplayResults];
+
+    /// Total number of steps
+    const fn count() -> usize {
+        Self::ALL.len()
+    }
+
+    /// User-facing description for the step
+    fn description(self) -> &'static str {
+        match self {
+            Self::ResolveAddress => "Resolving the tracker address",
+            Self::Announce => "Announcing a random torrent",
+            Self::DisplayResults => "Displaying results",
+        }
+    }
+}
+
+/// Presentation layer controller for the `probe udp` workflow
+///
+/// ## Responsibilities
+///
+/// - Resolve the `host:port` given by the user
+/// - Announce a random info-hash and peer ID with the retry policy of the
+///   command line
+/// - Display the announce interval and the peer counts, or fail with the
+///   protocol error
+pub struct ProbeUdpCommandController {
+    progress: ProgressReporter,
+}
+
+impl ProbeUdpCommandController {
+    /// Create a new `ProbeUdpCommandController` with dependencies
+    #[allow(clippy::needless_pass_by_value)] // Arc parameters are moved to constructor for ownership
+    pub fn new(user_output: Arc<ReentrantMutex<RefCell<UserOutput>>>) -> Self {
+        let progress = ProgressReporter::new(user_output, ProbeUdpStep::count());
+
+        Self { progress }
+    }
+
+    /// Execute the `probe udp` workflow
+    ///
+    /// # Arguments
+    ///
+    /// * `tracker` - Address of the tracker, as `host:port`
+    /// * `retry_policy` - How long to wait for responses and how often to retry
+    /// * `output_format` - Output format (Text or Json)
+    ///
+    /// # Errors
+    ///
+    /// Returns `ProbeUdpCommandError` if the address does not resolve or the
+    /// tracker does not answer the announce successfully
+    pub async fn execute(
+        &mut self,
+        tracker: &str,
+        retry_policy: UdpRetryPolicy,
+        output_format: OutputFormat,
+    ) -> Result<UdpProbeData, ProbeUdpCommandError> {
+        self.progress.set_output_format(output_format);
+        let result = self
+            .execute_steps(tracker, retry_policy, output_format)
+            .await;
+        self.progress.finish(result)
+    }
+
+    /// Steps of [`Self::execute`], before the timing summary
+    async fn execute_steps(
+        &mut self,
+        tracker: &str,
+        retry_policy: UdpRetryPolicy,
+        output_format: OutputFormat,
+    ) -> Result<UdpProbeData, ProbeUdpCommandError> {
+        // Step 1: Resolve the address
+        let address = self.resolve_address(tracker).await?;
+
+        // Step 2: Announce
+        let data = self.announce(tracker, address, retry_policy).await?;
+
+        // Step 3: Display results
+        self.display_results(&data, output_format)?;
+
+        Ok(data)
+    }
+
+    /// Step 1: Resolve `tracker` to its first socket address
+    async fn resolve_address(&mut self, tracker: &str) -> Result<SocketAddr, ProbeUdpCommandError> {
+        self.progress
+            .start_step(ProbeUdpStep::ResolveAddress.description())?;
+
+        let invalid = |reason: String| ProbeUdpCommandError::InvalidTrackerAddress {
+            tracker: tracker.to_string(),
+            reason,
+        };
+
+        let address = tokio::net::lookup_host(tracker)
+            .await
+            .map_err(|e| invalid(e.to_string()))?
+            .next()
+            .ok_or_else(|| invalid("the host has no address".to_string()))?;
+
+        self.progress.complete_step(Some(&address.to_string()))?;
+
+        Ok(address)
+    }
+
+    /// Step 2: Announce a random torrent to the tracker
+    async fn announce(
+        &mut self,
+        tracker: &str,
+        address: SocketAddr,
+        retry_policy: UdpRetryPolicy,
+    ) -> Result<UdpProbeData, ProbeUdpCommandError> {
+        self.progress
+            .start_step(ProbeUdpStep::Announce.description())?;
+
+        let started_at = Instant::now();
+        let response = UdpTrackerClient::new(address)
+            .with_retry_policy(retry_policy)
+            .announce(&random_info_hash(), &random_peer_id(), PROBE_PEER_PORT)
+            .await
+            .map_err(|source| ProbeUdpCommandError::AnnounceFailed {
+                tracker: tracker.to_string(),
+                address,
+                source,
+            })?;
+        let data = UdpProbeData::new(tracker, address, &response, started_at.elapsed());
+
+        self.progress
+            .complete_step(Some(&format!("answered in {} ms", data.duration_ms)))?;
+
+        Ok(data)
+    }
+
+    /// Step 3: Display the response on stdout
+    fn display_results(
+        &mut self,
+        data: &UdpProbeData,
+        output_format: OutputFormat,
+    ) -> Result<(), ProbeUdpCommandError> {
+        self.progress
+            .start_step(ProbeUdpStep::DisplayResults.description())?;
+
+        let output = match output_format {
+            OutputFormat::Text => TextView::render(data)?,
+            OutputFormat::Json => JsonView::render(data)?,
+        };
+
+        self.progress.result(&output)?;
+
+        self.progress.complete_step(Some("Results displayed"))?;
+
+        Ok(())
+    }
+}
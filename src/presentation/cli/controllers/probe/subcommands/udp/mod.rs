@@ -0,0 +1,11 @@
+//! UDP Probe Subcommand
+//!
+//! This module handles `probe udp`, which announces a random torrent to a
+//! UDP tracker with the client of `shared::udp_tracker_client`.
+
+pub mod errors;
+pub mod handler;
+
+// Re-export the main handler and error types
+pub use errors::ProbeUdpCommandError;
+pub use handler::ProbeUdpCommandController;
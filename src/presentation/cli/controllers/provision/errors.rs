@@ -77,6 +77,21 @@ Tip: Check logs and try running with --log-output file-and-stderr for more detai
         source: Box<ProvisionCommandHandlerError>,
     },
 
+    // ===== Bulk Provision Errors =====
+    /// The environments matching `--label` could not be listed
+    #[error(
+        "Failed to select the environments with the labels '{labels}': {reason}
+Tip: Check the selection with 'list --label <key=value> --state created'"
+    )]
+    EnvironmentSelectionFailed { labels: String, reason: String },
+
+    /// Some of the environments selected with `--label` were not provisioned
+    #[error(
+        "Failed to provision {failed} of {total} environments
+Tip: The report above lists the error of each environment"
+    )]
+    BulkProvisionFailed { failed: usize, total: usize },
+
     // ===== Internal Errors =====
     /// Progress reporting failed
     ///
@@ -325,6 +340,34 @@ For persistent issues, check system logs and file system health."
 For more information, see the provisioning troubleshooting guide."
             }
 
+            Self::EnvironmentSelectionFailed { .. } => {
+                "Environment Selection Failed - Detailed Troubleshooting:
+
+1. Check that the data directory exists and is readable:
+   ls -la data/
+
+2. Preview the selection:
+   torrust-tracker-deployer list --label purpose=nightly --state created
+
+For more information, see docs/user-guide/commands/provision.md"
+            }
+
+            Self::BulkProvisionFailed { .. } => {
+                "Bulk Provision Failed - Detailed Troubleshooting:
+
+The other environments were provisioned: only the ones reported with an
+error failed. Their state is 'provision_failed'.
+
+1. Review the failure of an environment:
+   torrust-tracker-deployer show <env-name>
+
+2. Destroy a failed environment and create it again, then re-run the bulk
+   provision: it only selects the environments in the 'created' state
+   torrust-tracker-deployer provision --label purpose=nightly --parallel 4
+
+For more information, see docs/user-guide/commands/provision.md"
+            }
+
             Self::ProgressReportingFailed { .. } => {
                 "Progress Reporting Failed - Critical Internal Error:
 
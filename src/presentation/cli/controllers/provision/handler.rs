@@ -4,24 +4,40 @@
 //! including environment validation, repository initialization, and user interaction.
 
 use std::cell::RefCell;
+use std::num::NonZeroUsize;
+use std::path::PathBuf;
 use std::sync::Arc;
 
 use parking_lot::ReentrantMutex;
+use tokio_util::sync::CancellationToken;
 
-use crate::application::command_handlers::ProvisionCommandHandler;
+use torrust_tracker_deployer_dependency_installer::Dependency;
+
+use crate::application::command_handlers::common::{DryRunReport, MultiEnvironmentRunner};
+use crate::application::command_handlers::list::ListFilter;
+use crate::application::command_handlers::{ListCommandHandler, ProvisionCommandHandler};
+use crate::application::services::deployment_report::DeploymentReportListener;
 use crate::domain::environment::name::EnvironmentName;
 use crate::domain::environment::repository::EnvironmentRepository;
 use crate::domain::environment::state::Provisioned;
-use crate::domain::environment::Environment;
+use crate::domain::environment::{DiskSpaceThresholds, Environment, Label};
 use crate::presentation::cli::input::cli::OutputFormat;
 use crate::presentation::cli::views::commands::provision::{
     JsonView, ProvisionDetailsData, TextView,
 };
-use crate::presentation::cli::views::progress::ProgressReporter;
-use crate::presentation::cli::views::progress::VerboseProgressListener;
+use crate::presentation::cli::views::commands::shared::dry_run_report::{
+    DryRunReportData, DryRunReportJsonView, DryRunReportTextView,
+};
+use crate::presentation::cli::views::commands::shared::multi_environment_report::{
+    MultiEnvironmentReportData, MultiEnvironmentReportJsonView, MultiEnvironmentReportTextView,
+};
+use crate::presentation::cli::views::progress::{
+    MultiEnvironmentProgressListener, ProgressReporter, VerboseProgressListener,
+};
 use crate::presentation::cli::views::Render;
 use crate::presentation::cli::views::UserOutput;
 use crate::shared::clock::Clock;
+use crate::shared::command::run_log::DEFAULT_KEPT_RUNS;
 
 use super::errors::ProvisionSubcommandError;
 
@@ -77,6 +93,12 @@ pub struct ProvisionCommandController {
     repository: Arc<dyn EnvironmentRepository + Send + Sync>,
     clock: Arc<dyn Clock>,
     progress: ProgressReporter,
+    cancellation: CancellationToken,
+    list_handler: Option<ListCommandHandler>,
+    runner: MultiEnvironmentRunner,
+    kept_run_logs: usize,
+    trace_dir: Option<PathBuf>,
+    disk_space_thresholds: DiskSpaceThresholds,
 }
 
 impl ProvisionCommandController {
@@ -96,9 +118,57 @@ impl ProvisionCommandController {
             repository,
             clock,
             progress,
+            cancellation: CancellationToken::new(),
+            list_handler: None,
+            runner: MultiEnvironmentRunner::default(),
+            kept_run_logs: DEFAULT_KEPT_RUNS,
+            trace_dir: None,
+            disk_space_thresholds: DiskSpaceThresholds::default(),
         }
     }
 
+    /// Keep the `OpenTofu` output logs of the last `runs` runs of the command
+    #[must_use]
+    pub fn with_kept_run_logs(mut self, runs: usize) -> Self {
+        self.kept_run_logs = runs;
+        self
+    }
+
+    /// Write the trace files of a failure to `trace_dir` (`--trace-dir`)
+    #[must_use]
+    pub fn with_trace_dir(mut self, trace_dir: Option<PathBuf>) -> Self {
+        self.trace_dir = trace_dir;
+        self
+    }
+
+    /// Require the given free disk space before running `OpenTofu`
+    #[must_use]
+    pub fn with_disk_space_thresholds(mut self, thresholds: DiskSpaceThresholds) -> Self {
+        self.disk_space_thresholds = thresholds;
+        self
+    }
+
+    /// Cancel the workflow when the given token is cancelled (e.g. on Ctrl-C)
+    #[must_use]
+    pub fn with_cancellation(mut self, cancellation: CancellationToken) -> Self {
+        self.cancellation = cancellation;
+        self
+    }
+
+    /// Provision up to `parallelism` of the environments selected with `--label` at once
+    #[must_use]
+    pub fn with_parallelism(mut self, parallelism: NonZeroUsize) -> Self {
+        self.runner = MultiEnvironmentRunner::new(parallelism);
+        self
+    }
+
+    /// Select the environments of `provision --label` with this handler
+    #[must_use]
+    pub fn with_list_handler(mut self, list_handler: ListCommandHandler) -> Self {
+        self.list_handler = Some(list_handler);
+        self
+    }
+
     /// Execute the complete provision workflow
     ///
     /// Orchestrates all steps of the provision command:
@@ -131,6 +201,18 @@ impl ProvisionCommandController {
         &mut self,
         environment_name: &str,
         output_format: OutputFormat,
+    ) -> Result<Environment<Provisioned>, ProvisionSubcommandError> {
+        self.progress.set_output_format(output_format);
+        let result = self.execute_steps(environment_name, output_format).await;
+        self.progress.finish(result)
+    }
+
+    /// Steps of [`Self::execute`], before the timing summary
+    #[allow(clippy::result_large_err)]
+    async fn execute_steps(
+        &mut self,
+        environment_name: &str,
+        output_format: OutputFormat,
     ) -> Result<Environment<Provisioned>, ProvisionSubcommandError> {
         let env_name = self.validate_environment_name(environment_name)?;
 
@@ -145,6 +227,188 @@ impl ProvisionCommandController {
         Ok(provisioned)
     }
 
+    /// Simulate the provision workflow (`--dry-run`)
+    ///
+    /// Renders the `OpenTofu` templates and runs `tofu plan`, then displays the
+    /// resources that would be created. No infrastructure is created and the
+    /// environment stays in the "Created" state.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the environment name is invalid, the environment
+    /// is not in "Created" state, or rendering or planning fails.
+    #[allow(clippy::result_large_err)]
+    pub async fn dry_run(
+        &mut self,
+        environment_name: &str,
+        output_format: OutputFormat,
+    ) -> Result<DryRunReport, ProvisionSubcommandError> {
+        self.progress.set_output_format(output_format);
+        let result = self.dry_run_steps(environment_name, output_format).await;
+        self.progress.finish(result)
+    }
+
+    /// Steps of [`Self::dry_run`], before the timing summary
+    #[allow(clippy::result_large_err)]
+    async fn dry_run_steps(
+        &mut self,
+        environment_name: &str,
+        output_format: OutputFormat,
+    ) -> Result<DryRunReport, ProvisionSubcommandError> {
+        let env_name = self.validate_environment_name(environment_name)?;
+
+        let handler = self.create_command_handler()?;
+
+        self.progress
+            .start_step(ProvisionStep::ProvisionInfrastructure.description())?;
+
+        let listener = VerboseProgressListener::new(self.progress.output().clone());
+
+        let report = handler
+            .dry_run(&env_name, Some(&listener))
+            .await
+            .map_err(
+                |source| ProvisionSubcommandError::ProvisionOperationFailed {
+                    name: env_name.to_string(),
+                    source: Box::new(source),
+                },
+            )?;
+
+        self.progress
+            .complete_step(Some("Dry run completed, nothing was provisioned"))?;
+
+        self.progress.blank_line()?;
+        let data = DryRunReportData::new(environment_name, "provision", &report);
+        let output = match output_format {
+            OutputFormat::Text => DryRunReportTextView::render(&data)?,
+            OutputFormat::Json => DryRunReportJsonView::render(&data)?,
+        };
+        self.progress.result(&output)?;
+
+        Ok(report)
+    }
+
+    /// Provision every created environment with the given labels (`--label`)
+    ///
+    /// Up to the configured parallelism (`--parallel`) environments are
+    /// provisioned at the same time. The progress of each one is prefixed
+    /// with its name. A failure does not stop the other environments: the
+    /// results are reported in a summary table once all were attempted.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the environments cannot be listed, or any
+    /// selected environment fails to be provisioned.
+    #[allow(clippy::result_large_err)]
+    #[allow(clippy::unused_async)] // Part of uniform async presentation layer interface
+    pub async fn execute_by_labels(
+        &mut self,
+        labels: &[Label],
+        output_format: OutputFormat,
+    ) -> Result<MultiEnvironmentReportData, ProvisionSubcommandError> {
+        self.progress.set_output_format(output_format);
+        let result = self.execute_by_labels_steps(labels, output_format).await;
+        self.progress.finish(result)
+    }
+
+    /// Steps of [`Self::execute_by_labels`], before the timing summary
+    #[allow(clippy::result_large_err)]
+    #[allow(clippy::unused_async)] // Part of uniform async presentation layer interface
+    async fn execute_by_labels_steps(
+        &mut self,
+        labels: &[Label],
+        output_format: OutputFormat,
+    ) -> Result<MultiEnvironmentReportData, ProvisionSubcommandError> {
+        let names = self.select_environments(labels)?;
+
+        let output = self.progress.output().clone();
+        let listener = MultiEnvironmentProgressListener::new(output.clone(), "provision");
+        let (repository, clock) = (&self.repository, &self.clock);
+
+        // Each worker builds its own handler: handlers are not shared between threads
+        let report = self.runner.run_async(&names, Some(&listener), |env_name| {
+            let handler = ProvisionCommandHandler::new(clock.clone(), repository.clone())
+                .with_kept_run_logs(self.kept_run_logs)
+                .with_trace_dir(self.trace_dir.clone())
+                .with_disk_space_thresholds(self.disk_space_thresholds)
+                .with_cancellation(self.cancellation.clone());
+            let verbose = VerboseProgressListener::new(output.clone())
+                .with_environment_prefix(env_name.as_str());
+
+            async move {
+                let recorder = DeploymentReportListener::new(
+                    "provision",
+                    &env_name,
+                    clock.clone(),
+                    Some(&verbose),
+                );
+                let result = handler.execute(&env_name, Some(&recorder)).await;
+                recorder.write(
+                    repository.as_ref(),
+                    &[Dependency::OpenTofu, Dependency::Ansible],
+                    result.as_ref().err(),
+                );
+                result
+            }
+        });
+
+        let data = MultiEnvironmentReportData::new(
+            "provision",
+            labels,
+            self.runner.parallelism().get(),
+            &report,
+        );
+        let output = match output_format {
+            OutputFormat::Text => MultiEnvironmentReportTextView::render(&data)?,
+            OutputFormat::Json => MultiEnvironmentReportJsonView::render(&data)?,
+        };
+        self.progress.result(&output)?;
+
+        match data.failed_count() {
+            0 => Ok(data),
+            failed => Err(ProvisionSubcommandError::BulkProvisionFailed {
+                failed,
+                total: data.results.len(),
+            }),
+        }
+    }
+
+    /// List the environments with all the given labels that are in the created state
+    #[allow(clippy::result_large_err)]
+    fn select_environments(
+        &self,
+        labels: &[Label],
+    ) -> Result<Vec<EnvironmentName>, ProvisionSubcommandError> {
+        let selection_failed =
+            |reason: String| ProvisionSubcommandError::EnvironmentSelectionFailed {
+                labels: labels
+                    .iter()
+                    .map(ToString::to_string)
+                    .collect::<Vec<_>>()
+                    .join(", "),
+                reason,
+            };
+
+        let list_handler = self
+            .list_handler
+            .as_ref()
+            .ok_or_else(|| selection_failed("environment listing is not available".to_string()))?;
+
+        let filter = ListFilter::new()
+            .with_labels(labels.iter().cloned())
+            .with_states(["created"]);
+        let list = list_handler
+            .execute(&filter)
+            .map_err(|e| selection_failed(e.to_string()))?;
+
+        list.environments
+            .into_iter()
+            .map(|summary| {
+                EnvironmentName::new(summary.name).map_err(|e| selection_failed(e.to_string()))
+            })
+            .collect()
+    }
+
     /// Validate the environment name format
     ///
     /// Shows progress to user and validates that the environment name
@@ -180,7 +444,11 @@ impl ProvisionCommandController {
     ) -> Result<ProvisionCommandHandler, ProvisionSubcommandError> {
         self.progress
             .start_step(ProvisionStep::CreateCommandHandler.description())?;
-        let handler = ProvisionCommandHandler::new(self.clock.clone(), self.repository.clone());
+        let handler = ProvisionCommandHandler::new(self.clock.clone(), self.repository.clone())
+            .with_kept_run_logs(self.kept_run_logs)
+            .with_trace_dir(self.trace_dir.clone())
+            .with_disk_space_thresholds(self.disk_space_thresholds)
+            .with_cancellation(self.cancellation.clone());
         self.progress.complete_step(None)?;
 
         Ok(handler)
@@ -209,11 +477,26 @@ impl ProvisionCommandController {
         // The VerboseProgressListener translates step events into
         // user-facing detail messages via UserOutput's verbosity filter.
         let listener = VerboseProgressListener::new(self.progress.output().clone());
+        let recorder = DeploymentReportListener::new(
+            "provision",
+            env_name,
+            self.clock.clone(),
+            Some(&listener),
+        );
+
+        let result = handler.execute(env_name, Some(&recorder)).await;
+        let report = recorder.write(
+            self.repository.as_ref(),
+            &[Dependency::OpenTofu, Dependency::Ansible],
+            result.as_ref().err(),
+        );
+        if let Some(trace_file) = report.and_then(|report| report.trace_file) {
+            self.progress
+                .warn(&format!("Trace file: {}", trace_file.display()))?;
+        }
 
-        let provisioned = handler
-            .execute(env_name, Some(&listener))
-            .await
-            .map_err(
+        let provisioned =
+            result.map_err(
                 |source| ProvisionSubcommandError::ProvisionOperationFailed {
                     name: env_name.to_string(),
                     source: Box::new(source),
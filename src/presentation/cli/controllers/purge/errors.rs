@@ -6,6 +6,7 @@
 
 use thiserror::Error;
 
+use crate::application::command_handlers::destroy::DestroyCommandHandlerError;
 use crate::application::command_handlers::purge::errors::PurgeCommandHandlerError;
 use crate::domain::environment::name::EnvironmentNameError;
 use crate::presentation::cli::views::progress::ProgressReporterError;
@@ -70,6 +71,20 @@ Tip: Check directory permissions and disk space"
     RepositoryAccessFailed { data_dir: String, reason: String },
 
     // ===== Purge Operation Errors =====
+    /// Destroying the infrastructure before purging failed (`--destroy-first`)
+    ///
+    /// Nothing was purged: the local data is still needed to retry destroy.
+    /// Use `.help()` for detailed troubleshooting steps.
+    #[error(
+        "Failed to destroy environment '{name}' before purging it: {source}
+Tip: Nothing was purged - fix the error and run the command again"
+    )]
+    DestroyOperationFailed {
+        name: String,
+        #[source]
+        source: DestroyCommandHandlerError,
+    },
+
     /// Purge operation failed
     ///
     /// The purge process encountered an error during execution.
@@ -81,6 +96,27 @@ Tip: Check directory permissions and disk space"
         source: PurgeCommandHandlerError,
     },
 
+    /// The environments to garbage collect could not be selected (`--gc`)
+    #[error("Failed to select the environments to garbage collect: {source}")]
+    GarbageCollectionFailed {
+        #[source]
+        source: PurgeCommandHandlerError,
+    },
+
+    /// `--gc` selected environments but `--yes` was not given
+    #[error(
+        "Refusing to purge {count} environments without --yes: {environments}
+Tip: Re-run with --yes to purge them, or with --dry-run to see the details"
+    )]
+    ConfirmationRequired { count: usize, environments: String },
+
+    /// Some of the environments selected with `--gc` were not purged
+    #[error(
+        "Failed to purge {failed} of {total} environments
+Tip: The report above lists the error of each environment"
+    )]
+    BulkPurgeFailed { failed: usize, total: usize },
+
     // ===== Internal Errors =====
     /// Progress reporting failed
     ///
@@ -134,10 +170,12 @@ impl PurgeSubcommandError {
     /// use std::cell::RefCell;
     /// use parking_lot::ReentrantMutex;
     /// use torrust_tracker_deployer_lib::application::command_handlers::purge::handler::PurgeCommandHandler;
+    /// use torrust_tracker_deployer_lib::application::command_handlers::DestroyCommandHandler;
     /// use torrust_tracker_deployer_lib::presentation::cli::controllers::purge::handler::PurgeCommandController;
     /// use torrust_tracker_deployer_lib::presentation::cli::input::cli::OutputFormat;
     /// use torrust_tracker_deployer_lib::presentation::cli::views::{UserOutput, VerbosityLevel};
     /// use torrust_tracker_deployer_lib::infrastructure::persistence::file_repository_factory::FileRepositoryFactory;
+    /// use torrust_tracker_deployer_lib::shared::SystemClock;
     ///
     /// # #[tokio::main]
     /// # async fn main() {
@@ -145,8 +183,10 @@ impl PurgeSubcommandError {
     /// let data_dir = PathBuf::from("./data");
     /// let file_repository_factory = FileRepositoryFactory::new(Duration::from_secs(30));
     /// let repository = file_repository_factory.create(data_dir.clone());
-    /// let handler = PurgeCommandHandler::new(repository, data_dir);
-    /// if let Err(e) = PurgeCommandController::new(handler, output).execute("test-env", false, OutputFormat::Text).await {
+    /// let handler = PurgeCommandHandler::new(repository.clone(), data_dir);
+    /// let destroy_handler = DestroyCommandHandler::new(repository, Arc::new(SystemClock));
+    /// let mut controller = PurgeCommandController::new(handler, destroy_handler, output);
+    /// if let Err(e) = controller.execute("test-env", false, false, OutputFormat::Text).await {
     ///     eprintln!("Error: {e}");
     ///     eprintln!("\nTroubleshooting:\n{}", e.help());
     /// }
@@ -213,8 +253,8 @@ No changes were made to the environment data.
 
 To proceed with purge:
 1. Run the command again and confirm when prompted
-2. Or use --force flag to skip confirmation:
-   torrust-tracker-deployer purge <environment-name> --force
+2. Or use --yes flag to skip confirmation:
+   torrust-tracker-deployer purge <environment-name> --yes
 
 Warning: Purge is irreversible - all local environment data will be permanently deleted."
             }
@@ -228,14 +268,14 @@ Possible causes:
 
 Troubleshooting steps:
 1. Ensure running in an interactive terminal
-2. Use --force flag to skip confirmation prompt:
-   torrust-tracker-deployer purge <environment-name> --force
+2. Use --yes flag to skip confirmation prompt:
+   torrust-tracker-deployer purge <environment-name> --yes
 
 3. Check if stdin is available:
    test -t 0 && echo 'stdin is terminal' || echo 'stdin is not terminal'
 
 4. Run from a proper terminal (not via automation/CI)
-   - For automation, always use --force flag"
+   - For automation, always use --yes flag"
             }
             Self::RepositoryAccessFailed { .. } => {
                 r"Failed to access environment repository.
@@ -261,14 +301,31 @@ Troubleshooting steps:
 
 5. Try running with elevated permissions (if appropriate):
    sudo torrust-tracker-deployer purge <environment-name>"
+            }
+            Self::DestroyOperationFailed { .. } => {
+                r"Destroying the infrastructure failed, so nothing was purged.
+
+The local data is kept: it is what the deployer needs to retry the destroy.
+
+Troubleshooting steps:
+1. Check logs for detailed error information:
+   torrust-tracker-deployer destroy <environment-name> --log-output file-and-stderr
+
+2. Retry the destroy on its own, then purge:
+   torrust-tracker-deployer destroy <environment-name>
+   torrust-tracker-deployer purge <environment-name>
+
+3. If the infrastructure was removed by other means, purge anyway:
+   torrust-tracker-deployer purge <environment-name> --force"
             }
             Self::PurgeOperationFailed { .. } => {
                 r"Purge operation failed during execution.
 
 This could be due to:
-1. File system errors (permissions, disk full)
-2. Locked files or directories
-3. Corrupted environment data
+1. The environment is not destroyed (its infrastructure may still exist)
+2. File system errors (permissions, disk full)
+3. Locked files or directories
+4. Corrupted environment data
 
 Troubleshooting steps:
 1. Check logs for detailed error information:
@@ -288,6 +345,43 @@ Troubleshooting steps:
 5. If environment is in an invalid state:
    - Check environment.json for corruption
    - Restore from backup if available"
+            }
+            Self::GarbageCollectionFailed { .. } => {
+                r"The environments to garbage collect could not be selected.
+
+Nothing was purged.
+
+Troubleshooting steps:
+1. Check that the data directory is readable:
+   ls -la ./data/
+
+2. Check that the environments can be listed:
+   torrust-tracker-deployer list"
+            }
+            Self::ConfirmationRequired { .. } => {
+                r"Garbage collection can remove many environments at once, so the selected
+environments are only listed until the command is confirmed.
+
+Troubleshooting steps:
+1. Review what would be removed:
+   torrust-tracker-deployer purge --gc --older-than 14d --dry-run
+
+2. Re-run the command with --yes:
+   torrust-tracker-deployer purge --gc --older-than 14d --yes"
+            }
+            Self::BulkPurgeFailed { .. } => {
+                r"Some of the selected environments could not be purged.
+
+The other environments were purged: only the ones reported with an error
+are left.
+
+Troubleshooting steps:
+1. Purge a failed environment on its own to see the full error:
+   torrust-tracker-deployer purge <environment-name> --yes
+
+2. Re-running the garbage collection only retries the environments that
+   are left:
+   torrust-tracker-deployer purge --gc --older-than 14d --yes"
             }
             Self::ProgressReportingFailed { .. } => {
                 r"Progress reporting system encountered a critical error.
@@ -308,8 +402,8 @@ Immediate steps:
    - Steps to reproduce
 
 Workaround:
-Try using --force flag and check if operation completes despite the error:
-   torrust-tracker-deployer purge <environment-name> --force"
+Try using --yes flag and check if operation completes despite the error:
+   torrust-tracker-deployer purge <environment-name> --yes"
             }
             Self::OutputFormatting { .. } => {
                 "Output Formatting Failed - Critical Internal Error:\n\nThis error should not occur during normal operation. It indicates a bug in the output formatting system.\n\n1. Immediate actions:\n   - Save full error output\n   - Copy log files from data/logs/\n   - Note the exact command and output format being used\n\n2. Report the issue:\n   - Create GitHub issue with full details\n   - Include: command, output format (--output-format), error output, logs\n   - Describe steps to reproduce\n\n3. Temporary workarounds:\n   - Try using different output format (text vs json)\n   - Try running command again\n\nPlease report it so we can fix it."
@@ -8,10 +8,18 @@ use std::sync::Arc;
 
 use parking_lot::ReentrantMutex;
 
+use crate::application::command_handlers::common::DryRunReport;
+use crate::application::command_handlers::purge::gc::GcCriteria;
 use crate::application::command_handlers::purge::handler::PurgeCommandHandler;
+use crate::application::command_handlers::DestroyCommandHandler;
 use crate::domain::environment::name::EnvironmentName;
 use crate::presentation::cli::input::cli::OutputFormat;
-use crate::presentation::cli::views::commands::purge::{JsonView, PurgeDetailsData, TextView};
+use crate::presentation::cli::views::commands::purge::{
+    GcJsonView, GcReportData, GcTextView, JsonView, PurgeDetailsData, TextView,
+};
+use crate::presentation::cli::views::commands::shared::dry_run_report::{
+    DryRunReportData, DryRunReportJsonView, DryRunReportTextView,
+};
 use crate::presentation::cli::views::progress::ProgressReporter;
 use crate::presentation::cli::views::Render;
 use crate::presentation::cli::views::UserOutput;
@@ -23,6 +31,7 @@ use super::errors::PurgeSubcommandError;
 enum PurgeStep {
     ValidateEnvironment,
     ConfirmOperation,
+    DestroyInfrastructure,
     PurgeLocalData,
 }
 
@@ -31,6 +40,7 @@ impl PurgeStep {
     const ALL: &'static [Self] = &[
         Self::ValidateEnvironment,
         Self::ConfirmOperation,
+        Self::DestroyInfrastructure,
         Self::PurgeLocalData,
     ];
 
@@ -44,6 +54,7 @@ impl PurgeStep {
         match self {
             Self::ValidateEnvironment => "Validating environment",
             Self::ConfirmOperation => "Confirming operation",
+            Self::DestroyInfrastructure => "Destroying infrastructure",
             Self::PurgeLocalData => "Purging local data",
         }
     }
@@ -58,7 +69,9 @@ impl PurgeStep {
 ///
 /// - Validate user input (environment name format)
 /// - Show progress updates to the user
-/// - Handle confirmation prompts (unless --force is provided)
+/// - Handle confirmation prompts (unless --yes or --force is provided)
+/// - Destroy the infrastructure first when --destroy-first is provided
+/// - Purge every environment destroyed long enough ago with --gc
 /// - Format success/error messages for display
 /// - Delegate business logic to application layer
 ///
@@ -69,36 +82,59 @@ impl PurgeStep {
 /// `PurgeCommandHandler`, maintaining clear separation of concerns.
 pub struct PurgeCommandController {
     handler: PurgeCommandHandler,
+    destroy_handler: DestroyCommandHandler,
     progress: ProgressReporter,
+    force: bool,
 }
 
 impl PurgeCommandController {
     /// Create a new purge command controller
     ///
-    /// Creates a `PurgeCommandController` with the application handler.
+    /// Creates a `PurgeCommandController` with the application handlers.
+    /// The destroy handler is only used with `--destroy-first`.
     /// This follows the single container architecture pattern.
     #[allow(clippy::needless_pass_by_value)] // Constructor takes ownership of Arc parameters
     pub fn new(
         handler: PurgeCommandHandler,
+        destroy_handler: DestroyCommandHandler,
         user_output: Arc<ReentrantMutex<RefCell<UserOutput>>>,
     ) -> Self {
         let progress = ProgressReporter::new(user_output, PurgeStep::count());
 
-        Self { handler, progress }
+        Self {
+            handler,
+            destroy_handler,
+            progress,
+            force: false,
+        }
+    }
+
+    /// Purge environments that may still have infrastructure (`--force`)
+    ///
+    /// Like `--yes`, it also skips the confirmation prompt of a single
+    /// environment: `--force` did so before it allowed purging such
+    /// environments, and scripts rely on it.
+    #[must_use]
+    pub fn with_force(mut self, force: bool) -> Self {
+        self.handler = self.handler.with_force(force);
+        self.force = force;
+        self
     }
 
     /// Execute the complete purge workflow
     ///
     /// Orchestrates all steps of the purge command:
     /// 1. Validate environment name
-    /// 2. Confirm operation (unless --force is provided)
-    /// 3. Purge local data
-    /// 4. Complete with success message
+    /// 2. Confirm operation (unless --yes or --force is provided)
+    /// 3. Destroy infrastructure (only with --destroy-first)
+    /// 4. Purge local data
+    /// 5. Complete with success message
     ///
     /// # Arguments
     ///
     /// * `environment_name` - The name of the environment to purge
-    /// * `force` - Skip confirmation prompt if true
+    /// * `skip_confirmation` - Skip confirmation prompt if true
+    /// * `destroy_first` - Destroy the infrastructure before purging if true
     /// * `output_format` - Output format (text or JSON)
     ///
     /// # Errors
@@ -107,7 +143,8 @@ impl PurgeCommandController {
     /// - Environment name is invalid (format validation fails)
     /// - Environment cannot be loaded from repository
     /// - User cancels operation at confirmation prompt
-    /// - Purge operation fails
+    /// - Infrastructure destruction fails
+    /// - Purge operation fails (e.g. the environment is not destroyed)
     /// - Progress reporting encounters a poisoned mutex
     ///
     /// # Returns
@@ -118,21 +155,44 @@ impl PurgeCommandController {
     pub async fn execute(
         &mut self,
         environment_name: &str,
-        force: bool,
+        skip_confirmation: bool,
+        destroy_first: bool,
+        output_format: OutputFormat,
+    ) -> Result<(), PurgeSubcommandError> {
+        self.progress.set_output_format(output_format);
+        let result = self
+            .execute_steps(
+                environment_name,
+                skip_confirmation,
+                destroy_first,
+                output_format,
+            )
+            .await;
+        self.progress.finish(result)
+    }
+
+    /// Steps of [`Self::execute`], before the timing summary
+    #[allow(clippy::result_large_err)]
+    #[allow(clippy::unused_async)] // Part of uniform async presentation layer interface
+    async fn execute_steps(
+        &mut self,
+        environment_name: &str,
+        skip_confirmation: bool,
+        destroy_first: bool,
         output_format: OutputFormat,
     ) -> Result<(), PurgeSubcommandError> {
         let env_name = self.validate_environment_name(environment_name)?;
 
-        // Handle confirmation unless --force flag provided
-        if !force {
+        // Handle confirmation unless --yes or --force flag provided
+        if !skip_confirmation && !self.force {
             self.progress
                 .start_step(PurgeStep::ConfirmOperation.description())?;
 
-            // Show warning and prompt for confirmation
-            self.show_confirmation_prompt(environment_name);
+            // Show warning and prompt for the environment name
+            self.show_confirmation_prompt(environment_name, destroy_first);
 
             // Read user response
-            if !Self::read_user_confirmation()? {
+            if !Self::read_user_confirmation(environment_name)? {
                 self.progress.complete_step(None)?;
                 return Err(PurgeSubcommandError::UserCancelled);
             }
@@ -140,6 +200,19 @@ impl PurgeCommandController {
             self.progress.complete_step(None)?;
         }
 
+        if destroy_first {
+            self.progress
+                .start_step(PurgeStep::DestroyInfrastructure.description())?;
+            self.destroy_handler
+                .execute(&env_name, None)
+                .map_err(|source| PurgeSubcommandError::DestroyOperationFailed {
+                    name: environment_name.to_string(),
+                    source,
+                })?;
+            self.progress
+                .complete_step(Some("Infrastructure torn down"))?;
+        }
+
         // Execute purge via application handler
         self.progress
             .start_step(PurgeStep::PurgeLocalData.description())?;
@@ -156,6 +229,138 @@ impl PurgeCommandController {
         Ok(())
     }
 
+    /// Simulate the purge workflow (`--dry-run`)
+    ///
+    /// Displays the local directories that would be removed. No confirmation
+    /// is asked because nothing is deleted.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the environment name is invalid or the environment
+    /// does not exist.
+    #[allow(clippy::result_large_err)]
+    #[allow(clippy::unused_async)] // Part of uniform async presentation layer interface
+    pub async fn dry_run(
+        &mut self,
+        environment_name: &str,
+        output_format: OutputFormat,
+    ) -> Result<DryRunReport, PurgeSubcommandError> {
+        self.progress.set_output_format(output_format);
+        let result = self.dry_run_steps(environment_name, output_format).await;
+        self.progress.finish(result)
+    }
+
+    /// Steps of [`Self::dry_run`], before the timing summary
+    #[allow(clippy::result_large_err)]
+    #[allow(clippy::unused_async)] // Part of uniform async presentation layer interface
+    async fn dry_run_steps(
+        &mut self,
+        environment_name: &str,
+        output_format: OutputFormat,
+    ) -> Result<DryRunReport, PurgeSubcommandError> {
+        let env_name = self.validate_environment_name(environment_name)?;
+
+        self.progress
+            .start_step(PurgeStep::PurgeLocalData.description())?;
+        let report = self.handler.dry_run(&env_name).map_err(|source| {
+            PurgeSubcommandError::PurgeOperationFailed {
+                name: environment_name.to_string(),
+                source,
+            }
+        })?;
+        self.progress
+            .complete_step(Some("Dry run completed, nothing was removed"))?;
+
+        self.progress.blank_line()?;
+        let data = DryRunReportData::new(environment_name, "purge", &report);
+        let output = match output_format {
+            OutputFormat::Text => DryRunReportTextView::render(&data)?,
+            OutputFormat::Json => DryRunReportJsonView::render(&data)?,
+        };
+        self.progress.result(&output)?;
+
+        Ok(report)
+    }
+
+    /// Purge every environment selected by `criteria` (`--gc`)
+    ///
+    /// With `dry_run`, only reports the environments that would be purged.
+    /// Otherwise, without `confirmed` (`--yes`), the selected environments
+    /// are only listed in the returned error. A failed purge does not stop
+    /// the other environments: the results and the reclaimed disk space are
+    /// reported once all were attempted.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the environments cannot be listed, the command is
+    /// not confirmed, or any selected environment fails to be purged.
+    #[allow(clippy::result_large_err)]
+    #[allow(clippy::unused_async)] // Part of uniform async presentation layer interface
+    pub async fn gc(
+        &mut self,
+        criteria: GcCriteria,
+        confirmed: bool,
+        dry_run: bool,
+        output_format: OutputFormat,
+    ) -> Result<GcReportData, PurgeSubcommandError> {
+        self.progress.set_output_format(output_format);
+        let result = self
+            .gc_steps(criteria, confirmed, dry_run, output_format)
+            .await;
+        self.progress.finish(result)
+    }
+
+    /// Steps of [`Self::gc`], before the timing summary
+    #[allow(clippy::result_large_err)]
+    #[allow(clippy::unused_async)] // Part of uniform async presentation layer interface
+    async fn gc_steps(
+        &mut self,
+        criteria: GcCriteria,
+        confirmed: bool,
+        dry_run: bool,
+        output_format: OutputFormat,
+    ) -> Result<GcReportData, PurgeSubcommandError> {
+        let selection = self
+            .handler
+            .gc_dry_run(criteria)
+            .map_err(|source| PurgeSubcommandError::GarbageCollectionFailed { source })?;
+
+        let report = if dry_run {
+            selection
+        } else {
+            if !selection.purged.is_empty() && !confirmed {
+                return Err(PurgeSubcommandError::ConfirmationRequired {
+                    count: selection.purged.len(),
+                    environments: selection
+                        .purged
+                        .iter()
+                        .map(|candidate| candidate.name.to_string())
+                        .collect::<Vec<_>>()
+                        .join(", "),
+                });
+            }
+
+            self.handler
+                .gc(criteria)
+                .map_err(|source| PurgeSubcommandError::GarbageCollectionFailed { source })?
+        };
+
+        let data = GcReportData::new(criteria.older_than(), &report);
+        let output = match output_format {
+            OutputFormat::Text => GcTextView::render(&data)?,
+            OutputFormat::Json => GcJsonView::render(&data)?,
+        };
+        self.progress.result(&output)?;
+
+        match data.failed.len() {
+            0 => Ok(data),
+            failed => Err(PurgeSubcommandError::BulkPurgeFailed {
+                failed,
+                total: failed + data.purged.len(),
+            }),
+        }
+    }
+
     /// Validate the environment name format
     ///
     /// Shows progress to user and validates that the environment name
@@ -205,10 +410,16 @@ impl PurgeCommandController {
     /// Show confirmation prompt with warning message
     ///
     /// Displays a warning about the irreversible nature of the purge operation
-    /// and prompts the user to confirm.
-    fn show_confirmation_prompt(&mut self, environment_name: &str) {
+    /// and asks the user to type the environment name to confirm.
+    fn show_confirmation_prompt(&mut self, environment_name: &str, destroy_first: bool) {
+        let destroy_line = if destroy_first {
+            format!("• The infrastructure of '{environment_name}' (destroyed first)\n")
+        } else {
+            String::new()
+        };
         let warning = format!(
             "⚠️  WARNING: This will permanently delete all local data for '{environment_name}':\n\
+             {destroy_line}\
              • data/{environment_name}/ directory\n\
              • build/{environment_name}/ directory\n\
              • Environment registry entry\n\
@@ -222,14 +433,16 @@ impl PurgeCommandController {
             .output()
             .lock()
             .borrow_mut()
-            .progress("Are you sure you want to continue? (y/N): ");
+            .progress(&format!(
+                "Type the environment name ({environment_name}) to confirm: "
+            ));
     }
 
     /// Read user confirmation from stdin
     ///
-    /// Returns `true` if user confirms (enters 'y' or 'Y'), `false` otherwise.
+    /// Returns `true` if the user typed the environment name, `false` otherwise.
     #[allow(clippy::result_large_err)]
-    fn read_user_confirmation() -> Result<bool, PurgeSubcommandError> {
+    fn read_user_confirmation(environment_name: &str) -> Result<bool, PurgeSubcommandError> {
         use std::io::{self, BufRead};
 
         let stdin = io::stdin();
@@ -243,17 +456,165 @@ impl PurgeCommandController {
                 source,
             })?;
 
-        let response = line.trim().to_lowercase();
-        Ok(response == "y" || response == "yes")
+        Ok(line.trim() == environment_name)
     }
 }
 
 #[cfg(test)]
+#[cfg(unix)] // The fake `tofu` is a shell script made executable with Unix permissions
 mod tests {
-    // TODO: Add unit tests in Phase 3 when implementing actual purge logic
-    // Tests should cover:
-    // - Valid environment name validation
-    // - Invalid environment name rejection
-    // - Force flag behavior
-    // - Error handling for non-existent environments
+    use std::os::unix::fs::PermissionsExt;
+    use std::path::{Path, PathBuf};
+
+    use tempfile::TempDir;
+
+    use super::*;
+    use crate::application::command_handlers::destroy::DestroyCommandHandlerError;
+    use crate::application::command_handlers::purge::errors::PurgeCommandHandlerError;
+    use crate::domain::environment::repository::EnvironmentRepository;
+    use crate::domain::environment::state::AnyEnvironmentState;
+    use crate::domain::environment::testing::EnvironmentTestBuilder;
+    use crate::infrastructure::persistence::filesystem::file_environment_repository::FileEnvironmentRepository;
+    use crate::presentation::cli::views::testing::TestUserOutput;
+    use crate::presentation::cli::views::VerbosityLevel;
+    use crate::shared::SystemClock;
+
+    /// Writes a fake `tofu` that logs its subcommands and exits with `destroy_exit_code` on `destroy`
+    fn write_fake_tofu(dir: &Path, destroy_exit_code: u8) -> (String, PathBuf) {
+        let script = dir.join("fake-tofu");
+        let calls_file = dir.join("tofu-calls.log");
+        std::fs::write(
+            &script,
+            format!(
+                "#!/bin/sh\necho \"$1\" >> {}\nif [ \"$1\" = destroy ]; then\n  echo 'Error: Unable to connect to the LXD server' >&2\n  exit {destroy_exit_code}\nfi\n",
+                calls_file.display()
+            ),
+        )
+        .unwrap();
+        std::fs::set_permissions(&script, std::fs::Permissions::from_mode(0o755)).unwrap();
+
+        (script.display().to_string(), calls_file)
+    }
+
+    /// Saves a provisioned-looking environment: its `OpenTofu` build directory exists
+    fn save_environment(name: &str) -> (EnvironmentName, Arc<FileEnvironmentRepository>, TempDir) {
+        let (environment, _data_dir, _build_dir, temp_dir) = EnvironmentTestBuilder::new()
+            .with_name(name)
+            .build_with_custom_paths();
+        std::fs::create_dir_all(environment.tofu_build_dir()).unwrap();
+        let env_name = environment.name().clone();
+        let repository = Arc::new(FileEnvironmentRepository::new(temp_dir.path().join("data")));
+        repository.save(&environment.into_any()).unwrap();
+
+        (env_name, repository, temp_dir)
+    }
+
+    fn controller(
+        repository: &Arc<FileEnvironmentRepository>,
+        temp_dir: &TempDir,
+        fake_tofu: String,
+    ) -> PurgeCommandController {
+        let (user_output, _, _) =
+            TestUserOutput::new(VerbosityLevel::Normal).into_reentrant_wrapped();
+
+        PurgeCommandController::new(
+            PurgeCommandHandler::new(repository.clone(), temp_dir.path().to_path_buf()),
+            DestroyCommandHandler::new(repository.clone(), Arc::new(SystemClock))
+                .with_opentofu_executable(fake_tofu),
+            user_output,
+        )
+    }
+
+    #[tokio::test]
+    async fn it_should_destroy_the_infrastructure_then_purge_with_destroy_first() {
+        let (env_name, repository, temp_dir) = save_environment("destroy-first-env");
+        let (fake_tofu, calls_file) = write_fake_tofu(temp_dir.path(), 0);
+
+        controller(&repository, &temp_dir, fake_tofu)
+            .execute(env_name.as_str(), true, true, OutputFormat::Text)
+            .await
+            .unwrap();
+
+        assert!(std::fs::read_to_string(calls_file)
+            .unwrap()
+            .lines()
+            .any(|call| call == "destroy"));
+        assert!(!repository.exists(&env_name).unwrap());
+        assert!(!temp_dir
+            .path()
+            .join("data")
+            .join(env_name.as_str())
+            .exists());
+        assert!(!temp_dir
+            .path()
+            .join("build")
+            .join(env_name.as_str())
+            .exists());
+    }
+
+    #[tokio::test]
+    async fn it_should_keep_the_local_data_when_destroy_first_fails() {
+        let (env_name, repository, temp_dir) = save_environment("destroy-fails-env");
+        let (fake_tofu, _calls_file) = write_fake_tofu(temp_dir.path(), 1);
+
+        let result = controller(&repository, &temp_dir, fake_tofu)
+            .execute(env_name.as_str(), true, true, OutputFormat::Text)
+            .await;
+
+        assert!(matches!(
+            result,
+            Err(PurgeSubcommandError::DestroyOperationFailed {
+                source: DestroyCommandHandlerError::Command(_),
+                ..
+            })
+        ));
+        assert!(matches!(
+            repository.load(&env_name).unwrap().unwrap(),
+            AnyEnvironmentState::DestroyFailed(_)
+        ));
+        assert!(temp_dir
+            .path()
+            .join("build")
+            .join(env_name.as_str())
+            .exists());
+    }
+
+    #[tokio::test]
+    async fn it_should_refuse_to_purge_an_environment_that_may_have_infrastructure() {
+        let (env_name, repository, temp_dir) = save_environment("destroy-failed-env");
+        let (fake_tofu, _calls_file) = write_fake_tofu(temp_dir.path(), 1);
+        let mut controller = controller(&repository, &temp_dir, fake_tofu);
+        controller
+            .execute(env_name.as_str(), true, true, OutputFormat::Text)
+            .await
+            .unwrap_err();
+
+        let result = controller
+            .execute(env_name.as_str(), true, false, OutputFormat::Text)
+            .await;
+
+        assert!(matches!(
+            result,
+            Err(PurgeSubcommandError::PurgeOperationFailed {
+                source: PurgeCommandHandlerError::EnvironmentNotDestroyed { .. },
+                ..
+            })
+        ));
+        assert!(repository.exists(&env_name).unwrap());
+    }
+
+    #[tokio::test]
+    async fn it_should_skip_the_confirmation_prompt_with_force() {
+        let (env_name, repository, temp_dir) = save_environment("force-env");
+        let (fake_tofu, calls_file) = write_fake_tofu(temp_dir.path(), 0);
+
+        controller(&repository, &temp_dir, fake_tofu)
+            .with_force(true)
+            .execute(env_name.as_str(), false, false, OutputFormat::Text)
+            .await
+            .unwrap();
+
+        assert!(!repository.exists(&env_name).unwrap());
+        assert!(!calls_file.exists(), "OpenTofu should not have been run");
+    }
 }
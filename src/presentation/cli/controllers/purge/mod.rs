@@ -29,7 +29,7 @@
 //! let container = Container::new(VerbosityLevel::Normal, Path::new("."));
 //! if let Err(e) = container
 //!     .create_purge_controller()
-//!     .execute("test-env", false, OutputFormat::Text)
+//!     .execute("test-env", false, false, OutputFormat::Text)
 //!     .await
 //! {
 //!     eprintln!("Purge failed: {e}");
@@ -47,10 +47,12 @@
 //! use parking_lot::ReentrantMutex;
 //! use std::cell::RefCell;
 //! use torrust_tracker_deployer_lib::application::command_handlers::purge::handler::PurgeCommandHandler;
+//! use torrust_tracker_deployer_lib::application::command_handlers::DestroyCommandHandler;
 //! use torrust_tracker_deployer_lib::presentation::cli::controllers::purge::handler::PurgeCommandController;
 //! use torrust_tracker_deployer_lib::presentation::cli::input::cli::OutputFormat;
 //! use torrust_tracker_deployer_lib::presentation::cli::views::{UserOutput, VerbosityLevel};
 //! use torrust_tracker_deployer_lib::infrastructure::persistence::file_repository_factory::FileRepositoryFactory;
+//! use torrust_tracker_deployer_lib::shared::SystemClock;
 //!
 //! # #[tokio::main]
 //! # async fn main() {
@@ -58,8 +60,10 @@
 //! let data_dir = PathBuf::from("./data");
 //! let file_repository_factory = FileRepositoryFactory::new(Duration::from_secs(30));
 //! let repository = file_repository_factory.create(data_dir.clone());
-//! let handler = PurgeCommandHandler::new(repository, data_dir);
-//! if let Err(e) = PurgeCommandController::new(handler, output).execute("test-env", false, OutputFormat::Text).await {
+//! let handler = PurgeCommandHandler::new(repository.clone(), data_dir);
+//! let destroy_handler = DestroyCommandHandler::new(repository, Arc::new(SystemClock));
+//! let mut controller = PurgeCommandController::new(handler, destroy_handler, output);
+//! if let Err(e) = controller.execute("test-env", false, false, OutputFormat::Text).await {
 //!     eprintln!("Purge failed: {e}");
 //!     eprintln!("\n{}", e.help());
 //! }
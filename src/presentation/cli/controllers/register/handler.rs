@@ -8,8 +8,10 @@ use std::net::IpAddr;
 use std::sync::Arc;
 
 use parking_lot::ReentrantMutex;
+use torrust_tracker_deployer_dependency_installer::Dependency;
 
 use crate::application::command_handlers::RegisterCommandHandler;
+use crate::application::services::deployment_report::DeploymentReportListener;
 use crate::domain::environment::name::EnvironmentName;
 use crate::domain::environment::repository::EnvironmentRepository;
 use crate::domain::environment::state::Provisioned;
@@ -126,6 +128,22 @@ impl RegisterCommandController {
         instance_ip_str: &str,
         ssh_port: Option<u16>,
         output_format: OutputFormat,
+    ) -> Result<Environment<Provisioned>, RegisterSubcommandError> {
+        self.progress.set_output_format(output_format);
+        let result = self
+            .execute_steps(environment_name, instance_ip_str, ssh_port, output_format)
+            .await;
+        self.progress.finish(result)
+    }
+
+    /// Steps of [`Self::execute`], before the timing summary
+    #[allow(clippy::result_large_err)]
+    async fn execute_steps(
+        &mut self,
+        environment_name: &str,
+        instance_ip_str: &str,
+        ssh_port: Option<u16>,
+        output_format: OutputFormat,
     ) -> Result<Environment<Provisioned>, RegisterSubcommandError> {
         let (env_name, instance_ip) = self.validate_input(environment_name, instance_ip_str)?;
 
@@ -199,10 +217,17 @@ impl RegisterCommandController {
         self.progress
             .start_step(RegisterStep::RegisterInstance.description())?;
 
-        let provisioned = handler
-            .execute(env_name, instance_ip, ssh_port)
-            .await
-            .map_err(|source| RegisterSubcommandError::RegisterOperationFailed {
+        let recorder =
+            DeploymentReportListener::new("register", env_name, self.clock.clone(), None);
+        let result = handler.execute(env_name, instance_ip, ssh_port).await;
+        recorder.write(
+            self.repository.as_ref(),
+            &[Dependency::Ansible],
+            result.as_ref().err(),
+        );
+
+        let provisioned =
+            result.map_err(|source| RegisterSubcommandError::RegisterOperationFailed {
                 name: env_name.to_string(),
                 source: Box::new(source),
             })?;
@@ -4,22 +4,31 @@
 //! including environment validation, state validation, and user interaction.
 
 use std::cell::RefCell;
+use std::path::PathBuf;
 use std::sync::Arc;
 
 use parking_lot::ReentrantMutex;
+use torrust_tracker_deployer_dependency_installer::Dependency;
 use tracing::info;
 
+use crate::adapters::ansible::AnsiblePlaybookOptions;
+use crate::application::command_handlers::common::CheckReport;
 use crate::application::command_handlers::release::ReleaseCommandHandler;
+use crate::application::services::deployment_report::DeploymentReportListener;
 use crate::domain::environment::name::EnvironmentName;
 use crate::domain::environment::repository::EnvironmentRepository;
 use crate::domain::environment::state::Released;
-use crate::domain::environment::Environment;
+use crate::domain::environment::{DiskSpaceThresholds, Environment};
 use crate::presentation::cli::input::cli::OutputFormat;
 use crate::presentation::cli::views::commands::release::{JsonView, ReleaseDetailsData, TextView};
+use crate::presentation::cli::views::commands::shared::check_report::{
+    CheckReportData, CheckReportJsonView, CheckReportTextView,
+};
 use crate::presentation::cli::views::progress::{ProgressReporter, VerboseProgressListener};
 use crate::presentation::cli::views::Render;
 use crate::presentation::cli::views::UserOutput;
 use crate::shared::clock::Clock;
+use crate::shared::command::run_log::DEFAULT_KEPT_RUNS;
 
 use super::errors::ReleaseSubcommandError;
 
@@ -69,6 +78,11 @@ pub struct ReleaseCommandController {
     repository: Arc<dyn EnvironmentRepository + Send + Sync>,
     clock: Arc<dyn Clock>,
     progress: ProgressReporter,
+    ansible_options: AnsiblePlaybookOptions,
+    kept_run_logs: usize,
+    trace_dir: Option<PathBuf>,
+    disk_space_thresholds: DiskSpaceThresholds,
+    config_override: Option<PathBuf>,
 }
 
 impl ReleaseCommandController {
@@ -88,9 +102,49 @@ impl ReleaseCommandController {
             repository,
             clock,
             progress,
+            ansible_options: AnsiblePlaybookOptions::default(),
+            kept_run_logs: DEFAULT_KEPT_RUNS,
+            trace_dir: None,
+            disk_space_thresholds: DiskSpaceThresholds::default(),
+            config_override: None,
         }
     }
 
+    /// Keep the Ansible output logs of the last `runs` runs of the command
+    #[must_use]
+    pub fn with_kept_run_logs(mut self, runs: usize) -> Self {
+        self.kept_run_logs = runs;
+        self
+    }
+
+    /// Write the trace files of a failure to `trace_dir` (`--trace-dir`)
+    #[must_use]
+    pub fn with_trace_dir(mut self, trace_dir: Option<PathBuf>) -> Self {
+        self.trace_dir = trace_dir;
+        self
+    }
+
+    /// Require the given free disk space on the instance before releasing
+    #[must_use]
+    pub fn with_disk_space_thresholds(mut self, thresholds: DiskSpaceThresholds) -> Self {
+        self.disk_space_thresholds = thresholds;
+        self
+    }
+
+    /// Pass `--tags`/`--skip-tags` through to `ansible-playbook`
+    #[must_use]
+    pub fn with_ansible_options(mut self, options: AnsiblePlaybookOptions) -> Self {
+        self.ansible_options = options;
+        self
+    }
+
+    /// Merge the override file at `path` onto the configuration of the environment
+    #[must_use]
+    pub fn with_config_override(mut self, path: Option<PathBuf>) -> Self {
+        self.config_override = path;
+        self
+    }
+
     /// Execute the complete release workflow
     ///
     /// Orchestrates all steps of the release command:
@@ -118,6 +172,18 @@ impl ReleaseCommandController {
         &mut self,
         environment_name: &str,
         output_format: OutputFormat,
+    ) -> Result<(), ReleaseSubcommandError> {
+        self.progress.set_output_format(output_format);
+        let result = self.execute_steps(environment_name, output_format).await;
+        self.progress.finish(result)
+    }
+
+    /// Steps of [`Self::execute`], before the timing summary
+    #[allow(clippy::result_large_err)]
+    async fn execute_steps(
+        &mut self,
+        environment_name: &str,
+        output_format: OutputFormat,
     ) -> Result<(), ReleaseSubcommandError> {
         let env_name = self.validate_environment_name(environment_name)?;
 
@@ -128,6 +194,137 @@ impl ReleaseCommandController {
         Ok(())
     }
 
+    /// Execute the release workflow in check (dry-run) mode
+    ///
+    /// Renders the templates and runs the release playbooks with
+    /// `ansible-playbook --check`, then displays what would change. The
+    /// environment state is not changed.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the environment name is invalid, the environment
+    /// is not in "Configured" state, or a step fails in check mode.
+    #[allow(clippy::result_large_err)]
+    pub async fn check(
+        &mut self,
+        environment_name: &str,
+        output_format: OutputFormat,
+    ) -> Result<CheckReport, ReleaseSubcommandError> {
+        self.progress.set_output_format(output_format);
+        let result = self.check_steps(environment_name, output_format).await;
+        self.progress.finish(result)
+    }
+
+    /// Steps of [`Self::check`], before the timing summary
+    #[allow(clippy::result_large_err)]
+    async fn check_steps(
+        &mut self,
+        environment_name: &str,
+        output_format: OutputFormat,
+    ) -> Result<CheckReport, ReleaseSubcommandError> {
+        let env_name = self.validate_environment_name(environment_name)?;
+
+        self.progress
+            .start_step(ReleaseStep::ReleaseApplication.description())?;
+
+        let handler = self.create_command_handler();
+
+        let listener = VerboseProgressListener::new(self.progress.output().clone());
+
+        let report = handler
+            .check(&env_name, Some(&listener))
+            .await
+            .map_err(|source| ReleaseSubcommandError::ApplicationLayerError { source })?;
+
+        self.progress
+            .complete_step(Some("Check completed, no changes applied"))?;
+
+        let data = CheckReportData::new(environment_name, "release", &report);
+        let output = match output_format {
+            OutputFormat::Text => CheckReportTextView::render(&data)?,
+            OutputFormat::Json => CheckReportJsonView::render(&data)?,
+        };
+        self.progress.result(&output)?;
+
+        Ok(report)
+    }
+
+    /// Run only the release playbook tasks selected by `--tags`/`--skip-tags`
+    ///
+    /// A tag-filtered run does not release every service, so the environment
+    /// stays `Configured`; run release without tags to make it `Released`.
+    /// The deployment report records the run.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the environment name is invalid, the environment
+    /// is not in "Configured" state, or a release step fails.
+    #[allow(clippy::result_large_err)]
+    pub async fn execute_tagged(
+        &mut self,
+        environment_name: &str,
+        output_format: OutputFormat,
+    ) -> Result<(), ReleaseSubcommandError> {
+        self.progress.set_output_format(output_format);
+        let result = self.execute_tagged_steps(environment_name).await;
+        self.progress.finish(result)
+    }
+
+    /// Steps of [`Self::execute_tagged`], before the timing summary
+    #[allow(clippy::result_large_err)]
+    async fn execute_tagged_steps(
+        &mut self,
+        environment_name: &str,
+    ) -> Result<(), ReleaseSubcommandError> {
+        let env_name = self.validate_environment_name(environment_name)?;
+
+        self.progress
+            .start_step(ReleaseStep::ReleaseApplication.description())?;
+
+        let handler = self.create_command_handler();
+
+        let listener = VerboseProgressListener::new(self.progress.output().clone());
+        let recorder = DeploymentReportListener::new(
+            "release",
+            &env_name,
+            self.clock.clone(),
+            Some(&listener),
+        );
+
+        let result = handler.execute_tagged(&env_name, Some(&recorder)).await;
+        let report = recorder.write(
+            self.repository.as_ref(),
+            &[Dependency::Ansible],
+            result.as_ref().err(),
+        );
+        if let Some(trace_file) = report.and_then(|report| report.trace_file) {
+            self.progress
+                .warn(&format!("Trace file: {}", trace_file.display()))?;
+        }
+        result.map_err(|source| ReleaseSubcommandError::ApplicationLayerError { source })?;
+
+        self.progress
+            .complete_step(Some("Selected tasks released, environment state unchanged"))?;
+        self.progress.complete(&format!(
+            "Tag-filtered release of '{environment_name}' completed"
+        ))?;
+
+        Ok(())
+    }
+
+    /// Create the application layer command handler with the controller settings
+    fn create_command_handler(&self) -> ReleaseCommandHandler {
+        let handler = ReleaseCommandHandler::new(self.repository.clone(), self.clock.clone())
+            .with_kept_run_logs(self.kept_run_logs)
+            .with_trace_dir(self.trace_dir.clone())
+            .with_disk_space_thresholds(self.disk_space_thresholds)
+            .with_ansible_options(self.ansible_options.clone());
+        match &self.config_override {
+            Some(path) => handler.with_config_override(path.clone()),
+            None => handler,
+        }
+    }
+
     /// Validate the environment name format
     ///
     /// Shows progress to user and validates that the environment name
@@ -164,17 +361,28 @@ impl ReleaseCommandController {
         self.progress
             .start_step(ReleaseStep::ReleaseApplication.description())?;
 
-        let handler = ReleaseCommandHandler::new(self.repository.clone(), self.clock.clone());
+        let handler = self.create_command_handler();
 
         // Create the listener for verbose progress reporting.
         // The VerboseProgressListener translates step events into
         // user-facing detail messages via UserOutput's verbosity filter.
         let listener = VerboseProgressListener::new(self.progress.output().clone());
+        let recorder =
+            DeploymentReportListener::new("release", env_name, self.clock.clone(), Some(&listener));
+
+        let result = handler.execute(env_name, Some(&recorder)).await;
+        let report = recorder.write(
+            self.repository.as_ref(),
+            &[Dependency::Ansible],
+            result.as_ref().err(),
+        );
+        if let Some(trace_file) = report.and_then(|report| report.trace_file) {
+            self.progress
+                .warn(&format!("Trace file: {}", trace_file.display()))?;
+        }
 
-        let released_env = handler
-            .execute(env_name, Some(&listener))
-            .await
-            .map_err(|source| ReleaseSubcommandError::ApplicationLayerError { source })?;
+        let released_env =
+            result.map_err(|source| ReleaseSubcommandError::ApplicationLayerError { source })?;
 
         info!(
             environment = %env_name,
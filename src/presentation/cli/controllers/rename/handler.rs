@@ -0,0 +1,182 @@
+//! Rename Command Handler
+//!
+//! This module handles the rename command execution at the presentation layer,
+//! giving an existing environment a new name.
+
+use std::cell::RefCell;
+use std::sync::Arc;
+
+use parking_lot::ReentrantMutex;
+
+use crate::application::command_handlers::rename::RenameCommandHandler;
+use crate::domain::environment::state::AnyEnvironmentState;
+use crate::domain::environment::EnvironmentName;
+use crate::presentation::cli::input::cli::output_format::OutputFormat;
+use crate::presentation::cli::views::commands::rename::{JsonView, RenameDetailsData, TextView};
+use crate::presentation::cli::views::progress::ProgressReporter;
+use crate::presentation::cli::views::Render;
+use crate::presentation::cli::views::UserOutput;
+
+use super::errors::RenameSubcommandError;
+
+/// Steps in the rename workflow
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum RenameStep {
+    ValidateNames,
+    RenameEnvironment,
+}
+
+impl RenameStep {
+    /// All steps in execution order
+    const ALL: &'static [Self] = &[Self::ValidateNames, Self::RenameEnvironment];
+
+    /// Total number of steps
+    const fn count() -> usize {
+        Self::ALL.len()
+    }
+
+    /// User-facing description for the step
+    fn description(self) -> &'static str {
+        match self {
+            Self::ValidateNames => "Validating environment names",
+            Self::RenameEnvironment => "Renaming environment",
+        }
+    }
+}
+
+/// Presentation layer controller for rename command workflow
+///
+/// ## Responsibilities
+///
+/// - Validate the current and new environment names
+/// - Delegate the rename to the application layer
+/// - Display the new name and directories of the environment
+pub struct RenameCommandController {
+    handler: RenameCommandHandler,
+    progress: ProgressReporter,
+}
+
+impl RenameCommandController {
+    /// Create a new `RenameCommandController` with dependencies
+    #[allow(clippy::needless_pass_by_value)] // Arc parameters are moved to constructor for ownership
+    pub fn new(
+        handler: RenameCommandHandler,
+        user_output: Arc<ReentrantMutex<RefCell<UserOutput>>>,
+    ) -> Self {
+        let progress = ProgressReporter::new(user_output, RenameStep::count());
+
+        Self { handler, progress }
+    }
+
+    /// Rename environments that may have infrastructure (`--allow-live`)
+    #[must_use]
+    pub fn with_allow_live(mut self, allow_live: bool) -> Self {
+        self.handler = self.handler.with_allow_live(allow_live);
+        self
+    }
+
+    /// Execute the rename command workflow
+    ///
+    /// # Arguments
+    ///
+    /// * `name` - Current name of the environment
+    /// * `new_name` - New name of the environment
+    /// * `output_format` - Output format (Text or Json)
+    ///
+    /// # Errors
+    ///
+    /// Returns `RenameSubcommandError` if a name is invalid or the environment
+    /// cannot be renamed
+    pub fn execute(
+        &mut self,
+        name: &str,
+        new_name: &str,
+        output_format: OutputFormat,
+    ) -> Result<(), RenameSubcommandError> {
+        self.progress.set_output_format(output_format);
+        let result = self.execute_steps(name, new_name, output_format);
+        self.progress.finish(result)
+    }
+
+    /// Steps of [`Self::execute`], before the timing summary
+    fn execute_steps(
+        &mut self,
+        name: &str,
+        new_name: &str,
+        output_format: OutputFormat,
+    ) -> Result<(), RenameSubcommandError> {
+        let (env_name, new_env_name) = self.validate_names(name, new_name)?;
+
+        let environment = self.rename_environment(&env_name, &new_env_name)?;
+
+        self.display_results(&env_name, &environment, output_format)
+    }
+
+    /// Step 1: Validate the current and new environment names
+    fn validate_names(
+        &mut self,
+        name: &str,
+        new_name: &str,
+    ) -> Result<(EnvironmentName, EnvironmentName), RenameSubcommandError> {
+        self.progress
+            .start_step(RenameStep::ValidateNames.description())?;
+
+        let parse = |name: &str| {
+            EnvironmentName::new(name.to_string()).map_err(|source| {
+                RenameSubcommandError::InvalidEnvironmentName {
+                    name: name.to_string(),
+                    source,
+                }
+            })
+        };
+        let names = (parse(name)?, parse(new_name)?);
+
+        self.progress.complete_step(None)?;
+
+        Ok(names)
+    }
+
+    /// Step 2: Rename the environment via application layer
+    fn rename_environment(
+        &mut self,
+        env_name: &EnvironmentName,
+        new_name: &EnvironmentName,
+    ) -> Result<AnyEnvironmentState, RenameSubcommandError> {
+        self.progress
+            .start_step(RenameStep::RenameEnvironment.description())?;
+
+        let environment = self.handler.execute(env_name, new_name).map_err(|e| {
+            RenameSubcommandError::RenameFailed {
+                name: env_name.to_string(),
+                new_name: new_name.to_string(),
+                source: e,
+            }
+        })?;
+
+        self.progress
+            .complete_step(Some(&format!("Moved the directories of '{env_name}'")))?;
+
+        Ok(environment)
+    }
+
+    /// Display the new name and directories of the environment
+    fn display_results(
+        &mut self,
+        previous_name: &EnvironmentName,
+        environment: &AnyEnvironmentState,
+        output_format: OutputFormat,
+    ) -> Result<(), RenameSubcommandError> {
+        let data = RenameDetailsData::from_environment(previous_name, environment);
+
+        match output_format {
+            OutputFormat::Text => {
+                self.progress.complete(&TextView::render(&data)?)?;
+            }
+            OutputFormat::Json => {
+                self.progress.result(&JsonView::render(&data)?)?;
+            }
+        }
+
+        Ok(())
+    }
+}
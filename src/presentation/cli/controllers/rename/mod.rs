@@ -0,0 +1,17 @@
+//! Rename Command Presentation Module
+//!
+//! This module implements the CLI presentation layer for the rename command,
+//! which gives an existing environment a new name and moves its local
+//! directories. Environments that may have infrastructure need `--allow-live`.
+//!
+//! ## Components
+//!
+//! - `errors` - Presentation layer error types with `.help()` methods
+//! - `handler` - Main command handler orchestrating the workflow
+
+pub mod errors;
+pub mod handler;
+pub use handler::RenameCommandController;
+
+// Re-export commonly used types for convenience
+pub use errors::RenameSubcommandError;
@@ -20,6 +20,7 @@ use crate::presentation::cli::views::commands::render::{JsonView, RenderDetailsD
 use crate::presentation::cli::views::progress::ProgressReporter;
 use crate::presentation::cli::views::Render;
 use crate::presentation::cli::views::UserOutput;
+use crate::shared::Clock;
 
 use super::errors::RenderCommandError;
 
@@ -85,10 +86,11 @@ impl RenderCommandController {
     #[allow(clippy::needless_pass_by_value)] // Constructor takes ownership of Arc parameters
     pub fn new(
         repository: Arc<dyn EnvironmentRepository>,
+        clock: Arc<dyn Clock>,
         user_output: Arc<ReentrantMutex<RefCell<UserOutput>>>,
     ) -> Self {
         Self {
-            handler: RenderCommandHandler::new(repository),
+            handler: RenderCommandHandler::new(repository, clock),
             progress: ProgressReporter::new(user_output, RenderStep::count()),
         }
     }
@@ -131,6 +133,33 @@ impl RenderCommandController {
         force: bool,
         working_dir: &Path,
         output_format: OutputFormat,
+    ) -> Result<(), RenderCommandError> {
+        self.progress.set_output_format(output_format);
+        let result = self
+            .execute_steps(
+                env_name,
+                env_file,
+                ip,
+                output_dir,
+                force,
+                working_dir,
+                output_format,
+            )
+            .await;
+        self.progress.finish(result)
+    }
+
+    /// Steps of [`Self::execute`], before the timing summary
+    #[allow(clippy::too_many_arguments)] // Required parameters for render workflow - all are necessary
+    async fn execute_steps(
+        &mut self,
+        env_name: Option<&str>,
+        env_file: Option<&Path>,
+        ip: &str,
+        output_dir: &Path,
+        force: bool,
+        working_dir: &Path,
+        output_format: OutputFormat,
     ) -> Result<(), RenderCommandError> {
         // Step 1: Validate input
         self.progress
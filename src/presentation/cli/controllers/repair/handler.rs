@@ -0,0 +1,195 @@
+//! Repair Command Handler
+//!
+//! This module handles the repair command execution at the presentation
+//! layer, including environment name validation and user interaction.
+
+use std::cell::RefCell;
+use std::sync::Arc;
+
+use parking_lot::ReentrantMutex;
+
+use crate::application::command_handlers::repair::{RepairCommandHandler, RepairReport};
+use crate::domain::environment::name::EnvironmentName;
+use crate::domain::environment::repository::EnvironmentRepository;
+use crate::presentation::cli::input::cli::OutputFormat;
+use crate::presentation::cli::views::commands::repair::{JsonView, TextView};
+use crate::presentation::cli::views::progress::ProgressReporter;
+use crate::presentation::cli::views::Render;
+use crate::presentation::cli::views::UserOutput;
+
+use super::errors::RepairSubcommandError;
+
+/// Steps in the repair workflow
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum RepairStep {
+    ValidateEnvironment,
+    RepairState,
+    DisplayResults,
+}
+
+impl RepairStep {
+    /// All steps in execution order
+    const ALL: &'static [Self] = &[
+        Self::ValidateEnvironment,
+        Self::RepairState,
+        Self::DisplayResults,
+    ];
+
+    /// Total number of steps
+    const fn count() -> usize {
+        Self::ALL.len()
+    }
+
+    /// User-facing description for the step
+    fn description(self, list_only: bool) -> &'static str {
+        match self {
+            Self::ValidateEnvironment => "Validating environment",
+            Self::RepairState if list_only => "Checking environment state",
+            Self::RepairState => "Restoring environment state from backup",
+            Self::DisplayResults => "Displaying results",
+        }
+    }
+}
+
+/// Presentation layer controller for the repair command workflow
+///
+/// Validates the environment name, reports progress and delegates to the
+/// application layer `RepairCommandHandler`.
+pub struct RepairCommandController {
+    repository: Arc<dyn EnvironmentRepository + Send + Sync>,
+    progress: ProgressReporter,
+}
+
+impl RepairCommandController {
+    /// Create a new repair command controller
+    #[allow(clippy::needless_pass_by_value)] // Constructor takes ownership of Arc parameters
+    pub fn new(
+        repository: Arc<dyn EnvironmentRepository + Send + Sync>,
+        user_output: Arc<ReentrantMutex<RefCell<UserOutput>>>,
+    ) -> Self {
+        let progress = ProgressReporter::new(user_output, RepairStep::count());
+
+        Self {
+            repository,
+            progress,
+        }
+    }
+
+    /// Execute the complete repair workflow
+    ///
+    /// # Arguments
+    ///
+    /// * `environment_name` - Name of the environment to repair
+    /// * `backup_id` - Backup to restore, `None` for the latest backup
+    /// * `list_only` - Only report the state and the backups, restore nothing
+    /// * `output_format` - Output format (Text or Json)
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if:
+    /// - Environment name is invalid (format validation fails)
+    /// - Neither the state nor a backup of the environment exists
+    /// - The state loads and no backup was selected (unless only listing)
+    /// - The selected backup does not exist or cannot be restored
+    pub fn execute(
+        &mut self,
+        environment_name: &str,
+        backup_id: Option<&str>,
+        list_only: bool,
+        output_format: OutputFormat,
+    ) -> Result<(), RepairSubcommandError> {
+        self.progress.set_output_format(output_format);
+        let result = self.execute_steps(environment_name, backup_id, list_only, output_format);
+        self.progress.finish(result)
+    }
+
+    /// Steps of [`Self::execute`], before the timing summary
+    fn execute_steps(
+        &mut self,
+        environment_name: &str,
+        backup_id: Option<&str>,
+        list_only: bool,
+        output_format: OutputFormat,
+    ) -> Result<(), RepairSubcommandError> {
+        let env_name = self.validate_environment_name(environment_name)?;
+
+        let report = self.repair_state(&env_name, backup_id, list_only)?;
+
+        self.display_results(&report, output_format)?;
+
+        Ok(())
+    }
+
+    /// Validate the environment name format
+    fn validate_environment_name(
+        &mut self,
+        name: &str,
+    ) -> Result<EnvironmentName, RepairSubcommandError> {
+        self.progress
+            .start_step(RepairStep::ValidateEnvironment.description(false))?;
+
+        let env_name = EnvironmentName::new(name.to_string()).map_err(|source| {
+            RepairSubcommandError::InvalidEnvironmentName {
+                name: name.to_string(),
+                source,
+            }
+        })?;
+
+        self.progress
+            .complete_step(Some(&format!("Environment name validated: {name}")))?;
+
+        Ok(env_name)
+    }
+
+    /// Inspect the state, or restore a backup, through the application layer handler
+    fn repair_state(
+        &mut self,
+        env_name: &EnvironmentName,
+        backup_id: Option<&str>,
+        list_only: bool,
+    ) -> Result<RepairReport, RepairSubcommandError> {
+        self.progress
+            .start_step(RepairStep::RepairState.description(list_only))?;
+
+        let handler = RepairCommandHandler::new(self.repository.clone());
+
+        let report = if list_only {
+            handler.inspect(env_name)
+        } else {
+            handler.execute(env_name, backup_id)
+        }
+        .map_err(|source| RepairSubcommandError::ApplicationLayerError { source })?;
+
+        let summary = match &report.restored {
+            Some(backup) => format!(
+                "Restored backup {} ({} state)",
+                backup.id, backup.state_name
+            ),
+            None => format!("Found {} state backup(s)", report.backups.len()),
+        };
+        self.progress.complete_step(Some(&summary))?;
+
+        Ok(report)
+    }
+
+    /// Display the report on stdout
+    fn display_results(
+        &mut self,
+        report: &RepairReport,
+        output_format: OutputFormat,
+    ) -> Result<(), RepairSubcommandError> {
+        self.progress
+            .start_step(RepairStep::DisplayResults.description(false))?;
+
+        let output = match output_format {
+            OutputFormat::Text => TextView::render(report)?,
+            OutputFormat::Json => JsonView::render(report)?,
+        };
+
+        self.progress.result(&output)?;
+
+        self.progress.complete_step(Some("Results displayed"))?;
+
+        Ok(())
+    }
+}
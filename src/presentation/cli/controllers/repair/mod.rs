@@ -0,0 +1,20 @@
+//! Repair Command Presentation Module
+//!
+//! This module implements the CLI presentation layer for the repair command,
+//! which restores the persisted state of an environment from one of its
+//! state backups.
+//!
+//! ## Components
+//!
+//! - `errors` - Presentation layer error types with `.help()` methods
+//! - `handler` - Main command handler orchestrating the workflow
+
+pub mod errors;
+pub mod handler;
+pub use handler::RepairCommandController;
+
+#[cfg(test)]
+mod tests;
+
+// Re-export commonly used types for convenience
+pub use errors::RepairSubcommandError;
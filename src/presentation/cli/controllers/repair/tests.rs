@@ -0,0 +1,70 @@
+//! Tests for the Repair Command Controller
+
+use std::cell::RefCell;
+use std::sync::Arc;
+
+use parking_lot::ReentrantMutex;
+use tempfile::TempDir;
+
+use crate::application::command_handlers::repair::RepairCommandHandlerError;
+use crate::domain::environment::repository::EnvironmentRepository;
+use crate::infrastructure::persistence::file_repository_factory::FileRepositoryFactory;
+use crate::presentation::cli::controllers::constants::DEFAULT_LOCK_TIMEOUT;
+use crate::presentation::cli::controllers::repair::errors::RepairSubcommandError;
+use crate::presentation::cli::controllers::repair::handler::RepairCommandController;
+use crate::presentation::cli::input::cli::OutputFormat;
+use crate::presentation::cli::views::testing::TestUserOutput;
+use crate::presentation::cli::views::{UserOutput, VerbosityLevel};
+
+/// Create test dependencies for repair command controller tests
+fn create_test_dependencies(
+    temp_dir: &TempDir,
+) -> (
+    Arc<ReentrantMutex<RefCell<UserOutput>>>,
+    Arc<dyn EnvironmentRepository + Send + Sync>,
+) {
+    let (user_output, _, _) = TestUserOutput::new(VerbosityLevel::Normal).into_reentrant_wrapped();
+    let data_dir = temp_dir.path().join("data");
+    let file_repository_factory = FileRepositoryFactory::new(DEFAULT_LOCK_TIMEOUT);
+    let repository = file_repository_factory.create(data_dir);
+
+    (user_output, repository)
+}
+
+#[test]
+fn it_should_reject_an_invalid_environment_name() {
+    let temp_dir = TempDir::new().unwrap();
+    let (user_output, repository) = create_test_dependencies(&temp_dir);
+
+    let result = RepairCommandController::new(repository, user_output).execute(
+        "invalid_name",
+        None,
+        false,
+        OutputFormat::Text,
+    );
+
+    assert!(matches!(
+        result,
+        Err(RepairSubcommandError::InvalidEnvironmentName { .. })
+    ));
+}
+
+#[test]
+fn it_should_fail_when_the_environment_does_not_exist() {
+    let temp_dir = TempDir::new().unwrap();
+    let (user_output, repository) = create_test_dependencies(&temp_dir);
+
+    let result = RepairCommandController::new(repository, user_output).execute(
+        "test-env",
+        None,
+        true,
+        OutputFormat::Text,
+    );
+
+    assert!(matches!(
+        result,
+        Err(RepairSubcommandError::ApplicationLayerError {
+            source: RepairCommandHandlerError::EnvironmentNotFound { .. }
+        })
+    ));
+}
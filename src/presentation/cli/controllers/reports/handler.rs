@@ -0,0 +1,169 @@
+//! Reports Command Handler
+//!
+//! This module handles the reports command execution at the presentation layer,
+//! listing the deployment reports of an environment or displaying one of them.
+
+use std::cell::RefCell;
+use std::sync::Arc;
+
+use parking_lot::ReentrantMutex;
+
+use crate::application::command_handlers::reports::ReportsCommandHandler;
+use crate::domain::environment::name::EnvironmentName;
+use crate::presentation::cli::input::cli::output_format::OutputFormat;
+use crate::presentation::cli::views::commands::reports::{
+    JsonView, ReportDetailsData, ReportListData, TextView,
+};
+use crate::presentation::cli::views::progress::ProgressReporter;
+use crate::presentation::cli::views::Render;
+use crate::presentation::cli::views::UserOutput;
+
+use super::errors::ReportsSubcommandError;
+
+/// Which reports to display
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ReportSelection {
+    /// List all reports
+    All,
+    /// Display the most recent report
+    Latest,
+    /// Display the report with the given file name
+    File(String),
+}
+
+/// Steps in the reports workflow
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum ReportsStep {
+    ValidateEnvironment,
+    ReadReports,
+}
+
+impl ReportsStep {
+    /// All steps in execution order
+    const ALL: &'static [Self] = &[Self::ValidateEnvironment, Self::ReadReports];
+
+    /// Total number of steps
+    const fn count() -> usize {
+        Self::ALL.len()
+    }
+
+    /// User-facing description for the step
+    fn description(self) -> &'static str {
+        match self {
+            Self::ValidateEnvironment => "Validating environment",
+            Self::ReadReports => "Reading deployment reports",
+        }
+    }
+}
+
+/// Presentation layer controller for reports command workflow
+pub struct ReportsCommandController {
+    handler: ReportsCommandHandler,
+    progress: ProgressReporter,
+}
+
+impl ReportsCommandController {
+    /// Create a new `ReportsCommandController` with dependencies
+    #[allow(clippy::needless_pass_by_value)] // Arc parameters are moved to constructor for ownership
+    pub fn new(
+        handler: ReportsCommandHandler,
+        user_output: Arc<ReentrantMutex<RefCell<UserOutput>>>,
+    ) -> Self {
+        let progress = ProgressReporter::new(user_output, ReportsStep::count());
+
+        Self { handler, progress }
+    }
+
+    /// Execute the reports command workflow
+    ///
+    /// # Arguments
+    ///
+    /// * `environment_name` - Name of the environment
+    /// * `selection` - List all reports or display a single one
+    /// * `output_format` - Output format (Text or Json)
+    ///
+    /// # Errors
+    ///
+    /// Returns `ReportsSubcommandError` if the name is invalid or the reports
+    /// cannot be read
+    pub fn execute(
+        &mut self,
+        environment_name: &str,
+        selection: &ReportSelection,
+        output_format: OutputFormat,
+    ) -> Result<(), ReportsSubcommandError> {
+        self.progress.set_output_format(output_format);
+        let result = self.execute_steps(environment_name, selection, output_format);
+        self.progress.finish(result)
+    }
+
+    /// Steps of [`Self::execute`], before the timing summary
+    fn execute_steps(
+        &mut self,
+        environment_name: &str,
+        selection: &ReportSelection,
+        output_format: OutputFormat,
+    ) -> Result<(), ReportsSubcommandError> {
+        let env_name = self.validate_environment_name(environment_name)?;
+
+        self.progress
+            .start_step(ReportsStep::ReadReports.description())?;
+
+        let read_failed = |source| ReportsSubcommandError::ReadFailed {
+            name: environment_name.to_string(),
+            source,
+        };
+
+        let output = match selection {
+            ReportSelection::All => {
+                let reports = self.handler.list(&env_name).map_err(read_failed)?;
+                let data = ReportListData::new(environment_name, &reports);
+                match output_format {
+                    OutputFormat::Text => TextView::render(&data)?,
+                    OutputFormat::Json => JsonView::render(&data)?,
+                }
+            }
+            ReportSelection::Latest | ReportSelection::File(_) => {
+                let file_name = match selection {
+                    ReportSelection::File(file_name) => Some(file_name.as_str()),
+                    _ => None,
+                };
+                let report = self
+                    .handler
+                    .show(&env_name, file_name)
+                    .map_err(read_failed)?;
+                let data = ReportDetailsData::from(report);
+                match output_format {
+                    OutputFormat::Text => TextView::render(&data)?,
+                    OutputFormat::Json => JsonView::render(&data)?,
+                }
+            }
+        };
+
+        self.progress.complete_step(None)?;
+
+        self.progress.result(&output)?;
+
+        Ok(())
+    }
+
+    /// Validate the environment name format
+    fn validate_environment_name(
+        &mut self,
+        name: &str,
+    ) -> Result<EnvironmentName, ReportsSubcommandError> {
+        self.progress
+            .start_step(ReportsStep::ValidateEnvironment.description())?;
+
+        let env_name = EnvironmentName::new(name.to_string()).map_err(|source| {
+            ReportsSubcommandError::InvalidEnvironmentName {
+                name: name.to_string(),
+                source,
+            }
+        })?;
+
+        self.progress.complete_step(None)?;
+
+        Ok(env_name)
+    }
+}
@@ -0,0 +1,17 @@
+//! Reports Command Presentation Module
+//!
+//! This module implements the CLI presentation layer for the reports command,
+//! which lists the deployment reports of an environment or displays one of
+//! them (`--latest` or `--show <FILE>`).
+//!
+//! ## Components
+//!
+//! - `errors` - Presentation layer error types with `.help()` methods
+//! - `handler` - Main command handler orchestrating the workflow
+
+pub mod errors;
+pub mod handler;
+pub use handler::{ReportSelection, ReportsCommandController};
+
+// Re-export commonly used types for convenience
+pub use errors::ReportsSubcommandError;
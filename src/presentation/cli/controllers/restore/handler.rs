@@ -0,0 +1,138 @@
+//! Restore Command Handler
+//!
+//! This module handles the restore command execution at the presentation layer,
+//! restoring the tracker database of an environment from one of its backups.
+
+use std::cell::RefCell;
+use std::sync::Arc;
+
+use parking_lot::ReentrantMutex;
+
+use crate::application::command_handlers::restore::RestoreCommandHandler;
+use crate::domain::environment::name::EnvironmentName;
+use crate::presentation::cli::input::cli::output_format::OutputFormat;
+use crate::presentation::cli::views::commands::restore::{JsonView, RestoreData, TextView};
+use crate::presentation::cli::views::progress::ProgressReporter;
+use crate::presentation::cli::views::Render;
+use crate::presentation::cli::views::UserOutput;
+
+use super::errors::RestoreSubcommandError;
+
+/// Steps in the restore workflow
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum RestoreStep {
+    ValidateEnvironment,
+    RestoreDatabase,
+}
+
+impl RestoreStep {
+    /// All steps in execution order
+    const ALL: &'static [Self] = &[Self::ValidateEnvironment, Self::RestoreDatabase];
+
+    /// Total number of steps
+    const fn count() -> usize {
+        Self::ALL.len()
+    }
+
+    /// User-facing description for the step
+    fn description(self) -> &'static str {
+        match self {
+            Self::ValidateEnvironment => "Validating environment",
+            Self::RestoreDatabase => "Restoring the tracker database",
+        }
+    }
+}
+
+/// Presentation layer controller for restore command workflow
+pub struct RestoreCommandController {
+    handler: RestoreCommandHandler,
+    progress: ProgressReporter,
+}
+
+impl RestoreCommandController {
+    /// Create a new `RestoreCommandController` with dependencies
+    #[allow(clippy::needless_pass_by_value)] // Arc parameters are moved to constructor for ownership
+    pub fn new(
+        handler: RestoreCommandHandler,
+        user_output: Arc<ReentrantMutex<RefCell<UserOutput>>>,
+    ) -> Self {
+        let progress = ProgressReporter::new(user_output, RestoreStep::count());
+
+        Self { handler, progress }
+    }
+
+    /// Execute the restore command workflow
+    ///
+    /// # Arguments
+    ///
+    /// * `environment_name` - Name of the environment
+    /// * `from` - Id of the backup to restore
+    /// * `output_format` - Output format (Text or Json)
+    ///
+    /// # Errors
+    ///
+    /// Returns `RestoreSubcommandError` if the name is invalid or the restore fails
+    pub fn execute(
+        &mut self,
+        environment_name: &str,
+        from: &str,
+        output_format: OutputFormat,
+    ) -> Result<(), RestoreSubcommandError> {
+        self.progress.set_output_format(output_format);
+        let result = self.execute_steps(environment_name, from, output_format);
+        self.progress.finish(result)
+    }
+
+    /// Steps of [`Self::execute`], before the timing summary
+    fn execute_steps(
+        &mut self,
+        environment_name: &str,
+        from: &str,
+        output_format: OutputFormat,
+    ) -> Result<(), RestoreSubcommandError> {
+        let env_name = self.validate_environment_name(environment_name)?;
+
+        self.progress
+            .start_step(RestoreStep::RestoreDatabase.description())?;
+
+        let backup = self.handler.execute(&env_name, from).map_err(|source| {
+            RestoreSubcommandError::RestoreFailed {
+                name: environment_name.to_string(),
+                source: Box::new(source),
+            }
+        })?;
+
+        self.progress
+            .complete_step(Some(&format!("Backup {} restored", backup.id)))?;
+
+        let data = RestoreData::new(environment_name, &backup);
+        let output = match output_format {
+            OutputFormat::Text => TextView::render(&data)?,
+            OutputFormat::Json => JsonView::render(&data)?,
+        };
+
+        self.progress.result(&output)?;
+
+        Ok(())
+    }
+
+    /// Validate the environment name format
+    fn validate_environment_name(
+        &mut self,
+        name: &str,
+    ) -> Result<EnvironmentName, RestoreSubcommandError> {
+        self.progress
+            .start_step(RestoreStep::ValidateEnvironment.description())?;
+
+        let env_name = EnvironmentName::new(name.to_string()).map_err(|source| {
+            RestoreSubcommandError::InvalidEnvironmentName {
+                name: name.to_string(),
+                source,
+            }
+        })?;
+
+        self.progress.complete_step(None)?;
+
+        Ok(env_name)
+    }
+}
@@ -0,0 +1,17 @@
+//! Restore Command Presentation Module
+//!
+//! This module implements the CLI presentation layer for the restore command,
+//! which restores the tracker database of a released or running environment
+//! from a backup taken by the backup command.
+//!
+//! ## Components
+//!
+//! - `errors` - Presentation layer error types with `.help()` methods
+//! - `handler` - Main command handler orchestrating the workflow
+
+pub mod errors;
+pub mod handler;
+pub use handler::RestoreCommandController;
+
+// Re-export commonly used types for convenience
+pub use errors::RestoreSubcommandError;
@@ -0,0 +1,235 @@
+//! Rollback Command Handler
+//!
+//! This module handles the rollback command execution at the presentation
+//! layer, including environment name validation and user interaction.
+
+use std::cell::RefCell;
+use std::path::PathBuf;
+use std::sync::Arc;
+
+use parking_lot::ReentrantMutex;
+use torrust_tracker_deployer_dependency_installer::Dependency;
+use tracing::info;
+
+use crate::application::command_handlers::rollback::RollbackCommandHandler;
+use crate::application::services::deployment_report::DeploymentReportListener;
+use crate::domain::environment::name::EnvironmentName;
+use crate::domain::environment::repository::EnvironmentRepository;
+use crate::domain::environment::state::Released;
+use crate::domain::environment::{DiskSpaceThresholds, Environment};
+use crate::presentation::cli::input::cli::OutputFormat;
+use crate::presentation::cli::views::commands::release::{JsonView, ReleaseDetailsData, TextView};
+use crate::presentation::cli::views::progress::{ProgressReporter, VerboseProgressListener};
+use crate::presentation::cli::views::Render;
+use crate::presentation::cli::views::UserOutput;
+use crate::shared::clock::Clock;
+use crate::shared::command::run_log::DEFAULT_KEPT_RUNS;
+
+use super::errors::RollbackSubcommandError;
+
+/// Steps in the rollback workflow
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum RollbackStep {
+    ValidateEnvironment,
+    RollbackRelease,
+}
+
+impl RollbackStep {
+    /// All steps in execution order
+    const ALL: &'static [Self] = &[Self::ValidateEnvironment, Self::RollbackRelease];
+
+    /// Total number of steps
+    const fn count() -> usize {
+        Self::ALL.len()
+    }
+
+    /// User-facing description for the step
+    fn description(self) -> &'static str {
+        match self {
+            Self::ValidateEnvironment => "Validating environment",
+            Self::RollbackRelease => "Rolling back to the previous release",
+        }
+    }
+}
+
+/// Presentation layer controller for the rollback command workflow
+///
+/// Validates the environment name, reports progress and delegates to the
+/// application layer `RollbackCommandHandler`.
+pub struct RollbackCommandController {
+    repository: Arc<dyn EnvironmentRepository + Send + Sync>,
+    clock: Arc<dyn Clock>,
+    progress: ProgressReporter,
+    kept_run_logs: usize,
+    trace_dir: Option<PathBuf>,
+    disk_space_thresholds: DiskSpaceThresholds,
+}
+
+impl RollbackCommandController {
+    /// Create a new rollback command controller
+    #[allow(clippy::needless_pass_by_value)] // Constructor takes ownership of Arc parameters
+    pub fn new(
+        repository: Arc<dyn EnvironmentRepository + Send + Sync>,
+        clock: Arc<dyn Clock>,
+        user_output: Arc<ReentrantMutex<RefCell<UserOutput>>>,
+    ) -> Self {
+        let progress = ProgressReporter::new(user_output, RollbackStep::count());
+
+        Self {
+            repository,
+            clock,
+            progress,
+            kept_run_logs: DEFAULT_KEPT_RUNS,
+            trace_dir: None,
+            disk_space_thresholds: DiskSpaceThresholds::default(),
+        }
+    }
+
+    /// Keep the Ansible output logs of the last `runs` runs of the command
+    #[must_use]
+    pub fn with_kept_run_logs(mut self, runs: usize) -> Self {
+        self.kept_run_logs = runs;
+        self
+    }
+
+    /// Write the trace files of a failure to `trace_dir` (`--trace-dir`)
+    #[must_use]
+    pub fn with_trace_dir(mut self, trace_dir: Option<PathBuf>) -> Self {
+        self.trace_dir = trace_dir;
+        self
+    }
+
+    /// Require the given free disk space on the instance before releasing
+    #[must_use]
+    pub fn with_disk_space_thresholds(mut self, thresholds: DiskSpaceThresholds) -> Self {
+        self.disk_space_thresholds = thresholds;
+        self
+    }
+
+    /// Execute the complete rollback workflow
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if:
+    /// - Environment name is invalid (format validation fails)
+    /// - Environment is not in the Released or Running state
+    /// - The environment has no previous release
+    /// - Redeploying the previous release fails
+    #[allow(clippy::result_large_err)]
+    pub async fn execute(
+        &mut self,
+        environment_name: &str,
+        output_format: OutputFormat,
+    ) -> Result<(), RollbackSubcommandError> {
+        self.progress.set_output_format(output_format);
+        let result = self.execute_steps(environment_name, output_format).await;
+        self.progress.finish(result)
+    }
+
+    /// Steps of [`Self::execute`], before the timing summary
+    #[allow(clippy::result_large_err)]
+    async fn execute_steps(
+        &mut self,
+        environment_name: &str,
+        output_format: OutputFormat,
+    ) -> Result<(), RollbackSubcommandError> {
+        let env_name = self.validate_environment_name(environment_name)?;
+
+        let released_env = self.rollback_release(&env_name).await?;
+
+        self.complete_workflow(&released_env, output_format)?;
+
+        Ok(())
+    }
+
+    /// Validate the environment name format
+    #[allow(clippy::result_large_err)]
+    fn validate_environment_name(
+        &mut self,
+        name: &str,
+    ) -> Result<EnvironmentName, RollbackSubcommandError> {
+        self.progress
+            .start_step(RollbackStep::ValidateEnvironment.description())?;
+
+        let env_name = EnvironmentName::new(name.to_string()).map_err(|source| {
+            RollbackSubcommandError::InvalidEnvironmentName {
+                name: name.to_string(),
+                source,
+            }
+        })?;
+
+        self.progress
+            .complete_step(Some(&format!("Environment name validated: {name}")))?;
+
+        Ok(env_name)
+    }
+
+    /// Redeploy the previous release through the application layer handler
+    #[allow(clippy::result_large_err)]
+    async fn rollback_release(
+        &mut self,
+        env_name: &EnvironmentName,
+    ) -> Result<Environment<Released>, RollbackSubcommandError> {
+        self.progress
+            .start_step(RollbackStep::RollbackRelease.description())?;
+
+        let handler = RollbackCommandHandler::new(self.repository.clone(), self.clock.clone())
+            .with_kept_run_logs(self.kept_run_logs)
+            .with_trace_dir(self.trace_dir.clone())
+            .with_disk_space_thresholds(self.disk_space_thresholds);
+
+        let listener = VerboseProgressListener::new(self.progress.output().clone());
+        let recorder = DeploymentReportListener::new(
+            "rollback",
+            env_name,
+            self.clock.clone(),
+            Some(&listener),
+        );
+
+        let result = handler.execute(env_name, Some(&recorder)).await;
+        let report = recorder.write(
+            self.repository.as_ref(),
+            &[Dependency::Ansible],
+            result.as_ref().err(),
+        );
+        if let Some(trace_file) = report.and_then(|report| report.trace_file) {
+            self.progress
+                .warn(&format!("Trace file: {}", trace_file.display()))?;
+        }
+
+        let released_env =
+            result.map_err(|source| RollbackSubcommandError::ApplicationLayerError { source })?;
+
+        info!(
+            environment = %env_name,
+            final_state = "Released",
+            "Previous release redeployed successfully"
+        );
+
+        self.progress.complete_step(Some(&format!(
+            "Tracker {} redeployed, run 'run {env_name}' to start the services",
+            released_env.tracker_config().effective_version()
+        )))?;
+
+        Ok(released_env)
+    }
+
+    /// Render the released environment details
+    #[allow(clippy::result_large_err)]
+    fn complete_workflow(
+        &mut self,
+        released_env: &Environment<Released>,
+        output_format: OutputFormat,
+    ) -> Result<(), RollbackSubcommandError> {
+        let details = ReleaseDetailsData::from(released_env);
+
+        let output = match output_format {
+            OutputFormat::Text => TextView::render(&details)?,
+            OutputFormat::Json => JsonView::render(&details)?,
+        };
+
+        self.progress.result(&output)?;
+
+        Ok(())
+    }
+}
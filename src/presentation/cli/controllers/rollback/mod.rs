@@ -0,0 +1,21 @@
+//! Rollback Command Presentation Module
+//!
+//! This module implements the CLI presentation layer for the rollback command,
+//! which redeploys the tracker version of the previous release.
+//!
+//! ## Components
+//!
+//! - `errors` - Presentation layer error types with `.help()` methods
+//! - `handler` - Main command handler orchestrating the workflow
+//!
+//! The released environment is displayed with the release command views.
+
+pub mod errors;
+pub mod handler;
+pub use handler::RollbackCommandController;
+
+#[cfg(test)]
+mod tests;
+
+// Re-export commonly used types for convenience
+pub use errors::RollbackSubcommandError;
@@ -7,6 +7,7 @@
 use thiserror::Error;
 
 use crate::application::command_handlers::run::RunCommandHandlerError;
+use crate::application::errors::AllowedCommands;
 use crate::domain::environment::name::EnvironmentNameError;
 use crate::domain::environment::repository::RepositoryError;
 use crate::presentation::cli::views::progress::ProgressReporterError;
@@ -49,9 +50,13 @@ Tip: Check if environment exists: ls -la {data_dir}/"
     /// Use `.help()` for detailed troubleshooting steps.
     #[error(
         "Environment '{name}' is not in the required state for run (current: {current_state}, required: Released)
-Tip: Run 'release' command first to deploy the application"
+Tip: environment is '{current_state}'; {allowed_commands}"
     )]
-    InvalidEnvironmentState { name: String, current_state: String },
+    InvalidEnvironmentState {
+        name: String,
+        current_state: String,
+        allowed_commands: AllowedCommands,
+    },
 
     // ===== Run Operation Errors =====
     /// Run operation failed
@@ -123,6 +128,15 @@ impl From<RepositoryError> for RunSubcommandError {
                 name: "environment".to_string(),
                 reason: "Another process is accessing this environment".to_string(),
             },
+            RepositoryError::Corrupted { reason } => Self::RunOperationFailed {
+                name: "environment".to_string(),
+                reason: format!("Environment state is corrupted: {reason}"),
+            },
+            error @ (RepositoryError::UnsupportedVersion { .. }
+            | RepositoryError::MissingSecret { .. }) => Self::RunOperationFailed {
+                name: "environment".to_string(),
+                reason: error.to_string(),
+            },
             RepositoryError::Internal(err) => Self::RunOperationFailed {
                 name: "environment".to_string(),
                 reason: format!("Repository error: {err}"),
@@ -142,7 +156,8 @@ impl From<RunCommandHandlerError> for RunSubcommandError {
             }
             RunCommandHandlerError::InvalidState(state_err) => Self::InvalidEnvironmentState {
                 name: "environment".to_string(),
-                current_state: state_err.to_string(),
+                current_state: state_err.actual,
+                allowed_commands: state_err.allowed_commands,
             },
             RunCommandHandlerError::MissingInstanceIp { name } => Self::RunOperationFailed {
                 name,
@@ -155,6 +170,18 @@ impl From<RunCommandHandlerError> for RunSubcommandError {
                     reason: message,
                 }
             }
+            RunCommandHandlerError::HealthCheckFailed { source } => Self::ServiceStartFailed {
+                name: "environment".to_string(),
+                reason: source.to_string(),
+            },
+            RunCommandHandlerError::HostKeyCheck(err) => Self::RunOperationFailed {
+                name: "environment".to_string(),
+                reason: err.to_string(),
+            },
+            error @ RunCommandHandlerError::Interrupted => Self::RunOperationFailed {
+                name: "environment".to_string(),
+                reason: error.to_string(),
+            },
             RunCommandHandlerError::StatePersistence(err) => Self::RunOperationFailed {
                 name: "environment".to_string(),
                 reason: format!("Failed to persist state: {err}"),
@@ -351,6 +378,7 @@ Please report it to the development team with full details."
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::application::errors::InvalidStateError;
 
     #[test]
     fn it_should_provide_help_for_invalid_environment_name() {
@@ -385,6 +413,7 @@ mod tests {
         let error = RunSubcommandError::InvalidEnvironmentState {
             name: "test-env".to_string(),
             current_state: "Configured".to_string(),
+            allowed_commands: AllowedCommands::default(),
         };
 
         let help = error.help();
@@ -392,6 +421,27 @@ mod tests {
         assert!(help.contains("release"));
     }
 
+    #[test]
+    fn it_should_list_the_valid_commands_when_the_state_is_invalid() {
+        let error =
+            RunSubcommandError::from(RunCommandHandlerError::InvalidState(InvalidStateError {
+                expected: "released".to_string(),
+                actual: "provisioned".to_string(),
+                allowed_commands: AllowedCommands {
+                    valid: vec![
+                        "configure".to_string(),
+                        "destroy".to_string(),
+                        "purge".to_string(),
+                    ],
+                    suggested_next: Some("configure".to_string()),
+                },
+            }));
+
+        assert!(error.to_string().contains(
+            "environment is 'provisioned'; valid commands: configure, destroy, purge; suggested next: configure"
+        ));
+    }
+
     #[test]
     fn it_should_provide_help_for_service_start_failed() {
         let error = RunSubcommandError::ServiceStartFailed {
@@ -422,6 +472,7 @@ mod tests {
             RunSubcommandError::InvalidEnvironmentState {
                 name: "test".to_string(),
                 current_state: "Created".to_string(),
+                allowed_commands: AllowedCommands::default(),
             },
             RunSubcommandError::RunOperationFailed {
                 name: "test".to_string(),
@@ -4,12 +4,16 @@
 //! including environment validation, state validation, and user interaction.
 
 use std::cell::RefCell;
+use std::path::PathBuf;
 use std::sync::Arc;
 
 use parking_lot::ReentrantMutex;
+use tokio_util::sync::CancellationToken;
+use torrust_tracker_deployer_dependency_installer::Dependency;
 
 use crate::application::command_handlers::run::RunCommandHandler;
 use crate::application::command_handlers::show::info::{GrafanaInfo, ServiceInfo};
+use crate::application::services::deployment_report::DeploymentReportListener;
 use crate::domain::environment::name::EnvironmentName;
 use crate::domain::environment::repository::EnvironmentRepository;
 use crate::domain::environment::state::AnyEnvironmentState;
@@ -19,6 +23,7 @@ use crate::presentation::cli::views::progress::ProgressReporter;
 use crate::presentation::cli::views::Render;
 use crate::presentation::cli::views::UserOutput;
 use crate::shared::clock::Clock;
+use crate::shared::command::run_log::DEFAULT_KEPT_RUNS;
 
 use super::errors::RunSubcommandError;
 
@@ -69,6 +74,10 @@ pub struct RunCommandController {
     repository: Arc<dyn EnvironmentRepository + Send + Sync>,
     clock: Arc<dyn Clock>,
     progress: ProgressReporter,
+    wait_for_health: bool,
+    kept_run_logs: usize,
+    trace_dir: Option<PathBuf>,
+    cancellation: CancellationToken,
 }
 
 impl RunCommandController {
@@ -88,9 +97,41 @@ impl RunCommandController {
             repository,
             clock,
             progress,
+            wait_for_health: true,
+            kept_run_logs: DEFAULT_KEPT_RUNS,
+            trace_dir: None,
+            cancellation: CancellationToken::new(),
         }
     }
 
+    /// Cancel the workflow when the given token is cancelled (e.g. on Ctrl-C)
+    #[must_use]
+    pub fn with_cancellation(mut self, cancellation: CancellationToken) -> Self {
+        self.cancellation = cancellation;
+        self
+    }
+
+    /// Keep the Ansible output logs of the last `runs` runs of the command
+    #[must_use]
+    pub fn with_kept_run_logs(mut self, runs: usize) -> Self {
+        self.kept_run_logs = runs;
+        self
+    }
+
+    /// Write the trace files of a failure to `trace_dir` (`--trace-dir`)
+    #[must_use]
+    pub fn with_trace_dir(mut self, trace_dir: Option<PathBuf>) -> Self {
+        self.trace_dir = trace_dir;
+        self
+    }
+
+    /// Wait for the tracker to report healthy before finishing (the default)
+    #[must_use]
+    pub fn with_wait_for_health(mut self, wait_for_health: bool) -> Self {
+        self.wait_for_health = wait_for_health;
+        self
+    }
+
     /// Execute the complete run workflow
     ///
     /// Orchestrates all steps of the run command:
@@ -119,6 +160,19 @@ impl RunCommandController {
         &mut self,
         environment_name: &str,
         output_format: OutputFormat,
+    ) -> Result<(), RunSubcommandError> {
+        self.progress.set_output_format(output_format);
+        let result = self.execute_steps(environment_name, output_format).await;
+        self.progress.finish(result)
+    }
+
+    /// Steps of [`Self::execute`], before the timing summary
+    #[allow(clippy::result_large_err)]
+    #[allow(clippy::unused_async)] // Part of uniform async presentation layer interface
+    async fn execute_steps(
+        &mut self,
+        environment_name: &str,
+        output_format: OutputFormat,
     ) -> Result<(), RunSubcommandError> {
         let env_name = self.validate_environment_name(environment_name)?;
 
@@ -171,9 +225,24 @@ impl RunCommandController {
             Arc::clone(&self.repository)
                 as Arc<dyn crate::domain::environment::repository::EnvironmentRepository>;
 
-        let handler = RunCommandHandler::new(repository, Arc::clone(&self.clock));
-
-        handler.execute(env_name)?;
+        let handler = RunCommandHandler::new(repository, Arc::clone(&self.clock))
+            .with_kept_run_logs(self.kept_run_logs)
+            .with_trace_dir(self.trace_dir.clone())
+            .with_wait_for_health(self.wait_for_health)
+            .with_cancellation(self.cancellation.clone());
+
+        let recorder = DeploymentReportListener::new("run", env_name, self.clock.clone(), None);
+        let result = handler.execute(env_name);
+        let report = recorder.write(
+            self.repository.as_ref(),
+            &[Dependency::Ansible],
+            result.as_ref().err(),
+        );
+        if let Some(trace_file) = report.and_then(|report| report.trace_file) {
+            self.progress
+                .warn(&format!("Trace file: {}", trace_file.display()))?;
+        }
+        result?;
 
         self.progress.complete_step(Some("Services started"))?;
 
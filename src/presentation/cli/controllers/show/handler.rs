@@ -90,6 +90,13 @@ impl ShowCommandController {
         Self { handler, progress }
     }
 
+    /// Also display the secrets of the environment, in plaintext
+    #[must_use]
+    pub fn with_reveal_secrets(mut self, reveal_secrets: bool) -> Self {
+        self.handler = self.handler.with_reveal_secrets(reveal_secrets);
+        self
+    }
+
     /// Execute the show command workflow
     ///
     /// This method orchestrates the three-step workflow:
@@ -109,6 +116,17 @@ impl ShowCommandController {
         &mut self,
         environment_name: &str,
         output_format: OutputFormat,
+    ) -> Result<(), ShowSubcommandError> {
+        self.progress.set_output_format(output_format);
+        let result = self.execute_steps(environment_name, output_format);
+        self.progress.finish(result)
+    }
+
+    /// Steps of [`Self::execute`], before the timing summary
+    fn execute_steps(
+        &mut self,
+        environment_name: &str,
+        output_format: OutputFormat,
     ) -> Result<(), ShowSubcommandError> {
         // Step 1: Validate environment name
         let env_name = self.validate_environment_name(environment_name)?;
@@ -0,0 +1,171 @@
+//! Error types for the SSH Subcommand
+//!
+//! This module defines error types that can occur during CLI ssh command execution.
+//! All errors follow the project's error handling principles by providing clear,
+//! contextual, and actionable error messages with `.help()` methods.
+
+use thiserror::Error;
+
+use crate::domain::environment::name::EnvironmentNameError;
+
+/// SSH command specific errors
+///
+/// This enum contains all error variants specific to the ssh command,
+/// including environment validation, state checks and ssh client launch errors.
+/// Each variant includes relevant context and actionable error messages.
+#[derive(Debug, Error)]
+pub enum SshSubcommandError {
+    // ===== Environment Validation Errors =====
+    /// Environment name validation failed
+    ///
+    /// The provided environment name doesn't meet the validation requirements.
+    /// Use `.help()` for detailed troubleshooting steps.
+    #[error("Invalid environment name '{name}': {source}
+Tip: Environment names must be 1-63 characters, start with letter/digit, contain only letters/digits/hyphens")]
+    InvalidEnvironmentName {
+        name: String,
+        #[source]
+        source: EnvironmentNameError,
+    },
+
+    /// Environment not found
+    #[error(
+        "Environment '{name}' not found
+Tip: Use 'list' to see the available environments"
+    )]
+    EnvironmentNotFound { name: String },
+
+    /// Failed to load the environment state
+    #[error(
+        "Failed to load environment '{name}': {message}
+Tip: Check file permissions and the environment state file"
+    )]
+    LoadFailed { name: String, message: String },
+
+    // ===== State Errors =====
+    /// The environment has no instance to connect to
+    ///
+    /// Environments in `Created` state have not been provisioned yet and
+    /// environments in `Destroyed` state no longer have an instance.
+    #[error(
+        "Cannot open SSH session to environment '{name}' in '{state}' state
+Tip: The environment has no running instance. Provision or register an instance first"
+    )]
+    NoInstance { name: String, state: String },
+
+    /// The environment state does not record an instance IP
+    #[error(
+        "Environment '{name}' in '{state}' state has no instance IP
+Tip: Use 'show {name}' to inspect the environment"
+    )]
+    MissingInstanceIp { name: String, state: String },
+
+    // ===== SSH Client Errors =====
+    /// The system `ssh` client could not be launched
+    #[error(
+        "Failed to launch the ssh client: {source}
+Tip: Make sure the OpenSSH client is installed and available in PATH"
+    )]
+    SshLaunchFailed {
+        #[source]
+        source: std::io::Error,
+    },
+}
+
+impl SshSubcommandError {
+    /// Get detailed troubleshooting guidance for this error
+    ///
+    /// This method provides comprehensive troubleshooting steps that can be
+    /// displayed to users when they need more help resolving the error.
+    #[must_use]
+    pub fn help(&self) -> &'static str {
+        match self {
+            Self::InvalidEnvironmentName { .. } => {
+                "Invalid Environment Name - Detailed Troubleshooting:
+
+1. Check environment name format:
+   - Length: Must be 1-63 characters
+   - Start: Must begin with a letter or digit
+   - Characters: Only letters, digits, and hyphens allowed
+
+2. Valid examples:
+   - 'production'
+   - 'staging-01'
+
+For more information, see environment naming documentation."
+            }
+
+            Self::EnvironmentNotFound { .. } => {
+                "Environment Not Found - Detailed Troubleshooting:
+
+1. List the available environments:
+   torrust-tracker-deployer list
+
+2. Verify you are using the correct working directory:
+   torrust-tracker-deployer ssh <env> --working-dir <path>
+
+Common causes:
+- Typo in environment name
+- Environment was purged
+- Working in the wrong directory"
+            }
+
+            Self::LoadFailed { .. } => {
+                "Environment Load Failed - Detailed Troubleshooting:
+
+1. Check if the environment state file exists:
+   ls data/<env-name>/environment.json
+
+2. Verify the file is valid JSON:
+   cat data/<env-name>/environment.json | jq .
+
+Common causes:
+- Corrupted environment state file
+- File system permissions issues"
+            }
+
+            Self::NoInstance { .. } => {
+                "No Instance Available - Detailed Troubleshooting:
+
+The environment does not have an instance to connect to.
+
+1. Check the current state:
+   torrust-tracker-deployer show <env-name>
+
+2. For 'Created' environments, provision or register an instance first:
+   torrust-tracker-deployer provision <env-name>
+   torrust-tracker-deployer register <env-name> --instance-ip <ip>
+
+3. 'Destroyed' environments no longer have an instance. Create a new
+   environment to deploy again."
+            }
+
+            Self::MissingInstanceIp { .. } => {
+                "Missing Instance IP - Detailed Troubleshooting:
+
+The environment state does not record the instance IP address. This usually
+happens when provisioning failed before the instance was created.
+
+1. Check the current state and failure details:
+   torrust-tracker-deployer show <env-name>
+
+2. Review the trace files of the failed command:
+   ls data/<env-name>/traces/
+
+3. Destroy and provision the environment again if needed."
+            }
+
+            Self::SshLaunchFailed { .. } => {
+                "SSH Client Launch Failed - Detailed Troubleshooting:
+
+1. Verify the OpenSSH client is installed:
+   which ssh
+
+2. Install it if missing (Debian/Ubuntu):
+   sudo apt-get install openssh-client
+
+3. Ensure the ssh binary is in your PATH"
+            }
+        }
+    }
+}
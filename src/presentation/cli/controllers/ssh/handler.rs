@@ -0,0 +1,296 @@
+//! SSH Command Handler
+//!
+//! This module handles the ssh command execution at the presentation layer,
+//! opening an SSH session to the instance of an environment with the stored
+//! credentials.
+
+use std::net::SocketAddr;
+use std::process::Command;
+use std::sync::Arc;
+
+use crate::adapters::ssh::{SshClient, SshConfig};
+use crate::domain::environment::name::EnvironmentName;
+use crate::domain::environment::repository::EnvironmentRepository;
+use crate::domain::environment::state::AnyEnvironmentState;
+
+use super::errors::SshSubcommandError;
+
+/// Name of the system SSH client binary
+const SSH_BINARY: &str = "ssh";
+
+/// Presentation layer controller for ssh command workflow
+///
+/// Opens an SSH session (or runs a single remote command) on the instance of
+/// an environment using the credentials and port stored in its state.
+///
+/// ## Responsibilities
+///
+/// - Validate environment name format
+/// - Load the environment and check it has an instance to connect to
+/// - Build the `ssh` arguments with the same options as the internal SSH adapter
+/// - Hand over the terminal to the system `ssh` client
+///
+/// ## Architecture
+///
+/// This controller intentionally does NOT use `ProgressReporter`: the `ssh`
+/// client takes over the terminal and any progress output would be mixed
+/// with the remote session.
+pub struct SshCommandController {
+    repository: Arc<dyn EnvironmentRepository + Send + Sync>,
+}
+
+impl SshCommandController {
+    /// Create a new `SshCommandController` with dependencies
+    ///
+    /// # Arguments
+    ///
+    /// * `repository` - Environment repository for loading environment data
+    #[must_use]
+    pub fn new(repository: Arc<dyn EnvironmentRepository + Send + Sync>) -> Self {
+        Self { repository }
+    }
+
+    /// Execute the ssh command workflow
+    ///
+    /// This method orchestrates the workflow:
+    /// 1. Validate environment name
+    /// 2. Load environment
+    /// 3. Build the `ssh` arguments
+    /// 4. Launch the system `ssh` client
+    ///
+    /// On Unix the current process is replaced by `ssh`, so on success this
+    /// method does not return and the exit code of `ssh` (the remote command
+    /// exit code when one is given) becomes the exit code of the deployer.
+    ///
+    /// # Arguments
+    ///
+    /// * `environment_name` - Name of the environment to connect to
+    /// * `remote_command` - Optional command to run instead of a login shell
+    ///
+    /// # Errors
+    ///
+    /// Returns `SshSubcommandError` if any step before launching `ssh` fails,
+    /// or if the `ssh` client cannot be launched
+    pub fn execute(
+        &self,
+        environment_name: &str,
+        remote_command: &[String],
+    ) -> Result<(), SshSubcommandError> {
+        // Step 1: Validate environment name
+        let env_name = Self::validate_environment_name(environment_name)?;
+
+        // Step 2: Load environment
+        let environment = self.load_environment(&env_name)?;
+
+        // Step 3: Build ssh arguments
+        let args = Self::build_ssh_args(&environment, remote_command)?;
+
+        // Step 4: Launch ssh
+        Self::launch_ssh(&args)
+    }
+
+    /// Step 1: Validate environment name format
+    fn validate_environment_name(name: &str) -> Result<EnvironmentName, SshSubcommandError> {
+        EnvironmentName::new(name.to_string()).map_err(|source| {
+            SshSubcommandError::InvalidEnvironmentName {
+                name: name.to_string(),
+                source,
+            }
+        })
+    }
+
+    /// Step 2: Load environment from the repository
+    fn load_environment(
+        &self,
+        env_name: &EnvironmentName,
+    ) -> Result<AnyEnvironmentState, SshSubcommandError> {
+        self.repository
+            .load(env_name)
+            .map_err(|e| SshSubcommandError::LoadFailed {
+                name: env_name.to_string(),
+                message: e.to_string(),
+            })?
+            .ok_or_else(|| SshSubcommandError::EnvironmentNotFound {
+                name: env_name.to_string(),
+            })
+    }
+
+    /// Step 3: Build the `ssh` arguments for the environment's instance
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the environment is in `Created` or `Destroyed`
+    /// state, or if its state does not record an instance IP
+    fn build_ssh_args(
+        environment: &AnyEnvironmentState,
+        remote_command: &[String],
+    ) -> Result<Vec<String>, SshSubcommandError> {
+        if matches!(
+            environment,
+            AnyEnvironmentState::Created(_) | AnyEnvironmentState::Destroyed(_)
+        ) {
+            return Err(SshSubcommandError::NoInstance {
+                name: environment.name().to_string(),
+                state: environment.state_display_name().to_string(),
+            });
+        }
+
+        let instance_ip =
+            environment
+                .instance_ip()
+                .ok_or_else(|| SshSubcommandError::MissingInstanceIp {
+                    name: environment.name().to_string(),
+                    state: environment.state_display_name().to_string(),
+                })?;
+
+        let mut ssh_config = SshConfig::new(
+            environment.ssh_credentials().clone(),
+            SocketAddr::new(instance_ip, environment.ssh_port()),
+        );
+
+        // Environments provisioned before host keys were recorded have no
+        // `known_hosts` file yet; their keys get recorded by the next command
+        let known_hosts = environment.ssh_known_hosts_path();
+        if known_hosts.exists() {
+            ssh_config = ssh_config.with_known_hosts(known_hosts);
+        }
+
+        Ok(SshClient::new(ssh_config).build_interactive_args(remote_command))
+    }
+
+    /// Step 4: Replace the current process with the system `ssh` client
+    #[cfg(unix)]
+    fn launch_ssh(args: &[String]) -> Result<(), SshSubcommandError> {
+        use std::os::unix::process::CommandExt;
+
+        // `exec` only returns if the process could not be replaced
+        let source = Command::new(SSH_BINARY).args(args).exec();
+
+        Err(SshSubcommandError::SshLaunchFailed { source })
+    }
+
+    /// Step 4: Run the system `ssh` client and exit with its exit code
+    #[cfg(not(unix))]
+    fn launch_ssh(args: &[String]) -> Result<(), SshSubcommandError> {
+        let status = Command::new(SSH_BINARY)
+            .args(args)
+            .status()
+            .map_err(|source| SshSubcommandError::SshLaunchFailed { source })?;
+
+        std::process::exit(status.code().unwrap_or(1));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::net::{IpAddr, Ipv4Addr};
+
+    use super::*;
+    use crate::adapters::ssh::KnownHostsFile;
+    use crate::domain::environment::testing::EnvironmentTestBuilder;
+    use crate::domain::environment::ProvisionMethod;
+
+    const INSTANCE_IP: IpAddr = IpAddr::V4(Ipv4Addr::new(10, 140, 190, 14));
+
+    fn provisioned_environment() -> (AnyEnvironmentState, tempfile::TempDir) {
+        let (env, _data_dir, _build_dir, temp_dir) = EnvironmentTestBuilder::new()
+            .with_name("ssh-env")
+            .build_with_custom_paths();
+        let provisioned = env
+            .start_provisioning()
+            .provisioned(INSTANCE_IP, ProvisionMethod::Provisioned);
+        (provisioned.into_any(), temp_dir)
+    }
+
+    #[test]
+    fn it_should_connect_to_instance_with_stored_credentials_and_port() {
+        let (environment, _temp_dir) = provisioned_environment();
+        let key_path = environment
+            .ssh_credentials()
+            .ssh_priv_key_path
+            .to_string_lossy()
+            .to_string();
+
+        let args = SshCommandController::build_ssh_args(&environment, &[]).unwrap();
+
+        assert_eq!(
+            args[..4],
+            [
+                "-i".to_string(),
+                key_path,
+                "-p".to_string(),
+                environment.ssh_port().to_string()
+            ]
+        );
+        assert!(args.contains(&"StrictHostKeyChecking=no".to_string()));
+        assert_eq!(
+            args.last().unwrap(),
+            &format!(
+                "{}@{INSTANCE_IP}",
+                environment.ssh_credentials().ssh_username
+            )
+        );
+    }
+
+    #[test]
+    fn it_should_check_the_host_key_against_the_recorded_one() {
+        let (environment, _temp_dir) = provisioned_environment();
+        let known_hosts = KnownHostsFile::new(environment.ssh_known_hosts_path());
+        known_hosts
+            .write(&[format!(
+                "{INSTANCE_IP} ssh-ed25519 AAAAC3NzaC1lZDI1NTE5AAAAIExample"
+            )])
+            .unwrap();
+
+        let args = SshCommandController::build_ssh_args(&environment, &[]).unwrap();
+
+        assert!(args.contains(&"StrictHostKeyChecking=yes".to_string()));
+        assert!(args.contains(&format!(
+            "UserKnownHostsFile={}",
+            known_hosts.path().display()
+        )));
+    }
+
+    #[test]
+    fn it_should_pass_remote_command_after_the_target() {
+        let (environment, _temp_dir) = provisioned_environment();
+        let remote_command = vec!["docker".to_string(), "ps".to_string()];
+
+        let args = SshCommandController::build_ssh_args(&environment, &remote_command).unwrap();
+
+        assert_eq!(
+            args[args.len() - 3..],
+            [
+                format!(
+                    "{}@{INSTANCE_IP}",
+                    environment.ssh_credentials().ssh_username
+                ),
+                "docker".to_string(),
+                "ps".to_string(),
+            ]
+        );
+    }
+
+    #[test]
+    fn it_should_refuse_environments_in_created_state() {
+        let (env, _data_dir, _build_dir, _temp_dir) = EnvironmentTestBuilder::new()
+            .with_name("ssh-env")
+            .build_with_custom_paths();
+
+        let result = SshCommandController::build_ssh_args(&env.into_any(), &[]);
+
+        assert!(matches!(result, Err(SshSubcommandError::NoInstance { .. })));
+    }
+
+    #[test]
+    fn it_should_refuse_environments_in_destroyed_state() {
+        let (env, _data_dir, _build_dir, _temp_dir) = EnvironmentTestBuilder::new()
+            .with_name("ssh-env")
+            .build_with_custom_paths();
+        let destroyed = env.start_destroying().destroyed();
+
+        let result = SshCommandController::build_ssh_args(&destroyed.into_any(), &[]);
+
+        assert!(matches!(result, Err(SshSubcommandError::NoInstance { .. })));
+    }
+}
@@ -0,0 +1,27 @@
+//! SSH Command Presentation Module
+//!
+//! This module implements the CLI presentation layer for the ssh command,
+//! a convenience command that opens an SSH session to an environment's instance.
+//!
+//! ## Architecture
+//!
+//! The ssh command is a read-only command: it loads the stored environment,
+//! validates that it has a reachable instance, and replaces the current process
+//! with the system `ssh` client using the environment's stored credentials.
+//!
+//! ## Components
+//!
+//! - `errors` - Presentation layer error types with `.help()` methods
+//! - `handler` - Main command handler orchestrating the workflow
+//!
+//! ## Exit Code Contract
+//!
+//! - The exit code of the `ssh` client (and therefore of the remote command) is propagated
+//! - Exit code 1 for errors raised before `ssh` is launched (invalid name, wrong state, ...)
+
+pub mod errors;
+pub mod handler;
+pub use handler::SshCommandController;
+
+// Re-export commonly used types for convenience
+pub use errors::SshSubcommandError;
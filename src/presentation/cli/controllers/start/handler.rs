@@ -0,0 +1,140 @@
+//! Start Command Handler
+//!
+//! This module handles the start command execution at the presentation layer,
+//! starting the stopped tracker services of an environment and displaying its
+//! new state.
+
+use std::cell::RefCell;
+use std::sync::Arc;
+
+use parking_lot::ReentrantMutex;
+
+use crate::application::command_handlers::start::StartCommandHandler;
+use crate::domain::environment::name::EnvironmentName;
+use crate::presentation::cli::input::cli::output_format::OutputFormat;
+use crate::presentation::cli::views::commands::shared::services_state_report::{
+    ServicesStateData, ServicesStateJsonView, ServicesStateTextView,
+};
+use crate::presentation::cli::views::progress::ProgressReporter;
+use crate::presentation::cli::views::Render;
+use crate::presentation::cli::views::UserOutput;
+
+use super::errors::StartSubcommandError;
+
+/// Steps in the start workflow
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum StartStep {
+    ValidateEnvironment,
+    StartServices,
+}
+
+impl StartStep {
+    /// All steps in execution order
+    const ALL: &'static [Self] = &[Self::ValidateEnvironment, Self::StartServices];
+
+    /// Total number of steps
+    const fn count() -> usize {
+        Self::ALL.len()
+    }
+
+    /// User-facing description for the step
+    fn description(self) -> &'static str {
+        match self {
+            Self::ValidateEnvironment => "Validating environment",
+            Self::StartServices => "Starting the tracker services",
+        }
+    }
+}
+
+/// Presentation layer controller for start command workflow
+pub struct StartCommandController {
+    handler: StartCommandHandler,
+    progress: ProgressReporter,
+}
+
+impl StartCommandController {
+    /// Create a new `StartCommandController` with dependencies
+    #[allow(clippy::needless_pass_by_value)] // Arc parameters are moved to constructor for ownership
+    pub fn new(
+        handler: StartCommandHandler,
+        user_output: Arc<ReentrantMutex<RefCell<UserOutput>>>,
+    ) -> Self {
+        let progress = ProgressReporter::new(user_output, StartStep::count());
+
+        Self { handler, progress }
+    }
+
+    /// Execute the start command workflow
+    ///
+    /// # Arguments
+    ///
+    /// * `environment_name` - Name of the environment
+    /// * `output_format` - Output format (Text or Json)
+    ///
+    /// # Errors
+    ///
+    /// Returns `StartSubcommandError` if the name is invalid, the environment
+    /// is not stopped, the services cannot be started or the tracker never
+    /// reports healthy
+    pub fn execute(
+        &mut self,
+        environment_name: &str,
+        output_format: OutputFormat,
+    ) -> Result<(), StartSubcommandError> {
+        self.progress.set_output_format(output_format);
+        let result = self.execute_steps(environment_name, output_format);
+        self.progress.finish(result)
+    }
+
+    /// Steps of [`Self::execute`], before the timing summary
+    fn execute_steps(
+        &mut self,
+        environment_name: &str,
+        output_format: OutputFormat,
+    ) -> Result<(), StartSubcommandError> {
+        let env_name = self.validate_environment_name(environment_name)?;
+
+        self.progress
+            .start_step(StartStep::StartServices.description())?;
+
+        self.handler
+            .execute(&env_name)
+            .map_err(|source| StartSubcommandError::StartFailed {
+                name: environment_name.to_string(),
+                source: Box::new(source),
+            })?;
+
+        self.progress
+            .complete_step(Some("Services started and healthy"))?;
+
+        let data = ServicesStateData::new(environment_name, "start", "running");
+        let output = match output_format {
+            OutputFormat::Text => ServicesStateTextView::render(&data)?,
+            OutputFormat::Json => ServicesStateJsonView::render(&data)?,
+        };
+
+        self.progress.result(&output)?;
+
+        Ok(())
+    }
+
+    /// Validate the environment name format
+    fn validate_environment_name(
+        &mut self,
+        name: &str,
+    ) -> Result<EnvironmentName, StartSubcommandError> {
+        self.progress
+            .start_step(StartStep::ValidateEnvironment.description())?;
+
+        let env_name = EnvironmentName::new(name.to_string()).map_err(|source| {
+            StartSubcommandError::InvalidEnvironmentName {
+                name: name.to_string(),
+                source,
+            }
+        })?;
+
+        self.progress.complete_step(None)?;
+
+        Ok(env_name)
+    }
+}
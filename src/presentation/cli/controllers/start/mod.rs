@@ -0,0 +1,19 @@
+//! Start Command Presentation Module
+//!
+//! This module implements the CLI presentation layer for the start command,
+//! which starts the tracker services of a stopped environment again.
+//!
+//! ## Components
+//!
+//! - `errors` - Presentation layer error types with `.help()` methods
+//! - `handler` - Main command handler orchestrating the workflow
+
+pub mod errors;
+pub mod handler;
+pub use handler::StartCommandController;
+
+#[cfg(test)]
+mod tests;
+
+// Re-export commonly used types for convenience
+pub use errors::StartSubcommandError;
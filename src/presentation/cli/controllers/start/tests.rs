@@ -0,0 +1,46 @@
+//! Tests for the Start Command Controller
+
+use tempfile::TempDir;
+
+use crate::application::command_handlers::start::{StartCommandHandler, StartCommandHandlerError};
+use crate::infrastructure::persistence::file_repository_factory::FileRepositoryFactory;
+use crate::presentation::cli::controllers::constants::DEFAULT_LOCK_TIMEOUT;
+use crate::presentation::cli::controllers::start::errors::StartSubcommandError;
+use crate::presentation::cli::controllers::start::handler::StartCommandController;
+use crate::presentation::cli::input::cli::OutputFormat;
+use crate::presentation::cli::views::testing::TestUserOutput;
+use crate::presentation::cli::views::VerbosityLevel;
+
+fn create_controller(temp_dir: &TempDir) -> StartCommandController {
+    let (user_output, _, _) = TestUserOutput::new(VerbosityLevel::Normal).into_reentrant_wrapped();
+    let repository =
+        FileRepositoryFactory::new(DEFAULT_LOCK_TIMEOUT).create(temp_dir.path().join("data"));
+    let handler = StartCommandHandler::new(repository);
+
+    StartCommandController::new(handler, user_output)
+}
+
+#[test]
+fn it_should_reject_an_invalid_environment_name() {
+    let temp_dir = TempDir::new().unwrap();
+
+    let result = create_controller(&temp_dir).execute("invalid_name", OutputFormat::Text);
+
+    assert!(matches!(
+        result,
+        Err(StartSubcommandError::InvalidEnvironmentName { .. })
+    ));
+}
+
+#[test]
+fn it_should_fail_when_the_environment_does_not_exist() {
+    let temp_dir = TempDir::new().unwrap();
+
+    let result = create_controller(&temp_dir).execute("test-env", OutputFormat::Text);
+
+    assert!(matches!(
+        result,
+        Err(StartSubcommandError::StartFailed { source, .. })
+            if matches!(*source, StartCommandHandlerError::EnvironmentNotFound { .. })
+    ));
+}
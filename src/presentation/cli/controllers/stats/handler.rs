@@ -0,0 +1,191 @@
+//! Stats Command Handler
+//!
+//! This module handles the stats command execution at the presentation layer,
+//! displaying the phase durations of one environment, the aggregates across
+//! all environments of the workspace, or the live statistics of a running
+//! tracker.
+
+use std::cell::RefCell;
+use std::sync::Arc;
+
+use parking_lot::ReentrantMutex;
+
+use crate::application::command_handlers::stats::StatsCommandHandler;
+use crate::domain::environment::name::EnvironmentName;
+use crate::presentation::cli::input::cli::output_format::OutputFormat;
+use crate::presentation::cli::views::commands::stats::{EnvironmentStatsData, JsonView, TextView};
+use crate::presentation::cli::views::progress::ProgressReporter;
+use crate::presentation::cli::views::Render;
+use crate::presentation::cli::views::UserOutput;
+
+use super::errors::StatsSubcommandError;
+
+/// Steps in the stats workflow
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum StatsStep {
+    ValidateEnvironment,
+    ReadDurations,
+    ReadTrackerStats,
+}
+
+impl StatsStep {
+    /// Steps of a run, which reads either the durations or the tracker statistics
+    const ALL: &'static [Self] = &[Self::ValidateEnvironment, Self::ReadDurations];
+
+    /// Total number of steps
+    const fn count() -> usize {
+        Self::ALL.len()
+    }
+
+    /// User-facing description for the step
+    fn description(self) -> &'static str {
+        match self {
+            Self::ValidateEnvironment => "Validating environment",
+            Self::ReadDurations => "Reading deployment durations",
+            Self::ReadTrackerStats => "Reading tracker statistics through the API",
+        }
+    }
+}
+
+/// Presentation layer controller for stats command workflow
+pub struct StatsCommandController {
+    handler: StatsCommandHandler,
+    progress: ProgressReporter,
+}
+
+impl StatsCommandController {
+    /// Create a new `StatsCommandController` with dependencies
+    #[allow(clippy::needless_pass_by_value)] // Arc parameters are moved to constructor for ownership
+    pub fn new(
+        handler: StatsCommandHandler,
+        user_output: Arc<ReentrantMutex<RefCell<UserOutput>>>,
+    ) -> Self {
+        let progress = ProgressReporter::new(user_output, StatsStep::count());
+
+        Self { handler, progress }
+    }
+
+    /// Execute the stats command workflow
+    ///
+    /// # Arguments
+    ///
+    /// * `environment_name` - Name of the environment, `None` to aggregate
+    ///   the durations of all environments
+    /// * `output_format` - Output format (Text or Json)
+    ///
+    /// # Errors
+    ///
+    /// Returns `StatsSubcommandError` if the name is invalid or the durations
+    /// cannot be read
+    pub fn execute(
+        &mut self,
+        environment_name: Option<&str>,
+        output_format: OutputFormat,
+    ) -> Result<(), StatsSubcommandError> {
+        self.progress.set_output_format(output_format);
+        let result = self.execute_steps(environment_name, output_format);
+        self.progress.finish(result)
+    }
+
+    /// Steps of [`Self::execute`], before the timing summary
+    fn execute_steps(
+        &mut self,
+        environment_name: Option<&str>,
+        output_format: OutputFormat,
+    ) -> Result<(), StatsSubcommandError> {
+        let output = match environment_name {
+            Some(name) => {
+                let env_name = self.validate_environment_name(name)?;
+
+                self.progress
+                    .start_step(StatsStep::ReadDurations.description())?;
+                let durations = self.handler.for_environment(&env_name)?;
+                let data = EnvironmentStatsData::new(name, durations);
+                match output_format {
+                    OutputFormat::Text => TextView::render(&data)?,
+                    OutputFormat::Json => JsonView::render(&data)?,
+                }
+            }
+            None => {
+                self.progress
+                    .start_step(StatsStep::ReadDurations.description())?;
+                let stats = self.handler.all()?;
+                match output_format {
+                    OutputFormat::Text => TextView::render(&stats)?,
+                    OutputFormat::Json => JsonView::render(&stats)?,
+                }
+            }
+        };
+
+        self.progress.complete_step(None)?;
+
+        self.progress.result(&output)?;
+
+        Ok(())
+    }
+
+    /// Execute the stats command workflow for the live tracker statistics
+    ///
+    /// # Arguments
+    ///
+    /// * `environment_name` - Name of the running environment
+    /// * `output_format` - Output format (Text or Json)
+    ///
+    /// # Errors
+    ///
+    /// Returns `StatsSubcommandError` if the name is invalid or the tracker
+    /// API cannot be read
+    pub async fn execute_remote(
+        &mut self,
+        environment_name: &str,
+        output_format: OutputFormat,
+    ) -> Result<(), StatsSubcommandError> {
+        self.progress.set_output_format(output_format);
+        let result = self
+            .execute_remote_steps(environment_name, output_format)
+            .await;
+        self.progress.finish(result)
+    }
+
+    /// Steps of [`Self::execute_remote`], before the timing summary
+    async fn execute_remote_steps(
+        &mut self,
+        environment_name: &str,
+        output_format: OutputFormat,
+    ) -> Result<(), StatsSubcommandError> {
+        let env_name = self.validate_environment_name(environment_name)?;
+
+        self.progress
+            .start_step(StatsStep::ReadTrackerStats.description())?;
+        let stats = self.handler.remote(&env_name).await?;
+        let output = match output_format {
+            OutputFormat::Text => TextView::render(&stats)?,
+            OutputFormat::Json => JsonView::render(&stats)?,
+        };
+        self.progress.complete_step(None)?;
+
+        self.progress.result(&output)?;
+
+        Ok(())
+    }
+
+    /// Validate the environment name format
+    fn validate_environment_name(
+        &mut self,
+        name: &str,
+    ) -> Result<EnvironmentName, StatsSubcommandError> {
+        self.progress
+            .start_step(StatsStep::ValidateEnvironment.description())?;
+
+        let env_name = EnvironmentName::new(name.to_string()).map_err(|source| {
+            StatsSubcommandError::InvalidEnvironmentName {
+                name: name.to_string(),
+                source,
+            }
+        })?;
+
+        self.progress.complete_step(None)?;
+
+        Ok(env_name)
+    }
+}
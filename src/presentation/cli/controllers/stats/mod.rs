@@ -0,0 +1,17 @@
+//! Stats Command Presentation Module
+//!
+//! This module implements the CLI presentation layer for the stats command,
+//! which displays the deployment phase durations of one environment or their
+//! aggregates across the workspace (`--all`).
+//!
+//! ## Components
+//!
+//! - `errors` - Presentation layer error types with `.help()` methods
+//! - `handler` - Main command handler orchestrating the workflow
+
+pub mod errors;
+pub mod handler;
+pub use handler::StatsCommandController;
+
+// Re-export commonly used types for convenience
+pub use errors::StatsSubcommandError;
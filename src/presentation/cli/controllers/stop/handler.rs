@@ -0,0 +1,138 @@
+//! Stop Command Handler
+//!
+//! This module handles the stop command execution at the presentation layer,
+//! stopping the tracker services of an environment and displaying its new
+//! state.
+
+use std::cell::RefCell;
+use std::sync::Arc;
+
+use parking_lot::ReentrantMutex;
+
+use crate::application::command_handlers::stop::StopCommandHandler;
+use crate::domain::environment::name::EnvironmentName;
+use crate::presentation::cli::input::cli::output_format::OutputFormat;
+use crate::presentation::cli::views::commands::shared::services_state_report::{
+    ServicesStateData, ServicesStateJsonView, ServicesStateTextView,
+};
+use crate::presentation::cli::views::progress::ProgressReporter;
+use crate::presentation::cli::views::Render;
+use crate::presentation::cli::views::UserOutput;
+
+use super::errors::StopSubcommandError;
+
+/// Steps in the stop workflow
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum StopStep {
+    ValidateEnvironment,
+    StopServices,
+}
+
+impl StopStep {
+    /// All steps in execution order
+    const ALL: &'static [Self] = &[Self::ValidateEnvironment, Self::StopServices];
+
+    /// Total number of steps
+    const fn count() -> usize {
+        Self::ALL.len()
+    }
+
+    /// User-facing description for the step
+    fn description(self) -> &'static str {
+        match self {
+            Self::ValidateEnvironment => "Validating environment",
+            Self::StopServices => "Stopping the tracker services",
+        }
+    }
+}
+
+/// Presentation layer controller for stop command workflow
+pub struct StopCommandController {
+    handler: StopCommandHandler,
+    progress: ProgressReporter,
+}
+
+impl StopCommandController {
+    /// Create a new `StopCommandController` with dependencies
+    #[allow(clippy::needless_pass_by_value)] // Arc parameters are moved to constructor for ownership
+    pub fn new(
+        handler: StopCommandHandler,
+        user_output: Arc<ReentrantMutex<RefCell<UserOutput>>>,
+    ) -> Self {
+        let progress = ProgressReporter::new(user_output, StopStep::count());
+
+        Self { handler, progress }
+    }
+
+    /// Execute the stop command workflow
+    ///
+    /// # Arguments
+    ///
+    /// * `environment_name` - Name of the environment
+    /// * `output_format` - Output format (Text or Json)
+    ///
+    /// # Errors
+    ///
+    /// Returns `StopSubcommandError` if the name is invalid, the environment
+    /// is not running or the services cannot be stopped
+    pub fn execute(
+        &mut self,
+        environment_name: &str,
+        output_format: OutputFormat,
+    ) -> Result<(), StopSubcommandError> {
+        self.progress.set_output_format(output_format);
+        let result = self.execute_steps(environment_name, output_format);
+        self.progress.finish(result)
+    }
+
+    /// Steps of [`Self::execute`], before the timing summary
+    fn execute_steps(
+        &mut self,
+        environment_name: &str,
+        output_format: OutputFormat,
+    ) -> Result<(), StopSubcommandError> {
+        let env_name = self.validate_environment_name(environment_name)?;
+
+        self.progress
+            .start_step(StopStep::StopServices.description())?;
+
+        self.handler
+            .execute(&env_name)
+            .map_err(|source| StopSubcommandError::StopFailed {
+                name: environment_name.to_string(),
+                source: Box::new(source),
+            })?;
+
+        self.progress.complete_step(Some("Services stopped"))?;
+
+        let data = ServicesStateData::new(environment_name, "stop", "stopped");
+        let output = match output_format {
+            OutputFormat::Text => ServicesStateTextView::render(&data)?,
+            OutputFormat::Json => ServicesStateJsonView::render(&data)?,
+        };
+
+        self.progress.result(&output)?;
+
+        Ok(())
+    }
+
+    /// Validate the environment name format
+    fn validate_environment_name(
+        &mut self,
+        name: &str,
+    ) -> Result<EnvironmentName, StopSubcommandError> {
+        self.progress
+            .start_step(StopStep::ValidateEnvironment.description())?;
+
+        let env_name = EnvironmentName::new(name.to_string()).map_err(|source| {
+            StopSubcommandError::InvalidEnvironmentName {
+                name: name.to_string(),
+                source,
+            }
+        })?;
+
+        self.progress.complete_step(None)?;
+
+        Ok(env_name)
+    }
+}
@@ -0,0 +1,20 @@
+//! Stop Command Presentation Module
+//!
+//! This module implements the CLI presentation layer for the stop command,
+//! which stops the tracker services of a running environment for
+//! maintenance.
+//!
+//! ## Components
+//!
+//! - `errors` - Presentation layer error types with `.help()` methods
+//! - `handler` - Main command handler orchestrating the workflow
+
+pub mod errors;
+pub mod handler;
+pub use handler::StopCommandController;
+
+#[cfg(test)]
+mod tests;
+
+// Re-export commonly used types for convenience
+pub use errors::StopSubcommandError;
@@ -9,6 +9,7 @@ use std::sync::Arc;
 use parking_lot::ReentrantMutex;
 
 use crate::application::command_handlers::test::result::TestResult;
+use crate::application::command_handlers::test::SmokeTestOptions;
 use crate::application::command_handlers::TestCommandHandler;
 use crate::domain::environment::name::EnvironmentName;
 use crate::domain::environment::repository::EnvironmentRepository;
@@ -68,10 +69,10 @@ impl TestStep {
 /// This controller **only orchestrates the workflow** - all validation logic
 /// is implemented in the `TestCommandHandler` at the application layer.
 ///
-/// The `TestCommandHandler.execute()` method performs all infrastructure validation:
-/// - Cloud-init completion check
-/// - Docker installation verification
-/// - Docker Compose installation verification
+/// The `TestCommandHandler.execute_with_options()` method runs the selected smoke checks:
+/// - Health check endpoints of the API and HTTP trackers
+/// - UDP and HTTP tracker announces
+/// - Tracker API statistics
 pub struct TestCommandController {
     repository: Arc<dyn EnvironmentRepository>,
     progress: ProgressReporter,
@@ -109,6 +110,7 @@ impl TestCommandController {
     /// # Arguments
     ///
     /// * `environment_name` - Name of the environment to test
+    /// * `options` - Which smoke checks to run and their timeout
     ///
     /// # Errors
     ///
@@ -116,6 +118,21 @@ impl TestCommandController {
     pub async fn execute(
         &mut self,
         environment_name: &str,
+        options: &SmokeTestOptions,
+        output_format: OutputFormat,
+    ) -> Result<(), TestSubcommandError> {
+        self.progress.set_output_format(output_format);
+        let result = self
+            .execute_steps(environment_name, options, output_format)
+            .await;
+        self.progress.finish(result)
+    }
+
+    /// Steps of [`Self::execute`], before the timing summary
+    async fn execute_steps(
+        &mut self,
+        environment_name: &str,
+        options: &SmokeTestOptions,
         output_format: OutputFormat,
     ) -> Result<(), TestSubcommandError> {
         // 1. Validate environment name
@@ -125,7 +142,9 @@ impl TestCommandController {
         let handler = self.create_command_handler()?;
 
         // 3. Execute validation workflow via application layer
-        let result = self.fixture_infrastructure(&handler, &env_name).await?;
+        let result = self
+            .fixture_infrastructure(&handler, &env_name, options)
+            .await?;
 
         // 4. Complete workflow with rendered output
         self.complete_workflow(environment_name, &result, output_format)?;
@@ -176,8 +195,8 @@ impl TestCommandController {
     /// Step 3: Execute infrastructure validation tests
     ///
     /// Delegates all validation logic to the application layer `TestCommandHandler`.
-    /// The handler returns a structured `TestResult` containing DNS warnings
-    /// which are rendered here in the presentation layer.
+    /// The handler returns a structured `TestResult` containing the outcome of
+    /// every check and DNS warnings which are rendered here in the presentation layer.
     ///
     /// # Errors
     ///
@@ -186,16 +205,18 @@ impl TestCommandController {
         &mut self,
         handler: &TestCommandHandler,
         env_name: &EnvironmentName,
+        options: &SmokeTestOptions,
     ) -> Result<TestResult, TestSubcommandError> {
         self.progress
             .start_step(TestStep::TestInfrastructure.description())?;
 
-        let result = handler.execute(env_name).await.map_err(|source| {
-            TestSubcommandError::ValidationFailed {
+        let result = handler
+            .execute_with_options(env_name, options)
+            .await
+            .map_err(|source| TestSubcommandError::ValidationFailed {
                 name: env_name.to_string(),
                 source: Box::new(source),
-            }
-        })?;
+            })?;
 
         // Render advisory DNS warnings from the test result
         for warning in &result.dns_warnings {
@@ -420,16 +420,24 @@ pub fn default_global_args(
 ) -> crate::presentation::cli::input::cli::args::GlobalArgs {
     use crate::bootstrap::logging::{LogFormat, LogOutput};
     use crate::presentation::cli::input::cli::args::GlobalArgs;
-    use crate::presentation::cli::input::cli::OutputFormat;
+    use crate::presentation::cli::input::cli::{ColorChoice, OutputFormat};
 
     GlobalArgs {
         log_file_format: LogFormat::Compact,
         log_stderr_format: LogFormat::Compact,
+        log_format: None,
         log_output: LogOutput::FileOnly,
-        log_dir: working_dir.join("logs"),
+        log_dir: Some(working_dir.join("logs")),
+        otlp_endpoint: None,
+        trace_dir: None,
+        workspace: None,
         working_dir: working_dir.to_path_buf(),
+        workspace_source: crate::bootstrap::workspace::WorkspaceSource::default(),
+        allow_uninitialized: false,
         output_format: OutputFormat::Text,
         verbosity: 0, // Normal verbosity by default
+        quiet: false,
+        color: ColorChoice::Never,
     }
 }
 
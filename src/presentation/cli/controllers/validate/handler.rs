@@ -45,7 +45,7 @@ impl ValidateStep {
     fn description(self) -> &'static str {
         match self {
             Self::LoadConfiguration => "Loading configuration file",
-            Self::ValidateSchema => "Validating JSON schema",
+            Self::ValidateSchema => "Validating configuration syntax",
             Self::ValidateFields => "Validating configuration fields",
         }
     }
@@ -110,6 +110,17 @@ impl ValidateCommandController {
         &mut self,
         env_file: &Path,
         output_format: OutputFormat,
+    ) -> Result<(), ValidateSubcommandError> {
+        self.progress.set_output_format(output_format);
+        let result = self.execute_steps(env_file, output_format);
+        self.progress.finish(result)
+    }
+
+    /// Steps of [`Self::execute`], before the timing summary
+    fn execute_steps(
+        &mut self,
+        env_file: &Path,
+        output_format: OutputFormat,
     ) -> Result<(), ValidateSubcommandError> {
         // Step 1: Load Configuration (file existence check)
         self.progress
@@ -49,8 +49,8 @@ use std::sync::Arc;
 
 use parking_lot::ReentrantMutex;
 
+use crate::application::traits::RepositoryProvider;
 use crate::bootstrap::Container;
-use crate::infrastructure::persistence::file_repository_factory::FileRepositoryFactory;
 use crate::presentation::cli::input::cli::args::GlobalArgs;
 use crate::presentation::cli::input::cli::OutputFormat;
 use crate::presentation::cli::views::UserOutput;
@@ -100,7 +100,8 @@ impl ExecutionContext {
     /// use torrust_tracker_deployer_lib::presentation::cli::dispatch::ExecutionContext;
     /// use torrust_tracker_deployer_lib::presentation::cli::input::cli::args::GlobalArgs;
     /// use torrust_tracker_deployer_lib::bootstrap::logging::{LogFormat, LogOutput};
-    /// use torrust_tracker_deployer_lib::presentation::cli::input::cli::OutputFormat;
+    /// use torrust_tracker_deployer_lib::bootstrap::workspace::WorkspaceSource;
+    /// use torrust_tracker_deployer_lib::presentation::cli::input::cli::{ColorChoice, OutputFormat};
     /// use std::sync::Arc;
     /// use std::path::PathBuf;
     ///
@@ -109,11 +110,19 @@ impl ExecutionContext {
     /// let global_args = GlobalArgs {
     ///     log_file_format: LogFormat::Compact,
     ///     log_stderr_format: LogFormat::Pretty,
+    ///     log_format: None,
     ///     log_output: LogOutput::FileOnly,
-    ///     log_dir: PathBuf::from("./data/logs"),
+    ///     log_dir: Some(PathBuf::from("./data/logs")),
+    ///     otlp_endpoint: None,
+    ///     trace_dir: None,
+    ///     workspace: None,
     ///     working_dir: PathBuf::from("."),
+    ///     workspace_source: WorkspaceSource::default(),
+    ///     allow_uninitialized: false,
     ///     output_format: OutputFormat::Text,
     ///     verbosity: 0,
+    ///     quiet: false,
+    ///     color: ColorChoice::Auto,
     /// };
     /// let context = ExecutionContext::new(Arc::new(container), global_args);
     /// # Ok(())
@@ -184,10 +193,10 @@ impl ExecutionContext {
         self.container.user_output()
     }
 
-    /// Get shared reference to repository factory service
+    /// Get shared reference to repository provider
     ///
-    /// Returns the repository factory service for creating environment
-    /// repositories. The service is wrapped in `Arc<T>` for shared access.
+    /// Returns the provider creating environment repositories of the storage
+    /// backend of the workspace. It is wrapped in `Arc<T>` for shared access.
     ///
     /// # Examples
     ///
@@ -202,14 +211,14 @@ impl ExecutionContext {
     /// let container = Container::new(VerbosityLevel::Normal, Path::new("."));
     /// let context = ExecutionContext::new(Arc::new(container), global_args);
     ///
-    /// let file_repository_factory = context.file_repository_factory();
-    /// // Use file_repository_factory to create repositories
+    /// let repository_provider = context.repository_provider();
+    /// // Use repository_provider to create repositories
     /// # Ok(())
     /// # }
     /// ```
     #[must_use]
-    pub fn file_repository_factory(&self) -> Arc<FileRepositoryFactory> {
-        self.container.file_repository_factory()
+    pub fn repository_provider(&self) -> Arc<dyn RepositoryProvider> {
+        self.container.repository_provider()
     }
 
     /// Get shared reference to environment repository
@@ -282,8 +291,9 @@ impl ExecutionContext {
     /// use torrust_tracker_deployer_lib::presentation::cli::views::VerbosityLevel;
     /// use torrust_tracker_deployer_lib::presentation::cli::dispatch::ExecutionContext;
     /// use torrust_tracker_deployer_lib::presentation::cli::input::cli::args::GlobalArgs;
-    /// use torrust_tracker_deployer_lib::presentation::cli::input::cli::OutputFormat;
+    /// use torrust_tracker_deployer_lib::presentation::cli::input::cli::{ColorChoice, OutputFormat};
     /// use torrust_tracker_deployer_lib::bootstrap::logging::{LogFormat, LogOutput};
+    /// use torrust_tracker_deployer_lib::bootstrap::workspace::WorkspaceSource;
     /// use std::sync::Arc;
     /// use std::path::PathBuf;
     ///
@@ -292,11 +302,19 @@ impl ExecutionContext {
     /// let global_args = GlobalArgs {
     ///     log_file_format: LogFormat::Compact,
     ///     log_stderr_format: LogFormat::Pretty,
+    ///     log_format: None,
     ///     log_output: LogOutput::FileOnly,
-    ///     log_dir: PathBuf::from("./data/logs"),
+    ///     log_dir: Some(PathBuf::from("./data/logs")),
+    ///     otlp_endpoint: None,
+    ///     trace_dir: None,
+    ///     workspace: None,
     ///     working_dir: PathBuf::from("."),
+    ///     workspace_source: WorkspaceSource::default(),
+    ///     allow_uninitialized: false,
     ///     output_format: OutputFormat::Json,
     ///     verbosity: 0,
+    ///     quiet: false,
+    ///     color: ColorChoice::Auto,
     /// };
     /// let context = ExecutionContext::new(Arc::new(container), global_args);
     ///
@@ -325,8 +343,9 @@ impl ExecutionContext {
     /// use torrust_tracker_deployer_lib::presentation::cli::views::VerbosityLevel;
     /// use torrust_tracker_deployer_lib::presentation::cli::dispatch::ExecutionContext;
     /// use torrust_tracker_deployer_lib::presentation::cli::input::cli::args::GlobalArgs;
-    /// use torrust_tracker_deployer_lib::presentation::cli::input::cli::OutputFormat;
+    /// use torrust_tracker_deployer_lib::presentation::cli::input::cli::{ColorChoice, OutputFormat};
     /// use torrust_tracker_deployer_lib::bootstrap::logging::{LogFormat, LogOutput};
+    /// use torrust_tracker_deployer_lib::bootstrap::workspace::WorkspaceSource;
     /// use std::sync::Arc;
     /// use std::path::PathBuf;
     ///
@@ -335,11 +354,19 @@ impl ExecutionContext {
     /// let global_args = GlobalArgs {
     ///     log_file_format: LogFormat::Compact,
     ///     log_stderr_format: LogFormat::Pretty,
+    ///     log_format: None,
     ///     log_output: LogOutput::FileOnly,
-    ///     log_dir: PathBuf::from("./data/logs"),
+    ///     log_dir: Some(PathBuf::from("./data/logs")),
+    ///     otlp_endpoint: None,
+    ///     trace_dir: None,
+    ///     workspace: None,
     ///     working_dir: PathBuf::from("/tmp/test-workspace"),
+    ///     workspace_source: WorkspaceSource::default(),
+    ///     allow_uninitialized: false,
     ///     output_format: OutputFormat::Text,
     ///     verbosity: 0,
+    ///     quiet: false,
+    ///     color: ColorChoice::Auto,
     /// };
     /// let context = ExecutionContext::new(Arc::new(container), global_args);
     ///
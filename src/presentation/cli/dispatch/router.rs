@@ -50,9 +50,19 @@
 //! # }
 //! ```
 
-use std::path::Path;
+use std::num::NonZeroUsize;
+use std::path::{Path, PathBuf};
+use std::time::Duration;
 
+use crate::application::command_handlers::list::ListFilter;
+use crate::application::command_handlers::purge::gc::GcCriteria;
+use crate::application::command_handlers::test::SmokeTestOptions;
+use crate::presentation::cli::controllers::config;
 use crate::presentation::cli::controllers::create;
+use crate::presentation::cli::controllers::import::ImportArgs;
+use crate::presentation::cli::controllers::keys;
+use crate::presentation::cli::controllers::probe;
+use crate::presentation::cli::controllers::reports::ReportSelection;
 use crate::presentation::cli::errors::CommandError;
 use crate::presentation::cli::input::Commands;
 
@@ -110,51 +120,160 @@ pub async fn route_command(
     context: &ExecutionContext,
 ) -> Result<(), CommandError> {
     match command {
-        Commands::Create { action } => {
-            create::route_command(action, working_dir, context).await?;
+        Commands::Create { wizard, action } => {
+            match action {
+                Some(action) => create::route_command(action, working_dir, context).await?,
+                None => create::route_wizard(wizard, working_dir, context).await?,
+            }
             Ok(())
         }
-        Commands::Destroy { environment } => {
-            let output_format = context.output_format();
+        Commands::Init => {
             context
+                .container()
+                .create_init_controller()
+                .execute(context.output_format())?;
+            Ok(())
+        }
+        Commands::Destroy {
+            environment,
+            label,
+            yes,
+            parallel,
+            dry_run,
+            refresh,
+        } => {
+            let output_format = context.output_format();
+            let mut controller = context
                 .container()
                 .create_destroy_controller()
-                .execute(&environment, output_format)
-                .await?;
+                .with_refresh(refresh)
+                .with_parallelism(parallel.unwrap_or(NonZeroUsize::MIN));
+            match environment {
+                None => {
+                    controller
+                        .execute_by_labels(&label, yes, output_format)
+                        .await?;
+                }
+                Some(environment) if dry_run => {
+                    controller.dry_run(&environment, output_format).await?;
+                }
+                Some(environment) => {
+                    controller.execute(&environment, output_format).await?;
+                }
+            }
             Ok(())
         }
-        Commands::Purge { environment, force } => {
+        Commands::Purge {
+            environment,
+            gc,
+            older_than,
+            include_failed,
+            yes,
+            force,
+            destroy_first,
+            dry_run,
+        } => {
             let output_format = context.output_format();
-            context
+            let mut controller = context
                 .container()
                 .create_purge_controller()
-                .execute(&environment, force, output_format)
-                .await?;
+                .with_force(force);
+            match (environment, older_than) {
+                (_, Some(older_than)) if gc => {
+                    let criteria = GcCriteria::new(older_than).with_failed(include_failed);
+                    controller.gc(criteria, yes, dry_run, output_format).await?;
+                }
+                (Some(environment), _) if dry_run => {
+                    controller.dry_run(&environment, output_format).await?;
+                }
+                (Some(environment), _) => {
+                    controller
+                        .execute(&environment, yes, destroy_first, output_format)
+                        .await?;
+                }
+                (None, _) => unreachable!("clap requires an environment name without --gc"),
+            }
             Ok(())
         }
-        Commands::Provision { environment } => {
+        Commands::Provision {
+            environment,
+            label,
+            parallel,
+            dry_run,
+        } => {
             let output_format = context.output_format();
-            context
+            let mut controller = context
                 .container()
                 .create_provision_controller()
-                .execute(&environment, output_format)
-                .await?;
+                .with_parallelism(parallel.unwrap_or(NonZeroUsize::MIN));
+            match environment {
+                None => {
+                    controller.execute_by_labels(&label, output_format).await?;
+                }
+                Some(environment) if dry_run => {
+                    controller.dry_run(&environment, output_format).await?;
+                }
+                Some(environment) => {
+                    controller.execute(&environment, output_format).await?;
+                }
+            }
             Ok(())
         }
-        Commands::Configure { environment } => {
+        Commands::Configure {
+            environment,
+            label,
+            parallel,
+            config_override,
+            experimental: _,
+            target_host,
+            ssh_port,
+            ansible,
+        } => {
             let output_format = context.output_format();
-            context
+            let mut controller = context
                 .container()
                 .create_configure_controller()
-                .execute(&environment, output_format)?;
+                .with_ansible_options(ansible.to_playbook_options())
+                .with_config_override(config_override)
+                .with_parallelism(parallel.unwrap_or(NonZeroUsize::MIN));
+            match (environment, target_host) {
+                (None, _) => {
+                    controller.execute_by_labels(&label, output_format)?;
+                }
+                (Some(environment), Some(target_host)) => {
+                    controller.execute_out_of_band(
+                        &environment,
+                        &target_host,
+                        ssh_port,
+                        output_format,
+                    )?;
+                }
+                (Some(environment), None) if ansible.check => {
+                    controller.check(&environment, output_format)?;
+                }
+                (Some(environment), None) if ansible.is_tag_filtered() => {
+                    controller.execute_tagged(&environment, output_format)?;
+                }
+                (Some(environment), None) => {
+                    controller.execute(&environment, output_format)?;
+                }
+            }
             Ok(())
         }
-        Commands::Test { environment } => {
+        Commands::Test {
+            environment,
+            checks,
+            timeout,
+        } => {
+            let options = SmokeTestOptions::new()
+                .with_checks(checks)
+                .with_timeout(Duration::from_secs(timeout));
+
             let output_format = context.output_format();
             context
                 .container()
                 .create_test_controller()
-                .execute(&environment, output_format)
+                .execute(&environment, &options, output_format)
                 .await?;
             Ok(())
         }
@@ -179,15 +298,70 @@ pub async fn route_command(
                 .await?;
             Ok(())
         }
-        Commands::Release { environment } => {
+        Commands::Release {
+            environment,
+            config_override,
+            ansible,
+        } => {
             let output_format = context.output_format();
-            context
+            let mut controller = context
                 .container()
                 .create_release_controller()
+                .with_ansible_options(ansible.to_playbook_options())
+                .with_config_override(config_override);
+            if ansible.check {
+                controller.check(&environment, output_format).await?;
+            } else if ansible.is_tag_filtered() {
+                controller
+                    .execute_tagged(&environment, output_format)
+                    .await?;
+            } else {
+                controller.execute(&environment, output_format).await?;
+            }
+            Ok(())
+        }
+        Commands::Rollback { environment } => {
+            let output_format = context.output_format();
+            context
+                .container()
+                .create_rollback_controller()
                 .execute(&environment, output_format)
                 .await?;
             Ok(())
         }
+        Commands::Backup {
+            environment,
+            resume,
+        } => {
+            let output_format = context.output_format();
+            context.container().create_backup_controller().execute(
+                &environment,
+                resume,
+                output_format,
+            )?;
+            Ok(())
+        }
+        Commands::Drift {
+            environment,
+            exit_code,
+        } => {
+            let output_format = context.output_format();
+            context
+                .container()
+                .create_drift_controller()
+                .execute(&environment, exit_code, output_format)
+                .await?;
+            Ok(())
+        }
+        Commands::Restore { environment, from } => {
+            let output_format = context.output_format();
+            context.container().create_restore_controller().execute(
+                &environment,
+                &from,
+                output_format,
+            )?;
+            Ok(())
+        }
         Commands::Render {
             env_name,
             env_file,
@@ -211,22 +385,67 @@ pub async fn route_command(
                 .await?;
             Ok(())
         }
-        Commands::Run { environment } => {
+        Commands::Run { environment, wait } => {
             let output_format = context.output_format();
             context
                 .container()
                 .create_run_controller()
+                .with_wait_for_health(wait.wait_for_health())
                 .execute(&environment, output_format)
                 .await?;
             Ok(())
         }
-        Commands::Show { environment } => {
+        Commands::Stop { environment } => {
+            let output_format = context.output_format();
+            context
+                .container()
+                .create_stop_controller()
+                .execute(&environment, output_format)?;
+            Ok(())
+        }
+        Commands::Start { environment } => {
+            let output_format = context.output_format();
+            context
+                .container()
+                .create_start_controller()
+                .execute(&environment, output_format)?;
+            Ok(())
+        }
+        Commands::Deploy {
+            environment,
+            until,
+            wait,
+        } => {
+            let output_format = context.output_format();
+            context
+                .container()
+                .create_deploy_controller()
+                .with_wait_for_health(wait.wait_for_health())
+                .execute(&environment, until, output_format)
+                .await?;
+            Ok(())
+        }
+        Commands::Show {
+            environment,
+            reveal_secrets,
+        } => {
             context
                 .container()
                 .create_show_controller()
+                .with_reveal_secrets(reveal_secrets)
                 .execute(&environment, context.output_format())?;
             Ok(())
         }
+        Commands::Ssh {
+            environment,
+            command,
+        } => {
+            context
+                .container()
+                .create_ssh_controller()
+                .execute(&environment, &command)?;
+            Ok(())
+        }
         Commands::Exists { environment } => {
             context
                 .container()
@@ -234,14 +453,228 @@ pub async fn route_command(
                 .execute(&environment, context.output_format())?;
             Ok(())
         }
-        Commands::List => {
+        Commands::List {
+            state,
+            name,
+            older_than,
+            label,
+            sort,
+        } => {
+            let mut filter = ListFilter::new()
+                .with_states(state)
+                .with_labels(label)
+                .sorted_by(sort);
+            if let Some(name) = name {
+                filter = filter.with_name_pattern(name);
+            }
+            if let Some(age) = older_than {
+                filter = filter.older_than(age);
+            }
+
             let output_format = context.output_format();
             context
                 .container()
                 .create_list_controller()
+                .execute(&filter, output_format)?;
+            Ok(())
+        }
+        Commands::Orphans { delete } => {
+            let output_format = context.output_format();
+            context
+                .container()
+                .create_orphans_controller()
+                .execute(delete, output_format)?;
+            Ok(())
+        }
+        Commands::Doctor => {
+            let output_format = context.output_format();
+            context
+                .container()
+                .create_doctor_controller()
                 .execute(output_format)?;
             Ok(())
         }
+        Commands::Repair {
+            environment,
+            backup,
+            list,
+        } => {
+            let output_format = context.output_format();
+            context.container().create_repair_controller().execute(
+                &environment,
+                backup.as_deref(),
+                list,
+                output_format,
+            )?;
+            Ok(())
+        }
+        Commands::Reports {
+            environment,
+            latest,
+            show,
+        } => {
+            let selection = match (latest, show) {
+                (_, Some(file_name)) => ReportSelection::File(file_name),
+                (true, None) => ReportSelection::Latest,
+                (false, None) => ReportSelection::All,
+            };
+            let output_format = context.output_format();
+            context.container().create_reports_controller().execute(
+                &environment,
+                &selection,
+                output_format,
+            )?;
+            Ok(())
+        }
+        Commands::Diff {
+            environment,
+            config_file,
+        } => {
+            let output_format = context.output_format();
+            context.container().create_diff_controller().execute(
+                &environment,
+                &config_file,
+                output_format,
+            )?;
+            Ok(())
+        }
+        Commands::Logs {
+            environment,
+            local: _,
+            show,
+        } => {
+            let output_format = context.output_format();
+            context.container().create_logs_controller().execute(
+                &environment,
+                show.as_deref(),
+                output_format,
+            )?;
+            Ok(())
+        }
+        Commands::Stats {
+            environment,
+            all: _,
+            remote,
+        } => {
+            let output_format = context.output_format();
+            let mut controller = context.container().create_stats_controller();
+            match environment.as_deref() {
+                Some(environment) if remote => {
+                    controller
+                        .execute_remote(environment, output_format)
+                        .await?;
+                }
+                environment => controller.execute(environment, output_format)?,
+            }
+            Ok(())
+        }
+        Commands::Import {
+            environment,
+            ip,
+            ssh_key,
+            ssh_public_key,
+            ssh_user,
+            ssh_port,
+            detect_existing,
+        } => {
+            let output_format = context.output_format();
+            let args = ImportArgs {
+                environment,
+                ip,
+                ssh_key,
+                ssh_public_key,
+                ssh_user,
+                ssh_port,
+                detect_existing,
+            };
+            context
+                .container()
+                .create_import_controller()
+                .execute(&args, output_format)
+                .await?;
+            Ok(())
+        }
+        Commands::Clone {
+            source,
+            target,
+            offset,
+        } => {
+            let output_format = context.output_format();
+            context.container().create_clone_controller().execute(
+                &source,
+                &target,
+                offset,
+                output_format,
+            )?;
+            Ok(())
+        }
+        Commands::Rename {
+            environment,
+            new_name,
+            allow_live,
+        } => {
+            let output_format = context.output_format();
+            context
+                .container()
+                .create_rename_controller()
+                .with_allow_live(allow_live)
+                .execute(&environment, &new_name, output_format)?;
+            Ok(())
+        }
+        Commands::Export {
+            environment,
+            output,
+            passphrase_env,
+            ..
+        } => {
+            let output_format = context.output_format();
+            let output =
+                output.unwrap_or_else(|| PathBuf::from(format!("{environment}-bundle.tar.gz")));
+            context.container().create_export_controller().execute(
+                &environment,
+                &output,
+                passphrase_env.as_deref(),
+                output_format,
+            )?;
+            Ok(())
+        }
+        Commands::ImportBundle {
+            bundle,
+            passphrase_env,
+        } => {
+            let output_format = context.output_format();
+            context
+                .container()
+                .create_import_bundle_controller()
+                .execute(&bundle, passphrase_env.as_deref(), output_format)?;
+            Ok(())
+        }
+        Commands::Label {
+            environment,
+            set,
+            remove,
+        } => {
+            let output_format = context.output_format();
+            context.container().create_label_controller().execute(
+                &environment,
+                &set,
+                &remove,
+                output_format,
+            )?;
+            Ok(())
+        }
+        Commands::Keys { action } => {
+            keys::route_command(action, context).await?;
+            Ok(())
+        }
+        Commands::Config { action } => {
+            config::route_command(&action, context)?;
+            Ok(())
+        }
+        Commands::Probe { action } => {
+            probe::route_command(action, context).await?;
+            Ok(())
+        }
         Commands::Docs { output_path } => {
             context
                 .container()
@@ -249,5 +682,19 @@ pub async fn route_command(
                 .execute(output_path.as_ref())?;
             Ok(())
         }
+        Commands::Completions { shell } => {
+            context
+                .container()
+                .create_completions_controller()
+                .execute(shell);
+            Ok(())
+        }
+        Commands::CompleteEnvNames => {
+            context
+                .container()
+                .create_completions_controller()
+                .list_environment_names();
+            Ok(())
+        }
     }
 }
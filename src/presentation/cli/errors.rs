@@ -18,17 +18,39 @@
 //! └── Exists(ExistsSubcommandError) # Exists command errors
 //! ```
 
+use std::path::PathBuf;
+
 use thiserror::Error;
 
+use crate::application::command_handlers::init::WorkspaceError;
+use crate::bootstrap::otlp::OtlpError;
+use crate::bootstrap::workspace::{GlobalConfigError, WorkspaceSource};
 use crate::presentation::cli::controllers::{
-    configure::ConfigureSubcommandError, create::CreateCommandError,
-    destroy::DestroySubcommandError, docs::DocsCommandError, exists::ExistsSubcommandError,
-    list::ListSubcommandError, provision::ProvisionSubcommandError, purge::PurgeSubcommandError,
-    register::errors::RegisterSubcommandError, release::ReleaseSubcommandError,
-    render::errors::RenderCommandError, run::RunSubcommandError, show::ShowSubcommandError,
-    test::TestSubcommandError, validate::errors::ValidateSubcommandError,
+    backup::BackupSubcommandError, clone::CloneSubcommandError, config::ConfigCommandError,
+    configure::ConfigureSubcommandError, create::CreateCommandError, deploy::DeploySubcommandError,
+    destroy::DestroySubcommandError, diff::DiffSubcommandError, docs::DocsCommandError,
+    doctor::DoctorSubcommandError, drift::DriftSubcommandError, exists::ExistsSubcommandError,
+    export::ExportSubcommandError, import::ImportSubcommandError,
+    import_bundle::ImportBundleSubcommandError, init::InitSubcommandError, keys::KeysCommandError,
+    label::LabelSubcommandError, list::ListSubcommandError, logs::LogsSubcommandError,
+    orphans::OrphansSubcommandError, probe::ProbeCommandError, provision::ProvisionSubcommandError,
+    purge::PurgeSubcommandError, register::errors::RegisterSubcommandError,
+    release::ReleaseSubcommandError, rename::RenameSubcommandError,
+    render::errors::RenderCommandError, repair::RepairSubcommandError,
+    reports::ReportsSubcommandError, restore::RestoreSubcommandError,
+    rollback::RollbackSubcommandError, run::RunSubcommandError, show::ShowSubcommandError,
+    ssh::SshSubcommandError, start::StartSubcommandError, stats::StatsSubcommandError,
+    stop::StopSubcommandError, test::TestSubcommandError,
+    validate::errors::ValidateSubcommandError,
 };
 
+/// How the workspace is chosen, appended to the help of workspace errors
+const WORKSPACE_PRECEDENCE: &str = "The workspace is chosen by, in order:
+1. --workspace <DIR>
+2. The TORRUST_DEPLOYER_WORKSPACE environment variable
+3. workspace = \"<DIR>\" in ~/.config/torrust-deployer/config.toml
+4. The current directory";
+
 /// Errors that can occur during CLI command execution
 ///
 /// This enum provides a unified interface for all command-specific errors,
@@ -43,6 +65,13 @@ pub enum CommandError {
     #[error("Create command failed: {0}")]
     Create(Box<CreateCommandError>),
 
+    /// Config command specific errors
+    ///
+    /// Encapsulates all errors that can occur in config subcommands.
+    /// Use `.help()` for detailed troubleshooting steps.
+    #[error("Config command failed: {0}")]
+    Config(Box<ConfigCommandError>),
+
     /// Destroy command specific errors
     ///
     /// Encapsulates all errors that can occur during environment destruction.
@@ -92,6 +121,57 @@ pub enum CommandError {
     #[error("Release command failed: {0}")]
     Release(Box<ReleaseSubcommandError>),
 
+    /// Rollback command specific errors
+    ///
+    /// Encapsulates all errors that can occur while redeploying the previous release.
+    /// Use `.help()` for detailed troubleshooting steps.
+    #[error("Rollback command failed: {0}")]
+    Rollback(Box<RollbackSubcommandError>),
+
+    /// Backup command specific errors
+    ///
+    /// Encapsulates all errors that can occur while backing up the tracker database.
+    /// Use `.help()` for detailed troubleshooting steps.
+    #[error("Backup command failed: {0}")]
+    Backup(Box<BackupSubcommandError>),
+
+    /// Stop command specific errors
+    ///
+    /// Encapsulates all errors that can occur while stopping the tracker services.
+    /// Use `.help()` for detailed troubleshooting steps.
+    #[error("Stop command failed: {0}")]
+    Stop(Box<StopSubcommandError>),
+
+    /// Start command specific errors
+    ///
+    /// Encapsulates all errors that can occur while starting the stopped
+    /// tracker services, including the health wait.
+    /// Use `.help()` for detailed troubleshooting steps.
+    #[error("Start command failed: {0}")]
+    Start(Box<StartSubcommandError>),
+
+    /// Drift command specific errors
+    ///
+    /// Encapsulates all errors that can occur while detecting configuration
+    /// drift, including the drift itself with `--exit-code`.
+    /// Use `.help()` for detailed troubleshooting steps.
+    #[error("Drift command failed: {0}")]
+    Drift(Box<DriftSubcommandError>),
+
+    /// Restore command specific errors
+    ///
+    /// Encapsulates all errors that can occur while restoring the tracker database.
+    /// Use `.help()` for detailed troubleshooting steps.
+    #[error("Restore command failed: {0}")]
+    Restore(Box<RestoreSubcommandError>),
+
+    /// Deploy command specific errors
+    ///
+    /// Encapsulates all errors that can occur while deploying an environment.
+    /// Use `.help()` for detailed troubleshooting steps.
+    #[error("Deploy command failed: {0}")]
+    Deploy(Box<DeploySubcommandError>),
+
     /// Render command specific errors
     ///
     /// Encapsulates all errors that can occur during artifact generation.
@@ -113,6 +193,13 @@ pub enum CommandError {
     #[error("Show command failed: {0}")]
     Show(Box<ShowSubcommandError>),
 
+    /// SSH command specific errors
+    ///
+    /// Encapsulates all errors that can occur before handing over to the `ssh` client.
+    /// Use `.help()` for detailed troubleshooting steps.
+    #[error("SSH command failed: {0}")]
+    Ssh(Box<SshSubcommandError>),
+
     /// Exists command specific errors
     ///
     /// Encapsulates all errors that can occur during environment existence check.
@@ -127,6 +214,116 @@ pub enum CommandError {
     #[error("List command failed: {0}")]
     List(Box<ListSubcommandError>),
 
+    /// Orphans command specific errors
+    ///
+    /// Encapsulates all errors that can occur while finding or deleting orphaned resources.
+    /// Use `.help()` for detailed troubleshooting steps.
+    #[error("Orphans command failed: {0}")]
+    Orphans(Box<OrphansSubcommandError>),
+
+    /// Probe command specific errors
+    ///
+    /// Encapsulates all errors that can occur in probe subcommands.
+    /// Use `.help()` for detailed troubleshooting steps.
+    #[error("Probe command failed: {0}")]
+    Probe(Box<ProbeCommandError>),
+
+    /// Keys command specific errors
+    ///
+    /// Encapsulates all errors that can occur in keys subcommands.
+    /// Use `.help()` for detailed troubleshooting steps.
+    #[error("Keys command failed: {0}")]
+    Keys(Box<KeysCommandError>),
+
+    /// Doctor command specific errors
+    ///
+    /// Reports the critical checks that failed while diagnosing the toolchain and workspace.
+    /// Use `.help()` for detailed troubleshooting steps.
+    #[error("Doctor command failed: {0}")]
+    Doctor(Box<DoctorSubcommandError>),
+
+    /// Repair command specific errors
+    ///
+    /// Encapsulates all errors that can occur while restoring environment state from a backup.
+    /// Use `.help()` for detailed troubleshooting steps.
+    #[error("Repair command failed: {0}")]
+    Repair(Box<RepairSubcommandError>),
+
+    /// Diff command specific errors
+    ///
+    /// Encapsulates all errors that can occur while comparing an environment
+    /// with a configuration file.
+    /// Use `.help()` for detailed troubleshooting steps.
+    #[error("Diff command failed: {0}")]
+    Diff(Box<DiffSubcommandError>),
+
+    /// Logs command specific errors
+    ///
+    /// Encapsulates all errors that can occur while reading run logs.
+    /// Use `.help()` for detailed troubleshooting steps.
+    #[error("Logs command failed: {0}")]
+    Logs(Box<LogsSubcommandError>),
+
+    /// Reports command specific errors
+    ///
+    /// Encapsulates all errors that can occur while reading deployment reports.
+    /// Use `.help()` for detailed troubleshooting steps.
+    #[error("Reports command failed: {0}")]
+    Reports(Box<ReportsSubcommandError>),
+
+    /// Stats command specific errors
+    ///
+    /// Encapsulates all errors that can occur while reading deployment durations.
+    /// Use `.help()` for detailed troubleshooting steps.
+    #[error("Stats command failed: {0}")]
+    Stats(Box<StatsSubcommandError>),
+
+    /// Clone command specific errors
+    ///
+    /// Encapsulates all errors that can occur while duplicating an environment.
+    /// Use `.help()` for detailed troubleshooting steps.
+    #[error("Clone command failed: {0}")]
+    Clone(Box<CloneSubcommandError>),
+
+    /// Import command specific errors
+    ///
+    /// Encapsulates all errors that can occur while adopting an existing instance.
+    /// Use `.help()` for detailed troubleshooting steps.
+    #[error("Import command failed: {0}")]
+    Import(Box<ImportSubcommandError>),
+
+    /// Init command specific errors
+    ///
+    /// Encapsulates all errors that can occur while initializing a workspace.
+    /// Use `.help()` for detailed troubleshooting steps.
+    #[error("Init command failed: {0}")]
+    Init(Box<InitSubcommandError>),
+
+    /// The working directory is not a usable workspace
+    ///
+    /// Raised before commands operating on environments run, unless
+    /// `--allow-uninitialized` is given. Reports the directory that was
+    /// checked and how it was chosen. Use `.help()` for detailed
+    /// troubleshooting steps.
+    #[error("{error}\nSearched: {} ({origin})", path.display())]
+    Workspace {
+        path: PathBuf,
+        origin: WorkspaceSource,
+        error: Box<WorkspaceError>,
+    },
+
+    /// The global configuration file is invalid
+    ///
+    /// Use `.help()` for detailed troubleshooting steps.
+    #[error("{0}")]
+    GlobalConfig(Box<GlobalConfigError>),
+
+    /// The span export requested with `--otlp-endpoint` cannot be set up
+    ///
+    /// Use `.help()` for detailed troubleshooting steps.
+    #[error("{0}")]
+    TraceExport(Box<OtlpError>),
+
     /// Purge command specific errors
     ///
     /// Encapsulates all errors that can occur during local environment data removal.
@@ -134,6 +331,34 @@ pub enum CommandError {
     #[error("Purge command failed: {0}")]
     Purge(Box<PurgeSubcommandError>),
 
+    /// Label command specific errors
+    ///
+    /// Encapsulates all errors that can occur while labeling an environment.
+    /// Use `.help()` for detailed troubleshooting steps.
+    #[error("Label command failed: {0}")]
+    Label(Box<LabelSubcommandError>),
+
+    /// Rename command specific errors
+    ///
+    /// Encapsulates all errors that can occur while renaming an environment.
+    /// Use `.help()` for detailed troubleshooting steps.
+    #[error("Rename command failed: {0}")]
+    Rename(Box<RenameSubcommandError>),
+
+    /// Export command specific errors
+    ///
+    /// Encapsulates all errors that can occur while exporting an environment.
+    /// Use `.help()` for detailed troubleshooting steps.
+    #[error("Export command failed: {0}")]
+    Export(Box<ExportSubcommandError>),
+
+    /// Import bundle command specific errors
+    ///
+    /// Encapsulates all errors that can occur while importing a bundle.
+    /// Use `.help()` for detailed troubleshooting steps.
+    #[error("Import bundle command failed: {0}")]
+    ImportBundle(Box<ImportBundleSubcommandError>),
+
     /// Validate command specific errors
     ///
     /// Encapsulates all errors that can occur during configuration validation.
@@ -155,6 +380,12 @@ impl From<CreateCommandError> for CommandError {
     }
 }
 
+impl From<ConfigCommandError> for CommandError {
+    fn from(error: ConfigCommandError) -> Self {
+        Self::Config(Box::new(error))
+    }
+}
+
 impl From<DestroySubcommandError> for CommandError {
     fn from(error: DestroySubcommandError) -> Self {
         Self::Destroy(Box::new(error))
@@ -197,6 +428,48 @@ impl From<ReleaseSubcommandError> for CommandError {
     }
 }
 
+impl From<RollbackSubcommandError> for CommandError {
+    fn from(error: RollbackSubcommandError) -> Self {
+        Self::Rollback(Box::new(error))
+    }
+}
+
+impl From<BackupSubcommandError> for CommandError {
+    fn from(error: BackupSubcommandError) -> Self {
+        Self::Backup(Box::new(error))
+    }
+}
+
+impl From<StopSubcommandError> for CommandError {
+    fn from(error: StopSubcommandError) -> Self {
+        Self::Stop(Box::new(error))
+    }
+}
+
+impl From<StartSubcommandError> for CommandError {
+    fn from(error: StartSubcommandError) -> Self {
+        Self::Start(Box::new(error))
+    }
+}
+
+impl From<DriftSubcommandError> for CommandError {
+    fn from(error: DriftSubcommandError) -> Self {
+        Self::Drift(Box::new(error))
+    }
+}
+
+impl From<RestoreSubcommandError> for CommandError {
+    fn from(error: RestoreSubcommandError) -> Self {
+        Self::Restore(Box::new(error))
+    }
+}
+
+impl From<DeploySubcommandError> for CommandError {
+    fn from(error: DeploySubcommandError) -> Self {
+        Self::Deploy(Box::new(error))
+    }
+}
+
 impl From<RenderCommandError> for CommandError {
     fn from(error: RenderCommandError) -> Self {
         Self::Render(Box::new(error))
@@ -215,6 +488,12 @@ impl From<ShowSubcommandError> for CommandError {
     }
 }
 
+impl From<SshSubcommandError> for CommandError {
+    fn from(error: SshSubcommandError) -> Self {
+        Self::Ssh(Box::new(error))
+    }
+}
+
 impl From<ExistsSubcommandError> for CommandError {
     fn from(error: ExistsSubcommandError) -> Self {
         Self::Exists(Box::new(error))
@@ -227,12 +506,120 @@ impl From<ListSubcommandError> for CommandError {
     }
 }
 
+impl From<OrphansSubcommandError> for CommandError {
+    fn from(error: OrphansSubcommandError) -> Self {
+        Self::Orphans(Box::new(error))
+    }
+}
+
+impl From<ProbeCommandError> for CommandError {
+    fn from(error: ProbeCommandError) -> Self {
+        Self::Probe(Box::new(error))
+    }
+}
+
+impl From<KeysCommandError> for CommandError {
+    fn from(error: KeysCommandError) -> Self {
+        Self::Keys(Box::new(error))
+    }
+}
+
+impl From<DoctorSubcommandError> for CommandError {
+    fn from(error: DoctorSubcommandError) -> Self {
+        Self::Doctor(Box::new(error))
+    }
+}
+
+impl From<RepairSubcommandError> for CommandError {
+    fn from(error: RepairSubcommandError) -> Self {
+        Self::Repair(Box::new(error))
+    }
+}
+
+impl From<DiffSubcommandError> for CommandError {
+    fn from(error: DiffSubcommandError) -> Self {
+        Self::Diff(Box::new(error))
+    }
+}
+
+impl From<LogsSubcommandError> for CommandError {
+    fn from(error: LogsSubcommandError) -> Self {
+        Self::Logs(Box::new(error))
+    }
+}
+
+impl From<ReportsSubcommandError> for CommandError {
+    fn from(error: ReportsSubcommandError) -> Self {
+        Self::Reports(Box::new(error))
+    }
+}
+
+impl From<StatsSubcommandError> for CommandError {
+    fn from(error: StatsSubcommandError) -> Self {
+        Self::Stats(Box::new(error))
+    }
+}
+
+impl From<CloneSubcommandError> for CommandError {
+    fn from(error: CloneSubcommandError) -> Self {
+        Self::Clone(Box::new(error))
+    }
+}
+
+impl From<InitSubcommandError> for CommandError {
+    fn from(error: InitSubcommandError) -> Self {
+        Self::Init(Box::new(error))
+    }
+}
+
+impl From<GlobalConfigError> for CommandError {
+    fn from(error: GlobalConfigError) -> Self {
+        Self::GlobalConfig(Box::new(error))
+    }
+}
+
+impl From<OtlpError> for CommandError {
+    fn from(error: OtlpError) -> Self {
+        Self::TraceExport(Box::new(error))
+    }
+}
+
+impl From<ImportSubcommandError> for CommandError {
+    fn from(error: ImportSubcommandError) -> Self {
+        Self::Import(Box::new(error))
+    }
+}
+
 impl From<PurgeSubcommandError> for CommandError {
     fn from(error: PurgeSubcommandError) -> Self {
         Self::Purge(Box::new(error))
     }
 }
 
+impl From<LabelSubcommandError> for CommandError {
+    fn from(error: LabelSubcommandError) -> Self {
+        Self::Label(Box::new(error))
+    }
+}
+
+impl From<RenameSubcommandError> for CommandError {
+    fn from(error: RenameSubcommandError) -> Self {
+        Self::Rename(Box::new(error))
+    }
+}
+
+impl From<ExportSubcommandError> for CommandError {
+    fn from(error: ExportSubcommandError) -> Self {
+        Self::Export(Box::new(error))
+    }
+}
+
+impl From<ImportBundleSubcommandError> for CommandError {
+    fn from(error: ImportBundleSubcommandError) -> Self {
+        Self::ImportBundle(Box::new(error))
+    }
+}
+
 impl From<ValidateSubcommandError> for CommandError {
     fn from(error: ValidateSubcommandError) -> Self {
         Self::Validate(Box::new(error))
@@ -276,6 +663,7 @@ impl CommandError {
     pub fn help(&self) -> String {
         match self {
             Self::Create(e) => e.help(),
+            Self::Config(e) => e.help(),
             Self::Destroy(e) => e.help().to_string(),
             Self::Docs(e) => e.help(),
             Self::Provision(e) => e.help().to_string(),
@@ -283,14 +671,43 @@ impl CommandError {
             Self::Register(e) => e.help().to_string(),
             Self::Test(e) => e.as_ref().help().to_string(),
             Self::Release(e) => e.help().to_string(),
+            Self::Rollback(e) => e.help().to_string(),
+            Self::Backup(e) => e.help().to_string(),
+            Self::Stop(e) => e.help().to_string(),
+            Self::Start(e) => e.help().to_string(),
+            Self::Drift(e) => e.help().to_string(),
+            Self::Restore(e) => e.help().to_string(),
+            Self::Deploy(e) => e.help().to_string(),
             Self::Render(e) => e
                 .help()
                 .unwrap_or_else(|| "No additional help available".to_string()),
             Self::Run(e) => e.help().to_string(),
             Self::Show(e) => e.help().to_string(),
+            Self::Ssh(e) => e.help().to_string(),
             Self::Exists(e) => e.help().to_string(),
             Self::List(e) => e.help().to_string(),
+            Self::Orphans(e) => e.help().to_string(),
+            Self::Probe(e) => e.help(),
+            Self::Keys(e) => e.help(),
+            Self::Doctor(e) => e.help().to_string(),
+            Self::Clone(e) => e.help().to_string(),
+            Self::Import(e) => e.help().to_string(),
+            Self::Init(e) => e.help().to_string(),
+            Self::Workspace { error, .. } => {
+                format!("{}\n\n{WORKSPACE_PRECEDENCE}", error.help())
+            }
+            Self::GlobalConfig(e) => e.help().to_string(),
+            Self::TraceExport(e) => e.help().to_string(),
+            Self::Repair(e) => e.help().to_string(),
+            Self::Diff(e) => e.help().to_string(),
+            Self::Logs(e) => e.help().to_string(),
+            Self::Reports(e) => e.help().to_string(),
+            Self::Stats(e) => e.help().to_string(),
             Self::Purge(e) => e.help().to_string(),
+            Self::Label(e) => e.help().to_string(),
+            Self::Rename(e) => e.help().to_string(),
+            Self::Export(e) => e.help().to_string(),
+            Self::ImportBundle(e) => e.help().to_string(),
             Self::Validate(e) => e
                 .help()
                 .unwrap_or_else(|| "No additional help available".to_string()),
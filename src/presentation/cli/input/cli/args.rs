@@ -4,11 +4,17 @@
 //! primarily logging configuration options. These arguments follow clap conventions
 //! and provide comprehensive documentation for users.
 
+use std::io::IsTerminal;
 use std::path::PathBuf;
 
+use url::Url;
+
+use crate::adapters::ansible::AnsiblePlaybookOptions;
 use crate::bootstrap::logging::{LogFormat, LogOutput, LoggingConfig};
-use crate::presentation::cli::input::cli::OutputFormat;
-use crate::presentation::cli::views::VerbosityLevel;
+use crate::bootstrap::workspace::WorkspaceSource;
+use crate::domain::provider::Provider;
+use crate::presentation::cli::input::cli::{ColorChoice, OutputFormat};
+use crate::presentation::cli::views::{Theme, VerbosityLevel};
 
 /// Global CLI arguments for logging and output configuration
 ///
@@ -39,6 +45,18 @@ pub struct GlobalArgs {
     #[arg(long, value_enum, default_value = "pretty", global = true)]
     pub log_stderr_format: LogFormat,
 
+    /// Format for both file and stderr logging
+    ///
+    /// Shortcut for setting --log-file-format and --log-stderr-format to the
+    /// same value, e.g. `--log-format json` for log aggregation.
+    #[arg(
+        long,
+        value_enum,
+        global = true,
+        conflicts_with_all = ["log_file_format", "log_stderr_format"]
+    )]
+    pub log_format: Option<LogFormat>,
+
     /// Log output mode (default: file-only for production)
     ///
     /// - file-only: Write logs to file only (production mode)
@@ -46,7 +64,7 @@ pub struct GlobalArgs {
     #[arg(long, value_enum, default_value = "file-only", global = true)]
     pub log_output: LogOutput,
 
-    /// Log directory (default: ./data/logs)
+    /// Log directory (default: data/logs inside the workspace)
     ///
     /// Directory where log files will be written. The log file will be
     /// named 'log.txt' inside this directory. Parent directories will be
@@ -55,23 +73,63 @@ pub struct GlobalArgs {
     /// Note: If the directory cannot be created due to filesystem permissions,
     /// the application will exit with an error. Logging is critical for
     /// observability and the application cannot function without it.
-    #[arg(long, default_value = "./data/logs", global = true)]
-    pub log_dir: PathBuf,
+    #[arg(long, global = true)]
+    pub log_dir: Option<PathBuf>,
+
+    /// Export tracing spans to an OpenTelemetry collector (OTLP over HTTP)
+    ///
+    /// Collector URL, e.g. http://localhost:4318 for Grafana Tempo or an
+    /// OpenTelemetry Collector. The traces path (/v1/traces) is appended
+    /// when the URL has no path. Each run is exported as one trace, from the
+    /// command down to the external tools it runs. Logging is unchanged.
+    #[arg(long, value_name = "URL", global = true)]
+    pub otlp_endpoint: Option<Url>,
+
+    /// Directory the trace files of a failed command are written to
+    ///
+    /// Overrides, for this invocation, the 'traces.dir' setting of the
+    /// environment (default: data/<env>/traces inside the workspace). Used
+    /// by provision, configure, release, run, deploy and rollback, e.g. to
+    /// collect the traces as CI artifacts: --trace-dir $ARTIFACTS/traces
+    #[arg(long, value_name = "DIR", global = true)]
+    pub trace_dir: Option<PathBuf>,
 
-    /// Working directory for environment data (default: .)
+    /// Workspace holding the environment data (default: see below)
     ///
     /// Root directory where environment data will be stored. Each environment
     /// creates subdirectories within this location for build files and state.
-    /// This is useful for testing or when you want to manage environments in
-    /// a different location than the current directory.
+    ///
+    /// When not given, the workspace is taken from the
+    /// TORRUST_DEPLOYER_WORKSPACE environment variable, then from
+    /// `workspace = "<DIR>"` in ~/.config/torrust-deployer/config.toml, and
+    /// finally the current directory is used.
     ///
     /// Examples:
-    /// - Default: './data' (relative to current directory)
-    /// - Testing: '/tmp/test-workspace' (absolute path)
-    /// - Production: '/var/lib/torrust-deployer' (system location)
-    #[arg(long, default_value = ".", global = true)]
+    /// - Testing: '/tmp/test-workspace'
+    /// - Production: '/var/lib/torrust-deployer'
+    #[arg(long, visible_alias = "working-dir", value_name = "DIR", global = true)]
+    pub workspace: Option<PathBuf>,
+
+    /// Absolute path of the workspace the command runs in
+    ///
+    /// Resolved by the bootstrap from `--workspace`, the environment variable
+    /// and the global configuration (see [`crate::bootstrap::workspace`]).
+    #[arg(skip = PathBuf::from("."))]
     pub working_dir: PathBuf,
 
+    /// How `working_dir` was chosen, for error messages
+    #[arg(skip)]
+    pub workspace_source: WorkspaceSource,
+
+    /// Run the command in a working directory that was not initialized
+    ///
+    /// Commands operating on environments refuse to run in a working
+    /// directory without a workspace marker (created by 'init'), to report
+    /// a wrong --workspace up front instead of failing on missing files.
+    /// This flag skips that check.
+    #[arg(long, global = true)]
+    pub allow_uninitialized: bool,
+
     /// Output format for command results (default: json)
     ///
     /// Controls the format of result data written to stdout. Progress messages,
@@ -113,6 +171,24 @@ pub struct GlobalArgs {
         global = true
     )]
     pub verbosity: u8,
+
+    /// Only show errors and the final result
+    ///
+    /// Suppresses progress, success and warning messages on stderr. The
+    /// command result on stdout is unchanged, so it can still be piped.
+    ///
+    /// Example:
+    ///   provision my-env --quiet
+    #[arg(short = 'q', long, global = true, conflicts_with = "verbosity")]
+    pub quiet: bool,
+
+    /// When to use colors and emoji in the output (default: auto)
+    ///
+    /// - auto: only when stderr is a terminal and `NO_COLOR` is not set
+    /// - always: also when the output is piped or redirected
+    /// - never: plain text labels and no ANSI escape sequences
+    #[arg(long, value_enum, default_value = "auto", global = true)]
+    pub color: ColorChoice,
 }
 
 impl GlobalArgs {
@@ -131,36 +207,69 @@ impl GlobalArgs {
     ///
     /// ```rust
     /// # use torrust_tracker_deployer_lib::presentation::cli::input::cli::args::GlobalArgs;
-    /// # use torrust_tracker_deployer_lib::presentation::cli::input::cli::OutputFormat;
+    /// # use torrust_tracker_deployer_lib::presentation::cli::input::cli::{ColorChoice, OutputFormat};
     /// # use torrust_tracker_deployer_lib::bootstrap::logging::{LogFormat, LogOutput, LoggingConfig};
+    /// # use torrust_tracker_deployer_lib::bootstrap::workspace::WorkspaceSource;
     /// # use std::path::PathBuf;
     /// // Create args with log configuration
     /// let args = GlobalArgs {
     ///     log_file_format: LogFormat::Compact,
     ///     log_stderr_format: LogFormat::Pretty,
+    ///     log_format: None,
     ///     log_output: LogOutput::FileAndStderr,
-    ///     log_dir: PathBuf::from("/tmp/logs"),
+    ///     log_dir: Some(PathBuf::from("/tmp/logs")),
+    ///     otlp_endpoint: None,
+    ///     trace_dir: None,
+    ///     workspace: None,
     ///     working_dir: PathBuf::from("."),
+    ///     workspace_source: WorkspaceSource::default(),
+    ///     allow_uninitialized: false,
     ///     output_format: OutputFormat::Text,
     ///     verbosity: 0,
+    ///     quiet: false,
+    ///     color: ColorChoice::Auto,
     /// };
     /// let config = args.logging_config();
     /// // config will have specified log formats and directory
     /// ```
     #[must_use]
     pub fn logging_config(&self) -> LoggingConfig {
-        LoggingConfig::new(
-            self.log_dir.clone(),
-            self.log_file_format.clone(),
-            self.log_stderr_format.clone(),
-            self.log_output,
-        )
+        let file_format = self
+            .log_format
+            .clone()
+            .unwrap_or_else(|| self.log_file_format.clone());
+        let stderr_format = self
+            .log_format
+            .clone()
+            .unwrap_or_else(|| self.log_stderr_format.clone());
+
+        let config =
+            LoggingConfig::new(self.log_dir(), file_format, stderr_format, self.log_output)
+                .with_stderr_ansi(self.use_color());
+
+        match &self.otlp_endpoint {
+            Some(endpoint) => config.with_otlp_endpoint(endpoint.clone()),
+            None => config,
+        }
+    }
+
+    /// Directory of the log file
+    ///
+    /// `--log-dir` when given, `data/logs` inside the workspace otherwise, so
+    /// running the CLI from another directory does not create a `data/`
+    /// folder there.
+    #[must_use]
+    pub fn log_dir(&self) -> PathBuf {
+        self.log_dir
+            .clone()
+            .unwrap_or_else(|| self.working_dir.join("data").join("logs"))
     }
 
     /// Convert CLI verbosity count to `VerbosityLevel`
     ///
     /// Maps the number of `-v` flags provided by the user to the appropriate
     /// `VerbosityLevel` enum variant:
+    /// - `--quiet` → Quiet
     /// - 0 flags (default) → Normal
     /// - 1 flag (-v) → Verbose
     /// - 2 flags (-vv) → `VeryVerbose`
@@ -170,23 +279,36 @@ impl GlobalArgs {
     ///
     /// ```rust
     /// # use torrust_tracker_deployer_lib::presentation::cli::input::cli::args::GlobalArgs;
-    /// # use torrust_tracker_deployer_lib::presentation::cli::input::cli::OutputFormat;
+    /// # use torrust_tracker_deployer_lib::presentation::cli::input::cli::{ColorChoice, OutputFormat};
     /// # use torrust_tracker_deployer_lib::bootstrap::logging::{LogFormat, LogOutput};
+    /// # use torrust_tracker_deployer_lib::bootstrap::workspace::WorkspaceSource;
     /// # use torrust_tracker_deployer_lib::presentation::cli::views::VerbosityLevel;
     /// # use std::path::PathBuf;
     /// let args = GlobalArgs {
     ///     log_file_format: LogFormat::Compact,
     ///     log_stderr_format: LogFormat::Pretty,
+    ///     log_format: None,
     ///     log_output: LogOutput::FileOnly,
-    ///     log_dir: PathBuf::from("./data/logs"),
+    ///     log_dir: Some(PathBuf::from("./data/logs")),
+    ///     otlp_endpoint: None,
+    ///     trace_dir: None,
+    ///     workspace: None,
     ///     working_dir: PathBuf::from("."),
+    ///     workspace_source: WorkspaceSource::default(),
+    ///     allow_uninitialized: false,
     ///     output_format: OutputFormat::Text,
     ///     verbosity: 2,  // -vv
+    ///     quiet: false,
+    ///     color: ColorChoice::Auto,
     /// };
     /// assert_eq!(args.verbosity_level(), VerbosityLevel::VeryVerbose);
     /// ```
     #[must_use]
     pub fn verbosity_level(&self) -> VerbosityLevel {
+        if self.quiet {
+            return VerbosityLevel::Quiet;
+        }
+
         match self.verbosity {
             0 => VerbosityLevel::Normal,      // Default
             1 => VerbosityLevel::Verbose,     // -v
@@ -194,6 +316,181 @@ impl GlobalArgs {
             _ => VerbosityLevel::Debug,       // -vvv or more
         }
     }
+
+    /// Whether the output is decorated with colors and emoji
+    ///
+    /// Resolves `--color` against the current process: in `auto` mode
+    /// decorations are used only when stderr is a terminal and `NO_COLOR`
+    /// is not set.
+    #[must_use]
+    pub fn use_color(&self) -> bool {
+        self.color.resolve(
+            std::io::stderr().is_terminal(),
+            std::env::var_os("NO_COLOR").as_deref(),
+        )
+    }
+
+    /// Theme for user-facing output
+    ///
+    /// Emoji symbols when the output is decorated, plain text labels otherwise.
+    #[must_use]
+    pub fn output_theme(&self) -> Theme {
+        if self.use_color() {
+            Theme::emoji()
+        } else {
+            Theme::plain()
+        }
+    }
+}
+
+/// Ansible execution controls for the `configure` and `release` commands
+///
+/// Passed through to every `ansible-playbook` run of the command.
+#[derive(clap::Args, Debug, Clone, Default)]
+pub struct AnsibleArgs {
+    /// Only run the playbook tasks with these tags (e.g. `docker,firewall`)
+    ///
+    /// configure: docker, docker-compose, security-updates, firewall
+    /// release: tracker, prometheus, grafana, mysql, backup, caddy, compose
+    ///
+    /// The environment state is not changed.
+    #[arg(long, value_delimiter = ',')]
+    pub tags: Vec<String>,
+
+    /// Skip the playbook tasks with these tags
+    ///
+    /// The environment state is not changed.
+    #[arg(long, value_delimiter = ',')]
+    pub skip_tags: Vec<String>,
+
+    /// Dry run: report what would change without changing the host
+    ///
+    /// The environment state is not changed.
+    #[arg(long)]
+    pub check: bool,
+}
+
+impl AnsibleArgs {
+    /// Convert the arguments to the Ansible adapter options
+    #[must_use]
+    pub fn to_playbook_options(&self) -> AnsiblePlaybookOptions {
+        AnsiblePlaybookOptions::default()
+            .with_tags(self.tags.clone())
+            .with_skip_tags(self.skip_tags.clone())
+            .with_check(self.check)
+    }
+
+    /// Whether `--tags` or `--skip-tags` restrict the run to some tasks
+    #[must_use]
+    pub fn is_tag_filtered(&self) -> bool {
+        self.to_playbook_options().is_tag_filtered()
+    }
+}
+
+/// Health wait controls for the `run` and `deploy` commands
+///
+/// By default the command waits for the tracker Health Check API to report
+/// healthy before finishing. The last of `--wait` and `--no-wait` wins.
+#[derive(clap::Args, Debug, Clone, Default)]
+pub struct WaitArgs {
+    /// Wait for the tracker to report healthy before finishing (default)
+    #[arg(long, overrides_with = "no_wait")]
+    pub wait: bool,
+
+    /// Finish as soon as the services are started, without waiting for the
+    /// tracker to report healthy
+    #[arg(long, overrides_with = "wait")]
+    pub no_wait: bool,
+}
+
+impl WaitArgs {
+    /// Whether to wait for the tracker to report healthy
+    #[must_use]
+    pub fn wait_for_health(&self) -> bool {
+        !self.no_wait
+    }
+}
+
+/// Answers for the interactive `create` wizard (`create --interactive`)
+///
+/// Every prompt of the wizard can be answered with one of these flags, so the
+/// wizard also runs without a terminal. Together with `--defaults` no prompt
+/// is shown at all.
+#[derive(clap::Args, Debug, Clone, Default)]
+pub struct CreateWizardArgs {
+    /// Build the environment configuration by answering prompts
+    #[arg(long)]
+    pub interactive: bool,
+
+    /// Use the default answer for every prompt not answered by a flag
+    #[arg(long, requires = "interactive")]
+    pub defaults: bool,
+
+    /// Environment name
+    #[arg(long, requires = "interactive")]
+    pub name: Option<String>,
+
+    /// Generate a new SSH key pair in `data/{env}/ssh/`
+    #[arg(long, requires = "interactive", conflicts_with = "ssh_private_key")]
+    pub generate_ssh_key: bool,
+
+    /// Path to an existing SSH private key
+    #[arg(long, value_name = "PATH", requires = "interactive")]
+    pub ssh_private_key: Option<PathBuf>,
+
+    /// Path to the SSH public key (default: the private key path with `.pub`)
+    #[arg(long, value_name = "PATH", requires = "ssh_private_key")]
+    pub ssh_public_key: Option<PathBuf>,
+
+    /// Infrastructure provider
+    #[arg(long, value_enum, requires = "interactive")]
+    pub provider: Option<Provider>,
+
+    /// LXD profile name (LXD provider)
+    #[arg(long, requires = "interactive")]
+    pub lxd_profile: Option<String>,
+
+    /// Hetzner Cloud API token (Hetzner provider)
+    #[arg(long, requires = "interactive")]
+    pub hetzner_api_token: Option<String>,
+
+    /// `DigitalOcean` API token (`DigitalOcean` provider)
+    #[arg(long, requires = "interactive")]
+    pub digitalocean_api_token: Option<String>,
+
+    /// Tracker database
+    #[arg(long, value_enum, requires = "interactive")]
+    pub database: Option<WizardDatabase>,
+
+    /// Port of the UDP tracker
+    #[arg(long, requires = "interactive")]
+    pub udp_port: Option<u16>,
+
+    /// Port of the HTTP tracker
+    #[arg(long, requires = "interactive")]
+    pub http_port: Option<u16>,
+
+    /// Port of the tracker REST API
+    #[arg(long, requires = "interactive")]
+    pub api_port: Option<u16>,
+
+    /// Where to write the configuration (default: `envs/{env}.json`)
+    #[arg(long, value_name = "FILE", requires = "interactive")]
+    pub output: Option<PathBuf>,
+
+    /// Only write the configuration file, do not create the environment
+    #[arg(long, requires = "interactive")]
+    pub no_create: bool,
+}
+
+/// Tracker database offered by the `create` wizard
+#[derive(clap::ValueEnum, Debug, Clone, Copy, PartialEq, Eq)]
+pub enum WizardDatabase {
+    /// `SQLite` database file inside the tracker container
+    Sqlite3,
+
+    /// `MySQL` server deployed next to the tracker
+    Mysql,
 }
 
 #[cfg(test)]
@@ -204,11 +501,19 @@ mod tests {
         GlobalArgs {
             log_file_format: LogFormat::Compact,
             log_stderr_format: LogFormat::Pretty,
+            log_format: None,
             log_output: LogOutput::FileOnly,
-            log_dir: PathBuf::from("./data/logs"),
+            log_dir: Some(PathBuf::from("./data/logs")),
+            otlp_endpoint: None,
+            trace_dir: None,
+            workspace: None,
             working_dir: PathBuf::from("."),
+            workspace_source: WorkspaceSource::default(),
+            allow_uninitialized: false,
             output_format: OutputFormat::Text,
             verbosity,
+            quiet: false,
+            color: ColorChoice::Auto,
         }
     }
 
@@ -236,6 +541,37 @@ mod tests {
         assert_eq!(args.verbosity_level(), VerbosityLevel::Debug);
     }
 
+    #[test]
+    fn it_should_return_quiet_level_when_quiet_flag_provided() {
+        let args = GlobalArgs {
+            quiet: true,
+            ..create_test_args(0)
+        };
+        assert_eq!(args.verbosity_level(), VerbosityLevel::Quiet);
+    }
+
+    #[test]
+    fn it_should_use_the_plain_theme_and_no_ansi_codes_when_colors_are_disabled() {
+        let args = GlobalArgs {
+            color: ColorChoice::Never,
+            ..create_test_args(0)
+        };
+
+        assert_eq!(args.output_theme(), Theme::plain());
+        assert!(!args.logging_config().stderr_ansi);
+    }
+
+    #[test]
+    fn it_should_use_the_emoji_theme_when_colors_are_forced() {
+        let args = GlobalArgs {
+            color: ColorChoice::Always,
+            ..create_test_args(0)
+        };
+
+        assert_eq!(args.output_theme(), Theme::emoji());
+        assert!(args.logging_config().stderr_ansi);
+    }
+
     #[test]
     fn it_should_cap_at_debug_level_when_more_than_three_v_flags_provided() {
         let args = create_test_args(4);
@@ -0,0 +1,88 @@
+//! Color choice for user-facing output
+//!
+//! This module defines the `--color` option that controls whether output
+//! written to the terminal is decorated with ANSI colors and emoji symbols.
+
+use std::ffi::OsStr;
+
+/// When to decorate output with colors and emoji
+///
+/// With `auto` (default) decorations are used only when stderr is a terminal
+/// and the `NO_COLOR` environment variable is not set (see <https://no-color.org>).
+/// `always` and `never` override the detection.
+///
+/// # Examples
+///
+/// ```rust
+/// use torrust_tracker_deployer_lib::presentation::cli::input::cli::ColorChoice;
+///
+/// // Piped output is never decorated in auto mode
+/// assert!(!ColorChoice::Auto.resolve(false, None));
+///
+/// // NO_COLOR disables decorations on a terminal
+/// assert!(!ColorChoice::Auto.resolve(true, Some("1".as_ref())));
+///
+/// // An explicit choice wins over the detection
+/// assert!(ColorChoice::Always.resolve(false, Some("1".as_ref())));
+/// ```
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, clap::ValueEnum)]
+pub enum ColorChoice {
+    /// Decorate output when stderr is a terminal and `NO_COLOR` is not set (default)
+    #[default]
+    Auto,
+
+    /// Always decorate output, even when piped
+    Always,
+
+    /// Never decorate output
+    Never,
+}
+
+impl ColorChoice {
+    /// Decide whether output is decorated
+    ///
+    /// # Arguments
+    ///
+    /// * `stderr_is_terminal` - Whether stderr is attached to a terminal
+    /// * `no_color` - Value of the `NO_COLOR` environment variable, if set.
+    ///   An empty value is treated as not set, as the convention requires.
+    #[must_use]
+    pub fn resolve(self, stderr_is_terminal: bool, no_color: Option<&OsStr>) -> bool {
+        match self {
+            Self::Always => true,
+            Self::Never => false,
+            Self::Auto => stderr_is_terminal && no_color.is_none_or(OsStr::is_empty),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn it_should_decorate_terminal_output_in_auto_mode() {
+        assert!(ColorChoice::Auto.resolve(true, None));
+    }
+
+    #[test]
+    fn it_should_not_decorate_piped_output_in_auto_mode() {
+        assert!(!ColorChoice::Auto.resolve(false, None));
+    }
+
+    #[test]
+    fn it_should_not_decorate_output_when_no_color_is_set() {
+        assert!(!ColorChoice::Auto.resolve(true, Some(OsStr::new("1"))));
+    }
+
+    #[test]
+    fn it_should_ignore_an_empty_no_color_value() {
+        assert!(ColorChoice::Auto.resolve(true, Some(OsStr::new(""))));
+    }
+
+    #[test]
+    fn it_should_let_the_explicit_choice_override_the_detection() {
+        assert!(ColorChoice::Always.resolve(false, Some(OsStr::new("1"))));
+        assert!(!ColorChoice::Never.resolve(true, None));
+    }
+}
@@ -5,9 +5,20 @@
 
 use clap::Subcommand;
 
+use std::num::NonZeroUsize;
 use std::path::PathBuf;
+use std::time::Duration;
 
+use crate::application::command_handlers::deploy::DeployTarget;
+use crate::application::command_handlers::list::filter::parse_age;
+use crate::application::command_handlers::list::ListSort;
+use crate::application::command_handlers::test::SmokeCheck;
+use crate::domain::environment::Label;
 use crate::domain::provider::Provider;
+use crate::infrastructure::shell_completion::{
+    CompletionShell, COMPLETE_ENV_NAMES_COMMAND, ENVIRONMENT_VALUE_NAME,
+};
+use crate::presentation::cli::input::cli::{AnsibleArgs, CreateWizardArgs, WaitArgs};
 
 /// Available CLI commands
 ///
@@ -18,12 +29,38 @@ pub enum Commands {
     /// Create operations (environment creation or template generation)
     ///
     /// This command provides subcommands for creating environments and generating
-    /// configuration templates.
+    /// configuration templates. With `--interactive` it asks for the main
+    /// settings instead, writes the configuration file and creates the
+    /// environment.
+    #[command(arg_required_else_help = true, args_conflicts_with_subcommands = true)]
     Create {
+        #[command(flatten)]
+        wizard: CreateWizardArgs,
+
         #[command(subcommand)]
-        action: CreateAction,
+        action: Option<CreateAction>,
     },
 
+    /// Initialize a workspace in the working directory
+    ///
+    /// Creates the data/ and build/ directories and the workspace marker
+    /// file (deployer-workspace.json) recording the workspace format
+    /// version. The other commands refuse to run in a working directory
+    /// without the marker, unless --allow-uninitialized is given.
+    ///
+    /// Running init again is safe: an initialized workspace is left as it
+    /// is. In a workspace created by an older version, which holds
+    /// environments but no marker, init checks that every environment loads
+    /// before adding the marker.
+    ///
+    /// EXAMPLES:
+    ///   Initialize the current directory:
+    ///     torrust-tracker-deployer init
+    ///
+    ///   Initialize another directory:
+    ///     torrust-tracker-deployer --working-dir /var/lib/torrust-deployer init
+    Init,
+
     /// Destroy an existing deployment environment
     ///
     /// This command will tear down all infrastructure associated with the
@@ -54,12 +91,55 @@ pub enum Commands {
     /// EXECUTION TIME:
     ///   Typical duration: 1-3 minutes
     ///   Factors: provider API response, resource cleanup timing
+    ///
+    /// DRY RUN:
+    ///   --dry-run lists the resources and local files that would be deleted
+    ///   without changing anything: destroy {env-name} --dry-run
+    ///
+    /// BULK DESTROY:
+    ///   --label destroys every environment with the given labels that is not
+    ///   destroyed yet. A failure does not stop the others; the results are
+    ///   reported per environment: destroy --label purpose=e2e --yes
     Destroy {
         /// Name of the environment to destroy
         ///
         /// The environment name must be a valid identifier that was previously
         /// created through the provision command.
-        environment: String,
+        #[arg(required_unless_present = "label")]
+        environment: Option<String>,
+
+        /// Destroy every environment with this label (`key=value`, repeatable)
+        #[arg(
+            long,
+            value_name = "KEY=VALUE",
+            conflicts_with_all = ["environment", "dry_run"]
+        )]
+        label: Vec<Label>,
+
+        /// Destroy the environments selected with --label without listing
+        /// them for confirmation first
+        #[arg(short, long, conflicts_with = "environment")]
+        yes: bool,
+
+        /// Maximum number of environments selected with --label destroyed
+        /// at the same time (default: 1)
+        #[arg(
+            long,
+            value_name = "N",
+            requires = "label",
+            conflicts_with = "environment"
+        )]
+        parallel: Option<NonZeroUsize>,
+
+        /// Simulate the command: list what would be destroyed without
+        /// destroying anything or changing the environment state
+        #[arg(long)]
+        dry_run: bool,
+
+        /// Run `tofu refresh` before destroying, so resources deleted outside
+        /// the deployer (e.g. with `lxc delete`) are dropped from the state
+        #[arg(long, conflicts_with = "dry_run")]
+        refresh: bool,
     },
 
     /// Purge local data for an environment
@@ -87,31 +167,77 @@ pub enum Commands {
     ///   After purge, the environment name becomes available for reuse
     ///
     /// SAFETY WARNINGS:
-    ///   • Always prompts for confirmation unless --force is provided
+    ///   • Asks to type the environment name to confirm unless --yes (or --force)
+    ///     is provided
     ///   • Operation is IRREVERSIBLE - local data permanently deleted
-    ///   • For running environments: only removes LOCAL data, does NOT destroy infrastructure
+    ///   • Refuses environments that may still have infrastructure (any state
+    ///     other than Created or Destroyed): without the local state, the
+    ///     deployer can no longer destroy it
     ///   • Best practice: only purge after destroy completes successfully
     ///
     /// EXAMPLES:
     ///   After destroying an environment:
     ///     torrust-tracker-deployer purge my-env
     ///
+    ///   Destroy the infrastructure, then purge:
+    ///     torrust-tracker-deployer purge my-env --destroy-first
+    ///
     ///   Skip confirmation (for automation/scripts):
-    ///     torrust-tracker-deployer purge my-env --force
+    ///     torrust-tracker-deployer purge my-env --yes
+    ///
+    ///   Purge every environment destroyed more than 14 days ago (CI cleanup):
+    ///     torrust-tracker-deployer purge --gc --older-than 14d --yes
     Purge {
         /// Name of the environment to purge
         ///
         /// The environment name must match an existing environment in the
         /// local data directory.
-        environment: String,
+        #[arg(required_unless_present = "gc")]
+        environment: Option<String>,
 
-        /// Skip confirmation prompt
+        /// Purge every environment destroyed longer ago than --older-than
+        /// instead of a single one
+        #[arg(
+            long,
+            requires = "older_than",
+            conflicts_with_all = ["environment", "destroy_first"]
+        )]
+        gc: bool,
+
+        /// Minimum time since the environment was destroyed, or failed with
+        /// --include-failed (e.g. `12h`, `14d`, `2w`)
+        #[arg(long, value_parser = parse_age, requires = "gc")]
+        older_than: Option<Duration>,
+
+        /// Also purge environments whose last command failed longer ago than
+        /// --older-than (they may still have infrastructure: needs --force)
+        #[arg(long, requires = "gc")]
+        include_failed: bool,
+
+        /// Skip the confirmation prompt
         ///
-        /// When provided, the purge operation proceeds without asking for
-        /// user confirmation. Use with caution, especially for non-destroyed
-        /// environments.
+        /// When provided, the purge operation proceeds without asking to type
+        /// the environment name.
+        #[arg(short, long)]
+        yes: bool,
+
+        /// Purge even if the environment may still have infrastructure
+        ///
+        /// Removes the local data of environments that are not destroyed (or
+        /// whose state cannot be read). Their infrastructure is left running
+        /// and must be removed manually. Also skips the confirmation prompt,
+        /// like --yes.
         #[arg(short, long)]
         force: bool,
+
+        /// Destroy the infrastructure of the environment before purging
+        #[arg(long, conflicts_with = "dry_run")]
+        destroy_first: bool,
+
+        /// Simulate the command: list the directories that would be removed
+        /// without removing anything (no confirmation is asked)
+        #[arg(long)]
+        dry_run: bool,
     },
 
     /// Provision a new deployment environment infrastructure
@@ -153,12 +279,46 @@ pub enum Commands {
     ///   • "Environment not in Created state": Run 'create environment' first
     ///   • "Provider credentials missing": Check environment config file
     ///   • "SSH connection failed": Verify network connectivity
+    ///
+    /// DRY RUN:
+    ///   --dry-run renders the templates and runs 'tofu plan' only; no
+    ///   infrastructure is created and the state stays Created
+    ///
+    /// SEVERAL ENVIRONMENTS:
+    ///   --label provisions every Created environment with the labels,
+    ///   --parallel of them at a time, and ends with a summary table:
+    ///   provision --label purpose=nightly --parallel 4
     Provision {
         /// Name of the environment to provision
         ///
         /// The environment name must match an existing environment that was
         /// previously created and is in "Created" state.
-        environment: String,
+        #[arg(required_unless_present = "label")]
+        environment: Option<String>,
+
+        /// Provision every environment in the Created state with this label
+        /// (`key=value`, repeatable)
+        #[arg(
+            long,
+            value_name = "KEY=VALUE",
+            conflicts_with_all = ["environment", "dry_run"]
+        )]
+        label: Vec<Label>,
+
+        /// Maximum number of environments selected with --label provisioned
+        /// at the same time (default: 1)
+        #[arg(
+            long,
+            value_name = "N",
+            requires = "label",
+            conflicts_with = "environment"
+        )]
+        parallel: Option<NonZeroUsize>,
+
+        /// Simulate the command: list the resources that would be created
+        /// without creating anything or changing the environment state
+        #[arg(long)]
+        dry_run: bool,
     },
 
     /// Configure a provisioned deployment environment
@@ -189,50 +349,125 @@ pub enum Commands {
     /// EXECUTION TIME:
     ///   Typical duration: 2-5 minutes
     ///   Factors: network speed, package downloads, instance specifications
+    ///
+    /// ANSIBLE CONTROLS:
+    ///   • --tags / --skip-tags: only (or never) run the given components
+    ///   • --check: report what would change; the state is not changed
+    ///
+    /// CONFIGURATION OVERRIDES:
+    ///   --override <FILE> merges a partial configuration file onto the
+    ///   persisted one before configuring (e.g. new firewall rules). Settings
+    ///   that need a new instance are rejected.
+    ///
+    /// EXAMPLES:
+    ///   torrust-tracker-deployer configure my-env
+    ///   torrust-tracker-deployer configure my-env --tags docker
+    ///   torrust-tracker-deployer configure my-env --check
+    ///   torrust-tracker-deployer configure my-env --override overrides.json
+    ///   torrust-tracker-deployer configure --label purpose=nightly --parallel 4
     Configure {
         /// Name of the environment to configure
         ///
         /// The environment name must match an existing environment that was
         /// previously provisioned and is in "Provisioned" state.
-        environment: String,
+        #[arg(required_unless_present = "label")]
+        environment: Option<String>,
+
+        /// Configure every environment in the Provisioned state with this
+        /// label (`key=value`, repeatable)
+        #[arg(
+            long,
+            value_name = "KEY=VALUE",
+            conflicts_with_all = ["environment", "check", "tags", "skip_tags"]
+        )]
+        label: Vec<Label>,
+
+        /// Maximum number of environments selected with --label configured
+        /// at the same time (default: 1)
+        #[arg(
+            long,
+            value_name = "N",
+            requires = "label",
+            conflicts_with = "environment"
+        )]
+        parallel: Option<NonZeroUsize>,
+
+        /// Partial configuration file merged onto the persisted configuration
+        /// before configuring
+        ///
+        /// Only settings applied by configure or release can be changed. The
+        /// merged configuration is persisted and the configuration revision
+        /// of the environment is incremented.
+        #[arg(
+            long = "override",
+            alias = "env-file",
+            value_name = "FILE",
+            conflicts_with = "label"
+        )]
+        config_override: Option<PathBuf>,
+
+        /// Enable experimental options (`--target-host`)
+        #[arg(long)]
+        experimental: bool,
+
+        /// Run the playbooks against this host instead of the instance
+        /// (experimental, requires --experimental)
+        ///
+        /// Developer mode for Ansible roles: the host is reached with the SSH
+        /// credentials of the environment, its host key is not checked, and
+        /// the environment state is not changed.
+        #[arg(
+            long,
+            value_name = "HOST",
+            requires = "experimental",
+            conflicts_with_all = ["label", "config_override", "check"]
+        )]
+        target_host: Option<String>,
+
+        /// SSH port of the --target-host (default: 22)
+        #[arg(long, value_name = "PORT", requires = "target_host")]
+        ssh_port: Option<u16>,
+
+        /// Ansible execution controls
+        #[command(flatten)]
+        ansible: AnsibleArgs,
     },
 
-    /// Verify deployment infrastructure
-    ///
-    /// This command validates that a deployed environment's infrastructure is
-    /// properly configured and ready. It will:
-    /// - Verify cloud-init completion
-    /// - Verify Docker installation
-    /// - Verify Docker Compose installation
+    /// Run smoke checks against a running tracker
     ///
-    /// The environment must have an instance IP set (use 'provision' command first).
+    /// This command checks from outside the instance that a deployed tracker
+    /// answers on each of its public interfaces. The selected checks run
+    /// concurrently, and each one reports whether it passed and how long it took.
     ///
-    /// WHAT GETS VALIDATED:
-    ///   • Cloud-init completion (system initialization finished)
-    ///   • Docker engine installed and running
-    ///   • Docker Compose installed and accessible
-    ///   • SSH connectivity to instance
+    /// The environment must be running (use 'run' command first).
     ///
-    /// WHEN TO RUN (Recommended Checkpoints):
-    ///   • After 'provision' - verify infrastructure is ready
-    ///   • After 'register' - verify existing instance meets requirements
-    ///   • Before 'configure' - confirm base system is operational
-    ///   • Troubleshooting - diagnose infrastructure issues
+    /// CHECKS:
+    ///   • health: health check endpoints of the API and HTTP trackers
+    ///   • udp: connect and announce round-trip on every UDP tracker
+    ///   • http: announce with a synthetic info-hash on every HTTP tracker
+    ///   • api: tracker statistics (`GET /api/v1/stats`) with the admin token
     ///
     /// EXIT CODES:
-    ///   • 0: All checks passed - infrastructure ready
-    ///   • Non-zero: One or more checks failed - see output for details
+    ///   • 0: All checks passed
+    ///   • Non-zero: One or more checks failed - each failure is listed
     ///
-    /// WHAT THIS DOES NOT VALIDATE:
-    ///   • Application deployment (use after 'release' to check that)
-    ///   • Running services (use 'show' after 'run' to check that)
-    ///   • Configuration file syntax (use 'validate' for that)
+    /// EXAMPLES:
+    ///   torrust-tracker-deployer test my-env
+    ///   torrust-tracker-deployer test my-env --check udp,api --timeout 30
     Test {
         /// Name of the environment to test
         ///
-        /// The environment name must match an existing environment that was
-        /// previously provisioned and has an instance IP assigned.
+        /// The environment name must match an existing environment that is
+        /// in the running state.
         environment: String,
+
+        /// Only run these checks (e.g. `udp,api`); all checks run by default
+        #[arg(long = "check", value_enum, value_delimiter = ',')]
+        checks: Vec<SmokeCheck>,
+
+        /// Time each check may take before it fails, in seconds
+        #[arg(long, value_name = "SECONDS", default_value_t = 10, value_parser = clap::value_parser!(u64).range(1..))]
+        timeout: u64,
     },
 
     /// Validate environment configuration without deployment
@@ -289,6 +524,28 @@ pub enum Commands {
         env_file: PathBuf,
     },
 
+    /// Compare an environment with an updated configuration file
+    ///
+    /// Loads the configuration the environment was created with and compares
+    /// it with the file, which is validated as by 'validate'. Every changed
+    /// setting is listed with its impact: whether it requires a new instance
+    /// (provision), configuring the instance again (configure), a new release
+    /// (release), or no redeploy at all. Secret values are never displayed.
+    ///
+    /// Nothing is changed: the environment keeps its configuration.
+    ///
+    /// EXAMPLES:
+    ///   torrust-tracker-deployer diff my-env envs/my-env.json
+    ///   torrust-tracker-deployer diff my-env new.json --output-format json
+    Diff {
+        /// Name of the environment
+        environment: String,
+
+        /// Path to the updated environment configuration file
+        #[arg(value_name = "FILE")]
+        config_file: PathBuf,
+    },
+
     /// Register an existing instance as an alternative to provisioning
     ///
     /// This command registers an existing VM, physical server, or container
@@ -384,12 +641,122 @@ pub enum Commands {
     /// EXAMPLES:
     ///   torrust-tracker-deployer release my-env
     ///   torrust-tracker-deployer release production
+    ///   torrust-tracker-deployer release my-env --tags tracker
+    ///   torrust-tracker-deployer release my-env --check
+    ///   torrust-tracker-deployer release my-env --override overrides.json
+    ///
+    /// CONFIGURATION OVERRIDES:
+    ///   --override <FILE> merges a partial configuration file onto the
+    ///   persisted one before rendering (e.g. a new API admin token or tracker
+    ///   version). Settings that need configure or a new instance are rejected.
     Release {
         /// Name of the environment to release to
         ///
         /// The environment name must match an existing environment that was
         /// previously configured and is in "Configured" state.
         environment: String,
+
+        /// Partial configuration file merged onto the persisted configuration
+        /// before releasing
+        ///
+        /// Only settings applied by a release can be changed. The merged
+        /// configuration is persisted and the configuration revision of the
+        /// environment is incremented.
+        #[arg(long = "override", alias = "env-file", value_name = "FILE")]
+        config_override: Option<PathBuf>,
+
+        /// Ansible execution controls
+        #[command(flatten)]
+        ansible: AnsibleArgs,
+    },
+
+    /// Redeploy the tracker version of the previous release
+    ///
+    /// Every successful release records the deployed tracker image version.
+    /// This command pins the tracker to the version released before the
+    /// current one and runs the release workflow again. The environment must
+    /// be in the "Released" or "Running" state and have a previous release.
+    ///
+    /// STATE TRANSITION:
+    ///   • Prerequisites: Environment must be in Released or Running state
+    ///   • After Success: Environment transitions to Released state
+    ///   • On Failure: Environment transitions to ReleaseFailed state
+    ///
+    /// After a successful rollback run `run <environment>` to start the
+    /// services with the previous version.
+    ///
+    /// EXAMPLES:
+    ///   torrust-tracker-deployer rollback my-env
+    Rollback {
+        /// Name of the environment to roll back
+        environment: String,
+    },
+
+    /// Back up the tracker database of an environment
+    ///
+    /// Dumps the tracker database on the instance and downloads it to
+    /// `data/{env}/backups/{id}/`, where the id is the UTC time the backup
+    /// was taken, next to a `manifest.json` with its size, SHA-256 checksum
+    /// and database driver. A `SQLite` tracker is stopped while its database
+    /// file is copied; `MySQL` is dumped in a single transaction.
+    ///
+    /// The download is chunked and verified: if it is interrupted, the dump
+    /// stays on the instance and --resume continues from the last verified
+    /// chunk instead of starting over.
+    ///
+    /// The environment must be in the "Released" or "Running" state.
+    ///
+    /// EXAMPLES:
+    ///   torrust-tracker-deployer backup my-env
+    ///   torrust-tracker-deployer backup my-env --resume
+    Backup {
+        /// Name of the environment to back up
+        environment: String,
+
+        /// Complete the most recent interrupted backup, if any, instead of taking a new one
+        #[arg(long)]
+        resume: bool,
+    },
+
+    /// Restore the tracker database of an environment from a backup
+    ///
+    /// Verifies the backup against its manifest, uploads it, stops the
+    /// tracker, replaces its database, starts it again and waits for the
+    /// tracker Health Check API to report healthy.
+    ///
+    /// The environment must be in the "Released" or "Running" state.
+    ///
+    /// EXAMPLES:
+    ///   torrust-tracker-deployer restore my-env --from 20261016T143045Z
+    Restore {
+        /// Name of the environment to restore
+        environment: String,
+
+        /// Id of the backup to restore (its directory in data/{env}/backups/)
+        #[arg(long, value_name = "ID")]
+        from: String,
+    },
+
+    /// Detect configuration drift on the instance of an environment
+    ///
+    /// Renders the release artifacts (docker-compose.yml, tracker.toml and,
+    /// with HTTPS, the Caddyfile) again, downloads the copies deployed on the
+    /// instance and shows a unified diff of every modified one. The images of
+    /// the running containers are compared with the expected images and
+    /// their pinned digests.
+    ///
+    /// The environment must be in the "Released" or "Running" state.
+    ///
+    /// EXAMPLES:
+    ///   torrust-tracker-deployer drift my-env
+    ///   torrust-tracker-deployer drift my-env --exit-code
+    Drift {
+        /// Name of the environment to check
+        environment: String,
+
+        /// Exit with a non-zero code when the instance drifted (for CI)
+        #[arg(long)]
+        exit_code: bool,
     },
 
     /// Generate deployment artifacts without executing deployment
@@ -514,15 +881,94 @@ pub enum Commands {
     ///   - Check container status: docker compose ps
     ///   - View logs: docker compose logs tracker
     ///
+    /// HEALTH WAIT:
+    ///   By default the command waits for the tracker Health Check API to
+    ///   report healthy. With --no-wait it finishes as soon as the containers
+    ///   are started.
+    ///
     /// EXAMPLES:
     ///   torrust-tracker-deployer run my-env
     ///   torrust-tracker-deployer run production
+    ///   torrust-tracker-deployer run my-env --no-wait
     Run {
         /// Name of the environment to run
         ///
         /// The environment name must match an existing environment that was
         /// previously released and is in "Released" state.
         environment: String,
+
+        /// Tracker health wait controls
+        #[command(flatten)]
+        wait: WaitArgs,
+    },
+
+    /// Stop the tracker services of a running environment
+    ///
+    /// Takes the tracker offline for maintenance without destroying or
+    /// redeploying anything: the Docker Compose services are stopped, keeping
+    /// the containers, their data and the deployed configuration. The
+    /// environment moves to the "Stopped" state.
+    ///
+    /// The environment must be in the "Running" state.
+    ///
+    /// EXAMPLES:
+    ///   torrust-tracker-deployer stop my-env
+    Stop {
+        /// Name of the environment to stop
+        environment: String,
+    },
+
+    /// Start the stopped tracker services of an environment again
+    ///
+    /// Starts the Docker Compose services stopped by the stop command, without
+    /// pulling images or recreating containers, and waits for the tracker
+    /// Health Check API to report healthy. The environment moves back to the
+    /// "Running" state.
+    ///
+    /// The environment must be in the "Stopped" state.
+    ///
+    /// EXAMPLES:
+    ///   torrust-tracker-deployer start my-env
+    Start {
+        /// Name of the environment to start
+        environment: String,
+    },
+
+    /// Deploy an environment: provision, configure, release and run
+    ///
+    /// This command runs the deployment workflow with a single command,
+    /// starting from the current state of the environment. Phases the
+    /// environment already completed are skipped, so running it again after
+    /// a failure or an interruption resumes the deployment.
+    ///
+    /// STATE TRANSITION:
+    ///   • Prerequisites: Environment in Created, Provisioned, Configured,
+    ///     Released or Running state (use 'create environment' first)
+    ///   • After Success: Environment in the --until state (Running by default)
+    ///   • On Failure: Stops at the failed phase, which persists its failed state
+    ///
+    /// PHASES:
+    ///   Created → provision → configure → release → run → Running
+    ///
+    /// HEALTH WAIT:
+    ///   The run phase waits for the tracker to report healthy unless
+    ///   --no-wait is given.
+    ///
+    /// EXAMPLES:
+    ///   torrust-tracker-deployer deploy my-env
+    ///   torrust-tracker-deployer deploy my-env --until configured
+    ///   torrust-tracker-deployer deploy my-env --no-wait
+    Deploy {
+        /// Name of the environment to deploy
+        environment: String,
+
+        /// Stop once the environment reaches this state
+        #[arg(long, value_enum, value_name = "STATE", default_value_t = DeployTarget::Running)]
+        until: DeployTarget,
+
+        /// Tracker health wait controls
+        #[command(flatten)]
+        wait: WaitArgs,
     },
 
     /// Show environment information with state-aware details
@@ -555,17 +1001,26 @@ pub enum Commands {
     ///   Use --output-format json for machine-readable output
     ///   Default: Human-readable text with tables
     ///
+    /// SECRETS:
+    ///   Tokens and passwords are omitted unless --reveal-secrets is given
+    ///
     /// PERFORMANCE NOTE:
     ///   Fast operation - reads local state file only (no network calls)
     ///
     /// EXAMPLES:
     ///   torrust-tracker-deployer show my-env
     ///   torrust-tracker-deployer show production
+    ///   torrust-tracker-deployer show production --reveal-secrets
     Show {
         /// Name of the environment to show
         ///
         /// The environment name must match an existing environment.
         environment: String,
+
+        /// Also show the secrets of the environment (API tokens and
+        /// passwords) in plaintext
+        #[arg(long)]
+        reveal_secrets: bool,
     },
 
     #[allow(clippy::doc_link_with_quotes)]
@@ -611,6 +1066,39 @@ pub enum Commands {
         environment: String,
     },
 
+    /// Open an SSH session to the instance of an environment
+    ///
+    /// Connects to the environment's instance with the system `ssh` client,
+    /// using the SSH credentials, port and instance IP stored in the
+    /// environment state. Host key checking follows the same policy as the
+    /// deployer's internal SSH connections.
+    ///
+    /// REQUIREMENTS:
+    ///   The environment must have an instance: it cannot be in Created
+    ///   (not yet provisioned) or Destroyed state.
+    ///
+    /// REMOTE COMMANDS:
+    ///   Arguments after `--` are executed on the instance instead of opening
+    ///   an interactive shell. The exit code of the remote command becomes the
+    ///   exit code of the deployer.
+    ///
+    /// EXAMPLES:
+    ///   Open an interactive shell:
+    ///     torrust-tracker-deployer ssh my-env
+    ///
+    ///   Run a single command:
+    ///     torrust-tracker-deployer ssh my-env -- docker ps
+    Ssh {
+        /// Name of the environment to connect to
+        ///
+        /// The environment name must match an existing environment.
+        environment: String,
+
+        /// Command to run on the instance instead of an interactive shell
+        #[arg(last = true)]
+        command: Vec<String>,
+    },
+
     /// List all environments in the deployment workspace
     ///
     /// This command provides a quick overview of all environments with their
@@ -634,12 +1122,436 @@ pub enum Commands {
     ///   • Quick audit of all deployment environments
     ///   • See what can be purged to free up space
     ///
+    /// FILTERING AND SORTING:
+    ///   • --state: only environments in the given states (comma separated)
+    ///   • --name: only environments whose name matches a glob (`*`, `?`)
+    ///   • --older-than: only environments created at least this long ago
+    ///   • --label: only environments with the given labels (repeatable)
+    ///   • --sort: order by name (default), state or creation time
+    ///
     /// PERFORMANCE:
     ///   Fast operation - only reads local JSON files, no network calls
     ///
-    /// EXAMPLE:
+    /// EXAMPLES:
     ///   torrust-tracker-deployer list
-    List,
+    ///   torrust-tracker-deployer list --state provision_failed --older-than 3d
+    ///   torrust-tracker-deployer list --name 'e2e-*' --sort created
+    ///   torrust-tracker-deployer list --label team=infra --label purpose=e2e
+    List {
+        /// Only list environments in these states (e.g. `running,provision_failed`)
+        #[arg(long, value_delimiter = ',')]
+        state: Vec<String>,
+
+        /// Only list environments whose name matches this glob (e.g. `e2e-*`)
+        #[arg(long)]
+        name: Option<String>,
+
+        /// Only list environments created at least this long ago (e.g. `30m`, `12h`, `7d`, `2w`)
+        #[arg(long, value_parser = parse_age)]
+        older_than: Option<Duration>,
+
+        /// Only list environments with this label (`key=value`, repeatable)
+        #[arg(long, value_name = "KEY=VALUE")]
+        label: Vec<Label>,
+
+        /// Sort order of the listed environments
+        #[arg(long, value_enum, default_value_t = ListSort::Name)]
+        sort: ListSort,
+    },
+
+    /// Find resources left behind by environments that no longer exist
+    ///
+    /// Lists LXD instances (`torrust-tracker-vm-{env}`), LXD profiles
+    /// (`lxd-{env}`, `torrust-profile-{env}`) and `build/{env}` directories
+    /// that no environment references, for example after purging an
+    /// environment whose provisioning had partially succeeded. Instances and
+    /// profiles with custom names are found through the destroyed
+    /// environments that used them.
+    ///
+    /// SAFETY:
+    ///   • Other resources whose name does not match these patterns are never touched
+    ///   • Nothing is deleted without --delete
+    ///
+    /// EXAMPLES:
+    ///   Report orphaned resources:
+    ///     torrust-tracker-deployer orphans
+    ///
+    ///   Delete them:
+    ///     torrust-tracker-deployer orphans --delete
+    Orphans {
+        /// Delete the orphaned resources instead of only reporting them
+        #[arg(long)]
+        delete: bool,
+    },
+
+    /// Diagnose the local toolchain and workspace
+    ///
+    /// Checks the external tools and their versions (`OpenTofu`, Ansible,
+    /// LXD, Docker), that the LXD daemon answers and the current user may
+    /// use it, the free disk space and write permissions of the workspace,
+    /// and lists the environments left in an error state. Each problem comes
+    /// with a hint on how to fix it.
+    ///
+    /// LXD and Docker are only required when an environment of the
+    /// workspace uses that provider. The command exits with a non-zero code
+    /// when a critical check fails; warnings do not change the exit code.
+    ///
+    /// EXAMPLES:
+    ///   Diagnose the host and the workspace:
+    ///     torrust-tracker-deployer doctor
+    ///
+    ///   Produce a report to attach to a bug report:
+    ///     torrust-tracker-deployer doctor --output-format json
+    Doctor,
+
+    /// List or display the deployment reports of an environment
+    ///
+    /// Every deployment command (provision, register, configure, release,
+    /// run, destroy) writes a JSON report to `data/{env}/reports/` when it
+    /// finishes, successfully or not. A report records the start and end
+    /// time, the resulting state, the duration of each step, the versions of
+    /// `OpenTofu` and Ansible, and the trace id of a failure.
+    ///
+    /// EXAMPLES:
+    ///   List the reports of an environment:
+    ///     torrust-tracker-deployer reports my-env
+    ///
+    ///   Display the latest report:
+    ///     torrust-tracker-deployer reports my-env --latest
+    ///
+    ///   Display a specific report:
+    ///     torrust-tracker-deployer reports my-env --show 20251008-143045-provision.json
+    Reports {
+        /// Name of the environment
+        environment: String,
+
+        /// Display the most recent report instead of listing all of them
+        #[arg(long, conflicts_with = "show")]
+        latest: bool,
+
+        /// Display the report with this file name (as listed)
+        #[arg(long, value_name = "FILE")]
+        show: Option<String>,
+    },
+
+    /// List or display the local run logs of an environment
+    ///
+    /// Every external tool invocation (`OpenTofu`, Ansible) of the provision,
+    /// configure, release, rollback, run and destroy commands writes its
+    /// complete output to `build/{env}/logs/{command}/{run_id}-{step}.log`,
+    /// for successful runs too. Only the most recent runs of each command are
+    /// kept (`kept_run_logs` in the workspace marker, 10 by default).
+    ///
+    /// EXAMPLES:
+    ///   List the run logs of an environment:
+    ///     torrust-tracker-deployer logs my-env --local
+    ///
+    ///   Display the tofu apply log of a provision run:
+    ///     torrust-tracker-deployer logs my-env --local --show provision/20251008T143045.123456789Z-tofu-apply.log
+    Logs {
+        /// Name of the environment
+        environment: String,
+
+        /// Read the logs kept on this machine (the only source supported)
+        #[arg(long, required = true)]
+        local: bool,
+
+        /// Display the log with this path (as listed)
+        #[arg(long, value_name = "FILE")]
+        show: Option<String>,
+    },
+
+    /// Display the deployment phase durations or tracker statistics of an environment
+    ///
+    /// The provision, configure, release and run commands record how long
+    /// their last successful run took in the environment state. With --all,
+    /// the durations of every environment in the workspace are aggregated
+    /// into the minimum, average and maximum of each phase.
+    ///
+    /// With --remote, the torrent and peer counts of the running tracker are
+    /// read from its API (`/api/v1/stats`) with the admin token of the
+    /// environment.
+    ///
+    /// EXAMPLES:
+    ///   Display the phase durations of an environment:
+    ///     torrust-tracker-deployer stats my-env
+    ///
+    ///   Aggregate the durations of all environments:
+    ///     torrust-tracker-deployer stats --all
+    ///
+    ///   Display the current torrent and peer counts of the tracker:
+    ///     torrust-tracker-deployer stats my-env --remote
+    Stats {
+        /// Name of the environment
+        #[arg(required_unless_present = "all")]
+        environment: Option<String>,
+
+        /// Aggregate the durations of all environments in the workspace
+        #[arg(long, conflicts_with = "environment")]
+        all: bool,
+
+        /// Read the current statistics of the running tracker through its API
+        #[arg(long, conflicts_with = "all")]
+        remote: bool,
+    },
+
+    /// Restore the persisted state of an environment from a backup
+    ///
+    /// Each time the state in `data/{env}/environment.json` is overwritten,
+    /// the previous readable state is kept in `data/{env}/state-backups/`
+    /// (the last 5). When the state can no longer be loaded, for example
+    /// after the process was interrupted while saving it, this command
+    /// restores the latest backup.
+    ///
+    /// SAFETY:
+    ///   • A state that loads is only replaced when --backup is given
+    ///   • The replaced state is backed up first when it is readable
+    ///   • Only the local state changes; the instance is not contacted
+    ///
+    /// EXAMPLES:
+    ///   Show the state status and the available backups:
+    ///     torrust-tracker-deployer repair my-env --list
+    ///
+    ///   Restore the latest backup:
+    ///     torrust-tracker-deployer repair my-env
+    ///
+    ///   Restore a specific backup:
+    ///     torrust-tracker-deployer repair my-env --backup 20251008T143045.123456789Z
+    Repair {
+        /// Name of the environment to repair
+        environment: String,
+
+        /// Restore the backup with this id (as listed) instead of the latest one
+        #[arg(long, value_name = "ID", conflicts_with = "list")]
+        backup: Option<String>,
+
+        /// Only show the state status and the available backups
+        #[arg(long)]
+        list: bool,
+    },
+
+    /// Duplicate an environment's configuration under a new name
+    ///
+    /// Creates a new environment in the Created state from the configuration
+    /// of an existing one. The instance name, LXD profile name and data/build
+    /// directories are regenerated for the new name. Runtime data such as the
+    /// instance IP is never copied.
+    ///
+    /// With --offset, every tracker port (UDP, HTTP, API, health check) is
+    /// shifted so both environments can run side by side on the same host.
+    ///
+    /// EXAMPLES:
+    ///   Clone production as staging with tracker ports shifted by 1000:
+    ///     torrust-tracker-deployer clone production staging --offset 1000
+    Clone {
+        /// Name of the existing environment to copy
+        #[arg(value_name = ENVIRONMENT_VALUE_NAME)]
+        source: String,
+
+        /// Name of the new environment
+        target: String,
+
+        /// Offset added to every tracker port of the new environment
+        #[arg(long, default_value_t = 0)]
+        offset: u16,
+    },
+
+    /// Give an existing environment a new name
+    ///
+    /// Moves the data/ and build/ directories of the environment to the new
+    /// name. For Created and Destroyed environments, the instance name and
+    /// LXD profile name derived from the old name are regenerated too.
+    ///
+    /// Environments that may have infrastructure are only renamed with
+    /// --allow-live: their instance and LXD profile keep their current names.
+    /// Environments with an operation in progress are never renamed.
+    ///
+    /// EXAMPLES:
+    ///   Rename a new environment:
+    ///     torrust-tracker-deployer rename tmp prod
+    ///
+    ///   Rename a running environment, keeping its instance:
+    ///     torrust-tracker-deployer rename tmp prod --allow-live
+    Rename {
+        /// Current name of the environment
+        #[arg(value_name = ENVIRONMENT_VALUE_NAME)]
+        environment: String,
+
+        /// New name of the environment
+        new_name: String,
+
+        /// Rename the environment even if it may have infrastructure
+        ///
+        /// The instance and LXD profile keep the names derived from the
+        /// current environment name.
+        #[arg(long)]
+        allow_live: bool,
+    },
+
+    /// Export an environment to a bundle file for another machine
+    ///
+    /// Writes the environment state, the build directory (including the
+    /// OpenTofu state) and the SSH public key to a single .tar.gz file, with a
+    /// manifest recording the checksum of every file. Everything holding a
+    /// secret (the state, the SSH private key, the environment secrets and
+    /// build files such as .env and variables.tfvars) is encrypted with the
+    /// passphrase read from --passphrase-env.
+    ///
+    /// With --no-secrets, the secrets are left out instead, e.g. to share the
+    /// environment for inspection. Such a bundle cannot be imported.
+    ///
+    /// Import the bundle on the other machine with 'import-bundle'.
+    ///
+    /// EXAMPLES:
+    ///   Export an environment with its secrets:
+    ///     torrust-tracker-deployer export prod --passphrase-env BUNDLE_PASSPHRASE
+    ///
+    ///   Export an environment without its secrets, for inspection:
+    ///     torrust-tracker-deployer export prod --no-secrets --output prod.tar.gz
+    Export {
+        /// Name of the environment to export
+        #[arg(value_name = ENVIRONMENT_VALUE_NAME)]
+        environment: String,
+
+        /// Path of the bundle file to write (default: <ENVIRONMENT>-bundle.tar.gz)
+        #[arg(long, short = 'o', value_name = "FILE")]
+        output: Option<PathBuf>,
+
+        /// Leave every secret out of the bundle (it cannot be imported then)
+        #[arg(long, conflicts_with = "passphrase_env")]
+        no_secrets: bool,
+
+        /// Environment variable holding the passphrase that encrypts the secrets
+        #[arg(long, value_name = "VAR", required_unless_present = "no_secrets")]
+        passphrase_env: Option<String>,
+    },
+
+    /// Import an environment from a bundle written by 'export'
+    ///
+    /// Validates the checksums of the bundle, then adds the environment to
+    /// this workspace: its build directory goes to build/<ENVIRONMENT> and its
+    /// SSH keys to data/<ENVIRONMENT>/ssh. An environment that already exists
+    /// is never overwritten.
+    ///
+    /// The imported environment manages the same infrastructure as the
+    /// exported one, so commands such as 'configure' and 'destroy' work from
+    /// this machine.
+    ///
+    /// EXAMPLES:
+    ///   Import a bundle:
+    ///     torrust-tracker-deployer import-bundle prod-bundle.tar.gz --passphrase-env BUNDLE_PASSPHRASE
+    ImportBundle {
+        /// Path of the bundle file
+        #[arg(value_name = "FILE")]
+        bundle: PathBuf,
+
+        /// Environment variable holding the passphrase of the bundle secrets
+        #[arg(long, value_name = "VAR")]
+        passphrase_env: Option<String>,
+    },
+
+    /// Set or remove the labels of an environment
+    ///
+    /// Labels are `key=value` pairs used to organize environments: they can
+    /// be set at creation time in the `labels` section of the configuration,
+    /// filtered on with 'list --label' and used to select the environments
+    /// of 'destroy --label'. Without --set or --remove, the current labels
+    /// are displayed.
+    ///
+    /// EXAMPLES:
+    ///   Tag an environment:
+    ///     torrust-tracker-deployer label my-env --set team=infra --set purpose=e2e
+    ///
+    ///   Remove a label:
+    ///     torrust-tracker-deployer label my-env --remove purpose
+    Label {
+        /// Name of the environment to label
+        #[arg(value_name = ENVIRONMENT_VALUE_NAME)]
+        environment: String,
+
+        /// Set a label, replacing its current value (`key=value`, repeatable)
+        #[arg(long, value_name = "KEY=VALUE")]
+        set: Vec<Label>,
+
+        /// Remove the label with this key (repeatable)
+        #[arg(long, value_name = "KEY")]
+        remove: Vec<String>,
+    },
+
+    /// Manage the authentication keys of a private tracker
+    ///
+    /// In private mode, peers can only announce with a key issued by the
+    /// tracker. These subcommands call the HTTP API of the deployed tracker
+    /// with the admin token of the environment. Added keys are recorded in
+    /// the environment secrets, since the tracker API cannot list them.
+    ///
+    /// EXAMPLES:
+    ///   Issue a key valid for 30 days:
+    ///     torrust-tracker-deployer keys add my-env --duration 30d
+    ///
+    ///   List the issued keys with their expiry times:
+    ///     torrust-tracker-deployer keys list my-env
+    ///
+    ///   Revoke a key:
+    ///     torrust-tracker-deployer keys remove my-env YZSl4lMZupRuOpSRC3krIKR5BPB14nrJ
+    Keys {
+        #[command(subcommand)]
+        action: KeysAction,
+    },
+
+    /// Import an instance deployed outside this tool as a new environment
+    ///
+    /// Creates an environment directly from a name, SSH credentials and an IP
+    /// address, without a configuration file and without provisioning. The
+    /// environment uses the default tracker configuration and starts in the
+    /// Provisioned state, ready for 'configure'.
+    ///
+    /// The instance is marked as externally provisioned: 'destroy' never runs
+    /// 'tofu destroy' for it and only removes local state.
+    ///
+    /// With --detect-existing, the instance is probed over SSH and the
+    /// environment starts in the Configured state when Docker and Docker
+    /// Compose are installed, or in the Running state when a tracker
+    /// container is running.
+    ///
+    /// WHEN TO USE IMPORT VS REGISTER:
+    ///   • import: adopt a server quickly with the default configuration
+    ///   • register: attach a server to an environment created from a
+    ///     configuration file (custom tracker, monitoring, HTTPS settings)
+    ///
+    /// EXAMPLES:
+    ///   Import a manually deployed server and converge it:
+    ///     torrust-tracker-deployer import legacy --ip 10.0.0.5 --ssh-key ~/.ssh/id_ed25519
+    ///     torrust-tracker-deployer configure legacy
+    Import {
+        /// Name of the new environment
+        #[arg(value_name = "NAME")]
+        environment: String,
+
+        /// IP address of the existing instance
+        #[arg(long, value_name = "IP_ADDRESS")]
+        ip: String,
+
+        /// Path to the SSH private key accepted by the instance
+        #[arg(long, value_name = "PATH")]
+        ssh_key: PathBuf,
+
+        /// Path to the SSH public key (defaults to the private key path with a .pub suffix)
+        #[arg(long, value_name = "PATH")]
+        ssh_public_key: Option<PathBuf>,
+
+        /// SSH username with sudo access on the instance
+        #[arg(long, value_name = "USER", default_value = "torrust")]
+        ssh_user: String,
+
+        /// SSH port of the instance
+        #[arg(long, value_name = "PORT", default_value_t = 22)]
+        ssh_port: u16,
+
+        /// Probe the instance to start in the Configured or Running state
+        #[arg(long)]
+        detect_existing: bool,
+    },
 
     /// Generate CLI documentation in JSON format
     ///
@@ -694,6 +1606,61 @@ pub enum Commands {
         #[arg(value_name = "PATH")]
         output_path: Option<PathBuf>,
     },
+
+    /// Generate a shell completion script
+    ///
+    /// Prints a completion script for bash, zsh or fish to stdout. Besides
+    /// subcommands and options, the script completes environment names (e.g.
+    /// `show <TAB>`) with the environments found in the data directory of the
+    /// current working directory.
+    ///
+    /// NOT PART OF DEPLOYMENT WORKFLOW:
+    ///   This is a convenience command for interactive use.
+    ///
+    /// EXAMPLES:
+    ///   Enable completions in the current bash session:
+    ///     source <(torrust-tracker-deployer completions bash)
+    ///
+    ///   Enable completions in the current zsh session:
+    ///     source <(torrust-tracker-deployer completions zsh)
+    ///
+    ///   Install completions for fish:
+    ///     torrust-tracker-deployer completions fish > ~/.config/fish/completions/torrust-tracker-deployer.fish
+    Completions {
+        /// Shell to generate the completion script for
+        shell: CompletionShell,
+    },
+
+    /// Utilities for writing environment configuration files
+    ///
+    /// NOT PART OF DEPLOYMENT WORKFLOW:
+    ///   These subcommands read no workspace and create nothing.
+    ///
+    /// EXAMPLES:
+    ///   Print the JSON Schema of environment configuration files:
+    ///     torrust-tracker-deployer config schema > envs/environment-schema.json
+    Config {
+        #[command(subcommand)]
+        action: ConfigAction,
+    },
+
+    /// Check that a tracker answers, from this machine
+    ///
+    /// NOT PART OF DEPLOYMENT WORKFLOW:
+    ///   These subcommands read no workspace: they can check any tracker,
+    ///   deployed with this tool or not.
+    ///
+    /// EXAMPLES:
+    ///   Announce a random torrent to a UDP tracker:
+    ///     torrust-tracker-deployer probe udp tracker.example.com:6969
+    Probe {
+        #[command(subcommand)]
+        action: ProbeAction,
+    },
+
+    /// List environment names, one per line, for shell completion scripts
+    #[command(name = COMPLETE_ENV_NAMES_COMMAND, hide = true)]
+    CompleteEnvNames,
 }
 /// Actions available for the create command
 #[derive(Debug, Subcommand)]
@@ -723,6 +1690,13 @@ pub enum CreateAction {
         /// required fields for environment creation.
         #[arg(long, short = 'f', value_name = "FILE")]
         env_file: PathBuf,
+
+        /// Generate a new SSH key pair in data/{env-name}/ssh/
+        ///
+        /// Same as `"generate_ssh_keys": true` in the configuration file. The
+        /// key paths in `ssh_credentials` must then be left out.
+        #[arg(long)]
+        generate_ssh_keys: bool,
     },
 
     /// Generate template configuration file
@@ -737,8 +1711,8 @@ pub enum CreateAction {
     ///
     /// AVAILABLE PROVIDERS:
     ///   Templates are provider-specific and include appropriate defaults:
-    ///   • Local VM providers (e.g., LXD) - for development/testing
-    ///   • Cloud providers (e.g., Hetzner) - for production deployments
+    ///   • Local VM providers (e.g., LXD, libvirt) - for development/testing
+    ///   • Cloud providers (e.g., Hetzner, DigitalOcean) - for production deployments
     ///   Each provider template includes provider-specific configuration fields
     ///
     /// CUSTOMIZATION REQUIRED:
@@ -768,6 +1742,9 @@ pub enum CreateAction {
         /// Available providers:
         /// - lxd: Local LXD provider for development and testing
         /// - hetzner: Hetzner Cloud provider for production deployments
+        /// - digitalocean: DigitalOcean provider for production deployments
+        /// - docker: Local Docker container for fast smoke tests (testing only)
+        /// - libvirt: Local KVM virtual machine for development and testing
         #[arg(long, short = 'p', value_enum)]
         provider: Provider,
     },
@@ -824,6 +1801,159 @@ pub enum CreateAction {
     },
 }
 
+/// Actions available for the config command
+#[derive(Debug, Subcommand)]
+pub enum ConfigAction {
+    /// Print the JSON Schema of environment configuration files
+    ///
+    /// The schema covers every provider, the tracker sections and the
+    /// forms a secret can take, with the documentation of each field. Save
+    /// it next to your configuration files and reference it with `$schema`
+    /// to get autocompletion and validation in editors:
+    ///
+    ///   {
+    ///     "$schema": "./environment-schema.json",
+    ///     "environment": { "name": "production" },
+    ///     ...
+    ///   }
+    ///
+    /// The `$schema` field is ignored when the configuration is loaded.
+    ///
+    /// EXAMPLES:
+    ///   torrust-tracker-deployer config schema > envs/environment-schema.json
+    Schema,
+}
+
+/// Actions available for the keys command
+#[derive(Debug, Subcommand)]
+pub enum KeysAction {
+    /// Issue a new key on the tracker of an environment
+    ///
+    /// The environment must be running a private tracker. Without
+    /// --duration the key never expires.
+    ///
+    /// EXAMPLES:
+    ///   torrust-tracker-deployer keys add my-env --duration 30d
+    ///   torrust-tracker-deployer keys add my-env
+    Add {
+        /// Name of the environment
+        #[arg(value_name = ENVIRONMENT_VALUE_NAME)]
+        environment: String,
+
+        /// How long the key is valid (e.g. 12h, 30d, 2w)
+        #[arg(long, value_parser = parse_age)]
+        duration: Option<Duration>,
+    },
+
+    /// List the keys issued with this tool, with their expiry times
+    ///
+    /// Reads the keys recorded in the environment secrets; the tracker is
+    /// not contacted.
+    ///
+    /// EXAMPLES:
+    ///   torrust-tracker-deployer keys list my-env
+    List {
+        /// Name of the environment
+        #[arg(value_name = ENVIRONMENT_VALUE_NAME)]
+        environment: String,
+    },
+
+    /// Revoke a key on the tracker of an environment
+    ///
+    /// EXAMPLES:
+    ///   torrust-tracker-deployer keys remove my-env YZSl4lMZupRuOpSRC3krIKR5BPB14nrJ
+    Remove {
+        /// Name of the environment
+        #[arg(value_name = ENVIRONMENT_VALUE_NAME)]
+        environment: String,
+
+        /// The key to revoke (32 alphanumeric characters)
+        key: String,
+    },
+}
+
+/// Actions available for the probe command
+#[derive(Debug, Subcommand)]
+pub enum ProbeAction {
+    /// Announce a random torrent to a UDP tracker (BEP 15)
+    ///
+    /// Sends a connect request then an announce request with a random
+    /// info-hash and peer ID, and prints the announce interval and the peer
+    /// counts of the response. An unanswered attempt is retried with the
+    /// exponential backoff of BEP 15: each retry waits twice as long as the
+    /// previous one.
+    ///
+    /// EXIT CODES:
+    ///   • 0: The tracker answered the announce
+    ///   • Non-zero: The address is invalid, the tracker did not answer or
+    ///     answered with an error or a malformed packet
+    ///
+    /// EXAMPLES:
+    ///   torrust-tracker-deployer probe udp tracker.example.com:6969
+    ///   torrust-tracker-deployer probe udp 1.2.3.4:6969 --timeout 2 --retries 4
+    Udp {
+        /// Address of the tracker, as `host:port` (e.g. `1.2.3.4:6969`)
+        tracker: String,
+
+        /// Time to wait for the first response, in seconds
+        #[arg(long, value_name = "SECONDS", default_value_t = 5, value_parser = clap::value_parser!(u64).range(1..))]
+        timeout: u64,
+
+        /// Number of retries after the first attempt
+        #[arg(long, value_name = "N", default_value_t = 2, value_parser = clap::value_parser!(u32).range(..=8))]
+        retries: u32,
+    },
+}
+
+impl Commands {
+    /// Whether the command operates on the workspace of the working directory
+    ///
+    /// These commands only run in an initialized workspace, unless
+    /// `--allow-uninitialized` is given. The others either create the
+    /// workspace (`init`) or do not use it: they read a configuration file,
+    /// write to a path given to them or print generated content.
+    #[must_use]
+    pub fn requires_workspace(&self) -> bool {
+        !matches!(
+            self,
+            Self::Init
+                | Self::Doctor
+                | Self::Validate { .. }
+                | Self::Docs { .. }
+                | Self::Completions { .. }
+                | Self::Config { .. }
+                | Self::Probe { .. }
+                | Self::CompleteEnvNames
+                | Self::Create {
+                    action: Some(CreateAction::Template { .. } | CreateAction::Schema { .. }),
+                    ..
+                }
+                | Self::Render {
+                    env_file: Some(_),
+                    ..
+                }
+        )
+    }
+
+    /// Whether the command stops cleanly when the user presses Ctrl-C
+    ///
+    /// These commands watch the cancellation token: they stop waiting and
+    /// save the environment state before exiting. Ctrl-C terminates the
+    /// others right away.
+    #[must_use]
+    pub fn handles_interrupts(&self) -> bool {
+        matches!(
+            self,
+            Self::Provision { .. }
+                | Self::Configure { .. }
+                | Self::Deploy { .. }
+                | Self::Run { .. }
+                | Self::Start { .. }
+                | Self::Restore { .. }
+        )
+    }
+}
+
 impl CreateAction {
     /// Get the default template output path
     #[must_use]
@@ -836,6 +1966,60 @@ impl CreateAction {
 mod tests {
     use super::*;
 
+    fn parse(args: &[&str]) -> Commands {
+        use clap::Parser;
+
+        let cli = crate::presentation::cli::Cli::try_parse_from(
+            std::iter::once("torrust-tracker-deployer").chain(args.iter().copied()),
+        )
+        .unwrap();
+        cli.command.unwrap()
+    }
+
+    #[test]
+    fn it_should_only_handle_interrupts_for_commands_watching_the_cancellation_token() {
+        assert!(parse(&["provision", "prod"]).handles_interrupts());
+        assert!(parse(&["run", "prod"]).handles_interrupts());
+        assert!(parse(&["start", "prod"]).handles_interrupts());
+        assert!(!parse(&["release", "prod"]).handles_interrupts());
+        assert!(!parse(&["list"]).handles_interrupts());
+    }
+
+    #[test]
+    fn it_should_require_a_workspace_for_commands_using_environments() {
+        assert!(parse(&["list"]).requires_workspace());
+        assert!(parse(&["create", "environment", "--env-file", "env.json"]).requires_workspace());
+        assert!(parse(&[
+            "render",
+            "--env-name",
+            "prod",
+            "--instance-ip",
+            "10.0.0.1",
+            "--output-dir",
+            "out"
+        ])
+        .requires_workspace());
+    }
+
+    #[test]
+    fn it_should_not_require_a_workspace_for_init_and_commands_not_using_it() {
+        assert!(!parse(&["init"]).requires_workspace());
+        assert!(!parse(&["doctor"]).requires_workspace());
+        assert!(!parse(&["probe", "udp", "1.2.3.4:6969"]).requires_workspace());
+        assert!(!parse(&["validate", "--env-file", "env.json"]).requires_workspace());
+        assert!(!parse(&["create", "template", "--provider", "lxd"]).requires_workspace());
+        assert!(!parse(&[
+            "render",
+            "--env-file",
+            "env.json",
+            "--instance-ip",
+            "10.0.0.1",
+            "--output-dir",
+            "out"
+        ])
+        .requires_workspace());
+    }
+
     #[test]
     fn it_should_use_default_template_path() {
         let default_path = CreateAction::default_template_path();
@@ -8,11 +8,13 @@ use clap::Parser;
 
 // Re-export submodules for convenient access
 pub mod args;
+pub mod color_choice;
 pub mod commands;
 pub mod output_format;
 
-pub use args::GlobalArgs;
-pub use commands::{Commands, CreateAction};
+pub use args::{AnsibleArgs, CreateWizardArgs, GlobalArgs, WaitArgs, WizardDatabase};
+pub use color_choice::ColorChoice;
+pub use commands::{Commands, ConfigAction, CreateAction, KeysAction, ProbeAction};
 pub use output_format::OutputFormat;
 
 /// Command-line interface for Torrust Tracker Deployer
@@ -37,7 +39,11 @@ pub struct Cli {
 
 #[cfg(test)]
 mod tests {
+    use std::num::NonZeroUsize;
+    use std::time::Duration;
+
     use super::*;
+    use crate::application::command_handlers::deploy::DeployTarget;
 
     #[test]
     fn it_should_parse_destroy_subcommand() {
@@ -46,8 +52,20 @@ mod tests {
 
         assert!(cli.command.is_some());
         match cli.command.unwrap() {
-            Commands::Destroy { environment } => {
-                assert_eq!(environment, "test-env");
+            Commands::Destroy {
+                environment,
+                label,
+                yes,
+                parallel,
+                dry_run,
+                refresh,
+            } => {
+                assert_eq!(environment.as_deref(), Some("test-env"));
+                assert!(label.is_empty());
+                assert!(!yes);
+                assert_eq!(parallel, None);
+                assert!(!dry_run);
+                assert!(!refresh);
             }
             Commands::Create { .. }
             | Commands::Provision { .. }
@@ -55,14 +73,41 @@ mod tests {
             | Commands::Test { .. }
             | Commands::Register { .. }
             | Commands::Release { .. }
+            | Commands::Rollback { .. }
+            | Commands::Deploy { .. }
             | Commands::Run { .. }
             | Commands::Show { .. }
-            | Commands::List
+            | Commands::List { .. }
+            | Commands::Orphans { .. }
+            | Commands::Doctor
+            | Commands::Repair { .. }
+            | Commands::Clone { .. }
+            | Commands::Rename { .. }
+            | Commands::Export { .. }
+            | Commands::ImportBundle { .. }
+            | Commands::Label { .. }
+            | Commands::Keys { .. }
+            | Commands::Init
+            | Commands::Import { .. }
+            | Commands::Diff { .. }
+            | Commands::Logs { .. }
+            | Commands::Backup { .. }
+            | Commands::Drift { .. }
+            | Commands::Stop { .. }
+            | Commands::Start { .. }
+            | Commands::Restore { .. }
+            | Commands::Reports { .. }
+            | Commands::Stats { .. }
             | Commands::Purge { .. }
             | Commands::Validate { .. }
             | Commands::Render { .. }
             | Commands::Exists { .. }
-            | Commands::Docs { .. } => {
+            | Commands::Ssh { .. }
+            | Commands::Docs { .. }
+            | Commands::Completions { .. }
+            | Commands::Config { .. }
+            | Commands::Probe { .. }
+            | Commands::CompleteEnvNames => {
                 panic!("Expected Destroy command")
             }
         }
@@ -77,8 +122,8 @@ mod tests {
             let cli = Cli::try_parse_from(args).unwrap();
 
             match cli.command.unwrap() {
-                Commands::Destroy { environment } => {
-                    assert_eq!(environment, env_name);
+                Commands::Destroy { environment, .. } => {
+                    assert_eq!(environment.as_deref(), Some(env_name));
                 }
                 Commands::Create { .. }
                 | Commands::Provision { .. }
@@ -86,14 +131,41 @@ mod tests {
                 | Commands::Test { .. }
                 | Commands::Register { .. }
                 | Commands::Release { .. }
+                | Commands::Rollback { .. }
+                | Commands::Deploy { .. }
                 | Commands::Run { .. }
                 | Commands::Show { .. }
-                | Commands::List
+                | Commands::List { .. }
+                | Commands::Orphans { .. }
+                | Commands::Doctor
+                | Commands::Repair { .. }
+                | Commands::Clone { .. }
+                | Commands::Rename { .. }
+                | Commands::Export { .. }
+                | Commands::ImportBundle { .. }
+                | Commands::Label { .. }
+                | Commands::Keys { .. }
+                | Commands::Init
+                | Commands::Import { .. }
+                | Commands::Diff { .. }
+                | Commands::Logs { .. }
+                | Commands::Backup { .. }
+                | Commands::Drift { .. }
+                | Commands::Stop { .. }
+                | Commands::Start { .. }
+                | Commands::Restore { .. }
+                | Commands::Reports { .. }
+                | Commands::Stats { .. }
                 | Commands::Purge { .. }
                 | Commands::Validate { .. }
                 | Commands::Render { .. }
                 | Commands::Exists { .. }
-                | Commands::Docs { .. } => {
+                | Commands::Ssh { .. }
+                | Commands::Docs { .. }
+                | Commands::Completions { .. }
+                | Commands::Config { .. }
+                | Commands::Probe { .. }
+                | Commands::CompleteEnvNames => {
                     panic!("Expected Destroy command")
                 }
             }
@@ -133,8 +205,8 @@ mod tests {
 
         // Verify the destroy command was parsed correctly
         match cli.command.unwrap() {
-            Commands::Destroy { environment } => {
-                assert_eq!(environment, "test-env");
+            Commands::Destroy { environment, .. } => {
+                assert_eq!(environment.as_deref(), Some("test-env"));
             }
             Commands::Create { .. }
             | Commands::Provision { .. }
@@ -142,20 +214,50 @@ mod tests {
             | Commands::Test { .. }
             | Commands::Register { .. }
             | Commands::Release { .. }
+            | Commands::Rollback { .. }
+            | Commands::Deploy { .. }
             | Commands::Run { .. }
             | Commands::Show { .. }
-            | Commands::List
+            | Commands::List { .. }
+            | Commands::Orphans { .. }
+            | Commands::Doctor
+            | Commands::Repair { .. }
+            | Commands::Clone { .. }
+            | Commands::Rename { .. }
+            | Commands::Export { .. }
+            | Commands::ImportBundle { .. }
+            | Commands::Label { .. }
+            | Commands::Keys { .. }
+            | Commands::Init
+            | Commands::Import { .. }
+            | Commands::Diff { .. }
+            | Commands::Logs { .. }
+            | Commands::Backup { .. }
+            | Commands::Drift { .. }
+            | Commands::Stop { .. }
+            | Commands::Start { .. }
+            | Commands::Restore { .. }
+            | Commands::Reports { .. }
+            | Commands::Stats { .. }
             | Commands::Purge { .. }
             | Commands::Validate { .. }
             | Commands::Render { .. }
             | Commands::Exists { .. }
-            | Commands::Docs { .. } => {
+            | Commands::Ssh { .. }
+            | Commands::Docs { .. }
+            | Commands::Completions { .. }
+            | Commands::Config { .. }
+            | Commands::Probe { .. }
+            | Commands::CompleteEnvNames => {
                 panic!("Expected Destroy command")
             }
         }
 
         // Log options are set but we don't compare them as they don't implement PartialEq
-        assert_eq!(cli.global.log_dir, std::path::PathBuf::from("/tmp/logs"));
+        assert_eq!(
+            cli.global.log_dir,
+            Some(std::path::PathBuf::from("/tmp/logs"))
+        );
     }
 
     #[test]
@@ -163,7 +265,80 @@ mod tests {
         let args = vec!["torrust-tracker-deployer", "destroy", "test-env"];
         let cli = Cli::try_parse_from(args).unwrap();
 
-        assert_eq!(cli.global.log_dir, std::path::PathBuf::from("./data/logs"));
+        assert_eq!(cli.global.log_dir, None);
+        assert_eq!(
+            cli.global.log_dir(),
+            std::path::PathBuf::from("./data/logs")
+        );
+    }
+
+    #[test]
+    fn it_should_apply_the_log_format_to_file_and_stderr() {
+        use crate::bootstrap::logging::LogFormat;
+
+        let args = vec![
+            "torrust-tracker-deployer",
+            "--log-format",
+            "json",
+            "destroy",
+            "test-env",
+        ];
+        let cli = Cli::try_parse_from(args).unwrap();
+
+        let config = cli.global.logging_config();
+
+        assert!(matches!(config.file_format, LogFormat::Json));
+        assert!(matches!(config.stderr_format, LogFormat::Json));
+    }
+
+    #[test]
+    fn it_should_reject_the_log_format_together_with_a_specific_format() {
+        let args = vec![
+            "torrust-tracker-deployer",
+            "--log-format",
+            "json",
+            "--log-stderr-format",
+            "pretty",
+            "destroy",
+            "test-env",
+        ];
+
+        let error = Cli::try_parse_from(args).unwrap_err();
+
+        assert_eq!(error.kind(), clap::error::ErrorKind::ArgumentConflict);
+    }
+
+    #[test]
+    fn it_should_parse_the_otlp_endpoint() {
+        let args = vec![
+            "torrust-tracker-deployer",
+            "provision",
+            "test-env",
+            "--otlp-endpoint",
+            "http://localhost:4318",
+        ];
+        let cli = Cli::try_parse_from(args).unwrap();
+
+        let config = cli.global.logging_config();
+
+        assert_eq!(
+            config.otlp_endpoint.map(String::from),
+            Some("http://localhost:4318/".to_string())
+        );
+    }
+
+    #[test]
+    fn it_should_reject_an_otlp_endpoint_that_is_not_a_url() {
+        let args = vec![
+            "torrust-tracker-deployer",
+            "--otlp-endpoint",
+            "tempo.local/otlp",
+            "list",
+        ];
+
+        let result = Cli::try_parse_from(args);
+
+        assert!(result.is_err());
     }
 
     #[test]
@@ -227,29 +402,61 @@ mod tests {
 
         assert!(cli.command.is_some());
         match cli.command.unwrap() {
-            Commands::Create { action } => match action {
-                crate::presentation::cli::input::cli::CreateAction::Environment { env_file } => {
-                    assert_eq!(env_file, std::path::PathBuf::from("config.json"));
+            Commands::Create { action, .. } => {
+                match action.expect("Expected a create subcommand") {
+                    crate::presentation::cli::input::cli::CreateAction::Environment {
+                        env_file,
+                        ..
+                    } => {
+                        assert_eq!(env_file, std::path::PathBuf::from("config.json"));
+                    }
+                    crate::presentation::cli::input::cli::CreateAction::Template { .. }
+                    | crate::presentation::cli::input::cli::CreateAction::Schema { .. } => {
+                        panic!("Expected Environment action")
+                    }
                 }
-                crate::presentation::cli::input::cli::CreateAction::Template { .. }
-                | crate::presentation::cli::input::cli::CreateAction::Schema { .. } => {
-                    panic!("Expected Environment action")
-                }
-            },
+            }
             Commands::Destroy { .. }
             | Commands::Provision { .. }
             | Commands::Configure { .. }
             | Commands::Test { .. }
             | Commands::Register { .. }
             | Commands::Release { .. }
+            | Commands::Rollback { .. }
+            | Commands::Deploy { .. }
             | Commands::Run { .. }
             | Commands::Show { .. }
-            | Commands::List
+            | Commands::List { .. }
+            | Commands::Orphans { .. }
+            | Commands::Doctor
+            | Commands::Repair { .. }
+            | Commands::Clone { .. }
+            | Commands::Rename { .. }
+            | Commands::Export { .. }
+            | Commands::ImportBundle { .. }
+            | Commands::Label { .. }
+            | Commands::Keys { .. }
+            | Commands::Init
+            | Commands::Import { .. }
+            | Commands::Diff { .. }
+            | Commands::Logs { .. }
+            | Commands::Backup { .. }
+            | Commands::Drift { .. }
+            | Commands::Stop { .. }
+            | Commands::Start { .. }
+            | Commands::Restore { .. }
+            | Commands::Reports { .. }
+            | Commands::Stats { .. }
             | Commands::Purge { .. }
             | Commands::Validate { .. }
             | Commands::Render { .. }
             | Commands::Exists { .. }
-            | Commands::Docs { .. } => {
+            | Commands::Ssh { .. }
+            | Commands::Docs { .. }
+            | Commands::Completions { .. }
+            | Commands::Config { .. }
+            | Commands::Probe { .. }
+            | Commands::CompleteEnvNames => {
                 panic!("Expected Create command")
             }
         }
@@ -267,29 +474,61 @@ mod tests {
         let cli = Cli::try_parse_from(args).unwrap();
 
         match cli.command.unwrap() {
-            Commands::Create { action } => match action {
-                crate::presentation::cli::input::cli::CreateAction::Environment { env_file } => {
-                    assert_eq!(env_file, std::path::PathBuf::from("env.json"));
-                }
-                crate::presentation::cli::input::cli::CreateAction::Template { .. }
-                | crate::presentation::cli::input::cli::CreateAction::Schema { .. } => {
-                    panic!("Expected Environment action")
+            Commands::Create { action, .. } => {
+                match action.expect("Expected a create subcommand") {
+                    crate::presentation::cli::input::cli::CreateAction::Environment {
+                        env_file,
+                        ..
+                    } => {
+                        assert_eq!(env_file, std::path::PathBuf::from("env.json"));
+                    }
+                    crate::presentation::cli::input::cli::CreateAction::Template { .. }
+                    | crate::presentation::cli::input::cli::CreateAction::Schema { .. } => {
+                        panic!("Expected Environment action")
+                    }
                 }
-            },
+            }
             Commands::Destroy { .. }
             | Commands::Provision { .. }
             | Commands::Configure { .. }
             | Commands::Test { .. }
             | Commands::Register { .. }
             | Commands::Release { .. }
+            | Commands::Rollback { .. }
+            | Commands::Deploy { .. }
             | Commands::Run { .. }
             | Commands::Show { .. }
-            | Commands::List
+            | Commands::List { .. }
+            | Commands::Orphans { .. }
+            | Commands::Doctor
+            | Commands::Repair { .. }
+            | Commands::Clone { .. }
+            | Commands::Rename { .. }
+            | Commands::Export { .. }
+            | Commands::ImportBundle { .. }
+            | Commands::Label { .. }
+            | Commands::Keys { .. }
+            | Commands::Init
+            | Commands::Import { .. }
+            | Commands::Diff { .. }
+            | Commands::Logs { .. }
+            | Commands::Backup { .. }
+            | Commands::Drift { .. }
+            | Commands::Stop { .. }
+            | Commands::Start { .. }
+            | Commands::Restore { .. }
+            | Commands::Reports { .. }
+            | Commands::Stats { .. }
             | Commands::Purge { .. }
             | Commands::Validate { .. }
             | Commands::Render { .. }
             | Commands::Exists { .. }
-            | Commands::Docs { .. } => {
+            | Commands::Ssh { .. }
+            | Commands::Docs { .. }
+            | Commands::Completions { .. }
+            | Commands::Config { .. }
+            | Commands::Probe { .. }
+            | Commands::CompleteEnvNames => {
                 panic!("Expected Create command")
             }
         }
@@ -323,34 +562,66 @@ mod tests {
         let cli = Cli::try_parse_from(args).unwrap();
 
         assert_eq!(
-            cli.global.working_dir,
-            std::path::PathBuf::from("/tmp/workspace")
+            cli.global.workspace,
+            Some(std::path::PathBuf::from("/tmp/workspace"))
         );
 
         match cli.command.unwrap() {
-            Commands::Create { action } => match action {
-                crate::presentation::cli::input::cli::CreateAction::Environment { env_file } => {
-                    assert_eq!(env_file, std::path::PathBuf::from("config.json"));
-                }
-                crate::presentation::cli::input::cli::CreateAction::Template { .. }
-                | crate::presentation::cli::input::cli::CreateAction::Schema { .. } => {
-                    panic!("Expected Environment action")
+            Commands::Create { action, .. } => {
+                match action.expect("Expected a create subcommand") {
+                    crate::presentation::cli::input::cli::CreateAction::Environment {
+                        env_file,
+                        ..
+                    } => {
+                        assert_eq!(env_file, std::path::PathBuf::from("config.json"));
+                    }
+                    crate::presentation::cli::input::cli::CreateAction::Template { .. }
+                    | crate::presentation::cli::input::cli::CreateAction::Schema { .. } => {
+                        panic!("Expected Environment action")
+                    }
                 }
-            },
+            }
             Commands::Destroy { .. }
             | Commands::Provision { .. }
             | Commands::Configure { .. }
             | Commands::Test { .. }
             | Commands::Register { .. }
             | Commands::Release { .. }
+            | Commands::Rollback { .. }
+            | Commands::Deploy { .. }
             | Commands::Run { .. }
             | Commands::Show { .. }
-            | Commands::List
+            | Commands::List { .. }
+            | Commands::Orphans { .. }
+            | Commands::Doctor
+            | Commands::Repair { .. }
+            | Commands::Clone { .. }
+            | Commands::Rename { .. }
+            | Commands::Export { .. }
+            | Commands::ImportBundle { .. }
+            | Commands::Label { .. }
+            | Commands::Keys { .. }
+            | Commands::Init
+            | Commands::Import { .. }
+            | Commands::Diff { .. }
+            | Commands::Logs { .. }
+            | Commands::Backup { .. }
+            | Commands::Drift { .. }
+            | Commands::Stop { .. }
+            | Commands::Start { .. }
+            | Commands::Restore { .. }
+            | Commands::Reports { .. }
+            | Commands::Stats { .. }
             | Commands::Purge { .. }
             | Commands::Validate { .. }
             | Commands::Render { .. }
             | Commands::Exists { .. }
-            | Commands::Docs { .. } => {
+            | Commands::Ssh { .. }
+            | Commands::Docs { .. }
+            | Commands::Completions { .. }
+            | Commands::Config { .. }
+            | Commands::Probe { .. }
+            | Commands::CompleteEnvNames => {
                 panic!("Expected Create command")
             }
         }
@@ -367,9 +638,26 @@ mod tests {
         ];
         let cli = Cli::try_parse_from(args).unwrap();
 
+        assert_eq!(cli.global.workspace, None);
         assert_eq!(cli.global.working_dir, std::path::PathBuf::from("."));
     }
 
+    #[test]
+    fn it_should_parse_the_workspace_global_option_after_the_subcommand() {
+        let args = vec![
+            "torrust-tracker-deployer",
+            "list",
+            "--workspace",
+            "/srv/deployer",
+        ];
+        let cli = Cli::try_parse_from(args).unwrap();
+
+        assert_eq!(
+            cli.global.workspace,
+            Some(std::path::PathBuf::from("/srv/deployer"))
+        );
+    }
+
     #[test]
     fn it_should_show_create_help() {
         let args = vec!["torrust-tracker-deployer", "create", "--help"];
@@ -386,6 +674,43 @@ mod tests {
         );
     }
 
+    #[test]
+    fn it_should_parse_create_interactive_with_answer_flags() {
+        let args = vec![
+            "torrust-tracker-deployer",
+            "create",
+            "--interactive",
+            "--name",
+            "my-env",
+            "--provider",
+            "hetzner",
+            "--udp-port",
+            "6868",
+        ];
+        let cli = Cli::try_parse_from(args).unwrap();
+
+        let Some(Commands::Create { wizard, action }) = cli.command else {
+            panic!("Expected Create command");
+        };
+        assert!(action.is_none());
+        assert!(wizard.interactive);
+        assert_eq!(wizard.name.as_deref(), Some("my-env"));
+        assert_eq!(
+            wizard.provider,
+            Some(crate::domain::provider::Provider::Hetzner)
+        );
+        assert_eq!(wizard.udp_port, Some(6868));
+    }
+
+    #[test]
+    fn it_should_require_interactive_for_wizard_flags() {
+        let args = vec!["torrust-tracker-deployer", "create", "--name", "my-env"];
+        let result = Cli::try_parse_from(args);
+
+        assert!(result.is_err());
+        assert!(result.unwrap_err().to_string().contains("--interactive"));
+    }
+
     #[test]
     fn it_should_require_provider_for_create_template() {
         let args = vec!["torrust-tracker-deployer", "create", "template"];
@@ -414,33 +739,62 @@ mod tests {
         let cli = Cli::try_parse_from(args).unwrap();
 
         match cli.command.unwrap() {
-            Commands::Create { action } => match action {
-                crate::presentation::cli::input::cli::CreateAction::Template {
-                    output_path,
-                    provider,
-                } => {
-                    assert!(output_path.is_none());
-                    assert_eq!(provider, Provider::Lxd);
-                }
-                crate::presentation::cli::input::cli::CreateAction::Environment { .. }
-                | crate::presentation::cli::input::cli::CreateAction::Schema { .. } => {
-                    panic!("Expected Template action")
+            Commands::Create { action, .. } => {
+                match action.expect("Expected a create subcommand") {
+                    crate::presentation::cli::input::cli::CreateAction::Template {
+                        output_path,
+                        provider,
+                    } => {
+                        assert!(output_path.is_none());
+                        assert_eq!(provider, Provider::Lxd);
+                    }
+                    crate::presentation::cli::input::cli::CreateAction::Environment { .. }
+                    | crate::presentation::cli::input::cli::CreateAction::Schema { .. } => {
+                        panic!("Expected Template action")
+                    }
                 }
-            },
+            }
             Commands::Destroy { .. }
             | Commands::Provision { .. }
             | Commands::Configure { .. }
             | Commands::Test { .. }
             | Commands::Register { .. }
             | Commands::Release { .. }
+            | Commands::Rollback { .. }
+            | Commands::Deploy { .. }
             | Commands::Run { .. }
             | Commands::Show { .. }
-            | Commands::List
+            | Commands::List { .. }
+            | Commands::Orphans { .. }
+            | Commands::Doctor
+            | Commands::Repair { .. }
+            | Commands::Clone { .. }
+            | Commands::Rename { .. }
+            | Commands::Export { .. }
+            | Commands::ImportBundle { .. }
+            | Commands::Label { .. }
+            | Commands::Keys { .. }
+            | Commands::Init
+            | Commands::Import { .. }
+            | Commands::Diff { .. }
+            | Commands::Logs { .. }
+            | Commands::Backup { .. }
+            | Commands::Drift { .. }
+            | Commands::Stop { .. }
+            | Commands::Start { .. }
+            | Commands::Restore { .. }
+            | Commands::Reports { .. }
+            | Commands::Stats { .. }
             | Commands::Purge { .. }
             | Commands::Validate { .. }
             | Commands::Render { .. }
             | Commands::Exists { .. }
-            | Commands::Docs { .. } => {
+            | Commands::Ssh { .. }
+            | Commands::Docs { .. }
+            | Commands::Completions { .. }
+            | Commands::Config { .. }
+            | Commands::Probe { .. }
+            | Commands::CompleteEnvNames => {
                 panic!("Expected Create command")
             }
         }
@@ -459,35 +813,64 @@ mod tests {
         let cli = Cli::try_parse_from(args).unwrap();
 
         match cli.command.unwrap() {
-            Commands::Create { action } => match action {
-                crate::presentation::cli::input::cli::CreateAction::Template {
-                    output_path,
-                    ..
-                } => {
-                    assert_eq!(
+            Commands::Create { action, .. } => {
+                match action.expect("Expected a create subcommand") {
+                    crate::presentation::cli::input::cli::CreateAction::Template {
                         output_path,
-                        Some(std::path::PathBuf::from("./config/my-env.json"))
-                    );
+                        ..
+                    } => {
+                        assert_eq!(
+                            output_path,
+                            Some(std::path::PathBuf::from("./config/my-env.json"))
+                        );
+                    }
+                    crate::presentation::cli::input::cli::CreateAction::Environment { .. }
+                    | crate::presentation::cli::input::cli::CreateAction::Schema { .. } => {
+                        panic!("Expected Template action")
+                    }
                 }
-                crate::presentation::cli::input::cli::CreateAction::Environment { .. }
-                | crate::presentation::cli::input::cli::CreateAction::Schema { .. } => {
-                    panic!("Expected Template action")
-                }
-            },
+            }
             Commands::Destroy { .. }
             | Commands::Provision { .. }
             | Commands::Configure { .. }
             | Commands::Test { .. }
             | Commands::Register { .. }
             | Commands::Release { .. }
+            | Commands::Rollback { .. }
+            | Commands::Deploy { .. }
             | Commands::Run { .. }
             | Commands::Show { .. }
-            | Commands::List
+            | Commands::List { .. }
+            | Commands::Orphans { .. }
+            | Commands::Doctor
+            | Commands::Repair { .. }
+            | Commands::Clone { .. }
+            | Commands::Rename { .. }
+            | Commands::Export { .. }
+            | Commands::ImportBundle { .. }
+            | Commands::Label { .. }
+            | Commands::Keys { .. }
+            | Commands::Init
+            | Commands::Import { .. }
+            | Commands::Diff { .. }
+            | Commands::Logs { .. }
+            | Commands::Backup { .. }
+            | Commands::Drift { .. }
+            | Commands::Stop { .. }
+            | Commands::Start { .. }
+            | Commands::Restore { .. }
+            | Commands::Reports { .. }
+            | Commands::Stats { .. }
             | Commands::Purge { .. }
             | Commands::Validate { .. }
             | Commands::Render { .. }
             | Commands::Exists { .. }
-            | Commands::Docs { .. } => {
+            | Commands::Ssh { .. }
+            | Commands::Docs { .. }
+            | Commands::Completions { .. }
+            | Commands::Config { .. }
+            | Commands::Probe { .. }
+            | Commands::CompleteEnvNames => {
                 panic!("Expected Create command")
             }
         }
@@ -507,17 +890,20 @@ mod tests {
         let cli = Cli::try_parse_from(args).unwrap();
 
         match cli.command.unwrap() {
-            Commands::Create { action } => match action {
-                crate::presentation::cli::input::cli::CreateAction::Template {
-                    provider, ..
-                } => {
-                    assert_eq!(provider, Provider::Lxd);
+            Commands::Create { action, .. } => {
+                match action.expect("Expected a create subcommand") {
+                    crate::presentation::cli::input::cli::CreateAction::Template {
+                        provider,
+                        ..
+                    } => {
+                        assert_eq!(provider, Provider::Lxd);
+                    }
+                    crate::presentation::cli::input::cli::CreateAction::Environment { .. }
+                    | crate::presentation::cli::input::cli::CreateAction::Schema { .. } => {
+                        panic!("Expected Template action")
+                    }
                 }
-                crate::presentation::cli::input::cli::CreateAction::Environment { .. }
-                | crate::presentation::cli::input::cli::CreateAction::Schema { .. } => {
-                    panic!("Expected Template action")
-                }
-            },
+            }
             _ => panic!("Expected Create command"),
         }
     }
@@ -536,17 +922,20 @@ mod tests {
         let cli = Cli::try_parse_from(args).unwrap();
 
         match cli.command.unwrap() {
-            Commands::Create { action } => match action {
-                crate::presentation::cli::input::cli::CreateAction::Template {
-                    provider, ..
-                } => {
-                    assert_eq!(provider, Provider::Hetzner);
+            Commands::Create { action, .. } => {
+                match action.expect("Expected a create subcommand") {
+                    crate::presentation::cli::input::cli::CreateAction::Template {
+                        provider,
+                        ..
+                    } => {
+                        assert_eq!(provider, Provider::Hetzner);
+                    }
+                    crate::presentation::cli::input::cli::CreateAction::Environment { .. }
+                    | crate::presentation::cli::input::cli::CreateAction::Schema { .. } => {
+                        panic!("Expected Template action")
+                    }
                 }
-                crate::presentation::cli::input::cli::CreateAction::Environment { .. }
-                | crate::presentation::cli::input::cli::CreateAction::Schema { .. } => {
-                    panic!("Expected Template action")
-                }
-            },
+            }
             _ => panic!("Expected Create command"),
         }
     }
@@ -565,17 +954,20 @@ mod tests {
         let cli = Cli::try_parse_from(args).unwrap();
 
         match cli.command.unwrap() {
-            Commands::Create { action } => match action {
-                crate::presentation::cli::input::cli::CreateAction::Template {
-                    provider, ..
-                } => {
-                    assert_eq!(provider, Provider::Hetzner);
-                }
-                crate::presentation::cli::input::cli::CreateAction::Environment { .. }
-                | crate::presentation::cli::input::cli::CreateAction::Schema { .. } => {
-                    panic!("Expected Template action")
+            Commands::Create { action, .. } => {
+                match action.expect("Expected a create subcommand") {
+                    crate::presentation::cli::input::cli::CreateAction::Template {
+                        provider,
+                        ..
+                    } => {
+                        assert_eq!(provider, Provider::Hetzner);
+                    }
+                    crate::presentation::cli::input::cli::CreateAction::Environment { .. }
+                    | crate::presentation::cli::input::cli::CreateAction::Schema { .. } => {
+                        panic!("Expected Template action")
+                    }
                 }
-            },
+            }
             _ => panic!("Expected Create command"),
         }
     }
@@ -595,22 +987,24 @@ mod tests {
         let cli = Cli::try_parse_from(args).unwrap();
 
         match cli.command.unwrap() {
-            Commands::Create { action } => match action {
-                crate::presentation::cli::input::cli::CreateAction::Template {
-                    output_path,
-                    provider,
-                } => {
-                    assert_eq!(
+            Commands::Create { action, .. } => {
+                match action.expect("Expected a create subcommand") {
+                    crate::presentation::cli::input::cli::CreateAction::Template {
                         output_path,
-                        Some(std::path::PathBuf::from("my-config.json"))
-                    );
-                    assert_eq!(provider, Provider::Hetzner);
-                }
-                crate::presentation::cli::input::cli::CreateAction::Environment { .. }
-                | crate::presentation::cli::input::cli::CreateAction::Schema { .. } => {
-                    panic!("Expected Template action")
+                        provider,
+                    } => {
+                        assert_eq!(
+                            output_path,
+                            Some(std::path::PathBuf::from("my-config.json"))
+                        );
+                        assert_eq!(provider, Provider::Hetzner);
+                    }
+                    crate::presentation::cli::input::cli::CreateAction::Environment { .. }
+                    | crate::presentation::cli::input::cli::CreateAction::Schema { .. } => {
+                        panic!("Expected Template action")
+                    }
                 }
-            },
+            }
             _ => panic!("Expected Create command"),
         }
     }
@@ -679,14 +1073,41 @@ mod tests {
             | Commands::Configure { .. }
             | Commands::Test { .. }
             | Commands::Release { .. }
+            | Commands::Rollback { .. }
+            | Commands::Deploy { .. }
             | Commands::Run { .. }
             | Commands::Show { .. }
-            | Commands::List
+            | Commands::List { .. }
+            | Commands::Orphans { .. }
+            | Commands::Doctor
+            | Commands::Repair { .. }
+            | Commands::Clone { .. }
+            | Commands::Rename { .. }
+            | Commands::Export { .. }
+            | Commands::ImportBundle { .. }
+            | Commands::Label { .. }
+            | Commands::Keys { .. }
+            | Commands::Init
+            | Commands::Import { .. }
+            | Commands::Diff { .. }
+            | Commands::Logs { .. }
+            | Commands::Backup { .. }
+            | Commands::Drift { .. }
+            | Commands::Stop { .. }
+            | Commands::Start { .. }
+            | Commands::Restore { .. }
+            | Commands::Reports { .. }
+            | Commands::Stats { .. }
             | Commands::Purge { .. }
             | Commands::Validate { .. }
             | Commands::Render { .. }
             | Commands::Exists { .. }
-            | Commands::Docs { .. } => {
+            | Commands::Ssh { .. }
+            | Commands::Docs { .. }
+            | Commands::Completions { .. }
+            | Commands::Config { .. }
+            | Commands::Probe { .. }
+            | Commands::CompleteEnvNames => {
                 panic!("Expected Register command")
             }
         }
@@ -721,4 +1142,557 @@ mod tests {
             "Help text should mention instance-ip parameter"
         );
     }
+
+    #[test]
+    fn it_should_parse_ansible_controls_for_configure() {
+        let args = vec![
+            "torrust-tracker-deployer",
+            "configure",
+            "my-env",
+            "--tags",
+            "docker,firewall",
+            "--skip-tags",
+            "security-updates",
+            "--check",
+        ];
+        let cli = Cli::try_parse_from(args).unwrap();
+
+        let Some(Commands::Configure {
+            environment,
+            ansible,
+            ..
+        }) = cli.command
+        else {
+            panic!("Expected Configure command");
+        };
+
+        assert_eq!(environment.as_deref(), Some("my-env"));
+        assert_eq!(ansible.tags, vec!["docker", "firewall"]);
+        assert_eq!(ansible.skip_tags, vec!["security-updates"]);
+        assert!(ansible.check);
+    }
+
+    #[test]
+    fn it_should_run_all_release_playbooks_without_ansible_controls() {
+        let args = vec!["torrust-tracker-deployer", "release", "my-env"];
+        let cli = Cli::try_parse_from(args).unwrap();
+
+        let Some(Commands::Release { ansible, .. }) = cli.command else {
+            panic!("Expected Release command");
+        };
+
+        assert!(ansible.to_playbook_options().to_args().is_empty());
+    }
+
+    #[test]
+    fn it_should_parse_the_configuration_override_file_of_release() {
+        for flag in ["--override", "--env-file"] {
+            let args = vec![
+                "torrust-tracker-deployer",
+                "release",
+                "my-env",
+                flag,
+                "overrides.json",
+            ];
+            let cli = Cli::try_parse_from(args).unwrap();
+
+            let Some(Commands::Release {
+                config_override, ..
+            }) = cli.command
+            else {
+                panic!("Expected Release command");
+            };
+
+            assert_eq!(
+                config_override,
+                Some(std::path::PathBuf::from("overrides.json"))
+            );
+        }
+    }
+
+    #[test]
+    fn it_should_parse_an_out_of_band_target_host_of_configure() {
+        let args = vec![
+            "torrust-tracker-deployer",
+            "configure",
+            "my-env",
+            "--experimental",
+            "--target-host",
+            "127.0.0.1",
+            "--ssh-port",
+            "2222",
+        ];
+        let cli = Cli::try_parse_from(args).unwrap();
+
+        let Some(Commands::Configure {
+            target_host,
+            ssh_port,
+            ..
+        }) = cli.command
+        else {
+            panic!("Expected Configure command");
+        };
+
+        assert_eq!(target_host.as_deref(), Some("127.0.0.1"));
+        assert_eq!(ssh_port, Some(2222));
+    }
+
+    #[test]
+    fn it_should_require_the_experimental_flag_for_a_target_host() {
+        let result = Cli::try_parse_from([
+            "torrust-tracker-deployer",
+            "configure",
+            "my-env",
+            "--target-host",
+            "127.0.0.1",
+        ]);
+
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn it_should_parse_the_dry_run_flag_for_destructive_commands() {
+        let cli =
+            Cli::try_parse_from(["torrust-tracker-deployer", "destroy", "my-env", "--dry-run"])
+                .unwrap();
+        let Some(Commands::Destroy { dry_run, .. }) = cli.command else {
+            panic!("Expected Destroy command");
+        };
+        assert!(dry_run);
+
+        let cli = Cli::try_parse_from(["torrust-tracker-deployer", "purge", "my-env", "--dry-run"])
+            .unwrap();
+        let Some(Commands::Purge { force, dry_run, .. }) = cli.command else {
+            panic!("Expected Purge command");
+        };
+        assert!(dry_run);
+        assert!(!force);
+
+        let cli = Cli::try_parse_from([
+            "torrust-tracker-deployer",
+            "provision",
+            "my-env",
+            "--dry-run",
+        ])
+        .unwrap();
+        let Some(Commands::Provision { dry_run, .. }) = cli.command else {
+            panic!("Expected Provision command");
+        };
+        assert!(dry_run);
+    }
+
+    #[test]
+    fn it_should_parse_the_purge_safety_flags() {
+        let cli = Cli::try_parse_from([
+            "torrust-tracker-deployer",
+            "purge",
+            "my-env",
+            "--yes",
+            "--destroy-first",
+        ])
+        .unwrap();
+        let Some(Commands::Purge {
+            yes,
+            force,
+            destroy_first,
+            ..
+        }) = cli.command
+        else {
+            panic!("Expected Purge command");
+        };
+        assert!(yes);
+        assert!(destroy_first);
+        assert!(!force);
+
+        let cli = Cli::try_parse_from(["torrust-tracker-deployer", "purge", "my-env", "-y", "-f"])
+            .unwrap();
+        let Some(Commands::Purge { yes, force, .. }) = cli.command else {
+            panic!("Expected Purge command");
+        };
+        assert!(yes);
+        assert!(force);
+    }
+
+    #[test]
+    fn it_should_reject_destroying_first_in_a_purge_dry_run() {
+        let result = Cli::try_parse_from([
+            "torrust-tracker-deployer",
+            "purge",
+            "my-env",
+            "--destroy-first",
+            "--dry-run",
+        ]);
+
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn it_should_parse_a_purge_garbage_collection() {
+        let cli = Cli::try_parse_from([
+            "torrust-tracker-deployer",
+            "purge",
+            "--gc",
+            "--older-than",
+            "14d",
+            "--yes",
+        ])
+        .unwrap();
+        let Some(Commands::Purge {
+            environment,
+            gc,
+            older_than,
+            include_failed,
+            ..
+        }) = cli.command
+        else {
+            panic!("Expected Purge command");
+        };
+        assert!(gc);
+        assert_eq!(environment, None);
+        assert_eq!(older_than, Some(Duration::from_secs(14 * 24 * 60 * 60)));
+        assert!(!include_failed);
+    }
+
+    #[test]
+    fn it_should_reject_a_purge_garbage_collection_without_an_age_or_with_an_environment() {
+        for args in [
+            vec!["purge", "--gc"],
+            vec!["purge", "my-env", "--gc", "--older-than", "14d"],
+            vec!["purge", "--older-than", "14d"],
+            vec!["purge"],
+        ] {
+            let result =
+                Cli::try_parse_from(std::iter::once("torrust-tracker-deployer").chain(args));
+
+            assert!(result.is_err());
+        }
+    }
+
+    #[test]
+    fn it_should_parse_the_destroy_refresh_flag() {
+        let cli =
+            Cli::try_parse_from(["torrust-tracker-deployer", "destroy", "my-env", "--refresh"])
+                .unwrap();
+        let Some(Commands::Destroy {
+            refresh, dry_run, ..
+        }) = cli.command
+        else {
+            panic!("Expected Destroy command");
+        };
+        assert!(refresh);
+        assert!(!dry_run);
+
+        let result = Cli::try_parse_from([
+            "torrust-tracker-deployer",
+            "destroy",
+            "my-env",
+            "--refresh",
+            "--dry-run",
+        ]);
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn it_should_parse_the_destroy_label_selection() {
+        let cli = Cli::try_parse_from([
+            "torrust-tracker-deployer",
+            "destroy",
+            "--label",
+            "purpose=e2e",
+            "--yes",
+        ])
+        .unwrap();
+        let Some(Commands::Destroy {
+            environment,
+            label,
+            yes,
+            ..
+        }) = cli.command
+        else {
+            panic!("Expected Destroy command");
+        };
+        assert_eq!(environment, None);
+        assert_eq!(label, vec!["purpose=e2e".parse().unwrap()]);
+        assert!(yes);
+
+        for args in [
+            vec!["destroy", "my-env", "--label", "purpose=e2e"],
+            vec!["destroy", "--label", "purpose"],
+            vec!["destroy", "my-env", "--yes"],
+            vec!["destroy", "my-env", "--parallel", "4"],
+        ] {
+            let result = Cli::try_parse_from(["torrust-tracker-deployer"].into_iter().chain(args));
+            assert!(result.is_err());
+        }
+    }
+
+    #[test]
+    fn it_should_parse_the_udp_probe_with_its_retry_options() {
+        let cli = Cli::try_parse_from([
+            "torrust-tracker-deployer",
+            "probe",
+            "udp",
+            "1.2.3.4:6969",
+            "--timeout",
+            "2",
+            "--retries",
+            "4",
+        ])
+        .unwrap();
+        let Some(Commands::Probe {
+            action:
+                ProbeAction::Udp {
+                    tracker,
+                    timeout,
+                    retries,
+                },
+        }) = cli.command
+        else {
+            panic!("Expected Probe command");
+        };
+        assert_eq!(tracker, "1.2.3.4:6969");
+        assert_eq!((timeout, retries), (2, 4));
+
+        for args in [
+            vec!["probe", "udp"],
+            vec!["probe", "udp", "1.2.3.4:6969", "--timeout", "0"],
+            vec!["probe", "udp", "1.2.3.4:6969", "--retries", "9"],
+        ] {
+            let result =
+                Cli::try_parse_from(["torrust-tracker-deployer"].into_iter().chain(args.clone()));
+            assert!(result.is_err(), "expected {args:?} to be rejected");
+        }
+    }
+
+    #[test]
+    fn it_should_parse_the_keys_add_duration_as_an_age() {
+        let cli = Cli::try_parse_from([
+            "torrust-tracker-deployer",
+            "keys",
+            "add",
+            "prod",
+            "--duration",
+            "30d",
+        ])
+        .unwrap();
+        let Some(Commands::Keys {
+            action:
+                KeysAction::Add {
+                    environment,
+                    duration,
+                },
+        }) = cli.command
+        else {
+            panic!("Expected Keys add command");
+        };
+        assert_eq!(environment, "prod");
+        assert_eq!(duration, Some(Duration::from_secs(30 * 24 * 60 * 60)));
+
+        let result = Cli::try_parse_from([
+            "torrust-tracker-deployer",
+            "keys",
+            "add",
+            "prod",
+            "--duration",
+            "soon",
+        ]);
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn it_should_parse_the_provision_label_selection_with_parallelism() {
+        let cli = Cli::try_parse_from([
+            "torrust-tracker-deployer",
+            "provision",
+            "--label",
+            "purpose=nightly",
+            "--parallel",
+            "4",
+        ])
+        .unwrap();
+        let Some(Commands::Provision {
+            environment,
+            label,
+            parallel,
+            ..
+        }) = cli.command
+        else {
+            panic!("Expected Provision command");
+        };
+        assert_eq!(environment, None);
+        assert_eq!(label, vec!["purpose=nightly".parse().unwrap()]);
+        assert_eq!(parallel.map(NonZeroUsize::get), Some(4));
+
+        for args in [
+            vec!["provision"],
+            vec!["provision", "my-env", "--label", "purpose=nightly"],
+            vec!["provision", "--label", "purpose=nightly", "--dry-run"],
+            vec!["provision", "--label", "purpose=nightly", "--parallel", "0"],
+            vec!["configure", "--label", "purpose=nightly", "--check"],
+            vec!["configure", "my-env", "--parallel", "2"],
+        ] {
+            let result =
+                Cli::try_parse_from(["torrust-tracker-deployer"].into_iter().chain(args.clone()));
+            assert!(result.is_err(), "expected {args:?} to be rejected");
+        }
+    }
+
+    #[test]
+    fn it_should_parse_the_label_command() {
+        let cli = Cli::try_parse_from([
+            "torrust-tracker-deployer",
+            "label",
+            "my-env",
+            "--set",
+            "team=infra",
+            "--remove",
+            "purpose",
+        ])
+        .unwrap();
+        let Some(Commands::Label {
+            environment,
+            set,
+            remove,
+        }) = cli.command
+        else {
+            panic!("Expected Label command");
+        };
+        assert_eq!(environment, "my-env");
+        assert_eq!(set, vec!["team=infra".parse().unwrap()]);
+        assert_eq!(remove, vec!["purpose".to_string()]);
+    }
+
+    #[test]
+    fn it_should_parse_the_repair_backup_selection() {
+        let cli = Cli::try_parse_from([
+            "torrust-tracker-deployer",
+            "repair",
+            "my-env",
+            "--backup",
+            "20251008T143045.123456789Z",
+        ])
+        .unwrap();
+        let Some(Commands::Repair {
+            environment,
+            backup,
+            list,
+        }) = cli.command
+        else {
+            panic!("Expected Repair command");
+        };
+        assert_eq!(environment, "my-env");
+        assert_eq!(backup.as_deref(), Some("20251008T143045.123456789Z"));
+        assert!(!list);
+
+        let result = Cli::try_parse_from([
+            "torrust-tracker-deployer",
+            "repair",
+            "my-env",
+            "--list",
+            "--backup",
+            "20251008T143045.123456789Z",
+        ]);
+        assert!(result.is_err(), "--list and --backup should conflict");
+    }
+
+    #[test]
+    fn it_should_parse_the_deploy_target_and_wait_flags() {
+        let cli = Cli::try_parse_from(["torrust-tracker-deployer", "deploy", "my-env"]).unwrap();
+        let Some(Commands::Deploy { until, wait, .. }) = cli.command else {
+            panic!("Expected Deploy command");
+        };
+        assert_eq!(until, DeployTarget::Running);
+        assert!(wait.wait_for_health());
+
+        let cli = Cli::try_parse_from([
+            "torrust-tracker-deployer",
+            "deploy",
+            "my-env",
+            "--until",
+            "configured",
+            "--wait",
+            "--no-wait",
+        ])
+        .unwrap();
+        let Some(Commands::Deploy { until, wait, .. }) = cli.command else {
+            panic!("Expected Deploy command");
+        };
+        assert_eq!(until, DeployTarget::Configured);
+        assert!(!wait.wait_for_health(), "the last wait flag should win");
+
+        let result = Cli::try_parse_from([
+            "torrust-tracker-deployer",
+            "deploy",
+            "my-env",
+            "--until",
+            "created",
+        ]);
+        assert!(result.is_err(), "created is not a deploy target");
+    }
+
+    #[test]
+    fn it_should_parse_the_completions_shell() {
+        let cli = Cli::try_parse_from(["torrust-tracker-deployer", "completions", "zsh"]).unwrap();
+
+        let Some(Commands::Completions { shell }) = cli.command else {
+            panic!("Expected Completions command");
+        };
+        assert_eq!(
+            shell,
+            crate::infrastructure::shell_completion::CompletionShell::Zsh
+        );
+    }
+
+    #[test]
+    fn it_should_parse_the_hidden_environment_names_callback_without_listing_it_in_help() {
+        let cli =
+            Cli::try_parse_from(["torrust-tracker-deployer", "__complete-env-names"]).unwrap();
+        assert!(matches!(cli.command, Some(Commands::CompleteEnvNames)));
+
+        let help = Cli::try_parse_from(["torrust-tracker-deployer", "--help"])
+            .unwrap_err()
+            .to_string();
+        assert!(!help.contains("__complete-env-names"));
+    }
+
+    #[test]
+    fn it_should_require_a_passphrase_or_no_secrets_for_export() {
+        let cli = Cli::try_parse_from([
+            "torrust-tracker-deployer",
+            "export",
+            "my-env",
+            "--passphrase-env",
+            "BUNDLE_PASSPHRASE",
+        ])
+        .unwrap();
+        let Some(Commands::Export {
+            environment,
+            output,
+            no_secrets,
+            passphrase_env,
+        }) = cli.command
+        else {
+            panic!("Expected Export command");
+        };
+        assert_eq!(environment, "my-env");
+        assert_eq!(output, None);
+        assert!(!no_secrets);
+        assert_eq!(passphrase_env.as_deref(), Some("BUNDLE_PASSPHRASE"));
+
+        for args in [
+            vec!["export", "my-env"],
+            vec![
+                "export",
+                "my-env",
+                "--no-secrets",
+                "--passphrase-env",
+                "VAR",
+            ],
+        ] {
+            let result =
+                Cli::try_parse_from(std::iter::once("torrust-tracker-deployer").chain(args));
+
+            assert!(result.is_err());
+        }
+    }
 }
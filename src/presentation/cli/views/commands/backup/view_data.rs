@@ -0,0 +1,42 @@
+//! View data for the backup command
+
+use std::path::Path;
+
+use serde::Serialize;
+
+use crate::domain::environment::DatabaseBackup;
+
+/// Data for rendering a backup of the tracker database
+#[derive(Debug, Clone, Serialize)]
+pub struct BackupData {
+    /// Name of the environment
+    pub environment_name: String,
+    /// Id of the backup, used to select it with `restore --from`
+    pub id: String,
+    /// When the backup was taken (RFC 3339)
+    pub created_at: String,
+    /// Database driver of the tracker
+    pub driver: String,
+    /// Path of the backup file
+    pub path: String,
+    /// Size of the backup file in bytes
+    pub size: u64,
+    /// SHA-256 checksum of the backup file
+    pub sha256: String,
+}
+
+impl BackupData {
+    /// Build the view data from a backup and the path of its file
+    #[must_use]
+    pub fn new(environment_name: &str, backup: &DatabaseBackup, backup_file: &Path) -> Self {
+        Self {
+            environment_name: environment_name.to_string(),
+            id: backup.id.clone(),
+            created_at: backup.created_at.to_rfc3339(),
+            driver: backup.driver.clone(),
+            path: backup_file.display().to_string(),
+            size: backup.size,
+            sha256: backup.sha256.clone(),
+        }
+    }
+}
@@ -0,0 +1,15 @@
+//! JSON View for Database Backups
+//!
+//! This module provides JSON-based rendering for the backup command.
+
+use crate::presentation::cli::views::commands::backup::view_data::BackupData;
+use crate::presentation::cli::views::{Render, ViewRenderError};
+
+/// JSON view for rendering a backup
+pub struct JsonView;
+
+impl Render<BackupData> for JsonView {
+    fn render(data: &BackupData) -> Result<String, ViewRenderError> {
+        Ok(serde_json::to_string_pretty(data)?)
+    }
+}
@@ -0,0 +1,57 @@
+//! Text View for Database Backups
+//!
+//! This module provides text-based rendering for the backup command: where
+//! the backup was saved, its checksum, and the command to restore it.
+
+use crate::presentation::cli::views::commands::backup::view_data::BackupData;
+use crate::presentation::cli::views::{Render, ViewRenderError};
+
+/// Text view for rendering a backup
+pub struct TextView;
+
+impl Render<BackupData> for TextView {
+    fn render(data: &BackupData) -> Result<String, ViewRenderError> {
+        let lines = [
+            format!(
+                "Backup {} of environment '{}' saved:",
+                data.id, data.environment_name
+            ),
+            format!("  Driver:  {}", data.driver),
+            format!("  File:    {}", data.path),
+            format!("  Size:    {} bytes", data.size),
+            format!("  SHA-256: {}", data.sha256),
+            String::new(),
+            "To restore it:".to_string(),
+            format!(
+                "  torrust-tracker-deployer restore {} --from {}",
+                data.environment_name, data.id
+            ),
+        ];
+
+        Ok(lines.join("\n"))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn it_should_show_the_command_to_restore_the_backup() {
+        let data = BackupData {
+            environment_name: "my-env".to_string(),
+            id: "20261016T143045Z".to_string(),
+            created_at: "2026-10-16T14:30:45+00:00".to_string(),
+            driver: "sqlite3".to_string(),
+            path: "data/my-env/backups/20261016T143045Z/tracker.db".to_string(),
+            size: 40_960,
+            sha256: "ab".repeat(32),
+        };
+
+        let output = TextView::render(&data).unwrap();
+
+        assert!(output.starts_with("Backup 20261016T143045Z of environment 'my-env' saved:"));
+        assert!(output.contains("  Size:    40960 bytes"));
+        assert!(output.ends_with("torrust-tracker-deployer restore my-env --from 20261016T143045Z"));
+    }
+}
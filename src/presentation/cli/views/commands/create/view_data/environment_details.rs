@@ -20,6 +20,7 @@ use chrono::{DateTime, Utc};
 use serde::Serialize;
 use std::path::PathBuf;
 
+use crate::application::command_handlers::create::CreateResult;
 use crate::domain::environment::state::Created;
 use crate::domain::environment::Environment;
 
@@ -47,6 +48,8 @@ pub struct EnvironmentDetailsData {
     pub build_dir: PathBuf,
     /// Timestamp when the environment was created (ISO 8601 format in JSON)
     pub created_at: DateTime<Utc>,
+    /// Whether the environment already existed with the same configuration
+    pub already_existed: bool,
 }
 
 /// Conversion from domain model to presentation DTO
@@ -70,6 +73,25 @@ impl From<&Environment<Created>> for EnvironmentDetailsData {
             data_dir: environment.data_dir().clone(),
             build_dir: environment.build_dir().clone(),
             created_at: environment.created_at(),
+            already_existed: false,
+        }
+    }
+}
+
+/// Conversion from the create command result
+///
+/// Used when the environment may already exist: its current state is not
+/// necessarily `Created`.
+impl From<&CreateResult> for EnvironmentDetailsData {
+    fn from(result: &CreateResult) -> Self {
+        let environment = &result.environment;
+        Self {
+            environment_name: environment.name().as_str().to_string(),
+            instance_name: environment.instance_name().as_str().to_string(),
+            data_dir: environment.data_dir().clone(),
+            build_dir: environment.build_dir().clone(),
+            created_at: environment.created_at(),
+            already_existed: result.already_existed,
         }
     }
 }
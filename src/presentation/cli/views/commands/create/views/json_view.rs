@@ -35,6 +35,7 @@ use crate::presentation::cli::views::{Render, ViewRenderError};
 ///     data_dir: PathBuf::from("./data/my-env"),
 ///     build_dir: PathBuf::from("./build/my-env"),
 ///     created_at: Utc.with_ymd_and_hms(2026, 2, 16, 14, 30, 0).unwrap(),
+///     already_existed: false,
 /// };
 ///
 /// let json = JsonView::render(&data).expect("JSON serialization failed");
@@ -68,6 +69,7 @@ mod tests {
             data_dir: PathBuf::from("./data/test-env"),
             build_dir: PathBuf::from("./build/test-env"),
             created_at: test_timestamp(),
+            already_existed: false,
         };
 
         // When
@@ -90,6 +92,7 @@ mod tests {
             data_dir: PathBuf::from("/opt/data/prod"),
             build_dir: PathBuf::from("/opt/build/prod"),
             created_at: test_timestamp(),
+            already_existed: false,
         };
 
         // When
@@ -103,6 +106,7 @@ mod tests {
         assert_eq!(parsed["data_dir"], "/opt/data/prod");
         assert_eq!(parsed["build_dir"], "/opt/build/prod");
         assert_eq!(parsed["created_at"], "2026-02-16T14:30:00Z");
+        assert_eq!(parsed["already_existed"], false);
     }
 
     #[test]
@@ -114,6 +118,7 @@ mod tests {
             data_dir: PathBuf::from("./data/my-env"),
             build_dir: PathBuf::from("./build/my-env"),
             created_at: test_timestamp(),
+            already_existed: false,
         };
 
         // When
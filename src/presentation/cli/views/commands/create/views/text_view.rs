@@ -35,6 +35,7 @@ use crate::presentation::cli::views::{Render, ViewRenderError};
 ///     data_dir: PathBuf::from("./data/my-env"),
 ///     build_dir: PathBuf::from("./build/my-env"),
 ///     created_at: Utc.with_ymd_and_hms(2026, 2, 16, 14, 30, 0).unwrap(),
+///     already_existed: false,
 /// };
 ///
 /// let output = TextView::render(&data).unwrap();
@@ -76,6 +77,7 @@ mod tests {
             data_dir: PathBuf::from("./data/test-env"),
             build_dir: PathBuf::from("./build/test-env"),
             created_at: test_timestamp(),
+            already_existed: false,
         };
 
         // When
@@ -101,6 +103,7 @@ mod tests {
             data_dir: PathBuf::from("/opt/deployer/data/prod"),
             build_dir: PathBuf::from("/opt/deployer/build/prod"),
             created_at: test_timestamp(),
+            already_existed: false,
         };
 
         // When
@@ -125,6 +128,7 @@ mod tests {
             data_dir: PathBuf::from("/absolute/path/data/my-env"),
             build_dir: PathBuf::from("/absolute/path/build/my-env"),
             created_at: test_timestamp(),
+            already_existed: false,
         };
 
         // When
@@ -0,0 +1,38 @@
+//! Views for Deploy Command
+//!
+//! This module contains view components for rendering deploy command output.
+//!
+//! # Architecture
+//!
+//! This module follows the Strategy Pattern for rendering:
+//! - `DeployDetailsData`: The data DTO passed to all views
+//! - `TextView`: Renders human-readable text output
+//! - `JsonView`: Renders machine-readable JSON output
+//!
+//! # Structure
+//!
+//! - `view_data/`: Data structures (DTOs) passed to views
+//!   - `deploy_details.rs`: Main DTO with deployment result data
+//! - `views/`: View rendering implementations
+//!   - `text_view.rs`: Human-readable text rendering
+//!   - `json_view.rs`: Machine-readable JSON rendering
+
+pub mod view_data {
+    pub mod deploy_details;
+
+    // Re-export main types for convenience
+    pub use deploy_details::DeployDetailsData;
+}
+
+pub mod views {
+    pub mod json_view;
+    pub mod text_view;
+
+    // Re-export views for convenience
+    pub use json_view::JsonView;
+    pub use text_view::TextView;
+}
+
+// Re-export at module root for convenience
+pub use view_data::DeployDetailsData;
+pub use views::{JsonView, TextView};
@@ -0,0 +1,73 @@
+//! Deploy Details Data Transfer Object
+//!
+//! This module contains the presentation DTO for deploy command details.
+//! It serves as the data structure passed to view renderers (`TextView`, `JsonView`).
+
+use serde::Serialize;
+
+use crate::application::command_handlers::deploy::{DeployOutcome, DeployTarget};
+
+/// Deploy details data for rendering
+///
+/// Built from the outcome of a successful deployment: deploy failures result
+/// in an error return.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct DeployDetailsData {
+    /// Name of the deployed environment
+    pub environment_name: String,
+    /// State at which the deployment was asked to stop
+    pub until: String,
+    /// State of the environment before the deployment
+    pub initial_state: String,
+    /// State of the environment after the deployment
+    pub state: String,
+    /// Commands run by the deployment, in order (empty if nothing was left to do)
+    pub phases: Vec<String>,
+}
+
+impl DeployDetailsData {
+    /// Construct a `DeployDetailsData` from the deployment outcome
+    ///
+    /// # Arguments
+    ///
+    /// * `until` - State at which the deployment was asked to stop
+    /// * `outcome` - Result of the deployment
+    #[must_use]
+    pub fn from_outcome(until: DeployTarget, outcome: &DeployOutcome) -> Self {
+        Self {
+            environment_name: outcome.environment.name().to_string(),
+            until: until.to_string(),
+            initial_state: outcome.initial_state.to_string(),
+            state: outcome.environment.state_name().to_string(),
+            phases: outcome
+                .performed
+                .iter()
+                .map(|target| target.command().to_string())
+                .collect(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::environment::testing::EnvironmentTestBuilder;
+
+    #[test]
+    fn it_should_list_the_commands_of_the_phases_run() {
+        let (environment, _data_dir, _build_dir, _temp_dir) = EnvironmentTestBuilder::new()
+            .with_name("prod")
+            .build_with_custom_paths();
+        let outcome = DeployOutcome {
+            initial_state: "created",
+            performed: vec![DeployTarget::Provisioned, DeployTarget::Configured],
+            environment: environment.into_any(),
+        };
+
+        let data = DeployDetailsData::from_outcome(DeployTarget::Configured, &outcome);
+
+        assert_eq!(data.environment_name, "prod");
+        assert_eq!(data.until, "configured");
+        assert_eq!(data.phases, vec!["provision", "configure"]);
+    }
+}
@@ -0,0 +1,42 @@
+//! JSON View for Deploy Command
+//!
+//! This module provides JSON-based rendering for the deploy command.
+//! It follows the Strategy Pattern, providing a machine-readable output format
+//! for the same underlying data (`DeployDetailsData` DTO).
+
+use crate::presentation::cli::views::commands::deploy::DeployDetailsData;
+use crate::presentation::cli::views::{Render, ViewRenderError};
+
+/// View for rendering deploy details as JSON
+///
+/// This view provides machine-readable JSON output for automation workflows
+/// and AI agents. It serializes the deploy details without any transformations.
+pub struct JsonView;
+
+impl Render<DeployDetailsData> for JsonView {
+    fn render(data: &DeployDetailsData) -> Result<String, ViewRenderError> {
+        Ok(serde_json::to_string_pretty(data)?)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn it_should_render_the_phases_as_an_array() {
+        let data = DeployDetailsData {
+            environment_name: "prod".to_string(),
+            until: "running".to_string(),
+            initial_state: "configured".to_string(),
+            state: "running".to_string(),
+            phases: vec!["release".to_string(), "run".to_string()],
+        };
+
+        let json = JsonView::render(&data).unwrap();
+
+        let parsed: serde_json::Value = serde_json::from_str(&json).unwrap();
+        assert_eq!(parsed["initial_state"], "configured");
+        assert_eq!(parsed["phases"], serde_json::json!(["release", "run"]));
+    }
+}
@@ -0,0 +1,88 @@
+//! Text View for Deploy Command
+//!
+//! This module provides text-based rendering for the deploy command.
+//! It follows the Strategy Pattern, providing a human-readable output format
+//! for the same underlying data (`DeployDetailsData` DTO).
+
+use crate::presentation::cli::views::commands::deploy::DeployDetailsData;
+use crate::presentation::cli::views::{Render, ViewRenderError};
+
+/// View for rendering deploy details as human-readable text
+///
+/// The rendered string is intended to be passed to `ProgressReporter::complete()`,
+/// which adds the `✅` prefix to the first line.
+pub struct TextView;
+
+impl Render<DeployDetailsData> for TextView {
+    fn render(data: &DeployDetailsData) -> Result<String, ViewRenderError> {
+        let phases = if data.phases.is_empty() {
+            format!("none, the environment was already {}", data.initial_state)
+        } else {
+            data.phases.join(" → ")
+        };
+
+        let mut lines = vec![
+            format!(
+                "Environment '{}' deployed up to '{}'",
+                data.environment_name, data.until
+            ),
+            format!("  Initial state:     {}", data.initial_state),
+            format!("  Phases run:        {phases}"),
+            format!("  State:             {}", data.state),
+        ];
+
+        if data.state == "running" {
+            lines.push(format!(
+                "  Run 'show {}' for the service URLs",
+                data.environment_name
+            ));
+        } else {
+            lines.push(format!(
+                "  Run 'deploy {}' to continue the deployment",
+                data.environment_name
+            ));
+        }
+
+        Ok(lines.join("\n"))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn create_test_data() -> DeployDetailsData {
+        DeployDetailsData {
+            environment_name: "prod".to_string(),
+            until: "running".to_string(),
+            initial_state: "configured".to_string(),
+            state: "running".to_string(),
+            phases: vec!["release".to_string(), "run".to_string()],
+        }
+    }
+
+    #[test]
+    fn it_should_render_the_phases_run_in_order() {
+        let text = TextView::render(&create_test_data()).unwrap();
+
+        assert!(text.starts_with("Environment 'prod' deployed up to 'running'"));
+        assert!(text.contains("release → run"));
+        assert!(text.contains("show prod"));
+    }
+
+    #[test]
+    fn it_should_explain_when_there_was_nothing_left_to_deploy() {
+        let data = DeployDetailsData {
+            until: "configured".to_string(),
+            initial_state: "released".to_string(),
+            state: "released".to_string(),
+            phases: vec![],
+            ..create_test_data()
+        };
+
+        let text = TextView::render(&data).unwrap();
+
+        assert!(text.contains("none, the environment was already released"));
+        assert!(text.contains("deploy prod"));
+    }
+}
@@ -17,6 +17,9 @@
 //!   - `text_view.rs`: Human-readable text rendering
 //!   - `json_view.rs`: Machine-readable JSON rendering
 //!
+//! The summary of `destroy --label` is rendered with the shared
+//! `multi_environment_report` views.
+//!
 //! # SOLID Principles
 //!
 //! - **Single Responsibility**: Each view has one job - render in its format
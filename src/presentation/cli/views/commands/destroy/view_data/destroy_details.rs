@@ -104,6 +104,7 @@ mod tests {
     fn create_test_provider_config() -> ProviderConfig {
         ProviderConfig::Lxd(LxdConfig {
             profile_name: ProfileName::new("lxd-test-env".to_string()).unwrap(),
+            remote: None,
         })
     }
 
@@ -0,0 +1,31 @@
+//! View data for the diff command
+
+use serde::Serialize;
+
+use crate::domain::environment::{ChangeImpact, ConfigChange, ConfigDiff};
+
+/// Data for rendering the configuration changes of an environment
+#[derive(Debug, Clone, Serialize)]
+pub struct DiffData {
+    /// Name of the environment
+    pub environment_name: String,
+    /// Path of the compared configuration file
+    pub config_file: String,
+    /// Most disruptive impact of the changes, `None` when nothing changed
+    pub impact: Option<ChangeImpact>,
+    /// Changed settings, in comparison order
+    pub changes: Vec<ConfigChange>,
+}
+
+impl DiffData {
+    /// Build the view data from the diff of an environment
+    #[must_use]
+    pub fn new(environment_name: &str, config_file: &str, diff: &ConfigDiff) -> Self {
+        Self {
+            environment_name: environment_name.to_string(),
+            config_file: config_file.to_string(),
+            impact: diff.impact(),
+            changes: diff.changes().to_vec(),
+        }
+    }
+}
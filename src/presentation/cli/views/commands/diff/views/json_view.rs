@@ -0,0 +1,15 @@
+//! JSON View for Configuration Diffs
+//!
+//! This module provides JSON-based rendering for the diff command.
+
+use crate::presentation::cli::views::commands::diff::view_data::DiffData;
+use crate::presentation::cli::views::{Render, ViewRenderError};
+
+/// JSON view for rendering configuration changes
+pub struct JsonView;
+
+impl Render<DiffData> for JsonView {
+    fn render(data: &DiffData) -> Result<String, ViewRenderError> {
+        Ok(serde_json::to_string_pretty(data)?)
+    }
+}
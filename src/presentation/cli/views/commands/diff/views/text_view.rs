@@ -0,0 +1,137 @@
+//! Text View for Configuration Diffs
+//!
+//! This module provides text-based rendering for the diff command: the
+//! changed settings grouped by impact, most disruptive first, followed by the
+//! commands that apply them.
+
+use crate::domain::environment::ChangeImpact;
+use crate::presentation::cli::views::commands::diff::view_data::DiffData;
+use crate::presentation::cli::views::{Render, ViewRenderError};
+
+/// Deployment commands in workflow order
+const WORKFLOW: [&str; 4] = ["provision", "configure", "release", "run"];
+
+/// Text view for rendering configuration changes
+pub struct TextView;
+
+impl TextView {
+    /// Heading of the group of changes with `impact`
+    fn heading(impact: ChangeImpact) -> &'static str {
+        match impact {
+            ChangeImpact::RequiresProvision => "Requires provision (new instance):",
+            ChangeImpact::RequiresConfigure => "Requires configure (firewall, Docker):",
+            ChangeImpact::RequiresRelease => "Requires release (tracker and services):",
+            ChangeImpact::NoRedeploy => "No redeploy needed:",
+        }
+    }
+
+    /// Summary of what applies the changes
+    fn summary(impact: ChangeImpact) -> &'static str {
+        match impact {
+            ChangeImpact::RequiresProvision => {
+                "A new instance has to be provisioned to apply the changes:"
+            }
+            ChangeImpact::RequiresConfigure => {
+                "The instance has to be configured again to apply the changes:"
+            }
+            ChangeImpact::RequiresRelease => "A release is sufficient to apply the changes:",
+            ChangeImpact::NoRedeploy => "No redeploy is needed for the changes.",
+        }
+    }
+}
+
+impl Render<DiffData> for TextView {
+    fn render(data: &DiffData) -> Result<String, ViewRenderError> {
+        let Some(impact) = data.impact else {
+            return Ok(format!(
+                "No configuration changes for environment '{}' in {}.",
+                data.environment_name, data.config_file
+            ));
+        };
+
+        let mut lines = vec![format!(
+            "Configuration changes for environment '{}' in {} ({} found):",
+            data.environment_name,
+            data.config_file,
+            data.changes.len()
+        )];
+
+        for group in ChangeImpact::ALL {
+            let changes: Vec<_> = data
+                .changes
+                .iter()
+                .filter(|change| change.impact == group)
+                .collect();
+            if changes.is_empty() {
+                continue;
+            }
+
+            lines.push(String::new());
+            lines.push(Self::heading(group).to_string());
+            for change in changes {
+                lines.push(format!(
+                    "  {}: {} → {}",
+                    change.path,
+                    change.before.as_deref().unwrap_or("(not set)"),
+                    change.after.as_deref().unwrap_or("(not set)")
+                ));
+            }
+        }
+
+        lines.push(String::new());
+        lines.push(Self::summary(impact).to_string());
+        if let Some(command) = impact.command() {
+            let commands = WORKFLOW.iter().skip_while(|step| **step != command);
+            for step in commands {
+                lines.push(format!(
+                    "  torrust-tracker-deployer {step} {}",
+                    data.environment_name
+                ));
+            }
+        }
+
+        Ok(lines.join("\n"))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::environment::ConfigChange;
+
+    fn data(changes: Vec<ConfigChange>) -> DiffData {
+        DiffData {
+            environment_name: "my-env".to_string(),
+            config_file: "new.json".to_string(),
+            impact: changes.iter().map(|change| change.impact).max(),
+            changes,
+        }
+    }
+
+    #[test]
+    fn it_should_say_a_release_is_sufficient_for_an_api_token_change() {
+        let output = TextView::render(&data(vec![ConfigChange {
+            path: "tracker.http_api.admin_token".to_string(),
+            impact: ChangeImpact::RequiresRelease,
+            before: Some("[REDACTED]".to_string()),
+            after: Some("[REDACTED]".to_string()),
+        }]))
+        .unwrap();
+
+        assert!(output.contains("Requires release (tracker and services):"));
+        assert!(output.contains("  tracker.http_api.admin_token: [REDACTED] → [REDACTED]"));
+        assert!(output.contains("A release is sufficient"));
+        assert!(output.contains("torrust-tracker-deployer release my-env"));
+        assert!(!output.contains("torrust-tracker-deployer configure my-env"));
+    }
+
+    #[test]
+    fn it_should_say_so_when_nothing_changed() {
+        let output = TextView::render(&data(Vec::new())).unwrap();
+
+        assert_eq!(
+            output,
+            "No configuration changes for environment 'my-env' in new.json."
+        );
+    }
+}
@@ -0,0 +1,38 @@
+//! Views for Doctor Command
+//!
+//! This module contains view components for rendering doctor command output.
+//!
+//! # Architecture
+//!
+//! This module follows the Strategy Pattern for rendering:
+//! - `TextView`: Renders a human-readable list of checks grouped by category
+//! - `JsonView`: Renders machine-readable JSON output to attach to bug reports
+//!
+//! # Structure
+//!
+//! - `views/`: View rendering implementations
+//!   - `text_view.rs`: Human-readable check list rendering
+//!   - `json_view.rs`: JSON output for automation workflows
+
+pub mod view_data {
+    //! View data for the doctor command.
+    //!
+    //! Re-exports the application-layer DTOs as the canonical view input types.
+
+    pub use crate::application::command_handlers::doctor::info::{
+        CheckCategory, CheckStatus, DoctorCheck, DoctorReport, FailedEnvironment,
+    };
+}
+
+pub mod views {
+    pub mod json_view;
+    pub mod text_view;
+
+    // Re-export main types for convenience
+    pub use json_view::JsonView;
+    pub use text_view::TextView;
+}
+
+// Re-export at module root for convenience
+pub use view_data::DoctorReport;
+pub use views::{JsonView, TextView};
@@ -0,0 +1,43 @@
+//! JSON View for Doctor Report
+//!
+//! This module provides JSON-based rendering for the doctor command,
+//! serializing the `DoctorReport` DTO without transformations.
+
+use crate::presentation::cli::views::commands::doctor::view_data::DoctorReport;
+use crate::presentation::cli::views::{Render, ViewRenderError};
+
+/// View for rendering a doctor report as JSON
+///
+/// # Examples
+///
+/// ```rust
+/// # use torrust_tracker_deployer_lib::presentation::cli::views::Render;
+/// use torrust_tracker_deployer_lib::application::command_handlers::doctor::{
+///     CheckCategory, CheckStatus, DoctorCheck, DoctorReport,
+/// };
+/// use torrust_tracker_deployer_lib::presentation::cli::views::commands::doctor::JsonView;
+///
+/// let report = DoctorReport {
+///     deployer_version: "0.1.0".to_string(),
+///     platform: "linux x86_64".to_string(),
+///     working_dir: "/workspace".to_string(),
+///     checks: vec![DoctorCheck::new(
+///         CheckCategory::Dependencies,
+///         "opentofu",
+///         CheckStatus::Ok,
+///         "1.10.5",
+///     )],
+///     failed_environments: vec![],
+/// };
+///
+/// let output = JsonView::render(&report).unwrap();
+/// let parsed: serde_json::Value = serde_json::from_str(&output).unwrap();
+/// assert_eq!(parsed["checks"][0]["status"], "ok");
+/// ```
+pub struct JsonView;
+
+impl Render<DoctorReport> for JsonView {
+    fn render(data: &DoctorReport) -> Result<String, ViewRenderError> {
+        Ok(serde_json::to_string_pretty(data)?)
+    }
+}
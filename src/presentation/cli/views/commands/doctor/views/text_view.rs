@@ -0,0 +1,161 @@
+//! Text View for Doctor Report
+//!
+//! This module provides text-based rendering for the doctor command: the
+//! checks grouped by category, the environments needing attention and a
+//! summary line.
+
+use crate::presentation::cli::views::commands::doctor::view_data::{
+    CheckStatus, DoctorCheck, DoctorReport,
+};
+use crate::presentation::cli::views::{Render, ViewRenderError};
+
+/// Text view for rendering a doctor report
+///
+/// # Examples
+///
+/// ```rust
+/// # use torrust_tracker_deployer_lib::presentation::cli::views::Render;
+/// use torrust_tracker_deployer_lib::application::command_handlers::doctor::{
+///     CheckCategory, CheckStatus, DoctorCheck, DoctorReport,
+/// };
+/// use torrust_tracker_deployer_lib::presentation::cli::views::commands::doctor::TextView;
+///
+/// let report = DoctorReport {
+///     deployer_version: "0.1.0".to_string(),
+///     platform: "linux x86_64".to_string(),
+///     working_dir: "/workspace".to_string(),
+///     checks: vec![DoctorCheck::new(
+///         CheckCategory::Dependencies,
+///         "opentofu",
+///         CheckStatus::Ok,
+///         "1.10.5",
+///     )],
+///     failed_environments: vec![],
+/// };
+///
+/// let output = TextView::render(&report).unwrap();
+/// assert!(output.contains("[ok]"));
+/// assert!(output.contains("1.10.5"));
+/// ```
+pub struct TextView;
+
+impl TextView {
+    /// Render a single check line
+    fn render_check(check: &DoctorCheck) -> String {
+        let marker = match check.status {
+            CheckStatus::Ok => "[ok]  ",
+            CheckStatus::Warning => "[warn]",
+            CheckStatus::Failed => "[FAIL]",
+        };
+
+        format!("  {marker} {:<18} {}", check.name, check.detail)
+    }
+}
+
+impl Render<DoctorReport> for TextView {
+    fn render(report: &DoctorReport) -> Result<String, ViewRenderError> {
+        let mut lines = vec![
+            String::new(),
+            format!(
+                "Torrust Tracker Deployer {} ({})",
+                report.deployer_version, report.platform
+            ),
+            format!("Working directory: {}", report.working_dir),
+        ];
+
+        let mut category = None;
+        for check in &report.checks {
+            if category != Some(check.category) {
+                category = Some(check.category);
+                lines.push(String::new());
+                lines.push(format!("{}:", check.category));
+            }
+            lines.push(Self::render_check(check));
+        }
+
+        if !report.failed_environments.is_empty() {
+            lines.push(String::new());
+            lines.push("Environments needing attention:".to_string());
+            for environment in &report.failed_environments {
+                lines.push(format!(
+                    "  {} ({}): {}",
+                    environment.name, environment.state, environment.error
+                ));
+            }
+            lines.push("Run 'torrust-tracker-deployer show <env>' for details.".to_string());
+        }
+
+        let failed = report.count(CheckStatus::Failed);
+        let warnings = report.count(CheckStatus::Warning);
+        lines.push(String::new());
+        lines.push(if failed == 0 && warnings == 0 {
+            "All checks passed.".to_string()
+        } else {
+            format!("{failed} check(s) failed, {warnings} warning(s).")
+        });
+
+        Ok(lines.join("\n"))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::presentation::cli::views::commands::doctor::view_data::{
+        CheckCategory, FailedEnvironment,
+    };
+
+    fn report(checks: Vec<DoctorCheck>) -> DoctorReport {
+        DoctorReport {
+            deployer_version: "0.1.0".to_string(),
+            platform: "linux x86_64".to_string(),
+            working_dir: "/workspace".to_string(),
+            checks,
+            failed_environments: vec![],
+        }
+    }
+
+    #[test]
+    fn it_should_group_the_checks_by_category() {
+        let output = TextView::render(&report(vec![
+            DoctorCheck::new(
+                CheckCategory::Dependencies,
+                "opentofu",
+                CheckStatus::Ok,
+                "1.10.5",
+            ),
+            DoctorCheck::new(
+                CheckCategory::Dependencies,
+                "ansible",
+                CheckStatus::Ok,
+                "2.16.3",
+            ),
+            DoctorCheck::new(
+                CheckCategory::Workspace,
+                "disk space",
+                CheckStatus::Warning,
+                "2.0 GiB free under /workspace",
+            ),
+        ]))
+        .unwrap();
+
+        assert_eq!(output.matches("dependencies:").count(), 1);
+        assert!(output.contains("[warn] disk space"));
+        assert!(output.ends_with("0 check(s) failed, 1 warning(s)."));
+    }
+
+    #[test]
+    fn it_should_list_the_environments_needing_attention() {
+        let mut report = report(vec![]);
+        report.failed_environments.push(FailedEnvironment {
+            name: "staging".to_string(),
+            state: "provision_failed".to_string(),
+            error: "tofu apply failed".to_string(),
+        });
+
+        let output = TextView::render(&report).unwrap();
+
+        assert!(output.contains("staging (provision_failed): tofu apply failed"));
+        assert!(output.ends_with("All checks passed."));
+    }
+}
@@ -0,0 +1,32 @@
+//! Views for Drift Command
+//!
+//! This module contains view components for rendering drift command output.
+//!
+//! # Architecture
+//!
+//! This module follows the Strategy Pattern for rendering:
+//! - `DriftData`: The data DTO passed to the views
+//! - `TextView`: Renders the drift of each artifact and container, the diffs and the verdict
+//! - `JsonView`: Renders machine-readable JSON output
+//!
+//! # Structure
+//!
+//! - `view_data.rs`: Data structures (DTOs) prepared for view rendering
+//! - `views/`: View rendering implementations
+//!   - `text_view.rs`: Human-readable rendering
+//!   - `json_view.rs`: JSON output for automation workflows
+
+pub mod view_data;
+
+pub mod views {
+    pub mod json_view;
+    pub mod text_view;
+
+    // Re-export main types for convenience
+    pub use json_view::JsonView;
+    pub use text_view::TextView;
+}
+
+// Re-export at module root for convenience
+pub use view_data::DriftData;
+pub use views::{JsonView, TextView};
@@ -0,0 +1,100 @@
+//! View data for the drift command
+
+use serde::Serialize;
+
+use crate::application::command_handlers::drift::{
+    DriftReport, FileDrift, FileDriftStatus, ImageDrift, ImageDriftStatus,
+};
+
+/// Data for rendering a drift report
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct DriftData {
+    /// Name of the environment
+    pub environment_name: String,
+    /// Whether anything differs from what the deployer would deploy
+    pub drifted: bool,
+    /// Drift of each release artifact
+    pub files: Vec<FileDriftData>,
+    /// Drift of the container of each service
+    pub containers: Vec<ContainerDriftData>,
+}
+
+/// Drift of one release artifact
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct FileDriftData {
+    /// Name of the artifact (e.g. `tracker.toml`)
+    pub name: String,
+    /// Path of the deployed copy on the instance
+    pub remote_path: String,
+    /// `clean`, `modified` or `missing`
+    pub status: String,
+    /// Unified diff from the deployed copy to the expected one, if modified
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub diff: Option<String>,
+}
+
+/// Drift of the container of one service
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct ContainerDriftData {
+    /// Name of the Docker Compose service
+    pub service: String,
+    /// `clean`, `image-changed`, `digest-mismatch`, `unexpected` or `not-running`
+    pub status: String,
+    /// Image in the expected `docker-compose.yml`
+    pub expected_image: Option<String>,
+    /// Image the running container was created from
+    pub running_image: Option<String>,
+    /// Repository digests of the running image, on a digest mismatch
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub running_digests: Vec<String>,
+}
+
+impl DriftData {
+    /// Build the view data from the application layer report
+    #[must_use]
+    pub fn new(environment_name: &str, report: &DriftReport) -> Self {
+        Self {
+            environment_name: environment_name.to_string(),
+            drifted: report.is_drifted(),
+            files: report.files.iter().map(FileDriftData::from).collect(),
+            containers: report.images.iter().map(ContainerDriftData::from).collect(),
+        }
+    }
+}
+
+impl From<&FileDrift> for FileDriftData {
+    fn from(drift: &FileDrift) -> Self {
+        let (status, diff) = match &drift.status {
+            FileDriftStatus::Clean => ("clean", None),
+            FileDriftStatus::Modified { diff } => ("modified", Some(diff.clone())),
+            FileDriftStatus::Missing => ("missing", None),
+        };
+
+        Self {
+            name: drift.name.clone(),
+            remote_path: drift.remote_path.clone(),
+            status: status.to_string(),
+            diff,
+        }
+    }
+}
+
+impl From<&ImageDrift> for ContainerDriftData {
+    fn from(drift: &ImageDrift) -> Self {
+        let (status, running_digests) = match &drift.status {
+            ImageDriftStatus::Clean => ("clean", Vec::new()),
+            ImageDriftStatus::ImageChanged => ("image-changed", Vec::new()),
+            ImageDriftStatus::DigestMismatch { actual, .. } => ("digest-mismatch", actual.clone()),
+            ImageDriftStatus::Unexpected => ("unexpected", Vec::new()),
+            ImageDriftStatus::NotRunning => ("not-running", Vec::new()),
+        };
+
+        Self {
+            service: drift.service.clone(),
+            status: status.to_string(),
+            expected_image: drift.expected.clone(),
+            running_image: drift.running.clone(),
+            running_digests,
+        }
+    }
+}
@@ -0,0 +1,15 @@
+//! JSON View for Drift Reports
+//!
+//! This module provides JSON-based rendering for the drift command.
+
+use crate::presentation::cli::views::commands::drift::view_data::DriftData;
+use crate::presentation::cli::views::{Render, ViewRenderError};
+
+/// JSON view for rendering a drift report
+pub struct JsonView;
+
+impl Render<DriftData> for JsonView {
+    fn render(data: &DriftData) -> Result<String, ViewRenderError> {
+        Ok(serde_json::to_string_pretty(data)?)
+    }
+}
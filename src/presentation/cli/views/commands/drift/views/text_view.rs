@@ -0,0 +1,123 @@
+//! Text View for Drift Reports
+//!
+//! This module provides text-based rendering for the drift command: one
+//! line per release artifact and per container, the unified diff of every
+//! modified artifact, and the overall verdict.
+
+use crate::presentation::cli::views::commands::drift::view_data::DriftData;
+use crate::presentation::cli::views::{Render, ViewRenderError};
+
+/// Text view for rendering a drift report
+pub struct TextView;
+
+impl Render<DriftData> for TextView {
+    fn render(data: &DriftData) -> Result<String, ViewRenderError> {
+        let mut lines = vec![format!(
+            "Drift report for environment '{}':",
+            data.environment_name
+        )];
+
+        lines.push(String::new());
+        lines.push("Files:".to_string());
+        let width = data
+            .files
+            .iter()
+            .map(|file| file.name.len())
+            .max()
+            .unwrap_or_default();
+        for file in &data.files {
+            lines.push(format!(
+                "  {:<width$}  {:<8}  {}",
+                file.name, file.status, file.remote_path
+            ));
+        }
+
+        lines.push(String::new());
+        lines.push("Containers:".to_string());
+        if data.containers.is_empty() {
+            lines.push("  (no services)".to_string());
+        }
+        let width = data
+            .containers
+            .iter()
+            .map(|container| container.service.len())
+            .max()
+            .unwrap_or_default();
+        for container in &data.containers {
+            let detail = match (&container.expected_image, &container.running_image) {
+                (Some(expected), Some(running)) if expected != running => {
+                    format!("running {running}, expected {expected}")
+                }
+                (_, Some(running)) if !container.running_digests.is_empty() => format!(
+                    "running {running} with digest {}",
+                    container.running_digests.join(", ")
+                ),
+                (Some(image), _) | (None, Some(image)) => image.clone(),
+                (None, None) => String::new(),
+            };
+            lines.push(
+                format!(
+                    "  {:<width$}  {:<15}  {detail}",
+                    container.service, container.status
+                )
+                .trim_end()
+                .to_string(),
+            );
+        }
+
+        for file in &data.files {
+            if let Some(diff) = &file.diff {
+                lines.push(String::new());
+                lines.push(diff.trim_end().to_string());
+            }
+        }
+
+        lines.push(String::new());
+        lines.push(if data.drifted {
+            "Result: DRIFTED - the instance differs from the environment configuration".to_string()
+        } else {
+            "Result: CLEAN - the instance matches the environment configuration".to_string()
+        });
+
+        Ok(lines.join("\n"))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::presentation::cli::views::commands::drift::view_data::{
+        ContainerDriftData, FileDriftData,
+    };
+
+    #[test]
+    fn it_should_show_the_diff_of_the_modified_files_and_the_verdict() {
+        let data = DriftData {
+            environment_name: "my-env".to_string(),
+            drifted: true,
+            files: vec![FileDriftData {
+                name: "tracker.toml".to_string(),
+                remote_path: "/opt/torrust/storage/tracker/etc/tracker.toml".to_string(),
+                status: "modified".to_string(),
+                diff: Some("--- a\n+++ b\n-private = true\n+private = false\n".to_string()),
+            }],
+            containers: vec![ContainerDriftData {
+                service: "mysql".to_string(),
+                status: "image-changed".to_string(),
+                expected_image: Some("mysql:8.4".to_string()),
+                running_image: Some("mysql:8.0".to_string()),
+                running_digests: Vec::new(),
+            }],
+        };
+
+        let output = TextView::render(&data).unwrap();
+
+        assert!(output
+            .contains("  tracker.toml  modified  /opt/torrust/storage/tracker/etc/tracker.toml"));
+        assert!(output.contains("  mysql  image-changed    running mysql:8.0, expected mysql:8.4"));
+        assert!(output.contains("-private = true\n+private = false"));
+        assert!(output.ends_with(
+            "Result: DRIFTED - the instance differs from the environment configuration"
+        ));
+    }
+}
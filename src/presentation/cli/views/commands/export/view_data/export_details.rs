@@ -0,0 +1,39 @@
+//! Export Details Data Transfer Object
+//!
+//! This module contains the presentation DTO for export command details.
+//! It serves as the data structure passed to view renderers (`TextView`, `JsonView`).
+
+use serde::Serialize;
+
+use crate::application::command_handlers::export::ExportedBundle;
+use crate::infrastructure::bundle::BundleSecrets;
+
+/// Export details data for rendering
+///
+/// Built from the written bundle, on the success path only: export failures
+/// result in an error return.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct ExportDetailsData {
+    /// Name of the exported environment
+    pub environment_name: String,
+    /// Path of the bundle file
+    pub bundle_path: String,
+    /// Number of files in the bundle
+    pub file_count: usize,
+    /// Total size of the files in the bundle, before compression
+    pub content_size_bytes: u64,
+    /// Whether the SSH private key and the environment secrets are included
+    pub secrets: BundleSecrets,
+}
+
+impl From<&ExportedBundle> for ExportDetailsData {
+    fn from(bundle: &ExportedBundle) -> Self {
+        Self {
+            environment_name: bundle.manifest.environment.clone(),
+            bundle_path: bundle.path.display().to_string(),
+            file_count: bundle.manifest.files.len(),
+            content_size_bytes: bundle.content_size(),
+            secrets: bundle.manifest.secrets,
+        }
+    }
+}
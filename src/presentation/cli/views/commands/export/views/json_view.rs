@@ -0,0 +1,43 @@
+//! JSON View for Export Command
+//!
+//! This module provides JSON-based rendering for the export command.
+//! It follows the Strategy Pattern, providing a machine-readable output format
+//! for the same underlying data (`ExportDetailsData` DTO).
+
+use crate::presentation::cli::views::commands::export::ExportDetailsData;
+use crate::presentation::cli::views::{Render, ViewRenderError};
+
+/// View for rendering export details as JSON
+///
+/// This view provides machine-readable JSON output for automation workflows
+/// and AI agents. It serializes the export details without any transformations.
+pub struct JsonView;
+
+impl Render<ExportDetailsData> for JsonView {
+    fn render(data: &ExportDetailsData) -> Result<String, ViewRenderError> {
+        Ok(serde_json::to_string_pretty(data)?)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::infrastructure::bundle::BundleSecrets;
+
+    #[test]
+    fn it_should_render_secrets_in_snake_case() {
+        let data = ExportDetailsData {
+            environment_name: "prod".to_string(),
+            bundle_path: "prod-bundle.tar.gz".to_string(),
+            file_count: 12,
+            content_size_bytes: 4096,
+            secrets: BundleSecrets::Encrypted,
+        };
+
+        let json = JsonView::render(&data).unwrap();
+
+        let parsed: serde_json::Value = serde_json::from_str(&json).unwrap();
+        assert_eq!(parsed["bundle_path"], "prod-bundle.tar.gz");
+        assert_eq!(parsed["secrets"], "encrypted");
+    }
+}
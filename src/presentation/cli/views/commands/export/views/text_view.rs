@@ -0,0 +1,63 @@
+//! Text View for Export Command
+//!
+//! This module provides text-based rendering for the export command.
+//! It follows the Strategy Pattern, providing a human-readable output format
+//! for the same underlying data (`ExportDetailsData` DTO).
+
+use crate::infrastructure::bundle::BundleSecrets;
+use crate::presentation::cli::views::commands::export::ExportDetailsData;
+use crate::presentation::cli::views::{Render, ViewRenderError};
+
+/// View for rendering export details as human-readable text
+///
+/// The rendered string is intended to be passed to `ProgressReporter::complete()`,
+/// which adds the `✅` prefix to the first line.
+pub struct TextView;
+
+impl Render<ExportDetailsData> for TextView {
+    fn render(data: &ExportDetailsData) -> Result<String, ViewRenderError> {
+        let secrets = match data.secrets {
+            BundleSecrets::Encrypted => "included, encrypted with the passphrase",
+            BundleSecrets::Excluded => "excluded (the bundle cannot be imported)",
+        };
+
+        Ok([
+            format!(
+                "Environment '{}' exported to {}",
+                data.environment_name, data.bundle_path
+            ),
+            format!(
+                "  Files:     {} ({} bytes uncompressed)",
+                data.file_count, data.content_size_bytes
+            ),
+            format!("  Secrets:   {secrets}"),
+            format!(
+                "  Import it on another machine with: import-bundle {}",
+                data.bundle_path
+            ),
+        ]
+        .join("\n"))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn it_should_render_the_bundle_path_and_whether_secrets_are_included() {
+        let data = ExportDetailsData {
+            environment_name: "prod".to_string(),
+            bundle_path: "prod-bundle.tar.gz".to_string(),
+            file_count: 12,
+            content_size_bytes: 4096,
+            secrets: BundleSecrets::Excluded,
+        };
+
+        let text = TextView::render(&data).unwrap();
+
+        assert!(text.starts_with("Environment 'prod' exported to prod-bundle.tar.gz"));
+        assert!(text.contains("12 (4096 bytes uncompressed)"));
+        assert!(text.contains("excluded"));
+    }
+}
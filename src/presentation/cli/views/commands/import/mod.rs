@@ -0,0 +1,32 @@
+//! Views for Import Command
+//!
+//! This module contains view components for rendering import command output.
+//!
+//! # Architecture
+//!
+//! This module follows the Strategy Pattern for rendering:
+//! - `ImportDetailsData`: The data DTO passed to all views
+//! - `TextView`: Renders human-readable text output
+//! - `JsonView`: Renders machine-readable JSON output
+//!
+//! # Structure
+//!
+//! - `view_data.rs`: Data structures (DTOs) prepared for view rendering
+//! - `views/`: View rendering implementations
+//!   - `text_view.rs`: Human-readable rendering
+//!   - `json_view.rs`: JSON output for automation workflows
+
+pub mod view_data;
+
+pub mod views {
+    pub mod json_view;
+    pub mod text_view;
+
+    // Re-export main types for convenience
+    pub use json_view::JsonView;
+    pub use text_view::TextView;
+}
+
+// Re-export at module root for convenience
+pub use view_data::{DetectedInstallationData, ImportDetailsData};
+pub use views::{JsonView, TextView};
@@ -0,0 +1,62 @@
+//! View data for the import command
+
+use serde::Serialize;
+
+use crate::application::command_handlers::import::ImportOutcome;
+use crate::infrastructure::remote_actions::ExistingInstallation;
+
+/// Data for rendering the result of an import
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct ImportDetailsData {
+    /// Name of the imported environment
+    pub environment_name: String,
+    /// IP address of the imported instance (empty string if unknown)
+    pub instance_ip: String,
+    /// SSH port of the imported instance
+    pub ssh_port: u16,
+    /// State the environment starts in (e.g. `provisioned`)
+    pub state: String,
+    /// Always `true`: `destroy` never touches the instance itself
+    pub externally_provisioned: bool,
+    /// What was found on the instance, `None` when detection was not requested
+    pub detected: Option<DetectedInstallationData>,
+}
+
+/// Software found on the imported instance
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+pub struct DetectedInstallationData {
+    /// Docker engine is installed
+    pub docker: bool,
+    /// Docker Compose plugin is installed
+    pub docker_compose: bool,
+    /// A tracker container is running
+    pub tracker_running: bool,
+}
+
+impl From<ExistingInstallation> for DetectedInstallationData {
+    fn from(installation: ExistingInstallation) -> Self {
+        Self {
+            docker: installation.docker_installed,
+            docker_compose: installation.compose_installed,
+            tracker_running: installation.tracker_running,
+        }
+    }
+}
+
+impl From<&ImportOutcome> for ImportDetailsData {
+    fn from(outcome: &ImportOutcome) -> Self {
+        let environment = &outcome.environment;
+
+        Self {
+            environment_name: environment.name().to_string(),
+            instance_ip: environment
+                .instance_ip()
+                .map(|ip| ip.to_string())
+                .unwrap_or_default(),
+            ssh_port: environment.ssh_port(),
+            state: environment.state_name().to_string(),
+            externally_provisioned: environment.is_externally_provisioned(),
+            detected: outcome.installation.map(DetectedInstallationData::from),
+        }
+    }
+}
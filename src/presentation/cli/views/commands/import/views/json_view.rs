@@ -0,0 +1,18 @@
+//! JSON View for Import Command
+//!
+//! This module provides JSON-based rendering for the import command.
+
+use crate::presentation::cli::views::commands::import::ImportDetailsData;
+use crate::presentation::cli::views::{Render, ViewRenderError};
+
+/// View for rendering import details as JSON
+///
+/// This view provides machine-readable JSON output for automation workflows
+/// and AI agents. It serializes the import details without any transformations.
+pub struct JsonView;
+
+impl Render<ImportDetailsData> for JsonView {
+    fn render(data: &ImportDetailsData) -> Result<String, ViewRenderError> {
+        Ok(serde_json::to_string_pretty(data)?)
+    }
+}
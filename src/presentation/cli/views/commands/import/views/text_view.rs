@@ -0,0 +1,92 @@
+//! Text View for Import Command
+//!
+//! This module provides text-based rendering for the import command.
+
+use crate::presentation::cli::views::commands::import::ImportDetailsData;
+use crate::presentation::cli::views::{Render, ViewRenderError};
+
+/// View for rendering import details as human-readable text
+///
+/// The rendered string is intended to be passed to `ProgressReporter::complete()`,
+/// which adds the `✅` prefix to the first line.
+pub struct TextView;
+
+impl Render<ImportDetailsData> for TextView {
+    fn render(data: &ImportDetailsData) -> Result<String, ViewRenderError> {
+        let mut lines = vec![format!(
+            "Instance {} imported as environment '{}' (state: {})",
+            data.instance_ip, data.environment_name, data.state
+        )];
+
+        if let Some(detected) = data.detected {
+            lines.push(format!(
+                "Detected: Docker {}, Docker Compose {}, tracker {}",
+                if detected.docker { "yes" } else { "no" },
+                if detected.docker_compose { "yes" } else { "no" },
+                if detected.tracker_running {
+                    "running"
+                } else {
+                    "not running"
+                }
+            ));
+        }
+
+        lines.push(
+            "The instance is externally provisioned: 'destroy' only removes local state."
+                .to_string(),
+        );
+
+        let next_command = match data.state.as_str() {
+            "configured" => "release",
+            "running" => "test",
+            _ => "configure",
+        };
+        lines.push(format!(
+            "Next: torrust-tracker-deployer {next_command} {}",
+            data.environment_name
+        ));
+
+        Ok(lines.join("\n"))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::presentation::cli::views::commands::import::DetectedInstallationData;
+
+    fn create_test_data(state: &str) -> ImportDetailsData {
+        ImportDetailsData {
+            environment_name: "legacy".to_string(),
+            instance_ip: "10.0.0.5".to_string(),
+            ssh_port: 22,
+            state: state.to_string(),
+            externally_provisioned: true,
+            detected: None,
+        }
+    }
+
+    #[test]
+    fn it_should_suggest_configure_after_a_plain_import() {
+        let output = TextView::render(&create_test_data("provisioned")).unwrap();
+
+        assert!(output.contains("Instance 10.0.0.5 imported as environment 'legacy'"));
+        assert!(output.contains("Next: torrust-tracker-deployer configure legacy"));
+        assert!(!output.contains("Detected:"));
+    }
+
+    #[test]
+    fn it_should_render_the_detected_installation() {
+        let mut data = create_test_data("configured");
+        data.detected = Some(DetectedInstallationData {
+            docker: true,
+            docker_compose: true,
+            tracker_running: false,
+        });
+
+        let output = TextView::render(&data).unwrap();
+
+        assert!(output.contains("Detected: Docker yes, Docker Compose yes, tracker not running"));
+        assert!(output.contains("Next: torrust-tracker-deployer release legacy"));
+    }
+}
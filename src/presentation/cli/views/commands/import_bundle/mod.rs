@@ -0,0 +1,38 @@
+//! Views for Import Bundle Command
+//!
+//! This module contains view components for rendering import-bundle command output.
+//!
+//! # Architecture
+//!
+//! This module follows the Strategy Pattern for rendering:
+//! - `ImportBundleDetailsData`: The data DTO passed to all views
+//! - `TextView`: Renders human-readable text output
+//! - `JsonView`: Renders machine-readable JSON output
+//!
+//! # Structure
+//!
+//! - `view_data/`: Data structures (DTOs) passed to views
+//!   - `import_bundle_details.rs`: Main DTO with the imported environment
+//! - `views/`: View rendering implementations
+//!   - `text_view.rs`: Human-readable text rendering
+//!   - `json_view.rs`: Machine-readable JSON rendering
+
+pub mod view_data {
+    pub mod import_bundle_details;
+
+    // Re-export main types for convenience
+    pub use import_bundle_details::ImportBundleDetailsData;
+}
+
+pub mod views {
+    pub mod json_view;
+    pub mod text_view;
+
+    // Re-export views for convenience
+    pub use json_view::JsonView;
+    pub use text_view::TextView;
+}
+
+// Re-export at module root for convenience
+pub use view_data::ImportBundleDetailsData;
+pub use views::{JsonView, TextView};
@@ -0,0 +1,54 @@
+//! Import Bundle Details Data Transfer Object
+//!
+//! This module contains the presentation DTO for import-bundle command details.
+//! It serves as the data structure passed to view renderers (`TextView`, `JsonView`).
+
+use chrono::{DateTime, Utc};
+use serde::Serialize;
+
+use crate::application::command_handlers::import_bundle::ImportedBundle;
+
+/// Import bundle details data for rendering
+///
+/// Built from the imported environment, on the success path only: import
+/// failures result in an error return.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct ImportBundleDetailsData {
+    /// Name of the imported environment
+    pub environment_name: String,
+    /// Current state of the imported environment
+    pub state: String,
+    /// Data directory of the imported environment
+    pub data_dir: String,
+    /// Build directory of the imported environment
+    pub build_dir: String,
+    /// Path the SSH private key is expected at
+    pub ssh_private_key: String,
+    /// Whether the SSH private key was imported from the bundle
+    pub private_key_imported: bool,
+    /// When the bundle was exported
+    pub exported_at: DateTime<Utc>,
+    /// Version of the deployer that exported the bundle
+    pub exported_by: String,
+}
+
+impl From<&ImportedBundle> for ImportBundleDetailsData {
+    fn from(imported: &ImportedBundle) -> Self {
+        let environment = &imported.environment;
+
+        Self {
+            environment_name: environment.name().to_string(),
+            state: environment.state_display_name().to_string(),
+            data_dir: environment.data_dir().display().to_string(),
+            build_dir: environment.build_dir().display().to_string(),
+            ssh_private_key: environment
+                .ssh_credentials()
+                .ssh_priv_key_path
+                .display()
+                .to_string(),
+            private_key_imported: imported.private_key_imported(),
+            exported_at: imported.manifest.exported_at,
+            exported_by: imported.manifest.deployer_version.clone(),
+        }
+    }
+}
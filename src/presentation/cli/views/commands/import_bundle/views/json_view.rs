@@ -0,0 +1,20 @@
+//! JSON View for Import Bundle Command
+//!
+//! This module provides JSON-based rendering for the import-bundle command.
+//! It follows the Strategy Pattern, providing a machine-readable output format
+//! for the same underlying data (`ImportBundleDetailsData` DTO).
+
+use crate::presentation::cli::views::commands::import_bundle::ImportBundleDetailsData;
+use crate::presentation::cli::views::{Render, ViewRenderError};
+
+/// View for rendering import-bundle details as JSON
+///
+/// This view provides machine-readable JSON output for automation workflows
+/// and AI agents. It serializes the import details without any transformations.
+pub struct JsonView;
+
+impl Render<ImportBundleDetailsData> for JsonView {
+    fn render(data: &ImportBundleDetailsData) -> Result<String, ViewRenderError> {
+        Ok(serde_json::to_string_pretty(data)?)
+    }
+}
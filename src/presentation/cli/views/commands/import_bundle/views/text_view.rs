@@ -0,0 +1,82 @@
+//! Text View for Import Bundle Command
+//!
+//! This module provides text-based rendering for the import-bundle command.
+//! It follows the Strategy Pattern, providing a human-readable output format
+//! for the same underlying data (`ImportBundleDetailsData` DTO).
+
+use crate::presentation::cli::views::commands::import_bundle::ImportBundleDetailsData;
+use crate::presentation::cli::views::{Render, ViewRenderError};
+
+/// View for rendering import-bundle details as human-readable text
+///
+/// The rendered string is intended to be passed to `ProgressReporter::complete()`,
+/// which adds the `✅` prefix to the first line.
+pub struct TextView;
+
+impl Render<ImportBundleDetailsData> for TextView {
+    fn render(data: &ImportBundleDetailsData) -> Result<String, ViewRenderError> {
+        let mut lines = vec![
+            format!("Environment '{}' imported", data.environment_name),
+            format!("  State:             {}", data.state),
+            format!("  Data directory:    {}", data.data_dir),
+            format!("  Build directory:   {}", data.build_dir),
+            format!("  SSH private key:   {}", data.ssh_private_key),
+            format!(
+                "  Exported at:       {} (deployer {})",
+                data.exported_at.format("%Y-%m-%d %H:%M:%S UTC"),
+                data.exported_by
+            ),
+        ];
+
+        if !data.private_key_imported {
+            lines.push(
+                "  Note: the bundle has no secrets: copy the SSH private key to the path above"
+                    .to_string(),
+            );
+        }
+
+        Ok(lines.join("\n"))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::{TimeZone, Utc};
+
+    use super::*;
+
+    fn create_test_data() -> ImportBundleDetailsData {
+        ImportBundleDetailsData {
+            environment_name: "prod".to_string(),
+            state: "Running".to_string(),
+            data_dir: "./data/prod".to_string(),
+            build_dir: "./build/prod".to_string(),
+            ssh_private_key: "./data/prod/ssh/id_ed25519".to_string(),
+            private_key_imported: true,
+            exported_at: Utc.with_ymd_and_hms(2026, 10, 1, 12, 0, 0).unwrap(),
+            exported_by: "0.1.0".to_string(),
+        }
+    }
+
+    #[test]
+    fn it_should_render_the_directories_of_the_imported_environment() {
+        let text = TextView::render(&create_test_data()).unwrap();
+
+        assert!(text.starts_with("Environment 'prod' imported"));
+        assert!(text.contains("./build/prod"));
+        assert!(text.contains("2026-10-01 12:00:00 UTC (deployer 0.1.0)"));
+        assert!(!text.contains("Note:"));
+    }
+
+    #[test]
+    fn it_should_ask_for_the_private_key_when_secrets_were_excluded() {
+        let data = ImportBundleDetailsData {
+            private_key_imported: false,
+            ..create_test_data()
+        };
+
+        let text = TextView::render(&data).unwrap();
+
+        assert!(text.contains("copy the SSH private key"));
+    }
+}
@@ -0,0 +1,61 @@
+//! Init Details Data Transfer Object
+//!
+//! This module contains the presentation DTO for init command details.
+//! It serves as the data structure passed to view renderers (`TextView`, `JsonView`).
+
+use serde::Serialize;
+
+use crate::application::command_handlers::init::WorkspaceInitialization;
+
+/// Init details data for rendering
+///
+/// Built from the initialized workspace, on the success path only: init
+/// failures result in an error return.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct InitDetailsData {
+    /// Root of the workspace, holding the marker file
+    pub working_dir: String,
+    /// Directory holding one data directory per environment
+    pub data_dir: String,
+    /// Directory holding one build directory per environment
+    pub build_dir: String,
+    /// Format version recorded in the marker
+    pub format_version: u32,
+    /// Whether the workspace was already initialized
+    pub already_initialized: bool,
+    /// Environments of a workspace created without marker
+    pub migrated_environments: Vec<String>,
+    /// Path of the workspace key, when the workspace has one
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub workspace_key: Option<String>,
+    /// Whether the workspace key was created by this run
+    pub workspace_key_created: bool,
+    /// Environments whose plaintext secrets were sealed with the key
+    pub sealed_environments: Vec<String>,
+}
+
+impl From<&WorkspaceInitialization> for InitDetailsData {
+    fn from(initialization: &WorkspaceInitialization) -> Self {
+        Self {
+            working_dir: initialization.working_dir.display().to_string(),
+            data_dir: initialization.data_dir.display().to_string(),
+            build_dir: initialization.build_dir.display().to_string(),
+            format_version: initialization.format_version,
+            already_initialized: initialization.already_initialized,
+            migrated_environments: initialization.migrated_environments.clone(),
+            workspace_key: initialization
+                .secret_sealing
+                .as_ref()
+                .map(|sealing| sealing.key_path.display().to_string()),
+            workspace_key_created: initialization
+                .secret_sealing
+                .as_ref()
+                .is_some_and(|sealing| sealing.key_created),
+            sealed_environments: initialization
+                .secret_sealing
+                .as_ref()
+                .map(|sealing| sealing.sealed_environments.clone())
+                .unwrap_or_default(),
+        }
+    }
+}
@@ -0,0 +1,47 @@
+//! JSON View for Init Command
+//!
+//! This module provides JSON-based rendering for the init command.
+//! It follows the Strategy Pattern, providing a machine-readable output format
+//! for the same underlying data (`InitDetailsData` DTO).
+
+use crate::presentation::cli::views::commands::init::InitDetailsData;
+use crate::presentation::cli::views::{Render, ViewRenderError};
+
+/// View for rendering init details as JSON
+///
+/// This view provides machine-readable JSON output for automation workflows
+/// and AI agents. It serializes the init details without any transformations.
+pub struct JsonView;
+
+impl Render<InitDetailsData> for JsonView {
+    fn render(data: &InitDetailsData) -> Result<String, ViewRenderError> {
+        Ok(serde_json::to_string_pretty(data)?)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn it_should_render_the_workspace_layout_as_json() {
+        let data = InitDetailsData {
+            working_dir: ".".to_string(),
+            data_dir: "./data".to_string(),
+            build_dir: "./build".to_string(),
+            format_version: 1,
+            already_initialized: false,
+            migrated_environments: vec!["prod".to_string()],
+            workspace_key: None,
+            workspace_key_created: false,
+            sealed_environments: Vec::new(),
+        };
+
+        let json = JsonView::render(&data).unwrap();
+
+        let parsed: serde_json::Value = serde_json::from_str(&json).unwrap();
+        assert_eq!(parsed["format_version"], 1);
+        assert_eq!(parsed["already_initialized"], false);
+        assert_eq!(parsed["migrated_environments"][0], "prod");
+    }
+}
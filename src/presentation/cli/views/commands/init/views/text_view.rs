@@ -0,0 +1,111 @@
+//! Text View for Init Command
+//!
+//! This module provides text-based rendering for the init command.
+//! It follows the Strategy Pattern, providing a human-readable output format
+//! for the same underlying data (`InitDetailsData` DTO).
+
+use crate::presentation::cli::views::commands::init::InitDetailsData;
+use crate::presentation::cli::views::{Render, ViewRenderError};
+
+/// View for rendering init details as human-readable text
+///
+/// The rendered string is intended to be passed to `ProgressReporter::complete()`,
+/// which adds the `✅` prefix to the first line.
+pub struct TextView;
+
+impl Render<InitDetailsData> for TextView {
+    fn render(data: &InitDetailsData) -> Result<String, ViewRenderError> {
+        let headline = if data.already_initialized {
+            format!("Workspace '{}' is already initialized", data.working_dir)
+        } else {
+            format!("Workspace '{}' initialized", data.working_dir)
+        };
+
+        let mut lines = vec![
+            headline,
+            format!("  Format version:    {}", data.format_version),
+            format!("  Data directory:    {}", data.data_dir),
+            format!("  Build directory:   {}", data.build_dir),
+        ];
+
+        if !data.migrated_environments.is_empty() {
+            lines.push(format!(
+                "  Migrated environments: {}",
+                data.migrated_environments.join(", ")
+            ));
+        }
+
+        if let Some(workspace_key) = &data.workspace_key {
+            let created = if data.workspace_key_created {
+                " (created, back it up)"
+            } else {
+                ""
+            };
+            lines.push(format!("  Workspace key:     {workspace_key}{created}"));
+        }
+
+        if !data.sealed_environments.is_empty() {
+            lines.push(format!(
+                "  Secrets encrypted: {}",
+                data.sealed_environments.join(", ")
+            ));
+        }
+
+        Ok(lines.join("\n"))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn create_test_data() -> InitDetailsData {
+        InitDetailsData {
+            working_dir: ".".to_string(),
+            data_dir: "./data".to_string(),
+            build_dir: "./build".to_string(),
+            format_version: 1,
+            already_initialized: false,
+            migrated_environments: Vec::new(),
+            workspace_key: None,
+            workspace_key_created: false,
+            sealed_environments: Vec::new(),
+        }
+    }
+
+    #[test]
+    fn it_should_render_the_layout_of_a_new_workspace() {
+        let text = TextView::render(&create_test_data()).unwrap();
+
+        assert!(text.starts_with("Workspace '.' initialized"));
+        assert!(text.contains("./data"));
+        assert!(!text.contains("Migrated"));
+    }
+
+    #[test]
+    fn it_should_list_the_migrated_environments() {
+        let data = InitDetailsData {
+            migrated_environments: vec!["prod".to_string(), "staging".to_string()],
+            ..create_test_data()
+        };
+
+        let text = TextView::render(&data).unwrap();
+
+        assert!(text.contains("Migrated environments: prod, staging"));
+    }
+
+    #[test]
+    fn it_should_show_a_created_workspace_key_and_the_sealed_environments() {
+        let data = InitDetailsData {
+            workspace_key: Some("/home/user/.config/torrust-deployer/workspace.key".to_string()),
+            workspace_key_created: true,
+            sealed_environments: vec!["prod".to_string()],
+            ..create_test_data()
+        };
+
+        let text = TextView::render(&data).unwrap();
+
+        assert!(text.contains("workspace.key (created, back it up)"));
+        assert!(text.contains("Secrets encrypted: prod"));
+    }
+}
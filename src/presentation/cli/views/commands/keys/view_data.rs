@@ -0,0 +1,84 @@
+//! View data for the keys command
+
+use chrono::{DateTime, Utc};
+use serde::Serialize;
+
+use crate::application::command_handlers::keys::ListedAuthKey;
+use crate::domain::tracker::IssuedAuthKey;
+
+/// Data for rendering a key of the tracker
+#[derive(Debug, Clone, Serialize)]
+pub struct KeyData {
+    /// The key to append to the announce URL
+    pub key: String,
+    /// When the tracker stops accepting the key, `None` for a permanent key
+    pub valid_until: Option<DateTime<Utc>>,
+    /// Whether the tracker no longer accepts the key
+    pub expired: bool,
+}
+
+impl From<&ListedAuthKey> for KeyData {
+    fn from(listed: &ListedAuthKey) -> Self {
+        Self {
+            key: listed.key.key.to_string(),
+            valid_until: listed.key.valid_until,
+            expired: listed.expired,
+        }
+    }
+}
+
+/// Data for rendering the key minted by `keys add`
+#[derive(Debug, Clone, Serialize)]
+pub struct AddedKeyData {
+    /// Name of the environment
+    pub environment: String,
+    /// The new key
+    #[serde(flatten)]
+    pub key: KeyData,
+}
+
+impl AddedKeyData {
+    /// Build the view data from the key issued by the tracker
+    #[must_use]
+    pub fn new(environment: &str, issued: &IssuedAuthKey) -> Self {
+        Self {
+            environment: environment.to_string(),
+            key: KeyData {
+                key: issued.key.to_string(),
+                valid_until: issued.valid_until,
+                expired: false,
+            },
+        }
+    }
+}
+
+/// Data for rendering the keys recorded for an environment
+#[derive(Debug, Clone, Serialize)]
+pub struct KeyListData {
+    /// Name of the environment
+    pub environment: String,
+    /// The recorded keys, in the order they were added
+    pub keys: Vec<KeyData>,
+}
+
+impl KeyListData {
+    /// Build the view data from the recorded keys
+    #[must_use]
+    pub fn new(environment: &str, keys: &[ListedAuthKey]) -> Self {
+        Self {
+            environment: environment.to_string(),
+            keys: keys.iter().map(KeyData::from).collect(),
+        }
+    }
+}
+
+/// Data for rendering the key revoked by `keys remove`
+#[derive(Debug, Clone, Serialize)]
+pub struct RemovedKeyData {
+    /// Name of the environment
+    pub environment: String,
+    /// The revoked key
+    pub key: String,
+    /// Whether the key was recorded for the environment
+    pub was_recorded: bool,
+}
@@ -0,0 +1,29 @@
+//! JSON View for Tracker Keys
+//!
+//! This module provides JSON-based rendering for the keys command.
+
+use crate::presentation::cli::views::commands::keys::view_data::{
+    AddedKeyData, KeyListData, RemovedKeyData,
+};
+use crate::presentation::cli::views::{Render, ViewRenderError};
+
+/// JSON view for rendering the keys of a private tracker
+pub struct JsonView;
+
+impl Render<AddedKeyData> for JsonView {
+    fn render(data: &AddedKeyData) -> Result<String, ViewRenderError> {
+        Ok(serde_json::to_string_pretty(data)?)
+    }
+}
+
+impl Render<KeyListData> for JsonView {
+    fn render(data: &KeyListData) -> Result<String, ViewRenderError> {
+        Ok(serde_json::to_string_pretty(data)?)
+    }
+}
+
+impl Render<RemovedKeyData> for JsonView {
+    fn render(data: &RemovedKeyData) -> Result<String, ViewRenderError> {
+        Ok(serde_json::to_string_pretty(data)?)
+    }
+}
@@ -0,0 +1,154 @@
+//! Text View for Tracker Keys
+//!
+//! This module provides text-based rendering for the keys command: the key
+//! added, the recorded keys with their expiry times, or the key removed.
+
+use crate::presentation::cli::views::commands::keys::view_data::{
+    AddedKeyData, KeyData, KeyListData, RemovedKeyData,
+};
+use crate::presentation::cli::views::{Render, ViewRenderError};
+
+/// Text view for rendering the keys of a private tracker
+pub struct TextView;
+
+/// Validity of a key, e.g. `valid until 2026-02-01 00:00:00 UTC`
+fn validity(key: &KeyData) -> String {
+    match key.valid_until {
+        None => "permanent".to_string(),
+        Some(valid_until) if key.expired => {
+            format!("expired {}", valid_until.format("%Y-%m-%d %H:%M:%S UTC"))
+        }
+        Some(valid_until) => {
+            format!(
+                "valid until {}",
+                valid_until.format("%Y-%m-%d %H:%M:%S UTC")
+            )
+        }
+    }
+}
+
+impl Render<AddedKeyData> for TextView {
+    fn render(data: &AddedKeyData) -> Result<String, ViewRenderError> {
+        let lines = [
+            format!(
+                "Key added to the tracker of environment '{}':",
+                data.environment
+            ),
+            format!("  Key:      {}", data.key.key),
+            format!("  Validity: {}", validity(&data.key)),
+            String::new(),
+            "Peers announce with the key appended to the announce URL:".to_string(),
+            format!("  http://<tracker>:<port>/announce/{}", data.key.key),
+        ];
+
+        Ok(lines.join("\n"))
+    }
+}
+
+impl Render<KeyListData> for TextView {
+    fn render(data: &KeyListData) -> Result<String, ViewRenderError> {
+        if data.keys.is_empty() {
+            return Ok(format!(
+                "No keys recorded for environment '{0}'.\nAdd one with: torrust-tracker-deployer keys add {0} --duration 30d",
+                data.environment
+            ));
+        }
+
+        let mut lines = vec![format!(
+            "Keys of environment '{}' ({}):",
+            data.environment,
+            data.keys.len()
+        )];
+        lines.extend(
+            data.keys
+                .iter()
+                .map(|key| format!("  {}  {}", key.key, validity(key))),
+        );
+
+        Ok(lines.join("\n"))
+    }
+}
+
+impl Render<RemovedKeyData> for TextView {
+    fn render(data: &RemovedKeyData) -> Result<String, ViewRenderError> {
+        let mut output = format!(
+            "Key {} removed from the tracker of environment '{}'",
+            data.key, data.environment
+        );
+
+        if !data.was_recorded {
+            output.push_str("\nThe key was not added with this tool, so it was not listed");
+        }
+
+        Ok(output)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::{TimeZone, Utc};
+
+    use super::*;
+
+    const KEY: &str = "YZSl4lMZupRuOpSRC3krIKR5BPB14nrJ";
+
+    fn key(valid_until: Option<(i32, u32)>, expired: bool) -> KeyData {
+        KeyData {
+            key: KEY.to_string(),
+            valid_until: valid_until
+                .map(|(year, month)| Utc.with_ymd_and_hms(year, month, 1, 0, 0, 0).unwrap()),
+            expired,
+        }
+    }
+
+    #[test]
+    fn it_should_show_the_added_key_and_how_to_announce_with_it() {
+        let data = AddedKeyData {
+            environment: "prod".to_string(),
+            key: key(Some((2026, 2)), false),
+        };
+
+        let output = TextView::render(&data).unwrap();
+
+        assert!(output.contains(&format!("  Key:      {KEY}")));
+        assert!(output.contains("  Validity: valid until 2026-02-01 00:00:00 UTC"));
+        assert!(output.contains(&format!("/announce/{KEY}")));
+    }
+
+    #[test]
+    fn it_should_list_the_keys_with_their_validity() {
+        let data = KeyListData {
+            environment: "prod".to_string(),
+            keys: vec![
+                key(Some((2026, 2)), false),
+                key(None, false),
+                key(Some((2025, 12)), true),
+            ],
+        };
+
+        let output = TextView::render(&data).unwrap();
+
+        assert_eq!(
+            output,
+            [
+                "Keys of environment 'prod' (3):".to_string(),
+                format!("  {KEY}  valid until 2026-02-01 00:00:00 UTC"),
+                format!("  {KEY}  permanent"),
+                format!("  {KEY}  expired 2025-12-01 00:00:00 UTC"),
+            ]
+            .join("\n")
+        );
+    }
+
+    #[test]
+    fn it_should_suggest_adding_a_key_when_none_is_recorded() {
+        let data = KeyListData {
+            environment: "prod".to_string(),
+            keys: vec![],
+        };
+
+        let output = TextView::render(&data).unwrap();
+
+        assert!(output.contains("keys add prod --duration 30d"));
+    }
+}
@@ -0,0 +1,36 @@
+//! Label Details Data Transfer Object
+//!
+//! This module contains the presentation DTO for label command details.
+//! It serves as the data structure passed to view renderers (`TextView`, `JsonView`).
+
+use std::collections::BTreeMap;
+
+use serde::Serialize;
+
+use crate::domain::environment::state::AnyEnvironmentState;
+
+/// Label details data for rendering
+///
+/// Built from the environment after its labels were changed, so it always
+/// shows the labels as persisted.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct LabelDetailsData {
+    /// Name of the environment
+    pub environment_name: String,
+    /// Current state of the environment (unchanged by labeling)
+    pub state: String,
+    /// Labels of the environment, sorted by key
+    pub labels: BTreeMap<String, String>,
+}
+
+impl LabelDetailsData {
+    /// Construct a `LabelDetailsData` from the labeled environment
+    #[must_use]
+    pub fn from_environment(environment: &AnyEnvironmentState) -> Self {
+        Self {
+            environment_name: environment.name().to_string(),
+            state: environment.state_display_name().to_string(),
+            labels: environment.labels().as_map().clone(),
+        }
+    }
+}
@@ -0,0 +1,42 @@
+//! JSON View for Label Command
+//!
+//! This module provides JSON-based rendering for the label command.
+//! It follows the Strategy Pattern, providing a machine-readable output format
+//! for the same underlying data (`LabelDetailsData` DTO).
+
+use crate::presentation::cli::views::commands::label::LabelDetailsData;
+use crate::presentation::cli::views::{Render, ViewRenderError};
+
+/// View for rendering label details as JSON
+///
+/// The labels are always present, as an empty object when none is set, so
+/// automation can read them without checking for the key.
+pub struct JsonView;
+
+impl Render<LabelDetailsData> for JsonView {
+    fn render(data: &LabelDetailsData) -> Result<String, ViewRenderError> {
+        Ok(serde_json::to_string_pretty(data)?)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::BTreeMap;
+
+    use super::*;
+
+    #[test]
+    fn it_should_render_the_labels_as_an_object_even_when_empty() {
+        let data = LabelDetailsData {
+            environment_name: "tmp".to_string(),
+            state: "Created".to_string(),
+            labels: BTreeMap::new(),
+        };
+
+        let json = JsonView::render(&data).unwrap();
+
+        let parsed: serde_json::Value = serde_json::from_str(&json).unwrap();
+        assert_eq!(parsed["environment_name"], "tmp");
+        assert_eq!(parsed["labels"], serde_json::json!({}));
+    }
+}
@@ -0,0 +1,68 @@
+//! Text View for Label Command
+//!
+//! This module provides text-based rendering for the label command.
+//! It follows the Strategy Pattern, providing a human-readable output format
+//! for the same underlying data (`LabelDetailsData` DTO).
+
+use crate::presentation::cli::views::commands::label::LabelDetailsData;
+use crate::presentation::cli::views::{Render, ViewRenderError};
+
+/// View for rendering label details as human-readable text
+///
+/// The rendered string is intended to be passed to `ProgressReporter::complete()`,
+/// which adds the `✅` prefix to the first line.
+pub struct TextView;
+
+impl Render<LabelDetailsData> for TextView {
+    fn render(data: &LabelDetailsData) -> Result<String, ViewRenderError> {
+        let mut lines = vec![format!("Labels of environment '{}'", data.environment_name)];
+
+        if data.labels.is_empty() {
+            lines.push("  (no labels)".to_string());
+        }
+        for (key, value) in &data.labels {
+            lines.push(format!("  {key}={value}"));
+        }
+
+        Ok(lines.join("\n"))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::BTreeMap;
+
+    use super::*;
+
+    #[test]
+    fn it_should_render_one_label_per_line_sorted_by_key() {
+        let data = LabelDetailsData {
+            environment_name: "tmp".to_string(),
+            state: "Running".to_string(),
+            labels: BTreeMap::from([
+                ("team".to_string(), "infra".to_string()),
+                ("purpose".to_string(), "e2e".to_string()),
+            ]),
+        };
+
+        let text = TextView::render(&data).unwrap();
+
+        assert_eq!(
+            text,
+            "Labels of environment 'tmp'\n  purpose=e2e\n  team=infra"
+        );
+    }
+
+    #[test]
+    fn it_should_say_when_no_label_is_set() {
+        let data = LabelDetailsData {
+            environment_name: "tmp".to_string(),
+            state: "Running".to_string(),
+            labels: BTreeMap::new(),
+        };
+
+        let text = TextView::render(&data).unwrap();
+
+        assert!(text.contains("(no labels)"));
+    }
+}
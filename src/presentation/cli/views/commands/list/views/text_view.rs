@@ -88,6 +88,18 @@ impl TextView {
         )
     }
 
+    /// Render the labels of an environment below its row
+    fn render_labels(
+        env: &crate::application::command_handlers::list::info::EnvironmentSummary,
+    ) -> String {
+        let labels: Vec<String> = env
+            .labels
+            .iter()
+            .map(|(key, value)| format!("{key}={value}"))
+            .collect();
+        format!("  labels: {}", labels.join(", "))
+    }
+
     /// Truncate a string to fit column width
     fn truncate(s: &str, max_len: usize) -> String {
         if s.len() <= max_len {
@@ -120,6 +132,9 @@ impl Render<EnvironmentList> for TextView {
         // Table rows
         for env in &list.environments {
             lines.push(Self::render_table_row(env));
+            if !env.labels.is_empty() {
+                lines.push(Self::render_labels(env));
+            }
         }
 
         // Partial failure warnings
@@ -211,6 +226,29 @@ mod tests {
             .contains("Hint: Use 'purge' command to completely remove destroyed environments."));
     }
 
+    #[test]
+    fn it_should_render_the_labels_below_labeled_environments() {
+        let summaries = vec![EnvironmentSummary::new(
+            "e2e-a".to_string(),
+            "Running".to_string(),
+            "LXD".to_string(),
+            "2026-01-05T10:30:00Z".to_string(),
+        )
+        .with_labels(
+            [
+                ("team".to_string(), "infra".to_string()),
+                ("purpose".to_string(), "e2e".to_string()),
+            ]
+            .into(),
+        )];
+
+        let list = EnvironmentList::new(summaries, vec![], "/path/to/data".to_string());
+
+        let output = TextView::render(&list).unwrap();
+
+        assert!(output.contains("  labels: purpose=e2e, team=infra"));
+    }
+
     #[test]
     fn it_should_render_partial_failure_warnings() {
         let summaries = vec![EnvironmentSummary::new(
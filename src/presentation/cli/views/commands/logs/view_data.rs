@@ -0,0 +1,73 @@
+//! View data for the logs command
+
+use serde::Serialize;
+
+use crate::shared::command::RunLogFile;
+
+/// Data for rendering the list of run logs of an environment
+#[derive(Debug, Clone, Serialize)]
+pub struct LogListData {
+    /// Name of the environment
+    pub environment_name: String,
+    /// Log files, oldest run first
+    pub logs: Vec<LogFileData>,
+}
+
+/// One line of the log list
+#[derive(Debug, Clone, Serialize)]
+pub struct LogFileData {
+    /// Path relative to the logs directory, used to select the log with `--show`
+    pub file: String,
+    /// Command of the run
+    pub command: String,
+    /// Run id, the UTC time the run started
+    pub run_id: String,
+    /// Step that wrote the log
+    pub step: String,
+    /// Size of the log in bytes
+    pub size: u64,
+}
+
+/// Data for rendering a single log
+#[derive(Debug, Clone, Serialize)]
+pub struct LogContentData {
+    /// The log file
+    #[serde(flatten)]
+    pub file: LogFileData,
+    /// Content of the log
+    pub content: String,
+}
+
+impl From<&RunLogFile> for LogFileData {
+    fn from(file: &RunLogFile) -> Self {
+        Self {
+            file: file.relative_path(),
+            command: file.command.clone(),
+            run_id: file.run_id.clone(),
+            step: file.step.clone(),
+            size: file.size,
+        }
+    }
+}
+
+impl LogListData {
+    /// Build the view data from the run logs of an environment
+    #[must_use]
+    pub fn new(environment_name: &str, files: &[RunLogFile]) -> Self {
+        Self {
+            environment_name: environment_name.to_string(),
+            logs: files.iter().map(LogFileData::from).collect(),
+        }
+    }
+}
+
+impl LogContentData {
+    /// Build the view data from a log file and its content
+    #[must_use]
+    pub fn new(file: &RunLogFile, content: String) -> Self {
+        Self {
+            file: LogFileData::from(file),
+            content,
+        }
+    }
+}
@@ -0,0 +1,21 @@
+//! JSON View for Run Logs
+//!
+//! This module provides JSON-based rendering for the logs command.
+
+use crate::presentation::cli::views::commands::logs::view_data::{LogContentData, LogListData};
+use crate::presentation::cli::views::{Render, ViewRenderError};
+
+/// JSON view for rendering the log list and single logs
+pub struct JsonView;
+
+impl Render<LogListData> for JsonView {
+    fn render(data: &LogListData) -> Result<String, ViewRenderError> {
+        Ok(serde_json::to_string_pretty(data)?)
+    }
+}
+
+impl Render<LogContentData> for JsonView {
+    fn render(data: &LogContentData) -> Result<String, ViewRenderError> {
+        Ok(serde_json::to_string_pretty(data)?)
+    }
+}
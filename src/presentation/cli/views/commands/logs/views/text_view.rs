@@ -0,0 +1,96 @@
+//! Text View for Run Logs
+//!
+//! This module provides text-based rendering for the logs command: a table
+//! with one row per log file, or the content of one log as written.
+
+use crate::presentation::cli::views::commands::logs::view_data::{LogContentData, LogListData};
+use crate::presentation::cli::views::{Render, ViewRenderError};
+
+/// Text view for rendering the log list and single logs
+pub struct TextView;
+
+impl Render<LogListData> for TextView {
+    fn render(data: &LogListData) -> Result<String, ViewRenderError> {
+        if data.logs.is_empty() {
+            return Ok(format!(
+                "No run logs found for environment '{}'.",
+                data.environment_name
+            ));
+        }
+
+        let mut lines = vec![
+            format!(
+                "Run logs for environment '{}' ({} found):",
+                data.environment_name,
+                data.logs.len()
+            ),
+            String::new(),
+            format!("{:<72} {:>10}", "File", "Size"),
+            "─".repeat(83),
+        ];
+
+        for log in &data.logs {
+            lines.push(format!("{:<72} {:>10}", log.file, log.size));
+        }
+
+        if let Some(latest) = data.logs.last() {
+            lines.push(String::new());
+            lines.push("To display a log:".to_string());
+            lines.push(format!(
+                "  torrust-tracker-deployer logs {} --local --show {}",
+                data.environment_name, latest.file
+            ));
+        }
+
+        Ok(lines.join("\n"))
+    }
+}
+
+impl Render<LogContentData> for TextView {
+    fn render(data: &LogContentData) -> Result<String, ViewRenderError> {
+        Ok(data.content.trim_end().to_string())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::presentation::cli::views::commands::logs::view_data::LogFileData;
+
+    fn apply_log() -> LogFileData {
+        LogFileData {
+            file: "provision/20261016T100000.000000000Z-tofu-apply.log".to_string(),
+            command: "provision".to_string(),
+            run_id: "20261016T100000.000000000Z".to_string(),
+            step: "tofu-apply".to_string(),
+            size: 1_024,
+        }
+    }
+
+    #[test]
+    fn it_should_list_the_logs_with_the_command_to_show_one() {
+        let data = LogListData {
+            environment_name: "my-env".to_string(),
+            logs: vec![apply_log()],
+        };
+
+        let output = TextView::render(&data).unwrap();
+
+        assert!(output.contains("(1 found)"));
+        assert!(output.contains(
+            "logs my-env --local --show provision/20261016T100000.000000000Z-tofu-apply.log"
+        ));
+    }
+
+    #[test]
+    fn it_should_say_so_when_there_are_no_logs() {
+        let data = LogListData {
+            environment_name: "my-env".to_string(),
+            logs: Vec::new(),
+        };
+
+        let output = TextView::render(&data).unwrap();
+
+        assert_eq!(output, "No run logs found for environment 'my-env'.");
+    }
+}
@@ -4,18 +4,37 @@
 //! Each command has its own submodule with views for rendering
 //! command-specific output.
 
+pub mod backup;
 pub mod configure;
 pub mod create;
+pub mod deploy;
 pub mod destroy;
+pub mod diff;
+pub mod doctor;
+pub mod drift;
 pub mod exists;
+pub mod export;
+pub mod import;
+pub mod import_bundle;
+pub mod init;
+pub mod keys;
+pub mod label;
 pub mod list;
+pub mod logs;
+pub mod orphans;
+pub mod probe;
 pub mod provision;
 pub mod purge;
 pub mod register;
 pub mod release;
+pub mod rename;
 pub mod render;
+pub mod repair;
+pub mod reports;
+pub mod restore;
 pub mod run;
 pub mod shared;
 pub mod show;
+pub mod stats;
 pub mod test;
 pub mod validate;
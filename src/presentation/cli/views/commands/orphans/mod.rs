@@ -0,0 +1,38 @@
+//! Views for Orphans Command
+//!
+//! This module contains view components for rendering orphans command output.
+//!
+//! # Architecture
+//!
+//! This module follows the Strategy Pattern for rendering:
+//! - `TextView`: Renders a human-readable table of orphaned resources
+//! - `JsonView`: Renders machine-readable JSON output
+//!
+//! # Structure
+//!
+//! - `views/`: View rendering implementations
+//!   - `text_view.rs`: Human-readable table rendering
+//!   - `json_view.rs`: JSON output for automation workflows
+
+pub mod view_data {
+    //! View data for the orphans command.
+    //!
+    //! Re-exports the application-layer DTOs as the canonical view input types.
+
+    pub use crate::application::command_handlers::orphans::info::{
+        OrphanKind, OrphanReport, OrphanResource, OrphanStatus,
+    };
+}
+
+pub mod views {
+    pub mod json_view;
+    pub mod text_view;
+
+    // Re-export main types for convenience
+    pub use json_view::JsonView;
+    pub use text_view::TextView;
+}
+
+// Re-export at module root for convenience
+pub use view_data::OrphanReport;
+pub use views::{JsonView, TextView};
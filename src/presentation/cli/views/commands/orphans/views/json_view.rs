@@ -0,0 +1,40 @@
+//! JSON View for Orphaned Resources
+//!
+//! This module provides JSON-based rendering for the orphans command,
+//! serializing the `OrphanReport` DTO without transformations.
+
+use crate::presentation::cli::views::commands::orphans::view_data::OrphanReport;
+use crate::presentation::cli::views::{Render, ViewRenderError};
+
+/// View for rendering an orphans report as JSON
+///
+/// # Examples
+///
+/// ```rust
+/// # use torrust_tracker_deployer_lib::presentation::cli::views::Render;
+/// use torrust_tracker_deployer_lib::application::command_handlers::orphans::{
+///     OrphanKind, OrphanReport, OrphanResource,
+/// };
+/// use torrust_tracker_deployer_lib::presentation::cli::views::commands::orphans::JsonView;
+///
+/// let report = OrphanReport {
+///     orphans: vec![OrphanResource::new(
+///         OrphanKind::LxdInstance,
+///         "torrust-tracker-vm-e2e-provision".to_string(),
+///         "e2e-provision".to_string(),
+///     )],
+///     delete_requested: false,
+///     lxd_error: None,
+/// };
+///
+/// let output = JsonView::render(&report).unwrap();
+/// let parsed: serde_json::Value = serde_json::from_str(&output).unwrap();
+/// assert_eq!(parsed["orphans"][0]["kind"], "lxd_instance");
+/// ```
+pub struct JsonView;
+
+impl Render<OrphanReport> for JsonView {
+    fn render(data: &OrphanReport) -> Result<String, ViewRenderError> {
+        Ok(serde_json::to_string_pretty(data)?)
+    }
+}
@@ -0,0 +1,143 @@
+//! Text View for Orphaned Resources
+//!
+//! This module provides text-based rendering for the orphans command:
+//! a table with one row per orphaned resource and the action taken on it.
+
+use crate::presentation::cli::views::commands::orphans::view_data::{
+    OrphanReport, OrphanResource, OrphanStatus,
+};
+use crate::presentation::cli::views::{Render, ViewRenderError};
+
+/// Text view for rendering an orphans report
+///
+/// # Examples
+///
+/// ```rust
+/// # use torrust_tracker_deployer_lib::presentation::cli::views::Render;
+/// use torrust_tracker_deployer_lib::application::command_handlers::orphans::{
+///     OrphanKind, OrphanReport, OrphanResource,
+/// };
+/// use torrust_tracker_deployer_lib::presentation::cli::views::commands::orphans::TextView;
+///
+/// let report = OrphanReport {
+///     orphans: vec![OrphanResource::new(
+///         OrphanKind::BuildDirectory,
+///         "e2e-config".to_string(),
+///         "e2e-config".to_string(),
+///     )],
+///     delete_requested: false,
+///     lxd_error: None,
+/// };
+///
+/// let output = TextView::render(&report).unwrap();
+/// assert!(output.contains("e2e-config"));
+/// assert!(output.contains("--delete"));
+/// ```
+pub struct TextView;
+
+impl TextView {
+    /// Render a single table row
+    fn render_row(orphan: &OrphanResource) -> String {
+        let status = match &orphan.status {
+            OrphanStatus::Found => "found".to_string(),
+            OrphanStatus::Deleted => "deleted".to_string(),
+            OrphanStatus::DeleteFailed { error } => format!("delete failed: {error}"),
+        };
+
+        format!(
+            "{:<16} {:<50} {}",
+            orphan.kind.to_string(),
+            orphan.qualified_name(),
+            status
+        )
+    }
+}
+
+impl Render<OrphanReport> for TextView {
+    fn render(report: &OrphanReport) -> Result<String, ViewRenderError> {
+        let mut lines = vec![String::new()];
+
+        if let Some(error) = &report.lxd_error {
+            lines.push(format!("Warning: LXD resources were not checked: {error}"));
+            lines.push(String::new());
+        }
+
+        if report.is_empty() {
+            lines.push("No orphaned resources found.".to_string());
+            return Ok(lines.join("\n"));
+        }
+
+        lines.push(format!(
+            "Orphaned resources ({} found):",
+            report.orphans.len()
+        ));
+        lines.push(String::new());
+        lines.push(format!("{:<16} {:<50} {}", "Kind", "Name", "Status"));
+        lines.push("─".repeat(80));
+        lines.extend(report.orphans.iter().map(Self::render_row));
+
+        if !report.delete_requested {
+            lines.push(String::new());
+            lines.push("To delete these resources:".to_string());
+            lines.push("  torrust-tracker-deployer orphans --delete".to_string());
+        }
+
+        Ok(lines.join("\n"))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::presentation::cli::views::commands::orphans::view_data::OrphanKind;
+
+    fn report(orphans: Vec<OrphanResource>, delete_requested: bool) -> OrphanReport {
+        OrphanReport {
+            orphans,
+            delete_requested,
+            lxd_error: None,
+        }
+    }
+
+    #[test]
+    fn it_should_render_message_when_no_orphans_exist() {
+        let output = TextView::render(&report(vec![], false)).unwrap();
+
+        assert!(output.contains("No orphaned resources found."));
+    }
+
+    #[test]
+    fn it_should_report_the_action_taken_on_each_resource() {
+        let mut deleted = OrphanResource::new(
+            OrphanKind::LxdInstance,
+            "torrust-tracker-vm-e2e".to_string(),
+            "e2e".to_string(),
+        );
+        deleted.status = OrphanStatus::Deleted;
+        let mut failed = OrphanResource::new(
+            OrphanKind::LxdProfile,
+            "lxd-e2e".to_string(),
+            "e2e".to_string(),
+        );
+        failed.status = OrphanStatus::DeleteFailed {
+            error: "profile in use".to_string(),
+        };
+
+        let output = TextView::render(&report(vec![deleted, failed], true)).unwrap();
+
+        assert!(output.contains("LXD instance"));
+        assert!(output.contains("deleted"));
+        assert!(output.contains("delete failed: profile in use"));
+        assert!(!output.contains("--delete"));
+    }
+
+    #[test]
+    fn it_should_warn_when_lxd_could_not_be_checked() {
+        let mut report = report(vec![], false);
+        report.lxd_error = Some("lxc: command not found".to_string());
+
+        let output = TextView::render(&report).unwrap();
+
+        assert!(output.contains("Warning: LXD resources were not checked: lxc: command not found"));
+    }
+}
@@ -0,0 +1,45 @@
+//! View data for the probe command
+
+use std::net::SocketAddr;
+use std::time::Duration;
+
+use serde::Serialize;
+
+use crate::shared::udp_tracker_client::UdpAnnounceResponse;
+
+/// Data for rendering the announce response of a UDP tracker
+#[derive(Debug, Clone, Serialize)]
+pub struct UdpProbeData {
+    /// Tracker address, as given on the command line
+    pub tracker: String,
+    /// Socket address the announce was sent to
+    pub address: String,
+    /// Seconds the tracker asks peers to wait before re-announcing
+    pub interval: i32,
+    /// Number of leechers of the announced torrent
+    pub leechers: i32,
+    /// Number of seeders of the announced torrent
+    pub seeders: i32,
+    /// Time from the first request to the announce response, in milliseconds
+    pub duration_ms: u64,
+}
+
+impl UdpProbeData {
+    /// Build the view data from the announce response
+    #[must_use]
+    pub fn new(
+        tracker: &str,
+        address: SocketAddr,
+        response: &UdpAnnounceResponse,
+        duration: Duration,
+    ) -> Self {
+        Self {
+            tracker: tracker.to_string(),
+            address: address.to_string(),
+            interval: response.interval,
+            leechers: response.leechers,
+            seeders: response.seeders,
+            duration_ms: u64::try_from(duration.as_millis()).unwrap_or(u64::MAX),
+        }
+    }
+}
@@ -0,0 +1,15 @@
+//! JSON View for Tracker Probes
+//!
+//! This module provides JSON-based rendering for the probe command.
+
+use crate::presentation::cli::views::commands::probe::view_data::UdpProbeData;
+use crate::presentation::cli::views::{Render, ViewRenderError};
+
+/// JSON view for rendering the result of a UDP probe
+pub struct JsonView;
+
+impl Render<UdpProbeData> for JsonView {
+    fn render(data: &UdpProbeData) -> Result<String, ViewRenderError> {
+        Ok(serde_json::to_string_pretty(data)?)
+    }
+}
@@ -0,0 +1,56 @@
+//! Text View for Tracker Probes
+//!
+//! This module provides text-based rendering for the probe command: the
+//! tracker that answered, the announce interval and the peer counts.
+
+use crate::presentation::cli::views::commands::probe::view_data::UdpProbeData;
+use crate::presentation::cli::views::{Render, ViewRenderError};
+
+/// Text view for rendering the result of a UDP probe
+pub struct TextView;
+
+impl Render<UdpProbeData> for TextView {
+    fn render(data: &UdpProbeData) -> Result<String, ViewRenderError> {
+        let lines = [
+            format!(
+                "UDP tracker udp://{} ({}) answered in {} ms:",
+                data.tracker, data.address, data.duration_ms
+            ),
+            format!("  Interval: {} s", data.interval),
+            format!("  Leechers: {}", data.leechers),
+            format!("  Seeders:  {}", data.seeders),
+        ];
+
+        Ok(lines.join("\n"))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn it_should_show_the_interval_and_the_peer_counts() {
+        let data = UdpProbeData {
+            tracker: "tracker.example.com:6969".to_string(),
+            address: "1.2.3.4:6969".to_string(),
+            interval: 1800,
+            leechers: 0,
+            seeders: 1,
+            duration_ms: 48,
+        };
+
+        let output = TextView::render(&data).unwrap();
+
+        assert_eq!(
+            output,
+            [
+                "UDP tracker udp://tracker.example.com:6969 (1.2.3.4:6969) answered in 48 ms:",
+                "  Interval: 1800 s",
+                "  Leechers: 0",
+                "  Seeders:  1",
+            ]
+            .join("\n")
+        );
+    }
+}
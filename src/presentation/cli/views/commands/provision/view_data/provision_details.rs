@@ -51,7 +51,7 @@ pub struct ProvisionDetailsData {
     pub ssh_port: u16,
     /// Path to SSH private key
     pub ssh_private_key_path: PathBuf,
-    /// Infrastructure provider (lowercase: "lxd" or "hetzner")
+    /// Infrastructure provider (lowercase: "lxd", "hetzner", "digitalocean", "docker" or "libvirt")
     pub provider: String,
     /// Timestamp when the environment was provisioned (ISO 8601 format in JSON)
     pub provisioned_at: DateTime<Utc>,
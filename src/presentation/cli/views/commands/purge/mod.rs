@@ -8,14 +8,18 @@
 //! - `PurgeDetailsData`: The data DTO passed to all views
 //! - `TextView`: Renders human-readable text output
 //! - `JsonView`: Renders machine-readable JSON output
+//! - `GcReportData`, `GcTextView` and `GcJsonView`: the same for the report
+//!   of a garbage collection (`purge --gc`)
 //!
 //! # Structure
 //!
 //! - `view_data/`: Data structures (DTOs) passed to views
 //!   - `purge_details.rs`: Main DTO with purge result data
+//!   - `gc_report.rs`: DTO with the garbage collection report
 //! - `views/`: View rendering implementations
 //!   - `text_view.rs`: Human-readable text rendering
 //!   - `json_view.rs`: Machine-readable JSON rendering
+//!   - `gc_text_view.rs`, `gc_json_view.rs`: Garbage collection report rendering
 //!
 //! # SOLID Principles
 //!
@@ -32,21 +36,27 @@
 //! 4. No need to modify existing views or the DTO
 
 pub mod view_data {
+    pub mod gc_report;
     pub mod purge_details;
 
     // Re-export main types for convenience
+    pub use gc_report::GcReportData;
     pub use purge_details::PurgeDetailsData;
 }
 
 pub mod views {
+    pub mod gc_json_view;
+    pub mod gc_text_view;
     pub mod json_view;
     pub mod text_view;
 
     // Re-export views for convenience
+    pub use gc_json_view::GcJsonView;
+    pub use gc_text_view::GcTextView;
     pub use json_view::JsonView;
     pub use text_view::TextView;
 }
 
 // Re-export at module root for convenience
-pub use view_data::PurgeDetailsData;
-pub use views::{JsonView, TextView};
+pub use view_data::{GcReportData, PurgeDetailsData};
+pub use views::{GcJsonView, GcTextView, JsonView, TextView};
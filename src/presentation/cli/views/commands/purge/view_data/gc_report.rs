@@ -0,0 +1,129 @@
+//! Garbage Collection Report Data Transfer Object
+//!
+//! This module contains the presentation DTO for the outcome of
+//! `purge --gc`, passed to `GcTextView` and `GcJsonView`.
+
+use std::time::Duration;
+
+use serde::Serialize;
+
+use crate::application::command_handlers::purge::gc::GcReport;
+
+/// Garbage collection report data for rendering
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct GcReportData {
+    /// Whether nothing was removed (`--dry-run`)
+    pub dry_run: bool,
+    /// Minimum age of the last state transition, as given (e.g. `14d`)
+    pub older_than: String,
+    /// Environments purged, or that would be purged by a dry run
+    pub purged: Vec<GcEnvironmentData>,
+    /// Environments old enough that were left alone
+    pub skipped: Vec<GcSkippedData>,
+    /// Environments whose purge failed
+    pub failed: Vec<GcFailedData>,
+    /// Disk space freed by the purged environments, in bytes
+    pub reclaimed_bytes: u64,
+}
+
+/// An environment selected by the garbage collection
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct GcEnvironmentData {
+    /// Name of the environment
+    pub environment_name: String,
+    /// Display name of its state
+    pub state: String,
+    /// When it entered its state (RFC 3339)
+    pub since: String,
+    /// Size of its data and build directories, in bytes
+    pub size_bytes: u64,
+}
+
+/// An environment left alone by the garbage collection
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct GcSkippedData {
+    /// Name of the environment
+    pub environment_name: String,
+    /// Why it was left alone
+    pub reason: String,
+}
+
+/// An environment whose purge failed
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct GcFailedData {
+    /// Name of the environment
+    pub environment_name: String,
+    /// Why the purge failed
+    pub error: String,
+}
+
+impl GcReportData {
+    /// Build the view data from the application layer report
+    #[must_use]
+    pub fn new(older_than: Duration, report: &GcReport) -> Self {
+        Self {
+            dry_run: report.dry_run,
+            older_than: format_age(older_than),
+            purged: report
+                .purged
+                .iter()
+                .map(|candidate| GcEnvironmentData {
+                    environment_name: candidate.name.to_string(),
+                    state: candidate.state.clone(),
+                    since: candidate.since.to_rfc3339(),
+                    size_bytes: candidate.size_bytes,
+                })
+                .collect(),
+            skipped: report
+                .skipped
+                .iter()
+                .map(|skipped| GcSkippedData {
+                    environment_name: skipped.name.to_string(),
+                    reason: skipped.reason.clone(),
+                })
+                .collect(),
+            failed: report
+                .failed
+                .iter()
+                .map(|failure| GcFailedData {
+                    environment_name: failure.candidate.name.to_string(),
+                    error: failure.error.clone(),
+                })
+                .collect(),
+            reclaimed_bytes: report.reclaimed_bytes(),
+        }
+    }
+}
+
+/// Format an age in the largest unit accepted by `--older-than` that
+/// divides it (e.g. `14d`)
+fn format_age(age: Duration) -> String {
+    const UNITS: [(u64, &str); 4] = [
+        (7 * 24 * 60 * 60, "w"),
+        (24 * 60 * 60, "d"),
+        (60 * 60, "h"),
+        (60, "m"),
+    ];
+
+    let secs = age.as_secs();
+    UNITS
+        .iter()
+        .find(|(unit, _)| secs >= *unit && secs.is_multiple_of(*unit))
+        .map_or_else(
+            || format!("{secs}s"),
+            |(unit, suffix)| format!("{}{suffix}", secs / unit),
+        )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn it_should_format_the_age_in_the_largest_dividing_unit() {
+        assert_eq!(format_age(Duration::from_secs(14 * 24 * 60 * 60)), "2w");
+        assert_eq!(format_age(Duration::from_secs(10 * 24 * 60 * 60)), "10d");
+        assert_eq!(format_age(Duration::from_secs(36 * 60 * 60)), "36h");
+        assert_eq!(format_age(Duration::from_secs(90 * 60)), "90m");
+    }
+}
@@ -0,0 +1,16 @@
+//! JSON View for Purge Garbage Collection
+//!
+//! This module provides the machine-readable rendering of `purge --gc`,
+//! serializing the `GcReportData` DTO as it is.
+
+use crate::presentation::cli::views::commands::purge::GcReportData;
+use crate::presentation::cli::views::{Render, ViewRenderError};
+
+/// View for rendering a garbage collection report as JSON
+pub struct GcJsonView;
+
+impl Render<GcReportData> for GcJsonView {
+    fn render(data: &GcReportData) -> Result<String, ViewRenderError> {
+        Ok(serde_json::to_string_pretty(data)?)
+    }
+}
@@ -0,0 +1,205 @@
+//! Text View for Purge Garbage Collection
+//!
+//! This module provides the human-readable rendering of `purge --gc`: a
+//! table of the selected environments, the skipped and failed ones, and the
+//! reclaimed disk space.
+
+use crate::presentation::cli::views::commands::purge::GcReportData;
+use crate::presentation::cli::views::{Render, ViewRenderError};
+
+/// View for rendering a garbage collection report as human-readable text
+pub struct GcTextView;
+
+impl GcTextView {
+    /// Format a size in bytes with a binary unit (e.g. `1.5 MiB`)
+    fn format_size(bytes: u64) -> String {
+        const UNITS: [&str; 4] = ["KiB", "MiB", "GiB", "TiB"];
+
+        if bytes < 1024 {
+            return format!("{bytes} B");
+        }
+
+        #[allow(clippy::cast_precision_loss)] // Only displayed with one decimal
+        let mut size = bytes as f64 / 1024.0;
+        let mut unit = UNITS[0];
+        for next in &UNITS[1..] {
+            if size < 1024.0 {
+                break;
+            }
+            size /= 1024.0;
+            unit = next;
+        }
+
+        format!("{size:.1} {unit}")
+    }
+
+    /// Plural of "environment" for `count`
+    fn environments(count: usize) -> String {
+        if count == 1 {
+            "1 environment".to_string()
+        } else {
+            format!("{count} environments")
+        }
+    }
+}
+
+impl Render<GcReportData> for GcTextView {
+    fn render(data: &GcReportData) -> Result<String, ViewRenderError> {
+        let mut lines = Vec::new();
+
+        if data.dry_run {
+            lines.push(format!(
+                "DRY RUN (purge --gc): environments in their state for at least {}",
+                data.older_than
+            ));
+        }
+
+        if data.purged.is_empty() && data.failed.is_empty() {
+            lines.push(format!(
+                "No environment has been in its state for at least {}",
+                data.older_than
+            ));
+        }
+
+        if !data.purged.is_empty() {
+            let width = data
+                .purged
+                .iter()
+                .map(|env| env.environment_name.len())
+                .max()
+                .unwrap_or_default()
+                .max("Environment".len());
+
+            lines.push(format!(
+                "  {:<width$}  {:<15}  {:<25}  {:>10}",
+                "Environment", "State", "Since", "Size"
+            ));
+            for env in &data.purged {
+                lines.push(format!(
+                    "  {:<width$}  {:<15}  {:<25}  {:>10}",
+                    env.environment_name,
+                    env.state,
+                    env.since,
+                    Self::format_size(env.size_bytes)
+                ));
+            }
+        }
+
+        if !data.skipped.is_empty() {
+            lines.push("Skipped:".to_string());
+            for skipped in &data.skipped {
+                lines.push(format!(
+                    "  {}: {}",
+                    skipped.environment_name, skipped.reason
+                ));
+            }
+        }
+
+        if !data.failed.is_empty() {
+            lines.push("Failed:".to_string());
+            for failed in &data.failed {
+                lines.push(format!("  {}: {}", failed.environment_name, failed.error));
+            }
+        }
+
+        let reclaimed = Self::format_size(data.reclaimed_bytes);
+        let purged = Self::environments(data.purged.len());
+        if !data.failed.is_empty() {
+            lines.push(format!(
+                "Purged {purged}, reclaimed {reclaimed}; {} failed",
+                data.failed.len()
+            ));
+        } else if data.dry_run && !data.purged.is_empty() {
+            lines.push(format!("Would purge {purged} and reclaim {reclaimed}"));
+        } else if !data.purged.is_empty() {
+            lines.push(format!("Purged {purged}, reclaimed {reclaimed}"));
+        }
+
+        Ok(lines.join("\n"))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::presentation::cli::views::commands::purge::view_data::gc_report::{
+        GcEnvironmentData, GcFailedData, GcSkippedData,
+    };
+
+    fn report_data(dry_run: bool) -> GcReportData {
+        GcReportData {
+            dry_run,
+            older_than: "14d".to_string(),
+            purged: vec![GcEnvironmentData {
+                environment_name: "ci-101".to_string(),
+                state: "Destroyed".to_string(),
+                since: "2026-03-01T10:00:00+00:00".to_string(),
+                size_bytes: 1_572_864,
+            }],
+            skipped: vec![GcSkippedData {
+                environment_name: "ci-7".to_string(),
+                reason: "state cannot be loaded".to_string(),
+            }],
+            failed: vec![],
+            reclaimed_bytes: 1_572_864,
+        }
+    }
+
+    #[test]
+    fn it_should_render_the_selected_environments_and_the_reclaimed_space() {
+        let text = GcTextView::render(&report_data(true)).unwrap();
+
+        assert_eq!(
+            text,
+            [
+                "DRY RUN (purge --gc): environments in their state for at least 14d",
+                "  Environment  State            Since                            Size",
+                "  ci-101       Destroyed        2026-03-01T10:00:00+00:00     1.5 MiB",
+                "Skipped:",
+                "  ci-7: state cannot be loaded",
+                "Would purge 1 environment and reclaim 1.5 MiB",
+            ]
+            .join("\n")
+        );
+    }
+
+    #[test]
+    fn it_should_count_the_failed_purges_in_the_summary() {
+        let data = GcReportData {
+            failed: vec![GcFailedData {
+                environment_name: "ci-9".to_string(),
+                error: "Permission denied".to_string(),
+            }],
+            ..report_data(false)
+        };
+
+        let text = GcTextView::render(&data).unwrap();
+
+        assert!(text.contains("Failed:\n  ci-9: Permission denied"));
+        assert!(text.ends_with("Purged 1 environment, reclaimed 1.5 MiB; 1 failed"));
+    }
+
+    #[test]
+    fn it_should_say_when_no_environment_is_old_enough() {
+        let data = GcReportData {
+            purged: vec![],
+            skipped: vec![],
+            reclaimed_bytes: 0,
+            ..report_data(false)
+        };
+
+        let text = GcTextView::render(&data).unwrap();
+
+        assert_eq!(
+            text,
+            "No environment has been in its state for at least 14d"
+        );
+    }
+
+    #[test]
+    fn it_should_format_sizes_with_binary_units() {
+        assert_eq!(GcTextView::format_size(512), "512 B");
+        assert_eq!(GcTextView::format_size(2048), "2.0 KiB");
+        assert_eq!(GcTextView::format_size(3 * 1024 * 1024 * 1024), "3.0 GiB");
+    }
+}
@@ -47,6 +47,8 @@ pub struct ReleaseDetailsData {
     pub state: String,
     /// IP address of the instance (nullable)
     pub instance_ip: Option<IpAddr>,
+    /// Tracker image version deployed (tag or digest)
+    pub tracker_version: String,
     /// Timestamp when the environment was created (ISO 8601 format in JSON)
     pub created_at: DateTime<Utc>,
 }
@@ -72,6 +74,7 @@ impl From<&Environment<Released>> for ReleaseDetailsData {
             provider: env.provider_config().provider_name().to_string(),
             state: "Released".to_string(),
             instance_ip: env.instance_ip(),
+            tracker_version: env.tracker_config().effective_version().to_string(),
             created_at: env.created_at(),
         }
     }
@@ -104,6 +107,7 @@ mod tests {
     fn create_test_provider_config() -> ProviderConfig {
         ProviderConfig::Lxd(LxdConfig {
             profile_name: ProfileName::new("lxd-test-env".to_string()).unwrap(),
+            remote: None,
         })
     }
 
@@ -137,6 +141,7 @@ mod tests {
             provider: "lxd".to_string(),
             state: "Released".to_string(),
             instance_ip: Some(ip),
+            tracker_version: "develop".to_string(),
             created_at: create_test_timestamp(),
         }
     }
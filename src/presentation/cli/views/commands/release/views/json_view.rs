@@ -34,6 +34,7 @@ use crate::presentation::cli::views::{Render, ViewRenderError};
 ///     provider: "lxd".to_string(),
 ///     state: "Released".to_string(),
 ///     instance_ip: Some(IpAddr::V4(Ipv4Addr::new(10, 140, 190, 39))),
+///     tracker_version: "develop".to_string(),
 ///     created_at: Utc.with_ymd_and_hms(2026, 2, 20, 10, 0, 0).unwrap(),
 /// };
 ///
@@ -76,6 +77,7 @@ mod tests {
             provider: "lxd".to_string(),
             state: "Released".to_string(),
             instance_ip: ip,
+            tracker_version: "develop".to_string(),
             created_at: create_test_timestamp(),
         }
     }
@@ -0,0 +1,78 @@
+//! Rename Details Data Transfer Object
+//!
+//! This module contains the presentation DTO for rename command details.
+//! It serves as the data structure passed to view renderers (`TextView`, `JsonView`).
+
+use serde::Serialize;
+
+use crate::domain::environment::state::AnyEnvironmentState;
+use crate::domain::environment::EnvironmentName;
+
+/// Rename details data for rendering
+///
+/// Built from the renamed environment and its previous name, on the success
+/// path only: rename failures result in an error return.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct RenameDetailsData {
+    /// Name of the environment before the rename
+    pub previous_name: String,
+    /// New name of the environment
+    pub environment_name: String,
+    /// Current state of the environment (unchanged by the rename)
+    pub state: String,
+    /// Data directory of the renamed environment
+    pub data_dir: String,
+    /// Build directory of the renamed environment
+    pub build_dir: String,
+    /// Instance name of the renamed environment
+    pub instance_name: String,
+    /// Name the infrastructure keeps its names from, when it was not renamed
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub infrastructure_name: Option<String>,
+}
+
+impl RenameDetailsData {
+    /// Construct a `RenameDetailsData` from the renamed environment
+    ///
+    /// # Arguments
+    ///
+    /// * `previous_name` - Name of the environment before the rename
+    /// * `environment` - The renamed environment
+    #[must_use]
+    pub fn from_environment(
+        previous_name: &EnvironmentName,
+        environment: &AnyEnvironmentState,
+    ) -> Self {
+        Self {
+            previous_name: previous_name.to_string(),
+            environment_name: environment.name().to_string(),
+            state: environment.state_display_name().to_string(),
+            data_dir: environment.data_dir().display().to_string(),
+            build_dir: environment.build_dir().display().to_string(),
+            instance_name: environment.instance_name().to_string(),
+            infrastructure_name: environment.infrastructure_name().map(ToString::to_string),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::environment::testing::EnvironmentTestBuilder;
+
+    #[test]
+    fn it_should_build_dto_from_the_renamed_environment() {
+        let (environment, _data_dir, _build_dir, _temp_dir) = EnvironmentTestBuilder::new()
+            .with_name("prod")
+            .build_with_custom_paths();
+        let previous_name = EnvironmentName::new("tmp").unwrap();
+
+        let data = RenameDetailsData::from_environment(&previous_name, &environment.into_any());
+
+        assert_eq!(data.previous_name, "tmp");
+        assert_eq!(data.environment_name, "prod");
+        assert_eq!(data.state, "Created");
+        assert!(data.data_dir.ends_with("prod"));
+        assert_eq!(data.infrastructure_name, None);
+    }
+}
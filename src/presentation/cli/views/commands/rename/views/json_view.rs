@@ -0,0 +1,45 @@
+//! JSON View for Rename Command
+//!
+//! This module provides JSON-based rendering for the rename command.
+//! It follows the Strategy Pattern, providing a machine-readable output format
+//! for the same underlying data (`RenameDetailsData` DTO).
+
+use crate::presentation::cli::views::commands::rename::RenameDetailsData;
+use crate::presentation::cli::views::{Render, ViewRenderError};
+
+/// View for rendering rename details as JSON
+///
+/// This view provides machine-readable JSON output for automation workflows
+/// and AI agents. It serializes the rename details without any transformations.
+pub struct JsonView;
+
+impl Render<RenameDetailsData> for JsonView {
+    fn render(data: &RenameDetailsData) -> Result<String, ViewRenderError> {
+        Ok(serde_json::to_string_pretty(data)?)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn it_should_render_the_infrastructure_name_only_when_kept() {
+        let data = RenameDetailsData {
+            previous_name: "tmp".to_string(),
+            environment_name: "prod".to_string(),
+            state: "Created".to_string(),
+            data_dir: "./data/prod".to_string(),
+            build_dir: "./build/prod".to_string(),
+            instance_name: "torrust-tracker-vm-prod".to_string(),
+            infrastructure_name: None,
+        };
+
+        let json = JsonView::render(&data).unwrap();
+
+        let parsed: serde_json::Value = serde_json::from_str(&json).unwrap();
+        assert_eq!(parsed["previous_name"], "tmp");
+        assert_eq!(parsed["environment_name"], "prod");
+        assert!(parsed.get("infrastructure_name").is_none());
+    }
+}
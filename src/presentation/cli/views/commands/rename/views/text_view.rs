@@ -0,0 +1,75 @@
+//! Text View for Rename Command
+//!
+//! This module provides text-based rendering for the rename command.
+//! It follows the Strategy Pattern, providing a human-readable output format
+//! for the same underlying data (`RenameDetailsData` DTO).
+
+use crate::presentation::cli::views::commands::rename::RenameDetailsData;
+use crate::presentation::cli::views::{Render, ViewRenderError};
+
+/// View for rendering rename details as human-readable text
+///
+/// The rendered string is intended to be passed to `ProgressReporter::complete()`,
+/// which adds the `✅` prefix to the first line.
+pub struct TextView;
+
+impl Render<RenameDetailsData> for TextView {
+    fn render(data: &RenameDetailsData) -> Result<String, ViewRenderError> {
+        let mut lines = vec![
+            format!(
+                "Environment '{}' renamed to '{}'",
+                data.previous_name, data.environment_name
+            ),
+            format!("  State:             {}", data.state),
+            format!("  Data directory:    {}", data.data_dir),
+            format!("  Build directory:   {}", data.build_dir),
+            format!("  Instance name:     {}", data.instance_name),
+        ];
+
+        if let Some(infrastructure_name) = &data.infrastructure_name {
+            lines.push(format!(
+                "  Note: the instance and LXD profile keep the names derived from '{infrastructure_name}'"
+            ));
+        }
+
+        Ok(lines.join("\n"))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn create_test_data() -> RenameDetailsData {
+        RenameDetailsData {
+            previous_name: "tmp".to_string(),
+            environment_name: "prod".to_string(),
+            state: "Running".to_string(),
+            data_dir: "./data/prod".to_string(),
+            build_dir: "./build/prod".to_string(),
+            instance_name: "torrust-tracker-vm-tmp".to_string(),
+            infrastructure_name: None,
+        }
+    }
+
+    #[test]
+    fn it_should_render_both_names_and_the_new_directories() {
+        let text = TextView::render(&create_test_data()).unwrap();
+
+        assert!(text.starts_with("Environment 'tmp' renamed to 'prod'"));
+        assert!(text.contains("./data/prod"));
+        assert!(!text.contains("Note:"));
+    }
+
+    #[test]
+    fn it_should_note_infrastructure_names_kept_from_the_previous_name() {
+        let data = RenameDetailsData {
+            infrastructure_name: Some("tmp".to_string()),
+            ..create_test_data()
+        };
+
+        let text = TextView::render(&data).unwrap();
+
+        assert!(text.contains("keep the names derived from 'tmp'"));
+    }
+}
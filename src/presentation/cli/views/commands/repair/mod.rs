@@ -0,0 +1,37 @@
+//! Views for Repair Command
+//!
+//! This module contains view components for rendering repair command output.
+//!
+//! # Architecture
+//!
+//! This module follows the Strategy Pattern for rendering:
+//! - `TextView`: Renders the state status and a table of state backups
+//! - `JsonView`: Renders machine-readable JSON output
+//!
+//! # Structure
+//!
+//! - `views/`: View rendering implementations
+//!   - `text_view.rs`: Human-readable rendering
+//!   - `json_view.rs`: JSON output for automation workflows
+
+pub mod view_data {
+    //! View data for the repair command.
+    //!
+    //! Re-exports the application-layer DTOs as the canonical view input types.
+
+    pub use crate::application::command_handlers::repair::info::{RepairReport, StateFileStatus};
+    pub use crate::domain::environment::repository::StateBackup;
+}
+
+pub mod views {
+    pub mod json_view;
+    pub mod text_view;
+
+    // Re-export main types for convenience
+    pub use json_view::JsonView;
+    pub use text_view::TextView;
+}
+
+// Re-export at module root for convenience
+pub use view_data::RepairReport;
+pub use views::{JsonView, TextView};
@@ -0,0 +1,39 @@
+//! JSON View for the Repair Command
+//!
+//! This module provides JSON-based rendering for the repair command,
+//! serializing the `RepairReport` DTO without transformations.
+
+use crate::presentation::cli::views::commands::repair::view_data::RepairReport;
+use crate::presentation::cli::views::{Render, ViewRenderError};
+
+/// View for rendering a repair report as JSON
+///
+/// # Examples
+///
+/// ```rust
+/// # use torrust_tracker_deployer_lib::presentation::cli::views::Render;
+/// use torrust_tracker_deployer_lib::application::command_handlers::repair::{
+///     RepairReport, StateFileStatus,
+/// };
+/// use torrust_tracker_deployer_lib::presentation::cli::views::commands::repair::JsonView;
+///
+/// let report = RepairReport {
+///     environment_name: "my-env".to_string(),
+///     status: StateFileStatus::Corrupted {
+///         reason: "EOF while parsing".to_string(),
+///     },
+///     backups: vec![],
+///     restored: None,
+/// };
+///
+/// let output = JsonView::render(&report).unwrap();
+/// let parsed: serde_json::Value = serde_json::from_str(&output).unwrap();
+/// assert_eq!(parsed["status"], "corrupted");
+/// ```
+pub struct JsonView;
+
+impl Render<RepairReport> for JsonView {
+    fn render(data: &RepairReport) -> Result<String, ViewRenderError> {
+        Ok(serde_json::to_string_pretty(data)?)
+    }
+}
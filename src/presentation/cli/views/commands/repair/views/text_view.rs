@@ -0,0 +1,136 @@
+//! Text View for the Repair Command
+//!
+//! This module provides text-based rendering for the repair command: the
+//! status of the environment state, the restored backup and a table of the
+//! available backups.
+
+use crate::presentation::cli::views::commands::repair::view_data::{
+    RepairReport, StateBackup, StateFileStatus,
+};
+use crate::presentation::cli::views::{Render, ViewRenderError};
+
+/// Text view for rendering a repair report
+///
+/// # Examples
+///
+/// ```rust
+/// # use torrust_tracker_deployer_lib::presentation::cli::views::Render;
+/// use torrust_tracker_deployer_lib::application::command_handlers::repair::{
+///     RepairReport, StateFileStatus,
+/// };
+/// use torrust_tracker_deployer_lib::presentation::cli::views::commands::repair::TextView;
+///
+/// let report = RepairReport {
+///     environment_name: "my-env".to_string(),
+///     status: StateFileStatus::Healthy {
+///         state_name: "provisioned".to_string(),
+///     },
+///     backups: vec![],
+///     restored: None,
+/// };
+///
+/// let output = TextView::render(&report).unwrap();
+/// assert!(output.contains("State: provisioned (loads correctly)"));
+/// ```
+pub struct TextView;
+
+impl TextView {
+    /// Render a single table row
+    fn render_row(backup: &StateBackup) -> String {
+        format!(
+            "{:<28} {:<20} {}",
+            backup.id,
+            backup.state_name,
+            backup.created_at.format("%Y-%m-%d %H:%M:%S UTC")
+        )
+    }
+}
+
+impl Render<RepairReport> for TextView {
+    fn render(report: &RepairReport) -> Result<String, ViewRenderError> {
+        let name = &report.environment_name;
+        let mut lines = vec![String::new(), format!("Environment: {name}")];
+
+        lines.push(match &report.status {
+            StateFileStatus::Healthy { state_name } => {
+                format!("State: {state_name} (loads correctly)")
+            }
+            StateFileStatus::Corrupted { reason } => format!("State: corrupted ({reason})"),
+            StateFileStatus::Missing => "State: missing".to_string(),
+        });
+
+        if let Some(restored) = &report.restored {
+            lines.push(format!(
+                "Restored backup {} ({})",
+                restored.id, restored.state_name
+            ));
+        }
+
+        lines.push(String::new());
+
+        if report.backups.is_empty() {
+            lines.push("No state backups found.".to_string());
+            return Ok(lines.join("\n"));
+        }
+
+        lines.push(format!("State backups ({} found):", report.backups.len()));
+        lines.push(String::new());
+        lines.push(format!("{:<28} {:<20} {}", "ID", "State", "Written"));
+        lines.push("─".repeat(72));
+        lines.extend(report.backups.iter().map(Self::render_row));
+
+        if report.restored.is_none() && report.needs_repair() {
+            lines.push(String::new());
+            lines.push("To restore the latest backup:".to_string());
+            lines.push(format!("  torrust-tracker-deployer repair {name}"));
+        }
+
+        Ok(lines.join("\n"))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::{TimeZone, Utc};
+
+    use super::*;
+
+    fn backup(id: &str, state_name: &str) -> StateBackup {
+        StateBackup {
+            id: id.to_string(),
+            state_name: state_name.to_string(),
+            created_at: Utc.with_ymd_and_hms(2026, 10, 15, 12, 0, 0).unwrap(),
+        }
+    }
+
+    fn corrupted_report(restored: Option<StateBackup>) -> RepairReport {
+        RepairReport {
+            environment_name: "my-env".to_string(),
+            status: StateFileStatus::Corrupted {
+                reason: "EOF while parsing".to_string(),
+            },
+            backups: vec![backup("20261015T120000.000000000Z", "provisioned")],
+            restored,
+        }
+    }
+
+    #[test]
+    fn it_should_list_backups_and_suggest_restoring_corrupted_state() {
+        let output = TextView::render(&corrupted_report(None)).unwrap();
+
+        assert!(output.contains("State: corrupted (EOF while parsing)"));
+        assert!(output.contains("20261015T120000.000000000Z"));
+        assert!(output.contains("2026-10-15 12:00:00 UTC"));
+        assert!(output.contains("torrust-tracker-deployer repair my-env"));
+    }
+
+    #[test]
+    fn it_should_report_the_restored_backup() {
+        let restored = backup("20261015T120000.000000000Z", "provisioned");
+
+        let output = TextView::render(&corrupted_report(Some(restored))).unwrap();
+
+        assert!(output.contains("Restored backup 20261015T120000.000000000Z (provisioned)"));
+        assert!(!output.contains("To restore the latest backup"));
+    }
+}
@@ -0,0 +1,78 @@
+//! View data for the reports command
+
+use chrono::{DateTime, Utc};
+use serde::Serialize;
+
+use crate::domain::environment::DeploymentReport;
+use crate::infrastructure::reports::StoredReport;
+use crate::infrastructure::trace::TraceIndexEntry;
+
+/// Data for rendering the list of reports of an environment
+#[derive(Debug, Clone, Serialize)]
+pub struct ReportListData {
+    /// Name of the environment
+    pub environment_name: String,
+    /// Reports, oldest first
+    pub reports: Vec<ReportSummaryData>,
+}
+
+/// One line of the report list
+#[derive(Debug, Clone, Serialize)]
+pub struct ReportSummaryData {
+    /// Report file name, used to select the report with `--show`
+    pub file_name: String,
+    /// Command that produced the report
+    pub command: String,
+    /// Whether the command succeeded
+    pub succeeded: bool,
+    /// State of the environment after the command
+    pub state: Option<String>,
+    /// When the command finished
+    pub finished_at: DateTime<Utc>,
+    /// Duration of the command in milliseconds
+    pub duration_ms: u64,
+}
+
+/// Data for rendering a single report
+#[derive(Debug, Clone, Serialize)]
+pub struct ReportDetailsData {
+    /// Report file name
+    pub file_name: String,
+    /// The full report
+    #[serde(flatten)]
+    pub report: DeploymentReport,
+    /// Trace index entry of the failure, when its trace is still kept
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub trace: Option<TraceIndexEntry>,
+}
+
+impl ReportListData {
+    /// Build the view data from the stored reports of an environment
+    #[must_use]
+    pub fn new(environment_name: &str, reports: &[StoredReport]) -> Self {
+        Self {
+            environment_name: environment_name.to_string(),
+            reports: reports
+                .iter()
+                .map(|stored| ReportSummaryData {
+                    file_name: stored.file_name.clone(),
+                    command: stored.report.command.clone(),
+                    succeeded: stored.report.succeeded,
+                    state: stored.report.state.clone(),
+                    finished_at: stored.report.finished_at,
+                    duration_ms: stored.report.duration_ms,
+                })
+                .collect(),
+        }
+    }
+}
+
+impl From<StoredReport> for ReportDetailsData {
+    fn from(stored: StoredReport) -> Self {
+        Self {
+            file_name: stored.file_name,
+            report: stored.report,
+            trace: stored.trace,
+        }
+    }
+}
@@ -0,0 +1,65 @@
+//! JSON View for Deployment Reports
+//!
+//! This module provides JSON-based rendering for the reports command.
+
+use crate::presentation::cli::views::commands::reports::view_data::{
+    ReportDetailsData, ReportListData,
+};
+use crate::presentation::cli::views::{Render, ViewRenderError};
+
+/// JSON view for rendering the report list and single reports
+pub struct JsonView;
+
+impl Render<ReportListData> for JsonView {
+    fn render(data: &ReportListData) -> Result<String, ViewRenderError> {
+        Ok(serde_json::to_string_pretty(data)?)
+    }
+}
+
+impl Render<ReportDetailsData> for JsonView {
+    fn render(data: &ReportDetailsData) -> Result<String, ViewRenderError> {
+        Ok(serde_json::to_string_pretty(data)?)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::BTreeMap;
+
+    use chrono::{TimeZone, Utc};
+
+    use super::*;
+    use crate::domain::environment::DeploymentReport;
+
+    #[test]
+    fn it_should_flatten_the_report_next_to_its_file_name() {
+        let at = Utc.with_ymd_and_hms(2026, 1, 1, 12, 0, 0).unwrap();
+        let data = ReportDetailsData {
+            file_name: "20260101-120000-provision.json".to_string(),
+            report: DeploymentReport {
+                command: "provision".to_string(),
+                environment: "my-env".to_string(),
+                started_at: at,
+                finished_at: at,
+                duration_ms: 0,
+                succeeded: true,
+                state: Some("provisioned".to_string()),
+                config_revision: Some(1),
+                target_host: None,
+                steps: Vec::new(),
+                tool_versions: BTreeMap::new(),
+                applied_settings: BTreeMap::new(),
+                trace_id: None,
+                trace_file: None,
+                error: None,
+            },
+            trace: None,
+        };
+
+        let output = JsonView::render(&data).unwrap();
+
+        let parsed: serde_json::Value = serde_json::from_str(&output).unwrap();
+        assert_eq!(parsed["file_name"], "20260101-120000-provision.json");
+        assert_eq!(parsed["command"], "provision");
+    }
+}
@@ -0,0 +1,257 @@
+//! Text View for Deployment Reports
+//!
+//! This module provides text-based rendering for the reports command:
+//! a table with one row per report, or the details of one report with the
+//! timing of each step.
+
+use crate::presentation::cli::views::commands::reports::view_data::{
+    ReportDetailsData, ReportListData,
+};
+use crate::presentation::cli::views::commands::shared::duration::format_duration;
+use crate::presentation::cli::views::{Render, ViewRenderError};
+
+/// Text view for rendering the report list and single reports
+pub struct TextView;
+
+impl TextView {
+    /// Format the outcome of a command
+    fn outcome(succeeded: bool) -> &'static str {
+        if succeeded {
+            "succeeded"
+        } else {
+            "failed"
+        }
+    }
+}
+
+impl Render<ReportListData> for TextView {
+    fn render(data: &ReportListData) -> Result<String, ViewRenderError> {
+        if data.reports.is_empty() {
+            return Ok(format!(
+                "No deployment reports found for environment '{}'.",
+                data.environment_name
+            ));
+        }
+
+        let mut lines = vec![
+            format!(
+                "Deployment reports for environment '{}' ({} found):",
+                data.environment_name,
+                data.reports.len()
+            ),
+            String::new(),
+            format!(
+                "{:<40} {:<10} {:<10} {:<18} {}",
+                "File", "Command", "Result", "State", "Duration"
+            ),
+            "─".repeat(90),
+        ];
+
+        for report in &data.reports {
+            lines.push(format!(
+                "{:<40} {:<10} {:<10} {:<18} {}",
+                report.file_name,
+                report.command,
+                Self::outcome(report.succeeded),
+                report.state.as_deref().unwrap_or("-"),
+                format_duration(report.duration_ms)
+            ));
+        }
+
+        lines.push(String::new());
+        lines.push("To display the latest report:".to_string());
+        lines.push(format!(
+            "  torrust-tracker-deployer reports {} --latest",
+            data.environment_name
+        ));
+
+        Ok(lines.join("\n"))
+    }
+}
+
+impl Render<ReportDetailsData> for TextView {
+    fn render(data: &ReportDetailsData) -> Result<String, ViewRenderError> {
+        let report = &data.report;
+
+        let mut lines = vec![
+            format!("Deployment report: {}", data.file_name),
+            format!("  Command:     {}", report.command),
+            format!("  Environment: {}", report.environment),
+            format!("  Started:     {}", report.started_at.to_rfc3339()),
+            format!("  Finished:    {}", report.finished_at.to_rfc3339()),
+            format!("  Duration:    {}", format_duration(report.duration_ms)),
+            format!("  Result:      {}", Self::outcome(report.succeeded)),
+            format!("  State:       {}", report.state.as_deref().unwrap_or("-")),
+        ];
+
+        if let Some(config_revision) = report.config_revision {
+            lines.push(format!("  Revision:    {config_revision}"));
+        }
+        if let Some(target_host) = &report.target_host {
+            lines.push(format!(
+                "  Target:      {target_host} (out-of-band, environment state unchanged)"
+            ));
+        }
+
+        if let Some(trace_id) = &report.trace_id {
+            lines.push(format!("  Trace ID:    {trace_id}"));
+        }
+        if let Some(trace) = &data.trace {
+            lines.push(format!("  Failed step: {}", trace.step));
+        }
+        if let Some(trace_file) = &report.trace_file {
+            lines.push(format!("  Trace file:  {}", trace_file.display()));
+        }
+        if let Some(error) = &report.error {
+            lines.push(format!("  Error:       {error}"));
+        }
+
+        if !report.tool_versions.is_empty() {
+            let tools: Vec<String> = report
+                .tool_versions
+                .iter()
+                .map(|(tool, version)| format!("{tool} {version}"))
+                .collect();
+            lines.push(format!("  Tools:       {}", tools.join(", ")));
+        }
+
+        if !report.steps.is_empty() {
+            lines.push(String::new());
+            lines.push("Steps:".to_string());
+            for step in &report.steps {
+                let duration = step
+                    .duration_ms
+                    .map_or_else(|| "did not complete".to_string(), format_duration);
+                lines.push(format!(
+                    "  {:>2}. {:<50} {}",
+                    step.number, step.description, duration
+                ));
+            }
+        }
+
+        if !report.applied_settings.is_empty() {
+            lines.push(String::new());
+            lines.push("Applied settings:".to_string());
+            for (name, value) in &report.applied_settings {
+                lines.push(format!("  {name} = {value}"));
+            }
+        }
+
+        Ok(lines.join("\n"))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::BTreeMap;
+
+    use chrono::{TimeZone, Utc};
+
+    use super::*;
+    use crate::domain::environment::{DeploymentReport, StepReport, TraceId};
+    use crate::infrastructure::trace::TraceIndexEntry;
+
+    fn details(succeeded: bool) -> ReportDetailsData {
+        let at = Utc.with_ymd_and_hms(2026, 1, 1, 12, 0, 0).unwrap();
+        ReportDetailsData {
+            file_name: "20260101-120000-provision.json".to_string(),
+            report: DeploymentReport {
+                command: "provision".to_string(),
+                environment: "my-env".to_string(),
+                started_at: at,
+                finished_at: at,
+                duration_ms: 95_250,
+                succeeded,
+                state: Some("provisioned".to_string()),
+                config_revision: Some(2),
+                target_host: None,
+                steps: vec![
+                    StepReport {
+                        number: 1,
+                        description: "Rendering OpenTofu templates".to_string(),
+                        started_at: at,
+                        finished_at: Some(at),
+                        duration_ms: Some(1_200),
+                    },
+                    StepReport {
+                        number: 2,
+                        description: "Applying infrastructure".to_string(),
+                        started_at: at,
+                        finished_at: None,
+                        duration_ms: None,
+                    },
+                ],
+                tool_versions: BTreeMap::from([("opentofu".to_string(), "1.8.0".to_string())]),
+                applied_settings: BTreeMap::from([(
+                    "net.core.rmem_max".to_string(),
+                    "33554432".to_string(),
+                )]),
+                trace_id: (!succeeded).then(|| "abc123".to_string()),
+                trace_file: None,
+                error: None,
+            },
+            trace: None,
+        }
+    }
+
+    #[test]
+    fn it_should_render_the_timing_of_each_step() {
+        let output = TextView::render(&details(true)).unwrap();
+
+        assert!(output.contains("Duration:    95.2s"));
+        assert!(output.contains(" 1. Rendering OpenTofu templates"));
+        assert!(output.contains("1.2s"));
+        assert!(output.contains("did not complete"));
+        assert!(output.contains("Tools:       opentofu 1.8.0"));
+        assert!(output.contains("Revision:    2"));
+        assert!(output.contains("Applied settings:\n  net.core.rmem_max = 33554432"));
+    }
+
+    #[test]
+    fn it_should_mark_an_out_of_band_run() {
+        let mut data = details(true);
+        data.report.target_host = Some("127.0.0.1:2222".to_string());
+
+        let output = TextView::render(&data).unwrap();
+
+        assert!(output.contains("Target:      127.0.0.1:2222 (out-of-band"));
+    }
+
+    #[test]
+    fn it_should_render_the_trace_id_of_a_failed_run() {
+        let output = TextView::render(&details(false)).unwrap();
+
+        assert!(output.contains("Result:      failed"));
+        assert!(output.contains("Trace ID:    abc123"));
+    }
+
+    #[test]
+    fn it_should_render_the_failed_step_and_trace_file_of_a_failed_run() {
+        let mut data = details(false);
+        data.report.trace_file = Some("/ci/traces/20260101-120000-provision.log".into());
+        data.trace = Some(TraceIndexEntry {
+            trace_id: TraceId::new(),
+            command: "provision".to_string(),
+            step: "wait_cloud_init".to_string(),
+            timestamp: data.report.finished_at,
+            file: "20260101-120000-provision.log".to_string(),
+        });
+
+        let output = TextView::render(&data).unwrap();
+
+        assert!(output.contains("Failed step: wait_cloud_init"));
+        assert!(output.contains("Trace file:  /ci/traces/20260101-120000-provision.log"));
+    }
+
+    #[test]
+    fn it_should_render_a_hint_when_there_are_no_reports() {
+        let data = ReportListData {
+            environment_name: "my-env".to_string(),
+            reports: Vec::new(),
+        };
+
+        let output = TextView::render(&data).unwrap();
+
+        assert!(output.contains("No deployment reports found"));
+    }
+}
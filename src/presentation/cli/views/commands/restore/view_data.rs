@@ -0,0 +1,37 @@
+//! View data for the restore command
+
+use serde::Serialize;
+
+use crate::domain::environment::DatabaseBackup;
+
+/// Data for rendering a restored backup of the tracker database
+#[derive(Debug, Clone, Serialize)]
+pub struct RestoreData {
+    /// Name of the environment
+    pub environment_name: String,
+    /// Id of the restored backup
+    pub id: String,
+    /// When the backup was taken (RFC 3339)
+    pub created_at: String,
+    /// Database driver of the tracker
+    pub driver: String,
+    /// Size of the backup file in bytes
+    pub size: u64,
+    /// SHA-256 checksum of the backup file, verified before the upload
+    pub sha256: String,
+}
+
+impl RestoreData {
+    /// Build the view data from a restored backup
+    #[must_use]
+    pub fn new(environment_name: &str, backup: &DatabaseBackup) -> Self {
+        Self {
+            environment_name: environment_name.to_string(),
+            id: backup.id.clone(),
+            created_at: backup.created_at.to_rfc3339(),
+            driver: backup.driver.clone(),
+            size: backup.size,
+            sha256: backup.sha256.clone(),
+        }
+    }
+}
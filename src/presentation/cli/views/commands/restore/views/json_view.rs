@@ -0,0 +1,15 @@
+//! JSON View for Restored Backups
+//!
+//! This module provides JSON-based rendering for the restore command.
+
+use crate::presentation::cli::views::commands::restore::view_data::RestoreData;
+use crate::presentation::cli::views::{Render, ViewRenderError};
+
+/// JSON view for rendering a restored backup
+pub struct JsonView;
+
+impl Render<RestoreData> for JsonView {
+    fn render(data: &RestoreData) -> Result<String, ViewRenderError> {
+        Ok(serde_json::to_string_pretty(data)?)
+    }
+}
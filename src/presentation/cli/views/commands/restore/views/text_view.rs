@@ -0,0 +1,28 @@
+//! Text View for Restored Backups
+//!
+//! This module provides text-based rendering for the restore command.
+
+use crate::presentation::cli::views::commands::restore::view_data::RestoreData;
+use crate::presentation::cli::views::{Render, ViewRenderError};
+
+/// Text view for rendering a restored backup
+pub struct TextView;
+
+impl Render<RestoreData> for TextView {
+    fn render(data: &RestoreData) -> Result<String, ViewRenderError> {
+        let lines = [
+            format!(
+                "Backup {} restored into environment '{}':",
+                data.id, data.environment_name
+            ),
+            format!("  Taken:   {}", data.created_at),
+            format!("  Driver:  {}", data.driver),
+            format!("  Size:    {} bytes", data.size),
+            format!("  SHA-256: {} (verified)", data.sha256),
+            String::new(),
+            "The tracker was restarted and reports healthy.".to_string(),
+        ];
+
+        Ok(lines.join("\n"))
+    }
+}
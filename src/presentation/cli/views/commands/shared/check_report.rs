@@ -0,0 +1,137 @@
+//! Check Report Views
+//!
+//! This module provides the text and JSON views for the result of a
+//! `--check` (dry-run) run of the `configure` and `release` commands.
+
+use serde::Serialize;
+
+use crate::application::command_handlers::common::CheckReport;
+use crate::presentation::cli::views::{Render, ViewRenderError};
+
+/// Data for rendering a check mode run
+#[derive(Debug, Clone, Serialize)]
+pub struct CheckReportData {
+    /// Name of the environment that was checked
+    pub environment_name: String,
+    /// Command that was run in check mode (`configure` or `release`)
+    pub command: String,
+    /// Total number of tasks that would change the host
+    pub changed_tasks: u32,
+    /// Per-playbook task counters, in run order
+    pub playbooks: Vec<CheckedPlaybookData>,
+}
+
+/// Task counters of one playbook run in check mode
+#[derive(Debug, Clone, Serialize)]
+pub struct CheckedPlaybookData {
+    /// Name of the playbook
+    pub playbook: String,
+    /// Tasks that would change the host
+    pub changed: u32,
+    /// Tasks already in the desired state
+    pub ok: u32,
+    /// Tasks skipped by conditions or tag selection
+    pub skipped: u32,
+}
+
+impl CheckReportData {
+    /// Build the view data from the application layer report
+    #[must_use]
+    pub fn new(environment_name: &str, command: &str, report: &CheckReport) -> Self {
+        Self {
+            environment_name: environment_name.to_string(),
+            command: command.to_string(),
+            changed_tasks: report.changed_tasks(),
+            playbooks: report
+                .recaps()
+                .iter()
+                .map(|recap| CheckedPlaybookData {
+                    playbook: recap.playbook.clone(),
+                    changed: recap.changed,
+                    ok: recap.ok,
+                    skipped: recap.skipped,
+                })
+                .collect(),
+        }
+    }
+}
+
+/// Human-readable view of a check mode run
+pub struct CheckReportTextView;
+
+impl Render<CheckReportData> for CheckReportTextView {
+    fn render(data: &CheckReportData) -> Result<String, ViewRenderError> {
+        let mut lines = vec![format!(
+            "Check mode ({}) for environment '{}': no changes were applied.",
+            data.command, data.environment_name
+        )];
+
+        for playbook in &data.playbooks {
+            lines.push(format!(
+                "  {}: {} would change, {} ok, {} skipped",
+                playbook.playbook, playbook.changed, playbook.ok, playbook.skipped
+            ));
+        }
+
+        if data.changed_tasks == 0 {
+            lines.push("The host is up to date.".to_string());
+        } else {
+            lines.push(format!(
+                "{} task(s) would change the host. Run '{} {}' without --check to apply them.",
+                data.changed_tasks, data.command, data.environment_name
+            ));
+        }
+
+        Ok(lines.join("\n"))
+    }
+}
+
+/// Machine-readable view of a check mode run
+pub struct CheckReportJsonView;
+
+impl Render<CheckReportData> for CheckReportJsonView {
+    fn render(data: &CheckReportData) -> Result<String, ViewRenderError> {
+        Ok(serde_json::to_string_pretty(data)?)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::adapters::ansible::PlaybookRecap;
+
+    fn report_data(changed: u32) -> CheckReportData {
+        let report = CheckReport::new(vec![PlaybookRecap {
+            playbook: "install-docker".to_string(),
+            ok: 4,
+            changed,
+            ..PlaybookRecap::default()
+        }]);
+
+        CheckReportData::new("my-env", "configure", &report)
+    }
+
+    #[test]
+    fn it_should_list_the_playbooks_that_would_change_the_host() {
+        let output = CheckReportTextView::render(&report_data(2)).unwrap();
+
+        assert!(output.contains("install-docker: 2 would change, 4 ok, 0 skipped"));
+        assert!(output.contains("2 task(s) would change the host"));
+    }
+
+    #[test]
+    fn it_should_report_an_up_to_date_host() {
+        let output = CheckReportTextView::render(&report_data(0)).unwrap();
+
+        assert!(output.contains("The host is up to date."));
+    }
+
+    #[test]
+    fn it_should_render_the_check_report_as_json() {
+        let output = CheckReportJsonView::render(&report_data(2)).unwrap();
+
+        let parsed: serde_json::Value = serde_json::from_str(&output).unwrap();
+        assert_eq!(parsed["changed_tasks"], 2);
+        assert_eq!(parsed["playbooks"][0]["playbook"], "install-docker");
+    }
+}
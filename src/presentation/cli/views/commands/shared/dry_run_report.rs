@@ -0,0 +1,164 @@
+//! Dry Run Report Views
+//!
+//! This module provides the text and JSON views for the result of a
+//! `--dry-run` of the `destroy`, `purge` and `provision` commands.
+
+use serde::Serialize;
+
+use crate::application::command_handlers::common::DryRunReport;
+use crate::presentation::cli::views::{Render, ViewRenderError};
+
+/// Data for rendering a dry run
+#[derive(Debug, Clone, Serialize)]
+pub struct DryRunReportData {
+    /// Always `true`: marks the output as a simulation
+    pub dry_run: bool,
+    /// Name of the environment that was simulated
+    pub environment_name: String,
+    /// Command that was simulated (`destroy`, `purge` or `provision`)
+    pub command: String,
+    /// Infrastructure resources the command would change
+    pub resources: Vec<PlannedResourceData>,
+    /// Local files and directories the command would delete
+    pub removed_paths: Vec<String>,
+    /// Remarks about the simulated run
+    pub notes: Vec<String>,
+}
+
+/// An infrastructure resource the command would change
+#[derive(Debug, Clone, Serialize)]
+pub struct PlannedResourceData {
+    /// `OpenTofu` resource address
+    pub address: String,
+    /// What would happen to the resource (`create`, `destroy`, ...)
+    pub action: String,
+}
+
+impl DryRunReportData {
+    /// Build the view data from the application layer report
+    #[must_use]
+    pub fn new(environment_name: &str, command: &str, report: &DryRunReport) -> Self {
+        Self {
+            dry_run: true,
+            environment_name: environment_name.to_string(),
+            command: command.to_string(),
+            resources: report
+                .infrastructure_changes()
+                .iter()
+                .map(|change| PlannedResourceData {
+                    address: change.address.clone(),
+                    action: change.action.to_string(),
+                })
+                .collect(),
+            removed_paths: report
+                .removed_paths()
+                .iter()
+                .map(|path| path.display().to_string())
+                .collect(),
+            notes: report.notes().to_vec(),
+        }
+    }
+}
+
+/// Human-readable view of a dry run
+pub struct DryRunReportTextView;
+
+impl Render<DryRunReportData> for DryRunReportTextView {
+    fn render(data: &DryRunReportData) -> Result<String, ViewRenderError> {
+        let mut lines = vec![format!(
+            "DRY RUN ({}) for environment '{}': simulation only, no changes were made.",
+            data.command, data.environment_name
+        )];
+
+        if !data.resources.is_empty() {
+            lines.push("Infrastructure resources:".to_string());
+            for resource in &data.resources {
+                lines.push(format!("  {} ({})", resource.address, resource.action));
+            }
+        }
+
+        if !data.removed_paths.is_empty() {
+            lines.push("Local paths that would be deleted:".to_string());
+            for path in &data.removed_paths {
+                lines.push(format!("  {path}"));
+            }
+        }
+
+        for note in &data.notes {
+            lines.push(format!("Note: {note}"));
+        }
+
+        if data.resources.is_empty() && data.removed_paths.is_empty() {
+            lines.push("Nothing would change.".to_string());
+        } else {
+            lines.push(format!(
+                "Run '{} {}' without --dry-run to apply these changes.",
+                data.command, data.environment_name
+            ));
+        }
+
+        Ok(lines.join("\n"))
+    }
+}
+
+/// Machine-readable view of a dry run
+pub struct DryRunReportJsonView;
+
+impl Render<DryRunReportData> for DryRunReportJsonView {
+    fn render(data: &DryRunReportData) -> Result<String, ViewRenderError> {
+        Ok(serde_json::to_string_pretty(data)?)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::path::PathBuf;
+
+    use super::*;
+    use crate::adapters::tofu::{PlannedAction, PlannedChange};
+
+    fn report_data() -> DryRunReportData {
+        let report = DryRunReport::new()
+            .with_infrastructure_changes(vec![PlannedChange {
+                address: "lxd_instance.torrust_vm".to_string(),
+                action: PlannedAction::Destroy,
+            }])
+            .with_removed_path(PathBuf::from("data/my-env"));
+
+        DryRunReportData::new("my-env", "destroy", &report)
+    }
+
+    #[test]
+    fn it_should_label_the_output_as_a_simulation() {
+        let output = DryRunReportTextView::render(&report_data()).unwrap();
+
+        assert!(output.starts_with("DRY RUN (destroy)"));
+        assert!(output.contains("no changes were made"));
+    }
+
+    #[test]
+    fn it_should_list_the_resources_and_paths_that_would_be_removed() {
+        let output = DryRunReportTextView::render(&report_data()).unwrap();
+
+        assert!(output.contains("  lxd_instance.torrust_vm (destroy)"));
+        assert!(output.contains("  data/my-env"));
+    }
+
+    #[test]
+    fn it_should_report_when_nothing_would_change() {
+        let data = DryRunReportData::new("my-env", "purge", &DryRunReport::new());
+
+        let output = DryRunReportTextView::render(&data).unwrap();
+
+        assert!(output.contains("Nothing would change."));
+    }
+
+    #[test]
+    fn it_should_render_the_dry_run_report_as_json() {
+        let output = DryRunReportJsonView::render(&report_data()).unwrap();
+
+        let parsed: serde_json::Value = serde_json::from_str(&output).unwrap();
+        assert_eq!(parsed["dry_run"], true);
+        assert_eq!(parsed["resources"][0]["action"], "destroy");
+    }
+}
@@ -0,0 +1,22 @@
+//! Duration Formatting
+//!
+//! This module formats the recorded durations, in milliseconds, shown by the
+//! `show`, `stats` and `reports` commands and the `--label` summaries.
+
+/// Format a duration in milliseconds as seconds with one decimal (e.g. `95.2s`)
+#[must_use]
+pub fn format_duration(duration_ms: u64) -> String {
+    format!("{}.{}s", duration_ms / 1000, (duration_ms % 1000) / 100)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn it_should_format_milliseconds_as_seconds_with_one_decimal() {
+        assert_eq!(format_duration(0), "0.0s");
+        assert_eq!(format_duration(999), "0.9s");
+        assert_eq!(format_duration(95_230), "95.2s");
+    }
+}
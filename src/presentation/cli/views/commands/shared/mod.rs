@@ -6,6 +6,16 @@
 //!
 //! # Module Structure
 //!
+//! - `check_report`: Views for the result of a `--check` run of `configure` or `release`
+//! - `dry_run_report`: Views for the result of a `--dry-run` of `destroy`, `purge` or `provision`
+//! - `duration`: Formatting of recorded durations in milliseconds
+//! - `multi_environment_report`: Views for the summary of `provision`, `configure` or `destroy` run with `--label`
 //! - `service_urls`: Reusable views for rendering service URLs in a compact format
+//! - `services_state_report`: Views for the result of `stop` or `start`
 
+pub mod check_report;
+pub mod dry_run_report;
+pub mod duration;
+pub mod multi_environment_report;
 pub mod service_urls;
+pub mod services_state_report;
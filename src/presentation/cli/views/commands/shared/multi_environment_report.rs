@@ -0,0 +1,214 @@
+//! Multi-Environment Report Views
+//!
+//! This module provides the text and JSON views for the summary of a command
+//! run on the environments selected with `--label` (`provision`, `configure`
+//! or `destroy`).
+
+use serde::Serialize;
+
+use super::duration::format_duration;
+use crate::application::command_handlers::common::MultiEnvironmentReport;
+use crate::domain::environment::Label;
+use crate::presentation::cli::views::{Render, ViewRenderError};
+
+/// Data for rendering the summary of a multi-environment run
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct MultiEnvironmentReportData {
+    /// Command that was run (`provision`, `configure` or `destroy`)
+    pub command: String,
+    /// The label selection, as given on the command line (e.g. `purpose=e2e`)
+    pub labels: Vec<String>,
+    /// Maximum number of environments processed at the same time
+    pub parallelism: usize,
+    /// One result per selected environment, in name order
+    pub results: Vec<MultiEnvironmentResult>,
+}
+
+/// Result of the command on one of the selected environments
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct MultiEnvironmentResult {
+    /// Name of the environment
+    pub environment_name: String,
+    /// Whether the command succeeded on the environment
+    pub succeeded: bool,
+    /// How long the command ran on the environment, in milliseconds
+    pub duration_ms: u64,
+    /// Why the command failed
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub error: Option<String>,
+}
+
+impl MultiEnvironmentReportData {
+    /// Build the view data from the application layer report
+    #[must_use]
+    pub fn new(
+        command: &str,
+        labels: &[Label],
+        parallelism: usize,
+        report: &MultiEnvironmentReport,
+    ) -> Self {
+        Self {
+            command: command.to_string(),
+            labels: labels.iter().map(ToString::to_string).collect(),
+            parallelism,
+            results: report
+                .outcomes
+                .iter()
+                .map(|outcome| MultiEnvironmentResult {
+                    environment_name: outcome.name.to_string(),
+                    succeeded: outcome.succeeded(),
+                    duration_ms: u64::try_from(outcome.duration.as_millis()).unwrap_or(u64::MAX),
+                    error: outcome.error.clone(),
+                })
+                .collect(),
+        }
+    }
+
+    /// Number of environments the command failed on
+    #[must_use]
+    pub fn failed_count(&self) -> usize {
+        self.results.iter().filter(|r| !r.succeeded).count()
+    }
+}
+
+/// Human-readable view of a multi-environment run: a table and a summary
+pub struct MultiEnvironmentReportTextView;
+
+impl Render<MultiEnvironmentReportData> for MultiEnvironmentReportTextView {
+    fn render(data: &MultiEnvironmentReportData) -> Result<String, ViewRenderError> {
+        let selection = data.labels.join(", ");
+
+        if data.results.is_empty() {
+            return Ok(format!(
+                "No environment to {} has the labels: {selection}",
+                data.command
+            ));
+        }
+
+        let width = data
+            .results
+            .iter()
+            .map(|r| r.environment_name.len())
+            .max()
+            .unwrap_or_default()
+            .max("Environment".len());
+
+        let mut lines = vec![
+            format!(
+                "{} of the environments with the labels: {selection} (parallelism: {})",
+                data.command, data.parallelism
+            ),
+            format!(
+                "  {:<width$}  {:<9}  {:>9}",
+                "Environment", "Result", "Duration"
+            ),
+        ];
+        for result in &data.results {
+            let status = if result.succeeded {
+                "✓ ok"
+            } else {
+                "✗ failed"
+            };
+            lines.push(format!(
+                "  {:<width$}  {status:<9}  {:>9}",
+                result.environment_name,
+                format_duration(result.duration_ms)
+            ));
+            if let Some(error) = &result.error {
+                lines.push(format!("  {:<width$}    {error}", ""));
+            }
+        }
+
+        let total = data.results.len();
+        let failed = data.failed_count();
+        lines.push(format!(
+            "{} of {total} environments succeeded, {failed} failed",
+            total - failed
+        ));
+
+        Ok(lines.join("\n"))
+    }
+}
+
+/// Machine-readable view of a multi-environment run
+pub struct MultiEnvironmentReportJsonView;
+
+impl Render<MultiEnvironmentReportData> for MultiEnvironmentReportJsonView {
+    fn render(data: &MultiEnvironmentReportData) -> Result<String, ViewRenderError> {
+        Ok(serde_json::to_string_pretty(data)?)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use super::*;
+    use crate::application::command_handlers::common::EnvironmentOutcome;
+    use crate::domain::environment::name::EnvironmentName;
+
+    fn report_data() -> MultiEnvironmentReportData {
+        let outcome = |name: &str, error: Option<&str>, millis: u64| EnvironmentOutcome {
+            name: EnvironmentName::new(name.to_string()).unwrap(),
+            error: error.map(ToString::to_string),
+            duration: Duration::from_millis(millis),
+        };
+        let report = MultiEnvironmentReport {
+            outcomes: vec![
+                outcome("e2e-1", None, 312_400),
+                outcome("e2e-2", Some("tofu apply failed"), 45_000),
+            ],
+        };
+
+        MultiEnvironmentReportData::new(
+            "provision",
+            &["purpose=nightly".parse().unwrap()],
+            4,
+            &report,
+        )
+    }
+
+    #[test]
+    fn it_should_render_a_table_row_per_environment_and_a_summary() {
+        let text = MultiEnvironmentReportTextView::render(&report_data()).unwrap();
+
+        assert_eq!(
+            text,
+            [
+                "provision of the environments with the labels: purpose=nightly (parallelism: 4)",
+                "  Environment  Result      Duration",
+                "  e2e-1        ✓ ok          312.4s",
+                "  e2e-2        ✗ failed       45.0s",
+                "                 tofu apply failed",
+                "1 of 2 environments succeeded, 1 failed",
+            ]
+            .join("\n")
+        );
+    }
+
+    #[test]
+    fn it_should_say_when_no_environment_matches() {
+        let data = MultiEnvironmentReportData {
+            results: vec![],
+            ..report_data()
+        };
+
+        let text = MultiEnvironmentReportTextView::render(&data).unwrap();
+
+        assert_eq!(
+            text,
+            "No environment to provision has the labels: purpose=nightly"
+        );
+    }
+
+    #[test]
+    fn it_should_render_the_report_as_json() {
+        let output = MultiEnvironmentReportJsonView::render(&report_data()).unwrap();
+
+        let parsed: serde_json::Value = serde_json::from_str(&output).unwrap();
+        assert_eq!(parsed["parallelism"], 4);
+        assert_eq!(parsed["results"][0]["succeeded"], true);
+        assert_eq!(parsed["results"][1]["error"], "tofu apply failed");
+        assert!(parsed["results"][0].get("error").is_none());
+    }
+}
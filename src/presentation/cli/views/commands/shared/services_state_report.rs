@@ -0,0 +1,90 @@
+//! Services State Report Views
+//!
+//! This module provides the text and JSON views for the result of the
+//! `stop` and `start` commands, which only change whether the tracker
+//! services of an environment are running.
+
+use serde::Serialize;
+
+use crate::presentation::cli::views::{Render, ViewRenderError};
+
+/// Data for rendering the services state after `stop` or `start`
+#[derive(Debug, Clone, Serialize)]
+pub struct ServicesStateData {
+    /// Name of the environment
+    pub environment_name: String,
+    /// Command that was run (`stop` or `start`)
+    pub command: String,
+    /// State of the environment after the command
+    pub state: String,
+}
+
+impl ServicesStateData {
+    /// Build the view data for an environment now in `state`
+    #[must_use]
+    pub fn new(environment_name: &str, command: &str, state: &str) -> Self {
+        Self {
+            environment_name: environment_name.to_string(),
+            command: command.to_string(),
+            state: state.to_string(),
+        }
+    }
+}
+
+/// Human-readable view of the services state
+pub struct ServicesStateTextView;
+
+impl Render<ServicesStateData> for ServicesStateTextView {
+    fn render(data: &ServicesStateData) -> Result<String, ViewRenderError> {
+        let mut lines = vec![format!(
+            "Environment '{}' is now {}",
+            data.environment_name, data.state
+        )];
+
+        if data.command == "stop" {
+            lines.push(format!(
+                "The tracker is offline. Run 'start {}' to bring it back.",
+                data.environment_name
+            ));
+        } else {
+            lines.push("The tracker reports healthy.".to_string());
+        }
+
+        Ok(lines.join("\n"))
+    }
+}
+
+/// Machine-readable view of the services state
+pub struct ServicesStateJsonView;
+
+impl Render<ServicesStateData> for ServicesStateJsonView {
+    fn render(data: &ServicesStateData) -> Result<String, ViewRenderError> {
+        Ok(serde_json::to_string_pretty(data)?)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn it_should_tell_how_to_bring_a_stopped_tracker_back() {
+        let data = ServicesStateData::new("my-env", "stop", "stopped");
+
+        let output = ServicesStateTextView::render(&data).unwrap();
+
+        assert!(output.starts_with("Environment 'my-env' is now stopped"));
+        assert!(output.contains("Run 'start my-env'"));
+    }
+
+    #[test]
+    fn it_should_render_the_state_as_json() {
+        let data = ServicesStateData::new("my-env", "start", "running");
+
+        let json = ServicesStateJsonView::render(&data).unwrap();
+
+        let parsed: serde_json::Value = serde_json::from_str(&json).unwrap();
+        assert_eq!(parsed["state"], "running");
+        assert_eq!(parsed["command"], "start");
+    }
+}
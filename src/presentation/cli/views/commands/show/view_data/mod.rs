@@ -1,6 +1,7 @@
 pub mod show_details;
 
 pub use show_details::{
-    DockerImagesInfo, EnvironmentInfo, GrafanaInfo, InfrastructureInfo, LocalhostServiceInfo,
-    PrometheusInfo, ServiceInfo, TlsDomainInfo,
+    DockerImagesInfo, EndpointInfo, EndpointsInfo, EnvironmentInfo, FailureInfo, GrafanaInfo,
+    InfrastructureInfo, LocalhostServiceInfo, PhaseDurationsInfo, PrometheusInfo, ReleaseInfo,
+    SecretsInfo, ServiceInfo, StateFileInfo, TlsDomainInfo,
 };
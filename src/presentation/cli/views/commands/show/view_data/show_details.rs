@@ -5,10 +5,17 @@
 //! from the application layer.
 
 pub use crate::application::command_handlers::show::info::DockerImagesInfo;
+pub use crate::application::command_handlers::show::info::EndpointInfo;
+pub use crate::application::command_handlers::show::info::EndpointsInfo;
 pub use crate::application::command_handlers::show::info::EnvironmentInfo;
+pub use crate::application::command_handlers::show::info::FailureInfo;
 pub use crate::application::command_handlers::show::info::GrafanaInfo;
 pub use crate::application::command_handlers::show::info::InfrastructureInfo;
 pub use crate::application::command_handlers::show::info::LocalhostServiceInfo;
+pub use crate::application::command_handlers::show::info::PhaseDurationsInfo;
 pub use crate::application::command_handlers::show::info::PrometheusInfo;
+pub use crate::application::command_handlers::show::info::ReleaseInfo;
+pub use crate::application::command_handlers::show::info::SecretsInfo;
 pub use crate::application::command_handlers::show::info::ServiceInfo;
+pub use crate::application::command_handlers::show::info::StateFileInfo;
 pub use crate::application::command_handlers::show::info::TlsDomainInfo;
@@ -0,0 +1,70 @@
+//! Phase Durations View
+//!
+//! This module provides a view for rendering how long the last successful run
+//! of each deployment phase took.
+
+use crate::presentation::cli::views::commands::shared::duration::format_duration;
+use crate::presentation::cli::views::commands::show::view_data::PhaseDurationsInfo;
+
+/// View for rendering the deployment phase durations
+pub struct DurationsView;
+
+impl DurationsView {
+    /// Render the recorded phase durations as formatted lines
+    ///
+    /// Phases that never succeeded are skipped. Returns no lines when no
+    /// duration has been recorded.
+    #[must_use]
+    pub fn render(durations: &PhaseDurationsInfo) -> Vec<String> {
+        if durations.is_empty() {
+            return Vec::new();
+        }
+
+        let mut lines = vec![String::new(), "Durations:".to_string()];
+
+        let phases = [
+            ("provision", durations.provision_ms),
+            ("configure", durations.configure_ms),
+            ("release", durations.release_ms),
+            ("run", durations.run_ms),
+        ];
+        for (phase, duration_ms) in phases {
+            if let Some(duration_ms) = duration_ms {
+                lines.push(format!("  {phase:<10} {}", format_duration(duration_ms)));
+            }
+        }
+
+        lines
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn it_should_list_the_recorded_phases_in_deployment_order() {
+        let lines = DurationsView::render(&PhaseDurationsInfo {
+            provision_ms: Some(95_230),
+            configure_ms: None,
+            release_ms: Some(12_000),
+            run_ms: Some(4_560),
+        });
+
+        assert_eq!(
+            lines,
+            vec![
+                String::new(),
+                "Durations:".to_string(),
+                "  provision  95.2s".to_string(),
+                "  release    12.0s".to_string(),
+                "  run        4.5s".to_string(),
+            ]
+        );
+    }
+
+    #[test]
+    fn it_should_render_nothing_without_durations() {
+        assert!(DurationsView::render(&PhaseDurationsInfo::default()).is_empty());
+    }
+}
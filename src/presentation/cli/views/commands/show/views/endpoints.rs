@@ -0,0 +1,85 @@
+//! Endpoints View
+//!
+//! This module provides a view for rendering the resolved service endpoint
+//! URLs (announce, API and health check) of a provisioned environment.
+
+use crate::presentation::cli::views::commands::show::view_data::{EndpointInfo, EndpointsInfo};
+
+/// View for rendering resolved service endpoints
+///
+/// This view prints one copy-pasteable URL per line, marking endpoints that
+/// are only reachable through an SSH tunnel.
+pub struct EndpointsView;
+
+impl EndpointsView {
+    /// Render resolved service endpoints as formatted lines
+    ///
+    /// # Arguments
+    ///
+    /// * `endpoints` - Resolved endpoints of the tracker services
+    ///
+    /// # Returns
+    ///
+    /// A vector of formatted lines ready to be joined
+    #[must_use]
+    pub fn render(endpoints: &EndpointsInfo) -> Vec<String> {
+        let mut lines = vec![
+            String::new(), // blank line
+            "Endpoints:".to_string(),
+        ];
+
+        for endpoint in &endpoints.udp_announce {
+            lines.push(Self::render_endpoint("UDP announce", endpoint));
+        }
+        for endpoint in &endpoints.http_announce {
+            lines.push(Self::render_endpoint("HTTP announce", endpoint));
+        }
+        lines.push(Self::render_endpoint("API", &endpoints.api));
+        lines.push(Self::render_endpoint(
+            "Health check",
+            &endpoints.health_check,
+        ));
+
+        lines
+    }
+
+    fn render_endpoint(label: &str, endpoint: &EndpointInfo) -> String {
+        if endpoint.localhost_only {
+            format!("  {label}: {} (internal only)", endpoint.url)
+        } else {
+            format!("  {label}: {}", endpoint.url)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn endpoint(url: &str, localhost_only: bool) -> EndpointInfo {
+        EndpointInfo {
+            url: url.to_string(),
+            uses_tls: url.starts_with("https://"),
+            localhost_only,
+        }
+    }
+
+    #[test]
+    fn it_should_render_one_copy_pasteable_url_per_line() {
+        let endpoints = EndpointsInfo {
+            udp_announce: vec![endpoint("udp://10.0.0.1:6969/announce", false)],
+            http_announce: vec![endpoint("https://http.tracker.local/announce", false)],
+            api: endpoint("http://10.0.0.1:1212/api", false), // DevSkim: ignore DS137138
+            health_check: endpoint("http://10.0.0.1:1313/health_check", true), // DevSkim: ignore DS137138
+        };
+
+        let lines = EndpointsView::render(&endpoints);
+
+        assert_eq!(lines[1], "Endpoints:");
+        assert!(lines.contains(&"  UDP announce: udp://10.0.0.1:6969/announce".to_string()));
+        assert!(lines.contains(&"  HTTP announce: https://http.tracker.local/announce".to_string()));
+        assert!(lines.contains(
+            &"  Health check: http://10.0.0.1:1313/health_check (internal only)".to_string() // DevSkim: ignore DS137138
+        ));
+    }
+}
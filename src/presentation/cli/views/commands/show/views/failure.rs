@@ -0,0 +1,99 @@
+//! Error Details View
+//!
+//! This module provides a view for rendering the command failure that left
+//! the environment in a failed state.
+
+use crate::presentation::cli::views::commands::shared::duration::format_duration;
+use crate::presentation::cli::views::commands::show::view_data::FailureInfo;
+
+/// View for rendering the details of the last command failure
+pub struct FailureView;
+
+impl FailureView {
+    /// Render the failure details as formatted lines
+    ///
+    /// The failed Ansible task is only shown when the step failed in a
+    /// playbook task.
+    #[must_use]
+    pub fn render(failure: &FailureInfo) -> Vec<String> {
+        let mut lines = vec![
+            String::new(),
+            "Error Details:".to_string(),
+            format!(
+                "  Failed Step: {} ({})",
+                failure.failed_step, failure.command
+            ),
+        ];
+
+        if let Some(ref failed_task) = failure.failed_task {
+            lines.push(format!("  Failed Task: {failed_task}"));
+        }
+
+        lines.push(format!(
+            "  Failed At: {}",
+            failure.failed_at.format("%Y-%m-%d %H:%M:%S UTC")
+        ));
+        lines.push(format!(
+            "  Duration: {}",
+            format_duration(failure.duration_ms)
+        ));
+        lines.push(format!("  Trace ID: {}", failure.trace_id));
+
+        if let Some(ref trace_file) = failure.trace_file {
+            lines.push(format!("  Trace File: {trace_file}"));
+        }
+
+        lines
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::{TimeZone, Utc};
+
+    use super::*;
+
+    fn configure_failure(failed_task: Option<&str>) -> FailureInfo {
+        FailureInfo {
+            command: "configure".to_string(),
+            failed_step: "InstallDocker".to_string(),
+            failed_at: Utc.with_ymd_and_hms(2026, 1, 1, 10, 0, 0).unwrap(),
+            duration_ms: 30_400,
+            trace_id: "0b5f2c1e-7a3d-4e8f-9c6b-2d1a0e4f8b7c".to_string(),
+            failed_task: failed_task.map(str::to_string),
+            trace_file: Some("data/tmp/traces/20260101-100000-configure.log".to_string()),
+        }
+    }
+
+    #[test]
+    fn it_should_render_the_failed_ansible_task() {
+        let lines = FailureView::render(&configure_failure(Some(
+            "task 'Install docker-ce' failed: No package matching 'docker-ce' is available",
+        )));
+
+        assert_eq!(lines[1], "Error Details:");
+        assert_eq!(lines[2], "  Failed Step: InstallDocker (configure)");
+        assert_eq!(
+            lines[3],
+            "  Failed Task: task 'Install docker-ce' failed: No package matching 'docker-ce' is available"
+        );
+        assert!(lines
+            .iter()
+            .any(|line| line.contains("Trace File: data/tmp/traces/")));
+    }
+
+    #[test]
+    fn it_should_render_the_duration_and_trace_id_of_the_failed_command() {
+        let lines = FailureView::render(&configure_failure(None));
+
+        assert!(lines.contains(&"  Duration: 30.4s".to_string()));
+        assert!(lines.contains(&"  Trace ID: 0b5f2c1e-7a3d-4e8f-9c6b-2d1a0e4f8b7c".to_string()));
+    }
+
+    #[test]
+    fn it_should_omit_the_task_line_when_no_task_failed() {
+        let lines = FailureView::render(&configure_failure(None));
+
+        assert!(!lines.iter().any(|line| line.contains("Failed Task")));
+    }
+}
@@ -59,7 +59,9 @@ mod tests {
     use chrono::{TimeZone, Utc};
 
     use super::*;
+    use crate::domain::tracker::TrackerConfig;
     use crate::presentation::cli::views::commands::show::view_data::DockerImagesInfo;
+    use crate::presentation::cli::views::commands::show::view_data::EndpointsInfo;
     use crate::presentation::cli::views::commands::show::view_data::InfrastructureInfo;
     use crate::presentation::cli::views::Render;
 
@@ -100,6 +102,36 @@ mod tests {
                 || output.contains(r#""infrastructure": null"#)
         );
         assert!(output.contains(r#""services":null"#) || output.contains(r#""services": null"#));
+
+        // Endpoints are omitted rather than shown with placeholder IPs
+        assert!(!output.contains("endpoints"));
+    }
+
+    #[test]
+    fn it_should_render_endpoints_as_structured_objects() {
+        let created_at = Utc.with_ymd_and_hms(2026, 2, 16, 10, 0, 0).unwrap();
+        let info = EnvironmentInfo::new(
+            "test-env".to_string(),
+            "Provisioned".to_string(),
+            "LXD".to_string(),
+            created_at,
+            test_docker_images(),
+            "provisioned".to_string(),
+        )
+        .with_endpoints(EndpointsInfo::from_tracker_config(
+            &TrackerConfig::default(),
+            IpAddr::V4(Ipv4Addr::new(10, 140, 190, 39)),
+        ));
+
+        let output = JsonView::render(&info).unwrap();
+        let parsed: serde_json::Value = serde_json::from_str(&output).unwrap();
+
+        assert_eq!(
+            parsed["endpoints"]["udp_announce"][0]["url"],
+            "udp://10.140.190.39:6969/announce"
+        );
+        assert_eq!(parsed["endpoints"]["api"]["uses_tls"], false);
+        assert_eq!(parsed["endpoints"]["health_check"]["localhost_only"], true);
     }
 
     #[test]
@@ -9,11 +9,17 @@ mod text_view;
 
 // Helper modules for TextView (text-based rendering components)
 mod basic;
+mod durations;
+mod endpoints;
+mod failure;
 mod grafana;
 mod https_hint;
 mod infrastructure;
 mod next_step;
 mod prometheus;
+mod releases;
+mod secrets;
+mod state_file;
 mod tracker_services;
 
 pub use json_view::JsonView;
@@ -15,16 +15,21 @@ impl NextStepGuidanceView {
     /// # Arguments
     ///
     /// * `state_name` - Internal state name (e.g., "created", "provisioned")
+    /// * `suggested_next` - Command suggested in the current state, if any
     ///
     /// # Returns
     ///
     /// A vector of formatted lines ready to be joined
     #[must_use]
-    pub fn render(state_name: &str) -> Vec<String> {
-        vec![
+    pub fn render(state_name: &str, suggested_next: Option<&str>) -> Vec<String> {
+        let mut lines = vec![
             String::new(), // blank line
             Self::get_guidance(state_name),
-        ]
+        ];
+        if let Some(next) = suggested_next {
+            lines.push(format!("Next suggested step: {next}"));
+        }
+        lines
     }
 
     /// Get guidance text based on current state
@@ -42,6 +47,9 @@ impl NextStepGuidanceView {
             "releasing" => "Release in progress. Wait for completion or check logs.".to_string(),
             "released" => "Run 'run' to start the tracker services.".to_string(),
             "running" => "Services are running. Use 'test' to verify health.".to_string(),
+            "stopped" => {
+                "Services are stopped for maintenance. Run 'start' to bring them back.".to_string()
+            }
             "destroying" => "Destruction in progress. Wait for completion.".to_string(),
             "destroyed" => {
                 "Environment has been destroyed. Create a new environment to redeploy.".to_string()
@@ -70,42 +78,49 @@ mod tests {
 
     #[test]
     fn it_should_guide_from_created_state() {
-        let lines = NextStepGuidanceView::render("created");
+        let lines = NextStepGuidanceView::render("created", None);
         let text = lines.join("\n");
         assert!(text.contains("provision"));
     }
 
     #[test]
     fn it_should_guide_from_provisioned_state() {
-        let lines = NextStepGuidanceView::render("provisioned");
+        let lines = NextStepGuidanceView::render("provisioned", None);
         let text = lines.join("\n");
         assert!(text.contains("configure"));
     }
 
     #[test]
     fn it_should_guide_from_configured_state() {
-        let lines = NextStepGuidanceView::render("configured");
+        let lines = NextStepGuidanceView::render("configured", None);
         let text = lines.join("\n");
         assert!(text.contains("release"));
     }
 
     #[test]
     fn it_should_guide_from_released_state() {
-        let lines = NextStepGuidanceView::render("released");
+        let lines = NextStepGuidanceView::render("released", None);
         let text = lines.join("\n");
         assert!(text.contains("run"));
     }
 
     #[test]
     fn it_should_guide_from_running_state() {
-        let lines = NextStepGuidanceView::render("running");
+        let lines = NextStepGuidanceView::render("running", None);
         let text = lines.join("\n");
         assert!(text.contains("test"));
     }
 
+    #[test]
+    fn it_should_guide_from_stopped_state() {
+        let lines = NextStepGuidanceView::render("stopped", None);
+        let text = lines.join("\n");
+        assert!(text.contains("start"));
+    }
+
     #[test]
     fn it_should_guide_from_destroyed_state() {
-        let lines = NextStepGuidanceView::render("destroyed");
+        let lines = NextStepGuidanceView::render("destroyed", None);
         let text = lines.join("\n");
         assert!(text.contains("destroyed"));
         assert!(text.contains("new environment"));
@@ -113,7 +128,7 @@ mod tests {
 
     #[test]
     fn it_should_handle_provision_failed_state() {
-        let lines = NextStepGuidanceView::render("provision_failed");
+        let lines = NextStepGuidanceView::render("provision_failed", None);
         let text = lines.join("\n");
         assert!(text.contains("failed"));
         assert!(text.contains("destroy"));
@@ -121,7 +136,7 @@ mod tests {
 
     #[test]
     fn it_should_handle_configure_failed_state() {
-        let lines = NextStepGuidanceView::render("configure_failed");
+        let lines = NextStepGuidanceView::render("configure_failed", None);
         let text = lines.join("\n");
         assert!(text.contains("failed"));
         assert!(text.contains("destroy"));
@@ -129,7 +144,7 @@ mod tests {
 
     #[test]
     fn it_should_handle_release_failed_state() {
-        let lines = NextStepGuidanceView::render("release_failed");
+        let lines = NextStepGuidanceView::render("release_failed", None);
         let text = lines.join("\n");
         assert!(text.contains("failed"));
         assert!(text.contains("destroy"));
@@ -137,7 +152,7 @@ mod tests {
 
     #[test]
     fn it_should_handle_run_failed_state() {
-        let lines = NextStepGuidanceView::render("run_failed");
+        let lines = NextStepGuidanceView::render("run_failed", None);
         let text = lines.join("\n");
         assert!(text.contains("failed"));
         assert!(text.contains("destroy"));
@@ -145,7 +160,7 @@ mod tests {
 
     #[test]
     fn it_should_handle_destroy_failed_state() {
-        let lines = NextStepGuidanceView::render("destroy_failed");
+        let lines = NextStepGuidanceView::render("destroy_failed", None);
         let text = lines.join("\n");
         assert!(text.contains("failed"));
         assert!(text.contains("retry"));
@@ -153,14 +168,23 @@ mod tests {
 
     #[test]
     fn it_should_handle_unknown_state() {
-        let lines = NextStepGuidanceView::render("unknown_state");
+        let lines = NextStepGuidanceView::render("unknown_state", None);
         let text = lines.join("\n");
         assert!(text.contains("Unknown state"));
     }
 
+    #[test]
+    fn it_should_show_the_suggested_next_step() {
+        let lines = NextStepGuidanceView::render("provisioned", Some("configure"));
+        assert_eq!(
+            lines.last().map(String::as_str),
+            Some("Next suggested step: configure")
+        );
+    }
+
     #[test]
     fn it_should_start_with_blank_line() {
-        let lines = NextStepGuidanceView::render("created");
+        let lines = NextStepGuidanceView::render("created", None);
         assert!(lines.first().is_some_and(String::is_empty));
     }
 }
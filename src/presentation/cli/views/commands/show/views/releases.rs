@@ -0,0 +1,65 @@
+//! Release History View
+//!
+//! This module provides a view for rendering the tracker versions deployed by
+//! the recorded releases.
+
+use crate::presentation::cli::views::commands::show::view_data::ReleaseInfo;
+
+/// View for rendering the release history
+///
+/// Releases are listed newest first; the first one is currently deployed and
+/// the second one is the target of `rollback`.
+pub struct ReleasesView;
+
+impl ReleasesView {
+    /// Render the release history as formatted lines
+    ///
+    /// Returns no lines when no release has been recorded.
+    #[must_use]
+    pub fn render(releases: &[ReleaseInfo]) -> Vec<String> {
+        if releases.is_empty() {
+            return Vec::new();
+        }
+
+        let mut lines = vec![String::new(), "Releases:".to_string()];
+
+        for (index, release) in releases.iter().rev().enumerate() {
+            let marker = if index == 0 { " (current)" } else { "" };
+            lines.push(format!(
+                "  {}  {}{marker}",
+                release.released_at.format("%Y-%m-%d %H:%M:%S UTC"),
+                release.version
+            ));
+        }
+
+        lines
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::{TimeZone, Utc};
+
+    use super::*;
+
+    fn release(version: &str, hour: u32) -> ReleaseInfo {
+        ReleaseInfo {
+            version: version.to_string(),
+            released_at: Utc.with_ymd_and_hms(2026, 1, 1, hour, 0, 0).unwrap(),
+        }
+    }
+
+    #[test]
+    fn it_should_list_releases_newest_first_and_mark_the_current_one() {
+        let lines = ReleasesView::render(&[release("v3.0.0", 9), release("v3.0.1", 10)]);
+
+        assert_eq!(lines[1], "Releases:");
+        assert_eq!(lines[2], "  2026-01-01 10:00:00 UTC  v3.0.1 (current)");
+        assert_eq!(lines[3], "  2026-01-01 09:00:00 UTC  v3.0.0");
+    }
+
+    #[test]
+    fn it_should_render_nothing_without_releases() {
+        assert!(ReleasesView::render(&[]).is_empty());
+    }
+}
@@ -0,0 +1,67 @@
+//! Secrets View
+//!
+//! This module provides a view for rendering the secrets of the environment,
+//! shown only with `--reveal-secrets`.
+
+use crate::presentation::cli::views::commands::show::view_data::SecretsInfo;
+
+/// View for rendering the secrets of the environment
+pub struct SecretsView;
+
+impl SecretsView {
+    /// Render the secrets as formatted lines
+    ///
+    /// Secrets that are not configured are not listed.
+    #[must_use]
+    pub fn render(secrets: &SecretsInfo) -> Vec<String> {
+        let mut lines = vec![
+            String::new(),
+            "Secrets:".to_string(),
+            format!("  API admin token:         {}", secrets.admin_token),
+        ];
+
+        let optional = [
+            ("MySQL password:", &secrets.database_password),
+            ("MySQL root password:", &secrets.database_root_password),
+            ("Grafana admin password:", &secrets.grafana_admin_password),
+            ("Registry password:", &secrets.registry_password),
+            ("Provider API token:", &secrets.provider_api_token),
+            ("LXD trust token:", &secrets.lxd_trust_token),
+        ];
+        for (label, value) in optional {
+            if let Some(value) = value {
+                lines.push(format!("  {label:<24} {value}"));
+            }
+        }
+
+        lines
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn it_should_list_only_the_configured_secrets() {
+        let lines = SecretsView::render(&SecretsInfo {
+            admin_token: "MyAccessToken".to_string(),
+            database_password: None,
+            database_root_password: None,
+            grafana_admin_password: Some("grafana-pass".to_string()),
+            registry_password: None,
+            provider_api_token: None,
+            lxd_trust_token: None,
+        });
+
+        assert_eq!(
+            lines,
+            vec![
+                String::new(),
+                "Secrets:".to_string(),
+                "  API admin token:         MyAccessToken".to_string(),
+                "  Grafana admin password:  grafana-pass".to_string(),
+            ]
+        );
+    }
+}
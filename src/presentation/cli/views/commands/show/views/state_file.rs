@@ -0,0 +1,56 @@
+//! State File View
+//!
+//! This module provides a view for rendering the versions recorded in the
+//! persisted state of the environment.
+
+use crate::presentation::cli::views::commands::show::view_data::StateFileInfo;
+
+/// View for rendering the state file versions
+pub struct StateFileView;
+
+impl StateFileView {
+    /// Render the state file versions as a formatted line
+    #[must_use]
+    pub fn render(state_file: &StateFileInfo) -> Vec<String> {
+        let written_by = match state_file.deployer_version {
+            Some(ref version) => format!("last written by deployer {version}"),
+            None => "written before deployer versions were recorded".to_string(),
+        };
+
+        vec![format!(
+            "State file: schema version {}, {written_by}",
+            state_file.schema_version
+        )]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn it_should_render_the_schema_and_deployer_versions() {
+        let lines = StateFileView::render(&StateFileInfo {
+            schema_version: 2,
+            deployer_version: Some("0.1.0".to_string()),
+        });
+
+        assert_eq!(
+            lines,
+            vec!["State file: schema version 2, last written by deployer 0.1.0"]
+        );
+    }
+
+    #[test]
+    fn it_should_render_state_files_without_a_deployer_version() {
+        let lines = StateFileView::render(&StateFileInfo {
+            schema_version: 1,
+            deployer_version: None,
+        });
+
+        assert_eq!(
+            lines,
+            vec!["State file: schema version 1, written before deployer versions were recorded"]
+        );
+    }
+}
@@ -8,19 +8,31 @@
 //!
 //! The view is composed of specialized child views for each section:
 //! - `basic`: Basic environment info (name, state, provider, created)
+//! - `state_file`: Versions recorded in the persisted state
 //! - `infrastructure`: Infrastructure details (IP, SSH credentials)
+//! - `endpoints`: Resolved service endpoint URLs (before services are released)
 //! - `tracker_services`: Tracker service endpoints
 //! - `prometheus`: Prometheus metrics service
 //! - `grafana`: Grafana visualization service
+//! - `releases`: Release history (deployed tracker versions)
+//! - `durations`: Duration of the last successful run of each deployment phase
+//! - `secrets`: Secrets of the environment (only with `--reveal-secrets`)
+//! - `failure`: Details of the last command failure (failed states)
 //! - `https_hint`: HTTPS configuration hints (/etc/hosts)
 //! - `next_step`: State-aware guidance
 
 use super::basic::BasicInfoView;
+use super::durations::DurationsView;
+use super::endpoints::EndpointsView;
+use super::failure::FailureView;
 use super::grafana::GrafanaView;
 use super::https_hint::HttpsHintView;
 use super::infrastructure::InfrastructureView;
 use super::next_step::NextStepGuidanceView;
 use super::prometheus::PrometheusView;
+use super::releases::ReleasesView;
+use super::secrets::SecretsView;
+use super::state_file::StateFileView;
 use super::tracker_services::TrackerServicesView;
 
 use crate::presentation::cli::views::commands::show::view_data::{
@@ -78,14 +90,34 @@ impl Render<EnvironmentInfo> for TextView {
             info.created_at,
         ));
 
+        // Labels (if any were set)
+        if !info.labels.is_empty() {
+            let labels: Vec<String> = info
+                .labels
+                .iter()
+                .map(|(key, value)| format!("{key}={value}"))
+                .collect();
+            lines.push(format!("Labels: {}", labels.join(", ")));
+        }
+
+        lines.push(format!("Config revision: {}", info.config_revision));
+
+        // State file versions (if loaded from storage)
+        if let Some(ref state_file) = info.state_file {
+            lines.extend(StateFileView::render(state_file));
+        }
+
         // Infrastructure details (if available)
         if let Some(ref infra) = info.infrastructure {
             lines.extend(InfrastructureView::render(infra));
         }
 
-        // Tracker service information (if available)
+        // Tracker service information (if available); it lists the same URLs
+        // as the endpoints section, grouped per service, so only one is shown
         if let Some(ref services) = info.services {
             lines.extend(TrackerServicesView::render(services));
+        } else if let Some(ref endpoints) = info.endpoints {
+            lines.extend(EndpointsView::render(endpoints));
         }
 
         // Prometheus service (if configured)
@@ -101,14 +133,33 @@ impl Render<EnvironmentInfo> for TextView {
         // Docker images (always present)
         lines.extend(Self::render_docker_images(&info.docker_images));
 
+        // Release history (if any release was recorded)
+        lines.extend(ReleasesView::render(&info.releases));
+
+        // Phase durations (if any phase has succeeded)
+        lines.extend(DurationsView::render(&info.durations));
+
+        // Secrets (only when revealed)
+        if let Some(ref secrets) = info.secrets {
+            lines.extend(SecretsView::render(secrets));
+        }
+
         // HTTPS hint with /etc/hosts (if TLS is configured)
         if let Some(ref services) = info.services {
             let instance_ip = info.infrastructure.as_ref().map(|i| i.instance_ip);
             lines.extend(HttpsHintView::render(services, instance_ip));
         }
 
+        // Error details (failed states only)
+        if let Some(ref failure) = info.failure {
+            lines.extend(FailureView::render(failure));
+        }
+
         // Next step guidance (always present)
-        lines.extend(NextStepGuidanceView::render(&info.state_name));
+        lines.extend(NextStepGuidanceView::render(
+            &info.state_name,
+            info.suggested_next.as_deref(),
+        ));
 
         Ok(lines.join("\n"))
     }
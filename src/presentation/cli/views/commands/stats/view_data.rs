@@ -0,0 +1,32 @@
+//! View data for the stats command
+//!
+//! The workspace aggregates and the tracker statistics are rendered from the
+//! application-layer DTOs directly; the durations of one environment are
+//! paired with its name.
+
+use serde::Serialize;
+
+pub use crate::application::command_handlers::show::PhaseDurationsInfo;
+pub use crate::application::command_handlers::stats::{
+    PhaseStats, RemoteStats, ReplicaStats, WorkspaceStats,
+};
+
+/// Data for rendering the phase durations of one environment
+#[derive(Debug, Clone, Serialize)]
+pub struct EnvironmentStatsData {
+    /// Name of the environment
+    pub environment_name: String,
+    /// Durations of the last successful run of each phase
+    pub durations: PhaseDurationsInfo,
+}
+
+impl EnvironmentStatsData {
+    /// Build the view data from the durations of an environment
+    #[must_use]
+    pub fn new(environment_name: &str, durations: PhaseDurationsInfo) -> Self {
+        Self {
+            environment_name: environment_name.to_string(),
+            durations,
+        }
+    }
+}
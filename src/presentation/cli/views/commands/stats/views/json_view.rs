@@ -0,0 +1,58 @@
+//! JSON View for Deployment Stats
+//!
+//! This module provides JSON-based rendering for the stats command.
+
+use crate::presentation::cli::views::commands::stats::view_data::{
+    EnvironmentStatsData, RemoteStats, WorkspaceStats,
+};
+use crate::presentation::cli::views::{Render, ViewRenderError};
+
+/// JSON view for rendering environment durations, workspace aggregates and
+/// tracker statistics
+pub struct JsonView;
+
+impl Render<EnvironmentStatsData> for JsonView {
+    fn render(data: &EnvironmentStatsData) -> Result<String, ViewRenderError> {
+        Ok(serde_json::to_string_pretty(data)?)
+    }
+}
+
+impl Render<WorkspaceStats> for JsonView {
+    fn render(data: &WorkspaceStats) -> Result<String, ViewRenderError> {
+        Ok(serde_json::to_string_pretty(data)?)
+    }
+}
+
+impl Render<RemoteStats> for JsonView {
+    fn render(data: &RemoteStats) -> Result<String, ViewRenderError> {
+        Ok(serde_json::to_string_pretty(data)?)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::environment::DeploymentPhase;
+    use crate::presentation::cli::views::commands::stats::view_data::PhaseStats;
+
+    #[test]
+    fn it_should_name_phases_in_lowercase() {
+        let data = WorkspaceStats {
+            environments: 2,
+            phases: vec![PhaseStats {
+                phase: DeploymentPhase::Provision,
+                samples: 2,
+                min_ms: 90_000,
+                avg_ms: 100_000,
+                max_ms: 110_000,
+            }],
+        };
+
+        let output = JsonView::render(&data).unwrap();
+
+        let parsed: serde_json::Value = serde_json::from_str(&output).unwrap();
+        assert_eq!(parsed["environments"], 2);
+        assert_eq!(parsed["phases"][0]["phase"], "provision");
+        assert_eq!(parsed["phases"][0]["avg_ms"], 100_000);
+    }
+}
@@ -0,0 +1,215 @@
+//! Text View for Deployment Stats
+//!
+//! This module provides text-based rendering for the stats command: the
+//! duration of each phase of one environment, a table with the minimum,
+//! average and maximum duration of each phase across the workspace, or a
+//! table with the torrent and peer counts of each tracker replica.
+
+use crate::domain::environment::DeploymentPhase;
+use crate::presentation::cli::views::commands::shared::duration::format_duration;
+use crate::presentation::cli::views::commands::stats::view_data::{
+    EnvironmentStatsData, RemoteStats, WorkspaceStats,
+};
+use crate::presentation::cli::views::{Render, ViewRenderError};
+
+/// Text view for rendering environment durations, workspace aggregates and
+/// tracker statistics
+pub struct TextView;
+
+impl Render<EnvironmentStatsData> for TextView {
+    fn render(data: &EnvironmentStatsData) -> Result<String, ViewRenderError> {
+        if data.durations.is_empty() {
+            return Ok(format!(
+                "No phase durations recorded for environment '{}'.",
+                data.environment_name
+            ));
+        }
+
+        let mut lines = vec![format!(
+            "Phase durations for environment '{}':",
+            data.environment_name
+        )];
+
+        for phase in DeploymentPhase::ALL {
+            let duration = data
+                .durations
+                .get(phase)
+                .map_or_else(|| "-".to_string(), format_duration);
+            lines.push(format!("  {:<10} {duration}", phase.to_string()));
+        }
+
+        Ok(lines.join("\n"))
+    }
+}
+
+impl Render<WorkspaceStats> for TextView {
+    fn render(data: &WorkspaceStats) -> Result<String, ViewRenderError> {
+        if data.phases.is_empty() {
+            return Ok(format!(
+                "No phase durations recorded across {} environment(s).",
+                data.environments
+            ));
+        }
+
+        let mut lines = vec![
+            format!(
+                "Phase durations across {} environment(s):",
+                data.environments
+            ),
+            String::new(),
+            format!(
+                "{:<10} {:<8} {:<10} {:<10} {}",
+                "Phase", "Samples", "Min", "Avg", "Max"
+            ),
+            "─".repeat(50),
+        ];
+
+        for stats in &data.phases {
+            lines.push(format!(
+                "{:<10} {:<8} {:<10} {:<10} {}",
+                stats.phase.to_string(),
+                stats.samples,
+                format_duration(stats.min_ms),
+                format_duration(stats.avg_ms),
+                format_duration(stats.max_ms)
+            ));
+        }
+
+        Ok(lines.join("\n"))
+    }
+}
+
+impl Render<RemoteStats> for TextView {
+    fn render(data: &RemoteStats) -> Result<String, ViewRenderError> {
+        let mut lines = vec![
+            format!(
+                "Tracker statistics for environment '{}':",
+                data.environment_name
+            ),
+            String::new(),
+            format!(
+                "{:<8} {:<9} {:<8} {:<9} {:<10} {:<15} {}",
+                "Replica",
+                "Torrents",
+                "Seeders",
+                "Leechers",
+                "Completed",
+                "HTTP announces",
+                "UDP announces"
+            ),
+            "─".repeat(77),
+        ];
+
+        for (index, replica) in data.replicas.iter().enumerate() {
+            let stats = &replica.stats;
+            lines.push(format!(
+                "{:<8} {:<9} {:<8} {:<9} {:<10} {:<15} {}",
+                index + 1,
+                stats.torrents,
+                stats.seeders,
+                stats.leechers,
+                stats.completed,
+                stats.http_announces(),
+                stats.udp_announces()
+            ));
+        }
+
+        lines.push(String::new());
+        for (index, replica) in data.replicas.iter().enumerate() {
+            lines.push(format!("Replica {}: {}", index + 1, replica.url));
+        }
+
+        Ok(lines.join("\n"))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::infrastructure::tracker_api::TrackerStats;
+    use crate::presentation::cli::views::commands::stats::view_data::{
+        PhaseDurationsInfo, PhaseStats, ReplicaStats,
+    };
+
+    #[test]
+    fn it_should_list_the_four_phases_of_an_environment() {
+        let data = EnvironmentStatsData::new(
+            "my-env",
+            PhaseDurationsInfo {
+                provision_ms: Some(95_230),
+                configure_ms: Some(180_000),
+                release_ms: None,
+                run_ms: Some(4_500),
+            },
+        );
+
+        let output = TextView::render(&data).unwrap();
+
+        assert_eq!(
+            output,
+            "Phase durations for environment 'my-env':
+  provision  95.2s
+  configure  180.0s
+  release    -
+  run        4.5s"
+        );
+    }
+
+    #[test]
+    fn it_should_say_when_an_environment_has_no_durations() {
+        let data = EnvironmentStatsData::new("my-env", PhaseDurationsInfo::default());
+
+        let output = TextView::render(&data).unwrap();
+
+        assert_eq!(
+            output,
+            "No phase durations recorded for environment 'my-env'."
+        );
+    }
+
+    #[test]
+    fn it_should_render_one_row_per_aggregated_phase() {
+        let data = WorkspaceStats {
+            environments: 3,
+            phases: vec![PhaseStats {
+                phase: DeploymentPhase::Provision,
+                samples: 2,
+                min_ms: 90_000,
+                avg_ms: 100_000,
+                max_ms: 110_000,
+            }],
+        };
+
+        let output = TextView::render(&data).unwrap();
+
+        assert!(output.starts_with("Phase durations across 3 environment(s):"));
+        assert!(output.contains("provision  2        90.0s      100.0s     110.0s"));
+    }
+
+    #[test]
+    fn it_should_render_one_row_per_tracker_replica() {
+        let data = RemoteStats {
+            environment_name: "my-env".to_string(),
+            replicas: vec![ReplicaStats {
+                url: "https://api.tracker.local/api/v1/stats".to_string(),
+                stats: TrackerStats {
+                    torrents: 2,
+                    seeders: 1,
+                    completed: 3,
+                    leechers: 4,
+                    tcp4_announces_handled: 5,
+                    udp4_announces_handled: 2,
+                    ..TrackerStats::default()
+                },
+            }],
+        };
+
+        let output = TextView::render(&data).unwrap();
+
+        assert!(output.starts_with("Tracker statistics for environment 'my-env':"));
+        assert!(
+            output.contains("1        2         1        4         3          5               2")
+        );
+        assert!(output.ends_with("Replica 1: https://api.tracker.local/api/v1/stats"));
+    }
+}
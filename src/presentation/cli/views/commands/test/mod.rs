@@ -36,7 +36,7 @@ pub mod view_data {
     pub mod test_result_data;
 
     // Re-export main types for convenience
-    pub use test_result_data::{DnsWarningData, TestResultData};
+    pub use test_result_data::{CheckResultData, DnsWarningData, TestResultData};
 }
 
 pub mod views {
@@ -49,5 +49,5 @@ pub mod views {
 }
 
 // Re-export at module root for convenience
-pub use view_data::{DnsWarningData, TestResultData};
+pub use view_data::{CheckResultData, DnsWarningData, TestResultData};
 pub use views::{JsonView, TextView};
@@ -18,7 +18,8 @@
 
 use serde::Serialize;
 
-use crate::application::command_handlers::test::result::TestResult;
+use crate::application::command_handlers::show::info::PhaseDurationsInfo;
+use crate::application::command_handlers::test::result::{CheckResult, CheckStatus, TestResult};
 
 /// Test result data for rendering
 ///
@@ -40,8 +41,46 @@ pub struct TestResultData {
     pub instance_ip: String,
     /// Overall test result (always "pass" — failures are errors, not results)
     pub result: String,
+    /// Outcome of every smoke check that was run
+    pub checks: Vec<CheckResultData>,
     /// Advisory DNS warnings (may be empty)
     pub dns_warnings: Vec<DnsWarningData>,
+    /// Time the test command took, in milliseconds
+    pub duration_ms: u64,
+    /// Durations of the deployment phases of the tested environment
+    #[serde(skip_serializing_if = "PhaseDurationsInfo::is_empty")]
+    pub phase_durations: PhaseDurationsInfo,
+}
+
+/// Smoke check data for rendering
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct CheckResultData {
+    /// Name of the check (`health`, `udp`, `http` or `api`)
+    pub name: String,
+    /// Status of the check (`pass`, `fail` or `skip`)
+    pub status: String,
+    /// Time the check took, in milliseconds
+    pub latency_ms: u64,
+    /// Why the check failed or was skipped (absent when it passed)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub detail: Option<String>,
+}
+
+impl From<&CheckResult> for CheckResultData {
+    fn from(result: &CheckResult) -> Self {
+        let (status, detail) = match &result.status {
+            CheckStatus::Passed => ("pass", None),
+            CheckStatus::Failed(reason) => ("fail", Some(reason.clone())),
+            CheckStatus::Skipped(reason) => ("skip", Some(reason.clone())),
+        };
+
+        Self {
+            name: result.check.to_string(),
+            status: status.to_string(),
+            latency_ms: u64::try_from(result.latency.as_millis()).unwrap_or(u64::MAX),
+            detail,
+        }
+    }
 }
 
 /// DNS warning data for rendering
@@ -74,6 +113,11 @@ impl TestResultData {
             environment_name: environment_name.to_string(),
             instance_ip: test_result.instance_ip.to_string(),
             result: "pass".to_string(),
+            checks: test_result
+                .checks
+                .iter()
+                .map(CheckResultData::from)
+                .collect(),
             dns_warnings: test_result
                 .dns_warnings
                 .iter()
@@ -83,6 +127,8 @@ impl TestResultData {
                     issue: w.to_string(),
                 })
                 .collect(),
+            duration_ms: u64::try_from(test_result.duration.as_millis()).unwrap_or(u64::MAX),
+            phase_durations: PhaseDurationsInfo::from(&test_result.phase_durations),
         }
     }
 }
@@ -90,9 +136,12 @@ impl TestResultData {
 #[cfg(test)]
 mod tests {
     use std::net::IpAddr;
+    use std::time::Duration;
 
     use super::*;
+    use crate::application::command_handlers::test::checks::SmokeCheck;
     use crate::application::command_handlers::test::result::{DnsIssue, DnsWarning, TestResult};
+    use crate::domain::environment::{DeploymentPhase, PhaseDurations};
     use crate::shared::domain_name::DomainName;
 
     // Test fixtures and helpers
@@ -169,6 +218,55 @@ mod tests {
         assert_eq!(dto.result, "pass");
     }
 
+    #[test]
+    fn it_should_include_the_status_and_latency_of_each_check() {
+        // Arrange
+        let test_result = create_test_result_no_warnings().with_checks(vec![
+            CheckResult::passed(SmokeCheck::Udp, Duration::from_millis(4)),
+            CheckResult::skipped(SmokeCheck::Http, "no HTTP trackers configured"),
+        ]);
+
+        // Act
+        let dto = TestResultData::new("my-env", &test_result);
+
+        // Assert
+        assert_eq!(
+            dto.checks,
+            vec![
+                CheckResultData {
+                    name: "udp".to_string(),
+                    status: "pass".to_string(),
+                    latency_ms: 4,
+                    detail: None,
+                },
+                CheckResultData {
+                    name: "http".to_string(),
+                    status: "skip".to_string(),
+                    latency_ms: 0,
+                    detail: Some("no HTTP trackers configured".to_string()),
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn it_should_include_the_test_and_phase_durations() {
+        // Arrange
+        let mut phase_durations = PhaseDurations::default();
+        phase_durations.record(DeploymentPhase::Provision, 95_230);
+        let test_result = create_test_result_no_warnings()
+            .with_duration(Duration::from_millis(1_250))
+            .with_phase_durations(phase_durations);
+
+        // Act
+        let dto = TestResultData::new("my-env", &test_result);
+
+        // Assert
+        assert_eq!(dto.duration_ms, 1_250);
+        assert_eq!(dto.phase_durations.provision_ms, Some(95_230));
+        assert_eq!(dto.phase_durations.run_ms, None);
+    }
+
     #[test]
     fn it_should_convert_instance_ip_to_string() {
         // Arrange
@@ -30,7 +30,10 @@ use crate::presentation::cli::views::{Render, ViewRenderError};
 ///     environment_name: "my-env".to_string(),
 ///     instance_ip: "10.140.190.39".to_string(),
 ///     result: "pass".to_string(),
+///     checks: vec![],
 ///     dns_warnings: vec![],
+///     duration_ms: 1250,
+///     phase_durations: Default::default(),
 /// };
 ///
 /// let output = JsonView::render(&data).unwrap();
@@ -61,7 +64,10 @@ mod tests {
             environment_name: "test-env".to_string(),
             instance_ip: "10.140.190.39".to_string(),
             result: "pass".to_string(),
+            checks: vec![],
             dns_warnings: vec![],
+            duration_ms: 1250,
+            phase_durations: Default::default(),
         }
     }
 
@@ -70,6 +76,7 @@ mod tests {
             environment_name: "test-env".to_string(),
             instance_ip: "10.140.190.39".to_string(),
             result: "pass".to_string(),
+            checks: vec![],
             dns_warnings: vec![
                 DnsWarningData {
                     domain: "tracker.local".to_string(),
@@ -82,6 +89,8 @@ mod tests {
                     issue: "api.tracker.local resolves to [192.168.1.1] but expected 10.140.190.39".to_string(),
                 },
             ],
+            duration_ms: 1250,
+            phase_durations: Default::default(),
         }
     }
 
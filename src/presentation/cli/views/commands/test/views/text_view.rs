@@ -7,8 +7,9 @@
 //! # Design
 //!
 //! The `TextView` formats test results as human-readable text suitable
-//! for terminal display and direct user consumption. DNS warnings are
-//! rendered as indented bullet items when present.
+//! for terminal display and direct user consumption. Each smoke check is
+//! rendered on its own line with its latency, and DNS warnings are rendered
+//! as indented bullet items when present.
 
 use std::fmt::Write;
 
@@ -33,7 +34,10 @@ use crate::presentation::cli::views::{Render, ViewRenderError};
 ///     environment_name: "my-env".to_string(),
 ///     instance_ip: "10.140.190.39".to_string(),
 ///     result: "pass".to_string(),
+///     checks: vec![],
 ///     dns_warnings: vec![],
+///     duration_ms: 1250,
+///     phase_durations: Default::default(),
 /// };
 ///
 /// let output = TextView::render(&data).unwrap();
@@ -48,10 +52,31 @@ impl Render<TestResultData> for TextView {
             r"Test Results:
   Environment:       {}
   Instance IP:       {}
-  Result:            {}",
-            data.environment_name, data.instance_ip, data.result,
+  Result:            {}
+  Duration:          {}.{}s",
+            data.environment_name,
+            data.instance_ip,
+            data.result,
+            data.duration_ms / 1000,
+            (data.duration_ms % 1000) / 100,
         );
 
+        if !data.checks.is_empty() {
+            output.push_str("\n\nChecks:");
+            for check in &data.checks {
+                let _ = match &check.detail {
+                    Some(detail) => {
+                        write!(output, "\n  {:<8}{:<6}{detail}", check.name, check.status)
+                    }
+                    None => write!(
+                        output,
+                        "\n  {:<8}{:<6}{} ms",
+                        check.name, check.status, check.latency_ms
+                    ),
+                };
+            }
+        }
+
         if !data.dns_warnings.is_empty() {
             output.push_str("\n\nDNS Warnings:");
             for warning in &data.dns_warnings {
@@ -66,7 +91,7 @@ impl Render<TestResultData> for TextView {
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::presentation::cli::views::commands::test::DnsWarningData;
+    use crate::presentation::cli::views::commands::test::{CheckResultData, DnsWarningData};
 
     // Test fixtures and helpers
 
@@ -75,7 +100,10 @@ mod tests {
             environment_name: "test-env".to_string(),
             instance_ip: "10.140.190.39".to_string(),
             result: "pass".to_string(),
+            checks: vec![],
             dns_warnings: vec![],
+            duration_ms: 1250,
+            phase_durations: Default::default(),
         }
     }
 
@@ -84,6 +112,7 @@ mod tests {
             environment_name: "test-env".to_string(),
             instance_ip: "10.140.190.39".to_string(),
             result: "pass".to_string(),
+            checks: vec![],
             dns_warnings: vec![
                 DnsWarningData {
                     domain: "tracker.local".to_string(),
@@ -96,6 +125,8 @@ mod tests {
                     issue: "api.tracker.local resolves to [192.168.1.1] but expected 10.140.190.39".to_string(),
                 },
             ],
+            duration_ms: 1250,
+            phase_durations: Default::default(),
         }
     }
 
@@ -130,6 +161,40 @@ mod tests {
                 "10.140.190.39",
                 "Result:",
                 "pass",
+                "Duration:          1.2s",
+            ],
+        );
+    }
+
+    #[test]
+    fn it_should_render_each_check_with_its_latency_or_detail() {
+        // Arrange
+        let mut data = create_test_data_no_warnings();
+        data.checks = vec![
+            CheckResultData {
+                name: "udp".to_string(),
+                status: "pass".to_string(),
+                latency_ms: 4,
+                detail: None,
+            },
+            CheckResultData {
+                name: "http".to_string(),
+                status: "skip".to_string(),
+                latency_ms: 0,
+                detail: Some("no HTTP trackers configured".to_string()),
+            },
+        ];
+
+        // Act
+        let text = TextView::render(&data).unwrap();
+
+        // Assert
+        assert_contains_all(
+            &text,
+            &[
+                "Checks:",
+                "  udp     pass  4 ms",
+                "  http    skip  no HTTP trackers configured",
             ],
         );
     }
@@ -20,6 +20,7 @@ use std::path::Path;
 
 use serde::Serialize;
 
+use crate::application::command_handlers::create::config::ConfigValidationIssue;
 use crate::application::command_handlers::validate::ValidationResult;
 
 /// Validate details data for rendering
@@ -59,6 +60,11 @@ pub struct ValidateDetailsData {
     pub has_https: bool,
     /// Whether backups are configured
     pub has_backup: bool,
+    /// Resources downloaded from the Internet during the deployment
+    pub external_resources: Vec<String>,
+    /// Settings that are valid but likely to fail the deployment, with their
+    /// JSON paths
+    pub issues: Vec<ConfigValidationIssue>,
 }
 
 impl ValidateDetailsData {
@@ -83,6 +89,8 @@ impl ValidateDetailsData {
     ///     has_grafana: false,
     ///     has_https: false,
     ///     has_backup: false,
+    ///     external_resources: vec![],
+    ///     issues: vec![],
     /// };
     ///
     /// let data = ValidateDetailsData::from_result(Path::new("envs/my-env.json"), &result);
@@ -101,6 +109,8 @@ impl ValidateDetailsData {
             has_grafana: result.has_grafana,
             has_https: result.has_https,
             has_backup: result.has_backup,
+            external_resources: result.external_resources.clone(),
+            issues: result.issues.clone(),
         }
     }
 }
@@ -117,6 +127,8 @@ mod tests {
             has_grafana: false,
             has_https: false,
             has_backup: true,
+            external_resources: vec!["LXD base image 'ubuntu:24.04'".to_string()],
+            issues: vec![],
         }
     }
 
@@ -36,6 +36,8 @@ use crate::presentation::cli::views::{Render, ViewRenderError};
 ///     has_grafana: false,
 ///     has_https: false,
 ///     has_backup: false,
+///     external_resources: vec![],
+///     issues: vec![],
 /// };
 ///
 /// let output = JsonView::render(&data).unwrap();
@@ -70,6 +72,8 @@ mod tests {
             has_grafana: false,
             has_https: false,
             has_backup: true,
+            external_resources: vec![],
+            issues: vec![],
         }
     }
 
@@ -170,6 +174,8 @@ mod tests {
                 "has_grafana",
                 "has_https",
                 "has_backup",
+                "external_resources",
+                "issues",
             ],
         );
     }
@@ -186,6 +192,8 @@ mod tests {
             has_grafana: true,
             has_https: true,
             has_backup: true,
+            external_resources: vec![],
+            issues: vec![],
         };
 
         // Act
@@ -215,6 +223,8 @@ mod tests {
             has_grafana: false,
             has_https: false,
             has_backup: false,
+            external_resources: vec![],
+            issues: vec![],
         };
 
         // Act
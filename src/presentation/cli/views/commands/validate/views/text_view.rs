@@ -39,6 +39,8 @@ use crate::presentation::cli::views::{Render, ViewRenderError};
 ///     has_grafana: false,
 ///     has_https: false,
 ///     has_backup: false,
+///     external_resources: vec![],
+///     issues: vec![],
 /// };
 ///
 /// let output = TextView::render(&data).unwrap();
@@ -50,7 +52,7 @@ pub struct TextView;
 
 impl Render<ValidateDetailsData> for TextView {
     fn render(data: &ValidateDetailsData) -> Result<String, ViewRenderError> {
-        Ok(format!(
+        let mut output = format!(
             "Configuration file '{}' is valid\n\nEnvironment Details:\n\
             • Name: {}\n\
             • Provider: {}\n\
@@ -81,13 +83,30 @@ impl Render<ValidateDetailsData> for TextView {
             } else {
                 "Disabled"
             }
-        ))
+        );
+
+        if !data.external_resources.is_empty() {
+            output.push_str("\n\nDownloads required during deployment:");
+            for resource in &data.external_resources {
+                output.push_str(&format!("\n• {resource}"));
+            }
+        }
+
+        if !data.issues.is_empty() {
+            output.push_str("\n\nWarnings:");
+            for issue in &data.issues {
+                output.push_str(&format!("\n• {}: {}", issue.path, issue.message));
+            }
+        }
+
+        Ok(output)
     }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::application::command_handlers::create::config::ConfigValidationIssue;
 
     // Test fixtures
 
@@ -101,6 +120,8 @@ mod tests {
             has_grafana: true,
             has_https: true,
             has_backup: true,
+            external_resources: vec![],
+            issues: vec![],
         }
     }
 
@@ -114,6 +135,8 @@ mod tests {
             has_grafana: false,
             has_https: false,
             has_backup: false,
+            external_resources: vec![],
+            issues: vec![],
         }
     }
 
@@ -202,6 +225,44 @@ mod tests {
         );
     }
 
+    #[test]
+    fn it_should_list_the_downloads_required_during_deployment() {
+        // Arrange
+        let mut data = create_test_data_all_disabled();
+        data.external_resources = vec!["LXD base image 'ubuntu:24.04'".to_string()];
+
+        // Act
+        let text = TextView::render(&data).unwrap();
+
+        // Assert
+        assert!(
+            text.ends_with(
+                "Downloads required during deployment:\n• LXD base image 'ubuntu:24.04'"
+            ),
+            "Actual text:\n{text}"
+        );
+    }
+
+    #[test]
+    fn it_should_list_the_warnings_last() {
+        // Arrange
+        let mut data = create_test_data_all_disabled();
+        data.external_resources = vec!["LXD base image 'ubuntu:24.04'".to_string()];
+        data.issues = vec![ConfigValidationIssue::warning(
+            "proxy",
+            "Only the 'http' proxy is set",
+        )];
+
+        // Act
+        let text = TextView::render(&data).unwrap();
+
+        // Assert
+        assert!(
+            text.ends_with("\n\nWarnings:\n• proxy: Only the 'http' proxy is set"),
+            "Actual text:\n{text}"
+        );
+    }
+
     #[test]
     fn it_should_include_all_required_sections() {
         // Arrange
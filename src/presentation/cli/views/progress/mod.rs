@@ -6,11 +6,18 @@
 //!
 //! ## Sub-modules
 //!
+//! - `multi_environment_listener` - `MultiEnvironmentListener` implementation
+//!   reporting a command running on several environments
+//! - `timings` - Step timing summary shown at the end of a command
 //! - `verbose_listener` - `CommandProgressListener` implementation that translates
 //!   application-layer progress events into user-facing output
 
+pub mod multi_environment_listener;
+pub mod timings;
 pub mod verbose_listener;
 
+pub use multi_environment_listener::MultiEnvironmentProgressListener;
+pub use timings::{StepStatus, StepTiming, TimingSummary};
 pub use verbose_listener::VerboseProgressListener;
 
 use std::cell::RefCell;
@@ -21,6 +28,7 @@ use parking_lot::ReentrantMutex;
 
 use thiserror::Error;
 
+use crate::presentation::cli::input::cli::OutputFormat;
 use crate::presentation::cli::views::UserOutput;
 
 /// Errors that can occur during progress reporting
@@ -42,6 +50,10 @@ Tip: This is a critical bug - please report it with full logs using --log-output
 /// Tracks progress through multiple steps of a long-running operation,
 /// providing clear feedback with step numbers, descriptions, and timing.
 ///
+/// Every completed step is recorded, so the command can end with a timing
+/// table: `complete` shows it after the success message and `fail` shows it
+/// up to the failing step. With JSON output the table is a single JSON line.
+///
 /// # Examples
 ///
 /// ```rust
@@ -70,6 +82,11 @@ pub struct ProgressReporter {
     total_steps: usize,
     current_step: usize,
     step_start: Option<Instant>,
+    step_description: String,
+    started: Instant,
+    timings: Vec<StepTiming>,
+    output_format: OutputFormat,
+    summarized: bool,
 }
 
 impl ProgressReporter {
@@ -99,9 +116,28 @@ impl ProgressReporter {
             total_steps,
             current_step: 0,
             step_start: None,
+            step_description: String::new(),
+            started: Instant::now(),
+            timings: Vec::new(),
+            output_format: OutputFormat::Text,
+            summarized: false,
         }
     }
 
+    /// Show the timing summary in the given output format
+    ///
+    /// Controllers call this with the `--output-format` of the command; the
+    /// default is a text table.
+    pub fn set_output_format(&mut self, output_format: OutputFormat) {
+        self.output_format = output_format;
+    }
+
+    /// Steps recorded so far, in execution order
+    #[must_use]
+    pub fn timings(&self) -> &[StepTiming] {
+        &self.timings
+    }
+
     /// Execute a function with the locked `UserOutput`
     ///
     /// With `ReentrantMutex`, we can safely lock multiple times on the same thread.
@@ -151,6 +187,7 @@ impl ProgressReporter {
     pub fn start_step(&mut self, description: &str) -> Result<(), ProgressReporterError> {
         self.current_step += 1;
         self.step_start = Some(Instant::now());
+        self.step_description = description.to_string();
 
         self.with_output(|output| {
             output.progress(&format!(
@@ -202,12 +239,18 @@ impl ProgressReporter {
         if let Some(start) = self.step_start {
             let duration = start.elapsed();
             self.with_output(|output| {
-                if let Some(msg) = result {
-                    output.progress(&format!("  ✓ {} (took {})", msg, format_duration(duration)));
-                } else {
-                    output.progress(&format!("  ✓ Done (took {})", format_duration(duration)));
-                }
+                let symbol = output.theme().step_done_symbol().to_string();
+                let msg = result.unwrap_or("Done");
+                output.progress(&format!(
+                    "  {symbol} {msg} (took {})",
+                    format_duration(duration)
+                ));
             })?;
+            self.timings.push(StepTiming {
+                step: self.step_description.clone(),
+                duration,
+                status: StepStatus::Done,
+            });
         }
 
         self.step_start = None;
@@ -250,14 +293,16 @@ impl ProgressReporter {
     /// ```
     pub fn sub_step(&mut self, description: &str) -> Result<(), ProgressReporterError> {
         self.with_output(|output| {
-            output.progress(&format!("    → {description}"));
+            let symbol = output.theme().sub_step_symbol().to_string();
+            output.progress(&format!("    {symbol} {description}"));
         })?;
         Ok(())
     }
 
     /// Complete all steps and show summary
     ///
-    /// Displays a final success message indicating the entire operation completed.
+    /// Displays a final success message indicating the entire operation completed,
+    /// followed by the timing table of the recorded steps and the total elapsed time.
     /// This should be called after all steps are done.
     ///
     /// # Arguments
@@ -290,7 +335,124 @@ impl ProgressReporter {
     /// ```
     pub fn complete(&mut self, summary: &str) -> Result<(), ProgressReporterError> {
         self.with_output(|output| output.success(summary))?;
-        Ok(())
+        self.show_timings(
+            "Step timings:",
+            TimingSummary::succeeded(self.timings.clone(), self.started.elapsed()),
+        )
+    }
+
+    /// Show the timing table of a failed command
+    ///
+    /// The step in progress, if any, is recorded as failed with the time spent
+    /// on it so far; the table lists the steps up to that one.
+    ///
+    /// # Arguments
+    ///
+    /// * `summary` - Heading of the table, e.g. where the command failed
+    ///
+    /// # Errors
+    ///
+    /// Returns `ProgressReporterError::UserOutputMutexPoisoned` if the mutex is poisoned.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use std::sync::Arc;
+    /// use std::cell::RefCell;
+    /// use parking_lot::ReentrantMutex;
+    /// use torrust_tracker_deployer_lib::presentation::cli::views::progress::ProgressReporter;
+    /// use torrust_tracker_deployer_lib::presentation::cli::views::{UserOutput, VerbosityLevel};
+    ///
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let output = Arc::new(ReentrantMutex::new(RefCell::new(UserOutput::new(VerbosityLevel::Normal))));
+    /// let mut progress = ProgressReporter::new(output.clone(), 2);
+    ///
+    /// progress.start_step("Validating environment")?;
+    /// progress.complete_step(None)?;
+    /// progress.start_step("Releasing application")?;
+    /// progress.fail("Failed at step 2/2:")?;
+    /// // Output: the table with "Releasing application" marked FAILED
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn fail(&mut self, summary: &str) -> Result<(), ProgressReporterError> {
+        if let Some(start) = self.step_start.take() {
+            self.timings.push(StepTiming {
+                step: self.step_description.clone(),
+                duration: start.elapsed(),
+                status: StepStatus::Failed,
+            });
+        }
+
+        self.show_timings(
+            summary,
+            TimingSummary::failed(self.timings.clone(), self.started.elapsed()),
+        )
+    }
+
+    /// End the command with its timing table and pass its result through
+    ///
+    /// Calls `fail` on error. On success the table is shown unless `complete`
+    /// already showed it or no step was recorded, e.g. when only the result is
+    /// written to stdout.
+    ///
+    /// # Errors
+    ///
+    /// Returns the error of `result`, or the conversion of a
+    /// `ProgressReporterError` if the table cannot be shown.
+    pub fn finish<T, E>(&mut self, result: Result<T, E>) -> Result<T, E>
+    where
+        E: From<ProgressReporterError>,
+    {
+        match result {
+            Ok(value) => {
+                if !self.timings.is_empty() {
+                    self.show_timings(
+                        "Step timings:",
+                        TimingSummary::succeeded(self.timings.clone(), self.started.elapsed()),
+                    )?;
+                }
+                Ok(value)
+            }
+            Err(error) => {
+                let summary = if self.current_step == 0 {
+                    "Failed before the first step:".to_string()
+                } else {
+                    format!("Failed at step {}/{}:", self.current_step, self.total_steps)
+                };
+                self.fail(&summary)?;
+                Err(error)
+            }
+        }
+    }
+
+    /// Show the timing summary once, as a table under `title` or as a JSON line
+    ///
+    /// The JSON line is written alone, without the title, so it can be
+    /// parsed as is.
+    fn show_timings(
+        &mut self,
+        title: &str,
+        summary: TimingSummary,
+    ) -> Result<(), ProgressReporterError> {
+        if self.summarized {
+            return Ok(());
+        }
+        self.summarized = true;
+
+        match self.output_format {
+            OutputFormat::Text => {
+                let lines = summary.table_lines();
+                self.with_output(|output| {
+                    output.blank_line();
+                    output.info_block(title, &lines.iter().map(String::as_str).collect::<Vec<_>>());
+                })
+            }
+            OutputFormat::Json => {
+                let line = summary.to_json();
+                self.with_output(|output| output.info_block(&line, &[]))
+            }
+        }
     }
 
     /// Get a reference to the shared `UserOutput`
@@ -488,7 +650,7 @@ fn format_duration(duration: Duration) -> String {
 mod tests {
     use super::*;
     use crate::presentation::cli::views::testing::TestUserOutput;
-    use crate::presentation::cli::views::VerbosityLevel;
+    use crate::presentation::cli::views::{Theme, VerbosityLevel};
 
     #[test]
     fn it_should_create_progress_reporter_with_total_steps() {
@@ -657,6 +819,28 @@ mod tests {
         assert_eq!(stderr_content, "");
     }
 
+    #[test]
+    fn it_should_use_the_theme_symbols_for_steps_and_sub_steps() {
+        let test_output = TestUserOutput::with_theme(VerbosityLevel::Normal, Theme::plain());
+        let (output, _stdout, stderr) = test_output.into_reentrant_wrapped();
+        let mut progress = ProgressReporter::new(output, 1);
+
+        progress
+            .start_step("Provisioning")
+            .expect("Failed to start step");
+        progress
+            .sub_step("Creating VM")
+            .expect("Failed to report sub-step");
+        progress
+            .complete_step(None)
+            .expect("Failed to complete step");
+
+        let stderr_content = String::from_utf8(stderr.lock().clone()).expect("Invalid UTF-8");
+        assert!(stderr_content.contains("    -> Creating VM"));
+        assert!(stderr_content.contains("  [DONE] Done (took"));
+        assert!(stderr_content.is_ascii());
+    }
+
     #[test]
     fn it_should_format_milliseconds_correctly() {
         let duration = Duration::from_millis(150);
@@ -678,6 +862,107 @@ mod tests {
         assert_eq!(format_duration(duration), "10.0s");
     }
 
+    #[test]
+    fn it_should_show_the_timing_table_after_the_completion_summary() {
+        let test_output = TestUserOutput::new(VerbosityLevel::Normal);
+        let (output, _stdout, stderr) = test_output.into_reentrant_wrapped();
+        let mut progress = ProgressReporter::new(output, 2);
+
+        progress.start_step("Validating environment").unwrap();
+        progress.complete_step(None).unwrap();
+        progress.start_step("Releasing application").unwrap();
+        progress.complete_step(None).unwrap();
+        progress.complete("Released").unwrap();
+
+        let stderr_content = String::from_utf8(stderr.lock().clone()).unwrap();
+        let table = &stderr_content[stderr_content.find("Step timings:").unwrap()..];
+        assert!(table.contains("Validating environment"));
+        assert!(table.contains("Releasing application"));
+        assert!(table.contains("Total"));
+        assert!(table.contains("succeeded"));
+        assert_eq!(progress.timings().len(), 2);
+    }
+
+    #[test]
+    fn it_should_mark_the_step_in_progress_as_failed() {
+        let test_output = TestUserOutput::new(VerbosityLevel::Normal);
+        let (output, _stdout, stderr) = test_output.into_reentrant_wrapped();
+        let mut progress = ProgressReporter::new(output, 3);
+
+        progress.start_step("Validating environment").unwrap();
+        progress.complete_step(None).unwrap();
+        progress.start_step("Releasing application").unwrap();
+        let result: Result<(), ProgressReporterError> =
+            progress.finish(Err(ProgressReporterError::UserOutputMutexPoisoned));
+
+        assert!(result.is_err());
+        let statuses: Vec<StepStatus> = progress.timings().iter().map(|t| t.status).collect();
+        assert_eq!(statuses, [StepStatus::Done, StepStatus::Failed]);
+        let stderr_content = String::from_utf8(stderr.lock().clone()).unwrap();
+        assert!(stderr_content.contains("Failed at step 2/3:"));
+        assert!(stderr_content.contains("FAILED"));
+    }
+
+    #[test]
+    fn it_should_show_the_timings_as_json_with_the_json_output_format() {
+        let test_output = TestUserOutput::new(VerbosityLevel::Normal);
+        let (output, _stdout, stderr) = test_output.into_reentrant_wrapped();
+        let mut progress = ProgressReporter::new(output, 1);
+        progress.set_output_format(OutputFormat::Json);
+
+        progress.start_step("Scanning environments").unwrap();
+        progress.complete_step(None).unwrap();
+        progress
+            .finish(Ok::<(), ProgressReporterError>(()))
+            .unwrap();
+
+        let stderr_content = String::from_utf8(stderr.lock().clone()).unwrap();
+        let line = stderr_content
+            .lines()
+            .find(|line| line.starts_with(r#"{"timings""#))
+            .unwrap();
+        let json: serde_json::Value = serde_json::from_str(line).unwrap();
+        assert_eq!(json["timings"]["status"], "succeeded");
+        assert_eq!(json["timings"]["steps"][0]["step"], "Scanning environments");
+        assert!(!stderr_content.contains("Step timings:"));
+    }
+
+    #[test]
+    fn it_should_show_only_the_json_timings_of_a_failed_command_with_the_json_output_format() {
+        let test_output = TestUserOutput::new(VerbosityLevel::Normal);
+        let (output, _stdout, stderr) = test_output.into_reentrant_wrapped();
+        let mut progress = ProgressReporter::new(output, 2);
+        progress.set_output_format(OutputFormat::Json);
+
+        progress.start_step("Validating environment").unwrap();
+        progress.complete_step(None).unwrap();
+        progress.start_step("Releasing application").unwrap();
+        progress.fail("Failed at step 2/2:").unwrap();
+
+        let stderr_content = String::from_utf8(stderr.lock().clone()).unwrap();
+        let last_line = stderr_content.lines().last().unwrap();
+        let json: serde_json::Value = serde_json::from_str(last_line).unwrap();
+        assert_eq!(json["timings"]["status"], "failed");
+        assert!(!stderr_content.contains("Failed at step"));
+    }
+
+    #[test]
+    fn it_should_show_the_timings_only_once() {
+        let test_output = TestUserOutput::new(VerbosityLevel::Normal);
+        let (output, _stdout, stderr) = test_output.into_reentrant_wrapped();
+        let mut progress = ProgressReporter::new(output, 1);
+
+        progress.start_step("Creating environment").unwrap();
+        progress.complete_step(None).unwrap();
+        progress.complete("Created").unwrap();
+        progress
+            .finish(Ok::<(), ProgressReporterError>(()))
+            .unwrap();
+
+        let stderr_content = String::from_utf8(stderr.lock().clone()).unwrap();
+        assert_eq!(stderr_content.matches("Step timings:").count(), 1);
+    }
+
     #[test]
     fn it_should_handle_full_workflow() {
         let test_output = TestUserOutput::new(VerbosityLevel::Normal);
@@ -0,0 +1,133 @@
+//! Progress of a command running on several environments
+//!
+//! This module provides the presentation-layer implementation of the
+//! `MultiEnvironmentListener` trait: one line when the command starts on an
+//! environment and one when it finishes, prefixed with the environment name.
+
+use std::cell::RefCell;
+use std::sync::Arc;
+
+use parking_lot::ReentrantMutex;
+
+use super::format_duration;
+use crate::application::command_handlers::common::{EnvironmentOutcome, MultiEnvironmentListener};
+use crate::domain::environment::name::EnvironmentName;
+use crate::presentation::cli::views::UserOutput;
+
+/// Reports when a command starts and finishes on each environment
+///
+/// # Examples
+///
+/// ```rust,ignore
+/// let listener = MultiEnvironmentProgressListener::new(user_output_arc.clone(), "provision");
+/// listener.on_environment_started(&name);
+/// // Outputs: ⏳ [e2e-1] Running provision...
+/// ```
+pub struct MultiEnvironmentProgressListener {
+    user_output: Arc<ReentrantMutex<RefCell<UserOutput>>>,
+    command: &'static str,
+}
+
+impl MultiEnvironmentProgressListener {
+    /// Create a listener reporting the progress of `command`
+    #[must_use]
+    pub fn new(
+        user_output: Arc<ReentrantMutex<RefCell<UserOutput>>>,
+        command: &'static str,
+    ) -> Self {
+        Self {
+            user_output,
+            command,
+        }
+    }
+}
+
+impl MultiEnvironmentListener for MultiEnvironmentProgressListener {
+    fn on_environment_started(&self, name: &EnvironmentName) {
+        let guard = self.user_output.lock();
+        guard
+            .borrow_mut()
+            .progress(&format!("[{name}] Running {}...", self.command));
+    }
+
+    fn on_environment_finished(&self, outcome: &EnvironmentOutcome) {
+        let duration = format_duration(outcome.duration);
+        let guard = self.user_output.lock();
+        let mut output = guard.borrow_mut();
+
+        match &outcome.error {
+            None => output.success(&format!(
+                "[{}] {} completed ({duration})",
+                outcome.name, self.command
+            )),
+            Some(error) => output.warn(&format!(
+                "[{}] {} failed after {duration}: {error}",
+                outcome.name, self.command
+            )),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use super::*;
+    use crate::presentation::cli::views::testing::TestUserOutput;
+    use crate::presentation::cli::views::VerbosityLevel;
+
+    fn create_listener() -> (
+        MultiEnvironmentProgressListener,
+        Arc<parking_lot::Mutex<Vec<u8>>>,
+    ) {
+        let test_output = TestUserOutput::new(VerbosityLevel::Normal);
+        let stderr_buffer = Arc::clone(&test_output.stderr_buffer);
+        let (wrapped, _stdout, _stderr) = test_output.into_reentrant_wrapped();
+        let listener = MultiEnvironmentProgressListener::new(wrapped, "provision");
+        (listener, stderr_buffer)
+    }
+
+    fn outcome(error: Option<&str>) -> EnvironmentOutcome {
+        EnvironmentOutcome {
+            name: EnvironmentName::new("e2e-1".to_string()).unwrap(),
+            error: error.map(ToString::to_string),
+            duration: Duration::from_millis(2300),
+        }
+    }
+
+    #[test]
+    fn it_should_prefix_the_start_of_an_environment_with_its_name() {
+        let (listener, stderr_buffer) = create_listener();
+
+        listener.on_environment_started(&EnvironmentName::new("e2e-1".to_string()).unwrap());
+
+        let output = String::from_utf8(stderr_buffer.lock().clone()).unwrap();
+        assert!(output.contains("[e2e-1] Running provision..."), "{output}");
+    }
+
+    #[test]
+    fn it_should_report_the_duration_of_a_successful_environment() {
+        let (listener, stderr_buffer) = create_listener();
+
+        listener.on_environment_finished(&outcome(None));
+
+        let output = String::from_utf8(stderr_buffer.lock().clone()).unwrap();
+        assert!(
+            output.contains("[e2e-1] provision completed (2.3s)"),
+            "{output}"
+        );
+    }
+
+    #[test]
+    fn it_should_report_the_error_of_a_failed_environment() {
+        let (listener, stderr_buffer) = create_listener();
+
+        listener.on_environment_finished(&outcome(Some("tofu apply failed")));
+
+        let output = String::from_utf8(stderr_buffer.lock().clone()).unwrap();
+        assert!(
+            output.contains("[e2e-1] provision failed after 2.3s: tofu apply failed"),
+            "{output}"
+        );
+    }
+}
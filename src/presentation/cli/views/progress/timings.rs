@@ -0,0 +1,188 @@
+//! Step timing summary of a command
+//!
+//! `ProgressReporter` records the description and duration of every step it
+//! reports. At the end of the command the records are shown as an aligned
+//! table (text output) or as a single JSON object (JSON output), up to the
+//! failing step when the command fails.
+
+use std::time::Duration;
+
+use serde::Serialize;
+
+use super::format_duration;
+
+/// Outcome of a recorded step
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum StepStatus {
+    /// The step completed
+    Done,
+    /// The command failed during this step
+    Failed,
+}
+
+impl StepStatus {
+    fn label(self) -> &'static str {
+        match self {
+            Self::Done => "done",
+            Self::Failed => "FAILED",
+        }
+    }
+}
+
+/// Description, duration and outcome of one step
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct StepTiming {
+    /// Step description, as shown when the step started
+    pub step: String,
+    /// Time taken by the step
+    #[serde(rename = "duration_ms", serialize_with = "serialize_millis")]
+    pub duration: Duration,
+    /// Outcome of the step
+    pub status: StepStatus,
+}
+
+/// Step timings and total elapsed time of a command
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct TimingSummary {
+    /// `succeeded` or `failed`
+    pub status: &'static str,
+    /// Time elapsed since the reporter was created
+    #[serde(rename = "total_ms", serialize_with = "serialize_millis")]
+    pub total: Duration,
+    /// Recorded steps, in execution order
+    pub steps: Vec<StepTiming>,
+}
+
+impl TimingSummary {
+    /// Summary of a command that completed all its steps
+    #[must_use]
+    pub fn succeeded(steps: Vec<StepTiming>, total: Duration) -> Self {
+        Self {
+            status: "succeeded",
+            total,
+            steps,
+        }
+    }
+
+    /// Summary of a command that failed
+    #[must_use]
+    pub fn failed(steps: Vec<StepTiming>, total: Duration) -> Self {
+        Self {
+            status: "failed",
+            total,
+            steps,
+        }
+    }
+
+    /// Aligned `step / duration / status` table, ending with the total
+    #[must_use]
+    pub fn table_lines(&self) -> Vec<String> {
+        let durations: Vec<String> = self
+            .steps
+            .iter()
+            .map(|timing| format_duration(timing.duration))
+            .collect();
+        let total = format_duration(self.total);
+
+        let step_width = self
+            .steps
+            .iter()
+            .map(|timing| timing.step.chars().count())
+            .chain(["Step".len(), "Total".len()])
+            .max()
+            .unwrap_or_default();
+        let duration_width = durations
+            .iter()
+            .map(String::len)
+            .chain(["Duration".len(), total.len()])
+            .max()
+            .unwrap_or_default();
+
+        let mut lines = vec![format!(
+            "  {:<step_width$}  {:>duration_width$}  Status",
+            "Step", "Duration"
+        )];
+        lines.extend(self.steps.iter().zip(&durations).map(|(timing, duration)| {
+            format!(
+                "  {:<step_width$}  {duration:>duration_width$}  {}",
+                timing.step,
+                timing.status.label()
+            )
+        }));
+        lines.push(format!(
+            "  {:<step_width$}  {total:>duration_width$}  {}",
+            "Total", self.status
+        ));
+        lines
+    }
+
+    /// The summary as a single-line JSON object under a `timings` key
+    #[must_use]
+    pub fn to_json(&self) -> String {
+        serde_json::json!({ "timings": self }).to_string()
+    }
+}
+
+#[allow(clippy::trivially_copy_pass_by_ref)] // serde's `serialize_with` signature
+fn serialize_millis<S: serde::Serializer>(
+    duration: &Duration,
+    serializer: S,
+) -> Result<S::Ok, S::Error> {
+    serializer.serialize_u64(u64::try_from(duration.as_millis()).unwrap_or(u64::MAX))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn timings() -> Vec<StepTiming> {
+        vec![
+            StepTiming {
+                step: "Validating environment".to_string(),
+                duration: Duration::from_millis(3),
+                status: StepStatus::Done,
+            },
+            StepTiming {
+                step: "Releasing application".to_string(),
+                duration: Duration::from_millis(43_200),
+                status: StepStatus::Failed,
+            },
+        ]
+    }
+
+    #[test]
+    fn it_should_align_the_columns_of_the_table() {
+        let summary = TimingSummary::failed(timings(), Duration::from_millis(43_250));
+
+        assert_eq!(
+            summary.table_lines(),
+            [
+                "  Step                    Duration  Status",
+                "  Validating environment       3ms  done",
+                "  Releasing application      43.2s  FAILED",
+                "  Total                      43.2s  failed",
+            ]
+        );
+    }
+
+    #[test]
+    fn it_should_serialize_the_durations_in_milliseconds() {
+        let summary = TimingSummary::succeeded(timings()[..1].to_vec(), Duration::from_millis(5));
+
+        let json: serde_json::Value = serde_json::from_str(&summary.to_json()).unwrap();
+
+        assert_eq!(
+            json,
+            serde_json::json!({
+                "timings": {
+                    "status": "succeeded",
+                    "total_ms": 5,
+                    "steps": [
+                        { "step": "Validating environment", "duration_ms": 3, "status": "done" }
+                    ]
+                }
+            })
+        );
+    }
+}
@@ -9,7 +9,7 @@ use std::sync::Arc;
 
 use parking_lot::ReentrantMutex;
 
-use crate::application::traits::CommandProgressListener;
+use crate::application::traits::{CommandProgressListener, ExternalCommandOutputLine};
 use crate::presentation::cli::views::UserOutput;
 
 /// Presentation layer implementation of `CommandProgressListener`.
@@ -36,6 +36,7 @@ use crate::presentation::cli::views::UserOutput;
 /// ```
 pub struct VerboseProgressListener {
     user_output: Arc<ReentrantMutex<RefCell<UserOutput>>>,
+    prefix: String,
 }
 
 impl VerboseProgressListener {
@@ -46,7 +47,20 @@ impl VerboseProgressListener {
     /// * `user_output` - Shared `UserOutput` instance for emitting messages
     #[must_use]
     pub fn new(user_output: Arc<ReentrantMutex<RefCell<UserOutput>>>) -> Self {
-        Self { user_output }
+        Self {
+            user_output,
+            prefix: String::new(),
+        }
+    }
+
+    /// Prefix every message with the name of the environment (e.g. `[e2e-1]`)
+    ///
+    /// Used when a command runs on several environments at once, so the
+    /// interleaved messages can be told apart.
+    #[must_use]
+    pub fn with_environment_prefix(mut self, environment_name: &str) -> Self {
+        self.prefix = format!("[{environment_name}] ");
+        self
     }
 
     /// Access the shared `UserOutput` and execute a closure with mutable access
@@ -64,7 +78,8 @@ impl CommandProgressListener for VerboseProgressListener {
     fn on_step_started(&self, step_number: usize, total_steps: usize, description: &str) {
         self.with_output(|output| {
             output.step_progress(&format!(
-                "  [Step {step_number}/{total_steps}] {description}..."
+                "{}  [Step {step_number}/{total_steps}] {description}...",
+                self.prefix
             ));
         });
     }
@@ -76,13 +91,28 @@ impl CommandProgressListener for VerboseProgressListener {
 
     fn on_detail(&self, message: &str) {
         self.with_output(|output| {
-            output.detail(&format!("     → {message}"));
+            let symbol = output.theme().sub_step_symbol().to_string();
+            output.detail(&format!("{}     {symbol} {message}", self.prefix));
         });
     }
 
     fn on_debug(&self, message: &str) {
         self.with_output(|output| {
-            output.debug_detail(&format!("     → {message}"));
+            let symbol = output.theme().sub_step_symbol().to_string();
+            output.debug_detail(&format!("{}     {symbol} {message}", self.prefix));
+        });
+    }
+
+    fn on_external_command_output_line(&self, output_line: &ExternalCommandOutputLine<'_>) {
+        if output_line.line.trim().is_empty() {
+            return;
+        }
+
+        self.with_output(|output| {
+            output.detail(&format!(
+                "{}       {}: {}",
+                self.prefix, output_line.program, output_line.line
+            ));
         });
     }
 }
@@ -92,6 +122,7 @@ mod tests {
     use super::*;
     use crate::presentation::cli::views::testing::TestUserOutput;
     use crate::presentation::cli::views::VerbosityLevel;
+    use crate::shared::command::OutputStream;
 
     fn create_listener(
         verbosity: VerbosityLevel,
@@ -181,6 +212,40 @@ mod tests {
         );
     }
 
+    #[test]
+    fn it_should_emit_external_command_output_lines_at_very_verbose_level() {
+        let (listener, stderr_buffer) = create_listener(VerbosityLevel::VeryVerbose);
+
+        listener.on_external_command_output_line(&ExternalCommandOutputLine {
+            program: "tofu",
+            stream: OutputStream::Stdout,
+            line: "lxd_instance.vm: Creating...",
+        });
+
+        let output = String::from_utf8(stderr_buffer.lock().clone()).unwrap();
+        assert!(
+            output.contains("tofu: lxd_instance.vm: Creating..."),
+            "Expected output line in output, got: {output}"
+        );
+    }
+
+    #[test]
+    fn it_should_not_emit_external_command_output_lines_at_verbose_level() {
+        let (listener, stderr_buffer) = create_listener(VerbosityLevel::Verbose);
+
+        listener.on_external_command_output_line(&ExternalCommandOutputLine {
+            program: "tofu",
+            stream: OutputStream::Stdout,
+            line: "lxd_instance.vm: Creating...",
+        });
+
+        let output = String::from_utf8(stderr_buffer.lock().clone()).unwrap();
+        assert!(
+            output.is_empty(),
+            "Expected no output line at Verbose level, got: {output}"
+        );
+    }
+
     #[test]
     fn it_should_format_all_nine_steps_correctly() {
         let (listener, stderr_buffer) = create_listener(VerbosityLevel::Verbose);
@@ -211,4 +276,20 @@ mod tests {
             );
         }
     }
+
+    #[test]
+    fn it_should_prefix_the_messages_with_the_environment_name() {
+        let test_output = TestUserOutput::new(VerbosityLevel::Verbose);
+        let stderr_buffer = Arc::clone(&test_output.stderr_buffer);
+        let (wrapped, _stdout, _stderr) = test_output.into_reentrant_wrapped();
+        let listener = VerboseProgressListener::new(wrapped).with_environment_prefix("e2e-1");
+
+        listener.on_step_started(1, 9, "Rendering OpenTofu templates");
+
+        let output = String::from_utf8(stderr_buffer.lock().clone()).unwrap();
+        assert!(
+            output.contains("[e2e-1]   [Step 1/9] Rendering OpenTofu templates..."),
+            "Expected prefixed step message in output, got: {output}"
+        );
+    }
 }
@@ -143,4 +143,65 @@ impl TestUserOutput {
     pub fn stderr(&self) -> String {
         String::from_utf8(self.stderr_buffer.lock().clone()).expect("stderr should be valid UTF-8")
     }
+
+    /// Assert that nothing was written to stderr
+    ///
+    /// Used to check that every progress and status message was suppressed,
+    /// e.g. at `VerbosityLevel::Quiet`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust,ignore
+    /// let mut test_output = TestUserOutput::new(VerbosityLevel::Quiet);
+    /// test_output.output.progress("Working...");
+    /// test_output.assert_stderr_suppressed();
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// Panics with the unexpected stderr content if stderr is not empty.
+    pub fn assert_stderr_suppressed(&self) {
+        let stderr = self.stderr();
+        assert!(
+            stderr.is_empty(),
+            "expected stderr to be suppressed, got:\n{stderr}"
+        );
+    }
+
+    /// Assert that the only output is the given result on stdout
+    ///
+    /// # Panics
+    ///
+    /// Panics if stdout differs from `expected` or stderr is not empty.
+    pub fn assert_only_result(&self, expected: &str) {
+        assert_eq!(self.stdout(), expected);
+        self.assert_stderr_suppressed();
+    }
+
+    /// Assert that neither channel contains ANSI escape sequences
+    ///
+    /// # Panics
+    ///
+    /// Panics naming the channel that contains an escape character.
+    pub fn assert_no_escape_sequences(&self) {
+        for (channel, content) in [("stdout", self.stdout()), ("stderr", self.stderr())] {
+            assert!(
+                !content.contains('\x1b'),
+                "expected no escape sequences on {channel}, got:\n{content:?}"
+            );
+        }
+    }
+
+    /// Assert that neither channel contains emoji or other non-ASCII symbols
+    ///
+    /// # Panics
+    ///
+    /// Panics naming the channel and the first non-ASCII character found.
+    pub fn assert_no_emoji(&self) {
+        for (channel, content) in [("stdout", self.stdout()), ("stderr", self.stderr())] {
+            if let Some(symbol) = content.chars().find(|c| !c.is_ascii()) {
+                panic!("expected no emoji on {channel}, found '{symbol}' in:\n{content}");
+            }
+        }
+    }
 }
@@ -41,6 +41,8 @@ pub struct Theme {
     error_symbol: String,
     detail_symbol: String,
     debug_symbol: String,
+    step_done_symbol: String,
+    sub_step_symbol: String,
 }
 
 impl Theme {
@@ -69,6 +71,8 @@ impl Theme {
             error_symbol: "❌".to_string(),
             detail_symbol: "📋".to_string(),
             debug_symbol: "🔍".to_string(),
+            step_done_symbol: "✓".to_string(),
+            sub_step_symbol: "→".to_string(),
         }
     }
 
@@ -98,6 +102,8 @@ impl Theme {
             error_symbol: "[ERROR]".to_string(),
             detail_symbol: "[DETAIL]".to_string(),
             debug_symbol: "[DEBUG]".to_string(),
+            step_done_symbol: "[DONE]".to_string(),
+            sub_step_symbol: "->".to_string(),
         }
     }
 
@@ -127,6 +133,8 @@ impl Theme {
             error_symbol: "[x]".to_string(),
             detail_symbol: "[~]".to_string(),
             debug_symbol: "[?]".to_string(),
+            step_done_symbol: "*".to_string(),
+            sub_step_symbol: "->".to_string(),
         }
     }
 
@@ -165,6 +173,18 @@ impl Theme {
     pub fn debug_symbol(&self) -> &str {
         &self.debug_symbol
     }
+
+    /// Get the symbol marking a completed step of a multi-step operation
+    #[must_use]
+    pub fn step_done_symbol(&self) -> &str {
+        &self.step_done_symbol
+    }
+
+    /// Get the symbol introducing a sub-step or a detail line
+    #[must_use]
+    pub fn sub_step_symbol(&self) -> &str {
+        &self.sub_step_symbol
+    }
 }
 
 impl Default for Theme {
@@ -207,6 +227,24 @@ mod tests {
         assert_eq!(theme.error_symbol(), "[ERROR]");
     }
 
+    #[test]
+    fn it_should_only_use_ascii_characters_in_the_plain_and_ascii_themes() {
+        for theme in [Theme::plain(), Theme::ascii()] {
+            let symbols = [
+                theme.progress_symbol(),
+                theme.success_symbol(),
+                theme.warning_symbol(),
+                theme.error_symbol(),
+                theme.detail_symbol(),
+                theme.debug_symbol(),
+                theme.step_done_symbol(),
+                theme.sub_step_symbol(),
+            ];
+
+            assert!(symbols.iter().all(|symbol| symbol.is_ascii()));
+        }
+    }
+
     #[test]
     fn it_should_return_ascii_symbols_when_using_ascii_theme() {
         let theme = Theme::ascii();
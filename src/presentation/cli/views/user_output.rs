@@ -378,6 +378,15 @@ impl UserOutput {
         });
     }
 
+    /// Get the theme used to format messages
+    ///
+    /// Lets callers composing message text (e.g. `ProgressReporter`) use the
+    /// same symbols as the messages themselves.
+    #[must_use]
+    pub fn theme(&self) -> &Theme {
+        &self.theme
+    }
+
     /// Create `UserOutput` with a custom sink
     ///
     /// This constructor enables the use of alternative output destinations,
@@ -499,6 +508,33 @@ mod tests {
             assert_eq!(test_output.stdout(), "");
             assert_eq!(test_output.stderr(), "");
         }
+
+        #[test]
+        fn it_should_only_emit_the_result_when_quiet() {
+            let mut test_output = TestUserOutput::new(VerbosityLevel::Quiet);
+
+            test_output.output.progress("Provisioning...");
+            test_output.output.step_progress("[Step 1/9] Rendering");
+            test_output.output.warn("Slow network");
+            test_output.output.blank_line();
+            test_output.output.steps("Next steps:", &["Configure"]);
+            test_output
+                .output
+                .info_block("Details:", &["  - ip: 10.0.0.1"]);
+            test_output.output.success("Environment provisioned");
+            test_output.output.result("{\"state\":\"Provisioned\"}");
+
+            test_output.assert_only_result("{\"state\":\"Provisioned\"}\n");
+        }
+
+        #[test]
+        fn it_should_still_emit_errors_when_quiet() {
+            let mut test_output = TestUserOutput::new(VerbosityLevel::Quiet);
+
+            test_output.output.error("Provisioning failed");
+
+            assert_eq!(test_output.stderr(), "❌ Provisioning failed\n");
+        }
     }
 
     mod formatter {
@@ -618,6 +654,25 @@ mod tests {
 
             assert_eq!(test_output.stderr(), expected_output);
         }
+
+        #[test]
+        fn it_should_not_emit_emoji_or_escape_sequences_with_the_plain_theme() {
+            let mut test_output = TestUserOutput::with_theme(VerbosityLevel::Debug, Theme::plain());
+
+            test_output.output.progress("Provisioning...");
+            test_output.output.step_progress("[Step 1/9] Rendering");
+            test_output
+                .output
+                .detail("Template directory: build/test/tofu");
+            test_output.output.debug_detail("Command: tofu init");
+            test_output.output.warn("Slow network");
+            test_output.output.error("Provisioning failed");
+            test_output.output.success("Environment provisioned");
+            test_output.output.result("Done");
+
+            test_output.assert_no_emoji();
+            test_output.assert_no_escape_sequences();
+        }
     }
 
     mod sink {
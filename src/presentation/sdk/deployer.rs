@@ -128,10 +128,14 @@ impl Deployer {
     ///
     /// Equivalent to `torrust-tracker-deployer create environment --env-file <path>`.
     ///
+    /// Creating an environment that already exists with the same configuration
+    /// succeeds and leaves it untouched, so the call can be repeated.
+    ///
     /// # Errors
     ///
     /// Returns [`CreateCommandHandlerError`] if the configuration is invalid,
-    /// the environment already exists, or a repository error occurs.
+    /// the environment already exists with a different configuration, or a
+    /// repository error occurs.
     pub fn create_environment(
         &self,
         config: EnvironmentCreationConfig,
@@ -141,8 +145,8 @@ impl Deployer {
             Arc::clone(&self.clock),
         );
         handler
-            .execute(config, &self.working_dir)
-            .map(|env| env.name().clone())
+            .execute_or_reuse(config, &self.working_dir)
+            .map(|result| result.name().clone())
     }
 
     /// Create a new deployment environment from a JSON configuration file.
@@ -391,14 +395,15 @@ impl Deployer {
 
     /// Test a deployed environment.
     ///
-    /// Verifies connectivity and DNS resolution for the running instance.
+    /// Runs every smoke check against the running tracker and checks DNS
+    /// resolution of its domains.
     ///
     /// Equivalent to `torrust-tracker-deployer test <name>`.
     ///
     /// # Errors
     ///
-    /// Returns [`TestCommandHandlerError`] if the environment is not found
-    /// or the test fails.
+    /// Returns [`TestCommandHandlerError`] if the environment is not found,
+    /// is not running, or a check fails.
     pub async fn test(
         &self,
         env_name: &EnvironmentName,
@@ -4,6 +4,7 @@
 //! including startup errors and execution errors with detailed context.
 
 use std::path::PathBuf;
+use std::time::Duration;
 
 use thiserror::Error;
 
@@ -32,6 +33,18 @@ pub enum CommandError {
         stdout: String,
         stderr: String,
     },
+
+    /// The command did not finish in time and its process group was killed
+    #[error(
+        "Command '{command}' timed out after {}s\nStdout: {stdout}\nStderr: {stderr}",
+        timeout.as_secs()
+    )]
+    TimedOut {
+        command: String,
+        timeout: Duration,
+        stdout: String,
+        stderr: String,
+    },
 }
 
 impl crate::shared::Traceable for CommandError {
@@ -54,6 +67,17 @@ impl crate::shared::Traceable for CommandError {
             } => {
                 format!("CommandError: Command '{command}' failed with exit code {exit_code}\nStdout: {stdout}\nStderr: {stderr}")
             }
+            Self::TimedOut {
+                command,
+                timeout,
+                stdout,
+                stderr,
+            } => {
+                format!(
+                    "CommandError: Command '{command}' timed out after {}s\nStdout: {stdout}\nStderr: {stderr}",
+                    timeout.as_secs()
+                )
+            }
         }
     }
 
@@ -63,7 +87,12 @@ impl crate::shared::Traceable for CommandError {
     }
 
     fn error_kind(&self) -> crate::shared::ErrorKind {
-        crate::shared::ErrorKind::CommandExecution
+        match self {
+            Self::TimedOut { .. } => crate::shared::ErrorKind::Timeout,
+            Self::StartupFailed { .. }
+            | Self::WorkingDirectoryNotFound { .. }
+            | Self::ExecutionFailed { .. } => crate::shared::ErrorKind::CommandExecution,
+        }
     }
 }
 
@@ -123,4 +152,22 @@ mod tests {
         assert!(error_message.contains("Working directory does not exist"));
         assert!(error_message.contains("/nonexistent/path/to/dir"));
     }
+
+    #[test]
+    fn it_should_format_timed_out_error_with_the_timeout_in_seconds() {
+        let error = CommandError::TimedOut {
+            command: "tofu apply".to_string(),
+            timeout: Duration::from_secs(90),
+            stdout: String::new(),
+            stderr: String::new(),
+        };
+
+        assert!(error
+            .to_string()
+            .contains("Command 'tofu apply' timed out after 90s"));
+        assert_eq!(
+            crate::shared::Traceable::error_kind(&error),
+            crate::shared::ErrorKind::Timeout
+        );
+    }
 }
@@ -2,29 +2,130 @@
 //!
 //! This module provides the `CommandExecutor` struct for executing external commands
 //! with proper error handling, logging, and output capture.
+//!
+//! Commands run with a timeout are started in their own process group, so
+//! that killing them on timeout also kills the processes they spawned (e.g.
+//! `lxc` or provider plugins started by `OpenTofu`). Processes in another
+//! group no longer receive the terminal's Ctrl-C, so
+//! [`interrupt_running_commands`] forwards it to them.
+//!
+//! [`CommandExecutor::run_command_streaming`] forwards every output line to a
+//! callback as soon as the command writes it, instead of when it exits.
+//!
+//! An executor given a [`RunLog`] with [`CommandExecutor::with_run_log`]
+//! writes the complete output of every command it runs to the log file of
+//! its step, whether the command succeeds or not.
 
+use std::io::{BufRead, BufReader, Read, Write};
 use std::path::Path;
-use std::process::{Command, Stdio};
-use tracing::info;
+use std::process::{Child, Command, ExitStatus, Stdio};
+use std::sync::mpsc::{self, Receiver, Sender};
+use std::sync::{Mutex, PoisonError};
+use std::thread::{self, JoinHandle};
+use std::time::{Duration, Instant};
+
+use tracing::{info, info_span, warn};
 
 use super::error::CommandError;
 use super::result::CommandResult;
+use super::run_log::RunLog;
+use super::stream::OutputStream;
+
+/// Callback receiving each output line of a command as it is written
+///
+/// The line has no trailing newline and no ANSI escape sequences.
+pub type OutputLineHandler<'a> = &'a dyn Fn(OutputStream, &str);
+
+/// How often a command run with a timeout is checked for completion
+const TIMEOUT_POLL_INTERVAL: Duration = Duration::from_millis(50);
+
+/// Process groups of the commands currently running with a timeout
+static RUNNING_PROCESS_GROUPS: Mutex<Vec<u32>> = Mutex::new(Vec::new());
+
+/// Forward an interrupt (`SIGINT`) to every command running with a timeout
+///
+/// Those commands run in their own process group and do not receive the
+/// Ctrl-C sent by the terminal to the deployer.
+pub fn interrupt_running_commands() {
+    let groups = RUNNING_PROCESS_GROUPS
+        .lock()
+        .unwrap_or_else(PoisonError::into_inner)
+        .clone();
+
+    for process_group in groups {
+        signal_process_group(process_group, "INT");
+    }
+}
 
-/// A command executor that can run shell commands
-#[derive(Debug)]
-pub struct CommandExecutor {}
+/// Send `signal` to every process of the group, returning whether it was delivered
+fn signal_process_group(process_group: u32, signal: &str) -> bool {
+    Command::new("kill")
+        .args(["-s", signal, "--", &format!("-{process_group}")])
+        .stdout(Stdio::null())
+        .stderr(Stdio::null())
+        .status()
+        .is_ok_and(|status| status.success())
+}
 
-impl Default for CommandExecutor {
-    fn default() -> Self {
-        Self::new()
-    }
+/// A command executor that can run shell commands
+///
+/// Without a timeout commands run until they exit. Use
+/// [`CommandExecutor::with_timeout`] or
+/// [`CommandExecutor::run_command_with_timeout`] to bound them.
+#[derive(Debug, Clone, Default)]
+pub struct CommandExecutor {
+    timeout: Option<Duration>,
+    env: Vec<(String, String)>,
+    run_log: Option<(RunLog, String)>,
+    redacted: Vec<String>,
 }
 
 impl CommandExecutor {
     /// Creates a new `CommandExecutor`
     #[must_use]
     pub fn new() -> Self {
-        Self {}
+        Self {
+            timeout: None,
+            env: Vec::new(),
+            run_log: None,
+            redacted: Vec::new(),
+        }
+    }
+
+    /// Kill every command that runs longer than `timeout`
+    #[must_use]
+    pub fn with_timeout(mut self, timeout: Duration) -> Self {
+        self.timeout = Some(timeout);
+        self
+    }
+
+    /// Set an environment variable for every command run by this executor
+    ///
+    /// The variable is added to the environment inherited from the deployer.
+    #[must_use]
+    pub fn with_env(mut self, key: impl Into<String>, value: impl Into<String>) -> Self {
+        self.env.push((key.into(), value.into()));
+        self
+    }
+
+    /// Hide `secret` wherever the command line is displayed
+    ///
+    /// Logs, run logs and error messages show `[REDACTED]` instead of an
+    /// argument holding the secret. The command itself still receives it.
+    #[must_use]
+    pub fn with_redacted(mut self, secret: impl Into<String>) -> Self {
+        self.redacted.push(secret.into());
+        self
+    }
+
+    /// Write the output of every command run by this executor to the log file of `step`
+    ///
+    /// A log file that cannot be written is reported as a warning; the
+    /// command result is not affected.
+    #[must_use]
+    pub fn with_run_log(mut self, run_log: RunLog, step: impl Into<String>) -> Self {
+        self.run_log = Some((run_log, step.into()));
+        self
     }
 
     /// Runs a command with the given arguments and optional working directory
@@ -43,25 +144,127 @@ impl CommandExecutor {
     /// * The working directory does not exist - `CommandError::WorkingDirectoryNotFound`
     /// * The command cannot be started (e.g., command not found) - `CommandError::StartupFailed`
     /// * The command execution fails with a non-zero exit code - `CommandError::ExecutionFailed`
+    /// * The executor has a timeout and the command exceeds it - `CommandError::TimedOut`
     pub fn run_command(
         &self,
         cmd: &str,
         args: &[&str],
         working_dir: Option<&Path>,
     ) -> Result<CommandResult, CommandError> {
+        self.run(cmd, args, working_dir, self.timeout, None, None)
+    }
+
+    /// Runs a command like [`CommandExecutor::run_command`], killing it after `timeout`
+    ///
+    /// The command is started in its own process group and the whole group
+    /// is killed on timeout. The given timeout overrides the executor's own.
+    ///
+    /// # Errors
+    ///
+    /// Same as [`CommandExecutor::run_command`]; a command still running
+    /// after `timeout` fails with `CommandError::TimedOut`.
+    pub fn run_command_with_timeout(
+        &self,
+        cmd: &str,
+        args: &[&str],
+        working_dir: Option<&Path>,
+        timeout: Duration,
+    ) -> Result<CommandResult, CommandError> {
+        self.run(cmd, args, working_dir, Some(timeout), None, None)
+    }
+
+    /// Runs a command like [`CommandExecutor::run_command_with_timeout`], writing `input` to its stdin
+    ///
+    /// Stdin is closed once `input` is written, so the command sees the end
+    /// of its input.
+    ///
+    /// # Errors
+    ///
+    /// Same as [`CommandExecutor::run_command_with_timeout`].
+    pub fn run_command_with_input(
+        &self,
+        cmd: &str,
+        args: &[&str],
+        working_dir: Option<&Path>,
+        timeout: Duration,
+        input: &[u8],
+    ) -> Result<CommandResult, CommandError> {
+        self.run(cmd, args, working_dir, Some(timeout), Some(input), None)
+    }
+
+    /// Runs a command like [`CommandExecutor::run_command_with_timeout`], forwarding its output line by line
+    ///
+    /// Each line of stdout and stderr is passed to `on_line` and logged as
+    /// soon as the command writes it, on the calling thread. Lines of both
+    /// streams are forwarded in the order they are read; a line is never
+    /// split or mixed with another one. Bytes that are not valid UTF-8 are
+    /// replaced once the whole line is read, so multi-byte characters split
+    /// across reads are kept intact.
+    ///
+    /// The complete output is still captured in the returned
+    /// [`CommandResult`] or [`CommandError`].
+    ///
+    /// # Errors
+    ///
+    /// Same as [`CommandExecutor::run_command_with_timeout`].
+    pub fn run_command_streaming(
+        &self,
+        cmd: &str,
+        args: &[&str],
+        working_dir: Option<&Path>,
+        timeout: Duration,
+        on_line: OutputLineHandler<'_>,
+    ) -> Result<CommandResult, CommandError> {
+        self.run(cmd, args, working_dir, Some(timeout), None, Some(on_line))
+    }
+
+    fn run(
+        &self,
+        cmd: &str,
+        args: &[&str],
+        working_dir: Option<&Path>,
+        timeout: Option<Duration>,
+        input: Option<&[u8]>,
+        on_line: Option<OutputLineHandler<'_>>,
+    ) -> Result<CommandResult, CommandError> {
+        let program = Path::new(cmd)
+            .file_name()
+            .map_or(cmd.into(), |name| name.to_string_lossy());
+        let _span = info_span!(
+            "tool",
+            otel.name = %format!("tool.{program}"),
+            program = %program
+        )
+        .entered();
+
         Self::validate_working_directory(working_dir)?;
 
         let mut command = Self::build_command(cmd, args, working_dir);
+        command.envs(self.env.iter().map(|(key, value)| (key, value)));
 
-        let command_display = Self::format_command_display(cmd, args);
+        let command_display = self.format_command_display(cmd, args);
 
         Self::log_command_start(&command_display, working_dir);
 
-        let (status, stdout, stderr) = Self::execute_command(&mut command, &command_display)?;
+        let outcome = match timeout {
+            Some(timeout) => Self::execute_command_with_timeout(
+                &mut command,
+                &command_display,
+                timeout,
+                input,
+                on_line,
+            ),
+            None => Self::execute_command(&mut command, &command_display),
+        };
+        self.record_run_log(&command_display, &outcome);
+        let (status, stdout, stderr) = outcome?;
 
         Self::check_command_success(status, &command_display, &stdout, &stderr)?;
 
-        Self::log_command_output(&command_display, &stdout, &stderr);
+        // Streamed lines have already been logged one by one
+        if on_line.is_none() {
+            Self::log_command_output(&command_display, &stdout, &stderr);
+        }
 
         Ok(CommandResult::new(status, stdout, stderr))
     }
@@ -96,8 +299,13 @@ impl CommandExecutor {
     }
 
     /// Formats a command and its arguments for display in logs and error messages.
-    fn format_command_display(cmd: &str, args: &[&str]) -> String {
-        format!("{} {}", cmd, args.join(" "))
+    fn format_command_display(&self, cmd: &str, args: &[&str]) -> String {
+        self.redacted
+            .iter()
+            .filter(|secret| !secret.is_empty())
+            .fold(format!("{} {}", cmd, args.join(" ")), |display, secret| {
+                display.replace(secret.as_str(), "[REDACTED]")
+            })
     }
 
     /// Logs the command execution start with optional working directory.
@@ -123,7 +331,7 @@ impl CommandExecutor {
     fn execute_command(
         command: &mut Command,
         command_display: &str,
-    ) -> Result<(std::process::ExitStatus, String, String), CommandError> {
+    ) -> Result<(ExitStatus, String, String), CommandError> {
         let output = command
             .stdout(Stdio::piped())
             .stderr(Stdio::piped())
@@ -138,6 +346,197 @@ impl CommandExecutor {
         Ok((output.status, stdout, stderr))
     }
 
+    /// Executes the command in its own process group, killing the group after `timeout`.
+    ///
+    /// When `input` is given it is written to the stdin of the command.
+    /// When `on_line` is given every output line is forwarded to it while
+    /// the command runs.
+    ///
+    /// Returns a tuple of (`exit_status`, `stdout`, `stderr`).
+    fn execute_command_with_timeout(
+        command: &mut Command,
+        command_display: &str,
+        timeout: Duration,
+        input: Option<&[u8]>,
+        on_line: Option<OutputLineHandler<'_>>,
+    ) -> Result<(ExitStatus, String, String), CommandError> {
+        #[cfg(unix)]
+        {
+            use std::os::unix::process::CommandExt;
+            command.process_group(0);
+        }
+
+        if input.is_some() {
+            command.stdin(Stdio::piped());
+        }
+
+        let mut child = command
+            .stdout(Stdio::piped())
+            .stderr(Stdio::piped())
+            .spawn()
+            .map_err(|source| CommandError::StartupFailed {
+                command: command_display.to_string(),
+                source,
+            })?;
+
+        let stdin_writer =
+            input.and_then(|input| Self::spawn_input_writer(child.stdin.take(), input));
+
+        let (sender, receiver) = mpsc::channel();
+        let forwarder = on_line.map(|on_line| LineForwarder { receiver, on_line });
+        let line_sender = |stream| forwarder.as_ref().map(|_| (stream, sender.clone()));
+        let stdout_reader =
+            Self::spawn_output_reader(child.stdout.take(), line_sender(OutputStream::Stdout));
+        let stderr_reader =
+            Self::spawn_output_reader(child.stderr.take(), line_sender(OutputStream::Stderr));
+        // Only the readers keep a sender, so the channel closes when both finish
+        drop(sender);
+
+        let status =
+            Self::wait_with_timeout(&mut child, command_display, timeout, forwarder.as_ref());
+
+        // The writer finishes once the input is written or the pipe is closed
+        if let Some(writer) = stdin_writer {
+            writer.join().ok();
+        }
+
+        // Readers finish once every process holding the pipes has exited,
+        // which the process-group kill guarantees on timeout
+        if let Some(forwarder) = forwarder {
+            forwarder.forward_remaining();
+        }
+        let stdout = Self::join_output_reader(stdout_reader);
+        let stderr = Self::join_output_reader(stderr_reader);
+
+        match status {
+            Some(status) => Ok((status, stdout, stderr)),
+            None => Err(CommandError::TimedOut {
+                command: command_display.to_string(),
+                timeout,
+                stdout,
+                stderr,
+            }),
+        }
+    }
+
+    /// Waits for the child to exit, killing its process group after `timeout`.
+    ///
+    /// Lines read in the meantime are passed to `forwarder`.
+    ///
+    /// Returns `None` when the command timed out.
+    fn wait_with_timeout(
+        child: &mut Child,
+        command_display: &str,
+        timeout: Duration,
+        forwarder: Option<&LineForwarder<'_>>,
+    ) -> Option<ExitStatus> {
+        let process_group = child.id();
+        RUNNING_PROCESS_GROUPS
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner)
+            .push(process_group);
+
+        let started = Instant::now();
+        let status = loop {
+            if let Some(forwarder) = forwarder {
+                forwarder.forward_pending();
+            }
+
+            if let Ok(Some(status)) = child.try_wait() {
+                break Some(status);
+            }
+
+            let elapsed = started.elapsed();
+            if elapsed >= timeout {
+                warn!(
+                    operation = "command_execution",
+                    command = %command_display,
+                    timeout_secs = timeout.as_secs(),
+                    "Command timed out, killing its process group"
+                );
+                Self::kill_process_group(child);
+                break None;
+            }
+
+            thread::sleep(TIMEOUT_POLL_INTERVAL.min(timeout - elapsed));
+        };
+
+        RUNNING_PROCESS_GROUPS
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner)
+            .retain(|group| *group != process_group);
+
+        status
+    }
+
+    /// Kills the child and every process in its group, then reaps the child.
+    fn kill_process_group(child: &mut Child) {
+        if !(cfg!(unix) && signal_process_group(child.id(), "KILL")) {
+            // Fall back to the direct child when the group cannot be signalled
+            child.kill().ok();
+        }
+
+        child.wait().ok();
+    }
+
+    /// Writes `input` to the child stdin on a separate thread, then closes it.
+    ///
+    /// A separate thread keeps a command that writes output before reading
+    /// all of its input from blocking on a full pipe.
+    fn spawn_input_writer<W: Write + Send + 'static>(
+        pipe: Option<W>,
+        input: &[u8],
+    ) -> Option<JoinHandle<()>> {
+        let input = input.to_vec();
+        pipe.map(|mut pipe| {
+            thread::spawn(move || {
+                // A command exiting before reading everything closes the pipe
+                pipe.write_all(&input).ok();
+            })
+        })
+    }
+
+    /// Reads a child output pipe to the end on a separate thread.
+    ///
+    /// With a `line_sender` every complete line is also sent, tagged with
+    /// its stream, as soon as it is read.
+    fn spawn_output_reader<R: Read + Send + 'static>(
+        pipe: Option<R>,
+        line_sender: Option<(OutputStream, Sender<(OutputStream, String)>)>,
+    ) -> Option<JoinHandle<Vec<u8>>> {
+        pipe.map(|pipe| {
+            thread::spawn(move || {
+                let mut reader = BufReader::new(pipe);
+                let mut buffer = Vec::new();
+
+                loop {
+                    let line_start = buffer.len();
+                    match reader.read_until(b'\n', &mut buffer) {
+                        Ok(0) | Err(_) => break,
+                        Ok(_) => {
+                            if let Some((stream, sender)) = &line_sender {
+                                // The receiver is gone only once the command is over
+                                sender
+                                    .send((*stream, display_line(&buffer[line_start..])))
+                                    .ok();
+                            }
+                        }
+                    }
+                }
+
+                buffer
+            })
+        })
+    }
+
+    /// Collects the output read by [`Self::spawn_output_reader`] as a string.
+    fn join_output_reader(reader: Option<JoinHandle<Vec<u8>>>) -> String {
+        reader
+            .and_then(|reader| reader.join().ok())
+            .map(|buffer| String::from_utf8_lossy(&buffer).to_string())
+            .unwrap_or_default()
+    }
+
     /// Extracts stdout and stderr from command output as strings.
     fn extract_output(output: &std::process::Output) -> (String, String) {
         let stdout = String::from_utf8_lossy(&output.stdout).to_string();
@@ -147,7 +546,7 @@ impl CommandExecutor {
 
     /// Checks if the command executed successfully and returns an error if it failed.
     fn check_command_success(
-        status: std::process::ExitStatus,
+        status: ExitStatus,
         command_display: &str,
         stdout: &str,
         stderr: &str,
@@ -167,6 +566,42 @@ impl CommandExecutor {
         Ok(())
     }
 
+    /// Writes the outcome and output of the command to the run log, if any.
+    fn record_run_log(
+        &self,
+        command_display: &str,
+        outcome: &Result<(ExitStatus, String, String), CommandError>,
+    ) {
+        let Some((run_log, step)) = &self.run_log else {
+            return;
+        };
+
+        let (summary, stdout, stderr) = match outcome {
+            Ok((status, stdout, stderr)) => (status.to_string(), stdout.as_str(), stderr.as_str()),
+            Err(CommandError::TimedOut {
+                timeout,
+                stdout,
+                stderr,
+                ..
+            }) => (
+                format!("timed out after {}s", timeout.as_secs()),
+                stdout.as_str(),
+                stderr.as_str(),
+            ),
+            Err(error) => (error.to_string(), "", ""),
+        };
+
+        if let Err(e) = run_log.record(step, command_display, &summary, stdout, stderr) {
+            warn!(
+                operation = "command_execution",
+                command = %command_display,
+                log_file = %run_log.step_path(step).display(),
+                error = %e,
+                "Failed to write the run log"
+            );
+        }
+    }
+
     /// Logs the command output (stdout/stderr) at debug level.
     fn log_command_output(command_display: &str, stdout: &str, stderr: &str) {
         if !stdout.trim().is_empty() {
@@ -189,6 +624,60 @@ impl CommandExecutor {
     }
 }
 
+/// Passes the lines sent by the output readers to an [`OutputLineHandler`]
+struct LineForwarder<'a> {
+    receiver: Receiver<(OutputStream, String)>,
+    on_line: OutputLineHandler<'a>,
+}
+
+impl LineForwarder<'_> {
+    /// Forward the lines read so far
+    fn forward_pending(&self) {
+        for (stream, line) in self.receiver.try_iter() {
+            self.forward(stream, &line);
+        }
+    }
+
+    /// Forward every remaining line, until both readers are finished
+    fn forward_remaining(self) {
+        for (stream, line) in &self.receiver {
+            self.forward(stream, &line);
+        }
+    }
+
+    fn forward(&self, stream: OutputStream, line: &str) {
+        tracing::debug!(
+            operation = "command_execution",
+            stream = %stream,
+            "{line}"
+        );
+        (self.on_line)(stream, line);
+    }
+}
+
+/// Decode a raw output line for display
+///
+/// Drops the line terminator and ANSI escape sequences (tools like `tofu`
+/// color their output even when it is piped), and replaces invalid UTF-8.
+fn display_line(raw: &[u8]) -> String {
+    let line = String::from_utf8_lossy(raw);
+    let line = line.trim_end_matches(['\n', '\r']);
+
+    let mut text = String::with_capacity(line.len());
+    let mut chars = line.chars();
+    while let Some(c) = chars.next() {
+        if c == '\u{1b}' {
+            // Skip a CSI sequence up to its final byte, or the single escaped character
+            if chars.next() == Some('[') {
+                chars.by_ref().find(|c| ('@'..='~').contains(c));
+            }
+        } else {
+            text.push(c);
+        }
+    }
+    text
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -218,6 +707,28 @@ mod tests {
         assert!(output.is_success());
     }
 
+    #[test]
+    fn it_should_pass_its_environment_variables_to_the_command() {
+        let executor = CommandExecutor::new().with_env("TORRUST_TD_EXECUTOR_TEST", "json");
+        let result = executor
+            .run_command("sh", &["-c", "echo $TORRUST_TD_EXECUTOR_TEST"], None)
+            .unwrap();
+
+        assert_eq!(result.stdout_trimmed(), "json");
+    }
+
+    #[test]
+    fn it_should_not_display_redacted_arguments() {
+        let executor = CommandExecutor::new().with_redacted("s3cret-token");
+
+        let error = executor
+            .run_command("sh", &["-c", "exit 1", "s3cret-token"], None)
+            .unwrap_err();
+
+        assert!(!error.to_string().contains("s3cret-token"));
+        assert!(error.to_string().contains("[REDACTED]"));
+    }
+
     #[test]
     fn it_should_return_error_for_nonexistent_command() {
         let executor = CommandExecutor::new();
@@ -265,4 +776,177 @@ mod tests {
             other => panic!("Expected WorkingDirectoryNotFound, got: {other:?}"),
         }
     }
+
+    #[test]
+    fn it_should_return_output_when_the_command_finishes_before_the_timeout() {
+        let executor = CommandExecutor::new().with_timeout(Duration::from_secs(30));
+        let result = executor.run_command("echo", &["in_time"], None);
+
+        assert_eq!(result.unwrap().stdout_trimmed(), "in_time");
+    }
+
+    #[test]
+    fn it_should_write_the_input_to_the_stdin_of_the_command() {
+        let input = vec![b'x'; 1024 * 1024];
+
+        let result = CommandExecutor::new().run_command_with_input(
+            "wc",
+            &["-c"],
+            None,
+            Duration::from_secs(30),
+            &input,
+        );
+
+        assert_eq!(result.unwrap().stdout_trimmed(), "1048576");
+    }
+
+    #[test]
+    fn it_should_kill_the_whole_process_group_when_the_command_times_out() {
+        let temp_dir = tempfile::TempDir::new().unwrap();
+        let pid_file = temp_dir.path().join("grandchild.pid");
+        let script = format!("sleep 600 & echo $! > {}; wait", pid_file.display());
+
+        let started = Instant::now();
+        let result = CommandExecutor::new().run_command_with_timeout(
+            "sh",
+            &["-c", &script],
+            None,
+            Duration::from_millis(500),
+        );
+
+        assert!(started.elapsed() < Duration::from_secs(30));
+        match result {
+            Err(CommandError::TimedOut { timeout, .. }) => {
+                assert_eq!(timeout, Duration::from_millis(500));
+            }
+            other => panic!("Expected TimedOut, got: {other:?}"),
+        }
+
+        let grandchild_pid = std::fs::read_to_string(&pid_file).unwrap();
+        assert!(
+            !is_still_running(grandchild_pid.trim()),
+            "the process spawned by the command should have been killed"
+        );
+    }
+
+    #[test]
+    fn it_should_forward_the_output_lines_of_both_streams_while_capturing_them() {
+        let lines = Mutex::new(Vec::new());
+
+        let result = CommandExecutor::new()
+            .run_command_streaming(
+                "sh",
+                &["-c", "echo first; echo warning >&2; printf 'last'"],
+                None,
+                Duration::from_secs(30),
+                &|stream, line| lines.lock().unwrap().push((stream, line.to_string())),
+            )
+            .unwrap();
+
+        let lines = lines.into_inner().unwrap();
+        assert!(lines.contains(&(OutputStream::Stdout, "first".to_string())));
+        assert!(lines.contains(&(OutputStream::Stderr, "warning".to_string())));
+        assert!(lines.contains(&(OutputStream::Stdout, "last".to_string())));
+        assert_eq!(result.stdout, "first\nlast");
+        assert_eq!(result.stderr, "warning\n");
+    }
+
+    #[test]
+    fn it_should_forward_lines_before_the_command_exits() {
+        let first_line_at = Mutex::new(None);
+        let started = Instant::now();
+
+        CommandExecutor::new()
+            .run_command_streaming(
+                "sh",
+                &["-c", "echo early; sleep 2; echo late"],
+                None,
+                Duration::from_secs(30),
+                &|_, line| {
+                    if line == "early" {
+                        *first_line_at.lock().unwrap() = Some(started.elapsed());
+                    }
+                },
+            )
+            .unwrap();
+
+        let first_line_at = first_line_at.into_inner().unwrap().unwrap();
+        assert!(first_line_at < Duration::from_secs(2));
+    }
+
+    #[test]
+    fn it_should_keep_the_complete_output_of_a_failed_streamed_command() {
+        let result = CommandExecutor::new().run_command_streaming(
+            "sh",
+            &["-c", "echo creating; echo boom >&2; exit 3"],
+            None,
+            Duration::from_secs(30),
+            &|_, _| {},
+        );
+
+        match result {
+            Err(CommandError::ExecutionFailed {
+                exit_code,
+                stdout,
+                stderr,
+                ..
+            }) => {
+                assert_eq!(exit_code, "3");
+                assert_eq!(stdout, "creating\n");
+                assert_eq!(stderr, "boom\n");
+            }
+            other => panic!("Expected ExecutionFailed, got: {other:?}"),
+        }
+    }
+
+    #[test]
+    fn it_should_write_the_output_of_a_failed_command_to_its_run_log() {
+        let temp_dir = tempfile::TempDir::new().unwrap();
+        let run_log = RunLog::new(temp_dir.path(), "provision", chrono::Utc::now());
+        let executor = CommandExecutor::new().with_run_log(run_log.clone(), "tofu-apply");
+
+        let result = executor.run_command_streaming(
+            "sh",
+            &["-c", "echo creating; echo boom >&2; exit 3"],
+            None,
+            Duration::from_secs(30),
+            &|_, _| {},
+        );
+
+        assert!(result.is_err());
+        let content = std::fs::read_to_string(run_log.step_path("tofu-apply")).unwrap();
+        assert!(content.contains("exit status: 3"));
+        assert!(content.contains("--- stdout ---\ncreating\n"));
+        assert!(content.contains("--- stderr ---\nboom\n"));
+    }
+
+    #[test]
+    fn it_should_strip_ansi_escape_sequences_and_line_terminators_from_displayed_lines() {
+        assert_eq!(
+            display_line(b"\x1b[1m\x1b[32mApply complete!\x1b[0m\r\n"),
+            "Apply complete!"
+        );
+    }
+
+    #[test]
+    fn it_should_replace_invalid_utf8_in_displayed_lines() {
+        assert_eq!(display_line("caf\u{e9}\n".as_bytes()), "caf\u{e9}");
+        assert_eq!(display_line(b"bad \xff byte"), "bad \u{fffd} byte");
+    }
+
+    /// Whether the process is still alive after giving a pending kill time to land
+    ///
+    /// A killed process closes its pipes slightly before it becomes a zombie.
+    fn is_still_running(pid: &str) -> bool {
+        let started = Instant::now();
+        while started.elapsed() < Duration::from_secs(5) {
+            let alive = std::fs::read_to_string(format!("/proc/{pid}/stat"))
+                .is_ok_and(|stat| !stat.contains(") Z "));
+            if !alive {
+                return false;
+            }
+            thread::sleep(Duration::from_millis(20));
+        }
+        true
+    }
 }
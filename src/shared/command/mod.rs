@@ -10,12 +10,21 @@
 //! - Optional verbose output logging
 //! - Working directory support
 //! - Comprehensive error categorization (startup vs execution failures)
+//! - Per-step timeouts with process-group aware termination ([`CommandTimeouts`])
+//! - Line-by-line output streaming while the command runs ([`OutputStream`])
+//! - Complete output of each invocation kept in per-run log files ([`RunLog`])
 
 pub mod error;
 pub mod executor;
 pub mod result;
+pub mod run_log;
+pub mod stream;
+pub mod timeout;
 
 // Re-export the main types for convenience
 pub use error::CommandError;
-pub use executor::CommandExecutor;
+pub use executor::{interrupt_running_commands, CommandExecutor, OutputLineHandler};
 pub use result::CommandResult;
+pub use run_log::{list_run_logs, RunLog, RunLogFile};
+pub use stream::OutputStream;
+pub use timeout::CommandTimeouts;
@@ -0,0 +1,389 @@
+//! Output logs of external tool runs
+//!
+//! This module provides the `RunLog` which keeps the complete stdout and
+//! stderr of every external tool invocation of one deployer command run, one
+//! file per step (`{logs_dir}/{command}/{run_id}-{step}.log`). Unlike trace
+//! files, they are written for successful runs too.
+//!
+//! ## Key Features
+//!
+//! - Files of one run share the run id, the UTC time the run started
+//! - A step invoked several times in one run appends to the same file
+//! - Only the most recent runs of a command are kept ([`RunLog::prune`])
+//! - The files are only readable by their owner, as tool output may include
+//!   configuration values
+
+use std::fs::{self, OpenOptions};
+use std::io::{self, Write};
+use std::path::{Path, PathBuf};
+
+use chrono::{DateTime, Utc};
+
+/// Number of runs of a command whose logs are kept by default
+pub const DEFAULT_KEPT_RUNS: usize = 10;
+
+/// Format of the run id prefixing the log files of a run, sortable by time
+const RUN_ID_FORMAT: &str = "%Y%m%dT%H%M%S%.9fZ";
+
+/// Extension of the log files
+const LOG_FILE_EXTENSION: &str = "log";
+
+/// Output logs of one run of a deployer command
+#[derive(Debug, Clone)]
+pub struct RunLog {
+    dir: PathBuf,
+    run_id: String,
+    kept_runs: usize,
+}
+
+impl RunLog {
+    /// Create the logs of the `command` run started at `started_at`
+    ///
+    /// The files are written to `{logs_dir}/{command}/`, which is created by
+    /// the first [`Self::record`].
+    #[must_use]
+    pub fn new(logs_dir: &Path, command: &str, started_at: DateTime<Utc>) -> Self {
+        Self {
+            dir: logs_dir.join(command),
+            run_id: started_at.format(RUN_ID_FORMAT).to_string(),
+            kept_runs: DEFAULT_KEPT_RUNS,
+        }
+    }
+
+    /// Keep the logs of the last `runs` runs of the command when pruning
+    ///
+    /// The current run is always kept, even when `runs` is zero.
+    #[must_use]
+    pub fn keeping(mut self, runs: usize) -> Self {
+        self.kept_runs = runs;
+        self
+    }
+
+    /// Directory holding the logs of every run of the command
+    #[must_use]
+    pub fn dir(&self) -> &Path {
+        &self.dir
+    }
+
+    /// Path of the log file of `step` in this run
+    #[must_use]
+    pub fn step_path(&self, step: &str) -> PathBuf {
+        self.dir
+            .join(format!("{}-{step}.{LOG_FILE_EXTENSION}", self.run_id))
+    }
+
+    /// Append the output of one invocation to the log file of `step`
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the directory or the file cannot be written.
+    pub fn record(
+        &self,
+        step: &str,
+        command: &str,
+        outcome: &str,
+        stdout: &str,
+        stderr: &str,
+    ) -> io::Result<()> {
+        fs::create_dir_all(&self.dir)?;
+
+        let mut options = OpenOptions::new();
+        options.create(true).append(true);
+        #[cfg(unix)]
+        {
+            use std::os::unix::fs::OpenOptionsExt;
+            options.mode(0o600);
+        }
+        let mut file = options.open(self.step_path(step))?;
+
+        writeln!(file, "$ {command}")?;
+        writeln!(file, "# {outcome}")?;
+        writeln!(file, "--- stdout ---")?;
+        write_output(&mut file, stdout)?;
+        writeln!(file, "--- stderr ---")?;
+        write_output(&mut file, stderr)?;
+
+        Ok(())
+    }
+
+    /// Remove the log files of the runs older than the kept ones
+    ///
+    /// Files that do not belong to a run, and a missing directory, are left
+    /// alone.
+    ///
+    /// # Returns
+    ///
+    /// The number of removed files.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the directory cannot be read or a file cannot be
+    /// removed.
+    pub fn prune(&self) -> io::Result<usize> {
+        let files = match list_log_files(&self.dir) {
+            Ok(files) => files,
+            Err(e) if e.kind() == io::ErrorKind::NotFound => return Ok(0),
+            Err(e) => return Err(e),
+        };
+
+        let mut run_ids: Vec<&str> = files.iter().map(|(run_id, _)| run_id.as_str()).collect();
+        run_ids.sort_unstable_by(|a, b| b.cmp(a));
+        run_ids.dedup();
+        let kept: Vec<&str> = run_ids
+            .into_iter()
+            .take(self.kept_runs)
+            .chain(std::iter::once(self.run_id.as_str()))
+            .collect();
+
+        let mut removed = 0;
+        for (run_id, path) in &files {
+            if !kept.contains(&run_id.as_str()) {
+                fs::remove_file(path)?;
+                removed += 1;
+            }
+        }
+
+        Ok(removed)
+    }
+}
+
+/// A log file of one step of a run, as found on disk
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RunLogFile {
+    /// Deployer command of the run (`provision`, `configure`, ...)
+    pub command: String,
+    /// Run id, the UTC time the run started
+    pub run_id: String,
+    /// Step that wrote the file (`tofu-apply`, a playbook name, ...)
+    pub step: String,
+    /// Path of the file
+    pub path: PathBuf,
+    /// Size of the file in bytes
+    pub size: u64,
+}
+
+impl RunLogFile {
+    /// Path of the file relative to the logs directory (`{command}/{file}`)
+    ///
+    /// This is how the file is selected by the `logs` command.
+    #[must_use]
+    pub fn relative_path(&self) -> String {
+        let file_name = self
+            .path
+            .file_name()
+            .map(|name| name.to_string_lossy().into_owned())
+            .unwrap_or_default();
+        format!("{}/{file_name}", self.command)
+    }
+}
+
+/// Log files of every command under `logs_dir`, oldest run first
+///
+/// A missing directory yields an empty list.
+///
+/// # Errors
+///
+/// Returns an error if a directory or a file's metadata cannot be read.
+pub fn list_run_logs(logs_dir: &Path) -> io::Result<Vec<RunLogFile>> {
+    let entries = match fs::read_dir(logs_dir) {
+        Ok(entries) => entries,
+        Err(e) if e.kind() == io::ErrorKind::NotFound => return Ok(Vec::new()),
+        Err(e) => return Err(e),
+    };
+
+    let mut files = Vec::new();
+    for entry in entries {
+        let command_dir = entry?.path();
+        if !command_dir.is_dir() {
+            continue;
+        }
+        let Some(command) = command_dir.file_name().and_then(|name| name.to_str()) else {
+            continue;
+        };
+        for (run_id, path) in list_log_files(&command_dir)? {
+            let step = path
+                .file_stem()
+                .and_then(|stem| stem.to_str())
+                .and_then(|stem| stem.split_once('-'))
+                .map(|(_, step)| step.to_string())
+                .unwrap_or_default();
+            let size = fs::metadata(&path)?.len();
+            files.push(RunLogFile {
+                command: command.to_string(),
+                run_id,
+                step,
+                path,
+                size,
+            });
+        }
+    }
+
+    files.sort_by(|a, b| (&a.run_id, &a.command, &a.step).cmp(&(&b.run_id, &b.command, &b.step)));
+    Ok(files)
+}
+
+/// Log files of `dir` with the run id they belong to
+fn list_log_files(dir: &Path) -> io::Result<Vec<(String, PathBuf)>> {
+    let mut files = Vec::new();
+    for entry in fs::read_dir(dir)? {
+        let path = entry?.path();
+        if path.extension().is_none_or(|ext| ext != LOG_FILE_EXTENSION) {
+            continue;
+        }
+        let run_id = path
+            .file_name()
+            .and_then(|name| name.to_str())
+            .and_then(|name| name.split_once('-'))
+            .map(|(run_id, _)| run_id.to_string());
+        if let Some(run_id) = run_id {
+            files.push((run_id, path));
+        }
+    }
+    Ok(files)
+}
+
+/// Write `output`, ending it with a newline
+fn write_output(file: &mut fs::File, output: &str) -> io::Result<()> {
+    file.write_all(output.as_bytes())?;
+    if !output.is_empty() && !output.ends_with('\n') {
+        writeln!(file)?;
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::TimeZone;
+    use tempfile::TempDir;
+
+    use super::*;
+
+    fn started_at(minute: u32) -> DateTime<Utc> {
+        Utc.with_ymd_and_hms(2026, 10, 16, 10, minute, 0).unwrap()
+    }
+
+    #[test]
+    fn it_should_name_the_files_after_the_run_and_the_step() {
+        let run_log = RunLog::new(Path::new("build/prod/logs"), "provision", started_at(30));
+
+        assert_eq!(
+            run_log.step_path("tofu-apply"),
+            PathBuf::from("build/prod/logs/provision/20261016T103000.000000000Z-tofu-apply.log")
+        );
+    }
+
+    #[test]
+    fn it_should_append_every_invocation_of_a_step_to_its_file() {
+        let temp_dir = TempDir::new().unwrap();
+        let run_log = RunLog::new(temp_dir.path(), "provision", started_at(0));
+
+        run_log
+            .record(
+                "tofu-apply",
+                "tofu apply",
+                "exit status: 0",
+                "Apply complete!",
+                "",
+            )
+            .unwrap();
+        run_log
+            .record(
+                "tofu-apply",
+                "tofu apply",
+                "exit status: 1",
+                "",
+                "Error: boom\n",
+            )
+            .unwrap();
+
+        let content = fs::read_to_string(run_log.step_path("tofu-apply")).unwrap();
+        assert_eq!(
+            content,
+            "$ tofu apply\n# exit status: 0\n--- stdout ---\nApply complete!\n--- stderr ---\n\
+             $ tofu apply\n# exit status: 1\n--- stdout ---\n--- stderr ---\nError: boom\n"
+        );
+    }
+
+    #[test]
+    fn it_should_keep_the_files_of_the_most_recent_runs() {
+        let temp_dir = TempDir::new().unwrap();
+        for minute in 0..4 {
+            let run_log = RunLog::new(temp_dir.path(), "configure", started_at(minute));
+            run_log
+                .record("install-docker", "ansible-playbook", "", "", "")
+                .unwrap();
+            run_log
+                .record("configure-firewall", "ansible-playbook", "", "", "")
+                .unwrap();
+        }
+        let current = RunLog::new(temp_dir.path(), "configure", started_at(3)).keeping(2);
+
+        let removed = current.prune().unwrap();
+
+        assert_eq!(removed, 4);
+        let run = |minute| RunLog::new(temp_dir.path(), "configure", started_at(minute));
+        assert!(!run(1).step_path("install-docker").exists());
+        assert!(run(2).step_path("install-docker").exists());
+        assert!(run(3).step_path("configure-firewall").exists());
+    }
+
+    #[test]
+    fn it_should_always_keep_the_current_run() {
+        let temp_dir = TempDir::new().unwrap();
+        let run_log = RunLog::new(temp_dir.path(), "provision", started_at(0)).keeping(0);
+        run_log
+            .record("tofu-init", "tofu init", "", "", "")
+            .unwrap();
+
+        assert_eq!(run_log.prune().unwrap(), 0);
+        assert!(run_log.step_path("tofu-init").exists());
+    }
+
+    #[test]
+    fn it_should_list_the_files_of_every_command_oldest_run_first() {
+        let temp_dir = TempDir::new().unwrap();
+        RunLog::new(temp_dir.path(), "configure", started_at(5))
+            .record("install-docker", "ansible-playbook", "", "ok", "")
+            .unwrap();
+        RunLog::new(temp_dir.path(), "provision", started_at(0))
+            .record("tofu-apply", "tofu apply", "", "", "")
+            .unwrap();
+
+        let files = list_run_logs(temp_dir.path()).unwrap();
+
+        let listed: Vec<(String, String)> = files
+            .iter()
+            .map(|file| (file.relative_path(), file.step.clone()))
+            .collect();
+        assert_eq!(
+            listed,
+            vec![
+                (
+                    "provision/20261016T100000.000000000Z-tofu-apply.log".to_string(),
+                    "tofu-apply".to_string()
+                ),
+                (
+                    "configure/20261016T100500.000000000Z-install-docker.log".to_string(),
+                    "install-docker".to_string()
+                ),
+            ]
+        );
+    }
+
+    #[test]
+    fn it_should_list_nothing_when_the_logs_directory_does_not_exist() {
+        let temp_dir = TempDir::new().unwrap();
+
+        assert!(list_run_logs(&temp_dir.path().join("logs"))
+            .unwrap()
+            .is_empty());
+    }
+
+    #[test]
+    fn it_should_do_nothing_when_no_log_was_written() {
+        let temp_dir = TempDir::new().unwrap();
+        let run_log = RunLog::new(temp_dir.path(), "provision", started_at(0));
+
+        assert_eq!(run_log.prune().unwrap(), 0);
+    }
+}
@@ -0,0 +1,24 @@
+//! Output streams of a command
+//!
+//! This module provides the `OutputStream` enum that tells which stream of a
+//! command an output line was written to.
+
+use std::fmt;
+
+/// Stream a command output line was written to
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum OutputStream {
+    /// Standard output
+    Stdout,
+    /// Standard error
+    Stderr,
+}
+
+impl fmt::Display for OutputStream {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Stdout => f.write_str("stdout"),
+            Self::Stderr => f.write_str("stderr"),
+        }
+    }
+}
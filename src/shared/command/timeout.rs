@@ -0,0 +1,151 @@
+//! Timeout policy for external commands
+//!
+//! External tools can hang forever (e.g. `OpenTofu` waiting on the LXD
+//! socket). [`CommandTimeouts`] bounds every invocation: each step has its own
+//! timeout, and an optional overall deadline caps whatever is left of the
+//! workflow.
+
+use std::time::{Duration, Instant};
+
+/// Default timeout for `tofu init` (provider downloads included)
+pub const DEFAULT_OPENTOFU_INIT_TIMEOUT: Duration = Duration::from_secs(5 * 60);
+
+/// Default timeout for `tofu validate`
+pub const DEFAULT_OPENTOFU_VALIDATE_TIMEOUT: Duration = Duration::from_secs(2 * 60);
+
+/// Default timeout for `tofu plan`
+pub const DEFAULT_OPENTOFU_PLAN_TIMEOUT: Duration = Duration::from_secs(10 * 60);
+
+/// Default timeout for `tofu apply`
+pub const DEFAULT_OPENTOFU_APPLY_TIMEOUT: Duration = Duration::from_secs(30 * 60);
+
+/// Default timeout for `tofu destroy`
+pub const DEFAULT_OPENTOFU_DESTROY_TIMEOUT: Duration = Duration::from_secs(30 * 60);
+
+/// Default timeout for `tofu output`
+pub const DEFAULT_OPENTOFU_OUTPUT_TIMEOUT: Duration = Duration::from_secs(2 * 60);
+
+/// Default timeout for a single `ansible-playbook` run
+pub const DEFAULT_ANSIBLE_PLAYBOOK_TIMEOUT: Duration = Duration::from_secs(30 * 60);
+
+/// Default timeout for a single remote command run over SSH
+pub const DEFAULT_SSH_COMMAND_TIMEOUT: Duration = Duration::from_secs(10 * 60);
+
+/// Per-step timeouts for external commands, optionally capped by a deadline
+///
+/// The step timeouts are public so callers can tune a single step:
+///
+/// ```rust
+/// use std::time::Duration;
+/// use torrust_tracker_deployer_lib::shared::command::CommandTimeouts;
+///
+/// let timeouts = CommandTimeouts {
+///     opentofu_apply: Duration::from_secs(60 * 60),
+///     ..CommandTimeouts::default()
+/// };
+///
+/// assert_eq!(timeouts.budget(timeouts.opentofu_apply), Duration::from_secs(3600));
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CommandTimeouts {
+    /// Timeout for `tofu init`
+    pub opentofu_init: Duration,
+    /// Timeout for `tofu validate`
+    pub opentofu_validate: Duration,
+    /// Timeout for `tofu plan`
+    pub opentofu_plan: Duration,
+    /// Timeout for `tofu apply`
+    pub opentofu_apply: Duration,
+    /// Timeout for `tofu destroy`
+    pub opentofu_destroy: Duration,
+    /// Timeout for `tofu output`
+    pub opentofu_output: Duration,
+    /// Timeout for each `ansible-playbook` run
+    pub ansible_playbook: Duration,
+    /// Timeout for each remote command run over SSH
+    pub ssh_command: Duration,
+    /// Instant after which no command may keep running
+    pub deadline: Option<Instant>,
+}
+
+impl Default for CommandTimeouts {
+    fn default() -> Self {
+        Self {
+            opentofu_init: DEFAULT_OPENTOFU_INIT_TIMEOUT,
+            opentofu_validate: DEFAULT_OPENTOFU_VALIDATE_TIMEOUT,
+            opentofu_plan: DEFAULT_OPENTOFU_PLAN_TIMEOUT,
+            opentofu_apply: DEFAULT_OPENTOFU_APPLY_TIMEOUT,
+            opentofu_destroy: DEFAULT_OPENTOFU_DESTROY_TIMEOUT,
+            opentofu_output: DEFAULT_OPENTOFU_OUTPUT_TIMEOUT,
+            ansible_playbook: DEFAULT_ANSIBLE_PLAYBOOK_TIMEOUT,
+            ssh_command: DEFAULT_SSH_COMMAND_TIMEOUT,
+            deadline: None,
+        }
+    }
+}
+
+impl CommandTimeouts {
+    /// Cap every command so that none runs past `deadline`
+    #[must_use]
+    pub fn with_deadline(mut self, deadline: Instant) -> Self {
+        self.deadline = Some(deadline);
+        self
+    }
+
+    /// Cap every command at `overall` from now, or leave them uncapped for `None`
+    ///
+    /// Command handlers call this when a run starts, turning the overall
+    /// duration they were configured with into a deadline.
+    #[must_use]
+    pub fn with_deadline_after(self, overall: Option<Duration>) -> Self {
+        match overall {
+            Some(overall) => self.with_deadline(Instant::now() + overall),
+            None => self,
+        }
+    }
+
+    /// Time a step may run: its own timeout, or less when the deadline is near
+    ///
+    /// Returns [`Duration::ZERO`] once the deadline has passed, so the next
+    /// command times out immediately.
+    #[must_use]
+    pub fn budget(&self, step_timeout: Duration) -> Duration {
+        match self.deadline {
+            Some(deadline) => step_timeout.min(deadline.saturating_duration_since(Instant::now())),
+            None => step_timeout,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn it_should_use_the_step_timeout_without_a_deadline() {
+        let timeouts = CommandTimeouts::default();
+
+        assert_eq!(
+            timeouts.budget(timeouts.opentofu_apply),
+            DEFAULT_OPENTOFU_APPLY_TIMEOUT
+        );
+    }
+
+    #[test]
+    fn it_should_cap_the_step_timeout_at_the_remaining_deadline() {
+        let timeouts =
+            CommandTimeouts::default().with_deadline(Instant::now() + Duration::from_secs(10));
+
+        let budget = timeouts.budget(timeouts.opentofu_apply);
+
+        assert!(budget <= Duration::from_secs(10));
+        assert!(budget > Duration::from_secs(5));
+    }
+
+    #[test]
+    fn it_should_leave_no_budget_once_the_deadline_has_passed() {
+        let timeouts = CommandTimeouts::default().with_deadline(Instant::now());
+
+        assert_eq!(timeouts.budget(timeouts.ssh_command), Duration::ZERO);
+    }
+}
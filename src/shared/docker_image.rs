@@ -74,6 +74,8 @@ impl DockerImage {
 
     /// Returns the full image reference as `repository:tag`
     ///
+    /// Digest tags (`sha256:...`) are referenced as `repository@sha256:...`.
+    ///
     /// # Examples
     ///
     /// ```rust
@@ -84,13 +86,15 @@ impl DockerImage {
     /// ```
     #[must_use]
     pub fn full_reference(&self) -> String {
-        format!("{}:{}", self.repository, self.tag)
+        self.to_string()
     }
 }
 
 impl fmt::Display for DockerImage {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        write!(f, "{}:{}", self.repository, self.tag)
+        // Digests contain a colon themselves and use `@` as separator
+        let separator = if self.tag.contains(':') { '@' } else { ':' };
+        write!(f, "{}{separator}{}", self.repository, self.tag)
     }
 }
 
@@ -119,6 +123,14 @@ mod tests {
         assert_eq!(image.full_reference(), "torrust/tracker:develop");
     }
 
+    #[test]
+    fn it_should_reference_a_digest_with_an_at_sign() {
+        let digest = format!("sha256:{}", "0".repeat(64));
+        let image = DockerImage::new("torrust/tracker", digest.as_str());
+
+        assert_eq!(image.full_reference(), format!("torrust/tracker@{digest}"));
+    }
+
     #[test]
     fn it_should_display_as_full_reference() {
         let image = DockerImage::new("mysql", "8.4");
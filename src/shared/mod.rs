@@ -10,8 +10,10 @@ pub mod docker_image;
 pub mod domain_name;
 pub mod email;
 pub mod error;
+pub mod portable_path;
 pub mod secrets;
 pub mod service_endpoint;
+pub mod udp_tracker_client;
 pub mod username;
 
 // Re-export commonly used types for convenience
@@ -24,4 +26,5 @@ pub use secrets::{
     generate_random_password, ApiToken, ExposeSecret, Password, PlainApiToken, PlainPassword,
 };
 pub use service_endpoint::{InvalidServiceEndpointUrl, ServiceEndpoint};
+pub use udp_tracker_client::{UdpAnnounceResponse, UdpRetryPolicy, UdpTrackerClient};
 pub use username::{Username, UsernameError};
@@ -0,0 +1,176 @@
+//! Portable serialization of filesystem paths
+//!
+//! Paths persisted in the environment state must be readable on every
+//! platform. `PathBuf` serializes with the native separator, so a state file
+//! written on Windows contains `data\my-env` (or a mix of both separators when
+//! a `/`-style working directory is joined with native components) and cannot
+//! be resolved elsewhere.
+//!
+//! This module stores paths with `/` as the only separator and converts them
+//! back to the native separator when loading. Use it as a serde adapter:
+//!
+//! ```rust
+//! use std::path::PathBuf;
+//!
+//! use serde::{Deserialize, Serialize};
+//!
+//! #[derive(Serialize, Deserialize)]
+//! struct Dirs {
+//!     #[serde(with = "torrust_tracker_deployer_lib::shared::portable_path")]
+//!     data_dir: PathBuf,
+//!     #[serde(default, with = "torrust_tracker_deployer_lib::shared::portable_path::option")]
+//!     override_dir: Option<PathBuf>,
+//! }
+//! ```
+//!
+//! On Unix `\` is a valid file name character, so it is only treated as a
+//! separator on Windows.
+
+use std::path::{Path, PathBuf, MAIN_SEPARATOR, MAIN_SEPARATOR_STR};
+
+use serde::{Deserialize, Deserializer, Serializer};
+
+/// Render a path with `/` separators, independent of the platform
+#[must_use]
+pub fn to_portable_string(path: &Path) -> String {
+    let path = path.to_string_lossy();
+    if MAIN_SEPARATOR == '/' {
+        path.into_owned()
+    } else {
+        path.replace(MAIN_SEPARATOR, "/")
+    }
+}
+
+/// Parse a path written by [`to_portable_string`] (or by an older version
+/// that used the native separator) into a native path
+#[must_use]
+pub fn from_portable_str(path: &str) -> PathBuf {
+    if MAIN_SEPARATOR == '/' {
+        PathBuf::from(path)
+    } else {
+        PathBuf::from(path.replace('/', MAIN_SEPARATOR_STR))
+    }
+}
+
+/// Serialize a path with `/` separators
+///
+/// # Errors
+///
+/// Returns the serializer error.
+pub fn serialize<S: Serializer>(path: &Path, serializer: S) -> Result<S::Ok, S::Error> {
+    serializer.serialize_str(&to_portable_string(path))
+}
+
+/// Deserialize a path written with either separator
+///
+/// # Errors
+///
+/// Returns an error if the value is not a string.
+pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<PathBuf, D::Error> {
+    String::deserialize(deserializer).map(|path| from_portable_str(&path))
+}
+
+/// Serde adapter for `Option<PathBuf>` fields
+pub mod option {
+    use std::path::PathBuf;
+
+    use serde::{Deserialize, Deserializer, Serializer};
+
+    /// Serialize an optional path with `/` separators
+    ///
+    /// # Errors
+    ///
+    /// Returns the serializer error.
+    #[allow(clippy::ref_option)] // serde passes the field by reference
+    pub fn serialize<S: Serializer>(
+        path: &Option<PathBuf>,
+        serializer: S,
+    ) -> Result<S::Ok, S::Error> {
+        match path {
+            Some(path) => serializer.serialize_some(&super::to_portable_string(path)),
+            None => serializer.serialize_none(),
+        }
+    }
+
+    /// Deserialize an optional path written with either separator
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the value is neither a string nor null.
+    pub fn deserialize<'de, D: Deserializer<'de>>(
+        deserializer: D,
+    ) -> Result<Option<PathBuf>, D::Error> {
+        Option::<String>::deserialize(deserializer)
+            .map(|path| path.map(|path| super::from_portable_str(&path)))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde::{Deserialize, Serialize};
+
+    use super::*;
+
+    #[derive(Debug, PartialEq, Serialize, Deserialize)]
+    struct Dirs {
+        #[serde(with = "super")]
+        data_dir: PathBuf,
+        #[serde(default, with = "super::option")]
+        override_dir: Option<PathBuf>,
+    }
+
+    fn native(components: &[&str]) -> PathBuf {
+        components.iter().collect()
+    }
+
+    #[test]
+    fn it_should_serialize_paths_with_forward_slashes() {
+        let dirs = Dirs {
+            data_dir: native(&["workspace", "data", "my-env"]),
+            override_dir: Some(native(&["workspace", "templates"])),
+        };
+
+        let json = serde_json::to_value(&dirs).unwrap();
+
+        assert_eq!(json["data_dir"], "workspace/data/my-env");
+        assert_eq!(json["override_dir"], "workspace/templates");
+    }
+
+    #[test]
+    fn it_should_round_trip_paths_built_with_join() {
+        let dirs = Dirs {
+            data_dir: Path::new("workspace").join("data").join("my-env"),
+            override_dir: None,
+        };
+
+        let json = serde_json::to_string(&dirs).unwrap();
+        let loaded: Dirs = serde_json::from_str(&json).unwrap();
+
+        assert_eq!(loaded, dirs);
+    }
+
+    #[test]
+    fn it_should_load_forward_slash_paths_as_native_paths() {
+        let loaded: Dirs = serde_json::from_str(r#"{"data_dir": "./data/my-env"}"#).unwrap();
+
+        assert_eq!(loaded.data_dir, native(&[".", "data", "my-env"]));
+        assert_eq!(loaded.override_dir, None);
+    }
+
+    #[cfg(windows)]
+    #[test]
+    fn it_should_load_backslash_and_mixed_separator_paths_on_windows() {
+        let loaded: Dirs =
+            serde_json::from_str(r#"{"data_dir": "C:/work\\data\\my-env"}"#).unwrap();
+
+        assert_eq!(loaded.data_dir, PathBuf::from(r"C:\work\data\my-env"));
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn it_should_keep_backslashes_in_file_names_on_unix() {
+        let loaded: Dirs = serde_json::from_str(r#"{"data_dir": "data/odd\\name"}"#).unwrap();
+
+        assert_eq!(loaded.data_dir, Path::new("data").join("odd\\name"));
+    }
+}
@@ -0,0 +1,603 @@
+//! Minimal `BitTorrent` UDP tracker client
+//!
+//! Implements the two requests of the UDP tracker protocol
+//! ([BEP 15](https://www.bittorrent.org/beps/bep_0015.html)) needed to
+//! verify that a UDP tracker answers announces:
+//!
+//! 1. **Connect** - obtains a connection ID from the tracker
+//! 2. **Announce** - announces a peer for an info-hash using that connection ID
+//!
+//! The client announces a peer with `event = none` and reads the interval
+//! and peer counts of the response. It is used by the `udp` smoke check of
+//! the `test` command and by `probe udp`, which points it at any tracker.
+//!
+//! ## Retransmissions
+//!
+//! UDP packets can be lost, so an unanswered exchange is retried with the
+//! exponential backoff of BEP 15: attempt `n` waits `timeout * 2 ^ n` for each
+//! response, `n` starting at 0 and capped at 8. Every attempt starts with a
+//! new connect request, so the connection ID of the announce (valid for one
+//! minute) is never stale. Responses to another transaction (e.g. a late
+//! response to a previous attempt) are ignored.
+
+use std::net::SocketAddr;
+use std::time::Duration;
+
+use thiserror::Error;
+use tokio::net::UdpSocket;
+use tokio::time::Instant;
+use tracing::debug;
+
+/// Magic constant identifying the UDP tracker protocol in connect requests
+const PROTOCOL_ID: i64 = 0x0417_2710_1980;
+
+/// Action codes of the UDP tracker protocol
+const ACTION_CONNECT: i32 = 0;
+const ACTION_ANNOUNCE: i32 = 1;
+const ACTION_ERROR: i32 = 3;
+
+/// Size of a connect request and of its response, in bytes
+const CONNECT_PACKET_LEN: usize = 16;
+
+/// Size of an announce request, in bytes
+const ANNOUNCE_REQUEST_LEN: usize = 98;
+
+/// Minimum size of an announce response (without peers), in bytes
+const ANNOUNCE_RESPONSE_MIN_LEN: usize = 20;
+
+/// Largest datagram the client accepts
+const MAX_RESPONSE_LEN: usize = 2048;
+
+/// Highest exponent of the BEP 15 backoff (`15 * 2 ^ 8` = 3840 seconds)
+const MAX_BACKOFF_EXPONENT: u32 = 8;
+
+/// Prefix of the peer IDs generated by [`random_peer_id`] (Azureus style)
+const PEER_ID_PREFIX: &[u8; 8] = b"-TD0001-";
+
+/// Errors of a UDP tracker exchange
+#[derive(Debug, Error)]
+pub enum UdpTrackerClientError {
+    /// The local socket could not be bound or used
+    #[error("UDP socket error: {0}")]
+    Socket(#[from] std::io::Error),
+
+    /// The tracker answered with an error action
+    #[error("tracker returned an error: {message}")]
+    TrackerError { message: String },
+
+    /// The tracker answered with a malformed or unexpected packet
+    #[error("invalid {request} response: {reason}")]
+    InvalidResponse {
+        request: &'static str,
+        reason: String,
+    },
+
+    /// The tracker did not answer any attempt
+    #[error("no response to the {request} request after {attempts} attempt(s) ({waited:?} waited in total)")]
+    Timeout {
+        request: &'static str,
+        attempts: u32,
+        waited: Duration,
+    },
+}
+
+/// Peer counts returned by a successful announce
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct UdpAnnounceResponse {
+    /// Seconds the tracker asks the peer to wait before re-announcing
+    pub interval: i32,
+    /// Number of leechers of the torrent
+    pub leechers: i32,
+    /// Number of seeders of the torrent
+    pub seeders: i32,
+}
+
+/// How long the client waits for responses and how often it retries
+///
+/// The default is the policy of BEP 15: 15 seconds for the first attempt and
+/// 8 retransmissions, which can wait for more than two hours in total. Tools
+/// that report to a user usually want a shorter policy.
+///
+/// # Examples
+///
+/// ```rust
+/// use std::time::Duration;
+/// use torrust_tracker_deployer_lib::shared::udp_tracker_client::UdpRetryPolicy;
+///
+/// let policy = UdpRetryPolicy::new(Duration::from_secs(5), 2);
+///
+/// assert_eq!(policy.timeout(0), Duration::from_secs(5));
+/// assert_eq!(policy.timeout(2), Duration::from_secs(20));
+/// assert_eq!(policy.attempts(), 3);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct UdpRetryPolicy {
+    initial_timeout: Duration,
+    max_retransmissions: u32,
+}
+
+impl Default for UdpRetryPolicy {
+    fn default() -> Self {
+        Self::new(Duration::from_secs(15), MAX_BACKOFF_EXPONENT)
+    }
+}
+
+impl UdpRetryPolicy {
+    /// Create a policy waiting `initial_timeout` for the first attempt and
+    /// retrying at most `max_retransmissions` times
+    #[must_use]
+    pub const fn new(initial_timeout: Duration, max_retransmissions: u32) -> Self {
+        Self {
+            initial_timeout,
+            max_retransmissions,
+        }
+    }
+
+    /// How long attempt `n` (starting at 0) waits for each response
+    #[must_use]
+    pub fn timeout(&self, attempt: u32) -> Duration {
+        self.initial_timeout
+            .saturating_mul(1 << attempt.min(MAX_BACKOFF_EXPONENT))
+    }
+
+    /// Maximum number of attempts, the first one included
+    #[must_use]
+    pub fn attempts(&self) -> u32 {
+        self.max_retransmissions.saturating_add(1)
+    }
+}
+
+/// A UDP tracker client bound to one tracker address
+pub struct UdpTrackerClient {
+    tracker_addr: SocketAddr,
+    retry_policy: UdpRetryPolicy,
+}
+
+impl UdpTrackerClient {
+    /// Create a client for the tracker at `tracker_addr`, with the retry
+    /// policy of BEP 15
+    #[must_use]
+    pub fn new(tracker_addr: SocketAddr) -> Self {
+        Self {
+            tracker_addr,
+            retry_policy: UdpRetryPolicy::default(),
+        }
+    }
+
+    /// Use `retry_policy` instead of the policy of BEP 15
+    #[must_use]
+    pub fn with_retry_policy(mut self, retry_policy: UdpRetryPolicy) -> Self {
+        self.retry_policy = retry_policy;
+        self
+    }
+
+    /// Connect to the tracker and announce a peer for `info_hash`
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the socket fails, if the tracker answers either
+    /// request with an error or a malformed packet, or if no attempt of the
+    /// retry policy got an answer.
+    pub async fn announce(
+        &self,
+        info_hash: &[u8; 20],
+        peer_id: &[u8; 20],
+        port: u16,
+    ) -> Result<UdpAnnounceResponse, UdpTrackerClientError> {
+        let bind_addr: SocketAddr = if self.tracker_addr.is_ipv4() {
+            "0.0.0.0:0".parse().expect("valid IPv4 wildcard address")
+        } else {
+            "[::]:0".parse().expect("valid IPv6 wildcard address")
+        };
+
+        let socket = UdpSocket::bind(bind_addr).await?;
+        socket.connect(self.tracker_addr).await?;
+
+        let mut buffer = [0u8; MAX_RESPONSE_LEN];
+        let mut unanswered = "connect";
+        let mut waited = Duration::ZERO;
+
+        for attempt in 0..self.retry_policy.attempts() {
+            let timeout = self.retry_policy.timeout(attempt);
+
+            let transaction_id = rand::random::<i32>();
+            let started_at = Instant::now();
+            let Some(len) = exchange(
+                &socket,
+                &connect_request(transaction_id),
+                transaction_id,
+                timeout,
+                &mut buffer,
+            )
+            .await?
+            else {
+                debug!(tracker = %self.tracker_addr, attempt, "No response to the connect request");
+                unanswered = "connect";
+                waited += started_at.elapsed();
+                continue;
+            };
+            let connection_id = parse_connect_response(&buffer[..len], transaction_id)?;
+
+            let transaction_id = rand::random::<i32>();
+            let request = announce_request(connection_id, transaction_id, info_hash, peer_id, port);
+            let Some(len) =
+                exchange(&socket, &request, transaction_id, timeout, &mut buffer).await?
+            else {
+                debug!(tracker = %self.tracker_addr, attempt, "No response to the announce request");
+                unanswered = "announce";
+                waited += started_at.elapsed();
+                continue;
+            };
+
+            return parse_announce_response(&buffer[..len], transaction_id);
+        }
+
+        Err(UdpTrackerClientError::Timeout {
+            request: unanswered,
+            attempts: self.retry_policy.attempts(),
+            waited,
+        })
+    }
+}
+
+/// Generate a random info-hash, which no real torrent has
+#[must_use]
+pub fn random_info_hash() -> [u8; 20] {
+    rand::random()
+}
+
+/// Generate a random peer ID with the prefix of the deployer
+#[must_use]
+pub fn random_peer_id() -> [u8; 20] {
+    let mut peer_id: [u8; 20] = rand::random();
+    peer_id[..PEER_ID_PREFIX.len()].copy_from_slice(PEER_ID_PREFIX);
+    peer_id
+}
+
+/// Send `request` and wait up to `timeout` for the response to its transaction
+///
+/// Returns the length of the response in `buffer`, or `None` on timeout.
+/// Packets of other transactions are skipped.
+async fn exchange(
+    socket: &UdpSocket,
+    request: &[u8],
+    transaction_id: i32,
+    timeout: Duration,
+    buffer: &mut [u8],
+) -> Result<Option<usize>, UdpTrackerClientError> {
+    socket.send(request).await?;
+    let deadline = Instant::now() + timeout;
+
+    loop {
+        let Ok(received) = tokio::time::timeout_at(deadline, socket.recv(buffer)).await else {
+            return Ok(None);
+        };
+        let len = received?;
+
+        if len >= 8 && buffer[4..8] != transaction_id.to_be_bytes() {
+            debug!("Ignoring a response to another transaction");
+            continue;
+        }
+
+        return Ok(Some(len));
+    }
+}
+
+/// Build a connect request
+fn connect_request(transaction_id: i32) -> [u8; CONNECT_PACKET_LEN] {
+    let mut packet = [0u8; CONNECT_PACKET_LEN];
+    packet[0..8].copy_from_slice(&PROTOCOL_ID.to_be_bytes());
+    packet[8..12].copy_from_slice(&ACTION_CONNECT.to_be_bytes());
+    packet[12..16].copy_from_slice(&transaction_id.to_be_bytes());
+    packet
+}
+
+/// Build an announce request for a peer that has not started downloading
+fn announce_request(
+    connection_id: i64,
+    transaction_id: i32,
+    info_hash: &[u8; 20],
+    peer_id: &[u8; 20],
+    port: u16,
+) -> [u8; ANNOUNCE_REQUEST_LEN] {
+    let mut packet = [0u8; ANNOUNCE_REQUEST_LEN];
+    packet[0..8].copy_from_slice(&connection_id.to_be_bytes());
+    packet[8..12].copy_from_slice(&ACTION_ANNOUNCE.to_be_bytes());
+    packet[12..16].copy_from_slice(&transaction_id.to_be_bytes());
+    packet[16..36].copy_from_slice(info_hash);
+    packet[36..56].copy_from_slice(peer_id);
+    // downloaded (56..64) and left (64..72) are zero
+    // uploaded (72..80), event "none" (80..84), IP (84..88) and key (88..92) are zero
+    packet[92..96].copy_from_slice(&(-1i32).to_be_bytes()); // num_want: tracker default
+    packet[96..98].copy_from_slice(&port.to_be_bytes());
+    packet
+}
+
+/// Parse a connect response and return the connection ID
+fn parse_connect_response(
+    packet: &[u8],
+    transaction_id: i32,
+) -> Result<i64, UdpTrackerClientError> {
+    let body = check_header(packet, "connect", ACTION_CONNECT, transaction_id)?;
+
+    if packet.len() < CONNECT_PACKET_LEN {
+        return Err(invalid("connect", format!("{} bytes", packet.len())));
+    }
+
+    Ok(i64::from_be_bytes(
+        body[0..8].try_into().expect("slice of 8 bytes"),
+    ))
+}
+
+/// Parse an announce response
+fn parse_announce_response(
+    packet: &[u8],
+    transaction_id: i32,
+) -> Result<UdpAnnounceResponse, UdpTrackerClientError> {
+    let body = check_header(packet, "announce", ACTION_ANNOUNCE, transaction_id)?;
+
+    if packet.len() < ANNOUNCE_RESPONSE_MIN_LEN {
+        return Err(invalid("announce", format!("{} bytes", packet.len())));
+    }
+
+    let field = |index: usize| {
+        i32::from_be_bytes(
+            body[index * 4..index * 4 + 4]
+                .try_into()
+                .expect("slice of 4 bytes"),
+        )
+    };
+
+    Ok(UdpAnnounceResponse {
+        interval: field(0),
+        leechers: field(1),
+        seeders: field(2),
+    })
+}
+
+/// Check the action and transaction ID of a response and return its body
+///
+/// An error action is turned into [`UdpTrackerClientError::TrackerError`].
+fn check_header<'a>(
+    packet: &'a [u8],
+    request: &'static str,
+    expected_action: i32,
+    transaction_id: i32,
+) -> Result<&'a [u8], UdpTrackerClientError> {
+    if packet.len() < 8 {
+        return Err(invalid(request, format!("{} bytes", packet.len())));
+    }
+
+    let action = i32::from_be_bytes(packet[0..4].try_into().expect("slice of 4 bytes"));
+    let received_transaction_id =
+        i32::from_be_bytes(packet[4..8].try_into().expect("slice of 4 bytes"));
+
+    if received_transaction_id != transaction_id {
+        return Err(invalid(request, "transaction ID mismatch".to_string()));
+    }
+
+    if action == ACTION_ERROR {
+        return Err(UdpTrackerClientError::TrackerError {
+            message: String::from_utf8_lossy(&packet[8..]).into_owned(),
+        });
+    }
+
+    if action != expected_action {
+        return Err(invalid(request, format!("unexpected action {action}")));
+    }
+
+    Ok(&packet[8..])
+}
+
+fn invalid(request: &'static str, reason: String) -> UdpTrackerClientError {
+    UdpTrackerClientError::InvalidResponse { request, reason }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn response(action: i32, transaction_id: i32, body: &[u8]) -> Vec<u8> {
+        let mut packet = Vec::new();
+        packet.extend_from_slice(&action.to_be_bytes());
+        packet.extend_from_slice(&transaction_id.to_be_bytes());
+        packet.extend_from_slice(body);
+        packet
+    }
+
+    #[test]
+    fn it_should_build_a_connect_request_with_the_protocol_id() {
+        let packet = connect_request(7);
+
+        assert_eq!(&packet[0..8], &0x0417_2710_1980_i64.to_be_bytes());
+        assert_eq!(&packet[8..12], &0_i32.to_be_bytes());
+        assert_eq!(&packet[12..16], &7_i32.to_be_bytes());
+    }
+
+    #[test]
+    fn it_should_build_an_announce_request_with_the_info_hash_and_port() {
+        let packet = announce_request(42, 7, &[0xAB; 20], &[0xCD; 20], 6881);
+
+        assert_eq!(&packet[0..8], &42_i64.to_be_bytes());
+        assert_eq!(&packet[8..12], &1_i32.to_be_bytes());
+        assert_eq!(&packet[16..36], &[0xAB; 20]);
+        assert_eq!(&packet[36..56], &[0xCD; 20]);
+        assert_eq!(&packet[96..98], &6881_u16.to_be_bytes());
+    }
+
+    #[test]
+    fn it_should_read_the_connection_id_of_a_connect_response() {
+        let packet = response(0, 7, &42_i64.to_be_bytes());
+
+        assert_eq!(parse_connect_response(&packet, 7).unwrap(), 42);
+    }
+
+    #[test]
+    fn it_should_read_the_peer_counts_of_an_announce_response() {
+        let mut body = Vec::new();
+        for value in [120_i32, 1, 0] {
+            body.extend_from_slice(&value.to_be_bytes());
+        }
+        let packet = response(1, 7, &body);
+
+        assert_eq!(
+            parse_announce_response(&packet, 7).unwrap(),
+            UdpAnnounceResponse {
+                interval: 120,
+                leechers: 1,
+                seeders: 0,
+            }
+        );
+    }
+
+    #[test]
+    fn it_should_report_the_message_of_an_error_response() {
+        let packet = response(3, 7, b"torrent not on whitelist");
+
+        let error = parse_announce_response(&packet, 7).unwrap_err();
+
+        assert!(
+            matches!(error, UdpTrackerClientError::TrackerError { message } if message == "torrent not on whitelist")
+        );
+    }
+
+    #[tokio::test]
+    async fn it_should_complete_an_announce_round_trip_with_a_tracker() {
+        let tracker = UdpSocket::bind("127.0.0.1:0").await.unwrap();
+        let tracker_addr = tracker.local_addr().unwrap();
+
+        let fake_tracker = tokio::spawn(async move {
+            let mut buffer = [0u8; MAX_RESPONSE_LEN];
+
+            let (_, peer) = tracker.recv_from(&mut buffer).await.unwrap();
+            let transaction_id = i32::from_be_bytes(buffer[12..16].try_into().unwrap());
+            let reply = response(0, transaction_id, &42_i64.to_be_bytes());
+            tracker.send_to(&reply, peer).await.unwrap();
+
+            let (len, peer) = tracker.recv_from(&mut buffer).await.unwrap();
+            assert_eq!(len, ANNOUNCE_REQUEST_LEN);
+            assert_eq!(&buffer[0..8], &42_i64.to_be_bytes());
+            let transaction_id = i32::from_be_bytes(buffer[12..16].try_into().unwrap());
+            let mut body = Vec::new();
+            for value in [120_i32, 0, 1] {
+                body.extend_from_slice(&value.to_be_bytes());
+            }
+            tracker
+                .send_to(&response(1, transaction_id, &body), peer)
+                .await
+                .unwrap();
+        });
+
+        let response = UdpTrackerClient::new(tracker_addr)
+            .announce(&[1; 20], &[2; 20], 6881)
+            .await
+            .unwrap();
+
+        fake_tracker.await.unwrap();
+        assert_eq!(response.interval, 120);
+        assert_eq!(response.seeders, 1);
+    }
+
+    #[test]
+    fn it_should_reject_a_response_to_another_transaction() {
+        let packet = response(0, 8, &42_i64.to_be_bytes());
+
+        assert!(matches!(
+            parse_connect_response(&packet, 7),
+            Err(UdpTrackerClientError::InvalidResponse { .. })
+        ));
+    }
+
+    fn fast_retry_policy(max_retransmissions: u32) -> UdpRetryPolicy {
+        UdpRetryPolicy::new(Duration::from_millis(50), max_retransmissions)
+    }
+
+    #[test]
+    fn it_should_double_the_timeout_of_each_attempt_up_to_the_bep_15_cap() {
+        let policy = UdpRetryPolicy::default();
+
+        assert_eq!(policy.timeout(0), Duration::from_secs(15));
+        assert_eq!(policy.timeout(3), Duration::from_secs(120));
+        assert_eq!(policy.timeout(8), Duration::from_secs(3840));
+        assert_eq!(policy.timeout(12), Duration::from_secs(3840));
+        assert_eq!(policy.attempts(), 9);
+    }
+
+    #[test]
+    fn it_should_generate_peer_ids_with_the_deployer_prefix() {
+        let peer_id = random_peer_id();
+
+        assert_eq!(&peer_id[..8], b"-TD0001-");
+        assert_ne!(random_info_hash(), random_info_hash());
+    }
+
+    #[tokio::test]
+    async fn it_should_retransmit_a_request_the_tracker_did_not_answer() {
+        let tracker = UdpSocket::bind("127.0.0.1:0").await.unwrap();
+        let tracker_addr = tracker.local_addr().unwrap();
+
+        let fake_tracker = tokio::spawn(async move {
+            let mut buffer = [0u8; MAX_RESPONSE_LEN];
+
+            // The first connect request is lost
+            tracker.recv_from(&mut buffer).await.unwrap();
+
+            let (_, peer) = tracker.recv_from(&mut buffer).await.unwrap();
+            let transaction_id = i32::from_be_bytes(buffer[12..16].try_into().unwrap());
+            let reply = response(0, transaction_id, &42_i64.to_be_bytes());
+            tracker.send_to(&reply, peer).await.unwrap();
+
+            let (_, peer) = tracker.recv_from(&mut buffer).await.unwrap();
+            let transaction_id = i32::from_be_bytes(buffer[12..16].try_into().unwrap());
+            let mut body = Vec::new();
+            for value in [1800_i32, 3, 5] {
+                body.extend_from_slice(&value.to_be_bytes());
+            }
+            tracker
+                .send_to(&response(1, transaction_id, &body), peer)
+                .await
+                .unwrap();
+        });
+
+        let response = UdpTrackerClient::new(tracker_addr)
+            .with_retry_policy(fast_retry_policy(2))
+            .announce(&random_info_hash(), &random_peer_id(), 6881)
+            .await
+            .unwrap();
+
+        fake_tracker.await.unwrap();
+        assert_eq!(
+            response,
+            UdpAnnounceResponse {
+                interval: 1800,
+                leechers: 3,
+                seeders: 5,
+            }
+        );
+    }
+
+    #[tokio::test]
+    async fn it_should_give_up_after_the_last_attempt_of_the_retry_policy() {
+        let tracker = UdpSocket::bind("127.0.0.1:0").await.unwrap();
+        let tracker_addr = tracker.local_addr().unwrap();
+
+        let error = UdpTrackerClient::new(tracker_addr)
+            .with_retry_policy(fast_retry_policy(1))
+            .announce(&[1; 20], &[2; 20], 6881)
+            .await
+            .unwrap_err();
+
+        drop(tracker);
+        assert!(
+            matches!(
+                error,
+                UdpTrackerClientError::Timeout {
+                    request: "connect",
+                    attempts: 2,
+                    waited,
+                } if waited >= Duration::from_millis(150)
+            ),
+            "{error}"
+        );
+    }
+}
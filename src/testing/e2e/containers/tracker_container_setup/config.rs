@@ -23,6 +23,8 @@ pub struct E2eConfigEnvironment {
     pub ssh_port: u16,
     /// Tracker port configuration (from config)
     pub tracker_ports: TrackerPorts,
+    /// Memory limit of the instance in MiB, or `None` for the default sizing
+    pub instance_memory_mb: Option<u32>,
 }
 
 impl E2eConfigEnvironment {
@@ -49,9 +51,17 @@ impl E2eConfigEnvironment {
             config_file_path,
             ssh_port,
             tracker_ports,
+            instance_memory_mb: None,
         }
     }
 
+    /// Request the given memory limit for the instance, in MiB
+    #[must_use]
+    pub fn with_instance_memory_mb(mut self, memory_mb: u32) -> Self {
+        self.instance_memory_mb = Some(memory_mb);
+        self
+    }
+
     /// Generate JSON configuration string from this E2E environment
     ///
     /// Creates a complete environment configuration JSON using the values
@@ -76,7 +86,7 @@ impl E2eConfigEnvironment {
 
         // Create configuration JSON with absolute paths and tracker configuration
         // This must match the format expected by EnvironmentCreationConfig
-        serde_json::json!({
+        let mut config = serde_json::json!({
             "environment": {
                 "name": &self.environment_name
             },
@@ -117,8 +127,13 @@ impl E2eConfigEnvironment {
                 "admin_user": "admin",
                 "admin_password": "e2e-test-password"
             }
-        })
-        .to_string()
+        });
+
+        if let Some(memory_mb) = self.instance_memory_mb {
+            config["instance"] = serde_json::json!({ "memory_mb": memory_mb });
+        }
+
+        config.to_string()
     }
 
     /// Create E2E config environment from configuration file
@@ -149,6 +164,7 @@ impl E2eConfigEnvironment {
             config_file_path,
             ssh_port,
             tracker_ports,
+            instance_memory_mb: None,
         })
     }
 }
@@ -199,6 +199,7 @@ impl TestContext {
     /// );
     /// let provider_config = ProviderConfig::Lxd(LxdConfig {
     ///     profile_name: ProfileName::new(format!("lxd-{}", env_name.as_str())).unwrap(),
+    ///     remote: None,
     /// });
     /// let created_at = Utc.with_ymd_and_hms(2025, 1, 1, 0, 0, 0).unwrap();
     /// let environment = Environment::new(env_name, provider_config, ssh_credentials, 22, created_at);
@@ -24,6 +24,8 @@ pub struct ProcessRunner {
     log_dir: Option<PathBuf>,
     /// Path to the pre-built binary. When `None`, falls back to `cargo run`.
     binary: Option<PathBuf>,
+    /// Global options passed before the command (e.g. `--quiet`)
+    global_args: Vec<String>,
 }
 
 impl ProcessRunner {
@@ -38,6 +40,7 @@ impl ProcessRunner {
             working_dir: None,
             log_dir: None,
             binary: None,
+            global_args: Vec::new(),
         }
     }
 
@@ -52,19 +55,29 @@ impl ProcessRunner {
         self
     }
 
+    /// Pass a global option to every command run (e.g. `--quiet`)
+    #[must_use]
+    pub fn global_arg(mut self, arg: &str) -> Self {
+        self.global_args.push(arg.to_string());
+        self
+    }
+
     /// Build the base [`Command`] for the application.
     ///
     /// When a binary path is set, returns `Command::new(binary)`.
     /// Otherwise returns `Command::new("cargo")` pre-loaded with
     /// `["run", "--"]` so callers only need to append sub-command args.
+    /// Global options set with [`global_arg`](Self::global_arg) come first.
     fn make_command(&self) -> Command {
-        if let Some(binary) = &self.binary {
+        let mut cmd = if let Some(binary) = &self.binary {
             Command::new(binary)
         } else {
             let mut cmd = Command::new("cargo");
             cmd.args(["run", "--"]);
             cmd
-        }
+        };
+        cmd.args(&self.global_args);
+        cmd
     }
 
     /// Set the working directory for the test process (not the app working dir)
@@ -87,6 +100,38 @@ impl ProcessRunner {
         self
     }
 
+    /// Run the init command with the production binary
+    ///
+    /// This method runs `init` with optional working directory
+    /// for the application itself via `--working-dir`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the command fails to execute.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the working directory path contains invalid UTF-8.
+    pub fn run_init_command(&self) -> Result<ProcessResult> {
+        let mut cmd = self.make_command();
+
+        if let Some(working_dir) = &self.working_dir {
+            cmd.args(["init", "--working-dir", working_dir.to_str().unwrap()]);
+        } else {
+            cmd.arg("init");
+        }
+
+        // Add log-dir if specified
+        if let Some(log_dir) = &self.log_dir {
+            cmd.arg("--log-dir");
+            cmd.arg(log_dir);
+        }
+
+        let output = cmd.output().context("Failed to execute init command")?;
+
+        Ok(ProcessResult::new(output))
+    }
+
     /// Run the create command with the production binary
     ///
     /// This method runs `create environment --env-file <config_file>` with
@@ -512,6 +557,85 @@ impl ProcessRunner {
         Ok(ProcessResult::new(output))
     }
 
+    /// Run the stats command with the production binary
+    ///
+    /// This method runs `stats <environment_name> --remote --output-format json`
+    /// with optional working directory for the application itself via `--working-dir`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the command fails to execute.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the working directory path contains invalid UTF-8.
+    pub fn run_remote_stats_command(&self, environment_name: &str) -> Result<ProcessResult> {
+        let mut cmd = self.make_command();
+
+        cmd.args([
+            "stats",
+            environment_name,
+            "--remote",
+            "--output-format",
+            "json",
+        ]);
+
+        if let Some(working_dir) = &self.working_dir {
+            cmd.args(["--working-dir", working_dir.to_str().unwrap()]);
+        }
+
+        // Add log-dir if specified
+        if let Some(log_dir) = &self.log_dir {
+            cmd.arg("--log-dir");
+            cmd.arg(log_dir);
+        }
+
+        let output = cmd.output().context("Failed to execute stats command")?;
+
+        Ok(ProcessResult::new(output))
+    }
+
+    /// Run the ssh command with the production binary
+    ///
+    /// This method runs `ssh <environment_name> -- <remote_command...>` with
+    /// optional working directory for the application itself via `--working-dir`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the command fails to execute.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the working directory path contains invalid UTF-8.
+    pub fn run_ssh_command(
+        &self,
+        environment_name: &str,
+        remote_command: &[&str],
+    ) -> Result<ProcessResult> {
+        let mut cmd = self.make_command();
+
+        cmd.args(["ssh", environment_name]);
+
+        if let Some(working_dir) = &self.working_dir {
+            cmd.args(["--working-dir", working_dir.to_str().unwrap()]);
+        }
+
+        // Add log-dir if specified
+        if let Some(log_dir) = &self.log_dir {
+            cmd.arg("--log-dir");
+            cmd.arg(log_dir);
+        }
+
+        if !remote_command.is_empty() {
+            cmd.arg("--");
+            cmd.args(remote_command);
+        }
+
+        let output = cmd.output().context("Failed to execute ssh command")?;
+
+        Ok(ProcessResult::new(output))
+    }
+
     /// Run the validate command with the production binary
     ///
     /// This method runs `validate -f <config_file>` with
@@ -547,9 +671,9 @@ impl ProcessRunner {
 
     /// Run the purge command with the production binary
     ///
-    /// This method runs `cargo run -- purge <environment_name> --force` with
+    /// This method runs `cargo run -- purge <environment_name> --yes` with
     /// optional working directory for the application itself via `--working-dir`.
-    /// Always uses `--force` flag to skip interactive confirmation prompts in tests.
+    /// Always uses `--yes` flag to skip interactive confirmation prompts in tests.
     ///
     /// # Errors
     ///
@@ -654,9 +778,9 @@ impl ProcessRunner {
 
     /// Run the purge command with the production binary
     ///
-    /// This method runs `purge <environment_name> --force`
+    /// This method runs `purge <environment_name> --yes`
     /// with optional working directory for the application itself via `--working-dir`.
-    /// The `--force` flag is always used to skip interactive prompts.
+    /// The `--yes` flag is always used to skip interactive prompts.
     ///
     /// # Errors
     ///
@@ -672,12 +796,12 @@ impl ProcessRunner {
             cmd.args([
                 "purge",
                 environment_name,
-                "--force",
+                "--yes",
                 "--working-dir",
                 working_dir.to_str().unwrap(),
             ]);
         } else {
-            cmd.args(["purge", environment_name, "--force"]);
+            cmd.args(["purge", environment_name, "--yes"]);
         }
 
         // Add log-dir if specified
@@ -734,4 +858,17 @@ impl ProcessResult {
     pub fn exit_code(&self) -> Option<i32> {
         self.output.status.code()
     }
+
+    /// Get the step timing summary printed on stderr at the end of the command
+    ///
+    /// Returns the `timings` object of the JSON summary line, or `None` if the
+    /// command printed no summary (e.g. with the text output format).
+    #[must_use]
+    pub fn timing_summary(&self) -> Option<serde_json::Value> {
+        self.stderr()
+            .lines()
+            .filter(|line| line.trim_start().starts_with(r#"{"timings""#))
+            .find_map(|line| serde_json::from_str::<serde_json::Value>(line.trim()).ok())
+            .and_then(|json| json.get("timings").cloned())
+    }
 }
@@ -239,3 +239,127 @@ pub fn create_test_environment_config(environment_name: &str) -> String {
     })
     .to_string()
 }
+
+/// Creates a test environment configuration for the Hetzner provider
+///
+/// Same tracker configuration as [`create_test_environment_config`], but
+/// provisioned on Hetzner Cloud. The API token is not embedded: the
+/// configuration references the `api_token_env` environment variable, which
+/// is resolved by the `create` command.
+///
+/// # Arguments
+///
+/// * `environment_name` - The name of the environment to create
+/// * `api_token_env` - Name of the environment variable holding the Hetzner API token
+///
+/// # Returns
+///
+/// Returns a `String` containing the complete environment configuration as JSON
+#[must_use]
+pub fn create_hetzner_test_environment_config(
+    environment_name: &str,
+    api_token_env: &str,
+) -> String {
+    let mut config: serde_json::Value =
+        serde_json::from_str(&create_test_environment_config(environment_name))
+            .expect("generated test configuration is valid JSON");
+
+    config["provider"] = serde_json::json!({
+        "provider": "hetzner",
+        "api_token": { "env": api_token_env },
+        "server_type": "cx22",
+        "location": "nbg1",
+        "image": "ubuntu-24.04"
+    });
+
+    config.to_string()
+}
+
+/// Creates a test environment configuration for the `DigitalOcean` provider
+///
+/// Same tracker configuration as [`create_test_environment_config`], but
+/// provisioned as a `DigitalOcean` droplet. The API token is not embedded:
+/// the configuration references the `api_token_env` environment variable,
+/// which is resolved by the `create` command.
+///
+/// # Arguments
+///
+/// * `environment_name` - The name of the environment to create
+/// * `api_token_env` - Name of the environment variable holding the `DigitalOcean` API token
+///
+/// # Returns
+///
+/// Returns a `String` containing the complete environment configuration as JSON
+#[must_use]
+pub fn create_digitalocean_test_environment_config(
+    environment_name: &str,
+    api_token_env: &str,
+) -> String {
+    let mut config: serde_json::Value =
+        serde_json::from_str(&create_test_environment_config(environment_name))
+            .expect("generated test configuration is valid JSON");
+
+    config["provider"] = serde_json::json!({
+        "provider": "digitalocean",
+        "api_token": { "env": api_token_env },
+        "droplet_size": "s-1vcpu-2gb",
+        "region": "fra1",
+        "image": "ubuntu-24-04-x64"
+    });
+
+    config.to_string()
+}
+
+/// Creates a test environment configuration for the Docker provider
+///
+/// Same tracker configuration as [`create_test_environment_config`], but
+/// deployed into a local container of the `image` Docker image. The image
+/// must exist locally (see `docker/provisioned-instance`).
+///
+/// # Arguments
+///
+/// * `environment_name` - The name of the environment to create
+/// * `image` - Local Docker image of the instance (e.g. `torrust-provisioned-instance:latest`)
+///
+/// # Returns
+///
+/// Returns a `String` containing the complete environment configuration as JSON
+#[must_use]
+pub fn create_docker_test_environment_config(environment_name: &str, image: &str) -> String {
+    let mut config: serde_json::Value =
+        serde_json::from_str(&create_test_environment_config(environment_name))
+            .expect("generated test configuration is valid JSON");
+
+    config["provider"] = serde_json::json!({
+        "provider": "docker",
+        "image": image
+    });
+
+    config.to_string()
+}
+
+/// Creates a test environment configuration for the libvirt provider
+///
+/// Same tracker configuration as [`create_test_environment_config`], but
+/// provisioned as a KVM domain of the system libvirt daemon, with the
+/// default storage pool, network and cloud image.
+///
+/// # Arguments
+///
+/// * `environment_name` - The name of the environment to create
+///
+/// # Returns
+///
+/// Returns a `String` containing the complete environment configuration as JSON
+#[must_use]
+pub fn create_libvirt_test_environment_config(environment_name: &str) -> String {
+    let mut config: serde_json::Value =
+        serde_json::from_str(&create_test_environment_config(environment_name))
+            .expect("generated test configuration is valid JSON");
+
+    config["provider"] = serde_json::json!({
+        "provider": "libvirt"
+    });
+
+    config.to_string()
+}
@@ -26,7 +26,7 @@
 //! use torrust_tracker_deployer_dependency_installer::Dependency;
 //!
 //! // Setup tasks (before creating the test runner)
-//! verify_required_dependencies(&[Dependency::OpenTofu, Dependency::Ansible])?;
+//! verify_required_dependencies(&[Dependency::OpenTofu, Dependency::Ansible], false)?;
 //! run_preflight_cleanup("e2e-full")?;
 //! let config_path = generate_environment_config("e2e-full")?;
 //!
@@ -34,6 +34,7 @@
 //! let test_runner = E2eTestRunner::new("e2e-full")
 //!     .with_cleanup_on_failure(true);
 //!
+//! test_runner.initialize_workspace()?;
 //! test_runner.create_environment(&config_path)?;
 //! test_runner.provision_infrastructure()?;
 //! test_runner.configure_services()?;
@@ -51,8 +52,10 @@ pub use test_runner::E2eTestRunner;
 
 // Re-export standalone setup functions
 pub use generate_config::{
-    build_e2e_test_config, create_test_environment_config, generate_environment_config,
-    write_environment_config,
+    build_e2e_test_config, create_digitalocean_test_environment_config,
+    create_docker_test_environment_config, create_hetzner_test_environment_config,
+    create_libvirt_test_environment_config, create_test_environment_config,
+    generate_environment_config, write_environment_config,
 };
 pub use preflight_cleanup::run_container_preflight_cleanup;
 pub use preflight_cleanup::run_preflight_cleanup;
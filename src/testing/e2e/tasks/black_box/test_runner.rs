@@ -12,6 +12,7 @@
 //! let test_runner = E2eTestRunner::new("e2e-full")
 //!     .with_cleanup_on_failure(true);
 //!
+//! test_runner.initialize_workspace()?;
 //! test_runner.create_environment(&config_path)?;
 //! test_runner.provision_infrastructure()?;
 //! test_runner.configure_services()?;
@@ -24,6 +25,8 @@ use std::path::Path;
 use anyhow::Result;
 use tracing::{error, info, warn};
 
+use crate::adapters::lxd::LxdClient;
+use crate::domain::InstanceName;
 use crate::testing::e2e::ProcessRunner;
 
 /// A process runner bound to a specific E2E test environment.
@@ -88,6 +91,43 @@ impl E2eTestRunner {
         self.cleanup_on_failure
     }
 
+    /// Initializes the workspace the environments are created in.
+    ///
+    /// Does nothing when the workspace is already initialized.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the init command fails.
+    pub fn initialize_workspace(&self) -> Result<()> {
+        info!(step = "initialize_workspace", "Initializing workspace");
+
+        let init_result = self
+            .runner
+            .run_init_command()
+            .map_err(|e| anyhow::anyhow!("Failed to execute init command: {e}"))?;
+
+        if !init_result.success() {
+            error!(
+                step = "initialize_workspace",
+                exit_code = ?init_result.exit_code(),
+                stderr = %init_result.stderr(),
+                "Init command failed"
+            );
+            return Err(anyhow::anyhow!(
+                "Workspace initialization failed with exit code {:?}",
+                init_result.exit_code()
+            ));
+        }
+
+        info!(
+            step = "initialize_workspace",
+            status = "success",
+            "Workspace initialized successfully"
+        );
+
+        Ok(())
+    }
+
     /// Creates the environment from the configuration file.
     ///
     /// # Arguments
@@ -431,6 +471,179 @@ impl E2eTestRunner {
         Ok(())
     }
 
+    /// Verifies the tracker statistics read through the stats command.
+    ///
+    /// Runs `stats --remote` after [`validate_deployment`], whose smoke test
+    /// announces a peer, and checks that the tracker reports a torrent, a
+    /// peer and a handled announce.
+    ///
+    /// [`validate_deployment`]: Self::validate_deployment
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the stats command fails or reports no announce.
+    pub fn verify_tracker_stats(&self) -> Result<()> {
+        info!(
+            step = "stats",
+            environment = %self.environment_name,
+            "Verifying the tracker statistics read through the API"
+        );
+
+        let stats_result = self
+            .runner
+            .run_remote_stats_command(&self.environment_name)
+            .map_err(|e| anyhow::anyhow!("Failed to execute stats command: {e}"))?;
+
+        if !stats_result.success() {
+            error!(
+                step = "stats",
+                environment = %self.environment_name,
+                exit_code = ?stats_result.exit_code(),
+                stderr = %stats_result.stderr(),
+                "Stats command failed"
+            );
+            return Err(anyhow::anyhow!(
+                "Stats failed with exit code {:?}",
+                stats_result.exit_code()
+            ));
+        }
+
+        let output: serde_json::Value = serde_json::from_str(&stats_result.stdout())
+            .map_err(|e| anyhow::anyhow!("Stats command printed invalid JSON: {e}"))?;
+        let stats = &output["replicas"][0]["stats"];
+        let counter = |name: &str| stats[name].as_u64().unwrap_or(0);
+
+        let torrents = counter("torrents");
+        let peers = counter("seeders") + counter("leechers");
+        let announces = counter("tcp4_announces_handled")
+            + counter("tcp6_announces_handled")
+            + counter("udp4_announces_handled")
+            + counter("udp6_announces_handled");
+
+        if torrents == 0 || peers == 0 || announces == 0 {
+            return Err(anyhow::anyhow!(
+                "Tracker statistics do not show the smoke test announce: \
+                 {torrents} torrent(s), {peers} peer(s), {announces} announce(s)"
+            ));
+        }
+
+        info!(
+            step = "stats",
+            environment = %self.environment_name,
+            status = "success",
+            torrents,
+            peers,
+            announces,
+            "Tracker statistics verified successfully"
+        );
+
+        Ok(())
+    }
+
+    /// Verifies SSH access to the instance through the ssh command.
+    ///
+    /// Runs a successful and a failing remote command and checks that the
+    /// remote exit code is propagated by the deployer.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the ssh command cannot be executed or the exit
+    /// codes do not match the remote commands.
+    pub fn verify_ssh_access(&self) -> Result<()> {
+        info!(
+            step = "ssh",
+            environment = %self.environment_name,
+            "Verifying SSH access through the ssh command"
+        );
+
+        let success_result = self
+            .runner
+            .run_ssh_command(&self.environment_name, &["true"])
+            .map_err(|e| anyhow::anyhow!("Failed to execute ssh command: {e}"))?;
+
+        if !success_result.success() {
+            error!(
+                step = "ssh",
+                environment = %self.environment_name,
+                exit_code = ?success_result.exit_code(),
+                stderr = %success_result.stderr(),
+                "SSH command failed"
+            );
+            return Err(anyhow::anyhow!(
+                "SSH command failed with exit code {:?}",
+                success_result.exit_code()
+            ));
+        }
+
+        let failure_result = self
+            .runner
+            .run_ssh_command(&self.environment_name, &["exit", "3"])
+            .map_err(|e| anyhow::anyhow!("Failed to execute ssh command: {e}"))?;
+
+        if failure_result.exit_code() != Some(3) {
+            return Err(anyhow::anyhow!(
+                "SSH command did not propagate the remote exit code: expected 3, got {:?}",
+                failure_result.exit_code()
+            ));
+        }
+
+        info!(
+            step = "ssh",
+            environment = %self.environment_name,
+            status = "success",
+            "SSH access verified successfully"
+        );
+
+        Ok(())
+    }
+
+    /// Verifies the memory limit of the environment's LXD instance.
+    ///
+    /// Reads `limits.memory` of the instance through the LXD adapter, so the
+    /// check covers the configured sizing all the way to LXD.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if LXD cannot be queried or the limit does not match.
+    pub fn verify_instance_memory_limit(&self, expected_memory_mb: u32) -> Result<()> {
+        info!(
+            step = "verify_instance_resources",
+            environment = %self.environment_name,
+            expected_memory_mb,
+            "Verifying the memory limit of the LXD instance"
+        );
+
+        let instance_name =
+            InstanceName::new(format!("torrust-tracker-vm-{}", self.environment_name))?;
+        let memory_limit =
+            match LxdClient::new().get_instance_config_value(&instance_name, "limits.memory") {
+                Ok(memory_limit) => memory_limit,
+                Err(e) => {
+                    self.attempt_cleanup_on_failure();
+                    return Err(e);
+                }
+            };
+
+        let expected = format!("{expected_memory_mb}MiB");
+        if memory_limit.as_deref() != Some(expected.as_str()) {
+            self.attempt_cleanup_on_failure();
+
+            return Err(anyhow::anyhow!(
+                "Instance '{instance_name}' has memory limit {memory_limit:?}, expected '{expected}'"
+            ));
+        }
+
+        info!(
+            step = "verify_instance_resources",
+            environment = %self.environment_name,
+            status = "success",
+            memory_limit = %expected,
+            "Instance memory limit verified successfully"
+        );
+
+        Ok(())
+    }
+
     /// Destroys the infrastructure for the environment.
     ///
     /// # Errors
@@ -10,14 +10,13 @@
 //! use torrust_tracker_deployer_dependency_installer::Dependency;
 //!
 //! // Verify dependencies for provision tests (only Ansible needed)
-//! verify_required_dependencies(&[Dependency::Ansible])?;
+//! verify_required_dependencies(&[Dependency::Ansible], false)?;
 //!
 //! // Verify dependencies for full E2E tests
-//! verify_required_dependencies(&[
-//!     Dependency::OpenTofu,
-//!     Dependency::Ansible,
-//!     Dependency::Lxd,
-//! ])?;
+//! verify_required_dependencies(
+//!     &[Dependency::OpenTofu, Dependency::Ansible, Dependency::Lxd],
+//!     false,
+//! )?;
 //! ```
 
 use anyhow::Result;
@@ -33,10 +32,12 @@ use tracing::error;
 /// # Arguments
 ///
 /// * `required_deps` - Slice of dependencies required for the specific E2E test suite
+/// * `allow_outdated` - Accept installed tools older than the minimum supported version
 ///
 /// # Errors
 ///
-/// Returns an error if any required dependencies are missing or cannot be detected.
+/// Returns an error if any required dependencies are missing, outdated (unless
+/// `allow_outdated` is set), or cannot be detected.
 ///
 /// # Example
 ///
@@ -45,23 +46,25 @@ use tracing::error;
 /// use torrust_tracker_deployer_dependency_installer::Dependency;
 ///
 /// // For provision-only tests
-/// verify_required_dependencies(&[Dependency::Ansible])?;
+/// verify_required_dependencies(&[Dependency::Ansible], false)?;
 ///
 /// // For full E2E tests
-/// verify_required_dependencies(&[
-///     Dependency::OpenTofu,
-///     Dependency::Ansible,
-///     Dependency::Lxd,
-/// ])?;
+/// verify_required_dependencies(
+///     &[Dependency::OpenTofu, Dependency::Ansible, Dependency::Lxd],
+///     false,
+/// )?;
 /// ```
-pub fn verify_required_dependencies(required_deps: &[Dependency]) -> Result<()> {
-    if let Err(e) = verify_dependencies(required_deps) {
+pub fn verify_required_dependencies(
+    required_deps: &[Dependency],
+    allow_outdated: bool,
+) -> Result<()> {
+    if let Err(e) = verify_dependencies(required_deps, allow_outdated) {
         error!(
             error = %e,
             "Dependency verification failed"
         );
         eprintln!("\n{}\n", e.actionable_message());
-        return Err(anyhow::anyhow!("Missing required dependencies"));
+        return Err(anyhow::anyhow!("Required dependencies are not satisfied"));
     }
 
     Ok(())
@@ -98,7 +98,8 @@ pub fn run_create_command(
             ssh_port,
         ),
         ProviderSection::Lxd(LxdProviderSection {
-            profile_name: format!("lxd-{environment_name}"),
+            profile_name: Some(format!("lxd-{environment_name}")),
+            remote: None,
         }),
         TrackerSection::default(),
         None,
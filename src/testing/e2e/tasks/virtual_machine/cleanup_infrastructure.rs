@@ -59,7 +59,7 @@ pub fn cleanup_test_infrastructure(test_context: &TestContext) {
     let result = test_context
         .services
         .opentofu_client
-        .destroy(true, &["-var-file=variables.tfvars"]) // auto_approve = true
+        .destroy(true, &["-var-file=variables.tfvars"], &|_, _| {}) // auto_approve = true
         .map_err(anyhow::Error::from);
 
     match result {
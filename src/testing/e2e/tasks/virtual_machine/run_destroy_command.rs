@@ -132,7 +132,7 @@ pub fn run_destroy_command(test_context: &mut TestContext) -> Result<(), Destroy
     // The DestroyCommandHandler now loads the environment internally and handles all states
     let env_name = test_context.environment.name();
     let destroyed_env = destroy_command_handler
-        .execute(env_name)
+        .execute(env_name, None)
         .map_err(|source| DestroyTaskError::DestructionFailed { source })?;
 
     info!(
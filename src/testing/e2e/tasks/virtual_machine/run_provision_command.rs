@@ -72,6 +72,8 @@ impl ProvisionTaskError {
     ///     source: StateTypeError::UnexpectedState {
     ///         expected: "created",
     ///         actual: "provisioned".to_string(),
+    ///         allowed_commands: &[],
+    ///         suggested_next: None,
     ///     },
     /// };
     /// println!("{}", error.help());
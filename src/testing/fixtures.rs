@@ -1,10 +1,13 @@
 //! Test fixtures for persistence and serialization testing
 //!
 //! This module provides reusable test entities and builders for testing
-//! JSON serialization, deserialization, and file persistence operations.
+//! JSON serialization, deserialization, and file persistence operations,
+//! and domain configurations shared by several test modules.
 
 use serde::{Deserialize, Serialize};
 
+use crate::domain::tracker::{DatabaseConfig, MysqlConfig, TrackerConfig, TrackerCoreConfig};
+
 /// Test entity for JSON serialization tests
 ///
 /// This is a simple entity used across multiple tests to verify
@@ -70,3 +73,34 @@ impl TestEntity {
         }
     }
 }
+
+/// Default tracker configuration with a `MySQL` database
+///
+/// Unlike `SQLite`, a `MySQL` database can be shared by several tracker
+/// replicas.
+///
+/// # Panics
+///
+/// Panics if the fixed configuration fails validation.
+#[must_use]
+pub fn mysql_tracker_config() -> TrackerConfig {
+    let defaults = TrackerConfig::default();
+    let mysql = MysqlConfig::new(
+        "mysql",
+        3306,
+        "torrust_tracker",
+        "tracker_user",
+        "tracker_password".to_string().into(),
+        "root_password".to_string().into(),
+    )
+    .expect("valid MySQL configuration");
+
+    TrackerConfig::new(
+        TrackerCoreConfig::new(DatabaseConfig::Mysql(mysql), false),
+        defaults.udp_trackers().to_vec(),
+        defaults.http_trackers().to_vec(),
+        defaults.http_api().clone(),
+        defaults.health_check_api().clone(),
+    )
+    .expect("valid tracker configuration")
+}
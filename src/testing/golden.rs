@@ -0,0 +1,327 @@
+//! Golden-file assertions for rendered artifacts
+//!
+//! A golden directory holds the expected output of a rendering, checked in
+//! next to the inputs it was rendered from. [`GoldenFiles`] compares a
+//! freshly rendered directory with it byte for byte, and fails with a
+//! unified diff of every file that changed, so an unintended change to a
+//! template shows up in the test output.
+//!
+//! ## Updating the Golden Files
+//!
+//! When a change to the rendered output is intended, run the tests with
+//! `UPDATE_GOLDEN=1` to replace the golden files with the new output, and
+//! review the result with `git diff` before committing it:
+//!
+//! ```bash
+//! UPDATE_GOLDEN=1 cargo test --test golden_rendering
+//! ```
+
+use std::collections::{BTreeMap, HashSet};
+use std::fmt::Write as _;
+use std::path::{Path, PathBuf};
+
+use sha2::{Digest, Sha256};
+use similar::TextDiff;
+
+/// Environment variable that switches the assertions to update mode
+pub const UPDATE_GOLDEN_ENV: &str = "UPDATE_GOLDEN";
+
+/// Golden files of one rendering, and how rendered files are compared with them
+///
+/// # Examples
+///
+/// ```rust,no_run
+/// use std::path::Path;
+/// use torrust_tracker_deployer_lib::testing::golden::GoldenFiles;
+///
+/// GoldenFiles::new("fixtures/golden/lxd/rendered")
+///     .with_placeholder(env!("CARGO_MANIFEST_DIR"), "<project-root>")
+///     .excluding_copies_of(Path::new("templates"))
+///     .assert_matches(Path::new("/tmp/rendered"));
+/// ```
+#[derive(Debug, Clone)]
+pub struct GoldenFiles {
+    golden_dir: PathBuf,
+    placeholders: Vec<(String, String)>,
+    excluded_checksums: HashSet<String>,
+}
+
+impl GoldenFiles {
+    /// Golden files stored in `golden_dir`
+    #[must_use]
+    pub fn new(golden_dir: impl Into<PathBuf>) -> Self {
+        Self {
+            golden_dir: golden_dir.into(),
+            placeholders: Vec::new(),
+            excluded_checksums: HashSet::new(),
+        }
+    }
+
+    /// Replace `value` with `placeholder` in the rendered files
+    ///
+    /// Used for machine-specific values, such as absolute paths, so the
+    /// golden files are the same on every machine.
+    #[must_use]
+    pub fn with_placeholder(
+        mut self,
+        value: impl Into<String>,
+        placeholder: impl Into<String>,
+    ) -> Self {
+        self.placeholders.push((value.into(), placeholder.into()));
+        self
+    }
+
+    /// Leave out rendered files that are verbatim copies of a file in `dir`
+    ///
+    /// Static templates are copied to the build directory unchanged; they
+    /// are left out so the golden files only hold what was rendered.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `dir` cannot be read.
+    #[must_use]
+    pub fn excluding_copies_of(mut self, dir: &Path) -> Self {
+        let files =
+            read_tree(dir).unwrap_or_else(|e| panic!("Failed to read '{}': {e}", dir.display()));
+        self.excluded_checksums
+            .extend(files.values().map(|content| sha256_of(content)));
+        self
+    }
+
+    /// Assert that the files rendered to `actual_dir` match the golden files
+    ///
+    /// With `UPDATE_GOLDEN=1`, the golden directory is replaced with the
+    /// rendered files instead.
+    ///
+    /// # Panics
+    ///
+    /// Panics with a report of the missing, unexpected and modified files if
+    /// the rendered files differ from the golden ones, or if a directory
+    /// cannot be read or written.
+    pub fn assert_matches(&self, actual_dir: &Path) {
+        if std::env::var(UPDATE_GOLDEN_ENV).is_ok_and(|value| value == "1") {
+            self.update(actual_dir).unwrap_or_else(|e| {
+                panic!("Failed to update '{}': {e}", self.golden_dir.display())
+            });
+            return;
+        }
+
+        if let Err(report) = self.compare(actual_dir) {
+            panic!(
+                "Rendered files in '{}' differ from the golden files in '{}':\n\n{report}\nIf the change is intended, run the test again with {UPDATE_GOLDEN_ENV}=1 and review the golden files.",
+                actual_dir.display(),
+                self.golden_dir.display()
+            );
+        }
+    }
+
+    /// Compare the rendered files with the golden files, returning the differences
+    fn compare(&self, actual_dir: &Path) -> Result<(), String> {
+        let actual = self.rendered_files(actual_dir).map_err(|e| e.to_string())?;
+        let golden = read_tree(&self.golden_dir).map_err(|e| {
+            format!("cannot read the golden files: {e} (create them with {UPDATE_GOLDEN_ENV}=1)")
+        })?;
+        let mut report = String::new();
+
+        for path in golden.keys().filter(|path| !actual.contains_key(*path)) {
+            let _ = writeln!(report, "missing: {path}");
+        }
+        for path in actual.keys().filter(|path| !golden.contains_key(*path)) {
+            let _ = writeln!(report, "unexpected: {path}");
+        }
+        for (path, actual_content) in &actual {
+            let Some(golden_content) = golden.get(path) else {
+                continue;
+            };
+            if golden_content == actual_content {
+                continue;
+            }
+            let diff = TextDiff::from_lines(
+                &String::from_utf8_lossy(golden_content),
+                &String::from_utf8_lossy(actual_content),
+            )
+            .unified_diff()
+            .header(&format!("{path} (golden)"), &format!("{path} (rendered)"))
+            .to_string();
+            let _ = writeln!(report, "modified: {path}\n{diff}");
+        }
+
+        if report.is_empty() {
+            Ok(())
+        } else {
+            Err(report)
+        }
+    }
+
+    /// Replace the golden directory with the rendered files
+    fn update(&self, actual_dir: &Path) -> std::io::Result<()> {
+        let actual = self.rendered_files(actual_dir)?;
+
+        if self.golden_dir.exists() {
+            std::fs::remove_dir_all(&self.golden_dir)?;
+        }
+        for (path, content) in actual {
+            let target = self.golden_dir.join(path);
+            if let Some(parent) = target.parent() {
+                std::fs::create_dir_all(parent)?;
+            }
+            std::fs::write(target, content)?;
+        }
+
+        Ok(())
+    }
+
+    /// Rendered files under `actual_dir`, without static copies and with placeholders applied
+    fn rendered_files(&self, actual_dir: &Path) -> std::io::Result<BTreeMap<String, Vec<u8>>> {
+        let mut files = read_tree(actual_dir)?;
+        files.retain(|_, content| !self.excluded_checksums.contains(&sha256_of(content)));
+
+        for content in files.values_mut() {
+            if let Ok(text) = std::str::from_utf8(content) {
+                let mut text = text.to_string();
+                for (value, placeholder) in &self.placeholders {
+                    text = text.replace(value.as_str(), placeholder);
+                }
+                *content = text.into_bytes();
+            }
+        }
+
+        Ok(files)
+    }
+}
+
+/// Every file under `dir`, by its `/`-separated path relative to `dir`
+fn read_tree(dir: &Path) -> std::io::Result<BTreeMap<String, Vec<u8>>> {
+    let mut files = BTreeMap::new();
+    let mut pending = vec![dir.to_path_buf()];
+
+    while let Some(current) = pending.pop() {
+        for entry in std::fs::read_dir(&current)? {
+            let path = entry?.path();
+            if path.is_dir() {
+                pending.push(path);
+            } else {
+                let relative = path
+                    .strip_prefix(dir)
+                    .unwrap_or(&path)
+                    .components()
+                    .map(|component| component.as_os_str().to_string_lossy())
+                    .collect::<Vec<_>>()
+                    .join("/");
+                files.insert(relative, std::fs::read(&path)?);
+            }
+        }
+    }
+
+    Ok(files)
+}
+
+/// SHA-256 checksum of `content`, in lowercase hex
+fn sha256_of(content: &[u8]) -> String {
+    format!("{:x}", Sha256::digest(content))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn write(dir: &Path, path: &str, content: &str) {
+        let target = dir.join(path);
+        std::fs::create_dir_all(target.parent().unwrap()).unwrap();
+        std::fs::write(target, content).unwrap();
+    }
+
+    #[test]
+    fn it_should_accept_rendered_files_identical_to_the_golden_files() {
+        let golden = tempfile::tempdir().unwrap();
+        let actual = tempfile::tempdir().unwrap();
+        write(golden.path(), "tracker/tracker.toml", "[core]\n");
+        write(actual.path(), "tracker/tracker.toml", "[core]\n");
+
+        assert!(GoldenFiles::new(golden.path())
+            .compare(actual.path())
+            .is_ok());
+    }
+
+    #[test]
+    fn it_should_report_a_unified_diff_of_a_modified_file() {
+        let golden = tempfile::tempdir().unwrap();
+        let actual = tempfile::tempdir().unwrap();
+        write(golden.path(), "docker-compose/.env", "A=1\nB=2\n");
+        write(actual.path(), "docker-compose/.env", "A=1\nB=3\n");
+
+        let report = GoldenFiles::new(golden.path())
+            .compare(actual.path())
+            .unwrap_err();
+
+        assert!(report.contains("modified: docker-compose/.env"));
+        assert!(report.contains("-B=2\n+B=3"));
+    }
+
+    #[test]
+    fn it_should_report_missing_and_unexpected_files() {
+        let golden = tempfile::tempdir().unwrap();
+        let actual = tempfile::tempdir().unwrap();
+        write(golden.path(), "caddy/Caddyfile", "");
+        write(actual.path(), "prometheus/prometheus.yml", "");
+
+        let report = GoldenFiles::new(golden.path())
+            .compare(actual.path())
+            .unwrap_err();
+
+        assert!(report.contains("missing: caddy/Caddyfile"));
+        assert!(report.contains("unexpected: prometheus/prometheus.yml"));
+    }
+
+    #[test]
+    fn it_should_leave_out_copies_of_static_templates_and_apply_placeholders() {
+        let templates = tempfile::tempdir().unwrap();
+        let golden = tempfile::tempdir().unwrap();
+        let actual = tempfile::tempdir().unwrap();
+        write(
+            templates.path(),
+            "ansible/install-docker.yml",
+            "- hosts: all\n",
+        );
+        write(
+            actual.path(),
+            "ansible/install-docker.yml",
+            "- hosts: all\n",
+        );
+        write(
+            actual.path(),
+            "ansible/inventory.yml",
+            "key: /home/ci/repo/fixtures/testing_rsa\n",
+        );
+        write(
+            golden.path(),
+            "ansible/inventory.yml",
+            "key: <project-root>/fixtures/testing_rsa\n",
+        );
+
+        let result = GoldenFiles::new(golden.path())
+            .with_placeholder("/home/ci/repo", "<project-root>")
+            .excluding_copies_of(templates.path())
+            .compare(actual.path());
+
+        assert!(result.is_ok(), "{}", result.unwrap_err());
+    }
+
+    #[test]
+    fn it_should_replace_the_golden_files_with_the_rendered_files_in_update_mode() {
+        let golden = tempfile::tempdir().unwrap();
+        let actual = tempfile::tempdir().unwrap();
+        write(golden.path(), "stale.yml", "");
+        write(
+            actual.path(),
+            "tofu/lxd/variables.tfvars",
+            "instance_name = \"vm\"\n",
+        );
+        let golden_files = GoldenFiles::new(golden.path());
+
+        golden_files.update(actual.path()).unwrap();
+
+        assert!(golden_files.compare(actual.path()).is_ok());
+        assert!(!golden.path().join("stale.yml").exists());
+    }
+}
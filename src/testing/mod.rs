@@ -8,12 +8,14 @@
 //! - `e2e` - End-to-end testing infrastructure and scenarios (includes black-box CLI testing)
 //! - `integration` - Integration testing utilities
 //! - `fixtures` - Reusable test fixtures
+//! - `golden` - Golden-file assertions for rendered artifacts (`UPDATE_GOLDEN=1` to update them)
 //! - `mock_clock` - Mock clock implementation for deterministic time testing
 //! - `network` - Network testing utilities (port checking, connectivity testing)
 //! - `recording_progress_listener` - Records progress events for test assertions
 
 pub mod e2e;
 pub mod fixtures;
+pub mod golden;
 pub mod integration;
 pub mod mock_clock;
 pub mod network;
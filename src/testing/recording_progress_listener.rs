@@ -8,7 +8,7 @@ use std::sync::Arc;
 
 use parking_lot::Mutex;
 
-use crate::application::traits::CommandProgressListener;
+use crate::application::traits::{CommandProgressListener, ExternalCommandOutputLine};
 
 /// A recorded progress event from a command handler.
 ///
@@ -32,6 +32,8 @@ pub enum ProgressEvent {
     Detail { message: String },
     /// Recorded from `on_debug(message)`
     Debug { message: String },
+    /// Recorded from `on_external_command_output_line(output_line)`
+    OutputLine { program: String, line: String },
 }
 
 /// A test double that records all progress events for later assertion.
@@ -137,6 +139,13 @@ impl CommandProgressListener for RecordingProgressListener {
             message: message.to_string(),
         });
     }
+
+    fn on_external_command_output_line(&self, output_line: &ExternalCommandOutputLine<'_>) {
+        self.events.lock().push(ProgressEvent::OutputLine {
+            program: output_line.program.to_string(),
+            line: output_line.line.to_string(),
+        });
+    }
 }
 
 #[cfg(test)]
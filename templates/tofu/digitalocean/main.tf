@@ -0,0 +1,244 @@
+# ============================================================================
+# Torrust Tracker Deployer - Generated Configuration
+# ============================================================================
+#
+# This file was generated by the Torrust Tracker Deployer.
+#
+# DOCUMENTATION:
+#   Repository:    https://github.com/torrust/torrust-tracker-deployer
+#   Template:      templates/tofu/digitalocean/main.tf
+#   API Docs:      https://docs.rs/torrust-tracker-deployer/latest/
+#
+# DESCRIPTION:
+#   OpenTofu main configuration for DigitalOcean provider.
+#   Defines the droplet, SSH key, cloud firewall, optional reserved IP
+#   assignment and cloud-init configuration for DigitalOcean deployments.
+#
+# For configuration options and valid values, see the API documentation link above.
+# ============================================================================
+
+# This is the main OpenTofu configuration for deploying Torrust Tracker
+# environments to DigitalOcean.
+#
+# Resources created:
+# - SSH key: Imported from local keypair for secure access
+# - Droplet: DigitalOcean droplet running Ubuntu with cloud-init configuration
+# - Firewall: DigitalOcean cloud firewall opening only SSH and the ports of enabled services
+# - Reserved IP assignment: Only when an existing reserved IP is configured
+#
+# Dependencies:
+# - variables.tfvars: Runtime variables (API token, droplet settings, SSH config)
+# - cloud-init.yml: Droplet initialization script (rendered from template)
+
+terraform {
+  required_providers {
+    digitalocean = {
+      source  = "digitalocean/digitalocean"
+      version = "~> 2.40"
+    }
+  }
+  required_version = ">= 1.0"
+}
+
+# Configure the DigitalOcean provider with the API token from variables
+provider "digitalocean" {
+  token = var.do_token
+}
+
+# ============================================================================
+# Variables
+# ============================================================================
+
+variable "do_token" {
+  description = "DigitalOcean API token for authentication"
+  type        = string
+  sensitive   = true
+}
+
+variable "ssh_public_key" {
+  description = "Public SSH key content for droplet access"
+  type        = string
+}
+
+variable "ssh_key_name" {
+  description = "Name for the SSH key resource in DigitalOcean"
+  type        = string
+}
+
+variable "droplet_name" {
+  description = "Name for the droplet"
+  type        = string
+}
+
+variable "droplet_size" {
+  description = "DigitalOcean droplet size slug (e.g., s-1vcpu-2gb, s-2vcpu-4gb)"
+  type        = string
+}
+
+variable "droplet_image" {
+  description = "Operating system image slug for the droplet"
+  type        = string
+  default     = "ubuntu-24-04-x64"
+}
+
+variable "droplet_region" {
+  description = "DigitalOcean region slug (e.g., fra1, ams3, nyc3)"
+  type        = string
+}
+
+variable "droplet_tags" {
+  description = "Tags to apply to the droplet for organization"
+  type        = list(string)
+  default     = []
+}
+
+variable "reserved_ip" {
+  description = "Existing reserved IP to assign to the droplet (empty for none)"
+  type        = string
+  default     = ""
+}
+
+variable "firewall_rules" {
+  description = "Inbound ports to open in the cloud firewall (derived from the enabled services plus extra allow rules)"
+  type = list(object({
+    protocol         = string
+    port             = string
+    description      = string
+    source_addresses = list(string)
+  }))
+  default = []
+}
+
+# ============================================================================
+# Resources
+# ============================================================================
+
+# Register the SSH public key in the DigitalOcean account
+#
+# The key is added to the root user of the droplet as a recovery path when
+# cloud-init fails. The torrust user gets the same key through cloud-init.
+resource "digitalocean_ssh_key" "torrust" {
+  name       = var.ssh_key_name
+  public_key = var.ssh_public_key
+}
+
+# Create the DigitalOcean droplet
+#
+# OpenTofu waits for the droplet to become active before creating the
+# resources that depend on it and reading its addresses.
+resource "digitalocean_droplet" "torrust" {
+  name   = var.droplet_name
+  image  = var.droplet_image
+  size   = var.droplet_size
+  region = var.droplet_region
+  tags   = var.droplet_tags
+  ipv6   = true
+
+  ssh_keys = [
+    digitalocean_ssh_key.torrust.fingerprint
+  ]
+
+  # Cloud-init configuration for initial droplet setup
+  user_data = file("${path.module}/cloud-init.yml")
+}
+
+# Cloud firewall in front of the droplet
+#
+# Only SSH and the ports exposed by the enabled services are open. The rules
+# are generated by the deployer from the same port derivation used for the
+# Docker Compose port bindings, so they follow the environment configuration
+# (e.g. ports proxied through Caddy are not opened directly).
+#
+# DigitalOcean firewalls also filter outbound traffic: the droplet needs it
+# for package installation, image pulls and certificate acquisition.
+resource "digitalocean_firewall" "torrust" {
+  name        = "${var.droplet_name}-firewall"
+  droplet_ids = [digitalocean_droplet.torrust.id]
+
+  inbound_rule {
+    protocol         = "icmp"
+    source_addresses = ["0.0.0.0/0", "::/0"]
+  }
+
+  dynamic "inbound_rule" {
+    for_each = var.firewall_rules
+    content {
+      protocol         = inbound_rule.value.protocol
+      port_range       = inbound_rule.value.port
+      source_addresses = inbound_rule.value.source_addresses
+    }
+  }
+
+  outbound_rule {
+    protocol              = "tcp"
+    port_range            = "1-65535"
+    destination_addresses = ["0.0.0.0/0", "::/0"]
+  }
+
+  outbound_rule {
+    protocol              = "udp"
+    port_range            = "1-65535"
+    destination_addresses = ["0.0.0.0/0", "::/0"]
+  }
+
+  outbound_rule {
+    protocol              = "icmp"
+    destination_addresses = ["0.0.0.0/0", "::/0"]
+  }
+}
+
+# Assign the configured reserved IP to the droplet
+#
+# The reserved IP itself belongs to the account and is not managed here:
+# destroying the environment only unassigns it, so it can be reused.
+resource "digitalocean_reserved_ip_assignment" "torrust" {
+  count      = var.reserved_ip == "" ? 0 : 1
+  ip_address = var.reserved_ip
+  droplet_id = digitalocean_droplet.torrust.id
+}
+
+locals {
+  # Address the deployer connects to: the reserved IP when there is one
+  public_ipv4 = var.reserved_ip == "" ? digitalocean_droplet.torrust.ipv4_address : var.reserved_ip
+}
+
+# ============================================================================
+# Outputs
+# ============================================================================
+
+# IMPORTANT: This output is parsed by src/adapters/tofu/json_parser.rs
+# The output name "instance_info" and all fields (name, image, status, ipv4_address,
+# ipv6_address) are required by the parser and must remain present with these exact
+# names. An address the instance does not have is an empty string.
+output "instance_info" {
+  description = "Information about the created droplet"
+  value = {
+    name         = digitalocean_droplet.torrust.name
+    image        = digitalocean_droplet.torrust.image
+    status       = digitalocean_droplet.torrust.status
+    ipv4_address = local.public_ipv4
+    ipv6_address = digitalocean_droplet.torrust.ipv6_address
+  }
+  depends_on = [
+    digitalocean_firewall.torrust,
+    digitalocean_reserved_ip_assignment.torrust
+  ]
+}
+
+output "connection_commands" {
+  description = "Commands to connect to the droplet"
+  value = [
+    "ssh root@${local.public_ipv4}",
+    "doctl compute ssh ${var.droplet_name}"
+  ]
+}
+
+output "test_commands" {
+  description = "Commands to test the droplet functionality"
+  value = [
+    "doctl compute droplet get ${digitalocean_droplet.torrust.id}",
+    "doctl compute firewall list",
+    "ssh root@${local.public_ipv4} 'cat /etc/os-release'",
+    "ssh root@${local.public_ipv4} 'cloud-init status'"
+  ]
+}
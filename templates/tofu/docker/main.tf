@@ -0,0 +1,187 @@
+# ============================================================================
+# Torrust Tracker Deployer - Generated Configuration
+# ============================================================================
+#
+# This file was generated by the Torrust Tracker Deployer.
+#
+# DOCUMENTATION:
+#   Repository:    https://github.com/torrust/torrust-tracker-deployer
+#   Template:      templates/tofu/docker/main.tf
+#   API Docs:      https://docs.rs/torrust-tracker-deployer/latest/
+#
+# DESCRIPTION:
+#   OpenTofu main configuration for the Docker provider (testing only).
+#   Runs the instance as a local privileged container instead of a VM, with
+#   SSH published on a random loopback port and the service ports published
+#   on the same loopback ports.
+#
+# For configuration options and valid values, see the API documentation link above.
+# ============================================================================
+
+# This is the main OpenTofu configuration for deploying Torrust Tracker
+# environments into a local Docker container for fast smoke tests.
+#
+# Resources created:
+# - Image: Reference to the local instance image (built from docker/provisioned-instance)
+# - Container: Privileged container running sshd and dockerd, reachable through
+#   ports published on 127.0.0.1
+#
+# Dependencies:
+# - variables.tfvars: Runtime variables (instance name, image, SSH key, ports)
+#
+# The container does not run cloud-init: the image already contains the SSH
+# user and the cloud-init completion marker.
+
+terraform {
+  required_providers {
+    docker = {
+      source  = "kreuzwerker/docker"
+      version = "~> 3.0"
+    }
+  }
+  required_version = ">= 1.0"
+}
+
+# Configure the Docker provider
+provider "docker" {
+  # Use the local Docker daemon via unix socket
+}
+
+# ============================================================================
+# Variables
+# ============================================================================
+
+variable "instance_name" {
+  description = "Name of the container"
+  type        = string
+}
+
+variable "image" {
+  description = "Local Docker image of the instance"
+  type        = string
+  default     = "torrust-provisioned-instance:latest"
+}
+
+variable "ssh_public_key" {
+  description = "Public SSH key content authorized for the SSH user"
+  type        = string
+}
+
+variable "ssh_username" {
+  description = "SSH user existing in the image"
+  type        = string
+  default     = "torrust"
+}
+
+variable "ssh_port" {
+  description = "Port sshd listens on inside the container"
+  type        = number
+  default     = 22
+}
+
+variable "published_ports" {
+  description = "Service ports published on the same loopback port of the host"
+  type = list(object({
+    protocol    = string
+    port        = number
+    description = string
+  }))
+  default = []
+}
+
+# ============================================================================
+# Resources
+# ============================================================================
+
+# The image is built locally and must not be removed on destroy
+resource "docker_image" "torrust" {
+  name         = var.image
+  keep_locally = true
+}
+
+# Create the instance container
+#
+# Privileged so the tracker services can run in the Docker daemon of the
+# container, like they do on a VM.
+resource "docker_container" "torrust" {
+  name       = var.instance_name
+  hostname   = var.instance_name
+  image      = docker_image.torrust.image_id
+  privileged = true
+  restart    = "unless-stopped"
+
+  labels {
+    label = "managed-by"
+    value = "torrust-tracker-deployer"
+  }
+
+  # SSH is published on a random host port, read back from the outputs
+  ports {
+    internal = var.ssh_port
+    ip       = "127.0.0.1"
+    protocol = "tcp"
+  }
+
+  dynamic "ports" {
+    for_each = var.published_ports
+    content {
+      internal = ports.value.port
+      external = ports.value.port
+      ip       = "127.0.0.1"
+      protocol = ports.value.protocol
+    }
+  }
+
+  upload {
+    content     = "${var.ssh_public_key}\n"
+    file        = "/home/${var.ssh_username}/.ssh/authorized_keys"
+    permissions = "0600"
+  }
+}
+
+locals {
+  # Host port Docker picked for the SSH port of the container
+  published_ssh_port = one([
+    for port in docker_container.torrust.ports : port.external
+    if port.internal == var.ssh_port && port.protocol == "tcp"
+  ])
+}
+
+# ============================================================================
+# Outputs
+# ============================================================================
+
+# IMPORTANT: This output is parsed by src/adapters/tofu/json_parser.rs
+# The output name "instance_info" and all fields (name, image, status, ipv4_address,
+# ipv6_address) are required by the parser and must remain present with these exact
+# names. An address the instance does not have is an empty string.
+# The optional ssh_port field overrides the configured SSH port for connections.
+output "instance_info" {
+  description = "Information about the created container"
+  value = {
+    name         = docker_container.torrust.name
+    image        = var.image
+    status       = "running"
+    ipv4_address = "127.0.0.1"
+    ipv6_address = ""
+    ssh_port     = local.published_ssh_port
+  }
+  depends_on = [docker_container.torrust]
+}
+
+output "connection_commands" {
+  description = "Commands to connect to the container"
+  value = [
+    "ssh -p ${local.published_ssh_port} ${var.ssh_username}@127.0.0.1",
+    "docker exec -it ${var.instance_name} /bin/bash"
+  ]
+}
+
+output "test_commands" {
+  description = "Commands to test the container functionality"
+  value = [
+    "docker ps --filter name=${var.instance_name}",
+    "docker exec ${var.instance_name} cat /etc/os-release",
+    "docker exec ${var.instance_name} supervisorctl status"
+  ]
+}
@@ -11,7 +11,7 @@
 #
 # DESCRIPTION:
 #   OpenTofu main configuration for Hetzner Cloud provider.
-#   Defines cloud servers, SSH keys, and cloud-init configuration for Hetzner deployments.
+#   Defines cloud servers, SSH keys, firewall, and cloud-init configuration for Hetzner deployments.
 #
 # For configuration options and valid values, see the API documentation link above.
 # ============================================================================
@@ -21,6 +21,7 @@
 #
 # Resources created:
 # - SSH key: Imported from local keypair for secure access
+# - Firewall: Hetzner Cloud firewall opening only SSH and the ports of enabled services
 # - Server: Hetzner Cloud server running Ubuntu with cloud-init configuration
 #
 # Dependencies:
@@ -89,6 +90,17 @@ variable "server_labels" {
   default     = {}
 }
 
+variable "firewall_rules" {
+  description = "Inbound ports to open in the cloud firewall (derived from the enabled services plus extra allow rules)"
+  type = list(object({
+    protocol    = string
+    port        = string
+    description = string
+    source_ips  = list(string)
+  }))
+  default = []
+}
+
 # ============================================================================
 # Resources
 # ============================================================================
@@ -115,6 +127,35 @@ resource "hcloud_ssh_key" "torrust" {
   public_key = var.ssh_public_key
 }
 
+# Cloud firewall in front of the server
+#
+# Only SSH and the ports exposed by the enabled services are open. The rules
+# are generated by the deployer from the same port derivation used for the
+# Docker Compose port bindings, so they follow the environment configuration
+# (e.g. ports proxied through Caddy are not opened directly).
+resource "hcloud_firewall" "torrust" {
+  name   = "${var.server_name}-firewall"
+  labels = var.server_labels
+
+  rule {
+    direction   = "in"
+    protocol    = "icmp"
+    source_ips  = ["0.0.0.0/0", "::/0"]
+    description = "ICMP (ping)"
+  }
+
+  dynamic "rule" {
+    for_each = var.firewall_rules
+    content {
+      direction   = "in"
+      protocol    = rule.value.protocol
+      port        = rule.value.port
+      source_ips  = rule.value.source_ips
+      description = rule.value.description
+    }
+  }
+}
+
 # Create the Hetzner Cloud server
 resource "hcloud_server" "torrust" {
   name        = var.server_name
@@ -127,6 +168,10 @@ resource "hcloud_server" "torrust" {
     hcloud_ssh_key.torrust.id
   ]
 
+  firewall_ids = [
+    hcloud_firewall.torrust.id
+  ]
+
   # Cloud-init configuration for initial server setup
   user_data = file("${path.module}/cloud-init.yml")
 
@@ -139,15 +184,17 @@ resource "hcloud_server" "torrust" {
 # ============================================================================
 
 # IMPORTANT: This output is parsed by src/adapters/tofu/json_parser.rs
-# The output name "instance_info" and all fields (name, image, status, ip_address)
-# are required by the parser and must remain present with these exact names.
+# The output name "instance_info" and all fields (name, image, status, ipv4_address,
+# ipv6_address) are required by the parser and must remain present with these exact
+# names. An address the instance does not have is an empty string.
 output "instance_info" {
   description = "Information about the created server"
   value = {
-    name       = hcloud_server.torrust.name
-    image      = hcloud_server.torrust.image
-    status     = hcloud_server.torrust.status
-    ip_address = hcloud_server.torrust.ipv4_address
+    name         = hcloud_server.torrust.name
+    image        = hcloud_server.torrust.image
+    status       = hcloud_server.torrust.status
+    ipv4_address = hcloud_server.torrust.ipv4_address
+    ipv6_address = hcloud_server.torrust.ipv6_address
   }
   depends_on = [hcloud_server.torrust]
 }
@@ -0,0 +1,173 @@
+# ============================================================================
+# Torrust Tracker Deployer - Generated Configuration
+# ============================================================================
+#
+# This file was generated by the Torrust Tracker Deployer.
+#
+# DOCUMENTATION:
+#   Repository:    https://github.com/torrust/torrust-tracker-deployer
+#   Template:      templates/tofu/libvirt/main.tf
+#   API Docs:      https://docs.rs/torrust-tracker-deployer/latest/
+#
+# DESCRIPTION:
+#   OpenTofu main configuration for libvirt provider.
+#   Defines the KVM virtual machine, its disk created from a cloud image and
+#   the cloud-init ISO for local libvirt deployments.
+#
+# For configuration options and valid values, see the API documentation link above.
+# ============================================================================
+
+terraform {
+  required_providers {
+    libvirt = {
+      source  = "dmacvicar/libvirt"
+      version = "~> 0.8.0"
+    }
+  }
+  required_version = ">= 1.0"
+}
+
+# Configure the libvirt Provider
+# Uses the system daemon via its unix socket
+provider "libvirt" {
+  uri = var.uri
+}
+
+# Variables
+variable "instance_name" {
+  description = "Name of the libvirt domain"
+  type        = string
+  default     = "torrust-tracker-vm"
+}
+
+variable "uri" {
+  description = "Connection URI of the libvirt daemon"
+  type        = string
+  default     = "qemu:///system"
+}
+
+variable "pool" {
+  description = "Storage pool of the instance disk and cloud-init ISO"
+  type        = string
+  default     = "default"
+}
+
+variable "network_name" {
+  description = "Libvirt network the instance is attached to, with DHCP enabled"
+  type        = string
+  default     = "default"
+}
+
+variable "image" {
+  description = "Cloud image the instance disk is created from: a path on the host or an HTTP(S) URL"
+  type        = string
+  default     = "https://cloud-images.ubuntu.com/noble/current/noble-server-cloudimg-amd64.img"
+}
+
+variable "cpus" {
+  description = "Number of virtual CPUs of the instance"
+  type        = number
+  default     = 2
+}
+
+variable "memory_mb" {
+  description = "Memory of the instance, in MiB"
+  type        = number
+  default     = 2048
+}
+
+variable "disk_gb" {
+  description = "Root disk size of the instance, in GiB"
+  type        = number
+  default     = 10
+}
+
+# Base volume holding the cloud image, shared by nothing but this instance so
+# that destroying the environment leaves the pool clean
+resource "libvirt_volume" "base" {
+  name   = "${var.instance_name}-base.qcow2"
+  pool   = var.pool
+  source = var.image
+  format = "qcow2"
+}
+
+# Copy-on-write root disk on top of the cloud image, grown to the disk size
+resource "libvirt_volume" "root" {
+  name           = "${var.instance_name}.qcow2"
+  pool           = var.pool
+  base_volume_id = libvirt_volume.base.id
+  size           = var.disk_gb * 1024 * 1024 * 1024
+  format         = "qcow2"
+}
+
+# Cloud-init ISO with the shared provider-agnostic user data
+resource "libvirt_cloudinit_disk" "cloud_init" {
+  name      = "${var.instance_name}-cloud-init.iso"
+  pool      = var.pool
+  user_data = file("${path.module}/cloud-init.yml")
+  meta_data = yamlencode({
+    "instance-id"    = var.instance_name
+    "local-hostname" = var.instance_name
+  })
+}
+
+# Create the KVM virtual machine
+resource "libvirt_domain" "torrust_vm" {
+  name      = var.instance_name
+  vcpu      = var.cpus
+  memory    = var.memory_mb
+  autostart = true
+  cloudinit = libvirt_cloudinit_disk.cloud_init.id
+
+  cpu {
+    mode = "host-passthrough"
+  }
+
+  disk {
+    volume_id = libvirt_volume.root.id
+  }
+
+  # Wait for the DHCP lease so that the address is part of the state
+  network_interface {
+    network_name   = var.network_name
+    wait_for_lease = true
+  }
+
+  # Ubuntu cloud images log to the serial console during boot
+  console {
+    type        = "pty"
+    target_type = "serial"
+    target_port = "0"
+  }
+}
+
+locals {
+  addresses = try(libvirt_domain.torrust_vm.network_interface[0].addresses, [])
+}
+
+# Output information about the virtual machine
+# IMPORTANT: This output is parsed by src/opentofu/json_parser.rs
+# The output name "instance_info" and all fields (name, image, status, ipv4_address,
+# ipv6_address) are required by the parser and must remain present with these exact
+# names. An address the instance does not have is an empty string: the deployer
+# then asks libvirt for the DHCP lease of the domain (virsh domifaddr).
+output "instance_info" {
+  description = "Information about the created virtual machine"
+  value = {
+    name         = libvirt_domain.torrust_vm.name
+    image        = var.image
+    status       = libvirt_domain.torrust_vm.running ? "Running" : "Stopped"
+    ipv4_address = try([for address in local.addresses : address if length(regexall(":", address)) == 0][0], "")
+    ipv6_address = try([for address in local.addresses : address if length(regexall(":", address)) > 0][0], "")
+  }
+  depends_on = [libvirt_domain.torrust_vm]
+}
+
+output "connection_commands" {
+  description = "Commands to inspect the virtual machine"
+  value = [
+    "virsh --connect ${var.uri} dominfo ${var.instance_name}",
+    "virsh --connect ${var.uri} domifaddr ${var.instance_name}",
+    "virsh --connect ${var.uri} console ${var.instance_name}"
+  ]
+}
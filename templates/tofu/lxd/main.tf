@@ -27,8 +27,20 @@ terraform {
 }
 
 # Configure the LXD Provider
+# Uses the local LXD daemon via unix socket, unless a remote is set
 provider "lxd" {
-  # Use local LXD daemon via unix socket
+  config_dir = var.config_dir != "" ? var.config_dir : null
+
+  # A remote not configured in the LXD client; the client certificate must
+  # already be trusted by the server (the deployer adds the remote with its
+  # trust token before provisioning)
+  dynamic "remote" {
+    for_each = var.remote_address != "" ? [var.remote] : []
+    content {
+      name    = remote.value
+      address = var.remote_address
+    }
+  }
 }
 
 # Variables
@@ -50,14 +62,51 @@ variable "image" {
   default     = "ubuntu:24.04"
 }
 
+variable "cpus" {
+  description = "Number of virtual CPUs of the instance"
+  type        = number
+  default     = 2
+}
+
+variable "memory_mb" {
+  description = "Memory limit of the instance, in MiB"
+  type        = number
+  default     = 2048
+}
+
+variable "disk_gb" {
+  description = "Root disk size of the instance, in GiB"
+  type        = number
+  default     = 10
+}
+
+variable "remote" {
+  description = "Name of the LXD remote the instance is created on, empty for the local daemon"
+  type        = string
+  default     = ""
+}
+
+variable "remote_address" {
+  description = "HTTPS address of the LXD remote, empty when the LXD client configuration has it"
+  type        = string
+  default     = ""
+}
+
+variable "config_dir" {
+  description = "Directory of the LXD client configuration, empty for the default one"
+  type        = string
+  default     = ""
+}
+
 # Create a profile for our container with cloud-init support
 resource "lxd_profile" "torrust_profile" {
-  name = var.profile_name
+  name   = var.profile_name
+  remote = var.remote != "" ? var.remote : null
 
   config = {
     "user.user-data" = file("${path.module}/cloud-init.yml")
-    "limits.memory"  = "2GB"
-    "limits.cpu"     = "2"
+    "limits.memory"  = "${var.memory_mb}MiB"
+    "limits.cpu"     = tostring(var.cpus)
   }
 
   device {
@@ -66,7 +115,7 @@ resource "lxd_profile" "torrust_profile" {
     properties = {
       path = "/"
       pool = "default"
-      size = "10GB"
+      size = "${var.disk_gb}GiB"
     }
   }
 
@@ -86,6 +135,7 @@ resource "lxd_instance" "torrust_vm" {
   image     = var.image
   type      = "virtual-machine"
   profiles  = [lxd_profile.torrust_profile.name]
+  remote    = var.remote != "" ? var.remote : null
 
   config = {
     "boot.autostart"      = "true"
@@ -98,15 +148,17 @@ resource "lxd_instance" "torrust_vm" {
 
 # Output information about the container
 # IMPORTANT: This output is parsed by src/opentofu/json_parser.rs
-# The output name "instance_info" and all fields (name, image, status, ip_address)
-# are required by the parser and must remain present with these exact names.
+# The output name "instance_info" and all fields (name, image, status, ipv4_address,
+# ipv6_address) are required by the parser and must remain present with these exact
+# names. An address the instance does not have is an empty string.
 output "instance_info" {
   description = "Information about the created container"
   value = {
-    name       = lxd_instance.torrust_vm.name
-    image      = lxd_instance.torrust_vm.image
-    status     = lxd_instance.torrust_vm.status
-    ip_address = lxd_instance.torrust_vm.ipv4_address
+    name         = lxd_instance.torrust_vm.name
+    image        = lxd_instance.torrust_vm.image
+    status       = lxd_instance.torrust_vm.status
+    ipv4_address = lxd_instance.torrust_vm.ipv4_address
+    ipv6_address = lxd_instance.torrust_vm.ipv6_address
   }
   depends_on = [lxd_instance.torrust_vm]
 }
@@ -18,7 +18,9 @@
 //! 1. Happy path: Create environment from valid config file
 //! 2. Invalid config: Graceful failure with validation errors
 //! 3. Missing config file: Appropriate error when file not found
-//! 4. Duplicate detection: Error when environment already exists
+//! 4. Duplicate detection: Success when the environment already exists with the
+//!    same configuration, error when it exists with another one
+//! 5. Quiet mode: Only the JSON result is printed, without escape sequences
 
 use super::super::support::{process_runner, EnvironmentStateAssertions, TempWorkspace};
 use anyhow::Result;
@@ -47,7 +49,7 @@ use torrust_tracker_deployer_lib::testing::e2e::tasks::black_box::create_test_en
 fn verify_required_dependencies() -> Result<()> {
     // Currently no system dependencies required - empty array
     let required_deps: &[Dependency] = &[];
-    verify_dependencies(required_deps)?;
+    verify_dependencies(required_deps, false)?;
     Ok(())
 }
 
@@ -120,6 +122,18 @@ fn it_should_fail_gracefully_with_invalid_config() {
         stderr.contains("missing field") || stderr.contains("Configuration"),
         "Error message should mention configuration issues, got: {stderr}"
     );
+
+    // Verify the timing summary marks the failing step
+    let timings = result
+        .timing_summary()
+        .expect("Expected the step timings summary on stderr");
+    assert_eq!(timings["status"], "failed");
+    let steps = timings["steps"].as_array().expect("Expected the step list");
+    assert_eq!(
+        steps.last().map(|step| &step["status"]),
+        Some(&serde_json::json!("failed")),
+        "The last recorded step should be the failing one, got: {timings}"
+    );
 }
 
 #[test]
@@ -151,7 +165,7 @@ fn it_should_fail_when_config_file_not_found() {
 }
 
 #[test]
-fn it_should_fail_when_environment_already_exists() {
+fn it_should_succeed_when_environment_already_exists_with_the_same_configuration() {
     // Verify dependencies before running tests
     verify_required_dependencies().expect("Dependency verification failed");
 
@@ -175,26 +189,77 @@ fn it_should_fail_when_environment_already_exists() {
         result1.stderr()
     );
 
-    // Try to create same environment again
+    // Create same environment again
     let result2 = process_runner()
         .working_dir(temp_workspace.path())
         .log_dir(temp_workspace.path().join("logs"))
         .run_create_command("./config.json")
         .expect("Failed to run create command");
 
-    // Assert second create failed
+    assert!(
+        result2.success(),
+        "Second create with the same configuration should succeed, stderr: {}",
+        result2.stderr()
+    );
+    assert!(
+        result2
+            .stderr()
+            .contains("already exists with the same configuration"),
+        "Output should mention the existing environment, got: {}",
+        result2.stderr()
+    );
+}
+
+#[test]
+fn it_should_fail_when_environment_already_exists_with_another_configuration() {
+    // Verify dependencies before running tests
+    verify_required_dependencies().expect("Dependency verification failed");
+
+    let temp_workspace = TempWorkspace::new().expect("Failed to create temp workspace");
+
+    let config = create_test_environment_config("duplicate-env");
+    temp_workspace
+        .write_config_file("config.json", &config)
+        .expect("Failed to write config");
+
+    let mut changed_config: serde_json::Value =
+        serde_json::from_str(&config).expect("Failed to parse config");
+    changed_config["tracker"]["core"]["private"] = serde_json::Value::Bool(true);
+    temp_workspace
+        .write_config_file("changed.json", &changed_config.to_string())
+        .expect("Failed to write config");
+
+    // Create environment first time
+    let result1 = process_runner()
+        .working_dir(temp_workspace.path())
+        .log_dir(temp_workspace.path().join("logs"))
+        .run_create_command("./config.json")
+        .expect("Failed to run create command");
+
+    assert!(
+        result1.success(),
+        "First create should succeed, stderr: {}",
+        result1.stderr()
+    );
+
+    // Try to create same environment with another configuration
+    let result2 = process_runner()
+        .working_dir(temp_workspace.path())
+        .log_dir(temp_workspace.path().join("logs"))
+        .run_create_command("./changed.json")
+        .expect("Failed to run create command");
+
     assert!(
         !result2.success(),
-        "Second create should fail with duplicate environment"
+        "Second create with another configuration should fail"
     );
 
-    // Verify error message mentions duplicate or already exists
+    // Verify error message lists the differing setting
     let stderr = result2.stderr();
     assert!(
-        stderr.contains("Already Exists")
-            || stderr.contains("already exists")
-            || stderr.contains("AlreadyExists"),
-        "Error message should mention environment already exists, got: {stderr}"
+        stderr.contains("already exists with a different configuration")
+            && stderr.contains("tracker.core.private (false -> true)"),
+        "Error message should list the differing setting, got: {stderr}"
     );
 }
 
@@ -235,4 +300,58 @@ fn it_should_produce_json_by_default() {
         json.get("environment_name").is_some(),
         "Expected `environment_name` field in create JSON output, got: {stdout}"
     );
+
+    // Assert: The command ends with its step timings
+    let timings = result
+        .timing_summary()
+        .expect("Expected the step timings summary on stderr");
+    assert_eq!(
+        timings["status"], "succeeded",
+        "Unexpected timing summary: {timings}"
+    );
+    assert!(
+        timings["steps"]
+            .as_array()
+            .is_some_and(|steps| !steps.is_empty()),
+        "Expected the timings of the create steps, got: {timings}"
+    );
+}
+
+#[test]
+fn it_should_only_print_the_result_when_quiet() {
+    // Verify dependencies before running tests
+    verify_required_dependencies().expect("Dependency verification failed");
+
+    // Arrange: Create a valid environment config file in a temp workspace
+    let temp_workspace = TempWorkspace::new().expect("Failed to create temp workspace");
+    let config = create_test_environment_config("test-create-quiet");
+    temp_workspace
+        .write_config_file("environment.json", &config)
+        .expect("Failed to write config file");
+    let config_path = temp_workspace.path().join("environment.json");
+
+    // Act: Run create command with --quiet and piped output
+    let result = process_runner()
+        .global_arg("--quiet")
+        .working_dir(temp_workspace.path())
+        .log_dir(temp_workspace.path().join("logs"))
+        .run_create_command(config_path.to_str().unwrap())
+        .expect("Failed to run create command");
+
+    // Assert: Command succeeds without any progress output
+    assert!(
+        result.success(),
+        "Create command should succeed with a valid config, stderr: {}",
+        result.stderr()
+    );
+    assert_eq!(result.stderr(), "", "Quiet mode should suppress stderr");
+
+    // Assert: stdout only contains the JSON result, without escape sequences
+    let stdout = result.stdout();
+    assert!(
+        !stdout.contains('\x1b'),
+        "Piped output should not contain escape sequences, got: {stdout:?}"
+    );
+    serde_json::from_str::<serde_json::Value>(&stdout)
+        .expect("Quiet create output must be the JSON result");
 }
@@ -0,0 +1,191 @@
+//! End-to-End Black Box Test for the `DigitalOcean` Provider
+//!
+//! Deploys a tracker on a real `DigitalOcean` droplet by running the production
+//! application as an external process, then destroys the droplet and checks
+//! through the `DigitalOcean` API that nothing was left behind.
+//!
+//! The test needs a `DigitalOcean` API token and creates billable resources,
+//! so it only runs when `TORRUST_TD_DIGITALOCEAN_API_TOKEN` is set. Without it
+//! the test returns early, which lets CI skip it.
+//!
+//! ```bash
+//! TORRUST_TD_DIGITALOCEAN_API_TOKEN=... cargo test --test e2e_integration digitalocean_workflow -- --nocapture
+//! ```
+//!
+//! ## Test Scenario
+//!
+//! `create` → `provision` → `configure` → `release` → `run` → `test` →
+//! `destroy`, then the droplet and its cloud firewall must be gone. If a step
+//! fails, the environment is still destroyed.
+
+use std::process::Command;
+
+use super::super::support::{process_runner, TempWorkspace};
+use anyhow::{bail, Context, Result};
+use torrust_tracker_deployer_dependency_installer::{verify_dependencies, Dependency};
+use torrust_tracker_deployer_lib::testing::e2e::tasks::black_box::create_digitalocean_test_environment_config;
+use torrust_tracker_deployer_lib::testing::e2e::{ProcessResult, ProcessRunner};
+
+/// Environment variable holding the `DigitalOcean` API token
+const DIGITALOCEAN_API_TOKEN_ENV: &str = "TORRUST_TD_DIGITALOCEAN_API_TOKEN";
+
+/// Name of the environment created by the test
+const ENVIRONMENT_NAME: &str = "e2e-digitalocean";
+
+/// Name of the droplet, also used as its tag (default instance name)
+const DROPLET_NAME: &str = "torrust-tracker-vm-e2e-digitalocean";
+
+/// Verify that all required dependencies are installed for the `DigitalOcean` E2E test.
+///
+/// **Current State**: `OpenTofu` provisions the droplet and Ansible configures it.
+///
+/// # Errors
+///
+/// Returns an error if any required dependencies are missing or cannot be detected.
+fn verify_required_dependencies() -> Result<()> {
+    let required_deps = &[Dependency::OpenTofu, Dependency::Ansible];
+    verify_dependencies(required_deps, false)?;
+    Ok(())
+}
+
+/// A deployment command run against the environment
+type Step = fn(&ProcessRunner, &str) -> Result<ProcessResult>;
+
+/// Destroys the environment when dropped so a failed step never leaves a paid droplet behind
+struct DestroyOnDrop<'a> {
+    runner: &'a ProcessRunner,
+    armed: bool,
+}
+
+impl Drop for DestroyOnDrop<'_> {
+    fn drop(&mut self) {
+        if !self.armed {
+            return;
+        }
+
+        match self.runner.run_destroy_command(ENVIRONMENT_NAME) {
+            Ok(result) if result.success() => {}
+            Ok(result) => eprintln!(
+                "Failed to destroy DigitalOcean environment '{ENVIRONMENT_NAME}', delete the droplet and firewall manually: {}",
+                result.stderr()
+            ),
+            Err(e) => eprintln!(
+                "Failed to run destroy for DigitalOcean environment '{ENVIRONMENT_NAME}', delete the droplet and firewall manually: {e}"
+            ),
+        }
+    }
+}
+
+/// Query the `DigitalOcean` API and return the parsed JSON response
+fn digitalocean_api_get(path: &str) -> Result<serde_json::Value> {
+    let token = std::env::var(DIGITALOCEAN_API_TOKEN_ENV)?;
+
+    let output = Command::new("curl")
+        .args([
+            "--silent",
+            "--fail",
+            "--header",
+            &format!("Authorization: Bearer {token}"),
+            &format!("https://api.digitalocean.com/v2/{path}"),
+        ])
+        .output()
+        .context("Failed to run curl")?;
+
+    if !output.status.success() {
+        bail!("DigitalOcean API request to '{path}' failed");
+    }
+
+    serde_json::from_slice(&output.stdout).context("Invalid DigitalOcean API response")
+}
+
+/// Verify that no droplet or cloud firewall of the environment remains
+fn assert_no_resources_left() -> Result<()> {
+    let droplets = digitalocean_api_get(&format!("droplets?tag_name={DROPLET_NAME}"))?;
+    let droplet_count = droplets["droplets"].as_array().map_or(0, Vec::len);
+    if droplet_count > 0 {
+        bail!("{droplet_count} droplet(s) tagged '{DROPLET_NAME}' still exist");
+    }
+
+    let firewall_name = format!("{DROPLET_NAME}-firewall");
+    let firewalls = digitalocean_api_get("firewalls?per_page=200")?;
+    let firewall_left = firewalls["firewalls"]
+        .as_array()
+        .into_iter()
+        .flatten()
+        .any(|firewall| firewall["name"] == firewall_name.as_str());
+    if firewall_left {
+        bail!("Firewall '{firewall_name}' still exists");
+    }
+
+    Ok(())
+}
+
+#[test]
+fn it_should_deploy_a_running_tracker_on_digitalocean_and_remove_everything_on_destroy() {
+    if std::env::var(DIGITALOCEAN_API_TOKEN_ENV).is_err() {
+        eprintln!("Skipping DigitalOcean E2E test: {DIGITALOCEAN_API_TOKEN_ENV} is not set");
+        return;
+    }
+
+    // Verify dependencies before running tests
+    verify_required_dependencies().expect("Dependency verification failed");
+
+    // Arrange: Create a workspace with a DigitalOcean configuration referencing the token variable
+    let temp_workspace = TempWorkspace::new().expect("Failed to create temp workspace");
+    let config =
+        create_digitalocean_test_environment_config(ENVIRONMENT_NAME, DIGITALOCEAN_API_TOKEN_ENV);
+    temp_workspace
+        .write_config_file("environment.json", &config)
+        .expect("Failed to write config file");
+
+    let runner = process_runner()
+        .working_dir(temp_workspace.path())
+        .log_dir(temp_workspace.path().join("logs"));
+
+    let create_result = runner
+        .run_create_command("./environment.json")
+        .expect("Failed to run create command");
+    assert!(
+        create_result.success(),
+        "Create command failed: {}",
+        create_result.stderr()
+    );
+
+    let mut destroy_guard = DestroyOnDrop {
+        runner: &runner,
+        armed: true,
+    };
+
+    // Act + Assert: Run every deployment step against the real droplet
+    let steps: [(&str, Step); 5] = [
+        ("provision", ProcessRunner::run_provision_command),
+        ("configure", ProcessRunner::run_configure_command),
+        ("release", ProcessRunner::run_release_command),
+        ("run", ProcessRunner::run_run_command),
+        ("test", ProcessRunner::run_test_command),
+    ];
+
+    for (name, step) in steps {
+        let result = step(&runner, ENVIRONMENT_NAME)
+            .unwrap_or_else(|e| panic!("Failed to run {name} command: {e}"));
+        assert!(
+            result.success(),
+            "{name} command failed: {}",
+            result.stderr()
+        );
+    }
+
+    // Act: Destroy the environment (retried by the guard if it fails)
+    let destroy_result = runner
+        .run_destroy_command(ENVIRONMENT_NAME)
+        .expect("Failed to run destroy command");
+    destroy_guard.armed = !destroy_result.success();
+    assert!(
+        destroy_result.success(),
+        "destroy command failed: {}",
+        destroy_result.stderr()
+    );
+
+    // Assert: The droplet and its firewall are gone
+    assert_no_resources_left().expect("DigitalOcean resources left after destroy");
+}
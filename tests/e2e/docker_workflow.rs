@@ -0,0 +1,198 @@
+//! End-to-End Black Box Test for the Docker Provider (fast profile)
+//!
+//! Deploys a tracker into a local Docker container by running the production
+//! application as an external process, then destroys the container. The
+//! Docker provider replaces the VM with a container of the
+//! `torrust-provisioned-instance` image, so the whole deployment takes a
+//! fraction of the time of the LXD workflow while running the same commands.
+//!
+//! The test needs Docker on the host and runs privileged containers, so it
+//! only runs when `TORRUST_TD_E2E_DOCKER` is set to `true`. Otherwise the test
+//! returns early, which lets CI skip it.
+//!
+//! ```bash
+//! TORRUST_TD_E2E_DOCKER=true cargo test --test e2e_integration docker_workflow -- --nocapture
+//! ```
+//!
+//! ## Test Scenario
+//!
+//! `create` → `provision` → `configure` → `release` → `run` → `test` →
+//! `destroy`, then the container must be gone. If a step fails, the
+//! environment is still destroyed.
+
+use std::path::PathBuf;
+use std::process::Command;
+
+use super::super::support::{process_runner, TempWorkspace};
+use anyhow::{bail, Context, Result};
+use torrust_tracker_deployer_dependency_installer::{verify_dependencies, Dependency};
+use torrust_tracker_deployer_lib::testing::e2e::containers::{
+    ContainerImageBuilder, ContainerTimeouts,
+};
+use torrust_tracker_deployer_lib::testing::e2e::tasks::black_box::create_docker_test_environment_config;
+use torrust_tracker_deployer_lib::testing::e2e::{ProcessResult, ProcessRunner};
+
+/// Environment variable enabling the Docker workflow test
+const DOCKER_E2E_ENV: &str = "TORRUST_TD_E2E_DOCKER";
+
+/// Name of the environment created by the test
+const ENVIRONMENT_NAME: &str = "e2e-docker";
+
+/// Name of the container (default instance name)
+const CONTAINER_NAME: &str = "torrust-tracker-vm-e2e-docker";
+
+/// Name of the instance image built from `docker/provisioned-instance`
+const IMAGE_NAME: &str = "torrust-provisioned-instance";
+
+/// Tag of the instance image
+const IMAGE_TAG: &str = "latest";
+
+/// Verify that all required dependencies are installed for the Docker E2E test.
+///
+/// **Current State**: `OpenTofu` creates the container and Ansible configures it.
+///
+/// # Errors
+///
+/// Returns an error if any required dependencies are missing or cannot be detected.
+fn verify_required_dependencies() -> Result<()> {
+    let required_deps = &[
+        Dependency::OpenTofu,
+        Dependency::Ansible,
+        Dependency::Docker,
+    ];
+    verify_dependencies(required_deps, false)?;
+    Ok(())
+}
+
+/// Build the instance image the Docker provider runs
+fn build_instance_image() -> Result<()> {
+    ContainerImageBuilder::new()
+        .with_name(IMAGE_NAME)
+        .with_tag(IMAGE_TAG)
+        .with_dockerfile(PathBuf::from("docker/provisioned-instance/Dockerfile"))
+        .with_context(PathBuf::from("docker/provisioned-instance"))
+        .with_build_timeout(ContainerTimeouts::default().docker_build)
+        .build()
+        .context("Failed to build the instance image")
+}
+
+/// A deployment command run against the environment
+type Step = fn(&ProcessRunner, &str) -> Result<ProcessResult>;
+
+/// Destroys the environment when dropped so a failed step never leaves a container behind
+struct DestroyOnDrop<'a> {
+    runner: &'a ProcessRunner,
+    armed: bool,
+}
+
+impl Drop for DestroyOnDrop<'_> {
+    fn drop(&mut self) {
+        if !self.armed {
+            return;
+        }
+
+        match self.runner.run_destroy_command(ENVIRONMENT_NAME) {
+            Ok(result) if result.success() => {}
+            Ok(result) => eprintln!(
+                "Failed to destroy Docker environment '{ENVIRONMENT_NAME}', remove the container '{CONTAINER_NAME}' manually: {}",
+                result.stderr()
+            ),
+            Err(e) => eprintln!(
+                "Failed to run destroy for Docker environment '{ENVIRONMENT_NAME}', remove the container '{CONTAINER_NAME}' manually: {e}"
+            ),
+        }
+    }
+}
+
+/// Verify that the container of the environment was removed
+fn assert_no_container_left() -> Result<()> {
+    let output = Command::new("docker")
+        .args([
+            "ps",
+            "--all",
+            "--quiet",
+            "--filter",
+            &format!("name=^{CONTAINER_NAME}$"),
+        ])
+        .output()
+        .context("Failed to run docker")?;
+
+    if !String::from_utf8_lossy(&output.stdout).trim().is_empty() {
+        bail!("Container '{CONTAINER_NAME}' still exists");
+    }
+
+    Ok(())
+}
+
+#[test]
+fn it_should_deploy_a_running_tracker_into_a_docker_container_and_remove_it_on_destroy() {
+    if std::env::var(DOCKER_E2E_ENV).as_deref() != Ok("true") {
+        eprintln!("Skipping Docker E2E test: {DOCKER_E2E_ENV} is not set to 'true'");
+        return;
+    }
+
+    // Verify dependencies before running tests
+    verify_required_dependencies().expect("Dependency verification failed");
+    build_instance_image().expect("Failed to build the instance image");
+
+    // Arrange: Create a workspace with a Docker configuration
+    let temp_workspace = TempWorkspace::new().expect("Failed to create temp workspace");
+    let config = create_docker_test_environment_config(
+        ENVIRONMENT_NAME,
+        &format!("{IMAGE_NAME}:{IMAGE_TAG}"),
+    );
+    temp_workspace
+        .write_config_file("environment.json", &config)
+        .expect("Failed to write config file");
+
+    let runner = process_runner()
+        .working_dir(temp_workspace.path())
+        .log_dir(temp_workspace.path().join("logs"));
+
+    let create_result = runner
+        .run_create_command("./environment.json")
+        .expect("Failed to run create command");
+    assert!(
+        create_result.success(),
+        "Create command failed: {}",
+        create_result.stderr()
+    );
+
+    let mut destroy_guard = DestroyOnDrop {
+        runner: &runner,
+        armed: true,
+    };
+
+    // Act + Assert: Run every deployment step against the container
+    let steps: [(&str, Step); 5] = [
+        ("provision", ProcessRunner::run_provision_command),
+        ("configure", ProcessRunner::run_configure_command),
+        ("release", ProcessRunner::run_release_command),
+        ("run", ProcessRunner::run_run_command),
+        ("test", ProcessRunner::run_test_command),
+    ];
+
+    for (name, step) in steps {
+        let result = step(&runner, ENVIRONMENT_NAME)
+            .unwrap_or_else(|e| panic!("Failed to run {name} command: {e}"));
+        assert!(
+            result.success(),
+            "{name} command failed: {}",
+            result.stderr()
+        );
+    }
+
+    // Act: Destroy the environment (retried by the guard if it fails)
+    let destroy_result = runner
+        .run_destroy_command(ENVIRONMENT_NAME)
+        .expect("Failed to run destroy command");
+    destroy_guard.armed = !destroy_result.success();
+    assert!(
+        destroy_result.success(),
+        "destroy command failed: {}",
+        destroy_result.stderr()
+    );
+
+    // Assert: The container is gone
+    assert_no_container_left().expect("Docker container left after destroy");
+}
@@ -32,7 +32,7 @@ use torrust_tracker_deployer_lib::testing::e2e::tasks::black_box::create_test_en
 /// Returns an error if any required dependencies are missing or cannot be detected.
 fn verify_required_dependencies() -> Result<()> {
     let required_deps: &[Dependency] = &[];
-    verify_dependencies(required_deps)?;
+    verify_dependencies(required_deps, false)?;
     Ok(())
 }
 
@@ -0,0 +1,117 @@
+//! End-to-End Black Box Test for the Hetzner Provider
+//!
+//! Deploys a tracker on a real Hetzner Cloud server by running the production
+//! application as an external process, then destroys the server.
+//!
+//! The test needs a Hetzner Cloud API token and creates billable resources,
+//! so it only runs when `TORRUST_TD_HETZNER_API_TOKEN` is set. Without it the
+//! test returns early, which lets CI skip it.
+//!
+//! ```bash
+//! TORRUST_TD_HETZNER_API_TOKEN=... cargo test --test e2e_integration hetzner_workflow -- --nocapture
+//! ```
+//!
+//! ## Test Scenario
+//!
+//! `create` → `provision` → `configure` → `release` → `run` → `test`, followed
+//! by `destroy` even when one of the previous steps fails.
+
+use super::super::support::{process_runner, TempWorkspace};
+use anyhow::Result;
+use torrust_tracker_deployer_dependency_installer::{verify_dependencies, Dependency};
+use torrust_tracker_deployer_lib::testing::e2e::tasks::black_box::create_hetzner_test_environment_config;
+use torrust_tracker_deployer_lib::testing::e2e::{ProcessResult, ProcessRunner};
+
+/// Environment variable holding the Hetzner Cloud API token
+const HETZNER_API_TOKEN_ENV: &str = "TORRUST_TD_HETZNER_API_TOKEN";
+
+/// Name of the environment (and Hetzner server) created by the test
+const ENVIRONMENT_NAME: &str = "e2e-hetzner";
+
+/// Verify that all required dependencies are installed for the Hetzner E2E test.
+///
+/// **Current State**: `OpenTofu` provisions the server and Ansible configures it.
+///
+/// # Errors
+///
+/// Returns an error if any required dependencies are missing or cannot be detected.
+fn verify_required_dependencies() -> Result<()> {
+    let required_deps = &[Dependency::OpenTofu, Dependency::Ansible];
+    verify_dependencies(required_deps, false)?;
+    Ok(())
+}
+
+/// A deployment command run against the environment
+type Step = fn(&ProcessRunner, &str) -> Result<ProcessResult>;
+
+/// Destroys the environment when dropped so a failed step never leaves a paid server behind
+struct DestroyOnDrop<'a> {
+    runner: &'a ProcessRunner,
+}
+
+impl Drop for DestroyOnDrop<'_> {
+    fn drop(&mut self) {
+        match self.runner.run_destroy_command(ENVIRONMENT_NAME) {
+            Ok(result) if result.success() => {}
+            Ok(result) => eprintln!(
+                "Failed to destroy Hetzner environment '{ENVIRONMENT_NAME}', delete the server manually: {}",
+                result.stderr()
+            ),
+            Err(e) => eprintln!(
+                "Failed to run destroy for Hetzner environment '{ENVIRONMENT_NAME}', delete the server manually: {e}"
+            ),
+        }
+    }
+}
+
+#[test]
+fn it_should_deploy_a_running_tracker_on_hetzner() {
+    if std::env::var(HETZNER_API_TOKEN_ENV).is_err() {
+        eprintln!("Skipping Hetzner E2E test: {HETZNER_API_TOKEN_ENV} is not set");
+        return;
+    }
+
+    // Verify dependencies before running tests
+    verify_required_dependencies().expect("Dependency verification failed");
+
+    // Arrange: Create a workspace with a Hetzner configuration referencing the token variable
+    let temp_workspace = TempWorkspace::new().expect("Failed to create temp workspace");
+    let config = create_hetzner_test_environment_config(ENVIRONMENT_NAME, HETZNER_API_TOKEN_ENV);
+    temp_workspace
+        .write_config_file("environment.json", &config)
+        .expect("Failed to write config file");
+
+    let runner = process_runner()
+        .working_dir(temp_workspace.path())
+        .log_dir(temp_workspace.path().join("logs"));
+
+    let create_result = runner
+        .run_create_command("./environment.json")
+        .expect("Failed to run create command");
+    assert!(
+        create_result.success(),
+        "Create command failed: {}",
+        create_result.stderr()
+    );
+
+    let _destroy_guard = DestroyOnDrop { runner: &runner };
+
+    // Act + Assert: Run every deployment step against the real server
+    let steps: [(&str, Step); 5] = [
+        ("provision", ProcessRunner::run_provision_command),
+        ("configure", ProcessRunner::run_configure_command),
+        ("release", ProcessRunner::run_release_command),
+        ("run", ProcessRunner::run_run_command),
+        ("test", ProcessRunner::run_test_command),
+    ];
+
+    for (name, step) in steps {
+        let result = step(&runner, ENVIRONMENT_NAME)
+            .unwrap_or_else(|e| panic!("Failed to run {name} command: {e}"));
+        assert!(
+            result.success(),
+            "{name} command failed: {}",
+            result.stderr()
+        );
+    }
+}
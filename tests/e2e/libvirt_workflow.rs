@@ -0,0 +1,177 @@
+//! End-to-End Black Box Test for the libvirt Provider
+//!
+//! Deploys a tracker on a local KVM virtual machine by running the production
+//! application as an external process, then destroys the environment and
+//! checks through `virsh` that the domain was removed.
+//!
+//! The test needs a running system libvirt daemon the current user can reach
+//! without sudo, so it only runs when the libvirt socket exists and
+//! `virsh --connect qemu:///system version` succeeds. Otherwise the test
+//! returns early, which lets CI skip it.
+//!
+//! ```bash
+//! cargo test --test e2e_integration libvirt_workflow -- --nocapture
+//! ```
+//!
+//! ## Test Scenario
+//!
+//! `create` → `provision` → `configure` → `release` → `run` → `test` →
+//! `destroy`, then the domain must be gone. If a step fails, the environment
+//! is still destroyed.
+
+use std::process::Command;
+use std::time::Duration;
+
+use super::super::support::{process_runner, TempWorkspace};
+use anyhow::{bail, Context, Result};
+use torrust_tracker_deployer_dependency_installer::{verify_dependencies, Dependency};
+use torrust_tracker_deployer_lib::adapters::VirshClient;
+use torrust_tracker_deployer_lib::domain::provider::LIBVIRT_URI;
+use torrust_tracker_deployer_lib::testing::e2e::tasks::black_box::create_libvirt_test_environment_config;
+use torrust_tracker_deployer_lib::testing::e2e::{ProcessResult, ProcessRunner};
+
+/// Name of the environment created by the test
+const ENVIRONMENT_NAME: &str = "e2e-libvirt";
+
+/// Name of the libvirt domain (default instance name)
+const DOMAIN_NAME: &str = "torrust-tracker-vm-e2e-libvirt";
+
+/// Verify that all required dependencies are installed for the libvirt E2E test.
+///
+/// **Current State**: `OpenTofu` provisions the domain and Ansible configures it.
+///
+/// # Errors
+///
+/// Returns an error if any required dependencies are missing or cannot be detected.
+fn verify_required_dependencies() -> Result<()> {
+    let required_deps = &[Dependency::OpenTofu, Dependency::Ansible];
+    verify_dependencies(required_deps, false)?;
+    Ok(())
+}
+
+/// Whether a system libvirt daemon is running and reachable without sudo
+fn libvirt_available() -> bool {
+    VirshClient::system_socket_exists()
+        && VirshClient::new(LIBVIRT_URI)
+            .server_version(Duration::from_secs(10))
+            .is_ok()
+}
+
+/// A deployment command run against the environment
+type Step = fn(&ProcessRunner, &str) -> Result<ProcessResult>;
+
+/// Destroys the environment when dropped so a failed step never leaves a domain behind
+struct DestroyOnDrop<'a> {
+    runner: &'a ProcessRunner,
+    armed: bool,
+}
+
+impl Drop for DestroyOnDrop<'_> {
+    fn drop(&mut self) {
+        if !self.armed {
+            return;
+        }
+
+        match self.runner.run_destroy_command(ENVIRONMENT_NAME) {
+            Ok(result) if result.success() => {}
+            Ok(result) => eprintln!(
+                "Failed to destroy libvirt environment '{ENVIRONMENT_NAME}', remove the domain '{DOMAIN_NAME}' manually: {}",
+                result.stderr()
+            ),
+            Err(e) => eprintln!(
+                "Failed to run destroy for libvirt environment '{ENVIRONMENT_NAME}', remove the domain '{DOMAIN_NAME}' manually: {e}"
+            ),
+        }
+    }
+}
+
+/// Verify that the domain of the environment no longer exists
+fn assert_domain_removed() -> Result<()> {
+    let output = Command::new("virsh")
+        .args(["--connect", LIBVIRT_URI, "list", "--all", "--name"])
+        .output()
+        .context("Failed to run virsh")?;
+
+    if !output.status.success() {
+        bail!(
+            "virsh list failed: {}",
+            String::from_utf8_lossy(&output.stderr)
+        );
+    }
+
+    let domains = String::from_utf8_lossy(&output.stdout);
+    if domains.lines().any(|name| name.trim() == DOMAIN_NAME) {
+        bail!("Domain '{DOMAIN_NAME}' still exists");
+    }
+
+    Ok(())
+}
+
+#[test]
+fn it_should_deploy_a_running_tracker_on_libvirt_and_remove_the_domain_on_destroy() {
+    if !libvirt_available() {
+        eprintln!("Skipping libvirt E2E test: the system libvirt daemon is not reachable");
+        return;
+    }
+
+    // Verify dependencies before running tests
+    verify_required_dependencies().expect("Dependency verification failed");
+
+    // Arrange: Create a workspace with a libvirt configuration using the default pool and network
+    let temp_workspace = TempWorkspace::new().expect("Failed to create temp workspace");
+    let config = create_libvirt_test_environment_config(ENVIRONMENT_NAME);
+    temp_workspace
+        .write_config_file("environment.json", &config)
+        .expect("Failed to write config file");
+
+    let runner = process_runner()
+        .working_dir(temp_workspace.path())
+        .log_dir(temp_workspace.path().join("logs"));
+
+    let create_result = runner
+        .run_create_command("./environment.json")
+        .expect("Failed to run create command");
+    assert!(
+        create_result.success(),
+        "Create command failed: {}",
+        create_result.stderr()
+    );
+
+    let mut destroy_guard = DestroyOnDrop {
+        runner: &runner,
+        armed: true,
+    };
+
+    // Act + Assert: Run every deployment step against the local domain
+    let steps: [(&str, Step); 5] = [
+        ("provision", ProcessRunner::run_provision_command),
+        ("configure", ProcessRunner::run_configure_command),
+        ("release", ProcessRunner::run_release_command),
+        ("run", ProcessRunner::run_run_command),
+        ("test", ProcessRunner::run_test_command),
+    ];
+
+    for (name, step) in steps {
+        let result = step(&runner, ENVIRONMENT_NAME)
+            .unwrap_or_else(|e| panic!("Failed to run {name} command: {e}"));
+        assert!(
+            result.success(),
+            "{name} command failed: {}",
+            result.stderr()
+        );
+    }
+
+    // Act: Destroy the environment (retried by the guard if it fails)
+    let destroy_result = runner
+        .run_destroy_command(ENVIRONMENT_NAME)
+        .expect("Failed to run destroy command");
+    destroy_guard.armed = !destroy_result.success();
+    assert!(
+        destroy_result.success(),
+        "destroy command failed: {}",
+        destroy_result.stderr()
+    );
+
+    // Assert: The domain is gone
+    assert_domain_removed().expect("libvirt domain left after destroy");
+}
@@ -37,7 +37,7 @@ use torrust_tracker_deployer_lib::testing::e2e::tasks::black_box::create_test_en
 fn verify_required_dependencies() -> Result<()> {
     // Currently no system dependencies required - empty array
     let required_deps: &[Dependency] = &[];
-    verify_dependencies(required_deps)?;
+    verify_dependencies(required_deps, false)?;
     Ok(())
 }
 
@@ -254,4 +254,13 @@ fn it_should_produce_json_by_default() {
         json.get("total_count").is_some(),
         "Expected `total_count` field in list JSON output, got: {stdout}"
     );
+
+    // Assert: The command ends with its step timings
+    let timings = result
+        .timing_summary()
+        .expect("Expected the step timings summary on stderr");
+    assert_eq!(
+        timings["status"], "succeeded",
+        "Unexpected timing summary: {timings}"
+    );
 }
@@ -5,9 +5,14 @@
 
 pub mod create_command;
 pub mod destroy_command;
+pub mod digitalocean_workflow;
+pub mod docker_workflow;
 pub mod exists_command;
+pub mod hetzner_workflow;
+pub mod libvirt_workflow;
 pub mod list_command;
 pub mod purge_command;
 pub mod render_command;
 pub mod show_command;
+pub mod ssh_command;
 pub mod validate_command;
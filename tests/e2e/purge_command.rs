@@ -15,14 +15,14 @@
 //! ## Test Scenarios
 //!
 //! 1. Purge destroyed environment: Create → Destroy → Purge (normal workflow)
-//! 2. Purge with --force flag: Verify purge skips confirmation prompt
+//! 2. Purge with --yes flag: Verify purge skips confirmation prompt
 //! 3. Purge non-existent environment: Verify proper error handling
 //! 4. Purge removes all artifacts: Verify data/, build/, and registry removal
 //! 5. Full lifecycle with custom working directory: Create → Destroy → Purge
 //!
 //! ## Design Decisions
 //!
-//! - **Always uses --force**: Tests use `--force` flag to skip interactive prompts
+//! - **Always uses --yes**: Tests use `--yes` flag to skip interactive prompts
 //! - **After destroy**: Tests purge after destroy (normal workflow pattern)
 //! - **Complete verification**: Checks data/, build/, and registry all removed
 
@@ -53,7 +53,7 @@ use torrust_tracker_deployer_lib::testing::e2e::tasks::black_box::create_test_en
 fn verify_required_dependencies() -> Result<()> {
     // Currently no system dependencies required - empty array
     let required_deps: &[Dependency] = &[];
-    verify_dependencies(required_deps)?;
+    verify_dependencies(required_deps, false)?;
     Ok(())
 }
 
@@ -102,7 +102,7 @@ fn it_should_purge_destroyed_environment_successfully() {
     env_assertions.assert_environment_exists("test-purge-destroyed");
     env_assertions.assert_environment_state_is("test-purge-destroyed", "Destroyed");
 
-    // Act: Purge environment using purge command with --force
+    // Act: Purge environment using purge command with --yes
     let purge_result = process_runner()
         .working_dir(temp_workspace.path())
         .log_dir(temp_workspace.path().join("logs"))
@@ -430,4 +430,13 @@ fn it_should_produce_json_by_default() {
         json["purged"], true,
         "Expected `purged: true` in purge JSON output, got: {stdout}"
     );
+
+    // Assert: The command ends with its step timings
+    let timings = result
+        .timing_summary()
+        .expect("Expected the step timings summary on stderr");
+    assert_eq!(
+        timings["status"], "succeeded",
+        "Unexpected timing summary: {timings}"
+    );
 }
@@ -0,0 +1,96 @@
+//! End-to-End Black Box Tests for SSH Command
+//!
+//! This test suite provides true black-box testing of the ssh command
+//! by running the production application as an external process. These tests
+//! cover the cases that do not need a running instance; connecting to a real
+//! instance is covered by the `e2e-complete-workflow-tests` binary.
+//!
+//! ## Test Scenarios
+//!
+//! 1. Non-existing environment: ssh command reports environment not found
+//! 2. Created environment: ssh command refuses to connect (no instance yet)
+
+use super::super::support::{process_runner, TempWorkspace};
+use anyhow::Result;
+use torrust_tracker_deployer_dependency_installer::{verify_dependencies, Dependency};
+use torrust_tracker_deployer_lib::testing::e2e::tasks::black_box::create_test_environment_config;
+
+/// Verify that all required dependencies are installed for ssh command E2E tests.
+///
+/// **Current State**: No system dependencies required.
+///
+/// The scenarios below fail before the `ssh` client is launched, so no
+/// infrastructure tools are needed.
+///
+/// # Errors
+///
+/// Returns an error if any required dependencies are missing or cannot be detected.
+fn verify_required_dependencies() -> Result<()> {
+    // Currently no system dependencies required - empty array
+    let required_deps: &[Dependency] = &[];
+    verify_dependencies(required_deps, false)?;
+    Ok(())
+}
+
+#[test]
+fn it_should_report_environment_not_found_when_environment_does_not_exist() {
+    // Verify dependencies before running tests
+    verify_required_dependencies().expect("Dependency verification failed");
+
+    // Arrange: Create temporary workspace (empty)
+    let temp_workspace = TempWorkspace::new().expect("Failed to create temp workspace");
+
+    // Act: Run ssh command for a non-existing environment
+    let ssh_result = process_runner()
+        .working_dir(temp_workspace.path())
+        .log_dir(temp_workspace.path().join("logs"))
+        .run_ssh_command("non-existing-env", &[])
+        .expect("Failed to run ssh command");
+
+    // Assert: Command should fail because environment doesn't exist
+    assert_eq!(ssh_result.exit_code(), Some(1));
+    let stderr = ssh_result.stderr();
+    assert!(
+        stderr.contains("not found"),
+        "Expected error about missing environment, got: {stderr}"
+    );
+}
+
+#[test]
+fn it_should_refuse_to_connect_to_environment_in_created_state() {
+    // Verify dependencies before running tests
+    verify_required_dependencies().expect("Dependency verification failed");
+
+    // Arrange: Create an environment that has not been provisioned
+    let temp_workspace = TempWorkspace::new().expect("Failed to create temp workspace");
+    let config = create_test_environment_config("test-ssh-created");
+    temp_workspace
+        .write_config_file("environment.json", &config)
+        .expect("Failed to write config file");
+
+    let create_result = process_runner()
+        .working_dir(temp_workspace.path())
+        .log_dir(temp_workspace.path().join("logs"))
+        .run_create_command("./environment.json")
+        .expect("Failed to run create command");
+    assert!(
+        create_result.success(),
+        "Create command failed: {}",
+        create_result.stderr()
+    );
+
+    // Act: Run ssh command with a remote command
+    let ssh_result = process_runner()
+        .working_dir(temp_workspace.path())
+        .log_dir(temp_workspace.path().join("logs"))
+        .run_ssh_command("test-ssh-created", &["docker", "ps"])
+        .expect("Failed to run ssh command");
+
+    // Assert: Command should refuse with a clear message
+    assert_eq!(ssh_result.exit_code(), Some(1));
+    let stderr = ssh_result.stderr();
+    assert!(
+        stderr.contains("Cannot open SSH session") && stderr.contains("Created"),
+        "Expected refusal for Created state, got: {stderr}"
+    );
+}
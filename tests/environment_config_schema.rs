@@ -0,0 +1,137 @@
+//! Integration tests for the JSON Schema of environment configuration files
+//!
+//! These tests validate the example configurations of the repository against
+//! the schema emitted by `config schema`, and check that the committed schema
+//! in `schemas/` is up to date.
+//!
+//! Run with: `cargo test --test environment_config_schema`
+
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use serde_json::Value;
+use torrust_tracker_deployer_lib::application::command_handlers::create::config::EnvironmentCreationConfig;
+use torrust_tracker_deployer_lib::application::command_handlers::create::schema::CreateSchemaCommandHandler;
+
+const EXAMPLE_DIRS: [&str; 2] = [
+    "docs/ai-training/dataset/environment-configs",
+    "docs/reference/command-outputs/environment-configs",
+];
+const COMMITTED_SCHEMA: &str = "schemas/environment-config.json";
+
+fn emitted_schema() -> Value {
+    let schema = CreateSchemaCommandHandler::execute(None).expect("schema should be generated");
+    serde_json::from_str(&schema).expect("schema should be valid JSON")
+}
+
+fn example_files() -> Vec<PathBuf> {
+    let mut files: Vec<PathBuf> = EXAMPLE_DIRS
+        .iter()
+        .flat_map(|dir| fs::read_dir(dir).unwrap_or_else(|e| panic!("{dir} should exist: {e}")))
+        .map(|entry| entry.unwrap().path())
+        .filter(|path| path.extension().is_some_and(|ext| ext == "json"))
+        .collect();
+    files.sort();
+
+    assert!(!files.is_empty(), "Should find example configuration files");
+    files
+}
+
+fn read_json(path: &Path) -> Value {
+    let content = fs::read_to_string(path).unwrap();
+    serde_json::from_str(&content)
+        .unwrap_or_else(|e| panic!("{} should be valid JSON: {e}", path.display()))
+}
+
+#[test]
+fn it_should_validate_every_example_configuration_against_the_emitted_schema() {
+    let validator = jsonschema::validator_for(&emitted_schema()).unwrap();
+
+    let failures: Vec<String> = example_files()
+        .iter()
+        .flat_map(|path| {
+            validator
+                .iter_errors(&read_json(path))
+                .map(|error| format!("{}: {} at {}", path.display(), error, error.instance_path()))
+                .collect::<Vec<_>>()
+        })
+        .collect();
+
+    assert!(
+        failures.is_empty(),
+        "Example configurations do not match the schema:\n{}",
+        failures.join("\n")
+    );
+}
+
+#[test]
+fn it_should_reject_a_configuration_with_an_unknown_provider() {
+    let validator = jsonschema::validator_for(&emitted_schema()).unwrap();
+    let mut config = read_json(&example_files()[0]);
+    config["provider"]["provider"] = Value::from("vagrant");
+
+    assert!(!validator.is_valid(&config));
+}
+
+#[test]
+fn it_should_accept_a_schema_reference_in_a_configuration() {
+    let validator = jsonschema::validator_for(&emitted_schema()).unwrap();
+    let path = example_files()
+        .into_iter()
+        .find(|path| path.ends_with("lxd-local-example.json"))
+        .unwrap();
+    let mut config = read_json(&path);
+    config["$schema"] = Value::from("./environment-schema.json");
+
+    assert!(validator.is_valid(&config));
+    assert!(serde_json::from_value::<EnvironmentCreationConfig>(config).is_ok());
+}
+
+fn collect_descriptions<'a>(schema: &'a Value, descriptions: &mut Vec<&'a str>) {
+    match schema {
+        Value::Object(map) => {
+            for (key, value) in map {
+                match value {
+                    Value::String(text) if key == "description" => descriptions.push(text),
+                    _ => collect_descriptions(value, descriptions),
+                }
+            }
+        }
+        Value::Array(items) => items
+            .iter()
+            .for_each(|item| collect_descriptions(item, descriptions)),
+        _ => {}
+    }
+}
+
+#[test]
+fn it_should_emit_descriptions_without_rust_code_blocks_or_intra_doc_links() {
+    let schema = emitted_schema();
+    let mut descriptions = Vec::new();
+    collect_descriptions(&schema, &mut descriptions);
+
+    assert!(
+        descriptions.len() > 100,
+        "Should find the field descriptions"
+    );
+    let raw_rustdoc: Vec<&str> = descriptions
+        .into_iter()
+        .filter(|text| text.contains("```") || text.contains("[`"))
+        .collect();
+    assert!(
+        raw_rustdoc.is_empty(),
+        "Descriptions with raw rustdoc:\n{}",
+        raw_rustdoc.join("\n---\n")
+    );
+}
+
+#[test]
+fn it_should_keep_the_committed_schema_in_sync_with_the_configuration_types() {
+    let committed = read_json(Path::new(COMMITTED_SCHEMA));
+
+    assert!(
+        committed == emitted_schema(),
+        "{COMMITTED_SCHEMA} is outdated, regenerate it with:\n  \
+         cargo run -- config schema > {COMMITTED_SCHEMA}"
+    );
+}
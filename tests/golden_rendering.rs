@@ -0,0 +1,102 @@
+//! Golden-file tests of the rendered deployment artifacts
+//!
+//! Every case in `fixtures/golden/` holds an environment configuration and
+//! the artifacts it renders to (`rendered/`): `OpenTofu` variables and
+//! cloud-init, Ansible inventory and variables, Docker Compose files,
+//! `tracker.toml`, Prometheus, Grafana, the Caddyfile and the backup
+//! configuration. Static templates copied unchanged are left out.
+//!
+//! The artifacts are rendered with the `render` command handler, a fixed
+//! clock and a fixed instance IP, and must match the golden files byte for
+//! byte. After an intended template change, update them with:
+//!
+//! ```bash
+//! UPDATE_GOLDEN=1 cargo test --test golden_rendering
+//! ```
+//!
+//! Rendering validates `docker-compose.yml` with `docker compose config`
+//! when `docker` is installed, as the `render` command does.
+
+use std::path::Path;
+use std::sync::Arc;
+use std::time::Duration;
+
+use chrono::{TimeZone, Utc};
+use tempfile::TempDir;
+use torrust_tracker_deployer_lib::application::command_handlers::render::{
+    RenderCommandHandler, RenderInputMode,
+};
+use torrust_tracker_deployer_lib::infrastructure::persistence::file_repository_factory::FileRepositoryFactory;
+use torrust_tracker_deployer_lib::testing::golden::GoldenFiles;
+use torrust_tracker_deployer_lib::testing::MockClock;
+
+/// Placeholder for the repository root in the fixtures (e.g. SSH key paths)
+const PROJECT_ROOT_PLACEHOLDER: &str = "<project-root>";
+
+/// Instance IP every case is rendered for (TEST-NET-3)
+const INSTANCE_IP: &str = "203.0.113.10";
+
+/// Render the configuration of a golden case and compare the artifacts with its golden files
+async fn assert_rendering_matches_golden_files(case: &str) {
+    let project_root = env!("CARGO_MANIFEST_DIR");
+    let case_dir = Path::new(project_root).join("fixtures/golden").join(case);
+
+    // Arrange: The case configuration, with the repository root filled in
+    let working_dir = TempDir::new().expect("Failed to create temp directory");
+    let config = std::fs::read_to_string(case_dir.join("environment.json"))
+        .expect("Failed to read the case configuration")
+        .replace(PROJECT_ROOT_PLACEHOLDER, project_root);
+    let config_file = working_dir.path().join("environment.json");
+    std::fs::write(&config_file, config).expect("Failed to write the configuration");
+
+    let repository =
+        FileRepositoryFactory::new(Duration::from_secs(30)).create(working_dir.path().join("data"));
+    let clock = Arc::new(MockClock::new(
+        Utc.with_ymd_and_hms(2026, 1, 27, 13, 41, 56).unwrap(),
+    ));
+    let handler = RenderCommandHandler::new(repository, clock);
+    let output_dir = working_dir.path().join("rendered");
+
+    // Act
+    handler
+        .execute(
+            RenderInputMode::ConfigFile(config_file),
+            INSTANCE_IP,
+            &output_dir,
+            false,
+            working_dir.path(),
+        )
+        .await
+        .expect("Rendering should succeed");
+
+    // Assert
+    GoldenFiles::new(case_dir.join("rendered"))
+        .with_placeholder(project_root, PROJECT_ROOT_PLACEHOLDER)
+        .excluding_copies_of(&Path::new(project_root).join("templates"))
+        .assert_matches(&output_dir);
+}
+
+#[tokio::test]
+async fn it_should_render_the_golden_artifacts_of_an_lxd_environment_with_sqlite_and_monitoring() {
+    assert_rendering_matches_golden_files("lxd-sqlite").await;
+}
+
+#[tokio::test]
+async fn it_should_render_the_golden_artifacts_of_a_hetzner_environment_with_mysql_and_https() {
+    assert_rendering_matches_golden_files("hetzner-mysql-https").await;
+}
+
+#[tokio::test]
+async fn it_should_render_the_golden_artifacts_of_a_minimal_digitalocean_environment() {
+    assert_rendering_matches_golden_files("digitalocean-minimal").await;
+}
+
+#[tokio::test]
+async fn it_should_render_the_golden_artifacts_of_a_minimal_docker_environment() {
+    assert_rendering_matches_golden_files("docker-minimal").await;
+}
+
+#[tokio::test]
+async fn it_should_render_the_golden_artifacts_of_a_libvirt_environment() {
+    assert_rendering_matches_golden_files("libvirt-sqlite").await;
+}
@@ -0,0 +1,200 @@
+//! Remote File Transfer Tests
+//!
+//! Tests for the file transfer remote actions against a real SSH server:
+//! - A file uploaded and downloaded back is unchanged, across several chunks
+//! - An upload resumes from the `.part` file left by an interrupted transfer
+//! - A download resumes after the last verified chunk of an interrupted transfer
+
+use std::cell::Cell;
+use std::panic::{catch_unwind, AssertUnwindSafe};
+
+use tempfile::TempDir;
+use torrust_tracker_deployer_lib::infrastructure::remote_actions::RemoteFileTransfer;
+
+use super::*;
+
+/// Chunk size small enough to split the test file in several chunks
+const CHUNK_SIZE: usize = 4096;
+
+/// Test file content: not a multiple of the chunk size, and not valid UTF-8
+fn test_content() -> Vec<u8> {
+    (0..10_000u32).map(|i| (i % 251) as u8).collect()
+}
+
+/// Test that a file survives an upload followed by a download
+///
+/// ## Requirements
+///
+/// - Docker must be running
+/// - SSH server image must be built: `docker build -t torrust-ssh-server:latest docker/ssh-server/`
+///
+/// The test will skip gracefully if Docker is not available or the image is not built.
+#[tokio::test]
+async fn it_should_upload_and_download_a_file_in_chunks() {
+    // Arrange: Set up real SSH server container and a local file
+    let ssh_container = match RealSshServerContainer::start().await {
+        Ok(container) => container,
+        Err(e) => {
+            println!("Skipping file transfer test - Docker/image not available: {e}");
+            return;
+        }
+    };
+
+    let client = SshTestBuilder::new()
+        .with_real_container(&ssh_container)
+        .build_client();
+    assert_connectivity_succeeds_eventually(&client, 30).await;
+
+    let temp_dir = TempDir::new().expect("Failed to create temp directory");
+    let local_file = temp_dir.path().join("upload.bin");
+    std::fs::write(&local_file, test_content()).expect("Failed to write test file");
+    let transfer = RemoteFileTransfer::new(
+        SshTestBuilder::new()
+            .with_real_container(&ssh_container)
+            .build_config(),
+    )
+    .with_chunk_size(CHUNK_SIZE);
+    let progress_events = Cell::new(0);
+
+    // Act: Upload the file, then download it to another path
+    let uploaded = transfer
+        .upload_file(&local_file, "/tmp/transfer/file.bin", &|_| {
+            progress_events.set(progress_events.get() + 1);
+        })
+        .expect("Upload should succeed");
+    let downloaded_file = temp_dir.path().join("download/file.bin");
+    let downloaded = transfer
+        .download_file("/tmp/transfer/file.bin", &downloaded_file, &|_| {})
+        .expect("Download should succeed");
+
+    // Assert: Same content and checksum on both ends, progress after each chunk
+    assert_eq!(std::fs::read(&downloaded_file).unwrap(), test_content());
+    assert_eq!(uploaded.sha256, downloaded.sha256);
+    assert_eq!(uploaded.total_bytes, 10_000);
+    assert_eq!(progress_events.get(), 3);
+    assert!(
+        !client
+            .check_command("test -e /tmp/transfer/file.bin.part")
+            .unwrap(),
+        "The partial file should be renamed once verified"
+    );
+}
+
+/// Test that an upload continues from an existing partial file
+///
+/// ## Requirements
+///
+/// - Docker must be running
+/// - SSH server image must be built: `docker build -t torrust-ssh-server:latest docker/ssh-server/`
+///
+/// The test will skip gracefully if Docker is not available or the image is not built.
+#[tokio::test]
+async fn it_should_resume_an_interrupted_upload() {
+    // Arrange: Leave the first chunk of the file as a partial upload
+    let ssh_container = match RealSshServerContainer::start().await {
+        Ok(container) => container,
+        Err(e) => {
+            println!("Skipping file transfer resume test - Docker/image not available: {e}");
+            return;
+        }
+    };
+
+    let client = SshTestBuilder::new()
+        .with_real_container(&ssh_container)
+        .build_client();
+    assert_connectivity_succeeds_eventually(&client, 30).await;
+
+    let content = test_content();
+    client
+        .execute_with_input("cat > /tmp/resumed.bin.part", &content[..CHUNK_SIZE])
+        .expect("Writing the partial file should succeed");
+
+    let temp_dir = TempDir::new().expect("Failed to create temp directory");
+    let local_file = temp_dir.path().join("upload.bin");
+    std::fs::write(&local_file, &content).expect("Failed to write test file");
+    let transfer = RemoteFileTransfer::new(
+        SshTestBuilder::new()
+            .with_real_container(&ssh_container)
+            .build_config(),
+    )
+    .with_chunk_size(CHUNK_SIZE);
+
+    // Act
+    let report = transfer
+        .upload_file(&local_file, "/tmp/resumed.bin", &|_| {})
+        .expect("Resumed upload should succeed");
+
+    // Assert
+    assert_eq!(report.resumed_from, CHUNK_SIZE as u64);
+    assert_eq!(
+        client.execute("wc -c < /tmp/resumed.bin").unwrap().trim(),
+        "10000"
+    );
+}
+
+/// Test that a download killed midway continues after its verified chunks
+///
+/// The interruption is simulated by aborting the download from its progress
+/// callback once two chunks are written, leaving the `.part` file and its
+/// progress manifest behind as a killed process would.
+///
+/// ## Requirements
+///
+/// - Docker must be running
+/// - SSH server image must be built: `docker build -t torrust-ssh-server:latest docker/ssh-server/`
+///
+/// The test will skip gracefully if Docker is not available or the image is not built.
+#[tokio::test]
+async fn it_should_resume_an_interrupted_download() {
+    // Arrange: A remote file and a download aborted after two chunks
+    let ssh_container = match RealSshServerContainer::start().await {
+        Ok(container) => container,
+        Err(e) => {
+            println!("Skipping file transfer resume test - Docker/image not available: {e}");
+            return;
+        }
+    };
+
+    let client = SshTestBuilder::new()
+        .with_real_container(&ssh_container)
+        .build_client();
+    assert_connectivity_succeeds_eventually(&client, 30).await;
+
+    let content = test_content();
+    client
+        .execute_with_input("cat > /tmp/dump.bin", &content)
+        .expect("Writing the remote file should succeed");
+
+    let temp_dir = TempDir::new().expect("Failed to create temp directory");
+    let local_file = temp_dir.path().join("backup/dump.bin");
+    let transfer = RemoteFileTransfer::new(
+        SshTestBuilder::new()
+            .with_real_container(&ssh_container)
+            .build_config(),
+    )
+    .with_chunk_size(CHUNK_SIZE);
+
+    let interrupted = catch_unwind(AssertUnwindSafe(|| {
+        transfer.download_file("/tmp/dump.bin", &local_file, &|progress| {
+            assert!(
+                progress.bytes_transferred < 2 * CHUNK_SIZE as u64,
+                "transfer killed"
+            );
+        })
+    }));
+    assert!(interrupted.is_err(), "The first download should be aborted");
+    assert!(!local_file.exists());
+
+    // Act
+    let report = transfer
+        .download_file("/tmp/dump.bin", &local_file, &|_| {})
+        .expect("Resumed download should succeed");
+
+    // Assert: Only the last chunk was downloaded again, and the progress manifest is gone
+    assert_eq!(report.resumed_from, 2 * CHUNK_SIZE as u64);
+    assert_eq!(std::fs::read(&local_file).unwrap(), content);
+    assert!(!temp_dir
+        .path()
+        .join("backup/dump.bin.part.progress")
+        .exists());
+}
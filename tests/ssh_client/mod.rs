@@ -7,6 +7,8 @@
 pub mod command_execution_tests;
 pub mod configuration_tests;
 pub mod connectivity_tests;
+pub mod file_transfer_tests;
+pub mod multiplexing_tests;
 
 // Re-export common SSH testing utilities
 use std::net::{IpAddr, SocketAddr};
@@ -59,6 +61,7 @@ pub struct SshTestBuilder {
     username: Option<String>,
     private_key_path: Option<PathBuf>,
     public_key_path: Option<PathBuf>,
+    control_dir: Option<PathBuf>,
 }
 
 impl SshTestBuilder {
@@ -70,6 +73,7 @@ impl SshTestBuilder {
             username: None,
             private_key_path: None,
             public_key_path: None,
+            control_dir: None,
         }
     }
 
@@ -103,8 +107,19 @@ impl SshTestBuilder {
         self
     }
 
+    /// Share one connection between commands through control sockets in `control_dir`
+    pub fn with_control_dir(mut self, control_dir: PathBuf) -> Self {
+        self.control_dir = Some(control_dir);
+        self
+    }
+
     /// Build the SSH client with configured parameters
     pub fn build_client(self) -> SshClient {
+        SshClient::new(self.build_config())
+    }
+
+    /// Build the SSH configuration, for actions that create their own client
+    pub fn build_config(self) -> SshConfig {
         let private_key_path = self.private_key_path.unwrap();
         let public_key_path = self.public_key_path.unwrap();
 
@@ -118,12 +133,16 @@ impl SshTestBuilder {
             Username::new(self.username.unwrap()).unwrap(),
         );
 
-        let ssh_config = SshConfig::new(
+        let mut ssh_config = SshConfig::new(
             ssh_credentials,
             SocketAddr::new(self.host_ip.unwrap(), self.port.unwrap()),
         );
 
-        SshClient::new(ssh_config)
+        if let Some(control_dir) = self.control_dir {
+            ssh_config = ssh_config.with_control_dir(control_dir);
+        }
+
+        ssh_config
     }
 }
 
@@ -7,6 +7,7 @@
 //! - `ssh_client::connectivity_tests` - SSH connectivity testing
 //! - `ssh_client::command_execution_tests` - Remote command execution
 //! - `ssh_client::configuration_tests` - Configuration validation
+//! - `ssh_client::multiplexing_tests` - Connection reuse through control sockets
 //!
 //! All test utilities, constants, and helper functions are defined in the
 //! `ssh_client::mod` module and re-exported for use across all test modules.
@@ -23,6 +24,7 @@
 //! cargo test ssh_client::connectivity_tests
 //! cargo test ssh_client::command_execution_tests
 //! cargo test ssh_client::configuration_tests
+//! cargo test ssh_client::multiplexing_tests
 //! ```
 
 mod ssh_client;
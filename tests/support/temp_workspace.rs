@@ -7,6 +7,8 @@ use anyhow::Result;
 use std::fs;
 use std::path::{Path, PathBuf};
 use tempfile::TempDir;
+use torrust_tracker_deployer_lib::domain::environment::workspace_marker::WORKSPACE_MARKER_FILE_NAME;
+use torrust_tracker_deployer_lib::domain::environment::WorkspaceMarker;
 
 /// Manages a temporary workspace for black-box testing
 ///
@@ -20,11 +22,19 @@ pub struct TempWorkspace {
 impl TempWorkspace {
     /// Create a new temporary workspace
     ///
+    /// The workspace is initialized, as done by the `init` command, so the
+    /// commands under test accept it.
+    ///
     /// # Errors
     ///
-    /// Returns an error if the temporary directory cannot be created.
+    /// Returns an error if the temporary directory or the workspace marker
+    /// cannot be created.
     pub fn new() -> Result<Self> {
         let temp_dir = TempDir::new()?;
+        fs::write(
+            temp_dir.path().join(WORKSPACE_MARKER_FILE_NAME),
+            serde_json::to_string(&WorkspaceMarker::current())?,
+        )?;
         Ok(Self { temp_dir })
     }
 